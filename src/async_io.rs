@@ -0,0 +1,308 @@
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io::{Error as IoError, ErrorKind, Result as IoResult};
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::charset::Decoder as DecoderTrait;
+use crate::charsets::{Charset, DecodeError, Decoder, Encoder, Utf8Decoder};
+
+const BUFFER_SIZE: usize = 8 * 1024;
+
+/// The asynchronous counterpart of [`crate::io::DecodingReader`].
+///
+/// Invalid input is reported as an [`ErrorKind::InvalidData`] error once everything before it
+/// has been read.
+#[derive(Debug)]
+pub struct AsyncDecodingReader<R> {
+    decoder: Option<Decoder>,
+    error: Option<DecodeError>,
+    input: Vec<u8>,
+    output: String,
+    output_position: usize,
+    reader: R,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDecodingReader<R> {
+    pub fn new(reader: R, charset: Charset) -> Self {
+        AsyncDecodingReader {
+            decoder: Some(charset.decoder()),
+            error: None,
+            input: vec![0; BUFFER_SIZE],
+            output: String::new(),
+            output_position: 0,
+            reader,
+        }
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncDecodingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        context: &mut Context,
+        buffer: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        let this = self.get_mut();
+
+        while this.output_position == this.output.len() {
+            if let Some(error) = this.error.take() {
+                return Poll::Ready(Err(IoError::new(ErrorKind::InvalidData, error)));
+            }
+
+            let decoder = match this.decoder.as_mut() {
+                Some(decoder) => decoder,
+                None => return Poll::Ready(Ok(0)),
+            };
+
+            this.output.clear();
+            this.output_position = 0;
+            let length = match Pin::new(&mut this.reader).poll_read(context, &mut this.input) {
+                Poll::Ready(Ok(length)) => length,
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let result = if length == 0 {
+                let decoder = *decoder;
+                this.decoder = None;
+                decoder.finish()
+            } else {
+                decoder.decode_to_unicode(&this.input[..length], &mut this.output)
+            };
+
+            if let Err(error) = result {
+                this.decoder = None;
+                this.error = Some(error);
+            }
+        }
+
+        let output = &this.output.as_bytes()[this.output_position..];
+        let length = output.len().min(buffer.len());
+        buffer[..length].copy_from_slice(&output[..length]);
+        this.output_position += length;
+        Poll::Ready(Ok(length))
+    }
+}
+
+/// The asynchronous counterpart of [`crate::io::EncodingWriter`].
+///
+/// Encoded output is buffered until the next write, flush, or close. Closing the writer ends the
+/// output, failing if it ended in the middle of a UTF-8 sequence.
+#[derive(Debug)]
+pub struct AsyncEncodingWriter<W> {
+    buffer: Vec<u8>,
+    encoder: Encoder,
+    finished: bool,
+    output: Vec<u8>,
+    output_position: usize,
+    text: String,
+    utf8_decoder: Utf8Decoder,
+    writer: W,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncEncodingWriter<W> {
+    pub fn new(writer: W, charset: Charset) -> Self {
+        AsyncEncodingWriter {
+            buffer: vec![0; BUFFER_SIZE],
+            encoder: charset.encoder(),
+            finished: false,
+            output: Vec::new(),
+            output_position: 0,
+            text: String::new(),
+            utf8_decoder: Utf8Decoder::default(),
+            writer,
+        }
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn poll_write_output(&mut self, context: &mut Context) -> Poll<IoResult<()>> {
+        while self.output_position < self.output.len() {
+            let output = &self.output[self.output_position..];
+
+            match Pin::new(&mut self.writer).poll_write(context, output) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(IoError::new(
+                        ErrorKind::WriteZero,
+                        "failed to write encoded output",
+                    )))
+                }
+                Poll::Ready(Ok(length)) => self.output_position += length,
+                Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        self.output.clear();
+        self.output_position = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for AsyncEncodingWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        context: &mut Context,
+        buffer: &[u8],
+    ) -> Poll<IoResult<usize>> {
+        let this = self.get_mut();
+
+        // Only accept more input once everything encoded so far has been passed on.
+        match this.poll_write_output(context) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+            Poll::Pending => return Poll::Pending,
+        }
+
+        // Encode with copies of the state, so that nothing is kept if any of the input cannot be
+        // encoded.
+        let mut encoder = this.encoder;
+        let mut utf8_decoder = this.utf8_decoder;
+        this.text.clear();
+        utf8_decoder
+            .decode_to_unicode(buffer, &mut this.text)
+            .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+
+        let mut text = this.text.as_str();
+
+        while !text.is_empty() {
+            let (read, written) = match encoder.encode(text, &mut this.buffer) {
+                Ok(encoded) => encoded,
+                Err(error) => {
+                    this.output.clear();
+                    return Poll::Ready(Err(IoError::new(ErrorKind::InvalidData, error)));
+                }
+            };
+            this.output.extend_from_slice(&this.buffer[..written]);
+            text = &text[read..];
+        }
+
+        this.encoder = encoder;
+        this.utf8_decoder = utf8_decoder;
+        Poll::Ready(Ok(buffer.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, context: &mut Context) -> Poll<IoResult<()>> {
+        let this = self.get_mut();
+
+        match this.poll_write_output(context) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.writer).poll_flush(context),
+            result => result,
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, context: &mut Context) -> Poll<IoResult<()>> {
+        let this = self.get_mut();
+
+        if !this.finished {
+            match this.poll_write_output(context) {
+                Poll::Ready(Ok(())) => {}
+                result => return result,
+            }
+
+            this.finished = true;
+            mem::take(&mut this.utf8_decoder)
+                .finish()
+                .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+
+            // The buffer is far larger than anything an encoder needs to finish.
+            let length = this.encoder.finish(&mut this.buffer).unwrap();
+            this.output.extend_from_slice(&this.buffer[..length]);
+        }
+
+        match this.poll_write_output(context) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.writer).poll_close(context),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use futures::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+
+    use super::*;
+
+    #[test]
+    fn decoding_reader_decodes_whole_input() {
+        block_on(async {
+            let input = Cursor::new(b"\x1b$BF|K\\\x1b(B!".to_vec());
+            let mut reader = AsyncDecodingReader::new(input, Charset::Iso2022Jp);
+            let mut output = String::new();
+            reader.read_to_string(&mut output).await.unwrap();
+            assert_eq!(output, "\u{65e5}\u{672c}!");
+        });
+    }
+
+    #[test]
+    fn decoding_reader_reports_incomplete_input() {
+        block_on(async {
+            let input = Cursor::new(b"a\x82".to_vec());
+            let mut reader = AsyncDecodingReader::new(input, Charset::ShiftJis);
+            let mut output = Vec::new();
+            let error = reader.read_to_end(&mut output).await.unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidData);
+            assert_eq!(output, b"a");
+        });
+    }
+
+    #[test]
+    fn encoding_writer_encodes_on_close() {
+        block_on(async {
+            let mut writer = AsyncEncodingWriter::new(Cursor::new(Vec::new()), Charset::Iso2022Jp);
+            let text = "a\u{65e5}\u{672c}".as_bytes();
+
+            // Split the text in the middle of a UTF-8 sequence.
+            writer.write_all(&text[..3]).await.unwrap();
+            writer.write_all(&text[3..]).await.unwrap();
+            writer.close().await.unwrap();
+            assert_eq!(writer.into_inner().into_inner(), b"a\x1b$BF|K\\\x1b(B");
+        });
+    }
+
+    #[test]
+    fn encoding_writer_keeps_nothing_on_error() {
+        block_on(async {
+            let mut writer = AsyncEncodingWriter::new(Cursor::new(Vec::new()), Charset::UsAscii);
+            let error = writer.write("abc\u{e9}".as_bytes()).await.unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+            writer.write_all(b"xyz").await.unwrap();
+            writer.close().await.unwrap();
+            assert_eq!(writer.into_inner().into_inner(), b"xyz");
+        });
+    }
+
+    #[test]
+    fn encoding_writer_rejects_incomplete_utf8_on_close() {
+        block_on(async {
+            let mut writer = AsyncEncodingWriter::new(Cursor::new(Vec::new()), Charset::Utf8);
+            writer.write_all(b"a\xc3").await.unwrap();
+            let error = writer.close().await.unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidData);
+        });
+    }
+}
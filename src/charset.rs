@@ -1,8 +1,11 @@
-use std::borrow::{Borrow, ToOwned};
+use std::borrow::{Borrow, Cow, ToOwned};
+use std::char::REPLACEMENT_CHARACTER;
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::ops::Deref;
+use std::string::String as StdString;
 
 use crate::charsets::UsAsciiStr;
 
@@ -16,6 +19,7 @@ macro_rules! aliases {
         use crate::charset::Alias as AliasTrait;
         use crate::charsets::UsAsciiStr;
 
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
         pub enum $enum {
         $(
             $variant,
@@ -23,6 +27,14 @@ macro_rules! aliases {
         }
 
         impl AliasTrait for $enum {
+            fn all() -> &'static [Self] {
+                &[
+                $(
+                    $enum::$variant,
+                )+
+                ]
+            }
+
             fn name(&self) -> &'static UsAsciiStr {
                 use self::$enum::*;
 
@@ -33,16 +45,36 @@ macro_rules! aliases {
                 }
             }
         }
+
+        impl $enum {
+            pub(crate) const NAMES: &'static [&'static UsAsciiStr] = &[
+            $(
+                unsafe { UsAsciiStr::from_bytes_unchecked($value) },
+            )+
+            ];
+        }
     };
 }
 
-pub trait Alias: Sized {
+pub trait Alias: Clone + Copy + Debug + Eq + Hash + Ord + PartialEq + PartialOrd + 'static {
+    /// Returns every alias of the charset in declaration order.
+    fn all() -> &'static [Self];
+
     fn name(&self) -> &'static UsAsciiStr;
 }
 
 pub trait Character:
-    Clone + Copy + Debug + Display + Eq + Hash + Ord + PartialEq + PartialOrd
+    Clone + Copy + Debug + Display + Eq + Hash + Ord + PartialEq + PartialOrd + TryFrom<char>
 {
+    /// Returns the character encoded by `value` on its own, or [`None`] if the byte is not a
+    /// complete character in the charset.
+    fn from_byte(value: u8) -> Option<Self>;
+
+    /// Returns the byte encoding the character, or [`None`] if the charset does not encode the
+    /// character as a single byte.
+    fn to_byte(self) -> Option<u8>;
+
+    fn to_char(self) -> char;
 }
 
 pub trait Str:
@@ -57,11 +89,99 @@ pub trait Str:
     + private::Sealed
     + ToOwned<Owned = <Self as Str>::String>
 {
+    type Character: Character;
     type DecodeError: Error;
+
+    /// The state carried from one character to the next while iterating over the string, such as
+    /// the current mode of a stateful charset.
+    type State: Clone + Debug + Default;
     type String: String<DecodeError = Self::DecodeError, Str = Self>;
 
+    fn char_indices(&self) -> CharIndices<'_, Self> {
+        CharIndices {
+            index: 0,
+            state: Self::State::default(),
+            str: self,
+        }
+    }
+
+    fn chars(&self) -> Chars<'_, Self> {
+        Chars {
+            char_indices: self.char_indices(),
+        }
+    }
+
     fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError>;
     unsafe fn decode_unchecked(value: &[u8]) -> &Self;
+
+    /// Decodes the character starting at byte `index`, returning it along with its length in
+    /// bytes, or [`None`] if `index` is at the end of the string. The length includes any bytes
+    /// before the character that do not encode characters themselves, such as escape sequences.
+    /// A sequence that encodes two characters yields the first one with a length of 0, so both
+    /// are found at the same index.
+    ///
+    /// `index` must lie on a character boundary, and `state` must be the state left behind by
+    /// decoding the characters before it, starting from the default state at index 0.
+    fn next_character(
+        &self,
+        index: usize,
+        state: &mut Self::State,
+    ) -> Option<(Self::Character, usize)>;
+
+    /// Converts the string into Unicode, borrowing whenever the underlying bytes are already
+    /// valid UTF-8.
+    fn to_unicode(&self) -> Cow<'_, str>;
+}
+
+/// An iterator over the characters of a [`Str`] along with their byte indices.
+#[derive(Debug)]
+pub struct CharIndices<'str, S: Str + ?Sized> {
+    index: usize,
+    state: S::State,
+    str: &'str S,
+}
+
+impl<S: Str + ?Sized> Clone for CharIndices<'_, S> {
+    fn clone(&self) -> Self {
+        CharIndices {
+            index: self.index,
+            state: self.state.clone(),
+            str: self.str,
+        }
+    }
+}
+
+impl<S: Str + ?Sized> Iterator for CharIndices<'_, S> {
+    type Item = (usize, S::Character);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        let (character, length) = self.str.next_character(index, &mut self.state)?;
+        self.index += length;
+        Some((index, character))
+    }
+}
+
+/// An iterator over the characters of a [`Str`].
+#[derive(Debug)]
+pub struct Chars<'str, S: Str + ?Sized> {
+    char_indices: CharIndices<'str, S>,
+}
+
+impl<S: Str + ?Sized> Clone for Chars<'_, S> {
+    fn clone(&self) -> Self {
+        Chars {
+            char_indices: self.char_indices.clone(),
+        }
+    }
+}
+
+impl<S: Str + ?Sized> Iterator for Chars<'_, S> {
+    type Item = S::Character;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.char_indices.next().map(|(_, character)| character)
+    }
 }
 
 pub trait String:
@@ -83,25 +203,158 @@ pub trait String:
     + Sized
 {
     type DecodeError: Error;
+    type EncodeError: Error;
     type Str: Str<DecodeError = Self::DecodeError, String = Self> + ?Sized;
 
     fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)>;
+
+    /// Decodes the bytes into Unicode, substituting each invalid sequence according to
+    /// `replacement` instead of failing.
+    ///
+    /// Returns the decoded text along with the number of substitutions that were made.
+    fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize);
+
     unsafe fn decode_unchecked(value: Vec<u8>) -> Self;
+
+    /// Encodes Unicode text into the charset, failing on the first character that the charset
+    /// cannot represent.
+    fn encode(value: &str) -> Result<Self, Self::EncodeError>;
+}
+
+/// A stateful decoder for input that arrives in chunks.
+///
+/// Byte sequences split across chunks are carried over to the next call. Once an error has been
+/// returned, the decoder should no longer be used.
+pub trait Decoder: Debug + Default {
+    type DecodeError: DecodeError;
+
+    /// Validates the next chunk of input, appending the bytes of every complete character to
+    /// `output`.
+    fn decode_to_bytes(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Self::DecodeError>;
+
+    /// Decodes the next chunk of input, appending every complete character to `output`.
+    fn decode_to_unicode(
+        &mut self,
+        input: &[u8],
+        output: &mut StdString,
+    ) -> Result<(), Self::DecodeError>;
+
+    /// Signals the end of the input, failing if it ended in the middle of a byte sequence.
+    fn finish(self) -> Result<(), Self::DecodeError>;
 }
 
-pub trait DecodeError: Clone + Copy + Debug + Eq + Error + Hash + PartialEq {}
+/// A stateful encoder for text that arrives in chunks, writing into caller provided buffers.
+pub trait Encoder: Debug + Default {
+    type EncodeError: EncodeError;
+
+    /// Encodes as much of `input` as fits into `output`, returning the number of bytes of input
+    /// that were read and the number of bytes of output that were written. A buffer of at least
+    /// 8 bytes always has room for the next character, including any escape sequence before it.
+    ///
+    /// Encoding stops before the first character that cannot be encoded, so an error is only
+    /// returned if the very first character of `input` cannot be encoded. In that case, nothing is
+    /// written and [`EncodeError::valid_up_to`] is relative to the start of the whole text.
+    ///
+    /// A character that the charset may encode together with the character after it is held
+    /// back until that character is known, which can be in a later call or in [`finish`].
+    ///
+    /// [`finish`]: Encoder::finish
+    fn encode(
+        &mut self,
+        input: &str,
+        output: &mut [u8],
+    ) -> Result<(usize, usize), Self::EncodeError>;
+
+    /// Writes whatever is needed to end the output, such as returning a stateful charset to its
+    /// initial state or a character held back by [`encode`], returning the number of bytes
+    /// written.
+    ///
+    /// [`encode`]: Encoder::encode
+    ///
+    /// Returns [`None`] without writing anything if `output` is too small, in which case this
+    /// should be called again with a larger buffer.
+    fn finish(&mut self, output: &mut [u8]) -> Option<usize>;
+}
+
+/// The substitution policy used by lossy decoding for invalid byte sequences.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Replacement {
+    /// Substitute the given character.
+    Character(char),
+
+    /// Substitute U+FFFD REPLACEMENT CHARACTER.
+    ReplacementCharacter,
+}
+
+impl Replacement {
+    pub fn character(&self) -> char {
+        use self::Replacement::*;
+
+        match *self {
+            Character(character) => character,
+            ReplacementCharacter => REPLACEMENT_CHARACTER,
+        }
+    }
+}
+
+pub trait DecodeError: Clone + Copy + Debug + Eq + Error + Hash + PartialEq {
+    /// The length of the invalid byte sequence, or [`None`] if the input ended in the middle of a
+    /// byte sequence that could have been valid had more input been given.
+    fn error_len(&self) -> Option<usize>;
+
+    /// The invalid byte sequence starting at [`DecodeError::valid_up_to`].
+    ///
+    /// If [`DecodeError::error_len`] is [`None`], these are the remaining bytes of the incomplete
+    /// sequence.
+    fn invalid_bytes(&self) -> &[u8];
+
+    /// The index in the input up to which valid characters were decoded.
+    fn valid_up_to(&self) -> usize;
+}
+
+pub trait EncodeError: Clone + Copy + Debug + Eq + Error + Hash + PartialEq {
+    /// The character that cannot be represented in the charset.
+    fn character(&self) -> char;
+
+    /// The byte index in the input up to which the text could be encoded, i.e. the index of
+    /// [`EncodeError::character`].
+    fn valid_up_to(&self) -> usize;
+}
 
 pub trait Charset: private::Sealed {
     type Alias: Alias;
-    type Character: Character;
+    type Character: Character + TryFrom<char, Error = Self::EncodeError>;
     type DecodeError: DecodeError;
-    type Str: Str<DecodeError = Self::DecodeError, String = Self::String> + ?Sized;
-    type String: String<DecodeError = Self::DecodeError, Str = Self::Str>;
+    type Decoder: Decoder<DecodeError = Self::DecodeError>;
+    type EncodeError: EncodeError;
+    type Encoder: Encoder<EncodeError = Self::EncodeError>;
+    type Str: Str<Character = Self::Character, DecodeError = Self::DecodeError, String = Self::String>
+        + ?Sized;
+    type String: String<
+        DecodeError = Self::DecodeError,
+        EncodeError = Self::EncodeError,
+        Str = Self::Str,
+    >;
 
+    /// Whether every US-ASCII byte sequence is valid in the charset and decodes to the same
+    /// characters.
+    const IS_ASCII_COMPATIBLE: bool;
     const MIB_ENUM: u16;
     const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr>;
     const PRIMARY_NAME: &'static UsAsciiStr;
 
+    fn decoder() -> Self::Decoder {
+        Self::Decoder::default()
+    }
+
+    fn encoder() -> Self::Encoder {
+        Self::Encoder::default()
+    }
+
     fn is_mime_text_suitable() -> bool {
         Self::PREFERRED_MIME_NAME.is_some()
     }
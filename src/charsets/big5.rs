@@ -0,0 +1,82 @@
+use crate::charsets::tables::big5;
+
+multi_byte_charset! {
+    primary_name: "Big5",
+    preferred_mime_name: "Big5",
+    mib_enum: 2026,
+    next: next,
+    encode_character: encode_character,
+    aliases: [
+        (CsBig5, b"csBig5");
+    ],
+}
+
+fn next(value: &[u8]) -> Option<Sequence> {
+    next_with_table(value, big5::decode, &[])
+}
+
+/// Decodes the sequence at the start of `value`, looking up double-byte characters with `decode`
+/// unless they are one of the `pairs`. This is shared with Big5-HKSCS, which only differs in its
+/// table and pairs.
+pub(crate) fn next_with_table(
+    value: &[u8],
+    decode: fn(usize) -> Option<char>,
+    pairs: &[sequence::Pair],
+) -> Option<Sequence> {
+    let lead = *value.first()?;
+
+    let sequence = match lead {
+        0x00..=0x7f => Sequence::Character(lead as char, 1),
+        0x81..=0xfe => match value.get(1) {
+            Some(&trail) => next_double_byte(lead, trail, decode, pairs),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+/// Decodes a double-byte character.
+fn next_double_byte(
+    lead: u8,
+    trail: u8,
+    decode: fn(usize) -> Option<char>,
+    pairs: &[sequence::Pair],
+) -> Sequence {
+    if let Some(&(first, second, _)) = pairs.iter().find(|pair| pair.2 == [lead, trail]) {
+        return Sequence::Pair(first, second, 2);
+    }
+
+    let trail_offset = match trail {
+        0x40..=0x7e => 0x40,
+        0xa1..=0xfe => 0x62,
+        _ => return Sequence::Invalid(1),
+    };
+    let pointer = usize::from(lead - 0x81) * 157 + usize::from(trail - trail_offset);
+
+    match decode(pointer) {
+        Some(character) => Sequence::Character(character, 2),
+        None => sequence::invalid_pair(trail),
+    }
+}
+
+fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    encode_character_with_table(character, big5::encode)
+}
+
+/// Encodes a character, looking up the pointers of non-ASCII characters with `encode`.
+pub(crate) fn encode_character_with_table(
+    character: char,
+    encode: fn(char) -> Option<usize>,
+) -> Option<([u8; 4], usize)> {
+    match character {
+        '\0'..='\x7f' => Some(([character as u8, 0, 0, 0], 1)),
+        _ => {
+            let pointer = encode(character)?;
+            let (lead, trail) = ((pointer / 157) as u8, (pointer % 157) as u8);
+            let trail_offset = if trail < 0x3f { 0x40 } else { 0x62 };
+            Some(([lead + 0x81, trail + trail_offset, 0, 0], 2))
+        }
+    }
+}
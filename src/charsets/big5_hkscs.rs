@@ -0,0 +1,88 @@
+use crate::charsets::big5::{encode_character_with_table, next_with_table};
+use crate::charsets::tables::big5_hkscs;
+
+multi_byte_charset! {
+    primary_name: "Big5-HKSCS",
+    mib_enum: 2101,
+    next: next,
+    encode_character: encode_character,
+    pairs: PAIRS,
+    aliases: [
+        (CsBig5Hkscs, b"csBig5HKSCS");
+    ],
+}
+
+/// The letters followed by a combining mark that are encoded as a single sequence.
+const PAIRS: [sequence::Pair; 4] = [
+    ('\u{ca}', '\u{304}', b"\x88\x62"),
+    ('\u{ca}', '\u{30c}', b"\x88\x64"),
+    ('\u{ea}', '\u{304}', b"\x88\xa3"),
+    ('\u{ea}', '\u{30c}', b"\x88\xa5"),
+];
+
+fn next(value: &[u8]) -> Option<Sequence> {
+    next_with_table(value, big5_hkscs::decode, &PAIRS)
+}
+
+fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    encode_character_with_table(character, big5_hkscs::encode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_decode_to_two_characters() {
+        let string = Str::decode(b"\x88\x62\x88\x64\x88\xa3\x88\xa5").unwrap();
+        assert_eq!(
+            string.to_unicode(),
+            "\u{ca}\u{304}\u{ca}\u{30c}\u{ea}\u{304}\u{ea}\u{30c}"
+        );
+
+        let indices: Vec<_> = string.char_indices().map(|(index, _)| index).collect();
+        assert_eq!(indices, [0, 0, 2, 2, 4, 4, 6, 6]);
+    }
+
+    #[test]
+    fn pairs_decode_across_chunks() {
+        let mut decoder = Decoder::default();
+        let mut output = StdString::new();
+        decoder.decode_to_unicode(b"a\x88", &mut output).unwrap();
+        decoder.decode_to_unicode(b"\x62b", &mut output).unwrap();
+        decoder.finish().unwrap();
+        assert_eq!(output, "a\u{ca}\u{304}b");
+    }
+
+    #[test]
+    fn pairs_encode_together() {
+        let string = String::encode("\u{ca}\u{304}\u{ca}\u{ea}\u{30c}").unwrap();
+        assert_eq!(Vec::from(string), b"\x88\x62\x88\x66\x88\xa5");
+        assert!(String::encode("\u{304}").is_err());
+    }
+
+    #[test]
+    fn encoder_holds_back_the_first_character_of_a_pair() {
+        let mut encoder = Encoder::default();
+        let mut output = [0; 8];
+        assert_eq!(encoder.encode("a\u{ca}", &mut output).unwrap(), (3, 1));
+        assert_eq!(
+            encoder.encode("\u{304}\u{ea}", &mut output[1..]).unwrap(),
+            (4, 2)
+        );
+        assert_eq!(encoder.finish(&mut output[3..]), Some(2));
+        assert_eq!(&output[..5], b"a\x88\x62\x88\xa7");
+    }
+
+    #[test]
+    fn encoder_writes_the_held_back_character_before_failing() {
+        let mut encoder = Encoder::default();
+        let mut output = [0; 8];
+        assert_eq!(encoder.encode("\u{ca}", &mut output).unwrap(), (2, 0));
+        assert_eq!(encoder.encode("\u{30b}", &mut output).unwrap(), (0, 2));
+        assert_eq!(&output[..2], b"\x88\x66");
+        let error = encoder.encode("\u{30b}", &mut output[2..]).unwrap_err();
+        assert_eq!(error.character(), '\u{30b}');
+        assert_eq!(error.valid_up_to(), 2);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::charsets::tables::{jis0208, jis0212};
+
+multi_byte_charset! {
+    primary_name: "Extended_UNIX_Code_Packed_Format_for_Japanese",
+    preferred_mime_name: "EUC-JP",
+    mib_enum: 18,
+    next: next,
+    encode_character: encode_character,
+    aliases: [
+        (CsEucPkdFmtJapanese, b"csEUCPkdFmtJapanese");
+        (EucJp, b"EUC-JP");
+    ],
+}
+
+fn next(value: &[u8]) -> Option<Sequence> {
+    let lead = *value.first()?;
+
+    let sequence = match lead {
+        0x00..=0x7f => Sequence::Character(lead as char, 1),
+        0x8e => match value.get(1) {
+            Some(&trail @ 0xa1..=0xdf) => Sequence::Character(halfwidth_katakana(trail), 2),
+            Some(_) => Sequence::Invalid(1),
+            None => Sequence::Incomplete,
+        },
+        0x8f => match value.get(1..3) {
+            Some(&[first @ 0xa1..=0xfe, second @ 0xa1..=0xfe]) => {
+                match jis0212::decode(pointer(first, second)) {
+                    Some(character) => Sequence::Character(character, 3),
+                    None => Sequence::Invalid(3),
+                }
+            }
+            Some(_) => Sequence::Invalid(1),
+            None => match value.get(1) {
+                Some(0xa1..=0xfe) | None => Sequence::Incomplete,
+                Some(_) => Sequence::Invalid(1),
+            },
+        },
+        0xa1..=0xfe => match value.get(1) {
+            Some(&trail @ 0xa1..=0xfe) => match jis0208::decode(pointer(lead, trail)) {
+                Some(character) => Sequence::Character(character, 2),
+                None => Sequence::Invalid(2),
+            },
+            Some(_) => Sequence::Invalid(1),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    match character {
+        '\0'..='\x7f' => Some(([character as u8, 0, 0, 0], 1)),
+        // The yen sign and overline take the place of the backslash and tilde in JIS X 0201, so
+        // they are encoded as such even though those bytes decode to ASCII.
+        '\u{a5}' => Some(([0x5c, 0, 0, 0], 1)),
+        '\u{203e}' => Some(([0x7e, 0, 0, 0], 1)),
+        '\u{ff61}'..='\u{ff9f}' => {
+            let byte = (u32::from(character) - 0xff61) as u8 + 0xa1;
+            Some(([0x8e, byte, 0, 0], 2))
+        }
+        _ => match jis0208::encode(character) {
+            Some(pointer) => {
+                let (first, second) = bytes(pointer);
+                Some(([first, second, 0, 0], 2))
+            }
+            None => {
+                let (first, second) = bytes(jis0212::encode(character)?);
+                Some(([0x8f, first, second, 0], 3))
+            }
+        },
+    }
+}
+
+/// Returns the pointer of the character encoded by a pair of bytes between 0xa1 and 0xfe.
+fn pointer(first: u8, second: u8) -> usize {
+    usize::from(first - 0xa1) * 94 + usize::from(second - 0xa1)
+}
+
+/// Returns the pair of bytes encoding the character at `pointer`.
+fn bytes(pointer: usize) -> (u8, u8) {
+    ((pointer / 94) as u8 + 0xa1, (pointer % 94) as u8 + 0xa1)
+}
+
+fn halfwidth_katakana(byte: u8) -> char {
+    // Unsafe justification: U+FF61 through U+FF9F are all scalar values.
+    unsafe { char::from_u32_unchecked(0xff61 + u32::from(byte - 0xa1)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_text() {
+        let string = Str::decode(b"\xc6\xfc\xcb\xdc\xb8\xec\x8e\xb1\x8f\xb0\xa1").unwrap();
+        assert_eq!(
+            string.to_unicode(),
+            "\u{65e5}\u{672c}\u{8a9e}\u{ff71}\u{4e02}"
+        );
+    }
+
+    #[test]
+    fn encodes_known_text() {
+        let string = String::encode("a\u{65e5}\u{672c}\u{8a9e}\u{ff71}").unwrap();
+        assert_eq!(Vec::from(string), b"a\xc6\xfc\xcb\xdc\xb8\xec\x8e\xb1");
+    }
+
+    #[test]
+    fn errors_name_the_preferred_mime_name() {
+        let error = Str::decode(b"a\xff").unwrap_err();
+        assert_eq!(error.to_string(), "invalid EUC-JP sequence [ff] at index 1");
+    }
+}
@@ -0,0 +1,88 @@
+use crate::charsets::tables::uhc;
+
+multi_byte_charset! {
+    primary_name: "EUC-KR",
+    preferred_mime_name: "EUC-KR",
+    mib_enum: 38,
+    next: next,
+    encode_character: encode_character,
+    aliases: [
+        (CsEucKr, b"csEUCKR");
+    ],
+}
+
+/// Decoding and encoding follow Unified Hangul Code, the superset of EUC-KR that Windows uses
+/// under the same name, since text labelled EUC-KR often contains its additional Hangul syllables.
+/// KS_C_5601-1987 shares this implementation.
+pub(crate) fn next(value: &[u8]) -> Option<Sequence> {
+    let lead = *value.first()?;
+
+    let sequence = match lead {
+        0x00..=0x7f => Sequence::Character(lead as char, 1),
+        0x81..=0xfe => match value.get(1) {
+            Some(&trail) => next_double_byte(lead, trail),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+/// Decodes a double-byte character.
+fn next_double_byte(lead: u8, trail: u8) -> Sequence {
+    if trail < 0x41 || trail == 0xff {
+        return Sequence::Invalid(1);
+    }
+
+    let pointer = usize::from(lead - 0x81) * 190 + usize::from(trail - 0x41);
+
+    match uhc::decode(pointer) {
+        Some(character) => Sequence::Character(character, 2),
+        None => sequence::invalid_pair(trail),
+    }
+}
+
+pub(crate) fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    match character {
+        '\0'..='\x7f' => Some(([character as u8, 0, 0, 0], 1)),
+        _ => {
+            let pointer = uhc::encode(character)?;
+            let (lead, trail) = ((pointer / 190) as u8, (pointer % 190) as u8);
+            Some(([lead + 0x81, trail + 0x41, 0, 0], 2))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_text() {
+        let string = Str::decode(b"\xc7\xd1\xb1\xb9\xbe\xee a").unwrap();
+        assert_eq!(string.to_unicode(), "\u{d55c}\u{ad6d}\u{c5b4} a");
+    }
+
+    #[test]
+    fn decodes_unified_hangul_code_extension() {
+        let string = Str::decode(b"\x8c\x63\xb9\xe6").unwrap();
+        assert_eq!(string.to_unicode(), "\u{b620}\u{bc29}");
+    }
+
+    #[test]
+    fn encodes_known_text() {
+        let string = String::encode("\u{b620}\u{bc29}\u{ac01}\u{d558}").unwrap();
+        assert_eq!(Vec::from(string), b"\x8c\x63\xb9\xe6\xb0\xa2\xc7\xcf");
+        let error = String::encode("\u{4e00}\u{1f600}").unwrap_err();
+        assert_eq!(error.character(), '\u{1f600}');
+        assert_eq!(error.valid_up_to(), 3);
+    }
+
+    #[test]
+    fn invalid_trail_byte_leaves_ascii_for_next_character() {
+        let error = Str::decode(b"a\xc7\x41").unwrap_err();
+        assert_eq!(error.valid_up_to(), 1);
+        assert_eq!(error.invalid_bytes(), b"\xc7");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::charsets::tables::gb18030;
+
+multi_byte_charset! {
+    primary_name: "GB18030",
+    mib_enum: 114,
+    next: next,
+    encode_character: encode_character,
+    aliases: [
+        (CsGb18030, b"csGB18030");
+    ],
+}
+
+fn next(value: &[u8]) -> Option<Sequence> {
+    let lead = *value.first()?;
+
+    let sequence = match lead {
+        0x00..=0x7f => Sequence::Character(lead as char, 1),
+        0x81..=0xfe => match value.get(1) {
+            Some(&second @ 0x30..=0x39) => next_four_byte(lead, second, &value[2..]),
+            Some(&trail) => next_two_byte(lead, trail),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+/// Decodes a four-byte character from its first two bytes and whatever follows them. If the
+/// sequence is malformed, only the first byte is considered invalid.
+fn next_four_byte(first: u8, second: u8, rest: &[u8]) -> Sequence {
+    match rest {
+        [third @ 0x81..=0xfe, fourth @ 0x30..=0x39, ..] => {
+            let pointer = ((u32::from(first - 0x81) * 10 + u32::from(second - 0x30)) * 126
+                + u32::from(third - 0x81))
+                * 10
+                + u32::from(fourth - 0x30);
+
+            match gb18030::decode_four_byte(pointer) {
+                Some(character) => Sequence::Character(character, 4),
+                None => Sequence::Invalid(4),
+            }
+        }
+        [] | [0x81..=0xfe] => Sequence::Incomplete,
+        _ => Sequence::Invalid(1),
+    }
+}
+
+fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    match character {
+        '\0'..='\x7f' => Some(([character as u8, 0, 0, 0], 1)),
+        _ => match gb18030::encode(character) {
+            Some(pointer) => Some(encode_two_byte(pointer)),
+            None => {
+                let pointer = gb18030::encode_four_byte(character);
+                let bytes = [
+                    (pointer / 12600) as u8 + 0x81,
+                    (pointer / 1260 % 10) as u8 + 0x30,
+                    (pointer / 10 % 126) as u8 + 0x81,
+                    (pointer % 10) as u8 + 0x30,
+                ];
+                Some((bytes, 4))
+            }
+        },
+    }
+}
+
+/// Decodes a two-byte character, which GBK shares.
+pub(crate) fn next_two_byte(lead: u8, trail: u8) -> Sequence {
+    let trail_offset = match trail {
+        0x40..=0x7e => 0x40,
+        0x80..=0xfe => 0x41,
+        _ => return Sequence::Invalid(1),
+    };
+    let pointer = usize::from(lead - 0x81) * 190 + usize::from(trail - trail_offset);
+
+    match gb18030::decode(pointer) {
+        Some(character) => Sequence::Character(character, 2),
+        None => sequence::invalid_pair(trail),
+    }
+}
+
+pub(crate) fn encode_two_byte(pointer: usize) -> ([u8; 4], usize) {
+    let (lead, trail) = ((pointer / 190) as u8, (pointer % 190) as u8);
+    let trail_offset = if trail < 0x3f { 0x40 } else { 0x41 };
+    ([lead + 0x81, trail + trail_offset, 0, 0], 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_text() {
+        let string =
+            Str::decode(b"a\xd6\xd0\x81\x30\x81\x30\x90\x30\x81\x30\x94\x39\xfc\x36").unwrap();
+        assert_eq!(string.to_unicode(), "a\u{4e2d}\u{80}\u{10000}\u{1f600}");
+    }
+
+    #[test]
+    fn encodes_known_text() {
+        let string = String::encode("a\u{4e2d}\u{80}\u{20ac}\u{1f600}").unwrap();
+        assert_eq!(
+            Vec::from(string),
+            b"a\xd6\xd0\x81\x30\x81\x30\xa2\xe3\x94\x39\xfc\x36"
+        );
+    }
+
+    #[test]
+    fn rejects_four_byte_sequences_beyond_unicode() {
+        let error = Str::decode(b"a\xe3\x32\x9a\x36").unwrap_err();
+        assert_eq!(error.valid_up_to(), 1);
+    }
+}
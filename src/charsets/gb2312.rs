@@ -0,0 +1,63 @@
+use crate::charsets::tables::gb2312;
+
+multi_byte_charset! {
+    primary_name: "GB2312",
+    preferred_mime_name: "GB2312",
+    mib_enum: 2025,
+    next: next,
+    encode_character: encode_character,
+    aliases: [
+        (CsGb2312, b"csGB2312");
+    ],
+}
+
+fn next(value: &[u8]) -> Option<Sequence> {
+    let lead = *value.first()?;
+
+    let sequence = match lead {
+        0x00..=0x7f => Sequence::Character(lead as char, 1),
+        0xa1..=0xfe => match value.get(1) {
+            Some(&trail @ 0xa1..=0xfe) => {
+                let pointer = usize::from(lead - 0xa1) * 94 + usize::from(trail - 0xa1);
+
+                match gb2312::decode(pointer) {
+                    Some(character) => Sequence::Character(character, 2),
+                    None => Sequence::Invalid(2),
+                }
+            }
+            Some(_) => Sequence::Invalid(1),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    match character {
+        '\0'..='\x7f' => Some(([character as u8, 0, 0, 0], 1)),
+        _ => {
+            let pointer = gb2312::encode(character)?;
+            let (lead, trail) = ((pointer / 94) as u8, (pointer % 94) as u8);
+            Some(([lead + 0xa1, trail + 0xa1, 0, 0], 2))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_text() {
+        let string = Str::decode(b"a\xd6\xd0\xce\xc4").unwrap();
+        assert_eq!(string.to_unicode(), "a\u{4e2d}\u{6587}");
+    }
+
+    #[test]
+    fn encodes_known_text() {
+        let string = String::encode("a\u{4e2d}\u{6587}").unwrap();
+        assert_eq!(Vec::from(string), b"a\xd6\xd0\xce\xc4");
+    }
+}
@@ -0,0 +1,59 @@
+use crate::charsets::gb18030::{encode_two_byte, next_two_byte};
+use crate::charsets::tables::gb18030;
+
+multi_byte_charset! {
+    primary_name: "GBK",
+    mib_enum: 113,
+    next: next,
+    encode_character: encode_character,
+    aliases: [
+        (Cp936, b"CP936");
+        (CsGbk, b"csGBK");
+        (Ms936, b"MS936");
+        (Windows936, b"windows-936");
+    ],
+}
+
+fn next(value: &[u8]) -> Option<Sequence> {
+    let lead = *value.first()?;
+
+    let sequence = match lead {
+        0x00..=0x7f => Sequence::Character(lead as char, 1),
+        0x80 => Sequence::Character('\u{20ac}', 1),
+        0x81..=0xfe => match value.get(1) {
+            Some(&trail) => next_two_byte(lead, trail),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    match character {
+        '\0'..='\x7f' => Some(([character as u8, 0, 0, 0], 1)),
+        // Code page 936 added the euro sign as a single byte, which GB18030 later also encoded as
+        // a pair of bytes. The single byte is preferred as the more widely understood encoding.
+        '\u{20ac}' => Some(([0x80, 0, 0, 0], 1)),
+        _ => gb18030::encode(character).map(encode_two_byte),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_text() {
+        let string = Str::decode(b"a\xd6\xd0\x81\x40\x80").unwrap();
+        assert_eq!(string.to_unicode(), "a\u{4e2d}\u{4e02}\u{20ac}");
+    }
+
+    #[test]
+    fn encodes_known_text() {
+        let string = String::encode("a\u{4e2d}\u{4e02}\u{20ac}").unwrap();
+        assert_eq!(Vec::from(string), b"a\xd6\xd0\x81\x40\x80");
+        assert!(String::encode("\u{1f600}").is_err());
+    }
+}
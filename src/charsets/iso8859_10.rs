@@ -0,0 +1,45 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\u{0104}', '\u{0112}', '\u{0122}', '\u{012a}', '\u{0128}', '\u{0136}', '\u{00a7}', '\u{013b}', '\u{0110}', '\u{0160}', '\u{0166}', '\u{017d}', '\u{00ad}', '\u{016a}', '\u{014a}',
+    '\u{00b0}', '\u{0105}', '\u{0113}', '\u{0123}', '\u{012b}', '\u{0129}', '\u{0137}', '\u{00b7}', '\u{013c}', '\u{0111}', '\u{0161}', '\u{0167}', '\u{017e}', '\u{2015}', '\u{016b}', '\u{014b}',
+    '\u{0100}', '\u{00c1}', '\u{00c2}', '\u{00c3}', '\u{00c4}', '\u{00c5}', '\u{00c6}', '\u{012e}', '\u{010c}', '\u{00c9}', '\u{0118}', '\u{00cb}', '\u{0116}', '\u{00cd}', '\u{00ce}', '\u{00cf}',
+    '\u{00d0}', '\u{0145}', '\u{014c}', '\u{00d3}', '\u{00d4}', '\u{00d5}', '\u{00d6}', '\u{0168}', '\u{00d8}', '\u{0172}', '\u{00da}', '\u{00db}', '\u{00dc}', '\u{00dd}', '\u{00de}', '\u{00df}',
+    '\u{0101}', '\u{00e1}', '\u{00e2}', '\u{00e3}', '\u{00e4}', '\u{00e5}', '\u{00e6}', '\u{012f}', '\u{010d}', '\u{00e9}', '\u{0119}', '\u{00eb}', '\u{0117}', '\u{00ed}', '\u{00ee}', '\u{00ef}',
+    '\u{00f0}', '\u{0146}', '\u{014d}', '\u{00f3}', '\u{00f4}', '\u{00f5}', '\u{00f6}', '\u{0169}', '\u{00f8}', '\u{0173}', '\u{00fa}', '\u{00fb}', '\u{00fc}', '\u{00fd}', '\u{00fe}', '\u{0138}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 128] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a7}', 0xa7), ('\u{00ad}', 0xad), ('\u{00b0}', 0xb0), ('\u{00b7}', 0xb7), ('\u{00c1}', 0xc1), ('\u{00c2}', 0xc2), ('\u{00c3}', 0xc3),
+    ('\u{00c4}', 0xc4), ('\u{00c5}', 0xc5), ('\u{00c6}', 0xc6), ('\u{00c9}', 0xc9), ('\u{00cb}', 0xcb), ('\u{00cd}', 0xcd), ('\u{00ce}', 0xce), ('\u{00cf}', 0xcf),
+    ('\u{00d0}', 0xd0), ('\u{00d3}', 0xd3), ('\u{00d4}', 0xd4), ('\u{00d5}', 0xd5), ('\u{00d6}', 0xd6), ('\u{00d8}', 0xd8), ('\u{00da}', 0xda), ('\u{00db}', 0xdb),
+    ('\u{00dc}', 0xdc), ('\u{00dd}', 0xdd), ('\u{00de}', 0xde), ('\u{00df}', 0xdf), ('\u{00e1}', 0xe1), ('\u{00e2}', 0xe2), ('\u{00e3}', 0xe3), ('\u{00e4}', 0xe4),
+    ('\u{00e5}', 0xe5), ('\u{00e6}', 0xe6), ('\u{00e9}', 0xe9), ('\u{00eb}', 0xeb), ('\u{00ed}', 0xed), ('\u{00ee}', 0xee), ('\u{00ef}', 0xef), ('\u{00f0}', 0xf0),
+    ('\u{00f3}', 0xf3), ('\u{00f4}', 0xf4), ('\u{00f5}', 0xf5), ('\u{00f6}', 0xf6), ('\u{00f8}', 0xf8), ('\u{00fa}', 0xfa), ('\u{00fb}', 0xfb), ('\u{00fc}', 0xfc),
+    ('\u{00fd}', 0xfd), ('\u{00fe}', 0xfe), ('\u{0100}', 0xc0), ('\u{0101}', 0xe0), ('\u{0104}', 0xa1), ('\u{0105}', 0xb1), ('\u{010c}', 0xc8), ('\u{010d}', 0xe8),
+    ('\u{0110}', 0xa9), ('\u{0111}', 0xb9), ('\u{0112}', 0xa2), ('\u{0113}', 0xb2), ('\u{0116}', 0xcc), ('\u{0117}', 0xec), ('\u{0118}', 0xca), ('\u{0119}', 0xea),
+    ('\u{0122}', 0xa3), ('\u{0123}', 0xb3), ('\u{0128}', 0xa5), ('\u{0129}', 0xb5), ('\u{012a}', 0xa4), ('\u{012b}', 0xb4), ('\u{012e}', 0xc7), ('\u{012f}', 0xe7),
+    ('\u{0136}', 0xa6), ('\u{0137}', 0xb6), ('\u{0138}', 0xff), ('\u{013b}', 0xa8), ('\u{013c}', 0xb8), ('\u{0145}', 0xd1), ('\u{0146}', 0xf1), ('\u{014a}', 0xaf),
+    ('\u{014b}', 0xbf), ('\u{014c}', 0xd2), ('\u{014d}', 0xf2), ('\u{0160}', 0xaa), ('\u{0161}', 0xba), ('\u{0166}', 0xab), ('\u{0167}', 0xbb), ('\u{0168}', 0xd7),
+    ('\u{0169}', 0xf7), ('\u{016a}', 0xae), ('\u{016b}', 0xbe), ('\u{0172}', 0xd9), ('\u{0173}', 0xf9), ('\u{017d}', 0xac), ('\u{017e}', 0xbc), ('\u{2015}', 0xbd),
+];
+
+single_byte_charset! {
+    primary_name: "ISO-8859-10",
+    mib_enum: 13,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsIsoLatin6, b"csISOLatin6");
+        (Iso8859_10_1992, b"ISO_8859-10:1992");
+        (IsoIr157, b"iso-ir-157");
+        (L6, b"l6");
+        (Latin6, b"latin6");
+    ],
+}
@@ -0,0 +1,41 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\u{201d}', '\u{00a2}', '\u{00a3}', '\u{00a4}', '\u{201e}', '\u{00a6}', '\u{00a7}', '\u{00d8}', '\u{00a9}', '\u{0156}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{00c6}',
+    '\u{00b0}', '\u{00b1}', '\u{00b2}', '\u{00b3}', '\u{201c}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{00f8}', '\u{00b9}', '\u{0157}', '\u{00bb}', '\u{00bc}', '\u{00bd}', '\u{00be}', '\u{00e6}',
+    '\u{0104}', '\u{012e}', '\u{0100}', '\u{0106}', '\u{00c4}', '\u{00c5}', '\u{0118}', '\u{0112}', '\u{010c}', '\u{00c9}', '\u{0179}', '\u{0116}', '\u{0122}', '\u{0136}', '\u{012a}', '\u{013b}',
+    '\u{0160}', '\u{0143}', '\u{0145}', '\u{00d3}', '\u{014c}', '\u{00d5}', '\u{00d6}', '\u{00d7}', '\u{0172}', '\u{0141}', '\u{015a}', '\u{016a}', '\u{00dc}', '\u{017b}', '\u{017d}', '\u{00df}',
+    '\u{0105}', '\u{012f}', '\u{0101}', '\u{0107}', '\u{00e4}', '\u{00e5}', '\u{0119}', '\u{0113}', '\u{010d}', '\u{00e9}', '\u{017a}', '\u{0117}', '\u{0123}', '\u{0137}', '\u{012b}', '\u{013c}',
+    '\u{0161}', '\u{0144}', '\u{0146}', '\u{00f3}', '\u{014d}', '\u{00f5}', '\u{00f6}', '\u{00f7}', '\u{0173}', '\u{0142}', '\u{015b}', '\u{016b}', '\u{00fc}', '\u{017c}', '\u{017e}', '\u{2019}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 128] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a2}', 0xa2), ('\u{00a3}', 0xa3), ('\u{00a4}', 0xa4), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab),
+    ('\u{00ac}', 0xac), ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1), ('\u{00b2}', 0xb2), ('\u{00b3}', 0xb3), ('\u{00b5}', 0xb5),
+    ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7), ('\u{00b9}', 0xb9), ('\u{00bb}', 0xbb), ('\u{00bc}', 0xbc), ('\u{00bd}', 0xbd), ('\u{00be}', 0xbe), ('\u{00c4}', 0xc4),
+    ('\u{00c5}', 0xc5), ('\u{00c6}', 0xaf), ('\u{00c9}', 0xc9), ('\u{00d3}', 0xd3), ('\u{00d5}', 0xd5), ('\u{00d6}', 0xd6), ('\u{00d7}', 0xd7), ('\u{00d8}', 0xa8),
+    ('\u{00dc}', 0xdc), ('\u{00df}', 0xdf), ('\u{00e4}', 0xe4), ('\u{00e5}', 0xe5), ('\u{00e6}', 0xbf), ('\u{00e9}', 0xe9), ('\u{00f3}', 0xf3), ('\u{00f5}', 0xf5),
+    ('\u{00f6}', 0xf6), ('\u{00f7}', 0xf7), ('\u{00f8}', 0xb8), ('\u{00fc}', 0xfc), ('\u{0100}', 0xc2), ('\u{0101}', 0xe2), ('\u{0104}', 0xc0), ('\u{0105}', 0xe0),
+    ('\u{0106}', 0xc3), ('\u{0107}', 0xe3), ('\u{010c}', 0xc8), ('\u{010d}', 0xe8), ('\u{0112}', 0xc7), ('\u{0113}', 0xe7), ('\u{0116}', 0xcb), ('\u{0117}', 0xeb),
+    ('\u{0118}', 0xc6), ('\u{0119}', 0xe6), ('\u{0122}', 0xcc), ('\u{0123}', 0xec), ('\u{012a}', 0xce), ('\u{012b}', 0xee), ('\u{012e}', 0xc1), ('\u{012f}', 0xe1),
+    ('\u{0136}', 0xcd), ('\u{0137}', 0xed), ('\u{013b}', 0xcf), ('\u{013c}', 0xef), ('\u{0141}', 0xd9), ('\u{0142}', 0xf9), ('\u{0143}', 0xd1), ('\u{0144}', 0xf1),
+    ('\u{0145}', 0xd2), ('\u{0146}', 0xf2), ('\u{014c}', 0xd4), ('\u{014d}', 0xf4), ('\u{0156}', 0xaa), ('\u{0157}', 0xba), ('\u{015a}', 0xda), ('\u{015b}', 0xfa),
+    ('\u{0160}', 0xd0), ('\u{0161}', 0xf0), ('\u{016a}', 0xdb), ('\u{016b}', 0xfb), ('\u{0172}', 0xd8), ('\u{0173}', 0xf8), ('\u{0179}', 0xca), ('\u{017a}', 0xea),
+    ('\u{017b}', 0xdd), ('\u{017c}', 0xfd), ('\u{017d}', 0xde), ('\u{017e}', 0xfe), ('\u{2019}', 0xff), ('\u{201c}', 0xb4), ('\u{201d}', 0xa1), ('\u{201e}', 0xa5),
+];
+
+single_byte_charset! {
+    primary_name: "ISO-8859-13",
+    mib_enum: 109,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsIso885913, b"csISO885913");
+    ],
+}
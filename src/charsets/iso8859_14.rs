@@ -0,0 +1,47 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\u{1e02}', '\u{1e03}', '\u{00a3}', '\u{010a}', '\u{010b}', '\u{1e0a}', '\u{00a7}', '\u{1e80}', '\u{00a9}', '\u{1e82}', '\u{1e0b}', '\u{1ef2}', '\u{00ad}', '\u{00ae}', '\u{0178}',
+    '\u{1e1e}', '\u{1e1f}', '\u{0120}', '\u{0121}', '\u{1e40}', '\u{1e41}', '\u{00b6}', '\u{1e56}', '\u{1e81}', '\u{1e57}', '\u{1e83}', '\u{1e60}', '\u{1ef3}', '\u{1e84}', '\u{1e85}', '\u{1e61}',
+    '\u{00c0}', '\u{00c1}', '\u{00c2}', '\u{00c3}', '\u{00c4}', '\u{00c5}', '\u{00c6}', '\u{00c7}', '\u{00c8}', '\u{00c9}', '\u{00ca}', '\u{00cb}', '\u{00cc}', '\u{00cd}', '\u{00ce}', '\u{00cf}',
+    '\u{0174}', '\u{00d1}', '\u{00d2}', '\u{00d3}', '\u{00d4}', '\u{00d5}', '\u{00d6}', '\u{1e6a}', '\u{00d8}', '\u{00d9}', '\u{00da}', '\u{00db}', '\u{00dc}', '\u{00dd}', '\u{0176}', '\u{00df}',
+    '\u{00e0}', '\u{00e1}', '\u{00e2}', '\u{00e3}', '\u{00e4}', '\u{00e5}', '\u{00e6}', '\u{00e7}', '\u{00e8}', '\u{00e9}', '\u{00ea}', '\u{00eb}', '\u{00ec}', '\u{00ed}', '\u{00ee}', '\u{00ef}',
+    '\u{0175}', '\u{00f1}', '\u{00f2}', '\u{00f3}', '\u{00f4}', '\u{00f5}', '\u{00f6}', '\u{1e6b}', '\u{00f8}', '\u{00f9}', '\u{00fa}', '\u{00fb}', '\u{00fc}', '\u{00fd}', '\u{0177}', '\u{00ff}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 128] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a3}', 0xa3), ('\u{00a7}', 0xa7), ('\u{00a9}', 0xa9), ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00b6}', 0xb6), ('\u{00c0}', 0xc0),
+    ('\u{00c1}', 0xc1), ('\u{00c2}', 0xc2), ('\u{00c3}', 0xc3), ('\u{00c4}', 0xc4), ('\u{00c5}', 0xc5), ('\u{00c6}', 0xc6), ('\u{00c7}', 0xc7), ('\u{00c8}', 0xc8),
+    ('\u{00c9}', 0xc9), ('\u{00ca}', 0xca), ('\u{00cb}', 0xcb), ('\u{00cc}', 0xcc), ('\u{00cd}', 0xcd), ('\u{00ce}', 0xce), ('\u{00cf}', 0xcf), ('\u{00d1}', 0xd1),
+    ('\u{00d2}', 0xd2), ('\u{00d3}', 0xd3), ('\u{00d4}', 0xd4), ('\u{00d5}', 0xd5), ('\u{00d6}', 0xd6), ('\u{00d8}', 0xd8), ('\u{00d9}', 0xd9), ('\u{00da}', 0xda),
+    ('\u{00db}', 0xdb), ('\u{00dc}', 0xdc), ('\u{00dd}', 0xdd), ('\u{00df}', 0xdf), ('\u{00e0}', 0xe0), ('\u{00e1}', 0xe1), ('\u{00e2}', 0xe2), ('\u{00e3}', 0xe3),
+    ('\u{00e4}', 0xe4), ('\u{00e5}', 0xe5), ('\u{00e6}', 0xe6), ('\u{00e7}', 0xe7), ('\u{00e8}', 0xe8), ('\u{00e9}', 0xe9), ('\u{00ea}', 0xea), ('\u{00eb}', 0xeb),
+    ('\u{00ec}', 0xec), ('\u{00ed}', 0xed), ('\u{00ee}', 0xee), ('\u{00ef}', 0xef), ('\u{00f1}', 0xf1), ('\u{00f2}', 0xf2), ('\u{00f3}', 0xf3), ('\u{00f4}', 0xf4),
+    ('\u{00f5}', 0xf5), ('\u{00f6}', 0xf6), ('\u{00f8}', 0xf8), ('\u{00f9}', 0xf9), ('\u{00fa}', 0xfa), ('\u{00fb}', 0xfb), ('\u{00fc}', 0xfc), ('\u{00fd}', 0xfd),
+    ('\u{00ff}', 0xff), ('\u{010a}', 0xa4), ('\u{010b}', 0xa5), ('\u{0120}', 0xb2), ('\u{0121}', 0xb3), ('\u{0174}', 0xd0), ('\u{0175}', 0xf0), ('\u{0176}', 0xde),
+    ('\u{0177}', 0xfe), ('\u{0178}', 0xaf), ('\u{1e02}', 0xa1), ('\u{1e03}', 0xa2), ('\u{1e0a}', 0xa6), ('\u{1e0b}', 0xab), ('\u{1e1e}', 0xb0), ('\u{1e1f}', 0xb1),
+    ('\u{1e40}', 0xb4), ('\u{1e41}', 0xb5), ('\u{1e56}', 0xb7), ('\u{1e57}', 0xb9), ('\u{1e60}', 0xbb), ('\u{1e61}', 0xbf), ('\u{1e6a}', 0xd7), ('\u{1e6b}', 0xf7),
+    ('\u{1e80}', 0xa8), ('\u{1e81}', 0xb8), ('\u{1e82}', 0xaa), ('\u{1e83}', 0xba), ('\u{1e84}', 0xbd), ('\u{1e85}', 0xbe), ('\u{1ef2}', 0xac), ('\u{1ef3}', 0xbc),
+];
+
+single_byte_charset! {
+    primary_name: "ISO-8859-14",
+    mib_enum: 110,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsIso885914, b"csISO885914");
+        (Iso8859_14Alt, b"ISO_8859-14");
+        (Iso8859_14_1998, b"ISO_8859-14:1998");
+        (IsoCeltic, b"iso-celtic");
+        (IsoIr199, b"iso-ir-199");
+        (L8, b"l8");
+        (Latin8, b"latin8");
+    ],
+}
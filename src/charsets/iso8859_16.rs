@@ -0,0 +1,46 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\u{0104}', '\u{0105}', '\u{0141}', '\u{20ac}', '\u{201e}', '\u{0160}', '\u{00a7}', '\u{0161}', '\u{00a9}', '\u{0218}', '\u{00ab}', '\u{0179}', '\u{00ad}', '\u{017a}', '\u{017b}',
+    '\u{00b0}', '\u{00b1}', '\u{010c}', '\u{0142}', '\u{017d}', '\u{201d}', '\u{00b6}', '\u{00b7}', '\u{017e}', '\u{010d}', '\u{0219}', '\u{00bb}', '\u{0152}', '\u{0153}', '\u{0178}', '\u{017c}',
+    '\u{00c0}', '\u{00c1}', '\u{00c2}', '\u{0102}', '\u{00c4}', '\u{0106}', '\u{00c6}', '\u{00c7}', '\u{00c8}', '\u{00c9}', '\u{00ca}', '\u{00cb}', '\u{00cc}', '\u{00cd}', '\u{00ce}', '\u{00cf}',
+    '\u{0110}', '\u{0143}', '\u{00d2}', '\u{00d3}', '\u{00d4}', '\u{0150}', '\u{00d6}', '\u{015a}', '\u{0170}', '\u{00d9}', '\u{00da}', '\u{00db}', '\u{00dc}', '\u{0118}', '\u{021a}', '\u{00df}',
+    '\u{00e0}', '\u{00e1}', '\u{00e2}', '\u{0103}', '\u{00e4}', '\u{0107}', '\u{00e6}', '\u{00e7}', '\u{00e8}', '\u{00e9}', '\u{00ea}', '\u{00eb}', '\u{00ec}', '\u{00ed}', '\u{00ee}', '\u{00ef}',
+    '\u{0111}', '\u{0144}', '\u{00f2}', '\u{00f3}', '\u{00f4}', '\u{0151}', '\u{00f6}', '\u{015b}', '\u{0171}', '\u{00f9}', '\u{00fa}', '\u{00fb}', '\u{00fc}', '\u{0119}', '\u{021b}', '\u{00ff}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 128] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a7}', 0xa7), ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab), ('\u{00ad}', 0xad), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1), ('\u{00b6}', 0xb6),
+    ('\u{00b7}', 0xb7), ('\u{00bb}', 0xbb), ('\u{00c0}', 0xc0), ('\u{00c1}', 0xc1), ('\u{00c2}', 0xc2), ('\u{00c4}', 0xc4), ('\u{00c6}', 0xc6), ('\u{00c7}', 0xc7),
+    ('\u{00c8}', 0xc8), ('\u{00c9}', 0xc9), ('\u{00ca}', 0xca), ('\u{00cb}', 0xcb), ('\u{00cc}', 0xcc), ('\u{00cd}', 0xcd), ('\u{00ce}', 0xce), ('\u{00cf}', 0xcf),
+    ('\u{00d2}', 0xd2), ('\u{00d3}', 0xd3), ('\u{00d4}', 0xd4), ('\u{00d6}', 0xd6), ('\u{00d9}', 0xd9), ('\u{00da}', 0xda), ('\u{00db}', 0xdb), ('\u{00dc}', 0xdc),
+    ('\u{00df}', 0xdf), ('\u{00e0}', 0xe0), ('\u{00e1}', 0xe1), ('\u{00e2}', 0xe2), ('\u{00e4}', 0xe4), ('\u{00e6}', 0xe6), ('\u{00e7}', 0xe7), ('\u{00e8}', 0xe8),
+    ('\u{00e9}', 0xe9), ('\u{00ea}', 0xea), ('\u{00eb}', 0xeb), ('\u{00ec}', 0xec), ('\u{00ed}', 0xed), ('\u{00ee}', 0xee), ('\u{00ef}', 0xef), ('\u{00f2}', 0xf2),
+    ('\u{00f3}', 0xf3), ('\u{00f4}', 0xf4), ('\u{00f6}', 0xf6), ('\u{00f9}', 0xf9), ('\u{00fa}', 0xfa), ('\u{00fb}', 0xfb), ('\u{00fc}', 0xfc), ('\u{00ff}', 0xff),
+    ('\u{0102}', 0xc3), ('\u{0103}', 0xe3), ('\u{0104}', 0xa1), ('\u{0105}', 0xa2), ('\u{0106}', 0xc5), ('\u{0107}', 0xe5), ('\u{010c}', 0xb2), ('\u{010d}', 0xb9),
+    ('\u{0110}', 0xd0), ('\u{0111}', 0xf0), ('\u{0118}', 0xdd), ('\u{0119}', 0xfd), ('\u{0141}', 0xa3), ('\u{0142}', 0xb3), ('\u{0143}', 0xd1), ('\u{0144}', 0xf1),
+    ('\u{0150}', 0xd5), ('\u{0151}', 0xf5), ('\u{0152}', 0xbc), ('\u{0153}', 0xbd), ('\u{015a}', 0xd7), ('\u{015b}', 0xf7), ('\u{0160}', 0xa6), ('\u{0161}', 0xa8),
+    ('\u{0170}', 0xd8), ('\u{0171}', 0xf8), ('\u{0178}', 0xbe), ('\u{0179}', 0xac), ('\u{017a}', 0xae), ('\u{017b}', 0xaf), ('\u{017c}', 0xbf), ('\u{017d}', 0xb4),
+    ('\u{017e}', 0xb8), ('\u{0218}', 0xaa), ('\u{0219}', 0xba), ('\u{021a}', 0xde), ('\u{021b}', 0xfe), ('\u{201d}', 0xb5), ('\u{201e}', 0xa5), ('\u{20ac}', 0xa4),
+];
+
+single_byte_charset! {
+    primary_name: "ISO-8859-16",
+    mib_enum: 112,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsIso885916, b"csISO885916");
+        (Iso8859_16Alt, b"ISO_8859-16");
+        (Iso8859_16_2001, b"ISO_8859-16:2001");
+        (IsoIr226, b"iso-ir-226");
+        (L10, b"l10");
+        (Latin10, b"latin10");
+    ],
+}
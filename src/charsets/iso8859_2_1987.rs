@@ -1,11 +1,16 @@
-use std::borrow::{Borrow, ToOwned};
+use std::borrow::{Borrow, Cow, ToOwned};
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
 use std::ops::Deref;
+use std::slice;
+use std::str;
+use std::string::String as StdString;
 
 use crate::charset::private::Sealed;
 use crate::charset::{
     Character as CharacterTrait, Charset as CharsetTrait, DecodeError as DecodeErrorTrait,
+    Decoder as DecoderTrait, EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
     Str as StrTrait, String as StringTrait,
 };
 
@@ -26,9 +31,13 @@ impl CharsetTrait for Charset {
     type Alias = Alias;
     type Character = Character;
     type DecodeError = DecodeError;
+    type Decoder = Decoder;
+    type EncodeError = EncodeError;
+    type Encoder = Encoder;
     type Str = Str;
     type String = String;
 
+    const IS_ASCII_COMPATIBLE: bool = true;
     const MIB_ENUM: u16 = 5;
     const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> =
         Some(unsafe { UsAsciiStr::from_bytes_unchecked(b"ISO-8859-2") });
@@ -41,7 +50,19 @@ impl Sealed for Charset {}
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Character(char);
 
-impl CharacterTrait for Character {}
+impl CharacterTrait for Character {
+    fn from_byte(value: u8) -> Option<Self> {
+        Some(Character::from(value))
+    }
+
+    fn to_byte(self) -> Option<u8> {
+        encode_character(self.0)
+    }
+
+    fn to_char(self) -> char {
+        self.0
+    }
+}
 
 impl Debug for Character {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
@@ -65,6 +86,20 @@ impl From<u8> for Character {
     }
 }
 
+impl TryFrom<char> for Character {
+    type Error = EncodeError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match encode_character(value) {
+            Some(_) => Ok(Character(value)),
+            None => Err(EncodeError {
+                character: value,
+                valid_up_to: 0,
+            }),
+        }
+    }
+}
+
 #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Str([u8]);
 
@@ -93,7 +128,9 @@ impl Display for Str {
 impl Sealed for Str {}
 
 impl StrTrait for Str {
+    type Character = Character;
     type DecodeError = DecodeError;
+    type State = ();
     type String = String;
 
     fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
@@ -105,6 +142,23 @@ impl StrTrait for Str {
     unsafe fn decode_unchecked(value: &[u8]) -> &Self {
         &*(value as *const [u8] as *const Str)
     }
+
+    fn next_character(
+        &self,
+        index: usize,
+        _state: &mut Self::State,
+    ) -> Option<(Self::Character, usize)> {
+        self.0.get(index).map(|&byte| (Character::from(byte), 1))
+    }
+
+    fn to_unicode(&self) -> Cow<'_, str> {
+        if self.0.is_ascii() {
+            // Unsafe justification: ASCII is a subset of UTF-8.
+            Cow::Borrowed(unsafe { str::from_utf8_unchecked(&self.0) })
+        } else {
+            Cow::Owned(self.0.iter().map(|&byte| Character::from(byte).0).collect())
+        }
+    }
 }
 
 impl ToOwned for Str {
@@ -172,6 +226,7 @@ impl Sealed for String {}
 
 impl StringTrait for String {
     type DecodeError = DecodeError;
+    type EncodeError = EncodeError;
     type Str = Str;
 
     fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
@@ -180,24 +235,182 @@ impl StringTrait for String {
         Ok(unsafe { Self::decode_unchecked(value) })
     }
 
+    fn decode_lossy(value: &[u8], _replacement: Replacement) -> (Cow<'_, str>, usize) {
+        // Unsafe justification: ISO_8859-2:1987 is a 1-byte charset with a 1-to-1 mapping for each
+        // 8-bit value to a character, so any byte slice can be considered valid.
+        (unsafe { Str::decode_unchecked(value) }.to_unicode(), 0)
+    }
+
     unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
         String(value)
     }
+
+    fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+        let mut bytes = Vec::with_capacity(value.len());
+
+        for (index, character) in value.char_indices() {
+            match encode_character(character) {
+                Some(byte) => bytes.push(byte),
+                None => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: index,
+                    })
+                }
+            }
+        }
+
+        Ok(String(bytes))
+    }
 }
 
+/// Every byte is valid ISO_8859-2:1987, so this error is never actually returned.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-pub struct DecodeError;
+pub struct DecodeError {
+    byte: u8,
+    valid_up_to: usize,
+}
+
+impl DecodeErrorTrait for DecodeError {
+    fn error_len(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn invalid_bytes(&self) -> &[u8] {
+        slice::from_ref(&self.byte)
+    }
 
-impl DecodeErrorTrait for DecodeError {}
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
 
 impl Display for DecodeError {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("invalid ISO-8859-2:1987 (ISO-8859-2)")
+        write!(
+            formatter,
+            "invalid ISO-8859-2:1987 (ISO-8859-2) byte {:#04x} at index {}",
+            self.byte, self.valid_up_to
+        )
     }
 }
 
 impl Error for DecodeError {}
 
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EncodeError {
+    character: char,
+    valid_up_to: usize,
+}
+
+impl EncodeErrorTrait for EncodeError {
+    fn character(&self) -> char {
+        self.character
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "character {:?} at index {} cannot be encoded as ISO-8859-2:1987 (ISO-8859-2)",
+            self.character, self.valid_up_to
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Decoder;
+
+impl DecoderTrait for Decoder {
+    type DecodeError = DecodeError;
+
+    fn decode_to_bytes(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Self::DecodeError> {
+        output.extend_from_slice(input);
+        Ok(())
+    }
+
+    fn decode_to_unicode(
+        &mut self,
+        input: &[u8],
+        output: &mut StdString,
+    ) -> Result<(), Self::DecodeError> {
+        output.extend(input.iter().map(|&byte| Character::from(byte).0));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Self::DecodeError> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Encoder {
+    position: usize,
+}
+
+impl EncoderTrait for Encoder {
+    type EncodeError = EncodeError;
+
+    fn encode(
+        &mut self,
+        input: &str,
+        output: &mut [u8],
+    ) -> Result<(usize, usize), Self::EncodeError> {
+        let mut read = 0;
+        let mut written = 0;
+
+        for character in input.chars() {
+            if written == output.len() {
+                break;
+            }
+
+            match encode_character(character) {
+                Some(byte) => {
+                    output[written] = byte;
+                    read += character.len_utf8();
+                    written += 1;
+                }
+                None if read == 0 => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: self.position,
+                    })
+                }
+                None => break,
+            }
+        }
+
+        self.position += read;
+        Ok((read, written))
+    }
+
+    fn finish(&mut self, _output: &mut [u8]) -> Option<usize> {
+        Some(0)
+    }
+}
+
+fn encode_character(character: char) -> Option<u8> {
+    if (character as u32) < 0xa0 {
+        return Some(character as u8);
+    }
+
+    GRAPHICS_RIGHT_TO_UNICODE_MAP
+        .iter()
+        .position(|&mapped| mapped == character)
+        .map(|index| index as u8 + 0xa0)
+}
+
 aliases! {
     Alias,
 
@@ -1,11 +1,16 @@
-use std::borrow::{Borrow, ToOwned};
+use std::borrow::{Borrow, Cow, ToOwned};
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
 use std::ops::Deref;
+use std::slice;
+use std::str;
+use std::string::String as StdString;
 
 use crate::charset::private::Sealed;
 use crate::charset::{
     Character as CharacterTrait, Charset as CharsetTrait, DecodeError as DecodeErrorTrait,
+    Decoder as DecoderTrait, EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
     Str as StrTrait, String as StringTrait,
 };
 
@@ -26,9 +31,13 @@ impl CharsetTrait for Charset {
     type Alias = Alias;
     type Character = Character;
     type DecodeError = DecodeError;
+    type Decoder = Decoder;
+    type EncodeError = EncodeError;
+    type Encoder = Encoder;
     type Str = Str;
     type String = String;
 
+    const IS_ASCII_COMPATIBLE: bool = true;
     const MIB_ENUM: u16 = 6;
     const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> =
         Some(unsafe { UsAsciiStr::from_bytes_unchecked(b"ISO-8859-3") });
@@ -41,7 +50,23 @@ impl Sealed for Charset {}
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Character(char);
 
-impl CharacterTrait for Character {}
+impl CharacterTrait for Character {
+    fn from_byte(value: u8) -> Option<Self> {
+        if is_valid_byte(value) {
+            Some(Character::from(value))
+        } else {
+            None
+        }
+    }
+
+    fn to_byte(self) -> Option<u8> {
+        encode_character(self.0)
+    }
+
+    fn to_char(self) -> char {
+        self.0
+    }
+}
 
 impl Debug for Character {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
@@ -65,6 +90,20 @@ impl From<u8> for Character {
     }
 }
 
+impl TryFrom<char> for Character {
+    type Error = EncodeError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match encode_character(value) {
+            Some(_) => Ok(Character(value)),
+            None => Err(EncodeError {
+                character: value,
+                valid_up_to: 0,
+            }),
+        }
+    }
+}
+
 #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Str([u8]);
 
@@ -93,7 +132,9 @@ impl Display for Str {
 impl Sealed for Str {}
 
 impl StrTrait for Str {
+    type Character = Character;
     type DecodeError = DecodeError;
+    type State = ();
     type String = String;
 
     fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
@@ -104,6 +145,23 @@ impl StrTrait for Str {
     unsafe fn decode_unchecked(value: &[u8]) -> &Self {
         &*(value as *const [u8] as *const Str)
     }
+
+    fn next_character(
+        &self,
+        index: usize,
+        _state: &mut Self::State,
+    ) -> Option<(Self::Character, usize)> {
+        self.0.get(index).map(|&byte| (Character::from(byte), 1))
+    }
+
+    fn to_unicode(&self) -> Cow<'_, str> {
+        if self.0.is_ascii() {
+            // Unsafe justification: ASCII is a subset of UTF-8.
+            Cow::Borrowed(unsafe { str::from_utf8_unchecked(&self.0) })
+        } else {
+            Cow::Owned(self.0.iter().map(|&byte| Character::from(byte).0).collect())
+        }
+    }
 }
 
 impl ToOwned for Str {
@@ -171,6 +229,7 @@ impl Sealed for String {}
 
 impl StringTrait for String {
     type DecodeError = DecodeError;
+    type EncodeError = EncodeError;
     type Str = Str;
 
     fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
@@ -181,34 +240,241 @@ impl StringTrait for String {
         Ok(unsafe { Self::decode_unchecked(value) })
     }
 
+    fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+        if validate(value).is_ok() {
+            return (unsafe { Str::decode_unchecked(value) }.to_unicode(), 0);
+        }
+
+        let mut replacements = 0;
+        let text = value
+            .iter()
+            .map(|&byte| {
+                if is_valid_byte(byte) {
+                    Character::from(byte).0
+                } else {
+                    replacements += 1;
+                    replacement.character()
+                }
+            })
+            .collect();
+
+        (Cow::Owned(text), replacements)
+    }
+
     unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
         String(value)
     }
+
+    fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+        let mut bytes = Vec::with_capacity(value.len());
+
+        for (index, character) in value.char_indices() {
+            match encode_character(character) {
+                Some(byte) => bytes.push(byte),
+                None => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: index,
+                    })
+                }
+            }
+        }
+
+        Ok(String(bytes))
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-pub struct DecodeError;
+pub struct DecodeError {
+    byte: u8,
+    valid_up_to: usize,
+}
 
-impl DecodeErrorTrait for DecodeError {}
+impl DecodeErrorTrait for DecodeError {
+    fn error_len(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn invalid_bytes(&self) -> &[u8] {
+        slice::from_ref(&self.byte)
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
 
 impl Display for DecodeError {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("invalid ISO-8859-3:1988 (ISO-8859-3)")
+        write!(
+            formatter,
+            "invalid ISO-8859-3:1988 (ISO-8859-3) byte {:#04x} at index {}",
+            self.byte, self.valid_up_to
+        )
     }
 }
 
 impl Error for DecodeError {}
 
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EncodeError {
+    character: char,
+    valid_up_to: usize,
+}
+
+impl EncodeErrorTrait for EncodeError {
+    fn character(&self) -> char {
+        self.character
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "character {:?} at index {} cannot be encoded as ISO-8859-3:1988 (ISO-8859-3)",
+            self.character, self.valid_up_to
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Decoder {
+    position: usize,
+}
+
+impl DecoderTrait for Decoder {
+    type DecodeError = DecodeError;
+
+    fn decode_to_bytes(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Self::DecodeError> {
+        match validate(input) {
+            Ok(()) => {
+                output.extend_from_slice(input);
+                self.position += input.len();
+                Ok(())
+            }
+            Err(error) => {
+                output.extend_from_slice(&input[..error.valid_up_to]);
+                self.position += error.valid_up_to;
+                Err(DecodeError {
+                    valid_up_to: self.position,
+                    ..error
+                })
+            }
+        }
+    }
+
+    fn decode_to_unicode(
+        &mut self,
+        input: &[u8],
+        output: &mut StdString,
+    ) -> Result<(), Self::DecodeError> {
+        for &byte in input {
+            match Character::from_byte(byte) {
+                Some(character) => output.push(character.to_char()),
+                None => {
+                    return Err(DecodeError {
+                        byte,
+                        valid_up_to: self.position,
+                    })
+                }
+            }
+
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Self::DecodeError> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Encoder {
+    position: usize,
+}
+
+impl EncoderTrait for Encoder {
+    type EncodeError = EncodeError;
+
+    fn encode(
+        &mut self,
+        input: &str,
+        output: &mut [u8],
+    ) -> Result<(usize, usize), Self::EncodeError> {
+        let mut read = 0;
+        let mut written = 0;
+
+        for character in input.chars() {
+            if written == output.len() {
+                break;
+            }
+
+            match encode_character(character) {
+                Some(byte) => {
+                    output[written] = byte;
+                    read += character.len_utf8();
+                    written += 1;
+                }
+                None if read == 0 => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: self.position,
+                    })
+                }
+                None => break,
+            }
+        }
+
+        self.position += read;
+        Ok((read, written))
+    }
+
+    fn finish(&mut self, _output: &mut [u8]) -> Option<usize> {
+        Some(0)
+    }
+}
+
+fn is_valid_byte(byte: u8) -> bool {
+    byte <= 0xa0 || GRAPHICS_RIGHT_TO_UNICODE_MAP[(byte - 0xa0) as usize] != '\0'
+}
+
 fn validate(value: &[u8]) -> Result<(), DecodeError> {
-    for &byte in value {
-        if byte > 0xa0 && GRAPHICS_RIGHT_TO_UNICODE_MAP[(byte - 0xa0) as usize] == '\0' {
-            return Err(DecodeError);
+    for (index, &byte) in value.iter().enumerate() {
+        if !is_valid_byte(byte) {
+            return Err(DecodeError {
+                byte,
+                valid_up_to: index,
+            });
         }
     }
 
     Ok(())
 }
 
+fn encode_character(character: char) -> Option<u8> {
+    if (character as u32) < 0xa0 {
+        return Some(character as u8);
+    }
+
+    GRAPHICS_RIGHT_TO_UNICODE_MAP
+        .iter()
+        .position(|&mapped| mapped == character)
+        .map(|index| index as u8 + 0xa0)
+}
+
 aliases! {
     Alias,
 
@@ -219,3 +485,49 @@ aliases! {
     (Latin3, b"latin3");
     (L3, b"l3");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_text() {
+        let string = String::encode("a\u{126}\u{11d}").unwrap();
+        assert_eq!(AsRef::<[u8]>::as_ref(&string), b"a\xa1\xf8");
+    }
+
+    #[test]
+    fn rejects_unrepresentable_characters() {
+        let error = String::encode("a\u{126}\u{20ac}b").unwrap_err();
+        assert_eq!(error.character(), '\u{20ac}');
+        assert_eq!(error.valid_up_to(), 3);
+    }
+
+    #[test]
+    fn decode_lossy_replaces_unassigned_bytes() {
+        let (text, replacements) =
+            String::decode_lossy(b"a\xa5b\xa5", Replacement::ReplacementCharacter);
+        assert_eq!(text, "a\u{fffd}b\u{fffd}");
+        assert_eq!(replacements, 2);
+
+        let (text, replacements) = String::decode_lossy(b"a\xa5b", Replacement::Character('?'));
+        assert_eq!(text, "a?b");
+        assert_eq!(replacements, 1);
+
+        let (text, replacements) = String::decode_lossy(b"a\xa1b", Replacement::Character('?'));
+        assert_eq!(text, "a\u{126}b");
+        assert_eq!(replacements, 0);
+    }
+
+    #[test]
+    fn converts_characters() {
+        let character = Character::from_byte(0xa1).unwrap();
+        assert_eq!(character.to_char(), '\u{126}');
+        assert_eq!(character.to_byte(), Some(0xa1));
+        assert_eq!(Character::try_from('\u{126}'), Ok(character));
+        assert_eq!(Character::from_byte(0xa5), None);
+
+        let error = Character::try_from('\u{20ac}').unwrap_err();
+        assert_eq!(error.character(), '\u{20ac}');
+    }
+}
@@ -0,0 +1,46 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\u{0401}', '\u{0402}', '\u{0403}', '\u{0404}', '\u{0405}', '\u{0406}', '\u{0407}', '\u{0408}', '\u{0409}', '\u{040a}', '\u{040b}', '\u{040c}', '\u{00ad}', '\u{040e}', '\u{040f}',
+    '\u{0410}', '\u{0411}', '\u{0412}', '\u{0413}', '\u{0414}', '\u{0415}', '\u{0416}', '\u{0417}', '\u{0418}', '\u{0419}', '\u{041a}', '\u{041b}', '\u{041c}', '\u{041d}', '\u{041e}', '\u{041f}',
+    '\u{0420}', '\u{0421}', '\u{0422}', '\u{0423}', '\u{0424}', '\u{0425}', '\u{0426}', '\u{0427}', '\u{0428}', '\u{0429}', '\u{042a}', '\u{042b}', '\u{042c}', '\u{042d}', '\u{042e}', '\u{042f}',
+    '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}', '\u{0437}', '\u{0438}', '\u{0439}', '\u{043a}', '\u{043b}', '\u{043c}', '\u{043d}', '\u{043e}', '\u{043f}',
+    '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}', '\u{0445}', '\u{0446}', '\u{0447}', '\u{0448}', '\u{0449}', '\u{044a}', '\u{044b}', '\u{044c}', '\u{044d}', '\u{044e}', '\u{044f}',
+    '\u{2116}', '\u{0451}', '\u{0452}', '\u{0453}', '\u{0454}', '\u{0455}', '\u{0456}', '\u{0457}', '\u{0458}', '\u{0459}', '\u{045a}', '\u{045b}', '\u{045c}', '\u{00a7}', '\u{045e}', '\u{045f}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 128] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a7}', 0xfd), ('\u{00ad}', 0xad), ('\u{0401}', 0xa1), ('\u{0402}', 0xa2), ('\u{0403}', 0xa3), ('\u{0404}', 0xa4), ('\u{0405}', 0xa5),
+    ('\u{0406}', 0xa6), ('\u{0407}', 0xa7), ('\u{0408}', 0xa8), ('\u{0409}', 0xa9), ('\u{040a}', 0xaa), ('\u{040b}', 0xab), ('\u{040c}', 0xac), ('\u{040e}', 0xae),
+    ('\u{040f}', 0xaf), ('\u{0410}', 0xb0), ('\u{0411}', 0xb1), ('\u{0412}', 0xb2), ('\u{0413}', 0xb3), ('\u{0414}', 0xb4), ('\u{0415}', 0xb5), ('\u{0416}', 0xb6),
+    ('\u{0417}', 0xb7), ('\u{0418}', 0xb8), ('\u{0419}', 0xb9), ('\u{041a}', 0xba), ('\u{041b}', 0xbb), ('\u{041c}', 0xbc), ('\u{041d}', 0xbd), ('\u{041e}', 0xbe),
+    ('\u{041f}', 0xbf), ('\u{0420}', 0xc0), ('\u{0421}', 0xc1), ('\u{0422}', 0xc2), ('\u{0423}', 0xc3), ('\u{0424}', 0xc4), ('\u{0425}', 0xc5), ('\u{0426}', 0xc6),
+    ('\u{0427}', 0xc7), ('\u{0428}', 0xc8), ('\u{0429}', 0xc9), ('\u{042a}', 0xca), ('\u{042b}', 0xcb), ('\u{042c}', 0xcc), ('\u{042d}', 0xcd), ('\u{042e}', 0xce),
+    ('\u{042f}', 0xcf), ('\u{0430}', 0xd0), ('\u{0431}', 0xd1), ('\u{0432}', 0xd2), ('\u{0433}', 0xd3), ('\u{0434}', 0xd4), ('\u{0435}', 0xd5), ('\u{0436}', 0xd6),
+    ('\u{0437}', 0xd7), ('\u{0438}', 0xd8), ('\u{0439}', 0xd9), ('\u{043a}', 0xda), ('\u{043b}', 0xdb), ('\u{043c}', 0xdc), ('\u{043d}', 0xdd), ('\u{043e}', 0xde),
+    ('\u{043f}', 0xdf), ('\u{0440}', 0xe0), ('\u{0441}', 0xe1), ('\u{0442}', 0xe2), ('\u{0443}', 0xe3), ('\u{0444}', 0xe4), ('\u{0445}', 0xe5), ('\u{0446}', 0xe6),
+    ('\u{0447}', 0xe7), ('\u{0448}', 0xe8), ('\u{0449}', 0xe9), ('\u{044a}', 0xea), ('\u{044b}', 0xeb), ('\u{044c}', 0xec), ('\u{044d}', 0xed), ('\u{044e}', 0xee),
+    ('\u{044f}', 0xef), ('\u{0451}', 0xf1), ('\u{0452}', 0xf2), ('\u{0453}', 0xf3), ('\u{0454}', 0xf4), ('\u{0455}', 0xf5), ('\u{0456}', 0xf6), ('\u{0457}', 0xf7),
+    ('\u{0458}', 0xf8), ('\u{0459}', 0xf9), ('\u{045a}', 0xfa), ('\u{045b}', 0xfb), ('\u{045c}', 0xfc), ('\u{045e}', 0xfe), ('\u{045f}', 0xff), ('\u{2116}', 0xf0),
+];
+
+single_byte_charset! {
+    primary_name: "ISO_8859-5:1988",
+    preferred_mime_name: "ISO-8859-5",
+    mib_enum: 8,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsIsoLatinCyrillic, b"csISOLatinCyrillic");
+        (Cyrillic, b"cyrillic");
+        (Iso8859_5, b"ISO-8859-5");
+        (Iso8859_5Alt, b"ISO_8859-5");
+        (IsoIr144, b"iso-ir-144");
+    ],
+}
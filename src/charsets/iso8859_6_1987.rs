@@ -0,0 +1,67 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\0',       '\0',       '\0',       '\u{00a4}', '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\u{060c}', '\u{00ad}', '\0',       '\0',
+    '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\u{061b}', '\0',       '\0',       '\0',       '\u{061f}',
+    '\0',       '\u{0621}', '\u{0622}', '\u{0623}', '\u{0624}', '\u{0625}', '\u{0626}', '\u{0627}', '\u{0628}', '\u{0629}', '\u{062a}', '\u{062b}', '\u{062c}', '\u{062d}', '\u{062e}', '\u{062f}',
+    '\u{0630}', '\u{0631}', '\u{0632}', '\u{0633}', '\u{0634}', '\u{0635}', '\u{0636}', '\u{0637}', '\u{0638}', '\u{0639}', '\u{063a}', '\0',       '\0',       '\0',       '\0',       '\0',
+    '\u{0640}', '\u{0641}', '\u{0642}', '\u{0643}', '\u{0644}', '\u{0645}', '\u{0646}', '\u{0647}', '\u{0648}', '\u{0649}', '\u{064a}', '\u{064b}', '\u{064c}', '\u{064d}', '\u{064e}', '\u{064f}',
+    '\u{0650}', '\u{0651}', '\u{0652}', '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 83] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a4}', 0xa4), ('\u{00ad}', 0xad), ('\u{060c}', 0xac), ('\u{061b}', 0xbb), ('\u{061f}', 0xbf), ('\u{0621}', 0xc1), ('\u{0622}', 0xc2),
+    ('\u{0623}', 0xc3), ('\u{0624}', 0xc4), ('\u{0625}', 0xc5), ('\u{0626}', 0xc6), ('\u{0627}', 0xc7), ('\u{0628}', 0xc8), ('\u{0629}', 0xc9), ('\u{062a}', 0xca),
+    ('\u{062b}', 0xcb), ('\u{062c}', 0xcc), ('\u{062d}', 0xcd), ('\u{062e}', 0xce), ('\u{062f}', 0xcf), ('\u{0630}', 0xd0), ('\u{0631}', 0xd1), ('\u{0632}', 0xd2),
+    ('\u{0633}', 0xd3), ('\u{0634}', 0xd4), ('\u{0635}', 0xd5), ('\u{0636}', 0xd6), ('\u{0637}', 0xd7), ('\u{0638}', 0xd8), ('\u{0639}', 0xd9), ('\u{063a}', 0xda),
+    ('\u{0640}', 0xe0), ('\u{0641}', 0xe1), ('\u{0642}', 0xe2), ('\u{0643}', 0xe3), ('\u{0644}', 0xe4), ('\u{0645}', 0xe5), ('\u{0646}', 0xe6), ('\u{0647}', 0xe7),
+    ('\u{0648}', 0xe8), ('\u{0649}', 0xe9), ('\u{064a}', 0xea), ('\u{064b}', 0xeb), ('\u{064c}', 0xec), ('\u{064d}', 0xed), ('\u{064e}', 0xee), ('\u{064f}', 0xef),
+    ('\u{0650}', 0xf0), ('\u{0651}', 0xf1), ('\u{0652}', 0xf2),
+];
+
+single_byte_charset! {
+    primary_name: "ISO_8859-6:1987",
+    preferred_mime_name: "ISO-8859-6",
+    mib_enum: 9,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (Arabic, b"arabic");
+        (Asmo708, b"ASMO-708");
+        (CsIsoLatinArabic, b"csISOLatinArabic");
+        (Ecma114, b"ECMA-114");
+        (Iso8859_6, b"ISO-8859-6");
+        (Iso8859_6Alt, b"ISO_8859-6");
+        (IsoIr127, b"iso-ir-127");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0xa1), None);
+        let error = Str::decode(b"a\xac\xa1").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\xa1");
+    }
+
+    #[test]
+    fn decodes_and_encodes_arabic() {
+        let str = Str::decode(b"\xc7\xe4\xd9\xd1\xc8\xea\xc9\xac").unwrap();
+        let text = "\u{627}\u{644}\u{639}\u{631}\u{628}\u{64a}\u{629}\u{60c}";
+        assert_eq!(str.to_unicode(), text);
+        assert_eq!(
+            Vec::from(String::encode(text).unwrap()),
+            b"\xc7\xe4\xd9\xd1\xc8\xea\xc9\xac"
+        );
+    }
+}
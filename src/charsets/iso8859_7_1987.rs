@@ -0,0 +1,72 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\u{2018}', '\u{2019}', '\u{00a3}', '\u{20ac}', '\u{20af}', '\u{00a6}', '\u{00a7}', '\u{00a8}', '\u{00a9}', '\u{037a}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\0',       '\u{2015}',
+    '\u{00b0}', '\u{00b1}', '\u{00b2}', '\u{00b3}', '\u{0384}', '\u{0385}', '\u{0386}', '\u{00b7}', '\u{0388}', '\u{0389}', '\u{038a}', '\u{00bb}', '\u{038c}', '\u{00bd}', '\u{038e}', '\u{038f}',
+    '\u{0390}', '\u{0391}', '\u{0392}', '\u{0393}', '\u{0394}', '\u{0395}', '\u{0396}', '\u{0397}', '\u{0398}', '\u{0399}', '\u{039a}', '\u{039b}', '\u{039c}', '\u{039d}', '\u{039e}', '\u{039f}',
+    '\u{03a0}', '\u{03a1}', '\0',       '\u{03a3}', '\u{03a4}', '\u{03a5}', '\u{03a6}', '\u{03a7}', '\u{03a8}', '\u{03a9}', '\u{03aa}', '\u{03ab}', '\u{03ac}', '\u{03ad}', '\u{03ae}', '\u{03af}',
+    '\u{03b0}', '\u{03b1}', '\u{03b2}', '\u{03b3}', '\u{03b4}', '\u{03b5}', '\u{03b6}', '\u{03b7}', '\u{03b8}', '\u{03b9}', '\u{03ba}', '\u{03bb}', '\u{03bc}', '\u{03bd}', '\u{03be}', '\u{03bf}',
+    '\u{03c0}', '\u{03c1}', '\u{03c2}', '\u{03c3}', '\u{03c4}', '\u{03c5}', '\u{03c6}', '\u{03c7}', '\u{03c8}', '\u{03c9}', '\u{03ca}', '\u{03cb}', '\u{03cc}', '\u{03cd}', '\u{03ce}', '\0',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 125] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a3}', 0xa3), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a8}', 0xa8), ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab), ('\u{00ac}', 0xac),
+    ('\u{00ad}', 0xad), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1), ('\u{00b2}', 0xb2), ('\u{00b3}', 0xb3), ('\u{00b7}', 0xb7), ('\u{00bb}', 0xbb), ('\u{00bd}', 0xbd),
+    ('\u{037a}', 0xaa), ('\u{0384}', 0xb4), ('\u{0385}', 0xb5), ('\u{0386}', 0xb6), ('\u{0388}', 0xb8), ('\u{0389}', 0xb9), ('\u{038a}', 0xba), ('\u{038c}', 0xbc),
+    ('\u{038e}', 0xbe), ('\u{038f}', 0xbf), ('\u{0390}', 0xc0), ('\u{0391}', 0xc1), ('\u{0392}', 0xc2), ('\u{0393}', 0xc3), ('\u{0394}', 0xc4), ('\u{0395}', 0xc5),
+    ('\u{0396}', 0xc6), ('\u{0397}', 0xc7), ('\u{0398}', 0xc8), ('\u{0399}', 0xc9), ('\u{039a}', 0xca), ('\u{039b}', 0xcb), ('\u{039c}', 0xcc), ('\u{039d}', 0xcd),
+    ('\u{039e}', 0xce), ('\u{039f}', 0xcf), ('\u{03a0}', 0xd0), ('\u{03a1}', 0xd1), ('\u{03a3}', 0xd3), ('\u{03a4}', 0xd4), ('\u{03a5}', 0xd5), ('\u{03a6}', 0xd6),
+    ('\u{03a7}', 0xd7), ('\u{03a8}', 0xd8), ('\u{03a9}', 0xd9), ('\u{03aa}', 0xda), ('\u{03ab}', 0xdb), ('\u{03ac}', 0xdc), ('\u{03ad}', 0xdd), ('\u{03ae}', 0xde),
+    ('\u{03af}', 0xdf), ('\u{03b0}', 0xe0), ('\u{03b1}', 0xe1), ('\u{03b2}', 0xe2), ('\u{03b3}', 0xe3), ('\u{03b4}', 0xe4), ('\u{03b5}', 0xe5), ('\u{03b6}', 0xe6),
+    ('\u{03b7}', 0xe7), ('\u{03b8}', 0xe8), ('\u{03b9}', 0xe9), ('\u{03ba}', 0xea), ('\u{03bb}', 0xeb), ('\u{03bc}', 0xec), ('\u{03bd}', 0xed), ('\u{03be}', 0xee),
+    ('\u{03bf}', 0xef), ('\u{03c0}', 0xf0), ('\u{03c1}', 0xf1), ('\u{03c2}', 0xf2), ('\u{03c3}', 0xf3), ('\u{03c4}', 0xf4), ('\u{03c5}', 0xf5), ('\u{03c6}', 0xf6),
+    ('\u{03c7}', 0xf7), ('\u{03c8}', 0xf8), ('\u{03c9}', 0xf9), ('\u{03ca}', 0xfa), ('\u{03cb}', 0xfb), ('\u{03cc}', 0xfc), ('\u{03cd}', 0xfd), ('\u{03ce}', 0xfe),
+    ('\u{2015}', 0xaf), ('\u{2018}', 0xa1), ('\u{2019}', 0xa2), ('\u{20ac}', 0xa4), ('\u{20af}', 0xa5),
+];
+
+single_byte_charset! {
+    primary_name: "ISO_8859-7:1987",
+    preferred_mime_name: "ISO-8859-7",
+    mib_enum: 10,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsIsoLatinGreek, b"csISOLatinGreek");
+        (Ecma118, b"ECMA-118");
+        (Elot928, b"ELOT_928");
+        (Greek, b"greek");
+        (Greek8, b"greek8");
+        (Iso8859_7, b"ISO-8859-7");
+        (Iso8859_7Alt, b"ISO_8859-7");
+        (IsoIr126, b"iso-ir-126");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0xae), None);
+        let error = Str::decode(b"a\xae").unwrap_err();
+        assert_eq!(error.valid_up_to(), 1);
+        assert_eq!(error.invalid_bytes(), b"\xae");
+    }
+
+    #[test]
+    fn decodes_and_encodes_euro_sign() {
+        let str = Str::decode(b"\xa4\xe1").unwrap();
+        assert_eq!(str.to_unicode(), "\u{20ac}\u{3b1}");
+        assert_eq!(
+            Vec::from(String::encode("\u{20ac}\u{3b1}").unwrap()),
+            b"\xa4\xe1"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0080}', '\u{0081}', '\u{0082}', '\u{0083}', '\u{0084}', '\u{0085}', '\u{0086}', '\u{0087}', '\u{0088}', '\u{0089}', '\u{008a}', '\u{008b}', '\u{008c}', '\u{008d}', '\u{008e}', '\u{008f}',
+    '\u{0090}', '\u{0091}', '\u{0092}', '\u{0093}', '\u{0094}', '\u{0095}', '\u{0096}', '\u{0097}', '\u{0098}', '\u{0099}', '\u{009a}', '\u{009b}', '\u{009c}', '\u{009d}', '\u{009e}', '\u{009f}',
+    '\u{00a0}', '\0',       '\u{00a2}', '\u{00a3}', '\u{00a4}', '\u{00a5}', '\u{00a6}', '\u{00a7}', '\u{00a8}', '\u{00a9}', '\u{00d7}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{00af}',
+    '\u{00b0}', '\u{00b1}', '\u{00b2}', '\u{00b3}', '\u{00b4}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{00b8}', '\u{00b9}', '\u{00f7}', '\u{00bb}', '\u{00bc}', '\u{00bd}', '\u{00be}', '\0',
+    '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',
+    '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\u{2017}',
+    '\u{05d0}', '\u{05d1}', '\u{05d2}', '\u{05d3}', '\u{05d4}', '\u{05d5}', '\u{05d6}', '\u{05d7}', '\u{05d8}', '\u{05d9}', '\u{05da}', '\u{05db}', '\u{05dc}', '\u{05dd}', '\u{05de}', '\u{05df}',
+    '\u{05e0}', '\u{05e1}', '\u{05e2}', '\u{05e3}', '\u{05e4}', '\u{05e5}', '\u{05e6}', '\u{05e7}', '\u{05e8}', '\u{05e9}', '\u{05ea}', '\0',       '\0',       '\u{200e}', '\u{200f}', '\0',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 92] = [
+    ('\u{0080}', 0x80), ('\u{0081}', 0x81), ('\u{0082}', 0x82), ('\u{0083}', 0x83), ('\u{0084}', 0x84), ('\u{0085}', 0x85), ('\u{0086}', 0x86), ('\u{0087}', 0x87),
+    ('\u{0088}', 0x88), ('\u{0089}', 0x89), ('\u{008a}', 0x8a), ('\u{008b}', 0x8b), ('\u{008c}', 0x8c), ('\u{008d}', 0x8d), ('\u{008e}', 0x8e), ('\u{008f}', 0x8f),
+    ('\u{0090}', 0x90), ('\u{0091}', 0x91), ('\u{0092}', 0x92), ('\u{0093}', 0x93), ('\u{0094}', 0x94), ('\u{0095}', 0x95), ('\u{0096}', 0x96), ('\u{0097}', 0x97),
+    ('\u{0098}', 0x98), ('\u{0099}', 0x99), ('\u{009a}', 0x9a), ('\u{009b}', 0x9b), ('\u{009c}', 0x9c), ('\u{009d}', 0x9d), ('\u{009e}', 0x9e), ('\u{009f}', 0x9f),
+    ('\u{00a0}', 0xa0), ('\u{00a2}', 0xa2), ('\u{00a3}', 0xa3), ('\u{00a4}', 0xa4), ('\u{00a5}', 0xa5), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a8}', 0xa8),
+    ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab), ('\u{00ac}', 0xac), ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00af}', 0xaf), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1),
+    ('\u{00b2}', 0xb2), ('\u{00b3}', 0xb3), ('\u{00b4}', 0xb4), ('\u{00b5}', 0xb5), ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7), ('\u{00b8}', 0xb8), ('\u{00b9}', 0xb9),
+    ('\u{00bb}', 0xbb), ('\u{00bc}', 0xbc), ('\u{00bd}', 0xbd), ('\u{00be}', 0xbe), ('\u{00d7}', 0xaa), ('\u{00f7}', 0xba), ('\u{05d0}', 0xe0), ('\u{05d1}', 0xe1),
+    ('\u{05d2}', 0xe2), ('\u{05d3}', 0xe3), ('\u{05d4}', 0xe4), ('\u{05d5}', 0xe5), ('\u{05d6}', 0xe6), ('\u{05d7}', 0xe7), ('\u{05d8}', 0xe8), ('\u{05d9}', 0xe9),
+    ('\u{05da}', 0xea), ('\u{05db}', 0xeb), ('\u{05dc}', 0xec), ('\u{05dd}', 0xed), ('\u{05de}', 0xee), ('\u{05df}', 0xef), ('\u{05e0}', 0xf0), ('\u{05e1}', 0xf1),
+    ('\u{05e2}', 0xf2), ('\u{05e3}', 0xf3), ('\u{05e4}', 0xf4), ('\u{05e5}', 0xf5), ('\u{05e6}', 0xf6), ('\u{05e7}', 0xf7), ('\u{05e8}', 0xf8), ('\u{05e9}', 0xf9),
+    ('\u{05ea}', 0xfa), ('\u{200e}', 0xfd), ('\u{200f}', 0xfe), ('\u{2017}', 0xdf),
+];
+
+single_byte_charset! {
+    primary_name: "ISO_8859-8:1988",
+    preferred_mime_name: "ISO-8859-8",
+    mib_enum: 11,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsIsoLatinHebrew, b"csISOLatinHebrew");
+        (Hebrew, b"hebrew");
+        (Iso8859_8, b"ISO-8859-8");
+        (Iso8859_8Alt, b"ISO_8859-8");
+        (IsoIr138, b"iso-ir-138");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0xa1), None);
+        let error = Str::decode(b"\xaa\xa1").unwrap_err();
+        assert_eq!(error.valid_up_to(), 1);
+        assert_eq!(error.invalid_bytes(), b"\xa1");
+    }
+
+    #[test]
+    fn decodes_and_encodes_hebrew() {
+        let str = Str::decode(b"\xf9\xec\xe5\xed\xaa").unwrap();
+        assert_eq!(str.to_unicode(), "\u{5e9}\u{5dc}\u{5d5}\u{5dd}\u{d7}");
+        let string = String::encode("\u{5e9}\u{5dc}\u{5d5}\u{5dd}\u{d7}").unwrap();
+        assert_eq!(Vec::from(string), b"\xf9\xec\xe5\xed\xaa");
+    }
+}
@@ -0,0 +1,642 @@
+use std::borrow::{Borrow, Cow, ToOwned};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+use std::ops::Deref;
+use std::str;
+use std::string::String as StdString;
+
+use crate::charset::private::Sealed;
+use crate::charset::{
+    Character as CharacterTrait, Charset as CharsetTrait, DecodeError as DecodeErrorTrait,
+    Decoder as DecoderTrait, EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
+    Str as StrTrait, String as StringTrait,
+};
+use crate::charsets::sequence::{self, Sequence, SequenceDecoder, SequenceError};
+use crate::charsets::tables::jis0208;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Charset;
+
+impl CharsetTrait for Charset {
+    type Alias = Alias;
+    type Character = Character;
+    type DecodeError = DecodeError;
+    type Decoder = Decoder;
+    type EncodeError = EncodeError;
+    type Encoder = Encoder;
+    type Str = Str;
+    type String = String;
+
+    const IS_ASCII_COMPATIBLE: bool = false;
+    const MIB_ENUM: u16 = 39;
+    const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> =
+        Some(unsafe { UsAsciiStr::from_bytes_unchecked(b"ISO-2022-JP") });
+    const PRIMARY_NAME: &'static UsAsciiStr =
+        unsafe { UsAsciiStr::from_bytes_unchecked(b"ISO-2022-JP") };
+}
+
+impl Sealed for Charset {}
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Character(char);
+
+impl CharacterTrait for Character {
+    fn from_byte(value: u8) -> Option<Self> {
+        match next(&[value], &mut Mode::Ascii)? {
+            Sequence::Character(character, _) => Some(Character(character)),
+            _ => None,
+        }
+    }
+
+    fn to_byte(self) -> Option<u8> {
+        match encode_character(self.0)? {
+            (Mode::Ascii, bytes, 1) => Some(bytes[0]),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        self.0
+    }
+}
+
+impl Debug for Character {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_char(self.0)
+    }
+}
+
+impl Display for Character {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_char(self.0)
+    }
+}
+
+impl TryFrom<char> for Character {
+    type Error = EncodeError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match encode_character(value) {
+            Some(_) => Ok(Character(value)),
+            None => Err(EncodeError {
+                character: value,
+                valid_up_to: 0,
+            }),
+        }
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Str([u8]);
+
+impl AsRef<[u8]> for Str {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for Str {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        for character in self.chars() {
+            Debug::fmt(&character, formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Str {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        Debug::fmt(self, formatter)
+    }
+}
+
+impl Sealed for Str {}
+
+impl StrTrait for Str {
+    type Character = Character;
+    type DecodeError = DecodeError;
+    type State = State;
+    type String = String;
+
+    fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
+        validate(value).map_err(DecodeError)?;
+        Ok(unsafe { Self::decode_unchecked(value) })
+    }
+
+    unsafe fn decode_unchecked(value: &[u8]) -> &Self {
+        &*(value as *const [u8] as *const Str)
+    }
+
+    fn next_character(
+        &self,
+        index: usize,
+        state: &mut Self::State,
+    ) -> Option<(Self::Character, usize)> {
+        let mut position = index;
+
+        loop {
+            match next(&self.0[position..], &mut state.0)? {
+                Sequence::Character(character, length) => {
+                    return Some((Character(character), position + length - index));
+                }
+                Sequence::Escape(length) => position += length,
+                _ => unreachable!("string contains invalid ISO-2022-JP"),
+            }
+        }
+    }
+
+    fn to_unicode(&self) -> Cow<'_, str> {
+        if !self.0.contains(&ESCAPE) {
+            // Unsafe justification: without any escape sequences, the whole string is in ASCII,
+            // which is a subset of UTF-8.
+            return Cow::Borrowed(unsafe { str::from_utf8_unchecked(&self.0) });
+        }
+
+        Cow::Owned(self.chars().map(Character::to_char).collect())
+    }
+}
+
+impl ToOwned for Str {
+    type Owned = String;
+
+    fn to_owned(&self) -> Self::Owned {
+        String(self.0.to_owned())
+    }
+}
+
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct String(Vec<u8>);
+
+impl AsRef<[u8]> for String {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsRef<Str> for String {
+    fn as_ref(&self) -> &Str {
+        &self
+    }
+}
+
+impl Borrow<Str> for String {
+    fn borrow(&self) -> &Str {
+        &self
+    }
+}
+
+impl Debug for String {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        Debug::fmt(&**self, formatter)
+    }
+}
+
+impl Deref for String {
+    type Target = <Self as StringTrait>::Str;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { Str::decode_unchecked(&*self.0) }
+    }
+}
+
+impl Display for String {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        Debug::fmt(self, formatter)
+    }
+}
+
+impl<'str> From<&'str Str> for String {
+    fn from(value: &'str Str) -> Self {
+        value.to_owned()
+    }
+}
+
+impl From<String> for Vec<u8> {
+    fn from(value: String) -> Self {
+        value.0
+    }
+}
+
+impl Sealed for String {}
+
+impl StringTrait for String {
+    type DecodeError = DecodeError;
+    type EncodeError = EncodeError;
+    type Str = Str;
+
+    fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
+        if let Err(error) = validate(&value) {
+            return Err((value, DecodeError(error)));
+        }
+
+        Ok(unsafe { Self::decode_unchecked(value) })
+    }
+
+    fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+        if validate(value).is_ok() {
+            return (unsafe { Str::decode_unchecked(value) }.to_unicode(), 0);
+        }
+
+        let mut mode = Mode::Ascii;
+        let next = |value: &[u8]| next(value, &mut mode);
+        let (text, replacements) = sequence::decode_lossy(value, next, replacement);
+        (Cow::Owned(text), replacements)
+    }
+
+    unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
+        String(value)
+    }
+
+    fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+        let mut bytes = Vec::with_capacity(value.len());
+        let mut mode = Mode::Ascii;
+
+        for (index, character) in value.char_indices() {
+            let (character_mode, encoded, length) = match encode_character(character) {
+                Some(encoded) => encoded,
+                None => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: index,
+                    })
+                }
+            };
+
+            if character_mode != mode {
+                bytes.extend_from_slice(character_mode.escape_sequence());
+                mode = character_mode;
+            }
+
+            bytes.extend_from_slice(&encoded[..length]);
+        }
+
+        if mode != Mode::Ascii {
+            bytes.extend_from_slice(Mode::Ascii.escape_sequence());
+        }
+
+        Ok(String(bytes))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DecodeError(SequenceError);
+
+impl DecodeErrorTrait for DecodeError {
+    fn error_len(&self) -> Option<usize> {
+        self.0.error_len()
+    }
+
+    fn invalid_bytes(&self) -> &[u8] {
+        self.0.invalid_bytes()
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.0.valid_up_to()
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "{} ISO-2022-JP sequence {:02x?} at index {}",
+            self.0.kind(),
+            self.invalid_bytes(),
+            self.valid_up_to()
+        )
+    }
+}
+
+impl Error for DecodeError {}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EncodeError {
+    character: char,
+    valid_up_to: usize,
+}
+
+impl EncodeErrorTrait for EncodeError {
+    fn character(&self) -> char {
+        self.character
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "character {:?} at index {} cannot be encoded as ISO-2022-JP",
+            self.character, self.valid_up_to
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Decoder {
+    decoder: SequenceDecoder,
+    mode: Mode,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder {
+            decoder: SequenceDecoder::default(),
+            mode: Mode::Ascii,
+        }
+    }
+}
+
+impl DecoderTrait for Decoder {
+    type DecodeError = DecodeError;
+
+    fn decode_to_bytes(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Self::DecodeError> {
+        let mode = &mut self.mode;
+        self.decoder
+            .decode(
+                input,
+                |value| next(value, mode),
+                |bytes, _| output.extend_from_slice(bytes),
+            )
+            .map_err(DecodeError)
+    }
+
+    fn decode_to_unicode(
+        &mut self,
+        input: &[u8],
+        output: &mut StdString,
+    ) -> Result<(), Self::DecodeError> {
+        let mode = &mut self.mode;
+        self.decoder
+            .decode(
+                input,
+                |value| next(value, mode),
+                |_, characters| output.extend(characters),
+            )
+            .map_err(DecodeError)
+    }
+
+    /// Input that ends in a mode other than ASCII is accepted, since plenty of encoders in the wild
+    /// leave out the final switch back.
+    fn finish(self) -> Result<(), Self::DecodeError> {
+        self.decoder.finish().map_err(DecodeError)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Encoder {
+    mode: Mode,
+    position: usize,
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Encoder {
+            mode: Mode::Ascii,
+            position: 0,
+        }
+    }
+}
+
+impl EncoderTrait for Encoder {
+    type EncodeError = EncodeError;
+
+    fn encode(
+        &mut self,
+        input: &str,
+        output: &mut [u8],
+    ) -> Result<(usize, usize), Self::EncodeError> {
+        let mut read = 0;
+        let mut written = 0;
+
+        for character in input.chars() {
+            let (mode, encoded, length) = match encode_character(character) {
+                Some(encoded) => encoded,
+                None if read == 0 => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: self.position,
+                    })
+                }
+                None => break,
+            };
+            let escape_sequence = if mode == self.mode {
+                &[]
+            } else {
+                mode.escape_sequence()
+            };
+
+            if output.len() - written < escape_sequence.len() + length {
+                break;
+            }
+
+            output[written..written + escape_sequence.len()].copy_from_slice(escape_sequence);
+            written += escape_sequence.len();
+            output[written..written + length].copy_from_slice(&encoded[..length]);
+            written += length;
+            read += character.len_utf8();
+            self.mode = mode;
+        }
+
+        self.position += read;
+        Ok((read, written))
+    }
+
+    /// Switches back to ASCII, which RFC 1468 requires at the end of the text.
+    fn finish(&mut self, output: &mut [u8]) -> Option<usize> {
+        if self.mode == Mode::Ascii {
+            return Some(0);
+        }
+
+        let escape_sequence = Mode::Ascii.escape_sequence();
+        let output = output.get_mut(..escape_sequence.len())?;
+        output.copy_from_slice(escape_sequence);
+        self.mode = Mode::Ascii;
+        Some(escape_sequence.len())
+    }
+}
+
+const ESCAPE: u8 = 0x1b;
+
+/// The mode that the characters of a [`Str`] are decoded in, carried from one character to the
+/// next while iterating over them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct State(Mode);
+
+impl Default for State {
+    fn default() -> Self {
+        State(Mode::Ascii)
+    }
+}
+
+/// The character set that the bytes following an escape sequence are decoded with.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Mode {
+    Ascii,
+
+    /// JIS X 0201 Roman, which is ASCII with the yen sign and overline in place of the backslash
+    /// and tilde.
+    Roman,
+
+    /// JIS X 0208, where every character is a pair of bytes between 0x21 and 0x7e.
+    JisX0208,
+}
+
+impl Mode {
+    fn escape_sequence(self) -> &'static [u8] {
+        match self {
+            Mode::Ascii => b"\x1b(B",
+            Mode::Roman => b"\x1b(J",
+            Mode::JisX0208 => b"\x1b$B",
+        }
+    }
+}
+
+/// Decodes the sequence at the start of `value` in `mode`, switching `mode` if it is an escape
+/// sequence.
+fn next(value: &[u8], mode: &mut Mode) -> Option<Sequence> {
+    let first = *value.first()?;
+
+    let sequence = match (*mode, first) {
+        (_, ESCAPE) => next_escape_sequence(value, mode),
+        // RFC 1468 does not allow the shifts of other ISO-2022 encodings.
+        (_, 0x0e) | (_, 0x0f) => Sequence::Invalid(1),
+        // Control characters are the same in every mode, so that line breaks are recognized even
+        // when the text does not switch back to ASCII before them.
+        (_, 0x00..=0x1f) => Sequence::Character(first as char, 1),
+        (Mode::Roman, 0x5c) => Sequence::Character('\u{a5}', 1),
+        (Mode::Roman, 0x7e) => Sequence::Character('\u{203e}', 1),
+        (Mode::Ascii, 0x20..=0x7f) | (Mode::Roman, 0x20..=0x7f) => {
+            Sequence::Character(first as char, 1)
+        }
+        (Mode::JisX0208, 0x21..=0x7e) => match value.get(1) {
+            Some(&second @ 0x21..=0x7e) => {
+                let pointer = usize::from(first - 0x21) * 94 + usize::from(second - 0x21);
+
+                match jis0208::decode(pointer) {
+                    Some(character) => Sequence::Character(character, 2),
+                    None => Sequence::Invalid(2),
+                }
+            }
+            Some(_) => Sequence::Invalid(1),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+fn next_escape_sequence(value: &[u8], mode: &mut Mode) -> Sequence {
+    *mode = match value {
+        [_, b'(', b'B', ..] => Mode::Ascii,
+        [_, b'(', b'J', ..] => Mode::Roman,
+        // JIS C 6226-1978 is decoded as its successor JIS X 0208.
+        [_, b'$', b'@', ..] | [_, b'$', b'B', ..] => Mode::JisX0208,
+        [_] | [_, b'('] | [_, b'$'] => return Sequence::Incomplete,
+        _ => return Sequence::Invalid(1),
+    };
+
+    Sequence::Escape(3)
+}
+
+fn validate(value: &[u8]) -> Result<(), SequenceError> {
+    let mut mode = Mode::Ascii;
+    sequence::validate(value, |value| next(value, &mut mode))
+}
+
+/// Returns the mode that `character` is encoded in along with its bytes and their length.
+fn encode_character(character: char) -> Option<(Mode, [u8; 2], usize)> {
+    match character {
+        // The escape byte on its own would be mistaken for the start of an escape sequence, and
+        // RFC 1468 does not allow the shifts.
+        '\x0e' | '\x0f' | '\x1b' => None,
+        '\0'..='\x7f' => Some((Mode::Ascii, [character as u8, 0], 1)),
+        '\u{a5}' => Some((Mode::Roman, [0x5c, 0], 1)),
+        '\u{203e}' => Some((Mode::Roman, [0x7e, 0], 1)),
+        _ => {
+            let pointer = jis0208::encode(character)?;
+            let bytes = [(pointer / 94) as u8 + 0x21, (pointer % 94) as u8 + 0x21];
+            Some((Mode::JisX0208, bytes, 2))
+        }
+    }
+}
+
+aliases! {
+    Alias,
+
+    (CsIso2022Jp, b"csISO2022JP");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_across_escape_sequences() {
+        let string = Str::decode(b"a\x1b$BF|K\\\x1b(Bb").unwrap();
+        assert_eq!(string.to_unicode(), "a\u{65e5}\u{672c}b");
+
+        let indices: Vec<_> = string.char_indices().map(|(index, _)| index).collect();
+        assert_eq!(indices, [0, 1, 6, 8]);
+    }
+
+    #[test]
+    fn roman_maps_yen_sign_and_overline() {
+        let string = Str::decode(b"\x1b(J\\~\x1b(B\\~").unwrap();
+        assert_eq!(string.to_unicode(), "\u{a5}\u{203e}\\~");
+
+        let string = String::encode("\u{a5}\u{203e}\\~").unwrap();
+        assert_eq!(Vec::from(string), b"\x1b(J\\~\x1b(B\\~");
+    }
+
+    #[test]
+    fn encoder_finish_returns_to_ascii() {
+        let mut encoder = Encoder::default();
+        let mut output = [0; 16];
+        let (read, written) = encoder.encode("a\u{65e5}", &mut output).unwrap();
+        assert_eq!((read, written), (4, 6));
+        assert_eq!(encoder.finish(&mut output[written..]), Some(3));
+        assert_eq!(&output[..9], b"a\x1b$BF|\x1b(B");
+        assert_eq!(encoder.finish(&mut output), Some(0));
+    }
+
+    #[test]
+    fn rejects_shifts() {
+        let error = Str::decode(b"a\x0e").unwrap_err();
+        assert_eq!(error.valid_up_to(), 1);
+        assert_eq!(error.invalid_bytes(), b"\x0e");
+        assert!(Str::decode(b"\x1b$B\x0f").is_err());
+
+        let error = String::encode("a\x0f").unwrap_err();
+        assert_eq!(error.character(), '\x0f');
+        assert_eq!(error.valid_up_to(), 1);
+    }
+
+    #[test]
+    fn valid_up_to_counts_escape_sequences() {
+        let error = Str::decode(b"\x1b$BF|\x80").unwrap_err();
+        assert_eq!(error.valid_up_to(), 5);
+        assert_eq!(error.invalid_bytes(), b"\x80");
+
+        let mut decoder = Decoder::default();
+        let mut output = StdString::new();
+        decoder.decode_to_unicode(b"a\x1b$", &mut output).unwrap();
+        let error = decoder
+            .decode_to_unicode(b"BF|\x1b(Z", &mut output)
+            .unwrap_err();
+        assert_eq!(output, "a\u{65e5}");
+        assert_eq!(error.valid_up_to(), 6);
+    }
+}
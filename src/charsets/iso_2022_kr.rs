@@ -0,0 +1,660 @@
+use std::borrow::{Borrow, Cow, ToOwned};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+use std::ops::Deref;
+use std::str;
+use std::string::String as StdString;
+
+use crate::charset::private::Sealed;
+use crate::charset::{
+    Character as CharacterTrait, Charset as CharsetTrait, DecodeError as DecodeErrorTrait,
+    Decoder as DecoderTrait, EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
+    Str as StrTrait, String as StringTrait,
+};
+use crate::charsets::sequence::{self, Sequence, SequenceDecoder, SequenceError};
+use crate::charsets::tables::uhc;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Charset;
+
+impl CharsetTrait for Charset {
+    type Alias = Alias;
+    type Character = Character;
+    type DecodeError = DecodeError;
+    type Decoder = Decoder;
+    type EncodeError = EncodeError;
+    type Encoder = Encoder;
+    type Str = Str;
+    type String = String;
+
+    const IS_ASCII_COMPATIBLE: bool = false;
+    const MIB_ENUM: u16 = 37;
+    const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> =
+        Some(unsafe { UsAsciiStr::from_bytes_unchecked(b"ISO-2022-KR") });
+    const PRIMARY_NAME: &'static UsAsciiStr =
+        unsafe { UsAsciiStr::from_bytes_unchecked(b"ISO-2022-KR") };
+}
+
+impl Sealed for Charset {}
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Character(char);
+
+impl CharacterTrait for Character {
+    fn from_byte(value: u8) -> Option<Self> {
+        match next(&[value], &mut State::default())? {
+            Sequence::Character(character, _) => Some(Character(character)),
+            _ => None,
+        }
+    }
+
+    fn to_byte(self) -> Option<u8> {
+        match encode_character(self.0)? {
+            (Mode::Ascii, bytes, 1) => Some(bytes[0]),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        self.0
+    }
+}
+
+impl Debug for Character {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_char(self.0)
+    }
+}
+
+impl Display for Character {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_char(self.0)
+    }
+}
+
+impl TryFrom<char> for Character {
+    type Error = EncodeError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match encode_character(value) {
+            Some(_) => Ok(Character(value)),
+            None => Err(EncodeError {
+                character: value,
+                valid_up_to: 0,
+            }),
+        }
+    }
+}
+
+#[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Str([u8]);
+
+impl AsRef<[u8]> for Str {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for Str {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        for character in self.chars() {
+            Debug::fmt(&character, formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Str {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        Debug::fmt(self, formatter)
+    }
+}
+
+impl Sealed for Str {}
+
+impl StrTrait for Str {
+    type Character = Character;
+    type DecodeError = DecodeError;
+    type State = State;
+    type String = String;
+
+    fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
+        validate(value).map_err(DecodeError)?;
+        Ok(unsafe { Self::decode_unchecked(value) })
+    }
+
+    unsafe fn decode_unchecked(value: &[u8]) -> &Self {
+        &*(value as *const [u8] as *const Str)
+    }
+
+    fn next_character(
+        &self,
+        index: usize,
+        state: &mut Self::State,
+    ) -> Option<(Self::Character, usize)> {
+        let mut position = index;
+
+        loop {
+            match next(&self.0[position..], state)? {
+                Sequence::Character(character, length) => {
+                    return Some((Character(character), position + length - index));
+                }
+                Sequence::Escape(length) => position += length,
+                _ => unreachable!("string contains invalid ISO-2022-KR"),
+            }
+        }
+    }
+
+    fn to_unicode(&self) -> Cow<'_, str> {
+        if !self
+            .0
+            .iter()
+            .any(|&byte| byte == ESCAPE || byte == SHIFT_IN || byte == SHIFT_OUT)
+        {
+            // Unsafe justification: without any escape sequences or shifts, the whole string is in
+            // ASCII, which is a subset of UTF-8.
+            return Cow::Borrowed(unsafe { str::from_utf8_unchecked(&self.0) });
+        }
+
+        Cow::Owned(self.chars().map(Character::to_char).collect())
+    }
+}
+
+impl ToOwned for Str {
+    type Owned = String;
+
+    fn to_owned(&self) -> Self::Owned {
+        String(self.0.to_owned())
+    }
+}
+
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct String(Vec<u8>);
+
+impl AsRef<[u8]> for String {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl AsRef<Str> for String {
+    fn as_ref(&self) -> &Str {
+        &self
+    }
+}
+
+impl Borrow<Str> for String {
+    fn borrow(&self) -> &Str {
+        &self
+    }
+}
+
+impl Debug for String {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        Debug::fmt(&**self, formatter)
+    }
+}
+
+impl Deref for String {
+    type Target = <Self as StringTrait>::Str;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { Str::decode_unchecked(&*self.0) }
+    }
+}
+
+impl Display for String {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        Debug::fmt(self, formatter)
+    }
+}
+
+impl<'str> From<&'str Str> for String {
+    fn from(value: &'str Str) -> Self {
+        value.to_owned()
+    }
+}
+
+impl From<String> for Vec<u8> {
+    fn from(value: String) -> Self {
+        value.0
+    }
+}
+
+impl Sealed for String {}
+
+impl StringTrait for String {
+    type DecodeError = DecodeError;
+    type EncodeError = EncodeError;
+    type Str = Str;
+
+    fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
+        if let Err(error) = validate(&value) {
+            return Err((value, DecodeError(error)));
+        }
+
+        Ok(unsafe { Self::decode_unchecked(value) })
+    }
+
+    fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+        if validate(value).is_ok() {
+            return (unsafe { Str::decode_unchecked(value) }.to_unicode(), 0);
+        }
+
+        let mut state = State::default();
+        let next = |value: &[u8]| next(value, &mut state);
+        let (text, replacements) = sequence::decode_lossy(value, next, replacement);
+        (Cow::Owned(text), replacements)
+    }
+
+    unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
+        String(value)
+    }
+
+    /// Any non-empty text starts with the designation, whether or not it is shifted out.
+    fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+        let mut bytes = Vec::with_capacity(DESIGNATION.len() + value.len());
+        let mut mode = Mode::Ascii;
+
+        if !value.is_empty() {
+            bytes.extend_from_slice(DESIGNATION);
+        }
+
+        for (index, character) in value.char_indices() {
+            let (character_mode, encoded, length) = match encode_character(character) {
+                Some(encoded) => encoded,
+                None => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: index,
+                    })
+                }
+            };
+
+            if character_mode != mode {
+                bytes.push(character_mode.shift());
+                mode = character_mode;
+            }
+
+            bytes.extend_from_slice(&encoded[..length]);
+        }
+
+        if mode != Mode::Ascii {
+            bytes.push(Mode::Ascii.shift());
+        }
+
+        Ok(String(bytes))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DecodeError(SequenceError);
+
+impl DecodeErrorTrait for DecodeError {
+    fn error_len(&self) -> Option<usize> {
+        self.0.error_len()
+    }
+
+    fn invalid_bytes(&self) -> &[u8] {
+        self.0.invalid_bytes()
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.0.valid_up_to()
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "{} ISO-2022-KR sequence {:02x?} at index {}",
+            self.0.kind(),
+            self.invalid_bytes(),
+            self.valid_up_to()
+        )
+    }
+}
+
+impl Error for DecodeError {}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EncodeError {
+    character: char,
+    valid_up_to: usize,
+}
+
+impl EncodeErrorTrait for EncodeError {
+    fn character(&self) -> char {
+        self.character
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "character {:?} at index {} cannot be encoded as ISO-2022-KR",
+            self.character, self.valid_up_to
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Decoder {
+    decoder: SequenceDecoder,
+    state: State,
+}
+
+impl DecoderTrait for Decoder {
+    type DecodeError = DecodeError;
+
+    fn decode_to_bytes(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Self::DecodeError> {
+        let state = &mut self.state;
+        self.decoder
+            .decode(
+                input,
+                |value| next(value, state),
+                |bytes, _| output.extend_from_slice(bytes),
+            )
+            .map_err(DecodeError)
+    }
+
+    fn decode_to_unicode(
+        &mut self,
+        input: &[u8],
+        output: &mut StdString,
+    ) -> Result<(), Self::DecodeError> {
+        let state = &mut self.state;
+        self.decoder
+            .decode(
+                input,
+                |value| next(value, state),
+                |_, characters| output.extend(characters),
+            )
+            .map_err(DecodeError)
+    }
+
+    /// Input that ends in a mode other than ASCII is accepted, since plenty of encoders in the wild
+    /// leave out the final shift back in.
+    fn finish(self) -> Result<(), Self::DecodeError> {
+        self.decoder.finish().map_err(DecodeError)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Encoder {
+    designated: bool,
+    mode: Mode,
+    position: usize,
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Encoder {
+            designated: false,
+            mode: Mode::Ascii,
+            position: 0,
+        }
+    }
+}
+
+impl EncoderTrait for Encoder {
+    type EncodeError = EncodeError;
+
+    /// Any non-empty text starts with the designation, whether or not it is shifted out.
+    fn encode(
+        &mut self,
+        input: &str,
+        output: &mut [u8],
+    ) -> Result<(usize, usize), Self::EncodeError> {
+        let mut read = 0;
+        let mut written = 0;
+
+        for character in input.chars() {
+            let (mode, encoded, length) = match encode_character(character) {
+                Some(encoded) => encoded,
+                None if read == 0 => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: self.position,
+                    })
+                }
+                None => break,
+            };
+            let designation: &[u8] = if self.designated { &[] } else { DESIGNATION };
+            let shift_length = if mode == self.mode { 0 } else { 1 };
+
+            if output.len() - written < designation.len() + shift_length + length {
+                break;
+            }
+
+            output[written..written + designation.len()].copy_from_slice(designation);
+            written += designation.len();
+
+            if shift_length > 0 {
+                output[written] = mode.shift();
+                written += 1;
+            }
+
+            output[written..written + length].copy_from_slice(&encoded[..length]);
+            written += length;
+            read += character.len_utf8();
+            self.designated = true;
+            self.mode = mode;
+        }
+
+        self.position += read;
+        Ok((read, written))
+    }
+
+    /// Shifts back to ASCII, which RFC 1557 requires at the end of every line.
+    fn finish(&mut self, output: &mut [u8]) -> Option<usize> {
+        if self.mode == Mode::Ascii {
+            return Some(0);
+        }
+
+        *output.first_mut()? = Mode::Ascii.shift();
+        self.mode = Mode::Ascii;
+        Some(1)
+    }
+}
+
+/// The escape sequence that designates KS C 5601 as the character set shifted out to, which has to
+/// appear before the first shift.
+const DESIGNATION: &[u8] = b"\x1b$)C";
+const ESCAPE: u8 = 0x1b;
+const SHIFT_IN: u8 = 0x0f;
+const SHIFT_OUT: u8 = 0x0e;
+
+/// The mode that the characters of a [`Str`] are decoded in, along with whether KS C 5601 has been
+/// designated yet, carried from one character to the next while iterating over them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct State {
+    designated: bool,
+    mode: Mode,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            designated: false,
+            mode: Mode::Ascii,
+        }
+    }
+}
+
+/// The character set that the bytes following a shift are decoded with.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Mode {
+    Ascii,
+
+    /// KS C 5601, where every character is a pair of bytes between 0x21 and 0x7e.
+    KsC5601,
+}
+
+impl Mode {
+    fn shift(self) -> u8 {
+        match self {
+            Mode::Ascii => SHIFT_IN,
+            Mode::KsC5601 => SHIFT_OUT,
+        }
+    }
+}
+
+/// Decodes the sequence at the start of `value` in the mode of `state`, updating `state` if it is a
+/// designation or a shift.
+fn next(value: &[u8], state: &mut State) -> Option<Sequence> {
+    let first = *value.first()?;
+
+    let sequence = match (state.mode, first) {
+        (_, ESCAPE) => match value {
+            [_, b'$', b')', b'C', ..] => {
+                state.designated = true;
+                Sequence::Escape(DESIGNATION.len())
+            }
+            [_] | [_, b'$'] | [_, b'$', b')'] => Sequence::Incomplete,
+            _ => Sequence::Invalid(1),
+        },
+        (_, SHIFT_IN) => {
+            state.mode = Mode::Ascii;
+            Sequence::Escape(1)
+        }
+        // There is nothing to shift out to before KS C 5601 has been designated.
+        (_, SHIFT_OUT) if !state.designated => Sequence::Invalid(1),
+        (_, SHIFT_OUT) => {
+            state.mode = Mode::KsC5601;
+            Sequence::Escape(1)
+        }
+        // Every line starts out in ASCII, even if the previous one did not shift back in.
+        (_, b'\n') => {
+            state.mode = Mode::Ascii;
+            Sequence::Character('\n', 1)
+        }
+        (_, 0x00..=0x1f) | (Mode::Ascii, 0x20..=0x7f) => Sequence::Character(first as char, 1),
+        (Mode::KsC5601, 0x21..=0x7e) => match value.get(1) {
+            Some(&second @ 0x21..=0x7e) => {
+                // The pair is the EUC-KR encoding with the high bits cleared.
+                let pointer =
+                    usize::from(first + 0x80 - 0x81) * 190 + usize::from(second + 0x80 - 0x41);
+
+                match uhc::decode(pointer) {
+                    Some(character) => Sequence::Character(character, 2),
+                    None => Sequence::Invalid(2),
+                }
+            }
+            Some(_) => Sequence::Invalid(1),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+fn validate(value: &[u8]) -> Result<(), SequenceError> {
+    let mut state = State::default();
+    sequence::validate(value, |value| next(value, &mut state))
+}
+
+/// Returns the mode that `character` is encoded in along with its bytes and their length.
+fn encode_character(character: char) -> Option<(Mode, [u8; 2], usize)> {
+    match character {
+        // These would be mistaken for the start of an escape sequence or a shift.
+        '\x0e' | '\x0f' | '\x1b' => None,
+        '\0'..='\x7f' => Some((Mode::Ascii, [character as u8, 0], 1)),
+        _ => {
+            // Only the KS C 5601 part of Unified Hangul Code, where both bytes are at least 0xa1,
+            // can be shifted to.
+            let pointer = uhc::encode(character)?;
+            let (lead, trail) = ((pointer / 190) as u8 + 0x81, (pointer % 190) as u8 + 0x41);
+
+            if lead < 0xa1 || trail < 0xa1 {
+                return None;
+            }
+
+            Some((Mode::KsC5601, [lead - 0x80, trail - 0x80], 2))
+        }
+    }
+}
+
+aliases! {
+    Alias,
+
+    (CsIso2022Kr, b"csISO2022KR");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_shifted_text() {
+        let string = Str::decode(b"\x1b$)Ca\x0eGQ19\x0fb\x0e>n\x0f").unwrap();
+        assert_eq!(string.to_unicode(), "a\u{d55c}\u{ad6d}b\u{c5b4}");
+
+        let indices: Vec<_> = string.char_indices().map(|(index, _)| index).collect();
+        assert_eq!(indices, [0, 5, 8, 10, 12]);
+    }
+
+    #[test]
+    fn encodes_with_a_single_designation() {
+        let mut encoder = Encoder::default();
+        let mut output = [0; 32];
+        let (read, mut written) = encoder.encode("a\u{d55c}b\u{ad6d}", &mut output).unwrap();
+        assert_eq!(read, 8);
+        written += encoder.finish(&mut output[written..]).unwrap();
+        assert_eq!(&output[..written], b"\x1b$)Ca\x0eGQ\x0fb\x0e19\x0f");
+    }
+
+    #[test]
+    fn designates_before_any_text() {
+        let string = String::encode("a").unwrap();
+        assert_eq!(AsRef::<[u8]>::as_ref(&string), b"\x1b$)Ca");
+        assert_eq!(Vec::from(String::encode("").unwrap()), b"");
+
+        let mut encoder = Encoder::default();
+        let mut output = [0; 5];
+        assert_eq!(encoder.encode("ab", &mut output[..4]).unwrap(), (0, 0));
+        assert_eq!(encoder.encode("ab", &mut output).unwrap(), (1, 5));
+        assert_eq!(&output, b"\x1b$)Ca");
+    }
+
+    #[test]
+    fn rejects_shift_out_before_designation() {
+        let error = Str::decode(b"\x0e!!\x0f").unwrap_err();
+        assert_eq!(error.valid_up_to(), 0);
+        assert_eq!(error.invalid_bytes(), b"\x0e");
+
+        let error = Str::decode(b"a\x0e!!\x0f\x1b$)C").unwrap_err();
+        assert_eq!(error.valid_up_to(), 1);
+
+        let mut decoder = Decoder::default();
+        let mut output = StdString::new();
+        decoder.decode_to_unicode(b"\x1b$", &mut output).unwrap();
+        decoder
+            .decode_to_unicode(b")C\x0e!!\x0f", &mut output)
+            .unwrap();
+        decoder.finish().unwrap();
+        assert_eq!(output, "\u{3000}");
+    }
+
+    #[test]
+    fn rejects_extension_characters() {
+        let error = String::encode("a\u{b620}").unwrap_err();
+        assert_eq!(error.character(), '\u{b620}');
+        assert_eq!(error.valid_up_to(), 1);
+    }
+}
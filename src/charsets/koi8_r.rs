@@ -0,0 +1,71 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{2500}', '\u{2502}', '\u{250c}', '\u{2510}', '\u{2514}', '\u{2518}', '\u{251c}', '\u{2524}', '\u{252c}', '\u{2534}', '\u{253c}', '\u{2580}', '\u{2584}', '\u{2588}', '\u{258c}', '\u{2590}',
+    '\u{2591}', '\u{2592}', '\u{2593}', '\u{2320}', '\u{25a0}', '\u{2219}', '\u{221a}', '\u{2248}', '\u{2264}', '\u{2265}', '\u{00a0}', '\u{2321}', '\u{00b0}', '\u{00b2}', '\u{00b7}', '\u{00f7}',
+    '\u{2550}', '\u{2551}', '\u{2552}', '\u{0451}', '\u{2553}', '\u{2554}', '\u{2555}', '\u{2556}', '\u{2557}', '\u{2558}', '\u{2559}', '\u{255a}', '\u{255b}', '\u{255c}', '\u{255d}', '\u{255e}',
+    '\u{255f}', '\u{2560}', '\u{2561}', '\u{0401}', '\u{2562}', '\u{2563}', '\u{2564}', '\u{2565}', '\u{2566}', '\u{2567}', '\u{2568}', '\u{2569}', '\u{256a}', '\u{256b}', '\u{256c}', '\u{00a9}',
+    '\u{044e}', '\u{0430}', '\u{0431}', '\u{0446}', '\u{0434}', '\u{0435}', '\u{0444}', '\u{0433}', '\u{0445}', '\u{0438}', '\u{0439}', '\u{043a}', '\u{043b}', '\u{043c}', '\u{043d}', '\u{043e}',
+    '\u{043f}', '\u{044f}', '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0436}', '\u{0432}', '\u{044c}', '\u{044b}', '\u{0437}', '\u{0448}', '\u{044d}', '\u{0449}', '\u{0447}', '\u{044a}',
+    '\u{042e}', '\u{0410}', '\u{0411}', '\u{0426}', '\u{0414}', '\u{0415}', '\u{0424}', '\u{0413}', '\u{0425}', '\u{0418}', '\u{0419}', '\u{041a}', '\u{041b}', '\u{041c}', '\u{041d}', '\u{041e}',
+    '\u{041f}', '\u{042f}', '\u{0420}', '\u{0421}', '\u{0422}', '\u{0423}', '\u{0416}', '\u{0412}', '\u{042c}', '\u{042b}', '\u{0417}', '\u{0428}', '\u{042d}', '\u{0429}', '\u{0427}', '\u{042a}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 128] = [
+    ('\u{00a0}', 0x9a), ('\u{00a9}', 0xbf), ('\u{00b0}', 0x9c), ('\u{00b2}', 0x9d), ('\u{00b7}', 0x9e), ('\u{00f7}', 0x9f), ('\u{0401}', 0xb3), ('\u{0410}', 0xe1),
+    ('\u{0411}', 0xe2), ('\u{0412}', 0xf7), ('\u{0413}', 0xe7), ('\u{0414}', 0xe4), ('\u{0415}', 0xe5), ('\u{0416}', 0xf6), ('\u{0417}', 0xfa), ('\u{0418}', 0xe9),
+    ('\u{0419}', 0xea), ('\u{041a}', 0xeb), ('\u{041b}', 0xec), ('\u{041c}', 0xed), ('\u{041d}', 0xee), ('\u{041e}', 0xef), ('\u{041f}', 0xf0), ('\u{0420}', 0xf2),
+    ('\u{0421}', 0xf3), ('\u{0422}', 0xf4), ('\u{0423}', 0xf5), ('\u{0424}', 0xe6), ('\u{0425}', 0xe8), ('\u{0426}', 0xe3), ('\u{0427}', 0xfe), ('\u{0428}', 0xfb),
+    ('\u{0429}', 0xfd), ('\u{042a}', 0xff), ('\u{042b}', 0xf9), ('\u{042c}', 0xf8), ('\u{042d}', 0xfc), ('\u{042e}', 0xe0), ('\u{042f}', 0xf1), ('\u{0430}', 0xc1),
+    ('\u{0431}', 0xc2), ('\u{0432}', 0xd7), ('\u{0433}', 0xc7), ('\u{0434}', 0xc4), ('\u{0435}', 0xc5), ('\u{0436}', 0xd6), ('\u{0437}', 0xda), ('\u{0438}', 0xc9),
+    ('\u{0439}', 0xca), ('\u{043a}', 0xcb), ('\u{043b}', 0xcc), ('\u{043c}', 0xcd), ('\u{043d}', 0xce), ('\u{043e}', 0xcf), ('\u{043f}', 0xd0), ('\u{0440}', 0xd2),
+    ('\u{0441}', 0xd3), ('\u{0442}', 0xd4), ('\u{0443}', 0xd5), ('\u{0444}', 0xc6), ('\u{0445}', 0xc8), ('\u{0446}', 0xc3), ('\u{0447}', 0xde), ('\u{0448}', 0xdb),
+    ('\u{0449}', 0xdd), ('\u{044a}', 0xdf), ('\u{044b}', 0xd9), ('\u{044c}', 0xd8), ('\u{044d}', 0xdc), ('\u{044e}', 0xc0), ('\u{044f}', 0xd1), ('\u{0451}', 0xa3),
+    ('\u{2219}', 0x95), ('\u{221a}', 0x96), ('\u{2248}', 0x97), ('\u{2264}', 0x98), ('\u{2265}', 0x99), ('\u{2320}', 0x93), ('\u{2321}', 0x9b), ('\u{2500}', 0x80),
+    ('\u{2502}', 0x81), ('\u{250c}', 0x82), ('\u{2510}', 0x83), ('\u{2514}', 0x84), ('\u{2518}', 0x85), ('\u{251c}', 0x86), ('\u{2524}', 0x87), ('\u{252c}', 0x88),
+    ('\u{2534}', 0x89), ('\u{253c}', 0x8a), ('\u{2550}', 0xa0), ('\u{2551}', 0xa1), ('\u{2552}', 0xa2), ('\u{2553}', 0xa4), ('\u{2554}', 0xa5), ('\u{2555}', 0xa6),
+    ('\u{2556}', 0xa7), ('\u{2557}', 0xa8), ('\u{2558}', 0xa9), ('\u{2559}', 0xaa), ('\u{255a}', 0xab), ('\u{255b}', 0xac), ('\u{255c}', 0xad), ('\u{255d}', 0xae),
+    ('\u{255e}', 0xaf), ('\u{255f}', 0xb0), ('\u{2560}', 0xb1), ('\u{2561}', 0xb2), ('\u{2562}', 0xb4), ('\u{2563}', 0xb5), ('\u{2564}', 0xb6), ('\u{2565}', 0xb7),
+    ('\u{2566}', 0xb8), ('\u{2567}', 0xb9), ('\u{2568}', 0xba), ('\u{2569}', 0xbb), ('\u{256a}', 0xbc), ('\u{256b}', 0xbd), ('\u{256c}', 0xbe), ('\u{2580}', 0x8b),
+    ('\u{2584}', 0x8c), ('\u{2588}', 0x8d), ('\u{258c}', 0x8e), ('\u{2590}', 0x8f), ('\u{2591}', 0x90), ('\u{2592}', 0x91), ('\u{2593}', 0x92), ('\u{25a0}', 0x94),
+];
+
+single_byte_charset! {
+    primary_name: "KOI8-R",
+    preferred_mime_name: "KOI8-R",
+    mib_enum: 2084,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsKoi8R, b"csKOI8R");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_byte_round_trips() {
+        for byte in 0..=0xff {
+            let character = Character::from_byte(byte).unwrap();
+            assert_eq!(character.to_byte(), Some(byte));
+        }
+    }
+
+    #[test]
+    fn decodes_and_encodes_cyrillic() {
+        let str = Str::decode(b"\xf0\xd2\xc9\xd7\xc5\xd4!").unwrap();
+        assert_eq!(
+            str.to_unicode(),
+            "\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}!"
+        );
+
+        let string = String::encode("\u{41f}\u{440}\u{438}\u{432}\u{435}\u{442}!").unwrap();
+        assert_eq!(Vec::from(string), b"\xf0\xd2\xc9\xd7\xc5\xd4!");
+        assert_eq!(
+            String::encode("\u{20ac}").unwrap_err().character(),
+            '\u{20ac}'
+        );
+    }
+}
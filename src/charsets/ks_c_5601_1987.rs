@@ -0,0 +1,39 @@
+use crate::charsets::euc_kr;
+
+// Windows treats KS_C_5601-1987 as another name for Unified Hangul Code, so decoding and
+// encoding are shared with EUC-KR.
+multi_byte_charset! {
+    primary_name: "KS_C_5601-1987",
+    mib_enum: 36,
+    next: euc_kr::next,
+    encode_character: euc_kr::encode_character,
+    aliases: [
+        (CsKsc56011987, b"csKSC56011987");
+        (IsoIr149, b"iso-ir-149");
+        (Korean, b"korean");
+        (KsC5601_1989, b"KS_C_5601-1989");
+        (Ksc5601, b"KSC_5601");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_like_euc_kr() {
+        let bytes = b"\xc7\xd1\xb1\xb9\xbe\xee\x8c\x63";
+        let string = Str::decode(bytes).unwrap();
+        assert_eq!(string.to_unicode(), "\u{d55c}\u{ad6d}\u{c5b4}\u{b620}");
+        assert_eq!(
+            string.to_unicode(),
+            euc_kr::Str::decode(bytes).unwrap().to_unicode()
+        );
+    }
+
+    #[test]
+    fn encodes_known_text() {
+        let string = String::encode("\u{d55c}\u{ad6d}\u{c5b4}").unwrap();
+        assert_eq!(Vec::from(string), b"\xc7\xd1\xb1\xb9\xbe\xee");
+    }
+}
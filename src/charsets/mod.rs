@@ -1,42 +1,433 @@
 use paste::item;
-use std::borrow::ToOwned;
+use std::borrow::{Cow, ToOwned};
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::string::String as StdString;
 
+#[macro_use]
+mod multi_byte;
+#[macro_use]
+mod single_byte;
+#[macro_use]
+mod unicode;
+
+pub mod big5;
+pub mod big5_hkscs;
+pub mod euc_jp;
+pub mod euc_kr;
+pub mod gb18030;
+pub mod gb2312;
+pub mod gbk;
+pub mod ibm01140;
+pub mod ibm01141;
+pub mod ibm01142;
+pub mod ibm01143;
+pub mod ibm01144;
+pub mod ibm01145;
+pub mod ibm01146;
+pub mod ibm01147;
+pub mod ibm01148;
+pub mod ibm01149;
+pub mod ibm037;
+pub mod ibm1047;
+pub mod ibm273;
+pub mod ibm500;
+pub mod iso8859_10;
+pub mod iso8859_13;
+pub mod iso8859_14;
+pub mod iso8859_15;
+pub mod iso8859_16;
 pub mod iso8859_1_1987;
 pub mod iso8859_2_1987;
 pub mod iso8859_3_1988;
+pub mod iso8859_4_1988;
+pub mod iso8859_5_1988;
+pub mod iso8859_6_1987;
+pub mod iso8859_7_1987;
+pub mod iso8859_8_1988;
+pub mod iso8859_9_1989;
+pub mod iso_2022_jp;
+pub mod iso_2022_kr;
+pub mod koi8_r;
+pub mod koi8_u;
+pub mod ks_c_5601_1987;
+pub mod shift_jis;
 pub mod us_ascii;
+pub mod utf_16;
+pub mod utf_16be;
+pub mod utf_16le;
+pub mod utf_32;
+pub mod utf_32be;
+pub mod utf_32le;
 pub mod utf_8;
+pub mod windows_1250;
+pub mod windows_1251;
+pub mod windows_1252;
+pub mod windows_1253;
+pub mod windows_1254;
+pub mod windows_1255;
+pub mod windows_1256;
+pub mod windows_1257;
+pub mod windows_1258;
+pub mod windows_31j;
+
+mod names;
+mod sequence;
+mod tables;
 
+pub use big5::{
+    Alias as Big5Alias, Character as Big5Character, Charset as Big5Charset,
+    DecodeError as Big5DecodeError, Decoder as Big5Decoder, EncodeError as Big5EncodeError,
+    Encoder as Big5Encoder, Str as Big5Str, String as Big5String,
+};
+pub use big5_hkscs::{
+    Alias as Big5HkscsAlias, Character as Big5HkscsCharacter, Charset as Big5HkscsCharset,
+    DecodeError as Big5HkscsDecodeError, Decoder as Big5HkscsDecoder,
+    EncodeError as Big5HkscsEncodeError, Encoder as Big5HkscsEncoder, Str as Big5HkscsStr,
+    String as Big5HkscsString,
+};
+pub use euc_jp::{
+    Alias as EucJpAlias, Character as EucJpCharacter, Charset as EucJpCharset,
+    DecodeError as EucJpDecodeError, Decoder as EucJpDecoder, EncodeError as EucJpEncodeError,
+    Encoder as EucJpEncoder, Str as EucJpStr, String as EucJpString,
+};
+pub use euc_kr::{
+    Alias as EucKrAlias, Character as EucKrCharacter, Charset as EucKrCharset,
+    DecodeError as EucKrDecodeError, Decoder as EucKrDecoder, EncodeError as EucKrEncodeError,
+    Encoder as EucKrEncoder, Str as EucKrStr, String as EucKrString,
+};
+pub use gb18030::{
+    Alias as Gb18030Alias, Character as Gb18030Character, Charset as Gb18030Charset,
+    DecodeError as Gb18030DecodeError, Decoder as Gb18030Decoder,
+    EncodeError as Gb18030EncodeError, Encoder as Gb18030Encoder, Str as Gb18030Str,
+    String as Gb18030String,
+};
+pub use gb2312::{
+    Alias as Gb2312Alias, Character as Gb2312Character, Charset as Gb2312Charset,
+    DecodeError as Gb2312DecodeError, Decoder as Gb2312Decoder, EncodeError as Gb2312EncodeError,
+    Encoder as Gb2312Encoder, Str as Gb2312Str, String as Gb2312String,
+};
+pub use gbk::{
+    Alias as GbkAlias, Character as GbkCharacter, Charset as GbkCharset,
+    DecodeError as GbkDecodeError, Decoder as GbkDecoder, EncodeError as GbkEncodeError,
+    Encoder as GbkEncoder, Str as GbkStr, String as GbkString,
+};
+pub use ibm01140::{
+    Alias as Ibm01140Alias, Character as Ibm01140Character, Charset as Ibm01140Charset,
+    DecodeError as Ibm01140DecodeError, Decoder as Ibm01140Decoder,
+    EncodeError as Ibm01140EncodeError, Encoder as Ibm01140Encoder, Str as Ibm01140Str,
+    String as Ibm01140String,
+};
+pub use ibm01141::{
+    Alias as Ibm01141Alias, Character as Ibm01141Character, Charset as Ibm01141Charset,
+    DecodeError as Ibm01141DecodeError, Decoder as Ibm01141Decoder,
+    EncodeError as Ibm01141EncodeError, Encoder as Ibm01141Encoder, Str as Ibm01141Str,
+    String as Ibm01141String,
+};
+pub use ibm01142::{
+    Alias as Ibm01142Alias, Character as Ibm01142Character, Charset as Ibm01142Charset,
+    DecodeError as Ibm01142DecodeError, Decoder as Ibm01142Decoder,
+    EncodeError as Ibm01142EncodeError, Encoder as Ibm01142Encoder, Str as Ibm01142Str,
+    String as Ibm01142String,
+};
+pub use ibm01143::{
+    Alias as Ibm01143Alias, Character as Ibm01143Character, Charset as Ibm01143Charset,
+    DecodeError as Ibm01143DecodeError, Decoder as Ibm01143Decoder,
+    EncodeError as Ibm01143EncodeError, Encoder as Ibm01143Encoder, Str as Ibm01143Str,
+    String as Ibm01143String,
+};
+pub use ibm01144::{
+    Alias as Ibm01144Alias, Character as Ibm01144Character, Charset as Ibm01144Charset,
+    DecodeError as Ibm01144DecodeError, Decoder as Ibm01144Decoder,
+    EncodeError as Ibm01144EncodeError, Encoder as Ibm01144Encoder, Str as Ibm01144Str,
+    String as Ibm01144String,
+};
+pub use ibm01145::{
+    Alias as Ibm01145Alias, Character as Ibm01145Character, Charset as Ibm01145Charset,
+    DecodeError as Ibm01145DecodeError, Decoder as Ibm01145Decoder,
+    EncodeError as Ibm01145EncodeError, Encoder as Ibm01145Encoder, Str as Ibm01145Str,
+    String as Ibm01145String,
+};
+pub use ibm01146::{
+    Alias as Ibm01146Alias, Character as Ibm01146Character, Charset as Ibm01146Charset,
+    DecodeError as Ibm01146DecodeError, Decoder as Ibm01146Decoder,
+    EncodeError as Ibm01146EncodeError, Encoder as Ibm01146Encoder, Str as Ibm01146Str,
+    String as Ibm01146String,
+};
+pub use ibm01147::{
+    Alias as Ibm01147Alias, Character as Ibm01147Character, Charset as Ibm01147Charset,
+    DecodeError as Ibm01147DecodeError, Decoder as Ibm01147Decoder,
+    EncodeError as Ibm01147EncodeError, Encoder as Ibm01147Encoder, Str as Ibm01147Str,
+    String as Ibm01147String,
+};
+pub use ibm01148::{
+    Alias as Ibm01148Alias, Character as Ibm01148Character, Charset as Ibm01148Charset,
+    DecodeError as Ibm01148DecodeError, Decoder as Ibm01148Decoder,
+    EncodeError as Ibm01148EncodeError, Encoder as Ibm01148Encoder, Str as Ibm01148Str,
+    String as Ibm01148String,
+};
+pub use ibm01149::{
+    Alias as Ibm01149Alias, Character as Ibm01149Character, Charset as Ibm01149Charset,
+    DecodeError as Ibm01149DecodeError, Decoder as Ibm01149Decoder,
+    EncodeError as Ibm01149EncodeError, Encoder as Ibm01149Encoder, Str as Ibm01149Str,
+    String as Ibm01149String,
+};
+pub use ibm037::{
+    Alias as Ibm037Alias, Character as Ibm037Character, Charset as Ibm037Charset,
+    DecodeError as Ibm037DecodeError, Decoder as Ibm037Decoder, EncodeError as Ibm037EncodeError,
+    Encoder as Ibm037Encoder, Str as Ibm037Str, String as Ibm037String,
+};
+pub use ibm1047::{
+    Alias as Ibm1047Alias, Character as Ibm1047Character, Charset as Ibm1047Charset,
+    DecodeError as Ibm1047DecodeError, Decoder as Ibm1047Decoder,
+    EncodeError as Ibm1047EncodeError, Encoder as Ibm1047Encoder, Str as Ibm1047Str,
+    String as Ibm1047String,
+};
+pub use ibm273::{
+    Alias as Ibm273Alias, Character as Ibm273Character, Charset as Ibm273Charset,
+    DecodeError as Ibm273DecodeError, Decoder as Ibm273Decoder, EncodeError as Ibm273EncodeError,
+    Encoder as Ibm273Encoder, Str as Ibm273Str, String as Ibm273String,
+};
+pub use ibm500::{
+    Alias as Ibm500Alias, Character as Ibm500Character, Charset as Ibm500Charset,
+    DecodeError as Ibm500DecodeError, Decoder as Ibm500Decoder, EncodeError as Ibm500EncodeError,
+    Encoder as Ibm500Encoder, Str as Ibm500Str, String as Ibm500String,
+};
+pub use iso8859_10::{
+    Alias as Iso8859_10Alias, Character as Iso8859_10Character, Charset as Iso8859_10Charset,
+    DecodeError as Iso8859_10DecodeError, Decoder as Iso8859_10Decoder,
+    EncodeError as Iso8859_10EncodeError, Encoder as Iso8859_10Encoder, Str as Iso8859_10Str,
+    String as Iso8859_10String,
+};
+pub use iso8859_13::{
+    Alias as Iso8859_13Alias, Character as Iso8859_13Character, Charset as Iso8859_13Charset,
+    DecodeError as Iso8859_13DecodeError, Decoder as Iso8859_13Decoder,
+    EncodeError as Iso8859_13EncodeError, Encoder as Iso8859_13Encoder, Str as Iso8859_13Str,
+    String as Iso8859_13String,
+};
+pub use iso8859_14::{
+    Alias as Iso8859_14Alias, Character as Iso8859_14Character, Charset as Iso8859_14Charset,
+    DecodeError as Iso8859_14DecodeError, Decoder as Iso8859_14Decoder,
+    EncodeError as Iso8859_14EncodeError, Encoder as Iso8859_14Encoder, Str as Iso8859_14Str,
+    String as Iso8859_14String,
+};
+pub use iso8859_15::{
+    Alias as Iso8859_15Alias, Character as Iso8859_15Character, Charset as Iso8859_15Charset,
+    DecodeError as Iso8859_15DecodeError, Decoder as Iso8859_15Decoder,
+    EncodeError as Iso8859_15EncodeError, Encoder as Iso8859_15Encoder, Str as Iso8859_15Str,
+    String as Iso8859_15String,
+};
+pub use iso8859_16::{
+    Alias as Iso8859_16Alias, Character as Iso8859_16Character, Charset as Iso8859_16Charset,
+    DecodeError as Iso8859_16DecodeError, Decoder as Iso8859_16Decoder,
+    EncodeError as Iso8859_16EncodeError, Encoder as Iso8859_16Encoder, Str as Iso8859_16Str,
+    String as Iso8859_16String,
+};
 pub use iso8859_1_1987::{
     Alias as Iso8859_1_1987Alias, Character as Iso8859_1_1987Character,
     Charset as Iso8859_1_1987Charset, DecodeError as Iso8859_1_1987DecodeError,
-    Str as Iso8859_1_1987Str, String as Iso8859_1_1987String,
+    Decoder as Iso8859_1_1987Decoder, EncodeError as Iso8859_1_1987EncodeError,
+    Encoder as Iso8859_1_1987Encoder, Str as Iso8859_1_1987Str, String as Iso8859_1_1987String,
 };
 pub use iso8859_2_1987::{
     Alias as Iso8859_2_1987Alias, Character as Iso8859_2_1987Character,
     Charset as Iso8859_2_1987Charset, DecodeError as Iso8859_2_1987DecodeError,
-    Str as Iso8859_2_1987Str, String as Iso8859_2_1987String,
+    Decoder as Iso8859_2_1987Decoder, EncodeError as Iso8859_2_1987EncodeError,
+    Encoder as Iso8859_2_1987Encoder, Str as Iso8859_2_1987Str, String as Iso8859_2_1987String,
 };
 pub use iso8859_3_1988::{
     Alias as Iso8859_3_1988Alias, Character as Iso8859_3_1988Character,
     Charset as Iso8859_3_1988Charset, DecodeError as Iso8859_3_1988DecodeError,
-    Str as Iso8859_3_1988Str, String as Iso8859_3_1988String,
+    Decoder as Iso8859_3_1988Decoder, EncodeError as Iso8859_3_1988EncodeError,
+    Encoder as Iso8859_3_1988Encoder, Str as Iso8859_3_1988Str, String as Iso8859_3_1988String,
+};
+pub use iso8859_4_1988::{
+    Alias as Iso8859_4_1988Alias, Character as Iso8859_4_1988Character,
+    Charset as Iso8859_4_1988Charset, DecodeError as Iso8859_4_1988DecodeError,
+    Decoder as Iso8859_4_1988Decoder, EncodeError as Iso8859_4_1988EncodeError,
+    Encoder as Iso8859_4_1988Encoder, Str as Iso8859_4_1988Str, String as Iso8859_4_1988String,
+};
+pub use iso8859_5_1988::{
+    Alias as Iso8859_5_1988Alias, Character as Iso8859_5_1988Character,
+    Charset as Iso8859_5_1988Charset, DecodeError as Iso8859_5_1988DecodeError,
+    Decoder as Iso8859_5_1988Decoder, EncodeError as Iso8859_5_1988EncodeError,
+    Encoder as Iso8859_5_1988Encoder, Str as Iso8859_5_1988Str, String as Iso8859_5_1988String,
+};
+pub use iso8859_6_1987::{
+    Alias as Iso8859_6_1987Alias, Character as Iso8859_6_1987Character,
+    Charset as Iso8859_6_1987Charset, DecodeError as Iso8859_6_1987DecodeError,
+    Decoder as Iso8859_6_1987Decoder, EncodeError as Iso8859_6_1987EncodeError,
+    Encoder as Iso8859_6_1987Encoder, Str as Iso8859_6_1987Str, String as Iso8859_6_1987String,
+};
+pub use iso8859_7_1987::{
+    Alias as Iso8859_7_1987Alias, Character as Iso8859_7_1987Character,
+    Charset as Iso8859_7_1987Charset, DecodeError as Iso8859_7_1987DecodeError,
+    Decoder as Iso8859_7_1987Decoder, EncodeError as Iso8859_7_1987EncodeError,
+    Encoder as Iso8859_7_1987Encoder, Str as Iso8859_7_1987Str, String as Iso8859_7_1987String,
+};
+pub use iso8859_8_1988::{
+    Alias as Iso8859_8_1988Alias, Character as Iso8859_8_1988Character,
+    Charset as Iso8859_8_1988Charset, DecodeError as Iso8859_8_1988DecodeError,
+    Decoder as Iso8859_8_1988Decoder, EncodeError as Iso8859_8_1988EncodeError,
+    Encoder as Iso8859_8_1988Encoder, Str as Iso8859_8_1988Str, String as Iso8859_8_1988String,
+};
+pub use iso8859_9_1989::{
+    Alias as Iso8859_9_1989Alias, Character as Iso8859_9_1989Character,
+    Charset as Iso8859_9_1989Charset, DecodeError as Iso8859_9_1989DecodeError,
+    Decoder as Iso8859_9_1989Decoder, EncodeError as Iso8859_9_1989EncodeError,
+    Encoder as Iso8859_9_1989Encoder, Str as Iso8859_9_1989Str, String as Iso8859_9_1989String,
+};
+pub use iso_2022_jp::{
+    Alias as Iso2022JpAlias, Character as Iso2022JpCharacter, Charset as Iso2022JpCharset,
+    DecodeError as Iso2022JpDecodeError, Decoder as Iso2022JpDecoder,
+    EncodeError as Iso2022JpEncodeError, Encoder as Iso2022JpEncoder, Str as Iso2022JpStr,
+    String as Iso2022JpString,
+};
+pub use iso_2022_kr::{
+    Alias as Iso2022KrAlias, Character as Iso2022KrCharacter, Charset as Iso2022KrCharset,
+    DecodeError as Iso2022KrDecodeError, Decoder as Iso2022KrDecoder,
+    EncodeError as Iso2022KrEncodeError, Encoder as Iso2022KrEncoder, Str as Iso2022KrStr,
+    String as Iso2022KrString,
+};
+pub use koi8_r::{
+    Alias as Koi8RAlias, Character as Koi8RCharacter, Charset as Koi8RCharset,
+    DecodeError as Koi8RDecodeError, Decoder as Koi8RDecoder, EncodeError as Koi8REncodeError,
+    Encoder as Koi8REncoder, Str as Koi8RStr, String as Koi8RString,
+};
+pub use koi8_u::{
+    Alias as Koi8UAlias, Character as Koi8UCharacter, Charset as Koi8UCharset,
+    DecodeError as Koi8UDecodeError, Decoder as Koi8UDecoder, EncodeError as Koi8UEncodeError,
+    Encoder as Koi8UEncoder, Str as Koi8UStr, String as Koi8UString,
+};
+pub use ks_c_5601_1987::{
+    Alias as KsC5601_1987Alias, Character as KsC5601_1987Character, Charset as KsC5601_1987Charset,
+    DecodeError as KsC5601_1987DecodeError, Decoder as KsC5601_1987Decoder,
+    EncodeError as KsC5601_1987EncodeError, Encoder as KsC5601_1987Encoder, Str as KsC5601_1987Str,
+    String as KsC5601_1987String,
+};
+pub use shift_jis::{
+    Alias as ShiftJisAlias, Character as ShiftJisCharacter, Charset as ShiftJisCharset,
+    DecodeError as ShiftJisDecodeError, Decoder as ShiftJisDecoder,
+    EncodeError as ShiftJisEncodeError, Encoder as ShiftJisEncoder, Str as ShiftJisStr,
+    String as ShiftJisString,
 };
 pub use us_ascii::{
     Alias as UsAsciiAlias, Character as UsAsciiCharacter, Charset as UsAsciiCharset,
-    DecodeError as UsAsciiDecodeError, Str as UsAsciiStr, String as UsAsciiString,
+    DecodeError as UsAsciiDecodeError, Decoder as UsAsciiDecoder,
+    EncodeError as UsAsciiEncodeError, Encoder as UsAsciiEncoder, Str as UsAsciiStr,
+    String as UsAsciiString,
+};
+pub use utf_16::{
+    Alias as Utf16Alias, Character as Utf16Character, Charset as Utf16Charset,
+    DecodeError as Utf16DecodeError, Decoder as Utf16Decoder, EncodeError as Utf16EncodeError,
+    Encoder as Utf16Encoder, Str as Utf16Str, String as Utf16String,
+};
+pub use utf_16be::{
+    Alias as Utf16BeAlias, Character as Utf16BeCharacter, Charset as Utf16BeCharset,
+    DecodeError as Utf16BeDecodeError, Decoder as Utf16BeDecoder,
+    EncodeError as Utf16BeEncodeError, Encoder as Utf16BeEncoder, Str as Utf16BeStr,
+    String as Utf16BeString,
+};
+pub use utf_16le::{
+    Alias as Utf16LeAlias, Character as Utf16LeCharacter, Charset as Utf16LeCharset,
+    DecodeError as Utf16LeDecodeError, Decoder as Utf16LeDecoder,
+    EncodeError as Utf16LeEncodeError, Encoder as Utf16LeEncoder, Str as Utf16LeStr,
+    String as Utf16LeString,
+};
+pub use utf_32::{
+    Alias as Utf32Alias, Character as Utf32Character, Charset as Utf32Charset,
+    DecodeError as Utf32DecodeError, Decoder as Utf32Decoder, EncodeError as Utf32EncodeError,
+    Encoder as Utf32Encoder, Str as Utf32Str, String as Utf32String,
+};
+pub use utf_32be::{
+    Alias as Utf32BeAlias, Character as Utf32BeCharacter, Charset as Utf32BeCharset,
+    DecodeError as Utf32BeDecodeError, Decoder as Utf32BeDecoder,
+    EncodeError as Utf32BeEncodeError, Encoder as Utf32BeEncoder, Str as Utf32BeStr,
+    String as Utf32BeString,
+};
+pub use utf_32le::{
+    Alias as Utf32LeAlias, Character as Utf32LeCharacter, Charset as Utf32LeCharset,
+    DecodeError as Utf32LeDecodeError, Decoder as Utf32LeDecoder,
+    EncodeError as Utf32LeEncodeError, Encoder as Utf32LeEncoder, Str as Utf32LeStr,
+    String as Utf32LeString,
 };
 pub use utf_8::{
     Alias as Utf8Alias, Character as Utf8Character, Charset as Utf8Charset,
-    DecodeError as Utf8DecodeError, Str as Utf8Str, String as Utf8String,
+    DecodeError as Utf8DecodeError, Decoder as Utf8Decoder, EncodeError as Utf8EncodeError,
+    Encoder as Utf8Encoder, Str as Utf8Str, String as Utf8String,
+};
+pub use windows_1250::{
+    Alias as Windows1250Alias, Character as Windows1250Character, Charset as Windows1250Charset,
+    DecodeError as Windows1250DecodeError, Decoder as Windows1250Decoder,
+    EncodeError as Windows1250EncodeError, Encoder as Windows1250Encoder, Str as Windows1250Str,
+    String as Windows1250String,
+};
+pub use windows_1251::{
+    Alias as Windows1251Alias, Character as Windows1251Character, Charset as Windows1251Charset,
+    DecodeError as Windows1251DecodeError, Decoder as Windows1251Decoder,
+    EncodeError as Windows1251EncodeError, Encoder as Windows1251Encoder, Str as Windows1251Str,
+    String as Windows1251String,
+};
+pub use windows_1252::{
+    Alias as Windows1252Alias, Character as Windows1252Character, Charset as Windows1252Charset,
+    DecodeError as Windows1252DecodeError, Decoder as Windows1252Decoder,
+    EncodeError as Windows1252EncodeError, Encoder as Windows1252Encoder, Str as Windows1252Str,
+    String as Windows1252String,
+};
+pub use windows_1253::{
+    Alias as Windows1253Alias, Character as Windows1253Character, Charset as Windows1253Charset,
+    DecodeError as Windows1253DecodeError, Decoder as Windows1253Decoder,
+    EncodeError as Windows1253EncodeError, Encoder as Windows1253Encoder, Str as Windows1253Str,
+    String as Windows1253String,
+};
+pub use windows_1254::{
+    Alias as Windows1254Alias, Character as Windows1254Character, Charset as Windows1254Charset,
+    DecodeError as Windows1254DecodeError, Decoder as Windows1254Decoder,
+    EncodeError as Windows1254EncodeError, Encoder as Windows1254Encoder, Str as Windows1254Str,
+    String as Windows1254String,
+};
+pub use windows_1255::{
+    Alias as Windows1255Alias, Character as Windows1255Character, Charset as Windows1255Charset,
+    DecodeError as Windows1255DecodeError, Decoder as Windows1255Decoder,
+    EncodeError as Windows1255EncodeError, Encoder as Windows1255Encoder, Str as Windows1255Str,
+    String as Windows1255String,
+};
+pub use windows_1256::{
+    Alias as Windows1256Alias, Character as Windows1256Character, Charset as Windows1256Charset,
+    DecodeError as Windows1256DecodeError, Decoder as Windows1256Decoder,
+    EncodeError as Windows1256EncodeError, Encoder as Windows1256Encoder, Str as Windows1256Str,
+    String as Windows1256String,
+};
+pub use windows_1257::{
+    Alias as Windows1257Alias, Character as Windows1257Character, Charset as Windows1257Charset,
+    DecodeError as Windows1257DecodeError, Decoder as Windows1257Decoder,
+    EncodeError as Windows1257EncodeError, Encoder as Windows1257Encoder, Str as Windows1257Str,
+    String as Windows1257String,
+};
+pub use windows_1258::{
+    Alias as Windows1258Alias, Character as Windows1258Character, Charset as Windows1258Charset,
+    DecodeError as Windows1258DecodeError, Decoder as Windows1258Decoder,
+    EncodeError as Windows1258EncodeError, Encoder as Windows1258Encoder, Str as Windows1258Str,
+    String as Windows1258String,
+};
+pub use windows_31j::{
+    Alias as Windows31JAlias, Character as Windows31JCharacter, Charset as Windows31JCharset,
+    DecodeError as Windows31JDecodeError, Decoder as Windows31JDecoder,
+    EncodeError as Windows31JEncodeError, Encoder as Windows31JEncoder, Str as Windows31JStr,
+    String as Windows31JString,
 };
 
-use crate::charset::{Str as StrTrait, String as StringTrait};
+use crate::charset::{
+    CharIndices as StrCharIndices, Character as CharacterTrait, Charset as CharsetTrait,
+    DecodeError as DecodeErrorTrait, Decoder as DecoderTrait, EncodeError as EncodeErrorTrait,
+    Encoder as EncoderTrait, Replacement, Str as StrTrait, String as StringTrait,
+};
 
 macro_rules! enums {
     ($($charset:ident,)+) => {
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
         pub enum Charset {
         $(
             $charset,
@@ -44,6 +435,144 @@ macro_rules! enums {
         }
 
         impl Charset {
+            /// Converts bytes in the `from` charset into a string in the `to` charset.
+            ///
+            /// If the bytes can be used as is, i.e. the charsets are the same or the bytes are
+            /// US-ASCII and both charsets are ASCII compatible, only validation is performed.
+            /// Otherwise, the bytes are decoded into Unicode which is then encoded into the `to`
+            /// charset, in which case [`EncodeError::valid_up_to`] refers to the decoded Unicode
+            /// text.
+            pub fn transcode(
+                from: Charset,
+                to: Charset,
+                value: &[u8],
+            ) -> Result<String, TranscodeError> {
+                let str = from.decode_from_byte_slice(value)?;
+
+                if from == to
+                    || (from.is_ascii_compatible() && to.is_ascii_compatible() && value.is_ascii())
+                {
+                    // Unsafe justification: the bytes were just validated for the `from` charset,
+                    // which has the same byte representation as the `to` charset for them.
+                    return Ok(unsafe { to.decode_from_byte_vec_unchecked(value.to_vec()) });
+                }
+
+                Ok(to.encode(&str.to_unicode())?)
+            }
+
+            /// Returns every supported charset.
+            pub fn all() -> &'static [Charset] {
+                &[
+                $(
+                    Charset::$charset,
+                )+
+                ]
+            }
+
+            /// Looks up a charset by its primary name, its preferred MIME name, or any of its
+            /// aliases.
+            ///
+            /// Comparison ignores ASCII case as required by RFC 2978.
+            pub fn from_name(name: &[u8]) -> Option<Charset> {
+                if name.len() > names::MAX_NAME_LENGTH {
+                    return None;
+                }
+
+                let mut buffer = [0; names::MAX_NAME_LENGTH];
+                let buffer = &mut buffer[..name.len()];
+                buffer.copy_from_slice(name);
+                buffer.make_ascii_lowercase();
+                names::from_lowercase_name(buffer)
+            }
+
+            /// Looks up a charset by its MIBenum value as assigned in the IANA registry.
+            pub fn from_mib_enum(value: u16) -> Option<Charset> {
+            $(
+                item! {
+                    if value == <[<$charset Charset>] as CharsetTrait>::MIB_ENUM {
+                        return Some(Charset::$charset);
+                    }
+                }
+            )+
+
+                None
+            }
+
+            item! {
+                /// Returns the names of all aliases of the charset, excluding the primary name.
+                pub fn aliases(&self) -> &'static [&'static UsAsciiStr] {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => [<$charset Alias>]::NAMES,
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                /// Whether every US-ASCII byte sequence is valid in the charset and decodes to the
+                /// same characters.
+                pub fn is_ascii_compatible(&self) -> bool {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => <[<$charset Charset>] as CharsetTrait>::IS_ASCII_COMPATIBLE,
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                pub fn is_mime_text_suitable(&self) -> bool {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => [<$charset Charset>]::is_mime_text_suitable(),
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                pub fn mib_enum(&self) -> u16 {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => <[<$charset Charset>] as CharsetTrait>::MIB_ENUM,
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                pub fn preferred_mime_name(&self) -> Option<&'static UsAsciiStr> {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => <[<$charset Charset>] as CharsetTrait>::PREFERRED_MIME_NAME,
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                pub fn primary_name(&self) -> &'static UsAsciiStr {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => <[<$charset Charset>] as CharsetTrait>::PRIMARY_NAME,
+                    )+
+                    }
+                }
+            }
+
             item! {
                 pub fn decode_from_byte_slice<'str>(
                     &self,
@@ -75,6 +604,81 @@ macro_rules! enums {
                     }
                 }
             }
+
+            item! {
+                /// # Safety
+                ///
+                /// The bytes must be valid in the charset.
+                unsafe fn decode_from_byte_vec_unchecked(&self, value: Vec<u8>) -> String {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => String::$charset([<$charset String>]::decode_unchecked(value)),
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                /// Creates a decoder for input in the charset that arrives in chunks.
+                pub fn decoder(&self) -> Decoder {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => Decoder::$charset([<$charset Charset>]::decoder()),
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                /// Creates an encoder for text that arrives in chunks.
+                pub fn encoder(&self) -> Encoder {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => Encoder::$charset([<$charset Charset>]::encoder()),
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                /// Decodes the bytes into Unicode, substituting each invalid sequence according to
+                /// `replacement` instead of failing.
+                ///
+                /// Returns the decoded text along with the number of substitutions that were made.
+                pub fn decode_lossy<'str>(
+                    &self,
+                    value: &'str [u8],
+                    replacement: Replacement,
+                ) -> (Cow<'str, str>, usize) {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => [<$charset String>]::decode_lossy(value, replacement),
+                    )+
+                    }
+                }
+            }
+
+            item! {
+                /// Encodes Unicode text into the charset, failing on the first character that the
+                /// charset cannot represent.
+                pub fn encode(&self, value: &str) -> Result<String, EncodeError> {
+                    use self::Charset::*;
+
+                    match self {
+                    $(
+                        $charset => Ok(String::$charset([<$charset String>]::encode(value)?)),
+                    )+
+                    }
+                }
+            }
         }
 
         item! {
@@ -86,6 +690,66 @@ macro_rules! enums {
             }
         }
 
+        impl Character {
+            /// Returns the character of `charset` encoded by `value` on its own, or [`None`] if
+            /// the byte is not a complete character in the charset.
+            pub fn from_byte(charset: Charset, value: u8) -> Option<Character> {
+                match charset {
+                $(
+                    Charset::$charset => {
+                        item! {
+                            [<$charset Character>]::from_byte(value).map(Character::$charset)
+                        }
+                    }
+                )+
+                }
+            }
+
+            pub fn from_char(charset: Charset, value: char) -> Result<Character, EncodeError> {
+                match charset {
+                $(
+                    Charset::$charset => {
+                        item! {
+                            Ok(Character::$charset([<$charset Character>]::try_from(value)?))
+                        }
+                    }
+                )+
+                }
+            }
+
+            pub fn charset(&self) -> Charset {
+                use self::Character::*;
+
+                match self {
+                $(
+                    $charset(_) => Charset::$charset,
+                )+
+                }
+            }
+
+            /// Returns the byte encoding the character, or [`None`] if its charset does not
+            /// encode the character as a single byte.
+            pub fn to_byte(&self) -> Option<u8> {
+                use self::Character::*;
+
+                match *self {
+                $(
+                    $charset(character) => character.to_byte(),
+                )+
+                }
+            }
+
+            pub fn to_char(&self) -> char {
+                use self::Character::*;
+
+                match *self {
+                $(
+                    $charset(character) => character.to_char(),
+                )+
+                }
+            }
+        }
+
         impl Display for Character {
             fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
                 use self::Character::*;
@@ -135,18 +799,86 @@ macro_rules! enums {
             }
         }
 
-        impl Str<'_> {
+        impl<'str> Str<'str> {
+            pub fn char_indices(&self) -> CharIndices<'str> {
+                use self::Str::*;
+
+                match *self {
+                $(
+                    $charset(str) => CharIndices::$charset(str.char_indices()),
+                )+
+                }
+            }
+
+            pub fn chars(&self) -> Chars<'str> {
+                Chars {
+                    char_indices: self.char_indices(),
+                }
+            }
+
             pub fn to_owned(&self) -> String {
                 use self::Str::*;
 
-                match *self {
+                match *self {
+                $(
+                    $charset(str) => String::$charset(str.to_owned()),
+                )+
+                }
+            }
+
+            /// Converts the string into Unicode, borrowing whenever the underlying bytes are
+            /// already valid UTF-8.
+            pub fn to_unicode(&self) -> Cow<'str, str> {
+                use self::Str::*;
+
+                match *self {
+                $(
+                    $charset(str) => str.to_unicode(),
+                )+
+                }
+            }
+        }
+
+        item! {
+            /// An iterator over the characters of a [`Str`] along with their byte indices.
+            #[derive(Clone, Debug)]
+            pub enum CharIndices<'str> {
+            $(
+                $charset(StrCharIndices<'str, [<$charset Str>]>),
+            )+
+            }
+        }
+
+        impl Iterator for CharIndices<'_> {
+            type Item = (usize, Character);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                use self::CharIndices::*;
+
+                match self {
                 $(
-                    $charset(str) => String::$charset(str.to_owned()),
+                    $charset(char_indices) => char_indices
+                        .next()
+                        .map(|(index, character)| (index, Character::$charset(character))),
                 )+
                 }
             }
         }
 
+        /// An iterator over the characters of a [`Str`].
+        #[derive(Clone, Debug)]
+        pub struct Chars<'str> {
+            char_indices: CharIndices<'str>,
+        }
+
+        impl Iterator for Chars<'_> {
+            type Item = Character;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.char_indices.next().map(|(_, character)| character)
+            }
+        }
+
         impl AsRef<[u8]> for Str<'_> {
             fn as_ref(&self) -> &[u8] {
                 use self::Str::*;
@@ -254,6 +986,38 @@ macro_rules! enums {
             }
         }
 
+        impl DecodeError {
+            pub fn error_len(&self) -> Option<usize> {
+                use self::DecodeError::*;
+
+                match self {
+                $(
+                    $charset(error) => error.error_len(),
+                )+
+                }
+            }
+
+            pub fn invalid_bytes(&self) -> &[u8] {
+                use self::DecodeError::*;
+
+                match self {
+                $(
+                    $charset(error) => error.invalid_bytes(),
+                )+
+                }
+            }
+
+            pub fn valid_up_to(&self) -> usize {
+                use self::DecodeError::*;
+
+                match self {
+                $(
+                    $charset(error) => error.valid_up_to(),
+                )+
+                }
+            }
+        }
+
         impl Display for DecodeError {
             fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
                 use self::DecodeError::*;
@@ -287,9 +1051,254 @@ macro_rules! enums {
             }
         }
     )+
+
+        item! {
+            /// A stateful decoder for input that arrives in chunks.
+            ///
+            /// Byte sequences split across chunks are carried over to the next call. Once an error
+            /// has been returned, the decoder should no longer be used.
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            pub enum Decoder {
+            $(
+                $charset([<$charset Decoder>]),
+            )+
+            }
+        }
+
+        impl Decoder {
+            pub fn charset(&self) -> Charset {
+                use self::Decoder::*;
+
+                match self {
+                $(
+                    $charset(_) => Charset::$charset,
+                )+
+                }
+            }
+
+            /// Validates the next chunk of input, appending the bytes of every complete character
+            /// to `output`.
+            pub fn decode_to_bytes(
+                &mut self,
+                input: &[u8],
+                output: &mut Vec<u8>,
+            ) -> Result<(), DecodeError> {
+                use self::Decoder::*;
+
+                match self {
+                $(
+                    $charset(decoder) => Ok(decoder.decode_to_bytes(input, output)?),
+                )+
+                }
+            }
+
+            /// Decodes the next chunk of input, appending every complete character to `output`.
+            pub fn decode_to_unicode(
+                &mut self,
+                input: &[u8],
+                output: &mut StdString,
+            ) -> Result<(), DecodeError> {
+                use self::Decoder::*;
+
+                match self {
+                $(
+                    $charset(decoder) => Ok(decoder.decode_to_unicode(input, output)?),
+                )+
+                }
+            }
+
+            /// Signals the end of the input, failing if it ended in the middle of a byte sequence.
+            pub fn finish(self) -> Result<(), DecodeError> {
+                use self::Decoder::*;
+
+                match self {
+                $(
+                    $charset(decoder) => Ok(decoder.finish()?),
+                )+
+                }
+            }
+        }
+
+        item! {
+            /// A stateful encoder for text that arrives in chunks, writing into caller provided
+            /// buffers.
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            pub enum Encoder {
+            $(
+                $charset([<$charset Encoder>]),
+            )+
+            }
+        }
+
+        impl Encoder {
+            pub fn charset(&self) -> Charset {
+                use self::Encoder::*;
+
+                match self {
+                $(
+                    $charset(_) => Charset::$charset,
+                )+
+                }
+            }
+
+            /// Encodes as much of `input` as fits into `output`, returning the number of bytes of
+            /// input that were read and the number of bytes of output that were written.
+            ///
+            /// Encoding stops before the first character that cannot be encoded, so an error is
+            /// only returned if the very first character of `input` cannot be encoded.
+            pub fn encode(
+                &mut self,
+                input: &str,
+                output: &mut [u8],
+            ) -> Result<(usize, usize), EncodeError> {
+                use self::Encoder::*;
+
+                match self {
+                $(
+                    $charset(encoder) => Ok(encoder.encode(input, output)?),
+                )+
+                }
+            }
+
+            /// Writes whatever is needed to end the output, returning the number of bytes
+            /// written, or [`None`] if `output` is too small.
+            pub fn finish(&mut self, output: &mut [u8]) -> Option<usize> {
+                use self::Encoder::*;
+
+                match self {
+                $(
+                    $charset(encoder) => encoder.finish(output),
+                )+
+                }
+            }
+        }
+
+        item! {
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            pub enum EncodeError {
+            $(
+                $charset([<$charset EncodeError>]),
+            )+
+            }
+        }
+
+        impl EncodeError {
+            pub fn character(&self) -> char {
+                use self::EncodeError::*;
+
+                match self {
+                $(
+                    $charset(error) => error.character(),
+                )+
+                }
+            }
+
+            pub fn valid_up_to(&self) -> usize {
+                use self::EncodeError::*;
+
+                match self {
+                $(
+                    $charset(error) => error.valid_up_to(),
+                )+
+                }
+            }
+        }
+
+        impl Display for EncodeError {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                use self::EncodeError::*;
+
+                match self {
+                $(
+                    $charset(error) => error.fmt(formatter),
+                )+
+                }
+            }
+        }
+
+        impl Error for EncodeError {
+            fn cause(&self) -> Option<&dyn Error> {
+                use self::EncodeError::*;
+
+                match self {
+                $(
+                    $charset(error) => Some(error),
+                )+
+                }
+            }
+        }
+
+    $(
+        item! {
+            impl From<[<$charset EncodeError>]> for EncodeError {
+                fn from(value: [<$charset EncodeError>]) -> Self {
+                    EncodeError::$charset(value)
+                }
+            }
+        }
+    )+
     };
 }
 
+impl FromStr for Charset {
+    type Err = ParseCharsetError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Charset::from_name(value.as_bytes()).ok_or(ParseCharsetError)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseCharsetError;
+
+impl Display for ParseCharsetError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        formatter.write_str("unknown charset name")
+    }
+}
+
+impl Error for ParseCharsetError {}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TranscodeError {
+    Decode(DecodeError),
+    Encode(EncodeError),
+}
+
+impl Display for TranscodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        use self::TranscodeError::*;
+
+        match self {
+            Decode(error) => error.fmt(formatter),
+            Encode(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl Error for TranscodeError {
+    fn cause(&self) -> Option<&dyn Error> {
+        use self::TranscodeError::*;
+
+        match self {
+            Decode(error) => Some(error),
+            Encode(error) => Some(error),
+        }
+    }
+}
+
+impl From<DecodeError> for TranscodeError {
+    fn from(value: DecodeError) -> Self {
+        TranscodeError::Decode(value)
+    }
+}
+
+impl From<EncodeError> for TranscodeError {
+    fn from(value: EncodeError) -> Self {
+        TranscodeError::Encode(value)
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum CowStr<'str> {
     Borrowed(Str<'str>),
@@ -347,9 +1356,349 @@ impl From<String> for CowStr<'static> {
 }
 
 enums! {
+    Big5,
+    Big5Hkscs,
+    EucJp,
+    EucKr,
+    Gb2312,
+    Gb18030,
+    Gbk,
+    Ibm037,
+    Ibm273,
+    Ibm500,
+    Ibm1047,
+    Ibm01140,
+    Ibm01141,
+    Ibm01142,
+    Ibm01143,
+    Ibm01144,
+    Ibm01145,
+    Ibm01146,
+    Ibm01147,
+    Ibm01148,
+    Ibm01149,
+    Iso2022Jp,
+    Iso2022Kr,
     Iso8859_1_1987,
     Iso8859_2_1987,
     Iso8859_3_1988,
+    Iso8859_4_1988,
+    Iso8859_5_1988,
+    Iso8859_6_1987,
+    Iso8859_7_1987,
+    Iso8859_8_1988,
+    Iso8859_9_1989,
+    Iso8859_10,
+    Iso8859_13,
+    Iso8859_14,
+    Iso8859_15,
+    Iso8859_16,
+    Koi8R,
+    Koi8U,
+    KsC5601_1987,
+    ShiftJis,
     UsAscii,
     Utf8,
+    Utf16,
+    Utf16Be,
+    Utf16Le,
+    Utf32,
+    Utf32Be,
+    Utf32Le,
+    Windows31J,
+    Windows1250,
+    Windows1251,
+    Windows1252,
+    Windows1253,
+    Windows1254,
+    Windows1255,
+    Windows1256,
+    Windows1257,
+    Windows1258,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::charset::Alias as AliasTrait;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn from_name_resolves_every_name() {
+        for &charset in Charset::all() {
+            let names = Some(charset.primary_name())
+                .into_iter()
+                .chain(charset.preferred_mime_name())
+                .chain(charset.aliases().iter().cloned());
+
+            for name in names {
+                let name = name.as_ref();
+                assert_eq!(Charset::from_name(name), Some(charset), "{:?}", name);
+                let uppercase = name.to_ascii_uppercase();
+                assert_eq!(Charset::from_name(&uppercase), Some(charset), "{:?}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn from_name_rejects_unknown_names() {
+        assert_eq!(Charset::from_name(b""), None);
+        assert_eq!(Charset::from_name(b"utf-8 "), None);
+        assert_eq!(Charset::from_name(&[b'a'; 100]), None);
+    }
+
+    #[test]
+    fn from_mib_enum_resolves_every_charset() {
+        let mut mib_enums = BTreeSet::new();
+
+        for &charset in Charset::all() {
+            assert_eq!(Charset::from_mib_enum(charset.mib_enum()), Some(charset));
+            assert!(mib_enums.insert(charset.mib_enum()), "{:?}", charset);
+        }
+
+        assert_eq!(Charset::from_mib_enum(3), Some(Charset::UsAscii));
+        assert_eq!(Charset::from_mib_enum(0), None);
+    }
+
+    #[test]
+    fn metadata_matches_the_registry() {
+        let us_ascii = Charset::UsAscii;
+        assert_eq!(us_ascii.mib_enum(), 3);
+        assert_eq!(us_ascii.primary_name().as_ref(), b"US-ASCII");
+        assert_eq!(
+            us_ascii.preferred_mime_name().map(AsRef::as_ref),
+            Some(&b"US-ASCII"[..])
+        );
+        assert!(us_ascii
+            .aliases()
+            .iter()
+            .any(|alias| alias.as_ref() == b"us"));
+        assert!(us_ascii.is_ascii_compatible());
+        assert!(us_ascii.is_mime_text_suitable());
+
+        let windows_31j = Charset::Windows31J;
+        assert_eq!(windows_31j.mib_enum(), 2024);
+        assert_eq!(windows_31j.preferred_mime_name(), None);
+        assert!(!windows_31j.is_mime_text_suitable());
+
+        assert!(!Charset::Utf16.is_ascii_compatible());
+    }
+
+    #[test]
+    fn all_lists_every_charset_once() {
+        let all: BTreeSet<_> = Charset::all().iter().cloned().collect();
+        assert_eq!(all.len(), Charset::all().len());
+
+        for &(name, charset) in names::NAMES {
+            assert!(all.contains(&charset), "{:?}", name);
+        }
+    }
+
+    fn assert_alias_names<A: AliasTrait>(charset: Charset) {
+        let names: Vec<_> = A::all().iter().map(|alias| alias.name()).collect();
+        assert_eq!(names, charset.aliases(), "{:?}", charset);
+    }
+
+    #[test]
+    fn alias_all_matches_names() {
+        assert_alias_names::<Ibm01142Alias>(Charset::Ibm01142);
+        assert_alias_names::<Iso8859_1_1987Alias>(Charset::Iso8859_1_1987);
+        assert_alias_names::<UsAsciiAlias>(Charset::UsAscii);
+        assert_alias_names::<Utf8Alias>(Charset::Utf8);
+        assert_eq!(UsAsciiAlias::all().len(), 10);
+    }
+
+    fn is_borrowed(text: Cow<'_, str>) -> bool {
+        match text {
+            Cow::Borrowed(_) => true,
+            Cow::Owned(_) => false,
+        }
+    }
+
+    #[test]
+    fn to_unicode_borrows_only_valid_utf8() {
+        let str = Charset::UsAscii.decode_from_byte_slice(b"abc").unwrap();
+        let text = str.to_unicode();
+        assert_eq!(text, "abc");
+        assert!(is_borrowed(text));
+
+        let str = Charset::Utf8
+            .decode_from_byte_slice("a\u{e9}".as_bytes())
+            .unwrap();
+        let text = str.to_unicode();
+        assert_eq!(text, "a\u{e9}");
+        assert!(is_borrowed(text));
+
+        let str = Charset::Iso8859_1_1987
+            .decode_from_byte_slice(b"a\xe9")
+            .unwrap();
+        let text = str.to_unicode();
+        assert_eq!(text, "a\u{e9}");
+        assert!(!is_borrowed(text));
+    }
+
+    #[test]
+    fn decode_errors_report_position_and_bytes() {
+        let error = Charset::Utf8
+            .decode_from_byte_slice(b"ab\xe2\x82")
+            .unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\xe2\x82");
+        assert_eq!(error.error_len(), None);
+
+        let error = Charset::Utf8
+            .decode_from_byte_slice(b"ab\xe2\x82c")
+            .unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.error_len(), Some(2));
+
+        let error = Charset::UsAscii
+            .decode_from_byte_slice(b"abc\x80d")
+            .unwrap_err();
+        assert_eq!(error.valid_up_to(), 3);
+        assert_eq!(error.invalid_bytes(), b"\x80");
+        assert_eq!(error.error_len(), Some(1));
+    }
+
+    #[test]
+    fn char_indices_follow_escape_sequences() {
+        let str = Charset::Iso2022Jp
+            .decode_from_byte_slice(b"a\x1b$BF|K\\\x1b(Bb")
+            .unwrap();
+        let characters: Vec<_> = str
+            .char_indices()
+            .map(|(index, character)| (index, character.to_char()))
+            .collect();
+        assert_eq!(
+            characters,
+            [(0, 'a'), (1, '\u{65e5}'), (6, '\u{672c}'), (8, 'b')]
+        );
+        assert_eq!(str.chars().count(), 4);
+    }
+
+    #[test]
+    fn characters_convert_to_and_from_unicode() {
+        let character = Character::from_char(Charset::Iso8859_3_1988, '\u{126}').unwrap();
+        assert_eq!(character.charset(), Charset::Iso8859_3_1988);
+        assert_eq!(character.to_byte(), Some(0xa1));
+        assert_eq!(character.to_char(), '\u{126}');
+        assert_eq!(
+            Character::from_byte(Charset::Iso8859_3_1988, 0xa1),
+            Some(character)
+        );
+        assert_eq!(Character::from_byte(Charset::Iso8859_3_1988, 0xa5), None);
+
+        let error = Character::from_char(Charset::Iso8859_3_1988, '\u{20ac}').unwrap_err();
+        assert_eq!(error.character(), '\u{20ac}');
+
+        let character = Character::from_char(Charset::ShiftJis, '\u{65e5}').unwrap();
+        assert_eq!(character.to_byte(), None);
+    }
+
+    #[test]
+    fn transcodes_between_charsets() {
+        let string = Charset::transcode(Charset::Iso8859_2_1987, Charset::Utf8, b"a\xb1").unwrap();
+        assert_eq!(Vec::from(string), "a\u{105}".as_bytes());
+
+        let string = Charset::transcode(Charset::Utf8, Charset::Iso8859_1_1987, b"abc").unwrap();
+        assert_eq!(Vec::from(string), b"abc");
+
+        match Charset::transcode(Charset::Utf8, Charset::UsAscii, "a\u{e9}".as_bytes()) {
+            Err(TranscodeError::Encode(error)) => {
+                assert_eq!(error.character(), '\u{e9}');
+                assert_eq!(error.valid_up_to(), 1);
+            }
+            result => panic!("{:?}", result),
+        }
+
+        match Charset::transcode(Charset::UsAscii, Charset::Utf8, b"a\x80") {
+            Err(TranscodeError::Decode(error)) => assert_eq!(error.valid_up_to(), 1),
+            result => panic!("{:?}", result),
+        }
+    }
+
+    #[test]
+    fn encoders_write_what_fits_and_fail_only_on_the_first_character() {
+        let mut encoder = Charset::Utf8.encoder();
+        let mut output = [0; 4];
+        assert_eq!(encoder.encode("abc\u{20ac}", &mut output).unwrap(), (3, 3));
+        assert_eq!(&output[..3], b"abc");
+
+        let mut encoder = Charset::UsAscii.encoder();
+        let mut output = [0; 8];
+        assert_eq!(encoder.encode("ab\u{e9}c", &mut output).unwrap(), (2, 2));
+        let error = encoder.encode("\u{e9}c", &mut output).unwrap_err();
+        assert_eq!(error.character(), '\u{e9}');
+        assert_eq!(error.valid_up_to(), 2);
+    }
+
+    #[test]
+    fn encoders_need_room_for_escape_sequences() {
+        let mut encoder = Charset::Iso2022Jp.encoder();
+        let mut output = [0; 5];
+        assert_eq!(
+            encoder.encode("\u{65e5}", &mut output[..4]).unwrap(),
+            (0, 0)
+        );
+        assert_eq!(encoder.encode("\u{65e5}", &mut output).unwrap(), (3, 5));
+        assert_eq!(&output, b"\x1b$BF|");
+
+        assert_eq!(encoder.finish(&mut output[..2]), None);
+        assert_eq!(encoder.finish(&mut output), Some(3));
+        assert_eq!(&output[..3], b"\x1b(B");
+    }
+
+    fn decode_chunks<'a>(
+        charset: Charset,
+        chunks: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Result<StdString, DecodeError> {
+        let mut decoder = charset.decoder();
+        let mut output = StdString::new();
+
+        for chunk in chunks {
+            decoder.decode_to_unicode(chunk, &mut output)?;
+        }
+
+        decoder.finish()?;
+        Ok(output)
+    }
+
+    fn assert_decodes_byte_by_byte(charset: Charset, bytes: &[u8], text: &str) {
+        let whole = decode_chunks(charset, Some(bytes)).unwrap();
+        let byte_by_byte = decode_chunks(charset, bytes.chunks(1)).unwrap();
+        assert_eq!(whole, text, "{:?}", charset);
+        assert_eq!(byte_by_byte, text, "{:?}", charset);
+    }
+
+    #[test]
+    fn decoders_carry_sequences_across_chunks() {
+        let utf8 = b"a\xc3\xa9\xe2\x82\xac\xf0\x9f\x98\x80";
+        assert_decodes_byte_by_byte(Charset::Utf8, utf8, "a\u{e9}\u{20ac}\u{1f600}");
+
+        let shift_jis = b"a\xb1\x93\xfa\x96{";
+        assert_decodes_byte_by_byte(Charset::ShiftJis, shift_jis, "a\u{ff71}\u{65e5}\u{672c}");
+
+        let gb18030 = b"a\x810\x810\x949\xfc6\xd6\xd0";
+        assert_decodes_byte_by_byte(Charset::Gb18030, gb18030, "a\u{80}\u{1f600}\u{4e2d}");
+
+        let iso_2022_jp = b"a\x1b$BF|K\\\x1b(Bb";
+        assert_decodes_byte_by_byte(Charset::Iso2022Jp, iso_2022_jp, "a\u{65e5}\u{672c}b");
+    }
+
+    #[test]
+    fn decoders_reject_incomplete_tail_at_finish() {
+        let cases: &[(Charset, &[u8])] = &[
+            (Charset::Utf8, b"a\xf0\x9f\x98"),
+            (Charset::ShiftJis, b"a\x93"),
+            (Charset::Gb18030, b"a\x949\xfc"),
+            (Charset::Iso2022Jp, b"a\x1b$BF"),
+        ];
+
+        for &(charset, bytes) in cases {
+            for chunks in [bytes.chunks(bytes.len()), bytes.chunks(1)].iter().cloned() {
+                let error = decode_chunks(charset, chunks).unwrap_err();
+                assert_eq!(error.error_len(), None, "{:?}", charset);
+            }
+        }
+    }
 }
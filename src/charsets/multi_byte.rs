@@ -0,0 +1,453 @@
+//! The implementation shared by the ASCII-compatible charsets that encode characters as variable
+//! length byte sequences, driven by functions that decode and encode a single sequence.
+
+/// Defines the charset of the enclosing module, along with its string, character, error, decoder,
+/// and encoder types.
+///
+/// `next` decodes the [`Sequence`] at the start of some bytes, returning [`None`] if there are no
+/// bytes left. `encode_character` returns the bytes of a character along with their length, or
+/// [`None`] if the character cannot be encoded. `pairs` optionally lists the characters that are
+/// encoded together as a single sequence, which `next` decodes as [`Sequence::Pair`].
+///
+/// Error messages name the charset by its preferred MIME name if it has one, as that is usually the
+/// more familiar name.
+///
+/// [`Sequence`]: crate::charsets::sequence::Sequence
+/// [`Sequence::Pair`]: crate::charsets::sequence::Sequence::Pair
+macro_rules! multi_byte_charset {
+    (
+        primary_name: $primary_name:literal,
+        $(preferred_mime_name: $preferred_mime_name:literal,)?
+        mib_enum: $mib_enum:literal,
+        next: $next:path,
+        encode_character: $encode_character:path,
+        $(pairs: $pairs:path,)?
+        aliases: [
+            $(($variant:ident, $value:expr);)+
+        ],
+    ) => {
+        use std::borrow::{Borrow, Cow, ToOwned};
+        use std::convert::TryFrom;
+        use std::error::Error;
+        use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+        use std::ops::Deref;
+        use std::str;
+        use std::string::String as StdString;
+
+        use crate::charset::private::Sealed;
+        use crate::charset::{
+            Character as CharacterTrait, Charset as CharsetTrait,
+            DecodeError as DecodeErrorTrait, Decoder as DecoderTrait,
+            EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
+            Str as StrTrait, String as StringTrait,
+        };
+        use crate::charsets::sequence::{self, Sequence, SequenceDecoder, SequenceError};
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct Charset;
+
+        impl CharsetTrait for Charset {
+            type Alias = Alias;
+            type Character = Character;
+            type DecodeError = DecodeError;
+            type Decoder = Decoder;
+            type EncodeError = EncodeError;
+            type Encoder = Encoder;
+            type Str = Str;
+            type String = String;
+
+            const IS_ASCII_COMPATIBLE: bool = true;
+            const MIB_ENUM: u16 = $mib_enum;
+            const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> =
+                multi_byte_charset!(@preferred_mime_name $($preferred_mime_name)?);
+            const PRIMARY_NAME: &'static UsAsciiStr =
+                unsafe { UsAsciiStr::from_bytes_unchecked($primary_name.as_bytes()) };
+        }
+
+        impl Sealed for Charset {}
+
+        #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Character(char);
+
+        impl CharacterTrait for Character {
+            fn from_byte(value: u8) -> Option<Self> {
+                match $next(&[value])? {
+                    Sequence::Character(character, _) => Some(Character(character)),
+                    _ => None,
+                }
+            }
+
+            fn to_byte(self) -> Option<u8> {
+                match $encode_character(self.0)? {
+                    (bytes, 1) => Some(bytes[0]),
+                    _ => None,
+                }
+            }
+
+            fn to_char(self) -> char {
+                self.0
+            }
+        }
+
+        impl Debug for Character {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_char(self.0)
+            }
+        }
+
+        impl Display for Character {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_char(self.0)
+            }
+        }
+
+        impl TryFrom<char> for Character {
+            type Error = EncodeError;
+
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                match $encode_character(value) {
+                    Some(_) => Ok(Character(value)),
+                    None => Err(EncodeError {
+                        character: value,
+                        valid_up_to: 0,
+                    }),
+                }
+            }
+        }
+
+        #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Str([u8]);
+
+        impl AsRef<[u8]> for Str {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl Debug for Str {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                for character in self.chars() {
+                    Debug::fmt(&character, formatter)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Display for Str {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(self, formatter)
+            }
+        }
+
+        impl Sealed for Str {}
+
+        impl StrTrait for Str {
+            type Character = Character;
+            type DecodeError = DecodeError;
+            /// Whether the first character of the pair at the index has already been yielded.
+            type State = bool;
+            type String = String;
+
+            fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
+                sequence::validate(value, $next).map_err(DecodeError)?;
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            unsafe fn decode_unchecked(value: &[u8]) -> &Self {
+                &*(value as *const [u8] as *const Str)
+            }
+
+            fn next_character(
+                &self,
+                index: usize,
+                state: &mut Self::State,
+            ) -> Option<(Self::Character, usize)> {
+                match $next(&self.0[index..])? {
+                    Sequence::Character(character, length) => Some((Character(character), length)),
+                    Sequence::Pair(_, second, length) if *state => {
+                        *state = false;
+                        Some((Character(second), length))
+                    }
+                    Sequence::Pair(first, _, _) => {
+                        *state = true;
+                        Some((Character(first), 0))
+                    }
+                    _ => unreachable!(concat!(
+                        "string contains invalid ",
+                        multi_byte_charset!(@name $primary_name $($preferred_mime_name)?)
+                    )),
+                }
+            }
+
+            fn to_unicode(&self) -> Cow<'_, str> {
+                if self.0.is_ascii() {
+                    // Unsafe justification: ASCII is a subset of UTF-8.
+                    Cow::Borrowed(unsafe { str::from_utf8_unchecked(&self.0) })
+                } else {
+                    Cow::Owned(self.chars().map(Character::to_char).collect())
+                }
+            }
+        }
+
+        impl ToOwned for Str {
+            type Owned = String;
+
+            fn to_owned(&self) -> Self::Owned {
+                String(self.0.to_owned())
+            }
+        }
+
+        #[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct String(Vec<u8>);
+
+        impl AsRef<[u8]> for String {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl AsRef<Str> for String {
+            fn as_ref(&self) -> &Str {
+                &self
+            }
+        }
+
+        impl Borrow<Str> for String {
+            fn borrow(&self) -> &Str {
+                &self
+            }
+        }
+
+        impl Debug for String {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(&**self, formatter)
+            }
+        }
+
+        impl Deref for String {
+            type Target = <Self as StringTrait>::Str;
+
+            fn deref(&self) -> &Self::Target {
+                unsafe { Str::decode_unchecked(&*self.0) }
+            }
+        }
+
+        impl Display for String {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(self, formatter)
+            }
+        }
+
+        impl<'str> From<&'str Str> for String {
+            fn from(value: &'str Str) -> Self {
+                value.to_owned()
+            }
+        }
+
+        impl From<String> for Vec<u8> {
+            fn from(value: String) -> Self {
+                value.0
+            }
+        }
+
+        impl Sealed for String {}
+
+        impl StringTrait for String {
+            type DecodeError = DecodeError;
+            type EncodeError = EncodeError;
+            type Str = Str;
+
+            fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
+                if let Err(error) = sequence::validate(&value, $next) {
+                    return Err((value, DecodeError(error)));
+                }
+
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+                if sequence::validate(value, $next).is_ok() {
+                    return (unsafe { Str::decode_unchecked(value) }.to_unicode(), 0);
+                }
+
+                let (text, replacements) = sequence::decode_lossy(value, $next, replacement);
+                (Cow::Owned(text), replacements)
+            }
+
+            unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
+                String(value)
+            }
+
+            fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+                let pairs: &[sequence::Pair] = multi_byte_charset!(@pairs $($pairs)?);
+
+                sequence::encode_all(value, pairs, $encode_character)
+                    .map(String)
+                    .map_err(|(character, valid_up_to)| EncodeError {
+                        character,
+                        valid_up_to,
+                    })
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct DecodeError(SequenceError);
+
+        impl DecodeErrorTrait for DecodeError {
+            fn error_len(&self) -> Option<usize> {
+                self.0.error_len()
+            }
+
+            fn invalid_bytes(&self) -> &[u8] {
+                self.0.invalid_bytes()
+            }
+
+            fn valid_up_to(&self) -> usize {
+                self.0.valid_up_to()
+            }
+        }
+
+        impl Display for DecodeError {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    concat!(
+                        "{} ",
+                        multi_byte_charset!(@name $primary_name $($preferred_mime_name)?),
+                        " sequence {:02x?} at index {}"
+                    ),
+                    self.0.kind(),
+                    self.invalid_bytes(),
+                    self.valid_up_to()
+                )
+            }
+        }
+
+        impl Error for DecodeError {}
+
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct EncodeError {
+            character: char,
+            valid_up_to: usize,
+        }
+
+        impl EncodeErrorTrait for EncodeError {
+            fn character(&self) -> char {
+                self.character
+            }
+
+            fn valid_up_to(&self) -> usize {
+                self.valid_up_to
+            }
+        }
+
+        impl Display for EncodeError {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    concat!(
+                        "character {:?} at index {} cannot be encoded as ",
+                        multi_byte_charset!(@name $primary_name $($preferred_mime_name)?)
+                    ),
+                    self.character, self.valid_up_to
+                )
+            }
+        }
+
+        impl Error for EncodeError {}
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Decoder(SequenceDecoder);
+
+        impl DecoderTrait for Decoder {
+            type DecodeError = DecodeError;
+
+            fn decode_to_bytes(
+                &mut self,
+                input: &[u8],
+                output: &mut Vec<u8>,
+            ) -> Result<(), Self::DecodeError> {
+                self.0
+                    .decode(input, $next, |bytes, _| output.extend_from_slice(bytes))
+                    .map_err(DecodeError)
+            }
+
+            fn decode_to_unicode(
+                &mut self,
+                input: &[u8],
+                output: &mut StdString,
+            ) -> Result<(), Self::DecodeError> {
+                self.0
+                    .decode(input, $next, |_, characters| output.extend(characters))
+                    .map_err(DecodeError)
+            }
+
+            fn finish(self) -> Result<(), Self::DecodeError> {
+                self.0.finish().map_err(DecodeError)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Encoder {
+            pending: Option<char>,
+            position: usize,
+        }
+
+        impl EncoderTrait for Encoder {
+            type EncodeError = EncodeError;
+
+            fn encode(
+                &mut self,
+                input: &str,
+                output: &mut [u8],
+            ) -> Result<(usize, usize), Self::EncodeError> {
+                let (read, written) = sequence::encode(
+                    input,
+                    output,
+                    multi_byte_charset!(@pairs $($pairs)?),
+                    &mut self.pending,
+                    $encode_character,
+                )
+                .map_err(|character| EncodeError {
+                    character,
+                    valid_up_to: self.position,
+                })?;
+
+                self.position += read;
+                Ok((read, written))
+            }
+
+            fn finish(&mut self, output: &mut [u8]) -> Option<usize> {
+                sequence::finish(output, &mut self.pending, $encode_character)
+            }
+        }
+
+        aliases! {
+            Alias,
+
+            $(($variant, $value);)+
+        }
+    };
+
+    (@name $primary_name:literal) => {
+        $primary_name
+    };
+    (@name $primary_name:literal $preferred_mime_name:literal) => {
+        $preferred_mime_name
+    };
+
+    (@pairs) => {
+        &[]
+    };
+    (@pairs $pairs:path) => {
+        &$pairs
+    };
+
+    (@preferred_mime_name) => {
+        None
+    };
+    (@preferred_mime_name $name:literal) => {
+        Some(unsafe { UsAsciiStr::from_bytes_unchecked($name.as_bytes()) })
+    };
+}
@@ -0,0 +1,283 @@
+//! Lookup of charsets by name, through a hand-maintained list of the lowercased primary name,
+//! preferred MIME name, and aliases of every charset.
+
+use crate::charsets::Charset;
+
+/// The length of the longest registered name, beyond which no name can match.
+pub(crate) const MAX_NAME_LENGTH: usize = 45;
+
+/// Defines `from_lowercase_name` as a single match on the given names, which the tests check
+/// against the names registered by each charset.
+macro_rules! names {
+    ($($charset:ident => [$($name:literal),+ $(,)?],)+) => {
+        /// Looks up a charset by a name that has already been ASCII lowercased.
+        pub(crate) fn from_lowercase_name(name: &[u8]) -> Option<Charset> {
+            use self::Charset::*;
+
+            let charset = match name {
+            $(
+                $($name)|+ => $charset,
+            )+
+                _ => return None,
+            };
+
+            Some(charset)
+        }
+
+        /// Every name matched by [`from_lowercase_name`] along with its charset.
+        #[cfg(test)]
+        pub(crate) const NAMES: &[(&[u8], Charset)] = &[
+        $(
+            $(($name, Charset::$charset),)+
+        )+
+        ];
+    };
+}
+
+names! {
+    Big5 => [b"big5", b"csbig5"],
+    Big5Hkscs => [b"big5-hkscs", b"csbig5hkscs"],
+    EucJp => [
+        b"cseucpkdfmtjapanese",
+        b"euc-jp",
+        b"extended_unix_code_packed_format_for_japanese",
+    ],
+    EucKr => [b"cseuckr", b"euc-kr"],
+    Gb18030 => [b"csgb18030", b"gb18030"],
+    Gb2312 => [b"csgb2312", b"gb2312"],
+    Gbk => [b"cp936", b"csgbk", b"gbk", b"ms936", b"windows-936"],
+    Ibm01140 => [b"ccsid01140", b"cp01140", b"csibm01140", b"ebcdic-us-37+euro", b"ibm01140"],
+    Ibm01141 => [b"ccsid01141", b"cp01141", b"csibm01141", b"ebcdic-de-273+euro", b"ibm01141"],
+    Ibm01142 => [
+        b"ccsid01142",
+        b"cp01142",
+        b"csibm01142",
+        b"ebcdic-dk-277+euro",
+        b"ebcdic-no-277+euro",
+        b"ibm01142",
+    ],
+    Ibm01143 => [
+        b"ccsid01143",
+        b"cp01143",
+        b"csibm01143",
+        b"ebcdic-fi-278+euro",
+        b"ebcdic-se-278+euro",
+        b"ibm01143",
+    ],
+    Ibm01144 => [b"ccsid01144", b"cp01144", b"csibm01144", b"ebcdic-it-280+euro", b"ibm01144"],
+    Ibm01145 => [b"ccsid01145", b"cp01145", b"csibm01145", b"ebcdic-es-284+euro", b"ibm01145"],
+    Ibm01146 => [b"ccsid01146", b"cp01146", b"csibm01146", b"ebcdic-gb-285+euro", b"ibm01146"],
+    Ibm01147 => [b"ccsid01147", b"cp01147", b"csibm01147", b"ebcdic-fr-297+euro", b"ibm01147"],
+    Ibm01148 => [
+        b"ccsid01148",
+        b"cp01148",
+        b"csibm01148",
+        b"ebcdic-international-500+euro",
+        b"ibm01148",
+    ],
+    Ibm01149 => [b"ccsid01149", b"cp01149", b"csibm01149", b"ebcdic-is-871+euro", b"ibm01149"],
+    Ibm037 => [
+        b"cp037",
+        b"csibm037",
+        b"ebcdic-cp-ca",
+        b"ebcdic-cp-nl",
+        b"ebcdic-cp-us",
+        b"ebcdic-cp-wt",
+        b"ibm037",
+    ],
+    Ibm1047 => [b"csibm1047", b"ibm-1047", b"ibm1047"],
+    Ibm273 => [b"cp273", b"csibm273", b"ibm273"],
+    Ibm500 => [b"cp500", b"csibm500", b"ebcdic-cp-be", b"ebcdic-cp-ch", b"ibm500"],
+    Iso2022Jp => [b"csiso2022jp", b"iso-2022-jp"],
+    Iso2022Kr => [b"csiso2022kr", b"iso-2022-kr"],
+    Iso8859_10 => [
+        b"csisolatin6",
+        b"iso-8859-10",
+        b"iso-ir-157",
+        b"iso_8859-10:1992",
+        b"l6",
+        b"latin6",
+    ],
+    Iso8859_13 => [b"csiso885913", b"iso-8859-13"],
+    Iso8859_14 => [
+        b"csiso885914",
+        b"iso-8859-14",
+        b"iso-celtic",
+        b"iso-ir-199",
+        b"iso_8859-14",
+        b"iso_8859-14:1998",
+        b"l8",
+        b"latin8",
+    ],
+    Iso8859_15 => [b"csiso885915", b"iso-8859-15", b"iso_8859-15", b"latin-9"],
+    Iso8859_16 => [
+        b"csiso885916",
+        b"iso-8859-16",
+        b"iso-ir-226",
+        b"iso_8859-16",
+        b"iso_8859-16:2001",
+        b"l10",
+        b"latin10",
+    ],
+    Iso8859_1_1987 => [
+        b"cp819",
+        b"csisolatin1",
+        b"ibm819",
+        b"iso-8859-1",
+        b"iso-ir-100",
+        b"iso_8859-1",
+        b"iso_8859-1:1987",
+        b"l1",
+        b"latin1",
+    ],
+    Iso8859_2_1987 => [
+        b"csisolatin2",
+        b"iso-8859-2",
+        b"iso-ir-101",
+        b"iso_8859-2",
+        b"iso_8859-2:1987",
+        b"l2",
+        b"latin2",
+    ],
+    Iso8859_3_1988 => [
+        b"csisolatin3",
+        b"iso-8859-3",
+        b"iso-ir-109",
+        b"iso_8859-3",
+        b"iso_8859-3:1988",
+        b"l3",
+        b"latin3",
+    ],
+    Iso8859_4_1988 => [
+        b"csisolatin4",
+        b"iso-8859-4",
+        b"iso-ir-110",
+        b"iso_8859-4",
+        b"iso_8859-4:1988",
+        b"l4",
+        b"latin4",
+    ],
+    Iso8859_5_1988 => [
+        b"csisolatincyrillic",
+        b"cyrillic",
+        b"iso-8859-5",
+        b"iso-ir-144",
+        b"iso_8859-5",
+        b"iso_8859-5:1988",
+    ],
+    Iso8859_6_1987 => [
+        b"arabic",
+        b"asmo-708",
+        b"csisolatinarabic",
+        b"ecma-114",
+        b"iso-8859-6",
+        b"iso-ir-127",
+        b"iso_8859-6",
+        b"iso_8859-6:1987",
+    ],
+    Iso8859_7_1987 => [
+        b"csisolatingreek",
+        b"ecma-118",
+        b"elot_928",
+        b"greek",
+        b"greek8",
+        b"iso-8859-7",
+        b"iso-ir-126",
+        b"iso_8859-7",
+        b"iso_8859-7:1987",
+    ],
+    Iso8859_8_1988 => [
+        b"csisolatinhebrew",
+        b"hebrew",
+        b"iso-8859-8",
+        b"iso-ir-138",
+        b"iso_8859-8",
+        b"iso_8859-8:1988",
+    ],
+    Iso8859_9_1989 => [
+        b"csisolatin5",
+        b"iso-8859-9",
+        b"iso-ir-148",
+        b"iso_8859-9",
+        b"iso_8859-9:1989",
+        b"l5",
+        b"latin5",
+    ],
+    Koi8R => [b"cskoi8r", b"koi8-r"],
+    Koi8U => [b"cskoi8u", b"koi8-u"],
+    KsC5601_1987 => [
+        b"csksc56011987",
+        b"iso-ir-149",
+        b"korean",
+        b"ks_c_5601-1987",
+        b"ks_c_5601-1989",
+        b"ksc_5601",
+    ],
+    ShiftJis => [b"csshiftjis", b"ms_kanji", b"shift_jis"],
+    UsAscii => [
+        b"ansi_x3.4-1968",
+        b"ansi_x3.4-1986",
+        b"cp367",
+        b"csascii",
+        b"ibm367",
+        b"iso-ir-6",
+        b"iso646-us",
+        b"iso_646.irv:1991",
+        b"us",
+        b"us-ascii",
+    ],
+    Utf16 => [b"csutf16", b"utf-16"],
+    Utf16Be => [b"csutf16be", b"utf-16be"],
+    Utf16Le => [b"csutf16le", b"utf-16le"],
+    Utf32 => [b"csutf32", b"utf-32"],
+    Utf32Be => [b"csutf32be", b"utf-32be"],
+    Utf32Le => [b"csutf32le", b"utf-32le"],
+    Utf8 => [b"csutf8", b"utf-8"],
+    Windows1250 => [b"cswindows1250", b"windows-1250"],
+    Windows1251 => [b"cswindows1251", b"windows-1251"],
+    Windows1252 => [b"cswindows1252", b"windows-1252"],
+    Windows1253 => [b"cswindows1253", b"windows-1253"],
+    Windows1254 => [b"cswindows1254", b"windows-1254"],
+    Windows1255 => [b"cswindows1255", b"windows-1255"],
+    Windows1256 => [b"cswindows1256", b"windows-1256"],
+    Windows1257 => [b"cswindows1257", b"windows-1257"],
+    Windows1258 => [b"cswindows1258", b"windows-1258"],
+    Windows31J => [b"cswindows31j", b"windows-31j"],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn every_name_is_registered_by_its_charset() {
+        for &(name, charset) in NAMES {
+            let registered = Some(charset.primary_name())
+                .into_iter()
+                .chain(charset.preferred_mime_name())
+                .chain(charset.aliases().iter().cloned())
+                .any(|registered| registered.as_ref().eq_ignore_ascii_case(name));
+            assert!(registered, "{:?} is not a name of {:?}", name, charset);
+            assert!(name.len() <= MAX_NAME_LENGTH);
+        }
+    }
+
+    #[test]
+    fn every_registered_name_is_listed() {
+        let listed: BTreeSet<_> = NAMES.iter().map(|&(name, _)| name.to_vec()).collect();
+        assert_eq!(listed.len(), NAMES.len());
+
+        for &charset in Charset::all() {
+            let names = Some(charset.primary_name())
+                .into_iter()
+                .chain(charset.preferred_mime_name())
+                .chain(charset.aliases().iter().cloned());
+
+            for name in names {
+                let name = name.as_ref().to_ascii_lowercase();
+                assert!(listed.contains(&name), "{:?} is not listed", name);
+            }
+        }
+    }
+}
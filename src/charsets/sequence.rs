@@ -0,0 +1,395 @@
+//! Helpers shared by the charsets whose characters are encoded as variable length byte sequences.
+
+use std::string::String as StdString;
+
+use crate::charset::Replacement;
+
+/// The outcome of decoding the byte sequence at the start of some input.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Sequence {
+    /// A valid character along with the length of its encoding.
+    Character(char, usize),
+
+    /// Two characters encoded as a single sequence, such as a letter followed by a combining mark,
+    /// along with the length of their encoding.
+    Pair(char, char, usize),
+
+    /// An escape sequence of the given length, which changes how the bytes following it are
+    /// decoded instead of encoding a character itself.
+    Escape(usize),
+
+    /// The input ends in the middle of a sequence that could still turn out to be valid.
+    Incomplete,
+
+    /// An invalid sequence of the given length.
+    Invalid(usize),
+}
+
+/// Two characters that a charset encodes as a single sequence, along with the bytes of that
+/// sequence.
+pub(crate) type Pair = (char, char, &'static [u8]);
+
+/// An invalid or incomplete byte sequence found while decoding.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct SequenceError {
+    error_len: Option<u8>,
+    invalid_bytes: [u8; 4],
+    invalid_length: u8,
+    valid_up_to: usize,
+}
+
+impl SequenceError {
+    fn new(invalid: &[u8], error_len: Option<usize>, valid_up_to: usize) -> Self {
+        let mut invalid_bytes = [0; 4];
+        invalid_bytes[..invalid.len()].copy_from_slice(invalid);
+
+        SequenceError {
+            error_len: error_len.map(|length| length as u8),
+            invalid_bytes,
+            invalid_length: invalid.len() as u8,
+            valid_up_to,
+        }
+    }
+
+    pub(crate) fn error_len(&self) -> Option<usize> {
+        self.error_len.map(usize::from)
+    }
+
+    pub(crate) fn invalid_bytes(&self) -> &[u8] {
+        &self.invalid_bytes[..self.invalid_length as usize]
+    }
+
+    pub(crate) fn kind(&self) -> &'static str {
+        if self.error_len.is_some() {
+            "invalid"
+        } else {
+            "incomplete"
+        }
+    }
+
+    pub(crate) fn offset(self, offset: usize) -> Self {
+        SequenceError {
+            valid_up_to: self.valid_up_to + offset,
+            ..self
+        }
+    }
+
+    pub(crate) fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+/// Returns the sequence for a lead byte followed by `trail` when the pair does not encode a
+/// character. If the trail byte is ASCII, only the lead byte is considered invalid so that the
+/// trail byte starts the next character.
+pub(crate) fn invalid_pair(trail: u8) -> Sequence {
+    if trail.is_ascii() {
+        Sequence::Invalid(1)
+    } else {
+        Sequence::Invalid(2)
+    }
+}
+
+/// Checks that `value` consists entirely of valid sequences.
+pub(crate) fn validate<N>(value: &[u8], mut next: N) -> Result<(), SequenceError>
+where
+    N: FnMut(&[u8]) -> Option<Sequence>,
+{
+    let mut index = 0;
+
+    loop {
+        match next(&value[index..]) {
+            None => return Ok(()),
+            Some(Sequence::Character(_, length))
+            | Some(Sequence::Escape(length))
+            | Some(Sequence::Pair(_, _, length)) => index += length,
+            Some(Sequence::Incomplete) => {
+                return Err(SequenceError::new(&value[index..], None, index));
+            }
+            Some(Sequence::Invalid(length)) => {
+                let invalid = &value[index..index + length];
+                return Err(SequenceError::new(invalid, Some(length), index));
+            }
+        }
+    }
+}
+
+/// Decodes `value`, substituting each invalid sequence according to `replacement`.
+///
+/// Returns the decoded text along with the number of substitutions that were made.
+pub(crate) fn decode_lossy<N>(
+    value: &[u8],
+    mut next: N,
+    replacement: Replacement,
+) -> (StdString, usize)
+where
+    N: FnMut(&[u8]) -> Option<Sequence>,
+{
+    let mut index = 0;
+    let mut replacements = 0;
+    let mut text = StdString::with_capacity(value.len());
+
+    loop {
+        match next(&value[index..]) {
+            None => break,
+            Some(Sequence::Character(character, length)) => {
+                text.push(character);
+                index += length;
+            }
+            Some(Sequence::Escape(length)) => index += length,
+            Some(Sequence::Pair(first, second, length)) => {
+                text.push(first);
+                text.push(second);
+                index += length;
+            }
+            Some(Sequence::Incomplete) => {
+                text.push(replacement.character());
+                replacements += 1;
+                break;
+            }
+            Some(Sequence::Invalid(length)) => {
+                text.push(replacement.character());
+                replacements += 1;
+                index += length;
+            }
+        }
+    }
+
+    (text, replacements)
+}
+
+/// The state of a streaming decoder, carrying sequences split across chunks over to the next call.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub(crate) struct SequenceDecoder {
+    partial: [u8; 4],
+    partial_length: usize,
+    position: usize,
+}
+
+impl SequenceDecoder {
+    /// Decodes the next chunk of input, passing the bytes of every complete sequence along with
+    /// the characters they encode to `emit`. Escape sequences are passed without any characters.
+    pub(crate) fn decode<N, F>(
+        &mut self,
+        mut input: &[u8],
+        mut next: N,
+        mut emit: F,
+    ) -> Result<(), SequenceError>
+    where
+        N: FnMut(&[u8]) -> Option<Sequence>,
+        F: FnMut(&[u8], &[char]),
+    {
+        // Complete the pending sequence a byte at a time. Since sequences are at most 4 bytes
+        // long, this is cheap.
+        while self.partial_length > 0 {
+            let (&byte, remaining) = match input.split_first() {
+                Some(split) => split,
+                None => return Ok(()),
+            };
+
+            self.partial[self.partial_length] = byte;
+            self.partial_length += 1;
+            input = remaining;
+            let pending = &self.partial[..self.partial_length];
+
+            match next(pending) {
+                Some(Sequence::Character(character, length)) => {
+                    emit(&pending[..length], &[character]);
+                    self.partial_length = 0;
+                    self.position += length;
+                }
+                Some(Sequence::Escape(length)) => {
+                    emit(&pending[..length], &[]);
+                    self.partial_length = 0;
+                    self.position += length;
+                }
+                Some(Sequence::Pair(first, second, length)) => {
+                    emit(&pending[..length], &[first, second]);
+                    self.partial_length = 0;
+                    self.position += length;
+                }
+                Some(Sequence::Invalid(length)) => {
+                    let invalid = &pending[..length];
+                    return Err(SequenceError::new(invalid, Some(length), self.position));
+                }
+                Some(Sequence::Incomplete) | None => {}
+            }
+        }
+
+        loop {
+            match next(input) {
+                None => return Ok(()),
+                Some(Sequence::Character(character, length)) => {
+                    emit(&input[..length], &[character]);
+                    input = &input[length..];
+                    self.position += length;
+                }
+                Some(Sequence::Escape(length)) => {
+                    emit(&input[..length], &[]);
+                    input = &input[length..];
+                    self.position += length;
+                }
+                Some(Sequence::Pair(first, second, length)) => {
+                    emit(&input[..length], &[first, second]);
+                    input = &input[length..];
+                    self.position += length;
+                }
+                Some(Sequence::Incomplete) => {
+                    self.partial[..input.len()].copy_from_slice(input);
+                    self.partial_length = input.len();
+                    return Ok(());
+                }
+                Some(Sequence::Invalid(length)) => {
+                    let invalid = &input[..length];
+                    return Err(SequenceError::new(invalid, Some(length), self.position));
+                }
+            }
+        }
+    }
+
+    /// Skips bytes that are not part of the text, such as a byte order mark.
+    pub(crate) fn skip(&mut self, length: usize) {
+        self.position += length;
+    }
+
+    /// Signals the end of the input, failing if it ended in the middle of a sequence.
+    pub(crate) fn finish(self) -> Result<(), SequenceError> {
+        if self.partial_length == 0 {
+            return Ok(());
+        }
+
+        let pending = &self.partial[..self.partial_length];
+        Err(SequenceError::new(pending, None, self.position))
+    }
+}
+
+/// Encodes as much of `input` as fits into `output`, returning the number of bytes of input that
+/// were read and the number of bytes of output that were written.
+///
+/// `encode` returns the bytes of a character along with their length, or [`None`] if the character
+/// cannot be encoded. Encoding stops before such a character, and fails with it only if nothing
+/// was written.
+///
+/// The characters of `pairs` are encoded together. A character that starts a pair is held back in
+/// `pending` until the character after it is known, even across calls, and [`finish`] encodes it
+/// by itself if the input ends first.
+pub(crate) fn encode<E>(
+    input: &str,
+    output: &mut [u8],
+    pairs: &[Pair],
+    pending: &mut Option<char>,
+    encode: E,
+) -> Result<(usize, usize), char>
+where
+    E: Fn(char) -> Option<([u8; 4], usize)>,
+{
+    let mut read = 0;
+    let mut written = 0;
+
+    for character in input.chars() {
+        if let Some(first) = *pending {
+            let pair = pairs
+                .iter()
+                .find(|pair| pair.0 == first && pair.1 == character);
+            let encoded;
+            let bytes = match pair {
+                Some(pair) => pair.2,
+                None => {
+                    encoded = encode(first).expect("first character of a pair cannot be encoded");
+                    &encoded.0[..encoded.1]
+                }
+            };
+
+            if output.len() - written < bytes.len() {
+                break;
+            }
+
+            output[written..written + bytes.len()].copy_from_slice(bytes);
+            written += bytes.len();
+            *pending = None;
+
+            if pair.is_some() {
+                read += character.len_utf8();
+                continue;
+            }
+        }
+
+        if pairs.iter().any(|pair| pair.0 == character) {
+            *pending = Some(character);
+            read += character.len_utf8();
+            continue;
+        }
+
+        let (bytes, length) = match encode(character) {
+            Some(encoded) => encoded,
+            None if read == 0 && written == 0 => return Err(character),
+            None => break,
+        };
+
+        if output.len() - written < length {
+            break;
+        }
+
+        output[written..written + length].copy_from_slice(&bytes[..length]);
+        read += character.len_utf8();
+        written += length;
+    }
+
+    Ok((read, written))
+}
+
+/// Encodes the character held back by [`encode`], if any, returning the number of bytes
+/// written or [`None`] if `output` is too small.
+pub(crate) fn finish<E>(output: &mut [u8], pending: &mut Option<char>, encode: E) -> Option<usize>
+where
+    E: Fn(char) -> Option<([u8; 4], usize)>,
+{
+    let first = match *pending {
+        Some(first) => first,
+        None => return Some(0),
+    };
+
+    let (bytes, length) = encode(first).expect("first character of a pair cannot be encoded");
+
+    if output.len() < length {
+        return None;
+    }
+
+    output[..length].copy_from_slice(&bytes[..length]);
+    *pending = None;
+    Some(length)
+}
+
+/// Encodes all of `value`, encoding the characters of `pairs` together, and failing with the first
+/// character that cannot be encoded along with its index.
+pub(crate) fn encode_all<E>(
+    value: &str,
+    pairs: &[Pair],
+    encode: E,
+) -> Result<Vec<u8>, (char, usize)>
+where
+    E: Fn(char) -> Option<([u8; 4], usize)>,
+{
+    let mut output = Vec::with_capacity(value.len());
+    let mut characters = value.char_indices().peekable();
+
+    while let Some((index, character)) = characters.next() {
+        if let Some(&(_, next)) = characters.peek() {
+            if let Some(pair) = pairs
+                .iter()
+                .find(|pair| pair.0 == character && pair.1 == next)
+            {
+                output.extend_from_slice(pair.2);
+                characters.next();
+                continue;
+            }
+        }
+
+        match encode(character) {
+            Some((bytes, length)) => output.extend_from_slice(&bytes[..length]),
+            None => return Err((character, index)),
+        }
+    }
+
+    Ok(output)
+}
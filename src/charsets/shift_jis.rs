@@ -0,0 +1,95 @@
+use crate::charsets::tables::jis0208;
+
+multi_byte_charset! {
+    primary_name: "Shift_JIS",
+    preferred_mime_name: "Shift_JIS",
+    mib_enum: 17,
+    next: next,
+    encode_character: encode_character,
+    aliases: [
+        (CsShiftJis, b"csShiftJIS");
+        (MsKanji, b"MS_Kanji");
+    ],
+}
+
+fn next(value: &[u8]) -> Option<Sequence> {
+    let lead = *value.first()?;
+
+    let sequence = match lead {
+        0x00..=0x7f => Sequence::Character(lead as char, 1),
+        0xa1..=0xdf => Sequence::Character(halfwidth_katakana(lead), 1),
+        0x81..=0x9f | 0xe0..=0xef => match value.get(1) {
+            Some(&trail) => next_double_byte(lead, trail),
+            None => Sequence::Incomplete,
+        },
+        _ => Sequence::Invalid(1),
+    };
+
+    Some(sequence)
+}
+
+/// Decodes a double-byte character.
+fn next_double_byte(lead: u8, trail: u8) -> Sequence {
+    let trail_offset = match trail {
+        0x40..=0x7e => 0x40,
+        0x80..=0xfc => 0x41,
+        _ => return Sequence::Invalid(1),
+    };
+    let lead_offset = if lead < 0xa0 { 0x81 } else { 0xc1 };
+    let pointer = usize::from(lead - lead_offset) * 188 + usize::from(trail - trail_offset);
+
+    match jis0208::decode(pointer) {
+        Some(character) => Sequence::Character(character, 2),
+        None => sequence::invalid_pair(trail),
+    }
+}
+
+fn encode_character(character: char) -> Option<([u8; 4], usize)> {
+    match character {
+        '\0'..='\x7f' => Some(([character as u8, 0, 0, 0], 1)),
+        // The yen sign and overline take the place of the backslash and tilde in JIS X 0201, so
+        // they are encoded as such even though those bytes decode to ASCII.
+        '\u{a5}' => Some(([0x5c, 0, 0, 0], 1)),
+        '\u{203e}' => Some(([0x7e, 0, 0, 0], 1)),
+        '\u{ff61}'..='\u{ff9f}' => {
+            let byte = (u32::from(character) - 0xff61) as u8 + 0xa1;
+            Some(([byte, 0, 0, 0], 1))
+        }
+        _ => {
+            let pointer = jis0208::encode(character)?;
+            let (lead, trail) = ((pointer / 188) as u8, (pointer % 188) as u8);
+            let lead_offset = if lead < 0x1f { 0x81 } else { 0xc1 };
+            let trail_offset = if trail < 0x3f { 0x40 } else { 0x41 };
+            Some(([lead + lead_offset, trail + trail_offset, 0, 0], 2))
+        }
+    }
+}
+
+fn halfwidth_katakana(byte: u8) -> char {
+    // Unsafe justification: U+FF61 through U+FF9F are all scalar values.
+    unsafe { char::from_u32_unchecked(0xff61 + u32::from(byte - 0xa1)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_known_text() {
+        let string = Str::decode(b"a\\\xb1\x93\xfa\x96{").unwrap();
+        assert_eq!(string.to_unicode(), "a\\\u{ff71}\u{65e5}\u{672c}");
+    }
+
+    #[test]
+    fn encodes_known_text() {
+        let string = String::encode("a\u{ff71}\u{65e5}\u{672c}\u{a5}\u{203e}").unwrap();
+        assert_eq!(Vec::from(string), b"a\xb1\x93\xfa\x96{\\~");
+    }
+
+    #[test]
+    fn rejects_windows_extensions() {
+        let error = Str::decode(b"a\x87\x40").unwrap_err();
+        assert_eq!(error.valid_up_to(), 1);
+        assert!(String::encode("\u{2460}").is_err());
+    }
+}
@@ -0,0 +1,521 @@
+//! The implementation shared by the charsets that encode every character as a single byte, driven
+//! by a table of the character of each byte.
+
+/// Defines the charset of the enclosing module, along with its string, character, error, decoder,
+/// and encoder types.
+///
+/// The charset is described by one of two tables:
+///
+/// - `upper_half`, a `[char; 128]` with the characters of the bytes 0x80 to 0xff, for charsets that
+///   agree with US-ASCII on the bytes below 0x80;
+/// - `to_unicode`, a `[char; 256]` with the characters of every byte, for any other charset.
+///
+/// Bytes that are not assigned a character are marked with `'\0'`, which only the byte 0x00 can
+/// stand for. `from_unicode` pairs every character of the table with its byte, sorted by character.
+macro_rules! single_byte_charset {
+    (
+        primary_name: $primary_name:literal,
+        $(preferred_mime_name: $preferred_mime_name:literal,)?
+        mib_enum: $mib_enum:literal,
+        $table_kind:ident: $to_unicode:ident,
+        from_unicode: $from_unicode:ident,
+        aliases: [
+            $(($variant:ident, $value:expr);)+
+        ],
+    ) => {
+        use std::borrow::{Borrow, Cow, ToOwned};
+        use std::convert::TryFrom;
+        use std::error::Error;
+        use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+        use std::ops::Deref;
+        use std::slice;
+        use std::str;
+        use std::string::String as StdString;
+
+        use crate::charset::private::Sealed;
+        use crate::charset::{
+            Character as CharacterTrait, Charset as CharsetTrait,
+            DecodeError as DecodeErrorTrait, Decoder as DecoderTrait,
+            EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
+            Str as StrTrait, String as StringTrait,
+        };
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct Charset;
+
+        impl CharsetTrait for Charset {
+            type Alias = Alias;
+            type Character = Character;
+            type DecodeError = DecodeError;
+            type Decoder = Decoder;
+            type EncodeError = EncodeError;
+            type Encoder = Encoder;
+            type Str = Str;
+            type String = String;
+
+            const IS_ASCII_COMPATIBLE: bool = single_byte_charset!(@ascii_compatible $table_kind);
+            const MIB_ENUM: u16 = $mib_enum;
+            const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> =
+                single_byte_charset!(@preferred_mime_name $($preferred_mime_name)?);
+            const PRIMARY_NAME: &'static UsAsciiStr =
+                unsafe { UsAsciiStr::from_bytes_unchecked($primary_name.as_bytes()) };
+        }
+
+        impl Sealed for Charset {}
+
+        #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Character(char);
+
+        impl CharacterTrait for Character {
+            fn from_byte(value: u8) -> Option<Self> {
+                if is_valid_byte(value) {
+                    Some(Character::from(value))
+                } else {
+                    None
+                }
+            }
+
+            fn to_byte(self) -> Option<u8> {
+                encode_character(self.0)
+            }
+
+            fn to_char(self) -> char {
+                self.0
+            }
+        }
+
+        impl Debug for Character {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_char(self.0)
+            }
+        }
+
+        impl Display for Character {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_char(self.0)
+            }
+        }
+
+        impl From<u8> for Character {
+            fn from(value: u8) -> Self {
+                Character(single_byte_charset!(@decode $table_kind $to_unicode value))
+            }
+        }
+
+        impl TryFrom<char> for Character {
+            type Error = EncodeError;
+
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                match encode_character(value) {
+                    Some(_) => Ok(Character(value)),
+                    None => Err(EncodeError {
+                        character: value,
+                        valid_up_to: 0,
+                    }),
+                }
+            }
+        }
+
+        #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Str([u8]);
+
+        impl AsRef<[u8]> for Str {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl Debug for Str {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                for &byte in &self.0 {
+                    Debug::fmt(&Character::from(byte), formatter)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Display for Str {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(self, formatter)
+            }
+        }
+
+        impl Sealed for Str {}
+
+        impl StrTrait for Str {
+            type Character = Character;
+            type DecodeError = DecodeError;
+            type State = ();
+            type String = String;
+
+            fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
+                validate(value)?;
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            unsafe fn decode_unchecked(value: &[u8]) -> &Self {
+                &*(value as *const [u8] as *const Str)
+            }
+
+            fn next_character(
+                &self,
+                index: usize,
+                _state: &mut Self::State,
+            ) -> Option<(Self::Character, usize)> {
+                self.0.get(index).map(|&byte| (Character::from(byte), 1))
+            }
+
+            fn to_unicode(&self) -> Cow<'_, str> {
+                if Charset::IS_ASCII_COMPATIBLE && self.0.is_ascii() {
+                    // Unsafe justification: ASCII is a subset of UTF-8.
+                    Cow::Borrowed(unsafe { str::from_utf8_unchecked(&self.0) })
+                } else {
+                    Cow::Owned(self.0.iter().map(|&byte| Character::from(byte).0).collect())
+                }
+            }
+        }
+
+        impl ToOwned for Str {
+            type Owned = String;
+
+            fn to_owned(&self) -> Self::Owned {
+                String(self.0.to_owned())
+            }
+        }
+
+        #[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct String(Vec<u8>);
+
+        impl AsRef<[u8]> for String {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl AsRef<Str> for String {
+            fn as_ref(&self) -> &Str {
+                &self
+            }
+        }
+
+        impl Borrow<Str> for String {
+            fn borrow(&self) -> &Str {
+                &self
+            }
+        }
+
+        impl Debug for String {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(&**self, formatter)
+            }
+        }
+
+        impl Deref for String {
+            type Target = <Self as StringTrait>::Str;
+
+            fn deref(&self) -> &Self::Target {
+                unsafe { Str::decode_unchecked(&*self.0) }
+            }
+        }
+
+        impl Display for String {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(self, formatter)
+            }
+        }
+
+        impl<'str> From<&'str Str> for String {
+            fn from(value: &'str Str) -> Self {
+                value.to_owned()
+            }
+        }
+
+        impl From<String> for Vec<u8> {
+            fn from(value: String) -> Self {
+                value.0
+            }
+        }
+
+        impl Sealed for String {}
+
+        impl StringTrait for String {
+            type DecodeError = DecodeError;
+            type EncodeError = EncodeError;
+            type Str = Str;
+
+            fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
+                if let Err(error) = validate(&value) {
+                    return Err((value, error));
+                }
+
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+                if validate(value).is_ok() {
+                    return (unsafe { Str::decode_unchecked(value) }.to_unicode(), 0);
+                }
+
+                let mut replacements = 0;
+                let text = value
+                    .iter()
+                    .map(|&byte| {
+                        if is_valid_byte(byte) {
+                            Character::from(byte).0
+                        } else {
+                            replacements += 1;
+                            replacement.character()
+                        }
+                    })
+                    .collect();
+
+                (Cow::Owned(text), replacements)
+            }
+
+            unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
+                String(value)
+            }
+
+            fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+                let mut bytes = Vec::with_capacity(value.len());
+
+                for (index, character) in value.char_indices() {
+                    match encode_character(character) {
+                        Some(byte) => bytes.push(byte),
+                        None => {
+                            return Err(EncodeError {
+                                character,
+                                valid_up_to: index,
+                            })
+                        }
+                    }
+                }
+
+                Ok(String(bytes))
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct DecodeError {
+            byte: u8,
+            valid_up_to: usize,
+        }
+
+        impl DecodeErrorTrait for DecodeError {
+            fn error_len(&self) -> Option<usize> {
+                Some(1)
+            }
+
+            fn invalid_bytes(&self) -> &[u8] {
+                slice::from_ref(&self.byte)
+            }
+
+            fn valid_up_to(&self) -> usize {
+                self.valid_up_to
+            }
+        }
+
+        impl Display for DecodeError {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    "invalid {} byte {:#04x} at index {}",
+                    $primary_name, self.byte, self.valid_up_to
+                )
+            }
+        }
+
+        impl Error for DecodeError {}
+
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct EncodeError {
+            character: char,
+            valid_up_to: usize,
+        }
+
+        impl EncodeErrorTrait for EncodeError {
+            fn character(&self) -> char {
+                self.character
+            }
+
+            fn valid_up_to(&self) -> usize {
+                self.valid_up_to
+            }
+        }
+
+        impl Display for EncodeError {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    "character {:?} at index {} cannot be encoded as {}",
+                    self.character, self.valid_up_to, $primary_name
+                )
+            }
+        }
+
+        impl Error for EncodeError {}
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Decoder {
+            position: usize,
+        }
+
+        impl DecoderTrait for Decoder {
+            type DecodeError = DecodeError;
+
+            fn decode_to_bytes(
+                &mut self,
+                input: &[u8],
+                output: &mut Vec<u8>,
+            ) -> Result<(), Self::DecodeError> {
+                match validate(input) {
+                    Ok(()) => {
+                        output.extend_from_slice(input);
+                        self.position += input.len();
+                        Ok(())
+                    }
+                    Err(error) => {
+                        output.extend_from_slice(&input[..error.valid_up_to]);
+                        self.position += error.valid_up_to;
+                        Err(DecodeError {
+                            valid_up_to: self.position,
+                            ..error
+                        })
+                    }
+                }
+            }
+
+            fn decode_to_unicode(
+                &mut self,
+                input: &[u8],
+                output: &mut StdString,
+            ) -> Result<(), Self::DecodeError> {
+                for &byte in input {
+                    match Character::from_byte(byte) {
+                        Some(character) => output.push(character.to_char()),
+                        None => {
+                            return Err(DecodeError {
+                                byte,
+                                valid_up_to: self.position,
+                            })
+                        }
+                    }
+
+                    self.position += 1;
+                }
+
+                Ok(())
+            }
+
+            fn finish(self) -> Result<(), Self::DecodeError> {
+                Ok(())
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Encoder {
+            position: usize,
+        }
+
+        impl EncoderTrait for Encoder {
+            type EncodeError = EncodeError;
+
+            fn encode(
+                &mut self,
+                input: &str,
+                output: &mut [u8],
+            ) -> Result<(usize, usize), Self::EncodeError> {
+                let mut read = 0;
+                let mut written = 0;
+
+                for character in input.chars() {
+                    if written == output.len() {
+                        break;
+                    }
+
+                    match encode_character(character) {
+                        Some(byte) => {
+                            output[written] = byte;
+                            read += character.len_utf8();
+                            written += 1;
+                        }
+                        None if read == 0 => {
+                            return Err(EncodeError {
+                                character,
+                                valid_up_to: self.position,
+                            })
+                        }
+                        None => break,
+                    }
+                }
+
+                self.position += read;
+                Ok((read, written))
+            }
+
+            fn finish(&mut self, _output: &mut [u8]) -> Option<usize> {
+                Some(0)
+            }
+        }
+
+        fn is_valid_byte(byte: u8) -> bool {
+            byte == 0 || Character::from(byte).0 != '\0'
+        }
+
+        fn validate(value: &[u8]) -> Result<(), DecodeError> {
+            for (index, &byte) in value.iter().enumerate() {
+                if !is_valid_byte(byte) {
+                    return Err(DecodeError {
+                        byte,
+                        valid_up_to: index,
+                    });
+                }
+            }
+
+            Ok(())
+        }
+
+        fn encode_character(character: char) -> Option<u8> {
+            if Charset::IS_ASCII_COMPATIBLE && character.is_ascii() {
+                return Some(character as u8);
+            }
+
+            $from_unicode
+                .binary_search_by_key(&character, |&(character, _)| character)
+                .ok()
+                .map(|index| $from_unicode[index].1)
+        }
+
+        aliases! {
+            Alias,
+
+            $(($variant, $value);)+
+        }
+    };
+
+    (@ascii_compatible upper_half) => {
+        true
+    };
+    (@ascii_compatible to_unicode) => {
+        false
+    };
+
+    (@decode upper_half $table:ident $byte:expr) => {
+        if $byte < 0x80 {
+            $byte as char
+        } else {
+            $table[($byte - 0x80) as usize]
+        }
+    };
+    (@decode to_unicode $table:ident $byte:expr) => {
+        $table[$byte as usize]
+    };
+
+    (@preferred_mime_name) => {
+        None
+    };
+    (@preferred_mime_name $name:literal) => {
+        Some(unsafe { UsAsciiStr::from_bytes_unchecked($name.as_bytes()) })
+    };
+}
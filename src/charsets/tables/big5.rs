@@ -0,0 +1,2985 @@
+//! The Big5 character set, where the pointer of the character with lead byte `l` and trail byte `t`
+//! is `(l - 0x81) * 157 + t - o`, with `o` being 0x40 for trail bytes below 0x7f and 0x62
+//! otherwise.
+
+use std::char;
+use std::convert::TryFrom;
+
+/// The Unicode code point of the character at each pointer, or 0 if the pointer is unassigned.
+#[rustfmt::skip]
+static TO_UNICODE: [u16; 19782] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x3000, 0xff0c, 0x3001, 0x3002, 0xff0e, 0x2022, 0xff1b, 0xff1a, 0xff1f, 0xff01, 0xfe30, 0x2026, 0x2025, 0xfe50, 0xff64, 0xfe52,
+    0x00b7, 0xfe54, 0xfe55, 0xfe56, 0xfe57, 0xff5c, 0x2013, 0xfe31, 0x2014, 0xfe33, 0x2574, 0xfe34, 0xfe4f, 0xff08, 0xff09, 0xfe35,
+    0xfe36, 0xff5b, 0xff5d, 0xfe37, 0xfe38, 0x3014, 0x3015, 0xfe39, 0xfe3a, 0x3010, 0x3011, 0xfe3b, 0xfe3c, 0x300a, 0x300b, 0xfe3d,
+    0xfe3e, 0x3008, 0x3009, 0xfe3f, 0xfe40, 0x300c, 0x300d, 0xfe41, 0xfe42, 0x300e, 0x300f, 0xfe43, 0xfe44, 0xfe59, 0xfe5a, 0xfe5b,
+    0xfe5c, 0xfe5d, 0xfe5e, 0x2018, 0x2019, 0x201c, 0x201d, 0x301d, 0x301e, 0x2035, 0x2032, 0xff03, 0xff06, 0xff0a, 0x203b, 0x00a7,
+    0x3003, 0x25cb, 0x25cf, 0x25b3, 0x25b2, 0x25ce, 0x2606, 0x2605, 0x25c7, 0x25c6, 0x25a1, 0x25a0, 0x25bd, 0x25bc, 0x32a3, 0x2105,
+    0x203e, 0xffe3, 0xff3f, 0x02cd, 0xfe49, 0xfe4a, 0xfe4d, 0xfe4e, 0xfe4b, 0xfe4c, 0xfe5f, 0xfe60, 0xfe61, 0xff0b, 0xff0d, 0x00d7,
+    0x00f7, 0x00b1, 0x221a, 0xff1c, 0xff1e, 0xff1d, 0x2266, 0x2267, 0x2260, 0x221e, 0x2252, 0x2261, 0xfe62, 0xfe63, 0xfe64, 0xfe65,
+    0xfe66, 0x223c, 0x2229, 0x222a, 0x22a5, 0x2220, 0x221f, 0x22bf, 0x33d2, 0x33d1, 0x222b, 0x222e, 0x2235, 0x2234, 0x2640, 0x2642,
+    0x2641, 0x2609, 0x2191, 0x2193, 0x2190, 0x2192, 0x2196, 0x2197, 0x2199, 0x2198, 0x2225, 0x2223, 0xff0f, 0xff3c, 0xff0f, 0xff3c,
+    0xff04, 0x00a5, 0x3012, 0x00a2, 0x00a3, 0xff05, 0xff20, 0x2103, 0x2109, 0xfe69, 0xfe6a, 0xfe6b, 0x33d5, 0x339c, 0x339d, 0x339e,
+    0x33ce, 0x33a1, 0x338e, 0x338f, 0x33c4, 0x00b0, 0x5159, 0x515b, 0x515e, 0x515d, 0x5161, 0x5163, 0x55e7, 0x74e9, 0x7cce, 0x2581,
+    0x2582, 0x2583, 0x2584, 0x2585, 0x2586, 0x2587, 0x2588, 0x258f, 0x258e, 0x258d, 0x258c, 0x258b, 0x258a, 0x2589, 0x253c, 0x2534,
+    0x252c, 0x2524, 0x251c, 0x2594, 0x2500, 0x2502, 0x2595, 0x250c, 0x2510, 0x2514, 0x2518, 0x256d, 0x256e, 0x2570, 0x256f, 0x2550,
+    0x255e, 0x256a, 0x2561, 0x25e2, 0x25e3, 0x25e5, 0x25e4, 0x2571, 0x2572, 0x2573, 0xff10, 0xff11, 0xff12, 0xff13, 0xff14, 0xff15,
+    0xff16, 0xff17, 0xff18, 0xff19, 0x2160, 0x2161, 0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168, 0x2169, 0x3021, 0x3022,
+    0x3023, 0x3024, 0x3025, 0x3026, 0x3027, 0x3028, 0x3029, 0x5341, 0x5344, 0x5345, 0xff21, 0xff22, 0xff23, 0xff24, 0xff25, 0xff26,
+    0xff27, 0xff28, 0xff29, 0xff2a, 0xff2b, 0xff2c, 0xff2d, 0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34, 0xff35, 0xff36,
+    0xff37, 0xff38, 0xff39, 0xff3a, 0xff41, 0xff42, 0xff43, 0xff44, 0xff45, 0xff46, 0xff47, 0xff48, 0xff49, 0xff4a, 0xff4b, 0xff4c,
+    0xff4d, 0xff4e, 0xff4f, 0xff50, 0xff51, 0xff52, 0xff53, 0xff54, 0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a, 0x0391, 0x0392,
+    0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03a3,
+    0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9, 0x03ba,
+    0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9, 0x3105, 0x3106,
+    0x3107, 0x3108, 0x3109, 0x310a, 0x310b, 0x310c, 0x310d, 0x310e, 0x310f, 0x3110, 0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116,
+    0x3117, 0x3118, 0x3119, 0x311a, 0x311b, 0x311c, 0x311d, 0x311e, 0x311f, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124, 0x3125, 0x3126,
+    0x3127, 0x3128, 0x3129, 0x02d9, 0x02c9, 0x02ca, 0x02c7, 0x02cb, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4e00, 0x4e59, 0x4e01, 0x4e03, 0x4e43, 0x4e5d, 0x4e86, 0x4e8c, 0x4eba,
+    0x513f, 0x5165, 0x516b, 0x51e0, 0x5200, 0x5201, 0x529b, 0x5315, 0x5341, 0x535c, 0x53c8, 0x4e09, 0x4e0b, 0x4e08, 0x4e0a, 0x4e2b,
+    0x4e38, 0x51e1, 0x4e45, 0x4e48, 0x4e5f, 0x4e5e, 0x4e8e, 0x4ea1, 0x5140, 0x5203, 0x52fa, 0x5343, 0x53c9, 0x53e3, 0x571f, 0x58eb,
+    0x5915, 0x5927, 0x5973, 0x5b50, 0x5b51, 0x5b53, 0x5bf8, 0x5c0f, 0x5c22, 0x5c38, 0x5c71, 0x5ddd, 0x5de5, 0x5df1, 0x5df2, 0x5df3,
+    0x5dfe, 0x5e72, 0x5efe, 0x5f0b, 0x5f13, 0x624d, 0x4e11, 0x4e10, 0x4e0d, 0x4e2d, 0x4e30, 0x4e39, 0x4e4b, 0x5c39, 0x4e88, 0x4e91,
+    0x4e95, 0x4e92, 0x4e94, 0x4ea2, 0x4ec1, 0x4ec0, 0x4ec3, 0x4ec6, 0x4ec7, 0x4ecd, 0x4eca, 0x4ecb, 0x4ec4, 0x5143, 0x5141, 0x5167,
+    0x516d, 0x516e, 0x516c, 0x5197, 0x51f6, 0x5206, 0x5207, 0x5208, 0x52fb, 0x52fe, 0x52ff, 0x5316, 0x5339, 0x5348, 0x5347, 0x5345,
+    0x535e, 0x5384, 0x53cb, 0x53ca, 0x53cd, 0x58ec, 0x5929, 0x592b, 0x592a, 0x592d, 0x5b54, 0x5c11, 0x5c24, 0x5c3a, 0x5c6f, 0x5df4,
+    0x5e7b, 0x5eff, 0x5f14, 0x5f15, 0x5fc3, 0x6208, 0x6236, 0x624b, 0x624e, 0x652f, 0x6587, 0x6597, 0x65a4, 0x65b9, 0x65e5, 0x66f0,
+    0x6708, 0x6728, 0x6b20, 0x6b62, 0x6b79, 0x6bcb, 0x6bd4, 0x6bdb, 0x6c0f, 0x6c34, 0x706b, 0x722a, 0x7236, 0x723b, 0x7247, 0x7259,
+    0x725b, 0x72ac, 0x738b, 0x4e19, 0x4e16, 0x4e15, 0x4e14, 0x4e18, 0x4e3b, 0x4e4d, 0x4e4f, 0x4e4e, 0x4ee5, 0x4ed8, 0x4ed4, 0x4ed5,
+    0x4ed6, 0x4ed7, 0x4ee3, 0x4ee4, 0x4ed9, 0x4ede, 0x5145, 0x5144, 0x5189, 0x518a, 0x51ac, 0x51f9, 0x51fa, 0x51f8, 0x520a, 0x52a0,
+    0x529f, 0x5305, 0x5306, 0x5317, 0x531d, 0x4edf, 0x534a, 0x5349, 0x5361, 0x5360, 0x536f, 0x536e, 0x53bb, 0x53ef, 0x53e4, 0x53f3,
+    0x53ec, 0x53ee, 0x53e9, 0x53e8, 0x53fc, 0x53f8, 0x53f5, 0x53eb, 0x53e6, 0x53ea, 0x53f2, 0x53f1, 0x53f0, 0x53e5, 0x53ed, 0x53fb,
+    0x56db, 0x56da, 0x5916, 0x592e, 0x5931, 0x5974, 0x5976, 0x5b55, 0x5b83, 0x5c3c, 0x5de8, 0x5de7, 0x5de6, 0x5e02, 0x5e03, 0x5e73,
+    0x5e7c, 0x5f01, 0x5f18, 0x5f17, 0x5fc5, 0x620a, 0x6253, 0x6254, 0x6252, 0x6251, 0x65a5, 0x65e6, 0x672e, 0x672c, 0x672a, 0x672b,
+    0x672d, 0x6b63, 0x6bcd, 0x6c11, 0x6c10, 0x6c38, 0x6c41, 0x6c40, 0x6c3e, 0x72af, 0x7384, 0x7389, 0x74dc, 0x74e6, 0x7518, 0x751f,
+    0x7528, 0x7529, 0x7530, 0x7531, 0x7532, 0x7533, 0x758b, 0x767d, 0x76ae, 0x76bf, 0x76ee, 0x77db, 0x77e2, 0x77f3, 0x793a, 0x79be,
+    0x7a74, 0x7acb, 0x4e1e, 0x4e1f, 0x4e52, 0x4e53, 0x4e69, 0x4e99, 0x4ea4, 0x4ea6, 0x4ea5, 0x4eff, 0x4f09, 0x4f19, 0x4f0a, 0x4f15,
+    0x4f0d, 0x4f10, 0x4f11, 0x4f0f, 0x4ef2, 0x4ef6, 0x4efb, 0x4ef0, 0x4ef3, 0x4efd, 0x4f01, 0x4f0b, 0x5149, 0x5147, 0x5146, 0x5148,
+    0x5168, 0x5171, 0x518d, 0x51b0, 0x5217, 0x5211, 0x5212, 0x520e, 0x5216, 0x52a3, 0x5308, 0x5321, 0x5320, 0x5370, 0x5371, 0x5409,
+    0x540f, 0x540c, 0x540a, 0x5410, 0x5401, 0x540b, 0x5404, 0x5411, 0x540d, 0x5408, 0x5403, 0x540e, 0x5406, 0x5412, 0x56e0, 0x56de,
+    0x56dd, 0x5733, 0x5730, 0x5728, 0x572d, 0x572c, 0x572f, 0x5729, 0x5919, 0x591a, 0x5937, 0x5938, 0x5984, 0x5978, 0x5983, 0x597d,
+    0x5979, 0x5982, 0x5981, 0x5b57, 0x5b58, 0x5b87, 0x5b88, 0x5b85, 0x5b89, 0x5bfa, 0x5c16, 0x5c79, 0x5dde, 0x5e06, 0x5e76, 0x5e74,
+    0x5f0f, 0x5f1b, 0x5fd9, 0x5fd6, 0x620e, 0x620c, 0x620d, 0x6210, 0x6263, 0x625b, 0x6258, 0x6536, 0x65e9, 0x65e8, 0x65ec, 0x65ed,
+    0x66f2, 0x66f3, 0x6709, 0x673d, 0x6734, 0x6731, 0x6735, 0x6b21, 0x6b64, 0x6b7b, 0x6c16, 0x6c5d, 0x6c57, 0x6c59, 0x6c5f, 0x6c60,
+    0x6c50, 0x6c55, 0x6c61, 0x6c5b, 0x6c4d, 0x6c4e, 0x7070, 0x725f, 0x725d, 0x767e, 0x7af9, 0x7c73, 0x7cf8, 0x7f36, 0x7f8a, 0x7fbd,
+    0x8001, 0x8003, 0x800c, 0x8012, 0x8033, 0x807f, 0x8089, 0x808b, 0x808c, 0x81e3, 0x81ea, 0x81f3, 0x81fc, 0x820c, 0x821b, 0x821f,
+    0x826e, 0x8272, 0x827e, 0x866b, 0x8840, 0x884c, 0x8863, 0x897f, 0x9621, 0x4e32, 0x4ea8, 0x4f4d, 0x4f4f, 0x4f47, 0x4f57, 0x4f5e,
+    0x4f34, 0x4f5b, 0x4f55, 0x4f30, 0x4f50, 0x4f51, 0x4f3d, 0x4f3a, 0x4f38, 0x4f43, 0x4f54, 0x4f3c, 0x4f46, 0x4f63, 0x4f5c, 0x4f60,
+    0x4f2f, 0x4f4e, 0x4f36, 0x4f59, 0x4f5d, 0x4f48, 0x4f5a, 0x514c, 0x514b, 0x514d, 0x5175, 0x51b6, 0x51b7, 0x5225, 0x5224, 0x5229,
+    0x522a, 0x5228, 0x52ab, 0x52a9, 0x52aa, 0x52ac, 0x5323, 0x5373, 0x5375, 0x541d, 0x542d, 0x541e, 0x543e, 0x5426, 0x544e, 0x5427,
+    0x5446, 0x5443, 0x5433, 0x5448, 0x5442, 0x541b, 0x5429, 0x544a, 0x5439, 0x543b, 0x5438, 0x542e, 0x5435, 0x5436, 0x5420, 0x543c,
+    0x5440, 0x5431, 0x542b, 0x541f, 0x542c, 0x56ea, 0x56f0, 0x56e4, 0x56eb, 0x574a, 0x5751, 0x5740, 0x574d, 0x5747, 0x574e, 0x573e,
+    0x5750, 0x574f, 0x573b, 0x58ef, 0x593e, 0x599d, 0x5992, 0x59a8, 0x599e, 0x59a3, 0x5999, 0x5996, 0x598d, 0x59a4, 0x5993, 0x598a,
+    0x59a5, 0x5b5d, 0x5b5c, 0x5b5a, 0x5b5b, 0x5b8c, 0x5b8b, 0x5b8f, 0x5c2c, 0x5c40, 0x5c41, 0x5c3f, 0x5c3e, 0x5c90, 0x5c91, 0x5c94,
+    0x5c8c, 0x5deb, 0x5e0c, 0x5e8f, 0x5e87, 0x5e8a, 0x5ef7, 0x5f04, 0x5f1f, 0x5f64, 0x5f62, 0x5f77, 0x5f79, 0x5fd8, 0x5fcc, 0x5fd7,
+    0x5fcd, 0x5ff1, 0x5feb, 0x5ff8, 0x5fea, 0x6212, 0x6211, 0x6284, 0x6297, 0x6296, 0x6280, 0x6276, 0x6289, 0x626d, 0x628a, 0x627c,
+    0x627e, 0x6279, 0x6273, 0x6292, 0x626f, 0x6298, 0x626e, 0x6295, 0x6293, 0x6291, 0x6286, 0x6539, 0x653b, 0x6538, 0x65f1, 0x66f4,
+    0x675f, 0x674e, 0x674f, 0x6750, 0x6751, 0x675c, 0x6756, 0x675e, 0x6749, 0x6746, 0x6760, 0x6753, 0x6757, 0x6b65, 0x6bcf, 0x6c42,
+    0x6c5e, 0x6c99, 0x6c81, 0x6c88, 0x6c89, 0x6c85, 0x6c9b, 0x6c6a, 0x6c7a, 0x6c90, 0x6c70, 0x6c8c, 0x6c68, 0x6c96, 0x6c92, 0x6c7d,
+    0x6c83, 0x6c72, 0x6c7e, 0x6c74, 0x6c86, 0x6c76, 0x6c8d, 0x6c94, 0x6c98, 0x6c82, 0x7076, 0x707c, 0x707d, 0x7078, 0x7262, 0x7261,
+    0x7260, 0x72c4, 0x72c2, 0x7396, 0x752c, 0x752b, 0x7537, 0x7538, 0x7682, 0x76ef, 0x77e3, 0x79c1, 0x79c0, 0x79bf, 0x7a76, 0x7cfb,
+    0x7f55, 0x8096, 0x8093, 0x809d, 0x8098, 0x809b, 0x809a, 0x80b2, 0x826f, 0x8292, 0x828b, 0x828d, 0x898b, 0x89d2, 0x8a00, 0x8c37,
+    0x8c46, 0x8c55, 0x8c9d, 0x8d64, 0x8d70, 0x8db3, 0x8eab, 0x8eca, 0x8f9b, 0x8fb0, 0x8fc2, 0x8fc6, 0x8fc5, 0x8fc4, 0x5de1, 0x9091,
+    0x90a2, 0x90aa, 0x90a6, 0x90a3, 0x9149, 0x91c6, 0x91cc, 0x9632, 0x962e, 0x9631, 0x962a, 0x962c, 0x4e26, 0x4e56, 0x4e73, 0x4e8b,
+    0x4e9b, 0x4e9e, 0x4eab, 0x4eac, 0x4f6f, 0x4f9d, 0x4f8d, 0x4f73, 0x4f7f, 0x4f6c, 0x4f9b, 0x4f8b, 0x4f86, 0x4f83, 0x4f70, 0x4f75,
+    0x4f88, 0x4f69, 0x4f7b, 0x4f96, 0x4f7e, 0x4f8f, 0x4f91, 0x4f7a, 0x5154, 0x5152, 0x5155, 0x5169, 0x5177, 0x5176, 0x5178, 0x51bd,
+    0x51fd, 0x523b, 0x5238, 0x5237, 0x523a, 0x5230, 0x522e, 0x5236, 0x5241, 0x52be, 0x52bb, 0x5352, 0x5354, 0x5353, 0x5351, 0x5366,
+    0x5377, 0x5378, 0x5379, 0x53d6, 0x53d4, 0x53d7, 0x5473, 0x5475, 0x5496, 0x5478, 0x5495, 0x5480, 0x547b, 0x5477, 0x5484, 0x5492,
+    0x5486, 0x547c, 0x5490, 0x5471, 0x5476, 0x548c, 0x549a, 0x5462, 0x5468, 0x548b, 0x547d, 0x548e, 0x56fa, 0x5783, 0x5777, 0x576a,
+    0x5769, 0x5761, 0x5766, 0x5764, 0x577c, 0x591c, 0x5949, 0x5947, 0x5948, 0x5944, 0x5954, 0x59be, 0x59bb, 0x59d4, 0x59b9, 0x59ae,
+    0x59d1, 0x59c6, 0x59d0, 0x59cd, 0x59cb, 0x59d3, 0x59ca, 0x59af, 0x59b3, 0x59d2, 0x59c5, 0x5b5f, 0x5b64, 0x5b63, 0x5b97, 0x5b9a,
+    0x5b98, 0x5b9c, 0x5b99, 0x5b9b, 0x5c1a, 0x5c48, 0x5c45, 0x5c46, 0x5cb7, 0x5ca1, 0x5cb8, 0x5ca9, 0x5cab, 0x5cb1, 0x5cb3, 0x5e18,
+    0x5e1a, 0x5e16, 0x5e15, 0x5e1b, 0x5e11, 0x5e78, 0x5e9a, 0x5e97, 0x5e9c, 0x5e95, 0x5e96, 0x5ef6, 0x5f26, 0x5f27, 0x5f29, 0x5f80,
+    0x5f81, 0x5f7f, 0x5f7c, 0x5fdd, 0x5fe0, 0x5ffd, 0x5ff5, 0x5fff, 0x600f, 0x6014, 0x602f, 0x6035, 0x6016, 0x602a, 0x6015, 0x6021,
+    0x6027, 0x6029, 0x602b, 0x601b, 0x6216, 0x6215, 0x623f, 0x623e, 0x6240, 0x627f, 0x62c9, 0x62cc, 0x62c4, 0x62bf, 0x62c2, 0x62b9,
+    0x62d2, 0x62db, 0x62ab, 0x62d3, 0x62d4, 0x62cb, 0x62c8, 0x62a8, 0x62bd, 0x62bc, 0x62d0, 0x62d9, 0x62c7, 0x62cd, 0x62b5, 0x62da,
+    0x62b1, 0x62d8, 0x62d6, 0x62d7, 0x62c6, 0x62ac, 0x62ce, 0x653e, 0x65a7, 0x65bc, 0x65fa, 0x6614, 0x6613, 0x660c, 0x6606, 0x6602,
+    0x660e, 0x6600, 0x660f, 0x6615, 0x660a, 0x6607, 0x670d, 0x670b, 0x676d, 0x678b, 0x6795, 0x6771, 0x679c, 0x6773, 0x6777, 0x6787,
+    0x679d, 0x6797, 0x676f, 0x6770, 0x677f, 0x6789, 0x677e, 0x6790, 0x6775, 0x679a, 0x6793, 0x677c, 0x676a, 0x6772, 0x6b23, 0x6b66,
+    0x6b67, 0x6b7f, 0x6c13, 0x6c1b, 0x6ce3, 0x6ce8, 0x6cf3, 0x6cb1, 0x6ccc, 0x6ce5, 0x6cb3, 0x6cbd, 0x6cbe, 0x6cbc, 0x6ce2, 0x6cab,
+    0x6cd5, 0x6cd3, 0x6cb8, 0x6cc4, 0x6cb9, 0x6cc1, 0x6cae, 0x6cd7, 0x6cc5, 0x6cf1, 0x6cbf, 0x6cbb, 0x6ce1, 0x6cdb, 0x6cca, 0x6cac,
+    0x6cef, 0x6cdc, 0x6cd6, 0x6ce0, 0x7095, 0x708e, 0x7092, 0x708a, 0x7099, 0x722c, 0x722d, 0x7238, 0x7248, 0x7267, 0x7269, 0x72c0,
+    0x72ce, 0x72d9, 0x72d7, 0x72d0, 0x73a9, 0x73a8, 0x739f, 0x73ab, 0x73a5, 0x753d, 0x759d, 0x7599, 0x759a, 0x7684, 0x76c2, 0x76f2,
+    0x76f4, 0x77e5, 0x77fd, 0x793e, 0x7940, 0x7941, 0x79c9, 0x79c8, 0x7a7a, 0x7a79, 0x7afa, 0x7cfe, 0x7f54, 0x7f8c, 0x7f8b, 0x8005,
+    0x80ba, 0x80a5, 0x80a2, 0x80b1, 0x80a1, 0x80ab, 0x80a9, 0x80b4, 0x80aa, 0x80af, 0x81e5, 0x81fe, 0x820d, 0x82b3, 0x829d, 0x8299,
+    0x82ad, 0x82bd, 0x829f, 0x82b9, 0x82b1, 0x82ac, 0x82a5, 0x82af, 0x82b8, 0x82a3, 0x82b0, 0x82be, 0x82b7, 0x864e, 0x8671, 0x521d,
+    0x8868, 0x8ecb, 0x8fce, 0x8fd4, 0x8fd1, 0x90b5, 0x90b8, 0x90b1, 0x90b6, 0x91c7, 0x91d1, 0x9577, 0x9580, 0x961c, 0x9640, 0x963f,
+    0x963b, 0x9644, 0x9642, 0x96b9, 0x96e8, 0x9752, 0x975e, 0x4e9f, 0x4ead, 0x4eae, 0x4fe1, 0x4fb5, 0x4faf, 0x4fbf, 0x4fe0, 0x4fd1,
+    0x4fcf, 0x4fdd, 0x4fc3, 0x4fb6, 0x4fd8, 0x4fdf, 0x4fca, 0x4fd7, 0x4fae, 0x4fd0, 0x4fc4, 0x4fc2, 0x4fda, 0x4fce, 0x4fde, 0x4fb7,
+    0x5157, 0x5192, 0x5191, 0x51a0, 0x524e, 0x5243, 0x524a, 0x524d, 0x524c, 0x524b, 0x5247, 0x52c7, 0x52c9, 0x52c3, 0x52c1, 0x530d,
+    0x5357, 0x537b, 0x539a, 0x53db, 0x54ac, 0x54c0, 0x54a8, 0x54ce, 0x54c9, 0x54b8, 0x54a6, 0x54b3, 0x54c7, 0x54c2, 0x54bd, 0x54aa,
+    0x54c1, 0x54c4, 0x54c8, 0x54af, 0x54ab, 0x54b1, 0x54bb, 0x54a9, 0x54a7, 0x54bf, 0x56ff, 0x5782, 0x578b, 0x57a0, 0x57a3, 0x57a2,
+    0x57ce, 0x57ae, 0x5793, 0x5955, 0x5951, 0x594f, 0x594e, 0x5950, 0x59dc, 0x59d8, 0x59ff, 0x59e3, 0x59e8, 0x5a03, 0x59e5, 0x59ea,
+    0x59da, 0x59e6, 0x5a01, 0x59fb, 0x5b69, 0x5ba3, 0x5ba6, 0x5ba4, 0x5ba2, 0x5ba5, 0x5c01, 0x5c4e, 0x5c4f, 0x5c4d, 0x5c4b, 0x5cd9,
+    0x5cd2, 0x5df7, 0x5e1d, 0x5e25, 0x5e1f, 0x5e7d, 0x5ea0, 0x5ea6, 0x5efa, 0x5f08, 0x5f2d, 0x5f65, 0x5f88, 0x5f85, 0x5f8a, 0x5f8b,
+    0x5f87, 0x5f8c, 0x5f89, 0x6012, 0x601d, 0x6020, 0x6025, 0x600e, 0x6028, 0x604d, 0x6070, 0x6068, 0x6062, 0x6046, 0x6043, 0x606c,
+    0x606b, 0x606a, 0x6064, 0x6241, 0x62dc, 0x6316, 0x6309, 0x62fc, 0x62ed, 0x6301, 0x62ee, 0x62fd, 0x6307, 0x62f1, 0x62f7, 0x62ef,
+    0x62ec, 0x62fe, 0x62f4, 0x6311, 0x6302, 0x653f, 0x6545, 0x65ab, 0x65bd, 0x65e2, 0x6625, 0x662d, 0x6620, 0x6627, 0x662f, 0x661f,
+    0x6628, 0x6631, 0x6624, 0x66f7, 0x67ff, 0x67d3, 0x67f1, 0x67d4, 0x67d0, 0x67ec, 0x67b6, 0x67af, 0x67f5, 0x67e9, 0x67ef, 0x67c4,
+    0x67d1, 0x67b4, 0x67da, 0x67e5, 0x67b8, 0x67cf, 0x67de, 0x67f3, 0x67b0, 0x67d9, 0x67e2, 0x67dd, 0x67d2, 0x6b6a, 0x6b83, 0x6b86,
+    0x6bb5, 0x6bd2, 0x6bd7, 0x6c1f, 0x6cc9, 0x6d0b, 0x6d32, 0x6d2a, 0x6d41, 0x6d25, 0x6d0c, 0x6d31, 0x6d1e, 0x6d17, 0x6d3b, 0x6d3d,
+    0x6d3e, 0x6d36, 0x6d1b, 0x6cf5, 0x6d39, 0x6d27, 0x6d38, 0x6d29, 0x6d2e, 0x6d35, 0x6d0e, 0x6d2b, 0x70ab, 0x70ba, 0x70b3, 0x70ac,
+    0x70af, 0x70ad, 0x70b8, 0x70ae, 0x70a4, 0x7230, 0x7272, 0x726f, 0x7274, 0x72e9, 0x72e0, 0x72e1, 0x73b7, 0x73ca, 0x73bb, 0x73b2,
+    0x73cd, 0x73c0, 0x73b3, 0x751a, 0x752d, 0x754f, 0x754c, 0x754e, 0x754b, 0x75ab, 0x75a4, 0x75a5, 0x75a2, 0x75a3, 0x7678, 0x7686,
+    0x7687, 0x7688, 0x76c8, 0x76c6, 0x76c3, 0x76c5, 0x7701, 0x76f9, 0x76f8, 0x7709, 0x770b, 0x76fe, 0x76fc, 0x7707, 0x77dc, 0x7802,
+    0x7814, 0x780c, 0x780d, 0x7946, 0x7949, 0x7948, 0x7947, 0x79b9, 0x79ba, 0x79d1, 0x79d2, 0x79cb, 0x7a7f, 0x7a81, 0x7aff, 0x7afd,
+    0x7c7d, 0x7d02, 0x7d05, 0x7d00, 0x7d09, 0x7d07, 0x7d04, 0x7d06, 0x7f38, 0x7f8e, 0x7fbf, 0x8004, 0x8010, 0x800d, 0x8011, 0x8036,
+    0x80d6, 0x80e5, 0x80da, 0x80c3, 0x80c4, 0x80cc, 0x80e1, 0x80db, 0x80ce, 0x80de, 0x80e4, 0x80dd, 0x81f4, 0x8222, 0x82e7, 0x8303,
+    0x8305, 0x82e3, 0x82db, 0x82e6, 0x8304, 0x82e5, 0x8302, 0x8309, 0x82d2, 0x82d7, 0x82f1, 0x8301, 0x82dc, 0x82d4, 0x82d1, 0x82de,
+    0x82d3, 0x82df, 0x82ef, 0x8306, 0x8650, 0x8679, 0x867b, 0x867a, 0x884d, 0x886b, 0x8981, 0x89d4, 0x8a08, 0x8a02, 0x8a03, 0x8c9e,
+    0x8ca0, 0x8d74, 0x8d73, 0x8db4, 0x8ecd, 0x8ecc, 0x8ff0, 0x8fe6, 0x8fe2, 0x8fea, 0x8fe5, 0x8fed, 0x8feb, 0x8fe4, 0x8fe8, 0x90ca,
+    0x90ce, 0x90c1, 0x90c3, 0x914b, 0x914a, 0x91cd, 0x9582, 0x9650, 0x964b, 0x964c, 0x964d, 0x9762, 0x9769, 0x97cb, 0x97ed, 0x97f3,
+    0x9801, 0x98a8, 0x98db, 0x98df, 0x9996, 0x9999, 0x4e58, 0x4eb3, 0x500c, 0x500d, 0x5023, 0x4fef, 0x5026, 0x5025, 0x4ff8, 0x5029,
+    0x5016, 0x5006, 0x503c, 0x501f, 0x501a, 0x5012, 0x5011, 0x4ffa, 0x5000, 0x5014, 0x5028, 0x4ff1, 0x5021, 0x500b, 0x5019, 0x5018,
+    0x4ff3, 0x4fee, 0x502d, 0x502a, 0x4ffe, 0x502b, 0x5009, 0x517c, 0x51a4, 0x51a5, 0x51a2, 0x51cd, 0x51cc, 0x51c6, 0x51cb, 0x5256,
+    0x525c, 0x5254, 0x525b, 0x525d, 0x532a, 0x537f, 0x539f, 0x539d, 0x53df, 0x54e8, 0x5510, 0x5501, 0x5537, 0x54fc, 0x54e5, 0x54f2,
+    0x5506, 0x54fa, 0x5514, 0x54e9, 0x54ed, 0x54e1, 0x5509, 0x54ee, 0x54ea, 0x54e6, 0x5527, 0x5507, 0x54fd, 0x550f, 0x5703, 0x5704,
+    0x57c2, 0x57d4, 0x57cb, 0x57c3, 0x5809, 0x590f, 0x5957, 0x5958, 0x595a, 0x5a11, 0x5a18, 0x5a1c, 0x5a1f, 0x5a1b, 0x5a13, 0x59ec,
+    0x5a20, 0x5a23, 0x5a29, 0x5a25, 0x5a0c, 0x5a09, 0x5b6b, 0x5c58, 0x5bb0, 0x5bb3, 0x5bb6, 0x5bb4, 0x5bae, 0x5bb5, 0x5bb9, 0x5bb8,
+    0x5c04, 0x5c51, 0x5c55, 0x5c50, 0x5ced, 0x5cfd, 0x5cfb, 0x5cea, 0x5ce8, 0x5cf0, 0x5cf6, 0x5d01, 0x5cf4, 0x5dee, 0x5e2d, 0x5e2b,
+    0x5eab, 0x5ead, 0x5ea7, 0x5f31, 0x5f92, 0x5f91, 0x5f90, 0x6059, 0x6063, 0x6065, 0x6050, 0x6055, 0x606d, 0x6069, 0x606f, 0x6084,
+    0x609f, 0x609a, 0x608d, 0x6094, 0x608c, 0x6085, 0x6096, 0x6247, 0x62f3, 0x6308, 0x62ff, 0x634e, 0x633e, 0x632f, 0x6355, 0x6342,
+    0x6346, 0x634f, 0x6349, 0x633a, 0x6350, 0x633d, 0x632a, 0x632b, 0x6328, 0x634d, 0x634c, 0x6548, 0x6549, 0x6599, 0x65c1, 0x65c5,
+    0x6642, 0x6649, 0x664f, 0x6643, 0x6652, 0x664c, 0x6645, 0x6641, 0x66f8, 0x6714, 0x6715, 0x6717, 0x6821, 0x6838, 0x6848, 0x6846,
+    0x6853, 0x6839, 0x6842, 0x6854, 0x6829, 0x68b3, 0x6817, 0x684c, 0x6851, 0x683d, 0x67f4, 0x6850, 0x6840, 0x683c, 0x6843, 0x682a,
+    0x6845, 0x6813, 0x6818, 0x6841, 0x6b8a, 0x6b89, 0x6bb7, 0x6c23, 0x6c27, 0x6c28, 0x6c26, 0x6c24, 0x6cf0, 0x6d6a, 0x6d95, 0x6d88,
+    0x6d87, 0x6d66, 0x6d78, 0x6d77, 0x6d59, 0x6d93, 0x6d6c, 0x6d89, 0x6d6e, 0x6d5a, 0x6d74, 0x6d69, 0x6d8c, 0x6d8a, 0x6d79, 0x6d85,
+    0x6d65, 0x6d94, 0x70ca, 0x70d8, 0x70e4, 0x70d9, 0x70c8, 0x70cf, 0x7239, 0x7279, 0x72fc, 0x72f9, 0x72fd, 0x72f8, 0x72f7, 0x7386,
+    0x73ed, 0x7409, 0x73ee, 0x73e0, 0x73ea, 0x73de, 0x7554, 0x755d, 0x755c, 0x755a, 0x7559, 0x75be, 0x75c5, 0x75c7, 0x75b2, 0x75b3,
+    0x75bd, 0x75bc, 0x75b9, 0x75c2, 0x75b8, 0x768b, 0x76b0, 0x76ca, 0x76cd, 0x76ce, 0x7729, 0x771f, 0x7720, 0x7728, 0x77e9, 0x7830,
+    0x7827, 0x7838, 0x781d, 0x7834, 0x7837, 0x7825, 0x782d, 0x7820, 0x781f, 0x7832, 0x7955, 0x7950, 0x7960, 0x795f, 0x7956, 0x795e,
+    0x795d, 0x7957, 0x795a, 0x79e4, 0x79e3, 0x79e7, 0x79df, 0x79e6, 0x79e9, 0x79d8, 0x7a84, 0x7a88, 0x7ad9, 0x7b06, 0x7b11, 0x7c89,
+    0x7d21, 0x7d17, 0x7d0b, 0x7d0a, 0x7d20, 0x7d22, 0x7d14, 0x7d10, 0x7d15, 0x7d1a, 0x7d1c, 0x7d0d, 0x7d19, 0x7d1b, 0x7f3a, 0x7f5f,
+    0x7f94, 0x7fc5, 0x7fc1, 0x8006, 0x8018, 0x8015, 0x8019, 0x8017, 0x803d, 0x803f, 0x80f1, 0x8102, 0x80f0, 0x8105, 0x80ed, 0x80f4,
+    0x8106, 0x80f8, 0x80f3, 0x8108, 0x80fd, 0x810a, 0x80fc, 0x80ef, 0x81ed, 0x81ec, 0x8200, 0x8210, 0x822a, 0x822b, 0x8228, 0x822c,
+    0x82bb, 0x832b, 0x8352, 0x8354, 0x834a, 0x8338, 0x8350, 0x8349, 0x8335, 0x8334, 0x834f, 0x8332, 0x8339, 0x8336, 0x8317, 0x8340,
+    0x8331, 0x8328, 0x8343, 0x8654, 0x868a, 0x86aa, 0x8693, 0x86a4, 0x86a9, 0x868c, 0x86a3, 0x869c, 0x8870, 0x8877, 0x8881, 0x8882,
+    0x887d, 0x8879, 0x8a18, 0x8a10, 0x8a0e, 0x8a0c, 0x8a15, 0x8a0a, 0x8a17, 0x8a13, 0x8a16, 0x8a0f, 0x8a11, 0x8c48, 0x8c7a, 0x8c79,
+    0x8ca1, 0x8ca2, 0x8d77, 0x8eac, 0x8ed2, 0x8ed4, 0x8ecf, 0x8fb1, 0x9001, 0x9006, 0x8ff7, 0x9000, 0x8ffa, 0x8ff4, 0x9003, 0x8ffd,
+    0x9005, 0x8ff8, 0x9095, 0x90e1, 0x90dd, 0x90e2, 0x9152, 0x914d, 0x914c, 0x91d8, 0x91dd, 0x91d7, 0x91dc, 0x91d9, 0x9583, 0x9662,
+    0x9663, 0x9661, 0x965b, 0x965d, 0x9664, 0x9658, 0x965e, 0x96bb, 0x98e2, 0x99ac, 0x9aa8, 0x9ad8, 0x9b25, 0x9b32, 0x9b3c, 0x4e7e,
+    0x507a, 0x507d, 0x505c, 0x5047, 0x5043, 0x504c, 0x505a, 0x5049, 0x5065, 0x5076, 0x504e, 0x5055, 0x5075, 0x5074, 0x5077, 0x504f,
+    0x500f, 0x506f, 0x506d, 0x515c, 0x5195, 0x51f0, 0x526a, 0x526f, 0x52d2, 0x52d9, 0x52d8, 0x52d5, 0x5310, 0x530f, 0x5319, 0x533f,
+    0x5340, 0x533e, 0x53c3, 0x66fc, 0x5546, 0x556a, 0x5566, 0x5544, 0x555e, 0x5561, 0x5543, 0x554a, 0x5531, 0x5556, 0x554f, 0x5555,
+    0x552f, 0x5564, 0x5538, 0x552e, 0x555c, 0x552c, 0x5563, 0x5533, 0x5541, 0x5557, 0x5708, 0x570b, 0x5709, 0x57df, 0x5805, 0x580a,
+    0x5806, 0x57e0, 0x57e4, 0x57fa, 0x5802, 0x5835, 0x57f7, 0x57f9, 0x5920, 0x5962, 0x5a36, 0x5a41, 0x5a49, 0x5a66, 0x5a6a, 0x5a40,
+    0x5a3c, 0x5a62, 0x5a5a, 0x5a46, 0x5a4a, 0x5b70, 0x5bc7, 0x5bc5, 0x5bc4, 0x5bc2, 0x5bbf, 0x5bc6, 0x5c09, 0x5c08, 0x5c07, 0x5c60,
+    0x5c5c, 0x5c5d, 0x5d07, 0x5d06, 0x5d0e, 0x5d1b, 0x5d16, 0x5d22, 0x5d11, 0x5d29, 0x5d14, 0x5d19, 0x5d24, 0x5d27, 0x5d17, 0x5de2,
+    0x5e38, 0x5e36, 0x5e33, 0x5e37, 0x5eb7, 0x5eb8, 0x5eb6, 0x5eb5, 0x5ebe, 0x5f35, 0x5f37, 0x5f57, 0x5f6c, 0x5f69, 0x5f6b, 0x5f97,
+    0x5f99, 0x5f9e, 0x5f98, 0x5fa1, 0x5fa0, 0x5f9c, 0x607f, 0x60a3, 0x6089, 0x60a0, 0x60a8, 0x60cb, 0x60b4, 0x60e6, 0x60bd, 0x60c5,
+    0x60bb, 0x60b5, 0x60dc, 0x60bc, 0x60d8, 0x60d5, 0x60c6, 0x60df, 0x60b8, 0x60da, 0x60c7, 0x621a, 0x621b, 0x6248, 0x63a0, 0x63a7,
+    0x6372, 0x6396, 0x63a2, 0x63a5, 0x6377, 0x6367, 0x6398, 0x63aa, 0x6371, 0x63a9, 0x6389, 0x6383, 0x639b, 0x636b, 0x63a8, 0x6384,
+    0x6388, 0x6399, 0x63a1, 0x63ac, 0x6392, 0x638f, 0x6380, 0x637b, 0x6369, 0x6368, 0x637a, 0x655d, 0x6556, 0x6551, 0x6559, 0x6557,
+    0x555f, 0x654f, 0x6558, 0x6555, 0x6554, 0x659c, 0x659b, 0x65ac, 0x65cf, 0x65cb, 0x65cc, 0x65ce, 0x665d, 0x665a, 0x6664, 0x6668,
+    0x6666, 0x665e, 0x66f9, 0x52d7, 0x671b, 0x6881, 0x68af, 0x68a2, 0x6893, 0x68b5, 0x687f, 0x6876, 0x68b1, 0x68a7, 0x6897, 0x68b0,
+    0x6883, 0x68c4, 0x68ad, 0x6886, 0x6885, 0x6894, 0x689d, 0x68a8, 0x689f, 0x68a1, 0x6882, 0x6b32, 0x6bba, 0x6beb, 0x6bec, 0x6c2b,
+    0x6d8e, 0x6dbc, 0x6df3, 0x6dd9, 0x6db2, 0x6de1, 0x6dcc, 0x6de4, 0x6dfb, 0x6dfa, 0x6e05, 0x6dc7, 0x6dcb, 0x6daf, 0x6dd1, 0x6dae,
+    0x6dde, 0x6df9, 0x6db8, 0x6df7, 0x6df5, 0x6dc5, 0x6dd2, 0x6e1a, 0x6db5, 0x6dda, 0x6deb, 0x6dd8, 0x6dea, 0x6df1, 0x6dee, 0x6de8,
+    0x6dc6, 0x6dc4, 0x6daa, 0x6dec, 0x6dbf, 0x6de6, 0x70f9, 0x7109, 0x710a, 0x70fd, 0x70ef, 0x723d, 0x727d, 0x7281, 0x731c, 0x731b,
+    0x7316, 0x7313, 0x7319, 0x7387, 0x7405, 0x740a, 0x7403, 0x7406, 0x73fe, 0x740d, 0x74e0, 0x74f6, 0x74f7, 0x751c, 0x7522, 0x7565,
+    0x7566, 0x7562, 0x7570, 0x758f, 0x75d4, 0x75d5, 0x75b5, 0x75ca, 0x75cd, 0x768e, 0x76d4, 0x76d2, 0x76db, 0x7737, 0x773e, 0x773c,
+    0x7736, 0x7738, 0x773a, 0x786b, 0x7843, 0x784e, 0x7965, 0x7968, 0x796d, 0x79fb, 0x7a92, 0x7a95, 0x7b20, 0x7b28, 0x7b1b, 0x7b2c,
+    0x7b26, 0x7b19, 0x7b1e, 0x7b2e, 0x7c92, 0x7c97, 0x7c95, 0x7d46, 0x7d43, 0x7d71, 0x7d2e, 0x7d39, 0x7d3c, 0x7d40, 0x7d30, 0x7d33,
+    0x7d44, 0x7d2f, 0x7d42, 0x7d32, 0x7d31, 0x7f3d, 0x7f9e, 0x7f9a, 0x7fcc, 0x7fce, 0x7fd2, 0x801c, 0x804a, 0x8046, 0x812f, 0x8116,
+    0x8123, 0x812b, 0x8129, 0x8130, 0x8124, 0x8202, 0x8235, 0x8237, 0x8236, 0x8239, 0x838e, 0x839e, 0x8398, 0x8378, 0x83a2, 0x8396,
+    0x83bd, 0x83ab, 0x8392, 0x838a, 0x8393, 0x8389, 0x83a0, 0x8377, 0x837b, 0x837c, 0x8386, 0x83a7, 0x8655, 0x5f6a, 0x86c7, 0x86c0,
+    0x86b6, 0x86c4, 0x86b5, 0x86c6, 0x86cb, 0x86b1, 0x86af, 0x86c9, 0x8853, 0x889e, 0x8888, 0x88ab, 0x8892, 0x8896, 0x888d, 0x888b,
+    0x8993, 0x898f, 0x8a2a, 0x8a1d, 0x8a23, 0x8a25, 0x8a31, 0x8a2d, 0x8a1f, 0x8a1b, 0x8a22, 0x8c49, 0x8c5a, 0x8ca9, 0x8cac, 0x8cab,
+    0x8ca8, 0x8caa, 0x8ca7, 0x8d67, 0x8d66, 0x8dbe, 0x8dba, 0x8edb, 0x8edf, 0x9019, 0x900d, 0x901a, 0x9017, 0x9023, 0x901f, 0x901d,
+    0x9010, 0x9015, 0x901e, 0x9020, 0x900f, 0x9022, 0x9016, 0x901b, 0x9014, 0x90e8, 0x90ed, 0x90fd, 0x9157, 0x91ce, 0x91f5, 0x91e6,
+    0x91e3, 0x91e7, 0x91ed, 0x91e9, 0x9589, 0x966a, 0x9675, 0x9673, 0x9678, 0x9670, 0x9674, 0x9676, 0x9677, 0x966c, 0x96c0, 0x96ea,
+    0x96e9, 0x7ae0, 0x7adf, 0x9802, 0x9803, 0x9b5a, 0x9ce5, 0x9e75, 0x9e7f, 0x9ea5, 0x9ebb, 0x50a2, 0x508d, 0x5085, 0x5099, 0x5091,
+    0x5080, 0x5096, 0x5098, 0x509a, 0x6700, 0x51f1, 0x5272, 0x5274, 0x5275, 0x5269, 0x52de, 0x52dd, 0x52db, 0x535a, 0x53a5, 0x557b,
+    0x5580, 0x55a7, 0x557c, 0x558a, 0x559d, 0x5598, 0x5582, 0x559c, 0x55aa, 0x5594, 0x5587, 0x558b, 0x5583, 0x55b3, 0x55ae, 0x559f,
+    0x553e, 0x55b2, 0x559a, 0x55bb, 0x55ac, 0x55b1, 0x557e, 0x5589, 0x55ab, 0x5599, 0x570d, 0x582f, 0x582a, 0x5834, 0x5824, 0x5830,
+    0x5831, 0x5821, 0x581d, 0x5820, 0x58f9, 0x58fa, 0x5960, 0x5a77, 0x5a9a, 0x5a7f, 0x5a92, 0x5a9b, 0x5aa7, 0x5b73, 0x5b71, 0x5bd2,
+    0x5bcc, 0x5bd3, 0x5bd0, 0x5c0a, 0x5c0b, 0x5c31, 0x5d4c, 0x5d50, 0x5d34, 0x5d47, 0x5dfd, 0x5e45, 0x5e3d, 0x5e40, 0x5e43, 0x5e7e,
+    0x5eca, 0x5ec1, 0x5ec2, 0x5ec4, 0x5f3c, 0x5f6d, 0x5fa9, 0x5faa, 0x5fa8, 0x60d1, 0x60e1, 0x60b2, 0x60b6, 0x60e0, 0x611c, 0x6123,
+    0x60fa, 0x6115, 0x60f0, 0x60fb, 0x60f4, 0x6168, 0x60f1, 0x610e, 0x60f6, 0x6109, 0x6100, 0x6112, 0x621f, 0x6249, 0x63a3, 0x638c,
+    0x63cf, 0x63c0, 0x63e9, 0x63c9, 0x63c6, 0x63cd, 0x63d2, 0x63e3, 0x63d0, 0x63e1, 0x63d6, 0x63ed, 0x63ee, 0x6376, 0x63f4, 0x63ea,
+    0x63db, 0x6452, 0x63da, 0x63f9, 0x655e, 0x6566, 0x6562, 0x6563, 0x6591, 0x6590, 0x65af, 0x666e, 0x6670, 0x6674, 0x6676, 0x666f,
+    0x6691, 0x667a, 0x667e, 0x6677, 0x66fe, 0x66ff, 0x671f, 0x671d, 0x68fa, 0x68d5, 0x68e0, 0x68d8, 0x68d7, 0x6905, 0x68df, 0x68f5,
+    0x68ee, 0x68e7, 0x68f9, 0x68d2, 0x68f2, 0x68e3, 0x68cb, 0x68cd, 0x690d, 0x6912, 0x690e, 0x68c9, 0x68da, 0x696e, 0x68fb, 0x6b3e,
+    0x6b3a, 0x6b3d, 0x6b98, 0x6b96, 0x6bbc, 0x6bef, 0x6c2e, 0x6c2f, 0x6c2c, 0x6e2f, 0x6e38, 0x6e54, 0x6e21, 0x6e32, 0x6e67, 0x6e4a,
+    0x6e20, 0x6e25, 0x6e23, 0x6e1b, 0x6e5b, 0x6e58, 0x6e24, 0x6e56, 0x6e6e, 0x6e2d, 0x6e26, 0x6e6f, 0x6e34, 0x6e4d, 0x6e3a, 0x6e2c,
+    0x6e43, 0x6e1d, 0x6e3e, 0x6ecb, 0x6e89, 0x6e19, 0x6e4e, 0x6e63, 0x6e44, 0x6e72, 0x6e69, 0x6e5f, 0x7119, 0x711a, 0x7126, 0x7130,
+    0x7121, 0x7136, 0x716e, 0x711c, 0x724c, 0x7284, 0x7280, 0x7336, 0x7325, 0x7334, 0x7329, 0x743a, 0x742a, 0x7433, 0x7422, 0x7425,
+    0x7435, 0x7436, 0x7434, 0x742f, 0x741b, 0x7426, 0x7428, 0x7525, 0x7526, 0x756b, 0x756a, 0x75e2, 0x75db, 0x75e3, 0x75d9, 0x75d8,
+    0x75de, 0x75e0, 0x767b, 0x767c, 0x7696, 0x7693, 0x76b4, 0x76dc, 0x774f, 0x77ed, 0x785d, 0x786c, 0x786f, 0x7a0d, 0x7a08, 0x7a0b,
+    0x7a05, 0x7a00, 0x7a98, 0x7a97, 0x7a96, 0x7ae5, 0x7ae3, 0x7b49, 0x7b56, 0x7b46, 0x7b50, 0x7b52, 0x7b54, 0x7b4d, 0x7b4b, 0x7b4f,
+    0x7b51, 0x7c9f, 0x7ca5, 0x7d5e, 0x7d50, 0x7d68, 0x7d55, 0x7d2b, 0x7d6e, 0x7d72, 0x7d61, 0x7d66, 0x7d62, 0x7d70, 0x7d73, 0x5584,
+    0x7fd4, 0x7fd5, 0x800b, 0x8052, 0x8085, 0x8155, 0x8154, 0x814b, 0x8151, 0x814e, 0x8139, 0x8146, 0x813e, 0x814c, 0x8153, 0x8174,
+    0x8212, 0x821c, 0x83e9, 0x8403, 0x83f8, 0x840d, 0x83e0, 0x83c5, 0x840b, 0x83c1, 0x83ef, 0x83f1, 0x83f4, 0x8457, 0x840a, 0x83f0,
+    0x840c, 0x83cc, 0x83fd, 0x83f2, 0x83ca, 0x8438, 0x840e, 0x8404, 0x83dc, 0x8407, 0x83d4, 0x83df, 0x865b, 0x86df, 0x86d9, 0x86ed,
+    0x86d4, 0x86db, 0x86e4, 0x86d0, 0x86de, 0x8857, 0x88c1, 0x88c2, 0x88b1, 0x8983, 0x8996, 0x8a3b, 0x8a60, 0x8a55, 0x8a5e, 0x8a3c,
+    0x8a41, 0x8a54, 0x8a5b, 0x8a50, 0x8a46, 0x8a34, 0x8a3a, 0x8a36, 0x8a56, 0x8c61, 0x8c82, 0x8caf, 0x8cbc, 0x8cb3, 0x8cbd, 0x8cc1,
+    0x8cbb, 0x8cc0, 0x8cb4, 0x8cb7, 0x8cb6, 0x8cbf, 0x8cb8, 0x8d8a, 0x8d85, 0x8d81, 0x8dce, 0x8ddd, 0x8dcb, 0x8dda, 0x8dd1, 0x8dcc,
+    0x8ddb, 0x8dc6, 0x8efb, 0x8ef8, 0x8efc, 0x8f9c, 0x902e, 0x9035, 0x9031, 0x9038, 0x9032, 0x9036, 0x9102, 0x90f5, 0x9109, 0x90fe,
+    0x9163, 0x9165, 0x91cf, 0x9214, 0x9215, 0x9223, 0x9209, 0x921e, 0x920d, 0x9210, 0x9207, 0x9211, 0x9594, 0x958f, 0x958b, 0x9591,
+    0x9593, 0x9592, 0x958e, 0x968a, 0x968e, 0x968b, 0x967d, 0x9685, 0x9686, 0x968d, 0x9672, 0x9684, 0x96c1, 0x96c5, 0x96c4, 0x96c6,
+    0x96c7, 0x96ef, 0x96f2, 0x97cc, 0x9805, 0x9806, 0x9808, 0x98e7, 0x98ea, 0x98ef, 0x98e9, 0x98f2, 0x98ed, 0x99ae, 0x99ad, 0x9ec3,
+    0x9ecd, 0x9ed1, 0x4e82, 0x50ad, 0x50b5, 0x50b2, 0x50b3, 0x50c5, 0x50be, 0x50ac, 0x50b7, 0x50bb, 0x50af, 0x50c7, 0x527f, 0x5277,
+    0x527d, 0x52df, 0x52e6, 0x52e4, 0x52e2, 0x52e3, 0x532f, 0x55df, 0x55e8, 0x55d3, 0x55e6, 0x55ce, 0x55dc, 0x55c7, 0x55d1, 0x55e3,
+    0x55e4, 0x55ef, 0x55da, 0x55e1, 0x55c5, 0x55c6, 0x55e5, 0x55c9, 0x5712, 0x5713, 0x585e, 0x5851, 0x5858, 0x5857, 0x585a, 0x5854,
+    0x586b, 0x584c, 0x586d, 0x584a, 0x5862, 0x5852, 0x584b, 0x5967, 0x5ac1, 0x5ac9, 0x5acc, 0x5abe, 0x5abd, 0x5abc, 0x5ab3, 0x5ac2,
+    0x5ab2, 0x5d69, 0x5d6f, 0x5e4c, 0x5e79, 0x5ec9, 0x5ec8, 0x5f12, 0x5f59, 0x5fac, 0x5fae, 0x611a, 0x610f, 0x6148, 0x611f, 0x60f3,
+    0x611b, 0x60f9, 0x6101, 0x6108, 0x614e, 0x614c, 0x6144, 0x614d, 0x613e, 0x6134, 0x6127, 0x610d, 0x6106, 0x6137, 0x6221, 0x6222,
+    0x6413, 0x643e, 0x641e, 0x642a, 0x642d, 0x643d, 0x642c, 0x640f, 0x641c, 0x6414, 0x640d, 0x6436, 0x6416, 0x6417, 0x6406, 0x656c,
+    0x659f, 0x65b0, 0x6697, 0x6689, 0x6687, 0x6688, 0x6696, 0x6684, 0x6698, 0x668d, 0x6703, 0x6994, 0x696d, 0x695a, 0x6977, 0x6960,
+    0x6954, 0x6975, 0x6930, 0x6982, 0x694a, 0x6968, 0x696b, 0x695e, 0x6953, 0x6979, 0x6986, 0x695d, 0x6963, 0x695b, 0x6b47, 0x6b72,
+    0x6bc0, 0x6bbf, 0x6bd3, 0x6bfd, 0x6ea2, 0x6eaf, 0x6ed3, 0x6eb6, 0x6ec2, 0x6e90, 0x6e9d, 0x6ec7, 0x6ec5, 0x6ea5, 0x6e98, 0x6ebc,
+    0x6eba, 0x6eab, 0x6ed1, 0x6e96, 0x6e9c, 0x6ec4, 0x6ed4, 0x6eaa, 0x6ea7, 0x6eb4, 0x714e, 0x7159, 0x7169, 0x7164, 0x7149, 0x7167,
+    0x715c, 0x716c, 0x7166, 0x714c, 0x7165, 0x715e, 0x7146, 0x7168, 0x7156, 0x723a, 0x7252, 0x7337, 0x7345, 0x733f, 0x733e, 0x746f,
+    0x745a, 0x7455, 0x745f, 0x745e, 0x7441, 0x743f, 0x7459, 0x745b, 0x745c, 0x7576, 0x7578, 0x7600, 0x75f0, 0x7601, 0x75f2, 0x75f1,
+    0x75fa, 0x75ff, 0x75f4, 0x75f3, 0x76de, 0x76df, 0x775b, 0x776b, 0x7766, 0x775e, 0x7763, 0x7779, 0x776a, 0x776c, 0x775c, 0x7765,
+    0x7768, 0x7762, 0x77ee, 0x788e, 0x78b0, 0x7897, 0x7898, 0x788c, 0x7889, 0x787c, 0x7891, 0x7893, 0x787f, 0x797a, 0x797f, 0x7981,
+    0x842c, 0x79bd, 0x7a1c, 0x7a1a, 0x7a20, 0x7a14, 0x7a1f, 0x7a1e, 0x7a9f, 0x7aa0, 0x7b77, 0x7bc0, 0x7b60, 0x7b6e, 0x7b67, 0x7cb1,
+    0x7cb3, 0x7cb5, 0x7d93, 0x7d79, 0x7d91, 0x7d81, 0x7d8f, 0x7d5b, 0x7f6e, 0x7f69, 0x7f6a, 0x7f72, 0x7fa9, 0x7fa8, 0x7fa4, 0x8056,
+    0x8058, 0x8086, 0x8084, 0x8171, 0x8170, 0x8178, 0x8165, 0x816e, 0x8173, 0x816b, 0x8179, 0x817a, 0x8166, 0x8205, 0x8247, 0x8482,
+    0x8477, 0x843d, 0x8431, 0x8475, 0x8466, 0x846b, 0x8449, 0x846c, 0x845b, 0x843c, 0x8435, 0x8461, 0x8463, 0x8469, 0x846d, 0x8446,
+    0x865e, 0x865c, 0x865f, 0x86f9, 0x8713, 0x8708, 0x8707, 0x8700, 0x86fe, 0x86fb, 0x8702, 0x8703, 0x8706, 0x870a, 0x8859, 0x88df,
+    0x88d4, 0x88d9, 0x88dc, 0x88d8, 0x88dd, 0x88e1, 0x88ca, 0x88d5, 0x88d2, 0x899c, 0x89e3, 0x8a6b, 0x8a72, 0x8a73, 0x8a66, 0x8a69,
+    0x8a70, 0x8a87, 0x8a7c, 0x8a63, 0x8aa0, 0x8a71, 0x8a85, 0x8a6d, 0x8a62, 0x8a6e, 0x8a6c, 0x8a79, 0x8a7b, 0x8a3e, 0x8a68, 0x8c62,
+    0x8c8a, 0x8c89, 0x8cca, 0x8cc7, 0x8cc8, 0x8cc4, 0x8cb2, 0x8cc3, 0x8cc2, 0x8cc5, 0x8de1, 0x8ddf, 0x8de8, 0x8def, 0x8df3, 0x8dfa,
+    0x8dea, 0x8de4, 0x8de6, 0x8eb2, 0x8f03, 0x8f09, 0x8efe, 0x8f0a, 0x8f9f, 0x8fb2, 0x904b, 0x904a, 0x9053, 0x9042, 0x9054, 0x903c,
+    0x9055, 0x9050, 0x9047, 0x904f, 0x904e, 0x904d, 0x9051, 0x903e, 0x9041, 0x9112, 0x9117, 0x916c, 0x916a, 0x9169, 0x91c9, 0x9237,
+    0x9257, 0x9238, 0x923d, 0x9240, 0x923e, 0x925b, 0x924b, 0x9264, 0x9251, 0x9234, 0x9249, 0x924d, 0x9245, 0x9239, 0x923f, 0x925a,
+    0x9598, 0x9698, 0x9694, 0x9695, 0x96cd, 0x96cb, 0x96c9, 0x96ca, 0x96f7, 0x96fb, 0x96f9, 0x96f6, 0x9756, 0x9774, 0x9776, 0x9810,
+    0x9811, 0x9813, 0x980a, 0x9812, 0x980c, 0x98fc, 0x98f4, 0x98fd, 0x98fe, 0x99b3, 0x99b1, 0x99b4, 0x9ae1, 0x9ce9, 0x9e82, 0x9f0e,
+    0x9f13, 0x9f20, 0x50e7, 0x50ee, 0x50e5, 0x50d6, 0x50ed, 0x50da, 0x50d5, 0x50cf, 0x50d1, 0x50f1, 0x50ce, 0x50e9, 0x5162, 0x51f3,
+    0x5283, 0x5282, 0x5331, 0x53ad, 0x55fe, 0x5600, 0x561b, 0x5617, 0x55fd, 0x5614, 0x5606, 0x5609, 0x560d, 0x560e, 0x55f7, 0x5616,
+    0x561f, 0x5608, 0x5610, 0x55f6, 0x5718, 0x5716, 0x5875, 0x587e, 0x5883, 0x5893, 0x588a, 0x5879, 0x5885, 0x587d, 0x58fd, 0x5925,
+    0x5922, 0x5924, 0x596a, 0x5969, 0x5ae1, 0x5ae6, 0x5ae9, 0x5ad7, 0x5ad6, 0x5ad8, 0x5ae3, 0x5b75, 0x5bde, 0x5be7, 0x5be1, 0x5be5,
+    0x5be6, 0x5be8, 0x5be2, 0x5be4, 0x5bdf, 0x5c0d, 0x5c62, 0x5d84, 0x5d87, 0x5e5b, 0x5e63, 0x5e55, 0x5e57, 0x5e54, 0x5ed3, 0x5ed6,
+    0x5f0a, 0x5f46, 0x5f70, 0x5fb9, 0x6147, 0x613f, 0x614b, 0x6177, 0x6162, 0x6163, 0x615f, 0x615a, 0x6158, 0x6175, 0x622a, 0x6487,
+    0x6458, 0x6454, 0x64a4, 0x6478, 0x645f, 0x647a, 0x6451, 0x6467, 0x6434, 0x646d, 0x647b, 0x6572, 0x65a1, 0x65d7, 0x65d6, 0x66a2,
+    0x66a8, 0x669d, 0x699c, 0x69a8, 0x6995, 0x69c1, 0x69ae, 0x69d3, 0x69cb, 0x699b, 0x69b7, 0x69bb, 0x69ab, 0x69b4, 0x69d0, 0x69cd,
+    0x69ad, 0x69cc, 0x69a6, 0x69c3, 0x69a3, 0x6b49, 0x6b4c, 0x6c33, 0x6f33, 0x6f14, 0x6efe, 0x6f13, 0x6ef4, 0x6f29, 0x6f3e, 0x6f20,
+    0x6f2c, 0x6f0f, 0x6f02, 0x6f22, 0x6eff, 0x6eef, 0x6f06, 0x6f31, 0x6f38, 0x6f32, 0x6f23, 0x6f15, 0x6f2b, 0x6f2f, 0x6f88, 0x6f2a,
+    0x6eec, 0x6f01, 0x6ef2, 0x6ecc, 0x6ef7, 0x7194, 0x7199, 0x717d, 0x718a, 0x7184, 0x7192, 0x723e, 0x7292, 0x7296, 0x7344, 0x7350,
+    0x7464, 0x7463, 0x746a, 0x7470, 0x746d, 0x7504, 0x7591, 0x7627, 0x760d, 0x760b, 0x7609, 0x7613, 0x76e1, 0x76e3, 0x7784, 0x777d,
+    0x777f, 0x7761, 0x78c1, 0x789f, 0x78a7, 0x78b3, 0x78a9, 0x78a3, 0x798e, 0x798f, 0x798d, 0x7a2e, 0x7a31, 0x7aaa, 0x7aa9, 0x7aed,
+    0x7aef, 0x7ba1, 0x7b95, 0x7b8b, 0x7b75, 0x7b97, 0x7b9d, 0x7b94, 0x7b8f, 0x7bb8, 0x7b87, 0x7b84, 0x7cb9, 0x7cbd, 0x7cbe, 0x7dbb,
+    0x7db0, 0x7d9c, 0x7dbd, 0x7dbe, 0x7da0, 0x7dca, 0x7db4, 0x7db2, 0x7db1, 0x7dba, 0x7da2, 0x7dbf, 0x7db5, 0x7db8, 0x7dad, 0x7dd2,
+    0x7dc7, 0x7dac, 0x7f70, 0x7fe0, 0x7fe1, 0x7fdf, 0x805e, 0x805a, 0x8087, 0x8150, 0x8180, 0x818f, 0x8188, 0x818a, 0x817f, 0x8182,
+    0x81e7, 0x81fa, 0x8207, 0x8214, 0x821e, 0x824b, 0x84c9, 0x84bf, 0x84c6, 0x84c4, 0x8499, 0x849e, 0x84b2, 0x849c, 0x84cb, 0x84b8,
+    0x84c0, 0x84d3, 0x8490, 0x84bc, 0x84d1, 0x84ca, 0x873f, 0x871c, 0x873b, 0x8722, 0x8725, 0x8734, 0x8718, 0x8755, 0x8737, 0x8729,
+    0x88f3, 0x8902, 0x88f4, 0x88f9, 0x88f8, 0x88fd, 0x88e8, 0x891a, 0x88ef, 0x8aa6, 0x8a8c, 0x8a9e, 0x8aa3, 0x8a8d, 0x8aa1, 0x8a93,
+    0x8aa4, 0x8aaa, 0x8aa5, 0x8aa8, 0x8a98, 0x8a91, 0x8a9a, 0x8aa7, 0x8c6a, 0x8c8d, 0x8c8c, 0x8cd3, 0x8cd1, 0x8cd2, 0x8d6b, 0x8d99,
+    0x8d95, 0x8dfc, 0x8f14, 0x8f12, 0x8f15, 0x8f13, 0x8fa3, 0x9060, 0x9058, 0x905c, 0x9063, 0x9059, 0x905e, 0x9062, 0x905d, 0x905b,
+    0x9119, 0x9118, 0x911e, 0x9175, 0x9178, 0x9177, 0x9174, 0x9278, 0x9280, 0x9285, 0x9298, 0x9296, 0x927b, 0x9293, 0x929c, 0x92a8,
+    0x927c, 0x9291, 0x95a1, 0x95a8, 0x95a9, 0x95a3, 0x95a5, 0x95a4, 0x9699, 0x969c, 0x969b, 0x96cc, 0x96d2, 0x9700, 0x977c, 0x9785,
+    0x97f6, 0x9817, 0x9818, 0x98af, 0x98b1, 0x9903, 0x9905, 0x990c, 0x9909, 0x99c1, 0x9aaf, 0x9ab0, 0x9ae6, 0x9b41, 0x9b42, 0x9cf4,
+    0x9cf6, 0x9cf3, 0x9ebc, 0x9f3b, 0x9f4a, 0x5104, 0x5100, 0x50fb, 0x50f5, 0x50f9, 0x5102, 0x5108, 0x5109, 0x5105, 0x51dc, 0x5287,
+    0x5288, 0x5289, 0x528d, 0x528a, 0x52f0, 0x53b2, 0x562e, 0x563b, 0x5639, 0x5632, 0x563f, 0x5634, 0x5629, 0x5653, 0x564e, 0x5657,
+    0x5674, 0x5636, 0x562f, 0x5630, 0x5880, 0x589f, 0x589e, 0x58b3, 0x589c, 0x58ae, 0x58a9, 0x58a6, 0x596d, 0x5b09, 0x5afb, 0x5b0b,
+    0x5af5, 0x5b0c, 0x5b08, 0x5bee, 0x5bec, 0x5be9, 0x5beb, 0x5c64, 0x5c65, 0x5d9d, 0x5d94, 0x5e62, 0x5e5f, 0x5e61, 0x5ee2, 0x5eda,
+    0x5edf, 0x5edd, 0x5ee3, 0x5ee0, 0x5f48, 0x5f71, 0x5fb7, 0x5fb5, 0x6176, 0x6167, 0x616e, 0x615d, 0x6155, 0x6182, 0x617c, 0x6170,
+    0x616b, 0x617e, 0x61a7, 0x6190, 0x61ab, 0x618e, 0x61ac, 0x619a, 0x61a4, 0x6194, 0x61ae, 0x622e, 0x6469, 0x646f, 0x6479, 0x649e,
+    0x64b2, 0x6488, 0x6490, 0x64b0, 0x64a5, 0x6493, 0x6495, 0x64a9, 0x6492, 0x64ae, 0x64ad, 0x64ab, 0x649a, 0x64ac, 0x6499, 0x64a2,
+    0x64b3, 0x6575, 0x6577, 0x6578, 0x66ae, 0x66ab, 0x66b4, 0x66b1, 0x6a23, 0x6a1f, 0x69e8, 0x6a01, 0x6a1e, 0x6a19, 0x69fd, 0x6a21,
+    0x6a13, 0x6a0a, 0x69f3, 0x6a02, 0x6a05, 0x69ed, 0x6a11, 0x6b50, 0x6b4e, 0x6ba4, 0x6bc5, 0x6bc6, 0x6f3f, 0x6f7c, 0x6f84, 0x6f51,
+    0x6f66, 0x6f54, 0x6f86, 0x6f6d, 0x6f5b, 0x6f78, 0x6f6e, 0x6f8e, 0x6f7a, 0x6f70, 0x6f64, 0x6f97, 0x6f58, 0x6ed5, 0x6f6f, 0x6f60,
+    0x6f5f, 0x719f, 0x71ac, 0x71b1, 0x71a8, 0x7256, 0x729b, 0x734e, 0x7357, 0x7469, 0x748b, 0x7483, 0x747e, 0x7480, 0x757f, 0x7620,
+    0x7629, 0x761f, 0x7624, 0x7626, 0x7621, 0x7622, 0x769a, 0x76ba, 0x76e4, 0x778e, 0x7787, 0x778c, 0x7791, 0x778b, 0x78cb, 0x78c5,
+    0x78ba, 0x78ca, 0x78be, 0x78d5, 0x78bc, 0x78d0, 0x7a3f, 0x7a3c, 0x7a40, 0x7a3d, 0x7a37, 0x7a3b, 0x7aaf, 0x7aae, 0x7bad, 0x7bb1,
+    0x7bc4, 0x7bb4, 0x7bc6, 0x7bc7, 0x7bc1, 0x7ba0, 0x7bcc, 0x7cca, 0x7de0, 0x7df4, 0x7def, 0x7dfb, 0x7dd8, 0x7dec, 0x7ddd, 0x7de8,
+    0x7de3, 0x7dda, 0x7dde, 0x7de9, 0x7d9e, 0x7dd9, 0x7df2, 0x7df9, 0x7f75, 0x7f77, 0x7faf, 0x7fe9, 0x8026, 0x819b, 0x819c, 0x819d,
+    0x81a0, 0x819a, 0x8198, 0x8517, 0x853d, 0x851a, 0x84ee, 0x852c, 0x852d, 0x8513, 0x8511, 0x8523, 0x8521, 0x8514, 0x84ec, 0x8525,
+    0x84ff, 0x8506, 0x8782, 0x8774, 0x8776, 0x8760, 0x8766, 0x8778, 0x8768, 0x8759, 0x8757, 0x874c, 0x8753, 0x885b, 0x885d, 0x8910,
+    0x8907, 0x8912, 0x8913, 0x8915, 0x890a, 0x8abc, 0x8ad2, 0x8ac7, 0x8ac4, 0x8a95, 0x8acb, 0x8af8, 0x8ab2, 0x8ac9, 0x8ac2, 0x8abf,
+    0x8ab0, 0x8ad6, 0x8acd, 0x8ab6, 0x8ab9, 0x8adb, 0x8c4c, 0x8c4e, 0x8c6c, 0x8ce0, 0x8cde, 0x8ce6, 0x8ce4, 0x8cec, 0x8ced, 0x8ce2,
+    0x8ce3, 0x8cdc, 0x8cea, 0x8ce1, 0x8d6d, 0x8d9f, 0x8da3, 0x8e2b, 0x8e10, 0x8e1d, 0x8e22, 0x8e0f, 0x8e29, 0x8e1f, 0x8e21, 0x8e1e,
+    0x8eba, 0x8f1d, 0x8f1b, 0x8f1f, 0x8f29, 0x8f26, 0x8f2a, 0x8f1c, 0x8f1e, 0x8f25, 0x9069, 0x906e, 0x9068, 0x906d, 0x9077, 0x9130,
+    0x912d, 0x9127, 0x9131, 0x9187, 0x9189, 0x918b, 0x9183, 0x92c5, 0x92bb, 0x92b7, 0x92ea, 0x92ac, 0x92e4, 0x92c1, 0x92b3, 0x92bc,
+    0x92d2, 0x92c7, 0x92f0, 0x92b2, 0x95ad, 0x95b1, 0x9704, 0x9706, 0x9707, 0x9709, 0x9760, 0x978d, 0x978b, 0x978f, 0x9821, 0x982b,
+    0x981c, 0x98b3, 0x990a, 0x9913, 0x9912, 0x9918, 0x99dd, 0x99d0, 0x99df, 0x99db, 0x99d1, 0x99d5, 0x99d2, 0x99d9, 0x9ab7, 0x9aee,
+    0x9aef, 0x9b27, 0x9b45, 0x9b44, 0x9b77, 0x9b6f, 0x9d06, 0x9d09, 0x9d03, 0x9ea9, 0x9ebe, 0x9ece, 0x58a8, 0x9f52, 0x5112, 0x5118,
+    0x5114, 0x5110, 0x5115, 0x5180, 0x51aa, 0x51dd, 0x5291, 0x5293, 0x52f3, 0x5659, 0x566b, 0x5679, 0x5669, 0x5664, 0x5678, 0x566a,
+    0x5668, 0x5665, 0x5671, 0x566f, 0x566c, 0x5662, 0x5676, 0x58c1, 0x58be, 0x58c7, 0x58c5, 0x596e, 0x5b1d, 0x5b34, 0x5b78, 0x5bf0,
+    0x5c0e, 0x5f4a, 0x61b2, 0x6191, 0x61a9, 0x618a, 0x61cd, 0x61b6, 0x61be, 0x61ca, 0x61c8, 0x6230, 0x64c5, 0x64c1, 0x64cb, 0x64bb,
+    0x64bc, 0x64da, 0x64c4, 0x64c7, 0x64c2, 0x64cd, 0x64bf, 0x64d2, 0x64d4, 0x64be, 0x6574, 0x66c6, 0x66c9, 0x66b9, 0x66c4, 0x66c7,
+    0x66b8, 0x6a3d, 0x6a38, 0x6a3a, 0x6a59, 0x6a6b, 0x6a58, 0x6a39, 0x6a44, 0x6a62, 0x6a61, 0x6a4b, 0x6a47, 0x6a35, 0x6a5f, 0x6a48,
+    0x6b59, 0x6b77, 0x6c05, 0x6fc2, 0x6fb1, 0x6fa1, 0x6fc3, 0x6fa4, 0x6fc1, 0x6fa7, 0x6fb3, 0x6fc0, 0x6fb9, 0x6fb6, 0x6fa6, 0x6fa0,
+    0x6fb4, 0x71be, 0x71c9, 0x71d0, 0x71d2, 0x71c8, 0x71d5, 0x71b9, 0x71ce, 0x71d9, 0x71dc, 0x71c3, 0x71c4, 0x7368, 0x749c, 0x74a3,
+    0x7498, 0x749f, 0x749e, 0x74e2, 0x750c, 0x750d, 0x7634, 0x7638, 0x763a, 0x76e7, 0x76e5, 0x77a0, 0x779e, 0x779f, 0x77a5, 0x78e8,
+    0x78da, 0x78ec, 0x78e7, 0x79a6, 0x7a4d, 0x7a4e, 0x7a46, 0x7a4c, 0x7a4b, 0x7aba, 0x7bd9, 0x7c11, 0x7bc9, 0x7be4, 0x7bdb, 0x7be1,
+    0x7be9, 0x7be6, 0x7cd5, 0x7cd6, 0x7e0a, 0x7e11, 0x7e08, 0x7e1b, 0x7e23, 0x7e1e, 0x7e1d, 0x7e09, 0x7e10, 0x7f79, 0x7fb2, 0x7ff0,
+    0x7ff1, 0x7fee, 0x8028, 0x81b3, 0x81a9, 0x81a8, 0x81fb, 0x8208, 0x8258, 0x8259, 0x854a, 0x8559, 0x8548, 0x8568, 0x8569, 0x8543,
+    0x8549, 0x856d, 0x856a, 0x855e, 0x8783, 0x879f, 0x879e, 0x87a2, 0x878d, 0x8861, 0x892a, 0x8932, 0x8925, 0x892b, 0x8921, 0x89aa,
+    0x89a6, 0x8ae6, 0x8afa, 0x8aeb, 0x8af1, 0x8b00, 0x8adc, 0x8ae7, 0x8aee, 0x8afe, 0x8b01, 0x8b02, 0x8af7, 0x8aed, 0x8af3, 0x8af6,
+    0x8afc, 0x8c6b, 0x8c6d, 0x8c93, 0x8cf4, 0x8e44, 0x8e31, 0x8e34, 0x8e42, 0x8e39, 0x8e35, 0x8f3b, 0x8f2f, 0x8f38, 0x8f33, 0x8fa8,
+    0x8fa6, 0x9075, 0x9074, 0x9078, 0x9072, 0x907c, 0x907a, 0x9134, 0x9192, 0x9320, 0x9336, 0x92f8, 0x9333, 0x932f, 0x9322, 0x92fc,
+    0x932b, 0x9304, 0x931a, 0x9310, 0x9326, 0x9321, 0x9315, 0x932e, 0x9319, 0x95bb, 0x96a7, 0x96a8, 0x96aa, 0x96d5, 0x970e, 0x9711,
+    0x9716, 0x970d, 0x9713, 0x970f, 0x975b, 0x975c, 0x9766, 0x9798, 0x9830, 0x9838, 0x983b, 0x9837, 0x982d, 0x9839, 0x9824, 0x9910,
+    0x9928, 0x991e, 0x991b, 0x9921, 0x991a, 0x99ed, 0x99e2, 0x99f1, 0x9ab8, 0x9abc, 0x9afb, 0x9aed, 0x9b28, 0x9b91, 0x9d15, 0x9d23,
+    0x9d26, 0x9d28, 0x9d12, 0x9d1b, 0x9ed8, 0x9ed4, 0x9f8d, 0x9f9c, 0x512a, 0x511f, 0x5121, 0x5132, 0x52f5, 0x568e, 0x5680, 0x5690,
+    0x5685, 0x5687, 0x568f, 0x58d5, 0x58d3, 0x58d1, 0x58ce, 0x5b30, 0x5b2a, 0x5b24, 0x5b7a, 0x5c37, 0x5c68, 0x5dbc, 0x5dba, 0x5dbd,
+    0x5db8, 0x5e6b, 0x5f4c, 0x5fbd, 0x61c9, 0x61c2, 0x61c7, 0x61e6, 0x61cb, 0x6232, 0x6234, 0x64ce, 0x64ca, 0x64d8, 0x64e0, 0x64f0,
+    0x64e6, 0x64ec, 0x64f1, 0x64e2, 0x64ed, 0x6582, 0x6583, 0x66d9, 0x66d6, 0x6a80, 0x6a94, 0x6a84, 0x6aa2, 0x6a9c, 0x6adb, 0x6aa3,
+    0x6a7e, 0x6a97, 0x6a90, 0x6aa0, 0x6b5c, 0x6bae, 0x6bda, 0x6c08, 0x6fd8, 0x6ff1, 0x6fdf, 0x6fe0, 0x6fdb, 0x6fe4, 0x6feb, 0x6fef,
+    0x6f80, 0x6fec, 0x6fe1, 0x6fe9, 0x6fd5, 0x6fee, 0x6ff0, 0x71e7, 0x71df, 0x71ee, 0x71e6, 0x71e5, 0x71ed, 0x71ec, 0x71f4, 0x71e0,
+    0x7235, 0x7246, 0x7370, 0x7372, 0x74a9, 0x74b0, 0x74a6, 0x74a8, 0x7646, 0x7642, 0x764c, 0x76ea, 0x77b3, 0x77aa, 0x77b0, 0x77ac,
+    0x77a7, 0x77ad, 0x77ef, 0x78f7, 0x78fa, 0x78f4, 0x78ef, 0x7901, 0x79a7, 0x79aa, 0x7a57, 0x7abf, 0x7c07, 0x7c0d, 0x7bfe, 0x7bf7,
+    0x7c0c, 0x7be0, 0x7ce0, 0x7cdc, 0x7cde, 0x7ce2, 0x7cdf, 0x7cd9, 0x7cdd, 0x7e2e, 0x7e3e, 0x7e46, 0x7e37, 0x7e32, 0x7e43, 0x7e2b,
+    0x7e3d, 0x7e31, 0x7e45, 0x7e41, 0x7e34, 0x7e39, 0x7e48, 0x7e35, 0x7e3f, 0x7e2f, 0x7f44, 0x7ff3, 0x7ffc, 0x8071, 0x8072, 0x8070,
+    0x806f, 0x8073, 0x81c6, 0x81c3, 0x81ba, 0x81c2, 0x81c0, 0x81bf, 0x81bd, 0x81c9, 0x81be, 0x81e8, 0x8209, 0x8271, 0x85aa, 0x8584,
+    0x857e, 0x859c, 0x8591, 0x8594, 0x85af, 0x859b, 0x8587, 0x85a8, 0x858a, 0x8667, 0x87c0, 0x87d1, 0x87b3, 0x87d2, 0x87c6, 0x87ab,
+    0x87bb, 0x87ba, 0x87c8, 0x87cb, 0x893b, 0x8936, 0x8944, 0x8938, 0x893d, 0x89ac, 0x8b0e, 0x8b17, 0x8b19, 0x8b1b, 0x8b0a, 0x8b20,
+    0x8b1d, 0x8b04, 0x8b10, 0x8c41, 0x8c3f, 0x8c73, 0x8cfa, 0x8cfd, 0x8cfc, 0x8cf8, 0x8cfb, 0x8da8, 0x8e49, 0x8e4b, 0x8e48, 0x8e4a,
+    0x8f44, 0x8f3e, 0x8f42, 0x8f45, 0x8f3f, 0x907f, 0x907d, 0x9084, 0x9081, 0x9082, 0x9080, 0x9139, 0x91a3, 0x919e, 0x919c, 0x934d,
+    0x9382, 0x9328, 0x9375, 0x934a, 0x9365, 0x934b, 0x9318, 0x937e, 0x936c, 0x935b, 0x9370, 0x935a, 0x9354, 0x95ca, 0x95cb, 0x95cc,
+    0x95c8, 0x95c6, 0x96b1, 0x96b8, 0x96d6, 0x971c, 0x971e, 0x97a0, 0x97d3, 0x9846, 0x98b6, 0x9935, 0x9a01, 0x99ff, 0x9bae, 0x9bab,
+    0x9baa, 0x9bad, 0x9d3b, 0x9d3f, 0x9e8b, 0x9ecf, 0x9ede, 0x9edc, 0x9edd, 0x9edb, 0x9f3e, 0x9f4b, 0x53e2, 0x5695, 0x56ae, 0x58d9,
+    0x58d8, 0x5b38, 0x5f5d, 0x61e3, 0x6233, 0x64f4, 0x64f2, 0x64fe, 0x6506, 0x64fa, 0x64fb, 0x64f7, 0x65b7, 0x66dc, 0x6726, 0x6ab3,
+    0x6aac, 0x6ac3, 0x6abb, 0x6ab8, 0x6ac2, 0x6aae, 0x6aaf, 0x6b5f, 0x6b78, 0x6baf, 0x7009, 0x700b, 0x6ffe, 0x7006, 0x6ffa, 0x7011,
+    0x700f, 0x71fb, 0x71fc, 0x71fe, 0x71f8, 0x7377, 0x7375, 0x74a7, 0x74bf, 0x7515, 0x7656, 0x7658, 0x7652, 0x77bd, 0x77bf, 0x77bb,
+    0x77bc, 0x790e, 0x79ae, 0x7a61, 0x7a62, 0x7a60, 0x7ac4, 0x7ac5, 0x7c2b, 0x7c27, 0x7c2a, 0x7c1e, 0x7c23, 0x7c21, 0x7ce7, 0x7e54,
+    0x7e55, 0x7e5e, 0x7e5a, 0x7e61, 0x7e52, 0x7e59, 0x7f48, 0x7ff9, 0x7ffb, 0x8077, 0x8076, 0x81cd, 0x81cf, 0x820a, 0x85cf, 0x85a9,
+    0x85cd, 0x85d0, 0x85c9, 0x85b0, 0x85ba, 0x85b9, 0x85a6, 0x87ef, 0x87ec, 0x87f2, 0x87e0, 0x8986, 0x89b2, 0x89f4, 0x8b28, 0x8b39,
+    0x8b2c, 0x8b2b, 0x8c50, 0x8d05, 0x8e59, 0x8e63, 0x8e66, 0x8e64, 0x8e5f, 0x8e55, 0x8ec0, 0x8f49, 0x8f4d, 0x9087, 0x9083, 0x9088,
+    0x91ab, 0x91ac, 0x91d0, 0x9394, 0x938a, 0x9396, 0x93a2, 0x93b3, 0x93ae, 0x93ac, 0x93b0, 0x9398, 0x939a, 0x9397, 0x95d4, 0x95d6,
+    0x95d0, 0x95d5, 0x96e2, 0x96dc, 0x96d9, 0x96db, 0x96de, 0x9724, 0x97a3, 0x97a6, 0x97ad, 0x97f9, 0x984d, 0x984f, 0x984c, 0x984e,
+    0x9853, 0x98ba, 0x993e, 0x993f, 0x993d, 0x992e, 0x99a5, 0x9a0e, 0x9ac1, 0x9b03, 0x9b06, 0x9b4f, 0x9b4e, 0x9b4d, 0x9bca, 0x9bc9,
+    0x9bfd, 0x9bc8, 0x9bc0, 0x9d51, 0x9d5d, 0x9d60, 0x9ee0, 0x9f15, 0x9f2c, 0x5133, 0x56a5, 0x58de, 0x58df, 0x58e2, 0x5bf5, 0x9f90,
+    0x5eec, 0x61f2, 0x61f7, 0x61f6, 0x61f5, 0x6500, 0x650f, 0x66e0, 0x66dd, 0x6ae5, 0x6add, 0x6ada, 0x6ad3, 0x701b, 0x701f, 0x7028,
+    0x701a, 0x701d, 0x7015, 0x7018, 0x7206, 0x720d, 0x7258, 0x72a2, 0x7378, 0x737a, 0x74bd, 0x74ca, 0x74e3, 0x7587, 0x7586, 0x765f,
+    0x7661, 0x77c7, 0x7919, 0x79b1, 0x7a6b, 0x7a69, 0x7c3e, 0x7c3f, 0x7c38, 0x7c3d, 0x7c37, 0x7c40, 0x7e6b, 0x7e6d, 0x7e79, 0x7e69,
+    0x7e6a, 0x7f85, 0x7e73, 0x7fb6, 0x7fb9, 0x7fb8, 0x81d8, 0x85e9, 0x85dd, 0x85ea, 0x85d5, 0x85e4, 0x85e5, 0x85f7, 0x87fb, 0x8805,
+    0x880d, 0x87f9, 0x87fe, 0x8960, 0x895f, 0x8956, 0x895e, 0x8b41, 0x8b5c, 0x8b58, 0x8b49, 0x8b5a, 0x8b4e, 0x8b4f, 0x8b46, 0x8b59,
+    0x8d08, 0x8d0a, 0x8e7c, 0x8e72, 0x8e87, 0x8e76, 0x8e6c, 0x8e7a, 0x8e74, 0x8f54, 0x8f4e, 0x8fad, 0x908a, 0x908b, 0x91b1, 0x91ae,
+    0x93e1, 0x93d1, 0x93df, 0x93c3, 0x93c8, 0x93dc, 0x93dd, 0x93d6, 0x93e2, 0x93cd, 0x93d8, 0x93e4, 0x93d7, 0x93e8, 0x95dc, 0x96b4,
+    0x96e3, 0x972a, 0x9727, 0x9761, 0x97dc, 0x97fb, 0x985e, 0x9858, 0x985b, 0x98bc, 0x9945, 0x9949, 0x9a16, 0x9a19, 0x9b0d, 0x9be8,
+    0x9be7, 0x9bd6, 0x9bdb, 0x9d89, 0x9d61, 0x9d72, 0x9d6a, 0x9d6c, 0x9e92, 0x9e97, 0x9e93, 0x9eb4, 0x52f8, 0x56a8, 0x56b7, 0x56b6,
+    0x56b4, 0x56bc, 0x58e4, 0x5b40, 0x5b43, 0x5b7d, 0x5bf6, 0x5dc9, 0x61f8, 0x61fa, 0x6518, 0x6514, 0x6519, 0x66e6, 0x6727, 0x6aec,
+    0x703e, 0x7030, 0x7032, 0x7210, 0x737b, 0x74cf, 0x7662, 0x7665, 0x7926, 0x792a, 0x792c, 0x792b, 0x7ac7, 0x7af6, 0x7c4c, 0x7c43,
+    0x7c4d, 0x7cef, 0x7cf0, 0x8fae, 0x7e7d, 0x7e7c, 0x7e82, 0x7f4c, 0x8000, 0x81da, 0x8266, 0x85fb, 0x85f9, 0x8611, 0x85fa, 0x8606,
+    0x860b, 0x8607, 0x860a, 0x8814, 0x8815, 0x8964, 0x89ba, 0x89f8, 0x8b70, 0x8b6c, 0x8b66, 0x8b6f, 0x8b5f, 0x8b6b, 0x8d0f, 0x8d0d,
+    0x8e89, 0x8e81, 0x8e85, 0x8e82, 0x91b4, 0x91cb, 0x9418, 0x9403, 0x93fd, 0x95e1, 0x9730, 0x98c4, 0x9952, 0x9951, 0x99a8, 0x9a2b,
+    0x9a30, 0x9a37, 0x9a35, 0x9c13, 0x9c0d, 0x9e79, 0x9eb5, 0x9ee8, 0x9f2f, 0x9f5f, 0x9f63, 0x9f61, 0x5137, 0x5138, 0x56c1, 0x56c0,
+    0x56c2, 0x5914, 0x5c6c, 0x5dcd, 0x61fc, 0x61fe, 0x651d, 0x651c, 0x6595, 0x66e9, 0x6afb, 0x6b04, 0x6afa, 0x6bb2, 0x704c, 0x721b,
+    0x72a7, 0x74d6, 0x74d4, 0x7669, 0x77d3, 0x7c50, 0x7e8f, 0x7e8c, 0x7fbc, 0x8617, 0x862d, 0x861a, 0x8823, 0x8822, 0x8821, 0x881f,
+    0x896a, 0x896c, 0x89bd, 0x8b74, 0x8b77, 0x8b7d, 0x8d13, 0x8e8a, 0x8e8d, 0x8e8b, 0x8f5f, 0x8faf, 0x91ba, 0x942e, 0x9433, 0x9435,
+    0x943a, 0x9438, 0x9432, 0x942b, 0x95e2, 0x9738, 0x9739, 0x9732, 0x97ff, 0x9867, 0x9865, 0x9957, 0x9a45, 0x9a43, 0x9a40, 0x9a3e,
+    0x9acf, 0x9b54, 0x9b51, 0x9c2d, 0x9c25, 0x9daf, 0x9db4, 0x9dc2, 0x9db8, 0x9e9d, 0x9eef, 0x9f19, 0x9f5c, 0x9f66, 0x9f67, 0x513c,
+    0x513b, 0x56c8, 0x56ca, 0x56c9, 0x5b7f, 0x5dd4, 0x5dd2, 0x5f4e, 0x61ff, 0x6524, 0x6b0a, 0x6b61, 0x7051, 0x7058, 0x7380, 0x74e4,
+    0x758a, 0x766e, 0x766c, 0x79b3, 0x7c60, 0x7c5f, 0x807e, 0x807d, 0x81df, 0x8972, 0x896f, 0x89fc, 0x8b80, 0x8d16, 0x8d17, 0x8e91,
+    0x8e93, 0x8f61, 0x9148, 0x9444, 0x9451, 0x9452, 0x973d, 0x973e, 0x97c3, 0x97c1, 0x986b, 0x9955, 0x9a55, 0x9a4d, 0x9ad2, 0x9b1a,
+    0x9c49, 0x9c31, 0x9c3e, 0x9c3b, 0x9dd3, 0x9dd7, 0x9f34, 0x9f6c, 0x9f6a, 0x9f94, 0x56cc, 0x5dd6, 0x6200, 0x6523, 0x652b, 0x652a,
+    0x66ec, 0x6b10, 0x74da, 0x7aca, 0x7c64, 0x7c63, 0x7c65, 0x7e93, 0x7e96, 0x7e94, 0x81e2, 0x8638, 0x863f, 0x8831, 0x8b8a, 0x9090,
+    0x908f, 0x9463, 0x9460, 0x9464, 0x9768, 0x986f, 0x995c, 0x9a5a, 0x9a5b, 0x9a57, 0x9ad3, 0x9ad4, 0x9ad1, 0x9c54, 0x9c57, 0x9c56,
+    0x9de5, 0x9e9f, 0x9ef4, 0x56d1, 0x58e9, 0x652c, 0x705e, 0x7671, 0x7672, 0x77d7, 0x7f50, 0x7f88, 0x8836, 0x8839, 0x8862, 0x8b93,
+    0x8b92, 0x8b96, 0x8277, 0x8d1b, 0x91c0, 0x946a, 0x9742, 0x9748, 0x9744, 0x97c6, 0x9870, 0x9a5f, 0x9b22, 0x9b58, 0x9c5f, 0x9df9,
+    0x9dfa, 0x9e7c, 0x9e7d, 0x9f07, 0x9f77, 0x9f72, 0x5ef3, 0x6b16, 0x7063, 0x7c6c, 0x7c6e, 0x883b, 0x89c0, 0x8ea1, 0x91c1, 0x9472,
+    0x9470, 0x9871, 0x995e, 0x9ad6, 0x9b23, 0x9ecc, 0x7064, 0x77da, 0x8b9a, 0x9477, 0x97c9, 0x9a62, 0x9a65, 0x7e9c, 0x8b9c, 0x8eaa,
+    0x91c5, 0x947d, 0x947e, 0x947c, 0x9c77, 0x9c78, 0x9ef7, 0x8c54, 0x947f, 0x9e1a, 0x7228, 0x9a6a, 0x9b31, 0x9e1b, 0x9e1e, 0x7c72,
+    0x30fe, 0x309d, 0x309e, 0x3005, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046, 0x3047, 0x3048, 0x3049, 0x304a, 0x304b, 0x304c,
+    0x304d, 0x304e, 0x304f, 0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056, 0x3057, 0x3058, 0x3059, 0x305a, 0x305b, 0x305c,
+    0x305d, 0x305e, 0x305f, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066, 0x3067, 0x3068, 0x3069, 0x306a, 0x306b, 0x306c,
+    0x306d, 0x306e, 0x306f, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074, 0x3075, 0x3076, 0x3077, 0x3078, 0x3079, 0x307a, 0x307b, 0x307c,
+    0x307d, 0x307e, 0x307f, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086, 0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c,
+    0x308d, 0x308e, 0x308f, 0x3090, 0x3091, 0x3092, 0x3093, 0x30a1, 0x30a2, 0x30a3, 0x30a4, 0x30a5, 0x30a6, 0x30a7, 0x30a8, 0x30a9,
+    0x30aa, 0x30ab, 0x30ac, 0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1, 0x30b2, 0x30b3, 0x30b4, 0x30b5, 0x30b6, 0x30b7, 0x30b8, 0x30b9,
+    0x30ba, 0x30bb, 0x30bc, 0x30bd, 0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3, 0x30c4, 0x30c5, 0x30c6, 0x30c7, 0x30c8, 0x30c9,
+    0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4, 0x30d5, 0x30d6, 0x30d7, 0x30d8, 0x30d9,
+    0x30da, 0x30db, 0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2, 0x30e3, 0x30e4, 0x30e5, 0x30e6, 0x30e7, 0x30e8, 0x30e9,
+    0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x30f3, 0x30f4, 0x30f5, 0x30f6, 0x0414, 0x0415, 0x0401,
+    0x0416, 0x0417, 0x0418, 0x0419, 0x041a, 0x041b, 0x041c, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042a, 0x042b,
+    0x042c, 0x042d, 0x042e, 0x042f, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043a,
+    0x043b, 0x043c, 0x043d, 0x043e, 0x043f, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044a,
+    0x044b, 0x044c, 0x044d, 0x044e, 0x044f, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469, 0x2474,
+    0x2475, 0x2476, 0x2477, 0x2478, 0x2479, 0x247a, 0x247b, 0x247c, 0x247d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4e42, 0x4e5c, 0x51f5, 0x531a, 0x5382, 0x4e07, 0x4e0c, 0x4e47,
+    0x4e8d, 0x56d7, 0xfa0c, 0x5c6e, 0x5f73, 0x4e0f, 0x5187, 0x4e0e, 0x4e2e, 0x4e93, 0x4ec2, 0x4ec9, 0x4ec8, 0x5198, 0x52fc, 0x536c,
+    0x53b9, 0x5720, 0x5903, 0x592c, 0x5c10, 0x5dff, 0x65e1, 0x6bb3, 0x6bcc, 0x6c14, 0x723f, 0x4e31, 0x4e3c, 0x4ee8, 0x4edc, 0x4ee9,
+    0x4ee1, 0x4edd, 0x4eda, 0x520c, 0x531c, 0x534c, 0x5722, 0x5723, 0x5917, 0x592f, 0x5b81, 0x5b84, 0x5c12, 0x5c3b, 0x5c74, 0x5c73,
+    0x5e04, 0x5e80, 0x5e82, 0x5fc9, 0x6209, 0x6250, 0x6c15, 0x6c36, 0x6c43, 0x6c3f, 0x6c3b, 0x72ae, 0x72b0, 0x738a, 0x79b8, 0x808a,
+    0x961e, 0x4f0e, 0x4f18, 0x4f2c, 0x4ef5, 0x4f14, 0x4ef1, 0x4f00, 0x4ef7, 0x4f08, 0x4f1d, 0x4f02, 0x4f05, 0x4f22, 0x4f13, 0x4f04,
+    0x4ef4, 0x4f12, 0x51b1, 0x5213, 0x5209, 0x5210, 0x52a6, 0x5322, 0x531f, 0x534d, 0x538a, 0x5407, 0x56e1, 0x56df, 0x572e, 0x572a,
+    0x5734, 0x593c, 0x5980, 0x597c, 0x5985, 0x597b, 0x597e, 0x5977, 0x597f, 0x5b56, 0x5c15, 0x5c25, 0x5c7c, 0x5c7a, 0x5c7b, 0x5c7e,
+    0x5ddf, 0x5e75, 0x5e84, 0x5f02, 0x5f1a, 0x5f74, 0x5fd5, 0x5fd4, 0x5fcf, 0x625c, 0x625e, 0x6264, 0x6261, 0x6266, 0x6262, 0x6259,
+    0x6260, 0x625a, 0x6265, 0x65ef, 0x65ee, 0x673e, 0x6739, 0x6738, 0x673b, 0x673a, 0x673f, 0x673c, 0x6733, 0x6c18, 0x6c46, 0x6c52,
+    0x6c5c, 0x6c4f, 0x6c4a, 0x6c54, 0x6c4b, 0x6c4c, 0x7071, 0x725e, 0x72b4, 0x72b5, 0x738e, 0x752a, 0x767f, 0x7a75, 0x7f51, 0x8278,
+    0x827c, 0x8280, 0x827d, 0x827f, 0x864d, 0x897e, 0x9099, 0x9097, 0x9098, 0x909b, 0x9094, 0x9622, 0x9624, 0x9620, 0x9623, 0x4f56,
+    0x4f3b, 0x4f62, 0x4f49, 0x4f53, 0x4f64, 0x4f3e, 0x4f67, 0x4f52, 0x4f5f, 0x4f41, 0x4f58, 0x4f2d, 0x4f33, 0x4f3f, 0x4f61, 0x518f,
+    0x51b9, 0x521c, 0x521e, 0x5221, 0x52ad, 0x52ae, 0x5309, 0x5363, 0x5372, 0x538e, 0x538f, 0x5430, 0x5437, 0x542a, 0x5454, 0x5445,
+    0x5419, 0x541c, 0x5425, 0x5418, 0x543d, 0x544f, 0x5441, 0x5428, 0x5424, 0x5447, 0x56ee, 0x56e7, 0x56e5, 0x5741, 0x5745, 0x574c,
+    0x5749, 0x574b, 0x5752, 0x5906, 0x5940, 0x59a6, 0x5998, 0x59a0, 0x5997, 0x598e, 0x59a2, 0x5990, 0x598f, 0x59a7, 0x59a1, 0x5b8e,
+    0x5b92, 0x5c28, 0x5c2a, 0x5c8d, 0x5c8f, 0x5c88, 0x5c8b, 0x5c89, 0x5c92, 0x5c8a, 0x5c86, 0x5c93, 0x5c95, 0x5de0, 0x5e0a, 0x5e0e,
+    0x5e8b, 0x5e89, 0x5e8c, 0x5e88, 0x5e8d, 0x5f05, 0x5f1d, 0x5f78, 0x5f76, 0x5fd2, 0x5fd1, 0x5fd0, 0x5fed, 0x5fe8, 0x5fee, 0x5ff3,
+    0x5fe1, 0x5fe4, 0x5fe3, 0x5ffa, 0x5fef, 0x5ff7, 0x5ffb, 0x6000, 0x5ff4, 0x623a, 0x6283, 0x628c, 0x628e, 0x628f, 0x6294, 0x6287,
+    0x6271, 0x627b, 0x627a, 0x6270, 0x6281, 0x6288, 0x6277, 0x627d, 0x6272, 0x6274, 0x6537, 0x65f0, 0x65f4, 0x65f3, 0x65f2, 0x65f5,
+    0x6745, 0x6747, 0x6759, 0x6755, 0x674c, 0x6748, 0x675d, 0x674d, 0x675a, 0x674b, 0x6bd0, 0x6c19, 0x6c1a, 0x6c78, 0x6c67, 0x6c6b,
+    0x6c84, 0x6c8b, 0x6c8f, 0x6c71, 0x6c6f, 0x6c69, 0x6c9a, 0x6c6d, 0x6c87, 0x6c95, 0x6c9c, 0x6c66, 0x6c73, 0x6c65, 0x6c7b, 0x6c8e,
+    0x7074, 0x707a, 0x7263, 0x72bf, 0x72bd, 0x72c3, 0x72c6, 0x72c1, 0x72ba, 0x72c5, 0x7395, 0x7397, 0x7393, 0x7394, 0x7392, 0x753a,
+    0x7539, 0x7594, 0x7595, 0x7681, 0x793d, 0x8034, 0x8095, 0x8099, 0x8090, 0x8092, 0x809c, 0x8290, 0x828f, 0x8285, 0x828e, 0x8291,
+    0x8293, 0x828a, 0x8283, 0x8284, 0x8c78, 0x8fc9, 0x8fbf, 0x909f, 0x90a1, 0x90a5, 0x909e, 0x90a7, 0x90a0, 0x9630, 0x9628, 0x962f,
+    0x962d, 0x4e33, 0x4f98, 0x4f7c, 0x4f85, 0x4f7d, 0x4f80, 0x4f87, 0x4f76, 0x4f74, 0x4f89, 0x4f84, 0x4f77, 0x4f4c, 0x4f97, 0x4f6a,
+    0x4f9a, 0x4f79, 0x4f81, 0x4f78, 0x4f90, 0x4f9c, 0x4f94, 0x4f9e, 0x4f92, 0x4f82, 0x4f95, 0x4f6b, 0x4f6e, 0x519e, 0x51bc, 0x51be,
+    0x5235, 0x5232, 0x5233, 0x5246, 0x5231, 0x52bc, 0x530a, 0x530b, 0x533c, 0x5392, 0x5394, 0x5487, 0x547f, 0x5481, 0x5491, 0x5482,
+    0x5488, 0x546b, 0x547a, 0x547e, 0x5465, 0x546c, 0x5474, 0x5466, 0x548d, 0x546f, 0x5461, 0x5460, 0x5498, 0x5463, 0x5467, 0x5464,
+    0x56f7, 0x56f9, 0x576f, 0x5772, 0x576d, 0x576b, 0x5771, 0x5770, 0x5776, 0x5780, 0x5775, 0x577b, 0x5773, 0x5774, 0x5762, 0x5768,
+    0x577d, 0x590c, 0x5945, 0x59b5, 0x59ba, 0x59cf, 0x59ce, 0x59b2, 0x59cc, 0x59c1, 0x59b6, 0x59bc, 0x59c3, 0x59d6, 0x59b1, 0x59bd,
+    0x59c0, 0x59c8, 0x59b4, 0x59c7, 0x5b62, 0x5b65, 0x5b93, 0x5b95, 0x5c44, 0x5c47, 0x5cae, 0x5ca4, 0x5ca0, 0x5cb5, 0x5caf, 0x5ca8,
+    0x5cac, 0x5c9f, 0x5ca3, 0x5cad, 0x5ca2, 0x5caa, 0x5ca7, 0x5c9d, 0x5ca5, 0x5cb6, 0x5cb0, 0x5ca6, 0x5e17, 0x5e14, 0x5e19, 0x5f28,
+    0x5f22, 0x5f23, 0x5f24, 0x5f54, 0x5f82, 0x5f7e, 0x5f7d, 0x5fde, 0x5fe5, 0x602d, 0x6026, 0x6019, 0x6032, 0x600b, 0x6034, 0x600a,
+    0x6017, 0x6033, 0x601a, 0x601e, 0x602c, 0x6022, 0x600d, 0x6010, 0x602e, 0x6013, 0x6011, 0x600c, 0x6009, 0x601c, 0x6214, 0x623d,
+    0x62ad, 0x62b4, 0x62d1, 0x62be, 0x62aa, 0x62b6, 0x62ca, 0x62ae, 0x62b3, 0x62af, 0x62bb, 0x62a9, 0x62b0, 0x62b8, 0x653d, 0x65a8,
+    0x65bb, 0x6609, 0x65fc, 0x6604, 0x6612, 0x6608, 0x65fb, 0x6603, 0x660b, 0x660d, 0x6605, 0x65fd, 0x6611, 0x6610, 0x66f6, 0x670a,
+    0x6785, 0x676c, 0x678e, 0x6792, 0x6776, 0x677b, 0x6798, 0x6786, 0x6784, 0x6774, 0x678d, 0x678c, 0x677a, 0x679f, 0x6791, 0x6799,
+    0x6783, 0x677d, 0x6781, 0x6778, 0x6779, 0x6794, 0x6b25, 0x6b80, 0x6b7e, 0x6bde, 0x6c1d, 0x6c93, 0x6cec, 0x6ceb, 0x6cee, 0x6cd9,
+    0x6cb6, 0x6cd4, 0x6cad, 0x6ce7, 0x6cb7, 0x6cd0, 0x6cc2, 0x6cba, 0x6cc3, 0x6cc6, 0x6ced, 0x6cf2, 0x6cd2, 0x6cdd, 0x6cb4, 0x6c8a,
+    0x6c9d, 0x6c80, 0x6cde, 0x6cc0, 0x6d30, 0x6ccd, 0x6cc7, 0x6cb0, 0x6cf9, 0x6ccf, 0x6ce9, 0x6cd1, 0x7094, 0x7098, 0x7085, 0x7093,
+    0x7086, 0x7084, 0x7091, 0x7096, 0x7082, 0x709a, 0x7083, 0x726a, 0x72d6, 0x72cb, 0x72d8, 0x72c9, 0x72dc, 0x72d2, 0x72d4, 0x72da,
+    0x72cc, 0x72d1, 0x73a4, 0x73a1, 0x73ad, 0x73a6, 0x73a2, 0x73a0, 0x73ac, 0x739d, 0x74dd, 0x74e8, 0x753f, 0x7540, 0x753e, 0x758c,
+    0x7598, 0x76af, 0x76f3, 0x76f1, 0x76f0, 0x76f5, 0x77f8, 0x77fc, 0x77f9, 0x77fb, 0x77fa, 0x77f7, 0x7942, 0x793f, 0x79c5, 0x7a78,
+    0x7a7b, 0x7afb, 0x7c75, 0x7cfd, 0x8035, 0x808f, 0x80ae, 0x80a3, 0x80b8, 0x80b5, 0x80ad, 0x8220, 0x82a0, 0x82c0, 0x82ab, 0x829a,
+    0x8298, 0x829b, 0x82b5, 0x82a7, 0x82ae, 0x82bc, 0x829e, 0x82ba, 0x82b4, 0x82a8, 0x82a1, 0x82a9, 0x82c2, 0x82a4, 0x82c3, 0x82b6,
+    0x82a2, 0x8670, 0x866f, 0x866d, 0x866e, 0x8c56, 0x8fd2, 0x8fcb, 0x8fd3, 0x8fcd, 0x8fd6, 0x8fd5, 0x8fd7, 0x90b2, 0x90b4, 0x90af,
+    0x90b3, 0x90b0, 0x9639, 0x963d, 0x963c, 0x963a, 0x9643, 0x4fcd, 0x4fc5, 0x4fd3, 0x4fb2, 0x4fc9, 0x4fcb, 0x4fc1, 0x4fd4, 0x4fdc,
+    0x4fd9, 0x4fbb, 0x4fb3, 0x4fdb, 0x4fc7, 0x4fd6, 0x4fba, 0x4fc0, 0x4fb9, 0x4fec, 0x5244, 0x5249, 0x52c0, 0x52c2, 0x533d, 0x537c,
+    0x5397, 0x5396, 0x5399, 0x5398, 0x54ba, 0x54a1, 0x54ad, 0x54a5, 0x54cf, 0x54c3, 0x830d, 0x54b7, 0x54ae, 0x54d6, 0x54b6, 0x54c5,
+    0x54c6, 0x54a0, 0x5470, 0x54bc, 0x54a2, 0x54be, 0x5472, 0x54de, 0x54b0, 0x57b5, 0x579e, 0x579f, 0x57a4, 0x578c, 0x5797, 0x579d,
+    0x579b, 0x5794, 0x5798, 0x578f, 0x5799, 0x57a5, 0x579a, 0x5795, 0x58f4, 0x590d, 0x5953, 0x59e1, 0x59de, 0x59ee, 0x5a00, 0x59f1,
+    0x59dd, 0x59fa, 0x59fd, 0x59fc, 0x59f6, 0x59e4, 0x59f2, 0x59f7, 0x59db, 0x59e9, 0x59f3, 0x59f5, 0x59e0, 0x59fe, 0x59f4, 0x59ed,
+    0x5ba8, 0x5c4c, 0x5cd0, 0x5cd8, 0x5ccc, 0x5cd7, 0x5ccb, 0x5cdb, 0x5cde, 0x5cda, 0x5cc9, 0x5cc7, 0x5cca, 0x5cd6, 0x5cd3, 0x5cd4,
+    0x5ccf, 0x5cc8, 0x5cc6, 0x5cce, 0x5cdf, 0x5cf8, 0x5df9, 0x5e21, 0x5e22, 0x5e23, 0x5e20, 0x5e24, 0x5eb0, 0x5ea4, 0x5ea2, 0x5e9b,
+    0x5ea3, 0x5ea5, 0x5f07, 0x5f2e, 0x5f56, 0x5f86, 0x6037, 0x6039, 0x6054, 0x6072, 0x605e, 0x6045, 0x6053, 0x6047, 0x6049, 0x605b,
+    0x604c, 0x6040, 0x6042, 0x605f, 0x6024, 0x6044, 0x6058, 0x6066, 0x606e, 0x6242, 0x6243, 0x62cf, 0x630d, 0x630b, 0x62f5, 0x630e,
+    0x6303, 0x62eb, 0x62f9, 0x630f, 0x630c, 0x62f8, 0x62f6, 0x6300, 0x6313, 0x6314, 0x62fa, 0x6315, 0x62fb, 0x62f0, 0x6541, 0x6543,
+    0x65aa, 0x65bf, 0x6636, 0x6621, 0x6632, 0x6635, 0x661c, 0x6626, 0x6622, 0x6633, 0x662b, 0x663a, 0x661d, 0x6634, 0x6639, 0x662e,
+    0x670f, 0x6710, 0x67c1, 0x67f2, 0x67c8, 0x67ba, 0x67dc, 0x67bb, 0x67f8, 0x67d8, 0x67c0, 0x67b7, 0x67c5, 0x67eb, 0x67e4, 0x67df,
+    0x67b5, 0x67cd, 0x67b3, 0x67f7, 0x67f6, 0x67ee, 0x67e3, 0x67c2, 0x67b9, 0x67ce, 0x67e7, 0x67f0, 0x67b2, 0x67fc, 0x67c6, 0x67ed,
+    0x67cc, 0x67ae, 0x67e6, 0x67db, 0x67fa, 0x67c9, 0x67ca, 0x67c3, 0x67ea, 0x67cb, 0x6b28, 0x6b82, 0x6b84, 0x6bb6, 0x6bd6, 0x6bd8,
+    0x6be0, 0x6c20, 0x6c21, 0x6d28, 0x6d34, 0x6d2d, 0x6d1f, 0x6d3c, 0x6d3f, 0x6d12, 0x6d0a, 0x6cda, 0x6d33, 0x6d04, 0x6d19, 0x6d3a,
+    0x6d1a, 0x6d11, 0x6d00, 0x6d1d, 0x6d42, 0x6d01, 0x6d18, 0x6d37, 0x6d03, 0x6d0f, 0x6d40, 0x6d07, 0x6d20, 0x6d2c, 0x6d08, 0x6d22,
+    0x6d09, 0x6d10, 0x70b7, 0x709f, 0x70be, 0x70b1, 0x70b0, 0x70a1, 0x70b4, 0x70b5, 0x70a9, 0x7241, 0x7249, 0x724a, 0x726c, 0x7270,
+    0x7273, 0x726e, 0x72ca, 0x72e4, 0x72e8, 0x72eb, 0x72df, 0x72ea, 0x72e6, 0x72e3, 0x7385, 0x73cc, 0x73c2, 0x73c8, 0x73c5, 0x73b9,
+    0x73b6, 0x73b5, 0x73b4, 0x73eb, 0x73bf, 0x73c7, 0x73be, 0x73c3, 0x73c6, 0x73b8, 0x73cb, 0x74ec, 0x74ee, 0x752e, 0x7547, 0x7548,
+    0x75a7, 0x75aa, 0x7679, 0x76c4, 0x7708, 0x7703, 0x7704, 0x7705, 0x770a, 0x76f7, 0x76fb, 0x76fa, 0x77e7, 0x77e8, 0x7806, 0x7811,
+    0x7812, 0x7805, 0x7810, 0x780f, 0x780e, 0x7809, 0x7803, 0x7813, 0x794a, 0x794c, 0x794b, 0x7945, 0x7944, 0x79d5, 0x79cd, 0x79cf,
+    0x79d6, 0x79ce, 0x7a80, 0x7a7e, 0x7ad1, 0x7b00, 0x7b01, 0x7c7a, 0x7c78, 0x7c79, 0x7c7f, 0x7c80, 0x7c81, 0x7d03, 0x7d08, 0x7d01,
+    0x7f58, 0x7f91, 0x7f8d, 0x7fbe, 0x8007, 0x800e, 0x800f, 0x8014, 0x8037, 0x80d8, 0x80c7, 0x80e0, 0x80d1, 0x80c8, 0x80c2, 0x80d0,
+    0x80c5, 0x80e3, 0x80d9, 0x80dc, 0x80ca, 0x80d5, 0x80c9, 0x80cf, 0x80d7, 0x80e6, 0x80cd, 0x81ff, 0x8221, 0x8294, 0x82d9, 0x82fe,
+    0x82f9, 0x8307, 0x82e8, 0x8300, 0x82d5, 0x833a, 0x82eb, 0x82d6, 0x82f4, 0x82ec, 0x82e1, 0x82f2, 0x82f5, 0x830c, 0x82fb, 0x82f6,
+    0x82f0, 0x82ea, 0x82e4, 0x82e0, 0x82fa, 0x82f3, 0x82ed, 0x8677, 0x8674, 0x867c, 0x8673, 0x8841, 0x884e, 0x8867, 0x886a, 0x8869,
+    0x89d3, 0x8a04, 0x8a07, 0x8d72, 0x8fe3, 0x8fe1, 0x8fee, 0x8fe0, 0x90f1, 0x90bd, 0x90bf, 0x90d5, 0x90c5, 0x90be, 0x90c7, 0x90cb,
+    0x90c8, 0x91d4, 0x91d3, 0x9654, 0x964f, 0x9651, 0x9653, 0x964a, 0x964e, 0x501e, 0x5005, 0x5007, 0x5013, 0x5022, 0x5030, 0x501b,
+    0x4ff5, 0x4ff4, 0x5033, 0x5037, 0x502c, 0x4ff6, 0x4ff7, 0x5017, 0x501c, 0x5020, 0x5027, 0x5035, 0x502f, 0x5031, 0x500e, 0x515a,
+    0x5194, 0x5193, 0x51ca, 0x51c4, 0x51c5, 0x51c8, 0x51ce, 0x5261, 0x525a, 0x5252, 0x525e, 0x525f, 0x5255, 0x5262, 0x52cd, 0x530e,
+    0x539e, 0x5526, 0x54e2, 0x5517, 0x5512, 0x54e7, 0x54f3, 0x54e4, 0x551a, 0x54ff, 0x5504, 0x5508, 0x54eb, 0x5511, 0x5505, 0x54f1,
+    0x550a, 0x54fb, 0x54f7, 0x54f8, 0x54e0, 0x550e, 0x5503, 0x550b, 0x5701, 0x5702, 0x57cc, 0x5832, 0x57d5, 0x57d2, 0x57ba, 0x57c6,
+    0x57bd, 0x57bc, 0x57b8, 0x57b6, 0x57bf, 0x57c7, 0x57d0, 0x57b9, 0x57c1, 0x590e, 0x594a, 0x5a19, 0x5a16, 0x5a2d, 0x5a2e, 0x5a15,
+    0x5a0f, 0x5a17, 0x5a0a, 0x5a1e, 0x5a33, 0x5b6c, 0x5ba7, 0x5bad, 0x5bac, 0x5c03, 0x5c56, 0x5c54, 0x5cec, 0x5cff, 0x5cee, 0x5cf1,
+    0x5cf7, 0x5d00, 0x5cf9, 0x5e29, 0x5e28, 0x5ea8, 0x5eae, 0x5eaa, 0x5eac, 0x5f33, 0x5f30, 0x5f67, 0x605d, 0x605a, 0x6067, 0x6041,
+    0x60a2, 0x6088, 0x6080, 0x6092, 0x6081, 0x609d, 0x6083, 0x6095, 0x609b, 0x6097, 0x6087, 0x609c, 0x608e, 0x6219, 0x6246, 0x62f2,
+    0x6310, 0x6356, 0x632c, 0x6344, 0x6345, 0x6336, 0x6343, 0x63e4, 0x6339, 0x634b, 0x634a, 0x633c, 0x6329, 0x6341, 0x6334, 0x6358,
+    0x6354, 0x6359, 0x632d, 0x6347, 0x6333, 0x635a, 0x6351, 0x6338, 0x6357, 0x6340, 0x6348, 0x654a, 0x6546, 0x65c6, 0x65c3, 0x65c4,
+    0x65c2, 0x664a, 0x665f, 0x6647, 0x6651, 0x6712, 0x6713, 0x681f, 0x681a, 0x6849, 0x6832, 0x6833, 0x683b, 0x684b, 0x684f, 0x6816,
+    0x6831, 0x681c, 0x6835, 0x682b, 0x682d, 0x682f, 0x684e, 0x6844, 0x6834, 0x681d, 0x6812, 0x6814, 0x6826, 0x6828, 0x682e, 0x684d,
+    0x683a, 0x6825, 0x6820, 0x6b2c, 0x6b2f, 0x6b2d, 0x6b31, 0x6b34, 0x6b6d, 0x8082, 0x6b88, 0x6be6, 0x6be4, 0x6be8, 0x6be3, 0x6be2,
+    0x6be7, 0x6c25, 0x6d7a, 0x6d63, 0x6d64, 0x6d76, 0x6d0d, 0x6d61, 0x6d92, 0x6d58, 0x6d62, 0x6d6d, 0x6d6f, 0x6d91, 0x6d8d, 0x6def,
+    0x6d7f, 0x6d86, 0x6d5e, 0x6d67, 0x6d60, 0x6d97, 0x6d70, 0x6d7c, 0x6d5f, 0x6d82, 0x6d98, 0x6d2f, 0x6d68, 0x6d8b, 0x6d7e, 0x6d80,
+    0x6d84, 0x6d16, 0x6d83, 0x6d7b, 0x6d7d, 0x6d75, 0x6d90, 0x70dc, 0x70d3, 0x70d1, 0x70dd, 0x70cb, 0x7f39, 0x70e2, 0x70d7, 0x70d2,
+    0x70de, 0x70e0, 0x70d4, 0x70cd, 0x70c5, 0x70c6, 0x70c7, 0x70da, 0x70ce, 0x70e1, 0x7242, 0x7278, 0x7277, 0x7276, 0x7300, 0x72fa,
+    0x72f4, 0x72fe, 0x72f6, 0x72f3, 0x72fb, 0x7301, 0x73d3, 0x73d9, 0x73e5, 0x73d6, 0x73bc, 0x73e7, 0x73e3, 0x73e9, 0x73dc, 0x73d2,
+    0x73db, 0x73d4, 0x73dd, 0x73da, 0x73d7, 0x73d8, 0x73e8, 0x74de, 0x74df, 0x74f4, 0x74f5, 0x7521, 0x755b, 0x755f, 0x75b0, 0x75c1,
+    0x75bb, 0x75c4, 0x75c0, 0x75bf, 0x75b6, 0x75ba, 0x768a, 0x76c9, 0x771d, 0x771b, 0x7710, 0x7713, 0x7712, 0x7723, 0x7711, 0x7715,
+    0x7719, 0x771a, 0x7722, 0x7727, 0x7823, 0x782c, 0x7822, 0x7835, 0x782f, 0x7828, 0x782e, 0x782b, 0x7821, 0x7829, 0x7833, 0x782a,
+    0x7831, 0x7954, 0x795b, 0x794f, 0x795c, 0x7953, 0x7952, 0x7951, 0x79eb, 0x79ec, 0x79e0, 0x79ee, 0x79ed, 0x79ea, 0x79dc, 0x79de,
+    0x79dd, 0x7a86, 0x7a89, 0x7a85, 0x7a8b, 0x7a8c, 0x7a8a, 0x7a87, 0x7ad8, 0x7b10, 0x7b04, 0x7b13, 0x7b05, 0x7b0f, 0x7b08, 0x7b0a,
+    0x7b0e, 0x7b09, 0x7b12, 0x7c84, 0x7c91, 0x7c8a, 0x7c8c, 0x7c88, 0x7c8d, 0x7c85, 0x7d1e, 0x7d1d, 0x7d11, 0x7d0e, 0x7d18, 0x7d16,
+    0x7d13, 0x7d1f, 0x7d12, 0x7d0f, 0x7d0c, 0x7f5c, 0x7f61, 0x7f5e, 0x7f60, 0x7f5d, 0x7f5b, 0x7f96, 0x7f92, 0x7fc3, 0x7fc2, 0x7fc0,
+    0x8016, 0x803e, 0x8039, 0x80fa, 0x80f2, 0x80f9, 0x80f5, 0x8101, 0x80fb, 0x8100, 0x8201, 0x822f, 0x8225, 0x8333, 0x832d, 0x8344,
+    0x8319, 0x8351, 0x8325, 0x8356, 0x833f, 0x8341, 0x8326, 0x831c, 0x8322, 0x8342, 0x834e, 0x831b, 0x832a, 0x8308, 0x833c, 0x834d,
+    0x8316, 0x8324, 0x8320, 0x8337, 0x832f, 0x8329, 0x8347, 0x8345, 0x834c, 0x8353, 0x831e, 0x832c, 0x834b, 0x8327, 0x8348, 0x8653,
+    0x8652, 0x86a2, 0x86a8, 0x8696, 0x868d, 0x8691, 0x869e, 0x8687, 0x8697, 0x8686, 0x868b, 0x869a, 0x8685, 0x86a5, 0x8699, 0x86a1,
+    0x86a7, 0x8695, 0x8698, 0x868e, 0x869d, 0x8690, 0x8694, 0x8843, 0x8844, 0x886d, 0x8875, 0x8876, 0x8872, 0x8880, 0x8871, 0x887f,
+    0x886f, 0x8883, 0x887e, 0x8874, 0x887c, 0x8a12, 0x8c47, 0x8c57, 0x8c7b, 0x8ca4, 0x8ca3, 0x8d76, 0x8d78, 0x8db5, 0x8db7, 0x8db6,
+    0x8ed1, 0x8ed3, 0x8ffe, 0x8ff5, 0x9002, 0x8fff, 0x8ffb, 0x9004, 0x8ffc, 0x8ff6, 0x90d6, 0x90e0, 0x90d9, 0x90da, 0x90e3, 0x90df,
+    0x90e5, 0x90d8, 0x90db, 0x90d7, 0x90dc, 0x90e4, 0x9150, 0x914e, 0x914f, 0x91d5, 0x91e2, 0x91da, 0x965c, 0x965f, 0x96bc, 0x98e3,
+    0x9adf, 0x9b2f, 0x4e7f, 0x5070, 0x506a, 0x5061, 0x505e, 0x5060, 0x5053, 0x504b, 0x505d, 0x5072, 0x5048, 0x504d, 0x5041, 0x505b,
+    0x504a, 0x5062, 0x5015, 0x5045, 0x505f, 0x5069, 0x506b, 0x5063, 0x5064, 0x5046, 0x5040, 0x506e, 0x5073, 0x5057, 0x5051, 0x51d0,
+    0x526b, 0x526d, 0x526c, 0x526e, 0x52d6, 0x52d3, 0x532d, 0x539c, 0x5575, 0x5576, 0x553c, 0x554d, 0x5550, 0x5534, 0x552a, 0x5551,
+    0x5562, 0x5536, 0x5535, 0x5530, 0x5552, 0x5545, 0x550c, 0x5532, 0x5565, 0x554e, 0x5539, 0x5548, 0x552d, 0x553b, 0x5540, 0x554b,
+    0x570a, 0x5707, 0x57fb, 0x5814, 0x57e2, 0x57f6, 0x57dc, 0x57f4, 0x5800, 0x57ed, 0x57fd, 0x5808, 0x57f8, 0x580b, 0x57f3, 0x57cf,
+    0x5807, 0x57ee, 0x57e3, 0x57f2, 0x57e5, 0x57ec, 0x57e1, 0x580e, 0x57fc, 0x5810, 0x57e7, 0x5801, 0x580c, 0x57f1, 0x57e9, 0x57f0,
+    0x580d, 0x5804, 0x595c, 0x5a60, 0x5a58, 0x5a55, 0x5a67, 0x5a5e, 0x5a38, 0x5a35, 0x5a6d, 0x5a50, 0x5a5f, 0x5a65, 0x5a6c, 0x5a53,
+    0x5a64, 0x5a57, 0x5a43, 0x5a5d, 0x5a52, 0x5a44, 0x5a5b, 0x5a48, 0x5a8e, 0x5a3e, 0x5a4d, 0x5a39, 0x5a4c, 0x5a70, 0x5a69, 0x5a47,
+    0x5a51, 0x5a56, 0x5a42, 0x5a5c, 0x5b72, 0x5b6e, 0x5bc1, 0x5bc0, 0x5c59, 0x5d1e, 0x5d0b, 0x5d1d, 0x5d1a, 0x5d20, 0x5d0c, 0x5d28,
+    0x5d0d, 0x5d26, 0x5d25, 0x5d0f, 0x5d30, 0x5d12, 0x5d23, 0x5d1f, 0x5d2e, 0x5e3e, 0x5e34, 0x5eb1, 0x5eb4, 0x5eb9, 0x5eb2, 0x5eb3,
+    0x5f36, 0x5f38, 0x5f9b, 0x5f96, 0x5f9f, 0x608a, 0x6090, 0x6086, 0x60be, 0x60b0, 0x60ba, 0x60d3, 0x60d4, 0x60cf, 0x60e4, 0x60d9,
+    0x60dd, 0x60c8, 0x60b1, 0x60db, 0x60b7, 0x60ca, 0x60bf, 0x60c3, 0x60cd, 0x60c0, 0x6332, 0x6365, 0x638a, 0x6382, 0x637d, 0x63bd,
+    0x639e, 0x63ad, 0x639d, 0x6397, 0x63ab, 0x638e, 0x636f, 0x6387, 0x6390, 0x636e, 0x63af, 0x6375, 0x639c, 0x636d, 0x63ae, 0x637c,
+    0x63a4, 0x633b, 0x639f, 0x6378, 0x6385, 0x6381, 0x6391, 0x638d, 0x6370, 0x6553, 0x65cd, 0x6665, 0x6661, 0x665b, 0x6659, 0x665c,
+    0x6662, 0x6718, 0x6879, 0x6887, 0x6890, 0x689c, 0x686d, 0x686e, 0x68ae, 0x68ab, 0x6956, 0x686f, 0x68a3, 0x68ac, 0x68a9, 0x6875,
+    0x6874, 0x68b2, 0x688f, 0x6877, 0x6892, 0x687c, 0x686b, 0x6872, 0x68aa, 0x6880, 0x6871, 0x687e, 0x689b, 0x6896, 0x688b, 0x68a0,
+    0x6889, 0x68a4, 0x6878, 0x687b, 0x6891, 0x688c, 0x688a, 0x687d, 0x6b36, 0x6b33, 0x6b37, 0x6b38, 0x6b91, 0x6b8f, 0x6b8d, 0x6b8e,
+    0x6b8c, 0x6c2a, 0x6dc0, 0x6dab, 0x6db4, 0x6db3, 0x6e74, 0x6dac, 0x6de9, 0x6de2, 0x6db7, 0x6df6, 0x6dd4, 0x6e00, 0x6dc8, 0x6de0,
+    0x6ddf, 0x6dd6, 0x6dbe, 0x6de5, 0x6ddc, 0x6ddd, 0x6ddb, 0x6df4, 0x6dca, 0x6dbd, 0x6ded, 0x6df0, 0x6dba, 0x6dd5, 0x6dc2, 0x6dcf,
+    0x6dc9, 0x6dd0, 0x6df2, 0x6dd3, 0x6dfd, 0x6dd7, 0x6dcd, 0x6de3, 0x6dbb, 0x70fa, 0x710d, 0x70f7, 0x7117, 0x70f4, 0x710c, 0x70f0,
+    0x7104, 0x70f3, 0x7110, 0x70fc, 0x70ff, 0x7106, 0x7113, 0x7100, 0x70f8, 0x70f6, 0x710b, 0x7102, 0x710e, 0x727e, 0x727b, 0x727c,
+    0x727f, 0x731d, 0x7317, 0x7307, 0x7311, 0x7318, 0x730a, 0x7308, 0x72ff, 0x730f, 0x731e, 0x7388, 0x73f6, 0x73f8, 0x73f5, 0x7404,
+    0x7401, 0x73fd, 0x7407, 0x7400, 0x73fa, 0x73fc, 0x73ff, 0x740c, 0x740b, 0x73f4, 0x7408, 0x7564, 0x7563, 0x75ce, 0x75d2, 0x75cf,
+    0x75cb, 0x75cc, 0x75d1, 0x75d0, 0x768f, 0x7689, 0x76d3, 0x7739, 0x772f, 0x772d, 0x7731, 0x7732, 0x7734, 0x7733, 0x773d, 0x7725,
+    0x773b, 0x7735, 0x7848, 0x7852, 0x7849, 0x784d, 0x784a, 0x784c, 0x7826, 0x7845, 0x7850, 0x7964, 0x7967, 0x7969, 0x796a, 0x7963,
+    0x796b, 0x7961, 0x79bb, 0x79fa, 0x79f8, 0x79f6, 0x79f7, 0x7a8f, 0x7a94, 0x7a90, 0x7b35, 0x7b47, 0x7b34, 0x7b25, 0x7b30, 0x7b22,
+    0x7b24, 0x7b33, 0x7b18, 0x7b2a, 0x7b1d, 0x7b31, 0x7b2b, 0x7b2d, 0x7b2f, 0x7b32, 0x7b38, 0x7b1a, 0x7b23, 0x7c94, 0x7c98, 0x7c96,
+    0x7ca3, 0x7d35, 0x7d3d, 0x7d38, 0x7d36, 0x7d3a, 0x7d45, 0x7d2c, 0x7d29, 0x7d41, 0x7d47, 0x7d3e, 0x7d3f, 0x7d4a, 0x7d3b, 0x7d28,
+    0x7f63, 0x7f95, 0x7f9c, 0x7f9d, 0x7f9b, 0x7fca, 0x7fcb, 0x7fcd, 0x7fd0, 0x7fd1, 0x7fc7, 0x7fcf, 0x7fc9, 0x801f, 0x801e, 0x801b,
+    0x8047, 0x8043, 0x8048, 0x8118, 0x8125, 0x8119, 0x811b, 0x812d, 0x811f, 0x812c, 0x811e, 0x8121, 0x8115, 0x8127, 0x811d, 0x8122,
+    0x8211, 0x8238, 0x8233, 0x823a, 0x8234, 0x8232, 0x8274, 0x8390, 0x83a3, 0x83a8, 0x838d, 0x837a, 0x8373, 0x83a4, 0x8374, 0x838f,
+    0x8381, 0x8395, 0x8399, 0x8375, 0x8394, 0x83a9, 0x837d, 0x8383, 0x838c, 0x839d, 0x839b, 0x83aa, 0x838b, 0x837e, 0x83a5, 0x83af,
+    0x8388, 0x8397, 0x83b0, 0x837f, 0x83a6, 0x8387, 0x83ae, 0x8376, 0x839a, 0x8659, 0x8656, 0x86bf, 0x86b7, 0x86c2, 0x86c1, 0x86c5,
+    0x86ba, 0x86b0, 0x86c8, 0x86b9, 0x86b3, 0x86b8, 0x86cc, 0x86b4, 0x86bb, 0x86bc, 0x86c3, 0x86bd, 0x86be, 0x8852, 0x8889, 0x8895,
+    0x88a8, 0x88a2, 0x88aa, 0x889a, 0x8891, 0x88a1, 0x889f, 0x8898, 0x88a7, 0x8899, 0x889b, 0x8897, 0x88a4, 0x88ac, 0x888c, 0x8893,
+    0x888e, 0x8982, 0x89d6, 0x89d9, 0x89d5, 0x8a30, 0x8a27, 0x8a2c, 0x8a1e, 0x8c39, 0x8c3b, 0x8c5c, 0x8c5d, 0x8c7d, 0x8ca5, 0x8d7d,
+    0x8d7b, 0x8d79, 0x8dbc, 0x8dc2, 0x8db9, 0x8dbf, 0x8dc1, 0x8ed8, 0x8ede, 0x8edd, 0x8edc, 0x8ed7, 0x8ee0, 0x8ee1, 0x9024, 0x900b,
+    0x9011, 0x901c, 0x900c, 0x9021, 0x90ef, 0x90ea, 0x90f0, 0x90f4, 0x90f2, 0x90f3, 0x90d4, 0x90eb, 0x90ec, 0x90e9, 0x9156, 0x9158,
+    0x915a, 0x9153, 0x9155, 0x91ec, 0x91f4, 0x91f1, 0x91f3, 0x91f8, 0x91e4, 0x91f9, 0x91ea, 0x91eb, 0x91f7, 0x91e8, 0x91ee, 0x957a,
+    0x9586, 0x9588, 0x967c, 0x966d, 0x966b, 0x9671, 0x966f, 0x96bf, 0x976a, 0x9804, 0x98e5, 0x9997, 0x509b, 0x5095, 0x5094, 0x509e,
+    0x508b, 0x50a3, 0x5083, 0x508c, 0x508e, 0x509d, 0x5068, 0x509c, 0x5092, 0x5082, 0x5087, 0x515f, 0x51d4, 0x5312, 0x5311, 0x53a4,
+    0x53a7, 0x5591, 0x55a8, 0x55a5, 0x55ad, 0x5577, 0x5645, 0x55a2, 0x5593, 0x5588, 0x558f, 0x55b5, 0x5581, 0x55a3, 0x5592, 0x55a4,
+    0x557d, 0x558c, 0x55a6, 0x557f, 0x5595, 0x55a1, 0x558e, 0x570c, 0x5829, 0x5837, 0x5819, 0x581e, 0x5827, 0x5823, 0x5828, 0x57f5,
+    0x5848, 0x5825, 0x581c, 0x581b, 0x5833, 0x583f, 0x5836, 0x582e, 0x5839, 0x5838, 0x582d, 0x582c, 0x583b, 0x5961, 0x5aaf, 0x5a94,
+    0x5a9f, 0x5a7a, 0x5aa2, 0x5a9e, 0x5a78, 0x5aa6, 0x5a7c, 0x5aa5, 0x5aac, 0x5a95, 0x5aae, 0x5a37, 0x5a84, 0x5a8a, 0x5a97, 0x5a83,
+    0x5a8b, 0x5aa9, 0x5a7b, 0x5a7d, 0x5a8c, 0x5a9c, 0x5a8f, 0x5a93, 0x5a9d, 0x5bea, 0x5bcd, 0x5bcb, 0x5bd4, 0x5bd1, 0x5bca, 0x5bce,
+    0x5c0c, 0x5c30, 0x5d37, 0x5d43, 0x5d6b, 0x5d41, 0x5d4b, 0x5d3f, 0x5d35, 0x5d51, 0x5d4e, 0x5d55, 0x5d33, 0x5d3a, 0x5d52, 0x5d3d,
+    0x5d31, 0x5d59, 0x5d42, 0x5d39, 0x5d49, 0x5d38, 0x5d3c, 0x5d32, 0x5d36, 0x5d40, 0x5d45, 0x5e44, 0x5e41, 0x5f58, 0x5fa6, 0x5fa5,
+    0x5fab, 0x60c9, 0x60b9, 0x60cc, 0x60e2, 0x60ce, 0x60c4, 0x6114, 0x60f2, 0x610a, 0x6116, 0x6105, 0x60f5, 0x6113, 0x60f8, 0x60fc,
+    0x60fe, 0x60c1, 0x6103, 0x6118, 0x611d, 0x6110, 0x60ff, 0x6104, 0x610b, 0x624a, 0x6394, 0x63b1, 0x63b0, 0x63ce, 0x63e5, 0x63e8,
+    0x63ef, 0x63c3, 0x649d, 0x63f3, 0x63ca, 0x63e0, 0x63f6, 0x63d5, 0x63f2, 0x63f5, 0x6461, 0x63df, 0x63be, 0x63dd, 0x63dc, 0x63c4,
+    0x63d8, 0x63d3, 0x63c2, 0x63c7, 0x63cc, 0x63cb, 0x63c8, 0x63f0, 0x63d7, 0x63d9, 0x6532, 0x6567, 0x656a, 0x6564, 0x655c, 0x6568,
+    0x6565, 0x658c, 0x659d, 0x659e, 0x65ae, 0x65d0, 0x65d2, 0x667c, 0x666c, 0x667b, 0x6680, 0x6671, 0x6679, 0x666a, 0x6672, 0x6701,
+    0x690c, 0x68d3, 0x6904, 0x68dc, 0x692a, 0x68ec, 0x68ea, 0x68f1, 0x690f, 0x68d6, 0x68f7, 0x68eb, 0x68e4, 0x68f6, 0x6913, 0x6910,
+    0x68f3, 0x68e1, 0x6907, 0x68cc, 0x6908, 0x6970, 0x68b4, 0x6911, 0x68ef, 0x68c6, 0x6914, 0x68f8, 0x68d0, 0x68fd, 0x68fc, 0x68e8,
+    0x690b, 0x690a, 0x6917, 0x68ce, 0x68c8, 0x68dd, 0x68de, 0x68e6, 0x68f4, 0x68d1, 0x6906, 0x68d4, 0x68e9, 0x6915, 0x6925, 0x68c7,
+    0x6b39, 0x6b3b, 0x6b3f, 0x6b3c, 0x6b94, 0x6b97, 0x6b99, 0x6b95, 0x6bbd, 0x6bf0, 0x6bf2, 0x6bf3, 0x6c30, 0x6dfc, 0x6e46, 0x6e47,
+    0x6e1f, 0x6e49, 0x6e88, 0x6e3c, 0x6e3d, 0x6e45, 0x6e62, 0x6e2b, 0x6e3f, 0x6e41, 0x6e5d, 0x6e73, 0x6e1c, 0x6e33, 0x6e4b, 0x6e40,
+    0x6e51, 0x6e3b, 0x6e03, 0x6e2e, 0x6e5e, 0x6e68, 0x6e5c, 0x6e61, 0x6e31, 0x6e28, 0x6e60, 0x6e71, 0x6e6b, 0x6e39, 0x6e22, 0x6e30,
+    0x6e53, 0x6e65, 0x6e27, 0x6e78, 0x6e64, 0x6e77, 0x6e55, 0x6e79, 0x6e52, 0x6e66, 0x6e35, 0x6e36, 0x6e5a, 0x7120, 0x711e, 0x712f,
+    0x70fb, 0x712e, 0x7131, 0x7123, 0x7125, 0x7122, 0x7132, 0x711f, 0x7128, 0x713a, 0x711b, 0x724b, 0x725a, 0x7288, 0x7289, 0x7286,
+    0x7285, 0x728b, 0x7312, 0x730b, 0x7330, 0x7322, 0x7331, 0x7333, 0x7327, 0x7332, 0x732d, 0x7326, 0x7323, 0x7335, 0x730c, 0x742e,
+    0x742c, 0x7430, 0x742b, 0x7416, 0x741a, 0x7421, 0x742d, 0x7431, 0x7424, 0x7423, 0x741d, 0x7429, 0x7420, 0x7432, 0x74fb, 0x752f,
+    0x756f, 0x756c, 0x75e7, 0x75da, 0x75e1, 0x75e6, 0x75dd, 0x75df, 0x75e4, 0x75d7, 0x7695, 0x7692, 0x76da, 0x7746, 0x7747, 0x7744,
+    0x774d, 0x7745, 0x774a, 0x774e, 0x774b, 0x774c, 0x77de, 0x77ec, 0x7860, 0x7864, 0x7865, 0x785c, 0x786d, 0x7871, 0x786a, 0x786e,
+    0x7870, 0x7869, 0x7868, 0x785e, 0x7862, 0x7974, 0x7973, 0x7972, 0x7970, 0x7a02, 0x7a0a, 0x7a03, 0x7a0c, 0x7a04, 0x7a99, 0x7ae6,
+    0x7ae4, 0x7b4a, 0x7b3b, 0x7b44, 0x7b48, 0x7b4c, 0x7b4e, 0x7b40, 0x7b58, 0x7b45, 0x7ca2, 0x7c9e, 0x7ca8, 0x7ca1, 0x7d58, 0x7d6f,
+    0x7d63, 0x7d53, 0x7d56, 0x7d67, 0x7d6a, 0x7d4f, 0x7d6d, 0x7d5c, 0x7d6b, 0x7d52, 0x7d54, 0x7d69, 0x7d51, 0x7d5f, 0x7d4e, 0x7f3e,
+    0x7f3f, 0x7f65, 0x7f66, 0x7fa2, 0x7fa0, 0x7fa1, 0x7fd7, 0x8051, 0x804f, 0x8050, 0x80fe, 0x80d4, 0x8143, 0x814a, 0x8152, 0x814f,
+    0x8147, 0x813d, 0x814d, 0x813a, 0x81e6, 0x81ee, 0x81f7, 0x81f8, 0x81f9, 0x8204, 0x823c, 0x823d, 0x823f, 0x8275, 0x833b, 0x83cf,
+    0x83f9, 0x8423, 0x83c0, 0x83e8, 0x8412, 0x83e7, 0x83e4, 0x83fc, 0x83f6, 0x8410, 0x83c6, 0x83c8, 0x83eb, 0x83e3, 0x83bf, 0x8401,
+    0x83dd, 0x83e5, 0x83d8, 0x83ff, 0x83e1, 0x83cb, 0x83ce, 0x83d6, 0x83f5, 0x83c9, 0x8409, 0x840f, 0x83de, 0x8411, 0x8406, 0x83c2,
+    0x83f3, 0x83d5, 0x83fa, 0x83c7, 0x83d1, 0x83ea, 0x8413, 0x83c3, 0x83ec, 0x83ee, 0x83c4, 0x83fb, 0x83d7, 0x83e2, 0x841b, 0x83db,
+    0x83fe, 0x86d8, 0x86e2, 0x86e6, 0x86d3, 0x86e3, 0x86da, 0x86ea, 0x86dd, 0x86eb, 0x86dc, 0x86ec, 0x86e9, 0x86d7, 0x86e8, 0x86d1,
+    0x8848, 0x8856, 0x8855, 0x88ba, 0x88d7, 0x88b9, 0x88b8, 0x88c0, 0x88be, 0x88b6, 0x88bc, 0x88b7, 0x88bd, 0x88b2, 0x8901, 0x88c9,
+    0x8995, 0x8998, 0x8997, 0x89dd, 0x89da, 0x89db, 0x8a4e, 0x8a4d, 0x8a39, 0x8a59, 0x8a40, 0x8a57, 0x8a58, 0x8a44, 0x8a45, 0x8a52,
+    0x8a48, 0x8a51, 0x8a4a, 0x8a4c, 0x8a4f, 0x8c5f, 0x8c81, 0x8c80, 0x8cba, 0x8cbe, 0x8cb0, 0x8cb9, 0x8cb5, 0x8d84, 0x8d80, 0x8d89,
+    0x8dd8, 0x8dd3, 0x8dcd, 0x8dc7, 0x8dd6, 0x8ddc, 0x8dcf, 0x8dd5, 0x8dd9, 0x8dc8, 0x8dd7, 0x8dc5, 0x8eef, 0x8ef7, 0x8efa, 0x8ef9,
+    0x8ee6, 0x8eee, 0x8ee5, 0x8ef5, 0x8ee7, 0x8ee8, 0x8ef6, 0x8eeb, 0x8ef1, 0x8eec, 0x8ef4, 0x8ee9, 0x902d, 0x9034, 0x902f, 0x9106,
+    0x912c, 0x9104, 0x90ff, 0x90fc, 0x9108, 0x90f9, 0x90fb, 0x9101, 0x9100, 0x9107, 0x9105, 0x9103, 0x9161, 0x9164, 0x915f, 0x9162,
+    0x9160, 0x9201, 0x920a, 0x9225, 0x9203, 0x921a, 0x9226, 0x920f, 0x920c, 0x9200, 0x9212, 0x91ff, 0x91fd, 0x9206, 0x9204, 0x9227,
+    0x9202, 0x921c, 0x9224, 0x9219, 0x9217, 0x9205, 0x9216, 0x957b, 0x958d, 0x958c, 0x9590, 0x9687, 0x967e, 0x9688, 0x9689, 0x9683,
+    0x9680, 0x96c2, 0x96c8, 0x96c3, 0x96f1, 0x96f0, 0x976c, 0x9770, 0x976e, 0x9807, 0x98a9, 0x98eb, 0x9ce6, 0x9ef9, 0x4e83, 0x4e84,
+    0x4eb6, 0x50bd, 0x50bf, 0x50c6, 0x50ae, 0x50c4, 0x50ca, 0x50b4, 0x50c8, 0x50c2, 0x50b0, 0x50c1, 0x50ba, 0x50b1, 0x50cb, 0x50c9,
+    0x50b6, 0x50b8, 0x51d7, 0x527a, 0x5278, 0x527b, 0x527c, 0x55c3, 0x55db, 0x55cc, 0x55d0, 0x55cb, 0x55ca, 0x55dd, 0x55c0, 0x55d4,
+    0x55c4, 0x55e9, 0x55bf, 0x55d2, 0x558d, 0x55cf, 0x55d5, 0x55e2, 0x55d6, 0x55c8, 0x55f2, 0x55cd, 0x55d9, 0x55c2, 0x5714, 0x5853,
+    0x5868, 0x5864, 0x584f, 0x584d, 0x5849, 0x586f, 0x5855, 0x584e, 0x585d, 0x5859, 0x5865, 0x585b, 0x583d, 0x5863, 0x5871, 0x58fc,
+    0x5ac7, 0x5ac4, 0x5acb, 0x5aba, 0x5ab8, 0x5ab1, 0x5ab5, 0x5ab0, 0x5abf, 0x5ac8, 0x5abb, 0x5ac6, 0x5ab7, 0x5ac0, 0x5aca, 0x5ab4,
+    0x5ab6, 0x5acd, 0x5ab9, 0x5a90, 0x5bd6, 0x5bd8, 0x5bd9, 0x5c1f, 0x5c33, 0x5d71, 0x5d63, 0x5d4a, 0x5d65, 0x5d72, 0x5d6c, 0x5d5e,
+    0x5d68, 0x5d67, 0x5d62, 0x5df0, 0x5e4f, 0x5e4e, 0x5e4a, 0x5e4d, 0x5e4b, 0x5ec5, 0x5ecc, 0x5ec6, 0x5ecb, 0x5ec7, 0x5f40, 0x5faf,
+    0x5fad, 0x60f7, 0x6149, 0x614a, 0x612b, 0x6145, 0x6136, 0x6132, 0x612e, 0x6146, 0x612f, 0x614f, 0x6129, 0x6140, 0x6220, 0x9168,
+    0x6223, 0x6225, 0x6224, 0x63c5, 0x63f1, 0x63eb, 0x6410, 0x6412, 0x6409, 0x6420, 0x6424, 0x6433, 0x6443, 0x641f, 0x6415, 0x6418,
+    0x6439, 0x6437, 0x6422, 0x6423, 0x640c, 0x6426, 0x6430, 0x6428, 0x6441, 0x6435, 0x642f, 0x640a, 0x641a, 0x6440, 0x6425, 0x6427,
+    0x640b, 0x63e7, 0x641b, 0x642e, 0x6421, 0x640e, 0x656f, 0x6592, 0x65d3, 0x6686, 0x668c, 0x6695, 0x6690, 0x668b, 0x668a, 0x6699,
+    0x6694, 0x6678, 0x6720, 0x6966, 0x695f, 0x6938, 0x694e, 0x6962, 0x6971, 0x693f, 0x6945, 0x696a, 0x6939, 0x6942, 0x6957, 0x6959,
+    0x697a, 0x6948, 0x6949, 0x6935, 0x696c, 0x6933, 0x693d, 0x6965, 0x68f0, 0x6978, 0x6934, 0x6969, 0x6940, 0x696f, 0x6944, 0x6976,
+    0x6958, 0x6941, 0x6974, 0x694c, 0x693b, 0x694b, 0x6937, 0x695c, 0x694f, 0x6951, 0x6932, 0x6952, 0x692f, 0x697b, 0x693c, 0x6b46,
+    0x6b45, 0x6b43, 0x6b42, 0x6b48, 0x6b41, 0x6b9b, 0xfa0d, 0x6bfb, 0x6bfc, 0x6bf9, 0x6bf7, 0x6bf8, 0x6e9b, 0x6ed6, 0x6ec8, 0x6e8f,
+    0x6ec0, 0x6e9f, 0x6e93, 0x6e94, 0x6ea0, 0x6eb1, 0x6eb9, 0x6ec6, 0x6ed2, 0x6ebd, 0x6ec1, 0x6e9e, 0x6ec9, 0x6eb7, 0x6eb0, 0x6ecd,
+    0x6ea6, 0x6ecf, 0x6eb2, 0x6ebe, 0x6ec3, 0x6edc, 0x6ed8, 0x6e99, 0x6e92, 0x6e8e, 0x6e8d, 0x6ea4, 0x6ea1, 0x6ebf, 0x6eb3, 0x6ed0,
+    0x6eca, 0x6e97, 0x6eae, 0x6ea3, 0x7147, 0x7154, 0x7152, 0x7163, 0x7160, 0x7141, 0x715d, 0x7162, 0x7172, 0x7178, 0x716a, 0x7161,
+    0x7142, 0x7158, 0x7143, 0x714b, 0x7170, 0x715f, 0x7150, 0x7153, 0x7144, 0x714d, 0x715a, 0x724f, 0x728d, 0x728c, 0x7291, 0x7290,
+    0x728e, 0x733c, 0x7342, 0x733b, 0x733a, 0x7340, 0x734a, 0x7349, 0x7444, 0x744a, 0x744b, 0x7452, 0x7451, 0x7457, 0x7440, 0x744f,
+    0x7450, 0x744e, 0x7442, 0x7446, 0x744d, 0x7454, 0x74e1, 0x74ff, 0x74fe, 0x74fd, 0x751d, 0x7579, 0x7577, 0x6983, 0x75ef, 0x760f,
+    0x7603, 0x75f7, 0x75fe, 0x75fc, 0x75f9, 0x75f8, 0x7610, 0x75fb, 0x75f6, 0x75ed, 0x75f5, 0x75fd, 0x7699, 0x76b5, 0x76dd, 0x7755,
+    0x775f, 0x7760, 0x7752, 0x7756, 0x775a, 0x7769, 0x7767, 0x7754, 0x7759, 0x776d, 0x77e0, 0x7887, 0x789a, 0x7894, 0x788f, 0x7884,
+    0x7895, 0x7885, 0x7886, 0x78a1, 0x7883, 0x7879, 0x7899, 0x7880, 0x7896, 0x787b, 0x797c, 0x7982, 0x797d, 0x7979, 0x7a11, 0x7a18,
+    0x7a19, 0x7a12, 0x7a17, 0x7a15, 0x7a22, 0x7a13, 0x7a1b, 0x7a10, 0x7aa3, 0x7aa2, 0x7a9e, 0x7aeb, 0x7b66, 0x7b64, 0x7b6d, 0x7b74,
+    0x7b69, 0x7b72, 0x7b65, 0x7b73, 0x7b71, 0x7b70, 0x7b61, 0x7b78, 0x7b76, 0x7b63, 0x7cb2, 0x7cb4, 0x7caf, 0x7d88, 0x7d86, 0x7d80,
+    0x7d8d, 0x7d7f, 0x7d85, 0x7d7a, 0x7d8e, 0x7d7b, 0x7d83, 0x7d7c, 0x7d8c, 0x7d94, 0x7d84, 0x7d7d, 0x7d92, 0x7f6d, 0x7f6b, 0x7f67,
+    0x7f68, 0x7f6c, 0x7fa6, 0x7fa5, 0x7fa7, 0x7fdb, 0x7fdc, 0x8021, 0x8164, 0x8160, 0x8177, 0x815c, 0x8169, 0x815b, 0x8162, 0x8172,
+    0x6721, 0x815e, 0x8176, 0x8167, 0x816f, 0x8144, 0x8161, 0x821d, 0x8249, 0x8244, 0x8240, 0x8242, 0x8245, 0x84f1, 0x843f, 0x8456,
+    0x8476, 0x8479, 0x848f, 0x848d, 0x8465, 0x8451, 0x8440, 0x8486, 0x8467, 0x8430, 0x844d, 0x847d, 0x845a, 0x8459, 0x8474, 0x8473,
+    0x845d, 0x8507, 0x845e, 0x8437, 0x843a, 0x8434, 0x847a, 0x8443, 0x8478, 0x8432, 0x8445, 0x8429, 0x83d9, 0x844b, 0x842f, 0x8442,
+    0x842d, 0x845f, 0x8470, 0x8439, 0x844e, 0x844c, 0x8452, 0x846f, 0x84c5, 0x848e, 0x843b, 0x8447, 0x8436, 0x8433, 0x8468, 0x847e,
+    0x8444, 0x842b, 0x8460, 0x8454, 0x846e, 0x8450, 0x870b, 0x8704, 0x86f7, 0x870c, 0x86fa, 0x86d6, 0x86f5, 0x874d, 0x86f8, 0x870e,
+    0x8709, 0x8701, 0x86f6, 0x870d, 0x8705, 0x88d6, 0x88cb, 0x88cd, 0x88ce, 0x88de, 0x88db, 0x88da, 0x88cc, 0x88d0, 0x8985, 0x899b,
+    0x89df, 0x89e5, 0x89e4, 0x89e1, 0x89e0, 0x89e2, 0x89dc, 0x89e6, 0x8a76, 0x8a86, 0x8a7f, 0x8a61, 0x8a3f, 0x8a77, 0x8a82, 0x8a84,
+    0x8a75, 0x8a83, 0x8a81, 0x8a74, 0x8a7a, 0x8c3c, 0x8c4b, 0x8c4a, 0x8c65, 0x8c64, 0x8c66, 0x8c86, 0x8c84, 0x8c85, 0x8ccc, 0x8d68,
+    0x8d69, 0x8d91, 0x8d8c, 0x8d8e, 0x8d8f, 0x8d8d, 0x8d93, 0x8d94, 0x8d90, 0x8d92, 0x8df0, 0x8de0, 0x8dec, 0x8df1, 0x8dee, 0x8dd0,
+    0x8de9, 0x8de3, 0x8de2, 0x8de7, 0x8df2, 0x8deb, 0x8df4, 0x8f06, 0x8eff, 0x8f01, 0x8f00, 0x8f05, 0x8f07, 0x8f08, 0x8f02, 0x8f0b,
+    0x9052, 0x903f, 0x9044, 0x9049, 0x903d, 0x9110, 0x910d, 0x910f, 0x9111, 0x9116, 0x9114, 0x910b, 0x910e, 0x916e, 0x916f, 0x9248,
+    0x9252, 0x9230, 0x923a, 0x9266, 0x9233, 0x9265, 0x925e, 0x9283, 0x922e, 0x924a, 0x9246, 0x926d, 0x926c, 0x924f, 0x9260, 0x9267,
+    0x926f, 0x9236, 0x9261, 0x9270, 0x9231, 0x9254, 0x9263, 0x9250, 0x9272, 0x924e, 0x9253, 0x924c, 0x9256, 0x9232, 0x959f, 0x959c,
+    0x959e, 0x959b, 0x9692, 0x9693, 0x9691, 0x9697, 0x96ce, 0x96fa, 0x96fd, 0x96f8, 0x96f5, 0x9773, 0x9777, 0x9778, 0x9772, 0x980f,
+    0x980d, 0x980e, 0x98ac, 0x98f6, 0x98f9, 0x99af, 0x99b2, 0x99b0, 0x99b5, 0x9aad, 0x9aab, 0x9b5b, 0x9cea, 0x9ced, 0x9ce7, 0x9e80,
+    0x9efd, 0x50e6, 0x50d4, 0x50d7, 0x50e8, 0x50f3, 0x50db, 0x50ea, 0x50dd, 0x50e4, 0x50d3, 0x50ec, 0x50f0, 0x50ef, 0x50e3, 0x50e0,
+    0x51d8, 0x5280, 0x5281, 0x52e9, 0x52eb, 0x5330, 0x53ac, 0x5627, 0x5615, 0x560c, 0x5612, 0x55fc, 0x560f, 0x561c, 0x5601, 0x5613,
+    0x5602, 0x55fa, 0x561d, 0x5604, 0x55ff, 0x55f9, 0x5889, 0x587c, 0x5890, 0x5898, 0x5886, 0x5881, 0x587f, 0x5874, 0x588b, 0x587a,
+    0x5887, 0x5891, 0x588e, 0x5876, 0x5882, 0x5888, 0x587b, 0x5894, 0x588f, 0x58fe, 0x596b, 0x5adc, 0x5aee, 0x5ae5, 0x5ad5, 0x5aea,
+    0x5ada, 0x5aed, 0x5aeb, 0x5af3, 0x5ae2, 0x5ae0, 0x5adb, 0x5aec, 0x5ade, 0x5add, 0x5ad9, 0x5ae8, 0x5adf, 0x5b77, 0x5be0, 0x5be3,
+    0x5c63, 0x5d82, 0x5d80, 0x5d7d, 0x5d86, 0x5d7a, 0x5d81, 0x5d77, 0x5d8a, 0x5d89, 0x5d88, 0x5d7e, 0x5d7c, 0x5d8d, 0x5d79, 0x5d7f,
+    0x5e58, 0x5e59, 0x5e53, 0x5ed8, 0x5ed1, 0x5ed7, 0x5ece, 0x5edc, 0x5ed5, 0x5ed9, 0x5ed2, 0x5ed4, 0x5f44, 0x5f43, 0x5f6f, 0x5fb6,
+    0x612c, 0x6128, 0x6141, 0x615e, 0x6171, 0x6173, 0x6152, 0x6153, 0x6172, 0x616c, 0x6180, 0x6174, 0x6154, 0x617a, 0x615b, 0x6165,
+    0x613b, 0x616a, 0x6161, 0x6156, 0x6229, 0x6227, 0x622b, 0x642b, 0x644d, 0x645b, 0x645d, 0x6474, 0x6476, 0x6472, 0x6473, 0x647d,
+    0x6475, 0x6466, 0x64a6, 0x644e, 0x6482, 0x645e, 0x645c, 0x644b, 0x6453, 0x6460, 0x6450, 0x647f, 0x643f, 0x646c, 0x646b, 0x6459,
+    0x6465, 0x6477, 0x6573, 0x65a0, 0x66a1, 0x66a0, 0x669f, 0x6705, 0x6704, 0x6722, 0x69b1, 0x69b6, 0x69c9, 0x69a0, 0x69ce, 0x6996,
+    0x69b0, 0x69ac, 0x69bc, 0x6991, 0x6999, 0x698e, 0x69a7, 0x698d, 0x69a9, 0x69be, 0x69af, 0x69bf, 0x69c4, 0x69bd, 0x69a4, 0x69d4,
+    0x69b9, 0x69ca, 0x699a, 0x69cf, 0x69b3, 0x6993, 0x69aa, 0x69a1, 0x699e, 0x69d9, 0x6997, 0x6990, 0x69c2, 0x69b5, 0x69a5, 0x69c6,
+    0x6b4a, 0x6b4d, 0x6b4b, 0x6b9e, 0x6b9f, 0x6ba0, 0x6bc3, 0x6bc4, 0x6bfe, 0x6ece, 0x6ef5, 0x6ef1, 0x6f03, 0x6f25, 0x6ef8, 0x6f37,
+    0x6efb, 0x6f2e, 0x6f09, 0x6f4e, 0x6f19, 0x6f1a, 0x6f27, 0x6f18, 0x6f3b, 0x6f12, 0x6eed, 0x6f0a, 0x6f36, 0x6f73, 0x6ef9, 0x6eee,
+    0x6f2d, 0x6f40, 0x6f30, 0x6f3c, 0x6f35, 0x6eeb, 0x6f07, 0x6f0e, 0x6f43, 0x6f05, 0x6efd, 0x6ef6, 0x6f39, 0x6f1c, 0x6efc, 0x6f3a,
+    0x6f1f, 0x6f0d, 0x6f1e, 0x6f08, 0x6f21, 0x7187, 0x7190, 0x7189, 0x7180, 0x7185, 0x7182, 0x718f, 0x717b, 0x7186, 0x7181, 0x7197,
+    0x7244, 0x7253, 0x7297, 0x7295, 0x7293, 0x7343, 0x734d, 0x7351, 0x734c, 0x7462, 0x7473, 0x7471, 0x7475, 0x7472, 0x7467, 0x746e,
+    0x7500, 0x7502, 0x7503, 0x757d, 0x7590, 0x7616, 0x7608, 0x760c, 0x7615, 0x7611, 0x760a, 0x7614, 0x76b8, 0x7781, 0x777c, 0x7785,
+    0x7782, 0x776e, 0x7780, 0x776f, 0x777e, 0x7783, 0x78b2, 0x78aa, 0x78b4, 0x78ad, 0x78a8, 0x787e, 0x78ab, 0x789e, 0x78a5, 0x78a0,
+    0x78ac, 0x78a2, 0x78a4, 0x7998, 0x798a, 0x798b, 0x7996, 0x7995, 0x7994, 0x7993, 0x7997, 0x7988, 0x7992, 0x7990, 0x7a2b, 0x7a4a,
+    0x7a30, 0x7a2f, 0x7a28, 0x7a26, 0x7aa8, 0x7aab, 0x7aac, 0x7aee, 0x7b88, 0x7b9c, 0x7b8a, 0x7b91, 0x7b90, 0x7b96, 0x7b8d, 0x7b8c,
+    0x7b9b, 0x7b8e, 0x7b85, 0x7b98, 0x5284, 0x7b99, 0x7ba4, 0x7b82, 0x7cbb, 0x7cbf, 0x7cbc, 0x7cba, 0x7da7, 0x7db7, 0x7dc2, 0x7da3,
+    0x7daa, 0x7dc1, 0x7dc0, 0x7dc5, 0x7d9d, 0x7dce, 0x7dc4, 0x7dc6, 0x7dcb, 0x7dcc, 0x7daf, 0x7db9, 0x7d96, 0x7dbc, 0x7d9f, 0x7da6,
+    0x7dae, 0x7da9, 0x7da1, 0x7dc9, 0x7f73, 0x7fe2, 0x7fe3, 0x7fe5, 0x7fde, 0x8024, 0x805d, 0x805c, 0x8189, 0x8186, 0x8183, 0x8187,
+    0x818d, 0x818c, 0x818b, 0x8215, 0x8497, 0x84a4, 0x84a1, 0x849f, 0x84ba, 0x84ce, 0x84c2, 0x84ac, 0x84ae, 0x84ab, 0x84b9, 0x84b4,
+    0x84c1, 0x84cd, 0x84aa, 0x849a, 0x84b1, 0x84d0, 0x849d, 0x84a7, 0x84bb, 0x84a2, 0x8494, 0x84c7, 0x84cc, 0x849b, 0x84a9, 0x84af,
+    0x84a8, 0x84d6, 0x8498, 0x84b6, 0x84cf, 0x84a0, 0x84d7, 0x84d4, 0x84d2, 0x84db, 0x84b0, 0x8491, 0x8661, 0x8733, 0x8723, 0x8728,
+    0x876b, 0x8740, 0x872e, 0x871e, 0x8721, 0x8719, 0x871b, 0x8743, 0x872c, 0x8741, 0x873e, 0x8746, 0x8720, 0x8732, 0x872a, 0x872d,
+    0x873c, 0x8712, 0x873a, 0x8731, 0x8735, 0x8742, 0x8726, 0x8727, 0x8738, 0x8724, 0x871a, 0x8730, 0x8711, 0x88f7, 0x88e7, 0x88f1,
+    0x88f2, 0x88fa, 0x88fe, 0x88ee, 0x88fc, 0x88f6, 0x88fb, 0x88f0, 0x88ec, 0x88eb, 0x899d, 0x89a1, 0x899f, 0x899e, 0x89e9, 0x89eb,
+    0x89e8, 0x8aab, 0x8a99, 0x8a8b, 0x8a92, 0x8a8f, 0x8a96, 0x8c3d, 0x8c68, 0x8c69, 0x8cd5, 0x8ccf, 0x8cd7, 0x8d96, 0x8e09, 0x8e02,
+    0x8dff, 0x8e0d, 0x8dfd, 0x8e0a, 0x8e03, 0x8e07, 0x8e06, 0x8e05, 0x8dfe, 0x8e00, 0x8e04, 0x8f10, 0x8f11, 0x8f0e, 0x8f0d, 0x9123,
+    0x911c, 0x9120, 0x9122, 0x911f, 0x911d, 0x911a, 0x9124, 0x9121, 0x911b, 0x917a, 0x9172, 0x9179, 0x9173, 0x92a5, 0x92a4, 0x9276,
+    0x929b, 0x927a, 0x92a0, 0x9294, 0x92aa, 0x928d, 0x92a6, 0x929a, 0x92ab, 0x9279, 0x9297, 0x927f, 0x92a3, 0x92ee, 0x928e, 0x9282,
+    0x9295, 0x92a2, 0x927d, 0x9288, 0x92a1, 0x928a, 0x9286, 0x928c, 0x9299, 0x92a7, 0x927e, 0x9287, 0x92a9, 0x929d, 0x928b, 0x922d,
+    0x969e, 0x96a1, 0x96ff, 0x9758, 0x977d, 0x977a, 0x977e, 0x9783, 0x9780, 0x9782, 0x977b, 0x9784, 0x9781, 0x977f, 0x97ce, 0x97cd,
+    0x9816, 0x98ad, 0x98ae, 0x9902, 0x9900, 0x9907, 0x999d, 0x999c, 0x99c3, 0x99b9, 0x99bb, 0x99ba, 0x99c2, 0x99bd, 0x99c7, 0x9ab1,
+    0x9ae3, 0x9ae7, 0x9b3e, 0x9b3f, 0x9b60, 0x9b61, 0x9b5f, 0x9cf1, 0x9cf2, 0x9cf5, 0x9ea7, 0x50ff, 0x5103, 0x5130, 0x50f8, 0x5106,
+    0x5107, 0x50f6, 0x50fe, 0x510b, 0x510c, 0x50fd, 0x510a, 0x528b, 0x528c, 0x52f1, 0x52ef, 0x5648, 0x5642, 0x564c, 0x5635, 0x5641,
+    0x564a, 0x5649, 0x5646, 0x5658, 0x565a, 0x5640, 0x5633, 0x563d, 0x562c, 0x563e, 0x5638, 0x562a, 0x563a, 0x571a, 0x58ab, 0x589d,
+    0x58b1, 0x58a0, 0x58a3, 0x58af, 0x58ac, 0x58a5, 0x58a1, 0x58ff, 0x5aff, 0x5af4, 0x5afd, 0x5af7, 0x5af6, 0x5b03, 0x5af8, 0x5b02,
+    0x5af9, 0x5b01, 0x5b07, 0x5b05, 0x5b0f, 0x5c67, 0x5d99, 0x5d97, 0x5d9f, 0x5d92, 0x5da2, 0x5d93, 0x5d95, 0x5da0, 0x5d9c, 0x5da1,
+    0x5d9a, 0x5d9e, 0x5e69, 0x5e5d, 0x5e60, 0x5e5c, 0x7df3, 0x5edb, 0x5ede, 0x5ee1, 0x5f49, 0x5fb2, 0x618b, 0x6183, 0x6179, 0x61b1,
+    0x61b0, 0x61a2, 0x6189, 0x619b, 0x6193, 0x61af, 0x61ad, 0x619f, 0x6192, 0x61aa, 0x61a1, 0x618d, 0x6166, 0x61b3, 0x622d, 0x646e,
+    0x6470, 0x6496, 0x64a0, 0x6485, 0x6497, 0x649c, 0x648f, 0x648b, 0x648a, 0x648c, 0x64a3, 0x649f, 0x6468, 0x64b1, 0x6498, 0x6576,
+    0x657a, 0x6579, 0x657b, 0x65b2, 0x65b3, 0x66b5, 0x66b0, 0x66a9, 0x66b2, 0x66b7, 0x66aa, 0x66af, 0x6a00, 0x6a06, 0x6a17, 0x69e5,
+    0x69f8, 0x6a15, 0x69f1, 0x69e4, 0x6a20, 0x69ff, 0x69ec, 0x69e2, 0x6a1b, 0x6a1d, 0x69fe, 0x6a27, 0x69f2, 0x69ee, 0x6a14, 0x69f7,
+    0x69e7, 0x6a40, 0x6a08, 0x69e6, 0x69fb, 0x6a0d, 0x69fc, 0x69eb, 0x6a09, 0x6a04, 0x6a18, 0x6a25, 0x6a0f, 0x69f6, 0x6a26, 0x6a07,
+    0x69f4, 0x6a16, 0x6b51, 0x6ba5, 0x6ba3, 0x6ba2, 0x6ba6, 0x6c01, 0x6c00, 0x6bff, 0x6c02, 0x6f41, 0x6f26, 0x6f7e, 0x6f87, 0x6fc6,
+    0x6f92, 0x6f8d, 0x6f89, 0x6f8c, 0x6f62, 0x6f4f, 0x6f85, 0x6f5a, 0x6f96, 0x6f76, 0x6f6c, 0x6f82, 0x6f55, 0x6f72, 0x6f52, 0x6f50,
+    0x6f57, 0x6f94, 0x6f93, 0x6f5d, 0x6f00, 0x6f61, 0x6f6b, 0x6f7d, 0x6f67, 0x6f90, 0x6f53, 0x6f8b, 0x6f69, 0x6f7f, 0x6f95, 0x6f63,
+    0x6f77, 0x6f6a, 0x6f7b, 0x71b2, 0x71af, 0x719b, 0x71b0, 0x71a0, 0x719a, 0x71a9, 0x71b5, 0x719d, 0x71a5, 0x719e, 0x71a4, 0x71a1,
+    0x71aa, 0x719c, 0x71a7, 0x71b3, 0x7298, 0x729a, 0x7358, 0x7352, 0x735e, 0x735f, 0x7360, 0x735d, 0x735b, 0x7361, 0x735a, 0x7359,
+    0x7362, 0x7487, 0x7489, 0x748a, 0x7486, 0x7481, 0x747d, 0x7485, 0x7488, 0x747c, 0x7479, 0x7508, 0x7507, 0x757e, 0x7625, 0x761e,
+    0x7619, 0x761d, 0x761c, 0x7623, 0x761a, 0x7628, 0x761b, 0x769c, 0x769d, 0x769e, 0x769b, 0x778d, 0x778f, 0x7789, 0x7788, 0x78cd,
+    0x78bb, 0x78cf, 0x78cc, 0x78d1, 0x78ce, 0x78d4, 0x78c8, 0x78c3, 0x78c4, 0x78c9, 0x799a, 0x79a1, 0x79a0, 0x799c, 0x79a2, 0x799b,
+    0x6b76, 0x7a39, 0x7ab2, 0x7ab4, 0x7ab3, 0x7bb7, 0x7bcb, 0x7bbe, 0x7bac, 0x7bce, 0x7baf, 0x7bb9, 0x7bca, 0x7bb5, 0x7cc5, 0x7cc8,
+    0x7ccc, 0x7ccb, 0x7df7, 0x7ddb, 0x7dea, 0x7de7, 0x7dd7, 0x7de1, 0x7e03, 0x7dfa, 0x7de6, 0x7df6, 0x7df1, 0x7df0, 0x7dee, 0x7ddf,
+    0x7f76, 0x7fac, 0x7fb0, 0x7fad, 0x7fed, 0x7feb, 0x7fea, 0x7fec, 0x7fe6, 0x7fe8, 0x8064, 0x8067, 0x81a3, 0x819f, 0x819e, 0x8195,
+    0x81a2, 0x8199, 0x8197, 0x8216, 0x824f, 0x8253, 0x8252, 0x8250, 0x824e, 0x8251, 0x8524, 0x853b, 0x850f, 0x8500, 0x8529, 0x850e,
+    0x8509, 0x850d, 0x851f, 0x850a, 0x8527, 0x851c, 0x84fb, 0x852b, 0x84fa, 0x8508, 0x850c, 0x84f4, 0x852a, 0x84f2, 0x8515, 0x84f7,
+    0x84eb, 0x84f3, 0x84fc, 0x8512, 0x84ea, 0x84e9, 0x8516, 0x84fe, 0x8528, 0x851d, 0x852e, 0x8502, 0x84fd, 0x851e, 0x84f6, 0x8531,
+    0x8526, 0x84e7, 0x84e8, 0x84f0, 0x84ef, 0x84f9, 0x8518, 0x8520, 0x8530, 0x850b, 0x8519, 0x852f, 0x8662, 0x8756, 0x8763, 0x8764,
+    0x8777, 0x87e1, 0x8773, 0x8758, 0x8754, 0x875b, 0x8752, 0x8761, 0x875a, 0x8751, 0x875e, 0x876d, 0x876a, 0x8750, 0x874e, 0x875f,
+    0x875d, 0x876f, 0x876c, 0x877a, 0x876e, 0x875c, 0x8765, 0x874f, 0x877b, 0x8775, 0x8762, 0x8767, 0x8769, 0x885a, 0x8905, 0x890c,
+    0x8914, 0x890b, 0x8917, 0x8918, 0x8919, 0x8906, 0x8916, 0x8911, 0x890e, 0x8909, 0x89a2, 0x89a4, 0x89a3, 0x89ed, 0x89f0, 0x89ec,
+    0x8acf, 0x8ac6, 0x8ab8, 0x8ad3, 0x8ad1, 0x8ad4, 0x8ad5, 0x8abb, 0x8ad7, 0x8abe, 0x8ac0, 0x8ac5, 0x8ad8, 0x8ac3, 0x8aba, 0x8abd,
+    0x8ad9, 0x8c3e, 0x8c4d, 0x8c8f, 0x8ce5, 0x8cdf, 0x8cd9, 0x8ce8, 0x8cda, 0x8cdd, 0x8ce7, 0x8da0, 0x8d9c, 0x8da1, 0x8d9b, 0x8e20,
+    0x8e23, 0x8e25, 0x8e24, 0x8e2e, 0x8e15, 0x8e1b, 0x8e16, 0x8e11, 0x8e19, 0x8e26, 0x8e27, 0x8e14, 0x8e12, 0x8e18, 0x8e13, 0x8e1c,
+    0x8e17, 0x8e1a, 0x8f2c, 0x8f24, 0x8f18, 0x8f1a, 0x8f20, 0x8f23, 0x8f16, 0x8f17, 0x9073, 0x9070, 0x906f, 0x9067, 0x906b, 0x912f,
+    0x912b, 0x9129, 0x912a, 0x9132, 0x9126, 0x912e, 0x9185, 0x9186, 0x918a, 0x9181, 0x9182, 0x9184, 0x9180, 0x92d0, 0x92c3, 0x92c4,
+    0x92c0, 0x92d9, 0x92b6, 0x92cf, 0x92f1, 0x92df, 0x92d8, 0x92e9, 0x92d7, 0x92dd, 0x92cc, 0x92ef, 0x92c2, 0x92e8, 0x92ca, 0x92c8,
+    0x92ce, 0x92e6, 0x92cd, 0x92d5, 0x92c9, 0x92e0, 0x92de, 0x92e7, 0x92d1, 0x92d3, 0x92b5, 0x92e1, 0x92c6, 0x92b4, 0x957c, 0x95ac,
+    0x95ab, 0x95ae, 0x95b0, 0x96a4, 0x96a2, 0x96d3, 0x9705, 0x9708, 0x9702, 0x975a, 0x978a, 0x978e, 0x9788, 0x97d0, 0x97cf, 0x981e,
+    0x981d, 0x9826, 0x9829, 0x9828, 0x9820, 0x981b, 0x9827, 0x98b2, 0x9908, 0x98fa, 0x9911, 0x9914, 0x9916, 0x9917, 0x9915, 0x99dc,
+    0x99cd, 0x99cf, 0x99d3, 0x99d4, 0x99ce, 0x99c9, 0x99d6, 0x99d8, 0x99cb, 0x99d7, 0x99cc, 0x9ab3, 0x9aec, 0x9aeb, 0x9af3, 0x9af2,
+    0x9af1, 0x9b46, 0x9b43, 0x9b67, 0x9b74, 0x9b71, 0x9b66, 0x9b76, 0x9b75, 0x9b70, 0x9b68, 0x9b64, 0x9b6c, 0x9cfc, 0x9cfa, 0x9cfd,
+    0x9cff, 0x9cf7, 0x9d07, 0x9d00, 0x9cf9, 0x9cfb, 0x9d08, 0x9d05, 0x9d04, 0x9e83, 0x9ed3, 0x9f0f, 0x9f10, 0x511c, 0x5113, 0x5117,
+    0x511a, 0x5111, 0x51de, 0x5334, 0x53e1, 0x5670, 0x5660, 0x566e, 0x5673, 0x5666, 0x5663, 0x566d, 0x5672, 0x565e, 0x5677, 0x571c,
+    0x571b, 0x58c8, 0x58bd, 0x58c9, 0x58bf, 0x58ba, 0x58c2, 0x58bc, 0x58c6, 0x5b17, 0x5b19, 0x5b1b, 0x5b21, 0x5b14, 0x5b13, 0x5b10,
+    0x5b16, 0x5b28, 0x5b1a, 0x5b20, 0x5b1e, 0x5bef, 0x5dac, 0x5db1, 0x5da9, 0x5da7, 0x5db5, 0x5db0, 0x5dae, 0x5daa, 0x5da8, 0x5db2,
+    0x5dad, 0x5daf, 0x5db4, 0x5e67, 0x5e68, 0x5e66, 0x5e6f, 0x5ee9, 0x5ee7, 0x5ee6, 0x5ee8, 0x5ee5, 0x5f4b, 0x5fbc, 0x619d, 0x61a8,
+    0x6196, 0x61c5, 0x61b4, 0x61c6, 0x61c1, 0x61cc, 0x61ba, 0x61bf, 0x61b8, 0x618c, 0x64d7, 0x64d6, 0x64d0, 0x64cf, 0x64c9, 0x64bd,
+    0x6489, 0x64c3, 0x64db, 0x64f3, 0x64d9, 0x6533, 0x657f, 0x657c, 0x65a2, 0x66c8, 0x66be, 0x66c0, 0x66ca, 0x66cb, 0x66cf, 0x66bd,
+    0x66bb, 0x66ba, 0x66cc, 0x6723, 0x6a34, 0x6a66, 0x6a49, 0x6a67, 0x6a32, 0x6a68, 0x6a3e, 0x6a5d, 0x6a6d, 0x6a76, 0x6a5b, 0x6a51,
+    0x6a28, 0x6a5a, 0x6a3b, 0x6a3f, 0x6a41, 0x6a6a, 0x6a64, 0x6a50, 0x6a4f, 0x6a54, 0x6a6f, 0x6a69, 0x6a60, 0x6a3c, 0x6a5e, 0x6a56,
+    0x6a55, 0x6a4d, 0x6a4e, 0x6a46, 0x6b55, 0x6b54, 0x6b56, 0x6ba7, 0x6baa, 0x6bab, 0x6bc8, 0x6bc7, 0x6c04, 0x6c03, 0x6c06, 0x6fad,
+    0x6fcb, 0x6fa3, 0x6fc7, 0x6fbc, 0x6fce, 0x6fc8, 0x6f5e, 0x6fc4, 0x6fbd, 0x6f9e, 0x6fca, 0x6fa8, 0x7004, 0x6fa5, 0x6fae, 0x6fba,
+    0x6fac, 0x6faa, 0x6fcf, 0x6fbf, 0x6fb8, 0x6fa2, 0x6fc9, 0x6fab, 0x6fcd, 0x6faf, 0x6fb2, 0x6fb0, 0x71c5, 0x71c2, 0x71bf, 0x71b8,
+    0x71d6, 0x71c0, 0x71c1, 0x71cb, 0x71d4, 0x71ca, 0x71c7, 0x71cf, 0x71bd, 0x71d8, 0x71bc, 0x71c6, 0x71da, 0x71db, 0x729d, 0x729e,
+    0x7369, 0x7366, 0x7367, 0x736c, 0x7365, 0x736b, 0x736a, 0x747f, 0x749a, 0x74a0, 0x7494, 0x7492, 0x7495, 0x74a1, 0x750b, 0x7580,
+    0x762f, 0x762d, 0x7631, 0x763d, 0x7633, 0x763c, 0x7635, 0x7632, 0x7630, 0x76bb, 0x76e6, 0x779a, 0x779d, 0x77a1, 0x779c, 0x779b,
+    0x77a2, 0x77a3, 0x7795, 0x7799, 0x7797, 0x78dd, 0x78e9, 0x78e5, 0x78ea, 0x78de, 0x78e3, 0x78db, 0x78e1, 0x78e2, 0x78ed, 0x78df,
+    0x78e0, 0x79a4, 0x7a44, 0x7a48, 0x7a47, 0x7ab6, 0x7ab8, 0x7ab5, 0x7ab1, 0x7ab7, 0x7bde, 0x7be3, 0x7be7, 0x7bdd, 0x7bd5, 0x7be5,
+    0x7bda, 0x7be8, 0x7bf9, 0x7bd4, 0x7bea, 0x7be2, 0x7bdc, 0x7beb, 0x7bd8, 0x7bdf, 0x7cd2, 0x7cd4, 0x7cd7, 0x7cd0, 0x7cd1, 0x7e12,
+    0x7e21, 0x7e17, 0x7e0c, 0x7e1f, 0x7e20, 0x7e13, 0x7e0e, 0x7e1c, 0x7e15, 0x7e1a, 0x7e22, 0x7e0b, 0x7e0f, 0x7e16, 0x7e0d, 0x7e14,
+    0x7e25, 0x7e24, 0x7f43, 0x7f7b, 0x7f7c, 0x7f7a, 0x7fb1, 0x7fef, 0x802a, 0x8029, 0x806c, 0x81b1, 0x81a6, 0x81ae, 0x81b9, 0x81b5,
+    0x81ab, 0x81b0, 0x81ac, 0x81b4, 0x81b2, 0x81b7, 0x81a7, 0x81f2, 0x8255, 0x8256, 0x8257, 0x8556, 0x8545, 0x856b, 0x854d, 0x8553,
+    0x8561, 0x8558, 0x8540, 0x8546, 0x8564, 0x8541, 0x8562, 0x8544, 0x8551, 0x8547, 0x8563, 0x853e, 0x855b, 0x8571, 0x854e, 0x856e,
+    0x8575, 0x8555, 0x8567, 0x8560, 0x858c, 0x8566, 0x855d, 0x8554, 0x8565, 0x856c, 0x8663, 0x8665, 0x8664, 0x879b, 0x878f, 0x8797,
+    0x8793, 0x8792, 0x8788, 0x8781, 0x8796, 0x8798, 0x8779, 0x8787, 0x87a3, 0x8785, 0x8790, 0x8791, 0x879d, 0x8784, 0x8794, 0x879c,
+    0x879a, 0x8789, 0x891e, 0x8926, 0x8930, 0x892d, 0x892e, 0x8927, 0x8931, 0x8922, 0x8929, 0x8923, 0x892f, 0x892c, 0x891f, 0x89f1,
+    0x8ae0, 0x8ae2, 0x8af2, 0x8af4, 0x8af5, 0x8add, 0x8b14, 0x8ae4, 0x8adf, 0x8af0, 0x8ac8, 0x8ade, 0x8ae1, 0x8ae8, 0x8aff, 0x8aef,
+    0x8afb, 0x8c91, 0x8c92, 0x8c90, 0x8cf5, 0x8cee, 0x8cf1, 0x8cf0, 0x8cf3, 0x8d6c, 0x8d6e, 0x8da5, 0x8da7, 0x8e33, 0x8e3e, 0x8e38,
+    0x8e40, 0x8e45, 0x8e36, 0x8e3c, 0x8e3d, 0x8e41, 0x8e30, 0x8e3f, 0x8ebd, 0x8f36, 0x8f2e, 0x8f35, 0x8f32, 0x8f39, 0x8f37, 0x8f34,
+    0x9076, 0x9079, 0x907b, 0x9086, 0x90fa, 0x9133, 0x9135, 0x9136, 0x9193, 0x9190, 0x9191, 0x918d, 0x918f, 0x9327, 0x931e, 0x9308,
+    0x931f, 0x9306, 0x930f, 0x937a, 0x9338, 0x933c, 0x931b, 0x9323, 0x9312, 0x9301, 0x9346, 0x932d, 0x930e, 0x930d, 0x92cb, 0x931d,
+    0x92fa, 0x9325, 0x9313, 0x92f9, 0x92f7, 0x9334, 0x9302, 0x9324, 0x92ff, 0x9329, 0x9339, 0x9335, 0x932a, 0x9314, 0x930c, 0x930b,
+    0x92fe, 0x9309, 0x9300, 0x92fb, 0x9316, 0x95bc, 0x95cd, 0x95be, 0x95b9, 0x95ba, 0x95b6, 0x95bf, 0x95b5, 0x95bd, 0x96a9, 0x96d4,
+    0x970b, 0x9712, 0x9710, 0x9799, 0x9797, 0x9794, 0x97f0, 0x97f8, 0x9835, 0x982f, 0x9832, 0x9924, 0x991f, 0x9927, 0x9929, 0x999e,
+    0x99ee, 0x99ec, 0x99e5, 0x99e4, 0x99f0, 0x99e3, 0x99ea, 0x99e9, 0x99e7, 0x9ab9, 0x9abf, 0x9ab4, 0x9abb, 0x9af6, 0x9afa, 0x9af9,
+    0x9af7, 0x9b33, 0x9b80, 0x9b85, 0x9b87, 0x9b7c, 0x9b7e, 0x9b7b, 0x9b82, 0x9b93, 0x9b92, 0x9b90, 0x9b7a, 0x9b95, 0x9b7d, 0x9b88,
+    0x9d25, 0x9d17, 0x9d20, 0x9d1e, 0x9d14, 0x9d29, 0x9d1d, 0x9d18, 0x9d22, 0x9d10, 0x9d19, 0x9d1f, 0x9e88, 0x9e86, 0x9e87, 0x9eae,
+    0x9ead, 0x9ed5, 0x9ed6, 0x9efa, 0x9f12, 0x9f3d, 0x5126, 0x5125, 0x5122, 0x5124, 0x5120, 0x5129, 0x52f4, 0x5693, 0x568c, 0x568d,
+    0x5686, 0x5684, 0x5683, 0x567e, 0x5682, 0x567f, 0x5681, 0x58d6, 0x58d4, 0x58cf, 0x58d2, 0x5b2d, 0x5b25, 0x5b32, 0x5b23, 0x5b2c,
+    0x5b27, 0x5b26, 0x5b2f, 0x5b2e, 0x5b7b, 0x5bf1, 0x5bf2, 0x5db7, 0x5e6c, 0x5e6a, 0x5fbe, 0x5fbb, 0x61c3, 0x61b5, 0x61bc, 0x61e7,
+    0x61e0, 0x61e5, 0x61e4, 0x61e8, 0x61de, 0x64ef, 0x64e9, 0x64e3, 0x64eb, 0x64e4, 0x64e8, 0x6581, 0x6580, 0x65b6, 0x65da, 0x66d2,
+    0x6a8d, 0x6a96, 0x6a81, 0x6aa5, 0x6a89, 0x6a9f, 0x6a9b, 0x6aa1, 0x6a9e, 0x6a87, 0x6a93, 0x6a8e, 0x6a95, 0x6a83, 0x6aa8, 0x6aa4,
+    0x6a91, 0x6a7f, 0x6aa6, 0x6a9a, 0x6a85, 0x6a8c, 0x6a92, 0x6b5b, 0x6bad, 0x6c09, 0x6fcc, 0x6fa9, 0x6ff4, 0x6fd4, 0x6fe3, 0x6fdc,
+    0x6fed, 0x6fe7, 0x6fe6, 0x6fde, 0x6ff2, 0x6fdd, 0x6fe2, 0x6fe8, 0x71e1, 0x71f1, 0x71e8, 0x71f2, 0x71e4, 0x71f0, 0x71e2, 0x7373,
+    0x736e, 0x736f, 0x7497, 0x74b2, 0x74ab, 0x7490, 0x74aa, 0x74ad, 0x74b1, 0x74a5, 0x74af, 0x7510, 0x7511, 0x7512, 0x750f, 0x7584,
+    0x7643, 0x7648, 0x7649, 0x7647, 0x76a4, 0x76e9, 0x77b5, 0x77ab, 0x77b2, 0x77b7, 0x77b6, 0x77b4, 0x77b1, 0x77a8, 0x77f0, 0x78f3,
+    0x78fd, 0x7902, 0x78fb, 0x78fc, 0x78f2, 0x7905, 0x78f9, 0x78fe, 0x7904, 0x79ab, 0x79a8, 0x7a5c, 0x7a5b, 0x7a56, 0x7a58, 0x7a54,
+    0x7a5a, 0x7abe, 0x7ac0, 0x7ac1, 0x7c05, 0x7c0f, 0x7bf2, 0x7c00, 0x7bff, 0x7bfb, 0x7c0e, 0x7bf4, 0x7c0b, 0x7bf3, 0x7c02, 0x7c09,
+    0x7c03, 0x7c01, 0x7bf8, 0x7bfd, 0x7c06, 0x7bf0, 0x7bf1, 0x7c10, 0x7c0a, 0x7ce8, 0x7e2d, 0x7e3c, 0x7e42, 0x7e33, 0x9848, 0x7e38,
+    0x7e2a, 0x7e49, 0x7e40, 0x7e47, 0x7e29, 0x7e4c, 0x7e30, 0x7e3b, 0x7e36, 0x7e44, 0x7e3a, 0x7f45, 0x7f7f, 0x7f7e, 0x7f7d, 0x7ff4,
+    0x7ff2, 0x802c, 0x81bb, 0x81c4, 0x81cc, 0x81ca, 0x81c5, 0x81c7, 0x81bc, 0x81e9, 0x825b, 0x825a, 0x825c, 0x8583, 0x8580, 0x858f,
+    0x85a7, 0x8595, 0x85a0, 0x858b, 0x85a3, 0x857b, 0x85a4, 0x859a, 0x859e, 0x8577, 0x857c, 0x8589, 0x85a1, 0x857a, 0x8578, 0x8557,
+    0x858e, 0x8596, 0x8586, 0x858d, 0x8599, 0x859d, 0x8581, 0x85a2, 0x8582, 0x8588, 0x8585, 0x8579, 0x8576, 0x8598, 0x8590, 0x859f,
+    0x8668, 0x87be, 0x87aa, 0x87ad, 0x87c5, 0x87b0, 0x87ac, 0x87b9, 0x87b5, 0x87bc, 0x87ae, 0x87c9, 0x87c3, 0x87c2, 0x87cc, 0x87b7,
+    0x87af, 0x87c4, 0x87ca, 0x87b4, 0x87b6, 0x87bf, 0x87b8, 0x87bd, 0x87de, 0x87b2, 0x8935, 0x8933, 0x893c, 0x893e, 0x8941, 0x8952,
+    0x8937, 0x8942, 0x89ad, 0x89af, 0x89ae, 0x89f2, 0x89f3, 0x8b1e, 0x8b18, 0x8b16, 0x8b11, 0x8b05, 0x8b0b, 0x8b22, 0x8b0f, 0x8b12,
+    0x8b15, 0x8b07, 0x8b0d, 0x8b08, 0x8b06, 0x8b1c, 0x8b13, 0x8b1a, 0x8c4f, 0x8c70, 0x8c72, 0x8c71, 0x8c6f, 0x8c95, 0x8c94, 0x8cf9,
+    0x8d6f, 0x8e4e, 0x8e4d, 0x8e53, 0x8e50, 0x8e4c, 0x8e47, 0x8f43, 0x8f40, 0x9085, 0x907e, 0x9138, 0x919a, 0x91a2, 0x919b, 0x9199,
+    0x919f, 0x91a1, 0x919d, 0x91a0, 0x93a1, 0x9383, 0x93af, 0x9364, 0x9356, 0x9347, 0x937c, 0x9358, 0x935c, 0x9376, 0x9349, 0x9350,
+    0x9351, 0x9360, 0x936d, 0x938f, 0x934c, 0x936a, 0x9379, 0x9357, 0x9355, 0x9352, 0x934f, 0x9371, 0x9377, 0x937b, 0x9361, 0x935e,
+    0x9363, 0x9367, 0x9380, 0x934e, 0x9359, 0x95c7, 0x95c0, 0x95c9, 0x95c3, 0x95c5, 0x95b7, 0x96ae, 0x96b0, 0x96ac, 0x9720, 0x971f,
+    0x9718, 0x971d, 0x9719, 0x979a, 0x97a1, 0x979c, 0x979e, 0x979d, 0x97d5, 0x97d4, 0x97f1, 0x9841, 0x9844, 0x984a, 0x9849, 0x9845,
+    0x9843, 0x9925, 0x992b, 0x992c, 0x992a, 0x9933, 0x9932, 0x992f, 0x992d, 0x9931, 0x9930, 0x9998, 0x99a3, 0x99a1, 0x9a02, 0x99fa,
+    0x99f4, 0x99f7, 0x99f9, 0x99f8, 0x99f6, 0x99fb, 0x99fd, 0x99fe, 0x99fc, 0x9a03, 0x9abe, 0x9afe, 0x9afd, 0x9b01, 0x9afc, 0x9b48,
+    0x9b9a, 0x9ba8, 0x9b9e, 0x9b9b, 0x9ba6, 0x9ba1, 0x9ba5, 0x9ba4, 0x9b86, 0x9ba2, 0x9ba0, 0x9baf, 0x9d33, 0x9d41, 0x9d67, 0x9d36,
+    0x9d2e, 0x9d2f, 0x9d31, 0x9d38, 0x9d30, 0x9d45, 0x9d42, 0x9d43, 0x9d3e, 0x9d37, 0x9d40, 0x9d3d, 0x7ff5, 0x9d2d, 0x9e8a, 0x9e89,
+    0x9e8d, 0x9eb0, 0x9ec8, 0x9eda, 0x9efb, 0x9eff, 0x9f24, 0x9f23, 0x9f22, 0x9f54, 0x9fa0, 0x5131, 0x512d, 0x512e, 0x5698, 0x569c,
+    0x5697, 0x569a, 0x569d, 0x5699, 0x5970, 0x5b3c, 0x5c69, 0x5c6a, 0x5dc0, 0x5e6d, 0x5e6e, 0x61d8, 0x61df, 0x61ed, 0x61ee, 0x61f1,
+    0x61ea, 0x61f0, 0x61eb, 0x61d6, 0x61e9, 0x64ff, 0x6504, 0x64fd, 0x64f8, 0x6501, 0x6503, 0x64fc, 0x6594, 0x65db, 0x66da, 0x66db,
+    0x66d8, 0x6ac5, 0x6ab9, 0x6abd, 0x6ae1, 0x6ac6, 0x6aba, 0x6ab6, 0x6ab7, 0x6ac7, 0x6ab4, 0x6aad, 0x6b5e, 0x6bc9, 0x6c0b, 0x7007,
+    0x700c, 0x700d, 0x7001, 0x7005, 0x7014, 0x700e, 0x6fff, 0x7000, 0x6ffb, 0x7026, 0x6ffc, 0x6ff7, 0x700a, 0x7201, 0x71ff, 0x71f9,
+    0x7203, 0x71fd, 0x7376, 0x74b8, 0x74c0, 0x74b5, 0x74c1, 0x74be, 0x74b6, 0x74bb, 0x74c2, 0x7514, 0x7513, 0x765c, 0x7664, 0x7659,
+    0x7650, 0x7653, 0x7657, 0x765a, 0x76a6, 0x76bd, 0x76ec, 0x77c2, 0x77ba, 0x78ff, 0x790c, 0x7913, 0x7914, 0x7909, 0x7910, 0x7912,
+    0x7911, 0x79ad, 0x79ac, 0x7a5f, 0x7c1c, 0x7c29, 0x7c19, 0x7c20, 0x7c1f, 0x7c2d, 0x7c1d, 0x7c26, 0x7c28, 0x7c22, 0x7c25, 0x7c30,
+    0x7e5c, 0x7e50, 0x7e56, 0x7e63, 0x7e58, 0x7e62, 0x7e5f, 0x7e51, 0x7e60, 0x7e57, 0x7e53, 0x7fb5, 0x7fb3, 0x7ff7, 0x7ff8, 0x8075,
+    0x81d1, 0x81d2, 0x81d0, 0x825f, 0x825e, 0x85b4, 0x85c6, 0x85c0, 0x85c3, 0x85c2, 0x85b3, 0x85b5, 0x85bd, 0x85c7, 0x85c4, 0x85bf,
+    0x85cb, 0x85ce, 0x85c8, 0x85c5, 0x85b1, 0x85b6, 0x85d2, 0x8624, 0x85b8, 0x85b7, 0x85be, 0x8669, 0x87e7, 0x87e6, 0x87e2, 0x87db,
+    0x87eb, 0x87ea, 0x87e5, 0x87df, 0x87f3, 0x87e4, 0x87d4, 0x87dc, 0x87d3, 0x87ed, 0x87d8, 0x87e3, 0x87a4, 0x87d7, 0x87d9, 0x8801,
+    0x87f4, 0x87e8, 0x87dd, 0x8953, 0x894b, 0x894f, 0x894c, 0x8946, 0x8950, 0x8951, 0x8949, 0x8b2a, 0x8b27, 0x8b23, 0x8b33, 0x8b30,
+    0x8b35, 0x8b47, 0x8b2f, 0x8b3c, 0x8b3e, 0x8b31, 0x8b25, 0x8b37, 0x8b26, 0x8b36, 0x8b2e, 0x8b24, 0x8b3b, 0x8b3d, 0x8b3a, 0x8c42,
+    0x8c75, 0x8c99, 0x8c98, 0x8c97, 0x8cfe, 0x8d04, 0x8d02, 0x8d00, 0x8e5c, 0x8e62, 0x8e60, 0x8e57, 0x8e56, 0x8e5e, 0x8e65, 0x8e67,
+    0x8e5b, 0x8e5a, 0x8e61, 0x8e5d, 0x8e69, 0x8e54, 0x8f46, 0x8f47, 0x8f48, 0x8f4b, 0x9128, 0x913a, 0x913b, 0x913e, 0x91a8, 0x91a5,
+    0x91a7, 0x91af, 0x91aa, 0x93b5, 0x938c, 0x9392, 0x93b7, 0x939b, 0x939d, 0x9389, 0x93a7, 0x938e, 0x93aa, 0x939e, 0x93a6, 0x9395,
+    0x9388, 0x9399, 0x939f, 0x938d, 0x93b1, 0x9391, 0x93b2, 0x93a4, 0x93a8, 0x93b4, 0x93a3, 0x93a5, 0x95d2, 0x95d3, 0x95d1, 0x96b3,
+    0x96d7, 0x96da, 0x5dc2, 0x96df, 0x96d8, 0x96dd, 0x9723, 0x9722, 0x9725, 0x97ac, 0x97ae, 0x97a8, 0x97ab, 0x97a4, 0x97aa, 0x97a2,
+    0x97a5, 0x97d7, 0x97d9, 0x97d6, 0x97d8, 0x97fa, 0x9850, 0x9851, 0x9852, 0x98b8, 0x9941, 0x993c, 0x993a, 0x9a0f, 0x9a0b, 0x9a09,
+    0x9a0d, 0x9a04, 0x9a11, 0x9a0a, 0x9a05, 0x9a07, 0x9a06, 0x9ac0, 0x9adc, 0x9b08, 0x9b04, 0x9b05, 0x9b29, 0x9b35, 0x9b4a, 0x9b4c,
+    0x9b4b, 0x9bc7, 0x9bc6, 0x9bc3, 0x9bbf, 0x9bc1, 0x9bb5, 0x9bb8, 0x9bd3, 0x9bb6, 0x9bc4, 0x9bb9, 0x9bbd, 0x9d5c, 0x9d53, 0x9d4f,
+    0x9d4a, 0x9d5b, 0x9d4b, 0x9d59, 0x9d56, 0x9d4c, 0x9d57, 0x9d52, 0x9d54, 0x9d5f, 0x9d58, 0x9d5a, 0x9e8e, 0x9e8c, 0x9edf, 0x9f01,
+    0x9f00, 0x9f16, 0x9f25, 0x9f2b, 0x9f2a, 0x9f29, 0x9f28, 0x9f4c, 0x9f55, 0x5134, 0x5135, 0x5296, 0x52f7, 0x53b4, 0x56ab, 0x56ad,
+    0x56a6, 0x56a7, 0x56aa, 0x56ac, 0x58da, 0x58dd, 0x58db, 0x5912, 0x5b3d, 0x5b3e, 0x5b3f, 0x5dc3, 0x5e70, 0x5fbf, 0x61fb, 0x6507,
+    0x6510, 0x650d, 0x6509, 0x650c, 0x650e, 0x6584, 0x65de, 0x65dd, 0x66de, 0x6ae7, 0x6ae0, 0x6acc, 0x6ad1, 0x6ad9, 0x6acb, 0x6adf,
+    0x6adc, 0x6ad0, 0x6aeb, 0x6acf, 0x6acd, 0x6ade, 0x6b60, 0x6bb0, 0x6c0c, 0x7019, 0x7027, 0x7020, 0x7016, 0x702b, 0x7021, 0x7022,
+    0x7023, 0x7029, 0x7017, 0x7024, 0x701c, 0x702a, 0x720c, 0x720a, 0x7207, 0x7202, 0x7205, 0x72a5, 0x72a6, 0x72a4, 0x72a3, 0x72a1,
+    0x74cb, 0x74c5, 0x74b7, 0x74c3, 0x7516, 0x7660, 0x77c9, 0x77ca, 0x77c4, 0x77f1, 0x791d, 0x791b, 0x7921, 0x791c, 0x7917, 0x791e,
+    0x79b0, 0x7a67, 0x7a68, 0x7c33, 0x7c3c, 0x7c39, 0x7c2c, 0x7c3b, 0x7cec, 0x7cea, 0x7e76, 0x7e75, 0x7e78, 0x7e70, 0x7e77, 0x7e6f,
+    0x7e7a, 0x7e72, 0x7e74, 0x7e68, 0x7f4b, 0x7f4a, 0x7f83, 0x7f86, 0x7fb7, 0x7ffd, 0x7ffe, 0x8078, 0x81d7, 0x81d5, 0x8264, 0x8261,
+    0x8263, 0x85eb, 0x85f1, 0x85ed, 0x85d9, 0x85e1, 0x85e8, 0x85da, 0x85d7, 0x85ec, 0x85f2, 0x85f8, 0x85d8, 0x85df, 0x85e3, 0x85dc,
+    0x85d1, 0x85f0, 0x85e6, 0x85ef, 0x85de, 0x85e2, 0x8800, 0x87fa, 0x8803, 0x87f6, 0x87f7, 0x8809, 0x880c, 0x880b, 0x8806, 0x87fc,
+    0x8808, 0x87ff, 0x880a, 0x8802, 0x8962, 0x895a, 0x895b, 0x8957, 0x8961, 0x895c, 0x8958, 0x895d, 0x8959, 0x8988, 0x89b7, 0x89b6,
+    0x89f6, 0x8b50, 0x8b48, 0x8b4a, 0x8b40, 0x8b53, 0x8b56, 0x8b54, 0x8b4b, 0x8b55, 0x8b51, 0x8b42, 0x8b52, 0x8b57, 0x8c43, 0x8c77,
+    0x8c76, 0x8c9a, 0x8d06, 0x8d07, 0x8d09, 0x8dac, 0x8daa, 0x8dad, 0x8dab, 0x8e6d, 0x8e78, 0x8e73, 0x8e6a, 0x8e6f, 0x8e7b, 0x8ec2,
+    0x8f52, 0x8f51, 0x8f4f, 0x8f50, 0x8f53, 0x8fb4, 0x9140, 0x913f, 0x91b0, 0x91ad, 0x93de, 0x93c7, 0x93cf, 0x93c2, 0x93da, 0x93d0,
+    0x93f9, 0x93ec, 0x93cc, 0x93d9, 0x93a9, 0x93e6, 0x93ca, 0x93d4, 0x93ee, 0x93e3, 0x93d5, 0x93c4, 0x93ce, 0x93c0, 0x93d2, 0x93e7,
+    0x957d, 0x95da, 0x95db, 0x96e1, 0x9729, 0x972b, 0x972c, 0x9728, 0x9726, 0x97b3, 0x97b7, 0x97b6, 0x97dd, 0x97de, 0x97df, 0x985c,
+    0x9859, 0x985d, 0x9857, 0x98bf, 0x98bd, 0x98bb, 0x98be, 0x9948, 0x9947, 0x9943, 0x99a6, 0x99a7, 0x9a1a, 0x9a15, 0x9a25, 0x9a1d,
+    0x9a24, 0x9a1b, 0x9a22, 0x9a20, 0x9a27, 0x9a23, 0x9a1e, 0x9a1c, 0x9a14, 0x9ac2, 0x9b0b, 0x9b0a, 0x9b0e, 0x9b0c, 0x9b37, 0x9bea,
+    0x9beb, 0x9be0, 0x9bde, 0x9be4, 0x9be6, 0x9be2, 0x9bf0, 0x9bd4, 0x9bd7, 0x9bec, 0x9bdc, 0x9bd9, 0x9be5, 0x9bd5, 0x9be1, 0x9bda,
+    0x9d77, 0x9d81, 0x9d8a, 0x9d84, 0x9d88, 0x9d71, 0x9d80, 0x9d78, 0x9d86, 0x9d8b, 0x9d8c, 0x9d7d, 0x9d6b, 0x9d74, 0x9d75, 0x9d70,
+    0x9d69, 0x9d85, 0x9d73, 0x9d7b, 0x9d82, 0x9d6f, 0x9d79, 0x9d7f, 0x9d87, 0x9d68, 0x9e94, 0x9e91, 0x9ec0, 0x9efc, 0x9f2d, 0x9f40,
+    0x9f41, 0x9f4d, 0x9f56, 0x9f57, 0x9f58, 0x5337, 0x56b2, 0x56b5, 0x56b3, 0x58e3, 0x5b45, 0x5dc6, 0x5dc7, 0x5eee, 0x5eef, 0x5fc0,
+    0x5fc1, 0x61f9, 0x6517, 0x6516, 0x6515, 0x6513, 0x65df, 0x66e8, 0x66e3, 0x66e4, 0x6af3, 0x6af0, 0x6aea, 0x6ae8, 0x6af9, 0x6af1,
+    0x6aee, 0x6aef, 0x703c, 0x7035, 0x702f, 0x7037, 0x7034, 0x7031, 0x7042, 0x7038, 0x703f, 0x703a, 0x7039, 0x7040, 0x703b, 0x7033,
+    0x7041, 0x7213, 0x7214, 0x72a8, 0x737d, 0x737c, 0x74ba, 0x76ab, 0x76aa, 0x76be, 0x76ed, 0x77cc, 0x77ce, 0x77cf, 0x77cd, 0x77f2,
+    0x7925, 0x7923, 0x7927, 0x7928, 0x7924, 0x7929, 0x79b2, 0x7a6e, 0x7a6c, 0x7a6d, 0x7af7, 0x7c49, 0x7c48, 0x7c4a, 0x7c47, 0x7c45,
+    0x7cee, 0x7e7b, 0x7e7e, 0x7e81, 0x7e80, 0x7fba, 0x7fff, 0x8079, 0x81db, 0x81d9, 0x820b, 0x8268, 0x8269, 0x8622, 0x85ff, 0x8601,
+    0x85fe, 0x861b, 0x8600, 0x85f6, 0x8604, 0x8609, 0x8605, 0x860c, 0x85fd, 0x8819, 0x8810, 0x8811, 0x8817, 0x8813, 0x8816, 0x8963,
+    0x8966, 0x89b9, 0x89f7, 0x8b60, 0x8b6a, 0x8b5d, 0x8b68, 0x8b63, 0x8b65, 0x8b67, 0x8b6d, 0x8dae, 0x8e86, 0x8e88, 0x8e84, 0x8f59,
+    0x8f56, 0x8f57, 0x8f55, 0x8f58, 0x8f5a, 0x908d, 0x9143, 0x9141, 0x91b7, 0x91b5, 0x91b2, 0x91b3, 0x940b, 0x9413, 0x93fb, 0x9420,
+    0x940f, 0x9414, 0x93fe, 0x9415, 0x9410, 0x9428, 0x9419, 0x940d, 0x93f5, 0x9400, 0x93f7, 0x9407, 0x940e, 0x9416, 0x9412, 0x93fa,
+    0x9409, 0x93f8, 0x940a, 0x93ff, 0x93fc, 0x940c, 0x93f6, 0x9411, 0x9406, 0x95de, 0x95e0, 0x95df, 0x972e, 0x972f, 0x97b9, 0x97bb,
+    0x97fd, 0x97fe, 0x9860, 0x9862, 0x9863, 0x985f, 0x98c1, 0x98c2, 0x9950, 0x994e, 0x9959, 0x994c, 0x994b, 0x9953, 0x9a32, 0x9a34,
+    0x9a31, 0x9a2c, 0x9a2a, 0x9a36, 0x9a29, 0x9a2e, 0x9a38, 0x9a2d, 0x9ac7, 0x9aca, 0x9ac6, 0x9b10, 0x9b12, 0x9b11, 0x9c0b, 0x9c08,
+    0x9bf7, 0x9c05, 0x9c12, 0x9bf8, 0x9c40, 0x9c07, 0x9c0e, 0x9c06, 0x9c17, 0x9c14, 0x9c09, 0x9d9f, 0x9d99, 0x9da4, 0x9d9d, 0x9d92,
+    0x9d98, 0x9d90, 0x9d9b, 0x9da0, 0x9d94, 0x9d9c, 0x9daa, 0x9d97, 0x9da1, 0x9d9a, 0x9da2, 0x9da8, 0x9d9e, 0x9da3, 0x9dbf, 0x9da9,
+    0x9d96, 0x9da6, 0x9da7, 0x9e99, 0x9e9b, 0x9e9a, 0x9ee5, 0x9ee4, 0x9ee7, 0x9ee6, 0x9f30, 0x9f2e, 0x9f5b, 0x9f60, 0x9f5e, 0x9f5d,
+    0x9f59, 0x9f91, 0x513a, 0x5139, 0x5298, 0x5297, 0x56c3, 0x56bd, 0x56be, 0x5b48, 0x5b47, 0x5dcb, 0x5dcf, 0x5ef1, 0x61fd, 0x651b,
+    0x6b02, 0x6afc, 0x6b03, 0x6af8, 0x6b00, 0x7043, 0x7044, 0x704a, 0x7048, 0x7049, 0x7045, 0x7046, 0x721d, 0x721a, 0x7219, 0x737e,
+    0x7517, 0x766a, 0x77d0, 0x792d, 0x7931, 0x792f, 0x7c54, 0x7c53, 0x7cf2, 0x7e8a, 0x7e87, 0x7e88, 0x7e8b, 0x7e86, 0x7e8d, 0x7f4d,
+    0x7fbb, 0x8030, 0x81dd, 0x8618, 0x862a, 0x8626, 0x861f, 0x8623, 0x861c, 0x8619, 0x8627, 0x862e, 0x8621, 0x8620, 0x8629, 0x861e,
+    0x8625, 0x8829, 0x881d, 0x881b, 0x8820, 0x8824, 0x881c, 0x882b, 0x884a, 0x896d, 0x8969, 0x896e, 0x896b, 0x89fa, 0x8b79, 0x8b78,
+    0x8b45, 0x8b7a, 0x8b7b, 0x8d10, 0x8d14, 0x8daf, 0x8e8e, 0x8e8c, 0x8f5e, 0x8f5b, 0x8f5d, 0x9146, 0x9144, 0x9145, 0x91b9, 0x943f,
+    0x943b, 0x9436, 0x9429, 0x943d, 0x943c, 0x9430, 0x9439, 0x942a, 0x9437, 0x942c, 0x9440, 0x9431, 0x95e5, 0x95e4, 0x95e3, 0x9735,
+    0x973a, 0x97bf, 0x97e1, 0x9864, 0x98c9, 0x98c6, 0x98c0, 0x9958, 0x9956, 0x9a39, 0x9a3d, 0x9a46, 0x9a44, 0x9a42, 0x9a41, 0x9a3a,
+    0x9a3f, 0x9acd, 0x9b15, 0x9b17, 0x9b18, 0x9b16, 0x9b3a, 0x9b52, 0x9c2b, 0x9c1d, 0x9c1c, 0x9c2c, 0x9c23, 0x9c28, 0x9c29, 0x9c24,
+    0x9c21, 0x9db7, 0x9db6, 0x9dbc, 0x9dc1, 0x9dc7, 0x9dca, 0x9dcf, 0x9dbe, 0x9dc5, 0x9dc3, 0x9dbb, 0x9db5, 0x9dce, 0x9db9, 0x9dba,
+    0x9dac, 0x9dc8, 0x9db1, 0x9dad, 0x9dcc, 0x9db3, 0x9dcd, 0x9db2, 0x9e7a, 0x9e9c, 0x9eeb, 0x9eee, 0x9eed, 0x9f1b, 0x9f18, 0x9f1a,
+    0x9f31, 0x9f4e, 0x9f65, 0x9f64, 0x9f92, 0x4eb9, 0x56c6, 0x56c5, 0x56cb, 0x5971, 0x5b4b, 0x5b4c, 0x5dd5, 0x5dd1, 0x5ef2, 0x6521,
+    0x6520, 0x6526, 0x6522, 0x6b0b, 0x6b08, 0x6b09, 0x6c0d, 0x7055, 0x7056, 0x7057, 0x7052, 0x721e, 0x721f, 0x72a9, 0x737f, 0x74d8,
+    0x74d5, 0x74d9, 0x74d7, 0x766d, 0x76ad, 0x7935, 0x79b4, 0x7a70, 0x7a71, 0x7c57, 0x7c5c, 0x7c59, 0x7c5b, 0x7c5a, 0x7cf4, 0x7cf1,
+    0x7e91, 0x7f4f, 0x7f87, 0x81de, 0x826b, 0x8634, 0x8635, 0x8633, 0x862c, 0x8632, 0x8636, 0x882c, 0x8828, 0x8826, 0x882a, 0x8825,
+    0x8971, 0x89bf, 0x89be, 0x89fb, 0x8b7e, 0x8b84, 0x8b82, 0x8b86, 0x8b85, 0x8b7f, 0x8d15, 0x8e95, 0x8e94, 0x8e9a, 0x8e92, 0x8e90,
+    0x8e96, 0x8e97, 0x8f60, 0x8f62, 0x9147, 0x944c, 0x9450, 0x944a, 0x944b, 0x944f, 0x9447, 0x9445, 0x9448, 0x9449, 0x9446, 0x973f,
+    0x97e3, 0x986a, 0x9869, 0x98cb, 0x9954, 0x995b, 0x9a4e, 0x9a53, 0x9a54, 0x9a4c, 0x9a4f, 0x9a48, 0x9a4a, 0x9a49, 0x9a52, 0x9a50,
+    0x9ad0, 0x9b19, 0x9b2b, 0x9b3b, 0x9b56, 0x9b55, 0x9c46, 0x9c48, 0x9c3f, 0x9c44, 0x9c39, 0x9c33, 0x9c41, 0x9c3c, 0x9c37, 0x9c34,
+    0x9c32, 0x9c3d, 0x9c36, 0x9ddb, 0x9dd2, 0x9dde, 0x9dda, 0x9dcb, 0x9dd0, 0x9ddc, 0x9dd1, 0x9ddf, 0x9de9, 0x9dd9, 0x9dd8, 0x9dd6,
+    0x9df5, 0x9dd5, 0x9ddd, 0x9eb6, 0x9ef0, 0x9f35, 0x9f33, 0x9f32, 0x9f42, 0x9f6b, 0x9f95, 0x9fa2, 0x513d, 0x5299, 0x58e8, 0x58e7,
+    0x5972, 0x5b4d, 0x5dd8, 0x882f, 0x5f4f, 0x6201, 0x6203, 0x6204, 0x6529, 0x6525, 0x6596, 0x66eb, 0x6b11, 0x6b12, 0x6b0f, 0x6bca,
+    0x705b, 0x705a, 0x7222, 0x7382, 0x7381, 0x7383, 0x7670, 0x77d4, 0x7c67, 0x7c66, 0x7e95, 0x826c, 0x863a, 0x8640, 0x8639, 0x863c,
+    0x8631, 0x863b, 0x863e, 0x8830, 0x8832, 0x882e, 0x8833, 0x8976, 0x8974, 0x8973, 0x89fe, 0x8b8c, 0x8b8e, 0x8b8b, 0x8b88, 0x8c45,
+    0x8d19, 0x8e98, 0x8f64, 0x8f63, 0x91bc, 0x9462, 0x9455, 0x945d, 0x9457, 0x945e, 0x97c4, 0x97c5, 0x9800, 0x9a56, 0x9a59, 0x9b1e,
+    0x9b1f, 0x9b20, 0x9c52, 0x9c58, 0x9c50, 0x9c4a, 0x9c4d, 0x9c4b, 0x9c55, 0x9c59, 0x9c4c, 0x9c4e, 0x9dfb, 0x9df7, 0x9def, 0x9de3,
+    0x9deb, 0x9df8, 0x9de4, 0x9df6, 0x9de1, 0x9dee, 0x9de6, 0x9df2, 0x9df0, 0x9de2, 0x9dec, 0x9df4, 0x9df3, 0x9de8, 0x9ded, 0x9ec2,
+    0x9ed0, 0x9ef2, 0x9ef3, 0x9f06, 0x9f1c, 0x9f38, 0x9f37, 0x9f36, 0x9f43, 0x9f4f, 0x9f71, 0x9f70, 0x9f6e, 0x9f6f, 0x56d3, 0x56cd,
+    0x5b4e, 0x5c6d, 0x652d, 0x66ed, 0x66ee, 0x6b13, 0x705f, 0x7061, 0x705d, 0x7060, 0x7223, 0x74db, 0x74e5, 0x77d5, 0x7938, 0x79b7,
+    0x79b6, 0x7c6a, 0x7e97, 0x7f89, 0x826d, 0x8643, 0x8838, 0x8837, 0x8835, 0x884b, 0x8b94, 0x8b95, 0x8e9e, 0x8e9f, 0x8ea0, 0x8e9d,
+    0x91be, 0x91bd, 0x91c2, 0x946b, 0x9468, 0x9469, 0x96e5, 0x9746, 0x9743, 0x9747, 0x97c7, 0x97e5, 0x9a5e, 0x9ad5, 0x9b59, 0x9c63,
+    0x9c67, 0x9c66, 0x9c62, 0x9c5e, 0x9c60, 0x9e02, 0x9dfe, 0x9e07, 0x9e03, 0x9e06, 0x9e05, 0x9e00, 0x9e01, 0x9e09, 0x9dff, 0x9dfd,
+    0x9e04, 0x9ea0, 0x9f1e, 0x9f46, 0x9f74, 0x9f75, 0x9f76, 0x56d4, 0x652e, 0x65b8, 0x6b18, 0x6b19, 0x6b17, 0x6b1a, 0x7062, 0x7226,
+    0x72aa, 0x77d8, 0x77d9, 0x7939, 0x7c69, 0x7c6b, 0x7cf6, 0x7e9a, 0x7e98, 0x7e9b, 0x7e99, 0x81e0, 0x81e1, 0x8646, 0x8647, 0x8648,
+    0x8979, 0x897a, 0x897c, 0x897b, 0x89ff, 0x8b98, 0x8b99, 0x8ea5, 0x8ea4, 0x8ea3, 0x946e, 0x946d, 0x946f, 0x9471, 0x9473, 0x9749,
+    0x9872, 0x995f, 0x9c68, 0x9c6e, 0x9c6d, 0x9e0b, 0x9e0d, 0x9e10, 0x9e0f, 0x9e12, 0x9e11, 0x9ea1, 0x9ef5, 0x9f09, 0x9f47, 0x9f78,
+    0x9f7b, 0x9f7a, 0x9f79, 0x571e, 0x7066, 0x7c6f, 0x883c, 0x8db2, 0x8ea6, 0x91c3, 0x9474, 0x9478, 0x9476, 0x9475, 0x9a60, 0x9c74,
+    0x9c73, 0x9c71, 0x9c75, 0x9e14, 0x9e13, 0x9ef6, 0x9f0a, 0x9fa4, 0x7068, 0x7065, 0x7cf7, 0x866a, 0x883e, 0x883d, 0x883f, 0x8b9e,
+    0x8c9c, 0x8ea9, 0x8ec9, 0x974b, 0x9873, 0x9874, 0x98cc, 0x9961, 0x99ab, 0x9a64, 0x9a66, 0x9a67, 0x9b24, 0x9e15, 0x9e17, 0x9f48,
+    0x6207, 0x6b1e, 0x7227, 0x864c, 0x8ea8, 0x9482, 0x9480, 0x9481, 0x9a69, 0x9a68, 0x9b2e, 0x9e19, 0x7229, 0x864b, 0x8b9f, 0x9483,
+    0x9c79, 0x9eb7, 0x7675, 0x9a6b, 0x9c7a, 0x9e1d, 0x7069, 0x706a, 0x9ea4, 0x9f7e, 0x9f49, 0x9f98, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+];
+
+/// Pairs of a Unicode code point and the pointer of its character, sorted by code point.
+#[rustfmt::skip]
+static FROM_UNICODE: [(u16, u16); 13706] = [
+    (0x00a2, 5187), (0x00a3, 5188), (0x00a5, 5185), (0x00a7, 5103), (0x00b0, 5205), (0x00b1, 5137), (0x00b7, 5040), (0x00d7, 5135),
+    (0x00f7, 5136), (0x02c7, 5430), (0x02c9, 5428), (0x02ca, 5429), (0x02cb, 5431), (0x02cd, 5123), (0x02d9, 5427), (0x0391, 5342),
+    (0x0392, 5343), (0x0393, 5344), (0x0394, 5345), (0x0395, 5346), (0x0396, 5347), (0x0397, 5348), (0x0398, 5349), (0x0399, 5350),
+    (0x039a, 5351), (0x039b, 5352), (0x039c, 5353), (0x039d, 5354), (0x039e, 5355), (0x039f, 5356), (0x03a0, 5357), (0x03a1, 5358),
+    (0x03a3, 5359), (0x03a4, 5360), (0x03a5, 5361), (0x03a6, 5362), (0x03a7, 5363), (0x03a8, 5364), (0x03a9, 5365), (0x03b1, 5366),
+    (0x03b2, 5367), (0x03b3, 5368), (0x03b4, 5369), (0x03b5, 5370), (0x03b6, 5371), (0x03b7, 5372), (0x03b8, 5373), (0x03b9, 5374),
+    (0x03ba, 5375), (0x03bb, 5376), (0x03bc, 5377), (0x03bd, 5378), (0x03be, 5379), (0x03bf, 5380), (0x03c0, 5381), (0x03c1, 5382),
+    (0x03c3, 5383), (0x03c4, 5384), (0x03c5, 5385), (0x03c6, 5386), (0x03c7, 5387), (0x03c8, 5388), (0x03c9, 5389), (0x0401, 11071),
+    (0x0414, 11069), (0x0415, 11070), (0x0416, 11072), (0x0417, 11073), (0x0418, 11074), (0x0419, 11075), (0x041a, 11076), (0x041b, 11077),
+    (0x041c, 11078), (0x0423, 11079), (0x0424, 11080), (0x0425, 11081), (0x0426, 11082), (0x0427, 11083), (0x0428, 11084), (0x0429, 11085),
+    (0x042a, 11086), (0x042b, 11087), (0x042c, 11088), (0x042d, 11089), (0x042e, 11090), (0x042f, 11091), (0x0430, 11092), (0x0431, 11093),
+    (0x0432, 11094), (0x0433, 11095), (0x0434, 11096), (0x0435, 11097), (0x0436, 11099), (0x0437, 11100), (0x0438, 11101), (0x0439, 11102),
+    (0x043a, 11103), (0x043b, 11104), (0x043c, 11105), (0x043d, 11106), (0x043e, 11107), (0x043f, 11108), (0x0440, 11109), (0x0441, 11110),
+    (0x0442, 11111), (0x0443, 11112), (0x0444, 11113), (0x0445, 11114), (0x0446, 11115), (0x0447, 11116), (0x0448, 11117), (0x0449, 11118),
+    (0x044a, 11119), (0x044b, 11120), (0x044c, 11121), (0x044d, 11122), (0x044e, 11123), (0x044f, 11124), (0x0451, 11098), (0x2013, 5046),
+    (0x2014, 5048), (0x2018, 5091), (0x2019, 5092), (0x201c, 5093), (0x201d, 5094), (0x2022, 5029), (0x2025, 5036), (0x2026, 5035),
+    (0x2032, 5098), (0x2035, 5097), (0x203b, 5102), (0x203e, 5120), (0x2103, 5191), (0x2105, 5119), (0x2109, 5192), (0x2160, 5268),
+    (0x2161, 5269), (0x2162, 5270), (0x2163, 5271), (0x2164, 5272), (0x2165, 5273), (0x2166, 5274), (0x2167, 5275), (0x2168, 5276),
+    (0x2169, 5277), (0x2190, 5172), (0x2191, 5170), (0x2192, 5173), (0x2193, 5171), (0x2196, 5174), (0x2197, 5175), (0x2198, 5177),
+    (0x2199, 5176), (0x221a, 5138), (0x221e, 5145), (0x221f, 5158), (0x2220, 5157), (0x2223, 5179), (0x2225, 5178), (0x2229, 5154),
+    (0x222a, 5155), (0x222b, 5162), (0x222e, 5163), (0x2234, 5165), (0x2235, 5164), (0x223c, 5153), (0x2252, 5146), (0x2260, 5144),
+    (0x2261, 5147), (0x2266, 5142), (0x2267, 5143), (0x22a5, 5156), (0x22bf, 5159), (0x2460, 11125), (0x2461, 11126), (0x2462, 11127),
+    (0x2463, 11128), (0x2464, 11129), (0x2465, 11130), (0x2466, 11131), (0x2467, 11132), (0x2468, 11133), (0x2469, 11134), (0x2474, 11135),
+    (0x2475, 11136), (0x2476, 11137), (0x2477, 11138), (0x2478, 11139), (0x2479, 11140), (0x247a, 11141), (0x247b, 11142), (0x247c, 11143),
+    (0x247d, 11144), (0x2500, 5236), (0x2502, 5237), (0x250c, 5239), (0x2510, 5240), (0x2514, 5241), (0x2518, 5242), (0x251c, 5234),
+    (0x2524, 5233), (0x252c, 5232), (0x2534, 5231), (0x253c, 5230), (0x2550, 5247), (0x255e, 5248), (0x2561, 5250), (0x256a, 5249),
+    (0x256d, 5243), (0x256e, 5244), (0x256f, 5246), (0x2570, 5245), (0x2571, 5255), (0x2572, 5256), (0x2573, 5257), (0x2574, 5050),
+    (0x2581, 5215), (0x2582, 5216), (0x2583, 5217), (0x2584, 5218), (0x2585, 5219), (0x2586, 5220), (0x2587, 5221), (0x2588, 5222),
+    (0x2589, 5229), (0x258a, 5228), (0x258b, 5227), (0x258c, 5226), (0x258d, 5225), (0x258e, 5224), (0x258f, 5223), (0x2594, 5235),
+    (0x2595, 5238), (0x25a0, 5115), (0x25a1, 5114), (0x25b2, 5108), (0x25b3, 5107), (0x25bc, 5117), (0x25bd, 5116), (0x25c6, 5113),
+    (0x25c7, 5112), (0x25cb, 5105), (0x25ce, 5109), (0x25cf, 5106), (0x25e2, 5251), (0x25e3, 5252), (0x25e4, 5254), (0x25e5, 5253),
+    (0x2605, 5111), (0x2606, 5110), (0x2609, 5169), (0x2640, 5166), (0x2641, 5168), (0x2642, 5167), (0x3000, 5024), (0x3001, 5026),
+    (0x3002, 5027), (0x3003, 5104), (0x3005, 10899), (0x3008, 5073), (0x3009, 5074), (0x300a, 5069), (0x300b, 5070), (0x300c, 5077),
+    (0x300d, 5078), (0x300e, 5081), (0x300f, 5082), (0x3010, 5065), (0x3011, 5066), (0x3012, 5186), (0x3014, 5061), (0x3015, 5062),
+    (0x301d, 5095), (0x301e, 5096), (0x3021, 5278), (0x3022, 5279), (0x3023, 5280), (0x3024, 5281), (0x3025, 5282), (0x3026, 5283),
+    (0x3027, 5284), (0x3028, 5285), (0x3029, 5286), (0x3041, 10900), (0x3042, 10901), (0x3043, 10902), (0x3044, 10903), (0x3045, 10904),
+    (0x3046, 10905), (0x3047, 10906), (0x3048, 10907), (0x3049, 10908), (0x304a, 10909), (0x304b, 10910), (0x304c, 10911), (0x304d, 10912),
+    (0x304e, 10913), (0x304f, 10914), (0x3050, 10915), (0x3051, 10916), (0x3052, 10917), (0x3053, 10918), (0x3054, 10919), (0x3055, 10920),
+    (0x3056, 10921), (0x3057, 10922), (0x3058, 10923), (0x3059, 10924), (0x305a, 10925), (0x305b, 10926), (0x305c, 10927), (0x305d, 10928),
+    (0x305e, 10929), (0x305f, 10930), (0x3060, 10931), (0x3061, 10932), (0x3062, 10933), (0x3063, 10934), (0x3064, 10935), (0x3065, 10936),
+    (0x3066, 10937), (0x3067, 10938), (0x3068, 10939), (0x3069, 10940), (0x306a, 10941), (0x306b, 10942), (0x306c, 10943), (0x306d, 10944),
+    (0x306e, 10945), (0x306f, 10946), (0x3070, 10947), (0x3071, 10948), (0x3072, 10949), (0x3073, 10950), (0x3074, 10951), (0x3075, 10952),
+    (0x3076, 10953), (0x3077, 10954), (0x3078, 10955), (0x3079, 10956), (0x307a, 10957), (0x307b, 10958), (0x307c, 10959), (0x307d, 10960),
+    (0x307e, 10961), (0x307f, 10962), (0x3080, 10963), (0x3081, 10964), (0x3082, 10965), (0x3083, 10966), (0x3084, 10967), (0x3085, 10968),
+    (0x3086, 10969), (0x3087, 10970), (0x3088, 10971), (0x3089, 10972), (0x308a, 10973), (0x308b, 10974), (0x308c, 10975), (0x308d, 10976),
+    (0x308e, 10977), (0x308f, 10978), (0x3090, 10979), (0x3091, 10980), (0x3092, 10981), (0x3093, 10982), (0x309d, 10897), (0x309e, 10898),
+    (0x30a1, 10983), (0x30a2, 10984), (0x30a3, 10985), (0x30a4, 10986), (0x30a5, 10987), (0x30a6, 10988), (0x30a7, 10989), (0x30a8, 10990),
+    (0x30a9, 10991), (0x30aa, 10992), (0x30ab, 10993), (0x30ac, 10994), (0x30ad, 10995), (0x30ae, 10996), (0x30af, 10997), (0x30b0, 10998),
+    (0x30b1, 10999), (0x30b2, 11000), (0x30b3, 11001), (0x30b4, 11002), (0x30b5, 11003), (0x30b6, 11004), (0x30b7, 11005), (0x30b8, 11006),
+    (0x30b9, 11007), (0x30ba, 11008), (0x30bb, 11009), (0x30bc, 11010), (0x30bd, 11011), (0x30be, 11012), (0x30bf, 11013), (0x30c0, 11014),
+    (0x30c1, 11015), (0x30c2, 11016), (0x30c3, 11017), (0x30c4, 11018), (0x30c5, 11019), (0x30c6, 11020), (0x30c7, 11021), (0x30c8, 11022),
+    (0x30c9, 11023), (0x30ca, 11024), (0x30cb, 11025), (0x30cc, 11026), (0x30cd, 11027), (0x30ce, 11028), (0x30cf, 11029), (0x30d0, 11030),
+    (0x30d1, 11031), (0x30d2, 11032), (0x30d3, 11033), (0x30d4, 11034), (0x30d5, 11035), (0x30d6, 11036), (0x30d7, 11037), (0x30d8, 11038),
+    (0x30d9, 11039), (0x30da, 11040), (0x30db, 11041), (0x30dc, 11042), (0x30dd, 11043), (0x30de, 11044), (0x30df, 11045), (0x30e0, 11046),
+    (0x30e1, 11047), (0x30e2, 11048), (0x30e3, 11049), (0x30e4, 11050), (0x30e5, 11051), (0x30e6, 11052), (0x30e7, 11053), (0x30e8, 11054),
+    (0x30e9, 11055), (0x30ea, 11056), (0x30eb, 11057), (0x30ec, 11058), (0x30ed, 11059), (0x30ee, 11060), (0x30ef, 11061), (0x30f0, 11062),
+    (0x30f1, 11063), (0x30f2, 11064), (0x30f3, 11065), (0x30f4, 11066), (0x30f5, 11067), (0x30f6, 11068), (0x30fe, 10896), (0x3105, 5390),
+    (0x3106, 5391), (0x3107, 5392), (0x3108, 5393), (0x3109, 5394), (0x310a, 5395), (0x310b, 5396), (0x310c, 5397), (0x310d, 5398),
+    (0x310e, 5399), (0x310f, 5400), (0x3110, 5401), (0x3111, 5402), (0x3112, 5403), (0x3113, 5404), (0x3114, 5405), (0x3115, 5406),
+    (0x3116, 5407), (0x3117, 5408), (0x3118, 5409), (0x3119, 5410), (0x311a, 5411), (0x311b, 5412), (0x311c, 5413), (0x311d, 5414),
+    (0x311e, 5415), (0x311f, 5416), (0x3120, 5417), (0x3121, 5418), (0x3122, 5419), (0x3123, 5420), (0x3124, 5421), (0x3125, 5422),
+    (0x3126, 5423), (0x3127, 5424), (0x3128, 5425), (0x3129, 5426), (0x32a3, 5118), (0x338e, 5202), (0x338f, 5203), (0x339c, 5197),
+    (0x339d, 5198), (0x339e, 5199), (0x33a1, 5201), (0x33c4, 5204), (0x33ce, 5200), (0x33d1, 5161), (0x33d2, 5160), (0x33d5, 5196),
+    (0x4e00, 5495), (0x4e01, 5497), (0x4e03, 5498), (0x4e07, 11309), (0x4e08, 5517), (0x4e09, 5515), (0x4e0a, 5518), (0x4e0b, 5516),
+    (0x4e0c, 11310), (0x4e0d, 5560), (0x4e0e, 11319), (0x4e0f, 11317), (0x4e10, 5559), (0x4e11, 5558), (0x4e14, 5654), (0x4e15, 5653),
+    (0x4e16, 5652), (0x4e18, 5655), (0x4e19, 5651), (0x4e1e, 5778), (0x4e1f, 5779), (0x4e26, 6220), (0x4e2b, 5519), (0x4e2d, 5561),
+    (0x4e2e, 11320), (0x4e30, 5562), (0x4e31, 11339), (0x4e32, 5945), (0x4e33, 11697), (0x4e38, 5520), (0x4e39, 5563), (0x4e3b, 5656),
+    (0x4e3c, 11340), (0x4e42, 11304), (0x4e43, 5499), (0x4e45, 5522), (0x4e47, 11311), (0x4e48, 5523), (0x4e4b, 5564), (0x4e4d, 5657),
+    (0x4e4e, 5659), (0x4e4f, 5658), (0x4e52, 5780), (0x4e53, 5781), (0x4e56, 6221), (0x4e58, 6998), (0x4e59, 5496), (0x4e5c, 11305),
+    (0x4e5d, 5500), (0x4e5e, 5525), (0x4e5f, 5524), (0x4e69, 5782), (0x4e73, 6222), (0x4e7e, 7455), (0x4e7f, 13042), (0x4e82, 8418),
+    (0x4e83, 14366), (0x4e84, 14367), (0x4e86, 5501), (0x4e88, 5566), (0x4e8b, 6223), (0x4e8c, 5502), (0x4e8d, 11312), (0x4e8e, 5526),
+    (0x4e91, 5567), (0x4e92, 5569), (0x4e93, 11321), (0x4e94, 5570), (0x4e95, 5568), (0x4e99, 5783), (0x4e9b, 6224), (0x4e9e, 6225),
+    (0x4e9f, 6599), (0x4ea1, 5527), (0x4ea2, 5571), (0x4ea4, 5784), (0x4ea5, 5786), (0x4ea6, 5785), (0x4ea8, 5946), (0x4eab, 6226),
+    (0x4eac, 6227), (0x4ead, 6600), (0x4eae, 6601), (0x4eb3, 6999), (0x4eb6, 14368), (0x4eb9, 18485), (0x4eba, 5503), (0x4ec0, 5573),
+    (0x4ec1, 5572), (0x4ec2, 11322), (0x4ec3, 5574), (0x4ec4, 5580), (0x4ec6, 5575), (0x4ec7, 5576), (0x4ec8, 11324), (0x4ec9, 11323),
+    (0x4eca, 5578), (0x4ecb, 5579), (0x4ecd, 5577), (0x4ed4, 5662), (0x4ed5, 5663), (0x4ed6, 5664), (0x4ed7, 5665), (0x4ed8, 5661),
+    (0x4ed9, 5668), (0x4eda, 11346), (0x4edc, 11342), (0x4edd, 11345), (0x4ede, 5669), (0x4edf, 5685), (0x4ee1, 11344), (0x4ee3, 5666),
+    (0x4ee4, 5667), (0x4ee5, 5660), (0x4ee8, 11341), (0x4ee9, 11343), (0x4ef0, 5799), (0x4ef1, 11382), (0x4ef2, 5796), (0x4ef3, 5800),
+    (0x4ef4, 11392), (0x4ef5, 11380), (0x4ef6, 5797), (0x4ef7, 11384), (0x4efb, 5798), (0x4efd, 5801), (0x4eff, 5787), (0x4f00, 11383),
+    (0x4f01, 5802), (0x4f02, 11387), (0x4f04, 11391), (0x4f05, 11388), (0x4f08, 11385), (0x4f09, 5788), (0x4f0a, 5790), (0x4f0b, 5803),
+    (0x4f0d, 5792), (0x4f0e, 11377), (0x4f0f, 5795), (0x4f10, 5793), (0x4f11, 5794), (0x4f12, 11393), (0x4f13, 11390), (0x4f14, 11381),
+    (0x4f15, 5791), (0x4f18, 11378), (0x4f19, 5789), (0x4f1d, 11386), (0x4f22, 11389), (0x4f2c, 11379), (0x4f2d, 11499), (0x4f2f, 5968),
+    (0x4f30, 5955), (0x4f33, 11500), (0x4f34, 5952), (0x4f36, 5970), (0x4f38, 5960), (0x4f3a, 5959), (0x4f3b, 11488), (0x4f3c, 5963),
+    (0x4f3d, 5958), (0x4f3e, 11493), (0x4f3f, 11501), (0x4f41, 11497), (0x4f43, 5961), (0x4f46, 5964), (0x4f47, 5949), (0x4f48, 5973),
+    (0x4f49, 11490), (0x4f4c, 11709), (0x4f4d, 5947), (0x4f4e, 5969), (0x4f4f, 5948), (0x4f50, 5956), (0x4f51, 5957), (0x4f52, 11495),
+    (0x4f53, 11491), (0x4f54, 5962), (0x4f55, 5954), (0x4f56, 11487), (0x4f57, 5950), (0x4f58, 11498), (0x4f59, 5971), (0x4f5a, 5974),
+    (0x4f5b, 5953), (0x4f5c, 5966), (0x4f5d, 5972), (0x4f5e, 5951), (0x4f5f, 11496), (0x4f60, 5967), (0x4f61, 11502), (0x4f62, 11489),
+    (0x4f63, 5965), (0x4f64, 11492), (0x4f67, 11494), (0x4f69, 6241), (0x4f6a, 11711), (0x4f6b, 11723), (0x4f6c, 6233), (0x4f6e, 11724),
+    (0x4f6f, 6228), (0x4f70, 6238), (0x4f73, 6231), (0x4f74, 11705), (0x4f75, 6239), (0x4f76, 11704), (0x4f77, 11708), (0x4f78, 11715),
+    (0x4f79, 11713), (0x4f7a, 6247), (0x4f7b, 6242), (0x4f7c, 11699), (0x4f7d, 11701), (0x4f7e, 6244), (0x4f7f, 6232), (0x4f80, 11702),
+    (0x4f81, 11714), (0x4f82, 11721), (0x4f83, 6237), (0x4f84, 11707), (0x4f85, 11700), (0x4f86, 6236), (0x4f87, 11703), (0x4f88, 6240),
+    (0x4f89, 11706), (0x4f8b, 6235), (0x4f8d, 6230), (0x4f8f, 6245), (0x4f90, 11716), (0x4f91, 6246), (0x4f92, 11720), (0x4f94, 11718),
+    (0x4f95, 11722), (0x4f96, 6243), (0x4f97, 11710), (0x4f98, 11698), (0x4f9a, 11712), (0x4f9b, 6234), (0x4f9c, 11717), (0x4f9d, 6229),
+    (0x4f9e, 11719), (0x4fae, 6616), (0x4faf, 6604), (0x4fb2, 12058), (0x4fb3, 12066), (0x4fb5, 6603), (0x4fb6, 6611), (0x4fb7, 6623),
+    (0x4fb9, 12072), (0x4fba, 12070), (0x4fbb, 12065), (0x4fbf, 6605), (0x4fc0, 12071), (0x4fc1, 12061), (0x4fc2, 6619), (0x4fc3, 6610),
+    (0x4fc4, 6618), (0x4fc5, 12056), (0x4fc7, 12068), (0x4fc9, 12059), (0x4fca, 6614), (0x4fcb, 12060), (0x4fcd, 12055), (0x4fce, 6621),
+    (0x4fcf, 6608), (0x4fd0, 6617), (0x4fd1, 6607), (0x4fd3, 12057), (0x4fd4, 12062), (0x4fd6, 12069), (0x4fd7, 6615), (0x4fd8, 6612),
+    (0x4fd9, 12064), (0x4fda, 6620), (0x4fdb, 12067), (0x4fdc, 12063), (0x4fdd, 6609), (0x4fde, 6622), (0x4fdf, 6613), (0x4fe0, 6606),
+    (0x4fe1, 6602), (0x4fec, 12073), (0x4fee, 7025), (0x4fef, 7003), (0x4ff1, 7019), (0x4ff3, 7024), (0x4ff4, 12513), (0x4ff5, 12512),
+    (0x4ff6, 12517), (0x4ff7, 12518), (0x4ff8, 7006), (0x4ffa, 7015), (0x4ffe, 7028), (0x5000, 7016), (0x5005, 12506), (0x5006, 7009),
+    (0x5007, 12507), (0x5009, 7030), (0x500b, 7021), (0x500c, 7000), (0x500d, 7001), (0x500e, 12526), (0x500f, 7472), (0x5011, 7014),
+    (0x5012, 7013), (0x5013, 12508), (0x5014, 7017), (0x5015, 13058), (0x5016, 7008), (0x5017, 12519), (0x5018, 7023), (0x5019, 7022),
+    (0x501a, 7012), (0x501b, 12511), (0x501c, 12520), (0x501e, 12505), (0x501f, 7011), (0x5020, 12521), (0x5021, 7020), (0x5022, 12509),
+    (0x5023, 7002), (0x5025, 7005), (0x5026, 7004), (0x5027, 12522), (0x5028, 7018), (0x5029, 7007), (0x502a, 7027), (0x502b, 7029),
+    (0x502c, 12516), (0x502d, 7026), (0x502f, 12524), (0x5030, 12510), (0x5031, 12525), (0x5033, 12514), (0x5035, 12523), (0x5037, 12515),
+    (0x503c, 7010), (0x5040, 13066), (0x5041, 13054), (0x5043, 7460), (0x5045, 13059), (0x5046, 13065), (0x5047, 7459), (0x5048, 13052),
+    (0x5049, 7463), (0x504a, 13056), (0x504b, 13049), (0x504c, 7461), (0x504d, 13053), (0x504e, 7466), (0x504f, 7471), (0x5051, 13070),
+    (0x5053, 13048), (0x5055, 7467), (0x5057, 13069), (0x505a, 7462), (0x505b, 13055), (0x505c, 7458), (0x505d, 13050), (0x505e, 13046),
+    (0x505f, 13060), (0x5060, 13047), (0x5061, 13045), (0x5062, 13057), (0x5063, 13063), (0x5064, 13064), (0x5065, 7464), (0x5068, 13686),
+    (0x5069, 13061), (0x506a, 13044), (0x506b, 13062), (0x506d, 7474), (0x506e, 13067), (0x506f, 7473), (0x5070, 13043), (0x5072, 13051),
+    (0x5073, 13068), (0x5074, 7469), (0x5075, 7468), (0x5076, 7465), (0x5077, 7470), (0x507a, 7456), (0x507d, 7457), (0x5080, 7952),
+    (0x5082, 13689), (0x5083, 13682), (0x5085, 7949), (0x5087, 13690), (0x508b, 13680), (0x508c, 13683), (0x508d, 7948), (0x508e, 13684),
+    (0x5091, 7951), (0x5092, 13688), (0x5094, 13678), (0x5095, 13677), (0x5096, 7953), (0x5098, 7954), (0x5099, 7950), (0x509a, 7955),
+    (0x509b, 13676), (0x509c, 13687), (0x509d, 13685), (0x509e, 13679), (0x50a2, 7947), (0x50a3, 13681), (0x50ac, 8425), (0x50ad, 8419),
+    (0x50ae, 14372), (0x50af, 8428), (0x50b0, 14378), (0x50b1, 14381), (0x50b2, 8421), (0x50b3, 8422), (0x50b4, 14375), (0x50b5, 8420),
+    (0x50b6, 14384), (0x50b7, 8426), (0x50b8, 14385), (0x50ba, 14380), (0x50bb, 8427), (0x50bd, 14369), (0x50be, 8424), (0x50bf, 14370),
+    (0x50c1, 14379), (0x50c2, 14377), (0x50c4, 14373), (0x50c5, 8423), (0x50c6, 14371), (0x50c7, 8429), (0x50c8, 14376), (0x50c9, 14383),
+    (0x50ca, 14374), (0x50cb, 14382), (0x50ce, 8876), (0x50cf, 8873), (0x50d1, 8874), (0x50d3, 15066), (0x50d4, 15058), (0x50d5, 8872),
+    (0x50d6, 8869), (0x50d7, 15059), (0x50da, 8871), (0x50db, 15062), (0x50dd, 15064), (0x50e0, 15071), (0x50e3, 15070), (0x50e4, 15065),
+    (0x50e5, 8868), (0x50e6, 15057), (0x50e7, 8866), (0x50e8, 15060), (0x50e9, 8877), (0x50ea, 15063), (0x50ec, 15067), (0x50ed, 8870),
+    (0x50ee, 8867), (0x50ef, 15069), (0x50f0, 15068), (0x50f1, 8875), (0x50f3, 15061), (0x50f5, 9256), (0x50f6, 15681), (0x50f8, 15678),
+    (0x50f9, 9257), (0x50fb, 9255), (0x50fd, 15685), (0x50fe, 15682), (0x50ff, 15675), (0x5100, 9254), (0x5102, 9258), (0x5103, 15676),
+    (0x5104, 9253), (0x5105, 9261), (0x5106, 15679), (0x5107, 15680), (0x5108, 9259), (0x5109, 9260), (0x510a, 15686), (0x510b, 15683),
+    (0x510c, 15684), (0x5110, 9649), (0x5111, 16321), (0x5112, 9646), (0x5113, 16318), (0x5114, 9648), (0x5115, 9650), (0x5117, 16319),
+    (0x5118, 9647), (0x511a, 16320), (0x511c, 16317), (0x511f, 9945), (0x5120, 16890), (0x5121, 9946), (0x5122, 16888), (0x5124, 16889),
+    (0x5125, 16887), (0x5126, 16886), (0x5129, 16891), (0x512a, 9944), (0x512d, 17356), (0x512e, 17357), (0x5130, 15677), (0x5131, 17355),
+    (0x5132, 9947), (0x5133, 10393), (0x5134, 17721), (0x5135, 17722), (0x5137, 10636), (0x5138, 10637), (0x5139, 18307), (0x513a, 18306),
+    (0x513b, 10720), (0x513c, 10719), (0x513d, 18636), (0x513f, 5504), (0x5140, 5528), (0x5141, 5582), (0x5143, 5581), (0x5144, 5671),
+    (0x5145, 5670), (0x5146, 5806), (0x5147, 5805), (0x5148, 5807), (0x5149, 5804), (0x514b, 5976), (0x514c, 5975), (0x514d, 5977),
+    (0x5152, 6249), (0x5154, 6248), (0x5155, 6250), (0x5157, 6624), (0x5159, 5206), (0x515a, 12527), (0x515b, 5207), (0x515c, 7475),
+    (0x515d, 5209), (0x515e, 5208), (0x515f, 13691), (0x5161, 5210), (0x5162, 8878), (0x5163, 5211), (0x5165, 5505), (0x5167, 5583),
+    (0x5168, 5808), (0x5169, 6251), (0x516b, 5506), (0x516c, 5586), (0x516d, 5584), (0x516e, 5585), (0x5171, 5809), (0x5175, 5978),
+    (0x5176, 6253), (0x5177, 6252), (0x5178, 6254), (0x517c, 7031), (0x5180, 9651), (0x5187, 11318), (0x5189, 5672), (0x518a, 5673),
+    (0x518d, 5810), (0x518f, 11503), (0x5191, 6626), (0x5192, 6625), (0x5193, 12529), (0x5194, 12528), (0x5195, 7476), (0x5197, 5587),
+    (0x5198, 11325), (0x519e, 11725), (0x51a0, 6627), (0x51a2, 7034), (0x51a4, 7032), (0x51a5, 7033), (0x51aa, 9652), (0x51ac, 5674),
+    (0x51b0, 5811), (0x51b1, 11394), (0x51b6, 5979), (0x51b7, 5980), (0x51b9, 11504), (0x51bc, 11726), (0x51bd, 6255), (0x51be, 11727),
+    (0x51c4, 12531), (0x51c5, 12532), (0x51c6, 7037), (0x51c8, 12533), (0x51ca, 12530), (0x51cb, 7038), (0x51cc, 7036), (0x51cd, 7035),
+    (0x51ce, 12534), (0x51d0, 13071), (0x51d4, 13692), (0x51d7, 14386), (0x51d8, 15072), (0x51dc, 9262), (0x51dd, 9653), (0x51de, 16322),
+    (0x51e0, 5507), (0x51e1, 5521), (0x51f0, 7477), (0x51f1, 7957), (0x51f3, 8879), (0x51f5, 11306), (0x51f6, 5588), (0x51f8, 5677),
+    (0x51f9, 5675), (0x51fa, 5676), (0x51fd, 6256), (0x5200, 5508), (0x5201, 5509), (0x5203, 5529), (0x5206, 5589), (0x5207, 5590),
+    (0x5208, 5591), (0x5209, 11396), (0x520a, 5678), (0x520c, 11347), (0x520e, 5815), (0x5210, 11397), (0x5211, 5813), (0x5212, 5814),
+    (0x5213, 11395), (0x5216, 5816), (0x5217, 5812), (0x521c, 11505), (0x521d, 6575), (0x521e, 11506), (0x5221, 11507), (0x5224, 5982),
+    (0x5225, 5981), (0x5228, 5985), (0x5229, 5983), (0x522a, 5984), (0x522e, 6262), (0x5230, 6261), (0x5231, 11732), (0x5232, 11729),
+    (0x5233, 11730), (0x5235, 11728), (0x5236, 6263), (0x5237, 6259), (0x5238, 6258), (0x523a, 6260), (0x523b, 6257), (0x5241, 6264),
+    (0x5243, 6629), (0x5244, 12074), (0x5246, 11731), (0x5247, 6634), (0x5249, 12075), (0x524a, 6630), (0x524b, 6633), (0x524c, 6632),
+    (0x524d, 6631), (0x524e, 6628), (0x5252, 12537), (0x5254, 7041), (0x5255, 12540), (0x5256, 7039), (0x525a, 12536), (0x525b, 7042),
+    (0x525c, 7040), (0x525d, 7043), (0x525e, 12538), (0x525f, 12539), (0x5261, 12535), (0x5262, 12541), (0x5269, 7961), (0x526a, 7478),
+    (0x526b, 13072), (0x526c, 13074), (0x526d, 13073), (0x526e, 13075), (0x526f, 7479), (0x5272, 7958), (0x5274, 7959), (0x5275, 7960),
+    (0x5277, 8431), (0x5278, 14388), (0x527a, 14387), (0x527b, 14389), (0x527c, 14390), (0x527d, 8432), (0x527f, 8430), (0x5280, 15073),
+    (0x5281, 15074), (0x5282, 8881), (0x5283, 8880), (0x5284, 15412), (0x5287, 9263), (0x5288, 9264), (0x5289, 9265), (0x528a, 9267),
+    (0x528b, 15687), (0x528c, 15688), (0x528d, 9266), (0x5291, 9654), (0x5293, 9655), (0x5296, 17723), (0x5297, 18309), (0x5298, 18308),
+    (0x5299, 18637), (0x529b, 5510), (0x529f, 5680), (0x52a0, 5679), (0x52a3, 5817), (0x52a6, 11398), (0x52a9, 5987), (0x52aa, 5988),
+    (0x52ab, 5986), (0x52ac, 5989), (0x52ad, 11508), (0x52ae, 11509), (0x52bb, 6266), (0x52bc, 11733), (0x52be, 6265), (0x52c0, 12076),
+    (0x52c1, 6638), (0x52c2, 12077), (0x52c3, 6637), (0x52c7, 6635), (0x52c9, 6636), (0x52cd, 12542), (0x52d2, 7480), (0x52d3, 13077),
+    (0x52d5, 7483), (0x52d6, 13076), (0x52d7, 7667), (0x52d8, 7482), (0x52d9, 7481), (0x52db, 7964), (0x52dd, 7963), (0x52de, 7962),
+    (0x52df, 8433), (0x52e2, 8436), (0x52e3, 8437), (0x52e4, 8435), (0x52e6, 8434), (0x52e9, 15075), (0x52eb, 15076), (0x52ef, 15690),
+    (0x52f0, 9268), (0x52f1, 15689), (0x52f3, 9656), (0x52f4, 16892), (0x52f5, 9948), (0x52f7, 17724), (0x52f8, 10540), (0x52fa, 5530),
+    (0x52fb, 5592), (0x52fc, 11326), (0x52fe, 5593), (0x52ff, 5594), (0x5305, 5681), (0x5306, 5682), (0x5308, 5818), (0x5309, 11510),
+    (0x530a, 11734), (0x530b, 11735), (0x530d, 6639), (0x530e, 12543), (0x530f, 7485), (0x5310, 7484), (0x5311, 13694), (0x5312, 13693),
+    (0x5315, 5511), (0x5316, 5595), (0x5317, 5683), (0x5319, 7486), (0x531a, 11307), (0x531c, 11348), (0x531d, 5684), (0x531f, 11400),
+    (0x5320, 5820), (0x5321, 5819), (0x5322, 11399), (0x5323, 5990), (0x532a, 7044), (0x532d, 13078), (0x532f, 8438), (0x5330, 15077),
+    (0x5331, 8882), (0x5334, 16323), (0x5337, 18053), (0x5339, 5596), (0x533c, 11736), (0x533d, 12078), (0x533e, 7489), (0x533f, 7487),
+    (0x5340, 7488), (0x5341, 5512), (0x5343, 5531), (0x5344, 5288), (0x5345, 5599), (0x5347, 5598), (0x5348, 5597), (0x5349, 5687),
+    (0x534a, 5686), (0x534c, 11349), (0x534d, 11401), (0x5351, 6270), (0x5352, 6267), (0x5353, 6269), (0x5354, 6268), (0x5357, 6640),
+    (0x535a, 7965), (0x535c, 5513), (0x535e, 5600), (0x5360, 5689), (0x5361, 5688), (0x5363, 11511), (0x5366, 6271), (0x536c, 11327),
+    (0x536e, 5691), (0x536f, 5690), (0x5370, 5821), (0x5371, 5822), (0x5372, 11512), (0x5373, 5991), (0x5375, 5992), (0x5377, 6272),
+    (0x5378, 6273), (0x5379, 6274), (0x537b, 6641), (0x537c, 12079), (0x537f, 7045), (0x5382, 11308), (0x5384, 5601), (0x538a, 11402),
+    (0x538e, 11513), (0x538f, 11514), (0x5392, 11737), (0x5394, 11738), (0x5396, 12081), (0x5397, 12080), (0x5398, 12083), (0x5399, 12082),
+    (0x539a, 6642), (0x539c, 13079), (0x539d, 7047), (0x539e, 12544), (0x539f, 7046), (0x53a4, 13695), (0x53a5, 7966), (0x53a7, 13696),
+    (0x53ac, 15078), (0x53ad, 8883), (0x53b2, 9269), (0x53b4, 17725), (0x53b9, 11328), (0x53bb, 5692), (0x53c3, 7490), (0x53c8, 5514),
+    (0x53c9, 5532), (0x53ca, 5603), (0x53cb, 5602), (0x53cd, 5604), (0x53d4, 6276), (0x53d6, 6275), (0x53d7, 6277), (0x53db, 6643),
+    (0x53df, 7048), (0x53e1, 16324), (0x53e2, 10220), (0x53e3, 5533), (0x53e4, 5694), (0x53e5, 5709), (0x53e6, 5704), (0x53e8, 5699),
+    (0x53e9, 5698), (0x53ea, 5705), (0x53eb, 5703), (0x53ec, 5696), (0x53ed, 5710), (0x53ee, 5697), (0x53ef, 5693), (0x53f0, 5708),
+    (0x53f1, 5707), (0x53f2, 5706), (0x53f3, 5695), (0x53f5, 5702), (0x53f8, 5701), (0x53fb, 5711), (0x53fc, 5700), (0x5401, 5828),
+    (0x5403, 5834), (0x5404, 5830), (0x5406, 5836), (0x5407, 11403), (0x5408, 5833), (0x5409, 5823), (0x540a, 5826), (0x540b, 5829),
+    (0x540c, 5825), (0x540d, 5832), (0x540e, 5835), (0x540f, 5824), (0x5410, 5827), (0x5411, 5831), (0x5412, 5837), (0x5418, 11523),
+    (0x5419, 11520), (0x541b, 6005), (0x541c, 11521), (0x541d, 5993), (0x541e, 5995), (0x541f, 6019), (0x5420, 6014), (0x5424, 11528),
+    (0x5425, 11522), (0x5426, 5997), (0x5427, 5999), (0x5428, 11527), (0x5429, 6006), (0x542a, 11517), (0x542b, 6018), (0x542c, 6020),
+    (0x542d, 5994), (0x542e, 6011), (0x5430, 11515), (0x5431, 6017), (0x5433, 6002), (0x5435, 6012), (0x5436, 6013), (0x5437, 11516),
+    (0x5438, 6010), (0x5439, 6008), (0x543b, 6009), (0x543c, 6015), (0x543d, 11524), (0x543e, 5996), (0x5440, 6016), (0x5441, 11526),
+    (0x5442, 6004), (0x5443, 6001), (0x5445, 11519), (0x5446, 6000), (0x5447, 11529), (0x5448, 6003), (0x544a, 6007), (0x544e, 5998),
+    (0x544f, 11525), (0x5454, 11518), (0x5460, 11755), (0x5461, 11754), (0x5462, 6295), (0x5463, 11757), (0x5464, 11759), (0x5465, 11748),
+    (0x5466, 11751), (0x5467, 11758), (0x5468, 6296), (0x546b, 11745), (0x546c, 11749), (0x546f, 11753), (0x5470, 12098), (0x5471, 6291),
+    (0x5472, 12102), (0x5473, 6278), (0x5474, 11750), (0x5475, 6279), (0x5476, 6292), (0x5477, 6285), (0x5478, 6281), (0x547a, 11746),
+    (0x547b, 6284), (0x547c, 6289), (0x547d, 6298), (0x547e, 11747), (0x547f, 11740), (0x5480, 6283), (0x5481, 11741), (0x5482, 11743),
+    (0x5484, 6286), (0x5486, 6288), (0x5487, 11739), (0x5488, 11744), (0x548b, 6297), (0x548c, 6293), (0x548d, 11752), (0x548e, 6299),
+    (0x5490, 6290), (0x5491, 11742), (0x5492, 6287), (0x5495, 6282), (0x5496, 6280), (0x5498, 11756), (0x549a, 6294), (0x54a0, 12097),
+    (0x54a1, 12085), (0x54a2, 12100), (0x54a5, 12087), (0x54a6, 6650), (0x54a7, 6664), (0x54a8, 6646), (0x54a9, 6663), (0x54aa, 6655),
+    (0x54ab, 6660), (0x54ac, 6644), (0x54ad, 12086), (0x54ae, 12092), (0x54af, 6659), (0x54b0, 12104), (0x54b1, 6661), (0x54b3, 6651),
+    (0x54b6, 12094), (0x54b7, 12091), (0x54b8, 6649), (0x54ba, 12084), (0x54bb, 6662), (0x54bc, 12099), (0x54bd, 6654), (0x54be, 12101),
+    (0x54bf, 6665), (0x54c0, 6645), (0x54c1, 6656), (0x54c2, 6653), (0x54c3, 12089), (0x54c4, 6657), (0x54c5, 12095), (0x54c6, 12096),
+    (0x54c7, 6652), (0x54c8, 6658), (0x54c9, 6648), (0x54ce, 6647), (0x54cf, 12088), (0x54d6, 12093), (0x54de, 12103), (0x54e0, 12564),
+    (0x54e1, 7061), (0x54e2, 12546), (0x54e4, 12551), (0x54e5, 7054), (0x54e6, 7065), (0x54e7, 12549), (0x54e8, 7049), (0x54e9, 7059),
+    (0x54ea, 7064), (0x54eb, 12556), (0x54ed, 7060), (0x54ee, 7063), (0x54f1, 12559), (0x54f2, 7055), (0x54f3, 12550), (0x54f7, 12562),
+    (0x54f8, 12563), (0x54fa, 7057), (0x54fb, 12561), (0x54fc, 7053), (0x54fd, 7068), (0x54ff, 12553), (0x5501, 7051), (0x5503, 12566),
+    (0x5504, 12554), (0x5505, 12558), (0x5506, 7056), (0x5507, 7067), (0x5508, 12555), (0x5509, 7062), (0x550a, 12560), (0x550b, 12567),
+    (0x550c, 13094), (0x550e, 12565), (0x550f, 7069), (0x5510, 7050), (0x5511, 12557), (0x5512, 12548), (0x5514, 7058), (0x5517, 12547),
+    (0x551a, 12552), (0x5526, 12545), (0x5527, 7066), (0x552a, 13086), (0x552c, 7509), (0x552d, 13100), (0x552e, 7507), (0x552f, 7504),
+    (0x5530, 13091), (0x5531, 7500), (0x5532, 13095), (0x5533, 7511), (0x5534, 13085), (0x5535, 13090), (0x5536, 13089), (0x5537, 7052),
+    (0x5538, 7506), (0x5539, 13098), (0x553b, 13101), (0x553c, 13082), (0x553e, 7984), (0x5540, 13102), (0x5541, 7512), (0x5543, 7498),
+    (0x5544, 7495), (0x5545, 13093), (0x5546, 7492), (0x5548, 13099), (0x554a, 7499), (0x554b, 13103), (0x554d, 13083), (0x554e, 13097),
+    (0x554f, 7502), (0x5550, 13084), (0x5551, 13087), (0x5552, 13092), (0x5555, 7503), (0x5556, 7501), (0x5557, 7513), (0x555c, 7508),
+    (0x555e, 7496), (0x555f, 7648), (0x5561, 7497), (0x5562, 13088), (0x5563, 7510), (0x5564, 7505), (0x5565, 13096), (0x5566, 7494),
+    (0x556a, 7493), (0x5575, 13080), (0x5576, 13081), (0x5577, 13701), (0x557b, 7967), (0x557c, 7970), (0x557d, 13712), (0x557e, 7990),
+    (0x557f, 13715), (0x5580, 7968), (0x5581, 13708), (0x5582, 7974), (0x5583, 7980), (0x5584, 8255), (0x5587, 7978), (0x5588, 13705),
+    (0x5589, 7991), (0x558a, 7971), (0x558b, 7979), (0x558c, 13713), (0x558d, 14404), (0x558e, 13718), (0x558f, 13706), (0x5591, 13697),
+    (0x5592, 13710), (0x5593, 13704), (0x5594, 7977), (0x5595, 13716), (0x5598, 7973), (0x5599, 7993), (0x559a, 7986), (0x559c, 7975),
+    (0x559d, 7972), (0x559f, 7983), (0x55a1, 13717), (0x55a2, 13703), (0x55a3, 13709), (0x55a4, 13711), (0x55a5, 13699), (0x55a6, 13714),
+    (0x55a7, 7969), (0x55a8, 13698), (0x55aa, 7976), (0x55ab, 7992), (0x55ac, 7988), (0x55ad, 13700), (0x55ae, 7982), (0x55b1, 7989),
+    (0x55b2, 7985), (0x55b3, 7981), (0x55b5, 13707), (0x55bb, 7987), (0x55bf, 14402), (0x55c0, 14398), (0x55c2, 14413), (0x55c3, 14391),
+    (0x55c4, 14400), (0x55c5, 8452), (0x55c6, 8453), (0x55c7, 8445), (0x55c8, 14409), (0x55c9, 8455), (0x55ca, 14396), (0x55cb, 14395),
+    (0x55cc, 14393), (0x55cd, 14411), (0x55ce, 8443), (0x55cf, 14405), (0x55d0, 14394), (0x55d1, 8446), (0x55d2, 14403), (0x55d3, 8441),
+    (0x55d4, 14399), (0x55d5, 14406), (0x55d6, 14408), (0x55d9, 14412), (0x55da, 8450), (0x55db, 14392), (0x55dc, 8444), (0x55dd, 14397),
+    (0x55df, 8439), (0x55e1, 8451), (0x55e2, 14407), (0x55e3, 8447), (0x55e4, 8448), (0x55e5, 8454), (0x55e6, 8442), (0x55e7, 5212),
+    (0x55e8, 8440), (0x55e9, 14401), (0x55ef, 8449), (0x55f2, 14410), (0x55f6, 8899), (0x55f7, 8894), (0x55f9, 15093), (0x55fa, 15089),
+    (0x55fc, 15083), (0x55fd, 8888), (0x55fe, 8884), (0x55ff, 15092), (0x5600, 8885), (0x5601, 15086), (0x5602, 15088), (0x5604, 15091),
+    (0x5606, 8890), (0x5608, 8897), (0x5609, 8891), (0x560c, 15081), (0x560d, 8892), (0x560e, 8893), (0x560f, 15084), (0x5610, 8898),
+    (0x5612, 15082), (0x5613, 15087), (0x5614, 8889), (0x5615, 15080), (0x5616, 8895), (0x5617, 8887), (0x561b, 8886), (0x561c, 15085),
+    (0x561d, 15090), (0x561f, 8896), (0x5627, 15079), (0x5629, 9276), (0x562a, 15707), (0x562c, 15704), (0x562e, 9270), (0x562f, 9282),
+    (0x5630, 9283), (0x5632, 9273), (0x5633, 15702), (0x5634, 9275), (0x5635, 15694), (0x5636, 9281), (0x5638, 15706), (0x5639, 9272),
+    (0x563a, 15708), (0x563b, 9271), (0x563d, 15703), (0x563e, 15705), (0x563f, 9274), (0x5640, 15701), (0x5641, 15695), (0x5642, 15692),
+    (0x5645, 13702), (0x5646, 15698), (0x5648, 15691), (0x5649, 15697), (0x564a, 15696), (0x564c, 15693), (0x564e, 9278), (0x5653, 9277),
+    (0x5657, 9279), (0x5658, 15699), (0x5659, 9657), (0x565a, 15700), (0x565e, 16333), (0x5660, 16326), (0x5662, 9669), (0x5663, 16330),
+    (0x5664, 9661), (0x5665, 9665), (0x5666, 16329), (0x5668, 9664), (0x5669, 9660), (0x566a, 9663), (0x566b, 9658), (0x566c, 9668),
+    (0x566d, 16331), (0x566e, 16327), (0x566f, 9667), (0x5670, 16325), (0x5671, 9666), (0x5672, 16332), (0x5673, 16328), (0x5674, 9280),
+    (0x5676, 9670), (0x5677, 16334), (0x5678, 9662), (0x5679, 9659), (0x567e, 16899), (0x567f, 16901), (0x5680, 9950), (0x5681, 16902),
+    (0x5682, 16900), (0x5683, 16898), (0x5684, 16897), (0x5685, 9952), (0x5686, 16896), (0x5687, 9953), (0x568c, 16894), (0x568d, 16895),
+    (0x568e, 9949), (0x568f, 9954), (0x5690, 9951), (0x5693, 16893), (0x5695, 10221), (0x5697, 17360), (0x5698, 17358), (0x5699, 17363),
+    (0x569a, 17361), (0x569c, 17359), (0x569d, 17362), (0x56a5, 10394), (0x56a6, 17728), (0x56a7, 17729), (0x56a8, 10541), (0x56aa, 17730),
+    (0x56ab, 17726), (0x56ac, 17731), (0x56ad, 17727), (0x56ae, 10222), (0x56b2, 18054), (0x56b3, 18056), (0x56b4, 10544), (0x56b5, 18055),
+    (0x56b6, 10543), (0x56b7, 10542), (0x56bc, 10545), (0x56bd, 18311), (0x56be, 18312), (0x56c0, 10639), (0x56c1, 10638), (0x56c2, 10640),
+    (0x56c3, 18310), (0x56c5, 18487), (0x56c6, 18486), (0x56c8, 10721), (0x56c9, 10723), (0x56ca, 10722), (0x56cb, 18488), (0x56cc, 10778),
+    (0x56cd, 18751), (0x56d1, 10819), (0x56d3, 18750), (0x56d4, 18823), (0x56d7, 11313), (0x56da, 5713), (0x56db, 5712), (0x56dd, 5840),
+    (0x56de, 5839), (0x56df, 11405), (0x56e0, 5838), (0x56e1, 11404), (0x56e4, 6023), (0x56e5, 11532), (0x56e7, 11531), (0x56ea, 6021),
+    (0x56eb, 6024), (0x56ee, 11530), (0x56f0, 6022), (0x56f7, 11760), (0x56f9, 11761), (0x56fa, 6300), (0x56ff, 6666), (0x5701, 12568),
+    (0x5702, 12569), (0x5703, 7070), (0x5704, 7071), (0x5707, 13105), (0x5708, 7514), (0x5709, 7516), (0x570a, 13104), (0x570b, 7515),
+    (0x570c, 13719), (0x570d, 7994), (0x5712, 8456), (0x5713, 8457), (0x5714, 14414), (0x5716, 8901), (0x5718, 8900), (0x571a, 15709),
+    (0x571b, 16336), (0x571c, 16335), (0x571e, 18883), (0x571f, 5534), (0x5720, 11329), (0x5722, 11350), (0x5723, 11351), (0x5728, 5843),
+    (0x5729, 5847), (0x572a, 11407), (0x572c, 5845), (0x572d, 5844), (0x572e, 11406), (0x572f, 5846), (0x5730, 5842), (0x5733, 5841),
+    (0x5734, 11408), (0x573b, 6034), (0x573e, 6031), (0x5740, 6027), (0x5741, 11533), (0x5745, 11534), (0x5747, 6029), (0x5749, 11536),
+    (0x574a, 6025), (0x574b, 11537), (0x574c, 11535), (0x574d, 6028), (0x574e, 6030), (0x574f, 6033), (0x5750, 6032), (0x5751, 6026),
+    (0x5752, 11538), (0x5761, 6305), (0x5762, 11774), (0x5764, 6307), (0x5766, 6306), (0x5768, 11775), (0x5769, 6304), (0x576a, 6303),
+    (0x576b, 11765), (0x576d, 11764), (0x576f, 11762), (0x5770, 11767), (0x5771, 11766), (0x5772, 11763), (0x5773, 11772), (0x5774, 11773),
+    (0x5775, 11770), (0x5776, 11768), (0x5777, 6302), (0x577b, 11771), (0x577c, 6308), (0x577d, 11776), (0x5780, 11769), (0x5782, 6667),
+    (0x5783, 6301), (0x578b, 6668), (0x578c, 12109), (0x578f, 12115), (0x5793, 6674), (0x5794, 12113), (0x5795, 12119), (0x5797, 12110),
+    (0x5798, 12114), (0x5799, 12116), (0x579a, 12118), (0x579b, 12112), (0x579d, 12111), (0x579e, 12106), (0x579f, 12107), (0x57a0, 6669),
+    (0x57a2, 6671), (0x57a3, 6670), (0x57a4, 12108), (0x57a5, 12117), (0x57ae, 6673), (0x57b5, 12105), (0x57b6, 12579), (0x57b8, 12578),
+    (0x57b9, 12583), (0x57ba, 12574), (0x57bc, 12577), (0x57bd, 12576), (0x57bf, 12580), (0x57c1, 12584), (0x57c2, 7072), (0x57c3, 7075),
+    (0x57c6, 12575), (0x57c7, 12581), (0x57cb, 7074), (0x57cc, 12570), (0x57ce, 6672), (0x57cf, 13119), (0x57d0, 12582), (0x57d2, 12573),
+    (0x57d4, 7073), (0x57d5, 12572), (0x57dc, 13110), (0x57df, 7517), (0x57e0, 7521), (0x57e1, 13126), (0x57e2, 13108), (0x57e3, 13122),
+    (0x57e4, 7522), (0x57e5, 13124), (0x57e7, 13130), (0x57e9, 13134), (0x57ec, 13125), (0x57ed, 13113), (0x57ee, 13121), (0x57f0, 13135),
+    (0x57f1, 13133), (0x57f2, 13123), (0x57f3, 13118), (0x57f4, 13111), (0x57f5, 13727), (0x57f6, 13109), (0x57f7, 7526), (0x57f8, 13116),
+    (0x57f9, 7527), (0x57fa, 7523), (0x57fb, 13106), (0x57fc, 13128), (0x57fd, 13114), (0x5800, 13112), (0x5801, 13131), (0x5802, 7524),
+    (0x5804, 13137), (0x5805, 7518), (0x5806, 7520), (0x5807, 13120), (0x5808, 13115), (0x5809, 7076), (0x580a, 7519), (0x580b, 13117),
+    (0x580c, 13132), (0x580d, 13136), (0x580e, 13127), (0x5810, 13129), (0x5814, 13107), (0x5819, 13722), (0x581b, 13731), (0x581c, 13730),
+    (0x581d, 8002), (0x581e, 13723), (0x5820, 8003), (0x5821, 8001), (0x5823, 13725), (0x5824, 7998), (0x5825, 13729), (0x5827, 13724),
+    (0x5828, 13726), (0x5829, 13720), (0x582a, 7996), (0x582c, 13739), (0x582d, 13738), (0x582e, 13735), (0x582f, 7995), (0x5830, 7999),
+    (0x5831, 8000), (0x5832, 12571), (0x5833, 13732), (0x5834, 7997), (0x5835, 7525), (0x5836, 13734), (0x5837, 13721), (0x5838, 13737),
+    (0x5839, 13736), (0x583b, 13740), (0x583d, 14428), (0x583f, 13733), (0x5848, 13728), (0x5849, 14420), (0x584a, 8467), (0x584b, 8470),
+    (0x584c, 8465), (0x584d, 14419), (0x584e, 14423), (0x584f, 14418), (0x5851, 8459), (0x5852, 8469), (0x5853, 14415), (0x5854, 8463),
+    (0x5855, 14422), (0x5857, 8461), (0x5858, 8460), (0x5859, 14425), (0x585a, 8462), (0x585b, 14427), (0x585d, 14424), (0x585e, 8458),
+    (0x5862, 8468), (0x5863, 14429), (0x5864, 14417), (0x5865, 14426), (0x5868, 14416), (0x586b, 8464), (0x586d, 8466), (0x586f, 14421),
+    (0x5871, 14430), (0x5874, 15101), (0x5875, 8902), (0x5876, 15107), (0x5879, 8907), (0x587a, 15103), (0x587b, 15110), (0x587c, 15095),
+    (0x587d, 8909), (0x587e, 8903), (0x587f, 15100), (0x5880, 9284), (0x5881, 15099), (0x5882, 15108), (0x5883, 8904), (0x5885, 8908),
+    (0x5886, 15098), (0x5887, 15104), (0x5888, 15109), (0x5889, 15094), (0x588a, 8906), (0x588b, 15102), (0x588e, 15106), (0x588f, 15112),
+    (0x5890, 15096), (0x5891, 15105), (0x5893, 8905), (0x5894, 15111), (0x5898, 15097), (0x589c, 9288), (0x589d, 15711), (0x589e, 9286),
+    (0x589f, 9285), (0x58a0, 15713), (0x58a1, 15718), (0x58a3, 15714), (0x58a5, 15717), (0x58a6, 9291), (0x58a8, 9644), (0x58a9, 9290),
+    (0x58ab, 15710), (0x58ac, 15716), (0x58ae, 9289), (0x58af, 15715), (0x58b1, 15712), (0x58b3, 9287), (0x58ba, 16341), (0x58bc, 16343),
+    (0x58bd, 16338), (0x58be, 9672), (0x58bf, 16340), (0x58c1, 9671), (0x58c2, 16342), (0x58c5, 9674), (0x58c6, 16344), (0x58c7, 9673),
+    (0x58c8, 16337), (0x58c9, 16339), (0x58ce, 9958), (0x58cf, 16905), (0x58d1, 9957), (0x58d2, 16906), (0x58d3, 9956), (0x58d4, 16904),
+    (0x58d5, 9955), (0x58d6, 16903), (0x58d8, 10224), (0x58d9, 10223), (0x58da, 17732), (0x58db, 17734), (0x58dd, 17733), (0x58de, 10395),
+    (0x58df, 10396), (0x58e2, 10397), (0x58e3, 18057), (0x58e4, 10546), (0x58e7, 18639), (0x58e8, 18638), (0x58e9, 10820), (0x58eb, 5535),
+    (0x58ec, 5605), (0x58ef, 6035), (0x58f4, 12120), (0x58f9, 8004), (0x58fa, 8005), (0x58fc, 14431), (0x58fd, 8910), (0x58fe, 15113),
+    (0x58ff, 15719), (0x5903, 11330), (0x5906, 11539), (0x590c, 11777), (0x590d, 12121), (0x590e, 12585), (0x590f, 7077), (0x5912, 17735),
+    (0x5914, 10641), (0x5915, 5536), (0x5916, 5714), (0x5917, 11352), (0x5919, 5848), (0x591a, 5849), (0x591c, 6309), (0x5920, 7528),
+    (0x5922, 8912), (0x5924, 8913), (0x5925, 8911), (0x5927, 5537), (0x5929, 5606), (0x592a, 5608), (0x592b, 5607), (0x592c, 11331),
+    (0x592d, 5609), (0x592e, 5715), (0x592f, 11353), (0x5931, 5716), (0x5937, 5850), (0x5938, 5851), (0x593c, 11409), (0x593e, 6036),
+    (0x5940, 11540), (0x5944, 6313), (0x5945, 11778), (0x5947, 6311), (0x5948, 6312), (0x5949, 6310), (0x594a, 12586), (0x594e, 6678),
+    (0x594f, 6677), (0x5950, 6679), (0x5951, 6676), (0x5953, 12122), (0x5954, 6314), (0x5955, 6675), (0x5957, 7078), (0x5958, 7079),
+    (0x595a, 7080), (0x595c, 13138), (0x5960, 8006), (0x5961, 13741), (0x5962, 7529), (0x5967, 8471), (0x5969, 8915), (0x596a, 8914),
+    (0x596b, 15114), (0x596d, 9292), (0x596e, 9675), (0x5970, 17364), (0x5971, 18489), (0x5972, 18640), (0x5973, 5538), (0x5974, 5717),
+    (0x5976, 5718), (0x5977, 11415), (0x5978, 5853), (0x5979, 5856), (0x597b, 11413), (0x597c, 11411), (0x597d, 5855), (0x597e, 11414),
+    (0x597f, 11416), (0x5980, 11410), (0x5981, 5858), (0x5982, 5857), (0x5983, 5854), (0x5984, 5852), (0x5985, 11412), (0x598a, 6047),
+    (0x598d, 6044), (0x598e, 11545), (0x598f, 11548), (0x5990, 11547), (0x5992, 6038), (0x5993, 6046), (0x5996, 6043), (0x5997, 11544),
+    (0x5998, 11542), (0x5999, 6042), (0x599d, 6037), (0x599e, 6040), (0x59a0, 11543), (0x59a1, 11550), (0x59a2, 11546), (0x59a3, 6041),
+    (0x59a4, 6045), (0x59a5, 6048), (0x59a6, 11541), (0x59a7, 11549), (0x59a8, 6039), (0x59ae, 6319), (0x59af, 6327), (0x59b1, 11790),
+    (0x59b2, 11783), (0x59b3, 6328), (0x59b4, 11794), (0x59b5, 11779), (0x59b6, 11786), (0x59b9, 6318), (0x59ba, 11780), (0x59bb, 6316),
+    (0x59bc, 11787), (0x59bd, 11791), (0x59be, 6315), (0x59c0, 11792), (0x59c1, 11785), (0x59c3, 11788), (0x59c5, 6330), (0x59c6, 6321),
+    (0x59c7, 11795), (0x59c8, 11793), (0x59ca, 6326), (0x59cb, 6324), (0x59cc, 11784), (0x59cd, 6323), (0x59ce, 11782), (0x59cf, 11781),
+    (0x59d0, 6322), (0x59d1, 6320), (0x59d2, 6329), (0x59d3, 6325), (0x59d4, 6317), (0x59d6, 11789), (0x59d8, 6681), (0x59da, 6688),
+    (0x59db, 12136), (0x59dc, 6680), (0x59dd, 12128), (0x59de, 12124), (0x59e0, 12140), (0x59e1, 12123), (0x59e3, 6683), (0x59e4, 12133),
+    (0x59e5, 6686), (0x59e6, 6689), (0x59e8, 6684), (0x59e9, 12137), (0x59ea, 6687), (0x59ec, 7087), (0x59ed, 12143), (0x59ee, 12125),
+    (0x59f1, 12127), (0x59f2, 12134), (0x59f3, 12138), (0x59f4, 12142), (0x59f5, 12139), (0x59f6, 12132), (0x59f7, 12135), (0x59fa, 12129),
+    (0x59fb, 6691), (0x59fc, 12131), (0x59fd, 12130), (0x59fe, 12141), (0x59ff, 6682), (0x5a00, 12126), (0x5a01, 6690), (0x5a03, 6685),
+    (0x5a09, 7093), (0x5a0a, 12594), (0x5a0c, 7092), (0x5a0f, 12592), (0x5a11, 7081), (0x5a13, 7086), (0x5a15, 12591), (0x5a16, 12588),
+    (0x5a17, 12593), (0x5a18, 7082), (0x5a19, 12587), (0x5a1b, 7085), (0x5a1c, 7083), (0x5a1e, 12595), (0x5a1f, 7084), (0x5a20, 7088),
+    (0x5a23, 7089), (0x5a25, 7091), (0x5a29, 7090), (0x5a2d, 12589), (0x5a2e, 12590), (0x5a33, 12596), (0x5a35, 13145), (0x5a36, 7530),
+    (0x5a37, 13755), (0x5a38, 13144), (0x5a39, 13163), (0x5a3c, 7536), (0x5a3e, 13161), (0x5a40, 7535), (0x5a41, 7531), (0x5a42, 13170),
+    (0x5a43, 13154), (0x5a44, 13157), (0x5a46, 7539), (0x5a47, 13167), (0x5a48, 13159), (0x5a49, 7532), (0x5a4a, 7540), (0x5a4c, 13164),
+    (0x5a4d, 13162), (0x5a50, 13147), (0x5a51, 13168), (0x5a52, 13156), (0x5a53, 13151), (0x5a55, 13141), (0x5a56, 13169), (0x5a57, 13153),
+    (0x5a58, 13140), (0x5a5a, 7538), (0x5a5b, 13158), (0x5a5c, 13171), (0x5a5d, 13155), (0x5a5e, 13143), (0x5a5f, 13148), (0x5a60, 13139),
+    (0x5a62, 7537), (0x5a64, 13152), (0x5a65, 13149), (0x5a66, 7533), (0x5a67, 13142), (0x5a69, 13166), (0x5a6a, 7534), (0x5a6c, 13150),
+    (0x5a6d, 13146), (0x5a70, 13165), (0x5a77, 8007), (0x5a78, 13748), (0x5a7a, 13745), (0x5a7b, 13762), (0x5a7c, 13750), (0x5a7d, 13763),
+    (0x5a7f, 8009), (0x5a83, 13759), (0x5a84, 13756), (0x5a8a, 13757), (0x5a8b, 13760), (0x5a8c, 13764), (0x5a8e, 13160), (0x5a8f, 13766),
+    (0x5a90, 14451), (0x5a92, 8010), (0x5a93, 13767), (0x5a94, 13743), (0x5a95, 13753), (0x5a97, 13758), (0x5a9a, 8008), (0x5a9b, 8011),
+    (0x5a9c, 13765), (0x5a9d, 13768), (0x5a9e, 13747), (0x5a9f, 13744), (0x5aa2, 13746), (0x5aa5, 13751), (0x5aa6, 13749), (0x5aa7, 8012),
+    (0x5aa9, 13761), (0x5aac, 13752), (0x5aae, 13754), (0x5aaf, 13742), (0x5ab0, 14439), (0x5ab1, 14437), (0x5ab2, 8480), (0x5ab3, 8478),
+    (0x5ab4, 14447), (0x5ab5, 14438), (0x5ab6, 14448), (0x5ab7, 14444), (0x5ab8, 14436), (0x5ab9, 14450), (0x5aba, 14435), (0x5abb, 14442),
+    (0x5abc, 8477), (0x5abd, 8476), (0x5abe, 8475), (0x5abf, 14440), (0x5ac0, 14445), (0x5ac1, 8472), (0x5ac2, 8479), (0x5ac4, 14433),
+    (0x5ac6, 14443), (0x5ac7, 14432), (0x5ac8, 14441), (0x5ac9, 8473), (0x5aca, 14446), (0x5acb, 14434), (0x5acc, 8474), (0x5acd, 14449),
+    (0x5ad5, 15118), (0x5ad6, 8920), (0x5ad7, 8919), (0x5ad8, 8921), (0x5ad9, 15130), (0x5ada, 15120), (0x5adb, 15126), (0x5adc, 15115),
+    (0x5add, 15129), (0x5ade, 15128), (0x5adf, 15132), (0x5ae0, 15125), (0x5ae1, 8916), (0x5ae2, 15124), (0x5ae3, 8922), (0x5ae5, 15117),
+    (0x5ae6, 8917), (0x5ae8, 15131), (0x5ae9, 8918), (0x5aea, 15119), (0x5aeb, 15122), (0x5aec, 15127), (0x5aed, 15121), (0x5aee, 15116),
+    (0x5af3, 15123), (0x5af4, 15721), (0x5af5, 9296), (0x5af6, 15724), (0x5af7, 15723), (0x5af8, 15726), (0x5af9, 15728), (0x5afb, 9294),
+    (0x5afd, 15722), (0x5aff, 15720), (0x5b01, 15729), (0x5b02, 15727), (0x5b03, 15725), (0x5b05, 15731), (0x5b07, 15730), (0x5b08, 9298),
+    (0x5b09, 9293), (0x5b0b, 9295), (0x5b0c, 9297), (0x5b0f, 15732), (0x5b10, 16351), (0x5b13, 16350), (0x5b14, 16349), (0x5b16, 16352),
+    (0x5b17, 16345), (0x5b19, 16346), (0x5b1a, 16354), (0x5b1b, 16347), (0x5b1d, 9676), (0x5b1e, 16356), (0x5b20, 16355), (0x5b21, 16348),
+    (0x5b23, 16910), (0x5b24, 9961), (0x5b25, 16908), (0x5b26, 16913), (0x5b27, 16912), (0x5b28, 16353), (0x5b2a, 9960), (0x5b2c, 16911),
+    (0x5b2d, 16907), (0x5b2e, 16915), (0x5b2f, 16914), (0x5b30, 9959), (0x5b32, 16909), (0x5b34, 9677), (0x5b38, 10225), (0x5b3c, 17365),
+    (0x5b3d, 17736), (0x5b3e, 17737), (0x5b3f, 17738), (0x5b40, 10547), (0x5b43, 10548), (0x5b45, 18058), (0x5b47, 18314), (0x5b48, 18313),
+    (0x5b4b, 18490), (0x5b4c, 18491), (0x5b4d, 18641), (0x5b4e, 18752), (0x5b50, 5539), (0x5b51, 5540), (0x5b53, 5541), (0x5b54, 5610),
+    (0x5b55, 5719), (0x5b56, 11417), (0x5b57, 5859), (0x5b58, 5860), (0x5b5a, 6051), (0x5b5b, 6052), (0x5b5c, 6050), (0x5b5d, 6049),
+    (0x5b5f, 6331), (0x5b62, 11796), (0x5b63, 6333), (0x5b64, 6332), (0x5b65, 11797), (0x5b69, 6692), (0x5b6b, 7094), (0x5b6c, 12597),
+    (0x5b6e, 13173), (0x5b70, 7541), (0x5b71, 8014), (0x5b72, 13172), (0x5b73, 8013), (0x5b75, 8923), (0x5b77, 15133), (0x5b78, 9678),
+    (0x5b7a, 9962), (0x5b7b, 16916), (0x5b7d, 10549), (0x5b7f, 10724), (0x5b81, 11354), (0x5b83, 5720), (0x5b84, 11355), (0x5b85, 5863),
+    (0x5b87, 5861), (0x5b88, 5862), (0x5b89, 5864), (0x5b8b, 6054), (0x5b8c, 6053), (0x5b8e, 11551), (0x5b8f, 6055), (0x5b92, 11552),
+    (0x5b93, 11798), (0x5b95, 11799), (0x5b97, 6334), (0x5b98, 6336), (0x5b99, 6338), (0x5b9a, 6335), (0x5b9b, 6339), (0x5b9c, 6337),
+    (0x5ba2, 6696), (0x5ba3, 6693), (0x5ba4, 6695), (0x5ba5, 6697), (0x5ba6, 6694), (0x5ba7, 12598), (0x5ba8, 12144), (0x5bac, 12600),
+    (0x5bad, 12599), (0x5bae, 7100), (0x5bb0, 7096), (0x5bb3, 7097), (0x5bb4, 7099), (0x5bb5, 7101), (0x5bb6, 7098), (0x5bb8, 7103),
+    (0x5bb9, 7102), (0x5bbf, 7546), (0x5bc0, 13175), (0x5bc1, 13174), (0x5bc2, 7545), (0x5bc4, 7544), (0x5bc5, 7543), (0x5bc6, 7547),
+    (0x5bc7, 7542), (0x5bca, 13774), (0x5bcb, 13771), (0x5bcc, 8016), (0x5bcd, 13770), (0x5bce, 13775), (0x5bd0, 8018), (0x5bd1, 13773),
+    (0x5bd2, 8015), (0x5bd3, 8017), (0x5bd4, 13772), (0x5bd6, 14452), (0x5bd8, 14453), (0x5bd9, 14454), (0x5bde, 8924), (0x5bdf, 8932),
+    (0x5be0, 15134), (0x5be1, 8926), (0x5be2, 8930), (0x5be3, 15135), (0x5be4, 8931), (0x5be5, 8927), (0x5be6, 8928), (0x5be7, 8925),
+    (0x5be8, 8929), (0x5be9, 9301), (0x5bea, 13769), (0x5beb, 9302), (0x5bec, 9300), (0x5bee, 9299), (0x5bef, 16357), (0x5bf0, 9679),
+    (0x5bf1, 16917), (0x5bf2, 16918), (0x5bf5, 10398), (0x5bf6, 10550), (0x5bf8, 5542), (0x5bfa, 5865), (0x5c01, 6698), (0x5c03, 12601),
+    (0x5c04, 7104), (0x5c07, 7550), (0x5c08, 7549), (0x5c09, 7548), (0x5c0a, 8019), (0x5c0b, 8020), (0x5c0c, 13776), (0x5c0d, 8933),
+    (0x5c0e, 9680), (0x5c0f, 5543), (0x5c10, 11332), (0x5c11, 5611), (0x5c12, 11356), (0x5c15, 11418), (0x5c16, 5866), (0x5c1a, 6340),
+    (0x5c1f, 14455), (0x5c22, 5544), (0x5c24, 5612), (0x5c25, 11419), (0x5c28, 11553), (0x5c2a, 11554), (0x5c2c, 6056), (0x5c30, 13777),
+    (0x5c31, 8021), (0x5c33, 14456), (0x5c37, 9963), (0x5c38, 5545), (0x5c39, 5565), (0x5c3a, 5613), (0x5c3b, 11357), (0x5c3c, 5721),
+    (0x5c3e, 6060), (0x5c3f, 6059), (0x5c40, 6057), (0x5c41, 6058), (0x5c44, 11800), (0x5c45, 6342), (0x5c46, 6343), (0x5c47, 11801),
+    (0x5c48, 6341), (0x5c4b, 6702), (0x5c4c, 12145), (0x5c4d, 6701), (0x5c4e, 6699), (0x5c4f, 6700), (0x5c50, 7107), (0x5c51, 7105),
+    (0x5c54, 12603), (0x5c55, 7106), (0x5c56, 12602), (0x5c58, 7095), (0x5c59, 13176), (0x5c5c, 7552), (0x5c5d, 7553), (0x5c60, 7551),
+    (0x5c62, 8934), (0x5c63, 15136), (0x5c64, 9303), (0x5c65, 9304), (0x5c67, 15733), (0x5c68, 9964), (0x5c69, 17366), (0x5c6a, 17367),
+    (0x5c6c, 10642), (0x5c6d, 18753), (0x5c6e, 11315), (0x5c6f, 5614), (0x5c71, 5546), (0x5c73, 11359), (0x5c74, 11358), (0x5c79, 5867),
+    (0x5c7a, 11421), (0x5c7b, 11422), (0x5c7c, 11420), (0x5c7e, 11423), (0x5c86, 11562), (0x5c88, 11557), (0x5c89, 11559), (0x5c8a, 11561),
+    (0x5c8b, 11558), (0x5c8c, 6064), (0x5c8d, 11555), (0x5c8f, 11556), (0x5c90, 6061), (0x5c91, 6062), (0x5c92, 11560), (0x5c93, 11563),
+    (0x5c94, 6063), (0x5c95, 11564), (0x5c9d, 11815), (0x5c9f, 11809), (0x5ca0, 11804), (0x5ca1, 6345), (0x5ca2, 11812), (0x5ca3, 11810),
+    (0x5ca4, 11803), (0x5ca5, 11816), (0x5ca6, 11819), (0x5ca7, 11814), (0x5ca8, 11807), (0x5ca9, 6347), (0x5caa, 11813), (0x5cab, 6348),
+    (0x5cac, 11808), (0x5cad, 11811), (0x5cae, 11802), (0x5caf, 11806), (0x5cb0, 11818), (0x5cb1, 6349), (0x5cb3, 6350), (0x5cb5, 11805),
+    (0x5cb6, 11817), (0x5cb7, 6344), (0x5cb8, 6346), (0x5cc6, 12162), (0x5cc7, 12155), (0x5cc8, 12161), (0x5cc9, 12154), (0x5cca, 12156),
+    (0x5ccb, 12150), (0x5ccc, 12148), (0x5cce, 12163), (0x5ccf, 12160), (0x5cd0, 12146), (0x5cd2, 6704), (0x5cd3, 12158), (0x5cd4, 12159),
+    (0x5cd6, 12157), (0x5cd7, 12149), (0x5cd8, 12147), (0x5cd9, 6703), (0x5cda, 12153), (0x5cdb, 12151), (0x5cde, 12152), (0x5cdf, 12164),
+    (0x5ce8, 7112), (0x5cea, 7111), (0x5cec, 12604), (0x5ced, 7108), (0x5cee, 12606), (0x5cf0, 7113), (0x5cf1, 12607), (0x5cf4, 7116),
+    (0x5cf6, 7114), (0x5cf7, 12608), (0x5cf8, 12165), (0x5cf9, 12610), (0x5cfb, 7110), (0x5cfd, 7109), (0x5cff, 12605), (0x5d00, 12609),
+    (0x5d01, 7115), (0x5d06, 7555), (0x5d07, 7554), (0x5d0b, 13178), (0x5d0c, 13182), (0x5d0d, 13184), (0x5d0e, 7556), (0x5d0f, 13187),
+    (0x5d11, 7560), (0x5d12, 13189), (0x5d14, 7562), (0x5d16, 7558), (0x5d17, 7566), (0x5d19, 7563), (0x5d1a, 13180), (0x5d1b, 7557),
+    (0x5d1d, 13179), (0x5d1e, 13177), (0x5d1f, 13191), (0x5d20, 13181), (0x5d22, 7559), (0x5d23, 13190), (0x5d24, 7564), (0x5d25, 13186),
+    (0x5d26, 13185), (0x5d27, 7565), (0x5d28, 13183), (0x5d29, 7561), (0x5d2e, 13192), (0x5d30, 13188), (0x5d31, 13792), (0x5d32, 13799),
+    (0x5d33, 13788), (0x5d34, 8024), (0x5d35, 13784), (0x5d36, 13800), (0x5d37, 13778), (0x5d38, 13797), (0x5d39, 13795), (0x5d3a, 13789),
+    (0x5d3c, 13798), (0x5d3d, 13791), (0x5d3f, 13783), (0x5d40, 13801), (0x5d41, 13781), (0x5d42, 13794), (0x5d43, 13779), (0x5d45, 13802),
+    (0x5d47, 8025), (0x5d49, 13796), (0x5d4a, 14459), (0x5d4b, 13782), (0x5d4c, 8022), (0x5d4e, 13786), (0x5d50, 8023), (0x5d51, 13785),
+    (0x5d52, 13790), (0x5d55, 13787), (0x5d59, 13793), (0x5d5e, 14463), (0x5d62, 14466), (0x5d63, 14458), (0x5d65, 14460), (0x5d67, 14465),
+    (0x5d68, 14464), (0x5d69, 8481), (0x5d6b, 13780), (0x5d6c, 14462), (0x5d6f, 8482), (0x5d71, 14457), (0x5d72, 14461), (0x5d77, 15143),
+    (0x5d79, 15150), (0x5d7a, 15141), (0x5d7c, 15148), (0x5d7d, 15139), (0x5d7e, 15147), (0x5d7f, 15151), (0x5d80, 15138), (0x5d81, 15142),
+    (0x5d82, 15137), (0x5d84, 8935), (0x5d86, 15140), (0x5d87, 8936), (0x5d88, 15146), (0x5d89, 15145), (0x5d8a, 15144), (0x5d8d, 15149),
+    (0x5d92, 15737), (0x5d93, 15739), (0x5d94, 9306), (0x5d95, 15740), (0x5d97, 15735), (0x5d99, 15734), (0x5d9a, 15744), (0x5d9c, 15742),
+    (0x5d9d, 9305), (0x5d9e, 15745), (0x5d9f, 15736), (0x5da0, 15741), (0x5da1, 15743), (0x5da2, 15738), (0x5da7, 16361), (0x5da8, 16366),
+    (0x5da9, 16360), (0x5daa, 16365), (0x5dac, 16358), (0x5dad, 16368), (0x5dae, 16364), (0x5daf, 16369), (0x5db0, 16363), (0x5db1, 16359),
+    (0x5db2, 16367), (0x5db4, 16370), (0x5db5, 16362), (0x5db7, 16919), (0x5db8, 9968), (0x5dba, 9966), (0x5dbc, 9965), (0x5dbd, 9967),
+    (0x5dc0, 17368), (0x5dc2, 17634), (0x5dc3, 17739), (0x5dc6, 18059), (0x5dc7, 18060), (0x5dc9, 10551), (0x5dcb, 18315), (0x5dcd, 10643),
+    (0x5dcf, 18316), (0x5dd1, 18493), (0x5dd2, 10726), (0x5dd4, 10725), (0x5dd5, 18492), (0x5dd6, 10779), (0x5dd8, 18642), (0x5ddd, 5547),
+    (0x5dde, 5868), (0x5ddf, 11424), (0x5de0, 11565), (0x5de1, 6206), (0x5de2, 7567), (0x5de5, 5548), (0x5de6, 5724), (0x5de7, 5723),
+    (0x5de8, 5722), (0x5deb, 6065), (0x5dee, 7117), (0x5df0, 14467), (0x5df1, 5549), (0x5df2, 5550), (0x5df3, 5551), (0x5df4, 5615),
+    (0x5df7, 6705), (0x5df9, 12166), (0x5dfd, 8026), (0x5dfe, 5552), (0x5dff, 11333), (0x5e02, 5725), (0x5e03, 5726), (0x5e04, 11360),
+    (0x5e06, 5869), (0x5e0a, 11566), (0x5e0c, 6066), (0x5e0e, 11567), (0x5e11, 6356), (0x5e14, 11821), (0x5e15, 6354), (0x5e16, 6353),
+    (0x5e17, 11820), (0x5e18, 6351), (0x5e19, 11822), (0x5e1a, 6352), (0x5e1b, 6355), (0x5e1d, 6706), (0x5e1f, 6708), (0x5e20, 12170),
+    (0x5e21, 12167), (0x5e22, 12168), (0x5e23, 12169), (0x5e24, 12171), (0x5e25, 6707), (0x5e28, 12612), (0x5e29, 12611), (0x5e2b, 7119),
+    (0x5e2d, 7118), (0x5e33, 7570), (0x5e34, 13194), (0x5e36, 7569), (0x5e37, 7571), (0x5e38, 7568), (0x5e3d, 8028), (0x5e3e, 13193),
+    (0x5e40, 8029), (0x5e41, 13804), (0x5e43, 8030), (0x5e44, 13803), (0x5e45, 8027), (0x5e4a, 14470), (0x5e4b, 14472), (0x5e4c, 8483),
+    (0x5e4d, 14471), (0x5e4e, 14469), (0x5e4f, 14468), (0x5e53, 15154), (0x5e54, 8941), (0x5e55, 8939), (0x5e57, 8940), (0x5e58, 15152),
+    (0x5e59, 15153), (0x5e5b, 8937), (0x5e5c, 15749), (0x5e5d, 15747), (0x5e5f, 9308), (0x5e60, 15748), (0x5e61, 9309), (0x5e62, 9307),
+    (0x5e63, 8938), (0x5e66, 16373), (0x5e67, 16371), (0x5e68, 16372), (0x5e69, 15746), (0x5e6a, 16921), (0x5e6b, 9969), (0x5e6c, 16920),
+    (0x5e6d, 17369), (0x5e6e, 17370), (0x5e6f, 16374), (0x5e70, 17740), (0x5e72, 5553), (0x5e73, 5727), (0x5e74, 5871), (0x5e75, 11425),
+    (0x5e76, 5870), (0x5e78, 6357), (0x5e79, 8484), (0x5e7b, 5616), (0x5e7c, 5728), (0x5e7d, 6709), (0x5e7e, 8031), (0x5e80, 11361),
+    (0x5e82, 11362), (0x5e84, 11426), (0x5e87, 6068), (0x5e88, 11571), (0x5e89, 11569), (0x5e8a, 6069), (0x5e8b, 11568), (0x5e8c, 11570),
+    (0x5e8d, 11572), (0x5e8f, 6067), (0x5e95, 6361), (0x5e96, 6362), (0x5e97, 6359), (0x5e9a, 6358), (0x5e9b, 12175), (0x5e9c, 6360),
+    (0x5ea0, 6710), (0x5ea2, 12174), (0x5ea3, 12176), (0x5ea4, 12173), (0x5ea5, 12177), (0x5ea6, 6711), (0x5ea7, 7122), (0x5ea8, 12613),
+    (0x5eaa, 12615), (0x5eab, 7120), (0x5eac, 12616), (0x5ead, 7121), (0x5eae, 12614), (0x5eb0, 12172), (0x5eb1, 13195), (0x5eb2, 13198),
+    (0x5eb3, 13199), (0x5eb4, 13196), (0x5eb5, 7575), (0x5eb6, 7574), (0x5eb7, 7572), (0x5eb8, 7573), (0x5eb9, 13197), (0x5ebe, 7576),
+    (0x5ec1, 8033), (0x5ec2, 8034), (0x5ec4, 8035), (0x5ec5, 14473), (0x5ec6, 14475), (0x5ec7, 14477), (0x5ec8, 8486), (0x5ec9, 8485),
+    (0x5eca, 8032), (0x5ecb, 14476), (0x5ecc, 14474), (0x5ece, 15158), (0x5ed1, 15156), (0x5ed2, 15162), (0x5ed3, 8942), (0x5ed4, 15163),
+    (0x5ed5, 15160), (0x5ed6, 8943), (0x5ed7, 15157), (0x5ed8, 15155), (0x5ed9, 15161), (0x5eda, 9311), (0x5edb, 15751), (0x5edc, 15159),
+    (0x5edd, 9313), (0x5ede, 15752), (0x5edf, 9312), (0x5ee0, 9315), (0x5ee1, 15753), (0x5ee2, 9310), (0x5ee3, 9314), (0x5ee5, 16379),
+    (0x5ee6, 16377), (0x5ee7, 16376), (0x5ee8, 16378), (0x5ee9, 16375), (0x5eec, 10400), (0x5eee, 18061), (0x5eef, 18062), (0x5ef1, 18317),
+    (0x5ef2, 18494), (0x5ef3, 10854), (0x5ef6, 6363), (0x5ef7, 6070), (0x5efa, 6712), (0x5efe, 5554), (0x5eff, 5617), (0x5f01, 5729),
+    (0x5f02, 11427), (0x5f04, 6071), (0x5f05, 11573), (0x5f07, 12178), (0x5f08, 6713), (0x5f0a, 8944), (0x5f0b, 5555), (0x5f0f, 5872),
+    (0x5f12, 8487), (0x5f13, 5556), (0x5f14, 5618), (0x5f15, 5619), (0x5f17, 5731), (0x5f18, 5730), (0x5f1a, 11428), (0x5f1b, 5873),
+    (0x5f1d, 11574), (0x5f1f, 6072), (0x5f22, 11824), (0x5f23, 11825), (0x5f24, 11826), (0x5f26, 6364), (0x5f27, 6365), (0x5f28, 11823),
+    (0x5f29, 6366), (0x5f2d, 6714), (0x5f2e, 12179), (0x5f30, 12618), (0x5f31, 7123), (0x5f33, 12617), (0x5f35, 7577), (0x5f36, 13200),
+    (0x5f37, 7578), (0x5f38, 13201), (0x5f3c, 8036), (0x5f40, 14478), (0x5f43, 15165), (0x5f44, 15164), (0x5f46, 8945), (0x5f48, 9316),
+    (0x5f49, 15754), (0x5f4a, 9681), (0x5f4b, 16380), (0x5f4c, 9970), (0x5f4e, 10727), (0x5f4f, 18644), (0x5f54, 11827), (0x5f56, 12180),
+    (0x5f57, 7579), (0x5f58, 13805), (0x5f59, 8488), (0x5f5d, 10226), (0x5f62, 6074), (0x5f64, 6073), (0x5f65, 6715), (0x5f67, 12619),
+    (0x5f69, 7581), (0x5f6a, 7853), (0x5f6b, 7582), (0x5f6c, 7580), (0x5f6d, 8037), (0x5f6f, 15166), (0x5f70, 8946), (0x5f71, 9317),
+    (0x5f73, 11316), (0x5f74, 11429), (0x5f76, 11576), (0x5f77, 6075), (0x5f78, 11575), (0x5f79, 6076), (0x5f7c, 6370), (0x5f7d, 11830),
+    (0x5f7e, 11829), (0x5f7f, 6369), (0x5f80, 6367), (0x5f81, 6368), (0x5f82, 11828), (0x5f85, 6717), (0x5f86, 12181), (0x5f87, 6720),
+    (0x5f88, 6716), (0x5f89, 6722), (0x5f8a, 6718), (0x5f8b, 6719), (0x5f8c, 6721), (0x5f90, 7126), (0x5f91, 7125), (0x5f92, 7124),
+    (0x5f96, 13203), (0x5f97, 7583), (0x5f98, 7586), (0x5f99, 7584), (0x5f9b, 13202), (0x5f9c, 7589), (0x5f9e, 7585), (0x5f9f, 13204),
+    (0x5fa0, 7588), (0x5fa1, 7587), (0x5fa5, 13807), (0x5fa6, 13806), (0x5fa8, 8040), (0x5fa9, 8038), (0x5faa, 8039), (0x5fab, 13808),
+    (0x5fac, 8489), (0x5fad, 14480), (0x5fae, 8490), (0x5faf, 14479), (0x5fb2, 15755), (0x5fb5, 9319), (0x5fb6, 15167), (0x5fb7, 9318),
+    (0x5fb9, 8947), (0x5fbb, 16923), (0x5fbc, 16381), (0x5fbd, 9971), (0x5fbe, 16922), (0x5fbf, 17741), (0x5fc0, 18063), (0x5fc1, 18064),
+    (0x5fc3, 5620), (0x5fc5, 5732), (0x5fc9, 11363), (0x5fcc, 6078), (0x5fcd, 6080), (0x5fcf, 11432), (0x5fd0, 11579), (0x5fd1, 11578),
+    (0x5fd2, 11577), (0x5fd4, 11431), (0x5fd5, 11430), (0x5fd6, 5875), (0x5fd7, 6079), (0x5fd8, 6077), (0x5fd9, 5874), (0x5fdd, 6371),
+    (0x5fde, 11831), (0x5fe0, 6372), (0x5fe1, 11584), (0x5fe3, 11586), (0x5fe4, 11585), (0x5fe5, 11832), (0x5fe8, 11581), (0x5fea, 6084),
+    (0x5feb, 6082), (0x5fed, 11580), (0x5fee, 11582), (0x5fef, 11588), (0x5ff1, 6081), (0x5ff3, 11583), (0x5ff4, 11592), (0x5ff5, 6374),
+    (0x5ff7, 11589), (0x5ff8, 6083), (0x5ffa, 11587), (0x5ffb, 11590), (0x5ffd, 6373), (0x5fff, 6375), (0x6000, 11591), (0x6009, 11852),
+    (0x600a, 11839), (0x600b, 11837), (0x600c, 11851), (0x600d, 11846), (0x600e, 6727), (0x600f, 6376), (0x6010, 11847), (0x6011, 11850),
+    (0x6012, 6723), (0x6013, 11849), (0x6014, 6377), (0x6015, 6382), (0x6016, 6380), (0x6017, 11840), (0x6019, 11835), (0x601a, 11842),
+    (0x601b, 6387), (0x601c, 11853), (0x601d, 6724), (0x601e, 11843), (0x6020, 6725), (0x6021, 6383), (0x6022, 11845), (0x6024, 12196),
+    (0x6025, 6726), (0x6026, 11834), (0x6027, 6384), (0x6028, 6728), (0x6029, 6385), (0x602a, 6381), (0x602b, 6386), (0x602c, 11844),
+    (0x602d, 11833), (0x602e, 11848), (0x602f, 6378), (0x6032, 11836), (0x6033, 11841), (0x6034, 11838), (0x6035, 6379), (0x6037, 12182),
+    (0x6039, 12183), (0x6040, 12193), (0x6041, 12623), (0x6042, 12194), (0x6043, 6734), (0x6044, 12197), (0x6045, 12187), (0x6046, 6733),
+    (0x6047, 12189), (0x6049, 12190), (0x604c, 12192), (0x604d, 6729), (0x6050, 7130), (0x6053, 12188), (0x6054, 12184), (0x6055, 7131),
+    (0x6058, 12198), (0x6059, 7127), (0x605a, 12621), (0x605b, 12191), (0x605d, 12620), (0x605e, 12186), (0x605f, 12195), (0x6062, 6732),
+    (0x6063, 7128), (0x6064, 6738), (0x6065, 7129), (0x6066, 12199), (0x6067, 12622), (0x6068, 6731), (0x6069, 7133), (0x606a, 6737),
+    (0x606b, 6736), (0x606c, 6735), (0x606d, 7132), (0x606e, 12200), (0x606f, 7134), (0x6070, 6730), (0x6072, 12185), (0x607f, 7590),
+    (0x6080, 12626), (0x6081, 12628), (0x6083, 12630), (0x6084, 7135), (0x6085, 7141), (0x6086, 13207), (0x6087, 12634), (0x6088, 12625),
+    (0x6089, 7592), (0x608a, 13205), (0x608c, 7140), (0x608d, 7138), (0x608e, 12636), (0x6090, 13206), (0x6092, 12627), (0x6094, 7139),
+    (0x6095, 12631), (0x6096, 7142), (0x6097, 12633), (0x609a, 7137), (0x609b, 12632), (0x609c, 12635), (0x609d, 12629), (0x609f, 7136),
+    (0x60a0, 7593), (0x60a2, 12624), (0x60a3, 7591), (0x60a8, 7594), (0x60b0, 13209), (0x60b1, 13218), (0x60b2, 8043), (0x60b4, 7596),
+    (0x60b5, 7601), (0x60b6, 8044), (0x60b7, 13220), (0x60b8, 7608), (0x60b9, 13810), (0x60ba, 13210), (0x60bb, 7600), (0x60bc, 7603),
+    (0x60bd, 7598), (0x60be, 13208), (0x60bf, 13222), (0x60c0, 13225), (0x60c1, 13825), (0x60c3, 13223), (0x60c4, 13814), (0x60c5, 7599),
+    (0x60c6, 7606), (0x60c7, 7610), (0x60c8, 13217), (0x60c9, 13809), (0x60ca, 13221), (0x60cb, 7595), (0x60cc, 13811), (0x60cd, 13224),
+    (0x60ce, 13813), (0x60cf, 13213), (0x60d1, 8041), (0x60d3, 13211), (0x60d4, 13212), (0x60d5, 7605), (0x60d8, 7604), (0x60d9, 13215),
+    (0x60da, 7609), (0x60db, 13219), (0x60dc, 7602), (0x60dd, 13216), (0x60df, 7607), (0x60e0, 8045), (0x60e1, 8042), (0x60e2, 13812),
+    (0x60e4, 13214), (0x60e6, 7597), (0x60f0, 8050), (0x60f1, 8054), (0x60f2, 13816), (0x60f3, 8495), (0x60f4, 8052), (0x60f5, 13820),
+    (0x60f6, 8056), (0x60f7, 14481), (0x60f8, 13822), (0x60f9, 8497), (0x60fa, 8048), (0x60fb, 8051), (0x60fc, 13823), (0x60fe, 13824),
+    (0x60ff, 13830), (0x6100, 8058), (0x6101, 8498), (0x6103, 13826), (0x6104, 13831), (0x6105, 13819), (0x6106, 8508), (0x6108, 8499),
+    (0x6109, 8057), (0x610a, 13817), (0x610b, 13832), (0x610d, 8507), (0x610e, 8055), (0x610f, 8492), (0x6110, 13829), (0x6112, 8059),
+    (0x6113, 13821), (0x6114, 13815), (0x6115, 8049), (0x6116, 13818), (0x6118, 13827), (0x611a, 8491), (0x611b, 8496), (0x611c, 8046),
+    (0x611d, 13828), (0x611f, 8494), (0x6123, 8047), (0x6127, 8506), (0x6128, 15169), (0x6129, 14492), (0x612b, 14484), (0x612c, 15168),
+    (0x612e, 14488), (0x612f, 14490), (0x6132, 14487), (0x6134, 8505), (0x6136, 14486), (0x6137, 8509), (0x613b, 15184), (0x613e, 8504),
+    (0x613f, 8949), (0x6140, 14493), (0x6141, 15170), (0x6144, 8502), (0x6145, 14485), (0x6146, 14489), (0x6147, 8948), (0x6148, 8493),
+    (0x6149, 14482), (0x614a, 14483), (0x614b, 8950), (0x614c, 8501), (0x614d, 8503), (0x614e, 8500), (0x614f, 14491), (0x6152, 15174),
+    (0x6153, 15175), (0x6154, 15180), (0x6155, 9324), (0x6156, 15187), (0x6158, 8956), (0x615a, 8955), (0x615b, 15182), (0x615d, 9323),
+    (0x615e, 15171), (0x615f, 8954), (0x6161, 15186), (0x6162, 8952), (0x6163, 8953), (0x6165, 15183), (0x6166, 15772), (0x6167, 9321),
+    (0x6168, 8053), (0x616a, 15185), (0x616b, 9328), (0x616c, 15177), (0x616e, 9322), (0x6170, 9327), (0x6171, 15172), (0x6172, 15176),
+    (0x6173, 15173), (0x6174, 15179), (0x6175, 8957), (0x6176, 9320), (0x6177, 8951), (0x6179, 15758), (0x617a, 15181), (0x617c, 9326),
+    (0x617e, 9329), (0x6180, 15178), (0x6182, 9325), (0x6183, 15757), (0x6189, 15762), (0x618a, 9685), (0x618b, 15756), (0x618c, 16393),
+    (0x618d, 15771), (0x618e, 9333), (0x6190, 9331), (0x6191, 9683), (0x6192, 15768), (0x6193, 15764), (0x6194, 9337), (0x6196, 16384),
+    (0x619a, 9335), (0x619b, 15763), (0x619d, 16382), (0x619f, 15767), (0x61a1, 15770), (0x61a2, 15761), (0x61a4, 9336), (0x61a7, 9330),
+    (0x61a8, 16383), (0x61a9, 9684), (0x61aa, 15769), (0x61ab, 9332), (0x61ac, 9334), (0x61ad, 15766), (0x61ae, 9338), (0x61af, 15765),
+    (0x61b0, 15760), (0x61b1, 15759), (0x61b2, 9682), (0x61b3, 15773), (0x61b4, 16386), (0x61b5, 16925), (0x61b6, 9687), (0x61b8, 16392),
+    (0x61ba, 16390), (0x61bc, 16926), (0x61be, 9688), (0x61bf, 16391), (0x61c1, 16388), (0x61c2, 9973), (0x61c3, 16924), (0x61c5, 16385),
+    (0x61c6, 16387), (0x61c7, 9974), (0x61c8, 9690), (0x61c9, 9972), (0x61ca, 9689), (0x61cb, 9976), (0x61cc, 16389), (0x61cd, 9686),
+    (0x61d6, 17379), (0x61d8, 17371), (0x61de, 16932), (0x61df, 17372), (0x61e0, 16928), (0x61e3, 10227), (0x61e4, 16930), (0x61e5, 16929),
+    (0x61e6, 9975), (0x61e7, 16927), (0x61e8, 16931), (0x61e9, 17380), (0x61ea, 17376), (0x61eb, 17378), (0x61ed, 17373), (0x61ee, 17374),
+    (0x61f0, 17377), (0x61f1, 17375), (0x61f2, 10401), (0x61f5, 10404), (0x61f6, 10403), (0x61f7, 10402), (0x61f8, 10552), (0x61f9, 18065),
+    (0x61fa, 10553), (0x61fb, 17742), (0x61fc, 10644), (0x61fd, 18318), (0x61fe, 10645), (0x61ff, 10728), (0x6200, 10780), (0x6201, 18645),
+    (0x6203, 18646), (0x6204, 18647), (0x6207, 18928), (0x6208, 5621), (0x6209, 11364), (0x620a, 5733), (0x620c, 5877), (0x620d, 5878),
+    (0x620e, 5876), (0x6210, 5879), (0x6211, 6086), (0x6212, 6085), (0x6214, 11854), (0x6215, 6389), (0x6216, 6388), (0x6219, 12637),
+    (0x621a, 7611), (0x621b, 7612), (0x621f, 8060), (0x6220, 14494), (0x6221, 8510), (0x6222, 8511), (0x6223, 14496), (0x6224, 14498),
+    (0x6225, 14497), (0x6227, 15189), (0x6229, 15188), (0x622a, 8958), (0x622b, 15190), (0x622d, 15774), (0x622e, 9339), (0x6230, 9691),
+    (0x6232, 9977), (0x6233, 10228), (0x6234, 9978), (0x6236, 5622), (0x623a, 11593), (0x623d, 11855), (0x623e, 6391), (0x623f, 6390),
+    (0x6240, 6392), (0x6241, 6739), (0x6242, 12201), (0x6243, 12202), (0x6246, 12638), (0x6247, 7143), (0x6248, 7613), (0x6249, 8061),
+    (0x624a, 13833), (0x624b, 5623), (0x624d, 5557), (0x624e, 5624), (0x6250, 11365), (0x6251, 5737), (0x6252, 5736), (0x6253, 5734),
+    (0x6254, 5735), (0x6258, 5882), (0x6259, 11439), (0x625a, 11441), (0x625b, 5881), (0x625c, 11433), (0x625e, 11434), (0x6260, 11440),
+    (0x6261, 11436), (0x6262, 11438), (0x6263, 5880), (0x6264, 11435), (0x6265, 11442), (0x6266, 11437), (0x626d, 6093), (0x626e, 6102),
+    (0x626f, 6100), (0x6270, 11603), (0x6271, 11600), (0x6272, 11608), (0x6273, 6098), (0x6274, 11609), (0x6276, 6091), (0x6277, 11606),
+    (0x6279, 6097), (0x627a, 11602), (0x627b, 11601), (0x627c, 6095), (0x627d, 11607), (0x627e, 6096), (0x627f, 6393), (0x6280, 6090),
+    (0x6281, 11604), (0x6283, 11594), (0x6284, 6087), (0x6286, 6106), (0x6287, 11599), (0x6288, 11605), (0x6289, 6092), (0x628a, 6094),
+    (0x628c, 11595), (0x628e, 11596), (0x628f, 11597), (0x6291, 6105), (0x6292, 6099), (0x6293, 6104), (0x6294, 11598), (0x6295, 6103),
+    (0x6296, 6089), (0x6297, 6088), (0x6298, 6101), (0x62a8, 6407), (0x62a9, 11867), (0x62aa, 11860), (0x62ab, 6402), (0x62ac, 6421),
+    (0x62ad, 11856), (0x62ae, 11863), (0x62af, 11865), (0x62b0, 11868), (0x62b1, 6416), (0x62b3, 11864), (0x62b4, 11857), (0x62b5, 6414),
+    (0x62b6, 11861), (0x62b8, 11869), (0x62b9, 6399), (0x62bb, 11866), (0x62bc, 6409), (0x62bd, 6408), (0x62be, 11859), (0x62bf, 6397),
+    (0x62c2, 6398), (0x62c4, 6396), (0x62c6, 6420), (0x62c7, 6412), (0x62c8, 6406), (0x62c9, 6394), (0x62ca, 11862), (0x62cb, 6405),
+    (0x62cc, 6395), (0x62cd, 6413), (0x62ce, 6422), (0x62cf, 12203), (0x62d0, 6410), (0x62d1, 11858), (0x62d2, 6400), (0x62d3, 6403),
+    (0x62d4, 6404), (0x62d6, 6418), (0x62d7, 6419), (0x62d8, 6417), (0x62d9, 6411), (0x62da, 6415), (0x62db, 6401), (0x62dc, 6740),
+    (0x62eb, 12209), (0x62ec, 6752), (0x62ed, 6744), (0x62ee, 6746), (0x62ef, 6751), (0x62f0, 12221), (0x62f1, 6749), (0x62f2, 12639),
+    (0x62f3, 7144), (0x62f4, 6754), (0x62f5, 12206), (0x62f6, 12214), (0x62f7, 6750), (0x62f8, 12213), (0x62f9, 12210), (0x62fa, 12218),
+    (0x62fb, 12220), (0x62fc, 6743), (0x62fd, 6747), (0x62fe, 6753), (0x62ff, 7146), (0x6300, 12215), (0x6301, 6745), (0x6302, 6756),
+    (0x6303, 12208), (0x6307, 6748), (0x6308, 7145), (0x6309, 6742), (0x630b, 12205), (0x630c, 12212), (0x630d, 12204), (0x630e, 12207),
+    (0x630f, 12211), (0x6310, 12640), (0x6311, 6755), (0x6313, 12216), (0x6314, 12217), (0x6315, 12219), (0x6316, 6741), (0x6328, 7160),
+    (0x6329, 12652), (0x632a, 7158), (0x632b, 7159), (0x632c, 12642), (0x632d, 12658), (0x632f, 7149), (0x6332, 13226), (0x6333, 12660),
+    (0x6334, 12654), (0x6336, 12645), (0x6338, 12663), (0x6339, 12648), (0x633a, 7155), (0x633b, 13249), (0x633c, 12651), (0x633d, 7157),
+    (0x633e, 7148), (0x6340, 12665), (0x6341, 12653), (0x6342, 7151), (0x6343, 12646), (0x6344, 12643), (0x6345, 12644), (0x6346, 7152),
+    (0x6347, 12659), (0x6348, 12666), (0x6349, 7154), (0x634a, 12650), (0x634b, 12649), (0x634c, 7162), (0x634d, 7161), (0x634e, 7147),
+    (0x634f, 7153), (0x6350, 7156), (0x6351, 12662), (0x6354, 12656), (0x6355, 7150), (0x6356, 12641), (0x6357, 12664), (0x6358, 12655),
+    (0x6359, 12657), (0x635a, 12661), (0x6365, 13227), (0x6367, 7621), (0x6368, 7641), (0x6369, 7640), (0x636b, 7629), (0x636d, 13245),
+    (0x636e, 13241), (0x636f, 13238), (0x6370, 13256), (0x6371, 7624), (0x6372, 7616), (0x6375, 13243), (0x6376, 8077), (0x6377, 7620),
+    (0x6378, 13251), (0x637a, 7642), (0x637b, 7639), (0x637c, 13247), (0x637d, 13230), (0x6380, 7638), (0x6381, 13253), (0x6382, 13229),
+    (0x6383, 7627), (0x6384, 7631), (0x6385, 13252), (0x6387, 13239), (0x6388, 7632), (0x6389, 7626), (0x638a, 13228), (0x638c, 8063),
+    (0x638d, 13255), (0x638e, 13237), (0x638f, 7637), (0x6390, 13240), (0x6391, 13254), (0x6392, 7636), (0x6394, 13834), (0x6396, 7617),
+    (0x6397, 13235), (0x6398, 7622), (0x6399, 7633), (0x639b, 7628), (0x639c, 13244), (0x639d, 13234), (0x639e, 13232), (0x639f, 13250),
+    (0x63a0, 7614), (0x63a1, 7634), (0x63a2, 7618), (0x63a3, 8062), (0x63a4, 13248), (0x63a5, 7619), (0x63a7, 7615), (0x63a8, 7630),
+    (0x63a9, 7625), (0x63aa, 7623), (0x63ab, 13236), (0x63ac, 7635), (0x63ad, 13233), (0x63ae, 13246), (0x63af, 13242), (0x63b0, 13836),
+    (0x63b1, 13835), (0x63bd, 13231), (0x63be, 13852), (0x63c0, 8065), (0x63c2, 13858), (0x63c3, 13841), (0x63c4, 13855), (0x63c5, 14499),
+    (0x63c6, 8068), (0x63c7, 13859), (0x63c8, 13862), (0x63c9, 8067), (0x63ca, 13844), (0x63cb, 13861), (0x63cc, 13860), (0x63cd, 8069),
+    (0x63ce, 13837), (0x63cf, 8064), (0x63d0, 8072), (0x63d2, 8070), (0x63d3, 13857), (0x63d5, 13847), (0x63d6, 8074), (0x63d7, 13864),
+    (0x63d8, 13856), (0x63d9, 13865), (0x63da, 8082), (0x63db, 8080), (0x63dc, 13854), (0x63dd, 13853), (0x63df, 13851), (0x63e0, 13845),
+    (0x63e1, 8073), (0x63e3, 8071), (0x63e4, 12647), (0x63e5, 13838), (0x63e7, 14529), (0x63e8, 13839), (0x63e9, 8066), (0x63ea, 8079),
+    (0x63eb, 14501), (0x63ed, 8075), (0x63ee, 8076), (0x63ef, 13840), (0x63f0, 13863), (0x63f1, 14500), (0x63f2, 13848), (0x63f3, 13843),
+    (0x63f4, 8078), (0x63f5, 13849), (0x63f6, 13846), (0x63f9, 8083), (0x6406, 8526), (0x6409, 14504), (0x640a, 14523), (0x640b, 14528),
+    (0x640c, 14516), (0x640d, 8522), (0x640e, 14533), (0x640f, 8519), (0x6410, 14502), (0x6412, 14503), (0x6413, 8512), (0x6414, 8521),
+    (0x6415, 14510), (0x6416, 8524), (0x6417, 8525), (0x6418, 14511), (0x641a, 14524), (0x641b, 14530), (0x641c, 8520), (0x641e, 8514),
+    (0x641f, 14509), (0x6420, 14505), (0x6421, 14532), (0x6422, 14514), (0x6423, 14515), (0x6424, 14506), (0x6425, 14526), (0x6426, 14517),
+    (0x6427, 14527), (0x6428, 14519), (0x642a, 8515), (0x642b, 15191), (0x642c, 8518), (0x642d, 8516), (0x642e, 14531), (0x642f, 14522),
+    (0x6430, 14518), (0x6433, 14507), (0x6434, 8968), (0x6435, 14521), (0x6436, 8523), (0x6437, 14513), (0x6439, 14512), (0x643d, 8517),
+    (0x643e, 8513), (0x643f, 15212), (0x6440, 14525), (0x6441, 14520), (0x6443, 14508), (0x644b, 15207), (0x644d, 15192), (0x644e, 15203),
+    (0x6450, 15210), (0x6451, 8966), (0x6452, 8081), (0x6453, 15208), (0x6454, 8961), (0x6458, 8960), (0x6459, 15215), (0x645b, 15193),
+    (0x645c, 15206), (0x645d, 15194), (0x645e, 15205), (0x645f, 8964), (0x6460, 15209), (0x6461, 13850), (0x6465, 15216), (0x6466, 15201),
+    (0x6467, 8967), (0x6468, 15788), (0x6469, 9340), (0x646b, 15214), (0x646c, 15213), (0x646d, 8969), (0x646e, 15775), (0x646f, 9341),
+    (0x6470, 15776), (0x6472, 15197), (0x6473, 15198), (0x6474, 15195), (0x6475, 15200), (0x6476, 15196), (0x6477, 15217), (0x6478, 8963),
+    (0x6479, 9342), (0x647a, 8965), (0x647b, 8970), (0x647d, 15199), (0x647f, 15211), (0x6482, 15204), (0x6485, 15779), (0x6487, 8959),
+    (0x6488, 9345), (0x6489, 16400), (0x648a, 15784), (0x648b, 15783), (0x648c, 15785), (0x648f, 15782), (0x6490, 9346), (0x6492, 9352),
+    (0x6493, 9349), (0x6495, 9350), (0x6496, 15777), (0x6497, 15780), (0x6498, 15790), (0x6499, 9358), (0x649a, 9356), (0x649c, 15781),
+    (0x649d, 13842), (0x649e, 9343), (0x649f, 15787), (0x64a0, 15778), (0x64a2, 9359), (0x64a3, 15786), (0x64a4, 8962), (0x64a5, 9348),
+    (0x64a6, 15202), (0x64a9, 9351), (0x64ab, 9355), (0x64ac, 9357), (0x64ad, 9354), (0x64ae, 9353), (0x64b0, 9347), (0x64b1, 15789),
+    (0x64b2, 9344), (0x64b3, 9360), (0x64bb, 9695), (0x64bc, 9696), (0x64bd, 16399), (0x64be, 9705), (0x64bf, 9702), (0x64c1, 9693),
+    (0x64c2, 9700), (0x64c3, 16401), (0x64c4, 9698), (0x64c5, 9692), (0x64c7, 9699), (0x64c9, 16398), (0x64ca, 9980), (0x64cb, 9694),
+    (0x64cd, 9701), (0x64ce, 9979), (0x64cf, 16397), (0x64d0, 16396), (0x64d2, 9703), (0x64d4, 9704), (0x64d6, 16395), (0x64d7, 16394),
+    (0x64d8, 9981), (0x64d9, 16404), (0x64da, 9697), (0x64db, 16402), (0x64e0, 9982), (0x64e2, 9987), (0x64e3, 16935), (0x64e4, 16937),
+    (0x64e6, 9984), (0x64e8, 16938), (0x64e9, 16934), (0x64eb, 16936), (0x64ec, 9985), (0x64ed, 9988), (0x64ef, 16933), (0x64f0, 9983),
+    (0x64f1, 9986), (0x64f2, 10230), (0x64f3, 16403), (0x64f4, 10229), (0x64f7, 10235), (0x64f8, 17384), (0x64fa, 10233), (0x64fb, 10234),
+    (0x64fc, 17387), (0x64fd, 17383), (0x64fe, 10231), (0x64ff, 17381), (0x6500, 10405), (0x6501, 17385), (0x6503, 17386), (0x6504, 17382),
+    (0x6506, 10232), (0x6507, 17743), (0x6509, 17746), (0x650c, 17747), (0x650d, 17745), (0x650e, 17748), (0x650f, 10406), (0x6510, 17744),
+    (0x6513, 18069), (0x6514, 10555), (0x6515, 18068), (0x6516, 18067), (0x6517, 18066), (0x6518, 10554), (0x6519, 10556), (0x651b, 18319),
+    (0x651c, 10647), (0x651d, 10646), (0x6520, 18496), (0x6521, 18495), (0x6522, 18498), (0x6523, 10781), (0x6524, 10729), (0x6525, 18649),
+    (0x6526, 18497), (0x6529, 18648), (0x652a, 10783), (0x652b, 10782), (0x652c, 10821), (0x652d, 18754), (0x652e, 18824), (0x652f, 5625),
+    (0x6532, 13866), (0x6533, 16405), (0x6536, 5883), (0x6537, 11610), (0x6538, 6109), (0x6539, 6107), (0x653b, 6108), (0x653d, 11870),
+    (0x653e, 6423), (0x653f, 6757), (0x6541, 12222), (0x6543, 12223), (0x6545, 6758), (0x6546, 12668), (0x6548, 7163), (0x6549, 7164),
+    (0x654a, 12667), (0x654f, 7649), (0x6551, 7645), (0x6553, 13257), (0x6554, 7652), (0x6555, 7651), (0x6556, 7644), (0x6557, 7647),
+    (0x6558, 7650), (0x6559, 7646), (0x655c, 13870), (0x655d, 7643), (0x655e, 8084), (0x6562, 8086), (0x6563, 8087), (0x6564, 13869),
+    (0x6565, 13872), (0x6566, 8085), (0x6567, 13867), (0x6568, 13871), (0x656a, 13868), (0x656c, 8527), (0x656f, 14534), (0x6572, 8971),
+    (0x6573, 15218), (0x6574, 9706), (0x6575, 9361), (0x6576, 15791), (0x6577, 9362), (0x6578, 9363), (0x6579, 15793), (0x657a, 15792),
+    (0x657b, 15794), (0x657c, 16407), (0x657f, 16406), (0x6580, 16940), (0x6581, 16939), (0x6582, 9989), (0x6583, 9990), (0x6584, 17749),
+    (0x6587, 5626), (0x658c, 13873), (0x6590, 8089), (0x6591, 8088), (0x6592, 14535), (0x6594, 17388), (0x6595, 10648), (0x6596, 18650),
+    (0x6597, 5627), (0x6599, 7165), (0x659b, 7654), (0x659c, 7653), (0x659d, 13874), (0x659e, 13875), (0x659f, 8528), (0x65a0, 15219),
+    (0x65a1, 8972), (0x65a2, 16408), (0x65a4, 5628), (0x65a5, 5738), (0x65a7, 6424), (0x65a8, 11871), (0x65aa, 12224), (0x65ab, 6759),
+    (0x65ac, 7655), (0x65ae, 13876), (0x65af, 8090), (0x65b0, 8529), (0x65b2, 15795), (0x65b3, 15796), (0x65b6, 16941), (0x65b7, 10236),
+    (0x65b8, 18825), (0x65b9, 5629), (0x65bb, 11872), (0x65bc, 6425), (0x65bd, 6760), (0x65bf, 12225), (0x65c1, 7166), (0x65c2, 12672),
+    (0x65c3, 12670), (0x65c4, 12671), (0x65c5, 7167), (0x65c6, 12669), (0x65cb, 7657), (0x65cc, 7658), (0x65cd, 13258), (0x65ce, 7659),
+    (0x65cf, 7656), (0x65d0, 13877), (0x65d2, 13878), (0x65d3, 14536), (0x65d6, 8974), (0x65d7, 8973), (0x65da, 16942), (0x65db, 17389),
+    (0x65dd, 17751), (0x65de, 17750), (0x65df, 18070), (0x65e1, 11334), (0x65e2, 6761), (0x65e5, 5630), (0x65e6, 5739), (0x65e8, 5885),
+    (0x65e9, 5884), (0x65ec, 5886), (0x65ed, 5887), (0x65ee, 11444), (0x65ef, 11443), (0x65f0, 11611), (0x65f1, 6110), (0x65f2, 11614),
+    (0x65f3, 11613), (0x65f4, 11612), (0x65f5, 11615), (0x65fa, 6426), (0x65fb, 11878), (0x65fc, 11874), (0x65fd, 11883), (0x6600, 6433),
+    (0x6602, 6431), (0x6603, 11879), (0x6604, 11875), (0x6605, 11882), (0x6606, 6430), (0x6607, 6437), (0x6608, 11877), (0x6609, 11873),
+    (0x660a, 6436), (0x660b, 11880), (0x660c, 6429), (0x660d, 11881), (0x660e, 6432), (0x660f, 6434), (0x6610, 11885), (0x6611, 11884),
+    (0x6612, 11876), (0x6613, 6428), (0x6614, 6427), (0x6615, 6435), (0x661c, 12230), (0x661d, 12236), (0x661f, 6767), (0x6620, 6764),
+    (0x6621, 12227), (0x6622, 12232), (0x6624, 6770), (0x6625, 6762), (0x6626, 12231), (0x6627, 6765), (0x6628, 6768), (0x662b, 12234),
+    (0x662d, 6763), (0x662e, 12239), (0x662f, 6766), (0x6631, 6769), (0x6632, 12228), (0x6633, 12233), (0x6634, 12237), (0x6635, 12229),
+    (0x6636, 12226), (0x6639, 12238), (0x663a, 12235), (0x6641, 7175), (0x6642, 7168), (0x6643, 7171), (0x6645, 7174), (0x6647, 12675),
+    (0x6649, 7169), (0x664a, 12673), (0x664c, 7173), (0x664f, 7170), (0x6651, 12676), (0x6652, 7172), (0x6659, 13262), (0x665a, 7661),
+    (0x665b, 13261), (0x665c, 13263), (0x665d, 7660), (0x665e, 7665), (0x665f, 12674), (0x6661, 13260), (0x6662, 13264), (0x6664, 7662),
+    (0x6665, 13259), (0x6666, 7664), (0x6668, 7663), (0x666a, 13885), (0x666c, 13880), (0x666e, 8091), (0x666f, 8095), (0x6670, 8092),
+    (0x6671, 13883), (0x6672, 13886), (0x6674, 8093), (0x6676, 8094), (0x6677, 8099), (0x6678, 14545), (0x6679, 13884), (0x667a, 8097),
+    (0x667b, 13881), (0x667c, 13879), (0x667e, 8098), (0x6680, 13882), (0x6684, 8535), (0x6686, 14537), (0x6687, 8532), (0x6688, 8533),
+    (0x6689, 8531), (0x668a, 14542), (0x668b, 14541), (0x668c, 14538), (0x668d, 8537), (0x6690, 14540), (0x6691, 8096), (0x6694, 14544),
+    (0x6695, 14539), (0x6696, 8534), (0x6697, 8530), (0x6698, 8536), (0x6699, 14543), (0x669d, 8977), (0x669f, 15222), (0x66a0, 15221),
+    (0x66a1, 15220), (0x66a2, 8975), (0x66a8, 8976), (0x66a9, 15799), (0x66aa, 15802), (0x66ab, 9365), (0x66ae, 9364), (0x66af, 15803),
+    (0x66b0, 15798), (0x66b1, 9367), (0x66b2, 15800), (0x66b4, 9366), (0x66b5, 15797), (0x66b7, 15801), (0x66b8, 9712), (0x66b9, 9709),
+    (0x66ba, 16417), (0x66bb, 16416), (0x66bd, 16415), (0x66be, 16410), (0x66c0, 16411), (0x66c4, 9710), (0x66c6, 9707), (0x66c7, 9711),
+    (0x66c8, 16409), (0x66c9, 9708), (0x66ca, 16412), (0x66cb, 16413), (0x66cc, 16418), (0x66cf, 16414), (0x66d2, 16943), (0x66d6, 9992),
+    (0x66d8, 17392), (0x66d9, 9991), (0x66da, 17390), (0x66db, 17391), (0x66dc, 10237), (0x66dd, 10408), (0x66de, 17752), (0x66e0, 10407),
+    (0x66e3, 18072), (0x66e4, 18073), (0x66e6, 10557), (0x66e8, 18071), (0x66e9, 10649), (0x66eb, 18651), (0x66ec, 10784), (0x66ed, 18755),
+    (0x66ee, 18756), (0x66f0, 5631), (0x66f2, 5888), (0x66f3, 5889), (0x66f4, 6111), (0x66f6, 11886), (0x66f7, 6771), (0x66f8, 7176),
+    (0x66f9, 7666), (0x66fc, 7491), (0x66fe, 8100), (0x66ff, 8101), (0x6700, 7956), (0x6701, 13887), (0x6703, 8538), (0x6704, 15224),
+    (0x6705, 15223), (0x6708, 5632), (0x6709, 5890), (0x670a, 11887), (0x670b, 6439), (0x670d, 6438), (0x670f, 12240), (0x6710, 12241),
+    (0x6712, 12677), (0x6713, 12678), (0x6714, 7177), (0x6715, 7178), (0x6717, 7179), (0x6718, 13265), (0x671b, 7668), (0x671d, 8103),
+    (0x671f, 8102), (0x6720, 14546), (0x6721, 14816), (0x6722, 15225), (0x6723, 16419), (0x6726, 10238), (0x6727, 10558), (0x6728, 5633),
+    (0x672a, 5742), (0x672b, 5743), (0x672c, 5741), (0x672d, 5744), (0x672e, 5740), (0x6731, 5893), (0x6733, 11452), (0x6734, 5892),
+    (0x6735, 5894), (0x6738, 11447), (0x6739, 11446), (0x673a, 11449), (0x673b, 11448), (0x673c, 11451), (0x673d, 5891), (0x673e, 11445),
+    (0x673f, 11450), (0x6745, 11616), (0x6746, 6121), (0x6747, 11617), (0x6748, 11621), (0x6749, 6120), (0x674b, 11625), (0x674c, 11620),
+    (0x674d, 11623), (0x674e, 6113), (0x674f, 6114), (0x6750, 6115), (0x6751, 6116), (0x6753, 6123), (0x6755, 11619), (0x6756, 6118),
+    (0x6757, 6124), (0x6759, 11618), (0x675a, 11624), (0x675c, 6117), (0x675d, 11622), (0x675e, 6119), (0x675f, 6112), (0x6760, 6122),
+    (0x676a, 6460), (0x676c, 11889), (0x676d, 6440), (0x676f, 6450), (0x6770, 6451), (0x6771, 6443), (0x6772, 6461), (0x6773, 6445),
+    (0x6774, 11897), (0x6775, 6456), (0x6776, 11892), (0x6777, 6446), (0x6778, 11907), (0x6779, 11908), (0x677a, 11900), (0x677b, 11893),
+    (0x677c, 6459), (0x677d, 11905), (0x677e, 6454), (0x677f, 6452), (0x6781, 11906), (0x6783, 11904), (0x6784, 11896), (0x6785, 11888),
+    (0x6786, 11895), (0x6787, 6447), (0x6789, 6453), (0x678b, 6441), (0x678c, 11899), (0x678d, 11898), (0x678e, 11890), (0x6790, 6455),
+    (0x6791, 11902), (0x6792, 11891), (0x6793, 6458), (0x6794, 11909), (0x6795, 6442), (0x6797, 6449), (0x6798, 11894), (0x6799, 11903),
+    (0x679a, 6457), (0x679c, 6444), (0x679d, 6448), (0x679f, 11901), (0x67ae, 12273), (0x67af, 6779), (0x67b0, 6792), (0x67b2, 12268),
+    (0x67b3, 12258), (0x67b4, 6785), (0x67b5, 12256), (0x67b6, 6778), (0x67b7, 12251), (0x67b8, 6788), (0x67b9, 12264), (0x67ba, 12245),
+    (0x67bb, 12247), (0x67c0, 12250), (0x67c1, 12242), (0x67c2, 12263), (0x67c3, 12279), (0x67c4, 6783), (0x67c5, 12252), (0x67c6, 12270),
+    (0x67c8, 12244), (0x67c9, 12277), (0x67ca, 12278), (0x67cb, 12281), (0x67cc, 12272), (0x67cd, 12257), (0x67ce, 12265), (0x67cf, 6789),
+    (0x67d0, 6776), (0x67d1, 6784), (0x67d2, 6796), (0x67d3, 6773), (0x67d4, 6775), (0x67d8, 12249), (0x67d9, 6793), (0x67da, 6786),
+    (0x67db, 12275), (0x67dc, 12246), (0x67dd, 6795), (0x67de, 6790), (0x67df, 12255), (0x67e2, 6794), (0x67e3, 12262), (0x67e4, 12254),
+    (0x67e5, 6787), (0x67e6, 12274), (0x67e7, 12266), (0x67e9, 6781), (0x67ea, 12280), (0x67eb, 12253), (0x67ec, 6777), (0x67ed, 12271),
+    (0x67ee, 12261), (0x67ef, 6782), (0x67f0, 12267), (0x67f1, 6774), (0x67f2, 12243), (0x67f3, 6791), (0x67f4, 7194), (0x67f5, 6780),
+    (0x67f6, 12260), (0x67f7, 12259), (0x67f8, 12248), (0x67fa, 12276), (0x67fc, 12269), (0x67ff, 6772), (0x6812, 12698), (0x6813, 7201),
+    (0x6814, 12699), (0x6816, 12687), (0x6817, 7190), (0x6818, 7202), (0x681a, 12680), (0x681c, 12689), (0x681d, 12697), (0x681f, 12679),
+    (0x6820, 12706), (0x6821, 7180), (0x6825, 12705), (0x6826, 12700), (0x6828, 12701), (0x6829, 7188), (0x682a, 7199), (0x682b, 12691),
+    (0x682d, 12692), (0x682e, 12702), (0x682f, 12693), (0x6831, 12688), (0x6832, 12682), (0x6833, 12683), (0x6834, 12696), (0x6835, 12690),
+    (0x6838, 7181), (0x6839, 7185), (0x683a, 12704), (0x683b, 12684), (0x683c, 7197), (0x683d, 7193), (0x6840, 7196), (0x6841, 7203),
+    (0x6842, 7186), (0x6843, 7198), (0x6844, 12695), (0x6845, 7200), (0x6846, 7183), (0x6848, 7182), (0x6849, 12681), (0x684b, 12685),
+    (0x684c, 7191), (0x684d, 12703), (0x684e, 12694), (0x684f, 12686), (0x6850, 7195), (0x6851, 7192), (0x6853, 7184), (0x6854, 7187),
+    (0x686b, 13286), (0x686d, 13270), (0x686e, 13271), (0x686f, 13275), (0x6871, 13290), (0x6872, 13287), (0x6874, 13280), (0x6875, 13279),
+    (0x6876, 7675), (0x6877, 13283), (0x6878, 13298), (0x6879, 13266), (0x687b, 13299), (0x687c, 13285), (0x687d, 13303), (0x687e, 13291),
+    (0x687f, 7674), (0x6880, 13289), (0x6881, 7669), (0x6882, 7690), (0x6883, 7680), (0x6885, 7684), (0x6886, 7683), (0x6887, 13267),
+    (0x6889, 13296), (0x688a, 13302), (0x688b, 13294), (0x688c, 13301), (0x688f, 13282), (0x6890, 13268), (0x6891, 13300), (0x6892, 13284),
+    (0x6893, 7672), (0x6894, 7685), (0x6896, 13293), (0x6897, 7678), (0x689b, 13292), (0x689c, 13269), (0x689d, 7686), (0x689f, 7688),
+    (0x68a0, 13295), (0x68a1, 7689), (0x68a2, 7671), (0x68a3, 13276), (0x68a4, 13297), (0x68a7, 7677), (0x68a8, 7687), (0x68a9, 13278),
+    (0x68aa, 13288), (0x68ab, 13273), (0x68ac, 13277), (0x68ad, 7682), (0x68ae, 13272), (0x68af, 7670), (0x68b0, 7679), (0x68b1, 7676),
+    (0x68b2, 13281), (0x68b3, 7189), (0x68b4, 13910), (0x68b5, 7673), (0x68c4, 7681), (0x68c6, 13913), (0x68c7, 13935), (0x68c8, 13924),
+    (0x68c9, 8123), (0x68cb, 8118), (0x68cc, 13907), (0x68cd, 8119), (0x68ce, 13923), (0x68d0, 13916), (0x68d1, 13929), (0x68d2, 8115),
+    (0x68d3, 13889), (0x68d4, 13931), (0x68d5, 8105), (0x68d6, 13897), (0x68d7, 8108), (0x68d8, 8107), (0x68da, 8124), (0x68dc, 13891),
+    (0x68dd, 13925), (0x68de, 13926), (0x68df, 8110), (0x68e0, 8106), (0x68e1, 13905), (0x68e3, 8117), (0x68e4, 13900), (0x68e6, 13927),
+    (0x68e7, 8113), (0x68e8, 13919), (0x68e9, 13932), (0x68ea, 13894), (0x68eb, 13899), (0x68ec, 13893), (0x68ee, 8112), (0x68ef, 13912),
+    (0x68f0, 14568), (0x68f1, 13895), (0x68f2, 8116), (0x68f3, 13904), (0x68f4, 13928), (0x68f5, 8111), (0x68f6, 13901), (0x68f7, 13898),
+    (0x68f8, 13915), (0x68f9, 8114), (0x68fa, 8104), (0x68fb, 8126), (0x68fc, 13918), (0x68fd, 13917), (0x6904, 13890), (0x6905, 8109),
+    (0x6906, 13930), (0x6907, 13906), (0x6908, 13908), (0x690a, 13921), (0x690b, 13920), (0x690c, 13888), (0x690d, 8120), (0x690e, 8122),
+    (0x690f, 13896), (0x6910, 13903), (0x6911, 13911), (0x6912, 8121), (0x6913, 13902), (0x6914, 13914), (0x6915, 13933), (0x6917, 13922),
+    (0x6925, 13934), (0x692a, 13892), (0x692f, 14588), (0x6930, 8546), (0x6932, 14586), (0x6933, 14565), (0x6934, 14570), (0x6935, 14563),
+    (0x6937, 14582), (0x6938, 14549), (0x6939, 14556), (0x693b, 14580), (0x693c, 14590), (0x693d, 14566), (0x693f, 14553), (0x6940, 14572),
+    (0x6941, 14577), (0x6942, 14557), (0x6944, 14574), (0x6945, 14554), (0x6948, 14561), (0x6949, 14562), (0x694a, 8548), (0x694b, 14581),
+    (0x694c, 14579), (0x694e, 14550), (0x694f, 14584), (0x6951, 14585), (0x6952, 14587), (0x6953, 8552), (0x6954, 8544), (0x6956, 13274),
+    (0x6957, 14558), (0x6958, 14576), (0x6959, 14559), (0x695a, 8541), (0x695b, 8557), (0x695c, 14583), (0x695d, 8555), (0x695e, 8551),
+    (0x695f, 14548), (0x6960, 8543), (0x6962, 14551), (0x6963, 8556), (0x6965, 14567), (0x6966, 14547), (0x6968, 8549), (0x6969, 14571),
+    (0x696a, 14555), (0x696b, 8550), (0x696c, 14564), (0x696d, 8540), (0x696e, 8125), (0x696f, 14573), (0x6970, 13909), (0x6971, 14552),
+    (0x6974, 14578), (0x6975, 8545), (0x6976, 14575), (0x6977, 8542), (0x6978, 14569), (0x6979, 8553), (0x697a, 14560), (0x697b, 14589),
+    (0x6982, 8547), (0x6983, 14701), (0x6986, 8554), (0x698d, 15239), (0x698e, 15237), (0x6990, 15259), (0x6991, 15235), (0x6993, 15253),
+    (0x6994, 8539), (0x6995, 8980), (0x6996, 15231), (0x6997, 15258), (0x6999, 15236), (0x699a, 15250), (0x699b, 8985), (0x699c, 8978),
+    (0x699e, 15256), (0x69a0, 15229), (0x69a1, 15255), (0x69a3, 8996), (0x69a4, 15246), (0x69a5, 15262), (0x69a6, 8994), (0x69a7, 15238),
+    (0x69a8, 8979), (0x69a9, 15240), (0x69aa, 15254), (0x69ab, 8988), (0x69ac, 15233), (0x69ad, 8992), (0x69ae, 8982), (0x69af, 15242),
+    (0x69b0, 15232), (0x69b1, 15226), (0x69b3, 15252), (0x69b4, 8989), (0x69b5, 15261), (0x69b6, 15227), (0x69b7, 8986), (0x69b9, 15248),
+    (0x69bb, 8987), (0x69bc, 15234), (0x69bd, 15245), (0x69be, 15241), (0x69bf, 15243), (0x69c1, 8981), (0x69c2, 15260), (0x69c3, 8995),
+    (0x69c4, 15244), (0x69c6, 15263), (0x69c9, 15228), (0x69ca, 15249), (0x69cb, 8984), (0x69cc, 8993), (0x69cd, 8991), (0x69ce, 15230),
+    (0x69cf, 15251), (0x69d0, 8990), (0x69d3, 8983), (0x69d4, 15247), (0x69d9, 15257), (0x69e2, 15815), (0x69e4, 15811), (0x69e5, 15807),
+    (0x69e6, 15827), (0x69e7, 15824), (0x69e8, 9370), (0x69eb, 15831), (0x69ec, 15814), (0x69ed, 9381), (0x69ee, 15821), (0x69f1, 15810),
+    (0x69f2, 15820), (0x69f3, 9378), (0x69f4, 15840), (0x69f6, 15837), (0x69f7, 15823), (0x69f8, 15808), (0x69fb, 15828), (0x69fc, 15830),
+    (0x69fd, 9374), (0x69fe, 15818), (0x69ff, 15813), (0x6a00, 15804), (0x6a01, 9371), (0x6a02, 9379), (0x6a04, 15833), (0x6a05, 9380),
+    (0x6a06, 15805), (0x6a07, 15839), (0x6a08, 15826), (0x6a09, 15832), (0x6a0a, 9377), (0x6a0d, 15829), (0x6a0f, 15836), (0x6a11, 9382),
+    (0x6a13, 9376), (0x6a14, 15822), (0x6a15, 15809), (0x6a16, 15841), (0x6a17, 15806), (0x6a18, 15834), (0x6a19, 9373), (0x6a1b, 15816),
+    (0x6a1d, 15817), (0x6a1e, 9372), (0x6a1f, 9369), (0x6a20, 15812), (0x6a21, 9375), (0x6a23, 9368), (0x6a25, 15835), (0x6a26, 15838),
+    (0x6a27, 15819), (0x6a28, 16432), (0x6a32, 16424), (0x6a34, 16420), (0x6a35, 9725), (0x6a38, 9714), (0x6a39, 9719), (0x6a3a, 9715),
+    (0x6a3b, 16434), (0x6a3c, 16445), (0x6a3d, 9713), (0x6a3e, 16426), (0x6a3f, 16435), (0x6a40, 15825), (0x6a41, 16436), (0x6a44, 9720),
+    (0x6a46, 16451), (0x6a47, 9724), (0x6a48, 9727), (0x6a49, 16422), (0x6a4b, 9723), (0x6a4d, 16449), (0x6a4e, 16450), (0x6a4f, 16440),
+    (0x6a50, 16439), (0x6a51, 16431), (0x6a54, 16441), (0x6a55, 16448), (0x6a56, 16447), (0x6a58, 9718), (0x6a59, 9716), (0x6a5a, 16433),
+    (0x6a5b, 16430), (0x6a5d, 16427), (0x6a5e, 16446), (0x6a5f, 9726), (0x6a60, 16444), (0x6a61, 9722), (0x6a62, 9721), (0x6a64, 16438),
+    (0x6a66, 16421), (0x6a67, 16423), (0x6a68, 16425), (0x6a69, 16443), (0x6a6a, 16437), (0x6a6b, 9717), (0x6a6d, 16428), (0x6a6f, 16442),
+    (0x6a76, 16429), (0x6a7e, 10000), (0x6a7f, 16961), (0x6a80, 9993), (0x6a81, 16946), (0x6a83, 16957), (0x6a84, 9995), (0x6a85, 16964),
+    (0x6a87, 16953), (0x6a89, 16948), (0x6a8c, 16965), (0x6a8d, 16944), (0x6a8e, 16955), (0x6a90, 10002), (0x6a91, 16960), (0x6a92, 16966),
+    (0x6a93, 16954), (0x6a94, 9994), (0x6a95, 16956), (0x6a96, 16945), (0x6a97, 10001), (0x6a9a, 16963), (0x6a9b, 16950), (0x6a9c, 9997),
+    (0x6a9e, 16952), (0x6a9f, 16949), (0x6aa0, 10003), (0x6aa1, 16951), (0x6aa2, 9996), (0x6aa3, 9999), (0x6aa4, 16959), (0x6aa5, 16947),
+    (0x6aa6, 16962), (0x6aa8, 16958), (0x6aac, 10240), (0x6aad, 17403), (0x6aae, 10245), (0x6aaf, 10246), (0x6ab3, 10239), (0x6ab4, 17402),
+    (0x6ab6, 17399), (0x6ab7, 17400), (0x6ab8, 10243), (0x6ab9, 17394), (0x6aba, 17398), (0x6abb, 10242), (0x6abd, 17395), (0x6ac2, 10244),
+    (0x6ac3, 10241), (0x6ac5, 17393), (0x6ac6, 17397), (0x6ac7, 17401), (0x6acb, 17758), (0x6acc, 17755), (0x6acd, 17764), (0x6acf, 17763),
+    (0x6ad0, 17761), (0x6ad1, 17756), (0x6ad3, 10412), (0x6ad9, 17757), (0x6ada, 10411), (0x6adb, 9998), (0x6adc, 17760), (0x6add, 10410),
+    (0x6ade, 17765), (0x6adf, 17759), (0x6ae0, 17754), (0x6ae1, 17396), (0x6ae5, 10409), (0x6ae7, 17753), (0x6ae8, 18077), (0x6aea, 18076),
+    (0x6aeb, 17762), (0x6aec, 10559), (0x6aee, 18080), (0x6aef, 18081), (0x6af0, 18075), (0x6af1, 18079), (0x6af3, 18074), (0x6af8, 18323),
+    (0x6af9, 18078), (0x6afa, 10652), (0x6afb, 10650), (0x6afc, 18321), (0x6b00, 18324), (0x6b02, 18320), (0x6b03, 18322), (0x6b04, 10651),
+    (0x6b08, 18500), (0x6b09, 18501), (0x6b0a, 10730), (0x6b0b, 18499), (0x6b0f, 18654), (0x6b10, 10785), (0x6b11, 18652), (0x6b12, 18653),
+    (0x6b13, 18757), (0x6b16, 10855), (0x6b17, 18828), (0x6b18, 18826), (0x6b19, 18827), (0x6b1a, 18829), (0x6b1e, 18929), (0x6b20, 5634),
+    (0x6b21, 5895), (0x6b23, 6462), (0x6b25, 11910), (0x6b28, 12282), (0x6b2c, 12707), (0x6b2d, 12709), (0x6b2f, 12708), (0x6b31, 12710),
+    (0x6b32, 7691), (0x6b33, 13305), (0x6b34, 12711), (0x6b36, 13304), (0x6b37, 13306), (0x6b38, 13307), (0x6b39, 13936), (0x6b3a, 8128),
+    (0x6b3b, 13937), (0x6b3c, 13939), (0x6b3d, 8129), (0x6b3e, 8127), (0x6b3f, 13938), (0x6b41, 14596), (0x6b42, 14594), (0x6b43, 14593),
+    (0x6b45, 14592), (0x6b46, 14591), (0x6b47, 8558), (0x6b48, 14595), (0x6b49, 8997), (0x6b4a, 15264), (0x6b4b, 15266), (0x6b4c, 8998),
+    (0x6b4d, 15265), (0x6b4e, 9384), (0x6b50, 9383), (0x6b51, 15842), (0x6b54, 16453), (0x6b55, 16452), (0x6b56, 16454), (0x6b59, 9728),
+    (0x6b5b, 16967), (0x6b5c, 10004), (0x6b5e, 17404), (0x6b5f, 10247), (0x6b60, 17766), (0x6b61, 10731), (0x6b62, 5635), (0x6b63, 5745),
+    (0x6b64, 5896), (0x6b65, 6125), (0x6b66, 6463), (0x6b67, 6464), (0x6b6a, 6797), (0x6b6d, 12712), (0x6b72, 8559), (0x6b76, 15968),
+    (0x6b77, 9729), (0x6b78, 10248), (0x6b79, 5636), (0x6b7b, 5897), (0x6b7e, 11912), (0x6b7f, 6465), (0x6b80, 11911), (0x6b82, 12283),
+    (0x6b83, 6798), (0x6b84, 12284), (0x6b86, 6799), (0x6b88, 12714), (0x6b89, 7205), (0x6b8a, 7204), (0x6b8c, 13312), (0x6b8d, 13310),
+    (0x6b8e, 13311), (0x6b8f, 13309), (0x6b91, 13308), (0x6b94, 13940), (0x6b95, 13943), (0x6b96, 8131), (0x6b97, 13941), (0x6b98, 8130),
+    (0x6b99, 13942), (0x6b9b, 14597), (0x6b9e, 15267), (0x6b9f, 15268), (0x6ba0, 15269), (0x6ba2, 15845), (0x6ba3, 15844), (0x6ba4, 9385),
+    (0x6ba5, 15843), (0x6ba6, 15846), (0x6ba7, 16455), (0x6baa, 16456), (0x6bab, 16457), (0x6bad, 16968), (0x6bae, 10005), (0x6baf, 10249),
+    (0x6bb0, 17767), (0x6bb2, 10653), (0x6bb3, 11335), (0x6bb5, 6800), (0x6bb6, 12285), (0x6bb7, 7206), (0x6bba, 7692), (0x6bbc, 8132),
+    (0x6bbd, 13944), (0x6bbf, 8561), (0x6bc0, 8560), (0x6bc3, 15270), (0x6bc4, 15271), (0x6bc5, 9386), (0x6bc6, 9387), (0x6bc7, 16459),
+    (0x6bc8, 16458), (0x6bc9, 17405), (0x6bca, 18655), (0x6bcb, 5637), (0x6bcc, 11336), (0x6bcd, 5746), (0x6bcf, 6126), (0x6bd0, 11626),
+    (0x6bd2, 6801), (0x6bd3, 8562), (0x6bd4, 5638), (0x6bd6, 12286), (0x6bd7, 6802), (0x6bd8, 12287), (0x6bda, 10006), (0x6bdb, 5639),
+    (0x6bde, 11913), (0x6be0, 12288), (0x6be2, 12719), (0x6be3, 12718), (0x6be4, 12716), (0x6be6, 12715), (0x6be7, 12720), (0x6be8, 12717),
+    (0x6beb, 7693), (0x6bec, 7694), (0x6bef, 8133), (0x6bf0, 13945), (0x6bf2, 13946), (0x6bf3, 13947), (0x6bf7, 14602), (0x6bf8, 14603),
+    (0x6bf9, 14601), (0x6bfb, 14599), (0x6bfc, 14600), (0x6bfd, 8563), (0x6bfe, 15272), (0x6bff, 15849), (0x6c00, 15848), (0x6c01, 15847),
+    (0x6c02, 15850), (0x6c03, 16461), (0x6c04, 16460), (0x6c05, 9730), (0x6c06, 16462), (0x6c08, 10007), (0x6c09, 16969), (0x6c0b, 17406),
+    (0x6c0c, 17768), (0x6c0d, 18502), (0x6c0f, 5640), (0x6c10, 5748), (0x6c11, 5747), (0x6c13, 6466), (0x6c14, 11337), (0x6c15, 11366),
+    (0x6c16, 5898), (0x6c18, 11453), (0x6c19, 11627), (0x6c1a, 11628), (0x6c1b, 6467), (0x6c1d, 11914), (0x6c1f, 6803), (0x6c20, 12289),
+    (0x6c21, 12290), (0x6c23, 7207), (0x6c24, 7211), (0x6c25, 12721), (0x6c26, 7210), (0x6c27, 7208), (0x6c28, 7209), (0x6c2a, 13313),
+    (0x6c2b, 7695), (0x6c2c, 8136), (0x6c2e, 8134), (0x6c2f, 8135), (0x6c30, 13948), (0x6c33, 8999), (0x6c34, 5641), (0x6c36, 11367),
+    (0x6c38, 5749), (0x6c3b, 11370), (0x6c3e, 5752), (0x6c3f, 11369), (0x6c40, 5751), (0x6c41, 5750), (0x6c42, 6127), (0x6c43, 11368),
+    (0x6c46, 11454), (0x6c4a, 11458), (0x6c4b, 11460), (0x6c4c, 11461), (0x6c4d, 5908), (0x6c4e, 5909), (0x6c4f, 11457), (0x6c50, 5904),
+    (0x6c52, 11455), (0x6c54, 11459), (0x6c55, 5905), (0x6c57, 5900), (0x6c59, 5901), (0x6c5b, 5907), (0x6c5c, 11456), (0x6c5d, 5899),
+    (0x6c5e, 6128), (0x6c5f, 5902), (0x6c60, 5903), (0x6c61, 5906), (0x6c65, 11645), (0x6c66, 11643), (0x6c67, 11630), (0x6c68, 6140),
+    (0x6c69, 11637), (0x6c6a, 6135), (0x6c6b, 11631), (0x6c6d, 11639), (0x6c6f, 11636), (0x6c70, 6138), (0x6c71, 11635), (0x6c72, 6145),
+    (0x6c73, 11644), (0x6c74, 6147), (0x6c76, 6149), (0x6c78, 11629), (0x6c7a, 6136), (0x6c7b, 11646), (0x6c7d, 6143), (0x6c7e, 6146),
+    (0x6c80, 11937), (0x6c81, 6130), (0x6c82, 6153), (0x6c83, 6144), (0x6c84, 11632), (0x6c85, 6133), (0x6c86, 6148), (0x6c87, 11640),
+    (0x6c88, 6131), (0x6c89, 6132), (0x6c8a, 11935), (0x6c8b, 11633), (0x6c8c, 6139), (0x6c8d, 6150), (0x6c8e, 11647), (0x6c8f, 11634),
+    (0x6c90, 6137), (0x6c92, 6142), (0x6c93, 11915), (0x6c94, 6151), (0x6c95, 11641), (0x6c96, 6141), (0x6c98, 6152), (0x6c99, 6129),
+    (0x6c9a, 11638), (0x6c9b, 6134), (0x6c9c, 11642), (0x6c9d, 11936), (0x6cab, 6479), (0x6cac, 6495), (0x6cad, 11922), (0x6cae, 6486),
+    (0x6cb0, 11943), (0x6cb1, 6471), (0x6cb3, 6474), (0x6cb4, 11934), (0x6cb6, 11920), (0x6cb7, 11924), (0x6cb8, 6482), (0x6cb9, 6484),
+    (0x6cba, 11927), (0x6cbb, 6491), (0x6cbc, 6477), (0x6cbd, 6475), (0x6cbe, 6476), (0x6cbf, 6490), (0x6cc0, 11939), (0x6cc1, 6485),
+    (0x6cc2, 11926), (0x6cc3, 11928), (0x6cc4, 6483), (0x6cc5, 6488), (0x6cc6, 11929), (0x6cc7, 11942), (0x6cc9, 6804), (0x6cca, 6494),
+    (0x6ccc, 6472), (0x6ccd, 11941), (0x6ccf, 11945), (0x6cd0, 11925), (0x6cd1, 11947), (0x6cd2, 11932), (0x6cd3, 6481), (0x6cd4, 11921),
+    (0x6cd5, 6480), (0x6cd6, 6498), (0x6cd7, 6487), (0x6cd9, 11919), (0x6cda, 12299), (0x6cdb, 6493), (0x6cdc, 6497), (0x6cdd, 11933),
+    (0x6cde, 11938), (0x6ce0, 6499), (0x6ce1, 6492), (0x6ce2, 6478), (0x6ce3, 6468), (0x6ce5, 6473), (0x6ce7, 11923), (0x6ce8, 6469),
+    (0x6ce9, 11946), (0x6ceb, 11917), (0x6cec, 11916), (0x6ced, 11930), (0x6cee, 11918), (0x6cef, 6496), (0x6cf0, 7212), (0x6cf1, 6489),
+    (0x6cf2, 11931), (0x6cf3, 6470), (0x6cf5, 6819), (0x6cf9, 11944), (0x6d00, 12306), (0x6d01, 12309), (0x6d03, 12312), (0x6d04, 12301),
+    (0x6d07, 12315), (0x6d08, 12318), (0x6d09, 12320), (0x6d0a, 12298), (0x6d0b, 6805), (0x6d0c, 6810), (0x6d0d, 12726), (0x6d0e, 6826),
+    (0x6d0f, 12313), (0x6d10, 12321), (0x6d11, 12305), (0x6d12, 12297), (0x6d16, 12753), (0x6d17, 6813), (0x6d18, 12310), (0x6d19, 12302),
+    (0x6d1a, 12304), (0x6d1b, 6818), (0x6d1d, 12307), (0x6d1e, 6812), (0x6d1f, 12294), (0x6d20, 12316), (0x6d22, 12319), (0x6d25, 6809),
+    (0x6d27, 6821), (0x6d28, 12291), (0x6d29, 6823), (0x6d2a, 6807), (0x6d2b, 6827), (0x6d2c, 12317), (0x6d2d, 12293), (0x6d2e, 6824),
+    (0x6d2f, 12747), (0x6d30, 11940), (0x6d31, 6811), (0x6d32, 6806), (0x6d33, 12300), (0x6d34, 12292), (0x6d35, 6825), (0x6d36, 6817),
+    (0x6d37, 12311), (0x6d38, 6822), (0x6d39, 6820), (0x6d3a, 12303), (0x6d3b, 6814), (0x6d3c, 12295), (0x6d3d, 6815), (0x6d3e, 6816),
+    (0x6d3f, 12296), (0x6d40, 12314), (0x6d41, 6808), (0x6d42, 12308), (0x6d58, 12729), (0x6d59, 7220), (0x6d5a, 7225), (0x6d5e, 12738),
+    (0x6d5f, 12744), (0x6d60, 12740), (0x6d61, 12727), (0x6d62, 12730), (0x6d63, 12723), (0x6d64, 12724), (0x6d65, 7232), (0x6d66, 7217),
+    (0x6d67, 12739), (0x6d68, 12748), (0x6d69, 7227), (0x6d6a, 7213), (0x6d6c, 7222), (0x6d6d, 12731), (0x6d6e, 7224), (0x6d6f, 12732),
+    (0x6d70, 12742), (0x6d74, 7226), (0x6d75, 12757), (0x6d76, 12725), (0x6d77, 7219), (0x6d78, 7218), (0x6d79, 7230), (0x6d7a, 12722),
+    (0x6d7b, 12755), (0x6d7c, 12743), (0x6d7d, 12756), (0x6d7e, 12750), (0x6d7f, 12736), (0x6d80, 12751), (0x6d82, 12745), (0x6d83, 12754),
+    (0x6d84, 12752), (0x6d85, 7231), (0x6d86, 12737), (0x6d87, 7216), (0x6d88, 7215), (0x6d89, 7223), (0x6d8a, 7229), (0x6d8b, 12749),
+    (0x6d8c, 7228), (0x6d8d, 12734), (0x6d8e, 7696), (0x6d90, 12758), (0x6d91, 12733), (0x6d92, 12728), (0x6d93, 7221), (0x6d94, 7233),
+    (0x6d95, 7214), (0x6d97, 12741), (0x6d98, 12746), (0x6daa, 7730), (0x6dab, 13315), (0x6dac, 13319), (0x6dae, 7711), (0x6daf, 7709),
+    (0x6db2, 7700), (0x6db3, 13317), (0x6db4, 13316), (0x6db5, 7720), (0x6db7, 13322), (0x6db8, 7714), (0x6dba, 13340), (0x6dbb, 13352),
+    (0x6dbc, 7697), (0x6dbd, 13337), (0x6dbe, 13330), (0x6dbf, 7732), (0x6dc0, 13314), (0x6dc2, 13342), (0x6dc4, 7729), (0x6dc5, 7717),
+    (0x6dc6, 7728), (0x6dc7, 7707), (0x6dc8, 13326), (0x6dc9, 13344), (0x6dca, 13336), (0x6dcb, 7708), (0x6dcc, 7702), (0x6dcd, 13350),
+    (0x6dcf, 13343), (0x6dd0, 13345), (0x6dd1, 7710), (0x6dd2, 7718), (0x6dd3, 13347), (0x6dd4, 13324), (0x6dd5, 13341), (0x6dd6, 13329),
+    (0x6dd7, 13349), (0x6dd8, 7723), (0x6dd9, 7699), (0x6dda, 7721), (0x6ddb, 13334), (0x6ddc, 13332), (0x6ddd, 13333), (0x6dde, 7712),
+    (0x6ddf, 13328), (0x6de0, 13327), (0x6de1, 7701), (0x6de2, 13321), (0x6de3, 13351), (0x6de4, 7703), (0x6de5, 13331), (0x6de6, 7733),
+    (0x6de8, 7727), (0x6de9, 13320), (0x6dea, 7724), (0x6deb, 7722), (0x6dec, 7731), (0x6ded, 13338), (0x6dee, 7726), (0x6def, 12735),
+    (0x6df0, 13339), (0x6df1, 7725), (0x6df2, 13346), (0x6df3, 7698), (0x6df4, 13335), (0x6df5, 7716), (0x6df6, 13323), (0x6df7, 7715),
+    (0x6df9, 7713), (0x6dfa, 7705), (0x6dfb, 7704), (0x6dfc, 13949), (0x6dfd, 13348), (0x6e00, 13325), (0x6e03, 13970), (0x6e05, 7706),
+    (0x6e19, 8165), (0x6e1a, 7719), (0x6e1b, 8147), (0x6e1c, 13964), (0x6e1d, 8161), (0x6e1f, 13952), (0x6e20, 8144), (0x6e21, 8140),
+    (0x6e22, 13982), (0x6e23, 8146), (0x6e24, 8150), (0x6e25, 8145), (0x6e26, 8154), (0x6e27, 13986), (0x6e28, 13977), (0x6e2b, 13959),
+    (0x6e2c, 8159), (0x6e2d, 8153), (0x6e2e, 13971), (0x6e2f, 8137), (0x6e30, 13983), (0x6e31, 13976), (0x6e32, 8141), (0x6e33, 13965),
+    (0x6e34, 8156), (0x6e35, 13994), (0x6e36, 13995), (0x6e38, 8138), (0x6e39, 13981), (0x6e3a, 8158), (0x6e3b, 13969), (0x6e3c, 13955),
+    (0x6e3d, 13956), (0x6e3e, 8162), (0x6e3f, 13960), (0x6e40, 13967), (0x6e41, 13961), (0x6e43, 8160), (0x6e44, 8168), (0x6e45, 13957),
+    (0x6e46, 13950), (0x6e47, 13951), (0x6e49, 13953), (0x6e4a, 8143), (0x6e4b, 13966), (0x6e4d, 8157), (0x6e4e, 8166), (0x6e51, 13968),
+    (0x6e52, 13992), (0x6e53, 13984), (0x6e54, 8139), (0x6e55, 13990), (0x6e56, 8151), (0x6e58, 8149), (0x6e5a, 13996), (0x6e5b, 8148),
+    (0x6e5c, 13974), (0x6e5d, 13962), (0x6e5e, 13972), (0x6e5f, 8171), (0x6e60, 13978), (0x6e61, 13975), (0x6e62, 13958), (0x6e63, 8167),
+    (0x6e64, 13988), (0x6e65, 13985), (0x6e66, 13993), (0x6e67, 8142), (0x6e68, 13973), (0x6e69, 8170), (0x6e6b, 13980), (0x6e6e, 8152),
+    (0x6e6f, 8155), (0x6e71, 13979), (0x6e72, 8169), (0x6e73, 13963), (0x6e74, 13318), (0x6e77, 13989), (0x6e78, 13987), (0x6e79, 13991),
+    (0x6e88, 13954), (0x6e89, 8164), (0x6e8d, 14634), (0x6e8e, 14633), (0x6e8f, 14607), (0x6e90, 8569), (0x6e92, 14632), (0x6e93, 14610),
+    (0x6e94, 14611), (0x6e96, 8579), (0x6e97, 14641), (0x6e98, 8574), (0x6e99, 14631), (0x6e9b, 14604), (0x6e9c, 8580), (0x6e9d, 8570),
+    (0x6e9e, 14619), (0x6e9f, 14609), (0x6ea0, 14612), (0x6ea1, 14636), (0x6ea2, 8564), (0x6ea3, 14643), (0x6ea4, 14635), (0x6ea5, 8573),
+    (0x6ea6, 14624), (0x6ea7, 8584), (0x6eaa, 8583), (0x6eab, 8577), (0x6eae, 14642), (0x6eaf, 8565), (0x6eb0, 14622), (0x6eb1, 14613),
+    (0x6eb2, 14626), (0x6eb3, 14638), (0x6eb4, 8585), (0x6eb6, 8567), (0x6eb7, 14621), (0x6eb9, 14614), (0x6eba, 8576), (0x6ebc, 8575),
+    (0x6ebd, 14617), (0x6ebe, 14627), (0x6ebf, 14637), (0x6ec0, 14608), (0x6ec1, 14618), (0x6ec2, 8568), (0x6ec3, 14628), (0x6ec4, 8581),
+    (0x6ec5, 8572), (0x6ec6, 14615), (0x6ec7, 8571), (0x6ec8, 14606), (0x6ec9, 14620), (0x6eca, 14640), (0x6ecb, 8163), (0x6ecc, 9027),
+    (0x6ecd, 14623), (0x6ece, 15273), (0x6ecf, 14625), (0x6ed0, 14639), (0x6ed1, 8578), (0x6ed2, 14616), (0x6ed3, 8566), (0x6ed4, 8582),
+    (0x6ed5, 9405), (0x6ed6, 14605), (0x6ed8, 14630), (0x6edc, 14629), (0x6eeb, 15301), (0x6eec, 9024), (0x6eed, 15290), (0x6eee, 15295),
+    (0x6eef, 9013), (0x6ef1, 15275), (0x6ef2, 9026), (0x6ef4, 9004), (0x6ef5, 15274), (0x6ef6, 15307), (0x6ef7, 9028), (0x6ef8, 15278),
+    (0x6ef9, 15294), (0x6efb, 15280), (0x6efc, 15310), (0x6efd, 15306), (0x6efe, 9002), (0x6eff, 9012), (0x6f00, 15876), (0x6f01, 9025),
+    (0x6f02, 9010), (0x6f03, 15276), (0x6f05, 15305), (0x6f06, 9014), (0x6f07, 15302), (0x6f08, 15315), (0x6f09, 15282), (0x6f0a, 15291),
+    (0x6f0d, 15313), (0x6f0e, 15303), (0x6f0f, 9009), (0x6f12, 15289), (0x6f13, 9003), (0x6f14, 9001), (0x6f15, 9019), (0x6f18, 15287),
+    (0x6f19, 15284), (0x6f1a, 15285), (0x6f1c, 15309), (0x6f1e, 15314), (0x6f1f, 15312), (0x6f20, 9007), (0x6f21, 15316), (0x6f22, 9011),
+    (0x6f23, 9018), (0x6f25, 15277), (0x6f26, 15852), (0x6f27, 15286), (0x6f29, 9005), (0x6f2a, 9023), (0x6f2b, 9020), (0x6f2c, 9008),
+    (0x6f2d, 15296), (0x6f2e, 15281), (0x6f2f, 9021), (0x6f30, 15298), (0x6f31, 9015), (0x6f32, 9017), (0x6f33, 9000), (0x6f35, 15300),
+    (0x6f36, 15292), (0x6f37, 15279), (0x6f38, 9016), (0x6f39, 15308), (0x6f3a, 15311), (0x6f3b, 15288), (0x6f3c, 15299), (0x6f3e, 9006),
+    (0x6f3f, 9388), (0x6f40, 15297), (0x6f41, 15851), (0x6f43, 15304), (0x6f4e, 15283), (0x6f4f, 15861), (0x6f50, 15871), (0x6f51, 9391),
+    (0x6f52, 15870), (0x6f53, 15882), (0x6f54, 9393), (0x6f55, 15868), (0x6f57, 15872), (0x6f58, 9404), (0x6f5a, 15863), (0x6f5b, 9396),
+    (0x6f5d, 15875), (0x6f5e, 16470), (0x6f5f, 9408), (0x6f60, 9407), (0x6f61, 15877), (0x6f62, 15860), (0x6f63, 15887), (0x6f64, 9402),
+    (0x6f66, 9392), (0x6f67, 15880), (0x6f69, 15884), (0x6f6a, 15889), (0x6f6b, 15878), (0x6f6c, 15866), (0x6f6d, 9395), (0x6f6e, 9398),
+    (0x6f6f, 9406), (0x6f70, 9401), (0x6f72, 15869), (0x6f73, 15293), (0x6f76, 15865), (0x6f77, 15888), (0x6f78, 9397), (0x6f7a, 9400),
+    (0x6f7b, 15890), (0x6f7c, 9389), (0x6f7d, 15879), (0x6f7e, 15853), (0x6f7f, 15885), (0x6f80, 10016), (0x6f82, 15867), (0x6f84, 9390),
+    (0x6f85, 15862), (0x6f86, 9394), (0x6f87, 15854), (0x6f88, 9022), (0x6f89, 15858), (0x6f8b, 15883), (0x6f8c, 15859), (0x6f8d, 15857),
+    (0x6f8e, 9399), (0x6f90, 15881), (0x6f92, 15856), (0x6f93, 15874), (0x6f94, 15873), (0x6f95, 15886), (0x6f96, 15864), (0x6f97, 9403),
+    (0x6f9e, 16473), (0x6fa0, 9743), (0x6fa1, 9733), (0x6fa2, 16485), (0x6fa3, 16465), (0x6fa4, 9735), (0x6fa5, 16477), (0x6fa6, 9742),
+    (0x6fa7, 9737), (0x6fa8, 16475), (0x6fa9, 16971), (0x6faa, 16481), (0x6fab, 16487), (0x6fac, 16480), (0x6fad, 16463), (0x6fae, 16478),
+    (0x6faf, 16489), (0x6fb0, 16491), (0x6fb1, 9732), (0x6fb2, 16490), (0x6fb3, 9738), (0x6fb4, 9744), (0x6fb6, 9741), (0x6fb8, 16484),
+    (0x6fb9, 9740), (0x6fba, 16479), (0x6fbc, 16467), (0x6fbd, 16472), (0x6fbf, 16483), (0x6fc0, 9739), (0x6fc1, 9736), (0x6fc2, 9731),
+    (0x6fc3, 9734), (0x6fc4, 16471), (0x6fc6, 15855), (0x6fc7, 16466), (0x6fc8, 16469), (0x6fc9, 16486), (0x6fca, 16474), (0x6fcb, 16464),
+    (0x6fcc, 16970), (0x6fcd, 16488), (0x6fce, 16468), (0x6fcf, 16482), (0x6fd4, 16973), (0x6fd5, 10020), (0x6fd8, 10008), (0x6fdb, 10012),
+    (0x6fdc, 16975), (0x6fdd, 16981), (0x6fde, 16979), (0x6fdf, 10010), (0x6fe0, 10011), (0x6fe1, 10018), (0x6fe2, 16982), (0x6fe3, 16974),
+    (0x6fe4, 10013), (0x6fe6, 16978), (0x6fe7, 16977), (0x6fe8, 16983), (0x6fe9, 10019), (0x6feb, 10014), (0x6fec, 10017), (0x6fed, 16976),
+    (0x6fee, 10021), (0x6fef, 10015), (0x6ff0, 10022), (0x6ff1, 10009), (0x6ff2, 16980), (0x6ff4, 16972), (0x6ff7, 17419), (0x6ffa, 10254),
+    (0x6ffb, 17416), (0x6ffc, 17418), (0x6ffe, 10252), (0x6fff, 17414), (0x7000, 17415), (0x7001, 17410), (0x7004, 16476), (0x7005, 17411),
+    (0x7006, 10253), (0x7007, 17407), (0x7009, 10250), (0x700a, 17420), (0x700b, 10251), (0x700c, 17408), (0x700d, 17409), (0x700e, 17413),
+    (0x700f, 10256), (0x7011, 10255), (0x7014, 17412), (0x7015, 10418), (0x7016, 17772), (0x7017, 17778), (0x7018, 10419), (0x7019, 17769),
+    (0x701a, 10416), (0x701b, 10413), (0x701c, 17780), (0x701d, 10417), (0x701f, 10414), (0x7020, 17771), (0x7021, 17774), (0x7022, 17775),
+    (0x7023, 17776), (0x7024, 17779), (0x7026, 17417), (0x7027, 17770), (0x7028, 10415), (0x7029, 17777), (0x702a, 17781), (0x702b, 17773),
+    (0x702f, 18084), (0x7030, 10561), (0x7031, 18087), (0x7032, 10562), (0x7033, 18095), (0x7034, 18086), (0x7035, 18083), (0x7037, 18085),
+    (0x7038, 18089), (0x7039, 18092), (0x703a, 18091), (0x703b, 18094), (0x703c, 18082), (0x703e, 10560), (0x703f, 18090), (0x7040, 18093),
+    (0x7041, 18096), (0x7042, 18088), (0x7043, 18325), (0x7044, 18326), (0x7045, 18330), (0x7046, 18331), (0x7048, 18328), (0x7049, 18329),
+    (0x704a, 18327), (0x704c, 10654), (0x7051, 10732), (0x7052, 18506), (0x7055, 18503), (0x7056, 18504), (0x7057, 18505), (0x7058, 10733),
+    (0x705a, 18657), (0x705b, 18656), (0x705d, 18760), (0x705e, 10822), (0x705f, 18758), (0x7060, 18761), (0x7061, 18759), (0x7062, 18830),
+    (0x7063, 10856), (0x7064, 10870), (0x7065, 18905), (0x7066, 18884), (0x7068, 18904), (0x7069, 18950), (0x706a, 18951), (0x706b, 5642),
+    (0x7070, 5910), (0x7071, 11462), (0x7074, 11648), (0x7076, 6154), (0x7078, 6157), (0x707a, 11649), (0x707c, 6155), (0x707d, 6156),
+    (0x7082, 11956), (0x7083, 11958), (0x7084, 11953), (0x7085, 11950), (0x7086, 11952), (0x708a, 6503), (0x708e, 6501), (0x7091, 11954),
+    (0x7092, 6502), (0x7093, 11951), (0x7094, 11948), (0x7095, 6500), (0x7096, 11955), (0x7098, 11949), (0x7099, 6504), (0x709a, 11957),
+    (0x709f, 12323), (0x70a1, 12327), (0x70a4, 6836), (0x70a9, 12330), (0x70ab, 6828), (0x70ac, 6831), (0x70ad, 6833), (0x70ae, 6835),
+    (0x70af, 6832), (0x70b0, 12326), (0x70b1, 12325), (0x70b3, 6830), (0x70b4, 12328), (0x70b5, 12329), (0x70b7, 12322), (0x70b8, 6834),
+    (0x70ba, 6829), (0x70be, 12324), (0x70c5, 12772), (0x70c6, 12773), (0x70c7, 12774), (0x70c8, 7238), (0x70ca, 7234), (0x70cb, 12763),
+    (0x70cd, 12771), (0x70ce, 12776), (0x70cf, 7239), (0x70d1, 12761), (0x70d2, 12767), (0x70d3, 12760), (0x70d4, 12770), (0x70d7, 12766),
+    (0x70d8, 7235), (0x70d9, 7237), (0x70da, 12775), (0x70dc, 12759), (0x70dd, 12762), (0x70de, 12768), (0x70e0, 12769), (0x70e1, 12777),
+    (0x70e2, 12765), (0x70e4, 7236), (0x70ef, 7738), (0x70f0, 13359), (0x70f3, 13361), (0x70f4, 13357), (0x70f6, 13369), (0x70f7, 13355),
+    (0x70f8, 13368), (0x70f9, 7734), (0x70fa, 13353), (0x70fb, 14000), (0x70fc, 13363), (0x70fd, 7737), (0x70ff, 13364), (0x7100, 13367),
+    (0x7102, 13371), (0x7104, 13360), (0x7106, 13365), (0x7109, 7735), (0x710a, 7736), (0x710b, 13370), (0x710c, 13358), (0x710d, 13354),
+    (0x710e, 13372), (0x7110, 13362), (0x7113, 13366), (0x7117, 13356), (0x7119, 8172), (0x711a, 8173), (0x711b, 14010), (0x711c, 8179),
+    (0x711e, 13998), (0x711f, 14007), (0x7120, 13997), (0x7121, 8176), (0x7122, 14005), (0x7123, 14003), (0x7125, 14004), (0x7126, 8174),
+    (0x7128, 14008), (0x712e, 14001), (0x712f, 13999), (0x7130, 8175), (0x7131, 14002), (0x7132, 14006), (0x7136, 8177), (0x713a, 14009),
+    (0x7141, 14649), (0x7142, 14656), (0x7143, 14658), (0x7144, 14664), (0x7146, 8598), (0x7147, 14644), (0x7149, 8590), (0x714b, 14659),
+    (0x714c, 8595), (0x714d, 14665), (0x714e, 8586), (0x7150, 14662), (0x7152, 14646), (0x7153, 14663), (0x7154, 14645), (0x7156, 8600),
+    (0x7158, 14657), (0x7159, 8587), (0x715a, 14666), (0x715c, 8592), (0x715d, 14650), (0x715e, 8597), (0x715f, 14661), (0x7160, 14648),
+    (0x7161, 14655), (0x7162, 14651), (0x7163, 14647), (0x7164, 8589), (0x7165, 8596), (0x7166, 8594), (0x7167, 8591), (0x7168, 8599),
+    (0x7169, 8588), (0x716a, 14654), (0x716c, 8593), (0x716e, 8178), (0x7170, 14660), (0x7172, 14652), (0x7178, 14653), (0x717b, 15324),
+    (0x717d, 9031), (0x7180, 15320), (0x7181, 15326), (0x7182, 15322), (0x7184, 9033), (0x7185, 15321), (0x7186, 15325), (0x7187, 15317),
+    (0x7189, 15319), (0x718a, 9032), (0x718f, 15323), (0x7190, 15318), (0x7192, 9034), (0x7194, 9029), (0x7197, 15327), (0x7199, 9030),
+    (0x719a, 15896), (0x719b, 15893), (0x719c, 15905), (0x719d, 15899), (0x719e, 15901), (0x719f, 9409), (0x71a0, 15895), (0x71a1, 15903),
+    (0x71a4, 15902), (0x71a5, 15900), (0x71a7, 15906), (0x71a8, 9412), (0x71a9, 15897), (0x71aa, 15904), (0x71ac, 9410), (0x71af, 15892),
+    (0x71b0, 15894), (0x71b1, 9411), (0x71b2, 15891), (0x71b3, 15907), (0x71b5, 15898), (0x71b8, 16495), (0x71b9, 9751), (0x71bc, 16506),
+    (0x71bd, 16504), (0x71be, 9745), (0x71bf, 16494), (0x71c0, 16497), (0x71c1, 16498), (0x71c2, 16493), (0x71c3, 9755), (0x71c4, 9756),
+    (0x71c5, 16492), (0x71c6, 16507), (0x71c7, 16502), (0x71c8, 9749), (0x71c9, 9746), (0x71ca, 16501), (0x71cb, 16499), (0x71ce, 9752),
+    (0x71cf, 16503), (0x71d0, 9747), (0x71d2, 9748), (0x71d4, 16500), (0x71d5, 9750), (0x71d6, 16496), (0x71d8, 16505), (0x71d9, 9753),
+    (0x71da, 16508), (0x71db, 16509), (0x71dc, 9754), (0x71df, 10024), (0x71e0, 10031), (0x71e1, 16984), (0x71e2, 16990), (0x71e4, 16988),
+    (0x71e5, 10027), (0x71e6, 10026), (0x71e7, 10023), (0x71e8, 16986), (0x71ec, 10029), (0x71ed, 10028), (0x71ee, 10025), (0x71f0, 16989),
+    (0x71f1, 16985), (0x71f2, 16987), (0x71f4, 10030), (0x71f8, 10260), (0x71f9, 17423), (0x71fb, 10257), (0x71fc, 10258), (0x71fd, 17425),
+    (0x71fe, 10259), (0x71ff, 17422), (0x7201, 17421), (0x7202, 17785), (0x7203, 17424), (0x7205, 17786), (0x7206, 10420), (0x7207, 17784),
+    (0x720a, 17783), (0x720c, 17782), (0x720d, 10421), (0x7210, 10563), (0x7213, 18097), (0x7214, 18098), (0x7219, 18334), (0x721a, 18333),
+    (0x721b, 10655), (0x721d, 18332), (0x721e, 18507), (0x721f, 18508), (0x7222, 18658), (0x7223, 18762), (0x7226, 18831), (0x7227, 18930),
+    (0x7228, 10890), (0x7229, 18940), (0x722a, 5643), (0x722c, 6505), (0x722d, 6506), (0x7230, 6837), (0x7235, 10032), (0x7236, 5644),
+    (0x7238, 6507), (0x7239, 7240), (0x723a, 8601), (0x723b, 5645), (0x723d, 7739), (0x723e, 9035), (0x723f, 11338), (0x7241, 12331),
+    (0x7242, 12778), (0x7244, 15328), (0x7246, 10033), (0x7247, 5646), (0x7248, 6508), (0x7249, 12332), (0x724a, 12333), (0x724b, 14011),
+    (0x724c, 8180), (0x724f, 14667), (0x7252, 8602), (0x7253, 15329), (0x7256, 9413), (0x7258, 10422), (0x7259, 5647), (0x725a, 14012),
+    (0x725b, 5648), (0x725d, 5912), (0x725e, 11463), (0x725f, 5911), (0x7260, 6160), (0x7261, 6159), (0x7262, 6158), (0x7263, 11650),
+    (0x7267, 6509), (0x7269, 6510), (0x726a, 11959), (0x726c, 12334), (0x726e, 12337), (0x726f, 6839), (0x7270, 12335), (0x7272, 6838),
+    (0x7273, 12336), (0x7274, 6840), (0x7276, 12781), (0x7277, 12780), (0x7278, 12779), (0x7279, 7241), (0x727b, 13374), (0x727c, 13375),
+    (0x727d, 7740), (0x727e, 13373), (0x727f, 13376), (0x7280, 8182), (0x7281, 7741), (0x7284, 8181), (0x7285, 14016), (0x7286, 14015),
+    (0x7288, 14013), (0x7289, 14014), (0x728b, 14017), (0x728c, 14669), (0x728d, 14668), (0x728e, 14672), (0x7290, 14671), (0x7291, 14670),
+    (0x7292, 9036), (0x7293, 15332), (0x7295, 15331), (0x7296, 9037), (0x7297, 15330), (0x7298, 15908), (0x729a, 15909), (0x729b, 9414),
+    (0x729d, 16510), (0x729e, 16511), (0x72a1, 17791), (0x72a2, 10423), (0x72a3, 17790), (0x72a4, 17789), (0x72a5, 17787), (0x72a6, 17788),
+    (0x72a7, 10656), (0x72a8, 18099), (0x72a9, 18509), (0x72aa, 18832), (0x72ac, 5649), (0x72ae, 11371), (0x72af, 5753), (0x72b0, 11372),
+    (0x72b4, 11464), (0x72b5, 11465), (0x72ba, 11656), (0x72bd, 11652), (0x72bf, 11651), (0x72c0, 6511), (0x72c1, 11655), (0x72c2, 6162),
+    (0x72c3, 11653), (0x72c4, 6161), (0x72c5, 11657), (0x72c6, 11654), (0x72c9, 11963), (0x72ca, 12338), (0x72cb, 11961), (0x72cc, 11968),
+    (0x72ce, 6512), (0x72d0, 6515), (0x72d1, 11969), (0x72d2, 11965), (0x72d4, 11966), (0x72d6, 11960), (0x72d7, 6514), (0x72d8, 11962),
+    (0x72d9, 6513), (0x72da, 11967), (0x72dc, 11964), (0x72df, 12342), (0x72e0, 6842), (0x72e1, 6843), (0x72e3, 12345), (0x72e4, 12339),
+    (0x72e6, 12344), (0x72e8, 12340), (0x72e9, 6841), (0x72ea, 12343), (0x72eb, 12341), (0x72f3, 12787), (0x72f4, 12784), (0x72f6, 12786),
+    (0x72f7, 7246), (0x72f8, 7245), (0x72f9, 7243), (0x72fa, 12783), (0x72fb, 12788), (0x72fc, 7242), (0x72fd, 7244), (0x72fe, 12785),
+    (0x72ff, 13384), (0x7300, 12782), (0x7301, 12789), (0x7307, 13379), (0x7308, 13383), (0x730a, 13382), (0x730b, 14019), (0x730c, 14030),
+    (0x730f, 13385), (0x7311, 13380), (0x7312, 14018), (0x7313, 7745), (0x7316, 7744), (0x7317, 13378), (0x7318, 13381), (0x7319, 7746),
+    (0x731b, 7743), (0x731c, 7742), (0x731d, 13377), (0x731e, 13386), (0x7322, 14021), (0x7323, 14028), (0x7325, 8184), (0x7326, 14027),
+    (0x7327, 14024), (0x7329, 8186), (0x732d, 14026), (0x7330, 14020), (0x7331, 14022), (0x7332, 14025), (0x7333, 14023), (0x7334, 8185),
+    (0x7335, 14029), (0x7336, 8183), (0x7337, 8603), (0x733a, 14676), (0x733b, 14675), (0x733c, 14673), (0x733e, 8606), (0x733f, 8605),
+    (0x7340, 14677), (0x7342, 14674), (0x7343, 15333), (0x7344, 9038), (0x7345, 8604), (0x7349, 14679), (0x734a, 14678), (0x734c, 15336),
+    (0x734d, 15334), (0x734e, 9415), (0x7350, 9039), (0x7351, 15335), (0x7352, 15911), (0x7357, 9416), (0x7358, 15910), (0x7359, 15919),
+    (0x735a, 15918), (0x735b, 15916), (0x735d, 15915), (0x735e, 15912), (0x735f, 15913), (0x7360, 15914), (0x7361, 15917), (0x7362, 15920),
+    (0x7365, 16516), (0x7366, 16513), (0x7367, 16514), (0x7368, 9757), (0x7369, 16512), (0x736a, 16518), (0x736b, 16517), (0x736c, 16515),
+    (0x736e, 16992), (0x736f, 16993), (0x7370, 10034), (0x7372, 10035), (0x7373, 16991), (0x7375, 10262), (0x7376, 17426), (0x7377, 10261),
+    (0x7378, 10424), (0x737a, 10425), (0x737b, 10564), (0x737c, 18101), (0x737d, 18100), (0x737e, 18335), (0x737f, 18510), (0x7380, 10734),
+    (0x7381, 18660), (0x7382, 18659), (0x7383, 18661), (0x7384, 5754), (0x7385, 12346), (0x7386, 7247), (0x7387, 7747), (0x7388, 13387),
+    (0x7389, 5755), (0x738a, 11373), (0x738b, 5650), (0x738e, 11466), (0x7392, 11662), (0x7393, 11660), (0x7394, 11661), (0x7395, 11658),
+    (0x7396, 6163), (0x7397, 11659), (0x739d, 11977), (0x739f, 6518), (0x73a0, 11975), (0x73a1, 11971), (0x73a2, 11974), (0x73a4, 11970),
+    (0x73a5, 6520), (0x73a6, 11973), (0x73a8, 6517), (0x73a9, 6516), (0x73ab, 6519), (0x73ac, 11976), (0x73ad, 11972), (0x73b2, 6847),
+    (0x73b3, 6850), (0x73b4, 12354), (0x73b5, 12353), (0x73b6, 12352), (0x73b7, 6844), (0x73b8, 12361), (0x73b9, 12351), (0x73bb, 6846),
+    (0x73bc, 12794), (0x73be, 12358), (0x73bf, 12356), (0x73c0, 6849), (0x73c2, 12348), (0x73c3, 12359), (0x73c5, 12350), (0x73c6, 12360),
+    (0x73c7, 12357), (0x73c8, 12349), (0x73ca, 6845), (0x73cb, 12362), (0x73cc, 12347), (0x73cd, 6848), (0x73d2, 12799), (0x73d3, 12790),
+    (0x73d4, 12801), (0x73d6, 12793), (0x73d7, 12804), (0x73d8, 12805), (0x73d9, 12791), (0x73da, 12803), (0x73db, 12800), (0x73dc, 12798),
+    (0x73dd, 12802), (0x73de, 7253), (0x73e0, 7251), (0x73e3, 12796), (0x73e5, 12792), (0x73e7, 12795), (0x73e8, 12806), (0x73e9, 12797),
+    (0x73ea, 7252), (0x73eb, 12355), (0x73ed, 7248), (0x73ee, 7250), (0x73f4, 13401), (0x73f5, 13390), (0x73f6, 13388), (0x73f8, 13389),
+    (0x73fa, 13396), (0x73fc, 13397), (0x73fd, 13393), (0x73fe, 7752), (0x73ff, 13398), (0x7400, 13395), (0x7401, 13392), (0x7403, 7750),
+    (0x7404, 13391), (0x7405, 7748), (0x7406, 7751), (0x7407, 13394), (0x7408, 13402), (0x7409, 7249), (0x740a, 7749), (0x740b, 13400),
+    (0x740c, 13399), (0x740d, 7753), (0x7416, 14035), (0x741a, 14036), (0x741b, 8196), (0x741d, 14042), (0x7420, 14044), (0x7421, 14037),
+    (0x7422, 8190), (0x7423, 14041), (0x7424, 14040), (0x7425, 8191), (0x7426, 8197), (0x7428, 8198), (0x7429, 14043), (0x742a, 8188),
+    (0x742b, 14034), (0x742c, 14032), (0x742d, 14038), (0x742e, 14031), (0x742f, 8195), (0x7430, 14033), (0x7431, 14039), (0x7432, 14045),
+    (0x7433, 8189), (0x7434, 8194), (0x7435, 8192), (0x7436, 8193), (0x743a, 8187), (0x743f, 8613), (0x7440, 14686), (0x7441, 8612),
+    (0x7442, 14690), (0x7444, 14680), (0x7446, 14691), (0x744a, 14681), (0x744b, 14682), (0x744d, 14692), (0x744e, 14689), (0x744f, 14687),
+    (0x7450, 14688), (0x7451, 14684), (0x7452, 14683), (0x7454, 14693), (0x7455, 8609), (0x7457, 14685), (0x7459, 8614), (0x745a, 8608),
+    (0x745b, 8615), (0x745c, 8616), (0x745e, 8611), (0x745f, 8610), (0x7462, 15337), (0x7463, 9041), (0x7464, 9040), (0x7467, 15342),
+    (0x7469, 9417), (0x746a, 9042), (0x746d, 9044), (0x746e, 15343), (0x746f, 8607), (0x7470, 9043), (0x7471, 15339), (0x7472, 15341),
+    (0x7473, 15338), (0x7475, 15340), (0x7479, 15930), (0x747c, 15929), (0x747d, 15926), (0x747e, 9420), (0x747f, 16519), (0x7480, 9421),
+    (0x7481, 15925), (0x7483, 9419), (0x7485, 15927), (0x7486, 15924), (0x7487, 15921), (0x7488, 15928), (0x7489, 15922), (0x748a, 15923),
+    (0x748b, 9418), (0x7490, 16997), (0x7492, 16523), (0x7494, 16522), (0x7495, 16524), (0x7497, 16994), (0x7498, 9760), (0x749a, 16520),
+    (0x749c, 9758), (0x749e, 9762), (0x749f, 9761), (0x74a0, 16521), (0x74a1, 16525), (0x74a3, 9759), (0x74a5, 17001), (0x74a6, 10038),
+    (0x74a7, 10263), (0x74a8, 10039), (0x74a9, 10036), (0x74aa, 16998), (0x74ab, 16996), (0x74ad, 16999), (0x74af, 17002), (0x74b0, 10037),
+    (0x74b1, 17000), (0x74b2, 16995), (0x74b5, 17429), (0x74b6, 17432), (0x74b7, 17794), (0x74b8, 17427), (0x74ba, 18102), (0x74bb, 17433),
+    (0x74bd, 10426), (0x74be, 17431), (0x74bf, 10264), (0x74c0, 17428), (0x74c1, 17430), (0x74c2, 17434), (0x74c3, 17795), (0x74c5, 17793),
+    (0x74ca, 10427), (0x74cb, 17792), (0x74cf, 10565), (0x74d4, 10658), (0x74d5, 18512), (0x74d6, 10657), (0x74d7, 18514), (0x74d8, 18511),
+    (0x74d9, 18513), (0x74da, 10786), (0x74db, 18763), (0x74dc, 5756), (0x74dd, 11978), (0x74de, 12807), (0x74df, 12808), (0x74e0, 7754),
+    (0x74e1, 14694), (0x74e2, 9763), (0x74e3, 10428), (0x74e4, 10735), (0x74e5, 18764), (0x74e6, 5757), (0x74e8, 11979), (0x74e9, 5213),
+    (0x74ec, 12363), (0x74ee, 12364), (0x74f4, 12809), (0x74f5, 12810), (0x74f6, 7755), (0x74f7, 7756), (0x74fb, 14046), (0x74fd, 14697),
+    (0x74fe, 14696), (0x74ff, 14695), (0x7500, 15344), (0x7502, 15345), (0x7503, 15346), (0x7504, 9045), (0x7507, 15932), (0x7508, 15931),
+    (0x750b, 16526), (0x750c, 9764), (0x750d, 9765), (0x750f, 17006), (0x7510, 17003), (0x7511, 17004), (0x7512, 17005), (0x7513, 17436),
+    (0x7514, 17435), (0x7515, 10265), (0x7516, 17796), (0x7517, 18336), (0x7518, 5758), (0x751a, 6851), (0x751c, 7757), (0x751d, 14698),
+    (0x751f, 5759), (0x7521, 12811), (0x7522, 7758), (0x7525, 8199), (0x7526, 8200), (0x7528, 5760), (0x7529, 5761), (0x752a, 11467),
+    (0x752b, 6165), (0x752c, 6164), (0x752d, 6852), (0x752e, 12365), (0x752f, 14047), (0x7530, 5762), (0x7531, 5763), (0x7532, 5764),
+    (0x7533, 5765), (0x7537, 6166), (0x7538, 6167), (0x7539, 11664), (0x753a, 11663), (0x753d, 6521), (0x753e, 11982), (0x753f, 11980),
+    (0x7540, 11981), (0x7547, 12366), (0x7548, 12367), (0x754b, 6856), (0x754c, 6854), (0x754e, 6855), (0x754f, 6853), (0x7554, 7254),
+    (0x7559, 7258), (0x755a, 7257), (0x755b, 12812), (0x755c, 7256), (0x755d, 7255), (0x755f, 12813), (0x7562, 7761), (0x7563, 13404),
+    (0x7564, 13403), (0x7565, 7759), (0x7566, 7760), (0x756a, 8202), (0x756b, 8201), (0x756c, 14049), (0x756f, 14048), (0x7570, 7762),
+    (0x7576, 8617), (0x7577, 14700), (0x7578, 8618), (0x7579, 14699), (0x757d, 15347), (0x757e, 15933), (0x757f, 9422), (0x7580, 16527),
+    (0x7584, 17007), (0x7586, 10430), (0x7587, 10429), (0x758a, 10736), (0x758b, 5766), (0x758c, 11983), (0x758f, 7763), (0x7590, 15348),
+    (0x7591, 9046), (0x7594, 11665), (0x7595, 11666), (0x7598, 11984), (0x7599, 6523), (0x759a, 6524), (0x759d, 6522), (0x75a2, 6860),
+    (0x75a3, 6861), (0x75a4, 6858), (0x75a5, 6859), (0x75a7, 12368), (0x75aa, 12369), (0x75ab, 6857), (0x75b0, 12814), (0x75b2, 7262),
+    (0x75b3, 7263), (0x75b5, 7766), (0x75b6, 12820), (0x75b8, 7268), (0x75b9, 7266), (0x75ba, 12821), (0x75bb, 12816), (0x75bc, 7265),
+    (0x75bd, 7264), (0x75be, 7259), (0x75bf, 12819), (0x75c0, 12818), (0x75c1, 12815), (0x75c2, 7267), (0x75c4, 12817), (0x75c5, 7260),
+    (0x75c7, 7261), (0x75ca, 7767), (0x75cb, 13408), (0x75cc, 13409), (0x75cd, 7768), (0x75ce, 13405), (0x75cf, 13407), (0x75d0, 13411),
+    (0x75d1, 13410), (0x75d2, 13406), (0x75d4, 7764), (0x75d5, 7765), (0x75d7, 14057), (0x75d8, 8207), (0x75d9, 8206), (0x75da, 14051),
+    (0x75db, 8204), (0x75dd, 14054), (0x75de, 8208), (0x75df, 14055), (0x75e0, 8209), (0x75e1, 14052), (0x75e2, 8203), (0x75e3, 8205),
+    (0x75e4, 14056), (0x75e6, 14053), (0x75e7, 14050), (0x75ed, 14713), (0x75ef, 14702), (0x75f0, 8620), (0x75f1, 8623), (0x75f2, 8622),
+    (0x75f3, 8627), (0x75f4, 8626), (0x75f5, 14714), (0x75f6, 14712), (0x75f7, 14705), (0x75f8, 14709), (0x75f9, 14708), (0x75fa, 8624),
+    (0x75fb, 14711), (0x75fc, 14707), (0x75fd, 14715), (0x75fe, 14706), (0x75ff, 8625), (0x7600, 8619), (0x7601, 8621), (0x7603, 14704),
+    (0x7608, 15350), (0x7609, 9050), (0x760a, 15354), (0x760b, 9049), (0x760c, 15351), (0x760d, 9048), (0x760f, 14703), (0x7610, 14710),
+    (0x7611, 15353), (0x7613, 9051), (0x7614, 15355), (0x7615, 15352), (0x7616, 15349), (0x7619, 15936), (0x761a, 15940), (0x761b, 15942),
+    (0x761c, 15938), (0x761d, 15937), (0x761e, 15935), (0x761f, 9425), (0x7620, 9423), (0x7621, 9428), (0x7622, 9429), (0x7623, 15939),
+    (0x7624, 9426), (0x7625, 15934), (0x7626, 9427), (0x7627, 9047), (0x7628, 15941), (0x7629, 9424), (0x762d, 16529), (0x762f, 16528),
+    (0x7630, 16536), (0x7631, 16530), (0x7632, 16535), (0x7633, 16532), (0x7634, 9766), (0x7635, 16534), (0x7638, 9767), (0x763a, 9768),
+    (0x763c, 16533), (0x763d, 16531), (0x7642, 10041), (0x7643, 17008), (0x7646, 10040), (0x7647, 17011), (0x7648, 17009), (0x7649, 17010),
+    (0x764c, 10042), (0x7650, 17440), (0x7652, 10268), (0x7653, 17441), (0x7656, 10266), (0x7657, 17442), (0x7658, 10267), (0x7659, 17439),
+    (0x765a, 17443), (0x765c, 17437), (0x765f, 10431), (0x7660, 17797), (0x7661, 10432), (0x7662, 10566), (0x7664, 17438), (0x7665, 10567),
+    (0x7669, 10659), (0x766a, 18337), (0x766c, 10738), (0x766d, 18515), (0x766e, 10737), (0x7670, 18662), (0x7671, 10823), (0x7672, 10824),
+    (0x7675, 18946), (0x7678, 6862), (0x7679, 12370), (0x767b, 8210), (0x767c, 8211), (0x767d, 5767), (0x767e, 5913), (0x767f, 11468),
+    (0x7681, 11667), (0x7682, 6168), (0x7684, 6525), (0x7686, 6863), (0x7687, 6864), (0x7688, 6865), (0x7689, 13413), (0x768a, 12822),
+    (0x768b, 7269), (0x768e, 7769), (0x768f, 13412), (0x7692, 14059), (0x7693, 8213), (0x7695, 14058), (0x7696, 8212), (0x7699, 14716),
+    (0x769a, 9430), (0x769b, 15946), (0x769c, 15943), (0x769d, 15944), (0x769e, 15945), (0x76a4, 17012), (0x76a6, 17444), (0x76aa, 18104),
+    (0x76ab, 18103), (0x76ad, 18516), (0x76ae, 5768), (0x76af, 11985), (0x76b0, 7270), (0x76b4, 8214), (0x76b5, 14717), (0x76b8, 15356),
+    (0x76ba, 9431), (0x76bb, 16537), (0x76bd, 17445), (0x76be, 18105), (0x76bf, 5769), (0x76c2, 6526), (0x76c3, 6868), (0x76c4, 12371),
+    (0x76c5, 6869), (0x76c6, 6867), (0x76c8, 6866), (0x76c9, 12823), (0x76ca, 7271), (0x76cd, 7272), (0x76ce, 7273), (0x76d2, 7771),
+    (0x76d3, 13414), (0x76d4, 7770), (0x76da, 14060), (0x76db, 7772), (0x76dc, 8215), (0x76dd, 14718), (0x76de, 8628), (0x76df, 8629),
+    (0x76e1, 9052), (0x76e3, 9053), (0x76e4, 9432), (0x76e5, 9770), (0x76e6, 16538), (0x76e7, 9769), (0x76e9, 17013), (0x76ea, 10043),
+    (0x76ec, 17446), (0x76ed, 18106), (0x76ee, 5770), (0x76ef, 6169), (0x76f0, 11988), (0x76f1, 11987), (0x76f2, 6527), (0x76f3, 11986),
+    (0x76f4, 6528), (0x76f5, 11989), (0x76f7, 12377), (0x76f8, 6872), (0x76f9, 6871), (0x76fa, 12379), (0x76fb, 12378), (0x76fc, 6876),
+    (0x76fe, 6875), (0x7701, 6870), (0x7703, 12373), (0x7704, 12374), (0x7705, 12375), (0x7707, 6877), (0x7708, 12372), (0x7709, 6873),
+    (0x770a, 12376), (0x770b, 6874), (0x7710, 12826), (0x7711, 12830), (0x7712, 12828), (0x7713, 12827), (0x7715, 12831), (0x7719, 12832),
+    (0x771a, 12833), (0x771b, 12825), (0x771d, 12824), (0x771f, 7275), (0x7720, 7276), (0x7722, 12834), (0x7723, 12829), (0x7725, 13423),
+    (0x7727, 12835), (0x7728, 7277), (0x7729, 7274), (0x772d, 13417), (0x772f, 13416), (0x7731, 13418), (0x7732, 13419), (0x7733, 13421),
+    (0x7734, 13420), (0x7735, 13425), (0x7736, 7776), (0x7737, 7773), (0x7738, 7777), (0x7739, 13415), (0x773a, 7778), (0x773b, 13424),
+    (0x773c, 7775), (0x773d, 13422), (0x773e, 7774), (0x7744, 14063), (0x7745, 14065), (0x7746, 14061), (0x7747, 14062), (0x774a, 14066),
+    (0x774b, 14068), (0x774c, 14069), (0x774d, 14064), (0x774e, 14067), (0x774f, 8216), (0x7752, 14722), (0x7754, 14727), (0x7755, 14719),
+    (0x7756, 14723), (0x7759, 14728), (0x775a, 14724), (0x775b, 8630), (0x775c, 8638), (0x775e, 8633), (0x775f, 14720), (0x7760, 14721),
+    (0x7761, 9057), (0x7762, 8641), (0x7763, 8634), (0x7765, 8639), (0x7766, 8632), (0x7767, 14726), (0x7768, 8640), (0x7769, 14725),
+    (0x776a, 8636), (0x776b, 8631), (0x776c, 8637), (0x776d, 14729), (0x776e, 15361), (0x776f, 15363), (0x7779, 8635), (0x777c, 15358),
+    (0x777d, 9055), (0x777e, 15364), (0x777f, 9056), (0x7780, 15362), (0x7781, 15357), (0x7782, 15360), (0x7783, 15365), (0x7784, 9054),
+    (0x7785, 15359), (0x7787, 9434), (0x7788, 15950), (0x7789, 15949), (0x778b, 9437), (0x778c, 9435), (0x778d, 15947), (0x778e, 9433),
+    (0x778f, 15948), (0x7791, 9436), (0x7795, 16546), (0x7797, 16548), (0x7799, 16547), (0x779a, 16539), (0x779b, 16543), (0x779c, 16542),
+    (0x779d, 16540), (0x779e, 9772), (0x779f, 9773), (0x77a0, 9771), (0x77a1, 16541), (0x77a2, 16544), (0x77a3, 16545), (0x77a5, 9774),
+    (0x77a7, 10048), (0x77a8, 17021), (0x77aa, 10045), (0x77ab, 17015), (0x77ac, 10047), (0x77ad, 10049), (0x77b0, 10046), (0x77b1, 17020),
+    (0x77b2, 17016), (0x77b3, 10044), (0x77b4, 17019), (0x77b5, 17014), (0x77b6, 17018), (0x77b7, 17017), (0x77ba, 17448), (0x77bb, 10271),
+    (0x77bc, 10272), (0x77bd, 10269), (0x77bf, 10270), (0x77c2, 17447), (0x77c4, 17800), (0x77c7, 10433), (0x77c9, 17798), (0x77ca, 17799),
+    (0x77cc, 18107), (0x77cd, 18110), (0x77ce, 18108), (0x77cf, 18109), (0x77d0, 18338), (0x77d3, 10660), (0x77d4, 18663), (0x77d5, 18765),
+    (0x77d7, 10825), (0x77d8, 18833), (0x77d9, 18834), (0x77da, 10871), (0x77db, 5771), (0x77dc, 6878), (0x77de, 14070), (0x77e0, 14730),
+    (0x77e2, 5772), (0x77e3, 6170), (0x77e5, 6529), (0x77e7, 12380), (0x77e8, 12381), (0x77e9, 7278), (0x77ec, 14071), (0x77ed, 8217),
+    (0x77ee, 8642), (0x77ef, 10050), (0x77f0, 17022), (0x77f1, 17801), (0x77f2, 18111), (0x77f3, 5773), (0x77f7, 11995), (0x77f8, 11990),
+    (0x77f9, 11992), (0x77fa, 11994), (0x77fb, 11993), (0x77fc, 11991), (0x77fd, 6530), (0x7802, 6879), (0x7803, 12390), (0x7805, 12385),
+    (0x7806, 12382), (0x7809, 12389), (0x780c, 6881), (0x780d, 6882), (0x780e, 12388), (0x780f, 12387), (0x7810, 12386), (0x7811, 12383),
+    (0x7812, 12384), (0x7813, 12391), (0x7814, 6880), (0x781d, 7282), (0x781f, 7288), (0x7820, 7287), (0x7821, 12844), (0x7822, 12838),
+    (0x7823, 12836), (0x7825, 7285), (0x7826, 13432), (0x7827, 7280), (0x7828, 12841), (0x7829, 12845), (0x782a, 12847), (0x782b, 12843),
+    (0x782c, 12837), (0x782d, 7286), (0x782e, 12842), (0x782f, 12840), (0x7830, 7279), (0x7831, 12848), (0x7832, 7289), (0x7833, 12846),
+    (0x7834, 7283), (0x7835, 12839), (0x7837, 7284), (0x7838, 7281), (0x7843, 7780), (0x7845, 13433), (0x7848, 13426), (0x7849, 13428),
+    (0x784a, 13430), (0x784c, 13431), (0x784d, 13429), (0x784e, 7781), (0x7850, 13434), (0x7852, 13427), (0x785c, 14075), (0x785d, 8218),
+    (0x785e, 14083), (0x7860, 14072), (0x7862, 14084), (0x7864, 14073), (0x7865, 14074), (0x7868, 14082), (0x7869, 14081), (0x786a, 14078),
+    (0x786b, 7779), (0x786c, 8219), (0x786d, 14076), (0x786e, 14079), (0x786f, 8220), (0x7870, 14080), (0x7871, 14077), (0x7879, 14741),
+    (0x787b, 14745), (0x787c, 8649), (0x787e, 15371), (0x787f, 8652), (0x7880, 14743), (0x7883, 14740), (0x7884, 14735), (0x7885, 14737),
+    (0x7886, 14738), (0x7887, 14731), (0x7889, 8648), (0x788c, 8647), (0x788e, 8643), (0x788f, 14734), (0x7891, 8650), (0x7893, 8651),
+    (0x7894, 14733), (0x7895, 14736), (0x7896, 14744), (0x7897, 8645), (0x7898, 8646), (0x7899, 14742), (0x789a, 14732), (0x789e, 15373),
+    (0x789f, 9059), (0x78a0, 15375), (0x78a1, 14739), (0x78a2, 15377), (0x78a3, 9063), (0x78a4, 15378), (0x78a5, 15374), (0x78a7, 9060),
+    (0x78a8, 15370), (0x78a9, 9062), (0x78aa, 15367), (0x78ab, 15372), (0x78ac, 15376), (0x78ad, 15369), (0x78b0, 8644), (0x78b2, 15366),
+    (0x78b3, 9061), (0x78b4, 15368), (0x78ba, 9440), (0x78bb, 15952), (0x78bc, 9444), (0x78be, 9442), (0x78c1, 9058), (0x78c3, 15959),
+    (0x78c4, 15960), (0x78c5, 9439), (0x78c8, 15958), (0x78c9, 15961), (0x78ca, 9441), (0x78cb, 9438), (0x78cc, 15954), (0x78cd, 15951),
+    (0x78ce, 15956), (0x78cf, 15953), (0x78d0, 9445), (0x78d1, 15955), (0x78d4, 15957), (0x78d5, 9443), (0x78da, 9776), (0x78db, 16555),
+    (0x78dd, 16549), (0x78de, 16553), (0x78df, 16559), (0x78e0, 16560), (0x78e1, 16556), (0x78e2, 16557), (0x78e3, 16554), (0x78e5, 16551),
+    (0x78e7, 9778), (0x78e8, 9775), (0x78e9, 16550), (0x78ea, 16552), (0x78ec, 9777), (0x78ed, 16558), (0x78ef, 10054), (0x78f2, 17028),
+    (0x78f3, 17023), (0x78f4, 10053), (0x78f7, 10051), (0x78f9, 17030), (0x78fa, 10052), (0x78fb, 17026), (0x78fc, 17027), (0x78fd, 17024),
+    (0x78fe, 17031), (0x78ff, 17449), (0x7901, 10055), (0x7902, 17025), (0x7904, 17032), (0x7905, 17029), (0x7909, 17453), (0x790c, 17450),
+    (0x790e, 10273), (0x7910, 17454), (0x7911, 17456), (0x7912, 17455), (0x7913, 17451), (0x7914, 17452), (0x7917, 17806), (0x7919, 10434),
+    (0x791b, 17803), (0x791c, 17805), (0x791d, 17802), (0x791e, 17807), (0x7921, 17804), (0x7923, 18113), (0x7924, 18116), (0x7925, 18112),
+    (0x7926, 10568), (0x7927, 18114), (0x7928, 18115), (0x7929, 18117), (0x792a, 10569), (0x792b, 10571), (0x792c, 10570), (0x792d, 18339),
+    (0x792f, 18341), (0x7931, 18340), (0x7935, 18517), (0x7938, 18766), (0x7939, 18835), (0x793a, 5774), (0x793d, 11668), (0x793e, 6531),
+    (0x793f, 11997), (0x7940, 6532), (0x7941, 6533), (0x7942, 11996), (0x7944, 12396), (0x7945, 12395), (0x7946, 6883), (0x7947, 6886),
+    (0x7948, 6885), (0x7949, 6884), (0x794a, 12392), (0x794b, 12394), (0x794c, 12393), (0x794f, 12851), (0x7950, 7291), (0x7951, 12855),
+    (0x7952, 12854), (0x7953, 12853), (0x7954, 12849), (0x7955, 7290), (0x7956, 7294), (0x7957, 7297), (0x795a, 7298), (0x795b, 12850),
+    (0x795c, 12852), (0x795d, 7296), (0x795e, 7295), (0x795f, 7293), (0x7960, 7292), (0x7961, 13441), (0x7963, 13439), (0x7964, 13435),
+    (0x7965, 7782), (0x7967, 13436), (0x7968, 7783), (0x7969, 13437), (0x796a, 13438), (0x796b, 13440), (0x796d, 7784), (0x7970, 14088),
+    (0x7972, 14087), (0x7973, 14086), (0x7974, 14085), (0x7979, 14749), (0x797a, 8653), (0x797c, 14746), (0x797d, 14748), (0x797f, 8654),
+    (0x7981, 8655), (0x7982, 14747), (0x7988, 15387), (0x798a, 15380), (0x798b, 15381), (0x798d, 9066), (0x798e, 9064), (0x798f, 9065),
+    (0x7990, 15389), (0x7992, 15388), (0x7993, 15385), (0x7994, 15384), (0x7995, 15383), (0x7996, 15382), (0x7997, 15386), (0x7998, 15379),
+    (0x799a, 15962), (0x799b, 15967), (0x799c, 15965), (0x79a0, 15964), (0x79a1, 15963), (0x79a2, 15966), (0x79a4, 16561), (0x79a6, 9779),
+    (0x79a7, 10056), (0x79a8, 17034), (0x79aa, 10057), (0x79ab, 17033), (0x79ac, 17458), (0x79ad, 17457), (0x79ae, 10274), (0x79b0, 17808),
+    (0x79b1, 10435), (0x79b2, 18118), (0x79b3, 10739), (0x79b4, 18518), (0x79b6, 18768), (0x79b7, 18767), (0x79b8, 11374), (0x79b9, 6887),
+    (0x79ba, 6888), (0x79bb, 13442), (0x79bd, 8657), (0x79be, 5775), (0x79bf, 6173), (0x79c0, 6172), (0x79c1, 6171), (0x79c5, 11998),
+    (0x79c8, 6535), (0x79c9, 6534), (0x79cb, 6891), (0x79cd, 12398), (0x79ce, 12401), (0x79cf, 12399), (0x79d1, 6889), (0x79d2, 6890),
+    (0x79d5, 12397), (0x79d6, 12400), (0x79d8, 7305), (0x79dc, 12862), (0x79dd, 12864), (0x79de, 12863), (0x79df, 7302), (0x79e0, 12858),
+    (0x79e3, 7300), (0x79e4, 7299), (0x79e6, 7303), (0x79e7, 7301), (0x79e9, 7304), (0x79ea, 12861), (0x79eb, 12856), (0x79ec, 12857),
+    (0x79ed, 12860), (0x79ee, 12859), (0x79f6, 13445), (0x79f7, 13446), (0x79f8, 13444), (0x79fa, 13443), (0x79fb, 7785), (0x7a00, 8225),
+    (0x7a02, 14089), (0x7a03, 14091), (0x7a04, 14093), (0x7a05, 8224), (0x7a08, 8222), (0x7a0a, 14090), (0x7a0b, 8223), (0x7a0c, 14092),
+    (0x7a0d, 8221), (0x7a10, 14759), (0x7a11, 14750), (0x7a12, 14753), (0x7a13, 14757), (0x7a14, 8661), (0x7a15, 14755), (0x7a17, 14754),
+    (0x7a18, 14751), (0x7a19, 14752), (0x7a1a, 8659), (0x7a1b, 14758), (0x7a1c, 8658), (0x7a1e, 8663), (0x7a1f, 8662), (0x7a20, 8660),
+    (0x7a22, 14756), (0x7a26, 15395), (0x7a28, 15394), (0x7a2b, 15390), (0x7a2e, 9067), (0x7a2f, 15393), (0x7a30, 15392), (0x7a31, 9068),
+    (0x7a37, 9450), (0x7a39, 15969), (0x7a3b, 9451), (0x7a3c, 9447), (0x7a3d, 9449), (0x7a3f, 9446), (0x7a40, 9448), (0x7a44, 16562),
+    (0x7a46, 9782), (0x7a47, 16564), (0x7a48, 16563), (0x7a4a, 15391), (0x7a4b, 9784), (0x7a4c, 9783), (0x7a4d, 9780), (0x7a4e, 9781),
+    (0x7a54, 17039), (0x7a56, 17037), (0x7a57, 10058), (0x7a58, 17038), (0x7a5a, 17040), (0x7a5b, 17036), (0x7a5c, 17035), (0x7a5f, 17459),
+    (0x7a60, 10277), (0x7a61, 10275), (0x7a62, 10276), (0x7a67, 17809), (0x7a68, 17810), (0x7a69, 10437), (0x7a6b, 10436), (0x7a6c, 18120),
+    (0x7a6d, 18121), (0x7a6e, 18119), (0x7a70, 18519), (0x7a71, 18520), (0x7a74, 5776), (0x7a75, 11469), (0x7a76, 6174), (0x7a78, 11999),
+    (0x7a79, 6537), (0x7a7a, 6536), (0x7a7b, 12000), (0x7a7e, 12403), (0x7a7f, 6892), (0x7a80, 12402), (0x7a81, 6893), (0x7a84, 7306),
+    (0x7a85, 12867), (0x7a86, 12865), (0x7a87, 12871), (0x7a88, 7307), (0x7a89, 12866), (0x7a8a, 12870), (0x7a8b, 12868), (0x7a8c, 12869),
+    (0x7a8f, 13447), (0x7a90, 13449), (0x7a92, 7786), (0x7a94, 13448), (0x7a95, 7787), (0x7a96, 8228), (0x7a97, 8227), (0x7a98, 8226),
+    (0x7a99, 14094), (0x7a9e, 14762), (0x7a9f, 8664), (0x7aa0, 8665), (0x7aa2, 14761), (0x7aa3, 14760), (0x7aa8, 15396), (0x7aa9, 9070),
+    (0x7aaa, 9069), (0x7aab, 15397), (0x7aac, 15398), (0x7aae, 9453), (0x7aaf, 9452), (0x7ab1, 16568), (0x7ab2, 15970), (0x7ab3, 15972),
+    (0x7ab4, 15971), (0x7ab5, 16567), (0x7ab6, 16565), (0x7ab7, 16569), (0x7ab8, 16566), (0x7aba, 9785), (0x7abe, 17041), (0x7abf, 10059),
+    (0x7ac0, 17042), (0x7ac1, 17043), (0x7ac4, 10278), (0x7ac5, 10279), (0x7ac7, 10572), (0x7aca, 10787), (0x7acb, 5777), (0x7ad1, 12404),
+    (0x7ad8, 12872), (0x7ad9, 7308), (0x7adf, 7938), (0x7ae0, 7937), (0x7ae3, 8230), (0x7ae4, 14096), (0x7ae5, 8229), (0x7ae6, 14095),
+    (0x7aeb, 14763), (0x7aed, 9071), (0x7aee, 15399), (0x7aef, 9072), (0x7af6, 10573), (0x7af7, 18122), (0x7af9, 5914), (0x7afa, 6538),
+    (0x7afb, 12001), (0x7afd, 6895), (0x7aff, 6894), (0x7b00, 12405), (0x7b01, 12406), (0x7b04, 12874), (0x7b05, 12876), (0x7b06, 7309),
+    (0x7b08, 12878), (0x7b09, 12881), (0x7b0a, 12879), (0x7b0e, 12880), (0x7b0f, 12877), (0x7b10, 12873), (0x7b11, 7310), (0x7b12, 12882),
+    (0x7b13, 12875), (0x7b18, 13458), (0x7b19, 7793), (0x7b1a, 13467), (0x7b1b, 7790), (0x7b1d, 13460), (0x7b1e, 7794), (0x7b20, 7788),
+    (0x7b22, 13455), (0x7b23, 13468), (0x7b24, 13456), (0x7b25, 13453), (0x7b26, 7792), (0x7b28, 7789), (0x7b2a, 13459), (0x7b2b, 13462),
+    (0x7b2c, 7791), (0x7b2d, 13463), (0x7b2e, 7795), (0x7b2f, 13464), (0x7b30, 13454), (0x7b31, 13461), (0x7b32, 13465), (0x7b33, 13457),
+    (0x7b34, 13452), (0x7b35, 13450), (0x7b38, 13466), (0x7b3b, 14098), (0x7b40, 14103), (0x7b44, 14099), (0x7b45, 14105), (0x7b46, 8233),
+    (0x7b47, 13451), (0x7b48, 14100), (0x7b49, 8231), (0x7b4a, 14097), (0x7b4b, 8238), (0x7b4c, 14101), (0x7b4d, 8237), (0x7b4e, 14102),
+    (0x7b4f, 8239), (0x7b50, 8234), (0x7b51, 8240), (0x7b52, 8235), (0x7b54, 8236), (0x7b56, 8232), (0x7b58, 14104), (0x7b60, 8668),
+    (0x7b61, 14774), (0x7b63, 14777), (0x7b64, 14765), (0x7b65, 14770), (0x7b66, 14764), (0x7b67, 8670), (0x7b69, 14768), (0x7b6d, 14766),
+    (0x7b6e, 8669), (0x7b70, 14773), (0x7b71, 14772), (0x7b72, 14769), (0x7b73, 14771), (0x7b74, 14767), (0x7b75, 9076), (0x7b76, 14776),
+    (0x7b77, 8666), (0x7b78, 14775), (0x7b82, 15415), (0x7b84, 9083), (0x7b85, 15410), (0x7b87, 9082), (0x7b88, 15400), (0x7b8a, 15402),
+    (0x7b8b, 9075), (0x7b8c, 15407), (0x7b8d, 15406), (0x7b8e, 15409), (0x7b8f, 9080), (0x7b90, 15404), (0x7b91, 15403), (0x7b94, 9079),
+    (0x7b95, 9074), (0x7b96, 15405), (0x7b97, 9077), (0x7b98, 15411), (0x7b99, 15413), (0x7b9b, 15408), (0x7b9c, 15401), (0x7b9d, 9078),
+    (0x7ba0, 9461), (0x7ba1, 9073), (0x7ba4, 15414), (0x7bac, 15976), (0x7bad, 9454), (0x7baf, 15978), (0x7bb1, 9455), (0x7bb4, 9457),
+    (0x7bb5, 15981), (0x7bb7, 15973), (0x7bb8, 9081), (0x7bb9, 15979), (0x7bbe, 15975), (0x7bc0, 8667), (0x7bc1, 9460), (0x7bc4, 9456),
+    (0x7bc6, 9458), (0x7bc7, 9459), (0x7bc9, 9788), (0x7bca, 15980), (0x7bcb, 15974), (0x7bcc, 9462), (0x7bce, 15977), (0x7bd4, 16579),
+    (0x7bd5, 16574), (0x7bd8, 16584), (0x7bd9, 9786), (0x7bda, 16576), (0x7bdb, 9790), (0x7bdc, 16582), (0x7bdd, 16573), (0x7bde, 16570),
+    (0x7bdf, 16585), (0x7be0, 10065), (0x7be1, 9791), (0x7be2, 16581), (0x7be3, 16571), (0x7be4, 9789), (0x7be5, 16575), (0x7be6, 9793),
+    (0x7be7, 16572), (0x7be8, 16577), (0x7be9, 9792), (0x7bea, 16580), (0x7beb, 16583), (0x7bf0, 17061), (0x7bf1, 17062), (0x7bf2, 17046),
+    (0x7bf3, 17053), (0x7bf4, 17051), (0x7bf7, 10063), (0x7bf8, 17058), (0x7bf9, 16578), (0x7bfb, 17049), (0x7bfd, 17059), (0x7bfe, 10062),
+    (0x7bff, 17048), (0x7c00, 17047), (0x7c01, 17057), (0x7c02, 17054), (0x7c03, 17056), (0x7c05, 17044), (0x7c06, 17060), (0x7c07, 10060),
+    (0x7c09, 17055), (0x7c0a, 17064), (0x7c0b, 17052), (0x7c0c, 10064), (0x7c0d, 10061), (0x7c0e, 17050), (0x7c0f, 17045), (0x7c10, 17063),
+    (0x7c11, 9787), (0x7c19, 17462), (0x7c1c, 17460), (0x7c1d, 17466), (0x7c1e, 10283), (0x7c1f, 17464), (0x7c20, 17463), (0x7c21, 10285),
+    (0x7c22, 17469), (0x7c23, 10284), (0x7c25, 17470), (0x7c26, 17467), (0x7c27, 10281), (0x7c28, 17468), (0x7c29, 17461), (0x7c2a, 10282),
+    (0x7c2b, 10280), (0x7c2c, 17814), (0x7c2d, 17465), (0x7c30, 17471), (0x7c33, 17811), (0x7c37, 10442), (0x7c38, 10440), (0x7c39, 17813),
+    (0x7c3b, 17815), (0x7c3c, 17812), (0x7c3d, 10441), (0x7c3e, 10438), (0x7c3f, 10439), (0x7c40, 10443), (0x7c43, 10575), (0x7c45, 18127),
+    (0x7c47, 18126), (0x7c48, 18124), (0x7c49, 18123), (0x7c4a, 18125), (0x7c4c, 10574), (0x7c4d, 10576), (0x7c50, 10661), (0x7c53, 18343),
+    (0x7c54, 18342), (0x7c57, 18521), (0x7c59, 18523), (0x7c5a, 18525), (0x7c5b, 18524), (0x7c5c, 18522), (0x7c5f, 10741), (0x7c60, 10740),
+    (0x7c63, 10789), (0x7c64, 10788), (0x7c65, 10790), (0x7c66, 18665), (0x7c67, 18664), (0x7c69, 18836), (0x7c6a, 18769), (0x7c6b, 18837),
+    (0x7c6c, 10857), (0x7c6e, 10858), (0x7c6f, 18885), (0x7c72, 10895), (0x7c73, 5915), (0x7c75, 12002), (0x7c78, 12408), (0x7c79, 12409),
+    (0x7c7a, 12407), (0x7c7d, 6896), (0x7c7f, 12410), (0x7c80, 12411), (0x7c81, 12412), (0x7c84, 12883), (0x7c85, 12889), (0x7c88, 12887),
+    (0x7c89, 7311), (0x7c8a, 12885), (0x7c8c, 12886), (0x7c8d, 12888), (0x7c91, 12884), (0x7c92, 7796), (0x7c94, 13469), (0x7c95, 7798),
+    (0x7c96, 13471), (0x7c97, 7797), (0x7c98, 13470), (0x7c9e, 14107), (0x7c9f, 8241), (0x7ca1, 14109), (0x7ca2, 14106), (0x7ca3, 13472),
+    (0x7ca5, 8242), (0x7ca8, 14108), (0x7caf, 14780), (0x7cb1, 8671), (0x7cb2, 14778), (0x7cb3, 8672), (0x7cb4, 14779), (0x7cb5, 8673),
+    (0x7cb9, 9084), (0x7cba, 15419), (0x7cbb, 15416), (0x7cbc, 15418), (0x7cbd, 9085), (0x7cbe, 9086), (0x7cbf, 15417), (0x7cc5, 15982),
+    (0x7cc8, 15983), (0x7cca, 9463), (0x7ccb, 15985), (0x7ccc, 15984), (0x7cce, 5214), (0x7cd0, 16589), (0x7cd1, 16590), (0x7cd2, 16586),
+    (0x7cd4, 16587), (0x7cd5, 9794), (0x7cd6, 9795), (0x7cd7, 16588), (0x7cd9, 10071), (0x7cdc, 10067), (0x7cdd, 10072), (0x7cde, 10068),
+    (0x7cdf, 10070), (0x7ce0, 10066), (0x7ce2, 10069), (0x7ce7, 10286), (0x7ce8, 17065), (0x7cea, 17817), (0x7cec, 17816), (0x7cee, 18128),
+    (0x7cef, 10577), (0x7cf0, 10578), (0x7cf1, 18527), (0x7cf2, 18344), (0x7cf4, 18526), (0x7cf6, 18838), (0x7cf7, 18906), (0x7cf8, 5916),
+    (0x7cfb, 6175), (0x7cfd, 12003), (0x7cfe, 6539), (0x7d00, 6899), (0x7d01, 12415), (0x7d02, 6897), (0x7d03, 12413), (0x7d04, 6902),
+    (0x7d05, 6898), (0x7d06, 6903), (0x7d07, 6901), (0x7d08, 12414), (0x7d09, 6900), (0x7d0a, 7315), (0x7d0b, 7314), (0x7d0c, 12900),
+    (0x7d0d, 7323), (0x7d0e, 12893), (0x7d0f, 12899), (0x7d10, 7319), (0x7d11, 12892), (0x7d12, 12898), (0x7d13, 12896), (0x7d14, 7318),
+    (0x7d15, 7320), (0x7d16, 12895), (0x7d17, 7313), (0x7d18, 12894), (0x7d19, 7324), (0x7d1a, 7321), (0x7d1b, 7325), (0x7d1c, 7322),
+    (0x7d1d, 12891), (0x7d1e, 12890), (0x7d1f, 12897), (0x7d20, 7316), (0x7d21, 7312), (0x7d22, 7317), (0x7d28, 13487), (0x7d29, 13480),
+    (0x7d2b, 8247), (0x7d2c, 13479), (0x7d2e, 7802), (0x7d2f, 7809), (0x7d30, 7806), (0x7d31, 7812), (0x7d32, 7811), (0x7d33, 7807),
+    (0x7d35, 13473), (0x7d36, 13476), (0x7d38, 13475), (0x7d39, 7803), (0x7d3a, 13477), (0x7d3b, 13486), (0x7d3c, 7804), (0x7d3d, 13474),
+    (0x7d3e, 13483), (0x7d3f, 13484), (0x7d40, 7805), (0x7d41, 13481), (0x7d42, 7810), (0x7d43, 7800), (0x7d44, 7808), (0x7d45, 13478),
+    (0x7d46, 7799), (0x7d47, 13482), (0x7d4a, 13485), (0x7d4e, 14126), (0x7d4f, 14117), (0x7d50, 8244), (0x7d51, 14124), (0x7d52, 14121),
+    (0x7d53, 14113), (0x7d54, 14122), (0x7d55, 8246), (0x7d56, 14114), (0x7d58, 14110), (0x7d5b, 8679), (0x7d5c, 14119), (0x7d5e, 8243),
+    (0x7d5f, 14125), (0x7d61, 8250), (0x7d62, 8252), (0x7d63, 14112), (0x7d66, 8251), (0x7d67, 14115), (0x7d68, 8245), (0x7d69, 14123),
+    (0x7d6a, 14116), (0x7d6b, 14120), (0x7d6d, 14118), (0x7d6e, 8248), (0x7d6f, 14111), (0x7d70, 8253), (0x7d71, 7801), (0x7d72, 8249),
+    (0x7d73, 8254), (0x7d79, 8675), (0x7d7a, 14787), (0x7d7b, 14789), (0x7d7c, 14791), (0x7d7d, 14795), (0x7d7f, 14785), (0x7d80, 14783),
+    (0x7d81, 8677), (0x7d83, 14790), (0x7d84, 14794), (0x7d85, 14786), (0x7d86, 14782), (0x7d88, 14781), (0x7d8c, 14792), (0x7d8d, 14784),
+    (0x7d8e, 14788), (0x7d8f, 8678), (0x7d91, 8676), (0x7d92, 14796), (0x7d93, 8674), (0x7d94, 14793), (0x7d96, 15436), (0x7d9c, 9089),
+    (0x7d9d, 15428), (0x7d9e, 9476), (0x7d9f, 15438), (0x7da0, 9092), (0x7da1, 15442), (0x7da2, 9098), (0x7da3, 15423), (0x7da6, 15439),
+    (0x7da7, 15420), (0x7da9, 15441), (0x7daa, 15424), (0x7dac, 9105), (0x7dad, 9102), (0x7dae, 15440), (0x7daf, 15434), (0x7db0, 9088),
+    (0x7db1, 9096), (0x7db2, 9095), (0x7db4, 9094), (0x7db5, 9100), (0x7db7, 15421), (0x7db8, 9101), (0x7db9, 15435), (0x7dba, 9097),
+    (0x7dbb, 9087), (0x7dbc, 15437), (0x7dbd, 9090), (0x7dbe, 9091), (0x7dbf, 9099), (0x7dc0, 15426), (0x7dc1, 15425), (0x7dc2, 15422),
+    (0x7dc4, 15430), (0x7dc5, 15427), (0x7dc6, 15431), (0x7dc7, 9104), (0x7dc9, 15443), (0x7dca, 9093), (0x7dcb, 15432), (0x7dcc, 15433),
+    (0x7dce, 15429), (0x7dd2, 9103), (0x7dd7, 15990), (0x7dd8, 9468), (0x7dd9, 9477), (0x7dda, 9473), (0x7ddb, 15987), (0x7ddd, 9470),
+    (0x7dde, 9474), (0x7ddf, 15999), (0x7de0, 9464), (0x7de1, 15991), (0x7de3, 9472), (0x7de6, 15994), (0x7de7, 15989), (0x7de8, 9471),
+    (0x7de9, 9475), (0x7dea, 15988), (0x7dec, 9469), (0x7dee, 15998), (0x7def, 9466), (0x7df0, 15997), (0x7df1, 15996), (0x7df2, 9478),
+    (0x7df3, 15750), (0x7df4, 9465), (0x7df6, 15995), (0x7df7, 15986), (0x7df9, 9479), (0x7dfa, 15993), (0x7dfb, 9467), (0x7e03, 15992),
+    (0x7e08, 9798), (0x7e09, 9803), (0x7e0a, 9796), (0x7e0b, 16603), (0x7e0c, 16594), (0x7e0d, 16606), (0x7e0e, 16598), (0x7e0f, 16604),
+    (0x7e10, 9804), (0x7e11, 9797), (0x7e12, 16591), (0x7e13, 16597), (0x7e14, 16607), (0x7e15, 16600), (0x7e16, 16605), (0x7e17, 16593),
+    (0x7e1a, 16601), (0x7e1b, 9799), (0x7e1c, 16599), (0x7e1d, 9802), (0x7e1e, 9801), (0x7e1f, 16595), (0x7e20, 16596), (0x7e21, 16592),
+    (0x7e22, 16602), (0x7e23, 9800), (0x7e24, 16609), (0x7e25, 16608), (0x7e29, 17076), (0x7e2a, 17072), (0x7e2b, 10079), (0x7e2d, 17066),
+    (0x7e2e, 10073), (0x7e2f, 10089), (0x7e30, 17078), (0x7e31, 10081), (0x7e32, 10077), (0x7e33, 17069), (0x7e34, 10084), (0x7e35, 10087),
+    (0x7e36, 17080), (0x7e37, 10076), (0x7e38, 17071), (0x7e39, 10085), (0x7e3a, 17082), (0x7e3b, 17079), (0x7e3c, 17067), (0x7e3d, 10080),
+    (0x7e3e, 10074), (0x7e3f, 10088), (0x7e40, 17074), (0x7e41, 10083), (0x7e42, 17068), (0x7e43, 10078), (0x7e44, 17081), (0x7e45, 10082),
+    (0x7e46, 10075), (0x7e47, 17075), (0x7e48, 10086), (0x7e49, 17073), (0x7e4c, 17077), (0x7e50, 17473), (0x7e51, 17479), (0x7e52, 10292),
+    (0x7e53, 17482), (0x7e54, 10287), (0x7e55, 10288), (0x7e56, 17474), (0x7e57, 17481), (0x7e58, 17476), (0x7e59, 10293), (0x7e5a, 10290),
+    (0x7e5c, 17472), (0x7e5e, 10289), (0x7e5f, 17478), (0x7e60, 17480), (0x7e61, 10291), (0x7e62, 17477), (0x7e63, 17475), (0x7e68, 17827),
+    (0x7e69, 10447), (0x7e6a, 10448), (0x7e6b, 10444), (0x7e6d, 10445), (0x7e6f, 17823), (0x7e70, 17821), (0x7e72, 17825), (0x7e73, 10450),
+    (0x7e74, 17826), (0x7e75, 17819), (0x7e76, 17818), (0x7e77, 17822), (0x7e78, 17820), (0x7e79, 10446), (0x7e7a, 17824), (0x7e7b, 18129),
+    (0x7e7c, 10581), (0x7e7d, 10580), (0x7e7e, 18130), (0x7e80, 18132), (0x7e81, 18131), (0x7e82, 10582), (0x7e86, 18349), (0x7e87, 18346),
+    (0x7e88, 18347), (0x7e8a, 18345), (0x7e8b, 18348), (0x7e8c, 10663), (0x7e8d, 18350), (0x7e8f, 10662), (0x7e91, 18528), (0x7e93, 10791),
+    (0x7e94, 10793), (0x7e95, 18666), (0x7e96, 10792), (0x7e97, 18770), (0x7e98, 18840), (0x7e99, 18842), (0x7e9a, 18839), (0x7e9b, 18841),
+    (0x7e9c, 10877), (0x7f36, 5917), (0x7f38, 6904), (0x7f39, 12764), (0x7f3a, 7326), (0x7f3d, 7813), (0x7f3e, 14127), (0x7f3f, 14128),
+    (0x7f43, 16610), (0x7f44, 10090), (0x7f45, 17083), (0x7f48, 10294), (0x7f4a, 17829), (0x7f4b, 17828), (0x7f4c, 10583), (0x7f4d, 18351),
+    (0x7f4f, 18529), (0x7f50, 10826), (0x7f51, 11470), (0x7f54, 6540), (0x7f55, 6176), (0x7f58, 12416), (0x7f5b, 12906), (0x7f5c, 12901),
+    (0x7f5d, 12905), (0x7f5e, 12903), (0x7f5f, 7327), (0x7f60, 12904), (0x7f61, 12902), (0x7f63, 13488), (0x7f65, 14129), (0x7f66, 14130),
+    (0x7f67, 14799), (0x7f68, 14800), (0x7f69, 8681), (0x7f6a, 8682), (0x7f6b, 14798), (0x7f6c, 14801), (0x7f6d, 14797), (0x7f6e, 8680),
+    (0x7f70, 9106), (0x7f72, 8683), (0x7f73, 15444), (0x7f75, 9480), (0x7f76, 16000), (0x7f77, 9481), (0x7f79, 9805), (0x7f7a, 16613),
+    (0x7f7b, 16611), (0x7f7c, 16612), (0x7f7d, 17086), (0x7f7e, 17085), (0x7f7f, 17084), (0x7f83, 17830), (0x7f85, 10449), (0x7f86, 17831),
+    (0x7f87, 18530), (0x7f88, 10827), (0x7f89, 18771), (0x7f8a, 5918), (0x7f8b, 6542), (0x7f8c, 6541), (0x7f8d, 12418), (0x7f8e, 6905),
+    (0x7f91, 12417), (0x7f92, 12908), (0x7f94, 7328), (0x7f95, 13489), (0x7f96, 12907), (0x7f9a, 7815), (0x7f9b, 13492), (0x7f9c, 13490),
+    (0x7f9d, 13491), (0x7f9e, 7814), (0x7fa0, 14132), (0x7fa1, 14133), (0x7fa2, 14131), (0x7fa4, 8686), (0x7fa5, 14803), (0x7fa6, 14802),
+    (0x7fa7, 14804), (0x7fa8, 8685), (0x7fa9, 8684), (0x7fac, 16001), (0x7fad, 16003), (0x7faf, 9482), (0x7fb0, 16002), (0x7fb1, 16614),
+    (0x7fb2, 9806), (0x7fb3, 17484), (0x7fb5, 17483), (0x7fb6, 10451), (0x7fb7, 17832), (0x7fb8, 10453), (0x7fb9, 10452), (0x7fba, 18133),
+    (0x7fbb, 18352), (0x7fbc, 10664), (0x7fbd, 5919), (0x7fbe, 12419), (0x7fbf, 6906), (0x7fc0, 12911), (0x7fc1, 7330), (0x7fc2, 12910),
+    (0x7fc3, 12909), (0x7fc5, 7329), (0x7fc7, 13498), (0x7fc9, 13500), (0x7fca, 13493), (0x7fcb, 13494), (0x7fcc, 7816), (0x7fcd, 13495),
+    (0x7fce, 7817), (0x7fcf, 13499), (0x7fd0, 13496), (0x7fd1, 13497), (0x7fd2, 7818), (0x7fd4, 8256), (0x7fd5, 8257), (0x7fd7, 14134),
+    (0x7fdb, 14805), (0x7fdc, 14806), (0x7fde, 15448), (0x7fdf, 9109), (0x7fe0, 9107), (0x7fe1, 9108), (0x7fe2, 15445), (0x7fe3, 15446),
+    (0x7fe5, 15447), (0x7fe6, 16008), (0x7fe8, 16009), (0x7fe9, 9483), (0x7fea, 16006), (0x7feb, 16005), (0x7fec, 16007), (0x7fed, 16004),
+    (0x7fee, 9809), (0x7fef, 16615), (0x7ff0, 9807), (0x7ff1, 9808), (0x7ff2, 17088), (0x7ff3, 10091), (0x7ff4, 17087), (0x7ff5, 17340),
+    (0x7ff7, 17485), (0x7ff8, 17486), (0x7ff9, 10295), (0x7ffb, 10296), (0x7ffc, 10092), (0x7ffd, 17833), (0x7ffe, 17834), (0x7fff, 18134),
+    (0x8000, 10584), (0x8001, 5920), (0x8003, 5921), (0x8004, 6907), (0x8005, 6543), (0x8006, 7331), (0x8007, 12420), (0x800b, 8258),
+    (0x800c, 5922), (0x800d, 6909), (0x800e, 12421), (0x800f, 12422), (0x8010, 6908), (0x8011, 6910), (0x8012, 5923), (0x8014, 12423),
+    (0x8015, 7333), (0x8016, 12912), (0x8017, 7335), (0x8018, 7332), (0x8019, 7334), (0x801b, 13503), (0x801c, 7819), (0x801e, 13502),
+    (0x801f, 13501), (0x8021, 14807), (0x8024, 15449), (0x8026, 9484), (0x8028, 9810), (0x8029, 16617), (0x802a, 16616), (0x802c, 17089),
+    (0x8030, 18353), (0x8033, 5924), (0x8034, 11669), (0x8035, 12004), (0x8036, 6911), (0x8037, 12424), (0x8039, 12914), (0x803d, 7336),
+    (0x803e, 12913), (0x803f, 7337), (0x8043, 13505), (0x8046, 7821), (0x8047, 13504), (0x8048, 13506), (0x804a, 7820), (0x804f, 14136),
+    (0x8050, 14137), (0x8051, 14135), (0x8052, 8259), (0x8056, 8687), (0x8058, 8688), (0x805a, 9111), (0x805c, 15451), (0x805d, 15450),
+    (0x805e, 9110), (0x8064, 16010), (0x8067, 16011), (0x806c, 16618), (0x806f, 10096), (0x8070, 10095), (0x8071, 10093), (0x8072, 10094),
+    (0x8073, 10097), (0x8075, 17487), (0x8076, 10298), (0x8077, 10297), (0x8078, 17835), (0x8079, 18135), (0x807d, 10743), (0x807e, 10742),
+    (0x807f, 5925), (0x8082, 12713), (0x8084, 8690), (0x8085, 8260), (0x8086, 8689), (0x8087, 9112), (0x8089, 5926), (0x808a, 11375),
+    (0x808b, 5927), (0x808c, 5928), (0x808f, 12005), (0x8090, 11672), (0x8092, 11673), (0x8093, 6178), (0x8095, 11670), (0x8096, 6177),
+    (0x8098, 6180), (0x8099, 11671), (0x809a, 6182), (0x809b, 6181), (0x809c, 11674), (0x809d, 6179), (0x80a1, 6548), (0x80a2, 6546),
+    (0x80a3, 12007), (0x80a5, 6545), (0x80a9, 6550), (0x80aa, 6552), (0x80ab, 6549), (0x80ad, 12010), (0x80ae, 12006), (0x80af, 6553),
+    (0x80b1, 6547), (0x80b2, 6183), (0x80b4, 6551), (0x80b5, 12009), (0x80b8, 12008), (0x80ba, 6544), (0x80c2, 12430), (0x80c3, 6915),
+    (0x80c4, 6916), (0x80c5, 12432), (0x80c7, 12426), (0x80c8, 12429), (0x80c9, 12438), (0x80ca, 12436), (0x80cc, 6917), (0x80cd, 12442),
+    (0x80ce, 6920), (0x80cf, 12439), (0x80d0, 12431), (0x80d1, 12428), (0x80d4, 14139), (0x80d5, 12437), (0x80d6, 6912), (0x80d7, 12440),
+    (0x80d8, 12425), (0x80d9, 12434), (0x80da, 6914), (0x80db, 6919), (0x80dc, 12435), (0x80dd, 6923), (0x80de, 6921), (0x80e0, 12427),
+    (0x80e1, 6918), (0x80e3, 12433), (0x80e4, 6922), (0x80e5, 6913), (0x80e6, 12441), (0x80ed, 7342), (0x80ef, 7351), (0x80f0, 7340),
+    (0x80f1, 7338), (0x80f2, 12916), (0x80f3, 7346), (0x80f4, 7343), (0x80f5, 12918), (0x80f8, 7345), (0x80f9, 12917), (0x80fa, 12915),
+    (0x80fb, 12920), (0x80fc, 7350), (0x80fd, 7348), (0x80fe, 14138), (0x8100, 12921), (0x8101, 12919), (0x8102, 7339), (0x8105, 7341),
+    (0x8106, 7344), (0x8108, 7347), (0x810a, 7349), (0x8115, 13516), (0x8116, 7823), (0x8118, 13507), (0x8119, 13509), (0x811b, 13510),
+    (0x811d, 13518), (0x811e, 13514), (0x811f, 13512), (0x8121, 13515), (0x8122, 13519), (0x8123, 7824), (0x8124, 7828), (0x8125, 13508),
+    (0x8127, 13517), (0x8129, 7826), (0x812b, 7825), (0x812c, 13513), (0x812d, 13511), (0x812f, 7822), (0x8130, 7827), (0x8139, 8266),
+    (0x813a, 14147), (0x813d, 14145), (0x813e, 8268), (0x8143, 14140), (0x8144, 14821), (0x8146, 8267), (0x8147, 14144), (0x814a, 14141),
+    (0x814b, 8263), (0x814c, 8269), (0x814d, 14146), (0x814e, 8265), (0x814f, 14143), (0x8150, 9113), (0x8151, 8264), (0x8152, 14142),
+    (0x8153, 8270), (0x8154, 8262), (0x8155, 8261), (0x815b, 14813), (0x815c, 14811), (0x815e, 14817), (0x8160, 14809), (0x8161, 14822),
+    (0x8162, 14814), (0x8164, 14808), (0x8165, 8694), (0x8166, 8700), (0x8167, 14819), (0x8169, 14812), (0x816b, 8697), (0x816e, 8695),
+    (0x816f, 14820), (0x8170, 8692), (0x8171, 8691), (0x8172, 14815), (0x8173, 8696), (0x8174, 8271), (0x8176, 14818), (0x8177, 14810),
+    (0x8178, 8693), (0x8179, 8698), (0x817a, 8699), (0x817f, 9118), (0x8180, 9114), (0x8182, 9119), (0x8183, 15454), (0x8186, 15453),
+    (0x8187, 15455), (0x8188, 9116), (0x8189, 15452), (0x818a, 9117), (0x818b, 15458), (0x818c, 15457), (0x818d, 15456), (0x818f, 9115),
+    (0x8195, 16015), (0x8197, 16018), (0x8198, 9490), (0x8199, 16017), (0x819a, 9489), (0x819b, 9485), (0x819c, 9486), (0x819d, 9487),
+    (0x819e, 16014), (0x819f, 16013), (0x81a0, 9488), (0x81a2, 16016), (0x81a3, 16012), (0x81a6, 16620), (0x81a7, 16630), (0x81a8, 9813),
+    (0x81a9, 9812), (0x81ab, 16624), (0x81ac, 16626), (0x81ae, 16621), (0x81b0, 16625), (0x81b1, 16619), (0x81b2, 16628), (0x81b3, 9811),
+    (0x81b4, 16627), (0x81b5, 16623), (0x81b7, 16629), (0x81b9, 16622), (0x81ba, 10100), (0x81bb, 17090), (0x81bc, 17096), (0x81bd, 10104),
+    (0x81be, 10106), (0x81bf, 10103), (0x81c0, 10102), (0x81c2, 10101), (0x81c3, 10099), (0x81c4, 17091), (0x81c5, 17094), (0x81c6, 10098),
+    (0x81c7, 17095), (0x81c9, 10105), (0x81ca, 17093), (0x81cc, 17092), (0x81cd, 10299), (0x81cf, 10300), (0x81d0, 17490), (0x81d1, 17488),
+    (0x81d2, 17489), (0x81d5, 17837), (0x81d7, 17836), (0x81d8, 10454), (0x81d9, 18137), (0x81da, 10585), (0x81db, 18136), (0x81dd, 18354),
+    (0x81de, 18531), (0x81df, 10744), (0x81e0, 18843), (0x81e1, 18844), (0x81e2, 10794), (0x81e3, 5929), (0x81e5, 6554), (0x81e6, 14148),
+    (0x81e7, 9120), (0x81e8, 10107), (0x81e9, 17097), (0x81ea, 5930), (0x81ec, 7353), (0x81ed, 7352), (0x81ee, 14149), (0x81f2, 16631),
+    (0x81f3, 5931), (0x81f4, 6924), (0x81f7, 14150), (0x81f8, 14151), (0x81f9, 14152), (0x81fa, 9121), (0x81fb, 9814), (0x81fc, 5932),
+    (0x81fe, 6555), (0x81ff, 12443), (0x8200, 7354), (0x8201, 12922), (0x8202, 7829), (0x8204, 14153), (0x8205, 8701), (0x8207, 9122),
+    (0x8208, 9815), (0x8209, 10108), (0x820a, 10301), (0x820b, 18138), (0x820c, 5933), (0x820d, 6556), (0x8210, 7355), (0x8211, 13520),
+    (0x8212, 8272), (0x8214, 9123), (0x8215, 15459), (0x8216, 16019), (0x821b, 5934), (0x821c, 8273), (0x821d, 14823), (0x821e, 9124),
+    (0x821f, 5935), (0x8220, 12011), (0x8221, 12444), (0x8222, 6925), (0x8225, 12924), (0x8228, 7358), (0x822a, 7356), (0x822b, 7357),
+    (0x822c, 7359), (0x822f, 12923), (0x8232, 13525), (0x8233, 13522), (0x8234, 13524), (0x8235, 7830), (0x8236, 7832), (0x8237, 7831),
+    (0x8238, 13521), (0x8239, 7833), (0x823a, 13523), (0x823c, 14154), (0x823d, 14155), (0x823f, 14156), (0x8240, 14826), (0x8242, 14827),
+    (0x8244, 14825), (0x8245, 14828), (0x8247, 8702), (0x8249, 14824), (0x824b, 9125), (0x824e, 16024), (0x824f, 16020), (0x8250, 16023),
+    (0x8251, 16025), (0x8252, 16022), (0x8253, 16021), (0x8255, 16632), (0x8256, 16633), (0x8257, 16634), (0x8258, 9816), (0x8259, 9817),
+    (0x825a, 17099), (0x825b, 17098), (0x825c, 17100), (0x825e, 17492), (0x825f, 17491), (0x8261, 17839), (0x8263, 17840), (0x8264, 17838),
+    (0x8266, 10586), (0x8268, 18139), (0x8269, 18140), (0x826b, 18532), (0x826c, 18667), (0x826d, 18772), (0x826e, 5936), (0x826f, 6184),
+    (0x8271, 10109), (0x8272, 5937), (0x8274, 13526), (0x8275, 14157), (0x8277, 10834), (0x8278, 11471), (0x827c, 11472), (0x827d, 11474),
+    (0x827e, 5938), (0x827f, 11475), (0x8280, 11473), (0x8283, 11682), (0x8284, 11683), (0x8285, 11677), (0x828a, 11681), (0x828b, 6186),
+    (0x828d, 6187), (0x828e, 11678), (0x828f, 11676), (0x8290, 11675), (0x8291, 11679), (0x8292, 6185), (0x8293, 11680), (0x8294, 12445),
+    (0x8298, 12016), (0x8299, 6559), (0x829a, 12015), (0x829b, 12017), (0x829d, 6558), (0x829e, 12022), (0x829f, 6562), (0x82a0, 12012),
+    (0x82a1, 12026), (0x82a2, 12032), (0x82a3, 6569), (0x82a4, 12029), (0x82a5, 6566), (0x82a7, 12019), (0x82a8, 12025), (0x82a9, 12027),
+    (0x82ab, 12014), (0x82ac, 6565), (0x82ad, 6560), (0x82ae, 12020), (0x82af, 6567), (0x82b0, 6570), (0x82b1, 6564), (0x82b3, 6557),
+    (0x82b4, 12024), (0x82b5, 12018), (0x82b6, 12031), (0x82b7, 6572), (0x82b8, 6568), (0x82b9, 6563), (0x82ba, 12023), (0x82bb, 7360),
+    (0x82bc, 12021), (0x82bd, 6561), (0x82be, 6571), (0x82c0, 12013), (0x82c2, 12028), (0x82c3, 12030), (0x82d1, 6942), (0x82d2, 6936),
+    (0x82d3, 6944), (0x82d4, 6941), (0x82d5, 12452), (0x82d6, 12455), (0x82d7, 6937), (0x82d9, 12446), (0x82db, 6930), (0x82dc, 6940),
+    (0x82de, 6943), (0x82df, 6945), (0x82e0, 12467), (0x82e1, 12458), (0x82e3, 6929), (0x82e4, 12466), (0x82e5, 6933), (0x82e6, 6931),
+    (0x82e7, 6926), (0x82e8, 12450), (0x82ea, 12465), (0x82eb, 12454), (0x82ec, 12457), (0x82ed, 12470), (0x82ef, 6946), (0x82f0, 12464),
+    (0x82f1, 6938), (0x82f2, 12459), (0x82f3, 12469), (0x82f4, 12456), (0x82f5, 12460), (0x82f6, 12463), (0x82f9, 12448), (0x82fa, 12468),
+    (0x82fb, 12462), (0x82fe, 12447), (0x8300, 12451), (0x8301, 6939), (0x8302, 6934), (0x8303, 6927), (0x8304, 6932), (0x8305, 6928),
+    (0x8306, 6947), (0x8307, 12449), (0x8308, 12941), (0x8309, 6935), (0x830c, 12461), (0x830d, 12090), (0x8316, 12944), (0x8317, 7374),
+    (0x8319, 12928), (0x831b, 12939), (0x831c, 12935), (0x831e, 12954), (0x8320, 12946), (0x8322, 12936), (0x8324, 12945), (0x8325, 12930),
+    (0x8326, 12934), (0x8327, 12957), (0x8328, 7377), (0x8329, 12949), (0x832a, 12940), (0x832b, 7361), (0x832c, 12955), (0x832d, 12926),
+    (0x832f, 12948), (0x8331, 7376), (0x8332, 7371), (0x8333, 12925), (0x8334, 7369), (0x8335, 7368), (0x8336, 7373), (0x8337, 12947),
+    (0x8338, 7365), (0x8339, 7372), (0x833a, 12453), (0x833b, 14158), (0x833c, 12942), (0x833f, 12932), (0x8340, 7375), (0x8341, 12933),
+    (0x8342, 12937), (0x8343, 7378), (0x8344, 12927), (0x8345, 12951), (0x8347, 12950), (0x8348, 12958), (0x8349, 7367), (0x834a, 7364),
+    (0x834b, 12956), (0x834c, 12952), (0x834d, 12943), (0x834e, 12938), (0x834f, 7370), (0x8350, 7366), (0x8351, 12929), (0x8352, 7362),
+    (0x8353, 12953), (0x8354, 7363), (0x8356, 12931), (0x8373, 13532), (0x8374, 13534), (0x8375, 13539), (0x8376, 13559), (0x8377, 7847),
+    (0x8378, 7837), (0x837a, 13531), (0x837b, 7848), (0x837c, 7849), (0x837d, 13542), (0x837e, 13549), (0x837f, 13555), (0x8381, 13536),
+    (0x8383, 13543), (0x8386, 7850), (0x8387, 13557), (0x8388, 13552), (0x8389, 7845), (0x838a, 7843), (0x838b, 13548), (0x838c, 13544),
+    (0x838d, 13530), (0x838e, 7834), (0x838f, 13535), (0x8390, 13527), (0x8392, 7842), (0x8393, 7844), (0x8394, 13540), (0x8395, 13537),
+    (0x8396, 7839), (0x8397, 13553), (0x8398, 7836), (0x8399, 13538), (0x839a, 13560), (0x839b, 13546), (0x839d, 13545), (0x839e, 7835),
+    (0x83a0, 7846), (0x83a2, 7838), (0x83a3, 13528), (0x83a4, 13533), (0x83a5, 13550), (0x83a6, 13556), (0x83a7, 7851), (0x83a8, 13529),
+    (0x83a9, 13541), (0x83aa, 13547), (0x83ab, 7841), (0x83ae, 13558), (0x83af, 13551), (0x83b0, 13554), (0x83bd, 7840), (0x83bf, 14174),
+    (0x83c0, 14162), (0x83c1, 8281), (0x83c2, 14191), (0x83c3, 14199), (0x83c4, 14202), (0x83c5, 8279), (0x83c6, 14170), (0x83c7, 14195),
+    (0x83c8, 14171), (0x83c9, 14185), (0x83ca, 8292), (0x83cb, 14181), (0x83cc, 8289), (0x83ce, 14182), (0x83cf, 14159), (0x83d1, 14196),
+    (0x83d4, 8298), (0x83d5, 14193), (0x83d6, 14183), (0x83d7, 14204), (0x83d8, 14178), (0x83d9, 14860), (0x83db, 14207), (0x83dc, 8296),
+    (0x83dd, 14176), (0x83de, 14188), (0x83df, 8299), (0x83e0, 8278), (0x83e1, 14180), (0x83e2, 14205), (0x83e3, 14173), (0x83e4, 14166),
+    (0x83e5, 14177), (0x83e7, 14165), (0x83e8, 14163), (0x83e9, 8274), (0x83ea, 14197), (0x83eb, 14172), (0x83ec, 14200), (0x83ee, 14201),
+    (0x83ef, 8282), (0x83f0, 8287), (0x83f1, 8283), (0x83f2, 8291), (0x83f3, 14192), (0x83f4, 8284), (0x83f5, 14184), (0x83f6, 14168),
+    (0x83f8, 8276), (0x83f9, 14160), (0x83fa, 14194), (0x83fb, 14203), (0x83fc, 14167), (0x83fd, 8290), (0x83fe, 14208), (0x83ff, 14179),
+    (0x8401, 14175), (0x8403, 8275), (0x8404, 8295), (0x8406, 14190), (0x8407, 8297), (0x8409, 14186), (0x840a, 8286), (0x840b, 8280),
+    (0x840c, 8288), (0x840d, 8277), (0x840e, 8294), (0x840f, 14187), (0x8410, 14169), (0x8411, 14189), (0x8412, 14164), (0x8413, 14198),
+    (0x841b, 14206), (0x8423, 14161), (0x8429, 14859), (0x842b, 14881), (0x842c, 8656), (0x842d, 14864), (0x842f, 14862), (0x8430, 14841),
+    (0x8431, 8706), (0x8432, 14857), (0x8433, 14877), (0x8434, 14853), (0x8435, 8714), (0x8436, 14876), (0x8437, 14851), (0x8438, 8293),
+    (0x8439, 14867), (0x843a, 14852), (0x843b, 14874), (0x843c, 8713), (0x843d, 8705), (0x843f, 14830), (0x8440, 14838), (0x8442, 14863),
+    (0x8443, 14855), (0x8444, 14880), (0x8445, 14858), (0x8446, 8719), (0x8447, 14875), (0x8449, 8710), (0x844b, 14861), (0x844c, 14869),
+    (0x844d, 14842), (0x844e, 14868), (0x8450, 14885), (0x8451, 14837), (0x8452, 14870), (0x8454, 14883), (0x8456, 14831), (0x8457, 8285),
+    (0x8459, 14845), (0x845a, 14844), (0x845b, 8712), (0x845d, 14848), (0x845e, 14850), (0x845f, 14865), (0x8460, 14882), (0x8461, 8715),
+    (0x8463, 8716), (0x8465, 14836), (0x8466, 8708), (0x8467, 14840), (0x8468, 14878), (0x8469, 8717), (0x846b, 8709), (0x846c, 8711),
+    (0x846d, 8718), (0x846e, 14884), (0x846f, 14871), (0x8470, 14866), (0x8473, 14847), (0x8474, 14846), (0x8475, 8707), (0x8476, 14832),
+    (0x8477, 8704), (0x8478, 14856), (0x8479, 14833), (0x847a, 14854), (0x847d, 14843), (0x847e, 14879), (0x8482, 8703), (0x8486, 14839),
+    (0x848d, 14835), (0x848e, 14873), (0x848f, 14834), (0x8490, 9138), (0x8491, 15499), (0x8494, 15482), (0x8497, 15460), (0x8498, 15490),
+    (0x8499, 9130), (0x849a, 15475), (0x849b, 15485), (0x849c, 9133), (0x849d, 15478), (0x849e, 9131), (0x849f, 15463), (0x84a0, 15493),
+    (0x84a1, 15462), (0x84a2, 15481), (0x84a4, 15461), (0x84a7, 15479), (0x84a8, 15488), (0x84a9, 15486), (0x84aa, 15474), (0x84ab, 15469),
+    (0x84ac, 15467), (0x84ae, 15468), (0x84af, 15487), (0x84b0, 15498), (0x84b1, 15476), (0x84b2, 9132), (0x84b4, 15471), (0x84b6, 15491),
+    (0x84b8, 9135), (0x84b9, 15470), (0x84ba, 15464), (0x84bb, 15480), (0x84bc, 9139), (0x84bf, 9127), (0x84c0, 9136), (0x84c1, 15472),
+    (0x84c2, 15466), (0x84c4, 9129), (0x84c5, 14872), (0x84c6, 9128), (0x84c7, 15483), (0x84c9, 9126), (0x84ca, 9141), (0x84cb, 9134),
+    (0x84cc, 15484), (0x84cd, 15473), (0x84ce, 15465), (0x84cf, 15492), (0x84d0, 15477), (0x84d1, 9140), (0x84d2, 15496), (0x84d3, 9137),
+    (0x84d4, 15495), (0x84d6, 15489), (0x84d7, 15494), (0x84db, 15497), (0x84e7, 16065), (0x84e8, 16066), (0x84e9, 16053), (0x84ea, 16052),
+    (0x84eb, 16048), (0x84ec, 9502), (0x84ee, 9494), (0x84ef, 16068), (0x84f0, 16067), (0x84f1, 14829), (0x84f2, 16045), (0x84f3, 16049),
+    (0x84f4, 16043), (0x84f6, 16062), (0x84f7, 16047), (0x84f9, 16069), (0x84fa, 16040), (0x84fb, 16038), (0x84fc, 16050), (0x84fd, 16060),
+    (0x84fe, 16055), (0x84ff, 9504), (0x8500, 16029), (0x8502, 16059), (0x8506, 9505), (0x8507, 14849), (0x8508, 16041), (0x8509, 16032),
+    (0x850a, 16035), (0x850b, 16073), (0x850c, 16042), (0x850d, 16033), (0x850e, 16031), (0x850f, 16028), (0x8511, 9498), (0x8512, 16051),
+    (0x8513, 9497), (0x8514, 9501), (0x8515, 16046), (0x8516, 16054), (0x8517, 9491), (0x8518, 16070), (0x8519, 16074), (0x851a, 9493),
+    (0x851c, 16037), (0x851d, 16057), (0x851e, 16061), (0x851f, 16034), (0x8520, 16071), (0x8521, 9500), (0x8523, 9499), (0x8524, 16026),
+    (0x8525, 9503), (0x8526, 16064), (0x8527, 16036), (0x8528, 16056), (0x8529, 16030), (0x852a, 16044), (0x852b, 16039), (0x852c, 9495),
+    (0x852d, 9496), (0x852e, 16058), (0x852f, 16075), (0x8530, 16072), (0x8531, 16063), (0x853b, 16027), (0x853d, 9492), (0x853e, 16651),
+    (0x8540, 16642), (0x8541, 16645), (0x8543, 9823), (0x8544, 16647), (0x8545, 16636), (0x8546, 16643), (0x8547, 16649), (0x8548, 9820),
+    (0x8549, 9824), (0x854a, 9818), (0x854d, 16638), (0x854e, 16654), (0x8551, 16648), (0x8553, 16639), (0x8554, 16663), (0x8555, 16657),
+    (0x8556, 16635), (0x8557, 17119), (0x8558, 16641), (0x8559, 9819), (0x855b, 16652), (0x855d, 16662), (0x855e, 9827), (0x8560, 16659),
+    (0x8561, 16640), (0x8562, 16646), (0x8563, 16650), (0x8564, 16644), (0x8565, 16664), (0x8566, 16661), (0x8567, 16658), (0x8568, 9821),
+    (0x8569, 9822), (0x856a, 9826), (0x856b, 16637), (0x856c, 16665), (0x856d, 9825), (0x856e, 16655), (0x8571, 16653), (0x8575, 16656),
+    (0x8576, 17132), (0x8577, 17113), (0x8578, 17118), (0x8579, 17131), (0x857a, 17117), (0x857b, 17109), (0x857c, 17114), (0x857e, 10112),
+    (0x8580, 17102), (0x8581, 17126), (0x8582, 17128), (0x8583, 17101), (0x8584, 10111), (0x8585, 17130), (0x8586, 17122), (0x8587, 10118),
+    (0x8588, 17129), (0x8589, 17115), (0x858a, 10120), (0x858b, 17107), (0x858c, 16660), (0x858d, 17123), (0x858e, 17120), (0x858f, 17103),
+    (0x8590, 17134), (0x8591, 10114), (0x8594, 10115), (0x8595, 17105), (0x8596, 17121), (0x8598, 17133), (0x8599, 17124), (0x859a, 17111),
+    (0x859b, 10117), (0x859c, 10113), (0x859d, 17125), (0x859e, 17112), (0x859f, 17135), (0x85a0, 17106), (0x85a1, 17116), (0x85a2, 17127),
+    (0x85a3, 17108), (0x85a4, 17110), (0x85a6, 10310), (0x85a7, 17104), (0x85a8, 10119), (0x85a9, 10303), (0x85aa, 10110), (0x85af, 10116),
+    (0x85b0, 10307), (0x85b1, 17508), (0x85b3, 17498), (0x85b4, 17493), (0x85b5, 17499), (0x85b6, 17509), (0x85b7, 17513), (0x85b8, 17512),
+    (0x85b9, 10309), (0x85ba, 10308), (0x85bd, 17500), (0x85be, 17514), (0x85bf, 17503), (0x85c0, 17495), (0x85c2, 17497), (0x85c3, 17496),
+    (0x85c4, 17502), (0x85c5, 17507), (0x85c6, 17494), (0x85c7, 17501), (0x85c8, 17506), (0x85c9, 10306), (0x85cb, 17504), (0x85cd, 10304),
+    (0x85ce, 17505), (0x85cf, 10302), (0x85d0, 10305), (0x85d1, 17856), (0x85d2, 17510), (0x85d5, 10458), (0x85d7, 17848), (0x85d8, 17852),
+    (0x85d9, 17844), (0x85da, 17847), (0x85dc, 17855), (0x85dd, 10456), (0x85de, 17860), (0x85df, 17853), (0x85e1, 17845), (0x85e2, 17861),
+    (0x85e3, 17854), (0x85e4, 10459), (0x85e5, 10460), (0x85e6, 17858), (0x85e8, 17846), (0x85e9, 10455), (0x85ea, 10457), (0x85eb, 17841),
+    (0x85ec, 17849), (0x85ed, 17843), (0x85ef, 17859), (0x85f0, 17857), (0x85f1, 17842), (0x85f2, 17850), (0x85f6, 18147), (0x85f7, 10461),
+    (0x85f8, 17851), (0x85f9, 10588), (0x85fa, 10590), (0x85fb, 10587), (0x85fd, 18152), (0x85fe, 18144), (0x85ff, 18142), (0x8600, 18146),
+    (0x8601, 18143), (0x8604, 18148), (0x8605, 18150), (0x8606, 10591), (0x8607, 10593), (0x8609, 18149), (0x860a, 10594), (0x860b, 10592),
+    (0x860c, 18151), (0x8611, 10589), (0x8617, 10665), (0x8618, 18355), (0x8619, 18361), (0x861a, 10667), (0x861b, 18145), (0x861c, 18360),
+    (0x861e, 18367), (0x861f, 18358), (0x8620, 18365), (0x8621, 18364), (0x8622, 18141), (0x8623, 18359), (0x8624, 17511), (0x8625, 18368),
+    (0x8626, 18357), (0x8627, 18362), (0x8629, 18366), (0x862a, 18356), (0x862c, 18536), (0x862d, 10666), (0x862e, 18363), (0x8631, 18672),
+    (0x8632, 18537), (0x8633, 18535), (0x8634, 18533), (0x8635, 18534), (0x8636, 18538), (0x8638, 10795), (0x8639, 18670), (0x863a, 18668),
+    (0x863b, 18673), (0x863c, 18671), (0x863e, 18674), (0x863f, 10796), (0x8640, 18669), (0x8643, 18773), (0x8646, 18845), (0x8647, 18846),
+    (0x8648, 18847), (0x864b, 18941), (0x864c, 18931), (0x864d, 11476), (0x864e, 6573), (0x8650, 6948), (0x8652, 12960), (0x8653, 12959),
+    (0x8654, 7379), (0x8655, 7852), (0x8656, 13562), (0x8659, 13561), (0x865b, 8300), (0x865c, 8721), (0x865e, 8720), (0x865f, 8722),
+    (0x8661, 15500), (0x8662, 16076), (0x8663, 16666), (0x8664, 16668), (0x8665, 16667), (0x8667, 10121), (0x8668, 17136), (0x8669, 17515),
+    (0x866a, 18907), (0x866b, 5939), (0x866d, 12035), (0x866e, 12036), (0x866f, 12034), (0x8670, 12033), (0x8671, 6574), (0x8673, 12474),
+    (0x8674, 12472), (0x8677, 12471), (0x8679, 6949), (0x867a, 6951), (0x867b, 6950), (0x867c, 12473), (0x8685, 12972), (0x8686, 12969),
+    (0x8687, 12967), (0x868a, 7380), (0x868b, 12970), (0x868c, 7385), (0x868d, 12964), (0x868e, 12979), (0x8690, 12981), (0x8691, 12965),
+    (0x8693, 7382), (0x8694, 12982), (0x8695, 12977), (0x8696, 12963), (0x8697, 12968), (0x8698, 12978), (0x8699, 12974), (0x869a, 12971),
+    (0x869c, 7387), (0x869d, 12980), (0x869e, 12966), (0x86a1, 12975), (0x86a2, 12961), (0x86a3, 7386), (0x86a4, 7383), (0x86a5, 12973),
+    (0x86a7, 12976), (0x86a8, 12962), (0x86a9, 7384), (0x86aa, 7381), (0x86af, 7862), (0x86b0, 13569), (0x86b1, 7861), (0x86b3, 13572),
+    (0x86b4, 13575), (0x86b5, 7858), (0x86b6, 7856), (0x86b7, 13564), (0x86b8, 13573), (0x86b9, 13571), (0x86ba, 13568), (0x86bb, 13576),
+    (0x86bc, 13577), (0x86bd, 13579), (0x86be, 13580), (0x86bf, 13563), (0x86c0, 7855), (0x86c1, 13566), (0x86c2, 13565), (0x86c3, 13578),
+    (0x86c4, 7857), (0x86c5, 13567), (0x86c6, 7859), (0x86c7, 7854), (0x86c8, 13570), (0x86c9, 7863), (0x86cb, 7860), (0x86cc, 13574),
+    (0x86d0, 8307), (0x86d1, 14223), (0x86d3, 14212), (0x86d4, 8304), (0x86d6, 14891), (0x86d7, 14221), (0x86d8, 14209), (0x86d9, 8302),
+    (0x86da, 14214), (0x86db, 8305), (0x86dc, 14218), (0x86dd, 14216), (0x86de, 8308), (0x86df, 8301), (0x86e2, 14210), (0x86e3, 14213),
+    (0x86e4, 8306), (0x86e6, 14211), (0x86e8, 14222), (0x86e9, 14220), (0x86ea, 14215), (0x86eb, 14217), (0x86ec, 14219), (0x86ed, 8303),
+    (0x86f5, 14892), (0x86f6, 14898), (0x86f7, 14888), (0x86f8, 14894), (0x86f9, 8723), (0x86fa, 14890), (0x86fb, 8729), (0x86fe, 8728),
+    (0x8700, 8727), (0x8701, 14897), (0x8702, 8730), (0x8703, 8731), (0x8704, 14887), (0x8705, 14900), (0x8706, 8732), (0x8707, 8726),
+    (0x8708, 8725), (0x8709, 14896), (0x870a, 8733), (0x870b, 14886), (0x870c, 14889), (0x870d, 14899), (0x870e, 14895), (0x8711, 15532),
+    (0x8712, 15521), (0x8713, 8724), (0x8718, 9148), (0x8719, 15509), (0x871a, 15530), (0x871b, 15510), (0x871c, 9143), (0x871e, 15507),
+    (0x8720, 15516), (0x8721, 15508), (0x8722, 9145), (0x8723, 15502), (0x8724, 15529), (0x8725, 9146), (0x8726, 15526), (0x8727, 15527),
+    (0x8728, 15503), (0x8729, 9151), (0x872a, 15518), (0x872c, 15512), (0x872d, 15519), (0x872e, 15506), (0x8730, 15531), (0x8731, 15523),
+    (0x8732, 15517), (0x8733, 15501), (0x8734, 9147), (0x8735, 15524), (0x8737, 9150), (0x8738, 15528), (0x873a, 15522), (0x873b, 9144),
+    (0x873c, 15520), (0x873e, 15514), (0x873f, 9142), (0x8740, 15505), (0x8741, 15513), (0x8742, 15525), (0x8743, 15511), (0x8746, 15515),
+    (0x874c, 9515), (0x874d, 14893), (0x874e, 16094), (0x874f, 16103), (0x8750, 16093), (0x8751, 16089), (0x8752, 16086), (0x8753, 9516),
+    (0x8754, 16084), (0x8755, 9149), (0x8756, 16077), (0x8757, 9514), (0x8758, 16083), (0x8759, 9513), (0x875a, 16088), (0x875b, 16085),
+    (0x875c, 16101), (0x875d, 16096), (0x875e, 16090), (0x875f, 16095), (0x8760, 9509), (0x8761, 16087), (0x8762, 16106), (0x8763, 16078),
+    (0x8764, 16079), (0x8765, 16102), (0x8766, 9510), (0x8767, 16107), (0x8768, 9512), (0x8769, 16108), (0x876a, 16092), (0x876b, 15504),
+    (0x876c, 16098), (0x876d, 16091), (0x876e, 16100), (0x876f, 16097), (0x8773, 16082), (0x8774, 9507), (0x8775, 16105), (0x8776, 9508),
+    (0x8777, 16080), (0x8778, 9511), (0x8779, 16678), (0x877a, 16099), (0x877b, 16104), (0x8781, 16675), (0x8782, 9506), (0x8783, 9828),
+    (0x8784, 16685), (0x8785, 16681), (0x8787, 16679), (0x8788, 16674), (0x8789, 16689), (0x878d, 9832), (0x878f, 16670), (0x8790, 16682),
+    (0x8791, 16683), (0x8792, 16673), (0x8793, 16672), (0x8794, 16686), (0x8796, 16676), (0x8797, 16671), (0x8798, 16677), (0x879a, 16688),
+    (0x879b, 16669), (0x879c, 16687), (0x879d, 16684), (0x879e, 9830), (0x879f, 9829), (0x87a2, 9831), (0x87a3, 16680), (0x87a4, 17532),
+    (0x87aa, 17138), (0x87ab, 10127), (0x87ac, 17142), (0x87ad, 17139), (0x87ae, 17146), (0x87af, 17152), (0x87b0, 17141), (0x87b2, 17161),
+    (0x87b3, 10124), (0x87b4, 17155), (0x87b5, 17144), (0x87b6, 17156), (0x87b7, 17151), (0x87b8, 17158), (0x87b9, 17143), (0x87ba, 10129),
+    (0x87bb, 10128), (0x87bc, 17145), (0x87bd, 17159), (0x87be, 17137), (0x87bf, 17157), (0x87c0, 10122), (0x87c2, 17149), (0x87c3, 17148),
+    (0x87c4, 17153), (0x87c5, 17140), (0x87c6, 10126), (0x87c8, 10130), (0x87c9, 17147), (0x87ca, 17154), (0x87cb, 10131), (0x87cc, 17150),
+    (0x87d1, 10123), (0x87d2, 10125), (0x87d3, 17528), (0x87d4, 17526), (0x87d7, 17533), (0x87d8, 17530), (0x87d9, 17534), (0x87db, 17519),
+    (0x87dc, 17527), (0x87dd, 17538), (0x87de, 17160), (0x87df, 17523), (0x87e0, 10314), (0x87e1, 16081), (0x87e2, 17518), (0x87e3, 17531),
+    (0x87e4, 17525), (0x87e5, 17522), (0x87e6, 17517), (0x87e7, 17516), (0x87e8, 17537), (0x87ea, 17521), (0x87eb, 17520), (0x87ec, 10312),
+    (0x87ed, 17529), (0x87ef, 10311), (0x87f2, 10313), (0x87f3, 17524), (0x87f4, 17536), (0x87f6, 17865), (0x87f7, 17866), (0x87f9, 10465),
+    (0x87fa, 17863), (0x87fb, 10462), (0x87fc, 17871), (0x87fe, 10466), (0x87ff, 17873), (0x8800, 17862), (0x8801, 17535), (0x8802, 17875),
+    (0x8803, 17864), (0x8805, 10463), (0x8806, 17870), (0x8808, 17872), (0x8809, 17867), (0x880a, 17874), (0x880b, 17869), (0x880c, 17868),
+    (0x880d, 10464), (0x8810, 18154), (0x8811, 18155), (0x8813, 18157), (0x8814, 10595), (0x8815, 10596), (0x8816, 18158), (0x8817, 18156),
+    (0x8819, 18153), (0x881b, 18371), (0x881c, 18374), (0x881d, 18370), (0x881f, 10671), (0x8820, 18372), (0x8821, 10670), (0x8822, 10669),
+    (0x8823, 10668), (0x8824, 18373), (0x8825, 18543), (0x8826, 18541), (0x8828, 18540), (0x8829, 18369), (0x882a, 18542), (0x882b, 18375),
+    (0x882c, 18539), (0x882e, 18677), (0x882f, 18643), (0x8830, 18675), (0x8831, 10797), (0x8832, 18676), (0x8833, 18678), (0x8835, 18776),
+    (0x8836, 10828), (0x8837, 18775), (0x8838, 18774), (0x8839, 10829), (0x883b, 10859), (0x883c, 18886), (0x883d, 18909), (0x883e, 18908),
+    (0x883f, 18910), (0x8840, 5940), (0x8841, 12475), (0x8843, 12983), (0x8844, 12984), (0x8848, 14224), (0x884a, 18376), (0x884b, 18777),
+    (0x884c, 5941), (0x884d, 6952), (0x884e, 12476), (0x8852, 13581), (0x8853, 7864), (0x8855, 14226), (0x8856, 14225), (0x8857, 8309),
+    (0x8859, 8734), (0x885a, 16109), (0x885b, 9517), (0x885d, 9518), (0x8861, 9833), (0x8862, 10830), (0x8863, 5942), (0x8867, 12477),
+    (0x8868, 6576), (0x8869, 12479), (0x886a, 12478), (0x886b, 6953), (0x886d, 12985), (0x886f, 12992), (0x8870, 7388), (0x8871, 12990),
+    (0x8872, 12988), (0x8874, 12995), (0x8875, 12986), (0x8876, 12987), (0x8877, 7389), (0x8879, 7393), (0x887c, 12996), (0x887d, 7392),
+    (0x887e, 12994), (0x887f, 12991), (0x8880, 12989), (0x8881, 7390), (0x8882, 7391), (0x8883, 12993), (0x8888, 7866), (0x8889, 13582),
+    (0x888b, 7871), (0x888c, 13598), (0x888d, 7870), (0x888e, 13600), (0x8891, 13588), (0x8892, 7868), (0x8893, 13599), (0x8895, 13583),
+    (0x8896, 7869), (0x8897, 13595), (0x8898, 13591), (0x8899, 13593), (0x889a, 13587), (0x889b, 13594), (0x889e, 7865), (0x889f, 13590),
+    (0x88a1, 13589), (0x88a2, 13585), (0x88a4, 13596), (0x88a7, 13592), (0x88a8, 13584), (0x88aa, 13586), (0x88ab, 7867), (0x88ac, 13597),
+    (0x88b1, 8312), (0x88b2, 14237), (0x88b6, 14233), (0x88b7, 14235), (0x88b8, 14230), (0x88b9, 14229), (0x88ba, 14227), (0x88bc, 14234),
+    (0x88bd, 14236), (0x88be, 14232), (0x88c0, 14231), (0x88c1, 8310), (0x88c2, 8311), (0x88c9, 14239), (0x88ca, 8742), (0x88cb, 14902),
+    (0x88cc, 14908), (0x88cd, 14903), (0x88ce, 14904), (0x88d0, 14909), (0x88d2, 8744), (0x88d4, 8736), (0x88d5, 8743), (0x88d6, 14901),
+    (0x88d7, 14228), (0x88d8, 8739), (0x88d9, 8737), (0x88da, 14907), (0x88db, 14906), (0x88dc, 8738), (0x88dd, 8740), (0x88de, 14905),
+    (0x88df, 8735), (0x88e1, 8741), (0x88e7, 15534), (0x88e8, 9158), (0x88eb, 15545), (0x88ec, 15544), (0x88ee, 15539), (0x88ef, 9160),
+    (0x88f0, 15543), (0x88f1, 15535), (0x88f2, 15536), (0x88f3, 9152), (0x88f4, 9154), (0x88f6, 15541), (0x88f7, 15533), (0x88f8, 9156),
+    (0x88f9, 9155), (0x88fa, 15537), (0x88fb, 15542), (0x88fc, 15540), (0x88fd, 9157), (0x88fe, 15538), (0x8901, 14238), (0x8902, 9153),
+    (0x8905, 16110), (0x8906, 16117), (0x8907, 9520), (0x8909, 16121), (0x890a, 9524), (0x890b, 16113), (0x890c, 16111), (0x890e, 16120),
+    (0x8910, 9519), (0x8911, 16119), (0x8912, 9521), (0x8913, 9522), (0x8914, 16112), (0x8915, 9523), (0x8916, 16118), (0x8917, 16114),
+    (0x8918, 16115), (0x8919, 16116), (0x891a, 9159), (0x891e, 16690), (0x891f, 16702), (0x8921, 9838), (0x8922, 16697), (0x8923, 16699),
+    (0x8925, 9836), (0x8926, 16691), (0x8927, 16695), (0x8929, 16698), (0x892a, 9834), (0x892b, 9837), (0x892c, 16701), (0x892d, 16693),
+    (0x892e, 16694), (0x892f, 16700), (0x8930, 16692), (0x8931, 16696), (0x8932, 9835), (0x8933, 17163), (0x8935, 17162), (0x8936, 10133),
+    (0x8937, 17168), (0x8938, 10135), (0x893b, 10132), (0x893c, 17164), (0x893d, 10136), (0x893e, 17165), (0x8941, 17166), (0x8942, 17169),
+    (0x8944, 10134), (0x8946, 17543), (0x8949, 17546), (0x894b, 17540), (0x894c, 17542), (0x894f, 17541), (0x8950, 17544), (0x8951, 17545),
+    (0x8952, 17167), (0x8953, 17539), (0x8956, 10469), (0x8957, 17879), (0x8958, 17882), (0x8959, 17884), (0x895a, 17877), (0x895b, 17878),
+    (0x895c, 17881), (0x895d, 17883), (0x895e, 10470), (0x895f, 10468), (0x8960, 10467), (0x8961, 17880), (0x8962, 17876), (0x8963, 18159),
+    (0x8964, 10597), (0x8966, 18160), (0x8969, 18378), (0x896a, 10672), (0x896b, 18380), (0x896c, 10673), (0x896d, 18377), (0x896e, 18379),
+    (0x896f, 10746), (0x8971, 18544), (0x8972, 10745), (0x8973, 18681), (0x8974, 18680), (0x8976, 18679), (0x8979, 18848), (0x897a, 18849),
+    (0x897b, 18851), (0x897c, 18850), (0x897e, 11477), (0x897f, 5943), (0x8981, 6954), (0x8982, 13601), (0x8983, 8313), (0x8985, 14910),
+    (0x8986, 10315), (0x8988, 17885), (0x898b, 6188), (0x898f, 7873), (0x8993, 7872), (0x8995, 14240), (0x8996, 8314), (0x8997, 14242),
+    (0x8998, 14241), (0x899b, 14911), (0x899c, 8745), (0x899d, 15546), (0x899e, 15549), (0x899f, 15548), (0x89a1, 15547), (0x89a2, 16122),
+    (0x89a3, 16124), (0x89a4, 16123), (0x89a6, 9840), (0x89aa, 9839), (0x89ac, 10137), (0x89ad, 17170), (0x89ae, 17172), (0x89af, 17171),
+    (0x89b2, 10316), (0x89b6, 17887), (0x89b7, 17886), (0x89b9, 18161), (0x89ba, 10598), (0x89bd, 10674), (0x89be, 18546), (0x89bf, 18545),
+    (0x89c0, 10860), (0x89d2, 6189), (0x89d3, 12480), (0x89d4, 6955), (0x89d5, 13604), (0x89d6, 13602), (0x89d9, 13603), (0x89da, 14244),
+    (0x89db, 14245), (0x89dc, 14918), (0x89dd, 14243), (0x89df, 14912), (0x89e0, 14916), (0x89e1, 14915), (0x89e2, 14917), (0x89e3, 8746),
+    (0x89e4, 14914), (0x89e5, 14913), (0x89e6, 14919), (0x89e8, 15552), (0x89e9, 15550), (0x89eb, 15551), (0x89ec, 16127), (0x89ed, 16125),
+    (0x89f0, 16126), (0x89f1, 16703), (0x89f2, 17173), (0x89f3, 17174), (0x89f4, 10317), (0x89f6, 17888), (0x89f7, 18162), (0x89f8, 10599),
+    (0x89fa, 18381), (0x89fb, 18547), (0x89fc, 10747), (0x89fe, 18682), (0x89ff, 18852), (0x8a00, 6190), (0x8a02, 6957), (0x8a03, 6958),
+    (0x8a04, 12481), (0x8a07, 12482), (0x8a08, 6956), (0x8a0a, 7399), (0x8a0c, 7397), (0x8a0e, 7396), (0x8a0f, 7403), (0x8a10, 7395),
+    (0x8a11, 7404), (0x8a12, 12997), (0x8a13, 7401), (0x8a15, 7398), (0x8a16, 7402), (0x8a17, 7400), (0x8a18, 7394), (0x8a1b, 7881),
+    (0x8a1d, 7875), (0x8a1e, 13608), (0x8a1f, 7880), (0x8a22, 7882), (0x8a23, 7876), (0x8a25, 7877), (0x8a27, 13606), (0x8a2a, 7874),
+    (0x8a2c, 13607), (0x8a2d, 7879), (0x8a30, 13605), (0x8a31, 7878), (0x8a34, 8325), (0x8a36, 8327), (0x8a39, 14248), (0x8a3a, 8326),
+    (0x8a3b, 8315), (0x8a3c, 8319), (0x8a3e, 8765), (0x8a3f, 14924), (0x8a40, 14250), (0x8a41, 8320), (0x8a44, 14253), (0x8a45, 14254),
+    (0x8a46, 8324), (0x8a48, 14256), (0x8a4a, 14258), (0x8a4c, 14259), (0x8a4d, 14247), (0x8a4e, 14246), (0x8a4f, 14260), (0x8a50, 8323),
+    (0x8a51, 14257), (0x8a52, 14255), (0x8a54, 8321), (0x8a55, 8317), (0x8a56, 8328), (0x8a57, 14251), (0x8a58, 14252), (0x8a59, 14249),
+    (0x8a5b, 8322), (0x8a5e, 8318), (0x8a60, 8316), (0x8a61, 14923), (0x8a62, 8760), (0x8a63, 8755), (0x8a66, 8750), (0x8a68, 8766),
+    (0x8a69, 8751), (0x8a6b, 8747), (0x8a6c, 8762), (0x8a6d, 8759), (0x8a6e, 8761), (0x8a70, 8752), (0x8a71, 8757), (0x8a72, 8748),
+    (0x8a73, 8749), (0x8a74, 14931), (0x8a75, 14928), (0x8a76, 14920), (0x8a77, 14925), (0x8a79, 8763), (0x8a7a, 14932), (0x8a7b, 8764),
+    (0x8a7c, 8754), (0x8a7f, 14922), (0x8a81, 14930), (0x8a82, 14926), (0x8a83, 14929), (0x8a84, 14927), (0x8a85, 8758), (0x8a86, 14921),
+    (0x8a87, 8753), (0x8a8b, 15555), (0x8a8c, 9162), (0x8a8d, 9165), (0x8a8f, 15557), (0x8a91, 9173), (0x8a92, 15556), (0x8a93, 9167),
+    (0x8a95, 9529), (0x8a96, 15558), (0x8a98, 9172), (0x8a99, 15554), (0x8a9a, 9174), (0x8a9e, 9163), (0x8aa0, 8756), (0x8aa1, 9166),
+    (0x8aa3, 9164), (0x8aa4, 9168), (0x8aa5, 9170), (0x8aa6, 9161), (0x8aa7, 9175), (0x8aa8, 9171), (0x8aaa, 9169), (0x8aab, 15553),
+    (0x8ab0, 9536), (0x8ab2, 9532), (0x8ab6, 9539), (0x8ab8, 16130), (0x8ab9, 9540), (0x8aba, 16142), (0x8abb, 16135), (0x8abc, 9525),
+    (0x8abd, 16143), (0x8abe, 16137), (0x8abf, 9535), (0x8ac0, 16138), (0x8ac2, 9534), (0x8ac3, 16141), (0x8ac4, 9528), (0x8ac5, 16139),
+    (0x8ac6, 16129), (0x8ac7, 9527), (0x8ac8, 16714), (0x8ac9, 9533), (0x8acb, 9530), (0x8acd, 9538), (0x8acf, 16128), (0x8ad1, 16132),
+    (0x8ad2, 9526), (0x8ad3, 16131), (0x8ad4, 16133), (0x8ad5, 16134), (0x8ad6, 9537), (0x8ad7, 16136), (0x8ad8, 16140), (0x8ad9, 16144),
+    (0x8adb, 9541), (0x8adc, 9846), (0x8add, 16709), (0x8ade, 16715), (0x8adf, 16712), (0x8ae0, 16704), (0x8ae1, 16716), (0x8ae2, 16705),
+    (0x8ae4, 16711), (0x8ae6, 9841), (0x8ae7, 9847), (0x8ae8, 16717), (0x8aeb, 9843), (0x8aed, 9853), (0x8aee, 9848), (0x8aef, 16719),
+    (0x8af0, 16713), (0x8af1, 9844), (0x8af2, 16706), (0x8af3, 9854), (0x8af4, 16707), (0x8af5, 16708), (0x8af6, 9855), (0x8af7, 9852),
+    (0x8af8, 9531), (0x8afa, 9842), (0x8afb, 16720), (0x8afc, 9856), (0x8afe, 9849), (0x8aff, 16718), (0x8b00, 9845), (0x8b01, 9850),
+    (0x8b02, 9851), (0x8b04, 10145), (0x8b05, 17179), (0x8b06, 17188), (0x8b07, 17185), (0x8b08, 17187), (0x8b0a, 10142), (0x8b0b, 17180),
+    (0x8b0d, 17186), (0x8b0e, 10138), (0x8b0f, 17182), (0x8b10, 10146), (0x8b11, 17178), (0x8b12, 17183), (0x8b13, 17190), (0x8b14, 16710),
+    (0x8b15, 17184), (0x8b16, 17177), (0x8b17, 10139), (0x8b18, 17176), (0x8b19, 10140), (0x8b1a, 17191), (0x8b1b, 10141), (0x8b1c, 17189),
+    (0x8b1d, 10144), (0x8b1e, 17175), (0x8b20, 10143), (0x8b22, 17181), (0x8b23, 17549), (0x8b24, 17563), (0x8b25, 17558), (0x8b26, 17560),
+    (0x8b27, 17548), (0x8b28, 10318), (0x8b2a, 17547), (0x8b2b, 10321), (0x8b2c, 10320), (0x8b2e, 17562), (0x8b2f, 17554), (0x8b30, 17551),
+    (0x8b31, 17557), (0x8b33, 17550), (0x8b35, 17552), (0x8b36, 17561), (0x8b37, 17559), (0x8b39, 10319), (0x8b3a, 17566), (0x8b3b, 17564),
+    (0x8b3c, 17555), (0x8b3d, 17565), (0x8b3e, 17556), (0x8b40, 17892), (0x8b41, 10471), (0x8b42, 17899), (0x8b45, 18384), (0x8b46, 10478),
+    (0x8b47, 17553), (0x8b48, 17890), (0x8b49, 10474), (0x8b4a, 17891), (0x8b4b, 17896), (0x8b4e, 10476), (0x8b4f, 10477), (0x8b50, 17889),
+    (0x8b51, 17898), (0x8b52, 17900), (0x8b53, 17893), (0x8b54, 17895), (0x8b55, 17897), (0x8b56, 17894), (0x8b57, 17901), (0x8b58, 10473),
+    (0x8b59, 10479), (0x8b5a, 10475), (0x8b5c, 10472), (0x8b5d, 18165), (0x8b5f, 10604), (0x8b60, 18163), (0x8b63, 18167), (0x8b65, 18168),
+    (0x8b66, 10602), (0x8b67, 18169), (0x8b68, 18166), (0x8b6a, 18164), (0x8b6b, 10605), (0x8b6c, 10601), (0x8b6d, 18170), (0x8b6f, 10603),
+    (0x8b70, 10600), (0x8b74, 10675), (0x8b77, 10676), (0x8b78, 18383), (0x8b79, 18382), (0x8b7a, 18385), (0x8b7b, 18386), (0x8b7d, 10677),
+    (0x8b7e, 18548), (0x8b7f, 18553), (0x8b80, 10748), (0x8b82, 18550), (0x8b84, 18549), (0x8b85, 18552), (0x8b86, 18551), (0x8b88, 18686),
+    (0x8b8a, 10798), (0x8b8b, 18685), (0x8b8c, 18683), (0x8b8e, 18684), (0x8b92, 10832), (0x8b93, 10831), (0x8b94, 18778), (0x8b95, 18779),
+    (0x8b96, 10833), (0x8b98, 18853), (0x8b99, 18854), (0x8b9a, 10872), (0x8b9c, 10878), (0x8b9e, 18911), (0x8b9f, 18942), (0x8c37, 6191),
+    (0x8c39, 13609), (0x8c3b, 13610), (0x8c3c, 14933), (0x8c3d, 15559), (0x8c3e, 16145), (0x8c3f, 10148), (0x8c41, 10147), (0x8c42, 17567),
+    (0x8c43, 17902), (0x8c45, 18687), (0x8c46, 6192), (0x8c47, 12998), (0x8c48, 7405), (0x8c49, 7883), (0x8c4a, 14935), (0x8c4b, 14934),
+    (0x8c4c, 9542), (0x8c4d, 16146), (0x8c4e, 9543), (0x8c4f, 17192), (0x8c50, 10322), (0x8c54, 10887), (0x8c55, 6193), (0x8c56, 12037),
+    (0x8c57, 12999), (0x8c5a, 7884), (0x8c5c, 13611), (0x8c5d, 13612), (0x8c5f, 14261), (0x8c61, 8329), (0x8c62, 8767), (0x8c64, 14937),
+    (0x8c65, 14936), (0x8c66, 14938), (0x8c68, 15560), (0x8c69, 15561), (0x8c6a, 9176), (0x8c6b, 9857), (0x8c6c, 9544), (0x8c6d, 9858),
+    (0x8c6f, 17196), (0x8c70, 17193), (0x8c71, 17195), (0x8c72, 17194), (0x8c73, 10149), (0x8c75, 17568), (0x8c76, 17904), (0x8c77, 17903),
+    (0x8c78, 11684), (0x8c79, 7407), (0x8c7a, 7406), (0x8c7b, 13000), (0x8c7d, 13613), (0x8c80, 14263), (0x8c81, 14262), (0x8c82, 8330),
+    (0x8c84, 14940), (0x8c85, 14941), (0x8c86, 14939), (0x8c89, 8769), (0x8c8a, 8768), (0x8c8c, 9178), (0x8c8d, 9177), (0x8c8f, 16147),
+    (0x8c90, 16723), (0x8c91, 16721), (0x8c92, 16722), (0x8c93, 9859), (0x8c94, 17198), (0x8c95, 17197), (0x8c97, 17571), (0x8c98, 17570),
+    (0x8c99, 17569), (0x8c9a, 17905), (0x8c9c, 18912), (0x8c9d, 6194), (0x8c9e, 6959), (0x8ca0, 6960), (0x8ca1, 7408), (0x8ca2, 7409),
+    (0x8ca3, 13002), (0x8ca4, 13001), (0x8ca5, 13614), (0x8ca7, 7890), (0x8ca8, 7888), (0x8ca9, 7885), (0x8caa, 7889), (0x8cab, 7887),
+    (0x8cac, 7886), (0x8caf, 8331), (0x8cb0, 14266), (0x8cb2, 8774), (0x8cb3, 8333), (0x8cb4, 8338), (0x8cb5, 14268), (0x8cb6, 8340),
+    (0x8cb7, 8339), (0x8cb8, 8342), (0x8cb9, 14267), (0x8cba, 14264), (0x8cbb, 8336), (0x8cbc, 8332), (0x8cbd, 8334), (0x8cbe, 14265),
+    (0x8cbf, 8341), (0x8cc0, 8337), (0x8cc1, 8335), (0x8cc2, 8776), (0x8cc3, 8775), (0x8cc4, 8773), (0x8cc5, 8777), (0x8cc7, 8771),
+    (0x8cc8, 8772), (0x8cca, 8770), (0x8ccc, 14942), (0x8ccf, 15563), (0x8cd1, 9180), (0x8cd2, 9181), (0x8cd3, 9179), (0x8cd5, 15562),
+    (0x8cd7, 15564), (0x8cd9, 16150), (0x8cda, 16152), (0x8cdc, 9553), (0x8cdd, 16153), (0x8cde, 9546), (0x8cdf, 16149), (0x8ce0, 9545),
+    (0x8ce1, 9555), (0x8ce2, 9551), (0x8ce3, 9552), (0x8ce4, 9548), (0x8ce5, 16148), (0x8ce6, 9547), (0x8ce7, 16154), (0x8ce8, 16151),
+    (0x8cea, 9554), (0x8cec, 9549), (0x8ced, 9550), (0x8cee, 16725), (0x8cf0, 16727), (0x8cf1, 16726), (0x8cf3, 16728), (0x8cf4, 9860),
+    (0x8cf5, 16724), (0x8cf8, 10153), (0x8cf9, 17199), (0x8cfa, 10150), (0x8cfb, 10154), (0x8cfc, 10152), (0x8cfd, 10151), (0x8cfe, 17572),
+    (0x8d00, 17575), (0x8d02, 17574), (0x8d04, 17573), (0x8d05, 10323), (0x8d06, 17906), (0x8d07, 17907), (0x8d08, 10480), (0x8d09, 17908),
+    (0x8d0a, 10481), (0x8d0d, 10607), (0x8d0f, 10606), (0x8d10, 18387), (0x8d13, 10678), (0x8d14, 18388), (0x8d15, 18554), (0x8d16, 10749),
+    (0x8d17, 10750), (0x8d19, 18688), (0x8d1b, 10835), (0x8d64, 6195), (0x8d66, 7892), (0x8d67, 7891), (0x8d68, 14943), (0x8d69, 14944),
+    (0x8d6b, 9182), (0x8d6c, 16729), (0x8d6d, 9556), (0x8d6e, 16730), (0x8d6f, 17200), (0x8d70, 6196), (0x8d72, 12483), (0x8d73, 6962),
+    (0x8d74, 6961), (0x8d76, 13003), (0x8d77, 7410), (0x8d78, 13004), (0x8d79, 13617), (0x8d7b, 13616), (0x8d7d, 13615), (0x8d80, 14270),
+    (0x8d81, 8345), (0x8d84, 14269), (0x8d85, 8344), (0x8d89, 14271), (0x8d8a, 8343), (0x8d8c, 14946), (0x8d8d, 14949), (0x8d8e, 14947),
+    (0x8d8f, 14948), (0x8d90, 14952), (0x8d91, 14945), (0x8d92, 14953), (0x8d93, 14950), (0x8d94, 14951), (0x8d95, 9184), (0x8d96, 15565),
+    (0x8d99, 9183), (0x8d9b, 16158), (0x8d9c, 16156), (0x8d9f, 9557), (0x8da0, 16155), (0x8da1, 16157), (0x8da3, 9558), (0x8da5, 16731),
+    (0x8da7, 16732), (0x8da8, 10155), (0x8daa, 17910), (0x8dab, 17912), (0x8dac, 17909), (0x8dad, 17911), (0x8dae, 18171), (0x8daf, 18389),
+    (0x8db2, 18887), (0x8db3, 6197), (0x8db4, 6963), (0x8db5, 13005), (0x8db6, 13007), (0x8db7, 13006), (0x8db9, 13620), (0x8dba, 7894),
+    (0x8dbc, 13618), (0x8dbe, 7893), (0x8dbf, 13621), (0x8dc1, 13622), (0x8dc2, 13619), (0x8dc5, 14283), (0x8dc6, 8353), (0x8dc7, 14275),
+    (0x8dc8, 14281), (0x8dcb, 8348), (0x8dcc, 8351), (0x8dcd, 14274), (0x8dce, 8346), (0x8dcf, 14278), (0x8dd0, 14959), (0x8dd1, 8350),
+    (0x8dd3, 14273), (0x8dd5, 14279), (0x8dd6, 14276), (0x8dd7, 14282), (0x8dd8, 14272), (0x8dd9, 14280), (0x8dda, 8349), (0x8ddb, 8352),
+    (0x8ddc, 14277), (0x8ddd, 8347), (0x8ddf, 8779), (0x8de0, 14955), (0x8de1, 8778), (0x8de2, 14962), (0x8de3, 14961), (0x8de4, 8785),
+    (0x8de6, 8786), (0x8de7, 14963), (0x8de8, 8780), (0x8de9, 14960), (0x8dea, 8784), (0x8deb, 14965), (0x8dec, 14956), (0x8dee, 14958),
+    (0x8def, 8781), (0x8df0, 14954), (0x8df1, 14957), (0x8df2, 14964), (0x8df3, 8782), (0x8df4, 14966), (0x8dfa, 8783), (0x8dfc, 9185),
+    (0x8dfd, 15570), (0x8dfe, 15576), (0x8dff, 15568), (0x8e00, 15577), (0x8e02, 15567), (0x8e03, 15572), (0x8e04, 15578), (0x8e05, 15575),
+    (0x8e06, 15574), (0x8e07, 15573), (0x8e09, 15566), (0x8e0a, 15571), (0x8e0d, 15569), (0x8e0f, 9563), (0x8e10, 9560), (0x8e11, 16167),
+    (0x8e12, 16172), (0x8e13, 16174), (0x8e14, 16171), (0x8e15, 16164), (0x8e16, 16166), (0x8e17, 16176), (0x8e18, 16173), (0x8e19, 16168),
+    (0x8e1a, 16177), (0x8e1b, 16165), (0x8e1c, 16175), (0x8e1d, 9561), (0x8e1e, 9567), (0x8e1f, 9565), (0x8e20, 16159), (0x8e21, 9566),
+    (0x8e22, 9562), (0x8e23, 16160), (0x8e24, 16162), (0x8e25, 16161), (0x8e26, 16169), (0x8e27, 16170), (0x8e29, 9564), (0x8e2b, 9559),
+    (0x8e2e, 16163), (0x8e30, 16742), (0x8e31, 9862), (0x8e33, 16733), (0x8e34, 9863), (0x8e35, 9866), (0x8e36, 16738), (0x8e38, 16735),
+    (0x8e39, 9865), (0x8e3c, 16739), (0x8e3d, 16740), (0x8e3e, 16734), (0x8e3f, 16743), (0x8e40, 16736), (0x8e41, 16741), (0x8e42, 9864),
+    (0x8e44, 9861), (0x8e45, 16737), (0x8e47, 17206), (0x8e48, 10158), (0x8e49, 10156), (0x8e4a, 10159), (0x8e4b, 10157), (0x8e4c, 17205),
+    (0x8e4d, 17202), (0x8e4e, 17201), (0x8e50, 17204), (0x8e53, 17203), (0x8e54, 17589), (0x8e55, 10329), (0x8e56, 17580), (0x8e57, 17579),
+    (0x8e59, 10324), (0x8e5a, 17585), (0x8e5b, 17584), (0x8e5c, 17576), (0x8e5d, 17587), (0x8e5e, 17581), (0x8e5f, 10328), (0x8e60, 17578),
+    (0x8e61, 17586), (0x8e62, 17577), (0x8e63, 10325), (0x8e64, 10327), (0x8e65, 17582), (0x8e66, 10326), (0x8e67, 17583), (0x8e69, 17588),
+    (0x8e6a, 17916), (0x8e6c, 10486), (0x8e6d, 17913), (0x8e6f, 17917), (0x8e72, 10483), (0x8e73, 17915), (0x8e74, 10488), (0x8e76, 10485),
+    (0x8e78, 17914), (0x8e7a, 10487), (0x8e7b, 17918), (0x8e7c, 10482), (0x8e81, 10609), (0x8e82, 10611), (0x8e84, 18174), (0x8e85, 10610),
+    (0x8e86, 18172), (0x8e87, 10484), (0x8e88, 18173), (0x8e89, 10608), (0x8e8a, 10679), (0x8e8b, 10681), (0x8e8c, 18391), (0x8e8d, 10680),
+    (0x8e8e, 18390), (0x8e90, 18559), (0x8e91, 10751), (0x8e92, 18558), (0x8e93, 10752), (0x8e94, 18556), (0x8e95, 18555), (0x8e96, 18560),
+    (0x8e97, 18561), (0x8e98, 18689), (0x8e9a, 18557), (0x8e9d, 18783), (0x8e9e, 18780), (0x8e9f, 18781), (0x8ea0, 18782), (0x8ea1, 10861),
+    (0x8ea3, 18857), (0x8ea4, 18856), (0x8ea5, 18855), (0x8ea6, 18888), (0x8ea8, 18932), (0x8ea9, 18913), (0x8eaa, 10879), (0x8eab, 6198),
+    (0x8eac, 7411), (0x8eb2, 8787), (0x8eba, 9568), (0x8ebd, 16744), (0x8ec0, 10330), (0x8ec2, 17919), (0x8ec9, 18914), (0x8eca, 6199),
+    (0x8ecb, 6577), (0x8ecc, 6965), (0x8ecd, 6964), (0x8ecf, 7414), (0x8ed1, 13008), (0x8ed2, 7412), (0x8ed3, 13009), (0x8ed4, 7413),
+    (0x8ed7, 13627), (0x8ed8, 13623), (0x8edb, 7895), (0x8edc, 13626), (0x8edd, 13625), (0x8ede, 13624), (0x8edf, 7896), (0x8ee0, 13628),
+    (0x8ee1, 13629), (0x8ee5, 14290), (0x8ee6, 14288), (0x8ee7, 14292), (0x8ee8, 14293), (0x8ee9, 14299), (0x8eeb, 14295), (0x8eec, 14297),
+    (0x8eee, 14289), (0x8eef, 14284), (0x8ef1, 14296), (0x8ef4, 14298), (0x8ef5, 14291), (0x8ef6, 14294), (0x8ef7, 14285), (0x8ef8, 8355),
+    (0x8ef9, 14287), (0x8efa, 14286), (0x8efb, 8354), (0x8efc, 8356), (0x8efe, 8790), (0x8eff, 14968), (0x8f00, 14970), (0x8f01, 14969),
+    (0x8f02, 14974), (0x8f03, 8788), (0x8f05, 14971), (0x8f06, 14967), (0x8f07, 14972), (0x8f08, 14973), (0x8f09, 8789), (0x8f0a, 8791),
+    (0x8f0b, 14975), (0x8f0d, 15582), (0x8f0e, 15581), (0x8f10, 15579), (0x8f11, 15580), (0x8f12, 9187), (0x8f13, 9189), (0x8f14, 9186),
+    (0x8f15, 9188), (0x8f16, 16184), (0x8f17, 16185), (0x8f18, 16180), (0x8f1a, 16181), (0x8f1b, 9570), (0x8f1c, 9575), (0x8f1d, 9569),
+    (0x8f1e, 9576), (0x8f1f, 9571), (0x8f20, 16182), (0x8f23, 16183), (0x8f24, 16179), (0x8f25, 9577), (0x8f26, 9573), (0x8f29, 9572),
+    (0x8f2a, 9574), (0x8f2c, 16178), (0x8f2e, 16746), (0x8f2f, 9868), (0x8f32, 16748), (0x8f33, 9870), (0x8f34, 16751), (0x8f35, 16747),
+    (0x8f36, 16745), (0x8f37, 16750), (0x8f38, 9869), (0x8f39, 16749), (0x8f3b, 9867), (0x8f3e, 10161), (0x8f3f, 10164), (0x8f40, 17208),
+    (0x8f42, 10162), (0x8f43, 17207), (0x8f44, 10160), (0x8f45, 10163), (0x8f46, 17590), (0x8f47, 17591), (0x8f48, 17592), (0x8f49, 10331),
+    (0x8f4b, 17593), (0x8f4d, 10332), (0x8f4e, 10490), (0x8f4f, 17922), (0x8f50, 17923), (0x8f51, 17921), (0x8f52, 17920), (0x8f53, 17924),
+    (0x8f54, 10489), (0x8f55, 18178), (0x8f56, 18176), (0x8f57, 18177), (0x8f58, 18179), (0x8f59, 18175), (0x8f5a, 18180), (0x8f5b, 18393),
+    (0x8f5d, 18394), (0x8f5e, 18392), (0x8f5f, 10682), (0x8f60, 18562), (0x8f61, 10753), (0x8f62, 18563), (0x8f63, 18691), (0x8f64, 18690),
+    (0x8f9b, 6200), (0x8f9c, 8357), (0x8f9f, 8792), (0x8fa3, 9190), (0x8fa6, 9872), (0x8fa8, 9871), (0x8fad, 10491), (0x8fae, 10579),
+    (0x8faf, 10683), (0x8fb0, 6201), (0x8fb1, 7415), (0x8fb2, 8793), (0x8fb4, 17925), (0x8fbf, 11686), (0x8fc2, 6202), (0x8fc4, 6205),
+    (0x8fc5, 6204), (0x8fc6, 6203), (0x8fc9, 11685), (0x8fcb, 12039), (0x8fcd, 12041), (0x8fce, 6578), (0x8fd1, 6580), (0x8fd2, 12038),
+    (0x8fd3, 12040), (0x8fd4, 6579), (0x8fd5, 12043), (0x8fd6, 12042), (0x8fd7, 12044), (0x8fe0, 12487), (0x8fe1, 12485), (0x8fe2, 6968),
+    (0x8fe3, 12484), (0x8fe4, 6973), (0x8fe5, 6970), (0x8fe6, 6967), (0x8fe8, 6974), (0x8fea, 6969), (0x8feb, 6972), (0x8fed, 6971),
+    (0x8fee, 12486), (0x8ff0, 6966), (0x8ff4, 7421), (0x8ff5, 13011), (0x8ff6, 13017), (0x8ff7, 7418), (0x8ff8, 7425), (0x8ffa, 7420),
+    (0x8ffb, 13014), (0x8ffc, 13016), (0x8ffd, 7423), (0x8ffe, 13010), (0x8fff, 13013), (0x9000, 7419), (0x9001, 7416), (0x9002, 13012),
+    (0x9003, 7422), (0x9004, 13015), (0x9005, 7424), (0x9006, 7417), (0x900b, 13631), (0x900c, 13634), (0x900d, 7898), (0x900f, 7908),
+    (0x9010, 7904), (0x9011, 13632), (0x9014, 7912), (0x9015, 7905), (0x9016, 7910), (0x9017, 7900), (0x9019, 7897), (0x901a, 7899),
+    (0x901b, 7911), (0x901c, 13633), (0x901d, 7903), (0x901e, 7906), (0x901f, 7902), (0x9020, 7907), (0x9021, 13635), (0x9022, 7909),
+    (0x9023, 7901), (0x9024, 13630), (0x902d, 14300), (0x902e, 8358), (0x902f, 14302), (0x9031, 8360), (0x9032, 8362), (0x9034, 14301),
+    (0x9035, 8359), (0x9036, 8363), (0x9038, 8361), (0x903c, 8799), (0x903d, 14980), (0x903e, 8807), (0x903f, 14977), (0x9041, 8808),
+    (0x9042, 8797), (0x9044, 14978), (0x9047, 8802), (0x9049, 14979), (0x904a, 8795), (0x904b, 8794), (0x904d, 8805), (0x904e, 8804),
+    (0x904f, 8803), (0x9050, 8801), (0x9051, 8806), (0x9052, 14976), (0x9053, 8796), (0x9054, 8798), (0x9055, 8800), (0x9058, 9192),
+    (0x9059, 9195), (0x905b, 9199), (0x905c, 9193), (0x905d, 9198), (0x905e, 9196), (0x9060, 9191), (0x9062, 9197), (0x9063, 9194),
+    (0x9067, 16189), (0x9068, 9580), (0x9069, 9578), (0x906b, 16190), (0x906d, 9581), (0x906e, 9579), (0x906f, 16188), (0x9070, 16187),
+    (0x9072, 9876), (0x9073, 16186), (0x9074, 9874), (0x9075, 9873), (0x9076, 16752), (0x9077, 9582), (0x9078, 9875), (0x9079, 16753),
+    (0x907a, 9878), (0x907b, 16754), (0x907c, 9877), (0x907d, 10166), (0x907e, 17210), (0x907f, 10165), (0x9080, 10170), (0x9081, 10168),
+    (0x9082, 10169), (0x9083, 10334), (0x9084, 10167), (0x9085, 17209), (0x9086, 16755), (0x9087, 10333), (0x9088, 10335), (0x908a, 10492),
+    (0x908b, 10493), (0x908d, 18181), (0x908f, 10800), (0x9090, 10799), (0x9091, 6207), (0x9094, 11482), (0x9095, 7426), (0x9097, 11479),
+    (0x9098, 11480), (0x9099, 11478), (0x909b, 11481), (0x909e, 11690), (0x909f, 11687), (0x90a0, 11692), (0x90a1, 11688), (0x90a2, 6208),
+    (0x90a3, 6211), (0x90a5, 11689), (0x90a6, 6210), (0x90a7, 11691), (0x90aa, 6209), (0x90af, 12047), (0x90b0, 12049), (0x90b1, 6583),
+    (0x90b2, 12045), (0x90b3, 12048), (0x90b4, 12046), (0x90b5, 6581), (0x90b6, 6584), (0x90b8, 6582), (0x90bd, 12489), (0x90be, 12493),
+    (0x90bf, 12490), (0x90c1, 6977), (0x90c3, 6978), (0x90c5, 12492), (0x90c7, 12494), (0x90c8, 12496), (0x90ca, 6975), (0x90cb, 12495),
+    (0x90ce, 6976), (0x90d4, 13642), (0x90d5, 12491), (0x90d6, 13018), (0x90d7, 13027), (0x90d8, 13025), (0x90d9, 13020), (0x90da, 13021),
+    (0x90db, 13026), (0x90dc, 13028), (0x90dd, 7428), (0x90df, 13023), (0x90e0, 13019), (0x90e1, 7427), (0x90e2, 7429), (0x90e3, 13022),
+    (0x90e4, 13029), (0x90e5, 13024), (0x90e8, 7913), (0x90e9, 13645), (0x90ea, 13637), (0x90eb, 13643), (0x90ec, 13644), (0x90ed, 7914),
+    (0x90ef, 13636), (0x90f0, 13638), (0x90f1, 12488), (0x90f2, 13640), (0x90f3, 13641), (0x90f4, 13639), (0x90f5, 8365), (0x90f9, 14309),
+    (0x90fa, 16756), (0x90fb, 14310), (0x90fc, 14307), (0x90fd, 7915), (0x90fe, 8367), (0x90ff, 14306), (0x9100, 14312), (0x9101, 14311),
+    (0x9102, 8364), (0x9103, 14315), (0x9104, 14305), (0x9105, 14314), (0x9106, 14303), (0x9107, 14313), (0x9108, 14308), (0x9109, 8366),
+    (0x910b, 14987), (0x910d, 14982), (0x910e, 14988), (0x910f, 14983), (0x9110, 14981), (0x9111, 14984), (0x9112, 8809), (0x9114, 14986),
+    (0x9116, 14985), (0x9117, 8810), (0x9118, 9201), (0x9119, 9200), (0x911a, 15589), (0x911b, 15592), (0x911c, 15584), (0x911d, 15588),
+    (0x911e, 9202), (0x911f, 15587), (0x9120, 15585), (0x9121, 15591), (0x9122, 15586), (0x9123, 15583), (0x9124, 15590), (0x9126, 16196),
+    (0x9127, 9585), (0x9128, 17594), (0x9129, 16193), (0x912a, 16194), (0x912b, 16192), (0x912c, 14304), (0x912d, 9584), (0x912e, 16197),
+    (0x912f, 16191), (0x9130, 9583), (0x9131, 9586), (0x9132, 16195), (0x9133, 16757), (0x9134, 9879), (0x9135, 16758), (0x9136, 16759),
+    (0x9138, 17211), (0x9139, 10171), (0x913a, 17595), (0x913b, 17596), (0x913e, 17597), (0x913f, 17927), (0x9140, 17926), (0x9141, 18183),
+    (0x9143, 18182), (0x9144, 18396), (0x9145, 18397), (0x9146, 18395), (0x9147, 18564), (0x9148, 10754), (0x9149, 6212), (0x914a, 6980),
+    (0x914b, 6979), (0x914c, 7432), (0x914d, 7431), (0x914e, 13031), (0x914f, 13032), (0x9150, 13030), (0x9152, 7430), (0x9153, 13649),
+    (0x9155, 13650), (0x9156, 13646), (0x9157, 7916), (0x9158, 13647), (0x915a, 13648), (0x915f, 14318), (0x9160, 14320), (0x9161, 14316),
+    (0x9162, 14319), (0x9163, 8368), (0x9164, 14317), (0x9165, 8369), (0x9168, 14495), (0x9169, 8813), (0x916a, 8812), (0x916c, 8811),
+    (0x916e, 14989), (0x916f, 14990), (0x9172, 15594), (0x9173, 15596), (0x9174, 9206), (0x9175, 9203), (0x9177, 9205), (0x9178, 9204),
+    (0x9179, 15595), (0x917a, 15593), (0x9180, 16204), (0x9181, 16201), (0x9182, 16202), (0x9183, 9590), (0x9184, 16203), (0x9185, 16198),
+    (0x9186, 16199), (0x9187, 9587), (0x9189, 9588), (0x918a, 16200), (0x918b, 9589), (0x918d, 16763), (0x918f, 16764), (0x9190, 16761),
+    (0x9191, 16762), (0x9192, 9880), (0x9193, 16760), (0x9199, 17215), (0x919a, 17212), (0x919b, 17214), (0x919c, 10174), (0x919d, 17218),
+    (0x919e, 10173), (0x919f, 17216), (0x91a0, 17219), (0x91a1, 17217), (0x91a2, 17213), (0x91a3, 10172), (0x91a5, 17599), (0x91a7, 17600),
+    (0x91a8, 17598), (0x91aa, 17602), (0x91ab, 10336), (0x91ac, 10337), (0x91ad, 17929), (0x91ae, 10495), (0x91af, 17601), (0x91b0, 17928),
+    (0x91b1, 10494), (0x91b2, 18186), (0x91b3, 18187), (0x91b4, 10612), (0x91b5, 18185), (0x91b7, 18184), (0x91b9, 18398), (0x91ba, 10684),
+    (0x91bc, 18692), (0x91bd, 18785), (0x91be, 18784), (0x91c0, 10836), (0x91c1, 10862), (0x91c2, 18786), (0x91c3, 18889), (0x91c5, 10880),
+    (0x91c6, 6213), (0x91c7, 6585), (0x91c9, 8814), (0x91cb, 10613), (0x91cc, 6214), (0x91cd, 6981), (0x91ce, 7917), (0x91cf, 8370),
+    (0x91d0, 10338), (0x91d1, 6586), (0x91d3, 12498), (0x91d4, 12497), (0x91d5, 13033), (0x91d7, 7435), (0x91d8, 7433), (0x91d9, 7437),
+    (0x91da, 13035), (0x91dc, 7436), (0x91dd, 7434), (0x91e2, 13034), (0x91e3, 7920), (0x91e4, 13656), (0x91e6, 7919), (0x91e7, 7921),
+    (0x91e8, 13661), (0x91e9, 7923), (0x91ea, 13658), (0x91eb, 13659), (0x91ec, 13651), (0x91ed, 7922), (0x91ee, 13662), (0x91f1, 13653),
+    (0x91f3, 13654), (0x91f4, 13652), (0x91f5, 7918), (0x91f7, 13660), (0x91f8, 13655), (0x91f9, 13657), (0x91fd, 14332), (0x91ff, 14331),
+    (0x9200, 14329), (0x9201, 14321), (0x9202, 14336), (0x9203, 14324), (0x9204, 14334), (0x9205, 14341), (0x9206, 14333), (0x9207, 8378),
+    (0x9209, 8374), (0x920a, 14322), (0x920c, 14328), (0x920d, 8376), (0x920f, 14327), (0x9210, 8377), (0x9211, 8379), (0x9212, 14330),
+    (0x9214, 8371), (0x9215, 8372), (0x9216, 14342), (0x9217, 14340), (0x9219, 14339), (0x921a, 14325), (0x921c, 14337), (0x921e, 8375),
+    (0x9223, 8373), (0x9224, 14338), (0x9225, 14323), (0x9226, 14326), (0x9227, 14335), (0x922d, 15631), (0x922e, 15000), (0x9230, 14993),
+    (0x9231, 15012), (0x9232, 15021), (0x9233, 14996), (0x9234, 8825), (0x9236, 15009), (0x9237, 8815), (0x9238, 8817), (0x9239, 8829),
+    (0x923a, 14994), (0x923d, 8818), (0x923e, 8820), (0x923f, 8830), (0x9240, 8819), (0x9245, 8828), (0x9246, 15002), (0x9248, 14991),
+    (0x9249, 8826), (0x924a, 15001), (0x924b, 8822), (0x924c, 15019), (0x924d, 8827), (0x924e, 15017), (0x924f, 15005), (0x9250, 15015),
+    (0x9251, 8824), (0x9252, 14992), (0x9253, 15018), (0x9254, 15013), (0x9256, 15020), (0x9257, 8816), (0x925a, 8831), (0x925b, 8821),
+    (0x925e, 14998), (0x9260, 15006), (0x9261, 15010), (0x9263, 15014), (0x9264, 8823), (0x9265, 14997), (0x9266, 14995), (0x9267, 15007),
+    (0x926c, 15004), (0x926d, 15003), (0x926f, 15008), (0x9270, 15011), (0x9272, 15016), (0x9276, 15599), (0x9278, 9207), (0x9279, 15609),
+    (0x927a, 15601), (0x927b, 9212), (0x927c, 9216), (0x927d, 15618), (0x927e, 15626), (0x927f, 15611), (0x9280, 9208), (0x9282, 15615),
+    (0x9283, 14999), (0x9285, 9209), (0x9286, 15622), (0x9287, 15627), (0x9288, 15619), (0x928a, 15621), (0x928b, 15630), (0x928c, 15623),
+    (0x928d, 15605), (0x928e, 15614), (0x9291, 9217), (0x9293, 9213), (0x9294, 15603), (0x9295, 15616), (0x9296, 9211), (0x9297, 15610),
+    (0x9298, 9210), (0x9299, 15624), (0x929a, 15607), (0x929b, 15600), (0x929c, 9214), (0x929d, 15629), (0x92a0, 15602), (0x92a1, 15620),
+    (0x92a2, 15617), (0x92a3, 15612), (0x92a4, 15598), (0x92a5, 15597), (0x92a6, 15606), (0x92a7, 15625), (0x92a8, 9215), (0x92a9, 15628),
+    (0x92aa, 15604), (0x92ab, 15608), (0x92ac, 9595), (0x92b2, 9603), (0x92b3, 9598), (0x92b4, 16237), (0x92b5, 16234), (0x92b6, 16210),
+    (0x92b7, 9593), (0x92bb, 9592), (0x92bc, 9599), (0x92c0, 16208), (0x92c1, 9597), (0x92c2, 16220), (0x92c3, 16206), (0x92c4, 16207),
+    (0x92c5, 9591), (0x92c6, 16236), (0x92c7, 9601), (0x92c8, 16223), (0x92c9, 16228), (0x92ca, 16222), (0x92cb, 16782), (0x92cc, 16218),
+    (0x92cd, 16226), (0x92ce, 16224), (0x92cf, 16211), (0x92d0, 16205), (0x92d1, 16232), (0x92d2, 9600), (0x92d3, 16233), (0x92d5, 16227),
+    (0x92d7, 16216), (0x92d8, 16214), (0x92d9, 16209), (0x92dd, 16217), (0x92de, 16230), (0x92df, 16213), (0x92e0, 16229), (0x92e1, 16235),
+    (0x92e4, 9596), (0x92e6, 16225), (0x92e7, 16231), (0x92e8, 16221), (0x92e9, 16215), (0x92ea, 9594), (0x92ee, 15613), (0x92ef, 16219),
+    (0x92f0, 9602), (0x92f1, 16212), (0x92f7, 16788), (0x92f8, 9883), (0x92f9, 16787), (0x92fa, 16784), (0x92fb, 16803), (0x92fc, 9887),
+    (0x92fe, 16800), (0x92ff, 16792), (0x9300, 16802), (0x9301, 16777), (0x9302, 16790), (0x9304, 9889), (0x9306, 16769), (0x9308, 16767),
+    (0x9309, 16801), (0x930b, 16799), (0x930c, 16798), (0x930d, 16781), (0x930e, 16780), (0x930f, 16770), (0x9310, 9891), (0x9312, 16776),
+    (0x9313, 16786), (0x9314, 16797), (0x9315, 9894), (0x9316, 16804), (0x9318, 10182), (0x9319, 9896), (0x931a, 9890), (0x931b, 16774),
+    (0x931d, 16783), (0x931e, 16766), (0x931f, 16768), (0x9320, 9881), (0x9321, 9893), (0x9322, 9886), (0x9323, 16775), (0x9324, 16791),
+    (0x9325, 16785), (0x9326, 9892), (0x9327, 16765), (0x9328, 10177), (0x9329, 16793), (0x932a, 16796), (0x932b, 9888), (0x932d, 16779),
+    (0x932e, 9895), (0x932f, 9885), (0x9333, 9884), (0x9334, 16789), (0x9335, 16795), (0x9336, 9882), (0x9338, 16772), (0x9339, 16794),
+    (0x933c, 16773), (0x9346, 16778), (0x9347, 17225), (0x9349, 17230), (0x934a, 10179), (0x934b, 10181), (0x934c, 17236), (0x934d, 10175),
+    (0x934e, 17251), (0x934f, 17242), (0x9350, 17231), (0x9351, 17232), (0x9352, 17241), (0x9354, 10188), (0x9355, 17240), (0x9356, 17224),
+    (0x9357, 17239), (0x9358, 17227), (0x9359, 17252), (0x935a, 10187), (0x935b, 10185), (0x935c, 17228), (0x935e, 17247), (0x9360, 17233),
+    (0x9361, 17246), (0x9363, 17248), (0x9364, 17223), (0x9365, 10180), (0x9367, 17249), (0x936a, 17237), (0x936c, 10184), (0x936d, 17234),
+    (0x9370, 10186), (0x9371, 17243), (0x9375, 10178), (0x9376, 17229), (0x9377, 17244), (0x9379, 17238), (0x937a, 16771), (0x937b, 17245),
+    (0x937c, 17226), (0x937e, 10183), (0x9380, 17250), (0x9382, 10176), (0x9383, 17221), (0x9388, 17616), (0x9389, 17609), (0x938a, 10340),
+    (0x938c, 17604), (0x938d, 17619), (0x938e, 17611), (0x938f, 17235), (0x9391, 17621), (0x9392, 17605), (0x9394, 10339), (0x9395, 17615),
+    (0x9396, 10341), (0x9397, 10349), (0x9398, 10347), (0x9399, 17617), (0x939a, 10348), (0x939b, 17607), (0x939d, 17608), (0x939e, 17613),
+    (0x939f, 17618), (0x93a1, 17220), (0x93a2, 10342), (0x93a3, 17626), (0x93a4, 17623), (0x93a5, 17627), (0x93a6, 17614), (0x93a7, 17610),
+    (0x93a8, 17624), (0x93a9, 17940), (0x93aa, 17612), (0x93ac, 10345), (0x93ae, 10344), (0x93af, 17222), (0x93b0, 10346), (0x93b1, 17620),
+    (0x93b2, 17622), (0x93b3, 10343), (0x93b4, 17625), (0x93b5, 17603), (0x93b7, 17606), (0x93c0, 17949), (0x93c2, 17933), (0x93c3, 10499),
+    (0x93c4, 17947), (0x93c7, 17931), (0x93c8, 10500), (0x93ca, 17942), (0x93cc, 17938), (0x93cd, 10505), (0x93ce, 17948), (0x93cf, 17932),
+    (0x93d0, 17935), (0x93d1, 10497), (0x93d2, 17950), (0x93d4, 17943), (0x93d5, 17946), (0x93d6, 10503), (0x93d7, 10508), (0x93d8, 10506),
+    (0x93d9, 17939), (0x93da, 17934), (0x93dc, 10501), (0x93dd, 10502), (0x93de, 17930), (0x93df, 10498), (0x93e1, 10496), (0x93e2, 10504),
+    (0x93e3, 17945), (0x93e4, 10507), (0x93e6, 17941), (0x93e7, 17951), (0x93e8, 10509), (0x93ec, 17937), (0x93ee, 17944), (0x93f5, 18200),
+    (0x93f6, 18214), (0x93f7, 18202), (0x93f8, 18209), (0x93f9, 17936), (0x93fa, 18207), (0x93fb, 18190), (0x93fc, 18212), (0x93fd, 10616),
+    (0x93fe, 18194), (0x93ff, 18211), (0x9400, 18201), (0x9403, 10615), (0x9406, 18216), (0x9407, 18203), (0x9409, 18208), (0x940a, 18210),
+    (0x940b, 18188), (0x940c, 18213), (0x940d, 18199), (0x940e, 18204), (0x940f, 18192), (0x9410, 18196), (0x9411, 18215), (0x9412, 18206),
+    (0x9413, 18189), (0x9414, 18193), (0x9415, 18195), (0x9416, 18205), (0x9418, 10614), (0x9419, 18198), (0x9420, 18191), (0x9428, 18197),
+    (0x9429, 18402), (0x942a, 18407), (0x942b, 10691), (0x942c, 18409), (0x942e, 10685), (0x9430, 18405), (0x9431, 18411), (0x9432, 10690),
+    (0x9433, 10686), (0x9435, 10687), (0x9436, 18401), (0x9437, 18408), (0x9438, 10689), (0x9439, 18406), (0x943a, 10688), (0x943b, 18400),
+    (0x943c, 18404), (0x943d, 18403), (0x943f, 18399), (0x9440, 18410), (0x9444, 10755), (0x9445, 18571), (0x9446, 18574), (0x9447, 18570),
+    (0x9448, 18572), (0x9449, 18573), (0x944a, 18567), (0x944b, 18568), (0x944c, 18565), (0x944f, 18569), (0x9450, 18566), (0x9451, 10756),
+    (0x9452, 10757), (0x9455, 18694), (0x9457, 18696), (0x945d, 18695), (0x945e, 18697), (0x9460, 10802), (0x9462, 18693), (0x9463, 10801),
+    (0x9464, 10803), (0x9468, 18788), (0x9469, 18789), (0x946a, 10837), (0x946b, 18787), (0x946d, 18859), (0x946e, 18858), (0x946f, 18860),
+    (0x9470, 10864), (0x9471, 18861), (0x9472, 10863), (0x9473, 18862), (0x9474, 18890), (0x9475, 18893), (0x9476, 18892), (0x9477, 10873),
+    (0x9478, 18891), (0x947c, 10883), (0x947d, 10881), (0x947e, 10882), (0x947f, 10888), (0x9480, 18934), (0x9481, 18935), (0x9482, 18933),
+    (0x9483, 18943), (0x9577, 6587), (0x957a, 13663), (0x957b, 14343), (0x957c, 16238), (0x957d, 17952), (0x9580, 6588), (0x9582, 6982),
+    (0x9583, 7438), (0x9586, 13664), (0x9588, 13665), (0x9589, 7924), (0x958b, 8382), (0x958c, 14345), (0x958d, 14344), (0x958e, 8386),
+    (0x958f, 8381), (0x9590, 14346), (0x9591, 8383), (0x9592, 8385), (0x9593, 8384), (0x9594, 8380), (0x9598, 8832), (0x959b, 15025),
+    (0x959c, 15023), (0x959e, 15024), (0x959f, 15022), (0x95a1, 9218), (0x95a3, 9221), (0x95a4, 9223), (0x95a5, 9222), (0x95a8, 9219),
+    (0x95a9, 9220), (0x95ab, 16240), (0x95ac, 16239), (0x95ad, 9604), (0x95ae, 16241), (0x95b0, 16242), (0x95b1, 9605), (0x95b5, 16812),
+    (0x95b6, 16810), (0x95b7, 17258), (0x95b9, 16808), (0x95ba, 16809), (0x95bb, 9897), (0x95bc, 16805), (0x95bd, 16813), (0x95be, 16807),
+    (0x95bf, 16811), (0x95c0, 17254), (0x95c3, 17256), (0x95c5, 17257), (0x95c6, 10193), (0x95c7, 17253), (0x95c8, 10192), (0x95c9, 17255),
+    (0x95ca, 10189), (0x95cb, 10190), (0x95cc, 10191), (0x95cd, 16806), (0x95d0, 10352), (0x95d1, 17630), (0x95d2, 17628), (0x95d3, 17629),
+    (0x95d4, 10350), (0x95d5, 10353), (0x95d6, 10351), (0x95da, 17953), (0x95db, 17954), (0x95dc, 10510), (0x95de, 18217), (0x95df, 18219),
+    (0x95e0, 18218), (0x95e1, 10617), (0x95e2, 10692), (0x95e3, 18414), (0x95e4, 18413), (0x95e5, 18412), (0x961c, 6589), (0x961e, 11376),
+    (0x9620, 11485), (0x9621, 5944), (0x9622, 11483), (0x9623, 11486), (0x9624, 11484), (0x9628, 11694), (0x962a, 6218), (0x962c, 6219),
+    (0x962d, 11696), (0x962e, 6216), (0x962f, 11695), (0x9630, 11693), (0x9631, 6217), (0x9632, 6215), (0x9639, 12050), (0x963a, 12053),
+    (0x963b, 6592), (0x963c, 12052), (0x963d, 12051), (0x963f, 6591), (0x9640, 6590), (0x9642, 6594), (0x9643, 12054), (0x9644, 6593),
+    (0x964a, 12503), (0x964b, 6984), (0x964c, 6985), (0x964d, 6986), (0x964e, 12504), (0x964f, 12500), (0x9650, 6983), (0x9651, 12501),
+    (0x9653, 12502), (0x9654, 12499), (0x9658, 7445), (0x965b, 7442), (0x965c, 13036), (0x965d, 7443), (0x965e, 7446), (0x965f, 13037),
+    (0x9661, 7441), (0x9662, 7439), (0x9663, 7440), (0x9664, 7444), (0x966a, 7925), (0x966b, 13668), (0x966c, 7933), (0x966d, 13667),
+    (0x966f, 13670), (0x9670, 7929), (0x9671, 13669), (0x9672, 8394), (0x9673, 7927), (0x9674, 7930), (0x9675, 7926), (0x9676, 7931),
+    (0x9677, 7932), (0x9678, 7928), (0x967c, 13666), (0x967d, 8390), (0x967e, 14348), (0x9680, 14352), (0x9683, 14351), (0x9684, 8395),
+    (0x9685, 8391), (0x9686, 8392), (0x9687, 14347), (0x9688, 14349), (0x9689, 14350), (0x968a, 8387), (0x968b, 8389), (0x968d, 8393),
+    (0x968e, 8388), (0x9691, 15028), (0x9692, 15026), (0x9693, 15027), (0x9694, 8834), (0x9695, 8835), (0x9697, 15029), (0x9698, 8833),
+    (0x9699, 9224), (0x969b, 9226), (0x969c, 9225), (0x969e, 15632), (0x96a1, 15633), (0x96a2, 16244), (0x96a4, 16243), (0x96a7, 9898),
+    (0x96a8, 9899), (0x96a9, 16814), (0x96aa, 9900), (0x96ac, 17261), (0x96ae, 17259), (0x96b0, 17260), (0x96b1, 10194), (0x96b3, 17631),
+    (0x96b4, 10511), (0x96b8, 10195), (0x96b9, 6595), (0x96bb, 7447), (0x96bc, 13038), (0x96bf, 13671), (0x96c0, 7934), (0x96c1, 8396),
+    (0x96c2, 14353), (0x96c3, 14355), (0x96c4, 8398), (0x96c5, 8397), (0x96c6, 8399), (0x96c7, 8400), (0x96c8, 14354), (0x96c9, 8838),
+    (0x96ca, 8839), (0x96cb, 8837), (0x96cc, 9227), (0x96cd, 8836), (0x96ce, 15030), (0x96d2, 9228), (0x96d3, 16245), (0x96d4, 16815),
+    (0x96d5, 9901), (0x96d6, 10196), (0x96d7, 17632), (0x96d8, 17636), (0x96d9, 10356), (0x96da, 17633), (0x96db, 10357), (0x96dc, 10355),
+    (0x96dd, 17637), (0x96de, 10358), (0x96df, 17635), (0x96e1, 17955), (0x96e2, 10354), (0x96e3, 10512), (0x96e5, 18790), (0x96e8, 6596),
+    (0x96e9, 7936), (0x96ea, 7935), (0x96ef, 8401), (0x96f0, 14357), (0x96f1, 14356), (0x96f2, 8402), (0x96f5, 15034), (0x96f6, 8843),
+    (0x96f7, 8840), (0x96f8, 15033), (0x96f9, 8842), (0x96fa, 15031), (0x96fb, 8841), (0x96fd, 15032), (0x96ff, 15634), (0x9700, 9229),
+    (0x9702, 16248), (0x9704, 9606), (0x9705, 16246), (0x9706, 9607), (0x9707, 9608), (0x9708, 16247), (0x9709, 9609), (0x970b, 16816),
+    (0x970d, 9905), (0x970e, 9902), (0x970f, 9907), (0x9710, 16818), (0x9711, 9903), (0x9712, 16817), (0x9713, 9906), (0x9716, 9904),
+    (0x9718, 17264), (0x9719, 17266), (0x971c, 10197), (0x971d, 17265), (0x971e, 10198), (0x971f, 17263), (0x9720, 17262), (0x9722, 17639),
+    (0x9723, 17638), (0x9724, 10359), (0x9725, 17640), (0x9726, 17960), (0x9727, 10514), (0x9728, 17959), (0x9729, 17956), (0x972a, 10513),
+    (0x972b, 17957), (0x972c, 17958), (0x972e, 18220), (0x972f, 18221), (0x9730, 10618), (0x9732, 10695), (0x9735, 18415), (0x9738, 10693),
+    (0x9739, 10694), (0x973a, 18416), (0x973d, 10758), (0x973e, 10759), (0x973f, 18575), (0x9742, 10838), (0x9743, 18792), (0x9744, 10840),
+    (0x9746, 18791), (0x9747, 18793), (0x9748, 10839), (0x9749, 18863), (0x974b, 18915), (0x9752, 6597), (0x9756, 8844), (0x9758, 15635),
+    (0x975a, 16249), (0x975b, 9908), (0x975c, 9909), (0x975e, 6598), (0x9760, 9610), (0x9761, 10515), (0x9762, 6987), (0x9766, 9910),
+    (0x9768, 10804), (0x9769, 6988), (0x976a, 13672), (0x976c, 14358), (0x976e, 14360), (0x9770, 14359), (0x9772, 15038), (0x9773, 15035),
+    (0x9774, 8845), (0x9776, 8846), (0x9777, 15036), (0x9778, 15037), (0x977a, 15637), (0x977b, 15642), (0x977c, 9230), (0x977d, 15636),
+    (0x977e, 15638), (0x977f, 15645), (0x9780, 15640), (0x9781, 15644), (0x9782, 15641), (0x9783, 15639), (0x9784, 15643), (0x9785, 9231),
+    (0x9788, 16252), (0x978a, 16250), (0x978b, 9612), (0x978d, 9611), (0x978e, 16251), (0x978f, 9613), (0x9794, 16821), (0x9797, 16820),
+    (0x9798, 9911), (0x9799, 16819), (0x979a, 17267), (0x979c, 17269), (0x979d, 17271), (0x979e, 17270), (0x97a0, 10199), (0x97a1, 17268),
+    (0x97a2, 17647), (0x97a3, 10360), (0x97a4, 17645), (0x97a5, 17648), (0x97a6, 10361), (0x97a8, 17643), (0x97aa, 17646), (0x97ab, 17644),
+    (0x97ac, 17641), (0x97ad, 10362), (0x97ae, 17642), (0x97b3, 17961), (0x97b6, 17963), (0x97b7, 17962), (0x97b9, 18222), (0x97bb, 18223),
+    (0x97bf, 18417), (0x97c1, 10761), (0x97c3, 10760), (0x97c4, 18698), (0x97c5, 18699), (0x97c6, 10841), (0x97c7, 18794), (0x97c9, 10874),
+    (0x97cb, 6989), (0x97cc, 8403), (0x97cd, 15647), (0x97ce, 15646), (0x97cf, 16254), (0x97d0, 16253), (0x97d3, 10200), (0x97d4, 17273),
+    (0x97d5, 17272), (0x97d6, 17651), (0x97d7, 17649), (0x97d8, 17652), (0x97d9, 17650), (0x97dc, 10516), (0x97dd, 17964), (0x97de, 17965),
+    (0x97df, 17966), (0x97e1, 18418), (0x97e3, 18576), (0x97e5, 18795), (0x97ed, 6990), (0x97f0, 16822), (0x97f1, 17274), (0x97f3, 6991),
+    (0x97f6, 9232), (0x97f8, 16823), (0x97f9, 10363), (0x97fa, 17653), (0x97fb, 10517), (0x97fd, 18224), (0x97fe, 18225), (0x97ff, 10696),
+    (0x9800, 18700), (0x9801, 6992), (0x9802, 7939), (0x9803, 7940), (0x9804, 13673), (0x9805, 8404), (0x9806, 8405), (0x9807, 14361),
+    (0x9808, 8406), (0x980a, 8850), (0x980c, 8852), (0x980d, 15040), (0x980e, 15041), (0x980f, 15039), (0x9810, 8847), (0x9811, 8848),
+    (0x9812, 8851), (0x9813, 8849), (0x9816, 15648), (0x9817, 9233), (0x9818, 9234), (0x981b, 16261), (0x981c, 9616), (0x981d, 16256),
+    (0x981e, 16255), (0x9820, 16260), (0x9821, 9614), (0x9824, 9918), (0x9826, 16257), (0x9827, 16262), (0x9828, 16259), (0x9829, 16258),
+    (0x982b, 9615), (0x982d, 9916), (0x982f, 16825), (0x9830, 9912), (0x9832, 16826), (0x9835, 16824), (0x9837, 9915), (0x9838, 9913),
+    (0x9839, 9917), (0x983b, 9914), (0x9841, 17275), (0x9843, 17280), (0x9844, 17276), (0x9845, 17279), (0x9846, 10201), (0x9848, 17070),
+    (0x9849, 17278), (0x984a, 17277), (0x984c, 10366), (0x984d, 10364), (0x984e, 10367), (0x984f, 10365), (0x9850, 17654), (0x9851, 17655),
+    (0x9852, 17656), (0x9853, 10368), (0x9857, 17970), (0x9858, 10519), (0x9859, 17968), (0x985b, 10520), (0x985c, 17967), (0x985d, 17969),
+    (0x985e, 10518), (0x985f, 18229), (0x9860, 18226), (0x9862, 18227), (0x9863, 18228), (0x9864, 18419), (0x9865, 10698), (0x9867, 10697),
+    (0x9869, 18578), (0x986a, 18577), (0x986b, 10762), (0x986f, 10805), (0x9870, 10842), (0x9871, 10865), (0x9872, 18864), (0x9873, 18916),
+    (0x9874, 18917), (0x98a8, 6993), (0x98a9, 14362), (0x98ac, 15042), (0x98ad, 15649), (0x98ae, 15650), (0x98af, 9235), (0x98b1, 9236),
+    (0x98b2, 16263), (0x98b3, 9617), (0x98b6, 10202), (0x98b8, 17657), (0x98ba, 10369), (0x98bb, 17973), (0x98bc, 10521), (0x98bd, 17972),
+    (0x98be, 17974), (0x98bf, 17971), (0x98c0, 18422), (0x98c1, 18230), (0x98c2, 18231), (0x98c4, 10619), (0x98c6, 18421), (0x98c9, 18420),
+    (0x98cb, 18579), (0x98cc, 18918), (0x98db, 6994), (0x98df, 6995), (0x98e2, 7448), (0x98e3, 13039), (0x98e5, 13674), (0x98e7, 8407),
+    (0x98e9, 8410), (0x98ea, 8408), (0x98eb, 14363), (0x98ed, 8412), (0x98ef, 8409), (0x98f2, 8411), (0x98f4, 8854), (0x98f6, 15043),
+    (0x98f9, 15044), (0x98fa, 16265), (0x98fc, 8853), (0x98fd, 8855), (0x98fe, 8856), (0x9900, 15652), (0x9902, 15651), (0x9903, 9237),
+    (0x9905, 9238), (0x9907, 15653), (0x9908, 16264), (0x9909, 9240), (0x990a, 9618), (0x990c, 9239), (0x9910, 9919), (0x9911, 16266),
+    (0x9912, 9620), (0x9913, 9619), (0x9914, 16267), (0x9915, 16270), (0x9916, 16268), (0x9917, 16269), (0x9918, 9621), (0x991a, 9924),
+    (0x991b, 9922), (0x991e, 9921), (0x991f, 16828), (0x9921, 9923), (0x9924, 16827), (0x9925, 17281), (0x9927, 16829), (0x9928, 9920),
+    (0x9929, 16830), (0x992a, 17284), (0x992b, 17282), (0x992c, 17283), (0x992d, 17288), (0x992e, 10373), (0x992f, 17287), (0x9930, 17290),
+    (0x9931, 17289), (0x9932, 17286), (0x9933, 17285), (0x9935, 10203), (0x993a, 17660), (0x993c, 17659), (0x993d, 10372), (0x993e, 10370),
+    (0x993f, 10371), (0x9941, 17658), (0x9943, 17977), (0x9945, 10522), (0x9947, 17976), (0x9948, 17975), (0x9949, 10523), (0x994b, 18236),
+    (0x994c, 18235), (0x994e, 18233), (0x9950, 18232), (0x9951, 10621), (0x9952, 10620), (0x9953, 18237), (0x9954, 18580), (0x9955, 10763),
+    (0x9956, 18424), (0x9957, 10699), (0x9958, 18423), (0x9959, 18234), (0x995b, 18581), (0x995c, 10806), (0x995e, 10866), (0x995f, 18865),
+    (0x9961, 18919), (0x9996, 6996), (0x9997, 13675), (0x9998, 17291), (0x9999, 6997), (0x999c, 15655), (0x999d, 15654), (0x999e, 16831),
+    (0x99a1, 17293), (0x99a3, 17292), (0x99a5, 10374), (0x99a6, 17978), (0x99a7, 17979), (0x99a8, 10622), (0x99ab, 18920), (0x99ac, 7449),
+    (0x99ad, 8414), (0x99ae, 8413), (0x99af, 15045), (0x99b0, 15047), (0x99b1, 8858), (0x99b2, 15046), (0x99b3, 8857), (0x99b4, 8859),
+    (0x99b5, 15048), (0x99b9, 15657), (0x99ba, 15659), (0x99bb, 15658), (0x99bd, 15661), (0x99c1, 9241), (0x99c2, 15660), (0x99c3, 15656),
+    (0x99c7, 15662), (0x99c9, 16277), (0x99cb, 16280), (0x99cc, 16282), (0x99cd, 16272), (0x99ce, 16276), (0x99cf, 16273), (0x99d0, 9623),
+    (0x99d1, 9626), (0x99d2, 9628), (0x99d3, 16274), (0x99d4, 16275), (0x99d5, 9627), (0x99d6, 16278), (0x99d7, 16281), (0x99d8, 16279),
+    (0x99d9, 9629), (0x99db, 9625), (0x99dc, 16271), (0x99dd, 9622), (0x99df, 9624), (0x99e2, 9926), (0x99e3, 16837), (0x99e4, 16835),
+    (0x99e5, 16834), (0x99e7, 16840), (0x99e9, 16839), (0x99ea, 16838), (0x99ec, 16833), (0x99ed, 9925), (0x99ee, 16832), (0x99f0, 16836),
+    (0x99f1, 9927), (0x99f4, 17296), (0x99f6, 17300), (0x99f7, 17297), (0x99f8, 17299), (0x99f9, 17298), (0x99fa, 17295), (0x99fb, 17301),
+    (0x99fc, 17304), (0x99fd, 17302), (0x99fe, 17303), (0x99ff, 10205), (0x9a01, 10204), (0x9a02, 17294), (0x9a03, 17305), (0x9a04, 17665),
+    (0x9a05, 17668), (0x9a06, 17670), (0x9a07, 17669), (0x9a09, 17663), (0x9a0a, 17667), (0x9a0b, 17662), (0x9a0d, 17664), (0x9a0e, 10375),
+    (0x9a0f, 17661), (0x9a11, 17666), (0x9a14, 17992), (0x9a15, 17981), (0x9a16, 10524), (0x9a19, 10525), (0x9a1a, 17980), (0x9a1b, 17985),
+    (0x9a1c, 17991), (0x9a1d, 17983), (0x9a1e, 17990), (0x9a20, 17987), (0x9a22, 17986), (0x9a23, 17989), (0x9a24, 17984), (0x9a25, 17982),
+    (0x9a27, 17988), (0x9a29, 18244), (0x9a2a, 18242), (0x9a2b, 10623), (0x9a2c, 18241), (0x9a2d, 18247), (0x9a2e, 18245), (0x9a30, 10624),
+    (0x9a31, 18240), (0x9a32, 18238), (0x9a34, 18239), (0x9a35, 10626), (0x9a36, 18243), (0x9a37, 10625), (0x9a38, 18246), (0x9a39, 18425),
+    (0x9a3a, 18431), (0x9a3d, 18426), (0x9a3e, 10703), (0x9a3f, 18432), (0x9a40, 10702), (0x9a41, 18430), (0x9a42, 18429), (0x9a43, 10701),
+    (0x9a44, 18428), (0x9a45, 10700), (0x9a46, 18427), (0x9a48, 18587), (0x9a49, 18589), (0x9a4a, 18588), (0x9a4c, 18585), (0x9a4d, 10765),
+    (0x9a4e, 18582), (0x9a4f, 18586), (0x9a50, 18591), (0x9a52, 18590), (0x9a53, 18583), (0x9a54, 18584), (0x9a55, 10764), (0x9a56, 18701),
+    (0x9a57, 10809), (0x9a59, 18702), (0x9a5a, 10807), (0x9a5b, 10808), (0x9a5e, 18796), (0x9a5f, 10843), (0x9a60, 18894), (0x9a62, 10875),
+    (0x9a64, 18921), (0x9a65, 10876), (0x9a66, 18922), (0x9a67, 18923), (0x9a68, 18937), (0x9a69, 18936), (0x9a6a, 10891), (0x9a6b, 18947),
+    (0x9aa8, 7450), (0x9aab, 15050), (0x9aad, 15049), (0x9aaf, 9242), (0x9ab0, 9243), (0x9ab1, 15663), (0x9ab3, 16283), (0x9ab4, 16843),
+    (0x9ab7, 9630), (0x9ab8, 9928), (0x9ab9, 16841), (0x9abb, 16844), (0x9abc, 9929), (0x9abe, 17306), (0x9abf, 16842), (0x9ac0, 17671),
+    (0x9ac1, 10376), (0x9ac2, 17993), (0x9ac6, 18250), (0x9ac7, 18248), (0x9aca, 18249), (0x9acd, 18433), (0x9acf, 10704), (0x9ad0, 18592),
+    (0x9ad1, 10812), (0x9ad2, 10766), (0x9ad3, 10810), (0x9ad4, 10811), (0x9ad5, 18797), (0x9ad6, 10867), (0x9ad8, 7451), (0x9adc, 17672),
+    (0x9adf, 13040), (0x9ae1, 8860), (0x9ae3, 15664), (0x9ae6, 9244), (0x9ae7, 15665), (0x9aeb, 16285), (0x9aec, 16284), (0x9aed, 9931),
+    (0x9aee, 9631), (0x9aef, 9632), (0x9af1, 16288), (0x9af2, 16287), (0x9af3, 16286), (0x9af6, 16845), (0x9af7, 16848), (0x9af9, 16847),
+    (0x9afa, 16846), (0x9afb, 9930), (0x9afc, 17310), (0x9afd, 17308), (0x9afe, 17307), (0x9b01, 17309), (0x9b03, 10377), (0x9b04, 17674),
+    (0x9b05, 17675), (0x9b06, 10378), (0x9b08, 17673), (0x9b0a, 17995), (0x9b0b, 17994), (0x9b0c, 17997), (0x9b0d, 10526), (0x9b0e, 17996),
+    (0x9b10, 18251), (0x9b11, 18253), (0x9b12, 18252), (0x9b15, 18434), (0x9b16, 18437), (0x9b17, 18435), (0x9b18, 18436), (0x9b19, 18593),
+    (0x9b1a, 10767), (0x9b1e, 18703), (0x9b1f, 18704), (0x9b20, 18705), (0x9b22, 10844), (0x9b23, 10868), (0x9b24, 18924), (0x9b25, 7452),
+    (0x9b27, 9633), (0x9b28, 9932), (0x9b29, 17676), (0x9b2b, 18594), (0x9b2e, 18938), (0x9b2f, 13041), (0x9b31, 10892), (0x9b32, 7453),
+    (0x9b33, 16849), (0x9b35, 17677), (0x9b37, 17998), (0x9b3a, 18438), (0x9b3b, 18595), (0x9b3c, 7454), (0x9b3e, 15666), (0x9b3f, 15667),
+    (0x9b41, 9245), (0x9b42, 9246), (0x9b43, 16290), (0x9b44, 9635), (0x9b45, 9634), (0x9b46, 16289), (0x9b48, 17311), (0x9b4a, 17678),
+    (0x9b4b, 17680), (0x9b4c, 17679), (0x9b4d, 10381), (0x9b4e, 10380), (0x9b4f, 10379), (0x9b51, 10706), (0x9b52, 18439), (0x9b54, 10705),
+    (0x9b55, 18597), (0x9b56, 18596), (0x9b58, 10845), (0x9b59, 18798), (0x9b5a, 7941), (0x9b5b, 15051), (0x9b5f, 15670), (0x9b60, 15668),
+    (0x9b61, 15669), (0x9b64, 16299), (0x9b66, 16294), (0x9b67, 16291), (0x9b68, 16298), (0x9b6c, 16300), (0x9b6f, 9637), (0x9b70, 16297),
+    (0x9b71, 16293), (0x9b74, 16292), (0x9b75, 16296), (0x9b76, 16295), (0x9b77, 9636), (0x9b7a, 16860), (0x9b7b, 16855), (0x9b7c, 16853),
+    (0x9b7d, 16862), (0x9b7e, 16854), (0x9b80, 16850), (0x9b82, 16856), (0x9b85, 16851), (0x9b86, 17320), (0x9b87, 16852), (0x9b88, 16863),
+    (0x9b90, 16859), (0x9b91, 9933), (0x9b92, 16858), (0x9b93, 16857), (0x9b95, 16861), (0x9b9a, 17312), (0x9b9b, 17315), (0x9b9e, 17314),
+    (0x9ba0, 17322), (0x9ba1, 17317), (0x9ba2, 17321), (0x9ba4, 17319), (0x9ba5, 17318), (0x9ba6, 17316), (0x9ba8, 17313), (0x9baa, 10208),
+    (0x9bab, 10207), (0x9bad, 10209), (0x9bae, 10206), (0x9baf, 17323), (0x9bb5, 17686), (0x9bb6, 17689), (0x9bb8, 17687), (0x9bb9, 17691),
+    (0x9bbd, 17692), (0x9bbf, 17684), (0x9bc0, 10386), (0x9bc1, 17685), (0x9bc3, 17683), (0x9bc4, 17690), (0x9bc6, 17682), (0x9bc7, 17681),
+    (0x9bc8, 10385), (0x9bc9, 10383), (0x9bca, 10382), (0x9bd3, 17688), (0x9bd4, 18007), (0x9bd5, 18013), (0x9bd6, 10529), (0x9bd7, 18008),
+    (0x9bd9, 18011), (0x9bda, 18015), (0x9bdb, 10530), (0x9bdc, 18010), (0x9bde, 18002), (0x9be0, 18001), (0x9be1, 18014), (0x9be2, 18005),
+    (0x9be4, 18003), (0x9be5, 18012), (0x9be6, 18004), (0x9be7, 10528), (0x9be8, 10527), (0x9bea, 17999), (0x9beb, 18000), (0x9bec, 18009),
+    (0x9bf0, 18006), (0x9bf7, 18256), (0x9bf8, 18259), (0x9bfd, 10384), (0x9c05, 18257), (0x9c06, 18263), (0x9c07, 18261), (0x9c08, 18255),
+    (0x9c09, 18266), (0x9c0b, 18254), (0x9c0d, 10628), (0x9c0e, 18262), (0x9c12, 18258), (0x9c13, 10627), (0x9c14, 18265), (0x9c17, 18264),
+    (0x9c1c, 18442), (0x9c1d, 18441), (0x9c21, 18448), (0x9c23, 18444), (0x9c24, 18447), (0x9c25, 10708), (0x9c28, 18445), (0x9c29, 18446),
+    (0x9c2b, 18440), (0x9c2c, 18443), (0x9c2d, 10707), (0x9c31, 10769), (0x9c32, 18608), (0x9c33, 18603), (0x9c34, 18607), (0x9c36, 18610),
+    (0x9c37, 18606), (0x9c39, 18602), (0x9c3b, 10771), (0x9c3c, 18605), (0x9c3d, 18609), (0x9c3e, 10770), (0x9c3f, 18600), (0x9c40, 18260),
+    (0x9c41, 18604), (0x9c44, 18601), (0x9c46, 18598), (0x9c48, 18599), (0x9c49, 10768), (0x9c4a, 18709), (0x9c4b, 18711), (0x9c4c, 18714),
+    (0x9c4d, 18710), (0x9c4e, 18715), (0x9c50, 18708), (0x9c52, 18706), (0x9c54, 10813), (0x9c55, 18712), (0x9c56, 10815), (0x9c57, 10814),
+    (0x9c58, 18707), (0x9c59, 18713), (0x9c5e, 18803), (0x9c5f, 10846), (0x9c60, 18804), (0x9c62, 18802), (0x9c63, 18799), (0x9c66, 18801),
+    (0x9c67, 18800), (0x9c68, 18866), (0x9c6d, 18868), (0x9c6e, 18867), (0x9c71, 18897), (0x9c73, 18896), (0x9c74, 18895), (0x9c75, 18898),
+    (0x9c77, 10884), (0x9c78, 10885), (0x9c79, 18944), (0x9c7a, 18948), (0x9ce5, 7942), (0x9ce6, 14364), (0x9ce7, 15054), (0x9ce9, 8861),
+    (0x9cea, 15052), (0x9ced, 15053), (0x9cf1, 15671), (0x9cf2, 15672), (0x9cf3, 9249), (0x9cf4, 9247), (0x9cf5, 15673), (0x9cf6, 9248),
+    (0x9cf7, 16305), (0x9cf9, 16308), (0x9cfa, 16302), (0x9cfb, 16309), (0x9cfc, 16301), (0x9cfd, 16303), (0x9cff, 16304), (0x9d00, 16307),
+    (0x9d03, 9640), (0x9d04, 16312), (0x9d05, 16311), (0x9d06, 9638), (0x9d07, 16306), (0x9d08, 16310), (0x9d09, 9639), (0x9d10, 16873),
+    (0x9d12, 9938), (0x9d14, 16868), (0x9d15, 9934), (0x9d17, 16865), (0x9d18, 16871), (0x9d19, 16874), (0x9d1b, 9939), (0x9d1d, 16870),
+    (0x9d1e, 16867), (0x9d1f, 16875), (0x9d20, 16866), (0x9d22, 16872), (0x9d23, 9935), (0x9d25, 16864), (0x9d26, 9936), (0x9d28, 9937),
+    (0x9d29, 16869), (0x9d2d, 17341), (0x9d2e, 17328), (0x9d2f, 17329), (0x9d30, 17332), (0x9d31, 17330), (0x9d33, 17324), (0x9d36, 17327),
+    (0x9d37, 17337), (0x9d38, 17331), (0x9d3b, 10210), (0x9d3d, 17339), (0x9d3e, 17336), (0x9d3f, 10211), (0x9d40, 17338), (0x9d41, 17325),
+    (0x9d42, 17334), (0x9d43, 17335), (0x9d45, 17333), (0x9d4a, 17696), (0x9d4b, 17698), (0x9d4c, 17701), (0x9d4f, 17695), (0x9d51, 10387),
+    (0x9d52, 17703), (0x9d53, 17694), (0x9d54, 17704), (0x9d56, 17700), (0x9d57, 17702), (0x9d58, 17706), (0x9d59, 17699), (0x9d5a, 17707),
+    (0x9d5b, 17697), (0x9d5c, 17693), (0x9d5d, 10388), (0x9d5f, 17705), (0x9d60, 10389), (0x9d61, 10532), (0x9d67, 17326), (0x9d68, 18041),
+    (0x9d69, 18032), (0x9d6a, 10534), (0x9d6b, 18028), (0x9d6c, 10535), (0x9d6f, 18037), (0x9d70, 18031), (0x9d71, 18021), (0x9d72, 10533),
+    (0x9d73, 18034), (0x9d74, 18029), (0x9d75, 18030), (0x9d77, 18016), (0x9d78, 18023), (0x9d79, 18038), (0x9d7b, 18035), (0x9d7d, 18027),
+    (0x9d7f, 18039), (0x9d80, 18022), (0x9d81, 18017), (0x9d82, 18036), (0x9d84, 18019), (0x9d85, 18033), (0x9d86, 18024), (0x9d87, 18040),
+    (0x9d88, 18020), (0x9d89, 10531), (0x9d8a, 18018), (0x9d8b, 18025), (0x9d8c, 18026), (0x9d90, 18273), (0x9d92, 18271), (0x9d94, 18276),
+    (0x9d96, 18288), (0x9d97, 18279), (0x9d98, 18272), (0x9d99, 18268), (0x9d9a, 18281), (0x9d9b, 18274), (0x9d9c, 18277), (0x9d9d, 18270),
+    (0x9d9e, 18284), (0x9d9f, 18267), (0x9da0, 18275), (0x9da1, 18280), (0x9da2, 18282), (0x9da3, 18285), (0x9da4, 18269), (0x9da6, 18289),
+    (0x9da7, 18290), (0x9da8, 18283), (0x9da9, 18287), (0x9daa, 18278), (0x9dac, 18464), (0x9dad, 18467), (0x9daf, 10709), (0x9db1, 18466),
+    (0x9db2, 18471), (0x9db3, 18469), (0x9db4, 10710), (0x9db5, 18460), (0x9db6, 18450), (0x9db7, 18449), (0x9db8, 10712), (0x9db9, 18462),
+    (0x9dba, 18463), (0x9dbb, 18459), (0x9dbc, 18451), (0x9dbe, 18456), (0x9dbf, 18286), (0x9dc1, 18452), (0x9dc2, 10711), (0x9dc3, 18458),
+    (0x9dc5, 18457), (0x9dc7, 18453), (0x9dc8, 18465), (0x9dca, 18454), (0x9dcb, 18615), (0x9dcc, 18468), (0x9dcd, 18470), (0x9dce, 18461),
+    (0x9dcf, 18455), (0x9dd0, 18616), (0x9dd1, 18618), (0x9dd2, 18612), (0x9dd3, 10772), (0x9dd5, 18625), (0x9dd6, 18623), (0x9dd7, 10773),
+    (0x9dd8, 18622), (0x9dd9, 18621), (0x9dda, 18614), (0x9ddb, 18611), (0x9ddc, 18617), (0x9ddd, 18626), (0x9dde, 18613), (0x9ddf, 18619),
+    (0x9de1, 18724), (0x9de2, 18729), (0x9de3, 18719), (0x9de4, 18722), (0x9de5, 10816), (0x9de6, 18726), (0x9de8, 18733), (0x9de9, 18620),
+    (0x9deb, 18720), (0x9dec, 18730), (0x9ded, 18734), (0x9dee, 18725), (0x9def, 18718), (0x9df0, 18728), (0x9df2, 18727), (0x9df3, 18732),
+    (0x9df4, 18731), (0x9df5, 18624), (0x9df6, 18723), (0x9df7, 18717), (0x9df8, 18721), (0x9df9, 10847), (0x9dfa, 10848), (0x9dfb, 18716),
+    (0x9dfd, 18815), (0x9dfe, 18806), (0x9dff, 18814), (0x9e00, 18811), (0x9e01, 18812), (0x9e02, 18805), (0x9e03, 18808), (0x9e04, 18816),
+    (0x9e05, 18810), (0x9e06, 18809), (0x9e07, 18807), (0x9e09, 18813), (0x9e0b, 18869), (0x9e0d, 18870), (0x9e0f, 18872), (0x9e10, 18871),
+    (0x9e11, 18874), (0x9e12, 18873), (0x9e13, 18900), (0x9e14, 18899), (0x9e15, 18925), (0x9e17, 18926), (0x9e19, 18939), (0x9e1a, 10889),
+    (0x9e1b, 10893), (0x9e1d, 18949), (0x9e1e, 10894), (0x9e75, 7943), (0x9e79, 10629), (0x9e7a, 18472), (0x9e7c, 10849), (0x9e7d, 10850),
+    (0x9e7f, 7944), (0x9e80, 15055), (0x9e82, 8862), (0x9e83, 16313), (0x9e86, 16877), (0x9e87, 16878), (0x9e88, 16876), (0x9e89, 17343),
+    (0x9e8a, 17342), (0x9e8b, 10212), (0x9e8c, 17709), (0x9e8d, 17344), (0x9e8e, 17708), (0x9e91, 18043), (0x9e92, 10536), (0x9e93, 10538),
+    (0x9e94, 18042), (0x9e97, 10537), (0x9e99, 18291), (0x9e9a, 18293), (0x9e9b, 18292), (0x9e9c, 18473), (0x9e9d, 10713), (0x9e9f, 10817),
+    (0x9ea0, 18817), (0x9ea1, 18875), (0x9ea4, 18952), (0x9ea5, 7945), (0x9ea7, 15674), (0x9ea9, 9641), (0x9ead, 16880), (0x9eae, 16879),
+    (0x9eb0, 17345), (0x9eb4, 10539), (0x9eb5, 10630), (0x9eb6, 18627), (0x9eb7, 18945), (0x9ebb, 7946), (0x9ebc, 9250), (0x9ebe, 9642),
+    (0x9ec0, 18044), (0x9ec2, 18735), (0x9ec3, 8415), (0x9ec8, 17346), (0x9ecc, 10869), (0x9ecd, 8416), (0x9ece, 9643), (0x9ecf, 10213),
+    (0x9ed0, 18736), (0x9ed1, 8417), (0x9ed3, 16314), (0x9ed4, 9941), (0x9ed5, 16881), (0x9ed6, 16882), (0x9ed8, 9940), (0x9eda, 17347),
+    (0x9edb, 10217), (0x9edc, 10215), (0x9edd, 10216), (0x9ede, 10214), (0x9edf, 17710), (0x9ee0, 10390), (0x9ee4, 18295), (0x9ee5, 18294),
+    (0x9ee6, 18297), (0x9ee7, 18296), (0x9ee8, 10631), (0x9eeb, 18474), (0x9eed, 18476), (0x9eee, 18475), (0x9eef, 10714), (0x9ef0, 18628),
+    (0x9ef2, 18737), (0x9ef3, 18738), (0x9ef4, 10818), (0x9ef5, 18876), (0x9ef6, 18901), (0x9ef7, 10886), (0x9ef9, 14365), (0x9efa, 16883),
+    (0x9efb, 17348), (0x9efc, 18045), (0x9efd, 15056), (0x9eff, 17349), (0x9f00, 17712), (0x9f01, 17711), (0x9f06, 18739), (0x9f07, 10851),
+    (0x9f09, 18877), (0x9f0a, 18902), (0x9f0e, 8863), (0x9f0f, 16315), (0x9f10, 16316), (0x9f12, 16884), (0x9f13, 8864), (0x9f15, 10391),
+    (0x9f16, 17713), (0x9f18, 18478), (0x9f19, 10715), (0x9f1a, 18479), (0x9f1b, 18477), (0x9f1c, 18740), (0x9f1e, 18818), (0x9f20, 8865),
+    (0x9f22, 17352), (0x9f23, 17351), (0x9f24, 17350), (0x9f25, 17714), (0x9f28, 17718), (0x9f29, 17717), (0x9f2a, 17716), (0x9f2b, 17715),
+    (0x9f2c, 10392), (0x9f2d, 18046), (0x9f2e, 18299), (0x9f2f, 10632), (0x9f30, 18298), (0x9f31, 18480), (0x9f32, 18631), (0x9f33, 18630),
+    (0x9f34, 10774), (0x9f35, 18629), (0x9f36, 18743), (0x9f37, 18742), (0x9f38, 18741), (0x9f3b, 9251), (0x9f3d, 16885), (0x9f3e, 10218),
+    (0x9f40, 18047), (0x9f41, 18048), (0x9f42, 18632), (0x9f43, 18744), (0x9f46, 18819), (0x9f47, 18878), (0x9f48, 18927), (0x9f49, 18954),
+    (0x9f4a, 9252), (0x9f4b, 10219), (0x9f4c, 17719), (0x9f4d, 18049), (0x9f4e, 18481), (0x9f4f, 18745), (0x9f52, 9645), (0x9f54, 17353),
+    (0x9f55, 17720), (0x9f56, 18050), (0x9f57, 18051), (0x9f58, 18052), (0x9f59, 18304), (0x9f5b, 18300), (0x9f5c, 10716), (0x9f5d, 18303),
+    (0x9f5e, 18302), (0x9f5f, 10633), (0x9f60, 18301), (0x9f61, 10635), (0x9f63, 10634), (0x9f64, 18483), (0x9f65, 18482), (0x9f66, 10717),
+    (0x9f67, 10718), (0x9f6a, 10776), (0x9f6b, 18633), (0x9f6c, 10775), (0x9f6e, 18748), (0x9f6f, 18749), (0x9f70, 18747), (0x9f71, 18746),
+    (0x9f72, 10853), (0x9f74, 18820), (0x9f75, 18821), (0x9f76, 18822), (0x9f77, 10852), (0x9f78, 18879), (0x9f79, 18882), (0x9f7a, 18881),
+    (0x9f7b, 18880), (0x9f7e, 18953), (0x9f8d, 9942), (0x9f90, 10399), (0x9f91, 18305), (0x9f92, 18484), (0x9f94, 10777), (0x9f95, 18634),
+    (0x9f98, 18955), (0x9f9c, 9943), (0x9fa0, 17354), (0x9fa2, 18635), (0x9fa4, 18903), (0xfa0c, 11314), (0xfa0d, 14598), (0xfe30, 5034),
+    (0xfe31, 5047), (0xfe33, 5049), (0xfe34, 5051), (0xfe35, 5055), (0xfe36, 5056), (0xfe37, 5059), (0xfe38, 5060), (0xfe39, 5063),
+    (0xfe3a, 5064), (0xfe3b, 5067), (0xfe3c, 5068), (0xfe3d, 5071), (0xfe3e, 5072), (0xfe3f, 5075), (0xfe40, 5076), (0xfe41, 5079),
+    (0xfe42, 5080), (0xfe43, 5083), (0xfe44, 5084), (0xfe49, 5124), (0xfe4a, 5125), (0xfe4b, 5128), (0xfe4c, 5129), (0xfe4d, 5126),
+    (0xfe4e, 5127), (0xfe4f, 5052), (0xfe50, 5037), (0xfe52, 5039), (0xfe54, 5041), (0xfe55, 5042), (0xfe56, 5043), (0xfe57, 5044),
+    (0xfe59, 5085), (0xfe5a, 5086), (0xfe5b, 5087), (0xfe5c, 5088), (0xfe5d, 5089), (0xfe5e, 5090), (0xfe5f, 5130), (0xfe60, 5131),
+    (0xfe61, 5132), (0xfe62, 5148), (0xfe63, 5149), (0xfe64, 5150), (0xfe65, 5151), (0xfe66, 5152), (0xfe69, 5193), (0xfe6a, 5194),
+    (0xfe6b, 5195), (0xff01, 5033), (0xff03, 5099), (0xff04, 5184), (0xff05, 5189), (0xff06, 5100), (0xff08, 5053), (0xff09, 5054),
+    (0xff0a, 5101), (0xff0b, 5133), (0xff0c, 5025), (0xff0d, 5134), (0xff0e, 5028), (0xff0f, 5182), (0xff10, 5258), (0xff11, 5259),
+    (0xff12, 5260), (0xff13, 5261), (0xff14, 5262), (0xff15, 5263), (0xff16, 5264), (0xff17, 5265), (0xff18, 5266), (0xff19, 5267),
+    (0xff1a, 5031), (0xff1b, 5030), (0xff1c, 5139), (0xff1d, 5141), (0xff1e, 5140), (0xff1f, 5032), (0xff20, 5190), (0xff21, 5290),
+    (0xff22, 5291), (0xff23, 5292), (0xff24, 5293), (0xff25, 5294), (0xff26, 5295), (0xff27, 5296), (0xff28, 5297), (0xff29, 5298),
+    (0xff2a, 5299), (0xff2b, 5300), (0xff2c, 5301), (0xff2d, 5302), (0xff2e, 5303), (0xff2f, 5304), (0xff30, 5305), (0xff31, 5306),
+    (0xff32, 5307), (0xff33, 5308), (0xff34, 5309), (0xff35, 5310), (0xff36, 5311), (0xff37, 5312), (0xff38, 5313), (0xff39, 5314),
+    (0xff3a, 5315), (0xff3c, 5183), (0xff3f, 5122), (0xff41, 5316), (0xff42, 5317), (0xff43, 5318), (0xff44, 5319), (0xff45, 5320),
+    (0xff46, 5321), (0xff47, 5322), (0xff48, 5323), (0xff49, 5324), (0xff4a, 5325), (0xff4b, 5326), (0xff4c, 5327), (0xff4d, 5328),
+    (0xff4e, 5329), (0xff4f, 5330), (0xff50, 5331), (0xff51, 5332), (0xff52, 5333), (0xff53, 5334), (0xff54, 5335), (0xff55, 5336),
+    (0xff56, 5337), (0xff57, 5338), (0xff58, 5339), (0xff59, 5340), (0xff5a, 5341), (0xff5b, 5057), (0xff5c, 5045), (0xff5d, 5058),
+    (0xff64, 5038), (0xffe3, 5121),
+];
+
+/// Returns the character at `pointer`, if any.
+pub(crate) fn decode(pointer: usize) -> Option<char> {
+    match TO_UNICODE.get(pointer) {
+        Some(&0) | None => None,
+        Some(&code_point) => char::from_u32(u32::from(code_point)),
+    }
+}
+
+/// Returns the pointer of `character`, if it is part of the character set.
+pub(crate) fn encode(character: char) -> Option<usize> {
+    let code_point = u16::try_from(u32::from(character)).ok()?;
+
+    FROM_UNICODE
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| usize::from(FROM_UNICODE[index].1))
+}
@@ -0,0 +1,4312 @@
+//! The Big5-HKSCS character set, where the pointer of the character with lead byte `l` and trail
+//! byte `t` is `(l - 0x81) * 157 + t - o`, with `o` being 0x40 for trail bytes below 0x7f and 0x62
+//! otherwise.
+//!
+//! The four pairs of bytes that stand for a letter followed by a combining mark are left
+//! unassigned here, since each entry is a single scalar value. The charset decodes them separately.
+
+use std::char;
+
+/// The Unicode code point of the character at each pointer, or 0 if the pointer is unassigned.
+#[rustfmt::skip]
+static TO_UNICODE: [u32; 19782] = [
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x043f0, 0x04c32, 0x04603, 0x045a6, 0x04578, 0x27267,
+    0x04d77, 0x045b3, 0x27cb1, 0x04ce2, 0x27cc5, 0x03b95, 0x04736, 0x04744, 0x04c47, 0x04c40, 0x242bf, 0x23617,
+    0x27352, 0x26e8b, 0x270d2, 0x04c57, 0x2a351, 0x0474f, 0x045da, 0x04c85, 0x27c6c, 0x04d07, 0x04aa4, 0x046a1,
+    0x26b23, 0x07225, 0x25a54, 0x21a63, 0x23e06, 0x23f61, 0x0664d, 0x056fb, 0x00000, 0x07d95, 0x0591d, 0x28bb9,
+    0x03df4, 0x09734, 0x27bef, 0x05bdb, 0x21d5e, 0x05aa4, 0x03625, 0x29eb0, 0x05ad1, 0x05bb7, 0x05cfc, 0x0676e,
+    0x08593, 0x29945, 0x07461, 0x0749d, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x031c0, 0x031c1, 0x031c2, 0x031c3, 0x031c4,
+    0x2010c, 0x031c5, 0x200d1, 0x200cd, 0x031c6, 0x031c7, 0x200cb, 0x21fe8, 0x031c8, 0x200ca, 0x031c9, 0x031ca,
+    0x031cb, 0x031cc, 0x2010e, 0x031cd, 0x031ce, 0x00100, 0x000c1, 0x001cd, 0x000c0, 0x00112, 0x000c9, 0x0011a,
+    0x000c8, 0x0014c, 0x000d3, 0x001d1, 0x000d2, 0x00000, 0x01ebe, 0x00000, 0x01ec0, 0x000ca, 0x00101, 0x000e1,
+    0x001ce, 0x000e0, 0x00251, 0x00113, 0x000e9, 0x0011b, 0x000e8, 0x0012b, 0x000ed, 0x001d0, 0x000ec, 0x0014d,
+    0x000f3, 0x001d2, 0x000f2, 0x0016b, 0x000fa, 0x001d4, 0x000f9, 0x001d6, 0x001d8, 0x001da, 0x001dc, 0x000fc,
+    0x00000, 0x01ebf, 0x00000, 0x01ec1, 0x000ea, 0x00261, 0x023da, 0x023db, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x2a3a9, 0x21145, 0x00000, 0x0650a,
+    0x00000, 0x00000, 0x04e3d, 0x06edd, 0x09d4e, 0x091df, 0x00000, 0x00000, 0x27735, 0x06491, 0x04f1a, 0x04f28,
+    0x04fa8, 0x05156, 0x05174, 0x0519c, 0x051e4, 0x052a1, 0x052a8, 0x0533b, 0x0534e, 0x053d1, 0x053d8, 0x056e2,
+    0x058f0, 0x05904, 0x05907, 0x05932, 0x05934, 0x05b66, 0x05b9e, 0x05b9f, 0x05c9a, 0x05e86, 0x0603b, 0x06589,
+    0x067fe, 0x06804, 0x06865, 0x06d4e, 0x070bc, 0x07535, 0x07ea4, 0x07eac, 0x07eba, 0x07ec7, 0x07ecf, 0x07edf,
+    0x07f06, 0x07f37, 0x0827a, 0x082cf, 0x0836f, 0x089c6, 0x08bbe, 0x08be2, 0x08f66, 0x08f67, 0x08f6e, 0x07411,
+    0x07cfc, 0x07dcd, 0x06946, 0x07ac9, 0x05227, 0x00000, 0x00000, 0x00000, 0x00000, 0x0918c, 0x078b8, 0x0915e,
+    0x080bc, 0x00000, 0x08d0b, 0x080f6, 0x209e7, 0x00000, 0x00000, 0x0809f, 0x09ec7, 0x04ccd, 0x09dc9, 0x09e0c,
+    0x04c3e, 0x29df6, 0x2700e, 0x09e0a, 0x2a133, 0x035c1, 0x00000, 0x06e9a, 0x0823e, 0x07519, 0x00000, 0x04911,
+    0x09a6c, 0x09a8f, 0x09f99, 0x07987, 0x2846c, 0x21dca, 0x205d0, 0x22ae6, 0x04e24, 0x04e81, 0x04e80, 0x04e87,
+    0x04ebf, 0x04eeb, 0x04f37, 0x0344c, 0x04fbd, 0x03e48, 0x05003, 0x05088, 0x0347d, 0x03493, 0x034a5, 0x05186,
+    0x05905, 0x051db, 0x051fc, 0x05205, 0x04e89, 0x05279, 0x05290, 0x05327, 0x035c7, 0x053a9, 0x03551, 0x053b0,
+    0x03553, 0x053c2, 0x05423, 0x0356d, 0x03572, 0x03681, 0x05493, 0x054a3, 0x054b4, 0x054b9, 0x054d0, 0x054ef,
+    0x05518, 0x05523, 0x05528, 0x03598, 0x0553f, 0x035a5, 0x035bf, 0x055d7, 0x035c5, 0x27d84, 0x05525, 0x00000,
+    0x20c42, 0x20d15, 0x2512b, 0x05590, 0x22cc6, 0x039ec, 0x20341, 0x08e46, 0x24db8, 0x294e5, 0x04053, 0x280be,
+    0x0777a, 0x22c38, 0x03a34, 0x047d5, 0x2815d, 0x269f2, 0x24dea, 0x064dd, 0x20d7c, 0x20fb4, 0x20cd5, 0x210f4,
+    0x0648d, 0x08e7e, 0x20e96, 0x20c0b, 0x20f64, 0x22ca9, 0x28256, 0x244d3, 0x00000, 0x20d46, 0x29a4d, 0x280e9,
+    0x047f4, 0x24ea7, 0x22cc2, 0x09ab2, 0x03a67, 0x295f4, 0x03fed, 0x03506, 0x252c7, 0x297d4, 0x278c8, 0x22d44,
+    0x09d6e, 0x09815, 0x00000, 0x043d9, 0x260a5, 0x064b4, 0x054e3, 0x22d4c, 0x22bca, 0x21077, 0x039fb, 0x2106f,
+    0x266da, 0x26716, 0x279a0, 0x064ea, 0x25052, 0x20c43, 0x08e68, 0x221a1, 0x28b4c, 0x20731, 0x00000, 0x0480b,
+    0x201a9, 0x03ffa, 0x05873, 0x22d8d, 0x00000, 0x245c8, 0x204fc, 0x26097, 0x20f4c, 0x20d96, 0x05579, 0x040bb,
+    0x043ba, 0x00000, 0x04ab4, 0x22a66, 0x2109d, 0x081aa, 0x098f5, 0x20d9c, 0x06379, 0x039fe, 0x22775, 0x08dc0,
+    0x056a1, 0x0647c, 0x03e43, 0x00000, 0x2a601, 0x20e09, 0x22acf, 0x22cc9, 0x00000, 0x210c8, 0x239c2, 0x03992,
+    0x03a06, 0x2829b, 0x03578, 0x25e49, 0x220c7, 0x05652, 0x20f31, 0x22cb2, 0x29720, 0x034bc, 0x06c3d, 0x24e3b,
+    0x00000, 0x00000, 0x27574, 0x22e8b, 0x22208, 0x2a65b, 0x28ccd, 0x20e7a, 0x20c34, 0x2681c, 0x07f93, 0x210cf,
+    0x22803, 0x22939, 0x035fb, 0x251e3, 0x20e8c, 0x20f8d, 0x20eaa, 0x03f93, 0x20f30, 0x20d47, 0x2114f, 0x20e4c,
+    0x00000, 0x20eab, 0x20ba9, 0x20d48, 0x210c0, 0x2113d, 0x03ff9, 0x22696, 0x06432, 0x20fad, 0x233f4, 0x27639,
+    0x22bce, 0x20d7e, 0x20d7f, 0x22c51, 0x22c55, 0x03a18, 0x20e98, 0x210c7, 0x20f2e, 0x2a632, 0x26b50, 0x28cd2,
+    0x28d99, 0x28cca, 0x095aa, 0x054cc, 0x082c4, 0x055b9, 0x00000, 0x29ec3, 0x09c26, 0x09ab6, 0x2775e, 0x22dee,
+    0x07140, 0x0816d, 0x080ec, 0x05c1c, 0x26572, 0x08134, 0x03797, 0x0535f, 0x280bd, 0x091b6, 0x20efa, 0x20e0f,
+    0x20e77, 0x20efb, 0x035dd, 0x24deb, 0x03609, 0x20cd6, 0x056af, 0x227b5, 0x210c9, 0x20e10, 0x20e78, 0x21078,
+    0x21148, 0x28207, 0x21455, 0x20e79, 0x24e50, 0x22da4, 0x05a54, 0x2101d, 0x2101e, 0x210f5, 0x210f6, 0x0579c,
+    0x20e11, 0x27694, 0x282cd, 0x20fb5, 0x20e7b, 0x2517e, 0x03703, 0x20fb6, 0x21180, 0x252d8, 0x2a2bd, 0x249da,
+    0x2183a, 0x24177, 0x2827c, 0x05899, 0x05268, 0x0361a, 0x2573d, 0x07bb2, 0x05b68, 0x04800, 0x04b2c, 0x09f27,
+    0x049e7, 0x09c1f, 0x09b8d, 0x25b74, 0x2313d, 0x055fb, 0x035f2, 0x05689, 0x04e28, 0x05902, 0x21bc1, 0x2f878,
+    0x09751, 0x20086, 0x04e5b, 0x04ebb, 0x0353e, 0x05c23, 0x05f51, 0x05fc4, 0x038fa, 0x0624c, 0x06535, 0x06b7a,
+    0x06c35, 0x06c3a, 0x0706c, 0x0722b, 0x04e2c, 0x072ad, 0x248e9, 0x07f52, 0x0793b, 0x07cf9, 0x07f53, 0x2626a,
+    0x034c1, 0x00000, 0x2634b, 0x08002, 0x08080, 0x26612, 0x26951, 0x0535d, 0x08864, 0x089c1, 0x278b2, 0x08ba0,
+    0x08d1d, 0x09485, 0x09578, 0x0957f, 0x095e8, 0x28e0f, 0x097e6, 0x09875, 0x098ce, 0x098de, 0x09963, 0x29810,
+    0x09c7c, 0x09e1f, 0x09ec4, 0x06b6f, 0x0f907, 0x04e37, 0x20087, 0x0961d, 0x06237, 0x094a2, 0x00000, 0x0503b,
+    0x06dfe, 0x29c73, 0x09fa6, 0x03dc9, 0x0888f, 0x2414e, 0x07077, 0x05cf5, 0x04b20, 0x251cd, 0x03559, 0x25d30,
+    0x06122, 0x28a32, 0x08fa7, 0x091f6, 0x07191, 0x06719, 0x073ba, 0x23281, 0x2a107, 0x03c8b, 0x21980, 0x04b10,
+    0x078e4, 0x07402, 0x051ae, 0x2870f, 0x04009, 0x06a63, 0x2a2ba, 0x04223, 0x0860f, 0x20a6f, 0x07a2a, 0x29947,
+    0x28aea, 0x09755, 0x0704d, 0x05324, 0x2207e, 0x093f4, 0x076d9, 0x289e3, 0x09fa7, 0x077dd, 0x04ea3, 0x04ff0,
+    0x050bc, 0x04e2f, 0x04f17, 0x09fa8, 0x05434, 0x07d8b, 0x05892, 0x058d0, 0x21db6, 0x05e92, 0x05e99, 0x05fc2,
+    0x22712, 0x0658b, 0x233f9, 0x06919, 0x06a43, 0x23c63, 0x06cff, 0x00000, 0x07200, 0x24505, 0x0738c, 0x03edb,
+    0x24a13, 0x05b15, 0x074b9, 0x08b83, 0x25ca4, 0x25695, 0x07a93, 0x07bec, 0x07cc3, 0x07e6c, 0x082f8, 0x08597,
+    0x09fa9, 0x08890, 0x09faa, 0x08eb9, 0x09fab, 0x08fcf, 0x0855f, 0x099e0, 0x09221, 0x09fac, 0x28db9, 0x2143f,
+    0x04071, 0x042a2, 0x05a1a, 0x00000, 0x00000, 0x00000, 0x09868, 0x0676b, 0x04276, 0x0573d, 0x00000, 0x085d6,
+    0x2497b, 0x082bf, 0x2710d, 0x04c81, 0x26d74, 0x05d7b, 0x26b15, 0x26fbe, 0x09fad, 0x09fae, 0x05b96, 0x09faf,
+    0x066e7, 0x07e5b, 0x06e57, 0x079ca, 0x03d88, 0x044c3, 0x23256, 0x22796, 0x0439a, 0x04536, 0x00000, 0x05cd5,
+    0x23b1a, 0x08af9, 0x05c78, 0x03d12, 0x23551, 0x05d78, 0x09fb2, 0x07157, 0x04558, 0x240ec, 0x21e23, 0x04c77,
+    0x03978, 0x0344a, 0x201a4, 0x26c41, 0x08acc, 0x04fb4, 0x20239, 0x059bf, 0x0816c, 0x09856, 0x298fa, 0x05f3b,
+    0x20b9f, 0x00000, 0x221c1, 0x2896d, 0x04102, 0x046bb, 0x29079, 0x03f07, 0x09fb3, 0x2a1b5, 0x040f8, 0x037d6,
+    0x046f7, 0x26c46, 0x0417c, 0x286b2, 0x273ff, 0x0456d, 0x038d4, 0x2549a, 0x04561, 0x0451b, 0x04d89, 0x04c7b,
+    0x04d76, 0x045ea, 0x03fc8, 0x24b0f, 0x03661, 0x044de, 0x044bd, 0x041ed, 0x05d3e, 0x05d48, 0x05d56, 0x03dfc,
+    0x0380f, 0x05da4, 0x05db9, 0x03820, 0x03838, 0x05e42, 0x05ebd, 0x05f25, 0x05f83, 0x03908, 0x03914, 0x0393f,
+    0x0394d, 0x060d7, 0x0613d, 0x05ce5, 0x03989, 0x061b7, 0x061b9, 0x061cf, 0x039b8, 0x0622c, 0x06290, 0x062e5,
+    0x06318, 0x039f8, 0x056b1, 0x03a03, 0x063e2, 0x063fb, 0x06407, 0x0645a, 0x03a4b, 0x064c0, 0x05d15, 0x05621,
+    0x09f9f, 0x03a97, 0x06586, 0x03abd, 0x065ff, 0x06653, 0x03af2, 0x06692, 0x03b22, 0x06716, 0x03b42, 0x067a4,
+    0x06800, 0x03b58, 0x0684a, 0x06884, 0x03b72, 0x03b71, 0x03b7b, 0x06909, 0x06943, 0x0725c, 0x06964, 0x0699f,
+    0x06985, 0x03bbc, 0x069d6, 0x03bdd, 0x06a65, 0x06a74, 0x06a71, 0x06a82, 0x03bec, 0x06a99, 0x03bf2, 0x06aab,
+    0x06ab5, 0x06ad4, 0x06af6, 0x06b81, 0x06bc1, 0x06bea, 0x06c75, 0x06caa, 0x03ccb, 0x06d02, 0x06d06, 0x06d26,
+    0x06d81, 0x03cef, 0x06da4, 0x06db1, 0x06e15, 0x06e18, 0x06e29, 0x06e86, 0x289c0, 0x06ebb, 0x06ee2, 0x06eda,
+    0x09f7f, 0x06ee8, 0x06ee9, 0x06f24, 0x06f34, 0x03d46, 0x23f41, 0x06f81, 0x06fbe, 0x03d6a, 0x03d75, 0x071b7,
+    0x05c99, 0x03d8a, 0x0702c, 0x03d91, 0x07050, 0x07054, 0x0706f, 0x0707f, 0x07089, 0x20325, 0x043c1, 0x035f1,
+    0x20ed8, 0x23ed7, 0x057be, 0x26ed3, 0x0713e, 0x257e0, 0x0364e, 0x069a2, 0x28be9, 0x05b74, 0x07a49, 0x258e1,
+    0x294d9, 0x07a65, 0x07a7d, 0x259ac, 0x07abb, 0x07ab0, 0x07ac2, 0x07ac3, 0x071d1, 0x2648d, 0x041ca, 0x07ada,
+    0x07add, 0x07aea, 0x041ef, 0x054b2, 0x25c01, 0x07b0b, 0x07b55, 0x07b29, 0x2530e, 0x25cfe, 0x07ba2, 0x07b6f,
+    0x0839c, 0x25bb4, 0x26c7f, 0x07bd0, 0x08421, 0x07b92, 0x00000, 0x25d20, 0x03dad, 0x25c65, 0x08492, 0x07bfa,
+    0x00000, 0x07c35, 0x25cc1, 0x07c44, 0x07c83, 0x24882, 0x07ca6, 0x0667d, 0x24578, 0x07cc9, 0x07cc7, 0x07ce6,
+    0x07c74, 0x07cf3, 0x07cf5, 0x00000, 0x07e67, 0x0451d, 0x26e44, 0x07d5d, 0x26ed6, 0x0748d, 0x07d89, 0x07dab,
+    0x07135, 0x07db3, 0x00000, 0x24057, 0x26029, 0x07de4, 0x03d13, 0x07df5, 0x217f9, 0x07de5, 0x2836d, 0x00000,
+    0x26121, 0x2615a, 0x07e6e, 0x07e92, 0x0432b, 0x0946c, 0x07e27, 0x07f40, 0x07f41, 0x07f47, 0x07936, 0x262d0,
+    0x099e1, 0x07f97, 0x26351, 0x07fa3, 0x21661, 0x20068, 0x0455c, 0x23766, 0x04503, 0x2833a, 0x07ffa, 0x26489,
+    0x00000, 0x08008, 0x0801d, 0x00000, 0x0802f, 0x2a087, 0x26cc3, 0x0803b, 0x0803c, 0x08061, 0x22714, 0x04989,
+    0x26626, 0x23de3, 0x266e8, 0x06725, 0x080a7, 0x28a48, 0x08107, 0x0811a, 0x058b0, 0x226f6, 0x06c7f, 0x26498,
+    0x24fb8, 0x064e7, 0x2148a, 0x08218, 0x2185e, 0x06a53, 0x24a65, 0x24a95, 0x0447a, 0x08229, 0x20b0d, 0x26a52,
+    0x23d7e, 0x04ff9, 0x214fd, 0x084e2, 0x08362, 0x26b0a, 0x249a7, 0x23530, 0x21773, 0x23df8, 0x082aa, 0x0691b,
+    0x2f994, 0x041db, 0x0854b, 0x082d0, 0x0831a, 0x20e16, 0x217b4, 0x036c1, 0x2317d, 0x2355a, 0x0827b, 0x082e2,
+    0x08318, 0x23e8b, 0x26da3, 0x26b05, 0x26b97, 0x235ce, 0x03dbf, 0x0831d, 0x055ec, 0x08385, 0x0450b, 0x26da5,
+    0x083ac, 0x00000, 0x083d3, 0x0347e, 0x26ed4, 0x06a57, 0x0855a, 0x03496, 0x26e42, 0x22eef, 0x08458, 0x25be4,
+    0x08471, 0x03dd3, 0x044e4, 0x06aa7, 0x0844a, 0x23cb5, 0x07958, 0x00000, 0x26b96, 0x26e77, 0x26e43, 0x084de,
+    0x00000, 0x08391, 0x044a0, 0x08493, 0x084e4, 0x25c91, 0x04240, 0x25cc0, 0x04543, 0x08534, 0x05af2, 0x26e99,
+    0x04527, 0x08573, 0x04516, 0x067bf, 0x08616, 0x28625, 0x2863b, 0x085c1, 0x27088, 0x08602, 0x21582, 0x270cd,
+    0x2f9b2, 0x0456a, 0x08628, 0x03648, 0x218a2, 0x053f7, 0x2739a, 0x0867e, 0x08771, 0x2a0f8, 0x087ee, 0x22c27,
+    0x087b1, 0x087da, 0x0880f, 0x05661, 0x0866c, 0x06856, 0x0460f, 0x08845, 0x08846, 0x275e0, 0x23db9, 0x275e4,
+    0x0885e, 0x0889c, 0x0465b, 0x088b4, 0x088b5, 0x063c1, 0x088c5, 0x07777, 0x2770f, 0x08987, 0x0898a, 0x00000,
+    0x00000, 0x089a7, 0x089bc, 0x28a25, 0x089e7, 0x27924, 0x27abd, 0x08a9c, 0x07793, 0x091fe, 0x08a90, 0x27a59,
+    0x07ae9, 0x27b3a, 0x23f8f, 0x04713, 0x27b38, 0x0717c, 0x08b0c, 0x08b1f, 0x25430, 0x25565, 0x08b3f, 0x08b4c,
+    0x08b4d, 0x08aa9, 0x24a7a, 0x08b90, 0x08b9b, 0x08aaf, 0x216df, 0x04615, 0x0884f, 0x08c9b, 0x27d54, 0x27d8f,
+    0x2f9d4, 0x03725, 0x27d53, 0x08cd6, 0x27d98, 0x27dbd, 0x08d12, 0x08d03, 0x21910, 0x08cdb, 0x0705c, 0x08d11,
+    0x24cc9, 0x03ed0, 0x00000, 0x08da9, 0x28002, 0x21014, 0x2498a, 0x03b7c, 0x281bc, 0x2710c, 0x07ae7, 0x08ead,
+    0x08eb6, 0x08ec3, 0x092d4, 0x08f19, 0x08f2d, 0x28365, 0x28412, 0x08fa5, 0x09303, 0x2a29f, 0x20a50, 0x08fb3,
+    0x0492a, 0x289de, 0x2853d, 0x23dbb, 0x05ef8, 0x23262, 0x08ff9, 0x2a014, 0x286bc, 0x28501, 0x22325, 0x03980,
+    0x26ed7, 0x09037, 0x2853c, 0x27abe, 0x09061, 0x2856c, 0x2860b, 0x090a8, 0x28713, 0x090c4, 0x286e6, 0x090ae,
+    0x00000, 0x09167, 0x03af0, 0x091a9, 0x091c4, 0x07cac, 0x28933, 0x21e89, 0x0920e, 0x06c9f, 0x09241, 0x09262,
+    0x255b9, 0x00000, 0x28ac6, 0x23c9b, 0x28b0c, 0x255db, 0x20d31, 0x0932c, 0x0936b, 0x28ae1, 0x28beb, 0x0708f,
+    0x05ac3, 0x28ae2, 0x28ae5, 0x04965, 0x09244, 0x28bec, 0x28c39, 0x28bff, 0x09373, 0x0945b, 0x08ebc, 0x09585,
+    0x095a6, 0x09426, 0x095a0, 0x06ff6, 0x042b9, 0x2267a, 0x286d8, 0x2127c, 0x23e2e, 0x049df, 0x06c1c, 0x0967b,
+    0x09696, 0x0416c, 0x096a3, 0x26ed5, 0x061da, 0x096b6, 0x078f5, 0x28ae0, 0x096bd, 0x053cc, 0x049a1, 0x26cb8,
+    0x20274, 0x26410, 0x290af, 0x290e5, 0x24ad1, 0x21915, 0x2330a, 0x09731, 0x08642, 0x09736, 0x04a0f, 0x0453d,
+    0x04585, 0x24ae9, 0x07075, 0x05b41, 0x0971b, 0x00000, 0x291d5, 0x09757, 0x05b4a, 0x291eb, 0x0975f, 0x09425,
+    0x050d0, 0x230b7, 0x230bc, 0x09789, 0x0979f, 0x097b1, 0x097be, 0x097c0, 0x097d2, 0x097e0, 0x2546c, 0x097ee,
+    0x0741c, 0x29433, 0x00000, 0x097f5, 0x2941d, 0x2797a, 0x04ad1, 0x09834, 0x09833, 0x0984b, 0x09866, 0x03b0e,
+    0x27175, 0x03d51, 0x20630, 0x2415c, 0x25706, 0x098ca, 0x098b7, 0x098c8, 0x098c7, 0x04aff, 0x26d27, 0x216d3,
+    0x055b0, 0x098e1, 0x098e6, 0x098ec, 0x09378, 0x09939, 0x24a29, 0x04b72, 0x29857, 0x29905, 0x099f5, 0x09a0c,
+    0x09a3b, 0x09a10, 0x09a58, 0x25725, 0x036c4, 0x290b1, 0x29bd5, 0x09ae0, 0x09ae2, 0x29b05, 0x09af4, 0x04c0e,
+    0x09b14, 0x09b2d, 0x28600, 0x05034, 0x09b34, 0x269a8, 0x038c3, 0x2307d, 0x09b50, 0x09b40, 0x29d3e, 0x05a45,
+    0x21863, 0x09b8e, 0x2424b, 0x09c02, 0x09bff, 0x09c0c, 0x29e68, 0x09dd4, 0x29fb7, 0x2a192, 0x2a1ab, 0x2a0e1,
+    0x2a123, 0x2a1df, 0x09d7e, 0x09d83, 0x2a134, 0x09e0e, 0x06888, 0x09dc4, 0x2215b, 0x2a193, 0x2a220, 0x2193b,
+    0x2a233, 0x09d39, 0x2a0b9, 0x2a2b4, 0x09e90, 0x09e95, 0x09e9e, 0x09ea2, 0x04d34, 0x09eaa, 0x09eaf, 0x24364,
+    0x09ec1, 0x03b60, 0x039e5, 0x03d1d, 0x04f32, 0x037be, 0x28c2b, 0x09f02, 0x09f08, 0x04b96, 0x09424, 0x26da2,
+    0x09f17, 0x00000, 0x09f39, 0x0569f, 0x0568a, 0x09f45, 0x099b8, 0x2908b, 0x097f2, 0x0847f, 0x09f62, 0x09f69,
+    0x07adc, 0x09f8e, 0x07216, 0x04bbe, 0x24975, 0x249bb, 0x07177, 0x249f8, 0x24348, 0x24a51, 0x0739e, 0x28bda,
+    0x218fa, 0x0799f, 0x2897e, 0x28e36, 0x09369, 0x093f3, 0x28a44, 0x092ec, 0x09381, 0x093cb, 0x2896c, 0x244b9,
+    0x07217, 0x03eeb, 0x07772, 0x07a43, 0x070d0, 0x24473, 0x243f8, 0x0717e, 0x217ef, 0x070a3, 0x218be, 0x23599,
+    0x03ec7, 0x21885, 0x2542f, 0x217f8, 0x03722, 0x216fb, 0x21839, 0x036e1, 0x21774, 0x218d1, 0x25f4b, 0x03723,
+    0x216c0, 0x0575b, 0x24a25, 0x213fe, 0x212a8, 0x213c6, 0x214b6, 0x08503, 0x236a6, 0x00000, 0x08455, 0x24994,
+    0x27165, 0x23e31, 0x2555c, 0x23efb, 0x27052, 0x044f4, 0x236ee, 0x2999d, 0x26f26, 0x067f9, 0x03733, 0x03c15,
+    0x03de7, 0x0586c, 0x21922, 0x06810, 0x04057, 0x2373f, 0x240e1, 0x2408b, 0x2410f, 0x26c21, 0x054cb, 0x0569e,
+    0x266b1, 0x05692, 0x20fdf, 0x20ba8, 0x20e0d, 0x093c6, 0x28b13, 0x0939c, 0x04ef8, 0x0512b, 0x03819, 0x24436,
+    0x04ebc, 0x20465, 0x2037f, 0x04f4b, 0x04f8a, 0x25651, 0x05a68, 0x201ab, 0x203cb, 0x03999, 0x2030a, 0x20414,
+    0x03435, 0x04f29, 0x202c0, 0x28eb3, 0x20275, 0x08ada, 0x2020c, 0x04e98, 0x050cd, 0x0510d, 0x04fa2, 0x04f03,
+    0x24a0e, 0x23e8a, 0x04f42, 0x0502e, 0x0506c, 0x05081, 0x04fcc, 0x04fe5, 0x05058, 0x050fc, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x06e76, 0x23595, 0x23e39, 0x23ebf, 0x06d72, 0x21884, 0x23e89, 0x051a8, 0x051c3, 0x205e0,
+    0x044dd, 0x204a3, 0x20492, 0x20491, 0x08d7a, 0x28a9c, 0x2070e, 0x05259, 0x052a4, 0x20873, 0x052e1, 0x00000,
+    0x0467a, 0x0718c, 0x2438c, 0x20c20, 0x249ac, 0x210e4, 0x069d1, 0x20e1d, 0x00000, 0x03ede, 0x07499, 0x07414,
+    0x07456, 0x07398, 0x04b8e, 0x24abc, 0x2408d, 0x053d0, 0x03584, 0x0720f, 0x240c9, 0x055b4, 0x20345, 0x054cd,
+    0x20bc6, 0x0571d, 0x0925d, 0x096f4, 0x09366, 0x057dd, 0x0578d, 0x0577f, 0x0363e, 0x058cb, 0x05a99, 0x28a46,
+    0x216fa, 0x2176f, 0x21710, 0x05a2c, 0x059b8, 0x0928f, 0x05a7e, 0x05acf, 0x05a12, 0x25946, 0x219f3, 0x21861,
+    0x24295, 0x036f5, 0x06d05, 0x07443, 0x05a21, 0x25e83, 0x05a81, 0x28bd7, 0x20413, 0x093e0, 0x0748c, 0x21303,
+    0x07105, 0x04972, 0x09408, 0x289fb, 0x093bd, 0x037a0, 0x05c1e, 0x05c9e, 0x05e5e, 0x05e48, 0x21996, 0x2197c,
+    0x23aee, 0x05ecd, 0x05b4f, 0x21903, 0x21904, 0x03701, 0x218a0, 0x036dd, 0x216fe, 0x036d3, 0x0812a, 0x28a47,
+    0x21dba, 0x23472, 0x289a8, 0x05f0c, 0x05f0e, 0x21927, 0x217ab, 0x05a6b, 0x2173b, 0x05b44, 0x08614, 0x275fd,
+    0x08860, 0x0607e, 0x22860, 0x2262b, 0x05fdb, 0x03eb8, 0x225af, 0x225be, 0x29088, 0x26f73, 0x061c0, 0x2003e,
+    0x20046, 0x2261b, 0x06199, 0x06198, 0x06075, 0x22c9b, 0x22d07, 0x246d4, 0x2914d, 0x06471, 0x24665, 0x22b6a,
+    0x03a29, 0x22b22, 0x23450, 0x298ea, 0x22e78, 0x06337, 0x2a45b, 0x064b6, 0x06331, 0x063d1, 0x249e3, 0x22d67,
+    0x062a4, 0x22ca1, 0x0643b, 0x0656b, 0x06972, 0x03bf4, 0x2308e, 0x232ad, 0x24989, 0x232ab, 0x0550d, 0x232e0,
+    0x218d9, 0x2943f, 0x066ce, 0x23289, 0x231b3, 0x03ae0, 0x04190, 0x25584, 0x28b22, 0x2558f, 0x216fc, 0x2555b,
+    0x25425, 0x078ee, 0x23103, 0x2182a, 0x23234, 0x03464, 0x2320f, 0x23182, 0x242c9, 0x0668e, 0x26d24, 0x0666b,
+    0x04b93, 0x06630, 0x27870, 0x21deb, 0x06663, 0x232d2, 0x232e1, 0x0661e, 0x25872, 0x038d1, 0x2383a, 0x237bc,
+    0x03b99, 0x237a2, 0x233fe, 0x074d0, 0x03b96, 0x0678f, 0x2462a, 0x068b6, 0x0681e, 0x03bc4, 0x06abe, 0x03863,
+    0x237d5, 0x24487, 0x06a33, 0x06a52, 0x06ac9, 0x06b05, 0x21912, 0x06511, 0x06898, 0x06a4c, 0x03bd7, 0x06a7a,
+    0x06b57, 0x23fc0, 0x23c9a, 0x093a0, 0x092f2, 0x28bea, 0x28acb, 0x09289, 0x2801e, 0x289dc, 0x09467, 0x06da5,
+    0x06f0b, 0x249ec, 0x00000, 0x23f7f, 0x03d8f, 0x06e04, 0x2403c, 0x05a3d, 0x06e0a, 0x05847, 0x06d24, 0x07842,
+    0x0713b, 0x2431a, 0x24276, 0x070f1, 0x07250, 0x07287, 0x07294, 0x2478f, 0x24725, 0x05179, 0x24aa4, 0x205eb,
+    0x0747a, 0x23ef8, 0x2365f, 0x24a4a, 0x24917, 0x25fe1, 0x03f06, 0x03eb1, 0x24adf, 0x28c23, 0x23f35, 0x060a7,
+    0x03ef3, 0x074cc, 0x0743c, 0x09387, 0x07437, 0x0449f, 0x26dea, 0x04551, 0x07583, 0x03f63, 0x24cd9, 0x24d06,
+    0x03f58, 0x07555, 0x07673, 0x2a5c6, 0x03b19, 0x07468, 0x28acc, 0x249ab, 0x2498e, 0x03afb, 0x03dcd, 0x24a4e,
+    0x03eff, 0x249c5, 0x248f3, 0x091fa, 0x05732, 0x09342, 0x28ae3, 0x21864, 0x050df, 0x25221, 0x251e7, 0x07778,
+    0x23232, 0x0770e, 0x0770f, 0x0777b, 0x24697, 0x23781, 0x03a5e, 0x248f0, 0x07438, 0x0749b, 0x03ebf, 0x24aba,
+    0x24ac7, 0x040c8, 0x24a96, 0x261ae, 0x09307, 0x25581, 0x0781e, 0x0788d, 0x07888, 0x078d2, 0x073d0, 0x07959,
+    0x27741, 0x256e3, 0x0410e, 0x00000, 0x08496, 0x079a5, 0x06a2d, 0x23efa, 0x07a3a, 0x079f4, 0x0416e, 0x216e6,
+    0x04132, 0x09235, 0x079f1, 0x20d4c, 0x2498c, 0x20299, 0x23dba, 0x2176e, 0x03597, 0x0556b, 0x03570, 0x036aa,
+    0x201d4, 0x20c0d, 0x07ae2, 0x05a59, 0x226f5, 0x25aaf, 0x25a9c, 0x05a0d, 0x2025b, 0x078f0, 0x05a2a, 0x25bc6,
+    0x07afe, 0x041f9, 0x07c5d, 0x07c6d, 0x04211, 0x25bb3, 0x25ebc, 0x25ea6, 0x07ccd, 0x249f9, 0x217b0, 0x07c8e,
+    0x07c7c, 0x07cae, 0x06ab2, 0x07ddc, 0x07e07, 0x07dd3, 0x07f4e, 0x26261, 0x2615c, 0x27b48, 0x07d97, 0x25e82,
+    0x0426a, 0x26b75, 0x20916, 0x067d6, 0x2004e, 0x235cf, 0x057c4, 0x26412, 0x263f8, 0x24962, 0x07fdd, 0x07b27,
+    0x2082c, 0x25ae9, 0x25d43, 0x07b0c, 0x25e0e, 0x099e6, 0x08645, 0x09a63, 0x06a1c, 0x2343f, 0x039e2, 0x249f7,
+    0x265ad, 0x09a1f, 0x265a0, 0x08480, 0x27127, 0x26cd1, 0x044ea, 0x08137, 0x04402, 0x080c6, 0x08109, 0x08142,
+    0x267b4, 0x098c3, 0x26a42, 0x08262, 0x08265, 0x26a51, 0x08453, 0x26da7, 0x08610, 0x2721b, 0x05a86, 0x0417f,
+    0x21840, 0x05b2b, 0x218a1, 0x05ae4, 0x218d8, 0x086a0, 0x2f9bc, 0x23d8f, 0x0882d, 0x27422, 0x05a02, 0x0886e,
+    0x04f45, 0x08887, 0x088bf, 0x088e6, 0x08965, 0x0894d, 0x25683, 0x08954, 0x27785, 0x27784, 0x28bf5, 0x28bd9,
+    0x28b9c, 0x289f9, 0x03ead, 0x084a3, 0x046f5, 0x046cf, 0x037f2, 0x08a3d, 0x08a1c, 0x29448, 0x05f4d, 0x0922b,
+    0x24284, 0x065d4, 0x07129, 0x070c4, 0x21845, 0x09d6d, 0x08c9f, 0x08ce9, 0x27ddc, 0x0599a, 0x077c3, 0x059f0,
+    0x0436e, 0x036d4, 0x08e2a, 0x08ea7, 0x24c09, 0x08f30, 0x08f4a, 0x042f4, 0x06c58, 0x06fbb, 0x22321, 0x0489b,
+    0x06f79, 0x06e8b, 0x217da, 0x09be9, 0x036b5, 0x2492f, 0x090bb, 0x00000, 0x05571, 0x04906, 0x091bb, 0x09404,
+    0x28a4b, 0x04062, 0x28afc, 0x09427, 0x28c1d, 0x28c3b, 0x084e5, 0x08a2b, 0x09599, 0x095a7, 0x09597, 0x09596,
+    0x28d34, 0x07445, 0x03ec2, 0x248ff, 0x24a42, 0x243ea, 0x03ee7, 0x23225, 0x0968f, 0x28ee7, 0x28e66, 0x28e65,
+    0x03ecc, 0x249ed, 0x24a78, 0x23fee, 0x07412, 0x0746b, 0x03efc, 0x09741, 0x290b0, 0x06847, 0x04a1d, 0x29093,
+    0x257df, 0x00000, 0x09368, 0x28989, 0x28c26, 0x28b2f, 0x263be, 0x092ba, 0x05b11, 0x08b69, 0x0493c, 0x073f9,
+    0x2421b, 0x0979b, 0x09771, 0x09938, 0x20f26, 0x05dc1, 0x28bc5, 0x24ab2, 0x0981f, 0x294da, 0x092f6, 0x295d7,
+    0x091e5, 0x044c0, 0x28b50, 0x24a67, 0x28b64, 0x098dc, 0x28a45, 0x03f00, 0x0922a, 0x04925, 0x08414, 0x0993b,
+    0x0994d, 0x27b06, 0x03dfd, 0x0999b, 0x04b6f, 0x099aa, 0x09a5c, 0x28b65, 0x258c8, 0x06a8f, 0x09a21, 0x05afe,
+    0x09a2f, 0x298f1, 0x04b90, 0x29948, 0x099bc, 0x04bbd, 0x04b97, 0x0937d, 0x05872, 0x21302, 0x05822, 0x249b8,
+    0x214e8, 0x07844, 0x2271f, 0x23db8, 0x068c5, 0x03d7d, 0x09458, 0x03927, 0x06150, 0x22781, 0x2296b, 0x06107,
+    0x09c4f, 0x09c53, 0x09c7b, 0x09c35, 0x09c10, 0x09b7f, 0x09bcf, 0x29e2d, 0x09b9f, 0x2a1f5, 0x2a0fe, 0x09d21,
+    0x04cae, 0x24104, 0x09e18, 0x04cb0, 0x09d0c, 0x2a1b4, 0x2a0ed, 0x2a0f3, 0x2992f, 0x09da5, 0x084bd, 0x26e12,
+    0x26fdf, 0x26b82, 0x085fc, 0x04533, 0x26da4, 0x26e84, 0x26df0, 0x08420, 0x085ee, 0x26e00, 0x237d7, 0x26064,
+    0x079e2, 0x2359c, 0x23640, 0x0492d, 0x249de, 0x03d62, 0x093db, 0x092be, 0x09348, 0x202bf, 0x078b9, 0x09277,
+    0x0944d, 0x04fe4, 0x03440, 0x09064, 0x2555d, 0x0783d, 0x07854, 0x078b6, 0x0784b, 0x21757, 0x231c9, 0x24941,
+    0x0369a, 0x04f72, 0x06fda, 0x06fd9, 0x00000, 0x0701e, 0x05414, 0x241b5, 0x057bb, 0x058f3, 0x0578a, 0x09d16,
+    0x057d7, 0x07134, 0x034af, 0x241ac, 0x071eb, 0x26c40, 0x24f97, 0x00000, 0x217b5, 0x28a49, 0x0610c, 0x05ace,
+    0x05a0b, 0x042bc, 0x24488, 0x0372c, 0x04b7b, 0x289fc, 0x093bb, 0x093b8, 0x218d6, 0x20f1d, 0x08472, 0x26cc0,
+    0x21413, 0x242fa, 0x22c26, 0x243c1, 0x05994, 0x23db7, 0x26741, 0x07da8, 0x2615b, 0x260a4, 0x249b9, 0x2498b,
+    0x289fa, 0x092e5, 0x073e2, 0x03ee9, 0x074b4, 0x28b63, 0x2189f, 0x03ee1, 0x24ab3, 0x06ad8, 0x073f3, 0x073fb,
+    0x03ed6, 0x24a3e, 0x24a94, 0x217d9, 0x24a66, 0x203a7, 0x21424, 0x249e5, 0x07448, 0x24916, 0x070a5, 0x24976,
+    0x09284, 0x073e6, 0x0935f, 0x204fe, 0x09331, 0x28ace, 0x28a16, 0x09386, 0x28be7, 0x255d5, 0x04935, 0x28a82,
+    0x0716b, 0x24943, 0x20cff, 0x056a4, 0x2061a, 0x20beb, 0x20cb8, 0x05502, 0x079c4, 0x217fa, 0x07dfe, 0x216c2,
+    0x24a50, 0x21852, 0x0452e, 0x09401, 0x0370a, 0x28ac0, 0x249ad, 0x059b0, 0x218bf, 0x21883, 0x27484, 0x05aa1,
+    0x036e2, 0x23d5b, 0x036b0, 0x0925f, 0x05a79, 0x28a81, 0x21862, 0x09374, 0x03ccd, 0x20ab4, 0x04a96, 0x0398a,
+    0x050f4, 0x03d69, 0x03d4c, 0x2139c, 0x07175, 0x042fb, 0x28218, 0x06e0f, 0x290e4, 0x044eb, 0x06d57, 0x27e4f,
+    0x07067, 0x06caf, 0x03cd6, 0x23fed, 0x23e2d, 0x06e02, 0x06f0c, 0x03d6f, 0x203f5, 0x07551, 0x036bc, 0x034c8,
+    0x04680, 0x03eda, 0x04871, 0x059c4, 0x0926e, 0x0493e, 0x08f41, 0x28c1c, 0x26bc0, 0x05812, 0x057c8, 0x036d6,
+    0x21452, 0x070fe, 0x24362, 0x24a71, 0x22fe3, 0x212b0, 0x223bd, 0x068b9, 0x06967, 0x21398, 0x234e5, 0x27bf4,
+    0x236df, 0x28a83, 0x237d6, 0x233fa, 0x24c9f, 0x06a1a, 0x236ad, 0x26cb7, 0x0843e, 0x044df, 0x044ce, 0x26d26,
+    0x26d51, 0x26c82, 0x26fde, 0x06f17, 0x27109, 0x0833d, 0x2173a, 0x083ed, 0x26c80, 0x27053, 0x217db, 0x05989,
+    0x05a82, 0x217b3, 0x05a61, 0x05a71, 0x21905, 0x241fc, 0x0372d, 0x059ef, 0x2173c, 0x036c7, 0x0718e, 0x09390,
+    0x0669a, 0x242a5, 0x05a6e, 0x05a2b, 0x24293, 0x06a2b, 0x23ef9, 0x27736, 0x2445b, 0x242ca, 0x0711d, 0x24259,
+    0x289e1, 0x04fb0, 0x26d28, 0x05cc2, 0x244ce, 0x27e4d, 0x243bd, 0x06a0c, 0x24256, 0x21304, 0x070a6, 0x07133,
+    0x243e9, 0x03da5, 0x06cdf, 0x2f825, 0x24a4f, 0x07e65, 0x059eb, 0x05d2f, 0x03df3, 0x05f5c, 0x24a5d, 0x217df,
+    0x07da4, 0x08426, 0x05485, 0x23afa, 0x23300, 0x20214, 0x0577e, 0x208d5, 0x20619, 0x03fe5, 0x21f9e, 0x2a2b6,
+    0x07003, 0x2915b, 0x05d70, 0x0738f, 0x07cd3, 0x28a59, 0x29420, 0x04fc8, 0x07fe7, 0x072cd, 0x07310, 0x27af4,
+    0x07338, 0x07339, 0x256f6, 0x07341, 0x07348, 0x03ea9, 0x27b18, 0x0906c, 0x071f5, 0x248f2, 0x073e1, 0x081f6,
+    0x03eca, 0x0770c, 0x03ed1, 0x06ca2, 0x056fd, 0x07419, 0x0741e, 0x0741f, 0x03ee2, 0x03ef0, 0x03ef4, 0x03efa,
+    0x074d3, 0x03f0e, 0x03f53, 0x07542, 0x0756d, 0x07572, 0x0758d, 0x03f7c, 0x075c8, 0x075dc, 0x03fc0, 0x0764d,
+    0x03fd7, 0x07674, 0x03fdc, 0x0767a, 0x24f5c, 0x07188, 0x05623, 0x08980, 0x05869, 0x0401d, 0x07743, 0x04039,
+    0x06761, 0x04045, 0x035db, 0x07798, 0x0406a, 0x0406f, 0x05c5e, 0x077be, 0x077cb, 0x058f2, 0x07818, 0x070b9,
+    0x0781c, 0x040a8, 0x07839, 0x07847, 0x07851, 0x07866, 0x08448, 0x25535, 0x07933, 0x06803, 0x07932, 0x04103,
+    0x04109, 0x07991, 0x07999, 0x08fbb, 0x07a06, 0x08fbc, 0x04167, 0x07a91, 0x041b2, 0x07abc, 0x08279, 0x041c4,
+    0x07acf, 0x07adb, 0x041cf, 0x04e21, 0x07b62, 0x07b6c, 0x07b7b, 0x07c12, 0x07c1b, 0x04260, 0x0427a, 0x07c7b,
+    0x07c9c, 0x0428c, 0x07cb8, 0x04294, 0x07ced, 0x08f93, 0x070c0, 0x20ccf, 0x07dcf, 0x07dd4, 0x07dd0, 0x07dfd,
+    0x07fae, 0x07fb4, 0x0729f, 0x04397, 0x08020, 0x08025, 0x07b39, 0x0802e, 0x08031, 0x08054, 0x03dcc, 0x057b4,
+    0x070a0, 0x080b7, 0x080e9, 0x043ed, 0x0810c, 0x0732a, 0x0810e, 0x08112, 0x07560, 0x08114, 0x04401, 0x03b39,
+    0x08156, 0x08159, 0x0815a, 0x04413, 0x0583a, 0x0817c, 0x08184, 0x04425, 0x08193, 0x0442d, 0x081a5, 0x057ef,
+    0x081c1, 0x081e4, 0x08254, 0x0448f, 0x082a6, 0x08276, 0x082ca, 0x082d8, 0x082ff, 0x044b0, 0x08357, 0x09669,
+    0x0698a, 0x08405, 0x070f5, 0x08464, 0x060e3, 0x08488, 0x04504, 0x084be, 0x084e1, 0x084f8, 0x08510, 0x08538,
+    0x08552, 0x0453b, 0x0856f, 0x08570, 0x085e0, 0x04577, 0x08672, 0x08692, 0x086b2, 0x086ef, 0x09645, 0x0878b,
+    0x04606, 0x04617, 0x088ae, 0x088ff, 0x08924, 0x08947, 0x08991, 0x27967, 0x08a29, 0x08a38, 0x08a94, 0x08ab4,
+    0x08c51, 0x08cd4, 0x08cf2, 0x08d1c, 0x04798, 0x0585f, 0x08dc3, 0x047ed, 0x04eee, 0x08e3a, 0x055d8, 0x05754,
+    0x08e71, 0x055f5, 0x08eb0, 0x04837, 0x08ece, 0x08ee2, 0x08ee4, 0x08eed, 0x08ef2, 0x08fb7, 0x08fc1, 0x08fca,
+    0x08fcc, 0x09033, 0x099c4, 0x048ad, 0x098e0, 0x09213, 0x0491e, 0x09228, 0x09258, 0x0926b, 0x092b1, 0x092ae,
+    0x092bf, 0x092e3, 0x092eb, 0x092f3, 0x092f4, 0x092fd, 0x09343, 0x09384, 0x093ad, 0x04945, 0x04951, 0x09ebf,
+    0x09417, 0x05301, 0x0941d, 0x0942d, 0x0943e, 0x0496a, 0x09454, 0x09479, 0x0952d, 0x095a2, 0x049a7, 0x095f4,
+    0x09633, 0x049e5, 0x067a0, 0x04a24, 0x09740, 0x04a35, 0x097b2, 0x097c2, 0x05654, 0x04ae4, 0x060e8, 0x098b9,
+    0x04b19, 0x098f1, 0x05844, 0x0990e, 0x09919, 0x051b4, 0x0991c, 0x09937, 0x09942, 0x0995d, 0x09962, 0x04b70,
+    0x099c5, 0x04b9d, 0x09a3c, 0x09b0f, 0x07a83, 0x09b69, 0x09b81, 0x09bdd, 0x09bf1, 0x09bf4, 0x04c6d, 0x09c20,
+    0x0376f, 0x21bc2, 0x09d49, 0x09c3a, 0x09efe, 0x05650, 0x09d93, 0x09dbd, 0x09dc0, 0x09dfc, 0x094f6, 0x08fb6,
+    0x09e7b, 0x09eac, 0x09eb1, 0x09ebd, 0x09ec6, 0x094dc, 0x09ee2, 0x09ef1, 0x09ef8, 0x07ac8, 0x09f44, 0x20094,
+    0x202b7, 0x203a0, 0x0691a, 0x094c3, 0x059ac, 0x204d7, 0x05840, 0x094c1, 0x037b9, 0x205d5, 0x20615, 0x20676,
+    0x216ba, 0x05757, 0x07173, 0x20ac2, 0x20acd, 0x20bbf, 0x0546a, 0x2f83b, 0x20bcb, 0x0549e, 0x20bfb, 0x20c3b,
+    0x20c53, 0x20c65, 0x20c7c, 0x060e7, 0x20c8d, 0x0567a, 0x20cb5, 0x20cdd, 0x20ced, 0x20d6f, 0x20db2, 0x20dc8,
+    0x06955, 0x09c2f, 0x087a5, 0x20e04, 0x20e0e, 0x20ed7, 0x20f90, 0x20f2d, 0x20e73, 0x05c20, 0x20fbc, 0x05e0b,
+    0x2105c, 0x2104f, 0x21076, 0x0671e, 0x2107b, 0x21088, 0x21096, 0x03647, 0x210bf, 0x210d3, 0x2112f, 0x2113b,
+    0x05364, 0x084ad, 0x212e3, 0x21375, 0x21336, 0x08b81, 0x21577, 0x21619, 0x217c3, 0x217c7, 0x04e78, 0x070bb,
+    0x2182d, 0x2196a, 0x21a2d, 0x21a45, 0x21c2a, 0x21c70, 0x21cac, 0x21ec8, 0x062c3, 0x21ed5, 0x21f15, 0x07198,
+    0x06855, 0x22045, 0x069e9, 0x036c8, 0x2227c, 0x223d7, 0x223fa, 0x2272a, 0x22871, 0x2294f, 0x082fd, 0x22967,
+    0x22993, 0x22ad5, 0x089a5, 0x22ae8, 0x08fa0, 0x22b0e, 0x097b8, 0x22b3f, 0x09847, 0x09abd, 0x22c4c, 0x00000,
+    0x22c88, 0x22cb7, 0x25be8, 0x22d08, 0x22d12, 0x22db7, 0x22d95, 0x22e42, 0x22f74, 0x22fcc, 0x23033, 0x23066,
+    0x2331f, 0x233de, 0x05fb1, 0x06648, 0x066bf, 0x27a79, 0x23567, 0x235f3, 0x00000, 0x249ba, 0x00000, 0x2361a,
+    0x23716, 0x00000, 0x20346, 0x058b5, 0x0670e, 0x06918, 0x23aa7, 0x27657, 0x25fe2, 0x23e11, 0x23eb9, 0x275fe,
+    0x2209a, 0x048d0, 0x04ab8, 0x24119, 0x28a9a, 0x242ee, 0x2430d, 0x2403b, 0x24334, 0x24396, 0x24a45, 0x205ca,
+    0x051d2, 0x20611, 0x0599f, 0x21ea8, 0x03bbe, 0x23cff, 0x24404, 0x244d6, 0x05788, 0x24674, 0x0399b, 0x2472f,
+    0x285e8, 0x299c9, 0x03762, 0x221c3, 0x08b5e, 0x28b4e, 0x00000, 0x24812, 0x248fb, 0x24a15, 0x07209, 0x24ac0,
+    0x20c78, 0x05965, 0x24ea5, 0x24f86, 0x20779, 0x08eda, 0x2502c, 0x0528f, 0x0573f, 0x07171, 0x25299, 0x25419,
+    0x23f4a, 0x24aa7, 0x055bc, 0x25446, 0x2546e, 0x26b52, 0x00000, 0x03473, 0x2553f, 0x27632, 0x2555e, 0x04718,
+    0x25562, 0x25566, 0x257c7, 0x2493f, 0x2585d, 0x05066, 0x034fb, 0x233cc, 0x00000, 0x25903, 0x0477c, 0x28948,
+    0x25aae, 0x25b89, 0x25c06, 0x21d90, 0x057a1, 0x07151, 0x00000, 0x26102, 0x27c12, 0x09056, 0x261b2, 0x24f9a,
+    0x08b62, 0x26402, 0x2644a, 0x05d5b, 0x26bf7, 0x00000, 0x26484, 0x2191c, 0x08aea, 0x249f6, 0x26488, 0x23fef,
+    0x26512, 0x04bc0, 0x265bf, 0x266b5, 0x2271b, 0x09465, 0x257e1, 0x06195, 0x05a27, 0x2f8cd, 0x00000, 0x056b9,
+    0x24521, 0x266fc, 0x04e6a, 0x24934, 0x09656, 0x06d8f, 0x26cbd, 0x03618, 0x08977, 0x26799, 0x2686e, 0x26411,
+    0x2685e, 0x00000, 0x268c7, 0x07b42, 0x290c0, 0x20a11, 0x26926, 0x00000, 0x26939, 0x07a45, 0x00000, 0x269fa,
+    0x09a26, 0x26a2d, 0x0365f, 0x26469, 0x20021, 0x07983, 0x26a34, 0x26b5b, 0x05d2c, 0x23519, 0x00000, 0x26b9d,
+    0x046d0, 0x26ca4, 0x0753b, 0x08865, 0x26dae, 0x058b6, 0x0371c, 0x2258d, 0x2704b, 0x271cd, 0x03c54, 0x27280,
+    0x27285, 0x09281, 0x2217a, 0x2728b, 0x09330, 0x272e6, 0x249d0, 0x06c39, 0x0949f, 0x27450, 0x20ef8, 0x08827,
+    0x088f5, 0x22926, 0x28473, 0x217b1, 0x06eb8, 0x24a2a, 0x21820, 0x039a4, 0x036b9, 0x00000, 0x00000, 0x0453f,
+    0x066b6, 0x29cad, 0x298a4, 0x08943, 0x277cc, 0x27858, 0x056d6, 0x040df, 0x2160a, 0x039a1, 0x2372f, 0x280e8,
+    0x213c5, 0x071ad, 0x08366, 0x279dd, 0x291a8, 0x00000, 0x04cb7, 0x270af, 0x289ab, 0x279fd, 0x27a0a, 0x27b0b,
+    0x27d66, 0x2417a, 0x07b43, 0x0797e, 0x28009, 0x06fb5, 0x2a2df, 0x06a03, 0x28318, 0x053a2, 0x26e07, 0x093bf,
+    0x06836, 0x0975d, 0x2816f, 0x28023, 0x269b5, 0x213ed, 0x2322f, 0x28048, 0x05d85, 0x28c30, 0x28083, 0x05715,
+    0x09823, 0x28949, 0x05dab, 0x24988, 0x065be, 0x069d5, 0x053d2, 0x24aa5, 0x23f81, 0x03c11, 0x06736, 0x28090,
+    0x280f4, 0x2812e, 0x21fa1, 0x2814f, 0x28189, 0x281af, 0x2821a, 0x28306, 0x2832f, 0x2838a, 0x035ca, 0x28468,
+    0x286aa, 0x048fa, 0x063e6, 0x28956, 0x07808, 0x09255, 0x289b8, 0x043f2, 0x289e7, 0x043df, 0x289e8, 0x28b46,
+    0x28bd4, 0x059f8, 0x28c09, 0x00000, 0x28fc5, 0x290ec, 0x00000, 0x29110, 0x2913c, 0x03df7, 0x2915e, 0x24aca,
+    0x08fd0, 0x0728f, 0x0568b, 0x294e7, 0x295e9, 0x295b0, 0x295b8, 0x29732, 0x298d1, 0x29949, 0x2996a, 0x299c3,
+    0x29a28, 0x29b0e, 0x29d5a, 0x29d9b, 0x07e9f, 0x29ef8, 0x29f23, 0x04ca4, 0x09547, 0x2a293, 0x071a2, 0x2a2ff,
+    0x04d91, 0x09012, 0x2a5cb, 0x04d9c, 0x20c9c, 0x08fbe, 0x055c1, 0x08fba, 0x224b0, 0x08fb9, 0x24a93, 0x04509,
+    0x07e7f, 0x06f56, 0x06ab1, 0x04eea, 0x034e4, 0x28b2c, 0x2789d, 0x0373a, 0x08e80, 0x217f5, 0x28024, 0x28b6c,
+    0x28b99, 0x27a3e, 0x266af, 0x03deb, 0x27655, 0x23cb7, 0x25635, 0x25956, 0x04e9a, 0x25e81, 0x26258, 0x056bf,
+    0x20e6d, 0x08e0e, 0x05b6d, 0x23e88, 0x24c9e, 0x063de, 0x00000, 0x217f6, 0x2187b, 0x06530, 0x0562d, 0x25c4a,
+    0x0541a, 0x25311, 0x03dc6, 0x29d98, 0x04c7d, 0x05622, 0x0561e, 0x07f49, 0x25ed8, 0x05975, 0x23d40, 0x08770,
+    0x04e1c, 0x20fea, 0x20d49, 0x236ba, 0x08117, 0x09d5e, 0x08d18, 0x0763b, 0x09c45, 0x0764e, 0x077b9, 0x09345,
+    0x05432, 0x08148, 0x082f7, 0x05625, 0x08132, 0x08418, 0x080bd, 0x055ea, 0x07962, 0x05643, 0x05416, 0x20e9d,
+    0x035ce, 0x05605, 0x055f1, 0x066f1, 0x282e2, 0x0362d, 0x07534, 0x055f0, 0x055ba, 0x05497, 0x05572, 0x20c41,
+    0x20c96, 0x05ed0, 0x25148, 0x20e76, 0x22c62, 0x20ea2, 0x09eab, 0x07d5a, 0x055de, 0x21075, 0x0629d, 0x0976d,
+    0x05494, 0x08ccd, 0x071f6, 0x09176, 0x063fc, 0x063b9, 0x063fe, 0x05569, 0x22b43, 0x09c72, 0x22eb3, 0x0519a,
+    0x034df, 0x20da7, 0x051a7, 0x0544d, 0x0551e, 0x05513, 0x07666, 0x08e2d, 0x2688a, 0x075b1, 0x080b6, 0x08804,
+    0x08786, 0x088c7, 0x081b6, 0x0841c, 0x210c1, 0x044ec, 0x07304, 0x24706, 0x05b90, 0x0830b, 0x26893, 0x0567b,
+    0x226f4, 0x27d2f, 0x241a3, 0x27d73, 0x26ed0, 0x272b6, 0x09170, 0x211d9, 0x09208, 0x23cfc, 0x2a6a9, 0x20eac,
+    0x20ef9, 0x07266, 0x21ca2, 0x0474e, 0x24fc2, 0x27ff9, 0x20feb, 0x040fa, 0x09c5d, 0x0651f, 0x22da0, 0x048f3,
+    0x247e0, 0x29d7c, 0x20fec, 0x20e0a, 0x00000, 0x275a3, 0x20fed, 0x00000, 0x26048, 0x21187, 0x071a3, 0x07e8e,
+    0x09d50, 0x04e1a, 0x04e04, 0x03577, 0x05b0d, 0x06cb2, 0x05367, 0x036ac, 0x039dc, 0x0537d, 0x036a5, 0x24618,
+    0x0589a, 0x24b6e, 0x0822d, 0x0544b, 0x057aa, 0x25a95, 0x20979, 0x00000, 0x03a52, 0x22465, 0x07374, 0x29eac,
+    0x04d09, 0x09bed, 0x23cfe, 0x29f30, 0x04c5b, 0x24fa9, 0x2959e, 0x29fde, 0x0845c, 0x23db6, 0x272b2, 0x267b3,
+    0x23720, 0x0632e, 0x07d25, 0x23ef7, 0x23e2c, 0x03a2a, 0x09008, 0x052cc, 0x03e74, 0x0367a, 0x045e9, 0x2048e,
+    0x07640, 0x05af0, 0x20eb6, 0x0787a, 0x27f2e, 0x058a7, 0x040bf, 0x0567c, 0x09b8b, 0x05d74, 0x07654, 0x2a434,
+    0x09e85, 0x04ce1, 0x00000, 0x037fb, 0x06119, 0x230da, 0x243f2, 0x00000, 0x0565d, 0x212a9, 0x057a7, 0x24963,
+    0x29e06, 0x05234, 0x270ae, 0x035ad, 0x00000, 0x09d7c, 0x07c56, 0x09b39, 0x057de, 0x2176c, 0x05c53, 0x064d3,
+    0x294d0, 0x26335, 0x27164, 0x086ad, 0x20d28, 0x26d22, 0x24ae2, 0x20d71, 0x00000, 0x051fe, 0x21f0f, 0x05d8e,
+    0x09703, 0x21dd1, 0x09e81, 0x0904c, 0x07b1f, 0x09b02, 0x05cd1, 0x07ba3, 0x06268, 0x06335, 0x09aff, 0x07bcf,
+    0x09b2a, 0x07c7e, 0x00000, 0x07c42, 0x07c86, 0x09c15, 0x07bfc, 0x09b09, 0x00000, 0x09c1b, 0x2493e, 0x09f5a,
+    0x05573, 0x05bc3, 0x04ffd, 0x09e98, 0x04ff2, 0x05260, 0x03e06, 0x052d1, 0x05767, 0x05056, 0x059b7, 0x05e12,
+    0x097c8, 0x09dab, 0x08f5c, 0x05469, 0x097b4, 0x09940, 0x097ba, 0x0532c, 0x06130, 0x0692c, 0x053da, 0x09c0a,
+    0x09d02, 0x04c3b, 0x09641, 0x06980, 0x050a6, 0x07546, 0x2176d, 0x099da, 0x05273, 0x00000, 0x09159, 0x09681,
+    0x0915c, 0x00000, 0x09151, 0x28e97, 0x0637f, 0x26d23, 0x06aca, 0x05611, 0x0918e, 0x0757a, 0x06285, 0x203fc,
+    0x0734f, 0x07c70, 0x25c21, 0x23cfd, 0x00000, 0x24919, 0x076d6, 0x09b9d, 0x04e2a, 0x20cd4, 0x083be, 0x08842,
+    0x00000, 0x05c4a, 0x069c0, 0x00000, 0x0577a, 0x0521f, 0x05df5, 0x04ece, 0x06c31, 0x201f2, 0x04f39, 0x0549c,
+    0x054da, 0x0529a, 0x08d82, 0x035fe, 0x00000, 0x035f3, 0x00000, 0x06b52, 0x0917c, 0x09fa5, 0x09b97, 0x0982e,
+    0x098b4, 0x09aba, 0x09ea8, 0x09e84, 0x0717a, 0x07b14, 0x00000, 0x06bfa, 0x08818, 0x07f78, 0x00000, 0x05620,
+    0x2a64a, 0x08e77, 0x09f53, 0x00000, 0x08dd4, 0x08e4f, 0x09e1c, 0x08e01, 0x06282, 0x2837d, 0x08e28, 0x08e75,
+    0x07ad3, 0x24a77, 0x07a3e, 0x078d8, 0x06cea, 0x08a67, 0x07607, 0x28a5a, 0x09f26, 0x06cce, 0x087d6, 0x075c3,
+    0x2a2b2, 0x07853, 0x2f840, 0x08d0c, 0x072e2, 0x07371, 0x08b2d, 0x07302, 0x074f1, 0x08ceb, 0x24abb, 0x0862f,
+    0x05fba, 0x088a0, 0x044b7, 0x00000, 0x2183b, 0x26e05, 0x00000, 0x08a7e, 0x2251b, 0x00000, 0x060fd, 0x07667,
+    0x09ad7, 0x09d44, 0x0936e, 0x09b8f, 0x087f5, 0x00000, 0x00000, 0x08cf7, 0x0732c, 0x09721, 0x09bb0, 0x035d6,
+    0x072b2, 0x04c07, 0x07c51, 0x0994a, 0x26159, 0x06159, 0x04c04, 0x09e96, 0x0617d, 0x00000, 0x0575f, 0x0616f,
+    0x062a6, 0x06239, 0x00000, 0x03a5c, 0x061e2, 0x053aa, 0x233f5, 0x06364, 0x06802, 0x035d2, 0x05d57, 0x28bc2,
+    0x08fda, 0x28e39, 0x00000, 0x050d9, 0x21d46, 0x07906, 0x05332, 0x09638, 0x20f3b, 0x04065, 0x00000, 0x077fe,
+    0x00000, 0x07cc2, 0x25f1a, 0x07cda, 0x07a2d, 0x08066, 0x08063, 0x07d4d, 0x07505, 0x074f2, 0x08994, 0x0821a,
+    0x0670c, 0x08062, 0x27486, 0x0805b, 0x074f0, 0x08103, 0x07724, 0x08989, 0x267cc, 0x07553, 0x26ed1, 0x087a9,
+    0x087ce, 0x081c8, 0x0878c, 0x08a49, 0x08cad, 0x08b43, 0x0772b, 0x074f8, 0x084da, 0x03635, 0x069b2, 0x08da6,
+    0x00000, 0x089a9, 0x00000, 0x06db9, 0x087c1, 0x24011, 0x074e7, 0x03ddb, 0x07176, 0x060a4, 0x0619c, 0x03cd1,
+    0x00000, 0x06077, 0x00000, 0x07f71, 0x28b2d, 0x00000, 0x060e9, 0x04b7e, 0x05220, 0x03c18, 0x23cc7, 0x25ed7,
+    0x27656, 0x25531, 0x21944, 0x212fe, 0x29903, 0x26ddc, 0x270ad, 0x05cc1, 0x261ad, 0x28a0f, 0x23677, 0x200ee,
+    0x26846, 0x24f0e, 0x04562, 0x05b1f, 0x2634c, 0x09f50, 0x09ea6, 0x2626b, 0x03000, 0x0ff0c, 0x03001, 0x03002,
+    0x0ff0e, 0x02022, 0x0ff1b, 0x0ff1a, 0x0ff1f, 0x0ff01, 0x0fe30, 0x02026, 0x02025, 0x0fe50, 0x0ff64, 0x0fe52,
+    0x000b7, 0x0fe54, 0x0fe55, 0x0fe56, 0x0fe57, 0x0ff5c, 0x02013, 0x0fe31, 0x02014, 0x0fe33, 0x02574, 0x0fe34,
+    0x0fe4f, 0x0ff08, 0x0ff09, 0x0fe35, 0x0fe36, 0x0ff5b, 0x0ff5d, 0x0fe37, 0x0fe38, 0x03014, 0x03015, 0x0fe39,
+    0x0fe3a, 0x03010, 0x03011, 0x0fe3b, 0x0fe3c, 0x0300a, 0x0300b, 0x0fe3d, 0x0fe3e, 0x03008, 0x03009, 0x0fe3f,
+    0x0fe40, 0x0300c, 0x0300d, 0x0fe41, 0x0fe42, 0x0300e, 0x0300f, 0x0fe43, 0x0fe44, 0x0fe59, 0x0fe5a, 0x0fe5b,
+    0x0fe5c, 0x0fe5d, 0x0fe5e, 0x02018, 0x02019, 0x0201c, 0x0201d, 0x0301d, 0x0301e, 0x02035, 0x02032, 0x0ff03,
+    0x0ff06, 0x0ff0a, 0x0203b, 0x000a7, 0x03003, 0x025cb, 0x025cf, 0x025b3, 0x025b2, 0x025ce, 0x02606, 0x02605,
+    0x025c7, 0x025c6, 0x025a1, 0x025a0, 0x025bd, 0x025bc, 0x032a3, 0x02105, 0x0203e, 0x0ffe3, 0x0ff3f, 0x002cd,
+    0x0fe49, 0x0fe4a, 0x0fe4d, 0x0fe4e, 0x0fe4b, 0x0fe4c, 0x0fe5f, 0x0fe60, 0x0fe61, 0x0ff0b, 0x0ff0d, 0x000d7,
+    0x000f7, 0x000b1, 0x0221a, 0x0ff1c, 0x0ff1e, 0x0ff1d, 0x02266, 0x02267, 0x02260, 0x0221e, 0x02252, 0x02261,
+    0x0fe62, 0x0fe63, 0x0fe64, 0x0fe65, 0x0fe66, 0x0223c, 0x02229, 0x0222a, 0x022a5, 0x02220, 0x0221f, 0x022bf,
+    0x033d2, 0x033d1, 0x0222b, 0x0222e, 0x02235, 0x02234, 0x02640, 0x02642, 0x02641, 0x02609, 0x02191, 0x02193,
+    0x02190, 0x02192, 0x02196, 0x02197, 0x02199, 0x02198, 0x02225, 0x02223, 0x0ff0f, 0x0ff3c, 0x0ff0f, 0x0ff3c,
+    0x0ff04, 0x000a5, 0x03012, 0x000a2, 0x000a3, 0x0ff05, 0x0ff20, 0x02103, 0x02109, 0x0fe69, 0x0fe6a, 0x0fe6b,
+    0x033d5, 0x0339c, 0x0339d, 0x0339e, 0x033ce, 0x033a1, 0x0338e, 0x0338f, 0x033c4, 0x000b0, 0x05159, 0x0515b,
+    0x0515e, 0x0515d, 0x05161, 0x05163, 0x055e7, 0x074e9, 0x07cce, 0x02581, 0x02582, 0x02583, 0x02584, 0x02585,
+    0x02586, 0x02587, 0x02588, 0x0258f, 0x0258e, 0x0258d, 0x0258c, 0x0258b, 0x0258a, 0x02589, 0x0253c, 0x02534,
+    0x0252c, 0x02524, 0x0251c, 0x02594, 0x02500, 0x02502, 0x02595, 0x0250c, 0x02510, 0x02514, 0x02518, 0x0256d,
+    0x0256e, 0x02570, 0x0256f, 0x02550, 0x0255e, 0x0256a, 0x02561, 0x025e2, 0x025e3, 0x025e5, 0x025e4, 0x02571,
+    0x02572, 0x02573, 0x0ff10, 0x0ff11, 0x0ff12, 0x0ff13, 0x0ff14, 0x0ff15, 0x0ff16, 0x0ff17, 0x0ff18, 0x0ff19,
+    0x02160, 0x02161, 0x02162, 0x02163, 0x02164, 0x02165, 0x02166, 0x02167, 0x02168, 0x02169, 0x03021, 0x03022,
+    0x03023, 0x03024, 0x03025, 0x03026, 0x03027, 0x03028, 0x03029, 0x05341, 0x05344, 0x05345, 0x0ff21, 0x0ff22,
+    0x0ff23, 0x0ff24, 0x0ff25, 0x0ff26, 0x0ff27, 0x0ff28, 0x0ff29, 0x0ff2a, 0x0ff2b, 0x0ff2c, 0x0ff2d, 0x0ff2e,
+    0x0ff2f, 0x0ff30, 0x0ff31, 0x0ff32, 0x0ff33, 0x0ff34, 0x0ff35, 0x0ff36, 0x0ff37, 0x0ff38, 0x0ff39, 0x0ff3a,
+    0x0ff41, 0x0ff42, 0x0ff43, 0x0ff44, 0x0ff45, 0x0ff46, 0x0ff47, 0x0ff48, 0x0ff49, 0x0ff4a, 0x0ff4b, 0x0ff4c,
+    0x0ff4d, 0x0ff4e, 0x0ff4f, 0x0ff50, 0x0ff51, 0x0ff52, 0x0ff53, 0x0ff54, 0x0ff55, 0x0ff56, 0x0ff57, 0x0ff58,
+    0x0ff59, 0x0ff5a, 0x00391, 0x00392, 0x00393, 0x00394, 0x00395, 0x00396, 0x00397, 0x00398, 0x00399, 0x0039a,
+    0x0039b, 0x0039c, 0x0039d, 0x0039e, 0x0039f, 0x003a0, 0x003a1, 0x003a3, 0x003a4, 0x003a5, 0x003a6, 0x003a7,
+    0x003a8, 0x003a9, 0x003b1, 0x003b2, 0x003b3, 0x003b4, 0x003b5, 0x003b6, 0x003b7, 0x003b8, 0x003b9, 0x003ba,
+    0x003bb, 0x003bc, 0x003bd, 0x003be, 0x003bf, 0x003c0, 0x003c1, 0x003c3, 0x003c4, 0x003c5, 0x003c6, 0x003c7,
+    0x003c8, 0x003c9, 0x03105, 0x03106, 0x03107, 0x03108, 0x03109, 0x0310a, 0x0310b, 0x0310c, 0x0310d, 0x0310e,
+    0x0310f, 0x03110, 0x03111, 0x03112, 0x03113, 0x03114, 0x03115, 0x03116, 0x03117, 0x03118, 0x03119, 0x0311a,
+    0x0311b, 0x0311c, 0x0311d, 0x0311e, 0x0311f, 0x03120, 0x03121, 0x03122, 0x03123, 0x03124, 0x03125, 0x03126,
+    0x03127, 0x03128, 0x03129, 0x002d9, 0x002c9, 0x002ca, 0x002c7, 0x002cb, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x04e00,
+    0x04e59, 0x04e01, 0x04e03, 0x04e43, 0x04e5d, 0x04e86, 0x04e8c, 0x04eba, 0x0513f, 0x05165, 0x0516b, 0x051e0,
+    0x05200, 0x05201, 0x0529b, 0x05315, 0x05341, 0x0535c, 0x053c8, 0x04e09, 0x04e0b, 0x04e08, 0x04e0a, 0x04e2b,
+    0x04e38, 0x051e1, 0x04e45, 0x04e48, 0x04e5f, 0x04e5e, 0x04e8e, 0x04ea1, 0x05140, 0x05203, 0x052fa, 0x05343,
+    0x053c9, 0x053e3, 0x0571f, 0x058eb, 0x05915, 0x05927, 0x05973, 0x05b50, 0x05b51, 0x05b53, 0x05bf8, 0x05c0f,
+    0x05c22, 0x05c38, 0x05c71, 0x05ddd, 0x05de5, 0x05df1, 0x05df2, 0x05df3, 0x05dfe, 0x05e72, 0x05efe, 0x05f0b,
+    0x05f13, 0x0624d, 0x04e11, 0x04e10, 0x04e0d, 0x04e2d, 0x04e30, 0x04e39, 0x04e4b, 0x05c39, 0x04e88, 0x04e91,
+    0x04e95, 0x04e92, 0x04e94, 0x04ea2, 0x04ec1, 0x04ec0, 0x04ec3, 0x04ec6, 0x04ec7, 0x04ecd, 0x04eca, 0x04ecb,
+    0x04ec4, 0x05143, 0x05141, 0x05167, 0x0516d, 0x0516e, 0x0516c, 0x05197, 0x051f6, 0x05206, 0x05207, 0x05208,
+    0x052fb, 0x052fe, 0x052ff, 0x05316, 0x05339, 0x05348, 0x05347, 0x05345, 0x0535e, 0x05384, 0x053cb, 0x053ca,
+    0x053cd, 0x058ec, 0x05929, 0x0592b, 0x0592a, 0x0592d, 0x05b54, 0x05c11, 0x05c24, 0x05c3a, 0x05c6f, 0x05df4,
+    0x05e7b, 0x05eff, 0x05f14, 0x05f15, 0x05fc3, 0x06208, 0x06236, 0x0624b, 0x0624e, 0x0652f, 0x06587, 0x06597,
+    0x065a4, 0x065b9, 0x065e5, 0x066f0, 0x06708, 0x06728, 0x06b20, 0x06b62, 0x06b79, 0x06bcb, 0x06bd4, 0x06bdb,
+    0x06c0f, 0x06c34, 0x0706b, 0x0722a, 0x07236, 0x0723b, 0x07247, 0x07259, 0x0725b, 0x072ac, 0x0738b, 0x04e19,
+    0x04e16, 0x04e15, 0x04e14, 0x04e18, 0x04e3b, 0x04e4d, 0x04e4f, 0x04e4e, 0x04ee5, 0x04ed8, 0x04ed4, 0x04ed5,
+    0x04ed6, 0x04ed7, 0x04ee3, 0x04ee4, 0x04ed9, 0x04ede, 0x05145, 0x05144, 0x05189, 0x0518a, 0x051ac, 0x051f9,
+    0x051fa, 0x051f8, 0x0520a, 0x052a0, 0x0529f, 0x05305, 0x05306, 0x05317, 0x0531d, 0x04edf, 0x0534a, 0x05349,
+    0x05361, 0x05360, 0x0536f, 0x0536e, 0x053bb, 0x053ef, 0x053e4, 0x053f3, 0x053ec, 0x053ee, 0x053e9, 0x053e8,
+    0x053fc, 0x053f8, 0x053f5, 0x053eb, 0x053e6, 0x053ea, 0x053f2, 0x053f1, 0x053f0, 0x053e5, 0x053ed, 0x053fb,
+    0x056db, 0x056da, 0x05916, 0x0592e, 0x05931, 0x05974, 0x05976, 0x05b55, 0x05b83, 0x05c3c, 0x05de8, 0x05de7,
+    0x05de6, 0x05e02, 0x05e03, 0x05e73, 0x05e7c, 0x05f01, 0x05f18, 0x05f17, 0x05fc5, 0x0620a, 0x06253, 0x06254,
+    0x06252, 0x06251, 0x065a5, 0x065e6, 0x0672e, 0x0672c, 0x0672a, 0x0672b, 0x0672d, 0x06b63, 0x06bcd, 0x06c11,
+    0x06c10, 0x06c38, 0x06c41, 0x06c40, 0x06c3e, 0x072af, 0x07384, 0x07389, 0x074dc, 0x074e6, 0x07518, 0x0751f,
+    0x07528, 0x07529, 0x07530, 0x07531, 0x07532, 0x07533, 0x0758b, 0x0767d, 0x076ae, 0x076bf, 0x076ee, 0x077db,
+    0x077e2, 0x077f3, 0x0793a, 0x079be, 0x07a74, 0x07acb, 0x04e1e, 0x04e1f, 0x04e52, 0x04e53, 0x04e69, 0x04e99,
+    0x04ea4, 0x04ea6, 0x04ea5, 0x04eff, 0x04f09, 0x04f19, 0x04f0a, 0x04f15, 0x04f0d, 0x04f10, 0x04f11, 0x04f0f,
+    0x04ef2, 0x04ef6, 0x04efb, 0x04ef0, 0x04ef3, 0x04efd, 0x04f01, 0x04f0b, 0x05149, 0x05147, 0x05146, 0x05148,
+    0x05168, 0x05171, 0x0518d, 0x051b0, 0x05217, 0x05211, 0x05212, 0x0520e, 0x05216, 0x052a3, 0x05308, 0x05321,
+    0x05320, 0x05370, 0x05371, 0x05409, 0x0540f, 0x0540c, 0x0540a, 0x05410, 0x05401, 0x0540b, 0x05404, 0x05411,
+    0x0540d, 0x05408, 0x05403, 0x0540e, 0x05406, 0x05412, 0x056e0, 0x056de, 0x056dd, 0x05733, 0x05730, 0x05728,
+    0x0572d, 0x0572c, 0x0572f, 0x05729, 0x05919, 0x0591a, 0x05937, 0x05938, 0x05984, 0x05978, 0x05983, 0x0597d,
+    0x05979, 0x05982, 0x05981, 0x05b57, 0x05b58, 0x05b87, 0x05b88, 0x05b85, 0x05b89, 0x05bfa, 0x05c16, 0x05c79,
+    0x05dde, 0x05e06, 0x05e76, 0x05e74, 0x05f0f, 0x05f1b, 0x05fd9, 0x05fd6, 0x0620e, 0x0620c, 0x0620d, 0x06210,
+    0x06263, 0x0625b, 0x06258, 0x06536, 0x065e9, 0x065e8, 0x065ec, 0x065ed, 0x066f2, 0x066f3, 0x06709, 0x0673d,
+    0x06734, 0x06731, 0x06735, 0x06b21, 0x06b64, 0x06b7b, 0x06c16, 0x06c5d, 0x06c57, 0x06c59, 0x06c5f, 0x06c60,
+    0x06c50, 0x06c55, 0x06c61, 0x06c5b, 0x06c4d, 0x06c4e, 0x07070, 0x0725f, 0x0725d, 0x0767e, 0x07af9, 0x07c73,
+    0x07cf8, 0x07f36, 0x07f8a, 0x07fbd, 0x08001, 0x08003, 0x0800c, 0x08012, 0x08033, 0x0807f, 0x08089, 0x0808b,
+    0x0808c, 0x081e3, 0x081ea, 0x081f3, 0x081fc, 0x0820c, 0x0821b, 0x0821f, 0x0826e, 0x08272, 0x0827e, 0x0866b,
+    0x08840, 0x0884c, 0x08863, 0x0897f, 0x09621, 0x04e32, 0x04ea8, 0x04f4d, 0x04f4f, 0x04f47, 0x04f57, 0x04f5e,
+    0x04f34, 0x04f5b, 0x04f55, 0x04f30, 0x04f50, 0x04f51, 0x04f3d, 0x04f3a, 0x04f38, 0x04f43, 0x04f54, 0x04f3c,
+    0x04f46, 0x04f63, 0x04f5c, 0x04f60, 0x04f2f, 0x04f4e, 0x04f36, 0x04f59, 0x04f5d, 0x04f48, 0x04f5a, 0x0514c,
+    0x0514b, 0x0514d, 0x05175, 0x051b6, 0x051b7, 0x05225, 0x05224, 0x05229, 0x0522a, 0x05228, 0x052ab, 0x052a9,
+    0x052aa, 0x052ac, 0x05323, 0x05373, 0x05375, 0x0541d, 0x0542d, 0x0541e, 0x0543e, 0x05426, 0x0544e, 0x05427,
+    0x05446, 0x05443, 0x05433, 0x05448, 0x05442, 0x0541b, 0x05429, 0x0544a, 0x05439, 0x0543b, 0x05438, 0x0542e,
+    0x05435, 0x05436, 0x05420, 0x0543c, 0x05440, 0x05431, 0x0542b, 0x0541f, 0x0542c, 0x056ea, 0x056f0, 0x056e4,
+    0x056eb, 0x0574a, 0x05751, 0x05740, 0x0574d, 0x05747, 0x0574e, 0x0573e, 0x05750, 0x0574f, 0x0573b, 0x058ef,
+    0x0593e, 0x0599d, 0x05992, 0x059a8, 0x0599e, 0x059a3, 0x05999, 0x05996, 0x0598d, 0x059a4, 0x05993, 0x0598a,
+    0x059a5, 0x05b5d, 0x05b5c, 0x05b5a, 0x05b5b, 0x05b8c, 0x05b8b, 0x05b8f, 0x05c2c, 0x05c40, 0x05c41, 0x05c3f,
+    0x05c3e, 0x05c90, 0x05c91, 0x05c94, 0x05c8c, 0x05deb, 0x05e0c, 0x05e8f, 0x05e87, 0x05e8a, 0x05ef7, 0x05f04,
+    0x05f1f, 0x05f64, 0x05f62, 0x05f77, 0x05f79, 0x05fd8, 0x05fcc, 0x05fd7, 0x05fcd, 0x05ff1, 0x05feb, 0x05ff8,
+    0x05fea, 0x06212, 0x06211, 0x06284, 0x06297, 0x06296, 0x06280, 0x06276, 0x06289, 0x0626d, 0x0628a, 0x0627c,
+    0x0627e, 0x06279, 0x06273, 0x06292, 0x0626f, 0x06298, 0x0626e, 0x06295, 0x06293, 0x06291, 0x06286, 0x06539,
+    0x0653b, 0x06538, 0x065f1, 0x066f4, 0x0675f, 0x0674e, 0x0674f, 0x06750, 0x06751, 0x0675c, 0x06756, 0x0675e,
+    0x06749, 0x06746, 0x06760, 0x06753, 0x06757, 0x06b65, 0x06bcf, 0x06c42, 0x06c5e, 0x06c99, 0x06c81, 0x06c88,
+    0x06c89, 0x06c85, 0x06c9b, 0x06c6a, 0x06c7a, 0x06c90, 0x06c70, 0x06c8c, 0x06c68, 0x06c96, 0x06c92, 0x06c7d,
+    0x06c83, 0x06c72, 0x06c7e, 0x06c74, 0x06c86, 0x06c76, 0x06c8d, 0x06c94, 0x06c98, 0x06c82, 0x07076, 0x0707c,
+    0x0707d, 0x07078, 0x07262, 0x07261, 0x07260, 0x072c4, 0x072c2, 0x07396, 0x0752c, 0x0752b, 0x07537, 0x07538,
+    0x07682, 0x076ef, 0x077e3, 0x079c1, 0x079c0, 0x079bf, 0x07a76, 0x07cfb, 0x07f55, 0x08096, 0x08093, 0x0809d,
+    0x08098, 0x0809b, 0x0809a, 0x080b2, 0x0826f, 0x08292, 0x0828b, 0x0828d, 0x0898b, 0x089d2, 0x08a00, 0x08c37,
+    0x08c46, 0x08c55, 0x08c9d, 0x08d64, 0x08d70, 0x08db3, 0x08eab, 0x08eca, 0x08f9b, 0x08fb0, 0x08fc2, 0x08fc6,
+    0x08fc5, 0x08fc4, 0x05de1, 0x09091, 0x090a2, 0x090aa, 0x090a6, 0x090a3, 0x09149, 0x091c6, 0x091cc, 0x09632,
+    0x0962e, 0x09631, 0x0962a, 0x0962c, 0x04e26, 0x04e56, 0x04e73, 0x04e8b, 0x04e9b, 0x04e9e, 0x04eab, 0x04eac,
+    0x04f6f, 0x04f9d, 0x04f8d, 0x04f73, 0x04f7f, 0x04f6c, 0x04f9b, 0x04f8b, 0x04f86, 0x04f83, 0x04f70, 0x04f75,
+    0x04f88, 0x04f69, 0x04f7b, 0x04f96, 0x04f7e, 0x04f8f, 0x04f91, 0x04f7a, 0x05154, 0x05152, 0x05155, 0x05169,
+    0x05177, 0x05176, 0x05178, 0x051bd, 0x051fd, 0x0523b, 0x05238, 0x05237, 0x0523a, 0x05230, 0x0522e, 0x05236,
+    0x05241, 0x052be, 0x052bb, 0x05352, 0x05354, 0x05353, 0x05351, 0x05366, 0x05377, 0x05378, 0x05379, 0x053d6,
+    0x053d4, 0x053d7, 0x05473, 0x05475, 0x05496, 0x05478, 0x05495, 0x05480, 0x0547b, 0x05477, 0x05484, 0x05492,
+    0x05486, 0x0547c, 0x05490, 0x05471, 0x05476, 0x0548c, 0x0549a, 0x05462, 0x05468, 0x0548b, 0x0547d, 0x0548e,
+    0x056fa, 0x05783, 0x05777, 0x0576a, 0x05769, 0x05761, 0x05766, 0x05764, 0x0577c, 0x0591c, 0x05949, 0x05947,
+    0x05948, 0x05944, 0x05954, 0x059be, 0x059bb, 0x059d4, 0x059b9, 0x059ae, 0x059d1, 0x059c6, 0x059d0, 0x059cd,
+    0x059cb, 0x059d3, 0x059ca, 0x059af, 0x059b3, 0x059d2, 0x059c5, 0x05b5f, 0x05b64, 0x05b63, 0x05b97, 0x05b9a,
+    0x05b98, 0x05b9c, 0x05b99, 0x05b9b, 0x05c1a, 0x05c48, 0x05c45, 0x05c46, 0x05cb7, 0x05ca1, 0x05cb8, 0x05ca9,
+    0x05cab, 0x05cb1, 0x05cb3, 0x05e18, 0x05e1a, 0x05e16, 0x05e15, 0x05e1b, 0x05e11, 0x05e78, 0x05e9a, 0x05e97,
+    0x05e9c, 0x05e95, 0x05e96, 0x05ef6, 0x05f26, 0x05f27, 0x05f29, 0x05f80, 0x05f81, 0x05f7f, 0x05f7c, 0x05fdd,
+    0x05fe0, 0x05ffd, 0x05ff5, 0x05fff, 0x0600f, 0x06014, 0x0602f, 0x06035, 0x06016, 0x0602a, 0x06015, 0x06021,
+    0x06027, 0x06029, 0x0602b, 0x0601b, 0x06216, 0x06215, 0x0623f, 0x0623e, 0x06240, 0x0627f, 0x062c9, 0x062cc,
+    0x062c4, 0x062bf, 0x062c2, 0x062b9, 0x062d2, 0x062db, 0x062ab, 0x062d3, 0x062d4, 0x062cb, 0x062c8, 0x062a8,
+    0x062bd, 0x062bc, 0x062d0, 0x062d9, 0x062c7, 0x062cd, 0x062b5, 0x062da, 0x062b1, 0x062d8, 0x062d6, 0x062d7,
+    0x062c6, 0x062ac, 0x062ce, 0x0653e, 0x065a7, 0x065bc, 0x065fa, 0x06614, 0x06613, 0x0660c, 0x06606, 0x06602,
+    0x0660e, 0x06600, 0x0660f, 0x06615, 0x0660a, 0x06607, 0x0670d, 0x0670b, 0x0676d, 0x0678b, 0x06795, 0x06771,
+    0x0679c, 0x06773, 0x06777, 0x06787, 0x0679d, 0x06797, 0x0676f, 0x06770, 0x0677f, 0x06789, 0x0677e, 0x06790,
+    0x06775, 0x0679a, 0x06793, 0x0677c, 0x0676a, 0x06772, 0x06b23, 0x06b66, 0x06b67, 0x06b7f, 0x06c13, 0x06c1b,
+    0x06ce3, 0x06ce8, 0x06cf3, 0x06cb1, 0x06ccc, 0x06ce5, 0x06cb3, 0x06cbd, 0x06cbe, 0x06cbc, 0x06ce2, 0x06cab,
+    0x06cd5, 0x06cd3, 0x06cb8, 0x06cc4, 0x06cb9, 0x06cc1, 0x06cae, 0x06cd7, 0x06cc5, 0x06cf1, 0x06cbf, 0x06cbb,
+    0x06ce1, 0x06cdb, 0x06cca, 0x06cac, 0x06cef, 0x06cdc, 0x06cd6, 0x06ce0, 0x07095, 0x0708e, 0x07092, 0x0708a,
+    0x07099, 0x0722c, 0x0722d, 0x07238, 0x07248, 0x07267, 0x07269, 0x072c0, 0x072ce, 0x072d9, 0x072d7, 0x072d0,
+    0x073a9, 0x073a8, 0x0739f, 0x073ab, 0x073a5, 0x0753d, 0x0759d, 0x07599, 0x0759a, 0x07684, 0x076c2, 0x076f2,
+    0x076f4, 0x077e5, 0x077fd, 0x0793e, 0x07940, 0x07941, 0x079c9, 0x079c8, 0x07a7a, 0x07a79, 0x07afa, 0x07cfe,
+    0x07f54, 0x07f8c, 0x07f8b, 0x08005, 0x080ba, 0x080a5, 0x080a2, 0x080b1, 0x080a1, 0x080ab, 0x080a9, 0x080b4,
+    0x080aa, 0x080af, 0x081e5, 0x081fe, 0x0820d, 0x082b3, 0x0829d, 0x08299, 0x082ad, 0x082bd, 0x0829f, 0x082b9,
+    0x082b1, 0x082ac, 0x082a5, 0x082af, 0x082b8, 0x082a3, 0x082b0, 0x082be, 0x082b7, 0x0864e, 0x08671, 0x0521d,
+    0x08868, 0x08ecb, 0x08fce, 0x08fd4, 0x08fd1, 0x090b5, 0x090b8, 0x090b1, 0x090b6, 0x091c7, 0x091d1, 0x09577,
+    0x09580, 0x0961c, 0x09640, 0x0963f, 0x0963b, 0x09644, 0x09642, 0x096b9, 0x096e8, 0x09752, 0x0975e, 0x04e9f,
+    0x04ead, 0x04eae, 0x04fe1, 0x04fb5, 0x04faf, 0x04fbf, 0x04fe0, 0x04fd1, 0x04fcf, 0x04fdd, 0x04fc3, 0x04fb6,
+    0x04fd8, 0x04fdf, 0x04fca, 0x04fd7, 0x04fae, 0x04fd0, 0x04fc4, 0x04fc2, 0x04fda, 0x04fce, 0x04fde, 0x04fb7,
+    0x05157, 0x05192, 0x05191, 0x051a0, 0x0524e, 0x05243, 0x0524a, 0x0524d, 0x0524c, 0x0524b, 0x05247, 0x052c7,
+    0x052c9, 0x052c3, 0x052c1, 0x0530d, 0x05357, 0x0537b, 0x0539a, 0x053db, 0x054ac, 0x054c0, 0x054a8, 0x054ce,
+    0x054c9, 0x054b8, 0x054a6, 0x054b3, 0x054c7, 0x054c2, 0x054bd, 0x054aa, 0x054c1, 0x054c4, 0x054c8, 0x054af,
+    0x054ab, 0x054b1, 0x054bb, 0x054a9, 0x054a7, 0x054bf, 0x056ff, 0x05782, 0x0578b, 0x057a0, 0x057a3, 0x057a2,
+    0x057ce, 0x057ae, 0x05793, 0x05955, 0x05951, 0x0594f, 0x0594e, 0x05950, 0x059dc, 0x059d8, 0x059ff, 0x059e3,
+    0x059e8, 0x05a03, 0x059e5, 0x059ea, 0x059da, 0x059e6, 0x05a01, 0x059fb, 0x05b69, 0x05ba3, 0x05ba6, 0x05ba4,
+    0x05ba2, 0x05ba5, 0x05c01, 0x05c4e, 0x05c4f, 0x05c4d, 0x05c4b, 0x05cd9, 0x05cd2, 0x05df7, 0x05e1d, 0x05e25,
+    0x05e1f, 0x05e7d, 0x05ea0, 0x05ea6, 0x05efa, 0x05f08, 0x05f2d, 0x05f65, 0x05f88, 0x05f85, 0x05f8a, 0x05f8b,
+    0x05f87, 0x05f8c, 0x05f89, 0x06012, 0x0601d, 0x06020, 0x06025, 0x0600e, 0x06028, 0x0604d, 0x06070, 0x06068,
+    0x06062, 0x06046, 0x06043, 0x0606c, 0x0606b, 0x0606a, 0x06064, 0x06241, 0x062dc, 0x06316, 0x06309, 0x062fc,
+    0x062ed, 0x06301, 0x062ee, 0x062fd, 0x06307, 0x062f1, 0x062f7, 0x062ef, 0x062ec, 0x062fe, 0x062f4, 0x06311,
+    0x06302, 0x0653f, 0x06545, 0x065ab, 0x065bd, 0x065e2, 0x06625, 0x0662d, 0x06620, 0x06627, 0x0662f, 0x0661f,
+    0x06628, 0x06631, 0x06624, 0x066f7, 0x067ff, 0x067d3, 0x067f1, 0x067d4, 0x067d0, 0x067ec, 0x067b6, 0x067af,
+    0x067f5, 0x067e9, 0x067ef, 0x067c4, 0x067d1, 0x067b4, 0x067da, 0x067e5, 0x067b8, 0x067cf, 0x067de, 0x067f3,
+    0x067b0, 0x067d9, 0x067e2, 0x067dd, 0x067d2, 0x06b6a, 0x06b83, 0x06b86, 0x06bb5, 0x06bd2, 0x06bd7, 0x06c1f,
+    0x06cc9, 0x06d0b, 0x06d32, 0x06d2a, 0x06d41, 0x06d25, 0x06d0c, 0x06d31, 0x06d1e, 0x06d17, 0x06d3b, 0x06d3d,
+    0x06d3e, 0x06d36, 0x06d1b, 0x06cf5, 0x06d39, 0x06d27, 0x06d38, 0x06d29, 0x06d2e, 0x06d35, 0x06d0e, 0x06d2b,
+    0x070ab, 0x070ba, 0x070b3, 0x070ac, 0x070af, 0x070ad, 0x070b8, 0x070ae, 0x070a4, 0x07230, 0x07272, 0x0726f,
+    0x07274, 0x072e9, 0x072e0, 0x072e1, 0x073b7, 0x073ca, 0x073bb, 0x073b2, 0x073cd, 0x073c0, 0x073b3, 0x0751a,
+    0x0752d, 0x0754f, 0x0754c, 0x0754e, 0x0754b, 0x075ab, 0x075a4, 0x075a5, 0x075a2, 0x075a3, 0x07678, 0x07686,
+    0x07687, 0x07688, 0x076c8, 0x076c6, 0x076c3, 0x076c5, 0x07701, 0x076f9, 0x076f8, 0x07709, 0x0770b, 0x076fe,
+    0x076fc, 0x07707, 0x077dc, 0x07802, 0x07814, 0x0780c, 0x0780d, 0x07946, 0x07949, 0x07948, 0x07947, 0x079b9,
+    0x079ba, 0x079d1, 0x079d2, 0x079cb, 0x07a7f, 0x07a81, 0x07aff, 0x07afd, 0x07c7d, 0x07d02, 0x07d05, 0x07d00,
+    0x07d09, 0x07d07, 0x07d04, 0x07d06, 0x07f38, 0x07f8e, 0x07fbf, 0x08004, 0x08010, 0x0800d, 0x08011, 0x08036,
+    0x080d6, 0x080e5, 0x080da, 0x080c3, 0x080c4, 0x080cc, 0x080e1, 0x080db, 0x080ce, 0x080de, 0x080e4, 0x080dd,
+    0x081f4, 0x08222, 0x082e7, 0x08303, 0x08305, 0x082e3, 0x082db, 0x082e6, 0x08304, 0x082e5, 0x08302, 0x08309,
+    0x082d2, 0x082d7, 0x082f1, 0x08301, 0x082dc, 0x082d4, 0x082d1, 0x082de, 0x082d3, 0x082df, 0x082ef, 0x08306,
+    0x08650, 0x08679, 0x0867b, 0x0867a, 0x0884d, 0x0886b, 0x08981, 0x089d4, 0x08a08, 0x08a02, 0x08a03, 0x08c9e,
+    0x08ca0, 0x08d74, 0x08d73, 0x08db4, 0x08ecd, 0x08ecc, 0x08ff0, 0x08fe6, 0x08fe2, 0x08fea, 0x08fe5, 0x08fed,
+    0x08feb, 0x08fe4, 0x08fe8, 0x090ca, 0x090ce, 0x090c1, 0x090c3, 0x0914b, 0x0914a, 0x091cd, 0x09582, 0x09650,
+    0x0964b, 0x0964c, 0x0964d, 0x09762, 0x09769, 0x097cb, 0x097ed, 0x097f3, 0x09801, 0x098a8, 0x098db, 0x098df,
+    0x09996, 0x09999, 0x04e58, 0x04eb3, 0x0500c, 0x0500d, 0x05023, 0x04fef, 0x05026, 0x05025, 0x04ff8, 0x05029,
+    0x05016, 0x05006, 0x0503c, 0x0501f, 0x0501a, 0x05012, 0x05011, 0x04ffa, 0x05000, 0x05014, 0x05028, 0x04ff1,
+    0x05021, 0x0500b, 0x05019, 0x05018, 0x04ff3, 0x04fee, 0x0502d, 0x0502a, 0x04ffe, 0x0502b, 0x05009, 0x0517c,
+    0x051a4, 0x051a5, 0x051a2, 0x051cd, 0x051cc, 0x051c6, 0x051cb, 0x05256, 0x0525c, 0x05254, 0x0525b, 0x0525d,
+    0x0532a, 0x0537f, 0x0539f, 0x0539d, 0x053df, 0x054e8, 0x05510, 0x05501, 0x05537, 0x054fc, 0x054e5, 0x054f2,
+    0x05506, 0x054fa, 0x05514, 0x054e9, 0x054ed, 0x054e1, 0x05509, 0x054ee, 0x054ea, 0x054e6, 0x05527, 0x05507,
+    0x054fd, 0x0550f, 0x05703, 0x05704, 0x057c2, 0x057d4, 0x057cb, 0x057c3, 0x05809, 0x0590f, 0x05957, 0x05958,
+    0x0595a, 0x05a11, 0x05a18, 0x05a1c, 0x05a1f, 0x05a1b, 0x05a13, 0x059ec, 0x05a20, 0x05a23, 0x05a29, 0x05a25,
+    0x05a0c, 0x05a09, 0x05b6b, 0x05c58, 0x05bb0, 0x05bb3, 0x05bb6, 0x05bb4, 0x05bae, 0x05bb5, 0x05bb9, 0x05bb8,
+    0x05c04, 0x05c51, 0x05c55, 0x05c50, 0x05ced, 0x05cfd, 0x05cfb, 0x05cea, 0x05ce8, 0x05cf0, 0x05cf6, 0x05d01,
+    0x05cf4, 0x05dee, 0x05e2d, 0x05e2b, 0x05eab, 0x05ead, 0x05ea7, 0x05f31, 0x05f92, 0x05f91, 0x05f90, 0x06059,
+    0x06063, 0x06065, 0x06050, 0x06055, 0x0606d, 0x06069, 0x0606f, 0x06084, 0x0609f, 0x0609a, 0x0608d, 0x06094,
+    0x0608c, 0x06085, 0x06096, 0x06247, 0x062f3, 0x06308, 0x062ff, 0x0634e, 0x0633e, 0x0632f, 0x06355, 0x06342,
+    0x06346, 0x0634f, 0x06349, 0x0633a, 0x06350, 0x0633d, 0x0632a, 0x0632b, 0x06328, 0x0634d, 0x0634c, 0x06548,
+    0x06549, 0x06599, 0x065c1, 0x065c5, 0x06642, 0x06649, 0x0664f, 0x06643, 0x06652, 0x0664c, 0x06645, 0x06641,
+    0x066f8, 0x06714, 0x06715, 0x06717, 0x06821, 0x06838, 0x06848, 0x06846, 0x06853, 0x06839, 0x06842, 0x06854,
+    0x06829, 0x068b3, 0x06817, 0x0684c, 0x06851, 0x0683d, 0x067f4, 0x06850, 0x06840, 0x0683c, 0x06843, 0x0682a,
+    0x06845, 0x06813, 0x06818, 0x06841, 0x06b8a, 0x06b89, 0x06bb7, 0x06c23, 0x06c27, 0x06c28, 0x06c26, 0x06c24,
+    0x06cf0, 0x06d6a, 0x06d95, 0x06d88, 0x06d87, 0x06d66, 0x06d78, 0x06d77, 0x06d59, 0x06d93, 0x06d6c, 0x06d89,
+    0x06d6e, 0x06d5a, 0x06d74, 0x06d69, 0x06d8c, 0x06d8a, 0x06d79, 0x06d85, 0x06d65, 0x06d94, 0x070ca, 0x070d8,
+    0x070e4, 0x070d9, 0x070c8, 0x070cf, 0x07239, 0x07279, 0x072fc, 0x072f9, 0x072fd, 0x072f8, 0x072f7, 0x07386,
+    0x073ed, 0x07409, 0x073ee, 0x073e0, 0x073ea, 0x073de, 0x07554, 0x0755d, 0x0755c, 0x0755a, 0x07559, 0x075be,
+    0x075c5, 0x075c7, 0x075b2, 0x075b3, 0x075bd, 0x075bc, 0x075b9, 0x075c2, 0x075b8, 0x0768b, 0x076b0, 0x076ca,
+    0x076cd, 0x076ce, 0x07729, 0x0771f, 0x07720, 0x07728, 0x077e9, 0x07830, 0x07827, 0x07838, 0x0781d, 0x07834,
+    0x07837, 0x07825, 0x0782d, 0x07820, 0x0781f, 0x07832, 0x07955, 0x07950, 0x07960, 0x0795f, 0x07956, 0x0795e,
+    0x0795d, 0x07957, 0x0795a, 0x079e4, 0x079e3, 0x079e7, 0x079df, 0x079e6, 0x079e9, 0x079d8, 0x07a84, 0x07a88,
+    0x07ad9, 0x07b06, 0x07b11, 0x07c89, 0x07d21, 0x07d17, 0x07d0b, 0x07d0a, 0x07d20, 0x07d22, 0x07d14, 0x07d10,
+    0x07d15, 0x07d1a, 0x07d1c, 0x07d0d, 0x07d19, 0x07d1b, 0x07f3a, 0x07f5f, 0x07f94, 0x07fc5, 0x07fc1, 0x08006,
+    0x08018, 0x08015, 0x08019, 0x08017, 0x0803d, 0x0803f, 0x080f1, 0x08102, 0x080f0, 0x08105, 0x080ed, 0x080f4,
+    0x08106, 0x080f8, 0x080f3, 0x08108, 0x080fd, 0x0810a, 0x080fc, 0x080ef, 0x081ed, 0x081ec, 0x08200, 0x08210,
+    0x0822a, 0x0822b, 0x08228, 0x0822c, 0x082bb, 0x0832b, 0x08352, 0x08354, 0x0834a, 0x08338, 0x08350, 0x08349,
+    0x08335, 0x08334, 0x0834f, 0x08332, 0x08339, 0x08336, 0x08317, 0x08340, 0x08331, 0x08328, 0x08343, 0x08654,
+    0x0868a, 0x086aa, 0x08693, 0x086a4, 0x086a9, 0x0868c, 0x086a3, 0x0869c, 0x08870, 0x08877, 0x08881, 0x08882,
+    0x0887d, 0x08879, 0x08a18, 0x08a10, 0x08a0e, 0x08a0c, 0x08a15, 0x08a0a, 0x08a17, 0x08a13, 0x08a16, 0x08a0f,
+    0x08a11, 0x08c48, 0x08c7a, 0x08c79, 0x08ca1, 0x08ca2, 0x08d77, 0x08eac, 0x08ed2, 0x08ed4, 0x08ecf, 0x08fb1,
+    0x09001, 0x09006, 0x08ff7, 0x09000, 0x08ffa, 0x08ff4, 0x09003, 0x08ffd, 0x09005, 0x08ff8, 0x09095, 0x090e1,
+    0x090dd, 0x090e2, 0x09152, 0x0914d, 0x0914c, 0x091d8, 0x091dd, 0x091d7, 0x091dc, 0x091d9, 0x09583, 0x09662,
+    0x09663, 0x09661, 0x0965b, 0x0965d, 0x09664, 0x09658, 0x0965e, 0x096bb, 0x098e2, 0x099ac, 0x09aa8, 0x09ad8,
+    0x09b25, 0x09b32, 0x09b3c, 0x04e7e, 0x0507a, 0x0507d, 0x0505c, 0x05047, 0x05043, 0x0504c, 0x0505a, 0x05049,
+    0x05065, 0x05076, 0x0504e, 0x05055, 0x05075, 0x05074, 0x05077, 0x0504f, 0x0500f, 0x0506f, 0x0506d, 0x0515c,
+    0x05195, 0x051f0, 0x0526a, 0x0526f, 0x052d2, 0x052d9, 0x052d8, 0x052d5, 0x05310, 0x0530f, 0x05319, 0x0533f,
+    0x05340, 0x0533e, 0x053c3, 0x066fc, 0x05546, 0x0556a, 0x05566, 0x05544, 0x0555e, 0x05561, 0x05543, 0x0554a,
+    0x05531, 0x05556, 0x0554f, 0x05555, 0x0552f, 0x05564, 0x05538, 0x0552e, 0x0555c, 0x0552c, 0x05563, 0x05533,
+    0x05541, 0x05557, 0x05708, 0x0570b, 0x05709, 0x057df, 0x05805, 0x0580a, 0x05806, 0x057e0, 0x057e4, 0x057fa,
+    0x05802, 0x05835, 0x057f7, 0x057f9, 0x05920, 0x05962, 0x05a36, 0x05a41, 0x05a49, 0x05a66, 0x05a6a, 0x05a40,
+    0x05a3c, 0x05a62, 0x05a5a, 0x05a46, 0x05a4a, 0x05b70, 0x05bc7, 0x05bc5, 0x05bc4, 0x05bc2, 0x05bbf, 0x05bc6,
+    0x05c09, 0x05c08, 0x05c07, 0x05c60, 0x05c5c, 0x05c5d, 0x05d07, 0x05d06, 0x05d0e, 0x05d1b, 0x05d16, 0x05d22,
+    0x05d11, 0x05d29, 0x05d14, 0x05d19, 0x05d24, 0x05d27, 0x05d17, 0x05de2, 0x05e38, 0x05e36, 0x05e33, 0x05e37,
+    0x05eb7, 0x05eb8, 0x05eb6, 0x05eb5, 0x05ebe, 0x05f35, 0x05f37, 0x05f57, 0x05f6c, 0x05f69, 0x05f6b, 0x05f97,
+    0x05f99, 0x05f9e, 0x05f98, 0x05fa1, 0x05fa0, 0x05f9c, 0x0607f, 0x060a3, 0x06089, 0x060a0, 0x060a8, 0x060cb,
+    0x060b4, 0x060e6, 0x060bd, 0x060c5, 0x060bb, 0x060b5, 0x060dc, 0x060bc, 0x060d8, 0x060d5, 0x060c6, 0x060df,
+    0x060b8, 0x060da, 0x060c7, 0x0621a, 0x0621b, 0x06248, 0x063a0, 0x063a7, 0x06372, 0x06396, 0x063a2, 0x063a5,
+    0x06377, 0x06367, 0x06398, 0x063aa, 0x06371, 0x063a9, 0x06389, 0x06383, 0x0639b, 0x0636b, 0x063a8, 0x06384,
+    0x06388, 0x06399, 0x063a1, 0x063ac, 0x06392, 0x0638f, 0x06380, 0x0637b, 0x06369, 0x06368, 0x0637a, 0x0655d,
+    0x06556, 0x06551, 0x06559, 0x06557, 0x0555f, 0x0654f, 0x06558, 0x06555, 0x06554, 0x0659c, 0x0659b, 0x065ac,
+    0x065cf, 0x065cb, 0x065cc, 0x065ce, 0x0665d, 0x0665a, 0x06664, 0x06668, 0x06666, 0x0665e, 0x066f9, 0x052d7,
+    0x0671b, 0x06881, 0x068af, 0x068a2, 0x06893, 0x068b5, 0x0687f, 0x06876, 0x068b1, 0x068a7, 0x06897, 0x068b0,
+    0x06883, 0x068c4, 0x068ad, 0x06886, 0x06885, 0x06894, 0x0689d, 0x068a8, 0x0689f, 0x068a1, 0x06882, 0x06b32,
+    0x06bba, 0x06beb, 0x06bec, 0x06c2b, 0x06d8e, 0x06dbc, 0x06df3, 0x06dd9, 0x06db2, 0x06de1, 0x06dcc, 0x06de4,
+    0x06dfb, 0x06dfa, 0x06e05, 0x06dc7, 0x06dcb, 0x06daf, 0x06dd1, 0x06dae, 0x06dde, 0x06df9, 0x06db8, 0x06df7,
+    0x06df5, 0x06dc5, 0x06dd2, 0x06e1a, 0x06db5, 0x06dda, 0x06deb, 0x06dd8, 0x06dea, 0x06df1, 0x06dee, 0x06de8,
+    0x06dc6, 0x06dc4, 0x06daa, 0x06dec, 0x06dbf, 0x06de6, 0x070f9, 0x07109, 0x0710a, 0x070fd, 0x070ef, 0x0723d,
+    0x0727d, 0x07281, 0x0731c, 0x0731b, 0x07316, 0x07313, 0x07319, 0x07387, 0x07405, 0x0740a, 0x07403, 0x07406,
+    0x073fe, 0x0740d, 0x074e0, 0x074f6, 0x074f7, 0x0751c, 0x07522, 0x07565, 0x07566, 0x07562, 0x07570, 0x0758f,
+    0x075d4, 0x075d5, 0x075b5, 0x075ca, 0x075cd, 0x0768e, 0x076d4, 0x076d2, 0x076db, 0x07737, 0x0773e, 0x0773c,
+    0x07736, 0x07738, 0x0773a, 0x0786b, 0x07843, 0x0784e, 0x07965, 0x07968, 0x0796d, 0x079fb, 0x07a92, 0x07a95,
+    0x07b20, 0x07b28, 0x07b1b, 0x07b2c, 0x07b26, 0x07b19, 0x07b1e, 0x07b2e, 0x07c92, 0x07c97, 0x07c95, 0x07d46,
+    0x07d43, 0x07d71, 0x07d2e, 0x07d39, 0x07d3c, 0x07d40, 0x07d30, 0x07d33, 0x07d44, 0x07d2f, 0x07d42, 0x07d32,
+    0x07d31, 0x07f3d, 0x07f9e, 0x07f9a, 0x07fcc, 0x07fce, 0x07fd2, 0x0801c, 0x0804a, 0x08046, 0x0812f, 0x08116,
+    0x08123, 0x0812b, 0x08129, 0x08130, 0x08124, 0x08202, 0x08235, 0x08237, 0x08236, 0x08239, 0x0838e, 0x0839e,
+    0x08398, 0x08378, 0x083a2, 0x08396, 0x083bd, 0x083ab, 0x08392, 0x0838a, 0x08393, 0x08389, 0x083a0, 0x08377,
+    0x0837b, 0x0837c, 0x08386, 0x083a7, 0x08655, 0x05f6a, 0x086c7, 0x086c0, 0x086b6, 0x086c4, 0x086b5, 0x086c6,
+    0x086cb, 0x086b1, 0x086af, 0x086c9, 0x08853, 0x0889e, 0x08888, 0x088ab, 0x08892, 0x08896, 0x0888d, 0x0888b,
+    0x08993, 0x0898f, 0x08a2a, 0x08a1d, 0x08a23, 0x08a25, 0x08a31, 0x08a2d, 0x08a1f, 0x08a1b, 0x08a22, 0x08c49,
+    0x08c5a, 0x08ca9, 0x08cac, 0x08cab, 0x08ca8, 0x08caa, 0x08ca7, 0x08d67, 0x08d66, 0x08dbe, 0x08dba, 0x08edb,
+    0x08edf, 0x09019, 0x0900d, 0x0901a, 0x09017, 0x09023, 0x0901f, 0x0901d, 0x09010, 0x09015, 0x0901e, 0x09020,
+    0x0900f, 0x09022, 0x09016, 0x0901b, 0x09014, 0x090e8, 0x090ed, 0x090fd, 0x09157, 0x091ce, 0x091f5, 0x091e6,
+    0x091e3, 0x091e7, 0x091ed, 0x091e9, 0x09589, 0x0966a, 0x09675, 0x09673, 0x09678, 0x09670, 0x09674, 0x09676,
+    0x09677, 0x0966c, 0x096c0, 0x096ea, 0x096e9, 0x07ae0, 0x07adf, 0x09802, 0x09803, 0x09b5a, 0x09ce5, 0x09e75,
+    0x09e7f, 0x09ea5, 0x09ebb, 0x050a2, 0x0508d, 0x05085, 0x05099, 0x05091, 0x05080, 0x05096, 0x05098, 0x0509a,
+    0x06700, 0x051f1, 0x05272, 0x05274, 0x05275, 0x05269, 0x052de, 0x052dd, 0x052db, 0x0535a, 0x053a5, 0x0557b,
+    0x05580, 0x055a7, 0x0557c, 0x0558a, 0x0559d, 0x05598, 0x05582, 0x0559c, 0x055aa, 0x05594, 0x05587, 0x0558b,
+    0x05583, 0x055b3, 0x055ae, 0x0559f, 0x0553e, 0x055b2, 0x0559a, 0x055bb, 0x055ac, 0x055b1, 0x0557e, 0x05589,
+    0x055ab, 0x05599, 0x0570d, 0x0582f, 0x0582a, 0x05834, 0x05824, 0x05830, 0x05831, 0x05821, 0x0581d, 0x05820,
+    0x058f9, 0x058fa, 0x05960, 0x05a77, 0x05a9a, 0x05a7f, 0x05a92, 0x05a9b, 0x05aa7, 0x05b73, 0x05b71, 0x05bd2,
+    0x05bcc, 0x05bd3, 0x05bd0, 0x05c0a, 0x05c0b, 0x05c31, 0x05d4c, 0x05d50, 0x05d34, 0x05d47, 0x05dfd, 0x05e45,
+    0x05e3d, 0x05e40, 0x05e43, 0x05e7e, 0x05eca, 0x05ec1, 0x05ec2, 0x05ec4, 0x05f3c, 0x05f6d, 0x05fa9, 0x05faa,
+    0x05fa8, 0x060d1, 0x060e1, 0x060b2, 0x060b6, 0x060e0, 0x0611c, 0x06123, 0x060fa, 0x06115, 0x060f0, 0x060fb,
+    0x060f4, 0x06168, 0x060f1, 0x0610e, 0x060f6, 0x06109, 0x06100, 0x06112, 0x0621f, 0x06249, 0x063a3, 0x0638c,
+    0x063cf, 0x063c0, 0x063e9, 0x063c9, 0x063c6, 0x063cd, 0x063d2, 0x063e3, 0x063d0, 0x063e1, 0x063d6, 0x063ed,
+    0x063ee, 0x06376, 0x063f4, 0x063ea, 0x063db, 0x06452, 0x063da, 0x063f9, 0x0655e, 0x06566, 0x06562, 0x06563,
+    0x06591, 0x06590, 0x065af, 0x0666e, 0x06670, 0x06674, 0x06676, 0x0666f, 0x06691, 0x0667a, 0x0667e, 0x06677,
+    0x066fe, 0x066ff, 0x0671f, 0x0671d, 0x068fa, 0x068d5, 0x068e0, 0x068d8, 0x068d7, 0x06905, 0x068df, 0x068f5,
+    0x068ee, 0x068e7, 0x068f9, 0x068d2, 0x068f2, 0x068e3, 0x068cb, 0x068cd, 0x0690d, 0x06912, 0x0690e, 0x068c9,
+    0x068da, 0x0696e, 0x068fb, 0x06b3e, 0x06b3a, 0x06b3d, 0x06b98, 0x06b96, 0x06bbc, 0x06bef, 0x06c2e, 0x06c2f,
+    0x06c2c, 0x06e2f, 0x06e38, 0x06e54, 0x06e21, 0x06e32, 0x06e67, 0x06e4a, 0x06e20, 0x06e25, 0x06e23, 0x06e1b,
+    0x06e5b, 0x06e58, 0x06e24, 0x06e56, 0x06e6e, 0x06e2d, 0x06e26, 0x06e6f, 0x06e34, 0x06e4d, 0x06e3a, 0x06e2c,
+    0x06e43, 0x06e1d, 0x06e3e, 0x06ecb, 0x06e89, 0x06e19, 0x06e4e, 0x06e63, 0x06e44, 0x06e72, 0x06e69, 0x06e5f,
+    0x07119, 0x0711a, 0x07126, 0x07130, 0x07121, 0x07136, 0x0716e, 0x0711c, 0x0724c, 0x07284, 0x07280, 0x07336,
+    0x07325, 0x07334, 0x07329, 0x0743a, 0x0742a, 0x07433, 0x07422, 0x07425, 0x07435, 0x07436, 0x07434, 0x0742f,
+    0x0741b, 0x07426, 0x07428, 0x07525, 0x07526, 0x0756b, 0x0756a, 0x075e2, 0x075db, 0x075e3, 0x075d9, 0x075d8,
+    0x075de, 0x075e0, 0x0767b, 0x0767c, 0x07696, 0x07693, 0x076b4, 0x076dc, 0x0774f, 0x077ed, 0x0785d, 0x0786c,
+    0x0786f, 0x07a0d, 0x07a08, 0x07a0b, 0x07a05, 0x07a00, 0x07a98, 0x07a97, 0x07a96, 0x07ae5, 0x07ae3, 0x07b49,
+    0x07b56, 0x07b46, 0x07b50, 0x07b52, 0x07b54, 0x07b4d, 0x07b4b, 0x07b4f, 0x07b51, 0x07c9f, 0x07ca5, 0x07d5e,
+    0x07d50, 0x07d68, 0x07d55, 0x07d2b, 0x07d6e, 0x07d72, 0x07d61, 0x07d66, 0x07d62, 0x07d70, 0x07d73, 0x05584,
+    0x07fd4, 0x07fd5, 0x0800b, 0x08052, 0x08085, 0x08155, 0x08154, 0x0814b, 0x08151, 0x0814e, 0x08139, 0x08146,
+    0x0813e, 0x0814c, 0x08153, 0x08174, 0x08212, 0x0821c, 0x083e9, 0x08403, 0x083f8, 0x0840d, 0x083e0, 0x083c5,
+    0x0840b, 0x083c1, 0x083ef, 0x083f1, 0x083f4, 0x08457, 0x0840a, 0x083f0, 0x0840c, 0x083cc, 0x083fd, 0x083f2,
+    0x083ca, 0x08438, 0x0840e, 0x08404, 0x083dc, 0x08407, 0x083d4, 0x083df, 0x0865b, 0x086df, 0x086d9, 0x086ed,
+    0x086d4, 0x086db, 0x086e4, 0x086d0, 0x086de, 0x08857, 0x088c1, 0x088c2, 0x088b1, 0x08983, 0x08996, 0x08a3b,
+    0x08a60, 0x08a55, 0x08a5e, 0x08a3c, 0x08a41, 0x08a54, 0x08a5b, 0x08a50, 0x08a46, 0x08a34, 0x08a3a, 0x08a36,
+    0x08a56, 0x08c61, 0x08c82, 0x08caf, 0x08cbc, 0x08cb3, 0x08cbd, 0x08cc1, 0x08cbb, 0x08cc0, 0x08cb4, 0x08cb7,
+    0x08cb6, 0x08cbf, 0x08cb8, 0x08d8a, 0x08d85, 0x08d81, 0x08dce, 0x08ddd, 0x08dcb, 0x08dda, 0x08dd1, 0x08dcc,
+    0x08ddb, 0x08dc6, 0x08efb, 0x08ef8, 0x08efc, 0x08f9c, 0x0902e, 0x09035, 0x09031, 0x09038, 0x09032, 0x09036,
+    0x09102, 0x090f5, 0x09109, 0x090fe, 0x09163, 0x09165, 0x091cf, 0x09214, 0x09215, 0x09223, 0x09209, 0x0921e,
+    0x0920d, 0x09210, 0x09207, 0x09211, 0x09594, 0x0958f, 0x0958b, 0x09591, 0x09593, 0x09592, 0x0958e, 0x0968a,
+    0x0968e, 0x0968b, 0x0967d, 0x09685, 0x09686, 0x0968d, 0x09672, 0x09684, 0x096c1, 0x096c5, 0x096c4, 0x096c6,
+    0x096c7, 0x096ef, 0x096f2, 0x097cc, 0x09805, 0x09806, 0x09808, 0x098e7, 0x098ea, 0x098ef, 0x098e9, 0x098f2,
+    0x098ed, 0x099ae, 0x099ad, 0x09ec3, 0x09ecd, 0x09ed1, 0x04e82, 0x050ad, 0x050b5, 0x050b2, 0x050b3, 0x050c5,
+    0x050be, 0x050ac, 0x050b7, 0x050bb, 0x050af, 0x050c7, 0x0527f, 0x05277, 0x0527d, 0x052df, 0x052e6, 0x052e4,
+    0x052e2, 0x052e3, 0x0532f, 0x055df, 0x055e8, 0x055d3, 0x055e6, 0x055ce, 0x055dc, 0x055c7, 0x055d1, 0x055e3,
+    0x055e4, 0x055ef, 0x055da, 0x055e1, 0x055c5, 0x055c6, 0x055e5, 0x055c9, 0x05712, 0x05713, 0x0585e, 0x05851,
+    0x05858, 0x05857, 0x0585a, 0x05854, 0x0586b, 0x0584c, 0x0586d, 0x0584a, 0x05862, 0x05852, 0x0584b, 0x05967,
+    0x05ac1, 0x05ac9, 0x05acc, 0x05abe, 0x05abd, 0x05abc, 0x05ab3, 0x05ac2, 0x05ab2, 0x05d69, 0x05d6f, 0x05e4c,
+    0x05e79, 0x05ec9, 0x05ec8, 0x05f12, 0x05f59, 0x05fac, 0x05fae, 0x0611a, 0x0610f, 0x06148, 0x0611f, 0x060f3,
+    0x0611b, 0x060f9, 0x06101, 0x06108, 0x0614e, 0x0614c, 0x06144, 0x0614d, 0x0613e, 0x06134, 0x06127, 0x0610d,
+    0x06106, 0x06137, 0x06221, 0x06222, 0x06413, 0x0643e, 0x0641e, 0x0642a, 0x0642d, 0x0643d, 0x0642c, 0x0640f,
+    0x0641c, 0x06414, 0x0640d, 0x06436, 0x06416, 0x06417, 0x06406, 0x0656c, 0x0659f, 0x065b0, 0x06697, 0x06689,
+    0x06687, 0x06688, 0x06696, 0x06684, 0x06698, 0x0668d, 0x06703, 0x06994, 0x0696d, 0x0695a, 0x06977, 0x06960,
+    0x06954, 0x06975, 0x06930, 0x06982, 0x0694a, 0x06968, 0x0696b, 0x0695e, 0x06953, 0x06979, 0x06986, 0x0695d,
+    0x06963, 0x0695b, 0x06b47, 0x06b72, 0x06bc0, 0x06bbf, 0x06bd3, 0x06bfd, 0x06ea2, 0x06eaf, 0x06ed3, 0x06eb6,
+    0x06ec2, 0x06e90, 0x06e9d, 0x06ec7, 0x06ec5, 0x06ea5, 0x06e98, 0x06ebc, 0x06eba, 0x06eab, 0x06ed1, 0x06e96,
+    0x06e9c, 0x06ec4, 0x06ed4, 0x06eaa, 0x06ea7, 0x06eb4, 0x0714e, 0x07159, 0x07169, 0x07164, 0x07149, 0x07167,
+    0x0715c, 0x0716c, 0x07166, 0x0714c, 0x07165, 0x0715e, 0x07146, 0x07168, 0x07156, 0x0723a, 0x07252, 0x07337,
+    0x07345, 0x0733f, 0x0733e, 0x0746f, 0x0745a, 0x07455, 0x0745f, 0x0745e, 0x07441, 0x0743f, 0x07459, 0x0745b,
+    0x0745c, 0x07576, 0x07578, 0x07600, 0x075f0, 0x07601, 0x075f2, 0x075f1, 0x075fa, 0x075ff, 0x075f4, 0x075f3,
+    0x076de, 0x076df, 0x0775b, 0x0776b, 0x07766, 0x0775e, 0x07763, 0x07779, 0x0776a, 0x0776c, 0x0775c, 0x07765,
+    0x07768, 0x07762, 0x077ee, 0x0788e, 0x078b0, 0x07897, 0x07898, 0x0788c, 0x07889, 0x0787c, 0x07891, 0x07893,
+    0x0787f, 0x0797a, 0x0797f, 0x07981, 0x0842c, 0x079bd, 0x07a1c, 0x07a1a, 0x07a20, 0x07a14, 0x07a1f, 0x07a1e,
+    0x07a9f, 0x07aa0, 0x07b77, 0x07bc0, 0x07b60, 0x07b6e, 0x07b67, 0x07cb1, 0x07cb3, 0x07cb5, 0x07d93, 0x07d79,
+    0x07d91, 0x07d81, 0x07d8f, 0x07d5b, 0x07f6e, 0x07f69, 0x07f6a, 0x07f72, 0x07fa9, 0x07fa8, 0x07fa4, 0x08056,
+    0x08058, 0x08086, 0x08084, 0x08171, 0x08170, 0x08178, 0x08165, 0x0816e, 0x08173, 0x0816b, 0x08179, 0x0817a,
+    0x08166, 0x08205, 0x08247, 0x08482, 0x08477, 0x0843d, 0x08431, 0x08475, 0x08466, 0x0846b, 0x08449, 0x0846c,
+    0x0845b, 0x0843c, 0x08435, 0x08461, 0x08463, 0x08469, 0x0846d, 0x08446, 0x0865e, 0x0865c, 0x0865f, 0x086f9,
+    0x08713, 0x08708, 0x08707, 0x08700, 0x086fe, 0x086fb, 0x08702, 0x08703, 0x08706, 0x0870a, 0x08859, 0x088df,
+    0x088d4, 0x088d9, 0x088dc, 0x088d8, 0x088dd, 0x088e1, 0x088ca, 0x088d5, 0x088d2, 0x0899c, 0x089e3, 0x08a6b,
+    0x08a72, 0x08a73, 0x08a66, 0x08a69, 0x08a70, 0x08a87, 0x08a7c, 0x08a63, 0x08aa0, 0x08a71, 0x08a85, 0x08a6d,
+    0x08a62, 0x08a6e, 0x08a6c, 0x08a79, 0x08a7b, 0x08a3e, 0x08a68, 0x08c62, 0x08c8a, 0x08c89, 0x08cca, 0x08cc7,
+    0x08cc8, 0x08cc4, 0x08cb2, 0x08cc3, 0x08cc2, 0x08cc5, 0x08de1, 0x08ddf, 0x08de8, 0x08def, 0x08df3, 0x08dfa,
+    0x08dea, 0x08de4, 0x08de6, 0x08eb2, 0x08f03, 0x08f09, 0x08efe, 0x08f0a, 0x08f9f, 0x08fb2, 0x0904b, 0x0904a,
+    0x09053, 0x09042, 0x09054, 0x0903c, 0x09055, 0x09050, 0x09047, 0x0904f, 0x0904e, 0x0904d, 0x09051, 0x0903e,
+    0x09041, 0x09112, 0x09117, 0x0916c, 0x0916a, 0x09169, 0x091c9, 0x09237, 0x09257, 0x09238, 0x0923d, 0x09240,
+    0x0923e, 0x0925b, 0x0924b, 0x09264, 0x09251, 0x09234, 0x09249, 0x0924d, 0x09245, 0x09239, 0x0923f, 0x0925a,
+    0x09598, 0x09698, 0x09694, 0x09695, 0x096cd, 0x096cb, 0x096c9, 0x096ca, 0x096f7, 0x096fb, 0x096f9, 0x096f6,
+    0x09756, 0x09774, 0x09776, 0x09810, 0x09811, 0x09813, 0x0980a, 0x09812, 0x0980c, 0x098fc, 0x098f4, 0x098fd,
+    0x098fe, 0x099b3, 0x099b1, 0x099b4, 0x09ae1, 0x09ce9, 0x09e82, 0x09f0e, 0x09f13, 0x09f20, 0x050e7, 0x050ee,
+    0x050e5, 0x050d6, 0x050ed, 0x050da, 0x050d5, 0x050cf, 0x050d1, 0x050f1, 0x050ce, 0x050e9, 0x05162, 0x051f3,
+    0x05283, 0x05282, 0x05331, 0x053ad, 0x055fe, 0x05600, 0x0561b, 0x05617, 0x055fd, 0x05614, 0x05606, 0x05609,
+    0x0560d, 0x0560e, 0x055f7, 0x05616, 0x0561f, 0x05608, 0x05610, 0x055f6, 0x05718, 0x05716, 0x05875, 0x0587e,
+    0x05883, 0x05893, 0x0588a, 0x05879, 0x05885, 0x0587d, 0x058fd, 0x05925, 0x05922, 0x05924, 0x0596a, 0x05969,
+    0x05ae1, 0x05ae6, 0x05ae9, 0x05ad7, 0x05ad6, 0x05ad8, 0x05ae3, 0x05b75, 0x05bde, 0x05be7, 0x05be1, 0x05be5,
+    0x05be6, 0x05be8, 0x05be2, 0x05be4, 0x05bdf, 0x05c0d, 0x05c62, 0x05d84, 0x05d87, 0x05e5b, 0x05e63, 0x05e55,
+    0x05e57, 0x05e54, 0x05ed3, 0x05ed6, 0x05f0a, 0x05f46, 0x05f70, 0x05fb9, 0x06147, 0x0613f, 0x0614b, 0x06177,
+    0x06162, 0x06163, 0x0615f, 0x0615a, 0x06158, 0x06175, 0x0622a, 0x06487, 0x06458, 0x06454, 0x064a4, 0x06478,
+    0x0645f, 0x0647a, 0x06451, 0x06467, 0x06434, 0x0646d, 0x0647b, 0x06572, 0x065a1, 0x065d7, 0x065d6, 0x066a2,
+    0x066a8, 0x0669d, 0x0699c, 0x069a8, 0x06995, 0x069c1, 0x069ae, 0x069d3, 0x069cb, 0x0699b, 0x069b7, 0x069bb,
+    0x069ab, 0x069b4, 0x069d0, 0x069cd, 0x069ad, 0x069cc, 0x069a6, 0x069c3, 0x069a3, 0x06b49, 0x06b4c, 0x06c33,
+    0x06f33, 0x06f14, 0x06efe, 0x06f13, 0x06ef4, 0x06f29, 0x06f3e, 0x06f20, 0x06f2c, 0x06f0f, 0x06f02, 0x06f22,
+    0x06eff, 0x06eef, 0x06f06, 0x06f31, 0x06f38, 0x06f32, 0x06f23, 0x06f15, 0x06f2b, 0x06f2f, 0x06f88, 0x06f2a,
+    0x06eec, 0x06f01, 0x06ef2, 0x06ecc, 0x06ef7, 0x07194, 0x07199, 0x0717d, 0x0718a, 0x07184, 0x07192, 0x0723e,
+    0x07292, 0x07296, 0x07344, 0x07350, 0x07464, 0x07463, 0x0746a, 0x07470, 0x0746d, 0x07504, 0x07591, 0x07627,
+    0x0760d, 0x0760b, 0x07609, 0x07613, 0x076e1, 0x076e3, 0x07784, 0x0777d, 0x0777f, 0x07761, 0x078c1, 0x0789f,
+    0x078a7, 0x078b3, 0x078a9, 0x078a3, 0x0798e, 0x0798f, 0x0798d, 0x07a2e, 0x07a31, 0x07aaa, 0x07aa9, 0x07aed,
+    0x07aef, 0x07ba1, 0x07b95, 0x07b8b, 0x07b75, 0x07b97, 0x07b9d, 0x07b94, 0x07b8f, 0x07bb8, 0x07b87, 0x07b84,
+    0x07cb9, 0x07cbd, 0x07cbe, 0x07dbb, 0x07db0, 0x07d9c, 0x07dbd, 0x07dbe, 0x07da0, 0x07dca, 0x07db4, 0x07db2,
+    0x07db1, 0x07dba, 0x07da2, 0x07dbf, 0x07db5, 0x07db8, 0x07dad, 0x07dd2, 0x07dc7, 0x07dac, 0x07f70, 0x07fe0,
+    0x07fe1, 0x07fdf, 0x0805e, 0x0805a, 0x08087, 0x08150, 0x08180, 0x0818f, 0x08188, 0x0818a, 0x0817f, 0x08182,
+    0x081e7, 0x081fa, 0x08207, 0x08214, 0x0821e, 0x0824b, 0x084c9, 0x084bf, 0x084c6, 0x084c4, 0x08499, 0x0849e,
+    0x084b2, 0x0849c, 0x084cb, 0x084b8, 0x084c0, 0x084d3, 0x08490, 0x084bc, 0x084d1, 0x084ca, 0x0873f, 0x0871c,
+    0x0873b, 0x08722, 0x08725, 0x08734, 0x08718, 0x08755, 0x08737, 0x08729, 0x088f3, 0x08902, 0x088f4, 0x088f9,
+    0x088f8, 0x088fd, 0x088e8, 0x0891a, 0x088ef, 0x08aa6, 0x08a8c, 0x08a9e, 0x08aa3, 0x08a8d, 0x08aa1, 0x08a93,
+    0x08aa4, 0x08aaa, 0x08aa5, 0x08aa8, 0x08a98, 0x08a91, 0x08a9a, 0x08aa7, 0x08c6a, 0x08c8d, 0x08c8c, 0x08cd3,
+    0x08cd1, 0x08cd2, 0x08d6b, 0x08d99, 0x08d95, 0x08dfc, 0x08f14, 0x08f12, 0x08f15, 0x08f13, 0x08fa3, 0x09060,
+    0x09058, 0x0905c, 0x09063, 0x09059, 0x0905e, 0x09062, 0x0905d, 0x0905b, 0x09119, 0x09118, 0x0911e, 0x09175,
+    0x09178, 0x09177, 0x09174, 0x09278, 0x09280, 0x09285, 0x09298, 0x09296, 0x0927b, 0x09293, 0x0929c, 0x092a8,
+    0x0927c, 0x09291, 0x095a1, 0x095a8, 0x095a9, 0x095a3, 0x095a5, 0x095a4, 0x09699, 0x0969c, 0x0969b, 0x096cc,
+    0x096d2, 0x09700, 0x0977c, 0x09785, 0x097f6, 0x09817, 0x09818, 0x098af, 0x098b1, 0x09903, 0x09905, 0x0990c,
+    0x09909, 0x099c1, 0x09aaf, 0x09ab0, 0x09ae6, 0x09b41, 0x09b42, 0x09cf4, 0x09cf6, 0x09cf3, 0x09ebc, 0x09f3b,
+    0x09f4a, 0x05104, 0x05100, 0x050fb, 0x050f5, 0x050f9, 0x05102, 0x05108, 0x05109, 0x05105, 0x051dc, 0x05287,
+    0x05288, 0x05289, 0x0528d, 0x0528a, 0x052f0, 0x053b2, 0x0562e, 0x0563b, 0x05639, 0x05632, 0x0563f, 0x05634,
+    0x05629, 0x05653, 0x0564e, 0x05657, 0x05674, 0x05636, 0x0562f, 0x05630, 0x05880, 0x0589f, 0x0589e, 0x058b3,
+    0x0589c, 0x058ae, 0x058a9, 0x058a6, 0x0596d, 0x05b09, 0x05afb, 0x05b0b, 0x05af5, 0x05b0c, 0x05b08, 0x05bee,
+    0x05bec, 0x05be9, 0x05beb, 0x05c64, 0x05c65, 0x05d9d, 0x05d94, 0x05e62, 0x05e5f, 0x05e61, 0x05ee2, 0x05eda,
+    0x05edf, 0x05edd, 0x05ee3, 0x05ee0, 0x05f48, 0x05f71, 0x05fb7, 0x05fb5, 0x06176, 0x06167, 0x0616e, 0x0615d,
+    0x06155, 0x06182, 0x0617c, 0x06170, 0x0616b, 0x0617e, 0x061a7, 0x06190, 0x061ab, 0x0618e, 0x061ac, 0x0619a,
+    0x061a4, 0x06194, 0x061ae, 0x0622e, 0x06469, 0x0646f, 0x06479, 0x0649e, 0x064b2, 0x06488, 0x06490, 0x064b0,
+    0x064a5, 0x06493, 0x06495, 0x064a9, 0x06492, 0x064ae, 0x064ad, 0x064ab, 0x0649a, 0x064ac, 0x06499, 0x064a2,
+    0x064b3, 0x06575, 0x06577, 0x06578, 0x066ae, 0x066ab, 0x066b4, 0x066b1, 0x06a23, 0x06a1f, 0x069e8, 0x06a01,
+    0x06a1e, 0x06a19, 0x069fd, 0x06a21, 0x06a13, 0x06a0a, 0x069f3, 0x06a02, 0x06a05, 0x069ed, 0x06a11, 0x06b50,
+    0x06b4e, 0x06ba4, 0x06bc5, 0x06bc6, 0x06f3f, 0x06f7c, 0x06f84, 0x06f51, 0x06f66, 0x06f54, 0x06f86, 0x06f6d,
+    0x06f5b, 0x06f78, 0x06f6e, 0x06f8e, 0x06f7a, 0x06f70, 0x06f64, 0x06f97, 0x06f58, 0x06ed5, 0x06f6f, 0x06f60,
+    0x06f5f, 0x0719f, 0x071ac, 0x071b1, 0x071a8, 0x07256, 0x0729b, 0x0734e, 0x07357, 0x07469, 0x0748b, 0x07483,
+    0x0747e, 0x07480, 0x0757f, 0x07620, 0x07629, 0x0761f, 0x07624, 0x07626, 0x07621, 0x07622, 0x0769a, 0x076ba,
+    0x076e4, 0x0778e, 0x07787, 0x0778c, 0x07791, 0x0778b, 0x078cb, 0x078c5, 0x078ba, 0x078ca, 0x078be, 0x078d5,
+    0x078bc, 0x078d0, 0x07a3f, 0x07a3c, 0x07a40, 0x07a3d, 0x07a37, 0x07a3b, 0x07aaf, 0x07aae, 0x07bad, 0x07bb1,
+    0x07bc4, 0x07bb4, 0x07bc6, 0x07bc7, 0x07bc1, 0x07ba0, 0x07bcc, 0x07cca, 0x07de0, 0x07df4, 0x07def, 0x07dfb,
+    0x07dd8, 0x07dec, 0x07ddd, 0x07de8, 0x07de3, 0x07dda, 0x07dde, 0x07de9, 0x07d9e, 0x07dd9, 0x07df2, 0x07df9,
+    0x07f75, 0x07f77, 0x07faf, 0x07fe9, 0x08026, 0x0819b, 0x0819c, 0x0819d, 0x081a0, 0x0819a, 0x08198, 0x08517,
+    0x0853d, 0x0851a, 0x084ee, 0x0852c, 0x0852d, 0x08513, 0x08511, 0x08523, 0x08521, 0x08514, 0x084ec, 0x08525,
+    0x084ff, 0x08506, 0x08782, 0x08774, 0x08776, 0x08760, 0x08766, 0x08778, 0x08768, 0x08759, 0x08757, 0x0874c,
+    0x08753, 0x0885b, 0x0885d, 0x08910, 0x08907, 0x08912, 0x08913, 0x08915, 0x0890a, 0x08abc, 0x08ad2, 0x08ac7,
+    0x08ac4, 0x08a95, 0x08acb, 0x08af8, 0x08ab2, 0x08ac9, 0x08ac2, 0x08abf, 0x08ab0, 0x08ad6, 0x08acd, 0x08ab6,
+    0x08ab9, 0x08adb, 0x08c4c, 0x08c4e, 0x08c6c, 0x08ce0, 0x08cde, 0x08ce6, 0x08ce4, 0x08cec, 0x08ced, 0x08ce2,
+    0x08ce3, 0x08cdc, 0x08cea, 0x08ce1, 0x08d6d, 0x08d9f, 0x08da3, 0x08e2b, 0x08e10, 0x08e1d, 0x08e22, 0x08e0f,
+    0x08e29, 0x08e1f, 0x08e21, 0x08e1e, 0x08eba, 0x08f1d, 0x08f1b, 0x08f1f, 0x08f29, 0x08f26, 0x08f2a, 0x08f1c,
+    0x08f1e, 0x08f25, 0x09069, 0x0906e, 0x09068, 0x0906d, 0x09077, 0x09130, 0x0912d, 0x09127, 0x09131, 0x09187,
+    0x09189, 0x0918b, 0x09183, 0x092c5, 0x092bb, 0x092b7, 0x092ea, 0x092ac, 0x092e4, 0x092c1, 0x092b3, 0x092bc,
+    0x092d2, 0x092c7, 0x092f0, 0x092b2, 0x095ad, 0x095b1, 0x09704, 0x09706, 0x09707, 0x09709, 0x09760, 0x0978d,
+    0x0978b, 0x0978f, 0x09821, 0x0982b, 0x0981c, 0x098b3, 0x0990a, 0x09913, 0x09912, 0x09918, 0x099dd, 0x099d0,
+    0x099df, 0x099db, 0x099d1, 0x099d5, 0x099d2, 0x099d9, 0x09ab7, 0x09aee, 0x09aef, 0x09b27, 0x09b45, 0x09b44,
+    0x09b77, 0x09b6f, 0x09d06, 0x09d09, 0x09d03, 0x09ea9, 0x09ebe, 0x09ece, 0x058a8, 0x09f52, 0x05112, 0x05118,
+    0x05114, 0x05110, 0x05115, 0x05180, 0x051aa, 0x051dd, 0x05291, 0x05293, 0x052f3, 0x05659, 0x0566b, 0x05679,
+    0x05669, 0x05664, 0x05678, 0x0566a, 0x05668, 0x05665, 0x05671, 0x0566f, 0x0566c, 0x05662, 0x05676, 0x058c1,
+    0x058be, 0x058c7, 0x058c5, 0x0596e, 0x05b1d, 0x05b34, 0x05b78, 0x05bf0, 0x05c0e, 0x05f4a, 0x061b2, 0x06191,
+    0x061a9, 0x0618a, 0x061cd, 0x061b6, 0x061be, 0x061ca, 0x061c8, 0x06230, 0x064c5, 0x064c1, 0x064cb, 0x064bb,
+    0x064bc, 0x064da, 0x064c4, 0x064c7, 0x064c2, 0x064cd, 0x064bf, 0x064d2, 0x064d4, 0x064be, 0x06574, 0x066c6,
+    0x066c9, 0x066b9, 0x066c4, 0x066c7, 0x066b8, 0x06a3d, 0x06a38, 0x06a3a, 0x06a59, 0x06a6b, 0x06a58, 0x06a39,
+    0x06a44, 0x06a62, 0x06a61, 0x06a4b, 0x06a47, 0x06a35, 0x06a5f, 0x06a48, 0x06b59, 0x06b77, 0x06c05, 0x06fc2,
+    0x06fb1, 0x06fa1, 0x06fc3, 0x06fa4, 0x06fc1, 0x06fa7, 0x06fb3, 0x06fc0, 0x06fb9, 0x06fb6, 0x06fa6, 0x06fa0,
+    0x06fb4, 0x071be, 0x071c9, 0x071d0, 0x071d2, 0x071c8, 0x071d5, 0x071b9, 0x071ce, 0x071d9, 0x071dc, 0x071c3,
+    0x071c4, 0x07368, 0x0749c, 0x074a3, 0x07498, 0x0749f, 0x0749e, 0x074e2, 0x0750c, 0x0750d, 0x07634, 0x07638,
+    0x0763a, 0x076e7, 0x076e5, 0x077a0, 0x0779e, 0x0779f, 0x077a5, 0x078e8, 0x078da, 0x078ec, 0x078e7, 0x079a6,
+    0x07a4d, 0x07a4e, 0x07a46, 0x07a4c, 0x07a4b, 0x07aba, 0x07bd9, 0x07c11, 0x07bc9, 0x07be4, 0x07bdb, 0x07be1,
+    0x07be9, 0x07be6, 0x07cd5, 0x07cd6, 0x07e0a, 0x07e11, 0x07e08, 0x07e1b, 0x07e23, 0x07e1e, 0x07e1d, 0x07e09,
+    0x07e10, 0x07f79, 0x07fb2, 0x07ff0, 0x07ff1, 0x07fee, 0x08028, 0x081b3, 0x081a9, 0x081a8, 0x081fb, 0x08208,
+    0x08258, 0x08259, 0x0854a, 0x08559, 0x08548, 0x08568, 0x08569, 0x08543, 0x08549, 0x0856d, 0x0856a, 0x0855e,
+    0x08783, 0x0879f, 0x0879e, 0x087a2, 0x0878d, 0x08861, 0x0892a, 0x08932, 0x08925, 0x0892b, 0x08921, 0x089aa,
+    0x089a6, 0x08ae6, 0x08afa, 0x08aeb, 0x08af1, 0x08b00, 0x08adc, 0x08ae7, 0x08aee, 0x08afe, 0x08b01, 0x08b02,
+    0x08af7, 0x08aed, 0x08af3, 0x08af6, 0x08afc, 0x08c6b, 0x08c6d, 0x08c93, 0x08cf4, 0x08e44, 0x08e31, 0x08e34,
+    0x08e42, 0x08e39, 0x08e35, 0x08f3b, 0x08f2f, 0x08f38, 0x08f33, 0x08fa8, 0x08fa6, 0x09075, 0x09074, 0x09078,
+    0x09072, 0x0907c, 0x0907a, 0x09134, 0x09192, 0x09320, 0x09336, 0x092f8, 0x09333, 0x0932f, 0x09322, 0x092fc,
+    0x0932b, 0x09304, 0x0931a, 0x09310, 0x09326, 0x09321, 0x09315, 0x0932e, 0x09319, 0x095bb, 0x096a7, 0x096a8,
+    0x096aa, 0x096d5, 0x0970e, 0x09711, 0x09716, 0x0970d, 0x09713, 0x0970f, 0x0975b, 0x0975c, 0x09766, 0x09798,
+    0x09830, 0x09838, 0x0983b, 0x09837, 0x0982d, 0x09839, 0x09824, 0x09910, 0x09928, 0x0991e, 0x0991b, 0x09921,
+    0x0991a, 0x099ed, 0x099e2, 0x099f1, 0x09ab8, 0x09abc, 0x09afb, 0x09aed, 0x09b28, 0x09b91, 0x09d15, 0x09d23,
+    0x09d26, 0x09d28, 0x09d12, 0x09d1b, 0x09ed8, 0x09ed4, 0x09f8d, 0x09f9c, 0x0512a, 0x0511f, 0x05121, 0x05132,
+    0x052f5, 0x0568e, 0x05680, 0x05690, 0x05685, 0x05687, 0x0568f, 0x058d5, 0x058d3, 0x058d1, 0x058ce, 0x05b30,
+    0x05b2a, 0x05b24, 0x05b7a, 0x05c37, 0x05c68, 0x05dbc, 0x05dba, 0x05dbd, 0x05db8, 0x05e6b, 0x05f4c, 0x05fbd,
+    0x061c9, 0x061c2, 0x061c7, 0x061e6, 0x061cb, 0x06232, 0x06234, 0x064ce, 0x064ca, 0x064d8, 0x064e0, 0x064f0,
+    0x064e6, 0x064ec, 0x064f1, 0x064e2, 0x064ed, 0x06582, 0x06583, 0x066d9, 0x066d6, 0x06a80, 0x06a94, 0x06a84,
+    0x06aa2, 0x06a9c, 0x06adb, 0x06aa3, 0x06a7e, 0x06a97, 0x06a90, 0x06aa0, 0x06b5c, 0x06bae, 0x06bda, 0x06c08,
+    0x06fd8, 0x06ff1, 0x06fdf, 0x06fe0, 0x06fdb, 0x06fe4, 0x06feb, 0x06fef, 0x06f80, 0x06fec, 0x06fe1, 0x06fe9,
+    0x06fd5, 0x06fee, 0x06ff0, 0x071e7, 0x071df, 0x071ee, 0x071e6, 0x071e5, 0x071ed, 0x071ec, 0x071f4, 0x071e0,
+    0x07235, 0x07246, 0x07370, 0x07372, 0x074a9, 0x074b0, 0x074a6, 0x074a8, 0x07646, 0x07642, 0x0764c, 0x076ea,
+    0x077b3, 0x077aa, 0x077b0, 0x077ac, 0x077a7, 0x077ad, 0x077ef, 0x078f7, 0x078fa, 0x078f4, 0x078ef, 0x07901,
+    0x079a7, 0x079aa, 0x07a57, 0x07abf, 0x07c07, 0x07c0d, 0x07bfe, 0x07bf7, 0x07c0c, 0x07be0, 0x07ce0, 0x07cdc,
+    0x07cde, 0x07ce2, 0x07cdf, 0x07cd9, 0x07cdd, 0x07e2e, 0x07e3e, 0x07e46, 0x07e37, 0x07e32, 0x07e43, 0x07e2b,
+    0x07e3d, 0x07e31, 0x07e45, 0x07e41, 0x07e34, 0x07e39, 0x07e48, 0x07e35, 0x07e3f, 0x07e2f, 0x07f44, 0x07ff3,
+    0x07ffc, 0x08071, 0x08072, 0x08070, 0x0806f, 0x08073, 0x081c6, 0x081c3, 0x081ba, 0x081c2, 0x081c0, 0x081bf,
+    0x081bd, 0x081c9, 0x081be, 0x081e8, 0x08209, 0x08271, 0x085aa, 0x08584, 0x0857e, 0x0859c, 0x08591, 0x08594,
+    0x085af, 0x0859b, 0x08587, 0x085a8, 0x0858a, 0x08667, 0x087c0, 0x087d1, 0x087b3, 0x087d2, 0x087c6, 0x087ab,
+    0x087bb, 0x087ba, 0x087c8, 0x087cb, 0x0893b, 0x08936, 0x08944, 0x08938, 0x0893d, 0x089ac, 0x08b0e, 0x08b17,
+    0x08b19, 0x08b1b, 0x08b0a, 0x08b20, 0x08b1d, 0x08b04, 0x08b10, 0x08c41, 0x08c3f, 0x08c73, 0x08cfa, 0x08cfd,
+    0x08cfc, 0x08cf8, 0x08cfb, 0x08da8, 0x08e49, 0x08e4b, 0x08e48, 0x08e4a, 0x08f44, 0x08f3e, 0x08f42, 0x08f45,
+    0x08f3f, 0x0907f, 0x0907d, 0x09084, 0x09081, 0x09082, 0x09080, 0x09139, 0x091a3, 0x0919e, 0x0919c, 0x0934d,
+    0x09382, 0x09328, 0x09375, 0x0934a, 0x09365, 0x0934b, 0x09318, 0x0937e, 0x0936c, 0x0935b, 0x09370, 0x0935a,
+    0x09354, 0x095ca, 0x095cb, 0x095cc, 0x095c8, 0x095c6, 0x096b1, 0x096b8, 0x096d6, 0x0971c, 0x0971e, 0x097a0,
+    0x097d3, 0x09846, 0x098b6, 0x09935, 0x09a01, 0x099ff, 0x09bae, 0x09bab, 0x09baa, 0x09bad, 0x09d3b, 0x09d3f,
+    0x09e8b, 0x09ecf, 0x09ede, 0x09edc, 0x09edd, 0x09edb, 0x09f3e, 0x09f4b, 0x053e2, 0x05695, 0x056ae, 0x058d9,
+    0x058d8, 0x05b38, 0x05f5d, 0x061e3, 0x06233, 0x064f4, 0x064f2, 0x064fe, 0x06506, 0x064fa, 0x064fb, 0x064f7,
+    0x065b7, 0x066dc, 0x06726, 0x06ab3, 0x06aac, 0x06ac3, 0x06abb, 0x06ab8, 0x06ac2, 0x06aae, 0x06aaf, 0x06b5f,
+    0x06b78, 0x06baf, 0x07009, 0x0700b, 0x06ffe, 0x07006, 0x06ffa, 0x07011, 0x0700f, 0x071fb, 0x071fc, 0x071fe,
+    0x071f8, 0x07377, 0x07375, 0x074a7, 0x074bf, 0x07515, 0x07656, 0x07658, 0x07652, 0x077bd, 0x077bf, 0x077bb,
+    0x077bc, 0x0790e, 0x079ae, 0x07a61, 0x07a62, 0x07a60, 0x07ac4, 0x07ac5, 0x07c2b, 0x07c27, 0x07c2a, 0x07c1e,
+    0x07c23, 0x07c21, 0x07ce7, 0x07e54, 0x07e55, 0x07e5e, 0x07e5a, 0x07e61, 0x07e52, 0x07e59, 0x07f48, 0x07ff9,
+    0x07ffb, 0x08077, 0x08076, 0x081cd, 0x081cf, 0x0820a, 0x085cf, 0x085a9, 0x085cd, 0x085d0, 0x085c9, 0x085b0,
+    0x085ba, 0x085b9, 0x085a6, 0x087ef, 0x087ec, 0x087f2, 0x087e0, 0x08986, 0x089b2, 0x089f4, 0x08b28, 0x08b39,
+    0x08b2c, 0x08b2b, 0x08c50, 0x08d05, 0x08e59, 0x08e63, 0x08e66, 0x08e64, 0x08e5f, 0x08e55, 0x08ec0, 0x08f49,
+    0x08f4d, 0x09087, 0x09083, 0x09088, 0x091ab, 0x091ac, 0x091d0, 0x09394, 0x0938a, 0x09396, 0x093a2, 0x093b3,
+    0x093ae, 0x093ac, 0x093b0, 0x09398, 0x0939a, 0x09397, 0x095d4, 0x095d6, 0x095d0, 0x095d5, 0x096e2, 0x096dc,
+    0x096d9, 0x096db, 0x096de, 0x09724, 0x097a3, 0x097a6, 0x097ad, 0x097f9, 0x0984d, 0x0984f, 0x0984c, 0x0984e,
+    0x09853, 0x098ba, 0x0993e, 0x0993f, 0x0993d, 0x0992e, 0x099a5, 0x09a0e, 0x09ac1, 0x09b03, 0x09b06, 0x09b4f,
+    0x09b4e, 0x09b4d, 0x09bca, 0x09bc9, 0x09bfd, 0x09bc8, 0x09bc0, 0x09d51, 0x09d5d, 0x09d60, 0x09ee0, 0x09f15,
+    0x09f2c, 0x05133, 0x056a5, 0x058de, 0x058df, 0x058e2, 0x05bf5, 0x09f90, 0x05eec, 0x061f2, 0x061f7, 0x061f6,
+    0x061f5, 0x06500, 0x0650f, 0x066e0, 0x066dd, 0x06ae5, 0x06add, 0x06ada, 0x06ad3, 0x0701b, 0x0701f, 0x07028,
+    0x0701a, 0x0701d, 0x07015, 0x07018, 0x07206, 0x0720d, 0x07258, 0x072a2, 0x07378, 0x0737a, 0x074bd, 0x074ca,
+    0x074e3, 0x07587, 0x07586, 0x0765f, 0x07661, 0x077c7, 0x07919, 0x079b1, 0x07a6b, 0x07a69, 0x07c3e, 0x07c3f,
+    0x07c38, 0x07c3d, 0x07c37, 0x07c40, 0x07e6b, 0x07e6d, 0x07e79, 0x07e69, 0x07e6a, 0x07f85, 0x07e73, 0x07fb6,
+    0x07fb9, 0x07fb8, 0x081d8, 0x085e9, 0x085dd, 0x085ea, 0x085d5, 0x085e4, 0x085e5, 0x085f7, 0x087fb, 0x08805,
+    0x0880d, 0x087f9, 0x087fe, 0x08960, 0x0895f, 0x08956, 0x0895e, 0x08b41, 0x08b5c, 0x08b58, 0x08b49, 0x08b5a,
+    0x08b4e, 0x08b4f, 0x08b46, 0x08b59, 0x08d08, 0x08d0a, 0x08e7c, 0x08e72, 0x08e87, 0x08e76, 0x08e6c, 0x08e7a,
+    0x08e74, 0x08f54, 0x08f4e, 0x08fad, 0x0908a, 0x0908b, 0x091b1, 0x091ae, 0x093e1, 0x093d1, 0x093df, 0x093c3,
+    0x093c8, 0x093dc, 0x093dd, 0x093d6, 0x093e2, 0x093cd, 0x093d8, 0x093e4, 0x093d7, 0x093e8, 0x095dc, 0x096b4,
+    0x096e3, 0x0972a, 0x09727, 0x09761, 0x097dc, 0x097fb, 0x0985e, 0x09858, 0x0985b, 0x098bc, 0x09945, 0x09949,
+    0x09a16, 0x09a19, 0x09b0d, 0x09be8, 0x09be7, 0x09bd6, 0x09bdb, 0x09d89, 0x09d61, 0x09d72, 0x09d6a, 0x09d6c,
+    0x09e92, 0x09e97, 0x09e93, 0x09eb4, 0x052f8, 0x056a8, 0x056b7, 0x056b6, 0x056b4, 0x056bc, 0x058e4, 0x05b40,
+    0x05b43, 0x05b7d, 0x05bf6, 0x05dc9, 0x061f8, 0x061fa, 0x06518, 0x06514, 0x06519, 0x066e6, 0x06727, 0x06aec,
+    0x0703e, 0x07030, 0x07032, 0x07210, 0x0737b, 0x074cf, 0x07662, 0x07665, 0x07926, 0x0792a, 0x0792c, 0x0792b,
+    0x07ac7, 0x07af6, 0x07c4c, 0x07c43, 0x07c4d, 0x07cef, 0x07cf0, 0x08fae, 0x07e7d, 0x07e7c, 0x07e82, 0x07f4c,
+    0x08000, 0x081da, 0x08266, 0x085fb, 0x085f9, 0x08611, 0x085fa, 0x08606, 0x0860b, 0x08607, 0x0860a, 0x08814,
+    0x08815, 0x08964, 0x089ba, 0x089f8, 0x08b70, 0x08b6c, 0x08b66, 0x08b6f, 0x08b5f, 0x08b6b, 0x08d0f, 0x08d0d,
+    0x08e89, 0x08e81, 0x08e85, 0x08e82, 0x091b4, 0x091cb, 0x09418, 0x09403, 0x093fd, 0x095e1, 0x09730, 0x098c4,
+    0x09952, 0x09951, 0x099a8, 0x09a2b, 0x09a30, 0x09a37, 0x09a35, 0x09c13, 0x09c0d, 0x09e79, 0x09eb5, 0x09ee8,
+    0x09f2f, 0x09f5f, 0x09f63, 0x09f61, 0x05137, 0x05138, 0x056c1, 0x056c0, 0x056c2, 0x05914, 0x05c6c, 0x05dcd,
+    0x061fc, 0x061fe, 0x0651d, 0x0651c, 0x06595, 0x066e9, 0x06afb, 0x06b04, 0x06afa, 0x06bb2, 0x0704c, 0x0721b,
+    0x072a7, 0x074d6, 0x074d4, 0x07669, 0x077d3, 0x07c50, 0x07e8f, 0x07e8c, 0x07fbc, 0x08617, 0x0862d, 0x0861a,
+    0x08823, 0x08822, 0x08821, 0x0881f, 0x0896a, 0x0896c, 0x089bd, 0x08b74, 0x08b77, 0x08b7d, 0x08d13, 0x08e8a,
+    0x08e8d, 0x08e8b, 0x08f5f, 0x08faf, 0x091ba, 0x0942e, 0x09433, 0x09435, 0x0943a, 0x09438, 0x09432, 0x0942b,
+    0x095e2, 0x09738, 0x09739, 0x09732, 0x097ff, 0x09867, 0x09865, 0x09957, 0x09a45, 0x09a43, 0x09a40, 0x09a3e,
+    0x09acf, 0x09b54, 0x09b51, 0x09c2d, 0x09c25, 0x09daf, 0x09db4, 0x09dc2, 0x09db8, 0x09e9d, 0x09eef, 0x09f19,
+    0x09f5c, 0x09f66, 0x09f67, 0x0513c, 0x0513b, 0x056c8, 0x056ca, 0x056c9, 0x05b7f, 0x05dd4, 0x05dd2, 0x05f4e,
+    0x061ff, 0x06524, 0x06b0a, 0x06b61, 0x07051, 0x07058, 0x07380, 0x074e4, 0x0758a, 0x0766e, 0x0766c, 0x079b3,
+    0x07c60, 0x07c5f, 0x0807e, 0x0807d, 0x081df, 0x08972, 0x0896f, 0x089fc, 0x08b80, 0x08d16, 0x08d17, 0x08e91,
+    0x08e93, 0x08f61, 0x09148, 0x09444, 0x09451, 0x09452, 0x0973d, 0x0973e, 0x097c3, 0x097c1, 0x0986b, 0x09955,
+    0x09a55, 0x09a4d, 0x09ad2, 0x09b1a, 0x09c49, 0x09c31, 0x09c3e, 0x09c3b, 0x09dd3, 0x09dd7, 0x09f34, 0x09f6c,
+    0x09f6a, 0x09f94, 0x056cc, 0x05dd6, 0x06200, 0x06523, 0x0652b, 0x0652a, 0x066ec, 0x06b10, 0x074da, 0x07aca,
+    0x07c64, 0x07c63, 0x07c65, 0x07e93, 0x07e96, 0x07e94, 0x081e2, 0x08638, 0x0863f, 0x08831, 0x08b8a, 0x09090,
+    0x0908f, 0x09463, 0x09460, 0x09464, 0x09768, 0x0986f, 0x0995c, 0x09a5a, 0x09a5b, 0x09a57, 0x09ad3, 0x09ad4,
+    0x09ad1, 0x09c54, 0x09c57, 0x09c56, 0x09de5, 0x09e9f, 0x09ef4, 0x056d1, 0x058e9, 0x0652c, 0x0705e, 0x07671,
+    0x07672, 0x077d7, 0x07f50, 0x07f88, 0x08836, 0x08839, 0x08862, 0x08b93, 0x08b92, 0x08b96, 0x08277, 0x08d1b,
+    0x091c0, 0x0946a, 0x09742, 0x09748, 0x09744, 0x097c6, 0x09870, 0x09a5f, 0x09b22, 0x09b58, 0x09c5f, 0x09df9,
+    0x09dfa, 0x09e7c, 0x09e7d, 0x09f07, 0x09f77, 0x09f72, 0x05ef3, 0x06b16, 0x07063, 0x07c6c, 0x07c6e, 0x0883b,
+    0x089c0, 0x08ea1, 0x091c1, 0x09472, 0x09470, 0x09871, 0x0995e, 0x09ad6, 0x09b23, 0x09ecc, 0x07064, 0x077da,
+    0x08b9a, 0x09477, 0x097c9, 0x09a62, 0x09a65, 0x07e9c, 0x08b9c, 0x08eaa, 0x091c5, 0x0947d, 0x0947e, 0x0947c,
+    0x09c77, 0x09c78, 0x09ef7, 0x08c54, 0x0947f, 0x09e1a, 0x07228, 0x09a6a, 0x09b31, 0x09e1b, 0x09e1e, 0x07c72,
+    0x02460, 0x02461, 0x02462, 0x02463, 0x02464, 0x02465, 0x02466, 0x02467, 0x02468, 0x02469, 0x02474, 0x02475,
+    0x02476, 0x02477, 0x02478, 0x02479, 0x0247a, 0x0247b, 0x0247c, 0x0247d, 0x02170, 0x02171, 0x02172, 0x02173,
+    0x02174, 0x02175, 0x02176, 0x02177, 0x02178, 0x02179, 0x04e36, 0x04e3f, 0x04e85, 0x04ea0, 0x05182, 0x05196,
+    0x051ab, 0x052f9, 0x05338, 0x05369, 0x053b6, 0x0590a, 0x05b80, 0x05ddb, 0x02f33, 0x05e7f, 0x00000, 0x05f50,
+    0x05f61, 0x06534, 0x00000, 0x07592, 0x00000, 0x08fb5, 0x00000, 0x000a8, 0x002c6, 0x030fd, 0x030fe, 0x0309d,
+    0x0309e, 0x00000, 0x00000, 0x03005, 0x03006, 0x03007, 0x030fc, 0x0ff3b, 0x0ff3d, 0x0273d, 0x03041, 0x03042,
+    0x03043, 0x03044, 0x03045, 0x03046, 0x03047, 0x03048, 0x03049, 0x0304a, 0x0304b, 0x0304c, 0x0304d, 0x0304e,
+    0x0304f, 0x03050, 0x03051, 0x03052, 0x03053, 0x03054, 0x03055, 0x03056, 0x03057, 0x03058, 0x03059, 0x0305a,
+    0x0305b, 0x0305c, 0x0305d, 0x0305e, 0x0305f, 0x03060, 0x03061, 0x03062, 0x03063, 0x03064, 0x03065, 0x03066,
+    0x03067, 0x03068, 0x03069, 0x0306a, 0x0306b, 0x0306c, 0x0306d, 0x0306e, 0x0306f, 0x03070, 0x03071, 0x03072,
+    0x03073, 0x03074, 0x03075, 0x03076, 0x03077, 0x03078, 0x03079, 0x0307a, 0x0307b, 0x0307c, 0x0307d, 0x0307e,
+    0x0307f, 0x03080, 0x03081, 0x03082, 0x03083, 0x03084, 0x03085, 0x03086, 0x03087, 0x03088, 0x03089, 0x0308a,
+    0x0308b, 0x0308c, 0x0308d, 0x0308e, 0x0308f, 0x03090, 0x03091, 0x03092, 0x03093, 0x030a1, 0x030a2, 0x030a3,
+    0x030a4, 0x030a5, 0x030a6, 0x030a7, 0x030a8, 0x030a9, 0x030aa, 0x030ab, 0x030ac, 0x030ad, 0x030ae, 0x030af,
+    0x030b0, 0x030b1, 0x030b2, 0x030b3, 0x030b4, 0x030b5, 0x030b6, 0x030b7, 0x030b8, 0x030b9, 0x030ba, 0x030bb,
+    0x030bc, 0x030bd, 0x030be, 0x030bf, 0x030c0, 0x030c1, 0x030c2, 0x030c3, 0x030c4, 0x030c5, 0x030c6, 0x030c7,
+    0x030c8, 0x030c9, 0x030ca, 0x030cb, 0x030cc, 0x030cd, 0x030ce, 0x030cf, 0x030d0, 0x030d1, 0x030d2, 0x030d3,
+    0x030d4, 0x030d5, 0x030d6, 0x030d7, 0x030d8, 0x030d9, 0x030da, 0x030db, 0x030dc, 0x030dd, 0x030de, 0x030df,
+    0x030e0, 0x030e1, 0x030e2, 0x030e3, 0x030e4, 0x030e5, 0x030e6, 0x030e7, 0x030e8, 0x030e9, 0x030ea, 0x030eb,
+    0x030ec, 0x030ed, 0x030ee, 0x030ef, 0x030f0, 0x030f1, 0x030f2, 0x030f3, 0x030f4, 0x030f5, 0x030f6, 0x00410,
+    0x00411, 0x00412, 0x00413, 0x00414, 0x00415, 0x00401, 0x00416, 0x00417, 0x00418, 0x00419, 0x0041a, 0x0041b,
+    0x0041c, 0x0041d, 0x0041e, 0x0041f, 0x00420, 0x00421, 0x00422, 0x00423, 0x00424, 0x00425, 0x00426, 0x00427,
+    0x00428, 0x00429, 0x0042a, 0x0042b, 0x0042c, 0x0042d, 0x0042e, 0x0042f, 0x00430, 0x00431, 0x00432, 0x00433,
+    0x00434, 0x00435, 0x00451, 0x00436, 0x00437, 0x00438, 0x00439, 0x0043a, 0x0043b, 0x0043c, 0x0043d, 0x0043e,
+    0x0043f, 0x00440, 0x00441, 0x00442, 0x00443, 0x00444, 0x00445, 0x00446, 0x00447, 0x00448, 0x00449, 0x0044a,
+    0x0044b, 0x0044c, 0x0044d, 0x0044e, 0x0044f, 0x021e7, 0x021b8, 0x021b9, 0x031cf, 0x200cc, 0x04e5a, 0x2008a,
+    0x05202, 0x04491, 0x09fb0, 0x05188, 0x09fb1, 0x27607, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000,
+    0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x00000, 0x0ffe2, 0x0ffe4,
+    0x0ff07, 0x0ff02, 0x03231, 0x02116, 0x02121, 0x0309b, 0x0309c, 0x02e80, 0x02e84, 0x02e86, 0x02e87, 0x02e88,
+    0x02e8a, 0x02e8c, 0x02e8d, 0x02e95, 0x02e9c, 0x02e9d, 0x02ea5, 0x02ea7, 0x02eaa, 0x02eac, 0x02eae, 0x02eb6,
+    0x02ebc, 0x02ebe, 0x02ec6, 0x02eca, 0x02ecc, 0x02ecd, 0x02ecf, 0x02ed6, 0x02ed7, 0x02ede, 0x02ee3, 0x00000,
+    0x00000, 0x00000, 0x00283, 0x00250, 0x0025b, 0x00254, 0x00275, 0x00153, 0x000f8, 0x0014b, 0x0028a, 0x0026a,
+    0x04e42, 0x04e5c, 0x051f5, 0x0531a, 0x05382, 0x04e07, 0x04e0c, 0x04e47, 0x04e8d, 0x056d7, 0x0fa0c, 0x05c6e,
+    0x05f73, 0x04e0f, 0x05187, 0x04e0e, 0x04e2e, 0x04e93, 0x04ec2, 0x04ec9, 0x04ec8, 0x05198, 0x052fc, 0x0536c,
+    0x053b9, 0x05720, 0x05903, 0x0592c, 0x05c10, 0x05dff, 0x065e1, 0x06bb3, 0x06bcc, 0x06c14, 0x0723f, 0x04e31,
+    0x04e3c, 0x04ee8, 0x04edc, 0x04ee9, 0x04ee1, 0x04edd, 0x04eda, 0x0520c, 0x0531c, 0x0534c, 0x05722, 0x05723,
+    0x05917, 0x0592f, 0x05b81, 0x05b84, 0x05c12, 0x05c3b, 0x05c74, 0x05c73, 0x05e04, 0x05e80, 0x05e82, 0x05fc9,
+    0x06209, 0x06250, 0x06c15, 0x06c36, 0x06c43, 0x06c3f, 0x06c3b, 0x072ae, 0x072b0, 0x0738a, 0x079b8, 0x0808a,
+    0x0961e, 0x04f0e, 0x04f18, 0x04f2c, 0x04ef5, 0x04f14, 0x04ef1, 0x04f00, 0x04ef7, 0x04f08, 0x04f1d, 0x04f02,
+    0x04f05, 0x04f22, 0x04f13, 0x04f04, 0x04ef4, 0x04f12, 0x051b1, 0x05213, 0x05209, 0x05210, 0x052a6, 0x05322,
+    0x0531f, 0x0534d, 0x0538a, 0x05407, 0x056e1, 0x056df, 0x0572e, 0x0572a, 0x05734, 0x0593c, 0x05980, 0x0597c,
+    0x05985, 0x0597b, 0x0597e, 0x05977, 0x0597f, 0x05b56, 0x05c15, 0x05c25, 0x05c7c, 0x05c7a, 0x05c7b, 0x05c7e,
+    0x05ddf, 0x05e75, 0x05e84, 0x05f02, 0x05f1a, 0x05f74, 0x05fd5, 0x05fd4, 0x05fcf, 0x0625c, 0x0625e, 0x06264,
+    0x06261, 0x06266, 0x06262, 0x06259, 0x06260, 0x0625a, 0x06265, 0x065ef, 0x065ee, 0x0673e, 0x06739, 0x06738,
+    0x0673b, 0x0673a, 0x0673f, 0x0673c, 0x06733, 0x06c18, 0x06c46, 0x06c52, 0x06c5c, 0x06c4f, 0x06c4a, 0x06c54,
+    0x06c4b, 0x06c4c, 0x07071, 0x0725e, 0x072b4, 0x072b5, 0x0738e, 0x0752a, 0x0767f, 0x07a75, 0x07f51, 0x08278,
+    0x0827c, 0x08280, 0x0827d, 0x0827f, 0x0864d, 0x0897e, 0x09099, 0x09097, 0x09098, 0x0909b, 0x09094, 0x09622,
+    0x09624, 0x09620, 0x09623, 0x04f56, 0x04f3b, 0x04f62, 0x04f49, 0x04f53, 0x04f64, 0x04f3e, 0x04f67, 0x04f52,
+    0x04f5f, 0x04f41, 0x04f58, 0x04f2d, 0x04f33, 0x04f3f, 0x04f61, 0x0518f, 0x051b9, 0x0521c, 0x0521e, 0x05221,
+    0x052ad, 0x052ae, 0x05309, 0x05363, 0x05372, 0x0538e, 0x0538f, 0x05430, 0x05437, 0x0542a, 0x05454, 0x05445,
+    0x05419, 0x0541c, 0x05425, 0x05418, 0x0543d, 0x0544f, 0x05441, 0x05428, 0x05424, 0x05447, 0x056ee, 0x056e7,
+    0x056e5, 0x05741, 0x05745, 0x0574c, 0x05749, 0x0574b, 0x05752, 0x05906, 0x05940, 0x059a6, 0x05998, 0x059a0,
+    0x05997, 0x0598e, 0x059a2, 0x05990, 0x0598f, 0x059a7, 0x059a1, 0x05b8e, 0x05b92, 0x05c28, 0x05c2a, 0x05c8d,
+    0x05c8f, 0x05c88, 0x05c8b, 0x05c89, 0x05c92, 0x05c8a, 0x05c86, 0x05c93, 0x05c95, 0x05de0, 0x05e0a, 0x05e0e,
+    0x05e8b, 0x05e89, 0x05e8c, 0x05e88, 0x05e8d, 0x05f05, 0x05f1d, 0x05f78, 0x05f76, 0x05fd2, 0x05fd1, 0x05fd0,
+    0x05fed, 0x05fe8, 0x05fee, 0x05ff3, 0x05fe1, 0x05fe4, 0x05fe3, 0x05ffa, 0x05fef, 0x05ff7, 0x05ffb, 0x06000,
+    0x05ff4, 0x0623a, 0x06283, 0x0628c, 0x0628e, 0x0628f, 0x06294, 0x06287, 0x06271, 0x0627b, 0x0627a, 0x06270,
+    0x06281, 0x06288, 0x06277, 0x0627d, 0x06272, 0x06274, 0x06537, 0x065f0, 0x065f4, 0x065f3, 0x065f2, 0x065f5,
+    0x06745, 0x06747, 0x06759, 0x06755, 0x0674c, 0x06748, 0x0675d, 0x0674d, 0x0675a, 0x0674b, 0x06bd0, 0x06c19,
+    0x06c1a, 0x06c78, 0x06c67, 0x06c6b, 0x06c84, 0x06c8b, 0x06c8f, 0x06c71, 0x06c6f, 0x06c69, 0x06c9a, 0x06c6d,
+    0x06c87, 0x06c95, 0x06c9c, 0x06c66, 0x06c73, 0x06c65, 0x06c7b, 0x06c8e, 0x07074, 0x0707a, 0x07263, 0x072bf,
+    0x072bd, 0x072c3, 0x072c6, 0x072c1, 0x072ba, 0x072c5, 0x07395, 0x07397, 0x07393, 0x07394, 0x07392, 0x0753a,
+    0x07539, 0x07594, 0x07595, 0x07681, 0x0793d, 0x08034, 0x08095, 0x08099, 0x08090, 0x08092, 0x0809c, 0x08290,
+    0x0828f, 0x08285, 0x0828e, 0x08291, 0x08293, 0x0828a, 0x08283, 0x08284, 0x08c78, 0x08fc9, 0x08fbf, 0x0909f,
+    0x090a1, 0x090a5, 0x0909e, 0x090a7, 0x090a0, 0x09630, 0x09628, 0x0962f, 0x0962d, 0x04e33, 0x04f98, 0x04f7c,
+    0x04f85, 0x04f7d, 0x04f80, 0x04f87, 0x04f76, 0x04f74, 0x04f89, 0x04f84, 0x04f77, 0x04f4c, 0x04f97, 0x04f6a,
+    0x04f9a, 0x04f79, 0x04f81, 0x04f78, 0x04f90, 0x04f9c, 0x04f94, 0x04f9e, 0x04f92, 0x04f82, 0x04f95, 0x04f6b,
+    0x04f6e, 0x0519e, 0x051bc, 0x051be, 0x05235, 0x05232, 0x05233, 0x05246, 0x05231, 0x052bc, 0x0530a, 0x0530b,
+    0x0533c, 0x05392, 0x05394, 0x05487, 0x0547f, 0x05481, 0x05491, 0x05482, 0x05488, 0x0546b, 0x0547a, 0x0547e,
+    0x05465, 0x0546c, 0x05474, 0x05466, 0x0548d, 0x0546f, 0x05461, 0x05460, 0x05498, 0x05463, 0x05467, 0x05464,
+    0x056f7, 0x056f9, 0x0576f, 0x05772, 0x0576d, 0x0576b, 0x05771, 0x05770, 0x05776, 0x05780, 0x05775, 0x0577b,
+    0x05773, 0x05774, 0x05762, 0x05768, 0x0577d, 0x0590c, 0x05945, 0x059b5, 0x059ba, 0x059cf, 0x059ce, 0x059b2,
+    0x059cc, 0x059c1, 0x059b6, 0x059bc, 0x059c3, 0x059d6, 0x059b1, 0x059bd, 0x059c0, 0x059c8, 0x059b4, 0x059c7,
+    0x05b62, 0x05b65, 0x05b93, 0x05b95, 0x05c44, 0x05c47, 0x05cae, 0x05ca4, 0x05ca0, 0x05cb5, 0x05caf, 0x05ca8,
+    0x05cac, 0x05c9f, 0x05ca3, 0x05cad, 0x05ca2, 0x05caa, 0x05ca7, 0x05c9d, 0x05ca5, 0x05cb6, 0x05cb0, 0x05ca6,
+    0x05e17, 0x05e14, 0x05e19, 0x05f28, 0x05f22, 0x05f23, 0x05f24, 0x05f54, 0x05f82, 0x05f7e, 0x05f7d, 0x05fde,
+    0x05fe5, 0x0602d, 0x06026, 0x06019, 0x06032, 0x0600b, 0x06034, 0x0600a, 0x06017, 0x06033, 0x0601a, 0x0601e,
+    0x0602c, 0x06022, 0x0600d, 0x06010, 0x0602e, 0x06013, 0x06011, 0x0600c, 0x06009, 0x0601c, 0x06214, 0x0623d,
+    0x062ad, 0x062b4, 0x062d1, 0x062be, 0x062aa, 0x062b6, 0x062ca, 0x062ae, 0x062b3, 0x062af, 0x062bb, 0x062a9,
+    0x062b0, 0x062b8, 0x0653d, 0x065a8, 0x065bb, 0x06609, 0x065fc, 0x06604, 0x06612, 0x06608, 0x065fb, 0x06603,
+    0x0660b, 0x0660d, 0x06605, 0x065fd, 0x06611, 0x06610, 0x066f6, 0x0670a, 0x06785, 0x0676c, 0x0678e, 0x06792,
+    0x06776, 0x0677b, 0x06798, 0x06786, 0x06784, 0x06774, 0x0678d, 0x0678c, 0x0677a, 0x0679f, 0x06791, 0x06799,
+    0x06783, 0x0677d, 0x06781, 0x06778, 0x06779, 0x06794, 0x06b25, 0x06b80, 0x06b7e, 0x06bde, 0x06c1d, 0x06c93,
+    0x06cec, 0x06ceb, 0x06cee, 0x06cd9, 0x06cb6, 0x06cd4, 0x06cad, 0x06ce7, 0x06cb7, 0x06cd0, 0x06cc2, 0x06cba,
+    0x06cc3, 0x06cc6, 0x06ced, 0x06cf2, 0x06cd2, 0x06cdd, 0x06cb4, 0x06c8a, 0x06c9d, 0x06c80, 0x06cde, 0x06cc0,
+    0x06d30, 0x06ccd, 0x06cc7, 0x06cb0, 0x06cf9, 0x06ccf, 0x06ce9, 0x06cd1, 0x07094, 0x07098, 0x07085, 0x07093,
+    0x07086, 0x07084, 0x07091, 0x07096, 0x07082, 0x0709a, 0x07083, 0x0726a, 0x072d6, 0x072cb, 0x072d8, 0x072c9,
+    0x072dc, 0x072d2, 0x072d4, 0x072da, 0x072cc, 0x072d1, 0x073a4, 0x073a1, 0x073ad, 0x073a6, 0x073a2, 0x073a0,
+    0x073ac, 0x0739d, 0x074dd, 0x074e8, 0x0753f, 0x07540, 0x0753e, 0x0758c, 0x07598, 0x076af, 0x076f3, 0x076f1,
+    0x076f0, 0x076f5, 0x077f8, 0x077fc, 0x077f9, 0x077fb, 0x077fa, 0x077f7, 0x07942, 0x0793f, 0x079c5, 0x07a78,
+    0x07a7b, 0x07afb, 0x07c75, 0x07cfd, 0x08035, 0x0808f, 0x080ae, 0x080a3, 0x080b8, 0x080b5, 0x080ad, 0x08220,
+    0x082a0, 0x082c0, 0x082ab, 0x0829a, 0x08298, 0x0829b, 0x082b5, 0x082a7, 0x082ae, 0x082bc, 0x0829e, 0x082ba,
+    0x082b4, 0x082a8, 0x082a1, 0x082a9, 0x082c2, 0x082a4, 0x082c3, 0x082b6, 0x082a2, 0x08670, 0x0866f, 0x0866d,
+    0x0866e, 0x08c56, 0x08fd2, 0x08fcb, 0x08fd3, 0x08fcd, 0x08fd6, 0x08fd5, 0x08fd7, 0x090b2, 0x090b4, 0x090af,
+    0x090b3, 0x090b0, 0x09639, 0x0963d, 0x0963c, 0x0963a, 0x09643, 0x04fcd, 0x04fc5, 0x04fd3, 0x04fb2, 0x04fc9,
+    0x04fcb, 0x04fc1, 0x04fd4, 0x04fdc, 0x04fd9, 0x04fbb, 0x04fb3, 0x04fdb, 0x04fc7, 0x04fd6, 0x04fba, 0x04fc0,
+    0x04fb9, 0x04fec, 0x05244, 0x05249, 0x052c0, 0x052c2, 0x0533d, 0x0537c, 0x05397, 0x05396, 0x05399, 0x05398,
+    0x054ba, 0x054a1, 0x054ad, 0x054a5, 0x054cf, 0x054c3, 0x0830d, 0x054b7, 0x054ae, 0x054d6, 0x054b6, 0x054c5,
+    0x054c6, 0x054a0, 0x05470, 0x054bc, 0x054a2, 0x054be, 0x05472, 0x054de, 0x054b0, 0x057b5, 0x0579e, 0x0579f,
+    0x057a4, 0x0578c, 0x05797, 0x0579d, 0x0579b, 0x05794, 0x05798, 0x0578f, 0x05799, 0x057a5, 0x0579a, 0x05795,
+    0x058f4, 0x0590d, 0x05953, 0x059e1, 0x059de, 0x059ee, 0x05a00, 0x059f1, 0x059dd, 0x059fa, 0x059fd, 0x059fc,
+    0x059f6, 0x059e4, 0x059f2, 0x059f7, 0x059db, 0x059e9, 0x059f3, 0x059f5, 0x059e0, 0x059fe, 0x059f4, 0x059ed,
+    0x05ba8, 0x05c4c, 0x05cd0, 0x05cd8, 0x05ccc, 0x05cd7, 0x05ccb, 0x05cdb, 0x05cde, 0x05cda, 0x05cc9, 0x05cc7,
+    0x05cca, 0x05cd6, 0x05cd3, 0x05cd4, 0x05ccf, 0x05cc8, 0x05cc6, 0x05cce, 0x05cdf, 0x05cf8, 0x05df9, 0x05e21,
+    0x05e22, 0x05e23, 0x05e20, 0x05e24, 0x05eb0, 0x05ea4, 0x05ea2, 0x05e9b, 0x05ea3, 0x05ea5, 0x05f07, 0x05f2e,
+    0x05f56, 0x05f86, 0x06037, 0x06039, 0x06054, 0x06072, 0x0605e, 0x06045, 0x06053, 0x06047, 0x06049, 0x0605b,
+    0x0604c, 0x06040, 0x06042, 0x0605f, 0x06024, 0x06044, 0x06058, 0x06066, 0x0606e, 0x06242, 0x06243, 0x062cf,
+    0x0630d, 0x0630b, 0x062f5, 0x0630e, 0x06303, 0x062eb, 0x062f9, 0x0630f, 0x0630c, 0x062f8, 0x062f6, 0x06300,
+    0x06313, 0x06314, 0x062fa, 0x06315, 0x062fb, 0x062f0, 0x06541, 0x06543, 0x065aa, 0x065bf, 0x06636, 0x06621,
+    0x06632, 0x06635, 0x0661c, 0x06626, 0x06622, 0x06633, 0x0662b, 0x0663a, 0x0661d, 0x06634, 0x06639, 0x0662e,
+    0x0670f, 0x06710, 0x067c1, 0x067f2, 0x067c8, 0x067ba, 0x067dc, 0x067bb, 0x067f8, 0x067d8, 0x067c0, 0x067b7,
+    0x067c5, 0x067eb, 0x067e4, 0x067df, 0x067b5, 0x067cd, 0x067b3, 0x067f7, 0x067f6, 0x067ee, 0x067e3, 0x067c2,
+    0x067b9, 0x067ce, 0x067e7, 0x067f0, 0x067b2, 0x067fc, 0x067c6, 0x067ed, 0x067cc, 0x067ae, 0x067e6, 0x067db,
+    0x067fa, 0x067c9, 0x067ca, 0x067c3, 0x067ea, 0x067cb, 0x06b28, 0x06b82, 0x06b84, 0x06bb6, 0x06bd6, 0x06bd8,
+    0x06be0, 0x06c20, 0x06c21, 0x06d28, 0x06d34, 0x06d2d, 0x06d1f, 0x06d3c, 0x06d3f, 0x06d12, 0x06d0a, 0x06cda,
+    0x06d33, 0x06d04, 0x06d19, 0x06d3a, 0x06d1a, 0x06d11, 0x06d00, 0x06d1d, 0x06d42, 0x06d01, 0x06d18, 0x06d37,
+    0x06d03, 0x06d0f, 0x06d40, 0x06d07, 0x06d20, 0x06d2c, 0x06d08, 0x06d22, 0x06d09, 0x06d10, 0x070b7, 0x0709f,
+    0x070be, 0x070b1, 0x070b0, 0x070a1, 0x070b4, 0x070b5, 0x070a9, 0x07241, 0x07249, 0x0724a, 0x0726c, 0x07270,
+    0x07273, 0x0726e, 0x072ca, 0x072e4, 0x072e8, 0x072eb, 0x072df, 0x072ea, 0x072e6, 0x072e3, 0x07385, 0x073cc,
+    0x073c2, 0x073c8, 0x073c5, 0x073b9, 0x073b6, 0x073b5, 0x073b4, 0x073eb, 0x073bf, 0x073c7, 0x073be, 0x073c3,
+    0x073c6, 0x073b8, 0x073cb, 0x074ec, 0x074ee, 0x0752e, 0x07547, 0x07548, 0x075a7, 0x075aa, 0x07679, 0x076c4,
+    0x07708, 0x07703, 0x07704, 0x07705, 0x0770a, 0x076f7, 0x076fb, 0x076fa, 0x077e7, 0x077e8, 0x07806, 0x07811,
+    0x07812, 0x07805, 0x07810, 0x0780f, 0x0780e, 0x07809, 0x07803, 0x07813, 0x0794a, 0x0794c, 0x0794b, 0x07945,
+    0x07944, 0x079d5, 0x079cd, 0x079cf, 0x079d6, 0x079ce, 0x07a80, 0x07a7e, 0x07ad1, 0x07b00, 0x07b01, 0x07c7a,
+    0x07c78, 0x07c79, 0x07c7f, 0x07c80, 0x07c81, 0x07d03, 0x07d08, 0x07d01, 0x07f58, 0x07f91, 0x07f8d, 0x07fbe,
+    0x08007, 0x0800e, 0x0800f, 0x08014, 0x08037, 0x080d8, 0x080c7, 0x080e0, 0x080d1, 0x080c8, 0x080c2, 0x080d0,
+    0x080c5, 0x080e3, 0x080d9, 0x080dc, 0x080ca, 0x080d5, 0x080c9, 0x080cf, 0x080d7, 0x080e6, 0x080cd, 0x081ff,
+    0x08221, 0x08294, 0x082d9, 0x082fe, 0x082f9, 0x08307, 0x082e8, 0x08300, 0x082d5, 0x0833a, 0x082eb, 0x082d6,
+    0x082f4, 0x082ec, 0x082e1, 0x082f2, 0x082f5, 0x0830c, 0x082fb, 0x082f6, 0x082f0, 0x082ea, 0x082e4, 0x082e0,
+    0x082fa, 0x082f3, 0x082ed, 0x08677, 0x08674, 0x0867c, 0x08673, 0x08841, 0x0884e, 0x08867, 0x0886a, 0x08869,
+    0x089d3, 0x08a04, 0x08a07, 0x08d72, 0x08fe3, 0x08fe1, 0x08fee, 0x08fe0, 0x090f1, 0x090bd, 0x090bf, 0x090d5,
+    0x090c5, 0x090be, 0x090c7, 0x090cb, 0x090c8, 0x091d4, 0x091d3, 0x09654, 0x0964f, 0x09651, 0x09653, 0x0964a,
+    0x0964e, 0x0501e, 0x05005, 0x05007, 0x05013, 0x05022, 0x05030, 0x0501b, 0x04ff5, 0x04ff4, 0x05033, 0x05037,
+    0x0502c, 0x04ff6, 0x04ff7, 0x05017, 0x0501c, 0x05020, 0x05027, 0x05035, 0x0502f, 0x05031, 0x0500e, 0x0515a,
+    0x05194, 0x05193, 0x051ca, 0x051c4, 0x051c5, 0x051c8, 0x051ce, 0x05261, 0x0525a, 0x05252, 0x0525e, 0x0525f,
+    0x05255, 0x05262, 0x052cd, 0x0530e, 0x0539e, 0x05526, 0x054e2, 0x05517, 0x05512, 0x054e7, 0x054f3, 0x054e4,
+    0x0551a, 0x054ff, 0x05504, 0x05508, 0x054eb, 0x05511, 0x05505, 0x054f1, 0x0550a, 0x054fb, 0x054f7, 0x054f8,
+    0x054e0, 0x0550e, 0x05503, 0x0550b, 0x05701, 0x05702, 0x057cc, 0x05832, 0x057d5, 0x057d2, 0x057ba, 0x057c6,
+    0x057bd, 0x057bc, 0x057b8, 0x057b6, 0x057bf, 0x057c7, 0x057d0, 0x057b9, 0x057c1, 0x0590e, 0x0594a, 0x05a19,
+    0x05a16, 0x05a2d, 0x05a2e, 0x05a15, 0x05a0f, 0x05a17, 0x05a0a, 0x05a1e, 0x05a33, 0x05b6c, 0x05ba7, 0x05bad,
+    0x05bac, 0x05c03, 0x05c56, 0x05c54, 0x05cec, 0x05cff, 0x05cee, 0x05cf1, 0x05cf7, 0x05d00, 0x05cf9, 0x05e29,
+    0x05e28, 0x05ea8, 0x05eae, 0x05eaa, 0x05eac, 0x05f33, 0x05f30, 0x05f67, 0x0605d, 0x0605a, 0x06067, 0x06041,
+    0x060a2, 0x06088, 0x06080, 0x06092, 0x06081, 0x0609d, 0x06083, 0x06095, 0x0609b, 0x06097, 0x06087, 0x0609c,
+    0x0608e, 0x06219, 0x06246, 0x062f2, 0x06310, 0x06356, 0x0632c, 0x06344, 0x06345, 0x06336, 0x06343, 0x063e4,
+    0x06339, 0x0634b, 0x0634a, 0x0633c, 0x06329, 0x06341, 0x06334, 0x06358, 0x06354, 0x06359, 0x0632d, 0x06347,
+    0x06333, 0x0635a, 0x06351, 0x06338, 0x06357, 0x06340, 0x06348, 0x0654a, 0x06546, 0x065c6, 0x065c3, 0x065c4,
+    0x065c2, 0x0664a, 0x0665f, 0x06647, 0x06651, 0x06712, 0x06713, 0x0681f, 0x0681a, 0x06849, 0x06832, 0x06833,
+    0x0683b, 0x0684b, 0x0684f, 0x06816, 0x06831, 0x0681c, 0x06835, 0x0682b, 0x0682d, 0x0682f, 0x0684e, 0x06844,
+    0x06834, 0x0681d, 0x06812, 0x06814, 0x06826, 0x06828, 0x0682e, 0x0684d, 0x0683a, 0x06825, 0x06820, 0x06b2c,
+    0x06b2f, 0x06b2d, 0x06b31, 0x06b34, 0x06b6d, 0x08082, 0x06b88, 0x06be6, 0x06be4, 0x06be8, 0x06be3, 0x06be2,
+    0x06be7, 0x06c25, 0x06d7a, 0x06d63, 0x06d64, 0x06d76, 0x06d0d, 0x06d61, 0x06d92, 0x06d58, 0x06d62, 0x06d6d,
+    0x06d6f, 0x06d91, 0x06d8d, 0x06def, 0x06d7f, 0x06d86, 0x06d5e, 0x06d67, 0x06d60, 0x06d97, 0x06d70, 0x06d7c,
+    0x06d5f, 0x06d82, 0x06d98, 0x06d2f, 0x06d68, 0x06d8b, 0x06d7e, 0x06d80, 0x06d84, 0x06d16, 0x06d83, 0x06d7b,
+    0x06d7d, 0x06d75, 0x06d90, 0x070dc, 0x070d3, 0x070d1, 0x070dd, 0x070cb, 0x07f39, 0x070e2, 0x070d7, 0x070d2,
+    0x070de, 0x070e0, 0x070d4, 0x070cd, 0x070c5, 0x070c6, 0x070c7, 0x070da, 0x070ce, 0x070e1, 0x07242, 0x07278,
+    0x07277, 0x07276, 0x07300, 0x072fa, 0x072f4, 0x072fe, 0x072f6, 0x072f3, 0x072fb, 0x07301, 0x073d3, 0x073d9,
+    0x073e5, 0x073d6, 0x073bc, 0x073e7, 0x073e3, 0x073e9, 0x073dc, 0x073d2, 0x073db, 0x073d4, 0x073dd, 0x073da,
+    0x073d7, 0x073d8, 0x073e8, 0x074de, 0x074df, 0x074f4, 0x074f5, 0x07521, 0x0755b, 0x0755f, 0x075b0, 0x075c1,
+    0x075bb, 0x075c4, 0x075c0, 0x075bf, 0x075b6, 0x075ba, 0x0768a, 0x076c9, 0x0771d, 0x0771b, 0x07710, 0x07713,
+    0x07712, 0x07723, 0x07711, 0x07715, 0x07719, 0x0771a, 0x07722, 0x07727, 0x07823, 0x0782c, 0x07822, 0x07835,
+    0x0782f, 0x07828, 0x0782e, 0x0782b, 0x07821, 0x07829, 0x07833, 0x0782a, 0x07831, 0x07954, 0x0795b, 0x0794f,
+    0x0795c, 0x07953, 0x07952, 0x07951, 0x079eb, 0x079ec, 0x079e0, 0x079ee, 0x079ed, 0x079ea, 0x079dc, 0x079de,
+    0x079dd, 0x07a86, 0x07a89, 0x07a85, 0x07a8b, 0x07a8c, 0x07a8a, 0x07a87, 0x07ad8, 0x07b10, 0x07b04, 0x07b13,
+    0x07b05, 0x07b0f, 0x07b08, 0x07b0a, 0x07b0e, 0x07b09, 0x07b12, 0x07c84, 0x07c91, 0x07c8a, 0x07c8c, 0x07c88,
+    0x07c8d, 0x07c85, 0x07d1e, 0x07d1d, 0x07d11, 0x07d0e, 0x07d18, 0x07d16, 0x07d13, 0x07d1f, 0x07d12, 0x07d0f,
+    0x07d0c, 0x07f5c, 0x07f61, 0x07f5e, 0x07f60, 0x07f5d, 0x07f5b, 0x07f96, 0x07f92, 0x07fc3, 0x07fc2, 0x07fc0,
+    0x08016, 0x0803e, 0x08039, 0x080fa, 0x080f2, 0x080f9, 0x080f5, 0x08101, 0x080fb, 0x08100, 0x08201, 0x0822f,
+    0x08225, 0x08333, 0x0832d, 0x08344, 0x08319, 0x08351, 0x08325, 0x08356, 0x0833f, 0x08341, 0x08326, 0x0831c,
+    0x08322, 0x08342, 0x0834e, 0x0831b, 0x0832a, 0x08308, 0x0833c, 0x0834d, 0x08316, 0x08324, 0x08320, 0x08337,
+    0x0832f, 0x08329, 0x08347, 0x08345, 0x0834c, 0x08353, 0x0831e, 0x0832c, 0x0834b, 0x08327, 0x08348, 0x08653,
+    0x08652, 0x086a2, 0x086a8, 0x08696, 0x0868d, 0x08691, 0x0869e, 0x08687, 0x08697, 0x08686, 0x0868b, 0x0869a,
+    0x08685, 0x086a5, 0x08699, 0x086a1, 0x086a7, 0x08695, 0x08698, 0x0868e, 0x0869d, 0x08690, 0x08694, 0x08843,
+    0x08844, 0x0886d, 0x08875, 0x08876, 0x08872, 0x08880, 0x08871, 0x0887f, 0x0886f, 0x08883, 0x0887e, 0x08874,
+    0x0887c, 0x08a12, 0x08c47, 0x08c57, 0x08c7b, 0x08ca4, 0x08ca3, 0x08d76, 0x08d78, 0x08db5, 0x08db7, 0x08db6,
+    0x08ed1, 0x08ed3, 0x08ffe, 0x08ff5, 0x09002, 0x08fff, 0x08ffb, 0x09004, 0x08ffc, 0x08ff6, 0x090d6, 0x090e0,
+    0x090d9, 0x090da, 0x090e3, 0x090df, 0x090e5, 0x090d8, 0x090db, 0x090d7, 0x090dc, 0x090e4, 0x09150, 0x0914e,
+    0x0914f, 0x091d5, 0x091e2, 0x091da, 0x0965c, 0x0965f, 0x096bc, 0x098e3, 0x09adf, 0x09b2f, 0x04e7f, 0x05070,
+    0x0506a, 0x05061, 0x0505e, 0x05060, 0x05053, 0x0504b, 0x0505d, 0x05072, 0x05048, 0x0504d, 0x05041, 0x0505b,
+    0x0504a, 0x05062, 0x05015, 0x05045, 0x0505f, 0x05069, 0x0506b, 0x05063, 0x05064, 0x05046, 0x05040, 0x0506e,
+    0x05073, 0x05057, 0x05051, 0x051d0, 0x0526b, 0x0526d, 0x0526c, 0x0526e, 0x052d6, 0x052d3, 0x0532d, 0x0539c,
+    0x05575, 0x05576, 0x0553c, 0x0554d, 0x05550, 0x05534, 0x0552a, 0x05551, 0x05562, 0x05536, 0x05535, 0x05530,
+    0x05552, 0x05545, 0x0550c, 0x05532, 0x05565, 0x0554e, 0x05539, 0x05548, 0x0552d, 0x0553b, 0x05540, 0x0554b,
+    0x0570a, 0x05707, 0x057fb, 0x05814, 0x057e2, 0x057f6, 0x057dc, 0x057f4, 0x05800, 0x057ed, 0x057fd, 0x05808,
+    0x057f8, 0x0580b, 0x057f3, 0x057cf, 0x05807, 0x057ee, 0x057e3, 0x057f2, 0x057e5, 0x057ec, 0x057e1, 0x0580e,
+    0x057fc, 0x05810, 0x057e7, 0x05801, 0x0580c, 0x057f1, 0x057e9, 0x057f0, 0x0580d, 0x05804, 0x0595c, 0x05a60,
+    0x05a58, 0x05a55, 0x05a67, 0x05a5e, 0x05a38, 0x05a35, 0x05a6d, 0x05a50, 0x05a5f, 0x05a65, 0x05a6c, 0x05a53,
+    0x05a64, 0x05a57, 0x05a43, 0x05a5d, 0x05a52, 0x05a44, 0x05a5b, 0x05a48, 0x05a8e, 0x05a3e, 0x05a4d, 0x05a39,
+    0x05a4c, 0x05a70, 0x05a69, 0x05a47, 0x05a51, 0x05a56, 0x05a42, 0x05a5c, 0x05b72, 0x05b6e, 0x05bc1, 0x05bc0,
+    0x05c59, 0x05d1e, 0x05d0b, 0x05d1d, 0x05d1a, 0x05d20, 0x05d0c, 0x05d28, 0x05d0d, 0x05d26, 0x05d25, 0x05d0f,
+    0x05d30, 0x05d12, 0x05d23, 0x05d1f, 0x05d2e, 0x05e3e, 0x05e34, 0x05eb1, 0x05eb4, 0x05eb9, 0x05eb2, 0x05eb3,
+    0x05f36, 0x05f38, 0x05f9b, 0x05f96, 0x05f9f, 0x0608a, 0x06090, 0x06086, 0x060be, 0x060b0, 0x060ba, 0x060d3,
+    0x060d4, 0x060cf, 0x060e4, 0x060d9, 0x060dd, 0x060c8, 0x060b1, 0x060db, 0x060b7, 0x060ca, 0x060bf, 0x060c3,
+    0x060cd, 0x060c0, 0x06332, 0x06365, 0x0638a, 0x06382, 0x0637d, 0x063bd, 0x0639e, 0x063ad, 0x0639d, 0x06397,
+    0x063ab, 0x0638e, 0x0636f, 0x06387, 0x06390, 0x0636e, 0x063af, 0x06375, 0x0639c, 0x0636d, 0x063ae, 0x0637c,
+    0x063a4, 0x0633b, 0x0639f, 0x06378, 0x06385, 0x06381, 0x06391, 0x0638d, 0x06370, 0x06553, 0x065cd, 0x06665,
+    0x06661, 0x0665b, 0x06659, 0x0665c, 0x06662, 0x06718, 0x06879, 0x06887, 0x06890, 0x0689c, 0x0686d, 0x0686e,
+    0x068ae, 0x068ab, 0x06956, 0x0686f, 0x068a3, 0x068ac, 0x068a9, 0x06875, 0x06874, 0x068b2, 0x0688f, 0x06877,
+    0x06892, 0x0687c, 0x0686b, 0x06872, 0x068aa, 0x06880, 0x06871, 0x0687e, 0x0689b, 0x06896, 0x0688b, 0x068a0,
+    0x06889, 0x068a4, 0x06878, 0x0687b, 0x06891, 0x0688c, 0x0688a, 0x0687d, 0x06b36, 0x06b33, 0x06b37, 0x06b38,
+    0x06b91, 0x06b8f, 0x06b8d, 0x06b8e, 0x06b8c, 0x06c2a, 0x06dc0, 0x06dab, 0x06db4, 0x06db3, 0x06e74, 0x06dac,
+    0x06de9, 0x06de2, 0x06db7, 0x06df6, 0x06dd4, 0x06e00, 0x06dc8, 0x06de0, 0x06ddf, 0x06dd6, 0x06dbe, 0x06de5,
+    0x06ddc, 0x06ddd, 0x06ddb, 0x06df4, 0x06dca, 0x06dbd, 0x06ded, 0x06df0, 0x06dba, 0x06dd5, 0x06dc2, 0x06dcf,
+    0x06dc9, 0x06dd0, 0x06df2, 0x06dd3, 0x06dfd, 0x06dd7, 0x06dcd, 0x06de3, 0x06dbb, 0x070fa, 0x0710d, 0x070f7,
+    0x07117, 0x070f4, 0x0710c, 0x070f0, 0x07104, 0x070f3, 0x07110, 0x070fc, 0x070ff, 0x07106, 0x07113, 0x07100,
+    0x070f8, 0x070f6, 0x0710b, 0x07102, 0x0710e, 0x0727e, 0x0727b, 0x0727c, 0x0727f, 0x0731d, 0x07317, 0x07307,
+    0x07311, 0x07318, 0x0730a, 0x07308, 0x072ff, 0x0730f, 0x0731e, 0x07388, 0x073f6, 0x073f8, 0x073f5, 0x07404,
+    0x07401, 0x073fd, 0x07407, 0x07400, 0x073fa, 0x073fc, 0x073ff, 0x0740c, 0x0740b, 0x073f4, 0x07408, 0x07564,
+    0x07563, 0x075ce, 0x075d2, 0x075cf, 0x075cb, 0x075cc, 0x075d1, 0x075d0, 0x0768f, 0x07689, 0x076d3, 0x07739,
+    0x0772f, 0x0772d, 0x07731, 0x07732, 0x07734, 0x07733, 0x0773d, 0x07725, 0x0773b, 0x07735, 0x07848, 0x07852,
+    0x07849, 0x0784d, 0x0784a, 0x0784c, 0x07826, 0x07845, 0x07850, 0x07964, 0x07967, 0x07969, 0x0796a, 0x07963,
+    0x0796b, 0x07961, 0x079bb, 0x079fa, 0x079f8, 0x079f6, 0x079f7, 0x07a8f, 0x07a94, 0x07a90, 0x07b35, 0x07b47,
+    0x07b34, 0x07b25, 0x07b30, 0x07b22, 0x07b24, 0x07b33, 0x07b18, 0x07b2a, 0x07b1d, 0x07b31, 0x07b2b, 0x07b2d,
+    0x07b2f, 0x07b32, 0x07b38, 0x07b1a, 0x07b23, 0x07c94, 0x07c98, 0x07c96, 0x07ca3, 0x07d35, 0x07d3d, 0x07d38,
+    0x07d36, 0x07d3a, 0x07d45, 0x07d2c, 0x07d29, 0x07d41, 0x07d47, 0x07d3e, 0x07d3f, 0x07d4a, 0x07d3b, 0x07d28,
+    0x07f63, 0x07f95, 0x07f9c, 0x07f9d, 0x07f9b, 0x07fca, 0x07fcb, 0x07fcd, 0x07fd0, 0x07fd1, 0x07fc7, 0x07fcf,
+    0x07fc9, 0x0801f, 0x0801e, 0x0801b, 0x08047, 0x08043, 0x08048, 0x08118, 0x08125, 0x08119, 0x0811b, 0x0812d,
+    0x0811f, 0x0812c, 0x0811e, 0x08121, 0x08115, 0x08127, 0x0811d, 0x08122, 0x08211, 0x08238, 0x08233, 0x0823a,
+    0x08234, 0x08232, 0x08274, 0x08390, 0x083a3, 0x083a8, 0x0838d, 0x0837a, 0x08373, 0x083a4, 0x08374, 0x0838f,
+    0x08381, 0x08395, 0x08399, 0x08375, 0x08394, 0x083a9, 0x0837d, 0x08383, 0x0838c, 0x0839d, 0x0839b, 0x083aa,
+    0x0838b, 0x0837e, 0x083a5, 0x083af, 0x08388, 0x08397, 0x083b0, 0x0837f, 0x083a6, 0x08387, 0x083ae, 0x08376,
+    0x0839a, 0x08659, 0x08656, 0x086bf, 0x086b7, 0x086c2, 0x086c1, 0x086c5, 0x086ba, 0x086b0, 0x086c8, 0x086b9,
+    0x086b3, 0x086b8, 0x086cc, 0x086b4, 0x086bb, 0x086bc, 0x086c3, 0x086bd, 0x086be, 0x08852, 0x08889, 0x08895,
+    0x088a8, 0x088a2, 0x088aa, 0x0889a, 0x08891, 0x088a1, 0x0889f, 0x08898, 0x088a7, 0x08899, 0x0889b, 0x08897,
+    0x088a4, 0x088ac, 0x0888c, 0x08893, 0x0888e, 0x08982, 0x089d6, 0x089d9, 0x089d5, 0x08a30, 0x08a27, 0x08a2c,
+    0x08a1e, 0x08c39, 0x08c3b, 0x08c5c, 0x08c5d, 0x08c7d, 0x08ca5, 0x08d7d, 0x08d7b, 0x08d79, 0x08dbc, 0x08dc2,
+    0x08db9, 0x08dbf, 0x08dc1, 0x08ed8, 0x08ede, 0x08edd, 0x08edc, 0x08ed7, 0x08ee0, 0x08ee1, 0x09024, 0x0900b,
+    0x09011, 0x0901c, 0x0900c, 0x09021, 0x090ef, 0x090ea, 0x090f0, 0x090f4, 0x090f2, 0x090f3, 0x090d4, 0x090eb,
+    0x090ec, 0x090e9, 0x09156, 0x09158, 0x0915a, 0x09153, 0x09155, 0x091ec, 0x091f4, 0x091f1, 0x091f3, 0x091f8,
+    0x091e4, 0x091f9, 0x091ea, 0x091eb, 0x091f7, 0x091e8, 0x091ee, 0x0957a, 0x09586, 0x09588, 0x0967c, 0x0966d,
+    0x0966b, 0x09671, 0x0966f, 0x096bf, 0x0976a, 0x09804, 0x098e5, 0x09997, 0x0509b, 0x05095, 0x05094, 0x0509e,
+    0x0508b, 0x050a3, 0x05083, 0x0508c, 0x0508e, 0x0509d, 0x05068, 0x0509c, 0x05092, 0x05082, 0x05087, 0x0515f,
+    0x051d4, 0x05312, 0x05311, 0x053a4, 0x053a7, 0x05591, 0x055a8, 0x055a5, 0x055ad, 0x05577, 0x05645, 0x055a2,
+    0x05593, 0x05588, 0x0558f, 0x055b5, 0x05581, 0x055a3, 0x05592, 0x055a4, 0x0557d, 0x0558c, 0x055a6, 0x0557f,
+    0x05595, 0x055a1, 0x0558e, 0x0570c, 0x05829, 0x05837, 0x05819, 0x0581e, 0x05827, 0x05823, 0x05828, 0x057f5,
+    0x05848, 0x05825, 0x0581c, 0x0581b, 0x05833, 0x0583f, 0x05836, 0x0582e, 0x05839, 0x05838, 0x0582d, 0x0582c,
+    0x0583b, 0x05961, 0x05aaf, 0x05a94, 0x05a9f, 0x05a7a, 0x05aa2, 0x05a9e, 0x05a78, 0x05aa6, 0x05a7c, 0x05aa5,
+    0x05aac, 0x05a95, 0x05aae, 0x05a37, 0x05a84, 0x05a8a, 0x05a97, 0x05a83, 0x05a8b, 0x05aa9, 0x05a7b, 0x05a7d,
+    0x05a8c, 0x05a9c, 0x05a8f, 0x05a93, 0x05a9d, 0x05bea, 0x05bcd, 0x05bcb, 0x05bd4, 0x05bd1, 0x05bca, 0x05bce,
+    0x05c0c, 0x05c30, 0x05d37, 0x05d43, 0x05d6b, 0x05d41, 0x05d4b, 0x05d3f, 0x05d35, 0x05d51, 0x05d4e, 0x05d55,
+    0x05d33, 0x05d3a, 0x05d52, 0x05d3d, 0x05d31, 0x05d59, 0x05d42, 0x05d39, 0x05d49, 0x05d38, 0x05d3c, 0x05d32,
+    0x05d36, 0x05d40, 0x05d45, 0x05e44, 0x05e41, 0x05f58, 0x05fa6, 0x05fa5, 0x05fab, 0x060c9, 0x060b9, 0x060cc,
+    0x060e2, 0x060ce, 0x060c4, 0x06114, 0x060f2, 0x0610a, 0x06116, 0x06105, 0x060f5, 0x06113, 0x060f8, 0x060fc,
+    0x060fe, 0x060c1, 0x06103, 0x06118, 0x0611d, 0x06110, 0x060ff, 0x06104, 0x0610b, 0x0624a, 0x06394, 0x063b1,
+    0x063b0, 0x063ce, 0x063e5, 0x063e8, 0x063ef, 0x063c3, 0x0649d, 0x063f3, 0x063ca, 0x063e0, 0x063f6, 0x063d5,
+    0x063f2, 0x063f5, 0x06461, 0x063df, 0x063be, 0x063dd, 0x063dc, 0x063c4, 0x063d8, 0x063d3, 0x063c2, 0x063c7,
+    0x063cc, 0x063cb, 0x063c8, 0x063f0, 0x063d7, 0x063d9, 0x06532, 0x06567, 0x0656a, 0x06564, 0x0655c, 0x06568,
+    0x06565, 0x0658c, 0x0659d, 0x0659e, 0x065ae, 0x065d0, 0x065d2, 0x0667c, 0x0666c, 0x0667b, 0x06680, 0x06671,
+    0x06679, 0x0666a, 0x06672, 0x06701, 0x0690c, 0x068d3, 0x06904, 0x068dc, 0x0692a, 0x068ec, 0x068ea, 0x068f1,
+    0x0690f, 0x068d6, 0x068f7, 0x068eb, 0x068e4, 0x068f6, 0x06913, 0x06910, 0x068f3, 0x068e1, 0x06907, 0x068cc,
+    0x06908, 0x06970, 0x068b4, 0x06911, 0x068ef, 0x068c6, 0x06914, 0x068f8, 0x068d0, 0x068fd, 0x068fc, 0x068e8,
+    0x0690b, 0x0690a, 0x06917, 0x068ce, 0x068c8, 0x068dd, 0x068de, 0x068e6, 0x068f4, 0x068d1, 0x06906, 0x068d4,
+    0x068e9, 0x06915, 0x06925, 0x068c7, 0x06b39, 0x06b3b, 0x06b3f, 0x06b3c, 0x06b94, 0x06b97, 0x06b99, 0x06b95,
+    0x06bbd, 0x06bf0, 0x06bf2, 0x06bf3, 0x06c30, 0x06dfc, 0x06e46, 0x06e47, 0x06e1f, 0x06e49, 0x06e88, 0x06e3c,
+    0x06e3d, 0x06e45, 0x06e62, 0x06e2b, 0x06e3f, 0x06e41, 0x06e5d, 0x06e73, 0x06e1c, 0x06e33, 0x06e4b, 0x06e40,
+    0x06e51, 0x06e3b, 0x06e03, 0x06e2e, 0x06e5e, 0x06e68, 0x06e5c, 0x06e61, 0x06e31, 0x06e28, 0x06e60, 0x06e71,
+    0x06e6b, 0x06e39, 0x06e22, 0x06e30, 0x06e53, 0x06e65, 0x06e27, 0x06e78, 0x06e64, 0x06e77, 0x06e55, 0x06e79,
+    0x06e52, 0x06e66, 0x06e35, 0x06e36, 0x06e5a, 0x07120, 0x0711e, 0x0712f, 0x070fb, 0x0712e, 0x07131, 0x07123,
+    0x07125, 0x07122, 0x07132, 0x0711f, 0x07128, 0x0713a, 0x0711b, 0x0724b, 0x0725a, 0x07288, 0x07289, 0x07286,
+    0x07285, 0x0728b, 0x07312, 0x0730b, 0x07330, 0x07322, 0x07331, 0x07333, 0x07327, 0x07332, 0x0732d, 0x07326,
+    0x07323, 0x07335, 0x0730c, 0x0742e, 0x0742c, 0x07430, 0x0742b, 0x07416, 0x0741a, 0x07421, 0x0742d, 0x07431,
+    0x07424, 0x07423, 0x0741d, 0x07429, 0x07420, 0x07432, 0x074fb, 0x0752f, 0x0756f, 0x0756c, 0x075e7, 0x075da,
+    0x075e1, 0x075e6, 0x075dd, 0x075df, 0x075e4, 0x075d7, 0x07695, 0x07692, 0x076da, 0x07746, 0x07747, 0x07744,
+    0x0774d, 0x07745, 0x0774a, 0x0774e, 0x0774b, 0x0774c, 0x077de, 0x077ec, 0x07860, 0x07864, 0x07865, 0x0785c,
+    0x0786d, 0x07871, 0x0786a, 0x0786e, 0x07870, 0x07869, 0x07868, 0x0785e, 0x07862, 0x07974, 0x07973, 0x07972,
+    0x07970, 0x07a02, 0x07a0a, 0x07a03, 0x07a0c, 0x07a04, 0x07a99, 0x07ae6, 0x07ae4, 0x07b4a, 0x07b3b, 0x07b44,
+    0x07b48, 0x07b4c, 0x07b4e, 0x07b40, 0x07b58, 0x07b45, 0x07ca2, 0x07c9e, 0x07ca8, 0x07ca1, 0x07d58, 0x07d6f,
+    0x07d63, 0x07d53, 0x07d56, 0x07d67, 0x07d6a, 0x07d4f, 0x07d6d, 0x07d5c, 0x07d6b, 0x07d52, 0x07d54, 0x07d69,
+    0x07d51, 0x07d5f, 0x07d4e, 0x07f3e, 0x07f3f, 0x07f65, 0x07f66, 0x07fa2, 0x07fa0, 0x07fa1, 0x07fd7, 0x08051,
+    0x0804f, 0x08050, 0x080fe, 0x080d4, 0x08143, 0x0814a, 0x08152, 0x0814f, 0x08147, 0x0813d, 0x0814d, 0x0813a,
+    0x081e6, 0x081ee, 0x081f7, 0x081f8, 0x081f9, 0x08204, 0x0823c, 0x0823d, 0x0823f, 0x08275, 0x0833b, 0x083cf,
+    0x083f9, 0x08423, 0x083c0, 0x083e8, 0x08412, 0x083e7, 0x083e4, 0x083fc, 0x083f6, 0x08410, 0x083c6, 0x083c8,
+    0x083eb, 0x083e3, 0x083bf, 0x08401, 0x083dd, 0x083e5, 0x083d8, 0x083ff, 0x083e1, 0x083cb, 0x083ce, 0x083d6,
+    0x083f5, 0x083c9, 0x08409, 0x0840f, 0x083de, 0x08411, 0x08406, 0x083c2, 0x083f3, 0x083d5, 0x083fa, 0x083c7,
+    0x083d1, 0x083ea, 0x08413, 0x083c3, 0x083ec, 0x083ee, 0x083c4, 0x083fb, 0x083d7, 0x083e2, 0x0841b, 0x083db,
+    0x083fe, 0x086d8, 0x086e2, 0x086e6, 0x086d3, 0x086e3, 0x086da, 0x086ea, 0x086dd, 0x086eb, 0x086dc, 0x086ec,
+    0x086e9, 0x086d7, 0x086e8, 0x086d1, 0x08848, 0x08856, 0x08855, 0x088ba, 0x088d7, 0x088b9, 0x088b8, 0x088c0,
+    0x088be, 0x088b6, 0x088bc, 0x088b7, 0x088bd, 0x088b2, 0x08901, 0x088c9, 0x08995, 0x08998, 0x08997, 0x089dd,
+    0x089da, 0x089db, 0x08a4e, 0x08a4d, 0x08a39, 0x08a59, 0x08a40, 0x08a57, 0x08a58, 0x08a44, 0x08a45, 0x08a52,
+    0x08a48, 0x08a51, 0x08a4a, 0x08a4c, 0x08a4f, 0x08c5f, 0x08c81, 0x08c80, 0x08cba, 0x08cbe, 0x08cb0, 0x08cb9,
+    0x08cb5, 0x08d84, 0x08d80, 0x08d89, 0x08dd8, 0x08dd3, 0x08dcd, 0x08dc7, 0x08dd6, 0x08ddc, 0x08dcf, 0x08dd5,
+    0x08dd9, 0x08dc8, 0x08dd7, 0x08dc5, 0x08eef, 0x08ef7, 0x08efa, 0x08ef9, 0x08ee6, 0x08eee, 0x08ee5, 0x08ef5,
+    0x08ee7, 0x08ee8, 0x08ef6, 0x08eeb, 0x08ef1, 0x08eec, 0x08ef4, 0x08ee9, 0x0902d, 0x09034, 0x0902f, 0x09106,
+    0x0912c, 0x09104, 0x090ff, 0x090fc, 0x09108, 0x090f9, 0x090fb, 0x09101, 0x09100, 0x09107, 0x09105, 0x09103,
+    0x09161, 0x09164, 0x0915f, 0x09162, 0x09160, 0x09201, 0x0920a, 0x09225, 0x09203, 0x0921a, 0x09226, 0x0920f,
+    0x0920c, 0x09200, 0x09212, 0x091ff, 0x091fd, 0x09206, 0x09204, 0x09227, 0x09202, 0x0921c, 0x09224, 0x09219,
+    0x09217, 0x09205, 0x09216, 0x0957b, 0x0958d, 0x0958c, 0x09590, 0x09687, 0x0967e, 0x09688, 0x09689, 0x09683,
+    0x09680, 0x096c2, 0x096c8, 0x096c3, 0x096f1, 0x096f0, 0x0976c, 0x09770, 0x0976e, 0x09807, 0x098a9, 0x098eb,
+    0x09ce6, 0x09ef9, 0x04e83, 0x04e84, 0x04eb6, 0x050bd, 0x050bf, 0x050c6, 0x050ae, 0x050c4, 0x050ca, 0x050b4,
+    0x050c8, 0x050c2, 0x050b0, 0x050c1, 0x050ba, 0x050b1, 0x050cb, 0x050c9, 0x050b6, 0x050b8, 0x051d7, 0x0527a,
+    0x05278, 0x0527b, 0x0527c, 0x055c3, 0x055db, 0x055cc, 0x055d0, 0x055cb, 0x055ca, 0x055dd, 0x055c0, 0x055d4,
+    0x055c4, 0x055e9, 0x055bf, 0x055d2, 0x0558d, 0x055cf, 0x055d5, 0x055e2, 0x055d6, 0x055c8, 0x055f2, 0x055cd,
+    0x055d9, 0x055c2, 0x05714, 0x05853, 0x05868, 0x05864, 0x0584f, 0x0584d, 0x05849, 0x0586f, 0x05855, 0x0584e,
+    0x0585d, 0x05859, 0x05865, 0x0585b, 0x0583d, 0x05863, 0x05871, 0x058fc, 0x05ac7, 0x05ac4, 0x05acb, 0x05aba,
+    0x05ab8, 0x05ab1, 0x05ab5, 0x05ab0, 0x05abf, 0x05ac8, 0x05abb, 0x05ac6, 0x05ab7, 0x05ac0, 0x05aca, 0x05ab4,
+    0x05ab6, 0x05acd, 0x05ab9, 0x05a90, 0x05bd6, 0x05bd8, 0x05bd9, 0x05c1f, 0x05c33, 0x05d71, 0x05d63, 0x05d4a,
+    0x05d65, 0x05d72, 0x05d6c, 0x05d5e, 0x05d68, 0x05d67, 0x05d62, 0x05df0, 0x05e4f, 0x05e4e, 0x05e4a, 0x05e4d,
+    0x05e4b, 0x05ec5, 0x05ecc, 0x05ec6, 0x05ecb, 0x05ec7, 0x05f40, 0x05faf, 0x05fad, 0x060f7, 0x06149, 0x0614a,
+    0x0612b, 0x06145, 0x06136, 0x06132, 0x0612e, 0x06146, 0x0612f, 0x0614f, 0x06129, 0x06140, 0x06220, 0x09168,
+    0x06223, 0x06225, 0x06224, 0x063c5, 0x063f1, 0x063eb, 0x06410, 0x06412, 0x06409, 0x06420, 0x06424, 0x06433,
+    0x06443, 0x0641f, 0x06415, 0x06418, 0x06439, 0x06437, 0x06422, 0x06423, 0x0640c, 0x06426, 0x06430, 0x06428,
+    0x06441, 0x06435, 0x0642f, 0x0640a, 0x0641a, 0x06440, 0x06425, 0x06427, 0x0640b, 0x063e7, 0x0641b, 0x0642e,
+    0x06421, 0x0640e, 0x0656f, 0x06592, 0x065d3, 0x06686, 0x0668c, 0x06695, 0x06690, 0x0668b, 0x0668a, 0x06699,
+    0x06694, 0x06678, 0x06720, 0x06966, 0x0695f, 0x06938, 0x0694e, 0x06962, 0x06971, 0x0693f, 0x06945, 0x0696a,
+    0x06939, 0x06942, 0x06957, 0x06959, 0x0697a, 0x06948, 0x06949, 0x06935, 0x0696c, 0x06933, 0x0693d, 0x06965,
+    0x068f0, 0x06978, 0x06934, 0x06969, 0x06940, 0x0696f, 0x06944, 0x06976, 0x06958, 0x06941, 0x06974, 0x0694c,
+    0x0693b, 0x0694b, 0x06937, 0x0695c, 0x0694f, 0x06951, 0x06932, 0x06952, 0x0692f, 0x0697b, 0x0693c, 0x06b46,
+    0x06b45, 0x06b43, 0x06b42, 0x06b48, 0x06b41, 0x06b9b, 0x0fa0d, 0x06bfb, 0x06bfc, 0x06bf9, 0x06bf7, 0x06bf8,
+    0x06e9b, 0x06ed6, 0x06ec8, 0x06e8f, 0x06ec0, 0x06e9f, 0x06e93, 0x06e94, 0x06ea0, 0x06eb1, 0x06eb9, 0x06ec6,
+    0x06ed2, 0x06ebd, 0x06ec1, 0x06e9e, 0x06ec9, 0x06eb7, 0x06eb0, 0x06ecd, 0x06ea6, 0x06ecf, 0x06eb2, 0x06ebe,
+    0x06ec3, 0x06edc, 0x06ed8, 0x06e99, 0x06e92, 0x06e8e, 0x06e8d, 0x06ea4, 0x06ea1, 0x06ebf, 0x06eb3, 0x06ed0,
+    0x06eca, 0x06e97, 0x06eae, 0x06ea3, 0x07147, 0x07154, 0x07152, 0x07163, 0x07160, 0x07141, 0x0715d, 0x07162,
+    0x07172, 0x07178, 0x0716a, 0x07161, 0x07142, 0x07158, 0x07143, 0x0714b, 0x07170, 0x0715f, 0x07150, 0x07153,
+    0x07144, 0x0714d, 0x0715a, 0x0724f, 0x0728d, 0x0728c, 0x07291, 0x07290, 0x0728e, 0x0733c, 0x07342, 0x0733b,
+    0x0733a, 0x07340, 0x0734a, 0x07349, 0x07444, 0x0744a, 0x0744b, 0x07452, 0x07451, 0x07457, 0x07440, 0x0744f,
+    0x07450, 0x0744e, 0x07442, 0x07446, 0x0744d, 0x07454, 0x074e1, 0x074ff, 0x074fe, 0x074fd, 0x0751d, 0x07579,
+    0x07577, 0x06983, 0x075ef, 0x0760f, 0x07603, 0x075f7, 0x075fe, 0x075fc, 0x075f9, 0x075f8, 0x07610, 0x075fb,
+    0x075f6, 0x075ed, 0x075f5, 0x075fd, 0x07699, 0x076b5, 0x076dd, 0x07755, 0x0775f, 0x07760, 0x07752, 0x07756,
+    0x0775a, 0x07769, 0x07767, 0x07754, 0x07759, 0x0776d, 0x077e0, 0x07887, 0x0789a, 0x07894, 0x0788f, 0x07884,
+    0x07895, 0x07885, 0x07886, 0x078a1, 0x07883, 0x07879, 0x07899, 0x07880, 0x07896, 0x0787b, 0x0797c, 0x07982,
+    0x0797d, 0x07979, 0x07a11, 0x07a18, 0x07a19, 0x07a12, 0x07a17, 0x07a15, 0x07a22, 0x07a13, 0x07a1b, 0x07a10,
+    0x07aa3, 0x07aa2, 0x07a9e, 0x07aeb, 0x07b66, 0x07b64, 0x07b6d, 0x07b74, 0x07b69, 0x07b72, 0x07b65, 0x07b73,
+    0x07b71, 0x07b70, 0x07b61, 0x07b78, 0x07b76, 0x07b63, 0x07cb2, 0x07cb4, 0x07caf, 0x07d88, 0x07d86, 0x07d80,
+    0x07d8d, 0x07d7f, 0x07d85, 0x07d7a, 0x07d8e, 0x07d7b, 0x07d83, 0x07d7c, 0x07d8c, 0x07d94, 0x07d84, 0x07d7d,
+    0x07d92, 0x07f6d, 0x07f6b, 0x07f67, 0x07f68, 0x07f6c, 0x07fa6, 0x07fa5, 0x07fa7, 0x07fdb, 0x07fdc, 0x08021,
+    0x08164, 0x08160, 0x08177, 0x0815c, 0x08169, 0x0815b, 0x08162, 0x08172, 0x06721, 0x0815e, 0x08176, 0x08167,
+    0x0816f, 0x08144, 0x08161, 0x0821d, 0x08249, 0x08244, 0x08240, 0x08242, 0x08245, 0x084f1, 0x0843f, 0x08456,
+    0x08476, 0x08479, 0x0848f, 0x0848d, 0x08465, 0x08451, 0x08440, 0x08486, 0x08467, 0x08430, 0x0844d, 0x0847d,
+    0x0845a, 0x08459, 0x08474, 0x08473, 0x0845d, 0x08507, 0x0845e, 0x08437, 0x0843a, 0x08434, 0x0847a, 0x08443,
+    0x08478, 0x08432, 0x08445, 0x08429, 0x083d9, 0x0844b, 0x0842f, 0x08442, 0x0842d, 0x0845f, 0x08470, 0x08439,
+    0x0844e, 0x0844c, 0x08452, 0x0846f, 0x084c5, 0x0848e, 0x0843b, 0x08447, 0x08436, 0x08433, 0x08468, 0x0847e,
+    0x08444, 0x0842b, 0x08460, 0x08454, 0x0846e, 0x08450, 0x0870b, 0x08704, 0x086f7, 0x0870c, 0x086fa, 0x086d6,
+    0x086f5, 0x0874d, 0x086f8, 0x0870e, 0x08709, 0x08701, 0x086f6, 0x0870d, 0x08705, 0x088d6, 0x088cb, 0x088cd,
+    0x088ce, 0x088de, 0x088db, 0x088da, 0x088cc, 0x088d0, 0x08985, 0x0899b, 0x089df, 0x089e5, 0x089e4, 0x089e1,
+    0x089e0, 0x089e2, 0x089dc, 0x089e6, 0x08a76, 0x08a86, 0x08a7f, 0x08a61, 0x08a3f, 0x08a77, 0x08a82, 0x08a84,
+    0x08a75, 0x08a83, 0x08a81, 0x08a74, 0x08a7a, 0x08c3c, 0x08c4b, 0x08c4a, 0x08c65, 0x08c64, 0x08c66, 0x08c86,
+    0x08c84, 0x08c85, 0x08ccc, 0x08d68, 0x08d69, 0x08d91, 0x08d8c, 0x08d8e, 0x08d8f, 0x08d8d, 0x08d93, 0x08d94,
+    0x08d90, 0x08d92, 0x08df0, 0x08de0, 0x08dec, 0x08df1, 0x08dee, 0x08dd0, 0x08de9, 0x08de3, 0x08de2, 0x08de7,
+    0x08df2, 0x08deb, 0x08df4, 0x08f06, 0x08eff, 0x08f01, 0x08f00, 0x08f05, 0x08f07, 0x08f08, 0x08f02, 0x08f0b,
+    0x09052, 0x0903f, 0x09044, 0x09049, 0x0903d, 0x09110, 0x0910d, 0x0910f, 0x09111, 0x09116, 0x09114, 0x0910b,
+    0x0910e, 0x0916e, 0x0916f, 0x09248, 0x09252, 0x09230, 0x0923a, 0x09266, 0x09233, 0x09265, 0x0925e, 0x09283,
+    0x0922e, 0x0924a, 0x09246, 0x0926d, 0x0926c, 0x0924f, 0x09260, 0x09267, 0x0926f, 0x09236, 0x09261, 0x09270,
+    0x09231, 0x09254, 0x09263, 0x09250, 0x09272, 0x0924e, 0x09253, 0x0924c, 0x09256, 0x09232, 0x0959f, 0x0959c,
+    0x0959e, 0x0959b, 0x09692, 0x09693, 0x09691, 0x09697, 0x096ce, 0x096fa, 0x096fd, 0x096f8, 0x096f5, 0x09773,
+    0x09777, 0x09778, 0x09772, 0x0980f, 0x0980d, 0x0980e, 0x098ac, 0x098f6, 0x098f9, 0x099af, 0x099b2, 0x099b0,
+    0x099b5, 0x09aad, 0x09aab, 0x09b5b, 0x09cea, 0x09ced, 0x09ce7, 0x09e80, 0x09efd, 0x050e6, 0x050d4, 0x050d7,
+    0x050e8, 0x050f3, 0x050db, 0x050ea, 0x050dd, 0x050e4, 0x050d3, 0x050ec, 0x050f0, 0x050ef, 0x050e3, 0x050e0,
+    0x051d8, 0x05280, 0x05281, 0x052e9, 0x052eb, 0x05330, 0x053ac, 0x05627, 0x05615, 0x0560c, 0x05612, 0x055fc,
+    0x0560f, 0x0561c, 0x05601, 0x05613, 0x05602, 0x055fa, 0x0561d, 0x05604, 0x055ff, 0x055f9, 0x05889, 0x0587c,
+    0x05890, 0x05898, 0x05886, 0x05881, 0x0587f, 0x05874, 0x0588b, 0x0587a, 0x05887, 0x05891, 0x0588e, 0x05876,
+    0x05882, 0x05888, 0x0587b, 0x05894, 0x0588f, 0x058fe, 0x0596b, 0x05adc, 0x05aee, 0x05ae5, 0x05ad5, 0x05aea,
+    0x05ada, 0x05aed, 0x05aeb, 0x05af3, 0x05ae2, 0x05ae0, 0x05adb, 0x05aec, 0x05ade, 0x05add, 0x05ad9, 0x05ae8,
+    0x05adf, 0x05b77, 0x05be0, 0x05be3, 0x05c63, 0x05d82, 0x05d80, 0x05d7d, 0x05d86, 0x05d7a, 0x05d81, 0x05d77,
+    0x05d8a, 0x05d89, 0x05d88, 0x05d7e, 0x05d7c, 0x05d8d, 0x05d79, 0x05d7f, 0x05e58, 0x05e59, 0x05e53, 0x05ed8,
+    0x05ed1, 0x05ed7, 0x05ece, 0x05edc, 0x05ed5, 0x05ed9, 0x05ed2, 0x05ed4, 0x05f44, 0x05f43, 0x05f6f, 0x05fb6,
+    0x0612c, 0x06128, 0x06141, 0x0615e, 0x06171, 0x06173, 0x06152, 0x06153, 0x06172, 0x0616c, 0x06180, 0x06174,
+    0x06154, 0x0617a, 0x0615b, 0x06165, 0x0613b, 0x0616a, 0x06161, 0x06156, 0x06229, 0x06227, 0x0622b, 0x0642b,
+    0x0644d, 0x0645b, 0x0645d, 0x06474, 0x06476, 0x06472, 0x06473, 0x0647d, 0x06475, 0x06466, 0x064a6, 0x0644e,
+    0x06482, 0x0645e, 0x0645c, 0x0644b, 0x06453, 0x06460, 0x06450, 0x0647f, 0x0643f, 0x0646c, 0x0646b, 0x06459,
+    0x06465, 0x06477, 0x06573, 0x065a0, 0x066a1, 0x066a0, 0x0669f, 0x06705, 0x06704, 0x06722, 0x069b1, 0x069b6,
+    0x069c9, 0x069a0, 0x069ce, 0x06996, 0x069b0, 0x069ac, 0x069bc, 0x06991, 0x06999, 0x0698e, 0x069a7, 0x0698d,
+    0x069a9, 0x069be, 0x069af, 0x069bf, 0x069c4, 0x069bd, 0x069a4, 0x069d4, 0x069b9, 0x069ca, 0x0699a, 0x069cf,
+    0x069b3, 0x06993, 0x069aa, 0x069a1, 0x0699e, 0x069d9, 0x06997, 0x06990, 0x069c2, 0x069b5, 0x069a5, 0x069c6,
+    0x06b4a, 0x06b4d, 0x06b4b, 0x06b9e, 0x06b9f, 0x06ba0, 0x06bc3, 0x06bc4, 0x06bfe, 0x06ece, 0x06ef5, 0x06ef1,
+    0x06f03, 0x06f25, 0x06ef8, 0x06f37, 0x06efb, 0x06f2e, 0x06f09, 0x06f4e, 0x06f19, 0x06f1a, 0x06f27, 0x06f18,
+    0x06f3b, 0x06f12, 0x06eed, 0x06f0a, 0x06f36, 0x06f73, 0x06ef9, 0x06eee, 0x06f2d, 0x06f40, 0x06f30, 0x06f3c,
+    0x06f35, 0x06eeb, 0x06f07, 0x06f0e, 0x06f43, 0x06f05, 0x06efd, 0x06ef6, 0x06f39, 0x06f1c, 0x06efc, 0x06f3a,
+    0x06f1f, 0x06f0d, 0x06f1e, 0x06f08, 0x06f21, 0x07187, 0x07190, 0x07189, 0x07180, 0x07185, 0x07182, 0x0718f,
+    0x0717b, 0x07186, 0x07181, 0x07197, 0x07244, 0x07253, 0x07297, 0x07295, 0x07293, 0x07343, 0x0734d, 0x07351,
+    0x0734c, 0x07462, 0x07473, 0x07471, 0x07475, 0x07472, 0x07467, 0x0746e, 0x07500, 0x07502, 0x07503, 0x0757d,
+    0x07590, 0x07616, 0x07608, 0x0760c, 0x07615, 0x07611, 0x0760a, 0x07614, 0x076b8, 0x07781, 0x0777c, 0x07785,
+    0x07782, 0x0776e, 0x07780, 0x0776f, 0x0777e, 0x07783, 0x078b2, 0x078aa, 0x078b4, 0x078ad, 0x078a8, 0x0787e,
+    0x078ab, 0x0789e, 0x078a5, 0x078a0, 0x078ac, 0x078a2, 0x078a4, 0x07998, 0x0798a, 0x0798b, 0x07996, 0x07995,
+    0x07994, 0x07993, 0x07997, 0x07988, 0x07992, 0x07990, 0x07a2b, 0x07a4a, 0x07a30, 0x07a2f, 0x07a28, 0x07a26,
+    0x07aa8, 0x07aab, 0x07aac, 0x07aee, 0x07b88, 0x07b9c, 0x07b8a, 0x07b91, 0x07b90, 0x07b96, 0x07b8d, 0x07b8c,
+    0x07b9b, 0x07b8e, 0x07b85, 0x07b98, 0x05284, 0x07b99, 0x07ba4, 0x07b82, 0x07cbb, 0x07cbf, 0x07cbc, 0x07cba,
+    0x07da7, 0x07db7, 0x07dc2, 0x07da3, 0x07daa, 0x07dc1, 0x07dc0, 0x07dc5, 0x07d9d, 0x07dce, 0x07dc4, 0x07dc6,
+    0x07dcb, 0x07dcc, 0x07daf, 0x07db9, 0x07d96, 0x07dbc, 0x07d9f, 0x07da6, 0x07dae, 0x07da9, 0x07da1, 0x07dc9,
+    0x07f73, 0x07fe2, 0x07fe3, 0x07fe5, 0x07fde, 0x08024, 0x0805d, 0x0805c, 0x08189, 0x08186, 0x08183, 0x08187,
+    0x0818d, 0x0818c, 0x0818b, 0x08215, 0x08497, 0x084a4, 0x084a1, 0x0849f, 0x084ba, 0x084ce, 0x084c2, 0x084ac,
+    0x084ae, 0x084ab, 0x084b9, 0x084b4, 0x084c1, 0x084cd, 0x084aa, 0x0849a, 0x084b1, 0x084d0, 0x0849d, 0x084a7,
+    0x084bb, 0x084a2, 0x08494, 0x084c7, 0x084cc, 0x0849b, 0x084a9, 0x084af, 0x084a8, 0x084d6, 0x08498, 0x084b6,
+    0x084cf, 0x084a0, 0x084d7, 0x084d4, 0x084d2, 0x084db, 0x084b0, 0x08491, 0x08661, 0x08733, 0x08723, 0x08728,
+    0x0876b, 0x08740, 0x0872e, 0x0871e, 0x08721, 0x08719, 0x0871b, 0x08743, 0x0872c, 0x08741, 0x0873e, 0x08746,
+    0x08720, 0x08732, 0x0872a, 0x0872d, 0x0873c, 0x08712, 0x0873a, 0x08731, 0x08735, 0x08742, 0x08726, 0x08727,
+    0x08738, 0x08724, 0x0871a, 0x08730, 0x08711, 0x088f7, 0x088e7, 0x088f1, 0x088f2, 0x088fa, 0x088fe, 0x088ee,
+    0x088fc, 0x088f6, 0x088fb, 0x088f0, 0x088ec, 0x088eb, 0x0899d, 0x089a1, 0x0899f, 0x0899e, 0x089e9, 0x089eb,
+    0x089e8, 0x08aab, 0x08a99, 0x08a8b, 0x08a92, 0x08a8f, 0x08a96, 0x08c3d, 0x08c68, 0x08c69, 0x08cd5, 0x08ccf,
+    0x08cd7, 0x08d96, 0x08e09, 0x08e02, 0x08dff, 0x08e0d, 0x08dfd, 0x08e0a, 0x08e03, 0x08e07, 0x08e06, 0x08e05,
+    0x08dfe, 0x08e00, 0x08e04, 0x08f10, 0x08f11, 0x08f0e, 0x08f0d, 0x09123, 0x0911c, 0x09120, 0x09122, 0x0911f,
+    0x0911d, 0x0911a, 0x09124, 0x09121, 0x0911b, 0x0917a, 0x09172, 0x09179, 0x09173, 0x092a5, 0x092a4, 0x09276,
+    0x0929b, 0x0927a, 0x092a0, 0x09294, 0x092aa, 0x0928d, 0x092a6, 0x0929a, 0x092ab, 0x09279, 0x09297, 0x0927f,
+    0x092a3, 0x092ee, 0x0928e, 0x09282, 0x09295, 0x092a2, 0x0927d, 0x09288, 0x092a1, 0x0928a, 0x09286, 0x0928c,
+    0x09299, 0x092a7, 0x0927e, 0x09287, 0x092a9, 0x0929d, 0x0928b, 0x0922d, 0x0969e, 0x096a1, 0x096ff, 0x09758,
+    0x0977d, 0x0977a, 0x0977e, 0x09783, 0x09780, 0x09782, 0x0977b, 0x09784, 0x09781, 0x0977f, 0x097ce, 0x097cd,
+    0x09816, 0x098ad, 0x098ae, 0x09902, 0x09900, 0x09907, 0x0999d, 0x0999c, 0x099c3, 0x099b9, 0x099bb, 0x099ba,
+    0x099c2, 0x099bd, 0x099c7, 0x09ab1, 0x09ae3, 0x09ae7, 0x09b3e, 0x09b3f, 0x09b60, 0x09b61, 0x09b5f, 0x09cf1,
+    0x09cf2, 0x09cf5, 0x09ea7, 0x050ff, 0x05103, 0x05130, 0x050f8, 0x05106, 0x05107, 0x050f6, 0x050fe, 0x0510b,
+    0x0510c, 0x050fd, 0x0510a, 0x0528b, 0x0528c, 0x052f1, 0x052ef, 0x05648, 0x05642, 0x0564c, 0x05635, 0x05641,
+    0x0564a, 0x05649, 0x05646, 0x05658, 0x0565a, 0x05640, 0x05633, 0x0563d, 0x0562c, 0x0563e, 0x05638, 0x0562a,
+    0x0563a, 0x0571a, 0x058ab, 0x0589d, 0x058b1, 0x058a0, 0x058a3, 0x058af, 0x058ac, 0x058a5, 0x058a1, 0x058ff,
+    0x05aff, 0x05af4, 0x05afd, 0x05af7, 0x05af6, 0x05b03, 0x05af8, 0x05b02, 0x05af9, 0x05b01, 0x05b07, 0x05b05,
+    0x05b0f, 0x05c67, 0x05d99, 0x05d97, 0x05d9f, 0x05d92, 0x05da2, 0x05d93, 0x05d95, 0x05da0, 0x05d9c, 0x05da1,
+    0x05d9a, 0x05d9e, 0x05e69, 0x05e5d, 0x05e60, 0x05e5c, 0x07df3, 0x05edb, 0x05ede, 0x05ee1, 0x05f49, 0x05fb2,
+    0x0618b, 0x06183, 0x06179, 0x061b1, 0x061b0, 0x061a2, 0x06189, 0x0619b, 0x06193, 0x061af, 0x061ad, 0x0619f,
+    0x06192, 0x061aa, 0x061a1, 0x0618d, 0x06166, 0x061b3, 0x0622d, 0x0646e, 0x06470, 0x06496, 0x064a0, 0x06485,
+    0x06497, 0x0649c, 0x0648f, 0x0648b, 0x0648a, 0x0648c, 0x064a3, 0x0649f, 0x06468, 0x064b1, 0x06498, 0x06576,
+    0x0657a, 0x06579, 0x0657b, 0x065b2, 0x065b3, 0x066b5, 0x066b0, 0x066a9, 0x066b2, 0x066b7, 0x066aa, 0x066af,
+    0x06a00, 0x06a06, 0x06a17, 0x069e5, 0x069f8, 0x06a15, 0x069f1, 0x069e4, 0x06a20, 0x069ff, 0x069ec, 0x069e2,
+    0x06a1b, 0x06a1d, 0x069fe, 0x06a27, 0x069f2, 0x069ee, 0x06a14, 0x069f7, 0x069e7, 0x06a40, 0x06a08, 0x069e6,
+    0x069fb, 0x06a0d, 0x069fc, 0x069eb, 0x06a09, 0x06a04, 0x06a18, 0x06a25, 0x06a0f, 0x069f6, 0x06a26, 0x06a07,
+    0x069f4, 0x06a16, 0x06b51, 0x06ba5, 0x06ba3, 0x06ba2, 0x06ba6, 0x06c01, 0x06c00, 0x06bff, 0x06c02, 0x06f41,
+    0x06f26, 0x06f7e, 0x06f87, 0x06fc6, 0x06f92, 0x06f8d, 0x06f89, 0x06f8c, 0x06f62, 0x06f4f, 0x06f85, 0x06f5a,
+    0x06f96, 0x06f76, 0x06f6c, 0x06f82, 0x06f55, 0x06f72, 0x06f52, 0x06f50, 0x06f57, 0x06f94, 0x06f93, 0x06f5d,
+    0x06f00, 0x06f61, 0x06f6b, 0x06f7d, 0x06f67, 0x06f90, 0x06f53, 0x06f8b, 0x06f69, 0x06f7f, 0x06f95, 0x06f63,
+    0x06f77, 0x06f6a, 0x06f7b, 0x071b2, 0x071af, 0x0719b, 0x071b0, 0x071a0, 0x0719a, 0x071a9, 0x071b5, 0x0719d,
+    0x071a5, 0x0719e, 0x071a4, 0x071a1, 0x071aa, 0x0719c, 0x071a7, 0x071b3, 0x07298, 0x0729a, 0x07358, 0x07352,
+    0x0735e, 0x0735f, 0x07360, 0x0735d, 0x0735b, 0x07361, 0x0735a, 0x07359, 0x07362, 0x07487, 0x07489, 0x0748a,
+    0x07486, 0x07481, 0x0747d, 0x07485, 0x07488, 0x0747c, 0x07479, 0x07508, 0x07507, 0x0757e, 0x07625, 0x0761e,
+    0x07619, 0x0761d, 0x0761c, 0x07623, 0x0761a, 0x07628, 0x0761b, 0x0769c, 0x0769d, 0x0769e, 0x0769b, 0x0778d,
+    0x0778f, 0x07789, 0x07788, 0x078cd, 0x078bb, 0x078cf, 0x078cc, 0x078d1, 0x078ce, 0x078d4, 0x078c8, 0x078c3,
+    0x078c4, 0x078c9, 0x0799a, 0x079a1, 0x079a0, 0x0799c, 0x079a2, 0x0799b, 0x06b76, 0x07a39, 0x07ab2, 0x07ab4,
+    0x07ab3, 0x07bb7, 0x07bcb, 0x07bbe, 0x07bac, 0x07bce, 0x07baf, 0x07bb9, 0x07bca, 0x07bb5, 0x07cc5, 0x07cc8,
+    0x07ccc, 0x07ccb, 0x07df7, 0x07ddb, 0x07dea, 0x07de7, 0x07dd7, 0x07de1, 0x07e03, 0x07dfa, 0x07de6, 0x07df6,
+    0x07df1, 0x07df0, 0x07dee, 0x07ddf, 0x07f76, 0x07fac, 0x07fb0, 0x07fad, 0x07fed, 0x07feb, 0x07fea, 0x07fec,
+    0x07fe6, 0x07fe8, 0x08064, 0x08067, 0x081a3, 0x0819f, 0x0819e, 0x08195, 0x081a2, 0x08199, 0x08197, 0x08216,
+    0x0824f, 0x08253, 0x08252, 0x08250, 0x0824e, 0x08251, 0x08524, 0x0853b, 0x0850f, 0x08500, 0x08529, 0x0850e,
+    0x08509, 0x0850d, 0x0851f, 0x0850a, 0x08527, 0x0851c, 0x084fb, 0x0852b, 0x084fa, 0x08508, 0x0850c, 0x084f4,
+    0x0852a, 0x084f2, 0x08515, 0x084f7, 0x084eb, 0x084f3, 0x084fc, 0x08512, 0x084ea, 0x084e9, 0x08516, 0x084fe,
+    0x08528, 0x0851d, 0x0852e, 0x08502, 0x084fd, 0x0851e, 0x084f6, 0x08531, 0x08526, 0x084e7, 0x084e8, 0x084f0,
+    0x084ef, 0x084f9, 0x08518, 0x08520, 0x08530, 0x0850b, 0x08519, 0x0852f, 0x08662, 0x08756, 0x08763, 0x08764,
+    0x08777, 0x087e1, 0x08773, 0x08758, 0x08754, 0x0875b, 0x08752, 0x08761, 0x0875a, 0x08751, 0x0875e, 0x0876d,
+    0x0876a, 0x08750, 0x0874e, 0x0875f, 0x0875d, 0x0876f, 0x0876c, 0x0877a, 0x0876e, 0x0875c, 0x08765, 0x0874f,
+    0x0877b, 0x08775, 0x08762, 0x08767, 0x08769, 0x0885a, 0x08905, 0x0890c, 0x08914, 0x0890b, 0x08917, 0x08918,
+    0x08919, 0x08906, 0x08916, 0x08911, 0x0890e, 0x08909, 0x089a2, 0x089a4, 0x089a3, 0x089ed, 0x089f0, 0x089ec,
+    0x08acf, 0x08ac6, 0x08ab8, 0x08ad3, 0x08ad1, 0x08ad4, 0x08ad5, 0x08abb, 0x08ad7, 0x08abe, 0x08ac0, 0x08ac5,
+    0x08ad8, 0x08ac3, 0x08aba, 0x08abd, 0x08ad9, 0x08c3e, 0x08c4d, 0x08c8f, 0x08ce5, 0x08cdf, 0x08cd9, 0x08ce8,
+    0x08cda, 0x08cdd, 0x08ce7, 0x08da0, 0x08d9c, 0x08da1, 0x08d9b, 0x08e20, 0x08e23, 0x08e25, 0x08e24, 0x08e2e,
+    0x08e15, 0x08e1b, 0x08e16, 0x08e11, 0x08e19, 0x08e26, 0x08e27, 0x08e14, 0x08e12, 0x08e18, 0x08e13, 0x08e1c,
+    0x08e17, 0x08e1a, 0x08f2c, 0x08f24, 0x08f18, 0x08f1a, 0x08f20, 0x08f23, 0x08f16, 0x08f17, 0x09073, 0x09070,
+    0x0906f, 0x09067, 0x0906b, 0x0912f, 0x0912b, 0x09129, 0x0912a, 0x09132, 0x09126, 0x0912e, 0x09185, 0x09186,
+    0x0918a, 0x09181, 0x09182, 0x09184, 0x09180, 0x092d0, 0x092c3, 0x092c4, 0x092c0, 0x092d9, 0x092b6, 0x092cf,
+    0x092f1, 0x092df, 0x092d8, 0x092e9, 0x092d7, 0x092dd, 0x092cc, 0x092ef, 0x092c2, 0x092e8, 0x092ca, 0x092c8,
+    0x092ce, 0x092e6, 0x092cd, 0x092d5, 0x092c9, 0x092e0, 0x092de, 0x092e7, 0x092d1, 0x092d3, 0x092b5, 0x092e1,
+    0x092c6, 0x092b4, 0x0957c, 0x095ac, 0x095ab, 0x095ae, 0x095b0, 0x096a4, 0x096a2, 0x096d3, 0x09705, 0x09708,
+    0x09702, 0x0975a, 0x0978a, 0x0978e, 0x09788, 0x097d0, 0x097cf, 0x0981e, 0x0981d, 0x09826, 0x09829, 0x09828,
+    0x09820, 0x0981b, 0x09827, 0x098b2, 0x09908, 0x098fa, 0x09911, 0x09914, 0x09916, 0x09917, 0x09915, 0x099dc,
+    0x099cd, 0x099cf, 0x099d3, 0x099d4, 0x099ce, 0x099c9, 0x099d6, 0x099d8, 0x099cb, 0x099d7, 0x099cc, 0x09ab3,
+    0x09aec, 0x09aeb, 0x09af3, 0x09af2, 0x09af1, 0x09b46, 0x09b43, 0x09b67, 0x09b74, 0x09b71, 0x09b66, 0x09b76,
+    0x09b75, 0x09b70, 0x09b68, 0x09b64, 0x09b6c, 0x09cfc, 0x09cfa, 0x09cfd, 0x09cff, 0x09cf7, 0x09d07, 0x09d00,
+    0x09cf9, 0x09cfb, 0x09d08, 0x09d05, 0x09d04, 0x09e83, 0x09ed3, 0x09f0f, 0x09f10, 0x0511c, 0x05113, 0x05117,
+    0x0511a, 0x05111, 0x051de, 0x05334, 0x053e1, 0x05670, 0x05660, 0x0566e, 0x05673, 0x05666, 0x05663, 0x0566d,
+    0x05672, 0x0565e, 0x05677, 0x0571c, 0x0571b, 0x058c8, 0x058bd, 0x058c9, 0x058bf, 0x058ba, 0x058c2, 0x058bc,
+    0x058c6, 0x05b17, 0x05b19, 0x05b1b, 0x05b21, 0x05b14, 0x05b13, 0x05b10, 0x05b16, 0x05b28, 0x05b1a, 0x05b20,
+    0x05b1e, 0x05bef, 0x05dac, 0x05db1, 0x05da9, 0x05da7, 0x05db5, 0x05db0, 0x05dae, 0x05daa, 0x05da8, 0x05db2,
+    0x05dad, 0x05daf, 0x05db4, 0x05e67, 0x05e68, 0x05e66, 0x05e6f, 0x05ee9, 0x05ee7, 0x05ee6, 0x05ee8, 0x05ee5,
+    0x05f4b, 0x05fbc, 0x0619d, 0x061a8, 0x06196, 0x061c5, 0x061b4, 0x061c6, 0x061c1, 0x061cc, 0x061ba, 0x061bf,
+    0x061b8, 0x0618c, 0x064d7, 0x064d6, 0x064d0, 0x064cf, 0x064c9, 0x064bd, 0x06489, 0x064c3, 0x064db, 0x064f3,
+    0x064d9, 0x06533, 0x0657f, 0x0657c, 0x065a2, 0x066c8, 0x066be, 0x066c0, 0x066ca, 0x066cb, 0x066cf, 0x066bd,
+    0x066bb, 0x066ba, 0x066cc, 0x06723, 0x06a34, 0x06a66, 0x06a49, 0x06a67, 0x06a32, 0x06a68, 0x06a3e, 0x06a5d,
+    0x06a6d, 0x06a76, 0x06a5b, 0x06a51, 0x06a28, 0x06a5a, 0x06a3b, 0x06a3f, 0x06a41, 0x06a6a, 0x06a64, 0x06a50,
+    0x06a4f, 0x06a54, 0x06a6f, 0x06a69, 0x06a60, 0x06a3c, 0x06a5e, 0x06a56, 0x06a55, 0x06a4d, 0x06a4e, 0x06a46,
+    0x06b55, 0x06b54, 0x06b56, 0x06ba7, 0x06baa, 0x06bab, 0x06bc8, 0x06bc7, 0x06c04, 0x06c03, 0x06c06, 0x06fad,
+    0x06fcb, 0x06fa3, 0x06fc7, 0x06fbc, 0x06fce, 0x06fc8, 0x06f5e, 0x06fc4, 0x06fbd, 0x06f9e, 0x06fca, 0x06fa8,
+    0x07004, 0x06fa5, 0x06fae, 0x06fba, 0x06fac, 0x06faa, 0x06fcf, 0x06fbf, 0x06fb8, 0x06fa2, 0x06fc9, 0x06fab,
+    0x06fcd, 0x06faf, 0x06fb2, 0x06fb0, 0x071c5, 0x071c2, 0x071bf, 0x071b8, 0x071d6, 0x071c0, 0x071c1, 0x071cb,
+    0x071d4, 0x071ca, 0x071c7, 0x071cf, 0x071bd, 0x071d8, 0x071bc, 0x071c6, 0x071da, 0x071db, 0x0729d, 0x0729e,
+    0x07369, 0x07366, 0x07367, 0x0736c, 0x07365, 0x0736b, 0x0736a, 0x0747f, 0x0749a, 0x074a0, 0x07494, 0x07492,
+    0x07495, 0x074a1, 0x0750b, 0x07580, 0x0762f, 0x0762d, 0x07631, 0x0763d, 0x07633, 0x0763c, 0x07635, 0x07632,
+    0x07630, 0x076bb, 0x076e6, 0x0779a, 0x0779d, 0x077a1, 0x0779c, 0x0779b, 0x077a2, 0x077a3, 0x07795, 0x07799,
+    0x07797, 0x078dd, 0x078e9, 0x078e5, 0x078ea, 0x078de, 0x078e3, 0x078db, 0x078e1, 0x078e2, 0x078ed, 0x078df,
+    0x078e0, 0x079a4, 0x07a44, 0x07a48, 0x07a47, 0x07ab6, 0x07ab8, 0x07ab5, 0x07ab1, 0x07ab7, 0x07bde, 0x07be3,
+    0x07be7, 0x07bdd, 0x07bd5, 0x07be5, 0x07bda, 0x07be8, 0x07bf9, 0x07bd4, 0x07bea, 0x07be2, 0x07bdc, 0x07beb,
+    0x07bd8, 0x07bdf, 0x07cd2, 0x07cd4, 0x07cd7, 0x07cd0, 0x07cd1, 0x07e12, 0x07e21, 0x07e17, 0x07e0c, 0x07e1f,
+    0x07e20, 0x07e13, 0x07e0e, 0x07e1c, 0x07e15, 0x07e1a, 0x07e22, 0x07e0b, 0x07e0f, 0x07e16, 0x07e0d, 0x07e14,
+    0x07e25, 0x07e24, 0x07f43, 0x07f7b, 0x07f7c, 0x07f7a, 0x07fb1, 0x07fef, 0x0802a, 0x08029, 0x0806c, 0x081b1,
+    0x081a6, 0x081ae, 0x081b9, 0x081b5, 0x081ab, 0x081b0, 0x081ac, 0x081b4, 0x081b2, 0x081b7, 0x081a7, 0x081f2,
+    0x08255, 0x08256, 0x08257, 0x08556, 0x08545, 0x0856b, 0x0854d, 0x08553, 0x08561, 0x08558, 0x08540, 0x08546,
+    0x08564, 0x08541, 0x08562, 0x08544, 0x08551, 0x08547, 0x08563, 0x0853e, 0x0855b, 0x08571, 0x0854e, 0x0856e,
+    0x08575, 0x08555, 0x08567, 0x08560, 0x0858c, 0x08566, 0x0855d, 0x08554, 0x08565, 0x0856c, 0x08663, 0x08665,
+    0x08664, 0x0879b, 0x0878f, 0x08797, 0x08793, 0x08792, 0x08788, 0x08781, 0x08796, 0x08798, 0x08779, 0x08787,
+    0x087a3, 0x08785, 0x08790, 0x08791, 0x0879d, 0x08784, 0x08794, 0x0879c, 0x0879a, 0x08789, 0x0891e, 0x08926,
+    0x08930, 0x0892d, 0x0892e, 0x08927, 0x08931, 0x08922, 0x08929, 0x08923, 0x0892f, 0x0892c, 0x0891f, 0x089f1,
+    0x08ae0, 0x08ae2, 0x08af2, 0x08af4, 0x08af5, 0x08add, 0x08b14, 0x08ae4, 0x08adf, 0x08af0, 0x08ac8, 0x08ade,
+    0x08ae1, 0x08ae8, 0x08aff, 0x08aef, 0x08afb, 0x08c91, 0x08c92, 0x08c90, 0x08cf5, 0x08cee, 0x08cf1, 0x08cf0,
+    0x08cf3, 0x08d6c, 0x08d6e, 0x08da5, 0x08da7, 0x08e33, 0x08e3e, 0x08e38, 0x08e40, 0x08e45, 0x08e36, 0x08e3c,
+    0x08e3d, 0x08e41, 0x08e30, 0x08e3f, 0x08ebd, 0x08f36, 0x08f2e, 0x08f35, 0x08f32, 0x08f39, 0x08f37, 0x08f34,
+    0x09076, 0x09079, 0x0907b, 0x09086, 0x090fa, 0x09133, 0x09135, 0x09136, 0x09193, 0x09190, 0x09191, 0x0918d,
+    0x0918f, 0x09327, 0x0931e, 0x09308, 0x0931f, 0x09306, 0x0930f, 0x0937a, 0x09338, 0x0933c, 0x0931b, 0x09323,
+    0x09312, 0x09301, 0x09346, 0x0932d, 0x0930e, 0x0930d, 0x092cb, 0x0931d, 0x092fa, 0x09325, 0x09313, 0x092f9,
+    0x092f7, 0x09334, 0x09302, 0x09324, 0x092ff, 0x09329, 0x09339, 0x09335, 0x0932a, 0x09314, 0x0930c, 0x0930b,
+    0x092fe, 0x09309, 0x09300, 0x092fb, 0x09316, 0x095bc, 0x095cd, 0x095be, 0x095b9, 0x095ba, 0x095b6, 0x095bf,
+    0x095b5, 0x095bd, 0x096a9, 0x096d4, 0x0970b, 0x09712, 0x09710, 0x09799, 0x09797, 0x09794, 0x097f0, 0x097f8,
+    0x09835, 0x0982f, 0x09832, 0x09924, 0x0991f, 0x09927, 0x09929, 0x0999e, 0x099ee, 0x099ec, 0x099e5, 0x099e4,
+    0x099f0, 0x099e3, 0x099ea, 0x099e9, 0x099e7, 0x09ab9, 0x09abf, 0x09ab4, 0x09abb, 0x09af6, 0x09afa, 0x09af9,
+    0x09af7, 0x09b33, 0x09b80, 0x09b85, 0x09b87, 0x09b7c, 0x09b7e, 0x09b7b, 0x09b82, 0x09b93, 0x09b92, 0x09b90,
+    0x09b7a, 0x09b95, 0x09b7d, 0x09b88, 0x09d25, 0x09d17, 0x09d20, 0x09d1e, 0x09d14, 0x09d29, 0x09d1d, 0x09d18,
+    0x09d22, 0x09d10, 0x09d19, 0x09d1f, 0x09e88, 0x09e86, 0x09e87, 0x09eae, 0x09ead, 0x09ed5, 0x09ed6, 0x09efa,
+    0x09f12, 0x09f3d, 0x05126, 0x05125, 0x05122, 0x05124, 0x05120, 0x05129, 0x052f4, 0x05693, 0x0568c, 0x0568d,
+    0x05686, 0x05684, 0x05683, 0x0567e, 0x05682, 0x0567f, 0x05681, 0x058d6, 0x058d4, 0x058cf, 0x058d2, 0x05b2d,
+    0x05b25, 0x05b32, 0x05b23, 0x05b2c, 0x05b27, 0x05b26, 0x05b2f, 0x05b2e, 0x05b7b, 0x05bf1, 0x05bf2, 0x05db7,
+    0x05e6c, 0x05e6a, 0x05fbe, 0x05fbb, 0x061c3, 0x061b5, 0x061bc, 0x061e7, 0x061e0, 0x061e5, 0x061e4, 0x061e8,
+    0x061de, 0x064ef, 0x064e9, 0x064e3, 0x064eb, 0x064e4, 0x064e8, 0x06581, 0x06580, 0x065b6, 0x065da, 0x066d2,
+    0x06a8d, 0x06a96, 0x06a81, 0x06aa5, 0x06a89, 0x06a9f, 0x06a9b, 0x06aa1, 0x06a9e, 0x06a87, 0x06a93, 0x06a8e,
+    0x06a95, 0x06a83, 0x06aa8, 0x06aa4, 0x06a91, 0x06a7f, 0x06aa6, 0x06a9a, 0x06a85, 0x06a8c, 0x06a92, 0x06b5b,
+    0x06bad, 0x06c09, 0x06fcc, 0x06fa9, 0x06ff4, 0x06fd4, 0x06fe3, 0x06fdc, 0x06fed, 0x06fe7, 0x06fe6, 0x06fde,
+    0x06ff2, 0x06fdd, 0x06fe2, 0x06fe8, 0x071e1, 0x071f1, 0x071e8, 0x071f2, 0x071e4, 0x071f0, 0x071e2, 0x07373,
+    0x0736e, 0x0736f, 0x07497, 0x074b2, 0x074ab, 0x07490, 0x074aa, 0x074ad, 0x074b1, 0x074a5, 0x074af, 0x07510,
+    0x07511, 0x07512, 0x0750f, 0x07584, 0x07643, 0x07648, 0x07649, 0x07647, 0x076a4, 0x076e9, 0x077b5, 0x077ab,
+    0x077b2, 0x077b7, 0x077b6, 0x077b4, 0x077b1, 0x077a8, 0x077f0, 0x078f3, 0x078fd, 0x07902, 0x078fb, 0x078fc,
+    0x078f2, 0x07905, 0x078f9, 0x078fe, 0x07904, 0x079ab, 0x079a8, 0x07a5c, 0x07a5b, 0x07a56, 0x07a58, 0x07a54,
+    0x07a5a, 0x07abe, 0x07ac0, 0x07ac1, 0x07c05, 0x07c0f, 0x07bf2, 0x07c00, 0x07bff, 0x07bfb, 0x07c0e, 0x07bf4,
+    0x07c0b, 0x07bf3, 0x07c02, 0x07c09, 0x07c03, 0x07c01, 0x07bf8, 0x07bfd, 0x07c06, 0x07bf0, 0x07bf1, 0x07c10,
+    0x07c0a, 0x07ce8, 0x07e2d, 0x07e3c, 0x07e42, 0x07e33, 0x09848, 0x07e38, 0x07e2a, 0x07e49, 0x07e40, 0x07e47,
+    0x07e29, 0x07e4c, 0x07e30, 0x07e3b, 0x07e36, 0x07e44, 0x07e3a, 0x07f45, 0x07f7f, 0x07f7e, 0x07f7d, 0x07ff4,
+    0x07ff2, 0x0802c, 0x081bb, 0x081c4, 0x081cc, 0x081ca, 0x081c5, 0x081c7, 0x081bc, 0x081e9, 0x0825b, 0x0825a,
+    0x0825c, 0x08583, 0x08580, 0x0858f, 0x085a7, 0x08595, 0x085a0, 0x0858b, 0x085a3, 0x0857b, 0x085a4, 0x0859a,
+    0x0859e, 0x08577, 0x0857c, 0x08589, 0x085a1, 0x0857a, 0x08578, 0x08557, 0x0858e, 0x08596, 0x08586, 0x0858d,
+    0x08599, 0x0859d, 0x08581, 0x085a2, 0x08582, 0x08588, 0x08585, 0x08579, 0x08576, 0x08598, 0x08590, 0x0859f,
+    0x08668, 0x087be, 0x087aa, 0x087ad, 0x087c5, 0x087b0, 0x087ac, 0x087b9, 0x087b5, 0x087bc, 0x087ae, 0x087c9,
+    0x087c3, 0x087c2, 0x087cc, 0x087b7, 0x087af, 0x087c4, 0x087ca, 0x087b4, 0x087b6, 0x087bf, 0x087b8, 0x087bd,
+    0x087de, 0x087b2, 0x08935, 0x08933, 0x0893c, 0x0893e, 0x08941, 0x08952, 0x08937, 0x08942, 0x089ad, 0x089af,
+    0x089ae, 0x089f2, 0x089f3, 0x08b1e, 0x08b18, 0x08b16, 0x08b11, 0x08b05, 0x08b0b, 0x08b22, 0x08b0f, 0x08b12,
+    0x08b15, 0x08b07, 0x08b0d, 0x08b08, 0x08b06, 0x08b1c, 0x08b13, 0x08b1a, 0x08c4f, 0x08c70, 0x08c72, 0x08c71,
+    0x08c6f, 0x08c95, 0x08c94, 0x08cf9, 0x08d6f, 0x08e4e, 0x08e4d, 0x08e53, 0x08e50, 0x08e4c, 0x08e47, 0x08f43,
+    0x08f40, 0x09085, 0x0907e, 0x09138, 0x0919a, 0x091a2, 0x0919b, 0x09199, 0x0919f, 0x091a1, 0x0919d, 0x091a0,
+    0x093a1, 0x09383, 0x093af, 0x09364, 0x09356, 0x09347, 0x0937c, 0x09358, 0x0935c, 0x09376, 0x09349, 0x09350,
+    0x09351, 0x09360, 0x0936d, 0x0938f, 0x0934c, 0x0936a, 0x09379, 0x09357, 0x09355, 0x09352, 0x0934f, 0x09371,
+    0x09377, 0x0937b, 0x09361, 0x0935e, 0x09363, 0x09367, 0x09380, 0x0934e, 0x09359, 0x095c7, 0x095c0, 0x095c9,
+    0x095c3, 0x095c5, 0x095b7, 0x096ae, 0x096b0, 0x096ac, 0x09720, 0x0971f, 0x09718, 0x0971d, 0x09719, 0x0979a,
+    0x097a1, 0x0979c, 0x0979e, 0x0979d, 0x097d5, 0x097d4, 0x097f1, 0x09841, 0x09844, 0x0984a, 0x09849, 0x09845,
+    0x09843, 0x09925, 0x0992b, 0x0992c, 0x0992a, 0x09933, 0x09932, 0x0992f, 0x0992d, 0x09931, 0x09930, 0x09998,
+    0x099a3, 0x099a1, 0x09a02, 0x099fa, 0x099f4, 0x099f7, 0x099f9, 0x099f8, 0x099f6, 0x099fb, 0x099fd, 0x099fe,
+    0x099fc, 0x09a03, 0x09abe, 0x09afe, 0x09afd, 0x09b01, 0x09afc, 0x09b48, 0x09b9a, 0x09ba8, 0x09b9e, 0x09b9b,
+    0x09ba6, 0x09ba1, 0x09ba5, 0x09ba4, 0x09b86, 0x09ba2, 0x09ba0, 0x09baf, 0x09d33, 0x09d41, 0x09d67, 0x09d36,
+    0x09d2e, 0x09d2f, 0x09d31, 0x09d38, 0x09d30, 0x09d45, 0x09d42, 0x09d43, 0x09d3e, 0x09d37, 0x09d40, 0x09d3d,
+    0x07ff5, 0x09d2d, 0x09e8a, 0x09e89, 0x09e8d, 0x09eb0, 0x09ec8, 0x09eda, 0x09efb, 0x09eff, 0x09f24, 0x09f23,
+    0x09f22, 0x09f54, 0x09fa0, 0x05131, 0x0512d, 0x0512e, 0x05698, 0x0569c, 0x05697, 0x0569a, 0x0569d, 0x05699,
+    0x05970, 0x05b3c, 0x05c69, 0x05c6a, 0x05dc0, 0x05e6d, 0x05e6e, 0x061d8, 0x061df, 0x061ed, 0x061ee, 0x061f1,
+    0x061ea, 0x061f0, 0x061eb, 0x061d6, 0x061e9, 0x064ff, 0x06504, 0x064fd, 0x064f8, 0x06501, 0x06503, 0x064fc,
+    0x06594, 0x065db, 0x066da, 0x066db, 0x066d8, 0x06ac5, 0x06ab9, 0x06abd, 0x06ae1, 0x06ac6, 0x06aba, 0x06ab6,
+    0x06ab7, 0x06ac7, 0x06ab4, 0x06aad, 0x06b5e, 0x06bc9, 0x06c0b, 0x07007, 0x0700c, 0x0700d, 0x07001, 0x07005,
+    0x07014, 0x0700e, 0x06fff, 0x07000, 0x06ffb, 0x07026, 0x06ffc, 0x06ff7, 0x0700a, 0x07201, 0x071ff, 0x071f9,
+    0x07203, 0x071fd, 0x07376, 0x074b8, 0x074c0, 0x074b5, 0x074c1, 0x074be, 0x074b6, 0x074bb, 0x074c2, 0x07514,
+    0x07513, 0x0765c, 0x07664, 0x07659, 0x07650, 0x07653, 0x07657, 0x0765a, 0x076a6, 0x076bd, 0x076ec, 0x077c2,
+    0x077ba, 0x078ff, 0x0790c, 0x07913, 0x07914, 0x07909, 0x07910, 0x07912, 0x07911, 0x079ad, 0x079ac, 0x07a5f,
+    0x07c1c, 0x07c29, 0x07c19, 0x07c20, 0x07c1f, 0x07c2d, 0x07c1d, 0x07c26, 0x07c28, 0x07c22, 0x07c25, 0x07c30,
+    0x07e5c, 0x07e50, 0x07e56, 0x07e63, 0x07e58, 0x07e62, 0x07e5f, 0x07e51, 0x07e60, 0x07e57, 0x07e53, 0x07fb5,
+    0x07fb3, 0x07ff7, 0x07ff8, 0x08075, 0x081d1, 0x081d2, 0x081d0, 0x0825f, 0x0825e, 0x085b4, 0x085c6, 0x085c0,
+    0x085c3, 0x085c2, 0x085b3, 0x085b5, 0x085bd, 0x085c7, 0x085c4, 0x085bf, 0x085cb, 0x085ce, 0x085c8, 0x085c5,
+    0x085b1, 0x085b6, 0x085d2, 0x08624, 0x085b8, 0x085b7, 0x085be, 0x08669, 0x087e7, 0x087e6, 0x087e2, 0x087db,
+    0x087eb, 0x087ea, 0x087e5, 0x087df, 0x087f3, 0x087e4, 0x087d4, 0x087dc, 0x087d3, 0x087ed, 0x087d8, 0x087e3,
+    0x087a4, 0x087d7, 0x087d9, 0x08801, 0x087f4, 0x087e8, 0x087dd, 0x08953, 0x0894b, 0x0894f, 0x0894c, 0x08946,
+    0x08950, 0x08951, 0x08949, 0x08b2a, 0x08b27, 0x08b23, 0x08b33, 0x08b30, 0x08b35, 0x08b47, 0x08b2f, 0x08b3c,
+    0x08b3e, 0x08b31, 0x08b25, 0x08b37, 0x08b26, 0x08b36, 0x08b2e, 0x08b24, 0x08b3b, 0x08b3d, 0x08b3a, 0x08c42,
+    0x08c75, 0x08c99, 0x08c98, 0x08c97, 0x08cfe, 0x08d04, 0x08d02, 0x08d00, 0x08e5c, 0x08e62, 0x08e60, 0x08e57,
+    0x08e56, 0x08e5e, 0x08e65, 0x08e67, 0x08e5b, 0x08e5a, 0x08e61, 0x08e5d, 0x08e69, 0x08e54, 0x08f46, 0x08f47,
+    0x08f48, 0x08f4b, 0x09128, 0x0913a, 0x0913b, 0x0913e, 0x091a8, 0x091a5, 0x091a7, 0x091af, 0x091aa, 0x093b5,
+    0x0938c, 0x09392, 0x093b7, 0x0939b, 0x0939d, 0x09389, 0x093a7, 0x0938e, 0x093aa, 0x0939e, 0x093a6, 0x09395,
+    0x09388, 0x09399, 0x0939f, 0x0938d, 0x093b1, 0x09391, 0x093b2, 0x093a4, 0x093a8, 0x093b4, 0x093a3, 0x093a5,
+    0x095d2, 0x095d3, 0x095d1, 0x096b3, 0x096d7, 0x096da, 0x05dc2, 0x096df, 0x096d8, 0x096dd, 0x09723, 0x09722,
+    0x09725, 0x097ac, 0x097ae, 0x097a8, 0x097ab, 0x097a4, 0x097aa, 0x097a2, 0x097a5, 0x097d7, 0x097d9, 0x097d6,
+    0x097d8, 0x097fa, 0x09850, 0x09851, 0x09852, 0x098b8, 0x09941, 0x0993c, 0x0993a, 0x09a0f, 0x09a0b, 0x09a09,
+    0x09a0d, 0x09a04, 0x09a11, 0x09a0a, 0x09a05, 0x09a07, 0x09a06, 0x09ac0, 0x09adc, 0x09b08, 0x09b04, 0x09b05,
+    0x09b29, 0x09b35, 0x09b4a, 0x09b4c, 0x09b4b, 0x09bc7, 0x09bc6, 0x09bc3, 0x09bbf, 0x09bc1, 0x09bb5, 0x09bb8,
+    0x09bd3, 0x09bb6, 0x09bc4, 0x09bb9, 0x09bbd, 0x09d5c, 0x09d53, 0x09d4f, 0x09d4a, 0x09d5b, 0x09d4b, 0x09d59,
+    0x09d56, 0x09d4c, 0x09d57, 0x09d52, 0x09d54, 0x09d5f, 0x09d58, 0x09d5a, 0x09e8e, 0x09e8c, 0x09edf, 0x09f01,
+    0x09f00, 0x09f16, 0x09f25, 0x09f2b, 0x09f2a, 0x09f29, 0x09f28, 0x09f4c, 0x09f55, 0x05134, 0x05135, 0x05296,
+    0x052f7, 0x053b4, 0x056ab, 0x056ad, 0x056a6, 0x056a7, 0x056aa, 0x056ac, 0x058da, 0x058dd, 0x058db, 0x05912,
+    0x05b3d, 0x05b3e, 0x05b3f, 0x05dc3, 0x05e70, 0x05fbf, 0x061fb, 0x06507, 0x06510, 0x0650d, 0x06509, 0x0650c,
+    0x0650e, 0x06584, 0x065de, 0x065dd, 0x066de, 0x06ae7, 0x06ae0, 0x06acc, 0x06ad1, 0x06ad9, 0x06acb, 0x06adf,
+    0x06adc, 0x06ad0, 0x06aeb, 0x06acf, 0x06acd, 0x06ade, 0x06b60, 0x06bb0, 0x06c0c, 0x07019, 0x07027, 0x07020,
+    0x07016, 0x0702b, 0x07021, 0x07022, 0x07023, 0x07029, 0x07017, 0x07024, 0x0701c, 0x0702a, 0x0720c, 0x0720a,
+    0x07207, 0x07202, 0x07205, 0x072a5, 0x072a6, 0x072a4, 0x072a3, 0x072a1, 0x074cb, 0x074c5, 0x074b7, 0x074c3,
+    0x07516, 0x07660, 0x077c9, 0x077ca, 0x077c4, 0x077f1, 0x0791d, 0x0791b, 0x07921, 0x0791c, 0x07917, 0x0791e,
+    0x079b0, 0x07a67, 0x07a68, 0x07c33, 0x07c3c, 0x07c39, 0x07c2c, 0x07c3b, 0x07cec, 0x07cea, 0x07e76, 0x07e75,
+    0x07e78, 0x07e70, 0x07e77, 0x07e6f, 0x07e7a, 0x07e72, 0x07e74, 0x07e68, 0x07f4b, 0x07f4a, 0x07f83, 0x07f86,
+    0x07fb7, 0x07ffd, 0x07ffe, 0x08078, 0x081d7, 0x081d5, 0x08264, 0x08261, 0x08263, 0x085eb, 0x085f1, 0x085ed,
+    0x085d9, 0x085e1, 0x085e8, 0x085da, 0x085d7, 0x085ec, 0x085f2, 0x085f8, 0x085d8, 0x085df, 0x085e3, 0x085dc,
+    0x085d1, 0x085f0, 0x085e6, 0x085ef, 0x085de, 0x085e2, 0x08800, 0x087fa, 0x08803, 0x087f6, 0x087f7, 0x08809,
+    0x0880c, 0x0880b, 0x08806, 0x087fc, 0x08808, 0x087ff, 0x0880a, 0x08802, 0x08962, 0x0895a, 0x0895b, 0x08957,
+    0x08961, 0x0895c, 0x08958, 0x0895d, 0x08959, 0x08988, 0x089b7, 0x089b6, 0x089f6, 0x08b50, 0x08b48, 0x08b4a,
+    0x08b40, 0x08b53, 0x08b56, 0x08b54, 0x08b4b, 0x08b55, 0x08b51, 0x08b42, 0x08b52, 0x08b57, 0x08c43, 0x08c77,
+    0x08c76, 0x08c9a, 0x08d06, 0x08d07, 0x08d09, 0x08dac, 0x08daa, 0x08dad, 0x08dab, 0x08e6d, 0x08e78, 0x08e73,
+    0x08e6a, 0x08e6f, 0x08e7b, 0x08ec2, 0x08f52, 0x08f51, 0x08f4f, 0x08f50, 0x08f53, 0x08fb4, 0x09140, 0x0913f,
+    0x091b0, 0x091ad, 0x093de, 0x093c7, 0x093cf, 0x093c2, 0x093da, 0x093d0, 0x093f9, 0x093ec, 0x093cc, 0x093d9,
+    0x093a9, 0x093e6, 0x093ca, 0x093d4, 0x093ee, 0x093e3, 0x093d5, 0x093c4, 0x093ce, 0x093c0, 0x093d2, 0x093e7,
+    0x0957d, 0x095da, 0x095db, 0x096e1, 0x09729, 0x0972b, 0x0972c, 0x09728, 0x09726, 0x097b3, 0x097b7, 0x097b6,
+    0x097dd, 0x097de, 0x097df, 0x0985c, 0x09859, 0x0985d, 0x09857, 0x098bf, 0x098bd, 0x098bb, 0x098be, 0x09948,
+    0x09947, 0x09943, 0x099a6, 0x099a7, 0x09a1a, 0x09a15, 0x09a25, 0x09a1d, 0x09a24, 0x09a1b, 0x09a22, 0x09a20,
+    0x09a27, 0x09a23, 0x09a1e, 0x09a1c, 0x09a14, 0x09ac2, 0x09b0b, 0x09b0a, 0x09b0e, 0x09b0c, 0x09b37, 0x09bea,
+    0x09beb, 0x09be0, 0x09bde, 0x09be4, 0x09be6, 0x09be2, 0x09bf0, 0x09bd4, 0x09bd7, 0x09bec, 0x09bdc, 0x09bd9,
+    0x09be5, 0x09bd5, 0x09be1, 0x09bda, 0x09d77, 0x09d81, 0x09d8a, 0x09d84, 0x09d88, 0x09d71, 0x09d80, 0x09d78,
+    0x09d86, 0x09d8b, 0x09d8c, 0x09d7d, 0x09d6b, 0x09d74, 0x09d75, 0x09d70, 0x09d69, 0x09d85, 0x09d73, 0x09d7b,
+    0x09d82, 0x09d6f, 0x09d79, 0x09d7f, 0x09d87, 0x09d68, 0x09e94, 0x09e91, 0x09ec0, 0x09efc, 0x09f2d, 0x09f40,
+    0x09f41, 0x09f4d, 0x09f56, 0x09f57, 0x09f58, 0x05337, 0x056b2, 0x056b5, 0x056b3, 0x058e3, 0x05b45, 0x05dc6,
+    0x05dc7, 0x05eee, 0x05eef, 0x05fc0, 0x05fc1, 0x061f9, 0x06517, 0x06516, 0x06515, 0x06513, 0x065df, 0x066e8,
+    0x066e3, 0x066e4, 0x06af3, 0x06af0, 0x06aea, 0x06ae8, 0x06af9, 0x06af1, 0x06aee, 0x06aef, 0x0703c, 0x07035,
+    0x0702f, 0x07037, 0x07034, 0x07031, 0x07042, 0x07038, 0x0703f, 0x0703a, 0x07039, 0x07040, 0x0703b, 0x07033,
+    0x07041, 0x07213, 0x07214, 0x072a8, 0x0737d, 0x0737c, 0x074ba, 0x076ab, 0x076aa, 0x076be, 0x076ed, 0x077cc,
+    0x077ce, 0x077cf, 0x077cd, 0x077f2, 0x07925, 0x07923, 0x07927, 0x07928, 0x07924, 0x07929, 0x079b2, 0x07a6e,
+    0x07a6c, 0x07a6d, 0x07af7, 0x07c49, 0x07c48, 0x07c4a, 0x07c47, 0x07c45, 0x07cee, 0x07e7b, 0x07e7e, 0x07e81,
+    0x07e80, 0x07fba, 0x07fff, 0x08079, 0x081db, 0x081d9, 0x0820b, 0x08268, 0x08269, 0x08622, 0x085ff, 0x08601,
+    0x085fe, 0x0861b, 0x08600, 0x085f6, 0x08604, 0x08609, 0x08605, 0x0860c, 0x085fd, 0x08819, 0x08810, 0x08811,
+    0x08817, 0x08813, 0x08816, 0x08963, 0x08966, 0x089b9, 0x089f7, 0x08b60, 0x08b6a, 0x08b5d, 0x08b68, 0x08b63,
+    0x08b65, 0x08b67, 0x08b6d, 0x08dae, 0x08e86, 0x08e88, 0x08e84, 0x08f59, 0x08f56, 0x08f57, 0x08f55, 0x08f58,
+    0x08f5a, 0x0908d, 0x09143, 0x09141, 0x091b7, 0x091b5, 0x091b2, 0x091b3, 0x0940b, 0x09413, 0x093fb, 0x09420,
+    0x0940f, 0x09414, 0x093fe, 0x09415, 0x09410, 0x09428, 0x09419, 0x0940d, 0x093f5, 0x09400, 0x093f7, 0x09407,
+    0x0940e, 0x09416, 0x09412, 0x093fa, 0x09409, 0x093f8, 0x0940a, 0x093ff, 0x093fc, 0x0940c, 0x093f6, 0x09411,
+    0x09406, 0x095de, 0x095e0, 0x095df, 0x0972e, 0x0972f, 0x097b9, 0x097bb, 0x097fd, 0x097fe, 0x09860, 0x09862,
+    0x09863, 0x0985f, 0x098c1, 0x098c2, 0x09950, 0x0994e, 0x09959, 0x0994c, 0x0994b, 0x09953, 0x09a32, 0x09a34,
+    0x09a31, 0x09a2c, 0x09a2a, 0x09a36, 0x09a29, 0x09a2e, 0x09a38, 0x09a2d, 0x09ac7, 0x09aca, 0x09ac6, 0x09b10,
+    0x09b12, 0x09b11, 0x09c0b, 0x09c08, 0x09bf7, 0x09c05, 0x09c12, 0x09bf8, 0x09c40, 0x09c07, 0x09c0e, 0x09c06,
+    0x09c17, 0x09c14, 0x09c09, 0x09d9f, 0x09d99, 0x09da4, 0x09d9d, 0x09d92, 0x09d98, 0x09d90, 0x09d9b, 0x09da0,
+    0x09d94, 0x09d9c, 0x09daa, 0x09d97, 0x09da1, 0x09d9a, 0x09da2, 0x09da8, 0x09d9e, 0x09da3, 0x09dbf, 0x09da9,
+    0x09d96, 0x09da6, 0x09da7, 0x09e99, 0x09e9b, 0x09e9a, 0x09ee5, 0x09ee4, 0x09ee7, 0x09ee6, 0x09f30, 0x09f2e,
+    0x09f5b, 0x09f60, 0x09f5e, 0x09f5d, 0x09f59, 0x09f91, 0x0513a, 0x05139, 0x05298, 0x05297, 0x056c3, 0x056bd,
+    0x056be, 0x05b48, 0x05b47, 0x05dcb, 0x05dcf, 0x05ef1, 0x061fd, 0x0651b, 0x06b02, 0x06afc, 0x06b03, 0x06af8,
+    0x06b00, 0x07043, 0x07044, 0x0704a, 0x07048, 0x07049, 0x07045, 0x07046, 0x0721d, 0x0721a, 0x07219, 0x0737e,
+    0x07517, 0x0766a, 0x077d0, 0x0792d, 0x07931, 0x0792f, 0x07c54, 0x07c53, 0x07cf2, 0x07e8a, 0x07e87, 0x07e88,
+    0x07e8b, 0x07e86, 0x07e8d, 0x07f4d, 0x07fbb, 0x08030, 0x081dd, 0x08618, 0x0862a, 0x08626, 0x0861f, 0x08623,
+    0x0861c, 0x08619, 0x08627, 0x0862e, 0x08621, 0x08620, 0x08629, 0x0861e, 0x08625, 0x08829, 0x0881d, 0x0881b,
+    0x08820, 0x08824, 0x0881c, 0x0882b, 0x0884a, 0x0896d, 0x08969, 0x0896e, 0x0896b, 0x089fa, 0x08b79, 0x08b78,
+    0x08b45, 0x08b7a, 0x08b7b, 0x08d10, 0x08d14, 0x08daf, 0x08e8e, 0x08e8c, 0x08f5e, 0x08f5b, 0x08f5d, 0x09146,
+    0x09144, 0x09145, 0x091b9, 0x0943f, 0x0943b, 0x09436, 0x09429, 0x0943d, 0x0943c, 0x09430, 0x09439, 0x0942a,
+    0x09437, 0x0942c, 0x09440, 0x09431, 0x095e5, 0x095e4, 0x095e3, 0x09735, 0x0973a, 0x097bf, 0x097e1, 0x09864,
+    0x098c9, 0x098c6, 0x098c0, 0x09958, 0x09956, 0x09a39, 0x09a3d, 0x09a46, 0x09a44, 0x09a42, 0x09a41, 0x09a3a,
+    0x09a3f, 0x09acd, 0x09b15, 0x09b17, 0x09b18, 0x09b16, 0x09b3a, 0x09b52, 0x09c2b, 0x09c1d, 0x09c1c, 0x09c2c,
+    0x09c23, 0x09c28, 0x09c29, 0x09c24, 0x09c21, 0x09db7, 0x09db6, 0x09dbc, 0x09dc1, 0x09dc7, 0x09dca, 0x09dcf,
+    0x09dbe, 0x09dc5, 0x09dc3, 0x09dbb, 0x09db5, 0x09dce, 0x09db9, 0x09dba, 0x09dac, 0x09dc8, 0x09db1, 0x09dad,
+    0x09dcc, 0x09db3, 0x09dcd, 0x09db2, 0x09e7a, 0x09e9c, 0x09eeb, 0x09eee, 0x09eed, 0x09f1b, 0x09f18, 0x09f1a,
+    0x09f31, 0x09f4e, 0x09f65, 0x09f64, 0x09f92, 0x04eb9, 0x056c6, 0x056c5, 0x056cb, 0x05971, 0x05b4b, 0x05b4c,
+    0x05dd5, 0x05dd1, 0x05ef2, 0x06521, 0x06520, 0x06526, 0x06522, 0x06b0b, 0x06b08, 0x06b09, 0x06c0d, 0x07055,
+    0x07056, 0x07057, 0x07052, 0x0721e, 0x0721f, 0x072a9, 0x0737f, 0x074d8, 0x074d5, 0x074d9, 0x074d7, 0x0766d,
+    0x076ad, 0x07935, 0x079b4, 0x07a70, 0x07a71, 0x07c57, 0x07c5c, 0x07c59, 0x07c5b, 0x07c5a, 0x07cf4, 0x07cf1,
+    0x07e91, 0x07f4f, 0x07f87, 0x081de, 0x0826b, 0x08634, 0x08635, 0x08633, 0x0862c, 0x08632, 0x08636, 0x0882c,
+    0x08828, 0x08826, 0x0882a, 0x08825, 0x08971, 0x089bf, 0x089be, 0x089fb, 0x08b7e, 0x08b84, 0x08b82, 0x08b86,
+    0x08b85, 0x08b7f, 0x08d15, 0x08e95, 0x08e94, 0x08e9a, 0x08e92, 0x08e90, 0x08e96, 0x08e97, 0x08f60, 0x08f62,
+    0x09147, 0x0944c, 0x09450, 0x0944a, 0x0944b, 0x0944f, 0x09447, 0x09445, 0x09448, 0x09449, 0x09446, 0x0973f,
+    0x097e3, 0x0986a, 0x09869, 0x098cb, 0x09954, 0x0995b, 0x09a4e, 0x09a53, 0x09a54, 0x09a4c, 0x09a4f, 0x09a48,
+    0x09a4a, 0x09a49, 0x09a52, 0x09a50, 0x09ad0, 0x09b19, 0x09b2b, 0x09b3b, 0x09b56, 0x09b55, 0x09c46, 0x09c48,
+    0x09c3f, 0x09c44, 0x09c39, 0x09c33, 0x09c41, 0x09c3c, 0x09c37, 0x09c34, 0x09c32, 0x09c3d, 0x09c36, 0x09ddb,
+    0x09dd2, 0x09dde, 0x09dda, 0x09dcb, 0x09dd0, 0x09ddc, 0x09dd1, 0x09ddf, 0x09de9, 0x09dd9, 0x09dd8, 0x09dd6,
+    0x09df5, 0x09dd5, 0x09ddd, 0x09eb6, 0x09ef0, 0x09f35, 0x09f33, 0x09f32, 0x09f42, 0x09f6b, 0x09f95, 0x09fa2,
+    0x0513d, 0x05299, 0x058e8, 0x058e7, 0x05972, 0x05b4d, 0x05dd8, 0x0882f, 0x05f4f, 0x06201, 0x06203, 0x06204,
+    0x06529, 0x06525, 0x06596, 0x066eb, 0x06b11, 0x06b12, 0x06b0f, 0x06bca, 0x0705b, 0x0705a, 0x07222, 0x07382,
+    0x07381, 0x07383, 0x07670, 0x077d4, 0x07c67, 0x07c66, 0x07e95, 0x0826c, 0x0863a, 0x08640, 0x08639, 0x0863c,
+    0x08631, 0x0863b, 0x0863e, 0x08830, 0x08832, 0x0882e, 0x08833, 0x08976, 0x08974, 0x08973, 0x089fe, 0x08b8c,
+    0x08b8e, 0x08b8b, 0x08b88, 0x08c45, 0x08d19, 0x08e98, 0x08f64, 0x08f63, 0x091bc, 0x09462, 0x09455, 0x0945d,
+    0x09457, 0x0945e, 0x097c4, 0x097c5, 0x09800, 0x09a56, 0x09a59, 0x09b1e, 0x09b1f, 0x09b20, 0x09c52, 0x09c58,
+    0x09c50, 0x09c4a, 0x09c4d, 0x09c4b, 0x09c55, 0x09c59, 0x09c4c, 0x09c4e, 0x09dfb, 0x09df7, 0x09def, 0x09de3,
+    0x09deb, 0x09df8, 0x09de4, 0x09df6, 0x09de1, 0x09dee, 0x09de6, 0x09df2, 0x09df0, 0x09de2, 0x09dec, 0x09df4,
+    0x09df3, 0x09de8, 0x09ded, 0x09ec2, 0x09ed0, 0x09ef2, 0x09ef3, 0x09f06, 0x09f1c, 0x09f38, 0x09f37, 0x09f36,
+    0x09f43, 0x09f4f, 0x09f71, 0x09f70, 0x09f6e, 0x09f6f, 0x056d3, 0x056cd, 0x05b4e, 0x05c6d, 0x0652d, 0x066ed,
+    0x066ee, 0x06b13, 0x0705f, 0x07061, 0x0705d, 0x07060, 0x07223, 0x074db, 0x074e5, 0x077d5, 0x07938, 0x079b7,
+    0x079b6, 0x07c6a, 0x07e97, 0x07f89, 0x0826d, 0x08643, 0x08838, 0x08837, 0x08835, 0x0884b, 0x08b94, 0x08b95,
+    0x08e9e, 0x08e9f, 0x08ea0, 0x08e9d, 0x091be, 0x091bd, 0x091c2, 0x0946b, 0x09468, 0x09469, 0x096e5, 0x09746,
+    0x09743, 0x09747, 0x097c7, 0x097e5, 0x09a5e, 0x09ad5, 0x09b59, 0x09c63, 0x09c67, 0x09c66, 0x09c62, 0x09c5e,
+    0x09c60, 0x09e02, 0x09dfe, 0x09e07, 0x09e03, 0x09e06, 0x09e05, 0x09e00, 0x09e01, 0x09e09, 0x09dff, 0x09dfd,
+    0x09e04, 0x09ea0, 0x09f1e, 0x09f46, 0x09f74, 0x09f75, 0x09f76, 0x056d4, 0x0652e, 0x065b8, 0x06b18, 0x06b19,
+    0x06b17, 0x06b1a, 0x07062, 0x07226, 0x072aa, 0x077d8, 0x077d9, 0x07939, 0x07c69, 0x07c6b, 0x07cf6, 0x07e9a,
+    0x07e98, 0x07e9b, 0x07e99, 0x081e0, 0x081e1, 0x08646, 0x08647, 0x08648, 0x08979, 0x0897a, 0x0897c, 0x0897b,
+    0x089ff, 0x08b98, 0x08b99, 0x08ea5, 0x08ea4, 0x08ea3, 0x0946e, 0x0946d, 0x0946f, 0x09471, 0x09473, 0x09749,
+    0x09872, 0x0995f, 0x09c68, 0x09c6e, 0x09c6d, 0x09e0b, 0x09e0d, 0x09e10, 0x09e0f, 0x09e12, 0x09e11, 0x09ea1,
+    0x09ef5, 0x09f09, 0x09f47, 0x09f78, 0x09f7b, 0x09f7a, 0x09f79, 0x0571e, 0x07066, 0x07c6f, 0x0883c, 0x08db2,
+    0x08ea6, 0x091c3, 0x09474, 0x09478, 0x09476, 0x09475, 0x09a60, 0x09c74, 0x09c73, 0x09c71, 0x09c75, 0x09e14,
+    0x09e13, 0x09ef6, 0x09f0a, 0x09fa4, 0x07068, 0x07065, 0x07cf7, 0x0866a, 0x0883e, 0x0883d, 0x0883f, 0x08b9e,
+    0x08c9c, 0x08ea9, 0x08ec9, 0x0974b, 0x09873, 0x09874, 0x098cc, 0x09961, 0x099ab, 0x09a64, 0x09a66, 0x09a67,
+    0x09b24, 0x09e15, 0x09e17, 0x09f48, 0x06207, 0x06b1e, 0x07227, 0x0864c, 0x08ea8, 0x09482, 0x09480, 0x09481,
+    0x09a69, 0x09a68, 0x09b2e, 0x09e19, 0x07229, 0x0864b, 0x08b9f, 0x09483, 0x09c79, 0x09eb7, 0x07675, 0x09a6b,
+    0x09c7a, 0x09e1d, 0x07069, 0x0706a, 0x09ea4, 0x09f7e, 0x09f49, 0x09f98, 0x07881, 0x092b9, 0x088cf, 0x058bb,
+    0x06052, 0x07ca7, 0x05afa, 0x02554, 0x02566, 0x02557, 0x02560, 0x0256c, 0x02563, 0x0255a, 0x02569, 0x0255d,
+    0x02552, 0x02564, 0x02555, 0x0255e, 0x0256a, 0x02561, 0x02558, 0x02567, 0x0255b, 0x02553, 0x02565, 0x02556,
+    0x0255f, 0x0256b, 0x02562, 0x02559, 0x02568, 0x0255c, 0x02551, 0x02550, 0x0256d, 0x0256e, 0x02570, 0x0256f,
+    0x0ffed, 0x20547, 0x092db, 0x205df, 0x23fc5, 0x0854c, 0x042b5, 0x073ef, 0x051b5, 0x03649, 0x24942, 0x289e4,
+    0x09344, 0x219db, 0x082ee, 0x23cc8, 0x0783c, 0x06744, 0x062df, 0x24933, 0x289aa, 0x202a0, 0x26bb3, 0x21305,
+    0x04fab, 0x224ed, 0x05008, 0x26d29, 0x27a84, 0x23600, 0x24ab1, 0x22513, 0x00000, 0x2037e, 0x05fa4, 0x20380,
+    0x20347, 0x06edb, 0x2041f, 0x00000, 0x05101, 0x0347a, 0x0510e, 0x0986c, 0x03743, 0x08416, 0x249a4, 0x20487,
+    0x05160, 0x233b4, 0x0516a, 0x20bff, 0x220fc, 0x202e5, 0x22530, 0x2058e, 0x23233, 0x21983, 0x05b82, 0x0877d,
+    0x205b3, 0x23c99, 0x051b2, 0x051b8, 0x09d34, 0x051c9, 0x051cf, 0x051d1, 0x03cdc, 0x051d3, 0x24aa6, 0x051b3,
+    0x051e2, 0x05342, 0x051ed, 0x083cd, 0x0693e, 0x2372d, 0x05f7b, 0x0520b, 0x05226, 0x0523c, 0x052b5, 0x05257,
+    0x05294, 0x052b9, 0x052c5, 0x07c15, 0x08542, 0x052e0, 0x0860d, 0x26b13, 0x00000, 0x28ade, 0x05549, 0x06ed9,
+    0x23f80, 0x20954, 0x23fec, 0x05333, 0x00000, 0x20be2, 0x06ccb, 0x21726, 0x0681b, 0x073d5, 0x0604a, 0x03eaa,
+    0x038cc, 0x216e8, 0x071dd, 0x044a2, 0x0536d, 0x05374, 0x286ab, 0x0537e, 0x00000, 0x21596, 0x21613, 0x077e6,
+    0x05393, 0x28a9b, 0x053a0, 0x053ab, 0x053ae, 0x073a7, 0x25772, 0x03f59, 0x0739c, 0x053c1, 0x053c5, 0x06c49,
+    0x04e49, 0x057fe, 0x053d9, 0x03aab, 0x20b8f, 0x053e0, 0x23feb, 0x22da3, 0x053f6, 0x20c77, 0x05413, 0x07079,
+    0x0552b, 0x06657, 0x06d5b, 0x0546d, 0x26b53, 0x20d74, 0x0555d, 0x0548f, 0x054a4, 0x047a6, 0x2170d, 0x20edd,
+    0x03db4, 0x20d4d, 0x289bc, 0x22698, 0x05547, 0x04ced, 0x0542f, 0x07417, 0x05586, 0x055a9, 0x00000, 0x218d7,
+    0x2403a, 0x04552, 0x24435, 0x066b3, 0x210b4, 0x05637, 0x066cd, 0x2328a, 0x066a4, 0x066ad, 0x0564d, 0x0564f,
+    0x078f1, 0x056f1, 0x09787, 0x053fe, 0x05700, 0x056ef, 0x056ed, 0x28b66, 0x03623, 0x2124f, 0x05746, 0x241a5,
+    0x06c6e, 0x0708b, 0x05742, 0x036b1, 0x26c7e, 0x057e6, 0x21416, 0x05803, 0x21454, 0x24363, 0x05826, 0x24bf5,
+    0x0585c, 0x058aa, 0x03561, 0x058e0, 0x058dc, 0x2123c, 0x058fb, 0x05bff, 0x05743, 0x2a150, 0x24278, 0x093d3,
+    0x035a1, 0x0591f, 0x068a6, 0x036c3, 0x06e59, 0x2163e, 0x05a24, 0x05553, 0x21692, 0x08505, 0x059c9, 0x20d4e,
+    0x26c81, 0x26d2a, 0x217dc, 0x059d9, 0x217fb, 0x217b2, 0x26da6, 0x06d71, 0x21828, 0x216d5, 0x059f9, 0x26e45,
+    0x05aab, 0x05a63, 0x036e6, 0x249a9, 0x00000, 0x03708, 0x05a96, 0x07465, 0x05ad3, 0x26fa1, 0x22554, 0x03d85,
+    0x21911, 0x03732, 0x216b8, 0x05e83, 0x052d0, 0x05b76, 0x06588, 0x05b7c, 0x27a0e, 0x04004, 0x0485d, 0x20204,
+    0x05bd5, 0x06160, 0x21a34, 0x259cc, 0x205a5, 0x05bf3, 0x05b9d, 0x04d10, 0x05c05, 0x21b44, 0x05c13, 0x073ce,
+    0x05c14, 0x21ca5, 0x26b28, 0x05c49, 0x048dd, 0x05c85, 0x05ce9, 0x05cef, 0x05d8b, 0x21df9, 0x21e37, 0x05d10,
+    0x05d18, 0x05d46, 0x21ea4, 0x05cba, 0x05dd7, 0x082fc, 0x0382d, 0x24901, 0x22049, 0x22173, 0x08287, 0x03836,
+    0x03bc2, 0x05e2e, 0x06a8a, 0x00000, 0x05e7a, 0x244bc, 0x20cd3, 0x053a6, 0x04eb7, 0x00000, 0x053a8, 0x21771,
+    0x05e09, 0x05ef4, 0x28482, 0x05ef9, 0x05efb, 0x038a0, 0x05efc, 0x0683e, 0x0941b, 0x05f0d, 0x201c1, 0x2f894,
+    0x03ade, 0x048ae, 0x2133a, 0x05f3a, 0x26888, 0x223d0, 0x00000, 0x22471, 0x05f63, 0x097bd, 0x26e6e, 0x05f72,
+    0x09340, 0x28a36, 0x05fa7, 0x05db6, 0x03d5f, 0x25250, 0x21f6a, 0x270f8, 0x22668, 0x091d6, 0x2029e, 0x28a29,
+    0x06031, 0x06685, 0x21877, 0x03963, 0x03dc7, 0x03639, 0x05790, 0x227b4, 0x07971, 0x03e40, 0x0609e, 0x00000,
+    0x060b3, 0x24982, 0x2498f, 0x27a53, 0x074a4, 0x050e1, 0x05aa0, 0x06164, 0x08424, 0x06142, 0x2f8a6, 0x26ed2,
+    0x06181, 0x051f4, 0x20656, 0x06187, 0x05baa, 0x23fb7, 0x2285f, 0x061d3, 0x28b9d, 0x2995d, 0x061d0, 0x03932,
+    0x22980, 0x228c1, 0x06023, 0x0615c, 0x0651e, 0x0638b, 0x20118, 0x062c5, 0x21770, 0x062d5, 0x22e0d, 0x0636c,
+    0x249df, 0x03a17, 0x06438, 0x063f8, 0x2138e, 0x217fc, 0x00000, 0x06f8a, 0x22e36, 0x09814, 0x2408c, 0x2571d,
+    0x064e1, 0x064e5, 0x0947b, 0x03a66, 0x0643a, 0x03a57, 0x0654d, 0x06f16, 0x24a28, 0x24a23, 0x06585, 0x0656d,
+    0x0655f, 0x2307e, 0x065b5, 0x24940, 0x04b37, 0x065d1, 0x040d8, 0x21829, 0x065e0, 0x065e3, 0x05fdf, 0x23400,
+    0x06618, 0x231f7, 0x231f8, 0x06644, 0x231a4, 0x231a5, 0x0664b, 0x20e75, 0x06667, 0x251e6, 0x06673, 0x00000,
+    0x21e3d, 0x23231, 0x285f4, 0x231c8, 0x25313, 0x077c5, 0x228f7, 0x099a4, 0x06702, 0x2439c, 0x24a21, 0x03b2b,
+    0x069fa, 0x237c2, 0x00000, 0x06767, 0x06762, 0x241cd, 0x290ed, 0x067d7, 0x044e9, 0x06822, 0x06e50, 0x0923c,
+    0x06801, 0x233e6, 0x26da0, 0x0685d, 0x2346f, 0x069e1, 0x06a0b, 0x28adf, 0x06973, 0x068c3, 0x235cd, 0x06901,
+    0x06900, 0x03d32, 0x03a01, 0x2363c, 0x03b80, 0x067ac, 0x06961, 0x28a4a, 0x042fc, 0x06936, 0x06998, 0x03ba1,
+    0x203c9, 0x08363, 0x05090, 0x069f9, 0x23659, 0x2212a, 0x06a45, 0x23703, 0x06a9d, 0x03bf3, 0x067b1, 0x06ac8,
+    0x2919c, 0x03c0d, 0x06b1d, 0x20923, 0x060de, 0x06b35, 0x06b74, 0x227cd, 0x06eb5, 0x23adb, 0x203b5, 0x21958,
+    0x03740, 0x05421, 0x23b5a, 0x06be1, 0x23efc, 0x06bdc, 0x06c37, 0x2248b, 0x248f1, 0x26b51, 0x06c5a, 0x08226,
+    0x06c79, 0x23dbc, 0x044c5, 0x23dbd, 0x241a4, 0x2490c, 0x24900, 0x23cc9, 0x036e5, 0x03ceb, 0x20d32, 0x09b83,
+    0x231f9, 0x22491, 0x07f8f, 0x06837, 0x26d25, 0x26da1, 0x26deb, 0x06d96, 0x06d5c, 0x06e7c, 0x06f04, 0x2497f,
+    0x24085, 0x26e72, 0x08533, 0x26f74, 0x051c7, 0x00000, 0x00000, 0x0842e, 0x28b21, 0x00000, 0x23e2f, 0x07453,
+    0x23f82, 0x079cc, 0x06e4f, 0x05a91, 0x2304b, 0x06ff8, 0x0370d, 0x06f9d, 0x23e30, 0x06efa, 0x21497, 0x2403d,
+    0x04555, 0x093f0, 0x06f44, 0x06f5c, 0x03d4e, 0x06f74, 0x29170, 0x03d3b, 0x06f9f, 0x24144, 0x06fd3, 0x24091,
+    0x24155, 0x24039, 0x23ff0, 0x23fb4, 0x2413f, 0x051df, 0x24156, 0x24157, 0x24140, 0x261dd, 0x0704b, 0x0707e,
+    0x070a7, 0x07081, 0x070cc, 0x070d5, 0x070d6, 0x070df, 0x04104, 0x03de8, 0x071b4, 0x07196, 0x24277, 0x0712b,
+    0x07145, 0x05a88, 0x0714a, 0x00000, 0x05c9c, 0x24365, 0x0714f, 0x09362, 0x242c1, 0x0712c, 0x2445a, 0x24a27,
+    0x24a22, 0x071ba, 0x28be8, 0x070bd, 0x0720e, 0x09442, 0x07215, 0x05911, 0x09443, 0x07224, 0x09341, 0x25605,
+    0x0722e, 0x07240, 0x24974, 0x068bd, 0x07255, 0x07257, 0x03e55, 0x23044, 0x0680d, 0x06f3d, 0x07282, 0x00000,
+    0x0732b, 0x24823, 0x2882b, 0x048ed, 0x28804, 0x07328, 0x0732e, 0x073cf, 0x073aa, 0x20c3a, 0x26a2e, 0x073c9,
+    0x07449, 0x241e2, 0x216e7, 0x24a24, 0x06623, 0x036c5, 0x249b7, 0x2498d, 0x249fb, 0x073f7, 0x07415, 0x06903,
+    0x24a26, 0x07439, 0x205c3, 0x03ed7, 0x00000, 0x228ad, 0x07460, 0x28eb2, 0x07447, 0x073e4, 0x07476, 0x083b9,
+    0x0746c, 0x03730, 0x07474, 0x093f1, 0x06a2c, 0x07482, 0x04953, 0x24a8c, 0x2415f, 0x24a79, 0x28b8f, 0x05b46,
+    0x28c03, 0x2189e, 0x074c8, 0x21988, 0x0750e, 0x00000, 0x0751e, 0x28ed9, 0x21a4b, 0x05bd7, 0x28eac, 0x09385,
+    0x0754d, 0x0754a, 0x07567, 0x0756e, 0x24f82, 0x03f04, 0x24d13, 0x0758e, 0x0745d, 0x0759e, 0x075b4, 0x07602,
+    0x0762c, 0x07651, 0x0764f, 0x0766f, 0x07676, 0x263f5, 0x07690, 0x081ef, 0x037f8, 0x26911, 0x2690e, 0x076a1,
+    0x076a5, 0x076b7, 0x076cc, 0x26f9f, 0x08462, 0x2509d, 0x2517d, 0x21e1c, 0x0771e, 0x07726, 0x07740, 0x064af,
+    0x25220, 0x07758, 0x232ac, 0x077af, 0x28964, 0x28968, 0x216c1, 0x077f4, 0x00000, 0x21376, 0x24a12, 0x068ca,
+    0x078af, 0x078c7, 0x078d3, 0x096a5, 0x0792e, 0x255e0, 0x078d7, 0x07934, 0x078b1, 0x2760c, 0x08fb8, 0x08884,
+    0x28b2b, 0x26083, 0x2261c, 0x07986, 0x08900, 0x06902, 0x07980, 0x25857, 0x0799d, 0x27b39, 0x0793c, 0x079a9,
+    0x06e2a, 0x27126, 0x03ea8, 0x079c6, 0x2910d, 0x079d4,
+];
+
+/// Pairs of a Unicode code point and the pointer of its character, sorted by code point.
+#[rustfmt::skip]
+static FROM_UNICODE: [(u32, u16); 18386] = [
+    (0x000a2, 5187), (0x000a3, 5188), (0x000a5, 5185), (0x000a7, 5103), (0x000a8, 10951), (0x000b0, 5205), (0x000b1, 5137),
+    (0x000b7, 5040), (0x000c0, 1124), (0x000c1, 1122), (0x000c8, 1128), (0x000c9, 1126), (0x000ca, 1137), (0x000d2, 1132),
+    (0x000d3, 1130), (0x000d7, 5135), (0x000e0, 1141), (0x000e1, 1139), (0x000e8, 1146), (0x000e9, 1144), (0x000ea, 1168),
+    (0x000ec, 1150), (0x000ed, 1148), (0x000f2, 1154), (0x000f3, 1152), (0x000f7, 5136), (0x000f8, 11300), (0x000f9, 1158),
+    (0x000fa, 1156), (0x000fc, 1163), (0x00100, 1121), (0x00101, 1138), (0x00112, 1125), (0x00113, 1143), (0x0011a, 1127),
+    (0x0011b, 1145), (0x0012b, 1147), (0x0014b, 11301), (0x0014c, 1129), (0x0014d, 1151), (0x00153, 11299), (0x0016b, 1155),
+    (0x001cd, 1123), (0x001ce, 1140), (0x001d0, 1149), (0x001d1, 1131), (0x001d2, 1153), (0x001d4, 1157), (0x001d6, 1159),
+    (0x001d8, 1160), (0x001da, 1161), (0x001dc, 1162), (0x00250, 11295), (0x00251, 1142), (0x00254, 11297), (0x0025b, 11296),
+    (0x00261, 1169), (0x0026a, 11303), (0x00275, 11298), (0x00283, 11294), (0x0028a, 11302), (0x002c6, 10952), (0x002c7, 5430),
+    (0x002c9, 5428), (0x002ca, 5429), (0x002cb, 5431), (0x002cd, 5123), (0x002d9, 5427), (0x00391, 5342), (0x00392, 5343),
+    (0x00393, 5344), (0x00394, 5345), (0x00395, 5346), (0x00396, 5347), (0x00397, 5348), (0x00398, 5349), (0x00399, 5350),
+    (0x0039a, 5351), (0x0039b, 5352), (0x0039c, 5353), (0x0039d, 5354), (0x0039e, 5355), (0x0039f, 5356), (0x003a0, 5357),
+    (0x003a1, 5358), (0x003a3, 5359), (0x003a4, 5360), (0x003a5, 5361), (0x003a6, 5362), (0x003a7, 5363), (0x003a8, 5364),
+    (0x003a9, 5365), (0x003b1, 5366), (0x003b2, 5367), (0x003b3, 5368), (0x003b4, 5369), (0x003b5, 5370), (0x003b6, 5371),
+    (0x003b7, 5372), (0x003b8, 5373), (0x003b9, 5374), (0x003ba, 5375), (0x003bb, 5376), (0x003bc, 5377), (0x003bd, 5378),
+    (0x003be, 5379), (0x003bf, 5380), (0x003c0, 5381), (0x003c1, 5382), (0x003c3, 5383), (0x003c4, 5384), (0x003c5, 5385),
+    (0x003c6, 5386), (0x003c7, 5387), (0x003c8, 5388), (0x003c9, 5389), (0x00401, 11141), (0x00410, 11135), (0x00411, 11136),
+    (0x00412, 11137), (0x00413, 11138), (0x00414, 11139), (0x00415, 11140), (0x00416, 11142), (0x00417, 11143), (0x00418, 11144),
+    (0x00419, 11145), (0x0041a, 11146), (0x0041b, 11147), (0x0041c, 11148), (0x0041d, 11149), (0x0041e, 11150), (0x0041f, 11151),
+    (0x00420, 11152), (0x00421, 11153), (0x00422, 11154), (0x00423, 11155), (0x00424, 11156), (0x00425, 11157), (0x00426, 11158),
+    (0x00427, 11159), (0x00428, 11160), (0x00429, 11161), (0x0042a, 11162), (0x0042b, 11163), (0x0042c, 11164), (0x0042d, 11165),
+    (0x0042e, 11166), (0x0042f, 11167), (0x00430, 11168), (0x00431, 11169), (0x00432, 11170), (0x00433, 11171), (0x00434, 11172),
+    (0x00435, 11173), (0x00436, 11175), (0x00437, 11176), (0x00438, 11177), (0x00439, 11178), (0x0043a, 11179), (0x0043b, 11180),
+    (0x0043c, 11181), (0x0043d, 11182), (0x0043e, 11183), (0x0043f, 11184), (0x00440, 11185), (0x00441, 11186), (0x00442, 11187),
+    (0x00443, 11188), (0x00444, 11189), (0x00445, 11190), (0x00446, 11191), (0x00447, 11192), (0x00448, 11193), (0x00449, 11194),
+    (0x0044a, 11195), (0x0044b, 11196), (0x0044c, 11197), (0x0044d, 11198), (0x0044e, 11199), (0x0044f, 11200), (0x00451, 11174),
+    (0x01ebe, 1134), (0x01ebf, 1165), (0x01ec0, 1136), (0x01ec1, 1167), (0x02013, 5046), (0x02014, 5048), (0x02018, 5091),
+    (0x02019, 5092), (0x0201c, 5093), (0x0201d, 5094), (0x02022, 5029), (0x02025, 5036), (0x02026, 5035), (0x02032, 5098),
+    (0x02035, 5097), (0x0203b, 5102), (0x0203e, 5120), (0x02103, 5191), (0x02105, 5119), (0x02109, 5192), (0x02116, 11259),
+    (0x02121, 11260), (0x02160, 5268), (0x02161, 5269), (0x02162, 5270), (0x02163, 5271), (0x02164, 5272), (0x02165, 5273),
+    (0x02166, 5274), (0x02167, 5275), (0x02168, 5276), (0x02169, 5277), (0x02170, 10916), (0x02171, 10917), (0x02172, 10918),
+    (0x02173, 10919), (0x02174, 10920), (0x02175, 10921), (0x02176, 10922), (0x02177, 10923), (0x02178, 10924), (0x02179, 10925),
+    (0x02190, 5172), (0x02191, 5170), (0x02192, 5173), (0x02193, 5171), (0x02196, 5174), (0x02197, 5175), (0x02198, 5177),
+    (0x02199, 5176), (0x021b8, 11202), (0x021b9, 11203), (0x021e7, 11201), (0x0221a, 5138), (0x0221e, 5145), (0x0221f, 5158),
+    (0x02220, 5157), (0x02223, 5179), (0x02225, 5178), (0x02229, 5154), (0x0222a, 5155), (0x0222b, 5162), (0x0222e, 5163),
+    (0x02234, 5165), (0x02235, 5164), (0x0223c, 5153), (0x02252, 5146), (0x02260, 5144), (0x02261, 5147), (0x02266, 5142),
+    (0x02267, 5143), (0x022a5, 5156), (0x022bf, 5159), (0x023da, 1170), (0x023db, 1171), (0x02460, 10896), (0x02461, 10897),
+    (0x02462, 10898), (0x02463, 10899), (0x02464, 10900), (0x02465, 10901), (0x02466, 10902), (0x02467, 10903), (0x02468, 10904),
+    (0x02469, 10905), (0x02474, 10906), (0x02475, 10907), (0x02476, 10908), (0x02477, 10909), (0x02478, 10910), (0x02479, 10911),
+    (0x0247a, 10912), (0x0247b, 10913), (0x0247c, 10914), (0x0247d, 10915), (0x02500, 5236), (0x02502, 5237), (0x0250c, 5239),
+    (0x02510, 5240), (0x02514, 5241), (0x02518, 5242), (0x0251c, 5234), (0x02524, 5233), (0x0252c, 5232), (0x02534, 5231),
+    (0x0253c, 5230), (0x02550, 18991), (0x02551, 18990), (0x02552, 18972), (0x02553, 18981), (0x02554, 18963), (0x02555, 18974),
+    (0x02556, 18983), (0x02557, 18965), (0x02558, 18978), (0x02559, 18987), (0x0255a, 18969), (0x0255b, 18980), (0x0255c, 18989),
+    (0x0255d, 18971), (0x0255e, 18975), (0x0255f, 18984), (0x02560, 18966), (0x02561, 18977), (0x02562, 18986), (0x02563, 18968),
+    (0x02564, 18973), (0x02565, 18982), (0x02566, 18964), (0x02567, 18979), (0x02568, 18988), (0x02569, 18970), (0x0256a, 18976),
+    (0x0256b, 18985), (0x0256c, 18967), (0x0256d, 18992), (0x0256e, 18993), (0x0256f, 18995), (0x02570, 18994), (0x02571, 5255),
+    (0x02572, 5256), (0x02573, 5257), (0x02574, 5050), (0x02581, 5215), (0x02582, 5216), (0x02583, 5217), (0x02584, 5218),
+    (0x02585, 5219), (0x02586, 5220), (0x02587, 5221), (0x02588, 5222), (0x02589, 5229), (0x0258a, 5228), (0x0258b, 5227),
+    (0x0258c, 5226), (0x0258d, 5225), (0x0258e, 5224), (0x0258f, 5223), (0x02594, 5235), (0x02595, 5238), (0x025a0, 5115),
+    (0x025a1, 5114), (0x025b2, 5108), (0x025b3, 5107), (0x025bc, 5117), (0x025bd, 5116), (0x025c6, 5113), (0x025c7, 5112),
+    (0x025cb, 5105), (0x025ce, 5109), (0x025cf, 5106), (0x025e2, 5251), (0x025e3, 5252), (0x025e4, 5254), (0x025e5, 5253),
+    (0x02605, 5111), (0x02606, 5110), (0x02609, 5169), (0x02640, 5166), (0x02641, 5168), (0x02642, 5167), (0x0273d, 10965),
+    (0x02e80, 11263), (0x02e84, 11264), (0x02e86, 11265), (0x02e87, 11266), (0x02e88, 11267), (0x02e8a, 11268), (0x02e8c, 11269),
+    (0x02e8d, 11270), (0x02e95, 11271), (0x02e9c, 11272), (0x02e9d, 11273), (0x02ea5, 11274), (0x02ea7, 11275), (0x02eaa, 11276),
+    (0x02eac, 11277), (0x02eae, 11278), (0x02eb6, 11279), (0x02ebc, 11280), (0x02ebe, 11281), (0x02ec6, 11282), (0x02eca, 11283),
+    (0x02ecc, 11284), (0x02ecd, 11285), (0x02ecf, 11286), (0x02ed6, 11287), (0x02ed7, 11288), (0x02ede, 11289), (0x02ee3, 11290),
+    (0x02f33, 10940), (0x03000, 5024), (0x03001, 5026), (0x03002, 5027), (0x03003, 5104), (0x03005, 10959), (0x03006, 10960),
+    (0x03007, 10961), (0x03008, 5073), (0x03009, 5074), (0x0300a, 5069), (0x0300b, 5070), (0x0300c, 5077), (0x0300d, 5078),
+    (0x0300e, 5081), (0x0300f, 5082), (0x03010, 5065), (0x03011, 5066), (0x03012, 5186), (0x03014, 5061), (0x03015, 5062),
+    (0x0301d, 5095), (0x0301e, 5096), (0x03021, 5278), (0x03022, 5279), (0x03023, 5280), (0x03024, 5281), (0x03025, 5282),
+    (0x03026, 5283), (0x03027, 5284), (0x03028, 5285), (0x03029, 5286), (0x03041, 10966), (0x03042, 10967), (0x03043, 10968),
+    (0x03044, 10969), (0x03045, 10970), (0x03046, 10971), (0x03047, 10972), (0x03048, 10973), (0x03049, 10974), (0x0304a, 10975),
+    (0x0304b, 10976), (0x0304c, 10977), (0x0304d, 10978), (0x0304e, 10979), (0x0304f, 10980), (0x03050, 10981), (0x03051, 10982),
+    (0x03052, 10983), (0x03053, 10984), (0x03054, 10985), (0x03055, 10986), (0x03056, 10987), (0x03057, 10988), (0x03058, 10989),
+    (0x03059, 10990), (0x0305a, 10991), (0x0305b, 10992), (0x0305c, 10993), (0x0305d, 10994), (0x0305e, 10995), (0x0305f, 10996),
+    (0x03060, 10997), (0x03061, 10998), (0x03062, 10999), (0x03063, 11000), (0x03064, 11001), (0x03065, 11002), (0x03066, 11003),
+    (0x03067, 11004), (0x03068, 11005), (0x03069, 11006), (0x0306a, 11007), (0x0306b, 11008), (0x0306c, 11009), (0x0306d, 11010),
+    (0x0306e, 11011), (0x0306f, 11012), (0x03070, 11013), (0x03071, 11014), (0x03072, 11015), (0x03073, 11016), (0x03074, 11017),
+    (0x03075, 11018), (0x03076, 11019), (0x03077, 11020), (0x03078, 11021), (0x03079, 11022), (0x0307a, 11023), (0x0307b, 11024),
+    (0x0307c, 11025), (0x0307d, 11026), (0x0307e, 11027), (0x0307f, 11028), (0x03080, 11029), (0x03081, 11030), (0x03082, 11031),
+    (0x03083, 11032), (0x03084, 11033), (0x03085, 11034), (0x03086, 11035), (0x03087, 11036), (0x03088, 11037), (0x03089, 11038),
+    (0x0308a, 11039), (0x0308b, 11040), (0x0308c, 11041), (0x0308d, 11042), (0x0308e, 11043), (0x0308f, 11044), (0x03090, 11045),
+    (0x03091, 11046), (0x03092, 11047), (0x03093, 11048), (0x0309b, 11261), (0x0309c, 11262), (0x0309d, 10955), (0x0309e, 10956),
+    (0x030a1, 11049), (0x030a2, 11050), (0x030a3, 11051), (0x030a4, 11052), (0x030a5, 11053), (0x030a6, 11054), (0x030a7, 11055),
+    (0x030a8, 11056), (0x030a9, 11057), (0x030aa, 11058), (0x030ab, 11059), (0x030ac, 11060), (0x030ad, 11061), (0x030ae, 11062),
+    (0x030af, 11063), (0x030b0, 11064), (0x030b1, 11065), (0x030b2, 11066), (0x030b3, 11067), (0x030b4, 11068), (0x030b5, 11069),
+    (0x030b6, 11070), (0x030b7, 11071), (0x030b8, 11072), (0x030b9, 11073), (0x030ba, 11074), (0x030bb, 11075), (0x030bc, 11076),
+    (0x030bd, 11077), (0x030be, 11078), (0x030bf, 11079), (0x030c0, 11080), (0x030c1, 11081), (0x030c2, 11082), (0x030c3, 11083),
+    (0x030c4, 11084), (0x030c5, 11085), (0x030c6, 11086), (0x030c7, 11087), (0x030c8, 11088), (0x030c9, 11089), (0x030ca, 11090),
+    (0x030cb, 11091), (0x030cc, 11092), (0x030cd, 11093), (0x030ce, 11094), (0x030cf, 11095), (0x030d0, 11096), (0x030d1, 11097),
+    (0x030d2, 11098), (0x030d3, 11099), (0x030d4, 11100), (0x030d5, 11101), (0x030d6, 11102), (0x030d7, 11103), (0x030d8, 11104),
+    (0x030d9, 11105), (0x030da, 11106), (0x030db, 11107), (0x030dc, 11108), (0x030dd, 11109), (0x030de, 11110), (0x030df, 11111),
+    (0x030e0, 11112), (0x030e1, 11113), (0x030e2, 11114), (0x030e3, 11115), (0x030e4, 11116), (0x030e5, 11117), (0x030e6, 11118),
+    (0x030e7, 11119), (0x030e8, 11120), (0x030e9, 11121), (0x030ea, 11122), (0x030eb, 11123), (0x030ec, 11124), (0x030ed, 11125),
+    (0x030ee, 11126), (0x030ef, 11127), (0x030f0, 11128), (0x030f1, 11129), (0x030f2, 11130), (0x030f3, 11131), (0x030f4, 11132),
+    (0x030f5, 11133), (0x030f6, 11134), (0x030fc, 10962), (0x030fd, 10953), (0x030fe, 10954), (0x03105, 5390), (0x03106, 5391),
+    (0x03107, 5392), (0x03108, 5393), (0x03109, 5394), (0x0310a, 5395), (0x0310b, 5396), (0x0310c, 5397), (0x0310d, 5398),
+    (0x0310e, 5399), (0x0310f, 5400), (0x03110, 5401), (0x03111, 5402), (0x03112, 5403), (0x03113, 5404), (0x03114, 5405),
+    (0x03115, 5406), (0x03116, 5407), (0x03117, 5408), (0x03118, 5409), (0x03119, 5410), (0x0311a, 5411), (0x0311b, 5412),
+    (0x0311c, 5413), (0x0311d, 5414), (0x0311e, 5415), (0x0311f, 5416), (0x03120, 5417), (0x03121, 5418), (0x03122, 5419),
+    (0x03123, 5420), (0x03124, 5421), (0x03125, 5422), (0x03126, 5423), (0x03127, 5424), (0x03128, 5425), (0x03129, 5426),
+    (0x031c0, 1099), (0x031c1, 1100), (0x031c2, 1101), (0x031c3, 1102), (0x031c4, 1103), (0x031c5, 1105), (0x031c6, 1108),
+    (0x031c7, 1109), (0x031c8, 1112), (0x031c9, 1114), (0x031ca, 1115), (0x031cb, 1116), (0x031cc, 1117), (0x031cd, 1119),
+    (0x031ce, 1120), (0x031cf, 11204), (0x03231, 11258), (0x032a3, 5118), (0x0338e, 5202), (0x0338f, 5203), (0x0339c, 5197),
+    (0x0339d, 5198), (0x0339e, 5199), (0x033a1, 5201), (0x033c4, 5204), (0x033ce, 5200), (0x033d1, 5161), (0x033d2, 5160),
+    (0x033d5, 5196), (0x03435, 2724), (0x03440, 3422), (0x0344a, 1873), (0x0344c, 1371), (0x03464, 2933), (0x03473, 4207),
+    (0x0347a, 19037), (0x0347d, 1376), (0x0347e, 2223), (0x03493, 1377), (0x03496, 2227), (0x034a5, 1378), (0x034af, 3446),
+    (0x034bc, 1533), (0x034c1, 1692), (0x034c8, 3575), (0x034df, 4572), (0x034e4, 4468), (0x034fb, 4218), (0x03506, 1459),
+    (0x0353e, 1672), (0x03551, 1390), (0x03553, 1392), (0x03559, 1738), (0x03561, 19202), (0x0356d, 1395), (0x03570, 3106),
+    (0x03572, 1396), (0x03577, 4635), (0x03578, 1526), (0x03584, 2790), (0x03597, 3104), (0x03598, 1407), (0x035a1, 19212),
+    (0x035a5, 1409), (0x035ad, 4707), (0x035bf, 1410), (0x035c1, 1349), (0x035c5, 1412), (0x035c7, 1388), (0x035ca, 4402),
+    (0x035ce, 4536), (0x035d2, 4929), (0x035d6, 4907), (0x035db, 3746), (0x035dd, 1610), (0x035f1, 2039), (0x035f2, 1662),
+    (0x035f3, 4829), (0x035fb, 1550), (0x035fe, 4827), (0x03609, 1612), (0x03618, 4267), (0x0361a, 1649), (0x03623, 19184),
+    (0x03625, 990), (0x0362d, 4541), (0x03635, 4977), (0x03639, 19349), (0x0363e, 2804), (0x03647, 4063), (0x03648, 2271),
+    (0x03649, 19005), (0x0364e, 2046), (0x0365f, 4286), (0x03661, 1912), (0x0367a, 4677), (0x03681, 1397), (0x0369a, 3432),
+    (0x036a5, 4642), (0x036aa, 3107), (0x036ac, 4639), (0x036b0, 3542), (0x036b1, 19191), (0x036b5, 3256), (0x036b9, 4328),
+    (0x036bc, 3574), (0x036c1, 2203), (0x036c3, 19215), (0x036c4, 2536), (0x036c5, 19661), (0x036c7, 3633), (0x036c8, 4095),
+    (0x036d3, 2853), (0x036d4, 3241), (0x036d6, 3587), (0x036dd, 2851), (0x036e1, 2659), (0x036e2, 3540), (0x036e5, 19532),
+    (0x036e6, 19238), (0x036f5, 2821), (0x03701, 2849), (0x03703, 1638), (0x03708, 19241), (0x0370a, 3532), (0x0370d, 19566),
+    (0x0371c, 4302), (0x03722, 2656), (0x03723, 2663), (0x03725, 2341), (0x0372c, 3459), (0x0372d, 3630), (0x03730, 19681),
+    (0x03732, 19249), (0x03733, 2686), (0x0373a, 4471), (0x03740, 19512), (0x03743, 19040), (0x03762, 4178), (0x0376f, 3984),
+    (0x03797, 1602), (0x037a0, 2837), (0x037b9, 4016), (0x037be, 2597), (0x037d6, 1895), (0x037f2, 3222), (0x037f8, 19724),
+    (0x037fb, 4695), (0x0380f, 1920), (0x03819, 2710), (0x03820, 1923), (0x0382d, 19290), (0x03836, 19295), (0x03838, 1924),
+    (0x03863, 2963), (0x038a0, 19313), (0x038c3, 2550), (0x038cc, 19104), (0x038d1, 2949), (0x038d4, 1902), (0x038fa, 1676),
+    (0x03908, 1929), (0x03914, 1930), (0x03927, 3367), (0x03932, 19379), (0x0393f, 1931), (0x0394d, 1932), (0x03963, 19347),
+    (0x03978, 1872), (0x03980, 2387), (0x03989, 1936), (0x0398a, 3551), (0x03992, 1523), (0x03999, 2721), (0x0399b, 4174),
+    (0x039a1, 4341), (0x039a4, 4327), (0x039b8, 1940), (0x039dc, 4640), (0x039e2, 3166), (0x039e5, 2594), (0x039ec, 1421),
+    (0x039f8, 1945), (0x039fb, 1474), (0x039fe, 1509), (0x03a01, 19478), (0x03a03, 1947), (0x03a06, 1524), (0x03a17, 19393),
+    (0x03a18, 1577), (0x03a29, 2892), (0x03a2a, 4673), (0x03a34, 1430), (0x03a4b, 1952), (0x03a52, 4652), (0x03a57, 19409),
+    (0x03a5c, 4923), (0x03a5e, 3066), (0x03a66, 19407), (0x03a67, 1456), (0x03a97, 1957), (0x03aab, 19131), (0x03abd, 1959),
+    (0x03ade, 19320), (0x03ae0, 2921), (0x03af0, 2402), (0x03af2, 1962), (0x03afb, 3045), (0x03b0e, 2507), (0x03b19, 3040),
+    (0x03b22, 1964), (0x03b2b, 19451), (0x03b39, 3827), (0x03b42, 1966), (0x03b58, 1969), (0x03b60, 2593), (0x03b71, 1973),
+    (0x03b72, 1972), (0x03b7b, 1974), (0x03b7c, 2359), (0x03b80, 19480), (0x03b95, 953), (0x03b96, 2956), (0x03b99, 2952),
+    (0x03ba1, 19487), (0x03bbc, 1981), (0x03bbe, 4168), (0x03bc2, 19296), (0x03bc4, 2961), (0x03bd7, 2974), (0x03bdd, 1983),
+    (0x03bec, 1988), (0x03bf2, 1990), (0x03bf3, 19497), (0x03bf4, 2909), (0x03c0d, 19501), (0x03c11, 4389), (0x03c15, 2687),
+    (0x03c18, 5001), (0x03c54, 4306), (0x03c8b, 1749), (0x03ccb, 2000), (0x03ccd, 3548), (0x03cd1, 4991), (0x03cd6, 3566),
+    (0x03cdc, 19064), (0x03ceb, 19533), (0x03cef, 2005), (0x03d12, 1863), (0x03d13, 2118), (0x03d1d, 2595), (0x03d32, 19477),
+    (0x03d3b, 19579), (0x03d46, 2021), (0x03d4c, 3554), (0x03d4e, 19576), (0x03d51, 2509), (0x03d5f, 19336), (0x03d62, 3413),
+    (0x03d69, 3553), (0x03d6a, 2025), (0x03d6f, 3571), (0x03d75, 2026), (0x03d7d, 3365), (0x03d85, 19247), (0x03d88, 1852),
+    (0x03d8a, 2029), (0x03d8f, 2992), (0x03d91, 2031), (0x03da5, 3661), (0x03dad, 2084), (0x03db4, 19152), (0x03dbf, 2214),
+    (0x03dc6, 4502), (0x03dc7, 19348), (0x03dc9, 1731), (0x03dcc, 3814), (0x03dcd, 3046), (0x03dd3, 2233), (0x03ddb, 4987),
+    (0x03de7, 2688), (0x03de8, 19603), (0x03deb, 4479), (0x03df3, 3668), (0x03df4, 984), (0x03df7, 4425), (0x03dfc, 1919),
+    (0x03dfd, 3338), (0x03e06, 4758), (0x03e40, 19353), (0x03e43, 1514), (0x03e48, 1373), (0x03e55, 19638), (0x03e74, 4676),
+    (0x03ea8, 19778), (0x03ea9, 3701), (0x03eaa, 19103), (0x03ead, 3218), (0x03eb1, 3019), (0x03eb8, 2873), (0x03ebf, 3070),
+    (0x03ec2, 3278), (0x03ec7, 2652), (0x03eca, 3708), (0x03ecc, 3288), (0x03ed0, 2353), (0x03ed1, 3710), (0x03ed6, 3492),
+    (0x03ed7, 19671), (0x03eda, 3577), (0x03edb, 1799), (0x03ede, 2781), (0x03ee1, 3487), (0x03ee2, 3716), (0x03ee7, 3282),
+    (0x03ee9, 3483), (0x03eeb, 2641), (0x03ef0, 3717), (0x03ef3, 3024), (0x03ef4, 3718), (0x03efa, 3719), (0x03efc, 3294),
+    (0x03eff, 3048), (0x03f00, 3331), (0x03f04, 19709), (0x03f06, 3018), (0x03f07, 1891), (0x03f0e, 3721), (0x03f53, 3722),
+    (0x03f58, 3036), (0x03f59, 19123), (0x03f63, 3033), (0x03f7c, 3727), (0x03f93, 1555), (0x03fc0, 3730), (0x03fc8, 1910),
+    (0x03fd7, 3732), (0x03fdc, 3734), (0x03fe5, 3681), (0x03fed, 1458), (0x03ff9, 1566), (0x03ffa, 1489), (0x04004, 19257),
+    (0x04009, 1756), (0x0401d, 3741), (0x04039, 3743), (0x04045, 3745), (0x04053, 1426), (0x04057, 2692), (0x04062, 3265),
+    (0x04065, 4941), (0x0406a, 3748), (0x0406f, 3749), (0x04071, 1824), (0x040a8, 3757), (0x040bb, 1499), (0x040bf, 4686),
+    (0x040c8, 3073), (0x040d8, 19422), (0x040df, 4339), (0x040f8, 1894), (0x040fa, 4615), (0x04102, 1888), (0x04103, 3767),
+    (0x04104, 19602), (0x04109, 3768), (0x0410e, 3086), (0x04132, 3096), (0x04167, 3774), (0x0416c, 2449), (0x0416e, 3094),
+    (0x0417c, 1898), (0x0417f, 3191), (0x04190, 2922), (0x041b2, 3776), (0x041c4, 3779), (0x041ca, 2062), (0x041cf, 3782),
+    (0x041db, 2197), (0x041ed, 1915), (0x041ef, 2066), (0x041f9, 3121), (0x04211, 3124), (0x04223, 1759), (0x04240, 2250),
+    (0x04260, 3789), (0x0426a, 3144), (0x04276, 1832), (0x0427a, 3790), (0x0428c, 3793), (0x04294, 3795), (0x042a2, 1825),
+    (0x042b5, 19002), (0x042b9, 2440), (0x042bc, 3457), (0x042f4, 3247), (0x042fb, 3557), (0x042fc, 19484), (0x0432b, 2128),
+    (0x0436e, 3240), (0x04397, 3807), (0x0439a, 1856), (0x043ba, 1500), (0x043c1, 2038), (0x043d9, 1467), (0x043df, 4413),
+    (0x043ed, 3819), (0x043f0, 942), (0x043f2, 4411), (0x04401, 3826), (0x04402, 3176), (0x04413, 3831), (0x04425, 3835),
+    (0x0442d, 3837), (0x0447a, 2180), (0x0448f, 3843), (0x04491, 11209), (0x0449f, 3029), (0x044a0, 2246), (0x044a2, 19107),
+    (0x044b0, 3849), (0x044b7, 4886), (0x044bd, 1914), (0x044c0, 3325), (0x044c3, 1853), (0x044c5, 19526), (0x044ce, 3610),
+    (0x044dd, 2760), (0x044de, 1913), (0x044df, 3609), (0x044e4, 2234), (0x044e9, 19460), (0x044ea, 3174), (0x044eb, 3561),
+    (0x044ec, 4589), (0x044f4, 2681), (0x04503, 2144), (0x04504, 3858), (0x04509, 4463), (0x0450b, 2218), (0x04516, 2258),
+    (0x0451b, 1905), (0x0451d, 2105), (0x04527, 2256), (0x0452e, 3530), (0x04533, 3399), (0x04536, 1857), (0x0453b, 3865),
+    (0x0453d, 2471), (0x0453f, 4331), (0x04543, 2252), (0x04551, 3031), (0x04552, 19165), (0x04555, 19572), (0x04558, 1868),
+    (0x0455c, 2142), (0x04561, 1904), (0x04562, 5018), (0x0456a, 2269), (0x0456d, 1901), (0x04577, 3869), (0x04578, 946),
+    (0x04585, 2472), (0x045a6, 945), (0x045b3, 949), (0x045da, 966), (0x045e9, 4678), (0x045ea, 1909), (0x04603, 944),
+    (0x04606, 3876), (0x0460f, 2286), (0x04615, 2335), (0x04617, 3877), (0x0465b, 2294), (0x0467a, 2772), (0x04680, 3576),
+    (0x046a1, 971), (0x046bb, 1889), (0x046cf, 3221), (0x046d0, 4296), (0x046f5, 3220), (0x046f7, 1896), (0x04713, 2319),
+    (0x04718, 4211), (0x04736, 954), (0x04744, 955), (0x0474e, 4611), (0x0474f, 965), (0x0477c, 4222), (0x04798, 3892),
+    (0x047a6, 19149), (0x047d5, 1431), (0x047ed, 3895), (0x047f4, 1452), (0x04800, 1653), (0x0480b, 1487), (0x04837, 3903),
+    (0x0485d, 19258), (0x04871, 3578), (0x0489b, 3251), (0x048ad, 3915), (0x048ae, 19321), (0x048d0, 4153), (0x048dd, 19276),
+    (0x048ed, 19647), (0x048f3, 4619), (0x048fa, 4405), (0x04906, 3261), (0x04911, 1355), (0x0491e, 3918), (0x04925, 3333),
+    (0x0492a, 2376), (0x0492d, 3411), (0x04935, 3514), (0x0493c, 3310), (0x0493e, 3581), (0x04945, 3933), (0x04951, 3934),
+    (0x04953, 19686), (0x04965, 2427), (0x0496a, 3941), (0x04972, 2833), (0x04989, 2159), (0x049a1, 2458), (0x049a7, 3946),
+    (0x049df, 2445), (0x049e5, 3949), (0x049e7, 1656), (0x04a0f, 2470), (0x04a1d, 3298), (0x04a24, 3951), (0x04a35, 3953),
+    (0x04a96, 3550), (0x04aa4, 970), (0x04ab4, 1502), (0x04ab8, 4154), (0x04ad1, 2502), (0x04ae4, 3957), (0x04aff, 2517),
+    (0x04b10, 1751), (0x04b19, 3960), (0x04b20, 1736), (0x04b2c, 1654), (0x04b37, 19420), (0x04b6f, 3340), (0x04b70, 3971),
+    (0x04b72, 2527), (0x04b7b, 3460), (0x04b7e, 4999), (0x04b8e, 2786), (0x04b90, 3350), (0x04b93, 2940), (0x04b96, 2601),
+    (0x04b97, 3354), (0x04b9d, 3973), (0x04bbd, 3353), (0x04bbe, 2619), (0x04bc0, 4249), (0x04c04, 4914), (0x04c07, 4909),
+    (0x04c0e, 2543), (0x04c32, 943), (0x04c3b, 4777), (0x04c3e, 1344), (0x04c40, 957), (0x04c47, 956), (0x04c57, 963),
+    (0x04c5b, 4660), (0x04c6d, 3982), (0x04c77, 1871), (0x04c7b, 1907), (0x04c7d, 4504), (0x04c81, 1839), (0x04c85, 967),
+    (0x04ca4, 4447), (0x04cae, 3384), (0x04cb0, 3387), (0x04cb7, 4350), (0x04ccd, 1341), (0x04ce1, 4693), (0x04ce2, 951),
+    (0x04ced, 19157), (0x04d07, 969), (0x04d09, 4656), (0x04d10, 19267), (0x04d34, 2588), (0x04d76, 1908), (0x04d77, 948),
+    (0x04d89, 1906), (0x04d91, 4452), (0x04d9c, 4455), (0x04e00, 5495), (0x04e01, 5497), (0x04e03, 5498), (0x04e04, 4634),
+    (0x04e07, 11309), (0x04e08, 5517), (0x04e09, 5515), (0x04e0a, 5518), (0x04e0b, 5516), (0x04e0c, 11310), (0x04e0d, 5560),
+    (0x04e0e, 11319), (0x04e0f, 11317), (0x04e10, 5559), (0x04e11, 5558), (0x04e14, 5654), (0x04e15, 5653), (0x04e16, 5652),
+    (0x04e18, 5655), (0x04e19, 5651), (0x04e1a, 4633), (0x04e1c, 4512), (0x04e1e, 5778), (0x04e1f, 5779), (0x04e21, 3783),
+    (0x04e24, 1364), (0x04e26, 6220), (0x04e28, 1664), (0x04e2a, 4808), (0x04e2b, 5519), (0x04e2c, 1684), (0x04e2d, 5561),
+    (0x04e2e, 11320), (0x04e2f, 1777), (0x04e30, 5562), (0x04e31, 11339), (0x04e32, 5945), (0x04e33, 11697), (0x04e36, 10926),
+    (0x04e37, 1721), (0x04e38, 5520), (0x04e39, 5563), (0x04e3b, 5656), (0x04e3c, 11340), (0x04e3d, 1262), (0x04e3f, 10927),
+    (0x04e42, 11304), (0x04e43, 5499), (0x04e45, 5522), (0x04e47, 11311), (0x04e48, 5523), (0x04e49, 19128), (0x04e4b, 5564),
+    (0x04e4d, 5657), (0x04e4e, 5659), (0x04e4f, 5658), (0x04e52, 5780), (0x04e53, 5781), (0x04e56, 6221), (0x04e58, 6998),
+    (0x04e59, 5496), (0x04e5a, 11206), (0x04e5b, 1670), (0x04e5c, 11305), (0x04e5d, 5500), (0x04e5e, 5525), (0x04e5f, 5524),
+    (0x04e69, 5782), (0x04e6a, 4262), (0x04e73, 6222), (0x04e78, 4078), (0x04e7e, 7455), (0x04e7f, 13042), (0x04e80, 1366),
+    (0x04e81, 1365), (0x04e82, 8418), (0x04e83, 14366), (0x04e84, 14367), (0x04e85, 10928), (0x04e86, 5501), (0x04e87, 1367),
+    (0x04e88, 5566), (0x04e89, 1384), (0x04e8b, 6223), (0x04e8c, 5502), (0x04e8d, 11312), (0x04e8e, 5526), (0x04e91, 5567),
+    (0x04e92, 5569), (0x04e93, 11321), (0x04e94, 5570), (0x04e95, 5568), (0x04e98, 2731), (0x04e99, 5783), (0x04e9a, 4484),
+    (0x04e9b, 6224), (0x04e9e, 6225), (0x04e9f, 6599), (0x04ea0, 10929), (0x04ea1, 5527), (0x04ea2, 5571), (0x04ea3, 1774),
+    (0x04ea4, 5784), (0x04ea5, 5786), (0x04ea6, 5785), (0x04ea8, 5946), (0x04eab, 6226), (0x04eac, 6227), (0x04ead, 6600),
+    (0x04eae, 6601), (0x04eb3, 6999), (0x04eb6, 14368), (0x04eb7, 19304), (0x04eb9, 18485), (0x04eba, 5503), (0x04ebb, 1671),
+    (0x04ebc, 2712), (0x04ebf, 1368), (0x04ec0, 5573), (0x04ec1, 5572), (0x04ec2, 11322), (0x04ec3, 5574), (0x04ec4, 5580),
+    (0x04ec6, 5575), (0x04ec7, 5576), (0x04ec8, 11324), (0x04ec9, 11323), (0x04eca, 5578), (0x04ecb, 5579), (0x04ecd, 5577),
+    (0x04ece, 4819), (0x04ed4, 5662), (0x04ed5, 5663), (0x04ed6, 5664), (0x04ed7, 5665), (0x04ed8, 5661), (0x04ed9, 5668),
+    (0x04eda, 11346), (0x04edc, 11342), (0x04edd, 11345), (0x04ede, 5669), (0x04edf, 5685), (0x04ee1, 11344), (0x04ee3, 5666),
+    (0x04ee4, 5667), (0x04ee5, 5660), (0x04ee8, 11341), (0x04ee9, 11343), (0x04eea, 4467), (0x04eeb, 1369), (0x04eee, 3896),
+    (0x04ef0, 5799), (0x04ef1, 11382), (0x04ef2, 5796), (0x04ef3, 5800), (0x04ef4, 11392), (0x04ef5, 11380), (0x04ef6, 5797),
+    (0x04ef7, 11384), (0x04ef8, 2708), (0x04efb, 5798), (0x04efd, 5801), (0x04eff, 5787), (0x04f00, 11383), (0x04f01, 5802),
+    (0x04f02, 11387), (0x04f03, 2735), (0x04f04, 11391), (0x04f05, 11388), (0x04f08, 11385), (0x04f09, 5788), (0x04f0a, 5790),
+    (0x04f0b, 5803), (0x04f0d, 5792), (0x04f0e, 11377), (0x04f0f, 5795), (0x04f10, 5793), (0x04f11, 5794), (0x04f12, 11393),
+    (0x04f13, 11390), (0x04f14, 11381), (0x04f15, 5791), (0x04f17, 1778), (0x04f18, 11378), (0x04f19, 5789), (0x04f1a, 1270),
+    (0x04f1d, 11386), (0x04f22, 11389), (0x04f28, 1271), (0x04f29, 2725), (0x04f2c, 11379), (0x04f2d, 11499), (0x04f2f, 5968),
+    (0x04f30, 5955), (0x04f32, 2596), (0x04f33, 11500), (0x04f34, 5952), (0x04f36, 5970), (0x04f37, 1370), (0x04f38, 5960),
+    (0x04f39, 4822), (0x04f3a, 5959), (0x04f3b, 11488), (0x04f3c, 5963), (0x04f3d, 5958), (0x04f3e, 11493), (0x04f3f, 11501),
+    (0x04f41, 11497), (0x04f42, 2738), (0x04f43, 5961), (0x04f45, 3204), (0x04f46, 5964), (0x04f47, 5949), (0x04f48, 5973),
+    (0x04f49, 11490), (0x04f4b, 2715), (0x04f4c, 11709), (0x04f4d, 5947), (0x04f4e, 5969), (0x04f4f, 5948), (0x04f50, 5956),
+    (0x04f51, 5957), (0x04f52, 11495), (0x04f53, 11491), (0x04f54, 5962), (0x04f55, 5954), (0x04f56, 11487), (0x04f57, 5950),
+    (0x04f58, 11498), (0x04f59, 5971), (0x04f5a, 5974), (0x04f5b, 5953), (0x04f5c, 5966), (0x04f5d, 5972), (0x04f5e, 5951),
+    (0x04f5f, 11496), (0x04f60, 5967), (0x04f61, 11502), (0x04f62, 11489), (0x04f63, 5965), (0x04f64, 11492), (0x04f67, 11494),
+    (0x04f69, 6241), (0x04f6a, 11711), (0x04f6b, 11723), (0x04f6c, 6233), (0x04f6e, 11724), (0x04f6f, 6228), (0x04f70, 6238),
+    (0x04f72, 3433), (0x04f73, 6231), (0x04f74, 11705), (0x04f75, 6239), (0x04f76, 11704), (0x04f77, 11708), (0x04f78, 11715),
+    (0x04f79, 11713), (0x04f7a, 6247), (0x04f7b, 6242), (0x04f7c, 11699), (0x04f7d, 11701), (0x04f7e, 6244), (0x04f7f, 6232),
+    (0x04f80, 11702), (0x04f81, 11714), (0x04f82, 11721), (0x04f83, 6237), (0x04f84, 11707), (0x04f85, 11700), (0x04f86, 6236),
+    (0x04f87, 11703), (0x04f88, 6240), (0x04f89, 11706), (0x04f8a, 2716), (0x04f8b, 6235), (0x04f8d, 6230), (0x04f8f, 6245),
+    (0x04f90, 11716), (0x04f91, 6246), (0x04f92, 11720), (0x04f94, 11718), (0x04f95, 11722), (0x04f96, 6243), (0x04f97, 11710),
+    (0x04f98, 11698), (0x04f9a, 11712), (0x04f9b, 6234), (0x04f9c, 11717), (0x04f9d, 6229), (0x04f9e, 11719), (0x04fa2, 2734),
+    (0x04fa8, 1272), (0x04fab, 19020), (0x04fae, 6616), (0x04faf, 6604), (0x04fb0, 3649), (0x04fb2, 12058), (0x04fb3, 12066),
+    (0x04fb4, 1877), (0x04fb5, 6603), (0x04fb6, 6611), (0x04fb7, 6623), (0x04fb9, 12072), (0x04fba, 12070), (0x04fbb, 12065),
+    (0x04fbd, 1372), (0x04fbf, 6605), (0x04fc0, 12071), (0x04fc1, 12061), (0x04fc2, 6619), (0x04fc3, 6610), (0x04fc4, 6618),
+    (0x04fc5, 12056), (0x04fc7, 12068), (0x04fc8, 3691), (0x04fc9, 12059), (0x04fca, 6614), (0x04fcb, 12060), (0x04fcc, 2742),
+    (0x04fcd, 12055), (0x04fce, 6621), (0x04fcf, 6608), (0x04fd0, 6617), (0x04fd1, 6607), (0x04fd3, 12057), (0x04fd4, 12062),
+    (0x04fd6, 12069), (0x04fd7, 6615), (0x04fd8, 6612), (0x04fd9, 12064), (0x04fda, 6620), (0x04fdb, 12067), (0x04fdc, 12063),
+    (0x04fdd, 6609), (0x04fde, 6622), (0x04fdf, 6613), (0x04fe0, 6606), (0x04fe1, 6602), (0x04fe4, 3421), (0x04fe5, 2743),
+    (0x04fec, 12073), (0x04fee, 7025), (0x04fef, 7003), (0x04ff0, 1775), (0x04ff1, 7019), (0x04ff2, 4756), (0x04ff3, 7024),
+    (0x04ff4, 12513), (0x04ff5, 12512), (0x04ff6, 12517), (0x04ff7, 12518), (0x04ff8, 7006), (0x04ff9, 2185), (0x04ffa, 7015),
+    (0x04ffd, 4754), (0x04ffe, 7028), (0x05000, 7016), (0x05003, 1374), (0x05005, 12506), (0x05006, 7009), (0x05007, 12507),
+    (0x05008, 19022), (0x05009, 7030), (0x0500b, 7021), (0x0500c, 7000), (0x0500d, 7001), (0x0500e, 12526), (0x0500f, 7472),
+    (0x05011, 7014), (0x05012, 7013), (0x05013, 12508), (0x05014, 7017), (0x05015, 13058), (0x05016, 7008), (0x05017, 12519),
+    (0x05018, 7023), (0x05019, 7022), (0x0501a, 7012), (0x0501b, 12511), (0x0501c, 12520), (0x0501e, 12505), (0x0501f, 7011),
+    (0x05020, 12521), (0x05021, 7020), (0x05022, 12509), (0x05023, 7002), (0x05025, 7005), (0x05026, 7004), (0x05027, 12522),
+    (0x05028, 7018), (0x05029, 7007), (0x0502a, 7027), (0x0502b, 7029), (0x0502c, 12516), (0x0502d, 7026), (0x0502e, 2739),
+    (0x0502f, 12524), (0x05030, 12510), (0x05031, 12525), (0x05033, 12514), (0x05034, 2547), (0x05035, 12523), (0x05037, 12515),
+    (0x0503b, 1727), (0x0503c, 7010), (0x05040, 13066), (0x05041, 13054), (0x05043, 7460), (0x05045, 13059), (0x05046, 13065),
+    (0x05047, 7459), (0x05048, 13052), (0x05049, 7463), (0x0504a, 13056), (0x0504b, 13049), (0x0504c, 7461), (0x0504d, 13053),
+    (0x0504e, 7466), (0x0504f, 7471), (0x05051, 13070), (0x05053, 13048), (0x05055, 7467), (0x05056, 4761), (0x05057, 13069),
+    (0x05058, 2744), (0x0505a, 7462), (0x0505b, 13055), (0x0505c, 7458), (0x0505d, 13050), (0x0505e, 13046), (0x0505f, 13060),
+    (0x05060, 13047), (0x05061, 13045), (0x05062, 13057), (0x05063, 13063), (0x05064, 13064), (0x05065, 7464), (0x05066, 4217),
+    (0x05068, 13686), (0x05069, 13061), (0x0506a, 13044), (0x0506b, 13062), (0x0506c, 2740), (0x0506d, 7474), (0x0506e, 13067),
+    (0x0506f, 7473), (0x05070, 13043), (0x05072, 13051), (0x05073, 13068), (0x05074, 7469), (0x05075, 7468), (0x05076, 7465),
+    (0x05077, 7470), (0x0507a, 7456), (0x0507d, 7457), (0x05080, 7952), (0x05081, 2741), (0x05082, 13689), (0x05083, 13682),
+    (0x05085, 7949), (0x05087, 13690), (0x05088, 1375), (0x0508b, 13680), (0x0508c, 13683), (0x0508d, 7948), (0x0508e, 13684),
+    (0x05090, 19490), (0x05091, 7951), (0x05092, 13688), (0x05094, 13678), (0x05095, 13677), (0x05096, 7953), (0x05098, 7954),
+    (0x05099, 7950), (0x0509a, 7955), (0x0509b, 13676), (0x0509c, 13687), (0x0509d, 13685), (0x0509e, 13679), (0x050a2, 7947),
+    (0x050a3, 13681), (0x050a6, 4780), (0x050ac, 8425), (0x050ad, 8419), (0x050ae, 14372), (0x050af, 8428), (0x050b0, 14378),
+    (0x050b1, 14381), (0x050b2, 8421), (0x050b3, 8422), (0x050b4, 14375), (0x050b5, 8420), (0x050b6, 14384), (0x050b7, 8426),
+    (0x050b8, 14385), (0x050ba, 14380), (0x050bb, 8427), (0x050bc, 1776), (0x050bd, 14369), (0x050be, 8424), (0x050bf, 14370),
+    (0x050c1, 14379), (0x050c2, 14377), (0x050c4, 14373), (0x050c5, 8423), (0x050c6, 14371), (0x050c7, 8429), (0x050c8, 14376),
+    (0x050c9, 14383), (0x050ca, 14374), (0x050cb, 14382), (0x050cd, 2732), (0x050ce, 8876), (0x050cf, 8873), (0x050d0, 2484),
+    (0x050d1, 8874), (0x050d3, 15066), (0x050d4, 15058), (0x050d5, 8872), (0x050d6, 8869), (0x050d7, 15059), (0x050d9, 4935),
+    (0x050da, 8871), (0x050db, 15062), (0x050dd, 15064), (0x050df, 3056), (0x050e0, 15071), (0x050e1, 19361), (0x050e3, 15070),
+    (0x050e4, 15065), (0x050e5, 8868), (0x050e6, 15057), (0x050e7, 8866), (0x050e8, 15060), (0x050e9, 8877), (0x050ea, 15063),
+    (0x050ec, 15067), (0x050ed, 8870), (0x050ee, 8867), (0x050ef, 15069), (0x050f0, 15068), (0x050f1, 8875), (0x050f3, 15061),
+    (0x050f4, 3552), (0x050f5, 9256), (0x050f6, 15681), (0x050f8, 15678), (0x050f9, 9257), (0x050fb, 9255), (0x050fc, 2745),
+    (0x050fd, 15685), (0x050fe, 15682), (0x050ff, 15675), (0x05100, 9254), (0x05101, 19036), (0x05102, 9258), (0x05103, 15676),
+    (0x05104, 9253), (0x05105, 9261), (0x05106, 15679), (0x05107, 15680), (0x05108, 9259), (0x05109, 9260), (0x0510a, 15686),
+    (0x0510b, 15683), (0x0510c, 15684), (0x0510d, 2733), (0x0510e, 19038), (0x05110, 9649), (0x05111, 16321), (0x05112, 9646),
+    (0x05113, 16318), (0x05114, 9648), (0x05115, 9650), (0x05117, 16319), (0x05118, 9647), (0x0511a, 16320), (0x0511c, 16317),
+    (0x0511f, 9945), (0x05120, 16890), (0x05121, 9946), (0x05122, 16888), (0x05124, 16889), (0x05125, 16887), (0x05126, 16886),
+    (0x05129, 16891), (0x0512a, 9944), (0x0512b, 2709), (0x0512d, 17356), (0x0512e, 17357), (0x05130, 15677), (0x05131, 17355),
+    (0x05132, 9947), (0x05133, 10393), (0x05134, 17721), (0x05135, 17722), (0x05137, 10636), (0x05138, 10637), (0x05139, 18307),
+    (0x0513a, 18306), (0x0513b, 10720), (0x0513c, 10719), (0x0513d, 18636), (0x0513f, 5504), (0x05140, 5528), (0x05141, 5582),
+    (0x05143, 5581), (0x05144, 5671), (0x05145, 5670), (0x05146, 5806), (0x05147, 5805), (0x05148, 5807), (0x05149, 5804),
+    (0x0514b, 5976), (0x0514c, 5975), (0x0514d, 5977), (0x05152, 6249), (0x05154, 6248), (0x05155, 6250), (0x05156, 1273),
+    (0x05157, 6624), (0x05159, 5206), (0x0515a, 12527), (0x0515b, 5207), (0x0515c, 7475), (0x0515d, 5209), (0x0515e, 5208),
+    (0x0515f, 13691), (0x05160, 19044), (0x05161, 5210), (0x05162, 8878), (0x05163, 5211), (0x05165, 5505), (0x05167, 5583),
+    (0x05168, 5808), (0x05169, 6251), (0x0516a, 19046), (0x0516b, 5506), (0x0516c, 5586), (0x0516d, 5584), (0x0516e, 5585),
+    (0x05171, 5809), (0x05174, 1274), (0x05175, 5978), (0x05176, 6253), (0x05177, 6252), (0x05178, 6254), (0x05179, 3009),
+    (0x0517c, 7031), (0x05180, 9651), (0x05182, 10930), (0x05186, 1379), (0x05187, 11318), (0x05188, 11211), (0x05189, 5672),
+    (0x0518a, 5673), (0x0518d, 5810), (0x0518f, 11503), (0x05191, 6626), (0x05192, 6625), (0x05193, 12529), (0x05194, 12528),
+    (0x05195, 7476), (0x05196, 10931), (0x05197, 5587), (0x05198, 11325), (0x0519a, 4571), (0x0519c, 1275), (0x0519e, 11725),
+    (0x051a0, 6627), (0x051a2, 7034), (0x051a4, 7032), (0x051a5, 7033), (0x051a7, 4574), (0x051a8, 2757), (0x051aa, 9652),
+    (0x051ab, 10932), (0x051ac, 5674), (0x051ae, 1754), (0x051b0, 5811), (0x051b1, 11394), (0x051b2, 19058), (0x051b3, 19067),
+    (0x051b4, 3965), (0x051b5, 19004), (0x051b6, 5979), (0x051b7, 5980), (0x051b8, 19059), (0x051b9, 11504), (0x051bc, 11726),
+    (0x051bd, 6255), (0x051be, 11727), (0x051c3, 2758), (0x051c4, 12531), (0x051c5, 12532), (0x051c6, 7037), (0x051c7, 19552),
+    (0x051c8, 12533), (0x051c9, 19061), (0x051ca, 12530), (0x051cb, 7038), (0x051cc, 7036), (0x051cd, 7035), (0x051ce, 12534),
+    (0x051cf, 19062), (0x051d0, 13071), (0x051d1, 19063), (0x051d2, 4164), (0x051d3, 19065), (0x051d4, 13692), (0x051d7, 14386),
+    (0x051d8, 15072), (0x051db, 1381), (0x051dc, 9262), (0x051dd, 9653), (0x051de, 16322), (0x051df, 19589), (0x051e0, 5507),
+    (0x051e1, 5521), (0x051e2, 19068), (0x051e4, 1276), (0x051ed, 19070), (0x051f0, 7477), (0x051f1, 7957), (0x051f3, 8879),
+    (0x051f4, 19369), (0x051f5, 11306), (0x051f6, 5588), (0x051f8, 5677), (0x051f9, 5675), (0x051fa, 5676), (0x051fc, 1382),
+    (0x051fd, 6256), (0x051fe, 4725), (0x05200, 5508), (0x05201, 5509), (0x05202, 11208), (0x05203, 5529), (0x05205, 1383),
+    (0x05206, 5589), (0x05207, 5590), (0x05208, 5591), (0x05209, 11396), (0x0520a, 5678), (0x0520b, 19075), (0x0520c, 11347),
+    (0x0520e, 5815), (0x05210, 11397), (0x05211, 5813), (0x05212, 5814), (0x05213, 11395), (0x05216, 5816), (0x05217, 5812),
+    (0x0521c, 11505), (0x0521d, 6575), (0x0521e, 11506), (0x0521f, 4817), (0x05220, 5000), (0x05221, 11507), (0x05224, 5982),
+    (0x05225, 5981), (0x05226, 19076), (0x05227, 1324), (0x05228, 5985), (0x05229, 5983), (0x0522a, 5984), (0x0522e, 6262),
+    (0x05230, 6261), (0x05231, 11732), (0x05232, 11729), (0x05233, 11730), (0x05234, 4705), (0x05235, 11728), (0x05236, 6263),
+    (0x05237, 6259), (0x05238, 6258), (0x0523a, 6260), (0x0523b, 6257), (0x0523c, 19077), (0x05241, 6264), (0x05243, 6629),
+    (0x05244, 12074), (0x05246, 11731), (0x05247, 6634), (0x05249, 12075), (0x0524a, 6630), (0x0524b, 6633), (0x0524c, 6632),
+    (0x0524d, 6631), (0x0524e, 6628), (0x05252, 12537), (0x05254, 7041), (0x05255, 12540), (0x05256, 7039), (0x05257, 19079),
+    (0x05259, 2767), (0x0525a, 12536), (0x0525b, 7042), (0x0525c, 7040), (0x0525d, 7043), (0x0525e, 12538), (0x0525f, 12539),
+    (0x05260, 4757), (0x05261, 12535), (0x05262, 12541), (0x05268, 1648), (0x05269, 7961), (0x0526a, 7478), (0x0526b, 13072),
+    (0x0526c, 13074), (0x0526d, 13073), (0x0526e, 13075), (0x0526f, 7479), (0x05272, 7958), (0x05273, 4784), (0x05274, 7959),
+    (0x05275, 7960), (0x05277, 8431), (0x05278, 14388), (0x05279, 1385), (0x0527a, 14387), (0x0527b, 14389), (0x0527c, 14390),
+    (0x0527d, 8432), (0x0527f, 8430), (0x05280, 15073), (0x05281, 15074), (0x05282, 8881), (0x05283, 8880), (0x05284, 15412),
+    (0x05287, 9263), (0x05288, 9264), (0x05289, 9265), (0x0528a, 9267), (0x0528b, 15687), (0x0528c, 15688), (0x0528d, 9266),
+    (0x0528f, 4195), (0x05290, 1386), (0x05291, 9654), (0x05293, 9655), (0x05294, 19080), (0x05296, 17723), (0x05297, 18309),
+    (0x05298, 18308), (0x05299, 18637), (0x0529a, 4825), (0x0529b, 5510), (0x0529f, 5680), (0x052a0, 5679), (0x052a1, 1277),
+    (0x052a3, 5817), (0x052a4, 2768), (0x052a6, 11398), (0x052a8, 1278), (0x052a9, 5987), (0x052aa, 5988), (0x052ab, 5986),
+    (0x052ac, 5989), (0x052ad, 11508), (0x052ae, 11509), (0x052b5, 19078), (0x052b9, 19081), (0x052bb, 6266), (0x052bc, 11733),
+    (0x052be, 6265), (0x052c0, 12076), (0x052c1, 6638), (0x052c2, 12077), (0x052c3, 6637), (0x052c5, 19082), (0x052c7, 6635),
+    (0x052c9, 6636), (0x052cc, 4675), (0x052cd, 12542), (0x052d0, 19252), (0x052d1, 4759), (0x052d2, 7480), (0x052d3, 13077),
+    (0x052d5, 7483), (0x052d6, 13076), (0x052d7, 7667), (0x052d8, 7482), (0x052d9, 7481), (0x052db, 7964), (0x052dd, 7963),
+    (0x052de, 7962), (0x052df, 8433), (0x052e0, 19085), (0x052e1, 2770), (0x052e2, 8436), (0x052e3, 8437), (0x052e4, 8435),
+    (0x052e6, 8434), (0x052e9, 15075), (0x052eb, 15076), (0x052ef, 15690), (0x052f0, 9268), (0x052f1, 15689), (0x052f3, 9656),
+    (0x052f4, 16892), (0x052f5, 9948), (0x052f7, 17724), (0x052f8, 10540), (0x052f9, 10933), (0x052fa, 5530), (0x052fb, 5592),
+    (0x052fc, 11326), (0x052fe, 5593), (0x052ff, 5594), (0x05301, 3937), (0x05305, 5681), (0x05306, 5682), (0x05308, 5818),
+    (0x05309, 11510), (0x0530a, 11734), (0x0530b, 11735), (0x0530d, 6639), (0x0530e, 12543), (0x0530f, 7485), (0x05310, 7484),
+    (0x05311, 13694), (0x05312, 13693), (0x05315, 5511), (0x05316, 5595), (0x05317, 5683), (0x05319, 7486), (0x0531a, 11307),
+    (0x0531c, 11348), (0x0531d, 5684), (0x0531f, 11400), (0x05320, 5820), (0x05321, 5819), (0x05322, 11399), (0x05323, 5990),
+    (0x05324, 1767), (0x05327, 1387), (0x0532a, 7044), (0x0532c, 4771), (0x0532d, 13078), (0x0532f, 8438), (0x05330, 15077),
+    (0x05331, 8882), (0x05332, 4938), (0x05333, 19095), (0x05334, 16323), (0x05337, 18053), (0x05338, 10934), (0x05339, 5596),
+    (0x0533b, 1279), (0x0533c, 11736), (0x0533d, 12078), (0x0533e, 7489), (0x0533f, 7487), (0x05340, 7488), (0x05341, 5512),
+    (0x05342, 19069), (0x05343, 5531), (0x05344, 5288), (0x05345, 5599), (0x05347, 5598), (0x05348, 5597), (0x05349, 5687),
+    (0x0534a, 5686), (0x0534c, 11349), (0x0534d, 11401), (0x0534e, 1280), (0x05351, 6270), (0x05352, 6267), (0x05353, 6269),
+    (0x05354, 6268), (0x05357, 6640), (0x0535a, 7965), (0x0535c, 5513), (0x0535d, 1699), (0x0535e, 5600), (0x0535f, 1603),
+    (0x05360, 5689), (0x05361, 5688), (0x05363, 11511), (0x05364, 4068), (0x05366, 6271), (0x05367, 4638), (0x05369, 10935),
+    (0x0536c, 11327), (0x0536d, 19108), (0x0536e, 5691), (0x0536f, 5690), (0x05370, 5821), (0x05371, 5822), (0x05372, 11512),
+    (0x05373, 5991), (0x05374, 19109), (0x05375, 5992), (0x05377, 6272), (0x05378, 6273), (0x05379, 6274), (0x0537b, 6641),
+    (0x0537c, 12079), (0x0537d, 4641), (0x0537e, 19111), (0x0537f, 7045), (0x05382, 11308), (0x05384, 5601), (0x0538a, 11402),
+    (0x0538e, 11513), (0x0538f, 11514), (0x05392, 11737), (0x05393, 19116), (0x05394, 11738), (0x05396, 12081), (0x05397, 12080),
+    (0x05398, 12083), (0x05399, 12082), (0x0539a, 6642), (0x0539c, 13079), (0x0539d, 7047), (0x0539e, 12544), (0x0539f, 7046),
+    (0x053a0, 19118), (0x053a2, 4365), (0x053a4, 13695), (0x053a5, 7966), (0x053a6, 19303), (0x053a7, 13696), (0x053a8, 19306),
+    (0x053a9, 1389), (0x053aa, 4925), (0x053ab, 19119), (0x053ac, 15078), (0x053ad, 8883), (0x053ae, 19120), (0x053b0, 1391),
+    (0x053b2, 9269), (0x053b4, 17725), (0x053b6, 10936), (0x053b9, 11328), (0x053bb, 5692), (0x053c1, 19125), (0x053c2, 1393),
+    (0x053c3, 7490), (0x053c5, 19126), (0x053c8, 5514), (0x053c9, 5532), (0x053ca, 5603), (0x053cb, 5602), (0x053cc, 2457),
+    (0x053cd, 5604), (0x053d0, 2789), (0x053d1, 1281), (0x053d2, 4386), (0x053d4, 6276), (0x053d6, 6275), (0x053d7, 6277),
+    (0x053d8, 1282), (0x053d9, 19130), (0x053da, 4774), (0x053db, 6643), (0x053df, 7048), (0x053e0, 19133), (0x053e1, 16324),
+    (0x053e2, 10220), (0x053e3, 5533), (0x053e4, 5694), (0x053e5, 5709), (0x053e6, 5704), (0x053e8, 5699), (0x053e9, 5698),
+    (0x053ea, 5705), (0x053eb, 5703), (0x053ec, 5696), (0x053ed, 5710), (0x053ee, 5697), (0x053ef, 5693), (0x053f0, 5708),
+    (0x053f1, 5707), (0x053f2, 5706), (0x053f3, 5695), (0x053f5, 5702), (0x053f6, 19136), (0x053f7, 2273), (0x053f8, 5701),
+    (0x053fb, 5711), (0x053fc, 5700), (0x053fe, 19179), (0x05401, 5828), (0x05403, 5834), (0x05404, 5830), (0x05406, 5836),
+    (0x05407, 11403), (0x05408, 5833), (0x05409, 5823), (0x0540a, 5826), (0x0540b, 5829), (0x0540c, 5825), (0x0540d, 5832),
+    (0x0540e, 5835), (0x0540f, 5824), (0x05410, 5827), (0x05411, 5831), (0x05412, 5837), (0x05413, 19138), (0x05414, 3438),
+    (0x05416, 4534), (0x05418, 11523), (0x05419, 11520), (0x0541a, 4500), (0x0541b, 6005), (0x0541c, 11521), (0x0541d, 5993),
+    (0x0541e, 5995), (0x0541f, 6019), (0x05420, 6014), (0x05421, 19513), (0x05423, 1394), (0x05424, 11528), (0x05425, 11522),
+    (0x05426, 5997), (0x05427, 5999), (0x05428, 11527), (0x05429, 6006), (0x0542a, 11517), (0x0542b, 6018), (0x0542c, 6020),
+    (0x0542d, 5994), (0x0542e, 6011), (0x0542f, 19158), (0x05430, 11515), (0x05431, 6017), (0x05432, 4524), (0x05433, 6002),
+    (0x05434, 1780), (0x05435, 6012), (0x05436, 6013), (0x05437, 11516), (0x05438, 6010), (0x05439, 6008), (0x0543b, 6009),
+    (0x0543c, 6015), (0x0543d, 11524), (0x0543e, 5996), (0x05440, 6016), (0x05441, 11526), (0x05442, 6004), (0x05443, 6001),
+    (0x05445, 11519), (0x05446, 6000), (0x05447, 11529), (0x05448, 6003), (0x0544a, 6007), (0x0544b, 4647), (0x0544d, 4575),
+    (0x0544e, 5998), (0x0544f, 11525), (0x05454, 11518), (0x05460, 11755), (0x05461, 11754), (0x05462, 6295), (0x05463, 11757),
+    (0x05464, 11759), (0x05465, 11748), (0x05466, 11751), (0x05467, 11758), (0x05468, 6296), (0x05469, 4767), (0x0546a, 4026),
+    (0x0546b, 11745), (0x0546c, 11749), (0x0546d, 19143), (0x0546f, 11753), (0x05470, 12098), (0x05471, 6291), (0x05472, 12102),
+    (0x05473, 6278), (0x05474, 11750), (0x05475, 6279), (0x05476, 6292), (0x05477, 6285), (0x05478, 6281), (0x0547a, 11746),
+    (0x0547b, 6284), (0x0547c, 6289), (0x0547d, 6298), (0x0547e, 11747), (0x0547f, 11740), (0x05480, 6283), (0x05481, 11741),
+    (0x05482, 11743), (0x05484, 6286), (0x05485, 3674), (0x05486, 6288), (0x05487, 11739), (0x05488, 11744), (0x0548b, 6297),
+    (0x0548c, 6293), (0x0548d, 11752), (0x0548e, 6299), (0x0548f, 19147), (0x05490, 6290), (0x05491, 11742), (0x05492, 6287),
+    (0x05493, 1398), (0x05494, 4560), (0x05495, 6282), (0x05496, 6280), (0x05497, 4545), (0x05498, 11756), (0x0549a, 6294),
+    (0x0549c, 4823), (0x0549e, 4029), (0x054a0, 12097), (0x054a1, 12085), (0x054a2, 12100), (0x054a3, 1399), (0x054a4, 19148),
+    (0x054a5, 12087), (0x054a6, 6650), (0x054a7, 6664), (0x054a8, 6646), (0x054a9, 6663), (0x054aa, 6655), (0x054ab, 6660),
+    (0x054ac, 6644), (0x054ad, 12086), (0x054ae, 12092), (0x054af, 6659), (0x054b0, 12104), (0x054b1, 6661), (0x054b2, 2067),
+    (0x054b3, 6651), (0x054b4, 1400), (0x054b6, 12094), (0x054b7, 12091), (0x054b8, 6649), (0x054b9, 1401), (0x054ba, 12084),
+    (0x054bb, 6662), (0x054bc, 12099), (0x054bd, 6654), (0x054be, 12101), (0x054bf, 6665), (0x054c0, 6645), (0x054c1, 6656),
+    (0x054c2, 6653), (0x054c3, 12089), (0x054c4, 6657), (0x054c5, 12095), (0x054c6, 12096), (0x054c7, 6652), (0x054c8, 6658),
+    (0x054c9, 6648), (0x054cb, 2698), (0x054cc, 1587), (0x054cd, 2795), (0x054ce, 6647), (0x054cf, 12088), (0x054d0, 1402),
+    (0x054d6, 12093), (0x054da, 4824), (0x054de, 12103), (0x054e0, 12564), (0x054e1, 7061), (0x054e2, 12546), (0x054e3, 1470),
+    (0x054e4, 12551), (0x054e5, 7054), (0x054e6, 7065), (0x054e7, 12549), (0x054e8, 7049), (0x054e9, 7059), (0x054ea, 7064),
+    (0x054eb, 12556), (0x054ed, 7060), (0x054ee, 7063), (0x054ef, 1403), (0x054f1, 12559), (0x054f2, 7055), (0x054f3, 12550),
+    (0x054f7, 12562), (0x054f8, 12563), (0x054fa, 7057), (0x054fb, 12561), (0x054fc, 7053), (0x054fd, 7068), (0x054ff, 12553),
+    (0x05501, 7051), (0x05502, 3523), (0x05503, 12566), (0x05504, 12554), (0x05505, 12558), (0x05506, 7056), (0x05507, 7067),
+    (0x05508, 12555), (0x05509, 7062), (0x0550a, 12560), (0x0550b, 12567), (0x0550c, 13094), (0x0550d, 2914), (0x0550e, 12565),
+    (0x0550f, 7069), (0x05510, 7050), (0x05511, 12557), (0x05512, 12548), (0x05513, 4577), (0x05514, 7058), (0x05517, 12547),
+    (0x05518, 1404), (0x0551a, 12552), (0x0551e, 4576), (0x05523, 1405), (0x05525, 1414), (0x05526, 12545), (0x05527, 7066),
+    (0x05528, 1406), (0x0552a, 13086), (0x0552b, 19140), (0x0552c, 7509), (0x0552d, 13100), (0x0552e, 7507), (0x0552f, 7504),
+    (0x05530, 13091), (0x05531, 7500), (0x05532, 13095), (0x05533, 7511), (0x05534, 13085), (0x05535, 13090), (0x05536, 13089),
+    (0x05537, 7052), (0x05538, 7506), (0x05539, 13098), (0x0553b, 13101), (0x0553c, 13082), (0x0553e, 7984), (0x0553f, 1408),
+    (0x05540, 13102), (0x05541, 7512), (0x05543, 7498), (0x05544, 7495), (0x05545, 13093), (0x05546, 7492), (0x05547, 19156),
+    (0x05548, 13099), (0x05549, 19090), (0x0554a, 7499), (0x0554b, 13103), (0x0554d, 13083), (0x0554e, 13097), (0x0554f, 7502),
+    (0x05550, 13084), (0x05551, 13087), (0x05552, 13092), (0x05553, 19219), (0x05555, 7503), (0x05556, 7501), (0x05557, 7513),
+    (0x0555c, 7508), (0x0555d, 19146), (0x0555e, 7496), (0x0555f, 7648), (0x05561, 7497), (0x05562, 13088), (0x05563, 7510),
+    (0x05564, 7505), (0x05565, 13096), (0x05566, 7494), (0x05569, 4567), (0x0556a, 7493), (0x0556b, 3105), (0x05571, 3260),
+    (0x05572, 4546), (0x05573, 4752), (0x05575, 13080), (0x05576, 13081), (0x05577, 13701), (0x05579, 1498), (0x0557b, 7967),
+    (0x0557c, 7970), (0x0557d, 13712), (0x0557e, 7990), (0x0557f, 13715), (0x05580, 7968), (0x05581, 13708), (0x05582, 7974),
+    (0x05583, 7980), (0x05584, 8255), (0x05586, 19160), (0x05587, 7978), (0x05588, 13705), (0x05589, 7991), (0x0558a, 7971),
+    (0x0558b, 7979), (0x0558c, 13713), (0x0558d, 14404), (0x0558e, 13718), (0x0558f, 13706), (0x05590, 1419), (0x05591, 13697),
+    (0x05592, 13710), (0x05593, 13704), (0x05594, 7977), (0x05595, 13716), (0x05598, 7973), (0x05599, 7993), (0x0559a, 7986),
+    (0x0559c, 7975), (0x0559d, 7972), (0x0559f, 7983), (0x055a1, 13717), (0x055a2, 13703), (0x055a3, 13709), (0x055a4, 13711),
+    (0x055a5, 13699), (0x055a6, 13714), (0x055a7, 7969), (0x055a8, 13698), (0x055a9, 19161), (0x055aa, 7976), (0x055ab, 7992),
+    (0x055ac, 7988), (0x055ad, 13700), (0x055ae, 7982), (0x055b0, 2520), (0x055b1, 7989), (0x055b2, 7985), (0x055b3, 7981),
+    (0x055b4, 2793), (0x055b5, 13707), (0x055b9, 1589), (0x055ba, 4544), (0x055bb, 7987), (0x055bc, 4202), (0x055bf, 14402),
+    (0x055c0, 14398), (0x055c1, 4458), (0x055c2, 14413), (0x055c3, 14391), (0x055c4, 14400), (0x055c5, 8452), (0x055c6, 8453),
+    (0x055c7, 8445), (0x055c8, 14409), (0x055c9, 8455), (0x055ca, 14396), (0x055cb, 14395), (0x055cc, 14393), (0x055cd, 14411),
+    (0x055ce, 8443), (0x055cf, 14405), (0x055d0, 14394), (0x055d1, 8446), (0x055d2, 14403), (0x055d3, 8441), (0x055d4, 14399),
+    (0x055d5, 14406), (0x055d6, 14408), (0x055d7, 1411), (0x055d8, 3898), (0x055d9, 14412), (0x055da, 8450), (0x055db, 14392),
+    (0x055dc, 8444), (0x055dd, 14397), (0x055de, 4556), (0x055df, 8439), (0x055e1, 8451), (0x055e2, 14407), (0x055e3, 8447),
+    (0x055e4, 8448), (0x055e5, 8454), (0x055e6, 8442), (0x055e7, 5212), (0x055e8, 8440), (0x055e9, 14401), (0x055ea, 4531),
+    (0x055ec, 2216), (0x055ef, 8449), (0x055f0, 4543), (0x055f1, 4538), (0x055f2, 14410), (0x055f5, 3901), (0x055f6, 8899),
+    (0x055f7, 8894), (0x055f9, 15093), (0x055fa, 15089), (0x055fb, 1661), (0x055fc, 15083), (0x055fd, 8888), (0x055fe, 8884),
+    (0x055ff, 15092), (0x05600, 8885), (0x05601, 15086), (0x05602, 15088), (0x05604, 15091), (0x05605, 4537), (0x05606, 8890),
+    (0x05608, 8897), (0x05609, 8891), (0x0560c, 15081), (0x0560d, 8892), (0x0560e, 8893), (0x0560f, 15084), (0x05610, 8898),
+    (0x05611, 4795), (0x05612, 15082), (0x05613, 15087), (0x05614, 8889), (0x05615, 15080), (0x05616, 8895), (0x05617, 8887),
+    (0x0561b, 8886), (0x0561c, 15085), (0x0561d, 15090), (0x0561e, 4506), (0x0561f, 8896), (0x05620, 4847), (0x05621, 1955),
+    (0x05622, 4505), (0x05623, 3738), (0x05625, 4527), (0x05627, 15079), (0x05629, 9276), (0x0562a, 15707), (0x0562c, 15704),
+    (0x0562d, 4498), (0x0562e, 9270), (0x0562f, 9282), (0x05630, 9283), (0x05632, 9273), (0x05633, 15702), (0x05634, 9275),
+    (0x05635, 15694), (0x05636, 9281), (0x05637, 19169), (0x05638, 15706), (0x05639, 9272), (0x0563a, 15708), (0x0563b, 9271),
+    (0x0563d, 15703), (0x0563e, 15705), (0x0563f, 9274), (0x05640, 15701), (0x05641, 15695), (0x05642, 15692), (0x05643, 4533),
+    (0x05645, 13702), (0x05646, 15698), (0x05648, 15691), (0x05649, 15697), (0x0564a, 15696), (0x0564c, 15693), (0x0564d, 19174),
+    (0x0564e, 9278), (0x0564f, 19175), (0x05650, 3989), (0x05652, 1529), (0x05653, 9277), (0x05654, 3956), (0x05657, 9279),
+    (0x05658, 15699), (0x05659, 9657), (0x0565a, 15700), (0x0565d, 4700), (0x0565e, 16333), (0x05660, 16326), (0x05661, 2283),
+    (0x05662, 9669), (0x05663, 16330), (0x05664, 9661), (0x05665, 9665), (0x05666, 16329), (0x05668, 9664), (0x05669, 9660),
+    (0x0566a, 9663), (0x0566b, 9658), (0x0566c, 9668), (0x0566d, 16331), (0x0566e, 16327), (0x0566f, 9667), (0x05670, 16325),
+    (0x05671, 9666), (0x05672, 16332), (0x05673, 16328), (0x05674, 9280), (0x05676, 9670), (0x05677, 16334), (0x05678, 9662),
+    (0x05679, 9659), (0x0567a, 4037), (0x0567b, 4595), (0x0567c, 4687), (0x0567e, 16899), (0x0567f, 16901), (0x05680, 9950),
+    (0x05681, 16902), (0x05682, 16900), (0x05683, 16898), (0x05684, 16897), (0x05685, 9952), (0x05686, 16896), (0x05687, 9953),
+    (0x05689, 1663), (0x0568a, 2608), (0x0568b, 4430), (0x0568c, 16894), (0x0568d, 16895), (0x0568e, 9949), (0x0568f, 9954),
+    (0x05690, 9951), (0x05692, 2701), (0x05693, 16893), (0x05695, 10221), (0x05697, 17360), (0x05698, 17358), (0x05699, 17363),
+    (0x0569a, 17361), (0x0569c, 17359), (0x0569d, 17362), (0x0569e, 2699), (0x0569f, 2607), (0x056a1, 1512), (0x056a4, 3519),
+    (0x056a5, 10394), (0x056a6, 17728), (0x056a7, 17729), (0x056a8, 10541), (0x056aa, 17730), (0x056ab, 17726), (0x056ac, 17731),
+    (0x056ad, 17727), (0x056ae, 10222), (0x056af, 1614), (0x056b1, 1946), (0x056b2, 18054), (0x056b3, 18056), (0x056b4, 10544),
+    (0x056b5, 18055), (0x056b6, 10543), (0x056b7, 10542), (0x056b9, 4259), (0x056bc, 10545), (0x056bd, 18311), (0x056be, 18312),
+    (0x056bf, 4487), (0x056c0, 10639), (0x056c1, 10638), (0x056c2, 10640), (0x056c3, 18310), (0x056c5, 18487), (0x056c6, 18486),
+    (0x056c8, 10721), (0x056c9, 10723), (0x056ca, 10722), (0x056cb, 18488), (0x056cc, 10778), (0x056cd, 18751), (0x056d1, 10819),
+    (0x056d3, 18750), (0x056d4, 18823), (0x056d6, 4338), (0x056d7, 11313), (0x056da, 5713), (0x056db, 5712), (0x056dd, 5840),
+    (0x056de, 5839), (0x056df, 11405), (0x056e0, 5838), (0x056e1, 11404), (0x056e2, 1283), (0x056e4, 6023), (0x056e5, 11532),
+    (0x056e7, 11531), (0x056ea, 6021), (0x056eb, 6024), (0x056ed, 19182), (0x056ee, 11530), (0x056ef, 19181), (0x056f0, 6022),
+    (0x056f1, 19177), (0x056f7, 11760), (0x056f9, 11761), (0x056fa, 6300), (0x056fb, 979), (0x056fd, 3712), (0x056ff, 6666),
+    (0x05700, 19180), (0x05701, 12568), (0x05702, 12569), (0x05703, 7070), (0x05704, 7071), (0x05707, 13105), (0x05708, 7514),
+    (0x05709, 7516), (0x0570a, 13104), (0x0570b, 7515), (0x0570c, 13719), (0x0570d, 7994), (0x05712, 8456), (0x05713, 8457),
+    (0x05714, 14414), (0x05715, 4379), (0x05716, 8901), (0x05718, 8900), (0x0571a, 15709), (0x0571b, 16336), (0x0571c, 16335),
+    (0x0571d, 2797), (0x0571e, 18883), (0x0571f, 5534), (0x05720, 11329), (0x05722, 11350), (0x05723, 11351), (0x05728, 5843),
+    (0x05729, 5847), (0x0572a, 11407), (0x0572c, 5845), (0x0572d, 5844), (0x0572e, 11406), (0x0572f, 5846), (0x05730, 5842),
+    (0x05732, 3052), (0x05733, 5841), (0x05734, 11408), (0x0573b, 6034), (0x0573d, 1833), (0x0573e, 6031), (0x0573f, 4196),
+    (0x05740, 6027), (0x05741, 11533), (0x05742, 19190), (0x05743, 19208), (0x05745, 11534), (0x05746, 19186), (0x05747, 6029),
+    (0x05749, 11536), (0x0574a, 6025), (0x0574b, 11537), (0x0574c, 11535), (0x0574d, 6028), (0x0574e, 6030), (0x0574f, 6033),
+    (0x05750, 6032), (0x05751, 6026), (0x05752, 11538), (0x05754, 3899), (0x05757, 4021), (0x0575b, 2665), (0x0575f, 4918),
+    (0x05761, 6305), (0x05762, 11774), (0x05764, 6307), (0x05766, 6306), (0x05767, 4760), (0x05768, 11775), (0x05769, 6304),
+    (0x0576a, 6303), (0x0576b, 11765), (0x0576d, 11764), (0x0576f, 11762), (0x05770, 11767), (0x05771, 11766), (0x05772, 11763),
+    (0x05773, 11772), (0x05774, 11773), (0x05775, 11770), (0x05776, 11768), (0x05777, 6302), (0x0577a, 4816), (0x0577b, 11771),
+    (0x0577c, 6308), (0x0577d, 11776), (0x0577e, 3678), (0x0577f, 2803), (0x05780, 11769), (0x05782, 6667), (0x05783, 6301),
+    (0x05788, 4172), (0x0578a, 3442), (0x0578b, 6668), (0x0578c, 12109), (0x0578d, 2802), (0x0578f, 12115), (0x05790, 19350),
+    (0x05793, 6674), (0x05794, 12113), (0x05795, 12119), (0x05797, 12110), (0x05798, 12114), (0x05799, 12116), (0x0579a, 12118),
+    (0x0579b, 12112), (0x0579c, 1631), (0x0579d, 12111), (0x0579e, 12106), (0x0579f, 12107), (0x057a0, 6669), (0x057a1, 4228),
+    (0x057a2, 6671), (0x057a3, 6670), (0x057a4, 12108), (0x057a5, 12117), (0x057a7, 4702), (0x057aa, 4648), (0x057ae, 6673),
+    (0x057b4, 3815), (0x057b5, 12105), (0x057b6, 12579), (0x057b8, 12578), (0x057b9, 12583), (0x057ba, 12574), (0x057bb, 3440),
+    (0x057bc, 12577), (0x057bd, 12576), (0x057be, 2042), (0x057bf, 12580), (0x057c1, 12584), (0x057c2, 7072), (0x057c3, 7075),
+    (0x057c4, 3150), (0x057c6, 12575), (0x057c7, 12581), (0x057c8, 3586), (0x057cb, 7074), (0x057cc, 12570), (0x057ce, 6672),
+    (0x057cf, 13119), (0x057d0, 12582), (0x057d2, 12573), (0x057d4, 7073), (0x057d5, 12572), (0x057d7, 3444), (0x057dc, 13110),
+    (0x057dd, 2801), (0x057de, 4712), (0x057df, 7517), (0x057e0, 7521), (0x057e1, 13126), (0x057e2, 13108), (0x057e3, 13122),
+    (0x057e4, 7522), (0x057e5, 13124), (0x057e6, 19193), (0x057e7, 13130), (0x057e9, 13134), (0x057ec, 13125), (0x057ed, 13113),
+    (0x057ee, 13121), (0x057ef, 3839), (0x057f0, 13135), (0x057f1, 13133), (0x057f2, 13123), (0x057f3, 13118), (0x057f4, 13111),
+    (0x057f5, 13727), (0x057f6, 13109), (0x057f7, 7526), (0x057f8, 13116), (0x057f9, 7527), (0x057fa, 7523), (0x057fb, 13106),
+    (0x057fc, 13128), (0x057fd, 13114), (0x057fe, 19129), (0x05800, 13112), (0x05801, 13131), (0x05802, 7524), (0x05803, 19195),
+    (0x05804, 13137), (0x05805, 7518), (0x05806, 7520), (0x05807, 13120), (0x05808, 13115), (0x05809, 7076), (0x0580a, 7519),
+    (0x0580b, 13117), (0x0580c, 13132), (0x0580d, 13136), (0x0580e, 13127), (0x05810, 13129), (0x05812, 3585), (0x05814, 13107),
+    (0x05819, 13722), (0x0581b, 13731), (0x0581c, 13730), (0x0581d, 8002), (0x0581e, 13723), (0x05820, 8003), (0x05821, 8001),
+    (0x05822, 3358), (0x05823, 13725), (0x05824, 7998), (0x05825, 13729), (0x05826, 19198), (0x05827, 13724), (0x05828, 13726),
+    (0x05829, 13720), (0x0582a, 7996), (0x0582c, 13739), (0x0582d, 13738), (0x0582e, 13735), (0x0582f, 7995), (0x05830, 7999),
+    (0x05831, 8000), (0x05832, 12571), (0x05833, 13732), (0x05834, 7997), (0x05835, 7525), (0x05836, 13734), (0x05837, 13721),
+    (0x05838, 13737), (0x05839, 13736), (0x0583a, 3832), (0x0583b, 13740), (0x0583d, 14428), (0x0583f, 13733), (0x05840, 4014),
+    (0x05844, 3962), (0x05847, 2997), (0x05848, 13728), (0x05849, 14420), (0x0584a, 8467), (0x0584b, 8470), (0x0584c, 8465),
+    (0x0584d, 14419), (0x0584e, 14423), (0x0584f, 14418), (0x05851, 8459), (0x05852, 8469), (0x05853, 14415), (0x05854, 8463),
+    (0x05855, 14422), (0x05857, 8461), (0x05858, 8460), (0x05859, 14425), (0x0585a, 8462), (0x0585b, 14427), (0x0585c, 19200),
+    (0x0585d, 14424), (0x0585e, 8458), (0x0585f, 3893), (0x05862, 8468), (0x05863, 14429), (0x05864, 14417), (0x05865, 14426),
+    (0x05868, 14416), (0x05869, 3740), (0x0586b, 8464), (0x0586c, 2689), (0x0586d, 8466), (0x0586f, 14421), (0x05871, 14430),
+    (0x05872, 3356), (0x05873, 1490), (0x05874, 15101), (0x05875, 8902), (0x05876, 15107), (0x05879, 8907), (0x0587a, 15103),
+    (0x0587b, 15110), (0x0587c, 15095), (0x0587d, 8909), (0x0587e, 8903), (0x0587f, 15100), (0x05880, 9284), (0x05881, 15099),
+    (0x05882, 15108), (0x05883, 8904), (0x05885, 8908), (0x05886, 15098), (0x05887, 15104), (0x05888, 15109), (0x05889, 15094),
+    (0x0588a, 8906), (0x0588b, 15102), (0x0588e, 15106), (0x0588f, 15112), (0x05890, 15096), (0x05891, 15105), (0x05892, 1782),
+    (0x05893, 8905), (0x05894, 15111), (0x05898, 15097), (0x05899, 1647), (0x0589a, 4644), (0x0589c, 9288), (0x0589d, 15711),
+    (0x0589e, 9286), (0x0589f, 9285), (0x058a0, 15713), (0x058a1, 15718), (0x058a3, 15714), (0x058a5, 15717), (0x058a6, 9291),
+    (0x058a7, 4685), (0x058a8, 9644), (0x058a9, 9290), (0x058aa, 19201), (0x058ab, 15710), (0x058ac, 15716), (0x058ae, 9289),
+    (0x058af, 15715), (0x058b0, 2168), (0x058b1, 15712), (0x058b3, 9287), (0x058b5, 4143), (0x058b6, 4301), (0x058ba, 16341),
+    (0x058bb, 18959), (0x058bc, 16343), (0x058bd, 16338), (0x058be, 9672), (0x058bf, 16340), (0x058c1, 9671), (0x058c2, 16342),
+    (0x058c5, 9674), (0x058c6, 16344), (0x058c7, 9673), (0x058c8, 16337), (0x058c9, 16339), (0x058cb, 2805), (0x058ce, 9958),
+    (0x058cf, 16905), (0x058d0, 1783), (0x058d1, 9957), (0x058d2, 16906), (0x058d3, 9956), (0x058d4, 16904), (0x058d5, 9955),
+    (0x058d6, 16903), (0x058d8, 10224), (0x058d9, 10223), (0x058da, 17732), (0x058db, 17734), (0x058dc, 19204), (0x058dd, 17733),
+    (0x058de, 10395), (0x058df, 10396), (0x058e0, 19203), (0x058e2, 10397), (0x058e3, 18057), (0x058e4, 10546), (0x058e7, 18639),
+    (0x058e8, 18638), (0x058e9, 10820), (0x058eb, 5535), (0x058ec, 5605), (0x058ef, 6035), (0x058f0, 1284), (0x058f2, 3753),
+    (0x058f3, 3441), (0x058f4, 12120), (0x058f9, 8004), (0x058fa, 8005), (0x058fb, 19206), (0x058fc, 14431), (0x058fd, 8910),
+    (0x058fe, 15113), (0x058ff, 15719), (0x05902, 1665), (0x05903, 11330), (0x05904, 1285), (0x05905, 1380), (0x05906, 11539),
+    (0x05907, 1286), (0x0590a, 10937), (0x0590c, 11777), (0x0590d, 12121), (0x0590e, 12585), (0x0590f, 7077), (0x05911, 19627),
+    (0x05912, 17735), (0x05914, 10641), (0x05915, 5536), (0x05916, 5714), (0x05917, 11352), (0x05919, 5848), (0x0591a, 5849),
+    (0x0591c, 6309), (0x0591d, 982), (0x0591f, 19213), (0x05920, 7528), (0x05922, 8912), (0x05924, 8913), (0x05925, 8911),
+    (0x05927, 5537), (0x05929, 5606), (0x0592a, 5608), (0x0592b, 5607), (0x0592c, 11331), (0x0592d, 5609), (0x0592e, 5715),
+    (0x0592f, 11353), (0x05931, 5716), (0x05932, 1287), (0x05934, 1288), (0x05937, 5850), (0x05938, 5851), (0x0593c, 11409),
+    (0x0593e, 6036), (0x05940, 11540), (0x05944, 6313), (0x05945, 11778), (0x05947, 6311), (0x05948, 6312), (0x05949, 6310),
+    (0x0594a, 12586), (0x0594e, 6678), (0x0594f, 6677), (0x05950, 6679), (0x05951, 6676), (0x05953, 12122), (0x05954, 6314),
+    (0x05955, 6675), (0x05957, 7078), (0x05958, 7079), (0x0595a, 7080), (0x0595c, 13138), (0x05960, 8006), (0x05961, 13741),
+    (0x05962, 7529), (0x05965, 4189), (0x05967, 8471), (0x05969, 8915), (0x0596a, 8914), (0x0596b, 15114), (0x0596d, 9292),
+    (0x0596e, 9675), (0x05970, 17364), (0x05971, 18489), (0x05972, 18640), (0x05973, 5538), (0x05974, 5717), (0x05975, 4509),
+    (0x05976, 5718), (0x05977, 11415), (0x05978, 5853), (0x05979, 5856), (0x0597b, 11413), (0x0597c, 11411), (0x0597d, 5855),
+    (0x0597e, 11414), (0x0597f, 11416), (0x05980, 11410), (0x05981, 5858), (0x05982, 5857), (0x05983, 5854), (0x05984, 5852),
+    (0x05985, 11412), (0x05989, 3623), (0x0598a, 6047), (0x0598d, 6044), (0x0598e, 11545), (0x0598f, 11548), (0x05990, 11547),
+    (0x05992, 6038), (0x05993, 6046), (0x05994, 3472), (0x05996, 6043), (0x05997, 11544), (0x05998, 11542), (0x05999, 6042),
+    (0x0599a, 3237), (0x0599d, 6037), (0x0599e, 6040), (0x0599f, 4166), (0x059a0, 11543), (0x059a1, 11550), (0x059a2, 11546),
+    (0x059a3, 6041), (0x059a4, 6045), (0x059a5, 6048), (0x059a6, 11541), (0x059a7, 11549), (0x059a8, 6039), (0x059ac, 4012),
+    (0x059ae, 6319), (0x059af, 6327), (0x059b0, 3535), (0x059b1, 11790), (0x059b2, 11783), (0x059b3, 6328), (0x059b4, 11794),
+    (0x059b5, 11779), (0x059b6, 11786), (0x059b7, 4762), (0x059b8, 2812), (0x059b9, 6318), (0x059ba, 11780), (0x059bb, 6316),
+    (0x059bc, 11787), (0x059bd, 11791), (0x059be, 6315), (0x059bf, 1879), (0x059c0, 11792), (0x059c1, 11785), (0x059c3, 11788),
+    (0x059c4, 3579), (0x059c5, 6330), (0x059c6, 6321), (0x059c7, 11795), (0x059c8, 11793), (0x059c9, 19222), (0x059ca, 6326),
+    (0x059cb, 6324), (0x059cc, 11784), (0x059cd, 6323), (0x059ce, 11782), (0x059cf, 11781), (0x059d0, 6322), (0x059d1, 6320),
+    (0x059d2, 6329), (0x059d3, 6325), (0x059d4, 6317), (0x059d6, 11789), (0x059d8, 6681), (0x059d9, 19227), (0x059da, 6688),
+    (0x059db, 12136), (0x059dc, 6680), (0x059dd, 12128), (0x059de, 12124), (0x059e0, 12140), (0x059e1, 12123), (0x059e3, 6683),
+    (0x059e4, 12133), (0x059e5, 6686), (0x059e6, 6689), (0x059e8, 6684), (0x059e9, 12137), (0x059ea, 6687), (0x059eb, 3666),
+    (0x059ec, 7087), (0x059ed, 12143), (0x059ee, 12125), (0x059ef, 3631), (0x059f0, 3239), (0x059f1, 12127), (0x059f2, 12134),
+    (0x059f3, 12138), (0x059f4, 12142), (0x059f5, 12139), (0x059f6, 12132), (0x059f7, 12135), (0x059f8, 4417), (0x059f9, 19234),
+    (0x059fa, 12129), (0x059fb, 6691), (0x059fc, 12131), (0x059fd, 12130), (0x059fe, 12141), (0x059ff, 6682), (0x05a00, 12126),
+    (0x05a01, 6690), (0x05a02, 3202), (0x05a03, 6685), (0x05a09, 7093), (0x05a0a, 12594), (0x05a0b, 3456), (0x05a0c, 7092),
+    (0x05a0d, 3115), (0x05a0f, 12592), (0x05a11, 7081), (0x05a12, 2816), (0x05a13, 7086), (0x05a15, 12591), (0x05a16, 12588),
+    (0x05a17, 12593), (0x05a18, 7082), (0x05a19, 12587), (0x05a1a, 1826), (0x05a1b, 7085), (0x05a1c, 7083), (0x05a1e, 12595),
+    (0x05a1f, 7084), (0x05a20, 7088), (0x05a21, 2824), (0x05a23, 7089), (0x05a24, 19218), (0x05a25, 7091), (0x05a27, 4256),
+    (0x05a29, 7090), (0x05a2a, 3118), (0x05a2b, 3639), (0x05a2c, 2811), (0x05a2d, 12589), (0x05a2e, 12590), (0x05a33, 12596),
+    (0x05a35, 13145), (0x05a36, 7530), (0x05a37, 13755), (0x05a38, 13144), (0x05a39, 13163), (0x05a3c, 7536), (0x05a3d, 2995),
+    (0x05a3e, 13161), (0x05a40, 7535), (0x05a41, 7531), (0x05a42, 13170), (0x05a43, 13154), (0x05a44, 13157), (0x05a45, 2555),
+    (0x05a46, 7539), (0x05a47, 13167), (0x05a48, 13159), (0x05a49, 7532), (0x05a4a, 7540), (0x05a4c, 13164), (0x05a4d, 13162),
+    (0x05a50, 13147), (0x05a51, 13168), (0x05a52, 13156), (0x05a53, 13151), (0x05a54, 1626), (0x05a55, 13141), (0x05a56, 13169),
+    (0x05a57, 13153), (0x05a58, 13140), (0x05a59, 3111), (0x05a5a, 7538), (0x05a5b, 13158), (0x05a5c, 13171), (0x05a5d, 13155),
+    (0x05a5e, 13143), (0x05a5f, 13148), (0x05a60, 13139), (0x05a61, 3626), (0x05a62, 7537), (0x05a63, 19237), (0x05a64, 13152),
+    (0x05a65, 13149), (0x05a66, 7533), (0x05a67, 13142), (0x05a68, 2718), (0x05a69, 13166), (0x05a6a, 7534), (0x05a6b, 2863),
+    (0x05a6c, 13150), (0x05a6d, 13146), (0x05a6e, 3638), (0x05a70, 13165), (0x05a71, 3627), (0x05a77, 8007), (0x05a78, 13748),
+    (0x05a79, 3544), (0x05a7a, 13745), (0x05a7b, 13762), (0x05a7c, 13750), (0x05a7d, 13763), (0x05a7e, 2814), (0x05a7f, 8009),
+    (0x05a81, 2826), (0x05a82, 3624), (0x05a83, 13759), (0x05a84, 13756), (0x05a86, 3190), (0x05a88, 19609), (0x05a8a, 13757),
+    (0x05a8b, 13760), (0x05a8c, 13764), (0x05a8e, 13160), (0x05a8f, 13766), (0x05a90, 14451), (0x05a91, 19563), (0x05a92, 8010),
+    (0x05a93, 13767), (0x05a94, 13743), (0x05a95, 13753), (0x05a96, 19242), (0x05a97, 13758), (0x05a99, 2806), (0x05a9a, 8008),
+    (0x05a9b, 8011), (0x05a9c, 13765), (0x05a9d, 13768), (0x05a9e, 13747), (0x05a9f, 13744), (0x05aa0, 19362), (0x05aa1, 3539),
+    (0x05aa2, 13746), (0x05aa4, 989), (0x05aa5, 13751), (0x05aa6, 13749), (0x05aa7, 8012), (0x05aa9, 13761), (0x05aab, 19236),
+    (0x05aac, 13752), (0x05aae, 13754), (0x05aaf, 13742), (0x05ab0, 14439), (0x05ab1, 14437), (0x05ab2, 8480), (0x05ab3, 8478),
+    (0x05ab4, 14447), (0x05ab5, 14438), (0x05ab6, 14448), (0x05ab7, 14444), (0x05ab8, 14436), (0x05ab9, 14450), (0x05aba, 14435),
+    (0x05abb, 14442), (0x05abc, 8477), (0x05abd, 8476), (0x05abe, 8475), (0x05abf, 14440), (0x05ac0, 14445), (0x05ac1, 8472),
+    (0x05ac2, 8479), (0x05ac3, 2424), (0x05ac4, 14433), (0x05ac6, 14443), (0x05ac7, 14432), (0x05ac8, 14441), (0x05ac9, 8473),
+    (0x05aca, 14446), (0x05acb, 14434), (0x05acc, 8474), (0x05acd, 14449), (0x05ace, 3455), (0x05acf, 2815), (0x05ad1, 992),
+    (0x05ad3, 19244), (0x05ad5, 15118), (0x05ad6, 8920), (0x05ad7, 8919), (0x05ad8, 8921), (0x05ad9, 15130), (0x05ada, 15120),
+    (0x05adb, 15126), (0x05adc, 15115), (0x05add, 15129), (0x05ade, 15128), (0x05adf, 15132), (0x05ae0, 15125), (0x05ae1, 8916),
+    (0x05ae2, 15124), (0x05ae3, 8922), (0x05ae4, 3195), (0x05ae5, 15117), (0x05ae6, 8917), (0x05ae8, 15131), (0x05ae9, 8918),
+    (0x05aea, 15119), (0x05aeb, 15122), (0x05aec, 15127), (0x05aed, 15121), (0x05aee, 15116), (0x05af0, 4681), (0x05af2, 2254),
+    (0x05af3, 15123), (0x05af4, 15721), (0x05af5, 9296), (0x05af6, 15724), (0x05af7, 15723), (0x05af8, 15726), (0x05af9, 15728),
+    (0x05afa, 18962), (0x05afb, 9294), (0x05afd, 15722), (0x05afe, 3347), (0x05aff, 15720), (0x05b01, 15729), (0x05b02, 15727),
+    (0x05b03, 15725), (0x05b05, 15731), (0x05b07, 15730), (0x05b08, 9298), (0x05b09, 9293), (0x05b0b, 9295), (0x05b0c, 9297),
+    (0x05b0d, 4636), (0x05b0f, 15732), (0x05b10, 16351), (0x05b11, 3308), (0x05b13, 16350), (0x05b14, 16349), (0x05b15, 1801),
+    (0x05b16, 16352), (0x05b17, 16345), (0x05b19, 16346), (0x05b1a, 16354), (0x05b1b, 16347), (0x05b1d, 9676), (0x05b1e, 16356),
+    (0x05b1f, 5019), (0x05b20, 16355), (0x05b21, 16348), (0x05b23, 16910), (0x05b24, 9961), (0x05b25, 16908), (0x05b26, 16913),
+    (0x05b27, 16912), (0x05b28, 16353), (0x05b2a, 9960), (0x05b2b, 3193), (0x05b2c, 16911), (0x05b2d, 16907), (0x05b2e, 16915),
+    (0x05b2f, 16914), (0x05b30, 9959), (0x05b32, 16909), (0x05b34, 9677), (0x05b38, 10225), (0x05b3c, 17365), (0x05b3d, 17736),
+    (0x05b3e, 17737), (0x05b3f, 17738), (0x05b40, 10547), (0x05b41, 2475), (0x05b43, 10548), (0x05b44, 2865), (0x05b45, 18058),
+    (0x05b46, 19691), (0x05b47, 18314), (0x05b48, 18313), (0x05b4a, 2480), (0x05b4b, 18490), (0x05b4c, 18491), (0x05b4d, 18641),
+    (0x05b4e, 18752), (0x05b4f, 2846), (0x05b50, 5539), (0x05b51, 5540), (0x05b53, 5541), (0x05b54, 5610), (0x05b55, 5719),
+    (0x05b56, 11417), (0x05b57, 5859), (0x05b58, 5860), (0x05b5a, 6051), (0x05b5b, 6052), (0x05b5c, 6050), (0x05b5d, 6049),
+    (0x05b5f, 6331), (0x05b62, 11796), (0x05b63, 6333), (0x05b64, 6332), (0x05b65, 11797), (0x05b66, 1289), (0x05b68, 1652),
+    (0x05b69, 6692), (0x05b6b, 7094), (0x05b6c, 12597), (0x05b6d, 4490), (0x05b6e, 13173), (0x05b70, 7541), (0x05b71, 8014),
+    (0x05b72, 13172), (0x05b73, 8013), (0x05b74, 2049), (0x05b75, 8923), (0x05b76, 19253), (0x05b77, 15133), (0x05b78, 9678),
+    (0x05b7a, 9962), (0x05b7b, 16916), (0x05b7c, 19255), (0x05b7d, 10549), (0x05b7f, 10724), (0x05b80, 10938), (0x05b81, 11354),
+    (0x05b82, 19054), (0x05b83, 5720), (0x05b84, 11355), (0x05b85, 5863), (0x05b87, 5861), (0x05b88, 5862), (0x05b89, 5864),
+    (0x05b8b, 6054), (0x05b8c, 6053), (0x05b8e, 11551), (0x05b8f, 6055), (0x05b90, 4592), (0x05b92, 11552), (0x05b93, 11798),
+    (0x05b95, 11799), (0x05b96, 1846), (0x05b97, 6334), (0x05b98, 6336), (0x05b99, 6338), (0x05b9a, 6335), (0x05b9b, 6339),
+    (0x05b9c, 6337), (0x05b9d, 19266), (0x05b9e, 1290), (0x05b9f, 1291), (0x05ba2, 6696), (0x05ba3, 6693), (0x05ba4, 6695),
+    (0x05ba5, 6697), (0x05ba6, 6694), (0x05ba7, 12598), (0x05ba8, 12144), (0x05baa, 19372), (0x05bac, 12600), (0x05bad, 12599),
+    (0x05bae, 7100), (0x05bb0, 7096), (0x05bb3, 7097), (0x05bb4, 7099), (0x05bb5, 7101), (0x05bb6, 7098), (0x05bb7, 993),
+    (0x05bb8, 7103), (0x05bb9, 7102), (0x05bbf, 7546), (0x05bc0, 13175), (0x05bc1, 13174), (0x05bc2, 7545), (0x05bc3, 4753),
+    (0x05bc4, 7544), (0x05bc5, 7543), (0x05bc6, 7547), (0x05bc7, 7542), (0x05bca, 13774), (0x05bcb, 13771), (0x05bcc, 8016),
+    (0x05bcd, 13770), (0x05bce, 13775), (0x05bd0, 8018), (0x05bd1, 13773), (0x05bd2, 8015), (0x05bd3, 8017), (0x05bd4, 13772),
+    (0x05bd5, 19260), (0x05bd6, 14452), (0x05bd7, 19701), (0x05bd8, 14453), (0x05bd9, 14454), (0x05bdb, 987), (0x05bde, 8924),
+    (0x05bdf, 8932), (0x05be0, 15134), (0x05be1, 8926), (0x05be2, 8930), (0x05be3, 15135), (0x05be4, 8931), (0x05be5, 8927),
+    (0x05be6, 8928), (0x05be7, 8925), (0x05be8, 8929), (0x05be9, 9301), (0x05bea, 13769), (0x05beb, 9302), (0x05bec, 9300),
+    (0x05bee, 9299), (0x05bef, 16357), (0x05bf0, 9679), (0x05bf1, 16917), (0x05bf2, 16918), (0x05bf3, 19265), (0x05bf5, 10398),
+    (0x05bf6, 10550), (0x05bf8, 5542), (0x05bfa, 5865), (0x05bff, 19207), (0x05c01, 6698), (0x05c03, 12601), (0x05c04, 7104),
+    (0x05c05, 19268), (0x05c07, 7550), (0x05c08, 7549), (0x05c09, 7548), (0x05c0a, 8019), (0x05c0b, 8020), (0x05c0c, 13776),
+    (0x05c0d, 8933), (0x05c0e, 9680), (0x05c0f, 5543), (0x05c10, 11332), (0x05c11, 5611), (0x05c12, 11356), (0x05c13, 19270),
+    (0x05c14, 19272), (0x05c15, 11418), (0x05c16, 5866), (0x05c1a, 6340), (0x05c1c, 1599), (0x05c1e, 2838), (0x05c1f, 14455),
+    (0x05c20, 4053), (0x05c22, 5544), (0x05c23, 1673), (0x05c24, 5612), (0x05c25, 11419), (0x05c28, 11553), (0x05c2a, 11554),
+    (0x05c2c, 6056), (0x05c30, 13777), (0x05c31, 8021), (0x05c33, 14456), (0x05c37, 9963), (0x05c38, 5545), (0x05c39, 5565),
+    (0x05c3a, 5613), (0x05c3b, 11357), (0x05c3c, 5721), (0x05c3e, 6060), (0x05c3f, 6059), (0x05c40, 6057), (0x05c41, 6058),
+    (0x05c44, 11800), (0x05c45, 6342), (0x05c46, 6343), (0x05c47, 11801), (0x05c48, 6341), (0x05c49, 19275), (0x05c4a, 4813),
+    (0x05c4b, 6702), (0x05c4c, 12145), (0x05c4d, 6701), (0x05c4e, 6699), (0x05c4f, 6700), (0x05c50, 7107), (0x05c51, 7105),
+    (0x05c53, 4714), (0x05c54, 12603), (0x05c55, 7106), (0x05c56, 12602), (0x05c58, 7095), (0x05c59, 13176), (0x05c5c, 7552),
+    (0x05c5d, 7553), (0x05c5e, 3750), (0x05c60, 7551), (0x05c62, 8934), (0x05c63, 15136), (0x05c64, 9303), (0x05c65, 9304),
+    (0x05c67, 15733), (0x05c68, 9964), (0x05c69, 17366), (0x05c6a, 17367), (0x05c6c, 10642), (0x05c6d, 18753), (0x05c6e, 11315),
+    (0x05c6f, 5614), (0x05c71, 5546), (0x05c73, 11359), (0x05c74, 11358), (0x05c78, 1862), (0x05c79, 5867), (0x05c7a, 11421),
+    (0x05c7b, 11422), (0x05c7c, 11420), (0x05c7e, 11423), (0x05c85, 19277), (0x05c86, 11562), (0x05c88, 11557), (0x05c89, 11559),
+    (0x05c8a, 11561), (0x05c8b, 11558), (0x05c8c, 6064), (0x05c8d, 11555), (0x05c8f, 11556), (0x05c90, 6061), (0x05c91, 6062),
+    (0x05c92, 11560), (0x05c93, 11563), (0x05c94, 6063), (0x05c95, 11564), (0x05c99, 2028), (0x05c9a, 1292), (0x05c9c, 19612),
+    (0x05c9d, 11815), (0x05c9e, 2839), (0x05c9f, 11809), (0x05ca0, 11804), (0x05ca1, 6345), (0x05ca2, 11812), (0x05ca3, 11810),
+    (0x05ca4, 11803), (0x05ca5, 11816), (0x05ca6, 11819), (0x05ca7, 11814), (0x05ca8, 11807), (0x05ca9, 6347), (0x05caa, 11813),
+    (0x05cab, 6348), (0x05cac, 11808), (0x05cad, 11811), (0x05cae, 11802), (0x05caf, 11806), (0x05cb0, 11818), (0x05cb1, 6349),
+    (0x05cb3, 6350), (0x05cb5, 11805), (0x05cb6, 11817), (0x05cb7, 6344), (0x05cb8, 6346), (0x05cba, 19287), (0x05cc1, 5011),
+    (0x05cc2, 3651), (0x05cc6, 12162), (0x05cc7, 12155), (0x05cc8, 12161), (0x05cc9, 12154), (0x05cca, 12156), (0x05ccb, 12150),
+    (0x05ccc, 12148), (0x05cce, 12163), (0x05ccf, 12160), (0x05cd0, 12146), (0x05cd1, 4734), (0x05cd2, 6704), (0x05cd3, 12158),
+    (0x05cd4, 12159), (0x05cd5, 1859), (0x05cd6, 12157), (0x05cd7, 12149), (0x05cd8, 12147), (0x05cd9, 6703), (0x05cda, 12153),
+    (0x05cdb, 12151), (0x05cde, 12152), (0x05cdf, 12164), (0x05ce5, 1935), (0x05ce8, 7112), (0x05ce9, 19278), (0x05cea, 7111),
+    (0x05cec, 12604), (0x05ced, 7108), (0x05cee, 12606), (0x05cef, 19279), (0x05cf0, 7113), (0x05cf1, 12607), (0x05cf4, 7116),
+    (0x05cf5, 1735), (0x05cf6, 7114), (0x05cf7, 12608), (0x05cf8, 12165), (0x05cf9, 12610), (0x05cfb, 7110), (0x05cfc, 994),
+    (0x05cfd, 7109), (0x05cff, 12605), (0x05d00, 12609), (0x05d01, 7115), (0x05d06, 7555), (0x05d07, 7554), (0x05d0b, 13178),
+    (0x05d0c, 13182), (0x05d0d, 13184), (0x05d0e, 7556), (0x05d0f, 13187), (0x05d10, 19283), (0x05d11, 7560), (0x05d12, 13189),
+    (0x05d14, 7562), (0x05d15, 1954), (0x05d16, 7558), (0x05d17, 7566), (0x05d18, 19284), (0x05d19, 7563), (0x05d1a, 13180),
+    (0x05d1b, 7557), (0x05d1d, 13179), (0x05d1e, 13177), (0x05d1f, 13191), (0x05d20, 13181), (0x05d22, 7559), (0x05d23, 13190),
+    (0x05d24, 7564), (0x05d25, 13186), (0x05d26, 13185), (0x05d27, 7565), (0x05d28, 13183), (0x05d29, 7561), (0x05d2c, 4292),
+    (0x05d2e, 13192), (0x05d2f, 3667), (0x05d30, 13188), (0x05d31, 13792), (0x05d32, 13799), (0x05d33, 13788), (0x05d34, 8024),
+    (0x05d35, 13784), (0x05d36, 13800), (0x05d37, 13778), (0x05d38, 13797), (0x05d39, 13795), (0x05d3a, 13789), (0x05d3c, 13798),
+    (0x05d3d, 13791), (0x05d3e, 1916), (0x05d3f, 13783), (0x05d40, 13801), (0x05d41, 13781), (0x05d42, 13794), (0x05d43, 13779),
+    (0x05d45, 13802), (0x05d46, 19285), (0x05d47, 8025), (0x05d48, 1917), (0x05d49, 13796), (0x05d4a, 14459), (0x05d4b, 13782),
+    (0x05d4c, 8022), (0x05d4e, 13786), (0x05d50, 8023), (0x05d51, 13785), (0x05d52, 13790), (0x05d55, 13787), (0x05d56, 1918),
+    (0x05d57, 4930), (0x05d59, 13793), (0x05d5b, 4239), (0x05d5e, 14463), (0x05d62, 14466), (0x05d63, 14458), (0x05d65, 14460),
+    (0x05d67, 14465), (0x05d68, 14464), (0x05d69, 8481), (0x05d6b, 13780), (0x05d6c, 14462), (0x05d6f, 8482), (0x05d70, 3686),
+    (0x05d71, 14457), (0x05d72, 14461), (0x05d74, 4689), (0x05d77, 15143), (0x05d78, 1865), (0x05d79, 15150), (0x05d7a, 15141),
+    (0x05d7b, 1841), (0x05d7c, 15148), (0x05d7d, 15139), (0x05d7e, 15147), (0x05d7f, 15151), (0x05d80, 15138), (0x05d81, 15142),
+    (0x05d82, 15137), (0x05d84, 8935), (0x05d85, 4376), (0x05d86, 15140), (0x05d87, 8936), (0x05d88, 15146), (0x05d89, 15145),
+    (0x05d8a, 15144), (0x05d8b, 19280), (0x05d8d, 15149), (0x05d8e, 4727), (0x05d92, 15737), (0x05d93, 15739), (0x05d94, 9306),
+    (0x05d95, 15740), (0x05d97, 15735), (0x05d99, 15734), (0x05d9a, 15744), (0x05d9c, 15742), (0x05d9d, 9305), (0x05d9e, 15745),
+    (0x05d9f, 15736), (0x05da0, 15741), (0x05da1, 15743), (0x05da2, 15738), (0x05da4, 1921), (0x05da7, 16361), (0x05da8, 16366),
+    (0x05da9, 16360), (0x05daa, 16365), (0x05dab, 4382), (0x05dac, 16358), (0x05dad, 16368), (0x05dae, 16364), (0x05daf, 16369),
+    (0x05db0, 16363), (0x05db1, 16359), (0x05db2, 16367), (0x05db4, 16370), (0x05db5, 16362), (0x05db6, 19335), (0x05db7, 16919),
+    (0x05db8, 9968), (0x05db9, 1922), (0x05dba, 9966), (0x05dbc, 9965), (0x05dbd, 9967), (0x05dc0, 17368), (0x05dc1, 3317),
+    (0x05dc2, 17634), (0x05dc3, 17739), (0x05dc6, 18059), (0x05dc7, 18060), (0x05dc9, 10551), (0x05dcb, 18315), (0x05dcd, 10643),
+    (0x05dcf, 18316), (0x05dd1, 18493), (0x05dd2, 10726), (0x05dd4, 10725), (0x05dd5, 18492), (0x05dd6, 10779), (0x05dd7, 19288),
+    (0x05dd8, 18642), (0x05ddb, 10939), (0x05ddd, 5547), (0x05dde, 5868), (0x05ddf, 11424), (0x05de0, 11565), (0x05de1, 6206),
+    (0x05de2, 7567), (0x05de5, 5548), (0x05de6, 5724), (0x05de7, 5723), (0x05de8, 5722), (0x05deb, 6065), (0x05dee, 7117),
+    (0x05df0, 14467), (0x05df1, 5549), (0x05df2, 5550), (0x05df3, 5551), (0x05df4, 5615), (0x05df5, 4818), (0x05df7, 6705),
+    (0x05df9, 12166), (0x05dfd, 8026), (0x05dfe, 5552), (0x05dff, 11333), (0x05e02, 5725), (0x05e03, 5726), (0x05e04, 11360),
+    (0x05e06, 5869), (0x05e09, 19308), (0x05e0a, 11566), (0x05e0b, 4055), (0x05e0c, 6066), (0x05e0e, 11567), (0x05e11, 6356),
+    (0x05e12, 4763), (0x05e14, 11821), (0x05e15, 6354), (0x05e16, 6353), (0x05e17, 11820), (0x05e18, 6351), (0x05e19, 11822),
+    (0x05e1a, 6352), (0x05e1b, 6355), (0x05e1d, 6706), (0x05e1f, 6708), (0x05e20, 12170), (0x05e21, 12167), (0x05e22, 12168),
+    (0x05e23, 12169), (0x05e24, 12171), (0x05e25, 6707), (0x05e28, 12612), (0x05e29, 12611), (0x05e2b, 7119), (0x05e2d, 7118),
+    (0x05e2e, 19297), (0x05e33, 7570), (0x05e34, 13194), (0x05e36, 7569), (0x05e37, 7571), (0x05e38, 7568), (0x05e3d, 8028),
+    (0x05e3e, 13193), (0x05e40, 8029), (0x05e41, 13804), (0x05e42, 1925), (0x05e43, 8030), (0x05e44, 13803), (0x05e45, 8027),
+    (0x05e48, 2841), (0x05e4a, 14470), (0x05e4b, 14472), (0x05e4c, 8483), (0x05e4d, 14471), (0x05e4e, 14469), (0x05e4f, 14468),
+    (0x05e53, 15154), (0x05e54, 8941), (0x05e55, 8939), (0x05e57, 8940), (0x05e58, 15152), (0x05e59, 15153), (0x05e5b, 8937),
+    (0x05e5c, 15749), (0x05e5d, 15747), (0x05e5e, 2840), (0x05e5f, 9308), (0x05e60, 15748), (0x05e61, 9309), (0x05e62, 9307),
+    (0x05e63, 8938), (0x05e66, 16373), (0x05e67, 16371), (0x05e68, 16372), (0x05e69, 15746), (0x05e6a, 16921), (0x05e6b, 9969),
+    (0x05e6c, 16920), (0x05e6d, 17369), (0x05e6e, 17370), (0x05e6f, 16374), (0x05e70, 17740), (0x05e72, 5553), (0x05e73, 5727),
+    (0x05e74, 5871), (0x05e75, 11425), (0x05e76, 5870), (0x05e78, 6357), (0x05e79, 8484), (0x05e7a, 19300), (0x05e7b, 5616),
+    (0x05e7c, 5728), (0x05e7d, 6709), (0x05e7e, 8031), (0x05e7f, 10941), (0x05e80, 11361), (0x05e82, 11362), (0x05e83, 19251),
+    (0x05e84, 11426), (0x05e86, 1293), (0x05e87, 6068), (0x05e88, 11571), (0x05e89, 11569), (0x05e8a, 6069), (0x05e8b, 11568),
+    (0x05e8c, 11570), (0x05e8d, 11572), (0x05e8f, 6067), (0x05e92, 1785), (0x05e95, 6361), (0x05e96, 6362), (0x05e97, 6359),
+    (0x05e99, 1786), (0x05e9a, 6358), (0x05e9b, 12175), (0x05e9c, 6360), (0x05ea0, 6710), (0x05ea2, 12174), (0x05ea3, 12176),
+    (0x05ea4, 12173), (0x05ea5, 12177), (0x05ea6, 6711), (0x05ea7, 7122), (0x05ea8, 12613), (0x05eaa, 12615), (0x05eab, 7120),
+    (0x05eac, 12616), (0x05ead, 7121), (0x05eae, 12614), (0x05eb0, 12172), (0x05eb1, 13195), (0x05eb2, 13198), (0x05eb3, 13199),
+    (0x05eb4, 13196), (0x05eb5, 7575), (0x05eb6, 7574), (0x05eb7, 7572), (0x05eb8, 7573), (0x05eb9, 13197), (0x05ebd, 1926),
+    (0x05ebe, 7576), (0x05ec1, 8033), (0x05ec2, 8034), (0x05ec4, 8035), (0x05ec5, 14473), (0x05ec6, 14475), (0x05ec7, 14477),
+    (0x05ec8, 8486), (0x05ec9, 8485), (0x05eca, 8032), (0x05ecb, 14476), (0x05ecc, 14474), (0x05ecd, 2845), (0x05ece, 15158),
+    (0x05ed0, 4549), (0x05ed1, 15156), (0x05ed2, 15162), (0x05ed3, 8942), (0x05ed4, 15163), (0x05ed5, 15160), (0x05ed6, 8943),
+    (0x05ed7, 15157), (0x05ed8, 15155), (0x05ed9, 15161), (0x05eda, 9311), (0x05edb, 15751), (0x05edc, 15159), (0x05edd, 9313),
+    (0x05ede, 15752), (0x05edf, 9312), (0x05ee0, 9315), (0x05ee1, 15753), (0x05ee2, 9310), (0x05ee3, 9314), (0x05ee5, 16379),
+    (0x05ee6, 16377), (0x05ee7, 16376), (0x05ee8, 16378), (0x05ee9, 16375), (0x05eec, 10400), (0x05eee, 18061), (0x05eef, 18062),
+    (0x05ef1, 18317), (0x05ef2, 18494), (0x05ef3, 10854), (0x05ef4, 19309), (0x05ef6, 6363), (0x05ef7, 6070), (0x05ef8, 2380),
+    (0x05ef9, 19311), (0x05efa, 6712), (0x05efb, 19312), (0x05efc, 19314), (0x05efe, 5554), (0x05eff, 5617), (0x05f01, 5729),
+    (0x05f02, 11427), (0x05f04, 6071), (0x05f05, 11573), (0x05f07, 12178), (0x05f08, 6713), (0x05f0a, 8944), (0x05f0b, 5555),
+    (0x05f0c, 2859), (0x05f0d, 19317), (0x05f0e, 2860), (0x05f0f, 5872), (0x05f12, 8487), (0x05f13, 5556), (0x05f14, 5618),
+    (0x05f15, 5619), (0x05f17, 5731), (0x05f18, 5730), (0x05f1a, 11428), (0x05f1b, 5873), (0x05f1d, 11574), (0x05f1f, 6072),
+    (0x05f22, 11824), (0x05f23, 11825), (0x05f24, 11826), (0x05f25, 1927), (0x05f26, 6364), (0x05f27, 6365), (0x05f28, 11823),
+    (0x05f29, 6366), (0x05f2d, 6714), (0x05f2e, 12179), (0x05f30, 12618), (0x05f31, 7123), (0x05f33, 12617), (0x05f35, 7577),
+    (0x05f36, 13200), (0x05f37, 7578), (0x05f38, 13201), (0x05f3a, 19323), (0x05f3b, 1883), (0x05f3c, 8036), (0x05f40, 14478),
+    (0x05f43, 15165), (0x05f44, 15164), (0x05f46, 8945), (0x05f48, 9316), (0x05f49, 15754), (0x05f4a, 9681), (0x05f4b, 16380),
+    (0x05f4c, 9970), (0x05f4d, 3226), (0x05f4e, 10727), (0x05f4f, 18644), (0x05f50, 10943), (0x05f51, 1674), (0x05f54, 11827),
+    (0x05f56, 12180), (0x05f57, 7579), (0x05f58, 13805), (0x05f59, 8488), (0x05f5c, 3669), (0x05f5d, 10226), (0x05f61, 10944),
+    (0x05f62, 6074), (0x05f63, 19328), (0x05f64, 6073), (0x05f65, 6715), (0x05f67, 12619), (0x05f69, 7581), (0x05f6a, 7853),
+    (0x05f6b, 7582), (0x05f6c, 7580), (0x05f6d, 8037), (0x05f6f, 15166), (0x05f70, 8946), (0x05f71, 9317), (0x05f72, 19331),
+    (0x05f73, 11316), (0x05f74, 11429), (0x05f76, 11576), (0x05f77, 6075), (0x05f78, 11575), (0x05f79, 6076), (0x05f7b, 19074),
+    (0x05f7c, 6370), (0x05f7d, 11830), (0x05f7e, 11829), (0x05f7f, 6369), (0x05f80, 6367), (0x05f81, 6368), (0x05f82, 11828),
+    (0x05f83, 1928), (0x05f85, 6717), (0x05f86, 12181), (0x05f87, 6720), (0x05f88, 6716), (0x05f89, 6722), (0x05f8a, 6718),
+    (0x05f8b, 6719), (0x05f8c, 6721), (0x05f90, 7126), (0x05f91, 7125), (0x05f92, 7124), (0x05f96, 13203), (0x05f97, 7583),
+    (0x05f98, 7586), (0x05f99, 7584), (0x05f9b, 13202), (0x05f9c, 7589), (0x05f9e, 7585), (0x05f9f, 13204), (0x05fa0, 7588),
+    (0x05fa1, 7587), (0x05fa4, 19030), (0x05fa5, 13807), (0x05fa6, 13806), (0x05fa7, 19334), (0x05fa8, 8040), (0x05fa9, 8038),
+    (0x05faa, 8039), (0x05fab, 13808), (0x05fac, 8489), (0x05fad, 14480), (0x05fae, 8490), (0x05faf, 14479), (0x05fb1, 4130),
+    (0x05fb2, 15755), (0x05fb5, 9319), (0x05fb6, 15167), (0x05fb7, 9318), (0x05fb9, 8947), (0x05fba, 4884), (0x05fbb, 16923),
+    (0x05fbc, 16381), (0x05fbd, 9971), (0x05fbe, 16922), (0x05fbf, 17741), (0x05fc0, 18063), (0x05fc1, 18064), (0x05fc2, 1787),
+    (0x05fc3, 5620), (0x05fc4, 1675), (0x05fc5, 5732), (0x05fc9, 11363), (0x05fcc, 6078), (0x05fcd, 6080), (0x05fcf, 11432),
+    (0x05fd0, 11579), (0x05fd1, 11578), (0x05fd2, 11577), (0x05fd4, 11431), (0x05fd5, 11430), (0x05fd6, 5875), (0x05fd7, 6079),
+    (0x05fd8, 6077), (0x05fd9, 5874), (0x05fdb, 2872), (0x05fdd, 6371), (0x05fde, 11831), (0x05fdf, 19426), (0x05fe0, 6372),
+    (0x05fe1, 11584), (0x05fe3, 11586), (0x05fe4, 11585), (0x05fe5, 11832), (0x05fe8, 11581), (0x05fea, 6084), (0x05feb, 6082),
+    (0x05fed, 11580), (0x05fee, 11582), (0x05fef, 11588), (0x05ff1, 6081), (0x05ff3, 11583), (0x05ff4, 11592), (0x05ff5, 6374),
+    (0x05ff7, 11589), (0x05ff8, 6083), (0x05ffa, 11587), (0x05ffb, 11590), (0x05ffd, 6373), (0x05fff, 6375), (0x06000, 11591),
+    (0x06009, 11852), (0x0600a, 11839), (0x0600b, 11837), (0x0600c, 11851), (0x0600d, 11846), (0x0600e, 6727), (0x0600f, 6376),
+    (0x06010, 11847), (0x06011, 11850), (0x06012, 6723), (0x06013, 11849), (0x06014, 6377), (0x06015, 6382), (0x06016, 6380),
+    (0x06017, 11840), (0x06019, 11835), (0x0601a, 11842), (0x0601b, 6387), (0x0601c, 11853), (0x0601d, 6724), (0x0601e, 11843),
+    (0x06020, 6725), (0x06021, 6383), (0x06022, 11845), (0x06023, 19382), (0x06024, 12196), (0x06025, 6726), (0x06026, 11834),
+    (0x06027, 6384), (0x06028, 6728), (0x06029, 6385), (0x0602a, 6381), (0x0602b, 6386), (0x0602c, 11844), (0x0602d, 11833),
+    (0x0602e, 11848), (0x0602f, 6378), (0x06031, 19344), (0x06032, 11836), (0x06033, 11841), (0x06034, 11838), (0x06035, 6379),
+    (0x06037, 12182), (0x06039, 12183), (0x0603b, 1294), (0x06040, 12193), (0x06041, 12623), (0x06042, 12194), (0x06043, 6734),
+    (0x06044, 12197), (0x06045, 12187), (0x06046, 6733), (0x06047, 12189), (0x06049, 12190), (0x0604a, 19102), (0x0604c, 12192),
+    (0x0604d, 6729), (0x06050, 7130), (0x06052, 18960), (0x06053, 12188), (0x06054, 12184), (0x06055, 7131), (0x06058, 12198),
+    (0x06059, 7127), (0x0605a, 12621), (0x0605b, 12191), (0x0605d, 12620), (0x0605e, 12186), (0x0605f, 12195), (0x06062, 6732),
+    (0x06063, 7128), (0x06064, 6738), (0x06065, 7129), (0x06066, 12199), (0x06067, 12622), (0x06068, 6731), (0x06069, 7133),
+    (0x0606a, 6737), (0x0606b, 6736), (0x0606c, 6735), (0x0606d, 7132), (0x0606e, 12200), (0x0606f, 7134), (0x06070, 6730),
+    (0x06072, 12185), (0x06075, 2884), (0x06077, 4993), (0x0607e, 2869), (0x0607f, 7590), (0x06080, 12626), (0x06081, 12628),
+    (0x06083, 12630), (0x06084, 7135), (0x06085, 7141), (0x06086, 13207), (0x06087, 12634), (0x06088, 12625), (0x06089, 7592),
+    (0x0608a, 13205), (0x0608c, 7140), (0x0608d, 7138), (0x0608e, 12636), (0x06090, 13206), (0x06092, 12627), (0x06094, 7139),
+    (0x06095, 12631), (0x06096, 7142), (0x06097, 12633), (0x0609a, 7137), (0x0609b, 12632), (0x0609c, 12635), (0x0609d, 12629),
+    (0x0609e, 19354), (0x0609f, 7136), (0x060a0, 7593), (0x060a2, 12624), (0x060a3, 7591), (0x060a4, 4989), (0x060a7, 3023),
+    (0x060a8, 7594), (0x060b0, 13209), (0x060b1, 13218), (0x060b2, 8043), (0x060b3, 19356), (0x060b4, 7596), (0x060b5, 7601),
+    (0x060b6, 8044), (0x060b7, 13220), (0x060b8, 7608), (0x060b9, 13810), (0x060ba, 13210), (0x060bb, 7600), (0x060bc, 7603),
+    (0x060bd, 7598), (0x060be, 13208), (0x060bf, 13222), (0x060c0, 13225), (0x060c1, 13825), (0x060c3, 13223), (0x060c4, 13814),
+    (0x060c5, 7599), (0x060c6, 7606), (0x060c7, 7610), (0x060c8, 13217), (0x060c9, 13809), (0x060ca, 13221), (0x060cb, 7595),
+    (0x060cc, 13811), (0x060cd, 13224), (0x060ce, 13813), (0x060cf, 13213), (0x060d1, 8041), (0x060d3, 13211), (0x060d4, 13212),
+    (0x060d5, 7605), (0x060d7, 1933), (0x060d8, 7604), (0x060d9, 13215), (0x060da, 7609), (0x060db, 13219), (0x060dc, 7602),
+    (0x060dd, 13216), (0x060de, 19504), (0x060df, 7607), (0x060e0, 8045), (0x060e1, 8042), (0x060e2, 13812), (0x060e3, 3856),
+    (0x060e4, 13214), (0x060e6, 7597), (0x060e7, 4035), (0x060e8, 3958), (0x060e9, 4998), (0x060f0, 8050), (0x060f1, 8054),
+    (0x060f2, 13816), (0x060f3, 8495), (0x060f4, 8052), (0x060f5, 13820), (0x060f6, 8056), (0x060f7, 14481), (0x060f8, 13822),
+    (0x060f9, 8497), (0x060fa, 8048), (0x060fb, 8051), (0x060fc, 13823), (0x060fd, 4894), (0x060fe, 13824), (0x060ff, 13830),
+    (0x06100, 8058), (0x06101, 8498), (0x06103, 13826), (0x06104, 13831), (0x06105, 13819), (0x06106, 8508), (0x06107, 3371),
+    (0x06108, 8499), (0x06109, 8057), (0x0610a, 13817), (0x0610b, 13832), (0x0610c, 3454), (0x0610d, 8507), (0x0610e, 8055),
+    (0x0610f, 8492), (0x06110, 13829), (0x06112, 8059), (0x06113, 13821), (0x06114, 13815), (0x06115, 8049), (0x06116, 13818),
+    (0x06118, 13827), (0x06119, 4696), (0x0611a, 8491), (0x0611b, 8496), (0x0611c, 8046), (0x0611d, 13828), (0x0611f, 8494),
+    (0x06122, 1740), (0x06123, 8047), (0x06127, 8506), (0x06128, 15169), (0x06129, 14492), (0x0612b, 14484), (0x0612c, 15168),
+    (0x0612e, 14488), (0x0612f, 14490), (0x06130, 4772), (0x06132, 14487), (0x06134, 8505), (0x06136, 14486), (0x06137, 8509),
+    (0x0613b, 15184), (0x0613d, 1934), (0x0613e, 8504), (0x0613f, 8949), (0x06140, 14493), (0x06141, 15170), (0x06142, 19365),
+    (0x06144, 8502), (0x06145, 14485), (0x06146, 14489), (0x06147, 8948), (0x06148, 8493), (0x06149, 14482), (0x0614a, 14483),
+    (0x0614b, 8950), (0x0614c, 8501), (0x0614d, 8503), (0x0614e, 8500), (0x0614f, 14491), (0x06150, 3368), (0x06152, 15174),
+    (0x06153, 15175), (0x06154, 15180), (0x06155, 9324), (0x06156, 15187), (0x06158, 8956), (0x06159, 4913), (0x0615a, 8955),
+    (0x0615b, 15182), (0x0615c, 19383), (0x0615d, 9323), (0x0615e, 15171), (0x0615f, 8954), (0x06160, 19261), (0x06161, 15186),
+    (0x06162, 8952), (0x06163, 8953), (0x06164, 19363), (0x06165, 15183), (0x06166, 15772), (0x06167, 9321), (0x06168, 8053),
+    (0x0616a, 15185), (0x0616b, 9328), (0x0616c, 15177), (0x0616e, 9322), (0x0616f, 4919), (0x06170, 9327), (0x06171, 15172),
+    (0x06172, 15176), (0x06173, 15173), (0x06174, 15179), (0x06175, 8957), (0x06176, 9320), (0x06177, 8951), (0x06179, 15758),
+    (0x0617a, 15181), (0x0617c, 9326), (0x0617d, 4916), (0x0617e, 9329), (0x06180, 15178), (0x06181, 19368), (0x06182, 9325),
+    (0x06183, 15757), (0x06187, 19371), (0x06189, 15762), (0x0618a, 9685), (0x0618b, 15756), (0x0618c, 16393), (0x0618d, 15771),
+    (0x0618e, 9333), (0x06190, 9331), (0x06191, 9683), (0x06192, 15768), (0x06193, 15764), (0x06194, 9337), (0x06195, 4255),
+    (0x06196, 16384), (0x06198, 2883), (0x06199, 2882), (0x0619a, 9335), (0x0619b, 15763), (0x0619c, 4990), (0x0619d, 16382),
+    (0x0619f, 15767), (0x061a1, 15770), (0x061a2, 15761), (0x061a4, 9336), (0x061a7, 9330), (0x061a8, 16383), (0x061a9, 9684),
+    (0x061aa, 15769), (0x061ab, 9332), (0x061ac, 9334), (0x061ad, 15766), (0x061ae, 9338), (0x061af, 15765), (0x061b0, 15760),
+    (0x061b1, 15759), (0x061b2, 9682), (0x061b3, 15773), (0x061b4, 16386), (0x061b5, 16925), (0x061b6, 9687), (0x061b7, 1937),
+    (0x061b8, 16392), (0x061b9, 1938), (0x061ba, 16390), (0x061bc, 16926), (0x061be, 9688), (0x061bf, 16391), (0x061c0, 2878),
+    (0x061c1, 16388), (0x061c2, 9973), (0x061c3, 16924), (0x061c5, 16385), (0x061c6, 16387), (0x061c7, 9974), (0x061c8, 9690),
+    (0x061c9, 9972), (0x061ca, 9689), (0x061cb, 9976), (0x061cc, 16389), (0x061cd, 9686), (0x061cf, 1939), (0x061d0, 19378),
+    (0x061d3, 19375), (0x061d6, 17379), (0x061d8, 17371), (0x061da, 2452), (0x061de, 16932), (0x061df, 17372), (0x061e0, 16928),
+    (0x061e2, 4924), (0x061e3, 10227), (0x061e4, 16930), (0x061e5, 16929), (0x061e6, 9975), (0x061e7, 16927), (0x061e8, 16931),
+    (0x061e9, 17380), (0x061ea, 17376), (0x061eb, 17378), (0x061ed, 17373), (0x061ee, 17374), (0x061f0, 17377), (0x061f1, 17375),
+    (0x061f2, 10401), (0x061f5, 10404), (0x061f6, 10403), (0x061f7, 10402), (0x061f8, 10552), (0x061f9, 18065), (0x061fa, 10553),
+    (0x061fb, 17742), (0x061fc, 10644), (0x061fd, 18318), (0x061fe, 10645), (0x061ff, 10728), (0x06200, 10780), (0x06201, 18645),
+    (0x06203, 18646), (0x06204, 18647), (0x06207, 18928), (0x06208, 5621), (0x06209, 11364), (0x0620a, 5733), (0x0620c, 5877),
+    (0x0620d, 5878), (0x0620e, 5876), (0x06210, 5879), (0x06211, 6086), (0x06212, 6085), (0x06214, 11854), (0x06215, 6389),
+    (0x06216, 6388), (0x06219, 12637), (0x0621a, 7611), (0x0621b, 7612), (0x0621f, 8060), (0x06220, 14494), (0x06221, 8510),
+    (0x06222, 8511), (0x06223, 14496), (0x06224, 14498), (0x06225, 14497), (0x06227, 15189), (0x06229, 15188), (0x0622a, 8958),
+    (0x0622b, 15190), (0x0622c, 1941), (0x0622d, 15774), (0x0622e, 9339), (0x06230, 9691), (0x06232, 9977), (0x06233, 10228),
+    (0x06234, 9978), (0x06236, 5622), (0x06237, 1724), (0x06239, 4921), (0x0623a, 11593), (0x0623d, 11855), (0x0623e, 6391),
+    (0x0623f, 6390), (0x06240, 6392), (0x06241, 6739), (0x06242, 12201), (0x06243, 12202), (0x06246, 12638), (0x06247, 7143),
+    (0x06248, 7613), (0x06249, 8061), (0x0624a, 13833), (0x0624b, 5623), (0x0624c, 1677), (0x0624d, 5557), (0x0624e, 5624),
+    (0x06250, 11365), (0x06251, 5737), (0x06252, 5736), (0x06253, 5734), (0x06254, 5735), (0x06258, 5882), (0x06259, 11439),
+    (0x0625a, 11441), (0x0625b, 5881), (0x0625c, 11433), (0x0625e, 11434), (0x06260, 11440), (0x06261, 11436), (0x06262, 11438),
+    (0x06263, 5880), (0x06264, 11435), (0x06265, 11442), (0x06266, 11437), (0x06268, 4736), (0x0626d, 6093), (0x0626e, 6102),
+    (0x0626f, 6100), (0x06270, 11603), (0x06271, 11600), (0x06272, 11608), (0x06273, 6098), (0x06274, 11609), (0x06276, 6091),
+    (0x06277, 11606), (0x06279, 6097), (0x0627a, 11602), (0x0627b, 11601), (0x0627c, 6095), (0x0627d, 11607), (0x0627e, 6096),
+    (0x0627f, 6393), (0x06280, 6090), (0x06281, 11604), (0x06282, 4856), (0x06283, 11594), (0x06284, 6087), (0x06285, 4798),
+    (0x06286, 6106), (0x06287, 11599), (0x06288, 11605), (0x06289, 6092), (0x0628a, 6094), (0x0628c, 11595), (0x0628e, 11596),
+    (0x0628f, 11597), (0x06290, 1942), (0x06291, 6105), (0x06292, 6099), (0x06293, 6104), (0x06294, 11598), (0x06295, 6103),
+    (0x06296, 6089), (0x06297, 6088), (0x06298, 6101), (0x0629d, 4558), (0x062a4, 2904), (0x062a6, 4920), (0x062a8, 6407),
+    (0x062a9, 11867), (0x062aa, 11860), (0x062ab, 6402), (0x062ac, 6421), (0x062ad, 11856), (0x062ae, 11863), (0x062af, 11865),
+    (0x062b0, 11868), (0x062b1, 6416), (0x062b3, 11864), (0x062b4, 11857), (0x062b5, 6414), (0x062b6, 11861), (0x062b8, 11869),
+    (0x062b9, 6399), (0x062bb, 11866), (0x062bc, 6409), (0x062bd, 6408), (0x062be, 11859), (0x062bf, 6397), (0x062c2, 6398),
+    (0x062c3, 4088), (0x062c4, 6396), (0x062c5, 19387), (0x062c6, 6420), (0x062c7, 6412), (0x062c8, 6406), (0x062c9, 6394),
+    (0x062ca, 11862), (0x062cb, 6405), (0x062cc, 6395), (0x062cd, 6413), (0x062ce, 6422), (0x062cf, 12203), (0x062d0, 6410),
+    (0x062d1, 11858), (0x062d2, 6400), (0x062d3, 6403), (0x062d4, 6404), (0x062d5, 19389), (0x062d6, 6418), (0x062d7, 6419),
+    (0x062d8, 6417), (0x062d9, 6411), (0x062da, 6415), (0x062db, 6401), (0x062dc, 6740), (0x062df, 19014), (0x062e5, 1943),
+    (0x062eb, 12209), (0x062ec, 6752), (0x062ed, 6744), (0x062ee, 6746), (0x062ef, 6751), (0x062f0, 12221), (0x062f1, 6749),
+    (0x062f2, 12639), (0x062f3, 7144), (0x062f4, 6754), (0x062f5, 12206), (0x062f6, 12214), (0x062f7, 6750), (0x062f8, 12213),
+    (0x062f9, 12210), (0x062fa, 12218), (0x062fb, 12220), (0x062fc, 6743), (0x062fd, 6747), (0x062fe, 6753), (0x062ff, 7146),
+    (0x06300, 12215), (0x06301, 6745), (0x06302, 6756), (0x06303, 12208), (0x06307, 6748), (0x06308, 7145), (0x06309, 6742),
+    (0x0630b, 12205), (0x0630c, 12212), (0x0630d, 12204), (0x0630e, 12207), (0x0630f, 12211), (0x06310, 12640), (0x06311, 6755),
+    (0x06313, 12216), (0x06314, 12217), (0x06315, 12219), (0x06316, 6741), (0x06318, 1944), (0x06328, 7160), (0x06329, 12652),
+    (0x0632a, 7158), (0x0632b, 7159), (0x0632c, 12642), (0x0632d, 12658), (0x0632e, 4669), (0x0632f, 7149), (0x06331, 2900),
+    (0x06332, 13226), (0x06333, 12660), (0x06334, 12654), (0x06335, 4737), (0x06336, 12645), (0x06337, 2897), (0x06338, 12663),
+    (0x06339, 12648), (0x0633a, 7155), (0x0633b, 13249), (0x0633c, 12651), (0x0633d, 7157), (0x0633e, 7148), (0x06340, 12665),
+    (0x06341, 12653), (0x06342, 7151), (0x06343, 12646), (0x06344, 12643), (0x06345, 12644), (0x06346, 7152), (0x06347, 12659),
+    (0x06348, 12666), (0x06349, 7154), (0x0634a, 12650), (0x0634b, 12649), (0x0634c, 7162), (0x0634d, 7161), (0x0634e, 7147),
+    (0x0634f, 7153), (0x06350, 7156), (0x06351, 12662), (0x06354, 12656), (0x06355, 7150), (0x06356, 12641), (0x06357, 12664),
+    (0x06358, 12655), (0x06359, 12657), (0x0635a, 12661), (0x06364, 4927), (0x06365, 13227), (0x06367, 7621), (0x06368, 7641),
+    (0x06369, 7640), (0x0636b, 7629), (0x0636c, 19391), (0x0636d, 13245), (0x0636e, 13241), (0x0636f, 13238), (0x06370, 13256),
+    (0x06371, 7624), (0x06372, 7616), (0x06375, 13243), (0x06376, 8077), (0x06377, 7620), (0x06378, 13251), (0x06379, 1508),
+    (0x0637a, 7642), (0x0637b, 7639), (0x0637c, 13247), (0x0637d, 13230), (0x0637f, 4792), (0x06380, 7638), (0x06381, 13253),
+    (0x06382, 13229), (0x06383, 7627), (0x06384, 7631), (0x06385, 13252), (0x06387, 13239), (0x06388, 7632), (0x06389, 7626),
+    (0x0638a, 13228), (0x0638b, 19385), (0x0638c, 8063), (0x0638d, 13255), (0x0638e, 13237), (0x0638f, 7637), (0x06390, 13240),
+    (0x06391, 13254), (0x06392, 7636), (0x06394, 13834), (0x06396, 7617), (0x06397, 13235), (0x06398, 7622), (0x06399, 7633),
+    (0x0639b, 7628), (0x0639c, 13244), (0x0639d, 13234), (0x0639e, 13232), (0x0639f, 13250), (0x063a0, 7614), (0x063a1, 7634),
+    (0x063a2, 7618), (0x063a3, 8062), (0x063a4, 13248), (0x063a5, 7619), (0x063a7, 7615), (0x063a8, 7630), (0x063a9, 7625),
+    (0x063aa, 7623), (0x063ab, 13236), (0x063ac, 7635), (0x063ad, 13233), (0x063ae, 13246), (0x063af, 13242), (0x063b0, 13836),
+    (0x063b1, 13835), (0x063b9, 4565), (0x063bd, 13231), (0x063be, 13852), (0x063c0, 8065), (0x063c1, 2297), (0x063c2, 13858),
+    (0x063c3, 13841), (0x063c4, 13855), (0x063c5, 14499), (0x063c6, 8068), (0x063c7, 13859), (0x063c8, 13862), (0x063c9, 8067),
+    (0x063ca, 13844), (0x063cb, 13861), (0x063cc, 13860), (0x063cd, 8069), (0x063ce, 13837), (0x063cf, 8064), (0x063d0, 8072),
+    (0x063d1, 2901), (0x063d2, 8070), (0x063d3, 13857), (0x063d5, 13847), (0x063d6, 8074), (0x063d7, 13864), (0x063d8, 13856),
+    (0x063d9, 13865), (0x063da, 8082), (0x063db, 8080), (0x063dc, 13854), (0x063dd, 13853), (0x063de, 4493), (0x063df, 13851),
+    (0x063e0, 13845), (0x063e1, 8073), (0x063e2, 1948), (0x063e3, 8071), (0x063e4, 12647), (0x063e5, 13838), (0x063e6, 4406),
+    (0x063e7, 14529), (0x063e8, 13839), (0x063e9, 8066), (0x063ea, 8079), (0x063eb, 14501), (0x063ed, 8075), (0x063ee, 8076),
+    (0x063ef, 13840), (0x063f0, 13863), (0x063f1, 14500), (0x063f2, 13848), (0x063f3, 13843), (0x063f4, 8078), (0x063f5, 13849),
+    (0x063f6, 13846), (0x063f8, 19395), (0x063f9, 8083), (0x063fb, 1949), (0x063fc, 4564), (0x063fe, 4566), (0x06406, 8526),
+    (0x06407, 1950), (0x06409, 14504), (0x0640a, 14523), (0x0640b, 14528), (0x0640c, 14516), (0x0640d, 8522), (0x0640e, 14533),
+    (0x0640f, 8519), (0x06410, 14502), (0x06412, 14503), (0x06413, 8512), (0x06414, 8521), (0x06415, 14510), (0x06416, 8524),
+    (0x06417, 8525), (0x06418, 14511), (0x0641a, 14524), (0x0641b, 14530), (0x0641c, 8520), (0x0641e, 8514), (0x0641f, 14509),
+    (0x06420, 14505), (0x06421, 14532), (0x06422, 14514), (0x06423, 14515), (0x06424, 14506), (0x06425, 14526), (0x06426, 14517),
+    (0x06427, 14527), (0x06428, 14519), (0x0642a, 8515), (0x0642b, 15191), (0x0642c, 8518), (0x0642d, 8516), (0x0642e, 14531),
+    (0x0642f, 14522), (0x06430, 14518), (0x06432, 1568), (0x06433, 14507), (0x06434, 8968), (0x06435, 14521), (0x06436, 8523),
+    (0x06437, 14513), (0x06438, 19394), (0x06439, 14512), (0x0643a, 19408), (0x0643b, 2906), (0x0643d, 8517), (0x0643e, 8513),
+    (0x0643f, 15212), (0x06440, 14525), (0x06441, 14520), (0x06443, 14508), (0x0644b, 15207), (0x0644d, 15192), (0x0644e, 15203),
+    (0x06450, 15210), (0x06451, 8966), (0x06452, 8081), (0x06453, 15208), (0x06454, 8961), (0x06458, 8960), (0x06459, 15215),
+    (0x0645a, 1951), (0x0645b, 15193), (0x0645c, 15206), (0x0645d, 15194), (0x0645e, 15205), (0x0645f, 8964), (0x06460, 15209),
+    (0x06461, 13850), (0x06465, 15216), (0x06466, 15201), (0x06467, 8967), (0x06468, 15788), (0x06469, 9340), (0x0646b, 15214),
+    (0x0646c, 15213), (0x0646d, 8969), (0x0646e, 15775), (0x0646f, 9341), (0x06470, 15776), (0x06471, 2889), (0x06472, 15197),
+    (0x06473, 15198), (0x06474, 15195), (0x06475, 15200), (0x06476, 15196), (0x06477, 15217), (0x06478, 8963), (0x06479, 9342),
+    (0x0647a, 8965), (0x0647b, 8970), (0x0647c, 1513), (0x0647d, 15199), (0x0647f, 15211), (0x06482, 15204), (0x06485, 15779),
+    (0x06487, 8959), (0x06488, 9345), (0x06489, 16400), (0x0648a, 15784), (0x0648b, 15783), (0x0648c, 15785), (0x0648d, 1440),
+    (0x0648f, 15782), (0x06490, 9346), (0x06491, 1269), (0x06492, 9352), (0x06493, 9349), (0x06495, 9350), (0x06496, 15777),
+    (0x06497, 15780), (0x06498, 15790), (0x06499, 9358), (0x0649a, 9356), (0x0649c, 15781), (0x0649d, 13842), (0x0649e, 9343),
+    (0x0649f, 15787), (0x064a0, 15778), (0x064a2, 9359), (0x064a3, 15786), (0x064a4, 8962), (0x064a5, 9348), (0x064a6, 15202),
+    (0x064a9, 9351), (0x064ab, 9355), (0x064ac, 9357), (0x064ad, 9354), (0x064ae, 9353), (0x064af, 19739), (0x064b0, 9347),
+    (0x064b1, 15789), (0x064b2, 9344), (0x064b3, 9360), (0x064b4, 1469), (0x064b6, 2899), (0x064bb, 9695), (0x064bc, 9696),
+    (0x064bd, 16399), (0x064be, 9705), (0x064bf, 9702), (0x064c0, 1953), (0x064c1, 9693), (0x064c2, 9700), (0x064c3, 16401),
+    (0x064c4, 9698), (0x064c5, 9692), (0x064c7, 9699), (0x064c9, 16398), (0x064ca, 9980), (0x064cb, 9694), (0x064cd, 9701),
+    (0x064ce, 9979), (0x064cf, 16397), (0x064d0, 16396), (0x064d2, 9703), (0x064d3, 4715), (0x064d4, 9704), (0x064d6, 16395),
+    (0x064d7, 16394), (0x064d8, 9981), (0x064d9, 16404), (0x064da, 9697), (0x064db, 16402), (0x064dd, 1435), (0x064e0, 9982),
+    (0x064e1, 19404), (0x064e2, 9987), (0x064e3, 16935), (0x064e4, 16937), (0x064e5, 19405), (0x064e6, 9984), (0x064e7, 2173),
+    (0x064e8, 16938), (0x064e9, 16934), (0x064ea, 1479), (0x064eb, 16936), (0x064ec, 9985), (0x064ed, 9988), (0x064ef, 16933),
+    (0x064f0, 9983), (0x064f1, 9986), (0x064f2, 10230), (0x064f3, 16403), (0x064f4, 10229), (0x064f7, 10235), (0x064f8, 17384),
+    (0x064fa, 10233), (0x064fb, 10234), (0x064fc, 17387), (0x064fd, 17383), (0x064fe, 10231), (0x064ff, 17381), (0x06500, 10405),
+    (0x06501, 17385), (0x06503, 17386), (0x06504, 17382), (0x06506, 10232), (0x06507, 17743), (0x06509, 17746), (0x0650a, 1259),
+    (0x0650c, 17747), (0x0650d, 17745), (0x0650e, 17748), (0x0650f, 10406), (0x06510, 17744), (0x06511, 2971), (0x06513, 18069),
+    (0x06514, 10555), (0x06515, 18068), (0x06516, 18067), (0x06517, 18066), (0x06518, 10554), (0x06519, 10556), (0x0651b, 18319),
+    (0x0651c, 10647), (0x0651d, 10646), (0x0651e, 19384), (0x0651f, 4617), (0x06520, 18496), (0x06521, 18495), (0x06522, 18498),
+    (0x06523, 10781), (0x06524, 10729), (0x06525, 18649), (0x06526, 18497), (0x06529, 18648), (0x0652a, 10783), (0x0652b, 10782),
+    (0x0652c, 10821), (0x0652d, 18754), (0x0652e, 18824), (0x0652f, 5625), (0x06530, 4497), (0x06532, 13866), (0x06533, 16405),
+    (0x06534, 10945), (0x06535, 1678), (0x06536, 5883), (0x06537, 11610), (0x06538, 6109), (0x06539, 6107), (0x0653b, 6108),
+    (0x0653d, 11870), (0x0653e, 6423), (0x0653f, 6757), (0x06541, 12222), (0x06543, 12223), (0x06545, 6758), (0x06546, 12668),
+    (0x06548, 7163), (0x06549, 7164), (0x0654a, 12667), (0x0654d, 19410), (0x0654f, 7649), (0x06551, 7645), (0x06553, 13257),
+    (0x06554, 7652), (0x06555, 7651), (0x06556, 7644), (0x06557, 7647), (0x06558, 7650), (0x06559, 7646), (0x0655c, 13870),
+    (0x0655d, 7643), (0x0655e, 8084), (0x0655f, 19416), (0x06562, 8086), (0x06563, 8087), (0x06564, 13869), (0x06565, 13872),
+    (0x06566, 8085), (0x06567, 13867), (0x06568, 13871), (0x0656a, 13868), (0x0656b, 2907), (0x0656c, 8527), (0x0656d, 19415),
+    (0x0656f, 14534), (0x06572, 8971), (0x06573, 15218), (0x06574, 9706), (0x06575, 9361), (0x06576, 15791), (0x06577, 9362),
+    (0x06578, 9363), (0x06579, 15793), (0x0657a, 15792), (0x0657b, 15794), (0x0657c, 16407), (0x0657f, 16406), (0x06580, 16940),
+    (0x06581, 16939), (0x06582, 9989), (0x06583, 9990), (0x06584, 17749), (0x06585, 19414), (0x06586, 1958), (0x06587, 5626),
+    (0x06588, 19254), (0x06589, 1295), (0x0658b, 1789), (0x0658c, 13873), (0x06590, 8089), (0x06591, 8088), (0x06592, 14535),
+    (0x06594, 17388), (0x06595, 10648), (0x06596, 18650), (0x06597, 5627), (0x06599, 7165), (0x0659b, 7654), (0x0659c, 7653),
+    (0x0659d, 13874), (0x0659e, 13875), (0x0659f, 8528), (0x065a0, 15219), (0x065a1, 8972), (0x065a2, 16408), (0x065a4, 5628),
+    (0x065a5, 5738), (0x065a7, 6424), (0x065a8, 11871), (0x065aa, 12224), (0x065ab, 6759), (0x065ac, 7655), (0x065ae, 13876),
+    (0x065af, 8090), (0x065b0, 8529), (0x065b2, 15795), (0x065b3, 15796), (0x065b5, 19418), (0x065b6, 16941), (0x065b7, 10236),
+    (0x065b8, 18825), (0x065b9, 5629), (0x065bb, 11872), (0x065bc, 6425), (0x065bd, 6760), (0x065be, 4384), (0x065bf, 12225),
+    (0x065c1, 7166), (0x065c2, 12672), (0x065c3, 12670), (0x065c4, 12671), (0x065c5, 7167), (0x065c6, 12669), (0x065cb, 7657),
+    (0x065cc, 7658), (0x065cd, 13258), (0x065ce, 7659), (0x065cf, 7656), (0x065d0, 13877), (0x065d1, 19421), (0x065d2, 13878),
+    (0x065d3, 14536), (0x065d4, 3229), (0x065d6, 8974), (0x065d7, 8973), (0x065da, 16942), (0x065db, 17389), (0x065dd, 17751),
+    (0x065de, 17750), (0x065df, 18070), (0x065e0, 19424), (0x065e1, 11334), (0x065e2, 6761), (0x065e3, 19425), (0x065e5, 5630),
+    (0x065e6, 5739), (0x065e8, 5885), (0x065e9, 5884), (0x065ec, 5886), (0x065ed, 5887), (0x065ee, 11444), (0x065ef, 11443),
+    (0x065f0, 11611), (0x065f1, 6110), (0x065f2, 11614), (0x065f3, 11613), (0x065f4, 11612), (0x065f5, 11615), (0x065fa, 6426),
+    (0x065fb, 11878), (0x065fc, 11874), (0x065fd, 11883), (0x065ff, 1960), (0x06600, 6433), (0x06602, 6431), (0x06603, 11879),
+    (0x06604, 11875), (0x06605, 11882), (0x06606, 6430), (0x06607, 6437), (0x06608, 11877), (0x06609, 11873), (0x0660a, 6436),
+    (0x0660b, 11880), (0x0660c, 6429), (0x0660d, 11881), (0x0660e, 6432), (0x0660f, 6434), (0x06610, 11885), (0x06611, 11884),
+    (0x06612, 11876), (0x06613, 6428), (0x06614, 6427), (0x06615, 6435), (0x06618, 19428), (0x0661c, 12230), (0x0661d, 12236),
+    (0x0661e, 2947), (0x0661f, 6767), (0x06620, 6764), (0x06621, 12227), (0x06622, 12232), (0x06623, 19660), (0x06624, 6770),
+    (0x06625, 6762), (0x06626, 12231), (0x06627, 6765), (0x06628, 6768), (0x0662b, 12234), (0x0662d, 6763), (0x0662e, 12239),
+    (0x0662f, 6766), (0x06630, 2941), (0x06631, 6769), (0x06632, 12228), (0x06633, 12233), (0x06634, 12237), (0x06635, 12229),
+    (0x06636, 12226), (0x06639, 12238), (0x0663a, 12235), (0x06641, 7175), (0x06642, 7168), (0x06643, 7171), (0x06644, 19431),
+    (0x06645, 7174), (0x06647, 12675), (0x06648, 4131), (0x06649, 7169), (0x0664a, 12673), (0x0664b, 19434), (0x0664c, 7173),
+    (0x0664d, 978), (0x0664f, 7170), (0x06651, 12676), (0x06652, 7172), (0x06653, 1961), (0x06657, 19141), (0x06659, 13262),
+    (0x0665a, 7661), (0x0665b, 13261), (0x0665c, 13263), (0x0665d, 7660), (0x0665e, 7665), (0x0665f, 12674), (0x06661, 13260),
+    (0x06662, 13264), (0x06663, 2944), (0x06664, 7662), (0x06665, 13259), (0x06666, 7664), (0x06667, 19436), (0x06668, 7663),
+    (0x0666a, 13885), (0x0666b, 2939), (0x0666c, 13880), (0x0666e, 8091), (0x0666f, 8095), (0x06670, 8092), (0x06671, 13883),
+    (0x06672, 13886), (0x06673, 19438), (0x06674, 8093), (0x06676, 8094), (0x06677, 8099), (0x06678, 14545), (0x06679, 13884),
+    (0x0667a, 8097), (0x0667b, 13881), (0x0667c, 13879), (0x0667d, 2095), (0x0667e, 8098), (0x06680, 13882), (0x06684, 8535),
+    (0x06685, 19345), (0x06686, 14537), (0x06687, 8532), (0x06688, 8533), (0x06689, 8531), (0x0668a, 14542), (0x0668b, 14541),
+    (0x0668c, 14538), (0x0668d, 8537), (0x0668e, 2937), (0x06690, 14540), (0x06691, 8096), (0x06692, 1963), (0x06694, 14544),
+    (0x06695, 14539), (0x06696, 8534), (0x06697, 8530), (0x06698, 8536), (0x06699, 14543), (0x0669a, 3636), (0x0669d, 8977),
+    (0x0669f, 15222), (0x066a0, 15221), (0x066a1, 15220), (0x066a2, 8975), (0x066a4, 19172), (0x066a8, 8976), (0x066a9, 15799),
+    (0x066aa, 15802), (0x066ab, 9365), (0x066ad, 19173), (0x066ae, 9364), (0x066af, 15803), (0x066b0, 15798), (0x066b1, 9367),
+    (0x066b2, 15800), (0x066b3, 19167), (0x066b4, 9366), (0x066b5, 15797), (0x066b6, 4332), (0x066b7, 15801), (0x066b8, 9712),
+    (0x066b9, 9709), (0x066ba, 16417), (0x066bb, 16416), (0x066bd, 16415), (0x066be, 16410), (0x066bf, 4132), (0x066c0, 16411),
+    (0x066c4, 9710), (0x066c6, 9707), (0x066c7, 9711), (0x066c8, 16409), (0x066c9, 9708), (0x066ca, 16412), (0x066cb, 16413),
+    (0x066cc, 16418), (0x066cd, 19170), (0x066ce, 2918), (0x066cf, 16414), (0x066d2, 16943), (0x066d6, 9992), (0x066d8, 17392),
+    (0x066d9, 9991), (0x066da, 17390), (0x066db, 17391), (0x066dc, 10237), (0x066dd, 10408), (0x066de, 17752), (0x066e0, 10407),
+    (0x066e3, 18072), (0x066e4, 18073), (0x066e6, 10557), (0x066e7, 1848), (0x066e8, 18071), (0x066e9, 10649), (0x066eb, 18651),
+    (0x066ec, 10784), (0x066ed, 18755), (0x066ee, 18756), (0x066f0, 5631), (0x066f1, 4539), (0x066f2, 5888), (0x066f3, 5889),
+    (0x066f4, 6111), (0x066f6, 11886), (0x066f7, 6771), (0x066f8, 7176), (0x066f9, 7666), (0x066fc, 7491), (0x066fe, 8100),
+    (0x066ff, 8101), (0x06700, 7956), (0x06701, 13887), (0x06702, 19448), (0x06703, 8538), (0x06704, 15224), (0x06705, 15223),
+    (0x06708, 5632), (0x06709, 5890), (0x0670a, 11887), (0x0670b, 6439), (0x0670c, 4956), (0x0670d, 6438), (0x0670e, 4144),
+    (0x0670f, 12240), (0x06710, 12241), (0x06712, 12677), (0x06713, 12678), (0x06714, 7177), (0x06715, 7178), (0x06716, 1965),
+    (0x06717, 7179), (0x06718, 13265), (0x06719, 1745), (0x0671b, 7668), (0x0671d, 8103), (0x0671e, 4059), (0x0671f, 8102),
+    (0x06720, 14546), (0x06721, 14816), (0x06722, 15225), (0x06723, 16419), (0x06725, 2163), (0x06726, 10238), (0x06727, 10558),
+    (0x06728, 5633), (0x0672a, 5742), (0x0672b, 5743), (0x0672c, 5741), (0x0672d, 5744), (0x0672e, 5740), (0x06731, 5893),
+    (0x06733, 11452), (0x06734, 5892), (0x06735, 5894), (0x06736, 4390), (0x06738, 11447), (0x06739, 11446), (0x0673a, 11449),
+    (0x0673b, 11448), (0x0673c, 11451), (0x0673d, 5891), (0x0673e, 11445), (0x0673f, 11450), (0x06744, 19013), (0x06745, 11616),
+    (0x06746, 6121), (0x06747, 11617), (0x06748, 11621), (0x06749, 6120), (0x0674b, 11625), (0x0674c, 11620), (0x0674d, 11623),
+    (0x0674e, 6113), (0x0674f, 6114), (0x06750, 6115), (0x06751, 6116), (0x06753, 6123), (0x06755, 11619), (0x06756, 6118),
+    (0x06757, 6124), (0x06759, 11618), (0x0675a, 11624), (0x0675c, 6117), (0x0675d, 11622), (0x0675e, 6119), (0x0675f, 6112),
+    (0x06760, 6122), (0x06761, 3744), (0x06762, 19456), (0x06767, 19455), (0x0676a, 6460), (0x0676b, 1831), (0x0676c, 11889),
+    (0x0676d, 6440), (0x0676e, 995), (0x0676f, 6450), (0x06770, 6451), (0x06771, 6443), (0x06772, 6461), (0x06773, 6445),
+    (0x06774, 11897), (0x06775, 6456), (0x06776, 11892), (0x06777, 6446), (0x06778, 11907), (0x06779, 11908), (0x0677a, 11900),
+    (0x0677b, 11893), (0x0677c, 6459), (0x0677d, 11905), (0x0677e, 6454), (0x0677f, 6452), (0x06781, 11906), (0x06783, 11904),
+    (0x06784, 11896), (0x06785, 11888), (0x06786, 11895), (0x06787, 6447), (0x06789, 6453), (0x0678b, 6441), (0x0678c, 11899),
+    (0x0678d, 11898), (0x0678e, 11890), (0x0678f, 2957), (0x06790, 6455), (0x06791, 11902), (0x06792, 11891), (0x06793, 6458),
+    (0x06794, 11909), (0x06795, 6442), (0x06797, 6449), (0x06798, 11894), (0x06799, 11903), (0x0679a, 6457), (0x0679c, 6444),
+    (0x0679d, 6448), (0x0679f, 11901), (0x067a0, 3950), (0x067a4, 1967), (0x067ac, 19481), (0x067ae, 12273), (0x067af, 6779),
+    (0x067b0, 6792), (0x067b1, 19498), (0x067b2, 12268), (0x067b3, 12258), (0x067b4, 6785), (0x067b5, 12256), (0x067b6, 6778),
+    (0x067b7, 12251), (0x067b8, 6788), (0x067b9, 12264), (0x067ba, 12245), (0x067bb, 12247), (0x067bf, 2259), (0x067c0, 12250),
+    (0x067c1, 12242), (0x067c2, 12263), (0x067c3, 12279), (0x067c4, 6783), (0x067c5, 12252), (0x067c6, 12270), (0x067c8, 12244),
+    (0x067c9, 12277), (0x067ca, 12278), (0x067cb, 12281), (0x067cc, 12272), (0x067cd, 12257), (0x067ce, 12265), (0x067cf, 6789),
+    (0x067d0, 6776), (0x067d1, 6784), (0x067d2, 6796), (0x067d3, 6773), (0x067d4, 6775), (0x067d6, 3147), (0x067d7, 19459),
+    (0x067d8, 12249), (0x067d9, 6793), (0x067da, 6786), (0x067db, 12275), (0x067dc, 12246), (0x067dd, 6795), (0x067de, 6790),
+    (0x067df, 12255), (0x067e2, 6794), (0x067e3, 12262), (0x067e4, 12254), (0x067e5, 6787), (0x067e6, 12274), (0x067e7, 12266),
+    (0x067e9, 6781), (0x067ea, 12280), (0x067eb, 12253), (0x067ec, 6777), (0x067ed, 12271), (0x067ee, 12261), (0x067ef, 6782),
+    (0x067f0, 12267), (0x067f1, 6774), (0x067f2, 12243), (0x067f3, 6791), (0x067f4, 7194), (0x067f5, 6780), (0x067f6, 12260),
+    (0x067f7, 12259), (0x067f8, 12248), (0x067f9, 2685), (0x067fa, 12276), (0x067fc, 12269), (0x067fe, 1296), (0x067ff, 6772),
+    (0x06800, 1968), (0x06801, 19464), (0x06802, 4928), (0x06803, 3765), (0x06804, 1297), (0x0680d, 19640), (0x06810, 2691),
+    (0x06812, 12698), (0x06813, 7201), (0x06814, 12699), (0x06816, 12687), (0x06817, 7190), (0x06818, 7202), (0x0681a, 12680),
+    (0x0681b, 19100), (0x0681c, 12689), (0x0681d, 12697), (0x0681e, 2960), (0x0681f, 12679), (0x06820, 12706), (0x06821, 7180),
+    (0x06822, 19461), (0x06825, 12705), (0x06826, 12700), (0x06828, 12701), (0x06829, 7188), (0x0682a, 7199), (0x0682b, 12691),
+    (0x0682d, 12692), (0x0682e, 12702), (0x0682f, 12693), (0x06831, 12688), (0x06832, 12682), (0x06833, 12683), (0x06834, 12696),
+    (0x06835, 12690), (0x06836, 4368), (0x06837, 19539), (0x06838, 7181), (0x06839, 7185), (0x0683a, 12704), (0x0683b, 12684),
+    (0x0683c, 7197), (0x0683d, 7193), (0x0683e, 19315), (0x06840, 7196), (0x06841, 7203), (0x06842, 7186), (0x06843, 7198),
+    (0x06844, 12695), (0x06845, 7200), (0x06846, 7183), (0x06847, 3297), (0x06848, 7182), (0x06849, 12681), (0x0684a, 1970),
+    (0x0684b, 12685), (0x0684c, 7191), (0x0684d, 12703), (0x0684e, 12694), (0x0684f, 12686), (0x06850, 7195), (0x06851, 7192),
+    (0x06853, 7184), (0x06854, 7187), (0x06855, 4092), (0x06856, 2285), (0x0685d, 19467), (0x06865, 1298), (0x0686b, 13286),
+    (0x0686d, 13270), (0x0686e, 13271), (0x0686f, 13275), (0x06871, 13290), (0x06872, 13287), (0x06874, 13280), (0x06875, 13279),
+    (0x06876, 7675), (0x06877, 13283), (0x06878, 13298), (0x06879, 13266), (0x0687b, 13299), (0x0687c, 13285), (0x0687d, 13303),
+    (0x0687e, 13291), (0x0687f, 7674), (0x06880, 13289), (0x06881, 7669), (0x06882, 7690), (0x06883, 7680), (0x06884, 1971),
+    (0x06885, 7684), (0x06886, 7683), (0x06887, 13267), (0x06888, 2574), (0x06889, 13296), (0x0688a, 13302), (0x0688b, 13294),
+    (0x0688c, 13301), (0x0688f, 13282), (0x06890, 13268), (0x06891, 13300), (0x06892, 13284), (0x06893, 7672), (0x06894, 7685),
+    (0x06896, 13293), (0x06897, 7678), (0x06898, 2972), (0x0689b, 13292), (0x0689c, 13269), (0x0689d, 7686), (0x0689f, 7688),
+    (0x068a0, 13295), (0x068a1, 7689), (0x068a2, 7671), (0x068a3, 13276), (0x068a4, 13297), (0x068a6, 19214), (0x068a7, 7677),
+    (0x068a8, 7687), (0x068a9, 13278), (0x068aa, 13288), (0x068ab, 13273), (0x068ac, 13277), (0x068ad, 7682), (0x068ae, 13272),
+    (0x068af, 7670), (0x068b0, 7679), (0x068b1, 7676), (0x068b2, 13281), (0x068b3, 7189), (0x068b4, 13910), (0x068b5, 7673),
+    (0x068b6, 2959), (0x068b9, 3595), (0x068bd, 19635), (0x068c3, 19473), (0x068c4, 7681), (0x068c5, 3364), (0x068c6, 13913),
+    (0x068c7, 13935), (0x068c8, 13924), (0x068c9, 8123), (0x068ca, 19751), (0x068cb, 8118), (0x068cc, 13907), (0x068cd, 8119),
+    (0x068ce, 13923), (0x068d0, 13916), (0x068d1, 13929), (0x068d2, 8115), (0x068d3, 13889), (0x068d4, 13931), (0x068d5, 8105),
+    (0x068d6, 13897), (0x068d7, 8108), (0x068d8, 8107), (0x068da, 8124), (0x068dc, 13891), (0x068dd, 13925), (0x068de, 13926),
+    (0x068df, 8110), (0x068e0, 8106), (0x068e1, 13905), (0x068e3, 8117), (0x068e4, 13900), (0x068e6, 13927), (0x068e7, 8113),
+    (0x068e8, 13919), (0x068e9, 13932), (0x068ea, 13894), (0x068eb, 13899), (0x068ec, 13893), (0x068ee, 8112), (0x068ef, 13912),
+    (0x068f0, 14568), (0x068f1, 13895), (0x068f2, 8116), (0x068f3, 13904), (0x068f4, 13928), (0x068f5, 8111), (0x068f6, 13901),
+    (0x068f7, 13898), (0x068f8, 13915), (0x068f9, 8114), (0x068fa, 8104), (0x068fb, 8126), (0x068fc, 13918), (0x068fd, 13917),
+    (0x06900, 19476), (0x06901, 19475), (0x06902, 19769), (0x06903, 19667), (0x06904, 13890), (0x06905, 8109), (0x06906, 13930),
+    (0x06907, 13906), (0x06908, 13908), (0x06909, 1975), (0x0690a, 13921), (0x0690b, 13920), (0x0690c, 13888), (0x0690d, 8120),
+    (0x0690e, 8122), (0x0690f, 13896), (0x06910, 13903), (0x06911, 13911), (0x06912, 8121), (0x06913, 13902), (0x06914, 13914),
+    (0x06915, 13933), (0x06917, 13922), (0x06918, 4145), (0x06919, 1791), (0x0691a, 4010), (0x0691b, 2195), (0x06925, 13934),
+    (0x0692a, 13892), (0x0692c, 4773), (0x0692f, 14588), (0x06930, 8546), (0x06932, 14586), (0x06933, 14565), (0x06934, 14570),
+    (0x06935, 14563), (0x06936, 19485), (0x06937, 14582), (0x06938, 14549), (0x06939, 14556), (0x0693b, 14580), (0x0693c, 14590),
+    (0x0693d, 14566), (0x0693e, 19072), (0x0693f, 14553), (0x06940, 14572), (0x06941, 14577), (0x06942, 14557), (0x06943, 1976),
+    (0x06944, 14574), (0x06945, 14554), (0x06946, 1322), (0x06948, 14561), (0x06949, 14562), (0x0694a, 8548), (0x0694b, 14581),
+    (0x0694c, 14579), (0x0694e, 14550), (0x0694f, 14584), (0x06951, 14585), (0x06952, 14587), (0x06953, 8552), (0x06954, 8544),
+    (0x06955, 4044), (0x06956, 13274), (0x06957, 14558), (0x06958, 14576), (0x06959, 14559), (0x0695a, 8541), (0x0695b, 8557),
+    (0x0695c, 14583), (0x0695d, 8555), (0x0695e, 8551), (0x0695f, 14548), (0x06960, 8543), (0x06961, 19482), (0x06962, 14551),
+    (0x06963, 8556), (0x06964, 1978), (0x06965, 14567), (0x06966, 14547), (0x06967, 3596), (0x06968, 8549), (0x06969, 14571),
+    (0x0696a, 14555), (0x0696b, 8550), (0x0696c, 14564), (0x0696d, 8540), (0x0696e, 8125), (0x0696f, 14573), (0x06970, 13909),
+    (0x06971, 14552), (0x06972, 2908), (0x06973, 19472), (0x06974, 14578), (0x06975, 8545), (0x06976, 14575), (0x06977, 8542),
+    (0x06978, 14569), (0x06979, 8553), (0x0697a, 14560), (0x0697b, 14589), (0x06980, 4779), (0x06982, 8547), (0x06983, 14701),
+    (0x06985, 1980), (0x06986, 8554), (0x0698a, 3852), (0x0698d, 15239), (0x0698e, 15237), (0x06990, 15259), (0x06991, 15235),
+    (0x06993, 15253), (0x06994, 8539), (0x06995, 8980), (0x06996, 15231), (0x06997, 15258), (0x06998, 19486), (0x06999, 15236),
+    (0x0699a, 15250), (0x0699b, 8985), (0x0699c, 8978), (0x0699e, 15256), (0x0699f, 1979), (0x069a0, 15229), (0x069a1, 15255),
+    (0x069a2, 2047), (0x069a3, 8996), (0x069a4, 15246), (0x069a5, 15262), (0x069a6, 8994), (0x069a7, 15238), (0x069a8, 8979),
+    (0x069a9, 15240), (0x069aa, 15254), (0x069ab, 8988), (0x069ac, 15233), (0x069ad, 8992), (0x069ae, 8982), (0x069af, 15242),
+    (0x069b0, 15232), (0x069b1, 15226), (0x069b2, 4978), (0x069b3, 15252), (0x069b4, 8989), (0x069b5, 15261), (0x069b6, 15227),
+    (0x069b7, 8986), (0x069b9, 15248), (0x069bb, 8987), (0x069bc, 15234), (0x069bd, 15245), (0x069be, 15241), (0x069bf, 15243),
+    (0x069c0, 4814), (0x069c1, 8981), (0x069c2, 15260), (0x069c3, 8995), (0x069c4, 15244), (0x069c6, 15263), (0x069c9, 15228),
+    (0x069ca, 15249), (0x069cb, 8984), (0x069cc, 8993), (0x069cd, 8991), (0x069ce, 15230), (0x069cf, 15251), (0x069d0, 8990),
+    (0x069d1, 2778), (0x069d3, 8983), (0x069d4, 15247), (0x069d5, 4385), (0x069d6, 1982), (0x069d9, 15257), (0x069e1, 19469),
+    (0x069e2, 15815), (0x069e4, 15811), (0x069e5, 15807), (0x069e6, 15827), (0x069e7, 15824), (0x069e8, 9370), (0x069e9, 4094),
+    (0x069eb, 15831), (0x069ec, 15814), (0x069ed, 9381), (0x069ee, 15821), (0x069f1, 15810), (0x069f2, 15820), (0x069f3, 9378),
+    (0x069f4, 15840), (0x069f6, 15837), (0x069f7, 15823), (0x069f8, 15808), (0x069f9, 19491), (0x069fa, 19452), (0x069fb, 15828),
+    (0x069fc, 15830), (0x069fd, 9374), (0x069fe, 15818), (0x069ff, 15813), (0x06a00, 15804), (0x06a01, 9371), (0x06a02, 9379),
+    (0x06a03, 4363), (0x06a04, 15833), (0x06a05, 9380), (0x06a06, 15805), (0x06a07, 15839), (0x06a08, 15826), (0x06a09, 15832),
+    (0x06a0a, 9377), (0x06a0b, 19470), (0x06a0c, 3655), (0x06a0d, 15829), (0x06a0f, 15836), (0x06a11, 9382), (0x06a13, 9376),
+    (0x06a14, 15822), (0x06a15, 15809), (0x06a16, 15841), (0x06a17, 15806), (0x06a18, 15834), (0x06a19, 9373), (0x06a1a, 3605),
+    (0x06a1b, 15816), (0x06a1c, 3164), (0x06a1d, 15817), (0x06a1e, 9372), (0x06a1f, 9369), (0x06a20, 15812), (0x06a21, 9375),
+    (0x06a23, 9368), (0x06a25, 15835), (0x06a26, 15838), (0x06a27, 15819), (0x06a28, 16432), (0x06a2b, 3641), (0x06a2c, 19684),
+    (0x06a2d, 3090), (0x06a32, 16424), (0x06a33, 2966), (0x06a34, 16420), (0x06a35, 9725), (0x06a38, 9714), (0x06a39, 9719),
+    (0x06a3a, 9715), (0x06a3b, 16434), (0x06a3c, 16445), (0x06a3d, 9713), (0x06a3e, 16426), (0x06a3f, 16435), (0x06a40, 15825),
+    (0x06a41, 16436), (0x06a43, 1792), (0x06a44, 9720), (0x06a45, 19494), (0x06a46, 16451), (0x06a47, 9724), (0x06a48, 9727),
+    (0x06a49, 16422), (0x06a4b, 9723), (0x06a4c, 2973), (0x06a4d, 16449), (0x06a4e, 16450), (0x06a4f, 16440), (0x06a50, 16439),
+    (0x06a51, 16431), (0x06a52, 2967), (0x06a53, 2177), (0x06a54, 16441), (0x06a55, 16448), (0x06a56, 16447), (0x06a57, 2225),
+    (0x06a58, 9718), (0x06a59, 9716), (0x06a5a, 16433), (0x06a5b, 16430), (0x06a5d, 16427), (0x06a5e, 16446), (0x06a5f, 9726),
+    (0x06a60, 16444), (0x06a61, 9722), (0x06a62, 9721), (0x06a63, 1757), (0x06a64, 16438), (0x06a65, 1984), (0x06a66, 16421),
+    (0x06a67, 16423), (0x06a68, 16425), (0x06a69, 16443), (0x06a6a, 16437), (0x06a6b, 9717), (0x06a6d, 16428), (0x06a6f, 16442),
+    (0x06a71, 1986), (0x06a74, 1985), (0x06a76, 16429), (0x06a7a, 2975), (0x06a7e, 10000), (0x06a7f, 16961), (0x06a80, 9993),
+    (0x06a81, 16946), (0x06a82, 1987), (0x06a83, 16957), (0x06a84, 9995), (0x06a85, 16964), (0x06a87, 16953), (0x06a89, 16948),
+    (0x06a8a, 19298), (0x06a8c, 16965), (0x06a8d, 16944), (0x06a8e, 16955), (0x06a8f, 3345), (0x06a90, 10002), (0x06a91, 16960),
+    (0x06a92, 16966), (0x06a93, 16954), (0x06a94, 9994), (0x06a95, 16956), (0x06a96, 16945), (0x06a97, 10001), (0x06a99, 1989),
+    (0x06a9a, 16963), (0x06a9b, 16950), (0x06a9c, 9997), (0x06a9d, 19496), (0x06a9e, 16952), (0x06a9f, 16949), (0x06aa0, 10003),
+    (0x06aa1, 16951), (0x06aa2, 9996), (0x06aa3, 9999), (0x06aa4, 16959), (0x06aa5, 16947), (0x06aa6, 16962), (0x06aa7, 2235),
+    (0x06aa8, 16958), (0x06aab, 1991), (0x06aac, 10240), (0x06aad, 17403), (0x06aae, 10245), (0x06aaf, 10246), (0x06ab1, 4466),
+    (0x06ab2, 3134), (0x06ab3, 10239), (0x06ab4, 17402), (0x06ab5, 1992), (0x06ab6, 17399), (0x06ab7, 17400), (0x06ab8, 10243),
+    (0x06ab9, 17394), (0x06aba, 17398), (0x06abb, 10242), (0x06abd, 17395), (0x06abe, 2962), (0x06ac2, 10244), (0x06ac3, 10241),
+    (0x06ac5, 17393), (0x06ac6, 17397), (0x06ac7, 17401), (0x06ac8, 19499), (0x06ac9, 2968), (0x06aca, 4794), (0x06acb, 17758),
+    (0x06acc, 17755), (0x06acd, 17764), (0x06acf, 17763), (0x06ad0, 17761), (0x06ad1, 17756), (0x06ad3, 10412), (0x06ad4, 1993),
+    (0x06ad8, 3489), (0x06ad9, 17757), (0x06ada, 10411), (0x06adb, 9998), (0x06adc, 17760), (0x06add, 10410), (0x06ade, 17765),
+    (0x06adf, 17759), (0x06ae0, 17754), (0x06ae1, 17396), (0x06ae5, 10409), (0x06ae7, 17753), (0x06ae8, 18077), (0x06aea, 18076),
+    (0x06aeb, 17762), (0x06aec, 10559), (0x06aee, 18080), (0x06aef, 18081), (0x06af0, 18075), (0x06af1, 18079), (0x06af3, 18074),
+    (0x06af6, 1994), (0x06af8, 18323), (0x06af9, 18078), (0x06afa, 10652), (0x06afb, 10650), (0x06afc, 18321), (0x06b00, 18324),
+    (0x06b02, 18320), (0x06b03, 18322), (0x06b04, 10651), (0x06b05, 2969), (0x06b08, 18500), (0x06b09, 18501), (0x06b0a, 10730),
+    (0x06b0b, 18499), (0x06b0f, 18654), (0x06b10, 10785), (0x06b11, 18652), (0x06b12, 18653), (0x06b13, 18757), (0x06b16, 10855),
+    (0x06b17, 18828), (0x06b18, 18826), (0x06b19, 18827), (0x06b1a, 18829), (0x06b1d, 19502), (0x06b1e, 18929), (0x06b20, 5634),
+    (0x06b21, 5895), (0x06b23, 6462), (0x06b25, 11910), (0x06b28, 12282), (0x06b2c, 12707), (0x06b2d, 12709), (0x06b2f, 12708),
+    (0x06b31, 12710), (0x06b32, 7691), (0x06b33, 13305), (0x06b34, 12711), (0x06b35, 19505), (0x06b36, 13304), (0x06b37, 13306),
+    (0x06b38, 13307), (0x06b39, 13936), (0x06b3a, 8128), (0x06b3b, 13937), (0x06b3c, 13939), (0x06b3d, 8129), (0x06b3e, 8127),
+    (0x06b3f, 13938), (0x06b41, 14596), (0x06b42, 14594), (0x06b43, 14593), (0x06b45, 14592), (0x06b46, 14591), (0x06b47, 8558),
+    (0x06b48, 14595), (0x06b49, 8997), (0x06b4a, 15264), (0x06b4b, 15266), (0x06b4c, 8998), (0x06b4d, 15265), (0x06b4e, 9384),
+    (0x06b50, 9383), (0x06b51, 15842), (0x06b52, 4831), (0x06b54, 16453), (0x06b55, 16452), (0x06b56, 16454), (0x06b57, 2976),
+    (0x06b59, 9728), (0x06b5b, 16967), (0x06b5c, 10004), (0x06b5e, 17404), (0x06b5f, 10247), (0x06b60, 17766), (0x06b61, 10731),
+    (0x06b62, 5635), (0x06b63, 5745), (0x06b64, 5896), (0x06b65, 6125), (0x06b66, 6463), (0x06b67, 6464), (0x06b6a, 6797),
+    (0x06b6d, 12712), (0x06b6f, 1719), (0x06b72, 8559), (0x06b74, 19506), (0x06b76, 15968), (0x06b77, 9729), (0x06b78, 10248),
+    (0x06b79, 5636), (0x06b7a, 1679), (0x06b7b, 5897), (0x06b7e, 11912), (0x06b7f, 6465), (0x06b80, 11911), (0x06b81, 1995),
+    (0x06b82, 12283), (0x06b83, 6798), (0x06b84, 12284), (0x06b86, 6799), (0x06b88, 12714), (0x06b89, 7205), (0x06b8a, 7204),
+    (0x06b8c, 13312), (0x06b8d, 13310), (0x06b8e, 13311), (0x06b8f, 13309), (0x06b91, 13308), (0x06b94, 13940), (0x06b95, 13943),
+    (0x06b96, 8131), (0x06b97, 13941), (0x06b98, 8130), (0x06b99, 13942), (0x06b9b, 14597), (0x06b9e, 15267), (0x06b9f, 15268),
+    (0x06ba0, 15269), (0x06ba2, 15845), (0x06ba3, 15844), (0x06ba4, 9385), (0x06ba5, 15843), (0x06ba6, 15846), (0x06ba7, 16455),
+    (0x06baa, 16456), (0x06bab, 16457), (0x06bad, 16968), (0x06bae, 10005), (0x06baf, 10249), (0x06bb0, 17767), (0x06bb2, 10653),
+    (0x06bb3, 11335), (0x06bb5, 6800), (0x06bb6, 12285), (0x06bb7, 7206), (0x06bba, 7692), (0x06bbc, 8132), (0x06bbd, 13944),
+    (0x06bbf, 8561), (0x06bc0, 8560), (0x06bc1, 1996), (0x06bc3, 15270), (0x06bc4, 15271), (0x06bc5, 9386), (0x06bc6, 9387),
+    (0x06bc7, 16459), (0x06bc8, 16458), (0x06bc9, 17405), (0x06bca, 18655), (0x06bcb, 5637), (0x06bcc, 11336), (0x06bcd, 5746),
+    (0x06bcf, 6126), (0x06bd0, 11626), (0x06bd2, 6801), (0x06bd3, 8562), (0x06bd4, 5638), (0x06bd6, 12286), (0x06bd7, 6802),
+    (0x06bd8, 12287), (0x06bda, 10006), (0x06bdb, 5639), (0x06bdc, 19517), (0x06bde, 11913), (0x06be0, 12288), (0x06be1, 19515),
+    (0x06be2, 12719), (0x06be3, 12718), (0x06be4, 12716), (0x06be6, 12715), (0x06be7, 12720), (0x06be8, 12717), (0x06bea, 1997),
+    (0x06beb, 7693), (0x06bec, 7694), (0x06bef, 8133), (0x06bf0, 13945), (0x06bf2, 13946), (0x06bf3, 13947), (0x06bf7, 14602),
+    (0x06bf8, 14603), (0x06bf9, 14601), (0x06bfa, 4843), (0x06bfb, 14599), (0x06bfc, 14600), (0x06bfd, 8563), (0x06bfe, 15272),
+    (0x06bff, 15849), (0x06c00, 15848), (0x06c01, 15847), (0x06c02, 15850), (0x06c03, 16461), (0x06c04, 16460), (0x06c05, 9730),
+    (0x06c06, 16462), (0x06c08, 10007), (0x06c09, 16969), (0x06c0b, 17406), (0x06c0c, 17768), (0x06c0d, 18502), (0x06c0f, 5640),
+    (0x06c10, 5748), (0x06c11, 5747), (0x06c13, 6466), (0x06c14, 11337), (0x06c15, 11366), (0x06c16, 5898), (0x06c18, 11453),
+    (0x06c19, 11627), (0x06c1a, 11628), (0x06c1b, 6467), (0x06c1c, 2446), (0x06c1d, 11914), (0x06c1f, 6803), (0x06c20, 12289),
+    (0x06c21, 12290), (0x06c23, 7207), (0x06c24, 7211), (0x06c25, 12721), (0x06c26, 7210), (0x06c27, 7208), (0x06c28, 7209),
+    (0x06c2a, 13313), (0x06c2b, 7695), (0x06c2c, 8136), (0x06c2e, 8134), (0x06c2f, 8135), (0x06c30, 13948), (0x06c31, 4820),
+    (0x06c33, 8999), (0x06c34, 5641), (0x06c35, 1680), (0x06c36, 11367), (0x06c37, 19518), (0x06c38, 5749), (0x06c39, 4315),
+    (0x06c3a, 1681), (0x06c3b, 11370), (0x06c3d, 1534), (0x06c3e, 5752), (0x06c3f, 11369), (0x06c40, 5751), (0x06c41, 5750),
+    (0x06c42, 6127), (0x06c43, 11368), (0x06c46, 11454), (0x06c49, 19127), (0x06c4a, 11458), (0x06c4b, 11460), (0x06c4c, 11461),
+    (0x06c4d, 5908), (0x06c4e, 5909), (0x06c4f, 11457), (0x06c50, 5904), (0x06c52, 11455), (0x06c54, 11459), (0x06c55, 5905),
+    (0x06c57, 5900), (0x06c58, 3248), (0x06c59, 5901), (0x06c5a, 19522), (0x06c5b, 5907), (0x06c5c, 11456), (0x06c5d, 5899),
+    (0x06c5e, 6128), (0x06c5f, 5902), (0x06c60, 5903), (0x06c61, 5906), (0x06c65, 11645), (0x06c66, 11643), (0x06c67, 11630),
+    (0x06c68, 6140), (0x06c69, 11637), (0x06c6a, 6135), (0x06c6b, 11631), (0x06c6d, 11639), (0x06c6e, 19188), (0x06c6f, 11636),
+    (0x06c70, 6138), (0x06c71, 11635), (0x06c72, 6145), (0x06c73, 11644), (0x06c74, 6147), (0x06c75, 1998), (0x06c76, 6149),
+    (0x06c78, 11629), (0x06c79, 19524), (0x06c7a, 6136), (0x06c7b, 11646), (0x06c7d, 6143), (0x06c7e, 6146), (0x06c7f, 2170),
+    (0x06c80, 11937), (0x06c81, 6130), (0x06c82, 6153), (0x06c83, 6144), (0x06c84, 11632), (0x06c85, 6133), (0x06c86, 6148),
+    (0x06c87, 11640), (0x06c88, 6131), (0x06c89, 6132), (0x06c8a, 11935), (0x06c8b, 11633), (0x06c8c, 6139), (0x06c8d, 6150),
+    (0x06c8e, 11647), (0x06c8f, 11634), (0x06c90, 6137), (0x06c92, 6142), (0x06c93, 11915), (0x06c94, 6151), (0x06c95, 11641),
+    (0x06c96, 6141), (0x06c98, 6152), (0x06c99, 6129), (0x06c9a, 11638), (0x06c9b, 6134), (0x06c9c, 11642), (0x06c9d, 11936),
+    (0x06c9f, 2409), (0x06ca2, 3711), (0x06caa, 1999), (0x06cab, 6479), (0x06cac, 6495), (0x06cad, 11922), (0x06cae, 6486),
+    (0x06caf, 3565), (0x06cb0, 11943), (0x06cb1, 6471), (0x06cb2, 4637), (0x06cb3, 6474), (0x06cb4, 11934), (0x06cb6, 11920),
+    (0x06cb7, 11924), (0x06cb8, 6482), (0x06cb9, 6484), (0x06cba, 11927), (0x06cbb, 6491), (0x06cbc, 6477), (0x06cbd, 6475),
+    (0x06cbe, 6476), (0x06cbf, 6490), (0x06cc0, 11939), (0x06cc1, 6485), (0x06cc2, 11926), (0x06cc3, 11928), (0x06cc4, 6483),
+    (0x06cc5, 6488), (0x06cc6, 11929), (0x06cc7, 11942), (0x06cc9, 6804), (0x06cca, 6494), (0x06ccb, 19098), (0x06ccc, 6472),
+    (0x06ccd, 11941), (0x06cce, 4869), (0x06ccf, 11945), (0x06cd0, 11925), (0x06cd1, 11947), (0x06cd2, 11932), (0x06cd3, 6481),
+    (0x06cd4, 11921), (0x06cd5, 6480), (0x06cd6, 6498), (0x06cd7, 6487), (0x06cd9, 11919), (0x06cda, 12299), (0x06cdb, 6493),
+    (0x06cdc, 6497), (0x06cdd, 11933), (0x06cde, 11938), (0x06cdf, 3662), (0x06ce0, 6499), (0x06ce1, 6492), (0x06ce2, 6478),
+    (0x06ce3, 6468), (0x06ce5, 6473), (0x06ce7, 11923), (0x06ce8, 6469), (0x06ce9, 11946), (0x06cea, 4864), (0x06ceb, 11917),
+    (0x06cec, 11916), (0x06ced, 11930), (0x06cee, 11918), (0x06cef, 6496), (0x06cf0, 7212), (0x06cf1, 6489), (0x06cf2, 11931),
+    (0x06cf3, 6470), (0x06cf5, 6819), (0x06cf9, 11944), (0x06cff, 1794), (0x06d00, 12306), (0x06d01, 12309), (0x06d02, 2001),
+    (0x06d03, 12312), (0x06d04, 12301), (0x06d05, 2822), (0x06d06, 2002), (0x06d07, 12315), (0x06d08, 12318), (0x06d09, 12320),
+    (0x06d0a, 12298), (0x06d0b, 6805), (0x06d0c, 6810), (0x06d0d, 12726), (0x06d0e, 6826), (0x06d0f, 12313), (0x06d10, 12321),
+    (0x06d11, 12305), (0x06d12, 12297), (0x06d16, 12753), (0x06d17, 6813), (0x06d18, 12310), (0x06d19, 12302), (0x06d1a, 12304),
+    (0x06d1b, 6818), (0x06d1d, 12307), (0x06d1e, 6812), (0x06d1f, 12294), (0x06d20, 12316), (0x06d22, 12319), (0x06d24, 2998),
+    (0x06d25, 6809), (0x06d26, 2003), (0x06d27, 6821), (0x06d28, 12291), (0x06d29, 6823), (0x06d2a, 6807), (0x06d2b, 6827),
+    (0x06d2c, 12317), (0x06d2d, 12293), (0x06d2e, 6824), (0x06d2f, 12747), (0x06d30, 11940), (0x06d31, 6811), (0x06d32, 6806),
+    (0x06d33, 12300), (0x06d34, 12292), (0x06d35, 6825), (0x06d36, 6817), (0x06d37, 12311), (0x06d38, 6822), (0x06d39, 6820),
+    (0x06d3a, 12303), (0x06d3b, 6814), (0x06d3c, 12295), (0x06d3d, 6815), (0x06d3e, 6816), (0x06d3f, 12296), (0x06d40, 12314),
+    (0x06d41, 6808), (0x06d42, 12308), (0x06d4e, 1299), (0x06d57, 3562), (0x06d58, 12729), (0x06d59, 7220), (0x06d5a, 7225),
+    (0x06d5b, 19142), (0x06d5c, 19544), (0x06d5e, 12738), (0x06d5f, 12744), (0x06d60, 12740), (0x06d61, 12727), (0x06d62, 12730),
+    (0x06d63, 12723), (0x06d64, 12724), (0x06d65, 7232), (0x06d66, 7217), (0x06d67, 12739), (0x06d68, 12748), (0x06d69, 7227),
+    (0x06d6a, 7213), (0x06d6c, 7222), (0x06d6d, 12731), (0x06d6e, 7224), (0x06d6f, 12732), (0x06d70, 12742), (0x06d71, 19231),
+    (0x06d72, 2754), (0x06d74, 7226), (0x06d75, 12757), (0x06d76, 12725), (0x06d77, 7219), (0x06d78, 7218), (0x06d79, 7230),
+    (0x06d7a, 12722), (0x06d7b, 12755), (0x06d7c, 12743), (0x06d7d, 12756), (0x06d7e, 12750), (0x06d7f, 12736), (0x06d80, 12751),
+    (0x06d81, 2004), (0x06d82, 12745), (0x06d83, 12754), (0x06d84, 12752), (0x06d85, 7231), (0x06d86, 12737), (0x06d87, 7216),
+    (0x06d88, 7215), (0x06d89, 7223), (0x06d8a, 7229), (0x06d8b, 12749), (0x06d8c, 7228), (0x06d8d, 12734), (0x06d8e, 7696),
+    (0x06d8f, 4265), (0x06d90, 12758), (0x06d91, 12733), (0x06d92, 12728), (0x06d93, 7221), (0x06d94, 7233), (0x06d95, 7214),
+    (0x06d96, 19543), (0x06d97, 12741), (0x06d98, 12746), (0x06da4, 2006), (0x06da5, 2987), (0x06daa, 7730), (0x06dab, 13315),
+    (0x06dac, 13319), (0x06dae, 7711), (0x06daf, 7709), (0x06db1, 2007), (0x06db2, 7700), (0x06db3, 13317), (0x06db4, 13316),
+    (0x06db5, 7720), (0x06db7, 13322), (0x06db8, 7714), (0x06db9, 4983), (0x06dba, 13340), (0x06dbb, 13352), (0x06dbc, 7697),
+    (0x06dbd, 13337), (0x06dbe, 13330), (0x06dbf, 7732), (0x06dc0, 13314), (0x06dc2, 13342), (0x06dc4, 7729), (0x06dc5, 7717),
+    (0x06dc6, 7728), (0x06dc7, 7707), (0x06dc8, 13326), (0x06dc9, 13344), (0x06dca, 13336), (0x06dcb, 7708), (0x06dcc, 7702),
+    (0x06dcd, 13350), (0x06dcf, 13343), (0x06dd0, 13345), (0x06dd1, 7710), (0x06dd2, 7718), (0x06dd3, 13347), (0x06dd4, 13324),
+    (0x06dd5, 13341), (0x06dd6, 13329), (0x06dd7, 13349), (0x06dd8, 7723), (0x06dd9, 7699), (0x06dda, 7721), (0x06ddb, 13334),
+    (0x06ddc, 13332), (0x06ddd, 13333), (0x06dde, 7712), (0x06ddf, 13328), (0x06de0, 13327), (0x06de1, 7701), (0x06de2, 13321),
+    (0x06de3, 13351), (0x06de4, 7703), (0x06de5, 13331), (0x06de6, 7733), (0x06de8, 7727), (0x06de9, 13320), (0x06dea, 7724),
+    (0x06deb, 7722), (0x06dec, 7731), (0x06ded, 13338), (0x06dee, 7726), (0x06def, 12735), (0x06df0, 13339), (0x06df1, 7725),
+    (0x06df2, 13346), (0x06df3, 7698), (0x06df4, 13335), (0x06df5, 7716), (0x06df6, 13323), (0x06df7, 7715), (0x06df9, 7713),
+    (0x06dfa, 7705), (0x06dfb, 7704), (0x06dfc, 13949), (0x06dfd, 13348), (0x06dfe, 1728), (0x06e00, 13325), (0x06e02, 3569),
+    (0x06e03, 13970), (0x06e04, 2993), (0x06e05, 7706), (0x06e0a, 2996), (0x06e0f, 3559), (0x06e15, 2008), (0x06e18, 2009),
+    (0x06e19, 8165), (0x06e1a, 7719), (0x06e1b, 8147), (0x06e1c, 13964), (0x06e1d, 8161), (0x06e1f, 13952), (0x06e20, 8144),
+    (0x06e21, 8140), (0x06e22, 13982), (0x06e23, 8146), (0x06e24, 8150), (0x06e25, 8145), (0x06e26, 8154), (0x06e27, 13986),
+    (0x06e28, 13977), (0x06e29, 2010), (0x06e2a, 19776), (0x06e2b, 13959), (0x06e2c, 8159), (0x06e2d, 8153), (0x06e2e, 13971),
+    (0x06e2f, 8137), (0x06e30, 13983), (0x06e31, 13976), (0x06e32, 8141), (0x06e33, 13965), (0x06e34, 8156), (0x06e35, 13994),
+    (0x06e36, 13995), (0x06e38, 8138), (0x06e39, 13981), (0x06e3a, 8158), (0x06e3b, 13969), (0x06e3c, 13955), (0x06e3d, 13956),
+    (0x06e3e, 8162), (0x06e3f, 13960), (0x06e40, 13967), (0x06e41, 13961), (0x06e43, 8160), (0x06e44, 8168), (0x06e45, 13957),
+    (0x06e46, 13950), (0x06e47, 13951), (0x06e49, 13953), (0x06e4a, 8143), (0x06e4b, 13966), (0x06e4d, 8157), (0x06e4e, 8166),
+    (0x06e4f, 19562), (0x06e50, 19462), (0x06e51, 13968), (0x06e52, 13992), (0x06e53, 13984), (0x06e54, 8139), (0x06e55, 13990),
+    (0x06e56, 8151), (0x06e57, 1850), (0x06e58, 8149), (0x06e59, 19216), (0x06e5a, 13996), (0x06e5b, 8148), (0x06e5c, 13974),
+    (0x06e5d, 13962), (0x06e5e, 13972), (0x06e5f, 8171), (0x06e60, 13978), (0x06e61, 13975), (0x06e62, 13958), (0x06e63, 8167),
+    (0x06e64, 13988), (0x06e65, 13985), (0x06e66, 13993), (0x06e67, 8142), (0x06e68, 13973), (0x06e69, 8170), (0x06e6b, 13980),
+    (0x06e6e, 8152), (0x06e6f, 8155), (0x06e71, 13979), (0x06e72, 8169), (0x06e73, 13963), (0x06e74, 13318), (0x06e76, 2750),
+    (0x06e77, 13989), (0x06e78, 13987), (0x06e79, 13991), (0x06e7c, 19545), (0x06e86, 2011), (0x06e88, 13954), (0x06e89, 8164),
+    (0x06e8b, 3253), (0x06e8d, 14634), (0x06e8e, 14633), (0x06e8f, 14607), (0x06e90, 8569), (0x06e92, 14632), (0x06e93, 14610),
+    (0x06e94, 14611), (0x06e96, 8579), (0x06e97, 14641), (0x06e98, 8574), (0x06e99, 14631), (0x06e9a, 1351), (0x06e9b, 14604),
+    (0x06e9c, 8580), (0x06e9d, 8570), (0x06e9e, 14619), (0x06e9f, 14609), (0x06ea0, 14612), (0x06ea1, 14636), (0x06ea2, 8564),
+    (0x06ea3, 14643), (0x06ea4, 14635), (0x06ea5, 8573), (0x06ea6, 14624), (0x06ea7, 8584), (0x06eaa, 8583), (0x06eab, 8577),
+    (0x06eae, 14642), (0x06eaf, 8565), (0x06eb0, 14622), (0x06eb1, 14613), (0x06eb2, 14626), (0x06eb3, 14638), (0x06eb4, 8585),
+    (0x06eb5, 19508), (0x06eb6, 8567), (0x06eb7, 14621), (0x06eb8, 4324), (0x06eb9, 14614), (0x06eba, 8576), (0x06ebb, 2013),
+    (0x06ebc, 8575), (0x06ebd, 14617), (0x06ebe, 14627), (0x06ebf, 14637), (0x06ec0, 14608), (0x06ec1, 14618), (0x06ec2, 8568),
+    (0x06ec3, 14628), (0x06ec4, 8581), (0x06ec5, 8572), (0x06ec6, 14615), (0x06ec7, 8571), (0x06ec8, 14606), (0x06ec9, 14620),
+    (0x06eca, 14640), (0x06ecb, 8163), (0x06ecc, 9027), (0x06ecd, 14623), (0x06ece, 15273), (0x06ecf, 14625), (0x06ed0, 14639),
+    (0x06ed1, 8578), (0x06ed2, 14616), (0x06ed3, 8566), (0x06ed4, 8582), (0x06ed5, 9405), (0x06ed6, 14605), (0x06ed8, 14630),
+    (0x06ed9, 19091), (0x06eda, 2015), (0x06edb, 19033), (0x06edc, 14629), (0x06edd, 1263), (0x06ee2, 2014), (0x06ee8, 2017),
+    (0x06ee9, 2018), (0x06eeb, 15301), (0x06eec, 9024), (0x06eed, 15290), (0x06eee, 15295), (0x06eef, 9013), (0x06ef1, 15275),
+    (0x06ef2, 9026), (0x06ef4, 9004), (0x06ef5, 15274), (0x06ef6, 15307), (0x06ef7, 9028), (0x06ef8, 15278), (0x06ef9, 15294),
+    (0x06efa, 19569), (0x06efb, 15280), (0x06efc, 15310), (0x06efd, 15306), (0x06efe, 9002), (0x06eff, 9012), (0x06f00, 15876),
+    (0x06f01, 9025), (0x06f02, 9010), (0x06f03, 15276), (0x06f04, 19546), (0x06f05, 15305), (0x06f06, 9014), (0x06f07, 15302),
+    (0x06f08, 15315), (0x06f09, 15282), (0x06f0a, 15291), (0x06f0b, 2988), (0x06f0c, 3570), (0x06f0d, 15313), (0x06f0e, 15303),
+    (0x06f0f, 9009), (0x06f12, 15289), (0x06f13, 9003), (0x06f14, 9001), (0x06f15, 9019), (0x06f16, 19411), (0x06f17, 3615),
+    (0x06f18, 15287), (0x06f19, 15284), (0x06f1a, 15285), (0x06f1c, 15309), (0x06f1e, 15314), (0x06f1f, 15312), (0x06f20, 9007),
+    (0x06f21, 15316), (0x06f22, 9011), (0x06f23, 9018), (0x06f24, 2019), (0x06f25, 15277), (0x06f26, 15852), (0x06f27, 15286),
+    (0x06f29, 9005), (0x06f2a, 9023), (0x06f2b, 9020), (0x06f2c, 9008), (0x06f2d, 15296), (0x06f2e, 15281), (0x06f2f, 9021),
+    (0x06f30, 15298), (0x06f31, 9015), (0x06f32, 9017), (0x06f33, 9000), (0x06f34, 2020), (0x06f35, 15300), (0x06f36, 15292),
+    (0x06f37, 15279), (0x06f38, 9016), (0x06f39, 15308), (0x06f3a, 15311), (0x06f3b, 15288), (0x06f3c, 15299), (0x06f3d, 19641),
+    (0x06f3e, 9006), (0x06f3f, 9388), (0x06f40, 15297), (0x06f41, 15851), (0x06f43, 15304), (0x06f44, 19574), (0x06f4e, 15283),
+    (0x06f4f, 15861), (0x06f50, 15871), (0x06f51, 9391), (0x06f52, 15870), (0x06f53, 15882), (0x06f54, 9393), (0x06f55, 15868),
+    (0x06f56, 4465), (0x06f57, 15872), (0x06f58, 9404), (0x06f5a, 15863), (0x06f5b, 9396), (0x06f5c, 19575), (0x06f5d, 15875),
+    (0x06f5e, 16470), (0x06f5f, 9408), (0x06f60, 9407), (0x06f61, 15877), (0x06f62, 15860), (0x06f63, 15887), (0x06f64, 9402),
+    (0x06f66, 9392), (0x06f67, 15880), (0x06f69, 15884), (0x06f6a, 15889), (0x06f6b, 15878), (0x06f6c, 15866), (0x06f6d, 9395),
+    (0x06f6e, 9398), (0x06f6f, 9406), (0x06f70, 9401), (0x06f72, 15869), (0x06f73, 15293), (0x06f74, 19577), (0x06f76, 15865),
+    (0x06f77, 15888), (0x06f78, 9397), (0x06f79, 3252), (0x06f7a, 9400), (0x06f7b, 15890), (0x06f7c, 9389), (0x06f7d, 15879),
+    (0x06f7e, 15853), (0x06f7f, 15885), (0x06f80, 10016), (0x06f81, 2023), (0x06f82, 15867), (0x06f84, 9390), (0x06f85, 15862),
+    (0x06f86, 9394), (0x06f87, 15854), (0x06f88, 9022), (0x06f89, 15858), (0x06f8a, 19399), (0x06f8b, 15883), (0x06f8c, 15859),
+    (0x06f8d, 15857), (0x06f8e, 9399), (0x06f90, 15881), (0x06f92, 15856), (0x06f93, 15874), (0x06f94, 15873), (0x06f95, 15886),
+    (0x06f96, 15864), (0x06f97, 9403), (0x06f9d, 19567), (0x06f9e, 16473), (0x06f9f, 19580), (0x06fa0, 9743), (0x06fa1, 9733),
+    (0x06fa2, 16485), (0x06fa3, 16465), (0x06fa4, 9735), (0x06fa5, 16477), (0x06fa6, 9742), (0x06fa7, 9737), (0x06fa8, 16475),
+    (0x06fa9, 16971), (0x06faa, 16481), (0x06fab, 16487), (0x06fac, 16480), (0x06fad, 16463), (0x06fae, 16478), (0x06faf, 16489),
+    (0x06fb0, 16491), (0x06fb1, 9732), (0x06fb2, 16490), (0x06fb3, 9738), (0x06fb4, 9744), (0x06fb5, 4361), (0x06fb6, 9741),
+    (0x06fb8, 16484), (0x06fb9, 9740), (0x06fba, 16479), (0x06fbb, 3249), (0x06fbc, 16467), (0x06fbd, 16472), (0x06fbe, 2024),
+    (0x06fbf, 16483), (0x06fc0, 9739), (0x06fc1, 9736), (0x06fc2, 9731), (0x06fc3, 9734), (0x06fc4, 16471), (0x06fc6, 15855),
+    (0x06fc7, 16466), (0x06fc8, 16469), (0x06fc9, 16486), (0x06fca, 16474), (0x06fcb, 16464), (0x06fcc, 16970), (0x06fcd, 16488),
+    (0x06fce, 16468), (0x06fcf, 16482), (0x06fd3, 19582), (0x06fd4, 16973), (0x06fd5, 10020), (0x06fd8, 10008), (0x06fd9, 3435),
+    (0x06fda, 3434), (0x06fdb, 10012), (0x06fdc, 16975), (0x06fdd, 16981), (0x06fde, 16979), (0x06fdf, 10010), (0x06fe0, 10011),
+    (0x06fe1, 10018), (0x06fe2, 16982), (0x06fe3, 16974), (0x06fe4, 10013), (0x06fe6, 16978), (0x06fe7, 16977), (0x06fe8, 16983),
+    (0x06fe9, 10019), (0x06feb, 10014), (0x06fec, 10017), (0x06fed, 16976), (0x06fee, 10021), (0x06fef, 10015), (0x06ff0, 10022),
+    (0x06ff1, 10009), (0x06ff2, 16980), (0x06ff4, 16972), (0x06ff6, 2439), (0x06ff7, 17419), (0x06ff8, 19565), (0x06ffa, 10254),
+    (0x06ffb, 17416), (0x06ffc, 17418), (0x06ffe, 10252), (0x06fff, 17414), (0x07000, 17415), (0x07001, 17410), (0x07003, 3684),
+    (0x07004, 16476), (0x07005, 17411), (0x07006, 10253), (0x07007, 17407), (0x07009, 10250), (0x0700a, 17420), (0x0700b, 10251),
+    (0x0700c, 17408), (0x0700d, 17409), (0x0700e, 17413), (0x0700f, 10256), (0x07011, 10255), (0x07014, 17412), (0x07015, 10418),
+    (0x07016, 17772), (0x07017, 17778), (0x07018, 10419), (0x07019, 17769), (0x0701a, 10416), (0x0701b, 10413), (0x0701c, 17780),
+    (0x0701d, 10417), (0x0701e, 3437), (0x0701f, 10414), (0x07020, 17771), (0x07021, 17774), (0x07022, 17775), (0x07023, 17776),
+    (0x07024, 17779), (0x07026, 17417), (0x07027, 17770), (0x07028, 10415), (0x07029, 17777), (0x0702a, 17781), (0x0702b, 17773),
+    (0x0702c, 2030), (0x0702f, 18084), (0x07030, 10561), (0x07031, 18087), (0x07032, 10562), (0x07033, 18095), (0x07034, 18086),
+    (0x07035, 18083), (0x07037, 18085), (0x07038, 18089), (0x07039, 18092), (0x0703a, 18091), (0x0703b, 18094), (0x0703c, 18082),
+    (0x0703e, 10560), (0x0703f, 18090), (0x07040, 18093), (0x07041, 18096), (0x07042, 18088), (0x07043, 18325), (0x07044, 18326),
+    (0x07045, 18330), (0x07046, 18331), (0x07048, 18328), (0x07049, 18329), (0x0704a, 18327), (0x0704b, 19594), (0x0704c, 10654),
+    (0x0704d, 1766), (0x07050, 2032), (0x07051, 10732), (0x07052, 18506), (0x07054, 2033), (0x07055, 18503), (0x07056, 18504),
+    (0x07057, 18505), (0x07058, 10733), (0x0705a, 18657), (0x0705b, 18656), (0x0705c, 2350), (0x0705d, 18760), (0x0705e, 10822),
+    (0x0705f, 18758), (0x07060, 18761), (0x07061, 18759), (0x07062, 18830), (0x07063, 10856), (0x07064, 10870), (0x07065, 18905),
+    (0x07066, 18884), (0x07067, 3564), (0x07068, 18904), (0x07069, 18950), (0x0706a, 18951), (0x0706b, 5642), (0x0706c, 1682),
+    (0x0706f, 2034), (0x07070, 5910), (0x07071, 11462), (0x07074, 11648), (0x07075, 2474), (0x07076, 6154), (0x07077, 1734),
+    (0x07078, 6157), (0x07079, 19139), (0x0707a, 11649), (0x0707c, 6155), (0x0707d, 6156), (0x0707e, 19595), (0x0707f, 2035),
+    (0x07081, 19597), (0x07082, 11956), (0x07083, 11958), (0x07084, 11953), (0x07085, 11950), (0x07086, 11952), (0x07089, 2036),
+    (0x0708a, 6503), (0x0708b, 19189), (0x0708e, 6501), (0x0708f, 2423), (0x07091, 11954), (0x07092, 6502), (0x07093, 11951),
+    (0x07094, 11948), (0x07095, 6500), (0x07096, 11955), (0x07098, 11949), (0x07099, 6504), (0x0709a, 11957), (0x0709f, 12323),
+    (0x070a0, 3816), (0x070a1, 12327), (0x070a3, 2649), (0x070a4, 6836), (0x070a5, 3502), (0x070a6, 3658), (0x070a7, 19596),
+    (0x070a9, 12330), (0x070ab, 6828), (0x070ac, 6831), (0x070ad, 6833), (0x070ae, 6835), (0x070af, 6832), (0x070b0, 12326),
+    (0x070b1, 12325), (0x070b3, 6830), (0x070b4, 12328), (0x070b5, 12329), (0x070b7, 12322), (0x070b8, 6834), (0x070b9, 3755),
+    (0x070ba, 6829), (0x070bb, 4079), (0x070bc, 1300), (0x070bd, 19623), (0x070be, 12324), (0x070c0, 3798), (0x070c4, 3231),
+    (0x070c5, 12772), (0x070c6, 12773), (0x070c7, 12774), (0x070c8, 7238), (0x070ca, 7234), (0x070cb, 12763), (0x070cc, 19598),
+    (0x070cd, 12771), (0x070ce, 12776), (0x070cf, 7239), (0x070d0, 2644), (0x070d1, 12761), (0x070d2, 12767), (0x070d3, 12760),
+    (0x070d4, 12770), (0x070d5, 19599), (0x070d6, 19600), (0x070d7, 12766), (0x070d8, 7235), (0x070d9, 7237), (0x070da, 12775),
+    (0x070dc, 12759), (0x070dd, 12762), (0x070de, 12768), (0x070df, 19601), (0x070e0, 12769), (0x070e1, 12777), (0x070e2, 12765),
+    (0x070e4, 7236), (0x070ef, 7738), (0x070f0, 13359), (0x070f1, 3003), (0x070f3, 13361), (0x070f4, 13357), (0x070f5, 3854),
+    (0x070f6, 13369), (0x070f7, 13355), (0x070f8, 13368), (0x070f9, 7734), (0x070fa, 13353), (0x070fb, 14000), (0x070fc, 13363),
+    (0x070fd, 7737), (0x070fe, 3589), (0x070ff, 13364), (0x07100, 13367), (0x07102, 13371), (0x07104, 13360), (0x07105, 2832),
+    (0x07106, 13365), (0x07109, 7735), (0x0710a, 7736), (0x0710b, 13370), (0x0710c, 13358), (0x0710d, 13354), (0x0710e, 13372),
+    (0x07110, 13362), (0x07113, 13366), (0x07117, 13356), (0x07119, 8172), (0x0711a, 8173), (0x0711b, 14010), (0x0711c, 8179),
+    (0x0711d, 3646), (0x0711e, 13998), (0x0711f, 14007), (0x07120, 13997), (0x07121, 8176), (0x07122, 14005), (0x07123, 14003),
+    (0x07125, 14004), (0x07126, 8174), (0x07128, 14008), (0x07129, 3230), (0x0712b, 19607), (0x0712c, 19617), (0x0712e, 14001),
+    (0x0712f, 13999), (0x07130, 8175), (0x07131, 14002), (0x07132, 14006), (0x07133, 3659), (0x07134, 3445), (0x07135, 2112),
+    (0x07136, 8177), (0x0713a, 14009), (0x0713b, 3000), (0x0713e, 2044), (0x07140, 1596), (0x07141, 14649), (0x07142, 14656),
+    (0x07143, 14658), (0x07144, 14664), (0x07145, 19608), (0x07146, 8598), (0x07147, 14644), (0x07149, 8590), (0x0714a, 19610),
+    (0x0714b, 14659), (0x0714c, 8595), (0x0714d, 14665), (0x0714e, 8586), (0x0714f, 19614), (0x07150, 14662), (0x07151, 4229),
+    (0x07152, 14646), (0x07153, 14663), (0x07154, 14645), (0x07156, 8600), (0x07157, 1867), (0x07158, 14657), (0x07159, 8587),
+    (0x0715a, 14666), (0x0715c, 8592), (0x0715d, 14650), (0x0715e, 8597), (0x0715f, 14661), (0x07160, 14648), (0x07161, 14655),
+    (0x07162, 14651), (0x07163, 14647), (0x07164, 8589), (0x07165, 8596), (0x07166, 8594), (0x07167, 8591), (0x07168, 8599),
+    (0x07169, 8588), (0x0716a, 14654), (0x0716b, 3516), (0x0716c, 8593), (0x0716e, 8178), (0x07170, 14660), (0x07171, 4197),
+    (0x07172, 14652), (0x07173, 4022), (0x07175, 3556), (0x07176, 4988), (0x07177, 2622), (0x07178, 14653), (0x0717a, 4840),
+    (0x0717b, 15324), (0x0717c, 2321), (0x0717d, 9031), (0x0717e, 2647), (0x07180, 15320), (0x07181, 15326), (0x07182, 15322),
+    (0x07184, 9033), (0x07185, 15321), (0x07186, 15325), (0x07187, 15317), (0x07188, 3737), (0x07189, 15319), (0x0718a, 9032),
+    (0x0718c, 2773), (0x0718e, 3634), (0x0718f, 15323), (0x07190, 15318), (0x07191, 1744), (0x07192, 9034), (0x07194, 9029),
+    (0x07196, 19605), (0x07197, 15327), (0x07198, 4091), (0x07199, 9030), (0x0719a, 15896), (0x0719b, 15893), (0x0719c, 15905),
+    (0x0719d, 15899), (0x0719e, 15901), (0x0719f, 9409), (0x071a0, 15895), (0x071a1, 15903), (0x071a2, 4450), (0x071a3, 4630),
+    (0x071a4, 15902), (0x071a5, 15900), (0x071a7, 15906), (0x071a8, 9412), (0x071a9, 15897), (0x071aa, 15904), (0x071ac, 9410),
+    (0x071ad, 4345), (0x071af, 15892), (0x071b0, 15894), (0x071b1, 9411), (0x071b2, 15891), (0x071b3, 15907), (0x071b4, 19604),
+    (0x071b5, 15898), (0x071b7, 2027), (0x071b8, 16495), (0x071b9, 9751), (0x071ba, 19621), (0x071bc, 16506), (0x071bd, 16504),
+    (0x071be, 9745), (0x071bf, 16494), (0x071c0, 16497), (0x071c1, 16498), (0x071c2, 16493), (0x071c3, 9755), (0x071c4, 9756),
+    (0x071c5, 16492), (0x071c6, 16507), (0x071c7, 16502), (0x071c8, 9749), (0x071c9, 9746), (0x071ca, 16501), (0x071cb, 16499),
+    (0x071ce, 9752), (0x071cf, 16503), (0x071d0, 9747), (0x071d1, 2060), (0x071d2, 9748), (0x071d4, 16500), (0x071d5, 9750),
+    (0x071d6, 16496), (0x071d8, 16505), (0x071d9, 9753), (0x071da, 16508), (0x071db, 16509), (0x071dc, 9754), (0x071dd, 19106),
+    (0x071df, 10024), (0x071e0, 10031), (0x071e1, 16984), (0x071e2, 16990), (0x071e4, 16988), (0x071e5, 10027), (0x071e6, 10026),
+    (0x071e7, 10023), (0x071e8, 16986), (0x071eb, 3448), (0x071ec, 10029), (0x071ed, 10028), (0x071ee, 10025), (0x071f0, 16989),
+    (0x071f1, 16985), (0x071f2, 16987), (0x071f4, 10030), (0x071f5, 3704), (0x071f6, 4562), (0x071f8, 10260), (0x071f9, 17423),
+    (0x071fb, 10257), (0x071fc, 10258), (0x071fd, 17425), (0x071fe, 10259), (0x071ff, 17422), (0x07200, 1796), (0x07201, 17421),
+    (0x07202, 17785), (0x07203, 17424), (0x07205, 17786), (0x07206, 10420), (0x07207, 17784), (0x07209, 4186), (0x0720a, 17783),
+    (0x0720c, 17782), (0x0720d, 10421), (0x0720e, 19624), (0x0720f, 2791), (0x07210, 10563), (0x07213, 18097), (0x07214, 18098),
+    (0x07215, 19626), (0x07216, 2618), (0x07217, 2640), (0x07219, 18334), (0x0721a, 18333), (0x0721b, 10655), (0x0721d, 18332),
+    (0x0721e, 18507), (0x0721f, 18508), (0x07222, 18658), (0x07223, 18762), (0x07224, 19629), (0x07225, 973), (0x07226, 18831),
+    (0x07227, 18930), (0x07228, 10890), (0x07229, 18940), (0x0722a, 5643), (0x0722b, 1683), (0x0722c, 6505), (0x0722d, 6506),
+    (0x0722e, 19632), (0x07230, 6837), (0x07235, 10032), (0x07236, 5644), (0x07238, 6507), (0x07239, 7240), (0x0723a, 8601),
+    (0x0723b, 5645), (0x0723d, 7739), (0x0723e, 9035), (0x0723f, 11338), (0x07240, 19633), (0x07241, 12331), (0x07242, 12778),
+    (0x07244, 15328), (0x07246, 10033), (0x07247, 5646), (0x07248, 6508), (0x07249, 12332), (0x0724a, 12333), (0x0724b, 14011),
+    (0x0724c, 8180), (0x0724f, 14667), (0x07250, 3004), (0x07252, 8602), (0x07253, 15329), (0x07255, 19636), (0x07256, 9413),
+    (0x07257, 19637), (0x07258, 10422), (0x07259, 5647), (0x0725a, 14012), (0x0725b, 5648), (0x0725c, 1977), (0x0725d, 5912),
+    (0x0725e, 11463), (0x0725f, 5911), (0x07260, 6160), (0x07261, 6159), (0x07262, 6158), (0x07263, 11650), (0x07266, 4609),
+    (0x07267, 6509), (0x07269, 6510), (0x0726a, 11959), (0x0726c, 12334), (0x0726e, 12337), (0x0726f, 6839), (0x07270, 12335),
+    (0x07272, 6838), (0x07273, 12336), (0x07274, 6840), (0x07276, 12781), (0x07277, 12780), (0x07278, 12779), (0x07279, 7241),
+    (0x0727b, 13374), (0x0727c, 13375), (0x0727d, 7740), (0x0727e, 13373), (0x0727f, 13376), (0x07280, 8182), (0x07281, 7741),
+    (0x07282, 19642), (0x07284, 8181), (0x07285, 14016), (0x07286, 14015), (0x07287, 3005), (0x07288, 14013), (0x07289, 14014),
+    (0x0728b, 14017), (0x0728c, 14669), (0x0728d, 14668), (0x0728e, 14672), (0x0728f, 4429), (0x07290, 14671), (0x07291, 14670),
+    (0x07292, 9036), (0x07293, 15332), (0x07294, 3006), (0x07295, 15331), (0x07296, 9037), (0x07297, 15330), (0x07298, 15908),
+    (0x0729a, 15909), (0x0729b, 9414), (0x0729d, 16510), (0x0729e, 16511), (0x0729f, 3806), (0x072a1, 17791), (0x072a2, 10423),
+    (0x072a3, 17790), (0x072a4, 17789), (0x072a5, 17787), (0x072a6, 17788), (0x072a7, 10656), (0x072a8, 18099), (0x072a9, 18509),
+    (0x072aa, 18832), (0x072ac, 5649), (0x072ad, 1685), (0x072ae, 11371), (0x072af, 5753), (0x072b0, 11372), (0x072b2, 4908),
+    (0x072b4, 11464), (0x072b5, 11465), (0x072ba, 11656), (0x072bd, 11652), (0x072bf, 11651), (0x072c0, 6511), (0x072c1, 11655),
+    (0x072c2, 6162), (0x072c3, 11653), (0x072c4, 6161), (0x072c5, 11657), (0x072c6, 11654), (0x072c9, 11963), (0x072ca, 12338),
+    (0x072cb, 11961), (0x072cc, 11968), (0x072cd, 3693), (0x072ce, 6512), (0x072d0, 6515), (0x072d1, 11969), (0x072d2, 11965),
+    (0x072d4, 11966), (0x072d6, 11960), (0x072d7, 6514), (0x072d8, 11962), (0x072d9, 6513), (0x072da, 11967), (0x072dc, 11964),
+    (0x072df, 12342), (0x072e0, 6842), (0x072e1, 6843), (0x072e2, 4876), (0x072e3, 12345), (0x072e4, 12339), (0x072e6, 12344),
+    (0x072e8, 12340), (0x072e9, 6841), (0x072ea, 12343), (0x072eb, 12341), (0x072f3, 12787), (0x072f4, 12784), (0x072f6, 12786),
+    (0x072f7, 7246), (0x072f8, 7245), (0x072f9, 7243), (0x072fa, 12783), (0x072fb, 12788), (0x072fc, 7242), (0x072fd, 7244),
+    (0x072fe, 12785), (0x072ff, 13384), (0x07300, 12782), (0x07301, 12789), (0x07302, 4879), (0x07304, 4590), (0x07307, 13379),
+    (0x07308, 13383), (0x0730a, 13382), (0x0730b, 14019), (0x0730c, 14030), (0x0730f, 13385), (0x07310, 3694), (0x07311, 13380),
+    (0x07312, 14018), (0x07313, 7745), (0x07316, 7744), (0x07317, 13378), (0x07318, 13381), (0x07319, 7746), (0x0731b, 7743),
+    (0x0731c, 7742), (0x0731d, 13377), (0x0731e, 13386), (0x07322, 14021), (0x07323, 14028), (0x07325, 8184), (0x07326, 14027),
+    (0x07327, 14024), (0x07328, 19649), (0x07329, 8186), (0x0732a, 3821), (0x0732b, 19644), (0x0732c, 4904), (0x0732d, 14026),
+    (0x0732e, 19650), (0x07330, 14020), (0x07331, 14022), (0x07332, 14025), (0x07333, 14023), (0x07334, 8185), (0x07335, 14029),
+    (0x07336, 8183), (0x07337, 8603), (0x07338, 3696), (0x07339, 3697), (0x0733a, 14676), (0x0733b, 14675), (0x0733c, 14673),
+    (0x0733e, 8606), (0x0733f, 8605), (0x07340, 14677), (0x07341, 3699), (0x07342, 14674), (0x07343, 15333), (0x07344, 9038),
+    (0x07345, 8604), (0x07348, 3700), (0x07349, 14679), (0x0734a, 14678), (0x0734c, 15336), (0x0734d, 15334), (0x0734e, 9415),
+    (0x0734f, 4800), (0x07350, 9039), (0x07351, 15335), (0x07352, 15911), (0x07357, 9416), (0x07358, 15910), (0x07359, 15919),
+    (0x0735a, 15918), (0x0735b, 15916), (0x0735d, 15915), (0x0735e, 15912), (0x0735f, 15913), (0x07360, 15914), (0x07361, 15917),
+    (0x07362, 15920), (0x07365, 16516), (0x07366, 16513), (0x07367, 16514), (0x07368, 9757), (0x07369, 16512), (0x0736a, 16518),
+    (0x0736b, 16517), (0x0736c, 16515), (0x0736e, 16992), (0x0736f, 16993), (0x07370, 10034), (0x07371, 4877), (0x07372, 10035),
+    (0x07373, 16991), (0x07374, 4654), (0x07375, 10262), (0x07376, 17426), (0x07377, 10261), (0x07378, 10424), (0x0737a, 10425),
+    (0x0737b, 10564), (0x0737c, 18101), (0x0737d, 18100), (0x0737e, 18335), (0x0737f, 18510), (0x07380, 10734), (0x07381, 18660),
+    (0x07382, 18659), (0x07383, 18661), (0x07384, 5754), (0x07385, 12346), (0x07386, 7247), (0x07387, 7747), (0x07388, 13387),
+    (0x07389, 5755), (0x0738a, 11373), (0x0738b, 5650), (0x0738c, 1798), (0x0738e, 11466), (0x0738f, 3687), (0x07392, 11662),
+    (0x07393, 11660), (0x07394, 11661), (0x07395, 11658), (0x07396, 6163), (0x07397, 11659), (0x07398, 2785), (0x0739c, 19124),
+    (0x0739d, 11977), (0x0739e, 2626), (0x0739f, 6518), (0x073a0, 11975), (0x073a1, 11971), (0x073a2, 11974), (0x073a4, 11970),
+    (0x073a5, 6520), (0x073a6, 11973), (0x073a7, 19121), (0x073a8, 6517), (0x073a9, 6516), (0x073aa, 19652), (0x073ab, 6519),
+    (0x073ac, 11976), (0x073ad, 11972), (0x073b2, 6847), (0x073b3, 6850), (0x073b4, 12354), (0x073b5, 12353), (0x073b6, 12352),
+    (0x073b7, 6844), (0x073b8, 12361), (0x073b9, 12351), (0x073ba, 1746), (0x073bb, 6846), (0x073bc, 12794), (0x073be, 12358),
+    (0x073bf, 12356), (0x073c0, 6849), (0x073c2, 12348), (0x073c3, 12359), (0x073c5, 12350), (0x073c6, 12360), (0x073c7, 12357),
+    (0x073c8, 12349), (0x073c9, 19655), (0x073ca, 6845), (0x073cb, 12362), (0x073cc, 12347), (0x073cd, 6848), (0x073ce, 19271),
+    (0x073cf, 19651), (0x073d0, 3082), (0x073d2, 12799), (0x073d3, 12790), (0x073d4, 12801), (0x073d5, 19101), (0x073d6, 12793),
+    (0x073d7, 12804), (0x073d8, 12805), (0x073d9, 12791), (0x073da, 12803), (0x073db, 12800), (0x073dc, 12798), (0x073dd, 12802),
+    (0x073de, 7253), (0x073e0, 7251), (0x073e1, 3706), (0x073e2, 3482), (0x073e3, 12796), (0x073e4, 19677), (0x073e5, 12792),
+    (0x073e6, 3505), (0x073e7, 12795), (0x073e8, 12806), (0x073e9, 12797), (0x073ea, 7252), (0x073eb, 12355), (0x073ed, 7248),
+    (0x073ee, 7250), (0x073ef, 19003), (0x073f3, 3490), (0x073f4, 13401), (0x073f5, 13390), (0x073f6, 13388), (0x073f7, 19665),
+    (0x073f8, 13389), (0x073f9, 3311), (0x073fa, 13396), (0x073fb, 3491), (0x073fc, 13397), (0x073fd, 13393), (0x073fe, 7752),
+    (0x073ff, 13398), (0x07400, 13395), (0x07401, 13392), (0x07402, 1753), (0x07403, 7750), (0x07404, 13391), (0x07405, 7748),
+    (0x07406, 7751), (0x07407, 13394), (0x07408, 13402), (0x07409, 7249), (0x0740a, 7749), (0x0740b, 13400), (0x0740c, 13399),
+    (0x0740d, 7753), (0x07411, 1319), (0x07412, 3292), (0x07414, 2783), (0x07415, 19666), (0x07416, 14035), (0x07417, 19159),
+    (0x07419, 3713), (0x0741a, 14036), (0x0741b, 8196), (0x0741c, 2496), (0x0741d, 14042), (0x0741e, 3714), (0x0741f, 3715),
+    (0x07420, 14044), (0x07421, 14037), (0x07422, 8190), (0x07423, 14041), (0x07424, 14040), (0x07425, 8191), (0x07426, 8197),
+    (0x07428, 8198), (0x07429, 14043), (0x0742a, 8188), (0x0742b, 14034), (0x0742c, 14032), (0x0742d, 14038), (0x0742e, 14031),
+    (0x0742f, 8195), (0x07430, 14033), (0x07431, 14039), (0x07432, 14045), (0x07433, 8189), (0x07434, 8194), (0x07435, 8192),
+    (0x07436, 8193), (0x07437, 3028), (0x07438, 3068), (0x07439, 19669), (0x0743a, 8187), (0x0743c, 3026), (0x0743f, 8613),
+    (0x07440, 14686), (0x07441, 8612), (0x07442, 14690), (0x07443, 2823), (0x07444, 14680), (0x07445, 3277), (0x07446, 14691),
+    (0x07447, 19676), (0x07448, 3500), (0x07449, 19656), (0x0744a, 14681), (0x0744b, 14682), (0x0744d, 14692), (0x0744e, 14689),
+    (0x0744f, 14687), (0x07450, 14688), (0x07451, 14684), (0x07452, 14683), (0x07453, 19559), (0x07454, 14693), (0x07455, 8609),
+    (0x07456, 2784), (0x07457, 14685), (0x07459, 8614), (0x0745a, 8608), (0x0745b, 8615), (0x0745c, 8616), (0x0745d, 19712),
+    (0x0745e, 8611), (0x0745f, 8610), (0x07460, 19674), (0x07461, 998), (0x07462, 15337), (0x07463, 9041), (0x07464, 9040),
+    (0x07465, 19243), (0x07467, 15342), (0x07468, 3041), (0x07469, 9417), (0x0746a, 9042), (0x0746b, 3293), (0x0746c, 19680),
+    (0x0746d, 9044), (0x0746e, 15343), (0x0746f, 8607), (0x07470, 9043), (0x07471, 15339), (0x07472, 15341), (0x07473, 15338),
+    (0x07474, 19682), (0x07475, 15340), (0x07476, 19678), (0x07479, 15930), (0x0747a, 3012), (0x0747c, 15929), (0x0747d, 15926),
+    (0x0747e, 9420), (0x0747f, 16519), (0x07480, 9421), (0x07481, 15925), (0x07482, 19685), (0x07483, 9419), (0x07485, 15927),
+    (0x07486, 15924), (0x07487, 15921), (0x07488, 15928), (0x07489, 15922), (0x0748a, 15923), (0x0748b, 9418), (0x0748c, 2830),
+    (0x0748d, 2109), (0x07490, 16997), (0x07492, 16523), (0x07494, 16522), (0x07495, 16524), (0x07497, 16994), (0x07498, 9760),
+    (0x07499, 2782), (0x0749a, 16520), (0x0749b, 3069), (0x0749c, 9758), (0x0749d, 999), (0x0749e, 9762), (0x0749f, 9761),
+    (0x074a0, 16521), (0x074a1, 16525), (0x074a3, 9759), (0x074a4, 19360), (0x074a5, 17001), (0x074a6, 10038), (0x074a7, 10263),
+    (0x074a8, 10039), (0x074a9, 10036), (0x074aa, 16998), (0x074ab, 16996), (0x074ad, 16999), (0x074af, 17002), (0x074b0, 10037),
+    (0x074b1, 17000), (0x074b2, 16995), (0x074b4, 3484), (0x074b5, 17429), (0x074b6, 17432), (0x074b7, 17794), (0x074b8, 17427),
+    (0x074b9, 1802), (0x074ba, 18102), (0x074bb, 17433), (0x074bd, 10426), (0x074be, 17431), (0x074bf, 10264), (0x074c0, 17428),
+    (0x074c1, 17430), (0x074c2, 17434), (0x074c3, 17795), (0x074c5, 17793), (0x074c8, 19694), (0x074ca, 10427), (0x074cb, 17792),
+    (0x074cc, 3025), (0x074cf, 10565), (0x074d0, 2955), (0x074d3, 3720), (0x074d4, 10658), (0x074d5, 18512), (0x074d6, 10657),
+    (0x074d7, 18514), (0x074d8, 18511), (0x074d9, 18513), (0x074da, 10786), (0x074db, 18763), (0x074dc, 5756), (0x074dd, 11978),
+    (0x074de, 12807), (0x074df, 12808), (0x074e0, 7754), (0x074e1, 14694), (0x074e2, 9763), (0x074e3, 10428), (0x074e4, 10735),
+    (0x074e5, 18764), (0x074e6, 5757), (0x074e7, 4986), (0x074e8, 11979), (0x074e9, 5213), (0x074ec, 12363), (0x074ee, 12364),
+    (0x074f0, 4960), (0x074f1, 4880), (0x074f2, 4953), (0x074f4, 12809), (0x074f5, 12810), (0x074f6, 7755), (0x074f7, 7756),
+    (0x074f8, 4975), (0x074fb, 14046), (0x074fd, 14697), (0x074fe, 14696), (0x074ff, 14695), (0x07500, 15344), (0x07502, 15345),
+    (0x07503, 15346), (0x07504, 9045), (0x07505, 4952), (0x07507, 15932), (0x07508, 15931), (0x0750b, 16526), (0x0750c, 9764),
+    (0x0750d, 9765), (0x0750e, 19696), (0x0750f, 17006), (0x07510, 17003), (0x07511, 17004), (0x07512, 17005), (0x07513, 17436),
+    (0x07514, 17435), (0x07515, 10265), (0x07516, 17796), (0x07517, 18336), (0x07518, 5758), (0x07519, 1353), (0x0751a, 6851),
+    (0x0751c, 7757), (0x0751d, 14698), (0x0751e, 19698), (0x0751f, 5759), (0x07521, 12811), (0x07522, 7758), (0x07525, 8199),
+    (0x07526, 8200), (0x07528, 5760), (0x07529, 5761), (0x0752a, 11467), (0x0752b, 6165), (0x0752c, 6164), (0x0752d, 6852),
+    (0x0752e, 12365), (0x0752f, 14047), (0x07530, 5762), (0x07531, 5763), (0x07532, 5764), (0x07533, 5765), (0x07534, 4542),
+    (0x07535, 1301), (0x07537, 6166), (0x07538, 6167), (0x07539, 11664), (0x0753a, 11663), (0x0753b, 4298), (0x0753d, 6521),
+    (0x0753e, 11982), (0x0753f, 11980), (0x07540, 11981), (0x07542, 3723), (0x07546, 4781), (0x07547, 12366), (0x07548, 12367),
+    (0x0754a, 19705), (0x0754b, 6856), (0x0754c, 6854), (0x0754d, 19704), (0x0754e, 6855), (0x0754f, 6853), (0x07551, 3573),
+    (0x07553, 4965), (0x07554, 7254), (0x07555, 3037), (0x07559, 7258), (0x0755a, 7257), (0x0755b, 12812), (0x0755c, 7256),
+    (0x0755d, 7255), (0x0755f, 12813), (0x07560, 3824), (0x07562, 7761), (0x07563, 13404), (0x07564, 13403), (0x07565, 7759),
+    (0x07566, 7760), (0x07567, 19706), (0x0756a, 8202), (0x0756b, 8201), (0x0756c, 14049), (0x0756d, 3724), (0x0756e, 19707),
+    (0x0756f, 14048), (0x07570, 7762), (0x07572, 3725), (0x07576, 8617), (0x07577, 14700), (0x07578, 8618), (0x07579, 14699),
+    (0x0757a, 4797), (0x0757d, 15347), (0x0757e, 15933), (0x0757f, 9422), (0x07580, 16527), (0x07583, 3032), (0x07584, 17007),
+    (0x07586, 10430), (0x07587, 10429), (0x0758a, 10736), (0x0758b, 5766), (0x0758c, 11983), (0x0758d, 3726), (0x0758e, 19711),
+    (0x0758f, 7763), (0x07590, 15348), (0x07591, 9046), (0x07592, 10947), (0x07594, 11665), (0x07595, 11666), (0x07598, 11984),
+    (0x07599, 6523), (0x0759a, 6524), (0x0759d, 6522), (0x0759e, 19713), (0x075a2, 6860), (0x075a3, 6861), (0x075a4, 6858),
+    (0x075a5, 6859), (0x075a7, 12368), (0x075aa, 12369), (0x075ab, 6857), (0x075b0, 12814), (0x075b1, 4581), (0x075b2, 7262),
+    (0x075b3, 7263), (0x075b4, 19714), (0x075b5, 7766), (0x075b6, 12820), (0x075b8, 7268), (0x075b9, 7266), (0x075ba, 12821),
+    (0x075bb, 12816), (0x075bc, 7265), (0x075bd, 7264), (0x075be, 7259), (0x075bf, 12819), (0x075c0, 12818), (0x075c1, 12815),
+    (0x075c2, 7267), (0x075c3, 4871), (0x075c4, 12817), (0x075c5, 7260), (0x075c7, 7261), (0x075c8, 3728), (0x075ca, 7767),
+    (0x075cb, 13408), (0x075cc, 13409), (0x075cd, 7768), (0x075ce, 13405), (0x075cf, 13407), (0x075d0, 13411), (0x075d1, 13410),
+    (0x075d2, 13406), (0x075d4, 7764), (0x075d5, 7765), (0x075d7, 14057), (0x075d8, 8207), (0x075d9, 8206), (0x075da, 14051),
+    (0x075db, 8204), (0x075dc, 3729), (0x075dd, 14054), (0x075de, 8208), (0x075df, 14055), (0x075e0, 8209), (0x075e1, 14052),
+    (0x075e2, 8203), (0x075e3, 8205), (0x075e4, 14056), (0x075e6, 14053), (0x075e7, 14050), (0x075ed, 14713), (0x075ef, 14702),
+    (0x075f0, 8620), (0x075f1, 8623), (0x075f2, 8622), (0x075f3, 8627), (0x075f4, 8626), (0x075f5, 14714), (0x075f6, 14712),
+    (0x075f7, 14705), (0x075f8, 14709), (0x075f9, 14708), (0x075fa, 8624), (0x075fb, 14711), (0x075fc, 14707), (0x075fd, 14715),
+    (0x075fe, 14706), (0x075ff, 8625), (0x07600, 8619), (0x07601, 8621), (0x07602, 19715), (0x07603, 14704), (0x07607, 4866),
+    (0x07608, 15350), (0x07609, 9050), (0x0760a, 15354), (0x0760b, 9049), (0x0760c, 15351), (0x0760d, 9048), (0x0760f, 14703),
+    (0x07610, 14710), (0x07611, 15353), (0x07613, 9051), (0x07614, 15355), (0x07615, 15352), (0x07616, 15349), (0x07619, 15936),
+    (0x0761a, 15940), (0x0761b, 15942), (0x0761c, 15938), (0x0761d, 15937), (0x0761e, 15935), (0x0761f, 9425), (0x07620, 9423),
+    (0x07621, 9428), (0x07622, 9429), (0x07623, 15939), (0x07624, 9426), (0x07625, 15934), (0x07626, 9427), (0x07627, 9047),
+    (0x07628, 15941), (0x07629, 9424), (0x0762c, 19716), (0x0762d, 16529), (0x0762f, 16528), (0x07630, 16536), (0x07631, 16530),
+    (0x07632, 16535), (0x07633, 16532), (0x07634, 9766), (0x07635, 16534), (0x07638, 9767), (0x0763a, 9768), (0x0763b, 4519),
+    (0x0763c, 16533), (0x0763d, 16531), (0x07640, 4680), (0x07642, 10041), (0x07643, 17008), (0x07646, 10040), (0x07647, 17011),
+    (0x07648, 17009), (0x07649, 17010), (0x0764c, 10042), (0x0764d, 3731), (0x0764e, 4521), (0x0764f, 19718), (0x07650, 17440),
+    (0x07651, 19717), (0x07652, 10268), (0x07653, 17441), (0x07654, 4690), (0x07656, 10266), (0x07657, 17442), (0x07658, 10267),
+    (0x07659, 17439), (0x0765a, 17443), (0x0765c, 17437), (0x0765f, 10431), (0x07660, 17797), (0x07661, 10432), (0x07662, 10566),
+    (0x07664, 17438), (0x07665, 10567), (0x07666, 4578), (0x07667, 4895), (0x07669, 10659), (0x0766a, 18337), (0x0766c, 10738),
+    (0x0766d, 18515), (0x0766e, 10737), (0x0766f, 19719), (0x07670, 18662), (0x07671, 10823), (0x07672, 10824), (0x07673, 3038),
+    (0x07674, 3733), (0x07675, 18946), (0x07676, 19720), (0x07678, 6862), (0x07679, 12370), (0x0767a, 3735), (0x0767b, 8210),
+    (0x0767c, 8211), (0x0767d, 5767), (0x0767e, 5913), (0x0767f, 11468), (0x07681, 11667), (0x07682, 6168), (0x07684, 6525),
+    (0x07686, 6863), (0x07687, 6864), (0x07688, 6865), (0x07689, 13413), (0x0768a, 12822), (0x0768b, 7269), (0x0768e, 7769),
+    (0x0768f, 13412), (0x07690, 19722), (0x07692, 14059), (0x07693, 8213), (0x07695, 14058), (0x07696, 8212), (0x07699, 14716),
+    (0x0769a, 9430), (0x0769b, 15946), (0x0769c, 15943), (0x0769d, 15944), (0x0769e, 15945), (0x076a1, 19727), (0x076a4, 17012),
+    (0x076a5, 19728), (0x076a6, 17444), (0x076aa, 18104), (0x076ab, 18103), (0x076ad, 18516), (0x076ae, 5768), (0x076af, 11985),
+    (0x076b0, 7270), (0x076b4, 8214), (0x076b5, 14717), (0x076b7, 19729), (0x076b8, 15356), (0x076ba, 9431), (0x076bb, 16537),
+    (0x076bd, 17445), (0x076be, 18105), (0x076bf, 5769), (0x076c2, 6526), (0x076c3, 6868), (0x076c4, 12371), (0x076c5, 6869),
+    (0x076c6, 6867), (0x076c8, 6866), (0x076c9, 12823), (0x076ca, 7271), (0x076cc, 19730), (0x076cd, 7272), (0x076ce, 7273),
+    (0x076d2, 7771), (0x076d3, 13414), (0x076d4, 7770), (0x076d6, 4806), (0x076d9, 1770), (0x076da, 14060), (0x076db, 7772),
+    (0x076dc, 8215), (0x076dd, 14718), (0x076de, 8628), (0x076df, 8629), (0x076e1, 9052), (0x076e3, 9053), (0x076e4, 9432),
+    (0x076e5, 9770), (0x076e6, 16538), (0x076e7, 9769), (0x076e9, 17013), (0x076ea, 10043), (0x076ec, 17446), (0x076ed, 18106),
+    (0x076ee, 5770), (0x076ef, 6169), (0x076f0, 11988), (0x076f1, 11987), (0x076f2, 6527), (0x076f3, 11986), (0x076f4, 6528),
+    (0x076f5, 11989), (0x076f7, 12377), (0x076f8, 6872), (0x076f9, 6871), (0x076fa, 12379), (0x076fb, 12378), (0x076fc, 6876),
+    (0x076fe, 6875), (0x07701, 6870), (0x07703, 12373), (0x07704, 12374), (0x07705, 12375), (0x07707, 6877), (0x07708, 12372),
+    (0x07709, 6873), (0x0770a, 12376), (0x0770b, 6874), (0x0770c, 3709), (0x0770e, 3061), (0x0770f, 3062), (0x07710, 12826),
+    (0x07711, 12830), (0x07712, 12828), (0x07713, 12827), (0x07715, 12831), (0x07719, 12832), (0x0771a, 12833), (0x0771b, 12825),
+    (0x0771d, 12824), (0x0771e, 19736), (0x0771f, 7275), (0x07720, 7276), (0x07722, 12834), (0x07723, 12829), (0x07724, 4962),
+    (0x07725, 13423), (0x07726, 19737), (0x07727, 12835), (0x07728, 7277), (0x07729, 7274), (0x0772b, 4974), (0x0772d, 13417),
+    (0x0772f, 13416), (0x07731, 13418), (0x07732, 13419), (0x07733, 13421), (0x07734, 13420), (0x07735, 13425), (0x07736, 7776),
+    (0x07737, 7773), (0x07738, 7777), (0x07739, 13415), (0x0773a, 7778), (0x0773b, 13424), (0x0773c, 7775), (0x0773d, 13422),
+    (0x0773e, 7774), (0x07740, 19738), (0x07743, 3742), (0x07744, 14063), (0x07745, 14065), (0x07746, 14061), (0x07747, 14062),
+    (0x0774a, 14066), (0x0774b, 14068), (0x0774c, 14069), (0x0774d, 14064), (0x0774e, 14067), (0x0774f, 8216), (0x07752, 14722),
+    (0x07754, 14727), (0x07755, 14719), (0x07756, 14723), (0x07758, 19741), (0x07759, 14728), (0x0775a, 14724), (0x0775b, 8630),
+    (0x0775c, 8638), (0x0775e, 8633), (0x0775f, 14720), (0x07760, 14721), (0x07761, 9057), (0x07762, 8641), (0x07763, 8634),
+    (0x07765, 8639), (0x07766, 8632), (0x07767, 14726), (0x07768, 8640), (0x07769, 14725), (0x0776a, 8636), (0x0776b, 8631),
+    (0x0776c, 8637), (0x0776d, 14729), (0x0776e, 15361), (0x0776f, 15363), (0x07772, 2642), (0x07777, 2299), (0x07778, 3059),
+    (0x07779, 8635), (0x0777a, 1428), (0x0777b, 3063), (0x0777c, 15358), (0x0777d, 9055), (0x0777e, 15364), (0x0777f, 9056),
+    (0x07780, 15362), (0x07781, 15357), (0x07782, 15360), (0x07783, 15365), (0x07784, 9054), (0x07785, 15359), (0x07787, 9434),
+    (0x07788, 15950), (0x07789, 15949), (0x0778b, 9437), (0x0778c, 9435), (0x0778d, 15947), (0x0778e, 9433), (0x0778f, 15948),
+    (0x07791, 9436), (0x07793, 2312), (0x07795, 16546), (0x07797, 16548), (0x07798, 3747), (0x07799, 16547), (0x0779a, 16539),
+    (0x0779b, 16543), (0x0779c, 16542), (0x0779d, 16540), (0x0779e, 9772), (0x0779f, 9773), (0x077a0, 9771), (0x077a1, 16541),
+    (0x077a2, 16544), (0x077a3, 16545), (0x077a5, 9774), (0x077a7, 10048), (0x077a8, 17021), (0x077aa, 10045), (0x077ab, 17015),
+    (0x077ac, 10047), (0x077ad, 10049), (0x077af, 19743), (0x077b0, 10046), (0x077b1, 17020), (0x077b2, 17016), (0x077b3, 10044),
+    (0x077b4, 17019), (0x077b5, 17014), (0x077b6, 17018), (0x077b7, 17017), (0x077b9, 4522), (0x077ba, 17448), (0x077bb, 10271),
+    (0x077bc, 10272), (0x077bd, 10269), (0x077be, 3751), (0x077bf, 10270), (0x077c2, 17447), (0x077c3, 3238), (0x077c4, 17800),
+    (0x077c5, 19445), (0x077c7, 10433), (0x077c9, 17798), (0x077ca, 17799), (0x077cb, 3752), (0x077cc, 18107), (0x077cd, 18110),
+    (0x077ce, 18108), (0x077cf, 18109), (0x077d0, 18338), (0x077d3, 10660), (0x077d4, 18663), (0x077d5, 18765), (0x077d7, 10825),
+    (0x077d8, 18833), (0x077d9, 18834), (0x077da, 10871), (0x077db, 5771), (0x077dc, 6878), (0x077dd, 1773), (0x077de, 14070),
+    (0x077e0, 14730), (0x077e2, 5772), (0x077e3, 6170), (0x077e5, 6529), (0x077e6, 19115), (0x077e7, 12380), (0x077e8, 12381),
+    (0x077e9, 7278), (0x077ec, 14071), (0x077ed, 8217), (0x077ee, 8642), (0x077ef, 10050), (0x077f0, 17022), (0x077f1, 17801),
+    (0x077f2, 18111), (0x077f3, 5773), (0x077f4, 19747), (0x077f7, 11995), (0x077f8, 11990), (0x077f9, 11992), (0x077fa, 11994),
+    (0x077fb, 11993), (0x077fc, 11991), (0x077fd, 6530), (0x077fe, 4943), (0x07802, 6879), (0x07803, 12390), (0x07805, 12385),
+    (0x07806, 12382), (0x07808, 4408), (0x07809, 12389), (0x0780c, 6881), (0x0780d, 6882), (0x0780e, 12388), (0x0780f, 12387),
+    (0x07810, 12386), (0x07811, 12383), (0x07812, 12384), (0x07813, 12391), (0x07814, 6880), (0x07818, 3754), (0x0781c, 3756),
+    (0x0781d, 7282), (0x0781e, 3078), (0x0781f, 7288), (0x07820, 7287), (0x07821, 12844), (0x07822, 12838), (0x07823, 12836),
+    (0x07825, 7285), (0x07826, 13432), (0x07827, 7280), (0x07828, 12841), (0x07829, 12845), (0x0782a, 12847), (0x0782b, 12843),
+    (0x0782c, 12837), (0x0782d, 7286), (0x0782e, 12842), (0x0782f, 12840), (0x07830, 7279), (0x07831, 12848), (0x07832, 7289),
+    (0x07833, 12846), (0x07834, 7283), (0x07835, 12839), (0x07837, 7284), (0x07838, 7281), (0x07839, 3758), (0x0783c, 19012),
+    (0x0783d, 3425), (0x07842, 2999), (0x07843, 7780), (0x07844, 3361), (0x07845, 13433), (0x07847, 3759), (0x07848, 13426),
+    (0x07849, 13428), (0x0784a, 13430), (0x0784b, 3428), (0x0784c, 13431), (0x0784d, 13429), (0x0784e, 7781), (0x07850, 13434),
+    (0x07851, 3760), (0x07852, 13427), (0x07853, 4873), (0x07854, 3426), (0x0785c, 14075), (0x0785d, 8218), (0x0785e, 14083),
+    (0x07860, 14072), (0x07862, 14084), (0x07864, 14073), (0x07865, 14074), (0x07866, 3761), (0x07868, 14082), (0x07869, 14081),
+    (0x0786a, 14078), (0x0786b, 7779), (0x0786c, 8219), (0x0786d, 14076), (0x0786e, 14079), (0x0786f, 8220), (0x07870, 14080),
+    (0x07871, 14077), (0x07879, 14741), (0x0787a, 4683), (0x0787b, 14745), (0x0787c, 8649), (0x0787e, 15371), (0x0787f, 8652),
+    (0x07880, 14743), (0x07881, 18956), (0x07883, 14740), (0x07884, 14735), (0x07885, 14737), (0x07886, 14738), (0x07887, 14731),
+    (0x07888, 3080), (0x07889, 8648), (0x0788c, 8647), (0x0788d, 3079), (0x0788e, 8643), (0x0788f, 14734), (0x07891, 8650),
+    (0x07893, 8651), (0x07894, 14733), (0x07895, 14736), (0x07896, 14744), (0x07897, 8645), (0x07898, 8646), (0x07899, 14742),
+    (0x0789a, 14732), (0x0789e, 15373), (0x0789f, 9059), (0x078a0, 15375), (0x078a1, 14739), (0x078a2, 15377), (0x078a3, 9063),
+    (0x078a4, 15378), (0x078a5, 15374), (0x078a7, 9060), (0x078a8, 15370), (0x078a9, 9062), (0x078aa, 15367), (0x078ab, 15372),
+    (0x078ac, 15376), (0x078ad, 15369), (0x078af, 19752), (0x078b0, 8644), (0x078b1, 19760), (0x078b2, 15366), (0x078b3, 9061),
+    (0x078b4, 15368), (0x078b6, 3427), (0x078b8, 1330), (0x078b9, 3418), (0x078ba, 9440), (0x078bb, 15952), (0x078bc, 9444),
+    (0x078be, 9442), (0x078c1, 9058), (0x078c3, 15959), (0x078c4, 15960), (0x078c5, 9439), (0x078c7, 19753), (0x078c8, 15958),
+    (0x078c9, 15961), (0x078ca, 9441), (0x078cb, 9438), (0x078cc, 15954), (0x078cd, 15951), (0x078ce, 15956), (0x078cf, 15953),
+    (0x078d0, 9445), (0x078d1, 15955), (0x078d2, 3081), (0x078d3, 19754), (0x078d4, 15957), (0x078d5, 9443), (0x078d7, 19758),
+    (0x078d8, 4863), (0x078da, 9776), (0x078db, 16555), (0x078dd, 16549), (0x078de, 16553), (0x078df, 16559), (0x078e0, 16560),
+    (0x078e1, 16556), (0x078e2, 16557), (0x078e3, 16554), (0x078e4, 1752), (0x078e5, 16551), (0x078e7, 9778), (0x078e8, 9775),
+    (0x078e9, 16550), (0x078ea, 16552), (0x078ec, 9777), (0x078ed, 16558), (0x078ee, 2929), (0x078ef, 10054), (0x078f0, 3117),
+    (0x078f1, 19176), (0x078f2, 17028), (0x078f3, 17023), (0x078f4, 10053), (0x078f5, 2454), (0x078f7, 10051), (0x078f9, 17030),
+    (0x078fa, 10052), (0x078fb, 17026), (0x078fc, 17027), (0x078fd, 17024), (0x078fe, 17031), (0x078ff, 17449), (0x07901, 10055),
+    (0x07902, 17025), (0x07904, 17032), (0x07905, 17029), (0x07906, 4937), (0x07909, 17453), (0x0790c, 17450), (0x0790e, 10273),
+    (0x07910, 17454), (0x07911, 17456), (0x07912, 17455), (0x07913, 17451), (0x07914, 17452), (0x07917, 17806), (0x07919, 10434),
+    (0x0791b, 17803), (0x0791c, 17805), (0x0791d, 17802), (0x0791e, 17807), (0x07921, 17804), (0x07923, 18113), (0x07924, 18116),
+    (0x07925, 18112), (0x07926, 10568), (0x07927, 18114), (0x07928, 18115), (0x07929, 18117), (0x0792a, 10569), (0x0792b, 10571),
+    (0x0792c, 10570), (0x0792d, 18339), (0x0792e, 19756), (0x0792f, 18341), (0x07931, 18340), (0x07932, 3766), (0x07933, 3764),
+    (0x07934, 19759), (0x07935, 18517), (0x07936, 2134), (0x07938, 18766), (0x07939, 18835), (0x0793a, 5774), (0x0793b, 1688),
+    (0x0793c, 19774), (0x0793d, 11668), (0x0793e, 6531), (0x0793f, 11997), (0x07940, 6532), (0x07941, 6533), (0x07942, 11996),
+    (0x07944, 12396), (0x07945, 12395), (0x07946, 6883), (0x07947, 6886), (0x07948, 6885), (0x07949, 6884), (0x0794a, 12392),
+    (0x0794b, 12394), (0x0794c, 12393), (0x0794f, 12851), (0x07950, 7291), (0x07951, 12855), (0x07952, 12854), (0x07953, 12853),
+    (0x07954, 12849), (0x07955, 7290), (0x07956, 7294), (0x07957, 7297), (0x07958, 2238), (0x07959, 3083), (0x0795a, 7298),
+    (0x0795b, 12850), (0x0795c, 12852), (0x0795d, 7296), (0x0795e, 7295), (0x0795f, 7293), (0x07960, 7292), (0x07961, 13441),
+    (0x07962, 4532), (0x07963, 13439), (0x07964, 13435), (0x07965, 7782), (0x07967, 13436), (0x07968, 7783), (0x07969, 13437),
+    (0x0796a, 13438), (0x0796b, 13440), (0x0796d, 7784), (0x07970, 14088), (0x07971, 19352), (0x07972, 14087), (0x07973, 14086),
+    (0x07974, 14085), (0x07979, 14749), (0x0797a, 8653), (0x0797c, 14746), (0x0797d, 14748), (0x0797e, 4359), (0x0797f, 8654),
+    (0x07980, 19770), (0x07981, 8655), (0x07982, 14747), (0x07983, 4289), (0x07986, 19767), (0x07987, 1359), (0x07988, 15387),
+    (0x0798a, 15380), (0x0798b, 15381), (0x0798d, 9066), (0x0798e, 9064), (0x0798f, 9065), (0x07990, 15389), (0x07991, 3769),
+    (0x07992, 15388), (0x07993, 15385), (0x07994, 15384), (0x07995, 15383), (0x07996, 15382), (0x07997, 15386), (0x07998, 15379),
+    (0x07999, 3770), (0x0799a, 15962), (0x0799b, 15967), (0x0799c, 15965), (0x0799d, 19772), (0x0799f, 2629), (0x079a0, 15964),
+    (0x079a1, 15963), (0x079a2, 15966), (0x079a4, 16561), (0x079a5, 3089), (0x079a6, 9779), (0x079a7, 10056), (0x079a8, 17034),
+    (0x079a9, 19775), (0x079aa, 10057), (0x079ab, 17033), (0x079ac, 17458), (0x079ad, 17457), (0x079ae, 10274), (0x079b0, 17808),
+    (0x079b1, 10435), (0x079b2, 18118), (0x079b3, 10739), (0x079b4, 18518), (0x079b6, 18768), (0x079b7, 18767), (0x079b8, 11374),
+    (0x079b9, 6887), (0x079ba, 6888), (0x079bb, 13442), (0x079bd, 8657), (0x079be, 5775), (0x079bf, 6173), (0x079c0, 6172),
+    (0x079c1, 6171), (0x079c4, 3524), (0x079c5, 11998), (0x079c6, 19779), (0x079c8, 6535), (0x079c9, 6534), (0x079ca, 1851),
+    (0x079cb, 6891), (0x079cc, 19561), (0x079cd, 12398), (0x079ce, 12401), (0x079cf, 12399), (0x079d1, 6889), (0x079d2, 6890),
+    (0x079d4, 19781), (0x079d5, 12397), (0x079d6, 12400), (0x079d8, 7305), (0x079dc, 12862), (0x079dd, 12864), (0x079de, 12863),
+    (0x079df, 7302), (0x079e0, 12858), (0x079e2, 3408), (0x079e3, 7300), (0x079e4, 7299), (0x079e6, 7303), (0x079e7, 7301),
+    (0x079e9, 7304), (0x079ea, 12861), (0x079eb, 12856), (0x079ec, 12857), (0x079ed, 12860), (0x079ee, 12859), (0x079f1, 3098),
+    (0x079f4, 3093), (0x079f6, 13445), (0x079f7, 13446), (0x079f8, 13444), (0x079fa, 13443), (0x079fb, 7785), (0x07a00, 8225),
+    (0x07a02, 14089), (0x07a03, 14091), (0x07a04, 14093), (0x07a05, 8224), (0x07a06, 3772), (0x07a08, 8222), (0x07a0a, 14090),
+    (0x07a0b, 8223), (0x07a0c, 14092), (0x07a0d, 8221), (0x07a10, 14759), (0x07a11, 14750), (0x07a12, 14753), (0x07a13, 14757),
+    (0x07a14, 8661), (0x07a15, 14755), (0x07a17, 14754), (0x07a18, 14751), (0x07a19, 14752), (0x07a1a, 8659), (0x07a1b, 14758),
+    (0x07a1c, 8658), (0x07a1e, 8663), (0x07a1f, 8662), (0x07a20, 8660), (0x07a22, 14756), (0x07a26, 15395), (0x07a28, 15394),
+    (0x07a2a, 1762), (0x07a2b, 15390), (0x07a2d, 4948), (0x07a2e, 9067), (0x07a2f, 15393), (0x07a30, 15392), (0x07a31, 9068),
+    (0x07a37, 9450), (0x07a39, 15969), (0x07a3a, 3092), (0x07a3b, 9451), (0x07a3c, 9447), (0x07a3d, 9449), (0x07a3e, 4862),
+    (0x07a3f, 9446), (0x07a40, 9448), (0x07a43, 2643), (0x07a44, 16562), (0x07a45, 4281), (0x07a46, 9782), (0x07a47, 16564),
+    (0x07a48, 16563), (0x07a49, 2050), (0x07a4a, 15391), (0x07a4b, 9784), (0x07a4c, 9783), (0x07a4d, 9780), (0x07a4e, 9781),
+    (0x07a54, 17039), (0x07a56, 17037), (0x07a57, 10058), (0x07a58, 17038), (0x07a5a, 17040), (0x07a5b, 17036), (0x07a5c, 17035),
+    (0x07a5f, 17459), (0x07a60, 10277), (0x07a61, 10275), (0x07a62, 10276), (0x07a65, 2053), (0x07a67, 17809), (0x07a68, 17810),
+    (0x07a69, 10437), (0x07a6b, 10436), (0x07a6c, 18120), (0x07a6d, 18121), (0x07a6e, 18119), (0x07a70, 18519), (0x07a71, 18520),
+    (0x07a74, 5776), (0x07a75, 11469), (0x07a76, 6174), (0x07a78, 11999), (0x07a79, 6537), (0x07a7a, 6536), (0x07a7b, 12000),
+    (0x07a7d, 2054), (0x07a7e, 12403), (0x07a7f, 6892), (0x07a80, 12402), (0x07a81, 6893), (0x07a83, 3976), (0x07a84, 7306),
+    (0x07a85, 12867), (0x07a86, 12865), (0x07a87, 12871), (0x07a88, 7307), (0x07a89, 12866), (0x07a8a, 12870), (0x07a8b, 12868),
+    (0x07a8c, 12869), (0x07a8f, 13447), (0x07a90, 13449), (0x07a91, 3775), (0x07a92, 7786), (0x07a93, 1806), (0x07a94, 13448),
+    (0x07a95, 7787), (0x07a96, 8228), (0x07a97, 8227), (0x07a98, 8226), (0x07a99, 14094), (0x07a9e, 14762), (0x07a9f, 8664),
+    (0x07aa0, 8665), (0x07aa2, 14761), (0x07aa3, 14760), (0x07aa8, 15396), (0x07aa9, 9070), (0x07aaa, 9069), (0x07aab, 15397),
+    (0x07aac, 15398), (0x07aae, 9453), (0x07aaf, 9452), (0x07ab0, 2057), (0x07ab1, 16568), (0x07ab2, 15970), (0x07ab3, 15972),
+    (0x07ab4, 15971), (0x07ab5, 16567), (0x07ab6, 16565), (0x07ab7, 16569), (0x07ab8, 16566), (0x07aba, 9785), (0x07abb, 2056),
+    (0x07abc, 3777), (0x07abe, 17041), (0x07abf, 10059), (0x07ac0, 17042), (0x07ac1, 17043), (0x07ac2, 2058), (0x07ac3, 2059),
+    (0x07ac4, 10278), (0x07ac5, 10279), (0x07ac7, 10572), (0x07ac8, 4005), (0x07ac9, 1323), (0x07aca, 10787), (0x07acb, 5777),
+    (0x07acf, 3780), (0x07ad1, 12404), (0x07ad3, 4860), (0x07ad8, 12872), (0x07ad9, 7308), (0x07ada, 2063), (0x07adb, 3781),
+    (0x07adc, 2616), (0x07add, 2064), (0x07adf, 7938), (0x07ae0, 7937), (0x07ae2, 3110), (0x07ae3, 8230), (0x07ae4, 14096),
+    (0x07ae5, 8229), (0x07ae6, 14095), (0x07ae7, 2362), (0x07ae9, 2316), (0x07aea, 2065), (0x07aeb, 14763), (0x07aed, 9071),
+    (0x07aee, 15399), (0x07aef, 9072), (0x07af6, 10573), (0x07af7, 18122), (0x07af9, 5914), (0x07afa, 6538), (0x07afb, 12001),
+    (0x07afd, 6895), (0x07afe, 3120), (0x07aff, 6894), (0x07b00, 12405), (0x07b01, 12406), (0x07b04, 12874), (0x07b05, 12876),
+    (0x07b06, 7309), (0x07b08, 12878), (0x07b09, 12881), (0x07b0a, 12879), (0x07b0b, 2069), (0x07b0c, 3159), (0x07b0e, 12880),
+    (0x07b0f, 12877), (0x07b10, 12873), (0x07b11, 7310), (0x07b12, 12882), (0x07b13, 12875), (0x07b14, 4841), (0x07b18, 13458),
+    (0x07b19, 7793), (0x07b1a, 13467), (0x07b1b, 7790), (0x07b1d, 13460), (0x07b1e, 7794), (0x07b1f, 4732), (0x07b20, 7788),
+    (0x07b22, 13455), (0x07b23, 13468), (0x07b24, 13456), (0x07b25, 13453), (0x07b26, 7792), (0x07b27, 3155), (0x07b28, 7789),
+    (0x07b29, 2071), (0x07b2a, 13459), (0x07b2b, 13462), (0x07b2c, 7791), (0x07b2d, 13463), (0x07b2e, 7795), (0x07b2f, 13464),
+    (0x07b30, 13454), (0x07b31, 13461), (0x07b32, 13465), (0x07b33, 13457), (0x07b34, 13452), (0x07b35, 13450), (0x07b38, 13466),
+    (0x07b39, 3810), (0x07b3b, 14098), (0x07b40, 14103), (0x07b42, 4275), (0x07b43, 4358), (0x07b44, 14099), (0x07b45, 14105),
+    (0x07b46, 8233), (0x07b47, 13451), (0x07b48, 14100), (0x07b49, 8231), (0x07b4a, 14097), (0x07b4b, 8238), (0x07b4c, 14101),
+    (0x07b4d, 8237), (0x07b4e, 14102), (0x07b4f, 8239), (0x07b50, 8234), (0x07b51, 8240), (0x07b52, 8235), (0x07b54, 8236),
+    (0x07b55, 2070), (0x07b56, 8232), (0x07b58, 14104), (0x07b60, 8668), (0x07b61, 14774), (0x07b62, 3784), (0x07b63, 14777),
+    (0x07b64, 14765), (0x07b65, 14770), (0x07b66, 14764), (0x07b67, 8670), (0x07b69, 14768), (0x07b6c, 3785), (0x07b6d, 14766),
+    (0x07b6e, 8669), (0x07b6f, 2075), (0x07b70, 14773), (0x07b71, 14772), (0x07b72, 14769), (0x07b73, 14771), (0x07b74, 14767),
+    (0x07b75, 9076), (0x07b76, 14776), (0x07b77, 8666), (0x07b78, 14775), (0x07b7b, 3786), (0x07b82, 15415), (0x07b84, 9083),
+    (0x07b85, 15410), (0x07b87, 9082), (0x07b88, 15400), (0x07b8a, 15402), (0x07b8b, 9075), (0x07b8c, 15407), (0x07b8d, 15406),
+    (0x07b8e, 15409), (0x07b8f, 9080), (0x07b90, 15404), (0x07b91, 15403), (0x07b92, 2081), (0x07b94, 9079), (0x07b95, 9074),
+    (0x07b96, 15405), (0x07b97, 9077), (0x07b98, 15411), (0x07b99, 15413), (0x07b9b, 15408), (0x07b9c, 15401), (0x07b9d, 9078),
+    (0x07ba0, 9461), (0x07ba1, 9073), (0x07ba2, 2074), (0x07ba3, 4735), (0x07ba4, 15414), (0x07bac, 15976), (0x07bad, 9454),
+    (0x07baf, 15978), (0x07bb1, 9455), (0x07bb2, 1651), (0x07bb4, 9457), (0x07bb5, 15981), (0x07bb7, 15973), (0x07bb8, 9081),
+    (0x07bb9, 15979), (0x07bbe, 15975), (0x07bc0, 8667), (0x07bc1, 9460), (0x07bc4, 9456), (0x07bc6, 9458), (0x07bc7, 9459),
+    (0x07bc9, 9788), (0x07bca, 15980), (0x07bcb, 15974), (0x07bcc, 9462), (0x07bce, 15977), (0x07bcf, 4739), (0x07bd0, 2079),
+    (0x07bd4, 16579), (0x07bd5, 16574), (0x07bd8, 16584), (0x07bd9, 9786), (0x07bda, 16576), (0x07bdb, 9790), (0x07bdc, 16582),
+    (0x07bdd, 16573), (0x07bde, 16570), (0x07bdf, 16585), (0x07be0, 10065), (0x07be1, 9791), (0x07be2, 16581), (0x07be3, 16571),
+    (0x07be4, 9789), (0x07be5, 16575), (0x07be6, 9793), (0x07be7, 16572), (0x07be8, 16577), (0x07be9, 9792), (0x07bea, 16580),
+    (0x07beb, 16583), (0x07bec, 1807), (0x07bf0, 17061), (0x07bf1, 17062), (0x07bf2, 17046), (0x07bf3, 17053), (0x07bf4, 17051),
+    (0x07bf7, 10063), (0x07bf8, 17058), (0x07bf9, 16578), (0x07bfa, 2087), (0x07bfb, 17049), (0x07bfc, 4746), (0x07bfd, 17059),
+    (0x07bfe, 10062), (0x07bff, 17048), (0x07c00, 17047), (0x07c01, 17057), (0x07c02, 17054), (0x07c03, 17056), (0x07c05, 17044),
+    (0x07c06, 17060), (0x07c07, 10060), (0x07c09, 17055), (0x07c0a, 17064), (0x07c0b, 17052), (0x07c0c, 10064), (0x07c0d, 10061),
+    (0x07c0e, 17050), (0x07c0f, 17045), (0x07c10, 17063), (0x07c11, 9787), (0x07c12, 3787), (0x07c15, 19083), (0x07c19, 17462),
+    (0x07c1b, 3788), (0x07c1c, 17460), (0x07c1d, 17466), (0x07c1e, 10283), (0x07c1f, 17464), (0x07c20, 17463), (0x07c21, 10285),
+    (0x07c22, 17469), (0x07c23, 10284), (0x07c25, 17470), (0x07c26, 17467), (0x07c27, 10281), (0x07c28, 17468), (0x07c29, 17461),
+    (0x07c2a, 10282), (0x07c2b, 10280), (0x07c2c, 17814), (0x07c2d, 17465), (0x07c30, 17471), (0x07c33, 17811), (0x07c35, 2089),
+    (0x07c37, 10442), (0x07c38, 10440), (0x07c39, 17813), (0x07c3b, 17815), (0x07c3c, 17812), (0x07c3d, 10441), (0x07c3e, 10438),
+    (0x07c3f, 10439), (0x07c40, 10443), (0x07c42, 4743), (0x07c43, 10575), (0x07c44, 2091), (0x07c45, 18127), (0x07c47, 18126),
+    (0x07c48, 18124), (0x07c49, 18123), (0x07c4a, 18125), (0x07c4c, 10574), (0x07c4d, 10576), (0x07c50, 10661), (0x07c51, 4910),
+    (0x07c53, 18343), (0x07c54, 18342), (0x07c56, 4710), (0x07c57, 18521), (0x07c59, 18523), (0x07c5a, 18525), (0x07c5b, 18524),
+    (0x07c5c, 18522), (0x07c5d, 3122), (0x07c5f, 10741), (0x07c60, 10740), (0x07c63, 10789), (0x07c64, 10788), (0x07c65, 10790),
+    (0x07c66, 18665), (0x07c67, 18664), (0x07c69, 18836), (0x07c6a, 18769), (0x07c6b, 18837), (0x07c6c, 10857), (0x07c6d, 3123),
+    (0x07c6e, 10858), (0x07c6f, 18885), (0x07c70, 4801), (0x07c72, 10895), (0x07c73, 5915), (0x07c74, 2100), (0x07c75, 12002),
+    (0x07c78, 12408), (0x07c79, 12409), (0x07c7a, 12407), (0x07c7b, 3791), (0x07c7c, 3132), (0x07c7d, 6896), (0x07c7e, 4741),
+    (0x07c7f, 12410), (0x07c80, 12411), (0x07c81, 12412), (0x07c83, 2092), (0x07c84, 12883), (0x07c85, 12889), (0x07c86, 4744),
+    (0x07c88, 12887), (0x07c89, 7311), (0x07c8a, 12885), (0x07c8c, 12886), (0x07c8d, 12888), (0x07c8e, 3131), (0x07c91, 12884),
+    (0x07c92, 7796), (0x07c94, 13469), (0x07c95, 7798), (0x07c96, 13471), (0x07c97, 7797), (0x07c98, 13470), (0x07c9c, 3792),
+    (0x07c9e, 14107), (0x07c9f, 8241), (0x07ca1, 14109), (0x07ca2, 14106), (0x07ca3, 13472), (0x07ca5, 8242), (0x07ca6, 2094),
+    (0x07ca7, 18961), (0x07ca8, 14108), (0x07cac, 2405), (0x07cae, 3133), (0x07caf, 14780), (0x07cb1, 8671), (0x07cb2, 14778),
+    (0x07cb3, 8672), (0x07cb4, 14779), (0x07cb5, 8673), (0x07cb8, 3794), (0x07cb9, 9084), (0x07cba, 15419), (0x07cbb, 15416),
+    (0x07cbc, 15418), (0x07cbd, 9085), (0x07cbe, 9086), (0x07cbf, 15417), (0x07cc2, 4945), (0x07cc3, 1808), (0x07cc5, 15982),
+    (0x07cc7, 2098), (0x07cc8, 15983), (0x07cc9, 2097), (0x07cca, 9463), (0x07ccb, 15985), (0x07ccc, 15984), (0x07ccd, 3128),
+    (0x07cce, 5214), (0x07cd0, 16589), (0x07cd1, 16590), (0x07cd2, 16586), (0x07cd3, 3688), (0x07cd4, 16587), (0x07cd5, 9794),
+    (0x07cd6, 9795), (0x07cd7, 16588), (0x07cd9, 10071), (0x07cda, 4947), (0x07cdc, 10067), (0x07cdd, 10072), (0x07cde, 10068),
+    (0x07cdf, 10070), (0x07ce0, 10066), (0x07ce2, 10069), (0x07ce6, 2099), (0x07ce7, 10286), (0x07ce8, 17065), (0x07cea, 17817),
+    (0x07cec, 17816), (0x07ced, 3796), (0x07cee, 18128), (0x07cef, 10577), (0x07cf0, 10578), (0x07cf1, 18527), (0x07cf2, 18344),
+    (0x07cf3, 2101), (0x07cf4, 18526), (0x07cf5, 2102), (0x07cf6, 18838), (0x07cf7, 18906), (0x07cf8, 5916), (0x07cf9, 1689),
+    (0x07cfb, 6175), (0x07cfc, 1320), (0x07cfd, 12003), (0x07cfe, 6539), (0x07d00, 6899), (0x07d01, 12415), (0x07d02, 6897),
+    (0x07d03, 12413), (0x07d04, 6902), (0x07d05, 6898), (0x07d06, 6903), (0x07d07, 6901), (0x07d08, 12414), (0x07d09, 6900),
+    (0x07d0a, 7315), (0x07d0b, 7314), (0x07d0c, 12900), (0x07d0d, 7323), (0x07d0e, 12893), (0x07d0f, 12899), (0x07d10, 7319),
+    (0x07d11, 12892), (0x07d12, 12898), (0x07d13, 12896), (0x07d14, 7318), (0x07d15, 7320), (0x07d16, 12895), (0x07d17, 7313),
+    (0x07d18, 12894), (0x07d19, 7324), (0x07d1a, 7321), (0x07d1b, 7325), (0x07d1c, 7322), (0x07d1d, 12891), (0x07d1e, 12890),
+    (0x07d1f, 12897), (0x07d20, 7316), (0x07d21, 7312), (0x07d22, 7317), (0x07d25, 4670), (0x07d28, 13487), (0x07d29, 13480),
+    (0x07d2b, 8247), (0x07d2c, 13479), (0x07d2e, 7802), (0x07d2f, 7809), (0x07d30, 7806), (0x07d31, 7812), (0x07d32, 7811),
+    (0x07d33, 7807), (0x07d35, 13473), (0x07d36, 13476), (0x07d38, 13475), (0x07d39, 7803), (0x07d3a, 13477), (0x07d3b, 13486),
+    (0x07d3c, 7804), (0x07d3d, 13474), (0x07d3e, 13483), (0x07d3f, 13484), (0x07d40, 7805), (0x07d41, 13481), (0x07d42, 7810),
+    (0x07d43, 7800), (0x07d44, 7808), (0x07d45, 13478), (0x07d46, 7799), (0x07d47, 13482), (0x07d4a, 13485), (0x07d4d, 4951),
+    (0x07d4e, 14126), (0x07d4f, 14117), (0x07d50, 8244), (0x07d51, 14124), (0x07d52, 14121), (0x07d53, 14113), (0x07d54, 14122),
+    (0x07d55, 8246), (0x07d56, 14114), (0x07d58, 14110), (0x07d5a, 4555), (0x07d5b, 8679), (0x07d5c, 14119), (0x07d5d, 2107),
+    (0x07d5e, 8243), (0x07d5f, 14125), (0x07d61, 8250), (0x07d62, 8252), (0x07d63, 14112), (0x07d66, 8251), (0x07d67, 14115),
+    (0x07d68, 8245), (0x07d69, 14123), (0x07d6a, 14116), (0x07d6b, 14120), (0x07d6d, 14118), (0x07d6e, 8248), (0x07d6f, 14111),
+    (0x07d70, 8253), (0x07d71, 7801), (0x07d72, 8249), (0x07d73, 8254), (0x07d79, 8675), (0x07d7a, 14787), (0x07d7b, 14789),
+    (0x07d7c, 14791), (0x07d7d, 14795), (0x07d7f, 14785), (0x07d80, 14783), (0x07d81, 8677), (0x07d83, 14790), (0x07d84, 14794),
+    (0x07d85, 14786), (0x07d86, 14782), (0x07d88, 14781), (0x07d89, 2110), (0x07d8b, 1781), (0x07d8c, 14792), (0x07d8d, 14784),
+    (0x07d8e, 14788), (0x07d8f, 8678), (0x07d91, 8676), (0x07d92, 14796), (0x07d93, 8674), (0x07d94, 14793), (0x07d95, 981),
+    (0x07d96, 15436), (0x07d97, 3142), (0x07d9c, 9089), (0x07d9d, 15428), (0x07d9e, 9476), (0x07d9f, 15438), (0x07da0, 9092),
+    (0x07da1, 15442), (0x07da2, 9098), (0x07da3, 15423), (0x07da4, 3672), (0x07da6, 15439), (0x07da7, 15420), (0x07da8, 3475),
+    (0x07da9, 15441), (0x07daa, 15424), (0x07dab, 2111), (0x07dac, 9105), (0x07dad, 9102), (0x07dae, 15440), (0x07daf, 15434),
+    (0x07db0, 9088), (0x07db1, 9096), (0x07db2, 9095), (0x07db3, 2113), (0x07db4, 9094), (0x07db5, 9100), (0x07db7, 15421),
+    (0x07db8, 9101), (0x07db9, 15435), (0x07dba, 9097), (0x07dbb, 9087), (0x07dbc, 15437), (0x07dbd, 9090), (0x07dbe, 9091),
+    (0x07dbf, 9099), (0x07dc0, 15426), (0x07dc1, 15425), (0x07dc2, 15422), (0x07dc4, 15430), (0x07dc5, 15427), (0x07dc6, 15431),
+    (0x07dc7, 9104), (0x07dc9, 15443), (0x07dca, 9093), (0x07dcb, 15432), (0x07dcc, 15433), (0x07dcd, 1321), (0x07dce, 15429),
+    (0x07dcf, 3800), (0x07dd0, 3802), (0x07dd2, 9103), (0x07dd3, 3137), (0x07dd4, 3801), (0x07dd7, 15990), (0x07dd8, 9468),
+    (0x07dd9, 9477), (0x07dda, 9473), (0x07ddb, 15987), (0x07ddc, 3135), (0x07ddd, 9470), (0x07dde, 9474), (0x07ddf, 15999),
+    (0x07de0, 9464), (0x07de1, 15991), (0x07de3, 9472), (0x07de4, 2117), (0x07de5, 2121), (0x07de6, 15994), (0x07de7, 15989),
+    (0x07de8, 9471), (0x07de9, 9475), (0x07dea, 15988), (0x07dec, 9469), (0x07dee, 15998), (0x07def, 9466), (0x07df0, 15997),
+    (0x07df1, 15996), (0x07df2, 9478), (0x07df3, 15750), (0x07df4, 9465), (0x07df5, 2119), (0x07df6, 15995), (0x07df7, 15986),
+    (0x07df9, 9479), (0x07dfa, 15993), (0x07dfb, 9467), (0x07dfd, 3803), (0x07dfe, 3526), (0x07e03, 15992), (0x07e07, 3136),
+    (0x07e08, 9798), (0x07e09, 9803), (0x07e0a, 9796), (0x07e0b, 16603), (0x07e0c, 16594), (0x07e0d, 16606), (0x07e0e, 16598),
+    (0x07e0f, 16604), (0x07e10, 9804), (0x07e11, 9797), (0x07e12, 16591), (0x07e13, 16597), (0x07e14, 16607), (0x07e15, 16600),
+    (0x07e16, 16605), (0x07e17, 16593), (0x07e1a, 16601), (0x07e1b, 9799), (0x07e1c, 16599), (0x07e1d, 9802), (0x07e1e, 9801),
+    (0x07e1f, 16595), (0x07e20, 16596), (0x07e21, 16592), (0x07e22, 16602), (0x07e23, 9800), (0x07e24, 16609), (0x07e25, 16608),
+    (0x07e27, 2130), (0x07e29, 17076), (0x07e2a, 17072), (0x07e2b, 10079), (0x07e2d, 17066), (0x07e2e, 10073), (0x07e2f, 10089),
+    (0x07e30, 17078), (0x07e31, 10081), (0x07e32, 10077), (0x07e33, 17069), (0x07e34, 10084), (0x07e35, 10087), (0x07e36, 17080),
+    (0x07e37, 10076), (0x07e38, 17071), (0x07e39, 10085), (0x07e3a, 17082), (0x07e3b, 17079), (0x07e3c, 17067), (0x07e3d, 10080),
+    (0x07e3e, 10074), (0x07e3f, 10088), (0x07e40, 17074), (0x07e41, 10083), (0x07e42, 17068), (0x07e43, 10078), (0x07e44, 17081),
+    (0x07e45, 10082), (0x07e46, 10075), (0x07e47, 17075), (0x07e48, 10086), (0x07e49, 17073), (0x07e4c, 17077), (0x07e50, 17473),
+    (0x07e51, 17479), (0x07e52, 10292), (0x07e53, 17482), (0x07e54, 10287), (0x07e55, 10288), (0x07e56, 17474), (0x07e57, 17481),
+    (0x07e58, 17476), (0x07e59, 10293), (0x07e5a, 10290), (0x07e5b, 1849), (0x07e5c, 17472), (0x07e5e, 10289), (0x07e5f, 17478),
+    (0x07e60, 17480), (0x07e61, 10291), (0x07e62, 17477), (0x07e63, 17475), (0x07e65, 3665), (0x07e67, 2104), (0x07e68, 17827),
+    (0x07e69, 10447), (0x07e6a, 10448), (0x07e6b, 10444), (0x07e6c, 1809), (0x07e6d, 10445), (0x07e6e, 2126), (0x07e6f, 17823),
+    (0x07e70, 17821), (0x07e72, 17825), (0x07e73, 10450), (0x07e74, 17826), (0x07e75, 17819), (0x07e76, 17818), (0x07e77, 17822),
+    (0x07e78, 17820), (0x07e79, 10446), (0x07e7a, 17824), (0x07e7b, 18129), (0x07e7c, 10581), (0x07e7d, 10580), (0x07e7e, 18130),
+    (0x07e7f, 4464), (0x07e80, 18132), (0x07e81, 18131), (0x07e82, 10582), (0x07e86, 18349), (0x07e87, 18346), (0x07e88, 18347),
+    (0x07e8a, 18345), (0x07e8b, 18348), (0x07e8c, 10663), (0x07e8d, 18350), (0x07e8e, 4631), (0x07e8f, 10662), (0x07e91, 18528),
+    (0x07e92, 2127), (0x07e93, 10791), (0x07e94, 10793), (0x07e95, 18666), (0x07e96, 10792), (0x07e97, 18770), (0x07e98, 18840),
+    (0x07e99, 18842), (0x07e9a, 18839), (0x07e9b, 18841), (0x07e9c, 10877), (0x07e9f, 4444), (0x07ea4, 1302), (0x07eac, 1303),
+    (0x07eba, 1304), (0x07ec7, 1305), (0x07ecf, 1306), (0x07edf, 1307), (0x07f06, 1308), (0x07f36, 5917), (0x07f37, 1309),
+    (0x07f38, 6904), (0x07f39, 12764), (0x07f3a, 7326), (0x07f3d, 7813), (0x07f3e, 14127), (0x07f3f, 14128), (0x07f40, 2131),
+    (0x07f41, 2132), (0x07f43, 16610), (0x07f44, 10090), (0x07f45, 17083), (0x07f47, 2133), (0x07f48, 10294), (0x07f49, 4507),
+    (0x07f4a, 17829), (0x07f4b, 17828), (0x07f4c, 10583), (0x07f4d, 18351), (0x07f4e, 3138), (0x07f4f, 18529), (0x07f50, 10826),
+    (0x07f51, 11470), (0x07f52, 1687), (0x07f53, 1690), (0x07f54, 6540), (0x07f55, 6176), (0x07f58, 12416), (0x07f5b, 12906),
+    (0x07f5c, 12901), (0x07f5d, 12905), (0x07f5e, 12903), (0x07f5f, 7327), (0x07f60, 12904), (0x07f61, 12902), (0x07f63, 13488),
+    (0x07f65, 14129), (0x07f66, 14130), (0x07f67, 14799), (0x07f68, 14800), (0x07f69, 8681), (0x07f6a, 8682), (0x07f6b, 14798),
+    (0x07f6c, 14801), (0x07f6d, 14797), (0x07f6e, 8680), (0x07f70, 9106), (0x07f71, 4995), (0x07f72, 8683), (0x07f73, 15444),
+    (0x07f75, 9480), (0x07f76, 16000), (0x07f77, 9481), (0x07f78, 4845), (0x07f79, 9805), (0x07f7a, 16613), (0x07f7b, 16611),
+    (0x07f7c, 16612), (0x07f7d, 17086), (0x07f7e, 17085), (0x07f7f, 17084), (0x07f83, 17830), (0x07f85, 10449), (0x07f86, 17831),
+    (0x07f87, 18530), (0x07f88, 10827), (0x07f89, 18771), (0x07f8a, 5918), (0x07f8b, 6542), (0x07f8c, 6541), (0x07f8d, 12418),
+    (0x07f8e, 6905), (0x07f8f, 19538), (0x07f91, 12417), (0x07f92, 12908), (0x07f93, 1546), (0x07f94, 7328), (0x07f95, 13489),
+    (0x07f96, 12907), (0x07f97, 2137), (0x07f9a, 7815), (0x07f9b, 13492), (0x07f9c, 13490), (0x07f9d, 13491), (0x07f9e, 7814),
+    (0x07fa0, 14132), (0x07fa1, 14133), (0x07fa2, 14131), (0x07fa3, 2139), (0x07fa4, 8686), (0x07fa5, 14803), (0x07fa6, 14802),
+    (0x07fa7, 14804), (0x07fa8, 8685), (0x07fa9, 8684), (0x07fac, 16001), (0x07fad, 16003), (0x07fae, 3804), (0x07faf, 9482),
+    (0x07fb0, 16002), (0x07fb1, 16614), (0x07fb2, 9806), (0x07fb3, 17484), (0x07fb4, 3805), (0x07fb5, 17483), (0x07fb6, 10451),
+    (0x07fb7, 17832), (0x07fb8, 10453), (0x07fb9, 10452), (0x07fba, 18133), (0x07fbb, 18352), (0x07fbc, 10664), (0x07fbd, 5919),
+    (0x07fbe, 12419), (0x07fbf, 6906), (0x07fc0, 12911), (0x07fc1, 7330), (0x07fc2, 12910), (0x07fc3, 12909), (0x07fc5, 7329),
+    (0x07fc7, 13498), (0x07fc9, 13500), (0x07fca, 13493), (0x07fcb, 13494), (0x07fcc, 7816), (0x07fcd, 13495), (0x07fce, 7817),
+    (0x07fcf, 13499), (0x07fd0, 13496), (0x07fd1, 13497), (0x07fd2, 7818), (0x07fd4, 8256), (0x07fd5, 8257), (0x07fd7, 14134),
+    (0x07fdb, 14805), (0x07fdc, 14806), (0x07fdd, 3154), (0x07fde, 15448), (0x07fdf, 9109), (0x07fe0, 9107), (0x07fe1, 9108),
+    (0x07fe2, 15445), (0x07fe3, 15446), (0x07fe5, 15447), (0x07fe6, 16008), (0x07fe7, 3692), (0x07fe8, 16009), (0x07fe9, 9483),
+    (0x07fea, 16006), (0x07feb, 16005), (0x07fec, 16007), (0x07fed, 16004), (0x07fee, 9809), (0x07fef, 16615), (0x07ff0, 9807),
+    (0x07ff1, 9808), (0x07ff2, 17088), (0x07ff3, 10091), (0x07ff4, 17087), (0x07ff5, 17340), (0x07ff7, 17485), (0x07ff8, 17486),
+    (0x07ff9, 10295), (0x07ffa, 2146), (0x07ffb, 10296), (0x07ffc, 10092), (0x07ffd, 17833), (0x07ffe, 17834), (0x07fff, 18134),
+    (0x08000, 10584), (0x08001, 5920), (0x08002, 1695), (0x08003, 5921), (0x08004, 6907), (0x08005, 6543), (0x08006, 7331),
+    (0x08007, 12420), (0x08008, 2149), (0x0800b, 8258), (0x0800c, 5922), (0x0800d, 6909), (0x0800e, 12421), (0x0800f, 12422),
+    (0x08010, 6908), (0x08011, 6910), (0x08012, 5923), (0x08014, 12423), (0x08015, 7333), (0x08016, 12912), (0x08017, 7335),
+    (0x08018, 7332), (0x08019, 7334), (0x0801b, 13503), (0x0801c, 7819), (0x0801d, 2150), (0x0801e, 13502), (0x0801f, 13501),
+    (0x08020, 3808), (0x08021, 14807), (0x08024, 15449), (0x08025, 3809), (0x08026, 9484), (0x08028, 9810), (0x08029, 16617),
+    (0x0802a, 16616), (0x0802c, 17089), (0x0802e, 3811), (0x0802f, 2152), (0x08030, 18353), (0x08031, 3812), (0x08033, 5924),
+    (0x08034, 11669), (0x08035, 12004), (0x08036, 6911), (0x08037, 12424), (0x08039, 12914), (0x0803b, 2155), (0x0803c, 2156),
+    (0x0803d, 7336), (0x0803e, 12913), (0x0803f, 7337), (0x08043, 13505), (0x08046, 7821), (0x08047, 13504), (0x08048, 13506),
+    (0x0804a, 7820), (0x0804f, 14136), (0x08050, 14137), (0x08051, 14135), (0x08052, 8259), (0x08054, 3813), (0x08056, 8687),
+    (0x08058, 8688), (0x0805a, 9111), (0x0805b, 4959), (0x0805c, 15451), (0x0805d, 15450), (0x0805e, 9110), (0x08061, 2157),
+    (0x08062, 4957), (0x08063, 4950), (0x08064, 16010), (0x08066, 4949), (0x08067, 16011), (0x0806c, 16618), (0x0806f, 10096),
+    (0x08070, 10095), (0x08071, 10093), (0x08072, 10094), (0x08073, 10097), (0x08075, 17487), (0x08076, 10298), (0x08077, 10297),
+    (0x08078, 17835), (0x08079, 18135), (0x0807d, 10743), (0x0807e, 10742), (0x0807f, 5925), (0x08080, 1696), (0x08082, 12713),
+    (0x08084, 8690), (0x08085, 8260), (0x08086, 8689), (0x08087, 9112), (0x08089, 5926), (0x0808a, 11375), (0x0808b, 5927),
+    (0x0808c, 5928), (0x0808f, 12005), (0x08090, 11672), (0x08092, 11673), (0x08093, 6178), (0x08095, 11670), (0x08096, 6177),
+    (0x08098, 6180), (0x08099, 11671), (0x0809a, 6182), (0x0809b, 6181), (0x0809c, 11674), (0x0809d, 6179), (0x0809f, 1339),
+    (0x080a1, 6548), (0x080a2, 6546), (0x080a3, 12007), (0x080a5, 6545), (0x080a7, 2164), (0x080a9, 6550), (0x080aa, 6552),
+    (0x080ab, 6549), (0x080ad, 12010), (0x080ae, 12006), (0x080af, 6553), (0x080b1, 6547), (0x080b2, 6183), (0x080b4, 6551),
+    (0x080b5, 12009), (0x080b6, 4582), (0x080b7, 3817), (0x080b8, 12008), (0x080ba, 6544), (0x080bc, 1332), (0x080bd, 4530),
+    (0x080c2, 12430), (0x080c3, 6915), (0x080c4, 6916), (0x080c5, 12432), (0x080c6, 3177), (0x080c7, 12426), (0x080c8, 12429),
+    (0x080c9, 12438), (0x080ca, 12436), (0x080cc, 6917), (0x080cd, 12442), (0x080ce, 6920), (0x080cf, 12439), (0x080d0, 12431),
+    (0x080d1, 12428), (0x080d4, 14139), (0x080d5, 12437), (0x080d6, 6912), (0x080d7, 12440), (0x080d8, 12425), (0x080d9, 12434),
+    (0x080da, 6914), (0x080db, 6919), (0x080dc, 12435), (0x080dd, 6923), (0x080de, 6921), (0x080e0, 12427), (0x080e1, 6918),
+    (0x080e3, 12433), (0x080e4, 6922), (0x080e5, 6913), (0x080e6, 12441), (0x080e9, 3818), (0x080ec, 1598), (0x080ed, 7342),
+    (0x080ef, 7351), (0x080f0, 7340), (0x080f1, 7338), (0x080f2, 12916), (0x080f3, 7346), (0x080f4, 7343), (0x080f5, 12918),
+    (0x080f6, 1335), (0x080f8, 7345), (0x080f9, 12917), (0x080fa, 12915), (0x080fb, 12920), (0x080fc, 7350), (0x080fd, 7348),
+    (0x080fe, 14138), (0x08100, 12921), (0x08101, 12919), (0x08102, 7339), (0x08103, 4961), (0x08105, 7341), (0x08106, 7344),
+    (0x08107, 2166), (0x08108, 7347), (0x08109, 3178), (0x0810a, 7349), (0x0810c, 3820), (0x0810e, 3822), (0x08112, 3823),
+    (0x08114, 3825), (0x08115, 13516), (0x08116, 7823), (0x08117, 4516), (0x08118, 13507), (0x08119, 13509), (0x0811a, 2167),
+    (0x0811b, 13510), (0x0811d, 13518), (0x0811e, 13514), (0x0811f, 13512), (0x08121, 13515), (0x08122, 13519), (0x08123, 7824),
+    (0x08124, 7828), (0x08125, 13508), (0x08127, 13517), (0x08129, 7826), (0x0812a, 2854), (0x0812b, 7825), (0x0812c, 13513),
+    (0x0812d, 13511), (0x0812f, 7822), (0x08130, 7827), (0x08132, 4528), (0x08134, 1601), (0x08137, 3175), (0x08139, 8266),
+    (0x0813a, 14147), (0x0813d, 14145), (0x0813e, 8268), (0x08142, 3179), (0x08143, 14140), (0x08144, 14821), (0x08146, 8267),
+    (0x08147, 14144), (0x08148, 4525), (0x0814a, 14141), (0x0814b, 8263), (0x0814c, 8269), (0x0814d, 14146), (0x0814e, 8265),
+    (0x0814f, 14143), (0x08150, 9113), (0x08151, 8264), (0x08152, 14142), (0x08153, 8270), (0x08154, 8262), (0x08155, 8261),
+    (0x08156, 3828), (0x08159, 3829), (0x0815a, 3830), (0x0815b, 14813), (0x0815c, 14811), (0x0815e, 14817), (0x08160, 14809),
+    (0x08161, 14822), (0x08162, 14814), (0x08164, 14808), (0x08165, 8694), (0x08166, 8700), (0x08167, 14819), (0x08169, 14812),
+    (0x0816b, 8697), (0x0816c, 1880), (0x0816d, 1597), (0x0816e, 8695), (0x0816f, 14820), (0x08170, 8692), (0x08171, 8691),
+    (0x08172, 14815), (0x08173, 8696), (0x08174, 8271), (0x08176, 14818), (0x08177, 14810), (0x08178, 8693), (0x08179, 8698),
+    (0x0817a, 8699), (0x0817c, 3833), (0x0817f, 9118), (0x08180, 9114), (0x08182, 9119), (0x08183, 15454), (0x08184, 3834),
+    (0x08186, 15453), (0x08187, 15455), (0x08188, 9116), (0x08189, 15452), (0x0818a, 9117), (0x0818b, 15458), (0x0818c, 15457),
+    (0x0818d, 15456), (0x0818f, 9115), (0x08193, 3836), (0x08195, 16015), (0x08197, 16018), (0x08198, 9490), (0x08199, 16017),
+    (0x0819a, 9489), (0x0819b, 9485), (0x0819c, 9486), (0x0819d, 9487), (0x0819e, 16014), (0x0819f, 16013), (0x081a0, 9488),
+    (0x081a2, 16016), (0x081a3, 16012), (0x081a5, 3838), (0x081a6, 16620), (0x081a7, 16630), (0x081a8, 9813), (0x081a9, 9812),
+    (0x081aa, 1505), (0x081ab, 16624), (0x081ac, 16626), (0x081ae, 16621), (0x081b0, 16625), (0x081b1, 16619), (0x081b2, 16628),
+    (0x081b3, 9811), (0x081b4, 16627), (0x081b5, 16623), (0x081b6, 4586), (0x081b7, 16629), (0x081b9, 16622), (0x081ba, 10100),
+    (0x081bb, 17090), (0x081bc, 17096), (0x081bd, 10104), (0x081be, 10106), (0x081bf, 10103), (0x081c0, 10102), (0x081c1, 3840),
+    (0x081c2, 10101), (0x081c3, 10099), (0x081c4, 17091), (0x081c5, 17094), (0x081c6, 10098), (0x081c7, 17095), (0x081c8, 4969),
+    (0x081c9, 10105), (0x081ca, 17093), (0x081cc, 17092), (0x081cd, 10299), (0x081cf, 10300), (0x081d0, 17490), (0x081d1, 17488),
+    (0x081d2, 17489), (0x081d5, 17837), (0x081d7, 17836), (0x081d8, 10454), (0x081d9, 18137), (0x081da, 10585), (0x081db, 18136),
+    (0x081dd, 18354), (0x081de, 18531), (0x081df, 10744), (0x081e0, 18843), (0x081e1, 18844), (0x081e2, 10794), (0x081e3, 5929),
+    (0x081e4, 3841), (0x081e5, 6554), (0x081e6, 14148), (0x081e7, 9120), (0x081e8, 10107), (0x081e9, 17097), (0x081ea, 5930),
+    (0x081ec, 7353), (0x081ed, 7352), (0x081ee, 14149), (0x081ef, 19723), (0x081f2, 16631), (0x081f3, 5931), (0x081f4, 6924),
+    (0x081f6, 3707), (0x081f7, 14150), (0x081f8, 14151), (0x081f9, 14152), (0x081fa, 9121), (0x081fb, 9814), (0x081fc, 5932),
+    (0x081fe, 6555), (0x081ff, 12443), (0x08200, 7354), (0x08201, 12922), (0x08202, 7829), (0x08204, 14153), (0x08205, 8701),
+    (0x08207, 9122), (0x08208, 9815), (0x08209, 10108), (0x0820a, 10301), (0x0820b, 18138), (0x0820c, 5933), (0x0820d, 6556),
+    (0x08210, 7355), (0x08211, 13520), (0x08212, 8272), (0x08214, 9123), (0x08215, 15459), (0x08216, 16019), (0x08218, 2175),
+    (0x0821a, 4955), (0x0821b, 5934), (0x0821c, 8273), (0x0821d, 14823), (0x0821e, 9124), (0x0821f, 5935), (0x08220, 12011),
+    (0x08221, 12444), (0x08222, 6925), (0x08225, 12924), (0x08226, 19523), (0x08228, 7358), (0x08229, 2181), (0x0822a, 7356),
+    (0x0822b, 7357), (0x0822c, 7359), (0x0822d, 4646), (0x0822f, 12923), (0x08232, 13525), (0x08233, 13522), (0x08234, 13524),
+    (0x08235, 7830), (0x08236, 7832), (0x08237, 7831), (0x08238, 13521), (0x08239, 7833), (0x0823a, 13523), (0x0823c, 14154),
+    (0x0823d, 14155), (0x0823e, 1352), (0x0823f, 14156), (0x08240, 14826), (0x08242, 14827), (0x08244, 14825), (0x08245, 14828),
+    (0x08247, 8702), (0x08249, 14824), (0x0824b, 9125), (0x0824e, 16024), (0x0824f, 16020), (0x08250, 16023), (0x08251, 16025),
+    (0x08252, 16022), (0x08253, 16021), (0x08254, 3842), (0x08255, 16632), (0x08256, 16633), (0x08257, 16634), (0x08258, 9816),
+    (0x08259, 9817), (0x0825a, 17099), (0x0825b, 17098), (0x0825c, 17100), (0x0825e, 17492), (0x0825f, 17491), (0x08261, 17839),
+    (0x08262, 3183), (0x08263, 17840), (0x08264, 17838), (0x08265, 3184), (0x08266, 10586), (0x08268, 18139), (0x08269, 18140),
+    (0x0826b, 18532), (0x0826c, 18667), (0x0826d, 18772), (0x0826e, 5936), (0x0826f, 6184), (0x08271, 10109), (0x08272, 5937),
+    (0x08274, 13526), (0x08275, 14157), (0x08276, 3845), (0x08277, 10834), (0x08278, 11471), (0x08279, 3778), (0x0827a, 1310),
+    (0x0827b, 2206), (0x0827c, 11472), (0x0827d, 11474), (0x0827e, 5938), (0x0827f, 11475), (0x08280, 11473), (0x08283, 11682),
+    (0x08284, 11683), (0x08285, 11677), (0x08287, 19294), (0x0828a, 11681), (0x0828b, 6186), (0x0828d, 6187), (0x0828e, 11678),
+    (0x0828f, 11676), (0x08290, 11675), (0x08291, 11679), (0x08292, 6185), (0x08293, 11680), (0x08294, 12445), (0x08298, 12016),
+    (0x08299, 6559), (0x0829a, 12015), (0x0829b, 12017), (0x0829d, 6558), (0x0829e, 12022), (0x0829f, 6562), (0x082a0, 12012),
+    (0x082a1, 12026), (0x082a2, 12032), (0x082a3, 6569), (0x082a4, 12029), (0x082a5, 6566), (0x082a6, 3844), (0x082a7, 12019),
+    (0x082a8, 12025), (0x082a9, 12027), (0x082aa, 2194), (0x082ab, 12014), (0x082ac, 6565), (0x082ad, 6560), (0x082ae, 12020),
+    (0x082af, 6567), (0x082b0, 6570), (0x082b1, 6564), (0x082b3, 6557), (0x082b4, 12024), (0x082b5, 12018), (0x082b6, 12031),
+    (0x082b7, 6572), (0x082b8, 6568), (0x082b9, 6563), (0x082ba, 12023), (0x082bb, 7360), (0x082bc, 12021), (0x082bd, 6561),
+    (0x082be, 6571), (0x082bf, 1837), (0x082c0, 12013), (0x082c2, 12028), (0x082c3, 12030), (0x082c4, 1588), (0x082ca, 3846),
+    (0x082cf, 1311), (0x082d0, 2199), (0x082d1, 6942), (0x082d2, 6936), (0x082d3, 6944), (0x082d4, 6941), (0x082d5, 12452),
+    (0x082d6, 12455), (0x082d7, 6937), (0x082d8, 3847), (0x082d9, 12446), (0x082db, 6930), (0x082dc, 6940), (0x082de, 6943),
+    (0x082df, 6945), (0x082e0, 12467), (0x082e1, 12458), (0x082e2, 2207), (0x082e3, 6929), (0x082e4, 12466), (0x082e5, 6933),
+    (0x082e6, 6931), (0x082e7, 6926), (0x082e8, 12450), (0x082ea, 12465), (0x082eb, 12454), (0x082ec, 12457), (0x082ed, 12470),
+    (0x082ee, 19010), (0x082ef, 6946), (0x082f0, 12464), (0x082f1, 6938), (0x082f2, 12459), (0x082f3, 12469), (0x082f4, 12456),
+    (0x082f5, 12460), (0x082f6, 12463), (0x082f7, 4526), (0x082f8, 1810), (0x082f9, 12448), (0x082fa, 12468), (0x082fb, 12462),
+    (0x082fc, 19289), (0x082fd, 4102), (0x082fe, 12447), (0x082ff, 3848), (0x08300, 12451), (0x08301, 6939), (0x08302, 6934),
+    (0x08303, 6927), (0x08304, 6932), (0x08305, 6928), (0x08306, 6947), (0x08307, 12449), (0x08308, 12941), (0x08309, 6935),
+    (0x0830b, 4593), (0x0830c, 12461), (0x0830d, 12090), (0x08316, 12944), (0x08317, 7374), (0x08318, 2208), (0x08319, 12928),
+    (0x0831a, 2200), (0x0831b, 12939), (0x0831c, 12935), (0x0831d, 2215), (0x0831e, 12954), (0x08320, 12946), (0x08322, 12936),
+    (0x08324, 12945), (0x08325, 12930), (0x08326, 12934), (0x08327, 12957), (0x08328, 7377), (0x08329, 12949), (0x0832a, 12940),
+    (0x0832b, 7361), (0x0832c, 12955), (0x0832d, 12926), (0x0832f, 12948), (0x08331, 7376), (0x08332, 7371), (0x08333, 12925),
+    (0x08334, 7369), (0x08335, 7368), (0x08336, 7373), (0x08337, 12947), (0x08338, 7365), (0x08339, 7372), (0x0833a, 12453),
+    (0x0833b, 14158), (0x0833c, 12942), (0x0833d, 3617), (0x0833f, 12932), (0x08340, 7375), (0x08341, 12933), (0x08342, 12937),
+    (0x08343, 7378), (0x08344, 12927), (0x08345, 12951), (0x08347, 12950), (0x08348, 12958), (0x08349, 7367), (0x0834a, 7364),
+    (0x0834b, 12956), (0x0834c, 12952), (0x0834d, 12943), (0x0834e, 12938), (0x0834f, 7370), (0x08350, 7366), (0x08351, 12929),
+    (0x08352, 7362), (0x08353, 12953), (0x08354, 7363), (0x08356, 12931), (0x08357, 3850), (0x08362, 2188), (0x08363, 19489),
+    (0x08366, 4346), (0x0836f, 1312), (0x08373, 13532), (0x08374, 13534), (0x08375, 13539), (0x08376, 13559), (0x08377, 7847),
+    (0x08378, 7837), (0x0837a, 13531), (0x0837b, 7848), (0x0837c, 7849), (0x0837d, 13542), (0x0837e, 13549), (0x0837f, 13555),
+    (0x08381, 13536), (0x08383, 13543), (0x08385, 2217), (0x08386, 7850), (0x08387, 13557), (0x08388, 13552), (0x08389, 7845),
+    (0x0838a, 7843), (0x0838b, 13548), (0x0838c, 13544), (0x0838d, 13530), (0x0838e, 7834), (0x0838f, 13535), (0x08390, 13527),
+    (0x08391, 2245), (0x08392, 7842), (0x08393, 7844), (0x08394, 13540), (0x08395, 13537), (0x08396, 7839), (0x08397, 13553),
+    (0x08398, 7836), (0x08399, 13538), (0x0839a, 13560), (0x0839b, 13546), (0x0839c, 2076), (0x0839d, 13545), (0x0839e, 7835),
+    (0x083a0, 7846), (0x083a2, 7838), (0x083a3, 13528), (0x083a4, 13533), (0x083a5, 13550), (0x083a6, 13556), (0x083a7, 7851),
+    (0x083a8, 13529), (0x083a9, 13541), (0x083aa, 13547), (0x083ab, 7841), (0x083ac, 2220), (0x083ae, 13558), (0x083af, 13551),
+    (0x083b0, 13554), (0x083b9, 19679), (0x083bd, 7840), (0x083be, 4810), (0x083bf, 14174), (0x083c0, 14162), (0x083c1, 8281),
+    (0x083c2, 14191), (0x083c3, 14199), (0x083c4, 14202), (0x083c5, 8279), (0x083c6, 14170), (0x083c7, 14195), (0x083c8, 14171),
+    (0x083c9, 14185), (0x083ca, 8292), (0x083cb, 14181), (0x083cc, 8289), (0x083cd, 19071), (0x083ce, 14182), (0x083cf, 14159),
+    (0x083d1, 14196), (0x083d3, 2222), (0x083d4, 8298), (0x083d5, 14193), (0x083d6, 14183), (0x083d7, 14204), (0x083d8, 14178),
+    (0x083d9, 14860), (0x083db, 14207), (0x083dc, 8296), (0x083dd, 14176), (0x083de, 14188), (0x083df, 8299), (0x083e0, 8278),
+    (0x083e1, 14180), (0x083e2, 14205), (0x083e3, 14173), (0x083e4, 14166), (0x083e5, 14177), (0x083e7, 14165), (0x083e8, 14163),
+    (0x083e9, 8274), (0x083ea, 14197), (0x083eb, 14172), (0x083ec, 14200), (0x083ed, 3619), (0x083ee, 14201), (0x083ef, 8282),
+    (0x083f0, 8287), (0x083f1, 8283), (0x083f2, 8291), (0x083f3, 14192), (0x083f4, 8284), (0x083f5, 14184), (0x083f6, 14168),
+    (0x083f8, 8276), (0x083f9, 14160), (0x083fa, 14194), (0x083fb, 14203), (0x083fc, 14167), (0x083fd, 8290), (0x083fe, 14208),
+    (0x083ff, 14179), (0x08401, 14175), (0x08403, 8275), (0x08404, 8295), (0x08405, 3853), (0x08406, 14190), (0x08407, 8297),
+    (0x08409, 14186), (0x0840a, 8286), (0x0840b, 8280), (0x0840c, 8288), (0x0840d, 8277), (0x0840e, 8294), (0x0840f, 14187),
+    (0x08410, 14169), (0x08411, 14189), (0x08412, 14164), (0x08413, 14198), (0x08414, 3334), (0x08416, 19041), (0x08418, 4529),
+    (0x0841b, 14206), (0x0841c, 4587), (0x08420, 3403), (0x08421, 2080), (0x08423, 14161), (0x08424, 19364), (0x08426, 3673),
+    (0x08429, 14859), (0x0842b, 14881), (0x0842c, 8656), (0x0842d, 14864), (0x0842e, 19555), (0x0842f, 14862), (0x08430, 14841),
+    (0x08431, 8706), (0x08432, 14857), (0x08433, 14877), (0x08434, 14853), (0x08435, 8714), (0x08436, 14876), (0x08437, 14851),
+    (0x08438, 8293), (0x08439, 14867), (0x0843a, 14852), (0x0843b, 14874), (0x0843c, 8713), (0x0843d, 8705), (0x0843e, 3608),
+    (0x0843f, 14830), (0x08440, 14838), (0x08442, 14863), (0x08443, 14855), (0x08444, 14880), (0x08445, 14858), (0x08446, 8719),
+    (0x08447, 14875), (0x08448, 3762), (0x08449, 8710), (0x0844a, 2236), (0x0844b, 14861), (0x0844c, 14869), (0x0844d, 14842),
+    (0x0844e, 14868), (0x08450, 14885), (0x08451, 14837), (0x08452, 14870), (0x08453, 3186), (0x08454, 14883), (0x08455, 2674),
+    (0x08456, 14831), (0x08457, 8285), (0x08458, 2230), (0x08459, 14845), (0x0845a, 14844), (0x0845b, 8712), (0x0845c, 4664),
+    (0x0845d, 14848), (0x0845e, 14850), (0x0845f, 14865), (0x08460, 14882), (0x08461, 8715), (0x08462, 19732), (0x08463, 8716),
+    (0x08464, 3855), (0x08465, 14836), (0x08466, 8708), (0x08467, 14840), (0x08468, 14878), (0x08469, 8717), (0x0846b, 8709),
+    (0x0846c, 8711), (0x0846d, 8718), (0x0846e, 14884), (0x0846f, 14871), (0x08470, 14866), (0x08471, 2232), (0x08472, 3466),
+    (0x08473, 14847), (0x08474, 14846), (0x08475, 8707), (0x08476, 14832), (0x08477, 8704), (0x08478, 14856), (0x08479, 14833),
+    (0x0847a, 14854), (0x0847d, 14843), (0x0847e, 14879), (0x0847f, 2613), (0x08480, 3171), (0x08482, 8703), (0x08486, 14839),
+    (0x08488, 3857), (0x0848d, 14835), (0x0848e, 14873), (0x0848f, 14834), (0x08490, 9138), (0x08491, 15499), (0x08492, 2086),
+    (0x08493, 2247), (0x08494, 15482), (0x08496, 3088), (0x08497, 15460), (0x08498, 15490), (0x08499, 9130), (0x0849a, 15475),
+    (0x0849b, 15485), (0x0849c, 9133), (0x0849d, 15478), (0x0849e, 9131), (0x0849f, 15463), (0x084a0, 15493), (0x084a1, 15462),
+    (0x084a2, 15481), (0x084a3, 3219), (0x084a4, 15461), (0x084a7, 15479), (0x084a8, 15488), (0x084a9, 15486), (0x084aa, 15474),
+    (0x084ab, 15469), (0x084ac, 15467), (0x084ad, 4069), (0x084ae, 15468), (0x084af, 15487), (0x084b0, 15498), (0x084b1, 15476),
+    (0x084b2, 9132), (0x084b4, 15471), (0x084b6, 15491), (0x084b8, 9135), (0x084b9, 15470), (0x084ba, 15464), (0x084bb, 15480),
+    (0x084bc, 9139), (0x084bd, 3394), (0x084be, 3859), (0x084bf, 9127), (0x084c0, 9136), (0x084c1, 15472), (0x084c2, 15466),
+    (0x084c4, 9129), (0x084c5, 14872), (0x084c6, 9128), (0x084c7, 15483), (0x084c9, 9126), (0x084ca, 9141), (0x084cb, 9134),
+    (0x084cc, 15484), (0x084cd, 15473), (0x084ce, 15465), (0x084cf, 15492), (0x084d0, 15477), (0x084d1, 9140), (0x084d2, 15496),
+    (0x084d3, 9137), (0x084d4, 15495), (0x084d6, 15489), (0x084d7, 15494), (0x084da, 4976), (0x084db, 15497), (0x084de, 2243),
+    (0x084e1, 3860), (0x084e2, 2187), (0x084e4, 2248), (0x084e5, 3270), (0x084e7, 16065), (0x084e8, 16066), (0x084e9, 16053),
+    (0x084ea, 16052), (0x084eb, 16048), (0x084ec, 9502), (0x084ee, 9494), (0x084ef, 16068), (0x084f0, 16067), (0x084f1, 14829),
+    (0x084f2, 16045), (0x084f3, 16049), (0x084f4, 16043), (0x084f6, 16062), (0x084f7, 16047), (0x084f8, 3861), (0x084f9, 16069),
+    (0x084fa, 16040), (0x084fb, 16038), (0x084fc, 16050), (0x084fd, 16060), (0x084fe, 16055), (0x084ff, 9504), (0x08500, 16029),
+    (0x08502, 16059), (0x08503, 2671), (0x08505, 19221), (0x08506, 9505), (0x08507, 14849), (0x08508, 16041), (0x08509, 16032),
+    (0x0850a, 16035), (0x0850b, 16073), (0x0850c, 16042), (0x0850d, 16033), (0x0850e, 16031), (0x0850f, 16028), (0x08510, 3862),
+    (0x08511, 9498), (0x08512, 16051), (0x08513, 9497), (0x08514, 9501), (0x08515, 16046), (0x08516, 16054), (0x08517, 9491),
+    (0x08518, 16070), (0x08519, 16074), (0x0851a, 9493), (0x0851c, 16037), (0x0851d, 16057), (0x0851e, 16061), (0x0851f, 16034),
+    (0x08520, 16071), (0x08521, 9500), (0x08523, 9499), (0x08524, 16026), (0x08525, 9503), (0x08526, 16064), (0x08527, 16036),
+    (0x08528, 16056), (0x08529, 16030), (0x0852a, 16044), (0x0852b, 16039), (0x0852c, 9495), (0x0852d, 9496), (0x0852e, 16058),
+    (0x0852f, 16075), (0x08530, 16072), (0x08531, 16063), (0x08533, 19550), (0x08534, 2253), (0x08538, 3863), (0x0853b, 16027),
+    (0x0853d, 9492), (0x0853e, 16651), (0x08540, 16642), (0x08541, 16645), (0x08542, 19084), (0x08543, 9823), (0x08544, 16647),
+    (0x08545, 16636), (0x08546, 16643), (0x08547, 16649), (0x08548, 9820), (0x08549, 9824), (0x0854a, 9818), (0x0854b, 2198),
+    (0x0854c, 19001), (0x0854d, 16638), (0x0854e, 16654), (0x08551, 16648), (0x08552, 3864), (0x08553, 16639), (0x08554, 16663),
+    (0x08555, 16657), (0x08556, 16635), (0x08557, 17119), (0x08558, 16641), (0x08559, 9819), (0x0855a, 2226), (0x0855b, 16652),
+    (0x0855d, 16662), (0x0855e, 9827), (0x0855f, 1818), (0x08560, 16659), (0x08561, 16640), (0x08562, 16646), (0x08563, 16650),
+    (0x08564, 16644), (0x08565, 16664), (0x08566, 16661), (0x08567, 16658), (0x08568, 9821), (0x08569, 9822), (0x0856a, 9826),
+    (0x0856b, 16637), (0x0856c, 16665), (0x0856d, 9825), (0x0856e, 16655), (0x0856f, 3866), (0x08570, 3867), (0x08571, 16653),
+    (0x08573, 2257), (0x08575, 16656), (0x08576, 17132), (0x08577, 17113), (0x08578, 17118), (0x08579, 17131), (0x0857a, 17117),
+    (0x0857b, 17109), (0x0857c, 17114), (0x0857e, 10112), (0x08580, 17102), (0x08581, 17126), (0x08582, 17128), (0x08583, 17101),
+    (0x08584, 10111), (0x08585, 17130), (0x08586, 17122), (0x08587, 10118), (0x08588, 17129), (0x08589, 17115), (0x0858a, 10120),
+    (0x0858b, 17107), (0x0858c, 16660), (0x0858d, 17123), (0x0858e, 17120), (0x0858f, 17103), (0x08590, 17134), (0x08591, 10114),
+    (0x08593, 996), (0x08594, 10115), (0x08595, 17105), (0x08596, 17121), (0x08597, 1811), (0x08598, 17133), (0x08599, 17124),
+    (0x0859a, 17111), (0x0859b, 10117), (0x0859c, 10113), (0x0859d, 17125), (0x0859e, 17112), (0x0859f, 17135), (0x085a0, 17106),
+    (0x085a1, 17116), (0x085a2, 17127), (0x085a3, 17108), (0x085a4, 17110), (0x085a6, 10310), (0x085a7, 17104), (0x085a8, 10119),
+    (0x085a9, 10303), (0x085aa, 10110), (0x085af, 10116), (0x085b0, 10307), (0x085b1, 17508), (0x085b3, 17498), (0x085b4, 17493),
+    (0x085b5, 17499), (0x085b6, 17509), (0x085b7, 17513), (0x085b8, 17512), (0x085b9, 10309), (0x085ba, 10308), (0x085bd, 17500),
+    (0x085be, 17514), (0x085bf, 17503), (0x085c0, 17495), (0x085c1, 2263), (0x085c2, 17497), (0x085c3, 17496), (0x085c4, 17502),
+    (0x085c5, 17507), (0x085c6, 17494), (0x085c7, 17501), (0x085c8, 17506), (0x085c9, 10306), (0x085cb, 17504), (0x085cd, 10304),
+    (0x085ce, 17505), (0x085cf, 10302), (0x085d0, 10305), (0x085d1, 17856), (0x085d2, 17510), (0x085d5, 10458), (0x085d6, 1835),
+    (0x085d7, 17848), (0x085d8, 17852), (0x085d9, 17844), (0x085da, 17847), (0x085dc, 17855), (0x085dd, 10456), (0x085de, 17860),
+    (0x085df, 17853), (0x085e0, 3868), (0x085e1, 17845), (0x085e2, 17861), (0x085e3, 17854), (0x085e4, 10459), (0x085e5, 10460),
+    (0x085e6, 17858), (0x085e8, 17846), (0x085e9, 10455), (0x085ea, 10457), (0x085eb, 17841), (0x085ec, 17849), (0x085ed, 17843),
+    (0x085ee, 3404), (0x085ef, 17859), (0x085f0, 17857), (0x085f1, 17842), (0x085f2, 17850), (0x085f6, 18147), (0x085f7, 10461),
+    (0x085f8, 17851), (0x085f9, 10588), (0x085fa, 10590), (0x085fb, 10587), (0x085fc, 3398), (0x085fd, 18152), (0x085fe, 18144),
+    (0x085ff, 18142), (0x08600, 18146), (0x08601, 18143), (0x08602, 2265), (0x08604, 18148), (0x08605, 18150), (0x08606, 10591),
+    (0x08607, 10593), (0x08609, 18149), (0x0860a, 10594), (0x0860b, 10592), (0x0860c, 18151), (0x0860d, 19086), (0x0860f, 1760),
+    (0x08610, 3188), (0x08611, 10589), (0x08614, 2866), (0x08616, 2260), (0x08617, 10665), (0x08618, 18355), (0x08619, 18361),
+    (0x0861a, 10667), (0x0861b, 18145), (0x0861c, 18360), (0x0861e, 18367), (0x0861f, 18358), (0x08620, 18365), (0x08621, 18364),
+    (0x08622, 18141), (0x08623, 18359), (0x08624, 17511), (0x08625, 18368), (0x08626, 18357), (0x08627, 18362), (0x08628, 2270),
+    (0x08629, 18366), (0x0862a, 18356), (0x0862c, 18536), (0x0862d, 10666), (0x0862e, 18363), (0x0862f, 4883), (0x08631, 18672),
+    (0x08632, 18537), (0x08633, 18535), (0x08634, 18533), (0x08635, 18534), (0x08636, 18538), (0x08638, 10795), (0x08639, 18670),
+    (0x0863a, 18668), (0x0863b, 18673), (0x0863c, 18671), (0x0863e, 18674), (0x0863f, 10796), (0x08640, 18669), (0x08642, 2468),
+    (0x08643, 18773), (0x08645, 3162), (0x08646, 18845), (0x08647, 18846), (0x08648, 18847), (0x0864b, 18941), (0x0864c, 18931),
+    (0x0864d, 11476), (0x0864e, 6573), (0x08650, 6948), (0x08652, 12960), (0x08653, 12959), (0x08654, 7379), (0x08655, 7852),
+    (0x08656, 13562), (0x08659, 13561), (0x0865b, 8300), (0x0865c, 8721), (0x0865e, 8720), (0x0865f, 8722), (0x08661, 15500),
+    (0x08662, 16076), (0x08663, 16666), (0x08664, 16668), (0x08665, 16667), (0x08667, 10121), (0x08668, 17136), (0x08669, 17515),
+    (0x0866a, 18907), (0x0866b, 5939), (0x0866c, 2284), (0x0866d, 12035), (0x0866e, 12036), (0x0866f, 12034), (0x08670, 12033),
+    (0x08671, 6574), (0x08672, 3870), (0x08673, 12474), (0x08674, 12472), (0x08677, 12471), (0x08679, 6949), (0x0867a, 6951),
+    (0x0867b, 6950), (0x0867c, 12473), (0x0867e, 2275), (0x08685, 12972), (0x08686, 12969), (0x08687, 12967), (0x0868a, 7380),
+    (0x0868b, 12970), (0x0868c, 7385), (0x0868d, 12964), (0x0868e, 12979), (0x08690, 12981), (0x08691, 12965), (0x08692, 3871),
+    (0x08693, 7382), (0x08694, 12982), (0x08695, 12977), (0x08696, 12963), (0x08697, 12968), (0x08698, 12978), (0x08699, 12974),
+    (0x0869a, 12971), (0x0869c, 7387), (0x0869d, 12980), (0x0869e, 12966), (0x086a0, 3197), (0x086a1, 12975), (0x086a2, 12961),
+    (0x086a3, 7386), (0x086a4, 7383), (0x086a5, 12973), (0x086a7, 12976), (0x086a8, 12962), (0x086a9, 7384), (0x086aa, 7381),
+    (0x086ad, 4719), (0x086af, 7862), (0x086b0, 13569), (0x086b1, 7861), (0x086b2, 3872), (0x086b3, 13572), (0x086b4, 13575),
+    (0x086b5, 7858), (0x086b6, 7856), (0x086b7, 13564), (0x086b8, 13573), (0x086b9, 13571), (0x086ba, 13568), (0x086bb, 13576),
+    (0x086bc, 13577), (0x086bd, 13579), (0x086be, 13580), (0x086bf, 13563), (0x086c0, 7855), (0x086c1, 13566), (0x086c2, 13565),
+    (0x086c3, 13578), (0x086c4, 7857), (0x086c5, 13567), (0x086c6, 7859), (0x086c7, 7854), (0x086c8, 13570), (0x086c9, 7863),
+    (0x086cb, 7860), (0x086cc, 13574), (0x086d0, 8307), (0x086d1, 14223), (0x086d3, 14212), (0x086d4, 8304), (0x086d6, 14891),
+    (0x086d7, 14221), (0x086d8, 14209), (0x086d9, 8302), (0x086da, 14214), (0x086db, 8305), (0x086dc, 14218), (0x086dd, 14216),
+    (0x086de, 8308), (0x086df, 8301), (0x086e2, 14210), (0x086e3, 14213), (0x086e4, 8306), (0x086e6, 14211), (0x086e8, 14222),
+    (0x086e9, 14220), (0x086ea, 14215), (0x086eb, 14217), (0x086ec, 14219), (0x086ed, 8303), (0x086ef, 3873), (0x086f5, 14892),
+    (0x086f6, 14898), (0x086f7, 14888), (0x086f8, 14894), (0x086f9, 8723), (0x086fa, 14890), (0x086fb, 8729), (0x086fe, 8728),
+    (0x08700, 8727), (0x08701, 14897), (0x08702, 8730), (0x08703, 8731), (0x08704, 14887), (0x08705, 14900), (0x08706, 8732),
+    (0x08707, 8726), (0x08708, 8725), (0x08709, 14896), (0x0870a, 8733), (0x0870b, 14886), (0x0870c, 14889), (0x0870d, 14899),
+    (0x0870e, 14895), (0x08711, 15532), (0x08712, 15521), (0x08713, 8724), (0x08718, 9148), (0x08719, 15509), (0x0871a, 15530),
+    (0x0871b, 15510), (0x0871c, 9143), (0x0871e, 15507), (0x08720, 15516), (0x08721, 15508), (0x08722, 9145), (0x08723, 15502),
+    (0x08724, 15529), (0x08725, 9146), (0x08726, 15526), (0x08727, 15527), (0x08728, 15503), (0x08729, 9151), (0x0872a, 15518),
+    (0x0872c, 15512), (0x0872d, 15519), (0x0872e, 15506), (0x08730, 15531), (0x08731, 15523), (0x08732, 15517), (0x08733, 15501),
+    (0x08734, 9147), (0x08735, 15524), (0x08737, 9150), (0x08738, 15528), (0x0873a, 15522), (0x0873b, 9144), (0x0873c, 15520),
+    (0x0873e, 15514), (0x0873f, 9142), (0x08740, 15505), (0x08741, 15513), (0x08742, 15525), (0x08743, 15511), (0x08746, 15515),
+    (0x0874c, 9515), (0x0874d, 14893), (0x0874e, 16094), (0x0874f, 16103), (0x08750, 16093), (0x08751, 16089), (0x08752, 16086),
+    (0x08753, 9516), (0x08754, 16084), (0x08755, 9149), (0x08756, 16077), (0x08757, 9514), (0x08758, 16083), (0x08759, 9513),
+    (0x0875a, 16088), (0x0875b, 16085), (0x0875c, 16101), (0x0875d, 16096), (0x0875e, 16090), (0x0875f, 16095), (0x08760, 9509),
+    (0x08761, 16087), (0x08762, 16106), (0x08763, 16078), (0x08764, 16079), (0x08765, 16102), (0x08766, 9510), (0x08767, 16107),
+    (0x08768, 9512), (0x08769, 16108), (0x0876a, 16092), (0x0876b, 15504), (0x0876c, 16098), (0x0876d, 16091), (0x0876e, 16100),
+    (0x0876f, 16097), (0x08770, 4511), (0x08771, 2276), (0x08773, 16082), (0x08774, 9507), (0x08775, 16105), (0x08776, 9508),
+    (0x08777, 16080), (0x08778, 9511), (0x08779, 16678), (0x0877a, 16099), (0x0877b, 16104), (0x0877d, 19055), (0x08781, 16675),
+    (0x08782, 9506), (0x08783, 9828), (0x08784, 16685), (0x08785, 16681), (0x08786, 4584), (0x08787, 16679), (0x08788, 16674),
+    (0x08789, 16689), (0x0878b, 3875), (0x0878c, 4970), (0x0878d, 9832), (0x0878f, 16670), (0x08790, 16682), (0x08791, 16683),
+    (0x08792, 16673), (0x08793, 16672), (0x08794, 16686), (0x08796, 16676), (0x08797, 16671), (0x08798, 16677), (0x0879a, 16688),
+    (0x0879b, 16669), (0x0879c, 16687), (0x0879d, 16684), (0x0879e, 9830), (0x0879f, 9829), (0x087a2, 9831), (0x087a3, 16680),
+    (0x087a4, 17532), (0x087a5, 4046), (0x087a9, 4967), (0x087aa, 17138), (0x087ab, 10127), (0x087ac, 17142), (0x087ad, 17139),
+    (0x087ae, 17146), (0x087af, 17152), (0x087b0, 17141), (0x087b1, 2280), (0x087b2, 17161), (0x087b3, 10124), (0x087b4, 17155),
+    (0x087b5, 17144), (0x087b6, 17156), (0x087b7, 17151), (0x087b8, 17158), (0x087b9, 17143), (0x087ba, 10129), (0x087bb, 10128),
+    (0x087bc, 17145), (0x087bd, 17159), (0x087be, 17137), (0x087bf, 17157), (0x087c0, 10122), (0x087c1, 4984), (0x087c2, 17149),
+    (0x087c3, 17148), (0x087c4, 17153), (0x087c5, 17140), (0x087c6, 10126), (0x087c8, 10130), (0x087c9, 17147), (0x087ca, 17154),
+    (0x087cb, 10131), (0x087cc, 17150), (0x087ce, 4968), (0x087d1, 10123), (0x087d2, 10125), (0x087d3, 17528), (0x087d4, 17526),
+    (0x087d6, 4870), (0x087d7, 17533), (0x087d8, 17530), (0x087d9, 17534), (0x087da, 2281), (0x087db, 17519), (0x087dc, 17527),
+    (0x087dd, 17538), (0x087de, 17160), (0x087df, 17523), (0x087e0, 10314), (0x087e1, 16081), (0x087e2, 17518), (0x087e3, 17531),
+    (0x087e4, 17525), (0x087e5, 17522), (0x087e6, 17517), (0x087e7, 17516), (0x087e8, 17537), (0x087ea, 17521), (0x087eb, 17520),
+    (0x087ec, 10312), (0x087ed, 17529), (0x087ee, 2278), (0x087ef, 10311), (0x087f2, 10313), (0x087f3, 17524), (0x087f4, 17536),
+    (0x087f5, 4900), (0x087f6, 17865), (0x087f7, 17866), (0x087f9, 10465), (0x087fa, 17863), (0x087fb, 10462), (0x087fc, 17871),
+    (0x087fe, 10466), (0x087ff, 17873), (0x08800, 17862), (0x08801, 17535), (0x08802, 17875), (0x08803, 17864), (0x08804, 4583),
+    (0x08805, 10463), (0x08806, 17870), (0x08808, 17872), (0x08809, 17867), (0x0880a, 17874), (0x0880b, 17869), (0x0880c, 17868),
+    (0x0880d, 10464), (0x0880f, 2282), (0x08810, 18154), (0x08811, 18155), (0x08813, 18157), (0x08814, 10595), (0x08815, 10596),
+    (0x08816, 18158), (0x08817, 18156), (0x08818, 4844), (0x08819, 18153), (0x0881b, 18371), (0x0881c, 18374), (0x0881d, 18370),
+    (0x0881f, 10671), (0x08820, 18372), (0x08821, 10670), (0x08822, 10669), (0x08823, 10668), (0x08824, 18373), (0x08825, 18543),
+    (0x08826, 18541), (0x08827, 4319), (0x08828, 18540), (0x08829, 18369), (0x0882a, 18542), (0x0882b, 18375), (0x0882c, 18539),
+    (0x0882d, 3200), (0x0882e, 18677), (0x0882f, 18643), (0x08830, 18675), (0x08831, 10797), (0x08832, 18676), (0x08833, 18678),
+    (0x08835, 18776), (0x08836, 10828), (0x08837, 18775), (0x08838, 18774), (0x08839, 10829), (0x0883b, 10859), (0x0883c, 18886),
+    (0x0883d, 18909), (0x0883e, 18908), (0x0883f, 18910), (0x08840, 5940), (0x08841, 12475), (0x08842, 4811), (0x08843, 12983),
+    (0x08844, 12984), (0x08845, 2287), (0x08846, 2288), (0x08848, 14224), (0x0884a, 18376), (0x0884b, 18777), (0x0884c, 5941),
+    (0x0884d, 6952), (0x0884e, 12476), (0x0884f, 2336), (0x08852, 13581), (0x08853, 7864), (0x08855, 14226), (0x08856, 14225),
+    (0x08857, 8309), (0x08859, 8734), (0x0885a, 16109), (0x0885b, 9517), (0x0885d, 9518), (0x0885e, 2292), (0x08860, 2868),
+    (0x08861, 9833), (0x08862, 10830), (0x08863, 5942), (0x08864, 1700), (0x08865, 4299), (0x08867, 12477), (0x08868, 6576),
+    (0x08869, 12479), (0x0886a, 12478), (0x0886b, 6953), (0x0886d, 12985), (0x0886e, 3203), (0x0886f, 12992), (0x08870, 7388),
+    (0x08871, 12990), (0x08872, 12988), (0x08874, 12995), (0x08875, 12986), (0x08876, 12987), (0x08877, 7389), (0x08879, 7393),
+    (0x0887c, 12996), (0x0887d, 7392), (0x0887e, 12994), (0x0887f, 12991), (0x08880, 12989), (0x08881, 7390), (0x08882, 7391),
+    (0x08883, 12993), (0x08884, 19763), (0x08887, 3205), (0x08888, 7866), (0x08889, 13582), (0x0888b, 7871), (0x0888c, 13598),
+    (0x0888d, 7870), (0x0888e, 13600), (0x0888f, 1732), (0x08890, 1813), (0x08891, 13588), (0x08892, 7868), (0x08893, 13599),
+    (0x08895, 13583), (0x08896, 7869), (0x08897, 13595), (0x08898, 13591), (0x08899, 13593), (0x0889a, 13587), (0x0889b, 13594),
+    (0x0889c, 2293), (0x0889e, 7865), (0x0889f, 13590), (0x088a0, 4885), (0x088a1, 13589), (0x088a2, 13585), (0x088a4, 13596),
+    (0x088a7, 13592), (0x088a8, 13584), (0x088aa, 13586), (0x088ab, 7867), (0x088ac, 13597), (0x088ae, 3878), (0x088b1, 8312),
+    (0x088b2, 14237), (0x088b4, 2295), (0x088b5, 2296), (0x088b6, 14233), (0x088b7, 14235), (0x088b8, 14230), (0x088b9, 14229),
+    (0x088ba, 14227), (0x088bc, 14234), (0x088bd, 14236), (0x088be, 14232), (0x088bf, 3206), (0x088c0, 14231), (0x088c1, 8310),
+    (0x088c2, 8311), (0x088c5, 2298), (0x088c7, 4585), (0x088c9, 14239), (0x088ca, 8742), (0x088cb, 14902), (0x088cc, 14908),
+    (0x088cd, 14903), (0x088ce, 14904), (0x088cf, 18958), (0x088d0, 14909), (0x088d2, 8744), (0x088d4, 8736), (0x088d5, 8743),
+    (0x088d6, 14901), (0x088d7, 14228), (0x088d8, 8739), (0x088d9, 8737), (0x088da, 14907), (0x088db, 14906), (0x088dc, 8738),
+    (0x088dd, 8740), (0x088de, 14905), (0x088df, 8735), (0x088e1, 8741), (0x088e6, 3207), (0x088e7, 15534), (0x088e8, 9158),
+    (0x088eb, 15545), (0x088ec, 15544), (0x088ee, 15539), (0x088ef, 9160), (0x088f0, 15543), (0x088f1, 15535), (0x088f2, 15536),
+    (0x088f3, 9152), (0x088f4, 9154), (0x088f5, 4320), (0x088f6, 15541), (0x088f7, 15533), (0x088f8, 9156), (0x088f9, 9155),
+    (0x088fa, 15537), (0x088fb, 15542), (0x088fc, 15540), (0x088fd, 9157), (0x088fe, 15538), (0x088ff, 3879), (0x08900, 19768),
+    (0x08901, 14238), (0x08902, 9153), (0x08905, 16110), (0x08906, 16117), (0x08907, 9520), (0x08909, 16121), (0x0890a, 9524),
+    (0x0890b, 16113), (0x0890c, 16111), (0x0890e, 16120), (0x08910, 9519), (0x08911, 16119), (0x08912, 9521), (0x08913, 9522),
+    (0x08914, 16112), (0x08915, 9523), (0x08916, 16118), (0x08917, 16114), (0x08918, 16115), (0x08919, 16116), (0x0891a, 9159),
+    (0x0891e, 16690), (0x0891f, 16702), (0x08921, 9838), (0x08922, 16697), (0x08923, 16699), (0x08924, 3880), (0x08925, 9836),
+    (0x08926, 16691), (0x08927, 16695), (0x08929, 16698), (0x0892a, 9834), (0x0892b, 9837), (0x0892c, 16701), (0x0892d, 16693),
+    (0x0892e, 16694), (0x0892f, 16700), (0x08930, 16692), (0x08931, 16696), (0x08932, 9835), (0x08933, 17163), (0x08935, 17162),
+    (0x08936, 10133), (0x08937, 17168), (0x08938, 10135), (0x0893b, 10132), (0x0893c, 17164), (0x0893d, 10136), (0x0893e, 17165),
+    (0x08941, 17166), (0x08942, 17169), (0x08943, 4335), (0x08944, 10134), (0x08946, 17543), (0x08947, 3881), (0x08949, 17546),
+    (0x0894b, 17540), (0x0894c, 17542), (0x0894d, 3209), (0x0894f, 17541), (0x08950, 17544), (0x08951, 17545), (0x08952, 17167),
+    (0x08953, 17539), (0x08954, 3211), (0x08956, 10469), (0x08957, 17879), (0x08958, 17882), (0x08959, 17884), (0x0895a, 17877),
+    (0x0895b, 17878), (0x0895c, 17881), (0x0895d, 17883), (0x0895e, 10470), (0x0895f, 10468), (0x08960, 10467), (0x08961, 17880),
+    (0x08962, 17876), (0x08963, 18159), (0x08964, 10597), (0x08965, 3208), (0x08966, 18160), (0x08969, 18378), (0x0896a, 10672),
+    (0x0896b, 18380), (0x0896c, 10673), (0x0896d, 18377), (0x0896e, 18379), (0x0896f, 10746), (0x08971, 18544), (0x08972, 10745),
+    (0x08973, 18681), (0x08974, 18680), (0x08976, 18679), (0x08977, 4268), (0x08979, 18848), (0x0897a, 18849), (0x0897b, 18851),
+    (0x0897c, 18850), (0x0897e, 11477), (0x0897f, 5943), (0x08980, 3739), (0x08981, 6954), (0x08982, 13601), (0x08983, 8313),
+    (0x08985, 14910), (0x08986, 10315), (0x08987, 2301), (0x08988, 17885), (0x08989, 4963), (0x0898a, 2302), (0x0898b, 6188),
+    (0x0898f, 7873), (0x08991, 3882), (0x08993, 7872), (0x08994, 4954), (0x08995, 14240), (0x08996, 8314), (0x08997, 14242),
+    (0x08998, 14241), (0x0899b, 14911), (0x0899c, 8745), (0x0899d, 15546), (0x0899e, 15549), (0x0899f, 15548), (0x089a1, 15547),
+    (0x089a2, 16122), (0x089a3, 16124), (0x089a4, 16123), (0x089a5, 4106), (0x089a6, 9840), (0x089a7, 2305), (0x089a9, 4981),
+    (0x089aa, 9839), (0x089ac, 10137), (0x089ad, 17170), (0x089ae, 17172), (0x089af, 17171), (0x089b2, 10316), (0x089b6, 17887),
+    (0x089b7, 17886), (0x089b9, 18161), (0x089ba, 10598), (0x089bc, 2306), (0x089bd, 10674), (0x089be, 18546), (0x089bf, 18545),
+    (0x089c0, 10860), (0x089c1, 1701), (0x089c6, 1313), (0x089d2, 6189), (0x089d3, 12480), (0x089d4, 6955), (0x089d5, 13604),
+    (0x089d6, 13602), (0x089d9, 13603), (0x089da, 14244), (0x089db, 14245), (0x089dc, 14918), (0x089dd, 14243), (0x089df, 14912),
+    (0x089e0, 14916), (0x089e1, 14915), (0x089e2, 14917), (0x089e3, 8746), (0x089e4, 14914), (0x089e5, 14913), (0x089e6, 14919),
+    (0x089e7, 2308), (0x089e8, 15552), (0x089e9, 15550), (0x089eb, 15551), (0x089ec, 16127), (0x089ed, 16125), (0x089f0, 16126),
+    (0x089f1, 16703), (0x089f2, 17173), (0x089f3, 17174), (0x089f4, 10317), (0x089f6, 17888), (0x089f7, 18162), (0x089f8, 10599),
+    (0x089fa, 18381), (0x089fb, 18547), (0x089fc, 10747), (0x089fe, 18682), (0x089ff, 18852), (0x08a00, 6190), (0x08a02, 6957),
+    (0x08a03, 6958), (0x08a04, 12481), (0x08a07, 12482), (0x08a08, 6956), (0x08a0a, 7399), (0x08a0c, 7397), (0x08a0e, 7396),
+    (0x08a0f, 7403), (0x08a10, 7395), (0x08a11, 7404), (0x08a12, 12997), (0x08a13, 7401), (0x08a15, 7398), (0x08a16, 7402),
+    (0x08a17, 7400), (0x08a18, 7394), (0x08a1b, 7881), (0x08a1c, 3224), (0x08a1d, 7875), (0x08a1e, 13608), (0x08a1f, 7880),
+    (0x08a22, 7882), (0x08a23, 7876), (0x08a25, 7877), (0x08a27, 13606), (0x08a29, 3884), (0x08a2a, 7874), (0x08a2b, 3271),
+    (0x08a2c, 13607), (0x08a2d, 7879), (0x08a30, 13605), (0x08a31, 7878), (0x08a34, 8325), (0x08a36, 8327), (0x08a38, 3885),
+    (0x08a39, 14248), (0x08a3a, 8326), (0x08a3b, 8315), (0x08a3c, 8319), (0x08a3d, 3223), (0x08a3e, 8765), (0x08a3f, 14924),
+    (0x08a40, 14250), (0x08a41, 8320), (0x08a44, 14253), (0x08a45, 14254), (0x08a46, 8324), (0x08a48, 14256), (0x08a49, 4971),
+    (0x08a4a, 14258), (0x08a4c, 14259), (0x08a4d, 14247), (0x08a4e, 14246), (0x08a4f, 14260), (0x08a50, 8323), (0x08a51, 14257),
+    (0x08a52, 14255), (0x08a54, 8321), (0x08a55, 8317), (0x08a56, 8328), (0x08a57, 14251), (0x08a58, 14252), (0x08a59, 14249),
+    (0x08a5b, 8322), (0x08a5e, 8318), (0x08a60, 8316), (0x08a61, 14923), (0x08a62, 8760), (0x08a63, 8755), (0x08a66, 8750),
+    (0x08a67, 4865), (0x08a68, 8766), (0x08a69, 8751), (0x08a6b, 8747), (0x08a6c, 8762), (0x08a6d, 8759), (0x08a6e, 8761),
+    (0x08a70, 8752), (0x08a71, 8757), (0x08a72, 8748), (0x08a73, 8749), (0x08a74, 14931), (0x08a75, 14928), (0x08a76, 14920),
+    (0x08a77, 14925), (0x08a79, 8763), (0x08a7a, 14932), (0x08a7b, 8764), (0x08a7c, 8754), (0x08a7e, 4891), (0x08a7f, 14922),
+    (0x08a81, 14930), (0x08a82, 14926), (0x08a83, 14929), (0x08a84, 14927), (0x08a85, 8758), (0x08a86, 14921), (0x08a87, 8753),
+    (0x08a8b, 15555), (0x08a8c, 9162), (0x08a8d, 9165), (0x08a8f, 15557), (0x08a90, 2314), (0x08a91, 9173), (0x08a92, 15556),
+    (0x08a93, 9167), (0x08a94, 3886), (0x08a95, 9529), (0x08a96, 15558), (0x08a98, 9172), (0x08a99, 15554), (0x08a9a, 9174),
+    (0x08a9c, 2311), (0x08a9e, 9163), (0x08aa0, 8756), (0x08aa1, 9166), (0x08aa3, 9164), (0x08aa4, 9168), (0x08aa5, 9170),
+    (0x08aa6, 9161), (0x08aa7, 9175), (0x08aa8, 9171), (0x08aa9, 2329), (0x08aaa, 9169), (0x08aab, 15553), (0x08aaf, 2333),
+    (0x08ab0, 9536), (0x08ab2, 9532), (0x08ab4, 3887), (0x08ab6, 9539), (0x08ab8, 16130), (0x08ab9, 9540), (0x08aba, 16142),
+    (0x08abb, 16135), (0x08abc, 9525), (0x08abd, 16143), (0x08abe, 16137), (0x08abf, 9535), (0x08ac0, 16138), (0x08ac2, 9534),
+    (0x08ac3, 16141), (0x08ac4, 9528), (0x08ac5, 16139), (0x08ac6, 16129), (0x08ac7, 9527), (0x08ac8, 16714), (0x08ac9, 9533),
+    (0x08acb, 9530), (0x08acc, 1876), (0x08acd, 9538), (0x08acf, 16128), (0x08ad1, 16132), (0x08ad2, 9526), (0x08ad3, 16131),
+    (0x08ad4, 16133), (0x08ad5, 16134), (0x08ad6, 9537), (0x08ad7, 16136), (0x08ad8, 16140), (0x08ad9, 16144), (0x08ada, 2729),
+    (0x08adb, 9541), (0x08adc, 9846), (0x08add, 16709), (0x08ade, 16715), (0x08adf, 16712), (0x08ae0, 16704), (0x08ae1, 16716),
+    (0x08ae2, 16705), (0x08ae4, 16711), (0x08ae6, 9841), (0x08ae7, 9847), (0x08ae8, 16717), (0x08aea, 4244), (0x08aeb, 9843),
+    (0x08aed, 9853), (0x08aee, 9848), (0x08aef, 16719), (0x08af0, 16713), (0x08af1, 9844), (0x08af2, 16706), (0x08af3, 9854),
+    (0x08af4, 16707), (0x08af5, 16708), (0x08af6, 9855), (0x08af7, 9852), (0x08af8, 9531), (0x08af9, 1861), (0x08afa, 9842),
+    (0x08afb, 16720), (0x08afc, 9856), (0x08afe, 9849), (0x08aff, 16718), (0x08b00, 9845), (0x08b01, 9850), (0x08b02, 9851),
+    (0x08b04, 10145), (0x08b05, 17179), (0x08b06, 17188), (0x08b07, 17185), (0x08b08, 17187), (0x08b0a, 10142), (0x08b0b, 17180),
+    (0x08b0c, 2322), (0x08b0d, 17186), (0x08b0e, 10138), (0x08b0f, 17182), (0x08b10, 10146), (0x08b11, 17178), (0x08b12, 17183),
+    (0x08b13, 17190), (0x08b14, 16710), (0x08b15, 17184), (0x08b16, 17177), (0x08b17, 10139), (0x08b18, 17176), (0x08b19, 10140),
+    (0x08b1a, 17191), (0x08b1b, 10141), (0x08b1c, 17189), (0x08b1d, 10144), (0x08b1e, 17175), (0x08b1f, 2323), (0x08b20, 10143),
+    (0x08b22, 17181), (0x08b23, 17549), (0x08b24, 17563), (0x08b25, 17558), (0x08b26, 17560), (0x08b27, 17548), (0x08b28, 10318),
+    (0x08b2a, 17547), (0x08b2b, 10321), (0x08b2c, 10320), (0x08b2d, 4878), (0x08b2e, 17562), (0x08b2f, 17554), (0x08b30, 17551),
+    (0x08b31, 17557), (0x08b33, 17550), (0x08b35, 17552), (0x08b36, 17561), (0x08b37, 17559), (0x08b39, 10319), (0x08b3a, 17566),
+    (0x08b3b, 17564), (0x08b3c, 17555), (0x08b3d, 17565), (0x08b3e, 17556), (0x08b3f, 2326), (0x08b40, 17892), (0x08b41, 10471),
+    (0x08b42, 17899), (0x08b43, 4973), (0x08b45, 18384), (0x08b46, 10478), (0x08b47, 17553), (0x08b48, 17890), (0x08b49, 10474),
+    (0x08b4a, 17891), (0x08b4b, 17896), (0x08b4c, 2327), (0x08b4d, 2328), (0x08b4e, 10476), (0x08b4f, 10477), (0x08b50, 17889),
+    (0x08b51, 17898), (0x08b52, 17900), (0x08b53, 17893), (0x08b54, 17895), (0x08b55, 17897), (0x08b56, 17894), (0x08b57, 17901),
+    (0x08b58, 10473), (0x08b59, 10479), (0x08b5a, 10475), (0x08b5c, 10472), (0x08b5d, 18165), (0x08b5e, 4180), (0x08b5f, 10604),
+    (0x08b60, 18163), (0x08b62, 4236), (0x08b63, 18167), (0x08b65, 18168), (0x08b66, 10602), (0x08b67, 18169), (0x08b68, 18166),
+    (0x08b69, 3309), (0x08b6a, 18164), (0x08b6b, 10605), (0x08b6c, 10601), (0x08b6d, 18170), (0x08b6f, 10603), (0x08b70, 10600),
+    (0x08b74, 10675), (0x08b77, 10676), (0x08b78, 18383), (0x08b79, 18382), (0x08b7a, 18385), (0x08b7b, 18386), (0x08b7d, 10677),
+    (0x08b7e, 18548), (0x08b7f, 18553), (0x08b80, 10748), (0x08b81, 4073), (0x08b82, 18550), (0x08b83, 1803), (0x08b84, 18549),
+    (0x08b85, 18552), (0x08b86, 18551), (0x08b88, 18686), (0x08b8a, 10798), (0x08b8b, 18685), (0x08b8c, 18683), (0x08b8e, 18684),
+    (0x08b90, 2331), (0x08b92, 10832), (0x08b93, 10831), (0x08b94, 18778), (0x08b95, 18779), (0x08b96, 10833), (0x08b98, 18853),
+    (0x08b99, 18854), (0x08b9a, 10872), (0x08b9b, 2332), (0x08b9c, 10878), (0x08b9e, 18911), (0x08b9f, 18942), (0x08ba0, 1703),
+    (0x08bbe, 1314), (0x08be2, 1315), (0x08c37, 6191), (0x08c39, 13609), (0x08c3b, 13610), (0x08c3c, 14933), (0x08c3d, 15559),
+    (0x08c3e, 16145), (0x08c3f, 10148), (0x08c41, 10147), (0x08c42, 17567), (0x08c43, 17902), (0x08c45, 18687), (0x08c46, 6192),
+    (0x08c47, 12998), (0x08c48, 7405), (0x08c49, 7883), (0x08c4a, 14935), (0x08c4b, 14934), (0x08c4c, 9542), (0x08c4d, 16146),
+    (0x08c4e, 9543), (0x08c4f, 17192), (0x08c50, 10322), (0x08c51, 3888), (0x08c54, 10887), (0x08c55, 6193), (0x08c56, 12037),
+    (0x08c57, 12999), (0x08c5a, 7884), (0x08c5c, 13611), (0x08c5d, 13612), (0x08c5f, 14261), (0x08c61, 8329), (0x08c62, 8767),
+    (0x08c64, 14937), (0x08c65, 14936), (0x08c66, 14938), (0x08c68, 15560), (0x08c69, 15561), (0x08c6a, 9176), (0x08c6b, 9857),
+    (0x08c6c, 9544), (0x08c6d, 9858), (0x08c6f, 17196), (0x08c70, 17193), (0x08c71, 17195), (0x08c72, 17194), (0x08c73, 10149),
+    (0x08c75, 17568), (0x08c76, 17904), (0x08c77, 17903), (0x08c78, 11684), (0x08c79, 7407), (0x08c7a, 7406), (0x08c7b, 13000),
+    (0x08c7d, 13613), (0x08c80, 14263), (0x08c81, 14262), (0x08c82, 8330), (0x08c84, 14940), (0x08c85, 14941), (0x08c86, 14939),
+    (0x08c89, 8769), (0x08c8a, 8768), (0x08c8c, 9178), (0x08c8d, 9177), (0x08c8f, 16147), (0x08c90, 16723), (0x08c91, 16721),
+    (0x08c92, 16722), (0x08c93, 9859), (0x08c94, 17198), (0x08c95, 17197), (0x08c97, 17571), (0x08c98, 17570), (0x08c99, 17569),
+    (0x08c9a, 17905), (0x08c9b, 2337), (0x08c9c, 18912), (0x08c9d, 6194), (0x08c9e, 6959), (0x08c9f, 3234), (0x08ca0, 6960),
+    (0x08ca1, 7408), (0x08ca2, 7409), (0x08ca3, 13002), (0x08ca4, 13001), (0x08ca5, 13614), (0x08ca7, 7890), (0x08ca8, 7888),
+    (0x08ca9, 7885), (0x08caa, 7889), (0x08cab, 7887), (0x08cac, 7886), (0x08cad, 4972), (0x08caf, 8331), (0x08cb0, 14266),
+    (0x08cb2, 8774), (0x08cb3, 8333), (0x08cb4, 8338), (0x08cb5, 14268), (0x08cb6, 8340), (0x08cb7, 8339), (0x08cb8, 8342),
+    (0x08cb9, 14267), (0x08cba, 14264), (0x08cbb, 8336), (0x08cbc, 8332), (0x08cbd, 8334), (0x08cbe, 14265), (0x08cbf, 8341),
+    (0x08cc0, 8337), (0x08cc1, 8335), (0x08cc2, 8776), (0x08cc3, 8775), (0x08cc4, 8773), (0x08cc5, 8777), (0x08cc7, 8771),
+    (0x08cc8, 8772), (0x08cca, 8770), (0x08ccc, 14942), (0x08ccd, 4561), (0x08ccf, 15563), (0x08cd1, 9180), (0x08cd2, 9181),
+    (0x08cd3, 9179), (0x08cd4, 3889), (0x08cd5, 15562), (0x08cd6, 2343), (0x08cd7, 15564), (0x08cd9, 16150), (0x08cda, 16152),
+    (0x08cdb, 2349), (0x08cdc, 9553), (0x08cdd, 16153), (0x08cde, 9546), (0x08cdf, 16149), (0x08ce0, 9545), (0x08ce1, 9555),
+    (0x08ce2, 9551), (0x08ce3, 9552), (0x08ce4, 9548), (0x08ce5, 16148), (0x08ce6, 9547), (0x08ce7, 16154), (0x08ce8, 16151),
+    (0x08ce9, 3235), (0x08cea, 9554), (0x08ceb, 4881), (0x08cec, 9549), (0x08ced, 9550), (0x08cee, 16725), (0x08cf0, 16727),
+    (0x08cf1, 16726), (0x08cf2, 3890), (0x08cf3, 16728), (0x08cf4, 9860), (0x08cf5, 16724), (0x08cf7, 4903), (0x08cf8, 10153),
+    (0x08cf9, 17199), (0x08cfa, 10150), (0x08cfb, 10154), (0x08cfc, 10152), (0x08cfd, 10151), (0x08cfe, 17572), (0x08d00, 17575),
+    (0x08d02, 17574), (0x08d03, 2347), (0x08d04, 17573), (0x08d05, 10323), (0x08d06, 17906), (0x08d07, 17907), (0x08d08, 10480),
+    (0x08d09, 17908), (0x08d0a, 10481), (0x08d0b, 1334), (0x08d0c, 4875), (0x08d0d, 10607), (0x08d0f, 10606), (0x08d10, 18387),
+    (0x08d11, 2351), (0x08d12, 2346), (0x08d13, 10678), (0x08d14, 18388), (0x08d15, 18554), (0x08d16, 10749), (0x08d17, 10750),
+    (0x08d18, 4518), (0x08d19, 18688), (0x08d1b, 10835), (0x08d1c, 3891), (0x08d1d, 1704), (0x08d64, 6195), (0x08d66, 7892),
+    (0x08d67, 7891), (0x08d68, 14943), (0x08d69, 14944), (0x08d6b, 9182), (0x08d6c, 16729), (0x08d6d, 9556), (0x08d6e, 16730),
+    (0x08d6f, 17200), (0x08d70, 6196), (0x08d72, 12483), (0x08d73, 6962), (0x08d74, 6961), (0x08d76, 13003), (0x08d77, 7410),
+    (0x08d78, 13004), (0x08d79, 13617), (0x08d7a, 2764), (0x08d7b, 13616), (0x08d7d, 13615), (0x08d80, 14270), (0x08d81, 8345),
+    (0x08d82, 4826), (0x08d84, 14269), (0x08d85, 8344), (0x08d89, 14271), (0x08d8a, 8343), (0x08d8c, 14946), (0x08d8d, 14949),
+    (0x08d8e, 14947), (0x08d8f, 14948), (0x08d90, 14952), (0x08d91, 14945), (0x08d92, 14953), (0x08d93, 14950), (0x08d94, 14951),
+    (0x08d95, 9184), (0x08d96, 15565), (0x08d99, 9183), (0x08d9b, 16158), (0x08d9c, 16156), (0x08d9f, 9557), (0x08da0, 16155),
+    (0x08da1, 16157), (0x08da3, 9558), (0x08da5, 16731), (0x08da6, 4979), (0x08da7, 16732), (0x08da8, 10155), (0x08da9, 2355),
+    (0x08daa, 17910), (0x08dab, 17912), (0x08dac, 17909), (0x08dad, 17911), (0x08dae, 18171), (0x08daf, 18389), (0x08db2, 18887),
+    (0x08db3, 6197), (0x08db4, 6963), (0x08db5, 13005), (0x08db6, 13007), (0x08db7, 13006), (0x08db9, 13620), (0x08dba, 7894),
+    (0x08dbc, 13618), (0x08dbe, 7893), (0x08dbf, 13621), (0x08dc0, 1511), (0x08dc1, 13622), (0x08dc2, 13619), (0x08dc3, 3894),
+    (0x08dc5, 14283), (0x08dc6, 8353), (0x08dc7, 14275), (0x08dc8, 14281), (0x08dcb, 8348), (0x08dcc, 8351), (0x08dcd, 14274),
+    (0x08dce, 8346), (0x08dcf, 14278), (0x08dd0, 14959), (0x08dd1, 8350), (0x08dd3, 14273), (0x08dd4, 4852), (0x08dd5, 14279),
+    (0x08dd6, 14276), (0x08dd7, 14282), (0x08dd8, 14272), (0x08dd9, 14280), (0x08dda, 8349), (0x08ddb, 8352), (0x08ddc, 14277),
+    (0x08ddd, 8347), (0x08ddf, 8779), (0x08de0, 14955), (0x08de1, 8778), (0x08de2, 14962), (0x08de3, 14961), (0x08de4, 8785),
+    (0x08de6, 8786), (0x08de7, 14963), (0x08de8, 8780), (0x08de9, 14960), (0x08dea, 8784), (0x08deb, 14965), (0x08dec, 14956),
+    (0x08dee, 14958), (0x08def, 8781), (0x08df0, 14954), (0x08df1, 14957), (0x08df2, 14964), (0x08df3, 8782), (0x08df4, 14966),
+    (0x08dfa, 8783), (0x08dfc, 9185), (0x08dfd, 15570), (0x08dfe, 15576), (0x08dff, 15568), (0x08e00, 15577), (0x08e01, 4855),
+    (0x08e02, 15567), (0x08e03, 15572), (0x08e04, 15578), (0x08e05, 15575), (0x08e06, 15574), (0x08e07, 15573), (0x08e09, 15566),
+    (0x08e0a, 15571), (0x08e0d, 15569), (0x08e0e, 4489), (0x08e0f, 9563), (0x08e10, 9560), (0x08e11, 16167), (0x08e12, 16172),
+    (0x08e13, 16174), (0x08e14, 16171), (0x08e15, 16164), (0x08e16, 16166), (0x08e17, 16176), (0x08e18, 16173), (0x08e19, 16168),
+    (0x08e1a, 16177), (0x08e1b, 16165), (0x08e1c, 16175), (0x08e1d, 9561), (0x08e1e, 9567), (0x08e1f, 9565), (0x08e20, 16159),
+    (0x08e21, 9566), (0x08e22, 9562), (0x08e23, 16160), (0x08e24, 16162), (0x08e25, 16161), (0x08e26, 16169), (0x08e27, 16170),
+    (0x08e28, 4858), (0x08e29, 9564), (0x08e2a, 3242), (0x08e2b, 9559), (0x08e2d, 4579), (0x08e2e, 16163), (0x08e30, 16742),
+    (0x08e31, 9862), (0x08e33, 16733), (0x08e34, 9863), (0x08e35, 9866), (0x08e36, 16738), (0x08e38, 16735), (0x08e39, 9865),
+    (0x08e3a, 3897), (0x08e3c, 16739), (0x08e3d, 16740), (0x08e3e, 16734), (0x08e3f, 16743), (0x08e40, 16736), (0x08e41, 16741),
+    (0x08e42, 9864), (0x08e44, 9861), (0x08e45, 16737), (0x08e46, 1423), (0x08e47, 17206), (0x08e48, 10158), (0x08e49, 10156),
+    (0x08e4a, 10159), (0x08e4b, 10157), (0x08e4c, 17205), (0x08e4d, 17202), (0x08e4e, 17201), (0x08e4f, 4853), (0x08e50, 17204),
+    (0x08e53, 17203), (0x08e54, 17589), (0x08e55, 10329), (0x08e56, 17580), (0x08e57, 17579), (0x08e59, 10324), (0x08e5a, 17585),
+    (0x08e5b, 17584), (0x08e5c, 17576), (0x08e5d, 17587), (0x08e5e, 17581), (0x08e5f, 10328), (0x08e60, 17578), (0x08e61, 17586),
+    (0x08e62, 17577), (0x08e63, 10325), (0x08e64, 10327), (0x08e65, 17582), (0x08e66, 10326), (0x08e67, 17583), (0x08e68, 1482),
+    (0x08e69, 17588), (0x08e6a, 17916), (0x08e6c, 10486), (0x08e6d, 17913), (0x08e6f, 17917), (0x08e71, 3900), (0x08e72, 10483),
+    (0x08e73, 17915), (0x08e74, 10488), (0x08e75, 4859), (0x08e76, 10485), (0x08e77, 4849), (0x08e78, 17914), (0x08e7a, 10487),
+    (0x08e7b, 17918), (0x08e7c, 10482), (0x08e7e, 1441), (0x08e80, 4472), (0x08e81, 10609), (0x08e82, 10611), (0x08e84, 18174),
+    (0x08e85, 10610), (0x08e86, 18172), (0x08e87, 10484), (0x08e88, 18173), (0x08e89, 10608), (0x08e8a, 10679), (0x08e8b, 10681),
+    (0x08e8c, 18391), (0x08e8d, 10680), (0x08e8e, 18390), (0x08e90, 18559), (0x08e91, 10751), (0x08e92, 18558), (0x08e93, 10752),
+    (0x08e94, 18556), (0x08e95, 18555), (0x08e96, 18560), (0x08e97, 18561), (0x08e98, 18689), (0x08e9a, 18557), (0x08e9d, 18783),
+    (0x08e9e, 18780), (0x08e9f, 18781), (0x08ea0, 18782), (0x08ea1, 10861), (0x08ea3, 18857), (0x08ea4, 18856), (0x08ea5, 18855),
+    (0x08ea6, 18888), (0x08ea7, 3243), (0x08ea8, 18932), (0x08ea9, 18913), (0x08eaa, 10879), (0x08eab, 6198), (0x08eac, 7411),
+    (0x08ead, 2363), (0x08eb0, 3902), (0x08eb2, 8787), (0x08eb6, 2364), (0x08eb9, 1815), (0x08eba, 9568), (0x08ebc, 2434),
+    (0x08ebd, 16744), (0x08ec0, 10330), (0x08ec2, 17919), (0x08ec3, 2365), (0x08ec9, 18914), (0x08eca, 6199), (0x08ecb, 6577),
+    (0x08ecc, 6965), (0x08ecd, 6964), (0x08ece, 3904), (0x08ecf, 7414), (0x08ed1, 13008), (0x08ed2, 7412), (0x08ed3, 13009),
+    (0x08ed4, 7413), (0x08ed7, 13627), (0x08ed8, 13623), (0x08eda, 4193), (0x08edb, 7895), (0x08edc, 13626), (0x08edd, 13625),
+    (0x08ede, 13624), (0x08edf, 7896), (0x08ee0, 13628), (0x08ee1, 13629), (0x08ee2, 3905), (0x08ee4, 3906), (0x08ee5, 14290),
+    (0x08ee6, 14288), (0x08ee7, 14292), (0x08ee8, 14293), (0x08ee9, 14299), (0x08eeb, 14295), (0x08eec, 14297), (0x08eed, 3907),
+    (0x08eee, 14289), (0x08eef, 14284), (0x08ef1, 14296), (0x08ef2, 3908), (0x08ef4, 14298), (0x08ef5, 14291), (0x08ef6, 14294),
+    (0x08ef7, 14285), (0x08ef8, 8355), (0x08ef9, 14287), (0x08efa, 14286), (0x08efb, 8354), (0x08efc, 8356), (0x08efe, 8790),
+    (0x08eff, 14968), (0x08f00, 14970), (0x08f01, 14969), (0x08f02, 14974), (0x08f03, 8788), (0x08f05, 14971), (0x08f06, 14967),
+    (0x08f07, 14972), (0x08f08, 14973), (0x08f09, 8789), (0x08f0a, 8791), (0x08f0b, 14975), (0x08f0d, 15582), (0x08f0e, 15581),
+    (0x08f10, 15579), (0x08f11, 15580), (0x08f12, 9187), (0x08f13, 9189), (0x08f14, 9186), (0x08f15, 9188), (0x08f16, 16184),
+    (0x08f17, 16185), (0x08f18, 16180), (0x08f19, 2367), (0x08f1a, 16181), (0x08f1b, 9570), (0x08f1c, 9575), (0x08f1d, 9569),
+    (0x08f1e, 9576), (0x08f1f, 9571), (0x08f20, 16182), (0x08f23, 16183), (0x08f24, 16179), (0x08f25, 9577), (0x08f26, 9573),
+    (0x08f29, 9572), (0x08f2a, 9574), (0x08f2c, 16178), (0x08f2d, 2368), (0x08f2e, 16746), (0x08f2f, 9868), (0x08f30, 3245),
+    (0x08f32, 16748), (0x08f33, 9870), (0x08f34, 16751), (0x08f35, 16747), (0x08f36, 16745), (0x08f37, 16750), (0x08f38, 9869),
+    (0x08f39, 16749), (0x08f3b, 9867), (0x08f3e, 10161), (0x08f3f, 10164), (0x08f40, 17208), (0x08f41, 3582), (0x08f42, 10162),
+    (0x08f43, 17207), (0x08f44, 10160), (0x08f45, 10163), (0x08f46, 17590), (0x08f47, 17591), (0x08f48, 17592), (0x08f49, 10331),
+    (0x08f4a, 3246), (0x08f4b, 17593), (0x08f4d, 10332), (0x08f4e, 10490), (0x08f4f, 17922), (0x08f50, 17923), (0x08f51, 17921),
+    (0x08f52, 17920), (0x08f53, 17924), (0x08f54, 10489), (0x08f55, 18178), (0x08f56, 18176), (0x08f57, 18177), (0x08f58, 18179),
+    (0x08f59, 18175), (0x08f5a, 18180), (0x08f5b, 18393), (0x08f5c, 4766), (0x08f5d, 18394), (0x08f5e, 18392), (0x08f5f, 10682),
+    (0x08f60, 18562), (0x08f61, 10753), (0x08f62, 18563), (0x08f63, 18691), (0x08f64, 18690), (0x08f66, 1316), (0x08f67, 1317),
+    (0x08f6e, 1318), (0x08f93, 3797), (0x08f9b, 6200), (0x08f9c, 8357), (0x08f9f, 8792), (0x08fa0, 4108), (0x08fa3, 9190),
+    (0x08fa5, 2371), (0x08fa6, 9872), (0x08fa7, 1742), (0x08fa8, 9871), (0x08fad, 10491), (0x08fae, 10579), (0x08faf, 10683),
+    (0x08fb0, 6201), (0x08fb1, 7415), (0x08fb2, 8793), (0x08fb3, 2375), (0x08fb4, 17925), (0x08fb5, 10949), (0x08fb6, 3995),
+    (0x08fb7, 3909), (0x08fb8, 19762), (0x08fb9, 4461), (0x08fba, 4459), (0x08fbb, 3771), (0x08fbc, 3773), (0x08fbe, 4457),
+    (0x08fbf, 11686), (0x08fc1, 3910), (0x08fc2, 6202), (0x08fc4, 6205), (0x08fc5, 6204), (0x08fc6, 6203), (0x08fc9, 11685),
+    (0x08fca, 3911), (0x08fcb, 12039), (0x08fcc, 3912), (0x08fcd, 12041), (0x08fce, 6578), (0x08fcf, 1817), (0x08fd0, 4428),
+    (0x08fd1, 6580), (0x08fd2, 12038), (0x08fd3, 12040), (0x08fd4, 6579), (0x08fd5, 12043), (0x08fd6, 12042), (0x08fd7, 12044),
+    (0x08fda, 4932), (0x08fe0, 12487), (0x08fe1, 12485), (0x08fe2, 6968), (0x08fe3, 12484), (0x08fe4, 6973), (0x08fe5, 6970),
+    (0x08fe6, 6967), (0x08fe8, 6974), (0x08fea, 6969), (0x08feb, 6972), (0x08fed, 6971), (0x08fee, 12486), (0x08ff0, 6966),
+    (0x08ff4, 7421), (0x08ff5, 13011), (0x08ff6, 13017), (0x08ff7, 7418), (0x08ff8, 7425), (0x08ff9, 2382), (0x08ffa, 7420),
+    (0x08ffb, 13014), (0x08ffc, 13016), (0x08ffd, 7423), (0x08ffe, 13010), (0x08fff, 13013), (0x09000, 7419), (0x09001, 7416),
+    (0x09002, 13012), (0x09003, 7422), (0x09004, 13015), (0x09005, 7424), (0x09006, 7417), (0x09008, 4674), (0x0900b, 13631),
+    (0x0900c, 13634), (0x0900d, 7898), (0x0900f, 7908), (0x09010, 7904), (0x09011, 13632), (0x09012, 4453), (0x09014, 7912),
+    (0x09015, 7905), (0x09016, 7910), (0x09017, 7900), (0x09019, 7897), (0x0901a, 7899), (0x0901b, 7911), (0x0901c, 13633),
+    (0x0901d, 7903), (0x0901e, 7906), (0x0901f, 7902), (0x09020, 7907), (0x09021, 13635), (0x09022, 7909), (0x09023, 7901),
+    (0x09024, 13630), (0x0902d, 14300), (0x0902e, 8358), (0x0902f, 14302), (0x09031, 8360), (0x09032, 8362), (0x09033, 3913),
+    (0x09034, 14301), (0x09035, 8359), (0x09036, 8363), (0x09037, 2389), (0x09038, 8361), (0x0903c, 8799), (0x0903d, 14980),
+    (0x0903e, 8807), (0x0903f, 14977), (0x09041, 8808), (0x09042, 8797), (0x09044, 14978), (0x09047, 8802), (0x09049, 14979),
+    (0x0904a, 8795), (0x0904b, 8794), (0x0904c, 4731), (0x0904d, 8805), (0x0904e, 8804), (0x0904f, 8803), (0x09050, 8801),
+    (0x09051, 8806), (0x09052, 14976), (0x09053, 8796), (0x09054, 8798), (0x09055, 8800), (0x09056, 4233), (0x09058, 9192),
+    (0x09059, 9195), (0x0905b, 9199), (0x0905c, 9193), (0x0905d, 9198), (0x0905e, 9196), (0x09060, 9191), (0x09061, 2392),
+    (0x09062, 9197), (0x09063, 9194), (0x09064, 3423), (0x09067, 16189), (0x09068, 9580), (0x09069, 9578), (0x0906b, 16190),
+    (0x0906c, 3703), (0x0906d, 9581), (0x0906e, 9579), (0x0906f, 16188), (0x09070, 16187), (0x09072, 9876), (0x09073, 16186),
+    (0x09074, 9874), (0x09075, 9873), (0x09076, 16752), (0x09077, 9582), (0x09078, 9875), (0x09079, 16753), (0x0907a, 9878),
+    (0x0907b, 16754), (0x0907c, 9877), (0x0907d, 10166), (0x0907e, 17210), (0x0907f, 10165), (0x09080, 10170), (0x09081, 10168),
+    (0x09082, 10169), (0x09083, 10334), (0x09084, 10167), (0x09085, 17209), (0x09086, 16755), (0x09087, 10333), (0x09088, 10335),
+    (0x0908a, 10492), (0x0908b, 10493), (0x0908d, 18181), (0x0908f, 10800), (0x09090, 10799), (0x09091, 6207), (0x09094, 11482),
+    (0x09095, 7426), (0x09097, 11479), (0x09098, 11480), (0x09099, 11478), (0x0909b, 11481), (0x0909e, 11690), (0x0909f, 11687),
+    (0x090a0, 11692), (0x090a1, 11688), (0x090a2, 6208), (0x090a3, 6211), (0x090a5, 11689), (0x090a6, 6210), (0x090a7, 11691),
+    (0x090a8, 2395), (0x090aa, 6209), (0x090ae, 2399), (0x090af, 12047), (0x090b0, 12049), (0x090b1, 6583), (0x090b2, 12045),
+    (0x090b3, 12048), (0x090b4, 12046), (0x090b5, 6581), (0x090b6, 6584), (0x090b8, 6582), (0x090bb, 3258), (0x090bd, 12489),
+    (0x090be, 12493), (0x090bf, 12490), (0x090c1, 6977), (0x090c3, 6978), (0x090c4, 2397), (0x090c5, 12492), (0x090c7, 12494),
+    (0x090c8, 12496), (0x090ca, 6975), (0x090cb, 12495), (0x090ce, 6976), (0x090d4, 13642), (0x090d5, 12491), (0x090d6, 13018),
+    (0x090d7, 13027), (0x090d8, 13025), (0x090d9, 13020), (0x090da, 13021), (0x090db, 13026), (0x090dc, 13028), (0x090dd, 7428),
+    (0x090df, 13023), (0x090e0, 13019), (0x090e1, 7427), (0x090e2, 7429), (0x090e3, 13022), (0x090e4, 13029), (0x090e5, 13024),
+    (0x090e8, 7913), (0x090e9, 13645), (0x090ea, 13637), (0x090eb, 13643), (0x090ec, 13644), (0x090ed, 7914), (0x090ef, 13636),
+    (0x090f0, 13638), (0x090f1, 12488), (0x090f2, 13640), (0x090f3, 13641), (0x090f4, 13639), (0x090f5, 8365), (0x090f9, 14309),
+    (0x090fa, 16756), (0x090fb, 14310), (0x090fc, 14307), (0x090fd, 7915), (0x090fe, 8367), (0x090ff, 14306), (0x09100, 14312),
+    (0x09101, 14311), (0x09102, 8364), (0x09103, 14315), (0x09104, 14305), (0x09105, 14314), (0x09106, 14303), (0x09107, 14313),
+    (0x09108, 14308), (0x09109, 8366), (0x0910b, 14987), (0x0910d, 14982), (0x0910e, 14988), (0x0910f, 14983), (0x09110, 14981),
+    (0x09111, 14984), (0x09112, 8809), (0x09114, 14986), (0x09116, 14985), (0x09117, 8810), (0x09118, 9201), (0x09119, 9200),
+    (0x0911a, 15589), (0x0911b, 15592), (0x0911c, 15584), (0x0911d, 15588), (0x0911e, 9202), (0x0911f, 15587), (0x09120, 15585),
+    (0x09121, 15591), (0x09122, 15586), (0x09123, 15583), (0x09124, 15590), (0x09126, 16196), (0x09127, 9585), (0x09128, 17594),
+    (0x09129, 16193), (0x0912a, 16194), (0x0912b, 16192), (0x0912c, 14304), (0x0912d, 9584), (0x0912e, 16197), (0x0912f, 16191),
+    (0x09130, 9583), (0x09131, 9586), (0x09132, 16195), (0x09133, 16757), (0x09134, 9879), (0x09135, 16758), (0x09136, 16759),
+    (0x09138, 17211), (0x09139, 10171), (0x0913a, 17595), (0x0913b, 17596), (0x0913e, 17597), (0x0913f, 17927), (0x09140, 17926),
+    (0x09141, 18183), (0x09143, 18182), (0x09144, 18396), (0x09145, 18397), (0x09146, 18395), (0x09147, 18564), (0x09148, 10754),
+    (0x09149, 6212), (0x0914a, 6980), (0x0914b, 6979), (0x0914c, 7432), (0x0914d, 7431), (0x0914e, 13031), (0x0914f, 13032),
+    (0x09150, 13030), (0x09151, 4790), (0x09152, 7430), (0x09153, 13649), (0x09155, 13650), (0x09156, 13646), (0x09157, 7916),
+    (0x09158, 13647), (0x09159, 4786), (0x0915a, 13648), (0x0915c, 4788), (0x0915e, 1331), (0x0915f, 14318), (0x09160, 14320),
+    (0x09161, 14316), (0x09162, 14319), (0x09163, 8368), (0x09164, 14317), (0x09165, 8369), (0x09167, 2401), (0x09168, 14495),
+    (0x09169, 8813), (0x0916a, 8812), (0x0916c, 8811), (0x0916e, 14989), (0x0916f, 14990), (0x09170, 4602), (0x09172, 15594),
+    (0x09173, 15596), (0x09174, 9206), (0x09175, 9203), (0x09176, 4563), (0x09177, 9205), (0x09178, 9204), (0x09179, 15595),
+    (0x0917a, 15593), (0x0917c, 4832), (0x09180, 16204), (0x09181, 16201), (0x09182, 16202), (0x09183, 9590), (0x09184, 16203),
+    (0x09185, 16198), (0x09186, 16199), (0x09187, 9587), (0x09189, 9588), (0x0918a, 16200), (0x0918b, 9589), (0x0918c, 1329),
+    (0x0918d, 16763), (0x0918e, 4796), (0x0918f, 16764), (0x09190, 16761), (0x09191, 16762), (0x09192, 9880), (0x09193, 16760),
+    (0x09199, 17215), (0x0919a, 17212), (0x0919b, 17214), (0x0919c, 10174), (0x0919d, 17218), (0x0919e, 10173), (0x0919f, 17216),
+    (0x091a0, 17219), (0x091a1, 17217), (0x091a2, 17213), (0x091a3, 10172), (0x091a5, 17599), (0x091a7, 17600), (0x091a8, 17598),
+    (0x091a9, 2403), (0x091aa, 17602), (0x091ab, 10336), (0x091ac, 10337), (0x091ad, 17929), (0x091ae, 10495), (0x091af, 17601),
+    (0x091b0, 17928), (0x091b1, 10494), (0x091b2, 18186), (0x091b3, 18187), (0x091b4, 10612), (0x091b5, 18185), (0x091b6, 1605),
+    (0x091b7, 18184), (0x091b9, 18398), (0x091ba, 10684), (0x091bb, 3262), (0x091bc, 18692), (0x091bd, 18785), (0x091be, 18784),
+    (0x091c0, 10836), (0x091c1, 10862), (0x091c2, 18786), (0x091c3, 18889), (0x091c4, 2404), (0x091c5, 10880), (0x091c6, 6213),
+    (0x091c7, 6585), (0x091c9, 8814), (0x091cb, 10613), (0x091cc, 6214), (0x091cd, 6981), (0x091ce, 7917), (0x091cf, 8370),
+    (0x091d0, 10338), (0x091d1, 6586), (0x091d3, 12498), (0x091d4, 12497), (0x091d5, 13033), (0x091d6, 19341), (0x091d7, 7435),
+    (0x091d8, 7433), (0x091d9, 7437), (0x091da, 13035), (0x091dc, 7436), (0x091dd, 7434), (0x091df, 1265), (0x091e2, 13034),
+    (0x091e3, 7920), (0x091e4, 13656), (0x091e5, 3324), (0x091e6, 7919), (0x091e7, 7921), (0x091e8, 13661), (0x091e9, 7923),
+    (0x091ea, 13658), (0x091eb, 13659), (0x091ec, 13651), (0x091ed, 7922), (0x091ee, 13662), (0x091f1, 13653), (0x091f3, 13654),
+    (0x091f4, 13652), (0x091f5, 7918), (0x091f6, 1743), (0x091f7, 13660), (0x091f8, 13655), (0x091f9, 13657), (0x091fa, 3051),
+    (0x091fd, 14332), (0x091fe, 2313), (0x091ff, 14331), (0x09200, 14329), (0x09201, 14321), (0x09202, 14336), (0x09203, 14324),
+    (0x09204, 14334), (0x09205, 14341), (0x09206, 14333), (0x09207, 8378), (0x09208, 4604), (0x09209, 8374), (0x0920a, 14322),
+    (0x0920c, 14328), (0x0920d, 8376), (0x0920e, 2408), (0x0920f, 14327), (0x09210, 8377), (0x09211, 8379), (0x09212, 14330),
+    (0x09213, 3917), (0x09214, 8371), (0x09215, 8372), (0x09216, 14342), (0x09217, 14340), (0x09219, 14339), (0x0921a, 14325),
+    (0x0921c, 14337), (0x0921e, 8375), (0x09221, 1820), (0x09223, 8373), (0x09224, 14338), (0x09225, 14323), (0x09226, 14326),
+    (0x09227, 14335), (0x09228, 3919), (0x0922a, 3332), (0x0922b, 3227), (0x0922d, 15631), (0x0922e, 15000), (0x09230, 14993),
+    (0x09231, 15012), (0x09232, 15021), (0x09233, 14996), (0x09234, 8825), (0x09235, 3097), (0x09236, 15009), (0x09237, 8815),
+    (0x09238, 8817), (0x09239, 8829), (0x0923a, 14994), (0x0923c, 19463), (0x0923d, 8818), (0x0923e, 8820), (0x0923f, 8830),
+    (0x09240, 8819), (0x09241, 2410), (0x09244, 2428), (0x09245, 8828), (0x09246, 15002), (0x09248, 14991), (0x09249, 8826),
+    (0x0924a, 15001), (0x0924b, 8822), (0x0924c, 15019), (0x0924d, 8827), (0x0924e, 15017), (0x0924f, 15005), (0x09250, 15015),
+    (0x09251, 8824), (0x09252, 14992), (0x09253, 15018), (0x09254, 15013), (0x09255, 4409), (0x09256, 15020), (0x09257, 8816),
+    (0x09258, 3920), (0x0925a, 8831), (0x0925b, 8821), (0x0925d, 2798), (0x0925e, 14998), (0x0925f, 3543), (0x09260, 15006),
+    (0x09261, 15010), (0x09262, 2411), (0x09263, 15014), (0x09264, 8823), (0x09265, 14997), (0x09266, 14995), (0x09267, 15007),
+    (0x0926b, 3921), (0x0926c, 15004), (0x0926d, 15003), (0x0926e, 3580), (0x0926f, 15008), (0x09270, 15011), (0x09272, 15016),
+    (0x09276, 15599), (0x09277, 3419), (0x09278, 9207), (0x09279, 15609), (0x0927a, 15601), (0x0927b, 9212), (0x0927c, 9216),
+    (0x0927d, 15618), (0x0927e, 15626), (0x0927f, 15611), (0x09280, 9208), (0x09281, 4309), (0x09282, 15615), (0x09283, 14999),
+    (0x09284, 3504), (0x09285, 9209), (0x09286, 15622), (0x09287, 15627), (0x09288, 15619), (0x09289, 2983), (0x0928a, 15621),
+    (0x0928b, 15630), (0x0928c, 15623), (0x0928d, 15605), (0x0928e, 15614), (0x0928f, 2813), (0x09291, 9217), (0x09293, 9213),
+    (0x09294, 15603), (0x09295, 15616), (0x09296, 9211), (0x09297, 15610), (0x09298, 9210), (0x09299, 15624), (0x0929a, 15607),
+    (0x0929b, 15600), (0x0929c, 9214), (0x0929d, 15629), (0x092a0, 15602), (0x092a1, 15620), (0x092a2, 15617), (0x092a3, 15612),
+    (0x092a4, 15598), (0x092a5, 15597), (0x092a6, 15606), (0x092a7, 15625), (0x092a8, 9215), (0x092a9, 15628), (0x092aa, 15604),
+    (0x092ab, 15608), (0x092ac, 9595), (0x092ae, 3923), (0x092b1, 3922), (0x092b2, 9603), (0x092b3, 9598), (0x092b4, 16237),
+    (0x092b5, 16234), (0x092b6, 16210), (0x092b7, 9593), (0x092b9, 18957), (0x092ba, 3307), (0x092bb, 9592), (0x092bc, 9599),
+    (0x092be, 3415), (0x092bf, 3924), (0x092c0, 16208), (0x092c1, 9597), (0x092c2, 16220), (0x092c3, 16206), (0x092c4, 16207),
+    (0x092c5, 9591), (0x092c6, 16236), (0x092c7, 9601), (0x092c8, 16223), (0x092c9, 16228), (0x092ca, 16222), (0x092cb, 16782),
+    (0x092cc, 16218), (0x092cd, 16226), (0x092ce, 16224), (0x092cf, 16211), (0x092d0, 16205), (0x092d1, 16232), (0x092d2, 9600),
+    (0x092d3, 16233), (0x092d4, 2366), (0x092d5, 16227), (0x092d7, 16216), (0x092d8, 16214), (0x092d9, 16209), (0x092db, 18998),
+    (0x092dd, 16217), (0x092de, 16230), (0x092df, 16213), (0x092e0, 16229), (0x092e1, 16235), (0x092e3, 3925), (0x092e4, 9596),
+    (0x092e5, 3481), (0x092e6, 16225), (0x092e7, 16231), (0x092e8, 16221), (0x092e9, 16215), (0x092ea, 9594), (0x092eb, 3926),
+    (0x092ec, 2635), (0x092ee, 15613), (0x092ef, 16219), (0x092f0, 9602), (0x092f1, 16212), (0x092f2, 2980), (0x092f3, 3927),
+    (0x092f4, 3928), (0x092f6, 3322), (0x092f7, 16788), (0x092f8, 9883), (0x092f9, 16787), (0x092fa, 16784), (0x092fb, 16803),
+    (0x092fc, 9887), (0x092fd, 3929), (0x092fe, 16800), (0x092ff, 16792), (0x09300, 16802), (0x09301, 16777), (0x09302, 16790),
+    (0x09303, 2372), (0x09304, 9889), (0x09306, 16769), (0x09307, 3076), (0x09308, 16767), (0x09309, 16801), (0x0930b, 16799),
+    (0x0930c, 16798), (0x0930d, 16781), (0x0930e, 16780), (0x0930f, 16770), (0x09310, 9891), (0x09312, 16776), (0x09313, 16786),
+    (0x09314, 16797), (0x09315, 9894), (0x09316, 16804), (0x09318, 10182), (0x09319, 9896), (0x0931a, 9890), (0x0931b, 16774),
+    (0x0931d, 16783), (0x0931e, 16766), (0x0931f, 16768), (0x09320, 9881), (0x09321, 9893), (0x09322, 9886), (0x09323, 16775),
+    (0x09324, 16791), (0x09325, 16785), (0x09326, 9892), (0x09327, 16765), (0x09328, 10177), (0x09329, 16793), (0x0932a, 16796),
+    (0x0932b, 9888), (0x0932c, 2419), (0x0932d, 16779), (0x0932e, 9895), (0x0932f, 9885), (0x09330, 4312), (0x09331, 3508),
+    (0x09333, 9884), (0x09334, 16789), (0x09335, 16795), (0x09336, 9882), (0x09338, 16772), (0x09339, 16794), (0x0933c, 16773),
+    (0x09340, 19332), (0x09341, 19630), (0x09342, 3053), (0x09343, 3930), (0x09344, 19008), (0x09345, 4523), (0x09346, 16778),
+    (0x09347, 17225), (0x09348, 3416), (0x09349, 17230), (0x0934a, 10179), (0x0934b, 10181), (0x0934c, 17236), (0x0934d, 10175),
+    (0x0934e, 17251), (0x0934f, 17242), (0x09350, 17231), (0x09351, 17232), (0x09352, 17241), (0x09354, 10188), (0x09355, 17240),
+    (0x09356, 17224), (0x09357, 17239), (0x09358, 17227), (0x09359, 17252), (0x0935a, 10187), (0x0935b, 10185), (0x0935c, 17228),
+    (0x0935e, 17247), (0x0935f, 3506), (0x09360, 17233), (0x09361, 17246), (0x09362, 19615), (0x09363, 17248), (0x09364, 17223),
+    (0x09365, 10180), (0x09366, 2800), (0x09367, 17249), (0x09368, 3302), (0x09369, 2632), (0x0936a, 17237), (0x0936b, 2420),
+    (0x0936c, 10184), (0x0936d, 17234), (0x0936e, 4898), (0x09370, 10186), (0x09371, 17243), (0x09373, 2432), (0x09374, 3547),
+    (0x09375, 10178), (0x09376, 17229), (0x09377, 17244), (0x09378, 2524), (0x09379, 17238), (0x0937a, 16771), (0x0937b, 17245),
+    (0x0937c, 17226), (0x0937d, 3355), (0x0937e, 10183), (0x09380, 17250), (0x09381, 2636), (0x09382, 10176), (0x09383, 17221),
+    (0x09384, 3931), (0x09385, 19703), (0x09386, 3511), (0x09387, 3027), (0x09388, 17616), (0x09389, 17609), (0x0938a, 10340),
+    (0x0938c, 17604), (0x0938d, 17619), (0x0938e, 17611), (0x0938f, 17235), (0x09390, 3635), (0x09391, 17621), (0x09392, 17605),
+    (0x09394, 10339), (0x09395, 17615), (0x09396, 10341), (0x09397, 10349), (0x09398, 10347), (0x09399, 17617), (0x0939a, 10348),
+    (0x0939b, 17607), (0x0939c, 2707), (0x0939d, 17608), (0x0939e, 17613), (0x0939f, 17618), (0x093a0, 2979), (0x093a1, 17220),
+    (0x093a2, 10342), (0x093a3, 17626), (0x093a4, 17623), (0x093a5, 17627), (0x093a6, 17614), (0x093a7, 17610), (0x093a8, 17624),
+    (0x093a9, 17940), (0x093aa, 17612), (0x093ac, 10345), (0x093ad, 3932), (0x093ae, 10344), (0x093af, 17222), (0x093b0, 10346),
+    (0x093b1, 17620), (0x093b2, 17622), (0x093b3, 10343), (0x093b4, 17625), (0x093b5, 17603), (0x093b7, 17606), (0x093b8, 3463),
+    (0x093bb, 3462), (0x093bd, 2836), (0x093bf, 4367), (0x093c0, 17949), (0x093c2, 17933), (0x093c3, 10499), (0x093c4, 17947),
+    (0x093c6, 2705), (0x093c7, 17931), (0x093c8, 10500), (0x093ca, 17942), (0x093cb, 2637), (0x093cc, 17938), (0x093cd, 10505),
+    (0x093ce, 17948), (0x093cf, 17932), (0x093d0, 17935), (0x093d1, 10497), (0x093d2, 17950), (0x093d3, 19211), (0x093d4, 17943),
+    (0x093d5, 17946), (0x093d6, 10503), (0x093d7, 10508), (0x093d8, 10506), (0x093d9, 17939), (0x093da, 17934), (0x093db, 3414),
+    (0x093dc, 10501), (0x093dd, 10502), (0x093de, 17930), (0x093df, 10498), (0x093e0, 2829), (0x093e1, 10496), (0x093e2, 10504),
+    (0x093e3, 17945), (0x093e4, 10507), (0x093e6, 17941), (0x093e7, 17951), (0x093e8, 10509), (0x093ec, 17937), (0x093ee, 17944),
+    (0x093f0, 19573), (0x093f1, 19683), (0x093f3, 2633), (0x093f4, 1769), (0x093f5, 18200), (0x093f6, 18214), (0x093f7, 18202),
+    (0x093f8, 18209), (0x093f9, 17936), (0x093fa, 18207), (0x093fb, 18190), (0x093fc, 18212), (0x093fd, 10616), (0x093fe, 18194),
+    (0x093ff, 18211), (0x09400, 18201), (0x09401, 3531), (0x09403, 10615), (0x09404, 3263), (0x09406, 18216), (0x09407, 18203),
+    (0x09408, 2834), (0x09409, 18208), (0x0940a, 18210), (0x0940b, 18188), (0x0940c, 18213), (0x0940d, 18199), (0x0940e, 18204),
+    (0x0940f, 18192), (0x09410, 18196), (0x09411, 18215), (0x09412, 18206), (0x09413, 18189), (0x09414, 18193), (0x09415, 18195),
+    (0x09416, 18205), (0x09417, 3936), (0x09418, 10614), (0x09419, 18198), (0x0941b, 19316), (0x0941d, 3938), (0x09420, 18191),
+    (0x09424, 2602), (0x09425, 2483), (0x09426, 2437), (0x09427, 3267), (0x09428, 18197), (0x09429, 18402), (0x0942a, 18407),
+    (0x0942b, 10691), (0x0942c, 18409), (0x0942d, 3939), (0x0942e, 10685), (0x09430, 18405), (0x09431, 18411), (0x09432, 10690),
+    (0x09433, 10686), (0x09435, 10687), (0x09436, 18401), (0x09437, 18408), (0x09438, 10689), (0x09439, 18406), (0x0943a, 10688),
+    (0x0943b, 18400), (0x0943c, 18404), (0x0943d, 18403), (0x0943e, 3940), (0x0943f, 18399), (0x09440, 18410), (0x09442, 19625),
+    (0x09443, 19628), (0x09444, 10755), (0x09445, 18571), (0x09446, 18574), (0x09447, 18570), (0x09448, 18572), (0x09449, 18573),
+    (0x0944a, 18567), (0x0944b, 18568), (0x0944c, 18565), (0x0944d, 3420), (0x0944f, 18569), (0x09450, 18566), (0x09451, 10756),
+    (0x09452, 10757), (0x09454, 3942), (0x09455, 18694), (0x09457, 18696), (0x09458, 3366), (0x0945b, 2433), (0x0945d, 18695),
+    (0x0945e, 18697), (0x09460, 10802), (0x09462, 18693), (0x09463, 10801), (0x09464, 10803), (0x09465, 4253), (0x09467, 2986),
+    (0x09468, 18788), (0x09469, 18789), (0x0946a, 10837), (0x0946b, 18787), (0x0946c, 2129), (0x0946d, 18859), (0x0946e, 18858),
+    (0x0946f, 18860), (0x09470, 10864), (0x09471, 18861), (0x09472, 10863), (0x09473, 18862), (0x09474, 18890), (0x09475, 18893),
+    (0x09476, 18892), (0x09477, 10873), (0x09478, 18891), (0x09479, 3943), (0x0947b, 19406), (0x0947c, 10883), (0x0947d, 10881),
+    (0x0947e, 10882), (0x0947f, 10888), (0x09480, 18934), (0x09481, 18935), (0x09482, 18933), (0x09483, 18943), (0x09485, 1705),
+    (0x0949f, 4316), (0x094a2, 1725), (0x094c1, 4015), (0x094c3, 4011), (0x094dc, 4001), (0x094f6, 3994), (0x0952d, 3944),
+    (0x09547, 4448), (0x09577, 6587), (0x09578, 1706), (0x0957a, 13663), (0x0957b, 14343), (0x0957c, 16238), (0x0957d, 17952),
+    (0x0957f, 1707), (0x09580, 6588), (0x09582, 6982), (0x09583, 7438), (0x09585, 2435), (0x09586, 13664), (0x09588, 13665),
+    (0x09589, 7924), (0x0958b, 8382), (0x0958c, 14345), (0x0958d, 14344), (0x0958e, 8386), (0x0958f, 8381), (0x09590, 14346),
+    (0x09591, 8383), (0x09592, 8385), (0x09593, 8384), (0x09594, 8380), (0x09596, 3275), (0x09597, 3274), (0x09598, 8832),
+    (0x09599, 3272), (0x0959b, 15025), (0x0959c, 15023), (0x0959e, 15024), (0x0959f, 15022), (0x095a0, 2438), (0x095a1, 9218),
+    (0x095a2, 3945), (0x095a3, 9221), (0x095a4, 9223), (0x095a5, 9222), (0x095a6, 2436), (0x095a7, 3273), (0x095a8, 9219),
+    (0x095a9, 9220), (0x095aa, 1586), (0x095ab, 16240), (0x095ac, 16239), (0x095ad, 9604), (0x095ae, 16241), (0x095b0, 16242),
+    (0x095b1, 9605), (0x095b5, 16812), (0x095b6, 16810), (0x095b7, 17258), (0x095b9, 16808), (0x095ba, 16809), (0x095bb, 9897),
+    (0x095bc, 16805), (0x095bd, 16813), (0x095be, 16807), (0x095bf, 16811), (0x095c0, 17254), (0x095c3, 17256), (0x095c5, 17257),
+    (0x095c6, 10193), (0x095c7, 17253), (0x095c8, 10192), (0x095c9, 17255), (0x095ca, 10189), (0x095cb, 10190), (0x095cc, 10191),
+    (0x095cd, 16806), (0x095d0, 10352), (0x095d1, 17630), (0x095d2, 17628), (0x095d3, 17629), (0x095d4, 10350), (0x095d5, 10353),
+    (0x095d6, 10351), (0x095da, 17953), (0x095db, 17954), (0x095dc, 10510), (0x095de, 18217), (0x095df, 18219), (0x095e0, 18218),
+    (0x095e1, 10617), (0x095e2, 10692), (0x095e3, 18414), (0x095e4, 18413), (0x095e5, 18412), (0x095e8, 1708), (0x095f4, 3947),
+    (0x0961c, 6589), (0x0961d, 1723), (0x0961e, 11376), (0x09620, 11485), (0x09621, 5944), (0x09622, 11483), (0x09623, 11486),
+    (0x09624, 11484), (0x09628, 11694), (0x0962a, 6218), (0x0962c, 6219), (0x0962d, 11696), (0x0962e, 6216), (0x0962f, 11695),
+    (0x09630, 11693), (0x09631, 6217), (0x09632, 6215), (0x09633, 3948), (0x09638, 4939), (0x09639, 12050), (0x0963a, 12053),
+    (0x0963b, 6592), (0x0963c, 12052), (0x0963d, 12051), (0x0963f, 6591), (0x09640, 6590), (0x09641, 4778), (0x09642, 6594),
+    (0x09643, 12054), (0x09644, 6593), (0x09645, 3874), (0x0964a, 12503), (0x0964b, 6984), (0x0964c, 6985), (0x0964d, 6986),
+    (0x0964e, 12504), (0x0964f, 12500), (0x09650, 6983), (0x09651, 12501), (0x09653, 12502), (0x09654, 12499), (0x09656, 4264),
+    (0x09658, 7445), (0x0965b, 7442), (0x0965c, 13036), (0x0965d, 7443), (0x0965e, 7446), (0x0965f, 13037), (0x09661, 7441),
+    (0x09662, 7439), (0x09663, 7440), (0x09664, 7444), (0x09669, 3851), (0x0966a, 7925), (0x0966b, 13668), (0x0966c, 7933),
+    (0x0966d, 13667), (0x0966f, 13670), (0x09670, 7929), (0x09671, 13669), (0x09672, 8394), (0x09673, 7927), (0x09674, 7930),
+    (0x09675, 7926), (0x09676, 7931), (0x09677, 7932), (0x09678, 7928), (0x0967b, 2447), (0x0967c, 13666), (0x0967d, 8390),
+    (0x0967e, 14348), (0x09680, 14352), (0x09681, 4787), (0x09683, 14351), (0x09684, 8395), (0x09685, 8391), (0x09686, 8392),
+    (0x09687, 14347), (0x09688, 14349), (0x09689, 14350), (0x0968a, 8387), (0x0968b, 8389), (0x0968d, 8393), (0x0968e, 8388),
+    (0x0968f, 3284), (0x09691, 15028), (0x09692, 15026), (0x09693, 15027), (0x09694, 8834), (0x09695, 8835), (0x09696, 2448),
+    (0x09697, 15029), (0x09698, 8833), (0x09699, 9224), (0x0969b, 9226), (0x0969c, 9225), (0x0969e, 15632), (0x096a1, 15633),
+    (0x096a2, 16244), (0x096a3, 2450), (0x096a4, 16243), (0x096a5, 19755), (0x096a7, 9898), (0x096a8, 9899), (0x096a9, 16814),
+    (0x096aa, 9900), (0x096ac, 17261), (0x096ae, 17259), (0x096b0, 17260), (0x096b1, 10194), (0x096b3, 17631), (0x096b4, 10511),
+    (0x096b6, 2453), (0x096b8, 10195), (0x096b9, 6595), (0x096bb, 7447), (0x096bc, 13038), (0x096bd, 2456), (0x096bf, 13671),
+    (0x096c0, 7934), (0x096c1, 8396), (0x096c2, 14353), (0x096c3, 14355), (0x096c4, 8398), (0x096c5, 8397), (0x096c6, 8399),
+    (0x096c7, 8400), (0x096c8, 14354), (0x096c9, 8838), (0x096ca, 8839), (0x096cb, 8837), (0x096cc, 9227), (0x096cd, 8836),
+    (0x096ce, 15030), (0x096d2, 9228), (0x096d3, 16245), (0x096d4, 16815), (0x096d5, 9901), (0x096d6, 10196), (0x096d7, 17632),
+    (0x096d8, 17636), (0x096d9, 10356), (0x096da, 17633), (0x096db, 10357), (0x096dc, 10355), (0x096dd, 17637), (0x096de, 10358),
+    (0x096df, 17635), (0x096e1, 17955), (0x096e2, 10354), (0x096e3, 10512), (0x096e5, 18790), (0x096e8, 6596), (0x096e9, 7936),
+    (0x096ea, 7935), (0x096ef, 8401), (0x096f0, 14357), (0x096f1, 14356), (0x096f2, 8402), (0x096f4, 2799), (0x096f5, 15034),
+    (0x096f6, 8843), (0x096f7, 8840), (0x096f8, 15033), (0x096f9, 8842), (0x096fa, 15031), (0x096fb, 8841), (0x096fd, 15032),
+    (0x096ff, 15634), (0x09700, 9229), (0x09702, 16248), (0x09703, 4728), (0x09704, 9606), (0x09705, 16246), (0x09706, 9607),
+    (0x09707, 9608), (0x09708, 16247), (0x09709, 9609), (0x0970b, 16816), (0x0970d, 9905), (0x0970e, 9902), (0x0970f, 9907),
+    (0x09710, 16818), (0x09711, 9903), (0x09712, 16817), (0x09713, 9906), (0x09716, 9904), (0x09718, 17264), (0x09719, 17266),
+    (0x0971b, 2476), (0x0971c, 10197), (0x0971d, 17265), (0x0971e, 10198), (0x0971f, 17263), (0x09720, 17262), (0x09721, 4905),
+    (0x09722, 17639), (0x09723, 17638), (0x09724, 10359), (0x09725, 17640), (0x09726, 17960), (0x09727, 10514), (0x09728, 17959),
+    (0x09729, 17956), (0x0972a, 10513), (0x0972b, 17957), (0x0972c, 17958), (0x0972e, 18220), (0x0972f, 18221), (0x09730, 10618),
+    (0x09731, 2467), (0x09732, 10695), (0x09734, 985), (0x09735, 18415), (0x09736, 2469), (0x09738, 10693), (0x09739, 10694),
+    (0x0973a, 18416), (0x0973d, 10758), (0x0973e, 10759), (0x0973f, 18575), (0x09740, 3952), (0x09741, 3295), (0x09742, 10838),
+    (0x09743, 18792), (0x09744, 10840), (0x09746, 18791), (0x09747, 18793), (0x09748, 10839), (0x09749, 18863), (0x0974b, 18915),
+    (0x09751, 1668), (0x09752, 6597), (0x09755, 1765), (0x09756, 8844), (0x09757, 2479), (0x09758, 15635), (0x0975a, 16249),
+    (0x0975b, 9908), (0x0975c, 9909), (0x0975d, 4369), (0x0975e, 6598), (0x0975f, 2482), (0x09760, 9610), (0x09761, 10515),
+    (0x09762, 6987), (0x09766, 9910), (0x09768, 10804), (0x09769, 6988), (0x0976a, 13672), (0x0976c, 14358), (0x0976d, 4559),
+    (0x0976e, 14360), (0x09770, 14359), (0x09771, 3314), (0x09772, 15038), (0x09773, 15035), (0x09774, 8845), (0x09776, 8846),
+    (0x09777, 15036), (0x09778, 15037), (0x0977a, 15637), (0x0977b, 15642), (0x0977c, 9230), (0x0977d, 15636), (0x0977e, 15638),
+    (0x0977f, 15645), (0x09780, 15640), (0x09781, 15644), (0x09782, 15641), (0x09783, 15639), (0x09784, 15643), (0x09785, 9231),
+    (0x09787, 19178), (0x09788, 16252), (0x09789, 2487), (0x0978a, 16250), (0x0978b, 9612), (0x0978d, 9611), (0x0978e, 16251),
+    (0x0978f, 9613), (0x09794, 16821), (0x09797, 16820), (0x09798, 9911), (0x09799, 16819), (0x0979a, 17267), (0x0979b, 3313),
+    (0x0979c, 17269), (0x0979d, 17271), (0x0979e, 17270), (0x0979f, 2488), (0x097a0, 10199), (0x097a1, 17268), (0x097a2, 17647),
+    (0x097a3, 10360), (0x097a4, 17645), (0x097a5, 17648), (0x097a6, 10361), (0x097a8, 17643), (0x097aa, 17646), (0x097ab, 17644),
+    (0x097ac, 17641), (0x097ad, 10362), (0x097ae, 17642), (0x097b1, 2489), (0x097b2, 3954), (0x097b3, 17961), (0x097b4, 4768),
+    (0x097b6, 17963), (0x097b7, 17962), (0x097b8, 4110), (0x097b9, 18222), (0x097ba, 4770), (0x097bb, 18223), (0x097bd, 19329),
+    (0x097be, 2490), (0x097bf, 18417), (0x097c0, 2491), (0x097c1, 10761), (0x097c2, 3955), (0x097c3, 10760), (0x097c4, 18698),
+    (0x097c5, 18699), (0x097c6, 10841), (0x097c7, 18794), (0x097c8, 4764), (0x097c9, 10874), (0x097cb, 6989), (0x097cc, 8403),
+    (0x097cd, 15647), (0x097ce, 15646), (0x097cf, 16254), (0x097d0, 16253), (0x097d2, 2492), (0x097d3, 10200), (0x097d4, 17273),
+    (0x097d5, 17272), (0x097d6, 17651), (0x097d7, 17649), (0x097d8, 17652), (0x097d9, 17650), (0x097dc, 10516), (0x097dd, 17964),
+    (0x097de, 17965), (0x097df, 17966), (0x097e0, 2493), (0x097e1, 18418), (0x097e3, 18576), (0x097e5, 18795), (0x097e6, 1710),
+    (0x097ed, 6990), (0x097ee, 2495), (0x097f0, 16822), (0x097f1, 17274), (0x097f2, 2612), (0x097f3, 6991), (0x097f5, 2499),
+    (0x097f6, 9232), (0x097f8, 16823), (0x097f9, 10363), (0x097fa, 17653), (0x097fb, 10517), (0x097fd, 18224), (0x097fe, 18225),
+    (0x097ff, 10696), (0x09800, 18700), (0x09801, 6992), (0x09802, 7939), (0x09803, 7940), (0x09804, 13673), (0x09805, 8404),
+    (0x09806, 8405), (0x09807, 14361), (0x09808, 8406), (0x0980a, 8850), (0x0980c, 8852), (0x0980d, 15040), (0x0980e, 15041),
+    (0x0980f, 15039), (0x09810, 8847), (0x09811, 8848), (0x09812, 8851), (0x09813, 8849), (0x09814, 19401), (0x09815, 1465),
+    (0x09816, 15648), (0x09817, 9233), (0x09818, 9234), (0x0981b, 16261), (0x0981c, 9616), (0x0981d, 16256), (0x0981e, 16255),
+    (0x0981f, 3320), (0x09820, 16260), (0x09821, 9614), (0x09823, 4380), (0x09824, 9918), (0x09826, 16257), (0x09827, 16262),
+    (0x09828, 16259), (0x09829, 16258), (0x0982b, 9615), (0x0982d, 9916), (0x0982e, 4835), (0x0982f, 16825), (0x09830, 9912),
+    (0x09832, 16826), (0x09833, 2504), (0x09834, 2503), (0x09835, 16824), (0x09837, 9915), (0x09838, 9913), (0x09839, 9917),
+    (0x0983b, 9914), (0x09841, 17275), (0x09843, 17280), (0x09844, 17276), (0x09845, 17279), (0x09846, 10201), (0x09847, 4112),
+    (0x09848, 17070), (0x09849, 17278), (0x0984a, 17277), (0x0984b, 2505), (0x0984c, 10366), (0x0984d, 10364), (0x0984e, 10367),
+    (0x0984f, 10365), (0x09850, 17654), (0x09851, 17655), (0x09852, 17656), (0x09853, 10368), (0x09856, 1881), (0x09857, 17970),
+    (0x09858, 10519), (0x09859, 17968), (0x0985b, 10520), (0x0985c, 17967), (0x0985d, 17969), (0x0985e, 10518), (0x0985f, 18229),
+    (0x09860, 18226), (0x09862, 18227), (0x09863, 18228), (0x09864, 18419), (0x09865, 10698), (0x09866, 2506), (0x09867, 10697),
+    (0x09868, 1830), (0x09869, 18578), (0x0986a, 18577), (0x0986b, 10762), (0x0986c, 19039), (0x0986f, 10805), (0x09870, 10842),
+    (0x09871, 10865), (0x09872, 18864), (0x09873, 18916), (0x09874, 18917), (0x09875, 1711), (0x098a8, 6993), (0x098a9, 14362),
+    (0x098ac, 15042), (0x098ad, 15649), (0x098ae, 15650), (0x098af, 9235), (0x098b1, 9236), (0x098b2, 16263), (0x098b3, 9617),
+    (0x098b4, 4836), (0x098b6, 10202), (0x098b7, 2514), (0x098b8, 17657), (0x098b9, 3959), (0x098ba, 10369), (0x098bb, 17973),
+    (0x098bc, 10521), (0x098bd, 17972), (0x098be, 17974), (0x098bf, 17971), (0x098c0, 18422), (0x098c1, 18230), (0x098c2, 18231),
+    (0x098c3, 3181), (0x098c4, 10619), (0x098c6, 18421), (0x098c7, 2516), (0x098c8, 2515), (0x098c9, 18420), (0x098ca, 2513),
+    (0x098cb, 18579), (0x098cc, 18918), (0x098ce, 1712), (0x098db, 6994), (0x098dc, 3329), (0x098de, 1713), (0x098df, 6995),
+    (0x098e0, 3916), (0x098e1, 2521), (0x098e2, 7448), (0x098e3, 13039), (0x098e5, 13674), (0x098e6, 2522), (0x098e7, 8407),
+    (0x098e9, 8410), (0x098ea, 8408), (0x098eb, 14363), (0x098ec, 2523), (0x098ed, 8412), (0x098ef, 8409), (0x098f1, 3961),
+    (0x098f2, 8411), (0x098f4, 8854), (0x098f5, 1506), (0x098f6, 15043), (0x098f9, 15044), (0x098fa, 16265), (0x098fc, 8853),
+    (0x098fd, 8855), (0x098fe, 8856), (0x09900, 15652), (0x09902, 15651), (0x09903, 9237), (0x09905, 9238), (0x09907, 15653),
+    (0x09908, 16264), (0x09909, 9240), (0x0990a, 9618), (0x0990c, 9239), (0x0990e, 3963), (0x09910, 9919), (0x09911, 16266),
+    (0x09912, 9620), (0x09913, 9619), (0x09914, 16267), (0x09915, 16270), (0x09916, 16268), (0x09917, 16269), (0x09918, 9621),
+    (0x09919, 3964), (0x0991a, 9924), (0x0991b, 9922), (0x0991c, 3966), (0x0991e, 9921), (0x0991f, 16828), (0x09921, 9923),
+    (0x09924, 16827), (0x09925, 17281), (0x09927, 16829), (0x09928, 9920), (0x09929, 16830), (0x0992a, 17284), (0x0992b, 17282),
+    (0x0992c, 17283), (0x0992d, 17288), (0x0992e, 10373), (0x0992f, 17287), (0x09930, 17290), (0x09931, 17289), (0x09932, 17286),
+    (0x09933, 17285), (0x09935, 10203), (0x09937, 3967), (0x09938, 3315), (0x09939, 2525), (0x0993a, 17660), (0x0993b, 3335),
+    (0x0993c, 17659), (0x0993d, 10372), (0x0993e, 10370), (0x0993f, 10371), (0x09940, 4769), (0x09941, 17658), (0x09942, 3968),
+    (0x09943, 17977), (0x09945, 10522), (0x09947, 17976), (0x09948, 17975), (0x09949, 10523), (0x0994a, 4911), (0x0994b, 18236),
+    (0x0994c, 18235), (0x0994d, 3336), (0x0994e, 18233), (0x09950, 18232), (0x09951, 10621), (0x09952, 10620), (0x09953, 18237),
+    (0x09954, 18580), (0x09955, 10763), (0x09956, 18424), (0x09957, 10699), (0x09958, 18423), (0x09959, 18234), (0x0995b, 18581),
+    (0x0995c, 10806), (0x0995d, 3969), (0x0995e, 10866), (0x0995f, 18865), (0x09961, 18919), (0x09962, 3970), (0x09963, 1714),
+    (0x09996, 6996), (0x09997, 13675), (0x09998, 17291), (0x09999, 6997), (0x0999b, 3339), (0x0999c, 15655), (0x0999d, 15654),
+    (0x0999e, 16831), (0x099a1, 17293), (0x099a3, 17292), (0x099a4, 19447), (0x099a5, 10374), (0x099a6, 17978), (0x099a7, 17979),
+    (0x099a8, 10622), (0x099aa, 3341), (0x099ab, 18920), (0x099ac, 7449), (0x099ad, 8414), (0x099ae, 8413), (0x099af, 15045),
+    (0x099b0, 15047), (0x099b1, 8858), (0x099b2, 15046), (0x099b3, 8857), (0x099b4, 8859), (0x099b5, 15048), (0x099b8, 2610),
+    (0x099b9, 15657), (0x099ba, 15659), (0x099bb, 15658), (0x099bc, 3352), (0x099bd, 15661), (0x099c1, 9241), (0x099c2, 15660),
+    (0x099c3, 15656), (0x099c4, 3914), (0x099c5, 3972), (0x099c7, 15662), (0x099c9, 16277), (0x099cb, 16280), (0x099cc, 16282),
+    (0x099cd, 16272), (0x099ce, 16276), (0x099cf, 16273), (0x099d0, 9623), (0x099d1, 9626), (0x099d2, 9628), (0x099d3, 16274),
+    (0x099d4, 16275), (0x099d5, 9627), (0x099d6, 16278), (0x099d7, 16281), (0x099d8, 16279), (0x099d9, 9629), (0x099da, 4783),
+    (0x099db, 9625), (0x099dc, 16271), (0x099dd, 9622), (0x099df, 9624), (0x099e0, 1819), (0x099e1, 2136), (0x099e2, 9926),
+    (0x099e3, 16837), (0x099e4, 16835), (0x099e5, 16834), (0x099e6, 3161), (0x099e7, 16840), (0x099e9, 16839), (0x099ea, 16838),
+    (0x099ec, 16833), (0x099ed, 9925), (0x099ee, 16832), (0x099f0, 16836), (0x099f1, 9927), (0x099f4, 17296), (0x099f5, 2530),
+    (0x099f6, 17300), (0x099f7, 17297), (0x099f8, 17299), (0x099f9, 17298), (0x099fa, 17295), (0x099fb, 17301), (0x099fc, 17304),
+    (0x099fd, 17302), (0x099fe, 17303), (0x099ff, 10205), (0x09a01, 10204), (0x09a02, 17294), (0x09a03, 17305), (0x09a04, 17665),
+    (0x09a05, 17668), (0x09a06, 17670), (0x09a07, 17669), (0x09a09, 17663), (0x09a0a, 17667), (0x09a0b, 17662), (0x09a0c, 2531),
+    (0x09a0d, 17664), (0x09a0e, 10375), (0x09a0f, 17661), (0x09a10, 2533), (0x09a11, 17666), (0x09a14, 17992), (0x09a15, 17981),
+    (0x09a16, 10524), (0x09a19, 10525), (0x09a1a, 17980), (0x09a1b, 17985), (0x09a1c, 17991), (0x09a1d, 17983), (0x09a1e, 17990),
+    (0x09a1f, 3169), (0x09a20, 17987), (0x09a21, 3346), (0x09a22, 17986), (0x09a23, 17989), (0x09a24, 17984), (0x09a25, 17982),
+    (0x09a26, 4284), (0x09a27, 17988), (0x09a29, 18244), (0x09a2a, 18242), (0x09a2b, 10623), (0x09a2c, 18241), (0x09a2d, 18247),
+    (0x09a2e, 18245), (0x09a2f, 3348), (0x09a30, 10624), (0x09a31, 18240), (0x09a32, 18238), (0x09a34, 18239), (0x09a35, 10626),
+    (0x09a36, 18243), (0x09a37, 10625), (0x09a38, 18246), (0x09a39, 18425), (0x09a3a, 18431), (0x09a3b, 2532), (0x09a3c, 3974),
+    (0x09a3d, 18426), (0x09a3e, 10703), (0x09a3f, 18432), (0x09a40, 10702), (0x09a41, 18430), (0x09a42, 18429), (0x09a43, 10701),
+    (0x09a44, 18428), (0x09a45, 10700), (0x09a46, 18427), (0x09a48, 18587), (0x09a49, 18589), (0x09a4a, 18588), (0x09a4c, 18585),
+    (0x09a4d, 10765), (0x09a4e, 18582), (0x09a4f, 18586), (0x09a50, 18591), (0x09a52, 18590), (0x09a53, 18583), (0x09a54, 18584),
+    (0x09a55, 10764), (0x09a56, 18701), (0x09a57, 10809), (0x09a58, 2534), (0x09a59, 18702), (0x09a5a, 10807), (0x09a5b, 10808),
+    (0x09a5c, 3342), (0x09a5e, 18796), (0x09a5f, 10843), (0x09a60, 18894), (0x09a62, 10875), (0x09a63, 3163), (0x09a64, 18921),
+    (0x09a65, 10876), (0x09a66, 18922), (0x09a67, 18923), (0x09a68, 18937), (0x09a69, 18936), (0x09a6a, 10891), (0x09a6b, 18947),
+    (0x09a6c, 1356), (0x09a8f, 1357), (0x09aa8, 7450), (0x09aab, 15050), (0x09aad, 15049), (0x09aaf, 9242), (0x09ab0, 9243),
+    (0x09ab1, 15663), (0x09ab2, 1455), (0x09ab3, 16283), (0x09ab4, 16843), (0x09ab6, 1593), (0x09ab7, 9630), (0x09ab8, 9928),
+    (0x09ab9, 16841), (0x09aba, 4837), (0x09abb, 16844), (0x09abc, 9929), (0x09abd, 4113), (0x09abe, 17306), (0x09abf, 16842),
+    (0x09ac0, 17671), (0x09ac1, 10376), (0x09ac2, 17993), (0x09ac6, 18250), (0x09ac7, 18248), (0x09aca, 18249), (0x09acd, 18433),
+    (0x09acf, 10704), (0x09ad0, 18592), (0x09ad1, 10812), (0x09ad2, 10766), (0x09ad3, 10810), (0x09ad4, 10811), (0x09ad5, 18797),
+    (0x09ad6, 10867), (0x09ad7, 4896), (0x09ad8, 7451), (0x09adc, 17672), (0x09adf, 13040), (0x09ae0, 2539), (0x09ae1, 8860),
+    (0x09ae2, 2540), (0x09ae3, 15664), (0x09ae6, 9244), (0x09ae7, 15665), (0x09aeb, 16285), (0x09aec, 16284), (0x09aed, 9931),
+    (0x09aee, 9631), (0x09aef, 9632), (0x09af1, 16288), (0x09af2, 16287), (0x09af3, 16286), (0x09af4, 2542), (0x09af6, 16845),
+    (0x09af7, 16848), (0x09af9, 16847), (0x09afa, 16846), (0x09afb, 9930), (0x09afc, 17310), (0x09afd, 17308), (0x09afe, 17307),
+    (0x09aff, 4738), (0x09b01, 17309), (0x09b02, 4733), (0x09b03, 10377), (0x09b04, 17674), (0x09b05, 17675), (0x09b06, 10378),
+    (0x09b08, 17673), (0x09b09, 4747), (0x09b0a, 17995), (0x09b0b, 17994), (0x09b0c, 17997), (0x09b0d, 10526), (0x09b0e, 17996),
+    (0x09b0f, 3975), (0x09b10, 18251), (0x09b11, 18253), (0x09b12, 18252), (0x09b14, 2544), (0x09b15, 18434), (0x09b16, 18437),
+    (0x09b17, 18435), (0x09b18, 18436), (0x09b19, 18593), (0x09b1a, 10767), (0x09b1e, 18703), (0x09b1f, 18704), (0x09b20, 18705),
+    (0x09b22, 10844), (0x09b23, 10868), (0x09b24, 18924), (0x09b25, 7452), (0x09b27, 9633), (0x09b28, 9932), (0x09b29, 17676),
+    (0x09b2a, 4740), (0x09b2b, 18594), (0x09b2d, 2545), (0x09b2e, 18938), (0x09b2f, 13041), (0x09b31, 10892), (0x09b32, 7453),
+    (0x09b33, 16849), (0x09b34, 2548), (0x09b35, 17677), (0x09b37, 17998), (0x09b39, 4711), (0x09b3a, 18438), (0x09b3b, 18595),
+    (0x09b3c, 7454), (0x09b3e, 15666), (0x09b3f, 15667), (0x09b40, 2553), (0x09b41, 9245), (0x09b42, 9246), (0x09b43, 16290),
+    (0x09b44, 9635), (0x09b45, 9634), (0x09b46, 16289), (0x09b48, 17311), (0x09b4a, 17678), (0x09b4b, 17680), (0x09b4c, 17679),
+    (0x09b4d, 10381), (0x09b4e, 10380), (0x09b4f, 10379), (0x09b50, 2552), (0x09b51, 10706), (0x09b52, 18439), (0x09b54, 10705),
+    (0x09b55, 18597), (0x09b56, 18596), (0x09b58, 10845), (0x09b59, 18798), (0x09b5a, 7941), (0x09b5b, 15051), (0x09b5f, 15670),
+    (0x09b60, 15668), (0x09b61, 15669), (0x09b64, 16299), (0x09b66, 16294), (0x09b67, 16291), (0x09b68, 16298), (0x09b69, 3977),
+    (0x09b6c, 16300), (0x09b6f, 9637), (0x09b70, 16297), (0x09b71, 16293), (0x09b74, 16292), (0x09b75, 16296), (0x09b76, 16295),
+    (0x09b77, 9636), (0x09b7a, 16860), (0x09b7b, 16855), (0x09b7c, 16853), (0x09b7d, 16862), (0x09b7e, 16854), (0x09b7f, 3377),
+    (0x09b80, 16850), (0x09b81, 3978), (0x09b82, 16856), (0x09b83, 19535), (0x09b85, 16851), (0x09b86, 17320), (0x09b87, 16852),
+    (0x09b88, 16863), (0x09b8b, 4688), (0x09b8d, 1658), (0x09b8e, 2557), (0x09b8f, 4899), (0x09b90, 16859), (0x09b91, 9933),
+    (0x09b92, 16858), (0x09b93, 16857), (0x09b95, 16861), (0x09b97, 4834), (0x09b9a, 17312), (0x09b9b, 17315), (0x09b9d, 4807),
+    (0x09b9e, 17314), (0x09b9f, 3380), (0x09ba0, 17322), (0x09ba1, 17317), (0x09ba2, 17321), (0x09ba4, 17319), (0x09ba5, 17318),
+    (0x09ba6, 17316), (0x09ba8, 17313), (0x09baa, 10208), (0x09bab, 10207), (0x09bad, 10209), (0x09bae, 10206), (0x09baf, 17323),
+    (0x09bb0, 4906), (0x09bb5, 17686), (0x09bb6, 17689), (0x09bb8, 17687), (0x09bb9, 17691), (0x09bbd, 17692), (0x09bbf, 17684),
+    (0x09bc0, 10386), (0x09bc1, 17685), (0x09bc3, 17683), (0x09bc4, 17690), (0x09bc6, 17682), (0x09bc7, 17681), (0x09bc8, 10385),
+    (0x09bc9, 10383), (0x09bca, 10382), (0x09bcf, 3378), (0x09bd3, 17688), (0x09bd4, 18007), (0x09bd5, 18013), (0x09bd6, 10529),
+    (0x09bd7, 18008), (0x09bd9, 18011), (0x09bda, 18015), (0x09bdb, 10530), (0x09bdc, 18010), (0x09bdd, 3979), (0x09bde, 18002),
+    (0x09be0, 18001), (0x09be1, 18014), (0x09be2, 18005), (0x09be4, 18003), (0x09be5, 18012), (0x09be6, 18004), (0x09be7, 10528),
+    (0x09be8, 10527), (0x09be9, 3255), (0x09bea, 17999), (0x09beb, 18000), (0x09bec, 18009), (0x09bed, 4657), (0x09bf0, 18006),
+    (0x09bf1, 3980), (0x09bf4, 3981), (0x09bf7, 18256), (0x09bf8, 18259), (0x09bfd, 10384), (0x09bff, 2560), (0x09c02, 2559),
+    (0x09c05, 18257), (0x09c06, 18263), (0x09c07, 18261), (0x09c08, 18255), (0x09c09, 18266), (0x09c0a, 4775), (0x09c0b, 18254),
+    (0x09c0c, 2561), (0x09c0d, 10628), (0x09c0e, 18262), (0x09c10, 3376), (0x09c12, 18258), (0x09c13, 10627), (0x09c14, 18265),
+    (0x09c15, 4745), (0x09c17, 18264), (0x09c1b, 4749), (0x09c1c, 18442), (0x09c1d, 18441), (0x09c1f, 1657), (0x09c20, 3983),
+    (0x09c21, 18448), (0x09c23, 18444), (0x09c24, 18447), (0x09c25, 10708), (0x09c26, 1592), (0x09c28, 18445), (0x09c29, 18446),
+    (0x09c2b, 18440), (0x09c2c, 18443), (0x09c2d, 10707), (0x09c2f, 4045), (0x09c31, 10769), (0x09c32, 18608), (0x09c33, 18603),
+    (0x09c34, 18607), (0x09c35, 3375), (0x09c36, 18610), (0x09c37, 18606), (0x09c39, 18602), (0x09c3a, 3987), (0x09c3b, 10771),
+    (0x09c3c, 18605), (0x09c3d, 18609), (0x09c3e, 10770), (0x09c3f, 18600), (0x09c40, 18260), (0x09c41, 18604), (0x09c44, 18601),
+    (0x09c45, 4520), (0x09c46, 18598), (0x09c48, 18599), (0x09c49, 10768), (0x09c4a, 18709), (0x09c4b, 18711), (0x09c4c, 18714),
+    (0x09c4d, 18710), (0x09c4e, 18715), (0x09c4f, 3372), (0x09c50, 18708), (0x09c52, 18706), (0x09c53, 3373), (0x09c54, 10813),
+    (0x09c55, 18712), (0x09c56, 10815), (0x09c57, 10814), (0x09c58, 18707), (0x09c59, 18713), (0x09c5d, 4616), (0x09c5e, 18803),
+    (0x09c5f, 10846), (0x09c60, 18804), (0x09c62, 18802), (0x09c63, 18799), (0x09c66, 18801), (0x09c67, 18800), (0x09c68, 18866),
+    (0x09c6d, 18868), (0x09c6e, 18867), (0x09c71, 18897), (0x09c72, 4569), (0x09c73, 18896), (0x09c74, 18895), (0x09c75, 18898),
+    (0x09c77, 10884), (0x09c78, 10885), (0x09c79, 18944), (0x09c7a, 18948), (0x09c7b, 3374), (0x09c7c, 1716), (0x09ce5, 7942),
+    (0x09ce6, 14364), (0x09ce7, 15054), (0x09ce9, 8861), (0x09cea, 15052), (0x09ced, 15053), (0x09cf1, 15671), (0x09cf2, 15672),
+    (0x09cf3, 9249), (0x09cf4, 9247), (0x09cf5, 15673), (0x09cf6, 9248), (0x09cf7, 16305), (0x09cf9, 16308), (0x09cfa, 16302),
+    (0x09cfb, 16309), (0x09cfc, 16301), (0x09cfd, 16303), (0x09cff, 16304), (0x09d00, 16307), (0x09d02, 4776), (0x09d03, 9640),
+    (0x09d04, 16312), (0x09d05, 16311), (0x09d06, 9638), (0x09d07, 16306), (0x09d08, 16310), (0x09d09, 9639), (0x09d0c, 3388),
+    (0x09d10, 16873), (0x09d12, 9938), (0x09d14, 16868), (0x09d15, 9934), (0x09d16, 3443), (0x09d17, 16865), (0x09d18, 16871),
+    (0x09d19, 16874), (0x09d1b, 9939), (0x09d1d, 16870), (0x09d1e, 16867), (0x09d1f, 16875), (0x09d20, 16866), (0x09d21, 3383),
+    (0x09d22, 16872), (0x09d23, 9935), (0x09d25, 16864), (0x09d26, 9936), (0x09d28, 9937), (0x09d29, 16869), (0x09d2d, 17341),
+    (0x09d2e, 17328), (0x09d2f, 17329), (0x09d30, 17332), (0x09d31, 17330), (0x09d33, 17324), (0x09d34, 19060), (0x09d36, 17327),
+    (0x09d37, 17337), (0x09d38, 17331), (0x09d39, 2581), (0x09d3b, 10210), (0x09d3d, 17339), (0x09d3e, 17336), (0x09d3f, 10211),
+    (0x09d40, 17338), (0x09d41, 17325), (0x09d42, 17334), (0x09d43, 17335), (0x09d44, 4897), (0x09d45, 17333), (0x09d49, 3986),
+    (0x09d4a, 17696), (0x09d4b, 17698), (0x09d4c, 17701), (0x09d4e, 1264), (0x09d4f, 17695), (0x09d50, 4632), (0x09d51, 10387),
+    (0x09d52, 17703), (0x09d53, 17694), (0x09d54, 17704), (0x09d56, 17700), (0x09d57, 17702), (0x09d58, 17706), (0x09d59, 17699),
+    (0x09d5a, 17707), (0x09d5b, 17697), (0x09d5c, 17693), (0x09d5d, 10388), (0x09d5e, 4517), (0x09d5f, 17705), (0x09d60, 10389),
+    (0x09d61, 10532), (0x09d67, 17326), (0x09d68, 18041), (0x09d69, 18032), (0x09d6a, 10534), (0x09d6b, 18028), (0x09d6c, 10535),
+    (0x09d6d, 3233), (0x09d6e, 1464), (0x09d6f, 18037), (0x09d70, 18031), (0x09d71, 18021), (0x09d72, 10533), (0x09d73, 18034),
+    (0x09d74, 18029), (0x09d75, 18030), (0x09d77, 18016), (0x09d78, 18023), (0x09d79, 18038), (0x09d7b, 18035), (0x09d7c, 4709),
+    (0x09d7d, 18027), (0x09d7e, 2570), (0x09d7f, 18039), (0x09d80, 18022), (0x09d81, 18017), (0x09d82, 18036), (0x09d83, 2571),
+    (0x09d84, 18019), (0x09d85, 18033), (0x09d86, 18024), (0x09d87, 18040), (0x09d88, 18020), (0x09d89, 10531), (0x09d8a, 18018),
+    (0x09d8b, 18025), (0x09d8c, 18026), (0x09d90, 18273), (0x09d92, 18271), (0x09d93, 3990), (0x09d94, 18276), (0x09d96, 18288),
+    (0x09d97, 18279), (0x09d98, 18272), (0x09d99, 18268), (0x09d9a, 18281), (0x09d9b, 18274), (0x09d9c, 18277), (0x09d9d, 18270),
+    (0x09d9e, 18284), (0x09d9f, 18267), (0x09da0, 18275), (0x09da1, 18280), (0x09da2, 18282), (0x09da3, 18285), (0x09da4, 18269),
+    (0x09da5, 3393), (0x09da6, 18289), (0x09da7, 18290), (0x09da8, 18283), (0x09da9, 18287), (0x09daa, 18278), (0x09dab, 4765),
+    (0x09dac, 18464), (0x09dad, 18467), (0x09daf, 10709), (0x09db1, 18466), (0x09db2, 18471), (0x09db3, 18469), (0x09db4, 10710),
+    (0x09db5, 18460), (0x09db6, 18450), (0x09db7, 18449), (0x09db8, 10712), (0x09db9, 18462), (0x09dba, 18463), (0x09dbb, 18459),
+    (0x09dbc, 18451), (0x09dbd, 3991), (0x09dbe, 18456), (0x09dbf, 18286), (0x09dc0, 3992), (0x09dc1, 18452), (0x09dc2, 10711),
+    (0x09dc3, 18458), (0x09dc4, 2575), (0x09dc5, 18457), (0x09dc7, 18453), (0x09dc8, 18465), (0x09dc9, 1342), (0x09dca, 18454),
+    (0x09dcb, 18615), (0x09dcc, 18468), (0x09dcd, 18470), (0x09dce, 18461), (0x09dcf, 18455), (0x09dd0, 18616), (0x09dd1, 18618),
+    (0x09dd2, 18612), (0x09dd3, 10772), (0x09dd4, 2563), (0x09dd5, 18625), (0x09dd6, 18623), (0x09dd7, 10773), (0x09dd8, 18622),
+    (0x09dd9, 18621), (0x09dda, 18614), (0x09ddb, 18611), (0x09ddc, 18617), (0x09ddd, 18626), (0x09dde, 18613), (0x09ddf, 18619),
+    (0x09de1, 18724), (0x09de2, 18729), (0x09de3, 18719), (0x09de4, 18722), (0x09de5, 10816), (0x09de6, 18726), (0x09de8, 18733),
+    (0x09de9, 18620), (0x09deb, 18720), (0x09dec, 18730), (0x09ded, 18734), (0x09dee, 18725), (0x09def, 18718), (0x09df0, 18728),
+    (0x09df2, 18727), (0x09df3, 18732), (0x09df4, 18731), (0x09df5, 18624), (0x09df6, 18723), (0x09df7, 18717), (0x09df8, 18721),
+    (0x09df9, 10847), (0x09dfa, 10848), (0x09dfb, 18716), (0x09dfc, 3993), (0x09dfd, 18815), (0x09dfe, 18806), (0x09dff, 18814),
+    (0x09e00, 18811), (0x09e01, 18812), (0x09e02, 18805), (0x09e03, 18808), (0x09e04, 18816), (0x09e05, 18810), (0x09e06, 18809),
+    (0x09e07, 18807), (0x09e09, 18813), (0x09e0a, 1347), (0x09e0b, 18869), (0x09e0c, 1343), (0x09e0d, 18870), (0x09e0e, 2573),
+    (0x09e0f, 18872), (0x09e10, 18871), (0x09e11, 18874), (0x09e12, 18873), (0x09e13, 18900), (0x09e14, 18899), (0x09e15, 18925),
+    (0x09e17, 18926), (0x09e18, 3386), (0x09e19, 18939), (0x09e1a, 10889), (0x09e1b, 10893), (0x09e1c, 4854), (0x09e1d, 18949),
+    (0x09e1e, 10894), (0x09e1f, 1717), (0x09e75, 7943), (0x09e79, 10629), (0x09e7a, 18472), (0x09e7b, 3996), (0x09e7c, 10849),
+    (0x09e7d, 10850), (0x09e7f, 7944), (0x09e80, 15055), (0x09e81, 4730), (0x09e82, 8862), (0x09e83, 16313), (0x09e84, 4839),
+    (0x09e85, 4692), (0x09e86, 16877), (0x09e87, 16878), (0x09e88, 16876), (0x09e89, 17343), (0x09e8a, 17342), (0x09e8b, 10212),
+    (0x09e8c, 17709), (0x09e8d, 17344), (0x09e8e, 17708), (0x09e90, 2584), (0x09e91, 18043), (0x09e92, 10536), (0x09e93, 10538),
+    (0x09e94, 18042), (0x09e95, 2585), (0x09e96, 4915), (0x09e97, 10537), (0x09e98, 4755), (0x09e99, 18291), (0x09e9a, 18293),
+    (0x09e9b, 18292), (0x09e9c, 18473), (0x09e9d, 10713), (0x09e9e, 2586), (0x09e9f, 10817), (0x09ea0, 18817), (0x09ea1, 18875),
+    (0x09ea2, 2587), (0x09ea4, 18952), (0x09ea5, 7945), (0x09ea6, 5022), (0x09ea7, 15674), (0x09ea8, 4838), (0x09ea9, 9641),
+    (0x09eaa, 2589), (0x09eab, 4554), (0x09eac, 3997), (0x09ead, 16880), (0x09eae, 16879), (0x09eaf, 2590), (0x09eb0, 17345),
+    (0x09eb1, 3998), (0x09eb4, 10539), (0x09eb5, 10630), (0x09eb6, 18627), (0x09eb7, 18945), (0x09ebb, 7946), (0x09ebc, 9250),
+    (0x09ebd, 3999), (0x09ebe, 9642), (0x09ebf, 3935), (0x09ec0, 18044), (0x09ec1, 2592), (0x09ec2, 18735), (0x09ec3, 8415),
+    (0x09ec4, 1718), (0x09ec6, 4000), (0x09ec7, 1340), (0x09ec8, 17346), (0x09ecc, 10869), (0x09ecd, 8416), (0x09ece, 9643),
+    (0x09ecf, 10213), (0x09ed0, 18736), (0x09ed1, 8417), (0x09ed3, 16314), (0x09ed4, 9941), (0x09ed5, 16881), (0x09ed6, 16882),
+    (0x09ed8, 9940), (0x09eda, 17347), (0x09edb, 10217), (0x09edc, 10215), (0x09edd, 10216), (0x09ede, 10214), (0x09edf, 17710),
+    (0x09ee0, 10390), (0x09ee2, 4002), (0x09ee4, 18295), (0x09ee5, 18294), (0x09ee6, 18297), (0x09ee7, 18296), (0x09ee8, 10631),
+    (0x09eeb, 18474), (0x09eed, 18476), (0x09eee, 18475), (0x09eef, 10714), (0x09ef0, 18628), (0x09ef1, 4003), (0x09ef2, 18737),
+    (0x09ef3, 18738), (0x09ef4, 10818), (0x09ef5, 18876), (0x09ef6, 18901), (0x09ef7, 10886), (0x09ef8, 4004), (0x09ef9, 14365),
+    (0x09efa, 16883), (0x09efb, 17348), (0x09efc, 18045), (0x09efd, 15056), (0x09efe, 3988), (0x09eff, 17349), (0x09f00, 17712),
+    (0x09f01, 17711), (0x09f02, 2599), (0x09f06, 18739), (0x09f07, 10851), (0x09f08, 2600), (0x09f09, 18877), (0x09f0a, 18902),
+    (0x09f0e, 8863), (0x09f0f, 16315), (0x09f10, 16316), (0x09f12, 16884), (0x09f13, 8864), (0x09f15, 10391), (0x09f16, 17713),
+    (0x09f17, 2604), (0x09f18, 18478), (0x09f19, 10715), (0x09f1a, 18479), (0x09f1b, 18477), (0x09f1c, 18740), (0x09f1e, 18818),
+    (0x09f20, 8865), (0x09f22, 17352), (0x09f23, 17351), (0x09f24, 17350), (0x09f25, 17714), (0x09f26, 4868), (0x09f27, 1655),
+    (0x09f28, 17718), (0x09f29, 17717), (0x09f2a, 17716), (0x09f2b, 17715), (0x09f2c, 10392), (0x09f2d, 18046), (0x09f2e, 18299),
+    (0x09f2f, 10632), (0x09f30, 18298), (0x09f31, 18480), (0x09f32, 18631), (0x09f33, 18630), (0x09f34, 10774), (0x09f35, 18629),
+    (0x09f36, 18743), (0x09f37, 18742), (0x09f38, 18741), (0x09f39, 2606), (0x09f3b, 9251), (0x09f3d, 16885), (0x09f3e, 10218),
+    (0x09f40, 18047), (0x09f41, 18048), (0x09f42, 18632), (0x09f43, 18744), (0x09f44, 4006), (0x09f45, 2609), (0x09f46, 18819),
+    (0x09f47, 18878), (0x09f48, 18927), (0x09f49, 18954), (0x09f4a, 9252), (0x09f4b, 10219), (0x09f4c, 17719), (0x09f4d, 18049),
+    (0x09f4e, 18481), (0x09f4f, 18745), (0x09f50, 5021), (0x09f52, 9645), (0x09f53, 4850), (0x09f54, 17353), (0x09f55, 17720),
+    (0x09f56, 18050), (0x09f57, 18051), (0x09f58, 18052), (0x09f59, 18304), (0x09f5a, 4751), (0x09f5b, 18300), (0x09f5c, 10716),
+    (0x09f5d, 18303), (0x09f5e, 18302), (0x09f5f, 10633), (0x09f60, 18301), (0x09f61, 10635), (0x09f62, 2614), (0x09f63, 10634),
+    (0x09f64, 18483), (0x09f65, 18482), (0x09f66, 10717), (0x09f67, 10718), (0x09f69, 2615), (0x09f6a, 10776), (0x09f6b, 18633),
+    (0x09f6c, 10775), (0x09f6e, 18748), (0x09f6f, 18749), (0x09f70, 18747), (0x09f71, 18746), (0x09f72, 10853), (0x09f74, 18820),
+    (0x09f75, 18821), (0x09f76, 18822), (0x09f77, 10852), (0x09f78, 18879), (0x09f79, 18882), (0x09f7a, 18881), (0x09f7b, 18880),
+    (0x09f7e, 18953), (0x09f7f, 2016), (0x09f8d, 9942), (0x09f8e, 2617), (0x09f90, 10399), (0x09f91, 18305), (0x09f92, 18484),
+    (0x09f94, 10777), (0x09f95, 18634), (0x09f98, 18955), (0x09f99, 1358), (0x09f9c, 9943), (0x09f9f, 1956), (0x09fa0, 17354),
+    (0x09fa2, 18635), (0x09fa4, 18903), (0x09fa5, 4833), (0x09fa6, 1730), (0x09fa7, 1772), (0x09fa8, 1779), (0x09fa9, 1812),
+    (0x09faa, 1814), (0x09fab, 1816), (0x09fac, 1821), (0x09fad, 1844), (0x09fae, 1845), (0x09faf, 1847), (0x09fb0, 11210),
+    (0x09fb1, 11212), (0x09fb2, 1866), (0x09fb3, 1892), (0x0f907, 1720), (0x0fa0c, 11314), (0x0fa0d, 14598), (0x0fe30, 5034),
+    (0x0fe31, 5047), (0x0fe33, 5049), (0x0fe34, 5051), (0x0fe35, 5055), (0x0fe36, 5056), (0x0fe37, 5059), (0x0fe38, 5060),
+    (0x0fe39, 5063), (0x0fe3a, 5064), (0x0fe3b, 5067), (0x0fe3c, 5068), (0x0fe3d, 5071), (0x0fe3e, 5072), (0x0fe3f, 5075),
+    (0x0fe40, 5076), (0x0fe41, 5079), (0x0fe42, 5080), (0x0fe43, 5083), (0x0fe44, 5084), (0x0fe49, 5124), (0x0fe4a, 5125),
+    (0x0fe4b, 5128), (0x0fe4c, 5129), (0x0fe4d, 5126), (0x0fe4e, 5127), (0x0fe4f, 5052), (0x0fe50, 5037), (0x0fe52, 5039),
+    (0x0fe54, 5041), (0x0fe55, 5042), (0x0fe56, 5043), (0x0fe57, 5044), (0x0fe59, 5085), (0x0fe5a, 5086), (0x0fe5b, 5087),
+    (0x0fe5c, 5088), (0x0fe5d, 5089), (0x0fe5e, 5090), (0x0fe5f, 5130), (0x0fe60, 5131), (0x0fe61, 5132), (0x0fe62, 5148),
+    (0x0fe63, 5149), (0x0fe64, 5150), (0x0fe65, 5151), (0x0fe66, 5152), (0x0fe69, 5193), (0x0fe6a, 5194), (0x0fe6b, 5195),
+    (0x0ff01, 5033), (0x0ff02, 11257), (0x0ff03, 5099), (0x0ff04, 5184), (0x0ff05, 5189), (0x0ff06, 5100), (0x0ff07, 11256),
+    (0x0ff08, 5053), (0x0ff09, 5054), (0x0ff0a, 5101), (0x0ff0b, 5133), (0x0ff0c, 5025), (0x0ff0d, 5134), (0x0ff0e, 5028),
+    (0x0ff0f, 5182), (0x0ff10, 5258), (0x0ff11, 5259), (0x0ff12, 5260), (0x0ff13, 5261), (0x0ff14, 5262), (0x0ff15, 5263),
+    (0x0ff16, 5264), (0x0ff17, 5265), (0x0ff18, 5266), (0x0ff19, 5267), (0x0ff1a, 5031), (0x0ff1b, 5030), (0x0ff1c, 5139),
+    (0x0ff1d, 5141), (0x0ff1e, 5140), (0x0ff1f, 5032), (0x0ff20, 5190), (0x0ff21, 5290), (0x0ff22, 5291), (0x0ff23, 5292),
+    (0x0ff24, 5293), (0x0ff25, 5294), (0x0ff26, 5295), (0x0ff27, 5296), (0x0ff28, 5297), (0x0ff29, 5298), (0x0ff2a, 5299),
+    (0x0ff2b, 5300), (0x0ff2c, 5301), (0x0ff2d, 5302), (0x0ff2e, 5303), (0x0ff2f, 5304), (0x0ff30, 5305), (0x0ff31, 5306),
+    (0x0ff32, 5307), (0x0ff33, 5308), (0x0ff34, 5309), (0x0ff35, 5310), (0x0ff36, 5311), (0x0ff37, 5312), (0x0ff38, 5313),
+    (0x0ff39, 5314), (0x0ff3a, 5315), (0x0ff3b, 10963), (0x0ff3c, 5183), (0x0ff3d, 10964), (0x0ff3f, 5122), (0x0ff41, 5316),
+    (0x0ff42, 5317), (0x0ff43, 5318), (0x0ff44, 5319), (0x0ff45, 5320), (0x0ff46, 5321), (0x0ff47, 5322), (0x0ff48, 5323),
+    (0x0ff49, 5324), (0x0ff4a, 5325), (0x0ff4b, 5326), (0x0ff4c, 5327), (0x0ff4d, 5328), (0x0ff4e, 5329), (0x0ff4f, 5330),
+    (0x0ff50, 5331), (0x0ff51, 5332), (0x0ff52, 5333), (0x0ff53, 5334), (0x0ff54, 5335), (0x0ff55, 5336), (0x0ff56, 5337),
+    (0x0ff57, 5338), (0x0ff58, 5339), (0x0ff59, 5340), (0x0ff5a, 5341), (0x0ff5b, 5057), (0x0ff5c, 5045), (0x0ff5d, 5058),
+    (0x0ff64, 5038), (0x0ffe2, 11254), (0x0ffe3, 5121), (0x0ffe4, 11255), (0x0ffed, 18996), (0x20021, 4288), (0x2003e, 2879),
+    (0x20046, 2880), (0x2004e, 3148), (0x20068, 2141), (0x20086, 1669), (0x20087, 1722), (0x2008a, 11207), (0x20094, 4007),
+    (0x200ca, 1113), (0x200cb, 1110), (0x200cc, 11205), (0x200cd, 1107), (0x200d1, 1106), (0x200ee, 5015), (0x2010c, 1104),
+    (0x2010e, 1118), (0x20118, 19386), (0x201a4, 1874), (0x201a9, 1488), (0x201ab, 2719), (0x201c1, 19318), (0x201d4, 3108),
+    (0x201f2, 4821), (0x20204, 19259), (0x2020c, 2730), (0x20214, 3677), (0x20239, 1878), (0x2025b, 3116), (0x20274, 2460),
+    (0x20275, 2728), (0x20299, 3101), (0x2029e, 19342), (0x202a0, 19017), (0x202b7, 4008), (0x202bf, 3417), (0x202c0, 2726),
+    (0x202e5, 19049), (0x2030a, 2722), (0x20325, 2037), (0x20341, 1422), (0x20345, 2794), (0x20346, 4142), (0x20347, 19032),
+    (0x2037e, 19029), (0x2037f, 2714), (0x20380, 19031), (0x203a0, 4009), (0x203a7, 3497), (0x203b5, 19510), (0x203c9, 19488),
+    (0x203cb, 2720), (0x203f5, 3572), (0x203fc, 4799), (0x20413, 2828), (0x20414, 2723), (0x2041f, 19034), (0x20465, 2713),
+    (0x20487, 19043), (0x2048e, 4679), (0x20491, 2763), (0x20492, 2762), (0x204a3, 2761), (0x204d7, 4013), (0x204fc, 1494),
+    (0x204fe, 3507), (0x20547, 18997), (0x2058e, 19051), (0x205a5, 19264), (0x205b3, 19056), (0x205c3, 19670), (0x205ca, 4163),
+    (0x205d0, 1362), (0x205d5, 4017), (0x205df, 18999), (0x205e0, 2759), (0x205eb, 3011), (0x20611, 4165), (0x20615, 4018),
+    (0x20619, 3680), (0x2061a, 3520), (0x20630, 2510), (0x20656, 19370), (0x20676, 4019), (0x2070e, 2766), (0x20731, 1485),
+    (0x20779, 4192), (0x2082c, 3156), (0x20873, 2769), (0x208d5, 3679), (0x20916, 3146), (0x20923, 19503), (0x20954, 19093),
+    (0x20979, 4650), (0x209e7, 1336), (0x20a11, 4277), (0x20a50, 2374), (0x20a6f, 1761), (0x20ab4, 3549), (0x20ac2, 4023),
+    (0x20acd, 4024), (0x20b0d, 2182), (0x20b8f, 19132), (0x20b9f, 1884), (0x20ba8, 2703), (0x20ba9, 1562), (0x20bbf, 4025),
+    (0x20bc6, 2796), (0x20bcb, 4028), (0x20be2, 19097), (0x20beb, 3521), (0x20bfb, 4030), (0x20bff, 19047), (0x20c0b, 1443),
+    (0x20c0d, 3109), (0x20c20, 2775), (0x20c34, 1544), (0x20c3a, 19653), (0x20c3b, 4031), (0x20c41, 4547), (0x20c42, 1416),
+    (0x20c43, 1481), (0x20c53, 4032), (0x20c65, 4033), (0x20c77, 19137), (0x20c78, 4188), (0x20c7c, 4034), (0x20c8d, 4036),
+    (0x20c96, 4548), (0x20c9c, 4456), (0x20cb5, 4038), (0x20cb8, 3522), (0x20ccf, 3799), (0x20cd3, 19302), (0x20cd4, 4809),
+    (0x20cd5, 1438), (0x20cd6, 1613), (0x20cdd, 4039), (0x20ced, 4040), (0x20cff, 3518), (0x20d15, 1417), (0x20d28, 4720),
+    (0x20d31, 2418), (0x20d32, 19534), (0x20d46, 1449), (0x20d47, 1557), (0x20d48, 1563), (0x20d49, 4514), (0x20d4c, 3099),
+    (0x20d4d, 19153), (0x20d4e, 19223), (0x20d6f, 4041), (0x20d71, 4723), (0x20d74, 19145), (0x20d7c, 1436), (0x20d7e, 1573),
+    (0x20d7f, 1574), (0x20d96, 1497), (0x20d9c, 1507), (0x20da7, 4573), (0x20db2, 4042), (0x20dc8, 4043), (0x20e04, 4047),
+    (0x20e09, 1517), (0x20e0a, 4623), (0x20e0d, 2704), (0x20e0e, 4048), (0x20e0f, 1607), (0x20e10, 1617), (0x20e11, 1632),
+    (0x20e16, 2201), (0x20e1d, 2779), (0x20e4c, 1559), (0x20e6d, 4488), (0x20e73, 4052), (0x20e75, 19435), (0x20e76, 4551),
+    (0x20e77, 1608), (0x20e78, 1618), (0x20e79, 1623), (0x20e7a, 1543), (0x20e7b, 1636), (0x20e8c, 1552), (0x20e96, 1442),
+    (0x20e98, 1578), (0x20e9d, 4535), (0x20ea2, 4553), (0x20eaa, 1554), (0x20eab, 1561), (0x20eac, 4607), (0x20eb6, 4682),
+    (0x20ed7, 4049), (0x20ed8, 2040), (0x20edd, 19151), (0x20ef8, 4318), (0x20ef9, 4608), (0x20efa, 1606), (0x20efb, 1609),
+    (0x20f1d, 3465), (0x20f26, 3316), (0x20f2d, 4051), (0x20f2e, 1580), (0x20f30, 1556), (0x20f31, 1530), (0x20f3b, 4940),
+    (0x20f4c, 1496), (0x20f64, 1444), (0x20f8d, 1553), (0x20f90, 4050), (0x20fad, 1569), (0x20fb4, 1437), (0x20fb5, 1635),
+    (0x20fb6, 1639), (0x20fbc, 4054), (0x20fdf, 2702), (0x20fea, 4513), (0x20feb, 4614), (0x20fec, 4622), (0x20fed, 4626),
+    (0x21014, 2357), (0x2101d, 1627), (0x2101e, 1628), (0x2104f, 4057), (0x2105c, 4056), (0x2106f, 1475), (0x21075, 4557),
+    (0x21076, 4058), (0x21077, 1473), (0x21078, 1619), (0x2107b, 4060), (0x21088, 4061), (0x21096, 4062), (0x2109d, 1504),
+    (0x210b4, 19168), (0x210bf, 4064), (0x210c0, 1564), (0x210c1, 4588), (0x210c7, 1579), (0x210c8, 1521), (0x210c9, 1616),
+    (0x210cf, 1547), (0x210d3, 4065), (0x210e4, 2777), (0x210f4, 1439), (0x210f5, 1629), (0x210f6, 1630), (0x2112f, 4066),
+    (0x2113b, 4067), (0x2113d, 1565), (0x21145, 1257), (0x21148, 1620), (0x2114f, 1558), (0x21180, 1640), (0x21187, 4629),
+    (0x211d9, 4603), (0x2123c, 19205), (0x2124f, 19185), (0x2127c, 2443), (0x212a8, 2668), (0x212a9, 4701), (0x212b0, 3593),
+    (0x212e3, 4070), (0x212fe, 5007), (0x21302, 3357), (0x21303, 2831), (0x21304, 3657), (0x21305, 19019), (0x21336, 4072),
+    (0x2133a, 19322), (0x21375, 4071), (0x21376, 19749), (0x2138e, 19396), (0x21398, 3597), (0x2139c, 3555), (0x213c5, 4344),
+    (0x213c6, 2669), (0x213ed, 4373), (0x213fe, 2667), (0x21413, 3468), (0x21416, 19194), (0x21424, 3498), (0x2143f, 1823),
+    (0x21452, 3588), (0x21454, 19196), (0x21455, 1622), (0x2148a, 2174), (0x21497, 19570), (0x214b6, 2670), (0x214e8, 3360),
+    (0x214fd, 2186), (0x21577, 4074), (0x21582, 2266), (0x21596, 19113), (0x2160a, 4340), (0x21613, 19114), (0x21619, 4075),
+    (0x2163e, 19217), (0x21661, 2140), (0x21692, 19220), (0x216b8, 19250), (0x216ba, 4020), (0x216c0, 2664), (0x216c1, 19746),
+    (0x216c2, 3527), (0x216d3, 2519), (0x216d5, 19233), (0x216df, 2334), (0x216e6, 3095), (0x216e7, 19658), (0x216e8, 19105),
+    (0x216fa, 2808), (0x216fb, 2657), (0x216fc, 2926), (0x216fe, 2852), (0x2170d, 19150), (0x21710, 2810), (0x21726, 19099),
+    (0x2173a, 3618), (0x2173b, 2864), (0x2173c, 3632), (0x21757, 3429), (0x2176c, 4713), (0x2176d, 4782), (0x2176e, 3103),
+    (0x2176f, 2809), (0x21770, 19388), (0x21771, 19307), (0x21773, 2192), (0x21774, 2660), (0x217ab, 2862), (0x217b0, 3130),
+    (0x217b1, 4323), (0x217b2, 19229), (0x217b3, 3625), (0x217b4, 2202), (0x217b5, 3452), (0x217c3, 4076), (0x217c7, 4077),
+    (0x217d9, 3495), (0x217da, 3254), (0x217db, 3622), (0x217dc, 19226), (0x217df, 3671), (0x217ef, 2648), (0x217f5, 4473),
+    (0x217f6, 4495), (0x217f8, 2655), (0x217f9, 2120), (0x217fa, 3525), (0x217fb, 19228), (0x217fc, 19397), (0x21820, 4326),
+    (0x21828, 19232), (0x21829, 19423), (0x2182a, 2931), (0x2182d, 4080), (0x21839, 2658), (0x2183a, 1644), (0x2183b, 4888),
+    (0x21840, 3192), (0x21845, 3232), (0x21852, 3529), (0x2185e, 2176), (0x21861, 2819), (0x21862, 3546), (0x21863, 2556),
+    (0x21864, 3055), (0x21877, 19346), (0x2187b, 4496), (0x21883, 3537), (0x21884, 2755), (0x21885, 2653), (0x2189e, 19693),
+    (0x2189f, 3486), (0x218a0, 2850), (0x218a1, 3194), (0x218a2, 2272), (0x218be, 2650), (0x218bf, 3536), (0x218d1, 2661),
+    (0x218d6, 3464), (0x218d7, 19163), (0x218d8, 3196), (0x218d9, 2916), (0x218fa, 2628), (0x21903, 2847), (0x21904, 2848),
+    (0x21905, 3628), (0x21910, 2348), (0x21911, 19248), (0x21912, 2970), (0x21915, 2465), (0x2191c, 4243), (0x21922, 2690),
+    (0x21927, 2861), (0x2193b, 2579), (0x21944, 5006), (0x21958, 19511), (0x2196a, 4081), (0x2197c, 2843), (0x21980, 1750),
+    (0x21983, 19053), (0x21988, 19695), (0x21996, 2842), (0x219db, 19009), (0x219f3, 2818), (0x21a2d, 4082), (0x21a34, 19262),
+    (0x21a45, 4083), (0x21a4b, 19700), (0x21a63, 975), (0x21b44, 19269), (0x21bc1, 1666), (0x21bc2, 3985), (0x21c2a, 4084),
+    (0x21c70, 4085), (0x21ca2, 4610), (0x21ca5, 19273), (0x21cac, 4086), (0x21d46, 4936), (0x21d5e, 988), (0x21d90, 4227),
+    (0x21db6, 1784), (0x21dba, 2856), (0x21dca, 1361), (0x21dd1, 4729), (0x21deb, 2943), (0x21df9, 19281), (0x21e1c, 19735),
+    (0x21e23, 1870), (0x21e37, 19282), (0x21e3d, 19440), (0x21e89, 2407), (0x21ea4, 19286), (0x21ea8, 4167), (0x21ec8, 4087),
+    (0x21ed5, 4089), (0x21f0f, 4726), (0x21f15, 4090), (0x21f6a, 19338), (0x21f9e, 3682), (0x21fa1, 4394), (0x21fe8, 1111),
+    (0x22045, 4093), (0x22049, 19292), (0x2207e, 1768), (0x2209a, 4152), (0x220c7, 1528), (0x220fc, 19048), (0x2212a, 19493),
+    (0x2215b, 2576), (0x22173, 19293), (0x2217a, 4310), (0x221a1, 1483), (0x221c1, 1886), (0x221c3, 4179), (0x22208, 1540),
+    (0x2227c, 4096), (0x22321, 3250), (0x22325, 2386), (0x223bd, 3594), (0x223d0, 19325), (0x223d7, 4097), (0x223fa, 4098),
+    (0x22465, 4653), (0x22471, 19327), (0x2248b, 19519), (0x22491, 19537), (0x224b0, 4460), (0x224ed, 19021), (0x22513, 19027),
+    (0x2251b, 4892), (0x22530, 19050), (0x22554, 19246), (0x2258d, 4303), (0x225af, 2874), (0x225be, 2875), (0x2261b, 2881),
+    (0x2261c, 19766), (0x2262b, 2871), (0x22668, 19340), (0x2267a, 2441), (0x22696, 1567), (0x22698, 19155), (0x226f4, 4596),
+    (0x226f5, 3112), (0x226f6, 2169), (0x22712, 1788), (0x22714, 2158), (0x2271b, 4252), (0x2271f, 3362), (0x2272a, 4099),
+    (0x22775, 1510), (0x22781, 3369), (0x22796, 1855), (0x227b4, 19351), (0x227b5, 1615), (0x227cd, 19507), (0x22803, 1548),
+    (0x2285f, 19374), (0x22860, 2870), (0x22871, 4100), (0x228ad, 19673), (0x228c1, 19381), (0x228f7, 19446), (0x22926, 4321),
+    (0x22939, 1549), (0x2294f, 4101), (0x22967, 4103), (0x2296b, 3370), (0x22980, 19380), (0x22993, 4104), (0x22a66, 1503),
+    (0x22acf, 1518), (0x22ad5, 4105), (0x22ae6, 1363), (0x22ae8, 4107), (0x22b0e, 4109), (0x22b22, 2893), (0x22b3f, 4111),
+    (0x22b43, 4568), (0x22b6a, 2891), (0x22bca, 1472), (0x22bce, 1572), (0x22c26, 3470), (0x22c27, 2279), (0x22c38, 1429),
+    (0x22c4c, 4114), (0x22c51, 1575), (0x22c55, 1576), (0x22c62, 4552), (0x22c88, 4116), (0x22c9b, 2885), (0x22ca1, 2905),
+    (0x22ca9, 1445), (0x22cb2, 1531), (0x22cb7, 4117), (0x22cc2, 1454), (0x22cc6, 1420), (0x22cc9, 1519), (0x22d07, 2886),
+    (0x22d08, 4119), (0x22d12, 4120), (0x22d44, 1463), (0x22d4c, 1471), (0x22d67, 2903), (0x22d8d, 1491), (0x22d95, 4122),
+    (0x22da0, 4618), (0x22da3, 19135), (0x22da4, 1625), (0x22db7, 4121), (0x22dee, 1595), (0x22e0d, 19390), (0x22e36, 19400),
+    (0x22e42, 4123), (0x22e78, 2896), (0x22e8b, 1539), (0x22eb3, 4570), (0x22eef, 2229), (0x22f74, 4124), (0x22fcc, 4125),
+    (0x22fe3, 3592), (0x23033, 4126), (0x23044, 19639), (0x2304b, 19564), (0x23066, 4127), (0x2307d, 2551), (0x2307e, 19417),
+    (0x2308e, 2910), (0x230b7, 2485), (0x230bc, 2486), (0x230da, 4697), (0x23103, 2930), (0x2313d, 1660), (0x2317d, 2204),
+    (0x23182, 2935), (0x231a4, 19432), (0x231a5, 19433), (0x231b3, 2920), (0x231c8, 19443), (0x231c9, 3430), (0x231f7, 19429),
+    (0x231f8, 19430), (0x231f9, 19536), (0x2320f, 2934), (0x23225, 3283), (0x2322f, 4374), (0x23231, 19441), (0x23232, 3060),
+    (0x23233, 19052), (0x23234, 2932), (0x23256, 1854), (0x23262, 2381), (0x23281, 1747), (0x23289, 2919), (0x2328a, 19171),
+    (0x232ab, 2913), (0x232ac, 19742), (0x232ad, 2911), (0x232d2, 2945), (0x232e0, 2915), (0x232e1, 2946), (0x23300, 3676),
+    (0x2330a, 2466), (0x2331f, 4128), (0x233b4, 19045), (0x233cc, 4219), (0x233de, 4129), (0x233e6, 19465), (0x233f4, 1570),
+    (0x233f5, 4926), (0x233f9, 1790), (0x233fa, 3603), (0x233fe, 2954), (0x23400, 19427), (0x2343f, 3165), (0x23450, 2894),
+    (0x2346f, 19468), (0x23472, 2857), (0x234e5, 3598), (0x23519, 4293), (0x23530, 2191), (0x23551, 1864), (0x2355a, 2205),
+    (0x23567, 4134), (0x23595, 2751), (0x23599, 2651), (0x2359c, 3409), (0x235cd, 19474), (0x235ce, 2213), (0x235cf, 3149),
+    (0x235f3, 4135), (0x23600, 19025), (0x23617, 959), (0x2361a, 4139), (0x2363c, 19479), (0x23640, 3410), (0x23659, 19492),
+    (0x2365f, 3014), (0x23677, 5014), (0x236a6, 2672), (0x236ad, 3606), (0x236ba, 4515), (0x236df, 3600), (0x236ee, 2682),
+    (0x23703, 19495), (0x23716, 4140), (0x23720, 4668), (0x2372d, 19073), (0x2372f, 4342), (0x2373f, 2693), (0x23766, 2143),
+    (0x23781, 3065), (0x237a2, 2953), (0x237bc, 2951), (0x237c2, 19453), (0x237d5, 2964), (0x237d6, 3602), (0x237d7, 3406),
+    (0x2383a, 2950), (0x239c2, 1522), (0x23aa7, 4146), (0x23adb, 19509), (0x23aee, 2844), (0x23afa, 3675), (0x23b1a, 1860),
+    (0x23b5a, 19514), (0x23c63, 1793), (0x23c99, 19057), (0x23c9a, 2978), (0x23c9b, 2415), (0x23cb5, 2237), (0x23cb7, 4481),
+    (0x23cc7, 5002), (0x23cc8, 19011), (0x23cc9, 19531), (0x23cfc, 4605), (0x23cfd, 4803), (0x23cfe, 4658), (0x23cff, 4169),
+    (0x23d40, 4510), (0x23d5b, 3541), (0x23d7e, 2184), (0x23d8f, 3199), (0x23db6, 4665), (0x23db7, 3473), (0x23db8, 3363),
+    (0x23db9, 2290), (0x23dba, 3102), (0x23dbb, 2379), (0x23dbc, 19525), (0x23dbd, 19527), (0x23de3, 2161), (0x23df8, 2193),
+    (0x23e06, 976), (0x23e11, 4149), (0x23e2c, 4672), (0x23e2d, 3568), (0x23e2e, 2444), (0x23e2f, 19558), (0x23e30, 19568),
+    (0x23e31, 2677), (0x23e39, 2752), (0x23e88, 4491), (0x23e89, 2756), (0x23e8a, 2737), (0x23e8b, 2209), (0x23eb9, 4150),
+    (0x23ebf, 2753), (0x23ed7, 2041), (0x23ef7, 4671), (0x23ef8, 3013), (0x23ef9, 3642), (0x23efa, 3091), (0x23efb, 2679),
+    (0x23efc, 19516), (0x23f35, 3022), (0x23f41, 2022), (0x23f4a, 4200), (0x23f61, 977), (0x23f7f, 2991), (0x23f80, 19092),
+    (0x23f81, 4388), (0x23f82, 19560), (0x23f8f, 2318), (0x23fb4, 19587), (0x23fb7, 19373), (0x23fc0, 2977), (0x23fc5, 19000),
+    (0x23feb, 19134), (0x23fec, 19094), (0x23fed, 3567), (0x23fee, 3291), (0x23fef, 4247), (0x23ff0, 19586), (0x24011, 4985),
+    (0x24039, 19585), (0x2403a, 19164), (0x2403b, 4159), (0x2403c, 2994), (0x2403d, 19571), (0x24057, 2115), (0x24085, 19548),
+    (0x2408b, 2695), (0x2408c, 19402), (0x2408d, 2788), (0x24091, 19583), (0x240c9, 2792), (0x240e1, 2694), (0x240ec, 1869),
+    (0x24104, 3385), (0x2410f, 2696), (0x24119, 4155), (0x2413f, 19588), (0x24140, 19592), (0x24144, 19581), (0x2414e, 1733),
+    (0x24155, 19584), (0x24156, 19590), (0x24157, 19591), (0x2415c, 2511), (0x2415f, 19688), (0x24177, 1645), (0x2417a, 4357),
+    (0x241a3, 4598), (0x241a4, 19528), (0x241a5, 19187), (0x241ac, 3447), (0x241b5, 3439), (0x241cd, 19457), (0x241e2, 19657),
+    (0x241fc, 3629), (0x2421b, 3312), (0x2424b, 2558), (0x24256, 3656), (0x24259, 3647), (0x24276, 3002), (0x24277, 19606),
+    (0x24278, 19210), (0x24284, 3228), (0x24293, 3640), (0x24295, 2820), (0x242a5, 3637), (0x242bf, 958), (0x242c1, 19616),
+    (0x242c9, 2936), (0x242ca, 3645), (0x242ee, 4157), (0x242fa, 3469), (0x2430d, 4158), (0x2431a, 3001), (0x24334, 4160),
+    (0x24348, 2624), (0x24362, 3590), (0x24363, 19197), (0x24364, 2591), (0x24365, 19613), (0x2438c, 2774), (0x24396, 4161),
+    (0x2439c, 19449), (0x243bd, 3654), (0x243c1, 3471), (0x243e9, 3660), (0x243ea, 3281), (0x243f2, 4698), (0x243f8, 2646),
+    (0x24404, 4170), (0x24435, 19166), (0x24436, 2711), (0x2445a, 19618), (0x2445b, 3644), (0x24473, 2645), (0x24487, 2965),
+    (0x24488, 3458), (0x244b9, 2639), (0x244bc, 19301), (0x244ce, 3652), (0x244d3, 1447), (0x244d6, 4171), (0x24505, 1797),
+    (0x24521, 4260), (0x24578, 2096), (0x245c8, 1493), (0x24618, 4643), (0x2462a, 2958), (0x24665, 2890), (0x24674, 4173),
+    (0x24697, 3064), (0x246d4, 2887), (0x24706, 4591), (0x24725, 3008), (0x2472f, 4175), (0x2478f, 3007), (0x247e0, 4620),
+    (0x24812, 4183), (0x24823, 19645), (0x24882, 2093), (0x248e9, 1686), (0x248f0, 3067), (0x248f1, 19520), (0x248f2, 3705),
+    (0x248f3, 3050), (0x248fb, 4184), (0x248ff, 3279), (0x24900, 19530), (0x24901, 19291), (0x2490c, 19529), (0x24916, 3501),
+    (0x24917, 3016), (0x24919, 4805), (0x2492f, 3257), (0x24933, 19015), (0x24934, 4263), (0x2493e, 4750), (0x2493f, 4215),
+    (0x24940, 19419), (0x24941, 3431), (0x24942, 19006), (0x24943, 3517), (0x24962, 3153), (0x24963, 4703), (0x24974, 19634),
+    (0x24975, 2620), (0x24976, 3503), (0x2497b, 1836), (0x2497f, 19547), (0x24982, 19357), (0x24988, 4383), (0x24989, 2912),
+    (0x2498a, 2358), (0x2498b, 3479), (0x2498c, 3100), (0x2498d, 19663), (0x2498e, 3044), (0x2498f, 19358), (0x24994, 2675),
+    (0x249a4, 19042), (0x249a7, 2190), (0x249a9, 19239), (0x249ab, 3043), (0x249ac, 2776), (0x249ad, 3534), (0x249b7, 19662),
+    (0x249b8, 3359), (0x249b9, 3478), (0x249ba, 4137), (0x249bb, 2621), (0x249c5, 3049), (0x249d0, 4314), (0x249da, 1643),
+    (0x249de, 3412), (0x249df, 19392), (0x249e3, 2902), (0x249e5, 3499), (0x249ec, 2989), (0x249ed, 3289), (0x249f6, 4245),
+    (0x249f7, 3167), (0x249f8, 2623), (0x249f9, 3129), (0x249fb, 19664), (0x24a0e, 2736), (0x24a12, 19750), (0x24a13, 1800),
+    (0x24a15, 4185), (0x24a21, 19450), (0x24a22, 19620), (0x24a23, 19413), (0x24a24, 19659), (0x24a25, 2666), (0x24a26, 19668),
+    (0x24a27, 19619), (0x24a28, 19412), (0x24a29, 2526), (0x24a2a, 4325), (0x24a3e, 3493), (0x24a42, 3280), (0x24a45, 4162),
+    (0x24a4a, 3015), (0x24a4e, 3047), (0x24a4f, 3664), (0x24a50, 3528), (0x24a51, 2625), (0x24a5d, 3670), (0x24a65, 2178),
+    (0x24a66, 3496), (0x24a67, 3327), (0x24a71, 3591), (0x24a77, 4861), (0x24a78, 3290), (0x24a79, 19689), (0x24a7a, 2330),
+    (0x24a8c, 19687), (0x24a93, 4462), (0x24a94, 3494), (0x24a95, 2179), (0x24a96, 3074), (0x24aa4, 3010), (0x24aa5, 4387),
+    (0x24aa6, 19066), (0x24aa7, 4201), (0x24ab1, 19026), (0x24ab2, 3319), (0x24ab3, 3488), (0x24aba, 3071), (0x24abb, 4882),
+    (0x24abc, 2787), (0x24ac0, 4187), (0x24ac7, 3072), (0x24aca, 4427), (0x24ad1, 2464), (0x24adf, 3020), (0x24ae2, 4722),
+    (0x24ae9, 2473), (0x24b0f, 1911), (0x24b6e, 4645), (0x24bf5, 19199), (0x24c09, 3244), (0x24c9e, 4492), (0x24c9f, 3604),
+    (0x24cc9, 2352), (0x24cd9, 3034), (0x24d06, 3035), (0x24d13, 19710), (0x24db8, 1424), (0x24dea, 1434), (0x24deb, 1611),
+    (0x24e3b, 1535), (0x24e50, 1624), (0x24ea5, 4190), (0x24ea7, 1453), (0x24f0e, 5017), (0x24f5c, 3736), (0x24f82, 19708),
+    (0x24f86, 4191), (0x24f97, 3450), (0x24f9a, 4235), (0x24fa9, 4661), (0x24fb8, 2172), (0x24fc2, 4612), (0x2502c, 4194),
+    (0x25052, 1480), (0x2509d, 19733), (0x2512b, 1418), (0x25148, 4550), (0x2517d, 19734), (0x2517e, 1637), (0x251cd, 1737),
+    (0x251e3, 1551), (0x251e6, 19437), (0x251e7, 3058), (0x25220, 19740), (0x25221, 3057), (0x25250, 19337), (0x25299, 4198),
+    (0x252c7, 1460), (0x252d8, 1641), (0x2530e, 2072), (0x25311, 4501), (0x25313, 19444), (0x25419, 4199), (0x25425, 2928),
+    (0x2542f, 2654), (0x25430, 2324), (0x25446, 4203), (0x2546c, 2494), (0x2546e, 4204), (0x2549a, 1903), (0x25531, 5005),
+    (0x25535, 3763), (0x2553f, 4208), (0x2555b, 2927), (0x2555c, 2678), (0x2555d, 3424), (0x2555e, 4210), (0x25562, 4212),
+    (0x25565, 2325), (0x25566, 4213), (0x25581, 3077), (0x25584, 2923), (0x2558f, 2925), (0x255b9, 2412), (0x255d5, 3513),
+    (0x255db, 2417), (0x255e0, 19757), (0x25605, 19631), (0x25635, 4482), (0x25651, 2717), (0x25683, 3210), (0x25695, 1805),
+    (0x256e3, 3085), (0x256f6, 3698), (0x25706, 2512), (0x2571d, 19403), (0x25725, 2535), (0x2573d, 1650), (0x25772, 19122),
+    (0x257c7, 4214), (0x257df, 3300), (0x257e0, 2045), (0x257e1, 4254), (0x25857, 19771), (0x2585d, 4216), (0x25872, 2948),
+    (0x258c8, 3344), (0x258e1, 2051), (0x25903, 4221), (0x25946, 2817), (0x25956, 4483), (0x259ac, 2055), (0x259cc, 19263),
+    (0x25a54, 974), (0x25a95, 4649), (0x25a9c, 3114), (0x25aae, 4224), (0x25aaf, 3113), (0x25ae9, 3157), (0x25b74, 1659),
+    (0x25b89, 4225), (0x25bb3, 3125), (0x25bb4, 2077), (0x25bc6, 3119), (0x25be4, 2231), (0x25be8, 4118), (0x25c01, 2068),
+    (0x25c06, 4226), (0x25c21, 4802), (0x25c4a, 4499), (0x25c65, 2085), (0x25c91, 2249), (0x25ca4, 1804), (0x25cc0, 2251),
+    (0x25cc1, 2090), (0x25cfe, 2073), (0x25d20, 2083), (0x25d30, 1739), (0x25d43, 3158), (0x25e0e, 3160), (0x25e49, 1527),
+    (0x25e81, 4485), (0x25e82, 3143), (0x25e83, 2825), (0x25ea6, 3127), (0x25ebc, 3126), (0x25ed7, 5003), (0x25ed8, 4508),
+    (0x25f1a, 4946), (0x25f4b, 2662), (0x25fe1, 3017), (0x25fe2, 4148), (0x26029, 2116), (0x26048, 4628), (0x26064, 3407),
+    (0x26083, 19765), (0x26097, 1495), (0x260a4, 3477), (0x260a5, 1468), (0x26102, 4231), (0x26121, 2124), (0x26159, 4912),
+    (0x2615a, 2125), (0x2615b, 3476), (0x2615c, 3140), (0x261ad, 5012), (0x261ae, 3075), (0x261b2, 4234), (0x261dd, 19593),
+    (0x26258, 4486), (0x26261, 3139), (0x2626a, 1691), (0x2626b, 5023), (0x262d0, 2135), (0x26335, 4717), (0x2634b, 1694),
+    (0x2634c, 5020), (0x26351, 2138), (0x263be, 3306), (0x263f5, 19721), (0x263f8, 3152), (0x26402, 4237), (0x26410, 2461),
+    (0x26411, 4271), (0x26412, 3151), (0x2644a, 4238), (0x26469, 4287), (0x26484, 4242), (0x26488, 4246), (0x26489, 2147),
+    (0x2648d, 2061), (0x26498, 2171), (0x26512, 4248), (0x26572, 1600), (0x265a0, 3170), (0x265ad, 3168), (0x265bf, 4250),
+    (0x26612, 1697), (0x26626, 2160), (0x266af, 4478), (0x266b1, 2700), (0x266b5, 4251), (0x266da, 1476), (0x266e8, 2162),
+    (0x266fc, 4261), (0x26716, 1477), (0x26741, 3474), (0x26799, 4269), (0x267b3, 4667), (0x267b4, 3180), (0x267cc, 4964),
+    (0x2681c, 1545), (0x26846, 5016), (0x2685e, 4272), (0x2686e, 4270), (0x26888, 19324), (0x2688a, 4580), (0x26893, 4594),
+    (0x268c7, 4274), (0x2690e, 19726), (0x26911, 19725), (0x26926, 4278), (0x26939, 4280), (0x26951, 1698), (0x269a8, 2549),
+    (0x269b5, 4372), (0x269f2, 1433), (0x269fa, 4283), (0x26a2d, 4285), (0x26a2e, 19654), (0x26a34, 4290), (0x26a42, 3182),
+    (0x26a51, 3185), (0x26a52, 2183), (0x26b05, 2211), (0x26b0a, 2189), (0x26b13, 19087), (0x26b15, 1842), (0x26b23, 972),
+    (0x26b28, 19274), (0x26b50, 1582), (0x26b51, 19521), (0x26b52, 4205), (0x26b53, 19144), (0x26b5b, 4291), (0x26b75, 3145),
+    (0x26b82, 3397), (0x26b96, 2240), (0x26b97, 2212), (0x26b9d, 4295), (0x26bb3, 19018), (0x26bc0, 3584), (0x26bf7, 4240),
+    (0x26c21, 2697), (0x26c40, 3449), (0x26c41, 1875), (0x26c46, 1897), (0x26c7e, 19192), (0x26c7f, 2078), (0x26c80, 3620),
+    (0x26c81, 19224), (0x26c82, 3613), (0x26ca4, 4297), (0x26cb7, 3607), (0x26cb8, 2459), (0x26cbd, 4266), (0x26cc0, 3467),
+    (0x26cc3, 2154), (0x26cd1, 3173), (0x26d22, 4721), (0x26d23, 4793), (0x26d24, 2938), (0x26d25, 19540), (0x26d26, 3611),
+    (0x26d27, 2518), (0x26d28, 3650), (0x26d29, 19023), (0x26d2a, 19225), (0x26d51, 3612), (0x26d74, 1840), (0x26da0, 19466),
+    (0x26da1, 19541), (0x26da2, 2603), (0x26da3, 2210), (0x26da4, 3400), (0x26da5, 2219), (0x26da6, 19230), (0x26da7, 3187),
+    (0x26dae, 4300), (0x26ddc, 5009), (0x26dea, 3030), (0x26deb, 19542), (0x26df0, 3402), (0x26e00, 3405), (0x26e05, 4889),
+    (0x26e07, 4366), (0x26e12, 3395), (0x26e42, 2228), (0x26e43, 2242), (0x26e44, 2106), (0x26e45, 19235), (0x26e6e, 19330),
+    (0x26e72, 19549), (0x26e77, 2241), (0x26e84, 3401), (0x26e8b, 961), (0x26e99, 2255), (0x26ed0, 4600), (0x26ed1, 4966),
+    (0x26ed2, 19367), (0x26ed3, 2043), (0x26ed4, 2224), (0x26ed5, 2451), (0x26ed6, 2108), (0x26ed7, 2388), (0x26f26, 2684),
+    (0x26f73, 2877), (0x26f74, 19551), (0x26f9f, 19731), (0x26fa1, 19245), (0x26fbe, 1843), (0x26fde, 3614), (0x26fdf, 3396),
+    (0x2700e, 1346), (0x2704b, 4304), (0x27052, 2680), (0x27053, 3621), (0x27088, 2264), (0x270ad, 5010), (0x270ae, 4706),
+    (0x270af, 4351), (0x270cd, 2267), (0x270d2, 962), (0x270f8, 19339), (0x27109, 3616), (0x2710c, 2361), (0x2710d, 1838),
+    (0x27126, 19777), (0x27127, 3172), (0x27164, 4718), (0x27165, 2676), (0x27175, 2508), (0x271cd, 4305), (0x2721b, 3189),
+    (0x27267, 947), (0x27280, 4307), (0x27285, 4308), (0x2728b, 4311), (0x272b2, 4666), (0x272b6, 4601), (0x272e6, 4313),
+    (0x27352, 960), (0x2739a, 2274), (0x273ff, 1900), (0x27422, 3201), (0x27450, 4317), (0x27484, 3538), (0x27486, 4958),
+    (0x27574, 1538), (0x275a3, 4625), (0x275e0, 2289), (0x275e4, 2291), (0x275fd, 2867), (0x275fe, 4151), (0x27607, 11213),
+    (0x2760c, 19761), (0x27632, 4209), (0x27639, 1571), (0x27655, 4480), (0x27656, 5004), (0x27657, 4147), (0x27694, 1633),
+    (0x2770f, 2300), (0x27735, 1268), (0x27736, 3643), (0x27741, 3084), (0x2775e, 1594), (0x27784, 3213), (0x27785, 3212),
+    (0x277cc, 4336), (0x27858, 4337), (0x27870, 2942), (0x2789d, 4470), (0x278b2, 1702), (0x278c8, 1462), (0x27924, 2309),
+    (0x27967, 3883), (0x2797a, 2501), (0x279a0, 1478), (0x279dd, 4347), (0x279fd, 4353), (0x27a0a, 4354), (0x27a0e, 19256),
+    (0x27a3e, 4477), (0x27a53, 19359), (0x27a59, 2315), (0x27a79, 4133), (0x27a84, 19024), (0x27abd, 2310), (0x27abe, 2391),
+    (0x27af4, 3695), (0x27b06, 3337), (0x27b0b, 4355), (0x27b18, 3702), (0x27b38, 2320), (0x27b39, 19773), (0x27b3a, 2317),
+    (0x27b48, 3141), (0x27bef, 986), (0x27bf4, 3599), (0x27c12, 4232), (0x27c6c, 968), (0x27cb1, 950), (0x27cc5, 952),
+    (0x27d2f, 4597), (0x27d53, 2342), (0x27d54, 2338), (0x27d66, 4356), (0x27d73, 4599), (0x27d84, 1413), (0x27d8f, 2339),
+    (0x27d98, 2344), (0x27dbd, 2345), (0x27ddc, 3236), (0x27e4d, 3653), (0x27e4f, 3563), (0x27f2e, 4684), (0x27ff9, 4613),
+    (0x28002, 2356), (0x28009, 4360), (0x2801e, 2984), (0x28023, 4371), (0x28024, 4474), (0x28048, 4375), (0x28083, 4378),
+    (0x28090, 4391), (0x280bd, 1604), (0x280be, 1427), (0x280e8, 4343), (0x280e9, 1451), (0x280f4, 4392), (0x2812e, 4393),
+    (0x2814f, 4395), (0x2815d, 1432), (0x2816f, 4370), (0x28189, 4396), (0x281af, 4397), (0x281bc, 2360), (0x28207, 1621),
+    (0x28218, 3558), (0x2821a, 4398), (0x28256, 1446), (0x2827c, 1646), (0x2829b, 1525), (0x282cd, 1634), (0x282e2, 4540),
+    (0x28306, 4399), (0x28318, 4364), (0x2832f, 4400), (0x2833a, 2145), (0x28365, 2369), (0x2836d, 2122), (0x2837d, 4857),
+    (0x2838a, 4401), (0x28412, 2370), (0x28468, 4403), (0x2846c, 1360), (0x28473, 4322), (0x28482, 19310), (0x28501, 2385),
+    (0x2853c, 2390), (0x2853d, 2378), (0x2856c, 2393), (0x285e8, 4176), (0x285f4, 19442), (0x28600, 2546), (0x2860b, 2394),
+    (0x28625, 2261), (0x2863b, 2262), (0x286aa, 4404), (0x286ab, 19110), (0x286b2, 1899), (0x286bc, 2384), (0x286d8, 2442),
+    (0x286e6, 2398), (0x2870f, 1755), (0x28713, 2396), (0x28804, 19648), (0x2882b, 19646), (0x28933, 2406), (0x28948, 4223),
+    (0x28949, 4381), (0x28956, 4407), (0x28964, 19744), (0x28968, 19745), (0x2896c, 2638), (0x2896d, 1887), (0x2897e, 2630),
+    (0x28989, 3303), (0x289a8, 2858), (0x289aa, 19016), (0x289ab, 4352), (0x289b8, 4410), (0x289bc, 19154), (0x289c0, 2012),
+    (0x289dc, 2985), (0x289de, 2377), (0x289e1, 3648), (0x289e3, 1771), (0x289e4, 19007), (0x289e7, 4412), (0x289e8, 4414),
+    (0x289f9, 3217), (0x289fa, 3480), (0x289fb, 2835), (0x289fc, 3461), (0x28a0f, 5013), (0x28a16, 3510), (0x28a25, 2307),
+    (0x28a29, 19343), (0x28a32, 1741), (0x28a36, 19333), (0x28a44, 2634), (0x28a45, 3330), (0x28a46, 2807), (0x28a47, 2855),
+    (0x28a48, 2165), (0x28a49, 3453), (0x28a4a, 19483), (0x28a4b, 3264), (0x28a59, 3689), (0x28a5a, 4867), (0x28a81, 3545),
+    (0x28a82, 3515), (0x28a83, 3601), (0x28a9a, 4156), (0x28a9b, 19117), (0x28a9c, 2765), (0x28ac0, 3533), (0x28ac6, 2414),
+    (0x28acb, 2982), (0x28acc, 3042), (0x28ace, 3509), (0x28ade, 19089), (0x28adf, 19471), (0x28ae0, 2455), (0x28ae1, 2421),
+    (0x28ae2, 2425), (0x28ae3, 3054), (0x28ae5, 2426), (0x28aea, 1764), (0x28afc, 3266), (0x28b0c, 2416), (0x28b13, 2706),
+    (0x28b21, 19556), (0x28b22, 2924), (0x28b2b, 19764), (0x28b2c, 4469), (0x28b2d, 4996), (0x28b2f, 3305), (0x28b46, 4415),
+    (0x28b4c, 1484), (0x28b4e, 4181), (0x28b50, 3326), (0x28b63, 3485), (0x28b64, 3328), (0x28b65, 3343), (0x28b66, 19183),
+    (0x28b6c, 4475), (0x28b8f, 19690), (0x28b99, 4476), (0x28b9c, 3216), (0x28b9d, 19376), (0x28bb9, 983), (0x28bc2, 4931),
+    (0x28bc5, 3318), (0x28bd4, 4416), (0x28bd7, 2827), (0x28bd9, 3215), (0x28bda, 2627), (0x28be7, 3512), (0x28be8, 19622),
+    (0x28be9, 2048), (0x28bea, 2981), (0x28beb, 2422), (0x28bec, 2429), (0x28bf5, 3214), (0x28bff, 2431), (0x28c03, 19692),
+    (0x28c09, 4418), (0x28c1c, 3583), (0x28c1d, 3268), (0x28c23, 3021), (0x28c26, 3304), (0x28c2b, 2598), (0x28c30, 4377),
+    (0x28c39, 2430), (0x28c3b, 3269), (0x28cca, 1585), (0x28ccd, 1542), (0x28cd2, 1583), (0x28d34, 3276), (0x28d99, 1584),
+    (0x28db9, 1822), (0x28e0f, 1709), (0x28e36, 2631), (0x28e39, 4933), (0x28e65, 3287), (0x28e66, 3286), (0x28e97, 4791),
+    (0x28eac, 19702), (0x28eb2, 19675), (0x28eb3, 2727), (0x28ed9, 19699), (0x28ee7, 3285), (0x28fc5, 4420), (0x29079, 1890),
+    (0x29088, 2876), (0x2908b, 2611), (0x29093, 3299), (0x290af, 2462), (0x290b0, 3296), (0x290b1, 2537), (0x290c0, 4276),
+    (0x290e4, 3560), (0x290e5, 2463), (0x290ec, 4421), (0x290ed, 19458), (0x2910d, 19780), (0x29110, 4423), (0x2913c, 4424),
+    (0x2914d, 2888), (0x2915b, 3685), (0x2915e, 4426), (0x29170, 19578), (0x2919c, 19500), (0x291a8, 4348), (0x291d5, 2478),
+    (0x291eb, 2481), (0x2941d, 2500), (0x29420, 3690), (0x29433, 2497), (0x2943f, 2917), (0x29448, 3225), (0x294d0, 4716),
+    (0x294d9, 2052), (0x294da, 3321), (0x294e5, 1425), (0x294e7, 4431), (0x2959e, 4662), (0x295b0, 4433), (0x295b8, 4434),
+    (0x295d7, 3323), (0x295e9, 4432), (0x295f4, 1457), (0x29720, 1532), (0x29732, 4435), (0x297d4, 1461), (0x29810, 1715),
+    (0x29857, 2528), (0x298a4, 4334), (0x298d1, 4436), (0x298ea, 2895), (0x298f1, 3349), (0x298fa, 1882), (0x29903, 5008),
+    (0x29905, 2529), (0x2992f, 3392), (0x29945, 997), (0x29947, 1763), (0x29948, 3351), (0x29949, 4437), (0x2995d, 19377),
+    (0x2996a, 4438), (0x2999d, 2683), (0x299c3, 4439), (0x299c9, 4177), (0x29a28, 4440), (0x29a4d, 1450), (0x29b05, 2541),
+    (0x29b0e, 4441), (0x29bd5, 2538), (0x29c73, 1729), (0x29cad, 4333), (0x29d3e, 2554), (0x29d5a, 4442), (0x29d7c, 4621),
+    (0x29d98, 4503), (0x29d9b, 4443), (0x29df6, 1345), (0x29e06, 4704), (0x29e2d, 3379), (0x29e68, 2562), (0x29eac, 4655),
+    (0x29eb0, 991), (0x29ec3, 1591), (0x29ef8, 4445), (0x29f23, 4446), (0x29f30, 4659), (0x29fb7, 2564), (0x29fde, 4663),
+    (0x2a014, 2383), (0x2a087, 2153), (0x2a0b9, 2582), (0x2a0e1, 2567), (0x2a0ed, 3390), (0x2a0f3, 3391), (0x2a0f8, 2277),
+    (0x2a0fe, 3382), (0x2a107, 1748), (0x2a123, 2568), (0x2a133, 1348), (0x2a134, 2572), (0x2a150, 19209), (0x2a192, 2565),
+    (0x2a193, 2577), (0x2a1ab, 2566), (0x2a1b4, 3389), (0x2a1b5, 1893), (0x2a1df, 2569), (0x2a1f5, 3381), (0x2a220, 2578),
+    (0x2a233, 2580), (0x2a293, 4449), (0x2a29f, 2373), (0x2a2b2, 4872), (0x2a2b4, 2583), (0x2a2b6, 3683), (0x2a2ba, 1758),
+    (0x2a2bd, 1642), (0x2a2df, 4362), (0x2a2ff, 4451), (0x2a351, 964), (0x2a3a9, 1256), (0x2a434, 4691), (0x2a45b, 2898),
+    (0x2a5c6, 3039), (0x2a5cb, 4454), (0x2a601, 1516), (0x2a632, 1581), (0x2a64a, 4848), (0x2a65b, 1541), (0x2a6a9, 4606),
+    (0x2f825, 3663), (0x2f83b, 4027), (0x2f840, 4874), (0x2f878, 1667), (0x2f894, 19319), (0x2f8a6, 19366), (0x2f8cd, 4257),
+    (0x2f994, 2196), (0x2f9b2, 2268), (0x2f9bc, 3198), (0x2f9d4, 2340),
+];
+
+/// Returns the character at `pointer`, if any.
+pub(crate) fn decode(pointer: usize) -> Option<char> {
+    match TO_UNICODE.get(pointer) {
+        Some(&0) | None => None,
+        Some(&code_point) => char::from_u32(code_point),
+    }
+}
+
+/// Returns the pointer of `character`, if it is part of the character set.
+pub(crate) fn encode(character: char) -> Option<usize> {
+    let code_point = u32::from(character);
+
+    FROM_UNICODE
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| usize::from(FROM_UNICODE[index].1))
+}
@@ -0,0 +1,4610 @@
+//! The GB18030 character set. The pointer of the two-byte character with lead byte `l` and trail
+//! byte `t` is `(l - 0x81) * 190 + t - o`, with `o` being 0x40 for trail bytes below 0x7f and 0x41
+//! otherwise. The pointer of the four-byte character with bytes `b1` through `b4` is
+//! `(((b1 - 0x81) * 10 + b2 - 0x30) * 126 + b3 - 0x81) * 10 + b4 - 0x30`.
+
+use std::char;
+use std::convert::TryFrom;
+
+/// The Unicode code point of the character at each pointer, or 0 if the pointer is unassigned.
+#[rustfmt::skip]
+static TO_UNICODE: [u16; 23940] = [
+    0x4e02, 0x4e04, 0x4e05, 0x4e06, 0x4e0f, 0x4e12, 0x4e17, 0x4e1f, 0x4e20, 0x4e21, 0x4e23, 0x4e26, 0x4e29, 0x4e2e, 0x4e2f, 0x4e31,
+    0x4e33, 0x4e35, 0x4e37, 0x4e3c, 0x4e40, 0x4e41, 0x4e42, 0x4e44, 0x4e46, 0x4e4a, 0x4e51, 0x4e55, 0x4e57, 0x4e5a, 0x4e5b, 0x4e62,
+    0x4e63, 0x4e64, 0x4e65, 0x4e67, 0x4e68, 0x4e6a, 0x4e6b, 0x4e6c, 0x4e6d, 0x4e6e, 0x4e6f, 0x4e72, 0x4e74, 0x4e75, 0x4e76, 0x4e77,
+    0x4e78, 0x4e79, 0x4e7a, 0x4e7b, 0x4e7c, 0x4e7d, 0x4e7f, 0x4e80, 0x4e81, 0x4e82, 0x4e83, 0x4e84, 0x4e85, 0x4e87, 0x4e8a, 0x4e90,
+    0x4e96, 0x4e97, 0x4e99, 0x4e9c, 0x4e9d, 0x4e9e, 0x4ea3, 0x4eaa, 0x4eaf, 0x4eb0, 0x4eb1, 0x4eb4, 0x4eb6, 0x4eb7, 0x4eb8, 0x4eb9,
+    0x4ebc, 0x4ebd, 0x4ebe, 0x4ec8, 0x4ecc, 0x4ecf, 0x4ed0, 0x4ed2, 0x4eda, 0x4edb, 0x4edc, 0x4ee0, 0x4ee2, 0x4ee6, 0x4ee7, 0x4ee9,
+    0x4eed, 0x4eee, 0x4eef, 0x4ef1, 0x4ef4, 0x4ef8, 0x4ef9, 0x4efa, 0x4efc, 0x4efe, 0x4f00, 0x4f02, 0x4f03, 0x4f04, 0x4f05, 0x4f06,
+    0x4f07, 0x4f08, 0x4f0b, 0x4f0c, 0x4f12, 0x4f13, 0x4f14, 0x4f15, 0x4f16, 0x4f1c, 0x4f1d, 0x4f21, 0x4f23, 0x4f28, 0x4f29, 0x4f2c,
+    0x4f2d, 0x4f2e, 0x4f31, 0x4f33, 0x4f35, 0x4f37, 0x4f39, 0x4f3b, 0x4f3e, 0x4f3f, 0x4f40, 0x4f41, 0x4f42, 0x4f44, 0x4f45, 0x4f47,
+    0x4f48, 0x4f49, 0x4f4a, 0x4f4b, 0x4f4c, 0x4f52, 0x4f54, 0x4f56, 0x4f61, 0x4f62, 0x4f66, 0x4f68, 0x4f6a, 0x4f6b, 0x4f6d, 0x4f6e,
+    0x4f71, 0x4f72, 0x4f75, 0x4f77, 0x4f78, 0x4f79, 0x4f7a, 0x4f7d, 0x4f80, 0x4f81, 0x4f82, 0x4f85, 0x4f86, 0x4f87, 0x4f8a, 0x4f8c,
+    0x4f8e, 0x4f90, 0x4f92, 0x4f93, 0x4f95, 0x4f96, 0x4f98, 0x4f99, 0x4f9a, 0x4f9c, 0x4f9e, 0x4f9f, 0x4fa1, 0x4fa2, 0x4fa4, 0x4fab,
+    0x4fad, 0x4fb0, 0x4fb1, 0x4fb2, 0x4fb3, 0x4fb4, 0x4fb6, 0x4fb7, 0x4fb8, 0x4fb9, 0x4fba, 0x4fbb, 0x4fbc, 0x4fbd, 0x4fbe, 0x4fc0,
+    0x4fc1, 0x4fc2, 0x4fc6, 0x4fc7, 0x4fc8, 0x4fc9, 0x4fcb, 0x4fcc, 0x4fcd, 0x4fd2, 0x4fd3, 0x4fd4, 0x4fd5, 0x4fd6, 0x4fd9, 0x4fdb,
+    0x4fe0, 0x4fe2, 0x4fe4, 0x4fe5, 0x4fe7, 0x4feb, 0x4fec, 0x4ff0, 0x4ff2, 0x4ff4, 0x4ff5, 0x4ff6, 0x4ff7, 0x4ff9, 0x4ffb, 0x4ffc,
+    0x4ffd, 0x4fff, 0x5000, 0x5001, 0x5002, 0x5003, 0x5004, 0x5005, 0x5006, 0x5007, 0x5008, 0x5009, 0x500a, 0x500b, 0x500e, 0x5010,
+    0x5011, 0x5013, 0x5015, 0x5016, 0x5017, 0x501b, 0x501d, 0x501e, 0x5020, 0x5022, 0x5023, 0x5024, 0x5027, 0x502b, 0x502f, 0x5030,
+    0x5031, 0x5032, 0x5033, 0x5034, 0x5035, 0x5036, 0x5037, 0x5038, 0x5039, 0x503b, 0x503d, 0x503f, 0x5040, 0x5041, 0x5042, 0x5044,
+    0x5045, 0x5046, 0x5049, 0x504a, 0x504b, 0x504d, 0x5050, 0x5051, 0x5052, 0x5053, 0x5054, 0x5056, 0x5057, 0x5058, 0x5059, 0x505b,
+    0x505d, 0x505e, 0x505f, 0x5060, 0x5061, 0x5062, 0x5063, 0x5064, 0x5066, 0x5067, 0x5068, 0x5069, 0x506a, 0x506b, 0x506d, 0x506e,
+    0x506f, 0x5070, 0x5071, 0x5072, 0x5073, 0x5074, 0x5075, 0x5078, 0x5079, 0x507a, 0x507c, 0x507d, 0x5081, 0x5082, 0x5083, 0x5084,
+    0x5086, 0x5087, 0x5089, 0x508a, 0x508b, 0x508c, 0x508e, 0x508f, 0x5090, 0x5091, 0x5092, 0x5093, 0x5094, 0x5095, 0x5096, 0x5097,
+    0x5098, 0x5099, 0x509a, 0x509b, 0x509c, 0x509d, 0x509e, 0x509f, 0x50a0, 0x50a1, 0x50a2, 0x50a4, 0x50a6, 0x50aa, 0x50ab, 0x50ad,
+    0x50ae, 0x50af, 0x50b0, 0x50b1, 0x50b3, 0x50b4, 0x50b5, 0x50b6, 0x50b7, 0x50b8, 0x50b9, 0x50bc, 0x50bd, 0x50be, 0x50bf, 0x50c0,
+    0x50c1, 0x50c2, 0x50c3, 0x50c4, 0x50c5, 0x50c6, 0x50c7, 0x50c8, 0x50c9, 0x50ca, 0x50cb, 0x50cc, 0x50cd, 0x50ce, 0x50d0, 0x50d1,
+    0x50d2, 0x50d3, 0x50d4, 0x50d5, 0x50d7, 0x50d8, 0x50d9, 0x50db, 0x50dc, 0x50dd, 0x50de, 0x50df, 0x50e0, 0x50e1, 0x50e2, 0x50e3,
+    0x50e4, 0x50e5, 0x50e8, 0x50e9, 0x50ea, 0x50eb, 0x50ef, 0x50f0, 0x50f1, 0x50f2, 0x50f4, 0x50f6, 0x50f7, 0x50f8, 0x50f9, 0x50fa,
+    0x50fc, 0x50fd, 0x50fe, 0x50ff, 0x5100, 0x5101, 0x5102, 0x5103, 0x5104, 0x5105, 0x5108, 0x5109, 0x510a, 0x510c, 0x510d, 0x510e,
+    0x510f, 0x5110, 0x5111, 0x5113, 0x5114, 0x5115, 0x5116, 0x5117, 0x5118, 0x5119, 0x511a, 0x511b, 0x511c, 0x511d, 0x511e, 0x511f,
+    0x5120, 0x5122, 0x5123, 0x5124, 0x5125, 0x5126, 0x5127, 0x5128, 0x5129, 0x512a, 0x512b, 0x512c, 0x512d, 0x512e, 0x512f, 0x5130,
+    0x5131, 0x5132, 0x5133, 0x5134, 0x5135, 0x5136, 0x5137, 0x5138, 0x5139, 0x513a, 0x513b, 0x513c, 0x513d, 0x513e, 0x5142, 0x5147,
+    0x514a, 0x514c, 0x514e, 0x514f, 0x5150, 0x5152, 0x5153, 0x5157, 0x5158, 0x5159, 0x515b, 0x515d, 0x515e, 0x515f, 0x5160, 0x5161,
+    0x5163, 0x5164, 0x5166, 0x5167, 0x5169, 0x516a, 0x516f, 0x5172, 0x517a, 0x517e, 0x517f, 0x5183, 0x5184, 0x5186, 0x5187, 0x518a,
+    0x518b, 0x518e, 0x518f, 0x5190, 0x5191, 0x5193, 0x5194, 0x5198, 0x519a, 0x519d, 0x519e, 0x519f, 0x51a1, 0x51a3, 0x51a6, 0x51a7,
+    0x51a8, 0x51a9, 0x51aa, 0x51ad, 0x51ae, 0x51b4, 0x51b8, 0x51b9, 0x51ba, 0x51be, 0x51bf, 0x51c1, 0x51c2, 0x51c3, 0x51c5, 0x51c8,
+    0x51ca, 0x51cd, 0x51ce, 0x51d0, 0x51d2, 0x51d3, 0x51d4, 0x51d5, 0x51d6, 0x51d7, 0x51d8, 0x51d9, 0x51da, 0x51dc, 0x51de, 0x51df,
+    0x51e2, 0x51e3, 0x51e5, 0x51e6, 0x51e7, 0x51e8, 0x51e9, 0x51ea, 0x51ec, 0x51ee, 0x51f1, 0x51f2, 0x51f4, 0x51f7, 0x51fe, 0x5204,
+    0x5205, 0x5209, 0x520b, 0x520c, 0x520f, 0x5210, 0x5213, 0x5214, 0x5215, 0x521c, 0x521e, 0x521f, 0x5221, 0x5222, 0x5223, 0x5225,
+    0x5226, 0x5227, 0x522a, 0x522c, 0x522f, 0x5231, 0x5232, 0x5234, 0x5235, 0x523c, 0x523e, 0x5244, 0x5245, 0x5246, 0x5247, 0x5248,
+    0x5249, 0x524b, 0x524e, 0x524f, 0x5252, 0x5253, 0x5255, 0x5257, 0x5258, 0x5259, 0x525a, 0x525b, 0x525d, 0x525f, 0x5260, 0x5262,
+    0x5263, 0x5264, 0x5266, 0x5268, 0x526b, 0x526c, 0x526d, 0x526e, 0x5270, 0x5271, 0x5273, 0x5274, 0x5275, 0x5276, 0x5277, 0x5278,
+    0x5279, 0x527a, 0x527b, 0x527c, 0x527e, 0x5280, 0x5283, 0x5284, 0x5285, 0x5286, 0x5287, 0x5289, 0x528a, 0x528b, 0x528c, 0x528d,
+    0x528e, 0x528f, 0x5291, 0x5292, 0x5294, 0x5295, 0x5296, 0x5297, 0x5298, 0x5299, 0x529a, 0x529c, 0x52a4, 0x52a5, 0x52a6, 0x52a7,
+    0x52ae, 0x52af, 0x52b0, 0x52b4, 0x52b5, 0x52b6, 0x52b7, 0x52b8, 0x52b9, 0x52ba, 0x52bb, 0x52bc, 0x52bd, 0x52c0, 0x52c1, 0x52c2,
+    0x52c4, 0x52c5, 0x52c6, 0x52c8, 0x52ca, 0x52cc, 0x52cd, 0x52ce, 0x52cf, 0x52d1, 0x52d3, 0x52d4, 0x52d5, 0x52d7, 0x52d9, 0x52da,
+    0x52db, 0x52dc, 0x52dd, 0x52de, 0x52e0, 0x52e1, 0x52e2, 0x52e3, 0x52e5, 0x52e6, 0x52e7, 0x52e8, 0x52e9, 0x52ea, 0x52eb, 0x52ec,
+    0x52ed, 0x52ee, 0x52ef, 0x52f1, 0x52f2, 0x52f3, 0x52f4, 0x52f5, 0x52f6, 0x52f7, 0x52f8, 0x52fb, 0x52fc, 0x52fd, 0x5301, 0x5302,
+    0x5303, 0x5304, 0x5307, 0x5309, 0x530a, 0x530b, 0x530c, 0x530e, 0x5311, 0x5312, 0x5313, 0x5314, 0x5318, 0x531b, 0x531c, 0x531e,
+    0x531f, 0x5322, 0x5324, 0x5325, 0x5327, 0x5328, 0x5329, 0x532b, 0x532c, 0x532d, 0x532f, 0x5330, 0x5331, 0x5332, 0x5333, 0x5334,
+    0x5335, 0x5336, 0x5337, 0x5338, 0x533c, 0x533d, 0x5340, 0x5342, 0x5344, 0x5346, 0x534b, 0x534c, 0x534d, 0x5350, 0x5354, 0x5358,
+    0x5359, 0x535b, 0x535d, 0x5365, 0x5368, 0x536a, 0x536c, 0x536d, 0x5372, 0x5376, 0x5379, 0x537b, 0x537c, 0x537d, 0x537e, 0x5380,
+    0x5381, 0x5383, 0x5387, 0x5388, 0x538a, 0x538e, 0x538f, 0x5390, 0x5391, 0x5392, 0x5393, 0x5394, 0x5396, 0x5397, 0x5399, 0x539b,
+    0x539c, 0x539e, 0x53a0, 0x53a1, 0x53a4, 0x53a7, 0x53aa, 0x53ab, 0x53ac, 0x53ad, 0x53af, 0x53b0, 0x53b1, 0x53b2, 0x53b3, 0x53b4,
+    0x53b5, 0x53b7, 0x53b8, 0x53b9, 0x53ba, 0x53bc, 0x53bd, 0x53be, 0x53c0, 0x53c3, 0x53c4, 0x53c5, 0x53c6, 0x53c7, 0x53ce, 0x53cf,
+    0x53d0, 0x53d2, 0x53d3, 0x53d5, 0x53da, 0x53dc, 0x53dd, 0x53de, 0x53e1, 0x53e2, 0x53e7, 0x53f4, 0x53fa, 0x53fe, 0x53ff, 0x5400,
+    0x5402, 0x5405, 0x5407, 0x540b, 0x5414, 0x5418, 0x5419, 0x541a, 0x541c, 0x5422, 0x5424, 0x5425, 0x542a, 0x5430, 0x5433, 0x5436,
+    0x5437, 0x543a, 0x543d, 0x543f, 0x5441, 0x5442, 0x5444, 0x5445, 0x5447, 0x5449, 0x544c, 0x544d, 0x544e, 0x544f, 0x5451, 0x545a,
+    0x545d, 0x545e, 0x545f, 0x5460, 0x5461, 0x5463, 0x5465, 0x5467, 0x5469, 0x546a, 0x546b, 0x546c, 0x546d, 0x546e, 0x546f, 0x5470,
+    0x5474, 0x5479, 0x547a, 0x547e, 0x547f, 0x5481, 0x5483, 0x5485, 0x5487, 0x5488, 0x5489, 0x548a, 0x548d, 0x5491, 0x5493, 0x5497,
+    0x5498, 0x549c, 0x549e, 0x549f, 0x54a0, 0x54a1, 0x54a2, 0x54a5, 0x54ae, 0x54b0, 0x54b2, 0x54b5, 0x54b6, 0x54b7, 0x54b9, 0x54ba,
+    0x54bc, 0x54be, 0x54c3, 0x54c5, 0x54ca, 0x54cb, 0x54d6, 0x54d8, 0x54db, 0x54e0, 0x54e1, 0x54e2, 0x54e3, 0x54e4, 0x54eb, 0x54ec,
+    0x54ef, 0x54f0, 0x54f1, 0x54f4, 0x54f5, 0x54f6, 0x54f7, 0x54f8, 0x54f9, 0x54fb, 0x54fe, 0x5500, 0x5502, 0x5503, 0x5504, 0x5505,
+    0x5508, 0x550a, 0x550b, 0x550c, 0x550d, 0x550e, 0x5512, 0x5513, 0x5515, 0x5516, 0x5517, 0x5518, 0x5519, 0x551a, 0x551c, 0x551d,
+    0x551e, 0x551f, 0x5521, 0x5525, 0x5526, 0x5528, 0x5529, 0x552b, 0x552d, 0x5532, 0x5534, 0x5535, 0x5536, 0x5538, 0x5539, 0x553a,
+    0x553b, 0x553d, 0x5540, 0x5542, 0x5545, 0x5547, 0x5548, 0x554b, 0x554c, 0x554d, 0x554e, 0x554f, 0x5551, 0x5552, 0x5553, 0x5554,
+    0x5557, 0x5558, 0x5559, 0x555a, 0x555b, 0x555d, 0x555e, 0x555f, 0x5560, 0x5562, 0x5563, 0x5568, 0x5569, 0x556b, 0x556f, 0x5570,
+    0x5571, 0x5572, 0x5573, 0x5574, 0x5579, 0x557a, 0x557d, 0x557f, 0x5585, 0x5586, 0x558c, 0x558d, 0x558e, 0x5590, 0x5592, 0x5593,
+    0x5595, 0x5596, 0x5597, 0x559a, 0x559b, 0x559e, 0x55a0, 0x55a1, 0x55a2, 0x55a3, 0x55a4, 0x55a5, 0x55a6, 0x55a8, 0x55a9, 0x55aa,
+    0x55ab, 0x55ac, 0x55ad, 0x55ae, 0x55af, 0x55b0, 0x55b2, 0x55b4, 0x55b6, 0x55b8, 0x55ba, 0x55bc, 0x55bf, 0x55c0, 0x55c1, 0x55c2,
+    0x55c3, 0x55c6, 0x55c7, 0x55c8, 0x55ca, 0x55cb, 0x55ce, 0x55cf, 0x55d0, 0x55d5, 0x55d7, 0x55d8, 0x55d9, 0x55da, 0x55db, 0x55de,
+    0x55e0, 0x55e2, 0x55e7, 0x55e9, 0x55ed, 0x55ee, 0x55f0, 0x55f1, 0x55f4, 0x55f6, 0x55f8, 0x55f9, 0x55fa, 0x55fb, 0x55fc, 0x55ff,
+    0x5602, 0x5603, 0x5604, 0x5605, 0x5606, 0x5607, 0x560a, 0x560b, 0x560d, 0x5610, 0x5611, 0x5612, 0x5613, 0x5614, 0x5615, 0x5616,
+    0x5617, 0x5619, 0x561a, 0x561c, 0x561d, 0x5620, 0x5621, 0x5622, 0x5625, 0x5626, 0x5628, 0x5629, 0x562a, 0x562b, 0x562e, 0x562f,
+    0x5630, 0x5633, 0x5635, 0x5637, 0x5638, 0x563a, 0x563c, 0x563d, 0x563e, 0x5640, 0x5641, 0x5642, 0x5643, 0x5644, 0x5645, 0x5646,
+    0x5647, 0x5648, 0x5649, 0x564a, 0x564b, 0x564f, 0x5650, 0x5651, 0x5652, 0x5653, 0x5655, 0x5656, 0x565a, 0x565b, 0x565d, 0x565e,
+    0x565f, 0x5660, 0x5661, 0x5663, 0x5665, 0x5666, 0x5667, 0x566d, 0x566e, 0x566f, 0x5670, 0x5672, 0x5673, 0x5674, 0x5675, 0x5677,
+    0x5678, 0x5679, 0x567a, 0x567d, 0x567e, 0x567f, 0x5680, 0x5681, 0x5682, 0x5683, 0x5684, 0x5687, 0x5688, 0x5689, 0x568a, 0x568b,
+    0x568c, 0x568d, 0x5690, 0x5691, 0x5692, 0x5694, 0x5695, 0x5696, 0x5697, 0x5698, 0x5699, 0x569a, 0x569b, 0x569c, 0x569d, 0x569e,
+    0x569f, 0x56a0, 0x56a1, 0x56a2, 0x56a4, 0x56a5, 0x56a6, 0x56a7, 0x56a8, 0x56a9, 0x56aa, 0x56ab, 0x56ac, 0x56ad, 0x56ae, 0x56b0,
+    0x56b1, 0x56b2, 0x56b3, 0x56b4, 0x56b5, 0x56b6, 0x56b8, 0x56b9, 0x56ba, 0x56bb, 0x56bd, 0x56be, 0x56bf, 0x56c0, 0x56c1, 0x56c2,
+    0x56c3, 0x56c4, 0x56c5, 0x56c6, 0x56c7, 0x56c8, 0x56c9, 0x56cb, 0x56cc, 0x56cd, 0x56ce, 0x56cf, 0x56d0, 0x56d1, 0x56d2, 0x56d3,
+    0x56d5, 0x56d6, 0x56d8, 0x56d9, 0x56dc, 0x56e3, 0x56e5, 0x56e6, 0x56e7, 0x56e8, 0x56e9, 0x56ea, 0x56ec, 0x56ee, 0x56ef, 0x56f2,
+    0x56f3, 0x56f6, 0x56f7, 0x56f8, 0x56fb, 0x56fc, 0x5700, 0x5701, 0x5702, 0x5705, 0x5707, 0x570b, 0x570c, 0x570d, 0x570e, 0x570f,
+    0x5710, 0x5711, 0x5712, 0x5713, 0x5714, 0x5715, 0x5716, 0x5717, 0x5718, 0x5719, 0x571a, 0x571b, 0x571d, 0x571e, 0x5720, 0x5721,
+    0x5722, 0x5724, 0x5725, 0x5726, 0x5727, 0x572b, 0x5731, 0x5732, 0x5734, 0x5735, 0x5736, 0x5737, 0x5738, 0x573c, 0x573d, 0x573f,
+    0x5741, 0x5743, 0x5744, 0x5745, 0x5746, 0x5748, 0x5749, 0x574b, 0x5752, 0x5753, 0x5754, 0x5755, 0x5756, 0x5758, 0x5759, 0x5762,
+    0x5763, 0x5765, 0x5767, 0x576c, 0x576e, 0x5770, 0x5771, 0x5772, 0x5774, 0x5775, 0x5778, 0x5779, 0x577a, 0x577d, 0x577e, 0x577f,
+    0x5780, 0x5781, 0x5787, 0x5788, 0x5789, 0x578a, 0x578d, 0x578e, 0x578f, 0x5790, 0x5791, 0x5794, 0x5795, 0x5796, 0x5797, 0x5798,
+    0x5799, 0x579a, 0x579c, 0x579d, 0x579e, 0x579f, 0x57a5, 0x57a8, 0x57aa, 0x57ac, 0x57af, 0x57b0, 0x57b1, 0x57b3, 0x57b5, 0x57b6,
+    0x57b7, 0x57b9, 0x57ba, 0x57bb, 0x57bc, 0x57bd, 0x57be, 0x57bf, 0x57c0, 0x57c1, 0x57c4, 0x57c5, 0x57c6, 0x57c7, 0x57c8, 0x57c9,
+    0x57ca, 0x57cc, 0x57cd, 0x57d0, 0x57d1, 0x57d3, 0x57d6, 0x57d7, 0x57db, 0x57dc, 0x57de, 0x57e1, 0x57e2, 0x57e3, 0x57e5, 0x57e6,
+    0x57e7, 0x57e8, 0x57e9, 0x57ea, 0x57eb, 0x57ec, 0x57ee, 0x57f0, 0x57f1, 0x57f2, 0x57f3, 0x57f5, 0x57f6, 0x57f7, 0x57fb, 0x57fc,
+    0x57fe, 0x57ff, 0x5801, 0x5803, 0x5804, 0x5805, 0x5808, 0x5809, 0x580a, 0x580c, 0x580e, 0x580f, 0x5810, 0x5812, 0x5813, 0x5814,
+    0x5816, 0x5817, 0x5818, 0x581a, 0x581b, 0x581c, 0x581d, 0x581f, 0x5822, 0x5823, 0x5825, 0x5826, 0x5827, 0x5828, 0x5829, 0x582b,
+    0x582c, 0x582d, 0x582e, 0x582f, 0x5831, 0x5832, 0x5833, 0x5834, 0x5836, 0x5837, 0x5838, 0x5839, 0x583a, 0x583b, 0x583c, 0x583d,
+    0x583e, 0x583f, 0x5840, 0x5841, 0x5842, 0x5843, 0x5845, 0x5846, 0x5847, 0x5848, 0x5849, 0x584a, 0x584b, 0x584e, 0x584f, 0x5850,
+    0x5852, 0x5853, 0x5855, 0x5856, 0x5857, 0x5859, 0x585a, 0x585b, 0x585c, 0x585d, 0x585f, 0x5860, 0x5861, 0x5862, 0x5863, 0x5864,
+    0x5866, 0x5867, 0x5868, 0x5869, 0x586a, 0x586d, 0x586e, 0x586f, 0x5870, 0x5871, 0x5872, 0x5873, 0x5874, 0x5875, 0x5876, 0x5877,
+    0x5878, 0x5879, 0x587a, 0x587b, 0x587c, 0x587d, 0x587f, 0x5882, 0x5884, 0x5886, 0x5887, 0x5888, 0x588a, 0x588b, 0x588c, 0x588d,
+    0x588e, 0x588f, 0x5890, 0x5891, 0x5894, 0x5895, 0x5896, 0x5897, 0x5898, 0x589b, 0x589c, 0x589d, 0x58a0, 0x58a1, 0x58a2, 0x58a3,
+    0x58a4, 0x58a5, 0x58a6, 0x58a7, 0x58aa, 0x58ab, 0x58ac, 0x58ad, 0x58ae, 0x58af, 0x58b0, 0x58b1, 0x58b2, 0x58b3, 0x58b4, 0x58b5,
+    0x58b6, 0x58b7, 0x58b8, 0x58b9, 0x58ba, 0x58bb, 0x58bd, 0x58be, 0x58bf, 0x58c0, 0x58c2, 0x58c3, 0x58c4, 0x58c6, 0x58c7, 0x58c8,
+    0x58c9, 0x58ca, 0x58cb, 0x58cc, 0x58cd, 0x58ce, 0x58cf, 0x58d0, 0x58d2, 0x58d3, 0x58d4, 0x58d6, 0x58d7, 0x58d8, 0x58d9, 0x58da,
+    0x58db, 0x58dc, 0x58dd, 0x58de, 0x58df, 0x58e0, 0x58e1, 0x58e2, 0x58e3, 0x58e5, 0x58e6, 0x58e7, 0x58e8, 0x58e9, 0x58ea, 0x58ed,
+    0x58ef, 0x58f1, 0x58f2, 0x58f4, 0x58f5, 0x58f7, 0x58f8, 0x58fa, 0x58fb, 0x58fc, 0x58fd, 0x58fe, 0x58ff, 0x5900, 0x5901, 0x5903,
+    0x5905, 0x5906, 0x5908, 0x5909, 0x590a, 0x590b, 0x590c, 0x590e, 0x5910, 0x5911, 0x5912, 0x5913, 0x5917, 0x5918, 0x591b, 0x591d,
+    0x591e, 0x5920, 0x5921, 0x5922, 0x5923, 0x5926, 0x5928, 0x592c, 0x5930, 0x5932, 0x5933, 0x5935, 0x5936, 0x593b, 0x593d, 0x593e,
+    0x593f, 0x5940, 0x5943, 0x5945, 0x5946, 0x594a, 0x594c, 0x594d, 0x5950, 0x5952, 0x5953, 0x5959, 0x595b, 0x595c, 0x595d, 0x595e,
+    0x595f, 0x5961, 0x5963, 0x5964, 0x5966, 0x5967, 0x5968, 0x5969, 0x596a, 0x596b, 0x596c, 0x596d, 0x596e, 0x596f, 0x5970, 0x5971,
+    0x5972, 0x5975, 0x5977, 0x597a, 0x597b, 0x597c, 0x597e, 0x597f, 0x5980, 0x5985, 0x5989, 0x598b, 0x598c, 0x598e, 0x598f, 0x5990,
+    0x5991, 0x5994, 0x5995, 0x5998, 0x599a, 0x599b, 0x599c, 0x599d, 0x599f, 0x59a0, 0x59a1, 0x59a2, 0x59a6, 0x59a7, 0x59ac, 0x59ad,
+    0x59b0, 0x59b1, 0x59b3, 0x59b4, 0x59b5, 0x59b6, 0x59b7, 0x59b8, 0x59ba, 0x59bc, 0x59bd, 0x59bf, 0x59c0, 0x59c1, 0x59c2, 0x59c3,
+    0x59c4, 0x59c5, 0x59c7, 0x59c8, 0x59c9, 0x59cc, 0x59cd, 0x59ce, 0x59cf, 0x59d5, 0x59d6, 0x59d9, 0x59db, 0x59de, 0x59df, 0x59e0,
+    0x59e1, 0x59e2, 0x59e4, 0x59e6, 0x59e7, 0x59e9, 0x59ea, 0x59eb, 0x59ed, 0x59ee, 0x59ef, 0x59f0, 0x59f1, 0x59f2, 0x59f3, 0x59f4,
+    0x59f5, 0x59f6, 0x59f7, 0x59f8, 0x59fa, 0x59fc, 0x59fd, 0x59fe, 0x5a00, 0x5a02, 0x5a0a, 0x5a0b, 0x5a0d, 0x5a0e, 0x5a0f, 0x5a10,
+    0x5a12, 0x5a14, 0x5a15, 0x5a16, 0x5a17, 0x5a19, 0x5a1a, 0x5a1b, 0x5a1d, 0x5a1e, 0x5a21, 0x5a22, 0x5a24, 0x5a26, 0x5a27, 0x5a28,
+    0x5a2a, 0x5a2b, 0x5a2c, 0x5a2d, 0x5a2e, 0x5a2f, 0x5a30, 0x5a33, 0x5a35, 0x5a37, 0x5a38, 0x5a39, 0x5a3a, 0x5a3b, 0x5a3d, 0x5a3e,
+    0x5a3f, 0x5a41, 0x5a42, 0x5a43, 0x5a44, 0x5a45, 0x5a47, 0x5a48, 0x5a4b, 0x5a4c, 0x5a4d, 0x5a4e, 0x5a4f, 0x5a50, 0x5a51, 0x5a52,
+    0x5a53, 0x5a54, 0x5a56, 0x5a57, 0x5a58, 0x5a59, 0x5a5b, 0x5a5c, 0x5a5d, 0x5a5e, 0x5a5f, 0x5a60, 0x5a61, 0x5a63, 0x5a64, 0x5a65,
+    0x5a66, 0x5a68, 0x5a69, 0x5a6b, 0x5a6c, 0x5a6d, 0x5a6e, 0x5a6f, 0x5a70, 0x5a71, 0x5a72, 0x5a73, 0x5a78, 0x5a79, 0x5a7b, 0x5a7c,
+    0x5a7d, 0x5a7e, 0x5a80, 0x5a81, 0x5a82, 0x5a83, 0x5a84, 0x5a85, 0x5a86, 0x5a87, 0x5a88, 0x5a89, 0x5a8a, 0x5a8b, 0x5a8c, 0x5a8d,
+    0x5a8e, 0x5a8f, 0x5a90, 0x5a91, 0x5a93, 0x5a94, 0x5a95, 0x5a96, 0x5a97, 0x5a98, 0x5a99, 0x5a9c, 0x5a9d, 0x5a9e, 0x5a9f, 0x5aa0,
+    0x5aa1, 0x5aa2, 0x5aa3, 0x5aa4, 0x5aa5, 0x5aa6, 0x5aa7, 0x5aa8, 0x5aa9, 0x5aab, 0x5aac, 0x5aad, 0x5aae, 0x5aaf, 0x5ab0, 0x5ab1,
+    0x5ab4, 0x5ab6, 0x5ab7, 0x5ab9, 0x5aba, 0x5abb, 0x5abc, 0x5abd, 0x5abf, 0x5ac0, 0x5ac3, 0x5ac4, 0x5ac5, 0x5ac6, 0x5ac7, 0x5ac8,
+    0x5aca, 0x5acb, 0x5acd, 0x5ace, 0x5acf, 0x5ad0, 0x5ad1, 0x5ad3, 0x5ad5, 0x5ad7, 0x5ad9, 0x5ada, 0x5adb, 0x5add, 0x5ade, 0x5adf,
+    0x5ae2, 0x5ae4, 0x5ae5, 0x5ae7, 0x5ae8, 0x5aea, 0x5aec, 0x5aed, 0x5aee, 0x5aef, 0x5af0, 0x5af2, 0x5af3, 0x5af4, 0x5af5, 0x5af6,
+    0x5af7, 0x5af8, 0x5af9, 0x5afa, 0x5afb, 0x5afc, 0x5afd, 0x5afe, 0x5aff, 0x5b00, 0x5b01, 0x5b02, 0x5b03, 0x5b04, 0x5b05, 0x5b06,
+    0x5b07, 0x5b08, 0x5b0a, 0x5b0b, 0x5b0c, 0x5b0d, 0x5b0e, 0x5b0f, 0x5b10, 0x5b11, 0x5b12, 0x5b13, 0x5b14, 0x5b15, 0x5b18, 0x5b19,
+    0x5b1a, 0x5b1b, 0x5b1c, 0x5b1d, 0x5b1e, 0x5b1f, 0x5b20, 0x5b21, 0x5b22, 0x5b23, 0x5b24, 0x5b25, 0x5b26, 0x5b27, 0x5b28, 0x5b29,
+    0x5b2a, 0x5b2b, 0x5b2c, 0x5b2d, 0x5b2e, 0x5b2f, 0x5b30, 0x5b31, 0x5b33, 0x5b35, 0x5b36, 0x5b38, 0x5b39, 0x5b3a, 0x5b3b, 0x5b3c,
+    0x5b3d, 0x5b3e, 0x5b3f, 0x5b41, 0x5b42, 0x5b43, 0x5b44, 0x5b45, 0x5b46, 0x5b47, 0x5b48, 0x5b49, 0x5b4a, 0x5b4b, 0x5b4c, 0x5b4d,
+    0x5b4e, 0x5b4f, 0x5b52, 0x5b56, 0x5b5e, 0x5b60, 0x5b61, 0x5b67, 0x5b68, 0x5b6b, 0x5b6d, 0x5b6e, 0x5b6f, 0x5b72, 0x5b74, 0x5b76,
+    0x5b77, 0x5b78, 0x5b79, 0x5b7b, 0x5b7c, 0x5b7e, 0x5b7f, 0x5b82, 0x5b86, 0x5b8a, 0x5b8d, 0x5b8e, 0x5b90, 0x5b91, 0x5b92, 0x5b94,
+    0x5b96, 0x5b9f, 0x5ba7, 0x5ba8, 0x5ba9, 0x5bac, 0x5bad, 0x5bae, 0x5baf, 0x5bb1, 0x5bb2, 0x5bb7, 0x5bba, 0x5bbb, 0x5bbc, 0x5bc0,
+    0x5bc1, 0x5bc3, 0x5bc8, 0x5bc9, 0x5bca, 0x5bcb, 0x5bcd, 0x5bce, 0x5bcf, 0x5bd1, 0x5bd4, 0x5bd5, 0x5bd6, 0x5bd7, 0x5bd8, 0x5bd9,
+    0x5bda, 0x5bdb, 0x5bdc, 0x5be0, 0x5be2, 0x5be3, 0x5be6, 0x5be7, 0x5be9, 0x5bea, 0x5beb, 0x5bec, 0x5bed, 0x5bef, 0x5bf1, 0x5bf2,
+    0x5bf3, 0x5bf4, 0x5bf5, 0x5bf6, 0x5bf7, 0x5bfd, 0x5bfe, 0x5c00, 0x5c02, 0x5c03, 0x5c05, 0x5c07, 0x5c08, 0x5c0b, 0x5c0c, 0x5c0d,
+    0x5c0e, 0x5c10, 0x5c12, 0x5c13, 0x5c17, 0x5c19, 0x5c1b, 0x5c1e, 0x5c1f, 0x5c20, 0x5c21, 0x5c23, 0x5c26, 0x5c28, 0x5c29, 0x5c2a,
+    0x5c2b, 0x5c2d, 0x5c2e, 0x5c2f, 0x5c30, 0x5c32, 0x5c33, 0x5c35, 0x5c36, 0x5c37, 0x5c43, 0x5c44, 0x5c46, 0x5c47, 0x5c4c, 0x5c4d,
+    0x5c52, 0x5c53, 0x5c54, 0x5c56, 0x5c57, 0x5c58, 0x5c5a, 0x5c5b, 0x5c5c, 0x5c5d, 0x5c5f, 0x5c62, 0x5c64, 0x5c67, 0x5c68, 0x5c69,
+    0x5c6a, 0x5c6b, 0x5c6c, 0x5c6d, 0x5c70, 0x5c72, 0x5c73, 0x5c74, 0x5c75, 0x5c76, 0x5c77, 0x5c78, 0x5c7b, 0x5c7c, 0x5c7d, 0x5c7e,
+    0x5c80, 0x5c83, 0x5c84, 0x5c85, 0x5c86, 0x5c87, 0x5c89, 0x5c8a, 0x5c8b, 0x5c8e, 0x5c8f, 0x5c92, 0x5c93, 0x5c95, 0x5c9d, 0x5c9e,
+    0x5c9f, 0x5ca0, 0x5ca1, 0x5ca4, 0x5ca5, 0x5ca6, 0x5ca7, 0x5ca8, 0x5caa, 0x5cae, 0x5caf, 0x5cb0, 0x5cb2, 0x5cb4, 0x5cb6, 0x5cb9,
+    0x5cba, 0x5cbb, 0x5cbc, 0x5cbe, 0x5cc0, 0x5cc2, 0x5cc3, 0x5cc5, 0x5cc6, 0x5cc7, 0x5cc8, 0x5cc9, 0x5cca, 0x5ccc, 0x5ccd, 0x5cce,
+    0x5ccf, 0x5cd0, 0x5cd1, 0x5cd3, 0x5cd4, 0x5cd5, 0x5cd6, 0x5cd7, 0x5cd8, 0x5cda, 0x5cdb, 0x5cdc, 0x5cdd, 0x5cde, 0x5cdf, 0x5ce0,
+    0x5ce2, 0x5ce3, 0x5ce7, 0x5ce9, 0x5ceb, 0x5cec, 0x5cee, 0x5cef, 0x5cf1, 0x5cf2, 0x5cf3, 0x5cf4, 0x5cf5, 0x5cf6, 0x5cf7, 0x5cf8,
+    0x5cf9, 0x5cfa, 0x5cfc, 0x5cfd, 0x5cfe, 0x5cff, 0x5d00, 0x5d01, 0x5d04, 0x5d05, 0x5d08, 0x5d09, 0x5d0a, 0x5d0b, 0x5d0c, 0x5d0d,
+    0x5d0f, 0x5d10, 0x5d11, 0x5d12, 0x5d13, 0x5d15, 0x5d17, 0x5d18, 0x5d19, 0x5d1a, 0x5d1c, 0x5d1d, 0x5d1f, 0x5d20, 0x5d21, 0x5d22,
+    0x5d23, 0x5d25, 0x5d28, 0x5d2a, 0x5d2b, 0x5d2c, 0x5d2f, 0x5d30, 0x5d31, 0x5d32, 0x5d33, 0x5d35, 0x5d36, 0x5d37, 0x5d38, 0x5d39,
+    0x5d3a, 0x5d3b, 0x5d3c, 0x5d3f, 0x5d40, 0x5d41, 0x5d42, 0x5d43, 0x5d44, 0x5d45, 0x5d46, 0x5d48, 0x5d49, 0x5d4d, 0x5d4e, 0x5d4f,
+    0x5d50, 0x5d51, 0x5d52, 0x5d53, 0x5d54, 0x5d55, 0x5d56, 0x5d57, 0x5d59, 0x5d5a, 0x5d5c, 0x5d5e, 0x5d5f, 0x5d60, 0x5d61, 0x5d62,
+    0x5d63, 0x5d64, 0x5d65, 0x5d66, 0x5d67, 0x5d68, 0x5d6a, 0x5d6d, 0x5d6e, 0x5d70, 0x5d71, 0x5d72, 0x5d73, 0x5d75, 0x5d76, 0x5d77,
+    0x5d78, 0x5d79, 0x5d7a, 0x5d7b, 0x5d7c, 0x5d7d, 0x5d7e, 0x5d7f, 0x5d80, 0x5d81, 0x5d83, 0x5d84, 0x5d85, 0x5d86, 0x5d87, 0x5d88,
+    0x5d89, 0x5d8a, 0x5d8b, 0x5d8c, 0x5d8d, 0x5d8e, 0x5d8f, 0x5d90, 0x5d91, 0x5d92, 0x5d93, 0x5d94, 0x5d95, 0x5d96, 0x5d97, 0x5d98,
+    0x5d9a, 0x5d9b, 0x5d9c, 0x5d9e, 0x5d9f, 0x5da0, 0x5da1, 0x5da2, 0x5da3, 0x5da4, 0x5da5, 0x5da6, 0x5da7, 0x5da8, 0x5da9, 0x5daa,
+    0x5dab, 0x5dac, 0x5dad, 0x5dae, 0x5daf, 0x5db0, 0x5db1, 0x5db2, 0x5db3, 0x5db4, 0x5db5, 0x5db6, 0x5db8, 0x5db9, 0x5dba, 0x5dbb,
+    0x5dbc, 0x5dbd, 0x5dbe, 0x5dbf, 0x5dc0, 0x5dc1, 0x5dc2, 0x5dc3, 0x5dc4, 0x5dc6, 0x5dc7, 0x5dc8, 0x5dc9, 0x5dca, 0x5dcb, 0x5dcc,
+    0x5dce, 0x5dcf, 0x5dd0, 0x5dd1, 0x5dd2, 0x5dd3, 0x5dd4, 0x5dd5, 0x5dd6, 0x5dd7, 0x5dd8, 0x5dd9, 0x5dda, 0x5ddc, 0x5ddf, 0x5de0,
+    0x5de3, 0x5de4, 0x5dea, 0x5dec, 0x5ded, 0x5df0, 0x5df5, 0x5df6, 0x5df8, 0x5df9, 0x5dfa, 0x5dfb, 0x5dfc, 0x5dff, 0x5e00, 0x5e04,
+    0x5e07, 0x5e09, 0x5e0a, 0x5e0b, 0x5e0d, 0x5e0e, 0x5e12, 0x5e13, 0x5e17, 0x5e1e, 0x5e1f, 0x5e20, 0x5e21, 0x5e22, 0x5e23, 0x5e24,
+    0x5e25, 0x5e28, 0x5e29, 0x5e2a, 0x5e2b, 0x5e2c, 0x5e2f, 0x5e30, 0x5e32, 0x5e33, 0x5e34, 0x5e35, 0x5e36, 0x5e39, 0x5e3a, 0x5e3e,
+    0x5e3f, 0x5e40, 0x5e41, 0x5e43, 0x5e46, 0x5e47, 0x5e48, 0x5e49, 0x5e4a, 0x5e4b, 0x5e4d, 0x5e4e, 0x5e4f, 0x5e50, 0x5e51, 0x5e52,
+    0x5e53, 0x5e56, 0x5e57, 0x5e58, 0x5e59, 0x5e5a, 0x5e5c, 0x5e5d, 0x5e5f, 0x5e60, 0x5e63, 0x5e64, 0x5e65, 0x5e66, 0x5e67, 0x5e68,
+    0x5e69, 0x5e6a, 0x5e6b, 0x5e6c, 0x5e6d, 0x5e6e, 0x5e6f, 0x5e70, 0x5e71, 0x5e75, 0x5e77, 0x5e79, 0x5e7e, 0x5e81, 0x5e82, 0x5e83,
+    0x5e85, 0x5e88, 0x5e89, 0x5e8c, 0x5e8d, 0x5e8e, 0x5e92, 0x5e98, 0x5e9b, 0x5e9d, 0x5ea1, 0x5ea2, 0x5ea3, 0x5ea4, 0x5ea8, 0x5ea9,
+    0x5eaa, 0x5eab, 0x5eac, 0x5eae, 0x5eaf, 0x5eb0, 0x5eb1, 0x5eb2, 0x5eb4, 0x5eba, 0x5ebb, 0x5ebc, 0x5ebd, 0x5ebf, 0x5ec0, 0x5ec1,
+    0x5ec2, 0x5ec3, 0x5ec4, 0x5ec5, 0x5ec6, 0x5ec7, 0x5ec8, 0x5ecb, 0x5ecc, 0x5ecd, 0x5ece, 0x5ecf, 0x5ed0, 0x5ed4, 0x5ed5, 0x5ed7,
+    0x5ed8, 0x5ed9, 0x5eda, 0x5edc, 0x5edd, 0x5ede, 0x5edf, 0x5ee0, 0x5ee1, 0x5ee2, 0x5ee3, 0x5ee4, 0x5ee5, 0x5ee6, 0x5ee7, 0x5ee9,
+    0x5eeb, 0x5eec, 0x5eed, 0x5eee, 0x5eef, 0x5ef0, 0x5ef1, 0x5ef2, 0x5ef3, 0x5ef5, 0x5ef8, 0x5ef9, 0x5efb, 0x5efc, 0x5efd, 0x5f05,
+    0x5f06, 0x5f07, 0x5f09, 0x5f0c, 0x5f0d, 0x5f0e, 0x5f10, 0x5f12, 0x5f14, 0x5f16, 0x5f19, 0x5f1a, 0x5f1c, 0x5f1d, 0x5f1e, 0x5f21,
+    0x5f22, 0x5f23, 0x5f24, 0x5f28, 0x5f2b, 0x5f2c, 0x5f2e, 0x5f30, 0x5f32, 0x5f33, 0x5f34, 0x5f35, 0x5f36, 0x5f37, 0x5f38, 0x5f3b,
+    0x5f3d, 0x5f3e, 0x5f3f, 0x5f41, 0x5f42, 0x5f43, 0x5f44, 0x5f45, 0x5f46, 0x5f47, 0x5f48, 0x5f49, 0x5f4a, 0x5f4b, 0x5f4c, 0x5f4d,
+    0x5f4e, 0x5f4f, 0x5f51, 0x5f54, 0x5f59, 0x5f5a, 0x5f5b, 0x5f5c, 0x5f5e, 0x5f5f, 0x5f60, 0x5f63, 0x5f65, 0x5f67, 0x5f68, 0x5f6b,
+    0x5f6e, 0x5f6f, 0x5f72, 0x5f74, 0x5f75, 0x5f76, 0x5f78, 0x5f7a, 0x5f7d, 0x5f7e, 0x5f7f, 0x5f83, 0x5f86, 0x5f8d, 0x5f8e, 0x5f8f,
+    0x5f91, 0x5f93, 0x5f94, 0x5f96, 0x5f9a, 0x5f9b, 0x5f9d, 0x5f9e, 0x5f9f, 0x5fa0, 0x5fa2, 0x5fa3, 0x5fa4, 0x5fa5, 0x5fa6, 0x5fa7,
+    0x5fa9, 0x5fab, 0x5fac, 0x5faf, 0x5fb0, 0x5fb1, 0x5fb2, 0x5fb3, 0x5fb4, 0x5fb6, 0x5fb8, 0x5fb9, 0x5fba, 0x5fbb, 0x5fbe, 0x5fbf,
+    0x5fc0, 0x5fc1, 0x5fc2, 0x5fc7, 0x5fc8, 0x5fca, 0x5fcb, 0x5fce, 0x5fd3, 0x5fd4, 0x5fd5, 0x5fda, 0x5fdb, 0x5fdc, 0x5fde, 0x5fdf,
+    0x5fe2, 0x5fe3, 0x5fe5, 0x5fe6, 0x5fe8, 0x5fe9, 0x5fec, 0x5fef, 0x5ff0, 0x5ff2, 0x5ff3, 0x5ff4, 0x5ff6, 0x5ff7, 0x5ff9, 0x5ffa,
+    0x5ffc, 0x6007, 0x6008, 0x6009, 0x600b, 0x600c, 0x6010, 0x6011, 0x6013, 0x6017, 0x6018, 0x601a, 0x601e, 0x601f, 0x6022, 0x6023,
+    0x6024, 0x602c, 0x602d, 0x602e, 0x6030, 0x6031, 0x6032, 0x6033, 0x6034, 0x6036, 0x6037, 0x6038, 0x6039, 0x603a, 0x603d, 0x603e,
+    0x6040, 0x6044, 0x6045, 0x6046, 0x6047, 0x6048, 0x6049, 0x604a, 0x604c, 0x604e, 0x604f, 0x6051, 0x6053, 0x6054, 0x6056, 0x6057,
+    0x6058, 0x605b, 0x605c, 0x605e, 0x605f, 0x6060, 0x6061, 0x6065, 0x6066, 0x606e, 0x6071, 0x6072, 0x6074, 0x6075, 0x6077, 0x607e,
+    0x6080, 0x6081, 0x6082, 0x6085, 0x6086, 0x6087, 0x6088, 0x608a, 0x608b, 0x608e, 0x608f, 0x6090, 0x6091, 0x6093, 0x6095, 0x6097,
+    0x6098, 0x6099, 0x609c, 0x609e, 0x60a1, 0x60a2, 0x60a4, 0x60a5, 0x60a7, 0x60a9, 0x60aa, 0x60ae, 0x60b0, 0x60b3, 0x60b5, 0x60b6,
+    0x60b7, 0x60b9, 0x60ba, 0x60bd, 0x60be, 0x60bf, 0x60c0, 0x60c1, 0x60c2, 0x60c3, 0x60c4, 0x60c7, 0x60c8, 0x60c9, 0x60cc, 0x60cd,
+    0x60ce, 0x60cf, 0x60d0, 0x60d2, 0x60d3, 0x60d4, 0x60d6, 0x60d7, 0x60d9, 0x60db, 0x60de, 0x60e1, 0x60e2, 0x60e3, 0x60e4, 0x60e5,
+    0x60ea, 0x60f1, 0x60f2, 0x60f5, 0x60f7, 0x60f8, 0x60fb, 0x60fc, 0x60fd, 0x60fe, 0x60ff, 0x6102, 0x6103, 0x6104, 0x6105, 0x6107,
+    0x610a, 0x610b, 0x610c, 0x6110, 0x6111, 0x6112, 0x6113, 0x6114, 0x6116, 0x6117, 0x6118, 0x6119, 0x611b, 0x611c, 0x611d, 0x611e,
+    0x6121, 0x6122, 0x6125, 0x6128, 0x6129, 0x612a, 0x612c, 0x612d, 0x612e, 0x612f, 0x6130, 0x6131, 0x6132, 0x6133, 0x6134, 0x6135,
+    0x6136, 0x6137, 0x6138, 0x6139, 0x613a, 0x613b, 0x613c, 0x613d, 0x613e, 0x6140, 0x6141, 0x6142, 0x6143, 0x6144, 0x6145, 0x6146,
+    0x6147, 0x6149, 0x614b, 0x614d, 0x614f, 0x6150, 0x6152, 0x6153, 0x6154, 0x6156, 0x6157, 0x6158, 0x6159, 0x615a, 0x615b, 0x615c,
+    0x615e, 0x615f, 0x6160, 0x6161, 0x6163, 0x6164, 0x6165, 0x6166, 0x6169, 0x616a, 0x616b, 0x616c, 0x616d, 0x616e, 0x616f, 0x6171,
+    0x6172, 0x6173, 0x6174, 0x6176, 0x6178, 0x6179, 0x617a, 0x617b, 0x617c, 0x617d, 0x617e, 0x617f, 0x6180, 0x6181, 0x6182, 0x6183,
+    0x6184, 0x6185, 0x6186, 0x6187, 0x6188, 0x6189, 0x618a, 0x618c, 0x618d, 0x618f, 0x6190, 0x6191, 0x6192, 0x6193, 0x6195, 0x6196,
+    0x6197, 0x6198, 0x6199, 0x619a, 0x619b, 0x619c, 0x619e, 0x619f, 0x61a0, 0x61a1, 0x61a2, 0x61a3, 0x61a4, 0x61a5, 0x61a6, 0x61aa,
+    0x61ab, 0x61ad, 0x61ae, 0x61af, 0x61b0, 0x61b1, 0x61b2, 0x61b3, 0x61b4, 0x61b5, 0x61b6, 0x61b8, 0x61b9, 0x61ba, 0x61bb, 0x61bc,
+    0x61bd, 0x61bf, 0x61c0, 0x61c1, 0x61c3, 0x61c4, 0x61c5, 0x61c6, 0x61c7, 0x61c9, 0x61cc, 0x61cd, 0x61ce, 0x61cf, 0x61d0, 0x61d3,
+    0x61d5, 0x61d6, 0x61d7, 0x61d8, 0x61d9, 0x61da, 0x61db, 0x61dc, 0x61dd, 0x61de, 0x61df, 0x61e0, 0x61e1, 0x61e2, 0x61e3, 0x61e4,
+    0x61e5, 0x61e7, 0x61e8, 0x61e9, 0x61ea, 0x61eb, 0x61ec, 0x61ed, 0x61ee, 0x61ef, 0x61f0, 0x61f1, 0x61f2, 0x61f3, 0x61f4, 0x61f6,
+    0x61f7, 0x61f8, 0x61f9, 0x61fa, 0x61fb, 0x61fc, 0x61fd, 0x61fe, 0x6200, 0x6201, 0x6202, 0x6203, 0x6204, 0x6205, 0x6207, 0x6209,
+    0x6213, 0x6214, 0x6219, 0x621c, 0x621d, 0x621e, 0x6220, 0x6223, 0x6226, 0x6227, 0x6228, 0x6229, 0x622b, 0x622d, 0x622f, 0x6230,
+    0x6231, 0x6232, 0x6235, 0x6236, 0x6238, 0x6239, 0x623a, 0x623b, 0x623c, 0x6242, 0x6244, 0x6245, 0x6246, 0x624a, 0x624f, 0x6250,
+    0x6255, 0x6256, 0x6257, 0x6259, 0x625a, 0x625c, 0x625d, 0x625e, 0x625f, 0x6260, 0x6261, 0x6262, 0x6264, 0x6265, 0x6268, 0x6271,
+    0x6272, 0x6274, 0x6275, 0x6277, 0x6278, 0x627a, 0x627b, 0x627d, 0x6281, 0x6282, 0x6283, 0x6285, 0x6286, 0x6287, 0x6288, 0x628b,
+    0x628c, 0x628d, 0x628e, 0x628f, 0x6290, 0x6294, 0x6299, 0x629c, 0x629d, 0x629e, 0x62a3, 0x62a6, 0x62a7, 0x62a9, 0x62aa, 0x62ad,
+    0x62ae, 0x62af, 0x62b0, 0x62b2, 0x62b3, 0x62b4, 0x62b6, 0x62b7, 0x62b8, 0x62ba, 0x62be, 0x62c0, 0x62c1, 0x62c3, 0x62cb, 0x62cf,
+    0x62d1, 0x62d5, 0x62dd, 0x62de, 0x62e0, 0x62e1, 0x62e4, 0x62ea, 0x62eb, 0x62f0, 0x62f2, 0x62f5, 0x62f8, 0x62f9, 0x62fa, 0x62fb,
+    0x6300, 0x6303, 0x6304, 0x6305, 0x6306, 0x630a, 0x630b, 0x630c, 0x630d, 0x630f, 0x6310, 0x6312, 0x6313, 0x6314, 0x6315, 0x6317,
+    0x6318, 0x6319, 0x631c, 0x6326, 0x6327, 0x6329, 0x632c, 0x632d, 0x632e, 0x6330, 0x6331, 0x6333, 0x6334, 0x6335, 0x6336, 0x6337,
+    0x6338, 0x633b, 0x633c, 0x633e, 0x633f, 0x6340, 0x6341, 0x6344, 0x6347, 0x6348, 0x634a, 0x6351, 0x6352, 0x6353, 0x6354, 0x6356,
+    0x6357, 0x6358, 0x6359, 0x635a, 0x635b, 0x635c, 0x635d, 0x6360, 0x6364, 0x6365, 0x6366, 0x6368, 0x636a, 0x636b, 0x636c, 0x636f,
+    0x6370, 0x6372, 0x6373, 0x6374, 0x6375, 0x6378, 0x6379, 0x637c, 0x637d, 0x637e, 0x637f, 0x6381, 0x6383, 0x6384, 0x6385, 0x6386,
+    0x638b, 0x638d, 0x6391, 0x6393, 0x6394, 0x6395, 0x6397, 0x6399, 0x639a, 0x639b, 0x639c, 0x639d, 0x639e, 0x639f, 0x63a1, 0x63a4,
+    0x63a6, 0x63ab, 0x63af, 0x63b1, 0x63b2, 0x63b5, 0x63b6, 0x63b9, 0x63bb, 0x63bd, 0x63bf, 0x63c0, 0x63c1, 0x63c2, 0x63c3, 0x63c5,
+    0x63c7, 0x63c8, 0x63ca, 0x63cb, 0x63cc, 0x63d1, 0x63d3, 0x63d4, 0x63d5, 0x63d7, 0x63d8, 0x63d9, 0x63da, 0x63db, 0x63dc, 0x63dd,
+    0x63df, 0x63e2, 0x63e4, 0x63e5, 0x63e6, 0x63e7, 0x63e8, 0x63eb, 0x63ec, 0x63ee, 0x63ef, 0x63f0, 0x63f1, 0x63f3, 0x63f5, 0x63f7,
+    0x63f9, 0x63fa, 0x63fb, 0x63fc, 0x63fe, 0x6403, 0x6404, 0x6406, 0x6407, 0x6408, 0x6409, 0x640a, 0x640d, 0x640e, 0x6411, 0x6412,
+    0x6415, 0x6416, 0x6417, 0x6418, 0x6419, 0x641a, 0x641d, 0x641f, 0x6422, 0x6423, 0x6424, 0x6425, 0x6427, 0x6428, 0x6429, 0x642b,
+    0x642e, 0x642f, 0x6430, 0x6431, 0x6432, 0x6433, 0x6435, 0x6436, 0x6437, 0x6438, 0x6439, 0x643b, 0x643c, 0x643e, 0x6440, 0x6442,
+    0x6443, 0x6449, 0x644b, 0x644c, 0x644d, 0x644e, 0x644f, 0x6450, 0x6451, 0x6453, 0x6455, 0x6456, 0x6457, 0x6459, 0x645a, 0x645b,
+    0x645c, 0x645d, 0x645f, 0x6460, 0x6461, 0x6462, 0x6463, 0x6464, 0x6465, 0x6466, 0x6468, 0x646a, 0x646b, 0x646c, 0x646e, 0x646f,
+    0x6470, 0x6471, 0x6472, 0x6473, 0x6474, 0x6475, 0x6476, 0x6477, 0x647b, 0x647c, 0x647d, 0x647e, 0x647f, 0x6480, 0x6481, 0x6483,
+    0x6486, 0x6488, 0x6489, 0x648a, 0x648b, 0x648c, 0x648d, 0x648e, 0x648f, 0x6490, 0x6493, 0x6494, 0x6497, 0x6498, 0x649a, 0x649b,
+    0x649c, 0x649d, 0x649f, 0x64a0, 0x64a1, 0x64a2, 0x64a3, 0x64a5, 0x64a6, 0x64a7, 0x64a8, 0x64aa, 0x64ab, 0x64af, 0x64b1, 0x64b2,
+    0x64b3, 0x64b4, 0x64b6, 0x64b9, 0x64bb, 0x64bd, 0x64be, 0x64bf, 0x64c1, 0x64c3, 0x64c4, 0x64c6, 0x64c7, 0x64c8, 0x64c9, 0x64ca,
+    0x64cb, 0x64cc, 0x64cf, 0x64d1, 0x64d3, 0x64d4, 0x64d5, 0x64d6, 0x64d9, 0x64da, 0x64db, 0x64dc, 0x64dd, 0x64df, 0x64e0, 0x64e1,
+    0x64e3, 0x64e5, 0x64e7, 0x64e8, 0x64e9, 0x64ea, 0x64eb, 0x64ec, 0x64ed, 0x64ee, 0x64ef, 0x64f0, 0x64f1, 0x64f2, 0x64f3, 0x64f4,
+    0x64f5, 0x64f6, 0x64f7, 0x64f8, 0x64f9, 0x64fa, 0x64fb, 0x64fc, 0x64fd, 0x64fe, 0x64ff, 0x6501, 0x6502, 0x6503, 0x6504, 0x6505,
+    0x6506, 0x6507, 0x6508, 0x650a, 0x650b, 0x650c, 0x650d, 0x650e, 0x650f, 0x6510, 0x6511, 0x6513, 0x6514, 0x6515, 0x6516, 0x6517,
+    0x6519, 0x651a, 0x651b, 0x651c, 0x651d, 0x651e, 0x651f, 0x6520, 0x6521, 0x6522, 0x6523, 0x6524, 0x6526, 0x6527, 0x6528, 0x6529,
+    0x652a, 0x652c, 0x652d, 0x6530, 0x6531, 0x6532, 0x6533, 0x6537, 0x653a, 0x653c, 0x653d, 0x6540, 0x6541, 0x6542, 0x6543, 0x6544,
+    0x6546, 0x6547, 0x654a, 0x654b, 0x654d, 0x654e, 0x6550, 0x6552, 0x6553, 0x6554, 0x6557, 0x6558, 0x655a, 0x655c, 0x655f, 0x6560,
+    0x6561, 0x6564, 0x6565, 0x6567, 0x6568, 0x6569, 0x656a, 0x656d, 0x656e, 0x656f, 0x6571, 0x6573, 0x6575, 0x6576, 0x6578, 0x6579,
+    0x657a, 0x657b, 0x657c, 0x657d, 0x657e, 0x657f, 0x6580, 0x6581, 0x6582, 0x6583, 0x6584, 0x6585, 0x6586, 0x6588, 0x6589, 0x658a,
+    0x658d, 0x658e, 0x658f, 0x6592, 0x6594, 0x6595, 0x6596, 0x6598, 0x659a, 0x659d, 0x659e, 0x65a0, 0x65a2, 0x65a3, 0x65a6, 0x65a8,
+    0x65aa, 0x65ac, 0x65ae, 0x65b1, 0x65b2, 0x65b3, 0x65b4, 0x65b5, 0x65b6, 0x65b7, 0x65b8, 0x65ba, 0x65bb, 0x65be, 0x65bf, 0x65c0,
+    0x65c2, 0x65c7, 0x65c8, 0x65c9, 0x65ca, 0x65cd, 0x65d0, 0x65d1, 0x65d3, 0x65d4, 0x65d5, 0x65d8, 0x65d9, 0x65da, 0x65db, 0x65dc,
+    0x65dd, 0x65de, 0x65df, 0x65e1, 0x65e3, 0x65e4, 0x65ea, 0x65eb, 0x65f2, 0x65f3, 0x65f4, 0x65f5, 0x65f8, 0x65f9, 0x65fb, 0x65fc,
+    0x65fd, 0x65fe, 0x65ff, 0x6601, 0x6604, 0x6605, 0x6607, 0x6608, 0x6609, 0x660b, 0x660d, 0x6610, 0x6611, 0x6612, 0x6616, 0x6617,
+    0x6618, 0x661a, 0x661b, 0x661c, 0x661e, 0x6621, 0x6622, 0x6623, 0x6624, 0x6626, 0x6629, 0x662a, 0x662b, 0x662c, 0x662e, 0x6630,
+    0x6632, 0x6633, 0x6637, 0x6638, 0x6639, 0x663a, 0x663b, 0x663d, 0x663f, 0x6640, 0x6642, 0x6644, 0x6645, 0x6646, 0x6647, 0x6648,
+    0x6649, 0x664a, 0x664d, 0x664e, 0x6650, 0x6651, 0x6658, 0x6659, 0x665b, 0x665c, 0x665d, 0x665e, 0x6660, 0x6662, 0x6663, 0x6665,
+    0x6667, 0x6669, 0x666a, 0x666b, 0x666c, 0x666d, 0x6671, 0x6672, 0x6673, 0x6675, 0x6678, 0x6679, 0x667b, 0x667c, 0x667d, 0x667f,
+    0x6680, 0x6681, 0x6683, 0x6685, 0x6686, 0x6688, 0x6689, 0x668a, 0x668b, 0x668d, 0x668e, 0x668f, 0x6690, 0x6692, 0x6693, 0x6694,
+    0x6695, 0x6698, 0x6699, 0x669a, 0x669b, 0x669c, 0x669e, 0x669f, 0x66a0, 0x66a1, 0x66a2, 0x66a3, 0x66a4, 0x66a5, 0x66a6, 0x66a9,
+    0x66aa, 0x66ab, 0x66ac, 0x66ad, 0x66af, 0x66b0, 0x66b1, 0x66b2, 0x66b3, 0x66b5, 0x66b6, 0x66b7, 0x66b8, 0x66ba, 0x66bb, 0x66bc,
+    0x66bd, 0x66bf, 0x66c0, 0x66c1, 0x66c2, 0x66c3, 0x66c4, 0x66c5, 0x66c6, 0x66c7, 0x66c8, 0x66c9, 0x66ca, 0x66cb, 0x66cc, 0x66cd,
+    0x66ce, 0x66cf, 0x66d0, 0x66d1, 0x66d2, 0x66d3, 0x66d4, 0x66d5, 0x66d6, 0x66d7, 0x66d8, 0x66da, 0x66de, 0x66df, 0x66e0, 0x66e1,
+    0x66e2, 0x66e3, 0x66e4, 0x66e5, 0x66e7, 0x66e8, 0x66ea, 0x66eb, 0x66ec, 0x66ed, 0x66ee, 0x66ef, 0x66f1, 0x66f5, 0x66f6, 0x66f8,
+    0x66fa, 0x66fb, 0x66fd, 0x6701, 0x6702, 0x6703, 0x6704, 0x6705, 0x6706, 0x6707, 0x670c, 0x670e, 0x670f, 0x6711, 0x6712, 0x6713,
+    0x6716, 0x6718, 0x6719, 0x671a, 0x671c, 0x671e, 0x6720, 0x6721, 0x6722, 0x6723, 0x6724, 0x6725, 0x6727, 0x6729, 0x672e, 0x6730,
+    0x6732, 0x6733, 0x6736, 0x6737, 0x6738, 0x6739, 0x673b, 0x673c, 0x673e, 0x673f, 0x6741, 0x6744, 0x6745, 0x6747, 0x674a, 0x674b,
+    0x674d, 0x6752, 0x6754, 0x6755, 0x6757, 0x6758, 0x6759, 0x675a, 0x675b, 0x675d, 0x6762, 0x6763, 0x6764, 0x6766, 0x6767, 0x676b,
+    0x676c, 0x676e, 0x6771, 0x6774, 0x6776, 0x6778, 0x6779, 0x677a, 0x677b, 0x677d, 0x6780, 0x6782, 0x6783, 0x6785, 0x6786, 0x6788,
+    0x678a, 0x678c, 0x678d, 0x678e, 0x678f, 0x6791, 0x6792, 0x6793, 0x6794, 0x6796, 0x6799, 0x679b, 0x679f, 0x67a0, 0x67a1, 0x67a4,
+    0x67a6, 0x67a9, 0x67ac, 0x67ae, 0x67b1, 0x67b2, 0x67b4, 0x67b9, 0x67ba, 0x67bb, 0x67bc, 0x67bd, 0x67be, 0x67bf, 0x67c0, 0x67c2,
+    0x67c5, 0x67c6, 0x67c7, 0x67c8, 0x67c9, 0x67ca, 0x67cb, 0x67cc, 0x67cd, 0x67ce, 0x67d5, 0x67d6, 0x67d7, 0x67db, 0x67df, 0x67e1,
+    0x67e3, 0x67e4, 0x67e6, 0x67e7, 0x67e8, 0x67ea, 0x67eb, 0x67ed, 0x67ee, 0x67f2, 0x67f5, 0x67f6, 0x67f7, 0x67f8, 0x67f9, 0x67fa,
+    0x67fb, 0x67fc, 0x67fe, 0x6801, 0x6802, 0x6803, 0x6804, 0x6806, 0x680d, 0x6810, 0x6812, 0x6814, 0x6815, 0x6818, 0x6819, 0x681a,
+    0x681b, 0x681c, 0x681e, 0x681f, 0x6820, 0x6822, 0x6823, 0x6824, 0x6825, 0x6826, 0x6827, 0x6828, 0x682b, 0x682c, 0x682d, 0x682e,
+    0x682f, 0x6830, 0x6831, 0x6834, 0x6835, 0x6836, 0x683a, 0x683b, 0x683f, 0x6847, 0x684b, 0x684d, 0x684f, 0x6852, 0x6856, 0x6857,
+    0x6858, 0x6859, 0x685a, 0x685b, 0x685c, 0x685d, 0x685e, 0x685f, 0x686a, 0x686c, 0x686d, 0x686e, 0x686f, 0x6870, 0x6871, 0x6872,
+    0x6873, 0x6875, 0x6878, 0x6879, 0x687a, 0x687b, 0x687c, 0x687d, 0x687e, 0x687f, 0x6880, 0x6882, 0x6884, 0x6887, 0x6888, 0x6889,
+    0x688a, 0x688b, 0x688c, 0x688d, 0x688e, 0x6890, 0x6891, 0x6892, 0x6894, 0x6895, 0x6896, 0x6898, 0x6899, 0x689a, 0x689b, 0x689c,
+    0x689d, 0x689e, 0x689f, 0x68a0, 0x68a1, 0x68a3, 0x68a4, 0x68a5, 0x68a9, 0x68aa, 0x68ab, 0x68ac, 0x68ae, 0x68b1, 0x68b2, 0x68b4,
+    0x68b6, 0x68b7, 0x68b8, 0x68b9, 0x68ba, 0x68bb, 0x68bc, 0x68bd, 0x68be, 0x68bf, 0x68c1, 0x68c3, 0x68c4, 0x68c5, 0x68c6, 0x68c7,
+    0x68c8, 0x68ca, 0x68cc, 0x68ce, 0x68cf, 0x68d0, 0x68d1, 0x68d3, 0x68d4, 0x68d6, 0x68d7, 0x68d9, 0x68db, 0x68dc, 0x68dd, 0x68de,
+    0x68df, 0x68e1, 0x68e2, 0x68e4, 0x68e5, 0x68e6, 0x68e7, 0x68e8, 0x68e9, 0x68ea, 0x68eb, 0x68ec, 0x68ed, 0x68ef, 0x68f2, 0x68f3,
+    0x68f4, 0x68f6, 0x68f7, 0x68f8, 0x68fb, 0x68fd, 0x68fe, 0x68ff, 0x6900, 0x6902, 0x6903, 0x6904, 0x6906, 0x6907, 0x6908, 0x6909,
+    0x690a, 0x690c, 0x690f, 0x6911, 0x6913, 0x6914, 0x6915, 0x6916, 0x6917, 0x6918, 0x6919, 0x691a, 0x691b, 0x691c, 0x691d, 0x691e,
+    0x6921, 0x6922, 0x6923, 0x6925, 0x6926, 0x6927, 0x6928, 0x6929, 0x692a, 0x692b, 0x692c, 0x692e, 0x692f, 0x6931, 0x6932, 0x6933,
+    0x6935, 0x6936, 0x6937, 0x6938, 0x693a, 0x693b, 0x693c, 0x693e, 0x6940, 0x6941, 0x6943, 0x6944, 0x6945, 0x6946, 0x6947, 0x6948,
+    0x6949, 0x694a, 0x694b, 0x694c, 0x694d, 0x694e, 0x694f, 0x6950, 0x6951, 0x6952, 0x6953, 0x6955, 0x6956, 0x6958, 0x6959, 0x695b,
+    0x695c, 0x695f, 0x6961, 0x6962, 0x6964, 0x6965, 0x6967, 0x6968, 0x6969, 0x696a, 0x696c, 0x696d, 0x696f, 0x6970, 0x6972, 0x6973,
+    0x6974, 0x6975, 0x6976, 0x697a, 0x697b, 0x697d, 0x697e, 0x697f, 0x6981, 0x6983, 0x6985, 0x698a, 0x698b, 0x698c, 0x698e, 0x698f,
+    0x6990, 0x6991, 0x6992, 0x6993, 0x6996, 0x6997, 0x6999, 0x699a, 0x699d, 0x699e, 0x699f, 0x69a0, 0x69a1, 0x69a2, 0x69a3, 0x69a4,
+    0x69a5, 0x69a6, 0x69a9, 0x69aa, 0x69ac, 0x69ae, 0x69af, 0x69b0, 0x69b2, 0x69b3, 0x69b5, 0x69b6, 0x69b8, 0x69b9, 0x69ba, 0x69bc,
+    0x69bd, 0x69be, 0x69bf, 0x69c0, 0x69c2, 0x69c3, 0x69c4, 0x69c5, 0x69c6, 0x69c7, 0x69c8, 0x69c9, 0x69cb, 0x69cd, 0x69cf, 0x69d1,
+    0x69d2, 0x69d3, 0x69d5, 0x69d6, 0x69d7, 0x69d8, 0x69d9, 0x69da, 0x69dc, 0x69dd, 0x69de, 0x69e1, 0x69e2, 0x69e3, 0x69e4, 0x69e5,
+    0x69e6, 0x69e7, 0x69e8, 0x69e9, 0x69ea, 0x69eb, 0x69ec, 0x69ee, 0x69ef, 0x69f0, 0x69f1, 0x69f3, 0x69f4, 0x69f5, 0x69f6, 0x69f7,
+    0x69f8, 0x69f9, 0x69fa, 0x69fb, 0x69fc, 0x69fe, 0x6a00, 0x6a01, 0x6a02, 0x6a03, 0x6a04, 0x6a05, 0x6a06, 0x6a07, 0x6a08, 0x6a09,
+    0x6a0b, 0x6a0c, 0x6a0d, 0x6a0e, 0x6a0f, 0x6a10, 0x6a11, 0x6a12, 0x6a13, 0x6a14, 0x6a15, 0x6a16, 0x6a19, 0x6a1a, 0x6a1b, 0x6a1c,
+    0x6a1d, 0x6a1e, 0x6a20, 0x6a22, 0x6a23, 0x6a24, 0x6a25, 0x6a26, 0x6a27, 0x6a29, 0x6a2b, 0x6a2c, 0x6a2d, 0x6a2e, 0x6a30, 0x6a32,
+    0x6a33, 0x6a34, 0x6a36, 0x6a37, 0x6a38, 0x6a39, 0x6a3a, 0x6a3b, 0x6a3c, 0x6a3f, 0x6a40, 0x6a41, 0x6a42, 0x6a43, 0x6a45, 0x6a46,
+    0x6a48, 0x6a49, 0x6a4a, 0x6a4b, 0x6a4c, 0x6a4d, 0x6a4e, 0x6a4f, 0x6a51, 0x6a52, 0x6a53, 0x6a54, 0x6a55, 0x6a56, 0x6a57, 0x6a5a,
+    0x6a5c, 0x6a5d, 0x6a5e, 0x6a5f, 0x6a60, 0x6a62, 0x6a63, 0x6a64, 0x6a66, 0x6a67, 0x6a68, 0x6a69, 0x6a6a, 0x6a6b, 0x6a6c, 0x6a6d,
+    0x6a6e, 0x6a6f, 0x6a70, 0x6a72, 0x6a73, 0x6a74, 0x6a75, 0x6a76, 0x6a77, 0x6a78, 0x6a7a, 0x6a7b, 0x6a7d, 0x6a7e, 0x6a7f, 0x6a81,
+    0x6a82, 0x6a83, 0x6a85, 0x6a86, 0x6a87, 0x6a88, 0x6a89, 0x6a8a, 0x6a8b, 0x6a8c, 0x6a8d, 0x6a8f, 0x6a92, 0x6a93, 0x6a94, 0x6a95,
+    0x6a96, 0x6a98, 0x6a99, 0x6a9a, 0x6a9b, 0x6a9c, 0x6a9d, 0x6a9e, 0x6a9f, 0x6aa1, 0x6aa2, 0x6aa3, 0x6aa4, 0x6aa5, 0x6aa6, 0x6aa7,
+    0x6aa8, 0x6aaa, 0x6aad, 0x6aae, 0x6aaf, 0x6ab0, 0x6ab1, 0x6ab2, 0x6ab3, 0x6ab4, 0x6ab5, 0x6ab6, 0x6ab7, 0x6ab8, 0x6ab9, 0x6aba,
+    0x6abb, 0x6abc, 0x6abd, 0x6abe, 0x6abf, 0x6ac0, 0x6ac1, 0x6ac2, 0x6ac3, 0x6ac4, 0x6ac5, 0x6ac6, 0x6ac7, 0x6ac8, 0x6ac9, 0x6aca,
+    0x6acb, 0x6acc, 0x6acd, 0x6ace, 0x6acf, 0x6ad0, 0x6ad1, 0x6ad2, 0x6ad3, 0x6ad4, 0x6ad5, 0x6ad6, 0x6ad7, 0x6ad8, 0x6ad9, 0x6ada,
+    0x6adb, 0x6adc, 0x6add, 0x6ade, 0x6adf, 0x6ae0, 0x6ae1, 0x6ae2, 0x6ae3, 0x6ae4, 0x6ae5, 0x6ae6, 0x6ae7, 0x6ae8, 0x6ae9, 0x6aea,
+    0x6aeb, 0x6aec, 0x6aed, 0x6aee, 0x6aef, 0x6af0, 0x6af1, 0x6af2, 0x6af3, 0x6af4, 0x6af5, 0x6af6, 0x6af7, 0x6af8, 0x6af9, 0x6afa,
+    0x6afb, 0x6afc, 0x6afd, 0x6afe, 0x6aff, 0x6b00, 0x6b01, 0x6b02, 0x6b03, 0x6b04, 0x6b05, 0x6b06, 0x6b07, 0x6b08, 0x6b09, 0x6b0a,
+    0x6b0b, 0x6b0c, 0x6b0d, 0x6b0e, 0x6b0f, 0x6b10, 0x6b11, 0x6b12, 0x6b13, 0x6b14, 0x6b15, 0x6b16, 0x6b17, 0x6b18, 0x6b19, 0x6b1a,
+    0x6b1b, 0x6b1c, 0x6b1d, 0x6b1e, 0x6b1f, 0x6b25, 0x6b26, 0x6b28, 0x6b29, 0x6b2a, 0x6b2b, 0x6b2c, 0x6b2d, 0x6b2e, 0x6b2f, 0x6b30,
+    0x6b31, 0x6b33, 0x6b34, 0x6b35, 0x6b36, 0x6b38, 0x6b3b, 0x6b3c, 0x6b3d, 0x6b3f, 0x6b40, 0x6b41, 0x6b42, 0x6b44, 0x6b45, 0x6b48,
+    0x6b4a, 0x6b4b, 0x6b4d, 0x6b4e, 0x6b4f, 0x6b50, 0x6b51, 0x6b52, 0x6b53, 0x6b54, 0x6b55, 0x6b56, 0x6b57, 0x6b58, 0x6b5a, 0x6b5b,
+    0x6b5c, 0x6b5d, 0x6b5e, 0x6b5f, 0x6b60, 0x6b61, 0x6b68, 0x6b69, 0x6b6b, 0x6b6c, 0x6b6d, 0x6b6e, 0x6b6f, 0x6b70, 0x6b71, 0x6b72,
+    0x6b73, 0x6b74, 0x6b75, 0x6b76, 0x6b77, 0x6b78, 0x6b7a, 0x6b7d, 0x6b7e, 0x6b7f, 0x6b80, 0x6b85, 0x6b88, 0x6b8c, 0x6b8e, 0x6b8f,
+    0x6b90, 0x6b91, 0x6b94, 0x6b95, 0x6b97, 0x6b98, 0x6b99, 0x6b9c, 0x6b9d, 0x6b9e, 0x6b9f, 0x6ba0, 0x6ba2, 0x6ba3, 0x6ba4, 0x6ba5,
+    0x6ba6, 0x6ba7, 0x6ba8, 0x6ba9, 0x6bab, 0x6bac, 0x6bad, 0x6bae, 0x6baf, 0x6bb0, 0x6bb1, 0x6bb2, 0x6bb6, 0x6bb8, 0x6bb9, 0x6bba,
+    0x6bbb, 0x6bbc, 0x6bbd, 0x6bbe, 0x6bc0, 0x6bc3, 0x6bc4, 0x6bc6, 0x6bc7, 0x6bc8, 0x6bc9, 0x6bca, 0x6bcc, 0x6bce, 0x6bd0, 0x6bd1,
+    0x6bd8, 0x6bda, 0x6bdc, 0x6bdd, 0x6bde, 0x6bdf, 0x6be0, 0x6be2, 0x6be3, 0x6be4, 0x6be5, 0x6be6, 0x6be7, 0x6be8, 0x6be9, 0x6bec,
+    0x6bed, 0x6bee, 0x6bf0, 0x6bf1, 0x6bf2, 0x6bf4, 0x6bf6, 0x6bf7, 0x6bf8, 0x6bfa, 0x6bfb, 0x6bfc, 0x6bfe, 0x6bff, 0x6c00, 0x6c01,
+    0x6c02, 0x6c03, 0x6c04, 0x6c08, 0x6c09, 0x6c0a, 0x6c0b, 0x6c0c, 0x6c0e, 0x6c12, 0x6c17, 0x6c1c, 0x6c1d, 0x6c1e, 0x6c20, 0x6c23,
+    0x6c25, 0x6c2b, 0x6c2c, 0x6c2d, 0x6c31, 0x6c33, 0x6c36, 0x6c37, 0x6c39, 0x6c3a, 0x6c3b, 0x6c3c, 0x6c3e, 0x6c3f, 0x6c43, 0x6c44,
+    0x6c45, 0x6c48, 0x6c4b, 0x6c4c, 0x6c4d, 0x6c4e, 0x6c4f, 0x6c51, 0x6c52, 0x6c53, 0x6c56, 0x6c58, 0x6c59, 0x6c5a, 0x6c62, 0x6c63,
+    0x6c65, 0x6c66, 0x6c67, 0x6c6b, 0x6c6c, 0x6c6d, 0x6c6e, 0x6c6f, 0x6c71, 0x6c73, 0x6c75, 0x6c77, 0x6c78, 0x6c7a, 0x6c7b, 0x6c7c,
+    0x6c7f, 0x6c80, 0x6c84, 0x6c87, 0x6c8a, 0x6c8b, 0x6c8d, 0x6c8e, 0x6c91, 0x6c92, 0x6c95, 0x6c96, 0x6c97, 0x6c98, 0x6c9a, 0x6c9c,
+    0x6c9d, 0x6c9e, 0x6ca0, 0x6ca2, 0x6ca8, 0x6cac, 0x6caf, 0x6cb0, 0x6cb4, 0x6cb5, 0x6cb6, 0x6cb7, 0x6cba, 0x6cc0, 0x6cc1, 0x6cc2,
+    0x6cc3, 0x6cc6, 0x6cc7, 0x6cc8, 0x6ccb, 0x6ccd, 0x6cce, 0x6ccf, 0x6cd1, 0x6cd2, 0x6cd8, 0x6cd9, 0x6cda, 0x6cdc, 0x6cdd, 0x6cdf,
+    0x6ce4, 0x6ce6, 0x6ce7, 0x6ce9, 0x6cec, 0x6ced, 0x6cf2, 0x6cf4, 0x6cf9, 0x6cff, 0x6d00, 0x6d02, 0x6d03, 0x6d05, 0x6d06, 0x6d08,
+    0x6d09, 0x6d0a, 0x6d0d, 0x6d0f, 0x6d10, 0x6d11, 0x6d13, 0x6d14, 0x6d15, 0x6d16, 0x6d18, 0x6d1c, 0x6d1d, 0x6d1f, 0x6d20, 0x6d21,
+    0x6d22, 0x6d23, 0x6d24, 0x6d26, 0x6d28, 0x6d29, 0x6d2c, 0x6d2d, 0x6d2f, 0x6d30, 0x6d34, 0x6d36, 0x6d37, 0x6d38, 0x6d3a, 0x6d3f,
+    0x6d40, 0x6d42, 0x6d44, 0x6d49, 0x6d4c, 0x6d50, 0x6d55, 0x6d56, 0x6d57, 0x6d58, 0x6d5b, 0x6d5d, 0x6d5f, 0x6d61, 0x6d62, 0x6d64,
+    0x6d65, 0x6d67, 0x6d68, 0x6d6b, 0x6d6c, 0x6d6d, 0x6d70, 0x6d71, 0x6d72, 0x6d73, 0x6d75, 0x6d76, 0x6d79, 0x6d7a, 0x6d7b, 0x6d7d,
+    0x6d7e, 0x6d7f, 0x6d80, 0x6d81, 0x6d83, 0x6d84, 0x6d86, 0x6d87, 0x6d8a, 0x6d8b, 0x6d8d, 0x6d8f, 0x6d90, 0x6d92, 0x6d96, 0x6d97,
+    0x6d98, 0x6d99, 0x6d9a, 0x6d9c, 0x6da2, 0x6da5, 0x6dac, 0x6dad, 0x6db0, 0x6db1, 0x6db3, 0x6db4, 0x6db6, 0x6db7, 0x6db9, 0x6dba,
+    0x6dbb, 0x6dbc, 0x6dbd, 0x6dbe, 0x6dc1, 0x6dc2, 0x6dc3, 0x6dc8, 0x6dc9, 0x6dca, 0x6dcd, 0x6dce, 0x6dcf, 0x6dd0, 0x6dd2, 0x6dd3,
+    0x6dd4, 0x6dd5, 0x6dd7, 0x6dda, 0x6ddb, 0x6ddc, 0x6ddf, 0x6de2, 0x6de3, 0x6de5, 0x6de7, 0x6de8, 0x6de9, 0x6dea, 0x6ded, 0x6def,
+    0x6df0, 0x6df2, 0x6df4, 0x6df5, 0x6df6, 0x6df8, 0x6dfa, 0x6dfd, 0x6dfe, 0x6dff, 0x6e00, 0x6e01, 0x6e02, 0x6e03, 0x6e04, 0x6e06,
+    0x6e07, 0x6e08, 0x6e09, 0x6e0b, 0x6e0f, 0x6e12, 0x6e13, 0x6e15, 0x6e18, 0x6e19, 0x6e1b, 0x6e1c, 0x6e1e, 0x6e1f, 0x6e22, 0x6e26,
+    0x6e27, 0x6e28, 0x6e2a, 0x6e2c, 0x6e2e, 0x6e30, 0x6e31, 0x6e33, 0x6e35, 0x6e36, 0x6e37, 0x6e39, 0x6e3b, 0x6e3c, 0x6e3d, 0x6e3e,
+    0x6e3f, 0x6e40, 0x6e41, 0x6e42, 0x6e45, 0x6e46, 0x6e47, 0x6e48, 0x6e49, 0x6e4a, 0x6e4b, 0x6e4c, 0x6e4f, 0x6e50, 0x6e51, 0x6e52,
+    0x6e55, 0x6e57, 0x6e59, 0x6e5a, 0x6e5c, 0x6e5d, 0x6e5e, 0x6e60, 0x6e61, 0x6e62, 0x6e63, 0x6e64, 0x6e65, 0x6e66, 0x6e67, 0x6e68,
+    0x6e69, 0x6e6a, 0x6e6c, 0x6e6d, 0x6e6f, 0x6e70, 0x6e71, 0x6e72, 0x6e73, 0x6e74, 0x6e75, 0x6e76, 0x6e77, 0x6e78, 0x6e79, 0x6e7a,
+    0x6e7b, 0x6e7c, 0x6e7d, 0x6e80, 0x6e81, 0x6e82, 0x6e84, 0x6e87, 0x6e88, 0x6e8a, 0x6e8b, 0x6e8c, 0x6e8d, 0x6e8e, 0x6e91, 0x6e92,
+    0x6e93, 0x6e94, 0x6e95, 0x6e96, 0x6e97, 0x6e99, 0x6e9a, 0x6e9b, 0x6e9d, 0x6e9e, 0x6ea0, 0x6ea1, 0x6ea3, 0x6ea4, 0x6ea6, 0x6ea8,
+    0x6ea9, 0x6eab, 0x6eac, 0x6ead, 0x6eae, 0x6eb0, 0x6eb3, 0x6eb5, 0x6eb8, 0x6eb9, 0x6ebc, 0x6ebe, 0x6ebf, 0x6ec0, 0x6ec3, 0x6ec4,
+    0x6ec5, 0x6ec6, 0x6ec8, 0x6ec9, 0x6eca, 0x6ecc, 0x6ecd, 0x6ece, 0x6ed0, 0x6ed2, 0x6ed6, 0x6ed8, 0x6ed9, 0x6edb, 0x6edc, 0x6edd,
+    0x6ee3, 0x6ee7, 0x6eea, 0x6eeb, 0x6eec, 0x6eed, 0x6eee, 0x6eef, 0x6ef0, 0x6ef1, 0x6ef2, 0x6ef3, 0x6ef5, 0x6ef6, 0x6ef7, 0x6ef8,
+    0x6efa, 0x6efb, 0x6efc, 0x6efd, 0x6efe, 0x6eff, 0x6f00, 0x6f01, 0x6f03, 0x6f04, 0x6f05, 0x6f07, 0x6f08, 0x6f0a, 0x6f0b, 0x6f0c,
+    0x6f0d, 0x6f0e, 0x6f10, 0x6f11, 0x6f12, 0x6f16, 0x6f17, 0x6f18, 0x6f19, 0x6f1a, 0x6f1b, 0x6f1c, 0x6f1d, 0x6f1e, 0x6f1f, 0x6f21,
+    0x6f22, 0x6f23, 0x6f25, 0x6f26, 0x6f27, 0x6f28, 0x6f2c, 0x6f2e, 0x6f30, 0x6f32, 0x6f34, 0x6f35, 0x6f37, 0x6f38, 0x6f39, 0x6f3a,
+    0x6f3b, 0x6f3c, 0x6f3d, 0x6f3f, 0x6f40, 0x6f41, 0x6f42, 0x6f43, 0x6f44, 0x6f45, 0x6f48, 0x6f49, 0x6f4a, 0x6f4c, 0x6f4e, 0x6f4f,
+    0x6f50, 0x6f51, 0x6f52, 0x6f53, 0x6f54, 0x6f55, 0x6f56, 0x6f57, 0x6f59, 0x6f5a, 0x6f5b, 0x6f5d, 0x6f5f, 0x6f60, 0x6f61, 0x6f63,
+    0x6f64, 0x6f65, 0x6f67, 0x6f68, 0x6f69, 0x6f6a, 0x6f6b, 0x6f6c, 0x6f6f, 0x6f70, 0x6f71, 0x6f73, 0x6f75, 0x6f76, 0x6f77, 0x6f79,
+    0x6f7b, 0x6f7d, 0x6f7e, 0x6f7f, 0x6f80, 0x6f81, 0x6f82, 0x6f83, 0x6f85, 0x6f86, 0x6f87, 0x6f8a, 0x6f8b, 0x6f8f, 0x6f90, 0x6f91,
+    0x6f92, 0x6f93, 0x6f94, 0x6f95, 0x6f96, 0x6f97, 0x6f98, 0x6f99, 0x6f9a, 0x6f9b, 0x6f9d, 0x6f9e, 0x6f9f, 0x6fa0, 0x6fa2, 0x6fa3,
+    0x6fa4, 0x6fa5, 0x6fa6, 0x6fa8, 0x6fa9, 0x6faa, 0x6fab, 0x6fac, 0x6fad, 0x6fae, 0x6faf, 0x6fb0, 0x6fb1, 0x6fb2, 0x6fb4, 0x6fb5,
+    0x6fb7, 0x6fb8, 0x6fba, 0x6fbb, 0x6fbc, 0x6fbd, 0x6fbe, 0x6fbf, 0x6fc1, 0x6fc3, 0x6fc4, 0x6fc5, 0x6fc6, 0x6fc7, 0x6fc8, 0x6fca,
+    0x6fcb, 0x6fcc, 0x6fcd, 0x6fce, 0x6fcf, 0x6fd0, 0x6fd3, 0x6fd4, 0x6fd5, 0x6fd6, 0x6fd7, 0x6fd8, 0x6fd9, 0x6fda, 0x6fdb, 0x6fdc,
+    0x6fdd, 0x6fdf, 0x6fe2, 0x6fe3, 0x6fe4, 0x6fe5, 0x6fe6, 0x6fe7, 0x6fe8, 0x6fe9, 0x6fea, 0x6feb, 0x6fec, 0x6fed, 0x6ff0, 0x6ff1,
+    0x6ff2, 0x6ff3, 0x6ff4, 0x6ff5, 0x6ff6, 0x6ff7, 0x6ff8, 0x6ff9, 0x6ffa, 0x6ffb, 0x6ffc, 0x6ffd, 0x6ffe, 0x6fff, 0x7000, 0x7001,
+    0x7002, 0x7003, 0x7004, 0x7005, 0x7006, 0x7007, 0x7008, 0x7009, 0x700a, 0x700b, 0x700c, 0x700d, 0x700e, 0x700f, 0x7010, 0x7012,
+    0x7013, 0x7014, 0x7015, 0x7016, 0x7017, 0x7018, 0x7019, 0x701c, 0x701d, 0x701e, 0x701f, 0x7020, 0x7021, 0x7022, 0x7024, 0x7025,
+    0x7026, 0x7027, 0x7028, 0x7029, 0x702a, 0x702b, 0x702c, 0x702d, 0x702e, 0x702f, 0x7030, 0x7031, 0x7032, 0x7033, 0x7034, 0x7036,
+    0x7037, 0x7038, 0x703a, 0x703b, 0x703c, 0x703d, 0x703e, 0x703f, 0x7040, 0x7041, 0x7042, 0x7043, 0x7044, 0x7045, 0x7046, 0x7047,
+    0x7048, 0x7049, 0x704a, 0x704b, 0x704d, 0x704e, 0x7050, 0x7051, 0x7052, 0x7053, 0x7054, 0x7055, 0x7056, 0x7057, 0x7058, 0x7059,
+    0x705a, 0x705b, 0x705c, 0x705d, 0x705f, 0x7060, 0x7061, 0x7062, 0x7063, 0x7064, 0x7065, 0x7066, 0x7067, 0x7068, 0x7069, 0x706a,
+    0x706e, 0x7071, 0x7072, 0x7073, 0x7074, 0x7077, 0x7079, 0x707a, 0x707b, 0x707d, 0x7081, 0x7082, 0x7083, 0x7084, 0x7086, 0x7087,
+    0x7088, 0x708b, 0x708c, 0x708d, 0x708f, 0x7090, 0x7091, 0x7093, 0x7097, 0x7098, 0x709a, 0x709b, 0x709e, 0x709f, 0x70a0, 0x70a1,
+    0x70a2, 0x70a3, 0x70a4, 0x70a5, 0x70a6, 0x70a7, 0x70a8, 0x70a9, 0x70aa, 0x70b0, 0x70b2, 0x70b4, 0x70b5, 0x70b6, 0x70ba, 0x70be,
+    0x70bf, 0x70c4, 0x70c5, 0x70c6, 0x70c7, 0x70c9, 0x70cb, 0x70cc, 0x70cd, 0x70ce, 0x70cf, 0x70d0, 0x70d1, 0x70d2, 0x70d3, 0x70d4,
+    0x70d5, 0x70d6, 0x70d7, 0x70da, 0x70dc, 0x70dd, 0x70de, 0x70e0, 0x70e1, 0x70e2, 0x70e3, 0x70e5, 0x70ea, 0x70ee, 0x70f0, 0x70f1,
+    0x70f2, 0x70f3, 0x70f4, 0x70f5, 0x70f6, 0x70f8, 0x70fa, 0x70fb, 0x70fc, 0x70fe, 0x70ff, 0x7100, 0x7101, 0x7102, 0x7103, 0x7104,
+    0x7105, 0x7106, 0x7107, 0x7108, 0x710b, 0x710c, 0x710d, 0x710e, 0x710f, 0x7111, 0x7112, 0x7114, 0x7117, 0x711b, 0x711c, 0x711d,
+    0x711e, 0x711f, 0x7120, 0x7121, 0x7122, 0x7123, 0x7124, 0x7125, 0x7127, 0x7128, 0x7129, 0x712a, 0x712b, 0x712c, 0x712d, 0x712e,
+    0x7132, 0x7133, 0x7134, 0x7135, 0x7137, 0x7138, 0x7139, 0x713a, 0x713b, 0x713c, 0x713d, 0x713e, 0x713f, 0x7140, 0x7141, 0x7142,
+    0x7143, 0x7144, 0x7146, 0x7147, 0x7148, 0x7149, 0x714b, 0x714d, 0x714f, 0x7150, 0x7151, 0x7152, 0x7153, 0x7154, 0x7155, 0x7156,
+    0x7157, 0x7158, 0x7159, 0x715a, 0x715b, 0x715d, 0x715f, 0x7160, 0x7161, 0x7162, 0x7163, 0x7165, 0x7169, 0x716a, 0x716b, 0x716c,
+    0x716d, 0x716f, 0x7170, 0x7171, 0x7174, 0x7175, 0x7176, 0x7177, 0x7179, 0x717b, 0x717c, 0x717e, 0x717f, 0x7180, 0x7181, 0x7182,
+    0x7183, 0x7185, 0x7186, 0x7187, 0x7188, 0x7189, 0x718b, 0x718c, 0x718d, 0x718e, 0x7190, 0x7191, 0x7192, 0x7193, 0x7195, 0x7196,
+    0x7197, 0x719a, 0x719b, 0x719c, 0x719d, 0x719e, 0x71a1, 0x71a2, 0x71a3, 0x71a4, 0x71a5, 0x71a6, 0x71a7, 0x71a9, 0x71aa, 0x71ab,
+    0x71ad, 0x71ae, 0x71af, 0x71b0, 0x71b1, 0x71b2, 0x71b4, 0x71b6, 0x71b7, 0x71b8, 0x71ba, 0x71bb, 0x71bc, 0x71bd, 0x71be, 0x71bf,
+    0x71c0, 0x71c1, 0x71c2, 0x71c4, 0x71c5, 0x71c6, 0x71c7, 0x71c8, 0x71c9, 0x71ca, 0x71cb, 0x71cc, 0x71cd, 0x71cf, 0x71d0, 0x71d1,
+    0x71d2, 0x71d3, 0x71d6, 0x71d7, 0x71d8, 0x71d9, 0x71da, 0x71db, 0x71dc, 0x71dd, 0x71de, 0x71df, 0x71e1, 0x71e2, 0x71e3, 0x71e4,
+    0x71e6, 0x71e8, 0x71e9, 0x71ea, 0x71eb, 0x71ec, 0x71ed, 0x71ef, 0x71f0, 0x71f1, 0x71f2, 0x71f3, 0x71f4, 0x71f5, 0x71f6, 0x71f7,
+    0x71f8, 0x71fa, 0x71fb, 0x71fc, 0x71fd, 0x71fe, 0x71ff, 0x7200, 0x7201, 0x7202, 0x7203, 0x7204, 0x7205, 0x7207, 0x7208, 0x7209,
+    0x720a, 0x720b, 0x720c, 0x720d, 0x720e, 0x720f, 0x7210, 0x7211, 0x7212, 0x7213, 0x7214, 0x7215, 0x7216, 0x7217, 0x7218, 0x7219,
+    0x721a, 0x721b, 0x721c, 0x721e, 0x721f, 0x7220, 0x7221, 0x7222, 0x7223, 0x7224, 0x7225, 0x7226, 0x7227, 0x7229, 0x722b, 0x722d,
+    0x722e, 0x722f, 0x7232, 0x7233, 0x7234, 0x723a, 0x723c, 0x723e, 0x7240, 0x7241, 0x7242, 0x7243, 0x7244, 0x7245, 0x7246, 0x7249,
+    0x724a, 0x724b, 0x724e, 0x724f, 0x7250, 0x7251, 0x7253, 0x7254, 0x7255, 0x7257, 0x7258, 0x725a, 0x725c, 0x725e, 0x7260, 0x7263,
+    0x7264, 0x7265, 0x7268, 0x726a, 0x726b, 0x726c, 0x726d, 0x7270, 0x7271, 0x7273, 0x7274, 0x7276, 0x7277, 0x7278, 0x727b, 0x727c,
+    0x727d, 0x7282, 0x7283, 0x7285, 0x7286, 0x7287, 0x7288, 0x7289, 0x728c, 0x728e, 0x7290, 0x7291, 0x7293, 0x7294, 0x7295, 0x7296,
+    0x7297, 0x7298, 0x7299, 0x729a, 0x729b, 0x729c, 0x729d, 0x729e, 0x72a0, 0x72a1, 0x72a2, 0x72a3, 0x72a4, 0x72a5, 0x72a6, 0x72a7,
+    0x72a8, 0x72a9, 0x72aa, 0x72ab, 0x72ae, 0x72b1, 0x72b2, 0x72b3, 0x72b5, 0x72ba, 0x72bb, 0x72bc, 0x72bd, 0x72be, 0x72bf, 0x72c0,
+    0x72c5, 0x72c6, 0x72c7, 0x72c9, 0x72ca, 0x72cb, 0x72cc, 0x72cf, 0x72d1, 0x72d3, 0x72d4, 0x72d5, 0x72d6, 0x72d8, 0x72da, 0x72db,
+    0xe4c6, 0xe4c7, 0xe4c8, 0xe4c9, 0xe4ca, 0xe4cb, 0xe4cc, 0xe4cd, 0xe4ce, 0xe4cf, 0xe4d0, 0xe4d1, 0xe4d2, 0xe4d3, 0xe4d4, 0xe4d5,
+    0xe4d6, 0xe4d7, 0xe4d8, 0xe4d9, 0xe4da, 0xe4db, 0xe4dc, 0xe4dd, 0xe4de, 0xe4df, 0xe4e0, 0xe4e1, 0xe4e2, 0xe4e3, 0xe4e4, 0xe4e5,
+    0xe4e6, 0xe4e7, 0xe4e8, 0xe4e9, 0xe4ea, 0xe4eb, 0xe4ec, 0xe4ed, 0xe4ee, 0xe4ef, 0xe4f0, 0xe4f1, 0xe4f2, 0xe4f3, 0xe4f4, 0xe4f5,
+    0xe4f6, 0xe4f7, 0xe4f8, 0xe4f9, 0xe4fa, 0xe4fb, 0xe4fc, 0xe4fd, 0xe4fe, 0xe4ff, 0xe500, 0xe501, 0xe502, 0xe503, 0xe504, 0xe505,
+    0xe506, 0xe507, 0xe508, 0xe509, 0xe50a, 0xe50b, 0xe50c, 0xe50d, 0xe50e, 0xe50f, 0xe510, 0xe511, 0xe512, 0xe513, 0xe514, 0xe515,
+    0xe516, 0xe517, 0xe518, 0xe519, 0xe51a, 0xe51b, 0xe51c, 0xe51d, 0xe51e, 0xe51f, 0xe520, 0xe521, 0xe522, 0xe523, 0xe524, 0xe525,
+    0x3000, 0x3001, 0x3002, 0x00b7, 0x02c9, 0x02c7, 0x00a8, 0x3003, 0x3005, 0x2014, 0xff5e, 0x2016, 0x2026, 0x2018, 0x2019, 0x201c,
+    0x201d, 0x3014, 0x3015, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c, 0x300d, 0x300e, 0x300f, 0x3016, 0x3017, 0x3010, 0x3011, 0x00b1,
+    0x00d7, 0x00f7, 0x2236, 0x2227, 0x2228, 0x2211, 0x220f, 0x222a, 0x2229, 0x2208, 0x2237, 0x221a, 0x22a5, 0x2225, 0x2220, 0x2312,
+    0x2299, 0x222b, 0x222e, 0x2261, 0x224c, 0x2248, 0x223d, 0x221d, 0x2260, 0x226e, 0x226f, 0x2264, 0x2265, 0x221e, 0x2235, 0x2234,
+    0x2642, 0x2640, 0x00b0, 0x2032, 0x2033, 0x2103, 0xff04, 0x00a4, 0xffe0, 0xffe1, 0x2030, 0x00a7, 0x2116, 0x2606, 0x2605, 0x25cb,
+    0x25cf, 0x25ce, 0x25c7, 0x25c6, 0x25a1, 0x25a0, 0x25b3, 0x25b2, 0x203b, 0x2192, 0x2190, 0x2191, 0x2193, 0x3013, 0xe526, 0xe527,
+    0xe528, 0xe529, 0xe52a, 0xe52b, 0xe52c, 0xe52d, 0xe52e, 0xe52f, 0xe530, 0xe531, 0xe532, 0xe533, 0xe534, 0xe535, 0xe536, 0xe537,
+    0xe538, 0xe539, 0xe53a, 0xe53b, 0xe53c, 0xe53d, 0xe53e, 0xe53f, 0xe540, 0xe541, 0xe542, 0xe543, 0xe544, 0xe545, 0xe546, 0xe547,
+    0xe548, 0xe549, 0xe54a, 0xe54b, 0xe54c, 0xe54d, 0xe54e, 0xe54f, 0xe550, 0xe551, 0xe552, 0xe553, 0xe554, 0xe555, 0xe556, 0xe557,
+    0xe558, 0xe559, 0xe55a, 0xe55b, 0xe55c, 0xe55d, 0xe55e, 0xe55f, 0xe560, 0xe561, 0xe562, 0xe563, 0xe564, 0xe565, 0xe566, 0xe567,
+    0xe568, 0xe569, 0xe56a, 0xe56b, 0xe56c, 0xe56d, 0xe56e, 0xe56f, 0xe570, 0xe571, 0xe572, 0xe573, 0xe574, 0xe575, 0xe576, 0xe577,
+    0xe578, 0xe579, 0xe57a, 0xe57b, 0xe57c, 0xe57d, 0xe57e, 0xe57f, 0xe580, 0xe581, 0xe582, 0xe583, 0xe584, 0xe585, 0x2170, 0x2171,
+    0x2172, 0x2173, 0x2174, 0x2175, 0x2176, 0x2177, 0x2178, 0x2179, 0xe766, 0xe767, 0xe768, 0xe769, 0xe76a, 0xe76b, 0x2488, 0x2489,
+    0x248a, 0x248b, 0x248c, 0x248d, 0x248e, 0x248f, 0x2490, 0x2491, 0x2492, 0x2493, 0x2494, 0x2495, 0x2496, 0x2497, 0x2498, 0x2499,
+    0x249a, 0x249b, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479, 0x247a, 0x247b, 0x247c, 0x247d, 0x247e, 0x247f, 0x2480, 0x2481,
+    0x2482, 0x2483, 0x2484, 0x2485, 0x2486, 0x2487, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469,
+    0x20ac, 0xe76d, 0x3220, 0x3221, 0x3222, 0x3223, 0x3224, 0x3225, 0x3226, 0x3227, 0x3228, 0x3229, 0xe76e, 0xe76f, 0x2160, 0x2161,
+    0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168, 0x2169, 0x216a, 0x216b, 0xe770, 0xe771, 0xe586, 0xe587, 0xe588, 0xe589,
+    0xe58a, 0xe58b, 0xe58c, 0xe58d, 0xe58e, 0xe58f, 0xe590, 0xe591, 0xe592, 0xe593, 0xe594, 0xe595, 0xe596, 0xe597, 0xe598, 0xe599,
+    0xe59a, 0xe59b, 0xe59c, 0xe59d, 0xe59e, 0xe59f, 0xe5a0, 0xe5a1, 0xe5a2, 0xe5a3, 0xe5a4, 0xe5a5, 0xe5a6, 0xe5a7, 0xe5a8, 0xe5a9,
+    0xe5aa, 0xe5ab, 0xe5ac, 0xe5ad, 0xe5ae, 0xe5af, 0xe5b0, 0xe5b1, 0xe5b2, 0xe5b3, 0xe5b4, 0xe5b5, 0xe5b6, 0xe5b7, 0xe5b8, 0xe5b9,
+    0xe5ba, 0xe5bb, 0xe5bc, 0xe5bd, 0xe5be, 0xe5bf, 0xe5c0, 0xe5c1, 0xe5c2, 0xe5c3, 0xe5c4, 0xe5c5, 0xe5c6, 0xe5c7, 0xe5c8, 0xe5c9,
+    0xe5ca, 0xe5cb, 0xe5cc, 0xe5cd, 0xe5ce, 0xe5cf, 0xe5d0, 0xe5d1, 0xe5d2, 0xe5d3, 0xe5d4, 0xe5d5, 0xe5d6, 0xe5d7, 0xe5d8, 0xe5d9,
+    0xe5da, 0xe5db, 0xe5dc, 0xe5dd, 0xe5de, 0xe5df, 0xe5e0, 0xe5e1, 0xe5e2, 0xe5e3, 0xe5e4, 0xe5e5, 0xff01, 0xff02, 0xff03, 0xffe5,
+    0xff05, 0xff06, 0xff07, 0xff08, 0xff09, 0xff0a, 0xff0b, 0xff0c, 0xff0d, 0xff0e, 0xff0f, 0xff10, 0xff11, 0xff12, 0xff13, 0xff14,
+    0xff15, 0xff16, 0xff17, 0xff18, 0xff19, 0xff1a, 0xff1b, 0xff1c, 0xff1d, 0xff1e, 0xff1f, 0xff20, 0xff21, 0xff22, 0xff23, 0xff24,
+    0xff25, 0xff26, 0xff27, 0xff28, 0xff29, 0xff2a, 0xff2b, 0xff2c, 0xff2d, 0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34,
+    0xff35, 0xff36, 0xff37, 0xff38, 0xff39, 0xff3a, 0xff3b, 0xff3c, 0xff3d, 0xff3e, 0xff3f, 0xff40, 0xff41, 0xff42, 0xff43, 0xff44,
+    0xff45, 0xff46, 0xff47, 0xff48, 0xff49, 0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f, 0xff50, 0xff51, 0xff52, 0xff53, 0xff54,
+    0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a, 0xff5b, 0xff5c, 0xff5d, 0xffe3, 0xe5e6, 0xe5e7, 0xe5e8, 0xe5e9, 0xe5ea, 0xe5eb,
+    0xe5ec, 0xe5ed, 0xe5ee, 0xe5ef, 0xe5f0, 0xe5f1, 0xe5f2, 0xe5f3, 0xe5f4, 0xe5f5, 0xe5f6, 0xe5f7, 0xe5f8, 0xe5f9, 0xe5fa, 0xe5fb,
+    0xe5fc, 0xe5fd, 0xe5fe, 0xe5ff, 0xe600, 0xe601, 0xe602, 0xe603, 0xe604, 0xe605, 0xe606, 0xe607, 0xe608, 0xe609, 0xe60a, 0xe60b,
+    0xe60c, 0xe60d, 0xe60e, 0xe60f, 0xe610, 0xe611, 0xe612, 0xe613, 0xe614, 0xe615, 0xe616, 0xe617, 0xe618, 0xe619, 0xe61a, 0xe61b,
+    0xe61c, 0xe61d, 0xe61e, 0xe61f, 0xe620, 0xe621, 0xe622, 0xe623, 0xe624, 0xe625, 0xe626, 0xe627, 0xe628, 0xe629, 0xe62a, 0xe62b,
+    0xe62c, 0xe62d, 0xe62e, 0xe62f, 0xe630, 0xe631, 0xe632, 0xe633, 0xe634, 0xe635, 0xe636, 0xe637, 0xe638, 0xe639, 0xe63a, 0xe63b,
+    0xe63c, 0xe63d, 0xe63e, 0xe63f, 0xe640, 0xe641, 0xe642, 0xe643, 0xe644, 0xe645, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046,
+    0x3047, 0x3048, 0x3049, 0x304a, 0x304b, 0x304c, 0x304d, 0x304e, 0x304f, 0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056,
+    0x3057, 0x3058, 0x3059, 0x305a, 0x305b, 0x305c, 0x305d, 0x305e, 0x305f, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066,
+    0x3067, 0x3068, 0x3069, 0x306a, 0x306b, 0x306c, 0x306d, 0x306e, 0x306f, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074, 0x3075, 0x3076,
+    0x3077, 0x3078, 0x3079, 0x307a, 0x307b, 0x307c, 0x307d, 0x307e, 0x307f, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086,
+    0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c, 0x308d, 0x308e, 0x308f, 0x3090, 0x3091, 0x3092, 0x3093, 0xe772, 0xe773, 0xe774,
+    0xe775, 0xe776, 0xe777, 0xe778, 0xe779, 0xe77a, 0xe77b, 0xe77c, 0xe646, 0xe647, 0xe648, 0xe649, 0xe64a, 0xe64b, 0xe64c, 0xe64d,
+    0xe64e, 0xe64f, 0xe650, 0xe651, 0xe652, 0xe653, 0xe654, 0xe655, 0xe656, 0xe657, 0xe658, 0xe659, 0xe65a, 0xe65b, 0xe65c, 0xe65d,
+    0xe65e, 0xe65f, 0xe660, 0xe661, 0xe662, 0xe663, 0xe664, 0xe665, 0xe666, 0xe667, 0xe668, 0xe669, 0xe66a, 0xe66b, 0xe66c, 0xe66d,
+    0xe66e, 0xe66f, 0xe670, 0xe671, 0xe672, 0xe673, 0xe674, 0xe675, 0xe676, 0xe677, 0xe678, 0xe679, 0xe67a, 0xe67b, 0xe67c, 0xe67d,
+    0xe67e, 0xe67f, 0xe680, 0xe681, 0xe682, 0xe683, 0xe684, 0xe685, 0xe686, 0xe687, 0xe688, 0xe689, 0xe68a, 0xe68b, 0xe68c, 0xe68d,
+    0xe68e, 0xe68f, 0xe690, 0xe691, 0xe692, 0xe693, 0xe694, 0xe695, 0xe696, 0xe697, 0xe698, 0xe699, 0xe69a, 0xe69b, 0xe69c, 0xe69d,
+    0xe69e, 0xe69f, 0xe6a0, 0xe6a1, 0xe6a2, 0xe6a3, 0xe6a4, 0xe6a5, 0x30a1, 0x30a2, 0x30a3, 0x30a4, 0x30a5, 0x30a6, 0x30a7, 0x30a8,
+    0x30a9, 0x30aa, 0x30ab, 0x30ac, 0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1, 0x30b2, 0x30b3, 0x30b4, 0x30b5, 0x30b6, 0x30b7, 0x30b8,
+    0x30b9, 0x30ba, 0x30bb, 0x30bc, 0x30bd, 0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3, 0x30c4, 0x30c5, 0x30c6, 0x30c7, 0x30c8,
+    0x30c9, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4, 0x30d5, 0x30d6, 0x30d7, 0x30d8,
+    0x30d9, 0x30da, 0x30db, 0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2, 0x30e3, 0x30e4, 0x30e5, 0x30e6, 0x30e7, 0x30e8,
+    0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x30f3, 0x30f4, 0x30f5, 0x30f6, 0xe77d, 0xe77e,
+    0xe77f, 0xe780, 0xe781, 0xe782, 0xe783, 0xe784, 0xe6a6, 0xe6a7, 0xe6a8, 0xe6a9, 0xe6aa, 0xe6ab, 0xe6ac, 0xe6ad, 0xe6ae, 0xe6af,
+    0xe6b0, 0xe6b1, 0xe6b2, 0xe6b3, 0xe6b4, 0xe6b5, 0xe6b6, 0xe6b7, 0xe6b8, 0xe6b9, 0xe6ba, 0xe6bb, 0xe6bc, 0xe6bd, 0xe6be, 0xe6bf,
+    0xe6c0, 0xe6c1, 0xe6c2, 0xe6c3, 0xe6c4, 0xe6c5, 0xe6c6, 0xe6c7, 0xe6c8, 0xe6c9, 0xe6ca, 0xe6cb, 0xe6cc, 0xe6cd, 0xe6ce, 0xe6cf,
+    0xe6d0, 0xe6d1, 0xe6d2, 0xe6d3, 0xe6d4, 0xe6d5, 0xe6d6, 0xe6d7, 0xe6d8, 0xe6d9, 0xe6da, 0xe6db, 0xe6dc, 0xe6dd, 0xe6de, 0xe6df,
+    0xe6e0, 0xe6e1, 0xe6e2, 0xe6e3, 0xe6e4, 0xe6e5, 0xe6e6, 0xe6e7, 0xe6e8, 0xe6e9, 0xe6ea, 0xe6eb, 0xe6ec, 0xe6ed, 0xe6ee, 0xe6ef,
+    0xe6f0, 0xe6f1, 0xe6f2, 0xe6f3, 0xe6f4, 0xe6f5, 0xe6f6, 0xe6f7, 0xe6f8, 0xe6f9, 0xe6fa, 0xe6fb, 0xe6fc, 0xe6fd, 0xe6fe, 0xe6ff,
+    0xe700, 0xe701, 0xe702, 0xe703, 0xe704, 0xe705, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039a,
+    0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03a3, 0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9, 0xe785, 0xe786,
+    0xe787, 0xe788, 0xe789, 0xe78a, 0xe78b, 0xe78c, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9, 0x03ba,
+    0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9, 0xe78d, 0xe78e,
+    0xe78f, 0xe790, 0xe791, 0xe792, 0xe793, 0xfe35, 0xfe36, 0xfe39, 0xfe3a, 0xfe3f, 0xfe40, 0xfe3d, 0xfe3e, 0xfe41, 0xfe42, 0xfe43,
+    0xfe44, 0xe794, 0xe795, 0xfe3b, 0xfe3c, 0xfe37, 0xfe38, 0xfe31, 0xe796, 0xfe33, 0xfe34, 0xe797, 0xe798, 0xe799, 0xe79a, 0xe79b,
+    0xe79c, 0xe79d, 0xe79e, 0xe79f, 0xe706, 0xe707, 0xe708, 0xe709, 0xe70a, 0xe70b, 0xe70c, 0xe70d, 0xe70e, 0xe70f, 0xe710, 0xe711,
+    0xe712, 0xe713, 0xe714, 0xe715, 0xe716, 0xe717, 0xe718, 0xe719, 0xe71a, 0xe71b, 0xe71c, 0xe71d, 0xe71e, 0xe71f, 0xe720, 0xe721,
+    0xe722, 0xe723, 0xe724, 0xe725, 0xe726, 0xe727, 0xe728, 0xe729, 0xe72a, 0xe72b, 0xe72c, 0xe72d, 0xe72e, 0xe72f, 0xe730, 0xe731,
+    0xe732, 0xe733, 0xe734, 0xe735, 0xe736, 0xe737, 0xe738, 0xe739, 0xe73a, 0xe73b, 0xe73c, 0xe73d, 0xe73e, 0xe73f, 0xe740, 0xe741,
+    0xe742, 0xe743, 0xe744, 0xe745, 0xe746, 0xe747, 0xe748, 0xe749, 0xe74a, 0xe74b, 0xe74c, 0xe74d, 0xe74e, 0xe74f, 0xe750, 0xe751,
+    0xe752, 0xe753, 0xe754, 0xe755, 0xe756, 0xe757, 0xe758, 0xe759, 0xe75a, 0xe75b, 0xe75c, 0xe75d, 0xe75e, 0xe75f, 0xe760, 0xe761,
+    0xe762, 0xe763, 0xe764, 0xe765, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0401, 0x0416, 0x0417, 0x0418, 0x0419, 0x041a,
+    0x041b, 0x041c, 0x041d, 0x041e, 0x041f, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042a,
+    0x042b, 0x042c, 0x042d, 0x042e, 0x042f, 0xe7a0, 0xe7a1, 0xe7a2, 0xe7a3, 0xe7a4, 0xe7a5, 0xe7a6, 0xe7a7, 0xe7a8, 0xe7a9, 0xe7aa,
+    0xe7ab, 0xe7ac, 0xe7ad, 0xe7ae, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043a,
+    0x043b, 0x043c, 0x043d, 0x043e, 0x043f, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044a,
+    0x044b, 0x044c, 0x044d, 0x044e, 0x044f, 0xe7af, 0xe7b0, 0xe7b1, 0xe7b2, 0xe7b3, 0xe7b4, 0xe7b5, 0xe7b6, 0xe7b7, 0xe7b8, 0xe7b9,
+    0xe7ba, 0xe7bb, 0x02ca, 0x02cb, 0x02d9, 0x2013, 0x2015, 0x2025, 0x2035, 0x2105, 0x2109, 0x2196, 0x2197, 0x2198, 0x2199, 0x2215,
+    0x221f, 0x2223, 0x2252, 0x2266, 0x2267, 0x22bf, 0x2550, 0x2551, 0x2552, 0x2553, 0x2554, 0x2555, 0x2556, 0x2557, 0x2558, 0x2559,
+    0x255a, 0x255b, 0x255c, 0x255d, 0x255e, 0x255f, 0x2560, 0x2561, 0x2562, 0x2563, 0x2564, 0x2565, 0x2566, 0x2567, 0x2568, 0x2569,
+    0x256a, 0x256b, 0x256c, 0x256d, 0x256e, 0x256f, 0x2570, 0x2571, 0x2572, 0x2573, 0x2581, 0x2582, 0x2583, 0x2584, 0x2585, 0x2586,
+    0x2587, 0x2588, 0x2589, 0x258a, 0x258b, 0x258c, 0x258d, 0x258e, 0x258f, 0x2593, 0x2594, 0x2595, 0x25bc, 0x25bd, 0x25e2, 0x25e3,
+    0x25e4, 0x25e5, 0x2609, 0x2295, 0x3012, 0x301d, 0x301e, 0xe7bc, 0xe7bd, 0xe7be, 0xe7bf, 0xe7c0, 0xe7c1, 0xe7c2, 0xe7c3, 0xe7c4,
+    0xe7c5, 0xe7c6, 0x0101, 0x00e1, 0x01ce, 0x00e0, 0x0113, 0x00e9, 0x011b, 0x00e8, 0x012b, 0x00ed, 0x01d0, 0x00ec, 0x014d, 0x00f3,
+    0x01d2, 0x00f2, 0x016b, 0x00fa, 0x01d4, 0x00f9, 0x01d6, 0x01d8, 0x01da, 0x01dc, 0x00fc, 0x00ea, 0x0251, 0xe7c7, 0x0144, 0x0148,
+    0x01f9, 0x0261, 0xe7c9, 0xe7ca, 0xe7cb, 0xe7cc, 0x3105, 0x3106, 0x3107, 0x3108, 0x3109, 0x310a, 0x310b, 0x310c, 0x310d, 0x310e,
+    0x310f, 0x3110, 0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116, 0x3117, 0x3118, 0x3119, 0x311a, 0x311b, 0x311c, 0x311d, 0x311e,
+    0x311f, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128, 0x3129, 0xe7cd, 0xe7ce, 0xe7cf, 0xe7d0, 0xe7d1,
+    0xe7d2, 0xe7d3, 0xe7d4, 0xe7d5, 0xe7d6, 0xe7d7, 0xe7d8, 0xe7d9, 0xe7da, 0xe7db, 0xe7dc, 0xe7dd, 0xe7de, 0xe7df, 0xe7e0, 0xe7e1,
+    0x3021, 0x3022, 0x3023, 0x3024, 0x3025, 0x3026, 0x3027, 0x3028, 0x3029, 0x32a3, 0x338e, 0x338f, 0x339c, 0x339d, 0x339e, 0x33a1,
+    0x33c4, 0x33ce, 0x33d1, 0x33d2, 0x33d5, 0xfe30, 0xffe2, 0xffe4, 0xe7e2, 0x2121, 0x3231, 0xe7e3, 0x2010, 0xe7e4, 0xe7e5, 0xe7e6,
+    0x30fc, 0x309b, 0x309c, 0x30fd, 0x30fe, 0x3006, 0x309d, 0x309e, 0xfe49, 0xfe4a, 0xfe4b, 0xfe4c, 0xfe4d, 0xfe4e, 0xfe4f, 0xfe50,
+    0xfe51, 0xfe52, 0xfe54, 0xfe55, 0xfe56, 0xfe57, 0xfe59, 0xfe5a, 0xfe5b, 0xfe5c, 0xfe5d, 0xfe5e, 0xfe5f, 0xfe60, 0xfe61, 0xfe62,
+    0xfe63, 0xfe64, 0xfe65, 0xfe66, 0xfe68, 0xfe69, 0xfe6a, 0xfe6b, 0x303e, 0x2ff0, 0x2ff1, 0x2ff2, 0x2ff3, 0x2ff4, 0x2ff5, 0x2ff6,
+    0x2ff7, 0x2ff8, 0x2ff9, 0x2ffa, 0x2ffb, 0x3007, 0xe7f4, 0xe7f5, 0xe7f6, 0xe7f7, 0xe7f8, 0xe7f9, 0xe7fa, 0xe7fb, 0xe7fc, 0xe7fd,
+    0xe7fe, 0xe7ff, 0xe800, 0x2500, 0x2501, 0x2502, 0x2503, 0x2504, 0x2505, 0x2506, 0x2507, 0x2508, 0x2509, 0x250a, 0x250b, 0x250c,
+    0x250d, 0x250e, 0x250f, 0x2510, 0x2511, 0x2512, 0x2513, 0x2514, 0x2515, 0x2516, 0x2517, 0x2518, 0x2519, 0x251a, 0x251b, 0x251c,
+    0x251d, 0x251e, 0x251f, 0x2520, 0x2521, 0x2522, 0x2523, 0x2524, 0x2525, 0x2526, 0x2527, 0x2528, 0x2529, 0x252a, 0x252b, 0x252c,
+    0x252d, 0x252e, 0x252f, 0x2530, 0x2531, 0x2532, 0x2533, 0x2534, 0x2535, 0x2536, 0x2537, 0x2538, 0x2539, 0x253a, 0x253b, 0x253c,
+    0x253d, 0x253e, 0x253f, 0x2540, 0x2541, 0x2542, 0x2543, 0x2544, 0x2545, 0x2546, 0x2547, 0x2548, 0x2549, 0x254a, 0x254b, 0xe801,
+    0xe802, 0xe803, 0xe804, 0xe805, 0xe806, 0xe807, 0xe808, 0xe809, 0xe80a, 0xe80b, 0xe80c, 0xe80d, 0xe80e, 0xe80f, 0x72dc, 0x72dd,
+    0x72df, 0x72e2, 0x72e3, 0x72e4, 0x72e5, 0x72e6, 0x72e7, 0x72ea, 0x72eb, 0x72f5, 0x72f6, 0x72f9, 0x72fd, 0x72fe, 0x72ff, 0x7300,
+    0x7302, 0x7304, 0x7305, 0x7306, 0x7307, 0x7308, 0x7309, 0x730b, 0x730c, 0x730d, 0x730f, 0x7310, 0x7311, 0x7312, 0x7314, 0x7318,
+    0x7319, 0x731a, 0x731f, 0x7320, 0x7323, 0x7324, 0x7326, 0x7327, 0x7328, 0x732d, 0x732f, 0x7330, 0x7332, 0x7333, 0x7335, 0x7336,
+    0x733a, 0x733b, 0x733c, 0x733d, 0x7340, 0x7341, 0x7342, 0x7343, 0x7344, 0x7345, 0x7346, 0x7347, 0x7348, 0x7349, 0x734a, 0x734b,
+    0x734c, 0x734e, 0x734f, 0x7351, 0x7353, 0x7354, 0x7355, 0x7356, 0x7358, 0x7359, 0x735a, 0x735b, 0x735c, 0x735d, 0x735e, 0x735f,
+    0x7361, 0x7362, 0x7363, 0x7364, 0x7365, 0x7366, 0x7367, 0x7368, 0x7369, 0x736a, 0x736b, 0x736e, 0x7370, 0x7371, 0xe000, 0xe001,
+    0xe002, 0xe003, 0xe004, 0xe005, 0xe006, 0xe007, 0xe008, 0xe009, 0xe00a, 0xe00b, 0xe00c, 0xe00d, 0xe00e, 0xe00f, 0xe010, 0xe011,
+    0xe012, 0xe013, 0xe014, 0xe015, 0xe016, 0xe017, 0xe018, 0xe019, 0xe01a, 0xe01b, 0xe01c, 0xe01d, 0xe01e, 0xe01f, 0xe020, 0xe021,
+    0xe022, 0xe023, 0xe024, 0xe025, 0xe026, 0xe027, 0xe028, 0xe029, 0xe02a, 0xe02b, 0xe02c, 0xe02d, 0xe02e, 0xe02f, 0xe030, 0xe031,
+    0xe032, 0xe033, 0xe034, 0xe035, 0xe036, 0xe037, 0xe038, 0xe039, 0xe03a, 0xe03b, 0xe03c, 0xe03d, 0xe03e, 0xe03f, 0xe040, 0xe041,
+    0xe042, 0xe043, 0xe044, 0xe045, 0xe046, 0xe047, 0xe048, 0xe049, 0xe04a, 0xe04b, 0xe04c, 0xe04d, 0xe04e, 0xe04f, 0xe050, 0xe051,
+    0xe052, 0xe053, 0xe054, 0xe055, 0xe056, 0xe057, 0xe058, 0xe059, 0xe05a, 0xe05b, 0xe05c, 0xe05d, 0x7372, 0x7373, 0x7374, 0x7375,
+    0x7376, 0x7377, 0x7378, 0x7379, 0x737a, 0x737b, 0x737c, 0x737d, 0x737f, 0x7380, 0x7381, 0x7382, 0x7383, 0x7385, 0x7386, 0x7388,
+    0x738a, 0x738c, 0x738d, 0x738f, 0x7390, 0x7392, 0x7393, 0x7394, 0x7395, 0x7397, 0x7398, 0x7399, 0x739a, 0x739c, 0x739d, 0x739e,
+    0x73a0, 0x73a1, 0x73a3, 0x73a4, 0x73a5, 0x73a6, 0x73a7, 0x73a8, 0x73aa, 0x73ac, 0x73ad, 0x73b1, 0x73b4, 0x73b5, 0x73b6, 0x73b8,
+    0x73b9, 0x73bc, 0x73bd, 0x73be, 0x73bf, 0x73c1, 0x73c3, 0x73c4, 0x73c5, 0x73c6, 0x73c7, 0x73cb, 0x73cc, 0x73ce, 0x73d2, 0x73d3,
+    0x73d4, 0x73d5, 0x73d6, 0x73d7, 0x73d8, 0x73da, 0x73db, 0x73dc, 0x73dd, 0x73df, 0x73e1, 0x73e2, 0x73e3, 0x73e4, 0x73e6, 0x73e8,
+    0x73ea, 0x73eb, 0x73ec, 0x73ee, 0x73ef, 0x73f0, 0x73f1, 0x73f3, 0x73f4, 0x73f5, 0x73f6, 0x73f7, 0xe05e, 0xe05f, 0xe060, 0xe061,
+    0xe062, 0xe063, 0xe064, 0xe065, 0xe066, 0xe067, 0xe068, 0xe069, 0xe06a, 0xe06b, 0xe06c, 0xe06d, 0xe06e, 0xe06f, 0xe070, 0xe071,
+    0xe072, 0xe073, 0xe074, 0xe075, 0xe076, 0xe077, 0xe078, 0xe079, 0xe07a, 0xe07b, 0xe07c, 0xe07d, 0xe07e, 0xe07f, 0xe080, 0xe081,
+    0xe082, 0xe083, 0xe084, 0xe085, 0xe086, 0xe087, 0xe088, 0xe089, 0xe08a, 0xe08b, 0xe08c, 0xe08d, 0xe08e, 0xe08f, 0xe090, 0xe091,
+    0xe092, 0xe093, 0xe094, 0xe095, 0xe096, 0xe097, 0xe098, 0xe099, 0xe09a, 0xe09b, 0xe09c, 0xe09d, 0xe09e, 0xe09f, 0xe0a0, 0xe0a1,
+    0xe0a2, 0xe0a3, 0xe0a4, 0xe0a5, 0xe0a6, 0xe0a7, 0xe0a8, 0xe0a9, 0xe0aa, 0xe0ab, 0xe0ac, 0xe0ad, 0xe0ae, 0xe0af, 0xe0b0, 0xe0b1,
+    0xe0b2, 0xe0b3, 0xe0b4, 0xe0b5, 0xe0b6, 0xe0b7, 0xe0b8, 0xe0b9, 0xe0ba, 0xe0bb, 0x73f8, 0x73f9, 0x73fa, 0x73fb, 0x73fc, 0x73fd,
+    0x73fe, 0x73ff, 0x7400, 0x7401, 0x7402, 0x7404, 0x7407, 0x7408, 0x740b, 0x740c, 0x740d, 0x740e, 0x7411, 0x7412, 0x7413, 0x7414,
+    0x7415, 0x7416, 0x7417, 0x7418, 0x7419, 0x741c, 0x741d, 0x741e, 0x741f, 0x7420, 0x7421, 0x7423, 0x7424, 0x7427, 0x7429, 0x742b,
+    0x742d, 0x742f, 0x7431, 0x7432, 0x7437, 0x7438, 0x7439, 0x743a, 0x743b, 0x743d, 0x743e, 0x743f, 0x7440, 0x7442, 0x7443, 0x7444,
+    0x7445, 0x7446, 0x7447, 0x7448, 0x7449, 0x744a, 0x744b, 0x744c, 0x744d, 0x744e, 0x744f, 0x7450, 0x7451, 0x7452, 0x7453, 0x7454,
+    0x7456, 0x7458, 0x745d, 0x7460, 0x7461, 0x7462, 0x7463, 0x7464, 0x7465, 0x7466, 0x7467, 0x7468, 0x7469, 0x746a, 0x746b, 0x746c,
+    0x746e, 0x746f, 0x7471, 0x7472, 0x7473, 0x7474, 0x7475, 0x7478, 0x7479, 0x747a, 0xe0bc, 0xe0bd, 0xe0be, 0xe0bf, 0xe0c0, 0xe0c1,
+    0xe0c2, 0xe0c3, 0xe0c4, 0xe0c5, 0xe0c6, 0xe0c7, 0xe0c8, 0xe0c9, 0xe0ca, 0xe0cb, 0xe0cc, 0xe0cd, 0xe0ce, 0xe0cf, 0xe0d0, 0xe0d1,
+    0xe0d2, 0xe0d3, 0xe0d4, 0xe0d5, 0xe0d6, 0xe0d7, 0xe0d8, 0xe0d9, 0xe0da, 0xe0db, 0xe0dc, 0xe0dd, 0xe0de, 0xe0df, 0xe0e0, 0xe0e1,
+    0xe0e2, 0xe0e3, 0xe0e4, 0xe0e5, 0xe0e6, 0xe0e7, 0xe0e8, 0xe0e9, 0xe0ea, 0xe0eb, 0xe0ec, 0xe0ed, 0xe0ee, 0xe0ef, 0xe0f0, 0xe0f1,
+    0xe0f2, 0xe0f3, 0xe0f4, 0xe0f5, 0xe0f6, 0xe0f7, 0xe0f8, 0xe0f9, 0xe0fa, 0xe0fb, 0xe0fc, 0xe0fd, 0xe0fe, 0xe0ff, 0xe100, 0xe101,
+    0xe102, 0xe103, 0xe104, 0xe105, 0xe106, 0xe107, 0xe108, 0xe109, 0xe10a, 0xe10b, 0xe10c, 0xe10d, 0xe10e, 0xe10f, 0xe110, 0xe111,
+    0xe112, 0xe113, 0xe114, 0xe115, 0xe116, 0xe117, 0xe118, 0xe119, 0x747b, 0x747c, 0x747d, 0x747f, 0x7482, 0x7484, 0x7485, 0x7486,
+    0x7488, 0x7489, 0x748a, 0x748c, 0x748d, 0x748f, 0x7491, 0x7492, 0x7493, 0x7494, 0x7495, 0x7496, 0x7497, 0x7498, 0x7499, 0x749a,
+    0x749b, 0x749d, 0x749f, 0x74a0, 0x74a1, 0x74a2, 0x74a3, 0x74a4, 0x74a5, 0x74a6, 0x74aa, 0x74ab, 0x74ac, 0x74ad, 0x74ae, 0x74af,
+    0x74b0, 0x74b1, 0x74b2, 0x74b3, 0x74b4, 0x74b5, 0x74b6, 0x74b7, 0x74b8, 0x74b9, 0x74bb, 0x74bc, 0x74bd, 0x74be, 0x74bf, 0x74c0,
+    0x74c1, 0x74c2, 0x74c3, 0x74c4, 0x74c5, 0x74c6, 0x74c7, 0x74c8, 0x74c9, 0x74ca, 0x74cb, 0x74cc, 0x74cd, 0x74ce, 0x74cf, 0x74d0,
+    0x74d1, 0x74d3, 0x74d4, 0x74d5, 0x74d6, 0x74d7, 0x74d8, 0x74d9, 0x74da, 0x74db, 0x74dd, 0x74df, 0x74e1, 0x74e5, 0x74e7, 0x74e8,
+    0x74e9, 0x74ea, 0x74eb, 0x74ec, 0x74ed, 0x74f0, 0x74f1, 0x74f2, 0xe11a, 0xe11b, 0xe11c, 0xe11d, 0xe11e, 0xe11f, 0xe120, 0xe121,
+    0xe122, 0xe123, 0xe124, 0xe125, 0xe126, 0xe127, 0xe128, 0xe129, 0xe12a, 0xe12b, 0xe12c, 0xe12d, 0xe12e, 0xe12f, 0xe130, 0xe131,
+    0xe132, 0xe133, 0xe134, 0xe135, 0xe136, 0xe137, 0xe138, 0xe139, 0xe13a, 0xe13b, 0xe13c, 0xe13d, 0xe13e, 0xe13f, 0xe140, 0xe141,
+    0xe142, 0xe143, 0xe144, 0xe145, 0xe146, 0xe147, 0xe148, 0xe149, 0xe14a, 0xe14b, 0xe14c, 0xe14d, 0xe14e, 0xe14f, 0xe150, 0xe151,
+    0xe152, 0xe153, 0xe154, 0xe155, 0xe156, 0xe157, 0xe158, 0xe159, 0xe15a, 0xe15b, 0xe15c, 0xe15d, 0xe15e, 0xe15f, 0xe160, 0xe161,
+    0xe162, 0xe163, 0xe164, 0xe165, 0xe166, 0xe167, 0xe168, 0xe169, 0xe16a, 0xe16b, 0xe16c, 0xe16d, 0xe16e, 0xe16f, 0xe170, 0xe171,
+    0xe172, 0xe173, 0xe174, 0xe175, 0xe176, 0xe177, 0x74f3, 0x74f5, 0x74f8, 0x74f9, 0x74fa, 0x74fb, 0x74fc, 0x74fd, 0x74fe, 0x7500,
+    0x7501, 0x7502, 0x7503, 0x7505, 0x7506, 0x7507, 0x7508, 0x7509, 0x750a, 0x750b, 0x750c, 0x750e, 0x7510, 0x7512, 0x7514, 0x7515,
+    0x7516, 0x7517, 0x751b, 0x751d, 0x751e, 0x7520, 0x7521, 0x7522, 0x7523, 0x7524, 0x7526, 0x7527, 0x752a, 0x752e, 0x7534, 0x7536,
+    0x7539, 0x753c, 0x753d, 0x753f, 0x7541, 0x7542, 0x7543, 0x7544, 0x7546, 0x7547, 0x7549, 0x754a, 0x754d, 0x7550, 0x7551, 0x7552,
+    0x7553, 0x7555, 0x7556, 0x7557, 0x7558, 0x755d, 0x755e, 0x755f, 0x7560, 0x7561, 0x7562, 0x7563, 0x7564, 0x7567, 0x7568, 0x7569,
+    0x756b, 0x756c, 0x756d, 0x756e, 0x756f, 0x7570, 0x7571, 0x7573, 0x7575, 0x7576, 0x7577, 0x757a, 0x757b, 0x757c, 0x757d, 0x757e,
+    0x7580, 0x7581, 0x7582, 0x7584, 0x7585, 0x7587, 0xe178, 0xe179, 0xe17a, 0xe17b, 0xe17c, 0xe17d, 0xe17e, 0xe17f, 0xe180, 0xe181,
+    0xe182, 0xe183, 0xe184, 0xe185, 0xe186, 0xe187, 0xe188, 0xe189, 0xe18a, 0xe18b, 0xe18c, 0xe18d, 0xe18e, 0xe18f, 0xe190, 0xe191,
+    0xe192, 0xe193, 0xe194, 0xe195, 0xe196, 0xe197, 0xe198, 0xe199, 0xe19a, 0xe19b, 0xe19c, 0xe19d, 0xe19e, 0xe19f, 0xe1a0, 0xe1a1,
+    0xe1a2, 0xe1a3, 0xe1a4, 0xe1a5, 0xe1a6, 0xe1a7, 0xe1a8, 0xe1a9, 0xe1aa, 0xe1ab, 0xe1ac, 0xe1ad, 0xe1ae, 0xe1af, 0xe1b0, 0xe1b1,
+    0xe1b2, 0xe1b3, 0xe1b4, 0xe1b5, 0xe1b6, 0xe1b7, 0xe1b8, 0xe1b9, 0xe1ba, 0xe1bb, 0xe1bc, 0xe1bd, 0xe1be, 0xe1bf, 0xe1c0, 0xe1c1,
+    0xe1c2, 0xe1c3, 0xe1c4, 0xe1c5, 0xe1c6, 0xe1c7, 0xe1c8, 0xe1c9, 0xe1ca, 0xe1cb, 0xe1cc, 0xe1cd, 0xe1ce, 0xe1cf, 0xe1d0, 0xe1d1,
+    0xe1d2, 0xe1d3, 0xe1d4, 0xe1d5, 0x7588, 0x7589, 0x758a, 0x758c, 0x758d, 0x758e, 0x7590, 0x7593, 0x7595, 0x7598, 0x759b, 0x759c,
+    0x759e, 0x75a2, 0x75a6, 0x75a7, 0x75a8, 0x75a9, 0x75aa, 0x75ad, 0x75b6, 0x75b7, 0x75ba, 0x75bb, 0x75bf, 0x75c0, 0x75c1, 0x75c6,
+    0x75cb, 0x75cc, 0x75ce, 0x75cf, 0x75d0, 0x75d1, 0x75d3, 0x75d7, 0x75d9, 0x75da, 0x75dc, 0x75dd, 0x75df, 0x75e0, 0x75e1, 0x75e5,
+    0x75e9, 0x75ec, 0x75ed, 0x75ee, 0x75ef, 0x75f2, 0x75f3, 0x75f5, 0x75f6, 0x75f7, 0x75f8, 0x75fa, 0x75fb, 0x75fd, 0x75fe, 0x7602,
+    0x7604, 0x7606, 0x7607, 0x7608, 0x7609, 0x760b, 0x760d, 0x760e, 0x760f, 0x7611, 0x7612, 0x7613, 0x7614, 0x7616, 0x761a, 0x761c,
+    0x761d, 0x761e, 0x7621, 0x7623, 0x7627, 0x7628, 0x762c, 0x762e, 0x762f, 0x7631, 0x7632, 0x7636, 0x7637, 0x7639, 0x763a, 0x763b,
+    0x763d, 0x7641, 0x7642, 0x7644, 0xe1d6, 0xe1d7, 0xe1d8, 0xe1d9, 0xe1da, 0xe1db, 0xe1dc, 0xe1dd, 0xe1de, 0xe1df, 0xe1e0, 0xe1e1,
+    0xe1e2, 0xe1e3, 0xe1e4, 0xe1e5, 0xe1e6, 0xe1e7, 0xe1e8, 0xe1e9, 0xe1ea, 0xe1eb, 0xe1ec, 0xe1ed, 0xe1ee, 0xe1ef, 0xe1f0, 0xe1f1,
+    0xe1f2, 0xe1f3, 0xe1f4, 0xe1f5, 0xe1f6, 0xe1f7, 0xe1f8, 0xe1f9, 0xe1fa, 0xe1fb, 0xe1fc, 0xe1fd, 0xe1fe, 0xe1ff, 0xe200, 0xe201,
+    0xe202, 0xe203, 0xe204, 0xe205, 0xe206, 0xe207, 0xe208, 0xe209, 0xe20a, 0xe20b, 0xe20c, 0xe20d, 0xe20e, 0xe20f, 0xe210, 0xe211,
+    0xe212, 0xe213, 0xe214, 0xe215, 0xe216, 0xe217, 0xe218, 0xe219, 0xe21a, 0xe21b, 0xe21c, 0xe21d, 0xe21e, 0xe21f, 0xe220, 0xe221,
+    0xe222, 0xe223, 0xe224, 0xe225, 0xe226, 0xe227, 0xe228, 0xe229, 0xe22a, 0xe22b, 0xe22c, 0xe22d, 0xe22e, 0xe22f, 0xe230, 0xe231,
+    0xe232, 0xe233, 0x7645, 0x7646, 0x7647, 0x7648, 0x7649, 0x764a, 0x764b, 0x764e, 0x764f, 0x7650, 0x7651, 0x7652, 0x7653, 0x7655,
+    0x7657, 0x7658, 0x7659, 0x765a, 0x765b, 0x765d, 0x765f, 0x7660, 0x7661, 0x7662, 0x7664, 0x7665, 0x7666, 0x7667, 0x7668, 0x7669,
+    0x766a, 0x766c, 0x766d, 0x766e, 0x7670, 0x7671, 0x7672, 0x7673, 0x7674, 0x7675, 0x7676, 0x7677, 0x7679, 0x767a, 0x767c, 0x767f,
+    0x7680, 0x7681, 0x7683, 0x7685, 0x7689, 0x768a, 0x768c, 0x768d, 0x768f, 0x7690, 0x7692, 0x7694, 0x7695, 0x7697, 0x7698, 0x769a,
+    0x769b, 0x769c, 0x769d, 0x769e, 0x769f, 0x76a0, 0x76a1, 0x76a2, 0x76a3, 0x76a5, 0x76a6, 0x76a7, 0x76a8, 0x76a9, 0x76aa, 0x76ab,
+    0x76ac, 0x76ad, 0x76af, 0x76b0, 0x76b3, 0x76b5, 0x76b6, 0x76b7, 0x76b8, 0x76b9, 0x76ba, 0x76bb, 0x76bc, 0x76bd, 0x76be, 0x76c0,
+    0x76c1, 0x76c3, 0x554a, 0x963f, 0x57c3, 0x6328, 0x54ce, 0x5509, 0x54c0, 0x7691, 0x764c, 0x853c, 0x77ee, 0x827e, 0x788d, 0x7231,
+    0x9698, 0x978d, 0x6c28, 0x5b89, 0x4ffa, 0x6309, 0x6697, 0x5cb8, 0x80fa, 0x6848, 0x80ae, 0x6602, 0x76ce, 0x51f9, 0x6556, 0x71ac,
+    0x7ff1, 0x8884, 0x50b2, 0x5965, 0x61ca, 0x6fb3, 0x82ad, 0x634c, 0x6252, 0x53ed, 0x5427, 0x7b06, 0x516b, 0x75a4, 0x5df4, 0x62d4,
+    0x8dcb, 0x9776, 0x628a, 0x8019, 0x575d, 0x9738, 0x7f62, 0x7238, 0x767d, 0x67cf, 0x767e, 0x6446, 0x4f70, 0x8d25, 0x62dc, 0x7a17,
+    0x6591, 0x73ed, 0x642c, 0x6273, 0x822c, 0x9881, 0x677f, 0x7248, 0x626e, 0x62cc, 0x4f34, 0x74e3, 0x534a, 0x529e, 0x7eca, 0x90a6,
+    0x5e2e, 0x6886, 0x699c, 0x8180, 0x7ed1, 0x68d2, 0x78c5, 0x868c, 0x9551, 0x508d, 0x8c24, 0x82de, 0x80de, 0x5305, 0x8912, 0x5265,
+    0x76c4, 0x76c7, 0x76c9, 0x76cb, 0x76cc, 0x76d3, 0x76d5, 0x76d9, 0x76da, 0x76dc, 0x76dd, 0x76de, 0x76e0, 0x76e1, 0x76e2, 0x76e3,
+    0x76e4, 0x76e6, 0x76e7, 0x76e8, 0x76e9, 0x76ea, 0x76eb, 0x76ec, 0x76ed, 0x76f0, 0x76f3, 0x76f5, 0x76f6, 0x76f7, 0x76fa, 0x76fb,
+    0x76fd, 0x76ff, 0x7700, 0x7702, 0x7703, 0x7705, 0x7706, 0x770a, 0x770c, 0x770e, 0x770f, 0x7710, 0x7711, 0x7712, 0x7713, 0x7714,
+    0x7715, 0x7716, 0x7717, 0x7718, 0x771b, 0x771c, 0x771d, 0x771e, 0x7721, 0x7723, 0x7724, 0x7725, 0x7727, 0x772a, 0x772b, 0x772c,
+    0x772e, 0x7730, 0x7731, 0x7732, 0x7733, 0x7734, 0x7739, 0x773b, 0x773d, 0x773e, 0x773f, 0x7742, 0x7744, 0x7745, 0x7746, 0x7748,
+    0x7749, 0x774a, 0x774b, 0x774c, 0x774d, 0x774e, 0x774f, 0x7752, 0x7753, 0x7754, 0x7755, 0x7756, 0x7757, 0x7758, 0x7759, 0x775c,
+    0x8584, 0x96f9, 0x4fdd, 0x5821, 0x9971, 0x5b9d, 0x62b1, 0x62a5, 0x66b4, 0x8c79, 0x9c8d, 0x7206, 0x676f, 0x7891, 0x60b2, 0x5351,
+    0x5317, 0x8f88, 0x80cc, 0x8d1d, 0x94a1, 0x500d, 0x72c8, 0x5907, 0x60eb, 0x7119, 0x88ab, 0x5954, 0x82ef, 0x672c, 0x7b28, 0x5d29,
+    0x7ef7, 0x752d, 0x6cf5, 0x8e66, 0x8ff8, 0x903c, 0x9f3b, 0x6bd4, 0x9119, 0x7b14, 0x5f7c, 0x78a7, 0x84d6, 0x853d, 0x6bd5, 0x6bd9,
+    0x6bd6, 0x5e01, 0x5e87, 0x75f9, 0x95ed, 0x655d, 0x5f0a, 0x5fc5, 0x8f9f, 0x58c1, 0x81c2, 0x907f, 0x965b, 0x97ad, 0x8fb9, 0x7f16,
+    0x8d2c, 0x6241, 0x4fbf, 0x53d8, 0x535e, 0x8fa8, 0x8fa9, 0x8fab, 0x904d, 0x6807, 0x5f6a, 0x8198, 0x8868, 0x9cd6, 0x618b, 0x522b,
+    0x762a, 0x5f6c, 0x658c, 0x6fd2, 0x6ee8, 0x5bbe, 0x6448, 0x5175, 0x51b0, 0x67c4, 0x4e19, 0x79c9, 0x997c, 0x70b3, 0x775d, 0x775e,
+    0x775f, 0x7760, 0x7764, 0x7767, 0x7769, 0x776a, 0x776d, 0x776e, 0x776f, 0x7770, 0x7771, 0x7772, 0x7773, 0x7774, 0x7775, 0x7776,
+    0x7777, 0x7778, 0x777a, 0x777b, 0x777c, 0x7781, 0x7782, 0x7783, 0x7786, 0x7787, 0x7788, 0x7789, 0x778a, 0x778b, 0x778f, 0x7790,
+    0x7793, 0x7794, 0x7795, 0x7796, 0x7797, 0x7798, 0x7799, 0x779a, 0x779b, 0x779c, 0x779d, 0x779e, 0x77a1, 0x77a3, 0x77a4, 0x77a6,
+    0x77a8, 0x77ab, 0x77ad, 0x77ae, 0x77af, 0x77b1, 0x77b2, 0x77b4, 0x77b6, 0x77b7, 0x77b8, 0x77b9, 0x77ba, 0x77bc, 0x77be, 0x77c0,
+    0x77c1, 0x77c2, 0x77c3, 0x77c4, 0x77c5, 0x77c6, 0x77c7, 0x77c8, 0x77c9, 0x77ca, 0x77cb, 0x77cc, 0x77ce, 0x77cf, 0x77d0, 0x77d1,
+    0x77d2, 0x77d3, 0x77d4, 0x77d5, 0x77d6, 0x77d8, 0x77d9, 0x77da, 0x77dd, 0x77de, 0x77df, 0x77e0, 0x77e1, 0x77e4, 0x75c5, 0x5e76,
+    0x73bb, 0x83e0, 0x64ad, 0x62e8, 0x94b5, 0x6ce2, 0x535a, 0x52c3, 0x640f, 0x94c2, 0x7b94, 0x4f2f, 0x5e1b, 0x8236, 0x8116, 0x818a,
+    0x6e24, 0x6cca, 0x9a73, 0x6355, 0x535c, 0x54fa, 0x8865, 0x57e0, 0x4e0d, 0x5e03, 0x6b65, 0x7c3f, 0x90e8, 0x6016, 0x64e6, 0x731c,
+    0x88c1, 0x6750, 0x624d, 0x8d22, 0x776c, 0x8e29, 0x91c7, 0x5f69, 0x83dc, 0x8521, 0x9910, 0x53c2, 0x8695, 0x6b8b, 0x60ed, 0x60e8,
+    0x707f, 0x82cd, 0x8231, 0x4ed3, 0x6ca7, 0x85cf, 0x64cd, 0x7cd9, 0x69fd, 0x66f9, 0x8349, 0x5395, 0x7b56, 0x4fa7, 0x518c, 0x6d4b,
+    0x5c42, 0x8e6d, 0x63d2, 0x53c9, 0x832c, 0x8336, 0x67e5, 0x78b4, 0x643d, 0x5bdf, 0x5c94, 0x5dee, 0x8be7, 0x62c6, 0x67f4, 0x8c7a,
+    0x6400, 0x63ba, 0x8749, 0x998b, 0x8c17, 0x7f20, 0x94f2, 0x4ea7, 0x9610, 0x98a4, 0x660c, 0x7316, 0x77e6, 0x77e8, 0x77ea, 0x77ef,
+    0x77f0, 0x77f1, 0x77f2, 0x77f4, 0x77f5, 0x77f7, 0x77f9, 0x77fa, 0x77fb, 0x77fc, 0x7803, 0x7804, 0x7805, 0x7806, 0x7807, 0x7808,
+    0x780a, 0x780b, 0x780e, 0x780f, 0x7810, 0x7813, 0x7815, 0x7819, 0x781b, 0x781e, 0x7820, 0x7821, 0x7822, 0x7824, 0x7828, 0x782a,
+    0x782b, 0x782e, 0x782f, 0x7831, 0x7832, 0x7833, 0x7835, 0x7836, 0x783d, 0x783f, 0x7841, 0x7842, 0x7843, 0x7844, 0x7846, 0x7848,
+    0x7849, 0x784a, 0x784b, 0x784d, 0x784f, 0x7851, 0x7853, 0x7854, 0x7858, 0x7859, 0x785a, 0x785b, 0x785c, 0x785e, 0x785f, 0x7860,
+    0x7861, 0x7862, 0x7863, 0x7864, 0x7865, 0x7866, 0x7867, 0x7868, 0x7869, 0x786f, 0x7870, 0x7871, 0x7872, 0x7873, 0x7874, 0x7875,
+    0x7876, 0x7878, 0x7879, 0x787a, 0x787b, 0x787d, 0x787e, 0x787f, 0x7880, 0x7881, 0x7882, 0x7883, 0x573a, 0x5c1d, 0x5e38, 0x957f,
+    0x507f, 0x80a0, 0x5382, 0x655e, 0x7545, 0x5531, 0x5021, 0x8d85, 0x6284, 0x949e, 0x671d, 0x5632, 0x6f6e, 0x5de2, 0x5435, 0x7092,
+    0x8f66, 0x626f, 0x64a4, 0x63a3, 0x5f7b, 0x6f88, 0x90f4, 0x81e3, 0x8fb0, 0x5c18, 0x6668, 0x5ff1, 0x6c89, 0x9648, 0x8d81, 0x886c,
+    0x6491, 0x79f0, 0x57ce, 0x6a59, 0x6210, 0x5448, 0x4e58, 0x7a0b, 0x60e9, 0x6f84, 0x8bda, 0x627f, 0x901e, 0x9a8b, 0x79e4, 0x5403,
+    0x75f4, 0x6301, 0x5319, 0x6c60, 0x8fdf, 0x5f1b, 0x9a70, 0x803b, 0x9f7f, 0x4f88, 0x5c3a, 0x8d64, 0x7fc5, 0x65a5, 0x70bd, 0x5145,
+    0x51b2, 0x866b, 0x5d07, 0x5ba0, 0x62bd, 0x916c, 0x7574, 0x8e0c, 0x7a20, 0x6101, 0x7b79, 0x4ec7, 0x7ef8, 0x7785, 0x4e11, 0x81ed,
+    0x521d, 0x51fa, 0x6a71, 0x53a8, 0x8e87, 0x9504, 0x96cf, 0x6ec1, 0x9664, 0x695a, 0x7884, 0x7885, 0x7886, 0x7888, 0x788a, 0x788b,
+    0x788f, 0x7890, 0x7892, 0x7894, 0x7895, 0x7896, 0x7899, 0x789d, 0x789e, 0x78a0, 0x78a2, 0x78a4, 0x78a6, 0x78a8, 0x78a9, 0x78aa,
+    0x78ab, 0x78ac, 0x78ad, 0x78ae, 0x78af, 0x78b5, 0x78b6, 0x78b7, 0x78b8, 0x78ba, 0x78bb, 0x78bc, 0x78bd, 0x78bf, 0x78c0, 0x78c2,
+    0x78c3, 0x78c4, 0x78c6, 0x78c7, 0x78c8, 0x78cc, 0x78cd, 0x78ce, 0x78cf, 0x78d1, 0x78d2, 0x78d3, 0x78d6, 0x78d7, 0x78d8, 0x78da,
+    0x78db, 0x78dc, 0x78dd, 0x78de, 0x78df, 0x78e0, 0x78e1, 0x78e2, 0x78e3, 0x78e4, 0x78e5, 0x78e6, 0x78e7, 0x78e9, 0x78ea, 0x78eb,
+    0x78ed, 0x78ee, 0x78ef, 0x78f0, 0x78f1, 0x78f3, 0x78f5, 0x78f6, 0x78f8, 0x78f9, 0x78fb, 0x78fc, 0x78fd, 0x78fe, 0x78ff, 0x7900,
+    0x7902, 0x7903, 0x7904, 0x7906, 0x7907, 0x7908, 0x7909, 0x790a, 0x790b, 0x790c, 0x7840, 0x50a8, 0x77d7, 0x6410, 0x89e6, 0x5904,
+    0x63e3, 0x5ddd, 0x7a7f, 0x693d, 0x4f20, 0x8239, 0x5598, 0x4e32, 0x75ae, 0x7a97, 0x5e62, 0x5e8a, 0x95ef, 0x521b, 0x5439, 0x708a,
+    0x6376, 0x9524, 0x5782, 0x6625, 0x693f, 0x9187, 0x5507, 0x6df3, 0x7eaf, 0x8822, 0x6233, 0x7ef0, 0x75b5, 0x8328, 0x78c1, 0x96cc,
+    0x8f9e, 0x6148, 0x74f7, 0x8bcd, 0x6b64, 0x523a, 0x8d50, 0x6b21, 0x806a, 0x8471, 0x56f1, 0x5306, 0x4ece, 0x4e1b, 0x51d1, 0x7c97,
+    0x918b, 0x7c07, 0x4fc3, 0x8e7f, 0x7be1, 0x7a9c, 0x6467, 0x5d14, 0x50ac, 0x8106, 0x7601, 0x7cb9, 0x6dec, 0x7fe0, 0x6751, 0x5b58,
+    0x5bf8, 0x78cb, 0x64ae, 0x6413, 0x63aa, 0x632b, 0x9519, 0x642d, 0x8fbe, 0x7b54, 0x7629, 0x6253, 0x5927, 0x5446, 0x6b79, 0x50a3,
+    0x6234, 0x5e26, 0x6b86, 0x4ee3, 0x8d37, 0x888b, 0x5f85, 0x902e, 0x790d, 0x790e, 0x790f, 0x7910, 0x7911, 0x7912, 0x7914, 0x7915,
+    0x7916, 0x7917, 0x7918, 0x7919, 0x791a, 0x791b, 0x791c, 0x791d, 0x791f, 0x7920, 0x7921, 0x7922, 0x7923, 0x7925, 0x7926, 0x7927,
+    0x7928, 0x7929, 0x792a, 0x792b, 0x792c, 0x792d, 0x792e, 0x792f, 0x7930, 0x7931, 0x7932, 0x7933, 0x7935, 0x7936, 0x7937, 0x7938,
+    0x7939, 0x793d, 0x793f, 0x7942, 0x7943, 0x7944, 0x7945, 0x7947, 0x794a, 0x794b, 0x794c, 0x794d, 0x794e, 0x794f, 0x7950, 0x7951,
+    0x7952, 0x7954, 0x7955, 0x7958, 0x7959, 0x7961, 0x7963, 0x7964, 0x7966, 0x7969, 0x796a, 0x796b, 0x796c, 0x796e, 0x7970, 0x7971,
+    0x7972, 0x7973, 0x7974, 0x7975, 0x7976, 0x7979, 0x797b, 0x797c, 0x797d, 0x797e, 0x797f, 0x7982, 0x7983, 0x7986, 0x7987, 0x7988,
+    0x7989, 0x798b, 0x798c, 0x798d, 0x798e, 0x7990, 0x7991, 0x7992, 0x6020, 0x803d, 0x62c5, 0x4e39, 0x5355, 0x90f8, 0x63b8, 0x80c6,
+    0x65e6, 0x6c2e, 0x4f46, 0x60ee, 0x6de1, 0x8bde, 0x5f39, 0x86cb, 0x5f53, 0x6321, 0x515a, 0x8361, 0x6863, 0x5200, 0x6363, 0x8e48,
+    0x5012, 0x5c9b, 0x7977, 0x5bfc, 0x5230, 0x7a3b, 0x60bc, 0x9053, 0x76d7, 0x5fb7, 0x5f97, 0x7684, 0x8e6c, 0x706f, 0x767b, 0x7b49,
+    0x77aa, 0x51f3, 0x9093, 0x5824, 0x4f4e, 0x6ef4, 0x8fea, 0x654c, 0x7b1b, 0x72c4, 0x6da4, 0x7fdf, 0x5ae1, 0x62b5, 0x5e95, 0x5730,
+    0x8482, 0x7b2c, 0x5e1d, 0x5f1f, 0x9012, 0x7f14, 0x98a0, 0x6382, 0x6ec7, 0x7898, 0x70b9, 0x5178, 0x975b, 0x57ab, 0x7535, 0x4f43,
+    0x7538, 0x5e97, 0x60e6, 0x5960, 0x6dc0, 0x6bbf, 0x7889, 0x53fc, 0x96d5, 0x51cb, 0x5201, 0x6389, 0x540a, 0x9493, 0x8c03, 0x8dcc,
+    0x7239, 0x789f, 0x8776, 0x8fed, 0x8c0d, 0x53e0, 0x7993, 0x7994, 0x7995, 0x7996, 0x7997, 0x7998, 0x7999, 0x799b, 0x799c, 0x799d,
+    0x799e, 0x799f, 0x79a0, 0x79a1, 0x79a2, 0x79a3, 0x79a4, 0x79a5, 0x79a6, 0x79a8, 0x79a9, 0x79aa, 0x79ab, 0x79ac, 0x79ad, 0x79ae,
+    0x79af, 0x79b0, 0x79b1, 0x79b2, 0x79b4, 0x79b5, 0x79b6, 0x79b7, 0x79b8, 0x79bc, 0x79bf, 0x79c2, 0x79c4, 0x79c5, 0x79c7, 0x79c8,
+    0x79ca, 0x79cc, 0x79ce, 0x79cf, 0x79d0, 0x79d3, 0x79d4, 0x79d6, 0x79d7, 0x79d9, 0x79da, 0x79db, 0x79dc, 0x79dd, 0x79de, 0x79e0,
+    0x79e1, 0x79e2, 0x79e5, 0x79e8, 0x79ea, 0x79ec, 0x79ee, 0x79f1, 0x79f2, 0x79f3, 0x79f4, 0x79f5, 0x79f6, 0x79f7, 0x79f9, 0x79fa,
+    0x79fc, 0x79fe, 0x79ff, 0x7a01, 0x7a04, 0x7a05, 0x7a07, 0x7a08, 0x7a09, 0x7a0a, 0x7a0c, 0x7a0f, 0x7a10, 0x7a11, 0x7a12, 0x7a13,
+    0x7a15, 0x7a16, 0x7a18, 0x7a19, 0x7a1b, 0x7a1c, 0x4e01, 0x76ef, 0x53ee, 0x9489, 0x9876, 0x9f0e, 0x952d, 0x5b9a, 0x8ba2, 0x4e22,
+    0x4e1c, 0x51ac, 0x8463, 0x61c2, 0x52a8, 0x680b, 0x4f97, 0x606b, 0x51bb, 0x6d1e, 0x515c, 0x6296, 0x6597, 0x9661, 0x8c46, 0x9017,
+    0x75d8, 0x90fd, 0x7763, 0x6bd2, 0x728a, 0x72ec, 0x8bfb, 0x5835, 0x7779, 0x8d4c, 0x675c, 0x9540, 0x809a, 0x5ea6, 0x6e21, 0x5992,
+    0x7aef, 0x77ed, 0x953b, 0x6bb5, 0x65ad, 0x7f0e, 0x5806, 0x5151, 0x961f, 0x5bf9, 0x58a9, 0x5428, 0x8e72, 0x6566, 0x987f, 0x56e4,
+    0x949d, 0x76fe, 0x9041, 0x6387, 0x54c6, 0x591a, 0x593a, 0x579b, 0x8eb2, 0x6735, 0x8dfa, 0x8235, 0x5241, 0x60f0, 0x5815, 0x86fe,
+    0x5ce8, 0x9e45, 0x4fc4, 0x989d, 0x8bb9, 0x5a25, 0x6076, 0x5384, 0x627c, 0x904f, 0x9102, 0x997f, 0x6069, 0x800c, 0x513f, 0x8033,
+    0x5c14, 0x9975, 0x6d31, 0x4e8c, 0x7a1d, 0x7a1f, 0x7a21, 0x7a22, 0x7a24, 0x7a25, 0x7a26, 0x7a27, 0x7a28, 0x7a29, 0x7a2a, 0x7a2b,
+    0x7a2c, 0x7a2d, 0x7a2e, 0x7a2f, 0x7a30, 0x7a31, 0x7a32, 0x7a34, 0x7a35, 0x7a36, 0x7a38, 0x7a3a, 0x7a3e, 0x7a40, 0x7a41, 0x7a42,
+    0x7a43, 0x7a44, 0x7a45, 0x7a47, 0x7a48, 0x7a49, 0x7a4a, 0x7a4b, 0x7a4c, 0x7a4d, 0x7a4e, 0x7a4f, 0x7a50, 0x7a52, 0x7a53, 0x7a54,
+    0x7a55, 0x7a56, 0x7a58, 0x7a59, 0x7a5a, 0x7a5b, 0x7a5c, 0x7a5d, 0x7a5e, 0x7a5f, 0x7a60, 0x7a61, 0x7a62, 0x7a63, 0x7a64, 0x7a65,
+    0x7a66, 0x7a67, 0x7a68, 0x7a69, 0x7a6a, 0x7a6b, 0x7a6c, 0x7a6d, 0x7a6e, 0x7a6f, 0x7a71, 0x7a72, 0x7a73, 0x7a75, 0x7a7b, 0x7a7c,
+    0x7a7d, 0x7a7e, 0x7a82, 0x7a85, 0x7a87, 0x7a89, 0x7a8a, 0x7a8b, 0x7a8c, 0x7a8e, 0x7a8f, 0x7a90, 0x7a93, 0x7a94, 0x7a99, 0x7a9a,
+    0x7a9b, 0x7a9e, 0x7aa1, 0x7aa2, 0x8d30, 0x53d1, 0x7f5a, 0x7b4f, 0x4f10, 0x4e4f, 0x9600, 0x6cd5, 0x73d0, 0x85e9, 0x5e06, 0x756a,
+    0x7ffb, 0x6a0a, 0x77fe, 0x9492, 0x7e41, 0x51e1, 0x70e6, 0x53cd, 0x8fd4, 0x8303, 0x8d29, 0x72af, 0x996d, 0x6cdb, 0x574a, 0x82b3,
+    0x65b9, 0x80aa, 0x623f, 0x9632, 0x59a8, 0x4eff, 0x8bbf, 0x7eba, 0x653e, 0x83f2, 0x975e, 0x5561, 0x98de, 0x80a5, 0x532a, 0x8bfd,
+    0x5420, 0x80ba, 0x5e9f, 0x6cb8, 0x8d39, 0x82ac, 0x915a, 0x5429, 0x6c1b, 0x5206, 0x7eb7, 0x575f, 0x711a, 0x6c7e, 0x7c89, 0x594b,
+    0x4efd, 0x5fff, 0x6124, 0x7caa, 0x4e30, 0x5c01, 0x67ab, 0x8702, 0x5cf0, 0x950b, 0x98ce, 0x75af, 0x70fd, 0x9022, 0x51af, 0x7f1d,
+    0x8bbd, 0x5949, 0x51e4, 0x4f5b, 0x5426, 0x592b, 0x6577, 0x80a4, 0x5b75, 0x6276, 0x62c2, 0x8f90, 0x5e45, 0x6c1f, 0x7b26, 0x4f0f,
+    0x4fd8, 0x670d, 0x7aa3, 0x7aa4, 0x7aa7, 0x7aa9, 0x7aaa, 0x7aab, 0x7aae, 0x7aaf, 0x7ab0, 0x7ab1, 0x7ab2, 0x7ab4, 0x7ab5, 0x7ab6,
+    0x7ab7, 0x7ab8, 0x7ab9, 0x7aba, 0x7abb, 0x7abc, 0x7abd, 0x7abe, 0x7ac0, 0x7ac1, 0x7ac2, 0x7ac3, 0x7ac4, 0x7ac5, 0x7ac6, 0x7ac7,
+    0x7ac8, 0x7ac9, 0x7aca, 0x7acc, 0x7acd, 0x7ace, 0x7acf, 0x7ad0, 0x7ad1, 0x7ad2, 0x7ad3, 0x7ad4, 0x7ad5, 0x7ad7, 0x7ad8, 0x7ada,
+    0x7adb, 0x7adc, 0x7add, 0x7ae1, 0x7ae2, 0x7ae4, 0x7ae7, 0x7ae8, 0x7ae9, 0x7aea, 0x7aeb, 0x7aec, 0x7aee, 0x7af0, 0x7af1, 0x7af2,
+    0x7af3, 0x7af4, 0x7af5, 0x7af6, 0x7af7, 0x7af8, 0x7afb, 0x7afc, 0x7afe, 0x7b00, 0x7b01, 0x7b02, 0x7b05, 0x7b07, 0x7b09, 0x7b0c,
+    0x7b0d, 0x7b0e, 0x7b10, 0x7b12, 0x7b13, 0x7b16, 0x7b17, 0x7b18, 0x7b1a, 0x7b1c, 0x7b1d, 0x7b1f, 0x7b21, 0x7b22, 0x7b23, 0x7b27,
+    0x7b29, 0x7b2d, 0x6d6e, 0x6daa, 0x798f, 0x88b1, 0x5f17, 0x752b, 0x629a, 0x8f85, 0x4fef, 0x91dc, 0x65a7, 0x812f, 0x8151, 0x5e9c,
+    0x8150, 0x8d74, 0x526f, 0x8986, 0x8d4b, 0x590d, 0x5085, 0x4ed8, 0x961c, 0x7236, 0x8179, 0x8d1f, 0x5bcc, 0x8ba3, 0x9644, 0x5987,
+    0x7f1a, 0x5490, 0x5676, 0x560e, 0x8be5, 0x6539, 0x6982, 0x9499, 0x76d6, 0x6e89, 0x5e72, 0x7518, 0x6746, 0x67d1, 0x7aff, 0x809d,
+    0x8d76, 0x611f, 0x79c6, 0x6562, 0x8d63, 0x5188, 0x521a, 0x94a2, 0x7f38, 0x809b, 0x7eb2, 0x5c97, 0x6e2f, 0x6760, 0x7bd9, 0x768b,
+    0x9ad8, 0x818f, 0x7f94, 0x7cd5, 0x641e, 0x9550, 0x7a3f, 0x544a, 0x54e5, 0x6b4c, 0x6401, 0x6208, 0x9e3d, 0x80f3, 0x7599, 0x5272,
+    0x9769, 0x845b, 0x683c, 0x86e4, 0x9601, 0x9694, 0x94ec, 0x4e2a, 0x5404, 0x7ed9, 0x6839, 0x8ddf, 0x8015, 0x66f4, 0x5e9a, 0x7fb9,
+    0x7b2f, 0x7b30, 0x7b32, 0x7b34, 0x7b35, 0x7b36, 0x7b37, 0x7b39, 0x7b3b, 0x7b3d, 0x7b3f, 0x7b40, 0x7b41, 0x7b42, 0x7b43, 0x7b44,
+    0x7b46, 0x7b48, 0x7b4a, 0x7b4d, 0x7b4e, 0x7b53, 0x7b55, 0x7b57, 0x7b59, 0x7b5c, 0x7b5e, 0x7b5f, 0x7b61, 0x7b63, 0x7b64, 0x7b65,
+    0x7b66, 0x7b67, 0x7b68, 0x7b69, 0x7b6a, 0x7b6b, 0x7b6c, 0x7b6d, 0x7b6f, 0x7b70, 0x7b73, 0x7b74, 0x7b76, 0x7b78, 0x7b7a, 0x7b7c,
+    0x7b7d, 0x7b7f, 0x7b81, 0x7b82, 0x7b83, 0x7b84, 0x7b86, 0x7b87, 0x7b88, 0x7b89, 0x7b8a, 0x7b8b, 0x7b8c, 0x7b8e, 0x7b8f, 0x7b91,
+    0x7b92, 0x7b93, 0x7b96, 0x7b98, 0x7b99, 0x7b9a, 0x7b9b, 0x7b9e, 0x7b9f, 0x7ba0, 0x7ba3, 0x7ba4, 0x7ba5, 0x7bae, 0x7baf, 0x7bb0,
+    0x7bb2, 0x7bb3, 0x7bb5, 0x7bb6, 0x7bb7, 0x7bb9, 0x7bba, 0x7bbb, 0x7bbc, 0x7bbd, 0x7bbe, 0x7bbf, 0x7bc0, 0x7bc2, 0x7bc3, 0x7bc4,
+    0x57c2, 0x803f, 0x6897, 0x5de5, 0x653b, 0x529f, 0x606d, 0x9f9a, 0x4f9b, 0x8eac, 0x516c, 0x5bab, 0x5f13, 0x5de9, 0x6c5e, 0x62f1,
+    0x8d21, 0x5171, 0x94a9, 0x52fe, 0x6c9f, 0x82df, 0x72d7, 0x57a2, 0x6784, 0x8d2d, 0x591f, 0x8f9c, 0x83c7, 0x5495, 0x7b8d, 0x4f30,
+    0x6cbd, 0x5b64, 0x59d1, 0x9f13, 0x53e4, 0x86ca, 0x9aa8, 0x8c37, 0x80a1, 0x6545, 0x987e, 0x56fa, 0x96c7, 0x522e, 0x74dc, 0x5250,
+    0x5be1, 0x6302, 0x8902, 0x4e56, 0x62d0, 0x602a, 0x68fa, 0x5173, 0x5b98, 0x51a0, 0x89c2, 0x7ba1, 0x9986, 0x7f50, 0x60ef, 0x704c,
+    0x8d2f, 0x5149, 0x5e7f, 0x901b, 0x7470, 0x89c4, 0x572d, 0x7845, 0x5f52, 0x9f9f, 0x95fa, 0x8f68, 0x9b3c, 0x8be1, 0x7678, 0x6842,
+    0x67dc, 0x8dea, 0x8d35, 0x523d, 0x8f8a, 0x6eda, 0x68cd, 0x9505, 0x90ed, 0x56fd, 0x679c, 0x88f9, 0x8fc7, 0x54c8, 0x7bc5, 0x7bc8,
+    0x7bc9, 0x7bca, 0x7bcb, 0x7bcd, 0x7bce, 0x7bcf, 0x7bd0, 0x7bd2, 0x7bd4, 0x7bd5, 0x7bd6, 0x7bd7, 0x7bd8, 0x7bdb, 0x7bdc, 0x7bde,
+    0x7bdf, 0x7be0, 0x7be2, 0x7be3, 0x7be4, 0x7be7, 0x7be8, 0x7be9, 0x7beb, 0x7bec, 0x7bed, 0x7bef, 0x7bf0, 0x7bf2, 0x7bf3, 0x7bf4,
+    0x7bf5, 0x7bf6, 0x7bf8, 0x7bf9, 0x7bfa, 0x7bfb, 0x7bfd, 0x7bff, 0x7c00, 0x7c01, 0x7c02, 0x7c03, 0x7c04, 0x7c05, 0x7c06, 0x7c08,
+    0x7c09, 0x7c0a, 0x7c0d, 0x7c0e, 0x7c10, 0x7c11, 0x7c12, 0x7c13, 0x7c14, 0x7c15, 0x7c17, 0x7c18, 0x7c19, 0x7c1a, 0x7c1b, 0x7c1c,
+    0x7c1d, 0x7c1e, 0x7c20, 0x7c21, 0x7c22, 0x7c23, 0x7c24, 0x7c25, 0x7c28, 0x7c29, 0x7c2b, 0x7c2c, 0x7c2d, 0x7c2e, 0x7c2f, 0x7c30,
+    0x7c31, 0x7c32, 0x7c33, 0x7c34, 0x7c35, 0x7c36, 0x7c37, 0x7c39, 0x7c3a, 0x7c3b, 0x7c3c, 0x7c3d, 0x7c3e, 0x7c42, 0x9ab8, 0x5b69,
+    0x6d77, 0x6c26, 0x4ea5, 0x5bb3, 0x9a87, 0x9163, 0x61a8, 0x90af, 0x97e9, 0x542b, 0x6db5, 0x5bd2, 0x51fd, 0x558a, 0x7f55, 0x7ff0,
+    0x64bc, 0x634d, 0x65f1, 0x61be, 0x608d, 0x710a, 0x6c57, 0x6c49, 0x592f, 0x676d, 0x822a, 0x58d5, 0x568e, 0x8c6a, 0x6beb, 0x90dd,
+    0x597d, 0x8017, 0x53f7, 0x6d69, 0x5475, 0x559d, 0x8377, 0x83cf, 0x6838, 0x79be, 0x548c, 0x4f55, 0x5408, 0x76d2, 0x8c89, 0x9602,
+    0x6cb3, 0x6db8, 0x8d6b, 0x8910, 0x9e64, 0x8d3a, 0x563f, 0x9ed1, 0x75d5, 0x5f88, 0x72e0, 0x6068, 0x54fc, 0x4ea8, 0x6a2a, 0x8861,
+    0x6052, 0x8f70, 0x54c4, 0x70d8, 0x8679, 0x9e3f, 0x6d2a, 0x5b8f, 0x5f18, 0x7ea2, 0x5589, 0x4faf, 0x7334, 0x543c, 0x539a, 0x5019,
+    0x540e, 0x547c, 0x4e4e, 0x5ffd, 0x745a, 0x58f6, 0x846b, 0x80e1, 0x8774, 0x72d0, 0x7cca, 0x6e56, 0x7c43, 0x7c44, 0x7c45, 0x7c46,
+    0x7c47, 0x7c48, 0x7c49, 0x7c4a, 0x7c4b, 0x7c4c, 0x7c4e, 0x7c4f, 0x7c50, 0x7c51, 0x7c52, 0x7c53, 0x7c54, 0x7c55, 0x7c56, 0x7c57,
+    0x7c58, 0x7c59, 0x7c5a, 0x7c5b, 0x7c5c, 0x7c5d, 0x7c5e, 0x7c5f, 0x7c60, 0x7c61, 0x7c62, 0x7c63, 0x7c64, 0x7c65, 0x7c66, 0x7c67,
+    0x7c68, 0x7c69, 0x7c6a, 0x7c6b, 0x7c6c, 0x7c6d, 0x7c6e, 0x7c6f, 0x7c70, 0x7c71, 0x7c72, 0x7c75, 0x7c76, 0x7c77, 0x7c78, 0x7c79,
+    0x7c7a, 0x7c7e, 0x7c7f, 0x7c80, 0x7c81, 0x7c82, 0x7c83, 0x7c84, 0x7c85, 0x7c86, 0x7c87, 0x7c88, 0x7c8a, 0x7c8b, 0x7c8c, 0x7c8d,
+    0x7c8e, 0x7c8f, 0x7c90, 0x7c93, 0x7c94, 0x7c96, 0x7c99, 0x7c9a, 0x7c9b, 0x7ca0, 0x7ca1, 0x7ca3, 0x7ca6, 0x7ca7, 0x7ca8, 0x7ca9,
+    0x7cab, 0x7cac, 0x7cad, 0x7caf, 0x7cb0, 0x7cb4, 0x7cb5, 0x7cb6, 0x7cb7, 0x7cb8, 0x7cba, 0x7cbb, 0x5f27, 0x864e, 0x552c, 0x62a4,
+    0x4e92, 0x6caa, 0x6237, 0x82b1, 0x54d7, 0x534e, 0x733e, 0x6ed1, 0x753b, 0x5212, 0x5316, 0x8bdd, 0x69d0, 0x5f8a, 0x6000, 0x6dee,
+    0x574f, 0x6b22, 0x73af, 0x6853, 0x8fd8, 0x7f13, 0x6362, 0x60a3, 0x5524, 0x75ea, 0x8c62, 0x7115, 0x6da3, 0x5ba6, 0x5e7b, 0x8352,
+    0x614c, 0x9ec4, 0x78fa, 0x8757, 0x7c27, 0x7687, 0x51f0, 0x60f6, 0x714c, 0x6643, 0x5e4c, 0x604d, 0x8c0e, 0x7070, 0x6325, 0x8f89,
+    0x5fbd, 0x6062, 0x86d4, 0x56de, 0x6bc1, 0x6094, 0x6167, 0x5349, 0x60e0, 0x6666, 0x8d3f, 0x79fd, 0x4f1a, 0x70e9, 0x6c47, 0x8bb3,
+    0x8bf2, 0x7ed8, 0x8364, 0x660f, 0x5a5a, 0x9b42, 0x6d51, 0x6df7, 0x8c41, 0x6d3b, 0x4f19, 0x706b, 0x83b7, 0x6216, 0x60d1, 0x970d,
+    0x8d27, 0x7978, 0x51fb, 0x573e, 0x57fa, 0x673a, 0x7578, 0x7a3d, 0x79ef, 0x7b95, 0x7cbf, 0x7cc0, 0x7cc2, 0x7cc3, 0x7cc4, 0x7cc6,
+    0x7cc9, 0x7ccb, 0x7cce, 0x7ccf, 0x7cd0, 0x7cd1, 0x7cd2, 0x7cd3, 0x7cd4, 0x7cd8, 0x7cda, 0x7cdb, 0x7cdd, 0x7cde, 0x7ce1, 0x7ce2,
+    0x7ce3, 0x7ce4, 0x7ce5, 0x7ce6, 0x7ce7, 0x7ce9, 0x7cea, 0x7ceb, 0x7cec, 0x7ced, 0x7cee, 0x7cf0, 0x7cf1, 0x7cf2, 0x7cf3, 0x7cf4,
+    0x7cf5, 0x7cf6, 0x7cf7, 0x7cf9, 0x7cfa, 0x7cfc, 0x7cfd, 0x7cfe, 0x7cff, 0x7d00, 0x7d01, 0x7d02, 0x7d03, 0x7d04, 0x7d05, 0x7d06,
+    0x7d07, 0x7d08, 0x7d09, 0x7d0b, 0x7d0c, 0x7d0d, 0x7d0e, 0x7d0f, 0x7d10, 0x7d11, 0x7d12, 0x7d13, 0x7d14, 0x7d15, 0x7d16, 0x7d17,
+    0x7d18, 0x7d19, 0x7d1a, 0x7d1b, 0x7d1c, 0x7d1d, 0x7d1e, 0x7d1f, 0x7d21, 0x7d23, 0x7d24, 0x7d25, 0x7d26, 0x7d28, 0x7d29, 0x7d2a,
+    0x7d2c, 0x7d2d, 0x7d2e, 0x7d30, 0x7d31, 0x7d32, 0x7d33, 0x7d34, 0x7d35, 0x7d36, 0x808c, 0x9965, 0x8ff9, 0x6fc0, 0x8ba5, 0x9e21,
+    0x59ec, 0x7ee9, 0x7f09, 0x5409, 0x6781, 0x68d8, 0x8f91, 0x7c4d, 0x96c6, 0x53ca, 0x6025, 0x75be, 0x6c72, 0x5373, 0x5ac9, 0x7ea7,
+    0x6324, 0x51e0, 0x810a, 0x5df1, 0x84df, 0x6280, 0x5180, 0x5b63, 0x4f0e, 0x796d, 0x5242, 0x60b8, 0x6d4e, 0x5bc4, 0x5bc2, 0x8ba1,
+    0x8bb0, 0x65e2, 0x5fcc, 0x9645, 0x5993, 0x7ee7, 0x7eaa, 0x5609, 0x67b7, 0x5939, 0x4f73, 0x5bb6, 0x52a0, 0x835a, 0x988a, 0x8d3e,
+    0x7532, 0x94be, 0x5047, 0x7a3c, 0x4ef7, 0x67b6, 0x9a7e, 0x5ac1, 0x6b7c, 0x76d1, 0x575a, 0x5c16, 0x7b3a, 0x95f4, 0x714e, 0x517c,
+    0x80a9, 0x8270, 0x5978, 0x7f04, 0x8327, 0x68c0, 0x67ec, 0x78b1, 0x7877, 0x62e3, 0x6361, 0x7b80, 0x4fed, 0x526a, 0x51cf, 0x8350,
+    0x69db, 0x9274, 0x8df5, 0x8d31, 0x89c1, 0x952e, 0x7bad, 0x4ef6, 0x7d37, 0x7d38, 0x7d39, 0x7d3a, 0x7d3b, 0x7d3c, 0x7d3d, 0x7d3e,
+    0x7d3f, 0x7d40, 0x7d41, 0x7d42, 0x7d43, 0x7d44, 0x7d45, 0x7d46, 0x7d47, 0x7d48, 0x7d49, 0x7d4a, 0x7d4b, 0x7d4c, 0x7d4d, 0x7d4e,
+    0x7d4f, 0x7d50, 0x7d51, 0x7d52, 0x7d53, 0x7d54, 0x7d55, 0x7d56, 0x7d57, 0x7d58, 0x7d59, 0x7d5a, 0x7d5b, 0x7d5c, 0x7d5d, 0x7d5e,
+    0x7d5f, 0x7d60, 0x7d61, 0x7d62, 0x7d63, 0x7d64, 0x7d65, 0x7d66, 0x7d67, 0x7d68, 0x7d69, 0x7d6a, 0x7d6b, 0x7d6c, 0x7d6d, 0x7d6f,
+    0x7d70, 0x7d71, 0x7d72, 0x7d73, 0x7d74, 0x7d75, 0x7d76, 0x7d78, 0x7d79, 0x7d7a, 0x7d7b, 0x7d7c, 0x7d7d, 0x7d7e, 0x7d7f, 0x7d80,
+    0x7d81, 0x7d82, 0x7d83, 0x7d84, 0x7d85, 0x7d86, 0x7d87, 0x7d88, 0x7d89, 0x7d8a, 0x7d8b, 0x7d8c, 0x7d8d, 0x7d8e, 0x7d8f, 0x7d90,
+    0x7d91, 0x7d92, 0x7d93, 0x7d94, 0x7d95, 0x7d96, 0x7d97, 0x7d98, 0x5065, 0x8230, 0x5251, 0x996f, 0x6e10, 0x6e85, 0x6da7, 0x5efa,
+    0x50f5, 0x59dc, 0x5c06, 0x6d46, 0x6c5f, 0x7586, 0x848b, 0x6868, 0x5956, 0x8bb2, 0x5320, 0x9171, 0x964d, 0x8549, 0x6912, 0x7901,
+    0x7126, 0x80f6, 0x4ea4, 0x90ca, 0x6d47, 0x9a84, 0x5a07, 0x56bc, 0x6405, 0x94f0, 0x77eb, 0x4fa5, 0x811a, 0x72e1, 0x89d2, 0x997a,
+    0x7f34, 0x7ede, 0x527f, 0x6559, 0x9175, 0x8f7f, 0x8f83, 0x53eb, 0x7a96, 0x63ed, 0x63a5, 0x7686, 0x79f8, 0x8857, 0x9636, 0x622a,
+    0x52ab, 0x8282, 0x6854, 0x6770, 0x6377, 0x776b, 0x7aed, 0x6d01, 0x7ed3, 0x89e3, 0x59d0, 0x6212, 0x85c9, 0x82a5, 0x754c, 0x501f,
+    0x4ecb, 0x75a5, 0x8beb, 0x5c4a, 0x5dfe, 0x7b4b, 0x65a4, 0x91d1, 0x4eca, 0x6d25, 0x895f, 0x7d27, 0x9526, 0x4ec5, 0x8c28, 0x8fdb,
+    0x9773, 0x664b, 0x7981, 0x8fd1, 0x70ec, 0x6d78, 0x7d99, 0x7d9a, 0x7d9b, 0x7d9c, 0x7d9d, 0x7d9e, 0x7d9f, 0x7da0, 0x7da1, 0x7da2,
+    0x7da3, 0x7da4, 0x7da5, 0x7da7, 0x7da8, 0x7da9, 0x7daa, 0x7dab, 0x7dac, 0x7dad, 0x7daf, 0x7db0, 0x7db1, 0x7db2, 0x7db3, 0x7db4,
+    0x7db5, 0x7db6, 0x7db7, 0x7db8, 0x7db9, 0x7dba, 0x7dbb, 0x7dbc, 0x7dbd, 0x7dbe, 0x7dbf, 0x7dc0, 0x7dc1, 0x7dc2, 0x7dc3, 0x7dc4,
+    0x7dc5, 0x7dc6, 0x7dc7, 0x7dc8, 0x7dc9, 0x7dca, 0x7dcb, 0x7dcc, 0x7dcd, 0x7dce, 0x7dcf, 0x7dd0, 0x7dd1, 0x7dd2, 0x7dd3, 0x7dd4,
+    0x7dd5, 0x7dd6, 0x7dd7, 0x7dd8, 0x7dd9, 0x7dda, 0x7ddb, 0x7ddc, 0x7ddd, 0x7dde, 0x7ddf, 0x7de0, 0x7de1, 0x7de2, 0x7de3, 0x7de4,
+    0x7de5, 0x7de6, 0x7de7, 0x7de8, 0x7de9, 0x7dea, 0x7deb, 0x7dec, 0x7ded, 0x7dee, 0x7def, 0x7df0, 0x7df1, 0x7df2, 0x7df3, 0x7df4,
+    0x7df5, 0x7df6, 0x7df7, 0x7df8, 0x7df9, 0x7dfa, 0x5c3d, 0x52b2, 0x8346, 0x5162, 0x830e, 0x775b, 0x6676, 0x9cb8, 0x4eac, 0x60ca,
+    0x7cbe, 0x7cb3, 0x7ecf, 0x4e95, 0x8b66, 0x666f, 0x9888, 0x9759, 0x5883, 0x656c, 0x955c, 0x5f84, 0x75c9, 0x9756, 0x7adf, 0x7ade,
+    0x51c0, 0x70af, 0x7a98, 0x63ea, 0x7a76, 0x7ea0, 0x7396, 0x97ed, 0x4e45, 0x7078, 0x4e5d, 0x9152, 0x53a9, 0x6551, 0x65e7, 0x81fc,
+    0x8205, 0x548e, 0x5c31, 0x759a, 0x97a0, 0x62d8, 0x72d9, 0x75bd, 0x5c45, 0x9a79, 0x83ca, 0x5c40, 0x5480, 0x77e9, 0x4e3e, 0x6cae,
+    0x805a, 0x62d2, 0x636e, 0x5de8, 0x5177, 0x8ddd, 0x8e1e, 0x952f, 0x4ff1, 0x53e5, 0x60e7, 0x70ac, 0x5267, 0x6350, 0x9e43, 0x5a1f,
+    0x5026, 0x7737, 0x5377, 0x7ee2, 0x6485, 0x652b, 0x6289, 0x6398, 0x5014, 0x7235, 0x89c9, 0x51b3, 0x8bc0, 0x7edd, 0x5747, 0x83cc,
+    0x94a7, 0x519b, 0x541b, 0x5cfb, 0x7dfb, 0x7dfc, 0x7dfd, 0x7dfe, 0x7dff, 0x7e00, 0x7e01, 0x7e02, 0x7e03, 0x7e04, 0x7e05, 0x7e06,
+    0x7e07, 0x7e08, 0x7e09, 0x7e0a, 0x7e0b, 0x7e0c, 0x7e0d, 0x7e0e, 0x7e0f, 0x7e10, 0x7e11, 0x7e12, 0x7e13, 0x7e14, 0x7e15, 0x7e16,
+    0x7e17, 0x7e18, 0x7e19, 0x7e1a, 0x7e1b, 0x7e1c, 0x7e1d, 0x7e1e, 0x7e1f, 0x7e20, 0x7e21, 0x7e22, 0x7e23, 0x7e24, 0x7e25, 0x7e26,
+    0x7e27, 0x7e28, 0x7e29, 0x7e2a, 0x7e2b, 0x7e2c, 0x7e2d, 0x7e2e, 0x7e2f, 0x7e30, 0x7e31, 0x7e32, 0x7e33, 0x7e34, 0x7e35, 0x7e36,
+    0x7e37, 0x7e38, 0x7e39, 0x7e3a, 0x7e3c, 0x7e3d, 0x7e3e, 0x7e3f, 0x7e40, 0x7e42, 0x7e43, 0x7e44, 0x7e45, 0x7e46, 0x7e48, 0x7e49,
+    0x7e4a, 0x7e4b, 0x7e4c, 0x7e4d, 0x7e4e, 0x7e4f, 0x7e50, 0x7e51, 0x7e52, 0x7e53, 0x7e54, 0x7e55, 0x7e56, 0x7e57, 0x7e58, 0x7e59,
+    0x7e5a, 0x7e5b, 0x7e5c, 0x7e5d, 0x4fca, 0x7ae3, 0x6d5a, 0x90e1, 0x9a8f, 0x5580, 0x5496, 0x5361, 0x54af, 0x5f00, 0x63e9, 0x6977,
+    0x51ef, 0x6168, 0x520a, 0x582a, 0x52d8, 0x574e, 0x780d, 0x770b, 0x5eb7, 0x6177, 0x7ce0, 0x625b, 0x6297, 0x4ea2, 0x7095, 0x8003,
+    0x62f7, 0x70e4, 0x9760, 0x5777, 0x82db, 0x67ef, 0x68f5, 0x78d5, 0x9897, 0x79d1, 0x58f3, 0x54b3, 0x53ef, 0x6e34, 0x514b, 0x523b,
+    0x5ba2, 0x8bfe, 0x80af, 0x5543, 0x57a6, 0x6073, 0x5751, 0x542d, 0x7a7a, 0x6050, 0x5b54, 0x63a7, 0x62a0, 0x53e3, 0x6263, 0x5bc7,
+    0x67af, 0x54ed, 0x7a9f, 0x82e6, 0x9177, 0x5e93, 0x88e4, 0x5938, 0x57ae, 0x630e, 0x8de8, 0x80ef, 0x5757, 0x7b77, 0x4fa9, 0x5feb,
+    0x5bbd, 0x6b3e, 0x5321, 0x7b50, 0x72c2, 0x6846, 0x77ff, 0x7736, 0x65f7, 0x51b5, 0x4e8f, 0x76d4, 0x5cbf, 0x7aa5, 0x8475, 0x594e,
+    0x9b41, 0x5080, 0x7e5e, 0x7e5f, 0x7e60, 0x7e61, 0x7e62, 0x7e63, 0x7e64, 0x7e65, 0x7e66, 0x7e67, 0x7e68, 0x7e69, 0x7e6a, 0x7e6b,
+    0x7e6c, 0x7e6d, 0x7e6e, 0x7e6f, 0x7e70, 0x7e71, 0x7e72, 0x7e73, 0x7e74, 0x7e75, 0x7e76, 0x7e77, 0x7e78, 0x7e79, 0x7e7a, 0x7e7b,
+    0x7e7c, 0x7e7d, 0x7e7e, 0x7e7f, 0x7e80, 0x7e81, 0x7e83, 0x7e84, 0x7e85, 0x7e86, 0x7e87, 0x7e88, 0x7e89, 0x7e8a, 0x7e8b, 0x7e8c,
+    0x7e8d, 0x7e8e, 0x7e8f, 0x7e90, 0x7e91, 0x7e92, 0x7e93, 0x7e94, 0x7e95, 0x7e96, 0x7e97, 0x7e98, 0x7e99, 0x7e9a, 0x7e9c, 0x7e9d,
+    0x7e9e, 0x7eae, 0x7eb4, 0x7ebb, 0x7ebc, 0x7ed6, 0x7ee4, 0x7eec, 0x7ef9, 0x7f0a, 0x7f10, 0x7f1e, 0x7f37, 0x7f39, 0x7f3b, 0x7f3c,
+    0x7f3d, 0x7f3e, 0x7f3f, 0x7f40, 0x7f41, 0x7f43, 0x7f46, 0x7f47, 0x7f48, 0x7f49, 0x7f4a, 0x7f4b, 0x7f4c, 0x7f4d, 0x7f4e, 0x7f4f,
+    0x7f52, 0x7f53, 0x9988, 0x6127, 0x6e83, 0x5764, 0x6606, 0x6346, 0x56f0, 0x62ec, 0x6269, 0x5ed3, 0x9614, 0x5783, 0x62c9, 0x5587,
+    0x8721, 0x814a, 0x8fa3, 0x5566, 0x83b1, 0x6765, 0x8d56, 0x84dd, 0x5a6a, 0x680f, 0x62e6, 0x7bee, 0x9611, 0x5170, 0x6f9c, 0x8c30,
+    0x63fd, 0x89c8, 0x61d2, 0x7f06, 0x70c2, 0x6ee5, 0x7405, 0x6994, 0x72fc, 0x5eca, 0x90ce, 0x6717, 0x6d6a, 0x635e, 0x52b3, 0x7262,
+    0x8001, 0x4f6c, 0x59e5, 0x916a, 0x70d9, 0x6d9d, 0x52d2, 0x4e50, 0x96f7, 0x956d, 0x857e, 0x78ca, 0x7d2f, 0x5121, 0x5792, 0x64c2,
+    0x808b, 0x7c7b, 0x6cea, 0x68f1, 0x695e, 0x51b7, 0x5398, 0x68a8, 0x7281, 0x9ece, 0x7bf1, 0x72f8, 0x79bb, 0x6f13, 0x7406, 0x674e,
+    0x91cc, 0x9ca4, 0x793c, 0x8389, 0x8354, 0x540f, 0x6817, 0x4e3d, 0x5389, 0x52b1, 0x783e, 0x5386, 0x5229, 0x5088, 0x4f8b, 0x4fd0,
+    0x7f56, 0x7f59, 0x7f5b, 0x7f5c, 0x7f5d, 0x7f5e, 0x7f60, 0x7f63, 0x7f64, 0x7f65, 0x7f66, 0x7f67, 0x7f6b, 0x7f6c, 0x7f6d, 0x7f6f,
+    0x7f70, 0x7f73, 0x7f75, 0x7f76, 0x7f77, 0x7f78, 0x7f7a, 0x7f7b, 0x7f7c, 0x7f7d, 0x7f7f, 0x7f80, 0x7f82, 0x7f83, 0x7f84, 0x7f85,
+    0x7f86, 0x7f87, 0x7f88, 0x7f89, 0x7f8b, 0x7f8d, 0x7f8f, 0x7f90, 0x7f91, 0x7f92, 0x7f93, 0x7f95, 0x7f96, 0x7f97, 0x7f98, 0x7f99,
+    0x7f9b, 0x7f9c, 0x7fa0, 0x7fa2, 0x7fa3, 0x7fa5, 0x7fa6, 0x7fa8, 0x7fa9, 0x7faa, 0x7fab, 0x7fac, 0x7fad, 0x7fae, 0x7fb1, 0x7fb3,
+    0x7fb4, 0x7fb5, 0x7fb6, 0x7fb7, 0x7fba, 0x7fbb, 0x7fbe, 0x7fc0, 0x7fc2, 0x7fc3, 0x7fc4, 0x7fc6, 0x7fc7, 0x7fc8, 0x7fc9, 0x7fcb,
+    0x7fcd, 0x7fcf, 0x7fd0, 0x7fd1, 0x7fd2, 0x7fd3, 0x7fd6, 0x7fd7, 0x7fd9, 0x7fda, 0x7fdb, 0x7fdc, 0x7fdd, 0x7fde, 0x7fe2, 0x7fe3,
+    0x75e2, 0x7acb, 0x7c92, 0x6ca5, 0x96b6, 0x529b, 0x7483, 0x54e9, 0x4fe9, 0x8054, 0x83b2, 0x8fde, 0x9570, 0x5ec9, 0x601c, 0x6d9f,
+    0x5e18, 0x655b, 0x8138, 0x94fe, 0x604b, 0x70bc, 0x7ec3, 0x7cae, 0x51c9, 0x6881, 0x7cb1, 0x826f, 0x4e24, 0x8f86, 0x91cf, 0x667e,
+    0x4eae, 0x8c05, 0x64a9, 0x804a, 0x50da, 0x7597, 0x71ce, 0x5be5, 0x8fbd, 0x6f66, 0x4e86, 0x6482, 0x9563, 0x5ed6, 0x6599, 0x5217,
+    0x88c2, 0x70c8, 0x52a3, 0x730e, 0x7433, 0x6797, 0x78f7, 0x9716, 0x4e34, 0x90bb, 0x9cde, 0x6dcb, 0x51db, 0x8d41, 0x541d, 0x62ce,
+    0x73b2, 0x83f1, 0x96f6, 0x9f84, 0x94c3, 0x4f36, 0x7f9a, 0x51cc, 0x7075, 0x9675, 0x5cad, 0x9886, 0x53e6, 0x4ee4, 0x6e9c, 0x7409,
+    0x69b4, 0x786b, 0x998f, 0x7559, 0x5218, 0x7624, 0x6d41, 0x67f3, 0x516d, 0x9f99, 0x804b, 0x5499, 0x7b3c, 0x7abf, 0x7fe4, 0x7fe7,
+    0x7fe8, 0x7fea, 0x7feb, 0x7fec, 0x7fed, 0x7fef, 0x7ff2, 0x7ff4, 0x7ff5, 0x7ff6, 0x7ff7, 0x7ff8, 0x7ff9, 0x7ffa, 0x7ffd, 0x7ffe,
+    0x7fff, 0x8002, 0x8007, 0x8008, 0x8009, 0x800a, 0x800e, 0x800f, 0x8011, 0x8013, 0x801a, 0x801b, 0x801d, 0x801e, 0x801f, 0x8021,
+    0x8023, 0x8024, 0x802b, 0x802c, 0x802d, 0x802e, 0x802f, 0x8030, 0x8032, 0x8034, 0x8039, 0x803a, 0x803c, 0x803e, 0x8040, 0x8041,
+    0x8044, 0x8045, 0x8047, 0x8048, 0x8049, 0x804e, 0x804f, 0x8050, 0x8051, 0x8053, 0x8055, 0x8056, 0x8057, 0x8059, 0x805b, 0x805c,
+    0x805d, 0x805e, 0x805f, 0x8060, 0x8061, 0x8062, 0x8063, 0x8064, 0x8065, 0x8066, 0x8067, 0x8068, 0x806b, 0x806c, 0x806d, 0x806e,
+    0x806f, 0x8070, 0x8072, 0x8073, 0x8074, 0x8075, 0x8076, 0x8077, 0x8078, 0x8079, 0x807a, 0x807b, 0x807c, 0x807d, 0x9686, 0x5784,
+    0x62e2, 0x9647, 0x697c, 0x5a04, 0x6402, 0x7bd3, 0x6f0f, 0x964b, 0x82a6, 0x5362, 0x9885, 0x5e90, 0x7089, 0x63b3, 0x5364, 0x864f,
+    0x9c81, 0x9e93, 0x788c, 0x9732, 0x8def, 0x8d42, 0x9e7f, 0x6f5e, 0x7984, 0x5f55, 0x9646, 0x622e, 0x9a74, 0x5415, 0x94dd, 0x4fa3,
+    0x65c5, 0x5c65, 0x5c61, 0x7f15, 0x8651, 0x6c2f, 0x5f8b, 0x7387, 0x6ee4, 0x7eff, 0x5ce6, 0x631b, 0x5b6a, 0x6ee6, 0x5375, 0x4e71,
+    0x63a0, 0x7565, 0x62a1, 0x8f6e, 0x4f26, 0x4ed1, 0x6ca6, 0x7eb6, 0x8bba, 0x841d, 0x87ba, 0x7f57, 0x903b, 0x9523, 0x7ba9, 0x9aa1,
+    0x88f8, 0x843d, 0x6d1b, 0x9a86, 0x7edc, 0x5988, 0x9ebb, 0x739b, 0x7801, 0x8682, 0x9a6c, 0x9a82, 0x561b, 0x5417, 0x57cb, 0x4e70,
+    0x9ea6, 0x5356, 0x8fc8, 0x8109, 0x7792, 0x9992, 0x86ee, 0x6ee1, 0x8513, 0x66fc, 0x6162, 0x6f2b, 0x807e, 0x8081, 0x8082, 0x8085,
+    0x8088, 0x808a, 0x808d, 0x808e, 0x808f, 0x8090, 0x8091, 0x8092, 0x8094, 0x8095, 0x8097, 0x8099, 0x809e, 0x80a3, 0x80a6, 0x80a7,
+    0x80a8, 0x80ac, 0x80b0, 0x80b3, 0x80b5, 0x80b6, 0x80b8, 0x80b9, 0x80bb, 0x80c5, 0x80c7, 0x80c8, 0x80c9, 0x80ca, 0x80cb, 0x80cf,
+    0x80d0, 0x80d1, 0x80d2, 0x80d3, 0x80d4, 0x80d5, 0x80d8, 0x80df, 0x80e0, 0x80e2, 0x80e3, 0x80e6, 0x80ee, 0x80f5, 0x80f7, 0x80f9,
+    0x80fb, 0x80fe, 0x80ff, 0x8100, 0x8101, 0x8103, 0x8104, 0x8105, 0x8107, 0x8108, 0x810b, 0x810c, 0x8115, 0x8117, 0x8119, 0x811b,
+    0x811c, 0x811d, 0x811f, 0x8120, 0x8121, 0x8122, 0x8123, 0x8124, 0x8125, 0x8126, 0x8127, 0x8128, 0x8129, 0x812a, 0x812b, 0x812d,
+    0x812e, 0x8130, 0x8133, 0x8134, 0x8135, 0x8137, 0x8139, 0x813a, 0x813b, 0x813c, 0x813d, 0x813f, 0x8c29, 0x8292, 0x832b, 0x76f2,
+    0x6c13, 0x5fd9, 0x83bd, 0x732b, 0x8305, 0x951a, 0x6bdb, 0x77db, 0x94c6, 0x536f, 0x8302, 0x5192, 0x5e3d, 0x8c8c, 0x8d38, 0x4e48,
+    0x73ab, 0x679a, 0x6885, 0x9176, 0x9709, 0x7164, 0x6ca1, 0x7709, 0x5a92, 0x9541, 0x6bcf, 0x7f8e, 0x6627, 0x5bd0, 0x59b9, 0x5a9a,
+    0x95e8, 0x95f7, 0x4eec, 0x840c, 0x8499, 0x6aac, 0x76df, 0x9530, 0x731b, 0x68a6, 0x5b5f, 0x772f, 0x919a, 0x9761, 0x7cdc, 0x8ff7,
+    0x8c1c, 0x5f25, 0x7c73, 0x79d8, 0x89c5, 0x6ccc, 0x871c, 0x5bc6, 0x5e42, 0x68c9, 0x7720, 0x7ef5, 0x5195, 0x514d, 0x52c9, 0x5a29,
+    0x7f05, 0x9762, 0x82d7, 0x63cf, 0x7784, 0x85d0, 0x79d2, 0x6e3a, 0x5e99, 0x5999, 0x8511, 0x706d, 0x6c11, 0x62bf, 0x76bf, 0x654f,
+    0x60af, 0x95fd, 0x660e, 0x879f, 0x9e23, 0x94ed, 0x540d, 0x547d, 0x8c2c, 0x6478, 0x8140, 0x8141, 0x8142, 0x8143, 0x8144, 0x8145,
+    0x8147, 0x8149, 0x814d, 0x814e, 0x814f, 0x8152, 0x8156, 0x8157, 0x8158, 0x815b, 0x815c, 0x815d, 0x815e, 0x815f, 0x8161, 0x8162,
+    0x8163, 0x8164, 0x8166, 0x8168, 0x816a, 0x816b, 0x816c, 0x816f, 0x8172, 0x8173, 0x8175, 0x8176, 0x8177, 0x8178, 0x8181, 0x8183,
+    0x8184, 0x8185, 0x8186, 0x8187, 0x8189, 0x818b, 0x818c, 0x818d, 0x818e, 0x8190, 0x8192, 0x8193, 0x8194, 0x8195, 0x8196, 0x8197,
+    0x8199, 0x819a, 0x819e, 0x819f, 0x81a0, 0x81a1, 0x81a2, 0x81a4, 0x81a5, 0x81a7, 0x81a9, 0x81ab, 0x81ac, 0x81ad, 0x81ae, 0x81af,
+    0x81b0, 0x81b1, 0x81b2, 0x81b4, 0x81b5, 0x81b6, 0x81b7, 0x81b8, 0x81b9, 0x81bc, 0x81bd, 0x81be, 0x81bf, 0x81c4, 0x81c5, 0x81c7,
+    0x81c8, 0x81c9, 0x81cb, 0x81cd, 0x81ce, 0x81cf, 0x81d0, 0x81d1, 0x81d2, 0x81d3, 0x6479, 0x8611, 0x6a21, 0x819c, 0x78e8, 0x6469,
+    0x9b54, 0x62b9, 0x672b, 0x83ab, 0x58a8, 0x9ed8, 0x6cab, 0x6f20, 0x5bde, 0x964c, 0x8c0b, 0x725f, 0x67d0, 0x62c7, 0x7261, 0x4ea9,
+    0x59c6, 0x6bcd, 0x5893, 0x66ae, 0x5e55, 0x52df, 0x6155, 0x6728, 0x76ee, 0x7766, 0x7267, 0x7a46, 0x62ff, 0x54ea, 0x5450, 0x94a0,
+    0x90a3, 0x5a1c, 0x7eb3, 0x6c16, 0x4e43, 0x5976, 0x8010, 0x5948, 0x5357, 0x7537, 0x96be, 0x56ca, 0x6320, 0x8111, 0x607c, 0x95f9,
+    0x6dd6, 0x5462, 0x9981, 0x5185, 0x5ae9, 0x80fd, 0x59ae, 0x9713, 0x502a, 0x6ce5, 0x5c3c, 0x62df, 0x4f60, 0x533f, 0x817b, 0x9006,
+    0x6eba, 0x852b, 0x62c8, 0x5e74, 0x78be, 0x64b5, 0x637b, 0x5ff5, 0x5a18, 0x917f, 0x9e1f, 0x5c3f, 0x634f, 0x8042, 0x5b7d, 0x556e,
+    0x954a, 0x954d, 0x6d85, 0x60a8, 0x67e0, 0x72de, 0x51dd, 0x5b81, 0x81d4, 0x81d5, 0x81d6, 0x81d7, 0x81d8, 0x81d9, 0x81da, 0x81db,
+    0x81dc, 0x81dd, 0x81de, 0x81df, 0x81e0, 0x81e1, 0x81e2, 0x81e4, 0x81e5, 0x81e6, 0x81e8, 0x81e9, 0x81eb, 0x81ee, 0x81ef, 0x81f0,
+    0x81f1, 0x81f2, 0x81f5, 0x81f6, 0x81f7, 0x81f8, 0x81f9, 0x81fa, 0x81fd, 0x81ff, 0x8203, 0x8207, 0x8208, 0x8209, 0x820a, 0x820b,
+    0x820e, 0x820f, 0x8211, 0x8213, 0x8215, 0x8216, 0x8217, 0x8218, 0x8219, 0x821a, 0x821d, 0x8220, 0x8224, 0x8225, 0x8226, 0x8227,
+    0x8229, 0x822e, 0x8232, 0x823a, 0x823c, 0x823d, 0x823f, 0x8240, 0x8241, 0x8242, 0x8243, 0x8245, 0x8246, 0x8248, 0x824a, 0x824c,
+    0x824d, 0x824e, 0x8250, 0x8251, 0x8252, 0x8253, 0x8254, 0x8255, 0x8256, 0x8257, 0x8259, 0x825b, 0x825c, 0x825d, 0x825e, 0x8260,
+    0x8261, 0x8262, 0x8263, 0x8264, 0x8265, 0x8266, 0x8267, 0x8269, 0x62e7, 0x6cde, 0x725b, 0x626d, 0x94ae, 0x7ebd, 0x8113, 0x6d53,
+    0x519c, 0x5f04, 0x5974, 0x52aa, 0x6012, 0x5973, 0x6696, 0x8650, 0x759f, 0x632a, 0x61e6, 0x7cef, 0x8bfa, 0x54e6, 0x6b27, 0x9e25,
+    0x6bb4, 0x85d5, 0x5455, 0x5076, 0x6ca4, 0x556a, 0x8db4, 0x722c, 0x5e15, 0x6015, 0x7436, 0x62cd, 0x6392, 0x724c, 0x5f98, 0x6e43,
+    0x6d3e, 0x6500, 0x6f58, 0x76d8, 0x78d0, 0x76fc, 0x7554, 0x5224, 0x53db, 0x4e53, 0x5e9e, 0x65c1, 0x802a, 0x80d6, 0x629b, 0x5486,
+    0x5228, 0x70ae, 0x888d, 0x8dd1, 0x6ce1, 0x5478, 0x80da, 0x57f9, 0x88f4, 0x8d54, 0x966a, 0x914d, 0x4f69, 0x6c9b, 0x55b7, 0x76c6,
+    0x7830, 0x62a8, 0x70f9, 0x6f8e, 0x5f6d, 0x84ec, 0x68da, 0x787c, 0x7bf7, 0x81a8, 0x670b, 0x9e4f, 0x6367, 0x78b0, 0x576f, 0x7812,
+    0x9739, 0x6279, 0x62ab, 0x5288, 0x7435, 0x6bd7, 0x826a, 0x826b, 0x826c, 0x826d, 0x8271, 0x8275, 0x8276, 0x8277, 0x8278, 0x827b,
+    0x827c, 0x8280, 0x8281, 0x8283, 0x8285, 0x8286, 0x8287, 0x8289, 0x828c, 0x8290, 0x8293, 0x8294, 0x8295, 0x8296, 0x829a, 0x829b,
+    0x829e, 0x82a0, 0x82a2, 0x82a3, 0x82a7, 0x82b2, 0x82b5, 0x82b6, 0x82ba, 0x82bb, 0x82bc, 0x82bf, 0x82c0, 0x82c2, 0x82c3, 0x82c5,
+    0x82c6, 0x82c9, 0x82d0, 0x82d6, 0x82d9, 0x82da, 0x82dd, 0x82e2, 0x82e7, 0x82e8, 0x82e9, 0x82ea, 0x82ec, 0x82ed, 0x82ee, 0x82f0,
+    0x82f2, 0x82f3, 0x82f5, 0x82f6, 0x82f8, 0x82fa, 0x82fc, 0x82fd, 0x82fe, 0x82ff, 0x8300, 0x830a, 0x830b, 0x830d, 0x8310, 0x8312,
+    0x8313, 0x8316, 0x8318, 0x8319, 0x831d, 0x831e, 0x831f, 0x8320, 0x8321, 0x8322, 0x8323, 0x8324, 0x8325, 0x8326, 0x8329, 0x832a,
+    0x832e, 0x8330, 0x8332, 0x8337, 0x833b, 0x833d, 0x5564, 0x813e, 0x75b2, 0x76ae, 0x5339, 0x75de, 0x50fb, 0x5c41, 0x8b6c, 0x7bc7,
+    0x504f, 0x7247, 0x9a97, 0x98d8, 0x6f02, 0x74e2, 0x7968, 0x6487, 0x77a5, 0x62fc, 0x9891, 0x8d2b, 0x54c1, 0x8058, 0x4e52, 0x576a,
+    0x82f9, 0x840d, 0x5e73, 0x51ed, 0x74f6, 0x8bc4, 0x5c4f, 0x5761, 0x6cfc, 0x9887, 0x5a46, 0x7834, 0x9b44, 0x8feb, 0x7c95, 0x5256,
+    0x6251, 0x94fa, 0x4ec6, 0x8386, 0x8461, 0x83e9, 0x84b2, 0x57d4, 0x6734, 0x5703, 0x666e, 0x6d66, 0x8c31, 0x66dd, 0x7011, 0x671f,
+    0x6b3a, 0x6816, 0x621a, 0x59bb, 0x4e03, 0x51c4, 0x6f06, 0x67d2, 0x6c8f, 0x5176, 0x68cb, 0x5947, 0x6b67, 0x7566, 0x5d0e, 0x8110,
+    0x9f50, 0x65d7, 0x7948, 0x7941, 0x9a91, 0x8d77, 0x5c82, 0x4e5e, 0x4f01, 0x542f, 0x5951, 0x780c, 0x5668, 0x6c14, 0x8fc4, 0x5f03,
+    0x6c7d, 0x6ce3, 0x8bab, 0x6390, 0x833e, 0x833f, 0x8341, 0x8342, 0x8344, 0x8345, 0x8348, 0x834a, 0x834b, 0x834c, 0x834d, 0x834e,
+    0x8353, 0x8355, 0x8356, 0x8357, 0x8358, 0x8359, 0x835d, 0x8362, 0x8370, 0x8371, 0x8372, 0x8373, 0x8374, 0x8375, 0x8376, 0x8379,
+    0x837a, 0x837e, 0x837f, 0x8380, 0x8381, 0x8382, 0x8383, 0x8384, 0x8387, 0x8388, 0x838a, 0x838b, 0x838c, 0x838d, 0x838f, 0x8390,
+    0x8391, 0x8394, 0x8395, 0x8396, 0x8397, 0x8399, 0x839a, 0x839d, 0x839f, 0x83a1, 0x83a2, 0x83a3, 0x83a4, 0x83a5, 0x83a6, 0x83a7,
+    0x83ac, 0x83ad, 0x83ae, 0x83af, 0x83b5, 0x83bb, 0x83be, 0x83bf, 0x83c2, 0x83c3, 0x83c4, 0x83c6, 0x83c8, 0x83c9, 0x83cb, 0x83cd,
+    0x83ce, 0x83d0, 0x83d1, 0x83d2, 0x83d3, 0x83d5, 0x83d7, 0x83d9, 0x83da, 0x83db, 0x83de, 0x83e2, 0x83e3, 0x83e4, 0x83e6, 0x83e7,
+    0x83e8, 0x83eb, 0x83ec, 0x83ed, 0x6070, 0x6d3d, 0x7275, 0x6266, 0x948e, 0x94c5, 0x5343, 0x8fc1, 0x7b7e, 0x4edf, 0x8c26, 0x4e7e,
+    0x9ed4, 0x94b1, 0x94b3, 0x524d, 0x6f5c, 0x9063, 0x6d45, 0x8c34, 0x5811, 0x5d4c, 0x6b20, 0x6b49, 0x67aa, 0x545b, 0x8154, 0x7f8c,
+    0x5899, 0x8537, 0x5f3a, 0x62a2, 0x6a47, 0x9539, 0x6572, 0x6084, 0x6865, 0x77a7, 0x4e54, 0x4fa8, 0x5de7, 0x9798, 0x64ac, 0x7fd8,
+    0x5ced, 0x4fcf, 0x7a8d, 0x5207, 0x8304, 0x4e14, 0x602f, 0x7a83, 0x94a6, 0x4fb5, 0x4eb2, 0x79e6, 0x7434, 0x52e4, 0x82b9, 0x64d2,
+    0x79bd, 0x5bdd, 0x6c81, 0x9752, 0x8f7b, 0x6c22, 0x503e, 0x537f, 0x6e05, 0x64ce, 0x6674, 0x6c30, 0x60c5, 0x9877, 0x8bf7, 0x5e86,
+    0x743c, 0x7a77, 0x79cb, 0x4e18, 0x90b1, 0x7403, 0x6c42, 0x56da, 0x914b, 0x6cc5, 0x8d8b, 0x533a, 0x86c6, 0x66f2, 0x8eaf, 0x5c48,
+    0x9a71, 0x6e20, 0x83ee, 0x83ef, 0x83f3, 0x83f4, 0x83f5, 0x83f6, 0x83f7, 0x83fa, 0x83fb, 0x83fc, 0x83fe, 0x83ff, 0x8400, 0x8402,
+    0x8405, 0x8407, 0x8408, 0x8409, 0x840a, 0x8410, 0x8412, 0x8413, 0x8414, 0x8415, 0x8416, 0x8417, 0x8419, 0x841a, 0x841b, 0x841e,
+    0x841f, 0x8420, 0x8421, 0x8422, 0x8423, 0x8429, 0x842a, 0x842b, 0x842c, 0x842d, 0x842e, 0x842f, 0x8430, 0x8432, 0x8433, 0x8434,
+    0x8435, 0x8436, 0x8437, 0x8439, 0x843a, 0x843b, 0x843e, 0x843f, 0x8440, 0x8441, 0x8442, 0x8443, 0x8444, 0x8445, 0x8447, 0x8448,
+    0x8449, 0x844a, 0x844b, 0x844c, 0x844d, 0x844e, 0x844f, 0x8450, 0x8452, 0x8453, 0x8454, 0x8455, 0x8456, 0x8458, 0x845d, 0x845e,
+    0x845f, 0x8460, 0x8462, 0x8464, 0x8465, 0x8466, 0x8467, 0x8468, 0x846a, 0x846e, 0x846f, 0x8470, 0x8472, 0x8474, 0x8477, 0x8479,
+    0x847b, 0x847c, 0x53d6, 0x5a36, 0x9f8b, 0x8da3, 0x53bb, 0x5708, 0x98a7, 0x6743, 0x919b, 0x6cc9, 0x5168, 0x75ca, 0x62f3, 0x72ac,
+    0x5238, 0x529d, 0x7f3a, 0x7094, 0x7638, 0x5374, 0x9e4a, 0x69b7, 0x786e, 0x96c0, 0x88d9, 0x7fa4, 0x7136, 0x71c3, 0x5189, 0x67d3,
+    0x74e4, 0x58e4, 0x6518, 0x56b7, 0x8ba9, 0x9976, 0x6270, 0x7ed5, 0x60f9, 0x70ed, 0x58ec, 0x4ec1, 0x4eba, 0x5fcd, 0x97e7, 0x4efb,
+    0x8ba4, 0x5203, 0x598a, 0x7eab, 0x6254, 0x4ecd, 0x65e5, 0x620e, 0x8338, 0x84c9, 0x8363, 0x878d, 0x7194, 0x6eb6, 0x5bb9, 0x7ed2,
+    0x5197, 0x63c9, 0x67d4, 0x8089, 0x8339, 0x8815, 0x5112, 0x5b7a, 0x5982, 0x8fb1, 0x4e73, 0x6c5d, 0x5165, 0x8925, 0x8f6f, 0x962e,
+    0x854a, 0x745e, 0x9510, 0x95f0, 0x6da6, 0x82e5, 0x5f31, 0x6492, 0x6d12, 0x8428, 0x816e, 0x9cc3, 0x585e, 0x8d5b, 0x4e09, 0x53c1,
+    0x847d, 0x847e, 0x847f, 0x8480, 0x8481, 0x8483, 0x8484, 0x8485, 0x8486, 0x848a, 0x848d, 0x848f, 0x8490, 0x8491, 0x8492, 0x8493,
+    0x8494, 0x8495, 0x8496, 0x8498, 0x849a, 0x849b, 0x849d, 0x849e, 0x849f, 0x84a0, 0x84a2, 0x84a3, 0x84a4, 0x84a5, 0x84a6, 0x84a7,
+    0x84a8, 0x84a9, 0x84aa, 0x84ab, 0x84ac, 0x84ad, 0x84ae, 0x84b0, 0x84b1, 0x84b3, 0x84b5, 0x84b6, 0x84b7, 0x84bb, 0x84bc, 0x84be,
+    0x84c0, 0x84c2, 0x84c3, 0x84c5, 0x84c6, 0x84c7, 0x84c8, 0x84cb, 0x84cc, 0x84ce, 0x84cf, 0x84d2, 0x84d4, 0x84d5, 0x84d7, 0x84d8,
+    0x84d9, 0x84da, 0x84db, 0x84dc, 0x84de, 0x84e1, 0x84e2, 0x84e4, 0x84e7, 0x84e8, 0x84e9, 0x84ea, 0x84eb, 0x84ed, 0x84ee, 0x84ef,
+    0x84f1, 0x84f2, 0x84f3, 0x84f4, 0x84f5, 0x84f6, 0x84f7, 0x84f8, 0x84f9, 0x84fa, 0x84fb, 0x84fd, 0x84fe, 0x8500, 0x8501, 0x8502,
+    0x4f1e, 0x6563, 0x6851, 0x55d3, 0x4e27, 0x6414, 0x9a9a, 0x626b, 0x5ac2, 0x745f, 0x8272, 0x6da9, 0x68ee, 0x50e7, 0x838e, 0x7802,
+    0x6740, 0x5239, 0x6c99, 0x7eb1, 0x50bb, 0x5565, 0x715e, 0x7b5b, 0x6652, 0x73ca, 0x82eb, 0x6749, 0x5c71, 0x5220, 0x717d, 0x886b,
+    0x95ea, 0x9655, 0x64c5, 0x8d61, 0x81b3, 0x5584, 0x6c55, 0x6247, 0x7f2e, 0x5892, 0x4f24, 0x5546, 0x8d4f, 0x664c, 0x4e0a, 0x5c1a,
+    0x88f3, 0x68a2, 0x634e, 0x7a0d, 0x70e7, 0x828d, 0x52fa, 0x97f6, 0x5c11, 0x54e8, 0x90b5, 0x7ecd, 0x5962, 0x8d4a, 0x86c7, 0x820c,
+    0x820d, 0x8d66, 0x6444, 0x5c04, 0x6151, 0x6d89, 0x793e, 0x8bbe, 0x7837, 0x7533, 0x547b, 0x4f38, 0x8eab, 0x6df1, 0x5a20, 0x7ec5,
+    0x795e, 0x6c88, 0x5ba1, 0x5a76, 0x751a, 0x80be, 0x614e, 0x6e17, 0x58f0, 0x751f, 0x7525, 0x7272, 0x5347, 0x7ef3, 0x8503, 0x8504,
+    0x8505, 0x8506, 0x8507, 0x8508, 0x8509, 0x850a, 0x850b, 0x850d, 0x850e, 0x850f, 0x8510, 0x8512, 0x8514, 0x8515, 0x8516, 0x8518,
+    0x8519, 0x851b, 0x851c, 0x851d, 0x851e, 0x8520, 0x8522, 0x8523, 0x8524, 0x8525, 0x8526, 0x8527, 0x8528, 0x8529, 0x852a, 0x852d,
+    0x852e, 0x852f, 0x8530, 0x8531, 0x8532, 0x8533, 0x8534, 0x8535, 0x8536, 0x853e, 0x853f, 0x8540, 0x8541, 0x8542, 0x8544, 0x8545,
+    0x8546, 0x8547, 0x854b, 0x854c, 0x854d, 0x854e, 0x854f, 0x8550, 0x8551, 0x8552, 0x8553, 0x8554, 0x8555, 0x8557, 0x8558, 0x855a,
+    0x855b, 0x855c, 0x855d, 0x855f, 0x8560, 0x8561, 0x8562, 0x8563, 0x8565, 0x8566, 0x8567, 0x8569, 0x856a, 0x856b, 0x856c, 0x856d,
+    0x856e, 0x856f, 0x8570, 0x8571, 0x8573, 0x8575, 0x8576, 0x8577, 0x8578, 0x857c, 0x857d, 0x857f, 0x8580, 0x8581, 0x7701, 0x76db,
+    0x5269, 0x80dc, 0x5723, 0x5e08, 0x5931, 0x72ee, 0x65bd, 0x6e7f, 0x8bd7, 0x5c38, 0x8671, 0x5341, 0x77f3, 0x62fe, 0x65f6, 0x4ec0,
+    0x98df, 0x8680, 0x5b9e, 0x8bc6, 0x53f2, 0x77e2, 0x4f7f, 0x5c4e, 0x9a76, 0x59cb, 0x5f0f, 0x793a, 0x58eb, 0x4e16, 0x67ff, 0x4e8b,
+    0x62ed, 0x8a93, 0x901d, 0x52bf, 0x662f, 0x55dc, 0x566c, 0x9002, 0x4ed5, 0x4f8d, 0x91ca, 0x9970, 0x6c0f, 0x5e02, 0x6043, 0x5ba4,
+    0x89c6, 0x8bd5, 0x6536, 0x624b, 0x9996, 0x5b88, 0x5bff, 0x6388, 0x552e, 0x53d7, 0x7626, 0x517d, 0x852c, 0x67a2, 0x68b3, 0x6b8a,
+    0x6292, 0x8f93, 0x53d4, 0x8212, 0x6dd1, 0x758f, 0x4e66, 0x8d4e, 0x5b70, 0x719f, 0x85af, 0x6691, 0x66d9, 0x7f72, 0x8700, 0x9ecd,
+    0x9f20, 0x5c5e, 0x672f, 0x8ff0, 0x6811, 0x675f, 0x620d, 0x7ad6, 0x5885, 0x5eb6, 0x6570, 0x6f31, 0x8582, 0x8583, 0x8586, 0x8588,
+    0x8589, 0x858a, 0x858b, 0x858c, 0x858d, 0x858e, 0x8590, 0x8591, 0x8592, 0x8593, 0x8594, 0x8595, 0x8596, 0x8597, 0x8598, 0x8599,
+    0x859a, 0x859d, 0x859e, 0x859f, 0x85a0, 0x85a1, 0x85a2, 0x85a3, 0x85a5, 0x85a6, 0x85a7, 0x85a9, 0x85ab, 0x85ac, 0x85ad, 0x85b1,
+    0x85b2, 0x85b3, 0x85b4, 0x85b5, 0x85b6, 0x85b8, 0x85ba, 0x85bb, 0x85bc, 0x85bd, 0x85be, 0x85bf, 0x85c0, 0x85c2, 0x85c3, 0x85c4,
+    0x85c5, 0x85c6, 0x85c7, 0x85c8, 0x85ca, 0x85cb, 0x85cc, 0x85cd, 0x85ce, 0x85d1, 0x85d2, 0x85d4, 0x85d6, 0x85d7, 0x85d8, 0x85d9,
+    0x85da, 0x85db, 0x85dd, 0x85de, 0x85df, 0x85e0, 0x85e1, 0x85e2, 0x85e3, 0x85e5, 0x85e6, 0x85e7, 0x85e8, 0x85ea, 0x85eb, 0x85ec,
+    0x85ed, 0x85ee, 0x85ef, 0x85f0, 0x85f1, 0x85f2, 0x85f3, 0x85f4, 0x85f5, 0x85f6, 0x85f7, 0x85f8, 0x6055, 0x5237, 0x800d, 0x6454,
+    0x8870, 0x7529, 0x5e05, 0x6813, 0x62f4, 0x971c, 0x53cc, 0x723d, 0x8c01, 0x6c34, 0x7761, 0x7a0e, 0x542e, 0x77ac, 0x987a, 0x821c,
+    0x8bf4, 0x7855, 0x6714, 0x70c1, 0x65af, 0x6495, 0x5636, 0x601d, 0x79c1, 0x53f8, 0x4e1d, 0x6b7b, 0x8086, 0x5bfa, 0x55e3, 0x56db,
+    0x4f3a, 0x4f3c, 0x9972, 0x5df3, 0x677e, 0x8038, 0x6002, 0x9882, 0x9001, 0x5b8b, 0x8bbc, 0x8bf5, 0x641c, 0x8258, 0x64de, 0x55fd,
+    0x82cf, 0x9165, 0x4fd7, 0x7d20, 0x901f, 0x7c9f, 0x50f3, 0x5851, 0x6eaf, 0x5bbf, 0x8bc9, 0x8083, 0x9178, 0x849c, 0x7b97, 0x867d,
+    0x968b, 0x968f, 0x7ee5, 0x9ad3, 0x788e, 0x5c81, 0x7a57, 0x9042, 0x96a7, 0x795f, 0x5b59, 0x635f, 0x7b0b, 0x84d1, 0x68ad, 0x5506,
+    0x7f29, 0x7410, 0x7d22, 0x9501, 0x6240, 0x584c, 0x4ed6, 0x5b83, 0x5979, 0x5854, 0x85f9, 0x85fa, 0x85fc, 0x85fd, 0x85fe, 0x8600,
+    0x8601, 0x8602, 0x8603, 0x8604, 0x8606, 0x8607, 0x8608, 0x8609, 0x860a, 0x860b, 0x860c, 0x860d, 0x860e, 0x860f, 0x8610, 0x8612,
+    0x8613, 0x8614, 0x8615, 0x8617, 0x8618, 0x8619, 0x861a, 0x861b, 0x861c, 0x861d, 0x861e, 0x861f, 0x8620, 0x8621, 0x8622, 0x8623,
+    0x8624, 0x8625, 0x8626, 0x8628, 0x862a, 0x862b, 0x862c, 0x862d, 0x862e, 0x862f, 0x8630, 0x8631, 0x8632, 0x8633, 0x8634, 0x8635,
+    0x8636, 0x8637, 0x8639, 0x863a, 0x863b, 0x863d, 0x863e, 0x863f, 0x8640, 0x8641, 0x8642, 0x8643, 0x8644, 0x8645, 0x8646, 0x8647,
+    0x8648, 0x8649, 0x864a, 0x864b, 0x864c, 0x8652, 0x8653, 0x8655, 0x8656, 0x8657, 0x8658, 0x8659, 0x865b, 0x865c, 0x865d, 0x865f,
+    0x8660, 0x8661, 0x8663, 0x8664, 0x8665, 0x8666, 0x8667, 0x8668, 0x8669, 0x866a, 0x736d, 0x631e, 0x8e4b, 0x8e0f, 0x80ce, 0x82d4,
+    0x62ac, 0x53f0, 0x6cf0, 0x915e, 0x592a, 0x6001, 0x6c70, 0x574d, 0x644a, 0x8d2a, 0x762b, 0x6ee9, 0x575b, 0x6a80, 0x75f0, 0x6f6d,
+    0x8c2d, 0x8c08, 0x5766, 0x6bef, 0x8892, 0x78b3, 0x63a2, 0x53f9, 0x70ad, 0x6c64, 0x5858, 0x642a, 0x5802, 0x68e0, 0x819b, 0x5510,
+    0x7cd6, 0x5018, 0x8eba, 0x6dcc, 0x8d9f, 0x70eb, 0x638f, 0x6d9b, 0x6ed4, 0x7ee6, 0x8404, 0x6843, 0x9003, 0x6dd8, 0x9676, 0x8ba8,
+    0x5957, 0x7279, 0x85e4, 0x817e, 0x75bc, 0x8a8a, 0x68af, 0x5254, 0x8e22, 0x9511, 0x63d0, 0x9898, 0x8e44, 0x557c, 0x4f53, 0x66ff,
+    0x568f, 0x60d5, 0x6d95, 0x5243, 0x5c49, 0x5929, 0x6dfb, 0x586b, 0x7530, 0x751c, 0x606c, 0x8214, 0x8146, 0x6311, 0x6761, 0x8fe2,
+    0x773a, 0x8df3, 0x8d34, 0x94c1, 0x5e16, 0x5385, 0x542c, 0x70c3, 0x866d, 0x866f, 0x8670, 0x8672, 0x8673, 0x8674, 0x8675, 0x8676,
+    0x8677, 0x8678, 0x8683, 0x8684, 0x8685, 0x8686, 0x8687, 0x8688, 0x8689, 0x868e, 0x868f, 0x8690, 0x8691, 0x8692, 0x8694, 0x8696,
+    0x8697, 0x8698, 0x8699, 0x869a, 0x869b, 0x869e, 0x869f, 0x86a0, 0x86a1, 0x86a2, 0x86a5, 0x86a6, 0x86ab, 0x86ad, 0x86ae, 0x86b2,
+    0x86b3, 0x86b7, 0x86b8, 0x86b9, 0x86bb, 0x86bc, 0x86bd, 0x86be, 0x86bf, 0x86c1, 0x86c2, 0x86c3, 0x86c5, 0x86c8, 0x86cc, 0x86cd,
+    0x86d2, 0x86d3, 0x86d5, 0x86d6, 0x86d7, 0x86da, 0x86dc, 0x86dd, 0x86e0, 0x86e1, 0x86e2, 0x86e3, 0x86e5, 0x86e6, 0x86e7, 0x86e8,
+    0x86ea, 0x86eb, 0x86ec, 0x86ef, 0x86f5, 0x86f6, 0x86f7, 0x86fa, 0x86fb, 0x86fc, 0x86fd, 0x86ff, 0x8701, 0x8704, 0x8705, 0x8706,
+    0x870b, 0x870c, 0x870e, 0x870f, 0x8710, 0x8711, 0x8714, 0x8716, 0x6c40, 0x5ef7, 0x505c, 0x4ead, 0x5ead, 0x633a, 0x8247, 0x901a,
+    0x6850, 0x916e, 0x77b3, 0x540c, 0x94dc, 0x5f64, 0x7ae5, 0x6876, 0x6345, 0x7b52, 0x7edf, 0x75db, 0x5077, 0x6295, 0x5934, 0x900f,
+    0x51f8, 0x79c3, 0x7a81, 0x56fe, 0x5f92, 0x9014, 0x6d82, 0x5c60, 0x571f, 0x5410, 0x5154, 0x6e4d, 0x56e2, 0x63a8, 0x9893, 0x817f,
+    0x8715, 0x892a, 0x9000, 0x541e, 0x5c6f, 0x81c0, 0x62d6, 0x6258, 0x8131, 0x9e35, 0x9640, 0x9a6e, 0x9a7c, 0x692d, 0x59a5, 0x62d3,
+    0x553e, 0x6316, 0x54c7, 0x86d9, 0x6d3c, 0x5a03, 0x74e6, 0x889c, 0x6b6a, 0x5916, 0x8c4c, 0x5f2f, 0x6e7e, 0x73a9, 0x987d, 0x4e38,
+    0x70f7, 0x5b8c, 0x7897, 0x633d, 0x665a, 0x7696, 0x60cb, 0x5b9b, 0x5a49, 0x4e07, 0x8155, 0x6c6a, 0x738b, 0x4ea1, 0x6789, 0x7f51,
+    0x5f80, 0x65fa, 0x671b, 0x5fd8, 0x5984, 0x5a01, 0x8719, 0x871b, 0x871d, 0x871f, 0x8720, 0x8724, 0x8726, 0x8727, 0x8728, 0x872a,
+    0x872b, 0x872c, 0x872d, 0x872f, 0x8730, 0x8732, 0x8733, 0x8735, 0x8736, 0x8738, 0x8739, 0x873a, 0x873c, 0x873d, 0x8740, 0x8741,
+    0x8742, 0x8743, 0x8744, 0x8745, 0x8746, 0x874a, 0x874b, 0x874d, 0x874f, 0x8750, 0x8751, 0x8752, 0x8754, 0x8755, 0x8756, 0x8758,
+    0x875a, 0x875b, 0x875c, 0x875d, 0x875e, 0x875f, 0x8761, 0x8762, 0x8766, 0x8767, 0x8768, 0x8769, 0x876a, 0x876b, 0x876c, 0x876d,
+    0x876f, 0x8771, 0x8772, 0x8773, 0x8775, 0x8777, 0x8778, 0x8779, 0x877a, 0x877f, 0x8780, 0x8781, 0x8784, 0x8786, 0x8787, 0x8789,
+    0x878a, 0x878c, 0x878e, 0x878f, 0x8790, 0x8791, 0x8792, 0x8794, 0x8795, 0x8796, 0x8798, 0x8799, 0x879a, 0x879b, 0x879c, 0x879d,
+    0x879e, 0x87a0, 0x87a1, 0x87a2, 0x87a3, 0x87a4, 0x5dcd, 0x5fae, 0x5371, 0x97e6, 0x8fdd, 0x6845, 0x56f4, 0x552f, 0x60df, 0x4e3a,
+    0x6f4d, 0x7ef4, 0x82c7, 0x840e, 0x59d4, 0x4f1f, 0x4f2a, 0x5c3e, 0x7eac, 0x672a, 0x851a, 0x5473, 0x754f, 0x80c3, 0x5582, 0x9b4f,
+    0x4f4d, 0x6e2d, 0x8c13, 0x5c09, 0x6170, 0x536b, 0x761f, 0x6e29, 0x868a, 0x6587, 0x95fb, 0x7eb9, 0x543b, 0x7a33, 0x7d0a, 0x95ee,
+    0x55e1, 0x7fc1, 0x74ee, 0x631d, 0x8717, 0x6da1, 0x7a9d, 0x6211, 0x65a1, 0x5367, 0x63e1, 0x6c83, 0x5deb, 0x545c, 0x94a8, 0x4e4c,
+    0x6c61, 0x8bec, 0x5c4b, 0x65e0, 0x829c, 0x68a7, 0x543e, 0x5434, 0x6bcb, 0x6b66, 0x4e94, 0x6342, 0x5348, 0x821e, 0x4f0d, 0x4fae,
+    0x575e, 0x620a, 0x96fe, 0x6664, 0x7269, 0x52ff, 0x52a1, 0x609f, 0x8bef, 0x6614, 0x7199, 0x6790, 0x897f, 0x7852, 0x77fd, 0x6670,
+    0x563b, 0x5438, 0x9521, 0x727a, 0x87a5, 0x87a6, 0x87a7, 0x87a9, 0x87aa, 0x87ae, 0x87b0, 0x87b1, 0x87b2, 0x87b4, 0x87b6, 0x87b7,
+    0x87b8, 0x87b9, 0x87bb, 0x87bc, 0x87be, 0x87bf, 0x87c1, 0x87c2, 0x87c3, 0x87c4, 0x87c5, 0x87c7, 0x87c8, 0x87c9, 0x87cc, 0x87cd,
+    0x87ce, 0x87cf, 0x87d0, 0x87d4, 0x87d5, 0x87d6, 0x87d7, 0x87d8, 0x87d9, 0x87da, 0x87dc, 0x87dd, 0x87de, 0x87df, 0x87e1, 0x87e2,
+    0x87e3, 0x87e4, 0x87e6, 0x87e7, 0x87e8, 0x87e9, 0x87eb, 0x87ec, 0x87ed, 0x87ef, 0x87f0, 0x87f1, 0x87f2, 0x87f3, 0x87f4, 0x87f5,
+    0x87f6, 0x87f7, 0x87f8, 0x87fa, 0x87fb, 0x87fc, 0x87fd, 0x87ff, 0x8800, 0x8801, 0x8802, 0x8804, 0x8805, 0x8806, 0x8807, 0x8808,
+    0x8809, 0x880b, 0x880c, 0x880d, 0x880e, 0x880f, 0x8810, 0x8811, 0x8812, 0x8814, 0x8817, 0x8818, 0x8819, 0x881a, 0x881c, 0x881d,
+    0x881e, 0x881f, 0x8820, 0x8823, 0x7a00, 0x606f, 0x5e0c, 0x6089, 0x819d, 0x5915, 0x60dc, 0x7184, 0x70ef, 0x6eaa, 0x6c50, 0x7280,
+    0x6a84, 0x88ad, 0x5e2d, 0x4e60, 0x5ab3, 0x559c, 0x94e3, 0x6d17, 0x7cfb, 0x9699, 0x620f, 0x7ec6, 0x778e, 0x867e, 0x5323, 0x971e,
+    0x8f96, 0x6687, 0x5ce1, 0x4fa0, 0x72ed, 0x4e0b, 0x53a6, 0x590f, 0x5413, 0x6380, 0x9528, 0x5148, 0x4ed9, 0x9c9c, 0x7ea4, 0x54b8,
+    0x8d24, 0x8854, 0x8237, 0x95f2, 0x6d8e, 0x5f26, 0x5acc, 0x663e, 0x9669, 0x73b0, 0x732e, 0x53bf, 0x817a, 0x9985, 0x7fa1, 0x5baa,
+    0x9677, 0x9650, 0x7ebf, 0x76f8, 0x53a2, 0x9576, 0x9999, 0x7bb1, 0x8944, 0x6e58, 0x4e61, 0x7fd4, 0x7965, 0x8be6, 0x60f3, 0x54cd,
+    0x4eab, 0x9879, 0x5df7, 0x6a61, 0x50cf, 0x5411, 0x8c61, 0x8427, 0x785d, 0x9704, 0x524a, 0x54ee, 0x56a3, 0x9500, 0x6d88, 0x5bb5,
+    0x6dc6, 0x6653, 0x8824, 0x8825, 0x8826, 0x8827, 0x8828, 0x8829, 0x882a, 0x882b, 0x882c, 0x882d, 0x882e, 0x882f, 0x8830, 0x8831,
+    0x8833, 0x8834, 0x8835, 0x8836, 0x8837, 0x8838, 0x883a, 0x883b, 0x883d, 0x883e, 0x883f, 0x8841, 0x8842, 0x8843, 0x8846, 0x8847,
+    0x8848, 0x8849, 0x884a, 0x884b, 0x884e, 0x884f, 0x8850, 0x8851, 0x8852, 0x8853, 0x8855, 0x8856, 0x8858, 0x885a, 0x885b, 0x885c,
+    0x885d, 0x885e, 0x885f, 0x8860, 0x8866, 0x8867, 0x886a, 0x886d, 0x886f, 0x8871, 0x8873, 0x8874, 0x8875, 0x8876, 0x8878, 0x8879,
+    0x887a, 0x887b, 0x887c, 0x8880, 0x8883, 0x8886, 0x8887, 0x8889, 0x888a, 0x888c, 0x888e, 0x888f, 0x8890, 0x8891, 0x8893, 0x8894,
+    0x8895, 0x8897, 0x8898, 0x8899, 0x889a, 0x889b, 0x889d, 0x889e, 0x889f, 0x88a0, 0x88a1, 0x88a3, 0x88a5, 0x88a6, 0x88a7, 0x88a8,
+    0x88a9, 0x88aa, 0x5c0f, 0x5b5d, 0x6821, 0x8096, 0x5578, 0x7b11, 0x6548, 0x6954, 0x4e9b, 0x6b47, 0x874e, 0x978b, 0x534f, 0x631f,
+    0x643a, 0x90aa, 0x659c, 0x80c1, 0x8c10, 0x5199, 0x68b0, 0x5378, 0x87f9, 0x61c8, 0x6cc4, 0x6cfb, 0x8c22, 0x5c51, 0x85aa, 0x82af,
+    0x950c, 0x6b23, 0x8f9b, 0x65b0, 0x5ffb, 0x5fc3, 0x4fe1, 0x8845, 0x661f, 0x8165, 0x7329, 0x60fa, 0x5174, 0x5211, 0x578b, 0x5f62,
+    0x90a2, 0x884c, 0x9192, 0x5e78, 0x674f, 0x6027, 0x59d3, 0x5144, 0x51f6, 0x80f8, 0x5308, 0x6c79, 0x96c4, 0x718a, 0x4f11, 0x4fee,
+    0x7f9e, 0x673d, 0x55c5, 0x9508, 0x79c0, 0x8896, 0x7ee3, 0x589f, 0x620c, 0x9700, 0x865a, 0x5618, 0x987b, 0x5f90, 0x8bb8, 0x84c4,
+    0x9157, 0x53d9, 0x65ed, 0x5e8f, 0x755c, 0x6064, 0x7d6e, 0x5a7f, 0x7eea, 0x7eed, 0x8f69, 0x55a7, 0x5ba3, 0x60ac, 0x65cb, 0x7384,
+    0x88ac, 0x88ae, 0x88af, 0x88b0, 0x88b2, 0x88b3, 0x88b4, 0x88b5, 0x88b6, 0x88b8, 0x88b9, 0x88ba, 0x88bb, 0x88bd, 0x88be, 0x88bf,
+    0x88c0, 0x88c3, 0x88c4, 0x88c7, 0x88c8, 0x88ca, 0x88cb, 0x88cc, 0x88cd, 0x88cf, 0x88d0, 0x88d1, 0x88d3, 0x88d6, 0x88d7, 0x88da,
+    0x88db, 0x88dc, 0x88dd, 0x88de, 0x88e0, 0x88e1, 0x88e6, 0x88e7, 0x88e9, 0x88ea, 0x88eb, 0x88ec, 0x88ed, 0x88ee, 0x88ef, 0x88f2,
+    0x88f5, 0x88f6, 0x88f7, 0x88fa, 0x88fb, 0x88fd, 0x88ff, 0x8900, 0x8901, 0x8903, 0x8904, 0x8905, 0x8906, 0x8907, 0x8908, 0x8909,
+    0x890b, 0x890c, 0x890d, 0x890e, 0x890f, 0x8911, 0x8914, 0x8915, 0x8916, 0x8917, 0x8918, 0x891c, 0x891d, 0x891e, 0x891f, 0x8920,
+    0x8922, 0x8923, 0x8924, 0x8926, 0x8927, 0x8928, 0x8929, 0x892c, 0x892d, 0x892e, 0x892f, 0x8931, 0x8932, 0x8933, 0x8935, 0x8937,
+    0x9009, 0x7663, 0x7729, 0x7eda, 0x9774, 0x859b, 0x5b66, 0x7a74, 0x96ea, 0x8840, 0x52cb, 0x718f, 0x5faa, 0x65ec, 0x8be2, 0x5bfb,
+    0x9a6f, 0x5de1, 0x6b89, 0x6c5b, 0x8bad, 0x8baf, 0x900a, 0x8fc5, 0x538b, 0x62bc, 0x9e26, 0x9e2d, 0x5440, 0x4e2b, 0x82bd, 0x7259,
+    0x869c, 0x5d16, 0x8859, 0x6daf, 0x96c5, 0x54d1, 0x4e9a, 0x8bb6, 0x7109, 0x54bd, 0x9609, 0x70df, 0x6df9, 0x76d0, 0x4e25, 0x7814,
+    0x8712, 0x5ca9, 0x5ef6, 0x8a00, 0x989c, 0x960e, 0x708e, 0x6cbf, 0x5944, 0x63a9, 0x773c, 0x884d, 0x6f14, 0x8273, 0x5830, 0x71d5,
+    0x538c, 0x781a, 0x96c1, 0x5501, 0x5f66, 0x7130, 0x5bb4, 0x8c1a, 0x9a8c, 0x6b83, 0x592e, 0x9e2f, 0x79e7, 0x6768, 0x626c, 0x4f6f,
+    0x75a1, 0x7f8a, 0x6d0b, 0x9633, 0x6c27, 0x4ef0, 0x75d2, 0x517b, 0x6837, 0x6f3e, 0x9080, 0x8170, 0x5996, 0x7476, 0x8938, 0x8939,
+    0x893a, 0x893b, 0x893c, 0x893d, 0x893e, 0x893f, 0x8940, 0x8942, 0x8943, 0x8945, 0x8946, 0x8947, 0x8948, 0x8949, 0x894a, 0x894b,
+    0x894c, 0x894d, 0x894e, 0x894f, 0x8950, 0x8951, 0x8952, 0x8953, 0x8954, 0x8955, 0x8956, 0x8957, 0x8958, 0x8959, 0x895a, 0x895b,
+    0x895c, 0x895d, 0x8960, 0x8961, 0x8962, 0x8963, 0x8964, 0x8965, 0x8967, 0x8968, 0x8969, 0x896a, 0x896b, 0x896c, 0x896d, 0x896e,
+    0x896f, 0x8970, 0x8971, 0x8972, 0x8973, 0x8974, 0x8975, 0x8976, 0x8977, 0x8978, 0x8979, 0x897a, 0x897c, 0x897d, 0x897e, 0x8980,
+    0x8982, 0x8984, 0x8985, 0x8987, 0x8988, 0x8989, 0x898a, 0x898b, 0x898c, 0x898d, 0x898e, 0x898f, 0x8990, 0x8991, 0x8992, 0x8993,
+    0x8994, 0x8995, 0x8996, 0x8997, 0x8998, 0x8999, 0x899a, 0x899b, 0x899c, 0x899d, 0x899e, 0x899f, 0x89a0, 0x89a1, 0x6447, 0x5c27,
+    0x9065, 0x7a91, 0x8c23, 0x59da, 0x54ac, 0x8200, 0x836f, 0x8981, 0x8000, 0x6930, 0x564e, 0x8036, 0x7237, 0x91ce, 0x51b6, 0x4e5f,
+    0x9875, 0x6396, 0x4e1a, 0x53f6, 0x66f3, 0x814b, 0x591c, 0x6db2, 0x4e00, 0x58f9, 0x533b, 0x63d6, 0x94f1, 0x4f9d, 0x4f0a, 0x8863,
+    0x9890, 0x5937, 0x9057, 0x79fb, 0x4eea, 0x80f0, 0x7591, 0x6c82, 0x5b9c, 0x59e8, 0x5f5d, 0x6905, 0x8681, 0x501a, 0x5df2, 0x4e59,
+    0x77e3, 0x4ee5, 0x827a, 0x6291, 0x6613, 0x9091, 0x5c79, 0x4ebf, 0x5f79, 0x81c6, 0x9038, 0x8084, 0x75ab, 0x4ea6, 0x88d4, 0x610f,
+    0x6bc5, 0x5fc6, 0x4e49, 0x76ca, 0x6ea2, 0x8be3, 0x8bae, 0x8c0a, 0x8bd1, 0x5f02, 0x7ffc, 0x7fcc, 0x7ece, 0x8335, 0x836b, 0x56e0,
+    0x6bb7, 0x97f3, 0x9634, 0x59fb, 0x541f, 0x94f6, 0x6deb, 0x5bc5, 0x996e, 0x5c39, 0x5f15, 0x9690, 0x89a2, 0x89a3, 0x89a4, 0x89a5,
+    0x89a6, 0x89a7, 0x89a8, 0x89a9, 0x89aa, 0x89ab, 0x89ac, 0x89ad, 0x89ae, 0x89af, 0x89b0, 0x89b1, 0x89b2, 0x89b3, 0x89b4, 0x89b5,
+    0x89b6, 0x89b7, 0x89b8, 0x89b9, 0x89ba, 0x89bb, 0x89bc, 0x89bd, 0x89be, 0x89bf, 0x89c0, 0x89c3, 0x89cd, 0x89d3, 0x89d4, 0x89d5,
+    0x89d7, 0x89d8, 0x89d9, 0x89db, 0x89dd, 0x89df, 0x89e0, 0x89e1, 0x89e2, 0x89e4, 0x89e7, 0x89e8, 0x89e9, 0x89ea, 0x89ec, 0x89ed,
+    0x89ee, 0x89f0, 0x89f1, 0x89f2, 0x89f4, 0x89f5, 0x89f6, 0x89f7, 0x89f8, 0x89f9, 0x89fa, 0x89fb, 0x89fc, 0x89fd, 0x89fe, 0x89ff,
+    0x8a01, 0x8a02, 0x8a03, 0x8a04, 0x8a05, 0x8a06, 0x8a08, 0x8a09, 0x8a0a, 0x8a0b, 0x8a0c, 0x8a0d, 0x8a0e, 0x8a0f, 0x8a10, 0x8a11,
+    0x8a12, 0x8a13, 0x8a14, 0x8a15, 0x8a16, 0x8a17, 0x8a18, 0x8a19, 0x8a1a, 0x8a1b, 0x8a1c, 0x8a1d, 0x5370, 0x82f1, 0x6a31, 0x5a74,
+    0x9e70, 0x5e94, 0x7f28, 0x83b9, 0x8424, 0x8425, 0x8367, 0x8747, 0x8fce, 0x8d62, 0x76c8, 0x5f71, 0x9896, 0x786c, 0x6620, 0x54df,
+    0x62e5, 0x4f63, 0x81c3, 0x75c8, 0x5eb8, 0x96cd, 0x8e0a, 0x86f9, 0x548f, 0x6cf3, 0x6d8c, 0x6c38, 0x607f, 0x52c7, 0x7528, 0x5e7d,
+    0x4f18, 0x60a0, 0x5fe7, 0x5c24, 0x7531, 0x90ae, 0x94c0, 0x72b9, 0x6cb9, 0x6e38, 0x9149, 0x6709, 0x53cb, 0x53f3, 0x4f51, 0x91c9,
+    0x8bf1, 0x53c8, 0x5e7c, 0x8fc2, 0x6de4, 0x4e8e, 0x76c2, 0x6986, 0x865e, 0x611a, 0x8206, 0x4f59, 0x4fde, 0x903e, 0x9c7c, 0x6109,
+    0x6e1d, 0x6e14, 0x9685, 0x4e88, 0x5a31, 0x96e8, 0x4e0e, 0x5c7f, 0x79b9, 0x5b87, 0x8bed, 0x7fbd, 0x7389, 0x57df, 0x828b, 0x90c1,
+    0x5401, 0x9047, 0x55bb, 0x5cea, 0x5fa1, 0x6108, 0x6b32, 0x72f1, 0x80b2, 0x8a89, 0x8a1e, 0x8a1f, 0x8a20, 0x8a21, 0x8a22, 0x8a23,
+    0x8a24, 0x8a25, 0x8a26, 0x8a27, 0x8a28, 0x8a29, 0x8a2a, 0x8a2b, 0x8a2c, 0x8a2d, 0x8a2e, 0x8a2f, 0x8a30, 0x8a31, 0x8a32, 0x8a33,
+    0x8a34, 0x8a35, 0x8a36, 0x8a37, 0x8a38, 0x8a39, 0x8a3a, 0x8a3b, 0x8a3c, 0x8a3d, 0x8a3f, 0x8a40, 0x8a41, 0x8a42, 0x8a43, 0x8a44,
+    0x8a45, 0x8a46, 0x8a47, 0x8a49, 0x8a4a, 0x8a4b, 0x8a4c, 0x8a4d, 0x8a4e, 0x8a4f, 0x8a50, 0x8a51, 0x8a52, 0x8a53, 0x8a54, 0x8a55,
+    0x8a56, 0x8a57, 0x8a58, 0x8a59, 0x8a5a, 0x8a5b, 0x8a5c, 0x8a5d, 0x8a5e, 0x8a5f, 0x8a60, 0x8a61, 0x8a62, 0x8a63, 0x8a64, 0x8a65,
+    0x8a66, 0x8a67, 0x8a68, 0x8a69, 0x8a6a, 0x8a6b, 0x8a6c, 0x8a6d, 0x8a6e, 0x8a6f, 0x8a70, 0x8a71, 0x8a72, 0x8a73, 0x8a74, 0x8a75,
+    0x8a76, 0x8a77, 0x8a78, 0x8a7a, 0x8a7b, 0x8a7c, 0x8a7d, 0x8a7e, 0x8a7f, 0x8a80, 0x6d74, 0x5bd3, 0x88d5, 0x9884, 0x8c6b, 0x9a6d,
+    0x9e33, 0x6e0a, 0x51a4, 0x5143, 0x57a3, 0x8881, 0x539f, 0x63f4, 0x8f95, 0x56ed, 0x5458, 0x5706, 0x733f, 0x6e90, 0x7f18, 0x8fdc,
+    0x82d1, 0x613f, 0x6028, 0x9662, 0x66f0, 0x7ea6, 0x8d8a, 0x8dc3, 0x94a5, 0x5cb3, 0x7ca4, 0x6708, 0x60a6, 0x9605, 0x8018, 0x4e91,
+    0x90e7, 0x5300, 0x9668, 0x5141, 0x8fd0, 0x8574, 0x915d, 0x6655, 0x97f5, 0x5b55, 0x531d, 0x7838, 0x6742, 0x683d, 0x54c9, 0x707e,
+    0x5bb0, 0x8f7d, 0x518d, 0x5728, 0x54b1, 0x6512, 0x6682, 0x8d5e, 0x8d43, 0x810f, 0x846c, 0x906d, 0x7cdf, 0x51ff, 0x85fb, 0x67a3,
+    0x65e9, 0x6fa1, 0x86a4, 0x8e81, 0x566a, 0x9020, 0x7682, 0x7076, 0x71e5, 0x8d23, 0x62e9, 0x5219, 0x6cfd, 0x8d3c, 0x600e, 0x589e,
+    0x618e, 0x66fe, 0x8d60, 0x624e, 0x55b3, 0x6e23, 0x672d, 0x8f67, 0x8a81, 0x8a82, 0x8a83, 0x8a84, 0x8a85, 0x8a86, 0x8a87, 0x8a88,
+    0x8a8b, 0x8a8c, 0x8a8d, 0x8a8e, 0x8a8f, 0x8a90, 0x8a91, 0x8a92, 0x8a94, 0x8a95, 0x8a96, 0x8a97, 0x8a98, 0x8a99, 0x8a9a, 0x8a9b,
+    0x8a9c, 0x8a9d, 0x8a9e, 0x8a9f, 0x8aa0, 0x8aa1, 0x8aa2, 0x8aa3, 0x8aa4, 0x8aa5, 0x8aa6, 0x8aa7, 0x8aa8, 0x8aa9, 0x8aaa, 0x8aab,
+    0x8aac, 0x8aad, 0x8aae, 0x8aaf, 0x8ab0, 0x8ab1, 0x8ab2, 0x8ab3, 0x8ab4, 0x8ab5, 0x8ab6, 0x8ab7, 0x8ab8, 0x8ab9, 0x8aba, 0x8abb,
+    0x8abc, 0x8abd, 0x8abe, 0x8abf, 0x8ac0, 0x8ac1, 0x8ac2, 0x8ac3, 0x8ac4, 0x8ac5, 0x8ac6, 0x8ac7, 0x8ac8, 0x8ac9, 0x8aca, 0x8acb,
+    0x8acc, 0x8acd, 0x8ace, 0x8acf, 0x8ad0, 0x8ad1, 0x8ad2, 0x8ad3, 0x8ad4, 0x8ad5, 0x8ad6, 0x8ad7, 0x8ad8, 0x8ad9, 0x8ada, 0x8adb,
+    0x8adc, 0x8add, 0x8ade, 0x8adf, 0x8ae0, 0x8ae1, 0x8ae2, 0x8ae3, 0x94e1, 0x95f8, 0x7728, 0x6805, 0x69a8, 0x548b, 0x4e4d, 0x70b8,
+    0x8bc8, 0x6458, 0x658b, 0x5b85, 0x7a84, 0x503a, 0x5be8, 0x77bb, 0x6be1, 0x8a79, 0x7c98, 0x6cbe, 0x76cf, 0x65a9, 0x8f97, 0x5d2d,
+    0x5c55, 0x8638, 0x6808, 0x5360, 0x6218, 0x7ad9, 0x6e5b, 0x7efd, 0x6a1f, 0x7ae0, 0x5f70, 0x6f33, 0x5f20, 0x638c, 0x6da8, 0x6756,
+    0x4e08, 0x5e10, 0x8d26, 0x4ed7, 0x80c0, 0x7634, 0x969c, 0x62db, 0x662d, 0x627e, 0x6cbc, 0x8d75, 0x7167, 0x7f69, 0x5146, 0x8087,
+    0x53ec, 0x906e, 0x6298, 0x54f2, 0x86f0, 0x8f99, 0x8005, 0x9517, 0x8517, 0x8fd9, 0x6d59, 0x73cd, 0x659f, 0x771f, 0x7504, 0x7827,
+    0x81fb, 0x8d1e, 0x9488, 0x4fa6, 0x6795, 0x75b9, 0x8bca, 0x9707, 0x632f, 0x9547, 0x9635, 0x84b8, 0x6323, 0x7741, 0x5f81, 0x72f0,
+    0x4e89, 0x6014, 0x6574, 0x62ef, 0x6b63, 0x653f, 0x8ae4, 0x8ae5, 0x8ae6, 0x8ae7, 0x8ae8, 0x8ae9, 0x8aea, 0x8aeb, 0x8aec, 0x8aed,
+    0x8aee, 0x8aef, 0x8af0, 0x8af1, 0x8af2, 0x8af3, 0x8af4, 0x8af5, 0x8af6, 0x8af7, 0x8af8, 0x8af9, 0x8afa, 0x8afb, 0x8afc, 0x8afd,
+    0x8afe, 0x8aff, 0x8b00, 0x8b01, 0x8b02, 0x8b03, 0x8b04, 0x8b05, 0x8b06, 0x8b08, 0x8b09, 0x8b0a, 0x8b0b, 0x8b0c, 0x8b0d, 0x8b0e,
+    0x8b0f, 0x8b10, 0x8b11, 0x8b12, 0x8b13, 0x8b14, 0x8b15, 0x8b16, 0x8b17, 0x8b18, 0x8b19, 0x8b1a, 0x8b1b, 0x8b1c, 0x8b1d, 0x8b1e,
+    0x8b1f, 0x8b20, 0x8b21, 0x8b22, 0x8b23, 0x8b24, 0x8b25, 0x8b27, 0x8b28, 0x8b29, 0x8b2a, 0x8b2b, 0x8b2c, 0x8b2d, 0x8b2e, 0x8b2f,
+    0x8b30, 0x8b31, 0x8b32, 0x8b33, 0x8b34, 0x8b35, 0x8b36, 0x8b37, 0x8b38, 0x8b39, 0x8b3a, 0x8b3b, 0x8b3c, 0x8b3d, 0x8b3e, 0x8b3f,
+    0x8b40, 0x8b41, 0x8b42, 0x8b43, 0x8b44, 0x8b45, 0x5e27, 0x75c7, 0x90d1, 0x8bc1, 0x829d, 0x679d, 0x652f, 0x5431, 0x8718, 0x77e5,
+    0x80a2, 0x8102, 0x6c41, 0x4e4b, 0x7ec7, 0x804c, 0x76f4, 0x690d, 0x6b96, 0x6267, 0x503c, 0x4f84, 0x5740, 0x6307, 0x6b62, 0x8dbe,
+    0x53ea, 0x65e8, 0x7eb8, 0x5fd7, 0x631a, 0x63b7, 0x81f3, 0x81f4, 0x7f6e, 0x5e1c, 0x5cd9, 0x5236, 0x667a, 0x79e9, 0x7a1a, 0x8d28,
+    0x7099, 0x75d4, 0x6ede, 0x6cbb, 0x7a92, 0x4e2d, 0x76c5, 0x5fe0, 0x949f, 0x8877, 0x7ec8, 0x79cd, 0x80bf, 0x91cd, 0x4ef2, 0x4f17,
+    0x821f, 0x5468, 0x5dde, 0x6d32, 0x8bcc, 0x7ca5, 0x8f74, 0x8098, 0x5e1a, 0x5492, 0x76b1, 0x5b99, 0x663c, 0x9aa4, 0x73e0, 0x682a,
+    0x86db, 0x6731, 0x732a, 0x8bf8, 0x8bdb, 0x9010, 0x7af9, 0x70db, 0x716e, 0x62c4, 0x77a9, 0x5631, 0x4e3b, 0x8457, 0x67f1, 0x52a9,
+    0x86c0, 0x8d2e, 0x94f8, 0x7b51, 0x8b46, 0x8b47, 0x8b48, 0x8b49, 0x8b4a, 0x8b4b, 0x8b4c, 0x8b4d, 0x8b4e, 0x8b4f, 0x8b50, 0x8b51,
+    0x8b52, 0x8b53, 0x8b54, 0x8b55, 0x8b56, 0x8b57, 0x8b58, 0x8b59, 0x8b5a, 0x8b5b, 0x8b5c, 0x8b5d, 0x8b5e, 0x8b5f, 0x8b60, 0x8b61,
+    0x8b62, 0x8b63, 0x8b64, 0x8b65, 0x8b67, 0x8b68, 0x8b69, 0x8b6a, 0x8b6b, 0x8b6d, 0x8b6e, 0x8b6f, 0x8b70, 0x8b71, 0x8b72, 0x8b73,
+    0x8b74, 0x8b75, 0x8b76, 0x8b77, 0x8b78, 0x8b79, 0x8b7a, 0x8b7b, 0x8b7c, 0x8b7d, 0x8b7e, 0x8b7f, 0x8b80, 0x8b81, 0x8b82, 0x8b83,
+    0x8b84, 0x8b85, 0x8b86, 0x8b87, 0x8b88, 0x8b89, 0x8b8a, 0x8b8b, 0x8b8c, 0x8b8d, 0x8b8e, 0x8b8f, 0x8b90, 0x8b91, 0x8b92, 0x8b93,
+    0x8b94, 0x8b95, 0x8b96, 0x8b97, 0x8b98, 0x8b99, 0x8b9a, 0x8b9b, 0x8b9c, 0x8b9d, 0x8b9e, 0x8b9f, 0x8bac, 0x8bb1, 0x8bbb, 0x8bc7,
+    0x8bd0, 0x8bea, 0x8c09, 0x8c1e, 0x4f4f, 0x6ce8, 0x795d, 0x9a7b, 0x6293, 0x722a, 0x62fd, 0x4e13, 0x7816, 0x8f6c, 0x64b0, 0x8d5a,
+    0x7bc6, 0x6869, 0x5e84, 0x88c5, 0x5986, 0x649e, 0x58ee, 0x72b6, 0x690e, 0x9525, 0x8ffd, 0x8d58, 0x5760, 0x7f00, 0x8c06, 0x51c6,
+    0x6349, 0x62d9, 0x5353, 0x684c, 0x7422, 0x8301, 0x914c, 0x5544, 0x7740, 0x707c, 0x6d4a, 0x5179, 0x54a8, 0x8d44, 0x59ff, 0x6ecb,
+    0x6dc4, 0x5b5c, 0x7d2b, 0x4ed4, 0x7c7d, 0x6ed3, 0x5b50, 0x81ea, 0x6e0d, 0x5b57, 0x9b03, 0x68d5, 0x8e2a, 0x5b97, 0x7efc, 0x603b,
+    0x7eb5, 0x90b9, 0x8d70, 0x594f, 0x63cd, 0x79df, 0x8db3, 0x5352, 0x65cf, 0x7956, 0x8bc5, 0x963b, 0x7ec4, 0x94bb, 0x7e82, 0x5634,
+    0x9189, 0x6700, 0x7f6a, 0x5c0a, 0x9075, 0x6628, 0x5de6, 0x4f50, 0x67de, 0x505a, 0x4f5c, 0x5750, 0x5ea7, 0xe810, 0xe811, 0xe812,
+    0xe813, 0xe814, 0x8c38, 0x8c39, 0x8c3a, 0x8c3b, 0x8c3c, 0x8c3d, 0x8c3e, 0x8c3f, 0x8c40, 0x8c42, 0x8c43, 0x8c44, 0x8c45, 0x8c48,
+    0x8c4a, 0x8c4b, 0x8c4d, 0x8c4e, 0x8c4f, 0x8c50, 0x8c51, 0x8c52, 0x8c53, 0x8c54, 0x8c56, 0x8c57, 0x8c58, 0x8c59, 0x8c5b, 0x8c5c,
+    0x8c5d, 0x8c5e, 0x8c5f, 0x8c60, 0x8c63, 0x8c64, 0x8c65, 0x8c66, 0x8c67, 0x8c68, 0x8c69, 0x8c6c, 0x8c6d, 0x8c6e, 0x8c6f, 0x8c70,
+    0x8c71, 0x8c72, 0x8c74, 0x8c75, 0x8c76, 0x8c77, 0x8c7b, 0x8c7c, 0x8c7d, 0x8c7e, 0x8c7f, 0x8c80, 0x8c81, 0x8c83, 0x8c84, 0x8c86,
+    0x8c87, 0x8c88, 0x8c8b, 0x8c8d, 0x8c8e, 0x8c8f, 0x8c90, 0x8c91, 0x8c92, 0x8c93, 0x8c95, 0x8c96, 0x8c97, 0x8c99, 0x8c9a, 0x8c9b,
+    0x8c9c, 0x8c9d, 0x8c9e, 0x8c9f, 0x8ca0, 0x8ca1, 0x8ca2, 0x8ca3, 0x8ca4, 0x8ca5, 0x8ca6, 0x8ca7, 0x8ca8, 0x8ca9, 0x8caa, 0x8cab,
+    0x8cac, 0x8cad, 0x4e8d, 0x4e0c, 0x5140, 0x4e10, 0x5eff, 0x5345, 0x4e15, 0x4e98, 0x4e1e, 0x9b32, 0x5b6c, 0x5669, 0x4e28, 0x79ba,
+    0x4e3f, 0x5315, 0x4e47, 0x592d, 0x723b, 0x536e, 0x6c10, 0x56df, 0x80e4, 0x9997, 0x6bd3, 0x777e, 0x9f17, 0x4e36, 0x4e9f, 0x9f10,
+    0x4e5c, 0x4e69, 0x4e93, 0x8288, 0x5b5b, 0x556c, 0x560f, 0x4ec4, 0x538d, 0x539d, 0x53a3, 0x53a5, 0x53ae, 0x9765, 0x8d5d, 0x531a,
+    0x53f5, 0x5326, 0x532e, 0x533e, 0x8d5c, 0x5366, 0x5363, 0x5202, 0x5208, 0x520e, 0x522d, 0x5233, 0x523f, 0x5240, 0x524c, 0x525e,
+    0x5261, 0x525c, 0x84af, 0x527d, 0x5282, 0x5281, 0x5290, 0x5293, 0x5182, 0x7f54, 0x4ebb, 0x4ec3, 0x4ec9, 0x4ec2, 0x4ee8, 0x4ee1,
+    0x4eeb, 0x4ede, 0x4f1b, 0x4ef3, 0x4f22, 0x4f64, 0x4ef5, 0x4f25, 0x4f27, 0x4f09, 0x4f2b, 0x4f5e, 0x4f67, 0x6538, 0x4f5a, 0x4f5d,
+    0x8cae, 0x8caf, 0x8cb0, 0x8cb1, 0x8cb2, 0x8cb3, 0x8cb4, 0x8cb5, 0x8cb6, 0x8cb7, 0x8cb8, 0x8cb9, 0x8cba, 0x8cbb, 0x8cbc, 0x8cbd,
+    0x8cbe, 0x8cbf, 0x8cc0, 0x8cc1, 0x8cc2, 0x8cc3, 0x8cc4, 0x8cc5, 0x8cc6, 0x8cc7, 0x8cc8, 0x8cc9, 0x8cca, 0x8ccb, 0x8ccc, 0x8ccd,
+    0x8cce, 0x8ccf, 0x8cd0, 0x8cd1, 0x8cd2, 0x8cd3, 0x8cd4, 0x8cd5, 0x8cd6, 0x8cd7, 0x8cd8, 0x8cd9, 0x8cda, 0x8cdb, 0x8cdc, 0x8cdd,
+    0x8cde, 0x8cdf, 0x8ce0, 0x8ce1, 0x8ce2, 0x8ce3, 0x8ce4, 0x8ce5, 0x8ce6, 0x8ce7, 0x8ce8, 0x8ce9, 0x8cea, 0x8ceb, 0x8cec, 0x8ced,
+    0x8cee, 0x8cef, 0x8cf0, 0x8cf1, 0x8cf2, 0x8cf3, 0x8cf4, 0x8cf5, 0x8cf6, 0x8cf7, 0x8cf8, 0x8cf9, 0x8cfa, 0x8cfb, 0x8cfc, 0x8cfd,
+    0x8cfe, 0x8cff, 0x8d00, 0x8d01, 0x8d02, 0x8d03, 0x8d04, 0x8d05, 0x8d06, 0x8d07, 0x8d08, 0x8d09, 0x8d0a, 0x8d0b, 0x8d0c, 0x8d0d,
+    0x4f5f, 0x4f57, 0x4f32, 0x4f3d, 0x4f76, 0x4f74, 0x4f91, 0x4f89, 0x4f83, 0x4f8f, 0x4f7e, 0x4f7b, 0x4faa, 0x4f7c, 0x4fac, 0x4f94,
+    0x4fe6, 0x4fe8, 0x4fea, 0x4fc5, 0x4fda, 0x4fe3, 0x4fdc, 0x4fd1, 0x4fdf, 0x4ff8, 0x5029, 0x504c, 0x4ff3, 0x502c, 0x500f, 0x502e,
+    0x502d, 0x4ffe, 0x501c, 0x500c, 0x5025, 0x5028, 0x507e, 0x5043, 0x5055, 0x5048, 0x504e, 0x506c, 0x507b, 0x50a5, 0x50a7, 0x50a9,
+    0x50ba, 0x50d6, 0x5106, 0x50ed, 0x50ec, 0x50e6, 0x50ee, 0x5107, 0x510b, 0x4edd, 0x6c3d, 0x4f58, 0x4f65, 0x4fce, 0x9fa0, 0x6c46,
+    0x7c74, 0x516e, 0x5dfd, 0x9ec9, 0x9998, 0x5181, 0x5914, 0x52f9, 0x530d, 0x8a07, 0x5310, 0x51eb, 0x5919, 0x5155, 0x4ea0, 0x5156,
+    0x4eb3, 0x886e, 0x88a4, 0x4eb5, 0x8114, 0x88d2, 0x7980, 0x5b34, 0x8803, 0x7fb8, 0x51ab, 0x51b1, 0x51bd, 0x51bc, 0x8d0e, 0x8d0f,
+    0x8d10, 0x8d11, 0x8d12, 0x8d13, 0x8d14, 0x8d15, 0x8d16, 0x8d17, 0x8d18, 0x8d19, 0x8d1a, 0x8d1b, 0x8d1c, 0x8d20, 0x8d51, 0x8d52,
+    0x8d57, 0x8d5f, 0x8d65, 0x8d68, 0x8d69, 0x8d6a, 0x8d6c, 0x8d6e, 0x8d6f, 0x8d71, 0x8d72, 0x8d78, 0x8d79, 0x8d7a, 0x8d7b, 0x8d7c,
+    0x8d7d, 0x8d7e, 0x8d7f, 0x8d80, 0x8d82, 0x8d83, 0x8d86, 0x8d87, 0x8d88, 0x8d89, 0x8d8c, 0x8d8d, 0x8d8e, 0x8d8f, 0x8d90, 0x8d92,
+    0x8d93, 0x8d95, 0x8d96, 0x8d97, 0x8d98, 0x8d99, 0x8d9a, 0x8d9b, 0x8d9c, 0x8d9d, 0x8d9e, 0x8da0, 0x8da1, 0x8da2, 0x8da4, 0x8da5,
+    0x8da6, 0x8da7, 0x8da8, 0x8da9, 0x8daa, 0x8dab, 0x8dac, 0x8dad, 0x8dae, 0x8daf, 0x8db0, 0x8db2, 0x8db6, 0x8db7, 0x8db9, 0x8dbb,
+    0x8dbd, 0x8dc0, 0x8dc1, 0x8dc2, 0x8dc5, 0x8dc7, 0x8dc8, 0x8dc9, 0x8dca, 0x8dcd, 0x8dd0, 0x8dd2, 0x8dd3, 0x8dd4, 0x51c7, 0x5196,
+    0x51a2, 0x51a5, 0x8ba0, 0x8ba6, 0x8ba7, 0x8baa, 0x8bb4, 0x8bb5, 0x8bb7, 0x8bc2, 0x8bc3, 0x8bcb, 0x8bcf, 0x8bce, 0x8bd2, 0x8bd3,
+    0x8bd4, 0x8bd6, 0x8bd8, 0x8bd9, 0x8bdc, 0x8bdf, 0x8be0, 0x8be4, 0x8be8, 0x8be9, 0x8bee, 0x8bf0, 0x8bf3, 0x8bf6, 0x8bf9, 0x8bfc,
+    0x8bff, 0x8c00, 0x8c02, 0x8c04, 0x8c07, 0x8c0c, 0x8c0f, 0x8c11, 0x8c12, 0x8c14, 0x8c15, 0x8c16, 0x8c19, 0x8c1b, 0x8c18, 0x8c1d,
+    0x8c1f, 0x8c20, 0x8c21, 0x8c25, 0x8c27, 0x8c2a, 0x8c2b, 0x8c2e, 0x8c2f, 0x8c32, 0x8c33, 0x8c35, 0x8c36, 0x5369, 0x537a, 0x961d,
+    0x9622, 0x9621, 0x9631, 0x962a, 0x963d, 0x963c, 0x9642, 0x9649, 0x9654, 0x965f, 0x9667, 0x966c, 0x9672, 0x9674, 0x9688, 0x968d,
+    0x9697, 0x96b0, 0x9097, 0x909b, 0x909d, 0x9099, 0x90ac, 0x90a1, 0x90b4, 0x90b3, 0x90b6, 0x90ba, 0x8dd5, 0x8dd8, 0x8dd9, 0x8ddc,
+    0x8de0, 0x8de1, 0x8de2, 0x8de5, 0x8de6, 0x8de7, 0x8de9, 0x8ded, 0x8dee, 0x8df0, 0x8df1, 0x8df2, 0x8df4, 0x8df6, 0x8dfc, 0x8dfe,
+    0x8dff, 0x8e00, 0x8e01, 0x8e02, 0x8e03, 0x8e04, 0x8e06, 0x8e07, 0x8e08, 0x8e0b, 0x8e0d, 0x8e0e, 0x8e10, 0x8e11, 0x8e12, 0x8e13,
+    0x8e15, 0x8e16, 0x8e17, 0x8e18, 0x8e19, 0x8e1a, 0x8e1b, 0x8e1c, 0x8e20, 0x8e21, 0x8e24, 0x8e25, 0x8e26, 0x8e27, 0x8e28, 0x8e2b,
+    0x8e2d, 0x8e30, 0x8e32, 0x8e33, 0x8e34, 0x8e36, 0x8e37, 0x8e38, 0x8e3b, 0x8e3c, 0x8e3e, 0x8e3f, 0x8e43, 0x8e45, 0x8e46, 0x8e4c,
+    0x8e4d, 0x8e4e, 0x8e4f, 0x8e50, 0x8e53, 0x8e54, 0x8e55, 0x8e56, 0x8e57, 0x8e58, 0x8e5a, 0x8e5b, 0x8e5c, 0x8e5d, 0x8e5e, 0x8e5f,
+    0x8e60, 0x8e61, 0x8e62, 0x8e63, 0x8e64, 0x8e65, 0x8e67, 0x8e68, 0x8e6a, 0x8e6b, 0x8e6e, 0x8e71, 0x90b8, 0x90b0, 0x90cf, 0x90c5,
+    0x90be, 0x90d0, 0x90c4, 0x90c7, 0x90d3, 0x90e6, 0x90e2, 0x90dc, 0x90d7, 0x90db, 0x90eb, 0x90ef, 0x90fe, 0x9104, 0x9122, 0x911e,
+    0x9123, 0x9131, 0x912f, 0x9139, 0x9143, 0x9146, 0x520d, 0x5942, 0x52a2, 0x52ac, 0x52ad, 0x52be, 0x54ff, 0x52d0, 0x52d6, 0x52f0,
+    0x53df, 0x71ee, 0x77cd, 0x5ef4, 0x51f5, 0x51fc, 0x9b2f, 0x53b6, 0x5f01, 0x755a, 0x5def, 0x574c, 0x57a9, 0x57a1, 0x587e, 0x58bc,
+    0x58c5, 0x58d1, 0x5729, 0x572c, 0x572a, 0x5733, 0x5739, 0x572e, 0x572f, 0x575c, 0x573b, 0x5742, 0x5769, 0x5785, 0x576b, 0x5786,
+    0x577c, 0x577b, 0x5768, 0x576d, 0x5776, 0x5773, 0x57ad, 0x57a4, 0x578c, 0x57b2, 0x57cf, 0x57a7, 0x57b4, 0x5793, 0x57a0, 0x57d5,
+    0x57d8, 0x57da, 0x57d9, 0x57d2, 0x57b8, 0x57f4, 0x57ef, 0x57f8, 0x57e4, 0x57dd, 0x8e73, 0x8e75, 0x8e77, 0x8e78, 0x8e79, 0x8e7a,
+    0x8e7b, 0x8e7d, 0x8e7e, 0x8e80, 0x8e82, 0x8e83, 0x8e84, 0x8e86, 0x8e88, 0x8e89, 0x8e8a, 0x8e8b, 0x8e8c, 0x8e8d, 0x8e8e, 0x8e91,
+    0x8e92, 0x8e93, 0x8e95, 0x8e96, 0x8e97, 0x8e98, 0x8e99, 0x8e9a, 0x8e9b, 0x8e9d, 0x8e9f, 0x8ea0, 0x8ea1, 0x8ea2, 0x8ea3, 0x8ea4,
+    0x8ea5, 0x8ea6, 0x8ea7, 0x8ea8, 0x8ea9, 0x8eaa, 0x8ead, 0x8eae, 0x8eb0, 0x8eb1, 0x8eb3, 0x8eb4, 0x8eb5, 0x8eb6, 0x8eb7, 0x8eb8,
+    0x8eb9, 0x8ebb, 0x8ebc, 0x8ebd, 0x8ebe, 0x8ebf, 0x8ec0, 0x8ec1, 0x8ec2, 0x8ec3, 0x8ec4, 0x8ec5, 0x8ec6, 0x8ec7, 0x8ec8, 0x8ec9,
+    0x8eca, 0x8ecb, 0x8ecc, 0x8ecd, 0x8ecf, 0x8ed0, 0x8ed1, 0x8ed2, 0x8ed3, 0x8ed4, 0x8ed5, 0x8ed6, 0x8ed7, 0x8ed8, 0x8ed9, 0x8eda,
+    0x8edb, 0x8edc, 0x8edd, 0x8ede, 0x8edf, 0x8ee0, 0x8ee1, 0x8ee2, 0x8ee3, 0x8ee4, 0x580b, 0x580d, 0x57fd, 0x57ed, 0x5800, 0x581e,
+    0x5819, 0x5844, 0x5820, 0x5865, 0x586c, 0x5881, 0x5889, 0x589a, 0x5880, 0x99a8, 0x9f19, 0x61ff, 0x8279, 0x827d, 0x827f, 0x828f,
+    0x828a, 0x82a8, 0x8284, 0x828e, 0x8291, 0x8297, 0x8299, 0x82ab, 0x82b8, 0x82be, 0x82b0, 0x82c8, 0x82ca, 0x82e3, 0x8298, 0x82b7,
+    0x82ae, 0x82cb, 0x82cc, 0x82c1, 0x82a9, 0x82b4, 0x82a1, 0x82aa, 0x829f, 0x82c4, 0x82ce, 0x82a4, 0x82e1, 0x8309, 0x82f7, 0x82e4,
+    0x830f, 0x8307, 0x82dc, 0x82f4, 0x82d2, 0x82d8, 0x830c, 0x82fb, 0x82d3, 0x8311, 0x831a, 0x8306, 0x8314, 0x8315, 0x82e0, 0x82d5,
+    0x831c, 0x8351, 0x835b, 0x835c, 0x8308, 0x8392, 0x833c, 0x8334, 0x8331, 0x839b, 0x835e, 0x832f, 0x834f, 0x8347, 0x8343, 0x835f,
+    0x8340, 0x8317, 0x8360, 0x832d, 0x833a, 0x8333, 0x8366, 0x8365, 0x8ee5, 0x8ee6, 0x8ee7, 0x8ee8, 0x8ee9, 0x8eea, 0x8eeb, 0x8eec,
+    0x8eed, 0x8eee, 0x8eef, 0x8ef0, 0x8ef1, 0x8ef2, 0x8ef3, 0x8ef4, 0x8ef5, 0x8ef6, 0x8ef7, 0x8ef8, 0x8ef9, 0x8efa, 0x8efb, 0x8efc,
+    0x8efd, 0x8efe, 0x8eff, 0x8f00, 0x8f01, 0x8f02, 0x8f03, 0x8f04, 0x8f05, 0x8f06, 0x8f07, 0x8f08, 0x8f09, 0x8f0a, 0x8f0b, 0x8f0c,
+    0x8f0d, 0x8f0e, 0x8f0f, 0x8f10, 0x8f11, 0x8f12, 0x8f13, 0x8f14, 0x8f15, 0x8f16, 0x8f17, 0x8f18, 0x8f19, 0x8f1a, 0x8f1b, 0x8f1c,
+    0x8f1d, 0x8f1e, 0x8f1f, 0x8f20, 0x8f21, 0x8f22, 0x8f23, 0x8f24, 0x8f25, 0x8f26, 0x8f27, 0x8f28, 0x8f29, 0x8f2a, 0x8f2b, 0x8f2c,
+    0x8f2d, 0x8f2e, 0x8f2f, 0x8f30, 0x8f31, 0x8f32, 0x8f33, 0x8f34, 0x8f35, 0x8f36, 0x8f37, 0x8f38, 0x8f39, 0x8f3a, 0x8f3b, 0x8f3c,
+    0x8f3d, 0x8f3e, 0x8f3f, 0x8f40, 0x8f41, 0x8f42, 0x8f43, 0x8f44, 0x8368, 0x831b, 0x8369, 0x836c, 0x836a, 0x836d, 0x836e, 0x83b0,
+    0x8378, 0x83b3, 0x83b4, 0x83a0, 0x83aa, 0x8393, 0x839c, 0x8385, 0x837c, 0x83b6, 0x83a9, 0x837d, 0x83b8, 0x837b, 0x8398, 0x839e,
+    0x83a8, 0x83ba, 0x83bc, 0x83c1, 0x8401, 0x83e5, 0x83d8, 0x5807, 0x8418, 0x840b, 0x83dd, 0x83fd, 0x83d6, 0x841c, 0x8438, 0x8411,
+    0x8406, 0x83d4, 0x83df, 0x840f, 0x8403, 0x83f8, 0x83f9, 0x83ea, 0x83c5, 0x83c0, 0x8426, 0x83f0, 0x83e1, 0x845c, 0x8451, 0x845a,
+    0x8459, 0x8473, 0x8487, 0x8488, 0x847a, 0x8489, 0x8478, 0x843c, 0x8446, 0x8469, 0x8476, 0x848c, 0x848e, 0x8431, 0x846d, 0x84c1,
+    0x84cd, 0x84d0, 0x84e6, 0x84bd, 0x84d3, 0x84ca, 0x84bf, 0x84ba, 0x84e0, 0x84a1, 0x84b9, 0x84b4, 0x8497, 0x84e5, 0x84e3, 0x850c,
+    0x750d, 0x8538, 0x84f0, 0x8539, 0x851f, 0x853a, 0x8f45, 0x8f46, 0x8f47, 0x8f48, 0x8f49, 0x8f4a, 0x8f4b, 0x8f4c, 0x8f4d, 0x8f4e,
+    0x8f4f, 0x8f50, 0x8f51, 0x8f52, 0x8f53, 0x8f54, 0x8f55, 0x8f56, 0x8f57, 0x8f58, 0x8f59, 0x8f5a, 0x8f5b, 0x8f5c, 0x8f5d, 0x8f5e,
+    0x8f5f, 0x8f60, 0x8f61, 0x8f62, 0x8f63, 0x8f64, 0x8f65, 0x8f6a, 0x8f80, 0x8f8c, 0x8f92, 0x8f9d, 0x8fa0, 0x8fa1, 0x8fa2, 0x8fa4,
+    0x8fa5, 0x8fa6, 0x8fa7, 0x8faa, 0x8fac, 0x8fad, 0x8fae, 0x8faf, 0x8fb2, 0x8fb3, 0x8fb4, 0x8fb5, 0x8fb7, 0x8fb8, 0x8fba, 0x8fbb,
+    0x8fbc, 0x8fbf, 0x8fc0, 0x8fc3, 0x8fc6, 0x8fc9, 0x8fca, 0x8fcb, 0x8fcc, 0x8fcd, 0x8fcf, 0x8fd2, 0x8fd6, 0x8fd7, 0x8fda, 0x8fe0,
+    0x8fe1, 0x8fe3, 0x8fe7, 0x8fec, 0x8fef, 0x8ff1, 0x8ff2, 0x8ff4, 0x8ff5, 0x8ff6, 0x8ffa, 0x8ffb, 0x8ffc, 0x8ffe, 0x8fff, 0x9007,
+    0x9008, 0x900c, 0x900e, 0x9013, 0x9015, 0x9018, 0x8556, 0x853b, 0x84ff, 0x84fc, 0x8559, 0x8548, 0x8568, 0x8564, 0x855e, 0x857a,
+    0x77a2, 0x8543, 0x8572, 0x857b, 0x85a4, 0x85a8, 0x8587, 0x858f, 0x8579, 0x85ae, 0x859c, 0x8585, 0x85b9, 0x85b7, 0x85b0, 0x85d3,
+    0x85c1, 0x85dc, 0x85ff, 0x8627, 0x8605, 0x8629, 0x8616, 0x863c, 0x5efe, 0x5f08, 0x593c, 0x5941, 0x8037, 0x5955, 0x595a, 0x5958,
+    0x530f, 0x5c22, 0x5c25, 0x5c2c, 0x5c34, 0x624c, 0x626a, 0x629f, 0x62bb, 0x62ca, 0x62da, 0x62d7, 0x62ee, 0x6322, 0x62f6, 0x6339,
+    0x634b, 0x6343, 0x63ad, 0x63f6, 0x6371, 0x637a, 0x638e, 0x63b4, 0x636d, 0x63ac, 0x638a, 0x6369, 0x63ae, 0x63bc, 0x63f2, 0x63f8,
+    0x63e0, 0x63ff, 0x63c4, 0x63de, 0x63ce, 0x6452, 0x63c6, 0x63be, 0x6445, 0x6441, 0x640b, 0x641b, 0x6420, 0x640c, 0x6426, 0x6421,
+    0x645e, 0x6484, 0x646d, 0x6496, 0x9019, 0x901c, 0x9023, 0x9024, 0x9025, 0x9027, 0x9028, 0x9029, 0x902a, 0x902b, 0x902c, 0x9030,
+    0x9031, 0x9032, 0x9033, 0x9034, 0x9037, 0x9039, 0x903a, 0x903d, 0x903f, 0x9040, 0x9043, 0x9045, 0x9046, 0x9048, 0x9049, 0x904a,
+    0x904b, 0x904c, 0x904e, 0x9054, 0x9055, 0x9056, 0x9059, 0x905a, 0x905c, 0x905d, 0x905e, 0x905f, 0x9060, 0x9061, 0x9064, 0x9066,
+    0x9067, 0x9069, 0x906a, 0x906b, 0x906c, 0x906f, 0x9070, 0x9071, 0x9072, 0x9073, 0x9076, 0x9077, 0x9078, 0x9079, 0x907a, 0x907b,
+    0x907c, 0x907e, 0x9081, 0x9084, 0x9085, 0x9086, 0x9087, 0x9089, 0x908a, 0x908c, 0x908d, 0x908e, 0x908f, 0x9090, 0x9092, 0x9094,
+    0x9096, 0x9098, 0x909a, 0x909c, 0x909e, 0x909f, 0x90a0, 0x90a4, 0x90a5, 0x90a7, 0x90a8, 0x90a9, 0x90ab, 0x90ad, 0x90b2, 0x90b7,
+    0x90bc, 0x90bd, 0x90bf, 0x90c0, 0x647a, 0x64b7, 0x64b8, 0x6499, 0x64ba, 0x64c0, 0x64d0, 0x64d7, 0x64e4, 0x64e2, 0x6509, 0x6525,
+    0x652e, 0x5f0b, 0x5fd2, 0x7519, 0x5f11, 0x535f, 0x53f1, 0x53fd, 0x53e9, 0x53e8, 0x53fb, 0x5412, 0x5416, 0x5406, 0x544b, 0x5452,
+    0x5453, 0x5454, 0x5456, 0x5443, 0x5421, 0x5457, 0x5459, 0x5423, 0x5432, 0x5482, 0x5494, 0x5477, 0x5471, 0x5464, 0x549a, 0x549b,
+    0x5484, 0x5476, 0x5466, 0x549d, 0x54d0, 0x54ad, 0x54c2, 0x54b4, 0x54d2, 0x54a7, 0x54a6, 0x54d3, 0x54d4, 0x5472, 0x54a3, 0x54d5,
+    0x54bb, 0x54bf, 0x54cc, 0x54d9, 0x54da, 0x54dc, 0x54a9, 0x54aa, 0x54a4, 0x54dd, 0x54cf, 0x54de, 0x551b, 0x54e7, 0x5520, 0x54fd,
+    0x5514, 0x54f3, 0x5522, 0x5523, 0x550f, 0x5511, 0x5527, 0x552a, 0x5567, 0x558f, 0x55b5, 0x5549, 0x556d, 0x5541, 0x5555, 0x553f,
+    0x5550, 0x553c, 0x90c2, 0x90c3, 0x90c6, 0x90c8, 0x90c9, 0x90cb, 0x90cc, 0x90cd, 0x90d2, 0x90d4, 0x90d5, 0x90d6, 0x90d8, 0x90d9,
+    0x90da, 0x90de, 0x90df, 0x90e0, 0x90e3, 0x90e4, 0x90e5, 0x90e9, 0x90ea, 0x90ec, 0x90ee, 0x90f0, 0x90f1, 0x90f2, 0x90f3, 0x90f5,
+    0x90f6, 0x90f7, 0x90f9, 0x90fa, 0x90fb, 0x90fc, 0x90ff, 0x9100, 0x9101, 0x9103, 0x9105, 0x9106, 0x9107, 0x9108, 0x9109, 0x910a,
+    0x910b, 0x910c, 0x910d, 0x910e, 0x910f, 0x9110, 0x9111, 0x9112, 0x9113, 0x9114, 0x9115, 0x9116, 0x9117, 0x9118, 0x911a, 0x911b,
+    0x911c, 0x911d, 0x911f, 0x9120, 0x9121, 0x9124, 0x9125, 0x9126, 0x9127, 0x9128, 0x9129, 0x912a, 0x912b, 0x912c, 0x912d, 0x912e,
+    0x9130, 0x9132, 0x9133, 0x9134, 0x9135, 0x9136, 0x9137, 0x9138, 0x913a, 0x913b, 0x913c, 0x913d, 0x913e, 0x913f, 0x9140, 0x9141,
+    0x9142, 0x9144, 0x5537, 0x5556, 0x5575, 0x5576, 0x5577, 0x5533, 0x5530, 0x555c, 0x558b, 0x55d2, 0x5583, 0x55b1, 0x55b9, 0x5588,
+    0x5581, 0x559f, 0x557e, 0x55d6, 0x5591, 0x557b, 0x55df, 0x55bd, 0x55be, 0x5594, 0x5599, 0x55ea, 0x55f7, 0x55c9, 0x561f, 0x55d1,
+    0x55eb, 0x55ec, 0x55d4, 0x55e6, 0x55dd, 0x55c4, 0x55ef, 0x55e5, 0x55f2, 0x55f3, 0x55cc, 0x55cd, 0x55e8, 0x55f5, 0x55e4, 0x8f94,
+    0x561e, 0x5608, 0x560c, 0x5601, 0x5624, 0x5623, 0x55fe, 0x5600, 0x5627, 0x562d, 0x5658, 0x5639, 0x5657, 0x562c, 0x564d, 0x5662,
+    0x5659, 0x565c, 0x564c, 0x5654, 0x5686, 0x5664, 0x5671, 0x566b, 0x567b, 0x567c, 0x5685, 0x5693, 0x56af, 0x56d4, 0x56d7, 0x56dd,
+    0x56e1, 0x56f5, 0x56eb, 0x56f9, 0x56ff, 0x5704, 0x570a, 0x5709, 0x571c, 0x5e0f, 0x5e19, 0x5e14, 0x5e11, 0x5e31, 0x5e3b, 0x5e3c,
+    0x9145, 0x9147, 0x9148, 0x9151, 0x9153, 0x9154, 0x9155, 0x9156, 0x9158, 0x9159, 0x915b, 0x915c, 0x915f, 0x9160, 0x9166, 0x9167,
+    0x9168, 0x916b, 0x916d, 0x9173, 0x917a, 0x917b, 0x917c, 0x9180, 0x9181, 0x9182, 0x9183, 0x9184, 0x9186, 0x9188, 0x918a, 0x918e,
+    0x918f, 0x9193, 0x9194, 0x9195, 0x9196, 0x9197, 0x9198, 0x9199, 0x919c, 0x919d, 0x919e, 0x919f, 0x91a0, 0x91a1, 0x91a4, 0x91a5,
+    0x91a6, 0x91a7, 0x91a8, 0x91a9, 0x91ab, 0x91ac, 0x91b0, 0x91b1, 0x91b2, 0x91b3, 0x91b6, 0x91b7, 0x91b8, 0x91b9, 0x91bb, 0x91bc,
+    0x91bd, 0x91be, 0x91bf, 0x91c0, 0x91c1, 0x91c2, 0x91c3, 0x91c4, 0x91c5, 0x91c6, 0x91c8, 0x91cb, 0x91d0, 0x91d2, 0x91d3, 0x91d4,
+    0x91d5, 0x91d6, 0x91d7, 0x91d8, 0x91d9, 0x91da, 0x91db, 0x91dd, 0x91de, 0x91df, 0x91e0, 0x91e1, 0x91e2, 0x91e3, 0x91e4, 0x91e5,
+    0x5e37, 0x5e44, 0x5e54, 0x5e5b, 0x5e5e, 0x5e61, 0x5c8c, 0x5c7a, 0x5c8d, 0x5c90, 0x5c96, 0x5c88, 0x5c98, 0x5c99, 0x5c91, 0x5c9a,
+    0x5c9c, 0x5cb5, 0x5ca2, 0x5cbd, 0x5cac, 0x5cab, 0x5cb1, 0x5ca3, 0x5cc1, 0x5cb7, 0x5cc4, 0x5cd2, 0x5ce4, 0x5ccb, 0x5ce5, 0x5d02,
+    0x5d03, 0x5d27, 0x5d26, 0x5d2e, 0x5d24, 0x5d1e, 0x5d06, 0x5d1b, 0x5d58, 0x5d3e, 0x5d34, 0x5d3d, 0x5d6c, 0x5d5b, 0x5d6f, 0x5d5d,
+    0x5d6b, 0x5d4b, 0x5d4a, 0x5d69, 0x5d74, 0x5d82, 0x5d99, 0x5d9d, 0x8c73, 0x5db7, 0x5dc5, 0x5f73, 0x5f77, 0x5f82, 0x5f87, 0x5f89,
+    0x5f8c, 0x5f95, 0x5f99, 0x5f9c, 0x5fa8, 0x5fad, 0x5fb5, 0x5fbc, 0x8862, 0x5f61, 0x72ad, 0x72b0, 0x72b4, 0x72b7, 0x72b8, 0x72c3,
+    0x72c1, 0x72ce, 0x72cd, 0x72d2, 0x72e8, 0x72ef, 0x72e9, 0x72f2, 0x72f4, 0x72f7, 0x7301, 0x72f3, 0x7303, 0x72fa, 0x91e6, 0x91e7,
+    0x91e8, 0x91e9, 0x91ea, 0x91eb, 0x91ec, 0x91ed, 0x91ee, 0x91ef, 0x91f0, 0x91f1, 0x91f2, 0x91f3, 0x91f4, 0x91f5, 0x91f6, 0x91f7,
+    0x91f8, 0x91f9, 0x91fa, 0x91fb, 0x91fc, 0x91fd, 0x91fe, 0x91ff, 0x9200, 0x9201, 0x9202, 0x9203, 0x9204, 0x9205, 0x9206, 0x9207,
+    0x9208, 0x9209, 0x920a, 0x920b, 0x920c, 0x920d, 0x920e, 0x920f, 0x9210, 0x9211, 0x9212, 0x9213, 0x9214, 0x9215, 0x9216, 0x9217,
+    0x9218, 0x9219, 0x921a, 0x921b, 0x921c, 0x921d, 0x921e, 0x921f, 0x9220, 0x9221, 0x9222, 0x9223, 0x9224, 0x9225, 0x9226, 0x9227,
+    0x9228, 0x9229, 0x922a, 0x922b, 0x922c, 0x922d, 0x922e, 0x922f, 0x9230, 0x9231, 0x9232, 0x9233, 0x9234, 0x9235, 0x9236, 0x9237,
+    0x9238, 0x9239, 0x923a, 0x923b, 0x923c, 0x923d, 0x923e, 0x923f, 0x9240, 0x9241, 0x9242, 0x9243, 0x9244, 0x9245, 0x72fb, 0x7317,
+    0x7313, 0x7321, 0x730a, 0x731e, 0x731d, 0x7315, 0x7322, 0x7339, 0x7325, 0x732c, 0x7338, 0x7331, 0x7350, 0x734d, 0x7357, 0x7360,
+    0x736c, 0x736f, 0x737e, 0x821b, 0x5925, 0x98e7, 0x5924, 0x5902, 0x9963, 0x9967, 0x9968, 0x9969, 0x996a, 0x996b, 0x996c, 0x9974,
+    0x9977, 0x997d, 0x9980, 0x9984, 0x9987, 0x998a, 0x998d, 0x9990, 0x9991, 0x9993, 0x9994, 0x9995, 0x5e80, 0x5e91, 0x5e8b, 0x5e96,
+    0x5ea5, 0x5ea0, 0x5eb9, 0x5eb5, 0x5ebe, 0x5eb3, 0x8d53, 0x5ed2, 0x5ed1, 0x5edb, 0x5ee8, 0x5eea, 0x81ba, 0x5fc4, 0x5fc9, 0x5fd6,
+    0x5fcf, 0x6003, 0x5fee, 0x6004, 0x5fe1, 0x5fe4, 0x5ffe, 0x6005, 0x6006, 0x5fea, 0x5fed, 0x5ff8, 0x6019, 0x6035, 0x6026, 0x601b,
+    0x600f, 0x600d, 0x6029, 0x602b, 0x600a, 0x603f, 0x6021, 0x6078, 0x6079, 0x607b, 0x607a, 0x6042, 0x9246, 0x9247, 0x9248, 0x9249,
+    0x924a, 0x924b, 0x924c, 0x924d, 0x924e, 0x924f, 0x9250, 0x9251, 0x9252, 0x9253, 0x9254, 0x9255, 0x9256, 0x9257, 0x9258, 0x9259,
+    0x925a, 0x925b, 0x925c, 0x925d, 0x925e, 0x925f, 0x9260, 0x9261, 0x9262, 0x9263, 0x9264, 0x9265, 0x9266, 0x9267, 0x9268, 0x9269,
+    0x926a, 0x926b, 0x926c, 0x926d, 0x926e, 0x926f, 0x9270, 0x9271, 0x9272, 0x9273, 0x9275, 0x9276, 0x9277, 0x9278, 0x9279, 0x927a,
+    0x927b, 0x927c, 0x927d, 0x927e, 0x927f, 0x9280, 0x9281, 0x9282, 0x9283, 0x9284, 0x9285, 0x9286, 0x9287, 0x9288, 0x9289, 0x928a,
+    0x928b, 0x928c, 0x928d, 0x928f, 0x9290, 0x9291, 0x9292, 0x9293, 0x9294, 0x9295, 0x9296, 0x9297, 0x9298, 0x9299, 0x929a, 0x929b,
+    0x929c, 0x929d, 0x929e, 0x929f, 0x92a0, 0x92a1, 0x92a2, 0x92a3, 0x92a4, 0x92a5, 0x92a6, 0x92a7, 0x606a, 0x607d, 0x6096, 0x609a,
+    0x60ad, 0x609d, 0x6083, 0x6092, 0x608c, 0x609b, 0x60ec, 0x60bb, 0x60b1, 0x60dd, 0x60d8, 0x60c6, 0x60da, 0x60b4, 0x6120, 0x6126,
+    0x6115, 0x6123, 0x60f4, 0x6100, 0x610e, 0x612b, 0x614a, 0x6175, 0x61ac, 0x6194, 0x61a7, 0x61b7, 0x61d4, 0x61f5, 0x5fdd, 0x96b3,
+    0x95e9, 0x95eb, 0x95f1, 0x95f3, 0x95f5, 0x95f6, 0x95fc, 0x95fe, 0x9603, 0x9604, 0x9606, 0x9608, 0x960a, 0x960b, 0x960c, 0x960d,
+    0x960f, 0x9612, 0x9615, 0x9616, 0x9617, 0x9619, 0x961a, 0x4e2c, 0x723f, 0x6215, 0x6c35, 0x6c54, 0x6c5c, 0x6c4a, 0x6ca3, 0x6c85,
+    0x6c90, 0x6c94, 0x6c8c, 0x6c68, 0x6c69, 0x6c74, 0x6c76, 0x6c86, 0x6ca9, 0x6cd0, 0x6cd4, 0x6cad, 0x6cf7, 0x6cf8, 0x6cf1, 0x6cd7,
+    0x6cb2, 0x6ce0, 0x6cd6, 0x6cfa, 0x6ceb, 0x6cee, 0x6cb1, 0x6cd3, 0x6cef, 0x6cfe, 0x92a8, 0x92a9, 0x92aa, 0x92ab, 0x92ac, 0x92ad,
+    0x92af, 0x92b0, 0x92b1, 0x92b2, 0x92b3, 0x92b4, 0x92b5, 0x92b6, 0x92b7, 0x92b8, 0x92b9, 0x92ba, 0x92bb, 0x92bc, 0x92bd, 0x92be,
+    0x92bf, 0x92c0, 0x92c1, 0x92c2, 0x92c3, 0x92c4, 0x92c5, 0x92c6, 0x92c7, 0x92c9, 0x92ca, 0x92cb, 0x92cc, 0x92cd, 0x92ce, 0x92cf,
+    0x92d0, 0x92d1, 0x92d2, 0x92d3, 0x92d4, 0x92d5, 0x92d6, 0x92d7, 0x92d8, 0x92d9, 0x92da, 0x92db, 0x92dc, 0x92dd, 0x92de, 0x92df,
+    0x92e0, 0x92e1, 0x92e2, 0x92e3, 0x92e4, 0x92e5, 0x92e6, 0x92e7, 0x92e8, 0x92e9, 0x92ea, 0x92eb, 0x92ec, 0x92ed, 0x92ee, 0x92ef,
+    0x92f0, 0x92f1, 0x92f2, 0x92f3, 0x92f4, 0x92f5, 0x92f6, 0x92f7, 0x92f8, 0x92f9, 0x92fa, 0x92fb, 0x92fc, 0x92fd, 0x92fe, 0x92ff,
+    0x9300, 0x9301, 0x9302, 0x9303, 0x9304, 0x9305, 0x9306, 0x9307, 0x9308, 0x9309, 0x6d39, 0x6d27, 0x6d0c, 0x6d43, 0x6d48, 0x6d07,
+    0x6d04, 0x6d19, 0x6d0e, 0x6d2b, 0x6d4d, 0x6d2e, 0x6d35, 0x6d1a, 0x6d4f, 0x6d52, 0x6d54, 0x6d33, 0x6d91, 0x6d6f, 0x6d9e, 0x6da0,
+    0x6d5e, 0x6d93, 0x6d94, 0x6d5c, 0x6d60, 0x6d7c, 0x6d63, 0x6e1a, 0x6dc7, 0x6dc5, 0x6dde, 0x6e0e, 0x6dbf, 0x6de0, 0x6e11, 0x6de6,
+    0x6ddd, 0x6dd9, 0x6e16, 0x6dab, 0x6e0c, 0x6dae, 0x6e2b, 0x6e6e, 0x6e4e, 0x6e6b, 0x6eb2, 0x6e5f, 0x6e86, 0x6e53, 0x6e54, 0x6e32,
+    0x6e25, 0x6e44, 0x6edf, 0x6eb1, 0x6e98, 0x6ee0, 0x6f2d, 0x6ee2, 0x6ea5, 0x6ea7, 0x6ebd, 0x6ebb, 0x6eb7, 0x6ed7, 0x6eb4, 0x6ecf,
+    0x6e8f, 0x6ec2, 0x6e9f, 0x6f62, 0x6f46, 0x6f47, 0x6f24, 0x6f15, 0x6ef9, 0x6f2f, 0x6f36, 0x6f4b, 0x6f74, 0x6f2a, 0x6f09, 0x6f29,
+    0x6f89, 0x6f8d, 0x6f8c, 0x6f78, 0x6f72, 0x6f7c, 0x6f7a, 0x6fd1, 0x930a, 0x930b, 0x930c, 0x930d, 0x930e, 0x930f, 0x9310, 0x9311,
+    0x9312, 0x9313, 0x9314, 0x9315, 0x9316, 0x9317, 0x9318, 0x9319, 0x931a, 0x931b, 0x931c, 0x931d, 0x931e, 0x931f, 0x9320, 0x9321,
+    0x9322, 0x9323, 0x9324, 0x9325, 0x9326, 0x9327, 0x9328, 0x9329, 0x932a, 0x932b, 0x932c, 0x932d, 0x932e, 0x932f, 0x9330, 0x9331,
+    0x9332, 0x9333, 0x9334, 0x9335, 0x9336, 0x9337, 0x9338, 0x9339, 0x933a, 0x933b, 0x933c, 0x933d, 0x933f, 0x9340, 0x9341, 0x9342,
+    0x9343, 0x9344, 0x9345, 0x9346, 0x9347, 0x9348, 0x9349, 0x934a, 0x934b, 0x934c, 0x934d, 0x934e, 0x934f, 0x9350, 0x9351, 0x9352,
+    0x9353, 0x9354, 0x9355, 0x9356, 0x9357, 0x9358, 0x9359, 0x935a, 0x935b, 0x935c, 0x935d, 0x935e, 0x935f, 0x9360, 0x9361, 0x9362,
+    0x9363, 0x9364, 0x9365, 0x9366, 0x9367, 0x9368, 0x9369, 0x936b, 0x6fc9, 0x6fa7, 0x6fb9, 0x6fb6, 0x6fc2, 0x6fe1, 0x6fee, 0x6fde,
+    0x6fe0, 0x6fef, 0x701a, 0x7023, 0x701b, 0x7039, 0x7035, 0x704f, 0x705e, 0x5b80, 0x5b84, 0x5b95, 0x5b93, 0x5ba5, 0x5bb8, 0x752f,
+    0x9a9e, 0x6434, 0x5be4, 0x5bee, 0x8930, 0x5bf0, 0x8e47, 0x8b07, 0x8fb6, 0x8fd3, 0x8fd5, 0x8fe5, 0x8fee, 0x8fe4, 0x8fe9, 0x8fe6,
+    0x8ff3, 0x8fe8, 0x9005, 0x9004, 0x900b, 0x9026, 0x9011, 0x900d, 0x9016, 0x9021, 0x9035, 0x9036, 0x902d, 0x902f, 0x9044, 0x9051,
+    0x9052, 0x9050, 0x9068, 0x9058, 0x9062, 0x905b, 0x66b9, 0x9074, 0x907d, 0x9082, 0x9088, 0x9083, 0x908b, 0x5f50, 0x5f57, 0x5f56,
+    0x5f58, 0x5c3b, 0x54ab, 0x5c50, 0x5c59, 0x5b71, 0x5c63, 0x5c66, 0x7fbc, 0x5f2a, 0x5f29, 0x5f2d, 0x8274, 0x5f3c, 0x9b3b, 0x5c6e,
+    0x5981, 0x5983, 0x598d, 0x59a9, 0x59aa, 0x59a3, 0x936c, 0x936d, 0x936e, 0x936f, 0x9370, 0x9371, 0x9372, 0x9373, 0x9374, 0x9375,
+    0x9376, 0x9377, 0x9378, 0x9379, 0x937a, 0x937b, 0x937c, 0x937d, 0x937e, 0x937f, 0x9380, 0x9381, 0x9382, 0x9383, 0x9384, 0x9385,
+    0x9386, 0x9387, 0x9388, 0x9389, 0x938a, 0x938b, 0x938c, 0x938d, 0x938e, 0x9390, 0x9391, 0x9392, 0x9393, 0x9394, 0x9395, 0x9396,
+    0x9397, 0x9398, 0x9399, 0x939a, 0x939b, 0x939c, 0x939d, 0x939e, 0x939f, 0x93a0, 0x93a1, 0x93a2, 0x93a3, 0x93a4, 0x93a5, 0x93a6,
+    0x93a7, 0x93a8, 0x93a9, 0x93aa, 0x93ab, 0x93ac, 0x93ad, 0x93ae, 0x93af, 0x93b0, 0x93b1, 0x93b2, 0x93b3, 0x93b4, 0x93b5, 0x93b6,
+    0x93b7, 0x93b8, 0x93b9, 0x93ba, 0x93bb, 0x93bc, 0x93bd, 0x93be, 0x93bf, 0x93c0, 0x93c1, 0x93c2, 0x93c3, 0x93c4, 0x93c5, 0x93c6,
+    0x93c7, 0x93c8, 0x93c9, 0x93cb, 0x93cc, 0x93cd, 0x5997, 0x59ca, 0x59ab, 0x599e, 0x59a4, 0x59d2, 0x59b2, 0x59af, 0x59d7, 0x59be,
+    0x5a05, 0x5a06, 0x59dd, 0x5a08, 0x59e3, 0x59d8, 0x59f9, 0x5a0c, 0x5a09, 0x5a32, 0x5a34, 0x5a11, 0x5a23, 0x5a13, 0x5a40, 0x5a67,
+    0x5a4a, 0x5a55, 0x5a3c, 0x5a62, 0x5a75, 0x80ec, 0x5aaa, 0x5a9b, 0x5a77, 0x5a7a, 0x5abe, 0x5aeb, 0x5ab2, 0x5ad2, 0x5ad4, 0x5ab8,
+    0x5ae0, 0x5ae3, 0x5af1, 0x5ad6, 0x5ae6, 0x5ad8, 0x5adc, 0x5b09, 0x5b17, 0x5b16, 0x5b32, 0x5b37, 0x5b40, 0x5c15, 0x5c1c, 0x5b5a,
+    0x5b65, 0x5b73, 0x5b51, 0x5b53, 0x5b62, 0x9a75, 0x9a77, 0x9a78, 0x9a7a, 0x9a7f, 0x9a7d, 0x9a80, 0x9a81, 0x9a85, 0x9a88, 0x9a8a,
+    0x9a90, 0x9a92, 0x9a93, 0x9a96, 0x9a98, 0x9a9b, 0x9a9c, 0x9a9d, 0x9a9f, 0x9aa0, 0x9aa2, 0x9aa3, 0x9aa5, 0x9aa7, 0x7e9f, 0x7ea1,
+    0x7ea3, 0x7ea5, 0x7ea8, 0x7ea9, 0x93ce, 0x93cf, 0x93d0, 0x93d1, 0x93d2, 0x93d3, 0x93d4, 0x93d5, 0x93d7, 0x93d8, 0x93d9, 0x93da,
+    0x93db, 0x93dc, 0x93dd, 0x93de, 0x93df, 0x93e0, 0x93e1, 0x93e2, 0x93e3, 0x93e4, 0x93e5, 0x93e6, 0x93e7, 0x93e8, 0x93e9, 0x93ea,
+    0x93eb, 0x93ec, 0x93ed, 0x93ee, 0x93ef, 0x93f0, 0x93f1, 0x93f2, 0x93f3, 0x93f4, 0x93f5, 0x93f6, 0x93f7, 0x93f8, 0x93f9, 0x93fa,
+    0x93fb, 0x93fc, 0x93fd, 0x93fe, 0x93ff, 0x9400, 0x9401, 0x9402, 0x9403, 0x9404, 0x9405, 0x9406, 0x9407, 0x9408, 0x9409, 0x940a,
+    0x940b, 0x940c, 0x940d, 0x940e, 0x940f, 0x9410, 0x9411, 0x9412, 0x9413, 0x9414, 0x9415, 0x9416, 0x9417, 0x9418, 0x9419, 0x941a,
+    0x941b, 0x941c, 0x941d, 0x941e, 0x941f, 0x9420, 0x9421, 0x9422, 0x9423, 0x9424, 0x9425, 0x9426, 0x9427, 0x9428, 0x9429, 0x942a,
+    0x942b, 0x942c, 0x942d, 0x942e, 0x7ead, 0x7eb0, 0x7ebe, 0x7ec0, 0x7ec1, 0x7ec2, 0x7ec9, 0x7ecb, 0x7ecc, 0x7ed0, 0x7ed4, 0x7ed7,
+    0x7edb, 0x7ee0, 0x7ee1, 0x7ee8, 0x7eeb, 0x7eee, 0x7eef, 0x7ef1, 0x7ef2, 0x7f0d, 0x7ef6, 0x7efa, 0x7efb, 0x7efe, 0x7f01, 0x7f02,
+    0x7f03, 0x7f07, 0x7f08, 0x7f0b, 0x7f0c, 0x7f0f, 0x7f11, 0x7f12, 0x7f17, 0x7f19, 0x7f1c, 0x7f1b, 0x7f1f, 0x7f21, 0x7f22, 0x7f23,
+    0x7f24, 0x7f25, 0x7f26, 0x7f27, 0x7f2a, 0x7f2b, 0x7f2c, 0x7f2d, 0x7f2f, 0x7f30, 0x7f31, 0x7f32, 0x7f33, 0x7f35, 0x5e7a, 0x757f,
+    0x5ddb, 0x753e, 0x9095, 0x738e, 0x7391, 0x73ae, 0x73a2, 0x739f, 0x73cf, 0x73c2, 0x73d1, 0x73b7, 0x73b3, 0x73c0, 0x73c9, 0x73c8,
+    0x73e5, 0x73d9, 0x987c, 0x740a, 0x73e9, 0x73e7, 0x73de, 0x73ba, 0x73f2, 0x740f, 0x742a, 0x745b, 0x7426, 0x7425, 0x7428, 0x7430,
+    0x742e, 0x742c, 0x942f, 0x9430, 0x9431, 0x9432, 0x9433, 0x9434, 0x9435, 0x9436, 0x9437, 0x9438, 0x9439, 0x943a, 0x943b, 0x943c,
+    0x943d, 0x943f, 0x9440, 0x9441, 0x9442, 0x9443, 0x9444, 0x9445, 0x9446, 0x9447, 0x9448, 0x9449, 0x944a, 0x944b, 0x944c, 0x944d,
+    0x944e, 0x944f, 0x9450, 0x9451, 0x9452, 0x9453, 0x9454, 0x9455, 0x9456, 0x9457, 0x9458, 0x9459, 0x945a, 0x945b, 0x945c, 0x945d,
+    0x945e, 0x945f, 0x9460, 0x9461, 0x9462, 0x9463, 0x9464, 0x9465, 0x9466, 0x9467, 0x9468, 0x9469, 0x946a, 0x946c, 0x946d, 0x946e,
+    0x946f, 0x9470, 0x9471, 0x9472, 0x9473, 0x9474, 0x9475, 0x9476, 0x9477, 0x9478, 0x9479, 0x947a, 0x947b, 0x947c, 0x947d, 0x947e,
+    0x947f, 0x9480, 0x9481, 0x9482, 0x9483, 0x9484, 0x9491, 0x9496, 0x9498, 0x94c7, 0x94cf, 0x94d3, 0x94d4, 0x94da, 0x94e6, 0x94fb,
+    0x951c, 0x9520, 0x741b, 0x741a, 0x7441, 0x745c, 0x7457, 0x7455, 0x7459, 0x7477, 0x746d, 0x747e, 0x749c, 0x748e, 0x7480, 0x7481,
+    0x7487, 0x748b, 0x749e, 0x74a8, 0x74a9, 0x7490, 0x74a7, 0x74d2, 0x74ba, 0x97ea, 0x97eb, 0x97ec, 0x674c, 0x6753, 0x675e, 0x6748,
+    0x6769, 0x67a5, 0x6787, 0x676a, 0x6773, 0x6798, 0x67a7, 0x6775, 0x67a8, 0x679e, 0x67ad, 0x678b, 0x6777, 0x677c, 0x67f0, 0x6809,
+    0x67d8, 0x680a, 0x67e9, 0x67b0, 0x680c, 0x67d9, 0x67b5, 0x67da, 0x67b3, 0x67dd, 0x6800, 0x67c3, 0x67b8, 0x67e2, 0x680e, 0x67c1,
+    0x67fd, 0x6832, 0x6833, 0x6860, 0x6861, 0x684e, 0x6862, 0x6844, 0x6864, 0x6883, 0x681d, 0x6855, 0x6866, 0x6841, 0x6867, 0x6840,
+    0x683e, 0x684a, 0x6849, 0x6829, 0x68b5, 0x688f, 0x6874, 0x6877, 0x6893, 0x686b, 0x68c2, 0x696e, 0x68fc, 0x691f, 0x6920, 0x68f9,
+    0x9527, 0x9533, 0x953d, 0x9543, 0x9548, 0x954b, 0x9555, 0x955a, 0x9560, 0x956e, 0x9574, 0x9575, 0x9577, 0x9578, 0x9579, 0x957a,
+    0x957b, 0x957c, 0x957d, 0x957e, 0x9580, 0x9581, 0x9582, 0x9583, 0x9584, 0x9585, 0x9586, 0x9587, 0x9588, 0x9589, 0x958a, 0x958b,
+    0x958c, 0x958d, 0x958e, 0x958f, 0x9590, 0x9591, 0x9592, 0x9593, 0x9594, 0x9595, 0x9596, 0x9597, 0x9598, 0x9599, 0x959a, 0x959b,
+    0x959c, 0x959d, 0x959e, 0x959f, 0x95a0, 0x95a1, 0x95a2, 0x95a3, 0x95a4, 0x95a5, 0x95a6, 0x95a7, 0x95a8, 0x95a9, 0x95aa, 0x95ab,
+    0x95ac, 0x95ad, 0x95ae, 0x95af, 0x95b0, 0x95b1, 0x95b2, 0x95b3, 0x95b4, 0x95b5, 0x95b6, 0x95b7, 0x95b8, 0x95b9, 0x95ba, 0x95bb,
+    0x95bc, 0x95bd, 0x95be, 0x95bf, 0x95c0, 0x95c1, 0x95c2, 0x95c3, 0x95c4, 0x95c5, 0x95c6, 0x95c7, 0x95c8, 0x95c9, 0x95ca, 0x95cb,
+    0x6924, 0x68f0, 0x690b, 0x6901, 0x6957, 0x68e3, 0x6910, 0x6971, 0x6939, 0x6960, 0x6942, 0x695d, 0x6984, 0x696b, 0x6980, 0x6998,
+    0x6978, 0x6934, 0x69cc, 0x6987, 0x6988, 0x69ce, 0x6989, 0x6966, 0x6963, 0x6979, 0x699b, 0x69a7, 0x69bb, 0x69ab, 0x69ad, 0x69d4,
+    0x69b1, 0x69c1, 0x69ca, 0x69df, 0x6995, 0x69e0, 0x698d, 0x69ff, 0x6a2f, 0x69ed, 0x6a17, 0x6a18, 0x6a65, 0x69f2, 0x6a44, 0x6a3e,
+    0x6aa0, 0x6a50, 0x6a5b, 0x6a35, 0x6a8e, 0x6a79, 0x6a3d, 0x6a28, 0x6a58, 0x6a7c, 0x6a91, 0x6a90, 0x6aa9, 0x6a97, 0x6aab, 0x7337,
+    0x7352, 0x6b81, 0x6b82, 0x6b87, 0x6b84, 0x6b92, 0x6b93, 0x6b8d, 0x6b9a, 0x6b9b, 0x6ba1, 0x6baa, 0x8f6b, 0x8f6d, 0x8f71, 0x8f72,
+    0x8f73, 0x8f75, 0x8f76, 0x8f78, 0x8f77, 0x8f79, 0x8f7a, 0x8f7c, 0x8f7e, 0x8f81, 0x8f82, 0x8f84, 0x8f87, 0x8f8b, 0x95cc, 0x95cd,
+    0x95ce, 0x95cf, 0x95d0, 0x95d1, 0x95d2, 0x95d3, 0x95d4, 0x95d5, 0x95d6, 0x95d7, 0x95d8, 0x95d9, 0x95da, 0x95db, 0x95dc, 0x95dd,
+    0x95de, 0x95df, 0x95e0, 0x95e1, 0x95e2, 0x95e3, 0x95e4, 0x95e5, 0x95e6, 0x95e7, 0x95ec, 0x95ff, 0x9607, 0x9613, 0x9618, 0x961b,
+    0x961e, 0x9620, 0x9623, 0x9624, 0x9625, 0x9626, 0x9627, 0x9628, 0x9629, 0x962b, 0x962c, 0x962d, 0x962f, 0x9630, 0x9637, 0x9638,
+    0x9639, 0x963a, 0x963e, 0x9641, 0x9643, 0x964a, 0x964e, 0x964f, 0x9651, 0x9652, 0x9653, 0x9656, 0x9657, 0x9658, 0x9659, 0x965a,
+    0x965c, 0x965d, 0x965e, 0x9660, 0x9663, 0x9665, 0x9666, 0x966b, 0x966d, 0x966e, 0x966f, 0x9670, 0x9671, 0x9673, 0x9678, 0x9679,
+    0x967a, 0x967b, 0x967c, 0x967d, 0x967e, 0x967f, 0x9680, 0x9681, 0x9682, 0x9683, 0x9684, 0x9687, 0x9689, 0x968a, 0x8f8d, 0x8f8e,
+    0x8f8f, 0x8f98, 0x8f9a, 0x8ece, 0x620b, 0x6217, 0x621b, 0x621f, 0x6222, 0x6221, 0x6225, 0x6224, 0x622c, 0x81e7, 0x74ef, 0x74f4,
+    0x74ff, 0x750f, 0x7511, 0x7513, 0x6534, 0x65ee, 0x65ef, 0x65f0, 0x660a, 0x6619, 0x6772, 0x6603, 0x6615, 0x6600, 0x7085, 0x66f7,
+    0x661d, 0x6634, 0x6631, 0x6636, 0x6635, 0x8006, 0x665f, 0x6654, 0x6641, 0x664f, 0x6656, 0x6661, 0x6657, 0x6677, 0x6684, 0x668c,
+    0x66a7, 0x669d, 0x66be, 0x66db, 0x66dc, 0x66e6, 0x66e9, 0x8d32, 0x8d33, 0x8d36, 0x8d3b, 0x8d3d, 0x8d40, 0x8d45, 0x8d46, 0x8d48,
+    0x8d49, 0x8d47, 0x8d4d, 0x8d55, 0x8d59, 0x89c7, 0x89ca, 0x89cb, 0x89cc, 0x89ce, 0x89cf, 0x89d0, 0x89d1, 0x726e, 0x729f, 0x725d,
+    0x7266, 0x726f, 0x727e, 0x727f, 0x7284, 0x728b, 0x728d, 0x728f, 0x7292, 0x6308, 0x6332, 0x63b0, 0x968c, 0x968e, 0x9691, 0x9692,
+    0x9693, 0x9695, 0x9696, 0x969a, 0x969b, 0x969d, 0x969e, 0x969f, 0x96a0, 0x96a1, 0x96a2, 0x96a3, 0x96a4, 0x96a5, 0x96a6, 0x96a8,
+    0x96a9, 0x96aa, 0x96ab, 0x96ac, 0x96ad, 0x96ae, 0x96af, 0x96b1, 0x96b2, 0x96b4, 0x96b5, 0x96b7, 0x96b8, 0x96ba, 0x96bb, 0x96bf,
+    0x96c2, 0x96c3, 0x96c8, 0x96ca, 0x96cb, 0x96d0, 0x96d1, 0x96d3, 0x96d4, 0x96d6, 0x96d7, 0x96d8, 0x96d9, 0x96da, 0x96db, 0x96dc,
+    0x96dd, 0x96de, 0x96df, 0x96e1, 0x96e2, 0x96e3, 0x96e4, 0x96e5, 0x96e6, 0x96e7, 0x96eb, 0x96ec, 0x96ed, 0x96ee, 0x96f0, 0x96f1,
+    0x96f2, 0x96f4, 0x96f5, 0x96f8, 0x96fa, 0x96fb, 0x96fc, 0x96fd, 0x96ff, 0x9702, 0x9703, 0x9705, 0x970a, 0x970b, 0x970c, 0x9710,
+    0x9711, 0x9712, 0x9714, 0x9715, 0x9717, 0x9718, 0x9719, 0x971a, 0x971b, 0x971d, 0x971f, 0x9720, 0x643f, 0x64d8, 0x8004, 0x6bea,
+    0x6bf3, 0x6bfd, 0x6bf5, 0x6bf9, 0x6c05, 0x6c07, 0x6c06, 0x6c0d, 0x6c15, 0x6c18, 0x6c19, 0x6c1a, 0x6c21, 0x6c29, 0x6c24, 0x6c2a,
+    0x6c32, 0x6535, 0x6555, 0x656b, 0x724d, 0x7252, 0x7256, 0x7230, 0x8662, 0x5216, 0x809f, 0x809c, 0x8093, 0x80bc, 0x670a, 0x80bd,
+    0x80b1, 0x80ab, 0x80ad, 0x80b4, 0x80b7, 0x80e7, 0x80e8, 0x80e9, 0x80ea, 0x80db, 0x80c2, 0x80c4, 0x80d9, 0x80cd, 0x80d7, 0x6710,
+    0x80dd, 0x80eb, 0x80f1, 0x80f4, 0x80ed, 0x810d, 0x810e, 0x80f2, 0x80fc, 0x6715, 0x8112, 0x8c5a, 0x8136, 0x811e, 0x812c, 0x8118,
+    0x8132, 0x8148, 0x814c, 0x8153, 0x8174, 0x8159, 0x815a, 0x8171, 0x8160, 0x8169, 0x817c, 0x817d, 0x816d, 0x8167, 0x584d, 0x5ab5,
+    0x8188, 0x8182, 0x8191, 0x6ed5, 0x81a3, 0x81aa, 0x81cc, 0x6726, 0x81ca, 0x81bb, 0x9721, 0x9722, 0x9723, 0x9724, 0x9725, 0x9726,
+    0x9727, 0x9728, 0x9729, 0x972b, 0x972c, 0x972e, 0x972f, 0x9731, 0x9733, 0x9734, 0x9735, 0x9736, 0x9737, 0x973a, 0x973b, 0x973c,
+    0x973d, 0x973f, 0x9740, 0x9741, 0x9742, 0x9743, 0x9744, 0x9745, 0x9746, 0x9747, 0x9748, 0x9749, 0x974a, 0x974b, 0x974c, 0x974d,
+    0x974e, 0x974f, 0x9750, 0x9751, 0x9754, 0x9755, 0x9757, 0x9758, 0x975a, 0x975c, 0x975d, 0x975f, 0x9763, 0x9764, 0x9766, 0x9767,
+    0x9768, 0x976a, 0x976b, 0x976c, 0x976d, 0x976e, 0x976f, 0x9770, 0x9771, 0x9772, 0x9775, 0x9777, 0x9778, 0x9779, 0x977a, 0x977b,
+    0x977d, 0x977e, 0x977f, 0x9780, 0x9781, 0x9782, 0x9783, 0x9784, 0x9786, 0x9787, 0x9788, 0x9789, 0x978a, 0x978c, 0x978e, 0x978f,
+    0x9790, 0x9793, 0x9795, 0x9796, 0x9797, 0x9799, 0x979a, 0x979b, 0x979c, 0x979d, 0x81c1, 0x81a6, 0x6b24, 0x6b37, 0x6b39, 0x6b43,
+    0x6b46, 0x6b59, 0x98d1, 0x98d2, 0x98d3, 0x98d5, 0x98d9, 0x98da, 0x6bb3, 0x5f40, 0x6bc2, 0x89f3, 0x6590, 0x9f51, 0x6593, 0x65bc,
+    0x65c6, 0x65c4, 0x65c3, 0x65cc, 0x65ce, 0x65d2, 0x65d6, 0x7080, 0x709c, 0x7096, 0x709d, 0x70bb, 0x70c0, 0x70b7, 0x70ab, 0x70b1,
+    0x70e8, 0x70ca, 0x7110, 0x7113, 0x7116, 0x712f, 0x7131, 0x7173, 0x715c, 0x7168, 0x7145, 0x7172, 0x714a, 0x7178, 0x717a, 0x7198,
+    0x71b3, 0x71b5, 0x71a8, 0x71a0, 0x71e0, 0x71d4, 0x71e7, 0x71f9, 0x721d, 0x7228, 0x706c, 0x7118, 0x7166, 0x71b9, 0x623e, 0x623d,
+    0x6243, 0x6248, 0x6249, 0x793b, 0x7940, 0x7946, 0x7949, 0x795b, 0x795c, 0x7953, 0x795a, 0x7962, 0x7957, 0x7960, 0x796f, 0x7967,
+    0x797a, 0x7985, 0x798a, 0x799a, 0x79a7, 0x79b3, 0x5fd1, 0x5fd0, 0x979e, 0x979f, 0x97a1, 0x97a2, 0x97a4, 0x97a5, 0x97a6, 0x97a7,
+    0x97a8, 0x97a9, 0x97aa, 0x97ac, 0x97ae, 0x97b0, 0x97b1, 0x97b3, 0x97b5, 0x97b6, 0x97b7, 0x97b8, 0x97b9, 0x97ba, 0x97bb, 0x97bc,
+    0x97bd, 0x97be, 0x97bf, 0x97c0, 0x97c1, 0x97c2, 0x97c3, 0x97c4, 0x97c5, 0x97c6, 0x97c7, 0x97c8, 0x97c9, 0x97ca, 0x97cb, 0x97cc,
+    0x97cd, 0x97ce, 0x97cf, 0x97d0, 0x97d1, 0x97d2, 0x97d3, 0x97d4, 0x97d5, 0x97d6, 0x97d7, 0x97d8, 0x97d9, 0x97da, 0x97db, 0x97dc,
+    0x97dd, 0x97de, 0x97df, 0x97e0, 0x97e1, 0x97e2, 0x97e3, 0x97e4, 0x97e5, 0x97e8, 0x97ee, 0x97ef, 0x97f0, 0x97f1, 0x97f2, 0x97f4,
+    0x97f7, 0x97f8, 0x97f9, 0x97fa, 0x97fb, 0x97fc, 0x97fd, 0x97fe, 0x97ff, 0x9800, 0x9801, 0x9802, 0x9803, 0x9804, 0x9805, 0x9806,
+    0x9807, 0x9808, 0x9809, 0x980a, 0x980b, 0x980c, 0x980d, 0x980e, 0x603c, 0x605d, 0x605a, 0x6067, 0x6041, 0x6059, 0x6063, 0x60ab,
+    0x6106, 0x610d, 0x615d, 0x61a9, 0x619d, 0x61cb, 0x61d1, 0x6206, 0x8080, 0x807f, 0x6c93, 0x6cf6, 0x6dfc, 0x77f6, 0x77f8, 0x7800,
+    0x7809, 0x7817, 0x7818, 0x7811, 0x65ab, 0x782d, 0x781c, 0x781d, 0x7839, 0x783a, 0x783b, 0x781f, 0x783c, 0x7825, 0x782c, 0x7823,
+    0x7829, 0x784e, 0x786d, 0x7856, 0x7857, 0x7826, 0x7850, 0x7847, 0x784c, 0x786a, 0x789b, 0x7893, 0x789a, 0x7887, 0x789c, 0x78a1,
+    0x78a3, 0x78b2, 0x78b9, 0x78a5, 0x78d4, 0x78d9, 0x78c9, 0x78ec, 0x78f2, 0x7905, 0x78f4, 0x7913, 0x7924, 0x791e, 0x7934, 0x9f9b,
+    0x9ef9, 0x9efb, 0x9efc, 0x76f1, 0x7704, 0x770d, 0x76f9, 0x7707, 0x7708, 0x771a, 0x7722, 0x7719, 0x772d, 0x7726, 0x7735, 0x7738,
+    0x7750, 0x7751, 0x7747, 0x7743, 0x775a, 0x7768, 0x980f, 0x9810, 0x9811, 0x9812, 0x9813, 0x9814, 0x9815, 0x9816, 0x9817, 0x9818,
+    0x9819, 0x981a, 0x981b, 0x981c, 0x981d, 0x981e, 0x981f, 0x9820, 0x9821, 0x9822, 0x9823, 0x9824, 0x9825, 0x9826, 0x9827, 0x9828,
+    0x9829, 0x982a, 0x982b, 0x982c, 0x982d, 0x982e, 0x982f, 0x9830, 0x9831, 0x9832, 0x9833, 0x9834, 0x9835, 0x9836, 0x9837, 0x9838,
+    0x9839, 0x983a, 0x983b, 0x983c, 0x983d, 0x983e, 0x983f, 0x9840, 0x9841, 0x9842, 0x9843, 0x9844, 0x9845, 0x9846, 0x9847, 0x9848,
+    0x9849, 0x984a, 0x984b, 0x984c, 0x984d, 0x984e, 0x984f, 0x9850, 0x9851, 0x9852, 0x9853, 0x9854, 0x9855, 0x9856, 0x9857, 0x9858,
+    0x9859, 0x985a, 0x985b, 0x985c, 0x985d, 0x985e, 0x985f, 0x9860, 0x9861, 0x9862, 0x9863, 0x9864, 0x9865, 0x9866, 0x9867, 0x9868,
+    0x9869, 0x986a, 0x986b, 0x986c, 0x986d, 0x986e, 0x7762, 0x7765, 0x777f, 0x778d, 0x777d, 0x7780, 0x778c, 0x7791, 0x779f, 0x77a0,
+    0x77b0, 0x77b5, 0x77bd, 0x753a, 0x7540, 0x754e, 0x754b, 0x7548, 0x755b, 0x7572, 0x7579, 0x7583, 0x7f58, 0x7f61, 0x7f5f, 0x8a48,
+    0x7f68, 0x7f74, 0x7f71, 0x7f79, 0x7f81, 0x7f7e, 0x76cd, 0x76e5, 0x8832, 0x9485, 0x9486, 0x9487, 0x948b, 0x948a, 0x948c, 0x948d,
+    0x948f, 0x9490, 0x9494, 0x9497, 0x9495, 0x949a, 0x949b, 0x949c, 0x94a3, 0x94a4, 0x94ab, 0x94aa, 0x94ad, 0x94ac, 0x94af, 0x94b0,
+    0x94b2, 0x94b4, 0x94b6, 0x94b7, 0x94b8, 0x94b9, 0x94ba, 0x94bc, 0x94bd, 0x94bf, 0x94c4, 0x94c8, 0x94c9, 0x94ca, 0x94cb, 0x94cc,
+    0x94cd, 0x94ce, 0x94d0, 0x94d1, 0x94d2, 0x94d5, 0x94d6, 0x94d7, 0x94d9, 0x94d8, 0x94db, 0x94de, 0x94df, 0x94e0, 0x94e2, 0x94e4,
+    0x94e5, 0x94e7, 0x94e8, 0x94ea, 0x986f, 0x9870, 0x9871, 0x9872, 0x9873, 0x9874, 0x988b, 0x988e, 0x9892, 0x9895, 0x9899, 0x98a3,
+    0x98a8, 0x98a9, 0x98aa, 0x98ab, 0x98ac, 0x98ad, 0x98ae, 0x98af, 0x98b0, 0x98b1, 0x98b2, 0x98b3, 0x98b4, 0x98b5, 0x98b6, 0x98b7,
+    0x98b8, 0x98b9, 0x98ba, 0x98bb, 0x98bc, 0x98bd, 0x98be, 0x98bf, 0x98c0, 0x98c1, 0x98c2, 0x98c3, 0x98c4, 0x98c5, 0x98c6, 0x98c7,
+    0x98c8, 0x98c9, 0x98ca, 0x98cb, 0x98cc, 0x98cd, 0x98cf, 0x98d0, 0x98d4, 0x98d6, 0x98d7, 0x98db, 0x98dc, 0x98dd, 0x98e0, 0x98e1,
+    0x98e2, 0x98e3, 0x98e4, 0x98e5, 0x98e6, 0x98e9, 0x98ea, 0x98eb, 0x98ec, 0x98ed, 0x98ee, 0x98ef, 0x98f0, 0x98f1, 0x98f2, 0x98f3,
+    0x98f4, 0x98f5, 0x98f6, 0x98f7, 0x98f8, 0x98f9, 0x98fa, 0x98fb, 0x98fc, 0x98fd, 0x98fe, 0x98ff, 0x9900, 0x9901, 0x9902, 0x9903,
+    0x9904, 0x9905, 0x9906, 0x9907, 0x94e9, 0x94eb, 0x94ee, 0x94ef, 0x94f3, 0x94f4, 0x94f5, 0x94f7, 0x94f9, 0x94fc, 0x94fd, 0x94ff,
+    0x9503, 0x9502, 0x9506, 0x9507, 0x9509, 0x950a, 0x950d, 0x950e, 0x950f, 0x9512, 0x9513, 0x9514, 0x9515, 0x9516, 0x9518, 0x951b,
+    0x951d, 0x951e, 0x951f, 0x9522, 0x952a, 0x952b, 0x9529, 0x952c, 0x9531, 0x9532, 0x9534, 0x9536, 0x9537, 0x9538, 0x953c, 0x953e,
+    0x953f, 0x9542, 0x9535, 0x9544, 0x9545, 0x9546, 0x9549, 0x954c, 0x954e, 0x954f, 0x9552, 0x9553, 0x9554, 0x9556, 0x9557, 0x9558,
+    0x9559, 0x955b, 0x955e, 0x955f, 0x955d, 0x9561, 0x9562, 0x9564, 0x9565, 0x9566, 0x9567, 0x9568, 0x9569, 0x956a, 0x956b, 0x956c,
+    0x956f, 0x9571, 0x9572, 0x9573, 0x953a, 0x77e7, 0x77ec, 0x96c9, 0x79d5, 0x79ed, 0x79e3, 0x79eb, 0x7a06, 0x5d47, 0x7a03, 0x7a02,
+    0x7a1e, 0x7a14, 0x9908, 0x9909, 0x990a, 0x990b, 0x990c, 0x990e, 0x990f, 0x9911, 0x9912, 0x9913, 0x9914, 0x9915, 0x9916, 0x9917,
+    0x9918, 0x9919, 0x991a, 0x991b, 0x991c, 0x991d, 0x991e, 0x991f, 0x9920, 0x9921, 0x9922, 0x9923, 0x9924, 0x9925, 0x9926, 0x9927,
+    0x9928, 0x9929, 0x992a, 0x992b, 0x992c, 0x992d, 0x992f, 0x9930, 0x9931, 0x9932, 0x9933, 0x9934, 0x9935, 0x9936, 0x9937, 0x9938,
+    0x9939, 0x993a, 0x993b, 0x993c, 0x993d, 0x993e, 0x993f, 0x9940, 0x9941, 0x9942, 0x9943, 0x9944, 0x9945, 0x9946, 0x9947, 0x9948,
+    0x9949, 0x994a, 0x994b, 0x994c, 0x994d, 0x994e, 0x994f, 0x9950, 0x9951, 0x9952, 0x9953, 0x9956, 0x9957, 0x9958, 0x9959, 0x995a,
+    0x995b, 0x995c, 0x995d, 0x995e, 0x995f, 0x9960, 0x9961, 0x9962, 0x9964, 0x9966, 0x9973, 0x9978, 0x9979, 0x997b, 0x997e, 0x9982,
+    0x9983, 0x9989, 0x7a39, 0x7a37, 0x7a51, 0x9ecf, 0x99a5, 0x7a70, 0x7688, 0x768e, 0x7693, 0x7699, 0x76a4, 0x74de, 0x74e0, 0x752c,
+    0x9e20, 0x9e22, 0x9e28, 0x9e29, 0x9e2a, 0x9e2b, 0x9e2c, 0x9e32, 0x9e31, 0x9e36, 0x9e38, 0x9e37, 0x9e39, 0x9e3a, 0x9e3e, 0x9e41,
+    0x9e42, 0x9e44, 0x9e46, 0x9e47, 0x9e48, 0x9e49, 0x9e4b, 0x9e4c, 0x9e4e, 0x9e51, 0x9e55, 0x9e57, 0x9e5a, 0x9e5b, 0x9e5c, 0x9e5e,
+    0x9e63, 0x9e66, 0x9e67, 0x9e68, 0x9e69, 0x9e6a, 0x9e6b, 0x9e6c, 0x9e71, 0x9e6d, 0x9e73, 0x7592, 0x7594, 0x7596, 0x75a0, 0x759d,
+    0x75ac, 0x75a3, 0x75b3, 0x75b4, 0x75b8, 0x75c4, 0x75b1, 0x75b0, 0x75c3, 0x75c2, 0x75d6, 0x75cd, 0x75e3, 0x75e8, 0x75e6, 0x75e4,
+    0x75eb, 0x75e7, 0x7603, 0x75f1, 0x75fc, 0x75ff, 0x7610, 0x7600, 0x7605, 0x760c, 0x7617, 0x760a, 0x7625, 0x7618, 0x7615, 0x7619,
+    0x998c, 0x998e, 0x999a, 0x999b, 0x999c, 0x999d, 0x999e, 0x999f, 0x99a0, 0x99a1, 0x99a2, 0x99a3, 0x99a4, 0x99a6, 0x99a7, 0x99a9,
+    0x99aa, 0x99ab, 0x99ac, 0x99ad, 0x99ae, 0x99af, 0x99b0, 0x99b1, 0x99b2, 0x99b3, 0x99b4, 0x99b5, 0x99b6, 0x99b7, 0x99b8, 0x99b9,
+    0x99ba, 0x99bb, 0x99bc, 0x99bd, 0x99be, 0x99bf, 0x99c0, 0x99c1, 0x99c2, 0x99c3, 0x99c4, 0x99c5, 0x99c6, 0x99c7, 0x99c8, 0x99c9,
+    0x99ca, 0x99cb, 0x99cc, 0x99cd, 0x99ce, 0x99cf, 0x99d0, 0x99d1, 0x99d2, 0x99d3, 0x99d4, 0x99d5, 0x99d6, 0x99d7, 0x99d8, 0x99d9,
+    0x99da, 0x99db, 0x99dc, 0x99dd, 0x99de, 0x99df, 0x99e0, 0x99e1, 0x99e2, 0x99e3, 0x99e4, 0x99e5, 0x99e6, 0x99e7, 0x99e8, 0x99e9,
+    0x99ea, 0x99eb, 0x99ec, 0x99ed, 0x99ee, 0x99ef, 0x99f0, 0x99f1, 0x99f2, 0x99f3, 0x99f4, 0x99f5, 0x99f6, 0x99f7, 0x99f8, 0x99f9,
+    0x761b, 0x763c, 0x7622, 0x7620, 0x7640, 0x762d, 0x7630, 0x763f, 0x7635, 0x7643, 0x763e, 0x7633, 0x764d, 0x765e, 0x7654, 0x765c,
+    0x7656, 0x766b, 0x766f, 0x7fca, 0x7ae6, 0x7a78, 0x7a79, 0x7a80, 0x7a86, 0x7a88, 0x7a95, 0x7aa6, 0x7aa0, 0x7aac, 0x7aa8, 0x7aad,
+    0x7ab3, 0x8864, 0x8869, 0x8872, 0x887d, 0x887f, 0x8882, 0x88a2, 0x88c6, 0x88b7, 0x88bc, 0x88c9, 0x88e2, 0x88ce, 0x88e3, 0x88e5,
+    0x88f1, 0x891a, 0x88fc, 0x88e8, 0x88fe, 0x88f0, 0x8921, 0x8919, 0x8913, 0x891b, 0x890a, 0x8934, 0x892b, 0x8936, 0x8941, 0x8966,
+    0x897b, 0x758b, 0x80e5, 0x76b2, 0x76b4, 0x77dc, 0x8012, 0x8014, 0x8016, 0x801c, 0x8020, 0x8022, 0x8025, 0x8026, 0x8027, 0x8029,
+    0x8028, 0x8031, 0x800b, 0x8035, 0x8043, 0x8046, 0x804d, 0x8052, 0x8069, 0x8071, 0x8983, 0x9878, 0x9880, 0x9883, 0x99fa, 0x99fb,
+    0x99fc, 0x99fd, 0x99fe, 0x99ff, 0x9a00, 0x9a01, 0x9a02, 0x9a03, 0x9a04, 0x9a05, 0x9a06, 0x9a07, 0x9a08, 0x9a09, 0x9a0a, 0x9a0b,
+    0x9a0c, 0x9a0d, 0x9a0e, 0x9a0f, 0x9a10, 0x9a11, 0x9a12, 0x9a13, 0x9a14, 0x9a15, 0x9a16, 0x9a17, 0x9a18, 0x9a19, 0x9a1a, 0x9a1b,
+    0x9a1c, 0x9a1d, 0x9a1e, 0x9a1f, 0x9a20, 0x9a21, 0x9a22, 0x9a23, 0x9a24, 0x9a25, 0x9a26, 0x9a27, 0x9a28, 0x9a29, 0x9a2a, 0x9a2b,
+    0x9a2c, 0x9a2d, 0x9a2e, 0x9a2f, 0x9a30, 0x9a31, 0x9a32, 0x9a33, 0x9a34, 0x9a35, 0x9a36, 0x9a37, 0x9a38, 0x9a39, 0x9a3a, 0x9a3b,
+    0x9a3c, 0x9a3d, 0x9a3e, 0x9a3f, 0x9a40, 0x9a41, 0x9a42, 0x9a43, 0x9a44, 0x9a45, 0x9a46, 0x9a47, 0x9a48, 0x9a49, 0x9a4a, 0x9a4b,
+    0x9a4c, 0x9a4d, 0x9a4e, 0x9a4f, 0x9a50, 0x9a51, 0x9a52, 0x9a53, 0x9a54, 0x9a55, 0x9a56, 0x9a57, 0x9a58, 0x9a59, 0x9889, 0x988c,
+    0x988d, 0x988f, 0x9894, 0x989a, 0x989b, 0x989e, 0x989f, 0x98a1, 0x98a2, 0x98a5, 0x98a6, 0x864d, 0x8654, 0x866c, 0x866e, 0x867f,
+    0x867a, 0x867c, 0x867b, 0x86a8, 0x868d, 0x868b, 0x86ac, 0x869d, 0x86a7, 0x86a3, 0x86aa, 0x8693, 0x86a9, 0x86b6, 0x86c4, 0x86b5,
+    0x86ce, 0x86b0, 0x86ba, 0x86b1, 0x86af, 0x86c9, 0x86cf, 0x86b4, 0x86e9, 0x86f1, 0x86f2, 0x86ed, 0x86f3, 0x86d0, 0x8713, 0x86de,
+    0x86f4, 0x86df, 0x86d8, 0x86d1, 0x8703, 0x8707, 0x86f8, 0x8708, 0x870a, 0x870d, 0x8709, 0x8723, 0x873b, 0x871e, 0x8725, 0x872e,
+    0x871a, 0x873e, 0x8748, 0x8734, 0x8731, 0x8729, 0x8737, 0x873f, 0x8782, 0x8722, 0x877d, 0x877e, 0x877b, 0x8760, 0x8770, 0x874c,
+    0x876e, 0x878b, 0x8753, 0x8763, 0x877c, 0x8764, 0x8759, 0x8765, 0x8793, 0x87af, 0x87a8, 0x87d2, 0x9a5a, 0x9a5b, 0x9a5c, 0x9a5d,
+    0x9a5e, 0x9a5f, 0x9a60, 0x9a61, 0x9a62, 0x9a63, 0x9a64, 0x9a65, 0x9a66, 0x9a67, 0x9a68, 0x9a69, 0x9a6a, 0x9a6b, 0x9a72, 0x9a83,
+    0x9a89, 0x9a8d, 0x9a8e, 0x9a94, 0x9a95, 0x9a99, 0x9aa6, 0x9aa9, 0x9aaa, 0x9aab, 0x9aac, 0x9aad, 0x9aae, 0x9aaf, 0x9ab2, 0x9ab3,
+    0x9ab4, 0x9ab5, 0x9ab9, 0x9abb, 0x9abd, 0x9abe, 0x9abf, 0x9ac3, 0x9ac4, 0x9ac6, 0x9ac7, 0x9ac8, 0x9ac9, 0x9aca, 0x9acd, 0x9ace,
+    0x9acf, 0x9ad0, 0x9ad2, 0x9ad4, 0x9ad5, 0x9ad6, 0x9ad7, 0x9ad9, 0x9ada, 0x9adb, 0x9adc, 0x9add, 0x9ade, 0x9ae0, 0x9ae2, 0x9ae3,
+    0x9ae4, 0x9ae5, 0x9ae7, 0x9ae8, 0x9ae9, 0x9aea, 0x9aec, 0x9aee, 0x9af0, 0x9af1, 0x9af2, 0x9af3, 0x9af4, 0x9af5, 0x9af6, 0x9af7,
+    0x9af8, 0x9afa, 0x9afc, 0x9afd, 0x9afe, 0x9aff, 0x9b00, 0x9b01, 0x9b02, 0x9b04, 0x9b05, 0x9b06, 0x87c6, 0x8788, 0x8785, 0x87ad,
+    0x8797, 0x8783, 0x87ab, 0x87e5, 0x87ac, 0x87b5, 0x87b3, 0x87cb, 0x87d3, 0x87bd, 0x87d1, 0x87c0, 0x87ca, 0x87db, 0x87ea, 0x87e0,
+    0x87ee, 0x8816, 0x8813, 0x87fe, 0x880a, 0x881b, 0x8821, 0x8839, 0x883c, 0x7f36, 0x7f42, 0x7f44, 0x7f45, 0x8210, 0x7afa, 0x7afd,
+    0x7b08, 0x7b03, 0x7b04, 0x7b15, 0x7b0a, 0x7b2b, 0x7b0f, 0x7b47, 0x7b38, 0x7b2a, 0x7b19, 0x7b2e, 0x7b31, 0x7b20, 0x7b25, 0x7b24,
+    0x7b33, 0x7b3e, 0x7b1e, 0x7b58, 0x7b5a, 0x7b45, 0x7b75, 0x7b4c, 0x7b5d, 0x7b60, 0x7b6e, 0x7b7b, 0x7b62, 0x7b72, 0x7b71, 0x7b90,
+    0x7ba6, 0x7ba7, 0x7bb8, 0x7bac, 0x7b9d, 0x7ba8, 0x7b85, 0x7baa, 0x7b9c, 0x7ba2, 0x7bab, 0x7bb4, 0x7bd1, 0x7bc1, 0x7bcc, 0x7bdd,
+    0x7bda, 0x7be5, 0x7be6, 0x7bea, 0x7c0c, 0x7bfe, 0x7bfc, 0x7c0f, 0x7c16, 0x7c0b, 0x9b07, 0x9b09, 0x9b0a, 0x9b0b, 0x9b0c, 0x9b0d,
+    0x9b0e, 0x9b10, 0x9b11, 0x9b12, 0x9b14, 0x9b15, 0x9b16, 0x9b17, 0x9b18, 0x9b19, 0x9b1a, 0x9b1b, 0x9b1c, 0x9b1d, 0x9b1e, 0x9b20,
+    0x9b21, 0x9b22, 0x9b24, 0x9b25, 0x9b26, 0x9b27, 0x9b28, 0x9b29, 0x9b2a, 0x9b2b, 0x9b2c, 0x9b2d, 0x9b2e, 0x9b30, 0x9b31, 0x9b33,
+    0x9b34, 0x9b35, 0x9b36, 0x9b37, 0x9b38, 0x9b39, 0x9b3a, 0x9b3d, 0x9b3e, 0x9b3f, 0x9b40, 0x9b46, 0x9b4a, 0x9b4b, 0x9b4c, 0x9b4e,
+    0x9b50, 0x9b52, 0x9b53, 0x9b55, 0x9b56, 0x9b57, 0x9b58, 0x9b59, 0x9b5a, 0x9b5b, 0x9b5c, 0x9b5d, 0x9b5e, 0x9b5f, 0x9b60, 0x9b61,
+    0x9b62, 0x9b63, 0x9b64, 0x9b65, 0x9b66, 0x9b67, 0x9b68, 0x9b69, 0x9b6a, 0x9b6b, 0x9b6c, 0x9b6d, 0x9b6e, 0x9b6f, 0x9b70, 0x9b71,
+    0x9b72, 0x9b73, 0x9b74, 0x9b75, 0x9b76, 0x9b77, 0x9b78, 0x9b79, 0x9b7a, 0x9b7b, 0x7c1f, 0x7c2a, 0x7c26, 0x7c38, 0x7c41, 0x7c40,
+    0x81fe, 0x8201, 0x8202, 0x8204, 0x81ec, 0x8844, 0x8221, 0x8222, 0x8223, 0x822d, 0x822f, 0x8228, 0x822b, 0x8238, 0x823b, 0x8233,
+    0x8234, 0x823e, 0x8244, 0x8249, 0x824b, 0x824f, 0x825a, 0x825f, 0x8268, 0x887e, 0x8885, 0x8888, 0x88d8, 0x88df, 0x895e, 0x7f9d,
+    0x7f9f, 0x7fa7, 0x7faf, 0x7fb0, 0x7fb2, 0x7c7c, 0x6549, 0x7c91, 0x7c9d, 0x7c9c, 0x7c9e, 0x7ca2, 0x7cb2, 0x7cbc, 0x7cbd, 0x7cc1,
+    0x7cc7, 0x7ccc, 0x7ccd, 0x7cc8, 0x7cc5, 0x7cd7, 0x7ce8, 0x826e, 0x66a8, 0x7fbf, 0x7fce, 0x7fd5, 0x7fe5, 0x7fe1, 0x7fe6, 0x7fe9,
+    0x7fee, 0x7ff3, 0x7cf8, 0x7d77, 0x7da6, 0x7dae, 0x7e47, 0x7e9b, 0x9eb8, 0x9eb4, 0x8d73, 0x8d84, 0x8d94, 0x8d91, 0x8db1, 0x8d67,
+    0x8d6d, 0x8c47, 0x8c49, 0x914a, 0x9150, 0x914e, 0x914f, 0x9164, 0x9b7c, 0x9b7d, 0x9b7e, 0x9b7f, 0x9b80, 0x9b81, 0x9b82, 0x9b83,
+    0x9b84, 0x9b85, 0x9b86, 0x9b87, 0x9b88, 0x9b89, 0x9b8a, 0x9b8b, 0x9b8c, 0x9b8d, 0x9b8e, 0x9b8f, 0x9b90, 0x9b91, 0x9b92, 0x9b93,
+    0x9b94, 0x9b95, 0x9b96, 0x9b97, 0x9b98, 0x9b99, 0x9b9a, 0x9b9b, 0x9b9c, 0x9b9d, 0x9b9e, 0x9b9f, 0x9ba0, 0x9ba1, 0x9ba2, 0x9ba3,
+    0x9ba4, 0x9ba5, 0x9ba6, 0x9ba7, 0x9ba8, 0x9ba9, 0x9baa, 0x9bab, 0x9bac, 0x9bad, 0x9bae, 0x9baf, 0x9bb0, 0x9bb1, 0x9bb2, 0x9bb3,
+    0x9bb4, 0x9bb5, 0x9bb6, 0x9bb7, 0x9bb8, 0x9bb9, 0x9bba, 0x9bbb, 0x9bbc, 0x9bbd, 0x9bbe, 0x9bbf, 0x9bc0, 0x9bc1, 0x9bc2, 0x9bc3,
+    0x9bc4, 0x9bc5, 0x9bc6, 0x9bc7, 0x9bc8, 0x9bc9, 0x9bca, 0x9bcb, 0x9bcc, 0x9bcd, 0x9bce, 0x9bcf, 0x9bd0, 0x9bd1, 0x9bd2, 0x9bd3,
+    0x9bd4, 0x9bd5, 0x9bd6, 0x9bd7, 0x9bd8, 0x9bd9, 0x9bda, 0x9bdb, 0x9162, 0x9161, 0x9170, 0x9169, 0x916f, 0x917d, 0x917e, 0x9172,
+    0x9174, 0x9179, 0x918c, 0x9185, 0x9190, 0x918d, 0x9191, 0x91a2, 0x91a3, 0x91aa, 0x91ad, 0x91ae, 0x91af, 0x91b5, 0x91b4, 0x91ba,
+    0x8c55, 0x9e7e, 0x8db8, 0x8deb, 0x8e05, 0x8e59, 0x8e69, 0x8db5, 0x8dbf, 0x8dbc, 0x8dba, 0x8dc4, 0x8dd6, 0x8dd7, 0x8dda, 0x8dde,
+    0x8dce, 0x8dcf, 0x8ddb, 0x8dc6, 0x8dec, 0x8df7, 0x8df8, 0x8de3, 0x8df9, 0x8dfb, 0x8de4, 0x8e09, 0x8dfd, 0x8e14, 0x8e1d, 0x8e1f,
+    0x8e2c, 0x8e2e, 0x8e23, 0x8e2f, 0x8e3a, 0x8e40, 0x8e39, 0x8e35, 0x8e3d, 0x8e31, 0x8e49, 0x8e41, 0x8e42, 0x8e51, 0x8e52, 0x8e4a,
+    0x8e70, 0x8e76, 0x8e7c, 0x8e6f, 0x8e74, 0x8e85, 0x8e8f, 0x8e94, 0x8e90, 0x8e9c, 0x8e9e, 0x8c78, 0x8c82, 0x8c8a, 0x8c85, 0x8c98,
+    0x8c94, 0x659b, 0x89d6, 0x89de, 0x89da, 0x89dc, 0x9bdc, 0x9bdd, 0x9bde, 0x9bdf, 0x9be0, 0x9be1, 0x9be2, 0x9be3, 0x9be4, 0x9be5,
+    0x9be6, 0x9be7, 0x9be8, 0x9be9, 0x9bea, 0x9beb, 0x9bec, 0x9bed, 0x9bee, 0x9bef, 0x9bf0, 0x9bf1, 0x9bf2, 0x9bf3, 0x9bf4, 0x9bf5,
+    0x9bf6, 0x9bf7, 0x9bf8, 0x9bf9, 0x9bfa, 0x9bfb, 0x9bfc, 0x9bfd, 0x9bfe, 0x9bff, 0x9c00, 0x9c01, 0x9c02, 0x9c03, 0x9c04, 0x9c05,
+    0x9c06, 0x9c07, 0x9c08, 0x9c09, 0x9c0a, 0x9c0b, 0x9c0c, 0x9c0d, 0x9c0e, 0x9c0f, 0x9c10, 0x9c11, 0x9c12, 0x9c13, 0x9c14, 0x9c15,
+    0x9c16, 0x9c17, 0x9c18, 0x9c19, 0x9c1a, 0x9c1b, 0x9c1c, 0x9c1d, 0x9c1e, 0x9c1f, 0x9c20, 0x9c21, 0x9c22, 0x9c23, 0x9c24, 0x9c25,
+    0x9c26, 0x9c27, 0x9c28, 0x9c29, 0x9c2a, 0x9c2b, 0x9c2c, 0x9c2d, 0x9c2e, 0x9c2f, 0x9c30, 0x9c31, 0x9c32, 0x9c33, 0x9c34, 0x9c35,
+    0x9c36, 0x9c37, 0x9c38, 0x9c39, 0x9c3a, 0x9c3b, 0x89e5, 0x89eb, 0x89ef, 0x8a3e, 0x8b26, 0x9753, 0x96e9, 0x96f3, 0x96ef, 0x9706,
+    0x9701, 0x9708, 0x970f, 0x970e, 0x972a, 0x972d, 0x9730, 0x973e, 0x9f80, 0x9f83, 0x9f85, 0x9f86, 0x9f87, 0x9f88, 0x9f89, 0x9f8a,
+    0x9f8c, 0x9efe, 0x9f0b, 0x9f0d, 0x96b9, 0x96bc, 0x96bd, 0x96ce, 0x96d2, 0x77bf, 0x96e0, 0x928e, 0x92ae, 0x92c8, 0x933e, 0x936a,
+    0x93ca, 0x938f, 0x943e, 0x946b, 0x9c7f, 0x9c82, 0x9c85, 0x9c86, 0x9c87, 0x9c88, 0x7a23, 0x9c8b, 0x9c8e, 0x9c90, 0x9c91, 0x9c92,
+    0x9c94, 0x9c95, 0x9c9a, 0x9c9b, 0x9c9e, 0x9c9f, 0x9ca0, 0x9ca1, 0x9ca2, 0x9ca3, 0x9ca5, 0x9ca6, 0x9ca7, 0x9ca8, 0x9ca9, 0x9cab,
+    0x9cad, 0x9cae, 0x9cb0, 0x9cb1, 0x9cb2, 0x9cb3, 0x9cb4, 0x9cb5, 0x9cb6, 0x9cb7, 0x9cba, 0x9cbb, 0x9cbc, 0x9cbd, 0x9cc4, 0x9cc5,
+    0x9cc6, 0x9cc7, 0x9cca, 0x9ccb, 0x9c3c, 0x9c3d, 0x9c3e, 0x9c3f, 0x9c40, 0x9c41, 0x9c42, 0x9c43, 0x9c44, 0x9c45, 0x9c46, 0x9c47,
+    0x9c48, 0x9c49, 0x9c4a, 0x9c4b, 0x9c4c, 0x9c4d, 0x9c4e, 0x9c4f, 0x9c50, 0x9c51, 0x9c52, 0x9c53, 0x9c54, 0x9c55, 0x9c56, 0x9c57,
+    0x9c58, 0x9c59, 0x9c5a, 0x9c5b, 0x9c5c, 0x9c5d, 0x9c5e, 0x9c5f, 0x9c60, 0x9c61, 0x9c62, 0x9c63, 0x9c64, 0x9c65, 0x9c66, 0x9c67,
+    0x9c68, 0x9c69, 0x9c6a, 0x9c6b, 0x9c6c, 0x9c6d, 0x9c6e, 0x9c6f, 0x9c70, 0x9c71, 0x9c72, 0x9c73, 0x9c74, 0x9c75, 0x9c76, 0x9c77,
+    0x9c78, 0x9c79, 0x9c7a, 0x9c7b, 0x9c7d, 0x9c7e, 0x9c80, 0x9c83, 0x9c84, 0x9c89, 0x9c8a, 0x9c8c, 0x9c8f, 0x9c93, 0x9c96, 0x9c97,
+    0x9c98, 0x9c99, 0x9c9d, 0x9caa, 0x9cac, 0x9caf, 0x9cb9, 0x9cbe, 0x9cbf, 0x9cc0, 0x9cc1, 0x9cc2, 0x9cc8, 0x9cc9, 0x9cd1, 0x9cd2,
+    0x9cda, 0x9cdb, 0x9ce0, 0x9ce1, 0x9ccc, 0x9ccd, 0x9cce, 0x9ccf, 0x9cd0, 0x9cd3, 0x9cd4, 0x9cd5, 0x9cd7, 0x9cd8, 0x9cd9, 0x9cdc,
+    0x9cdd, 0x9cdf, 0x9ce2, 0x977c, 0x9785, 0x9791, 0x9792, 0x9794, 0x97af, 0x97ab, 0x97a3, 0x97b2, 0x97b4, 0x9ab1, 0x9ab0, 0x9ab7,
+    0x9e58, 0x9ab6, 0x9aba, 0x9abc, 0x9ac1, 0x9ac0, 0x9ac5, 0x9ac2, 0x9acb, 0x9acc, 0x9ad1, 0x9b45, 0x9b43, 0x9b47, 0x9b49, 0x9b48,
+    0x9b4d, 0x9b51, 0x98e8, 0x990d, 0x992e, 0x9955, 0x9954, 0x9adf, 0x9ae1, 0x9ae6, 0x9aef, 0x9aeb, 0x9afb, 0x9aed, 0x9af9, 0x9b08,
+    0x9b0f, 0x9b13, 0x9b1f, 0x9b23, 0x9ebd, 0x9ebe, 0x7e3b, 0x9e82, 0x9e87, 0x9e88, 0x9e8b, 0x9e92, 0x93d6, 0x9e9d, 0x9e9f, 0x9edb,
+    0x9edc, 0x9edd, 0x9ee0, 0x9edf, 0x9ee2, 0x9ee9, 0x9ee7, 0x9ee5, 0x9eea, 0x9eef, 0x9f22, 0x9f2c, 0x9f2f, 0x9f39, 0x9f37, 0x9f3d,
+    0x9f3e, 0x9f44, 0x9ce3, 0x9ce4, 0x9ce5, 0x9ce6, 0x9ce7, 0x9ce8, 0x9ce9, 0x9cea, 0x9ceb, 0x9cec, 0x9ced, 0x9cee, 0x9cef, 0x9cf0,
+    0x9cf1, 0x9cf2, 0x9cf3, 0x9cf4, 0x9cf5, 0x9cf6, 0x9cf7, 0x9cf8, 0x9cf9, 0x9cfa, 0x9cfb, 0x9cfc, 0x9cfd, 0x9cfe, 0x9cff, 0x9d00,
+    0x9d01, 0x9d02, 0x9d03, 0x9d04, 0x9d05, 0x9d06, 0x9d07, 0x9d08, 0x9d09, 0x9d0a, 0x9d0b, 0x9d0c, 0x9d0d, 0x9d0e, 0x9d0f, 0x9d10,
+    0x9d11, 0x9d12, 0x9d13, 0x9d14, 0x9d15, 0x9d16, 0x9d17, 0x9d18, 0x9d19, 0x9d1a, 0x9d1b, 0x9d1c, 0x9d1d, 0x9d1e, 0x9d1f, 0x9d20,
+    0x9d21, 0x9d22, 0x9d23, 0x9d24, 0x9d25, 0x9d26, 0x9d27, 0x9d28, 0x9d29, 0x9d2a, 0x9d2b, 0x9d2c, 0x9d2d, 0x9d2e, 0x9d2f, 0x9d30,
+    0x9d31, 0x9d32, 0x9d33, 0x9d34, 0x9d35, 0x9d36, 0x9d37, 0x9d38, 0x9d39, 0x9d3a, 0x9d3b, 0x9d3c, 0x9d3d, 0x9d3e, 0x9d3f, 0x9d40,
+    0x9d41, 0x9d42, 0xe234, 0xe235, 0xe236, 0xe237, 0xe238, 0xe239, 0xe23a, 0xe23b, 0xe23c, 0xe23d, 0xe23e, 0xe23f, 0xe240, 0xe241,
+    0xe242, 0xe243, 0xe244, 0xe245, 0xe246, 0xe247, 0xe248, 0xe249, 0xe24a, 0xe24b, 0xe24c, 0xe24d, 0xe24e, 0xe24f, 0xe250, 0xe251,
+    0xe252, 0xe253, 0xe254, 0xe255, 0xe256, 0xe257, 0xe258, 0xe259, 0xe25a, 0xe25b, 0xe25c, 0xe25d, 0xe25e, 0xe25f, 0xe260, 0xe261,
+    0xe262, 0xe263, 0xe264, 0xe265, 0xe266, 0xe267, 0xe268, 0xe269, 0xe26a, 0xe26b, 0xe26c, 0xe26d, 0xe26e, 0xe26f, 0xe270, 0xe271,
+    0xe272, 0xe273, 0xe274, 0xe275, 0xe276, 0xe277, 0xe278, 0xe279, 0xe27a, 0xe27b, 0xe27c, 0xe27d, 0xe27e, 0xe27f, 0xe280, 0xe281,
+    0xe282, 0xe283, 0xe284, 0xe285, 0xe286, 0xe287, 0xe288, 0xe289, 0xe28a, 0xe28b, 0xe28c, 0xe28d, 0xe28e, 0xe28f, 0xe290, 0xe291,
+    0x9d43, 0x9d44, 0x9d45, 0x9d46, 0x9d47, 0x9d48, 0x9d49, 0x9d4a, 0x9d4b, 0x9d4c, 0x9d4d, 0x9d4e, 0x9d4f, 0x9d50, 0x9d51, 0x9d52,
+    0x9d53, 0x9d54, 0x9d55, 0x9d56, 0x9d57, 0x9d58, 0x9d59, 0x9d5a, 0x9d5b, 0x9d5c, 0x9d5d, 0x9d5e, 0x9d5f, 0x9d60, 0x9d61, 0x9d62,
+    0x9d63, 0x9d64, 0x9d65, 0x9d66, 0x9d67, 0x9d68, 0x9d69, 0x9d6a, 0x9d6b, 0x9d6c, 0x9d6d, 0x9d6e, 0x9d6f, 0x9d70, 0x9d71, 0x9d72,
+    0x9d73, 0x9d74, 0x9d75, 0x9d76, 0x9d77, 0x9d78, 0x9d79, 0x9d7a, 0x9d7b, 0x9d7c, 0x9d7d, 0x9d7e, 0x9d7f, 0x9d80, 0x9d81, 0x9d82,
+    0x9d83, 0x9d84, 0x9d85, 0x9d86, 0x9d87, 0x9d88, 0x9d89, 0x9d8a, 0x9d8b, 0x9d8c, 0x9d8d, 0x9d8e, 0x9d8f, 0x9d90, 0x9d91, 0x9d92,
+    0x9d93, 0x9d94, 0x9d95, 0x9d96, 0x9d97, 0x9d98, 0x9d99, 0x9d9a, 0x9d9b, 0x9d9c, 0x9d9d, 0x9d9e, 0x9d9f, 0x9da0, 0x9da1, 0x9da2,
+    0xe292, 0xe293, 0xe294, 0xe295, 0xe296, 0xe297, 0xe298, 0xe299, 0xe29a, 0xe29b, 0xe29c, 0xe29d, 0xe29e, 0xe29f, 0xe2a0, 0xe2a1,
+    0xe2a2, 0xe2a3, 0xe2a4, 0xe2a5, 0xe2a6, 0xe2a7, 0xe2a8, 0xe2a9, 0xe2aa, 0xe2ab, 0xe2ac, 0xe2ad, 0xe2ae, 0xe2af, 0xe2b0, 0xe2b1,
+    0xe2b2, 0xe2b3, 0xe2b4, 0xe2b5, 0xe2b6, 0xe2b7, 0xe2b8, 0xe2b9, 0xe2ba, 0xe2bb, 0xe2bc, 0xe2bd, 0xe2be, 0xe2bf, 0xe2c0, 0xe2c1,
+    0xe2c2, 0xe2c3, 0xe2c4, 0xe2c5, 0xe2c6, 0xe2c7, 0xe2c8, 0xe2c9, 0xe2ca, 0xe2cb, 0xe2cc, 0xe2cd, 0xe2ce, 0xe2cf, 0xe2d0, 0xe2d1,
+    0xe2d2, 0xe2d3, 0xe2d4, 0xe2d5, 0xe2d6, 0xe2d7, 0xe2d8, 0xe2d9, 0xe2da, 0xe2db, 0xe2dc, 0xe2dd, 0xe2de, 0xe2df, 0xe2e0, 0xe2e1,
+    0xe2e2, 0xe2e3, 0xe2e4, 0xe2e5, 0xe2e6, 0xe2e7, 0xe2e8, 0xe2e9, 0xe2ea, 0xe2eb, 0xe2ec, 0xe2ed, 0xe2ee, 0xe2ef, 0x9da3, 0x9da4,
+    0x9da5, 0x9da6, 0x9da7, 0x9da8, 0x9da9, 0x9daa, 0x9dab, 0x9dac, 0x9dad, 0x9dae, 0x9daf, 0x9db0, 0x9db1, 0x9db2, 0x9db3, 0x9db4,
+    0x9db5, 0x9db6, 0x9db7, 0x9db8, 0x9db9, 0x9dba, 0x9dbb, 0x9dbc, 0x9dbd, 0x9dbe, 0x9dbf, 0x9dc0, 0x9dc1, 0x9dc2, 0x9dc3, 0x9dc4,
+    0x9dc5, 0x9dc6, 0x9dc7, 0x9dc8, 0x9dc9, 0x9dca, 0x9dcb, 0x9dcc, 0x9dcd, 0x9dce, 0x9dcf, 0x9dd0, 0x9dd1, 0x9dd2, 0x9dd3, 0x9dd4,
+    0x9dd5, 0x9dd6, 0x9dd7, 0x9dd8, 0x9dd9, 0x9dda, 0x9ddb, 0x9ddc, 0x9ddd, 0x9dde, 0x9ddf, 0x9de0, 0x9de1, 0x9de2, 0x9de3, 0x9de4,
+    0x9de5, 0x9de6, 0x9de7, 0x9de8, 0x9de9, 0x9dea, 0x9deb, 0x9dec, 0x9ded, 0x9dee, 0x9def, 0x9df0, 0x9df1, 0x9df2, 0x9df3, 0x9df4,
+    0x9df5, 0x9df6, 0x9df7, 0x9df8, 0x9df9, 0x9dfa, 0x9dfb, 0x9dfc, 0x9dfd, 0x9dfe, 0x9dff, 0x9e00, 0x9e01, 0x9e02, 0xe2f0, 0xe2f1,
+    0xe2f2, 0xe2f3, 0xe2f4, 0xe2f5, 0xe2f6, 0xe2f7, 0xe2f8, 0xe2f9, 0xe2fa, 0xe2fb, 0xe2fc, 0xe2fd, 0xe2fe, 0xe2ff, 0xe300, 0xe301,
+    0xe302, 0xe303, 0xe304, 0xe305, 0xe306, 0xe307, 0xe308, 0xe309, 0xe30a, 0xe30b, 0xe30c, 0xe30d, 0xe30e, 0xe30f, 0xe310, 0xe311,
+    0xe312, 0xe313, 0xe314, 0xe315, 0xe316, 0xe317, 0xe318, 0xe319, 0xe31a, 0xe31b, 0xe31c, 0xe31d, 0xe31e, 0xe31f, 0xe320, 0xe321,
+    0xe322, 0xe323, 0xe324, 0xe325, 0xe326, 0xe327, 0xe328, 0xe329, 0xe32a, 0xe32b, 0xe32c, 0xe32d, 0xe32e, 0xe32f, 0xe330, 0xe331,
+    0xe332, 0xe333, 0xe334, 0xe335, 0xe336, 0xe337, 0xe338, 0xe339, 0xe33a, 0xe33b, 0xe33c, 0xe33d, 0xe33e, 0xe33f, 0xe340, 0xe341,
+    0xe342, 0xe343, 0xe344, 0xe345, 0xe346, 0xe347, 0xe348, 0xe349, 0xe34a, 0xe34b, 0xe34c, 0xe34d, 0x9e03, 0x9e04, 0x9e05, 0x9e06,
+    0x9e07, 0x9e08, 0x9e09, 0x9e0a, 0x9e0b, 0x9e0c, 0x9e0d, 0x9e0e, 0x9e0f, 0x9e10, 0x9e11, 0x9e12, 0x9e13, 0x9e14, 0x9e15, 0x9e16,
+    0x9e17, 0x9e18, 0x9e19, 0x9e1a, 0x9e1b, 0x9e1c, 0x9e1d, 0x9e1e, 0x9e24, 0x9e27, 0x9e2e, 0x9e30, 0x9e34, 0x9e3b, 0x9e3c, 0x9e40,
+    0x9e4d, 0x9e50, 0x9e52, 0x9e53, 0x9e54, 0x9e56, 0x9e59, 0x9e5d, 0x9e5f, 0x9e60, 0x9e61, 0x9e62, 0x9e65, 0x9e6e, 0x9e6f, 0x9e72,
+    0x9e74, 0x9e75, 0x9e76, 0x9e77, 0x9e78, 0x9e79, 0x9e7a, 0x9e7b, 0x9e7c, 0x9e7d, 0x9e80, 0x9e81, 0x9e83, 0x9e84, 0x9e85, 0x9e86,
+    0x9e89, 0x9e8a, 0x9e8c, 0x9e8d, 0x9e8e, 0x9e8f, 0x9e90, 0x9e91, 0x9e94, 0x9e95, 0x9e96, 0x9e97, 0x9e98, 0x9e99, 0x9e9a, 0x9e9b,
+    0x9e9c, 0x9e9e, 0x9ea0, 0x9ea1, 0x9ea2, 0x9ea3, 0x9ea4, 0x9ea5, 0x9ea7, 0x9ea8, 0x9ea9, 0x9eaa, 0xe34e, 0xe34f, 0xe350, 0xe351,
+    0xe352, 0xe353, 0xe354, 0xe355, 0xe356, 0xe357, 0xe358, 0xe359, 0xe35a, 0xe35b, 0xe35c, 0xe35d, 0xe35e, 0xe35f, 0xe360, 0xe361,
+    0xe362, 0xe363, 0xe364, 0xe365, 0xe366, 0xe367, 0xe368, 0xe369, 0xe36a, 0xe36b, 0xe36c, 0xe36d, 0xe36e, 0xe36f, 0xe370, 0xe371,
+    0xe372, 0xe373, 0xe374, 0xe375, 0xe376, 0xe377, 0xe378, 0xe379, 0xe37a, 0xe37b, 0xe37c, 0xe37d, 0xe37e, 0xe37f, 0xe380, 0xe381,
+    0xe382, 0xe383, 0xe384, 0xe385, 0xe386, 0xe387, 0xe388, 0xe389, 0xe38a, 0xe38b, 0xe38c, 0xe38d, 0xe38e, 0xe38f, 0xe390, 0xe391,
+    0xe392, 0xe393, 0xe394, 0xe395, 0xe396, 0xe397, 0xe398, 0xe399, 0xe39a, 0xe39b, 0xe39c, 0xe39d, 0xe39e, 0xe39f, 0xe3a0, 0xe3a1,
+    0xe3a2, 0xe3a3, 0xe3a4, 0xe3a5, 0xe3a6, 0xe3a7, 0xe3a8, 0xe3a9, 0xe3aa, 0xe3ab, 0x9eab, 0x9eac, 0x9ead, 0x9eae, 0x9eaf, 0x9eb0,
+    0x9eb1, 0x9eb2, 0x9eb3, 0x9eb5, 0x9eb6, 0x9eb7, 0x9eb9, 0x9eba, 0x9ebc, 0x9ebf, 0x9ec0, 0x9ec1, 0x9ec2, 0x9ec3, 0x9ec5, 0x9ec6,
+    0x9ec7, 0x9ec8, 0x9eca, 0x9ecb, 0x9ecc, 0x9ed0, 0x9ed2, 0x9ed3, 0x9ed5, 0x9ed6, 0x9ed7, 0x9ed9, 0x9eda, 0x9ede, 0x9ee1, 0x9ee3,
+    0x9ee4, 0x9ee6, 0x9ee8, 0x9eeb, 0x9eec, 0x9eed, 0x9eee, 0x9ef0, 0x9ef1, 0x9ef2, 0x9ef3, 0x9ef4, 0x9ef5, 0x9ef6, 0x9ef7, 0x9ef8,
+    0x9efa, 0x9efd, 0x9eff, 0x9f00, 0x9f01, 0x9f02, 0x9f03, 0x9f04, 0x9f05, 0x9f06, 0x9f07, 0x9f08, 0x9f09, 0x9f0a, 0x9f0c, 0x9f0f,
+    0x9f11, 0x9f12, 0x9f14, 0x9f15, 0x9f16, 0x9f18, 0x9f1a, 0x9f1b, 0x9f1c, 0x9f1d, 0x9f1e, 0x9f1f, 0x9f21, 0x9f23, 0x9f24, 0x9f25,
+    0x9f26, 0x9f27, 0x9f28, 0x9f29, 0x9f2a, 0x9f2b, 0x9f2d, 0x9f2e, 0x9f30, 0x9f31, 0xe3ac, 0xe3ad, 0xe3ae, 0xe3af, 0xe3b0, 0xe3b1,
+    0xe3b2, 0xe3b3, 0xe3b4, 0xe3b5, 0xe3b6, 0xe3b7, 0xe3b8, 0xe3b9, 0xe3ba, 0xe3bb, 0xe3bc, 0xe3bd, 0xe3be, 0xe3bf, 0xe3c0, 0xe3c1,
+    0xe3c2, 0xe3c3, 0xe3c4, 0xe3c5, 0xe3c6, 0xe3c7, 0xe3c8, 0xe3c9, 0xe3ca, 0xe3cb, 0xe3cc, 0xe3cd, 0xe3ce, 0xe3cf, 0xe3d0, 0xe3d1,
+    0xe3d2, 0xe3d3, 0xe3d4, 0xe3d5, 0xe3d6, 0xe3d7, 0xe3d8, 0xe3d9, 0xe3da, 0xe3db, 0xe3dc, 0xe3dd, 0xe3de, 0xe3df, 0xe3e0, 0xe3e1,
+    0xe3e2, 0xe3e3, 0xe3e4, 0xe3e5, 0xe3e6, 0xe3e7, 0xe3e8, 0xe3e9, 0xe3ea, 0xe3eb, 0xe3ec, 0xe3ed, 0xe3ee, 0xe3ef, 0xe3f0, 0xe3f1,
+    0xe3f2, 0xe3f3, 0xe3f4, 0xe3f5, 0xe3f6, 0xe3f7, 0xe3f8, 0xe3f9, 0xe3fa, 0xe3fb, 0xe3fc, 0xe3fd, 0xe3fe, 0xe3ff, 0xe400, 0xe401,
+    0xe402, 0xe403, 0xe404, 0xe405, 0xe406, 0xe407, 0xe408, 0xe409, 0x9f32, 0x9f33, 0x9f34, 0x9f35, 0x9f36, 0x9f38, 0x9f3a, 0x9f3c,
+    0x9f3f, 0x9f40, 0x9f41, 0x9f42, 0x9f43, 0x9f45, 0x9f46, 0x9f47, 0x9f48, 0x9f49, 0x9f4a, 0x9f4b, 0x9f4c, 0x9f4d, 0x9f4e, 0x9f4f,
+    0x9f52, 0x9f53, 0x9f54, 0x9f55, 0x9f56, 0x9f57, 0x9f58, 0x9f59, 0x9f5a, 0x9f5b, 0x9f5c, 0x9f5d, 0x9f5e, 0x9f5f, 0x9f60, 0x9f61,
+    0x9f62, 0x9f63, 0x9f64, 0x9f65, 0x9f66, 0x9f67, 0x9f68, 0x9f69, 0x9f6a, 0x9f6b, 0x9f6c, 0x9f6d, 0x9f6e, 0x9f6f, 0x9f70, 0x9f71,
+    0x9f72, 0x9f73, 0x9f74, 0x9f75, 0x9f76, 0x9f77, 0x9f78, 0x9f79, 0x9f7a, 0x9f7b, 0x9f7c, 0x9f7d, 0x9f7e, 0x9f81, 0x9f82, 0x9f8d,
+    0x9f8e, 0x9f8f, 0x9f90, 0x9f91, 0x9f92, 0x9f93, 0x9f94, 0x9f95, 0x9f96, 0x9f97, 0x9f98, 0x9f9c, 0x9f9d, 0x9f9e, 0x9fa1, 0x9fa2,
+    0x9fa3, 0x9fa4, 0x9fa5, 0xf92c, 0xf979, 0xf995, 0xf9e7, 0xf9f1, 0xe40a, 0xe40b, 0xe40c, 0xe40d, 0xe40e, 0xe40f, 0xe410, 0xe411,
+    0xe412, 0xe413, 0xe414, 0xe415, 0xe416, 0xe417, 0xe418, 0xe419, 0xe41a, 0xe41b, 0xe41c, 0xe41d, 0xe41e, 0xe41f, 0xe420, 0xe421,
+    0xe422, 0xe423, 0xe424, 0xe425, 0xe426, 0xe427, 0xe428, 0xe429, 0xe42a, 0xe42b, 0xe42c, 0xe42d, 0xe42e, 0xe42f, 0xe430, 0xe431,
+    0xe432, 0xe433, 0xe434, 0xe435, 0xe436, 0xe437, 0xe438, 0xe439, 0xe43a, 0xe43b, 0xe43c, 0xe43d, 0xe43e, 0xe43f, 0xe440, 0xe441,
+    0xe442, 0xe443, 0xe444, 0xe445, 0xe446, 0xe447, 0xe448, 0xe449, 0xe44a, 0xe44b, 0xe44c, 0xe44d, 0xe44e, 0xe44f, 0xe450, 0xe451,
+    0xe452, 0xe453, 0xe454, 0xe455, 0xe456, 0xe457, 0xe458, 0xe459, 0xe45a, 0xe45b, 0xe45c, 0xe45d, 0xe45e, 0xe45f, 0xe460, 0xe461,
+    0xe462, 0xe463, 0xe464, 0xe465, 0xe466, 0xe467, 0xfa0c, 0xfa0d, 0xfa0e, 0xfa0f, 0xfa11, 0xfa13, 0xfa14, 0xfa18, 0xfa1f, 0xfa20,
+    0xfa21, 0xfa23, 0xfa24, 0xfa27, 0xfa28, 0xfa29, 0x2e81, 0xe816, 0xe817, 0xe818, 0x2e84, 0x3473, 0x3447, 0x2e88, 0x2e8b, 0xe81e,
+    0x359e, 0x361a, 0x360e, 0x2e8c, 0x2e97, 0x396e, 0x3918, 0xe826, 0x39cf, 0x39df, 0x3a73, 0x39d0, 0xe82b, 0xe82c, 0x3b4e, 0x3c6e,
+    0x3ce0, 0x2ea7, 0xe831, 0xe832, 0x2eaa, 0x4056, 0x415f, 0x2eae, 0x4337, 0x2eb3, 0x2eb6, 0x2eb7, 0xe83b, 0x43b1, 0x43ac, 0x2ebb,
+    0x43dd, 0x44d6, 0x4661, 0x464c, 0xe843, 0x4723, 0x4729, 0x477c, 0x478d, 0x2eca, 0x4947, 0x497a, 0x497d, 0x4982, 0x4983, 0x4985,
+    0x4986, 0x499f, 0x499b, 0x49b7, 0x49b6, 0xe854, 0xe855, 0x4ca3, 0x4c9f, 0x4ca0, 0x4ca1, 0x4c77, 0x4ca2, 0x4d13, 0x4d14, 0x4d15,
+    0x4d16, 0x4d17, 0x4d18, 0x4d19, 0x4dae, 0xe864, 0xe468, 0xe469, 0xe46a, 0xe46b, 0xe46c, 0xe46d, 0xe46e, 0xe46f, 0xe470, 0xe471,
+    0xe472, 0xe473, 0xe474, 0xe475, 0xe476, 0xe477, 0xe478, 0xe479, 0xe47a, 0xe47b, 0xe47c, 0xe47d, 0xe47e, 0xe47f, 0xe480, 0xe481,
+    0xe482, 0xe483, 0xe484, 0xe485, 0xe486, 0xe487, 0xe488, 0xe489, 0xe48a, 0xe48b, 0xe48c, 0xe48d, 0xe48e, 0xe48f, 0xe490, 0xe491,
+    0xe492, 0xe493, 0xe494, 0xe495, 0xe496, 0xe497, 0xe498, 0xe499, 0xe49a, 0xe49b, 0xe49c, 0xe49d, 0xe49e, 0xe49f, 0xe4a0, 0xe4a1,
+    0xe4a2, 0xe4a3, 0xe4a4, 0xe4a5, 0xe4a6, 0xe4a7, 0xe4a8, 0xe4a9, 0xe4aa, 0xe4ab, 0xe4ac, 0xe4ad, 0xe4ae, 0xe4af, 0xe4b0, 0xe4b1,
+    0xe4b2, 0xe4b3, 0xe4b4, 0xe4b5, 0xe4b6, 0xe4b7, 0xe4b8, 0xe4b9, 0xe4ba, 0xe4bb, 0xe4bc, 0xe4bd, 0xe4be, 0xe4bf, 0xe4c0, 0xe4c1,
+    0xe4c2, 0xe4c3, 0xe4c4, 0xe4c5,
+];
+
+/// Pairs of a Unicode code point and the pointer of its character, sorted by code point.
+#[rustfmt::skip]
+static FROM_UNICODE: [(u16, u16); 23940] = [
+    (0x00a4, 6247), (0x00a7, 6251), (0x00a8, 6182), (0x00b0, 6242), (0x00b1, 6207), (0x00b7, 6179), (0x00d7, 6208), (0x00e0, 7509),
+    (0x00e1, 7507), (0x00e8, 7513), (0x00e9, 7511), (0x00ea, 7531), (0x00ec, 7517), (0x00ed, 7515), (0x00f2, 7521), (0x00f3, 7519),
+    (0x00f7, 6209), (0x00f9, 7525), (0x00fa, 7523), (0x00fc, 7530), (0x0101, 7506), (0x0113, 7510), (0x011b, 7512), (0x012b, 7514),
+    (0x0144, 7534), (0x0148, 7535), (0x014d, 7518), (0x016b, 7522), (0x01ce, 7508), (0x01d0, 7516), (0x01d2, 7520), (0x01d4, 7524),
+    (0x01d6, 7526), (0x01d8, 7527), (0x01da, 7528), (0x01dc, 7529), (0x01f9, 7536), (0x0251, 7532), (0x0261, 7537), (0x02c7, 6181),
+    (0x02c9, 6180), (0x02ca, 7410), (0x02cb, 7411), (0x02d9, 7412), (0x0391, 7126), (0x0392, 7127), (0x0393, 7128), (0x0394, 7129),
+    (0x0395, 7130), (0x0396, 7131), (0x0397, 7132), (0x0398, 7133), (0x0399, 7134), (0x039a, 7135), (0x039b, 7136), (0x039c, 7137),
+    (0x039d, 7138), (0x039e, 7139), (0x039f, 7140), (0x03a0, 7141), (0x03a1, 7142), (0x03a3, 7143), (0x03a4, 7144), (0x03a5, 7145),
+    (0x03a6, 7146), (0x03a7, 7147), (0x03a8, 7148), (0x03a9, 7149), (0x03b1, 7158), (0x03b2, 7159), (0x03b3, 7160), (0x03b4, 7161),
+    (0x03b5, 7162), (0x03b6, 7163), (0x03b7, 7164), (0x03b8, 7165), (0x03b9, 7166), (0x03ba, 7167), (0x03bb, 7168), (0x03bc, 7169),
+    (0x03bd, 7170), (0x03be, 7171), (0x03bf, 7172), (0x03c0, 7173), (0x03c1, 7174), (0x03c3, 7175), (0x03c4, 7176), (0x03c5, 7177),
+    (0x03c6, 7178), (0x03c7, 7179), (0x03c8, 7180), (0x03c9, 7181), (0x0401, 7322), (0x0410, 7316), (0x0411, 7317), (0x0412, 7318),
+    (0x0413, 7319), (0x0414, 7320), (0x0415, 7321), (0x0416, 7323), (0x0417, 7324), (0x0418, 7325), (0x0419, 7326), (0x041a, 7327),
+    (0x041b, 7328), (0x041c, 7329), (0x041d, 7330), (0x041e, 7331), (0x041f, 7332), (0x0420, 7333), (0x0421, 7334), (0x0422, 7335),
+    (0x0423, 7336), (0x0424, 7337), (0x0425, 7338), (0x0426, 7339), (0x0427, 7340), (0x0428, 7341), (0x0429, 7342), (0x042a, 7343),
+    (0x042b, 7344), (0x042c, 7345), (0x042d, 7346), (0x042e, 7347), (0x042f, 7348), (0x0430, 7364), (0x0431, 7365), (0x0432, 7366),
+    (0x0433, 7367), (0x0434, 7368), (0x0435, 7369), (0x0436, 7371), (0x0437, 7372), (0x0438, 7373), (0x0439, 7374), (0x043a, 7375),
+    (0x043b, 7376), (0x043c, 7377), (0x043d, 7378), (0x043e, 7379), (0x043f, 7380), (0x0440, 7381), (0x0441, 7382), (0x0442, 7383),
+    (0x0443, 7384), (0x0444, 7385), (0x0445, 7386), (0x0446, 7387), (0x0447, 7388), (0x0448, 7389), (0x0449, 7390), (0x044a, 7391),
+    (0x044b, 7392), (0x044c, 7393), (0x044d, 7394), (0x044e, 7395), (0x044f, 7396), (0x0451, 7370), (0x2010, 7628), (0x2013, 7413),
+    (0x2014, 6185), (0x2015, 7414), (0x2016, 6187), (0x2018, 6189), (0x2019, 6190), (0x201c, 6191), (0x201d, 6192), (0x2025, 7415),
+    (0x2026, 6188), (0x2030, 6250), (0x2032, 6243), (0x2033, 6244), (0x2035, 7416), (0x203b, 6264), (0x20ac, 6432), (0x2103, 6245),
+    (0x2105, 7417), (0x2109, 7418), (0x2116, 6252), (0x2121, 7625), (0x2160, 6446), (0x2161, 6447), (0x2162, 6448), (0x2163, 6449),
+    (0x2164, 6450), (0x2165, 6451), (0x2166, 6452), (0x2167, 6453), (0x2168, 6454), (0x2169, 6455), (0x216a, 6456), (0x216b, 6457),
+    (0x2170, 6366), (0x2171, 6367), (0x2172, 6368), (0x2173, 6369), (0x2174, 6370), (0x2175, 6371), (0x2176, 6372), (0x2177, 6373),
+    (0x2178, 6374), (0x2179, 6375), (0x2190, 6266), (0x2191, 6267), (0x2192, 6265), (0x2193, 6268), (0x2196, 7419), (0x2197, 7420),
+    (0x2198, 7421), (0x2199, 7422), (0x2208, 6217), (0x220f, 6214), (0x2211, 6213), (0x2215, 7423), (0x221a, 6219), (0x221d, 6231),
+    (0x221e, 6237), (0x221f, 7424), (0x2220, 6222), (0x2223, 7425), (0x2225, 6221), (0x2227, 6211), (0x2228, 6212), (0x2229, 6216),
+    (0x222a, 6215), (0x222b, 6225), (0x222e, 6226), (0x2234, 6239), (0x2235, 6238), (0x2236, 6210), (0x2237, 6218), (0x223d, 6230),
+    (0x2248, 6229), (0x224c, 6228), (0x2252, 7426), (0x2260, 6232), (0x2261, 6227), (0x2264, 6235), (0x2265, 6236), (0x2266, 7427),
+    (0x2267, 7428), (0x226e, 6233), (0x226f, 6234), (0x2295, 7491), (0x2299, 6224), (0x22a5, 6220), (0x22bf, 7429), (0x2312, 6223),
+    (0x2460, 6422), (0x2461, 6423), (0x2462, 6424), (0x2463, 6425), (0x2464, 6426), (0x2465, 6427), (0x2466, 6428), (0x2467, 6429),
+    (0x2468, 6430), (0x2469, 6431), (0x2474, 6402), (0x2475, 6403), (0x2476, 6404), (0x2477, 6405), (0x2478, 6406), (0x2479, 6407),
+    (0x247a, 6408), (0x247b, 6409), (0x247c, 6410), (0x247d, 6411), (0x247e, 6412), (0x247f, 6413), (0x2480, 6414), (0x2481, 6415),
+    (0x2482, 6416), (0x2483, 6417), (0x2484, 6418), (0x2485, 6419), (0x2486, 6420), (0x2487, 6421), (0x2488, 6382), (0x2489, 6383),
+    (0x248a, 6384), (0x248b, 6385), (0x248c, 6386), (0x248d, 6387), (0x248e, 6388), (0x248f, 6389), (0x2490, 6390), (0x2491, 6391),
+    (0x2492, 6392), (0x2493, 6393), (0x2494, 6394), (0x2495, 6395), (0x2496, 6396), (0x2497, 6397), (0x2498, 6398), (0x2499, 6399),
+    (0x249a, 6400), (0x249b, 6401), (0x2500, 7699), (0x2501, 7700), (0x2502, 7701), (0x2503, 7702), (0x2504, 7703), (0x2505, 7704),
+    (0x2506, 7705), (0x2507, 7706), (0x2508, 7707), (0x2509, 7708), (0x250a, 7709), (0x250b, 7710), (0x250c, 7711), (0x250d, 7712),
+    (0x250e, 7713), (0x250f, 7714), (0x2510, 7715), (0x2511, 7716), (0x2512, 7717), (0x2513, 7718), (0x2514, 7719), (0x2515, 7720),
+    (0x2516, 7721), (0x2517, 7722), (0x2518, 7723), (0x2519, 7724), (0x251a, 7725), (0x251b, 7726), (0x251c, 7727), (0x251d, 7728),
+    (0x251e, 7729), (0x251f, 7730), (0x2520, 7731), (0x2521, 7732), (0x2522, 7733), (0x2523, 7734), (0x2524, 7735), (0x2525, 7736),
+    (0x2526, 7737), (0x2527, 7738), (0x2528, 7739), (0x2529, 7740), (0x252a, 7741), (0x252b, 7742), (0x252c, 7743), (0x252d, 7744),
+    (0x252e, 7745), (0x252f, 7746), (0x2530, 7747), (0x2531, 7748), (0x2532, 7749), (0x2533, 7750), (0x2534, 7751), (0x2535, 7752),
+    (0x2536, 7753), (0x2537, 7754), (0x2538, 7755), (0x2539, 7756), (0x253a, 7757), (0x253b, 7758), (0x253c, 7759), (0x253d, 7760),
+    (0x253e, 7761), (0x253f, 7762), (0x2540, 7763), (0x2541, 7764), (0x2542, 7765), (0x2543, 7766), (0x2544, 7767), (0x2545, 7768),
+    (0x2546, 7769), (0x2547, 7770), (0x2548, 7771), (0x2549, 7772), (0x254a, 7773), (0x254b, 7774), (0x2550, 7430), (0x2551, 7431),
+    (0x2552, 7432), (0x2553, 7433), (0x2554, 7434), (0x2555, 7435), (0x2556, 7436), (0x2557, 7437), (0x2558, 7438), (0x2559, 7439),
+    (0x255a, 7440), (0x255b, 7441), (0x255c, 7442), (0x255d, 7443), (0x255e, 7444), (0x255f, 7445), (0x2560, 7446), (0x2561, 7447),
+    (0x2562, 7448), (0x2563, 7449), (0x2564, 7450), (0x2565, 7451), (0x2566, 7452), (0x2567, 7453), (0x2568, 7454), (0x2569, 7455),
+    (0x256a, 7456), (0x256b, 7457), (0x256c, 7458), (0x256d, 7459), (0x256e, 7460), (0x256f, 7461), (0x2570, 7462), (0x2571, 7463),
+    (0x2572, 7464), (0x2573, 7465), (0x2581, 7466), (0x2582, 7467), (0x2583, 7468), (0x2584, 7469), (0x2585, 7470), (0x2586, 7471),
+    (0x2587, 7472), (0x2588, 7473), (0x2589, 7474), (0x258a, 7475), (0x258b, 7476), (0x258c, 7477), (0x258d, 7478), (0x258e, 7479),
+    (0x258f, 7480), (0x2593, 7481), (0x2594, 7482), (0x2595, 7483), (0x25a0, 6261), (0x25a1, 6260), (0x25b2, 6263), (0x25b3, 6262),
+    (0x25bc, 7484), (0x25bd, 7485), (0x25c6, 6259), (0x25c7, 6258), (0x25cb, 6255), (0x25ce, 6257), (0x25cf, 6256), (0x25e2, 7486),
+    (0x25e3, 7487), (0x25e4, 7488), (0x25e5, 7489), (0x2605, 6254), (0x2606, 6253), (0x2609, 7490), (0x2640, 6241), (0x2642, 6240),
+    (0x2e81, 23766), (0x2e84, 23770), (0x2e88, 23773), (0x2e8b, 23774), (0x2e8c, 23779), (0x2e97, 23780), (0x2ea7, 23793), (0x2eaa, 23796),
+    (0x2eae, 23799), (0x2eb3, 23801), (0x2eb6, 23802), (0x2eb7, 23803), (0x2ebb, 23807), (0x2eca, 23817), (0x2ff0, 7673), (0x2ff1, 7674),
+    (0x2ff2, 7675), (0x2ff3, 7676), (0x2ff4, 7677), (0x2ff5, 7678), (0x2ff6, 7679), (0x2ff7, 7680), (0x2ff8, 7681), (0x2ff9, 7682),
+    (0x2ffa, 7683), (0x2ffb, 7684), (0x3000, 6176), (0x3001, 6177), (0x3002, 6178), (0x3003, 6183), (0x3005, 6184), (0x3006, 7637),
+    (0x3007, 7685), (0x3008, 6195), (0x3009, 6196), (0x300a, 6197), (0x300b, 6198), (0x300c, 6199), (0x300d, 6200), (0x300e, 6201),
+    (0x300f, 6202), (0x3010, 6205), (0x3011, 6206), (0x3012, 7492), (0x3013, 6269), (0x3014, 6193), (0x3015, 6194), (0x3016, 6203),
+    (0x3017, 6204), (0x301d, 7493), (0x301e, 7494), (0x3021, 7600), (0x3022, 7601), (0x3023, 7602), (0x3024, 7603), (0x3025, 7604),
+    (0x3026, 7605), (0x3027, 7606), (0x3028, 7607), (0x3029, 7608), (0x303e, 7672), (0x3041, 6746), (0x3042, 6747), (0x3043, 6748),
+    (0x3044, 6749), (0x3045, 6750), (0x3046, 6751), (0x3047, 6752), (0x3048, 6753), (0x3049, 6754), (0x304a, 6755), (0x304b, 6756),
+    (0x304c, 6757), (0x304d, 6758), (0x304e, 6759), (0x304f, 6760), (0x3050, 6761), (0x3051, 6762), (0x3052, 6763), (0x3053, 6764),
+    (0x3054, 6765), (0x3055, 6766), (0x3056, 6767), (0x3057, 6768), (0x3058, 6769), (0x3059, 6770), (0x305a, 6771), (0x305b, 6772),
+    (0x305c, 6773), (0x305d, 6774), (0x305e, 6775), (0x305f, 6776), (0x3060, 6777), (0x3061, 6778), (0x3062, 6779), (0x3063, 6780),
+    (0x3064, 6781), (0x3065, 6782), (0x3066, 6783), (0x3067, 6784), (0x3068, 6785), (0x3069, 6786), (0x306a, 6787), (0x306b, 6788),
+    (0x306c, 6789), (0x306d, 6790), (0x306e, 6791), (0x306f, 6792), (0x3070, 6793), (0x3071, 6794), (0x3072, 6795), (0x3073, 6796),
+    (0x3074, 6797), (0x3075, 6798), (0x3076, 6799), (0x3077, 6800), (0x3078, 6801), (0x3079, 6802), (0x307a, 6803), (0x307b, 6804),
+    (0x307c, 6805), (0x307d, 6806), (0x307e, 6807), (0x307f, 6808), (0x3080, 6809), (0x3081, 6810), (0x3082, 6811), (0x3083, 6812),
+    (0x3084, 6813), (0x3085, 6814), (0x3086, 6815), (0x3087, 6816), (0x3088, 6817), (0x3089, 6818), (0x308a, 6819), (0x308b, 6820),
+    (0x308c, 6821), (0x308d, 6822), (0x308e, 6823), (0x308f, 6824), (0x3090, 6825), (0x3091, 6826), (0x3092, 6827), (0x3093, 6828),
+    (0x309b, 7633), (0x309c, 7634), (0x309d, 7638), (0x309e, 7639), (0x30a1, 6936), (0x30a2, 6937), (0x30a3, 6938), (0x30a4, 6939),
+    (0x30a5, 6940), (0x30a6, 6941), (0x30a7, 6942), (0x30a8, 6943), (0x30a9, 6944), (0x30aa, 6945), (0x30ab, 6946), (0x30ac, 6947),
+    (0x30ad, 6948), (0x30ae, 6949), (0x30af, 6950), (0x30b0, 6951), (0x30b1, 6952), (0x30b2, 6953), (0x30b3, 6954), (0x30b4, 6955),
+    (0x30b5, 6956), (0x30b6, 6957), (0x30b7, 6958), (0x30b8, 6959), (0x30b9, 6960), (0x30ba, 6961), (0x30bb, 6962), (0x30bc, 6963),
+    (0x30bd, 6964), (0x30be, 6965), (0x30bf, 6966), (0x30c0, 6967), (0x30c1, 6968), (0x30c2, 6969), (0x30c3, 6970), (0x30c4, 6971),
+    (0x30c5, 6972), (0x30c6, 6973), (0x30c7, 6974), (0x30c8, 6975), (0x30c9, 6976), (0x30ca, 6977), (0x30cb, 6978), (0x30cc, 6979),
+    (0x30cd, 6980), (0x30ce, 6981), (0x30cf, 6982), (0x30d0, 6983), (0x30d1, 6984), (0x30d2, 6985), (0x30d3, 6986), (0x30d4, 6987),
+    (0x30d5, 6988), (0x30d6, 6989), (0x30d7, 6990), (0x30d8, 6991), (0x30d9, 6992), (0x30da, 6993), (0x30db, 6994), (0x30dc, 6995),
+    (0x30dd, 6996), (0x30de, 6997), (0x30df, 6998), (0x30e0, 6999), (0x30e1, 7000), (0x30e2, 7001), (0x30e3, 7002), (0x30e4, 7003),
+    (0x30e5, 7004), (0x30e6, 7005), (0x30e7, 7006), (0x30e8, 7007), (0x30e9, 7008), (0x30ea, 7009), (0x30eb, 7010), (0x30ec, 7011),
+    (0x30ed, 7012), (0x30ee, 7013), (0x30ef, 7014), (0x30f0, 7015), (0x30f1, 7016), (0x30f2, 7017), (0x30f3, 7018), (0x30f4, 7019),
+    (0x30f5, 7020), (0x30f6, 7021), (0x30fc, 7632), (0x30fd, 7635), (0x30fe, 7636), (0x3105, 7542), (0x3106, 7543), (0x3107, 7544),
+    (0x3108, 7545), (0x3109, 7546), (0x310a, 7547), (0x310b, 7548), (0x310c, 7549), (0x310d, 7550), (0x310e, 7551), (0x310f, 7552),
+    (0x3110, 7553), (0x3111, 7554), (0x3112, 7555), (0x3113, 7556), (0x3114, 7557), (0x3115, 7558), (0x3116, 7559), (0x3117, 7560),
+    (0x3118, 7561), (0x3119, 7562), (0x311a, 7563), (0x311b, 7564), (0x311c, 7565), (0x311d, 7566), (0x311e, 7567), (0x311f, 7568),
+    (0x3120, 7569), (0x3121, 7570), (0x3122, 7571), (0x3123, 7572), (0x3124, 7573), (0x3125, 7574), (0x3126, 7575), (0x3127, 7576),
+    (0x3128, 7577), (0x3129, 7578), (0x3220, 6434), (0x3221, 6435), (0x3222, 6436), (0x3223, 6437), (0x3224, 6438), (0x3225, 6439),
+    (0x3226, 6440), (0x3227, 6441), (0x3228, 6442), (0x3229, 6443), (0x3231, 7626), (0x32a3, 7609), (0x338e, 7610), (0x338f, 7611),
+    (0x339c, 7612), (0x339d, 7613), (0x339e, 7614), (0x33a1, 7615), (0x33c4, 7616), (0x33ce, 7617), (0x33d1, 7618), (0x33d2, 7619),
+    (0x33d5, 7620), (0x3447, 23772), (0x3473, 23771), (0x359e, 23776), (0x360e, 23778), (0x361a, 23777), (0x3918, 23782), (0x396e, 23781),
+    (0x39cf, 23784), (0x39d0, 23787), (0x39df, 23785), (0x3a73, 23786), (0x3b4e, 23790), (0x3c6e, 23791), (0x3ce0, 23792), (0x4056, 23797),
+    (0x415f, 23798), (0x4337, 23800), (0x43ac, 23806), (0x43b1, 23805), (0x43dd, 23808), (0x44d6, 23809), (0x464c, 23811), (0x4661, 23810),
+    (0x4723, 23813), (0x4729, 23814), (0x477c, 23815), (0x478d, 23816), (0x4947, 23818), (0x497a, 23819), (0x497d, 23820), (0x4982, 23821),
+    (0x4983, 23822), (0x4985, 23823), (0x4986, 23824), (0x499b, 23826), (0x499f, 23825), (0x49b6, 23828), (0x49b7, 23827), (0x4c77, 23835),
+    (0x4c9f, 23832), (0x4ca0, 23833), (0x4ca1, 23834), (0x4ca2, 23836), (0x4ca3, 23831), (0x4d13, 23837), (0x4d14, 23838), (0x4d15, 23839),
+    (0x4d16, 23840), (0x4d17, 23841), (0x4d18, 23842), (0x4d19, 23843), (0x4dae, 23844), (0x4e00, 15512), (0x4e01, 10166), (0x4e02, 0),
+    (0x4e03, 13268), (0x4e04, 1), (0x4e05, 2), (0x4e06, 3), (0x4e07, 14617), (0x4e08, 16096), (0x4e09, 13678), (0x4e0a, 13822),
+    (0x4e0b, 14949), (0x4e0c, 16627), (0x4e0d, 9432), (0x4e0e, 15750), (0x4e0f, 4), (0x4e10, 16629), (0x4e11, 9678), (0x4e12, 5),
+    (0x4e13, 16443), (0x4e14, 13445), (0x4e15, 16632), (0x4e16, 13997), (0x4e17, 6), (0x4e18, 13475), (0x4e19, 9306), (0x4e1a, 15506),
+    (0x4e1b, 9837), (0x4e1c, 10176), (0x4e1d, 14186), (0x4e1e, 16634), (0x4e1f, 7), (0x4e20, 8), (0x4e21, 9), (0x4e22, 10175),
+    (0x4e23, 10), (0x4e24, 12284), (0x4e25, 15342), (0x4e26, 11), (0x4e27, 13780), (0x4e28, 16638), (0x4e29, 12), (0x4e2a, 10631),
+    (0x4e2b, 15325), (0x4e2c, 18775), (0x4e2d, 16293), (0x4e2e, 13), (0x4e2f, 14), (0x4e30, 10420), (0x4e31, 15), (0x4e32, 9799),
+    (0x4e33, 16), (0x4e34, 12312), (0x4e35, 17), (0x4e36, 16653), (0x4e37, 18), (0x4e38, 14607), (0x4e39, 9979), (0x4e3a, 14735),
+    (0x4e3b, 16332), (0x4e3c, 19), (0x4e3d, 12151), (0x4e3e, 11742), (0x4e3f, 16640), (0x4e40, 20), (0x4e41, 21), (0x4e42, 22),
+    (0x4e43, 12868), (0x4e44, 23), (0x4e45, 11720), (0x4e46, 24), (0x4e47, 16642), (0x4e48, 12655), (0x4e49, 15554), (0x4e4a, 25),
+    (0x4e4b, 16259), (0x4e4c, 14783), (0x4e4d, 16062), (0x4e4e, 11010), (0x4e4f, 10361), (0x4e50, 12119), (0x4e51, 26), (0x4e52, 13230),
+    (0x4e53, 13065), (0x4e54, 13434), (0x4e55, 27), (0x4e56, 10787), (0x4e57, 28), (0x4e58, 9638), (0x4e59, 15535), (0x4e5a, 29),
+    (0x4e5b, 30), (0x4e5c, 16656), (0x4e5d, 11722), (0x4e5e, 13287), (0x4e5f, 15503), (0x4e60, 14931), (0x4e61, 14986), (0x4e62, 31),
+    (0x4e63, 32), (0x4e64, 33), (0x4e65, 34), (0x4e66, 14038), (0x4e67, 35), (0x4e68, 36), (0x4e69, 16657), (0x4e6a, 37),
+    (0x4e6b, 38), (0x4e6c, 39), (0x4e6d, 40), (0x4e6e, 41), (0x4e6f, 42), (0x4e70, 12527), (0x4e71, 12495), (0x4e72, 43),
+    (0x4e73, 13658), (0x4e74, 44), (0x4e75, 45), (0x4e76, 46), (0x4e77, 47), (0x4e78, 48), (0x4e79, 49), (0x4e7a, 50),
+    (0x4e7b, 51), (0x4e7c, 52), (0x4e7d, 53), (0x4e7e, 13407), (0x4e7f, 54), (0x4e80, 55), (0x4e81, 56), (0x4e82, 57),
+    (0x4e83, 58), (0x4e84, 59), (0x4e85, 60), (0x4e86, 12298), (0x4e87, 61), (0x4e88, 15747), (0x4e89, 16144), (0x4e8a, 62),
+    (0x4e8b, 13999), (0x4e8c, 10259), (0x4e8d, 16626), (0x4e8e, 15733), (0x4e8f, 11962), (0x4e90, 63), (0x4e91, 15903), (0x4e92, 11120),
+    (0x4e93, 16658), (0x4e94, 14794), (0x4e95, 11699), (0x4e96, 64), (0x4e97, 65), (0x4e98, 16633), (0x4e99, 66), (0x4e9a, 15334),
+    (0x4e9b, 15114), (0x4e9c, 67), (0x4e9d, 68), (0x4e9e, 69), (0x4e9f, 16654), (0x4ea0, 16894), (0x4ea1, 14621), (0x4ea2, 11901),
+    (0x4ea3, 70), (0x4ea4, 11522), (0x4ea5, 10930), (0x4ea6, 15549), (0x4ea7, 9495), (0x4ea8, 10989), (0x4ea9, 12847), (0x4eaa, 71),
+    (0x4eab, 14992), (0x4eac, 11694), (0x4ead, 14539), (0x4eae, 12288), (0x4eaf, 72), (0x4eb0, 73), (0x4eb1, 74), (0x4eb2, 13450),
+    (0x4eb3, 16896), (0x4eb4, 75), (0x4eb5, 16899), (0x4eb6, 76), (0x4eb7, 77), (0x4eb8, 78), (0x4eb9, 79), (0x4eba, 13628),
+    (0x4ebb, 16698), (0x4ebc, 80), (0x4ebd, 81), (0x4ebe, 82), (0x4ebf, 15543), (0x4ec0, 13983), (0x4ec1, 13627), (0x4ec2, 16701),
+    (0x4ec3, 16699), (0x4ec4, 16663), (0x4ec5, 11581), (0x4ec6, 13250), (0x4ec7, 9675), (0x4ec8, 83), (0x4ec9, 16700), (0x4eca, 11576),
+    (0x4ecb, 11568), (0x4ecc, 84), (0x4ecd, 13637), (0x4ece, 9836), (0x4ecf, 85), (0x4ed0, 86), (0x4ed1, 12501), (0x4ed2, 87),
+    (0x4ed3, 9459), (0x4ed4, 16483), (0x4ed5, 14008), (0x4ed6, 14246), (0x4ed7, 16099), (0x4ed8, 10567), (0x4ed9, 14956), (0x4eda, 88),
+    (0x4edb, 89), (0x4edc, 90), (0x4edd, 16873), (0x4ede, 16705), (0x4edf, 13405), (0x4ee0, 91), (0x4ee1, 16703), (0x4ee2, 92),
+    (0x4ee3, 9875), (0x4ee4, 12333), (0x4ee5, 15537), (0x4ee6, 93), (0x4ee7, 94), (0x4ee8, 16702), (0x4ee9, 95), (0x4eea, 15524),
+    (0x4eeb, 16704), (0x4eec, 12674), (0x4eed, 96), (0x4eee, 97), (0x4eef, 98), (0x4ef0, 15381), (0x4ef1, 99), (0x4ef2, 16302),
+    (0x4ef3, 16707), (0x4ef4, 100), (0x4ef5, 16710), (0x4ef6, 11399), (0x4ef7, 11364), (0x4ef8, 101), (0x4ef9, 102), (0x4efa, 103),
+    (0x4efb, 13631), (0x4efc, 104), (0x4efd, 10416), (0x4efe, 105), (0x4eff, 10389), (0x4f00, 106), (0x4f01, 13288), (0x4f02, 107),
+    (0x4f03, 108), (0x4f04, 109), (0x4f05, 110), (0x4f06, 111), (0x4f07, 112), (0x4f08, 113), (0x4f09, 16713), (0x4f0a, 15518),
+    (0x4f0b, 114), (0x4f0c, 115), (0x4f0d, 14798), (0x4f0e, 11336), (0x4f0f, 10447), (0x4f10, 10360), (0x4f11, 15166), (0x4f12, 116),
+    (0x4f13, 117), (0x4f14, 118), (0x4f15, 119), (0x4f16, 120), (0x4f17, 16303), (0x4f18, 15712), (0x4f19, 11194), (0x4f1a, 11180),
+    (0x4f1b, 16706), (0x4f1c, 121), (0x4f1d, 122), (0x4f1e, 13776), (0x4f1f, 14741), (0x4f20, 9796), (0x4f21, 123), (0x4f22, 16708),
+    (0x4f23, 124), (0x4f24, 13818), (0x4f25, 16711), (0x4f26, 12500), (0x4f27, 16712), (0x4f28, 125), (0x4f29, 126), (0x4f2a, 14742),
+    (0x4f2b, 16714), (0x4f2c, 127), (0x4f2d, 128), (0x4f2e, 129), (0x4f2f, 9419), (0x4f30, 10767), (0x4f31, 130), (0x4f32, 16818),
+    (0x4f33, 131), (0x4f34, 9098), (0x4f35, 132), (0x4f36, 12325), (0x4f37, 133), (0x4f38, 13851), (0x4f39, 134), (0x4f3a, 14192),
+    (0x4f3b, 135), (0x4f3c, 14193), (0x4f3d, 16819), (0x4f3e, 136), (0x4f3f, 137), (0x4f40, 138), (0x4f41, 139), (0x4f42, 140),
+    (0x4f43, 10047), (0x4f44, 141), (0x4f45, 142), (0x4f46, 9986), (0x4f47, 143), (0x4f48, 144), (0x4f49, 145), (0x4f4a, 146),
+    (0x4f4b, 147), (0x4f4c, 148), (0x4f4d, 14752), (0x4f4e, 10020), (0x4f4f, 16436), (0x4f50, 16519), (0x4f51, 15726), (0x4f52, 149),
+    (0x4f53, 14414), (0x4f54, 150), (0x4f55, 10971), (0x4f56, 151), (0x4f57, 16817), (0x4f58, 16875), (0x4f59, 15739), (0x4f5a, 16718),
+    (0x4f5b, 10435), (0x4f5c, 16522), (0x4f5d, 16719), (0x4f5e, 16715), (0x4f5f, 16816), (0x4f60, 12892), (0x4f61, 152), (0x4f62, 153),
+    (0x4f63, 15697), (0x4f64, 16709), (0x4f65, 16876), (0x4f66, 154), (0x4f67, 16716), (0x4f68, 155), (0x4f69, 13084), (0x4f6a, 156),
+    (0x4f6b, 157), (0x4f6c, 12113), (0x4f6d, 158), (0x4f6e, 159), (0x4f6f, 15375), (0x4f70, 9084), (0x4f71, 160), (0x4f72, 161),
+    (0x4f73, 11354), (0x4f74, 16821), (0x4f75, 162), (0x4f76, 16820), (0x4f77, 163), (0x4f78, 164), (0x4f79, 165), (0x4f7a, 166),
+    (0x4f7b, 16827), (0x4f7c, 16829), (0x4f7d, 167), (0x4f7e, 16826), (0x4f7f, 13990), (0x4f80, 168), (0x4f81, 169), (0x4f82, 170),
+    (0x4f83, 16824), (0x4f84, 16267), (0x4f85, 171), (0x4f86, 172), (0x4f87, 173), (0x4f88, 9657), (0x4f89, 16823), (0x4f8a, 174),
+    (0x4f8b, 12158), (0x4f8c, 175), (0x4f8d, 14009), (0x4f8e, 176), (0x4f8f, 16825), (0x4f90, 177), (0x4f91, 16822), (0x4f92, 178),
+    (0x4f93, 179), (0x4f94, 16831), (0x4f95, 180), (0x4f96, 181), (0x4f97, 10182), (0x4f98, 182), (0x4f99, 183), (0x4f9a, 184),
+    (0x4f9b, 10744), (0x4f9c, 185), (0x4f9d, 15517), (0x4f9e, 186), (0x4f9f, 187), (0x4fa0, 14947), (0x4fa1, 188), (0x4fa2, 189),
+    (0x4fa3, 12479), (0x4fa4, 190), (0x4fa5, 11531), (0x4fa6, 16131), (0x4fa7, 9469), (0x4fa8, 13435), (0x4fa9, 11950), (0x4faa, 16828),
+    (0x4fab, 191), (0x4fac, 16830), (0x4fad, 192), (0x4fae, 14799), (0x4faf, 11003), (0x4fb0, 193), (0x4fb1, 194), (0x4fb2, 195),
+    (0x4fb3, 196), (0x4fb4, 197), (0x4fb5, 13449), (0x4fb6, 198), (0x4fb7, 199), (0x4fb8, 200), (0x4fb9, 201), (0x4fba, 202),
+    (0x4fbb, 203), (0x4fbc, 204), (0x4fbd, 205), (0x4fbe, 206), (0x4fbf, 9282), (0x4fc0, 207), (0x4fc1, 208), (0x4fc2, 209),
+    (0x4fc3, 9842), (0x4fc4, 10242), (0x4fc5, 16835), (0x4fc6, 210), (0x4fc7, 211), (0x4fc8, 212), (0x4fc9, 213), (0x4fca, 11876),
+    (0x4fcb, 214), (0x4fcc, 215), (0x4fcd, 216), (0x4fce, 16877), (0x4fcf, 13441), (0x4fd0, 12159), (0x4fd1, 16839), (0x4fd2, 217),
+    (0x4fd3, 218), (0x4fd4, 219), (0x4fd5, 220), (0x4fd6, 221), (0x4fd7, 14210), (0x4fd8, 10448), (0x4fd9, 222), (0x4fda, 16836),
+    (0x4fdb, 223), (0x4fdc, 16838), (0x4fdd, 9218), (0x4fde, 15740), (0x4fdf, 16840), (0x4fe0, 224), (0x4fe1, 15142), (0x4fe2, 225),
+    (0x4fe3, 16837), (0x4fe4, 226), (0x4fe5, 227), (0x4fe6, 16832), (0x4fe7, 228), (0x4fe8, 16833), (0x4fe9, 12264), (0x4fea, 16834),
+    (0x4feb, 229), (0x4fec, 230), (0x4fed, 11388), (0x4fee, 15167), (0x4fef, 10554), (0x4ff0, 231), (0x4ff1, 11752), (0x4ff2, 232),
+    (0x4ff3, 16844), (0x4ff4, 233), (0x4ff5, 234), (0x4ff6, 235), (0x4ff7, 236), (0x4ff8, 16841), (0x4ff9, 237), (0x4ffa, 9044),
+    (0x4ffb, 238), (0x4ffc, 239), (0x4ffd, 240), (0x4ffe, 16849), (0x4fff, 241), (0x5000, 242), (0x5001, 243), (0x5002, 244),
+    (0x5003, 245), (0x5004, 246), (0x5005, 247), (0x5006, 248), (0x5007, 249), (0x5008, 250), (0x5009, 251), (0x500a, 252),
+    (0x500b, 253), (0x500c, 16851), (0x500d, 9237), (0x500e, 254), (0x500f, 16846), (0x5010, 255), (0x5011, 256), (0x5012, 10000),
+    (0x5013, 257), (0x5014, 11768), (0x5015, 258), (0x5016, 259), (0x5017, 260), (0x5018, 14385), (0x5019, 11007), (0x501a, 15533),
+    (0x501b, 261), (0x501c, 16850), (0x501d, 262), (0x501e, 263), (0x501f, 11567), (0x5020, 264), (0x5021, 9606), (0x5022, 265),
+    (0x5023, 266), (0x5024, 267), (0x5025, 16852), (0x5026, 11760), (0x5027, 268), (0x5028, 16853), (0x5029, 16842), (0x502a, 12888),
+    (0x502b, 269), (0x502c, 16845), (0x502d, 16848), (0x502e, 16847), (0x502f, 270), (0x5030, 271), (0x5031, 272), (0x5032, 273),
+    (0x5033, 274), (0x5034, 275), (0x5035, 276), (0x5036, 277), (0x5037, 278), (0x5038, 279), (0x5039, 280), (0x503a, 16069),
+    (0x503b, 281), (0x503c, 16266), (0x503d, 282), (0x503e, 13462), (0x503f, 283), (0x5040, 284), (0x5041, 285), (0x5042, 286),
+    (0x5043, 16855), (0x5044, 287), (0x5045, 288), (0x5046, 289), (0x5047, 11362), (0x5048, 16857), (0x5049, 290), (0x504a, 291),
+    (0x504b, 292), (0x504c, 16843), (0x504d, 293), (0x504e, 16858), (0x504f, 13216), (0x5050, 294), (0x5051, 295), (0x5052, 296),
+    (0x5053, 297), (0x5054, 298), (0x5055, 16856), (0x5056, 299), (0x5057, 300), (0x5058, 301), (0x5059, 302), (0x505a, 16521),
+    (0x505b, 303), (0x505c, 14538), (0x505d, 304), (0x505e, 305), (0x505f, 306), (0x5060, 307), (0x5061, 308), (0x5062, 309),
+    (0x5063, 310), (0x5064, 311), (0x5065, 11496), (0x5066, 312), (0x5067, 313), (0x5068, 314), (0x5069, 315), (0x506a, 316),
+    (0x506b, 317), (0x506c, 16859), (0x506d, 318), (0x506e, 319), (0x506f, 320), (0x5070, 321), (0x5071, 322), (0x5072, 323),
+    (0x5073, 324), (0x5074, 325), (0x5075, 326), (0x5076, 13043), (0x5077, 14556), (0x5078, 327), (0x5079, 328), (0x507a, 329),
+    (0x507b, 16860), (0x507c, 330), (0x507d, 331), (0x507e, 16854), (0x507f, 9600), (0x5080, 11969), (0x5081, 332), (0x5082, 333),
+    (0x5083, 334), (0x5084, 335), (0x5085, 10566), (0x5086, 336), (0x5087, 337), (0x5088, 12157), (0x5089, 338), (0x508a, 339),
+    (0x508b, 340), (0x508c, 341), (0x508d, 9113), (0x508e, 342), (0x508f, 343), (0x5090, 344), (0x5091, 345), (0x5092, 346),
+    (0x5093, 347), (0x5094, 348), (0x5095, 349), (0x5096, 350), (0x5097, 351), (0x5098, 352), (0x5099, 353), (0x509a, 354),
+    (0x509b, 355), (0x509c, 356), (0x509d, 357), (0x509e, 358), (0x509f, 359), (0x50a0, 360), (0x50a1, 361), (0x50a2, 362),
+    (0x50a3, 9871), (0x50a4, 363), (0x50a5, 16861), (0x50a6, 364), (0x50a7, 16862), (0x50a8, 9787), (0x50a9, 16863), (0x50aa, 365),
+    (0x50ab, 366), (0x50ac, 9848), (0x50ad, 367), (0x50ae, 368), (0x50af, 369), (0x50b0, 370), (0x50b1, 371), (0x50b2, 9058),
+    (0x50b3, 372), (0x50b4, 373), (0x50b5, 374), (0x50b6, 375), (0x50b7, 376), (0x50b8, 377), (0x50b9, 378), (0x50ba, 16864),
+    (0x50bb, 13796), (0x50bc, 379), (0x50bd, 380), (0x50be, 381), (0x50bf, 382), (0x50c0, 383), (0x50c1, 384), (0x50c2, 385),
+    (0x50c3, 386), (0x50c4, 387), (0x50c5, 388), (0x50c6, 389), (0x50c7, 390), (0x50c8, 391), (0x50c9, 392), (0x50ca, 393),
+    (0x50cb, 394), (0x50cc, 395), (0x50cd, 396), (0x50ce, 397), (0x50cf, 14996), (0x50d0, 398), (0x50d1, 399), (0x50d2, 400),
+    (0x50d3, 401), (0x50d4, 402), (0x50d5, 403), (0x50d6, 16865), (0x50d7, 404), (0x50d8, 405), (0x50d9, 406), (0x50da, 12292),
+    (0x50db, 407), (0x50dc, 408), (0x50dd, 409), (0x50de, 410), (0x50df, 411), (0x50e0, 412), (0x50e1, 413), (0x50e2, 414),
+    (0x50e3, 415), (0x50e4, 416), (0x50e5, 417), (0x50e6, 16869), (0x50e7, 13789), (0x50e8, 418), (0x50e9, 419), (0x50ea, 420),
+    (0x50eb, 421), (0x50ec, 16868), (0x50ed, 16867), (0x50ee, 16870), (0x50ef, 422), (0x50f0, 423), (0x50f1, 424), (0x50f2, 425),
+    (0x50f3, 14214), (0x50f4, 426), (0x50f5, 11504), (0x50f6, 427), (0x50f7, 428), (0x50f8, 429), (0x50f9, 430), (0x50fa, 431),
+    (0x50fb, 13212), (0x50fc, 432), (0x50fd, 433), (0x50fe, 434), (0x50ff, 435), (0x5100, 436), (0x5101, 437), (0x5102, 438),
+    (0x5103, 439), (0x5104, 440), (0x5105, 441), (0x5106, 16866), (0x5107, 16871), (0x5108, 442), (0x5109, 443), (0x510a, 444),
+    (0x510b, 16872), (0x510c, 445), (0x510d, 446), (0x510e, 447), (0x510f, 448), (0x5110, 449), (0x5111, 450), (0x5112, 13654),
+    (0x5113, 451), (0x5114, 452), (0x5115, 453), (0x5116, 454), (0x5117, 455), (0x5118, 456), (0x5119, 457), (0x511a, 458),
+    (0x511b, 459), (0x511c, 460), (0x511d, 461), (0x511e, 462), (0x511f, 463), (0x5120, 464), (0x5121, 12125), (0x5122, 465),
+    (0x5123, 466), (0x5124, 467), (0x5125, 468), (0x5126, 469), (0x5127, 470), (0x5128, 471), (0x5129, 472), (0x512a, 473),
+    (0x512b, 474), (0x512c, 475), (0x512d, 476), (0x512e, 477), (0x512f, 478), (0x5130, 479), (0x5131, 480), (0x5132, 481),
+    (0x5133, 482), (0x5134, 483), (0x5135, 484), (0x5136, 485), (0x5137, 486), (0x5138, 487), (0x5139, 488), (0x513a, 489),
+    (0x513b, 490), (0x513c, 491), (0x513d, 492), (0x513e, 493), (0x513f, 10254), (0x5140, 16628), (0x5141, 15907), (0x5142, 494),
+    (0x5143, 15875), (0x5144, 15159), (0x5145, 9663), (0x5146, 16110), (0x5147, 495), (0x5148, 14955), (0x5149, 10801), (0x514a, 496),
+    (0x514b, 11918), (0x514c, 497), (0x514d, 12701), (0x514e, 498), (0x514f, 499), (0x5150, 500), (0x5151, 10215), (0x5152, 501),
+    (0x5153, 502), (0x5154, 14570), (0x5155, 16893), (0x5156, 16895), (0x5157, 503), (0x5158, 504), (0x5159, 505), (0x515a, 9994),
+    (0x515b, 506), (0x515c, 10186), (0x515d, 507), (0x515e, 508), (0x515f, 509), (0x5160, 510), (0x5161, 511), (0x5162, 11689),
+    (0x5163, 512), (0x5164, 513), (0x5165, 13660), (0x5166, 514), (0x5167, 515), (0x5168, 13596), (0x5169, 516), (0x516a, 517),
+    (0x516b, 9068), (0x516c, 10746), (0x516d, 12344), (0x516e, 16881), (0x516f, 518), (0x5170, 12093), (0x5171, 10753), (0x5172, 519),
+    (0x5173, 10791), (0x5174, 15148), (0x5175, 9303), (0x5176, 13273), (0x5177, 11748), (0x5178, 10043), (0x5179, 16475), (0x517a, 520),
+    (0x517b, 15383), (0x517c, 11375), (0x517d, 14027), (0x517e, 521), (0x517f, 522), (0x5180, 11334), (0x5181, 16885), (0x5182, 16696),
+    (0x5183, 523), (0x5184, 524), (0x5185, 12883), (0x5186, 525), (0x5187, 526), (0x5188, 10597), (0x5189, 13614), (0x518a, 527),
+    (0x518b, 528), (0x518c, 9470), (0x518d, 15922), (0x518e, 529), (0x518f, 530), (0x5190, 531), (0x5191, 532), (0x5192, 12651),
+    (0x5193, 533), (0x5194, 534), (0x5195, 12700), (0x5196, 17007), (0x5197, 13648), (0x5198, 535), (0x5199, 15125), (0x519a, 536),
+    (0x519b, 11777), (0x519c, 13024), (0x519d, 537), (0x519e, 538), (0x519f, 539), (0x51a0, 10793), (0x51a1, 540), (0x51a2, 17008),
+    (0x51a3, 541), (0x51a4, 15874), (0x51a5, 17009), (0x51a6, 542), (0x51a7, 543), (0x51a8, 544), (0x51a9, 545), (0x51aa, 546),
+    (0x51ab, 16906), (0x51ac, 10177), (0x51ad, 547), (0x51ae, 548), (0x51af, 10430), (0x51b0, 9304), (0x51b1, 16907), (0x51b2, 9664),
+    (0x51b3, 11771), (0x51b4, 549), (0x51b5, 11961), (0x51b6, 15502), (0x51b7, 12133), (0x51b8, 550), (0x51b9, 551), (0x51ba, 552),
+    (0x51bb, 10184), (0x51bc, 16909), (0x51bd, 16908), (0x51be, 553), (0x51bf, 554), (0x51c0, 11712), (0x51c1, 555), (0x51c2, 556),
+    (0x51c3, 557), (0x51c4, 13269), (0x51c5, 558), (0x51c6, 16463), (0x51c7, 17006), (0x51c8, 559), (0x51c9, 12280), (0x51ca, 560),
+    (0x51cb, 10057), (0x51cc, 12327), (0x51cd, 561), (0x51ce, 562), (0x51cf, 11390), (0x51d0, 563), (0x51d1, 9838), (0x51d2, 564),
+    (0x51d3, 565), (0x51d4, 566), (0x51d5, 567), (0x51d6, 568), (0x51d7, 569), (0x51d8, 570), (0x51d9, 571), (0x51da, 572),
+    (0x51db, 12316), (0x51dc, 573), (0x51dd, 12918), (0x51de, 574), (0x51df, 575), (0x51e0, 11329), (0x51e1, 10373), (0x51e2, 576),
+    (0x51e3, 577), (0x51e4, 10434), (0x51e5, 578), (0x51e6, 579), (0x51e7, 580), (0x51e8, 581), (0x51e9, 582), (0x51ea, 583),
+    (0x51eb, 16891), (0x51ec, 584), (0x51ed, 13235), (0x51ee, 585), (0x51ef, 11888), (0x51f0, 11158), (0x51f1, 586), (0x51f2, 587),
+    (0x51f3, 10017), (0x51f4, 588), (0x51f5, 17236), (0x51f6, 15160), (0x51f7, 589), (0x51f8, 14560), (0x51f9, 9053), (0x51fa, 9681),
+    (0x51fb, 11202), (0x51fc, 17237), (0x51fd, 10940), (0x51fe, 590), (0x51ff, 15933), (0x5200, 9997), (0x5201, 10058), (0x5202, 16679),
+    (0x5203, 13633), (0x5204, 591), (0x5205, 592), (0x5206, 10409), (0x5207, 13443), (0x5208, 16680), (0x5209, 593), (0x520a, 11890),
+    (0x520b, 594), (0x520c, 595), (0x520d, 17222), (0x520e, 16681), (0x520f, 596), (0x5210, 597), (0x5211, 15149), (0x5212, 11129),
+    (0x5213, 598), (0x5214, 599), (0x5215, 600), (0x5216, 20265), (0x5217, 12303), (0x5218, 12340), (0x5219, 15947), (0x521a, 10598),
+    (0x521b, 9805), (0x521c, 601), (0x521d, 9680), (0x521e, 602), (0x521f, 603), (0x5220, 13805), (0x5221, 604), (0x5222, 605),
+    (0x5223, 606), (0x5224, 13063), (0x5225, 607), (0x5226, 608), (0x5227, 609), (0x5228, 13072), (0x5229, 12156), (0x522a, 610),
+    (0x522b, 9295), (0x522c, 611), (0x522d, 16682), (0x522e, 10781), (0x522f, 612), (0x5230, 10004), (0x5231, 613), (0x5232, 614),
+    (0x5233, 16683), (0x5234, 615), (0x5235, 616), (0x5236, 16283), (0x5237, 14157), (0x5238, 13600), (0x5239, 13793), (0x523a, 9829),
+    (0x523b, 11919), (0x523c, 617), (0x523d, 10819), (0x523e, 618), (0x523f, 16684), (0x5240, 16685), (0x5241, 10236), (0x5242, 11338),
+    (0x5243, 14419), (0x5244, 619), (0x5245, 620), (0x5246, 621), (0x5247, 622), (0x5248, 623), (0x5249, 624), (0x524a, 15002),
+    (0x524b, 625), (0x524c, 16686), (0x524d, 13411), (0x524e, 626), (0x524f, 627), (0x5250, 10783), (0x5251, 11498), (0x5252, 628),
+    (0x5253, 629), (0x5254, 14407), (0x5255, 630), (0x5256, 13247), (0x5257, 631), (0x5258, 632), (0x5259, 633), (0x525a, 634),
+    (0x525b, 635), (0x525c, 16689), (0x525d, 636), (0x525e, 16687), (0x525f, 637), (0x5260, 638), (0x5261, 16688), (0x5262, 639),
+    (0x5263, 640), (0x5264, 641), (0x5265, 9119), (0x5266, 642), (0x5267, 11756), (0x5268, 643), (0x5269, 13968), (0x526a, 11389),
+    (0x526b, 644), (0x526c, 645), (0x526d, 646), (0x526e, 647), (0x526f, 10562), (0x5270, 648), (0x5271, 649), (0x5272, 10623),
+    (0x5273, 650), (0x5274, 651), (0x5275, 652), (0x5276, 653), (0x5277, 654), (0x5278, 655), (0x5279, 656), (0x527a, 657),
+    (0x527b, 658), (0x527c, 659), (0x527d, 16691), (0x527e, 660), (0x527f, 11538), (0x5280, 661), (0x5281, 16693), (0x5282, 16692),
+    (0x5283, 662), (0x5284, 663), (0x5285, 664), (0x5286, 665), (0x5287, 666), (0x5288, 13107), (0x5289, 667), (0x528a, 668),
+    (0x528b, 669), (0x528c, 670), (0x528d, 671), (0x528e, 672), (0x528f, 673), (0x5290, 16694), (0x5291, 674), (0x5292, 675),
+    (0x5293, 16695), (0x5294, 676), (0x5295, 677), (0x5296, 678), (0x5297, 679), (0x5298, 680), (0x5299, 681), (0x529a, 682),
+    (0x529b, 12261), (0x529c, 683), (0x529d, 13601), (0x529e, 9101), (0x529f, 10741), (0x52a0, 11356), (0x52a1, 14806), (0x52a2, 17224),
+    (0x52a3, 12306), (0x52a4, 684), (0x52a5, 685), (0x52a6, 686), (0x52a7, 687), (0x52a8, 10180), (0x52a9, 16335), (0x52aa, 13027),
+    (0x52ab, 11552), (0x52ac, 17225), (0x52ad, 17226), (0x52ae, 688), (0x52af, 689), (0x52b0, 690), (0x52b1, 12153), (0x52b2, 11687),
+    (0x52b3, 12110), (0x52b4, 691), (0x52b5, 692), (0x52b6, 693), (0x52b7, 694), (0x52b8, 695), (0x52b9, 696), (0x52ba, 697),
+    (0x52bb, 698), (0x52bc, 699), (0x52bd, 700), (0x52be, 17227), (0x52bf, 14003), (0x52c0, 701), (0x52c1, 702), (0x52c2, 703),
+    (0x52c3, 9415), (0x52c4, 704), (0x52c5, 705), (0x52c6, 706), (0x52c7, 15709), (0x52c8, 707), (0x52c9, 12702), (0x52ca, 708),
+    (0x52cb, 15306), (0x52cc, 709), (0x52cd, 710), (0x52ce, 711), (0x52cf, 712), (0x52d0, 17229), (0x52d1, 713), (0x52d2, 12118),
+    (0x52d3, 714), (0x52d4, 715), (0x52d5, 716), (0x52d6, 17230), (0x52d7, 717), (0x52d8, 11892), (0x52d9, 718), (0x52da, 719),
+    (0x52db, 720), (0x52dc, 721), (0x52dd, 722), (0x52de, 723), (0x52df, 12853), (0x52e0, 724), (0x52e1, 725), (0x52e2, 726),
+    (0x52e3, 727), (0x52e4, 13453), (0x52e5, 728), (0x52e6, 729), (0x52e7, 730), (0x52e8, 731), (0x52e9, 732), (0x52ea, 733),
+    (0x52eb, 734), (0x52ec, 735), (0x52ed, 736), (0x52ee, 737), (0x52ef, 738), (0x52f0, 17231), (0x52f1, 739), (0x52f2, 740),
+    (0x52f3, 741), (0x52f4, 742), (0x52f5, 743), (0x52f6, 744), (0x52f7, 745), (0x52f8, 746), (0x52f9, 16887), (0x52fa, 13830),
+    (0x52fb, 747), (0x52fc, 748), (0x52fd, 749), (0x52fe, 10755), (0x52ff, 14805), (0x5300, 15905), (0x5301, 750), (0x5302, 751),
+    (0x5303, 752), (0x5304, 753), (0x5305, 9117), (0x5306, 9835), (0x5307, 754), (0x5308, 15162), (0x5309, 755), (0x530a, 756),
+    (0x530b, 757), (0x530c, 758), (0x530d, 16888), (0x530e, 759), (0x530f, 17808), (0x5310, 16890), (0x5311, 760), (0x5312, 761),
+    (0x5313, 762), (0x5314, 763), (0x5315, 16641), (0x5316, 11130), (0x5317, 9232), (0x5318, 764), (0x5319, 9650), (0x531a, 16671),
+    (0x531b, 765), (0x531c, 766), (0x531d, 15914), (0x531e, 767), (0x531f, 768), (0x5320, 11514), (0x5321, 11954), (0x5322, 769),
+    (0x5323, 14942), (0x5324, 770), (0x5325, 771), (0x5326, 16673), (0x5327, 772), (0x5328, 773), (0x5329, 774), (0x532a, 10398),
+    (0x532b, 775), (0x532c, 776), (0x532d, 777), (0x532e, 16674), (0x532f, 778), (0x5330, 779), (0x5331, 780), (0x5332, 781),
+    (0x5333, 782), (0x5334, 783), (0x5335, 784), (0x5336, 785), (0x5337, 786), (0x5338, 787), (0x5339, 13210), (0x533a, 13483),
+    (0x533b, 15514), (0x533c, 788), (0x533d, 789), (0x533e, 16675), (0x533f, 12893), (0x5340, 790), (0x5341, 13979), (0x5342, 791),
+    (0x5343, 13402), (0x5344, 792), (0x5345, 16631), (0x5346, 793), (0x5347, 13868), (0x5348, 14796), (0x5349, 11175), (0x534a, 9100),
+    (0x534b, 794), (0x534c, 795), (0x534d, 796), (0x534e, 11125), (0x534f, 15118), (0x5350, 797), (0x5351, 9231), (0x5352, 16503),
+    (0x5353, 16466), (0x5354, 798), (0x5355, 9980), (0x5356, 12529), (0x5357, 12872), (0x5358, 799), (0x5359, 800), (0x535a, 9414),
+    (0x535b, 801), (0x535c, 9428), (0x535d, 802), (0x535e, 9284), (0x535f, 17973), (0x5360, 16083), (0x5361, 11883), (0x5362, 12457),
+    (0x5363, 16678), (0x5364, 12462), (0x5365, 803), (0x5366, 16677), (0x5367, 14777), (0x5368, 804), (0x5369, 17069), (0x536a, 805),
+    (0x536b, 14757), (0x536c, 806), (0x536d, 807), (0x536e, 16645), (0x536f, 12649), (0x5370, 15676), (0x5371, 14728), (0x5372, 808),
+    (0x5373, 11325), (0x5374, 13605), (0x5375, 12494), (0x5376, 809), (0x5377, 11762), (0x5378, 15127), (0x5379, 810), (0x537a, 17070),
+    (0x537b, 811), (0x537c, 812), (0x537d, 813), (0x537e, 814), (0x537f, 13463), (0x5380, 815), (0x5381, 816), (0x5382, 9602),
+    (0x5383, 817), (0x5384, 10247), (0x5385, 14437), (0x5386, 12155), (0x5387, 818), (0x5388, 819), (0x5389, 12152), (0x538a, 820),
+    (0x538b, 15320), (0x538c, 15360), (0x538d, 16664), (0x538e, 821), (0x538f, 822), (0x5390, 823), (0x5391, 824), (0x5392, 825),
+    (0x5393, 826), (0x5394, 827), (0x5395, 9467), (0x5396, 828), (0x5397, 829), (0x5398, 12134), (0x5399, 830), (0x539a, 11006),
+    (0x539b, 831), (0x539c, 832), (0x539d, 16665), (0x539e, 833), (0x539f, 15878), (0x53a0, 834), (0x53a1, 835), (0x53a2, 14980),
+    (0x53a3, 16666), (0x53a4, 836), (0x53a5, 16667), (0x53a6, 14950), (0x53a7, 837), (0x53a8, 9683), (0x53a9, 11724), (0x53aa, 838),
+    (0x53ab, 839), (0x53ac, 840), (0x53ad, 841), (0x53ae, 16668), (0x53af, 842), (0x53b0, 843), (0x53b1, 844), (0x53b2, 845),
+    (0x53b3, 846), (0x53b4, 847), (0x53b5, 848), (0x53b6, 17239), (0x53b7, 849), (0x53b8, 850), (0x53b9, 851), (0x53ba, 852),
+    (0x53bb, 13590), (0x53bc, 853), (0x53bd, 854), (0x53be, 855), (0x53bf, 14971), (0x53c0, 856), (0x53c1, 13679), (0x53c2, 9451),
+    (0x53c3, 857), (0x53c4, 858), (0x53c5, 859), (0x53c6, 860), (0x53c7, 861), (0x53c8, 15729), (0x53c9, 9475), (0x53ca, 11321),
+    (0x53cb, 15724), (0x53cc, 14166), (0x53cd, 10375), (0x53ce, 862), (0x53cf, 863), (0x53d0, 864), (0x53d1, 10357), (0x53d2, 865),
+    (0x53d3, 866), (0x53d4, 14034), (0x53d5, 867), (0x53d6, 13586), (0x53d7, 14025), (0x53d8, 9283), (0x53d9, 15185), (0x53da, 868),
+    (0x53db, 13064), (0x53dc, 869), (0x53dd, 870), (0x53de, 871), (0x53df, 17232), (0x53e0, 10069), (0x53e1, 872), (0x53e2, 873),
+    (0x53e3, 11933), (0x53e4, 10772), (0x53e5, 11753), (0x53e6, 12332), (0x53e7, 874), (0x53e8, 17977), (0x53e9, 17976), (0x53ea, 16272),
+    (0x53eb, 11543), (0x53ec, 16112), (0x53ed, 9065), (0x53ee, 10168), (0x53ef, 11916), (0x53f0, 14353), (0x53f1, 17974), (0x53f2, 13988),
+    (0x53f3, 15725), (0x53f4, 875), (0x53f5, 16672), (0x53f6, 15507), (0x53f7, 10962), (0x53f8, 14185), (0x53f9, 14375), (0x53fa, 876),
+    (0x53fb, 17978), (0x53fc, 10055), (0x53fd, 17975), (0x53fe, 877), (0x53ff, 878), (0x5400, 879), (0x5401, 15760), (0x5402, 880),
+    (0x5403, 9647), (0x5404, 10632), (0x5405, 881), (0x5406, 17981), (0x5407, 882), (0x5408, 10972), (0x5409, 11315), (0x540a, 10060),
+    (0x540b, 883), (0x540c, 14547), (0x540d, 12726), (0x540e, 11008), (0x540f, 12149), (0x5410, 14569), (0x5411, 14997), (0x5412, 17979),
+    (0x5413, 14952), (0x5414, 884), (0x5415, 12477), (0x5416, 17980), (0x5417, 12525), (0x5418, 885), (0x5419, 886), (0x541a, 887),
+    (0x541b, 11778), (0x541c, 888), (0x541d, 12318), (0x541e, 14579), (0x541f, 15572), (0x5420, 10400), (0x5421, 17988), (0x5422, 889),
+    (0x5423, 17991), (0x5424, 890), (0x5425, 891), (0x5426, 10436), (0x5427, 9066), (0x5428, 10219), (0x5429, 10407), (0x542a, 892),
+    (0x542b, 10937), (0x542c, 14438), (0x542d, 11927), (0x542e, 14172), (0x542f, 13289), (0x5430, 893), (0x5431, 16253), (0x5432, 17992),
+    (0x5433, 894), (0x5434, 14791), (0x5435, 9614), (0x5436, 895), (0x5437, 896), (0x5438, 14817), (0x5439, 9806), (0x543a, 897),
+    (0x543b, 14764), (0x543c, 11005), (0x543d, 898), (0x543e, 14790), (0x543f, 899), (0x5440, 15324), (0x5441, 900), (0x5442, 901),
+    (0x5443, 17987), (0x5444, 902), (0x5445, 903), (0x5446, 9869), (0x5447, 904), (0x5448, 9637), (0x5449, 905), (0x544a, 10615),
+    (0x544b, 17982), (0x544c, 906), (0x544d, 907), (0x544e, 908), (0x544f, 909), (0x5450, 12862), (0x5451, 910), (0x5452, 17983),
+    (0x5453, 17984), (0x5454, 17985), (0x5455, 13042), (0x5456, 17986), (0x5457, 17989), (0x5458, 15882), (0x5459, 17990), (0x545a, 911),
+    (0x545b, 13421), (0x545c, 14781), (0x545d, 912), (0x545e, 913), (0x545f, 914), (0x5460, 915), (0x5461, 916), (0x5462, 12881),
+    (0x5463, 917), (0x5464, 17997), (0x5465, 918), (0x5466, 18002), (0x5467, 919), (0x5468, 16305), (0x5469, 920), (0x546a, 921),
+    (0x546b, 922), (0x546c, 923), (0x546d, 924), (0x546e, 925), (0x546f, 926), (0x5470, 927), (0x5471, 17996), (0x5472, 18013),
+    (0x5473, 14747), (0x5474, 928), (0x5475, 10964), (0x5476, 18001), (0x5477, 17995), (0x5478, 13077), (0x5479, 929), (0x547a, 930),
+    (0x547b, 13850), (0x547c, 11009), (0x547d, 12727), (0x547e, 931), (0x547f, 932), (0x5480, 11740), (0x5481, 933), (0x5482, 17993),
+    (0x5483, 934), (0x5484, 18000), (0x5485, 935), (0x5486, 13071), (0x5487, 936), (0x5488, 937), (0x5489, 938), (0x548a, 939),
+    (0x548b, 16061), (0x548c, 10970), (0x548d, 940), (0x548e, 11729), (0x548f, 15704), (0x5490, 10577), (0x5491, 941), (0x5492, 16313),
+    (0x5493, 942), (0x5494, 17994), (0x5495, 10765), (0x5496, 11882), (0x5497, 943), (0x5498, 944), (0x5499, 12347), (0x549a, 17998),
+    (0x549b, 17999), (0x549c, 945), (0x549d, 18003), (0x549e, 946), (0x549f, 947), (0x54a0, 948), (0x54a1, 949), (0x54a2, 950),
+    (0x54a3, 18014), (0x54a4, 18024), (0x54a5, 951), (0x54a6, 18010), (0x54a7, 18009), (0x54a8, 16476), (0x54a9, 18022), (0x54aa, 18023),
+    (0x54ab, 19170), (0x54ac, 15492), (0x54ad, 18005), (0x54ae, 952), (0x54af, 11884), (0x54b0, 953), (0x54b1, 15924), (0x54b2, 954),
+    (0x54b3, 11915), (0x54b4, 18007), (0x54b5, 955), (0x54b6, 956), (0x54b7, 957), (0x54b8, 14959), (0x54b9, 958), (0x54ba, 959),
+    (0x54bb, 18016), (0x54bc, 960), (0x54bd, 15337), (0x54be, 961), (0x54bf, 18017), (0x54c0, 9032), (0x54c1, 13228), (0x54c2, 18006),
+    (0x54c3, 962), (0x54c4, 10994), (0x54c5, 963), (0x54c6, 10228), (0x54c7, 14594), (0x54c8, 10829), (0x54c9, 15918), (0x54ca, 964),
+    (0x54cb, 965), (0x54cc, 18018), (0x54cd, 14991), (0x54ce, 9030), (0x54cf, 18026), (0x54d0, 18004), (0x54d1, 15333), (0x54d2, 18008),
+    (0x54d3, 18011), (0x54d4, 18012), (0x54d5, 18015), (0x54d6, 966), (0x54d7, 11124), (0x54d8, 967), (0x54d9, 18019), (0x54da, 18020),
+    (0x54db, 968), (0x54dc, 18021), (0x54dd, 18025), (0x54de, 18027), (0x54df, 15695), (0x54e0, 969), (0x54e1, 970), (0x54e2, 971),
+    (0x54e3, 972), (0x54e4, 973), (0x54e5, 10616), (0x54e6, 13037), (0x54e7, 18029), (0x54e8, 13833), (0x54e9, 12263), (0x54ea, 12861),
+    (0x54eb, 974), (0x54ec, 975), (0x54ed, 11937), (0x54ee, 15003), (0x54ef, 976), (0x54f0, 977), (0x54f1, 978), (0x54f2, 16115),
+    (0x54f3, 18033), (0x54f4, 979), (0x54f5, 980), (0x54f6, 981), (0x54f7, 982), (0x54f8, 983), (0x54f9, 984), (0x54fa, 9429),
+    (0x54fb, 985), (0x54fc, 10988), (0x54fd, 18031), (0x54fe, 986), (0x54ff, 17228), (0x5500, 987), (0x5501, 15363), (0x5502, 988),
+    (0x5503, 989), (0x5504, 990), (0x5505, 991), (0x5506, 14239), (0x5507, 9814), (0x5508, 992), (0x5509, 9031), (0x550a, 993),
+    (0x550b, 994), (0x550c, 995), (0x550d, 996), (0x550e, 997), (0x550f, 18036), (0x5510, 14383), (0x5511, 18037), (0x5512, 998),
+    (0x5513, 999), (0x5514, 18032), (0x5515, 1000), (0x5516, 1001), (0x5517, 1002), (0x5518, 1003), (0x5519, 1004), (0x551a, 1005),
+    (0x551b, 18028), (0x551c, 1006), (0x551d, 1007), (0x551e, 1008), (0x551f, 1009), (0x5520, 18030), (0x5521, 1010), (0x5522, 18034),
+    (0x5523, 18035), (0x5524, 11144), (0x5525, 1011), (0x5526, 1012), (0x5527, 18038), (0x5528, 1013), (0x5529, 1014), (0x552a, 18039),
+    (0x552b, 1015), (0x552c, 11118), (0x552d, 1016), (0x552e, 14024), (0x552f, 14733), (0x5530, 18152), (0x5531, 9605), (0x5532, 1017),
+    (0x5533, 18151), (0x5534, 1018), (0x5535, 1019), (0x5536, 1020), (0x5537, 18146), (0x5538, 1021), (0x5539, 1022), (0x553a, 1023),
+    (0x553b, 1024), (0x553c, 18049), (0x553d, 1025), (0x553e, 14592), (0x553f, 18047), (0x5540, 1026), (0x5541, 18045), (0x5542, 1027),
+    (0x5543, 11923), (0x5544, 16471), (0x5545, 1028), (0x5546, 13819), (0x5547, 1029), (0x5548, 1030), (0x5549, 18043), (0x554a, 9026),
+    (0x554b, 1031), (0x554c, 1032), (0x554d, 1033), (0x554e, 1034), (0x554f, 1035), (0x5550, 18048), (0x5551, 1036), (0x5552, 1037),
+    (0x5553, 1038), (0x5554, 1039), (0x5555, 18046), (0x5556, 18147), (0x5557, 1040), (0x5558, 1041), (0x5559, 1042), (0x555a, 1043),
+    (0x555b, 1044), (0x555c, 18153), (0x555d, 1045), (0x555e, 1046), (0x555f, 1047), (0x5560, 1048), (0x5561, 10395), (0x5562, 1049),
+    (0x5563, 1050), (0x5564, 13206), (0x5565, 13797), (0x5566, 12083), (0x5567, 18040), (0x5568, 1051), (0x5569, 1052), (0x556a, 13045),
+    (0x556b, 1053), (0x556c, 16661), (0x556d, 18044), (0x556e, 12911), (0x556f, 1054), (0x5570, 1055), (0x5571, 1056), (0x5572, 1057),
+    (0x5573, 1058), (0x5574, 1059), (0x5575, 18148), (0x5576, 18149), (0x5577, 18150), (0x5578, 15110), (0x5579, 1060), (0x557a, 1061),
+    (0x557b, 18165), (0x557c, 14413), (0x557d, 1062), (0x557e, 18162), (0x557f, 1063), (0x5580, 11881), (0x5581, 18160), (0x5582, 14750),
+    (0x5583, 18156), (0x5584, 13813), (0x5585, 1064), (0x5586, 1065), (0x5587, 12079), (0x5588, 18159), (0x5589, 11002), (0x558a, 10941),
+    (0x558b, 18154), (0x558c, 1066), (0x558d, 1067), (0x558e, 1068), (0x558f, 18041), (0x5590, 1069), (0x5591, 18164), (0x5592, 1070),
+    (0x5593, 1071), (0x5594, 18169), (0x5595, 1072), (0x5596, 1073), (0x5597, 1074), (0x5598, 9798), (0x5599, 18170), (0x559a, 1075),
+    (0x559b, 1076), (0x559c, 14933), (0x559d, 10965), (0x559e, 1077), (0x559f, 18161), (0x55a0, 1078), (0x55a1, 1079), (0x55a2, 1080),
+    (0x55a3, 1081), (0x55a4, 1082), (0x55a5, 1083), (0x55a6, 1084), (0x55a7, 15195), (0x55a8, 1085), (0x55a9, 1086), (0x55aa, 1087),
+    (0x55ab, 1088), (0x55ac, 1089), (0x55ad, 1090), (0x55ae, 1091), (0x55af, 1092), (0x55b0, 1093), (0x55b1, 18157), (0x55b2, 1094),
+    (0x55b3, 15956), (0x55b4, 1095), (0x55b5, 18042), (0x55b6, 1096), (0x55b7, 13086), (0x55b8, 1097), (0x55b9, 18158), (0x55ba, 1098),
+    (0x55bb, 15762), (0x55bc, 1099), (0x55bd, 18167), (0x55be, 18168), (0x55bf, 1100), (0x55c0, 1101), (0x55c1, 1102), (0x55c2, 1103),
+    (0x55c3, 1104), (0x55c4, 18181), (0x55c5, 15170), (0x55c6, 1105), (0x55c7, 1106), (0x55c8, 1107), (0x55c9, 18173), (0x55ca, 1108),
+    (0x55cb, 1109), (0x55cc, 18186), (0x55cd, 18187), (0x55ce, 1110), (0x55cf, 1111), (0x55d0, 1112), (0x55d1, 18175), (0x55d2, 18155),
+    (0x55d3, 13779), (0x55d4, 18178), (0x55d5, 1113), (0x55d6, 18163), (0x55d7, 1114), (0x55d8, 1115), (0x55d9, 1116), (0x55da, 1117),
+    (0x55db, 1118), (0x55dc, 14005), (0x55dd, 18180), (0x55de, 1119), (0x55df, 18166), (0x55e0, 1120), (0x55e1, 14768), (0x55e2, 1121),
+    (0x55e3, 14190), (0x55e4, 18190), (0x55e5, 18183), (0x55e6, 18179), (0x55e7, 1122), (0x55e8, 18188), (0x55e9, 1123), (0x55ea, 18171),
+    (0x55eb, 18176), (0x55ec, 18177), (0x55ed, 1124), (0x55ee, 1125), (0x55ef, 18182), (0x55f0, 1126), (0x55f1, 1127), (0x55f2, 18184),
+    (0x55f3, 18185), (0x55f4, 1128), (0x55f5, 18189), (0x55f6, 1129), (0x55f7, 18172), (0x55f8, 1130), (0x55f9, 1131), (0x55fa, 1132),
+    (0x55fb, 1133), (0x55fc, 1134), (0x55fd, 14207), (0x55fe, 18198), (0x55ff, 1135), (0x5600, 18199), (0x5601, 18195), (0x5602, 1136),
+    (0x5603, 1137), (0x5604, 1138), (0x5605, 1139), (0x5606, 1140), (0x5607, 1141), (0x5608, 18193), (0x5609, 11351), (0x560a, 1142),
+    (0x560b, 1143), (0x560c, 18194), (0x560d, 1144), (0x560e, 10579), (0x560f, 16662), (0x5610, 1145), (0x5611, 1146), (0x5612, 1147),
+    (0x5613, 1148), (0x5614, 1149), (0x5615, 1150), (0x5616, 1151), (0x5617, 1152), (0x5618, 15179), (0x5619, 1153), (0x561a, 1154),
+    (0x561b, 12524), (0x561c, 1155), (0x561d, 1156), (0x561e, 18192), (0x561f, 18174), (0x5620, 1157), (0x5621, 1158), (0x5622, 1159),
+    (0x5623, 18197), (0x5624, 18196), (0x5625, 1160), (0x5626, 1161), (0x5627, 18200), (0x5628, 1162), (0x5629, 1163), (0x562a, 1164),
+    (0x562b, 1165), (0x562c, 18205), (0x562d, 18201), (0x562e, 1166), (0x562f, 1167), (0x5630, 1168), (0x5631, 16331), (0x5632, 9611),
+    (0x5633, 1169), (0x5634, 16511), (0x5635, 1170), (0x5636, 14182), (0x5637, 1171), (0x5638, 1172), (0x5639, 18203), (0x563a, 1173),
+    (0x563b, 14816), (0x563c, 1174), (0x563d, 1175), (0x563e, 1176), (0x563f, 10982), (0x5640, 1177), (0x5641, 1178), (0x5642, 1179),
+    (0x5643, 1180), (0x5644, 1181), (0x5645, 1182), (0x5646, 1183), (0x5647, 1184), (0x5648, 1185), (0x5649, 1186), (0x564a, 1187),
+    (0x564b, 1188), (0x564c, 18210), (0x564d, 18206), (0x564e, 15498), (0x564f, 1189), (0x5650, 1190), (0x5651, 1191), (0x5652, 1192),
+    (0x5653, 1193), (0x5654, 18211), (0x5655, 1194), (0x5656, 1195), (0x5657, 18204), (0x5658, 18202), (0x5659, 18208), (0x565a, 1196),
+    (0x565b, 1197), (0x565c, 18209), (0x565d, 1198), (0x565e, 1199), (0x565f, 1200), (0x5660, 1201), (0x5661, 1202), (0x5662, 18207),
+    (0x5663, 1203), (0x5664, 18213), (0x5665, 1204), (0x5666, 1205), (0x5667, 1206), (0x5668, 13292), (0x5669, 16637), (0x566a, 15940),
+    (0x566b, 18215), (0x566c, 14006), (0x566d, 1207), (0x566e, 1208), (0x566f, 1209), (0x5670, 1210), (0x5671, 18214), (0x5672, 1211),
+    (0x5673, 1212), (0x5674, 1213), (0x5675, 1214), (0x5676, 10578), (0x5677, 1215), (0x5678, 1216), (0x5679, 1217), (0x567a, 1218),
+    (0x567b, 18216), (0x567c, 18217), (0x567d, 1219), (0x567e, 1220), (0x567f, 1221), (0x5680, 1222), (0x5681, 1223), (0x5682, 1224),
+    (0x5683, 1225), (0x5684, 1226), (0x5685, 18218), (0x5686, 18212), (0x5687, 1227), (0x5688, 1228), (0x5689, 1229), (0x568a, 1230),
+    (0x568b, 1231), (0x568c, 1232), (0x568d, 1233), (0x568e, 10956), (0x568f, 14416), (0x5690, 1234), (0x5691, 1235), (0x5692, 1236),
+    (0x5693, 18219), (0x5694, 1237), (0x5695, 1238), (0x5696, 1239), (0x5697, 1240), (0x5698, 1241), (0x5699, 1242), (0x569a, 1243),
+    (0x569b, 1244), (0x569c, 1245), (0x569d, 1246), (0x569e, 1247), (0x569f, 1248), (0x56a0, 1249), (0x56a1, 1250), (0x56a2, 1251),
+    (0x56a3, 15004), (0x56a4, 1252), (0x56a5, 1253), (0x56a6, 1254), (0x56a7, 1255), (0x56a8, 1256), (0x56a9, 1257), (0x56aa, 1258),
+    (0x56ab, 1259), (0x56ac, 1260), (0x56ad, 1261), (0x56ae, 1262), (0x56af, 18220), (0x56b0, 1263), (0x56b1, 1264), (0x56b2, 1265),
+    (0x56b3, 1266), (0x56b4, 1267), (0x56b5, 1268), (0x56b6, 1269), (0x56b7, 13619), (0x56b8, 1270), (0x56b9, 1271), (0x56ba, 1272),
+    (0x56bb, 1273), (0x56bc, 11527), (0x56bd, 1274), (0x56be, 1275), (0x56bf, 1276), (0x56c0, 1277), (0x56c1, 1278), (0x56c2, 1279),
+    (0x56c3, 1280), (0x56c4, 1281), (0x56c5, 1282), (0x56c6, 1283), (0x56c7, 1284), (0x56c8, 1285), (0x56c9, 1286), (0x56ca, 12875),
+    (0x56cb, 1287), (0x56cc, 1288), (0x56cd, 1289), (0x56ce, 1290), (0x56cf, 1291), (0x56d0, 1292), (0x56d1, 1293), (0x56d2, 1294),
+    (0x56d3, 1295), (0x56d4, 18221), (0x56d5, 1296), (0x56d6, 1297), (0x56d7, 18222), (0x56d8, 1298), (0x56d9, 1299), (0x56da, 13479),
+    (0x56db, 14191), (0x56dc, 1300), (0x56dd, 18223), (0x56de, 11171), (0x56df, 16647), (0x56e0, 15567), (0x56e1, 18224), (0x56e2, 14572),
+    (0x56e3, 1301), (0x56e4, 10223), (0x56e5, 1302), (0x56e6, 1303), (0x56e7, 1304), (0x56e8, 1305), (0x56e9, 1306), (0x56ea, 1307),
+    (0x56eb, 18226), (0x56ec, 1308), (0x56ed, 15881), (0x56ee, 1309), (0x56ef, 1310), (0x56f0, 12072), (0x56f1, 9834), (0x56f2, 1311),
+    (0x56f3, 1312), (0x56f4, 14732), (0x56f5, 18225), (0x56f6, 1313), (0x56f7, 1314), (0x56f8, 1315), (0x56f9, 18227), (0x56fa, 10779),
+    (0x56fb, 1316), (0x56fc, 1317), (0x56fd, 10825), (0x56fe, 14563), (0x56ff, 18228), (0x5700, 1318), (0x5701, 1319), (0x5702, 1320),
+    (0x5703, 13257), (0x5704, 18229), (0x5705, 1321), (0x5706, 15883), (0x5707, 1322), (0x5708, 13591), (0x5709, 18231), (0x570a, 18230),
+    (0x570b, 1323), (0x570c, 1324), (0x570d, 1325), (0x570e, 1326), (0x570f, 1327), (0x5710, 1328), (0x5711, 1329), (0x5712, 1330),
+    (0x5713, 1331), (0x5714, 1332), (0x5715, 1333), (0x5716, 1334), (0x5717, 1335), (0x5718, 1336), (0x5719, 1337), (0x571a, 1338),
+    (0x571b, 1339), (0x571c, 18232), (0x571d, 1340), (0x571e, 1341), (0x571f, 14568), (0x5720, 1342), (0x5721, 1343), (0x5722, 1344),
+    (0x5723, 13970), (0x5724, 1345), (0x5725, 1346), (0x5726, 1347), (0x5727, 1348), (0x5728, 15923), (0x5729, 17250), (0x572a, 17252),
+    (0x572b, 1349), (0x572c, 17251), (0x572d, 10806), (0x572e, 17255), (0x572f, 17256), (0x5730, 10031), (0x5731, 1350), (0x5732, 1351),
+    (0x5733, 17253), (0x5734, 1352), (0x5735, 1353), (0x5736, 1354), (0x5737, 1355), (0x5738, 1356), (0x5739, 17254), (0x573a, 9596),
+    (0x573b, 17258), (0x573c, 1357), (0x573d, 1358), (0x573e, 11203), (0x573f, 1359), (0x5740, 16268), (0x5741, 1360), (0x5742, 17259),
+    (0x5743, 1361), (0x5744, 1362), (0x5745, 1363), (0x5746, 1364), (0x5747, 11774), (0x5748, 1365), (0x5749, 1366), (0x574a, 10382),
+    (0x574b, 1367), (0x574c, 17243), (0x574d, 14359), (0x574e, 11893), (0x574f, 11136), (0x5750, 16523), (0x5751, 11926), (0x5752, 1368),
+    (0x5753, 1369), (0x5754, 1370), (0x5755, 1371), (0x5756, 1372), (0x5757, 11948), (0x5758, 1373), (0x5759, 1374), (0x575a, 11370),
+    (0x575b, 14364), (0x575c, 17257), (0x575d, 9076), (0x575e, 14800), (0x575f, 10411), (0x5760, 16460), (0x5761, 13239), (0x5762, 1375),
+    (0x5763, 1376), (0x5764, 12069), (0x5765, 1377), (0x5766, 14370), (0x5767, 1378), (0x5768, 17266), (0x5769, 17260), (0x576a, 13231),
+    (0x576b, 17262), (0x576c, 1379), (0x576d, 17267), (0x576e, 1380), (0x576f, 13102), (0x5770, 1381), (0x5771, 1382), (0x5772, 1383),
+    (0x5773, 17269), (0x5774, 1384), (0x5775, 1385), (0x5776, 17268), (0x5777, 11907), (0x5778, 1386), (0x5779, 1387), (0x577a, 1388),
+    (0x577b, 17265), (0x577c, 17264), (0x577d, 1389), (0x577e, 1390), (0x577f, 1391), (0x5780, 1392), (0x5781, 1393), (0x5782, 9810),
+    (0x5783, 12077), (0x5784, 12447), (0x5785, 17261), (0x5786, 17263), (0x5787, 1394), (0x5788, 1395), (0x5789, 1396), (0x578a, 1397),
+    (0x578b, 15150), (0x578c, 17272), (0x578d, 1398), (0x578e, 1399), (0x578f, 1400), (0x5790, 1401), (0x5791, 1402), (0x5792, 12126),
+    (0x5793, 17277), (0x5794, 1403), (0x5795, 1404), (0x5796, 1405), (0x5797, 1406), (0x5798, 1407), (0x5799, 1408), (0x579a, 1409),
+    (0x579b, 10231), (0x579c, 1410), (0x579d, 1411), (0x579e, 1412), (0x579f, 1413), (0x57a0, 17278), (0x57a1, 17245), (0x57a2, 10759),
+    (0x57a3, 15876), (0x57a4, 17271), (0x57a5, 1414), (0x57a6, 11924), (0x57a7, 17275), (0x57a8, 1415), (0x57a9, 17244), (0x57aa, 1416),
+    (0x57ab, 10045), (0x57ac, 1417), (0x57ad, 17270), (0x57ae, 11944), (0x57af, 1418), (0x57b0, 1419), (0x57b1, 1420), (0x57b2, 17273),
+    (0x57b3, 1421), (0x57b4, 17276), (0x57b5, 1422), (0x57b6, 1423), (0x57b7, 1424), (0x57b8, 17284), (0x57b9, 1425), (0x57ba, 1426),
+    (0x57bb, 1427), (0x57bc, 1428), (0x57bd, 1429), (0x57be, 1430), (0x57bf, 1431), (0x57c0, 1432), (0x57c1, 1433), (0x57c2, 10736),
+    (0x57c3, 9028), (0x57c4, 1434), (0x57c5, 1435), (0x57c6, 1436), (0x57c7, 1437), (0x57c8, 1438), (0x57c9, 1439), (0x57ca, 1440),
+    (0x57cb, 12526), (0x57cc, 1441), (0x57cd, 1442), (0x57ce, 9634), (0x57cf, 17274), (0x57d0, 1443), (0x57d1, 1444), (0x57d2, 17283),
+    (0x57d3, 1445), (0x57d4, 13255), (0x57d5, 17279), (0x57d6, 1446), (0x57d7, 1447), (0x57d8, 17280), (0x57d9, 17282), (0x57da, 17281),
+    (0x57db, 1448), (0x57dc, 1449), (0x57dd, 17289), (0x57de, 1450), (0x57df, 15757), (0x57e0, 9431), (0x57e1, 1451), (0x57e2, 1452),
+    (0x57e3, 1453), (0x57e4, 17288), (0x57e5, 1454), (0x57e6, 1455), (0x57e7, 1456), (0x57e8, 1457), (0x57e9, 1458), (0x57ea, 1459),
+    (0x57eb, 1460), (0x57ec, 1461), (0x57ed, 17389), (0x57ee, 1462), (0x57ef, 17286), (0x57f0, 1463), (0x57f1, 1464), (0x57f2, 1465),
+    (0x57f3, 1466), (0x57f4, 17285), (0x57f5, 1467), (0x57f6, 1468), (0x57f7, 1469), (0x57f8, 17287), (0x57f9, 13079), (0x57fa, 11204),
+    (0x57fb, 1470), (0x57fc, 1471), (0x57fd, 17388), (0x57fe, 1472), (0x57ff, 1473), (0x5800, 17390), (0x5801, 1474), (0x5802, 14380),
+    (0x5803, 1475), (0x5804, 1476), (0x5805, 1477), (0x5806, 10214), (0x5807, 17607), (0x5808, 1478), (0x5809, 1479), (0x580a, 1480),
+    (0x580b, 17386), (0x580c, 1481), (0x580d, 17387), (0x580e, 1482), (0x580f, 1483), (0x5810, 1484), (0x5811, 13416), (0x5812, 1485),
+    (0x5813, 1486), (0x5814, 1487), (0x5815, 10238), (0x5816, 1488), (0x5817, 1489), (0x5818, 1490), (0x5819, 17392), (0x581a, 1491),
+    (0x581b, 1492), (0x581c, 1493), (0x581d, 1494), (0x581e, 17391), (0x581f, 1495), (0x5820, 17394), (0x5821, 9219), (0x5822, 1496),
+    (0x5823, 1497), (0x5824, 10019), (0x5825, 1498), (0x5826, 1499), (0x5827, 1500), (0x5828, 1501), (0x5829, 1502), (0x582a, 11891),
+    (0x582b, 1503), (0x582c, 1504), (0x582d, 1505), (0x582e, 1506), (0x582f, 1507), (0x5830, 15358), (0x5831, 1508), (0x5832, 1509),
+    (0x5833, 1510), (0x5834, 1511), (0x5835, 10199), (0x5836, 1512), (0x5837, 1513), (0x5838, 1514), (0x5839, 1515), (0x583a, 1516),
+    (0x583b, 1517), (0x583c, 1518), (0x583d, 1519), (0x583e, 1520), (0x583f, 1521), (0x5840, 1522), (0x5841, 1523), (0x5842, 1524),
+    (0x5843, 1525), (0x5844, 17393), (0x5845, 1526), (0x5846, 1527), (0x5847, 1528), (0x5848, 1529), (0x5849, 1530), (0x584a, 1531),
+    (0x584b, 1532), (0x584c, 14245), (0x584d, 20318), (0x584e, 1533), (0x584f, 1534), (0x5850, 1535), (0x5851, 14215), (0x5852, 1536),
+    (0x5853, 1537), (0x5854, 14249), (0x5855, 1538), (0x5856, 1539), (0x5857, 1540), (0x5858, 14378), (0x5859, 1541), (0x585a, 1542),
+    (0x585b, 1543), (0x585c, 1544), (0x585d, 1545), (0x585e, 13676), (0x585f, 1546), (0x5860, 1547), (0x5861, 1548), (0x5862, 1549),
+    (0x5863, 1550), (0x5864, 1551), (0x5865, 17395), (0x5866, 1552), (0x5867, 1553), (0x5868, 1554), (0x5869, 1555), (0x586a, 1556),
+    (0x586b, 14423), (0x586c, 17396), (0x586d, 1557), (0x586e, 1558), (0x586f, 1559), (0x5870, 1560), (0x5871, 1561), (0x5872, 1562),
+    (0x5873, 1563), (0x5874, 1564), (0x5875, 1565), (0x5876, 1566), (0x5877, 1567), (0x5878, 1568), (0x5879, 1569), (0x587a, 1570),
+    (0x587b, 1571), (0x587c, 1572), (0x587d, 1573), (0x587e, 17246), (0x587f, 1574), (0x5880, 17400), (0x5881, 17397), (0x5882, 1575),
+    (0x5883, 11704), (0x5884, 1576), (0x5885, 14056), (0x5886, 1577), (0x5887, 1578), (0x5888, 1579), (0x5889, 17398), (0x588a, 1580),
+    (0x588b, 1581), (0x588c, 1582), (0x588d, 1583), (0x588e, 1584), (0x588f, 1585), (0x5890, 1586), (0x5891, 1587), (0x5892, 13817),
+    (0x5893, 12850), (0x5894, 1588), (0x5895, 1589), (0x5896, 1590), (0x5897, 1591), (0x5898, 1592), (0x5899, 13424), (0x589a, 17399),
+    (0x589b, 1593), (0x589c, 1594), (0x589d, 1595), (0x589e, 15951), (0x589f, 15175), (0x58a0, 1596), (0x58a1, 1597), (0x58a2, 1598),
+    (0x58a3, 1599), (0x58a4, 1600), (0x58a5, 1601), (0x58a6, 1602), (0x58a7, 1603), (0x58a8, 12836), (0x58a9, 10218), (0x58aa, 1604),
+    (0x58ab, 1605), (0x58ac, 1606), (0x58ad, 1607), (0x58ae, 1608), (0x58af, 1609), (0x58b0, 1610), (0x58b1, 1611), (0x58b2, 1612),
+    (0x58b3, 1613), (0x58b4, 1614), (0x58b5, 1615), (0x58b6, 1616), (0x58b7, 1617), (0x58b8, 1618), (0x58b9, 1619), (0x58ba, 1620),
+    (0x58bb, 1621), (0x58bc, 17247), (0x58bd, 1622), (0x58be, 1623), (0x58bf, 1624), (0x58c0, 1625), (0x58c1, 9273), (0x58c2, 1626),
+    (0x58c3, 1627), (0x58c4, 1628), (0x58c5, 17248), (0x58c6, 1629), (0x58c7, 1630), (0x58c8, 1631), (0x58c9, 1632), (0x58ca, 1633),
+    (0x58cb, 1634), (0x58cc, 1635), (0x58cd, 1636), (0x58ce, 1637), (0x58cf, 1638), (0x58d0, 1639), (0x58d1, 17249), (0x58d2, 1640),
+    (0x58d3, 1641), (0x58d4, 1642), (0x58d5, 10955), (0x58d6, 1643), (0x58d7, 1644), (0x58d8, 1645), (0x58d9, 1646), (0x58da, 1647),
+    (0x58db, 1648), (0x58dc, 1649), (0x58dd, 1650), (0x58de, 1651), (0x58df, 1652), (0x58e0, 1653), (0x58e1, 1654), (0x58e2, 1655),
+    (0x58e3, 1656), (0x58e4, 13617), (0x58e5, 1657), (0x58e6, 1658), (0x58e7, 1659), (0x58e8, 1660), (0x58e9, 1661), (0x58ea, 1662),
+    (0x58eb, 13996), (0x58ec, 13626), (0x58ed, 1663), (0x58ee, 16454), (0x58ef, 1664), (0x58f0, 13864), (0x58f1, 1665), (0x58f2, 1666),
+    (0x58f3, 11914), (0x58f4, 1667), (0x58f5, 1668), (0x58f6, 11013), (0x58f7, 1669), (0x58f8, 1670), (0x58f9, 15513), (0x58fa, 1671),
+    (0x58fb, 1672), (0x58fc, 1673), (0x58fd, 1674), (0x58fe, 1675), (0x58ff, 1676), (0x5900, 1677), (0x5901, 1678), (0x5902, 18551),
+    (0x5903, 1679), (0x5904, 9791), (0x5905, 1680), (0x5906, 1681), (0x5907, 9239), (0x5908, 1682), (0x5909, 1683), (0x590a, 1684),
+    (0x590b, 1685), (0x590c, 1686), (0x590d, 10565), (0x590e, 1687), (0x590f, 14951), (0x5910, 1688), (0x5911, 1689), (0x5912, 1690),
+    (0x5913, 1691), (0x5914, 16886), (0x5915, 14921), (0x5916, 14601), (0x5917, 1692), (0x5918, 1693), (0x5919, 16892), (0x591a, 10229),
+    (0x591b, 1694), (0x591c, 15510), (0x591d, 1695), (0x591e, 1696), (0x591f, 10762), (0x5920, 1697), (0x5921, 1698), (0x5922, 1699),
+    (0x5923, 1700), (0x5924, 18550), (0x5925, 18548), (0x5926, 1701), (0x5927, 9868), (0x5928, 1702), (0x5929, 14421), (0x592a, 14356),
+    (0x592b, 10437), (0x592c, 1703), (0x592d, 16643), (0x592e, 15370), (0x592f, 10952), (0x5930, 1704), (0x5931, 13972), (0x5932, 1705),
+    (0x5933, 1706), (0x5934, 14558), (0x5935, 1707), (0x5936, 1708), (0x5937, 15521), (0x5938, 11943), (0x5939, 11353), (0x593a, 10230),
+    (0x593b, 1709), (0x593c, 17802), (0x593d, 1710), (0x593e, 1711), (0x593f, 1712), (0x5940, 1713), (0x5941, 17803), (0x5942, 17223),
+    (0x5943, 1714), (0x5944, 15352), (0x5945, 1715), (0x5946, 1716), (0x5947, 13275), (0x5948, 12871), (0x5949, 10433), (0x594a, 1717),
+    (0x594b, 10415), (0x594c, 1718), (0x594d, 1719), (0x594e, 11967), (0x594f, 16499), (0x5950, 1720), (0x5951, 13290), (0x5952, 1721),
+    (0x5953, 1722), (0x5954, 9243), (0x5955, 17805), (0x5956, 11512), (0x5957, 14400), (0x5958, 17807), (0x5959, 1723), (0x595a, 17806),
+    (0x595b, 1724), (0x595c, 1725), (0x595d, 1726), (0x595e, 1727), (0x595f, 1728), (0x5960, 10051), (0x5961, 1729), (0x5962, 13836),
+    (0x5963, 1730), (0x5964, 1731), (0x5965, 9059), (0x5966, 1732), (0x5967, 1733), (0x5968, 1734), (0x5969, 1735), (0x596a, 1736),
+    (0x596b, 1737), (0x596c, 1738), (0x596d, 1739), (0x596e, 1740), (0x596f, 1741), (0x5970, 1742), (0x5971, 1743), (0x5972, 1744),
+    (0x5973, 13029), (0x5974, 13026), (0x5975, 1745), (0x5976, 12869), (0x5977, 1746), (0x5978, 11378), (0x5979, 14248), (0x597a, 1747),
+    (0x597b, 1748), (0x597c, 1749), (0x597d, 10960), (0x597e, 1750), (0x597f, 1751), (0x5980, 1752), (0x5981, 19184), (0x5982, 13656),
+    (0x5983, 19185), (0x5984, 14628), (0x5985, 1753), (0x5986, 16452), (0x5987, 10575), (0x5988, 12517), (0x5989, 1754), (0x598a, 13634),
+    (0x598b, 1755), (0x598c, 1756), (0x598d, 19186), (0x598e, 1757), (0x598f, 1758), (0x5990, 1759), (0x5991, 1760), (0x5992, 10207),
+    (0x5993, 11348), (0x5994, 1761), (0x5995, 1762), (0x5996, 15388), (0x5997, 19286), (0x5998, 1763), (0x5999, 12713), (0x599a, 1764),
+    (0x599b, 1765), (0x599c, 1766), (0x599d, 1767), (0x599e, 19289), (0x599f, 1768), (0x59a0, 1769), (0x59a1, 1770), (0x59a2, 1771),
+    (0x59a3, 19189), (0x59a4, 19290), (0x59a5, 14590), (0x59a6, 1772), (0x59a7, 1773), (0x59a8, 10388), (0x59a9, 19187), (0x59aa, 19188),
+    (0x59ab, 19288), (0x59ac, 1774), (0x59ad, 1775), (0x59ae, 12886), (0x59af, 19293), (0x59b0, 1776), (0x59b1, 1777), (0x59b2, 19292),
+    (0x59b3, 1778), (0x59b4, 1779), (0x59b5, 1780), (0x59b6, 1781), (0x59b7, 1782), (0x59b8, 1783), (0x59b9, 12670), (0x59ba, 1784),
+    (0x59bb, 13267), (0x59bc, 1785), (0x59bd, 1786), (0x59be, 19295), (0x59bf, 1787), (0x59c0, 1788), (0x59c1, 1789), (0x59c2, 1790),
+    (0x59c3, 1791), (0x59c4, 1792), (0x59c5, 1793), (0x59c6, 12848), (0x59c7, 1794), (0x59c8, 1795), (0x59c9, 1796), (0x59ca, 19287),
+    (0x59cb, 13993), (0x59cc, 1797), (0x59cd, 1798), (0x59ce, 1799), (0x59cf, 1800), (0x59d0, 11562), (0x59d1, 10770), (0x59d2, 19291),
+    (0x59d3, 15158), (0x59d4, 14740), (0x59d5, 1801), (0x59d6, 1802), (0x59d7, 19294), (0x59d8, 19301), (0x59d9, 1803), (0x59da, 15491),
+    (0x59db, 1804), (0x59dc, 11505), (0x59dd, 19298), (0x59de, 1805), (0x59df, 1806), (0x59e0, 1807), (0x59e1, 1808), (0x59e2, 1809),
+    (0x59e3, 19300), (0x59e4, 1810), (0x59e5, 12114), (0x59e6, 1811), (0x59e7, 1812), (0x59e8, 15529), (0x59e9, 1813), (0x59ea, 1814),
+    (0x59eb, 1815), (0x59ec, 11312), (0x59ed, 1816), (0x59ee, 1817), (0x59ef, 1818), (0x59f0, 1819), (0x59f1, 1820), (0x59f2, 1821),
+    (0x59f3, 1822), (0x59f4, 1823), (0x59f5, 1824), (0x59f6, 1825), (0x59f7, 1826), (0x59f8, 1827), (0x59f9, 19302), (0x59fa, 1828),
+    (0x59fb, 15571), (0x59fc, 1829), (0x59fd, 1830), (0x59fe, 1831), (0x59ff, 16478), (0x5a00, 1832), (0x5a01, 14629), (0x5a02, 1833),
+    (0x5a03, 14597), (0x5a04, 12451), (0x5a05, 19296), (0x5a06, 19297), (0x5a07, 11526), (0x5a08, 19299), (0x5a09, 19304), (0x5a0a, 1834),
+    (0x5a0b, 1835), (0x5a0c, 19303), (0x5a0d, 1836), (0x5a0e, 1837), (0x5a0f, 1838), (0x5a10, 1839), (0x5a11, 19307), (0x5a12, 1840),
+    (0x5a13, 19309), (0x5a14, 1841), (0x5a15, 1842), (0x5a16, 1843), (0x5a17, 1844), (0x5a18, 12904), (0x5a19, 1845), (0x5a1a, 1846),
+    (0x5a1b, 1847), (0x5a1c, 12865), (0x5a1d, 1848), (0x5a1e, 1849), (0x5a1f, 11759), (0x5a20, 13854), (0x5a21, 1850), (0x5a22, 1851),
+    (0x5a23, 19308), (0x5a24, 1852), (0x5a25, 10245), (0x5a26, 1853), (0x5a27, 1854), (0x5a28, 1855), (0x5a29, 12703), (0x5a2a, 1856),
+    (0x5a2b, 1857), (0x5a2c, 1858), (0x5a2d, 1859), (0x5a2e, 1860), (0x5a2f, 1861), (0x5a30, 1862), (0x5a31, 15748), (0x5a32, 19305),
+    (0x5a33, 1863), (0x5a34, 19306), (0x5a35, 1864), (0x5a36, 13587), (0x5a37, 1865), (0x5a38, 1866), (0x5a39, 1867), (0x5a3a, 1868),
+    (0x5a3b, 1869), (0x5a3c, 19314), (0x5a3d, 1870), (0x5a3e, 1871), (0x5a3f, 1872), (0x5a40, 19310), (0x5a41, 1873), (0x5a42, 1874),
+    (0x5a43, 1875), (0x5a44, 1876), (0x5a45, 1877), (0x5a46, 13242), (0x5a47, 1878), (0x5a48, 1879), (0x5a49, 14616), (0x5a4a, 19312),
+    (0x5a4b, 1880), (0x5a4c, 1881), (0x5a4d, 1882), (0x5a4e, 1883), (0x5a4f, 1884), (0x5a50, 1885), (0x5a51, 1886), (0x5a52, 1887),
+    (0x5a53, 1888), (0x5a54, 1889), (0x5a55, 19313), (0x5a56, 1890), (0x5a57, 1891), (0x5a58, 1892), (0x5a59, 1893), (0x5a5a, 11188),
+    (0x5a5b, 1894), (0x5a5c, 1895), (0x5a5d, 1896), (0x5a5e, 1897), (0x5a5f, 1898), (0x5a60, 1899), (0x5a61, 1900), (0x5a62, 19315),
+    (0x5a63, 1901), (0x5a64, 1902), (0x5a65, 1903), (0x5a66, 1904), (0x5a67, 19311), (0x5a68, 1905), (0x5a69, 1906), (0x5a6a, 12088),
+    (0x5a6b, 1907), (0x5a6c, 1908), (0x5a6d, 1909), (0x5a6e, 1910), (0x5a6f, 1911), (0x5a70, 1912), (0x5a71, 1913), (0x5a72, 1914),
+    (0x5a73, 1915), (0x5a74, 15679), (0x5a75, 19316), (0x5a76, 13859), (0x5a77, 19320), (0x5a78, 1916), (0x5a79, 1917), (0x5a7a, 19321),
+    (0x5a7b, 1918), (0x5a7c, 1919), (0x5a7d, 1920), (0x5a7e, 1921), (0x5a7f, 15191), (0x5a80, 1922), (0x5a81, 1923), (0x5a82, 1924),
+    (0x5a83, 1925), (0x5a84, 1926), (0x5a85, 1927), (0x5a86, 1928), (0x5a87, 1929), (0x5a88, 1930), (0x5a89, 1931), (0x5a8a, 1932),
+    (0x5a8b, 1933), (0x5a8c, 1934), (0x5a8d, 1935), (0x5a8e, 1936), (0x5a8f, 1937), (0x5a90, 1938), (0x5a91, 1939), (0x5a92, 12664),
+    (0x5a93, 1940), (0x5a94, 1941), (0x5a95, 1942), (0x5a96, 1943), (0x5a97, 1944), (0x5a98, 1945), (0x5a99, 1946), (0x5a9a, 12671),
+    (0x5a9b, 19319), (0x5a9c, 1947), (0x5a9d, 1948), (0x5a9e, 1949), (0x5a9f, 1950), (0x5aa0, 1951), (0x5aa1, 1952), (0x5aa2, 1953),
+    (0x5aa3, 1954), (0x5aa4, 1955), (0x5aa5, 1956), (0x5aa6, 1957), (0x5aa7, 1958), (0x5aa8, 1959), (0x5aa9, 1960), (0x5aaa, 19318),
+    (0x5aab, 1961), (0x5aac, 1962), (0x5aad, 1963), (0x5aae, 1964), (0x5aaf, 1965), (0x5ab0, 1966), (0x5ab1, 1967), (0x5ab2, 19324),
+    (0x5ab3, 14932), (0x5ab4, 1968), (0x5ab5, 20319), (0x5ab6, 1969), (0x5ab7, 1970), (0x5ab8, 19327), (0x5ab9, 1971), (0x5aba, 1972),
+    (0x5abb, 1973), (0x5abc, 1974), (0x5abd, 1975), (0x5abe, 19322), (0x5abf, 1976), (0x5ac0, 1977), (0x5ac1, 11367), (0x5ac2, 13784),
+    (0x5ac3, 1978), (0x5ac4, 1979), (0x5ac5, 1980), (0x5ac6, 1981), (0x5ac7, 1982), (0x5ac8, 1983), (0x5ac9, 11326), (0x5aca, 1984),
+    (0x5acb, 1985), (0x5acc, 14966), (0x5acd, 1986), (0x5ace, 1987), (0x5acf, 1988), (0x5ad0, 1989), (0x5ad1, 1990), (0x5ad2, 19325),
+    (0x5ad3, 1991), (0x5ad4, 19326), (0x5ad5, 1992), (0x5ad6, 19331), (0x5ad7, 1993), (0x5ad8, 19333), (0x5ad9, 1994), (0x5ada, 1995),
+    (0x5adb, 1996), (0x5adc, 19334), (0x5add, 1997), (0x5ade, 1998), (0x5adf, 1999), (0x5ae0, 19328), (0x5ae1, 10028), (0x5ae2, 2000),
+    (0x5ae3, 19329), (0x5ae4, 2001), (0x5ae5, 2002), (0x5ae6, 19332), (0x5ae7, 2003), (0x5ae8, 2004), (0x5ae9, 12884), (0x5aea, 2005),
+    (0x5aeb, 19323), (0x5aec, 2006), (0x5aed, 2007), (0x5aee, 2008), (0x5aef, 2009), (0x5af0, 2010), (0x5af1, 19330), (0x5af2, 2011),
+    (0x5af3, 2012), (0x5af4, 2013), (0x5af5, 2014), (0x5af6, 2015), (0x5af7, 2016), (0x5af8, 2017), (0x5af9, 2018), (0x5afa, 2019),
+    (0x5afb, 2020), (0x5afc, 2021), (0x5afd, 2022), (0x5afe, 2023), (0x5aff, 2024), (0x5b00, 2025), (0x5b01, 2026), (0x5b02, 2027),
+    (0x5b03, 2028), (0x5b04, 2029), (0x5b05, 2030), (0x5b06, 2031), (0x5b07, 2032), (0x5b08, 2033), (0x5b09, 19335), (0x5b0a, 2034),
+    (0x5b0b, 2035), (0x5b0c, 2036), (0x5b0d, 2037), (0x5b0e, 2038), (0x5b0f, 2039), (0x5b10, 2040), (0x5b11, 2041), (0x5b12, 2042),
+    (0x5b13, 2043), (0x5b14, 2044), (0x5b15, 2045), (0x5b16, 19337), (0x5b17, 19336), (0x5b18, 2046), (0x5b19, 2047), (0x5b1a, 2048),
+    (0x5b1b, 2049), (0x5b1c, 2050), (0x5b1d, 2051), (0x5b1e, 2052), (0x5b1f, 2053), (0x5b20, 2054), (0x5b21, 2055), (0x5b22, 2056),
+    (0x5b23, 2057), (0x5b24, 2058), (0x5b25, 2059), (0x5b26, 2060), (0x5b27, 2061), (0x5b28, 2062), (0x5b29, 2063), (0x5b2a, 2064),
+    (0x5b2b, 2065), (0x5b2c, 2066), (0x5b2d, 2067), (0x5b2e, 2068), (0x5b2f, 2069), (0x5b30, 2070), (0x5b31, 2071), (0x5b32, 19338),
+    (0x5b33, 2072), (0x5b34, 16903), (0x5b35, 2073), (0x5b36, 2074), (0x5b37, 19339), (0x5b38, 2075), (0x5b39, 2076), (0x5b3a, 2077),
+    (0x5b3b, 2078), (0x5b3c, 2079), (0x5b3d, 2080), (0x5b3e, 2081), (0x5b3f, 2082), (0x5b40, 19340), (0x5b41, 2083), (0x5b42, 2084),
+    (0x5b43, 2085), (0x5b44, 2086), (0x5b45, 2087), (0x5b46, 2088), (0x5b47, 2089), (0x5b48, 2090), (0x5b49, 2091), (0x5b4a, 2092),
+    (0x5b4b, 2093), (0x5b4c, 2094), (0x5b4d, 2095), (0x5b4e, 2096), (0x5b4f, 2097), (0x5b50, 16486), (0x5b51, 19346), (0x5b52, 2098),
+    (0x5b53, 19347), (0x5b54, 11930), (0x5b55, 15913), (0x5b56, 2099), (0x5b57, 16489), (0x5b58, 9855), (0x5b59, 14234), (0x5b5a, 19343),
+    (0x5b5b, 16660), (0x5b5c, 16481), (0x5b5d, 15107), (0x5b5e, 2100), (0x5b5f, 12682), (0x5b60, 2101), (0x5b61, 2102), (0x5b62, 19348),
+    (0x5b63, 11335), (0x5b64, 10769), (0x5b65, 19344), (0x5b66, 15302), (0x5b67, 2103), (0x5b68, 2104), (0x5b69, 10927), (0x5b6a, 12492),
+    (0x5b6b, 2105), (0x5b6c, 16636), (0x5b6d, 2106), (0x5b6e, 2107), (0x5b6f, 2108), (0x5b70, 14040), (0x5b71, 19173), (0x5b72, 2109),
+    (0x5b73, 19345), (0x5b74, 2110), (0x5b75, 10440), (0x5b76, 2111), (0x5b77, 2112), (0x5b78, 2113), (0x5b79, 2114), (0x5b7a, 13655),
+    (0x5b7b, 2115), (0x5b7c, 2116), (0x5b7d, 12910), (0x5b7e, 2117), (0x5b7f, 2118), (0x5b80, 19113), (0x5b81, 12919), (0x5b82, 2119),
+    (0x5b83, 14247), (0x5b84, 19114), (0x5b85, 16067), (0x5b86, 2120), (0x5b87, 15753), (0x5b88, 14021), (0x5b89, 9043), (0x5b8a, 2121),
+    (0x5b8b, 14201), (0x5b8c, 14609), (0x5b8d, 2122), (0x5b8e, 2123), (0x5b8f, 10999), (0x5b90, 2124), (0x5b91, 2125), (0x5b92, 2126),
+    (0x5b93, 19116), (0x5b94, 2127), (0x5b95, 19115), (0x5b96, 2128), (0x5b97, 16493), (0x5b98, 10792), (0x5b99, 16315), (0x5b9a, 10173),
+    (0x5b9b, 14615), (0x5b9c, 15528), (0x5b9d, 9221), (0x5b9e, 13986), (0x5b9f, 2129), (0x5ba0, 9667), (0x5ba1, 13858), (0x5ba2, 11920),
+    (0x5ba3, 15196), (0x5ba4, 14015), (0x5ba5, 19117), (0x5ba6, 11149), (0x5ba7, 2130), (0x5ba8, 2131), (0x5ba9, 2132), (0x5baa, 14975),
+    (0x5bab, 10747), (0x5bac, 2133), (0x5bad, 2134), (0x5bae, 2135), (0x5baf, 2136), (0x5bb0, 15920), (0x5bb1, 2137), (0x5bb2, 2138),
+    (0x5bb3, 10931), (0x5bb4, 15366), (0x5bb5, 15007), (0x5bb6, 11355), (0x5bb7, 2139), (0x5bb8, 19118), (0x5bb9, 13646), (0x5bba, 2140),
+    (0x5bbb, 2141), (0x5bbc, 2142), (0x5bbd, 11952), (0x5bbe, 9301), (0x5bbf, 14217), (0x5bc0, 2143), (0x5bc1, 2144), (0x5bc2, 11342),
+    (0x5bc3, 2145), (0x5bc4, 11341), (0x5bc5, 15575), (0x5bc6, 12695), (0x5bc7, 11935), (0x5bc8, 2146), (0x5bc9, 2147), (0x5bca, 2148),
+    (0x5bcb, 2149), (0x5bcc, 10572), (0x5bcd, 2150), (0x5bce, 2151), (0x5bcf, 2152), (0x5bd0, 12669), (0x5bd1, 2153), (0x5bd2, 10939),
+    (0x5bd3, 15867), (0x5bd4, 2154), (0x5bd5, 2155), (0x5bd6, 2156), (0x5bd7, 2157), (0x5bd8, 2158), (0x5bd9, 2159), (0x5bda, 2160),
+    (0x5bdb, 2161), (0x5bdc, 2162), (0x5bdd, 13457), (0x5bde, 12840), (0x5bdf, 9481), (0x5be0, 2163), (0x5be1, 10784), (0x5be2, 2164),
+    (0x5be3, 2165), (0x5be4, 19122), (0x5be5, 12295), (0x5be6, 2166), (0x5be7, 2167), (0x5be8, 16070), (0x5be9, 2168), (0x5bea, 2169),
+    (0x5beb, 2170), (0x5bec, 2171), (0x5bed, 2172), (0x5bee, 19123), (0x5bef, 2173), (0x5bf0, 19125), (0x5bf1, 2174), (0x5bf2, 2175),
+    (0x5bf3, 2176), (0x5bf4, 2177), (0x5bf5, 2178), (0x5bf6, 2179), (0x5bf7, 2180), (0x5bf8, 9856), (0x5bf9, 10217), (0x5bfa, 14189),
+    (0x5bfb, 15311), (0x5bfc, 10003), (0x5bfd, 2181), (0x5bfe, 2182), (0x5bff, 14022), (0x5c00, 2183), (0x5c01, 10421), (0x5c02, 2184),
+    (0x5c03, 2185), (0x5c04, 13843), (0x5c05, 2186), (0x5c06, 11506), (0x5c07, 2187), (0x5c08, 2188), (0x5c09, 14755), (0x5c0a, 16515),
+    (0x5c0b, 2189), (0x5c0c, 2190), (0x5c0d, 2191), (0x5c0e, 2192), (0x5c0f, 15106), (0x5c10, 2193), (0x5c11, 13832), (0x5c12, 2194),
+    (0x5c13, 2195), (0x5c14, 10256), (0x5c15, 19341), (0x5c16, 11371), (0x5c17, 2196), (0x5c18, 9625), (0x5c19, 2197), (0x5c1a, 13823),
+    (0x5c1b, 2198), (0x5c1c, 19342), (0x5c1d, 9597), (0x5c1e, 2199), (0x5c1f, 2200), (0x5c20, 2201), (0x5c21, 2202), (0x5c22, 17809),
+    (0x5c23, 2203), (0x5c24, 15715), (0x5c25, 17810), (0x5c26, 2204), (0x5c27, 15487), (0x5c28, 2205), (0x5c29, 2206), (0x5c2a, 2207),
+    (0x5c2b, 2208), (0x5c2c, 17811), (0x5c2d, 2209), (0x5c2e, 2210), (0x5c2f, 2211), (0x5c30, 2212), (0x5c31, 11730), (0x5c32, 2213),
+    (0x5c33, 2214), (0x5c34, 17812), (0x5c35, 2215), (0x5c36, 2216), (0x5c37, 2217), (0x5c38, 13977), (0x5c39, 15577), (0x5c3a, 9658),
+    (0x5c3b, 19169), (0x5c3c, 12890), (0x5c3d, 11686), (0x5c3e, 14743), (0x5c3f, 12907), (0x5c40, 11739), (0x5c41, 13213), (0x5c42, 9472),
+    (0x5c43, 2218), (0x5c44, 2219), (0x5c45, 11736), (0x5c46, 2220), (0x5c47, 2221), (0x5c48, 13487), (0x5c49, 14420), (0x5c4a, 11571),
+    (0x5c4b, 14786), (0x5c4c, 2222), (0x5c4d, 2223), (0x5c4e, 13991), (0x5c4f, 13238), (0x5c50, 19171), (0x5c51, 15133), (0x5c52, 2224),
+    (0x5c53, 2225), (0x5c54, 2226), (0x5c55, 16080), (0x5c56, 2227), (0x5c57, 2228), (0x5c58, 2229), (0x5c59, 19172), (0x5c5a, 2230),
+    (0x5c5b, 2231), (0x5c5c, 2232), (0x5c5d, 2233), (0x5c5e, 14049), (0x5c5f, 2234), (0x5c60, 14567), (0x5c61, 12482), (0x5c62, 2235),
+    (0x5c63, 19174), (0x5c64, 2236), (0x5c65, 12481), (0x5c66, 19175), (0x5c67, 2237), (0x5c68, 2238), (0x5c69, 2239), (0x5c6a, 2240),
+    (0x5c6b, 2241), (0x5c6c, 2242), (0x5c6d, 2243), (0x5c6e, 19183), (0x5c6f, 14580), (0x5c70, 2244), (0x5c71, 13804), (0x5c72, 2245),
+    (0x5c73, 2246), (0x5c74, 2247), (0x5c75, 2248), (0x5c76, 2249), (0x5c77, 2250), (0x5c78, 2251), (0x5c79, 15542), (0x5c7a, 18343),
+    (0x5c7b, 2252), (0x5c7c, 2253), (0x5c7d, 2254), (0x5c7e, 2255), (0x5c7f, 15751), (0x5c80, 2256), (0x5c81, 14229), (0x5c82, 13286),
+    (0x5c83, 2257), (0x5c84, 2258), (0x5c85, 2259), (0x5c86, 2260), (0x5c87, 2261), (0x5c88, 18347), (0x5c89, 2262), (0x5c8a, 2263),
+    (0x5c8b, 2264), (0x5c8c, 18342), (0x5c8d, 18344), (0x5c8e, 2265), (0x5c8f, 2266), (0x5c90, 18345), (0x5c91, 18350), (0x5c92, 2267),
+    (0x5c93, 2268), (0x5c94, 9482), (0x5c95, 2269), (0x5c96, 18346), (0x5c97, 10603), (0x5c98, 18348), (0x5c99, 18349), (0x5c9a, 18351),
+    (0x5c9b, 10001), (0x5c9c, 18352), (0x5c9d, 2270), (0x5c9e, 2271), (0x5c9f, 2272), (0x5ca0, 2273), (0x5ca1, 2274), (0x5ca2, 18354),
+    (0x5ca3, 18359), (0x5ca4, 2275), (0x5ca5, 2276), (0x5ca6, 2277), (0x5ca7, 2278), (0x5ca8, 2279), (0x5ca9, 15345), (0x5caa, 2280),
+    (0x5cab, 18357), (0x5cac, 18356), (0x5cad, 12330), (0x5cae, 2281), (0x5caf, 2282), (0x5cb0, 2283), (0x5cb1, 18358), (0x5cb2, 2284),
+    (0x5cb3, 15897), (0x5cb4, 2285), (0x5cb5, 18353), (0x5cb6, 2286), (0x5cb7, 18361), (0x5cb8, 9047), (0x5cb9, 2287), (0x5cba, 2288),
+    (0x5cbb, 2289), (0x5cbc, 2290), (0x5cbd, 18355), (0x5cbe, 2291), (0x5cbf, 11964), (0x5cc0, 2292), (0x5cc1, 18360), (0x5cc2, 2293),
+    (0x5cc3, 2294), (0x5cc4, 18362), (0x5cc5, 2295), (0x5cc6, 2296), (0x5cc7, 2297), (0x5cc8, 2298), (0x5cc9, 2299), (0x5cca, 2300),
+    (0x5ccb, 18365), (0x5ccc, 2301), (0x5ccd, 2302), (0x5cce, 2303), (0x5ccf, 2304), (0x5cd0, 2305), (0x5cd1, 2306), (0x5cd2, 18363),
+    (0x5cd3, 2307), (0x5cd4, 2308), (0x5cd5, 2309), (0x5cd6, 2310), (0x5cd7, 2311), (0x5cd8, 2312), (0x5cd9, 16282), (0x5cda, 2313),
+    (0x5cdb, 2314), (0x5cdc, 2315), (0x5cdd, 2316), (0x5cde, 2317), (0x5cdf, 2318), (0x5ce0, 2319), (0x5ce1, 14946), (0x5ce2, 2320),
+    (0x5ce3, 2321), (0x5ce4, 18364), (0x5ce5, 18366), (0x5ce6, 12490), (0x5ce7, 2322), (0x5ce8, 10240), (0x5ce9, 2323), (0x5cea, 15763),
+    (0x5ceb, 2324), (0x5cec, 2325), (0x5ced, 13440), (0x5cee, 2326), (0x5cef, 2327), (0x5cf0, 10424), (0x5cf1, 2328), (0x5cf2, 2329),
+    (0x5cf3, 2330), (0x5cf4, 2331), (0x5cf5, 2332), (0x5cf6, 2333), (0x5cf7, 2334), (0x5cf8, 2335), (0x5cf9, 2336), (0x5cfa, 2337),
+    (0x5cfb, 11779), (0x5cfc, 2338), (0x5cfd, 2339), (0x5cfe, 2340), (0x5cff, 2341), (0x5d00, 2342), (0x5d01, 2343), (0x5d02, 18367),
+    (0x5d03, 18368), (0x5d04, 2344), (0x5d05, 2345), (0x5d06, 18374), (0x5d07, 9666), (0x5d08, 2346), (0x5d09, 2347), (0x5d0a, 2348),
+    (0x5d0b, 2349), (0x5d0c, 2350), (0x5d0d, 2351), (0x5d0e, 13278), (0x5d0f, 2352), (0x5d10, 2353), (0x5d11, 2354), (0x5d12, 2355),
+    (0x5d13, 2356), (0x5d14, 9847), (0x5d15, 2357), (0x5d16, 15329), (0x5d17, 2358), (0x5d18, 2359), (0x5d19, 2360), (0x5d1a, 2361),
+    (0x5d1b, 18375), (0x5d1c, 2362), (0x5d1d, 2363), (0x5d1e, 18373), (0x5d1f, 2364), (0x5d20, 2365), (0x5d21, 2366), (0x5d22, 2367),
+    (0x5d23, 2368), (0x5d24, 18372), (0x5d25, 2369), (0x5d26, 18370), (0x5d27, 18369), (0x5d28, 2370), (0x5d29, 9247), (0x5d2a, 2371),
+    (0x5d2b, 2372), (0x5d2c, 2373), (0x5d2d, 16079), (0x5d2e, 18371), (0x5d2f, 2374), (0x5d30, 2375), (0x5d31, 2376), (0x5d32, 2377),
+    (0x5d33, 2378), (0x5d34, 18378), (0x5d35, 2379), (0x5d36, 2380), (0x5d37, 2381), (0x5d38, 2382), (0x5d39, 2383), (0x5d3a, 2384),
+    (0x5d3b, 2385), (0x5d3c, 2386), (0x5d3d, 18379), (0x5d3e, 18377), (0x5d3f, 2387), (0x5d40, 2388), (0x5d41, 2389), (0x5d42, 2390),
+    (0x5d43, 2391), (0x5d44, 2392), (0x5d45, 2393), (0x5d46, 2394), (0x5d47, 21085), (0x5d48, 2395), (0x5d49, 2396), (0x5d4a, 18386),
+    (0x5d4b, 18385), (0x5d4c, 13417), (0x5d4d, 2397), (0x5d4e, 2398), (0x5d4f, 2399), (0x5d50, 2400), (0x5d51, 2401), (0x5d52, 2402),
+    (0x5d53, 2403), (0x5d54, 2404), (0x5d55, 2405), (0x5d56, 2406), (0x5d57, 2407), (0x5d58, 18376), (0x5d59, 2408), (0x5d5a, 2409),
+    (0x5d5b, 18381), (0x5d5c, 2410), (0x5d5d, 18383), (0x5d5e, 2411), (0x5d5f, 2412), (0x5d60, 2413), (0x5d61, 2414), (0x5d62, 2415),
+    (0x5d63, 2416), (0x5d64, 2417), (0x5d65, 2418), (0x5d66, 2419), (0x5d67, 2420), (0x5d68, 2421), (0x5d69, 18387), (0x5d6a, 2422),
+    (0x5d6b, 18384), (0x5d6c, 18380), (0x5d6d, 2423), (0x5d6e, 2424), (0x5d6f, 18382), (0x5d70, 2425), (0x5d71, 2426), (0x5d72, 2427),
+    (0x5d73, 2428), (0x5d74, 18388), (0x5d75, 2429), (0x5d76, 2430), (0x5d77, 2431), (0x5d78, 2432), (0x5d79, 2433), (0x5d7a, 2434),
+    (0x5d7b, 2435), (0x5d7c, 2436), (0x5d7d, 2437), (0x5d7e, 2438), (0x5d7f, 2439), (0x5d80, 2440), (0x5d81, 2441), (0x5d82, 18389),
+    (0x5d83, 2442), (0x5d84, 2443), (0x5d85, 2444), (0x5d86, 2445), (0x5d87, 2446), (0x5d88, 2447), (0x5d89, 2448), (0x5d8a, 2449),
+    (0x5d8b, 2450), (0x5d8c, 2451), (0x5d8d, 2452), (0x5d8e, 2453), (0x5d8f, 2454), (0x5d90, 2455), (0x5d91, 2456), (0x5d92, 2457),
+    (0x5d93, 2458), (0x5d94, 2459), (0x5d95, 2460), (0x5d96, 2461), (0x5d97, 2462), (0x5d98, 2463), (0x5d99, 18390), (0x5d9a, 2464),
+    (0x5d9b, 2465), (0x5d9c, 2466), (0x5d9d, 18391), (0x5d9e, 2467), (0x5d9f, 2468), (0x5da0, 2469), (0x5da1, 2470), (0x5da2, 2471),
+    (0x5da3, 2472), (0x5da4, 2473), (0x5da5, 2474), (0x5da6, 2475), (0x5da7, 2476), (0x5da8, 2477), (0x5da9, 2478), (0x5daa, 2479),
+    (0x5dab, 2480), (0x5dac, 2481), (0x5dad, 2482), (0x5dae, 2483), (0x5daf, 2484), (0x5db0, 2485), (0x5db1, 2486), (0x5db2, 2487),
+    (0x5db3, 2488), (0x5db4, 2489), (0x5db5, 2490), (0x5db6, 2491), (0x5db7, 18393), (0x5db8, 2492), (0x5db9, 2493), (0x5dba, 2494),
+    (0x5dbb, 2495), (0x5dbc, 2496), (0x5dbd, 2497), (0x5dbe, 2498), (0x5dbf, 2499), (0x5dc0, 2500), (0x5dc1, 2501), (0x5dc2, 2502),
+    (0x5dc3, 2503), (0x5dc4, 2504), (0x5dc5, 18394), (0x5dc6, 2505), (0x5dc7, 2506), (0x5dc8, 2507), (0x5dc9, 2508), (0x5dca, 2509),
+    (0x5dcb, 2510), (0x5dcc, 2511), (0x5dcd, 14726), (0x5dce, 2512), (0x5dcf, 2513), (0x5dd0, 2514), (0x5dd1, 2515), (0x5dd2, 2516),
+    (0x5dd3, 2517), (0x5dd4, 2518), (0x5dd5, 2519), (0x5dd6, 2520), (0x5dd7, 2521), (0x5dd8, 2522), (0x5dd9, 2523), (0x5dda, 2524),
+    (0x5ddb, 19536), (0x5ddc, 2525), (0x5ddd, 9793), (0x5dde, 16306), (0x5ddf, 2526), (0x5de0, 2527), (0x5de1, 15313), (0x5de2, 9613),
+    (0x5de3, 2528), (0x5de4, 2529), (0x5de5, 10739), (0x5de6, 16518), (0x5de7, 13436), (0x5de8, 11747), (0x5de9, 10749), (0x5dea, 2530),
+    (0x5deb, 14780), (0x5dec, 2531), (0x5ded, 2532), (0x5dee, 9483), (0x5def, 17242), (0x5df0, 2533), (0x5df1, 11331), (0x5df2, 15534),
+    (0x5df3, 14195), (0x5df4, 9070), (0x5df5, 2534), (0x5df6, 2535), (0x5df7, 14994), (0x5df8, 2536), (0x5df9, 2537), (0x5dfa, 2538),
+    (0x5dfb, 2539), (0x5dfc, 2540), (0x5dfd, 16882), (0x5dfe, 11572), (0x5dff, 2541), (0x5e00, 2542), (0x5e01, 9265), (0x5e02, 14013),
+    (0x5e03, 9433), (0x5e04, 2543), (0x5e05, 14162), (0x5e06, 10366), (0x5e07, 2544), (0x5e08, 13971), (0x5e09, 2545), (0x5e0a, 2546),
+    (0x5e0b, 2547), (0x5e0c, 14918), (0x5e0d, 2548), (0x5e0e, 2549), (0x5e0f, 18233), (0x5e10, 16097), (0x5e11, 18236), (0x5e12, 2550),
+    (0x5e13, 2551), (0x5e14, 18235), (0x5e15, 13048), (0x5e16, 14436), (0x5e17, 2552), (0x5e18, 12272), (0x5e19, 18234), (0x5e1a, 16312),
+    (0x5e1b, 9420), (0x5e1c, 16281), (0x5e1d, 10034), (0x5e1e, 2553), (0x5e1f, 2554), (0x5e20, 2555), (0x5e21, 2556), (0x5e22, 2557),
+    (0x5e23, 2558), (0x5e24, 2559), (0x5e25, 2560), (0x5e26, 9873), (0x5e27, 16246), (0x5e28, 2561), (0x5e29, 2562), (0x5e2a, 2563),
+    (0x5e2b, 2564), (0x5e2c, 2565), (0x5e2d, 14930), (0x5e2e, 9104), (0x5e2f, 2566), (0x5e30, 2567), (0x5e31, 18237), (0x5e32, 2568),
+    (0x5e33, 2569), (0x5e34, 2570), (0x5e35, 2571), (0x5e36, 2572), (0x5e37, 18336), (0x5e38, 9598), (0x5e39, 2573), (0x5e3a, 2574),
+    (0x5e3b, 18238), (0x5e3c, 18239), (0x5e3d, 12652), (0x5e3e, 2575), (0x5e3f, 2576), (0x5e40, 2577), (0x5e41, 2578), (0x5e42, 12696),
+    (0x5e43, 2579), (0x5e44, 18337), (0x5e45, 10444), (0x5e46, 2580), (0x5e47, 2581), (0x5e48, 2582), (0x5e49, 2583), (0x5e4a, 2584),
+    (0x5e4b, 2585), (0x5e4c, 11162), (0x5e4d, 2586), (0x5e4e, 2587), (0x5e4f, 2588), (0x5e50, 2589), (0x5e51, 2590), (0x5e52, 2591),
+    (0x5e53, 2592), (0x5e54, 18338), (0x5e55, 12852), (0x5e56, 2593), (0x5e57, 2594), (0x5e58, 2595), (0x5e59, 2596), (0x5e5a, 2597),
+    (0x5e5b, 18339), (0x5e5c, 2598), (0x5e5d, 2599), (0x5e5e, 18340), (0x5e5f, 2600), (0x5e60, 2601), (0x5e61, 18341), (0x5e62, 9802),
+    (0x5e63, 2602), (0x5e64, 2603), (0x5e65, 2604), (0x5e66, 2605), (0x5e67, 2606), (0x5e68, 2607), (0x5e69, 2608), (0x5e6a, 2609),
+    (0x5e6b, 2610), (0x5e6c, 2611), (0x5e6d, 2612), (0x5e6e, 2613), (0x5e6f, 2614), (0x5e70, 2615), (0x5e71, 2616), (0x5e72, 10586),
+    (0x5e73, 13234), (0x5e74, 12899), (0x5e75, 2617), (0x5e76, 9407), (0x5e77, 2618), (0x5e78, 15155), (0x5e79, 2619), (0x5e7a, 19534),
+    (0x5e7b, 11150), (0x5e7c, 15730), (0x5e7d, 15711), (0x5e7e, 2620), (0x5e7f, 10802), (0x5e80, 18572), (0x5e81, 2621), (0x5e82, 2622),
+    (0x5e83, 2623), (0x5e84, 16450), (0x5e85, 2624), (0x5e86, 13471), (0x5e87, 9266), (0x5e88, 2625), (0x5e89, 2626), (0x5e8a, 9803),
+    (0x5e8b, 18574), (0x5e8c, 2627), (0x5e8d, 2628), (0x5e8e, 2629), (0x5e8f, 15187), (0x5e90, 12459), (0x5e91, 18573), (0x5e92, 2630),
+    (0x5e93, 11941), (0x5e94, 15681), (0x5e95, 10030), (0x5e96, 18575), (0x5e97, 10049), (0x5e98, 2631), (0x5e99, 12712), (0x5e9a, 10638),
+    (0x5e9b, 2632), (0x5e9c, 10559), (0x5e9d, 2633), (0x5e9e, 13066), (0x5e9f, 10402), (0x5ea0, 18577), (0x5ea1, 2634), (0x5ea2, 2635),
+    (0x5ea3, 2636), (0x5ea4, 2637), (0x5ea5, 18576), (0x5ea6, 10205), (0x5ea7, 16524), (0x5ea8, 2638), (0x5ea9, 2639), (0x5eaa, 2640),
+    (0x5eab, 2641), (0x5eac, 2642), (0x5ead, 14540), (0x5eae, 2643), (0x5eaf, 2644), (0x5eb0, 2645), (0x5eb1, 2646), (0x5eb2, 2647),
+    (0x5eb3, 18581), (0x5eb4, 2648), (0x5eb5, 18579), (0x5eb6, 14057), (0x5eb7, 11896), (0x5eb8, 15700), (0x5eb9, 18578), (0x5eba, 2649),
+    (0x5ebb, 2650), (0x5ebc, 2651), (0x5ebd, 2652), (0x5ebe, 18580), (0x5ebf, 2653), (0x5ec0, 2654), (0x5ec1, 2655), (0x5ec2, 2656),
+    (0x5ec3, 2657), (0x5ec4, 2658), (0x5ec5, 2659), (0x5ec6, 2660), (0x5ec7, 2661), (0x5ec8, 2662), (0x5ec9, 12269), (0x5eca, 12105),
+    (0x5ecb, 2663), (0x5ecc, 2664), (0x5ecd, 2665), (0x5ece, 2666), (0x5ecf, 2667), (0x5ed0, 2668), (0x5ed1, 18584), (0x5ed2, 18583),
+    (0x5ed3, 12075), (0x5ed4, 2669), (0x5ed5, 2670), (0x5ed6, 12301), (0x5ed7, 2671), (0x5ed8, 2672), (0x5ed9, 2673), (0x5eda, 2674),
+    (0x5edb, 18585), (0x5edc, 2675), (0x5edd, 2676), (0x5ede, 2677), (0x5edf, 2678), (0x5ee0, 2679), (0x5ee1, 2680), (0x5ee2, 2681),
+    (0x5ee3, 2682), (0x5ee4, 2683), (0x5ee5, 2684), (0x5ee6, 2685), (0x5ee7, 2686), (0x5ee8, 18586), (0x5ee9, 2687), (0x5eea, 18587),
+    (0x5eeb, 2688), (0x5eec, 2689), (0x5eed, 2690), (0x5eee, 2691), (0x5eef, 2692), (0x5ef0, 2693), (0x5ef1, 2694), (0x5ef2, 2695),
+    (0x5ef3, 2696), (0x5ef4, 17235), (0x5ef5, 2697), (0x5ef6, 15346), (0x5ef7, 14537), (0x5ef8, 2698), (0x5ef9, 2699), (0x5efa, 11503),
+    (0x5efb, 2700), (0x5efc, 2701), (0x5efd, 2702), (0x5efe, 17800), (0x5eff, 16630), (0x5f00, 11885), (0x5f01, 17240), (0x5f02, 15561),
+    (0x5f03, 13295), (0x5f04, 13025), (0x5f05, 2703), (0x5f06, 2704), (0x5f07, 2705), (0x5f08, 17801), (0x5f09, 2706), (0x5f0a, 9270),
+    (0x5f0b, 17969), (0x5f0c, 2707), (0x5f0d, 2708), (0x5f0e, 2709), (0x5f0f, 13994), (0x5f10, 2710), (0x5f11, 17972), (0x5f12, 2711),
+    (0x5f13, 10748), (0x5f14, 2712), (0x5f15, 15578), (0x5f16, 2713), (0x5f17, 10550), (0x5f18, 11000), (0x5f19, 2714), (0x5f1a, 2715),
+    (0x5f1b, 9653), (0x5f1c, 2716), (0x5f1d, 2717), (0x5f1e, 2718), (0x5f1f, 10035), (0x5f20, 16092), (0x5f21, 2719), (0x5f22, 2720),
+    (0x5f23, 2721), (0x5f24, 2722), (0x5f25, 12689), (0x5f26, 14965), (0x5f27, 11116), (0x5f28, 2723), (0x5f29, 19178), (0x5f2a, 19177),
+    (0x5f2b, 2724), (0x5f2c, 2725), (0x5f2d, 19179), (0x5f2e, 2726), (0x5f2f, 14603), (0x5f30, 2727), (0x5f31, 13670), (0x5f32, 2728),
+    (0x5f33, 2729), (0x5f34, 2730), (0x5f35, 2731), (0x5f36, 2732), (0x5f37, 2733), (0x5f38, 2734), (0x5f39, 9990), (0x5f3a, 13426),
+    (0x5f3b, 2735), (0x5f3c, 19181), (0x5f3d, 2736), (0x5f3e, 2737), (0x5f3f, 2738), (0x5f40, 20441), (0x5f41, 2739), (0x5f42, 2740),
+    (0x5f43, 2741), (0x5f44, 2742), (0x5f45, 2743), (0x5f46, 2744), (0x5f47, 2745), (0x5f48, 2746), (0x5f49, 2747), (0x5f4a, 2748),
+    (0x5f4b, 2749), (0x5f4c, 2750), (0x5f4d, 2751), (0x5f4e, 2752), (0x5f4f, 2753), (0x5f50, 19165), (0x5f51, 2754), (0x5f52, 10808),
+    (0x5f53, 9992), (0x5f54, 2755), (0x5f55, 12473), (0x5f56, 19167), (0x5f57, 19166), (0x5f58, 19168), (0x5f59, 2756), (0x5f5a, 2757),
+    (0x5f5b, 2758), (0x5f5c, 2759), (0x5f5d, 15530), (0x5f5e, 2760), (0x5f5f, 2761), (0x5f60, 2762), (0x5f61, 18409), (0x5f62, 15151),
+    (0x5f63, 2763), (0x5f64, 14549), (0x5f65, 2764), (0x5f66, 15364), (0x5f67, 2765), (0x5f68, 2766), (0x5f69, 9447), (0x5f6a, 9290),
+    (0x5f6b, 2767), (0x5f6c, 9297), (0x5f6d, 13092), (0x5f6e, 2768), (0x5f6f, 2769), (0x5f70, 16090), (0x5f71, 15691), (0x5f72, 2770),
+    (0x5f73, 18395), (0x5f74, 2771), (0x5f75, 2772), (0x5f76, 2773), (0x5f77, 18396), (0x5f78, 2774), (0x5f79, 15544), (0x5f7a, 2775),
+    (0x5f7b, 9620), (0x5f7c, 9258), (0x5f7d, 2776), (0x5f7e, 2777), (0x5f7f, 2778), (0x5f80, 14624), (0x5f81, 16142), (0x5f82, 18397),
+    (0x5f83, 2779), (0x5f84, 11707), (0x5f85, 9878), (0x5f86, 2780), (0x5f87, 18398), (0x5f88, 10985), (0x5f89, 18399), (0x5f8a, 11133),
+    (0x5f8b, 12486), (0x5f8c, 18400), (0x5f8d, 2781), (0x5f8e, 2782), (0x5f8f, 2783), (0x5f90, 15181), (0x5f91, 2784), (0x5f92, 14564),
+    (0x5f93, 2785), (0x5f94, 2786), (0x5f95, 18401), (0x5f96, 2787), (0x5f97, 10010), (0x5f98, 13054), (0x5f99, 18402), (0x5f9a, 2788),
+    (0x5f9b, 2789), (0x5f9c, 18403), (0x5f9d, 2790), (0x5f9e, 2791), (0x5f9f, 2792), (0x5fa0, 2793), (0x5fa1, 15764), (0x5fa2, 2794),
+    (0x5fa3, 2795), (0x5fa4, 2796), (0x5fa5, 2797), (0x5fa6, 2798), (0x5fa7, 2799), (0x5fa8, 18404), (0x5fa9, 2800), (0x5faa, 15308),
+    (0x5fab, 2801), (0x5fac, 2802), (0x5fad, 18405), (0x5fae, 14727), (0x5faf, 2803), (0x5fb0, 2804), (0x5fb1, 2805), (0x5fb2, 2806),
+    (0x5fb3, 2807), (0x5fb4, 2808), (0x5fb5, 18406), (0x5fb6, 2809), (0x5fb7, 10009), (0x5fb8, 2810), (0x5fb9, 2811), (0x5fba, 2812),
+    (0x5fbb, 2813), (0x5fbc, 18407), (0x5fbd, 11168), (0x5fbe, 2814), (0x5fbf, 2815), (0x5fc0, 2816), (0x5fc1, 2817), (0x5fc2, 2818),
+    (0x5fc3, 15141), (0x5fc4, 18589), (0x5fc5, 9271), (0x5fc6, 15553), (0x5fc7, 2819), (0x5fc8, 2820), (0x5fc9, 18590), (0x5fca, 2821),
+    (0x5fcb, 2822), (0x5fcc, 11346), (0x5fcd, 13629), (0x5fce, 2823), (0x5fcf, 18592), (0x5fd0, 20519), (0x5fd1, 20518), (0x5fd2, 17970),
+    (0x5fd3, 2824), (0x5fd4, 2825), (0x5fd5, 2826), (0x5fd6, 18591), (0x5fd7, 16275), (0x5fd8, 14627), (0x5fd9, 12641), (0x5fda, 2827),
+    (0x5fdb, 2828), (0x5fdc, 2829), (0x5fdd, 18750), (0x5fde, 2830), (0x5fdf, 2831), (0x5fe0, 16295), (0x5fe1, 18596), (0x5fe2, 2832),
+    (0x5fe3, 2833), (0x5fe4, 18597), (0x5fe5, 2834), (0x5fe6, 2835), (0x5fe7, 15714), (0x5fe8, 2836), (0x5fe9, 2837), (0x5fea, 18601),
+    (0x5feb, 11951), (0x5fec, 2838), (0x5fed, 18602), (0x5fee, 18594), (0x5fef, 2839), (0x5ff0, 2840), (0x5ff1, 9627), (0x5ff2, 2841),
+    (0x5ff3, 2842), (0x5ff4, 2843), (0x5ff5, 12903), (0x5ff6, 2844), (0x5ff7, 2845), (0x5ff8, 18603), (0x5ff9, 2846), (0x5ffa, 2847),
+    (0x5ffb, 15140), (0x5ffc, 2848), (0x5ffd, 11011), (0x5ffe, 18598), (0x5fff, 10417), (0x6000, 11134), (0x6001, 14357), (0x6002, 14198),
+    (0x6003, 18593), (0x6004, 18595), (0x6005, 18599), (0x6006, 18600), (0x6007, 2849), (0x6008, 2850), (0x6009, 2851), (0x600a, 18612),
+    (0x600b, 2852), (0x600c, 2853), (0x600d, 18609), (0x600e, 15950), (0x600f, 18608), (0x6010, 2854), (0x6011, 2855), (0x6012, 13028),
+    (0x6013, 2856), (0x6014, 16145), (0x6015, 13049), (0x6016, 9437), (0x6017, 2857), (0x6018, 2858), (0x6019, 18604), (0x601a, 2859),
+    (0x601b, 18607), (0x601c, 12270), (0x601d, 14183), (0x601e, 2860), (0x601f, 2861), (0x6020, 9976), (0x6021, 18614), (0x6022, 2862),
+    (0x6023, 2863), (0x6024, 2864), (0x6025, 11322), (0x6026, 18606), (0x6027, 15157), (0x6028, 15890), (0x6029, 18610), (0x602a, 10789),
+    (0x602b, 18611), (0x602c, 2865), (0x602d, 2866), (0x602e, 2867), (0x602f, 13446), (0x6030, 2868), (0x6031, 2869), (0x6032, 2870),
+    (0x6033, 2871), (0x6034, 2872), (0x6035, 18605), (0x6036, 2873), (0x6037, 2874), (0x6038, 2875), (0x6039, 2876), (0x603a, 2877),
+    (0x603b, 16495), (0x603c, 20616), (0x603d, 2878), (0x603e, 2879), (0x603f, 18613), (0x6040, 2880), (0x6041, 20620), (0x6042, 18619),
+    (0x6043, 14014), (0x6044, 2881), (0x6045, 2882), (0x6046, 2883), (0x6047, 2884), (0x6048, 2885), (0x6049, 2886), (0x604a, 2887),
+    (0x604b, 12276), (0x604c, 2888), (0x604d, 11163), (0x604e, 2889), (0x604f, 2890), (0x6050, 11929), (0x6051, 2891), (0x6052, 10992),
+    (0x6053, 2892), (0x6054, 2893), (0x6055, 14156), (0x6056, 2894), (0x6057, 2895), (0x6058, 2896), (0x6059, 20621), (0x605a, 20618),
+    (0x605b, 2897), (0x605c, 2898), (0x605d, 20617), (0x605e, 2899), (0x605f, 2900), (0x6060, 2901), (0x6061, 2902), (0x6062, 11169),
+    (0x6063, 20622), (0x6064, 15189), (0x6065, 2903), (0x6066, 2904), (0x6067, 20619), (0x6068, 10987), (0x6069, 10252), (0x606a, 18716),
+    (0x606b, 10183), (0x606c, 14426), (0x606d, 10742), (0x606e, 2905), (0x606f, 14917), (0x6070, 13396), (0x6071, 2906), (0x6072, 2907),
+    (0x6073, 11925), (0x6074, 2908), (0x6075, 2909), (0x6076, 10246), (0x6077, 2910), (0x6078, 18615), (0x6079, 18616), (0x607a, 18618),
+    (0x607b, 18617), (0x607c, 12878), (0x607d, 18717), (0x607e, 2911), (0x607f, 15708), (0x6080, 2912), (0x6081, 2913), (0x6082, 2914),
+    (0x6083, 18722), (0x6084, 13431), (0x6085, 2915), (0x6086, 2916), (0x6087, 2917), (0x6088, 2918), (0x6089, 14919), (0x608a, 2919),
+    (0x608b, 2920), (0x608c, 18724), (0x608d, 10948), (0x608e, 2921), (0x608f, 2922), (0x6090, 2923), (0x6091, 2924), (0x6092, 18723),
+    (0x6093, 2925), (0x6094, 11173), (0x6095, 2926), (0x6096, 18718), (0x6097, 2927), (0x6098, 2928), (0x6099, 2929), (0x609a, 18719),
+    (0x609b, 18725), (0x609c, 2930), (0x609d, 18721), (0x609e, 2931), (0x609f, 14807), (0x60a0, 15713), (0x60a1, 2932), (0x60a2, 2933),
+    (0x60a3, 11143), (0x60a4, 2934), (0x60a5, 2935), (0x60a6, 15900), (0x60a7, 2936), (0x60a8, 12915), (0x60a9, 2937), (0x60aa, 2938),
+    (0x60ab, 20623), (0x60ac, 15197), (0x60ad, 18720), (0x60ae, 2939), (0x60af, 12720), (0x60b0, 2940), (0x60b1, 18728), (0x60b2, 9230),
+    (0x60b3, 2941), (0x60b4, 18733), (0x60b5, 2942), (0x60b6, 2943), (0x60b7, 2944), (0x60b8, 11339), (0x60b9, 2945), (0x60ba, 2946),
+    (0x60bb, 18727), (0x60bc, 10006), (0x60bd, 2947), (0x60be, 2948), (0x60bf, 2949), (0x60c0, 2950), (0x60c1, 2951), (0x60c2, 2952),
+    (0x60c3, 2953), (0x60c4, 2954), (0x60c5, 13468), (0x60c6, 18731), (0x60c7, 2955), (0x60c8, 2956), (0x60c9, 2957), (0x60ca, 11695),
+    (0x60cb, 14614), (0x60cc, 2958), (0x60cd, 2959), (0x60ce, 2960), (0x60cf, 2961), (0x60d0, 2962), (0x60d1, 11198), (0x60d2, 2963),
+    (0x60d3, 2964), (0x60d4, 2965), (0x60d5, 14417), (0x60d6, 2966), (0x60d7, 2967), (0x60d8, 18730), (0x60d9, 2968), (0x60da, 18732),
+    (0x60db, 2969), (0x60dc, 14922), (0x60dd, 18729), (0x60de, 2970), (0x60df, 14734), (0x60e0, 11176), (0x60e1, 2971), (0x60e2, 2972),
+    (0x60e3, 2973), (0x60e4, 2974), (0x60e5, 2975), (0x60e6, 10050), (0x60e7, 11754), (0x60e8, 9455), (0x60e9, 9640), (0x60ea, 2976),
+    (0x60eb, 9240), (0x60ec, 18726), (0x60ed, 9454), (0x60ee, 9987), (0x60ef, 10798), (0x60f0, 10237), (0x60f1, 2977), (0x60f2, 2978),
+    (0x60f3, 14990), (0x60f4, 18738), (0x60f5, 2979), (0x60f6, 11159), (0x60f7, 2980), (0x60f8, 2981), (0x60f9, 13624), (0x60fa, 15147),
+    (0x60fb, 2982), (0x60fc, 2983), (0x60fd, 2984), (0x60fe, 2985), (0x60ff, 2986), (0x6100, 18739), (0x6101, 9673), (0x6102, 2987),
+    (0x6103, 2988), (0x6104, 2989), (0x6105, 2990), (0x6106, 20624), (0x6107, 2991), (0x6108, 15765), (0x6109, 15743), (0x610a, 2992),
+    (0x610b, 2993), (0x610c, 2994), (0x610d, 20625), (0x610e, 18740), (0x610f, 15551), (0x6110, 2995), (0x6111, 2996), (0x6112, 2997),
+    (0x6113, 2998), (0x6114, 2999), (0x6115, 18736), (0x6116, 3000), (0x6117, 3001), (0x6118, 3002), (0x6119, 3003), (0x611a, 15737),
+    (0x611b, 3004), (0x611c, 3005), (0x611d, 3006), (0x611e, 3007), (0x611f, 10593), (0x6120, 18734), (0x6121, 3008), (0x6122, 3009),
+    (0x6123, 18737), (0x6124, 10418), (0x6125, 3010), (0x6126, 18735), (0x6127, 12067), (0x6128, 3011), (0x6129, 3012), (0x612a, 3013),
+    (0x612b, 18741), (0x612c, 3014), (0x612d, 3015), (0x612e, 3016), (0x612f, 3017), (0x6130, 3018), (0x6131, 3019), (0x6132, 3020),
+    (0x6133, 3021), (0x6134, 3022), (0x6135, 3023), (0x6136, 3024), (0x6137, 3025), (0x6138, 3026), (0x6139, 3027), (0x613a, 3028),
+    (0x613b, 3029), (0x613c, 3030), (0x613d, 3031), (0x613e, 3032), (0x613f, 15889), (0x6140, 3033), (0x6141, 3034), (0x6142, 3035),
+    (0x6143, 3036), (0x6144, 3037), (0x6145, 3038), (0x6146, 3039), (0x6147, 3040), (0x6148, 9825), (0x6149, 3041), (0x614a, 18742),
+    (0x614b, 3042), (0x614c, 11152), (0x614d, 3043), (0x614e, 13862), (0x614f, 3044), (0x6150, 3045), (0x6151, 13844), (0x6152, 3046),
+    (0x6153, 3047), (0x6154, 3048), (0x6155, 12854), (0x6156, 3049), (0x6157, 3050), (0x6158, 3051), (0x6159, 3052), (0x615a, 3053),
+    (0x615b, 3054), (0x615c, 3055), (0x615d, 20626), (0x615e, 3056), (0x615f, 3057), (0x6160, 3058), (0x6161, 3059), (0x6162, 12538),
+    (0x6163, 3060), (0x6164, 3061), (0x6165, 3062), (0x6166, 3063), (0x6167, 11174), (0x6168, 11889), (0x6169, 3064), (0x616a, 3065),
+    (0x616b, 3066), (0x616c, 3067), (0x616d, 3068), (0x616e, 3069), (0x616f, 3070), (0x6170, 14756), (0x6171, 3071), (0x6172, 3072),
+    (0x6173, 3073), (0x6174, 3074), (0x6175, 18743), (0x6176, 3075), (0x6177, 11897), (0x6178, 3076), (0x6179, 3077), (0x617a, 3078),
+    (0x617b, 3079), (0x617c, 3080), (0x617d, 3081), (0x617e, 3082), (0x617f, 3083), (0x6180, 3084), (0x6181, 3085), (0x6182, 3086),
+    (0x6183, 3087), (0x6184, 3088), (0x6185, 3089), (0x6186, 3090), (0x6187, 3091), (0x6188, 3092), (0x6189, 3093), (0x618a, 3094),
+    (0x618b, 9294), (0x618c, 3095), (0x618d, 3096), (0x618e, 15952), (0x618f, 3097), (0x6190, 3098), (0x6191, 3099), (0x6192, 3100),
+    (0x6193, 3101), (0x6194, 18745), (0x6195, 3102), (0x6196, 3103), (0x6197, 3104), (0x6198, 3105), (0x6199, 3106), (0x619a, 3107),
+    (0x619b, 3108), (0x619c, 3109), (0x619d, 20628), (0x619e, 3110), (0x619f, 3111), (0x61a0, 3112), (0x61a1, 3113), (0x61a2, 3114),
+    (0x61a3, 3115), (0x61a4, 3116), (0x61a5, 3117), (0x61a6, 3118), (0x61a7, 18746), (0x61a8, 10934), (0x61a9, 20627), (0x61aa, 3119),
+    (0x61ab, 3120), (0x61ac, 18744), (0x61ad, 3121), (0x61ae, 3122), (0x61af, 3123), (0x61b0, 3124), (0x61b1, 3125), (0x61b2, 3126),
+    (0x61b3, 3127), (0x61b4, 3128), (0x61b5, 3129), (0x61b6, 3130), (0x61b7, 18747), (0x61b8, 3131), (0x61b9, 3132), (0x61ba, 3133),
+    (0x61bb, 3134), (0x61bc, 3135), (0x61bd, 3136), (0x61be, 10947), (0x61bf, 3137), (0x61c0, 3138), (0x61c1, 3139), (0x61c2, 10179),
+    (0x61c3, 3140), (0x61c4, 3141), (0x61c5, 3142), (0x61c6, 3143), (0x61c7, 3144), (0x61c8, 15129), (0x61c9, 3145), (0x61ca, 9060),
+    (0x61cb, 20629), (0x61cc, 3146), (0x61cd, 3147), (0x61ce, 3148), (0x61cf, 3149), (0x61d0, 3150), (0x61d1, 20630), (0x61d2, 12098),
+    (0x61d3, 3151), (0x61d4, 18748), (0x61d5, 3152), (0x61d6, 3153), (0x61d7, 3154), (0x61d8, 3155), (0x61d9, 3156), (0x61da, 3157),
+    (0x61db, 3158), (0x61dc, 3159), (0x61dd, 3160), (0x61de, 3161), (0x61df, 3162), (0x61e0, 3163), (0x61e1, 3164), (0x61e2, 3165),
+    (0x61e3, 3166), (0x61e4, 3167), (0x61e5, 3168), (0x61e6, 13034), (0x61e7, 3169), (0x61e8, 3170), (0x61e9, 3171), (0x61ea, 3172),
+    (0x61eb, 3173), (0x61ec, 3174), (0x61ed, 3175), (0x61ee, 3176), (0x61ef, 3177), (0x61f0, 3178), (0x61f1, 3179), (0x61f2, 3180),
+    (0x61f3, 3181), (0x61f4, 3182), (0x61f5, 18749), (0x61f6, 3183), (0x61f7, 3184), (0x61f8, 3185), (0x61f9, 3186), (0x61fa, 3187),
+    (0x61fb, 3188), (0x61fc, 3189), (0x61fd, 3190), (0x61fe, 3191), (0x61ff, 17403), (0x6200, 3192), (0x6201, 3193), (0x6202, 3194),
+    (0x6203, 3195), (0x6204, 3196), (0x6205, 3197), (0x6206, 20631), (0x6207, 3198), (0x6208, 10619), (0x6209, 3199), (0x620a, 14801),
+    (0x620b, 20052), (0x620c, 15176), (0x620d, 14054), (0x620e, 13639), (0x620f, 14938), (0x6210, 9636), (0x6211, 14775), (0x6212, 11563),
+    (0x6213, 3200), (0x6214, 3201), (0x6215, 18777), (0x6216, 11197), (0x6217, 20053), (0x6218, 16084), (0x6219, 3202), (0x621a, 13266),
+    (0x621b, 20054), (0x621c, 3203), (0x621d, 3204), (0x621e, 3205), (0x621f, 20055), (0x6220, 3206), (0x6221, 20057), (0x6222, 20056),
+    (0x6223, 3207), (0x6224, 20059), (0x6225, 20058), (0x6226, 3208), (0x6227, 3209), (0x6228, 3210), (0x6229, 3211), (0x622a, 11551),
+    (0x622b, 3212), (0x622c, 20060), (0x622d, 3213), (0x622e, 12475), (0x622f, 3214), (0x6230, 3215), (0x6231, 3216), (0x6232, 3217),
+    (0x6233, 9818), (0x6234, 9872), (0x6235, 3218), (0x6236, 3219), (0x6237, 11122), (0x6238, 3220), (0x6239, 3221), (0x623a, 3222),
+    (0x623b, 3223), (0x623c, 3224), (0x623d, 20495), (0x623e, 20494), (0x623f, 10386), (0x6240, 14244), (0x6241, 9281), (0x6242, 3225),
+    (0x6243, 20496), (0x6244, 3226), (0x6245, 3227), (0x6246, 3228), (0x6247, 13815), (0x6248, 20497), (0x6249, 20498), (0x624a, 3229),
+    (0x624b, 14019), (0x624c, 17813), (0x624d, 9442), (0x624e, 15955), (0x624f, 3230), (0x6250, 3231), (0x6251, 13248), (0x6252, 9064),
+    (0x6253, 9867), (0x6254, 13636), (0x6255, 3232), (0x6256, 3233), (0x6257, 3234), (0x6258, 14583), (0x6259, 3235), (0x625a, 3236),
+    (0x625b, 11899), (0x625c, 3237), (0x625d, 3238), (0x625e, 3239), (0x625f, 3240), (0x6260, 3241), (0x6261, 3242), (0x6262, 3243),
+    (0x6263, 11934), (0x6264, 3244), (0x6265, 3245), (0x6266, 13399), (0x6267, 16265), (0x6268, 3246), (0x6269, 12074), (0x626a, 17814),
+    (0x626b, 13783), (0x626c, 15374), (0x626d, 13019), (0x626e, 9096), (0x626f, 9617), (0x6270, 13622), (0x6271, 3247), (0x6272, 3248),
+    (0x6273, 9091), (0x6274, 3249), (0x6275, 3250), (0x6276, 10441), (0x6277, 3251), (0x6278, 3252), (0x6279, 13105), (0x627a, 3253),
+    (0x627b, 3254), (0x627c, 10248), (0x627d, 3255), (0x627e, 16105), (0x627f, 9643), (0x6280, 11333), (0x6281, 3256), (0x6282, 3257),
+    (0x6283, 3258), (0x6284, 9608), (0x6285, 3259), (0x6286, 3260), (0x6287, 3261), (0x6288, 3262), (0x6289, 11766), (0x628a, 9074),
+    (0x628b, 3263), (0x628c, 3264), (0x628d, 3265), (0x628e, 3266), (0x628f, 3267), (0x6290, 3268), (0x6291, 15539), (0x6292, 14032),
+    (0x6293, 16440), (0x6294, 3269), (0x6295, 14557), (0x6296, 10187), (0x6297, 11900), (0x6298, 16114), (0x6299, 3270), (0x629a, 10552),
+    (0x629b, 13070), (0x629c, 3271), (0x629d, 3272), (0x629e, 3273), (0x629f, 17815), (0x62a0, 11932), (0x62a1, 12498), (0x62a2, 13427),
+    (0x62a3, 3274), (0x62a4, 11119), (0x62a5, 9223), (0x62a6, 3275), (0x62a7, 3276), (0x62a8, 13089), (0x62a9, 3277), (0x62aa, 3278),
+    (0x62ab, 13106), (0x62ac, 14352), (0x62ad, 3279), (0x62ae, 3280), (0x62af, 3281), (0x62b0, 3282), (0x62b1, 9222), (0x62b2, 3283),
+    (0x62b3, 3284), (0x62b4, 3285), (0x62b5, 10029), (0x62b6, 3286), (0x62b7, 3287), (0x62b8, 3288), (0x62b9, 12833), (0x62ba, 3289),
+    (0x62bb, 17816), (0x62bc, 15321), (0x62bd, 9668), (0x62be, 3290), (0x62bf, 12717), (0x62c0, 3291), (0x62c1, 3292), (0x62c2, 10442),
+    (0x62c3, 3293), (0x62c4, 16329), (0x62c5, 9978), (0x62c6, 9485), (0x62c7, 12845), (0x62c8, 12898), (0x62c9, 12078), (0x62ca, 17817),
+    (0x62cb, 3294), (0x62cc, 9097), (0x62cd, 13051), (0x62ce, 12319), (0x62cf, 3295), (0x62d0, 10788), (0x62d1, 3296), (0x62d2, 11745),
+    (0x62d3, 14591), (0x62d4, 9071), (0x62d5, 3297), (0x62d6, 14582), (0x62d7, 17819), (0x62d8, 11733), (0x62d9, 16465), (0x62da, 17818),
+    (0x62db, 16103), (0x62dc, 9086), (0x62dd, 3298), (0x62de, 3299), (0x62df, 12891), (0x62e0, 3300), (0x62e1, 3301), (0x62e2, 12448),
+    (0x62e3, 11385), (0x62e4, 3302), (0x62e5, 15696), (0x62e6, 12090), (0x62e7, 13016), (0x62e8, 9411), (0x62e9, 15946), (0x62ea, 3303),
+    (0x62eb, 3304), (0x62ec, 12073), (0x62ed, 14000), (0x62ee, 17820), (0x62ef, 16147), (0x62f0, 3305), (0x62f1, 10751), (0x62f2, 3306),
+    (0x62f3, 13598), (0x62f4, 14164), (0x62f5, 3307), (0x62f6, 17822), (0x62f7, 11904), (0x62f8, 3308), (0x62f9, 3309), (0x62fa, 3310),
+    (0x62fb, 3311), (0x62fc, 13225), (0x62fd, 16442), (0x62fe, 13981), (0x62ff, 12860), (0x6300, 3312), (0x6301, 9649), (0x6302, 10785),
+    (0x6303, 3313), (0x6304, 3314), (0x6305, 3315), (0x6306, 3316), (0x6307, 16269), (0x6308, 20137), (0x6309, 9045), (0x630a, 3317),
+    (0x630b, 3318), (0x630c, 3319), (0x630d, 3320), (0x630e, 11945), (0x630f, 3321), (0x6310, 3322), (0x6311, 14429), (0x6312, 3323),
+    (0x6313, 3324), (0x6314, 3325), (0x6315, 3326), (0x6316, 14593), (0x6317, 3327), (0x6318, 3328), (0x6319, 3329), (0x631a, 16276),
+    (0x631b, 12491), (0x631c, 3330), (0x631d, 14771), (0x631e, 14347), (0x631f, 15119), (0x6320, 12876), (0x6321, 9993), (0x6322, 17821),
+    (0x6323, 16140), (0x6324, 11328), (0x6325, 11166), (0x6326, 3331), (0x6327, 3332), (0x6328, 9029), (0x6329, 3333), (0x632a, 13033),
+    (0x632b, 9861), (0x632c, 3334), (0x632d, 3335), (0x632e, 3336), (0x632f, 16136), (0x6330, 3337), (0x6331, 3338), (0x6332, 20138),
+    (0x6333, 3339), (0x6334, 3340), (0x6335, 3341), (0x6336, 3342), (0x6337, 3343), (0x6338, 3344), (0x6339, 17823), (0x633a, 14541),
+    (0x633b, 3345), (0x633c, 3346), (0x633d, 14611), (0x633e, 3347), (0x633f, 3348), (0x6340, 3349), (0x6341, 3350), (0x6342, 14795),
+    (0x6343, 17825), (0x6344, 3351), (0x6345, 14552), (0x6346, 12071), (0x6347, 3352), (0x6348, 3353), (0x6349, 16464), (0x634a, 3354),
+    (0x634b, 17824), (0x634c, 9063), (0x634d, 10945), (0x634e, 13826), (0x634f, 12908), (0x6350, 11757), (0x6351, 3355), (0x6352, 3356),
+    (0x6353, 3357), (0x6354, 3358), (0x6355, 9427), (0x6356, 3359), (0x6357, 3360), (0x6358, 3361), (0x6359, 3362), (0x635a, 3363),
+    (0x635b, 3364), (0x635c, 3365), (0x635d, 3366), (0x635e, 12109), (0x635f, 14235), (0x6360, 3367), (0x6361, 11386), (0x6362, 11142),
+    (0x6363, 9998), (0x6364, 3368), (0x6365, 3369), (0x6366, 3370), (0x6367, 13100), (0x6368, 3371), (0x6369, 17835), (0x636a, 3372),
+    (0x636b, 3373), (0x636c, 3374), (0x636d, 17832), (0x636e, 11746), (0x636f, 3375), (0x6370, 3376), (0x6371, 17828), (0x6372, 3377),
+    (0x6373, 3378), (0x6374, 3379), (0x6375, 3380), (0x6376, 9808), (0x6377, 11556), (0x6378, 3381), (0x6379, 3382), (0x637a, 17829),
+    (0x637b, 12902), (0x637c, 3383), (0x637d, 3384), (0x637e, 3385), (0x637f, 3386), (0x6380, 14953), (0x6381, 3387), (0x6382, 10039),
+    (0x6383, 3388), (0x6384, 3389), (0x6385, 3390), (0x6386, 3391), (0x6387, 10227), (0x6388, 14023), (0x6389, 10059), (0x638a, 17834),
+    (0x638b, 3392), (0x638c, 16093), (0x638d, 3393), (0x638e, 17830), (0x638f, 14390), (0x6390, 13299), (0x6391, 3394), (0x6392, 13052),
+    (0x6393, 3395), (0x6394, 3396), (0x6395, 3397), (0x6396, 15505), (0x6397, 3398), (0x6398, 11767), (0x6399, 3399), (0x639a, 3400),
+    (0x639b, 3401), (0x639c, 3402), (0x639d, 3403), (0x639e, 3404), (0x639f, 3405), (0x63a0, 12496), (0x63a1, 3406), (0x63a2, 14374),
+    (0x63a3, 9619), (0x63a4, 3407), (0x63a5, 11546), (0x63a6, 3408), (0x63a7, 11931), (0x63a8, 14573), (0x63a9, 15353), (0x63aa, 9860),
+    (0x63ab, 3409), (0x63ac, 17833), (0x63ad, 17826), (0x63ae, 17836), (0x63af, 3410), (0x63b0, 20139), (0x63b1, 3411), (0x63b2, 3412),
+    (0x63b3, 12461), (0x63b4, 17831), (0x63b5, 3413), (0x63b6, 3414), (0x63b7, 16277), (0x63b8, 9982), (0x63b9, 3415), (0x63ba, 9489),
+    (0x63bb, 3416), (0x63bc, 17837), (0x63bd, 3417), (0x63be, 17847), (0x63bf, 3418), (0x63c0, 3419), (0x63c1, 3420), (0x63c2, 3421),
+    (0x63c3, 3422), (0x63c4, 17842), (0x63c5, 3423), (0x63c6, 17846), (0x63c7, 3424), (0x63c8, 3425), (0x63c9, 13649), (0x63ca, 3426),
+    (0x63cb, 3427), (0x63cc, 3428), (0x63cd, 16500), (0x63ce, 17844), (0x63cf, 12707), (0x63d0, 14410), (0x63d1, 3429), (0x63d2, 9474),
+    (0x63d3, 3430), (0x63d4, 3431), (0x63d5, 3432), (0x63d6, 15515), (0x63d7, 3433), (0x63d8, 3434), (0x63d9, 3435), (0x63da, 3436),
+    (0x63db, 3437), (0x63dc, 3438), (0x63dd, 3439), (0x63de, 17843), (0x63df, 3440), (0x63e0, 17840), (0x63e1, 14778), (0x63e2, 3441),
+    (0x63e3, 9792), (0x63e4, 3442), (0x63e5, 3443), (0x63e6, 3444), (0x63e7, 3445), (0x63e8, 3446), (0x63e9, 11886), (0x63ea, 11715),
+    (0x63eb, 3447), (0x63ec, 3448), (0x63ed, 11545), (0x63ee, 3449), (0x63ef, 3450), (0x63f0, 3451), (0x63f1, 3452), (0x63f2, 17838),
+    (0x63f3, 3453), (0x63f4, 15879), (0x63f5, 3454), (0x63f6, 17827), (0x63f7, 3455), (0x63f8, 17839), (0x63f9, 3456), (0x63fa, 3457),
+    (0x63fb, 3458), (0x63fc, 3459), (0x63fd, 12096), (0x63fe, 3460), (0x63ff, 17841), (0x6400, 9488), (0x6401, 10618), (0x6402, 12452),
+    (0x6403, 3461), (0x6404, 3462), (0x6405, 11528), (0x6406, 3463), (0x6407, 3464), (0x6408, 3465), (0x6409, 3466), (0x640a, 3467),
+    (0x640b, 17850), (0x640c, 17853), (0x640d, 3468), (0x640e, 3469), (0x640f, 9416), (0x6410, 9789), (0x6411, 3470), (0x6412, 3471),
+    (0x6413, 9859), (0x6414, 13781), (0x6415, 3472), (0x6416, 3473), (0x6417, 3474), (0x6418, 3475), (0x6419, 3476), (0x641a, 3477),
+    (0x641b, 17851), (0x641c, 14204), (0x641d, 3478), (0x641e, 10612), (0x641f, 3479), (0x6420, 17852), (0x6421, 17855), (0x6422, 3480),
+    (0x6423, 3481), (0x6424, 3482), (0x6425, 3483), (0x6426, 17854), (0x6427, 3484), (0x6428, 3485), (0x6429, 3486), (0x642a, 14379),
+    (0x642b, 3487), (0x642c, 9090), (0x642d, 9863), (0x642e, 3488), (0x642f, 3489), (0x6430, 3490), (0x6431, 3491), (0x6432, 3492),
+    (0x6433, 3493), (0x6434, 19121), (0x6435, 3494), (0x6436, 3495), (0x6437, 3496), (0x6438, 3497), (0x6439, 3498), (0x643a, 15120),
+    (0x643b, 3499), (0x643c, 3500), (0x643d, 9480), (0x643e, 3501), (0x643f, 20236), (0x6440, 3502), (0x6441, 17849), (0x6442, 3503),
+    (0x6443, 3504), (0x6444, 13842), (0x6445, 17848), (0x6446, 9083), (0x6447, 15486), (0x6448, 9302), (0x6449, 3505), (0x644a, 14360),
+    (0x644b, 3506), (0x644c, 3507), (0x644d, 3508), (0x644e, 3509), (0x644f, 3510), (0x6450, 3511), (0x6451, 3512), (0x6452, 17845),
+    (0x6453, 3513), (0x6454, 14159), (0x6455, 3514), (0x6456, 3515), (0x6457, 3516), (0x6458, 16065), (0x6459, 3517), (0x645a, 3518),
+    (0x645b, 3519), (0x645c, 3520), (0x645d, 3521), (0x645e, 17856), (0x645f, 3522), (0x6460, 3523), (0x6461, 3524), (0x6462, 3525),
+    (0x6463, 3526), (0x6464, 3527), (0x6465, 3528), (0x6466, 3529), (0x6467, 9846), (0x6468, 3530), (0x6469, 12831), (0x646a, 3531),
+    (0x646b, 3532), (0x646c, 3533), (0x646d, 17858), (0x646e, 3534), (0x646f, 3535), (0x6470, 3536), (0x6471, 3537), (0x6472, 3538),
+    (0x6473, 3539), (0x6474, 3540), (0x6475, 3541), (0x6476, 3542), (0x6477, 3543), (0x6478, 12729), (0x6479, 12826), (0x647a, 17956),
+    (0x647b, 3544), (0x647c, 3545), (0x647d, 3546), (0x647e, 3547), (0x647f, 3548), (0x6480, 3549), (0x6481, 3550), (0x6482, 12299),
+    (0x6483, 3551), (0x6484, 17857), (0x6485, 11764), (0x6486, 3552), (0x6487, 13223), (0x6488, 3553), (0x6489, 3554), (0x648a, 3555),
+    (0x648b, 3556), (0x648c, 3557), (0x648d, 3558), (0x648e, 3559), (0x648f, 3560), (0x6490, 3561), (0x6491, 9632), (0x6492, 13671),
+    (0x6493, 3562), (0x6494, 3563), (0x6495, 14181), (0x6496, 17859), (0x6497, 3564), (0x6498, 3565), (0x6499, 17959), (0x649a, 3566),
+    (0x649b, 3567), (0x649c, 3568), (0x649d, 3569), (0x649e, 16453), (0x649f, 3570), (0x64a0, 3571), (0x64a1, 3572), (0x64a2, 3573),
+    (0x64a3, 3574), (0x64a4, 9618), (0x64a5, 3575), (0x64a6, 3576), (0x64a7, 3577), (0x64a8, 3578), (0x64a9, 12290), (0x64aa, 3579),
+    (0x64ab, 3580), (0x64ac, 13438), (0x64ad, 9410), (0x64ae, 9858), (0x64af, 3581), (0x64b0, 16446), (0x64b1, 3582), (0x64b2, 3583),
+    (0x64b3, 3584), (0x64b4, 3585), (0x64b5, 12901), (0x64b6, 3586), (0x64b7, 17957), (0x64b8, 17958), (0x64b9, 3587), (0x64ba, 17960),
+    (0x64bb, 3588), (0x64bc, 10944), (0x64bd, 3589), (0x64be, 3590), (0x64bf, 3591), (0x64c0, 17961), (0x64c1, 3592), (0x64c2, 12127),
+    (0x64c3, 3593), (0x64c4, 3594), (0x64c5, 13810), (0x64c6, 3595), (0x64c7, 3596), (0x64c8, 3597), (0x64c9, 3598), (0x64ca, 3599),
+    (0x64cb, 3600), (0x64cc, 3601), (0x64cd, 9462), (0x64ce, 13465), (0x64cf, 3602), (0x64d0, 17962), (0x64d1, 3603), (0x64d2, 13455),
+    (0x64d3, 3604), (0x64d4, 3605), (0x64d5, 3606), (0x64d6, 3607), (0x64d7, 17963), (0x64d8, 20237), (0x64d9, 3608), (0x64da, 3609),
+    (0x64db, 3610), (0x64dc, 3611), (0x64dd, 3612), (0x64de, 14206), (0x64df, 3613), (0x64e0, 3614), (0x64e1, 3615), (0x64e2, 17965),
+    (0x64e3, 3616), (0x64e4, 17964), (0x64e5, 3617), (0x64e6, 9438), (0x64e7, 3618), (0x64e8, 3619), (0x64e9, 3620), (0x64ea, 3621),
+    (0x64eb, 3622), (0x64ec, 3623), (0x64ed, 3624), (0x64ee, 3625), (0x64ef, 3626), (0x64f0, 3627), (0x64f1, 3628), (0x64f2, 3629),
+    (0x64f3, 3630), (0x64f4, 3631), (0x64f5, 3632), (0x64f6, 3633), (0x64f7, 3634), (0x64f8, 3635), (0x64f9, 3636), (0x64fa, 3637),
+    (0x64fb, 3638), (0x64fc, 3639), (0x64fd, 3640), (0x64fe, 3641), (0x64ff, 3642), (0x6500, 13057), (0x6501, 3643), (0x6502, 3644),
+    (0x6503, 3645), (0x6504, 3646), (0x6505, 3647), (0x6506, 3648), (0x6507, 3649), (0x6508, 3650), (0x6509, 17966), (0x650a, 3651),
+    (0x650b, 3652), (0x650c, 3653), (0x650d, 3654), (0x650e, 3655), (0x650f, 3656), (0x6510, 3657), (0x6511, 3658), (0x6512, 15925),
+    (0x6513, 3659), (0x6514, 3660), (0x6515, 3661), (0x6516, 3662), (0x6517, 3663), (0x6518, 13618), (0x6519, 3664), (0x651a, 3665),
+    (0x651b, 3666), (0x651c, 3667), (0x651d, 3668), (0x651e, 3669), (0x651f, 3670), (0x6520, 3671), (0x6521, 3672), (0x6522, 3673),
+    (0x6523, 3674), (0x6524, 3675), (0x6525, 17967), (0x6526, 3676), (0x6527, 3677), (0x6528, 3678), (0x6529, 3679), (0x652a, 3680),
+    (0x652b, 11765), (0x652c, 3681), (0x652d, 3682), (0x652e, 17968), (0x652f, 16252), (0x6530, 3683), (0x6531, 3684), (0x6532, 3685),
+    (0x6533, 3686), (0x6534, 20068), (0x6535, 20257), (0x6536, 14018), (0x6537, 3687), (0x6538, 16717), (0x6539, 10581), (0x653a, 3688),
+    (0x653b, 10740), (0x653c, 3689), (0x653d, 3690), (0x653e, 10392), (0x653f, 16149), (0x6540, 3691), (0x6541, 3692), (0x6542, 3693),
+    (0x6543, 3694), (0x6544, 3695), (0x6545, 10777), (0x6546, 3696), (0x6547, 3697), (0x6548, 15112), (0x6549, 21990), (0x654a, 3698),
+    (0x654b, 3699), (0x654c, 10023), (0x654d, 3700), (0x654e, 3701), (0x654f, 12719), (0x6550, 3702), (0x6551, 11725), (0x6552, 3703),
+    (0x6553, 3704), (0x6554, 3705), (0x6555, 20258), (0x6556, 9054), (0x6557, 3706), (0x6558, 3707), (0x6559, 11539), (0x655a, 3708),
+    (0x655b, 12273), (0x655c, 3709), (0x655d, 9269), (0x655e, 9603), (0x655f, 3710), (0x6560, 3711), (0x6561, 3712), (0x6562, 10595),
+    (0x6563, 13777), (0x6564, 3713), (0x6565, 3714), (0x6566, 10221), (0x6567, 3715), (0x6568, 3716), (0x6569, 3717), (0x656a, 3718),
+    (0x656b, 20259), (0x656c, 11705), (0x656d, 3719), (0x656e, 3720), (0x656f, 3721), (0x6570, 14058), (0x6571, 3722), (0x6572, 13430),
+    (0x6573, 3723), (0x6574, 16146), (0x6575, 3724), (0x6576, 3725), (0x6577, 10438), (0x6578, 3726), (0x6579, 3727), (0x657a, 3728),
+    (0x657b, 3729), (0x657c, 3730), (0x657d, 3731), (0x657e, 3732), (0x657f, 3733), (0x6580, 3734), (0x6581, 3735), (0x6582, 3736),
+    (0x6583, 3737), (0x6584, 3738), (0x6585, 3739), (0x6586, 3740), (0x6587, 14761), (0x6588, 3741), (0x6589, 3742), (0x658a, 3743),
+    (0x658b, 16066), (0x658c, 9298), (0x658d, 3744), (0x658e, 3745), (0x658f, 3746), (0x6590, 20444), (0x6591, 9088), (0x6592, 3747),
+    (0x6593, 20446), (0x6594, 3748), (0x6595, 3749), (0x6596, 3750), (0x6597, 10188), (0x6598, 3751), (0x6599, 12302), (0x659a, 3752),
+    (0x659b, 22225), (0x659c, 15122), (0x659d, 3753), (0x659e, 3754), (0x659f, 16124), (0x65a0, 3755), (0x65a1, 14776), (0x65a2, 3756),
+    (0x65a3, 3757), (0x65a4, 11574), (0x65a5, 9661), (0x65a6, 3758), (0x65a7, 10556), (0x65a8, 3759), (0x65a9, 16077), (0x65aa, 3760),
+    (0x65ab, 20644), (0x65ac, 3761), (0x65ad, 10212), (0x65ae, 3762), (0x65af, 14180), (0x65b0, 15139), (0x65b1, 3763), (0x65b2, 3764),
+    (0x65b3, 3765), (0x65b4, 3766), (0x65b5, 3767), (0x65b6, 3768), (0x65b7, 3769), (0x65b8, 3770), (0x65b9, 10384), (0x65ba, 3771),
+    (0x65bb, 3772), (0x65bc, 20447), (0x65bd, 13974), (0x65be, 3773), (0x65bf, 3774), (0x65c0, 3775), (0x65c1, 13067), (0x65c2, 3776),
+    (0x65c3, 20450), (0x65c4, 20449), (0x65c5, 12480), (0x65c6, 20448), (0x65c7, 3777), (0x65c8, 3778), (0x65c9, 3779), (0x65ca, 3780),
+    (0x65cb, 15198), (0x65cc, 20451), (0x65cd, 3781), (0x65ce, 20452), (0x65cf, 16504), (0x65d0, 3782), (0x65d1, 3783), (0x65d2, 20453),
+    (0x65d3, 3784), (0x65d4, 3785), (0x65d5, 3786), (0x65d6, 20454), (0x65d7, 13281), (0x65d8, 3787), (0x65d9, 3788), (0x65da, 3789),
+    (0x65db, 3790), (0x65dc, 3791), (0x65dd, 3792), (0x65de, 3793), (0x65df, 3794), (0x65e0, 14787), (0x65e1, 3795), (0x65e2, 11345),
+    (0x65e3, 3796), (0x65e4, 3797), (0x65e5, 13638), (0x65e6, 9984), (0x65e7, 11726), (0x65e8, 16273), (0x65e9, 15936), (0x65ea, 3798),
+    (0x65eb, 3799), (0x65ec, 15309), (0x65ed, 15186), (0x65ee, 20069), (0x65ef, 20070), (0x65f0, 20071), (0x65f1, 10946), (0x65f2, 3800),
+    (0x65f3, 3801), (0x65f4, 3802), (0x65f5, 3803), (0x65f6, 13982), (0x65f7, 11960), (0x65f8, 3804), (0x65f9, 3805), (0x65fa, 14625),
+    (0x65fb, 3806), (0x65fc, 3807), (0x65fd, 3808), (0x65fe, 3809), (0x65ff, 3810), (0x6600, 20077), (0x6601, 3811), (0x6602, 9051),
+    (0x6603, 20075), (0x6604, 3812), (0x6605, 3813), (0x6606, 12070), (0x6607, 3814), (0x6608, 3815), (0x6609, 3816), (0x660a, 20072),
+    (0x660b, 3817), (0x660c, 9498), (0x660d, 3818), (0x660e, 12722), (0x660f, 11187), (0x6610, 3819), (0x6611, 3820), (0x6612, 3821),
+    (0x6613, 15540), (0x6614, 14809), (0x6615, 20076), (0x6616, 3822), (0x6617, 3823), (0x6618, 3824), (0x6619, 20073), (0x661a, 3825),
+    (0x661b, 3826), (0x661c, 3827), (0x661d, 20080), (0x661e, 3828), (0x661f, 15144), (0x6620, 15694), (0x6621, 3829), (0x6622, 3830),
+    (0x6623, 3831), (0x6624, 3832), (0x6625, 9811), (0x6626, 3833), (0x6627, 12668), (0x6628, 16517), (0x6629, 3834), (0x662a, 3835),
+    (0x662b, 3836), (0x662c, 3837), (0x662d, 16104), (0x662e, 3838), (0x662f, 14004), (0x6630, 3839), (0x6631, 20082), (0x6632, 3840),
+    (0x6633, 3841), (0x6634, 20081), (0x6635, 20084), (0x6636, 20083), (0x6637, 3842), (0x6638, 3843), (0x6639, 3844), (0x663a, 3845),
+    (0x663b, 3846), (0x663c, 16316), (0x663d, 3847), (0x663e, 14967), (0x663f, 3848), (0x6640, 3849), (0x6641, 20088), (0x6642, 3850),
+    (0x6643, 11161), (0x6644, 3851), (0x6645, 3852), (0x6646, 3853), (0x6647, 3854), (0x6648, 3855), (0x6649, 3856), (0x664a, 3857),
+    (0x664b, 11585), (0x664c, 13821), (0x664d, 3858), (0x664e, 3859), (0x664f, 20089), (0x6650, 3860), (0x6651, 3861), (0x6652, 13800),
+    (0x6653, 15009), (0x6654, 20087), (0x6655, 15911), (0x6656, 20090), (0x6657, 20092), (0x6658, 3862), (0x6659, 3863), (0x665a, 14612),
+    (0x665b, 3864), (0x665c, 3865), (0x665d, 3866), (0x665e, 3867), (0x665f, 20086), (0x6660, 3868), (0x6661, 20091), (0x6662, 3869),
+    (0x6663, 3870), (0x6664, 14803), (0x6665, 3871), (0x6666, 11177), (0x6667, 3872), (0x6668, 9626), (0x6669, 3873), (0x666a, 3874),
+    (0x666b, 3875), (0x666c, 3876), (0x666d, 3877), (0x666e, 13258), (0x666f, 11701), (0x6670, 14815), (0x6671, 3878), (0x6672, 3879),
+    (0x6673, 3880), (0x6674, 13466), (0x6675, 3881), (0x6676, 11692), (0x6677, 20093), (0x6678, 3882), (0x6679, 3883), (0x667a, 16284),
+    (0x667b, 3884), (0x667c, 3885), (0x667d, 3886), (0x667e, 12287), (0x667f, 3887), (0x6680, 3888), (0x6681, 3889), (0x6682, 15926),
+    (0x6683, 3890), (0x6684, 20094), (0x6685, 3891), (0x6686, 3892), (0x6687, 14945), (0x6688, 3893), (0x6689, 3894), (0x668a, 3895),
+    (0x668b, 3896), (0x668c, 20095), (0x668d, 3897), (0x668e, 3898), (0x668f, 3899), (0x6690, 3900), (0x6691, 14043), (0x6692, 3901),
+    (0x6693, 3902), (0x6694, 3903), (0x6695, 3904), (0x6696, 13030), (0x6697, 9046), (0x6698, 3905), (0x6699, 3906), (0x669a, 3907),
+    (0x669b, 3908), (0x669c, 3909), (0x669d, 20097), (0x669e, 3910), (0x669f, 3911), (0x66a0, 3912), (0x66a1, 3913), (0x66a2, 3914),
+    (0x66a3, 3915), (0x66a4, 3916), (0x66a5, 3917), (0x66a6, 3918), (0x66a7, 20096), (0x66a8, 22008), (0x66a9, 3919), (0x66aa, 3920),
+    (0x66ab, 3921), (0x66ac, 3922), (0x66ad, 3923), (0x66ae, 12851), (0x66af, 3924), (0x66b0, 3925), (0x66b1, 3926), (0x66b2, 3927),
+    (0x66b3, 3928), (0x66b4, 9224), (0x66b5, 3929), (0x66b6, 3930), (0x66b7, 3931), (0x66b8, 3932), (0x66b9, 19158), (0x66ba, 3933),
+    (0x66bb, 3934), (0x66bc, 3935), (0x66bd, 3936), (0x66be, 20098), (0x66bf, 3937), (0x66c0, 3938), (0x66c1, 3939), (0x66c2, 3940),
+    (0x66c3, 3941), (0x66c4, 3942), (0x66c5, 3943), (0x66c6, 3944), (0x66c7, 3945), (0x66c8, 3946), (0x66c9, 3947), (0x66ca, 3948),
+    (0x66cb, 3949), (0x66cc, 3950), (0x66cd, 3951), (0x66ce, 3952), (0x66cf, 3953), (0x66d0, 3954), (0x66d1, 3955), (0x66d2, 3956),
+    (0x66d3, 3957), (0x66d4, 3958), (0x66d5, 3959), (0x66d6, 3960), (0x66d7, 3961), (0x66d8, 3962), (0x66d9, 14044), (0x66da, 3963),
+    (0x66db, 20099), (0x66dc, 20100), (0x66dd, 13261), (0x66de, 3964), (0x66df, 3965), (0x66e0, 3966), (0x66e1, 3967), (0x66e2, 3968),
+    (0x66e3, 3969), (0x66e4, 3970), (0x66e5, 3971), (0x66e6, 20101), (0x66e7, 3972), (0x66e8, 3973), (0x66e9, 20102), (0x66ea, 3974),
+    (0x66eb, 3975), (0x66ec, 3976), (0x66ed, 3977), (0x66ee, 3978), (0x66ef, 3979), (0x66f0, 15892), (0x66f1, 3980), (0x66f2, 13485),
+    (0x66f3, 15508), (0x66f4, 10637), (0x66f5, 3981), (0x66f6, 3982), (0x66f7, 20079), (0x66f8, 3983), (0x66f9, 9465), (0x66fa, 3984),
+    (0x66fb, 3985), (0x66fc, 12537), (0x66fd, 3986), (0x66fe, 15953), (0x66ff, 14415), (0x6700, 16513), (0x6701, 3987), (0x6702, 3988),
+    (0x6703, 3989), (0x6704, 3990), (0x6705, 3991), (0x6706, 3992), (0x6707, 3993), (0x6708, 15899), (0x6709, 15723), (0x670a, 20270),
+    (0x670b, 13098), (0x670c, 3994), (0x670d, 10449), (0x670e, 3995), (0x670f, 3996), (0x6710, 20287), (0x6711, 3997), (0x6712, 3998),
+    (0x6713, 3999), (0x6714, 14178), (0x6715, 20297), (0x6716, 4000), (0x6717, 12107), (0x6718, 4001), (0x6719, 4002), (0x671a, 4003),
+    (0x671b, 14626), (0x671c, 4004), (0x671d, 9610), (0x671e, 4005), (0x671f, 13263), (0x6720, 4006), (0x6721, 4007), (0x6722, 4008),
+    (0x6723, 4009), (0x6724, 4010), (0x6725, 4011), (0x6726, 20327), (0x6727, 4012), (0x6728, 12855), (0x6729, 4013), (0x672a, 14745),
+    (0x672b, 12834), (0x672c, 9245), (0x672d, 15958), (0x672e, 4014), (0x672f, 14050), (0x6730, 4015), (0x6731, 16321), (0x6732, 4016),
+    (0x6733, 4017), (0x6734, 13256), (0x6735, 10233), (0x6736, 4018), (0x6737, 4019), (0x6738, 4020), (0x6739, 4021), (0x673a, 11205),
+    (0x673b, 4022), (0x673c, 4023), (0x673d, 15169), (0x673e, 4024), (0x673f, 4025), (0x6740, 13792), (0x6741, 4026), (0x6742, 15916),
+    (0x6743, 13593), (0x6744, 4027), (0x6745, 4028), (0x6746, 10588), (0x6747, 4029), (0x6748, 19695), (0x6749, 13803), (0x674a, 4030),
+    (0x674b, 4031), (0x674c, 19692), (0x674d, 4032), (0x674e, 12143), (0x674f, 15156), (0x6750, 9441), (0x6751, 9854), (0x6752, 4033),
+    (0x6753, 19693), (0x6754, 4034), (0x6755, 4035), (0x6756, 16095), (0x6757, 4036), (0x6758, 4037), (0x6759, 4038), (0x675a, 4039),
+    (0x675b, 4040), (0x675c, 10202), (0x675d, 4041), (0x675e, 19694), (0x675f, 14053), (0x6760, 10605), (0x6761, 14430), (0x6762, 4042),
+    (0x6763, 4043), (0x6764, 4044), (0x6765, 12085), (0x6766, 4045), (0x6767, 4046), (0x6768, 15373), (0x6769, 19696), (0x676a, 19699),
+    (0x676b, 4047), (0x676c, 4048), (0x676d, 10953), (0x676e, 4049), (0x676f, 9228), (0x6770, 11555), (0x6771, 4050), (0x6772, 20074),
+    (0x6773, 19700), (0x6774, 4051), (0x6775, 19703), (0x6776, 4052), (0x6777, 19708), (0x6778, 4053), (0x6779, 4054), (0x677a, 4055),
+    (0x677b, 4056), (0x677c, 19709), (0x677d, 4057), (0x677e, 14196), (0x677f, 9094), (0x6780, 4058), (0x6781, 11316), (0x6782, 4059),
+    (0x6783, 4060), (0x6784, 10760), (0x6785, 4061), (0x6786, 4062), (0x6787, 19698), (0x6788, 4063), (0x6789, 14622), (0x678a, 4064),
+    (0x678b, 19707), (0x678c, 4065), (0x678d, 4066), (0x678e, 4067), (0x678f, 4068), (0x6790, 14811), (0x6791, 4069), (0x6792, 4070),
+    (0x6793, 4071), (0x6794, 4072), (0x6795, 16132), (0x6796, 4073), (0x6797, 12309), (0x6798, 19701), (0x6799, 4074), (0x679a, 12657),
+    (0x679b, 4075), (0x679c, 10826), (0x679d, 16251), (0x679e, 19705), (0x679f, 4076), (0x67a0, 4077), (0x67a1, 4078), (0x67a2, 14029),
+    (0x67a3, 15935), (0x67a4, 4079), (0x67a5, 19697), (0x67a6, 4080), (0x67a7, 19702), (0x67a8, 19704), (0x67a9, 4081), (0x67aa, 13420),
+    (0x67ab, 10422), (0x67ac, 4082), (0x67ad, 19706), (0x67ae, 4083), (0x67af, 11936), (0x67b0, 19715), (0x67b1, 4084), (0x67b2, 4085),
+    (0x67b3, 19720), (0x67b4, 4086), (0x67b5, 19718), (0x67b6, 11365), (0x67b7, 11352), (0x67b8, 19724), (0x67b9, 4087), (0x67ba, 4088),
+    (0x67bb, 4089), (0x67bc, 4090), (0x67bd, 4091), (0x67be, 4092), (0x67bf, 4093), (0x67c0, 4094), (0x67c1, 19727), (0x67c2, 4095),
+    (0x67c3, 19723), (0x67c4, 9305), (0x67c5, 4096), (0x67c6, 4097), (0x67c7, 4098), (0x67c8, 4099), (0x67c9, 4100), (0x67ca, 4101),
+    (0x67cb, 4102), (0x67cc, 4103), (0x67cd, 4104), (0x67ce, 4105), (0x67cf, 9081), (0x67d0, 12844), (0x67d1, 10589), (0x67d2, 13271),
+    (0x67d3, 13615), (0x67d4, 13650), (0x67d5, 4106), (0x67d6, 4107), (0x67d7, 4108), (0x67d8, 19712), (0x67d9, 19717), (0x67da, 19719),
+    (0x67db, 4109), (0x67dc, 10816), (0x67dd, 19721), (0x67de, 16520), (0x67df, 4110), (0x67e0, 12916), (0x67e1, 4111), (0x67e2, 19725),
+    (0x67e3, 4112), (0x67e4, 4113), (0x67e5, 9478), (0x67e6, 4114), (0x67e7, 4115), (0x67e8, 4116), (0x67e9, 19714), (0x67ea, 4117),
+    (0x67eb, 4118), (0x67ec, 11382), (0x67ed, 4119), (0x67ee, 4120), (0x67ef, 11909), (0x67f0, 19710), (0x67f1, 16334), (0x67f2, 4121),
+    (0x67f3, 12343), (0x67f4, 9486), (0x67f5, 4122), (0x67f6, 4123), (0x67f7, 4124), (0x67f8, 4125), (0x67f9, 4126), (0x67fa, 4127),
+    (0x67fb, 4128), (0x67fc, 4129), (0x67fd, 19728), (0x67fe, 4130), (0x67ff, 13998), (0x6800, 19722), (0x6801, 4131), (0x6802, 4132),
+    (0x6803, 4133), (0x6804, 4134), (0x6805, 16059), (0x6806, 4135), (0x6807, 9289), (0x6808, 16082), (0x6809, 19711), (0x680a, 19713),
+    (0x680b, 10181), (0x680c, 19716), (0x680d, 4136), (0x680e, 19726), (0x680f, 12089), (0x6810, 4137), (0x6811, 14052), (0x6812, 4138),
+    (0x6813, 14163), (0x6814, 4139), (0x6815, 4140), (0x6816, 13265), (0x6817, 12150), (0x6818, 4141), (0x6819, 4142), (0x681a, 4143),
+    (0x681b, 4144), (0x681c, 4145), (0x681d, 19738), (0x681e, 4146), (0x681f, 4147), (0x6820, 4148), (0x6821, 15108), (0x6822, 4149),
+    (0x6823, 4150), (0x6824, 4151), (0x6825, 4152), (0x6826, 4153), (0x6827, 4154), (0x6828, 4155), (0x6829, 19747), (0x682a, 16319),
+    (0x682b, 4156), (0x682c, 4157), (0x682d, 4158), (0x682e, 4159), (0x682f, 4160), (0x6830, 4161), (0x6831, 4162), (0x6832, 19729),
+    (0x6833, 19730), (0x6834, 4163), (0x6835, 4164), (0x6836, 4165), (0x6837, 15384), (0x6838, 10968), (0x6839, 10634), (0x683a, 4166),
+    (0x683b, 4167), (0x683c, 10626), (0x683d, 15917), (0x683e, 19744), (0x683f, 4168), (0x6840, 19743), (0x6841, 19741), (0x6842, 10815),
+    (0x6843, 14395), (0x6844, 19735), (0x6845, 14731), (0x6846, 11957), (0x6847, 4169), (0x6848, 9049), (0x6849, 19746), (0x684a, 19745),
+    (0x684b, 4170), (0x684c, 16467), (0x684d, 4171), (0x684e, 19733), (0x684f, 4172), (0x6850, 14544), (0x6851, 13778), (0x6852, 4173),
+    (0x6853, 11139), (0x6854, 11554), (0x6855, 19739), (0x6856, 4174), (0x6857, 4175), (0x6858, 4176), (0x6859, 4177), (0x685a, 4178),
+    (0x685b, 4179), (0x685c, 4180), (0x685d, 4181), (0x685e, 4182), (0x685f, 4183), (0x6860, 19731), (0x6861, 19732), (0x6862, 19734),
+    (0x6863, 9996), (0x6864, 19736), (0x6865, 13432), (0x6866, 19740), (0x6867, 19742), (0x6868, 11511), (0x6869, 16449), (0x686a, 4184),
+    (0x686b, 19753), (0x686c, 4185), (0x686d, 4186), (0x686e, 4187), (0x686f, 4188), (0x6870, 4189), (0x6871, 4190), (0x6872, 4191),
+    (0x6873, 4192), (0x6874, 19750), (0x6875, 4193), (0x6876, 14551), (0x6877, 19751), (0x6878, 4194), (0x6879, 4195), (0x687a, 4196),
+    (0x687b, 4197), (0x687c, 4198), (0x687d, 4199), (0x687e, 4200), (0x687f, 4201), (0x6880, 4202), (0x6881, 12281), (0x6882, 4203),
+    (0x6883, 19737), (0x6884, 4204), (0x6885, 12658), (0x6886, 9105), (0x6887, 4205), (0x6888, 4206), (0x6889, 4207), (0x688a, 4208),
+    (0x688b, 4209), (0x688c, 4210), (0x688d, 4211), (0x688e, 4212), (0x688f, 19749), (0x6890, 4213), (0x6891, 4214), (0x6892, 4215),
+    (0x6893, 19752), (0x6894, 4216), (0x6895, 4217), (0x6896, 4218), (0x6897, 10738), (0x6898, 4219), (0x6899, 4220), (0x689a, 4221),
+    (0x689b, 4222), (0x689c, 4223), (0x689d, 4224), (0x689e, 4225), (0x689f, 4226), (0x68a0, 4227), (0x68a1, 4228), (0x68a2, 13825),
+    (0x68a3, 4229), (0x68a4, 4230), (0x68a5, 4231), (0x68a6, 12681), (0x68a7, 14789), (0x68a8, 12135), (0x68a9, 4232), (0x68aa, 4233),
+    (0x68ab, 4234), (0x68ac, 4235), (0x68ad, 14238), (0x68ae, 4236), (0x68af, 14406), (0x68b0, 15126), (0x68b1, 4237), (0x68b2, 4238),
+    (0x68b3, 14030), (0x68b4, 4239), (0x68b5, 19748), (0x68b6, 4240), (0x68b7, 4241), (0x68b8, 4242), (0x68b9, 4243), (0x68ba, 4244),
+    (0x68bb, 4245), (0x68bc, 4246), (0x68bd, 4247), (0x68be, 4248), (0x68bf, 4249), (0x68c0, 11381), (0x68c1, 4250), (0x68c2, 19754),
+    (0x68c3, 4251), (0x68c4, 4252), (0x68c5, 4253), (0x68c6, 4254), (0x68c7, 4255), (0x68c8, 4256), (0x68c9, 12697), (0x68ca, 4257),
+    (0x68cb, 13274), (0x68cc, 4258), (0x68cd, 10822), (0x68ce, 4259), (0x68cf, 4260), (0x68d0, 4261), (0x68d1, 4262), (0x68d2, 9109),
+    (0x68d3, 4263), (0x68d4, 4264), (0x68d5, 16491), (0x68d6, 4265), (0x68d7, 4266), (0x68d8, 11317), (0x68d9, 4267), (0x68da, 13094),
+    (0x68db, 4268), (0x68dc, 4269), (0x68dd, 4270), (0x68de, 4271), (0x68df, 4272), (0x68e0, 14381), (0x68e1, 4273), (0x68e2, 4274),
+    (0x68e3, 19861), (0x68e4, 4275), (0x68e5, 4276), (0x68e6, 4277), (0x68e7, 4278), (0x68e8, 4279), (0x68e9, 4280), (0x68ea, 4281),
+    (0x68eb, 4282), (0x68ec, 4283), (0x68ed, 4284), (0x68ee, 13788), (0x68ef, 4285), (0x68f0, 19857), (0x68f1, 12131), (0x68f2, 4286),
+    (0x68f3, 4287), (0x68f4, 4288), (0x68f5, 11910), (0x68f6, 4289), (0x68f7, 4290), (0x68f8, 4291), (0x68f9, 19759), (0x68fa, 10790),
+    (0x68fb, 4292), (0x68fc, 19756), (0x68fd, 4293), (0x68fe, 4294), (0x68ff, 4295), (0x6900, 4296), (0x6901, 19859), (0x6902, 4297),
+    (0x6903, 4298), (0x6904, 4299), (0x6905, 15531), (0x6906, 4300), (0x6907, 4301), (0x6908, 4302), (0x6909, 4303), (0x690a, 4304),
+    (0x690b, 19858), (0x690c, 4305), (0x690d, 16263), (0x690e, 16456), (0x690f, 4306), (0x6910, 19862), (0x6911, 4307), (0x6912, 11518),
+    (0x6913, 4308), (0x6914, 4309), (0x6915, 4310), (0x6916, 4311), (0x6917, 4312), (0x6918, 4313), (0x6919, 4314), (0x691a, 4315),
+    (0x691b, 4316), (0x691c, 4317), (0x691d, 4318), (0x691e, 4319), (0x691f, 19757), (0x6920, 19758), (0x6921, 4320), (0x6922, 4321),
+    (0x6923, 4322), (0x6924, 19856), (0x6925, 4323), (0x6926, 4324), (0x6927, 4325), (0x6928, 4326), (0x6929, 4327), (0x692a, 4328),
+    (0x692b, 4329), (0x692c, 4330), (0x692d, 14589), (0x692e, 4331), (0x692f, 4332), (0x6930, 15497), (0x6931, 4333), (0x6932, 4334),
+    (0x6933, 4335), (0x6934, 19873), (0x6935, 4336), (0x6936, 4337), (0x6937, 4338), (0x6938, 4339), (0x6939, 19864), (0x693a, 4340),
+    (0x693b, 4341), (0x693c, 4342), (0x693d, 9795), (0x693e, 4343), (0x693f, 9812), (0x6940, 4344), (0x6941, 4345), (0x6942, 19866),
+    (0x6943, 4346), (0x6944, 4347), (0x6945, 4348), (0x6946, 4349), (0x6947, 4350), (0x6948, 4351), (0x6949, 4352), (0x694a, 4353),
+    (0x694b, 4354), (0x694c, 4355), (0x694d, 4356), (0x694e, 4357), (0x694f, 4358), (0x6950, 4359), (0x6951, 4360), (0x6952, 4361),
+    (0x6953, 4362), (0x6954, 15113), (0x6955, 4363), (0x6956, 4364), (0x6957, 19860), (0x6958, 4365), (0x6959, 4366), (0x695a, 9689),
+    (0x695b, 4367), (0x695c, 4368), (0x695d, 19867), (0x695e, 12132), (0x695f, 4369), (0x6960, 19865), (0x6961, 4370), (0x6962, 4371),
+    (0x6963, 19880), (0x6964, 4372), (0x6965, 4373), (0x6966, 19879), (0x6967, 4374), (0x6968, 4375), (0x6969, 4376), (0x696a, 4377),
+    (0x696b, 19869), (0x696c, 4378), (0x696d, 4379), (0x696e, 19755), (0x696f, 4380), (0x6970, 4381), (0x6971, 19863), (0x6972, 4382),
+    (0x6973, 4383), (0x6974, 4384), (0x6975, 4385), (0x6976, 4386), (0x6977, 11887), (0x6978, 19872), (0x6979, 19881), (0x697a, 4387),
+    (0x697b, 4388), (0x697c, 12450), (0x697d, 4389), (0x697e, 4390), (0x697f, 4391), (0x6980, 19870), (0x6981, 4392), (0x6982, 10582),
+    (0x6983, 4393), (0x6984, 19868), (0x6985, 4394), (0x6986, 15735), (0x6987, 19875), (0x6988, 19876), (0x6989, 19878), (0x698a, 4395),
+    (0x698b, 4396), (0x698c, 4397), (0x698d, 19894), (0x698e, 4398), (0x698f, 4399), (0x6990, 4400), (0x6991, 4401), (0x6992, 4402),
+    (0x6993, 4403), (0x6994, 12103), (0x6995, 19892), (0x6996, 4404), (0x6997, 4405), (0x6998, 19871), (0x6999, 4406), (0x699a, 4407),
+    (0x699b, 19882), (0x699c, 9106), (0x699d, 4408), (0x699e, 4409), (0x699f, 4410), (0x69a0, 4411), (0x69a1, 4412), (0x69a2, 4413),
+    (0x69a3, 4414), (0x69a4, 4415), (0x69a5, 4416), (0x69a6, 4417), (0x69a7, 19883), (0x69a8, 16060), (0x69a9, 4418), (0x69aa, 4419),
+    (0x69ab, 19885), (0x69ac, 4420), (0x69ad, 19886), (0x69ae, 4421), (0x69af, 4422), (0x69b0, 4423), (0x69b1, 19888), (0x69b2, 4424),
+    (0x69b3, 4425), (0x69b4, 12336), (0x69b5, 4426), (0x69b6, 4427), (0x69b7, 13607), (0x69b8, 4428), (0x69b9, 4429), (0x69ba, 4430),
+    (0x69bb, 19884), (0x69bc, 4431), (0x69bd, 4432), (0x69be, 4433), (0x69bf, 4434), (0x69c0, 4435), (0x69c1, 19889), (0x69c2, 4436),
+    (0x69c3, 4437), (0x69c4, 4438), (0x69c5, 4439), (0x69c6, 4440), (0x69c7, 4441), (0x69c8, 4442), (0x69c9, 4443), (0x69ca, 19890),
+    (0x69cb, 4444), (0x69cc, 19874), (0x69cd, 4445), (0x69ce, 19877), (0x69cf, 4446), (0x69d0, 11132), (0x69d1, 4447), (0x69d2, 4448),
+    (0x69d3, 4449), (0x69d4, 19887), (0x69d5, 4450), (0x69d6, 4451), (0x69d7, 4452), (0x69d8, 4453), (0x69d9, 4454), (0x69da, 4455),
+    (0x69db, 11392), (0x69dc, 4456), (0x69dd, 4457), (0x69de, 4458), (0x69df, 19891), (0x69e0, 19893), (0x69e1, 4459), (0x69e2, 4460),
+    (0x69e3, 4461), (0x69e4, 4462), (0x69e5, 4463), (0x69e6, 4464), (0x69e7, 4465), (0x69e8, 4466), (0x69e9, 4467), (0x69ea, 4468),
+    (0x69eb, 4469), (0x69ec, 4470), (0x69ed, 19897), (0x69ee, 4471), (0x69ef, 4472), (0x69f0, 4473), (0x69f1, 4474), (0x69f2, 19901),
+    (0x69f3, 4475), (0x69f4, 4476), (0x69f5, 4477), (0x69f6, 4478), (0x69f7, 4479), (0x69f8, 4480), (0x69f9, 4481), (0x69fa, 4482),
+    (0x69fb, 4483), (0x69fc, 4484), (0x69fd, 9464), (0x69fe, 4485), (0x69ff, 19895), (0x6a00, 4486), (0x6a01, 4487), (0x6a02, 4488),
+    (0x6a03, 4489), (0x6a04, 4490), (0x6a05, 4491), (0x6a06, 4492), (0x6a07, 4493), (0x6a08, 4494), (0x6a09, 4495), (0x6a0a, 10369),
+    (0x6a0b, 4496), (0x6a0c, 4497), (0x6a0d, 4498), (0x6a0e, 4499), (0x6a0f, 4500), (0x6a10, 4501), (0x6a11, 4502), (0x6a12, 4503),
+    (0x6a13, 4504), (0x6a14, 4505), (0x6a15, 4506), (0x6a16, 4507), (0x6a17, 19898), (0x6a18, 19899), (0x6a19, 4508), (0x6a1a, 4509),
+    (0x6a1b, 4510), (0x6a1c, 4511), (0x6a1d, 4512), (0x6a1e, 4513), (0x6a1f, 16088), (0x6a20, 4514), (0x6a21, 12828), (0x6a22, 4515),
+    (0x6a23, 4516), (0x6a24, 4517), (0x6a25, 4518), (0x6a26, 4519), (0x6a27, 4520), (0x6a28, 19911), (0x6a29, 4521), (0x6a2a, 10990),
+    (0x6a2b, 4522), (0x6a2c, 4523), (0x6a2d, 4524), (0x6a2e, 4525), (0x6a2f, 19896), (0x6a30, 4526), (0x6a31, 15678), (0x6a32, 4527),
+    (0x6a33, 4528), (0x6a34, 4529), (0x6a35, 19907), (0x6a36, 4530), (0x6a37, 4531), (0x6a38, 4532), (0x6a39, 4533), (0x6a3a, 4534),
+    (0x6a3b, 4535), (0x6a3c, 4536), (0x6a3d, 19910), (0x6a3e, 19903), (0x6a3f, 4537), (0x6a40, 4538), (0x6a41, 4539), (0x6a42, 4540),
+    (0x6a43, 4541), (0x6a44, 19902), (0x6a45, 4542), (0x6a46, 4543), (0x6a47, 13428), (0x6a48, 4544), (0x6a49, 4545), (0x6a4a, 4546),
+    (0x6a4b, 4547), (0x6a4c, 4548), (0x6a4d, 4549), (0x6a4e, 4550), (0x6a4f, 4551), (0x6a50, 19905), (0x6a51, 4552), (0x6a52, 4553),
+    (0x6a53, 4554), (0x6a54, 4555), (0x6a55, 4556), (0x6a56, 4557), (0x6a57, 4558), (0x6a58, 19912), (0x6a59, 9635), (0x6a5a, 4559),
+    (0x6a5b, 19906), (0x6a5c, 4560), (0x6a5d, 4561), (0x6a5e, 4562), (0x6a5f, 4563), (0x6a60, 4564), (0x6a61, 14995), (0x6a62, 4565),
+    (0x6a63, 4566), (0x6a64, 4567), (0x6a65, 19900), (0x6a66, 4568), (0x6a67, 4569), (0x6a68, 4570), (0x6a69, 4571), (0x6a6a, 4572),
+    (0x6a6b, 4573), (0x6a6c, 4574), (0x6a6d, 4575), (0x6a6e, 4576), (0x6a6f, 4577), (0x6a70, 4578), (0x6a71, 9682), (0x6a72, 4579),
+    (0x6a73, 4580), (0x6a74, 4581), (0x6a75, 4582), (0x6a76, 4583), (0x6a77, 4584), (0x6a78, 4585), (0x6a79, 19909), (0x6a7a, 4586),
+    (0x6a7b, 4587), (0x6a7c, 19913), (0x6a7d, 4588), (0x6a7e, 4589), (0x6a7f, 4590), (0x6a80, 14365), (0x6a81, 4591), (0x6a82, 4592),
+    (0x6a83, 4593), (0x6a84, 14928), (0x6a85, 4594), (0x6a86, 4595), (0x6a87, 4596), (0x6a88, 4597), (0x6a89, 4598), (0x6a8a, 4599),
+    (0x6a8b, 4600), (0x6a8c, 4601), (0x6a8d, 4602), (0x6a8e, 19908), (0x6a8f, 4603), (0x6a90, 19915), (0x6a91, 19914), (0x6a92, 4604),
+    (0x6a93, 4605), (0x6a94, 4606), (0x6a95, 4607), (0x6a96, 4608), (0x6a97, 19917), (0x6a98, 4609), (0x6a99, 4610), (0x6a9a, 4611),
+    (0x6a9b, 4612), (0x6a9c, 4613), (0x6a9d, 4614), (0x6a9e, 4615), (0x6a9f, 4616), (0x6aa0, 19904), (0x6aa1, 4617), (0x6aa2, 4618),
+    (0x6aa3, 4619), (0x6aa4, 4620), (0x6aa5, 4621), (0x6aa6, 4622), (0x6aa7, 4623), (0x6aa8, 4624), (0x6aa9, 19916), (0x6aaa, 4625),
+    (0x6aab, 19918), (0x6aac, 12677), (0x6aad, 4626), (0x6aae, 4627), (0x6aaf, 4628), (0x6ab0, 4629), (0x6ab1, 4630), (0x6ab2, 4631),
+    (0x6ab3, 4632), (0x6ab4, 4633), (0x6ab5, 4634), (0x6ab6, 4635), (0x6ab7, 4636), (0x6ab8, 4637), (0x6ab9, 4638), (0x6aba, 4639),
+    (0x6abb, 4640), (0x6abc, 4641), (0x6abd, 4642), (0x6abe, 4643), (0x6abf, 4644), (0x6ac0, 4645), (0x6ac1, 4646), (0x6ac2, 4647),
+    (0x6ac3, 4648), (0x6ac4, 4649), (0x6ac5, 4650), (0x6ac6, 4651), (0x6ac7, 4652), (0x6ac8, 4653), (0x6ac9, 4654), (0x6aca, 4655),
+    (0x6acb, 4656), (0x6acc, 4657), (0x6acd, 4658), (0x6ace, 4659), (0x6acf, 4660), (0x6ad0, 4661), (0x6ad1, 4662), (0x6ad2, 4663),
+    (0x6ad3, 4664), (0x6ad4, 4665), (0x6ad5, 4666), (0x6ad6, 4667), (0x6ad7, 4668), (0x6ad8, 4669), (0x6ad9, 4670), (0x6ada, 4671),
+    (0x6adb, 4672), (0x6adc, 4673), (0x6add, 4674), (0x6ade, 4675), (0x6adf, 4676), (0x6ae0, 4677), (0x6ae1, 4678), (0x6ae2, 4679),
+    (0x6ae3, 4680), (0x6ae4, 4681), (0x6ae5, 4682), (0x6ae6, 4683), (0x6ae7, 4684), (0x6ae8, 4685), (0x6ae9, 4686), (0x6aea, 4687),
+    (0x6aeb, 4688), (0x6aec, 4689), (0x6aed, 4690), (0x6aee, 4691), (0x6aef, 4692), (0x6af0, 4693), (0x6af1, 4694), (0x6af2, 4695),
+    (0x6af3, 4696), (0x6af4, 4697), (0x6af5, 4698), (0x6af6, 4699), (0x6af7, 4700), (0x6af8, 4701), (0x6af9, 4702), (0x6afa, 4703),
+    (0x6afb, 4704), (0x6afc, 4705), (0x6afd, 4706), (0x6afe, 4707), (0x6aff, 4708), (0x6b00, 4709), (0x6b01, 4710), (0x6b02, 4711),
+    (0x6b03, 4712), (0x6b04, 4713), (0x6b05, 4714), (0x6b06, 4715), (0x6b07, 4716), (0x6b08, 4717), (0x6b09, 4718), (0x6b0a, 4719),
+    (0x6b0b, 4720), (0x6b0c, 4721), (0x6b0d, 4722), (0x6b0e, 4723), (0x6b0f, 4724), (0x6b10, 4725), (0x6b11, 4726), (0x6b12, 4727),
+    (0x6b13, 4728), (0x6b14, 4729), (0x6b15, 4730), (0x6b16, 4731), (0x6b17, 4732), (0x6b18, 4733), (0x6b19, 4734), (0x6b1a, 4735),
+    (0x6b1b, 4736), (0x6b1c, 4737), (0x6b1d, 4738), (0x6b1e, 4739), (0x6b1f, 4740), (0x6b20, 13418), (0x6b21, 9831), (0x6b22, 11137),
+    (0x6b23, 15137), (0x6b24, 20428), (0x6b25, 4741), (0x6b26, 4742), (0x6b27, 13038), (0x6b28, 4743), (0x6b29, 4744), (0x6b2a, 4745),
+    (0x6b2b, 4746), (0x6b2c, 4747), (0x6b2d, 4748), (0x6b2e, 4749), (0x6b2f, 4750), (0x6b30, 4751), (0x6b31, 4752), (0x6b32, 15766),
+    (0x6b33, 4753), (0x6b34, 4754), (0x6b35, 4755), (0x6b36, 4756), (0x6b37, 20429), (0x6b38, 4757), (0x6b39, 20430), (0x6b3a, 13264),
+    (0x6b3b, 4758), (0x6b3c, 4759), (0x6b3d, 4760), (0x6b3e, 11953), (0x6b3f, 4761), (0x6b40, 4762), (0x6b41, 4763), (0x6b42, 4764),
+    (0x6b43, 20431), (0x6b44, 4765), (0x6b45, 4766), (0x6b46, 20432), (0x6b47, 15115), (0x6b48, 4767), (0x6b49, 13419), (0x6b4a, 4768),
+    (0x6b4b, 4769), (0x6b4c, 10617), (0x6b4d, 4770), (0x6b4e, 4771), (0x6b4f, 4772), (0x6b50, 4773), (0x6b51, 4774), (0x6b52, 4775),
+    (0x6b53, 4776), (0x6b54, 4777), (0x6b55, 4778), (0x6b56, 4779), (0x6b57, 4780), (0x6b58, 4781), (0x6b59, 20433), (0x6b5a, 4782),
+    (0x6b5b, 4783), (0x6b5c, 4784), (0x6b5d, 4785), (0x6b5e, 4786), (0x6b5f, 4787), (0x6b60, 4788), (0x6b61, 4789), (0x6b62, 16270),
+    (0x6b63, 16148), (0x6b64, 9828), (0x6b65, 9434), (0x6b66, 14793), (0x6b67, 13276), (0x6b68, 4790), (0x6b69, 4791), (0x6b6a, 14600),
+    (0x6b6b, 4792), (0x6b6c, 4793), (0x6b6d, 4794), (0x6b6e, 4795), (0x6b6f, 4796), (0x6b70, 4797), (0x6b71, 4798), (0x6b72, 4799),
+    (0x6b73, 4800), (0x6b74, 4801), (0x6b75, 4802), (0x6b76, 4803), (0x6b77, 4804), (0x6b78, 4805), (0x6b79, 9870), (0x6b7a, 4806),
+    (0x6b7b, 14187), (0x6b7c, 11368), (0x6b7d, 4807), (0x6b7e, 4808), (0x6b7f, 4809), (0x6b80, 4810), (0x6b81, 19921), (0x6b82, 19922),
+    (0x6b83, 15369), (0x6b84, 19924), (0x6b85, 4811), (0x6b86, 9874), (0x6b87, 19923), (0x6b88, 4812), (0x6b89, 15314), (0x6b8a, 14031),
+    (0x6b8b, 9453), (0x6b8c, 4813), (0x6b8d, 19927), (0x6b8e, 4814), (0x6b8f, 4815), (0x6b90, 4816), (0x6b91, 4817), (0x6b92, 19925),
+    (0x6b93, 19926), (0x6b94, 4818), (0x6b95, 4819), (0x6b96, 16264), (0x6b97, 4820), (0x6b98, 4821), (0x6b99, 4822), (0x6b9a, 19928),
+    (0x6b9b, 19929), (0x6b9c, 4823), (0x6b9d, 4824), (0x6b9e, 4825), (0x6b9f, 4826), (0x6ba0, 4827), (0x6ba1, 19930), (0x6ba2, 4828),
+    (0x6ba3, 4829), (0x6ba4, 4830), (0x6ba5, 4831), (0x6ba6, 4832), (0x6ba7, 4833), (0x6ba8, 4834), (0x6ba9, 4835), (0x6baa, 19931),
+    (0x6bab, 4836), (0x6bac, 4837), (0x6bad, 4838), (0x6bae, 4839), (0x6baf, 4840), (0x6bb0, 4841), (0x6bb1, 4842), (0x6bb2, 4843),
+    (0x6bb3, 20440), (0x6bb4, 13040), (0x6bb5, 10211), (0x6bb6, 4844), (0x6bb7, 15568), (0x6bb8, 4845), (0x6bb9, 4846), (0x6bba, 4847),
+    (0x6bbb, 4848), (0x6bbc, 4849), (0x6bbd, 4850), (0x6bbe, 4851), (0x6bbf, 10053), (0x6bc0, 4852), (0x6bc1, 11172), (0x6bc2, 20442),
+    (0x6bc3, 4853), (0x6bc4, 4854), (0x6bc5, 15552), (0x6bc6, 4855), (0x6bc7, 4856), (0x6bc8, 4857), (0x6bc9, 4858), (0x6bca, 4859),
+    (0x6bcb, 14792), (0x6bcc, 4860), (0x6bcd, 12849), (0x6bce, 4861), (0x6bcf, 12666), (0x6bd0, 4862), (0x6bd1, 4863), (0x6bd2, 10195),
+    (0x6bd3, 16650), (0x6bd4, 9255), (0x6bd5, 9262), (0x6bd6, 9264), (0x6bd7, 13109), (0x6bd8, 4864), (0x6bd9, 9263), (0x6bda, 4865),
+    (0x6bdb, 12646), (0x6bdc, 4866), (0x6bdd, 4867), (0x6bde, 4868), (0x6bdf, 4869), (0x6be0, 4870), (0x6be1, 16072), (0x6be2, 4871),
+    (0x6be3, 4872), (0x6be4, 4873), (0x6be5, 4874), (0x6be6, 4875), (0x6be7, 4876), (0x6be8, 4877), (0x6be9, 4878), (0x6bea, 20239),
+    (0x6beb, 10958), (0x6bec, 4879), (0x6bed, 4880), (0x6bee, 4881), (0x6bef, 14371), (0x6bf0, 4882), (0x6bf1, 4883), (0x6bf2, 4884),
+    (0x6bf3, 20240), (0x6bf4, 4885), (0x6bf5, 20242), (0x6bf6, 4886), (0x6bf7, 4887), (0x6bf8, 4888), (0x6bf9, 20243), (0x6bfa, 4889),
+    (0x6bfb, 4890), (0x6bfc, 4891), (0x6bfd, 20241), (0x6bfe, 4892), (0x6bff, 4893), (0x6c00, 4894), (0x6c01, 4895), (0x6c02, 4896),
+    (0x6c03, 4897), (0x6c04, 4898), (0x6c05, 20244), (0x6c06, 20246), (0x6c07, 20245), (0x6c08, 4899), (0x6c09, 4900), (0x6c0a, 4901),
+    (0x6c0b, 4902), (0x6c0c, 4903), (0x6c0d, 20247), (0x6c0e, 4904), (0x6c0f, 14012), (0x6c10, 16646), (0x6c11, 12716), (0x6c12, 4905),
+    (0x6c13, 12640), (0x6c14, 13293), (0x6c15, 20248), (0x6c16, 12867), (0x6c17, 4906), (0x6c18, 20249), (0x6c19, 20250), (0x6c1a, 20251),
+    (0x6c1b, 10408), (0x6c1c, 4907), (0x6c1d, 4908), (0x6c1e, 4909), (0x6c1f, 10445), (0x6c20, 4910), (0x6c21, 20252), (0x6c22, 13461),
+    (0x6c23, 4911), (0x6c24, 20254), (0x6c25, 4912), (0x6c26, 10929), (0x6c27, 15380), (0x6c28, 9042), (0x6c29, 20253), (0x6c2a, 20255),
+    (0x6c2b, 4913), (0x6c2c, 4914), (0x6c2d, 4915), (0x6c2e, 9985), (0x6c2f, 12485), (0x6c30, 13467), (0x6c31, 4916), (0x6c32, 20256),
+    (0x6c33, 4917), (0x6c34, 14169), (0x6c35, 18778), (0x6c36, 4918), (0x6c37, 4919), (0x6c38, 15707), (0x6c39, 4920), (0x6c3a, 4921),
+    (0x6c3b, 4922), (0x6c3c, 4923), (0x6c3d, 16874), (0x6c3e, 4924), (0x6c3f, 4925), (0x6c40, 14536), (0x6c41, 16258), (0x6c42, 13478),
+    (0x6c43, 4926), (0x6c44, 4927), (0x6c45, 4928), (0x6c46, 16879), (0x6c47, 11182), (0x6c48, 4929), (0x6c49, 10951), (0x6c4a, 18781),
+    (0x6c4b, 4930), (0x6c4c, 4931), (0x6c4d, 4932), (0x6c4e, 4933), (0x6c4f, 4934), (0x6c50, 14926), (0x6c51, 4935), (0x6c52, 4936),
+    (0x6c53, 4937), (0x6c54, 18779), (0x6c55, 13814), (0x6c56, 4938), (0x6c57, 10950), (0x6c58, 4939), (0x6c59, 4940), (0x6c5a, 4941),
+    (0x6c5b, 15315), (0x6c5c, 18780), (0x6c5d, 13659), (0x6c5e, 10750), (0x6c5f, 11508), (0x6c60, 9651), (0x6c61, 14784), (0x6c62, 4942),
+    (0x6c63, 4943), (0x6c64, 14377), (0x6c65, 4944), (0x6c66, 4945), (0x6c67, 4946), (0x6c68, 18787), (0x6c69, 18788), (0x6c6a, 14619),
+    (0x6c6b, 4947), (0x6c6c, 4948), (0x6c6d, 4949), (0x6c6e, 4950), (0x6c6f, 4951), (0x6c70, 14358), (0x6c71, 4952), (0x6c72, 11324),
+    (0x6c73, 4953), (0x6c74, 18789), (0x6c75, 4954), (0x6c76, 18790), (0x6c77, 4955), (0x6c78, 4956), (0x6c79, 15163), (0x6c7a, 4957),
+    (0x6c7b, 4958), (0x6c7c, 4959), (0x6c7d, 13296), (0x6c7e, 10413), (0x6c7f, 4960), (0x6c80, 4961), (0x6c81, 13458), (0x6c82, 15527),
+    (0x6c83, 14779), (0x6c84, 4962), (0x6c85, 18783), (0x6c86, 18791), (0x6c87, 4963), (0x6c88, 13857), (0x6c89, 9628), (0x6c8a, 4964),
+    (0x6c8b, 4965), (0x6c8c, 18786), (0x6c8d, 4966), (0x6c8e, 4967), (0x6c8f, 13272), (0x6c90, 18784), (0x6c91, 4968), (0x6c92, 4969),
+    (0x6c93, 20634), (0x6c94, 18785), (0x6c95, 4970), (0x6c96, 4971), (0x6c97, 4972), (0x6c98, 4973), (0x6c99, 13794), (0x6c9a, 4974),
+    (0x6c9b, 13085), (0x6c9c, 4975), (0x6c9d, 4976), (0x6c9e, 4977), (0x6c9f, 10756), (0x6ca0, 4978), (0x6ca1, 12662), (0x6ca2, 4979),
+    (0x6ca3, 18782), (0x6ca4, 13044), (0x6ca5, 12259), (0x6ca6, 12502), (0x6ca7, 9460), (0x6ca8, 4980), (0x6ca9, 18792), (0x6caa, 11121),
+    (0x6cab, 12838), (0x6cac, 4981), (0x6cad, 18795), (0x6cae, 11743), (0x6caf, 4982), (0x6cb0, 4983), (0x6cb1, 18806), (0x6cb2, 18800),
+    (0x6cb3, 10976), (0x6cb4, 4984), (0x6cb5, 4985), (0x6cb6, 4986), (0x6cb7, 4987), (0x6cb8, 10403), (0x6cb9, 15720), (0x6cba, 4988),
+    (0x6cbb, 16291), (0x6cbc, 16106), (0x6cbd, 10768), (0x6cbe, 16075), (0x6cbf, 15351), (0x6cc0, 4989), (0x6cc1, 4990), (0x6cc2, 4991),
+    (0x6cc3, 4992), (0x6cc4, 15130), (0x6cc5, 13481), (0x6cc6, 4993), (0x6cc7, 4994), (0x6cc8, 4995), (0x6cc9, 13595), (0x6cca, 9425),
+    (0x6ccb, 4996), (0x6ccc, 12693), (0x6ccd, 4997), (0x6cce, 4998), (0x6ccf, 4999), (0x6cd0, 18793), (0x6cd1, 5000), (0x6cd2, 5001),
+    (0x6cd3, 18807), (0x6cd4, 18794), (0x6cd5, 10363), (0x6cd6, 18802), (0x6cd7, 18799), (0x6cd8, 5002), (0x6cd9, 5003), (0x6cda, 5004),
+    (0x6cdb, 10381), (0x6cdc, 5005), (0x6cdd, 5006), (0x6cde, 13017), (0x6cdf, 5007), (0x6ce0, 18801), (0x6ce1, 13076), (0x6ce2, 9413),
+    (0x6ce3, 13297), (0x6ce4, 5008), (0x6ce5, 12889), (0x6ce6, 5009), (0x6ce7, 5010), (0x6ce8, 16437), (0x6ce9, 5011), (0x6cea, 12130),
+    (0x6ceb, 18804), (0x6cec, 5012), (0x6ced, 5013), (0x6cee, 18805), (0x6cef, 18808), (0x6cf0, 14354), (0x6cf1, 18798), (0x6cf2, 5014),
+    (0x6cf3, 15705), (0x6cf4, 5015), (0x6cf5, 9250), (0x6cf6, 20635), (0x6cf7, 18796), (0x6cf8, 18797), (0x6cf9, 5016), (0x6cfa, 18803),
+    (0x6cfb, 15131), (0x6cfc, 13240), (0x6cfd, 15948), (0x6cfe, 18809), (0x6cff, 5017), (0x6d00, 5018), (0x6d01, 11559), (0x6d02, 5019),
+    (0x6d03, 5020), (0x6d04, 18912), (0x6d05, 5021), (0x6d06, 5022), (0x6d07, 18911), (0x6d08, 5023), (0x6d09, 5024), (0x6d0a, 5025),
+    (0x6d0b, 15378), (0x6d0c, 18908), (0x6d0d, 5026), (0x6d0e, 18914), (0x6d0f, 5027), (0x6d10, 5028), (0x6d11, 5029), (0x6d12, 13672),
+    (0x6d13, 5030), (0x6d14, 5031), (0x6d15, 5032), (0x6d16, 5033), (0x6d17, 14935), (0x6d18, 5034), (0x6d19, 18913), (0x6d1a, 18919),
+    (0x6d1b, 12514), (0x6d1c, 5035), (0x6d1d, 5036), (0x6d1e, 10185), (0x6d1f, 5037), (0x6d20, 5038), (0x6d21, 5039), (0x6d22, 5040),
+    (0x6d23, 5041), (0x6d24, 5042), (0x6d25, 11577), (0x6d26, 5043), (0x6d27, 18907), (0x6d28, 5044), (0x6d29, 5045), (0x6d2a, 10998),
+    (0x6d2b, 18915), (0x6d2c, 5046), (0x6d2d, 5047), (0x6d2e, 18917), (0x6d2f, 5048), (0x6d30, 5049), (0x6d31, 10258), (0x6d32, 16307),
+    (0x6d33, 18923), (0x6d34, 5050), (0x6d35, 18918), (0x6d36, 5051), (0x6d37, 5052), (0x6d38, 5053), (0x6d39, 18906), (0x6d3a, 5054),
+    (0x6d3b, 11193), (0x6d3c, 14596), (0x6d3d, 13397), (0x6d3e, 13056), (0x6d3f, 5055), (0x6d40, 5056), (0x6d41, 12342), (0x6d42, 5057),
+    (0x6d43, 18909), (0x6d44, 5058), (0x6d45, 13414), (0x6d46, 11507), (0x6d47, 11524), (0x6d48, 18910), (0x6d49, 5059), (0x6d4a, 16474),
+    (0x6d4b, 9471), (0x6d4c, 5060), (0x6d4d, 18916), (0x6d4e, 11340), (0x6d4f, 18920), (0x6d50, 5061), (0x6d51, 11190), (0x6d52, 18921),
+    (0x6d53, 13023), (0x6d54, 18922), (0x6d55, 5062), (0x6d56, 5063), (0x6d57, 5064), (0x6d58, 5065), (0x6d59, 16122), (0x6d5a, 11878),
+    (0x6d5b, 5066), (0x6d5c, 18931), (0x6d5d, 5067), (0x6d5e, 18928), (0x6d5f, 5068), (0x6d60, 18932), (0x6d61, 5069), (0x6d62, 5070),
+    (0x6d63, 18934), (0x6d64, 5071), (0x6d65, 5072), (0x6d66, 13259), (0x6d67, 5073), (0x6d68, 5074), (0x6d69, 10963), (0x6d6a, 12108),
+    (0x6d6b, 5075), (0x6d6c, 5076), (0x6d6d, 5077), (0x6d6e, 10546), (0x6d6f, 18925), (0x6d70, 5078), (0x6d71, 5079), (0x6d72, 5080),
+    (0x6d73, 5081), (0x6d74, 15866), (0x6d75, 5082), (0x6d76, 5083), (0x6d77, 10928), (0x6d78, 11589), (0x6d79, 5084), (0x6d7a, 5085),
+    (0x6d7b, 5086), (0x6d7c, 18933), (0x6d7d, 5087), (0x6d7e, 5088), (0x6d7f, 5089), (0x6d80, 5090), (0x6d81, 5091), (0x6d82, 14566),
+    (0x6d83, 5092), (0x6d84, 5093), (0x6d85, 12914), (0x6d86, 5094), (0x6d87, 5095), (0x6d88, 15006), (0x6d89, 13845), (0x6d8a, 5096),
+    (0x6d8b, 5097), (0x6d8c, 15706), (0x6d8d, 5098), (0x6d8e, 14964), (0x6d8f, 5099), (0x6d90, 5100), (0x6d91, 18924), (0x6d92, 5101),
+    (0x6d93, 18929), (0x6d94, 18930), (0x6d95, 14418), (0x6d96, 5102), (0x6d97, 5103), (0x6d98, 5104), (0x6d99, 5105), (0x6d9a, 5106),
+    (0x6d9b, 14391), (0x6d9c, 5107), (0x6d9d, 12117), (0x6d9e, 18926), (0x6d9f, 12271), (0x6da0, 18927), (0x6da1, 14773), (0x6da2, 5108),
+    (0x6da3, 11148), (0x6da4, 10026), (0x6da5, 5109), (0x6da6, 13668), (0x6da7, 11502), (0x6da8, 16094), (0x6da9, 13787), (0x6daa, 10547),
+    (0x6dab, 18947), (0x6dac, 5110), (0x6dad, 5111), (0x6dae, 18949), (0x6daf, 15331), (0x6db0, 5112), (0x6db1, 5113), (0x6db2, 15511),
+    (0x6db3, 5114), (0x6db4, 5115), (0x6db5, 10938), (0x6db6, 5116), (0x6db7, 5117), (0x6db8, 10977), (0x6db9, 5118), (0x6dba, 5119),
+    (0x6dbb, 5120), (0x6dbc, 5121), (0x6dbd, 5122), (0x6dbe, 5123), (0x6dbf, 18940), (0x6dc0, 10052), (0x6dc1, 5124), (0x6dc2, 5125),
+    (0x6dc3, 5126), (0x6dc4, 16480), (0x6dc5, 18937), (0x6dc6, 15008), (0x6dc7, 18936), (0x6dc8, 5127), (0x6dc9, 5128), (0x6dca, 5129),
+    (0x6dcb, 12315), (0x6dcc, 14387), (0x6dcd, 5130), (0x6dce, 5131), (0x6dcf, 5132), (0x6dd0, 5133), (0x6dd1, 14036), (0x6dd2, 5134),
+    (0x6dd3, 5135), (0x6dd4, 5136), (0x6dd5, 5137), (0x6dd6, 12880), (0x6dd7, 5138), (0x6dd8, 14397), (0x6dd9, 18945), (0x6dda, 5139),
+    (0x6ddb, 5140), (0x6ddc, 5141), (0x6ddd, 18944), (0x6dde, 18938), (0x6ddf, 5142), (0x6de0, 18941), (0x6de1, 9988), (0x6de2, 5143),
+    (0x6de3, 5144), (0x6de4, 15732), (0x6de5, 5145), (0x6de6, 18943), (0x6de7, 5146), (0x6de8, 5147), (0x6de9, 5148), (0x6dea, 5149),
+    (0x6deb, 15574), (0x6dec, 9852), (0x6ded, 5150), (0x6dee, 11135), (0x6def, 5151), (0x6df0, 5152), (0x6df1, 13853), (0x6df2, 5153),
+    (0x6df3, 9815), (0x6df4, 5154), (0x6df5, 5155), (0x6df6, 5156), (0x6df7, 11191), (0x6df8, 5157), (0x6df9, 15340), (0x6dfa, 5158),
+    (0x6dfb, 14422), (0x6dfc, 20636), (0x6dfd, 5159), (0x6dfe, 5160), (0x6dff, 5161), (0x6e00, 5162), (0x6e01, 5163), (0x6e02, 5164),
+    (0x6e03, 5165), (0x6e04, 5166), (0x6e05, 13464), (0x6e06, 5167), (0x6e07, 5168), (0x6e08, 5169), (0x6e09, 5170), (0x6e0a, 15873),
+    (0x6e0b, 5171), (0x6e0c, 18948), (0x6e0d, 16488), (0x6e0e, 18939), (0x6e0f, 5172), (0x6e10, 11500), (0x6e11, 18942), (0x6e12, 5173),
+    (0x6e13, 5174), (0x6e14, 15745), (0x6e15, 5175), (0x6e16, 18946), (0x6e17, 13863), (0x6e18, 5176), (0x6e19, 5177), (0x6e1a, 18935),
+    (0x6e1b, 5178), (0x6e1c, 5179), (0x6e1d, 15744), (0x6e1e, 5180), (0x6e1f, 5181), (0x6e20, 13489), (0x6e21, 10206), (0x6e22, 5182),
+    (0x6e23, 15957), (0x6e24, 9424), (0x6e25, 18960), (0x6e26, 5183), (0x6e27, 5184), (0x6e28, 5185), (0x6e29, 14759), (0x6e2a, 5186),
+    (0x6e2b, 18950), (0x6e2c, 5187), (0x6e2d, 14753), (0x6e2e, 5188), (0x6e2f, 10604), (0x6e30, 5189), (0x6e31, 5190), (0x6e32, 18959),
+    (0x6e33, 5191), (0x6e34, 11917), (0x6e35, 5192), (0x6e36, 5193), (0x6e37, 5194), (0x6e38, 15721), (0x6e39, 5195), (0x6e3a, 12711),
+    (0x6e3b, 5196), (0x6e3c, 5197), (0x6e3d, 5198), (0x6e3e, 5199), (0x6e3f, 5200), (0x6e40, 5201), (0x6e41, 5202), (0x6e42, 5203),
+    (0x6e43, 13055), (0x6e44, 18961), (0x6e45, 5204), (0x6e46, 5205), (0x6e47, 5206), (0x6e48, 5207), (0x6e49, 5208), (0x6e4a, 5209),
+    (0x6e4b, 5210), (0x6e4c, 5211), (0x6e4d, 14571), (0x6e4e, 18952), (0x6e4f, 5212), (0x6e50, 5213), (0x6e51, 5214), (0x6e52, 5215),
+    (0x6e53, 18957), (0x6e54, 18958), (0x6e55, 5216), (0x6e56, 11019), (0x6e57, 5217), (0x6e58, 14985), (0x6e59, 5218), (0x6e5a, 5219),
+    (0x6e5b, 16086), (0x6e5c, 5220), (0x6e5d, 5221), (0x6e5e, 5222), (0x6e5f, 18955), (0x6e60, 5223), (0x6e61, 5224), (0x6e62, 5225),
+    (0x6e63, 5226), (0x6e64, 5227), (0x6e65, 5228), (0x6e66, 5229), (0x6e67, 5230), (0x6e68, 5231), (0x6e69, 5232), (0x6e6a, 5233),
+    (0x6e6b, 18953), (0x6e6c, 5234), (0x6e6d, 5235), (0x6e6e, 18951), (0x6e6f, 5236), (0x6e70, 5237), (0x6e71, 5238), (0x6e72, 5239),
+    (0x6e73, 5240), (0x6e74, 5241), (0x6e75, 5242), (0x6e76, 5243), (0x6e77, 5244), (0x6e78, 5245), (0x6e79, 5246), (0x6e7a, 5247),
+    (0x6e7b, 5248), (0x6e7c, 5249), (0x6e7d, 5250), (0x6e7e, 14604), (0x6e7f, 13975), (0x6e80, 5251), (0x6e81, 5252), (0x6e82, 5253),
+    (0x6e83, 12068), (0x6e84, 5254), (0x6e85, 11501), (0x6e86, 18956), (0x6e87, 5255), (0x6e88, 5256), (0x6e89, 10585), (0x6e8a, 5257),
+    (0x6e8b, 5258), (0x6e8c, 5259), (0x6e8d, 5260), (0x6e8e, 5261), (0x6e8f, 18976), (0x6e90, 15885), (0x6e91, 5262), (0x6e92, 5263),
+    (0x6e93, 5264), (0x6e94, 5265), (0x6e95, 5266), (0x6e96, 5267), (0x6e97, 5268), (0x6e98, 18964), (0x6e99, 5269), (0x6e9a, 5270),
+    (0x6e9b, 5271), (0x6e9c, 12334), (0x6e9d, 5272), (0x6e9e, 5273), (0x6e9f, 18978), (0x6ea0, 5274), (0x6ea1, 5275), (0x6ea2, 15556),
+    (0x6ea3, 5276), (0x6ea4, 5277), (0x6ea5, 18968), (0x6ea6, 5278), (0x6ea7, 18969), (0x6ea8, 5279), (0x6ea9, 5280), (0x6eaa, 14925),
+    (0x6eab, 5281), (0x6eac, 5282), (0x6ead, 5283), (0x6eae, 5284), (0x6eaf, 14216), (0x6eb0, 5285), (0x6eb1, 18963), (0x6eb2, 18954),
+    (0x6eb3, 5286), (0x6eb4, 18974), (0x6eb5, 5287), (0x6eb6, 13645), (0x6eb7, 18972), (0x6eb8, 5288), (0x6eb9, 5289), (0x6eba, 12896),
+    (0x6ebb, 18971), (0x6ebc, 5290), (0x6ebd, 18970), (0x6ebe, 5291), (0x6ebf, 5292), (0x6ec0, 5293), (0x6ec1, 9687), (0x6ec2, 18977),
+    (0x6ec3, 5294), (0x6ec4, 5295), (0x6ec5, 5296), (0x6ec6, 5297), (0x6ec7, 10040), (0x6ec8, 5298), (0x6ec9, 5299), (0x6eca, 5300),
+    (0x6ecb, 16479), (0x6ecc, 5301), (0x6ecd, 5302), (0x6ece, 5303), (0x6ecf, 18975), (0x6ed0, 5304), (0x6ed1, 11127), (0x6ed2, 5305),
+    (0x6ed3, 16485), (0x6ed4, 14392), (0x6ed5, 20323), (0x6ed6, 5306), (0x6ed7, 18973), (0x6ed8, 5307), (0x6ed9, 5308), (0x6eda, 10821),
+    (0x6edb, 5309), (0x6edc, 5310), (0x6edd, 5311), (0x6ede, 16290), (0x6edf, 18962), (0x6ee0, 18965), (0x6ee1, 12535), (0x6ee2, 18967),
+    (0x6ee3, 5312), (0x6ee4, 12488), (0x6ee5, 12101), (0x6ee6, 12493), (0x6ee7, 5313), (0x6ee8, 9300), (0x6ee9, 14363), (0x6eea, 5314),
+    (0x6eeb, 5315), (0x6eec, 5316), (0x6eed, 5317), (0x6eee, 5318), (0x6eef, 5319), (0x6ef0, 5320), (0x6ef1, 5321), (0x6ef2, 5322),
+    (0x6ef3, 5323), (0x6ef4, 10021), (0x6ef5, 5324), (0x6ef6, 5325), (0x6ef7, 5326), (0x6ef8, 5327), (0x6ef9, 18984), (0x6efa, 5328),
+    (0x6efb, 5329), (0x6efc, 5330), (0x6efd, 5331), (0x6efe, 5332), (0x6eff, 5333), (0x6f00, 5334), (0x6f01, 5335), (0x6f02, 13220),
+    (0x6f03, 5336), (0x6f04, 5337), (0x6f05, 5338), (0x6f06, 13270), (0x6f07, 5339), (0x6f08, 5340), (0x6f09, 18990), (0x6f0a, 5341),
+    (0x6f0b, 5342), (0x6f0c, 5343), (0x6f0d, 5344), (0x6f0e, 5345), (0x6f0f, 12454), (0x6f10, 5346), (0x6f11, 5347), (0x6f12, 5348),
+    (0x6f13, 12141), (0x6f14, 15356), (0x6f15, 18983), (0x6f16, 5349), (0x6f17, 5350), (0x6f18, 5351), (0x6f19, 5352), (0x6f1a, 5353),
+    (0x6f1b, 5354), (0x6f1c, 5355), (0x6f1d, 5356), (0x6f1e, 5357), (0x6f1f, 5358), (0x6f20, 12839), (0x6f21, 5359), (0x6f22, 5360),
+    (0x6f23, 5361), (0x6f24, 18982), (0x6f25, 5362), (0x6f26, 5363), (0x6f27, 5364), (0x6f28, 5365), (0x6f29, 18991), (0x6f2a, 18989),
+    (0x6f2b, 12539), (0x6f2c, 5366), (0x6f2d, 18966), (0x6f2e, 5367), (0x6f2f, 18985), (0x6f30, 5368), (0x6f31, 14059), (0x6f32, 5369),
+    (0x6f33, 16091), (0x6f34, 5370), (0x6f35, 5371), (0x6f36, 18986), (0x6f37, 5372), (0x6f38, 5373), (0x6f39, 5374), (0x6f3a, 5375),
+    (0x6f3b, 5376), (0x6f3c, 5377), (0x6f3d, 5378), (0x6f3e, 15385), (0x6f3f, 5379), (0x6f40, 5380), (0x6f41, 5381), (0x6f42, 5382),
+    (0x6f43, 5383), (0x6f44, 5384), (0x6f45, 5385), (0x6f46, 18980), (0x6f47, 18981), (0x6f48, 5386), (0x6f49, 5387), (0x6f4a, 5388),
+    (0x6f4b, 18987), (0x6f4c, 5389), (0x6f4d, 14736), (0x6f4e, 5390), (0x6f4f, 5391), (0x6f50, 5392), (0x6f51, 5393), (0x6f52, 5394),
+    (0x6f53, 5395), (0x6f54, 5396), (0x6f55, 5397), (0x6f56, 5398), (0x6f57, 5399), (0x6f58, 13058), (0x6f59, 5400), (0x6f5a, 5401),
+    (0x6f5b, 5402), (0x6f5c, 13412), (0x6f5d, 5403), (0x6f5e, 12471), (0x6f5f, 5404), (0x6f60, 5405), (0x6f61, 5406), (0x6f62, 18979),
+    (0x6f63, 5407), (0x6f64, 5408), (0x6f65, 5409), (0x6f66, 12297), (0x6f67, 5410), (0x6f68, 5411), (0x6f69, 5412), (0x6f6a, 5413),
+    (0x6f6b, 5414), (0x6f6c, 5415), (0x6f6d, 14367), (0x6f6e, 9612), (0x6f6f, 5416), (0x6f70, 5417), (0x6f71, 5418), (0x6f72, 18996),
+    (0x6f73, 5419), (0x6f74, 18988), (0x6f75, 5420), (0x6f76, 5421), (0x6f77, 5422), (0x6f78, 18995), (0x6f79, 5423), (0x6f7a, 18998),
+    (0x6f7b, 5424), (0x6f7c, 18997), (0x6f7d, 5425), (0x6f7e, 5426), (0x6f7f, 5427), (0x6f80, 5428), (0x6f81, 5429), (0x6f82, 5430),
+    (0x6f83, 5431), (0x6f84, 9641), (0x6f85, 5432), (0x6f86, 5433), (0x6f87, 5434), (0x6f88, 9621), (0x6f89, 18992), (0x6f8a, 5435),
+    (0x6f8b, 5436), (0x6f8c, 18994), (0x6f8d, 18993), (0x6f8e, 13091), (0x6f8f, 5437), (0x6f90, 5438), (0x6f91, 5439), (0x6f92, 5440),
+    (0x6f93, 5441), (0x6f94, 5442), (0x6f95, 5443), (0x6f96, 5444), (0x6f97, 5445), (0x6f98, 5446), (0x6f99, 5447), (0x6f9a, 5448),
+    (0x6f9b, 5449), (0x6f9c, 12094), (0x6f9d, 5450), (0x6f9e, 5451), (0x6f9f, 5452), (0x6fa0, 5453), (0x6fa1, 15937), (0x6fa2, 5454),
+    (0x6fa3, 5455), (0x6fa4, 5456), (0x6fa5, 5457), (0x6fa6, 5458), (0x6fa7, 19097), (0x6fa8, 5459), (0x6fa9, 5460), (0x6faa, 5461),
+    (0x6fab, 5462), (0x6fac, 5463), (0x6fad, 5464), (0x6fae, 5465), (0x6faf, 5466), (0x6fb0, 5467), (0x6fb1, 5468), (0x6fb2, 5469),
+    (0x6fb3, 9061), (0x6fb4, 5470), (0x6fb5, 5471), (0x6fb6, 19099), (0x6fb7, 5472), (0x6fb8, 5473), (0x6fb9, 19098), (0x6fba, 5474),
+    (0x6fbb, 5475), (0x6fbc, 5476), (0x6fbd, 5477), (0x6fbe, 5478), (0x6fbf, 5479), (0x6fc0, 11309), (0x6fc1, 5480), (0x6fc2, 19100),
+    (0x6fc3, 5481), (0x6fc4, 5482), (0x6fc5, 5483), (0x6fc6, 5484), (0x6fc7, 5485), (0x6fc8, 5486), (0x6fc9, 19096), (0x6fca, 5487),
+    (0x6fcb, 5488), (0x6fcc, 5489), (0x6fcd, 5490), (0x6fce, 5491), (0x6fcf, 5492), (0x6fd0, 5493), (0x6fd1, 18999), (0x6fd2, 9299),
+    (0x6fd3, 5494), (0x6fd4, 5495), (0x6fd5, 5496), (0x6fd6, 5497), (0x6fd7, 5498), (0x6fd8, 5499), (0x6fd9, 5500), (0x6fda, 5501),
+    (0x6fdb, 5502), (0x6fdc, 5503), (0x6fdd, 5504), (0x6fde, 19103), (0x6fdf, 5505), (0x6fe0, 19104), (0x6fe1, 19101), (0x6fe2, 5506),
+    (0x6fe3, 5507), (0x6fe4, 5508), (0x6fe5, 5509), (0x6fe6, 5510), (0x6fe7, 5511), (0x6fe8, 5512), (0x6fe9, 5513), (0x6fea, 5514),
+    (0x6feb, 5515), (0x6fec, 5516), (0x6fed, 5517), (0x6fee, 19102), (0x6fef, 19105), (0x6ff0, 5518), (0x6ff1, 5519), (0x6ff2, 5520),
+    (0x6ff3, 5521), (0x6ff4, 5522), (0x6ff5, 5523), (0x6ff6, 5524), (0x6ff7, 5525), (0x6ff8, 5526), (0x6ff9, 5527), (0x6ffa, 5528),
+    (0x6ffb, 5529), (0x6ffc, 5530), (0x6ffd, 5531), (0x6ffe, 5532), (0x6fff, 5533), (0x7000, 5534), (0x7001, 5535), (0x7002, 5536),
+    (0x7003, 5537), (0x7004, 5538), (0x7005, 5539), (0x7006, 5540), (0x7007, 5541), (0x7008, 5542), (0x7009, 5543), (0x700a, 5544),
+    (0x700b, 5545), (0x700c, 5546), (0x700d, 5547), (0x700e, 5548), (0x700f, 5549), (0x7010, 5550), (0x7011, 13262), (0x7012, 5551),
+    (0x7013, 5552), (0x7014, 5553), (0x7015, 5554), (0x7016, 5555), (0x7017, 5556), (0x7018, 5557), (0x7019, 5558), (0x701a, 19106),
+    (0x701b, 19108), (0x701c, 5559), (0x701d, 5560), (0x701e, 5561), (0x701f, 5562), (0x7020, 5563), (0x7021, 5564), (0x7022, 5565),
+    (0x7023, 19107), (0x7024, 5566), (0x7025, 5567), (0x7026, 5568), (0x7027, 5569), (0x7028, 5570), (0x7029, 5571), (0x702a, 5572),
+    (0x702b, 5573), (0x702c, 5574), (0x702d, 5575), (0x702e, 5576), (0x702f, 5577), (0x7030, 5578), (0x7031, 5579), (0x7032, 5580),
+    (0x7033, 5581), (0x7034, 5582), (0x7035, 19110), (0x7036, 5583), (0x7037, 5584), (0x7038, 5585), (0x7039, 19109), (0x703a, 5586),
+    (0x703b, 5587), (0x703c, 5588), (0x703d, 5589), (0x703e, 5590), (0x703f, 5591), (0x7040, 5592), (0x7041, 5593), (0x7042, 5594),
+    (0x7043, 5595), (0x7044, 5596), (0x7045, 5597), (0x7046, 5598), (0x7047, 5599), (0x7048, 5600), (0x7049, 5601), (0x704a, 5602),
+    (0x704b, 5603), (0x704c, 10799), (0x704d, 5604), (0x704e, 5605), (0x704f, 19111), (0x7050, 5606), (0x7051, 5607), (0x7052, 5608),
+    (0x7053, 5609), (0x7054, 5610), (0x7055, 5611), (0x7056, 5612), (0x7057, 5613), (0x7058, 5614), (0x7059, 5615), (0x705a, 5616),
+    (0x705b, 5617), (0x705c, 5618), (0x705d, 5619), (0x705e, 19112), (0x705f, 5620), (0x7060, 5621), (0x7061, 5622), (0x7062, 5623),
+    (0x7063, 5624), (0x7064, 5625), (0x7065, 5626), (0x7066, 5627), (0x7067, 5628), (0x7068, 5629), (0x7069, 5630), (0x706a, 5631),
+    (0x706b, 11195), (0x706c, 20490), (0x706d, 12715), (0x706e, 5632), (0x706f, 10013), (0x7070, 11165), (0x7071, 5633), (0x7072, 5634),
+    (0x7073, 5635), (0x7074, 5636), (0x7075, 12328), (0x7076, 15943), (0x7077, 5637), (0x7078, 11721), (0x7079, 5638), (0x707a, 5639),
+    (0x707b, 5640), (0x707c, 16473), (0x707d, 5641), (0x707e, 15919), (0x707f, 9456), (0x7080, 20455), (0x7081, 5642), (0x7082, 5643),
+    (0x7083, 5644), (0x7084, 5645), (0x7085, 20078), (0x7086, 5646), (0x7087, 5647), (0x7088, 5648), (0x7089, 12460), (0x708a, 9807),
+    (0x708b, 5649), (0x708c, 5650), (0x708d, 5651), (0x708e, 15350), (0x708f, 5652), (0x7090, 5653), (0x7091, 5654), (0x7092, 9615),
+    (0x7093, 5655), (0x7094, 13603), (0x7095, 11902), (0x7096, 20457), (0x7097, 5656), (0x7098, 5657), (0x7099, 16288), (0x709a, 5658),
+    (0x709b, 5659), (0x709c, 20456), (0x709d, 20458), (0x709e, 5660), (0x709f, 5661), (0x70a0, 5662), (0x70a1, 5663), (0x70a2, 5664),
+    (0x70a3, 5665), (0x70a4, 5666), (0x70a5, 5667), (0x70a6, 5668), (0x70a7, 5669), (0x70a8, 5670), (0x70a9, 5671), (0x70aa, 5672),
+    (0x70ab, 20462), (0x70ac, 11755), (0x70ad, 14376), (0x70ae, 13073), (0x70af, 11713), (0x70b0, 5673), (0x70b1, 20463), (0x70b2, 5674),
+    (0x70b3, 9309), (0x70b4, 5675), (0x70b5, 5676), (0x70b6, 5677), (0x70b7, 20461), (0x70b8, 16063), (0x70b9, 10042), (0x70ba, 5678),
+    (0x70bb, 20459), (0x70bc, 12277), (0x70bd, 9662), (0x70be, 5679), (0x70bf, 5680), (0x70c0, 20460), (0x70c1, 14179), (0x70c2, 12100),
+    (0x70c3, 14439), (0x70c4, 5681), (0x70c5, 5682), (0x70c6, 5683), (0x70c7, 5684), (0x70c8, 12305), (0x70c9, 5685), (0x70ca, 20465),
+    (0x70cb, 5686), (0x70cc, 5687), (0x70cd, 5688), (0x70ce, 5689), (0x70cf, 5690), (0x70d0, 5691), (0x70d1, 5692), (0x70d2, 5693),
+    (0x70d3, 5694), (0x70d4, 5695), (0x70d5, 5696), (0x70d6, 5697), (0x70d7, 5698), (0x70d8, 10995), (0x70d9, 12116), (0x70da, 5699),
+    (0x70db, 16327), (0x70dc, 5700), (0x70dd, 5701), (0x70de, 5702), (0x70df, 15339), (0x70e0, 5703), (0x70e1, 5704), (0x70e2, 5705),
+    (0x70e3, 5706), (0x70e4, 11905), (0x70e5, 5707), (0x70e6, 10374), (0x70e7, 13828), (0x70e8, 20464), (0x70e9, 11181), (0x70ea, 5708),
+    (0x70eb, 14389), (0x70ec, 11588), (0x70ed, 13625), (0x70ee, 5709), (0x70ef, 14924), (0x70f0, 5710), (0x70f1, 5711), (0x70f2, 5712),
+    (0x70f3, 5713), (0x70f4, 5714), (0x70f5, 5715), (0x70f6, 5716), (0x70f7, 14608), (0x70f8, 5717), (0x70f9, 13090), (0x70fa, 5718),
+    (0x70fb, 5719), (0x70fc, 5720), (0x70fd, 10428), (0x70fe, 5721), (0x70ff, 5722), (0x7100, 5723), (0x7101, 5724), (0x7102, 5725),
+    (0x7103, 5726), (0x7104, 5727), (0x7105, 5728), (0x7106, 5729), (0x7107, 5730), (0x7108, 5731), (0x7109, 15336), (0x710a, 10949),
+    (0x710b, 5732), (0x710c, 5733), (0x710d, 5734), (0x710e, 5735), (0x710f, 5736), (0x7110, 20466), (0x7111, 5737), (0x7112, 5738),
+    (0x7113, 20467), (0x7114, 5739), (0x7115, 11147), (0x7116, 20468), (0x7117, 5740), (0x7118, 20491), (0x7119, 9241), (0x711a, 10412),
+    (0x711b, 5741), (0x711c, 5742), (0x711d, 5743), (0x711e, 5744), (0x711f, 5745), (0x7120, 5746), (0x7121, 5747), (0x7122, 5748),
+    (0x7123, 5749), (0x7124, 5750), (0x7125, 5751), (0x7126, 11520), (0x7127, 5752), (0x7128, 5753), (0x7129, 5754), (0x712a, 5755),
+    (0x712b, 5756), (0x712c, 5757), (0x712d, 5758), (0x712e, 5759), (0x712f, 20469), (0x7130, 15365), (0x7131, 20470), (0x7132, 5760),
+    (0x7133, 5761), (0x7134, 5762), (0x7135, 5763), (0x7136, 13612), (0x7137, 5764), (0x7138, 5765), (0x7139, 5766), (0x713a, 5767),
+    (0x713b, 5768), (0x713c, 5769), (0x713d, 5770), (0x713e, 5771), (0x713f, 5772), (0x7140, 5773), (0x7141, 5774), (0x7142, 5775),
+    (0x7143, 5776), (0x7144, 5777), (0x7145, 20474), (0x7146, 5778), (0x7147, 5779), (0x7148, 5780), (0x7149, 5781), (0x714a, 20476),
+    (0x714b, 5782), (0x714c, 11160), (0x714d, 5783), (0x714e, 11374), (0x714f, 5784), (0x7150, 5785), (0x7151, 5786), (0x7152, 5787),
+    (0x7153, 5788), (0x7154, 5789), (0x7155, 5790), (0x7156, 5791), (0x7157, 5792), (0x7158, 5793), (0x7159, 5794), (0x715a, 5795),
+    (0x715b, 5796), (0x715c, 20472), (0x715d, 5797), (0x715e, 13798), (0x715f, 5798), (0x7160, 5799), (0x7161, 5800), (0x7162, 5801),
+    (0x7163, 5802), (0x7164, 12661), (0x7165, 5803), (0x7166, 20492), (0x7167, 16108), (0x7168, 20473), (0x7169, 5804), (0x716a, 5805),
+    (0x716b, 5806), (0x716c, 5807), (0x716d, 5808), (0x716e, 16328), (0x716f, 5809), (0x7170, 5810), (0x7171, 5811), (0x7172, 20475),
+    (0x7173, 20471), (0x7174, 5812), (0x7175, 5813), (0x7176, 5814), (0x7177, 5815), (0x7178, 20477), (0x7179, 5816), (0x717a, 20478),
+    (0x717b, 5817), (0x717c, 5818), (0x717d, 13806), (0x717e, 5819), (0x717f, 5820), (0x7180, 5821), (0x7181, 5822), (0x7182, 5823),
+    (0x7183, 5824), (0x7184, 14923), (0x7185, 5825), (0x7186, 5826), (0x7187, 5827), (0x7188, 5828), (0x7189, 5829), (0x718a, 15165),
+    (0x718b, 5830), (0x718c, 5831), (0x718d, 5832), (0x718e, 5833), (0x718f, 15307), (0x7190, 5834), (0x7191, 5835), (0x7192, 5836),
+    (0x7193, 5837), (0x7194, 13644), (0x7195, 5838), (0x7196, 5839), (0x7197, 5840), (0x7198, 20479), (0x7199, 14810), (0x719a, 5841),
+    (0x719b, 5842), (0x719c, 5843), (0x719d, 5844), (0x719e, 5845), (0x719f, 14041), (0x71a0, 20483), (0x71a1, 5846), (0x71a2, 5847),
+    (0x71a3, 5848), (0x71a4, 5849), (0x71a5, 5850), (0x71a6, 5851), (0x71a7, 5852), (0x71a8, 20482), (0x71a9, 5853), (0x71aa, 5854),
+    (0x71ab, 5855), (0x71ac, 9055), (0x71ad, 5856), (0x71ae, 5857), (0x71af, 5858), (0x71b0, 5859), (0x71b1, 5860), (0x71b2, 5861),
+    (0x71b3, 20480), (0x71b4, 5862), (0x71b5, 20481), (0x71b6, 5863), (0x71b7, 5864), (0x71b8, 5865), (0x71b9, 20493), (0x71ba, 5866),
+    (0x71bb, 5867), (0x71bc, 5868), (0x71bd, 5869), (0x71be, 5870), (0x71bf, 5871), (0x71c0, 5872), (0x71c1, 5873), (0x71c2, 5874),
+    (0x71c3, 13613), (0x71c4, 5875), (0x71c5, 5876), (0x71c6, 5877), (0x71c7, 5878), (0x71c8, 5879), (0x71c9, 5880), (0x71ca, 5881),
+    (0x71cb, 5882), (0x71cc, 5883), (0x71cd, 5884), (0x71ce, 12294), (0x71cf, 5885), (0x71d0, 5886), (0x71d1, 5887), (0x71d2, 5888),
+    (0x71d3, 5889), (0x71d4, 20485), (0x71d5, 15359), (0x71d6, 5890), (0x71d7, 5891), (0x71d8, 5892), (0x71d9, 5893), (0x71da, 5894),
+    (0x71db, 5895), (0x71dc, 5896), (0x71dd, 5897), (0x71de, 5898), (0x71df, 5899), (0x71e0, 20484), (0x71e1, 5900), (0x71e2, 5901),
+    (0x71e3, 5902), (0x71e4, 5903), (0x71e5, 15944), (0x71e6, 5904), (0x71e7, 20486), (0x71e8, 5905), (0x71e9, 5906), (0x71ea, 5907),
+    (0x71eb, 5908), (0x71ec, 5909), (0x71ed, 5910), (0x71ee, 17233), (0x71ef, 5911), (0x71f0, 5912), (0x71f1, 5913), (0x71f2, 5914),
+    (0x71f3, 5915), (0x71f4, 5916), (0x71f5, 5917), (0x71f6, 5918), (0x71f7, 5919), (0x71f8, 5920), (0x71f9, 20487), (0x71fa, 5921),
+    (0x71fb, 5922), (0x71fc, 5923), (0x71fd, 5924), (0x71fe, 5925), (0x71ff, 5926), (0x7200, 5927), (0x7201, 5928), (0x7202, 5929),
+    (0x7203, 5930), (0x7204, 5931), (0x7205, 5932), (0x7206, 9227), (0x7207, 5933), (0x7208, 5934), (0x7209, 5935), (0x720a, 5936),
+    (0x720b, 5937), (0x720c, 5938), (0x720d, 5939), (0x720e, 5940), (0x720f, 5941), (0x7210, 5942), (0x7211, 5943), (0x7212, 5944),
+    (0x7213, 5945), (0x7214, 5946), (0x7215, 5947), (0x7216, 5948), (0x7217, 5949), (0x7218, 5950), (0x7219, 5951), (0x721a, 5952),
+    (0x721b, 5953), (0x721c, 5954), (0x721d, 20488), (0x721e, 5955), (0x721f, 5956), (0x7220, 5957), (0x7221, 5958), (0x7222, 5959),
+    (0x7223, 5960), (0x7224, 5961), (0x7225, 5962), (0x7226, 5963), (0x7227, 5964), (0x7228, 20489), (0x7229, 5965), (0x722a, 16441),
+    (0x722b, 5966), (0x722c, 13047), (0x722d, 5967), (0x722e, 5968), (0x722f, 5969), (0x7230, 20263), (0x7231, 9039), (0x7232, 5970),
+    (0x7233, 5971), (0x7234, 5972), (0x7235, 11769), (0x7236, 10569), (0x7237, 15500), (0x7238, 9079), (0x7239, 10064), (0x723a, 5973),
+    (0x723b, 16644), (0x723c, 5974), (0x723d, 14167), (0x723e, 5975), (0x723f, 18776), (0x7240, 5976), (0x7241, 5977), (0x7242, 5978),
+    (0x7243, 5979), (0x7244, 5980), (0x7245, 5981), (0x7246, 5982), (0x7247, 13217), (0x7248, 9095), (0x7249, 5983), (0x724a, 5984),
+    (0x724b, 5985), (0x724c, 13053), (0x724d, 20260), (0x724e, 5986), (0x724f, 5987), (0x7250, 5988), (0x7251, 5989), (0x7252, 20261),
+    (0x7253, 5990), (0x7254, 5991), (0x7255, 5992), (0x7256, 20262), (0x7257, 5993), (0x7258, 5994), (0x7259, 15327), (0x725a, 5995),
+    (0x725b, 13018), (0x725c, 5996), (0x725d, 20127), (0x725e, 5997), (0x725f, 12843), (0x7260, 5998), (0x7261, 12846), (0x7262, 12111),
+    (0x7263, 5999), (0x7264, 6000), (0x7265, 6001), (0x7266, 20128), (0x7267, 12858), (0x7268, 6002), (0x7269, 14804), (0x726a, 6003),
+    (0x726b, 6004), (0x726c, 6005), (0x726d, 6006), (0x726e, 20125), (0x726f, 20129), (0x7270, 6007), (0x7271, 6008), (0x7272, 13867),
+    (0x7273, 6009), (0x7274, 6010), (0x7275, 13398), (0x7276, 6011), (0x7277, 6012), (0x7278, 6013), (0x7279, 14401), (0x727a, 14819),
+    (0x727b, 6014), (0x727c, 6015), (0x727d, 6016), (0x727e, 20130), (0x727f, 20131), (0x7280, 14927), (0x7281, 12136), (0x7282, 6017),
+    (0x7283, 6018), (0x7284, 20132), (0x7285, 6019), (0x7286, 6020), (0x7287, 6021), (0x7288, 6022), (0x7289, 6023), (0x728a, 10196),
+    (0x728b, 20133), (0x728c, 6024), (0x728d, 20134), (0x728e, 6025), (0x728f, 20135), (0x7290, 6026), (0x7291, 6027), (0x7292, 20136),
+    (0x7293, 6028), (0x7294, 6029), (0x7295, 6030), (0x7296, 6031), (0x7297, 6032), (0x7298, 6033), (0x7299, 6034), (0x729a, 6035),
+    (0x729b, 6036), (0x729c, 6037), (0x729d, 6038), (0x729e, 6039), (0x729f, 20126), (0x72a0, 6040), (0x72a1, 6041), (0x72a2, 6042),
+    (0x72a3, 6043), (0x72a4, 6044), (0x72a5, 6045), (0x72a6, 6046), (0x72a7, 6047), (0x72a8, 6048), (0x72a9, 6049), (0x72aa, 6050),
+    (0x72ab, 6051), (0x72ac, 13599), (0x72ad, 18410), (0x72ae, 6052), (0x72af, 10379), (0x72b0, 18411), (0x72b1, 6053), (0x72b2, 6054),
+    (0x72b3, 6055), (0x72b4, 18412), (0x72b5, 6056), (0x72b6, 16455), (0x72b7, 18413), (0x72b8, 18414), (0x72b9, 15719), (0x72ba, 6057),
+    (0x72bb, 6058), (0x72bc, 6059), (0x72bd, 6060), (0x72be, 6061), (0x72bf, 6062), (0x72c0, 6063), (0x72c1, 18416), (0x72c2, 11956),
+    (0x72c3, 18415), (0x72c4, 10025), (0x72c5, 6064), (0x72c6, 6065), (0x72c7, 6066), (0x72c8, 9238), (0x72c9, 6067), (0x72ca, 6068),
+    (0x72cb, 6069), (0x72cc, 6070), (0x72cd, 18418), (0x72ce, 18417), (0x72cf, 6071), (0x72d0, 11017), (0x72d1, 6072), (0x72d2, 18419),
+    (0x72d3, 6073), (0x72d4, 6074), (0x72d5, 6075), (0x72d6, 6076), (0x72d7, 10758), (0x72d8, 6077), (0x72d9, 11734), (0x72da, 6078),
+    (0x72db, 6079), (0x72dc, 7790), (0x72dd, 7791), (0x72de, 12917), (0x72df, 7792), (0x72e0, 10986), (0x72e1, 11533), (0x72e2, 7793),
+    (0x72e3, 7794), (0x72e4, 7795), (0x72e5, 7796), (0x72e6, 7797), (0x72e7, 7798), (0x72e8, 18420), (0x72e9, 18422), (0x72ea, 7799),
+    (0x72eb, 7800), (0x72ec, 10197), (0x72ed, 14948), (0x72ee, 13973), (0x72ef, 18421), (0x72f0, 16143), (0x72f1, 15767), (0x72f2, 18423),
+    (0x72f3, 18427), (0x72f4, 18424), (0x72f5, 7801), (0x72f6, 7802), (0x72f7, 18425), (0x72f8, 12139), (0x72f9, 7803), (0x72fa, 18429),
+    (0x72fb, 18526), (0x72fc, 12104), (0x72fd, 7804), (0x72fe, 7805), (0x72ff, 7806), (0x7300, 7807), (0x7301, 18426), (0x7302, 7808),
+    (0x7303, 18428), (0x7304, 7809), (0x7305, 7810), (0x7306, 7811), (0x7307, 7812), (0x7308, 7813), (0x7309, 7814), (0x730a, 18530),
+    (0x730b, 7815), (0x730c, 7816), (0x730d, 7817), (0x730e, 12307), (0x730f, 7818), (0x7310, 7819), (0x7311, 7820), (0x7312, 7821),
+    (0x7313, 18528), (0x7314, 7822), (0x7315, 18533), (0x7316, 9499), (0x7317, 18527), (0x7318, 7823), (0x7319, 7824), (0x731a, 7825),
+    (0x731b, 12680), (0x731c, 9439), (0x731d, 18532), (0x731e, 18531), (0x731f, 7826), (0x7320, 7827), (0x7321, 18529), (0x7322, 18534),
+    (0x7323, 7828), (0x7324, 7829), (0x7325, 18536), (0x7326, 7830), (0x7327, 7831), (0x7328, 7832), (0x7329, 15146), (0x732a, 16322),
+    (0x732b, 12643), (0x732c, 18537), (0x732d, 7833), (0x732e, 14970), (0x732f, 7834), (0x7330, 7835), (0x7331, 18539), (0x7332, 7836),
+    (0x7333, 7837), (0x7334, 11004), (0x7335, 7838), (0x7336, 7839), (0x7337, 19919), (0x7338, 18538), (0x7339, 18535), (0x733a, 7840),
+    (0x733b, 7841), (0x733c, 7842), (0x733d, 7843), (0x733e, 11126), (0x733f, 15884), (0x7340, 7844), (0x7341, 7845), (0x7342, 7846),
+    (0x7343, 7847), (0x7344, 7848), (0x7345, 7849), (0x7346, 7850), (0x7347, 7851), (0x7348, 7852), (0x7349, 7853), (0x734a, 7854),
+    (0x734b, 7855), (0x734c, 7856), (0x734d, 18541), (0x734e, 7857), (0x734f, 7858), (0x7350, 18540), (0x7351, 7859), (0x7352, 19920),
+    (0x7353, 7860), (0x7354, 7861), (0x7355, 7862), (0x7356, 7863), (0x7357, 18542), (0x7358, 7864), (0x7359, 7865), (0x735a, 7866),
+    (0x735b, 7867), (0x735c, 7868), (0x735d, 7869), (0x735e, 7870), (0x735f, 7871), (0x7360, 18543), (0x7361, 7872), (0x7362, 7873),
+    (0x7363, 7874), (0x7364, 7875), (0x7365, 7876), (0x7366, 7877), (0x7367, 7878), (0x7368, 7879), (0x7369, 7880), (0x736a, 7881),
+    (0x736b, 7882), (0x736c, 18544), (0x736d, 14346), (0x736e, 7883), (0x736f, 18545), (0x7370, 7884), (0x7371, 7885), (0x7372, 7980),
+    (0x7373, 7981), (0x7374, 7982), (0x7375, 7983), (0x7376, 7984), (0x7377, 7985), (0x7378, 7986), (0x7379, 7987), (0x737a, 7988),
+    (0x737b, 7989), (0x737c, 7990), (0x737d, 7991), (0x737e, 18546), (0x737f, 7992), (0x7380, 7993), (0x7381, 7994), (0x7382, 7995),
+    (0x7383, 7996), (0x7384, 15199), (0x7385, 7997), (0x7386, 7998), (0x7387, 12487), (0x7388, 7999), (0x7389, 15756), (0x738a, 8000),
+    (0x738b, 14620), (0x738c, 8001), (0x738d, 8002), (0x738e, 19539), (0x738f, 8003), (0x7390, 8004), (0x7391, 19540), (0x7392, 8005),
+    (0x7393, 8006), (0x7394, 8007), (0x7395, 8008), (0x7396, 11718), (0x7397, 8009), (0x7398, 8010), (0x7399, 8011), (0x739a, 8012),
+    (0x739b, 12519), (0x739c, 8013), (0x739d, 8014), (0x739e, 8015), (0x739f, 19543), (0x73a0, 8016), (0x73a1, 8017), (0x73a2, 19542),
+    (0x73a3, 8018), (0x73a4, 8019), (0x73a5, 8020), (0x73a6, 8021), (0x73a7, 8022), (0x73a8, 8023), (0x73a9, 14605), (0x73aa, 8024),
+    (0x73ab, 12656), (0x73ac, 8025), (0x73ad, 8026), (0x73ae, 19541), (0x73af, 11138), (0x73b0, 14969), (0x73b1, 8027), (0x73b2, 12320),
+    (0x73b3, 19548), (0x73b4, 8028), (0x73b5, 8029), (0x73b6, 8030), (0x73b7, 19547), (0x73b8, 8031), (0x73b9, 8032), (0x73ba, 19559),
+    (0x73bb, 9408), (0x73bc, 8033), (0x73bd, 8034), (0x73be, 8035), (0x73bf, 8036), (0x73c0, 19549), (0x73c1, 8037), (0x73c2, 19545),
+    (0x73c3, 8038), (0x73c4, 8039), (0x73c5, 8040), (0x73c6, 8041), (0x73c7, 8042), (0x73c8, 19551), (0x73c9, 19550), (0x73ca, 13801),
+    (0x73cb, 8043), (0x73cc, 8044), (0x73cd, 16123), (0x73ce, 8045), (0x73cf, 19544), (0x73d0, 10364), (0x73d1, 19546), (0x73d2, 8046),
+    (0x73d3, 8047), (0x73d4, 8048), (0x73d5, 8049), (0x73d6, 8050), (0x73d7, 8051), (0x73d8, 8052), (0x73d9, 19553), (0x73da, 8053),
+    (0x73db, 8054), (0x73dc, 8055), (0x73dd, 8056), (0x73de, 19558), (0x73df, 8057), (0x73e0, 16318), (0x73e1, 8058), (0x73e2, 8059),
+    (0x73e3, 8060), (0x73e4, 8061), (0x73e5, 19552), (0x73e6, 8062), (0x73e7, 19557), (0x73e8, 8063), (0x73e9, 19556), (0x73ea, 8064),
+    (0x73eb, 8065), (0x73ec, 8066), (0x73ed, 9089), (0x73ee, 8067), (0x73ef, 8068), (0x73f0, 8069), (0x73f1, 8070), (0x73f2, 19560),
+    (0x73f3, 8071), (0x73f4, 8072), (0x73f5, 8073), (0x73f6, 8074), (0x73f7, 8075), (0x73f8, 8170), (0x73f9, 8171), (0x73fa, 8172),
+    (0x73fb, 8173), (0x73fc, 8174), (0x73fd, 8175), (0x73fe, 8176), (0x73ff, 8177), (0x7400, 8178), (0x7401, 8179), (0x7402, 8180),
+    (0x7403, 13477), (0x7404, 8181), (0x7405, 12102), (0x7406, 12142), (0x7407, 8182), (0x7408, 8183), (0x7409, 12335), (0x740a, 19555),
+    (0x740b, 8184), (0x740c, 8185), (0x740d, 8186), (0x740e, 8187), (0x740f, 19561), (0x7410, 14241), (0x7411, 8188), (0x7412, 8189),
+    (0x7413, 8190), (0x7414, 8191), (0x7415, 8192), (0x7416, 8193), (0x7417, 8194), (0x7418, 8195), (0x7419, 8196), (0x741a, 19667),
+    (0x741b, 19666), (0x741c, 8197), (0x741d, 8198), (0x741e, 8199), (0x741f, 8200), (0x7420, 8201), (0x7421, 8202), (0x7422, 16468),
+    (0x7423, 8203), (0x7424, 8204), (0x7425, 19565), (0x7426, 19564), (0x7427, 8205), (0x7428, 19566), (0x7429, 8206), (0x742a, 19562),
+    (0x742b, 8207), (0x742c, 19569), (0x742d, 8208), (0x742e, 19568), (0x742f, 8209), (0x7430, 19567), (0x7431, 8210), (0x7432, 8211),
+    (0x7433, 12308), (0x7434, 13452), (0x7435, 13108), (0x7436, 13050), (0x7437, 8212), (0x7438, 8213), (0x7439, 8214), (0x743a, 8215),
+    (0x743b, 8216), (0x743c, 13472), (0x743d, 8217), (0x743e, 8218), (0x743f, 8219), (0x7440, 8220), (0x7441, 19668), (0x7442, 8221),
+    (0x7443, 8222), (0x7444, 8223), (0x7445, 8224), (0x7446, 8225), (0x7447, 8226), (0x7448, 8227), (0x7449, 8228), (0x744a, 8229),
+    (0x744b, 8230), (0x744c, 8231), (0x744d, 8232), (0x744e, 8233), (0x744f, 8234), (0x7450, 8235), (0x7451, 8236), (0x7452, 8237),
+    (0x7453, 8238), (0x7454, 8239), (0x7455, 19671), (0x7456, 8240), (0x7457, 19670), (0x7458, 8241), (0x7459, 19672), (0x745a, 11012),
+    (0x745b, 19563), (0x745c, 19669), (0x745d, 8242), (0x745e, 13665), (0x745f, 13785), (0x7460, 8243), (0x7461, 8244), (0x7462, 8245),
+    (0x7463, 8246), (0x7464, 8247), (0x7465, 8248), (0x7466, 8249), (0x7467, 8250), (0x7468, 8251), (0x7469, 8252), (0x746a, 8253),
+    (0x746b, 8254), (0x746c, 8255), (0x746d, 19674), (0x746e, 8256), (0x746f, 8257), (0x7470, 10804), (0x7471, 8258), (0x7472, 8259),
+    (0x7473, 8260), (0x7474, 8261), (0x7475, 8262), (0x7476, 15389), (0x7477, 19673), (0x7478, 8263), (0x7479, 8264), (0x747a, 8265),
+    (0x747b, 8360), (0x747c, 8361), (0x747d, 8362), (0x747e, 19675), (0x747f, 8363), (0x7480, 19678), (0x7481, 19679), (0x7482, 8364),
+    (0x7483, 12262), (0x7484, 8365), (0x7485, 8366), (0x7486, 8367), (0x7487, 19680), (0x7488, 8368), (0x7489, 8369), (0x748a, 8370),
+    (0x748b, 19681), (0x748c, 8371), (0x748d, 8372), (0x748e, 19677), (0x748f, 8373), (0x7490, 19685), (0x7491, 8374), (0x7492, 8375),
+    (0x7493, 8376), (0x7494, 8377), (0x7495, 8378), (0x7496, 8379), (0x7497, 8380), (0x7498, 8381), (0x7499, 8382), (0x749a, 8383),
+    (0x749b, 8384), (0x749c, 19676), (0x749d, 8385), (0x749e, 19682), (0x749f, 8386), (0x74a0, 8387), (0x74a1, 8388), (0x74a2, 8389),
+    (0x74a3, 8390), (0x74a4, 8391), (0x74a5, 8392), (0x74a6, 8393), (0x74a7, 19686), (0x74a8, 19683), (0x74a9, 19684), (0x74aa, 8394),
+    (0x74ab, 8395), (0x74ac, 8396), (0x74ad, 8397), (0x74ae, 8398), (0x74af, 8399), (0x74b0, 8400), (0x74b1, 8401), (0x74b2, 8402),
+    (0x74b3, 8403), (0x74b4, 8404), (0x74b5, 8405), (0x74b6, 8406), (0x74b7, 8407), (0x74b8, 8408), (0x74b9, 8409), (0x74ba, 19688),
+    (0x74bb, 8410), (0x74bc, 8411), (0x74bd, 8412), (0x74be, 8413), (0x74bf, 8414), (0x74c0, 8415), (0x74c1, 8416), (0x74c2, 8417),
+    (0x74c3, 8418), (0x74c4, 8419), (0x74c5, 8420), (0x74c6, 8421), (0x74c7, 8422), (0x74c8, 8423), (0x74c9, 8424), (0x74ca, 8425),
+    (0x74cb, 8426), (0x74cc, 8427), (0x74cd, 8428), (0x74ce, 8429), (0x74cf, 8430), (0x74d0, 8431), (0x74d1, 8432), (0x74d2, 19687),
+    (0x74d3, 8433), (0x74d4, 8434), (0x74d5, 8435), (0x74d6, 8436), (0x74d7, 8437), (0x74d8, 8438), (0x74d9, 8439), (0x74da, 8440),
+    (0x74db, 8441), (0x74dc, 10782), (0x74dd, 8442), (0x74de, 21197), (0x74df, 8443), (0x74e0, 21198), (0x74e1, 8444), (0x74e2, 13221),
+    (0x74e3, 9099), (0x74e4, 13616), (0x74e5, 8445), (0x74e6, 14598), (0x74e7, 8446), (0x74e8, 8447), (0x74e9, 8448), (0x74ea, 8449),
+    (0x74eb, 8450), (0x74ec, 8451), (0x74ed, 8452), (0x74ee, 14770), (0x74ef, 20062), (0x74f0, 8453), (0x74f1, 8454), (0x74f2, 8455),
+    (0x74f3, 8550), (0x74f4, 20063), (0x74f5, 8551), (0x74f6, 13236), (0x74f7, 9826), (0x74f8, 8552), (0x74f9, 8553), (0x74fa, 8554),
+    (0x74fb, 8555), (0x74fc, 8556), (0x74fd, 8557), (0x74fe, 8558), (0x74ff, 20064), (0x7500, 8559), (0x7501, 8560), (0x7502, 8561),
+    (0x7503, 8562), (0x7504, 16126), (0x7505, 8563), (0x7506, 8564), (0x7507, 8565), (0x7508, 8566), (0x7509, 8567), (0x750a, 8568),
+    (0x750b, 8569), (0x750c, 8570), (0x750d, 17664), (0x750e, 8571), (0x750f, 20065), (0x7510, 8572), (0x7511, 20066), (0x7512, 8573),
+    (0x7513, 20067), (0x7514, 8574), (0x7515, 8575), (0x7516, 8576), (0x7517, 8577), (0x7518, 10587), (0x7519, 17971), (0x751a, 13860),
+    (0x751b, 8578), (0x751c, 14425), (0x751d, 8579), (0x751e, 8580), (0x751f, 13865), (0x7520, 8581), (0x7521, 8582), (0x7522, 8583),
+    (0x7523, 8584), (0x7524, 8585), (0x7525, 13866), (0x7526, 8586), (0x7527, 8587), (0x7528, 15710), (0x7529, 14161), (0x752a, 8588),
+    (0x752b, 10551), (0x752c, 21199), (0x752d, 9249), (0x752e, 8589), (0x752f, 19119), (0x7530, 14424), (0x7531, 15716), (0x7532, 11360),
+    (0x7533, 13849), (0x7534, 8590), (0x7535, 10046), (0x7536, 8591), (0x7537, 12873), (0x7538, 10048), (0x7539, 8592), (0x753a, 20819),
+    (0x753b, 11128), (0x753c, 8593), (0x753d, 8594), (0x753e, 19537), (0x753f, 8595), (0x7540, 20820), (0x7541, 8596), (0x7542, 8597),
+    (0x7543, 8598), (0x7544, 8599), (0x7545, 9604), (0x7546, 8600), (0x7547, 8601), (0x7548, 20823), (0x7549, 8602), (0x754a, 8603),
+    (0x754b, 20822), (0x754c, 11566), (0x754d, 8604), (0x754e, 20821), (0x754f, 14748), (0x7550, 8605), (0x7551, 8606), (0x7552, 8607),
+    (0x7553, 8608), (0x7554, 13062), (0x7555, 8609), (0x7556, 8610), (0x7557, 8611), (0x7558, 8612), (0x7559, 12339), (0x755a, 17241),
+    (0x755b, 20824), (0x755c, 15188), (0x755d, 8613), (0x755e, 8614), (0x755f, 8615), (0x7560, 8616), (0x7561, 8617), (0x7562, 8618),
+    (0x7563, 8619), (0x7564, 8620), (0x7565, 12497), (0x7566, 13277), (0x7567, 8621), (0x7568, 8622), (0x7569, 8623), (0x756a, 10367),
+    (0x756b, 8624), (0x756c, 8625), (0x756d, 8626), (0x756e, 8627), (0x756f, 8628), (0x7570, 8629), (0x7571, 8630), (0x7572, 20825),
+    (0x7573, 8631), (0x7574, 9670), (0x7575, 8632), (0x7576, 8633), (0x7577, 8634), (0x7578, 11206), (0x7579, 20826), (0x757a, 8635),
+    (0x757b, 8636), (0x757c, 8637), (0x757d, 8638), (0x757e, 8639), (0x757f, 19535), (0x7580, 8640), (0x7581, 8641), (0x7582, 8642),
+    (0x7583, 20827), (0x7584, 8643), (0x7585, 8644), (0x7586, 11509), (0x7587, 8645), (0x7588, 8740), (0x7589, 8741), (0x758a, 8742),
+    (0x758b, 21441), (0x758c, 8743), (0x758d, 8744), (0x758e, 8745), (0x758f, 14037), (0x7590, 8746), (0x7591, 15526), (0x7592, 21243),
+    (0x7593, 8747), (0x7594, 21244), (0x7595, 8748), (0x7596, 21245), (0x7597, 12293), (0x7598, 8749), (0x7599, 10622), (0x759a, 11731),
+    (0x759b, 8750), (0x759c, 8751), (0x759d, 21247), (0x759e, 8752), (0x759f, 13032), (0x75a0, 21246), (0x75a1, 15376), (0x75a2, 8753),
+    (0x75a3, 21249), (0x75a4, 9069), (0x75a5, 11569), (0x75a6, 8754), (0x75a7, 8755), (0x75a8, 8756), (0x75a9, 8757), (0x75aa, 8758),
+    (0x75ab, 15548), (0x75ac, 21248), (0x75ad, 8759), (0x75ae, 9800), (0x75af, 10427), (0x75b0, 21255), (0x75b1, 21254), (0x75b2, 13208),
+    (0x75b3, 21250), (0x75b4, 21251), (0x75b5, 9820), (0x75b6, 8760), (0x75b7, 8761), (0x75b8, 21252), (0x75b9, 16133), (0x75ba, 8762),
+    (0x75bb, 8763), (0x75bc, 14404), (0x75bd, 11735), (0x75be, 11323), (0x75bf, 8764), (0x75c0, 8765), (0x75c1, 8766), (0x75c2, 21257),
+    (0x75c3, 21256), (0x75c4, 21253), (0x75c5, 9406), (0x75c6, 8767), (0x75c7, 16247), (0x75c8, 15699), (0x75c9, 11708), (0x75ca, 13597),
+    (0x75cb, 8768), (0x75cc, 8769), (0x75cd, 21259), (0x75ce, 8770), (0x75cf, 8771), (0x75d0, 8772), (0x75d1, 8773), (0x75d2, 15382),
+    (0x75d3, 8774), (0x75d4, 16289), (0x75d5, 10984), (0x75d6, 21258), (0x75d7, 8775), (0x75d8, 10192), (0x75d9, 8776), (0x75da, 8777),
+    (0x75db, 14555), (0x75dc, 8778), (0x75dd, 8779), (0x75de, 13211), (0x75df, 8780), (0x75e0, 8781), (0x75e1, 8782), (0x75e2, 12256),
+    (0x75e3, 21260), (0x75e4, 21263), (0x75e5, 8783), (0x75e6, 21262), (0x75e7, 21265), (0x75e8, 21261), (0x75e9, 8784), (0x75ea, 11145),
+    (0x75eb, 21264), (0x75ec, 8785), (0x75ed, 8786), (0x75ee, 8787), (0x75ef, 8788), (0x75f0, 14366), (0x75f1, 21267), (0x75f2, 8789),
+    (0x75f3, 8790), (0x75f4, 9648), (0x75f5, 8791), (0x75f6, 8792), (0x75f7, 8793), (0x75f8, 8794), (0x75f9, 9267), (0x75fa, 8795),
+    (0x75fb, 8796), (0x75fc, 21268), (0x75fd, 8797), (0x75fe, 8798), (0x75ff, 21269), (0x7600, 21271), (0x7601, 9850), (0x7602, 8799),
+    (0x7603, 21266), (0x7604, 8800), (0x7605, 21272), (0x7606, 8801), (0x7607, 8802), (0x7608, 8803), (0x7609, 8804), (0x760a, 21275),
+    (0x760b, 8805), (0x760c, 21273), (0x760d, 8806), (0x760e, 8807), (0x760f, 8808), (0x7610, 21270), (0x7611, 8809), (0x7612, 8810),
+    (0x7613, 8811), (0x7614, 8812), (0x7615, 21278), (0x7616, 8813), (0x7617, 21274), (0x7618, 21277), (0x7619, 21279), (0x761a, 8814),
+    (0x761b, 21376), (0x761c, 8815), (0x761d, 8816), (0x761e, 8817), (0x761f, 14758), (0x7620, 21379), (0x7621, 8818), (0x7622, 21378),
+    (0x7623, 8819), (0x7624, 12341), (0x7625, 21276), (0x7626, 14026), (0x7627, 8820), (0x7628, 8821), (0x7629, 9866), (0x762a, 9296),
+    (0x762b, 14362), (0x762c, 8822), (0x762d, 21381), (0x762e, 8823), (0x762f, 8824), (0x7630, 21382), (0x7631, 8825), (0x7632, 8826),
+    (0x7633, 21387), (0x7634, 16101), (0x7635, 21384), (0x7636, 8827), (0x7637, 8828), (0x7638, 13604), (0x7639, 8829), (0x763a, 8830),
+    (0x763b, 8831), (0x763c, 21377), (0x763d, 8832), (0x763e, 21386), (0x763f, 21383), (0x7640, 21380), (0x7641, 8833), (0x7642, 8834),
+    (0x7643, 21385), (0x7644, 8835), (0x7645, 8930), (0x7646, 8931), (0x7647, 8932), (0x7648, 8933), (0x7649, 8934), (0x764a, 8935),
+    (0x764b, 8936), (0x764c, 9034), (0x764d, 21388), (0x764e, 8937), (0x764f, 8938), (0x7650, 8939), (0x7651, 8940), (0x7652, 8941),
+    (0x7653, 8942), (0x7654, 21390), (0x7655, 8943), (0x7656, 21392), (0x7657, 8944), (0x7658, 8945), (0x7659, 8946), (0x765a, 8947),
+    (0x765b, 8948), (0x765c, 21391), (0x765d, 8949), (0x765e, 21389), (0x765f, 8950), (0x7660, 8951), (0x7661, 8952), (0x7662, 8953),
+    (0x7663, 15297), (0x7664, 8954), (0x7665, 8955), (0x7666, 8956), (0x7667, 8957), (0x7668, 8958), (0x7669, 8959), (0x766a, 8960),
+    (0x766b, 21393), (0x766c, 8961), (0x766d, 8962), (0x766e, 8963), (0x766f, 21394), (0x7670, 8964), (0x7671, 8965), (0x7672, 8966),
+    (0x7673, 8967), (0x7674, 8968), (0x7675, 8969), (0x7676, 8970), (0x7677, 8971), (0x7678, 10814), (0x7679, 8972), (0x767a, 8973),
+    (0x767b, 10014), (0x767c, 8974), (0x767d, 9080), (0x767e, 9082), (0x767f, 8975), (0x7680, 8976), (0x7681, 8977), (0x7682, 15942),
+    (0x7683, 8978), (0x7684, 10011), (0x7685, 8979), (0x7686, 11547), (0x7687, 11157), (0x7688, 21192), (0x7689, 8980), (0x768a, 8981),
+    (0x768b, 10607), (0x768c, 8982), (0x768d, 8983), (0x768e, 21193), (0x768f, 8984), (0x7690, 8985), (0x7691, 9033), (0x7692, 8986),
+    (0x7693, 21194), (0x7694, 8987), (0x7695, 8988), (0x7696, 14613), (0x7697, 8989), (0x7698, 8990), (0x7699, 21195), (0x769a, 8991),
+    (0x769b, 8992), (0x769c, 8993), (0x769d, 8994), (0x769e, 8995), (0x769f, 8996), (0x76a0, 8997), (0x76a1, 8998), (0x76a2, 8999),
+    (0x76a3, 9000), (0x76a4, 21196), (0x76a5, 9001), (0x76a6, 9002), (0x76a7, 9003), (0x76a8, 9004), (0x76a9, 9005), (0x76aa, 9006),
+    (0x76ab, 9007), (0x76ac, 9008), (0x76ad, 9009), (0x76ae, 13209), (0x76af, 9010), (0x76b0, 9011), (0x76b1, 16314), (0x76b2, 21443),
+    (0x76b3, 9012), (0x76b4, 21444), (0x76b5, 9013), (0x76b6, 9014), (0x76b7, 9015), (0x76b8, 9016), (0x76b9, 9017), (0x76ba, 9018),
+    (0x76bb, 9019), (0x76bc, 9020), (0x76bd, 9021), (0x76be, 9022), (0x76bf, 12718), (0x76c0, 9023), (0x76c1, 9024), (0x76c2, 15734),
+    (0x76c3, 9025), (0x76c4, 9120), (0x76c5, 16294), (0x76c6, 13087), (0x76c7, 9121), (0x76c8, 15690), (0x76c9, 9122), (0x76ca, 15555),
+    (0x76cb, 9123), (0x76cc, 9124), (0x76cd, 20838), (0x76ce, 9052), (0x76cf, 16076), (0x76d0, 15341), (0x76d1, 11369), (0x76d2, 10973),
+    (0x76d3, 9125), (0x76d4, 11963), (0x76d5, 9126), (0x76d6, 10584), (0x76d7, 10008), (0x76d8, 13059), (0x76d9, 9127), (0x76da, 9128),
+    (0x76db, 13967), (0x76dc, 9129), (0x76dd, 9130), (0x76de, 9131), (0x76df, 12678), (0x76e0, 9132), (0x76e1, 9133), (0x76e2, 9134),
+    (0x76e3, 9135), (0x76e4, 9136), (0x76e5, 20839), (0x76e6, 9137), (0x76e7, 9138), (0x76e8, 9139), (0x76e9, 9140), (0x76ea, 9141),
+    (0x76eb, 9142), (0x76ec, 9143), (0x76ed, 9144), (0x76ee, 12856), (0x76ef, 10167), (0x76f0, 9145), (0x76f1, 20691), (0x76f2, 12639),
+    (0x76f3, 9146), (0x76f4, 16262), (0x76f5, 9147), (0x76f6, 9148), (0x76f7, 9149), (0x76f8, 14979), (0x76f9, 20694), (0x76fa, 9150),
+    (0x76fb, 9151), (0x76fc, 13061), (0x76fd, 9152), (0x76fe, 10225), (0x76ff, 9153), (0x7700, 9154), (0x7701, 13966), (0x7702, 9155),
+    (0x7703, 9156), (0x7704, 20692), (0x7705, 9157), (0x7706, 9158), (0x7707, 20695), (0x7708, 20696), (0x7709, 12663), (0x770a, 9159),
+    (0x770b, 11895), (0x770c, 9160), (0x770d, 20693), (0x770e, 9161), (0x770f, 9162), (0x7710, 9163), (0x7711, 9164), (0x7712, 9165),
+    (0x7713, 9166), (0x7714, 9167), (0x7715, 9168), (0x7716, 9169), (0x7717, 9170), (0x7718, 9171), (0x7719, 20699), (0x771a, 20697),
+    (0x771b, 9172), (0x771c, 9173), (0x771d, 9174), (0x771e, 9175), (0x771f, 16125), (0x7720, 12698), (0x7721, 9176), (0x7722, 20698),
+    (0x7723, 9177), (0x7724, 9178), (0x7725, 9179), (0x7726, 20701), (0x7727, 9180), (0x7728, 16058), (0x7729, 15298), (0x772a, 9181),
+    (0x772b, 9182), (0x772c, 9183), (0x772d, 20700), (0x772e, 9184), (0x772f, 12683), (0x7730, 9185), (0x7731, 9186), (0x7732, 9187),
+    (0x7733, 9188), (0x7734, 9189), (0x7735, 20702), (0x7736, 11959), (0x7737, 11761), (0x7738, 20703), (0x7739, 9190), (0x773a, 14432),
+    (0x773b, 9191), (0x773c, 15354), (0x773d, 9192), (0x773e, 9193), (0x773f, 9194), (0x7740, 16472), (0x7741, 16141), (0x7742, 9195),
+    (0x7743, 20707), (0x7744, 9196), (0x7745, 9197), (0x7746, 9198), (0x7747, 20706), (0x7748, 9199), (0x7749, 9200), (0x774a, 9201),
+    (0x774b, 9202), (0x774c, 9203), (0x774d, 9204), (0x774e, 9205), (0x774f, 9206), (0x7750, 20704), (0x7751, 20705), (0x7752, 9207),
+    (0x7753, 9208), (0x7754, 9209), (0x7755, 9210), (0x7756, 9211), (0x7757, 9212), (0x7758, 9213), (0x7759, 9214), (0x775a, 20708),
+    (0x775b, 11691), (0x775c, 9215), (0x775d, 9310), (0x775e, 9311), (0x775f, 9312), (0x7760, 9313), (0x7761, 14170), (0x7762, 20806),
+    (0x7763, 10194), (0x7764, 9314), (0x7765, 20807), (0x7766, 12857), (0x7767, 9315), (0x7768, 20709), (0x7769, 9316), (0x776a, 9317),
+    (0x776b, 11557), (0x776c, 9444), (0x776d, 9318), (0x776e, 9319), (0x776f, 9320), (0x7770, 9321), (0x7771, 9322), (0x7772, 9323),
+    (0x7773, 9324), (0x7774, 9325), (0x7775, 9326), (0x7776, 9327), (0x7777, 9328), (0x7778, 9329), (0x7779, 10200), (0x777a, 9330),
+    (0x777b, 9331), (0x777c, 9332), (0x777d, 20810), (0x777e, 16651), (0x777f, 20808), (0x7780, 20811), (0x7781, 9333), (0x7782, 9334),
+    (0x7783, 9335), (0x7784, 12708), (0x7785, 9677), (0x7786, 9336), (0x7787, 9337), (0x7788, 9338), (0x7789, 9339), (0x778a, 9340),
+    (0x778b, 9341), (0x778c, 20812), (0x778d, 20809), (0x778e, 14940), (0x778f, 9342), (0x7790, 9343), (0x7791, 20813), (0x7792, 12532),
+    (0x7793, 9344), (0x7794, 9345), (0x7795, 9346), (0x7796, 9347), (0x7797, 9348), (0x7798, 9349), (0x7799, 9350), (0x779a, 9351),
+    (0x779b, 9352), (0x779c, 9353), (0x779d, 9354), (0x779e, 9355), (0x779f, 20814), (0x77a0, 20815), (0x77a1, 9356), (0x77a2, 17776),
+    (0x77a3, 9357), (0x77a4, 9358), (0x77a5, 13224), (0x77a6, 9359), (0x77a7, 13433), (0x77a8, 9360), (0x77a9, 16330), (0x77aa, 10016),
+    (0x77ab, 9361), (0x77ac, 14173), (0x77ad, 9362), (0x77ae, 9363), (0x77af, 9364), (0x77b0, 20816), (0x77b1, 9365), (0x77b2, 9366),
+    (0x77b3, 14546), (0x77b4, 9367), (0x77b5, 20817), (0x77b6, 9368), (0x77b7, 9369), (0x77b8, 9370), (0x77b9, 9371), (0x77ba, 9372),
+    (0x77bb, 16071), (0x77bc, 9373), (0x77bd, 20818), (0x77be, 9374), (0x77bf, 22361), (0x77c0, 9375), (0x77c1, 9376), (0x77c2, 9377),
+    (0x77c3, 9378), (0x77c4, 9379), (0x77c5, 9380), (0x77c6, 9381), (0x77c7, 9382), (0x77c8, 9383), (0x77c9, 9384), (0x77ca, 9385),
+    (0x77cb, 9386), (0x77cc, 9387), (0x77cd, 17234), (0x77ce, 9388), (0x77cf, 9389), (0x77d0, 9390), (0x77d1, 9391), (0x77d2, 9392),
+    (0x77d3, 9393), (0x77d4, 9394), (0x77d5, 9395), (0x77d6, 9396), (0x77d7, 9788), (0x77d8, 9397), (0x77d9, 9398), (0x77da, 9399),
+    (0x77db, 12647), (0x77dc, 21445), (0x77dd, 9400), (0x77de, 9401), (0x77df, 9402), (0x77e0, 9403), (0x77e1, 9404), (0x77e2, 13989),
+    (0x77e3, 15536), (0x77e4, 9405), (0x77e5, 16255), (0x77e6, 9500), (0x77e7, 21077), (0x77e8, 9501), (0x77e9, 11741), (0x77ea, 9502),
+    (0x77eb, 11530), (0x77ec, 21078), (0x77ed, 10209), (0x77ee, 9036), (0x77ef, 9503), (0x77f0, 9504), (0x77f1, 9505), (0x77f2, 9506),
+    (0x77f3, 13980), (0x77f4, 9507), (0x77f5, 9508), (0x77f6, 20637), (0x77f7, 9509), (0x77f8, 20638), (0x77f9, 9510), (0x77fa, 9511),
+    (0x77fb, 9512), (0x77fc, 9513), (0x77fd, 14814), (0x77fe, 10370), (0x77ff, 11958), (0x7800, 20639), (0x7801, 12520), (0x7802, 13791),
+    (0x7803, 9514), (0x7804, 9515), (0x7805, 9516), (0x7806, 9517), (0x7807, 9518), (0x7808, 9519), (0x7809, 20640), (0x780a, 9520),
+    (0x780b, 9521), (0x780c, 13291), (0x780d, 11894), (0x780e, 9522), (0x780f, 9523), (0x7810, 9524), (0x7811, 20643), (0x7812, 13103),
+    (0x7813, 9525), (0x7814, 15343), (0x7815, 9526), (0x7816, 16444), (0x7817, 20641), (0x7818, 20642), (0x7819, 9527), (0x781a, 15361),
+    (0x781b, 9528), (0x781c, 20646), (0x781d, 20647), (0x781e, 9529), (0x781f, 20651), (0x7820, 9530), (0x7821, 9531), (0x7822, 9532),
+    (0x7823, 20655), (0x7824, 9533), (0x7825, 20653), (0x7826, 20661), (0x7827, 16127), (0x7828, 9534), (0x7829, 20656), (0x782a, 9535),
+    (0x782b, 9536), (0x782c, 20654), (0x782d, 20645), (0x782e, 9537), (0x782f, 9538), (0x7830, 13088), (0x7831, 9539), (0x7832, 9540),
+    (0x7833, 9541), (0x7834, 13243), (0x7835, 9542), (0x7836, 9543), (0x7837, 13848), (0x7838, 15915), (0x7839, 20648), (0x783a, 20649),
+    (0x783b, 20650), (0x783c, 20652), (0x783d, 9544), (0x783e, 12154), (0x783f, 9545), (0x7840, 9786), (0x7841, 9546), (0x7842, 9547),
+    (0x7843, 9548), (0x7844, 9549), (0x7845, 10807), (0x7846, 9550), (0x7847, 20663), (0x7848, 9551), (0x7849, 9552), (0x784a, 9553),
+    (0x784b, 9554), (0x784c, 20664), (0x784d, 9555), (0x784e, 20657), (0x784f, 9556), (0x7850, 20662), (0x7851, 9557), (0x7852, 14813),
+    (0x7853, 9558), (0x7854, 9559), (0x7855, 14177), (0x7856, 20659), (0x7857, 20660), (0x7858, 9560), (0x7859, 9561), (0x785a, 9562),
+    (0x785b, 9563), (0x785c, 9564), (0x785d, 15000), (0x785e, 9565), (0x785f, 9566), (0x7860, 9567), (0x7861, 9568), (0x7862, 9569),
+    (0x7863, 9570), (0x7864, 9571), (0x7865, 9572), (0x7866, 9573), (0x7867, 9574), (0x7868, 9575), (0x7869, 9576), (0x786a, 20665),
+    (0x786b, 12337), (0x786c, 15693), (0x786d, 20658), (0x786e, 13608), (0x786f, 9577), (0x7870, 9578), (0x7871, 9579), (0x7872, 9580),
+    (0x7873, 9581), (0x7874, 9582), (0x7875, 9583), (0x7876, 9584), (0x7877, 11384), (0x7878, 9585), (0x7879, 9586), (0x787a, 9587),
+    (0x787b, 9588), (0x787c, 13095), (0x787d, 9589), (0x787e, 9590), (0x787f, 9591), (0x7880, 9592), (0x7881, 9593), (0x7882, 9594),
+    (0x7883, 9595), (0x7884, 9690), (0x7885, 9691), (0x7886, 9692), (0x7887, 20669), (0x7888, 9693), (0x7889, 10054), (0x788a, 9694),
+    (0x788b, 9695), (0x788c, 12466), (0x788d, 9038), (0x788e, 14228), (0x788f, 9696), (0x7890, 9697), (0x7891, 9229), (0x7892, 9698),
+    (0x7893, 20667), (0x7894, 9699), (0x7895, 9700), (0x7896, 9701), (0x7897, 14610), (0x7898, 10041), (0x7899, 9702), (0x789a, 20668),
+    (0x789b, 20666), (0x789c, 20670), (0x789d, 9703), (0x789e, 9704), (0x789f, 10065), (0x78a0, 9705), (0x78a1, 20671), (0x78a2, 9706),
+    (0x78a3, 20672), (0x78a4, 9707), (0x78a5, 20675), (0x78a6, 9708), (0x78a7, 9259), (0x78a8, 9709), (0x78a9, 9710), (0x78aa, 9711),
+    (0x78ab, 9712), (0x78ac, 9713), (0x78ad, 9714), (0x78ae, 9715), (0x78af, 9716), (0x78b0, 13101), (0x78b1, 11383), (0x78b2, 20673),
+    (0x78b3, 14373), (0x78b4, 9479), (0x78b5, 9717), (0x78b6, 9718), (0x78b7, 9719), (0x78b8, 9720), (0x78b9, 20674), (0x78ba, 9721),
+    (0x78bb, 9722), (0x78bc, 9723), (0x78bd, 9724), (0x78be, 12900), (0x78bf, 9725), (0x78c0, 9726), (0x78c1, 9822), (0x78c2, 9727),
+    (0x78c3, 9728), (0x78c4, 9729), (0x78c5, 9110), (0x78c6, 9730), (0x78c7, 9731), (0x78c8, 9732), (0x78c9, 20678), (0x78ca, 12123),
+    (0x78cb, 9857), (0x78cc, 9733), (0x78cd, 9734), (0x78ce, 9735), (0x78cf, 9736), (0x78d0, 13060), (0x78d1, 9737), (0x78d2, 9738),
+    (0x78d3, 9739), (0x78d4, 20676), (0x78d5, 11911), (0x78d6, 9740), (0x78d7, 9741), (0x78d8, 9742), (0x78d9, 20677), (0x78da, 9743),
+    (0x78db, 9744), (0x78dc, 9745), (0x78dd, 9746), (0x78de, 9747), (0x78df, 9748), (0x78e0, 9749), (0x78e1, 9750), (0x78e2, 9751),
+    (0x78e3, 9752), (0x78e4, 9753), (0x78e5, 9754), (0x78e6, 9755), (0x78e7, 9756), (0x78e8, 12830), (0x78e9, 9757), (0x78ea, 9758),
+    (0x78eb, 9759), (0x78ec, 20679), (0x78ed, 9760), (0x78ee, 9761), (0x78ef, 9762), (0x78f0, 9763), (0x78f1, 9764), (0x78f2, 20680),
+    (0x78f3, 9765), (0x78f4, 20682), (0x78f5, 9766), (0x78f6, 9767), (0x78f7, 12310), (0x78f8, 9768), (0x78f9, 9769), (0x78fa, 11154),
+    (0x78fb, 9770), (0x78fc, 9771), (0x78fd, 9772), (0x78fe, 9773), (0x78ff, 9774), (0x7900, 9775), (0x7901, 11519), (0x7902, 9776),
+    (0x7903, 9777), (0x7904, 9778), (0x7905, 20681), (0x7906, 9779), (0x7907, 9780), (0x7908, 9781), (0x7909, 9782), (0x790a, 9783),
+    (0x790b, 9784), (0x790c, 9785), (0x790d, 9880), (0x790e, 9881), (0x790f, 9882), (0x7910, 9883), (0x7911, 9884), (0x7912, 9885),
+    (0x7913, 20683), (0x7914, 9886), (0x7915, 9887), (0x7916, 9888), (0x7917, 9889), (0x7918, 9890), (0x7919, 9891), (0x791a, 9892),
+    (0x791b, 9893), (0x791c, 9894), (0x791d, 9895), (0x791e, 20685), (0x791f, 9896), (0x7920, 9897), (0x7921, 9898), (0x7922, 9899),
+    (0x7923, 9900), (0x7924, 20684), (0x7925, 9901), (0x7926, 9902), (0x7927, 9903), (0x7928, 9904), (0x7929, 9905), (0x792a, 9906),
+    (0x792b, 9907), (0x792c, 9908), (0x792d, 9909), (0x792e, 9910), (0x792f, 9911), (0x7930, 9912), (0x7931, 9913), (0x7932, 9914),
+    (0x7933, 9915), (0x7934, 20686), (0x7935, 9916), (0x7936, 9917), (0x7937, 9918), (0x7938, 9919), (0x7939, 9920), (0x793a, 13995),
+    (0x793b, 20499), (0x793c, 12146), (0x793d, 9921), (0x793e, 13846), (0x793f, 9922), (0x7940, 20500), (0x7941, 13283), (0x7942, 9923),
+    (0x7943, 9924), (0x7944, 9925), (0x7945, 9926), (0x7946, 20501), (0x7947, 9927), (0x7948, 13282), (0x7949, 20502), (0x794a, 9928),
+    (0x794b, 9929), (0x794c, 9930), (0x794d, 9931), (0x794e, 9932), (0x794f, 9933), (0x7950, 9934), (0x7951, 9935), (0x7952, 9936),
+    (0x7953, 20505), (0x7954, 9937), (0x7955, 9938), (0x7956, 16505), (0x7957, 20508), (0x7958, 9939), (0x7959, 9940), (0x795a, 20506),
+    (0x795b, 20503), (0x795c, 20504), (0x795d, 16438), (0x795e, 13856), (0x795f, 14233), (0x7960, 20509), (0x7961, 9941), (0x7962, 20507),
+    (0x7963, 9942), (0x7964, 9943), (0x7965, 14988), (0x7966, 9944), (0x7967, 20511), (0x7968, 13222), (0x7969, 9945), (0x796a, 9946),
+    (0x796b, 9947), (0x796c, 9948), (0x796d, 11337), (0x796e, 9949), (0x796f, 20510), (0x7970, 9950), (0x7971, 9951), (0x7972, 9952),
+    (0x7973, 9953), (0x7974, 9954), (0x7975, 9955), (0x7976, 9956), (0x7977, 10002), (0x7978, 11201), (0x7979, 9957), (0x797a, 20512),
+    (0x797b, 9958), (0x797c, 9959), (0x797d, 9960), (0x797e, 9961), (0x797f, 9962), (0x7980, 16902), (0x7981, 11586), (0x7982, 9963),
+    (0x7983, 9964), (0x7984, 12472), (0x7985, 20513), (0x7986, 9965), (0x7987, 9966), (0x7988, 9967), (0x7989, 9968), (0x798a, 20514),
+    (0x798b, 9969), (0x798c, 9970), (0x798d, 9971), (0x798e, 9972), (0x798f, 10548), (0x7990, 9973), (0x7991, 9974), (0x7992, 9975),
+    (0x7993, 10070), (0x7994, 10071), (0x7995, 10072), (0x7996, 10073), (0x7997, 10074), (0x7998, 10075), (0x7999, 10076), (0x799a, 20515),
+    (0x799b, 10077), (0x799c, 10078), (0x799d, 10079), (0x799e, 10080), (0x799f, 10081), (0x79a0, 10082), (0x79a1, 10083), (0x79a2, 10084),
+    (0x79a3, 10085), (0x79a4, 10086), (0x79a5, 10087), (0x79a6, 10088), (0x79a7, 20516), (0x79a8, 10089), (0x79a9, 10090), (0x79aa, 10091),
+    (0x79ab, 10092), (0x79ac, 10093), (0x79ad, 10094), (0x79ae, 10095), (0x79af, 10096), (0x79b0, 10097), (0x79b1, 10098), (0x79b2, 10099),
+    (0x79b3, 20517), (0x79b4, 10100), (0x79b5, 10101), (0x79b6, 10102), (0x79b7, 10103), (0x79b8, 10104), (0x79b9, 15752), (0x79ba, 16639),
+    (0x79bb, 12140), (0x79bc, 10105), (0x79bd, 13456), (0x79be, 10969), (0x79bf, 10106), (0x79c0, 15172), (0x79c1, 14184), (0x79c2, 10107),
+    (0x79c3, 14561), (0x79c4, 10108), (0x79c5, 10109), (0x79c6, 10594), (0x79c7, 10110), (0x79c8, 10111), (0x79c9, 9307), (0x79ca, 10112),
+    (0x79cb, 13474), (0x79cc, 10113), (0x79cd, 16299), (0x79ce, 10114), (0x79cf, 10115), (0x79d0, 10116), (0x79d1, 11913), (0x79d2, 12710),
+    (0x79d3, 10117), (0x79d4, 10118), (0x79d5, 21080), (0x79d6, 10119), (0x79d7, 10120), (0x79d8, 12691), (0x79d9, 10121), (0x79da, 10122),
+    (0x79db, 10123), (0x79dc, 10124), (0x79dd, 10125), (0x79de, 10126), (0x79df, 16501), (0x79e0, 10127), (0x79e1, 10128), (0x79e2, 10129),
+    (0x79e3, 21082), (0x79e4, 9646), (0x79e5, 10130), (0x79e6, 13451), (0x79e7, 15372), (0x79e8, 10131), (0x79e9, 16285), (0x79ea, 10132),
+    (0x79eb, 21083), (0x79ec, 10133), (0x79ed, 21081), (0x79ee, 10134), (0x79ef, 11208), (0x79f0, 9633), (0x79f1, 10135), (0x79f2, 10136),
+    (0x79f3, 10137), (0x79f4, 10138), (0x79f5, 10139), (0x79f6, 10140), (0x79f7, 10141), (0x79f8, 11548), (0x79f9, 10142), (0x79fa, 10143),
+    (0x79fb, 15523), (0x79fc, 10144), (0x79fd, 11179), (0x79fe, 10145), (0x79ff, 10146), (0x7a00, 14916), (0x7a01, 10147), (0x7a02, 21087),
+    (0x7a03, 21086), (0x7a04, 10148), (0x7a05, 10149), (0x7a06, 21084), (0x7a07, 10150), (0x7a08, 10151), (0x7a09, 10152), (0x7a0a, 10153),
+    (0x7a0b, 9639), (0x7a0c, 10154), (0x7a0d, 13827), (0x7a0e, 14171), (0x7a0f, 10155), (0x7a10, 10156), (0x7a11, 10157), (0x7a12, 10158),
+    (0x7a13, 10159), (0x7a14, 21089), (0x7a15, 10160), (0x7a16, 10161), (0x7a17, 9087), (0x7a18, 10162), (0x7a19, 10163), (0x7a1a, 16286),
+    (0x7a1b, 10164), (0x7a1c, 10165), (0x7a1d, 10260), (0x7a1e, 21088), (0x7a1f, 10261), (0x7a20, 9672), (0x7a21, 10262), (0x7a22, 10263),
+    (0x7a23, 22378), (0x7a24, 10264), (0x7a25, 10265), (0x7a26, 10266), (0x7a27, 10267), (0x7a28, 10268), (0x7a29, 10269), (0x7a2a, 10270),
+    (0x7a2b, 10271), (0x7a2c, 10272), (0x7a2d, 10273), (0x7a2e, 10274), (0x7a2f, 10275), (0x7a30, 10276), (0x7a31, 10277), (0x7a32, 10278),
+    (0x7a33, 14765), (0x7a34, 10279), (0x7a35, 10280), (0x7a36, 10281), (0x7a37, 21187), (0x7a38, 10282), (0x7a39, 21186), (0x7a3a, 10283),
+    (0x7a3b, 10005), (0x7a3c, 11363), (0x7a3d, 11207), (0x7a3e, 10284), (0x7a3f, 10614), (0x7a40, 10285), (0x7a41, 10286), (0x7a42, 10287),
+    (0x7a43, 10288), (0x7a44, 10289), (0x7a45, 10290), (0x7a46, 12859), (0x7a47, 10291), (0x7a48, 10292), (0x7a49, 10293), (0x7a4a, 10294),
+    (0x7a4b, 10295), (0x7a4c, 10296), (0x7a4d, 10297), (0x7a4e, 10298), (0x7a4f, 10299), (0x7a50, 10300), (0x7a51, 21188), (0x7a52, 10301),
+    (0x7a53, 10302), (0x7a54, 10303), (0x7a55, 10304), (0x7a56, 10305), (0x7a57, 14230), (0x7a58, 10306), (0x7a59, 10307), (0x7a5a, 10308),
+    (0x7a5b, 10309), (0x7a5c, 10310), (0x7a5d, 10311), (0x7a5e, 10312), (0x7a5f, 10313), (0x7a60, 10314), (0x7a61, 10315), (0x7a62, 10316),
+    (0x7a63, 10317), (0x7a64, 10318), (0x7a65, 10319), (0x7a66, 10320), (0x7a67, 10321), (0x7a68, 10322), (0x7a69, 10323), (0x7a6a, 10324),
+    (0x7a6b, 10325), (0x7a6c, 10326), (0x7a6d, 10327), (0x7a6e, 10328), (0x7a6f, 10329), (0x7a70, 21191), (0x7a71, 10330), (0x7a72, 10331),
+    (0x7a73, 10332), (0x7a74, 15303), (0x7a75, 10333), (0x7a76, 11716), (0x7a77, 13473), (0x7a78, 21397), (0x7a79, 21398), (0x7a7a, 11928),
+    (0x7a7b, 10334), (0x7a7c, 10335), (0x7a7d, 10336), (0x7a7e, 10337), (0x7a7f, 9794), (0x7a80, 21399), (0x7a81, 14562), (0x7a82, 10338),
+    (0x7a83, 13447), (0x7a84, 16068), (0x7a85, 10339), (0x7a86, 21400), (0x7a87, 10340), (0x7a88, 21401), (0x7a89, 10341), (0x7a8a, 10342),
+    (0x7a8b, 10343), (0x7a8c, 10344), (0x7a8d, 13442), (0x7a8e, 10345), (0x7a8f, 10346), (0x7a90, 10347), (0x7a91, 15489), (0x7a92, 16292),
+    (0x7a93, 10348), (0x7a94, 10349), (0x7a95, 21402), (0x7a96, 11544), (0x7a97, 9801), (0x7a98, 11714), (0x7a99, 10350), (0x7a9a, 10351),
+    (0x7a9b, 10352), (0x7a9c, 9845), (0x7a9d, 14774), (0x7a9e, 10353), (0x7a9f, 11938), (0x7aa0, 21404), (0x7aa1, 10354), (0x7aa2, 10355),
+    (0x7aa3, 10450), (0x7aa4, 10451), (0x7aa5, 11965), (0x7aa6, 21403), (0x7aa7, 10452), (0x7aa8, 21406), (0x7aa9, 10453), (0x7aaa, 10454),
+    (0x7aab, 10455), (0x7aac, 21405), (0x7aad, 21407), (0x7aae, 10456), (0x7aaf, 10457), (0x7ab0, 10458), (0x7ab1, 10459), (0x7ab2, 10460),
+    (0x7ab3, 21408), (0x7ab4, 10461), (0x7ab5, 10462), (0x7ab6, 10463), (0x7ab7, 10464), (0x7ab8, 10465), (0x7ab9, 10466), (0x7aba, 10467),
+    (0x7abb, 10468), (0x7abc, 10469), (0x7abd, 10470), (0x7abe, 10471), (0x7abf, 12349), (0x7ac0, 10472), (0x7ac1, 10473), (0x7ac2, 10474),
+    (0x7ac3, 10475), (0x7ac4, 10476), (0x7ac5, 10477), (0x7ac6, 10478), (0x7ac7, 10479), (0x7ac8, 10480), (0x7ac9, 10481), (0x7aca, 10482),
+    (0x7acb, 12257), (0x7acc, 10483), (0x7acd, 10484), (0x7ace, 10485), (0x7acf, 10486), (0x7ad0, 10487), (0x7ad1, 10488), (0x7ad2, 10489),
+    (0x7ad3, 10490), (0x7ad4, 10491), (0x7ad5, 10492), (0x7ad6, 14055), (0x7ad7, 10493), (0x7ad8, 10494), (0x7ad9, 16085), (0x7ada, 10495),
+    (0x7adb, 10496), (0x7adc, 10497), (0x7add, 10498), (0x7ade, 11711), (0x7adf, 11710), (0x7ae0, 16089), (0x7ae1, 10499), (0x7ae2, 10500),
+    (0x7ae3, 11877), (0x7ae4, 10501), (0x7ae5, 14550), (0x7ae6, 21396), (0x7ae7, 10502), (0x7ae8, 10503), (0x7ae9, 10504), (0x7aea, 10505),
+    (0x7aeb, 10506), (0x7aec, 10507), (0x7aed, 11558), (0x7aee, 10508), (0x7aef, 10208), (0x7af0, 10509), (0x7af1, 10510), (0x7af2, 10511),
+    (0x7af3, 10512), (0x7af4, 10513), (0x7af5, 10514), (0x7af6, 10515), (0x7af7, 10516), (0x7af8, 10517), (0x7af9, 16326), (0x7afa, 21790),
+    (0x7afb, 10518), (0x7afc, 10519), (0x7afd, 21791), (0x7afe, 10520), (0x7aff, 10590), (0x7b00, 10521), (0x7b01, 10522), (0x7b02, 10523),
+    (0x7b03, 21793), (0x7b04, 21794), (0x7b05, 10524), (0x7b06, 9067), (0x7b07, 10525), (0x7b08, 21792), (0x7b09, 10526), (0x7b0a, 21796),
+    (0x7b0b, 14236), (0x7b0c, 10527), (0x7b0d, 10528), (0x7b0e, 10529), (0x7b0f, 21798), (0x7b10, 10530), (0x7b11, 15111), (0x7b12, 10531),
+    (0x7b13, 10532), (0x7b14, 9257), (0x7b15, 21795), (0x7b16, 10533), (0x7b17, 10534), (0x7b18, 10535), (0x7b19, 21802), (0x7b1a, 10536),
+    (0x7b1b, 10024), (0x7b1c, 10537), (0x7b1d, 10538), (0x7b1e, 21810), (0x7b1f, 10539), (0x7b20, 21805), (0x7b21, 10540), (0x7b22, 10541),
+    (0x7b23, 10542), (0x7b24, 21807), (0x7b25, 21806), (0x7b26, 10446), (0x7b27, 10543), (0x7b28, 9246), (0x7b29, 10544), (0x7b2a, 21801),
+    (0x7b2b, 21797), (0x7b2c, 10033), (0x7b2d, 10545), (0x7b2e, 21803), (0x7b2f, 10640), (0x7b30, 10641), (0x7b31, 21804), (0x7b32, 10642),
+    (0x7b33, 21808), (0x7b34, 10643), (0x7b35, 10644), (0x7b36, 10645), (0x7b37, 10646), (0x7b38, 21800), (0x7b39, 10647), (0x7b3a, 11372),
+    (0x7b3b, 10648), (0x7b3c, 12348), (0x7b3d, 10649), (0x7b3e, 21809), (0x7b3f, 10650), (0x7b40, 10651), (0x7b41, 10652), (0x7b42, 10653),
+    (0x7b43, 10654), (0x7b44, 10655), (0x7b45, 21813), (0x7b46, 10656), (0x7b47, 21799), (0x7b48, 10657), (0x7b49, 10015), (0x7b4a, 10658),
+    (0x7b4b, 11573), (0x7b4c, 21815), (0x7b4d, 10659), (0x7b4e, 10660), (0x7b4f, 10359), (0x7b50, 11955), (0x7b51, 16339), (0x7b52, 14553),
+    (0x7b53, 10661), (0x7b54, 9865), (0x7b55, 10662), (0x7b56, 9468), (0x7b57, 10663), (0x7b58, 21811), (0x7b59, 10664), (0x7b5a, 21812),
+    (0x7b5b, 13799), (0x7b5c, 10665), (0x7b5d, 21816), (0x7b5e, 10666), (0x7b5f, 10667), (0x7b60, 21817), (0x7b61, 10668), (0x7b62, 21820),
+    (0x7b63, 10669), (0x7b64, 10670), (0x7b65, 10671), (0x7b66, 10672), (0x7b67, 10673), (0x7b68, 10674), (0x7b69, 10675), (0x7b6a, 10676),
+    (0x7b6b, 10677), (0x7b6c, 10678), (0x7b6d, 10679), (0x7b6e, 21818), (0x7b6f, 10680), (0x7b70, 10681), (0x7b71, 21822), (0x7b72, 21821),
+    (0x7b73, 10682), (0x7b74, 10683), (0x7b75, 21814), (0x7b76, 10684), (0x7b77, 11949), (0x7b78, 10685), (0x7b79, 9674), (0x7b7a, 10686),
+    (0x7b7b, 21819), (0x7b7c, 10687), (0x7b7d, 10688), (0x7b7e, 13404), (0x7b7f, 10689), (0x7b80, 11387), (0x7b81, 10690), (0x7b82, 10691),
+    (0x7b83, 10692), (0x7b84, 10693), (0x7b85, 21830), (0x7b86, 10694), (0x7b87, 10695), (0x7b88, 10696), (0x7b89, 10697), (0x7b8a, 10698),
+    (0x7b8b, 10699), (0x7b8c, 10700), (0x7b8d, 10766), (0x7b8e, 10701), (0x7b8f, 10702), (0x7b90, 21823), (0x7b91, 10703), (0x7b92, 10704),
+    (0x7b93, 10705), (0x7b94, 9418), (0x7b95, 11209), (0x7b96, 10706), (0x7b97, 14222), (0x7b98, 10707), (0x7b99, 10708), (0x7b9a, 10709),
+    (0x7b9b, 10710), (0x7b9c, 21832), (0x7b9d, 21828), (0x7b9e, 10711), (0x7b9f, 10712), (0x7ba0, 10713), (0x7ba1, 10795), (0x7ba2, 21833),
+    (0x7ba3, 10714), (0x7ba4, 10715), (0x7ba5, 10716), (0x7ba6, 21824), (0x7ba7, 21825), (0x7ba8, 21829), (0x7ba9, 12510), (0x7baa, 21831),
+    (0x7bab, 21834), (0x7bac, 21827), (0x7bad, 11398), (0x7bae, 10717), (0x7baf, 10718), (0x7bb0, 10719), (0x7bb1, 14983), (0x7bb2, 10720),
+    (0x7bb3, 10721), (0x7bb4, 21835), (0x7bb5, 10722), (0x7bb6, 10723), (0x7bb7, 10724), (0x7bb8, 21826), (0x7bb9, 10725), (0x7bba, 10726),
+    (0x7bbb, 10727), (0x7bbc, 10728), (0x7bbd, 10729), (0x7bbe, 10730), (0x7bbf, 10731), (0x7bc0, 10732), (0x7bc1, 21837), (0x7bc2, 10733),
+    (0x7bc3, 10734), (0x7bc4, 10735), (0x7bc5, 10830), (0x7bc6, 16448), (0x7bc7, 13215), (0x7bc8, 10831), (0x7bc9, 10832), (0x7bca, 10833),
+    (0x7bcb, 10834), (0x7bcc, 21838), (0x7bcd, 10835), (0x7bce, 10836), (0x7bcf, 10837), (0x7bd0, 10838), (0x7bd1, 21836), (0x7bd2, 10839),
+    (0x7bd3, 12453), (0x7bd4, 10840), (0x7bd5, 10841), (0x7bd6, 10842), (0x7bd7, 10843), (0x7bd8, 10844), (0x7bd9, 10606), (0x7bda, 21840),
+    (0x7bdb, 10845), (0x7bdc, 10846), (0x7bdd, 21839), (0x7bde, 10847), (0x7bdf, 10848), (0x7be0, 10849), (0x7be1, 9844), (0x7be2, 10850),
+    (0x7be3, 10851), (0x7be4, 10852), (0x7be5, 21841), (0x7be6, 21842), (0x7be7, 10853), (0x7be8, 10854), (0x7be9, 10855), (0x7bea, 21843),
+    (0x7beb, 10856), (0x7bec, 10857), (0x7bed, 10858), (0x7bee, 12091), (0x7bef, 10859), (0x7bf0, 10860), (0x7bf1, 12138), (0x7bf2, 10861),
+    (0x7bf3, 10862), (0x7bf4, 10863), (0x7bf5, 10864), (0x7bf6, 10865), (0x7bf7, 13096), (0x7bf8, 10866), (0x7bf9, 10867), (0x7bfa, 10868),
+    (0x7bfb, 10869), (0x7bfc, 21846), (0x7bfd, 10870), (0x7bfe, 21845), (0x7bff, 10871), (0x7c00, 10872), (0x7c01, 10873), (0x7c02, 10874),
+    (0x7c03, 10875), (0x7c04, 10876), (0x7c05, 10877), (0x7c06, 10878), (0x7c07, 9841), (0x7c08, 10879), (0x7c09, 10880), (0x7c0a, 10881),
+    (0x7c0b, 21849), (0x7c0c, 21844), (0x7c0d, 10882), (0x7c0e, 10883), (0x7c0f, 21847), (0x7c10, 10884), (0x7c11, 10885), (0x7c12, 10886),
+    (0x7c13, 10887), (0x7c14, 10888), (0x7c15, 10889), (0x7c16, 21848), (0x7c17, 10890), (0x7c18, 10891), (0x7c19, 10892), (0x7c1a, 10893),
+    (0x7c1b, 10894), (0x7c1c, 10895), (0x7c1d, 10896), (0x7c1e, 10897), (0x7c1f, 21946), (0x7c20, 10898), (0x7c21, 10899), (0x7c22, 10900),
+    (0x7c23, 10901), (0x7c24, 10902), (0x7c25, 10903), (0x7c26, 21948), (0x7c27, 11156), (0x7c28, 10904), (0x7c29, 10905), (0x7c2a, 21947),
+    (0x7c2b, 10906), (0x7c2c, 10907), (0x7c2d, 10908), (0x7c2e, 10909), (0x7c2f, 10910), (0x7c30, 10911), (0x7c31, 10912), (0x7c32, 10913),
+    (0x7c33, 10914), (0x7c34, 10915), (0x7c35, 10916), (0x7c36, 10917), (0x7c37, 10918), (0x7c38, 21949), (0x7c39, 10919), (0x7c3a, 10920),
+    (0x7c3b, 10921), (0x7c3c, 10922), (0x7c3d, 10923), (0x7c3e, 10924), (0x7c3f, 9435), (0x7c40, 21951), (0x7c41, 21950), (0x7c42, 10925),
+    (0x7c43, 11020), (0x7c44, 11021), (0x7c45, 11022), (0x7c46, 11023), (0x7c47, 11024), (0x7c48, 11025), (0x7c49, 11026), (0x7c4a, 11027),
+    (0x7c4b, 11028), (0x7c4c, 11029), (0x7c4d, 11319), (0x7c4e, 11030), (0x7c4f, 11031), (0x7c50, 11032), (0x7c51, 11033), (0x7c52, 11034),
+    (0x7c53, 11035), (0x7c54, 11036), (0x7c55, 11037), (0x7c56, 11038), (0x7c57, 11039), (0x7c58, 11040), (0x7c59, 11041), (0x7c5a, 11042),
+    (0x7c5b, 11043), (0x7c5c, 11044), (0x7c5d, 11045), (0x7c5e, 11046), (0x7c5f, 11047), (0x7c60, 11048), (0x7c61, 11049), (0x7c62, 11050),
+    (0x7c63, 11051), (0x7c64, 11052), (0x7c65, 11053), (0x7c66, 11054), (0x7c67, 11055), (0x7c68, 11056), (0x7c69, 11057), (0x7c6a, 11058),
+    (0x7c6b, 11059), (0x7c6c, 11060), (0x7c6d, 11061), (0x7c6e, 11062), (0x7c6f, 11063), (0x7c70, 11064), (0x7c71, 11065), (0x7c72, 11066),
+    (0x7c73, 12690), (0x7c74, 16880), (0x7c75, 11067), (0x7c76, 11068), (0x7c77, 11069), (0x7c78, 11070), (0x7c79, 11071), (0x7c7a, 11072),
+    (0x7c7b, 12129), (0x7c7c, 21989), (0x7c7d, 16484), (0x7c7e, 11073), (0x7c7f, 11074), (0x7c80, 11075), (0x7c81, 11076), (0x7c82, 11077),
+    (0x7c83, 11078), (0x7c84, 11079), (0x7c85, 11080), (0x7c86, 11081), (0x7c87, 11082), (0x7c88, 11083), (0x7c89, 10414), (0x7c8a, 11084),
+    (0x7c8b, 11085), (0x7c8c, 11086), (0x7c8d, 11087), (0x7c8e, 11088), (0x7c8f, 11089), (0x7c90, 11090), (0x7c91, 21991), (0x7c92, 12258),
+    (0x7c93, 11091), (0x7c94, 11092), (0x7c95, 13246), (0x7c96, 11093), (0x7c97, 9839), (0x7c98, 16074), (0x7c99, 11094), (0x7c9a, 11095),
+    (0x7c9b, 11096), (0x7c9c, 21993), (0x7c9d, 21992), (0x7c9e, 21994), (0x7c9f, 14213), (0x7ca0, 11097), (0x7ca1, 11098), (0x7ca2, 21995),
+    (0x7ca3, 11099), (0x7ca4, 15898), (0x7ca5, 16309), (0x7ca6, 11100), (0x7ca7, 11101), (0x7ca8, 11102), (0x7ca9, 11103), (0x7caa, 10419),
+    (0x7cab, 11104), (0x7cac, 11105), (0x7cad, 11106), (0x7cae, 12279), (0x7caf, 11107), (0x7cb0, 11108), (0x7cb1, 12282), (0x7cb2, 21996),
+    (0x7cb3, 11697), (0x7cb4, 11109), (0x7cb5, 11110), (0x7cb6, 11111), (0x7cb7, 11112), (0x7cb8, 11113), (0x7cb9, 9851), (0x7cba, 11114),
+    (0x7cbb, 11115), (0x7cbc, 21997), (0x7cbd, 21998), (0x7cbe, 11696), (0x7cbf, 11210), (0x7cc0, 11211), (0x7cc1, 21999), (0x7cc2, 11212),
+    (0x7cc3, 11213), (0x7cc4, 11214), (0x7cc5, 22004), (0x7cc6, 11215), (0x7cc7, 22000), (0x7cc8, 22003), (0x7cc9, 11216), (0x7cca, 11018),
+    (0x7ccb, 11217), (0x7ccc, 22001), (0x7ccd, 22002), (0x7cce, 11218), (0x7ccf, 11219), (0x7cd0, 11220), (0x7cd1, 11221), (0x7cd2, 11222),
+    (0x7cd3, 11223), (0x7cd4, 11224), (0x7cd5, 10611), (0x7cd6, 14384), (0x7cd7, 22005), (0x7cd8, 11225), (0x7cd9, 9463), (0x7cda, 11226),
+    (0x7cdb, 11227), (0x7cdc, 12686), (0x7cdd, 11228), (0x7cde, 11229), (0x7cdf, 15932), (0x7ce0, 11898), (0x7ce1, 11230), (0x7ce2, 11231),
+    (0x7ce3, 11232), (0x7ce4, 11233), (0x7ce5, 11234), (0x7ce6, 11235), (0x7ce7, 11236), (0x7ce8, 22006), (0x7ce9, 11237), (0x7cea, 11238),
+    (0x7ceb, 11239), (0x7cec, 11240), (0x7ced, 11241), (0x7cee, 11242), (0x7cef, 13035), (0x7cf0, 11243), (0x7cf1, 11244), (0x7cf2, 11245),
+    (0x7cf3, 11246), (0x7cf4, 11247), (0x7cf5, 11248), (0x7cf6, 11249), (0x7cf7, 11250), (0x7cf8, 22018), (0x7cf9, 11251), (0x7cfa, 11252),
+    (0x7cfb, 14936), (0x7cfc, 11253), (0x7cfd, 11254), (0x7cfe, 11255), (0x7cff, 11256), (0x7d00, 11257), (0x7d01, 11258), (0x7d02, 11259),
+    (0x7d03, 11260), (0x7d04, 11261), (0x7d05, 11262), (0x7d06, 11263), (0x7d07, 11264), (0x7d08, 11265), (0x7d09, 11266), (0x7d0a, 14766),
+    (0x7d0b, 11267), (0x7d0c, 11268), (0x7d0d, 11269), (0x7d0e, 11270), (0x7d0f, 11271), (0x7d10, 11272), (0x7d11, 11273), (0x7d12, 11274),
+    (0x7d13, 11275), (0x7d14, 11276), (0x7d15, 11277), (0x7d16, 11278), (0x7d17, 11279), (0x7d18, 11280), (0x7d19, 11281), (0x7d1a, 11282),
+    (0x7d1b, 11283), (0x7d1c, 11284), (0x7d1d, 11285), (0x7d1e, 11286), (0x7d1f, 11287), (0x7d20, 14211), (0x7d21, 11288), (0x7d22, 14242),
+    (0x7d23, 11289), (0x7d24, 11290), (0x7d25, 11291), (0x7d26, 11292), (0x7d27, 11579), (0x7d28, 11293), (0x7d29, 11294), (0x7d2a, 11295),
+    (0x7d2b, 16482), (0x7d2c, 11296), (0x7d2d, 11297), (0x7d2e, 11298), (0x7d2f, 12124), (0x7d30, 11299), (0x7d31, 11300), (0x7d32, 11301),
+    (0x7d33, 11302), (0x7d34, 11303), (0x7d35, 11304), (0x7d36, 11305), (0x7d37, 11400), (0x7d38, 11401), (0x7d39, 11402), (0x7d3a, 11403),
+    (0x7d3b, 11404), (0x7d3c, 11405), (0x7d3d, 11406), (0x7d3e, 11407), (0x7d3f, 11408), (0x7d40, 11409), (0x7d41, 11410), (0x7d42, 11411),
+    (0x7d43, 11412), (0x7d44, 11413), (0x7d45, 11414), (0x7d46, 11415), (0x7d47, 11416), (0x7d48, 11417), (0x7d49, 11418), (0x7d4a, 11419),
+    (0x7d4b, 11420), (0x7d4c, 11421), (0x7d4d, 11422), (0x7d4e, 11423), (0x7d4f, 11424), (0x7d50, 11425), (0x7d51, 11426), (0x7d52, 11427),
+    (0x7d53, 11428), (0x7d54, 11429), (0x7d55, 11430), (0x7d56, 11431), (0x7d57, 11432), (0x7d58, 11433), (0x7d59, 11434), (0x7d5a, 11435),
+    (0x7d5b, 11436), (0x7d5c, 11437), (0x7d5d, 11438), (0x7d5e, 11439), (0x7d5f, 11440), (0x7d60, 11441), (0x7d61, 11442), (0x7d62, 11443),
+    (0x7d63, 11444), (0x7d64, 11445), (0x7d65, 11446), (0x7d66, 11447), (0x7d67, 11448), (0x7d68, 11449), (0x7d69, 11450), (0x7d6a, 11451),
+    (0x7d6b, 11452), (0x7d6c, 11453), (0x7d6d, 11454), (0x7d6e, 15190), (0x7d6f, 11455), (0x7d70, 11456), (0x7d71, 11457), (0x7d72, 11458),
+    (0x7d73, 11459), (0x7d74, 11460), (0x7d75, 11461), (0x7d76, 11462), (0x7d77, 22019), (0x7d78, 11463), (0x7d79, 11464), (0x7d7a, 11465),
+    (0x7d7b, 11466), (0x7d7c, 11467), (0x7d7d, 11468), (0x7d7e, 11469), (0x7d7f, 11470), (0x7d80, 11471), (0x7d81, 11472), (0x7d82, 11473),
+    (0x7d83, 11474), (0x7d84, 11475), (0x7d85, 11476), (0x7d86, 11477), (0x7d87, 11478), (0x7d88, 11479), (0x7d89, 11480), (0x7d8a, 11481),
+    (0x7d8b, 11482), (0x7d8c, 11483), (0x7d8d, 11484), (0x7d8e, 11485), (0x7d8f, 11486), (0x7d90, 11487), (0x7d91, 11488), (0x7d92, 11489),
+    (0x7d93, 11490), (0x7d94, 11491), (0x7d95, 11492), (0x7d96, 11493), (0x7d97, 11494), (0x7d98, 11495), (0x7d99, 11590), (0x7d9a, 11591),
+    (0x7d9b, 11592), (0x7d9c, 11593), (0x7d9d, 11594), (0x7d9e, 11595), (0x7d9f, 11596), (0x7da0, 11597), (0x7da1, 11598), (0x7da2, 11599),
+    (0x7da3, 11600), (0x7da4, 11601), (0x7da5, 11602), (0x7da6, 22020), (0x7da7, 11603), (0x7da8, 11604), (0x7da9, 11605), (0x7daa, 11606),
+    (0x7dab, 11607), (0x7dac, 11608), (0x7dad, 11609), (0x7dae, 22021), (0x7daf, 11610), (0x7db0, 11611), (0x7db1, 11612), (0x7db2, 11613),
+    (0x7db3, 11614), (0x7db4, 11615), (0x7db5, 11616), (0x7db6, 11617), (0x7db7, 11618), (0x7db8, 11619), (0x7db9, 11620), (0x7dba, 11621),
+    (0x7dbb, 11622), (0x7dbc, 11623), (0x7dbd, 11624), (0x7dbe, 11625), (0x7dbf, 11626), (0x7dc0, 11627), (0x7dc1, 11628), (0x7dc2, 11629),
+    (0x7dc3, 11630), (0x7dc4, 11631), (0x7dc5, 11632), (0x7dc6, 11633), (0x7dc7, 11634), (0x7dc8, 11635), (0x7dc9, 11636), (0x7dca, 11637),
+    (0x7dcb, 11638), (0x7dcc, 11639), (0x7dcd, 11640), (0x7dce, 11641), (0x7dcf, 11642), (0x7dd0, 11643), (0x7dd1, 11644), (0x7dd2, 11645),
+    (0x7dd3, 11646), (0x7dd4, 11647), (0x7dd5, 11648), (0x7dd6, 11649), (0x7dd7, 11650), (0x7dd8, 11651), (0x7dd9, 11652), (0x7dda, 11653),
+    (0x7ddb, 11654), (0x7ddc, 11655), (0x7ddd, 11656), (0x7dde, 11657), (0x7ddf, 11658), (0x7de0, 11659), (0x7de1, 11660), (0x7de2, 11661),
+    (0x7de3, 11662), (0x7de4, 11663), (0x7de5, 11664), (0x7de6, 11665), (0x7de7, 11666), (0x7de8, 11667), (0x7de9, 11668), (0x7dea, 11669),
+    (0x7deb, 11670), (0x7dec, 11671), (0x7ded, 11672), (0x7dee, 11673), (0x7def, 11674), (0x7df0, 11675), (0x7df1, 11676), (0x7df2, 11677),
+    (0x7df3, 11678), (0x7df4, 11679), (0x7df5, 11680), (0x7df6, 11681), (0x7df7, 11682), (0x7df8, 11683), (0x7df9, 11684), (0x7dfa, 11685),
+    (0x7dfb, 11780), (0x7dfc, 11781), (0x7dfd, 11782), (0x7dfe, 11783), (0x7dff, 11784), (0x7e00, 11785), (0x7e01, 11786), (0x7e02, 11787),
+    (0x7e03, 11788), (0x7e04, 11789), (0x7e05, 11790), (0x7e06, 11791), (0x7e07, 11792), (0x7e08, 11793), (0x7e09, 11794), (0x7e0a, 11795),
+    (0x7e0b, 11796), (0x7e0c, 11797), (0x7e0d, 11798), (0x7e0e, 11799), (0x7e0f, 11800), (0x7e10, 11801), (0x7e11, 11802), (0x7e12, 11803),
+    (0x7e13, 11804), (0x7e14, 11805), (0x7e15, 11806), (0x7e16, 11807), (0x7e17, 11808), (0x7e18, 11809), (0x7e19, 11810), (0x7e1a, 11811),
+    (0x7e1b, 11812), (0x7e1c, 11813), (0x7e1d, 11814), (0x7e1e, 11815), (0x7e1f, 11816), (0x7e20, 11817), (0x7e21, 11818), (0x7e22, 11819),
+    (0x7e23, 11820), (0x7e24, 11821), (0x7e25, 11822), (0x7e26, 11823), (0x7e27, 11824), (0x7e28, 11825), (0x7e29, 11826), (0x7e2a, 11827),
+    (0x7e2b, 11828), (0x7e2c, 11829), (0x7e2d, 11830), (0x7e2e, 11831), (0x7e2f, 11832), (0x7e30, 11833), (0x7e31, 11834), (0x7e32, 11835),
+    (0x7e33, 11836), (0x7e34, 11837), (0x7e35, 11838), (0x7e36, 11839), (0x7e37, 11840), (0x7e38, 11841), (0x7e39, 11842), (0x7e3a, 11843),
+    (0x7e3b, 22582), (0x7e3c, 11844), (0x7e3d, 11845), (0x7e3e, 11846), (0x7e3f, 11847), (0x7e40, 11848), (0x7e41, 10372), (0x7e42, 11849),
+    (0x7e43, 11850), (0x7e44, 11851), (0x7e45, 11852), (0x7e46, 11853), (0x7e47, 22022), (0x7e48, 11854), (0x7e49, 11855), (0x7e4a, 11856),
+    (0x7e4b, 11857), (0x7e4c, 11858), (0x7e4d, 11859), (0x7e4e, 11860), (0x7e4f, 11861), (0x7e50, 11862), (0x7e51, 11863), (0x7e52, 11864),
+    (0x7e53, 11865), (0x7e54, 11866), (0x7e55, 11867), (0x7e56, 11868), (0x7e57, 11869), (0x7e58, 11870), (0x7e59, 11871), (0x7e5a, 11872),
+    (0x7e5b, 11873), (0x7e5c, 11874), (0x7e5d, 11875), (0x7e5e, 11970), (0x7e5f, 11971), (0x7e60, 11972), (0x7e61, 11973), (0x7e62, 11974),
+    (0x7e63, 11975), (0x7e64, 11976), (0x7e65, 11977), (0x7e66, 11978), (0x7e67, 11979), (0x7e68, 11980), (0x7e69, 11981), (0x7e6a, 11982),
+    (0x7e6b, 11983), (0x7e6c, 11984), (0x7e6d, 11985), (0x7e6e, 11986), (0x7e6f, 11987), (0x7e70, 11988), (0x7e71, 11989), (0x7e72, 11990),
+    (0x7e73, 11991), (0x7e74, 11992), (0x7e75, 11993), (0x7e76, 11994), (0x7e77, 11995), (0x7e78, 11996), (0x7e79, 11997), (0x7e7a, 11998),
+    (0x7e7b, 11999), (0x7e7c, 12000), (0x7e7d, 12001), (0x7e7e, 12002), (0x7e7f, 12003), (0x7e80, 12004), (0x7e81, 12005), (0x7e82, 16510),
+    (0x7e83, 12006), (0x7e84, 12007), (0x7e85, 12008), (0x7e86, 12009), (0x7e87, 12010), (0x7e88, 12011), (0x7e89, 12012), (0x7e8a, 12013),
+    (0x7e8b, 12014), (0x7e8c, 12015), (0x7e8d, 12016), (0x7e8e, 12017), (0x7e8f, 12018), (0x7e90, 12019), (0x7e91, 12020), (0x7e92, 12021),
+    (0x7e93, 12022), (0x7e94, 12023), (0x7e95, 12024), (0x7e96, 12025), (0x7e97, 12026), (0x7e98, 12027), (0x7e99, 12028), (0x7e9a, 12029),
+    (0x7e9b, 22023), (0x7e9c, 12030), (0x7e9d, 12031), (0x7e9e, 12032), (0x7e9f, 19374), (0x7ea0, 11717), (0x7ea1, 19375), (0x7ea2, 11001),
+    (0x7ea3, 19376), (0x7ea4, 14958), (0x7ea5, 19377), (0x7ea6, 15893), (0x7ea7, 11327), (0x7ea8, 19378), (0x7ea9, 19379), (0x7eaa, 11350),
+    (0x7eab, 13635), (0x7eac, 14744), (0x7ead, 19476), (0x7eae, 12033), (0x7eaf, 9816), (0x7eb0, 19477), (0x7eb1, 13795), (0x7eb2, 10602),
+    (0x7eb3, 12866), (0x7eb4, 12034), (0x7eb5, 16496), (0x7eb6, 12503), (0x7eb7, 10410), (0x7eb8, 16274), (0x7eb9, 14763), (0x7eba, 10391),
+    (0x7ebb, 12035), (0x7ebc, 12036), (0x7ebd, 13021), (0x7ebe, 19478), (0x7ebf, 14978), (0x7ec0, 19479), (0x7ec1, 19480), (0x7ec2, 19481),
+    (0x7ec3, 12278), (0x7ec4, 16508), (0x7ec5, 13855), (0x7ec6, 14939), (0x7ec7, 16260), (0x7ec8, 16298), (0x7ec9, 19482), (0x7eca, 9102),
+    (0x7ecb, 19483), (0x7ecc, 19484), (0x7ecd, 13835), (0x7ece, 15564), (0x7ecf, 11698), (0x7ed0, 19485), (0x7ed1, 9108), (0x7ed2, 13647),
+    (0x7ed3, 11560), (0x7ed4, 19486), (0x7ed5, 13623), (0x7ed6, 12037), (0x7ed7, 19487), (0x7ed8, 11185), (0x7ed9, 10633), (0x7eda, 15299),
+    (0x7edb, 19488), (0x7edc, 12516), (0x7edd, 11773), (0x7ede, 11537), (0x7edf, 14554), (0x7ee0, 19489), (0x7ee1, 19490), (0x7ee2, 11763),
+    (0x7ee3, 15174), (0x7ee4, 12038), (0x7ee5, 14226), (0x7ee6, 14393), (0x7ee7, 11349), (0x7ee8, 19491), (0x7ee9, 11313), (0x7eea, 15192),
+    (0x7eeb, 19492), (0x7eec, 12039), (0x7eed, 15193), (0x7eee, 19493), (0x7eef, 19494), (0x7ef0, 9819), (0x7ef1, 19495), (0x7ef2, 19496),
+    (0x7ef3, 13869), (0x7ef4, 14737), (0x7ef5, 12699), (0x7ef6, 19498), (0x7ef7, 9248), (0x7ef8, 9676), (0x7ef9, 12040), (0x7efa, 19499),
+    (0x7efb, 19500), (0x7efc, 16494), (0x7efd, 16087), (0x7efe, 19501), (0x7eff, 12489), (0x7f00, 16461), (0x7f01, 19502), (0x7f02, 19503),
+    (0x7f03, 19504), (0x7f04, 11379), (0x7f05, 12704), (0x7f06, 12099), (0x7f07, 19505), (0x7f08, 19506), (0x7f09, 11314), (0x7f0a, 12041),
+    (0x7f0b, 19507), (0x7f0c, 19508), (0x7f0d, 19497), (0x7f0e, 10213), (0x7f0f, 19509), (0x7f10, 12042), (0x7f11, 19510), (0x7f12, 19511),
+    (0x7f13, 11141), (0x7f14, 10037), (0x7f15, 12483), (0x7f16, 9279), (0x7f17, 19512), (0x7f18, 15886), (0x7f19, 19513), (0x7f1a, 10576),
+    (0x7f1b, 19515), (0x7f1c, 19514), (0x7f1d, 10431), (0x7f1e, 12043), (0x7f1f, 19516), (0x7f20, 9493), (0x7f21, 19517), (0x7f22, 19518),
+    (0x7f23, 19519), (0x7f24, 19520), (0x7f25, 19521), (0x7f26, 19522), (0x7f27, 19523), (0x7f28, 15682), (0x7f29, 14240), (0x7f2a, 19524),
+    (0x7f2b, 19525), (0x7f2c, 19526), (0x7f2d, 19527), (0x7f2e, 13816), (0x7f2f, 19528), (0x7f30, 19529), (0x7f31, 19530), (0x7f32, 19531),
+    (0x7f33, 19532), (0x7f34, 11536), (0x7f35, 19533), (0x7f36, 21785), (0x7f37, 12044), (0x7f38, 10600), (0x7f39, 12045), (0x7f3a, 13602),
+    (0x7f3b, 12046), (0x7f3c, 12047), (0x7f3d, 12048), (0x7f3e, 12049), (0x7f3f, 12050), (0x7f40, 12051), (0x7f41, 12052), (0x7f42, 21786),
+    (0x7f43, 12053), (0x7f44, 21787), (0x7f45, 21788), (0x7f46, 12054), (0x7f47, 12055), (0x7f48, 12056), (0x7f49, 12057), (0x7f4a, 12058),
+    (0x7f4b, 12059), (0x7f4c, 12060), (0x7f4d, 12061), (0x7f4e, 12062), (0x7f4f, 12063), (0x7f50, 10797), (0x7f51, 14623), (0x7f52, 12064),
+    (0x7f53, 12065), (0x7f54, 16697), (0x7f55, 10942), (0x7f56, 12160), (0x7f57, 12507), (0x7f58, 20828), (0x7f59, 12161), (0x7f5a, 10358),
+    (0x7f5b, 12162), (0x7f5c, 12163), (0x7f5d, 12164), (0x7f5e, 12165), (0x7f5f, 20830), (0x7f60, 12166), (0x7f61, 20829), (0x7f62, 9078),
+    (0x7f63, 12167), (0x7f64, 12168), (0x7f65, 12169), (0x7f66, 12170), (0x7f67, 12171), (0x7f68, 20832), (0x7f69, 16109), (0x7f6a, 16514),
+    (0x7f6b, 12172), (0x7f6c, 12173), (0x7f6d, 12174), (0x7f6e, 16280), (0x7f6f, 12175), (0x7f70, 12176), (0x7f71, 20834), (0x7f72, 14045),
+    (0x7f73, 12177), (0x7f74, 20833), (0x7f75, 12178), (0x7f76, 12179), (0x7f77, 12180), (0x7f78, 12181), (0x7f79, 20835), (0x7f7a, 12182),
+    (0x7f7b, 12183), (0x7f7c, 12184), (0x7f7d, 12185), (0x7f7e, 20837), (0x7f7f, 12186), (0x7f80, 12187), (0x7f81, 20836), (0x7f82, 12188),
+    (0x7f83, 12189), (0x7f84, 12190), (0x7f85, 12191), (0x7f86, 12192), (0x7f87, 12193), (0x7f88, 12194), (0x7f89, 12195), (0x7f8a, 15377),
+    (0x7f8b, 12196), (0x7f8c, 13423), (0x7f8d, 12197), (0x7f8e, 12667), (0x7f8f, 12198), (0x7f90, 12199), (0x7f91, 12200), (0x7f92, 12201),
+    (0x7f93, 12202), (0x7f94, 10610), (0x7f95, 12203), (0x7f96, 12204), (0x7f97, 12205), (0x7f98, 12206), (0x7f99, 12207), (0x7f9a, 12326),
+    (0x7f9b, 12208), (0x7f9c, 12209), (0x7f9d, 21983), (0x7f9e, 15168), (0x7f9f, 21984), (0x7fa0, 12210), (0x7fa1, 14974), (0x7fa2, 12211),
+    (0x7fa3, 12212), (0x7fa4, 13611), (0x7fa5, 12213), (0x7fa6, 12214), (0x7fa7, 21985), (0x7fa8, 12215), (0x7fa9, 12216), (0x7faa, 12217),
+    (0x7fab, 12218), (0x7fac, 12219), (0x7fad, 12220), (0x7fae, 12221), (0x7faf, 21986), (0x7fb0, 21987), (0x7fb1, 12222), (0x7fb2, 21988),
+    (0x7fb3, 12223), (0x7fb4, 12224), (0x7fb5, 12225), (0x7fb6, 12226), (0x7fb7, 12227), (0x7fb8, 16905), (0x7fb9, 10639), (0x7fba, 12228),
+    (0x7fbb, 12229), (0x7fbc, 19176), (0x7fbd, 15755), (0x7fbe, 12230), (0x7fbf, 22009), (0x7fc0, 12231), (0x7fc1, 14769), (0x7fc2, 12232),
+    (0x7fc3, 12233), (0x7fc4, 12234), (0x7fc5, 9660), (0x7fc6, 12235), (0x7fc7, 12236), (0x7fc8, 12237), (0x7fc9, 12238), (0x7fca, 21395),
+    (0x7fcb, 12239), (0x7fcc, 15563), (0x7fcd, 12240), (0x7fce, 22010), (0x7fcf, 12241), (0x7fd0, 12242), (0x7fd1, 12243), (0x7fd2, 12244),
+    (0x7fd3, 12245), (0x7fd4, 14987), (0x7fd5, 22011), (0x7fd6, 12246), (0x7fd7, 12247), (0x7fd8, 13439), (0x7fd9, 12248), (0x7fda, 12249),
+    (0x7fdb, 12250), (0x7fdc, 12251), (0x7fdd, 12252), (0x7fde, 12253), (0x7fdf, 10027), (0x7fe0, 9853), (0x7fe1, 22013), (0x7fe2, 12254),
+    (0x7fe3, 12255), (0x7fe4, 12350), (0x7fe5, 22012), (0x7fe6, 22014), (0x7fe7, 12351), (0x7fe8, 12352), (0x7fe9, 22015), (0x7fea, 12353),
+    (0x7feb, 12354), (0x7fec, 12355), (0x7fed, 12356), (0x7fee, 22016), (0x7fef, 12357), (0x7ff0, 10943), (0x7ff1, 9056), (0x7ff2, 12358),
+    (0x7ff3, 22017), (0x7ff4, 12359), (0x7ff5, 12360), (0x7ff6, 12361), (0x7ff7, 12362), (0x7ff8, 12363), (0x7ff9, 12364), (0x7ffa, 12365),
+    (0x7ffb, 10368), (0x7ffc, 15562), (0x7ffd, 12366), (0x7ffe, 12367), (0x7fff, 12368), (0x8000, 15496), (0x8001, 12112), (0x8002, 12369),
+    (0x8003, 11903), (0x8004, 20238), (0x8005, 16118), (0x8006, 20085), (0x8007, 12370), (0x8008, 12371), (0x8009, 12372), (0x800a, 12373),
+    (0x800b, 21458), (0x800c, 10253), (0x800d, 14158), (0x800e, 12374), (0x800f, 12375), (0x8010, 12870), (0x8011, 12376), (0x8012, 21446),
+    (0x8013, 12377), (0x8014, 21447), (0x8015, 10636), (0x8016, 21448), (0x8017, 10961), (0x8018, 15902), (0x8019, 9075), (0x801a, 12378),
+    (0x801b, 12379), (0x801c, 21449), (0x801d, 12380), (0x801e, 12381), (0x801f, 12382), (0x8020, 21450), (0x8021, 12383), (0x8022, 21451),
+    (0x8023, 12384), (0x8024, 12385), (0x8025, 21452), (0x8026, 21453), (0x8027, 21454), (0x8028, 21456), (0x8029, 21455), (0x802a, 13068),
+    (0x802b, 12386), (0x802c, 12387), (0x802d, 12388), (0x802e, 12389), (0x802f, 12390), (0x8030, 12391), (0x8031, 21457), (0x8032, 12392),
+    (0x8033, 10255), (0x8034, 12393), (0x8035, 21459), (0x8036, 15499), (0x8037, 17804), (0x8038, 14197), (0x8039, 12394), (0x803a, 12395),
+    (0x803b, 9655), (0x803c, 12396), (0x803d, 9977), (0x803e, 12397), (0x803f, 10737), (0x8040, 12398), (0x8041, 12399), (0x8042, 12909),
+    (0x8043, 21460), (0x8044, 12400), (0x8045, 12401), (0x8046, 21461), (0x8047, 12402), (0x8048, 12403), (0x8049, 12404), (0x804a, 12291),
+    (0x804b, 12346), (0x804c, 16261), (0x804d, 21462), (0x804e, 12405), (0x804f, 12406), (0x8050, 12407), (0x8051, 12408), (0x8052, 21463),
+    (0x8053, 12409), (0x8054, 12265), (0x8055, 12410), (0x8056, 12411), (0x8057, 12412), (0x8058, 13229), (0x8059, 12413), (0x805a, 11744),
+    (0x805b, 12414), (0x805c, 12415), (0x805d, 12416), (0x805e, 12417), (0x805f, 12418), (0x8060, 12419), (0x8061, 12420), (0x8062, 12421),
+    (0x8063, 12422), (0x8064, 12423), (0x8065, 12424), (0x8066, 12425), (0x8067, 12426), (0x8068, 12427), (0x8069, 21464), (0x806a, 9832),
+    (0x806b, 12428), (0x806c, 12429), (0x806d, 12430), (0x806e, 12431), (0x806f, 12432), (0x8070, 12433), (0x8071, 21465), (0x8072, 12434),
+    (0x8073, 12435), (0x8074, 12436), (0x8075, 12437), (0x8076, 12438), (0x8077, 12439), (0x8078, 12440), (0x8079, 12441), (0x807a, 12442),
+    (0x807b, 12443), (0x807c, 12444), (0x807d, 12445), (0x807e, 12540), (0x807f, 20633), (0x8080, 20632), (0x8081, 12541), (0x8082, 12542),
+    (0x8083, 14219), (0x8084, 15547), (0x8085, 12543), (0x8086, 14188), (0x8087, 16111), (0x8088, 12544), (0x8089, 13651), (0x808a, 12545),
+    (0x808b, 12128), (0x808c, 11306), (0x808d, 12546), (0x808e, 12547), (0x808f, 12548), (0x8090, 12549), (0x8091, 12550), (0x8092, 12551),
+    (0x8093, 20268), (0x8094, 12552), (0x8095, 12553), (0x8096, 15109), (0x8097, 12554), (0x8098, 16311), (0x8099, 12555), (0x809a, 10204),
+    (0x809b, 10601), (0x809c, 20267), (0x809d, 10591), (0x809e, 12556), (0x809f, 20266), (0x80a0, 9601), (0x80a1, 10776), (0x80a2, 16256),
+    (0x80a3, 12557), (0x80a4, 10439), (0x80a5, 10397), (0x80a6, 12558), (0x80a7, 12559), (0x80a8, 12560), (0x80a9, 11376), (0x80aa, 10385),
+    (0x80ab, 20273), (0x80ac, 12561), (0x80ad, 20274), (0x80ae, 9050), (0x80af, 11922), (0x80b0, 12562), (0x80b1, 20272), (0x80b2, 15768),
+    (0x80b3, 12563), (0x80b4, 20275), (0x80b5, 12564), (0x80b6, 12565), (0x80b7, 20276), (0x80b8, 12566), (0x80b9, 12567), (0x80ba, 10401),
+    (0x80bb, 12568), (0x80bc, 20269), (0x80bd, 20271), (0x80be, 13861), (0x80bf, 16300), (0x80c0, 16100), (0x80c1, 15123), (0x80c2, 20282),
+    (0x80c3, 14749), (0x80c4, 20283), (0x80c5, 12569), (0x80c6, 9983), (0x80c7, 12570), (0x80c8, 12571), (0x80c9, 12572), (0x80ca, 12573),
+    (0x80cb, 12574), (0x80cc, 9234), (0x80cd, 20285), (0x80ce, 14350), (0x80cf, 12575), (0x80d0, 12576), (0x80d1, 12577), (0x80d2, 12578),
+    (0x80d3, 12579), (0x80d4, 12580), (0x80d5, 12581), (0x80d6, 13069), (0x80d7, 20286), (0x80d8, 12582), (0x80d9, 20284), (0x80da, 13078),
+    (0x80db, 20281), (0x80dc, 13969), (0x80dd, 20288), (0x80de, 9116), (0x80df, 12583), (0x80e0, 12584), (0x80e1, 11015), (0x80e2, 12585),
+    (0x80e3, 12586), (0x80e4, 16648), (0x80e5, 21442), (0x80e6, 12587), (0x80e7, 20277), (0x80e8, 20278), (0x80e9, 20279), (0x80ea, 20280),
+    (0x80eb, 20289), (0x80ec, 19317), (0x80ed, 20292), (0x80ee, 12588), (0x80ef, 11947), (0x80f0, 15525), (0x80f1, 20290), (0x80f2, 20295),
+    (0x80f3, 10621), (0x80f4, 20291), (0x80f5, 12589), (0x80f6, 11521), (0x80f7, 12590), (0x80f8, 15161), (0x80f9, 12591), (0x80fa, 9048),
+    (0x80fb, 12592), (0x80fc, 20296), (0x80fd, 12885), (0x80fe, 12593), (0x80ff, 12594), (0x8100, 12595), (0x8101, 12596), (0x8102, 16257),
+    (0x8103, 12597), (0x8104, 12598), (0x8105, 12599), (0x8106, 9849), (0x8107, 12600), (0x8108, 12601), (0x8109, 12531), (0x810a, 11330),
+    (0x810b, 12602), (0x810c, 12603), (0x810d, 20293), (0x810e, 20294), (0x810f, 15929), (0x8110, 13279), (0x8111, 12877), (0x8112, 20298),
+    (0x8113, 13022), (0x8114, 16900), (0x8115, 12604), (0x8116, 9422), (0x8117, 12605), (0x8118, 20303), (0x8119, 12606), (0x811a, 11532),
+    (0x811b, 12607), (0x811c, 12608), (0x811d, 12609), (0x811e, 20301), (0x811f, 12610), (0x8120, 12611), (0x8121, 12612), (0x8122, 12613),
+    (0x8123, 12614), (0x8124, 12615), (0x8125, 12616), (0x8126, 12617), (0x8127, 12618), (0x8128, 12619), (0x8129, 12620), (0x812a, 12621),
+    (0x812b, 12622), (0x812c, 20302), (0x812d, 12623), (0x812e, 12624), (0x812f, 10557), (0x8130, 12625), (0x8131, 14584), (0x8132, 20304),
+    (0x8133, 12626), (0x8134, 12627), (0x8135, 12628), (0x8136, 20300), (0x8137, 12629), (0x8138, 12274), (0x8139, 12630), (0x813a, 12631),
+    (0x813b, 12632), (0x813c, 12633), (0x813d, 12634), (0x813e, 13207), (0x813f, 12635), (0x8140, 12730), (0x8141, 12731), (0x8142, 12732),
+    (0x8143, 12733), (0x8144, 12734), (0x8145, 12735), (0x8146, 14428), (0x8147, 12736), (0x8148, 20305), (0x8149, 12737), (0x814a, 12081),
+    (0x814b, 15509), (0x814c, 20306), (0x814d, 12738), (0x814e, 12739), (0x814f, 12740), (0x8150, 10560), (0x8151, 10558), (0x8152, 12741),
+    (0x8153, 20307), (0x8154, 13422), (0x8155, 14618), (0x8156, 12742), (0x8157, 12743), (0x8158, 12744), (0x8159, 20309), (0x815a, 20310),
+    (0x815b, 12745), (0x815c, 12746), (0x815d, 12747), (0x815e, 12748), (0x815f, 12749), (0x8160, 20312), (0x8161, 12750), (0x8162, 12751),
+    (0x8163, 12752), (0x8164, 12753), (0x8165, 15145), (0x8166, 12754), (0x8167, 20317), (0x8168, 12755), (0x8169, 20313), (0x816a, 12756),
+    (0x816b, 12757), (0x816c, 12758), (0x816d, 20316), (0x816e, 13674), (0x816f, 12759), (0x8170, 15387), (0x8171, 20311), (0x8172, 12760),
+    (0x8173, 12761), (0x8174, 20308), (0x8175, 12762), (0x8176, 12763), (0x8177, 12764), (0x8178, 12765), (0x8179, 10570), (0x817a, 14972),
+    (0x817b, 12894), (0x817c, 20314), (0x817d, 20315), (0x817e, 14403), (0x817f, 14575), (0x8180, 9107), (0x8181, 12766), (0x8182, 20321),
+    (0x8183, 12767), (0x8184, 12768), (0x8185, 12769), (0x8186, 12770), (0x8187, 12771), (0x8188, 20320), (0x8189, 12772), (0x818a, 9423),
+    (0x818b, 12773), (0x818c, 12774), (0x818d, 12775), (0x818e, 12776), (0x818f, 10609), (0x8190, 12777), (0x8191, 20322), (0x8192, 12778),
+    (0x8193, 12779), (0x8194, 12780), (0x8195, 12781), (0x8196, 12782), (0x8197, 12783), (0x8198, 9291), (0x8199, 12784), (0x819a, 12785),
+    (0x819b, 14382), (0x819c, 12829), (0x819d, 14920), (0x819e, 12786), (0x819f, 12787), (0x81a0, 12788), (0x81a1, 12789), (0x81a2, 12790),
+    (0x81a3, 20324), (0x81a4, 12791), (0x81a5, 12792), (0x81a6, 20427), (0x81a7, 12793), (0x81a8, 13097), (0x81a9, 12794), (0x81aa, 20325),
+    (0x81ab, 12795), (0x81ac, 12796), (0x81ad, 12797), (0x81ae, 12798), (0x81af, 12799), (0x81b0, 12800), (0x81b1, 12801), (0x81b2, 12802),
+    (0x81b3, 13812), (0x81b4, 12803), (0x81b5, 12804), (0x81b6, 12805), (0x81b7, 12806), (0x81b8, 12807), (0x81b9, 12808), (0x81ba, 18588),
+    (0x81bb, 20329), (0x81bc, 12809), (0x81bd, 12810), (0x81be, 12811), (0x81bf, 12812), (0x81c0, 14581), (0x81c1, 20426), (0x81c2, 9274),
+    (0x81c3, 15698), (0x81c4, 12813), (0x81c5, 12814), (0x81c6, 15545), (0x81c7, 12815), (0x81c8, 12816), (0x81c9, 12817), (0x81ca, 20328),
+    (0x81cb, 12818), (0x81cc, 20326), (0x81cd, 12819), (0x81ce, 12820), (0x81cf, 12821), (0x81d0, 12822), (0x81d1, 12823), (0x81d2, 12824),
+    (0x81d3, 12825), (0x81d4, 12920), (0x81d5, 12921), (0x81d6, 12922), (0x81d7, 12923), (0x81d8, 12924), (0x81d9, 12925), (0x81da, 12926),
+    (0x81db, 12927), (0x81dc, 12928), (0x81dd, 12929), (0x81de, 12930), (0x81df, 12931), (0x81e0, 12932), (0x81e1, 12933), (0x81e2, 12934),
+    (0x81e3, 9623), (0x81e4, 12935), (0x81e5, 12936), (0x81e6, 12937), (0x81e7, 20061), (0x81e8, 12938), (0x81e9, 12939), (0x81ea, 16487),
+    (0x81eb, 12940), (0x81ec, 21956), (0x81ed, 9679), (0x81ee, 12941), (0x81ef, 12942), (0x81f0, 12943), (0x81f1, 12944), (0x81f2, 12945),
+    (0x81f3, 16278), (0x81f4, 16279), (0x81f5, 12946), (0x81f6, 12947), (0x81f7, 12948), (0x81f8, 12949), (0x81f9, 12950), (0x81fa, 12951),
+    (0x81fb, 16128), (0x81fc, 11727), (0x81fd, 12952), (0x81fe, 21952), (0x81ff, 12953), (0x8200, 15493), (0x8201, 21953), (0x8202, 21954),
+    (0x8203, 12954), (0x8204, 21955), (0x8205, 11728), (0x8206, 15738), (0x8207, 12955), (0x8208, 12956), (0x8209, 12957), (0x820a, 12958),
+    (0x820b, 12959), (0x820c, 13839), (0x820d, 13840), (0x820e, 12960), (0x820f, 12961), (0x8210, 21789), (0x8211, 12962), (0x8212, 14035),
+    (0x8213, 12963), (0x8214, 14427), (0x8215, 12964), (0x8216, 12965), (0x8217, 12966), (0x8218, 12967), (0x8219, 12968), (0x821a, 12969),
+    (0x821b, 18547), (0x821c, 14175), (0x821d, 12970), (0x821e, 14797), (0x821f, 16304), (0x8220, 12971), (0x8221, 21958), (0x8222, 21959),
+    (0x8223, 21960), (0x8224, 12972), (0x8225, 12973), (0x8226, 12974), (0x8227, 12975), (0x8228, 21963), (0x8229, 12976), (0x822a, 10954),
+    (0x822b, 21964), (0x822c, 9092), (0x822d, 21961), (0x822e, 12977), (0x822f, 21962), (0x8230, 11497), (0x8231, 9458), (0x8232, 12978),
+    (0x8233, 21967), (0x8234, 21968), (0x8235, 10235), (0x8236, 9421), (0x8237, 14962), (0x8238, 21965), (0x8239, 9797), (0x823a, 12979),
+    (0x823b, 21966), (0x823c, 12980), (0x823d, 12981), (0x823e, 21969), (0x823f, 12982), (0x8240, 12983), (0x8241, 12984), (0x8242, 12985),
+    (0x8243, 12986), (0x8244, 21970), (0x8245, 12987), (0x8246, 12988), (0x8247, 14542), (0x8248, 12989), (0x8249, 21971), (0x824a, 12990),
+    (0x824b, 21972), (0x824c, 12991), (0x824d, 12992), (0x824e, 12993), (0x824f, 21973), (0x8250, 12994), (0x8251, 12995), (0x8252, 12996),
+    (0x8253, 12997), (0x8254, 12998), (0x8255, 12999), (0x8256, 13000), (0x8257, 13001), (0x8258, 14205), (0x8259, 13002), (0x825a, 21974),
+    (0x825b, 13003), (0x825c, 13004), (0x825d, 13005), (0x825e, 13006), (0x825f, 21975), (0x8260, 13007), (0x8261, 13008), (0x8262, 13009),
+    (0x8263, 13010), (0x8264, 13011), (0x8265, 13012), (0x8266, 13013), (0x8267, 13014), (0x8268, 21976), (0x8269, 13015), (0x826a, 13110),
+    (0x826b, 13111), (0x826c, 13112), (0x826d, 13113), (0x826e, 22007), (0x826f, 12283), (0x8270, 11377), (0x8271, 13114), (0x8272, 13786),
+    (0x8273, 15357), (0x8274, 19180), (0x8275, 13115), (0x8276, 13116), (0x8277, 13117), (0x8278, 13118), (0x8279, 17404), (0x827a, 15538),
+    (0x827b, 13119), (0x827c, 13120), (0x827d, 17405), (0x827e, 9037), (0x827f, 17406), (0x8280, 13121), (0x8281, 13122), (0x8282, 11553),
+    (0x8283, 13123), (0x8284, 17410), (0x8285, 13124), (0x8286, 13125), (0x8287, 13126), (0x8288, 16659), (0x8289, 13127), (0x828a, 17408),
+    (0x828b, 15758), (0x828c, 13128), (0x828d, 13829), (0x828e, 17411), (0x828f, 17407), (0x8290, 13129), (0x8291, 17412), (0x8292, 12637),
+    (0x8293, 13130), (0x8294, 13131), (0x8295, 13132), (0x8296, 13133), (0x8297, 17413), (0x8298, 17422), (0x8299, 17414), (0x829a, 13134),
+    (0x829b, 13135), (0x829c, 14788), (0x829d, 16250), (0x829e, 13136), (0x829f, 17432), (0x82a0, 13137), (0x82a1, 17430), (0x82a2, 13138),
+    (0x82a3, 13139), (0x82a4, 17435), (0x82a5, 11565), (0x82a6, 12456), (0x82a7, 13140), (0x82a8, 17409), (0x82a9, 17428), (0x82aa, 17431),
+    (0x82ab, 17415), (0x82ac, 10405), (0x82ad, 9062), (0x82ae, 17424), (0x82af, 15135), (0x82b0, 17418), (0x82b1, 11123), (0x82b2, 13141),
+    (0x82b3, 10383), (0x82b4, 17429), (0x82b5, 13142), (0x82b6, 13143), (0x82b7, 17423), (0x82b8, 17416), (0x82b9, 13454), (0x82ba, 13144),
+    (0x82bb, 13145), (0x82bc, 13146), (0x82bd, 15326), (0x82be, 17417), (0x82bf, 13147), (0x82c0, 13148), (0x82c1, 17427), (0x82c2, 13149),
+    (0x82c3, 13150), (0x82c4, 17433), (0x82c5, 13151), (0x82c6, 13152), (0x82c7, 14738), (0x82c8, 17419), (0x82c9, 13153), (0x82ca, 17420),
+    (0x82cb, 17425), (0x82cc, 17426), (0x82cd, 9457), (0x82ce, 17434), (0x82cf, 14208), (0x82d0, 13154), (0x82d1, 15888), (0x82d2, 17444),
+    (0x82d3, 17448), (0x82d4, 14351), (0x82d5, 17455), (0x82d6, 13155), (0x82d7, 12706), (0x82d8, 17445), (0x82d9, 13156), (0x82da, 13157),
+    (0x82db, 11908), (0x82dc, 17442), (0x82dd, 13158), (0x82de, 9115), (0x82df, 10757), (0x82e0, 17454), (0x82e1, 17436), (0x82e2, 13159),
+    (0x82e3, 17421), (0x82e4, 17439), (0x82e5, 13669), (0x82e6, 11939), (0x82e7, 13160), (0x82e8, 13161), (0x82e9, 13162), (0x82ea, 13163),
+    (0x82eb, 13802), (0x82ec, 13164), (0x82ed, 13165), (0x82ee, 13166), (0x82ef, 9244), (0x82f0, 13167), (0x82f1, 15677), (0x82f2, 13168),
+    (0x82f3, 13169), (0x82f4, 17443), (0x82f5, 13170), (0x82f6, 13171), (0x82f7, 17438), (0x82f8, 13172), (0x82f9, 13232), (0x82fa, 13173),
+    (0x82fb, 17447), (0x82fc, 13174), (0x82fd, 13175), (0x82fe, 13176), (0x82ff, 13177), (0x8300, 13178), (0x8301, 16469), (0x8302, 12650),
+    (0x8303, 10377), (0x8304, 13444), (0x8305, 12644), (0x8306, 17451), (0x8307, 17441), (0x8308, 17460), (0x8309, 17437), (0x830a, 13179),
+    (0x830b, 13180), (0x830c, 17446), (0x830d, 13181), (0x830e, 11690), (0x830f, 17440), (0x8310, 13182), (0x8311, 17449), (0x8312, 13183),
+    (0x8313, 13184), (0x8314, 17452), (0x8315, 17453), (0x8316, 13185), (0x8317, 17473), (0x8318, 13186), (0x8319, 13187), (0x831a, 17450),
+    (0x831b, 17577), (0x831c, 17456), (0x831d, 13188), (0x831e, 13189), (0x831f, 13190), (0x8320, 13191), (0x8321, 13192), (0x8322, 13193),
+    (0x8323, 13194), (0x8324, 13195), (0x8325, 13196), (0x8326, 13197), (0x8327, 11380), (0x8328, 9821), (0x8329, 13198), (0x832a, 13199),
+    (0x832b, 12638), (0x832c, 9476), (0x832d, 17475), (0x832e, 13200), (0x832f, 17467), (0x8330, 13201), (0x8331, 17464), (0x8332, 13202),
+    (0x8333, 17477), (0x8334, 17463), (0x8335, 15565), (0x8336, 9477), (0x8337, 13203), (0x8338, 13640), (0x8339, 13652), (0x833a, 17476),
+    (0x833b, 13204), (0x833c, 17462), (0x833d, 13205), (0x833e, 13300), (0x833f, 13301), (0x8340, 17472), (0x8341, 13302), (0x8342, 13303),
+    (0x8343, 17470), (0x8344, 13304), (0x8345, 13305), (0x8346, 11688), (0x8347, 17469), (0x8348, 13306), (0x8349, 9466), (0x834a, 13307),
+    (0x834b, 13308), (0x834c, 13309), (0x834d, 13310), (0x834e, 13311), (0x834f, 17468), (0x8350, 11391), (0x8351, 17457), (0x8352, 11151),
+    (0x8353, 13312), (0x8354, 12148), (0x8355, 13313), (0x8356, 13314), (0x8357, 13315), (0x8358, 13316), (0x8359, 13317), (0x835a, 11357),
+    (0x835b, 17458), (0x835c, 17459), (0x835d, 13318), (0x835e, 17466), (0x835f, 17471), (0x8360, 17474), (0x8361, 9995), (0x8362, 13319),
+    (0x8363, 13642), (0x8364, 11186), (0x8365, 17479), (0x8366, 17478), (0x8367, 15686), (0x8368, 17576), (0x8369, 17578), (0x836a, 17580),
+    (0x836b, 15566), (0x836c, 17579), (0x836d, 17581), (0x836e, 17582), (0x836f, 15494), (0x8370, 13320), (0x8371, 13321), (0x8372, 13322),
+    (0x8373, 13323), (0x8374, 13324), (0x8375, 13325), (0x8376, 13326), (0x8377, 10966), (0x8378, 17584), (0x8379, 13327), (0x837a, 13328),
+    (0x837b, 17597), (0x837c, 17592), (0x837d, 17595), (0x837e, 13329), (0x837f, 13330), (0x8380, 13331), (0x8381, 13332), (0x8382, 13333),
+    (0x8383, 13334), (0x8384, 13335), (0x8385, 17591), (0x8386, 13251), (0x8387, 13336), (0x8388, 13337), (0x8389, 12147), (0x838a, 13338),
+    (0x838b, 13339), (0x838c, 13340), (0x838d, 13341), (0x838e, 13790), (0x838f, 13342), (0x8390, 13343), (0x8391, 13344), (0x8392, 17461),
+    (0x8393, 17589), (0x8394, 13345), (0x8395, 13346), (0x8396, 13347), (0x8397, 13348), (0x8398, 17598), (0x8399, 13349), (0x839a, 13350),
+    (0x839b, 17465), (0x839c, 17590), (0x839d, 13351), (0x839e, 17599), (0x839f, 13352), (0x83a0, 17587), (0x83a1, 13353), (0x83a2, 13354),
+    (0x83a3, 13355), (0x83a4, 13356), (0x83a5, 13357), (0x83a6, 13358), (0x83a7, 13359), (0x83a8, 17600), (0x83a9, 17594), (0x83aa, 17588),
+    (0x83ab, 12835), (0x83ac, 13360), (0x83ad, 13361), (0x83ae, 13362), (0x83af, 13363), (0x83b0, 17583), (0x83b1, 12084), (0x83b2, 12266),
+    (0x83b3, 17585), (0x83b4, 17586), (0x83b5, 13364), (0x83b6, 17593), (0x83b7, 11196), (0x83b8, 17596), (0x83b9, 15683), (0x83ba, 17601),
+    (0x83bb, 13365), (0x83bc, 17602), (0x83bd, 12642), (0x83be, 13366), (0x83bf, 13367), (0x83c0, 17625), (0x83c1, 17603), (0x83c2, 13368),
+    (0x83c3, 13369), (0x83c4, 13370), (0x83c5, 17624), (0x83c6, 13371), (0x83c7, 10764), (0x83c8, 13372), (0x83c9, 13373), (0x83ca, 11738),
+    (0x83cb, 13374), (0x83cc, 11775), (0x83cd, 13375), (0x83ce, 13376), (0x83cf, 10967), (0x83d0, 13377), (0x83d1, 13378), (0x83d2, 13379),
+    (0x83d3, 13380), (0x83d4, 17617), (0x83d5, 13381), (0x83d6, 17612), (0x83d7, 13382), (0x83d8, 17606), (0x83d9, 13383), (0x83da, 13384),
+    (0x83db, 13385), (0x83dc, 9448), (0x83dd, 17610), (0x83de, 13386), (0x83df, 17618), (0x83e0, 9409), (0x83e1, 17628), (0x83e2, 13387),
+    (0x83e3, 13388), (0x83e4, 13389), (0x83e5, 17605), (0x83e6, 13390), (0x83e7, 13391), (0x83e8, 13392), (0x83e9, 13253), (0x83ea, 17623),
+    (0x83eb, 13393), (0x83ec, 13394), (0x83ed, 13395), (0x83ee, 13490), (0x83ef, 13491), (0x83f0, 17627), (0x83f1, 12321), (0x83f2, 10393),
+    (0x83f3, 13492), (0x83f4, 13493), (0x83f5, 13494), (0x83f6, 13495), (0x83f7, 13496), (0x83f8, 17621), (0x83f9, 17622), (0x83fa, 13497),
+    (0x83fb, 13498), (0x83fc, 13499), (0x83fd, 17611), (0x83fe, 13500), (0x83ff, 13501), (0x8400, 13502), (0x8401, 17604), (0x8402, 13503),
+    (0x8403, 17620), (0x8404, 14394), (0x8405, 13504), (0x8406, 17616), (0x8407, 13505), (0x8408, 13506), (0x8409, 13507), (0x840a, 13508),
+    (0x840b, 17609), (0x840c, 12675), (0x840d, 13233), (0x840e, 14739), (0x840f, 17619), (0x8410, 13509), (0x8411, 17615), (0x8412, 13510),
+    (0x8413, 13511), (0x8414, 13512), (0x8415, 13513), (0x8416, 13514), (0x8417, 13515), (0x8418, 17608), (0x8419, 13516), (0x841a, 13517),
+    (0x841b, 13518), (0x841c, 17613), (0x841d, 12505), (0x841e, 13519), (0x841f, 13520), (0x8420, 13521), (0x8421, 13522), (0x8422, 13523),
+    (0x8423, 13524), (0x8424, 15684), (0x8425, 15685), (0x8426, 17626), (0x8427, 14999), (0x8428, 13673), (0x8429, 13525), (0x842a, 13526),
+    (0x842b, 13527), (0x842c, 13528), (0x842d, 13529), (0x842e, 13530), (0x842f, 13531), (0x8430, 13532), (0x8431, 17645), (0x8432, 13533),
+    (0x8433, 13534), (0x8434, 13535), (0x8435, 13536), (0x8436, 13537), (0x8437, 13538), (0x8438, 17614), (0x8439, 13539), (0x843a, 13540),
+    (0x843b, 13541), (0x843c, 17639), (0x843d, 12513), (0x843e, 13542), (0x843f, 13543), (0x8440, 13544), (0x8441, 13545), (0x8442, 13546),
+    (0x8443, 13547), (0x8444, 13548), (0x8445, 13549), (0x8446, 17640), (0x8447, 13550), (0x8448, 13551), (0x8449, 13552), (0x844a, 13553),
+    (0x844b, 13554), (0x844c, 13555), (0x844d, 13556), (0x844e, 13557), (0x844f, 13558), (0x8450, 13559), (0x8451, 17630), (0x8452, 13560),
+    (0x8453, 13561), (0x8454, 13562), (0x8455, 13563), (0x8456, 13564), (0x8457, 16333), (0x8458, 13565), (0x8459, 17632), (0x845a, 17631),
+    (0x845b, 10625), (0x845c, 17629), (0x845d, 13566), (0x845e, 13567), (0x845f, 13568), (0x8460, 13569), (0x8461, 13252), (0x8462, 13570),
+    (0x8463, 10178), (0x8464, 13571), (0x8465, 13572), (0x8466, 13573), (0x8467, 13574), (0x8468, 13575), (0x8469, 17641), (0x846a, 13576),
+    (0x846b, 11014), (0x846c, 15930), (0x846d, 17646), (0x846e, 13577), (0x846f, 13578), (0x8470, 13579), (0x8471, 9833), (0x8472, 13580),
+    (0x8473, 17633), (0x8474, 13581), (0x8475, 11966), (0x8476, 17642), (0x8477, 13582), (0x8478, 17638), (0x8479, 13583), (0x847a, 17636),
+    (0x847b, 13584), (0x847c, 13585), (0x847d, 13680), (0x847e, 13681), (0x847f, 13682), (0x8480, 13683), (0x8481, 13684), (0x8482, 10032),
+    (0x8483, 13685), (0x8484, 13686), (0x8485, 13687), (0x8486, 13688), (0x8487, 17634), (0x8488, 17635), (0x8489, 17637), (0x848a, 13689),
+    (0x848b, 11510), (0x848c, 17643), (0x848d, 13690), (0x848e, 17644), (0x848f, 13691), (0x8490, 13692), (0x8491, 13693), (0x8492, 13694),
+    (0x8493, 13695), (0x8494, 13696), (0x8495, 13697), (0x8496, 13698), (0x8497, 17660), (0x8498, 13699), (0x8499, 12676), (0x849a, 13700),
+    (0x849b, 13701), (0x849c, 14221), (0x849d, 13702), (0x849e, 13703), (0x849f, 13704), (0x84a0, 13705), (0x84a1, 17657), (0x84a2, 13706),
+    (0x84a3, 13707), (0x84a4, 13708), (0x84a5, 13709), (0x84a6, 13710), (0x84a7, 13711), (0x84a8, 13712), (0x84a9, 13713), (0x84aa, 13714),
+    (0x84ab, 13715), (0x84ac, 13716), (0x84ad, 13717), (0x84ae, 13718), (0x84af, 16690), (0x84b0, 13719), (0x84b1, 13720), (0x84b2, 13254),
+    (0x84b3, 13721), (0x84b4, 17659), (0x84b5, 13722), (0x84b6, 13723), (0x84b7, 13724), (0x84b8, 16139), (0x84b9, 17658), (0x84ba, 17655),
+    (0x84bb, 13725), (0x84bc, 13726), (0x84bd, 17651), (0x84be, 13727), (0x84bf, 17654), (0x84c0, 13728), (0x84c1, 17647), (0x84c2, 13729),
+    (0x84c3, 13730), (0x84c4, 15183), (0x84c5, 13731), (0x84c6, 13732), (0x84c7, 13733), (0x84c8, 13734), (0x84c9, 13641), (0x84ca, 17653),
+    (0x84cb, 13735), (0x84cc, 13736), (0x84cd, 17648), (0x84ce, 13737), (0x84cf, 13738), (0x84d0, 17649), (0x84d1, 14237), (0x84d2, 13739),
+    (0x84d3, 17652), (0x84d4, 13740), (0x84d5, 13741), (0x84d6, 9260), (0x84d7, 13742), (0x84d8, 13743), (0x84d9, 13744), (0x84da, 13745),
+    (0x84db, 13746), (0x84dc, 13747), (0x84dd, 12087), (0x84de, 13748), (0x84df, 11332), (0x84e0, 17656), (0x84e1, 13749), (0x84e2, 13750),
+    (0x84e3, 17662), (0x84e4, 13751), (0x84e5, 17661), (0x84e6, 17650), (0x84e7, 13752), (0x84e8, 13753), (0x84e9, 13754), (0x84ea, 13755),
+    (0x84eb, 13756), (0x84ec, 13093), (0x84ed, 13757), (0x84ee, 13758), (0x84ef, 13759), (0x84f0, 17666), (0x84f1, 13760), (0x84f2, 13761),
+    (0x84f3, 13762), (0x84f4, 13763), (0x84f5, 13764), (0x84f6, 13765), (0x84f7, 13766), (0x84f8, 13767), (0x84f9, 13768), (0x84fa, 13769),
+    (0x84fb, 13770), (0x84fc, 17769), (0x84fd, 13771), (0x84fe, 13772), (0x84ff, 17768), (0x8500, 13773), (0x8501, 13774), (0x8502, 13775),
+    (0x8503, 13870), (0x8504, 13871), (0x8505, 13872), (0x8506, 13873), (0x8507, 13874), (0x8508, 13875), (0x8509, 13876), (0x850a, 13877),
+    (0x850b, 13878), (0x850c, 17663), (0x850d, 13879), (0x850e, 13880), (0x850f, 13881), (0x8510, 13882), (0x8511, 12714), (0x8512, 13883),
+    (0x8513, 12536), (0x8514, 13884), (0x8515, 13885), (0x8516, 13886), (0x8517, 16120), (0x8518, 13887), (0x8519, 13888), (0x851a, 14746),
+    (0x851b, 13889), (0x851c, 13890), (0x851d, 13891), (0x851e, 13892), (0x851f, 17668), (0x8520, 13893), (0x8521, 9449), (0x8522, 13894),
+    (0x8523, 13895), (0x8524, 13896), (0x8525, 13897), (0x8526, 13898), (0x8527, 13899), (0x8528, 13900), (0x8529, 13901), (0x852a, 13902),
+    (0x852b, 12897), (0x852c, 14028), (0x852d, 13903), (0x852e, 13904), (0x852f, 13905), (0x8530, 13906), (0x8531, 13907), (0x8532, 13908),
+    (0x8533, 13909), (0x8534, 13910), (0x8535, 13911), (0x8536, 13912), (0x8537, 13425), (0x8538, 17665), (0x8539, 17667), (0x853a, 17669),
+    (0x853b, 17767), (0x853c, 9035), (0x853d, 9261), (0x853e, 13913), (0x853f, 13914), (0x8540, 13915), (0x8541, 13916), (0x8542, 13917),
+    (0x8543, 17777), (0x8544, 13918), (0x8545, 13919), (0x8546, 13920), (0x8547, 13921), (0x8548, 17771), (0x8549, 11517), (0x854a, 13664),
+    (0x854b, 13922), (0x854c, 13923), (0x854d, 13924), (0x854e, 13925), (0x854f, 13926), (0x8550, 13927), (0x8551, 13928), (0x8552, 13929),
+    (0x8553, 13930), (0x8554, 13931), (0x8555, 13932), (0x8556, 17766), (0x8557, 13933), (0x8558, 13934), (0x8559, 17770), (0x855a, 13935),
+    (0x855b, 13936), (0x855c, 13937), (0x855d, 13938), (0x855e, 17774), (0x855f, 13939), (0x8560, 13940), (0x8561, 13941), (0x8562, 13942),
+    (0x8563, 13943), (0x8564, 17773), (0x8565, 13944), (0x8566, 13945), (0x8567, 13946), (0x8568, 17772), (0x8569, 13947), (0x856a, 13948),
+    (0x856b, 13949), (0x856c, 13950), (0x856d, 13951), (0x856e, 13952), (0x856f, 13953), (0x8570, 13954), (0x8571, 13955), (0x8572, 17778),
+    (0x8573, 13956), (0x8574, 15909), (0x8575, 13957), (0x8576, 13958), (0x8577, 13959), (0x8578, 13960), (0x8579, 17784), (0x857a, 17775),
+    (0x857b, 17779), (0x857c, 13961), (0x857d, 13962), (0x857e, 12122), (0x857f, 13963), (0x8580, 13964), (0x8581, 13965), (0x8582, 14060),
+    (0x8583, 14061), (0x8584, 9216), (0x8585, 17787), (0x8586, 14062), (0x8587, 17782), (0x8588, 14063), (0x8589, 14064), (0x858a, 14065),
+    (0x858b, 14066), (0x858c, 14067), (0x858d, 14068), (0x858e, 14069), (0x858f, 17783), (0x8590, 14070), (0x8591, 14071), (0x8592, 14072),
+    (0x8593, 14073), (0x8594, 14074), (0x8595, 14075), (0x8596, 14076), (0x8597, 14077), (0x8598, 14078), (0x8599, 14079), (0x859a, 14080),
+    (0x859b, 15301), (0x859c, 17786), (0x859d, 14081), (0x859e, 14082), (0x859f, 14083), (0x85a0, 14084), (0x85a1, 14085), (0x85a2, 14086),
+    (0x85a3, 14087), (0x85a4, 17780), (0x85a5, 14088), (0x85a6, 14089), (0x85a7, 14090), (0x85a8, 17781), (0x85a9, 14091), (0x85aa, 15134),
+    (0x85ab, 14092), (0x85ac, 14093), (0x85ad, 14094), (0x85ae, 17785), (0x85af, 14042), (0x85b0, 17790), (0x85b1, 14095), (0x85b2, 14096),
+    (0x85b3, 14097), (0x85b4, 14098), (0x85b5, 14099), (0x85b6, 14100), (0x85b7, 17789), (0x85b8, 14101), (0x85b9, 17788), (0x85ba, 14102),
+    (0x85bb, 14103), (0x85bc, 14104), (0x85bd, 14105), (0x85be, 14106), (0x85bf, 14107), (0x85c0, 14108), (0x85c1, 17792), (0x85c2, 14109),
+    (0x85c3, 14110), (0x85c4, 14111), (0x85c5, 14112), (0x85c6, 14113), (0x85c7, 14114), (0x85c8, 14115), (0x85c9, 11564), (0x85ca, 14116),
+    (0x85cb, 14117), (0x85cc, 14118), (0x85cd, 14119), (0x85ce, 14120), (0x85cf, 9461), (0x85d0, 12709), (0x85d1, 14121), (0x85d2, 14122),
+    (0x85d3, 17791), (0x85d4, 14123), (0x85d5, 13041), (0x85d6, 14124), (0x85d7, 14125), (0x85d8, 14126), (0x85d9, 14127), (0x85da, 14128),
+    (0x85db, 14129), (0x85dc, 17793), (0x85dd, 14130), (0x85de, 14131), (0x85df, 14132), (0x85e0, 14133), (0x85e1, 14134), (0x85e2, 14135),
+    (0x85e3, 14136), (0x85e4, 14402), (0x85e5, 14137), (0x85e6, 14138), (0x85e7, 14139), (0x85e8, 14140), (0x85e9, 10365), (0x85ea, 14141),
+    (0x85eb, 14142), (0x85ec, 14143), (0x85ed, 14144), (0x85ee, 14145), (0x85ef, 14146), (0x85f0, 14147), (0x85f1, 14148), (0x85f2, 14149),
+    (0x85f3, 14150), (0x85f4, 14151), (0x85f5, 14152), (0x85f6, 14153), (0x85f7, 14154), (0x85f8, 14155), (0x85f9, 14250), (0x85fa, 14251),
+    (0x85fb, 15934), (0x85fc, 14252), (0x85fd, 14253), (0x85fe, 14254), (0x85ff, 17794), (0x8600, 14255), (0x8601, 14256), (0x8602, 14257),
+    (0x8603, 14258), (0x8604, 14259), (0x8605, 17796), (0x8606, 14260), (0x8607, 14261), (0x8608, 14262), (0x8609, 14263), (0x860a, 14264),
+    (0x860b, 14265), (0x860c, 14266), (0x860d, 14267), (0x860e, 14268), (0x860f, 14269), (0x8610, 14270), (0x8611, 12827), (0x8612, 14271),
+    (0x8613, 14272), (0x8614, 14273), (0x8615, 14274), (0x8616, 17798), (0x8617, 14275), (0x8618, 14276), (0x8619, 14277), (0x861a, 14278),
+    (0x861b, 14279), (0x861c, 14280), (0x861d, 14281), (0x861e, 14282), (0x861f, 14283), (0x8620, 14284), (0x8621, 14285), (0x8622, 14286),
+    (0x8623, 14287), (0x8624, 14288), (0x8625, 14289), (0x8626, 14290), (0x8627, 17795), (0x8628, 14291), (0x8629, 17797), (0x862a, 14292),
+    (0x862b, 14293), (0x862c, 14294), (0x862d, 14295), (0x862e, 14296), (0x862f, 14297), (0x8630, 14298), (0x8631, 14299), (0x8632, 14300),
+    (0x8633, 14301), (0x8634, 14302), (0x8635, 14303), (0x8636, 14304), (0x8637, 14305), (0x8638, 16081), (0x8639, 14306), (0x863a, 14307),
+    (0x863b, 14308), (0x863c, 17799), (0x863d, 14309), (0x863e, 14310), (0x863f, 14311), (0x8640, 14312), (0x8641, 14313), (0x8642, 14314),
+    (0x8643, 14315), (0x8644, 14316), (0x8645, 14317), (0x8646, 14318), (0x8647, 14319), (0x8648, 14320), (0x8649, 14321), (0x864a, 14322),
+    (0x864b, 14323), (0x864c, 14324), (0x864d, 21579), (0x864e, 11117), (0x864f, 12463), (0x8650, 13031), (0x8651, 12484), (0x8652, 14325),
+    (0x8653, 14326), (0x8654, 21580), (0x8655, 14327), (0x8656, 14328), (0x8657, 14329), (0x8658, 14330), (0x8659, 14331), (0x865a, 15178),
+    (0x865b, 14332), (0x865c, 14333), (0x865d, 14334), (0x865e, 15736), (0x865f, 14335), (0x8660, 14336), (0x8661, 14337), (0x8662, 20264),
+    (0x8663, 14338), (0x8664, 14339), (0x8665, 14340), (0x8666, 14341), (0x8667, 14342), (0x8668, 14343), (0x8669, 14344), (0x866a, 14345),
+    (0x866b, 9665), (0x866c, 21581), (0x866d, 14440), (0x866e, 21582), (0x866f, 14441), (0x8670, 14442), (0x8671, 13978), (0x8672, 14443),
+    (0x8673, 14444), (0x8674, 14445), (0x8675, 14446), (0x8676, 14447), (0x8677, 14448), (0x8678, 14449), (0x8679, 10996), (0x867a, 21584),
+    (0x867b, 21586), (0x867c, 21585), (0x867d, 14223), (0x867e, 14941), (0x867f, 21583), (0x8680, 13985), (0x8681, 15532), (0x8682, 12521),
+    (0x8683, 14450), (0x8684, 14451), (0x8685, 14452), (0x8686, 14453), (0x8687, 14454), (0x8688, 14455), (0x8689, 14456), (0x868a, 14760),
+    (0x868b, 21589), (0x868c, 9111), (0x868d, 21588), (0x868e, 14457), (0x868f, 14458), (0x8690, 14459), (0x8691, 14460), (0x8692, 14461),
+    (0x8693, 21595), (0x8694, 14462), (0x8695, 9452), (0x8696, 14463), (0x8697, 14464), (0x8698, 14465), (0x8699, 14466), (0x869a, 14467),
+    (0x869b, 14468), (0x869c, 15328), (0x869d, 21591), (0x869e, 14469), (0x869f, 14470), (0x86a0, 14471), (0x86a1, 14472), (0x86a2, 14473),
+    (0x86a3, 21593), (0x86a4, 15938), (0x86a5, 14474), (0x86a6, 14475), (0x86a7, 21592), (0x86a8, 21587), (0x86a9, 21596), (0x86aa, 21594),
+    (0x86ab, 14476), (0x86ac, 21590), (0x86ad, 14477), (0x86ae, 14478), (0x86af, 21604), (0x86b0, 21601), (0x86b1, 21603), (0x86b2, 14479),
+    (0x86b3, 14480), (0x86b4, 21607), (0x86b5, 21599), (0x86b6, 21597), (0x86b7, 14481), (0x86b8, 14482), (0x86b9, 14483), (0x86ba, 21602),
+    (0x86bb, 14484), (0x86bc, 14485), (0x86bd, 14486), (0x86be, 14487), (0x86bf, 14488), (0x86c0, 16336), (0x86c1, 14489), (0x86c2, 14490),
+    (0x86c3, 14491), (0x86c4, 21598), (0x86c5, 14492), (0x86c6, 13484), (0x86c7, 13838), (0x86c8, 14493), (0x86c9, 21605), (0x86ca, 10773),
+    (0x86cb, 9991), (0x86cc, 14494), (0x86cd, 14495), (0x86ce, 21600), (0x86cf, 21606), (0x86d0, 21613), (0x86d1, 21619), (0x86d2, 14496),
+    (0x86d3, 14497), (0x86d4, 11170), (0x86d5, 14498), (0x86d6, 14499), (0x86d7, 14500), (0x86d8, 21618), (0x86d9, 14595), (0x86da, 14501),
+    (0x86db, 16320), (0x86dc, 14502), (0x86dd, 14503), (0x86de, 21615), (0x86df, 21617), (0x86e0, 14504), (0x86e1, 14505), (0x86e2, 14506),
+    (0x86e3, 14507), (0x86e4, 10627), (0x86e5, 14508), (0x86e6, 14509), (0x86e7, 14510), (0x86e8, 14511), (0x86e9, 21608), (0x86ea, 14512),
+    (0x86eb, 14513), (0x86ec, 14514), (0x86ed, 21611), (0x86ee, 12534), (0x86ef, 14515), (0x86f0, 16116), (0x86f1, 21609), (0x86f2, 21610),
+    (0x86f3, 21612), (0x86f4, 21616), (0x86f5, 14516), (0x86f6, 14517), (0x86f7, 14518), (0x86f8, 21622), (0x86f9, 15703), (0x86fa, 14519),
+    (0x86fb, 14520), (0x86fc, 14521), (0x86fd, 14522), (0x86fe, 10239), (0x86ff, 14523), (0x8700, 14046), (0x8701, 14524), (0x8702, 10423),
+    (0x8703, 21620), (0x8704, 14525), (0x8705, 14526), (0x8706, 14527), (0x8707, 21621), (0x8708, 21623), (0x8709, 21626), (0x870a, 21624),
+    (0x870b, 14528), (0x870c, 14529), (0x870d, 21625), (0x870e, 14530), (0x870f, 14531), (0x8710, 14532), (0x8711, 14533), (0x8712, 15344),
+    (0x8713, 21614), (0x8714, 14534), (0x8715, 14576), (0x8716, 14535), (0x8717, 14772), (0x8718, 16254), (0x8719, 14630), (0x871a, 21632),
+    (0x871b, 14631), (0x871c, 12694), (0x871d, 14632), (0x871e, 21629), (0x871f, 14633), (0x8720, 14634), (0x8721, 12080), (0x8722, 21641),
+    (0x8723, 21627), (0x8724, 14635), (0x8725, 21630), (0x8726, 14636), (0x8727, 14637), (0x8728, 14638), (0x8729, 21637), (0x872a, 14639),
+    (0x872b, 14640), (0x872c, 14641), (0x872d, 14642), (0x872e, 21631), (0x872f, 14643), (0x8730, 14644), (0x8731, 21636), (0x8732, 14645),
+    (0x8733, 14646), (0x8734, 21635), (0x8735, 14647), (0x8736, 14648), (0x8737, 21638), (0x8738, 14649), (0x8739, 14650), (0x873a, 14651),
+    (0x873b, 21628), (0x873c, 14652), (0x873d, 14653), (0x873e, 21633), (0x873f, 21639), (0x8740, 14654), (0x8741, 14655), (0x8742, 14656),
+    (0x8743, 14657), (0x8744, 14658), (0x8745, 14659), (0x8746, 14660), (0x8747, 15687), (0x8748, 21634), (0x8749, 9490), (0x874a, 14661),
+    (0x874b, 14662), (0x874c, 21647), (0x874d, 14663), (0x874e, 15116), (0x874f, 14664), (0x8750, 14665), (0x8751, 14666), (0x8752, 14667),
+    (0x8753, 21650), (0x8754, 14668), (0x8755, 14669), (0x8756, 14670), (0x8757, 11155), (0x8758, 14671), (0x8759, 21654), (0x875a, 14672),
+    (0x875b, 14673), (0x875c, 14674), (0x875d, 14675), (0x875e, 14676), (0x875f, 14677), (0x8760, 21645), (0x8761, 14678), (0x8762, 14679),
+    (0x8763, 21651), (0x8764, 21653), (0x8765, 21655), (0x8766, 14680), (0x8767, 14681), (0x8768, 14682), (0x8769, 14683), (0x876a, 14684),
+    (0x876b, 14685), (0x876c, 14686), (0x876d, 14687), (0x876e, 21648), (0x876f, 14688), (0x8770, 21646), (0x8771, 14689), (0x8772, 14690),
+    (0x8773, 14691), (0x8774, 11016), (0x8775, 14692), (0x8776, 10066), (0x8777, 14693), (0x8778, 14694), (0x8779, 14695), (0x877a, 14696),
+    (0x877b, 21644), (0x877c, 21652), (0x877d, 21642), (0x877e, 21643), (0x877f, 14697), (0x8780, 14698), (0x8781, 14699), (0x8782, 21640),
+    (0x8783, 21761), (0x8784, 14700), (0x8785, 21758), (0x8786, 14701), (0x8787, 14702), (0x8788, 21757), (0x8789, 14703), (0x878a, 14704),
+    (0x878b, 21649), (0x878c, 14705), (0x878d, 13643), (0x878e, 14706), (0x878f, 14707), (0x8790, 14708), (0x8791, 14709), (0x8792, 14710),
+    (0x8793, 21656), (0x8794, 14711), (0x8795, 14712), (0x8796, 14713), (0x8797, 21760), (0x8798, 14714), (0x8799, 14715), (0x879a, 14716),
+    (0x879b, 14717), (0x879c, 14718), (0x879d, 14719), (0x879e, 14720), (0x879f, 12723), (0x87a0, 14721), (0x87a1, 14722), (0x87a2, 14723),
+    (0x87a3, 14724), (0x87a4, 14725), (0x87a5, 14820), (0x87a6, 14821), (0x87a7, 14822), (0x87a8, 21658), (0x87a9, 14823), (0x87aa, 14824),
+    (0x87ab, 21762), (0x87ac, 21764), (0x87ad, 21759), (0x87ae, 14825), (0x87af, 21657), (0x87b0, 14826), (0x87b1, 14827), (0x87b2, 14828),
+    (0x87b3, 21766), (0x87b4, 14829), (0x87b5, 21765), (0x87b6, 14830), (0x87b7, 14831), (0x87b8, 14832), (0x87b9, 14833), (0x87ba, 12506),
+    (0x87bb, 14834), (0x87bc, 14835), (0x87bd, 21769), (0x87be, 14836), (0x87bf, 14837), (0x87c0, 21771), (0x87c1, 14838), (0x87c2, 14839),
+    (0x87c3, 14840), (0x87c4, 14841), (0x87c5, 14842), (0x87c6, 21756), (0x87c7, 14843), (0x87c8, 14844), (0x87c9, 14845), (0x87ca, 21772),
+    (0x87cb, 21767), (0x87cc, 14846), (0x87cd, 14847), (0x87ce, 14848), (0x87cf, 14849), (0x87d0, 14850), (0x87d1, 21770), (0x87d2, 21659),
+    (0x87d3, 21768), (0x87d4, 14851), (0x87d5, 14852), (0x87d6, 14853), (0x87d7, 14854), (0x87d8, 14855), (0x87d9, 14856), (0x87da, 14857),
+    (0x87db, 21773), (0x87dc, 14858), (0x87dd, 14859), (0x87de, 14860), (0x87df, 14861), (0x87e0, 21775), (0x87e1, 14862), (0x87e2, 14863),
+    (0x87e3, 14864), (0x87e4, 14865), (0x87e5, 21763), (0x87e6, 14866), (0x87e7, 14867), (0x87e8, 14868), (0x87e9, 14869), (0x87ea, 21774),
+    (0x87eb, 14870), (0x87ec, 14871), (0x87ed, 14872), (0x87ee, 21776), (0x87ef, 14873), (0x87f0, 14874), (0x87f1, 14875), (0x87f2, 14876),
+    (0x87f3, 14877), (0x87f4, 14878), (0x87f5, 14879), (0x87f6, 14880), (0x87f7, 14881), (0x87f8, 14882), (0x87f9, 15128), (0x87fa, 14883),
+    (0x87fb, 14884), (0x87fc, 14885), (0x87fd, 14886), (0x87fe, 21779), (0x87ff, 14887), (0x8800, 14888), (0x8801, 14889), (0x8802, 14890),
+    (0x8803, 16904), (0x8804, 14891), (0x8805, 14892), (0x8806, 14893), (0x8807, 14894), (0x8808, 14895), (0x8809, 14896), (0x880a, 21780),
+    (0x880b, 14897), (0x880c, 14898), (0x880d, 14899), (0x880e, 14900), (0x880f, 14901), (0x8810, 14902), (0x8811, 14903), (0x8812, 14904),
+    (0x8813, 21778), (0x8814, 14905), (0x8815, 13653), (0x8816, 21777), (0x8817, 14906), (0x8818, 14907), (0x8819, 14908), (0x881a, 14909),
+    (0x881b, 21781), (0x881c, 14910), (0x881d, 14911), (0x881e, 14912), (0x881f, 14913), (0x8820, 14914), (0x8821, 21782), (0x8822, 9817),
+    (0x8823, 14915), (0x8824, 15010), (0x8825, 15011), (0x8826, 15012), (0x8827, 15013), (0x8828, 15014), (0x8829, 15015), (0x882a, 15016),
+    (0x882b, 15017), (0x882c, 15018), (0x882d, 15019), (0x882e, 15020), (0x882f, 15021), (0x8830, 15022), (0x8831, 15023), (0x8832, 20840),
+    (0x8833, 15024), (0x8834, 15025), (0x8835, 15026), (0x8836, 15027), (0x8837, 15028), (0x8838, 15029), (0x8839, 21783), (0x883a, 15030),
+    (0x883b, 15031), (0x883c, 21784), (0x883d, 15032), (0x883e, 15033), (0x883f, 15034), (0x8840, 15305), (0x8841, 15035), (0x8842, 15036),
+    (0x8843, 15037), (0x8844, 21957), (0x8845, 15143), (0x8846, 15038), (0x8847, 15039), (0x8848, 15040), (0x8849, 15041), (0x884a, 15042),
+    (0x884b, 15043), (0x884c, 15153), (0x884d, 15355), (0x884e, 15044), (0x884f, 15045), (0x8850, 15046), (0x8851, 15047), (0x8852, 15048),
+    (0x8853, 15049), (0x8854, 14961), (0x8855, 15050), (0x8856, 15051), (0x8857, 11549), (0x8858, 15052), (0x8859, 15330), (0x885a, 15053),
+    (0x885b, 15054), (0x885c, 15055), (0x885d, 15056), (0x885e, 15057), (0x885f, 15058), (0x8860, 15059), (0x8861, 10991), (0x8862, 18408),
+    (0x8863, 15519), (0x8864, 21409), (0x8865, 9430), (0x8866, 15060), (0x8867, 15061), (0x8868, 9292), (0x8869, 21410), (0x886a, 15062),
+    (0x886b, 13807), (0x886c, 9631), (0x886d, 15063), (0x886e, 16897), (0x886f, 15064), (0x8870, 14160), (0x8871, 15065), (0x8872, 21411),
+    (0x8873, 15066), (0x8874, 15067), (0x8875, 15068), (0x8876, 15069), (0x8877, 16297), (0x8878, 15070), (0x8879, 15071), (0x887a, 15072),
+    (0x887b, 15073), (0x887c, 15074), (0x887d, 21412), (0x887e, 21977), (0x887f, 21413), (0x8880, 15075), (0x8881, 15877), (0x8882, 21414),
+    (0x8883, 15076), (0x8884, 9057), (0x8885, 21978), (0x8886, 15077), (0x8887, 15078), (0x8888, 21979), (0x8889, 15079), (0x888a, 15080),
+    (0x888b, 9877), (0x888c, 15081), (0x888d, 13074), (0x888e, 15082), (0x888f, 15083), (0x8890, 15084), (0x8891, 15085), (0x8892, 14372),
+    (0x8893, 15086), (0x8894, 15087), (0x8895, 15088), (0x8896, 15173), (0x8897, 15089), (0x8898, 15090), (0x8899, 15091), (0x889a, 15092),
+    (0x889b, 15093), (0x889c, 14599), (0x889d, 15094), (0x889e, 15095), (0x889f, 15096), (0x88a0, 15097), (0x88a1, 15098), (0x88a2, 21415),
+    (0x88a3, 15099), (0x88a4, 16898), (0x88a5, 15100), (0x88a6, 15101), (0x88a7, 15102), (0x88a8, 15103), (0x88a9, 15104), (0x88aa, 15105),
+    (0x88ab, 9242), (0x88ac, 15200), (0x88ad, 14929), (0x88ae, 15201), (0x88af, 15202), (0x88b0, 15203), (0x88b1, 10549), (0x88b2, 15204),
+    (0x88b3, 15205), (0x88b4, 15206), (0x88b5, 15207), (0x88b6, 15208), (0x88b7, 21417), (0x88b8, 15209), (0x88b9, 15210), (0x88ba, 15211),
+    (0x88bb, 15212), (0x88bc, 21418), (0x88bd, 15213), (0x88be, 15214), (0x88bf, 15215), (0x88c0, 15216), (0x88c1, 9440), (0x88c2, 12304),
+    (0x88c3, 15217), (0x88c4, 15218), (0x88c5, 16451), (0x88c6, 21416), (0x88c7, 15219), (0x88c8, 15220), (0x88c9, 21419), (0x88ca, 15221),
+    (0x88cb, 15222), (0x88cc, 15223), (0x88cd, 15224), (0x88ce, 21421), (0x88cf, 15225), (0x88d0, 15226), (0x88d1, 15227), (0x88d2, 16901),
+    (0x88d3, 15228), (0x88d4, 15550), (0x88d5, 15868), (0x88d6, 15229), (0x88d7, 15230), (0x88d8, 21980), (0x88d9, 13610), (0x88da, 15231),
+    (0x88db, 15232), (0x88dc, 15233), (0x88dd, 15234), (0x88de, 15235), (0x88df, 21981), (0x88e0, 15236), (0x88e1, 15237), (0x88e2, 21420),
+    (0x88e3, 21422), (0x88e4, 11942), (0x88e5, 21423), (0x88e6, 15238), (0x88e7, 15239), (0x88e8, 21427), (0x88e9, 15240), (0x88ea, 15241),
+    (0x88eb, 15242), (0x88ec, 15243), (0x88ed, 15244), (0x88ee, 15245), (0x88ef, 15246), (0x88f0, 21429), (0x88f1, 21424), (0x88f2, 15247),
+    (0x88f3, 13824), (0x88f4, 13080), (0x88f5, 15248), (0x88f6, 15249), (0x88f7, 15250), (0x88f8, 12512), (0x88f9, 10827), (0x88fa, 15251),
+    (0x88fb, 15252), (0x88fc, 21426), (0x88fd, 15253), (0x88fe, 21428), (0x88ff, 15254), (0x8900, 15255), (0x8901, 15256), (0x8902, 10786),
+    (0x8903, 15257), (0x8904, 15258), (0x8905, 15259), (0x8906, 15260), (0x8907, 15261), (0x8908, 15262), (0x8909, 15263), (0x890a, 21434),
+    (0x890b, 15264), (0x890c, 15265), (0x890d, 15266), (0x890e, 15267), (0x890f, 15268), (0x8910, 10979), (0x8911, 15269), (0x8912, 9118),
+    (0x8913, 21432), (0x8914, 15270), (0x8915, 15271), (0x8916, 15272), (0x8917, 15273), (0x8918, 15274), (0x8919, 21431), (0x891a, 21425),
+    (0x891b, 21433), (0x891c, 15275), (0x891d, 15276), (0x891e, 15277), (0x891f, 15278), (0x8920, 15279), (0x8921, 21430), (0x8922, 15280),
+    (0x8923, 15281), (0x8924, 15282), (0x8925, 13661), (0x8926, 15283), (0x8927, 15284), (0x8928, 15285), (0x8929, 15286), (0x892a, 14577),
+    (0x892b, 21436), (0x892c, 15287), (0x892d, 15288), (0x892e, 15289), (0x892f, 15290), (0x8930, 19124), (0x8931, 15291), (0x8932, 15292),
+    (0x8933, 15293), (0x8934, 21435), (0x8935, 15294), (0x8936, 21437), (0x8937, 15295), (0x8938, 15390), (0x8939, 15391), (0x893a, 15392),
+    (0x893b, 15393), (0x893c, 15394), (0x893d, 15395), (0x893e, 15396), (0x893f, 15397), (0x8940, 15398), (0x8941, 21438), (0x8942, 15399),
+    (0x8943, 15400), (0x8944, 14984), (0x8945, 15401), (0x8946, 15402), (0x8947, 15403), (0x8948, 15404), (0x8949, 15405), (0x894a, 15406),
+    (0x894b, 15407), (0x894c, 15408), (0x894d, 15409), (0x894e, 15410), (0x894f, 15411), (0x8950, 15412), (0x8951, 15413), (0x8952, 15414),
+    (0x8953, 15415), (0x8954, 15416), (0x8955, 15417), (0x8956, 15418), (0x8957, 15419), (0x8958, 15420), (0x8959, 15421), (0x895a, 15422),
+    (0x895b, 15423), (0x895c, 15424), (0x895d, 15425), (0x895e, 21982), (0x895f, 11578), (0x8960, 15426), (0x8961, 15427), (0x8962, 15428),
+    (0x8963, 15429), (0x8964, 15430), (0x8965, 15431), (0x8966, 21439), (0x8967, 15432), (0x8968, 15433), (0x8969, 15434), (0x896a, 15435),
+    (0x896b, 15436), (0x896c, 15437), (0x896d, 15438), (0x896e, 15439), (0x896f, 15440), (0x8970, 15441), (0x8971, 15442), (0x8972, 15443),
+    (0x8973, 15444), (0x8974, 15445), (0x8975, 15446), (0x8976, 15447), (0x8977, 15448), (0x8978, 15449), (0x8979, 15450), (0x897a, 15451),
+    (0x897b, 21440), (0x897c, 15452), (0x897d, 15453), (0x897e, 15454), (0x897f, 14812), (0x8980, 15455), (0x8981, 15495), (0x8982, 15456),
+    (0x8983, 21466), (0x8984, 15457), (0x8985, 15458), (0x8986, 10563), (0x8987, 15459), (0x8988, 15460), (0x8989, 15461), (0x898a, 15462),
+    (0x898b, 15463), (0x898c, 15464), (0x898d, 15465), (0x898e, 15466), (0x898f, 15467), (0x8990, 15468), (0x8991, 15469), (0x8992, 15470),
+    (0x8993, 15471), (0x8994, 15472), (0x8995, 15473), (0x8996, 15474), (0x8997, 15475), (0x8998, 15476), (0x8999, 15477), (0x899a, 15478),
+    (0x899b, 15479), (0x899c, 15480), (0x899d, 15481), (0x899e, 15482), (0x899f, 15483), (0x89a0, 15484), (0x89a1, 15485), (0x89a2, 15580),
+    (0x89a3, 15581), (0x89a4, 15582), (0x89a5, 15583), (0x89a6, 15584), (0x89a7, 15585), (0x89a8, 15586), (0x89a9, 15587), (0x89aa, 15588),
+    (0x89ab, 15589), (0x89ac, 15590), (0x89ad, 15591), (0x89ae, 15592), (0x89af, 15593), (0x89b0, 15594), (0x89b1, 15595), (0x89b2, 15596),
+    (0x89b3, 15597), (0x89b4, 15598), (0x89b5, 15599), (0x89b6, 15600), (0x89b7, 15601), (0x89b8, 15602), (0x89b9, 15603), (0x89ba, 15604),
+    (0x89bb, 15605), (0x89bc, 15606), (0x89bd, 15607), (0x89be, 15608), (0x89bf, 15609), (0x89c0, 15610), (0x89c1, 11396), (0x89c2, 10794),
+    (0x89c3, 15611), (0x89c4, 10805), (0x89c5, 12692), (0x89c6, 14016), (0x89c7, 20117), (0x89c8, 12097), (0x89c9, 11770), (0x89ca, 20118),
+    (0x89cb, 20119), (0x89cc, 20120), (0x89cd, 15612), (0x89ce, 20121), (0x89cf, 20122), (0x89d0, 20123), (0x89d1, 20124), (0x89d2, 11534),
+    (0x89d3, 15613), (0x89d4, 15614), (0x89d5, 15615), (0x89d6, 22226), (0x89d7, 15616), (0x89d8, 15617), (0x89d9, 15618), (0x89da, 22228),
+    (0x89db, 15619), (0x89dc, 22229), (0x89dd, 15620), (0x89de, 22227), (0x89df, 15621), (0x89e0, 15622), (0x89e1, 15623), (0x89e2, 15624),
+    (0x89e3, 11561), (0x89e4, 15625), (0x89e5, 22326), (0x89e6, 9790), (0x89e7, 15626), (0x89e8, 15627), (0x89e9, 15628), (0x89ea, 15629),
+    (0x89eb, 22327), (0x89ec, 15630), (0x89ed, 15631), (0x89ee, 15632), (0x89ef, 22328), (0x89f0, 15633), (0x89f1, 15634), (0x89f2, 15635),
+    (0x89f3, 20443), (0x89f4, 15636), (0x89f5, 15637), (0x89f6, 15638), (0x89f7, 15639), (0x89f8, 15640), (0x89f9, 15641), (0x89fa, 15642),
+    (0x89fb, 15643), (0x89fc, 15644), (0x89fd, 15645), (0x89fe, 15646), (0x89ff, 15647), (0x8a00, 15347), (0x8a01, 15648), (0x8a02, 15649),
+    (0x8a03, 15650), (0x8a04, 15651), (0x8a05, 15652), (0x8a06, 15653), (0x8a07, 16889), (0x8a08, 15654), (0x8a09, 15655), (0x8a0a, 15656),
+    (0x8a0b, 15657), (0x8a0c, 15658), (0x8a0d, 15659), (0x8a0e, 15660), (0x8a0f, 15661), (0x8a10, 15662), (0x8a11, 15663), (0x8a12, 15664),
+    (0x8a13, 15665), (0x8a14, 15666), (0x8a15, 15667), (0x8a16, 15668), (0x8a17, 15669), (0x8a18, 15670), (0x8a19, 15671), (0x8a1a, 15672),
+    (0x8a1b, 15673), (0x8a1c, 15674), (0x8a1d, 15675), (0x8a1e, 15770), (0x8a1f, 15771), (0x8a20, 15772), (0x8a21, 15773), (0x8a22, 15774),
+    (0x8a23, 15775), (0x8a24, 15776), (0x8a25, 15777), (0x8a26, 15778), (0x8a27, 15779), (0x8a28, 15780), (0x8a29, 15781), (0x8a2a, 15782),
+    (0x8a2b, 15783), (0x8a2c, 15784), (0x8a2d, 15785), (0x8a2e, 15786), (0x8a2f, 15787), (0x8a30, 15788), (0x8a31, 15789), (0x8a32, 15790),
+    (0x8a33, 15791), (0x8a34, 15792), (0x8a35, 15793), (0x8a36, 15794), (0x8a37, 15795), (0x8a38, 15796), (0x8a39, 15797), (0x8a3a, 15798),
+    (0x8a3b, 15799), (0x8a3c, 15800), (0x8a3d, 15801), (0x8a3e, 22329), (0x8a3f, 15802), (0x8a40, 15803), (0x8a41, 15804), (0x8a42, 15805),
+    (0x8a43, 15806), (0x8a44, 15807), (0x8a45, 15808), (0x8a46, 15809), (0x8a47, 15810), (0x8a48, 20831), (0x8a49, 15811), (0x8a4a, 15812),
+    (0x8a4b, 15813), (0x8a4c, 15814), (0x8a4d, 15815), (0x8a4e, 15816), (0x8a4f, 15817), (0x8a50, 15818), (0x8a51, 15819), (0x8a52, 15820),
+    (0x8a53, 15821), (0x8a54, 15822), (0x8a55, 15823), (0x8a56, 15824), (0x8a57, 15825), (0x8a58, 15826), (0x8a59, 15827), (0x8a5a, 15828),
+    (0x8a5b, 15829), (0x8a5c, 15830), (0x8a5d, 15831), (0x8a5e, 15832), (0x8a5f, 15833), (0x8a60, 15834), (0x8a61, 15835), (0x8a62, 15836),
+    (0x8a63, 15837), (0x8a64, 15838), (0x8a65, 15839), (0x8a66, 15840), (0x8a67, 15841), (0x8a68, 15842), (0x8a69, 15843), (0x8a6a, 15844),
+    (0x8a6b, 15845), (0x8a6c, 15846), (0x8a6d, 15847), (0x8a6e, 15848), (0x8a6f, 15849), (0x8a70, 15850), (0x8a71, 15851), (0x8a72, 15852),
+    (0x8a73, 15853), (0x8a74, 15854), (0x8a75, 15855), (0x8a76, 15856), (0x8a77, 15857), (0x8a78, 15858), (0x8a79, 16073), (0x8a7a, 15859),
+    (0x8a7b, 15860), (0x8a7c, 15861), (0x8a7d, 15862), (0x8a7e, 15863), (0x8a7f, 15864), (0x8a80, 15865), (0x8a81, 15960), (0x8a82, 15961),
+    (0x8a83, 15962), (0x8a84, 15963), (0x8a85, 15964), (0x8a86, 15965), (0x8a87, 15966), (0x8a88, 15967), (0x8a89, 15769), (0x8a8a, 14405),
+    (0x8a8b, 15968), (0x8a8c, 15969), (0x8a8d, 15970), (0x8a8e, 15971), (0x8a8f, 15972), (0x8a90, 15973), (0x8a91, 15974), (0x8a92, 15975),
+    (0x8a93, 14001), (0x8a94, 15976), (0x8a95, 15977), (0x8a96, 15978), (0x8a97, 15979), (0x8a98, 15980), (0x8a99, 15981), (0x8a9a, 15982),
+    (0x8a9b, 15983), (0x8a9c, 15984), (0x8a9d, 15985), (0x8a9e, 15986), (0x8a9f, 15987), (0x8aa0, 15988), (0x8aa1, 15989), (0x8aa2, 15990),
+    (0x8aa3, 15991), (0x8aa4, 15992), (0x8aa5, 15993), (0x8aa6, 15994), (0x8aa7, 15995), (0x8aa8, 15996), (0x8aa9, 15997), (0x8aaa, 15998),
+    (0x8aab, 15999), (0x8aac, 16000), (0x8aad, 16001), (0x8aae, 16002), (0x8aaf, 16003), (0x8ab0, 16004), (0x8ab1, 16005), (0x8ab2, 16006),
+    (0x8ab3, 16007), (0x8ab4, 16008), (0x8ab5, 16009), (0x8ab6, 16010), (0x8ab7, 16011), (0x8ab8, 16012), (0x8ab9, 16013), (0x8aba, 16014),
+    (0x8abb, 16015), (0x8abc, 16016), (0x8abd, 16017), (0x8abe, 16018), (0x8abf, 16019), (0x8ac0, 16020), (0x8ac1, 16021), (0x8ac2, 16022),
+    (0x8ac3, 16023), (0x8ac4, 16024), (0x8ac5, 16025), (0x8ac6, 16026), (0x8ac7, 16027), (0x8ac8, 16028), (0x8ac9, 16029), (0x8aca, 16030),
+    (0x8acb, 16031), (0x8acc, 16032), (0x8acd, 16033), (0x8ace, 16034), (0x8acf, 16035), (0x8ad0, 16036), (0x8ad1, 16037), (0x8ad2, 16038),
+    (0x8ad3, 16039), (0x8ad4, 16040), (0x8ad5, 16041), (0x8ad6, 16042), (0x8ad7, 16043), (0x8ad8, 16044), (0x8ad9, 16045), (0x8ada, 16046),
+    (0x8adb, 16047), (0x8adc, 16048), (0x8add, 16049), (0x8ade, 16050), (0x8adf, 16051), (0x8ae0, 16052), (0x8ae1, 16053), (0x8ae2, 16054),
+    (0x8ae3, 16055), (0x8ae4, 16150), (0x8ae5, 16151), (0x8ae6, 16152), (0x8ae7, 16153), (0x8ae8, 16154), (0x8ae9, 16155), (0x8aea, 16156),
+    (0x8aeb, 16157), (0x8aec, 16158), (0x8aed, 16159), (0x8aee, 16160), (0x8aef, 16161), (0x8af0, 16162), (0x8af1, 16163), (0x8af2, 16164),
+    (0x8af3, 16165), (0x8af4, 16166), (0x8af5, 16167), (0x8af6, 16168), (0x8af7, 16169), (0x8af8, 16170), (0x8af9, 16171), (0x8afa, 16172),
+    (0x8afb, 16173), (0x8afc, 16174), (0x8afd, 16175), (0x8afe, 16176), (0x8aff, 16177), (0x8b00, 16178), (0x8b01, 16179), (0x8b02, 16180),
+    (0x8b03, 16181), (0x8b04, 16182), (0x8b05, 16183), (0x8b06, 16184), (0x8b07, 19127), (0x8b08, 16185), (0x8b09, 16186), (0x8b0a, 16187),
+    (0x8b0b, 16188), (0x8b0c, 16189), (0x8b0d, 16190), (0x8b0e, 16191), (0x8b0f, 16192), (0x8b10, 16193), (0x8b11, 16194), (0x8b12, 16195),
+    (0x8b13, 16196), (0x8b14, 16197), (0x8b15, 16198), (0x8b16, 16199), (0x8b17, 16200), (0x8b18, 16201), (0x8b19, 16202), (0x8b1a, 16203),
+    (0x8b1b, 16204), (0x8b1c, 16205), (0x8b1d, 16206), (0x8b1e, 16207), (0x8b1f, 16208), (0x8b20, 16209), (0x8b21, 16210), (0x8b22, 16211),
+    (0x8b23, 16212), (0x8b24, 16213), (0x8b25, 16214), (0x8b26, 22330), (0x8b27, 16215), (0x8b28, 16216), (0x8b29, 16217), (0x8b2a, 16218),
+    (0x8b2b, 16219), (0x8b2c, 16220), (0x8b2d, 16221), (0x8b2e, 16222), (0x8b2f, 16223), (0x8b30, 16224), (0x8b31, 16225), (0x8b32, 16226),
+    (0x8b33, 16227), (0x8b34, 16228), (0x8b35, 16229), (0x8b36, 16230), (0x8b37, 16231), (0x8b38, 16232), (0x8b39, 16233), (0x8b3a, 16234),
+    (0x8b3b, 16235), (0x8b3c, 16236), (0x8b3d, 16237), (0x8b3e, 16238), (0x8b3f, 16239), (0x8b40, 16240), (0x8b41, 16241), (0x8b42, 16242),
+    (0x8b43, 16243), (0x8b44, 16244), (0x8b45, 16245), (0x8b46, 16340), (0x8b47, 16341), (0x8b48, 16342), (0x8b49, 16343), (0x8b4a, 16344),
+    (0x8b4b, 16345), (0x8b4c, 16346), (0x8b4d, 16347), (0x8b4e, 16348), (0x8b4f, 16349), (0x8b50, 16350), (0x8b51, 16351), (0x8b52, 16352),
+    (0x8b53, 16353), (0x8b54, 16354), (0x8b55, 16355), (0x8b56, 16356), (0x8b57, 16357), (0x8b58, 16358), (0x8b59, 16359), (0x8b5a, 16360),
+    (0x8b5b, 16361), (0x8b5c, 16362), (0x8b5d, 16363), (0x8b5e, 16364), (0x8b5f, 16365), (0x8b60, 16366), (0x8b61, 16367), (0x8b62, 16368),
+    (0x8b63, 16369), (0x8b64, 16370), (0x8b65, 16371), (0x8b66, 11700), (0x8b67, 16372), (0x8b68, 16373), (0x8b69, 16374), (0x8b6a, 16375),
+    (0x8b6b, 16376), (0x8b6c, 13214), (0x8b6d, 16377), (0x8b6e, 16378), (0x8b6f, 16379), (0x8b70, 16380), (0x8b71, 16381), (0x8b72, 16382),
+    (0x8b73, 16383), (0x8b74, 16384), (0x8b75, 16385), (0x8b76, 16386), (0x8b77, 16387), (0x8b78, 16388), (0x8b79, 16389), (0x8b7a, 16390),
+    (0x8b7b, 16391), (0x8b7c, 16392), (0x8b7d, 16393), (0x8b7e, 16394), (0x8b7f, 16395), (0x8b80, 16396), (0x8b81, 16397), (0x8b82, 16398),
+    (0x8b83, 16399), (0x8b84, 16400), (0x8b85, 16401), (0x8b86, 16402), (0x8b87, 16403), (0x8b88, 16404), (0x8b89, 16405), (0x8b8a, 16406),
+    (0x8b8b, 16407), (0x8b8c, 16408), (0x8b8d, 16409), (0x8b8e, 16410), (0x8b8f, 16411), (0x8b90, 16412), (0x8b91, 16413), (0x8b92, 16414),
+    (0x8b93, 16415), (0x8b94, 16416), (0x8b95, 16417), (0x8b96, 16418), (0x8b97, 16419), (0x8b98, 16420), (0x8b99, 16421), (0x8b9a, 16422),
+    (0x8b9b, 16423), (0x8b9c, 16424), (0x8b9d, 16425), (0x8b9e, 16426), (0x8b9f, 16427), (0x8ba0, 17010), (0x8ba1, 11343), (0x8ba2, 10174),
+    (0x8ba3, 10573), (0x8ba4, 13632), (0x8ba5, 11310), (0x8ba6, 17011), (0x8ba7, 17012), (0x8ba8, 14399), (0x8ba9, 13620), (0x8baa, 17013),
+    (0x8bab, 13298), (0x8bac, 16428), (0x8bad, 15316), (0x8bae, 15558), (0x8baf, 15317), (0x8bb0, 11344), (0x8bb1, 16429), (0x8bb2, 11513),
+    (0x8bb3, 11183), (0x8bb4, 17014), (0x8bb5, 17015), (0x8bb6, 15335), (0x8bb7, 17016), (0x8bb8, 15182), (0x8bb9, 10244), (0x8bba, 12504),
+    (0x8bbb, 16430), (0x8bbc, 14202), (0x8bbd, 10432), (0x8bbe, 13847), (0x8bbf, 10390), (0x8bc0, 11772), (0x8bc1, 16249), (0x8bc2, 17017),
+    (0x8bc3, 17018), (0x8bc4, 13237), (0x8bc5, 16506), (0x8bc6, 13987), (0x8bc7, 16431), (0x8bc8, 16064), (0x8bc9, 14218), (0x8bca, 16134),
+    (0x8bcb, 17019), (0x8bcc, 16308), (0x8bcd, 9827), (0x8bce, 17021), (0x8bcf, 17020), (0x8bd0, 16432), (0x8bd1, 15560), (0x8bd2, 17022),
+    (0x8bd3, 17023), (0x8bd4, 17024), (0x8bd5, 14017), (0x8bd6, 17025), (0x8bd7, 13976), (0x8bd8, 17026), (0x8bd9, 17027), (0x8bda, 9642),
+    (0x8bdb, 16324), (0x8bdc, 17028), (0x8bdd, 11131), (0x8bde, 9989), (0x8bdf, 17029), (0x8be0, 17030), (0x8be1, 10813), (0x8be2, 15310),
+    (0x8be3, 15557), (0x8be4, 17031), (0x8be5, 10580), (0x8be6, 14989), (0x8be7, 9484), (0x8be8, 17032), (0x8be9, 17033), (0x8bea, 16433),
+    (0x8beb, 11570), (0x8bec, 14785), (0x8bed, 15754), (0x8bee, 17034), (0x8bef, 14808), (0x8bf0, 17035), (0x8bf1, 15728), (0x8bf2, 11184),
+    (0x8bf3, 17036), (0x8bf4, 14176), (0x8bf5, 14203), (0x8bf6, 17037), (0x8bf7, 13470), (0x8bf8, 16323), (0x8bf9, 17038), (0x8bfa, 13036),
+    (0x8bfb, 10198), (0x8bfc, 17039), (0x8bfd, 10399), (0x8bfe, 11921), (0x8bff, 17040), (0x8c00, 17041), (0x8c01, 14168), (0x8c02, 17042),
+    (0x8c03, 10062), (0x8c04, 17043), (0x8c05, 12289), (0x8c06, 16462), (0x8c07, 17044), (0x8c08, 14369), (0x8c09, 16434), (0x8c0a, 15559),
+    (0x8c0b, 12842), (0x8c0c, 17045), (0x8c0d, 10068), (0x8c0e, 11164), (0x8c0f, 17046), (0x8c10, 15124), (0x8c11, 17047), (0x8c12, 17048),
+    (0x8c13, 14754), (0x8c14, 17049), (0x8c15, 17050), (0x8c16, 17051), (0x8c17, 9492), (0x8c18, 17054), (0x8c19, 17052), (0x8c1a, 15367),
+    (0x8c1b, 17053), (0x8c1c, 12688), (0x8c1d, 17055), (0x8c1e, 16435), (0x8c1f, 17056), (0x8c20, 17057), (0x8c21, 17058), (0x8c22, 15132),
+    (0x8c23, 15490), (0x8c24, 9114), (0x8c25, 17059), (0x8c26, 13406), (0x8c27, 17060), (0x8c28, 11582), (0x8c29, 12636), (0x8c2a, 17061),
+    (0x8c2b, 17062), (0x8c2c, 12728), (0x8c2d, 14368), (0x8c2e, 17063), (0x8c2f, 17064), (0x8c30, 12095), (0x8c31, 13260), (0x8c32, 17065),
+    (0x8c33, 17066), (0x8c34, 13415), (0x8c35, 17067), (0x8c36, 17068), (0x8c37, 10775), (0x8c38, 16530), (0x8c39, 16531), (0x8c3a, 16532),
+    (0x8c3b, 16533), (0x8c3c, 16534), (0x8c3d, 16535), (0x8c3e, 16536), (0x8c3f, 16537), (0x8c40, 16538), (0x8c41, 11192), (0x8c42, 16539),
+    (0x8c43, 16540), (0x8c44, 16541), (0x8c45, 16542), (0x8c46, 10190), (0x8c47, 22033), (0x8c48, 16543), (0x8c49, 22034), (0x8c4a, 16544),
+    (0x8c4b, 16545), (0x8c4c, 14602), (0x8c4d, 16546), (0x8c4e, 16547), (0x8c4f, 16548), (0x8c50, 16549), (0x8c51, 16550), (0x8c52, 16551),
+    (0x8c53, 16552), (0x8c54, 16553), (0x8c55, 22160), (0x8c56, 16554), (0x8c57, 16555), (0x8c58, 16556), (0x8c59, 16557), (0x8c5a, 20299),
+    (0x8c5b, 16558), (0x8c5c, 16559), (0x8c5d, 16560), (0x8c5e, 16561), (0x8c5f, 16562), (0x8c60, 16563), (0x8c61, 14998), (0x8c62, 11146),
+    (0x8c63, 16564), (0x8c64, 16565), (0x8c65, 16566), (0x8c66, 16567), (0x8c67, 16568), (0x8c68, 16569), (0x8c69, 16570), (0x8c6a, 10957),
+    (0x8c6b, 15870), (0x8c6c, 16571), (0x8c6d, 16572), (0x8c6e, 16573), (0x8c6f, 16574), (0x8c70, 16575), (0x8c71, 16576), (0x8c72, 16577),
+    (0x8c73, 18392), (0x8c74, 16578), (0x8c75, 16579), (0x8c76, 16580), (0x8c77, 16581), (0x8c78, 22219), (0x8c79, 9225), (0x8c7a, 9487),
+    (0x8c7b, 16582), (0x8c7c, 16583), (0x8c7d, 16584), (0x8c7e, 16585), (0x8c7f, 16586), (0x8c80, 16587), (0x8c81, 16588), (0x8c82, 22220),
+    (0x8c83, 16589), (0x8c84, 16590), (0x8c85, 22222), (0x8c86, 16591), (0x8c87, 16592), (0x8c88, 16593), (0x8c89, 10974), (0x8c8a, 22221),
+    (0x8c8b, 16594), (0x8c8c, 12653), (0x8c8d, 16595), (0x8c8e, 16596), (0x8c8f, 16597), (0x8c90, 16598), (0x8c91, 16599), (0x8c92, 16600),
+    (0x8c93, 16601), (0x8c94, 22224), (0x8c95, 16602), (0x8c96, 16603), (0x8c97, 16604), (0x8c98, 22223), (0x8c99, 16605), (0x8c9a, 16606),
+    (0x8c9b, 16607), (0x8c9c, 16608), (0x8c9d, 16609), (0x8c9e, 16610), (0x8c9f, 16611), (0x8ca0, 16612), (0x8ca1, 16613), (0x8ca2, 16614),
+    (0x8ca3, 16615), (0x8ca4, 16616), (0x8ca5, 16617), (0x8ca6, 16618), (0x8ca7, 16619), (0x8ca8, 16620), (0x8ca9, 16621), (0x8caa, 16622),
+    (0x8cab, 16623), (0x8cac, 16624), (0x8cad, 16625), (0x8cae, 16720), (0x8caf, 16721), (0x8cb0, 16722), (0x8cb1, 16723), (0x8cb2, 16724),
+    (0x8cb3, 16725), (0x8cb4, 16726), (0x8cb5, 16727), (0x8cb6, 16728), (0x8cb7, 16729), (0x8cb8, 16730), (0x8cb9, 16731), (0x8cba, 16732),
+    (0x8cbb, 16733), (0x8cbc, 16734), (0x8cbd, 16735), (0x8cbe, 16736), (0x8cbf, 16737), (0x8cc0, 16738), (0x8cc1, 16739), (0x8cc2, 16740),
+    (0x8cc3, 16741), (0x8cc4, 16742), (0x8cc5, 16743), (0x8cc6, 16744), (0x8cc7, 16745), (0x8cc8, 16746), (0x8cc9, 16747), (0x8cca, 16748),
+    (0x8ccb, 16749), (0x8ccc, 16750), (0x8ccd, 16751), (0x8cce, 16752), (0x8ccf, 16753), (0x8cd0, 16754), (0x8cd1, 16755), (0x8cd2, 16756),
+    (0x8cd3, 16757), (0x8cd4, 16758), (0x8cd5, 16759), (0x8cd6, 16760), (0x8cd7, 16761), (0x8cd8, 16762), (0x8cd9, 16763), (0x8cda, 16764),
+    (0x8cdb, 16765), (0x8cdc, 16766), (0x8cdd, 16767), (0x8cde, 16768), (0x8cdf, 16769), (0x8ce0, 16770), (0x8ce1, 16771), (0x8ce2, 16772),
+    (0x8ce3, 16773), (0x8ce4, 16774), (0x8ce5, 16775), (0x8ce6, 16776), (0x8ce7, 16777), (0x8ce8, 16778), (0x8ce9, 16779), (0x8cea, 16780),
+    (0x8ceb, 16781), (0x8cec, 16782), (0x8ced, 16783), (0x8cee, 16784), (0x8cef, 16785), (0x8cf0, 16786), (0x8cf1, 16787), (0x8cf2, 16788),
+    (0x8cf3, 16789), (0x8cf4, 16790), (0x8cf5, 16791), (0x8cf6, 16792), (0x8cf7, 16793), (0x8cf8, 16794), (0x8cf9, 16795), (0x8cfa, 16796),
+    (0x8cfb, 16797), (0x8cfc, 16798), (0x8cfd, 16799), (0x8cfe, 16800), (0x8cff, 16801), (0x8d00, 16802), (0x8d01, 16803), (0x8d02, 16804),
+    (0x8d03, 16805), (0x8d04, 16806), (0x8d05, 16807), (0x8d06, 16808), (0x8d07, 16809), (0x8d08, 16810), (0x8d09, 16811), (0x8d0a, 16812),
+    (0x8d0b, 16813), (0x8d0c, 16814), (0x8d0d, 16815), (0x8d0e, 16910), (0x8d0f, 16911), (0x8d10, 16912), (0x8d11, 16913), (0x8d12, 16914),
+    (0x8d13, 16915), (0x8d14, 16916), (0x8d15, 16917), (0x8d16, 16918), (0x8d17, 16919), (0x8d18, 16920), (0x8d19, 16921), (0x8d1a, 16922),
+    (0x8d1b, 16923), (0x8d1c, 16924), (0x8d1d, 9235), (0x8d1e, 16129), (0x8d1f, 10571), (0x8d20, 16925), (0x8d21, 10752), (0x8d22, 9443),
+    (0x8d23, 15945), (0x8d24, 14960), (0x8d25, 9085), (0x8d26, 16098), (0x8d27, 11200), (0x8d28, 16287), (0x8d29, 10378), (0x8d2a, 14361),
+    (0x8d2b, 13227), (0x8d2c, 9280), (0x8d2d, 10761), (0x8d2e, 16337), (0x8d2f, 10800), (0x8d30, 10356), (0x8d31, 11395), (0x8d32, 20103),
+    (0x8d33, 20104), (0x8d34, 14434), (0x8d35, 10818), (0x8d36, 20105), (0x8d37, 9876), (0x8d38, 12654), (0x8d39, 10404), (0x8d3a, 10981),
+    (0x8d3b, 20106), (0x8d3c, 15949), (0x8d3d, 20107), (0x8d3e, 11359), (0x8d3f, 11178), (0x8d40, 20108), (0x8d41, 12317), (0x8d42, 12469),
+    (0x8d43, 15928), (0x8d44, 16477), (0x8d45, 20109), (0x8d46, 20110), (0x8d47, 20113), (0x8d48, 20111), (0x8d49, 20112), (0x8d4a, 13837),
+    (0x8d4b, 10564), (0x8d4c, 10201), (0x8d4d, 20114), (0x8d4e, 14039), (0x8d4f, 13820), (0x8d50, 9830), (0x8d51, 16926), (0x8d52, 16927),
+    (0x8d53, 18582), (0x8d54, 13081), (0x8d55, 20115), (0x8d56, 12086), (0x8d57, 16928), (0x8d58, 16459), (0x8d59, 20116), (0x8d5a, 16447),
+    (0x8d5b, 13677), (0x8d5c, 16676), (0x8d5d, 16670), (0x8d5e, 15927), (0x8d5f, 16929), (0x8d60, 15954), (0x8d61, 13811), (0x8d62, 15689),
+    (0x8d63, 10596), (0x8d64, 9659), (0x8d65, 16930), (0x8d66, 13841), (0x8d67, 22031), (0x8d68, 16931), (0x8d69, 16932), (0x8d6a, 16933),
+    (0x8d6b, 10978), (0x8d6c, 16934), (0x8d6d, 22032), (0x8d6e, 16935), (0x8d6f, 16936), (0x8d70, 16498), (0x8d71, 16937), (0x8d72, 16938),
+    (0x8d73, 22026), (0x8d74, 10561), (0x8d75, 16107), (0x8d76, 10592), (0x8d77, 13285), (0x8d78, 16939), (0x8d79, 16940), (0x8d7a, 16941),
+    (0x8d7b, 16942), (0x8d7c, 16943), (0x8d7d, 16944), (0x8d7e, 16945), (0x8d7f, 16946), (0x8d80, 16947), (0x8d81, 9630), (0x8d82, 16948),
+    (0x8d83, 16949), (0x8d84, 22027), (0x8d85, 9607), (0x8d86, 16950), (0x8d87, 16951), (0x8d88, 16952), (0x8d89, 16953), (0x8d8a, 15894),
+    (0x8d8b, 13482), (0x8d8c, 16954), (0x8d8d, 16955), (0x8d8e, 16956), (0x8d8f, 16957), (0x8d90, 16958), (0x8d91, 22029), (0x8d92, 16959),
+    (0x8d93, 16960), (0x8d94, 22028), (0x8d95, 16961), (0x8d96, 16962), (0x8d97, 16963), (0x8d98, 16964), (0x8d99, 16965), (0x8d9a, 16966),
+    (0x8d9b, 16967), (0x8d9c, 16968), (0x8d9d, 16969), (0x8d9e, 16970), (0x8d9f, 14388), (0x8da0, 16971), (0x8da1, 16972), (0x8da2, 16973),
+    (0x8da3, 13589), (0x8da4, 16974), (0x8da5, 16975), (0x8da6, 16976), (0x8da7, 16977), (0x8da8, 16978), (0x8da9, 16979), (0x8daa, 16980),
+    (0x8dab, 16981), (0x8dac, 16982), (0x8dad, 16983), (0x8dae, 16984), (0x8daf, 16985), (0x8db0, 16986), (0x8db1, 22030), (0x8db2, 16987),
+    (0x8db3, 16502), (0x8db4, 13046), (0x8db5, 22167), (0x8db6, 16988), (0x8db7, 16989), (0x8db8, 22162), (0x8db9, 16990), (0x8dba, 22170),
+    (0x8dbb, 16991), (0x8dbc, 22169), (0x8dbd, 16992), (0x8dbe, 16271), (0x8dbf, 22168), (0x8dc0, 16993), (0x8dc1, 16994), (0x8dc2, 16995),
+    (0x8dc3, 15895), (0x8dc4, 22171), (0x8dc5, 16996), (0x8dc6, 22179), (0x8dc7, 16997), (0x8dc8, 16998), (0x8dc9, 16999), (0x8dca, 17000),
+    (0x8dcb, 9072), (0x8dcc, 10063), (0x8dcd, 17001), (0x8dce, 22176), (0x8dcf, 22177), (0x8dd0, 17002), (0x8dd1, 13075), (0x8dd2, 17003),
+    (0x8dd3, 17004), (0x8dd4, 17005), (0x8dd5, 17100), (0x8dd6, 22172), (0x8dd7, 22173), (0x8dd8, 17101), (0x8dd9, 17102), (0x8dda, 22174),
+    (0x8ddb, 22178), (0x8ddc, 17103), (0x8ddd, 11749), (0x8dde, 22175), (0x8ddf, 10635), (0x8de0, 17104), (0x8de1, 17105), (0x8de2, 17106),
+    (0x8de3, 22183), (0x8de4, 22186), (0x8de5, 17107), (0x8de6, 17108), (0x8de7, 17109), (0x8de8, 11946), (0x8de9, 17110), (0x8dea, 10817),
+    (0x8deb, 22163), (0x8dec, 22180), (0x8ded, 17111), (0x8dee, 17112), (0x8def, 12468), (0x8df0, 17113), (0x8df1, 17114), (0x8df2, 17115),
+    (0x8df3, 14433), (0x8df4, 17116), (0x8df5, 11394), (0x8df6, 17117), (0x8df7, 22181), (0x8df8, 22182), (0x8df9, 22184), (0x8dfa, 10234),
+    (0x8dfb, 22185), (0x8dfc, 17118), (0x8dfd, 22188), (0x8dfe, 17119), (0x8dff, 17120), (0x8e00, 17121), (0x8e01, 17122), (0x8e02, 17123),
+    (0x8e03, 17124), (0x8e04, 17125), (0x8e05, 22164), (0x8e06, 17126), (0x8e07, 17127), (0x8e08, 17128), (0x8e09, 22187), (0x8e0a, 15702),
+    (0x8e0b, 17129), (0x8e0c, 9671), (0x8e0d, 17130), (0x8e0e, 17131), (0x8e0f, 14349), (0x8e10, 17132), (0x8e11, 17133), (0x8e12, 17134),
+    (0x8e13, 17135), (0x8e14, 22189), (0x8e15, 17136), (0x8e16, 17137), (0x8e17, 17138), (0x8e18, 17139), (0x8e19, 17140), (0x8e1a, 17141),
+    (0x8e1b, 17142), (0x8e1c, 17143), (0x8e1d, 22190), (0x8e1e, 11750), (0x8e1f, 22191), (0x8e20, 17144), (0x8e21, 17145), (0x8e22, 14408),
+    (0x8e23, 22194), (0x8e24, 17146), (0x8e25, 17147), (0x8e26, 17148), (0x8e27, 17149), (0x8e28, 17150), (0x8e29, 9445), (0x8e2a, 16492),
+    (0x8e2b, 17151), (0x8e2c, 22192), (0x8e2d, 17152), (0x8e2e, 22193), (0x8e2f, 22195), (0x8e30, 17153), (0x8e31, 22201), (0x8e32, 17154),
+    (0x8e33, 17155), (0x8e34, 17156), (0x8e35, 22199), (0x8e36, 17157), (0x8e37, 17158), (0x8e38, 17159), (0x8e39, 22198), (0x8e3a, 22196),
+    (0x8e3b, 17160), (0x8e3c, 17161), (0x8e3d, 22200), (0x8e3e, 17162), (0x8e3f, 17163), (0x8e40, 22197), (0x8e41, 22203), (0x8e42, 22204),
+    (0x8e43, 17164), (0x8e44, 14412), (0x8e45, 17165), (0x8e46, 17166), (0x8e47, 19126), (0x8e48, 9999), (0x8e49, 22202), (0x8e4a, 22207),
+    (0x8e4b, 14348), (0x8e4c, 17167), (0x8e4d, 17168), (0x8e4e, 17169), (0x8e4f, 17170), (0x8e50, 17171), (0x8e51, 22205), (0x8e52, 22206),
+    (0x8e53, 17172), (0x8e54, 17173), (0x8e55, 17174), (0x8e56, 17175), (0x8e57, 17176), (0x8e58, 17177), (0x8e59, 22165), (0x8e5a, 17178),
+    (0x8e5b, 17179), (0x8e5c, 17180), (0x8e5d, 17181), (0x8e5e, 17182), (0x8e5f, 17183), (0x8e60, 17184), (0x8e61, 17185), (0x8e62, 17186),
+    (0x8e63, 17187), (0x8e64, 17188), (0x8e65, 17189), (0x8e66, 9251), (0x8e67, 17190), (0x8e68, 17191), (0x8e69, 22166), (0x8e6a, 17192),
+    (0x8e6b, 17193), (0x8e6c, 10012), (0x8e6d, 9473), (0x8e6e, 17194), (0x8e6f, 22211), (0x8e70, 22208), (0x8e71, 17195), (0x8e72, 10220),
+    (0x8e73, 17290), (0x8e74, 22212), (0x8e75, 17291), (0x8e76, 22209), (0x8e77, 17292), (0x8e78, 17293), (0x8e79, 17294), (0x8e7a, 17295),
+    (0x8e7b, 17296), (0x8e7c, 22210), (0x8e7d, 17297), (0x8e7e, 17298), (0x8e7f, 9843), (0x8e80, 17299), (0x8e81, 15939), (0x8e82, 17300),
+    (0x8e83, 17301), (0x8e84, 17302), (0x8e85, 22213), (0x8e86, 17303), (0x8e87, 9684), (0x8e88, 17304), (0x8e89, 17305), (0x8e8a, 17306),
+    (0x8e8b, 17307), (0x8e8c, 17308), (0x8e8d, 17309), (0x8e8e, 17310), (0x8e8f, 22214), (0x8e90, 22216), (0x8e91, 17311), (0x8e92, 17312),
+    (0x8e93, 17313), (0x8e94, 22215), (0x8e95, 17314), (0x8e96, 17315), (0x8e97, 17316), (0x8e98, 17317), (0x8e99, 17318), (0x8e9a, 17319),
+    (0x8e9b, 17320), (0x8e9c, 22217), (0x8e9d, 17321), (0x8e9e, 22218), (0x8e9f, 17322), (0x8ea0, 17323), (0x8ea1, 17324), (0x8ea2, 17325),
+    (0x8ea3, 17326), (0x8ea4, 17327), (0x8ea5, 17328), (0x8ea6, 17329), (0x8ea7, 17330), (0x8ea8, 17331), (0x8ea9, 17332), (0x8eaa, 17333),
+    (0x8eab, 13852), (0x8eac, 10745), (0x8ead, 17334), (0x8eae, 17335), (0x8eaf, 13486), (0x8eb0, 17336), (0x8eb1, 17337), (0x8eb2, 10232),
+    (0x8eb3, 17338), (0x8eb4, 17339), (0x8eb5, 17340), (0x8eb6, 17341), (0x8eb7, 17342), (0x8eb8, 17343), (0x8eb9, 17344), (0x8eba, 14386),
+    (0x8ebb, 17345), (0x8ebc, 17346), (0x8ebd, 17347), (0x8ebe, 17348), (0x8ebf, 17349), (0x8ec0, 17350), (0x8ec1, 17351), (0x8ec2, 17352),
+    (0x8ec3, 17353), (0x8ec4, 17354), (0x8ec5, 17355), (0x8ec6, 17356), (0x8ec7, 17357), (0x8ec8, 17358), (0x8ec9, 17359), (0x8eca, 17360),
+    (0x8ecb, 17361), (0x8ecc, 17362), (0x8ecd, 17363), (0x8ece, 20051), (0x8ecf, 17364), (0x8ed0, 17365), (0x8ed1, 17366), (0x8ed2, 17367),
+    (0x8ed3, 17368), (0x8ed4, 17369), (0x8ed5, 17370), (0x8ed6, 17371), (0x8ed7, 17372), (0x8ed8, 17373), (0x8ed9, 17374), (0x8eda, 17375),
+    (0x8edb, 17376), (0x8edc, 17377), (0x8edd, 17378), (0x8ede, 17379), (0x8edf, 17380), (0x8ee0, 17381), (0x8ee1, 17382), (0x8ee2, 17383),
+    (0x8ee3, 17384), (0x8ee4, 17385), (0x8ee5, 17480), (0x8ee6, 17481), (0x8ee7, 17482), (0x8ee8, 17483), (0x8ee9, 17484), (0x8eea, 17485),
+    (0x8eeb, 17486), (0x8eec, 17487), (0x8eed, 17488), (0x8eee, 17489), (0x8eef, 17490), (0x8ef0, 17491), (0x8ef1, 17492), (0x8ef2, 17493),
+    (0x8ef3, 17494), (0x8ef4, 17495), (0x8ef5, 17496), (0x8ef6, 17497), (0x8ef7, 17498), (0x8ef8, 17499), (0x8ef9, 17500), (0x8efa, 17501),
+    (0x8efb, 17502), (0x8efc, 17503), (0x8efd, 17504), (0x8efe, 17505), (0x8eff, 17506), (0x8f00, 17507), (0x8f01, 17508), (0x8f02, 17509),
+    (0x8f03, 17510), (0x8f04, 17511), (0x8f05, 17512), (0x8f06, 17513), (0x8f07, 17514), (0x8f08, 17515), (0x8f09, 17516), (0x8f0a, 17517),
+    (0x8f0b, 17518), (0x8f0c, 17519), (0x8f0d, 17520), (0x8f0e, 17521), (0x8f0f, 17522), (0x8f10, 17523), (0x8f11, 17524), (0x8f12, 17525),
+    (0x8f13, 17526), (0x8f14, 17527), (0x8f15, 17528), (0x8f16, 17529), (0x8f17, 17530), (0x8f18, 17531), (0x8f19, 17532), (0x8f1a, 17533),
+    (0x8f1b, 17534), (0x8f1c, 17535), (0x8f1d, 17536), (0x8f1e, 17537), (0x8f1f, 17538), (0x8f20, 17539), (0x8f21, 17540), (0x8f22, 17541),
+    (0x8f23, 17542), (0x8f24, 17543), (0x8f25, 17544), (0x8f26, 17545), (0x8f27, 17546), (0x8f28, 17547), (0x8f29, 17548), (0x8f2a, 17549),
+    (0x8f2b, 17550), (0x8f2c, 17551), (0x8f2d, 17552), (0x8f2e, 17553), (0x8f2f, 17554), (0x8f30, 17555), (0x8f31, 17556), (0x8f32, 17557),
+    (0x8f33, 17558), (0x8f34, 17559), (0x8f35, 17560), (0x8f36, 17561), (0x8f37, 17562), (0x8f38, 17563), (0x8f39, 17564), (0x8f3a, 17565),
+    (0x8f3b, 17566), (0x8f3c, 17567), (0x8f3d, 17568), (0x8f3e, 17569), (0x8f3f, 17570), (0x8f40, 17571), (0x8f41, 17572), (0x8f42, 17573),
+    (0x8f43, 17574), (0x8f44, 17575), (0x8f45, 17670), (0x8f46, 17671), (0x8f47, 17672), (0x8f48, 17673), (0x8f49, 17674), (0x8f4a, 17675),
+    (0x8f4b, 17676), (0x8f4c, 17677), (0x8f4d, 17678), (0x8f4e, 17679), (0x8f4f, 17680), (0x8f50, 17681), (0x8f51, 17682), (0x8f52, 17683),
+    (0x8f53, 17684), (0x8f54, 17685), (0x8f55, 17686), (0x8f56, 17687), (0x8f57, 17688), (0x8f58, 17689), (0x8f59, 17690), (0x8f5a, 17691),
+    (0x8f5b, 17692), (0x8f5c, 17693), (0x8f5d, 17694), (0x8f5e, 17695), (0x8f5f, 17696), (0x8f60, 17697), (0x8f61, 17698), (0x8f62, 17699),
+    (0x8f63, 17700), (0x8f64, 17701), (0x8f65, 17702), (0x8f66, 9616), (0x8f67, 15959), (0x8f68, 10811), (0x8f69, 15194), (0x8f6a, 17703),
+    (0x8f6b, 19932), (0x8f6c, 16445), (0x8f6d, 19933), (0x8f6e, 12499), (0x8f6f, 13662), (0x8f70, 10993), (0x8f71, 19934), (0x8f72, 19935),
+    (0x8f73, 19936), (0x8f74, 16310), (0x8f75, 19937), (0x8f76, 19938), (0x8f77, 19940), (0x8f78, 19939), (0x8f79, 19941), (0x8f7a, 19942),
+    (0x8f7b, 13460), (0x8f7c, 19943), (0x8f7d, 15921), (0x8f7e, 19944), (0x8f7f, 11541), (0x8f80, 17704), (0x8f81, 19945), (0x8f82, 19946),
+    (0x8f83, 11542), (0x8f84, 19947), (0x8f85, 10553), (0x8f86, 12285), (0x8f87, 19948), (0x8f88, 9233), (0x8f89, 11167), (0x8f8a, 10820),
+    (0x8f8b, 19949), (0x8f8c, 17705), (0x8f8d, 20046), (0x8f8e, 20047), (0x8f8f, 20048), (0x8f90, 10443), (0x8f91, 11318), (0x8f92, 17706),
+    (0x8f93, 14033), (0x8f94, 18191), (0x8f95, 15880), (0x8f96, 14944), (0x8f97, 16078), (0x8f98, 20049), (0x8f99, 16117), (0x8f9a, 20050),
+    (0x8f9b, 15138), (0x8f9c, 10763), (0x8f9d, 17707), (0x8f9e, 9824), (0x8f9f, 9272), (0x8fa0, 17708), (0x8fa1, 17709), (0x8fa2, 17710),
+    (0x8fa3, 12082), (0x8fa4, 17711), (0x8fa5, 17712), (0x8fa6, 17713), (0x8fa7, 17714), (0x8fa8, 9285), (0x8fa9, 9286), (0x8faa, 17715),
+    (0x8fab, 9287), (0x8fac, 17716), (0x8fad, 17717), (0x8fae, 17718), (0x8faf, 17719), (0x8fb0, 9624), (0x8fb1, 13657), (0x8fb2, 17720),
+    (0x8fb3, 17721), (0x8fb4, 17722), (0x8fb5, 17723), (0x8fb6, 19128), (0x8fb7, 17724), (0x8fb8, 17725), (0x8fb9, 9278), (0x8fba, 17726),
+    (0x8fbb, 17727), (0x8fbc, 17728), (0x8fbd, 12296), (0x8fbe, 9864), (0x8fbf, 17729), (0x8fc0, 17730), (0x8fc1, 13403), (0x8fc2, 15731),
+    (0x8fc3, 17731), (0x8fc4, 13294), (0x8fc5, 15319), (0x8fc6, 17732), (0x8fc7, 10828), (0x8fc8, 12530), (0x8fc9, 17733), (0x8fca, 17734),
+    (0x8fcb, 17735), (0x8fcc, 17736), (0x8fcd, 17737), (0x8fce, 15688), (0x8fcf, 17738), (0x8fd0, 15908), (0x8fd1, 11587), (0x8fd2, 17739),
+    (0x8fd3, 19129), (0x8fd4, 10376), (0x8fd5, 19130), (0x8fd6, 17740), (0x8fd7, 17741), (0x8fd8, 11140), (0x8fd9, 16121), (0x8fda, 17742),
+    (0x8fdb, 11583), (0x8fdc, 15887), (0x8fdd, 14730), (0x8fde, 12267), (0x8fdf, 9652), (0x8fe0, 17743), (0x8fe1, 17744), (0x8fe2, 14431),
+    (0x8fe3, 17745), (0x8fe4, 19133), (0x8fe5, 19131), (0x8fe6, 19135), (0x8fe7, 17746), (0x8fe8, 19137), (0x8fe9, 19134), (0x8fea, 10022),
+    (0x8feb, 13245), (0x8fec, 17747), (0x8fed, 10067), (0x8fee, 19132), (0x8fef, 17748), (0x8ff0, 14051), (0x8ff1, 17749), (0x8ff2, 17750),
+    (0x8ff3, 19136), (0x8ff4, 17751), (0x8ff5, 17752), (0x8ff6, 17753), (0x8ff7, 12687), (0x8ff8, 9252), (0x8ff9, 11308), (0x8ffa, 17754),
+    (0x8ffb, 17755), (0x8ffc, 17756), (0x8ffd, 16458), (0x8ffe, 17757), (0x8fff, 17758), (0x9000, 14578), (0x9001, 14200), (0x9002, 14007),
+    (0x9003, 14396), (0x9004, 19139), (0x9005, 19138), (0x9006, 12895), (0x9007, 17759), (0x9008, 17760), (0x9009, 15296), (0x900a, 15318),
+    (0x900b, 19140), (0x900c, 17761), (0x900d, 19143), (0x900e, 17762), (0x900f, 14559), (0x9010, 16325), (0x9011, 19142), (0x9012, 10036),
+    (0x9013, 17763), (0x9014, 14565), (0x9015, 17764), (0x9016, 19144), (0x9017, 10191), (0x9018, 17765), (0x9019, 17860), (0x901a, 14543),
+    (0x901b, 10803), (0x901c, 17861), (0x901d, 14002), (0x901e, 9644), (0x901f, 14212), (0x9020, 15941), (0x9021, 19145), (0x9022, 10429),
+    (0x9023, 17862), (0x9024, 17863), (0x9025, 17864), (0x9026, 19141), (0x9027, 17865), (0x9028, 17866), (0x9029, 17867), (0x902a, 17868),
+    (0x902b, 17869), (0x902c, 17870), (0x902d, 19148), (0x902e, 9879), (0x902f, 19149), (0x9030, 17871), (0x9031, 17872), (0x9032, 17873),
+    (0x9033, 17874), (0x9034, 17875), (0x9035, 19146), (0x9036, 19147), (0x9037, 17876), (0x9038, 15546), (0x9039, 17877), (0x903a, 17878),
+    (0x903b, 12508), (0x903c, 9253), (0x903d, 17879), (0x903e, 15741), (0x903f, 17880), (0x9040, 17881), (0x9041, 10226), (0x9042, 14231),
+    (0x9043, 17882), (0x9044, 19150), (0x9045, 17883), (0x9046, 17884), (0x9047, 15761), (0x9048, 17885), (0x9049, 17886), (0x904a, 17887),
+    (0x904b, 17888), (0x904c, 17889), (0x904d, 9288), (0x904e, 17890), (0x904f, 10249), (0x9050, 19153), (0x9051, 19151), (0x9052, 19152),
+    (0x9053, 10007), (0x9054, 17891), (0x9055, 17892), (0x9056, 17893), (0x9057, 15522), (0x9058, 19155), (0x9059, 17894), (0x905a, 17895),
+    (0x905b, 19157), (0x905c, 17896), (0x905d, 17897), (0x905e, 17898), (0x905f, 17899), (0x9060, 17900), (0x9061, 17901), (0x9062, 19156),
+    (0x9063, 13413), (0x9064, 17902), (0x9065, 15488), (0x9066, 17903), (0x9067, 17904), (0x9068, 19154), (0x9069, 17905), (0x906a, 17906),
+    (0x906b, 17907), (0x906c, 17908), (0x906d, 15931), (0x906e, 16113), (0x906f, 17909), (0x9070, 17910), (0x9071, 17911), (0x9072, 17912),
+    (0x9073, 17913), (0x9074, 19159), (0x9075, 16516), (0x9076, 17914), (0x9077, 17915), (0x9078, 17916), (0x9079, 17917), (0x907a, 17918),
+    (0x907b, 17919), (0x907c, 17920), (0x907d, 19160), (0x907e, 17921), (0x907f, 9275), (0x9080, 15386), (0x9081, 17922), (0x9082, 19161),
+    (0x9083, 19163), (0x9084, 17923), (0x9085, 17924), (0x9086, 17925), (0x9087, 17926), (0x9088, 19162), (0x9089, 17927), (0x908a, 17928),
+    (0x908b, 19164), (0x908c, 17929), (0x908d, 17930), (0x908e, 17931), (0x908f, 17932), (0x9090, 17933), (0x9091, 15541), (0x9092, 17934),
+    (0x9093, 10018), (0x9094, 17935), (0x9095, 19538), (0x9096, 17936), (0x9097, 17090), (0x9098, 17937), (0x9099, 17093), (0x909a, 17938),
+    (0x909b, 17091), (0x909c, 17939), (0x909d, 17092), (0x909e, 17940), (0x909f, 17941), (0x90a0, 17942), (0x90a1, 17095), (0x90a2, 15152),
+    (0x90a3, 12864), (0x90a4, 17943), (0x90a5, 17944), (0x90a6, 9103), (0x90a7, 17945), (0x90a8, 17946), (0x90a9, 17947), (0x90aa, 15121),
+    (0x90ab, 17948), (0x90ac, 17094), (0x90ad, 17949), (0x90ae, 15717), (0x90af, 10935), (0x90b0, 17197), (0x90b1, 13476), (0x90b2, 17950),
+    (0x90b3, 17097), (0x90b4, 17096), (0x90b5, 13834), (0x90b6, 17098), (0x90b7, 17951), (0x90b8, 17196), (0x90b9, 16497), (0x90ba, 17099),
+    (0x90bb, 12313), (0x90bc, 17952), (0x90bd, 17953), (0x90be, 17200), (0x90bf, 17954), (0x90c0, 17955), (0x90c1, 15759), (0x90c2, 18050),
+    (0x90c3, 18051), (0x90c4, 17202), (0x90c5, 17199), (0x90c6, 18052), (0x90c7, 17203), (0x90c8, 18053), (0x90c9, 18054), (0x90ca, 11523),
+    (0x90cb, 18055), (0x90cc, 18056), (0x90cd, 18057), (0x90ce, 12106), (0x90cf, 17198), (0x90d0, 17201), (0x90d1, 16248), (0x90d2, 18058),
+    (0x90d3, 17204), (0x90d4, 18059), (0x90d5, 18060), (0x90d6, 18061), (0x90d7, 17208), (0x90d8, 18062), (0x90d9, 18063), (0x90da, 18064),
+    (0x90db, 17209), (0x90dc, 17207), (0x90dd, 10959), (0x90de, 18065), (0x90df, 18066), (0x90e0, 18067), (0x90e1, 11879), (0x90e2, 17206),
+    (0x90e3, 18068), (0x90e4, 18069), (0x90e5, 18070), (0x90e6, 17205), (0x90e7, 15904), (0x90e8, 9436), (0x90e9, 18071), (0x90ea, 18072),
+    (0x90eb, 17210), (0x90ec, 18073), (0x90ed, 10824), (0x90ee, 18074), (0x90ef, 17211), (0x90f0, 18075), (0x90f1, 18076), (0x90f2, 18077),
+    (0x90f3, 18078), (0x90f4, 9622), (0x90f5, 18079), (0x90f6, 18080), (0x90f7, 18081), (0x90f8, 9981), (0x90f9, 18082), (0x90fa, 18083),
+    (0x90fb, 18084), (0x90fc, 18085), (0x90fd, 10193), (0x90fe, 17212), (0x90ff, 18086), (0x9100, 18087), (0x9101, 18088), (0x9102, 10250),
+    (0x9103, 18089), (0x9104, 17213), (0x9105, 18090), (0x9106, 18091), (0x9107, 18092), (0x9108, 18093), (0x9109, 18094), (0x910a, 18095),
+    (0x910b, 18096), (0x910c, 18097), (0x910d, 18098), (0x910e, 18099), (0x910f, 18100), (0x9110, 18101), (0x9111, 18102), (0x9112, 18103),
+    (0x9113, 18104), (0x9114, 18105), (0x9115, 18106), (0x9116, 18107), (0x9117, 18108), (0x9118, 18109), (0x9119, 9256), (0x911a, 18110),
+    (0x911b, 18111), (0x911c, 18112), (0x911d, 18113), (0x911e, 17215), (0x911f, 18114), (0x9120, 18115), (0x9121, 18116), (0x9122, 17214),
+    (0x9123, 17216), (0x9124, 18117), (0x9125, 18118), (0x9126, 18119), (0x9127, 18120), (0x9128, 18121), (0x9129, 18122), (0x912a, 18123),
+    (0x912b, 18124), (0x912c, 18125), (0x912d, 18126), (0x912e, 18127), (0x912f, 17218), (0x9130, 18128), (0x9131, 17217), (0x9132, 18129),
+    (0x9133, 18130), (0x9134, 18131), (0x9135, 18132), (0x9136, 18133), (0x9137, 18134), (0x9138, 18135), (0x9139, 17219), (0x913a, 18136),
+    (0x913b, 18137), (0x913c, 18138), (0x913d, 18139), (0x913e, 18140), (0x913f, 18141), (0x9140, 18142), (0x9141, 18143), (0x9142, 18144),
+    (0x9143, 17220), (0x9144, 18145), (0x9145, 18240), (0x9146, 17221), (0x9147, 18241), (0x9148, 18242), (0x9149, 15722), (0x914a, 22035),
+    (0x914b, 13480), (0x914c, 16470), (0x914d, 13083), (0x914e, 22037), (0x914f, 22038), (0x9150, 22036), (0x9151, 18243), (0x9152, 11723),
+    (0x9153, 18244), (0x9154, 18245), (0x9155, 18246), (0x9156, 18247), (0x9157, 15184), (0x9158, 18248), (0x9159, 18249), (0x915a, 10406),
+    (0x915b, 18250), (0x915c, 18251), (0x915d, 15910), (0x915e, 14355), (0x915f, 18252), (0x9160, 18253), (0x9161, 22137), (0x9162, 22136),
+    (0x9163, 10933), (0x9164, 22039), (0x9165, 14209), (0x9166, 18254), (0x9167, 18255), (0x9168, 18256), (0x9169, 22139), (0x916a, 12115),
+    (0x916b, 18257), (0x916c, 9669), (0x916d, 18258), (0x916e, 14545), (0x916f, 22140), (0x9170, 22138), (0x9171, 11515), (0x9172, 22143),
+    (0x9173, 18259), (0x9174, 22144), (0x9175, 11540), (0x9176, 12659), (0x9177, 11940), (0x9178, 14220), (0x9179, 22145), (0x917a, 18260),
+    (0x917b, 18261), (0x917c, 18262), (0x917d, 22141), (0x917e, 22142), (0x917f, 12905), (0x9180, 18263), (0x9181, 18264), (0x9182, 18265),
+    (0x9183, 18266), (0x9184, 18267), (0x9185, 22147), (0x9186, 18268), (0x9187, 9813), (0x9188, 18269), (0x9189, 16512), (0x918a, 18270),
+    (0x918b, 9840), (0x918c, 22146), (0x918d, 22149), (0x918e, 18271), (0x918f, 18272), (0x9190, 22148), (0x9191, 22150), (0x9192, 15154),
+    (0x9193, 18273), (0x9194, 18274), (0x9195, 18275), (0x9196, 18276), (0x9197, 18277), (0x9198, 18278), (0x9199, 18279), (0x919a, 12684),
+    (0x919b, 13594), (0x919c, 18280), (0x919d, 18281), (0x919e, 18282), (0x919f, 18283), (0x91a0, 18284), (0x91a1, 18285), (0x91a2, 22151),
+    (0x91a3, 22152), (0x91a4, 18286), (0x91a5, 18287), (0x91a6, 18288), (0x91a7, 18289), (0x91a8, 18290), (0x91a9, 18291), (0x91aa, 22153),
+    (0x91ab, 18292), (0x91ac, 18293), (0x91ad, 22154), (0x91ae, 22155), (0x91af, 22156), (0x91b0, 18294), (0x91b1, 18295), (0x91b2, 18296),
+    (0x91b3, 18297), (0x91b4, 22158), (0x91b5, 22157), (0x91b6, 18298), (0x91b7, 18299), (0x91b8, 18300), (0x91b9, 18301), (0x91ba, 22159),
+    (0x91bb, 18302), (0x91bc, 18303), (0x91bd, 18304), (0x91be, 18305), (0x91bf, 18306), (0x91c0, 18307), (0x91c1, 18308), (0x91c2, 18309),
+    (0x91c3, 18310), (0x91c4, 18311), (0x91c5, 18312), (0x91c6, 18313), (0x91c7, 9446), (0x91c8, 18314), (0x91c9, 15727), (0x91ca, 14010),
+    (0x91cb, 18315), (0x91cc, 12144), (0x91cd, 16301), (0x91ce, 15501), (0x91cf, 12286), (0x91d0, 18316), (0x91d1, 11575), (0x91d2, 18317),
+    (0x91d3, 18318), (0x91d4, 18319), (0x91d5, 18320), (0x91d6, 18321), (0x91d7, 18322), (0x91d8, 18323), (0x91d9, 18324), (0x91da, 18325),
+    (0x91db, 18326), (0x91dc, 10555), (0x91dd, 18327), (0x91de, 18328), (0x91df, 18329), (0x91e0, 18330), (0x91e1, 18331), (0x91e2, 18332),
+    (0x91e3, 18333), (0x91e4, 18334), (0x91e5, 18335), (0x91e6, 18430), (0x91e7, 18431), (0x91e8, 18432), (0x91e9, 18433), (0x91ea, 18434),
+    (0x91eb, 18435), (0x91ec, 18436), (0x91ed, 18437), (0x91ee, 18438), (0x91ef, 18439), (0x91f0, 18440), (0x91f1, 18441), (0x91f2, 18442),
+    (0x91f3, 18443), (0x91f4, 18444), (0x91f5, 18445), (0x91f6, 18446), (0x91f7, 18447), (0x91f8, 18448), (0x91f9, 18449), (0x91fa, 18450),
+    (0x91fb, 18451), (0x91fc, 18452), (0x91fd, 18453), (0x91fe, 18454), (0x91ff, 18455), (0x9200, 18456), (0x9201, 18457), (0x9202, 18458),
+    (0x9203, 18459), (0x9204, 18460), (0x9205, 18461), (0x9206, 18462), (0x9207, 18463), (0x9208, 18464), (0x9209, 18465), (0x920a, 18466),
+    (0x920b, 18467), (0x920c, 18468), (0x920d, 18469), (0x920e, 18470), (0x920f, 18471), (0x9210, 18472), (0x9211, 18473), (0x9212, 18474),
+    (0x9213, 18475), (0x9214, 18476), (0x9215, 18477), (0x9216, 18478), (0x9217, 18479), (0x9218, 18480), (0x9219, 18481), (0x921a, 18482),
+    (0x921b, 18483), (0x921c, 18484), (0x921d, 18485), (0x921e, 18486), (0x921f, 18487), (0x9220, 18488), (0x9221, 18489), (0x9222, 18490),
+    (0x9223, 18491), (0x9224, 18492), (0x9225, 18493), (0x9226, 18494), (0x9227, 18495), (0x9228, 18496), (0x9229, 18497), (0x922a, 18498),
+    (0x922b, 18499), (0x922c, 18500), (0x922d, 18501), (0x922e, 18502), (0x922f, 18503), (0x9230, 18504), (0x9231, 18505), (0x9232, 18506),
+    (0x9233, 18507), (0x9234, 18508), (0x9235, 18509), (0x9236, 18510), (0x9237, 18511), (0x9238, 18512), (0x9239, 18513), (0x923a, 18514),
+    (0x923b, 18515), (0x923c, 18516), (0x923d, 18517), (0x923e, 18518), (0x923f, 18519), (0x9240, 18520), (0x9241, 18521), (0x9242, 18522),
+    (0x9243, 18523), (0x9244, 18524), (0x9245, 18525), (0x9246, 18620), (0x9247, 18621), (0x9248, 18622), (0x9249, 18623), (0x924a, 18624),
+    (0x924b, 18625), (0x924c, 18626), (0x924d, 18627), (0x924e, 18628), (0x924f, 18629), (0x9250, 18630), (0x9251, 18631), (0x9252, 18632),
+    (0x9253, 18633), (0x9254, 18634), (0x9255, 18635), (0x9256, 18636), (0x9257, 18637), (0x9258, 18638), (0x9259, 18639), (0x925a, 18640),
+    (0x925b, 18641), (0x925c, 18642), (0x925d, 18643), (0x925e, 18644), (0x925f, 18645), (0x9260, 18646), (0x9261, 18647), (0x9262, 18648),
+    (0x9263, 18649), (0x9264, 18650), (0x9265, 18651), (0x9266, 18652), (0x9267, 18653), (0x9268, 18654), (0x9269, 18655), (0x926a, 18656),
+    (0x926b, 18657), (0x926c, 18658), (0x926d, 18659), (0x926e, 18660), (0x926f, 18661), (0x9270, 18662), (0x9271, 18663), (0x9272, 18664),
+    (0x9273, 18665), (0x9274, 11393), (0x9275, 18666), (0x9276, 18667), (0x9277, 18668), (0x9278, 18669), (0x9279, 18670), (0x927a, 18671),
+    (0x927b, 18672), (0x927c, 18673), (0x927d, 18674), (0x927e, 18675), (0x927f, 18676), (0x9280, 18677), (0x9281, 18678), (0x9282, 18679),
+    (0x9283, 18680), (0x9284, 18681), (0x9285, 18682), (0x9286, 18683), (0x9287, 18684), (0x9288, 18685), (0x9289, 18686), (0x928a, 18687),
+    (0x928b, 18688), (0x928c, 18689), (0x928d, 18690), (0x928e, 22363), (0x928f, 18691), (0x9290, 18692), (0x9291, 18693), (0x9292, 18694),
+    (0x9293, 18695), (0x9294, 18696), (0x9295, 18697), (0x9296, 18698), (0x9297, 18699), (0x9298, 18700), (0x9299, 18701), (0x929a, 18702),
+    (0x929b, 18703), (0x929c, 18704), (0x929d, 18705), (0x929e, 18706), (0x929f, 18707), (0x92a0, 18708), (0x92a1, 18709), (0x92a2, 18710),
+    (0x92a3, 18711), (0x92a4, 18712), (0x92a5, 18713), (0x92a6, 18714), (0x92a7, 18715), (0x92a8, 18810), (0x92a9, 18811), (0x92aa, 18812),
+    (0x92ab, 18813), (0x92ac, 18814), (0x92ad, 18815), (0x92ae, 22364), (0x92af, 18816), (0x92b0, 18817), (0x92b1, 18818), (0x92b2, 18819),
+    (0x92b3, 18820), (0x92b4, 18821), (0x92b5, 18822), (0x92b6, 18823), (0x92b7, 18824), (0x92b8, 18825), (0x92b9, 18826), (0x92ba, 18827),
+    (0x92bb, 18828), (0x92bc, 18829), (0x92bd, 18830), (0x92be, 18831), (0x92bf, 18832), (0x92c0, 18833), (0x92c1, 18834), (0x92c2, 18835),
+    (0x92c3, 18836), (0x92c4, 18837), (0x92c5, 18838), (0x92c6, 18839), (0x92c7, 18840), (0x92c8, 22365), (0x92c9, 18841), (0x92ca, 18842),
+    (0x92cb, 18843), (0x92cc, 18844), (0x92cd, 18845), (0x92ce, 18846), (0x92cf, 18847), (0x92d0, 18848), (0x92d1, 18849), (0x92d2, 18850),
+    (0x92d3, 18851), (0x92d4, 18852), (0x92d5, 18853), (0x92d6, 18854), (0x92d7, 18855), (0x92d8, 18856), (0x92d9, 18857), (0x92da, 18858),
+    (0x92db, 18859), (0x92dc, 18860), (0x92dd, 18861), (0x92de, 18862), (0x92df, 18863), (0x92e0, 18864), (0x92e1, 18865), (0x92e2, 18866),
+    (0x92e3, 18867), (0x92e4, 18868), (0x92e5, 18869), (0x92e6, 18870), (0x92e7, 18871), (0x92e8, 18872), (0x92e9, 18873), (0x92ea, 18874),
+    (0x92eb, 18875), (0x92ec, 18876), (0x92ed, 18877), (0x92ee, 18878), (0x92ef, 18879), (0x92f0, 18880), (0x92f1, 18881), (0x92f2, 18882),
+    (0x92f3, 18883), (0x92f4, 18884), (0x92f5, 18885), (0x92f6, 18886), (0x92f7, 18887), (0x92f8, 18888), (0x92f9, 18889), (0x92fa, 18890),
+    (0x92fb, 18891), (0x92fc, 18892), (0x92fd, 18893), (0x92fe, 18894), (0x92ff, 18895), (0x9300, 18896), (0x9301, 18897), (0x9302, 18898),
+    (0x9303, 18899), (0x9304, 18900), (0x9305, 18901), (0x9306, 18902), (0x9307, 18903), (0x9308, 18904), (0x9309, 18905), (0x930a, 19000),
+    (0x930b, 19001), (0x930c, 19002), (0x930d, 19003), (0x930e, 19004), (0x930f, 19005), (0x9310, 19006), (0x9311, 19007), (0x9312, 19008),
+    (0x9313, 19009), (0x9314, 19010), (0x9315, 19011), (0x9316, 19012), (0x9317, 19013), (0x9318, 19014), (0x9319, 19015), (0x931a, 19016),
+    (0x931b, 19017), (0x931c, 19018), (0x931d, 19019), (0x931e, 19020), (0x931f, 19021), (0x9320, 19022), (0x9321, 19023), (0x9322, 19024),
+    (0x9323, 19025), (0x9324, 19026), (0x9325, 19027), (0x9326, 19028), (0x9327, 19029), (0x9328, 19030), (0x9329, 19031), (0x932a, 19032),
+    (0x932b, 19033), (0x932c, 19034), (0x932d, 19035), (0x932e, 19036), (0x932f, 19037), (0x9330, 19038), (0x9331, 19039), (0x9332, 19040),
+    (0x9333, 19041), (0x9334, 19042), (0x9335, 19043), (0x9336, 19044), (0x9337, 19045), (0x9338, 19046), (0x9339, 19047), (0x933a, 19048),
+    (0x933b, 19049), (0x933c, 19050), (0x933d, 19051), (0x933e, 22366), (0x933f, 19052), (0x9340, 19053), (0x9341, 19054), (0x9342, 19055),
+    (0x9343, 19056), (0x9344, 19057), (0x9345, 19058), (0x9346, 19059), (0x9347, 19060), (0x9348, 19061), (0x9349, 19062), (0x934a, 19063),
+    (0x934b, 19064), (0x934c, 19065), (0x934d, 19066), (0x934e, 19067), (0x934f, 19068), (0x9350, 19069), (0x9351, 19070), (0x9352, 19071),
+    (0x9353, 19072), (0x9354, 19073), (0x9355, 19074), (0x9356, 19075), (0x9357, 19076), (0x9358, 19077), (0x9359, 19078), (0x935a, 19079),
+    (0x935b, 19080), (0x935c, 19081), (0x935d, 19082), (0x935e, 19083), (0x935f, 19084), (0x9360, 19085), (0x9361, 19086), (0x9362, 19087),
+    (0x9363, 19088), (0x9364, 19089), (0x9365, 19090), (0x9366, 19091), (0x9367, 19092), (0x9368, 19093), (0x9369, 19094), (0x936a, 22367),
+    (0x936b, 19095), (0x936c, 19190), (0x936d, 19191), (0x936e, 19192), (0x936f, 19193), (0x9370, 19194), (0x9371, 19195), (0x9372, 19196),
+    (0x9373, 19197), (0x9374, 19198), (0x9375, 19199), (0x9376, 19200), (0x9377, 19201), (0x9378, 19202), (0x9379, 19203), (0x937a, 19204),
+    (0x937b, 19205), (0x937c, 19206), (0x937d, 19207), (0x937e, 19208), (0x937f, 19209), (0x9380, 19210), (0x9381, 19211), (0x9382, 19212),
+    (0x9383, 19213), (0x9384, 19214), (0x9385, 19215), (0x9386, 19216), (0x9387, 19217), (0x9388, 19218), (0x9389, 19219), (0x938a, 19220),
+    (0x938b, 19221), (0x938c, 19222), (0x938d, 19223), (0x938e, 19224), (0x938f, 22369), (0x9390, 19225), (0x9391, 19226), (0x9392, 19227),
+    (0x9393, 19228), (0x9394, 19229), (0x9395, 19230), (0x9396, 19231), (0x9397, 19232), (0x9398, 19233), (0x9399, 19234), (0x939a, 19235),
+    (0x939b, 19236), (0x939c, 19237), (0x939d, 19238), (0x939e, 19239), (0x939f, 19240), (0x93a0, 19241), (0x93a1, 19242), (0x93a2, 19243),
+    (0x93a3, 19244), (0x93a4, 19245), (0x93a5, 19246), (0x93a6, 19247), (0x93a7, 19248), (0x93a8, 19249), (0x93a9, 19250), (0x93aa, 19251),
+    (0x93ab, 19252), (0x93ac, 19253), (0x93ad, 19254), (0x93ae, 19255), (0x93af, 19256), (0x93b0, 19257), (0x93b1, 19258), (0x93b2, 19259),
+    (0x93b3, 19260), (0x93b4, 19261), (0x93b5, 19262), (0x93b6, 19263), (0x93b7, 19264), (0x93b8, 19265), (0x93b9, 19266), (0x93ba, 19267),
+    (0x93bb, 19268), (0x93bc, 19269), (0x93bd, 19270), (0x93be, 19271), (0x93bf, 19272), (0x93c0, 19273), (0x93c1, 19274), (0x93c2, 19275),
+    (0x93c3, 19276), (0x93c4, 19277), (0x93c5, 19278), (0x93c6, 19279), (0x93c7, 19280), (0x93c8, 19281), (0x93c9, 19282), (0x93ca, 22368),
+    (0x93cb, 19283), (0x93cc, 19284), (0x93cd, 19285), (0x93ce, 19380), (0x93cf, 19381), (0x93d0, 19382), (0x93d1, 19383), (0x93d2, 19384),
+    (0x93d3, 19385), (0x93d4, 19386), (0x93d5, 19387), (0x93d6, 22588), (0x93d7, 19388), (0x93d8, 19389), (0x93d9, 19390), (0x93da, 19391),
+    (0x93db, 19392), (0x93dc, 19393), (0x93dd, 19394), (0x93de, 19395), (0x93df, 19396), (0x93e0, 19397), (0x93e1, 19398), (0x93e2, 19399),
+    (0x93e3, 19400), (0x93e4, 19401), (0x93e5, 19402), (0x93e6, 19403), (0x93e7, 19404), (0x93e8, 19405), (0x93e9, 19406), (0x93ea, 19407),
+    (0x93eb, 19408), (0x93ec, 19409), (0x93ed, 19410), (0x93ee, 19411), (0x93ef, 19412), (0x93f0, 19413), (0x93f1, 19414), (0x93f2, 19415),
+    (0x93f3, 19416), (0x93f4, 19417), (0x93f5, 19418), (0x93f6, 19419), (0x93f7, 19420), (0x93f8, 19421), (0x93f9, 19422), (0x93fa, 19423),
+    (0x93fb, 19424), (0x93fc, 19425), (0x93fd, 19426), (0x93fe, 19427), (0x93ff, 19428), (0x9400, 19429), (0x9401, 19430), (0x9402, 19431),
+    (0x9403, 19432), (0x9404, 19433), (0x9405, 19434), (0x9406, 19435), (0x9407, 19436), (0x9408, 19437), (0x9409, 19438), (0x940a, 19439),
+    (0x940b, 19440), (0x940c, 19441), (0x940d, 19442), (0x940e, 19443), (0x940f, 19444), (0x9410, 19445), (0x9411, 19446), (0x9412, 19447),
+    (0x9413, 19448), (0x9414, 19449), (0x9415, 19450), (0x9416, 19451), (0x9417, 19452), (0x9418, 19453), (0x9419, 19454), (0x941a, 19455),
+    (0x941b, 19456), (0x941c, 19457), (0x941d, 19458), (0x941e, 19459), (0x941f, 19460), (0x9420, 19461), (0x9421, 19462), (0x9422, 19463),
+    (0x9423, 19464), (0x9424, 19465), (0x9425, 19466), (0x9426, 19467), (0x9427, 19468), (0x9428, 19469), (0x9429, 19470), (0x942a, 19471),
+    (0x942b, 19472), (0x942c, 19473), (0x942d, 19474), (0x942e, 19475), (0x942f, 19570), (0x9430, 19571), (0x9431, 19572), (0x9432, 19573),
+    (0x9433, 19574), (0x9434, 19575), (0x9435, 19576), (0x9436, 19577), (0x9437, 19578), (0x9438, 19579), (0x9439, 19580), (0x943a, 19581),
+    (0x943b, 19582), (0x943c, 19583), (0x943d, 19584), (0x943e, 22370), (0x943f, 19585), (0x9440, 19586), (0x9441, 19587), (0x9442, 19588),
+    (0x9443, 19589), (0x9444, 19590), (0x9445, 19591), (0x9446, 19592), (0x9447, 19593), (0x9448, 19594), (0x9449, 19595), (0x944a, 19596),
+    (0x944b, 19597), (0x944c, 19598), (0x944d, 19599), (0x944e, 19600), (0x944f, 19601), (0x9450, 19602), (0x9451, 19603), (0x9452, 19604),
+    (0x9453, 19605), (0x9454, 19606), (0x9455, 19607), (0x9456, 19608), (0x9457, 19609), (0x9458, 19610), (0x9459, 19611), (0x945a, 19612),
+    (0x945b, 19613), (0x945c, 19614), (0x945d, 19615), (0x945e, 19616), (0x945f, 19617), (0x9460, 19618), (0x9461, 19619), (0x9462, 19620),
+    (0x9463, 19621), (0x9464, 19622), (0x9465, 19623), (0x9466, 19624), (0x9467, 19625), (0x9468, 19626), (0x9469, 19627), (0x946a, 19628),
+    (0x946b, 22371), (0x946c, 19629), (0x946d, 19630), (0x946e, 19631), (0x946f, 19632), (0x9470, 19633), (0x9471, 19634), (0x9472, 19635),
+    (0x9473, 19636), (0x9474, 19637), (0x9475, 19638), (0x9476, 19639), (0x9477, 19640), (0x9478, 19641), (0x9479, 19642), (0x947a, 19643),
+    (0x947b, 19644), (0x947c, 19645), (0x947d, 19646), (0x947e, 19647), (0x947f, 19648), (0x9480, 19649), (0x9481, 19650), (0x9482, 19651),
+    (0x9483, 19652), (0x9484, 19653), (0x9485, 20841), (0x9486, 20842), (0x9487, 20843), (0x9488, 16130), (0x9489, 10169), (0x948a, 20845),
+    (0x948b, 20844), (0x948c, 20846), (0x948d, 20847), (0x948e, 13400), (0x948f, 20848), (0x9490, 20849), (0x9491, 19654), (0x9492, 10371),
+    (0x9493, 10061), (0x9494, 20850), (0x9495, 20852), (0x9496, 19655), (0x9497, 20851), (0x9498, 19656), (0x9499, 10583), (0x949a, 20853),
+    (0x949b, 20854), (0x949c, 20855), (0x949d, 10224), (0x949e, 9609), (0x949f, 16296), (0x94a0, 12863), (0x94a1, 9236), (0x94a2, 10599),
+    (0x94a3, 20856), (0x94a4, 20857), (0x94a5, 15896), (0x94a6, 13448), (0x94a7, 11776), (0x94a8, 14782), (0x94a9, 10754), (0x94aa, 20859),
+    (0x94ab, 20858), (0x94ac, 20861), (0x94ad, 20860), (0x94ae, 13020), (0x94af, 20862), (0x94b0, 20863), (0x94b1, 13409), (0x94b2, 20864),
+    (0x94b3, 13410), (0x94b4, 20865), (0x94b5, 9412), (0x94b6, 20866), (0x94b7, 20867), (0x94b8, 20868), (0x94b9, 20869), (0x94ba, 20870),
+    (0x94bb, 16509), (0x94bc, 20871), (0x94bd, 20872), (0x94be, 11361), (0x94bf, 20873), (0x94c0, 15718), (0x94c1, 14435), (0x94c2, 9417),
+    (0x94c3, 12324), (0x94c4, 20874), (0x94c5, 13401), (0x94c6, 12648), (0x94c7, 19657), (0x94c8, 20875), (0x94c9, 20876), (0x94ca, 20877),
+    (0x94cb, 20878), (0x94cc, 20879), (0x94cd, 20880), (0x94ce, 20881), (0x94cf, 19658), (0x94d0, 20882), (0x94d1, 20883), (0x94d2, 20884),
+    (0x94d3, 19659), (0x94d4, 19660), (0x94d5, 20885), (0x94d6, 20886), (0x94d7, 20887), (0x94d8, 20889), (0x94d9, 20888), (0x94da, 19661),
+    (0x94db, 20890), (0x94dc, 14548), (0x94dd, 12478), (0x94de, 20891), (0x94df, 20892), (0x94e0, 20893), (0x94e1, 16056), (0x94e2, 20894),
+    (0x94e3, 14934), (0x94e4, 20895), (0x94e5, 20896), (0x94e6, 19662), (0x94e7, 20897), (0x94e8, 20898), (0x94e9, 20996), (0x94ea, 20899),
+    (0x94eb, 20997), (0x94ec, 10630), (0x94ed, 12725), (0x94ee, 20998), (0x94ef, 20999), (0x94f0, 11529), (0x94f1, 15516), (0x94f2, 9494),
+    (0x94f3, 21000), (0x94f4, 21001), (0x94f5, 21002), (0x94f6, 15573), (0x94f7, 21003), (0x94f8, 16338), (0x94f9, 21004), (0x94fa, 13249),
+    (0x94fb, 19663), (0x94fc, 21005), (0x94fd, 21006), (0x94fe, 12275), (0x94ff, 21007), (0x9500, 15005), (0x9501, 14243), (0x9502, 21009),
+    (0x9503, 21008), (0x9504, 9685), (0x9505, 10823), (0x9506, 21010), (0x9507, 21011), (0x9508, 15171), (0x9509, 21012), (0x950a, 21013),
+    (0x950b, 10425), (0x950c, 15136), (0x950d, 21014), (0x950e, 21015), (0x950f, 21016), (0x9510, 13666), (0x9511, 14409), (0x9512, 21017),
+    (0x9513, 21018), (0x9514, 21019), (0x9515, 21020), (0x9516, 21021), (0x9517, 16119), (0x9518, 21022), (0x9519, 9862), (0x951a, 12645),
+    (0x951b, 21023), (0x951c, 19664), (0x951d, 21024), (0x951e, 21025), (0x951f, 21026), (0x9520, 19665), (0x9521, 14818), (0x9522, 21027),
+    (0x9523, 12509), (0x9524, 9809), (0x9525, 16457), (0x9526, 11580), (0x9527, 19760), (0x9528, 14954), (0x9529, 21030), (0x952a, 21028),
+    (0x952b, 21029), (0x952c, 21031), (0x952d, 10172), (0x952e, 11397), (0x952f, 11751), (0x9530, 12679), (0x9531, 21032), (0x9532, 21033),
+    (0x9533, 19761), (0x9534, 21034), (0x9535, 21042), (0x9536, 21035), (0x9537, 21036), (0x9538, 21037), (0x9539, 13429), (0x953a, 21076),
+    (0x953b, 10210), (0x953c, 21038), (0x953d, 19762), (0x953e, 21039), (0x953f, 21040), (0x9540, 10203), (0x9541, 12665), (0x9542, 21041),
+    (0x9543, 19763), (0x9544, 21043), (0x9545, 21044), (0x9546, 21045), (0x9547, 16137), (0x9548, 19764), (0x9549, 21046), (0x954a, 12912),
+    (0x954b, 19765), (0x954c, 21047), (0x954d, 12913), (0x954e, 21048), (0x954f, 21049), (0x9550, 10613), (0x9551, 9112), (0x9552, 21050),
+    (0x9553, 21051), (0x9554, 21052), (0x9555, 19766), (0x9556, 21053), (0x9557, 21054), (0x9558, 21055), (0x9559, 21056), (0x955a, 19767),
+    (0x955b, 21057), (0x955c, 11706), (0x955d, 21060), (0x955e, 21058), (0x955f, 21059), (0x9560, 19768), (0x9561, 21061), (0x9562, 21062),
+    (0x9563, 12300), (0x9564, 21063), (0x9565, 21064), (0x9566, 21065), (0x9567, 21066), (0x9568, 21067), (0x9569, 21068), (0x956a, 21069),
+    (0x956b, 21070), (0x956c, 21071), (0x956d, 12121), (0x956e, 19769), (0x956f, 21072), (0x9570, 12268), (0x9571, 21073), (0x9572, 21074),
+    (0x9573, 21075), (0x9574, 19770), (0x9575, 19771), (0x9576, 14981), (0x9577, 19772), (0x9578, 19773), (0x9579, 19774), (0x957a, 19775),
+    (0x957b, 19776), (0x957c, 19777), (0x957d, 19778), (0x957e, 19779), (0x957f, 9599), (0x9580, 19780), (0x9581, 19781), (0x9582, 19782),
+    (0x9583, 19783), (0x9584, 19784), (0x9585, 19785), (0x9586, 19786), (0x9587, 19787), (0x9588, 19788), (0x9589, 19789), (0x958a, 19790),
+    (0x958b, 19791), (0x958c, 19792), (0x958d, 19793), (0x958e, 19794), (0x958f, 19795), (0x9590, 19796), (0x9591, 19797), (0x9592, 19798),
+    (0x9593, 19799), (0x9594, 19800), (0x9595, 19801), (0x9596, 19802), (0x9597, 19803), (0x9598, 19804), (0x9599, 19805), (0x959a, 19806),
+    (0x959b, 19807), (0x959c, 19808), (0x959d, 19809), (0x959e, 19810), (0x959f, 19811), (0x95a0, 19812), (0x95a1, 19813), (0x95a2, 19814),
+    (0x95a3, 19815), (0x95a4, 19816), (0x95a5, 19817), (0x95a6, 19818), (0x95a7, 19819), (0x95a8, 19820), (0x95a9, 19821), (0x95aa, 19822),
+    (0x95ab, 19823), (0x95ac, 19824), (0x95ad, 19825), (0x95ae, 19826), (0x95af, 19827), (0x95b0, 19828), (0x95b1, 19829), (0x95b2, 19830),
+    (0x95b3, 19831), (0x95b4, 19832), (0x95b5, 19833), (0x95b6, 19834), (0x95b7, 19835), (0x95b8, 19836), (0x95b9, 19837), (0x95ba, 19838),
+    (0x95bb, 19839), (0x95bc, 19840), (0x95bd, 19841), (0x95be, 19842), (0x95bf, 19843), (0x95c0, 19844), (0x95c1, 19845), (0x95c2, 19846),
+    (0x95c3, 19847), (0x95c4, 19848), (0x95c5, 19849), (0x95c6, 19850), (0x95c7, 19851), (0x95c8, 19852), (0x95c9, 19853), (0x95ca, 19854),
+    (0x95cb, 19855), (0x95cc, 19950), (0x95cd, 19951), (0x95ce, 19952), (0x95cf, 19953), (0x95d0, 19954), (0x95d1, 19955), (0x95d2, 19956),
+    (0x95d3, 19957), (0x95d4, 19958), (0x95d5, 19959), (0x95d6, 19960), (0x95d7, 19961), (0x95d8, 19962), (0x95d9, 19963), (0x95da, 19964),
+    (0x95db, 19965), (0x95dc, 19966), (0x95dd, 19967), (0x95de, 19968), (0x95df, 19969), (0x95e0, 19970), (0x95e1, 19971), (0x95e2, 19972),
+    (0x95e3, 19973), (0x95e4, 19974), (0x95e5, 19975), (0x95e6, 19976), (0x95e7, 19977), (0x95e8, 12672), (0x95e9, 18752), (0x95ea, 13808),
+    (0x95eb, 18753), (0x95ec, 19978), (0x95ed, 9268), (0x95ee, 14767), (0x95ef, 9804), (0x95f0, 13667), (0x95f1, 18754), (0x95f2, 14963),
+    (0x95f3, 18755), (0x95f4, 11373), (0x95f5, 18756), (0x95f6, 18757), (0x95f7, 12673), (0x95f8, 16057), (0x95f9, 12879), (0x95fa, 10810),
+    (0x95fb, 14762), (0x95fc, 18758), (0x95fd, 12721), (0x95fe, 18759), (0x95ff, 19979), (0x9600, 10362), (0x9601, 10628), (0x9602, 10975),
+    (0x9603, 18760), (0x9604, 18761), (0x9605, 15901), (0x9606, 18762), (0x9607, 19980), (0x9608, 18763), (0x9609, 15338), (0x960a, 18764),
+    (0x960b, 18765), (0x960c, 18766), (0x960d, 18767), (0x960e, 15349), (0x960f, 18768), (0x9610, 9496), (0x9611, 12092), (0x9612, 18769),
+    (0x9613, 19981), (0x9614, 12076), (0x9615, 18770), (0x9616, 18771), (0x9617, 18772), (0x9618, 19982), (0x9619, 18773), (0x961a, 18774),
+    (0x961b, 19983), (0x961c, 10568), (0x961d, 17071), (0x961e, 19984), (0x961f, 10216), (0x9620, 19985), (0x9621, 17073), (0x9622, 17072),
+    (0x9623, 19986), (0x9624, 19987), (0x9625, 19988), (0x9626, 19989), (0x9627, 19990), (0x9628, 19991), (0x9629, 19992), (0x962a, 17075),
+    (0x962b, 19993), (0x962c, 19994), (0x962d, 19995), (0x962e, 13663), (0x962f, 19996), (0x9630, 19997), (0x9631, 17074), (0x9632, 10387),
+    (0x9633, 15379), (0x9634, 15570), (0x9635, 16138), (0x9636, 11550), (0x9637, 19998), (0x9638, 19999), (0x9639, 20000), (0x963a, 20001),
+    (0x963b, 16507), (0x963c, 17077), (0x963d, 17076), (0x963e, 20002), (0x963f, 9027), (0x9640, 14586), (0x9641, 20003), (0x9642, 17078),
+    (0x9643, 20004), (0x9644, 10574), (0x9645, 11347), (0x9646, 12474), (0x9647, 12449), (0x9648, 9629), (0x9649, 17079), (0x964a, 20005),
+    (0x964b, 12455), (0x964c, 12841), (0x964d, 11516), (0x964e, 20006), (0x964f, 20007), (0x9650, 14977), (0x9651, 20008), (0x9652, 20009),
+    (0x9653, 20010), (0x9654, 17080), (0x9655, 13809), (0x9656, 20011), (0x9657, 20012), (0x9658, 20013), (0x9659, 20014), (0x965a, 20015),
+    (0x965b, 9276), (0x965c, 20016), (0x965d, 20017), (0x965e, 20018), (0x965f, 17081), (0x9660, 20019), (0x9661, 10189), (0x9662, 15891),
+    (0x9663, 20020), (0x9664, 9688), (0x9665, 20021), (0x9666, 20022), (0x9667, 17082), (0x9668, 15906), (0x9669, 14968), (0x966a, 13082),
+    (0x966b, 20023), (0x966c, 17083), (0x966d, 20024), (0x966e, 20025), (0x966f, 20026), (0x9670, 20027), (0x9671, 20028), (0x9672, 17084),
+    (0x9673, 20029), (0x9674, 17085), (0x9675, 12329), (0x9676, 14398), (0x9677, 14976), (0x9678, 20030), (0x9679, 20031), (0x967a, 20032),
+    (0x967b, 20033), (0x967c, 20034), (0x967d, 20035), (0x967e, 20036), (0x967f, 20037), (0x9680, 20038), (0x9681, 20039), (0x9682, 20040),
+    (0x9683, 20041), (0x9684, 20042), (0x9685, 15746), (0x9686, 12446), (0x9687, 20043), (0x9688, 17086), (0x9689, 20044), (0x968a, 20045),
+    (0x968b, 14224), (0x968c, 20140), (0x968d, 17087), (0x968e, 20141), (0x968f, 14225), (0x9690, 15579), (0x9691, 20142), (0x9692, 20143),
+    (0x9693, 20144), (0x9694, 10629), (0x9695, 20145), (0x9696, 20146), (0x9697, 17088), (0x9698, 9040), (0x9699, 14937), (0x969a, 20147),
+    (0x969b, 20148), (0x969c, 16102), (0x969d, 20149), (0x969e, 20150), (0x969f, 20151), (0x96a0, 20152), (0x96a1, 20153), (0x96a2, 20154),
+    (0x96a3, 20155), (0x96a4, 20156), (0x96a5, 20157), (0x96a6, 20158), (0x96a7, 14232), (0x96a8, 20159), (0x96a9, 20160), (0x96aa, 20161),
+    (0x96ab, 20162), (0x96ac, 20163), (0x96ad, 20164), (0x96ae, 20165), (0x96af, 20166), (0x96b0, 17089), (0x96b1, 20167), (0x96b2, 20168),
+    (0x96b3, 18751), (0x96b4, 20169), (0x96b5, 20170), (0x96b6, 12260), (0x96b7, 20171), (0x96b8, 20172), (0x96b9, 22356), (0x96ba, 20173),
+    (0x96bb, 20174), (0x96bc, 22357), (0x96bd, 22358), (0x96be, 12874), (0x96bf, 20175), (0x96c0, 13609), (0x96c1, 15362), (0x96c2, 20176),
+    (0x96c3, 20177), (0x96c4, 15164), (0x96c5, 15332), (0x96c6, 11320), (0x96c7, 10780), (0x96c8, 20178), (0x96c9, 21079), (0x96ca, 20179),
+    (0x96cb, 20180), (0x96cc, 9823), (0x96cd, 15701), (0x96ce, 22359), (0x96cf, 9686), (0x96d0, 20181), (0x96d1, 20182), (0x96d2, 22360),
+    (0x96d3, 20183), (0x96d4, 20184), (0x96d5, 10056), (0x96d6, 20185), (0x96d7, 20186), (0x96d8, 20187), (0x96d9, 20188), (0x96da, 20189),
+    (0x96db, 20190), (0x96dc, 20191), (0x96dd, 20192), (0x96de, 20193), (0x96df, 20194), (0x96e0, 22362), (0x96e1, 20195), (0x96e2, 20196),
+    (0x96e3, 20197), (0x96e4, 20198), (0x96e5, 20199), (0x96e6, 20200), (0x96e7, 20201), (0x96e8, 15749), (0x96e9, 22332), (0x96ea, 15304),
+    (0x96eb, 20202), (0x96ec, 20203), (0x96ed, 20204), (0x96ee, 20205), (0x96ef, 22334), (0x96f0, 20206), (0x96f1, 20207), (0x96f2, 20208),
+    (0x96f3, 22333), (0x96f4, 20209), (0x96f5, 20210), (0x96f6, 12322), (0x96f7, 12120), (0x96f8, 20211), (0x96f9, 9217), (0x96fa, 20212),
+    (0x96fb, 20213), (0x96fc, 20214), (0x96fd, 20215), (0x96fe, 14802), (0x96ff, 20216), (0x9700, 15177), (0x9701, 22336), (0x9702, 20217),
+    (0x9703, 20218), (0x9704, 15001), (0x9705, 20219), (0x9706, 22335), (0x9707, 16135), (0x9708, 22337), (0x9709, 12660), (0x970a, 20220),
+    (0x970b, 20221), (0x970c, 20222), (0x970d, 11199), (0x970e, 22339), (0x970f, 22338), (0x9710, 20223), (0x9711, 20224), (0x9712, 20225),
+    (0x9713, 12887), (0x9714, 20226), (0x9715, 20227), (0x9716, 12311), (0x9717, 20228), (0x9718, 20229), (0x9719, 20230), (0x971a, 20231),
+    (0x971b, 20232), (0x971c, 14165), (0x971d, 20233), (0x971e, 14943), (0x971f, 20234), (0x9720, 20235), (0x9721, 20330), (0x9722, 20331),
+    (0x9723, 20332), (0x9724, 20333), (0x9725, 20334), (0x9726, 20335), (0x9727, 20336), (0x9728, 20337), (0x9729, 20338), (0x972a, 22340),
+    (0x972b, 20339), (0x972c, 20340), (0x972d, 22341), (0x972e, 20341), (0x972f, 20342), (0x9730, 22342), (0x9731, 20343), (0x9732, 12467),
+    (0x9733, 20344), (0x9734, 20345), (0x9735, 20346), (0x9736, 20347), (0x9737, 20348), (0x9738, 9077), (0x9739, 13104), (0x973a, 20349),
+    (0x973b, 20350), (0x973c, 20351), (0x973d, 20352), (0x973e, 22343), (0x973f, 20353), (0x9740, 20354), (0x9741, 20355), (0x9742, 20356),
+    (0x9743, 20357), (0x9744, 20358), (0x9745, 20359), (0x9746, 20360), (0x9747, 20361), (0x9748, 20362), (0x9749, 20363), (0x974a, 20364),
+    (0x974b, 20365), (0x974c, 20366), (0x974d, 20367), (0x974e, 20368), (0x974f, 20369), (0x9750, 20370), (0x9751, 20371), (0x9752, 13459),
+    (0x9753, 22331), (0x9754, 20372), (0x9755, 20373), (0x9756, 11709), (0x9757, 20374), (0x9758, 20375), (0x9759, 11703), (0x975a, 20376),
+    (0x975b, 10044), (0x975c, 20377), (0x975d, 20378), (0x975e, 10394), (0x975f, 20379), (0x9760, 11906), (0x9761, 12685), (0x9762, 12705),
+    (0x9763, 20380), (0x9764, 20381), (0x9765, 16669), (0x9766, 20382), (0x9767, 20383), (0x9768, 20384), (0x9769, 10624), (0x976a, 20385),
+    (0x976b, 20386), (0x976c, 20387), (0x976d, 20388), (0x976e, 20389), (0x976f, 20390), (0x9770, 20391), (0x9771, 20392), (0x9772, 20393),
+    (0x9773, 11584), (0x9774, 15300), (0x9775, 20394), (0x9776, 9073), (0x9777, 20395), (0x9778, 20396), (0x9779, 20397), (0x977a, 20398),
+    (0x977b, 20399), (0x977c, 22531), (0x977d, 20400), (0x977e, 20401), (0x977f, 20402), (0x9780, 20403), (0x9781, 20404), (0x9782, 20405),
+    (0x9783, 20406), (0x9784, 20407), (0x9785, 22532), (0x9786, 20408), (0x9787, 20409), (0x9788, 20410), (0x9789, 20411), (0x978a, 20412),
+    (0x978b, 15117), (0x978c, 20413), (0x978d, 9041), (0x978e, 20414), (0x978f, 20415), (0x9790, 20416), (0x9791, 22533), (0x9792, 22534),
+    (0x9793, 20417), (0x9794, 22535), (0x9795, 20418), (0x9796, 20419), (0x9797, 20420), (0x9798, 13437), (0x9799, 20421), (0x979a, 20422),
+    (0x979b, 20423), (0x979c, 20424), (0x979d, 20425), (0x979e, 20520), (0x979f, 20521), (0x97a0, 11732), (0x97a1, 20522), (0x97a2, 20523),
+    (0x97a3, 22538), (0x97a4, 20524), (0x97a5, 20525), (0x97a6, 20526), (0x97a7, 20527), (0x97a8, 20528), (0x97a9, 20529), (0x97aa, 20530),
+    (0x97ab, 22537), (0x97ac, 20531), (0x97ad, 9277), (0x97ae, 20532), (0x97af, 22536), (0x97b0, 20533), (0x97b1, 20534), (0x97b2, 22539),
+    (0x97b3, 20535), (0x97b4, 22540), (0x97b5, 20536), (0x97b6, 20537), (0x97b7, 20538), (0x97b8, 20539), (0x97b9, 20540), (0x97ba, 20541),
+    (0x97bb, 20542), (0x97bc, 20543), (0x97bd, 20544), (0x97be, 20545), (0x97bf, 20546), (0x97c0, 20547), (0x97c1, 20548), (0x97c2, 20549),
+    (0x97c3, 20550), (0x97c4, 20551), (0x97c5, 20552), (0x97c6, 20553), (0x97c7, 20554), (0x97c8, 20555), (0x97c9, 20556), (0x97ca, 20557),
+    (0x97cb, 20558), (0x97cc, 20559), (0x97cd, 20560), (0x97ce, 20561), (0x97cf, 20562), (0x97d0, 20563), (0x97d1, 20564), (0x97d2, 20565),
+    (0x97d3, 20566), (0x97d4, 20567), (0x97d5, 20568), (0x97d6, 20569), (0x97d7, 20570), (0x97d8, 20571), (0x97d9, 20572), (0x97da, 20573),
+    (0x97db, 20574), (0x97dc, 20575), (0x97dd, 20576), (0x97de, 20577), (0x97df, 20578), (0x97e0, 20579), (0x97e1, 20580), (0x97e2, 20581),
+    (0x97e3, 20582), (0x97e4, 20583), (0x97e5, 20584), (0x97e6, 14729), (0x97e7, 13630), (0x97e8, 20585), (0x97e9, 10936), (0x97ea, 19689),
+    (0x97eb, 19690), (0x97ec, 19691), (0x97ed, 11719), (0x97ee, 20586), (0x97ef, 20587), (0x97f0, 20588), (0x97f1, 20589), (0x97f2, 20590),
+    (0x97f3, 15569), (0x97f4, 20591), (0x97f5, 15912), (0x97f6, 13831), (0x97f7, 20592), (0x97f8, 20593), (0x97f9, 20594), (0x97fa, 20595),
+    (0x97fb, 20596), (0x97fc, 20597), (0x97fd, 20598), (0x97fe, 20599), (0x97ff, 20600), (0x9800, 20601), (0x9801, 20602), (0x9802, 20603),
+    (0x9803, 20604), (0x9804, 20605), (0x9805, 20606), (0x9806, 20607), (0x9807, 20608), (0x9808, 20609), (0x9809, 20610), (0x980a, 20611),
+    (0x980b, 20612), (0x980c, 20613), (0x980d, 20614), (0x980e, 20615), (0x980f, 20710), (0x9810, 20711), (0x9811, 20712), (0x9812, 20713),
+    (0x9813, 20714), (0x9814, 20715), (0x9815, 20716), (0x9816, 20717), (0x9817, 20718), (0x9818, 20719), (0x9819, 20720), (0x981a, 20721),
+    (0x981b, 20722), (0x981c, 20723), (0x981d, 20724), (0x981e, 20725), (0x981f, 20726), (0x9820, 20727), (0x9821, 20728), (0x9822, 20729),
+    (0x9823, 20730), (0x9824, 20731), (0x9825, 20732), (0x9826, 20733), (0x9827, 20734), (0x9828, 20735), (0x9829, 20736), (0x982a, 20737),
+    (0x982b, 20738), (0x982c, 20739), (0x982d, 20740), (0x982e, 20741), (0x982f, 20742), (0x9830, 20743), (0x9831, 20744), (0x9832, 20745),
+    (0x9833, 20746), (0x9834, 20747), (0x9835, 20748), (0x9836, 20749), (0x9837, 20750), (0x9838, 20751), (0x9839, 20752), (0x983a, 20753),
+    (0x983b, 20754), (0x983c, 20755), (0x983d, 20756), (0x983e, 20757), (0x983f, 20758), (0x9840, 20759), (0x9841, 20760), (0x9842, 20761),
+    (0x9843, 20762), (0x9844, 20763), (0x9845, 20764), (0x9846, 20765), (0x9847, 20766), (0x9848, 20767), (0x9849, 20768), (0x984a, 20769),
+    (0x984b, 20770), (0x984c, 20771), (0x984d, 20772), (0x984e, 20773), (0x984f, 20774), (0x9850, 20775), (0x9851, 20776), (0x9852, 20777),
+    (0x9853, 20778), (0x9854, 20779), (0x9855, 20780), (0x9856, 20781), (0x9857, 20782), (0x9858, 20783), (0x9859, 20784), (0x985a, 20785),
+    (0x985b, 20786), (0x985c, 20787), (0x985d, 20788), (0x985e, 20789), (0x985f, 20790), (0x9860, 20791), (0x9861, 20792), (0x9862, 20793),
+    (0x9863, 20794), (0x9864, 20795), (0x9865, 20796), (0x9866, 20797), (0x9867, 20798), (0x9868, 20799), (0x9869, 20800), (0x986a, 20801),
+    (0x986b, 20802), (0x986c, 20803), (0x986d, 20804), (0x986e, 20805), (0x986f, 20900), (0x9870, 20901), (0x9871, 20902), (0x9872, 20903),
+    (0x9873, 20904), (0x9874, 20905), (0x9875, 15504), (0x9876, 10170), (0x9877, 13469), (0x9878, 21467), (0x9879, 14993), (0x987a, 14174),
+    (0x987b, 15180), (0x987c, 19554), (0x987d, 14606), (0x987e, 10778), (0x987f, 10222), (0x9880, 21468), (0x9881, 9093), (0x9882, 14199),
+    (0x9883, 21469), (0x9884, 15869), (0x9885, 12458), (0x9886, 12331), (0x9887, 13241), (0x9888, 11702), (0x9889, 21566), (0x988a, 11358),
+    (0x988b, 20906), (0x988c, 21567), (0x988d, 21568), (0x988e, 20907), (0x988f, 21569), (0x9890, 15520), (0x9891, 13226), (0x9892, 20908),
+    (0x9893, 14574), (0x9894, 21570), (0x9895, 20909), (0x9896, 15692), (0x9897, 11912), (0x9898, 14411), (0x9899, 20910), (0x989a, 21571),
+    (0x989b, 21572), (0x989c, 15348), (0x989d, 10243), (0x989e, 21573), (0x989f, 21574), (0x98a0, 10038), (0x98a1, 21575), (0x98a2, 21576),
+    (0x98a3, 20911), (0x98a4, 9497), (0x98a5, 21577), (0x98a6, 21578), (0x98a7, 13592), (0x98a8, 20912), (0x98a9, 20913), (0x98aa, 20914),
+    (0x98ab, 20915), (0x98ac, 20916), (0x98ad, 20917), (0x98ae, 20918), (0x98af, 20919), (0x98b0, 20920), (0x98b1, 20921), (0x98b2, 20922),
+    (0x98b3, 20923), (0x98b4, 20924), (0x98b5, 20925), (0x98b6, 20926), (0x98b7, 20927), (0x98b8, 20928), (0x98b9, 20929), (0x98ba, 20930),
+    (0x98bb, 20931), (0x98bc, 20932), (0x98bd, 20933), (0x98be, 20934), (0x98bf, 20935), (0x98c0, 20936), (0x98c1, 20937), (0x98c2, 20938),
+    (0x98c3, 20939), (0x98c4, 20940), (0x98c5, 20941), (0x98c6, 20942), (0x98c7, 20943), (0x98c8, 20944), (0x98c9, 20945), (0x98ca, 20946),
+    (0x98cb, 20947), (0x98cc, 20948), (0x98cd, 20949), (0x98ce, 10426), (0x98cf, 20950), (0x98d0, 20951), (0x98d1, 20434), (0x98d2, 20435),
+    (0x98d3, 20436), (0x98d4, 20952), (0x98d5, 20437), (0x98d6, 20953), (0x98d7, 20954), (0x98d8, 13219), (0x98d9, 20438), (0x98da, 20439),
+    (0x98db, 20955), (0x98dc, 20956), (0x98dd, 20957), (0x98de, 10396), (0x98df, 13984), (0x98e0, 20958), (0x98e1, 20959), (0x98e2, 20960),
+    (0x98e3, 20961), (0x98e4, 20962), (0x98e5, 20963), (0x98e6, 20964), (0x98e7, 18549), (0x98e8, 22562), (0x98e9, 20965), (0x98ea, 20966),
+    (0x98eb, 20967), (0x98ec, 20968), (0x98ed, 20969), (0x98ee, 20970), (0x98ef, 20971), (0x98f0, 20972), (0x98f1, 20973), (0x98f2, 20974),
+    (0x98f3, 20975), (0x98f4, 20976), (0x98f5, 20977), (0x98f6, 20978), (0x98f7, 20979), (0x98f8, 20980), (0x98f9, 20981), (0x98fa, 20982),
+    (0x98fb, 20983), (0x98fc, 20984), (0x98fd, 20985), (0x98fe, 20986), (0x98ff, 20987), (0x9900, 20988), (0x9901, 20989), (0x9902, 20990),
+    (0x9903, 20991), (0x9904, 20992), (0x9905, 20993), (0x9906, 20994), (0x9907, 20995), (0x9908, 21090), (0x9909, 21091), (0x990a, 21092),
+    (0x990b, 21093), (0x990c, 21094), (0x990d, 22563), (0x990e, 21095), (0x990f, 21096), (0x9910, 9450), (0x9911, 21097), (0x9912, 21098),
+    (0x9913, 21099), (0x9914, 21100), (0x9915, 21101), (0x9916, 21102), (0x9917, 21103), (0x9918, 21104), (0x9919, 21105), (0x991a, 21106),
+    (0x991b, 21107), (0x991c, 21108), (0x991d, 21109), (0x991e, 21110), (0x991f, 21111), (0x9920, 21112), (0x9921, 21113), (0x9922, 21114),
+    (0x9923, 21115), (0x9924, 21116), (0x9925, 21117), (0x9926, 21118), (0x9927, 21119), (0x9928, 21120), (0x9929, 21121), (0x992a, 21122),
+    (0x992b, 21123), (0x992c, 21124), (0x992d, 21125), (0x992e, 22564), (0x992f, 21126), (0x9930, 21127), (0x9931, 21128), (0x9932, 21129),
+    (0x9933, 21130), (0x9934, 21131), (0x9935, 21132), (0x9936, 21133), (0x9937, 21134), (0x9938, 21135), (0x9939, 21136), (0x993a, 21137),
+    (0x993b, 21138), (0x993c, 21139), (0x993d, 21140), (0x993e, 21141), (0x993f, 21142), (0x9940, 21143), (0x9941, 21144), (0x9942, 21145),
+    (0x9943, 21146), (0x9944, 21147), (0x9945, 21148), (0x9946, 21149), (0x9947, 21150), (0x9948, 21151), (0x9949, 21152), (0x994a, 21153),
+    (0x994b, 21154), (0x994c, 21155), (0x994d, 21156), (0x994e, 21157), (0x994f, 21158), (0x9950, 21159), (0x9951, 21160), (0x9952, 21161),
+    (0x9953, 21162), (0x9954, 22566), (0x9955, 22565), (0x9956, 21163), (0x9957, 21164), (0x9958, 21165), (0x9959, 21166), (0x995a, 21167),
+    (0x995b, 21168), (0x995c, 21169), (0x995d, 21170), (0x995e, 21171), (0x995f, 21172), (0x9960, 21173), (0x9961, 21174), (0x9962, 21175),
+    (0x9963, 18552), (0x9964, 21176), (0x9965, 11307), (0x9966, 21177), (0x9967, 18553), (0x9968, 18554), (0x9969, 18555), (0x996a, 18556),
+    (0x996b, 18557), (0x996c, 18558), (0x996d, 10380), (0x996e, 15576), (0x996f, 11499), (0x9970, 14011), (0x9971, 9220), (0x9972, 14194),
+    (0x9973, 21178), (0x9974, 18559), (0x9975, 10257), (0x9976, 13621), (0x9977, 18560), (0x9978, 21179), (0x9979, 21180), (0x997a, 11535),
+    (0x997b, 21181), (0x997c, 9308), (0x997d, 18561), (0x997e, 21182), (0x997f, 10251), (0x9980, 18562), (0x9981, 12882), (0x9982, 21183),
+    (0x9983, 21184), (0x9984, 18563), (0x9985, 14973), (0x9986, 10796), (0x9987, 18564), (0x9988, 12066), (0x9989, 21185), (0x998a, 18565),
+    (0x998b, 9491), (0x998c, 21280), (0x998d, 18566), (0x998e, 21281), (0x998f, 12338), (0x9990, 18567), (0x9991, 18568), (0x9992, 12533),
+    (0x9993, 18569), (0x9994, 18570), (0x9995, 18571), (0x9996, 14020), (0x9997, 16649), (0x9998, 16884), (0x9999, 14982), (0x999a, 21282),
+    (0x999b, 21283), (0x999c, 21284), (0x999d, 21285), (0x999e, 21286), (0x999f, 21287), (0x99a0, 21288), (0x99a1, 21289), (0x99a2, 21290),
+    (0x99a3, 21291), (0x99a4, 21292), (0x99a5, 21190), (0x99a6, 21293), (0x99a7, 21294), (0x99a8, 17401), (0x99a9, 21295), (0x99aa, 21296),
+    (0x99ab, 21297), (0x99ac, 21298), (0x99ad, 21299), (0x99ae, 21300), (0x99af, 21301), (0x99b0, 21302), (0x99b1, 21303), (0x99b2, 21304),
+    (0x99b3, 21305), (0x99b4, 21306), (0x99b5, 21307), (0x99b6, 21308), (0x99b7, 21309), (0x99b8, 21310), (0x99b9, 21311), (0x99ba, 21312),
+    (0x99bb, 21313), (0x99bc, 21314), (0x99bd, 21315), (0x99be, 21316), (0x99bf, 21317), (0x99c0, 21318), (0x99c1, 21319), (0x99c2, 21320),
+    (0x99c3, 21321), (0x99c4, 21322), (0x99c5, 21323), (0x99c6, 21324), (0x99c7, 21325), (0x99c8, 21326), (0x99c9, 21327), (0x99ca, 21328),
+    (0x99cb, 21329), (0x99cc, 21330), (0x99cd, 21331), (0x99ce, 21332), (0x99cf, 21333), (0x99d0, 21334), (0x99d1, 21335), (0x99d2, 21336),
+    (0x99d3, 21337), (0x99d4, 21338), (0x99d5, 21339), (0x99d6, 21340), (0x99d7, 21341), (0x99d8, 21342), (0x99d9, 21343), (0x99da, 21344),
+    (0x99db, 21345), (0x99dc, 21346), (0x99dd, 21347), (0x99de, 21348), (0x99df, 21349), (0x99e0, 21350), (0x99e1, 21351), (0x99e2, 21352),
+    (0x99e3, 21353), (0x99e4, 21354), (0x99e5, 21355), (0x99e6, 21356), (0x99e7, 21357), (0x99e8, 21358), (0x99e9, 21359), (0x99ea, 21360),
+    (0x99eb, 21361), (0x99ec, 21362), (0x99ed, 21363), (0x99ee, 21364), (0x99ef, 21365), (0x99f0, 21366), (0x99f1, 21367), (0x99f2, 21368),
+    (0x99f3, 21369), (0x99f4, 21370), (0x99f5, 21371), (0x99f6, 21372), (0x99f7, 21373), (0x99f8, 21374), (0x99f9, 21375), (0x99fa, 21470),
+    (0x99fb, 21471), (0x99fc, 21472), (0x99fd, 21473), (0x99fe, 21474), (0x99ff, 21475), (0x9a00, 21476), (0x9a01, 21477), (0x9a02, 21478),
+    (0x9a03, 21479), (0x9a04, 21480), (0x9a05, 21481), (0x9a06, 21482), (0x9a07, 21483), (0x9a08, 21484), (0x9a09, 21485), (0x9a0a, 21486),
+    (0x9a0b, 21487), (0x9a0c, 21488), (0x9a0d, 21489), (0x9a0e, 21490), (0x9a0f, 21491), (0x9a10, 21492), (0x9a11, 21493), (0x9a12, 21494),
+    (0x9a13, 21495), (0x9a14, 21496), (0x9a15, 21497), (0x9a16, 21498), (0x9a17, 21499), (0x9a18, 21500), (0x9a19, 21501), (0x9a1a, 21502),
+    (0x9a1b, 21503), (0x9a1c, 21504), (0x9a1d, 21505), (0x9a1e, 21506), (0x9a1f, 21507), (0x9a20, 21508), (0x9a21, 21509), (0x9a22, 21510),
+    (0x9a23, 21511), (0x9a24, 21512), (0x9a25, 21513), (0x9a26, 21514), (0x9a27, 21515), (0x9a28, 21516), (0x9a29, 21517), (0x9a2a, 21518),
+    (0x9a2b, 21519), (0x9a2c, 21520), (0x9a2d, 21521), (0x9a2e, 21522), (0x9a2f, 21523), (0x9a30, 21524), (0x9a31, 21525), (0x9a32, 21526),
+    (0x9a33, 21527), (0x9a34, 21528), (0x9a35, 21529), (0x9a36, 21530), (0x9a37, 21531), (0x9a38, 21532), (0x9a39, 21533), (0x9a3a, 21534),
+    (0x9a3b, 21535), (0x9a3c, 21536), (0x9a3d, 21537), (0x9a3e, 21538), (0x9a3f, 21539), (0x9a40, 21540), (0x9a41, 21541), (0x9a42, 21542),
+    (0x9a43, 21543), (0x9a44, 21544), (0x9a45, 21545), (0x9a46, 21546), (0x9a47, 21547), (0x9a48, 21548), (0x9a49, 21549), (0x9a4a, 21550),
+    (0x9a4b, 21551), (0x9a4c, 21552), (0x9a4d, 21553), (0x9a4e, 21554), (0x9a4f, 21555), (0x9a50, 21556), (0x9a51, 21557), (0x9a52, 21558),
+    (0x9a53, 21559), (0x9a54, 21560), (0x9a55, 21561), (0x9a56, 21562), (0x9a57, 21563), (0x9a58, 21564), (0x9a59, 21565), (0x9a5a, 21660),
+    (0x9a5b, 21661), (0x9a5c, 21662), (0x9a5d, 21663), (0x9a5e, 21664), (0x9a5f, 21665), (0x9a60, 21666), (0x9a61, 21667), (0x9a62, 21668),
+    (0x9a63, 21669), (0x9a64, 21670), (0x9a65, 21671), (0x9a66, 21672), (0x9a67, 21673), (0x9a68, 21674), (0x9a69, 21675), (0x9a6a, 21676),
+    (0x9a6b, 21677), (0x9a6c, 12522), (0x9a6d, 15871), (0x9a6e, 14587), (0x9a6f, 15312), (0x9a70, 9654), (0x9a71, 13488), (0x9a72, 21678),
+    (0x9a73, 9426), (0x9a74, 12476), (0x9a75, 19349), (0x9a76, 13992), (0x9a77, 19350), (0x9a78, 19351), (0x9a79, 11737), (0x9a7a, 19352),
+    (0x9a7b, 16439), (0x9a7c, 14588), (0x9a7d, 19354), (0x9a7e, 11366), (0x9a7f, 19353), (0x9a80, 19355), (0x9a81, 19356), (0x9a82, 12523),
+    (0x9a83, 21679), (0x9a84, 11525), (0x9a85, 19357), (0x9a86, 12515), (0x9a87, 10932), (0x9a88, 19358), (0x9a89, 21680), (0x9a8a, 19359),
+    (0x9a8b, 9645), (0x9a8c, 15368), (0x9a8d, 21681), (0x9a8e, 21682), (0x9a8f, 11880), (0x9a90, 19360), (0x9a91, 13284), (0x9a92, 19361),
+    (0x9a93, 19362), (0x9a94, 21683), (0x9a95, 21684), (0x9a96, 19363), (0x9a97, 13218), (0x9a98, 19364), (0x9a99, 21685), (0x9a9a, 13782),
+    (0x9a9b, 19365), (0x9a9c, 19366), (0x9a9d, 19367), (0x9a9e, 19120), (0x9a9f, 19368), (0x9aa0, 19369), (0x9aa1, 12511), (0x9aa2, 19370),
+    (0x9aa3, 19371), (0x9aa4, 16317), (0x9aa5, 19372), (0x9aa6, 21686), (0x9aa7, 19373), (0x9aa8, 10774), (0x9aa9, 21687), (0x9aaa, 21688),
+    (0x9aab, 21689), (0x9aac, 21690), (0x9aad, 21691), (0x9aae, 21692), (0x9aaf, 21693), (0x9ab0, 22542), (0x9ab1, 22541), (0x9ab2, 21694),
+    (0x9ab3, 21695), (0x9ab4, 21696), (0x9ab5, 21697), (0x9ab6, 22545), (0x9ab7, 22543), (0x9ab8, 10926), (0x9ab9, 21698), (0x9aba, 22546),
+    (0x9abb, 21699), (0x9abc, 22547), (0x9abd, 21700), (0x9abe, 21701), (0x9abf, 21702), (0x9ac0, 22549), (0x9ac1, 22548), (0x9ac2, 22551),
+    (0x9ac3, 21703), (0x9ac4, 21704), (0x9ac5, 22550), (0x9ac6, 21705), (0x9ac7, 21706), (0x9ac8, 21707), (0x9ac9, 21708), (0x9aca, 21709),
+    (0x9acb, 22552), (0x9acc, 22553), (0x9acd, 21710), (0x9ace, 21711), (0x9acf, 21712), (0x9ad0, 21713), (0x9ad1, 22554), (0x9ad2, 21714),
+    (0x9ad3, 14227), (0x9ad4, 21715), (0x9ad5, 21716), (0x9ad6, 21717), (0x9ad7, 21718), (0x9ad8, 10608), (0x9ad9, 21719), (0x9ada, 21720),
+    (0x9adb, 21721), (0x9adc, 21722), (0x9add, 21723), (0x9ade, 21724), (0x9adf, 22567), (0x9ae0, 21725), (0x9ae1, 22568), (0x9ae2, 21726),
+    (0x9ae3, 21727), (0x9ae4, 21728), (0x9ae5, 21729), (0x9ae6, 22569), (0x9ae7, 21730), (0x9ae8, 21731), (0x9ae9, 21732), (0x9aea, 21733),
+    (0x9aeb, 22571), (0x9aec, 21734), (0x9aed, 22573), (0x9aee, 21735), (0x9aef, 22570), (0x9af0, 21736), (0x9af1, 21737), (0x9af2, 21738),
+    (0x9af3, 21739), (0x9af4, 21740), (0x9af5, 21741), (0x9af6, 21742), (0x9af7, 21743), (0x9af8, 21744), (0x9af9, 22574), (0x9afa, 21745),
+    (0x9afb, 22572), (0x9afc, 21746), (0x9afd, 21747), (0x9afe, 21748), (0x9aff, 21749), (0x9b00, 21750), (0x9b01, 21751), (0x9b02, 21752),
+    (0x9b03, 16490), (0x9b04, 21753), (0x9b05, 21754), (0x9b06, 21755), (0x9b07, 21850), (0x9b08, 22575), (0x9b09, 21851), (0x9b0a, 21852),
+    (0x9b0b, 21853), (0x9b0c, 21854), (0x9b0d, 21855), (0x9b0e, 21856), (0x9b0f, 22576), (0x9b10, 21857), (0x9b11, 21858), (0x9b12, 21859),
+    (0x9b13, 22577), (0x9b14, 21860), (0x9b15, 21861), (0x9b16, 21862), (0x9b17, 21863), (0x9b18, 21864), (0x9b19, 21865), (0x9b1a, 21866),
+    (0x9b1b, 21867), (0x9b1c, 21868), (0x9b1d, 21869), (0x9b1e, 21870), (0x9b1f, 22578), (0x9b20, 21871), (0x9b21, 21872), (0x9b22, 21873),
+    (0x9b23, 22579), (0x9b24, 21874), (0x9b25, 21875), (0x9b26, 21876), (0x9b27, 21877), (0x9b28, 21878), (0x9b29, 21879), (0x9b2a, 21880),
+    (0x9b2b, 21881), (0x9b2c, 21882), (0x9b2d, 21883), (0x9b2e, 21884), (0x9b2f, 17238), (0x9b30, 21885), (0x9b31, 21886), (0x9b32, 16635),
+    (0x9b33, 21887), (0x9b34, 21888), (0x9b35, 21889), (0x9b36, 21890), (0x9b37, 21891), (0x9b38, 21892), (0x9b39, 21893), (0x9b3a, 21894),
+    (0x9b3b, 19182), (0x9b3c, 10812), (0x9b3d, 21895), (0x9b3e, 21896), (0x9b3f, 21897), (0x9b40, 21898), (0x9b41, 11968), (0x9b42, 11189),
+    (0x9b43, 22556), (0x9b44, 13244), (0x9b45, 22555), (0x9b46, 21899), (0x9b47, 22557), (0x9b48, 22559), (0x9b49, 22558), (0x9b4a, 21900),
+    (0x9b4b, 21901), (0x9b4c, 21902), (0x9b4d, 22560), (0x9b4e, 21903), (0x9b4f, 14751), (0x9b50, 21904), (0x9b51, 22561), (0x9b52, 21905),
+    (0x9b53, 21906), (0x9b54, 12832), (0x9b55, 21907), (0x9b56, 21908), (0x9b57, 21909), (0x9b58, 21910), (0x9b59, 21911), (0x9b5a, 21912),
+    (0x9b5b, 21913), (0x9b5c, 21914), (0x9b5d, 21915), (0x9b5e, 21916), (0x9b5f, 21917), (0x9b60, 21918), (0x9b61, 21919), (0x9b62, 21920),
+    (0x9b63, 21921), (0x9b64, 21922), (0x9b65, 21923), (0x9b66, 21924), (0x9b67, 21925), (0x9b68, 21926), (0x9b69, 21927), (0x9b6a, 21928),
+    (0x9b6b, 21929), (0x9b6c, 21930), (0x9b6d, 21931), (0x9b6e, 21932), (0x9b6f, 21933), (0x9b70, 21934), (0x9b71, 21935), (0x9b72, 21936),
+    (0x9b73, 21937), (0x9b74, 21938), (0x9b75, 21939), (0x9b76, 21940), (0x9b77, 21941), (0x9b78, 21942), (0x9b79, 21943), (0x9b7a, 21944),
+    (0x9b7b, 21945), (0x9b7c, 22040), (0x9b7d, 22041), (0x9b7e, 22042), (0x9b7f, 22043), (0x9b80, 22044), (0x9b81, 22045), (0x9b82, 22046),
+    (0x9b83, 22047), (0x9b84, 22048), (0x9b85, 22049), (0x9b86, 22050), (0x9b87, 22051), (0x9b88, 22052), (0x9b89, 22053), (0x9b8a, 22054),
+    (0x9b8b, 22055), (0x9b8c, 22056), (0x9b8d, 22057), (0x9b8e, 22058), (0x9b8f, 22059), (0x9b90, 22060), (0x9b91, 22061), (0x9b92, 22062),
+    (0x9b93, 22063), (0x9b94, 22064), (0x9b95, 22065), (0x9b96, 22066), (0x9b97, 22067), (0x9b98, 22068), (0x9b99, 22069), (0x9b9a, 22070),
+    (0x9b9b, 22071), (0x9b9c, 22072), (0x9b9d, 22073), (0x9b9e, 22074), (0x9b9f, 22075), (0x9ba0, 22076), (0x9ba1, 22077), (0x9ba2, 22078),
+    (0x9ba3, 22079), (0x9ba4, 22080), (0x9ba5, 22081), (0x9ba6, 22082), (0x9ba7, 22083), (0x9ba8, 22084), (0x9ba9, 22085), (0x9baa, 22086),
+    (0x9bab, 22087), (0x9bac, 22088), (0x9bad, 22089), (0x9bae, 22090), (0x9baf, 22091), (0x9bb0, 22092), (0x9bb1, 22093), (0x9bb2, 22094),
+    (0x9bb3, 22095), (0x9bb4, 22096), (0x9bb5, 22097), (0x9bb6, 22098), (0x9bb7, 22099), (0x9bb8, 22100), (0x9bb9, 22101), (0x9bba, 22102),
+    (0x9bbb, 22103), (0x9bbc, 22104), (0x9bbd, 22105), (0x9bbe, 22106), (0x9bbf, 22107), (0x9bc0, 22108), (0x9bc1, 22109), (0x9bc2, 22110),
+    (0x9bc3, 22111), (0x9bc4, 22112), (0x9bc5, 22113), (0x9bc6, 22114), (0x9bc7, 22115), (0x9bc8, 22116), (0x9bc9, 22117), (0x9bca, 22118),
+    (0x9bcb, 22119), (0x9bcc, 22120), (0x9bcd, 22121), (0x9bce, 22122), (0x9bcf, 22123), (0x9bd0, 22124), (0x9bd1, 22125), (0x9bd2, 22126),
+    (0x9bd3, 22127), (0x9bd4, 22128), (0x9bd5, 22129), (0x9bd6, 22130), (0x9bd7, 22131), (0x9bd8, 22132), (0x9bd9, 22133), (0x9bda, 22134),
+    (0x9bdb, 22135), (0x9bdc, 22230), (0x9bdd, 22231), (0x9bde, 22232), (0x9bdf, 22233), (0x9be0, 22234), (0x9be1, 22235), (0x9be2, 22236),
+    (0x9be3, 22237), (0x9be4, 22238), (0x9be5, 22239), (0x9be6, 22240), (0x9be7, 22241), (0x9be8, 22242), (0x9be9, 22243), (0x9bea, 22244),
+    (0x9beb, 22245), (0x9bec, 22246), (0x9bed, 22247), (0x9bee, 22248), (0x9bef, 22249), (0x9bf0, 22250), (0x9bf1, 22251), (0x9bf2, 22252),
+    (0x9bf3, 22253), (0x9bf4, 22254), (0x9bf5, 22255), (0x9bf6, 22256), (0x9bf7, 22257), (0x9bf8, 22258), (0x9bf9, 22259), (0x9bfa, 22260),
+    (0x9bfb, 22261), (0x9bfc, 22262), (0x9bfd, 22263), (0x9bfe, 22264), (0x9bff, 22265), (0x9c00, 22266), (0x9c01, 22267), (0x9c02, 22268),
+    (0x9c03, 22269), (0x9c04, 22270), (0x9c05, 22271), (0x9c06, 22272), (0x9c07, 22273), (0x9c08, 22274), (0x9c09, 22275), (0x9c0a, 22276),
+    (0x9c0b, 22277), (0x9c0c, 22278), (0x9c0d, 22279), (0x9c0e, 22280), (0x9c0f, 22281), (0x9c10, 22282), (0x9c11, 22283), (0x9c12, 22284),
+    (0x9c13, 22285), (0x9c14, 22286), (0x9c15, 22287), (0x9c16, 22288), (0x9c17, 22289), (0x9c18, 22290), (0x9c19, 22291), (0x9c1a, 22292),
+    (0x9c1b, 22293), (0x9c1c, 22294), (0x9c1d, 22295), (0x9c1e, 22296), (0x9c1f, 22297), (0x9c20, 22298), (0x9c21, 22299), (0x9c22, 22300),
+    (0x9c23, 22301), (0x9c24, 22302), (0x9c25, 22303), (0x9c26, 22304), (0x9c27, 22305), (0x9c28, 22306), (0x9c29, 22307), (0x9c2a, 22308),
+    (0x9c2b, 22309), (0x9c2c, 22310), (0x9c2d, 22311), (0x9c2e, 22312), (0x9c2f, 22313), (0x9c30, 22314), (0x9c31, 22315), (0x9c32, 22316),
+    (0x9c33, 22317), (0x9c34, 22318), (0x9c35, 22319), (0x9c36, 22320), (0x9c37, 22321), (0x9c38, 22322), (0x9c39, 22323), (0x9c3a, 22324),
+    (0x9c3b, 22325), (0x9c3c, 22420), (0x9c3d, 22421), (0x9c3e, 22422), (0x9c3f, 22423), (0x9c40, 22424), (0x9c41, 22425), (0x9c42, 22426),
+    (0x9c43, 22427), (0x9c44, 22428), (0x9c45, 22429), (0x9c46, 22430), (0x9c47, 22431), (0x9c48, 22432), (0x9c49, 22433), (0x9c4a, 22434),
+    (0x9c4b, 22435), (0x9c4c, 22436), (0x9c4d, 22437), (0x9c4e, 22438), (0x9c4f, 22439), (0x9c50, 22440), (0x9c51, 22441), (0x9c52, 22442),
+    (0x9c53, 22443), (0x9c54, 22444), (0x9c55, 22445), (0x9c56, 22446), (0x9c57, 22447), (0x9c58, 22448), (0x9c59, 22449), (0x9c5a, 22450),
+    (0x9c5b, 22451), (0x9c5c, 22452), (0x9c5d, 22453), (0x9c5e, 22454), (0x9c5f, 22455), (0x9c60, 22456), (0x9c61, 22457), (0x9c62, 22458),
+    (0x9c63, 22459), (0x9c64, 22460), (0x9c65, 22461), (0x9c66, 22462), (0x9c67, 22463), (0x9c68, 22464), (0x9c69, 22465), (0x9c6a, 22466),
+    (0x9c6b, 22467), (0x9c6c, 22468), (0x9c6d, 22469), (0x9c6e, 22470), (0x9c6f, 22471), (0x9c70, 22472), (0x9c71, 22473), (0x9c72, 22474),
+    (0x9c73, 22475), (0x9c74, 22476), (0x9c75, 22477), (0x9c76, 22478), (0x9c77, 22479), (0x9c78, 22480), (0x9c79, 22481), (0x9c7a, 22482),
+    (0x9c7b, 22483), (0x9c7c, 15742), (0x9c7d, 22484), (0x9c7e, 22485), (0x9c7f, 22372), (0x9c80, 22486), (0x9c81, 12464), (0x9c82, 22373),
+    (0x9c83, 22487), (0x9c84, 22488), (0x9c85, 22374), (0x9c86, 22375), (0x9c87, 22376), (0x9c88, 22377), (0x9c89, 22489), (0x9c8a, 22490),
+    (0x9c8b, 22379), (0x9c8c, 22491), (0x9c8d, 9226), (0x9c8e, 22380), (0x9c8f, 22492), (0x9c90, 22381), (0x9c91, 22382), (0x9c92, 22383),
+    (0x9c93, 22493), (0x9c94, 22384), (0x9c95, 22385), (0x9c96, 22494), (0x9c97, 22495), (0x9c98, 22496), (0x9c99, 22497), (0x9c9a, 22386),
+    (0x9c9b, 22387), (0x9c9c, 14957), (0x9c9d, 22498), (0x9c9e, 22388), (0x9c9f, 22389), (0x9ca0, 22390), (0x9ca1, 22391), (0x9ca2, 22392),
+    (0x9ca3, 22393), (0x9ca4, 12145), (0x9ca5, 22394), (0x9ca6, 22395), (0x9ca7, 22396), (0x9ca8, 22397), (0x9ca9, 22398), (0x9caa, 22499),
+    (0x9cab, 22399), (0x9cac, 22500), (0x9cad, 22400), (0x9cae, 22401), (0x9caf, 22501), (0x9cb0, 22402), (0x9cb1, 22403), (0x9cb2, 22404),
+    (0x9cb3, 22405), (0x9cb4, 22406), (0x9cb5, 22407), (0x9cb6, 22408), (0x9cb7, 22409), (0x9cb8, 11693), (0x9cb9, 22502), (0x9cba, 22410),
+    (0x9cbb, 22411), (0x9cbc, 22412), (0x9cbd, 22413), (0x9cbe, 22503), (0x9cbf, 22504), (0x9cc0, 22505), (0x9cc1, 22506), (0x9cc2, 22507),
+    (0x9cc3, 13675), (0x9cc4, 22414), (0x9cc5, 22415), (0x9cc6, 22416), (0x9cc7, 22417), (0x9cc8, 22508), (0x9cc9, 22509), (0x9cca, 22418),
+    (0x9ccb, 22419), (0x9ccc, 22516), (0x9ccd, 22517), (0x9cce, 22518), (0x9ccf, 22519), (0x9cd0, 22520), (0x9cd1, 22510), (0x9cd2, 22511),
+    (0x9cd3, 22521), (0x9cd4, 22522), (0x9cd5, 22523), (0x9cd6, 9293), (0x9cd7, 22524), (0x9cd8, 22525), (0x9cd9, 22526), (0x9cda, 22512),
+    (0x9cdb, 22513), (0x9cdc, 22527), (0x9cdd, 22528), (0x9cde, 12314), (0x9cdf, 22529), (0x9ce0, 22514), (0x9ce1, 22515), (0x9ce2, 22530),
+    (0x9ce3, 22610), (0x9ce4, 22611), (0x9ce5, 22612), (0x9ce6, 22613), (0x9ce7, 22614), (0x9ce8, 22615), (0x9ce9, 22616), (0x9cea, 22617),
+    (0x9ceb, 22618), (0x9cec, 22619), (0x9ced, 22620), (0x9cee, 22621), (0x9cef, 22622), (0x9cf0, 22623), (0x9cf1, 22624), (0x9cf2, 22625),
+    (0x9cf3, 22626), (0x9cf4, 22627), (0x9cf5, 22628), (0x9cf6, 22629), (0x9cf7, 22630), (0x9cf8, 22631), (0x9cf9, 22632), (0x9cfa, 22633),
+    (0x9cfb, 22634), (0x9cfc, 22635), (0x9cfd, 22636), (0x9cfe, 22637), (0x9cff, 22638), (0x9d00, 22639), (0x9d01, 22640), (0x9d02, 22641),
+    (0x9d03, 22642), (0x9d04, 22643), (0x9d05, 22644), (0x9d06, 22645), (0x9d07, 22646), (0x9d08, 22647), (0x9d09, 22648), (0x9d0a, 22649),
+    (0x9d0b, 22650), (0x9d0c, 22651), (0x9d0d, 22652), (0x9d0e, 22653), (0x9d0f, 22654), (0x9d10, 22655), (0x9d11, 22656), (0x9d12, 22657),
+    (0x9d13, 22658), (0x9d14, 22659), (0x9d15, 22660), (0x9d16, 22661), (0x9d17, 22662), (0x9d18, 22663), (0x9d19, 22664), (0x9d1a, 22665),
+    (0x9d1b, 22666), (0x9d1c, 22667), (0x9d1d, 22668), (0x9d1e, 22669), (0x9d1f, 22670), (0x9d20, 22671), (0x9d21, 22672), (0x9d22, 22673),
+    (0x9d23, 22674), (0x9d24, 22675), (0x9d25, 22676), (0x9d26, 22677), (0x9d27, 22678), (0x9d28, 22679), (0x9d29, 22680), (0x9d2a, 22681),
+    (0x9d2b, 22682), (0x9d2c, 22683), (0x9d2d, 22684), (0x9d2e, 22685), (0x9d2f, 22686), (0x9d30, 22687), (0x9d31, 22688), (0x9d32, 22689),
+    (0x9d33, 22690), (0x9d34, 22691), (0x9d35, 22692), (0x9d36, 22693), (0x9d37, 22694), (0x9d38, 22695), (0x9d39, 22696), (0x9d3a, 22697),
+    (0x9d3b, 22698), (0x9d3c, 22699), (0x9d3d, 22700), (0x9d3e, 22701), (0x9d3f, 22702), (0x9d40, 22703), (0x9d41, 22704), (0x9d42, 22705),
+    (0x9d43, 22800), (0x9d44, 22801), (0x9d45, 22802), (0x9d46, 22803), (0x9d47, 22804), (0x9d48, 22805), (0x9d49, 22806), (0x9d4a, 22807),
+    (0x9d4b, 22808), (0x9d4c, 22809), (0x9d4d, 22810), (0x9d4e, 22811), (0x9d4f, 22812), (0x9d50, 22813), (0x9d51, 22814), (0x9d52, 22815),
+    (0x9d53, 22816), (0x9d54, 22817), (0x9d55, 22818), (0x9d56, 22819), (0x9d57, 22820), (0x9d58, 22821), (0x9d59, 22822), (0x9d5a, 22823),
+    (0x9d5b, 22824), (0x9d5c, 22825), (0x9d5d, 22826), (0x9d5e, 22827), (0x9d5f, 22828), (0x9d60, 22829), (0x9d61, 22830), (0x9d62, 22831),
+    (0x9d63, 22832), (0x9d64, 22833), (0x9d65, 22834), (0x9d66, 22835), (0x9d67, 22836), (0x9d68, 22837), (0x9d69, 22838), (0x9d6a, 22839),
+    (0x9d6b, 22840), (0x9d6c, 22841), (0x9d6d, 22842), (0x9d6e, 22843), (0x9d6f, 22844), (0x9d70, 22845), (0x9d71, 22846), (0x9d72, 22847),
+    (0x9d73, 22848), (0x9d74, 22849), (0x9d75, 22850), (0x9d76, 22851), (0x9d77, 22852), (0x9d78, 22853), (0x9d79, 22854), (0x9d7a, 22855),
+    (0x9d7b, 22856), (0x9d7c, 22857), (0x9d7d, 22858), (0x9d7e, 22859), (0x9d7f, 22860), (0x9d80, 22861), (0x9d81, 22862), (0x9d82, 22863),
+    (0x9d83, 22864), (0x9d84, 22865), (0x9d85, 22866), (0x9d86, 22867), (0x9d87, 22868), (0x9d88, 22869), (0x9d89, 22870), (0x9d8a, 22871),
+    (0x9d8b, 22872), (0x9d8c, 22873), (0x9d8d, 22874), (0x9d8e, 22875), (0x9d8f, 22876), (0x9d90, 22877), (0x9d91, 22878), (0x9d92, 22879),
+    (0x9d93, 22880), (0x9d94, 22881), (0x9d95, 22882), (0x9d96, 22883), (0x9d97, 22884), (0x9d98, 22885), (0x9d99, 22886), (0x9d9a, 22887),
+    (0x9d9b, 22888), (0x9d9c, 22889), (0x9d9d, 22890), (0x9d9e, 22891), (0x9d9f, 22892), (0x9da0, 22893), (0x9da1, 22894), (0x9da2, 22895),
+    (0x9da3, 22990), (0x9da4, 22991), (0x9da5, 22992), (0x9da6, 22993), (0x9da7, 22994), (0x9da8, 22995), (0x9da9, 22996), (0x9daa, 22997),
+    (0x9dab, 22998), (0x9dac, 22999), (0x9dad, 23000), (0x9dae, 23001), (0x9daf, 23002), (0x9db0, 23003), (0x9db1, 23004), (0x9db2, 23005),
+    (0x9db3, 23006), (0x9db4, 23007), (0x9db5, 23008), (0x9db6, 23009), (0x9db7, 23010), (0x9db8, 23011), (0x9db9, 23012), (0x9dba, 23013),
+    (0x9dbb, 23014), (0x9dbc, 23015), (0x9dbd, 23016), (0x9dbe, 23017), (0x9dbf, 23018), (0x9dc0, 23019), (0x9dc1, 23020), (0x9dc2, 23021),
+    (0x9dc3, 23022), (0x9dc4, 23023), (0x9dc5, 23024), (0x9dc6, 23025), (0x9dc7, 23026), (0x9dc8, 23027), (0x9dc9, 23028), (0x9dca, 23029),
+    (0x9dcb, 23030), (0x9dcc, 23031), (0x9dcd, 23032), (0x9dce, 23033), (0x9dcf, 23034), (0x9dd0, 23035), (0x9dd1, 23036), (0x9dd2, 23037),
+    (0x9dd3, 23038), (0x9dd4, 23039), (0x9dd5, 23040), (0x9dd6, 23041), (0x9dd7, 23042), (0x9dd8, 23043), (0x9dd9, 23044), (0x9dda, 23045),
+    (0x9ddb, 23046), (0x9ddc, 23047), (0x9ddd, 23048), (0x9dde, 23049), (0x9ddf, 23050), (0x9de0, 23051), (0x9de1, 23052), (0x9de2, 23053),
+    (0x9de3, 23054), (0x9de4, 23055), (0x9de5, 23056), (0x9de6, 23057), (0x9de7, 23058), (0x9de8, 23059), (0x9de9, 23060), (0x9dea, 23061),
+    (0x9deb, 23062), (0x9dec, 23063), (0x9ded, 23064), (0x9dee, 23065), (0x9def, 23066), (0x9df0, 23067), (0x9df1, 23068), (0x9df2, 23069),
+    (0x9df3, 23070), (0x9df4, 23071), (0x9df5, 23072), (0x9df6, 23073), (0x9df7, 23074), (0x9df8, 23075), (0x9df9, 23076), (0x9dfa, 23077),
+    (0x9dfb, 23078), (0x9dfc, 23079), (0x9dfd, 23080), (0x9dfe, 23081), (0x9dff, 23082), (0x9e00, 23083), (0x9e01, 23084), (0x9e02, 23085),
+    (0x9e03, 23180), (0x9e04, 23181), (0x9e05, 23182), (0x9e06, 23183), (0x9e07, 23184), (0x9e08, 23185), (0x9e09, 23186), (0x9e0a, 23187),
+    (0x9e0b, 23188), (0x9e0c, 23189), (0x9e0d, 23190), (0x9e0e, 23191), (0x9e0f, 23192), (0x9e10, 23193), (0x9e11, 23194), (0x9e12, 23195),
+    (0x9e13, 23196), (0x9e14, 23197), (0x9e15, 23198), (0x9e16, 23199), (0x9e17, 23200), (0x9e18, 23201), (0x9e19, 23202), (0x9e1a, 23203),
+    (0x9e1b, 23204), (0x9e1c, 23205), (0x9e1d, 23206), (0x9e1e, 23207), (0x9e1f, 12906), (0x9e20, 21200), (0x9e21, 11311), (0x9e22, 21201),
+    (0x9e23, 12724), (0x9e24, 23208), (0x9e25, 13039), (0x9e26, 15322), (0x9e27, 23209), (0x9e28, 21202), (0x9e29, 21203), (0x9e2a, 21204),
+    (0x9e2b, 21205), (0x9e2c, 21206), (0x9e2d, 15323), (0x9e2e, 23210), (0x9e2f, 15371), (0x9e30, 23211), (0x9e31, 21208), (0x9e32, 21207),
+    (0x9e33, 15872), (0x9e34, 23212), (0x9e35, 14585), (0x9e36, 21209), (0x9e37, 21211), (0x9e38, 21210), (0x9e39, 21212), (0x9e3a, 21213),
+    (0x9e3b, 23213), (0x9e3c, 23214), (0x9e3d, 10620), (0x9e3e, 21214), (0x9e3f, 10997), (0x9e40, 23215), (0x9e41, 21215), (0x9e42, 21216),
+    (0x9e43, 11758), (0x9e44, 21217), (0x9e45, 10241), (0x9e46, 21218), (0x9e47, 21219), (0x9e48, 21220), (0x9e49, 21221), (0x9e4a, 13606),
+    (0x9e4b, 21222), (0x9e4c, 21223), (0x9e4d, 23216), (0x9e4e, 21224), (0x9e4f, 13099), (0x9e50, 23217), (0x9e51, 21225), (0x9e52, 23218),
+    (0x9e53, 23219), (0x9e54, 23220), (0x9e55, 21226), (0x9e56, 23221), (0x9e57, 21227), (0x9e58, 22544), (0x9e59, 23222), (0x9e5a, 21228),
+    (0x9e5b, 21229), (0x9e5c, 21230), (0x9e5d, 23223), (0x9e5e, 21231), (0x9e5f, 23224), (0x9e60, 23225), (0x9e61, 23226), (0x9e62, 23227),
+    (0x9e63, 21232), (0x9e64, 10980), (0x9e65, 23228), (0x9e66, 21233), (0x9e67, 21234), (0x9e68, 21235), (0x9e69, 21236), (0x9e6a, 21237),
+    (0x9e6b, 21238), (0x9e6c, 21239), (0x9e6d, 21241), (0x9e6e, 23229), (0x9e6f, 23230), (0x9e70, 15680), (0x9e71, 21240), (0x9e72, 23231),
+    (0x9e73, 21242), (0x9e74, 23232), (0x9e75, 23233), (0x9e76, 23234), (0x9e77, 23235), (0x9e78, 23236), (0x9e79, 23237), (0x9e7a, 23238),
+    (0x9e7b, 23239), (0x9e7c, 23240), (0x9e7d, 23241), (0x9e7e, 22161), (0x9e7f, 12470), (0x9e80, 23242), (0x9e81, 23243), (0x9e82, 22583),
+    (0x9e83, 23244), (0x9e84, 23245), (0x9e85, 23246), (0x9e86, 23247), (0x9e87, 22584), (0x9e88, 22585), (0x9e89, 23248), (0x9e8a, 23249),
+    (0x9e8b, 22586), (0x9e8c, 23250), (0x9e8d, 23251), (0x9e8e, 23252), (0x9e8f, 23253), (0x9e90, 23254), (0x9e91, 23255), (0x9e92, 22587),
+    (0x9e93, 12465), (0x9e94, 23256), (0x9e95, 23257), (0x9e96, 23258), (0x9e97, 23259), (0x9e98, 23260), (0x9e99, 23261), (0x9e9a, 23262),
+    (0x9e9b, 23263), (0x9e9c, 23264), (0x9e9d, 22589), (0x9e9e, 23265), (0x9e9f, 22590), (0x9ea0, 23266), (0x9ea1, 23267), (0x9ea2, 23268),
+    (0x9ea3, 23269), (0x9ea4, 23270), (0x9ea5, 23271), (0x9ea6, 12528), (0x9ea7, 23272), (0x9ea8, 23273), (0x9ea9, 23274), (0x9eaa, 23275),
+    (0x9eab, 23370), (0x9eac, 23371), (0x9ead, 23372), (0x9eae, 23373), (0x9eaf, 23374), (0x9eb0, 23375), (0x9eb1, 23376), (0x9eb2, 23377),
+    (0x9eb3, 23378), (0x9eb4, 22025), (0x9eb5, 23379), (0x9eb6, 23380), (0x9eb7, 23381), (0x9eb8, 22024), (0x9eb9, 23382), (0x9eba, 23383),
+    (0x9ebb, 12518), (0x9ebc, 23384), (0x9ebd, 22580), (0x9ebe, 22581), (0x9ebf, 23385), (0x9ec0, 23386), (0x9ec1, 23387), (0x9ec2, 23388),
+    (0x9ec3, 23389), (0x9ec4, 11153), (0x9ec5, 23390), (0x9ec6, 23391), (0x9ec7, 23392), (0x9ec8, 23393), (0x9ec9, 16883), (0x9eca, 23394),
+    (0x9ecb, 23395), (0x9ecc, 23396), (0x9ecd, 14047), (0x9ece, 12137), (0x9ecf, 21189), (0x9ed0, 23397), (0x9ed1, 10983), (0x9ed2, 23398),
+    (0x9ed3, 23399), (0x9ed4, 13408), (0x9ed5, 23400), (0x9ed6, 23401), (0x9ed7, 23402), (0x9ed8, 12837), (0x9ed9, 23403), (0x9eda, 23404),
+    (0x9edb, 22591), (0x9edc, 22592), (0x9edd, 22593), (0x9ede, 23405), (0x9edf, 22595), (0x9ee0, 22594), (0x9ee1, 23406), (0x9ee2, 22596),
+    (0x9ee3, 23407), (0x9ee4, 23408), (0x9ee5, 22599), (0x9ee6, 23409), (0x9ee7, 22598), (0x9ee8, 23410), (0x9ee9, 22597), (0x9eea, 22600),
+    (0x9eeb, 23411), (0x9eec, 23412), (0x9eed, 23413), (0x9eee, 23414), (0x9eef, 22601), (0x9ef0, 23415), (0x9ef1, 23416), (0x9ef2, 23417),
+    (0x9ef3, 23418), (0x9ef4, 23419), (0x9ef5, 23420), (0x9ef6, 23421), (0x9ef7, 23422), (0x9ef8, 23423), (0x9ef9, 20688), (0x9efa, 23424),
+    (0x9efb, 20689), (0x9efc, 20690), (0x9efd, 23425), (0x9efe, 22353), (0x9eff, 23426), (0x9f00, 23427), (0x9f01, 23428), (0x9f02, 23429),
+    (0x9f03, 23430), (0x9f04, 23431), (0x9f05, 23432), (0x9f06, 23433), (0x9f07, 23434), (0x9f08, 23435), (0x9f09, 23436), (0x9f0a, 23437),
+    (0x9f0b, 22354), (0x9f0c, 23438), (0x9f0d, 22355), (0x9f0e, 10171), (0x9f0f, 23439), (0x9f10, 16655), (0x9f11, 23440), (0x9f12, 23441),
+    (0x9f13, 10771), (0x9f14, 23442), (0x9f15, 23443), (0x9f16, 23444), (0x9f17, 16652), (0x9f18, 23445), (0x9f19, 17402), (0x9f1a, 23446),
+    (0x9f1b, 23447), (0x9f1c, 23448), (0x9f1d, 23449), (0x9f1e, 23450), (0x9f1f, 23451), (0x9f20, 14048), (0x9f21, 23452), (0x9f22, 22602),
+    (0x9f23, 23453), (0x9f24, 23454), (0x9f25, 23455), (0x9f26, 23456), (0x9f27, 23457), (0x9f28, 23458), (0x9f29, 23459), (0x9f2a, 23460),
+    (0x9f2b, 23461), (0x9f2c, 22603), (0x9f2d, 23462), (0x9f2e, 23463), (0x9f2f, 22604), (0x9f30, 23464), (0x9f31, 23465), (0x9f32, 23560),
+    (0x9f33, 23561), (0x9f34, 23562), (0x9f35, 23563), (0x9f36, 23564), (0x9f37, 22606), (0x9f38, 23565), (0x9f39, 22605), (0x9f3a, 23566),
+    (0x9f3b, 9254), (0x9f3c, 23567), (0x9f3d, 22607), (0x9f3e, 22608), (0x9f3f, 23568), (0x9f40, 23569), (0x9f41, 23570), (0x9f42, 23571),
+    (0x9f43, 23572), (0x9f44, 22609), (0x9f45, 23573), (0x9f46, 23574), (0x9f47, 23575), (0x9f48, 23576), (0x9f49, 23577), (0x9f4a, 23578),
+    (0x9f4b, 23579), (0x9f4c, 23580), (0x9f4d, 23581), (0x9f4e, 23582), (0x9f4f, 23583), (0x9f50, 13280), (0x9f51, 20445), (0x9f52, 23584),
+    (0x9f53, 23585), (0x9f54, 23586), (0x9f55, 23587), (0x9f56, 23588), (0x9f57, 23589), (0x9f58, 23590), (0x9f59, 23591), (0x9f5a, 23592),
+    (0x9f5b, 23593), (0x9f5c, 23594), (0x9f5d, 23595), (0x9f5e, 23596), (0x9f5f, 23597), (0x9f60, 23598), (0x9f61, 23599), (0x9f62, 23600),
+    (0x9f63, 23601), (0x9f64, 23602), (0x9f65, 23603), (0x9f66, 23604), (0x9f67, 23605), (0x9f68, 23606), (0x9f69, 23607), (0x9f6a, 23608),
+    (0x9f6b, 23609), (0x9f6c, 23610), (0x9f6d, 23611), (0x9f6e, 23612), (0x9f6f, 23613), (0x9f70, 23614), (0x9f71, 23615), (0x9f72, 23616),
+    (0x9f73, 23617), (0x9f74, 23618), (0x9f75, 23619), (0x9f76, 23620), (0x9f77, 23621), (0x9f78, 23622), (0x9f79, 23623), (0x9f7a, 23624),
+    (0x9f7b, 23625), (0x9f7c, 23626), (0x9f7d, 23627), (0x9f7e, 23628), (0x9f7f, 9656), (0x9f80, 22344), (0x9f81, 23629), (0x9f82, 23630),
+    (0x9f83, 22345), (0x9f84, 12323), (0x9f85, 22346), (0x9f86, 22347), (0x9f87, 22348), (0x9f88, 22349), (0x9f89, 22350), (0x9f8a, 22351),
+    (0x9f8b, 13588), (0x9f8c, 22352), (0x9f8d, 23631), (0x9f8e, 23632), (0x9f8f, 23633), (0x9f90, 23634), (0x9f91, 23635), (0x9f92, 23636),
+    (0x9f93, 23637), (0x9f94, 23638), (0x9f95, 23639), (0x9f96, 23640), (0x9f97, 23641), (0x9f98, 23642), (0x9f99, 12345), (0x9f9a, 10743),
+    (0x9f9b, 20687), (0x9f9c, 23643), (0x9f9d, 23644), (0x9f9e, 23645), (0x9f9f, 10809), (0x9fa0, 16878), (0x9fa1, 23646), (0x9fa2, 23647),
+    (0x9fa3, 23648), (0x9fa4, 23649), (0x9fa5, 23650), (0xe000, 7886), (0xe001, 7887), (0xe002, 7888), (0xe003, 7889), (0xe004, 7890),
+    (0xe005, 7891), (0xe006, 7892), (0xe007, 7893), (0xe008, 7894), (0xe009, 7895), (0xe00a, 7896), (0xe00b, 7897), (0xe00c, 7898),
+    (0xe00d, 7899), (0xe00e, 7900), (0xe00f, 7901), (0xe010, 7902), (0xe011, 7903), (0xe012, 7904), (0xe013, 7905), (0xe014, 7906),
+    (0xe015, 7907), (0xe016, 7908), (0xe017, 7909), (0xe018, 7910), (0xe019, 7911), (0xe01a, 7912), (0xe01b, 7913), (0xe01c, 7914),
+    (0xe01d, 7915), (0xe01e, 7916), (0xe01f, 7917), (0xe020, 7918), (0xe021, 7919), (0xe022, 7920), (0xe023, 7921), (0xe024, 7922),
+    (0xe025, 7923), (0xe026, 7924), (0xe027, 7925), (0xe028, 7926), (0xe029, 7927), (0xe02a, 7928), (0xe02b, 7929), (0xe02c, 7930),
+    (0xe02d, 7931), (0xe02e, 7932), (0xe02f, 7933), (0xe030, 7934), (0xe031, 7935), (0xe032, 7936), (0xe033, 7937), (0xe034, 7938),
+    (0xe035, 7939), (0xe036, 7940), (0xe037, 7941), (0xe038, 7942), (0xe039, 7943), (0xe03a, 7944), (0xe03b, 7945), (0xe03c, 7946),
+    (0xe03d, 7947), (0xe03e, 7948), (0xe03f, 7949), (0xe040, 7950), (0xe041, 7951), (0xe042, 7952), (0xe043, 7953), (0xe044, 7954),
+    (0xe045, 7955), (0xe046, 7956), (0xe047, 7957), (0xe048, 7958), (0xe049, 7959), (0xe04a, 7960), (0xe04b, 7961), (0xe04c, 7962),
+    (0xe04d, 7963), (0xe04e, 7964), (0xe04f, 7965), (0xe050, 7966), (0xe051, 7967), (0xe052, 7968), (0xe053, 7969), (0xe054, 7970),
+    (0xe055, 7971), (0xe056, 7972), (0xe057, 7973), (0xe058, 7974), (0xe059, 7975), (0xe05a, 7976), (0xe05b, 7977), (0xe05c, 7978),
+    (0xe05d, 7979), (0xe05e, 8076), (0xe05f, 8077), (0xe060, 8078), (0xe061, 8079), (0xe062, 8080), (0xe063, 8081), (0xe064, 8082),
+    (0xe065, 8083), (0xe066, 8084), (0xe067, 8085), (0xe068, 8086), (0xe069, 8087), (0xe06a, 8088), (0xe06b, 8089), (0xe06c, 8090),
+    (0xe06d, 8091), (0xe06e, 8092), (0xe06f, 8093), (0xe070, 8094), (0xe071, 8095), (0xe072, 8096), (0xe073, 8097), (0xe074, 8098),
+    (0xe075, 8099), (0xe076, 8100), (0xe077, 8101), (0xe078, 8102), (0xe079, 8103), (0xe07a, 8104), (0xe07b, 8105), (0xe07c, 8106),
+    (0xe07d, 8107), (0xe07e, 8108), (0xe07f, 8109), (0xe080, 8110), (0xe081, 8111), (0xe082, 8112), (0xe083, 8113), (0xe084, 8114),
+    (0xe085, 8115), (0xe086, 8116), (0xe087, 8117), (0xe088, 8118), (0xe089, 8119), (0xe08a, 8120), (0xe08b, 8121), (0xe08c, 8122),
+    (0xe08d, 8123), (0xe08e, 8124), (0xe08f, 8125), (0xe090, 8126), (0xe091, 8127), (0xe092, 8128), (0xe093, 8129), (0xe094, 8130),
+    (0xe095, 8131), (0xe096, 8132), (0xe097, 8133), (0xe098, 8134), (0xe099, 8135), (0xe09a, 8136), (0xe09b, 8137), (0xe09c, 8138),
+    (0xe09d, 8139), (0xe09e, 8140), (0xe09f, 8141), (0xe0a0, 8142), (0xe0a1, 8143), (0xe0a2, 8144), (0xe0a3, 8145), (0xe0a4, 8146),
+    (0xe0a5, 8147), (0xe0a6, 8148), (0xe0a7, 8149), (0xe0a8, 8150), (0xe0a9, 8151), (0xe0aa, 8152), (0xe0ab, 8153), (0xe0ac, 8154),
+    (0xe0ad, 8155), (0xe0ae, 8156), (0xe0af, 8157), (0xe0b0, 8158), (0xe0b1, 8159), (0xe0b2, 8160), (0xe0b3, 8161), (0xe0b4, 8162),
+    (0xe0b5, 8163), (0xe0b6, 8164), (0xe0b7, 8165), (0xe0b8, 8166), (0xe0b9, 8167), (0xe0ba, 8168), (0xe0bb, 8169), (0xe0bc, 8266),
+    (0xe0bd, 8267), (0xe0be, 8268), (0xe0bf, 8269), (0xe0c0, 8270), (0xe0c1, 8271), (0xe0c2, 8272), (0xe0c3, 8273), (0xe0c4, 8274),
+    (0xe0c5, 8275), (0xe0c6, 8276), (0xe0c7, 8277), (0xe0c8, 8278), (0xe0c9, 8279), (0xe0ca, 8280), (0xe0cb, 8281), (0xe0cc, 8282),
+    (0xe0cd, 8283), (0xe0ce, 8284), (0xe0cf, 8285), (0xe0d0, 8286), (0xe0d1, 8287), (0xe0d2, 8288), (0xe0d3, 8289), (0xe0d4, 8290),
+    (0xe0d5, 8291), (0xe0d6, 8292), (0xe0d7, 8293), (0xe0d8, 8294), (0xe0d9, 8295), (0xe0da, 8296), (0xe0db, 8297), (0xe0dc, 8298),
+    (0xe0dd, 8299), (0xe0de, 8300), (0xe0df, 8301), (0xe0e0, 8302), (0xe0e1, 8303), (0xe0e2, 8304), (0xe0e3, 8305), (0xe0e4, 8306),
+    (0xe0e5, 8307), (0xe0e6, 8308), (0xe0e7, 8309), (0xe0e8, 8310), (0xe0e9, 8311), (0xe0ea, 8312), (0xe0eb, 8313), (0xe0ec, 8314),
+    (0xe0ed, 8315), (0xe0ee, 8316), (0xe0ef, 8317), (0xe0f0, 8318), (0xe0f1, 8319), (0xe0f2, 8320), (0xe0f3, 8321), (0xe0f4, 8322),
+    (0xe0f5, 8323), (0xe0f6, 8324), (0xe0f7, 8325), (0xe0f8, 8326), (0xe0f9, 8327), (0xe0fa, 8328), (0xe0fb, 8329), (0xe0fc, 8330),
+    (0xe0fd, 8331), (0xe0fe, 8332), (0xe0ff, 8333), (0xe100, 8334), (0xe101, 8335), (0xe102, 8336), (0xe103, 8337), (0xe104, 8338),
+    (0xe105, 8339), (0xe106, 8340), (0xe107, 8341), (0xe108, 8342), (0xe109, 8343), (0xe10a, 8344), (0xe10b, 8345), (0xe10c, 8346),
+    (0xe10d, 8347), (0xe10e, 8348), (0xe10f, 8349), (0xe110, 8350), (0xe111, 8351), (0xe112, 8352), (0xe113, 8353), (0xe114, 8354),
+    (0xe115, 8355), (0xe116, 8356), (0xe117, 8357), (0xe118, 8358), (0xe119, 8359), (0xe11a, 8456), (0xe11b, 8457), (0xe11c, 8458),
+    (0xe11d, 8459), (0xe11e, 8460), (0xe11f, 8461), (0xe120, 8462), (0xe121, 8463), (0xe122, 8464), (0xe123, 8465), (0xe124, 8466),
+    (0xe125, 8467), (0xe126, 8468), (0xe127, 8469), (0xe128, 8470), (0xe129, 8471), (0xe12a, 8472), (0xe12b, 8473), (0xe12c, 8474),
+    (0xe12d, 8475), (0xe12e, 8476), (0xe12f, 8477), (0xe130, 8478), (0xe131, 8479), (0xe132, 8480), (0xe133, 8481), (0xe134, 8482),
+    (0xe135, 8483), (0xe136, 8484), (0xe137, 8485), (0xe138, 8486), (0xe139, 8487), (0xe13a, 8488), (0xe13b, 8489), (0xe13c, 8490),
+    (0xe13d, 8491), (0xe13e, 8492), (0xe13f, 8493), (0xe140, 8494), (0xe141, 8495), (0xe142, 8496), (0xe143, 8497), (0xe144, 8498),
+    (0xe145, 8499), (0xe146, 8500), (0xe147, 8501), (0xe148, 8502), (0xe149, 8503), (0xe14a, 8504), (0xe14b, 8505), (0xe14c, 8506),
+    (0xe14d, 8507), (0xe14e, 8508), (0xe14f, 8509), (0xe150, 8510), (0xe151, 8511), (0xe152, 8512), (0xe153, 8513), (0xe154, 8514),
+    (0xe155, 8515), (0xe156, 8516), (0xe157, 8517), (0xe158, 8518), (0xe159, 8519), (0xe15a, 8520), (0xe15b, 8521), (0xe15c, 8522),
+    (0xe15d, 8523), (0xe15e, 8524), (0xe15f, 8525), (0xe160, 8526), (0xe161, 8527), (0xe162, 8528), (0xe163, 8529), (0xe164, 8530),
+    (0xe165, 8531), (0xe166, 8532), (0xe167, 8533), (0xe168, 8534), (0xe169, 8535), (0xe16a, 8536), (0xe16b, 8537), (0xe16c, 8538),
+    (0xe16d, 8539), (0xe16e, 8540), (0xe16f, 8541), (0xe170, 8542), (0xe171, 8543), (0xe172, 8544), (0xe173, 8545), (0xe174, 8546),
+    (0xe175, 8547), (0xe176, 8548), (0xe177, 8549), (0xe178, 8646), (0xe179, 8647), (0xe17a, 8648), (0xe17b, 8649), (0xe17c, 8650),
+    (0xe17d, 8651), (0xe17e, 8652), (0xe17f, 8653), (0xe180, 8654), (0xe181, 8655), (0xe182, 8656), (0xe183, 8657), (0xe184, 8658),
+    (0xe185, 8659), (0xe186, 8660), (0xe187, 8661), (0xe188, 8662), (0xe189, 8663), (0xe18a, 8664), (0xe18b, 8665), (0xe18c, 8666),
+    (0xe18d, 8667), (0xe18e, 8668), (0xe18f, 8669), (0xe190, 8670), (0xe191, 8671), (0xe192, 8672), (0xe193, 8673), (0xe194, 8674),
+    (0xe195, 8675), (0xe196, 8676), (0xe197, 8677), (0xe198, 8678), (0xe199, 8679), (0xe19a, 8680), (0xe19b, 8681), (0xe19c, 8682),
+    (0xe19d, 8683), (0xe19e, 8684), (0xe19f, 8685), (0xe1a0, 8686), (0xe1a1, 8687), (0xe1a2, 8688), (0xe1a3, 8689), (0xe1a4, 8690),
+    (0xe1a5, 8691), (0xe1a6, 8692), (0xe1a7, 8693), (0xe1a8, 8694), (0xe1a9, 8695), (0xe1aa, 8696), (0xe1ab, 8697), (0xe1ac, 8698),
+    (0xe1ad, 8699), (0xe1ae, 8700), (0xe1af, 8701), (0xe1b0, 8702), (0xe1b1, 8703), (0xe1b2, 8704), (0xe1b3, 8705), (0xe1b4, 8706),
+    (0xe1b5, 8707), (0xe1b6, 8708), (0xe1b7, 8709), (0xe1b8, 8710), (0xe1b9, 8711), (0xe1ba, 8712), (0xe1bb, 8713), (0xe1bc, 8714),
+    (0xe1bd, 8715), (0xe1be, 8716), (0xe1bf, 8717), (0xe1c0, 8718), (0xe1c1, 8719), (0xe1c2, 8720), (0xe1c3, 8721), (0xe1c4, 8722),
+    (0xe1c5, 8723), (0xe1c6, 8724), (0xe1c7, 8725), (0xe1c8, 8726), (0xe1c9, 8727), (0xe1ca, 8728), (0xe1cb, 8729), (0xe1cc, 8730),
+    (0xe1cd, 8731), (0xe1ce, 8732), (0xe1cf, 8733), (0xe1d0, 8734), (0xe1d1, 8735), (0xe1d2, 8736), (0xe1d3, 8737), (0xe1d4, 8738),
+    (0xe1d5, 8739), (0xe1d6, 8836), (0xe1d7, 8837), (0xe1d8, 8838), (0xe1d9, 8839), (0xe1da, 8840), (0xe1db, 8841), (0xe1dc, 8842),
+    (0xe1dd, 8843), (0xe1de, 8844), (0xe1df, 8845), (0xe1e0, 8846), (0xe1e1, 8847), (0xe1e2, 8848), (0xe1e3, 8849), (0xe1e4, 8850),
+    (0xe1e5, 8851), (0xe1e6, 8852), (0xe1e7, 8853), (0xe1e8, 8854), (0xe1e9, 8855), (0xe1ea, 8856), (0xe1eb, 8857), (0xe1ec, 8858),
+    (0xe1ed, 8859), (0xe1ee, 8860), (0xe1ef, 8861), (0xe1f0, 8862), (0xe1f1, 8863), (0xe1f2, 8864), (0xe1f3, 8865), (0xe1f4, 8866),
+    (0xe1f5, 8867), (0xe1f6, 8868), (0xe1f7, 8869), (0xe1f8, 8870), (0xe1f9, 8871), (0xe1fa, 8872), (0xe1fb, 8873), (0xe1fc, 8874),
+    (0xe1fd, 8875), (0xe1fe, 8876), (0xe1ff, 8877), (0xe200, 8878), (0xe201, 8879), (0xe202, 8880), (0xe203, 8881), (0xe204, 8882),
+    (0xe205, 8883), (0xe206, 8884), (0xe207, 8885), (0xe208, 8886), (0xe209, 8887), (0xe20a, 8888), (0xe20b, 8889), (0xe20c, 8890),
+    (0xe20d, 8891), (0xe20e, 8892), (0xe20f, 8893), (0xe210, 8894), (0xe211, 8895), (0xe212, 8896), (0xe213, 8897), (0xe214, 8898),
+    (0xe215, 8899), (0xe216, 8900), (0xe217, 8901), (0xe218, 8902), (0xe219, 8903), (0xe21a, 8904), (0xe21b, 8905), (0xe21c, 8906),
+    (0xe21d, 8907), (0xe21e, 8908), (0xe21f, 8909), (0xe220, 8910), (0xe221, 8911), (0xe222, 8912), (0xe223, 8913), (0xe224, 8914),
+    (0xe225, 8915), (0xe226, 8916), (0xe227, 8917), (0xe228, 8918), (0xe229, 8919), (0xe22a, 8920), (0xe22b, 8921), (0xe22c, 8922),
+    (0xe22d, 8923), (0xe22e, 8924), (0xe22f, 8925), (0xe230, 8926), (0xe231, 8927), (0xe232, 8928), (0xe233, 8929), (0xe234, 22706),
+    (0xe235, 22707), (0xe236, 22708), (0xe237, 22709), (0xe238, 22710), (0xe239, 22711), (0xe23a, 22712), (0xe23b, 22713), (0xe23c, 22714),
+    (0xe23d, 22715), (0xe23e, 22716), (0xe23f, 22717), (0xe240, 22718), (0xe241, 22719), (0xe242, 22720), (0xe243, 22721), (0xe244, 22722),
+    (0xe245, 22723), (0xe246, 22724), (0xe247, 22725), (0xe248, 22726), (0xe249, 22727), (0xe24a, 22728), (0xe24b, 22729), (0xe24c, 22730),
+    (0xe24d, 22731), (0xe24e, 22732), (0xe24f, 22733), (0xe250, 22734), (0xe251, 22735), (0xe252, 22736), (0xe253, 22737), (0xe254, 22738),
+    (0xe255, 22739), (0xe256, 22740), (0xe257, 22741), (0xe258, 22742), (0xe259, 22743), (0xe25a, 22744), (0xe25b, 22745), (0xe25c, 22746),
+    (0xe25d, 22747), (0xe25e, 22748), (0xe25f, 22749), (0xe260, 22750), (0xe261, 22751), (0xe262, 22752), (0xe263, 22753), (0xe264, 22754),
+    (0xe265, 22755), (0xe266, 22756), (0xe267, 22757), (0xe268, 22758), (0xe269, 22759), (0xe26a, 22760), (0xe26b, 22761), (0xe26c, 22762),
+    (0xe26d, 22763), (0xe26e, 22764), (0xe26f, 22765), (0xe270, 22766), (0xe271, 22767), (0xe272, 22768), (0xe273, 22769), (0xe274, 22770),
+    (0xe275, 22771), (0xe276, 22772), (0xe277, 22773), (0xe278, 22774), (0xe279, 22775), (0xe27a, 22776), (0xe27b, 22777), (0xe27c, 22778),
+    (0xe27d, 22779), (0xe27e, 22780), (0xe27f, 22781), (0xe280, 22782), (0xe281, 22783), (0xe282, 22784), (0xe283, 22785), (0xe284, 22786),
+    (0xe285, 22787), (0xe286, 22788), (0xe287, 22789), (0xe288, 22790), (0xe289, 22791), (0xe28a, 22792), (0xe28b, 22793), (0xe28c, 22794),
+    (0xe28d, 22795), (0xe28e, 22796), (0xe28f, 22797), (0xe290, 22798), (0xe291, 22799), (0xe292, 22896), (0xe293, 22897), (0xe294, 22898),
+    (0xe295, 22899), (0xe296, 22900), (0xe297, 22901), (0xe298, 22902), (0xe299, 22903), (0xe29a, 22904), (0xe29b, 22905), (0xe29c, 22906),
+    (0xe29d, 22907), (0xe29e, 22908), (0xe29f, 22909), (0xe2a0, 22910), (0xe2a1, 22911), (0xe2a2, 22912), (0xe2a3, 22913), (0xe2a4, 22914),
+    (0xe2a5, 22915), (0xe2a6, 22916), (0xe2a7, 22917), (0xe2a8, 22918), (0xe2a9, 22919), (0xe2aa, 22920), (0xe2ab, 22921), (0xe2ac, 22922),
+    (0xe2ad, 22923), (0xe2ae, 22924), (0xe2af, 22925), (0xe2b0, 22926), (0xe2b1, 22927), (0xe2b2, 22928), (0xe2b3, 22929), (0xe2b4, 22930),
+    (0xe2b5, 22931), (0xe2b6, 22932), (0xe2b7, 22933), (0xe2b8, 22934), (0xe2b9, 22935), (0xe2ba, 22936), (0xe2bb, 22937), (0xe2bc, 22938),
+    (0xe2bd, 22939), (0xe2be, 22940), (0xe2bf, 22941), (0xe2c0, 22942), (0xe2c1, 22943), (0xe2c2, 22944), (0xe2c3, 22945), (0xe2c4, 22946),
+    (0xe2c5, 22947), (0xe2c6, 22948), (0xe2c7, 22949), (0xe2c8, 22950), (0xe2c9, 22951), (0xe2ca, 22952), (0xe2cb, 22953), (0xe2cc, 22954),
+    (0xe2cd, 22955), (0xe2ce, 22956), (0xe2cf, 22957), (0xe2d0, 22958), (0xe2d1, 22959), (0xe2d2, 22960), (0xe2d3, 22961), (0xe2d4, 22962),
+    (0xe2d5, 22963), (0xe2d6, 22964), (0xe2d7, 22965), (0xe2d8, 22966), (0xe2d9, 22967), (0xe2da, 22968), (0xe2db, 22969), (0xe2dc, 22970),
+    (0xe2dd, 22971), (0xe2de, 22972), (0xe2df, 22973), (0xe2e0, 22974), (0xe2e1, 22975), (0xe2e2, 22976), (0xe2e3, 22977), (0xe2e4, 22978),
+    (0xe2e5, 22979), (0xe2e6, 22980), (0xe2e7, 22981), (0xe2e8, 22982), (0xe2e9, 22983), (0xe2ea, 22984), (0xe2eb, 22985), (0xe2ec, 22986),
+    (0xe2ed, 22987), (0xe2ee, 22988), (0xe2ef, 22989), (0xe2f0, 23086), (0xe2f1, 23087), (0xe2f2, 23088), (0xe2f3, 23089), (0xe2f4, 23090),
+    (0xe2f5, 23091), (0xe2f6, 23092), (0xe2f7, 23093), (0xe2f8, 23094), (0xe2f9, 23095), (0xe2fa, 23096), (0xe2fb, 23097), (0xe2fc, 23098),
+    (0xe2fd, 23099), (0xe2fe, 23100), (0xe2ff, 23101), (0xe300, 23102), (0xe301, 23103), (0xe302, 23104), (0xe303, 23105), (0xe304, 23106),
+    (0xe305, 23107), (0xe306, 23108), (0xe307, 23109), (0xe308, 23110), (0xe309, 23111), (0xe30a, 23112), (0xe30b, 23113), (0xe30c, 23114),
+    (0xe30d, 23115), (0xe30e, 23116), (0xe30f, 23117), (0xe310, 23118), (0xe311, 23119), (0xe312, 23120), (0xe313, 23121), (0xe314, 23122),
+    (0xe315, 23123), (0xe316, 23124), (0xe317, 23125), (0xe318, 23126), (0xe319, 23127), (0xe31a, 23128), (0xe31b, 23129), (0xe31c, 23130),
+    (0xe31d, 23131), (0xe31e, 23132), (0xe31f, 23133), (0xe320, 23134), (0xe321, 23135), (0xe322, 23136), (0xe323, 23137), (0xe324, 23138),
+    (0xe325, 23139), (0xe326, 23140), (0xe327, 23141), (0xe328, 23142), (0xe329, 23143), (0xe32a, 23144), (0xe32b, 23145), (0xe32c, 23146),
+    (0xe32d, 23147), (0xe32e, 23148), (0xe32f, 23149), (0xe330, 23150), (0xe331, 23151), (0xe332, 23152), (0xe333, 23153), (0xe334, 23154),
+    (0xe335, 23155), (0xe336, 23156), (0xe337, 23157), (0xe338, 23158), (0xe339, 23159), (0xe33a, 23160), (0xe33b, 23161), (0xe33c, 23162),
+    (0xe33d, 23163), (0xe33e, 23164), (0xe33f, 23165), (0xe340, 23166), (0xe341, 23167), (0xe342, 23168), (0xe343, 23169), (0xe344, 23170),
+    (0xe345, 23171), (0xe346, 23172), (0xe347, 23173), (0xe348, 23174), (0xe349, 23175), (0xe34a, 23176), (0xe34b, 23177), (0xe34c, 23178),
+    (0xe34d, 23179), (0xe34e, 23276), (0xe34f, 23277), (0xe350, 23278), (0xe351, 23279), (0xe352, 23280), (0xe353, 23281), (0xe354, 23282),
+    (0xe355, 23283), (0xe356, 23284), (0xe357, 23285), (0xe358, 23286), (0xe359, 23287), (0xe35a, 23288), (0xe35b, 23289), (0xe35c, 23290),
+    (0xe35d, 23291), (0xe35e, 23292), (0xe35f, 23293), (0xe360, 23294), (0xe361, 23295), (0xe362, 23296), (0xe363, 23297), (0xe364, 23298),
+    (0xe365, 23299), (0xe366, 23300), (0xe367, 23301), (0xe368, 23302), (0xe369, 23303), (0xe36a, 23304), (0xe36b, 23305), (0xe36c, 23306),
+    (0xe36d, 23307), (0xe36e, 23308), (0xe36f, 23309), (0xe370, 23310), (0xe371, 23311), (0xe372, 23312), (0xe373, 23313), (0xe374, 23314),
+    (0xe375, 23315), (0xe376, 23316), (0xe377, 23317), (0xe378, 23318), (0xe379, 23319), (0xe37a, 23320), (0xe37b, 23321), (0xe37c, 23322),
+    (0xe37d, 23323), (0xe37e, 23324), (0xe37f, 23325), (0xe380, 23326), (0xe381, 23327), (0xe382, 23328), (0xe383, 23329), (0xe384, 23330),
+    (0xe385, 23331), (0xe386, 23332), (0xe387, 23333), (0xe388, 23334), (0xe389, 23335), (0xe38a, 23336), (0xe38b, 23337), (0xe38c, 23338),
+    (0xe38d, 23339), (0xe38e, 23340), (0xe38f, 23341), (0xe390, 23342), (0xe391, 23343), (0xe392, 23344), (0xe393, 23345), (0xe394, 23346),
+    (0xe395, 23347), (0xe396, 23348), (0xe397, 23349), (0xe398, 23350), (0xe399, 23351), (0xe39a, 23352), (0xe39b, 23353), (0xe39c, 23354),
+    (0xe39d, 23355), (0xe39e, 23356), (0xe39f, 23357), (0xe3a0, 23358), (0xe3a1, 23359), (0xe3a2, 23360), (0xe3a3, 23361), (0xe3a4, 23362),
+    (0xe3a5, 23363), (0xe3a6, 23364), (0xe3a7, 23365), (0xe3a8, 23366), (0xe3a9, 23367), (0xe3aa, 23368), (0xe3ab, 23369), (0xe3ac, 23466),
+    (0xe3ad, 23467), (0xe3ae, 23468), (0xe3af, 23469), (0xe3b0, 23470), (0xe3b1, 23471), (0xe3b2, 23472), (0xe3b3, 23473), (0xe3b4, 23474),
+    (0xe3b5, 23475), (0xe3b6, 23476), (0xe3b7, 23477), (0xe3b8, 23478), (0xe3b9, 23479), (0xe3ba, 23480), (0xe3bb, 23481), (0xe3bc, 23482),
+    (0xe3bd, 23483), (0xe3be, 23484), (0xe3bf, 23485), (0xe3c0, 23486), (0xe3c1, 23487), (0xe3c2, 23488), (0xe3c3, 23489), (0xe3c4, 23490),
+    (0xe3c5, 23491), (0xe3c6, 23492), (0xe3c7, 23493), (0xe3c8, 23494), (0xe3c9, 23495), (0xe3ca, 23496), (0xe3cb, 23497), (0xe3cc, 23498),
+    (0xe3cd, 23499), (0xe3ce, 23500), (0xe3cf, 23501), (0xe3d0, 23502), (0xe3d1, 23503), (0xe3d2, 23504), (0xe3d3, 23505), (0xe3d4, 23506),
+    (0xe3d5, 23507), (0xe3d6, 23508), (0xe3d7, 23509), (0xe3d8, 23510), (0xe3d9, 23511), (0xe3da, 23512), (0xe3db, 23513), (0xe3dc, 23514),
+    (0xe3dd, 23515), (0xe3de, 23516), (0xe3df, 23517), (0xe3e0, 23518), (0xe3e1, 23519), (0xe3e2, 23520), (0xe3e3, 23521), (0xe3e4, 23522),
+    (0xe3e5, 23523), (0xe3e6, 23524), (0xe3e7, 23525), (0xe3e8, 23526), (0xe3e9, 23527), (0xe3ea, 23528), (0xe3eb, 23529), (0xe3ec, 23530),
+    (0xe3ed, 23531), (0xe3ee, 23532), (0xe3ef, 23533), (0xe3f0, 23534), (0xe3f1, 23535), (0xe3f2, 23536), (0xe3f3, 23537), (0xe3f4, 23538),
+    (0xe3f5, 23539), (0xe3f6, 23540), (0xe3f7, 23541), (0xe3f8, 23542), (0xe3f9, 23543), (0xe3fa, 23544), (0xe3fb, 23545), (0xe3fc, 23546),
+    (0xe3fd, 23547), (0xe3fe, 23548), (0xe3ff, 23549), (0xe400, 23550), (0xe401, 23551), (0xe402, 23552), (0xe403, 23553), (0xe404, 23554),
+    (0xe405, 23555), (0xe406, 23556), (0xe407, 23557), (0xe408, 23558), (0xe409, 23559), (0xe40a, 23656), (0xe40b, 23657), (0xe40c, 23658),
+    (0xe40d, 23659), (0xe40e, 23660), (0xe40f, 23661), (0xe410, 23662), (0xe411, 23663), (0xe412, 23664), (0xe413, 23665), (0xe414, 23666),
+    (0xe415, 23667), (0xe416, 23668), (0xe417, 23669), (0xe418, 23670), (0xe419, 23671), (0xe41a, 23672), (0xe41b, 23673), (0xe41c, 23674),
+    (0xe41d, 23675), (0xe41e, 23676), (0xe41f, 23677), (0xe420, 23678), (0xe421, 23679), (0xe422, 23680), (0xe423, 23681), (0xe424, 23682),
+    (0xe425, 23683), (0xe426, 23684), (0xe427, 23685), (0xe428, 23686), (0xe429, 23687), (0xe42a, 23688), (0xe42b, 23689), (0xe42c, 23690),
+    (0xe42d, 23691), (0xe42e, 23692), (0xe42f, 23693), (0xe430, 23694), (0xe431, 23695), (0xe432, 23696), (0xe433, 23697), (0xe434, 23698),
+    (0xe435, 23699), (0xe436, 23700), (0xe437, 23701), (0xe438, 23702), (0xe439, 23703), (0xe43a, 23704), (0xe43b, 23705), (0xe43c, 23706),
+    (0xe43d, 23707), (0xe43e, 23708), (0xe43f, 23709), (0xe440, 23710), (0xe441, 23711), (0xe442, 23712), (0xe443, 23713), (0xe444, 23714),
+    (0xe445, 23715), (0xe446, 23716), (0xe447, 23717), (0xe448, 23718), (0xe449, 23719), (0xe44a, 23720), (0xe44b, 23721), (0xe44c, 23722),
+    (0xe44d, 23723), (0xe44e, 23724), (0xe44f, 23725), (0xe450, 23726), (0xe451, 23727), (0xe452, 23728), (0xe453, 23729), (0xe454, 23730),
+    (0xe455, 23731), (0xe456, 23732), (0xe457, 23733), (0xe458, 23734), (0xe459, 23735), (0xe45a, 23736), (0xe45b, 23737), (0xe45c, 23738),
+    (0xe45d, 23739), (0xe45e, 23740), (0xe45f, 23741), (0xe460, 23742), (0xe461, 23743), (0xe462, 23744), (0xe463, 23745), (0xe464, 23746),
+    (0xe465, 23747), (0xe466, 23748), (0xe467, 23749), (0xe468, 23846), (0xe469, 23847), (0xe46a, 23848), (0xe46b, 23849), (0xe46c, 23850),
+    (0xe46d, 23851), (0xe46e, 23852), (0xe46f, 23853), (0xe470, 23854), (0xe471, 23855), (0xe472, 23856), (0xe473, 23857), (0xe474, 23858),
+    (0xe475, 23859), (0xe476, 23860), (0xe477, 23861), (0xe478, 23862), (0xe479, 23863), (0xe47a, 23864), (0xe47b, 23865), (0xe47c, 23866),
+    (0xe47d, 23867), (0xe47e, 23868), (0xe47f, 23869), (0xe480, 23870), (0xe481, 23871), (0xe482, 23872), (0xe483, 23873), (0xe484, 23874),
+    (0xe485, 23875), (0xe486, 23876), (0xe487, 23877), (0xe488, 23878), (0xe489, 23879), (0xe48a, 23880), (0xe48b, 23881), (0xe48c, 23882),
+    (0xe48d, 23883), (0xe48e, 23884), (0xe48f, 23885), (0xe490, 23886), (0xe491, 23887), (0xe492, 23888), (0xe493, 23889), (0xe494, 23890),
+    (0xe495, 23891), (0xe496, 23892), (0xe497, 23893), (0xe498, 23894), (0xe499, 23895), (0xe49a, 23896), (0xe49b, 23897), (0xe49c, 23898),
+    (0xe49d, 23899), (0xe49e, 23900), (0xe49f, 23901), (0xe4a0, 23902), (0xe4a1, 23903), (0xe4a2, 23904), (0xe4a3, 23905), (0xe4a4, 23906),
+    (0xe4a5, 23907), (0xe4a6, 23908), (0xe4a7, 23909), (0xe4a8, 23910), (0xe4a9, 23911), (0xe4aa, 23912), (0xe4ab, 23913), (0xe4ac, 23914),
+    (0xe4ad, 23915), (0xe4ae, 23916), (0xe4af, 23917), (0xe4b0, 23918), (0xe4b1, 23919), (0xe4b2, 23920), (0xe4b3, 23921), (0xe4b4, 23922),
+    (0xe4b5, 23923), (0xe4b6, 23924), (0xe4b7, 23925), (0xe4b8, 23926), (0xe4b9, 23927), (0xe4ba, 23928), (0xe4bb, 23929), (0xe4bc, 23930),
+    (0xe4bd, 23931), (0xe4be, 23932), (0xe4bf, 23933), (0xe4c0, 23934), (0xe4c1, 23935), (0xe4c2, 23936), (0xe4c3, 23937), (0xe4c4, 23938),
+    (0xe4c5, 23939), (0xe4c6, 6080), (0xe4c7, 6081), (0xe4c8, 6082), (0xe4c9, 6083), (0xe4ca, 6084), (0xe4cb, 6085), (0xe4cc, 6086),
+    (0xe4cd, 6087), (0xe4ce, 6088), (0xe4cf, 6089), (0xe4d0, 6090), (0xe4d1, 6091), (0xe4d2, 6092), (0xe4d3, 6093), (0xe4d4, 6094),
+    (0xe4d5, 6095), (0xe4d6, 6096), (0xe4d7, 6097), (0xe4d8, 6098), (0xe4d9, 6099), (0xe4da, 6100), (0xe4db, 6101), (0xe4dc, 6102),
+    (0xe4dd, 6103), (0xe4de, 6104), (0xe4df, 6105), (0xe4e0, 6106), (0xe4e1, 6107), (0xe4e2, 6108), (0xe4e3, 6109), (0xe4e4, 6110),
+    (0xe4e5, 6111), (0xe4e6, 6112), (0xe4e7, 6113), (0xe4e8, 6114), (0xe4e9, 6115), (0xe4ea, 6116), (0xe4eb, 6117), (0xe4ec, 6118),
+    (0xe4ed, 6119), (0xe4ee, 6120), (0xe4ef, 6121), (0xe4f0, 6122), (0xe4f1, 6123), (0xe4f2, 6124), (0xe4f3, 6125), (0xe4f4, 6126),
+    (0xe4f5, 6127), (0xe4f6, 6128), (0xe4f7, 6129), (0xe4f8, 6130), (0xe4f9, 6131), (0xe4fa, 6132), (0xe4fb, 6133), (0xe4fc, 6134),
+    (0xe4fd, 6135), (0xe4fe, 6136), (0xe4ff, 6137), (0xe500, 6138), (0xe501, 6139), (0xe502, 6140), (0xe503, 6141), (0xe504, 6142),
+    (0xe505, 6143), (0xe506, 6144), (0xe507, 6145), (0xe508, 6146), (0xe509, 6147), (0xe50a, 6148), (0xe50b, 6149), (0xe50c, 6150),
+    (0xe50d, 6151), (0xe50e, 6152), (0xe50f, 6153), (0xe510, 6154), (0xe511, 6155), (0xe512, 6156), (0xe513, 6157), (0xe514, 6158),
+    (0xe515, 6159), (0xe516, 6160), (0xe517, 6161), (0xe518, 6162), (0xe519, 6163), (0xe51a, 6164), (0xe51b, 6165), (0xe51c, 6166),
+    (0xe51d, 6167), (0xe51e, 6168), (0xe51f, 6169), (0xe520, 6170), (0xe521, 6171), (0xe522, 6172), (0xe523, 6173), (0xe524, 6174),
+    (0xe525, 6175), (0xe526, 6270), (0xe527, 6271), (0xe528, 6272), (0xe529, 6273), (0xe52a, 6274), (0xe52b, 6275), (0xe52c, 6276),
+    (0xe52d, 6277), (0xe52e, 6278), (0xe52f, 6279), (0xe530, 6280), (0xe531, 6281), (0xe532, 6282), (0xe533, 6283), (0xe534, 6284),
+    (0xe535, 6285), (0xe536, 6286), (0xe537, 6287), (0xe538, 6288), (0xe539, 6289), (0xe53a, 6290), (0xe53b, 6291), (0xe53c, 6292),
+    (0xe53d, 6293), (0xe53e, 6294), (0xe53f, 6295), (0xe540, 6296), (0xe541, 6297), (0xe542, 6298), (0xe543, 6299), (0xe544, 6300),
+    (0xe545, 6301), (0xe546, 6302), (0xe547, 6303), (0xe548, 6304), (0xe549, 6305), (0xe54a, 6306), (0xe54b, 6307), (0xe54c, 6308),
+    (0xe54d, 6309), (0xe54e, 6310), (0xe54f, 6311), (0xe550, 6312), (0xe551, 6313), (0xe552, 6314), (0xe553, 6315), (0xe554, 6316),
+    (0xe555, 6317), (0xe556, 6318), (0xe557, 6319), (0xe558, 6320), (0xe559, 6321), (0xe55a, 6322), (0xe55b, 6323), (0xe55c, 6324),
+    (0xe55d, 6325), (0xe55e, 6326), (0xe55f, 6327), (0xe560, 6328), (0xe561, 6329), (0xe562, 6330), (0xe563, 6331), (0xe564, 6332),
+    (0xe565, 6333), (0xe566, 6334), (0xe567, 6335), (0xe568, 6336), (0xe569, 6337), (0xe56a, 6338), (0xe56b, 6339), (0xe56c, 6340),
+    (0xe56d, 6341), (0xe56e, 6342), (0xe56f, 6343), (0xe570, 6344), (0xe571, 6345), (0xe572, 6346), (0xe573, 6347), (0xe574, 6348),
+    (0xe575, 6349), (0xe576, 6350), (0xe577, 6351), (0xe578, 6352), (0xe579, 6353), (0xe57a, 6354), (0xe57b, 6355), (0xe57c, 6356),
+    (0xe57d, 6357), (0xe57e, 6358), (0xe57f, 6359), (0xe580, 6360), (0xe581, 6361), (0xe582, 6362), (0xe583, 6363), (0xe584, 6364),
+    (0xe585, 6365), (0xe586, 6460), (0xe587, 6461), (0xe588, 6462), (0xe589, 6463), (0xe58a, 6464), (0xe58b, 6465), (0xe58c, 6466),
+    (0xe58d, 6467), (0xe58e, 6468), (0xe58f, 6469), (0xe590, 6470), (0xe591, 6471), (0xe592, 6472), (0xe593, 6473), (0xe594, 6474),
+    (0xe595, 6475), (0xe596, 6476), (0xe597, 6477), (0xe598, 6478), (0xe599, 6479), (0xe59a, 6480), (0xe59b, 6481), (0xe59c, 6482),
+    (0xe59d, 6483), (0xe59e, 6484), (0xe59f, 6485), (0xe5a0, 6486), (0xe5a1, 6487), (0xe5a2, 6488), (0xe5a3, 6489), (0xe5a4, 6490),
+    (0xe5a5, 6491), (0xe5a6, 6492), (0xe5a7, 6493), (0xe5a8, 6494), (0xe5a9, 6495), (0xe5aa, 6496), (0xe5ab, 6497), (0xe5ac, 6498),
+    (0xe5ad, 6499), (0xe5ae, 6500), (0xe5af, 6501), (0xe5b0, 6502), (0xe5b1, 6503), (0xe5b2, 6504), (0xe5b3, 6505), (0xe5b4, 6506),
+    (0xe5b5, 6507), (0xe5b6, 6508), (0xe5b7, 6509), (0xe5b8, 6510), (0xe5b9, 6511), (0xe5ba, 6512), (0xe5bb, 6513), (0xe5bc, 6514),
+    (0xe5bd, 6515), (0xe5be, 6516), (0xe5bf, 6517), (0xe5c0, 6518), (0xe5c1, 6519), (0xe5c2, 6520), (0xe5c3, 6521), (0xe5c4, 6522),
+    (0xe5c5, 6523), (0xe5c6, 6524), (0xe5c7, 6525), (0xe5c8, 6526), (0xe5c9, 6527), (0xe5ca, 6528), (0xe5cb, 6529), (0xe5cc, 6530),
+    (0xe5cd, 6531), (0xe5ce, 6532), (0xe5cf, 6533), (0xe5d0, 6534), (0xe5d1, 6535), (0xe5d2, 6536), (0xe5d3, 6537), (0xe5d4, 6538),
+    (0xe5d5, 6539), (0xe5d6, 6540), (0xe5d7, 6541), (0xe5d8, 6542), (0xe5d9, 6543), (0xe5da, 6544), (0xe5db, 6545), (0xe5dc, 6546),
+    (0xe5dd, 6547), (0xe5de, 6548), (0xe5df, 6549), (0xe5e0, 6550), (0xe5e1, 6551), (0xe5e2, 6552), (0xe5e3, 6553), (0xe5e4, 6554),
+    (0xe5e5, 6555), (0xe5e6, 6650), (0xe5e7, 6651), (0xe5e8, 6652), (0xe5e9, 6653), (0xe5ea, 6654), (0xe5eb, 6655), (0xe5ec, 6656),
+    (0xe5ed, 6657), (0xe5ee, 6658), (0xe5ef, 6659), (0xe5f0, 6660), (0xe5f1, 6661), (0xe5f2, 6662), (0xe5f3, 6663), (0xe5f4, 6664),
+    (0xe5f5, 6665), (0xe5f6, 6666), (0xe5f7, 6667), (0xe5f8, 6668), (0xe5f9, 6669), (0xe5fa, 6670), (0xe5fb, 6671), (0xe5fc, 6672),
+    (0xe5fd, 6673), (0xe5fe, 6674), (0xe5ff, 6675), (0xe600, 6676), (0xe601, 6677), (0xe602, 6678), (0xe603, 6679), (0xe604, 6680),
+    (0xe605, 6681), (0xe606, 6682), (0xe607, 6683), (0xe608, 6684), (0xe609, 6685), (0xe60a, 6686), (0xe60b, 6687), (0xe60c, 6688),
+    (0xe60d, 6689), (0xe60e, 6690), (0xe60f, 6691), (0xe610, 6692), (0xe611, 6693), (0xe612, 6694), (0xe613, 6695), (0xe614, 6696),
+    (0xe615, 6697), (0xe616, 6698), (0xe617, 6699), (0xe618, 6700), (0xe619, 6701), (0xe61a, 6702), (0xe61b, 6703), (0xe61c, 6704),
+    (0xe61d, 6705), (0xe61e, 6706), (0xe61f, 6707), (0xe620, 6708), (0xe621, 6709), (0xe622, 6710), (0xe623, 6711), (0xe624, 6712),
+    (0xe625, 6713), (0xe626, 6714), (0xe627, 6715), (0xe628, 6716), (0xe629, 6717), (0xe62a, 6718), (0xe62b, 6719), (0xe62c, 6720),
+    (0xe62d, 6721), (0xe62e, 6722), (0xe62f, 6723), (0xe630, 6724), (0xe631, 6725), (0xe632, 6726), (0xe633, 6727), (0xe634, 6728),
+    (0xe635, 6729), (0xe636, 6730), (0xe637, 6731), (0xe638, 6732), (0xe639, 6733), (0xe63a, 6734), (0xe63b, 6735), (0xe63c, 6736),
+    (0xe63d, 6737), (0xe63e, 6738), (0xe63f, 6739), (0xe640, 6740), (0xe641, 6741), (0xe642, 6742), (0xe643, 6743), (0xe644, 6744),
+    (0xe645, 6745), (0xe646, 6840), (0xe647, 6841), (0xe648, 6842), (0xe649, 6843), (0xe64a, 6844), (0xe64b, 6845), (0xe64c, 6846),
+    (0xe64d, 6847), (0xe64e, 6848), (0xe64f, 6849), (0xe650, 6850), (0xe651, 6851), (0xe652, 6852), (0xe653, 6853), (0xe654, 6854),
+    (0xe655, 6855), (0xe656, 6856), (0xe657, 6857), (0xe658, 6858), (0xe659, 6859), (0xe65a, 6860), (0xe65b, 6861), (0xe65c, 6862),
+    (0xe65d, 6863), (0xe65e, 6864), (0xe65f, 6865), (0xe660, 6866), (0xe661, 6867), (0xe662, 6868), (0xe663, 6869), (0xe664, 6870),
+    (0xe665, 6871), (0xe666, 6872), (0xe667, 6873), (0xe668, 6874), (0xe669, 6875), (0xe66a, 6876), (0xe66b, 6877), (0xe66c, 6878),
+    (0xe66d, 6879), (0xe66e, 6880), (0xe66f, 6881), (0xe670, 6882), (0xe671, 6883), (0xe672, 6884), (0xe673, 6885), (0xe674, 6886),
+    (0xe675, 6887), (0xe676, 6888), (0xe677, 6889), (0xe678, 6890), (0xe679, 6891), (0xe67a, 6892), (0xe67b, 6893), (0xe67c, 6894),
+    (0xe67d, 6895), (0xe67e, 6896), (0xe67f, 6897), (0xe680, 6898), (0xe681, 6899), (0xe682, 6900), (0xe683, 6901), (0xe684, 6902),
+    (0xe685, 6903), (0xe686, 6904), (0xe687, 6905), (0xe688, 6906), (0xe689, 6907), (0xe68a, 6908), (0xe68b, 6909), (0xe68c, 6910),
+    (0xe68d, 6911), (0xe68e, 6912), (0xe68f, 6913), (0xe690, 6914), (0xe691, 6915), (0xe692, 6916), (0xe693, 6917), (0xe694, 6918),
+    (0xe695, 6919), (0xe696, 6920), (0xe697, 6921), (0xe698, 6922), (0xe699, 6923), (0xe69a, 6924), (0xe69b, 6925), (0xe69c, 6926),
+    (0xe69d, 6927), (0xe69e, 6928), (0xe69f, 6929), (0xe6a0, 6930), (0xe6a1, 6931), (0xe6a2, 6932), (0xe6a3, 6933), (0xe6a4, 6934),
+    (0xe6a5, 6935), (0xe6a6, 7030), (0xe6a7, 7031), (0xe6a8, 7032), (0xe6a9, 7033), (0xe6aa, 7034), (0xe6ab, 7035), (0xe6ac, 7036),
+    (0xe6ad, 7037), (0xe6ae, 7038), (0xe6af, 7039), (0xe6b0, 7040), (0xe6b1, 7041), (0xe6b2, 7042), (0xe6b3, 7043), (0xe6b4, 7044),
+    (0xe6b5, 7045), (0xe6b6, 7046), (0xe6b7, 7047), (0xe6b8, 7048), (0xe6b9, 7049), (0xe6ba, 7050), (0xe6bb, 7051), (0xe6bc, 7052),
+    (0xe6bd, 7053), (0xe6be, 7054), (0xe6bf, 7055), (0xe6c0, 7056), (0xe6c1, 7057), (0xe6c2, 7058), (0xe6c3, 7059), (0xe6c4, 7060),
+    (0xe6c5, 7061), (0xe6c6, 7062), (0xe6c7, 7063), (0xe6c8, 7064), (0xe6c9, 7065), (0xe6ca, 7066), (0xe6cb, 7067), (0xe6cc, 7068),
+    (0xe6cd, 7069), (0xe6ce, 7070), (0xe6cf, 7071), (0xe6d0, 7072), (0xe6d1, 7073), (0xe6d2, 7074), (0xe6d3, 7075), (0xe6d4, 7076),
+    (0xe6d5, 7077), (0xe6d6, 7078), (0xe6d7, 7079), (0xe6d8, 7080), (0xe6d9, 7081), (0xe6da, 7082), (0xe6db, 7083), (0xe6dc, 7084),
+    (0xe6dd, 7085), (0xe6de, 7086), (0xe6df, 7087), (0xe6e0, 7088), (0xe6e1, 7089), (0xe6e2, 7090), (0xe6e3, 7091), (0xe6e4, 7092),
+    (0xe6e5, 7093), (0xe6e6, 7094), (0xe6e7, 7095), (0xe6e8, 7096), (0xe6e9, 7097), (0xe6ea, 7098), (0xe6eb, 7099), (0xe6ec, 7100),
+    (0xe6ed, 7101), (0xe6ee, 7102), (0xe6ef, 7103), (0xe6f0, 7104), (0xe6f1, 7105), (0xe6f2, 7106), (0xe6f3, 7107), (0xe6f4, 7108),
+    (0xe6f5, 7109), (0xe6f6, 7110), (0xe6f7, 7111), (0xe6f8, 7112), (0xe6f9, 7113), (0xe6fa, 7114), (0xe6fb, 7115), (0xe6fc, 7116),
+    (0xe6fd, 7117), (0xe6fe, 7118), (0xe6ff, 7119), (0xe700, 7120), (0xe701, 7121), (0xe702, 7122), (0xe703, 7123), (0xe704, 7124),
+    (0xe705, 7125), (0xe706, 7220), (0xe707, 7221), (0xe708, 7222), (0xe709, 7223), (0xe70a, 7224), (0xe70b, 7225), (0xe70c, 7226),
+    (0xe70d, 7227), (0xe70e, 7228), (0xe70f, 7229), (0xe710, 7230), (0xe711, 7231), (0xe712, 7232), (0xe713, 7233), (0xe714, 7234),
+    (0xe715, 7235), (0xe716, 7236), (0xe717, 7237), (0xe718, 7238), (0xe719, 7239), (0xe71a, 7240), (0xe71b, 7241), (0xe71c, 7242),
+    (0xe71d, 7243), (0xe71e, 7244), (0xe71f, 7245), (0xe720, 7246), (0xe721, 7247), (0xe722, 7248), (0xe723, 7249), (0xe724, 7250),
+    (0xe725, 7251), (0xe726, 7252), (0xe727, 7253), (0xe728, 7254), (0xe729, 7255), (0xe72a, 7256), (0xe72b, 7257), (0xe72c, 7258),
+    (0xe72d, 7259), (0xe72e, 7260), (0xe72f, 7261), (0xe730, 7262), (0xe731, 7263), (0xe732, 7264), (0xe733, 7265), (0xe734, 7266),
+    (0xe735, 7267), (0xe736, 7268), (0xe737, 7269), (0xe738, 7270), (0xe739, 7271), (0xe73a, 7272), (0xe73b, 7273), (0xe73c, 7274),
+    (0xe73d, 7275), (0xe73e, 7276), (0xe73f, 7277), (0xe740, 7278), (0xe741, 7279), (0xe742, 7280), (0xe743, 7281), (0xe744, 7282),
+    (0xe745, 7283), (0xe746, 7284), (0xe747, 7285), (0xe748, 7286), (0xe749, 7287), (0xe74a, 7288), (0xe74b, 7289), (0xe74c, 7290),
+    (0xe74d, 7291), (0xe74e, 7292), (0xe74f, 7293), (0xe750, 7294), (0xe751, 7295), (0xe752, 7296), (0xe753, 7297), (0xe754, 7298),
+    (0xe755, 7299), (0xe756, 7300), (0xe757, 7301), (0xe758, 7302), (0xe759, 7303), (0xe75a, 7304), (0xe75b, 7305), (0xe75c, 7306),
+    (0xe75d, 7307), (0xe75e, 7308), (0xe75f, 7309), (0xe760, 7310), (0xe761, 7311), (0xe762, 7312), (0xe763, 7313), (0xe764, 7314),
+    (0xe765, 7315), (0xe766, 6376), (0xe767, 6377), (0xe768, 6378), (0xe769, 6379), (0xe76a, 6380), (0xe76b, 6381), (0xe76d, 6433),
+    (0xe76e, 6444), (0xe76f, 6445), (0xe770, 6458), (0xe771, 6459), (0xe772, 6829), (0xe773, 6830), (0xe774, 6831), (0xe775, 6832),
+    (0xe776, 6833), (0xe777, 6834), (0xe778, 6835), (0xe779, 6836), (0xe77a, 6837), (0xe77b, 6838), (0xe77c, 6839), (0xe77d, 7022),
+    (0xe77e, 7023), (0xe77f, 7024), (0xe780, 7025), (0xe781, 7026), (0xe782, 7027), (0xe783, 7028), (0xe784, 7029), (0xe785, 7150),
+    (0xe786, 7151), (0xe787, 7152), (0xe788, 7153), (0xe789, 7154), (0xe78a, 7155), (0xe78b, 7156), (0xe78c, 7157), (0xe78d, 7182),
+    (0xe78e, 7183), (0xe78f, 7184), (0xe790, 7185), (0xe791, 7186), (0xe792, 7187), (0xe793, 7188), (0xe794, 7201), (0xe795, 7202),
+    (0xe796, 7208), (0xe797, 7211), (0xe798, 7212), (0xe799, 7213), (0xe79a, 7214), (0xe79b, 7215), (0xe79c, 7216), (0xe79d, 7217),
+    (0xe79e, 7218), (0xe79f, 7219), (0xe7a0, 7349), (0xe7a1, 7350), (0xe7a2, 7351), (0xe7a3, 7352), (0xe7a4, 7353), (0xe7a5, 7354),
+    (0xe7a6, 7355), (0xe7a7, 7356), (0xe7a8, 7357), (0xe7a9, 7358), (0xe7aa, 7359), (0xe7ab, 7360), (0xe7ac, 7361), (0xe7ad, 7362),
+    (0xe7ae, 7363), (0xe7af, 7397), (0xe7b0, 7398), (0xe7b1, 7399), (0xe7b2, 7400), (0xe7b3, 7401), (0xe7b4, 7402), (0xe7b5, 7403),
+    (0xe7b6, 7404), (0xe7b7, 7405), (0xe7b8, 7406), (0xe7b9, 7407), (0xe7ba, 7408), (0xe7bb, 7409), (0xe7bc, 7495), (0xe7bd, 7496),
+    (0xe7be, 7497), (0xe7bf, 7498), (0xe7c0, 7499), (0xe7c1, 7500), (0xe7c2, 7501), (0xe7c3, 7502), (0xe7c4, 7503), (0xe7c5, 7504),
+    (0xe7c6, 7505), (0xe7c7, 7533), (0xe7c9, 7538), (0xe7ca, 7539), (0xe7cb, 7540), (0xe7cc, 7541), (0xe7cd, 7579), (0xe7ce, 7580),
+    (0xe7cf, 7581), (0xe7d0, 7582), (0xe7d1, 7583), (0xe7d2, 7584), (0xe7d3, 7585), (0xe7d4, 7586), (0xe7d5, 7587), (0xe7d6, 7588),
+    (0xe7d7, 7589), (0xe7d8, 7590), (0xe7d9, 7591), (0xe7da, 7592), (0xe7db, 7593), (0xe7dc, 7594), (0xe7dd, 7595), (0xe7de, 7596),
+    (0xe7df, 7597), (0xe7e0, 7598), (0xe7e1, 7599), (0xe7e2, 7624), (0xe7e3, 7627), (0xe7e4, 7629), (0xe7e5, 7630), (0xe7e6, 7631),
+    (0xe7f4, 7686), (0xe7f5, 7687), (0xe7f6, 7688), (0xe7f7, 7689), (0xe7f8, 7690), (0xe7f9, 7691), (0xe7fa, 7692), (0xe7fb, 7693),
+    (0xe7fc, 7694), (0xe7fd, 7695), (0xe7fe, 7696), (0xe7ff, 7697), (0xe800, 7698), (0xe801, 7775), (0xe802, 7776), (0xe803, 7777),
+    (0xe804, 7778), (0xe805, 7779), (0xe806, 7780), (0xe807, 7781), (0xe808, 7782), (0xe809, 7783), (0xe80a, 7784), (0xe80b, 7785),
+    (0xe80c, 7786), (0xe80d, 7787), (0xe80e, 7788), (0xe80f, 7789), (0xe810, 16525), (0xe811, 16526), (0xe812, 16527), (0xe813, 16528),
+    (0xe814, 16529), (0xe816, 23767), (0xe817, 23768), (0xe818, 23769), (0xe81e, 23775), (0xe826, 23783), (0xe82b, 23788), (0xe82c, 23789),
+    (0xe831, 23794), (0xe832, 23795), (0xe83b, 23804), (0xe843, 23812), (0xe854, 23829), (0xe855, 23830), (0xe864, 23845), (0xf92c, 23651),
+    (0xf979, 23652), (0xf995, 23653), (0xf9e7, 23654), (0xf9f1, 23655), (0xfa0c, 23750), (0xfa0d, 23751), (0xfa0e, 23752), (0xfa0f, 23753),
+    (0xfa11, 23754), (0xfa13, 23755), (0xfa14, 23756), (0xfa18, 23757), (0xfa1f, 23758), (0xfa20, 23759), (0xfa21, 23760), (0xfa23, 23761),
+    (0xfa24, 23762), (0xfa27, 23763), (0xfa28, 23764), (0xfa29, 23765), (0xfe30, 7621), (0xfe31, 7207), (0xfe33, 7209), (0xfe34, 7210),
+    (0xfe35, 7189), (0xfe36, 7190), (0xfe37, 7205), (0xfe38, 7206), (0xfe39, 7191), (0xfe3a, 7192), (0xfe3b, 7203), (0xfe3c, 7204),
+    (0xfe3d, 7195), (0xfe3e, 7196), (0xfe3f, 7193), (0xfe40, 7194), (0xfe41, 7197), (0xfe42, 7198), (0xfe43, 7199), (0xfe44, 7200),
+    (0xfe49, 7640), (0xfe4a, 7641), (0xfe4b, 7642), (0xfe4c, 7643), (0xfe4d, 7644), (0xfe4e, 7645), (0xfe4f, 7646), (0xfe50, 7647),
+    (0xfe51, 7648), (0xfe52, 7649), (0xfe54, 7650), (0xfe55, 7651), (0xfe56, 7652), (0xfe57, 7653), (0xfe59, 7654), (0xfe5a, 7655),
+    (0xfe5b, 7656), (0xfe5c, 7657), (0xfe5d, 7658), (0xfe5e, 7659), (0xfe5f, 7660), (0xfe60, 7661), (0xfe61, 7662), (0xfe62, 7663),
+    (0xfe63, 7664), (0xfe64, 7665), (0xfe65, 7666), (0xfe66, 7667), (0xfe68, 7668), (0xfe69, 7669), (0xfe6a, 7670), (0xfe6b, 7671),
+    (0xff01, 6556), (0xff02, 6557), (0xff03, 6558), (0xff04, 6246), (0xff05, 6560), (0xff06, 6561), (0xff07, 6562), (0xff08, 6563),
+    (0xff09, 6564), (0xff0a, 6565), (0xff0b, 6566), (0xff0c, 6567), (0xff0d, 6568), (0xff0e, 6569), (0xff0f, 6570), (0xff10, 6571),
+    (0xff11, 6572), (0xff12, 6573), (0xff13, 6574), (0xff14, 6575), (0xff15, 6576), (0xff16, 6577), (0xff17, 6578), (0xff18, 6579),
+    (0xff19, 6580), (0xff1a, 6581), (0xff1b, 6582), (0xff1c, 6583), (0xff1d, 6584), (0xff1e, 6585), (0xff1f, 6586), (0xff20, 6587),
+    (0xff21, 6588), (0xff22, 6589), (0xff23, 6590), (0xff24, 6591), (0xff25, 6592), (0xff26, 6593), (0xff27, 6594), (0xff28, 6595),
+    (0xff29, 6596), (0xff2a, 6597), (0xff2b, 6598), (0xff2c, 6599), (0xff2d, 6600), (0xff2e, 6601), (0xff2f, 6602), (0xff30, 6603),
+    (0xff31, 6604), (0xff32, 6605), (0xff33, 6606), (0xff34, 6607), (0xff35, 6608), (0xff36, 6609), (0xff37, 6610), (0xff38, 6611),
+    (0xff39, 6612), (0xff3a, 6613), (0xff3b, 6614), (0xff3c, 6615), (0xff3d, 6616), (0xff3e, 6617), (0xff3f, 6618), (0xff40, 6619),
+    (0xff41, 6620), (0xff42, 6621), (0xff43, 6622), (0xff44, 6623), (0xff45, 6624), (0xff46, 6625), (0xff47, 6626), (0xff48, 6627),
+    (0xff49, 6628), (0xff4a, 6629), (0xff4b, 6630), (0xff4c, 6631), (0xff4d, 6632), (0xff4e, 6633), (0xff4f, 6634), (0xff50, 6635),
+    (0xff51, 6636), (0xff52, 6637), (0xff53, 6638), (0xff54, 6639), (0xff55, 6640), (0xff56, 6641), (0xff57, 6642), (0xff58, 6643),
+    (0xff59, 6644), (0xff5a, 6645), (0xff5b, 6646), (0xff5c, 6647), (0xff5d, 6648), (0xff5e, 6186), (0xffe0, 6248), (0xffe1, 6249),
+    (0xffe2, 7622), (0xffe3, 6649), (0xffe4, 7623), (0xffe5, 6559),
+];
+
+/// Pairs of the first pointer and code point of every run of consecutive four-byte characters in the
+/// Basic Multilingual Plane, sorted by both.
+#[rustfmt::skip]
+static FOUR_BYTE_RANGES: [(u16, u16); 206] = [
+    (0, 0x0080), (36, 0x00a5), (38, 0x00a9), (45, 0x00b2), (50, 0x00b8), (81, 0x00d8),
+    (89, 0x00e2), (95, 0x00eb), (96, 0x00ee), (100, 0x00f4), (103, 0x00f8), (104, 0x00fb),
+    (105, 0x00fd), (109, 0x0102), (126, 0x0114), (133, 0x011c), (148, 0x012c), (172, 0x0145),
+    (175, 0x0149), (179, 0x014e), (208, 0x016c), (306, 0x01cf), (307, 0x01d1), (308, 0x01d3),
+    (309, 0x01d5), (310, 0x01d7), (311, 0x01d9), (312, 0x01db), (313, 0x01dd), (341, 0x01fa),
+    (428, 0x0252), (443, 0x0262), (544, 0x02c8), (545, 0x02cc), (558, 0x02da), (741, 0x03a2),
+    (742, 0x03aa), (749, 0x03c2), (750, 0x03ca), (805, 0x0402), (819, 0x0450), (820, 0x0452),
+    (7922, 0x2011), (7924, 0x2017), (7925, 0x201a), (7927, 0x201e), (7934, 0x2027), (7943, 0x2031),
+    (7944, 0x2034), (7945, 0x2036), (7950, 0x203c), (8062, 0x20ad), (8148, 0x2104), (8149, 0x2106),
+    (8152, 0x210a), (8164, 0x2117), (8174, 0x2122), (8236, 0x216c), (8240, 0x217a), (8262, 0x2194),
+    (8264, 0x219a), (8374, 0x2209), (8380, 0x2210), (8381, 0x2212), (8384, 0x2216), (8388, 0x221b),
+    (8390, 0x2221), (8392, 0x2224), (8393, 0x2226), (8394, 0x222c), (8396, 0x222f), (8401, 0x2238),
+    (8406, 0x223e), (8416, 0x2249), (8419, 0x224d), (8424, 0x2253), (8437, 0x2262), (8439, 0x2268),
+    (8445, 0x2270), (8482, 0x2296), (8485, 0x229a), (8496, 0x22a6), (8521, 0x22c0), (8603, 0x2313),
+    (8936, 0x246a), (8946, 0x249c), (9046, 0x254c), (9050, 0x2574), (9063, 0x2590), (9066, 0x2596),
+    (9076, 0x25a2), (9092, 0x25b4), (9100, 0x25be), (9108, 0x25c8), (9111, 0x25cc), (9113, 0x25d0),
+    (9131, 0x25e6), (9162, 0x2607), (9164, 0x260a), (9218, 0x2641), (9219, 0x2643), (11329, 0x2e82),
+    (11331, 0x2e85), (11334, 0x2e89), (11336, 0x2e8d), (11346, 0x2e98), (11361, 0x2ea8), (11363, 0x2eab),
+    (11366, 0x2eaf), (11370, 0x2eb4), (11372, 0x2eb8), (11375, 0x2ebc), (11389, 0x2ecb), (11682, 0x2ffc),
+    (11686, 0x3004), (11687, 0x3018), (11692, 0x301f), (11694, 0x302a), (11714, 0x303f), (11716, 0x3094),
+    (11723, 0x309f), (11725, 0x30f7), (11730, 0x30ff), (11736, 0x312a), (11982, 0x322a), (11989, 0x3232),
+    (12102, 0x32a4), (12336, 0x3390), (12348, 0x339f), (12350, 0x33a2), (12384, 0x33c5), (12393, 0x33cf),
+    (12395, 0x33d3), (12397, 0x33d6), (12510, 0x3448), (12553, 0x3474), (12851, 0x359f), (12962, 0x360f),
+    (12973, 0x361b), (13738, 0x3919), (13823, 0x396f), (13919, 0x39d1), (13933, 0x39e0), (14080, 0x3a74),
+    (14298, 0x3b4f), (14585, 0x3c6f), (14698, 0x3ce1), (15583, 0x4057), (15847, 0x4160), (16318, 0x4338),
+    (16434, 0x43ad), (16438, 0x43b2), (16481, 0x43de), (16729, 0x44d7), (17102, 0x464d), (17122, 0x4662),
+    (17315, 0x4724), (17320, 0x472a), (17402, 0x477d), (17418, 0x478e), (17859, 0x4948), (17909, 0x497b),
+    (17911, 0x497e), (17915, 0x4984), (17916, 0x4987), (17936, 0x499c), (17939, 0x49a0), (17961, 0x49b8),
+    (18664, 0x4c78), (18703, 0x4ca4), (18814, 0x4d1a), (18962, 0x4daf), (19043, 0x9fa6), (33469, 0xe76c),
+    (33470, 0xe7c8), (33471, 0xe7e7), (33484, 0xe815), (33485, 0xe819), (33490, 0xe81f), (33497, 0xe827),
+    (33501, 0xe82d), (33505, 0xe833), (33513, 0xe83c), (33520, 0xe844), (33536, 0xe856), (33550, 0xe865),
+    (37845, 0xf92d), (37921, 0xf97a), (37948, 0xf996), (38029, 0xf9e8), (38038, 0xf9f2), (38064, 0xfa10),
+    (38065, 0xfa12), (38066, 0xfa15), (38069, 0xfa19), (38075, 0xfa22), (38076, 0xfa25), (38078, 0xfa2a),
+    (39108, 0xfe32), (39109, 0xfe45), (39113, 0xfe53), (39114, 0xfe58), (39115, 0xfe67), (39116, 0xfe6c),
+    (39265, 0xff5f), (39394, 0xffe6),
+];
+
+/// The pointer of U+10000, after which the characters of the supplementary planes follow in order.
+const SUPPLEMENTARY_POINTER: u32 = 189_000;
+
+/// Returns the character at the two-byte `pointer`, if any.
+pub(crate) fn decode(pointer: usize) -> Option<char> {
+    match TO_UNICODE.get(pointer) {
+        Some(&0) | None => None,
+        Some(&code_point) => char::from_u32(u32::from(code_point)),
+    }
+}
+
+/// Returns the two-byte pointer of `character`, if it has one.
+pub(crate) fn encode(character: char) -> Option<usize> {
+    let code_point = u16::try_from(u32::from(character)).ok()?;
+
+    FROM_UNICODE
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| usize::from(FROM_UNICODE[index].1))
+}
+
+/// Returns the character at the four-byte `pointer`, if any.
+pub(crate) fn decode_four_byte(pointer: u32) -> Option<char> {
+    if pointer >= SUPPLEMENTARY_POINTER {
+        return char::from_u32(0x10000 + pointer - SUPPLEMENTARY_POINTER);
+    }
+
+    let pointer = u16::try_from(pointer).ok()?;
+    let index = match FOUR_BYTE_RANGES.binary_search_by_key(&pointer, |&(pointer, _)| pointer) {
+        Ok(index) => index,
+        Err(index) => index - 1,
+    };
+    let (first_pointer, first_code_point) = FOUR_BYTE_RANGES[index];
+    let code_point = u32::from(first_code_point) + u32::from(pointer - first_pointer);
+
+    // The last run ends at U+FFFF.
+    if code_point > 0xffff {
+        return None;
+    }
+
+    char::from_u32(code_point)
+}
+
+/// Returns the four-byte pointer of `character`, which must be neither ASCII nor have a two-byte
+/// pointer.
+pub(crate) fn encode_four_byte(character: char) -> u32 {
+    let code_point = u32::from(character);
+
+    if code_point >= 0x10000 {
+        return SUPPLEMENTARY_POINTER + code_point - 0x10000;
+    }
+
+    let code_point = code_point as u16;
+    let index =
+        match FOUR_BYTE_RANGES.binary_search_by_key(&code_point, |&(_, code_point)| code_point) {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        };
+    let (first_pointer, first_code_point) = FOUR_BYTE_RANGES[index];
+    u32::from(first_pointer) + u32::from(code_point - first_code_point)
+}
@@ -0,0 +1,1517 @@
+//! The GB 2312 character set, where the pointer of the character at row `r` and cell `c` is
+//! `(r - 1) * 94 + (c - 1)`.
+
+use std::char;
+use std::convert::TryFrom;
+
+/// The Unicode code point of the character at each pointer, or 0 if the pointer is unassigned.
+#[rustfmt::skip]
+static TO_UNICODE: [u16; 8836] = [
+    0x3000, 0x3001, 0x3002, 0x30fb, 0x02c9, 0x02c7, 0x00a8, 0x3003, 0x3005, 0x2015, 0xff5e, 0x2016, 0x2026, 0x2018, 0x2019, 0x201c,
+    0x201d, 0x3014, 0x3015, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c, 0x300d, 0x300e, 0x300f, 0x3016, 0x3017, 0x3010, 0x3011, 0x00b1,
+    0x00d7, 0x00f7, 0x2236, 0x2227, 0x2228, 0x2211, 0x220f, 0x222a, 0x2229, 0x2208, 0x2237, 0x221a, 0x22a5, 0x2225, 0x2220, 0x2312,
+    0x2299, 0x222b, 0x222e, 0x2261, 0x224c, 0x2248, 0x223d, 0x221d, 0x2260, 0x226e, 0x226f, 0x2264, 0x2265, 0x221e, 0x2235, 0x2234,
+    0x2642, 0x2640, 0x00b0, 0x2032, 0x2033, 0x2103, 0xff04, 0x00a4, 0xffe0, 0xffe1, 0x2030, 0x00a7, 0x2116, 0x2606, 0x2605, 0x25cb,
+    0x25cf, 0x25ce, 0x25c7, 0x25c6, 0x25a1, 0x25a0, 0x25b3, 0x25b2, 0x203b, 0x2192, 0x2190, 0x2191, 0x2193, 0x3013, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2488, 0x2489,
+    0x248a, 0x248b, 0x248c, 0x248d, 0x248e, 0x248f, 0x2490, 0x2491, 0x2492, 0x2493, 0x2494, 0x2495, 0x2496, 0x2497, 0x2498, 0x2499,
+    0x249a, 0x249b, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479, 0x247a, 0x247b, 0x247c, 0x247d, 0x247e, 0x247f, 0x2480, 0x2481,
+    0x2482, 0x2483, 0x2484, 0x2485, 0x2486, 0x2487, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469,
+    0x0000, 0x0000, 0x3220, 0x3221, 0x3222, 0x3223, 0x3224, 0x3225, 0x3226, 0x3227, 0x3228, 0x3229, 0x0000, 0x0000, 0x2160, 0x2161,
+    0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168, 0x2169, 0x216a, 0x216b, 0x0000, 0x0000, 0xff01, 0xff02, 0xff03, 0xffe5,
+    0xff05, 0xff06, 0xff07, 0xff08, 0xff09, 0xff0a, 0xff0b, 0xff0c, 0xff0d, 0xff0e, 0xff0f, 0xff10, 0xff11, 0xff12, 0xff13, 0xff14,
+    0xff15, 0xff16, 0xff17, 0xff18, 0xff19, 0xff1a, 0xff1b, 0xff1c, 0xff1d, 0xff1e, 0xff1f, 0xff20, 0xff21, 0xff22, 0xff23, 0xff24,
+    0xff25, 0xff26, 0xff27, 0xff28, 0xff29, 0xff2a, 0xff2b, 0xff2c, 0xff2d, 0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34,
+    0xff35, 0xff36, 0xff37, 0xff38, 0xff39, 0xff3a, 0xff3b, 0xff3c, 0xff3d, 0xff3e, 0xff3f, 0xff40, 0xff41, 0xff42, 0xff43, 0xff44,
+    0xff45, 0xff46, 0xff47, 0xff48, 0xff49, 0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f, 0xff50, 0xff51, 0xff52, 0xff53, 0xff54,
+    0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a, 0xff5b, 0xff5c, 0xff5d, 0xffe3, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046,
+    0x3047, 0x3048, 0x3049, 0x304a, 0x304b, 0x304c, 0x304d, 0x304e, 0x304f, 0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056,
+    0x3057, 0x3058, 0x3059, 0x305a, 0x305b, 0x305c, 0x305d, 0x305e, 0x305f, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066,
+    0x3067, 0x3068, 0x3069, 0x306a, 0x306b, 0x306c, 0x306d, 0x306e, 0x306f, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074, 0x3075, 0x3076,
+    0x3077, 0x3078, 0x3079, 0x307a, 0x307b, 0x307c, 0x307d, 0x307e, 0x307f, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086,
+    0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c, 0x308d, 0x308e, 0x308f, 0x3090, 0x3091, 0x3092, 0x3093, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x30a1, 0x30a2, 0x30a3, 0x30a4, 0x30a5, 0x30a6, 0x30a7, 0x30a8,
+    0x30a9, 0x30aa, 0x30ab, 0x30ac, 0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1, 0x30b2, 0x30b3, 0x30b4, 0x30b5, 0x30b6, 0x30b7, 0x30b8,
+    0x30b9, 0x30ba, 0x30bb, 0x30bc, 0x30bd, 0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3, 0x30c4, 0x30c5, 0x30c6, 0x30c7, 0x30c8,
+    0x30c9, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4, 0x30d5, 0x30d6, 0x30d7, 0x30d8,
+    0x30d9, 0x30da, 0x30db, 0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2, 0x30e3, 0x30e4, 0x30e5, 0x30e6, 0x30e7, 0x30e8,
+    0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x30f3, 0x30f4, 0x30f5, 0x30f6, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039a,
+    0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03a3, 0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9, 0x03ba,
+    0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0401, 0x0416, 0x0417, 0x0418, 0x0419, 0x041a,
+    0x041b, 0x041c, 0x041d, 0x041e, 0x041f, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042a,
+    0x042b, 0x042c, 0x042d, 0x042e, 0x042f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043a,
+    0x043b, 0x043c, 0x043d, 0x043e, 0x043f, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044a,
+    0x044b, 0x044c, 0x044d, 0x044e, 0x044f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0101, 0x00e1, 0x01ce, 0x00e0, 0x0113, 0x00e9, 0x011b, 0x00e8, 0x012b, 0x00ed, 0x01d0, 0x00ec, 0x014d, 0x00f3,
+    0x01d2, 0x00f2, 0x016b, 0x00fa, 0x01d4, 0x00f9, 0x01d6, 0x01d8, 0x01da, 0x01dc, 0x00fc, 0x00ea, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3105, 0x3106, 0x3107, 0x3108, 0x3109, 0x310a, 0x310b, 0x310c, 0x310d, 0x310e,
+    0x310f, 0x3110, 0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116, 0x3117, 0x3118, 0x3119, 0x311a, 0x311b, 0x311c, 0x311d, 0x311e,
+    0x311f, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128, 0x3129, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x2500, 0x2501, 0x2502, 0x2503, 0x2504, 0x2505, 0x2506, 0x2507, 0x2508, 0x2509, 0x250a, 0x250b, 0x250c,
+    0x250d, 0x250e, 0x250f, 0x2510, 0x2511, 0x2512, 0x2513, 0x2514, 0x2515, 0x2516, 0x2517, 0x2518, 0x2519, 0x251a, 0x251b, 0x251c,
+    0x251d, 0x251e, 0x251f, 0x2520, 0x2521, 0x2522, 0x2523, 0x2524, 0x2525, 0x2526, 0x2527, 0x2528, 0x2529, 0x252a, 0x252b, 0x252c,
+    0x252d, 0x252e, 0x252f, 0x2530, 0x2531, 0x2532, 0x2533, 0x2534, 0x2535, 0x2536, 0x2537, 0x2538, 0x2539, 0x253a, 0x253b, 0x253c,
+    0x253d, 0x253e, 0x253f, 0x2540, 0x2541, 0x2542, 0x2543, 0x2544, 0x2545, 0x2546, 0x2547, 0x2548, 0x2549, 0x254a, 0x254b, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x554a, 0x963f, 0x57c3, 0x6328, 0x54ce, 0x5509, 0x54c0, 0x7691, 0x764c, 0x853c, 0x77ee, 0x827e, 0x788d, 0x7231,
+    0x9698, 0x978d, 0x6c28, 0x5b89, 0x4ffa, 0x6309, 0x6697, 0x5cb8, 0x80fa, 0x6848, 0x80ae, 0x6602, 0x76ce, 0x51f9, 0x6556, 0x71ac,
+    0x7ff1, 0x8884, 0x50b2, 0x5965, 0x61ca, 0x6fb3, 0x82ad, 0x634c, 0x6252, 0x53ed, 0x5427, 0x7b06, 0x516b, 0x75a4, 0x5df4, 0x62d4,
+    0x8dcb, 0x9776, 0x628a, 0x8019, 0x575d, 0x9738, 0x7f62, 0x7238, 0x767d, 0x67cf, 0x767e, 0x6446, 0x4f70, 0x8d25, 0x62dc, 0x7a17,
+    0x6591, 0x73ed, 0x642c, 0x6273, 0x822c, 0x9881, 0x677f, 0x7248, 0x626e, 0x62cc, 0x4f34, 0x74e3, 0x534a, 0x529e, 0x7eca, 0x90a6,
+    0x5e2e, 0x6886, 0x699c, 0x8180, 0x7ed1, 0x68d2, 0x78c5, 0x868c, 0x9551, 0x508d, 0x8c24, 0x82de, 0x80de, 0x5305, 0x8912, 0x5265,
+    0x8584, 0x96f9, 0x4fdd, 0x5821, 0x9971, 0x5b9d, 0x62b1, 0x62a5, 0x66b4, 0x8c79, 0x9c8d, 0x7206, 0x676f, 0x7891, 0x60b2, 0x5351,
+    0x5317, 0x8f88, 0x80cc, 0x8d1d, 0x94a1, 0x500d, 0x72c8, 0x5907, 0x60eb, 0x7119, 0x88ab, 0x5954, 0x82ef, 0x672c, 0x7b28, 0x5d29,
+    0x7ef7, 0x752d, 0x6cf5, 0x8e66, 0x8ff8, 0x903c, 0x9f3b, 0x6bd4, 0x9119, 0x7b14, 0x5f7c, 0x78a7, 0x84d6, 0x853d, 0x6bd5, 0x6bd9,
+    0x6bd6, 0x5e01, 0x5e87, 0x75f9, 0x95ed, 0x655d, 0x5f0a, 0x5fc5, 0x8f9f, 0x58c1, 0x81c2, 0x907f, 0x965b, 0x97ad, 0x8fb9, 0x7f16,
+    0x8d2c, 0x6241, 0x4fbf, 0x53d8, 0x535e, 0x8fa8, 0x8fa9, 0x8fab, 0x904d, 0x6807, 0x5f6a, 0x8198, 0x8868, 0x9cd6, 0x618b, 0x522b,
+    0x762a, 0x5f6c, 0x658c, 0x6fd2, 0x6ee8, 0x5bbe, 0x6448, 0x5175, 0x51b0, 0x67c4, 0x4e19, 0x79c9, 0x997c, 0x70b3, 0x75c5, 0x5e76,
+    0x73bb, 0x83e0, 0x64ad, 0x62e8, 0x94b5, 0x6ce2, 0x535a, 0x52c3, 0x640f, 0x94c2, 0x7b94, 0x4f2f, 0x5e1b, 0x8236, 0x8116, 0x818a,
+    0x6e24, 0x6cca, 0x9a73, 0x6355, 0x535c, 0x54fa, 0x8865, 0x57e0, 0x4e0d, 0x5e03, 0x6b65, 0x7c3f, 0x90e8, 0x6016, 0x64e6, 0x731c,
+    0x88c1, 0x6750, 0x624d, 0x8d22, 0x776c, 0x8e29, 0x91c7, 0x5f69, 0x83dc, 0x8521, 0x9910, 0x53c2, 0x8695, 0x6b8b, 0x60ed, 0x60e8,
+    0x707f, 0x82cd, 0x8231, 0x4ed3, 0x6ca7, 0x85cf, 0x64cd, 0x7cd9, 0x69fd, 0x66f9, 0x8349, 0x5395, 0x7b56, 0x4fa7, 0x518c, 0x6d4b,
+    0x5c42, 0x8e6d, 0x63d2, 0x53c9, 0x832c, 0x8336, 0x67e5, 0x78b4, 0x643d, 0x5bdf, 0x5c94, 0x5dee, 0x8be7, 0x62c6, 0x67f4, 0x8c7a,
+    0x6400, 0x63ba, 0x8749, 0x998b, 0x8c17, 0x7f20, 0x94f2, 0x4ea7, 0x9610, 0x98a4, 0x660c, 0x7316, 0x573a, 0x5c1d, 0x5e38, 0x957f,
+    0x507f, 0x80a0, 0x5382, 0x655e, 0x7545, 0x5531, 0x5021, 0x8d85, 0x6284, 0x949e, 0x671d, 0x5632, 0x6f6e, 0x5de2, 0x5435, 0x7092,
+    0x8f66, 0x626f, 0x64a4, 0x63a3, 0x5f7b, 0x6f88, 0x90f4, 0x81e3, 0x8fb0, 0x5c18, 0x6668, 0x5ff1, 0x6c89, 0x9648, 0x8d81, 0x886c,
+    0x6491, 0x79f0, 0x57ce, 0x6a59, 0x6210, 0x5448, 0x4e58, 0x7a0b, 0x60e9, 0x6f84, 0x8bda, 0x627f, 0x901e, 0x9a8b, 0x79e4, 0x5403,
+    0x75f4, 0x6301, 0x5319, 0x6c60, 0x8fdf, 0x5f1b, 0x9a70, 0x803b, 0x9f7f, 0x4f88, 0x5c3a, 0x8d64, 0x7fc5, 0x65a5, 0x70bd, 0x5145,
+    0x51b2, 0x866b, 0x5d07, 0x5ba0, 0x62bd, 0x916c, 0x7574, 0x8e0c, 0x7a20, 0x6101, 0x7b79, 0x4ec7, 0x7ef8, 0x7785, 0x4e11, 0x81ed,
+    0x521d, 0x51fa, 0x6a71, 0x53a8, 0x8e87, 0x9504, 0x96cf, 0x6ec1, 0x9664, 0x695a, 0x7840, 0x50a8, 0x77d7, 0x6410, 0x89e6, 0x5904,
+    0x63e3, 0x5ddd, 0x7a7f, 0x693d, 0x4f20, 0x8239, 0x5598, 0x4e32, 0x75ae, 0x7a97, 0x5e62, 0x5e8a, 0x95ef, 0x521b, 0x5439, 0x708a,
+    0x6376, 0x9524, 0x5782, 0x6625, 0x693f, 0x9187, 0x5507, 0x6df3, 0x7eaf, 0x8822, 0x6233, 0x7ef0, 0x75b5, 0x8328, 0x78c1, 0x96cc,
+    0x8f9e, 0x6148, 0x74f7, 0x8bcd, 0x6b64, 0x523a, 0x8d50, 0x6b21, 0x806a, 0x8471, 0x56f1, 0x5306, 0x4ece, 0x4e1b, 0x51d1, 0x7c97,
+    0x918b, 0x7c07, 0x4fc3, 0x8e7f, 0x7be1, 0x7a9c, 0x6467, 0x5d14, 0x50ac, 0x8106, 0x7601, 0x7cb9, 0x6dec, 0x7fe0, 0x6751, 0x5b58,
+    0x5bf8, 0x78cb, 0x64ae, 0x6413, 0x63aa, 0x632b, 0x9519, 0x642d, 0x8fbe, 0x7b54, 0x7629, 0x6253, 0x5927, 0x5446, 0x6b79, 0x50a3,
+    0x6234, 0x5e26, 0x6b86, 0x4ee3, 0x8d37, 0x888b, 0x5f85, 0x902e, 0x6020, 0x803d, 0x62c5, 0x4e39, 0x5355, 0x90f8, 0x63b8, 0x80c6,
+    0x65e6, 0x6c2e, 0x4f46, 0x60ee, 0x6de1, 0x8bde, 0x5f39, 0x86cb, 0x5f53, 0x6321, 0x515a, 0x8361, 0x6863, 0x5200, 0x6363, 0x8e48,
+    0x5012, 0x5c9b, 0x7977, 0x5bfc, 0x5230, 0x7a3b, 0x60bc, 0x9053, 0x76d7, 0x5fb7, 0x5f97, 0x7684, 0x8e6c, 0x706f, 0x767b, 0x7b49,
+    0x77aa, 0x51f3, 0x9093, 0x5824, 0x4f4e, 0x6ef4, 0x8fea, 0x654c, 0x7b1b, 0x72c4, 0x6da4, 0x7fdf, 0x5ae1, 0x62b5, 0x5e95, 0x5730,
+    0x8482, 0x7b2c, 0x5e1d, 0x5f1f, 0x9012, 0x7f14, 0x98a0, 0x6382, 0x6ec7, 0x7898, 0x70b9, 0x5178, 0x975b, 0x57ab, 0x7535, 0x4f43,
+    0x7538, 0x5e97, 0x60e6, 0x5960, 0x6dc0, 0x6bbf, 0x7889, 0x53fc, 0x96d5, 0x51cb, 0x5201, 0x6389, 0x540a, 0x9493, 0x8c03, 0x8dcc,
+    0x7239, 0x789f, 0x8776, 0x8fed, 0x8c0d, 0x53e0, 0x4e01, 0x76ef, 0x53ee, 0x9489, 0x9876, 0x9f0e, 0x952d, 0x5b9a, 0x8ba2, 0x4e22,
+    0x4e1c, 0x51ac, 0x8463, 0x61c2, 0x52a8, 0x680b, 0x4f97, 0x606b, 0x51bb, 0x6d1e, 0x515c, 0x6296, 0x6597, 0x9661, 0x8c46, 0x9017,
+    0x75d8, 0x90fd, 0x7763, 0x6bd2, 0x728a, 0x72ec, 0x8bfb, 0x5835, 0x7779, 0x8d4c, 0x675c, 0x9540, 0x809a, 0x5ea6, 0x6e21, 0x5992,
+    0x7aef, 0x77ed, 0x953b, 0x6bb5, 0x65ad, 0x7f0e, 0x5806, 0x5151, 0x961f, 0x5bf9, 0x58a9, 0x5428, 0x8e72, 0x6566, 0x987f, 0x56e4,
+    0x949d, 0x76fe, 0x9041, 0x6387, 0x54c6, 0x591a, 0x593a, 0x579b, 0x8eb2, 0x6735, 0x8dfa, 0x8235, 0x5241, 0x60f0, 0x5815, 0x86fe,
+    0x5ce8, 0x9e45, 0x4fc4, 0x989d, 0x8bb9, 0x5a25, 0x6076, 0x5384, 0x627c, 0x904f, 0x9102, 0x997f, 0x6069, 0x800c, 0x513f, 0x8033,
+    0x5c14, 0x9975, 0x6d31, 0x4e8c, 0x8d30, 0x53d1, 0x7f5a, 0x7b4f, 0x4f10, 0x4e4f, 0x9600, 0x6cd5, 0x73d0, 0x85e9, 0x5e06, 0x756a,
+    0x7ffb, 0x6a0a, 0x77fe, 0x9492, 0x7e41, 0x51e1, 0x70e6, 0x53cd, 0x8fd4, 0x8303, 0x8d29, 0x72af, 0x996d, 0x6cdb, 0x574a, 0x82b3,
+    0x65b9, 0x80aa, 0x623f, 0x9632, 0x59a8, 0x4eff, 0x8bbf, 0x7eba, 0x653e, 0x83f2, 0x975e, 0x5561, 0x98de, 0x80a5, 0x532a, 0x8bfd,
+    0x5420, 0x80ba, 0x5e9f, 0x6cb8, 0x8d39, 0x82ac, 0x915a, 0x5429, 0x6c1b, 0x5206, 0x7eb7, 0x575f, 0x711a, 0x6c7e, 0x7c89, 0x594b,
+    0x4efd, 0x5fff, 0x6124, 0x7caa, 0x4e30, 0x5c01, 0x67ab, 0x8702, 0x5cf0, 0x950b, 0x98ce, 0x75af, 0x70fd, 0x9022, 0x51af, 0x7f1d,
+    0x8bbd, 0x5949, 0x51e4, 0x4f5b, 0x5426, 0x592b, 0x6577, 0x80a4, 0x5b75, 0x6276, 0x62c2, 0x8f90, 0x5e45, 0x6c1f, 0x7b26, 0x4f0f,
+    0x4fd8, 0x670d, 0x6d6e, 0x6daa, 0x798f, 0x88b1, 0x5f17, 0x752b, 0x629a, 0x8f85, 0x4fef, 0x91dc, 0x65a7, 0x812f, 0x8151, 0x5e9c,
+    0x8150, 0x8d74, 0x526f, 0x8986, 0x8d4b, 0x590d, 0x5085, 0x4ed8, 0x961c, 0x7236, 0x8179, 0x8d1f, 0x5bcc, 0x8ba3, 0x9644, 0x5987,
+    0x7f1a, 0x5490, 0x5676, 0x560e, 0x8be5, 0x6539, 0x6982, 0x9499, 0x76d6, 0x6e89, 0x5e72, 0x7518, 0x6746, 0x67d1, 0x7aff, 0x809d,
+    0x8d76, 0x611f, 0x79c6, 0x6562, 0x8d63, 0x5188, 0x521a, 0x94a2, 0x7f38, 0x809b, 0x7eb2, 0x5c97, 0x6e2f, 0x6760, 0x7bd9, 0x768b,
+    0x9ad8, 0x818f, 0x7f94, 0x7cd5, 0x641e, 0x9550, 0x7a3f, 0x544a, 0x54e5, 0x6b4c, 0x6401, 0x6208, 0x9e3d, 0x80f3, 0x7599, 0x5272,
+    0x9769, 0x845b, 0x683c, 0x86e4, 0x9601, 0x9694, 0x94ec, 0x4e2a, 0x5404, 0x7ed9, 0x6839, 0x8ddf, 0x8015, 0x66f4, 0x5e9a, 0x7fb9,
+    0x57c2, 0x803f, 0x6897, 0x5de5, 0x653b, 0x529f, 0x606d, 0x9f9a, 0x4f9b, 0x8eac, 0x516c, 0x5bab, 0x5f13, 0x5de9, 0x6c5e, 0x62f1,
+    0x8d21, 0x5171, 0x94a9, 0x52fe, 0x6c9f, 0x82df, 0x72d7, 0x57a2, 0x6784, 0x8d2d, 0x591f, 0x8f9c, 0x83c7, 0x5495, 0x7b8d, 0x4f30,
+    0x6cbd, 0x5b64, 0x59d1, 0x9f13, 0x53e4, 0x86ca, 0x9aa8, 0x8c37, 0x80a1, 0x6545, 0x987e, 0x56fa, 0x96c7, 0x522e, 0x74dc, 0x5250,
+    0x5be1, 0x6302, 0x8902, 0x4e56, 0x62d0, 0x602a, 0x68fa, 0x5173, 0x5b98, 0x51a0, 0x89c2, 0x7ba1, 0x9986, 0x7f50, 0x60ef, 0x704c,
+    0x8d2f, 0x5149, 0x5e7f, 0x901b, 0x7470, 0x89c4, 0x572d, 0x7845, 0x5f52, 0x9f9f, 0x95fa, 0x8f68, 0x9b3c, 0x8be1, 0x7678, 0x6842,
+    0x67dc, 0x8dea, 0x8d35, 0x523d, 0x8f8a, 0x6eda, 0x68cd, 0x9505, 0x90ed, 0x56fd, 0x679c, 0x88f9, 0x8fc7, 0x54c8, 0x9ab8, 0x5b69,
+    0x6d77, 0x6c26, 0x4ea5, 0x5bb3, 0x9a87, 0x9163, 0x61a8, 0x90af, 0x97e9, 0x542b, 0x6db5, 0x5bd2, 0x51fd, 0x558a, 0x7f55, 0x7ff0,
+    0x64bc, 0x634d, 0x65f1, 0x61be, 0x608d, 0x710a, 0x6c57, 0x6c49, 0x592f, 0x676d, 0x822a, 0x58d5, 0x568e, 0x8c6a, 0x6beb, 0x90dd,
+    0x597d, 0x8017, 0x53f7, 0x6d69, 0x5475, 0x559d, 0x8377, 0x83cf, 0x6838, 0x79be, 0x548c, 0x4f55, 0x5408, 0x76d2, 0x8c89, 0x9602,
+    0x6cb3, 0x6db8, 0x8d6b, 0x8910, 0x9e64, 0x8d3a, 0x563f, 0x9ed1, 0x75d5, 0x5f88, 0x72e0, 0x6068, 0x54fc, 0x4ea8, 0x6a2a, 0x8861,
+    0x6052, 0x8f70, 0x54c4, 0x70d8, 0x8679, 0x9e3f, 0x6d2a, 0x5b8f, 0x5f18, 0x7ea2, 0x5589, 0x4faf, 0x7334, 0x543c, 0x539a, 0x5019,
+    0x540e, 0x547c, 0x4e4e, 0x5ffd, 0x745a, 0x58f6, 0x846b, 0x80e1, 0x8774, 0x72d0, 0x7cca, 0x6e56, 0x5f27, 0x864e, 0x552c, 0x62a4,
+    0x4e92, 0x6caa, 0x6237, 0x82b1, 0x54d7, 0x534e, 0x733e, 0x6ed1, 0x753b, 0x5212, 0x5316, 0x8bdd, 0x69d0, 0x5f8a, 0x6000, 0x6dee,
+    0x574f, 0x6b22, 0x73af, 0x6853, 0x8fd8, 0x7f13, 0x6362, 0x60a3, 0x5524, 0x75ea, 0x8c62, 0x7115, 0x6da3, 0x5ba6, 0x5e7b, 0x8352,
+    0x614c, 0x9ec4, 0x78fa, 0x8757, 0x7c27, 0x7687, 0x51f0, 0x60f6, 0x714c, 0x6643, 0x5e4c, 0x604d, 0x8c0e, 0x7070, 0x6325, 0x8f89,
+    0x5fbd, 0x6062, 0x86d4, 0x56de, 0x6bc1, 0x6094, 0x6167, 0x5349, 0x60e0, 0x6666, 0x8d3f, 0x79fd, 0x4f1a, 0x70e9, 0x6c47, 0x8bb3,
+    0x8bf2, 0x7ed8, 0x8364, 0x660f, 0x5a5a, 0x9b42, 0x6d51, 0x6df7, 0x8c41, 0x6d3b, 0x4f19, 0x706b, 0x83b7, 0x6216, 0x60d1, 0x970d,
+    0x8d27, 0x7978, 0x51fb, 0x573e, 0x57fa, 0x673a, 0x7578, 0x7a3d, 0x79ef, 0x7b95, 0x808c, 0x9965, 0x8ff9, 0x6fc0, 0x8ba5, 0x9e21,
+    0x59ec, 0x7ee9, 0x7f09, 0x5409, 0x6781, 0x68d8, 0x8f91, 0x7c4d, 0x96c6, 0x53ca, 0x6025, 0x75be, 0x6c72, 0x5373, 0x5ac9, 0x7ea7,
+    0x6324, 0x51e0, 0x810a, 0x5df1, 0x84df, 0x6280, 0x5180, 0x5b63, 0x4f0e, 0x796d, 0x5242, 0x60b8, 0x6d4e, 0x5bc4, 0x5bc2, 0x8ba1,
+    0x8bb0, 0x65e2, 0x5fcc, 0x9645, 0x5993, 0x7ee7, 0x7eaa, 0x5609, 0x67b7, 0x5939, 0x4f73, 0x5bb6, 0x52a0, 0x835a, 0x988a, 0x8d3e,
+    0x7532, 0x94be, 0x5047, 0x7a3c, 0x4ef7, 0x67b6, 0x9a7e, 0x5ac1, 0x6b7c, 0x76d1, 0x575a, 0x5c16, 0x7b3a, 0x95f4, 0x714e, 0x517c,
+    0x80a9, 0x8270, 0x5978, 0x7f04, 0x8327, 0x68c0, 0x67ec, 0x78b1, 0x7877, 0x62e3, 0x6361, 0x7b80, 0x4fed, 0x526a, 0x51cf, 0x8350,
+    0x69db, 0x9274, 0x8df5, 0x8d31, 0x89c1, 0x952e, 0x7bad, 0x4ef6, 0x5065, 0x8230, 0x5251, 0x996f, 0x6e10, 0x6e85, 0x6da7, 0x5efa,
+    0x50f5, 0x59dc, 0x5c06, 0x6d46, 0x6c5f, 0x7586, 0x848b, 0x6868, 0x5956, 0x8bb2, 0x5320, 0x9171, 0x964d, 0x8549, 0x6912, 0x7901,
+    0x7126, 0x80f6, 0x4ea4, 0x90ca, 0x6d47, 0x9a84, 0x5a07, 0x56bc, 0x6405, 0x94f0, 0x77eb, 0x4fa5, 0x811a, 0x72e1, 0x89d2, 0x997a,
+    0x7f34, 0x7ede, 0x527f, 0x6559, 0x9175, 0x8f7f, 0x8f83, 0x53eb, 0x7a96, 0x63ed, 0x63a5, 0x7686, 0x79f8, 0x8857, 0x9636, 0x622a,
+    0x52ab, 0x8282, 0x6854, 0x6770, 0x6377, 0x776b, 0x7aed, 0x6d01, 0x7ed3, 0x89e3, 0x59d0, 0x6212, 0x85c9, 0x82a5, 0x754c, 0x501f,
+    0x4ecb, 0x75a5, 0x8beb, 0x5c4a, 0x5dfe, 0x7b4b, 0x65a4, 0x91d1, 0x4eca, 0x6d25, 0x895f, 0x7d27, 0x9526, 0x4ec5, 0x8c28, 0x8fdb,
+    0x9773, 0x664b, 0x7981, 0x8fd1, 0x70ec, 0x6d78, 0x5c3d, 0x52b2, 0x8346, 0x5162, 0x830e, 0x775b, 0x6676, 0x9cb8, 0x4eac, 0x60ca,
+    0x7cbe, 0x7cb3, 0x7ecf, 0x4e95, 0x8b66, 0x666f, 0x9888, 0x9759, 0x5883, 0x656c, 0x955c, 0x5f84, 0x75c9, 0x9756, 0x7adf, 0x7ade,
+    0x51c0, 0x70af, 0x7a98, 0x63ea, 0x7a76, 0x7ea0, 0x7396, 0x97ed, 0x4e45, 0x7078, 0x4e5d, 0x9152, 0x53a9, 0x6551, 0x65e7, 0x81fc,
+    0x8205, 0x548e, 0x5c31, 0x759a, 0x97a0, 0x62d8, 0x72d9, 0x75bd, 0x5c45, 0x9a79, 0x83ca, 0x5c40, 0x5480, 0x77e9, 0x4e3e, 0x6cae,
+    0x805a, 0x62d2, 0x636e, 0x5de8, 0x5177, 0x8ddd, 0x8e1e, 0x952f, 0x4ff1, 0x53e5, 0x60e7, 0x70ac, 0x5267, 0x6350, 0x9e43, 0x5a1f,
+    0x5026, 0x7737, 0x5377, 0x7ee2, 0x6485, 0x652b, 0x6289, 0x6398, 0x5014, 0x7235, 0x89c9, 0x51b3, 0x8bc0, 0x7edd, 0x5747, 0x83cc,
+    0x94a7, 0x519b, 0x541b, 0x5cfb, 0x4fca, 0x7ae3, 0x6d5a, 0x90e1, 0x9a8f, 0x5580, 0x5496, 0x5361, 0x54af, 0x5f00, 0x63e9, 0x6977,
+    0x51ef, 0x6168, 0x520a, 0x582a, 0x52d8, 0x574e, 0x780d, 0x770b, 0x5eb7, 0x6177, 0x7ce0, 0x625b, 0x6297, 0x4ea2, 0x7095, 0x8003,
+    0x62f7, 0x70e4, 0x9760, 0x5777, 0x82db, 0x67ef, 0x68f5, 0x78d5, 0x9897, 0x79d1, 0x58f3, 0x54b3, 0x53ef, 0x6e34, 0x514b, 0x523b,
+    0x5ba2, 0x8bfe, 0x80af, 0x5543, 0x57a6, 0x6073, 0x5751, 0x542d, 0x7a7a, 0x6050, 0x5b54, 0x63a7, 0x62a0, 0x53e3, 0x6263, 0x5bc7,
+    0x67af, 0x54ed, 0x7a9f, 0x82e6, 0x9177, 0x5e93, 0x88e4, 0x5938, 0x57ae, 0x630e, 0x8de8, 0x80ef, 0x5757, 0x7b77, 0x4fa9, 0x5feb,
+    0x5bbd, 0x6b3e, 0x5321, 0x7b50, 0x72c2, 0x6846, 0x77ff, 0x7736, 0x65f7, 0x51b5, 0x4e8f, 0x76d4, 0x5cbf, 0x7aa5, 0x8475, 0x594e,
+    0x9b41, 0x5080, 0x9988, 0x6127, 0x6e83, 0x5764, 0x6606, 0x6346, 0x56f0, 0x62ec, 0x6269, 0x5ed3, 0x9614, 0x5783, 0x62c9, 0x5587,
+    0x8721, 0x814a, 0x8fa3, 0x5566, 0x83b1, 0x6765, 0x8d56, 0x84dd, 0x5a6a, 0x680f, 0x62e6, 0x7bee, 0x9611, 0x5170, 0x6f9c, 0x8c30,
+    0x63fd, 0x89c8, 0x61d2, 0x7f06, 0x70c2, 0x6ee5, 0x7405, 0x6994, 0x72fc, 0x5eca, 0x90ce, 0x6717, 0x6d6a, 0x635e, 0x52b3, 0x7262,
+    0x8001, 0x4f6c, 0x59e5, 0x916a, 0x70d9, 0x6d9d, 0x52d2, 0x4e50, 0x96f7, 0x956d, 0x857e, 0x78ca, 0x7d2f, 0x5121, 0x5792, 0x64c2,
+    0x808b, 0x7c7b, 0x6cea, 0x68f1, 0x695e, 0x51b7, 0x5398, 0x68a8, 0x7281, 0x9ece, 0x7bf1, 0x72f8, 0x79bb, 0x6f13, 0x7406, 0x674e,
+    0x91cc, 0x9ca4, 0x793c, 0x8389, 0x8354, 0x540f, 0x6817, 0x4e3d, 0x5389, 0x52b1, 0x783e, 0x5386, 0x5229, 0x5088, 0x4f8b, 0x4fd0,
+    0x75e2, 0x7acb, 0x7c92, 0x6ca5, 0x96b6, 0x529b, 0x7483, 0x54e9, 0x4fe9, 0x8054, 0x83b2, 0x8fde, 0x9570, 0x5ec9, 0x601c, 0x6d9f,
+    0x5e18, 0x655b, 0x8138, 0x94fe, 0x604b, 0x70bc, 0x7ec3, 0x7cae, 0x51c9, 0x6881, 0x7cb1, 0x826f, 0x4e24, 0x8f86, 0x91cf, 0x667e,
+    0x4eae, 0x8c05, 0x64a9, 0x804a, 0x50da, 0x7597, 0x71ce, 0x5be5, 0x8fbd, 0x6f66, 0x4e86, 0x6482, 0x9563, 0x5ed6, 0x6599, 0x5217,
+    0x88c2, 0x70c8, 0x52a3, 0x730e, 0x7433, 0x6797, 0x78f7, 0x9716, 0x4e34, 0x90bb, 0x9cde, 0x6dcb, 0x51db, 0x8d41, 0x541d, 0x62ce,
+    0x73b2, 0x83f1, 0x96f6, 0x9f84, 0x94c3, 0x4f36, 0x7f9a, 0x51cc, 0x7075, 0x9675, 0x5cad, 0x9886, 0x53e6, 0x4ee4, 0x6e9c, 0x7409,
+    0x69b4, 0x786b, 0x998f, 0x7559, 0x5218, 0x7624, 0x6d41, 0x67f3, 0x516d, 0x9f99, 0x804b, 0x5499, 0x7b3c, 0x7abf, 0x9686, 0x5784,
+    0x62e2, 0x9647, 0x697c, 0x5a04, 0x6402, 0x7bd3, 0x6f0f, 0x964b, 0x82a6, 0x5362, 0x9885, 0x5e90, 0x7089, 0x63b3, 0x5364, 0x864f,
+    0x9c81, 0x9e93, 0x788c, 0x9732, 0x8def, 0x8d42, 0x9e7f, 0x6f5e, 0x7984, 0x5f55, 0x9646, 0x622e, 0x9a74, 0x5415, 0x94dd, 0x4fa3,
+    0x65c5, 0x5c65, 0x5c61, 0x7f15, 0x8651, 0x6c2f, 0x5f8b, 0x7387, 0x6ee4, 0x7eff, 0x5ce6, 0x631b, 0x5b6a, 0x6ee6, 0x5375, 0x4e71,
+    0x63a0, 0x7565, 0x62a1, 0x8f6e, 0x4f26, 0x4ed1, 0x6ca6, 0x7eb6, 0x8bba, 0x841d, 0x87ba, 0x7f57, 0x903b, 0x9523, 0x7ba9, 0x9aa1,
+    0x88f8, 0x843d, 0x6d1b, 0x9a86, 0x7edc, 0x5988, 0x9ebb, 0x739b, 0x7801, 0x8682, 0x9a6c, 0x9a82, 0x561b, 0x5417, 0x57cb, 0x4e70,
+    0x9ea6, 0x5356, 0x8fc8, 0x8109, 0x7792, 0x9992, 0x86ee, 0x6ee1, 0x8513, 0x66fc, 0x6162, 0x6f2b, 0x8c29, 0x8292, 0x832b, 0x76f2,
+    0x6c13, 0x5fd9, 0x83bd, 0x732b, 0x8305, 0x951a, 0x6bdb, 0x77db, 0x94c6, 0x536f, 0x8302, 0x5192, 0x5e3d, 0x8c8c, 0x8d38, 0x4e48,
+    0x73ab, 0x679a, 0x6885, 0x9176, 0x9709, 0x7164, 0x6ca1, 0x7709, 0x5a92, 0x9541, 0x6bcf, 0x7f8e, 0x6627, 0x5bd0, 0x59b9, 0x5a9a,
+    0x95e8, 0x95f7, 0x4eec, 0x840c, 0x8499, 0x6aac, 0x76df, 0x9530, 0x731b, 0x68a6, 0x5b5f, 0x772f, 0x919a, 0x9761, 0x7cdc, 0x8ff7,
+    0x8c1c, 0x5f25, 0x7c73, 0x79d8, 0x89c5, 0x6ccc, 0x871c, 0x5bc6, 0x5e42, 0x68c9, 0x7720, 0x7ef5, 0x5195, 0x514d, 0x52c9, 0x5a29,
+    0x7f05, 0x9762, 0x82d7, 0x63cf, 0x7784, 0x85d0, 0x79d2, 0x6e3a, 0x5e99, 0x5999, 0x8511, 0x706d, 0x6c11, 0x62bf, 0x76bf, 0x654f,
+    0x60af, 0x95fd, 0x660e, 0x879f, 0x9e23, 0x94ed, 0x540d, 0x547d, 0x8c2c, 0x6478, 0x6479, 0x8611, 0x6a21, 0x819c, 0x78e8, 0x6469,
+    0x9b54, 0x62b9, 0x672b, 0x83ab, 0x58a8, 0x9ed8, 0x6cab, 0x6f20, 0x5bde, 0x964c, 0x8c0b, 0x725f, 0x67d0, 0x62c7, 0x7261, 0x4ea9,
+    0x59c6, 0x6bcd, 0x5893, 0x66ae, 0x5e55, 0x52df, 0x6155, 0x6728, 0x76ee, 0x7766, 0x7267, 0x7a46, 0x62ff, 0x54ea, 0x5450, 0x94a0,
+    0x90a3, 0x5a1c, 0x7eb3, 0x6c16, 0x4e43, 0x5976, 0x8010, 0x5948, 0x5357, 0x7537, 0x96be, 0x56ca, 0x6320, 0x8111, 0x607c, 0x95f9,
+    0x6dd6, 0x5462, 0x9981, 0x5185, 0x5ae9, 0x80fd, 0x59ae, 0x9713, 0x502a, 0x6ce5, 0x5c3c, 0x62df, 0x4f60, 0x533f, 0x817b, 0x9006,
+    0x6eba, 0x852b, 0x62c8, 0x5e74, 0x78be, 0x64b5, 0x637b, 0x5ff5, 0x5a18, 0x917f, 0x9e1f, 0x5c3f, 0x634f, 0x8042, 0x5b7d, 0x556e,
+    0x954a, 0x954d, 0x6d85, 0x60a8, 0x67e0, 0x72de, 0x51dd, 0x5b81, 0x62e7, 0x6cde, 0x725b, 0x626d, 0x94ae, 0x7ebd, 0x8113, 0x6d53,
+    0x519c, 0x5f04, 0x5974, 0x52aa, 0x6012, 0x5973, 0x6696, 0x8650, 0x759f, 0x632a, 0x61e6, 0x7cef, 0x8bfa, 0x54e6, 0x6b27, 0x9e25,
+    0x6bb4, 0x85d5, 0x5455, 0x5076, 0x6ca4, 0x556a, 0x8db4, 0x722c, 0x5e15, 0x6015, 0x7436, 0x62cd, 0x6392, 0x724c, 0x5f98, 0x6e43,
+    0x6d3e, 0x6500, 0x6f58, 0x76d8, 0x78d0, 0x76fc, 0x7554, 0x5224, 0x53db, 0x4e53, 0x5e9e, 0x65c1, 0x802a, 0x80d6, 0x629b, 0x5486,
+    0x5228, 0x70ae, 0x888d, 0x8dd1, 0x6ce1, 0x5478, 0x80da, 0x57f9, 0x88f4, 0x8d54, 0x966a, 0x914d, 0x4f69, 0x6c9b, 0x55b7, 0x76c6,
+    0x7830, 0x62a8, 0x70f9, 0x6f8e, 0x5f6d, 0x84ec, 0x68da, 0x787c, 0x7bf7, 0x81a8, 0x670b, 0x9e4f, 0x6367, 0x78b0, 0x576f, 0x7812,
+    0x9739, 0x6279, 0x62ab, 0x5288, 0x7435, 0x6bd7, 0x5564, 0x813e, 0x75b2, 0x76ae, 0x5339, 0x75de, 0x50fb, 0x5c41, 0x8b6c, 0x7bc7,
+    0x504f, 0x7247, 0x9a97, 0x98d8, 0x6f02, 0x74e2, 0x7968, 0x6487, 0x77a5, 0x62fc, 0x9891, 0x8d2b, 0x54c1, 0x8058, 0x4e52, 0x576a,
+    0x82f9, 0x840d, 0x5e73, 0x51ed, 0x74f6, 0x8bc4, 0x5c4f, 0x5761, 0x6cfc, 0x9887, 0x5a46, 0x7834, 0x9b44, 0x8feb, 0x7c95, 0x5256,
+    0x6251, 0x94fa, 0x4ec6, 0x8386, 0x8461, 0x83e9, 0x84b2, 0x57d4, 0x6734, 0x5703, 0x666e, 0x6d66, 0x8c31, 0x66dd, 0x7011, 0x671f,
+    0x6b3a, 0x6816, 0x621a, 0x59bb, 0x4e03, 0x51c4, 0x6f06, 0x67d2, 0x6c8f, 0x5176, 0x68cb, 0x5947, 0x6b67, 0x7566, 0x5d0e, 0x8110,
+    0x9f50, 0x65d7, 0x7948, 0x7941, 0x9a91, 0x8d77, 0x5c82, 0x4e5e, 0x4f01, 0x542f, 0x5951, 0x780c, 0x5668, 0x6c14, 0x8fc4, 0x5f03,
+    0x6c7d, 0x6ce3, 0x8bab, 0x6390, 0x6070, 0x6d3d, 0x7275, 0x6266, 0x948e, 0x94c5, 0x5343, 0x8fc1, 0x7b7e, 0x4edf, 0x8c26, 0x4e7e,
+    0x9ed4, 0x94b1, 0x94b3, 0x524d, 0x6f5c, 0x9063, 0x6d45, 0x8c34, 0x5811, 0x5d4c, 0x6b20, 0x6b49, 0x67aa, 0x545b, 0x8154, 0x7f8c,
+    0x5899, 0x8537, 0x5f3a, 0x62a2, 0x6a47, 0x9539, 0x6572, 0x6084, 0x6865, 0x77a7, 0x4e54, 0x4fa8, 0x5de7, 0x9798, 0x64ac, 0x7fd8,
+    0x5ced, 0x4fcf, 0x7a8d, 0x5207, 0x8304, 0x4e14, 0x602f, 0x7a83, 0x94a6, 0x4fb5, 0x4eb2, 0x79e6, 0x7434, 0x52e4, 0x82b9, 0x64d2,
+    0x79bd, 0x5bdd, 0x6c81, 0x9752, 0x8f7b, 0x6c22, 0x503e, 0x537f, 0x6e05, 0x64ce, 0x6674, 0x6c30, 0x60c5, 0x9877, 0x8bf7, 0x5e86,
+    0x743c, 0x7a77, 0x79cb, 0x4e18, 0x90b1, 0x7403, 0x6c42, 0x56da, 0x914b, 0x6cc5, 0x8d8b, 0x533a, 0x86c6, 0x66f2, 0x8eaf, 0x5c48,
+    0x9a71, 0x6e20, 0x53d6, 0x5a36, 0x9f8b, 0x8da3, 0x53bb, 0x5708, 0x98a7, 0x6743, 0x919b, 0x6cc9, 0x5168, 0x75ca, 0x62f3, 0x72ac,
+    0x5238, 0x529d, 0x7f3a, 0x7094, 0x7638, 0x5374, 0x9e4a, 0x69b7, 0x786e, 0x96c0, 0x88d9, 0x7fa4, 0x7136, 0x71c3, 0x5189, 0x67d3,
+    0x74e4, 0x58e4, 0x6518, 0x56b7, 0x8ba9, 0x9976, 0x6270, 0x7ed5, 0x60f9, 0x70ed, 0x58ec, 0x4ec1, 0x4eba, 0x5fcd, 0x97e7, 0x4efb,
+    0x8ba4, 0x5203, 0x598a, 0x7eab, 0x6254, 0x4ecd, 0x65e5, 0x620e, 0x8338, 0x84c9, 0x8363, 0x878d, 0x7194, 0x6eb6, 0x5bb9, 0x7ed2,
+    0x5197, 0x63c9, 0x67d4, 0x8089, 0x8339, 0x8815, 0x5112, 0x5b7a, 0x5982, 0x8fb1, 0x4e73, 0x6c5d, 0x5165, 0x8925, 0x8f6f, 0x962e,
+    0x854a, 0x745e, 0x9510, 0x95f0, 0x6da6, 0x82e5, 0x5f31, 0x6492, 0x6d12, 0x8428, 0x816e, 0x9cc3, 0x585e, 0x8d5b, 0x4e09, 0x53c1,
+    0x4f1e, 0x6563, 0x6851, 0x55d3, 0x4e27, 0x6414, 0x9a9a, 0x626b, 0x5ac2, 0x745f, 0x8272, 0x6da9, 0x68ee, 0x50e7, 0x838e, 0x7802,
+    0x6740, 0x5239, 0x6c99, 0x7eb1, 0x50bb, 0x5565, 0x715e, 0x7b5b, 0x6652, 0x73ca, 0x82eb, 0x6749, 0x5c71, 0x5220, 0x717d, 0x886b,
+    0x95ea, 0x9655, 0x64c5, 0x8d61, 0x81b3, 0x5584, 0x6c55, 0x6247, 0x7f2e, 0x5892, 0x4f24, 0x5546, 0x8d4f, 0x664c, 0x4e0a, 0x5c1a,
+    0x88f3, 0x68a2, 0x634e, 0x7a0d, 0x70e7, 0x828d, 0x52fa, 0x97f6, 0x5c11, 0x54e8, 0x90b5, 0x7ecd, 0x5962, 0x8d4a, 0x86c7, 0x820c,
+    0x820d, 0x8d66, 0x6444, 0x5c04, 0x6151, 0x6d89, 0x793e, 0x8bbe, 0x7837, 0x7533, 0x547b, 0x4f38, 0x8eab, 0x6df1, 0x5a20, 0x7ec5,
+    0x795e, 0x6c88, 0x5ba1, 0x5a76, 0x751a, 0x80be, 0x614e, 0x6e17, 0x58f0, 0x751f, 0x7525, 0x7272, 0x5347, 0x7ef3, 0x7701, 0x76db,
+    0x5269, 0x80dc, 0x5723, 0x5e08, 0x5931, 0x72ee, 0x65bd, 0x6e7f, 0x8bd7, 0x5c38, 0x8671, 0x5341, 0x77f3, 0x62fe, 0x65f6, 0x4ec0,
+    0x98df, 0x8680, 0x5b9e, 0x8bc6, 0x53f2, 0x77e2, 0x4f7f, 0x5c4e, 0x9a76, 0x59cb, 0x5f0f, 0x793a, 0x58eb, 0x4e16, 0x67ff, 0x4e8b,
+    0x62ed, 0x8a93, 0x901d, 0x52bf, 0x662f, 0x55dc, 0x566c, 0x9002, 0x4ed5, 0x4f8d, 0x91ca, 0x9970, 0x6c0f, 0x5e02, 0x6043, 0x5ba4,
+    0x89c6, 0x8bd5, 0x6536, 0x624b, 0x9996, 0x5b88, 0x5bff, 0x6388, 0x552e, 0x53d7, 0x7626, 0x517d, 0x852c, 0x67a2, 0x68b3, 0x6b8a,
+    0x6292, 0x8f93, 0x53d4, 0x8212, 0x6dd1, 0x758f, 0x4e66, 0x8d4e, 0x5b70, 0x719f, 0x85af, 0x6691, 0x66d9, 0x7f72, 0x8700, 0x9ecd,
+    0x9f20, 0x5c5e, 0x672f, 0x8ff0, 0x6811, 0x675f, 0x620d, 0x7ad6, 0x5885, 0x5eb6, 0x6570, 0x6f31, 0x6055, 0x5237, 0x800d, 0x6454,
+    0x8870, 0x7529, 0x5e05, 0x6813, 0x62f4, 0x971c, 0x53cc, 0x723d, 0x8c01, 0x6c34, 0x7761, 0x7a0e, 0x542e, 0x77ac, 0x987a, 0x821c,
+    0x8bf4, 0x7855, 0x6714, 0x70c1, 0x65af, 0x6495, 0x5636, 0x601d, 0x79c1, 0x53f8, 0x4e1d, 0x6b7b, 0x8086, 0x5bfa, 0x55e3, 0x56db,
+    0x4f3a, 0x4f3c, 0x9972, 0x5df3, 0x677e, 0x8038, 0x6002, 0x9882, 0x9001, 0x5b8b, 0x8bbc, 0x8bf5, 0x641c, 0x8258, 0x64de, 0x55fd,
+    0x82cf, 0x9165, 0x4fd7, 0x7d20, 0x901f, 0x7c9f, 0x50f3, 0x5851, 0x6eaf, 0x5bbf, 0x8bc9, 0x8083, 0x9178, 0x849c, 0x7b97, 0x867d,
+    0x968b, 0x968f, 0x7ee5, 0x9ad3, 0x788e, 0x5c81, 0x7a57, 0x9042, 0x96a7, 0x795f, 0x5b59, 0x635f, 0x7b0b, 0x84d1, 0x68ad, 0x5506,
+    0x7f29, 0x7410, 0x7d22, 0x9501, 0x6240, 0x584c, 0x4ed6, 0x5b83, 0x5979, 0x5854, 0x736d, 0x631e, 0x8e4b, 0x8e0f, 0x80ce, 0x82d4,
+    0x62ac, 0x53f0, 0x6cf0, 0x915e, 0x592a, 0x6001, 0x6c70, 0x574d, 0x644a, 0x8d2a, 0x762b, 0x6ee9, 0x575b, 0x6a80, 0x75f0, 0x6f6d,
+    0x8c2d, 0x8c08, 0x5766, 0x6bef, 0x8892, 0x78b3, 0x63a2, 0x53f9, 0x70ad, 0x6c64, 0x5858, 0x642a, 0x5802, 0x68e0, 0x819b, 0x5510,
+    0x7cd6, 0x5018, 0x8eba, 0x6dcc, 0x8d9f, 0x70eb, 0x638f, 0x6d9b, 0x6ed4, 0x7ee6, 0x8404, 0x6843, 0x9003, 0x6dd8, 0x9676, 0x8ba8,
+    0x5957, 0x7279, 0x85e4, 0x817e, 0x75bc, 0x8a8a, 0x68af, 0x5254, 0x8e22, 0x9511, 0x63d0, 0x9898, 0x8e44, 0x557c, 0x4f53, 0x66ff,
+    0x568f, 0x60d5, 0x6d95, 0x5243, 0x5c49, 0x5929, 0x6dfb, 0x586b, 0x7530, 0x751c, 0x606c, 0x8214, 0x8146, 0x6311, 0x6761, 0x8fe2,
+    0x773a, 0x8df3, 0x8d34, 0x94c1, 0x5e16, 0x5385, 0x542c, 0x70c3, 0x6c40, 0x5ef7, 0x505c, 0x4ead, 0x5ead, 0x633a, 0x8247, 0x901a,
+    0x6850, 0x916e, 0x77b3, 0x540c, 0x94dc, 0x5f64, 0x7ae5, 0x6876, 0x6345, 0x7b52, 0x7edf, 0x75db, 0x5077, 0x6295, 0x5934, 0x900f,
+    0x51f8, 0x79c3, 0x7a81, 0x56fe, 0x5f92, 0x9014, 0x6d82, 0x5c60, 0x571f, 0x5410, 0x5154, 0x6e4d, 0x56e2, 0x63a8, 0x9893, 0x817f,
+    0x8715, 0x892a, 0x9000, 0x541e, 0x5c6f, 0x81c0, 0x62d6, 0x6258, 0x8131, 0x9e35, 0x9640, 0x9a6e, 0x9a7c, 0x692d, 0x59a5, 0x62d3,
+    0x553e, 0x6316, 0x54c7, 0x86d9, 0x6d3c, 0x5a03, 0x74e6, 0x889c, 0x6b6a, 0x5916, 0x8c4c, 0x5f2f, 0x6e7e, 0x73a9, 0x987d, 0x4e38,
+    0x70f7, 0x5b8c, 0x7897, 0x633d, 0x665a, 0x7696, 0x60cb, 0x5b9b, 0x5a49, 0x4e07, 0x8155, 0x6c6a, 0x738b, 0x4ea1, 0x6789, 0x7f51,
+    0x5f80, 0x65fa, 0x671b, 0x5fd8, 0x5984, 0x5a01, 0x5dcd, 0x5fae, 0x5371, 0x97e6, 0x8fdd, 0x6845, 0x56f4, 0x552f, 0x60df, 0x4e3a,
+    0x6f4d, 0x7ef4, 0x82c7, 0x840e, 0x59d4, 0x4f1f, 0x4f2a, 0x5c3e, 0x7eac, 0x672a, 0x851a, 0x5473, 0x754f, 0x80c3, 0x5582, 0x9b4f,
+    0x4f4d, 0x6e2d, 0x8c13, 0x5c09, 0x6170, 0x536b, 0x761f, 0x6e29, 0x868a, 0x6587, 0x95fb, 0x7eb9, 0x543b, 0x7a33, 0x7d0a, 0x95ee,
+    0x55e1, 0x7fc1, 0x74ee, 0x631d, 0x8717, 0x6da1, 0x7a9d, 0x6211, 0x65a1, 0x5367, 0x63e1, 0x6c83, 0x5deb, 0x545c, 0x94a8, 0x4e4c,
+    0x6c61, 0x8bec, 0x5c4b, 0x65e0, 0x829c, 0x68a7, 0x543e, 0x5434, 0x6bcb, 0x6b66, 0x4e94, 0x6342, 0x5348, 0x821e, 0x4f0d, 0x4fae,
+    0x575e, 0x620a, 0x96fe, 0x6664, 0x7269, 0x52ff, 0x52a1, 0x609f, 0x8bef, 0x6614, 0x7199, 0x6790, 0x897f, 0x7852, 0x77fd, 0x6670,
+    0x563b, 0x5438, 0x9521, 0x727a, 0x7a00, 0x606f, 0x5e0c, 0x6089, 0x819d, 0x5915, 0x60dc, 0x7184, 0x70ef, 0x6eaa, 0x6c50, 0x7280,
+    0x6a84, 0x88ad, 0x5e2d, 0x4e60, 0x5ab3, 0x559c, 0x94e3, 0x6d17, 0x7cfb, 0x9699, 0x620f, 0x7ec6, 0x778e, 0x867e, 0x5323, 0x971e,
+    0x8f96, 0x6687, 0x5ce1, 0x4fa0, 0x72ed, 0x4e0b, 0x53a6, 0x590f, 0x5413, 0x6380, 0x9528, 0x5148, 0x4ed9, 0x9c9c, 0x7ea4, 0x54b8,
+    0x8d24, 0x8854, 0x8237, 0x95f2, 0x6d8e, 0x5f26, 0x5acc, 0x663e, 0x9669, 0x73b0, 0x732e, 0x53bf, 0x817a, 0x9985, 0x7fa1, 0x5baa,
+    0x9677, 0x9650, 0x7ebf, 0x76f8, 0x53a2, 0x9576, 0x9999, 0x7bb1, 0x8944, 0x6e58, 0x4e61, 0x7fd4, 0x7965, 0x8be6, 0x60f3, 0x54cd,
+    0x4eab, 0x9879, 0x5df7, 0x6a61, 0x50cf, 0x5411, 0x8c61, 0x8427, 0x785d, 0x9704, 0x524a, 0x54ee, 0x56a3, 0x9500, 0x6d88, 0x5bb5,
+    0x6dc6, 0x6653, 0x5c0f, 0x5b5d, 0x6821, 0x8096, 0x5578, 0x7b11, 0x6548, 0x6954, 0x4e9b, 0x6b47, 0x874e, 0x978b, 0x534f, 0x631f,
+    0x643a, 0x90aa, 0x659c, 0x80c1, 0x8c10, 0x5199, 0x68b0, 0x5378, 0x87f9, 0x61c8, 0x6cc4, 0x6cfb, 0x8c22, 0x5c51, 0x85aa, 0x82af,
+    0x950c, 0x6b23, 0x8f9b, 0x65b0, 0x5ffb, 0x5fc3, 0x4fe1, 0x8845, 0x661f, 0x8165, 0x7329, 0x60fa, 0x5174, 0x5211, 0x578b, 0x5f62,
+    0x90a2, 0x884c, 0x9192, 0x5e78, 0x674f, 0x6027, 0x59d3, 0x5144, 0x51f6, 0x80f8, 0x5308, 0x6c79, 0x96c4, 0x718a, 0x4f11, 0x4fee,
+    0x7f9e, 0x673d, 0x55c5, 0x9508, 0x79c0, 0x8896, 0x7ee3, 0x589f, 0x620c, 0x9700, 0x865a, 0x5618, 0x987b, 0x5f90, 0x8bb8, 0x84c4,
+    0x9157, 0x53d9, 0x65ed, 0x5e8f, 0x755c, 0x6064, 0x7d6e, 0x5a7f, 0x7eea, 0x7eed, 0x8f69, 0x55a7, 0x5ba3, 0x60ac, 0x65cb, 0x7384,
+    0x9009, 0x7663, 0x7729, 0x7eda, 0x9774, 0x859b, 0x5b66, 0x7a74, 0x96ea, 0x8840, 0x52cb, 0x718f, 0x5faa, 0x65ec, 0x8be2, 0x5bfb,
+    0x9a6f, 0x5de1, 0x6b89, 0x6c5b, 0x8bad, 0x8baf, 0x900a, 0x8fc5, 0x538b, 0x62bc, 0x9e26, 0x9e2d, 0x5440, 0x4e2b, 0x82bd, 0x7259,
+    0x869c, 0x5d16, 0x8859, 0x6daf, 0x96c5, 0x54d1, 0x4e9a, 0x8bb6, 0x7109, 0x54bd, 0x9609, 0x70df, 0x6df9, 0x76d0, 0x4e25, 0x7814,
+    0x8712, 0x5ca9, 0x5ef6, 0x8a00, 0x989c, 0x960e, 0x708e, 0x6cbf, 0x5944, 0x63a9, 0x773c, 0x884d, 0x6f14, 0x8273, 0x5830, 0x71d5,
+    0x538c, 0x781a, 0x96c1, 0x5501, 0x5f66, 0x7130, 0x5bb4, 0x8c1a, 0x9a8c, 0x6b83, 0x592e, 0x9e2f, 0x79e7, 0x6768, 0x626c, 0x4f6f,
+    0x75a1, 0x7f8a, 0x6d0b, 0x9633, 0x6c27, 0x4ef0, 0x75d2, 0x517b, 0x6837, 0x6f3e, 0x9080, 0x8170, 0x5996, 0x7476, 0x6447, 0x5c27,
+    0x9065, 0x7a91, 0x8c23, 0x59da, 0x54ac, 0x8200, 0x836f, 0x8981, 0x8000, 0x6930, 0x564e, 0x8036, 0x7237, 0x91ce, 0x51b6, 0x4e5f,
+    0x9875, 0x6396, 0x4e1a, 0x53f6, 0x66f3, 0x814b, 0x591c, 0x6db2, 0x4e00, 0x58f9, 0x533b, 0x63d6, 0x94f1, 0x4f9d, 0x4f0a, 0x8863,
+    0x9890, 0x5937, 0x9057, 0x79fb, 0x4eea, 0x80f0, 0x7591, 0x6c82, 0x5b9c, 0x59e8, 0x5f5d, 0x6905, 0x8681, 0x501a, 0x5df2, 0x4e59,
+    0x77e3, 0x4ee5, 0x827a, 0x6291, 0x6613, 0x9091, 0x5c79, 0x4ebf, 0x5f79, 0x81c6, 0x9038, 0x8084, 0x75ab, 0x4ea6, 0x88d4, 0x610f,
+    0x6bc5, 0x5fc6, 0x4e49, 0x76ca, 0x6ea2, 0x8be3, 0x8bae, 0x8c0a, 0x8bd1, 0x5f02, 0x7ffc, 0x7fcc, 0x7ece, 0x8335, 0x836b, 0x56e0,
+    0x6bb7, 0x97f3, 0x9634, 0x59fb, 0x541f, 0x94f6, 0x6deb, 0x5bc5, 0x996e, 0x5c39, 0x5f15, 0x9690, 0x5370, 0x82f1, 0x6a31, 0x5a74,
+    0x9e70, 0x5e94, 0x7f28, 0x83b9, 0x8424, 0x8425, 0x8367, 0x8747, 0x8fce, 0x8d62, 0x76c8, 0x5f71, 0x9896, 0x786c, 0x6620, 0x54df,
+    0x62e5, 0x4f63, 0x81c3, 0x75c8, 0x5eb8, 0x96cd, 0x8e0a, 0x86f9, 0x548f, 0x6cf3, 0x6d8c, 0x6c38, 0x607f, 0x52c7, 0x7528, 0x5e7d,
+    0x4f18, 0x60a0, 0x5fe7, 0x5c24, 0x7531, 0x90ae, 0x94c0, 0x72b9, 0x6cb9, 0x6e38, 0x9149, 0x6709, 0x53cb, 0x53f3, 0x4f51, 0x91c9,
+    0x8bf1, 0x53c8, 0x5e7c, 0x8fc2, 0x6de4, 0x4e8e, 0x76c2, 0x6986, 0x865e, 0x611a, 0x8206, 0x4f59, 0x4fde, 0x903e, 0x9c7c, 0x6109,
+    0x6e1d, 0x6e14, 0x9685, 0x4e88, 0x5a31, 0x96e8, 0x4e0e, 0x5c7f, 0x79b9, 0x5b87, 0x8bed, 0x7fbd, 0x7389, 0x57df, 0x828b, 0x90c1,
+    0x5401, 0x9047, 0x55bb, 0x5cea, 0x5fa1, 0x6108, 0x6b32, 0x72f1, 0x80b2, 0x8a89, 0x6d74, 0x5bd3, 0x88d5, 0x9884, 0x8c6b, 0x9a6d,
+    0x9e33, 0x6e0a, 0x51a4, 0x5143, 0x57a3, 0x8881, 0x539f, 0x63f4, 0x8f95, 0x56ed, 0x5458, 0x5706, 0x733f, 0x6e90, 0x7f18, 0x8fdc,
+    0x82d1, 0x613f, 0x6028, 0x9662, 0x66f0, 0x7ea6, 0x8d8a, 0x8dc3, 0x94a5, 0x5cb3, 0x7ca4, 0x6708, 0x60a6, 0x9605, 0x8018, 0x4e91,
+    0x90e7, 0x5300, 0x9668, 0x5141, 0x8fd0, 0x8574, 0x915d, 0x6655, 0x97f5, 0x5b55, 0x531d, 0x7838, 0x6742, 0x683d, 0x54c9, 0x707e,
+    0x5bb0, 0x8f7d, 0x518d, 0x5728, 0x54b1, 0x6512, 0x6682, 0x8d5e, 0x8d43, 0x810f, 0x846c, 0x906d, 0x7cdf, 0x51ff, 0x85fb, 0x67a3,
+    0x65e9, 0x6fa1, 0x86a4, 0x8e81, 0x566a, 0x9020, 0x7682, 0x7076, 0x71e5, 0x8d23, 0x62e9, 0x5219, 0x6cfd, 0x8d3c, 0x600e, 0x589e,
+    0x618e, 0x66fe, 0x8d60, 0x624e, 0x55b3, 0x6e23, 0x672d, 0x8f67, 0x94e1, 0x95f8, 0x7728, 0x6805, 0x69a8, 0x548b, 0x4e4d, 0x70b8,
+    0x8bc8, 0x6458, 0x658b, 0x5b85, 0x7a84, 0x503a, 0x5be8, 0x77bb, 0x6be1, 0x8a79, 0x7c98, 0x6cbe, 0x76cf, 0x65a9, 0x8f97, 0x5d2d,
+    0x5c55, 0x8638, 0x6808, 0x5360, 0x6218, 0x7ad9, 0x6e5b, 0x7efd, 0x6a1f, 0x7ae0, 0x5f70, 0x6f33, 0x5f20, 0x638c, 0x6da8, 0x6756,
+    0x4e08, 0x5e10, 0x8d26, 0x4ed7, 0x80c0, 0x7634, 0x969c, 0x62db, 0x662d, 0x627e, 0x6cbc, 0x8d75, 0x7167, 0x7f69, 0x5146, 0x8087,
+    0x53ec, 0x906e, 0x6298, 0x54f2, 0x86f0, 0x8f99, 0x8005, 0x9517, 0x8517, 0x8fd9, 0x6d59, 0x73cd, 0x659f, 0x771f, 0x7504, 0x7827,
+    0x81fb, 0x8d1e, 0x9488, 0x4fa6, 0x6795, 0x75b9, 0x8bca, 0x9707, 0x632f, 0x9547, 0x9635, 0x84b8, 0x6323, 0x7741, 0x5f81, 0x72f0,
+    0x4e89, 0x6014, 0x6574, 0x62ef, 0x6b63, 0x653f, 0x5e27, 0x75c7, 0x90d1, 0x8bc1, 0x829d, 0x679d, 0x652f, 0x5431, 0x8718, 0x77e5,
+    0x80a2, 0x8102, 0x6c41, 0x4e4b, 0x7ec7, 0x804c, 0x76f4, 0x690d, 0x6b96, 0x6267, 0x503c, 0x4f84, 0x5740, 0x6307, 0x6b62, 0x8dbe,
+    0x53ea, 0x65e8, 0x7eb8, 0x5fd7, 0x631a, 0x63b7, 0x81f3, 0x81f4, 0x7f6e, 0x5e1c, 0x5cd9, 0x5236, 0x667a, 0x79e9, 0x7a1a, 0x8d28,
+    0x7099, 0x75d4, 0x6ede, 0x6cbb, 0x7a92, 0x4e2d, 0x76c5, 0x5fe0, 0x949f, 0x8877, 0x7ec8, 0x79cd, 0x80bf, 0x91cd, 0x4ef2, 0x4f17,
+    0x821f, 0x5468, 0x5dde, 0x6d32, 0x8bcc, 0x7ca5, 0x8f74, 0x8098, 0x5e1a, 0x5492, 0x76b1, 0x5b99, 0x663c, 0x9aa4, 0x73e0, 0x682a,
+    0x86db, 0x6731, 0x732a, 0x8bf8, 0x8bdb, 0x9010, 0x7af9, 0x70db, 0x716e, 0x62c4, 0x77a9, 0x5631, 0x4e3b, 0x8457, 0x67f1, 0x52a9,
+    0x86c0, 0x8d2e, 0x94f8, 0x7b51, 0x4f4f, 0x6ce8, 0x795d, 0x9a7b, 0x6293, 0x722a, 0x62fd, 0x4e13, 0x7816, 0x8f6c, 0x64b0, 0x8d5a,
+    0x7bc6, 0x6869, 0x5e84, 0x88c5, 0x5986, 0x649e, 0x58ee, 0x72b6, 0x690e, 0x9525, 0x8ffd, 0x8d58, 0x5760, 0x7f00, 0x8c06, 0x51c6,
+    0x6349, 0x62d9, 0x5353, 0x684c, 0x7422, 0x8301, 0x914c, 0x5544, 0x7740, 0x707c, 0x6d4a, 0x5179, 0x54a8, 0x8d44, 0x59ff, 0x6ecb,
+    0x6dc4, 0x5b5c, 0x7d2b, 0x4ed4, 0x7c7d, 0x6ed3, 0x5b50, 0x81ea, 0x6e0d, 0x5b57, 0x9b03, 0x68d5, 0x8e2a, 0x5b97, 0x7efc, 0x603b,
+    0x7eb5, 0x90b9, 0x8d70, 0x594f, 0x63cd, 0x79df, 0x8db3, 0x5352, 0x65cf, 0x7956, 0x8bc5, 0x963b, 0x7ec4, 0x94bb, 0x7e82, 0x5634,
+    0x9189, 0x6700, 0x7f6a, 0x5c0a, 0x9075, 0x6628, 0x5de6, 0x4f50, 0x67de, 0x505a, 0x4f5c, 0x5750, 0x5ea7, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x4e8d, 0x4e0c, 0x5140, 0x4e10, 0x5eff, 0x5345, 0x4e15, 0x4e98, 0x4e1e, 0x9b32, 0x5b6c, 0x5669, 0x4e28, 0x79ba,
+    0x4e3f, 0x5315, 0x4e47, 0x592d, 0x723b, 0x536e, 0x6c10, 0x56df, 0x80e4, 0x9997, 0x6bd3, 0x777e, 0x9f17, 0x4e36, 0x4e9f, 0x9f10,
+    0x4e5c, 0x4e69, 0x4e93, 0x8288, 0x5b5b, 0x556c, 0x560f, 0x4ec4, 0x538d, 0x539d, 0x53a3, 0x53a5, 0x53ae, 0x9765, 0x8d5d, 0x531a,
+    0x53f5, 0x5326, 0x532e, 0x533e, 0x8d5c, 0x5366, 0x5363, 0x5202, 0x5208, 0x520e, 0x522d, 0x5233, 0x523f, 0x5240, 0x524c, 0x525e,
+    0x5261, 0x525c, 0x84af, 0x527d, 0x5282, 0x5281, 0x5290, 0x5293, 0x5182, 0x7f54, 0x4ebb, 0x4ec3, 0x4ec9, 0x4ec2, 0x4ee8, 0x4ee1,
+    0x4eeb, 0x4ede, 0x4f1b, 0x4ef3, 0x4f22, 0x4f64, 0x4ef5, 0x4f25, 0x4f27, 0x4f09, 0x4f2b, 0x4f5e, 0x4f67, 0x6538, 0x4f5a, 0x4f5d,
+    0x4f5f, 0x4f57, 0x4f32, 0x4f3d, 0x4f76, 0x4f74, 0x4f91, 0x4f89, 0x4f83, 0x4f8f, 0x4f7e, 0x4f7b, 0x4faa, 0x4f7c, 0x4fac, 0x4f94,
+    0x4fe6, 0x4fe8, 0x4fea, 0x4fc5, 0x4fda, 0x4fe3, 0x4fdc, 0x4fd1, 0x4fdf, 0x4ff8, 0x5029, 0x504c, 0x4ff3, 0x502c, 0x500f, 0x502e,
+    0x502d, 0x4ffe, 0x501c, 0x500c, 0x5025, 0x5028, 0x507e, 0x5043, 0x5055, 0x5048, 0x504e, 0x506c, 0x507b, 0x50a5, 0x50a7, 0x50a9,
+    0x50ba, 0x50d6, 0x5106, 0x50ed, 0x50ec, 0x50e6, 0x50ee, 0x5107, 0x510b, 0x4edd, 0x6c3d, 0x4f58, 0x4f65, 0x4fce, 0x9fa0, 0x6c46,
+    0x7c74, 0x516e, 0x5dfd, 0x9ec9, 0x9998, 0x5181, 0x5914, 0x52f9, 0x530d, 0x8a07, 0x5310, 0x51eb, 0x5919, 0x5155, 0x4ea0, 0x5156,
+    0x4eb3, 0x886e, 0x88a4, 0x4eb5, 0x8114, 0x88d2, 0x7980, 0x5b34, 0x8803, 0x7fb8, 0x51ab, 0x51b1, 0x51bd, 0x51bc, 0x51c7, 0x5196,
+    0x51a2, 0x51a5, 0x8ba0, 0x8ba6, 0x8ba7, 0x8baa, 0x8bb4, 0x8bb5, 0x8bb7, 0x8bc2, 0x8bc3, 0x8bcb, 0x8bcf, 0x8bce, 0x8bd2, 0x8bd3,
+    0x8bd4, 0x8bd6, 0x8bd8, 0x8bd9, 0x8bdc, 0x8bdf, 0x8be0, 0x8be4, 0x8be8, 0x8be9, 0x8bee, 0x8bf0, 0x8bf3, 0x8bf6, 0x8bf9, 0x8bfc,
+    0x8bff, 0x8c00, 0x8c02, 0x8c04, 0x8c07, 0x8c0c, 0x8c0f, 0x8c11, 0x8c12, 0x8c14, 0x8c15, 0x8c16, 0x8c19, 0x8c1b, 0x8c18, 0x8c1d,
+    0x8c1f, 0x8c20, 0x8c21, 0x8c25, 0x8c27, 0x8c2a, 0x8c2b, 0x8c2e, 0x8c2f, 0x8c32, 0x8c33, 0x8c35, 0x8c36, 0x5369, 0x537a, 0x961d,
+    0x9622, 0x9621, 0x9631, 0x962a, 0x963d, 0x963c, 0x9642, 0x9649, 0x9654, 0x965f, 0x9667, 0x966c, 0x9672, 0x9674, 0x9688, 0x968d,
+    0x9697, 0x96b0, 0x9097, 0x909b, 0x909d, 0x9099, 0x90ac, 0x90a1, 0x90b4, 0x90b3, 0x90b6, 0x90ba, 0x90b8, 0x90b0, 0x90cf, 0x90c5,
+    0x90be, 0x90d0, 0x90c4, 0x90c7, 0x90d3, 0x90e6, 0x90e2, 0x90dc, 0x90d7, 0x90db, 0x90eb, 0x90ef, 0x90fe, 0x9104, 0x9122, 0x911e,
+    0x9123, 0x9131, 0x912f, 0x9139, 0x9143, 0x9146, 0x520d, 0x5942, 0x52a2, 0x52ac, 0x52ad, 0x52be, 0x54ff, 0x52d0, 0x52d6, 0x52f0,
+    0x53df, 0x71ee, 0x77cd, 0x5ef4, 0x51f5, 0x51fc, 0x9b2f, 0x53b6, 0x5f01, 0x755a, 0x5def, 0x574c, 0x57a9, 0x57a1, 0x587e, 0x58bc,
+    0x58c5, 0x58d1, 0x5729, 0x572c, 0x572a, 0x5733, 0x5739, 0x572e, 0x572f, 0x575c, 0x573b, 0x5742, 0x5769, 0x5785, 0x576b, 0x5786,
+    0x577c, 0x577b, 0x5768, 0x576d, 0x5776, 0x5773, 0x57ad, 0x57a4, 0x578c, 0x57b2, 0x57cf, 0x57a7, 0x57b4, 0x5793, 0x57a0, 0x57d5,
+    0x57d8, 0x57da, 0x57d9, 0x57d2, 0x57b8, 0x57f4, 0x57ef, 0x57f8, 0x57e4, 0x57dd, 0x580b, 0x580d, 0x57fd, 0x57ed, 0x5800, 0x581e,
+    0x5819, 0x5844, 0x5820, 0x5865, 0x586c, 0x5881, 0x5889, 0x589a, 0x5880, 0x99a8, 0x9f19, 0x61ff, 0x8279, 0x827d, 0x827f, 0x828f,
+    0x828a, 0x82a8, 0x8284, 0x828e, 0x8291, 0x8297, 0x8299, 0x82ab, 0x82b8, 0x82be, 0x82b0, 0x82c8, 0x82ca, 0x82e3, 0x8298, 0x82b7,
+    0x82ae, 0x82cb, 0x82cc, 0x82c1, 0x82a9, 0x82b4, 0x82a1, 0x82aa, 0x829f, 0x82c4, 0x82ce, 0x82a4, 0x82e1, 0x8309, 0x82f7, 0x82e4,
+    0x830f, 0x8307, 0x82dc, 0x82f4, 0x82d2, 0x82d8, 0x830c, 0x82fb, 0x82d3, 0x8311, 0x831a, 0x8306, 0x8314, 0x8315, 0x82e0, 0x82d5,
+    0x831c, 0x8351, 0x835b, 0x835c, 0x8308, 0x8392, 0x833c, 0x8334, 0x8331, 0x839b, 0x835e, 0x832f, 0x834f, 0x8347, 0x8343, 0x835f,
+    0x8340, 0x8317, 0x8360, 0x832d, 0x833a, 0x8333, 0x8366, 0x8365, 0x8368, 0x831b, 0x8369, 0x836c, 0x836a, 0x836d, 0x836e, 0x83b0,
+    0x8378, 0x83b3, 0x83b4, 0x83a0, 0x83aa, 0x8393, 0x839c, 0x8385, 0x837c, 0x83b6, 0x83a9, 0x837d, 0x83b8, 0x837b, 0x8398, 0x839e,
+    0x83a8, 0x83ba, 0x83bc, 0x83c1, 0x8401, 0x83e5, 0x83d8, 0x5807, 0x8418, 0x840b, 0x83dd, 0x83fd, 0x83d6, 0x841c, 0x8438, 0x8411,
+    0x8406, 0x83d4, 0x83df, 0x840f, 0x8403, 0x83f8, 0x83f9, 0x83ea, 0x83c5, 0x83c0, 0x8426, 0x83f0, 0x83e1, 0x845c, 0x8451, 0x845a,
+    0x8459, 0x8473, 0x8487, 0x8488, 0x847a, 0x8489, 0x8478, 0x843c, 0x8446, 0x8469, 0x8476, 0x848c, 0x848e, 0x8431, 0x846d, 0x84c1,
+    0x84cd, 0x84d0, 0x84e6, 0x84bd, 0x84d3, 0x84ca, 0x84bf, 0x84ba, 0x84e0, 0x84a1, 0x84b9, 0x84b4, 0x8497, 0x84e5, 0x84e3, 0x850c,
+    0x750d, 0x8538, 0x84f0, 0x8539, 0x851f, 0x853a, 0x8556, 0x853b, 0x84ff, 0x84fc, 0x8559, 0x8548, 0x8568, 0x8564, 0x855e, 0x857a,
+    0x77a2, 0x8543, 0x8572, 0x857b, 0x85a4, 0x85a8, 0x8587, 0x858f, 0x8579, 0x85ae, 0x859c, 0x8585, 0x85b9, 0x85b7, 0x85b0, 0x85d3,
+    0x85c1, 0x85dc, 0x85ff, 0x8627, 0x8605, 0x8629, 0x8616, 0x863c, 0x5efe, 0x5f08, 0x593c, 0x5941, 0x8037, 0x5955, 0x595a, 0x5958,
+    0x530f, 0x5c22, 0x5c25, 0x5c2c, 0x5c34, 0x624c, 0x626a, 0x629f, 0x62bb, 0x62ca, 0x62da, 0x62d7, 0x62ee, 0x6322, 0x62f6, 0x6339,
+    0x634b, 0x6343, 0x63ad, 0x63f6, 0x6371, 0x637a, 0x638e, 0x63b4, 0x636d, 0x63ac, 0x638a, 0x6369, 0x63ae, 0x63bc, 0x63f2, 0x63f8,
+    0x63e0, 0x63ff, 0x63c4, 0x63de, 0x63ce, 0x6452, 0x63c6, 0x63be, 0x6445, 0x6441, 0x640b, 0x641b, 0x6420, 0x640c, 0x6426, 0x6421,
+    0x645e, 0x6484, 0x646d, 0x6496, 0x647a, 0x64b7, 0x64b8, 0x6499, 0x64ba, 0x64c0, 0x64d0, 0x64d7, 0x64e4, 0x64e2, 0x6509, 0x6525,
+    0x652e, 0x5f0b, 0x5fd2, 0x7519, 0x5f11, 0x535f, 0x53f1, 0x53fd, 0x53e9, 0x53e8, 0x53fb, 0x5412, 0x5416, 0x5406, 0x544b, 0x5452,
+    0x5453, 0x5454, 0x5456, 0x5443, 0x5421, 0x5457, 0x5459, 0x5423, 0x5432, 0x5482, 0x5494, 0x5477, 0x5471, 0x5464, 0x549a, 0x549b,
+    0x5484, 0x5476, 0x5466, 0x549d, 0x54d0, 0x54ad, 0x54c2, 0x54b4, 0x54d2, 0x54a7, 0x54a6, 0x54d3, 0x54d4, 0x5472, 0x54a3, 0x54d5,
+    0x54bb, 0x54bf, 0x54cc, 0x54d9, 0x54da, 0x54dc, 0x54a9, 0x54aa, 0x54a4, 0x54dd, 0x54cf, 0x54de, 0x551b, 0x54e7, 0x5520, 0x54fd,
+    0x5514, 0x54f3, 0x5522, 0x5523, 0x550f, 0x5511, 0x5527, 0x552a, 0x5567, 0x558f, 0x55b5, 0x5549, 0x556d, 0x5541, 0x5555, 0x553f,
+    0x5550, 0x553c, 0x5537, 0x5556, 0x5575, 0x5576, 0x5577, 0x5533, 0x5530, 0x555c, 0x558b, 0x55d2, 0x5583, 0x55b1, 0x55b9, 0x5588,
+    0x5581, 0x559f, 0x557e, 0x55d6, 0x5591, 0x557b, 0x55df, 0x55bd, 0x55be, 0x5594, 0x5599, 0x55ea, 0x55f7, 0x55c9, 0x561f, 0x55d1,
+    0x55eb, 0x55ec, 0x55d4, 0x55e6, 0x55dd, 0x55c4, 0x55ef, 0x55e5, 0x55f2, 0x55f3, 0x55cc, 0x55cd, 0x55e8, 0x55f5, 0x55e4, 0x8f94,
+    0x561e, 0x5608, 0x560c, 0x5601, 0x5624, 0x5623, 0x55fe, 0x5600, 0x5627, 0x562d, 0x5658, 0x5639, 0x5657, 0x562c, 0x564d, 0x5662,
+    0x5659, 0x565c, 0x564c, 0x5654, 0x5686, 0x5664, 0x5671, 0x566b, 0x567b, 0x567c, 0x5685, 0x5693, 0x56af, 0x56d4, 0x56d7, 0x56dd,
+    0x56e1, 0x56f5, 0x56eb, 0x56f9, 0x56ff, 0x5704, 0x570a, 0x5709, 0x571c, 0x5e0f, 0x5e19, 0x5e14, 0x5e11, 0x5e31, 0x5e3b, 0x5e3c,
+    0x5e37, 0x5e44, 0x5e54, 0x5e5b, 0x5e5e, 0x5e61, 0x5c8c, 0x5c7a, 0x5c8d, 0x5c90, 0x5c96, 0x5c88, 0x5c98, 0x5c99, 0x5c91, 0x5c9a,
+    0x5c9c, 0x5cb5, 0x5ca2, 0x5cbd, 0x5cac, 0x5cab, 0x5cb1, 0x5ca3, 0x5cc1, 0x5cb7, 0x5cc4, 0x5cd2, 0x5ce4, 0x5ccb, 0x5ce5, 0x5d02,
+    0x5d03, 0x5d27, 0x5d26, 0x5d2e, 0x5d24, 0x5d1e, 0x5d06, 0x5d1b, 0x5d58, 0x5d3e, 0x5d34, 0x5d3d, 0x5d6c, 0x5d5b, 0x5d6f, 0x5d5d,
+    0x5d6b, 0x5d4b, 0x5d4a, 0x5d69, 0x5d74, 0x5d82, 0x5d99, 0x5d9d, 0x8c73, 0x5db7, 0x5dc5, 0x5f73, 0x5f77, 0x5f82, 0x5f87, 0x5f89,
+    0x5f8c, 0x5f95, 0x5f99, 0x5f9c, 0x5fa8, 0x5fad, 0x5fb5, 0x5fbc, 0x8862, 0x5f61, 0x72ad, 0x72b0, 0x72b4, 0x72b7, 0x72b8, 0x72c3,
+    0x72c1, 0x72ce, 0x72cd, 0x72d2, 0x72e8, 0x72ef, 0x72e9, 0x72f2, 0x72f4, 0x72f7, 0x7301, 0x72f3, 0x7303, 0x72fa, 0x72fb, 0x7317,
+    0x7313, 0x7321, 0x730a, 0x731e, 0x731d, 0x7315, 0x7322, 0x7339, 0x7325, 0x732c, 0x7338, 0x7331, 0x7350, 0x734d, 0x7357, 0x7360,
+    0x736c, 0x736f, 0x737e, 0x821b, 0x5925, 0x98e7, 0x5924, 0x5902, 0x9963, 0x9967, 0x9968, 0x9969, 0x996a, 0x996b, 0x996c, 0x9974,
+    0x9977, 0x997d, 0x9980, 0x9984, 0x9987, 0x998a, 0x998d, 0x9990, 0x9991, 0x9993, 0x9994, 0x9995, 0x5e80, 0x5e91, 0x5e8b, 0x5e96,
+    0x5ea5, 0x5ea0, 0x5eb9, 0x5eb5, 0x5ebe, 0x5eb3, 0x8d53, 0x5ed2, 0x5ed1, 0x5edb, 0x5ee8, 0x5eea, 0x81ba, 0x5fc4, 0x5fc9, 0x5fd6,
+    0x5fcf, 0x6003, 0x5fee, 0x6004, 0x5fe1, 0x5fe4, 0x5ffe, 0x6005, 0x6006, 0x5fea, 0x5fed, 0x5ff8, 0x6019, 0x6035, 0x6026, 0x601b,
+    0x600f, 0x600d, 0x6029, 0x602b, 0x600a, 0x603f, 0x6021, 0x6078, 0x6079, 0x607b, 0x607a, 0x6042, 0x606a, 0x607d, 0x6096, 0x609a,
+    0x60ad, 0x609d, 0x6083, 0x6092, 0x608c, 0x609b, 0x60ec, 0x60bb, 0x60b1, 0x60dd, 0x60d8, 0x60c6, 0x60da, 0x60b4, 0x6120, 0x6126,
+    0x6115, 0x6123, 0x60f4, 0x6100, 0x610e, 0x612b, 0x614a, 0x6175, 0x61ac, 0x6194, 0x61a7, 0x61b7, 0x61d4, 0x61f5, 0x5fdd, 0x96b3,
+    0x95e9, 0x95eb, 0x95f1, 0x95f3, 0x95f5, 0x95f6, 0x95fc, 0x95fe, 0x9603, 0x9604, 0x9606, 0x9608, 0x960a, 0x960b, 0x960c, 0x960d,
+    0x960f, 0x9612, 0x9615, 0x9616, 0x9617, 0x9619, 0x961a, 0x4e2c, 0x723f, 0x6215, 0x6c35, 0x6c54, 0x6c5c, 0x6c4a, 0x6ca3, 0x6c85,
+    0x6c90, 0x6c94, 0x6c8c, 0x6c68, 0x6c69, 0x6c74, 0x6c76, 0x6c86, 0x6ca9, 0x6cd0, 0x6cd4, 0x6cad, 0x6cf7, 0x6cf8, 0x6cf1, 0x6cd7,
+    0x6cb2, 0x6ce0, 0x6cd6, 0x6cfa, 0x6ceb, 0x6cee, 0x6cb1, 0x6cd3, 0x6cef, 0x6cfe, 0x6d39, 0x6d27, 0x6d0c, 0x6d43, 0x6d48, 0x6d07,
+    0x6d04, 0x6d19, 0x6d0e, 0x6d2b, 0x6d4d, 0x6d2e, 0x6d35, 0x6d1a, 0x6d4f, 0x6d52, 0x6d54, 0x6d33, 0x6d91, 0x6d6f, 0x6d9e, 0x6da0,
+    0x6d5e, 0x6d93, 0x6d94, 0x6d5c, 0x6d60, 0x6d7c, 0x6d63, 0x6e1a, 0x6dc7, 0x6dc5, 0x6dde, 0x6e0e, 0x6dbf, 0x6de0, 0x6e11, 0x6de6,
+    0x6ddd, 0x6dd9, 0x6e16, 0x6dab, 0x6e0c, 0x6dae, 0x6e2b, 0x6e6e, 0x6e4e, 0x6e6b, 0x6eb2, 0x6e5f, 0x6e86, 0x6e53, 0x6e54, 0x6e32,
+    0x6e25, 0x6e44, 0x6edf, 0x6eb1, 0x6e98, 0x6ee0, 0x6f2d, 0x6ee2, 0x6ea5, 0x6ea7, 0x6ebd, 0x6ebb, 0x6eb7, 0x6ed7, 0x6eb4, 0x6ecf,
+    0x6e8f, 0x6ec2, 0x6e9f, 0x6f62, 0x6f46, 0x6f47, 0x6f24, 0x6f15, 0x6ef9, 0x6f2f, 0x6f36, 0x6f4b, 0x6f74, 0x6f2a, 0x6f09, 0x6f29,
+    0x6f89, 0x6f8d, 0x6f8c, 0x6f78, 0x6f72, 0x6f7c, 0x6f7a, 0x6fd1, 0x6fc9, 0x6fa7, 0x6fb9, 0x6fb6, 0x6fc2, 0x6fe1, 0x6fee, 0x6fde,
+    0x6fe0, 0x6fef, 0x701a, 0x7023, 0x701b, 0x7039, 0x7035, 0x704f, 0x705e, 0x5b80, 0x5b84, 0x5b95, 0x5b93, 0x5ba5, 0x5bb8, 0x752f,
+    0x9a9e, 0x6434, 0x5be4, 0x5bee, 0x8930, 0x5bf0, 0x8e47, 0x8b07, 0x8fb6, 0x8fd3, 0x8fd5, 0x8fe5, 0x8fee, 0x8fe4, 0x8fe9, 0x8fe6,
+    0x8ff3, 0x8fe8, 0x9005, 0x9004, 0x900b, 0x9026, 0x9011, 0x900d, 0x9016, 0x9021, 0x9035, 0x9036, 0x902d, 0x902f, 0x9044, 0x9051,
+    0x9052, 0x9050, 0x9068, 0x9058, 0x9062, 0x905b, 0x66b9, 0x9074, 0x907d, 0x9082, 0x9088, 0x9083, 0x908b, 0x5f50, 0x5f57, 0x5f56,
+    0x5f58, 0x5c3b, 0x54ab, 0x5c50, 0x5c59, 0x5b71, 0x5c63, 0x5c66, 0x7fbc, 0x5f2a, 0x5f29, 0x5f2d, 0x8274, 0x5f3c, 0x9b3b, 0x5c6e,
+    0x5981, 0x5983, 0x598d, 0x59a9, 0x59aa, 0x59a3, 0x5997, 0x59ca, 0x59ab, 0x599e, 0x59a4, 0x59d2, 0x59b2, 0x59af, 0x59d7, 0x59be,
+    0x5a05, 0x5a06, 0x59dd, 0x5a08, 0x59e3, 0x59d8, 0x59f9, 0x5a0c, 0x5a09, 0x5a32, 0x5a34, 0x5a11, 0x5a23, 0x5a13, 0x5a40, 0x5a67,
+    0x5a4a, 0x5a55, 0x5a3c, 0x5a62, 0x5a75, 0x80ec, 0x5aaa, 0x5a9b, 0x5a77, 0x5a7a, 0x5abe, 0x5aeb, 0x5ab2, 0x5ad2, 0x5ad4, 0x5ab8,
+    0x5ae0, 0x5ae3, 0x5af1, 0x5ad6, 0x5ae6, 0x5ad8, 0x5adc, 0x5b09, 0x5b17, 0x5b16, 0x5b32, 0x5b37, 0x5b40, 0x5c15, 0x5c1c, 0x5b5a,
+    0x5b65, 0x5b73, 0x5b51, 0x5b53, 0x5b62, 0x9a75, 0x9a77, 0x9a78, 0x9a7a, 0x9a7f, 0x9a7d, 0x9a80, 0x9a81, 0x9a85, 0x9a88, 0x9a8a,
+    0x9a90, 0x9a92, 0x9a93, 0x9a96, 0x9a98, 0x9a9b, 0x9a9c, 0x9a9d, 0x9a9f, 0x9aa0, 0x9aa2, 0x9aa3, 0x9aa5, 0x9aa7, 0x7e9f, 0x7ea1,
+    0x7ea3, 0x7ea5, 0x7ea8, 0x7ea9, 0x7ead, 0x7eb0, 0x7ebe, 0x7ec0, 0x7ec1, 0x7ec2, 0x7ec9, 0x7ecb, 0x7ecc, 0x7ed0, 0x7ed4, 0x7ed7,
+    0x7edb, 0x7ee0, 0x7ee1, 0x7ee8, 0x7eeb, 0x7eee, 0x7eef, 0x7ef1, 0x7ef2, 0x7f0d, 0x7ef6, 0x7efa, 0x7efb, 0x7efe, 0x7f01, 0x7f02,
+    0x7f03, 0x7f07, 0x7f08, 0x7f0b, 0x7f0c, 0x7f0f, 0x7f11, 0x7f12, 0x7f17, 0x7f19, 0x7f1c, 0x7f1b, 0x7f1f, 0x7f21, 0x7f22, 0x7f23,
+    0x7f24, 0x7f25, 0x7f26, 0x7f27, 0x7f2a, 0x7f2b, 0x7f2c, 0x7f2d, 0x7f2f, 0x7f30, 0x7f31, 0x7f32, 0x7f33, 0x7f35, 0x5e7a, 0x757f,
+    0x5ddb, 0x753e, 0x9095, 0x738e, 0x7391, 0x73ae, 0x73a2, 0x739f, 0x73cf, 0x73c2, 0x73d1, 0x73b7, 0x73b3, 0x73c0, 0x73c9, 0x73c8,
+    0x73e5, 0x73d9, 0x987c, 0x740a, 0x73e9, 0x73e7, 0x73de, 0x73ba, 0x73f2, 0x740f, 0x742a, 0x745b, 0x7426, 0x7425, 0x7428, 0x7430,
+    0x742e, 0x742c, 0x741b, 0x741a, 0x7441, 0x745c, 0x7457, 0x7455, 0x7459, 0x7477, 0x746d, 0x747e, 0x749c, 0x748e, 0x7480, 0x7481,
+    0x7487, 0x748b, 0x749e, 0x74a8, 0x74a9, 0x7490, 0x74a7, 0x74d2, 0x74ba, 0x97ea, 0x97eb, 0x97ec, 0x674c, 0x6753, 0x675e, 0x6748,
+    0x6769, 0x67a5, 0x6787, 0x676a, 0x6773, 0x6798, 0x67a7, 0x6775, 0x67a8, 0x679e, 0x67ad, 0x678b, 0x6777, 0x677c, 0x67f0, 0x6809,
+    0x67d8, 0x680a, 0x67e9, 0x67b0, 0x680c, 0x67d9, 0x67b5, 0x67da, 0x67b3, 0x67dd, 0x6800, 0x67c3, 0x67b8, 0x67e2, 0x680e, 0x67c1,
+    0x67fd, 0x6832, 0x6833, 0x6860, 0x6861, 0x684e, 0x6862, 0x6844, 0x6864, 0x6883, 0x681d, 0x6855, 0x6866, 0x6841, 0x6867, 0x6840,
+    0x683e, 0x684a, 0x6849, 0x6829, 0x68b5, 0x688f, 0x6874, 0x6877, 0x6893, 0x686b, 0x68c2, 0x696e, 0x68fc, 0x691f, 0x6920, 0x68f9,
+    0x6924, 0x68f0, 0x690b, 0x6901, 0x6957, 0x68e3, 0x6910, 0x6971, 0x6939, 0x6960, 0x6942, 0x695d, 0x6984, 0x696b, 0x6980, 0x6998,
+    0x6978, 0x6934, 0x69cc, 0x6987, 0x6988, 0x69ce, 0x6989, 0x6966, 0x6963, 0x6979, 0x699b, 0x69a7, 0x69bb, 0x69ab, 0x69ad, 0x69d4,
+    0x69b1, 0x69c1, 0x69ca, 0x69df, 0x6995, 0x69e0, 0x698d, 0x69ff, 0x6a2f, 0x69ed, 0x6a17, 0x6a18, 0x6a65, 0x69f2, 0x6a44, 0x6a3e,
+    0x6aa0, 0x6a50, 0x6a5b, 0x6a35, 0x6a8e, 0x6a79, 0x6a3d, 0x6a28, 0x6a58, 0x6a7c, 0x6a91, 0x6a90, 0x6aa9, 0x6a97, 0x6aab, 0x7337,
+    0x7352, 0x6b81, 0x6b82, 0x6b87, 0x6b84, 0x6b92, 0x6b93, 0x6b8d, 0x6b9a, 0x6b9b, 0x6ba1, 0x6baa, 0x8f6b, 0x8f6d, 0x8f71, 0x8f72,
+    0x8f73, 0x8f75, 0x8f76, 0x8f78, 0x8f77, 0x8f79, 0x8f7a, 0x8f7c, 0x8f7e, 0x8f81, 0x8f82, 0x8f84, 0x8f87, 0x8f8b, 0x8f8d, 0x8f8e,
+    0x8f8f, 0x8f98, 0x8f9a, 0x8ece, 0x620b, 0x6217, 0x621b, 0x621f, 0x6222, 0x6221, 0x6225, 0x6224, 0x622c, 0x81e7, 0x74ef, 0x74f4,
+    0x74ff, 0x750f, 0x7511, 0x7513, 0x6534, 0x65ee, 0x65ef, 0x65f0, 0x660a, 0x6619, 0x6772, 0x6603, 0x6615, 0x6600, 0x7085, 0x66f7,
+    0x661d, 0x6634, 0x6631, 0x6636, 0x6635, 0x8006, 0x665f, 0x6654, 0x6641, 0x664f, 0x6656, 0x6661, 0x6657, 0x6677, 0x6684, 0x668c,
+    0x66a7, 0x669d, 0x66be, 0x66db, 0x66dc, 0x66e6, 0x66e9, 0x8d32, 0x8d33, 0x8d36, 0x8d3b, 0x8d3d, 0x8d40, 0x8d45, 0x8d46, 0x8d48,
+    0x8d49, 0x8d47, 0x8d4d, 0x8d55, 0x8d59, 0x89c7, 0x89ca, 0x89cb, 0x89cc, 0x89ce, 0x89cf, 0x89d0, 0x89d1, 0x726e, 0x729f, 0x725d,
+    0x7266, 0x726f, 0x727e, 0x727f, 0x7284, 0x728b, 0x728d, 0x728f, 0x7292, 0x6308, 0x6332, 0x63b0, 0x643f, 0x64d8, 0x8004, 0x6bea,
+    0x6bf3, 0x6bfd, 0x6bf5, 0x6bf9, 0x6c05, 0x6c07, 0x6c06, 0x6c0d, 0x6c15, 0x6c18, 0x6c19, 0x6c1a, 0x6c21, 0x6c29, 0x6c24, 0x6c2a,
+    0x6c32, 0x6535, 0x6555, 0x656b, 0x724d, 0x7252, 0x7256, 0x7230, 0x8662, 0x5216, 0x809f, 0x809c, 0x8093, 0x80bc, 0x670a, 0x80bd,
+    0x80b1, 0x80ab, 0x80ad, 0x80b4, 0x80b7, 0x80e7, 0x80e8, 0x80e9, 0x80ea, 0x80db, 0x80c2, 0x80c4, 0x80d9, 0x80cd, 0x80d7, 0x6710,
+    0x80dd, 0x80eb, 0x80f1, 0x80f4, 0x80ed, 0x810d, 0x810e, 0x80f2, 0x80fc, 0x6715, 0x8112, 0x8c5a, 0x8136, 0x811e, 0x812c, 0x8118,
+    0x8132, 0x8148, 0x814c, 0x8153, 0x8174, 0x8159, 0x815a, 0x8171, 0x8160, 0x8169, 0x817c, 0x817d, 0x816d, 0x8167, 0x584d, 0x5ab5,
+    0x8188, 0x8182, 0x8191, 0x6ed5, 0x81a3, 0x81aa, 0x81cc, 0x6726, 0x81ca, 0x81bb, 0x81c1, 0x81a6, 0x6b24, 0x6b37, 0x6b39, 0x6b43,
+    0x6b46, 0x6b59, 0x98d1, 0x98d2, 0x98d3, 0x98d5, 0x98d9, 0x98da, 0x6bb3, 0x5f40, 0x6bc2, 0x89f3, 0x6590, 0x9f51, 0x6593, 0x65bc,
+    0x65c6, 0x65c4, 0x65c3, 0x65cc, 0x65ce, 0x65d2, 0x65d6, 0x7080, 0x709c, 0x7096, 0x709d, 0x70bb, 0x70c0, 0x70b7, 0x70ab, 0x70b1,
+    0x70e8, 0x70ca, 0x7110, 0x7113, 0x7116, 0x712f, 0x7131, 0x7173, 0x715c, 0x7168, 0x7145, 0x7172, 0x714a, 0x7178, 0x717a, 0x7198,
+    0x71b3, 0x71b5, 0x71a8, 0x71a0, 0x71e0, 0x71d4, 0x71e7, 0x71f9, 0x721d, 0x7228, 0x706c, 0x7118, 0x7166, 0x71b9, 0x623e, 0x623d,
+    0x6243, 0x6248, 0x6249, 0x793b, 0x7940, 0x7946, 0x7949, 0x795b, 0x795c, 0x7953, 0x795a, 0x7962, 0x7957, 0x7960, 0x796f, 0x7967,
+    0x797a, 0x7985, 0x798a, 0x799a, 0x79a7, 0x79b3, 0x5fd1, 0x5fd0, 0x603c, 0x605d, 0x605a, 0x6067, 0x6041, 0x6059, 0x6063, 0x60ab,
+    0x6106, 0x610d, 0x615d, 0x61a9, 0x619d, 0x61cb, 0x61d1, 0x6206, 0x8080, 0x807f, 0x6c93, 0x6cf6, 0x6dfc, 0x77f6, 0x77f8, 0x7800,
+    0x7809, 0x7817, 0x7818, 0x7811, 0x65ab, 0x782d, 0x781c, 0x781d, 0x7839, 0x783a, 0x783b, 0x781f, 0x783c, 0x7825, 0x782c, 0x7823,
+    0x7829, 0x784e, 0x786d, 0x7856, 0x7857, 0x7826, 0x7850, 0x7847, 0x784c, 0x786a, 0x789b, 0x7893, 0x789a, 0x7887, 0x789c, 0x78a1,
+    0x78a3, 0x78b2, 0x78b9, 0x78a5, 0x78d4, 0x78d9, 0x78c9, 0x78ec, 0x78f2, 0x7905, 0x78f4, 0x7913, 0x7924, 0x791e, 0x7934, 0x9f9b,
+    0x9ef9, 0x9efb, 0x9efc, 0x76f1, 0x7704, 0x770d, 0x76f9, 0x7707, 0x7708, 0x771a, 0x7722, 0x7719, 0x772d, 0x7726, 0x7735, 0x7738,
+    0x7750, 0x7751, 0x7747, 0x7743, 0x775a, 0x7768, 0x7762, 0x7765, 0x777f, 0x778d, 0x777d, 0x7780, 0x778c, 0x7791, 0x779f, 0x77a0,
+    0x77b0, 0x77b5, 0x77bd, 0x753a, 0x7540, 0x754e, 0x754b, 0x7548, 0x755b, 0x7572, 0x7579, 0x7583, 0x7f58, 0x7f61, 0x7f5f, 0x8a48,
+    0x7f68, 0x7f74, 0x7f71, 0x7f79, 0x7f81, 0x7f7e, 0x76cd, 0x76e5, 0x8832, 0x9485, 0x9486, 0x9487, 0x948b, 0x948a, 0x948c, 0x948d,
+    0x948f, 0x9490, 0x9494, 0x9497, 0x9495, 0x949a, 0x949b, 0x949c, 0x94a3, 0x94a4, 0x94ab, 0x94aa, 0x94ad, 0x94ac, 0x94af, 0x94b0,
+    0x94b2, 0x94b4, 0x94b6, 0x94b7, 0x94b8, 0x94b9, 0x94ba, 0x94bc, 0x94bd, 0x94bf, 0x94c4, 0x94c8, 0x94c9, 0x94ca, 0x94cb, 0x94cc,
+    0x94cd, 0x94ce, 0x94d0, 0x94d1, 0x94d2, 0x94d5, 0x94d6, 0x94d7, 0x94d9, 0x94d8, 0x94db, 0x94de, 0x94df, 0x94e0, 0x94e2, 0x94e4,
+    0x94e5, 0x94e7, 0x94e8, 0x94ea, 0x94e9, 0x94eb, 0x94ee, 0x94ef, 0x94f3, 0x94f4, 0x94f5, 0x94f7, 0x94f9, 0x94fc, 0x94fd, 0x94ff,
+    0x9503, 0x9502, 0x9506, 0x9507, 0x9509, 0x950a, 0x950d, 0x950e, 0x950f, 0x9512, 0x9513, 0x9514, 0x9515, 0x9516, 0x9518, 0x951b,
+    0x951d, 0x951e, 0x951f, 0x9522, 0x952a, 0x952b, 0x9529, 0x952c, 0x9531, 0x9532, 0x9534, 0x9536, 0x9537, 0x9538, 0x953c, 0x953e,
+    0x953f, 0x9542, 0x9535, 0x9544, 0x9545, 0x9546, 0x9549, 0x954c, 0x954e, 0x954f, 0x9552, 0x9553, 0x9554, 0x9556, 0x9557, 0x9558,
+    0x9559, 0x955b, 0x955e, 0x955f, 0x955d, 0x9561, 0x9562, 0x9564, 0x9565, 0x9566, 0x9567, 0x9568, 0x9569, 0x956a, 0x956b, 0x956c,
+    0x956f, 0x9571, 0x9572, 0x9573, 0x953a, 0x77e7, 0x77ec, 0x96c9, 0x79d5, 0x79ed, 0x79e3, 0x79eb, 0x7a06, 0x5d47, 0x7a03, 0x7a02,
+    0x7a1e, 0x7a14, 0x7a39, 0x7a37, 0x7a51, 0x9ecf, 0x99a5, 0x7a70, 0x7688, 0x768e, 0x7693, 0x7699, 0x76a4, 0x74de, 0x74e0, 0x752c,
+    0x9e20, 0x9e22, 0x9e28, 0x9e29, 0x9e2a, 0x9e2b, 0x9e2c, 0x9e32, 0x9e31, 0x9e36, 0x9e38, 0x9e37, 0x9e39, 0x9e3a, 0x9e3e, 0x9e41,
+    0x9e42, 0x9e44, 0x9e46, 0x9e47, 0x9e48, 0x9e49, 0x9e4b, 0x9e4c, 0x9e4e, 0x9e51, 0x9e55, 0x9e57, 0x9e5a, 0x9e5b, 0x9e5c, 0x9e5e,
+    0x9e63, 0x9e66, 0x9e67, 0x9e68, 0x9e69, 0x9e6a, 0x9e6b, 0x9e6c, 0x9e71, 0x9e6d, 0x9e73, 0x7592, 0x7594, 0x7596, 0x75a0, 0x759d,
+    0x75ac, 0x75a3, 0x75b3, 0x75b4, 0x75b8, 0x75c4, 0x75b1, 0x75b0, 0x75c3, 0x75c2, 0x75d6, 0x75cd, 0x75e3, 0x75e8, 0x75e6, 0x75e4,
+    0x75eb, 0x75e7, 0x7603, 0x75f1, 0x75fc, 0x75ff, 0x7610, 0x7600, 0x7605, 0x760c, 0x7617, 0x760a, 0x7625, 0x7618, 0x7615, 0x7619,
+    0x761b, 0x763c, 0x7622, 0x7620, 0x7640, 0x762d, 0x7630, 0x763f, 0x7635, 0x7643, 0x763e, 0x7633, 0x764d, 0x765e, 0x7654, 0x765c,
+    0x7656, 0x766b, 0x766f, 0x7fca, 0x7ae6, 0x7a78, 0x7a79, 0x7a80, 0x7a86, 0x7a88, 0x7a95, 0x7aa6, 0x7aa0, 0x7aac, 0x7aa8, 0x7aad,
+    0x7ab3, 0x8864, 0x8869, 0x8872, 0x887d, 0x887f, 0x8882, 0x88a2, 0x88c6, 0x88b7, 0x88bc, 0x88c9, 0x88e2, 0x88ce, 0x88e3, 0x88e5,
+    0x88f1, 0x891a, 0x88fc, 0x88e8, 0x88fe, 0x88f0, 0x8921, 0x8919, 0x8913, 0x891b, 0x890a, 0x8934, 0x892b, 0x8936, 0x8941, 0x8966,
+    0x897b, 0x758b, 0x80e5, 0x76b2, 0x76b4, 0x77dc, 0x8012, 0x8014, 0x8016, 0x801c, 0x8020, 0x8022, 0x8025, 0x8026, 0x8027, 0x8029,
+    0x8028, 0x8031, 0x800b, 0x8035, 0x8043, 0x8046, 0x804d, 0x8052, 0x8069, 0x8071, 0x8983, 0x9878, 0x9880, 0x9883, 0x9889, 0x988c,
+    0x988d, 0x988f, 0x9894, 0x989a, 0x989b, 0x989e, 0x989f, 0x98a1, 0x98a2, 0x98a5, 0x98a6, 0x864d, 0x8654, 0x866c, 0x866e, 0x867f,
+    0x867a, 0x867c, 0x867b, 0x86a8, 0x868d, 0x868b, 0x86ac, 0x869d, 0x86a7, 0x86a3, 0x86aa, 0x8693, 0x86a9, 0x86b6, 0x86c4, 0x86b5,
+    0x86ce, 0x86b0, 0x86ba, 0x86b1, 0x86af, 0x86c9, 0x86cf, 0x86b4, 0x86e9, 0x86f1, 0x86f2, 0x86ed, 0x86f3, 0x86d0, 0x8713, 0x86de,
+    0x86f4, 0x86df, 0x86d8, 0x86d1, 0x8703, 0x8707, 0x86f8, 0x8708, 0x870a, 0x870d, 0x8709, 0x8723, 0x873b, 0x871e, 0x8725, 0x872e,
+    0x871a, 0x873e, 0x8748, 0x8734, 0x8731, 0x8729, 0x8737, 0x873f, 0x8782, 0x8722, 0x877d, 0x877e, 0x877b, 0x8760, 0x8770, 0x874c,
+    0x876e, 0x878b, 0x8753, 0x8763, 0x877c, 0x8764, 0x8759, 0x8765, 0x8793, 0x87af, 0x87a8, 0x87d2, 0x87c6, 0x8788, 0x8785, 0x87ad,
+    0x8797, 0x8783, 0x87ab, 0x87e5, 0x87ac, 0x87b5, 0x87b3, 0x87cb, 0x87d3, 0x87bd, 0x87d1, 0x87c0, 0x87ca, 0x87db, 0x87ea, 0x87e0,
+    0x87ee, 0x8816, 0x8813, 0x87fe, 0x880a, 0x881b, 0x8821, 0x8839, 0x883c, 0x7f36, 0x7f42, 0x7f44, 0x7f45, 0x8210, 0x7afa, 0x7afd,
+    0x7b08, 0x7b03, 0x7b04, 0x7b15, 0x7b0a, 0x7b2b, 0x7b0f, 0x7b47, 0x7b38, 0x7b2a, 0x7b19, 0x7b2e, 0x7b31, 0x7b20, 0x7b25, 0x7b24,
+    0x7b33, 0x7b3e, 0x7b1e, 0x7b58, 0x7b5a, 0x7b45, 0x7b75, 0x7b4c, 0x7b5d, 0x7b60, 0x7b6e, 0x7b7b, 0x7b62, 0x7b72, 0x7b71, 0x7b90,
+    0x7ba6, 0x7ba7, 0x7bb8, 0x7bac, 0x7b9d, 0x7ba8, 0x7b85, 0x7baa, 0x7b9c, 0x7ba2, 0x7bab, 0x7bb4, 0x7bd1, 0x7bc1, 0x7bcc, 0x7bdd,
+    0x7bda, 0x7be5, 0x7be6, 0x7bea, 0x7c0c, 0x7bfe, 0x7bfc, 0x7c0f, 0x7c16, 0x7c0b, 0x7c1f, 0x7c2a, 0x7c26, 0x7c38, 0x7c41, 0x7c40,
+    0x81fe, 0x8201, 0x8202, 0x8204, 0x81ec, 0x8844, 0x8221, 0x8222, 0x8223, 0x822d, 0x822f, 0x8228, 0x822b, 0x8238, 0x823b, 0x8233,
+    0x8234, 0x823e, 0x8244, 0x8249, 0x824b, 0x824f, 0x825a, 0x825f, 0x8268, 0x887e, 0x8885, 0x8888, 0x88d8, 0x88df, 0x895e, 0x7f9d,
+    0x7f9f, 0x7fa7, 0x7faf, 0x7fb0, 0x7fb2, 0x7c7c, 0x6549, 0x7c91, 0x7c9d, 0x7c9c, 0x7c9e, 0x7ca2, 0x7cb2, 0x7cbc, 0x7cbd, 0x7cc1,
+    0x7cc7, 0x7ccc, 0x7ccd, 0x7cc8, 0x7cc5, 0x7cd7, 0x7ce8, 0x826e, 0x66a8, 0x7fbf, 0x7fce, 0x7fd5, 0x7fe5, 0x7fe1, 0x7fe6, 0x7fe9,
+    0x7fee, 0x7ff3, 0x7cf8, 0x7d77, 0x7da6, 0x7dae, 0x7e47, 0x7e9b, 0x9eb8, 0x9eb4, 0x8d73, 0x8d84, 0x8d94, 0x8d91, 0x8db1, 0x8d67,
+    0x8d6d, 0x8c47, 0x8c49, 0x914a, 0x9150, 0x914e, 0x914f, 0x9164, 0x9162, 0x9161, 0x9170, 0x9169, 0x916f, 0x917d, 0x917e, 0x9172,
+    0x9174, 0x9179, 0x918c, 0x9185, 0x9190, 0x918d, 0x9191, 0x91a2, 0x91a3, 0x91aa, 0x91ad, 0x91ae, 0x91af, 0x91b5, 0x91b4, 0x91ba,
+    0x8c55, 0x9e7e, 0x8db8, 0x8deb, 0x8e05, 0x8e59, 0x8e69, 0x8db5, 0x8dbf, 0x8dbc, 0x8dba, 0x8dc4, 0x8dd6, 0x8dd7, 0x8dda, 0x8dde,
+    0x8dce, 0x8dcf, 0x8ddb, 0x8dc6, 0x8dec, 0x8df7, 0x8df8, 0x8de3, 0x8df9, 0x8dfb, 0x8de4, 0x8e09, 0x8dfd, 0x8e14, 0x8e1d, 0x8e1f,
+    0x8e2c, 0x8e2e, 0x8e23, 0x8e2f, 0x8e3a, 0x8e40, 0x8e39, 0x8e35, 0x8e3d, 0x8e31, 0x8e49, 0x8e41, 0x8e42, 0x8e51, 0x8e52, 0x8e4a,
+    0x8e70, 0x8e76, 0x8e7c, 0x8e6f, 0x8e74, 0x8e85, 0x8e8f, 0x8e94, 0x8e90, 0x8e9c, 0x8e9e, 0x8c78, 0x8c82, 0x8c8a, 0x8c85, 0x8c98,
+    0x8c94, 0x659b, 0x89d6, 0x89de, 0x89da, 0x89dc, 0x89e5, 0x89eb, 0x89ef, 0x8a3e, 0x8b26, 0x9753, 0x96e9, 0x96f3, 0x96ef, 0x9706,
+    0x9701, 0x9708, 0x970f, 0x970e, 0x972a, 0x972d, 0x9730, 0x973e, 0x9f80, 0x9f83, 0x9f85, 0x9f86, 0x9f87, 0x9f88, 0x9f89, 0x9f8a,
+    0x9f8c, 0x9efe, 0x9f0b, 0x9f0d, 0x96b9, 0x96bc, 0x96bd, 0x96ce, 0x96d2, 0x77bf, 0x96e0, 0x928e, 0x92ae, 0x92c8, 0x933e, 0x936a,
+    0x93ca, 0x938f, 0x943e, 0x946b, 0x9c7f, 0x9c82, 0x9c85, 0x9c86, 0x9c87, 0x9c88, 0x7a23, 0x9c8b, 0x9c8e, 0x9c90, 0x9c91, 0x9c92,
+    0x9c94, 0x9c95, 0x9c9a, 0x9c9b, 0x9c9e, 0x9c9f, 0x9ca0, 0x9ca1, 0x9ca2, 0x9ca3, 0x9ca5, 0x9ca6, 0x9ca7, 0x9ca8, 0x9ca9, 0x9cab,
+    0x9cad, 0x9cae, 0x9cb0, 0x9cb1, 0x9cb2, 0x9cb3, 0x9cb4, 0x9cb5, 0x9cb6, 0x9cb7, 0x9cba, 0x9cbb, 0x9cbc, 0x9cbd, 0x9cc4, 0x9cc5,
+    0x9cc6, 0x9cc7, 0x9cca, 0x9ccb, 0x9ccc, 0x9ccd, 0x9cce, 0x9ccf, 0x9cd0, 0x9cd3, 0x9cd4, 0x9cd5, 0x9cd7, 0x9cd8, 0x9cd9, 0x9cdc,
+    0x9cdd, 0x9cdf, 0x9ce2, 0x977c, 0x9785, 0x9791, 0x9792, 0x9794, 0x97af, 0x97ab, 0x97a3, 0x97b2, 0x97b4, 0x9ab1, 0x9ab0, 0x9ab7,
+    0x9e58, 0x9ab6, 0x9aba, 0x9abc, 0x9ac1, 0x9ac0, 0x9ac5, 0x9ac2, 0x9acb, 0x9acc, 0x9ad1, 0x9b45, 0x9b43, 0x9b47, 0x9b49, 0x9b48,
+    0x9b4d, 0x9b51, 0x98e8, 0x990d, 0x992e, 0x9955, 0x9954, 0x9adf, 0x9ae1, 0x9ae6, 0x9aef, 0x9aeb, 0x9afb, 0x9aed, 0x9af9, 0x9b08,
+    0x9b0f, 0x9b13, 0x9b1f, 0x9b23, 0x9ebd, 0x9ebe, 0x7e3b, 0x9e82, 0x9e87, 0x9e88, 0x9e8b, 0x9e92, 0x93d6, 0x9e9d, 0x9e9f, 0x9edb,
+    0x9edc, 0x9edd, 0x9ee0, 0x9edf, 0x9ee2, 0x9ee9, 0x9ee7, 0x9ee5, 0x9eea, 0x9eef, 0x9f22, 0x9f2c, 0x9f2f, 0x9f39, 0x9f37, 0x9f3d,
+    0x9f3e, 0x9f44, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000,
+];
+
+/// Pairs of a Unicode code point and the pointer of its character, sorted by code point.
+#[rustfmt::skip]
+static FROM_UNICODE: [(u16, u16); 7445] = [
+    (0x00a4, 71), (0x00a7, 75), (0x00a8, 6), (0x00b0, 66), (0x00b1, 31), (0x00d7, 32), (0x00e0, 661), (0x00e1, 659),
+    (0x00e8, 665), (0x00e9, 663), (0x00ea, 683), (0x00ec, 669), (0x00ed, 667), (0x00f2, 673), (0x00f3, 671), (0x00f7, 33),
+    (0x00f9, 677), (0x00fa, 675), (0x00fc, 682), (0x0101, 658), (0x0113, 662), (0x011b, 664), (0x012b, 666), (0x014d, 670),
+    (0x016b, 674), (0x01ce, 660), (0x01d0, 668), (0x01d2, 672), (0x01d4, 676), (0x01d6, 678), (0x01d8, 679), (0x01da, 680),
+    (0x01dc, 681), (0x02c7, 5), (0x02c9, 4), (0x0391, 470), (0x0392, 471), (0x0393, 472), (0x0394, 473), (0x0395, 474),
+    (0x0396, 475), (0x0397, 476), (0x0398, 477), (0x0399, 478), (0x039a, 479), (0x039b, 480), (0x039c, 481), (0x039d, 482),
+    (0x039e, 483), (0x039f, 484), (0x03a0, 485), (0x03a1, 486), (0x03a3, 487), (0x03a4, 488), (0x03a5, 489), (0x03a6, 490),
+    (0x03a7, 491), (0x03a8, 492), (0x03a9, 493), (0x03b1, 502), (0x03b2, 503), (0x03b3, 504), (0x03b4, 505), (0x03b5, 506),
+    (0x03b6, 507), (0x03b7, 508), (0x03b8, 509), (0x03b9, 510), (0x03ba, 511), (0x03bb, 512), (0x03bc, 513), (0x03bd, 514),
+    (0x03be, 515), (0x03bf, 516), (0x03c0, 517), (0x03c1, 518), (0x03c3, 519), (0x03c4, 520), (0x03c5, 521), (0x03c6, 522),
+    (0x03c7, 523), (0x03c8, 524), (0x03c9, 525), (0x0401, 570), (0x0410, 564), (0x0411, 565), (0x0412, 566), (0x0413, 567),
+    (0x0414, 568), (0x0415, 569), (0x0416, 571), (0x0417, 572), (0x0418, 573), (0x0419, 574), (0x041a, 575), (0x041b, 576),
+    (0x041c, 577), (0x041d, 578), (0x041e, 579), (0x041f, 580), (0x0420, 581), (0x0421, 582), (0x0422, 583), (0x0423, 584),
+    (0x0424, 585), (0x0425, 586), (0x0426, 587), (0x0427, 588), (0x0428, 589), (0x0429, 590), (0x042a, 591), (0x042b, 592),
+    (0x042c, 593), (0x042d, 594), (0x042e, 595), (0x042f, 596), (0x0430, 612), (0x0431, 613), (0x0432, 614), (0x0433, 615),
+    (0x0434, 616), (0x0435, 617), (0x0436, 619), (0x0437, 620), (0x0438, 621), (0x0439, 622), (0x043a, 623), (0x043b, 624),
+    (0x043c, 625), (0x043d, 626), (0x043e, 627), (0x043f, 628), (0x0440, 629), (0x0441, 630), (0x0442, 631), (0x0443, 632),
+    (0x0444, 633), (0x0445, 634), (0x0446, 635), (0x0447, 636), (0x0448, 637), (0x0449, 638), (0x044a, 639), (0x044b, 640),
+    (0x044c, 641), (0x044d, 642), (0x044e, 643), (0x044f, 644), (0x0451, 618), (0x2015, 9), (0x2016, 11), (0x2018, 13),
+    (0x2019, 14), (0x201c, 15), (0x201d, 16), (0x2026, 12), (0x2030, 74), (0x2032, 67), (0x2033, 68), (0x203b, 88),
+    (0x2103, 69), (0x2116, 76), (0x2160, 174), (0x2161, 175), (0x2162, 176), (0x2163, 177), (0x2164, 178), (0x2165, 179),
+    (0x2166, 180), (0x2167, 181), (0x2168, 182), (0x2169, 183), (0x216a, 184), (0x216b, 185), (0x2190, 90), (0x2191, 91),
+    (0x2192, 89), (0x2193, 92), (0x2208, 41), (0x220f, 38), (0x2211, 37), (0x221a, 43), (0x221d, 55), (0x221e, 61),
+    (0x2220, 46), (0x2225, 45), (0x2227, 35), (0x2228, 36), (0x2229, 40), (0x222a, 39), (0x222b, 49), (0x222e, 50),
+    (0x2234, 63), (0x2235, 62), (0x2236, 34), (0x2237, 42), (0x223d, 54), (0x2248, 53), (0x224c, 52), (0x2260, 56),
+    (0x2261, 51), (0x2264, 59), (0x2265, 60), (0x226e, 57), (0x226f, 58), (0x2299, 48), (0x22a5, 44), (0x2312, 47),
+    (0x2460, 150), (0x2461, 151), (0x2462, 152), (0x2463, 153), (0x2464, 154), (0x2465, 155), (0x2466, 156), (0x2467, 157),
+    (0x2468, 158), (0x2469, 159), (0x2474, 130), (0x2475, 131), (0x2476, 132), (0x2477, 133), (0x2478, 134), (0x2479, 135),
+    (0x247a, 136), (0x247b, 137), (0x247c, 138), (0x247d, 139), (0x247e, 140), (0x247f, 141), (0x2480, 142), (0x2481, 143),
+    (0x2482, 144), (0x2483, 145), (0x2484, 146), (0x2485, 147), (0x2486, 148), (0x2487, 149), (0x2488, 110), (0x2489, 111),
+    (0x248a, 112), (0x248b, 113), (0x248c, 114), (0x248d, 115), (0x248e, 116), (0x248f, 117), (0x2490, 118), (0x2491, 119),
+    (0x2492, 120), (0x2493, 121), (0x2494, 122), (0x2495, 123), (0x2496, 124), (0x2497, 125), (0x2498, 126), (0x2499, 127),
+    (0x249a, 128), (0x249b, 129), (0x2500, 755), (0x2501, 756), (0x2502, 757), (0x2503, 758), (0x2504, 759), (0x2505, 760),
+    (0x2506, 761), (0x2507, 762), (0x2508, 763), (0x2509, 764), (0x250a, 765), (0x250b, 766), (0x250c, 767), (0x250d, 768),
+    (0x250e, 769), (0x250f, 770), (0x2510, 771), (0x2511, 772), (0x2512, 773), (0x2513, 774), (0x2514, 775), (0x2515, 776),
+    (0x2516, 777), (0x2517, 778), (0x2518, 779), (0x2519, 780), (0x251a, 781), (0x251b, 782), (0x251c, 783), (0x251d, 784),
+    (0x251e, 785), (0x251f, 786), (0x2520, 787), (0x2521, 788), (0x2522, 789), (0x2523, 790), (0x2524, 791), (0x2525, 792),
+    (0x2526, 793), (0x2527, 794), (0x2528, 795), (0x2529, 796), (0x252a, 797), (0x252b, 798), (0x252c, 799), (0x252d, 800),
+    (0x252e, 801), (0x252f, 802), (0x2530, 803), (0x2531, 804), (0x2532, 805), (0x2533, 806), (0x2534, 807), (0x2535, 808),
+    (0x2536, 809), (0x2537, 810), (0x2538, 811), (0x2539, 812), (0x253a, 813), (0x253b, 814), (0x253c, 815), (0x253d, 816),
+    (0x253e, 817), (0x253f, 818), (0x2540, 819), (0x2541, 820), (0x2542, 821), (0x2543, 822), (0x2544, 823), (0x2545, 824),
+    (0x2546, 825), (0x2547, 826), (0x2548, 827), (0x2549, 828), (0x254a, 829), (0x254b, 830), (0x25a0, 85), (0x25a1, 84),
+    (0x25b2, 87), (0x25b3, 86), (0x25c6, 83), (0x25c7, 82), (0x25cb, 79), (0x25ce, 81), (0x25cf, 80), (0x2605, 78),
+    (0x2606, 77), (0x2640, 65), (0x2642, 64), (0x3000, 0), (0x3001, 1), (0x3002, 2), (0x3003, 7), (0x3005, 8),
+    (0x3008, 19), (0x3009, 20), (0x300a, 21), (0x300b, 22), (0x300c, 23), (0x300d, 24), (0x300e, 25), (0x300f, 26),
+    (0x3010, 29), (0x3011, 30), (0x3013, 93), (0x3014, 17), (0x3015, 18), (0x3016, 27), (0x3017, 28), (0x3041, 282),
+    (0x3042, 283), (0x3043, 284), (0x3044, 285), (0x3045, 286), (0x3046, 287), (0x3047, 288), (0x3048, 289), (0x3049, 290),
+    (0x304a, 291), (0x304b, 292), (0x304c, 293), (0x304d, 294), (0x304e, 295), (0x304f, 296), (0x3050, 297), (0x3051, 298),
+    (0x3052, 299), (0x3053, 300), (0x3054, 301), (0x3055, 302), (0x3056, 303), (0x3057, 304), (0x3058, 305), (0x3059, 306),
+    (0x305a, 307), (0x305b, 308), (0x305c, 309), (0x305d, 310), (0x305e, 311), (0x305f, 312), (0x3060, 313), (0x3061, 314),
+    (0x3062, 315), (0x3063, 316), (0x3064, 317), (0x3065, 318), (0x3066, 319), (0x3067, 320), (0x3068, 321), (0x3069, 322),
+    (0x306a, 323), (0x306b, 324), (0x306c, 325), (0x306d, 326), (0x306e, 327), (0x306f, 328), (0x3070, 329), (0x3071, 330),
+    (0x3072, 331), (0x3073, 332), (0x3074, 333), (0x3075, 334), (0x3076, 335), (0x3077, 336), (0x3078, 337), (0x3079, 338),
+    (0x307a, 339), (0x307b, 340), (0x307c, 341), (0x307d, 342), (0x307e, 343), (0x307f, 344), (0x3080, 345), (0x3081, 346),
+    (0x3082, 347), (0x3083, 348), (0x3084, 349), (0x3085, 350), (0x3086, 351), (0x3087, 352), (0x3088, 353), (0x3089, 354),
+    (0x308a, 355), (0x308b, 356), (0x308c, 357), (0x308d, 358), (0x308e, 359), (0x308f, 360), (0x3090, 361), (0x3091, 362),
+    (0x3092, 363), (0x3093, 364), (0x30a1, 376), (0x30a2, 377), (0x30a3, 378), (0x30a4, 379), (0x30a5, 380), (0x30a6, 381),
+    (0x30a7, 382), (0x30a8, 383), (0x30a9, 384), (0x30aa, 385), (0x30ab, 386), (0x30ac, 387), (0x30ad, 388), (0x30ae, 389),
+    (0x30af, 390), (0x30b0, 391), (0x30b1, 392), (0x30b2, 393), (0x30b3, 394), (0x30b4, 395), (0x30b5, 396), (0x30b6, 397),
+    (0x30b7, 398), (0x30b8, 399), (0x30b9, 400), (0x30ba, 401), (0x30bb, 402), (0x30bc, 403), (0x30bd, 404), (0x30be, 405),
+    (0x30bf, 406), (0x30c0, 407), (0x30c1, 408), (0x30c2, 409), (0x30c3, 410), (0x30c4, 411), (0x30c5, 412), (0x30c6, 413),
+    (0x30c7, 414), (0x30c8, 415), (0x30c9, 416), (0x30ca, 417), (0x30cb, 418), (0x30cc, 419), (0x30cd, 420), (0x30ce, 421),
+    (0x30cf, 422), (0x30d0, 423), (0x30d1, 424), (0x30d2, 425), (0x30d3, 426), (0x30d4, 427), (0x30d5, 428), (0x30d6, 429),
+    (0x30d7, 430), (0x30d8, 431), (0x30d9, 432), (0x30da, 433), (0x30db, 434), (0x30dc, 435), (0x30dd, 436), (0x30de, 437),
+    (0x30df, 438), (0x30e0, 439), (0x30e1, 440), (0x30e2, 441), (0x30e3, 442), (0x30e4, 443), (0x30e5, 444), (0x30e6, 445),
+    (0x30e7, 446), (0x30e8, 447), (0x30e9, 448), (0x30ea, 449), (0x30eb, 450), (0x30ec, 451), (0x30ed, 452), (0x30ee, 453),
+    (0x30ef, 454), (0x30f0, 455), (0x30f1, 456), (0x30f2, 457), (0x30f3, 458), (0x30f4, 459), (0x30f5, 460), (0x30f6, 461),
+    (0x30fb, 3), (0x3105, 694), (0x3106, 695), (0x3107, 696), (0x3108, 697), (0x3109, 698), (0x310a, 699), (0x310b, 700),
+    (0x310c, 701), (0x310d, 702), (0x310e, 703), (0x310f, 704), (0x3110, 705), (0x3111, 706), (0x3112, 707), (0x3113, 708),
+    (0x3114, 709), (0x3115, 710), (0x3116, 711), (0x3117, 712), (0x3118, 713), (0x3119, 714), (0x311a, 715), (0x311b, 716),
+    (0x311c, 717), (0x311d, 718), (0x311e, 719), (0x311f, 720), (0x3120, 721), (0x3121, 722), (0x3122, 723), (0x3123, 724),
+    (0x3124, 725), (0x3125, 726), (0x3126, 727), (0x3127, 728), (0x3128, 729), (0x3129, 730), (0x3220, 162), (0x3221, 163),
+    (0x3222, 164), (0x3223, 165), (0x3224, 166), (0x3225, 167), (0x3226, 168), (0x3227, 169), (0x3228, 170), (0x3229, 171),
+    (0x4e00, 4632), (0x4e01, 1974), (0x4e03, 3540), (0x4e07, 4217), (0x4e08, 4928), (0x4e09, 3758), (0x4e0a, 3806), (0x4e0b, 4357),
+    (0x4e0c, 5171), (0x4e0d, 1624), (0x4e0e, 4774), (0x4e10, 5173), (0x4e11, 1774), (0x4e13, 5083), (0x4e14, 3621), (0x4e15, 5176),
+    (0x4e16, 3885), (0x4e18, 3651), (0x4e19, 1594), (0x4e1a, 4626), (0x4e1b, 1837), (0x4e1c, 1984), (0x4e1d, 3978), (0x4e1e, 5178),
+    (0x4e22, 1983), (0x4e24, 3036), (0x4e25, 4558), (0x4e27, 3764), (0x4e28, 5182), (0x4e2a, 2247), (0x4e2b, 4541), (0x4e2c, 6263),
+    (0x4e2d, 5029), (0x4e30, 2132), (0x4e32, 1799), (0x4e34, 3064), (0x4e36, 5197), (0x4e38, 4207), (0x4e39, 1883), (0x4e3a, 4239),
+    (0x4e3b, 5068), (0x4e3d, 2999), (0x4e3e, 2782), (0x4e3f, 5184), (0x4e43, 3332), (0x4e45, 2760), (0x4e47, 5186), (0x4e48, 3215),
+    (0x4e49, 4674), (0x4e4b, 4995), (0x4e4c, 4287), (0x4e4d, 4894), (0x4e4e, 2434), (0x4e4f, 2073), (0x4e50, 2967), (0x4e52, 3502),
+    (0x4e53, 3433), (0x4e54, 3610), (0x4e56, 2307), (0x4e58, 1734), (0x4e59, 4655), (0x4e5c, 5200), (0x4e5d, 2762), (0x4e5e, 3559),
+    (0x4e5f, 4623), (0x4e60, 4339), (0x4e61, 4394), (0x4e66, 3926), (0x4e69, 5201), (0x4e70, 3183), (0x4e71, 3151), (0x4e73, 3738),
+    (0x4e7e, 3583), (0x4e86, 3050), (0x4e88, 4771), (0x4e89, 4976), (0x4e8b, 3887), (0x4e8c, 2067), (0x4e8d, 5170), (0x4e8e, 4757),
+    (0x4e8f, 2906), (0x4e91, 4831), (0x4e92, 2448), (0x4e93, 5202), (0x4e94, 4298), (0x4e95, 2739), (0x4e98, 5177), (0x4e9a, 4550),
+    (0x4e9b, 4426), (0x4e9f, 5198), (0x4ea0, 5342), (0x4ea1, 4221), (0x4ea2, 2845), (0x4ea4, 2658), (0x4ea5, 2354), (0x4ea6, 4669),
+    (0x4ea7, 1687), (0x4ea8, 2413), (0x4ea9, 3311), (0x4eab, 4400), (0x4eac, 2734), (0x4ead, 4139), (0x4eae, 3040), (0x4eb2, 3626),
+    (0x4eb3, 5344), (0x4eb5, 5347), (0x4eba, 3708), (0x4ebb, 5242), (0x4ebf, 4663), (0x4ec0, 3871), (0x4ec1, 3707), (0x4ec2, 5245),
+    (0x4ec3, 5243), (0x4ec4, 5207), (0x4ec5, 2717), (0x4ec6, 3522), (0x4ec7, 1771), (0x4ec9, 5244), (0x4eca, 2712), (0x4ecb, 2704),
+    (0x4ecd, 3717), (0x4ece, 1836), (0x4ed1, 3157), (0x4ed3, 1651), (0x4ed4, 5123), (0x4ed5, 3896), (0x4ed6, 4038), (0x4ed7, 4931),
+    (0x4ed8, 2183), (0x4ed9, 4364), (0x4edd, 5321), (0x4ede, 5249), (0x4edf, 3581), (0x4ee1, 5247), (0x4ee3, 1875), (0x4ee4, 3085),
+    (0x4ee5, 4657), (0x4ee8, 5246), (0x4eea, 4644), (0x4eeb, 5248), (0x4eec, 3234), (0x4ef0, 4597), (0x4ef2, 5038), (0x4ef3, 5251),
+    (0x4ef5, 5254), (0x4ef6, 2631), (0x4ef7, 2596), (0x4efb, 3711), (0x4efd, 2128), (0x4eff, 2101), (0x4f01, 3560), (0x4f09, 5257),
+    (0x4f0a, 4638), (0x4f0d, 4302), (0x4f0e, 2568), (0x4f0f, 2159), (0x4f10, 2072), (0x4f11, 4478), (0x4f17, 5039), (0x4f18, 4736),
+    (0x4f19, 2522), (0x4f1a, 2508), (0x4f1b, 5250), (0x4f1e, 3760), (0x4f1f, 4245), (0x4f20, 1796), (0x4f22, 5252), (0x4f24, 3802),
+    (0x4f25, 5255), (0x4f26, 3156), (0x4f27, 5256), (0x4f2a, 4246), (0x4f2b, 5258), (0x4f2f, 1611), (0x4f30, 2287), (0x4f32, 5266),
+    (0x4f34, 1482), (0x4f36, 3077), (0x4f38, 3835), (0x4f3a, 3984), (0x4f3c, 3985), (0x4f3d, 5267), (0x4f43, 1951), (0x4f46, 1890),
+    (0x4f4d, 4256), (0x4f4e, 1924), (0x4f4f, 5076), (0x4f50, 5159), (0x4f51, 4750), (0x4f53, 4110), (0x4f55, 2395), (0x4f57, 5265),
+    (0x4f58, 5323), (0x4f59, 4763), (0x4f5a, 5262), (0x4f5b, 2147), (0x4f5c, 5162), (0x4f5d, 5263), (0x4f5e, 5259), (0x4f5f, 5264),
+    (0x4f60, 3356), (0x4f63, 4721), (0x4f64, 5253), (0x4f65, 5324), (0x4f67, 5260), (0x4f69, 3452), (0x4f6c, 2961), (0x4f6f, 4591),
+    (0x4f70, 1468), (0x4f73, 2586), (0x4f74, 5269), (0x4f76, 5268), (0x4f7b, 5275), (0x4f7c, 5277), (0x4f7e, 5274), (0x4f7f, 3878),
+    (0x4f83, 5272), (0x4f84, 5003), (0x4f88, 1753), (0x4f89, 5271), (0x4f8b, 3006), (0x4f8d, 3897), (0x4f8f, 5273), (0x4f91, 5270),
+    (0x4f94, 5279), (0x4f97, 1990), (0x4f9b, 2264), (0x4f9d, 4637), (0x4fa0, 4355), (0x4fa3, 3135), (0x4fa5, 2667), (0x4fa6, 4963),
+    (0x4fa7, 1661), (0x4fa8, 3611), (0x4fa9, 2894), (0x4faa, 5276), (0x4fac, 5278), (0x4fae, 4303), (0x4faf, 2427), (0x4fb5, 3625),
+    (0x4fbf, 1570), (0x4fc3, 1842), (0x4fc4, 2050), (0x4fc5, 5283), (0x4fca, 2820), (0x4fce, 5325), (0x4fcf, 3617), (0x4fd0, 3007),
+    (0x4fd1, 5287), (0x4fd7, 4002), (0x4fd8, 2160), (0x4fda, 5284), (0x4fdc, 5286), (0x4fdd, 1506), (0x4fde, 4764), (0x4fdf, 5288),
+    (0x4fe1, 4454), (0x4fe3, 5285), (0x4fe6, 5280), (0x4fe8, 5281), (0x4fe9, 3016), (0x4fea, 5282), (0x4fed, 2620), (0x4fee, 4479),
+    (0x4fef, 2170), (0x4ff1, 2792), (0x4ff3, 5292), (0x4ff8, 5289), (0x4ffa, 1428), (0x4ffe, 5297), (0x500c, 5299), (0x500d, 1525),
+    (0x500f, 5294), (0x5012, 1904), (0x5014, 2808), (0x5018, 4081), (0x5019, 2431), (0x501a, 4653), (0x501c, 5298), (0x501f, 2703),
+    (0x5021, 1702), (0x5025, 5300), (0x5026, 2800), (0x5028, 5301), (0x5029, 5290), (0x502a, 3352), (0x502c, 5293), (0x502d, 5296),
+    (0x502e, 5295), (0x503a, 4901), (0x503c, 5002), (0x503e, 3638), (0x5043, 5303), (0x5047, 2594), (0x5048, 5305), (0x504c, 5291),
+    (0x504e, 5306), (0x504f, 3488), (0x5055, 5304), (0x505a, 5161), (0x505c, 4138), (0x5065, 2632), (0x506c, 5307), (0x5076, 3411),
+    (0x5077, 4156), (0x507b, 5308), (0x507e, 5302), (0x507f, 1696), (0x5080, 2913), (0x5085, 2182), (0x5088, 3005), (0x508d, 1497),
+    (0x50a3, 1871), (0x50a5, 5309), (0x50a7, 5310), (0x50a8, 1787), (0x50a9, 5311), (0x50ac, 1848), (0x50b2, 1442), (0x50ba, 5312),
+    (0x50bb, 3780), (0x50cf, 4404), (0x50d6, 5313), (0x50da, 3044), (0x50e6, 5317), (0x50e7, 3773), (0x50ec, 5316), (0x50ed, 5315),
+    (0x50ee, 5318), (0x50f3, 4006), (0x50f5, 2640), (0x50fb, 3484), (0x5106, 5314), (0x5107, 5319), (0x510b, 5320), (0x5112, 3734),
+    (0x5121, 2973), (0x513f, 2062), (0x5140, 5172), (0x5141, 4835), (0x5143, 4803), (0x5144, 4471), (0x5145, 1759), (0x5146, 4942),
+    (0x5148, 4363), (0x5149, 2321), (0x514b, 2862), (0x514d, 3261), (0x5151, 2023), (0x5154, 4170), (0x5155, 5341), (0x5156, 5343),
+    (0x515a, 1898), (0x515c, 1994), (0x5162, 2729), (0x5165, 3740), (0x5168, 3676), (0x516b, 1452), (0x516c, 2266), (0x516d, 3096),
+    (0x516e, 5329), (0x5170, 2941), (0x5171, 2273), (0x5173, 2311), (0x5174, 4460), (0x5175, 1591), (0x5176, 3545), (0x5177, 2788),
+    (0x5178, 1947), (0x5179, 5115), (0x517b, 4599), (0x517c, 2607), (0x517d, 3915), (0x5180, 2566), (0x5181, 5333), (0x5182, 5240),
+    (0x5185, 3347), (0x5188, 2213), (0x5189, 3694), (0x518c, 1662), (0x518d, 4850), (0x5192, 3211), (0x5195, 3260), (0x5196, 5359),
+    (0x5197, 3728), (0x5199, 4437), (0x519b, 2817), (0x519c, 3392), (0x51a0, 2313), (0x51a2, 5360), (0x51a4, 4802), (0x51a5, 5361),
+    (0x51ab, 5354), (0x51ac, 1985), (0x51af, 2142), (0x51b0, 1592), (0x51b1, 5355), (0x51b2, 1760), (0x51b3, 2811), (0x51b5, 2905),
+    (0x51b6, 4622), (0x51b7, 2981), (0x51bb, 1992), (0x51bc, 5357), (0x51bd, 5356), (0x51c0, 2752), (0x51c4, 3541), (0x51c6, 5103),
+    (0x51c7, 5358), (0x51c9, 3032), (0x51cb, 1961), (0x51cc, 3079), (0x51cf, 2622), (0x51d1, 1838), (0x51db, 3068), (0x51dd, 3382),
+    (0x51e0, 2561), (0x51e1, 2085), (0x51e4, 2146), (0x51eb, 5339), (0x51ed, 3507), (0x51ef, 2832), (0x51f0, 2486), (0x51f3, 1921),
+    (0x51f5, 5492), (0x51f6, 4472), (0x51f8, 4160), (0x51f9, 1437), (0x51fa, 1777), (0x51fb, 2530), (0x51fc, 5493), (0x51fd, 2364),
+    (0x51ff, 4861), (0x5200, 1901), (0x5201, 1962), (0x5202, 5223), (0x5203, 3713), (0x5206, 2121), (0x5207, 3619), (0x5208, 5224),
+    (0x520a, 2834), (0x520d, 5478), (0x520e, 5225), (0x5211, 4461), (0x5212, 2457), (0x5216, 6985), (0x5217, 3055), (0x5218, 3092),
+    (0x5219, 4875), (0x521a, 2214), (0x521b, 1805), (0x521d, 1776), (0x5220, 3789), (0x5224, 3431), (0x5228, 3440), (0x5229, 3004),
+    (0x522b, 1583), (0x522d, 5226), (0x522e, 2301), (0x5230, 1908), (0x5233, 5227), (0x5236, 5019), (0x5237, 3949), (0x5238, 3680),
+    (0x5239, 3777), (0x523a, 1829), (0x523b, 2863), (0x523d, 2339), (0x523f, 5228), (0x5240, 5229), (0x5241, 2044), (0x5242, 2570),
+    (0x5243, 4115), (0x524a, 4410), (0x524c, 5230), (0x524d, 3587), (0x5250, 2303), (0x5251, 2634), (0x5254, 4103), (0x5256, 3519),
+    (0x525c, 5233), (0x525e, 5231), (0x5261, 5232), (0x5265, 1503), (0x5267, 2796), (0x5269, 3856), (0x526a, 2621), (0x526f, 2178),
+    (0x5272, 2239), (0x527d, 5235), (0x527f, 2674), (0x5281, 5237), (0x5282, 5236), (0x5288, 3475), (0x5290, 5238), (0x5293, 5239),
+    (0x529b, 3013), (0x529d, 3681), (0x529e, 1485), (0x529f, 2261), (0x52a0, 2588), (0x52a1, 4310), (0x52a2, 5480), (0x52a3, 3058),
+    (0x52a8, 1988), (0x52a9, 5071), (0x52aa, 3395), (0x52ab, 2688), (0x52ac, 5481), (0x52ad, 5482), (0x52b1, 3001), (0x52b2, 2727),
+    (0x52b3, 2958), (0x52be, 5483), (0x52bf, 3891), (0x52c3, 1607), (0x52c7, 4733), (0x52c9, 3262), (0x52cb, 4522), (0x52d0, 5485),
+    (0x52d2, 2966), (0x52d6, 5486), (0x52d8, 2836), (0x52df, 3317), (0x52e4, 3629), (0x52f0, 5487), (0x52f9, 5335), (0x52fa, 3814),
+    (0x52fe, 2275), (0x52ff, 4309), (0x5300, 4833), (0x5305, 1501), (0x5306, 1835), (0x5308, 4474), (0x530d, 5336), (0x530f, 5776),
+    (0x5310, 5338), (0x5315, 5185), (0x5316, 2458), (0x5317, 1520), (0x5319, 1746), (0x531a, 5215), (0x531d, 4842), (0x5320, 2650),
+    (0x5321, 2898), (0x5323, 4350), (0x5326, 5217), (0x532a, 2110), (0x532e, 5218), (0x5339, 3482), (0x533a, 3659), (0x533b, 4634),
+    (0x533e, 5219), (0x533f, 3357), (0x5341, 3867), (0x5343, 3578), (0x5345, 5175), (0x5347, 3852), (0x5348, 4300), (0x5349, 2503),
+    (0x534a, 1484), (0x534e, 2453), (0x534f, 4430), (0x5351, 1519), (0x5352, 5143), (0x5353, 5106), (0x5355, 1884), (0x5356, 3185),
+    (0x5357, 3336), (0x535a, 1606), (0x535c, 1620), (0x535e, 1572), (0x535f, 5845), (0x5360, 4915), (0x5361, 2827), (0x5362, 3113),
+    (0x5363, 5222), (0x5364, 3118), (0x5366, 5221), (0x5367, 4281), (0x5369, 5421), (0x536b, 4261), (0x536e, 5189), (0x536f, 3209),
+    (0x5370, 4700), (0x5371, 4232), (0x5373, 2557), (0x5374, 3685), (0x5375, 3150), (0x5377, 2802), (0x5378, 4439), (0x537a, 5422),
+    (0x537f, 3639), (0x5382, 1698), (0x5384, 2055), (0x5385, 4133), (0x5386, 3003), (0x5389, 3000), (0x538b, 4536), (0x538c, 4576),
+    (0x538d, 5208), (0x5395, 1659), (0x5398, 2982), (0x539a, 2430), (0x539d, 5209), (0x539f, 4806), (0x53a2, 4388), (0x53a3, 5210),
+    (0x53a5, 5211), (0x53a6, 4358), (0x53a8, 1779), (0x53a9, 2764), (0x53ae, 5212), (0x53b6, 5495), (0x53bb, 3670), (0x53bf, 4379),
+    (0x53c1, 3759), (0x53c2, 1643), (0x53c8, 4753), (0x53c9, 1667), (0x53ca, 2553), (0x53cb, 4748), (0x53cc, 3958), (0x53cd, 2087),
+    (0x53d1, 2069), (0x53d4, 3922), (0x53d6, 3666), (0x53d7, 3913), (0x53d8, 1571), (0x53d9, 4497), (0x53db, 3432), (0x53df, 5488),
+    (0x53e0, 1973), (0x53e3, 2877), (0x53e4, 2292), (0x53e5, 2793), (0x53e6, 3084), (0x53e8, 5849), (0x53e9, 5848), (0x53ea, 5008),
+    (0x53eb, 2679), (0x53ec, 4944), (0x53ed, 1449), (0x53ee, 1976), (0x53ef, 2860), (0x53f0, 4049), (0x53f1, 5846), (0x53f2, 3876),
+    (0x53f3, 4749), (0x53f5, 5216), (0x53f6, 4627), (0x53f7, 2386), (0x53f8, 3977), (0x53f9, 4071), (0x53fb, 5850), (0x53fc, 1959),
+    (0x53fd, 5847), (0x5401, 4784), (0x5403, 1743), (0x5404, 2248), (0x5406, 5853), (0x5408, 2396), (0x5409, 2547), (0x540a, 1964),
+    (0x540c, 4147), (0x540d, 3286), (0x540e, 2432), (0x540f, 2997), (0x5410, 4169), (0x5411, 4405), (0x5412, 5851), (0x5413, 4360),
+    (0x5415, 3133), (0x5416, 5852), (0x5417, 3181), (0x541b, 2818), (0x541d, 3070), (0x541e, 4179), (0x541f, 4692), (0x5420, 2112),
+    (0x5421, 5860), (0x5423, 5863), (0x5426, 2148), (0x5427, 1450), (0x5428, 2027), (0x5429, 2119), (0x542b, 2361), (0x542c, 4134),
+    (0x542d, 2871), (0x542e, 3964), (0x542f, 3561), (0x5431, 4989), (0x5432, 5864), (0x5434, 4295), (0x5435, 1710), (0x5438, 4321),
+    (0x5439, 1806), (0x543b, 4268), (0x543c, 2429), (0x543e, 4294), (0x5440, 4540), (0x5443, 5859), (0x5446, 1869), (0x5448, 1733),
+    (0x544a, 2231), (0x544b, 5854), (0x5450, 3326), (0x5452, 5855), (0x5453, 5856), (0x5454, 5857), (0x5455, 3410), (0x5456, 5858),
+    (0x5457, 5861), (0x5458, 4810), (0x5459, 5862), (0x545b, 3597), (0x545c, 4285), (0x5462, 3345), (0x5464, 5869), (0x5466, 5874),
+    (0x5468, 5041), (0x5471, 5868), (0x5472, 5885), (0x5473, 4251), (0x5475, 2388), (0x5476, 5873), (0x5477, 5867), (0x5478, 3445),
+    (0x547b, 3834), (0x547c, 2433), (0x547d, 3287), (0x5480, 2780), (0x5482, 5865), (0x5484, 5872), (0x5486, 3439), (0x548b, 4893),
+    (0x548c, 2394), (0x548e, 2769), (0x548f, 4728), (0x5490, 2193), (0x5492, 5049), (0x5494, 5866), (0x5495, 2285), (0x5496, 2826),
+    (0x5499, 3099), (0x549a, 5870), (0x549b, 5871), (0x549d, 5875), (0x54a3, 5886), (0x54a4, 5896), (0x54a6, 5882), (0x54a7, 5881),
+    (0x54a8, 5116), (0x54a9, 5894), (0x54aa, 5895), (0x54ab, 6466), (0x54ac, 4612), (0x54ad, 5877), (0x54af, 2828), (0x54b1, 4852),
+    (0x54b3, 2859), (0x54b4, 5879), (0x54b8, 4367), (0x54bb, 5888), (0x54bd, 4553), (0x54bf, 5889), (0x54c0, 1416), (0x54c1, 3500),
+    (0x54c2, 5878), (0x54c4, 2418), (0x54c6, 2036), (0x54c7, 4194), (0x54c8, 2349), (0x54c9, 4846), (0x54cc, 5890), (0x54cd, 4399),
+    (0x54ce, 1414), (0x54cf, 5898), (0x54d0, 5876), (0x54d1, 4549), (0x54d2, 5880), (0x54d3, 5883), (0x54d4, 5884), (0x54d5, 5887),
+    (0x54d7, 2452), (0x54d9, 5891), (0x54da, 5892), (0x54dc, 5893), (0x54dd, 5897), (0x54de, 5899), (0x54df, 4719), (0x54e5, 2232),
+    (0x54e6, 3405), (0x54e7, 5901), (0x54e8, 3817), (0x54e9, 3015), (0x54ea, 3325), (0x54ed, 2881), (0x54ee, 4411), (0x54f2, 4947),
+    (0x54f3, 5905), (0x54fa, 1621), (0x54fc, 2412), (0x54fd, 5903), (0x54ff, 5484), (0x5501, 4579), (0x5506, 4031), (0x5507, 1814),
+    (0x5509, 1415), (0x550f, 5908), (0x5510, 4079), (0x5511, 5909), (0x5514, 5904), (0x551b, 5900), (0x5520, 5902), (0x5522, 5906),
+    (0x5523, 5907), (0x5524, 2472), (0x5527, 5910), (0x552a, 5911), (0x552c, 2446), (0x552e, 3912), (0x552f, 4237), (0x5530, 5928),
+    (0x5531, 1701), (0x5533, 5927), (0x5537, 5922), (0x553c, 5921), (0x553e, 4192), (0x553f, 5919), (0x5541, 5917), (0x5543, 2867),
+    (0x5544, 5111), (0x5546, 3803), (0x5549, 5915), (0x554a, 1410), (0x5550, 5920), (0x5555, 5918), (0x5556, 5923), (0x555c, 5929),
+    (0x5561, 2107), (0x5564, 3478), (0x5565, 3781), (0x5566, 2931), (0x5567, 5912), (0x556a, 3413), (0x556c, 5205), (0x556d, 5916),
+    (0x556e, 3375), (0x5575, 5924), (0x5576, 5925), (0x5577, 5926), (0x5578, 4422), (0x557b, 5941), (0x557c, 4109), (0x557e, 5938),
+    (0x5580, 2825), (0x5581, 5936), (0x5582, 4254), (0x5583, 5932), (0x5584, 3797), (0x5587, 2927), (0x5588, 5935), (0x5589, 2426),
+    (0x558a, 2365), (0x558b, 5930), (0x558f, 5913), (0x5591, 5940), (0x5594, 5945), (0x5598, 1798), (0x5599, 5946), (0x559c, 4341),
+    (0x559d, 2389), (0x559f, 5937), (0x55a7, 4507), (0x55b1, 5933), (0x55b3, 4884), (0x55b5, 5914), (0x55b7, 3454), (0x55b9, 5934),
+    (0x55bb, 4786), (0x55bd, 5943), (0x55be, 5944), (0x55c4, 5957), (0x55c5, 4482), (0x55c9, 5949), (0x55cc, 5962), (0x55cd, 5963),
+    (0x55d1, 5951), (0x55d2, 5931), (0x55d3, 3763), (0x55d4, 5954), (0x55d6, 5939), (0x55dc, 3893), (0x55dd, 5956), (0x55df, 5942),
+    (0x55e1, 4272), (0x55e3, 3982), (0x55e4, 5966), (0x55e5, 5959), (0x55e6, 5955), (0x55e8, 5964), (0x55ea, 5947), (0x55eb, 5952),
+    (0x55ec, 5953), (0x55ef, 5958), (0x55f2, 5960), (0x55f3, 5961), (0x55f5, 5965), (0x55f7, 5948), (0x55fd, 3999), (0x55fe, 5974),
+    (0x5600, 5975), (0x5601, 5971), (0x5608, 5969), (0x5609, 2583), (0x560c, 5970), (0x560e, 2195), (0x560f, 5206), (0x5618, 4491),
+    (0x561b, 3180), (0x561e, 5968), (0x561f, 5950), (0x5623, 5973), (0x5624, 5972), (0x5627, 5976), (0x562c, 5981), (0x562d, 5977),
+    (0x5631, 5067), (0x5632, 1707), (0x5634, 5151), (0x5636, 3974), (0x5639, 5979), (0x563b, 4320), (0x563f, 2406), (0x564c, 5986),
+    (0x564d, 5982), (0x564e, 4618), (0x5654, 5987), (0x5657, 5980), (0x5658, 5978), (0x5659, 5984), (0x565c, 5985), (0x5662, 5983),
+    (0x5664, 5989), (0x5668, 3564), (0x5669, 5181), (0x566a, 4868), (0x566b, 5991), (0x566c, 3894), (0x5671, 5990), (0x5676, 2194),
+    (0x567b, 5992), (0x567c, 5993), (0x5685, 5994), (0x5686, 5988), (0x568e, 2380), (0x568f, 4112), (0x5693, 5995), (0x56a3, 4412),
+    (0x56af, 5996), (0x56b7, 3699), (0x56bc, 2663), (0x56ca, 3339), (0x56d4, 5997), (0x56d7, 5998), (0x56da, 3655), (0x56db, 3983),
+    (0x56dd, 5999), (0x56de, 2499), (0x56df, 5191), (0x56e0, 4687), (0x56e1, 6000), (0x56e2, 4172), (0x56e4, 2031), (0x56eb, 6002),
+    (0x56ed, 4809), (0x56f0, 2920), (0x56f1, 1834), (0x56f4, 4236), (0x56f5, 6001), (0x56f9, 6003), (0x56fa, 2299), (0x56fd, 2345),
+    (0x56fe, 4163), (0x56ff, 6004), (0x5703, 3529), (0x5704, 6005), (0x5706, 4811), (0x5708, 3671), (0x5709, 6007), (0x570a, 6006),
+    (0x571c, 6008), (0x571f, 4168), (0x5723, 3858), (0x5728, 4851), (0x5729, 5506), (0x572a, 5508), (0x572c, 5507), (0x572d, 2326),
+    (0x572e, 5511), (0x572f, 5512), (0x5730, 1935), (0x5733, 5509), (0x5739, 5510), (0x573a, 1692), (0x573b, 5514), (0x573e, 2531),
+    (0x5740, 5004), (0x5742, 5515), (0x5747, 2814), (0x574a, 2094), (0x574c, 5499), (0x574d, 4055), (0x574e, 2837), (0x574f, 2464),
+    (0x5750, 5163), (0x5751, 2870), (0x5757, 2892), (0x575a, 2602), (0x575b, 4060), (0x575c, 5513), (0x575d, 1460), (0x575e, 4304),
+    (0x575f, 2123), (0x5760, 5100), (0x5761, 3511), (0x5764, 2917), (0x5766, 4066), (0x5768, 5522), (0x5769, 5516), (0x576a, 3503),
+    (0x576b, 5518), (0x576d, 5523), (0x576f, 3470), (0x5773, 5525), (0x5776, 5524), (0x5777, 2851), (0x577b, 5521), (0x577c, 5520),
+    (0x5782, 1810), (0x5783, 2925), (0x5784, 3103), (0x5785, 5517), (0x5786, 5519), (0x578b, 4462), (0x578c, 5528), (0x5792, 2974),
+    (0x5793, 5533), (0x579b, 2039), (0x57a0, 5534), (0x57a1, 5501), (0x57a2, 2279), (0x57a3, 4804), (0x57a4, 5527), (0x57a6, 2868),
+    (0x57a7, 5531), (0x57a9, 5500), (0x57ab, 1949), (0x57ad, 5526), (0x57ae, 2888), (0x57b2, 5529), (0x57b4, 5532), (0x57b8, 5540),
+    (0x57c2, 2256), (0x57c3, 1412), (0x57cb, 3182), (0x57ce, 1730), (0x57cf, 5530), (0x57d2, 5539), (0x57d4, 3527), (0x57d5, 5535),
+    (0x57d8, 5536), (0x57d9, 5538), (0x57da, 5537), (0x57dd, 5545), (0x57df, 4781), (0x57e0, 1623), (0x57e4, 5544), (0x57ed, 5549),
+    (0x57ef, 5542), (0x57f4, 5541), (0x57f8, 5543), (0x57f9, 3447), (0x57fa, 2532), (0x57fd, 5548), (0x5800, 5550), (0x5802, 4076),
+    (0x5806, 2022), (0x5807, 5671), (0x580b, 5546), (0x580d, 5547), (0x5811, 3592), (0x5815, 2046), (0x5819, 5552), (0x581e, 5551),
+    (0x5820, 5554), (0x5821, 1507), (0x5824, 1923), (0x582a, 2835), (0x5830, 4574), (0x5835, 2007), (0x5844, 5553), (0x584c, 4037),
+    (0x584d, 7038), (0x5851, 4007), (0x5854, 4041), (0x5858, 4074), (0x585e, 3756), (0x5865, 5555), (0x586b, 4119), (0x586c, 5556),
+    (0x587e, 5502), (0x5880, 5560), (0x5881, 5557), (0x5883, 2744), (0x5885, 3944), (0x5889, 5558), (0x5892, 3801), (0x5893, 3314),
+    (0x5899, 3600), (0x589a, 5559), (0x589e, 4879), (0x589f, 4487), (0x58a8, 3300), (0x58a9, 2026), (0x58bc, 5503), (0x58c1, 1561),
+    (0x58c5, 5504), (0x58d1, 5505), (0x58d5, 2379), (0x58e4, 3697), (0x58eb, 3884), (0x58ec, 3706), (0x58ee, 5094), (0x58f0, 3848),
+    (0x58f3, 2858), (0x58f6, 2437), (0x58f9, 4633), (0x5902, 6135), (0x5904, 1791), (0x5907, 1527), (0x590d, 2181), (0x590f, 4359),
+    (0x5914, 5334), (0x5915, 4329), (0x5916, 4201), (0x5919, 5340), (0x591a, 2037), (0x591c, 4630), (0x591f, 2282), (0x5924, 6134),
+    (0x5925, 6132), (0x5927, 1868), (0x5929, 4117), (0x592a, 4052), (0x592b, 2149), (0x592d, 5187), (0x592e, 4586), (0x592f, 2376),
+    (0x5931, 3860), (0x5934, 4158), (0x5937, 4641), (0x5938, 2887), (0x5939, 2585), (0x593a, 2038), (0x593c, 5770), (0x5941, 5771),
+    (0x5942, 5479), (0x5944, 4568), (0x5947, 3547), (0x5948, 3335), (0x5949, 2145), (0x594b, 2127), (0x594e, 2911), (0x594f, 5139),
+    (0x5951, 3562), (0x5954, 1531), (0x5955, 5773), (0x5956, 2648), (0x5957, 4096), (0x5958, 5775), (0x595a, 5774), (0x5960, 1955),
+    (0x5962, 3820), (0x5965, 1443), (0x5973, 3397), (0x5974, 3394), (0x5976, 3333), (0x5978, 2610), (0x5979, 4040), (0x597d, 2384),
+    (0x5981, 6480), (0x5982, 3736), (0x5983, 6481), (0x5984, 4228), (0x5986, 5092), (0x5987, 2191), (0x5988, 3173), (0x598a, 3714),
+    (0x598d, 6482), (0x5992, 2015), (0x5993, 2580), (0x5996, 4604), (0x5997, 6486), (0x5999, 3273), (0x599e, 6489), (0x59a3, 6485),
+    (0x59a4, 6490), (0x59a5, 4190), (0x59a8, 2100), (0x59a9, 6483), (0x59aa, 6484), (0x59ab, 6488), (0x59ae, 3350), (0x59af, 6493),
+    (0x59b2, 6492), (0x59b9, 3230), (0x59bb, 3539), (0x59be, 6495), (0x59c6, 3312), (0x59ca, 6487), (0x59cb, 3881), (0x59d0, 2698),
+    (0x59d1, 2290), (0x59d2, 6491), (0x59d3, 4470), (0x59d4, 4244), (0x59d7, 6494), (0x59d8, 6501), (0x59da, 4611), (0x59dc, 2641),
+    (0x59dd, 6498), (0x59e3, 6500), (0x59e5, 2962), (0x59e8, 4649), (0x59ec, 2544), (0x59f9, 6502), (0x59fb, 4691), (0x59ff, 5118),
+    (0x5a01, 4229), (0x5a03, 4197), (0x5a04, 3107), (0x5a05, 6496), (0x5a06, 6497), (0x5a07, 2662), (0x5a08, 6499), (0x5a09, 6504),
+    (0x5a0c, 6503), (0x5a11, 6507), (0x5a13, 6509), (0x5a18, 3368), (0x5a1c, 3329), (0x5a1f, 2799), (0x5a20, 3838), (0x5a23, 6508),
+    (0x5a25, 2053), (0x5a29, 3263), (0x5a31, 4772), (0x5a32, 6505), (0x5a34, 6506), (0x5a36, 3667), (0x5a3c, 6514), (0x5a40, 6510),
+    (0x5a46, 3514), (0x5a49, 4216), (0x5a4a, 6512), (0x5a55, 6513), (0x5a5a, 2516), (0x5a62, 6515), (0x5a67, 6511), (0x5a6a, 2936),
+    (0x5a74, 4703), (0x5a75, 6516), (0x5a76, 3843), (0x5a77, 6520), (0x5a7a, 6521), (0x5a7f, 4503), (0x5a92, 3224), (0x5a9a, 3231),
+    (0x5a9b, 6519), (0x5aaa, 6518), (0x5ab2, 6524), (0x5ab3, 4340), (0x5ab5, 7039), (0x5ab8, 6527), (0x5abe, 6522), (0x5ac1, 2599),
+    (0x5ac2, 3768), (0x5ac9, 2558), (0x5acc, 4374), (0x5ad2, 6525), (0x5ad4, 6526), (0x5ad6, 6531), (0x5ad8, 6533), (0x5adc, 6534),
+    (0x5ae0, 6528), (0x5ae1, 1932), (0x5ae3, 6529), (0x5ae6, 6532), (0x5ae9, 3348), (0x5aeb, 6523), (0x5af1, 6530), (0x5b09, 6535),
+    (0x5b16, 6537), (0x5b17, 6536), (0x5b32, 6538), (0x5b34, 5351), (0x5b37, 6539), (0x5b40, 6540), (0x5b50, 5126), (0x5b51, 6546),
+    (0x5b53, 6547), (0x5b54, 2874), (0x5b55, 4841), (0x5b57, 5129), (0x5b58, 1855), (0x5b59, 4026), (0x5b5a, 6543), (0x5b5b, 5204),
+    (0x5b5c, 5121), (0x5b5d, 4419), (0x5b5f, 3242), (0x5b62, 6548), (0x5b63, 2567), (0x5b64, 2289), (0x5b65, 6544), (0x5b66, 4518),
+    (0x5b69, 2351), (0x5b6a, 3148), (0x5b6c, 5180), (0x5b70, 3928), (0x5b71, 6469), (0x5b73, 6545), (0x5b75, 2152), (0x5b7a, 3735),
+    (0x5b7d, 3374), (0x5b80, 6409), (0x5b81, 3383), (0x5b83, 4039), (0x5b84, 6410), (0x5b85, 4899), (0x5b87, 4777), (0x5b88, 3909),
+    (0x5b89, 1427), (0x5b8b, 3993), (0x5b8c, 4209), (0x5b8f, 2423), (0x5b93, 6412), (0x5b95, 6411), (0x5b97, 5133), (0x5b98, 2312),
+    (0x5b99, 5051), (0x5b9a, 1981), (0x5b9b, 4215), (0x5b9c, 4648), (0x5b9d, 1509), (0x5b9e, 3874), (0x5ba0, 1763), (0x5ba1, 3842),
+    (0x5ba2, 2864), (0x5ba3, 4508), (0x5ba4, 3903), (0x5ba5, 6413), (0x5ba6, 2477), (0x5baa, 4383), (0x5bab, 2267), (0x5bb0, 4848),
+    (0x5bb3, 2355), (0x5bb4, 4582), (0x5bb5, 4415), (0x5bb6, 2587), (0x5bb8, 6414), (0x5bb9, 3726), (0x5bbd, 2896), (0x5bbe, 1589),
+    (0x5bbf, 4009), (0x5bc2, 2574), (0x5bc4, 2573), (0x5bc5, 4695), (0x5bc6, 3255), (0x5bc7, 2879), (0x5bcc, 2188), (0x5bd0, 3229),
+    (0x5bd2, 2363), (0x5bd3, 4795), (0x5bdd, 3633), (0x5bde, 3304), (0x5bdf, 1673), (0x5be1, 2304), (0x5be4, 6418), (0x5be5, 3047),
+    (0x5be8, 4902), (0x5bee, 6419), (0x5bf0, 6421), (0x5bf8, 1856), (0x5bf9, 2025), (0x5bfa, 3981), (0x5bfb, 4527), (0x5bfc, 1907),
+    (0x5bff, 3910), (0x5c01, 2133), (0x5c04, 3827), (0x5c06, 2642), (0x5c09, 4259), (0x5c0a, 5155), (0x5c0f, 4418), (0x5c11, 3816),
+    (0x5c14, 2064), (0x5c15, 6541), (0x5c16, 2603), (0x5c18, 1721), (0x5c1a, 3807), (0x5c1c, 6542), (0x5c1d, 1693), (0x5c22, 5777),
+    (0x5c24, 4739), (0x5c25, 5778), (0x5c27, 4607), (0x5c2c, 5779), (0x5c31, 2770), (0x5c34, 5780), (0x5c38, 3865), (0x5c39, 4697),
+    (0x5c3a, 1754), (0x5c3b, 6465), (0x5c3c, 3354), (0x5c3d, 2726), (0x5c3e, 4247), (0x5c3f, 3371), (0x5c40, 2779), (0x5c41, 3485),
+    (0x5c42, 1664), (0x5c45, 2776), (0x5c48, 3663), (0x5c49, 4116), (0x5c4a, 2707), (0x5c4b, 4290), (0x5c4e, 3879), (0x5c4f, 3510),
+    (0x5c50, 6467), (0x5c51, 4445), (0x5c55, 4912), (0x5c59, 6468), (0x5c5e, 3937), (0x5c60, 4167), (0x5c61, 3138), (0x5c63, 6470),
+    (0x5c65, 3137), (0x5c66, 6471), (0x5c6e, 6479), (0x5c6f, 4180), (0x5c71, 3788), (0x5c79, 4662), (0x5c7a, 6023), (0x5c7f, 4775),
+    (0x5c81, 4021), (0x5c82, 3558), (0x5c88, 6027), (0x5c8c, 6022), (0x5c8d, 6024), (0x5c90, 6025), (0x5c91, 6030), (0x5c94, 1674),
+    (0x5c96, 6026), (0x5c97, 2219), (0x5c98, 6028), (0x5c99, 6029), (0x5c9a, 6031), (0x5c9b, 1905), (0x5c9c, 6032), (0x5ca2, 6034),
+    (0x5ca3, 6039), (0x5ca9, 4561), (0x5cab, 6037), (0x5cac, 6036), (0x5cad, 3082), (0x5cb1, 6038), (0x5cb3, 4825), (0x5cb5, 6033),
+    (0x5cb7, 6041), (0x5cb8, 1431), (0x5cbd, 6035), (0x5cbf, 2908), (0x5cc1, 6040), (0x5cc4, 6042), (0x5ccb, 6045), (0x5cd2, 6043),
+    (0x5cd9, 5018), (0x5ce1, 4354), (0x5ce4, 6044), (0x5ce5, 6046), (0x5ce6, 3146), (0x5ce8, 2048), (0x5cea, 4787), (0x5ced, 3616),
+    (0x5cf0, 2136), (0x5cfb, 2819), (0x5d02, 6047), (0x5d03, 6048), (0x5d06, 6054), (0x5d07, 1762), (0x5d0e, 3550), (0x5d14, 1847),
+    (0x5d16, 4545), (0x5d1b, 6055), (0x5d1e, 6053), (0x5d24, 6052), (0x5d26, 6050), (0x5d27, 6049), (0x5d29, 1535), (0x5d2d, 4911),
+    (0x5d2e, 6051), (0x5d34, 6058), (0x5d3d, 6059), (0x5d3e, 6057), (0x5d47, 7421), (0x5d4a, 6066), (0x5d4b, 6065), (0x5d4c, 3593),
+    (0x5d58, 6056), (0x5d5b, 6061), (0x5d5d, 6063), (0x5d69, 6067), (0x5d6b, 6064), (0x5d6c, 6060), (0x5d6f, 6062), (0x5d74, 6068),
+    (0x5d82, 6069), (0x5d99, 6070), (0x5d9d, 6071), (0x5db7, 6073), (0x5dc5, 6074), (0x5dcd, 4230), (0x5ddb, 6640), (0x5ddd, 1793),
+    (0x5dde, 5042), (0x5de1, 4529), (0x5de2, 1709), (0x5de5, 2259), (0x5de6, 5158), (0x5de7, 3612), (0x5de8, 2787), (0x5de9, 2269),
+    (0x5deb, 4284), (0x5dee, 1675), (0x5def, 5498), (0x5df1, 2563), (0x5df2, 4654), (0x5df3, 3987), (0x5df4, 1454), (0x5df7, 4402),
+    (0x5dfd, 5330), (0x5dfe, 2708), (0x5e01, 1553), (0x5e02, 3901), (0x5e03, 1625), (0x5e05, 3954), (0x5e06, 2078), (0x5e08, 3859),
+    (0x5e0c, 4326), (0x5e0f, 6009), (0x5e10, 4929), (0x5e11, 6012), (0x5e14, 6011), (0x5e15, 3416), (0x5e16, 4132), (0x5e18, 3024),
+    (0x5e19, 6010), (0x5e1a, 5048), (0x5e1b, 1612), (0x5e1c, 5017), (0x5e1d, 1938), (0x5e26, 1873), (0x5e27, 4982), (0x5e2d, 4338),
+    (0x5e2e, 1488), (0x5e31, 6013), (0x5e37, 6016), (0x5e38, 1694), (0x5e3b, 6014), (0x5e3c, 6015), (0x5e3d, 3212), (0x5e42, 3256),
+    (0x5e44, 6017), (0x5e45, 2156), (0x5e4c, 2490), (0x5e54, 6018), (0x5e55, 3316), (0x5e5b, 6019), (0x5e5e, 6020), (0x5e61, 6021),
+    (0x5e62, 1802), (0x5e72, 2202), (0x5e73, 3506), (0x5e74, 3363), (0x5e76, 1599), (0x5e78, 4467), (0x5e7a, 6638), (0x5e7b, 2478),
+    (0x5e7c, 4754), (0x5e7d, 4735), (0x5e7f, 2322), (0x5e80, 6156), (0x5e84, 5090), (0x5e86, 3647), (0x5e87, 1554), (0x5e8a, 1803),
+    (0x5e8b, 6158), (0x5e8f, 4499), (0x5e90, 3115), (0x5e91, 6157), (0x5e93, 2885), (0x5e94, 4705), (0x5e95, 1934), (0x5e96, 6159),
+    (0x5e97, 1953), (0x5e99, 3272), (0x5e9a, 2254), (0x5e9c, 2175), (0x5e9e, 3434), (0x5e9f, 2114), (0x5ea0, 6161), (0x5ea5, 6160),
+    (0x5ea6, 2013), (0x5ea7, 5164), (0x5ead, 4140), (0x5eb3, 6165), (0x5eb5, 6163), (0x5eb6, 3945), (0x5eb7, 2840), (0x5eb8, 4724),
+    (0x5eb9, 6162), (0x5ebe, 6164), (0x5ec9, 3021), (0x5eca, 2953), (0x5ed1, 6168), (0x5ed2, 6167), (0x5ed3, 2923), (0x5ed6, 3053),
+    (0x5edb, 6169), (0x5ee8, 6170), (0x5eea, 6171), (0x5ef4, 5491), (0x5ef6, 4562), (0x5ef7, 4137), (0x5efa, 2639), (0x5efe, 5768),
+    (0x5eff, 5174), (0x5f00, 2829), (0x5f01, 5496), (0x5f02, 4681), (0x5f03, 3567), (0x5f04, 3393), (0x5f08, 5769), (0x5f0a, 1558),
+    (0x5f0b, 5841), (0x5f0f, 3882), (0x5f11, 5844), (0x5f13, 2268), (0x5f15, 4698), (0x5f17, 2166), (0x5f18, 2424), (0x5f1b, 1749),
+    (0x5f1f, 1939), (0x5f20, 4924), (0x5f25, 3249), (0x5f26, 4373), (0x5f27, 2444), (0x5f29, 6474), (0x5f2a, 6473), (0x5f2d, 6475),
+    (0x5f2f, 4203), (0x5f31, 3750), (0x5f39, 1894), (0x5f3a, 3602), (0x5f3c, 6477), (0x5f40, 7065), (0x5f50, 6461), (0x5f52, 2328),
+    (0x5f53, 1896), (0x5f55, 3129), (0x5f56, 6463), (0x5f57, 6462), (0x5f58, 6464), (0x5f5d, 4650), (0x5f61, 6089), (0x5f62, 4463),
+    (0x5f64, 4149), (0x5f66, 4580), (0x5f69, 1639), (0x5f6a, 1578), (0x5f6c, 1585), (0x5f6d, 3460), (0x5f70, 4922), (0x5f71, 4715),
+    (0x5f73, 6075), (0x5f77, 6076), (0x5f79, 4664), (0x5f7b, 1716), (0x5f7c, 1546), (0x5f80, 4224), (0x5f81, 4974), (0x5f82, 6077),
+    (0x5f84, 2747), (0x5f85, 1878), (0x5f87, 6078), (0x5f88, 2409), (0x5f89, 6079), (0x5f8a, 2461), (0x5f8b, 3142), (0x5f8c, 6080),
+    (0x5f90, 4493), (0x5f92, 4164), (0x5f95, 6081), (0x5f97, 1914), (0x5f98, 3422), (0x5f99, 6082), (0x5f9c, 6083), (0x5fa1, 4788),
+    (0x5fa8, 6084), (0x5faa, 4524), (0x5fad, 6085), (0x5fae, 4231), (0x5fb5, 6086), (0x5fb7, 1913), (0x5fbc, 6087), (0x5fbd, 2496),
+    (0x5fc3, 4453), (0x5fc4, 6173), (0x5fc5, 1559), (0x5fc6, 4673), (0x5fc9, 6174), (0x5fcc, 2578), (0x5fcd, 3709), (0x5fcf, 6176),
+    (0x5fd0, 7143), (0x5fd1, 7142), (0x5fd2, 5842), (0x5fd6, 6175), (0x5fd7, 5011), (0x5fd8, 4227), (0x5fd9, 3201), (0x5fdd, 6238),
+    (0x5fe0, 5031), (0x5fe1, 6180), (0x5fe4, 6181), (0x5fe7, 4738), (0x5fea, 6185), (0x5feb, 2895), (0x5fed, 6186), (0x5fee, 6178),
+    (0x5ff1, 1723), (0x5ff5, 3367), (0x5ff8, 6187), (0x5ffb, 4452), (0x5ffd, 2435), (0x5ffe, 6182), (0x5fff, 2129), (0x6000, 2462),
+    (0x6001, 4053), (0x6002, 3990), (0x6003, 6177), (0x6004, 6179), (0x6005, 6183), (0x6006, 6184), (0x600a, 6196), (0x600d, 6193),
+    (0x600e, 4878), (0x600f, 6192), (0x6012, 3396), (0x6014, 4977), (0x6015, 3417), (0x6016, 1629), (0x6019, 6188), (0x601b, 6191),
+    (0x601c, 3022), (0x601d, 3975), (0x6020, 1880), (0x6021, 6198), (0x6025, 2554), (0x6026, 6190), (0x6027, 4469), (0x6028, 4818),
+    (0x6029, 6194), (0x602a, 2309), (0x602b, 6195), (0x602f, 3622), (0x6035, 6189), (0x603b, 5135), (0x603c, 7144), (0x603f, 6197),
+    (0x6041, 7148), (0x6042, 6203), (0x6043, 3902), (0x604b, 3028), (0x604d, 2491), (0x6050, 2873), (0x6052, 2416), (0x6055, 3948),
+    (0x6059, 7149), (0x605a, 7146), (0x605d, 7145), (0x6062, 2497), (0x6063, 7150), (0x6064, 4501), (0x6067, 7147), (0x6068, 2411),
+    (0x6069, 2060), (0x606a, 6204), (0x606b, 1991), (0x606c, 4122), (0x606d, 2262), (0x606f, 4325), (0x6070, 3572), (0x6073, 2869),
+    (0x6076, 2054), (0x6078, 6199), (0x6079, 6200), (0x607a, 6202), (0x607b, 6201), (0x607c, 3342), (0x607d, 6205), (0x607f, 4732),
+    (0x6083, 6210), (0x6084, 3607), (0x6089, 4327), (0x608c, 6212), (0x608d, 2372), (0x6092, 6211), (0x6094, 2501), (0x6096, 6206),
+    (0x609a, 6207), (0x609b, 6213), (0x609d, 6209), (0x609f, 4311), (0x60a0, 4737), (0x60a3, 2471), (0x60a6, 4828), (0x60a8, 3379),
+    (0x60ab, 7151), (0x60ac, 4509), (0x60ad, 6208), (0x60af, 3280), (0x60b1, 6216), (0x60b2, 1518), (0x60b4, 6221), (0x60b8, 2571),
+    (0x60bb, 6215), (0x60bc, 1910), (0x60c5, 3644), (0x60c6, 6219), (0x60ca, 2735), (0x60cb, 4214), (0x60d1, 2526), (0x60d5, 4113),
+    (0x60d8, 6218), (0x60da, 6220), (0x60dc, 4330), (0x60dd, 6217), (0x60df, 4238), (0x60e0, 2504), (0x60e6, 1954), (0x60e7, 2794),
+    (0x60e8, 1647), (0x60e9, 1736), (0x60eb, 1528), (0x60ec, 6214), (0x60ed, 1646), (0x60ee, 1891), (0x60ef, 2318), (0x60f0, 2045),
+    (0x60f3, 4398), (0x60f4, 6226), (0x60f6, 2487), (0x60f9, 3704), (0x60fa, 4459), (0x6100, 6227), (0x6101, 1769), (0x6106, 7152),
+    (0x6108, 4789), (0x6109, 4767), (0x610d, 7153), (0x610e, 6228), (0x610f, 4671), (0x6115, 6224), (0x611a, 4761), (0x611f, 2209),
+    (0x6120, 6222), (0x6123, 6225), (0x6124, 2130), (0x6126, 6223), (0x6127, 2915), (0x612b, 6229), (0x613f, 4817), (0x6148, 1825),
+    (0x614a, 6230), (0x614c, 2480), (0x614e, 3846), (0x6151, 3828), (0x6155, 3318), (0x615d, 7154), (0x6162, 3194), (0x6167, 2502),
+    (0x6168, 2833), (0x6170, 4260), (0x6175, 6231), (0x6177, 2841), (0x618b, 1582), (0x618e, 4880), (0x6194, 6233), (0x619d, 7156),
+    (0x61a7, 6234), (0x61a8, 2358), (0x61a9, 7155), (0x61ac, 6232), (0x61b7, 6235), (0x61be, 2371), (0x61c2, 1987), (0x61c8, 4441),
+    (0x61ca, 1444), (0x61cb, 7157), (0x61d1, 7158), (0x61d2, 2946), (0x61d4, 6236), (0x61e6, 3402), (0x61f5, 6237), (0x61ff, 5563),
+    (0x6206, 7159), (0x6208, 2235), (0x620a, 4305), (0x620b, 6868), (0x620c, 4488), (0x620d, 3942), (0x620e, 3719), (0x620f, 4346),
+    (0x6210, 1732), (0x6211, 4279), (0x6212, 2699), (0x6215, 6265), (0x6216, 2525), (0x6217, 6869), (0x6218, 4916), (0x621a, 3538),
+    (0x621b, 6870), (0x621f, 6871), (0x6221, 6873), (0x6222, 6872), (0x6224, 6875), (0x6225, 6874), (0x622a, 2687), (0x622c, 6876),
+    (0x622e, 3131), (0x6233, 1818), (0x6234, 1872), (0x6237, 2450), (0x623d, 7119), (0x623e, 7118), (0x623f, 2098), (0x6240, 4036),
+    (0x6241, 1569), (0x6243, 7120), (0x6247, 3799), (0x6248, 7121), (0x6249, 7122), (0x624b, 3907), (0x624c, 5781), (0x624d, 1634),
+    (0x624e, 4883), (0x6251, 3520), (0x6252, 1448), (0x6253, 1867), (0x6254, 3716), (0x6258, 4183), (0x625b, 2843), (0x6263, 2878),
+    (0x6266, 3575), (0x6267, 5001), (0x6269, 2922), (0x626a, 5782), (0x626b, 3767), (0x626c, 4590), (0x626d, 3387), (0x626e, 1480),
+    (0x626f, 1713), (0x6270, 3702), (0x6273, 1475), (0x6276, 2153), (0x6279, 3473), (0x627c, 2056), (0x627e, 4937), (0x627f, 1739),
+    (0x6280, 2565), (0x6284, 1704), (0x6289, 2806), (0x628a, 1458), (0x6291, 4659), (0x6292, 3920), (0x6293, 5080), (0x6295, 4157),
+    (0x6296, 1995), (0x6297, 2844), (0x6298, 4946), (0x629a, 2168), (0x629b, 3438), (0x629f, 5783), (0x62a0, 2876), (0x62a1, 3154),
+    (0x62a2, 3603), (0x62a4, 2447), (0x62a5, 1511), (0x62a8, 3457), (0x62ab, 3474), (0x62ac, 4048), (0x62b1, 1510), (0x62b5, 1933),
+    (0x62b9, 3297), (0x62bb, 5784), (0x62bc, 4537), (0x62bd, 1764), (0x62bf, 3277), (0x62c2, 2154), (0x62c4, 5065), (0x62c5, 1882),
+    (0x62c6, 1677), (0x62c7, 3309), (0x62c8, 3362), (0x62c9, 2926), (0x62ca, 5785), (0x62cc, 1481), (0x62cd, 3419), (0x62ce, 3071),
+    (0x62d0, 2308), (0x62d2, 2785), (0x62d3, 4191), (0x62d4, 1455), (0x62d6, 4182), (0x62d7, 5787), (0x62d8, 2773), (0x62d9, 5105),
+    (0x62da, 5786), (0x62db, 4935), (0x62dc, 1470), (0x62df, 3355), (0x62e2, 3104), (0x62e3, 2617), (0x62e5, 4720), (0x62e6, 2938),
+    (0x62e7, 3384), (0x62e8, 1603), (0x62e9, 4874), (0x62ec, 2921), (0x62ed, 3888), (0x62ee, 5788), (0x62ef, 4979), (0x62f1, 2271),
+    (0x62f3, 3678), (0x62f4, 3956), (0x62f6, 5790), (0x62f7, 2848), (0x62fc, 3497), (0x62fd, 5082), (0x62fe, 3869), (0x62ff, 3324),
+    (0x6301, 1745), (0x6302, 2305), (0x6307, 5005), (0x6308, 6953), (0x6309, 1429), (0x630e, 2889), (0x6311, 4125), (0x6316, 4193),
+    (0x631a, 5012), (0x631b, 3147), (0x631d, 4275), (0x631e, 4043), (0x631f, 4431), (0x6320, 3340), (0x6321, 1897), (0x6322, 5789),
+    (0x6323, 4972), (0x6324, 2560), (0x6325, 2494), (0x6328, 1413), (0x632a, 3401), (0x632b, 1861), (0x632f, 4968), (0x6332, 6954),
+    (0x6339, 5791), (0x633a, 4141), (0x633d, 4211), (0x6342, 4299), (0x6343, 5793), (0x6345, 4152), (0x6346, 2919), (0x6349, 5104),
+    (0x634b, 5792), (0x634c, 1447), (0x634d, 2369), (0x634e, 3810), (0x634f, 3372), (0x6350, 2797), (0x6355, 1619), (0x635e, 2957),
+    (0x635f, 4027), (0x6361, 2618), (0x6362, 2470), (0x6363, 1902), (0x6367, 3468), (0x6369, 5803), (0x636d, 5800), (0x636e, 2786),
+    (0x6371, 5796), (0x6376, 1808), (0x6377, 2692), (0x637a, 5797), (0x637b, 3366), (0x6380, 4361), (0x6382, 1943), (0x6387, 2035),
+    (0x6388, 3911), (0x6389, 1963), (0x638a, 5802), (0x638c, 4925), (0x638e, 5798), (0x638f, 4086), (0x6390, 3571), (0x6392, 3420),
+    (0x6396, 4625), (0x6398, 2807), (0x63a0, 3152), (0x63a2, 4070), (0x63a3, 1715), (0x63a5, 2682), (0x63a7, 2875), (0x63a8, 4173),
+    (0x63a9, 4569), (0x63aa, 1860), (0x63ac, 5801), (0x63ad, 5794), (0x63ae, 5804), (0x63b0, 6955), (0x63b3, 3117), (0x63b4, 5799),
+    (0x63b7, 5013), (0x63b8, 1886), (0x63ba, 1681), (0x63bc, 5805), (0x63be, 5815), (0x63c4, 5810), (0x63c6, 5814), (0x63c9, 3729),
+    (0x63cd, 5140), (0x63ce, 5812), (0x63cf, 3267), (0x63d0, 4106), (0x63d2, 1666), (0x63d6, 4635), (0x63de, 5811), (0x63e0, 5808),
+    (0x63e1, 4282), (0x63e3, 1792), (0x63e9, 2830), (0x63ea, 2755), (0x63ed, 2681), (0x63f2, 5806), (0x63f4, 4807), (0x63f6, 5795),
+    (0x63f8, 5807), (0x63fd, 2944), (0x63ff, 5809), (0x6400, 1680), (0x6401, 2234), (0x6402, 3108), (0x6405, 2664), (0x640b, 5818),
+    (0x640c, 5821), (0x640f, 1608), (0x6410, 1789), (0x6413, 1859), (0x6414, 3765), (0x641b, 5819), (0x641c, 3996), (0x641e, 2228),
+    (0x6420, 5820), (0x6421, 5823), (0x6426, 5822), (0x642a, 4075), (0x642c, 1474), (0x642d, 1863), (0x6434, 6417), (0x643a, 4432),
+    (0x643d, 1672), (0x643f, 6956), (0x6441, 5817), (0x6444, 3826), (0x6445, 5816), (0x6446, 1467), (0x6447, 4606), (0x6448, 1590),
+    (0x644a, 4056), (0x6452, 5813), (0x6454, 3951), (0x6458, 4897), (0x645e, 5824), (0x6467, 1846), (0x6469, 3295), (0x646d, 5826),
+    (0x6478, 3289), (0x6479, 3290), (0x647a, 5828), (0x6482, 3051), (0x6484, 5825), (0x6485, 2804), (0x6487, 3495), (0x6491, 1728),
+    (0x6492, 3751), (0x6495, 3973), (0x6496, 5827), (0x6499, 5831), (0x649e, 5093), (0x64a4, 1714), (0x64a9, 3042), (0x64ac, 3614),
+    (0x64ad, 1602), (0x64ae, 1858), (0x64b0, 5086), (0x64b5, 3365), (0x64b7, 5829), (0x64b8, 5830), (0x64ba, 5832), (0x64bc, 2368),
+    (0x64c0, 5833), (0x64c2, 2975), (0x64c5, 3794), (0x64cd, 1654), (0x64ce, 3641), (0x64d0, 5834), (0x64d2, 3631), (0x64d7, 5835),
+    (0x64d8, 6957), (0x64de, 3998), (0x64e2, 5837), (0x64e4, 5836), (0x64e6, 1630), (0x6500, 3425), (0x6509, 5838), (0x6512, 4853),
+    (0x6518, 3698), (0x6525, 5839), (0x652b, 2805), (0x652e, 5840), (0x652f, 4988), (0x6534, 6884), (0x6535, 6977), (0x6536, 3906),
+    (0x6538, 5261), (0x6539, 2197), (0x653b, 2260), (0x653e, 2104), (0x653f, 4981), (0x6545, 2297), (0x6548, 4424), (0x6549, 7846),
+    (0x654c, 1927), (0x654f, 3279), (0x6551, 2765), (0x6555, 6978), (0x6556, 1438), (0x6559, 2675), (0x655b, 3025), (0x655d, 1557),
+    (0x655e, 1699), (0x6562, 2211), (0x6563, 3761), (0x6566, 2029), (0x656b, 6979), (0x656c, 2745), (0x6570, 3946), (0x6572, 3606),
+    (0x6574, 4978), (0x6577, 2150), (0x6587, 4265), (0x658b, 4898), (0x658c, 1586), (0x6590, 7068), (0x6591, 1472), (0x6593, 7070),
+    (0x6597, 1996), (0x6599, 3054), (0x659b, 7985), (0x659c, 4434), (0x659f, 4956), (0x65a1, 4280), (0x65a4, 2710), (0x65a5, 1757),
+    (0x65a7, 2172), (0x65a9, 4909), (0x65ab, 7172), (0x65ad, 2020), (0x65af, 3972), (0x65b0, 4451), (0x65b9, 2096), (0x65bc, 7071),
+    (0x65bd, 3862), (0x65c1, 3435), (0x65c3, 7074), (0x65c4, 7073), (0x65c5, 3136), (0x65c6, 7072), (0x65cb, 4510), (0x65cc, 7075),
+    (0x65ce, 7076), (0x65cf, 5144), (0x65d2, 7077), (0x65d6, 7078), (0x65d7, 3553), (0x65e0, 4291), (0x65e2, 2577), (0x65e5, 3718),
+    (0x65e6, 1888), (0x65e7, 2766), (0x65e8, 5009), (0x65e9, 4864), (0x65ec, 4525), (0x65ed, 4498), (0x65ee, 6885), (0x65ef, 6886),
+    (0x65f0, 6887), (0x65f1, 2370), (0x65f6, 3870), (0x65f7, 2904), (0x65fa, 4225), (0x6600, 6893), (0x6602, 1435), (0x6603, 6891),
+    (0x6606, 2918), (0x660a, 6888), (0x660c, 1690), (0x660e, 3282), (0x660f, 2515), (0x6613, 4660), (0x6614, 4313), (0x6615, 6892),
+    (0x6619, 6889), (0x661d, 6896), (0x661f, 4456), (0x6620, 4718), (0x6625, 1811), (0x6627, 3228), (0x6628, 5157), (0x662d, 4936),
+    (0x662f, 3892), (0x6631, 6898), (0x6634, 6897), (0x6635, 6900), (0x6636, 6899), (0x663c, 5052), (0x663e, 4375), (0x6641, 6904),
+    (0x6643, 2489), (0x664b, 2721), (0x664c, 3805), (0x664f, 6905), (0x6652, 3784), (0x6653, 4417), (0x6654, 6903), (0x6655, 4839),
+    (0x6656, 6906), (0x6657, 6908), (0x665a, 4212), (0x665f, 6902), (0x6661, 6907), (0x6664, 4307), (0x6666, 2505), (0x6668, 1722),
+    (0x666e, 3530), (0x666f, 2741), (0x6670, 4319), (0x6674, 3642), (0x6676, 2732), (0x6677, 6909), (0x667a, 5020), (0x667e, 3039),
+    (0x6682, 4854), (0x6684, 6910), (0x6687, 4353), (0x668c, 6911), (0x6691, 3931), (0x6696, 3398), (0x6697, 1430), (0x669d, 6913),
+    (0x66a7, 6912), (0x66a8, 7864), (0x66ae, 3315), (0x66b4, 1512), (0x66b9, 6454), (0x66be, 6914), (0x66d9, 3932), (0x66db, 6915),
+    (0x66dc, 6916), (0x66dd, 3533), (0x66e6, 6917), (0x66e9, 6918), (0x66f0, 4820), (0x66f2, 3661), (0x66f3, 4628), (0x66f4, 2253),
+    (0x66f7, 6895), (0x66f9, 1657), (0x66fc, 3193), (0x66fe, 4881), (0x66ff, 4111), (0x6700, 5153), (0x6708, 4827), (0x6709, 4747),
+    (0x670a, 6990), (0x670b, 3466), (0x670d, 2161), (0x6710, 7007), (0x6714, 3970), (0x6715, 7017), (0x6717, 2955), (0x671b, 4226),
+    (0x671d, 1706), (0x671f, 3535), (0x6726, 7047), (0x6728, 3319), (0x672a, 4249), (0x672b, 3298), (0x672c, 1533), (0x672d, 4886),
+    (0x672f, 3938), (0x6731, 5057), (0x6734, 3528), (0x6735, 2041), (0x673a, 2533), (0x673d, 4481), (0x6740, 3776), (0x6742, 4844),
+    (0x6743, 3673), (0x6746, 2204), (0x6748, 6703), (0x6749, 3787), (0x674c, 6700), (0x674e, 2991), (0x674f, 4468), (0x6750, 1633),
+    (0x6751, 1854), (0x6753, 6701), (0x6756, 4927), (0x675c, 2010), (0x675e, 6702), (0x675f, 3941), (0x6760, 2221), (0x6761, 4126),
+    (0x6765, 2933), (0x6768, 4589), (0x6769, 6704), (0x676a, 6707), (0x676d, 2377), (0x676f, 1516), (0x6770, 2691), (0x6772, 6890),
+    (0x6773, 6708), (0x6775, 6711), (0x6777, 6716), (0x677c, 6717), (0x677e, 3988), (0x677f, 1478), (0x6781, 2548), (0x6784, 2280),
+    (0x6787, 6706), (0x6789, 4222), (0x678b, 6715), (0x6790, 4315), (0x6795, 4964), (0x6797, 3061), (0x6798, 6709), (0x679a, 3217),
+    (0x679c, 2346), (0x679d, 4987), (0x679e, 6713), (0x67a2, 3917), (0x67a3, 4863), (0x67a5, 6705), (0x67a7, 6710), (0x67a8, 6712),
+    (0x67aa, 3596), (0x67ab, 2134), (0x67ad, 6714), (0x67af, 2880), (0x67b0, 6723), (0x67b3, 6728), (0x67b5, 6726), (0x67b6, 2597),
+    (0x67b7, 2584), (0x67b8, 6732), (0x67c1, 6735), (0x67c3, 6731), (0x67c4, 1593), (0x67cf, 1465), (0x67d0, 3308), (0x67d1, 2205),
+    (0x67d2, 3543), (0x67d3, 3695), (0x67d4, 3730), (0x67d8, 6720), (0x67d9, 6725), (0x67da, 6727), (0x67dc, 2336), (0x67dd, 6729),
+    (0x67de, 5160), (0x67e0, 3380), (0x67e2, 6733), (0x67e5, 1670), (0x67e9, 6722), (0x67ec, 2614), (0x67ef, 2853), (0x67f0, 6718),
+    (0x67f1, 5070), (0x67f3, 3095), (0x67f4, 1678), (0x67fd, 6736), (0x67ff, 3886), (0x6800, 6730), (0x6805, 4891), (0x6807, 1577),
+    (0x6808, 4914), (0x6809, 6719), (0x680a, 6721), (0x680b, 1989), (0x680c, 6724), (0x680e, 6734), (0x680f, 2937), (0x6811, 3940),
+    (0x6813, 3955), (0x6816, 3537), (0x6817, 2998), (0x681d, 6746), (0x6821, 4420), (0x6829, 6755), (0x682a, 5055), (0x6832, 6737),
+    (0x6833, 6738), (0x6837, 4600), (0x6838, 2392), (0x6839, 2250), (0x683c, 2242), (0x683d, 4845), (0x683e, 6752), (0x6840, 6751),
+    (0x6841, 6749), (0x6842, 2335), (0x6843, 4091), (0x6844, 6743), (0x6845, 4235), (0x6846, 2901), (0x6848, 1433), (0x6849, 6754),
+    (0x684a, 6753), (0x684c, 5107), (0x684e, 6741), (0x6850, 4144), (0x6851, 3762), (0x6853, 2467), (0x6854, 2690), (0x6855, 6747),
+    (0x6860, 6739), (0x6861, 6740), (0x6862, 6742), (0x6863, 1900), (0x6864, 6744), (0x6865, 3608), (0x6866, 6748), (0x6867, 6750),
+    (0x6868, 2647), (0x6869, 5089), (0x686b, 6761), (0x6874, 6758), (0x6876, 4151), (0x6877, 6759), (0x6881, 3033), (0x6883, 6745),
+    (0x6885, 3218), (0x6886, 1489), (0x688f, 6757), (0x6893, 6760), (0x6897, 2258), (0x68a2, 3809), (0x68a6, 3241), (0x68a7, 4293),
+    (0x68a8, 2983), (0x68ad, 4030), (0x68af, 4102), (0x68b0, 4438), (0x68b3, 3918), (0x68b5, 6756), (0x68c0, 2613), (0x68c2, 6762),
+    (0x68c9, 3257), (0x68cb, 3546), (0x68cd, 2342), (0x68d2, 1493), (0x68d5, 5131), (0x68d8, 2549), (0x68da, 3462), (0x68e0, 4077),
+    (0x68e3, 6773), (0x68ee, 3772), (0x68f0, 6769), (0x68f1, 2979), (0x68f5, 2854), (0x68f9, 6767), (0x68fa, 2310), (0x68fc, 6764),
+    (0x6901, 6771), (0x6905, 4651), (0x690b, 6770), (0x690d, 4999), (0x690e, 5096), (0x6910, 6774), (0x6912, 2654), (0x691f, 6765),
+    (0x6920, 6766), (0x6924, 6768), (0x692d, 4189), (0x6930, 4617), (0x6934, 6785), (0x6939, 6776), (0x693d, 1795), (0x693f, 1812),
+    (0x6942, 6778), (0x6954, 4425), (0x6957, 6772), (0x695a, 1785), (0x695d, 6779), (0x695e, 2980), (0x6960, 6777), (0x6963, 6792),
+    (0x6966, 6791), (0x696b, 6781), (0x696e, 6763), (0x6971, 6775), (0x6977, 2831), (0x6978, 6784), (0x6979, 6793), (0x697c, 3106),
+    (0x6980, 6782), (0x6982, 2198), (0x6984, 6780), (0x6986, 4759), (0x6987, 6787), (0x6988, 6788), (0x6989, 6790), (0x698d, 6806),
+    (0x6994, 2951), (0x6995, 6804), (0x6998, 6783), (0x699b, 6794), (0x699c, 1490), (0x69a7, 6795), (0x69a8, 4892), (0x69ab, 6797),
+    (0x69ad, 6798), (0x69b1, 6800), (0x69b4, 3088), (0x69b7, 3687), (0x69bb, 6796), (0x69c1, 6801), (0x69ca, 6802), (0x69cc, 6786),
+    (0x69ce, 6789), (0x69d0, 2460), (0x69d4, 6799), (0x69db, 2624), (0x69df, 6803), (0x69e0, 6805), (0x69ed, 6809), (0x69f2, 6813),
+    (0x69fd, 1656), (0x69ff, 6807), (0x6a0a, 2081), (0x6a17, 6810), (0x6a18, 6811), (0x6a1f, 4920), (0x6a21, 3292), (0x6a28, 6823),
+    (0x6a2a, 2414), (0x6a2f, 6808), (0x6a31, 4702), (0x6a35, 6819), (0x6a3d, 6822), (0x6a3e, 6815), (0x6a44, 6814), (0x6a47, 3604),
+    (0x6a50, 6817), (0x6a58, 6824), (0x6a59, 1731), (0x6a5b, 6818), (0x6a61, 4403), (0x6a65, 6812), (0x6a71, 1778), (0x6a79, 6821),
+    (0x6a7c, 6825), (0x6a80, 4061), (0x6a84, 4336), (0x6a8e, 6820), (0x6a90, 6827), (0x6a91, 6826), (0x6a97, 6829), (0x6aa0, 6816),
+    (0x6aa9, 6828), (0x6aab, 6830), (0x6aac, 3237), (0x6b20, 3594), (0x6b21, 1831), (0x6b22, 2465), (0x6b23, 4449), (0x6b24, 7052),
+    (0x6b27, 3406), (0x6b32, 4790), (0x6b37, 7053), (0x6b39, 7054), (0x6b3a, 3536), (0x6b3e, 2897), (0x6b43, 7055), (0x6b46, 7056),
+    (0x6b47, 4427), (0x6b49, 3595), (0x6b4c, 2233), (0x6b59, 7057), (0x6b62, 5006), (0x6b63, 4980), (0x6b64, 1828), (0x6b65, 1626),
+    (0x6b66, 4297), (0x6b67, 3548), (0x6b6a, 4200), (0x6b79, 1870), (0x6b7b, 3979), (0x6b7c, 2600), (0x6b81, 6833), (0x6b82, 6834),
+    (0x6b83, 4585), (0x6b84, 6836), (0x6b86, 1874), (0x6b87, 6835), (0x6b89, 4530), (0x6b8a, 3919), (0x6b8b, 1645), (0x6b8d, 6839),
+    (0x6b92, 6837), (0x6b93, 6838), (0x6b96, 5000), (0x6b9a, 6840), (0x6b9b, 6841), (0x6ba1, 6842), (0x6baa, 6843), (0x6bb3, 7064),
+    (0x6bb4, 3408), (0x6bb5, 2019), (0x6bb7, 4688), (0x6bbf, 1957), (0x6bc1, 2500), (0x6bc2, 7066), (0x6bc5, 4672), (0x6bcb, 4296),
+    (0x6bcd, 3313), (0x6bcf, 3226), (0x6bd2, 2003), (0x6bd3, 5194), (0x6bd4, 1543), (0x6bd5, 1550), (0x6bd6, 1552), (0x6bd7, 3477),
+    (0x6bd9, 1551), (0x6bdb, 3206), (0x6be1, 4904), (0x6bea, 6959), (0x6beb, 2382), (0x6bef, 4067), (0x6bf3, 6960), (0x6bf5, 6962),
+    (0x6bf9, 6963), (0x6bfd, 6961), (0x6c05, 6964), (0x6c06, 6966), (0x6c07, 6965), (0x6c0d, 6967), (0x6c0f, 3900), (0x6c10, 5190),
+    (0x6c11, 3276), (0x6c13, 3200), (0x6c14, 3565), (0x6c15, 6968), (0x6c16, 3331), (0x6c18, 6969), (0x6c19, 6970), (0x6c1a, 6971),
+    (0x6c1b, 2120), (0x6c1f, 2157), (0x6c21, 6972), (0x6c22, 3637), (0x6c24, 6974), (0x6c26, 2353), (0x6c27, 4596), (0x6c28, 1426),
+    (0x6c29, 6973), (0x6c2a, 6975), (0x6c2e, 1889), (0x6c2f, 3141), (0x6c30, 3643), (0x6c32, 6976), (0x6c34, 3961), (0x6c35, 6266),
+    (0x6c38, 4731), (0x6c3d, 5322), (0x6c40, 4136), (0x6c41, 4994), (0x6c42, 3654), (0x6c46, 5327), (0x6c47, 2510), (0x6c49, 2375),
+    (0x6c4a, 6269), (0x6c50, 4334), (0x6c54, 6267), (0x6c55, 3798), (0x6c57, 2374), (0x6c5b, 4531), (0x6c5c, 6268), (0x6c5d, 3739),
+    (0x6c5e, 2270), (0x6c5f, 2644), (0x6c60, 1747), (0x6c61, 4288), (0x6c64, 4073), (0x6c68, 6275), (0x6c69, 6276), (0x6c6a, 4219),
+    (0x6c70, 4054), (0x6c72, 2556), (0x6c74, 6277), (0x6c76, 6278), (0x6c79, 4475), (0x6c7d, 3568), (0x6c7e, 2125), (0x6c81, 3634),
+    (0x6c82, 4647), (0x6c83, 4283), (0x6c85, 6271), (0x6c86, 6279), (0x6c88, 3841), (0x6c89, 1724), (0x6c8c, 6274), (0x6c8f, 3544),
+    (0x6c90, 6272), (0x6c93, 7162), (0x6c94, 6273), (0x6c99, 3778), (0x6c9b, 3453), (0x6c9f, 2276), (0x6ca1, 3222), (0x6ca3, 6270),
+    (0x6ca4, 3412), (0x6ca5, 3011), (0x6ca6, 3158), (0x6ca7, 1652), (0x6ca9, 6280), (0x6caa, 2449), (0x6cab, 3302), (0x6cad, 6283),
+    (0x6cae, 2783), (0x6cb1, 6294), (0x6cb2, 6288), (0x6cb3, 2400), (0x6cb8, 2115), (0x6cb9, 4744), (0x6cbb, 5027), (0x6cbc, 4938),
+    (0x6cbd, 2288), (0x6cbe, 4907), (0x6cbf, 4567), (0x6cc4, 4442), (0x6cc5, 3657), (0x6cc9, 3675), (0x6cca, 1617), (0x6ccc, 3253),
+    (0x6cd0, 6281), (0x6cd3, 6295), (0x6cd4, 6282), (0x6cd5, 2075), (0x6cd6, 6290), (0x6cd7, 6287), (0x6cdb, 2093), (0x6cde, 3385),
+    (0x6ce0, 6289), (0x6ce1, 3444), (0x6ce2, 1605), (0x6ce3, 3569), (0x6ce5, 3353), (0x6ce8, 5077), (0x6cea, 2978), (0x6ceb, 6292),
+    (0x6cee, 6293), (0x6cef, 6296), (0x6cf0, 4050), (0x6cf1, 6286), (0x6cf3, 4729), (0x6cf5, 1538), (0x6cf6, 7163), (0x6cf7, 6284),
+    (0x6cf8, 6285), (0x6cfa, 6291), (0x6cfb, 4443), (0x6cfc, 3512), (0x6cfd, 4876), (0x6cfe, 6297), (0x6d01, 2695), (0x6d04, 6304),
+    (0x6d07, 6303), (0x6d0b, 4594), (0x6d0c, 6300), (0x6d0e, 6306), (0x6d12, 3752), (0x6d17, 4343), (0x6d19, 6305), (0x6d1a, 6311),
+    (0x6d1b, 3170), (0x6d1e, 1993), (0x6d25, 2713), (0x6d27, 6299), (0x6d2a, 2422), (0x6d2b, 6307), (0x6d2e, 6309), (0x6d31, 2066),
+    (0x6d32, 5043), (0x6d33, 6315), (0x6d35, 6310), (0x6d39, 6298), (0x6d3b, 2521), (0x6d3c, 4196), (0x6d3d, 3573), (0x6d3e, 3424),
+    (0x6d41, 3094), (0x6d43, 6301), (0x6d45, 3590), (0x6d46, 2643), (0x6d47, 2660), (0x6d48, 6302), (0x6d4a, 5114), (0x6d4b, 1663),
+    (0x6d4d, 6308), (0x6d4e, 2572), (0x6d4f, 6312), (0x6d51, 2518), (0x6d52, 6313), (0x6d53, 3391), (0x6d54, 6314), (0x6d59, 4954),
+    (0x6d5a, 2822), (0x6d5c, 6323), (0x6d5e, 6320), (0x6d60, 6324), (0x6d63, 6326), (0x6d66, 3531), (0x6d69, 2387), (0x6d6a, 2956),
+    (0x6d6e, 2162), (0x6d6f, 6317), (0x6d74, 4794), (0x6d77, 2352), (0x6d78, 2725), (0x6d7c, 6325), (0x6d82, 4166), (0x6d85, 3378),
+    (0x6d88, 4414), (0x6d89, 3829), (0x6d8c, 4730), (0x6d8e, 4372), (0x6d91, 6316), (0x6d93, 6321), (0x6d94, 6322), (0x6d95, 4114),
+    (0x6d9b, 4087), (0x6d9d, 2965), (0x6d9e, 6318), (0x6d9f, 3023), (0x6da0, 6319), (0x6da1, 4277), (0x6da3, 2476), (0x6da4, 1930),
+    (0x6da6, 3748), (0x6da7, 2638), (0x6da8, 4926), (0x6da9, 3771), (0x6daa, 2163), (0x6dab, 6339), (0x6dae, 6341), (0x6daf, 4547),
+    (0x6db2, 4631), (0x6db5, 2362), (0x6db8, 2401), (0x6dbf, 6332), (0x6dc0, 1956), (0x6dc4, 5120), (0x6dc5, 6329), (0x6dc6, 4416),
+    (0x6dc7, 6328), (0x6dcb, 3067), (0x6dcc, 4083), (0x6dd1, 3924), (0x6dd6, 3344), (0x6dd8, 4093), (0x6dd9, 6337), (0x6ddd, 6336),
+    (0x6dde, 6330), (0x6de0, 6333), (0x6de1, 1892), (0x6de4, 4756), (0x6de6, 6335), (0x6deb, 4694), (0x6dec, 1852), (0x6dee, 2463),
+    (0x6df1, 3837), (0x6df3, 1815), (0x6df7, 2519), (0x6df9, 4556), (0x6dfb, 4118), (0x6dfc, 7164), (0x6e05, 3640), (0x6e0a, 4801),
+    (0x6e0c, 6340), (0x6e0d, 5128), (0x6e0e, 6331), (0x6e10, 2636), (0x6e11, 6334), (0x6e14, 4769), (0x6e16, 6338), (0x6e17, 3847),
+    (0x6e1a, 6327), (0x6e1d, 4768), (0x6e20, 3665), (0x6e21, 2014), (0x6e23, 4885), (0x6e24, 1616), (0x6e25, 6352), (0x6e29, 4263),
+    (0x6e2b, 6342), (0x6e2d, 4257), (0x6e2f, 2220), (0x6e32, 6351), (0x6e34, 2861), (0x6e38, 4745), (0x6e3a, 3271), (0x6e43, 3423),
+    (0x6e44, 6353), (0x6e4d, 4171), (0x6e4e, 6344), (0x6e53, 6349), (0x6e54, 6350), (0x6e56, 2443), (0x6e58, 4393), (0x6e5b, 4918),
+    (0x6e5f, 6347), (0x6e6b, 6345), (0x6e6e, 6343), (0x6e7e, 4204), (0x6e7f, 3863), (0x6e83, 2916), (0x6e85, 2637), (0x6e86, 6348),
+    (0x6e89, 2201), (0x6e8f, 6368), (0x6e90, 4813), (0x6e98, 6356), (0x6e9c, 3086), (0x6e9f, 6370), (0x6ea2, 4676), (0x6ea5, 6360),
+    (0x6ea7, 6361), (0x6eaa, 4333), (0x6eaf, 4008), (0x6eb1, 6355), (0x6eb2, 6346), (0x6eb4, 6366), (0x6eb6, 3725), (0x6eb7, 6364),
+    (0x6eba, 3360), (0x6ebb, 6363), (0x6ebd, 6362), (0x6ec1, 1783), (0x6ec2, 6369), (0x6ec7, 1944), (0x6ecb, 5119), (0x6ecf, 6367),
+    (0x6ed1, 2455), (0x6ed3, 5125), (0x6ed4, 4088), (0x6ed5, 7043), (0x6ed7, 6365), (0x6eda, 2341), (0x6ede, 5026), (0x6edf, 6354),
+    (0x6ee0, 6357), (0x6ee1, 3191), (0x6ee2, 6359), (0x6ee4, 3144), (0x6ee5, 2949), (0x6ee6, 3149), (0x6ee8, 1588), (0x6ee9, 4059),
+    (0x6ef4, 1925), (0x6ef9, 6376), (0x6f02, 3492), (0x6f06, 3542), (0x6f09, 6382), (0x6f0f, 3110), (0x6f13, 2989), (0x6f14, 4572),
+    (0x6f15, 6375), (0x6f20, 3303), (0x6f24, 6374), (0x6f29, 6383), (0x6f2a, 6381), (0x6f2b, 3195), (0x6f2d, 6358), (0x6f2f, 6377),
+    (0x6f31, 3947), (0x6f33, 4923), (0x6f36, 6378), (0x6f3e, 4601), (0x6f46, 6372), (0x6f47, 6373), (0x6f4b, 6379), (0x6f4d, 4240),
+    (0x6f58, 3426), (0x6f5c, 3588), (0x6f5e, 3127), (0x6f62, 6371), (0x6f66, 3049), (0x6f6d, 4063), (0x6f6e, 1708), (0x6f72, 6388),
+    (0x6f74, 6380), (0x6f78, 6387), (0x6f7a, 6390), (0x6f7c, 6389), (0x6f84, 1737), (0x6f88, 1717), (0x6f89, 6384), (0x6f8c, 6386),
+    (0x6f8d, 6385), (0x6f8e, 3459), (0x6f9c, 2942), (0x6fa1, 4865), (0x6fa7, 6393), (0x6fb3, 1445), (0x6fb6, 6395), (0x6fb9, 6394),
+    (0x6fc0, 2541), (0x6fc2, 6396), (0x6fc9, 6392), (0x6fd1, 6391), (0x6fd2, 1587), (0x6fde, 6399), (0x6fe0, 6400), (0x6fe1, 6397),
+    (0x6fee, 6398), (0x6fef, 6401), (0x7011, 3534), (0x701a, 6402), (0x701b, 6404), (0x7023, 6403), (0x7035, 6406), (0x7039, 6405),
+    (0x704c, 2319), (0x704f, 6407), (0x705e, 6408), (0x706b, 2523), (0x706c, 7114), (0x706d, 3275), (0x706f, 1917), (0x7070, 2493),
+    (0x7075, 3080), (0x7076, 4871), (0x7078, 2761), (0x707c, 5113), (0x707e, 4847), (0x707f, 1648), (0x7080, 7079), (0x7085, 6894),
+    (0x7089, 3116), (0x708a, 1807), (0x708e, 4566), (0x7092, 1711), (0x7094, 3683), (0x7095, 2846), (0x7096, 7081), (0x7099, 5024),
+    (0x709c, 7080), (0x709d, 7082), (0x70ab, 7086), (0x70ac, 2795), (0x70ad, 4072), (0x70ae, 3441), (0x70af, 2753), (0x70b1, 7087),
+    (0x70b3, 1597), (0x70b7, 7085), (0x70b8, 4895), (0x70b9, 1946), (0x70bb, 7083), (0x70bc, 3029), (0x70bd, 1758), (0x70c0, 7084),
+    (0x70c1, 3971), (0x70c2, 2948), (0x70c3, 4135), (0x70c8, 3057), (0x70ca, 7089), (0x70d8, 2419), (0x70d9, 2964), (0x70db, 5063),
+    (0x70df, 4555), (0x70e4, 2849), (0x70e6, 2086), (0x70e7, 3812), (0x70e8, 7088), (0x70e9, 2509), (0x70eb, 4085), (0x70ec, 2724),
+    (0x70ed, 3705), (0x70ef, 4332), (0x70f7, 4208), (0x70f9, 3458), (0x70fd, 2140), (0x7109, 4552), (0x710a, 2373), (0x7110, 7090),
+    (0x7113, 7091), (0x7115, 2475), (0x7116, 7092), (0x7118, 7115), (0x7119, 1529), (0x711a, 2124), (0x7126, 2656), (0x712f, 7093),
+    (0x7130, 4581), (0x7131, 7094), (0x7136, 3692), (0x7145, 7098), (0x714a, 7100), (0x714c, 2488), (0x714e, 2606), (0x715c, 7096),
+    (0x715e, 3782), (0x7164, 3221), (0x7166, 7116), (0x7167, 4940), (0x7168, 7097), (0x716e, 5064), (0x7172, 7099), (0x7173, 7095),
+    (0x7178, 7101), (0x717a, 7102), (0x717d, 3790), (0x7184, 4331), (0x718a, 4477), (0x718f, 4523), (0x7194, 3724), (0x7198, 7103),
+    (0x7199, 4314), (0x719f, 3929), (0x71a0, 7107), (0x71a8, 7106), (0x71ac, 1439), (0x71b3, 7104), (0x71b5, 7105), (0x71b9, 7117),
+    (0x71c3, 3693), (0x71ce, 3046), (0x71d4, 7109), (0x71d5, 4575), (0x71e0, 7108), (0x71e5, 4872), (0x71e7, 7110), (0x71ee, 5489),
+    (0x71f9, 7111), (0x7206, 1515), (0x721d, 7112), (0x7228, 7113), (0x722a, 5081), (0x722c, 3415), (0x7230, 6983), (0x7231, 1423),
+    (0x7235, 2809), (0x7236, 2185), (0x7237, 4620), (0x7238, 1463), (0x7239, 1968), (0x723b, 5188), (0x723d, 3959), (0x723f, 6264),
+    (0x7247, 3489), (0x7248, 1479), (0x724c, 3421), (0x724d, 6980), (0x7252, 6981), (0x7256, 6982), (0x7259, 4543), (0x725b, 3386),
+    (0x725d, 6943), (0x725f, 3307), (0x7261, 3310), (0x7262, 2959), (0x7266, 6944), (0x7267, 3322), (0x7269, 4308), (0x726e, 6941),
+    (0x726f, 6945), (0x7272, 3851), (0x7275, 3574), (0x7279, 4097), (0x727a, 4323), (0x727e, 6946), (0x727f, 6947), (0x7280, 4335),
+    (0x7281, 2984), (0x7284, 6948), (0x728a, 2004), (0x728b, 6949), (0x728d, 6950), (0x728f, 6951), (0x7292, 6952), (0x729f, 6942),
+    (0x72ac, 3679), (0x72ad, 6090), (0x72af, 2091), (0x72b0, 6091), (0x72b4, 6092), (0x72b6, 5095), (0x72b7, 6093), (0x72b8, 6094),
+    (0x72b9, 4743), (0x72c1, 6096), (0x72c2, 2900), (0x72c3, 6095), (0x72c4, 1929), (0x72c8, 1526), (0x72cd, 6098), (0x72ce, 6097),
+    (0x72d0, 2441), (0x72d2, 6099), (0x72d7, 2278), (0x72d9, 2774), (0x72de, 3381), (0x72e0, 2410), (0x72e1, 2669), (0x72e8, 6100),
+    (0x72e9, 6102), (0x72ec, 2005), (0x72ed, 4356), (0x72ee, 3861), (0x72ef, 6101), (0x72f0, 4975), (0x72f1, 4791), (0x72f2, 6103),
+    (0x72f3, 6107), (0x72f4, 6104), (0x72f7, 6105), (0x72f8, 2987), (0x72fa, 6109), (0x72fb, 6110), (0x72fc, 2952), (0x7301, 6106),
+    (0x7303, 6108), (0x730a, 6114), (0x730e, 3059), (0x7313, 6112), (0x7315, 6117), (0x7316, 1691), (0x7317, 6111), (0x731b, 3240),
+    (0x731c, 1631), (0x731d, 6116), (0x731e, 6115), (0x7321, 6113), (0x7322, 6118), (0x7325, 6120), (0x7329, 4458), (0x732a, 5058),
+    (0x732b, 3203), (0x732c, 6121), (0x732e, 4378), (0x7331, 6123), (0x7334, 2428), (0x7337, 6831), (0x7338, 6122), (0x7339, 6119),
+    (0x733e, 2454), (0x733f, 4812), (0x734d, 6125), (0x7350, 6124), (0x7352, 6832), (0x7357, 6126), (0x7360, 6127), (0x736c, 6128),
+    (0x736d, 4042), (0x736f, 6129), (0x737e, 6130), (0x7384, 4511), (0x7387, 3143), (0x7389, 4780), (0x738b, 4220), (0x738e, 6643),
+    (0x7391, 6644), (0x7396, 2758), (0x739b, 3175), (0x739f, 6647), (0x73a2, 6646), (0x73a9, 4205), (0x73ab, 3216), (0x73ae, 6645),
+    (0x73af, 2466), (0x73b0, 4377), (0x73b2, 3072), (0x73b3, 6652), (0x73b7, 6651), (0x73ba, 6663), (0x73bb, 1600), (0x73c0, 6653),
+    (0x73c2, 6649), (0x73c8, 6655), (0x73c9, 6654), (0x73ca, 3785), (0x73cd, 4955), (0x73cf, 6648), (0x73d0, 2076), (0x73d1, 6650),
+    (0x73d9, 6657), (0x73de, 6662), (0x73e0, 5054), (0x73e5, 6656), (0x73e7, 6661), (0x73e9, 6660), (0x73ed, 1473), (0x73f2, 6664),
+    (0x7403, 3653), (0x7405, 2950), (0x7406, 2990), (0x7409, 3087), (0x740a, 6659), (0x740f, 6665), (0x7410, 4033), (0x741a, 6675),
+    (0x741b, 6674), (0x7422, 5108), (0x7425, 6669), (0x7426, 6668), (0x7428, 6670), (0x742a, 6666), (0x742c, 6673), (0x742e, 6672),
+    (0x7430, 6671), (0x7433, 3060), (0x7434, 3628), (0x7435, 3476), (0x7436, 3418), (0x743c, 3648), (0x7441, 6676), (0x7455, 6679),
+    (0x7457, 6678), (0x7459, 6680), (0x745a, 2436), (0x745b, 6667), (0x745c, 6677), (0x745e, 3745), (0x745f, 3769), (0x746d, 6682),
+    (0x7470, 2324), (0x7476, 4605), (0x7477, 6681), (0x747e, 6683), (0x7480, 6686), (0x7481, 6687), (0x7483, 3014), (0x7487, 6688),
+    (0x748b, 6689), (0x748e, 6685), (0x7490, 6693), (0x749c, 6684), (0x749e, 6690), (0x74a7, 6694), (0x74a8, 6691), (0x74a9, 6692),
+    (0x74ba, 6696), (0x74d2, 6695), (0x74dc, 2302), (0x74de, 7437), (0x74e0, 7438), (0x74e2, 3493), (0x74e3, 1483), (0x74e4, 3696),
+    (0x74e6, 4198), (0x74ee, 4274), (0x74ef, 6878), (0x74f4, 6879), (0x74f6, 3508), (0x74f7, 1826), (0x74ff, 6880), (0x7504, 4958),
+    (0x750d, 5728), (0x750f, 6881), (0x7511, 6882), (0x7513, 6883), (0x7518, 2203), (0x7519, 5843), (0x751a, 3844), (0x751c, 4121),
+    (0x751f, 3849), (0x7525, 3850), (0x7528, 4734), (0x7529, 3953), (0x752b, 2167), (0x752c, 7439), (0x752d, 1537), (0x752f, 6415),
+    (0x7530, 4120), (0x7531, 4740), (0x7532, 2592), (0x7533, 3833), (0x7535, 1950), (0x7537, 3337), (0x7538, 1952), (0x753a, 7251),
+    (0x753b, 2456), (0x753e, 6641), (0x7540, 7252), (0x7545, 1700), (0x7548, 7255), (0x754b, 7254), (0x754c, 2702), (0x754e, 7253),
+    (0x754f, 4252), (0x7554, 3430), (0x7559, 3091), (0x755a, 5497), (0x755b, 7256), (0x755c, 4500), (0x7565, 3153), (0x7566, 3549),
+    (0x756a, 2079), (0x7572, 7257), (0x7574, 1766), (0x7578, 2534), (0x7579, 7258), (0x757f, 6639), (0x7583, 7259), (0x7586, 2645),
+    (0x758b, 7585), (0x758f, 3925), (0x7591, 4646), (0x7592, 7483), (0x7594, 7484), (0x7596, 7485), (0x7597, 3045), (0x7599, 2238),
+    (0x759a, 2771), (0x759d, 7487), (0x759f, 3400), (0x75a0, 7486), (0x75a1, 4592), (0x75a3, 7489), (0x75a4, 1453), (0x75a5, 2705),
+    (0x75ab, 4668), (0x75ac, 7488), (0x75ae, 1800), (0x75af, 2139), (0x75b0, 7495), (0x75b1, 7494), (0x75b2, 3480), (0x75b3, 7490),
+    (0x75b4, 7491), (0x75b5, 1820), (0x75b8, 7492), (0x75b9, 4965), (0x75bc, 4100), (0x75bd, 2775), (0x75be, 2555), (0x75c2, 7497),
+    (0x75c3, 7496), (0x75c4, 7493), (0x75c5, 1598), (0x75c7, 4983), (0x75c8, 4723), (0x75c9, 2748), (0x75ca, 3677), (0x75cd, 7499),
+    (0x75d2, 4598), (0x75d4, 5025), (0x75d5, 2408), (0x75d6, 7498), (0x75d8, 2000), (0x75db, 4155), (0x75de, 3483), (0x75e2, 3008),
+    (0x75e3, 7500), (0x75e4, 7503), (0x75e6, 7502), (0x75e7, 7505), (0x75e8, 7501), (0x75ea, 2473), (0x75eb, 7504), (0x75f0, 4062),
+    (0x75f1, 7507), (0x75f4, 1744), (0x75f9, 1555), (0x75fc, 7508), (0x75ff, 7509), (0x7600, 7511), (0x7601, 1850), (0x7603, 7506),
+    (0x7605, 7512), (0x760a, 7515), (0x760c, 7513), (0x7610, 7510), (0x7615, 7518), (0x7617, 7514), (0x7618, 7517), (0x7619, 7519),
+    (0x761b, 7520), (0x761f, 4262), (0x7620, 7523), (0x7622, 7522), (0x7624, 3093), (0x7625, 7516), (0x7626, 3914), (0x7629, 1866),
+    (0x762a, 1584), (0x762b, 4058), (0x762d, 7525), (0x7630, 7526), (0x7633, 7531), (0x7634, 4933), (0x7635, 7528), (0x7638, 3684),
+    (0x763c, 7521), (0x763e, 7530), (0x763f, 7527), (0x7640, 7524), (0x7643, 7529), (0x764c, 1418), (0x764d, 7532), (0x7654, 7534),
+    (0x7656, 7536), (0x765c, 7535), (0x765e, 7533), (0x7663, 4513), (0x766b, 7537), (0x766f, 7538), (0x7678, 2334), (0x767b, 1918),
+    (0x767d, 1464), (0x767e, 1466), (0x7682, 4870), (0x7684, 1915), (0x7686, 2683), (0x7687, 2485), (0x7688, 7432), (0x768b, 2223),
+    (0x768e, 7433), (0x7691, 1417), (0x7693, 7434), (0x7696, 4213), (0x7699, 7435), (0x76a4, 7436), (0x76ae, 3481), (0x76b1, 5050),
+    (0x76b2, 7587), (0x76b4, 7588), (0x76bf, 3278), (0x76c2, 4758), (0x76c5, 5030), (0x76c6, 3455), (0x76c8, 4714), (0x76ca, 4675),
+    (0x76cd, 7270), (0x76ce, 1436), (0x76cf, 4908), (0x76d0, 4557), (0x76d1, 2601), (0x76d2, 2397), (0x76d4, 2907), (0x76d6, 2200),
+    (0x76d7, 1912), (0x76d8, 3427), (0x76db, 3855), (0x76df, 3238), (0x76e5, 7271), (0x76ee, 3320), (0x76ef, 1975), (0x76f1, 7219),
+    (0x76f2, 3199), (0x76f4, 4998), (0x76f8, 4387), (0x76f9, 7222), (0x76fc, 3429), (0x76fe, 2033), (0x7701, 3854), (0x7704, 7220),
+    (0x7707, 7223), (0x7708, 7224), (0x7709, 3223), (0x770b, 2839), (0x770d, 7221), (0x7719, 7227), (0x771a, 7225), (0x771f, 4957),
+    (0x7720, 3258), (0x7722, 7226), (0x7726, 7229), (0x7728, 4890), (0x7729, 4514), (0x772d, 7228), (0x772f, 3243), (0x7735, 7230),
+    (0x7736, 2903), (0x7737, 2801), (0x7738, 7231), (0x773a, 4128), (0x773c, 4570), (0x7740, 5112), (0x7741, 4973), (0x7743, 7235),
+    (0x7747, 7234), (0x7750, 7232), (0x7751, 7233), (0x775a, 7236), (0x775b, 2731), (0x7761, 3962), (0x7762, 7238), (0x7763, 2002),
+    (0x7765, 7239), (0x7766, 3321), (0x7768, 7237), (0x776b, 2693), (0x776c, 1636), (0x7779, 2008), (0x777d, 7242), (0x777e, 5195),
+    (0x777f, 7240), (0x7780, 7243), (0x7784, 3268), (0x7785, 1773), (0x778c, 7244), (0x778d, 7241), (0x778e, 4348), (0x7791, 7245),
+    (0x7792, 3188), (0x779f, 7246), (0x77a0, 7247), (0x77a2, 5744), (0x77a5, 3496), (0x77a7, 3609), (0x77a9, 5066), (0x77aa, 1920),
+    (0x77ac, 3965), (0x77b0, 7248), (0x77b3, 4146), (0x77b5, 7249), (0x77bb, 4903), (0x77bd, 7250), (0x77bf, 8025), (0x77cd, 5490),
+    (0x77d7, 1788), (0x77db, 3207), (0x77dc, 7589), (0x77e2, 3877), (0x77e3, 4656), (0x77e5, 4991), (0x77e7, 7413), (0x77e9, 2781),
+    (0x77eb, 2666), (0x77ec, 7414), (0x77ed, 2017), (0x77ee, 1420), (0x77f3, 3868), (0x77f6, 7165), (0x77f8, 7166), (0x77fd, 4318),
+    (0x77fe, 2082), (0x77ff, 2902), (0x7800, 7167), (0x7801, 3176), (0x7802, 3775), (0x7809, 7168), (0x780c, 3563), (0x780d, 2838),
+    (0x7811, 7171), (0x7812, 3471), (0x7814, 4559), (0x7816, 5084), (0x7817, 7169), (0x7818, 7170), (0x781a, 4577), (0x781c, 7174),
+    (0x781d, 7175), (0x781f, 7179), (0x7823, 7183), (0x7825, 7181), (0x7826, 7189), (0x7827, 4959), (0x7829, 7184), (0x782c, 7182),
+    (0x782d, 7173), (0x7830, 3456), (0x7834, 3515), (0x7837, 3832), (0x7838, 4843), (0x7839, 7176), (0x783a, 7177), (0x783b, 7178),
+    (0x783c, 7180), (0x783e, 3002), (0x7840, 1786), (0x7845, 2327), (0x7847, 7191), (0x784c, 7192), (0x784e, 7185), (0x7850, 7190),
+    (0x7852, 4317), (0x7855, 3969), (0x7856, 7187), (0x7857, 7188), (0x785d, 4408), (0x786a, 7193), (0x786b, 3089), (0x786c, 4717),
+    (0x786d, 7186), (0x786e, 3688), (0x7877, 2616), (0x787c, 3463), (0x7887, 7197), (0x7889, 1958), (0x788c, 3122), (0x788d, 1422),
+    (0x788e, 4020), (0x7891, 1517), (0x7893, 7195), (0x7897, 4210), (0x7898, 1945), (0x789a, 7196), (0x789b, 7194), (0x789c, 7198),
+    (0x789f, 1969), (0x78a1, 7199), (0x78a3, 7200), (0x78a5, 7203), (0x78a7, 1547), (0x78b0, 3469), (0x78b1, 2615), (0x78b2, 7201),
+    (0x78b3, 4069), (0x78b4, 1671), (0x78b9, 7202), (0x78be, 3364), (0x78c1, 1822), (0x78c5, 1494), (0x78c9, 7206), (0x78ca, 2971),
+    (0x78cb, 1857), (0x78d0, 3428), (0x78d4, 7204), (0x78d5, 2855), (0x78d9, 7205), (0x78e8, 3294), (0x78ec, 7207), (0x78f2, 7208),
+    (0x78f4, 7210), (0x78f7, 3062), (0x78fa, 2482), (0x7901, 2655), (0x7905, 7209), (0x7913, 7211), (0x791e, 7213), (0x7924, 7212),
+    (0x7934, 7214), (0x793a, 3883), (0x793b, 7123), (0x793c, 2994), (0x793e, 3830), (0x7940, 7124), (0x7941, 3555), (0x7946, 7125),
+    (0x7948, 3554), (0x7949, 7126), (0x7953, 7129), (0x7956, 5145), (0x7957, 7132), (0x795a, 7130), (0x795b, 7127), (0x795c, 7128),
+    (0x795d, 5078), (0x795e, 3840), (0x795f, 4025), (0x7960, 7133), (0x7962, 7131), (0x7965, 4396), (0x7967, 7135), (0x7968, 3494),
+    (0x796d, 2569), (0x796f, 7134), (0x7977, 1906), (0x7978, 2529), (0x797a, 7136), (0x7980, 5350), (0x7981, 2722), (0x7984, 3128),
+    (0x7985, 7137), (0x798a, 7138), (0x798f, 2164), (0x799a, 7139), (0x79a7, 7140), (0x79b3, 7141), (0x79b9, 4776), (0x79ba, 5183),
+    (0x79bb, 2988), (0x79bd, 3632), (0x79be, 2393), (0x79c0, 4484), (0x79c1, 3976), (0x79c3, 4161), (0x79c6, 2210), (0x79c9, 1595),
+    (0x79cb, 3650), (0x79cd, 5035), (0x79d1, 2857), (0x79d2, 3270), (0x79d5, 7416), (0x79d8, 3251), (0x79df, 5141), (0x79e3, 7418),
+    (0x79e4, 1742), (0x79e6, 3627), (0x79e7, 4588), (0x79e9, 5021), (0x79eb, 7419), (0x79ed, 7417), (0x79ef, 2536), (0x79f0, 1729),
+    (0x79f8, 2684), (0x79fb, 4643), (0x79fd, 2507), (0x7a00, 4324), (0x7a02, 7423), (0x7a03, 7422), (0x7a06, 7420), (0x7a0b, 1735),
+    (0x7a0d, 3811), (0x7a0e, 3963), (0x7a14, 7425), (0x7a17, 1471), (0x7a1a, 5022), (0x7a1e, 7424), (0x7a20, 1768), (0x7a23, 8042),
+    (0x7a33, 4269), (0x7a37, 7427), (0x7a39, 7426), (0x7a3b, 1909), (0x7a3c, 2595), (0x7a3d, 2535), (0x7a3f, 2230), (0x7a46, 3323),
+    (0x7a51, 7428), (0x7a57, 4022), (0x7a70, 7431), (0x7a74, 4519), (0x7a76, 2756), (0x7a77, 3649), (0x7a78, 7541), (0x7a79, 7542),
+    (0x7a7a, 2872), (0x7a7f, 1794), (0x7a80, 7543), (0x7a81, 4162), (0x7a83, 3623), (0x7a84, 4900), (0x7a86, 7544), (0x7a88, 7545),
+    (0x7a8d, 3618), (0x7a91, 4609), (0x7a92, 5028), (0x7a95, 7546), (0x7a96, 2680), (0x7a97, 1801), (0x7a98, 2754), (0x7a9c, 1845),
+    (0x7a9d, 4278), (0x7a9f, 2882), (0x7aa0, 7548), (0x7aa5, 2909), (0x7aa6, 7547), (0x7aa8, 7550), (0x7aac, 7549), (0x7aad, 7551),
+    (0x7ab3, 7552), (0x7abf, 3101), (0x7acb, 3009), (0x7ad6, 3943), (0x7ad9, 4917), (0x7ade, 2751), (0x7adf, 2750), (0x7ae0, 4921),
+    (0x7ae3, 2821), (0x7ae5, 4150), (0x7ae6, 7540), (0x7aed, 2694), (0x7aef, 2016), (0x7af9, 5062), (0x7afa, 7742), (0x7afd, 7743),
+    (0x7aff, 2206), (0x7b03, 7745), (0x7b04, 7746), (0x7b06, 1451), (0x7b08, 7744), (0x7b0a, 7748), (0x7b0b, 4028), (0x7b0f, 7750),
+    (0x7b11, 4423), (0x7b14, 1545), (0x7b15, 7747), (0x7b19, 7754), (0x7b1b, 1928), (0x7b1e, 7762), (0x7b20, 7757), (0x7b24, 7759),
+    (0x7b25, 7758), (0x7b26, 2158), (0x7b28, 1534), (0x7b2a, 7753), (0x7b2b, 7749), (0x7b2c, 1937), (0x7b2e, 7755), (0x7b31, 7756),
+    (0x7b33, 7760), (0x7b38, 7752), (0x7b3a, 2604), (0x7b3c, 3100), (0x7b3e, 7761), (0x7b45, 7765), (0x7b47, 7751), (0x7b49, 1919),
+    (0x7b4b, 2709), (0x7b4c, 7767), (0x7b4f, 2071), (0x7b50, 2899), (0x7b51, 5075), (0x7b52, 4153), (0x7b54, 1865), (0x7b56, 1660),
+    (0x7b58, 7763), (0x7b5a, 7764), (0x7b5b, 3783), (0x7b5d, 7768), (0x7b60, 7769), (0x7b62, 7772), (0x7b6e, 7770), (0x7b71, 7774),
+    (0x7b72, 7773), (0x7b75, 7766), (0x7b77, 2893), (0x7b79, 1770), (0x7b7b, 7771), (0x7b7e, 3580), (0x7b80, 2619), (0x7b85, 7782),
+    (0x7b8d, 2286), (0x7b90, 7775), (0x7b94, 1610), (0x7b95, 2537), (0x7b97, 4014), (0x7b9c, 7784), (0x7b9d, 7780), (0x7ba1, 2315),
+    (0x7ba2, 7785), (0x7ba6, 7776), (0x7ba7, 7777), (0x7ba8, 7781), (0x7ba9, 3166), (0x7baa, 7783), (0x7bab, 7786), (0x7bac, 7779),
+    (0x7bad, 2630), (0x7bb1, 4391), (0x7bb4, 7787), (0x7bb8, 7778), (0x7bc1, 7789), (0x7bc6, 5088), (0x7bc7, 3487), (0x7bcc, 7790),
+    (0x7bd1, 7788), (0x7bd3, 3109), (0x7bd9, 2222), (0x7bda, 7792), (0x7bdd, 7791), (0x7be1, 1844), (0x7be5, 7793), (0x7be6, 7794),
+    (0x7bea, 7795), (0x7bee, 2939), (0x7bf1, 2986), (0x7bf7, 3464), (0x7bfc, 7798), (0x7bfe, 7797), (0x7c07, 1841), (0x7c0b, 7801),
+    (0x7c0c, 7796), (0x7c0f, 7799), (0x7c16, 7800), (0x7c1f, 7802), (0x7c26, 7804), (0x7c27, 2484), (0x7c2a, 7803), (0x7c38, 7805),
+    (0x7c3f, 1627), (0x7c40, 7807), (0x7c41, 7806), (0x7c4d, 2551), (0x7c73, 3250), (0x7c74, 5328), (0x7c7b, 2977), (0x7c7c, 7845),
+    (0x7c7d, 5124), (0x7c89, 2126), (0x7c91, 7847), (0x7c92, 3010), (0x7c95, 3518), (0x7c97, 1839), (0x7c98, 4906), (0x7c9c, 7849),
+    (0x7c9d, 7848), (0x7c9e, 7850), (0x7c9f, 4005), (0x7ca2, 7851), (0x7ca4, 4826), (0x7ca5, 5045), (0x7caa, 2131), (0x7cae, 3031),
+    (0x7cb1, 3034), (0x7cb2, 7852), (0x7cb3, 2737), (0x7cb9, 1851), (0x7cbc, 7853), (0x7cbd, 7854), (0x7cbe, 2736), (0x7cc1, 7855),
+    (0x7cc5, 7860), (0x7cc7, 7856), (0x7cc8, 7859), (0x7cca, 2442), (0x7ccc, 7857), (0x7ccd, 7858), (0x7cd5, 2227), (0x7cd6, 4080),
+    (0x7cd7, 7861), (0x7cd9, 1655), (0x7cdc, 3246), (0x7cdf, 4860), (0x7ce0, 2842), (0x7ce8, 7862), (0x7cef, 3403), (0x7cf8, 7874),
+    (0x7cfb, 4344), (0x7d0a, 4270), (0x7d20, 4003), (0x7d22, 4034), (0x7d27, 2715), (0x7d2b, 5122), (0x7d2f, 2972), (0x7d6e, 4502),
+    (0x7d77, 7875), (0x7da6, 7876), (0x7dae, 7877), (0x7e3b, 8150), (0x7e41, 2084), (0x7e47, 7878), (0x7e82, 5150), (0x7e9b, 7879),
+    (0x7e9f, 6574), (0x7ea0, 2757), (0x7ea1, 6575), (0x7ea2, 2425), (0x7ea3, 6576), (0x7ea4, 4366), (0x7ea5, 6577), (0x7ea6, 4821),
+    (0x7ea7, 2559), (0x7ea8, 6578), (0x7ea9, 6579), (0x7eaa, 2582), (0x7eab, 3715), (0x7eac, 4248), (0x7ead, 6580), (0x7eaf, 1816),
+    (0x7eb0, 6581), (0x7eb1, 3779), (0x7eb2, 2218), (0x7eb3, 3330), (0x7eb5, 5136), (0x7eb6, 3159), (0x7eb7, 2122), (0x7eb8, 5010),
+    (0x7eb9, 4267), (0x7eba, 2103), (0x7ebd, 3389), (0x7ebe, 6582), (0x7ebf, 4386), (0x7ec0, 6583), (0x7ec1, 6584), (0x7ec2, 6585),
+    (0x7ec3, 3030), (0x7ec4, 5148), (0x7ec5, 3839), (0x7ec6, 4347), (0x7ec7, 4996), (0x7ec8, 5034), (0x7ec9, 6586), (0x7eca, 1486),
+    (0x7ecb, 6587), (0x7ecc, 6588), (0x7ecd, 3819), (0x7ece, 4684), (0x7ecf, 2738), (0x7ed0, 6589), (0x7ed1, 1492), (0x7ed2, 3727),
+    (0x7ed3, 2696), (0x7ed4, 6590), (0x7ed5, 3703), (0x7ed7, 6591), (0x7ed8, 2513), (0x7ed9, 2249), (0x7eda, 4515), (0x7edb, 6592),
+    (0x7edc, 3172), (0x7edd, 2813), (0x7ede, 2673), (0x7edf, 4154), (0x7ee0, 6593), (0x7ee1, 6594), (0x7ee2, 2803), (0x7ee3, 4486),
+    (0x7ee5, 4018), (0x7ee6, 4089), (0x7ee7, 2581), (0x7ee8, 6595), (0x7ee9, 2545), (0x7eea, 4504), (0x7eeb, 6596), (0x7eed, 4505),
+    (0x7eee, 6597), (0x7eef, 6598), (0x7ef0, 1819), (0x7ef1, 6599), (0x7ef2, 6600), (0x7ef3, 3853), (0x7ef4, 4241), (0x7ef5, 3259),
+    (0x7ef6, 6602), (0x7ef7, 1536), (0x7ef8, 1772), (0x7efa, 6603), (0x7efb, 6604), (0x7efc, 5134), (0x7efd, 4919), (0x7efe, 6605),
+    (0x7eff, 3145), (0x7f00, 5101), (0x7f01, 6606), (0x7f02, 6607), (0x7f03, 6608), (0x7f04, 2611), (0x7f05, 3264), (0x7f06, 2947),
+    (0x7f07, 6609), (0x7f08, 6610), (0x7f09, 2546), (0x7f0b, 6611), (0x7f0c, 6612), (0x7f0d, 6601), (0x7f0e, 2021), (0x7f0f, 6613),
+    (0x7f11, 6614), (0x7f12, 6615), (0x7f13, 2469), (0x7f14, 1941), (0x7f15, 3139), (0x7f16, 1567), (0x7f17, 6616), (0x7f18, 4814),
+    (0x7f19, 6617), (0x7f1a, 2192), (0x7f1b, 6619), (0x7f1c, 6618), (0x7f1d, 2143), (0x7f1f, 6620), (0x7f20, 1685), (0x7f21, 6621),
+    (0x7f22, 6622), (0x7f23, 6623), (0x7f24, 6624), (0x7f25, 6625), (0x7f26, 6626), (0x7f27, 6627), (0x7f28, 4706), (0x7f29, 4032),
+    (0x7f2a, 6628), (0x7f2b, 6629), (0x7f2c, 6630), (0x7f2d, 6631), (0x7f2e, 3800), (0x7f2f, 6632), (0x7f30, 6633), (0x7f31, 6634),
+    (0x7f32, 6635), (0x7f33, 6636), (0x7f34, 2672), (0x7f35, 6637), (0x7f36, 7737), (0x7f38, 2216), (0x7f3a, 3682), (0x7f42, 7738),
+    (0x7f44, 7739), (0x7f45, 7740), (0x7f50, 2317), (0x7f51, 4223), (0x7f54, 5241), (0x7f55, 2366), (0x7f57, 3163), (0x7f58, 7260),
+    (0x7f5a, 2070), (0x7f5f, 7262), (0x7f61, 7261), (0x7f62, 1462), (0x7f68, 7264), (0x7f69, 4941), (0x7f6a, 5154), (0x7f6e, 5016),
+    (0x7f71, 7266), (0x7f72, 3933), (0x7f74, 7265), (0x7f79, 7267), (0x7f7e, 7269), (0x7f81, 7268), (0x7f8a, 4593), (0x7f8c, 3599),
+    (0x7f8e, 3227), (0x7f94, 2226), (0x7f9a, 3078), (0x7f9d, 7839), (0x7f9e, 4480), (0x7f9f, 7840), (0x7fa1, 4382), (0x7fa4, 3691),
+    (0x7fa7, 7841), (0x7faf, 7842), (0x7fb0, 7843), (0x7fb2, 7844), (0x7fb8, 5353), (0x7fb9, 2255), (0x7fbc, 6472), (0x7fbd, 4779),
+    (0x7fbf, 7865), (0x7fc1, 4273), (0x7fc5, 1756), (0x7fca, 7539), (0x7fcc, 4683), (0x7fce, 7866), (0x7fd4, 4395), (0x7fd5, 7867),
+    (0x7fd8, 3615), (0x7fdf, 1931), (0x7fe0, 1853), (0x7fe1, 7869), (0x7fe5, 7868), (0x7fe6, 7870), (0x7fe9, 7871), (0x7fee, 7872),
+    (0x7ff0, 2367), (0x7ff1, 1440), (0x7ff3, 7873), (0x7ffb, 2080), (0x7ffc, 4682), (0x8000, 4616), (0x8001, 2960), (0x8003, 2847),
+    (0x8004, 6958), (0x8005, 4950), (0x8006, 6901), (0x800b, 7602), (0x800c, 2061), (0x800d, 3950), (0x8010, 3334), (0x8012, 7590),
+    (0x8014, 7591), (0x8015, 2252), (0x8016, 7592), (0x8017, 2385), (0x8018, 4830), (0x8019, 1459), (0x801c, 7593), (0x8020, 7594),
+    (0x8022, 7595), (0x8025, 7596), (0x8026, 7597), (0x8027, 7598), (0x8028, 7600), (0x8029, 7599), (0x802a, 3436), (0x8031, 7601),
+    (0x8033, 2063), (0x8035, 7603), (0x8036, 4619), (0x8037, 5772), (0x8038, 3989), (0x803b, 1751), (0x803d, 1881), (0x803f, 2257),
+    (0x8042, 3373), (0x8043, 7604), (0x8046, 7605), (0x804a, 3043), (0x804b, 3098), (0x804c, 4997), (0x804d, 7606), (0x8052, 7607),
+    (0x8054, 3017), (0x8058, 3501), (0x805a, 2784), (0x8069, 7608), (0x806a, 1832), (0x8071, 7609), (0x807f, 7161), (0x8080, 7160),
+    (0x8083, 4011), (0x8084, 4667), (0x8086, 3980), (0x8087, 4943), (0x8089, 3731), (0x808b, 2976), (0x808c, 2538), (0x8093, 6988),
+    (0x8096, 4421), (0x8098, 5047), (0x809a, 2012), (0x809b, 2217), (0x809c, 6987), (0x809d, 2207), (0x809f, 6986), (0x80a0, 1697),
+    (0x80a1, 2296), (0x80a2, 4992), (0x80a4, 2151), (0x80a5, 2109), (0x80a9, 2608), (0x80aa, 2097), (0x80ab, 6993), (0x80ad, 6994),
+    (0x80ae, 1434), (0x80af, 2866), (0x80b1, 6992), (0x80b2, 4792), (0x80b4, 6995), (0x80b7, 6996), (0x80ba, 2113), (0x80bc, 6989),
+    (0x80bd, 6991), (0x80be, 3845), (0x80bf, 5036), (0x80c0, 4932), (0x80c1, 4435), (0x80c2, 7002), (0x80c3, 4253), (0x80c4, 7003),
+    (0x80c6, 1887), (0x80cc, 1522), (0x80cd, 7005), (0x80ce, 4046), (0x80d6, 3437), (0x80d7, 7006), (0x80d9, 7004), (0x80da, 3446),
+    (0x80db, 7001), (0x80dc, 3857), (0x80dd, 7008), (0x80de, 1500), (0x80e1, 2439), (0x80e4, 5192), (0x80e5, 7586), (0x80e7, 6997),
+    (0x80e8, 6998), (0x80e9, 6999), (0x80ea, 7000), (0x80eb, 7009), (0x80ec, 6517), (0x80ed, 7012), (0x80ef, 2891), (0x80f0, 4645),
+    (0x80f1, 7010), (0x80f2, 7015), (0x80f3, 2237), (0x80f4, 7011), (0x80f6, 2657), (0x80f8, 4473), (0x80fa, 1432), (0x80fc, 7016),
+    (0x80fd, 3349), (0x8102, 4993), (0x8106, 1849), (0x8109, 3187), (0x810a, 2562), (0x810d, 7013), (0x810e, 7014), (0x810f, 4857),
+    (0x8110, 3551), (0x8111, 3341), (0x8112, 7018), (0x8113, 3390), (0x8114, 5348), (0x8116, 1614), (0x8118, 7023), (0x811a, 2668),
+    (0x811e, 7021), (0x812c, 7022), (0x812f, 2173), (0x8131, 4184), (0x8132, 7024), (0x8136, 7020), (0x8138, 3026), (0x813e, 3479),
+    (0x8146, 4124), (0x8148, 7025), (0x814a, 2929), (0x814b, 4629), (0x814c, 7026), (0x8150, 2176), (0x8151, 2174), (0x8153, 7027),
+    (0x8154, 3598), (0x8155, 4218), (0x8159, 7029), (0x815a, 7030), (0x8160, 7032), (0x8165, 4457), (0x8167, 7037), (0x8169, 7033),
+    (0x816d, 7036), (0x816e, 3754), (0x8170, 4603), (0x8171, 7031), (0x8174, 7028), (0x8179, 2186), (0x817a, 4380), (0x817b, 3358),
+    (0x817c, 7034), (0x817d, 7035), (0x817e, 4099), (0x817f, 4175), (0x8180, 1491), (0x8182, 7041), (0x8188, 7040), (0x818a, 1615),
+    (0x818f, 2225), (0x8191, 7042), (0x8198, 1579), (0x819b, 4078), (0x819c, 3293), (0x819d, 4328), (0x81a3, 7044), (0x81a6, 7051),
+    (0x81a8, 3465), (0x81aa, 7045), (0x81b3, 3796), (0x81ba, 6172), (0x81bb, 7049), (0x81c0, 4181), (0x81c1, 7050), (0x81c2, 1562),
+    (0x81c3, 4722), (0x81c6, 4665), (0x81ca, 7048), (0x81cc, 7046), (0x81e3, 1719), (0x81e7, 6877), (0x81ea, 5127), (0x81ec, 7812),
+    (0x81ed, 1775), (0x81f3, 5014), (0x81f4, 5015), (0x81fb, 4960), (0x81fc, 2767), (0x81fe, 7808), (0x8200, 4613), (0x8201, 7809),
+    (0x8202, 7810), (0x8204, 7811), (0x8205, 2768), (0x8206, 4762), (0x820c, 3823), (0x820d, 3824), (0x8210, 7741), (0x8212, 3923),
+    (0x8214, 4123), (0x821b, 6131), (0x821c, 3967), (0x821e, 4301), (0x821f, 5040), (0x8221, 7814), (0x8222, 7815), (0x8223, 7816),
+    (0x8228, 7819), (0x822a, 2378), (0x822b, 7820), (0x822c, 1476), (0x822d, 7817), (0x822f, 7818), (0x8230, 2633), (0x8231, 1650),
+    (0x8233, 7823), (0x8234, 7824), (0x8235, 2043), (0x8236, 1613), (0x8237, 4370), (0x8238, 7821), (0x8239, 1797), (0x823b, 7822),
+    (0x823e, 7825), (0x8244, 7826), (0x8247, 4142), (0x8249, 7827), (0x824b, 7828), (0x824f, 7829), (0x8258, 3997), (0x825a, 7830),
+    (0x825f, 7831), (0x8268, 7832), (0x826e, 7863), (0x826f, 3035), (0x8270, 2609), (0x8272, 3770), (0x8273, 4573), (0x8274, 6476),
+    (0x8279, 5564), (0x827a, 4658), (0x827d, 5565), (0x827e, 1421), (0x827f, 5566), (0x8282, 2689), (0x8284, 5570), (0x8288, 5203),
+    (0x828a, 5568), (0x828b, 4782), (0x828d, 3813), (0x828e, 5571), (0x828f, 5567), (0x8291, 5572), (0x8292, 3197), (0x8297, 5573),
+    (0x8298, 5582), (0x8299, 5574), (0x829c, 4292), (0x829d, 4986), (0x829f, 5592), (0x82a1, 5590), (0x82a4, 5595), (0x82a5, 2701),
+    (0x82a6, 3112), (0x82a8, 5569), (0x82a9, 5588), (0x82aa, 5591), (0x82ab, 5575), (0x82ac, 2117), (0x82ad, 1446), (0x82ae, 5584),
+    (0x82af, 4447), (0x82b0, 5578), (0x82b1, 2451), (0x82b3, 2095), (0x82b4, 5589), (0x82b7, 5583), (0x82b8, 5576), (0x82b9, 3630),
+    (0x82bd, 4542), (0x82be, 5577), (0x82c1, 5587), (0x82c4, 5593), (0x82c7, 4242), (0x82c8, 5579), (0x82ca, 5580), (0x82cb, 5585),
+    (0x82cc, 5586), (0x82cd, 1649), (0x82ce, 5594), (0x82cf, 4000), (0x82d1, 4816), (0x82d2, 5604), (0x82d3, 5608), (0x82d4, 4047),
+    (0x82d5, 5615), (0x82d7, 3266), (0x82d8, 5605), (0x82db, 2852), (0x82dc, 5602), (0x82de, 1499), (0x82df, 2277), (0x82e0, 5614),
+    (0x82e1, 5596), (0x82e3, 5581), (0x82e4, 5599), (0x82e5, 3749), (0x82e6, 2883), (0x82eb, 3786), (0x82ef, 1532), (0x82f1, 4701),
+    (0x82f4, 5603), (0x82f7, 5598), (0x82f9, 3504), (0x82fb, 5607), (0x8301, 5109), (0x8302, 3210), (0x8303, 2089), (0x8304, 3620),
+    (0x8305, 3204), (0x8306, 5611), (0x8307, 5601), (0x8308, 5620), (0x8309, 5597), (0x830c, 5606), (0x830e, 2730), (0x830f, 5600),
+    (0x8311, 5609), (0x8314, 5612), (0x8315, 5613), (0x8317, 5633), (0x831a, 5610), (0x831b, 5641), (0x831c, 5616), (0x8327, 2612),
+    (0x8328, 1821), (0x832b, 3198), (0x832c, 1668), (0x832d, 5635), (0x832f, 5627), (0x8331, 5624), (0x8333, 5637), (0x8334, 5623),
+    (0x8335, 4685), (0x8336, 1669), (0x8338, 3720), (0x8339, 3732), (0x833a, 5636), (0x833c, 5622), (0x8340, 5632), (0x8343, 5630),
+    (0x8346, 2728), (0x8347, 5629), (0x8349, 1658), (0x834f, 5628), (0x8350, 2623), (0x8351, 5617), (0x8352, 2479), (0x8354, 2996),
+    (0x835a, 2589), (0x835b, 5618), (0x835c, 5619), (0x835e, 5626), (0x835f, 5631), (0x8360, 5634), (0x8361, 1899), (0x8363, 3722),
+    (0x8364, 2514), (0x8365, 5639), (0x8366, 5638), (0x8367, 4710), (0x8368, 5640), (0x8369, 5642), (0x836a, 5644), (0x836b, 4686),
+    (0x836c, 5643), (0x836d, 5645), (0x836e, 5646), (0x836f, 4614), (0x8377, 2390), (0x8378, 5648), (0x837b, 5661), (0x837c, 5656),
+    (0x837d, 5659), (0x8385, 5655), (0x8386, 3523), (0x8389, 2995), (0x838e, 3774), (0x8392, 5621), (0x8393, 5653), (0x8398, 5662),
+    (0x839b, 5625), (0x839c, 5654), (0x839e, 5663), (0x83a0, 5651), (0x83a8, 5664), (0x83a9, 5658), (0x83aa, 5652), (0x83ab, 3299),
+    (0x83b0, 5647), (0x83b1, 2932), (0x83b2, 3018), (0x83b3, 5649), (0x83b4, 5650), (0x83b6, 5657), (0x83b7, 2524), (0x83b8, 5660),
+    (0x83b9, 4707), (0x83ba, 5665), (0x83bc, 5666), (0x83bd, 3202), (0x83c0, 5689), (0x83c1, 5667), (0x83c5, 5688), (0x83c7, 2284),
+    (0x83ca, 2778), (0x83cc, 2815), (0x83cf, 2391), (0x83d4, 5681), (0x83d6, 5676), (0x83d8, 5670), (0x83dc, 1640), (0x83dd, 5674),
+    (0x83df, 5682), (0x83e0, 1601), (0x83e1, 5692), (0x83e5, 5669), (0x83e9, 3525), (0x83ea, 5687), (0x83f0, 5691), (0x83f1, 3073),
+    (0x83f2, 2105), (0x83f8, 5685), (0x83f9, 5686), (0x83fd, 5675), (0x8401, 5668), (0x8403, 5684), (0x8404, 4090), (0x8406, 5680),
+    (0x840b, 5673), (0x840c, 3235), (0x840d, 3505), (0x840e, 4243), (0x840f, 5683), (0x8411, 5679), (0x8418, 5672), (0x841c, 5677),
+    (0x841d, 3161), (0x8424, 4708), (0x8425, 4709), (0x8426, 5690), (0x8427, 4407), (0x8428, 3753), (0x8431, 5709), (0x8438, 5678),
+    (0x843c, 5703), (0x843d, 3169), (0x8446, 5704), (0x8451, 5694), (0x8457, 5069), (0x8459, 5696), (0x845a, 5695), (0x845b, 2241),
+    (0x845c, 5693), (0x8461, 3524), (0x8463, 1986), (0x8469, 5705), (0x846b, 2438), (0x846c, 4858), (0x846d, 5710), (0x8471, 1833),
+    (0x8473, 5697), (0x8475, 2910), (0x8476, 5706), (0x8478, 5702), (0x847a, 5700), (0x8482, 1936), (0x8487, 5698), (0x8488, 5699),
+    (0x8489, 5701), (0x848b, 2646), (0x848c, 5707), (0x848e, 5708), (0x8497, 5724), (0x8499, 3236), (0x849c, 4013), (0x84a1, 5721),
+    (0x84af, 5234), (0x84b2, 3526), (0x84b4, 5723), (0x84b8, 4971), (0x84b9, 5722), (0x84ba, 5719), (0x84bd, 5715), (0x84bf, 5718),
+    (0x84c1, 5711), (0x84c4, 4495), (0x84c9, 3721), (0x84ca, 5717), (0x84cd, 5712), (0x84d0, 5713), (0x84d1, 4029), (0x84d3, 5716),
+    (0x84d6, 1548), (0x84dd, 2935), (0x84df, 2564), (0x84e0, 5720), (0x84e3, 5726), (0x84e5, 5725), (0x84e6, 5714), (0x84ec, 3461),
+    (0x84f0, 5730), (0x84fc, 5737), (0x84ff, 5736), (0x850c, 5727), (0x8511, 3274), (0x8513, 3192), (0x8517, 4952), (0x851a, 4250),
+    (0x851f, 5732), (0x8521, 1641), (0x852b, 3361), (0x852c, 3916), (0x8537, 3601), (0x8538, 5729), (0x8539, 5731), (0x853a, 5733),
+    (0x853b, 5735), (0x853c, 1419), (0x853d, 1549), (0x8543, 5745), (0x8548, 5739), (0x8549, 2653), (0x854a, 3744), (0x8556, 5734),
+    (0x8559, 5738), (0x855e, 5742), (0x8564, 5741), (0x8568, 5740), (0x8572, 5746), (0x8574, 4837), (0x8579, 5752), (0x857a, 5743),
+    (0x857b, 5747), (0x857e, 2970), (0x8584, 1504), (0x8585, 5755), (0x8587, 5750), (0x858f, 5751), (0x859b, 4517), (0x859c, 5754),
+    (0x85a4, 5748), (0x85a8, 5749), (0x85aa, 4446), (0x85ae, 5753), (0x85af, 3930), (0x85b0, 5758), (0x85b7, 5757), (0x85b9, 5756),
+    (0x85c1, 5760), (0x85c9, 2700), (0x85cf, 1653), (0x85d0, 3269), (0x85d3, 5759), (0x85d5, 3409), (0x85dc, 5761), (0x85e4, 4098),
+    (0x85e9, 2077), (0x85fb, 4862), (0x85ff, 5762), (0x8605, 5764), (0x8611, 3291), (0x8616, 5766), (0x8627, 5763), (0x8629, 5765),
+    (0x8638, 4913), (0x863c, 5767), (0x864d, 7627), (0x864e, 2445), (0x864f, 3119), (0x8650, 3399), (0x8651, 3140), (0x8654, 7628),
+    (0x865a, 4490), (0x865e, 4760), (0x8662, 6984), (0x866b, 1761), (0x866c, 7629), (0x866e, 7630), (0x8671, 3866), (0x8679, 2420),
+    (0x867a, 7632), (0x867b, 7634), (0x867c, 7633), (0x867d, 4015), (0x867e, 4349), (0x867f, 7631), (0x8680, 3873), (0x8681, 4652),
+    (0x8682, 3177), (0x868a, 4264), (0x868b, 7637), (0x868c, 1495), (0x868d, 7636), (0x8693, 7643), (0x8695, 1644), (0x869c, 4544),
+    (0x869d, 7639), (0x86a3, 7641), (0x86a4, 4866), (0x86a7, 7640), (0x86a8, 7635), (0x86a9, 7644), (0x86aa, 7642), (0x86ac, 7638),
+    (0x86af, 7652), (0x86b0, 7649), (0x86b1, 7651), (0x86b4, 7655), (0x86b5, 7647), (0x86b6, 7645), (0x86ba, 7650), (0x86c0, 5072),
+    (0x86c4, 7646), (0x86c6, 3660), (0x86c7, 3822), (0x86c9, 7653), (0x86ca, 2293), (0x86cb, 1895), (0x86ce, 7648), (0x86cf, 7654),
+    (0x86d0, 7661), (0x86d1, 7667), (0x86d4, 2498), (0x86d8, 7666), (0x86d9, 4195), (0x86db, 5056), (0x86de, 7663), (0x86df, 7665),
+    (0x86e4, 2243), (0x86e9, 7656), (0x86ed, 7659), (0x86ee, 3190), (0x86f0, 4948), (0x86f1, 7657), (0x86f2, 7658), (0x86f3, 7660),
+    (0x86f4, 7664), (0x86f8, 7670), (0x86f9, 4727), (0x86fe, 2047), (0x8700, 3934), (0x8702, 2135), (0x8703, 7668), (0x8707, 7669),
+    (0x8708, 7671), (0x8709, 7674), (0x870a, 7672), (0x870d, 7673), (0x8712, 4560), (0x8713, 7662), (0x8715, 4176), (0x8717, 4276),
+    (0x8718, 4990), (0x871a, 7680), (0x871c, 3254), (0x871e, 7677), (0x8721, 2928), (0x8722, 7689), (0x8723, 7675), (0x8725, 7678),
+    (0x8729, 7685), (0x872e, 7679), (0x8731, 7684), (0x8734, 7683), (0x8737, 7686), (0x873b, 7676), (0x873e, 7681), (0x873f, 7687),
+    (0x8747, 4711), (0x8748, 7682), (0x8749, 1682), (0x874c, 7695), (0x874e, 4428), (0x8753, 7698), (0x8757, 2483), (0x8759, 7702),
+    (0x8760, 7693), (0x8763, 7699), (0x8764, 7701), (0x8765, 7703), (0x876e, 7696), (0x8770, 7694), (0x8774, 2440), (0x8776, 1970),
+    (0x877b, 7692), (0x877c, 7700), (0x877d, 7690), (0x877e, 7691), (0x8782, 7688), (0x8783, 7713), (0x8785, 7710), (0x8788, 7709),
+    (0x878b, 7697), (0x878d, 3723), (0x8793, 7704), (0x8797, 7712), (0x879f, 3283), (0x87a8, 7706), (0x87ab, 7714), (0x87ac, 7716),
+    (0x87ad, 7711), (0x87af, 7705), (0x87b3, 7718), (0x87b5, 7717), (0x87ba, 3162), (0x87bd, 7721), (0x87c0, 7723), (0x87c6, 7708),
+    (0x87ca, 7724), (0x87cb, 7719), (0x87d1, 7722), (0x87d2, 7707), (0x87d3, 7720), (0x87db, 7725), (0x87e0, 7727), (0x87e5, 7715),
+    (0x87ea, 7726), (0x87ee, 7728), (0x87f9, 4440), (0x87fe, 7731), (0x8803, 5352), (0x880a, 7732), (0x8813, 7730), (0x8815, 3733),
+    (0x8816, 7729), (0x881b, 7733), (0x8821, 7734), (0x8822, 1817), (0x8832, 7272), (0x8839, 7735), (0x883c, 7736), (0x8840, 4521),
+    (0x8844, 7813), (0x8845, 4455), (0x884c, 4465), (0x884d, 4571), (0x8854, 4369), (0x8857, 2685), (0x8859, 4546), (0x8861, 2415),
+    (0x8862, 6088), (0x8863, 4639), (0x8864, 7553), (0x8865, 1622), (0x8868, 1580), (0x8869, 7554), (0x886b, 3791), (0x886c, 1727),
+    (0x886e, 5345), (0x8870, 3952), (0x8872, 7555), (0x8877, 5033), (0x887d, 7556), (0x887e, 7833), (0x887f, 7557), (0x8881, 4805),
+    (0x8882, 7558), (0x8884, 1441), (0x8885, 7834), (0x8888, 7835), (0x888b, 1877), (0x888d, 3442), (0x8892, 4068), (0x8896, 4485),
+    (0x889c, 4199), (0x88a2, 7559), (0x88a4, 5346), (0x88ab, 1530), (0x88ad, 4337), (0x88b1, 2165), (0x88b7, 7561), (0x88bc, 7562),
+    (0x88c1, 1632), (0x88c2, 3056), (0x88c5, 5091), (0x88c6, 7560), (0x88c9, 7563), (0x88ce, 7565), (0x88d2, 5349), (0x88d4, 4670),
+    (0x88d5, 4796), (0x88d8, 7836), (0x88d9, 3690), (0x88df, 7837), (0x88e2, 7564), (0x88e3, 7566), (0x88e4, 2886), (0x88e5, 7567),
+    (0x88e8, 7571), (0x88f0, 7573), (0x88f1, 7568), (0x88f3, 3808), (0x88f4, 3448), (0x88f8, 3168), (0x88f9, 2347), (0x88fc, 7570),
+    (0x88fe, 7572), (0x8902, 2306), (0x890a, 7578), (0x8910, 2403), (0x8912, 1502), (0x8913, 7576), (0x8919, 7575), (0x891a, 7569),
+    (0x891b, 7577), (0x8921, 7574), (0x8925, 3741), (0x892a, 4177), (0x892b, 7580), (0x8930, 6420), (0x8934, 7579), (0x8936, 7581),
+    (0x8941, 7582), (0x8944, 4392), (0x895e, 7838), (0x895f, 2714), (0x8966, 7583), (0x897b, 7584), (0x897f, 4316), (0x8981, 4615),
+    (0x8983, 7610), (0x8986, 2179), (0x89c1, 2628), (0x89c2, 2314), (0x89c4, 2325), (0x89c5, 3252), (0x89c6, 3904), (0x89c7, 6933),
+    (0x89c8, 2945), (0x89c9, 2810), (0x89ca, 6934), (0x89cb, 6935), (0x89cc, 6936), (0x89ce, 6937), (0x89cf, 6938), (0x89d0, 6939),
+    (0x89d1, 6940), (0x89d2, 2670), (0x89d6, 7986), (0x89da, 7988), (0x89dc, 7989), (0x89de, 7987), (0x89e3, 2697), (0x89e5, 7990),
+    (0x89e6, 1790), (0x89eb, 7991), (0x89ef, 7992), (0x89f3, 7067), (0x8a00, 4563), (0x8a07, 5337), (0x8a3e, 7993), (0x8a48, 7263),
+    (0x8a79, 4905), (0x8a89, 4793), (0x8a8a, 4101), (0x8a93, 3889), (0x8b07, 6423), (0x8b26, 7994), (0x8b66, 2740), (0x8b6c, 3486),
+    (0x8ba0, 5362), (0x8ba1, 2575), (0x8ba2, 1982), (0x8ba3, 2189), (0x8ba4, 3712), (0x8ba5, 2542), (0x8ba6, 5363), (0x8ba7, 5364),
+    (0x8ba8, 4095), (0x8ba9, 3700), (0x8baa, 5365), (0x8bab, 3570), (0x8bad, 4532), (0x8bae, 4678), (0x8baf, 4533), (0x8bb0, 2576),
+    (0x8bb2, 2649), (0x8bb3, 2511), (0x8bb4, 5366), (0x8bb5, 5367), (0x8bb6, 4551), (0x8bb7, 5368), (0x8bb8, 4494), (0x8bb9, 2052),
+    (0x8bba, 3160), (0x8bbc, 3994), (0x8bbd, 2144), (0x8bbe, 3831), (0x8bbf, 2102), (0x8bc0, 2812), (0x8bc1, 4985), (0x8bc2, 5369),
+    (0x8bc3, 5370), (0x8bc4, 3509), (0x8bc5, 5146), (0x8bc6, 3875), (0x8bc8, 4896), (0x8bc9, 4010), (0x8bca, 4966), (0x8bcb, 5371),
+    (0x8bcc, 5044), (0x8bcd, 1827), (0x8bce, 5373), (0x8bcf, 5372), (0x8bd1, 4680), (0x8bd2, 5374), (0x8bd3, 5375), (0x8bd4, 5376),
+    (0x8bd5, 3905), (0x8bd6, 5377), (0x8bd7, 3864), (0x8bd8, 5378), (0x8bd9, 5379), (0x8bda, 1738), (0x8bdb, 5060), (0x8bdc, 5380),
+    (0x8bdd, 2459), (0x8bde, 1893), (0x8bdf, 5381), (0x8be0, 5382), (0x8be1, 2333), (0x8be2, 4526), (0x8be3, 4677), (0x8be4, 5383),
+    (0x8be5, 2196), (0x8be6, 4397), (0x8be7, 1676), (0x8be8, 5384), (0x8be9, 5385), (0x8beb, 2706), (0x8bec, 4289), (0x8bed, 4778),
+    (0x8bee, 5386), (0x8bef, 4312), (0x8bf0, 5387), (0x8bf1, 4752), (0x8bf2, 2512), (0x8bf3, 5388), (0x8bf4, 3968), (0x8bf5, 3995),
+    (0x8bf6, 5389), (0x8bf7, 3646), (0x8bf8, 5059), (0x8bf9, 5390), (0x8bfa, 3404), (0x8bfb, 2006), (0x8bfc, 5391), (0x8bfd, 2111),
+    (0x8bfe, 2865), (0x8bff, 5392), (0x8c00, 5393), (0x8c01, 3960), (0x8c02, 5394), (0x8c03, 1966), (0x8c04, 5395), (0x8c05, 3041),
+    (0x8c06, 5102), (0x8c07, 5396), (0x8c08, 4065), (0x8c0a, 4679), (0x8c0b, 3306), (0x8c0c, 5397), (0x8c0d, 1972), (0x8c0e, 2492),
+    (0x8c0f, 5398), (0x8c10, 4436), (0x8c11, 5399), (0x8c12, 5400), (0x8c13, 4258), (0x8c14, 5401), (0x8c15, 5402), (0x8c16, 5403),
+    (0x8c17, 1684), (0x8c18, 5406), (0x8c19, 5404), (0x8c1a, 4583), (0x8c1b, 5405), (0x8c1c, 3248), (0x8c1d, 5407), (0x8c1f, 5408),
+    (0x8c20, 5409), (0x8c21, 5410), (0x8c22, 4444), (0x8c23, 4610), (0x8c24, 1498), (0x8c25, 5411), (0x8c26, 3582), (0x8c27, 5412),
+    (0x8c28, 2718), (0x8c29, 3196), (0x8c2a, 5413), (0x8c2b, 5414), (0x8c2c, 3288), (0x8c2d, 4064), (0x8c2e, 5415), (0x8c2f, 5416),
+    (0x8c30, 2943), (0x8c31, 3532), (0x8c32, 5417), (0x8c33, 5418), (0x8c34, 3591), (0x8c35, 5419), (0x8c36, 5420), (0x8c37, 2295),
+    (0x8c41, 2520), (0x8c46, 1998), (0x8c47, 7889), (0x8c49, 7890), (0x8c4c, 4202), (0x8c55, 7920), (0x8c5a, 7019), (0x8c61, 4406),
+    (0x8c62, 2474), (0x8c6a, 2381), (0x8c6b, 4798), (0x8c73, 6072), (0x8c78, 7979), (0x8c79, 1513), (0x8c7a, 1679), (0x8c82, 7980),
+    (0x8c85, 7982), (0x8c89, 2398), (0x8c8a, 7981), (0x8c8c, 3213), (0x8c94, 7984), (0x8c98, 7983), (0x8d1d, 1523), (0x8d1e, 4961),
+    (0x8d1f, 2187), (0x8d21, 2272), (0x8d22, 1635), (0x8d23, 4873), (0x8d24, 4368), (0x8d25, 1469), (0x8d26, 4930), (0x8d27, 2528),
+    (0x8d28, 5023), (0x8d29, 2090), (0x8d2a, 4057), (0x8d2b, 3499), (0x8d2c, 1568), (0x8d2d, 2281), (0x8d2e, 5073), (0x8d2f, 2320),
+    (0x8d30, 2068), (0x8d31, 2627), (0x8d32, 6919), (0x8d33, 6920), (0x8d34, 4130), (0x8d35, 2338), (0x8d36, 6921), (0x8d37, 1876),
+    (0x8d38, 3214), (0x8d39, 2116), (0x8d3a, 2405), (0x8d3b, 6922), (0x8d3c, 4877), (0x8d3d, 6923), (0x8d3e, 2591), (0x8d3f, 2506),
+    (0x8d40, 6924), (0x8d41, 3069), (0x8d42, 3125), (0x8d43, 4856), (0x8d44, 5117), (0x8d45, 6925), (0x8d46, 6926), (0x8d47, 6929),
+    (0x8d48, 6927), (0x8d49, 6928), (0x8d4a, 3821), (0x8d4b, 2180), (0x8d4c, 2009), (0x8d4d, 6930), (0x8d4e, 3927), (0x8d4f, 3804),
+    (0x8d50, 1830), (0x8d53, 6166), (0x8d54, 3449), (0x8d55, 6931), (0x8d56, 2934), (0x8d58, 5099), (0x8d59, 6932), (0x8d5a, 5087),
+    (0x8d5b, 3757), (0x8d5c, 5220), (0x8d5d, 5214), (0x8d5e, 4855), (0x8d60, 4882), (0x8d61, 3795), (0x8d62, 4713), (0x8d63, 2212),
+    (0x8d64, 1755), (0x8d66, 3825), (0x8d67, 7887), (0x8d6b, 2402), (0x8d6d, 7888), (0x8d70, 5138), (0x8d73, 7882), (0x8d74, 2177),
+    (0x8d75, 4939), (0x8d76, 2208), (0x8d77, 3557), (0x8d81, 1726), (0x8d84, 7883), (0x8d85, 1703), (0x8d8a, 4822), (0x8d8b, 3658),
+    (0x8d91, 7885), (0x8d94, 7884), (0x8d9f, 4084), (0x8da3, 3669), (0x8db1, 7886), (0x8db3, 5142), (0x8db4, 3414), (0x8db5, 7927),
+    (0x8db8, 7922), (0x8dba, 7930), (0x8dbc, 7929), (0x8dbe, 5007), (0x8dbf, 7928), (0x8dc3, 4823), (0x8dc4, 7931), (0x8dc6, 7939),
+    (0x8dcb, 1456), (0x8dcc, 1967), (0x8dce, 7936), (0x8dcf, 7937), (0x8dd1, 3443), (0x8dd6, 7932), (0x8dd7, 7933), (0x8dda, 7934),
+    (0x8ddb, 7938), (0x8ddd, 2789), (0x8dde, 7935), (0x8ddf, 2251), (0x8de3, 7943), (0x8de4, 7946), (0x8de8, 2890), (0x8dea, 2337),
+    (0x8deb, 7923), (0x8dec, 7940), (0x8def, 3124), (0x8df3, 4129), (0x8df5, 2626), (0x8df7, 7941), (0x8df8, 7942), (0x8df9, 7944),
+    (0x8dfa, 2042), (0x8dfb, 7945), (0x8dfd, 7948), (0x8e05, 7924), (0x8e09, 7947), (0x8e0a, 4726), (0x8e0c, 1767), (0x8e0f, 4045),
+    (0x8e14, 7949), (0x8e1d, 7950), (0x8e1e, 2790), (0x8e1f, 7951), (0x8e22, 4104), (0x8e23, 7954), (0x8e29, 1637), (0x8e2a, 5132),
+    (0x8e2c, 7952), (0x8e2e, 7953), (0x8e2f, 7955), (0x8e31, 7961), (0x8e35, 7959), (0x8e39, 7958), (0x8e3a, 7956), (0x8e3d, 7960),
+    (0x8e40, 7957), (0x8e41, 7963), (0x8e42, 7964), (0x8e44, 4108), (0x8e47, 6422), (0x8e48, 1903), (0x8e49, 7962), (0x8e4a, 7967),
+    (0x8e4b, 4044), (0x8e51, 7965), (0x8e52, 7966), (0x8e59, 7925), (0x8e66, 1539), (0x8e69, 7926), (0x8e6c, 1916), (0x8e6d, 1665),
+    (0x8e6f, 7971), (0x8e70, 7968), (0x8e72, 2028), (0x8e74, 7972), (0x8e76, 7969), (0x8e7c, 7970), (0x8e7f, 1843), (0x8e81, 4867),
+    (0x8e85, 7973), (0x8e87, 1780), (0x8e8f, 7974), (0x8e90, 7976), (0x8e94, 7975), (0x8e9c, 7977), (0x8e9e, 7978), (0x8eab, 3836),
+    (0x8eac, 2265), (0x8eaf, 3662), (0x8eb2, 2040), (0x8eba, 4082), (0x8ece, 6867), (0x8f66, 1712), (0x8f67, 4887), (0x8f68, 2331),
+    (0x8f69, 4506), (0x8f6b, 6844), (0x8f6c, 5085), (0x8f6d, 6845), (0x8f6e, 3155), (0x8f6f, 3742), (0x8f70, 2417), (0x8f71, 6846),
+    (0x8f72, 6847), (0x8f73, 6848), (0x8f74, 5046), (0x8f75, 6849), (0x8f76, 6850), (0x8f77, 6852), (0x8f78, 6851), (0x8f79, 6853),
+    (0x8f7a, 6854), (0x8f7b, 3636), (0x8f7c, 6855), (0x8f7d, 4849), (0x8f7e, 6856), (0x8f7f, 2677), (0x8f81, 6857), (0x8f82, 6858),
+    (0x8f83, 2678), (0x8f84, 6859), (0x8f85, 2169), (0x8f86, 3037), (0x8f87, 6860), (0x8f88, 1521), (0x8f89, 2495), (0x8f8a, 2340),
+    (0x8f8b, 6861), (0x8f8d, 6862), (0x8f8e, 6863), (0x8f8f, 6864), (0x8f90, 2155), (0x8f91, 2550), (0x8f93, 3921), (0x8f94, 5967),
+    (0x8f95, 4808), (0x8f96, 4352), (0x8f97, 4910), (0x8f98, 6865), (0x8f99, 4949), (0x8f9a, 6866), (0x8f9b, 4450), (0x8f9c, 2283),
+    (0x8f9e, 1824), (0x8f9f, 1560), (0x8fa3, 2930), (0x8fa8, 1573), (0x8fa9, 1574), (0x8fab, 1575), (0x8fb0, 1720), (0x8fb1, 3737),
+    (0x8fb6, 6424), (0x8fb9, 1566), (0x8fbd, 3048), (0x8fbe, 1864), (0x8fc1, 3579), (0x8fc2, 4755), (0x8fc4, 3566), (0x8fc5, 4535),
+    (0x8fc7, 2348), (0x8fc8, 3186), (0x8fce, 4712), (0x8fd0, 4836), (0x8fd1, 2723), (0x8fd3, 6425), (0x8fd4, 2088), (0x8fd5, 6426),
+    (0x8fd8, 2468), (0x8fd9, 4953), (0x8fdb, 2719), (0x8fdc, 4815), (0x8fdd, 4234), (0x8fde, 3019), (0x8fdf, 1748), (0x8fe2, 4127),
+    (0x8fe4, 6429), (0x8fe5, 6427), (0x8fe6, 6431), (0x8fe8, 6433), (0x8fe9, 6430), (0x8fea, 1926), (0x8feb, 3517), (0x8fed, 1971),
+    (0x8fee, 6428), (0x8ff0, 3939), (0x8ff3, 6432), (0x8ff7, 3247), (0x8ff8, 1540), (0x8ff9, 2540), (0x8ffd, 5098), (0x9000, 4178),
+    (0x9001, 3992), (0x9002, 3895), (0x9003, 4092), (0x9004, 6435), (0x9005, 6434), (0x9006, 3359), (0x9009, 4512), (0x900a, 4534),
+    (0x900b, 6436), (0x900d, 6439), (0x900f, 4159), (0x9010, 5061), (0x9011, 6438), (0x9012, 1940), (0x9014, 4165), (0x9016, 6440),
+    (0x9017, 1999), (0x901a, 4143), (0x901b, 2323), (0x901d, 3890), (0x901e, 1740), (0x901f, 4004), (0x9020, 4869), (0x9021, 6441),
+    (0x9022, 2141), (0x9026, 6437), (0x902d, 6444), (0x902e, 1879), (0x902f, 6445), (0x9035, 6442), (0x9036, 6443), (0x9038, 4666),
+    (0x903b, 3164), (0x903c, 1541), (0x903e, 4765), (0x9041, 2034), (0x9042, 4023), (0x9044, 6446), (0x9047, 4785), (0x904d, 1576),
+    (0x904f, 2057), (0x9050, 6449), (0x9051, 6447), (0x9052, 6448), (0x9053, 1911), (0x9057, 4642), (0x9058, 6451), (0x905b, 6453),
+    (0x9062, 6452), (0x9063, 3589), (0x9065, 4608), (0x9068, 6450), (0x906d, 4859), (0x906e, 4945), (0x9074, 6455), (0x9075, 5156),
+    (0x907d, 6456), (0x907f, 1563), (0x9080, 4602), (0x9082, 6457), (0x9083, 6459), (0x9088, 6458), (0x908b, 6460), (0x9091, 4661),
+    (0x9093, 1922), (0x9095, 6642), (0x9097, 5442), (0x9099, 5445), (0x909b, 5443), (0x909d, 5444), (0x90a1, 5447), (0x90a2, 4464),
+    (0x90a3, 3328), (0x90a6, 1487), (0x90aa, 4433), (0x90ac, 5446), (0x90ae, 4741), (0x90af, 2359), (0x90b0, 5453), (0x90b1, 3652),
+    (0x90b3, 5449), (0x90b4, 5448), (0x90b5, 3818), (0x90b6, 5450), (0x90b8, 5452), (0x90b9, 5137), (0x90ba, 5451), (0x90bb, 3065),
+    (0x90be, 5456), (0x90c1, 4783), (0x90c4, 5458), (0x90c5, 5455), (0x90c7, 5459), (0x90ca, 2659), (0x90ce, 2954), (0x90cf, 5454),
+    (0x90d0, 5457), (0x90d1, 4984), (0x90d3, 5460), (0x90d7, 5464), (0x90db, 5465), (0x90dc, 5463), (0x90dd, 2383), (0x90e1, 2823),
+    (0x90e2, 5462), (0x90e6, 5461), (0x90e7, 4832), (0x90e8, 1628), (0x90eb, 5466), (0x90ed, 2344), (0x90ef, 5467), (0x90f4, 1718),
+    (0x90f8, 1885), (0x90fd, 2001), (0x90fe, 5468), (0x9102, 2058), (0x9104, 5469), (0x9119, 1544), (0x911e, 5471), (0x9122, 5470),
+    (0x9123, 5472), (0x912f, 5474), (0x9131, 5473), (0x9139, 5475), (0x9143, 5476), (0x9146, 5477), (0x9149, 4746), (0x914a, 7891),
+    (0x914b, 3656), (0x914c, 5110), (0x914d, 3451), (0x914e, 7893), (0x914f, 7894), (0x9150, 7892), (0x9152, 2763), (0x9157, 4496),
+    (0x915a, 2118), (0x915d, 4838), (0x915e, 4051), (0x9161, 7897), (0x9162, 7896), (0x9163, 2357), (0x9164, 7895), (0x9165, 4001),
+    (0x9169, 7899), (0x916a, 2963), (0x916c, 1765), (0x916e, 4145), (0x916f, 7900), (0x9170, 7898), (0x9171, 2651), (0x9172, 7903),
+    (0x9174, 7904), (0x9175, 2676), (0x9176, 3219), (0x9177, 2884), (0x9178, 4012), (0x9179, 7905), (0x917d, 7901), (0x917e, 7902),
+    (0x917f, 3369), (0x9185, 7907), (0x9187, 1813), (0x9189, 5152), (0x918b, 1840), (0x918c, 7906), (0x918d, 7909), (0x9190, 7908),
+    (0x9191, 7910), (0x9192, 4466), (0x919a, 3244), (0x919b, 3674), (0x91a2, 7911), (0x91a3, 7912), (0x91aa, 7913), (0x91ad, 7914),
+    (0x91ae, 7915), (0x91af, 7916), (0x91b4, 7918), (0x91b5, 7917), (0x91ba, 7919), (0x91c7, 1638), (0x91c9, 4751), (0x91ca, 3898),
+    (0x91cc, 2992), (0x91cd, 5037), (0x91ce, 4621), (0x91cf, 3038), (0x91d1, 2711), (0x91dc, 2171), (0x9274, 2625), (0x928e, 8027),
+    (0x92ae, 8028), (0x92c8, 8029), (0x933e, 8030), (0x936a, 8031), (0x938f, 8033), (0x93ca, 8032), (0x93d6, 8156), (0x943e, 8034),
+    (0x946b, 8035), (0x9485, 7273), (0x9486, 7274), (0x9487, 7275), (0x9488, 4962), (0x9489, 1977), (0x948a, 7277), (0x948b, 7276),
+    (0x948c, 7278), (0x948d, 7279), (0x948e, 3576), (0x948f, 7280), (0x9490, 7281), (0x9492, 2083), (0x9493, 1965), (0x9494, 7282),
+    (0x9495, 7284), (0x9497, 7283), (0x9499, 2199), (0x949a, 7285), (0x949b, 7286), (0x949c, 7287), (0x949d, 2032), (0x949e, 1705),
+    (0x949f, 5032), (0x94a0, 3327), (0x94a1, 1524), (0x94a2, 2215), (0x94a3, 7288), (0x94a4, 7289), (0x94a5, 4824), (0x94a6, 3624),
+    (0x94a7, 2816), (0x94a8, 4286), (0x94a9, 2274), (0x94aa, 7291), (0x94ab, 7290), (0x94ac, 7293), (0x94ad, 7292), (0x94ae, 3388),
+    (0x94af, 7294), (0x94b0, 7295), (0x94b1, 3585), (0x94b2, 7296), (0x94b3, 3586), (0x94b4, 7297), (0x94b5, 1604), (0x94b6, 7298),
+    (0x94b7, 7299), (0x94b8, 7300), (0x94b9, 7301), (0x94ba, 7302), (0x94bb, 5149), (0x94bc, 7303), (0x94bd, 7304), (0x94be, 2593),
+    (0x94bf, 7305), (0x94c0, 4742), (0x94c1, 4131), (0x94c2, 1609), (0x94c3, 3076), (0x94c4, 7306), (0x94c5, 3577), (0x94c6, 3208),
+    (0x94c8, 7307), (0x94c9, 7308), (0x94ca, 7309), (0x94cb, 7310), (0x94cc, 7311), (0x94cd, 7312), (0x94ce, 7313), (0x94d0, 7314),
+    (0x94d1, 7315), (0x94d2, 7316), (0x94d5, 7317), (0x94d6, 7318), (0x94d7, 7319), (0x94d8, 7321), (0x94d9, 7320), (0x94db, 7322),
+    (0x94dc, 4148), (0x94dd, 3134), (0x94de, 7323), (0x94df, 7324), (0x94e0, 7325), (0x94e1, 4888), (0x94e2, 7326), (0x94e3, 4342),
+    (0x94e4, 7327), (0x94e5, 7328), (0x94e7, 7329), (0x94e8, 7330), (0x94e9, 7332), (0x94ea, 7331), (0x94eb, 7333), (0x94ec, 2246),
+    (0x94ed, 3285), (0x94ee, 7334), (0x94ef, 7335), (0x94f0, 2665), (0x94f1, 4636), (0x94f2, 1686), (0x94f3, 7336), (0x94f4, 7337),
+    (0x94f5, 7338), (0x94f6, 4693), (0x94f7, 7339), (0x94f8, 5074), (0x94f9, 7340), (0x94fa, 3521), (0x94fc, 7341), (0x94fd, 7342),
+    (0x94fe, 3027), (0x94ff, 7343), (0x9500, 4413), (0x9501, 4035), (0x9502, 7345), (0x9503, 7344), (0x9504, 1781), (0x9505, 2343),
+    (0x9506, 7346), (0x9507, 7347), (0x9508, 4483), (0x9509, 7348), (0x950a, 7349), (0x950b, 2137), (0x950c, 4448), (0x950d, 7350),
+    (0x950e, 7351), (0x950f, 7352), (0x9510, 3746), (0x9511, 4105), (0x9512, 7353), (0x9513, 7354), (0x9514, 7355), (0x9515, 7356),
+    (0x9516, 7357), (0x9517, 4951), (0x9518, 7358), (0x9519, 1862), (0x951a, 3205), (0x951b, 7359), (0x951d, 7360), (0x951e, 7361),
+    (0x951f, 7362), (0x9521, 4322), (0x9522, 7363), (0x9523, 3165), (0x9524, 1809), (0x9525, 5097), (0x9526, 2716), (0x9528, 4362),
+    (0x9529, 7366), (0x952a, 7364), (0x952b, 7365), (0x952c, 7367), (0x952d, 1980), (0x952e, 2629), (0x952f, 2791), (0x9530, 3239),
+    (0x9531, 7368), (0x9532, 7369), (0x9534, 7370), (0x9535, 7378), (0x9536, 7371), (0x9537, 7372), (0x9538, 7373), (0x9539, 3605),
+    (0x953a, 7412), (0x953b, 2018), (0x953c, 7374), (0x953e, 7375), (0x953f, 7376), (0x9540, 2011), (0x9541, 3225), (0x9542, 7377),
+    (0x9544, 7379), (0x9545, 7380), (0x9546, 7381), (0x9547, 4969), (0x9549, 7382), (0x954a, 3376), (0x954c, 7383), (0x954d, 3377),
+    (0x954e, 7384), (0x954f, 7385), (0x9550, 2229), (0x9551, 1496), (0x9552, 7386), (0x9553, 7387), (0x9554, 7388), (0x9556, 7389),
+    (0x9557, 7390), (0x9558, 7391), (0x9559, 7392), (0x955b, 7393), (0x955c, 2746), (0x955d, 7396), (0x955e, 7394), (0x955f, 7395),
+    (0x9561, 7397), (0x9562, 7398), (0x9563, 3052), (0x9564, 7399), (0x9565, 7400), (0x9566, 7401), (0x9567, 7402), (0x9568, 7403),
+    (0x9569, 7404), (0x956a, 7405), (0x956b, 7406), (0x956c, 7407), (0x956d, 2969), (0x956f, 7408), (0x9570, 3020), (0x9571, 7409),
+    (0x9572, 7410), (0x9573, 7411), (0x9576, 4389), (0x957f, 1695), (0x95e8, 3232), (0x95e9, 6240), (0x95ea, 3792), (0x95eb, 6241),
+    (0x95ed, 1556), (0x95ee, 4271), (0x95ef, 1804), (0x95f0, 3747), (0x95f1, 6242), (0x95f2, 4371), (0x95f3, 6243), (0x95f4, 2605),
+    (0x95f5, 6244), (0x95f6, 6245), (0x95f7, 3233), (0x95f8, 4889), (0x95f9, 3343), (0x95fa, 2330), (0x95fb, 4266), (0x95fc, 6246),
+    (0x95fd, 3281), (0x95fe, 6247), (0x9600, 2074), (0x9601, 2244), (0x9602, 2399), (0x9603, 6248), (0x9604, 6249), (0x9605, 4829),
+    (0x9606, 6250), (0x9608, 6251), (0x9609, 4554), (0x960a, 6252), (0x960b, 6253), (0x960c, 6254), (0x960d, 6255), (0x960e, 4565),
+    (0x960f, 6256), (0x9610, 1688), (0x9611, 2940), (0x9612, 6257), (0x9614, 2924), (0x9615, 6258), (0x9616, 6259), (0x9617, 6260),
+    (0x9619, 6261), (0x961a, 6262), (0x961c, 2184), (0x961d, 5423), (0x961f, 2024), (0x9621, 5425), (0x9622, 5424), (0x962a, 5427),
+    (0x962e, 3743), (0x9631, 5426), (0x9632, 2099), (0x9633, 4595), (0x9634, 4690), (0x9635, 4970), (0x9636, 2686), (0x963b, 5147),
+    (0x963c, 5429), (0x963d, 5428), (0x963f, 1411), (0x9640, 4186), (0x9642, 5430), (0x9644, 2190), (0x9645, 2579), (0x9646, 3130),
+    (0x9647, 3105), (0x9648, 1725), (0x9649, 5431), (0x964b, 3111), (0x964c, 3305), (0x964d, 2652), (0x9650, 4385), (0x9654, 5432),
+    (0x9655, 3793), (0x965b, 1564), (0x965f, 5433), (0x9661, 1997), (0x9662, 4819), (0x9664, 1784), (0x9667, 5434), (0x9668, 4834),
+    (0x9669, 4376), (0x966a, 3450), (0x966c, 5435), (0x9672, 5436), (0x9674, 5437), (0x9675, 3081), (0x9676, 4094), (0x9677, 4384),
+    (0x9685, 4770), (0x9686, 3102), (0x9688, 5438), (0x968b, 4016), (0x968d, 5439), (0x968f, 4017), (0x9690, 4699), (0x9694, 2245),
+    (0x9697, 5440), (0x9698, 1424), (0x9699, 4345), (0x969c, 4934), (0x96a7, 4024), (0x96b0, 5441), (0x96b3, 6239), (0x96b6, 3012),
+    (0x96b9, 8020), (0x96bc, 8021), (0x96bd, 8022), (0x96be, 3338), (0x96c0, 3689), (0x96c1, 4578), (0x96c4, 4476), (0x96c5, 4548),
+    (0x96c6, 2552), (0x96c7, 2300), (0x96c9, 7415), (0x96cc, 1823), (0x96cd, 4725), (0x96ce, 8023), (0x96cf, 1782), (0x96d2, 8024),
+    (0x96d5, 1960), (0x96e0, 8026), (0x96e8, 4773), (0x96e9, 7996), (0x96ea, 4520), (0x96ef, 7998), (0x96f3, 7997), (0x96f6, 3074),
+    (0x96f7, 2968), (0x96f9, 1505), (0x96fe, 4306), (0x9700, 4489), (0x9701, 8000), (0x9704, 4409), (0x9706, 7999), (0x9707, 4967),
+    (0x9708, 8001), (0x9709, 3220), (0x970d, 2527), (0x970e, 8003), (0x970f, 8002), (0x9713, 3351), (0x9716, 3063), (0x971c, 3957),
+    (0x971e, 4351), (0x972a, 8004), (0x972d, 8005), (0x9730, 8006), (0x9732, 3123), (0x9738, 1461), (0x9739, 3472), (0x973e, 8007),
+    (0x9752, 3635), (0x9753, 7995), (0x9756, 2749), (0x9759, 2743), (0x975b, 1948), (0x975e, 2106), (0x9760, 2850), (0x9761, 3245),
+    (0x9762, 3265), (0x9765, 5213), (0x9769, 2240), (0x9773, 2720), (0x9774, 4516), (0x9776, 1457), (0x977c, 8099), (0x9785, 8100),
+    (0x978b, 4429), (0x978d, 1425), (0x9791, 8101), (0x9792, 8102), (0x9794, 8103), (0x9798, 3613), (0x97a0, 2772), (0x97a3, 8106),
+    (0x97ab, 8105), (0x97ad, 1565), (0x97af, 8104), (0x97b2, 8107), (0x97b4, 8108), (0x97e6, 4233), (0x97e7, 3710), (0x97e9, 2360),
+    (0x97ea, 6697), (0x97eb, 6698), (0x97ec, 6699), (0x97ed, 2759), (0x97f3, 4689), (0x97f5, 4840), (0x97f6, 3815), (0x9875, 4624),
+    (0x9876, 1978), (0x9877, 3645), (0x9878, 7611), (0x9879, 4401), (0x987a, 3966), (0x987b, 4492), (0x987c, 6658), (0x987d, 4206),
+    (0x987e, 2298), (0x987f, 2030), (0x9880, 7612), (0x9881, 1477), (0x9882, 3991), (0x9883, 7613), (0x9884, 4797), (0x9885, 3114),
+    (0x9886, 3083), (0x9887, 3513), (0x9888, 2742), (0x9889, 7614), (0x988a, 2590), (0x988c, 7615), (0x988d, 7616), (0x988f, 7617),
+    (0x9890, 4640), (0x9891, 3498), (0x9893, 4174), (0x9894, 7618), (0x9896, 4716), (0x9897, 2856), (0x9898, 4107), (0x989a, 7619),
+    (0x989b, 7620), (0x989c, 4564), (0x989d, 2051), (0x989e, 7621), (0x989f, 7622), (0x98a0, 1942), (0x98a1, 7623), (0x98a2, 7624),
+    (0x98a4, 1689), (0x98a5, 7625), (0x98a6, 7626), (0x98a7, 3672), (0x98ce, 2138), (0x98d1, 7058), (0x98d2, 7059), (0x98d3, 7060),
+    (0x98d5, 7061), (0x98d8, 3491), (0x98d9, 7062), (0x98da, 7063), (0x98de, 2108), (0x98df, 3872), (0x98e7, 6133), (0x98e8, 8130),
+    (0x990d, 8131), (0x9910, 1642), (0x992e, 8132), (0x9954, 8134), (0x9955, 8133), (0x9963, 6136), (0x9965, 2539), (0x9967, 6137),
+    (0x9968, 6138), (0x9969, 6139), (0x996a, 6140), (0x996b, 6141), (0x996c, 6142), (0x996d, 2092), (0x996e, 4696), (0x996f, 2635),
+    (0x9970, 3899), (0x9971, 1508), (0x9972, 3986), (0x9974, 6143), (0x9975, 2065), (0x9976, 3701), (0x9977, 6144), (0x997a, 2671),
+    (0x997c, 1596), (0x997d, 6145), (0x997f, 2059), (0x9980, 6146), (0x9981, 3346), (0x9984, 6147), (0x9985, 4381), (0x9986, 2316),
+    (0x9987, 6148), (0x9988, 2914), (0x998a, 6149), (0x998b, 1683), (0x998d, 6150), (0x998f, 3090), (0x9990, 6151), (0x9991, 6152),
+    (0x9992, 3189), (0x9993, 6153), (0x9994, 6154), (0x9995, 6155), (0x9996, 3908), (0x9997, 5193), (0x9998, 5332), (0x9999, 4390),
+    (0x99a5, 7430), (0x99a8, 5561), (0x9a6c, 3178), (0x9a6d, 4799), (0x9a6e, 4187), (0x9a6f, 4528), (0x9a70, 1750), (0x9a71, 3664),
+    (0x9a73, 1618), (0x9a74, 3132), (0x9a75, 6549), (0x9a76, 3880), (0x9a77, 6550), (0x9a78, 6551), (0x9a79, 2777), (0x9a7a, 6552),
+    (0x9a7b, 5079), (0x9a7c, 4188), (0x9a7d, 6554), (0x9a7e, 2598), (0x9a7f, 6553), (0x9a80, 6555), (0x9a81, 6556), (0x9a82, 3179),
+    (0x9a84, 2661), (0x9a85, 6557), (0x9a86, 3171), (0x9a87, 2356), (0x9a88, 6558), (0x9a8a, 6559), (0x9a8b, 1741), (0x9a8c, 4584),
+    (0x9a8f, 2824), (0x9a90, 6560), (0x9a91, 3556), (0x9a92, 6561), (0x9a93, 6562), (0x9a96, 6563), (0x9a97, 3490), (0x9a98, 6564),
+    (0x9a9a, 3766), (0x9a9b, 6565), (0x9a9c, 6566), (0x9a9d, 6567), (0x9a9e, 6416), (0x9a9f, 6568), (0x9aa0, 6569), (0x9aa1, 3167),
+    (0x9aa2, 6570), (0x9aa3, 6571), (0x9aa4, 5053), (0x9aa5, 6572), (0x9aa7, 6573), (0x9aa8, 2294), (0x9ab0, 8110), (0x9ab1, 8109),
+    (0x9ab6, 8113), (0x9ab7, 8111), (0x9ab8, 2350), (0x9aba, 8114), (0x9abc, 8115), (0x9ac0, 8117), (0x9ac1, 8116), (0x9ac2, 8119),
+    (0x9ac5, 8118), (0x9acb, 8120), (0x9acc, 8121), (0x9ad1, 8122), (0x9ad3, 4019), (0x9ad8, 2224), (0x9adf, 8135), (0x9ae1, 8136),
+    (0x9ae6, 8137), (0x9aeb, 8139), (0x9aed, 8141), (0x9aef, 8138), (0x9af9, 8142), (0x9afb, 8140), (0x9b03, 5130), (0x9b08, 8143),
+    (0x9b0f, 8144), (0x9b13, 8145), (0x9b1f, 8146), (0x9b23, 8147), (0x9b2f, 5494), (0x9b32, 5179), (0x9b3b, 6478), (0x9b3c, 2332),
+    (0x9b41, 2912), (0x9b42, 2517), (0x9b43, 8124), (0x9b44, 3516), (0x9b45, 8123), (0x9b47, 8125), (0x9b48, 8127), (0x9b49, 8126),
+    (0x9b4d, 8128), (0x9b4f, 4255), (0x9b51, 8129), (0x9b54, 3296), (0x9c7c, 4766), (0x9c7f, 8036), (0x9c81, 3120), (0x9c82, 8037),
+    (0x9c85, 8038), (0x9c86, 8039), (0x9c87, 8040), (0x9c88, 8041), (0x9c8b, 8043), (0x9c8d, 1514), (0x9c8e, 8044), (0x9c90, 8045),
+    (0x9c91, 8046), (0x9c92, 8047), (0x9c94, 8048), (0x9c95, 8049), (0x9c9a, 8050), (0x9c9b, 8051), (0x9c9c, 4365), (0x9c9e, 8052),
+    (0x9c9f, 8053), (0x9ca0, 8054), (0x9ca1, 8055), (0x9ca2, 8056), (0x9ca3, 8057), (0x9ca4, 2993), (0x9ca5, 8058), (0x9ca6, 8059),
+    (0x9ca7, 8060), (0x9ca8, 8061), (0x9ca9, 8062), (0x9cab, 8063), (0x9cad, 8064), (0x9cae, 8065), (0x9cb0, 8066), (0x9cb1, 8067),
+    (0x9cb2, 8068), (0x9cb3, 8069), (0x9cb4, 8070), (0x9cb5, 8071), (0x9cb6, 8072), (0x9cb7, 8073), (0x9cb8, 2733), (0x9cba, 8074),
+    (0x9cbb, 8075), (0x9cbc, 8076), (0x9cbd, 8077), (0x9cc3, 3755), (0x9cc4, 8078), (0x9cc5, 8079), (0x9cc6, 8080), (0x9cc7, 8081),
+    (0x9cca, 8082), (0x9ccb, 8083), (0x9ccc, 8084), (0x9ccd, 8085), (0x9cce, 8086), (0x9ccf, 8087), (0x9cd0, 8088), (0x9cd3, 8089),
+    (0x9cd4, 8090), (0x9cd5, 8091), (0x9cd6, 1581), (0x9cd7, 8092), (0x9cd8, 8093), (0x9cd9, 8094), (0x9cdc, 8095), (0x9cdd, 8096),
+    (0x9cde, 3066), (0x9cdf, 8097), (0x9ce2, 8098), (0x9e1f, 3370), (0x9e20, 7440), (0x9e21, 2543), (0x9e22, 7441), (0x9e23, 3284),
+    (0x9e25, 3407), (0x9e26, 4538), (0x9e28, 7442), (0x9e29, 7443), (0x9e2a, 7444), (0x9e2b, 7445), (0x9e2c, 7446), (0x9e2d, 4539),
+    (0x9e2f, 4587), (0x9e31, 7448), (0x9e32, 7447), (0x9e33, 4800), (0x9e35, 4185), (0x9e36, 7449), (0x9e37, 7451), (0x9e38, 7450),
+    (0x9e39, 7452), (0x9e3a, 7453), (0x9e3d, 2236), (0x9e3e, 7454), (0x9e3f, 2421), (0x9e41, 7455), (0x9e42, 7456), (0x9e43, 2798),
+    (0x9e44, 7457), (0x9e45, 2049), (0x9e46, 7458), (0x9e47, 7459), (0x9e48, 7460), (0x9e49, 7461), (0x9e4a, 3686), (0x9e4b, 7462),
+    (0x9e4c, 7463), (0x9e4e, 7464), (0x9e4f, 3467), (0x9e51, 7465), (0x9e55, 7466), (0x9e57, 7467), (0x9e58, 8112), (0x9e5a, 7468),
+    (0x9e5b, 7469), (0x9e5c, 7470), (0x9e5e, 7471), (0x9e63, 7472), (0x9e64, 2404), (0x9e66, 7473), (0x9e67, 7474), (0x9e68, 7475),
+    (0x9e69, 7476), (0x9e6a, 7477), (0x9e6b, 7478), (0x9e6c, 7479), (0x9e6d, 7481), (0x9e70, 4704), (0x9e71, 7480), (0x9e73, 7482),
+    (0x9e7e, 7921), (0x9e7f, 3126), (0x9e82, 8151), (0x9e87, 8152), (0x9e88, 8153), (0x9e8b, 8154), (0x9e92, 8155), (0x9e93, 3121),
+    (0x9e9d, 8157), (0x9e9f, 8158), (0x9ea6, 3184), (0x9eb4, 7881), (0x9eb8, 7880), (0x9ebb, 3174), (0x9ebd, 8148), (0x9ebe, 8149),
+    (0x9ec4, 2481), (0x9ec9, 5331), (0x9ecd, 3935), (0x9ece, 2985), (0x9ecf, 7429), (0x9ed1, 2407), (0x9ed4, 3584), (0x9ed8, 3301),
+    (0x9edb, 8159), (0x9edc, 8160), (0x9edd, 8161), (0x9edf, 8163), (0x9ee0, 8162), (0x9ee2, 8164), (0x9ee5, 8167), (0x9ee7, 8166),
+    (0x9ee9, 8165), (0x9eea, 8168), (0x9eef, 8169), (0x9ef9, 7216), (0x9efb, 7217), (0x9efc, 7218), (0x9efe, 8017), (0x9f0b, 8018),
+    (0x9f0d, 8019), (0x9f0e, 1979), (0x9f10, 5199), (0x9f13, 2291), (0x9f17, 5196), (0x9f19, 5562), (0x9f20, 3936), (0x9f22, 8170),
+    (0x9f2c, 8171), (0x9f2f, 8172), (0x9f37, 8174), (0x9f39, 8173), (0x9f3b, 1542), (0x9f3d, 8175), (0x9f3e, 8176), (0x9f44, 8177),
+    (0x9f50, 3552), (0x9f51, 7069), (0x9f7f, 1752), (0x9f80, 8008), (0x9f83, 8009), (0x9f84, 3075), (0x9f85, 8010), (0x9f86, 8011),
+    (0x9f87, 8012), (0x9f88, 8013), (0x9f89, 8014), (0x9f8a, 8015), (0x9f8b, 3668), (0x9f8c, 8016), (0x9f99, 3097), (0x9f9a, 2263),
+    (0x9f9b, 7215), (0x9f9f, 2329), (0x9fa0, 5326), (0xff01, 188), (0xff02, 189), (0xff03, 190), (0xff04, 70), (0xff05, 192),
+    (0xff06, 193), (0xff07, 194), (0xff08, 195), (0xff09, 196), (0xff0a, 197), (0xff0b, 198), (0xff0c, 199), (0xff0d, 200),
+    (0xff0e, 201), (0xff0f, 202), (0xff10, 203), (0xff11, 204), (0xff12, 205), (0xff13, 206), (0xff14, 207), (0xff15, 208),
+    (0xff16, 209), (0xff17, 210), (0xff18, 211), (0xff19, 212), (0xff1a, 213), (0xff1b, 214), (0xff1c, 215), (0xff1d, 216),
+    (0xff1e, 217), (0xff1f, 218), (0xff20, 219), (0xff21, 220), (0xff22, 221), (0xff23, 222), (0xff24, 223), (0xff25, 224),
+    (0xff26, 225), (0xff27, 226), (0xff28, 227), (0xff29, 228), (0xff2a, 229), (0xff2b, 230), (0xff2c, 231), (0xff2d, 232),
+    (0xff2e, 233), (0xff2f, 234), (0xff30, 235), (0xff31, 236), (0xff32, 237), (0xff33, 238), (0xff34, 239), (0xff35, 240),
+    (0xff36, 241), (0xff37, 242), (0xff38, 243), (0xff39, 244), (0xff3a, 245), (0xff3b, 246), (0xff3c, 247), (0xff3d, 248),
+    (0xff3e, 249), (0xff3f, 250), (0xff40, 251), (0xff41, 252), (0xff42, 253), (0xff43, 254), (0xff44, 255), (0xff45, 256),
+    (0xff46, 257), (0xff47, 258), (0xff48, 259), (0xff49, 260), (0xff4a, 261), (0xff4b, 262), (0xff4c, 263), (0xff4d, 264),
+    (0xff4e, 265), (0xff4f, 266), (0xff50, 267), (0xff51, 268), (0xff52, 269), (0xff53, 270), (0xff54, 271), (0xff55, 272),
+    (0xff56, 273), (0xff57, 274), (0xff58, 275), (0xff59, 276), (0xff5a, 277), (0xff5b, 278), (0xff5c, 279), (0xff5d, 280),
+    (0xff5e, 10), (0xffe0, 72), (0xffe1, 73), (0xffe3, 281), (0xffe5, 191),
+];
+
+/// Returns the character at `pointer`, if any.
+pub(crate) fn decode(pointer: usize) -> Option<char> {
+    match TO_UNICODE.get(pointer) {
+        Some(&0) | None => None,
+        Some(&code_point) => char::from_u32(u32::from(code_point)),
+    }
+}
+
+/// Returns the pointer of `character`, if it is part of the character set.
+pub(crate) fn encode(character: char) -> Option<usize> {
+    let code_point = u16::try_from(u32::from(character)).ok()?;
+
+    FROM_UNICODE
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| usize::from(FROM_UNICODE[index].1))
+}
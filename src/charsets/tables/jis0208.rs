@@ -0,0 +1,1446 @@
+//! The JIS X 0208 character set, where the pointer of the character at row `r` and cell `c` is
+//! `(r - 1) * 94 + (c - 1)`.
+
+use std::char;
+use std::convert::TryFrom;
+
+/// The Unicode code point of the character at each pointer, or 0 if the pointer is unassigned.
+#[rustfmt::skip]
+static TO_UNICODE: [u16; 8836] = [
+    0x3000, 0x3001, 0x3002, 0xff0c, 0xff0e, 0x30fb, 0xff1a, 0xff1b, 0xff1f, 0xff01, 0x309b, 0x309c, 0x00b4, 0xff40, 0x00a8, 0xff3e,
+    0xffe3, 0xff3f, 0x30fd, 0x30fe, 0x309d, 0x309e, 0x3003, 0x4edd, 0x3005, 0x3006, 0x3007, 0x30fc, 0x2015, 0x2010, 0xff0f, 0xff3c,
+    0x301c, 0x2016, 0xff5c, 0x2026, 0x2025, 0x2018, 0x2019, 0x201c, 0x201d, 0xff08, 0xff09, 0x3014, 0x3015, 0xff3b, 0xff3d, 0xff5b,
+    0xff5d, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c, 0x300d, 0x300e, 0x300f, 0x3010, 0x3011, 0xff0b, 0x2212, 0x00b1, 0x00d7, 0x00f7,
+    0xff1d, 0x2260, 0xff1c, 0xff1e, 0x2266, 0x2267, 0x221e, 0x2234, 0x2642, 0x2640, 0x00b0, 0x2032, 0x2033, 0x2103, 0xffe5, 0xff04,
+    0x00a2, 0x00a3, 0xff05, 0xff03, 0xff06, 0xff0a, 0xff20, 0x00a7, 0x2606, 0x2605, 0x25cb, 0x25cf, 0x25ce, 0x25c7, 0x25c6, 0x25a1,
+    0x25a0, 0x25b3, 0x25b2, 0x25bd, 0x25bc, 0x203b, 0x3012, 0x2192, 0x2190, 0x2191, 0x2193, 0x3013, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2208, 0x220b, 0x2286, 0x2287, 0x2282, 0x2283, 0x222a, 0x2229, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2227, 0x2228, 0x00ac, 0x21d2, 0x21d4, 0x2200, 0x2203, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2220, 0x22a5, 0x2312, 0x2202, 0x2207, 0x2261, 0x2252,
+    0x226a, 0x226b, 0x221a, 0x223d, 0x221d, 0x2235, 0x222b, 0x222c, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x212b,
+    0x2030, 0x266f, 0x266d, 0x266a, 0x2020, 0x2021, 0x00b6, 0x0000, 0x0000, 0x0000, 0x0000, 0x25ef, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xff10, 0xff11, 0xff12, 0xff13, 0xff14,
+    0xff15, 0xff16, 0xff17, 0xff18, 0xff19, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xff21, 0xff22, 0xff23, 0xff24,
+    0xff25, 0xff26, 0xff27, 0xff28, 0xff29, 0xff2a, 0xff2b, 0xff2c, 0xff2d, 0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34,
+    0xff35, 0xff36, 0xff37, 0xff38, 0xff39, 0xff3a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xff41, 0xff42, 0xff43, 0xff44,
+    0xff45, 0xff46, 0xff47, 0xff48, 0xff49, 0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f, 0xff50, 0xff51, 0xff52, 0xff53, 0xff54,
+    0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a, 0x0000, 0x0000, 0x0000, 0x0000, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046,
+    0x3047, 0x3048, 0x3049, 0x304a, 0x304b, 0x304c, 0x304d, 0x304e, 0x304f, 0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056,
+    0x3057, 0x3058, 0x3059, 0x305a, 0x305b, 0x305c, 0x305d, 0x305e, 0x305f, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066,
+    0x3067, 0x3068, 0x3069, 0x306a, 0x306b, 0x306c, 0x306d, 0x306e, 0x306f, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074, 0x3075, 0x3076,
+    0x3077, 0x3078, 0x3079, 0x307a, 0x307b, 0x307c, 0x307d, 0x307e, 0x307f, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086,
+    0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c, 0x308d, 0x308e, 0x308f, 0x3090, 0x3091, 0x3092, 0x3093, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x30a1, 0x30a2, 0x30a3, 0x30a4, 0x30a5, 0x30a6, 0x30a7, 0x30a8,
+    0x30a9, 0x30aa, 0x30ab, 0x30ac, 0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1, 0x30b2, 0x30b3, 0x30b4, 0x30b5, 0x30b6, 0x30b7, 0x30b8,
+    0x30b9, 0x30ba, 0x30bb, 0x30bc, 0x30bd, 0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3, 0x30c4, 0x30c5, 0x30c6, 0x30c7, 0x30c8,
+    0x30c9, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4, 0x30d5, 0x30d6, 0x30d7, 0x30d8,
+    0x30d9, 0x30da, 0x30db, 0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2, 0x30e3, 0x30e4, 0x30e5, 0x30e6, 0x30e7, 0x30e8,
+    0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x30f3, 0x30f4, 0x30f5, 0x30f6, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039a,
+    0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03a3, 0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8, 0x03b9, 0x03ba,
+    0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0401, 0x0416, 0x0417, 0x0418, 0x0419, 0x041a,
+    0x041b, 0x041c, 0x041d, 0x041e, 0x041f, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042a,
+    0x042b, 0x042c, 0x042d, 0x042e, 0x042f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043a,
+    0x043b, 0x043c, 0x043d, 0x043e, 0x043f, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044a,
+    0x044b, 0x044c, 0x044d, 0x044e, 0x044f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x2500, 0x2502, 0x250c, 0x2510, 0x2518, 0x2514, 0x251c, 0x252c, 0x2524, 0x2534, 0x253c, 0x2501, 0x2503, 0x250f,
+    0x2513, 0x251b, 0x2517, 0x2523, 0x2533, 0x252b, 0x253b, 0x254b, 0x2520, 0x252f, 0x2528, 0x2537, 0x253f, 0x251d, 0x2530, 0x2525,
+    0x2538, 0x2542, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x4e9c, 0x5516, 0x5a03, 0x963f, 0x54c0, 0x611b, 0x6328, 0x59f6, 0x9022, 0x8475, 0x831c, 0x7a50, 0x60aa, 0x63e1,
+    0x6e25, 0x65ed, 0x8466, 0x82a6, 0x9bf5, 0x6893, 0x5727, 0x65a1, 0x6271, 0x5b9b, 0x59d0, 0x867b, 0x98f4, 0x7d62, 0x7dbe, 0x9b8e,
+    0x6216, 0x7c9f, 0x88b7, 0x5b89, 0x5eb5, 0x6309, 0x6697, 0x6848, 0x95c7, 0x978d, 0x674f, 0x4ee5, 0x4f0a, 0x4f4d, 0x4f9d, 0x5049,
+    0x56f2, 0x5937, 0x59d4, 0x5a01, 0x5c09, 0x60df, 0x610f, 0x6170, 0x6613, 0x6905, 0x70ba, 0x754f, 0x7570, 0x79fb, 0x7dad, 0x7def,
+    0x80c3, 0x840e, 0x8863, 0x8b02, 0x9055, 0x907a, 0x533b, 0x4e95, 0x4ea5, 0x57df, 0x80b2, 0x90c1, 0x78ef, 0x4e00, 0x58f1, 0x6ea2,
+    0x9038, 0x7a32, 0x8328, 0x828b, 0x9c2f, 0x5141, 0x5370, 0x54bd, 0x54e1, 0x56e0, 0x59fb, 0x5f15, 0x98f2, 0x6deb, 0x80e4, 0x852d,
+    0x9662, 0x9670, 0x96a0, 0x97fb, 0x540b, 0x53f3, 0x5b87, 0x70cf, 0x7fbd, 0x8fc2, 0x96e8, 0x536f, 0x9d5c, 0x7aba, 0x4e11, 0x7893,
+    0x81fc, 0x6e26, 0x5618, 0x5504, 0x6b1d, 0x851a, 0x9c3b, 0x59e5, 0x53a9, 0x6d66, 0x74dc, 0x958f, 0x5642, 0x4e91, 0x904b, 0x96f2,
+    0x834f, 0x990c, 0x53e1, 0x55b6, 0x5b30, 0x5f71, 0x6620, 0x66f3, 0x6804, 0x6c38, 0x6cf3, 0x6d29, 0x745b, 0x76c8, 0x7a4e, 0x9834,
+    0x82f1, 0x885b, 0x8a60, 0x92ed, 0x6db2, 0x75ab, 0x76ca, 0x99c5, 0x60a6, 0x8b01, 0x8d8a, 0x95b2, 0x698e, 0x53ad, 0x5186, 0x5712,
+    0x5830, 0x5944, 0x5bb4, 0x5ef6, 0x6028, 0x63a9, 0x63f4, 0x6cbf, 0x6f14, 0x708e, 0x7114, 0x7159, 0x71d5, 0x733f, 0x7e01, 0x8276,
+    0x82d1, 0x8597, 0x9060, 0x925b, 0x9d1b, 0x5869, 0x65bc, 0x6c5a, 0x7525, 0x51f9, 0x592e, 0x5965, 0x5f80, 0x5fdc, 0x62bc, 0x65fa,
+    0x6a2a, 0x6b27, 0x6bb4, 0x738b, 0x7fc1, 0x8956, 0x9d2c, 0x9d0e, 0x9ec4, 0x5ca1, 0x6c96, 0x837b, 0x5104, 0x5c4b, 0x61b6, 0x81c6,
+    0x6876, 0x7261, 0x4e59, 0x4ffa, 0x5378, 0x6069, 0x6e29, 0x7a4f, 0x97f3, 0x4e0b, 0x5316, 0x4eee, 0x4f55, 0x4f3d, 0x4fa1, 0x4f73,
+    0x52a0, 0x53ef, 0x5609, 0x590f, 0x5ac1, 0x5bb6, 0x5be1, 0x79d1, 0x6687, 0x679c, 0x67b6, 0x6b4c, 0x6cb3, 0x706b, 0x73c2, 0x798d,
+    0x79be, 0x7a3c, 0x7b87, 0x82b1, 0x82db, 0x8304, 0x8377, 0x83ef, 0x83d3, 0x8766, 0x8ab2, 0x5629, 0x8ca8, 0x8fe6, 0x904e, 0x971e,
+    0x868a, 0x4fc4, 0x5ce8, 0x6211, 0x7259, 0x753b, 0x81e5, 0x82bd, 0x86fe, 0x8cc0, 0x96c5, 0x9913, 0x99d5, 0x4ecb, 0x4f1a, 0x89e3,
+    0x56de, 0x584a, 0x58ca, 0x5efb, 0x5feb, 0x602a, 0x6094, 0x6062, 0x61d0, 0x6212, 0x62d0, 0x6539, 0x9b41, 0x6666, 0x68b0, 0x6d77,
+    0x7070, 0x754c, 0x7686, 0x7d75, 0x82a5, 0x87f9, 0x958b, 0x968e, 0x8c9d, 0x51f1, 0x52be, 0x5916, 0x54b3, 0x5bb3, 0x5d16, 0x6168,
+    0x6982, 0x6daf, 0x788d, 0x84cb, 0x8857, 0x8a72, 0x93a7, 0x9ab8, 0x6d6c, 0x99a8, 0x86d9, 0x57a3, 0x67ff, 0x86ce, 0x920e, 0x5283,
+    0x5687, 0x5404, 0x5ed3, 0x62e1, 0x64b9, 0x683c, 0x6838, 0x6bbb, 0x7372, 0x78ba, 0x7a6b, 0x899a, 0x89d2, 0x8d6b, 0x8f03, 0x90ed,
+    0x95a3, 0x9694, 0x9769, 0x5b66, 0x5cb3, 0x697d, 0x984d, 0x984e, 0x639b, 0x7b20, 0x6a2b, 0x6a7f, 0x68b6, 0x9c0d, 0x6f5f, 0x5272,
+    0x559d, 0x6070, 0x62ec, 0x6d3b, 0x6e07, 0x6ed1, 0x845b, 0x8910, 0x8f44, 0x4e14, 0x9c39, 0x53f6, 0x691b, 0x6a3a, 0x9784, 0x682a,
+    0x515c, 0x7ac3, 0x84b2, 0x91dc, 0x938c, 0x565b, 0x9d28, 0x6822, 0x8305, 0x8431, 0x7ca5, 0x5208, 0x82c5, 0x74e6, 0x4e7e, 0x4f83,
+    0x51a0, 0x5bd2, 0x520a, 0x52d8, 0x52e7, 0x5dfb, 0x559a, 0x582a, 0x59e6, 0x5b8c, 0x5b98, 0x5bdb, 0x5e72, 0x5e79, 0x60a3, 0x611f,
+    0x6163, 0x61be, 0x63db, 0x6562, 0x67d1, 0x6853, 0x68fa, 0x6b3e, 0x6b53, 0x6c57, 0x6f22, 0x6f97, 0x6f45, 0x74b0, 0x7518, 0x76e3,
+    0x770b, 0x7aff, 0x7ba1, 0x7c21, 0x7de9, 0x7f36, 0x7ff0, 0x809d, 0x8266, 0x839e, 0x89b3, 0x8acc, 0x8cab, 0x9084, 0x9451, 0x9593,
+    0x9591, 0x95a2, 0x9665, 0x97d3, 0x9928, 0x8218, 0x4e38, 0x542b, 0x5cb8, 0x5dcc, 0x73a9, 0x764c, 0x773c, 0x5ca9, 0x7feb, 0x8d0b,
+    0x96c1, 0x9811, 0x9854, 0x9858, 0x4f01, 0x4f0e, 0x5371, 0x559c, 0x5668, 0x57fa, 0x5947, 0x5b09, 0x5bc4, 0x5c90, 0x5e0c, 0x5e7e,
+    0x5fcc, 0x63ee, 0x673a, 0x65d7, 0x65e2, 0x671f, 0x68cb, 0x68c4, 0x6a5f, 0x5e30, 0x6bc5, 0x6c17, 0x6c7d, 0x757f, 0x7948, 0x5b63,
+    0x7a00, 0x7d00, 0x5fbd, 0x898f, 0x8a18, 0x8cb4, 0x8d77, 0x8ecc, 0x8f1d, 0x98e2, 0x9a0e, 0x9b3c, 0x4e80, 0x507d, 0x5100, 0x5993,
+    0x5b9c, 0x622f, 0x6280, 0x64ec, 0x6b3a, 0x72a0, 0x7591, 0x7947, 0x7fa9, 0x87fb, 0x8abc, 0x8b70, 0x63ac, 0x83ca, 0x97a0, 0x5409,
+    0x5403, 0x55ab, 0x6854, 0x6a58, 0x8a70, 0x7827, 0x6775, 0x9ecd, 0x5374, 0x5ba2, 0x811a, 0x8650, 0x9006, 0x4e18, 0x4e45, 0x4ec7,
+    0x4f11, 0x53ca, 0x5438, 0x5bae, 0x5f13, 0x6025, 0x6551, 0x673d, 0x6c42, 0x6c72, 0x6ce3, 0x7078, 0x7403, 0x7a76, 0x7aae, 0x7b08,
+    0x7d1a, 0x7cfe, 0x7d66, 0x65e7, 0x725b, 0x53bb, 0x5c45, 0x5de8, 0x62d2, 0x62e0, 0x6319, 0x6e20, 0x865a, 0x8a31, 0x8ddd, 0x92f8,
+    0x6f01, 0x79a6, 0x9b5a, 0x4ea8, 0x4eab, 0x4eac, 0x4f9b, 0x4fa0, 0x50d1, 0x5147, 0x7af6, 0x5171, 0x51f6, 0x5354, 0x5321, 0x537f,
+    0x53eb, 0x55ac, 0x5883, 0x5ce1, 0x5f37, 0x5f4a, 0x602f, 0x6050, 0x606d, 0x631f, 0x6559, 0x6a4b, 0x6cc1, 0x72c2, 0x72ed, 0x77ef,
+    0x80f8, 0x8105, 0x8208, 0x854e, 0x90f7, 0x93e1, 0x97ff, 0x9957, 0x9a5a, 0x4ef0, 0x51dd, 0x5c2d, 0x6681, 0x696d, 0x5c40, 0x66f2,
+    0x6975, 0x7389, 0x6850, 0x7c81, 0x50c5, 0x52e4, 0x5747, 0x5dfe, 0x9326, 0x65a4, 0x6b23, 0x6b3d, 0x7434, 0x7981, 0x79bd, 0x7b4b,
+    0x7dca, 0x82b9, 0x83cc, 0x887f, 0x895f, 0x8b39, 0x8fd1, 0x91d1, 0x541f, 0x9280, 0x4e5d, 0x5036, 0x53e5, 0x533a, 0x72d7, 0x7396,
+    0x77e9, 0x82e6, 0x8eaf, 0x99c6, 0x99c8, 0x99d2, 0x5177, 0x611a, 0x865e, 0x55b0, 0x7a7a, 0x5076, 0x5bd3, 0x9047, 0x9685, 0x4e32,
+    0x6adb, 0x91e7, 0x5c51, 0x5c48, 0x6398, 0x7a9f, 0x6c93, 0x9774, 0x8f61, 0x7aaa, 0x718a, 0x9688, 0x7c82, 0x6817, 0x7e70, 0x6851,
+    0x936c, 0x52f2, 0x541b, 0x85ab, 0x8a13, 0x7fa4, 0x8ecd, 0x90e1, 0x5366, 0x8888, 0x7941, 0x4fc2, 0x50be, 0x5211, 0x5144, 0x5553,
+    0x572d, 0x73ea, 0x578b, 0x5951, 0x5f62, 0x5f84, 0x6075, 0x6176, 0x6167, 0x61a9, 0x63b2, 0x643a, 0x656c, 0x666f, 0x6842, 0x6e13,
+    0x7566, 0x7a3d, 0x7cfb, 0x7d4c, 0x7d99, 0x7e4b, 0x7f6b, 0x830e, 0x834a, 0x86cd, 0x8a08, 0x8a63, 0x8b66, 0x8efd, 0x981a, 0x9d8f,
+    0x82b8, 0x8fce, 0x9be8, 0x5287, 0x621f, 0x6483, 0x6fc0, 0x9699, 0x6841, 0x5091, 0x6b20, 0x6c7a, 0x6f54, 0x7a74, 0x7d50, 0x8840,
+    0x8a23, 0x6708, 0x4ef6, 0x5039, 0x5026, 0x5065, 0x517c, 0x5238, 0x5263, 0x55a7, 0x570f, 0x5805, 0x5acc, 0x5efa, 0x61b2, 0x61f8,
+    0x62f3, 0x6372, 0x691c, 0x6a29, 0x727d, 0x72ac, 0x732e, 0x7814, 0x786f, 0x7d79, 0x770c, 0x80a9, 0x898b, 0x8b19, 0x8ce2, 0x8ed2,
+    0x9063, 0x9375, 0x967a, 0x9855, 0x9a13, 0x9e78, 0x5143, 0x539f, 0x53b3, 0x5e7b, 0x5f26, 0x6e1b, 0x6e90, 0x7384, 0x73fe, 0x7d43,
+    0x8237, 0x8a00, 0x8afa, 0x9650, 0x4e4e, 0x500b, 0x53e4, 0x547c, 0x56fa, 0x59d1, 0x5b64, 0x5df1, 0x5eab, 0x5f27, 0x6238, 0x6545,
+    0x67af, 0x6e56, 0x72d0, 0x7cca, 0x88b4, 0x80a1, 0x80e1, 0x83f0, 0x864e, 0x8a87, 0x8de8, 0x9237, 0x96c7, 0x9867, 0x9f13, 0x4e94,
+    0x4e92, 0x4f0d, 0x5348, 0x5449, 0x543e, 0x5a2f, 0x5f8c, 0x5fa1, 0x609f, 0x68a7, 0x6a8e, 0x745a, 0x7881, 0x8a9e, 0x8aa4, 0x8b77,
+    0x9190, 0x4e5e, 0x9bc9, 0x4ea4, 0x4f7c, 0x4faf, 0x5019, 0x5016, 0x5149, 0x516c, 0x529f, 0x52b9, 0x52fe, 0x539a, 0x53e3, 0x5411,
+    0x540e, 0x5589, 0x5751, 0x57a2, 0x597d, 0x5b54, 0x5b5d, 0x5b8f, 0x5de5, 0x5de7, 0x5df7, 0x5e78, 0x5e83, 0x5e9a, 0x5eb7, 0x5f18,
+    0x6052, 0x614c, 0x6297, 0x62d8, 0x63a7, 0x653b, 0x6602, 0x6643, 0x66f4, 0x676d, 0x6821, 0x6897, 0x69cb, 0x6c5f, 0x6d2a, 0x6d69,
+    0x6e2f, 0x6e9d, 0x7532, 0x7687, 0x786c, 0x7a3f, 0x7ce0, 0x7d05, 0x7d18, 0x7d5e, 0x7db1, 0x8015, 0x8003, 0x80af, 0x80b1, 0x8154,
+    0x818f, 0x822a, 0x8352, 0x884c, 0x8861, 0x8b1b, 0x8ca2, 0x8cfc, 0x90ca, 0x9175, 0x9271, 0x783f, 0x92fc, 0x95a4, 0x964d, 0x9805,
+    0x9999, 0x9ad8, 0x9d3b, 0x525b, 0x52ab, 0x53f7, 0x5408, 0x58d5, 0x62f7, 0x6fe0, 0x8c6a, 0x8f5f, 0x9eb9, 0x514b, 0x523b, 0x544a,
+    0x56fd, 0x7a40, 0x9177, 0x9d60, 0x9ed2, 0x7344, 0x6f09, 0x8170, 0x7511, 0x5ffd, 0x60da, 0x9aa8, 0x72db, 0x8fbc, 0x6b64, 0x9803,
+    0x4eca, 0x56f0, 0x5764, 0x58be, 0x5a5a, 0x6068, 0x61c7, 0x660f, 0x6606, 0x6839, 0x68b1, 0x6df7, 0x75d5, 0x7d3a, 0x826e, 0x9b42,
+    0x4e9b, 0x4f50, 0x53c9, 0x5506, 0x5d6f, 0x5de6, 0x5dee, 0x67fb, 0x6c99, 0x7473, 0x7802, 0x8a50, 0x9396, 0x88df, 0x5750, 0x5ea7,
+    0x632b, 0x50b5, 0x50ac, 0x518d, 0x6700, 0x54c9, 0x585e, 0x59bb, 0x5bb0, 0x5f69, 0x624d, 0x63a1, 0x683d, 0x6b73, 0x6e08, 0x707d,
+    0x91c7, 0x7280, 0x7815, 0x7826, 0x796d, 0x658e, 0x7d30, 0x83dc, 0x88c1, 0x8f09, 0x969b, 0x5264, 0x5728, 0x6750, 0x7f6a, 0x8ca1,
+    0x51b4, 0x5742, 0x962a, 0x583a, 0x698a, 0x80b4, 0x54b2, 0x5d0e, 0x57fc, 0x7895, 0x9dfa, 0x4f5c, 0x524a, 0x548b, 0x643e, 0x6628,
+    0x6714, 0x67f5, 0x7a84, 0x7b56, 0x7d22, 0x932f, 0x685c, 0x9bad, 0x7b39, 0x5319, 0x518a, 0x5237, 0x5bdf, 0x62f6, 0x64ae, 0x64e6,
+    0x672d, 0x6bba, 0x85a9, 0x96d1, 0x7690, 0x9bd6, 0x634c, 0x9306, 0x9bab, 0x76bf, 0x6652, 0x4e09, 0x5098, 0x53c2, 0x5c71, 0x60e8,
+    0x6492, 0x6563, 0x685f, 0x71e6, 0x73ca, 0x7523, 0x7b97, 0x7e82, 0x8695, 0x8b83, 0x8cdb, 0x9178, 0x9910, 0x65ac, 0x66ab, 0x6b8b,
+    0x4ed5, 0x4ed4, 0x4f3a, 0x4f7f, 0x523a, 0x53f8, 0x53f2, 0x55e3, 0x56db, 0x58eb, 0x59cb, 0x59c9, 0x59ff, 0x5b50, 0x5c4d, 0x5e02,
+    0x5e2b, 0x5fd7, 0x601d, 0x6307, 0x652f, 0x5b5c, 0x65af, 0x65bd, 0x65e8, 0x679d, 0x6b62, 0x6b7b, 0x6c0f, 0x7345, 0x7949, 0x79c1,
+    0x7cf8, 0x7d19, 0x7d2b, 0x80a2, 0x8102, 0x81f3, 0x8996, 0x8a5e, 0x8a69, 0x8a66, 0x8a8c, 0x8aee, 0x8cc7, 0x8cdc, 0x96cc, 0x98fc,
+    0x6b6f, 0x4e8b, 0x4f3c, 0x4f8d, 0x5150, 0x5b57, 0x5bfa, 0x6148, 0x6301, 0x6642, 0x6b21, 0x6ecb, 0x6cbb, 0x723e, 0x74bd, 0x75d4,
+    0x78c1, 0x793a, 0x800c, 0x8033, 0x81ea, 0x8494, 0x8f9e, 0x6c50, 0x9e7f, 0x5f0f, 0x8b58, 0x9d2b, 0x7afa, 0x8ef8, 0x5b8d, 0x96eb,
+    0x4e03, 0x53f1, 0x57f7, 0x5931, 0x5ac9, 0x5ba4, 0x6089, 0x6e7f, 0x6f06, 0x75be, 0x8cea, 0x5b9f, 0x8500, 0x7be0, 0x5072, 0x67f4,
+    0x829d, 0x5c61, 0x854a, 0x7e1e, 0x820e, 0x5199, 0x5c04, 0x6368, 0x8d66, 0x659c, 0x716e, 0x793e, 0x7d17, 0x8005, 0x8b1d, 0x8eca,
+    0x906e, 0x86c7, 0x90aa, 0x501f, 0x52fa, 0x5c3a, 0x6753, 0x707c, 0x7235, 0x914c, 0x91c8, 0x932b, 0x82e5, 0x5bc2, 0x5f31, 0x60f9,
+    0x4e3b, 0x53d6, 0x5b88, 0x624b, 0x6731, 0x6b8a, 0x72e9, 0x73e0, 0x7a2e, 0x816b, 0x8da3, 0x9152, 0x9996, 0x5112, 0x53d7, 0x546a,
+    0x5bff, 0x6388, 0x6a39, 0x7dac, 0x9700, 0x56da, 0x53ce, 0x5468, 0x5b97, 0x5c31, 0x5dde, 0x4fee, 0x6101, 0x62fe, 0x6d32, 0x79c0,
+    0x79cb, 0x7d42, 0x7e4d, 0x7fd2, 0x81ed, 0x821f, 0x8490, 0x8846, 0x8972, 0x8b90, 0x8e74, 0x8f2f, 0x9031, 0x914b, 0x916c, 0x96c6,
+    0x919c, 0x4ec0, 0x4f4f, 0x5145, 0x5341, 0x5f93, 0x620e, 0x67d4, 0x6c41, 0x6e0b, 0x7363, 0x7e26, 0x91cd, 0x9283, 0x53d4, 0x5919,
+    0x5bbf, 0x6dd1, 0x795d, 0x7e2e, 0x7c9b, 0x587e, 0x719f, 0x51fa, 0x8853, 0x8ff0, 0x4fca, 0x5cfb, 0x6625, 0x77ac, 0x7ae3, 0x821c,
+    0x99ff, 0x51c6, 0x5faa, 0x65ec, 0x696f, 0x6b89, 0x6df3, 0x6e96, 0x6f64, 0x76fe, 0x7d14, 0x5de1, 0x9075, 0x9187, 0x9806, 0x51e6,
+    0x521d, 0x6240, 0x6691, 0x66d9, 0x6e1a, 0x5eb6, 0x7dd2, 0x7f72, 0x66f8, 0x85af, 0x85f7, 0x8af8, 0x52a9, 0x53d9, 0x5973, 0x5e8f,
+    0x5f90, 0x6055, 0x92e4, 0x9664, 0x50b7, 0x511f, 0x52dd, 0x5320, 0x5347, 0x53ec, 0x54e8, 0x5546, 0x5531, 0x5617, 0x5968, 0x59be,
+    0x5a3c, 0x5bb5, 0x5c06, 0x5c0f, 0x5c11, 0x5c1a, 0x5e84, 0x5e8a, 0x5ee0, 0x5f70, 0x627f, 0x6284, 0x62db, 0x638c, 0x6377, 0x6607,
+    0x660c, 0x662d, 0x6676, 0x677e, 0x68a2, 0x6a1f, 0x6a35, 0x6cbc, 0x6d88, 0x6e09, 0x6e58, 0x713c, 0x7126, 0x7167, 0x75c7, 0x7701,
+    0x785d, 0x7901, 0x7965, 0x79f0, 0x7ae0, 0x7b11, 0x7ca7, 0x7d39, 0x8096, 0x83d6, 0x848b, 0x8549, 0x885d, 0x88f3, 0x8a1f, 0x8a3c,
+    0x8a54, 0x8a73, 0x8c61, 0x8cde, 0x91a4, 0x9266, 0x937e, 0x9418, 0x969c, 0x9798, 0x4e0a, 0x4e08, 0x4e1e, 0x4e57, 0x5197, 0x5270,
+    0x57ce, 0x5834, 0x58cc, 0x5b22, 0x5e38, 0x60c5, 0x64fe, 0x6761, 0x6756, 0x6d44, 0x72b6, 0x7573, 0x7a63, 0x84b8, 0x8b72, 0x91b8,
+    0x9320, 0x5631, 0x57f4, 0x98fe, 0x62ed, 0x690d, 0x6b96, 0x71ed, 0x7e54, 0x8077, 0x8272, 0x89e6, 0x98df, 0x8755, 0x8fb1, 0x5c3b,
+    0x4f38, 0x4fe1, 0x4fb5, 0x5507, 0x5a20, 0x5bdd, 0x5be9, 0x5fc3, 0x614e, 0x632f, 0x65b0, 0x664b, 0x68ee, 0x699b, 0x6d78, 0x6df1,
+    0x7533, 0x75b9, 0x771f, 0x795e, 0x79e6, 0x7d33, 0x81e3, 0x82af, 0x85aa, 0x89aa, 0x8a3a, 0x8eab, 0x8f9b, 0x9032, 0x91dd, 0x9707,
+    0x4eba, 0x4ec1, 0x5203, 0x5875, 0x58ec, 0x5c0b, 0x751a, 0x5c3d, 0x814e, 0x8a0a, 0x8fc5, 0x9663, 0x976d, 0x7b25, 0x8acf, 0x9808,
+    0x9162, 0x56f3, 0x53a8, 0x9017, 0x5439, 0x5782, 0x5e25, 0x63a8, 0x6c34, 0x708a, 0x7761, 0x7c8b, 0x7fe0, 0x8870, 0x9042, 0x9154,
+    0x9310, 0x9318, 0x968f, 0x745e, 0x9ac4, 0x5d07, 0x5d69, 0x6570, 0x67a2, 0x8da8, 0x96db, 0x636e, 0x6749, 0x6919, 0x83c5, 0x9817,
+    0x96c0, 0x88fe, 0x6f84, 0x647a, 0x5bf8, 0x4e16, 0x702c, 0x755d, 0x662f, 0x51c4, 0x5236, 0x52e2, 0x59d3, 0x5f81, 0x6027, 0x6210,
+    0x653f, 0x6574, 0x661f, 0x6674, 0x68f2, 0x6816, 0x6b63, 0x6e05, 0x7272, 0x751f, 0x76db, 0x7cbe, 0x8056, 0x58f0, 0x88fd, 0x897f,
+    0x8aa0, 0x8a93, 0x8acb, 0x901d, 0x9192, 0x9752, 0x9759, 0x6589, 0x7a0e, 0x8106, 0x96bb, 0x5e2d, 0x60dc, 0x621a, 0x65a5, 0x6614,
+    0x6790, 0x77f3, 0x7a4d, 0x7c4d, 0x7e3e, 0x810a, 0x8cac, 0x8d64, 0x8de1, 0x8e5f, 0x78a9, 0x5207, 0x62d9, 0x63a5, 0x6442, 0x6298,
+    0x8a2d, 0x7a83, 0x7bc0, 0x8aac, 0x96ea, 0x7d76, 0x820c, 0x8749, 0x4ed9, 0x5148, 0x5343, 0x5360, 0x5ba3, 0x5c02, 0x5c16, 0x5ddd,
+    0x6226, 0x6247, 0x64b0, 0x6813, 0x6834, 0x6cc9, 0x6d45, 0x6d17, 0x67d3, 0x6f5c, 0x714e, 0x717d, 0x65cb, 0x7a7f, 0x7bad, 0x7dda,
+    0x7e4a, 0x7fa8, 0x817a, 0x821b, 0x8239, 0x85a6, 0x8a6e, 0x8cce, 0x8df5, 0x9078, 0x9077, 0x92ad, 0x9291, 0x9583, 0x9bae, 0x524d,
+    0x5584, 0x6f38, 0x7136, 0x5168, 0x7985, 0x7e55, 0x81b3, 0x7cce, 0x564c, 0x5851, 0x5ca8, 0x63aa, 0x66fe, 0x66fd, 0x695a, 0x72d9,
+    0x758f, 0x758e, 0x790e, 0x7956, 0x79df, 0x7c97, 0x7d20, 0x7d44, 0x8607, 0x8a34, 0x963b, 0x9061, 0x9f20, 0x50e7, 0x5275, 0x53cc,
+    0x53e2, 0x5009, 0x55aa, 0x58ee, 0x594f, 0x723d, 0x5b8b, 0x5c64, 0x531d, 0x60e3, 0x60f3, 0x635c, 0x6383, 0x633f, 0x63bb, 0x64cd,
+    0x65e9, 0x66f9, 0x5de3, 0x69cd, 0x69fd, 0x6f15, 0x71e5, 0x4e89, 0x75e9, 0x76f8, 0x7a93, 0x7cdf, 0x7dcf, 0x7d9c, 0x8061, 0x8349,
+    0x8358, 0x846c, 0x84bc, 0x85fb, 0x88c5, 0x8d70, 0x9001, 0x906d, 0x9397, 0x971c, 0x9a12, 0x50cf, 0x5897, 0x618e, 0x81d3, 0x8535,
+    0x8d08, 0x9020, 0x4fc3, 0x5074, 0x5247, 0x5373, 0x606f, 0x6349, 0x675f, 0x6e2c, 0x8db3, 0x901f, 0x4fd7, 0x5c5e, 0x8cca, 0x65cf,
+    0x7d9a, 0x5352, 0x8896, 0x5176, 0x63c3, 0x5b58, 0x5b6b, 0x5c0a, 0x640d, 0x6751, 0x905c, 0x4ed6, 0x591a, 0x592a, 0x6c70, 0x8a51,
+    0x553e, 0x5815, 0x59a5, 0x60f0, 0x6253, 0x67c1, 0x8235, 0x6955, 0x9640, 0x99c4, 0x9a28, 0x4f53, 0x5806, 0x5bfe, 0x8010, 0x5cb1,
+    0x5e2f, 0x5f85, 0x6020, 0x614b, 0x6234, 0x66ff, 0x6cf0, 0x6ede, 0x80ce, 0x817f, 0x82d4, 0x888b, 0x8cb8, 0x9000, 0x902e, 0x968a,
+    0x9edb, 0x9bdb, 0x4ee3, 0x53f0, 0x5927, 0x7b2c, 0x918d, 0x984c, 0x9df9, 0x6edd, 0x7027, 0x5353, 0x5544, 0x5b85, 0x6258, 0x629e,
+    0x62d3, 0x6ca2, 0x6fef, 0x7422, 0x8a17, 0x9438, 0x6fc1, 0x8afe, 0x8338, 0x51e7, 0x86f8, 0x53ea, 0x53e9, 0x4f46, 0x9054, 0x8fb0,
+    0x596a, 0x8131, 0x5dfd, 0x7aea, 0x8fbf, 0x68da, 0x8c37, 0x72f8, 0x9c48, 0x6a3d, 0x8ab0, 0x4e39, 0x5358, 0x5606, 0x5766, 0x62c5,
+    0x63a2, 0x65e6, 0x6b4e, 0x6de1, 0x6e5b, 0x70ad, 0x77ed, 0x7aef, 0x7baa, 0x7dbb, 0x803d, 0x80c6, 0x86cb, 0x8a95, 0x935b, 0x56e3,
+    0x58c7, 0x5f3e, 0x65ad, 0x6696, 0x6a80, 0x6bb5, 0x7537, 0x8ac7, 0x5024, 0x77e5, 0x5730, 0x5f1b, 0x6065, 0x667a, 0x6c60, 0x75f4,
+    0x7a1a, 0x7f6e, 0x81f4, 0x8718, 0x9045, 0x99b3, 0x7bc9, 0x755c, 0x7af9, 0x7b51, 0x84c4, 0x9010, 0x79e9, 0x7a92, 0x8336, 0x5ae1,
+    0x7740, 0x4e2d, 0x4ef2, 0x5b99, 0x5fe0, 0x62bd, 0x663c, 0x67f1, 0x6ce8, 0x866b, 0x8877, 0x8a3b, 0x914e, 0x92f3, 0x99d0, 0x6a17,
+    0x7026, 0x732a, 0x82e7, 0x8457, 0x8caf, 0x4e01, 0x5146, 0x51cb, 0x558b, 0x5bf5, 0x5e16, 0x5e33, 0x5e81, 0x5f14, 0x5f35, 0x5f6b,
+    0x5fb4, 0x61f2, 0x6311, 0x66a2, 0x671d, 0x6f6e, 0x7252, 0x753a, 0x773a, 0x8074, 0x8139, 0x8178, 0x8776, 0x8abf, 0x8adc, 0x8d85,
+    0x8df3, 0x929a, 0x9577, 0x9802, 0x9ce5, 0x52c5, 0x6357, 0x76f4, 0x6715, 0x6c88, 0x73cd, 0x8cc3, 0x93ae, 0x9673, 0x6d25, 0x589c,
+    0x690e, 0x69cc, 0x8ffd, 0x939a, 0x75db, 0x901a, 0x585a, 0x6802, 0x63b4, 0x69fb, 0x4f43, 0x6f2c, 0x67d8, 0x8fbb, 0x8526, 0x7db4,
+    0x9354, 0x693f, 0x6f70, 0x576a, 0x58f7, 0x5b2c, 0x7d2c, 0x722a, 0x540a, 0x91e3, 0x9db4, 0x4ead, 0x4f4e, 0x505c, 0x5075, 0x5243,
+    0x8c9e, 0x5448, 0x5824, 0x5b9a, 0x5e1d, 0x5e95, 0x5ead, 0x5ef7, 0x5f1f, 0x608c, 0x62b5, 0x633a, 0x63d0, 0x68af, 0x6c40, 0x7887,
+    0x798e, 0x7a0b, 0x7de0, 0x8247, 0x8a02, 0x8ae6, 0x8e44, 0x9013, 0x90b8, 0x912d, 0x91d8, 0x9f0e, 0x6ce5, 0x6458, 0x64e2, 0x6575,
+    0x6ef4, 0x7684, 0x7b1b, 0x9069, 0x93d1, 0x6eba, 0x54f2, 0x5fb9, 0x64a4, 0x8f4d, 0x8fed, 0x9244, 0x5178, 0x586b, 0x5929, 0x5c55,
+    0x5e97, 0x6dfb, 0x7e8f, 0x751c, 0x8cbc, 0x8ee2, 0x985b, 0x70b9, 0x4f1d, 0x6bbf, 0x6fb1, 0x7530, 0x96fb, 0x514e, 0x5410, 0x5835,
+    0x5857, 0x59ac, 0x5c60, 0x5f92, 0x6597, 0x675c, 0x6e21, 0x767b, 0x83df, 0x8ced, 0x9014, 0x90fd, 0x934d, 0x7825, 0x783a, 0x52aa,
+    0x5ea6, 0x571f, 0x5974, 0x6012, 0x5012, 0x515a, 0x51ac, 0x51cd, 0x5200, 0x5510, 0x5854, 0x5858, 0x5957, 0x5b95, 0x5cf6, 0x5d8b,
+    0x60bc, 0x6295, 0x642d, 0x6771, 0x6843, 0x68bc, 0x68df, 0x76d7, 0x6dd8, 0x6e6f, 0x6d9b, 0x706f, 0x71c8, 0x5f53, 0x75d8, 0x7977,
+    0x7b49, 0x7b54, 0x7b52, 0x7cd6, 0x7d71, 0x5230, 0x8463, 0x8569, 0x85e4, 0x8a0e, 0x8b04, 0x8c46, 0x8e0f, 0x9003, 0x900f, 0x9419,
+    0x9676, 0x982d, 0x9a30, 0x95d8, 0x50cd, 0x52d5, 0x540c, 0x5802, 0x5c0e, 0x61a7, 0x649e, 0x6d1e, 0x77b3, 0x7ae5, 0x80f4, 0x8404,
+    0x9053, 0x9285, 0x5ce0, 0x9d07, 0x533f, 0x5f97, 0x5fb3, 0x6d9c, 0x7279, 0x7763, 0x79bf, 0x7be4, 0x6bd2, 0x72ec, 0x8aad, 0x6803,
+    0x6a61, 0x51f8, 0x7a81, 0x6934, 0x5c4a, 0x9cf6, 0x82eb, 0x5bc5, 0x9149, 0x701e, 0x5678, 0x5c6f, 0x60c7, 0x6566, 0x6c8c, 0x8c5a,
+    0x9041, 0x9813, 0x5451, 0x66c7, 0x920d, 0x5948, 0x90a3, 0x5185, 0x4e4d, 0x51ea, 0x8599, 0x8b0e, 0x7058, 0x637a, 0x934b, 0x6962,
+    0x99b4, 0x7e04, 0x7577, 0x5357, 0x6960, 0x8edf, 0x96e3, 0x6c5d, 0x4e8c, 0x5c3c, 0x5f10, 0x8fe9, 0x5302, 0x8cd1, 0x8089, 0x8679,
+    0x5eff, 0x65e5, 0x4e73, 0x5165, 0x5982, 0x5c3f, 0x97ee, 0x4efb, 0x598a, 0x5fcd, 0x8a8d, 0x6fe1, 0x79b0, 0x7962, 0x5be7, 0x8471,
+    0x732b, 0x71b1, 0x5e74, 0x5ff5, 0x637b, 0x649a, 0x71c3, 0x7c98, 0x4e43, 0x5efc, 0x4e4b, 0x57dc, 0x56a2, 0x60a9, 0x6fc3, 0x7d0d,
+    0x80fd, 0x8133, 0x81bf, 0x8fb2, 0x8997, 0x86a4, 0x5df4, 0x628a, 0x64ad, 0x8987, 0x6777, 0x6ce2, 0x6d3e, 0x7436, 0x7834, 0x5a46,
+    0x7f75, 0x82ad, 0x99ac, 0x4ff3, 0x5ec3, 0x62dd, 0x6392, 0x6557, 0x676f, 0x76c3, 0x724c, 0x80cc, 0x80ba, 0x8f29, 0x914d, 0x500d,
+    0x57f9, 0x5a92, 0x6885, 0x6973, 0x7164, 0x72fd, 0x8cb7, 0x58f2, 0x8ce0, 0x966a, 0x9019, 0x877f, 0x79e4, 0x77e7, 0x8429, 0x4f2f,
+    0x5265, 0x535a, 0x62cd, 0x67cf, 0x6cca, 0x767d, 0x7b94, 0x7c95, 0x8236, 0x8584, 0x8feb, 0x66dd, 0x6f20, 0x7206, 0x7e1b, 0x83ab,
+    0x99c1, 0x9ea6, 0x51fd, 0x7bb1, 0x7872, 0x7bb8, 0x8087, 0x7b48, 0x6ae8, 0x5e61, 0x808c, 0x7551, 0x7560, 0x516b, 0x9262, 0x6e8c,
+    0x767a, 0x9197, 0x9aea, 0x4f10, 0x7f70, 0x629c, 0x7b4f, 0x95a5, 0x9ce9, 0x567a, 0x5859, 0x86e4, 0x96bc, 0x4f34, 0x5224, 0x534a,
+    0x53cd, 0x53db, 0x5e06, 0x642c, 0x6591, 0x677f, 0x6c3e, 0x6c4e, 0x7248, 0x72af, 0x73ed, 0x7554, 0x7e41, 0x822c, 0x85e9, 0x8ca9,
+    0x7bc4, 0x91c6, 0x7169, 0x9812, 0x98ef, 0x633d, 0x6669, 0x756a, 0x76e4, 0x78d0, 0x8543, 0x86ee, 0x532a, 0x5351, 0x5426, 0x5983,
+    0x5e87, 0x5f7c, 0x60b2, 0x6249, 0x6279, 0x62ab, 0x6590, 0x6bd4, 0x6ccc, 0x75b2, 0x76ae, 0x7891, 0x79d8, 0x7dcb, 0x7f77, 0x80a5,
+    0x88ab, 0x8ab9, 0x8cbb, 0x907f, 0x975e, 0x98db, 0x6a0b, 0x7c38, 0x5099, 0x5c3e, 0x5fae, 0x6787, 0x6bd8, 0x7435, 0x7709, 0x7f8e,
+    0x9f3b, 0x67ca, 0x7a17, 0x5339, 0x758b, 0x9aed, 0x5f66, 0x819d, 0x83f1, 0x8098, 0x5f3c, 0x5fc5, 0x7562, 0x7b46, 0x903c, 0x6867,
+    0x59eb, 0x5a9b, 0x7d10, 0x767e, 0x8b2c, 0x4ff5, 0x5f6a, 0x6a19, 0x6c37, 0x6f02, 0x74e2, 0x7968, 0x8868, 0x8a55, 0x8c79, 0x5edf,
+    0x63cf, 0x75c5, 0x79d2, 0x82d7, 0x9328, 0x92f2, 0x849c, 0x86ed, 0x9c2d, 0x54c1, 0x5f6c, 0x658c, 0x6d5c, 0x7015, 0x8ca7, 0x8cd3,
+    0x983b, 0x654f, 0x74f6, 0x4e0d, 0x4ed8, 0x57e0, 0x592b, 0x5a66, 0x5bcc, 0x51a8, 0x5e03, 0x5e9c, 0x6016, 0x6276, 0x6577, 0x65a7,
+    0x666e, 0x6d6e, 0x7236, 0x7b26, 0x8150, 0x819a, 0x8299, 0x8b5c, 0x8ca0, 0x8ce6, 0x8d74, 0x961c, 0x9644, 0x4fae, 0x64ab, 0x6b66,
+    0x821e, 0x8461, 0x856a, 0x90e8, 0x5c01, 0x6953, 0x98a8, 0x847a, 0x8557, 0x4f0f, 0x526f, 0x5fa9, 0x5e45, 0x670d, 0x798f, 0x8179,
+    0x8907, 0x8986, 0x6df5, 0x5f17, 0x6255, 0x6cb8, 0x4ecf, 0x7269, 0x9b92, 0x5206, 0x543b, 0x5674, 0x58b3, 0x61a4, 0x626e, 0x711a,
+    0x596e, 0x7c89, 0x7cde, 0x7d1b, 0x96f0, 0x6587, 0x805e, 0x4e19, 0x4f75, 0x5175, 0x5840, 0x5e63, 0x5e73, 0x5f0a, 0x67c4, 0x4e26,
+    0x853d, 0x9589, 0x965b, 0x7c73, 0x9801, 0x50fb, 0x58c1, 0x7656, 0x78a7, 0x5225, 0x77a5, 0x8511, 0x7b86, 0x504f, 0x5909, 0x7247,
+    0x7bc7, 0x7de8, 0x8fba, 0x8fd4, 0x904d, 0x4fbf, 0x52c9, 0x5a29, 0x5f01, 0x97ad, 0x4fdd, 0x8217, 0x92ea, 0x5703, 0x6355, 0x6b69,
+    0x752b, 0x88dc, 0x8f14, 0x7a42, 0x52df, 0x5893, 0x6155, 0x620a, 0x66ae, 0x6bcd, 0x7c3f, 0x83e9, 0x5023, 0x4ff8, 0x5305, 0x5446,
+    0x5831, 0x5949, 0x5b9d, 0x5cf0, 0x5cef, 0x5d29, 0x5e96, 0x62b1, 0x6367, 0x653e, 0x65b9, 0x670b, 0x6cd5, 0x6ce1, 0x70f9, 0x7832,
+    0x7e2b, 0x80de, 0x82b3, 0x840c, 0x84ec, 0x8702, 0x8912, 0x8a2a, 0x8c4a, 0x90a6, 0x92d2, 0x98fd, 0x9cf3, 0x9d6c, 0x4e4f, 0x4ea1,
+    0x508d, 0x5256, 0x574a, 0x59a8, 0x5e3d, 0x5fd8, 0x5fd9, 0x623f, 0x66b4, 0x671b, 0x67d0, 0x68d2, 0x5192, 0x7d21, 0x80aa, 0x81a8,
+    0x8b00, 0x8c8c, 0x8cbf, 0x927e, 0x9632, 0x5420, 0x982c, 0x5317, 0x50d5, 0x535c, 0x58a8, 0x64b2, 0x6734, 0x7267, 0x7766, 0x7a46,
+    0x91e6, 0x52c3, 0x6ca1, 0x6b86, 0x5800, 0x5e4c, 0x5954, 0x672c, 0x7ffb, 0x51e1, 0x76c6, 0x6469, 0x78e8, 0x9b54, 0x9ebb, 0x57cb,
+    0x59b9, 0x6627, 0x679a, 0x6bce, 0x54e9, 0x69d9, 0x5e55, 0x819c, 0x6795, 0x9baa, 0x67fe, 0x9c52, 0x685d, 0x4ea6, 0x4fe3, 0x53c8,
+    0x62b9, 0x672b, 0x6cab, 0x8fc4, 0x4fad, 0x7e6d, 0x9ebf, 0x4e07, 0x6162, 0x6e80, 0x6f2b, 0x8513, 0x5473, 0x672a, 0x9b45, 0x5df3,
+    0x7b95, 0x5cac, 0x5bc6, 0x871c, 0x6e4a, 0x84d1, 0x7a14, 0x8108, 0x5999, 0x7c8d, 0x6c11, 0x7720, 0x52d9, 0x5922, 0x7121, 0x725f,
+    0x77db, 0x9727, 0x9d61, 0x690b, 0x5a7f, 0x5a18, 0x51a5, 0x540d, 0x547d, 0x660e, 0x76df, 0x8ff7, 0x9298, 0x9cf4, 0x59ea, 0x725d,
+    0x6ec5, 0x514d, 0x68c9, 0x7dbf, 0x7dec, 0x9762, 0x9eba, 0x6478, 0x6a21, 0x8302, 0x5984, 0x5b5f, 0x6bdb, 0x731b, 0x76f2, 0x7db2,
+    0x8017, 0x8499, 0x5132, 0x6728, 0x9ed9, 0x76ee, 0x6762, 0x52ff, 0x9905, 0x5c24, 0x623b, 0x7c7e, 0x8cb0, 0x554f, 0x60b6, 0x7d0b,
+    0x9580, 0x5301, 0x4e5f, 0x51b6, 0x591c, 0x723a, 0x8036, 0x91ce, 0x5f25, 0x77e2, 0x5384, 0x5f79, 0x7d04, 0x85ac, 0x8a33, 0x8e8d,
+    0x9756, 0x67f3, 0x85ae, 0x9453, 0x6109, 0x6108, 0x6cb9, 0x7652, 0x8aed, 0x8f38, 0x552f, 0x4f51, 0x512a, 0x52c7, 0x53cb, 0x5ba5,
+    0x5e7d, 0x60a0, 0x6182, 0x63d6, 0x6709, 0x67da, 0x6e67, 0x6d8c, 0x7336, 0x7337, 0x7531, 0x7950, 0x88d5, 0x8a98, 0x904a, 0x9091,
+    0x90f5, 0x96c4, 0x878d, 0x5915, 0x4e88, 0x4f59, 0x4e0e, 0x8a89, 0x8f3f, 0x9810, 0x50ad, 0x5e7c, 0x5996, 0x5bb9, 0x5eb8, 0x63da,
+    0x63fa, 0x64c1, 0x66dc, 0x694a, 0x69d8, 0x6d0b, 0x6eb6, 0x7194, 0x7528, 0x7aaf, 0x7f8a, 0x8000, 0x8449, 0x84c9, 0x8981, 0x8b21,
+    0x8e0a, 0x9065, 0x967d, 0x990a, 0x617e, 0x6291, 0x6b32, 0x6c83, 0x6d74, 0x7fcc, 0x7ffc, 0x6dc0, 0x7f85, 0x87ba, 0x88f8, 0x6765,
+    0x83b1, 0x983c, 0x96f7, 0x6d1b, 0x7d61, 0x843d, 0x916a, 0x4e71, 0x5375, 0x5d50, 0x6b04, 0x6feb, 0x85cd, 0x862d, 0x89a7, 0x5229,
+    0x540f, 0x5c65, 0x674e, 0x68a8, 0x7406, 0x7483, 0x75e2, 0x88cf, 0x88e1, 0x91cc, 0x96e2, 0x9678, 0x5f8b, 0x7387, 0x7acb, 0x844e,
+    0x63a0, 0x7565, 0x5289, 0x6d41, 0x6e9c, 0x7409, 0x7559, 0x786b, 0x7c92, 0x9686, 0x7adc, 0x9f8d, 0x4fb6, 0x616e, 0x65c5, 0x865c,
+    0x4e86, 0x4eae, 0x50da, 0x4e21, 0x51cc, 0x5bee, 0x6599, 0x6881, 0x6dbc, 0x731f, 0x7642, 0x77ad, 0x7a1c, 0x7ce7, 0x826f, 0x8ad2,
+    0x907c, 0x91cf, 0x9675, 0x9818, 0x529b, 0x7dd1, 0x502b, 0x5398, 0x6797, 0x6dcb, 0x71d0, 0x7433, 0x81e8, 0x8f2a, 0x96a3, 0x9c57,
+    0x9e9f, 0x7460, 0x5841, 0x6d99, 0x7d2f, 0x985e, 0x4ee4, 0x4f36, 0x4f8b, 0x51b7, 0x52b1, 0x5dba, 0x601c, 0x73b2, 0x793c, 0x82d3,
+    0x9234, 0x96b7, 0x96f6, 0x970a, 0x9e97, 0x9f62, 0x66a6, 0x6b74, 0x5217, 0x52a3, 0x70c8, 0x88c2, 0x5ec9, 0x604b, 0x6190, 0x6f23,
+    0x7149, 0x7c3e, 0x7df4, 0x806f, 0x84ee, 0x9023, 0x932c, 0x5442, 0x9b6f, 0x6ad3, 0x7089, 0x8cc2, 0x8def, 0x9732, 0x52b4, 0x5a41,
+    0x5eca, 0x5f04, 0x6717, 0x697c, 0x6994, 0x6d6a, 0x6f0f, 0x7262, 0x72fc, 0x7bed, 0x8001, 0x807e, 0x874b, 0x90ce, 0x516d, 0x9e93,
+    0x7984, 0x808b, 0x9332, 0x8ad6, 0x502d, 0x548c, 0x8a71, 0x6b6a, 0x8cc4, 0x8107, 0x60d1, 0x67a0, 0x9df2, 0x4e99, 0x4e98, 0x9c10,
+    0x8a6b, 0x85c1, 0x8568, 0x6900, 0x6e7e, 0x7897, 0x8155, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x5f0c, 0x4e10, 0x4e15, 0x4e2a, 0x4e31, 0x4e36, 0x4e3c, 0x4e3f, 0x4e42, 0x4e56, 0x4e58, 0x4e82, 0x4e85, 0x8c6b,
+    0x4e8a, 0x8212, 0x5f0d, 0x4e8e, 0x4e9e, 0x4e9f, 0x4ea0, 0x4ea2, 0x4eb0, 0x4eb3, 0x4eb6, 0x4ece, 0x4ecd, 0x4ec4, 0x4ec6, 0x4ec2,
+    0x4ed7, 0x4ede, 0x4eed, 0x4edf, 0x4ef7, 0x4f09, 0x4f5a, 0x4f30, 0x4f5b, 0x4f5d, 0x4f57, 0x4f47, 0x4f76, 0x4f88, 0x4f8f, 0x4f98,
+    0x4f7b, 0x4f69, 0x4f70, 0x4f91, 0x4f6f, 0x4f86, 0x4f96, 0x5118, 0x4fd4, 0x4fdf, 0x4fce, 0x4fd8, 0x4fdb, 0x4fd1, 0x4fda, 0x4fd0,
+    0x4fe4, 0x4fe5, 0x501a, 0x5028, 0x5014, 0x502a, 0x5025, 0x5005, 0x4f1c, 0x4ff6, 0x5021, 0x5029, 0x502c, 0x4ffe, 0x4fef, 0x5011,
+    0x5006, 0x5043, 0x5047, 0x6703, 0x5055, 0x5050, 0x5048, 0x505a, 0x5056, 0x506c, 0x5078, 0x5080, 0x509a, 0x5085, 0x50b4, 0x50b2,
+    0x50c9, 0x50ca, 0x50b3, 0x50c2, 0x50d6, 0x50de, 0x50e5, 0x50ed, 0x50e3, 0x50ee, 0x50f9, 0x50f5, 0x5109, 0x5101, 0x5102, 0x5116,
+    0x5115, 0x5114, 0x511a, 0x5121, 0x513a, 0x5137, 0x513c, 0x513b, 0x513f, 0x5140, 0x5152, 0x514c, 0x5154, 0x5162, 0x7af8, 0x5169,
+    0x516a, 0x516e, 0x5180, 0x5182, 0x56d8, 0x518c, 0x5189, 0x518f, 0x5191, 0x5193, 0x5195, 0x5196, 0x51a4, 0x51a6, 0x51a2, 0x51a9,
+    0x51aa, 0x51ab, 0x51b3, 0x51b1, 0x51b2, 0x51b0, 0x51b5, 0x51bd, 0x51c5, 0x51c9, 0x51db, 0x51e0, 0x8655, 0x51e9, 0x51ed, 0x51f0,
+    0x51f5, 0x51fe, 0x5204, 0x520b, 0x5214, 0x520e, 0x5227, 0x522a, 0x522e, 0x5233, 0x5239, 0x524f, 0x5244, 0x524b, 0x524c, 0x525e,
+    0x5254, 0x526a, 0x5274, 0x5269, 0x5273, 0x527f, 0x527d, 0x528d, 0x5294, 0x5292, 0x5271, 0x5288, 0x5291, 0x8fa8, 0x8fa7, 0x52ac,
+    0x52ad, 0x52bc, 0x52b5, 0x52c1, 0x52cd, 0x52d7, 0x52de, 0x52e3, 0x52e6, 0x98ed, 0x52e0, 0x52f3, 0x52f5, 0x52f8, 0x52f9, 0x5306,
+    0x5308, 0x7538, 0x530d, 0x5310, 0x530f, 0x5315, 0x531a, 0x5323, 0x532f, 0x5331, 0x5333, 0x5338, 0x5340, 0x5346, 0x5345, 0x4e17,
+    0x5349, 0x534d, 0x51d6, 0x535e, 0x5369, 0x536e, 0x5918, 0x537b, 0x5377, 0x5382, 0x5396, 0x53a0, 0x53a6, 0x53a5, 0x53ae, 0x53b0,
+    0x53b6, 0x53c3, 0x7c12, 0x96d9, 0x53df, 0x66fc, 0x71ee, 0x53ee, 0x53e8, 0x53ed, 0x53fa, 0x5401, 0x543d, 0x5440, 0x542c, 0x542d,
+    0x543c, 0x542e, 0x5436, 0x5429, 0x541d, 0x544e, 0x548f, 0x5475, 0x548e, 0x545f, 0x5471, 0x5477, 0x5470, 0x5492, 0x547b, 0x5480,
+    0x5476, 0x5484, 0x5490, 0x5486, 0x54c7, 0x54a2, 0x54b8, 0x54a5, 0x54ac, 0x54c4, 0x54c8, 0x54a8, 0x54ab, 0x54c2, 0x54a4, 0x54be,
+    0x54bc, 0x54d8, 0x54e5, 0x54e6, 0x550f, 0x5514, 0x54fd, 0x54ee, 0x54ed, 0x54fa, 0x54e2, 0x5539, 0x5540, 0x5563, 0x554c, 0x552e,
+    0x555c, 0x5545, 0x5556, 0x5557, 0x5538, 0x5533, 0x555d, 0x5599, 0x5580, 0x54af, 0x558a, 0x559f, 0x557b, 0x557e, 0x5598, 0x559e,
+    0x55ae, 0x557c, 0x5583, 0x55a9, 0x5587, 0x55a8, 0x55da, 0x55c5, 0x55df, 0x55c4, 0x55dc, 0x55e4, 0x55d4, 0x5614, 0x55f7, 0x5616,
+    0x55fe, 0x55fd, 0x561b, 0x55f9, 0x564e, 0x5650, 0x71df, 0x5634, 0x5636, 0x5632, 0x5638, 0x566b, 0x5664, 0x562f, 0x566c, 0x566a,
+    0x5686, 0x5680, 0x568a, 0x56a0, 0x5694, 0x568f, 0x56a5, 0x56ae, 0x56b6, 0x56b4, 0x56c2, 0x56bc, 0x56c1, 0x56c3, 0x56c0, 0x56c8,
+    0x56ce, 0x56d1, 0x56d3, 0x56d7, 0x56ee, 0x56f9, 0x5700, 0x56ff, 0x5704, 0x5709, 0x5708, 0x570b, 0x570d, 0x5713, 0x5718, 0x5716,
+    0x55c7, 0x571c, 0x5726, 0x5737, 0x5738, 0x574e, 0x573b, 0x5740, 0x574f, 0x5769, 0x57c0, 0x5788, 0x5761, 0x577f, 0x5789, 0x5793,
+    0x57a0, 0x57b3, 0x57a4, 0x57aa, 0x57b0, 0x57c3, 0x57c6, 0x57d4, 0x57d2, 0x57d3, 0x580a, 0x57d6, 0x57e3, 0x580b, 0x5819, 0x581d,
+    0x5872, 0x5821, 0x5862, 0x584b, 0x5870, 0x6bc0, 0x5852, 0x583d, 0x5879, 0x5885, 0x58b9, 0x589f, 0x58ab, 0x58ba, 0x58de, 0x58bb,
+    0x58b8, 0x58ae, 0x58c5, 0x58d3, 0x58d1, 0x58d7, 0x58d9, 0x58d8, 0x58e5, 0x58dc, 0x58e4, 0x58df, 0x58ef, 0x58fa, 0x58f9, 0x58fb,
+    0x58fc, 0x58fd, 0x5902, 0x590a, 0x5910, 0x591b, 0x68a6, 0x5925, 0x592c, 0x592d, 0x5932, 0x5938, 0x593e, 0x7ad2, 0x5955, 0x5950,
+    0x594e, 0x595a, 0x5958, 0x5962, 0x5960, 0x5967, 0x596c, 0x5969, 0x5978, 0x5981, 0x599d, 0x4f5e, 0x4fab, 0x59a3, 0x59b2, 0x59c6,
+    0x59e8, 0x59dc, 0x598d, 0x59d9, 0x59da, 0x5a25, 0x5a1f, 0x5a11, 0x5a1c, 0x5a09, 0x5a1a, 0x5a40, 0x5a6c, 0x5a49, 0x5a35, 0x5a36,
+    0x5a62, 0x5a6a, 0x5a9a, 0x5abc, 0x5abe, 0x5acb, 0x5ac2, 0x5abd, 0x5ae3, 0x5ad7, 0x5ae6, 0x5ae9, 0x5ad6, 0x5afa, 0x5afb, 0x5b0c,
+    0x5b0b, 0x5b16, 0x5b32, 0x5ad0, 0x5b2a, 0x5b36, 0x5b3e, 0x5b43, 0x5b45, 0x5b40, 0x5b51, 0x5b55, 0x5b5a, 0x5b5b, 0x5b65, 0x5b69,
+    0x5b70, 0x5b73, 0x5b75, 0x5b78, 0x6588, 0x5b7a, 0x5b80, 0x5b83, 0x5ba6, 0x5bb8, 0x5bc3, 0x5bc7, 0x5bc9, 0x5bd4, 0x5bd0, 0x5be4,
+    0x5be6, 0x5be2, 0x5bde, 0x5be5, 0x5beb, 0x5bf0, 0x5bf6, 0x5bf3, 0x5c05, 0x5c07, 0x5c08, 0x5c0d, 0x5c13, 0x5c20, 0x5c22, 0x5c28,
+    0x5c38, 0x5c39, 0x5c41, 0x5c46, 0x5c4e, 0x5c53, 0x5c50, 0x5c4f, 0x5b71, 0x5c6c, 0x5c6e, 0x4e62, 0x5c76, 0x5c79, 0x5c8c, 0x5c91,
+    0x5c94, 0x599b, 0x5cab, 0x5cbb, 0x5cb6, 0x5cbc, 0x5cb7, 0x5cc5, 0x5cbe, 0x5cc7, 0x5cd9, 0x5ce9, 0x5cfd, 0x5cfa, 0x5ced, 0x5d8c,
+    0x5cea, 0x5d0b, 0x5d15, 0x5d17, 0x5d5c, 0x5d1f, 0x5d1b, 0x5d11, 0x5d14, 0x5d22, 0x5d1a, 0x5d19, 0x5d18, 0x5d4c, 0x5d52, 0x5d4e,
+    0x5d4b, 0x5d6c, 0x5d73, 0x5d76, 0x5d87, 0x5d84, 0x5d82, 0x5da2, 0x5d9d, 0x5dac, 0x5dae, 0x5dbd, 0x5d90, 0x5db7, 0x5dbc, 0x5dc9,
+    0x5dcd, 0x5dd3, 0x5dd2, 0x5dd6, 0x5ddb, 0x5deb, 0x5df2, 0x5df5, 0x5e0b, 0x5e1a, 0x5e19, 0x5e11, 0x5e1b, 0x5e36, 0x5e37, 0x5e44,
+    0x5e43, 0x5e40, 0x5e4e, 0x5e57, 0x5e54, 0x5e5f, 0x5e62, 0x5e64, 0x5e47, 0x5e75, 0x5e76, 0x5e7a, 0x9ebc, 0x5e7f, 0x5ea0, 0x5ec1,
+    0x5ec2, 0x5ec8, 0x5ed0, 0x5ecf, 0x5ed6, 0x5ee3, 0x5edd, 0x5eda, 0x5edb, 0x5ee2, 0x5ee1, 0x5ee8, 0x5ee9, 0x5eec, 0x5ef1, 0x5ef3,
+    0x5ef0, 0x5ef4, 0x5ef8, 0x5efe, 0x5f03, 0x5f09, 0x5f5d, 0x5f5c, 0x5f0b, 0x5f11, 0x5f16, 0x5f29, 0x5f2d, 0x5f38, 0x5f41, 0x5f48,
+    0x5f4c, 0x5f4e, 0x5f2f, 0x5f51, 0x5f56, 0x5f57, 0x5f59, 0x5f61, 0x5f6d, 0x5f73, 0x5f77, 0x5f83, 0x5f82, 0x5f7f, 0x5f8a, 0x5f88,
+    0x5f91, 0x5f87, 0x5f9e, 0x5f99, 0x5f98, 0x5fa0, 0x5fa8, 0x5fad, 0x5fbc, 0x5fd6, 0x5ffb, 0x5fe4, 0x5ff8, 0x5ff1, 0x5fdd, 0x60b3,
+    0x5fff, 0x6021, 0x6060, 0x6019, 0x6010, 0x6029, 0x600e, 0x6031, 0x601b, 0x6015, 0x602b, 0x6026, 0x600f, 0x603a, 0x605a, 0x6041,
+    0x606a, 0x6077, 0x605f, 0x604a, 0x6046, 0x604d, 0x6063, 0x6043, 0x6064, 0x6042, 0x606c, 0x606b, 0x6059, 0x6081, 0x608d, 0x60e7,
+    0x6083, 0x609a, 0x6084, 0x609b, 0x6096, 0x6097, 0x6092, 0x60a7, 0x608b, 0x60e1, 0x60b8, 0x60e0, 0x60d3, 0x60b4, 0x5ff0, 0x60bd,
+    0x60c6, 0x60b5, 0x60d8, 0x614d, 0x6115, 0x6106, 0x60f6, 0x60f7, 0x6100, 0x60f4, 0x60fa, 0x6103, 0x6121, 0x60fb, 0x60f1, 0x610d,
+    0x610e, 0x6147, 0x613e, 0x6128, 0x6127, 0x614a, 0x613f, 0x613c, 0x612c, 0x6134, 0x613d, 0x6142, 0x6144, 0x6173, 0x6177, 0x6158,
+    0x6159, 0x615a, 0x616b, 0x6174, 0x616f, 0x6165, 0x6171, 0x615f, 0x615d, 0x6153, 0x6175, 0x6199, 0x6196, 0x6187, 0x61ac, 0x6194,
+    0x619a, 0x618a, 0x6191, 0x61ab, 0x61ae, 0x61cc, 0x61ca, 0x61c9, 0x61f7, 0x61c8, 0x61c3, 0x61c6, 0x61ba, 0x61cb, 0x7f79, 0x61cd,
+    0x61e6, 0x61e3, 0x61f6, 0x61fa, 0x61f4, 0x61ff, 0x61fd, 0x61fc, 0x61fe, 0x6200, 0x6208, 0x6209, 0x620d, 0x620c, 0x6214, 0x621b,
+    0x621e, 0x6221, 0x622a, 0x622e, 0x6230, 0x6232, 0x6233, 0x6241, 0x624e, 0x625e, 0x6263, 0x625b, 0x6260, 0x6268, 0x627c, 0x6282,
+    0x6289, 0x627e, 0x6292, 0x6293, 0x6296, 0x62d4, 0x6283, 0x6294, 0x62d7, 0x62d1, 0x62bb, 0x62cf, 0x62ff, 0x62c6, 0x64d4, 0x62c8,
+    0x62dc, 0x62cc, 0x62ca, 0x62c2, 0x62c7, 0x629b, 0x62c9, 0x630c, 0x62ee, 0x62f1, 0x6327, 0x6302, 0x6308, 0x62ef, 0x62f5, 0x6350,
+    0x633e, 0x634d, 0x641c, 0x634f, 0x6396, 0x638e, 0x6380, 0x63ab, 0x6376, 0x63a3, 0x638f, 0x6389, 0x639f, 0x63b5, 0x636b, 0x6369,
+    0x63be, 0x63e9, 0x63c0, 0x63c6, 0x63e3, 0x63c9, 0x63d2, 0x63f6, 0x63c4, 0x6416, 0x6434, 0x6406, 0x6413, 0x6426, 0x6436, 0x651d,
+    0x6417, 0x6428, 0x640f, 0x6467, 0x646f, 0x6476, 0x644e, 0x652a, 0x6495, 0x6493, 0x64a5, 0x64a9, 0x6488, 0x64bc, 0x64da, 0x64d2,
+    0x64c5, 0x64c7, 0x64bb, 0x64d8, 0x64c2, 0x64f1, 0x64e7, 0x8209, 0x64e0, 0x64e1, 0x62ac, 0x64e3, 0x64ef, 0x652c, 0x64f6, 0x64f4,
+    0x64f2, 0x64fa, 0x6500, 0x64fd, 0x6518, 0x651c, 0x6505, 0x6524, 0x6523, 0x652b, 0x6534, 0x6535, 0x6537, 0x6536, 0x6538, 0x754b,
+    0x6548, 0x6556, 0x6555, 0x654d, 0x6558, 0x655e, 0x655d, 0x6572, 0x6578, 0x6582, 0x6583, 0x8b8a, 0x659b, 0x659f, 0x65ab, 0x65b7,
+    0x65c3, 0x65c6, 0x65c1, 0x65c4, 0x65cc, 0x65d2, 0x65db, 0x65d9, 0x65e0, 0x65e1, 0x65f1, 0x6772, 0x660a, 0x6603, 0x65fb, 0x6773,
+    0x6635, 0x6636, 0x6634, 0x661c, 0x664f, 0x6644, 0x6649, 0x6641, 0x665e, 0x665d, 0x6664, 0x6667, 0x6668, 0x665f, 0x6662, 0x6670,
+    0x6683, 0x6688, 0x668e, 0x6689, 0x6684, 0x6698, 0x669d, 0x66c1, 0x66b9, 0x66c9, 0x66be, 0x66bc, 0x66c4, 0x66b8, 0x66d6, 0x66da,
+    0x66e0, 0x663f, 0x66e6, 0x66e9, 0x66f0, 0x66f5, 0x66f7, 0x670f, 0x6716, 0x671e, 0x6726, 0x6727, 0x9738, 0x672e, 0x673f, 0x6736,
+    0x6741, 0x6738, 0x6737, 0x6746, 0x675e, 0x6760, 0x6759, 0x6763, 0x6764, 0x6789, 0x6770, 0x67a9, 0x677c, 0x676a, 0x678c, 0x678b,
+    0x67a6, 0x67a1, 0x6785, 0x67b7, 0x67ef, 0x67b4, 0x67ec, 0x67b3, 0x67e9, 0x67b8, 0x67e4, 0x67de, 0x67dd, 0x67e2, 0x67ee, 0x67b9,
+    0x67ce, 0x67c6, 0x67e7, 0x6a9c, 0x681e, 0x6846, 0x6829, 0x6840, 0x684d, 0x6832, 0x684e, 0x68b3, 0x682b, 0x6859, 0x6863, 0x6877,
+    0x687f, 0x689f, 0x688f, 0x68ad, 0x6894, 0x689d, 0x689b, 0x6883, 0x6aae, 0x68b9, 0x6874, 0x68b5, 0x68a0, 0x68ba, 0x690f, 0x688d,
+    0x687e, 0x6901, 0x68ca, 0x6908, 0x68d8, 0x6922, 0x6926, 0x68e1, 0x690c, 0x68cd, 0x68d4, 0x68e7, 0x68d5, 0x6936, 0x6912, 0x6904,
+    0x68d7, 0x68e3, 0x6925, 0x68f9, 0x68e0, 0x68ef, 0x6928, 0x692a, 0x691a, 0x6923, 0x6921, 0x68c6, 0x6979, 0x6977, 0x695c, 0x6978,
+    0x696b, 0x6954, 0x697e, 0x696e, 0x6939, 0x6974, 0x693d, 0x6959, 0x6930, 0x6961, 0x695e, 0x695d, 0x6981, 0x696a, 0x69b2, 0x69ae,
+    0x69d0, 0x69bf, 0x69c1, 0x69d3, 0x69be, 0x69ce, 0x5be8, 0x69ca, 0x69dd, 0x69bb, 0x69c3, 0x69a7, 0x6a2e, 0x6991, 0x69a0, 0x699c,
+    0x6995, 0x69b4, 0x69de, 0x69e8, 0x6a02, 0x6a1b, 0x69ff, 0x6b0a, 0x69f9, 0x69f2, 0x69e7, 0x6a05, 0x69b1, 0x6a1e, 0x69ed, 0x6a14,
+    0x69eb, 0x6a0a, 0x6a12, 0x6ac1, 0x6a23, 0x6a13, 0x6a44, 0x6a0c, 0x6a72, 0x6a36, 0x6a78, 0x6a47, 0x6a62, 0x6a59, 0x6a66, 0x6a48,
+    0x6a38, 0x6a22, 0x6a90, 0x6a8d, 0x6aa0, 0x6a84, 0x6aa2, 0x6aa3, 0x6a97, 0x8617, 0x6abb, 0x6ac3, 0x6ac2, 0x6ab8, 0x6ab3, 0x6aac,
+    0x6ade, 0x6ad1, 0x6adf, 0x6aaa, 0x6ada, 0x6aea, 0x6afb, 0x6b05, 0x8616, 0x6afa, 0x6b12, 0x6b16, 0x9b31, 0x6b1f, 0x6b38, 0x6b37,
+    0x76dc, 0x6b39, 0x98ee, 0x6b47, 0x6b43, 0x6b49, 0x6b50, 0x6b59, 0x6b54, 0x6b5b, 0x6b5f, 0x6b61, 0x6b78, 0x6b79, 0x6b7f, 0x6b80,
+    0x6b84, 0x6b83, 0x6b8d, 0x6b98, 0x6b95, 0x6b9e, 0x6ba4, 0x6baa, 0x6bab, 0x6baf, 0x6bb2, 0x6bb1, 0x6bb3, 0x6bb7, 0x6bbc, 0x6bc6,
+    0x6bcb, 0x6bd3, 0x6bdf, 0x6bec, 0x6beb, 0x6bf3, 0x6bef, 0x9ebe, 0x6c08, 0x6c13, 0x6c14, 0x6c1b, 0x6c24, 0x6c23, 0x6c5e, 0x6c55,
+    0x6c62, 0x6c6a, 0x6c82, 0x6c8d, 0x6c9a, 0x6c81, 0x6c9b, 0x6c7e, 0x6c68, 0x6c73, 0x6c92, 0x6c90, 0x6cc4, 0x6cf1, 0x6cd3, 0x6cbd,
+    0x6cd7, 0x6cc5, 0x6cdd, 0x6cae, 0x6cb1, 0x6cbe, 0x6cba, 0x6cdb, 0x6cef, 0x6cd9, 0x6cea, 0x6d1f, 0x884d, 0x6d36, 0x6d2b, 0x6d3d,
+    0x6d38, 0x6d19, 0x6d35, 0x6d33, 0x6d12, 0x6d0c, 0x6d63, 0x6d93, 0x6d64, 0x6d5a, 0x6d79, 0x6d59, 0x6d8e, 0x6d95, 0x6fe4, 0x6d85,
+    0x6df9, 0x6e15, 0x6e0a, 0x6db5, 0x6dc7, 0x6de6, 0x6db8, 0x6dc6, 0x6dec, 0x6dde, 0x6dcc, 0x6de8, 0x6dd2, 0x6dc5, 0x6dfa, 0x6dd9,
+    0x6de4, 0x6dd5, 0x6dea, 0x6dee, 0x6e2d, 0x6e6e, 0x6e2e, 0x6e19, 0x6e72, 0x6e5f, 0x6e3e, 0x6e23, 0x6e6b, 0x6e2b, 0x6e76, 0x6e4d,
+    0x6e1f, 0x6e43, 0x6e3a, 0x6e4e, 0x6e24, 0x6eff, 0x6e1d, 0x6e38, 0x6e82, 0x6eaa, 0x6e98, 0x6ec9, 0x6eb7, 0x6ed3, 0x6ebd, 0x6eaf,
+    0x6ec4, 0x6eb2, 0x6ed4, 0x6ed5, 0x6e8f, 0x6ea5, 0x6ec2, 0x6e9f, 0x6f41, 0x6f11, 0x704c, 0x6eec, 0x6ef8, 0x6efe, 0x6f3f, 0x6ef2,
+    0x6f31, 0x6eef, 0x6f32, 0x6ecc, 0x6f3e, 0x6f13, 0x6ef7, 0x6f86, 0x6f7a, 0x6f78, 0x6f81, 0x6f80, 0x6f6f, 0x6f5b, 0x6ff3, 0x6f6d,
+    0x6f82, 0x6f7c, 0x6f58, 0x6f8e, 0x6f91, 0x6fc2, 0x6f66, 0x6fb3, 0x6fa3, 0x6fa1, 0x6fa4, 0x6fb9, 0x6fc6, 0x6faa, 0x6fdf, 0x6fd5,
+    0x6fec, 0x6fd4, 0x6fd8, 0x6ff1, 0x6fee, 0x6fdb, 0x7009, 0x700b, 0x6ffa, 0x7011, 0x7001, 0x700f, 0x6ffe, 0x701b, 0x701a, 0x6f74,
+    0x701d, 0x7018, 0x701f, 0x7030, 0x703e, 0x7032, 0x7051, 0x7063, 0x7099, 0x7092, 0x70af, 0x70f1, 0x70ac, 0x70b8, 0x70b3, 0x70ae,
+    0x70df, 0x70cb, 0x70dd, 0x70d9, 0x7109, 0x70fd, 0x711c, 0x7119, 0x7165, 0x7155, 0x7188, 0x7166, 0x7162, 0x714c, 0x7156, 0x716c,
+    0x718f, 0x71fb, 0x7184, 0x7195, 0x71a8, 0x71ac, 0x71d7, 0x71b9, 0x71be, 0x71d2, 0x71c9, 0x71d4, 0x71ce, 0x71e0, 0x71ec, 0x71e7,
+    0x71f5, 0x71fc, 0x71f9, 0x71ff, 0x720d, 0x7210, 0x721b, 0x7228, 0x722d, 0x722c, 0x7230, 0x7232, 0x723b, 0x723c, 0x723f, 0x7240,
+    0x7246, 0x724b, 0x7258, 0x7274, 0x727e, 0x7282, 0x7281, 0x7287, 0x7292, 0x7296, 0x72a2, 0x72a7, 0x72b9, 0x72b2, 0x72c3, 0x72c6,
+    0x72c4, 0x72ce, 0x72d2, 0x72e2, 0x72e0, 0x72e1, 0x72f9, 0x72f7, 0x500f, 0x7317, 0x730a, 0x731c, 0x7316, 0x731d, 0x7334, 0x732f,
+    0x7329, 0x7325, 0x733e, 0x734e, 0x734f, 0x9ed8, 0x7357, 0x736a, 0x7368, 0x7370, 0x7378, 0x7375, 0x737b, 0x737a, 0x73c8, 0x73b3,
+    0x73ce, 0x73bb, 0x73c0, 0x73e5, 0x73ee, 0x73de, 0x74a2, 0x7405, 0x746f, 0x7425, 0x73f8, 0x7432, 0x743a, 0x7455, 0x743f, 0x745f,
+    0x7459, 0x7441, 0x745c, 0x7469, 0x7470, 0x7463, 0x746a, 0x7476, 0x747e, 0x748b, 0x749e, 0x74a7, 0x74ca, 0x74cf, 0x74d4, 0x73f1,
+    0x74e0, 0x74e3, 0x74e7, 0x74e9, 0x74ee, 0x74f2, 0x74f0, 0x74f1, 0x74f8, 0x74f7, 0x7504, 0x7503, 0x7505, 0x750c, 0x750e, 0x750d,
+    0x7515, 0x7513, 0x751e, 0x7526, 0x752c, 0x753c, 0x7544, 0x754d, 0x754a, 0x7549, 0x755b, 0x7546, 0x755a, 0x7569, 0x7564, 0x7567,
+    0x756b, 0x756d, 0x7578, 0x7576, 0x7586, 0x7587, 0x7574, 0x758a, 0x7589, 0x7582, 0x7594, 0x759a, 0x759d, 0x75a5, 0x75a3, 0x75c2,
+    0x75b3, 0x75c3, 0x75b5, 0x75bd, 0x75b8, 0x75bc, 0x75b1, 0x75cd, 0x75ca, 0x75d2, 0x75d9, 0x75e3, 0x75de, 0x75fe, 0x75ff, 0x75fc,
+    0x7601, 0x75f0, 0x75fa, 0x75f2, 0x75f3, 0x760b, 0x760d, 0x7609, 0x761f, 0x7627, 0x7620, 0x7621, 0x7622, 0x7624, 0x7634, 0x7630,
+    0x763b, 0x7647, 0x7648, 0x7646, 0x765c, 0x7658, 0x7661, 0x7662, 0x7668, 0x7669, 0x766a, 0x7667, 0x766c, 0x7670, 0x7672, 0x7676,
+    0x7678, 0x767c, 0x7680, 0x7683, 0x7688, 0x768b, 0x768e, 0x7696, 0x7693, 0x7699, 0x769a, 0x76b0, 0x76b4, 0x76b8, 0x76b9, 0x76ba,
+    0x76c2, 0x76cd, 0x76d6, 0x76d2, 0x76de, 0x76e1, 0x76e5, 0x76e7, 0x76ea, 0x862f, 0x76fb, 0x7708, 0x7707, 0x7704, 0x7729, 0x7724,
+    0x771e, 0x7725, 0x7726, 0x771b, 0x7737, 0x7738, 0x7747, 0x775a, 0x7768, 0x776b, 0x775b, 0x7765, 0x777f, 0x777e, 0x7779, 0x778e,
+    0x778b, 0x7791, 0x77a0, 0x779e, 0x77b0, 0x77b6, 0x77b9, 0x77bf, 0x77bc, 0x77bd, 0x77bb, 0x77c7, 0x77cd, 0x77d7, 0x77da, 0x77dc,
+    0x77e3, 0x77ee, 0x77fc, 0x780c, 0x7812, 0x7926, 0x7820, 0x792a, 0x7845, 0x788e, 0x7874, 0x7886, 0x787c, 0x789a, 0x788c, 0x78a3,
+    0x78b5, 0x78aa, 0x78af, 0x78d1, 0x78c6, 0x78cb, 0x78d4, 0x78be, 0x78bc, 0x78c5, 0x78ca, 0x78ec, 0x78e7, 0x78da, 0x78fd, 0x78f4,
+    0x7907, 0x7912, 0x7911, 0x7919, 0x792c, 0x792b, 0x7940, 0x7960, 0x7957, 0x795f, 0x795a, 0x7955, 0x7953, 0x797a, 0x797f, 0x798a,
+    0x799d, 0x79a7, 0x9f4b, 0x79aa, 0x79ae, 0x79b3, 0x79b9, 0x79ba, 0x79c9, 0x79d5, 0x79e7, 0x79ec, 0x79e1, 0x79e3, 0x7a08, 0x7a0d,
+    0x7a18, 0x7a19, 0x7a20, 0x7a1f, 0x7980, 0x7a31, 0x7a3b, 0x7a3e, 0x7a37, 0x7a43, 0x7a57, 0x7a49, 0x7a61, 0x7a62, 0x7a69, 0x9f9d,
+    0x7a70, 0x7a79, 0x7a7d, 0x7a88, 0x7a97, 0x7a95, 0x7a98, 0x7a96, 0x7aa9, 0x7ac8, 0x7ab0, 0x7ab6, 0x7ac5, 0x7ac4, 0x7abf, 0x9083,
+    0x7ac7, 0x7aca, 0x7acd, 0x7acf, 0x7ad5, 0x7ad3, 0x7ad9, 0x7ada, 0x7add, 0x7ae1, 0x7ae2, 0x7ae6, 0x7aed, 0x7af0, 0x7b02, 0x7b0f,
+    0x7b0a, 0x7b06, 0x7b33, 0x7b18, 0x7b19, 0x7b1e, 0x7b35, 0x7b28, 0x7b36, 0x7b50, 0x7b7a, 0x7b04, 0x7b4d, 0x7b0b, 0x7b4c, 0x7b45,
+    0x7b75, 0x7b65, 0x7b74, 0x7b67, 0x7b70, 0x7b71, 0x7b6c, 0x7b6e, 0x7b9d, 0x7b98, 0x7b9f, 0x7b8d, 0x7b9c, 0x7b9a, 0x7b8b, 0x7b92,
+    0x7b8f, 0x7b5d, 0x7b99, 0x7bcb, 0x7bc1, 0x7bcc, 0x7bcf, 0x7bb4, 0x7bc6, 0x7bdd, 0x7be9, 0x7c11, 0x7c14, 0x7be6, 0x7be5, 0x7c60,
+    0x7c00, 0x7c07, 0x7c13, 0x7bf3, 0x7bf7, 0x7c17, 0x7c0d, 0x7bf6, 0x7c23, 0x7c27, 0x7c2a, 0x7c1f, 0x7c37, 0x7c2b, 0x7c3d, 0x7c4c,
+    0x7c43, 0x7c54, 0x7c4f, 0x7c40, 0x7c50, 0x7c58, 0x7c5f, 0x7c64, 0x7c56, 0x7c65, 0x7c6c, 0x7c75, 0x7c83, 0x7c90, 0x7ca4, 0x7cad,
+    0x7ca2, 0x7cab, 0x7ca1, 0x7ca8, 0x7cb3, 0x7cb2, 0x7cb1, 0x7cae, 0x7cb9, 0x7cbd, 0x7cc0, 0x7cc5, 0x7cc2, 0x7cd8, 0x7cd2, 0x7cdc,
+    0x7ce2, 0x9b3b, 0x7cef, 0x7cf2, 0x7cf4, 0x7cf6, 0x7cfa, 0x7d06, 0x7d02, 0x7d1c, 0x7d15, 0x7d0a, 0x7d45, 0x7d4b, 0x7d2e, 0x7d32,
+    0x7d3f, 0x7d35, 0x7d46, 0x7d73, 0x7d56, 0x7d4e, 0x7d72, 0x7d68, 0x7d6e, 0x7d4f, 0x7d63, 0x7d93, 0x7d89, 0x7d5b, 0x7d8f, 0x7d7d,
+    0x7d9b, 0x7dba, 0x7dae, 0x7da3, 0x7db5, 0x7dc7, 0x7dbd, 0x7dab, 0x7e3d, 0x7da2, 0x7daf, 0x7ddc, 0x7db8, 0x7d9f, 0x7db0, 0x7dd8,
+    0x7ddd, 0x7de4, 0x7dde, 0x7dfb, 0x7df2, 0x7de1, 0x7e05, 0x7e0a, 0x7e23, 0x7e21, 0x7e12, 0x7e31, 0x7e1f, 0x7e09, 0x7e0b, 0x7e22,
+    0x7e46, 0x7e66, 0x7e3b, 0x7e35, 0x7e39, 0x7e43, 0x7e37, 0x7e32, 0x7e3a, 0x7e67, 0x7e5d, 0x7e56, 0x7e5e, 0x7e59, 0x7e5a, 0x7e79,
+    0x7e6a, 0x7e69, 0x7e7c, 0x7e7b, 0x7e83, 0x7dd5, 0x7e7d, 0x8fae, 0x7e7f, 0x7e88, 0x7e89, 0x7e8c, 0x7e92, 0x7e90, 0x7e93, 0x7e94,
+    0x7e96, 0x7e8e, 0x7e9b, 0x7e9c, 0x7f38, 0x7f3a, 0x7f45, 0x7f4c, 0x7f4d, 0x7f4e, 0x7f50, 0x7f51, 0x7f55, 0x7f54, 0x7f58, 0x7f5f,
+    0x7f60, 0x7f68, 0x7f69, 0x7f67, 0x7f78, 0x7f82, 0x7f86, 0x7f83, 0x7f88, 0x7f87, 0x7f8c, 0x7f94, 0x7f9e, 0x7f9d, 0x7f9a, 0x7fa3,
+    0x7faf, 0x7fb2, 0x7fb9, 0x7fae, 0x7fb6, 0x7fb8, 0x8b71, 0x7fc5, 0x7fc6, 0x7fca, 0x7fd5, 0x7fd4, 0x7fe1, 0x7fe6, 0x7fe9, 0x7ff3,
+    0x7ff9, 0x98dc, 0x8006, 0x8004, 0x800b, 0x8012, 0x8018, 0x8019, 0x801c, 0x8021, 0x8028, 0x803f, 0x803b, 0x804a, 0x8046, 0x8052,
+    0x8058, 0x805a, 0x805f, 0x8062, 0x8068, 0x8073, 0x8072, 0x8070, 0x8076, 0x8079, 0x807d, 0x807f, 0x8084, 0x8086, 0x8085, 0x809b,
+    0x8093, 0x809a, 0x80ad, 0x5190, 0x80ac, 0x80db, 0x80e5, 0x80d9, 0x80dd, 0x80c4, 0x80da, 0x80d6, 0x8109, 0x80ef, 0x80f1, 0x811b,
+    0x8129, 0x8123, 0x812f, 0x814b, 0x968b, 0x8146, 0x813e, 0x8153, 0x8151, 0x80fc, 0x8171, 0x816e, 0x8165, 0x8166, 0x8174, 0x8183,
+    0x8188, 0x818a, 0x8180, 0x8182, 0x81a0, 0x8195, 0x81a4, 0x81a3, 0x815f, 0x8193, 0x81a9, 0x81b0, 0x81b5, 0x81be, 0x81b8, 0x81bd,
+    0x81c0, 0x81c2, 0x81ba, 0x81c9, 0x81cd, 0x81d1, 0x81d9, 0x81d8, 0x81c8, 0x81da, 0x81df, 0x81e0, 0x81e7, 0x81fa, 0x81fb, 0x81fe,
+    0x8201, 0x8202, 0x8205, 0x8207, 0x820a, 0x820d, 0x8210, 0x8216, 0x8229, 0x822b, 0x8238, 0x8233, 0x8240, 0x8259, 0x8258, 0x825d,
+    0x825a, 0x825f, 0x8264, 0x8262, 0x8268, 0x826a, 0x826b, 0x822e, 0x8271, 0x8277, 0x8278, 0x827e, 0x828d, 0x8292, 0x82ab, 0x829f,
+    0x82bb, 0x82ac, 0x82e1, 0x82e3, 0x82df, 0x82d2, 0x82f4, 0x82f3, 0x82fa, 0x8393, 0x8303, 0x82fb, 0x82f9, 0x82de, 0x8306, 0x82dc,
+    0x8309, 0x82d9, 0x8335, 0x8334, 0x8316, 0x8332, 0x8331, 0x8340, 0x8339, 0x8350, 0x8345, 0x832f, 0x832b, 0x8317, 0x8318, 0x8385,
+    0x839a, 0x83aa, 0x839f, 0x83a2, 0x8396, 0x8323, 0x838e, 0x8387, 0x838a, 0x837c, 0x83b5, 0x8373, 0x8375, 0x83a0, 0x8389, 0x83a8,
+    0x83f4, 0x8413, 0x83eb, 0x83ce, 0x83fd, 0x8403, 0x83d8, 0x840b, 0x83c1, 0x83f7, 0x8407, 0x83e0, 0x83f2, 0x840d, 0x8422, 0x8420,
+    0x83bd, 0x8438, 0x8506, 0x83fb, 0x846d, 0x842a, 0x843c, 0x855a, 0x8484, 0x8477, 0x846b, 0x84ad, 0x846e, 0x8482, 0x8469, 0x8446,
+    0x842c, 0x846f, 0x8479, 0x8435, 0x84ca, 0x8462, 0x84b9, 0x84bf, 0x849f, 0x84d9, 0x84cd, 0x84bb, 0x84da, 0x84d0, 0x84c1, 0x84c6,
+    0x84d6, 0x84a1, 0x8521, 0x84ff, 0x84f4, 0x8517, 0x8518, 0x852c, 0x851f, 0x8515, 0x8514, 0x84fc, 0x8540, 0x8563, 0x8558, 0x8548,
+    0x8541, 0x8602, 0x854b, 0x8555, 0x8580, 0x85a4, 0x8588, 0x8591, 0x858a, 0x85a8, 0x856d, 0x8594, 0x859b, 0x85ea, 0x8587, 0x859c,
+    0x8577, 0x857e, 0x8590, 0x85c9, 0x85ba, 0x85cf, 0x85b9, 0x85d0, 0x85d5, 0x85dd, 0x85e5, 0x85dc, 0x85f9, 0x860a, 0x8613, 0x860b,
+    0x85fe, 0x85fa, 0x8606, 0x8622, 0x861a, 0x8630, 0x863f, 0x864d, 0x4e55, 0x8654, 0x865f, 0x8667, 0x8671, 0x8693, 0x86a3, 0x86a9,
+    0x86aa, 0x868b, 0x868c, 0x86b6, 0x86af, 0x86c4, 0x86c6, 0x86b0, 0x86c9, 0x8823, 0x86ab, 0x86d4, 0x86de, 0x86e9, 0x86ec, 0x86df,
+    0x86db, 0x86ef, 0x8712, 0x8706, 0x8708, 0x8700, 0x8703, 0x86fb, 0x8711, 0x8709, 0x870d, 0x86f9, 0x870a, 0x8734, 0x873f, 0x8737,
+    0x873b, 0x8725, 0x8729, 0x871a, 0x8760, 0x875f, 0x8778, 0x874c, 0x874e, 0x8774, 0x8757, 0x8768, 0x876e, 0x8759, 0x8753, 0x8763,
+    0x876a, 0x8805, 0x87a2, 0x879f, 0x8782, 0x87af, 0x87cb, 0x87bd, 0x87c0, 0x87d0, 0x96d6, 0x87ab, 0x87c4, 0x87b3, 0x87c7, 0x87c6,
+    0x87bb, 0x87ef, 0x87f2, 0x87e0, 0x880f, 0x880d, 0x87fe, 0x87f6, 0x87f7, 0x880e, 0x87d2, 0x8811, 0x8816, 0x8815, 0x8822, 0x8821,
+    0x8831, 0x8836, 0x8839, 0x8827, 0x883b, 0x8844, 0x8842, 0x8852, 0x8859, 0x885e, 0x8862, 0x886b, 0x8881, 0x887e, 0x889e, 0x8875,
+    0x887d, 0x88b5, 0x8872, 0x8882, 0x8897, 0x8892, 0x88ae, 0x8899, 0x88a2, 0x888d, 0x88a4, 0x88b0, 0x88bf, 0x88b1, 0x88c3, 0x88c4,
+    0x88d4, 0x88d8, 0x88d9, 0x88dd, 0x88f9, 0x8902, 0x88fc, 0x88f4, 0x88e8, 0x88f2, 0x8904, 0x890c, 0x890a, 0x8913, 0x8943, 0x891e,
+    0x8925, 0x892a, 0x892b, 0x8941, 0x8944, 0x893b, 0x8936, 0x8938, 0x894c, 0x891d, 0x8960, 0x895e, 0x8966, 0x8964, 0x896d, 0x896a,
+    0x896f, 0x8974, 0x8977, 0x897e, 0x8983, 0x8988, 0x898a, 0x8993, 0x8998, 0x89a1, 0x89a9, 0x89a6, 0x89ac, 0x89af, 0x89b2, 0x89ba,
+    0x89bd, 0x89bf, 0x89c0, 0x89da, 0x89dc, 0x89dd, 0x89e7, 0x89f4, 0x89f8, 0x8a03, 0x8a16, 0x8a10, 0x8a0c, 0x8a1b, 0x8a1d, 0x8a25,
+    0x8a36, 0x8a41, 0x8a5b, 0x8a52, 0x8a46, 0x8a48, 0x8a7c, 0x8a6d, 0x8a6c, 0x8a62, 0x8a85, 0x8a82, 0x8a84, 0x8aa8, 0x8aa1, 0x8a91,
+    0x8aa5, 0x8aa6, 0x8a9a, 0x8aa3, 0x8ac4, 0x8acd, 0x8ac2, 0x8ada, 0x8aeb, 0x8af3, 0x8ae7, 0x8ae4, 0x8af1, 0x8b14, 0x8ae0, 0x8ae2,
+    0x8af7, 0x8ade, 0x8adb, 0x8b0c, 0x8b07, 0x8b1a, 0x8ae1, 0x8b16, 0x8b10, 0x8b17, 0x8b20, 0x8b33, 0x97ab, 0x8b26, 0x8b2b, 0x8b3e,
+    0x8b28, 0x8b41, 0x8b4c, 0x8b4f, 0x8b4e, 0x8b49, 0x8b56, 0x8b5b, 0x8b5a, 0x8b6b, 0x8b5f, 0x8b6c, 0x8b6f, 0x8b74, 0x8b7d, 0x8b80,
+    0x8b8c, 0x8b8e, 0x8b92, 0x8b93, 0x8b96, 0x8b99, 0x8b9a, 0x8c3a, 0x8c41, 0x8c3f, 0x8c48, 0x8c4c, 0x8c4e, 0x8c50, 0x8c55, 0x8c62,
+    0x8c6c, 0x8c78, 0x8c7a, 0x8c82, 0x8c89, 0x8c85, 0x8c8a, 0x8c8d, 0x8c8e, 0x8c94, 0x8c7c, 0x8c98, 0x621d, 0x8cad, 0x8caa, 0x8cbd,
+    0x8cb2, 0x8cb3, 0x8cae, 0x8cb6, 0x8cc8, 0x8cc1, 0x8ce4, 0x8ce3, 0x8cda, 0x8cfd, 0x8cfa, 0x8cfb, 0x8d04, 0x8d05, 0x8d0a, 0x8d07,
+    0x8d0f, 0x8d0d, 0x8d10, 0x9f4e, 0x8d13, 0x8ccd, 0x8d14, 0x8d16, 0x8d67, 0x8d6d, 0x8d71, 0x8d73, 0x8d81, 0x8d99, 0x8dc2, 0x8dbe,
+    0x8dba, 0x8dcf, 0x8dda, 0x8dd6, 0x8dcc, 0x8ddb, 0x8dcb, 0x8dea, 0x8deb, 0x8ddf, 0x8de3, 0x8dfc, 0x8e08, 0x8e09, 0x8dff, 0x8e1d,
+    0x8e1e, 0x8e10, 0x8e1f, 0x8e42, 0x8e35, 0x8e30, 0x8e34, 0x8e4a, 0x8e47, 0x8e49, 0x8e4c, 0x8e50, 0x8e48, 0x8e59, 0x8e64, 0x8e60,
+    0x8e2a, 0x8e63, 0x8e55, 0x8e76, 0x8e72, 0x8e7c, 0x8e81, 0x8e87, 0x8e85, 0x8e84, 0x8e8b, 0x8e8a, 0x8e93, 0x8e91, 0x8e94, 0x8e99,
+    0x8eaa, 0x8ea1, 0x8eac, 0x8eb0, 0x8ec6, 0x8eb1, 0x8ebe, 0x8ec5, 0x8ec8, 0x8ecb, 0x8edb, 0x8ee3, 0x8efc, 0x8efb, 0x8eeb, 0x8efe,
+    0x8f0a, 0x8f05, 0x8f15, 0x8f12, 0x8f19, 0x8f13, 0x8f1c, 0x8f1f, 0x8f1b, 0x8f0c, 0x8f26, 0x8f33, 0x8f3b, 0x8f39, 0x8f45, 0x8f42,
+    0x8f3e, 0x8f4c, 0x8f49, 0x8f46, 0x8f4e, 0x8f57, 0x8f5c, 0x8f62, 0x8f63, 0x8f64, 0x8f9c, 0x8f9f, 0x8fa3, 0x8fad, 0x8faf, 0x8fb7,
+    0x8fda, 0x8fe5, 0x8fe2, 0x8fea, 0x8fef, 0x9087, 0x8ff4, 0x9005, 0x8ff9, 0x8ffa, 0x9011, 0x9015, 0x9021, 0x900d, 0x901e, 0x9016,
+    0x900b, 0x9027, 0x9036, 0x9035, 0x9039, 0x8ff8, 0x904f, 0x9050, 0x9051, 0x9052, 0x900e, 0x9049, 0x903e, 0x9056, 0x9058, 0x905e,
+    0x9068, 0x906f, 0x9076, 0x96a8, 0x9072, 0x9082, 0x907d, 0x9081, 0x9080, 0x908a, 0x9089, 0x908f, 0x90a8, 0x90af, 0x90b1, 0x90b5,
+    0x90e2, 0x90e4, 0x6248, 0x90db, 0x9102, 0x9112, 0x9119, 0x9132, 0x9130, 0x914a, 0x9156, 0x9158, 0x9163, 0x9165, 0x9169, 0x9173,
+    0x9172, 0x918b, 0x9189, 0x9182, 0x91a2, 0x91ab, 0x91af, 0x91aa, 0x91b5, 0x91b4, 0x91ba, 0x91c0, 0x91c1, 0x91c9, 0x91cb, 0x91d0,
+    0x91d6, 0x91df, 0x91e1, 0x91db, 0x91fc, 0x91f5, 0x91f6, 0x921e, 0x91ff, 0x9214, 0x922c, 0x9215, 0x9211, 0x925e, 0x9257, 0x9245,
+    0x9249, 0x9264, 0x9248, 0x9295, 0x923f, 0x924b, 0x9250, 0x929c, 0x9296, 0x9293, 0x929b, 0x925a, 0x92cf, 0x92b9, 0x92b7, 0x92e9,
+    0x930f, 0x92fa, 0x9344, 0x932e, 0x9319, 0x9322, 0x931a, 0x9323, 0x933a, 0x9335, 0x933b, 0x935c, 0x9360, 0x937c, 0x936e, 0x9356,
+    0x93b0, 0x93ac, 0x93ad, 0x9394, 0x93b9, 0x93d6, 0x93d7, 0x93e8, 0x93e5, 0x93d8, 0x93c3, 0x93dd, 0x93d0, 0x93c8, 0x93e4, 0x941a,
+    0x9414, 0x9413, 0x9403, 0x9407, 0x9410, 0x9436, 0x942b, 0x9435, 0x9421, 0x943a, 0x9441, 0x9452, 0x9444, 0x945b, 0x9460, 0x9462,
+    0x945e, 0x946a, 0x9229, 0x9470, 0x9475, 0x9477, 0x947d, 0x945a, 0x947c, 0x947e, 0x9481, 0x947f, 0x9582, 0x9587, 0x958a, 0x9594,
+    0x9596, 0x9598, 0x9599, 0x95a0, 0x95a8, 0x95a7, 0x95ad, 0x95bc, 0x95bb, 0x95b9, 0x95be, 0x95ca, 0x6ff6, 0x95c3, 0x95cd, 0x95cc,
+    0x95d5, 0x95d4, 0x95d6, 0x95dc, 0x95e1, 0x95e5, 0x95e2, 0x9621, 0x9628, 0x962e, 0x962f, 0x9642, 0x964c, 0x964f, 0x964b, 0x9677,
+    0x965c, 0x965e, 0x965d, 0x965f, 0x9666, 0x9672, 0x966c, 0x968d, 0x9698, 0x9695, 0x9697, 0x96aa, 0x96a7, 0x96b1, 0x96b2, 0x96b0,
+    0x96b4, 0x96b6, 0x96b8, 0x96b9, 0x96ce, 0x96cb, 0x96c9, 0x96cd, 0x894d, 0x96dc, 0x970d, 0x96d5, 0x96f9, 0x9704, 0x9706, 0x9708,
+    0x9713, 0x970e, 0x9711, 0x970f, 0x9716, 0x9719, 0x9724, 0x972a, 0x9730, 0x9739, 0x973d, 0x973e, 0x9744, 0x9746, 0x9748, 0x9742,
+    0x9749, 0x975c, 0x9760, 0x9764, 0x9766, 0x9768, 0x52d2, 0x976b, 0x9771, 0x9779, 0x9785, 0x977c, 0x9781, 0x977a, 0x9786, 0x978b,
+    0x978f, 0x9790, 0x979c, 0x97a8, 0x97a6, 0x97a3, 0x97b3, 0x97b4, 0x97c3, 0x97c6, 0x97c8, 0x97cb, 0x97dc, 0x97ed, 0x9f4f, 0x97f2,
+    0x7adf, 0x97f6, 0x97f5, 0x980f, 0x980c, 0x9838, 0x9824, 0x9821, 0x9837, 0x983d, 0x9846, 0x984f, 0x984b, 0x986b, 0x986f, 0x9870,
+    0x9871, 0x9874, 0x9873, 0x98aa, 0x98af, 0x98b1, 0x98b6, 0x98c4, 0x98c3, 0x98c6, 0x98e9, 0x98eb, 0x9903, 0x9909, 0x9912, 0x9914,
+    0x9918, 0x9921, 0x991d, 0x991e, 0x9924, 0x9920, 0x992c, 0x992e, 0x993d, 0x993e, 0x9942, 0x9949, 0x9945, 0x9950, 0x994b, 0x9951,
+    0x9952, 0x994c, 0x9955, 0x9997, 0x9998, 0x99a5, 0x99ad, 0x99ae, 0x99bc, 0x99df, 0x99db, 0x99dd, 0x99d8, 0x99d1, 0x99ed, 0x99ee,
+    0x99f1, 0x99f2, 0x99fb, 0x99f8, 0x9a01, 0x9a0f, 0x9a05, 0x99e2, 0x9a19, 0x9a2b, 0x9a37, 0x9a45, 0x9a42, 0x9a40, 0x9a43, 0x9a3e,
+    0x9a55, 0x9a4d, 0x9a5b, 0x9a57, 0x9a5f, 0x9a62, 0x9a65, 0x9a64, 0x9a69, 0x9a6b, 0x9a6a, 0x9aad, 0x9ab0, 0x9abc, 0x9ac0, 0x9acf,
+    0x9ad1, 0x9ad3, 0x9ad4, 0x9ade, 0x9adf, 0x9ae2, 0x9ae3, 0x9ae6, 0x9aef, 0x9aeb, 0x9aee, 0x9af4, 0x9af1, 0x9af7, 0x9afb, 0x9b06,
+    0x9b18, 0x9b1a, 0x9b1f, 0x9b22, 0x9b23, 0x9b25, 0x9b27, 0x9b28, 0x9b29, 0x9b2a, 0x9b2e, 0x9b2f, 0x9b32, 0x9b44, 0x9b43, 0x9b4f,
+    0x9b4d, 0x9b4e, 0x9b51, 0x9b58, 0x9b74, 0x9b93, 0x9b83, 0x9b91, 0x9b96, 0x9b97, 0x9b9f, 0x9ba0, 0x9ba8, 0x9bb4, 0x9bc0, 0x9bca,
+    0x9bb9, 0x9bc6, 0x9bcf, 0x9bd1, 0x9bd2, 0x9be3, 0x9be2, 0x9be4, 0x9bd4, 0x9be1, 0x9c3a, 0x9bf2, 0x9bf1, 0x9bf0, 0x9c15, 0x9c14,
+    0x9c09, 0x9c13, 0x9c0c, 0x9c06, 0x9c08, 0x9c12, 0x9c0a, 0x9c04, 0x9c2e, 0x9c1b, 0x9c25, 0x9c24, 0x9c21, 0x9c30, 0x9c47, 0x9c32,
+    0x9c46, 0x9c3e, 0x9c5a, 0x9c60, 0x9c67, 0x9c76, 0x9c78, 0x9ce7, 0x9cec, 0x9cf0, 0x9d09, 0x9d08, 0x9ceb, 0x9d03, 0x9d06, 0x9d2a,
+    0x9d26, 0x9daf, 0x9d23, 0x9d1f, 0x9d44, 0x9d15, 0x9d12, 0x9d41, 0x9d3f, 0x9d3e, 0x9d46, 0x9d48, 0x9d5d, 0x9d5e, 0x9d64, 0x9d51,
+    0x9d50, 0x9d59, 0x9d72, 0x9d89, 0x9d87, 0x9dab, 0x9d6f, 0x9d7a, 0x9d9a, 0x9da4, 0x9da9, 0x9db2, 0x9dc4, 0x9dc1, 0x9dbb, 0x9db8,
+    0x9dba, 0x9dc6, 0x9dcf, 0x9dc2, 0x9dd9, 0x9dd3, 0x9df8, 0x9de6, 0x9ded, 0x9def, 0x9dfd, 0x9e1a, 0x9e1b, 0x9e1e, 0x9e75, 0x9e79,
+    0x9e7d, 0x9e81, 0x9e88, 0x9e8b, 0x9e8c, 0x9e92, 0x9e95, 0x9e91, 0x9e9d, 0x9ea5, 0x9ea9, 0x9eb8, 0x9eaa, 0x9ead, 0x9761, 0x9ecc,
+    0x9ece, 0x9ecf, 0x9ed0, 0x9ed4, 0x9edc, 0x9ede, 0x9edd, 0x9ee0, 0x9ee5, 0x9ee8, 0x9eef, 0x9ef4, 0x9ef6, 0x9ef7, 0x9ef9, 0x9efb,
+    0x9efc, 0x9efd, 0x9f07, 0x9f08, 0x76b7, 0x9f15, 0x9f21, 0x9f2c, 0x9f3e, 0x9f4a, 0x9f52, 0x9f54, 0x9f63, 0x9f5f, 0x9f60, 0x9f61,
+    0x9f66, 0x9f67, 0x9f6c, 0x9f6a, 0x9f77, 0x9f72, 0x9f76, 0x9f95, 0x9f9c, 0x9fa0, 0x582f, 0x69c7, 0x9059, 0x7464, 0x51dc, 0x7199,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000,
+];
+
+/// Pairs of a Unicode code point and the pointer of its character, sorted by code point.
+#[rustfmt::skip]
+static FROM_UNICODE: [(u16, u16); 6879] = [
+    (0x00a2, 80), (0x00a3, 81), (0x00a7, 87), (0x00a8, 14), (0x00ac, 137), (0x00b0, 74), (0x00b1, 61), (0x00b4, 12),
+    (0x00b6, 182), (0x00d7, 62), (0x00f7, 63), (0x0391, 470), (0x0392, 471), (0x0393, 472), (0x0394, 473), (0x0395, 474),
+    (0x0396, 475), (0x0397, 476), (0x0398, 477), (0x0399, 478), (0x039a, 479), (0x039b, 480), (0x039c, 481), (0x039d, 482),
+    (0x039e, 483), (0x039f, 484), (0x03a0, 485), (0x03a1, 486), (0x03a3, 487), (0x03a4, 488), (0x03a5, 489), (0x03a6, 490),
+    (0x03a7, 491), (0x03a8, 492), (0x03a9, 493), (0x03b1, 502), (0x03b2, 503), (0x03b3, 504), (0x03b4, 505), (0x03b5, 506),
+    (0x03b6, 507), (0x03b7, 508), (0x03b8, 509), (0x03b9, 510), (0x03ba, 511), (0x03bb, 512), (0x03bc, 513), (0x03bd, 514),
+    (0x03be, 515), (0x03bf, 516), (0x03c0, 517), (0x03c1, 518), (0x03c3, 519), (0x03c4, 520), (0x03c5, 521), (0x03c6, 522),
+    (0x03c7, 523), (0x03c8, 524), (0x03c9, 525), (0x0401, 570), (0x0410, 564), (0x0411, 565), (0x0412, 566), (0x0413, 567),
+    (0x0414, 568), (0x0415, 569), (0x0416, 571), (0x0417, 572), (0x0418, 573), (0x0419, 574), (0x041a, 575), (0x041b, 576),
+    (0x041c, 577), (0x041d, 578), (0x041e, 579), (0x041f, 580), (0x0420, 581), (0x0421, 582), (0x0422, 583), (0x0423, 584),
+    (0x0424, 585), (0x0425, 586), (0x0426, 587), (0x0427, 588), (0x0428, 589), (0x0429, 590), (0x042a, 591), (0x042b, 592),
+    (0x042c, 593), (0x042d, 594), (0x042e, 595), (0x042f, 596), (0x0430, 612), (0x0431, 613), (0x0432, 614), (0x0433, 615),
+    (0x0434, 616), (0x0435, 617), (0x0436, 619), (0x0437, 620), (0x0438, 621), (0x0439, 622), (0x043a, 623), (0x043b, 624),
+    (0x043c, 625), (0x043d, 626), (0x043e, 627), (0x043f, 628), (0x0440, 629), (0x0441, 630), (0x0442, 631), (0x0443, 632),
+    (0x0444, 633), (0x0445, 634), (0x0446, 635), (0x0447, 636), (0x0448, 637), (0x0449, 638), (0x044a, 639), (0x044b, 640),
+    (0x044c, 641), (0x044d, 642), (0x044e, 643), (0x044f, 644), (0x0451, 618), (0x2010, 29), (0x2015, 28), (0x2016, 33),
+    (0x2018, 37), (0x2019, 38), (0x201c, 39), (0x201d, 40), (0x2020, 180), (0x2021, 181), (0x2025, 36), (0x2026, 35),
+    (0x2030, 176), (0x2032, 75), (0x2033, 76), (0x203b, 101), (0x2103, 77), (0x212b, 175), (0x2190, 104), (0x2191, 105),
+    (0x2192, 103), (0x2193, 106), (0x21d2, 138), (0x21d4, 139), (0x2200, 140), (0x2202, 156), (0x2203, 141), (0x2207, 157),
+    (0x2208, 119), (0x220b, 120), (0x2212, 60), (0x221a, 162), (0x221d, 164), (0x221e, 70), (0x2220, 153), (0x2227, 135),
+    (0x2228, 136), (0x2229, 126), (0x222a, 125), (0x222b, 166), (0x222c, 167), (0x2234, 71), (0x2235, 165), (0x223d, 163),
+    (0x2252, 159), (0x2260, 65), (0x2261, 158), (0x2266, 68), (0x2267, 69), (0x226a, 160), (0x226b, 161), (0x2282, 123),
+    (0x2283, 124), (0x2286, 121), (0x2287, 122), (0x22a5, 154), (0x2312, 155), (0x2500, 658), (0x2501, 669), (0x2502, 659),
+    (0x2503, 670), (0x250c, 660), (0x250f, 671), (0x2510, 661), (0x2513, 672), (0x2514, 663), (0x2517, 674), (0x2518, 662),
+    (0x251b, 673), (0x251c, 664), (0x251d, 685), (0x2520, 680), (0x2523, 675), (0x2524, 666), (0x2525, 687), (0x2528, 682),
+    (0x252b, 677), (0x252c, 665), (0x252f, 681), (0x2530, 686), (0x2533, 676), (0x2534, 667), (0x2537, 683), (0x2538, 688),
+    (0x253b, 678), (0x253c, 668), (0x253f, 684), (0x2542, 689), (0x254b, 679), (0x25a0, 96), (0x25a1, 95), (0x25b2, 98),
+    (0x25b3, 97), (0x25bc, 100), (0x25bd, 99), (0x25c6, 94), (0x25c7, 93), (0x25cb, 90), (0x25ce, 92), (0x25cf, 91),
+    (0x25ef, 187), (0x2605, 89), (0x2606, 88), (0x2640, 73), (0x2642, 72), (0x266a, 179), (0x266d, 178), (0x266f, 177),
+    (0x3000, 0), (0x3001, 1), (0x3002, 2), (0x3003, 22), (0x3005, 24), (0x3006, 25), (0x3007, 26), (0x3008, 49),
+    (0x3009, 50), (0x300a, 51), (0x300b, 52), (0x300c, 53), (0x300d, 54), (0x300e, 55), (0x300f, 56), (0x3010, 57),
+    (0x3011, 58), (0x3012, 102), (0x3013, 107), (0x3014, 43), (0x3015, 44), (0x301c, 32), (0x3041, 282), (0x3042, 283),
+    (0x3043, 284), (0x3044, 285), (0x3045, 286), (0x3046, 287), (0x3047, 288), (0x3048, 289), (0x3049, 290), (0x304a, 291),
+    (0x304b, 292), (0x304c, 293), (0x304d, 294), (0x304e, 295), (0x304f, 296), (0x3050, 297), (0x3051, 298), (0x3052, 299),
+    (0x3053, 300), (0x3054, 301), (0x3055, 302), (0x3056, 303), (0x3057, 304), (0x3058, 305), (0x3059, 306), (0x305a, 307),
+    (0x305b, 308), (0x305c, 309), (0x305d, 310), (0x305e, 311), (0x305f, 312), (0x3060, 313), (0x3061, 314), (0x3062, 315),
+    (0x3063, 316), (0x3064, 317), (0x3065, 318), (0x3066, 319), (0x3067, 320), (0x3068, 321), (0x3069, 322), (0x306a, 323),
+    (0x306b, 324), (0x306c, 325), (0x306d, 326), (0x306e, 327), (0x306f, 328), (0x3070, 329), (0x3071, 330), (0x3072, 331),
+    (0x3073, 332), (0x3074, 333), (0x3075, 334), (0x3076, 335), (0x3077, 336), (0x3078, 337), (0x3079, 338), (0x307a, 339),
+    (0x307b, 340), (0x307c, 341), (0x307d, 342), (0x307e, 343), (0x307f, 344), (0x3080, 345), (0x3081, 346), (0x3082, 347),
+    (0x3083, 348), (0x3084, 349), (0x3085, 350), (0x3086, 351), (0x3087, 352), (0x3088, 353), (0x3089, 354), (0x308a, 355),
+    (0x308b, 356), (0x308c, 357), (0x308d, 358), (0x308e, 359), (0x308f, 360), (0x3090, 361), (0x3091, 362), (0x3092, 363),
+    (0x3093, 364), (0x309b, 10), (0x309c, 11), (0x309d, 20), (0x309e, 21), (0x30a1, 376), (0x30a2, 377), (0x30a3, 378),
+    (0x30a4, 379), (0x30a5, 380), (0x30a6, 381), (0x30a7, 382), (0x30a8, 383), (0x30a9, 384), (0x30aa, 385), (0x30ab, 386),
+    (0x30ac, 387), (0x30ad, 388), (0x30ae, 389), (0x30af, 390), (0x30b0, 391), (0x30b1, 392), (0x30b2, 393), (0x30b3, 394),
+    (0x30b4, 395), (0x30b5, 396), (0x30b6, 397), (0x30b7, 398), (0x30b8, 399), (0x30b9, 400), (0x30ba, 401), (0x30bb, 402),
+    (0x30bc, 403), (0x30bd, 404), (0x30be, 405), (0x30bf, 406), (0x30c0, 407), (0x30c1, 408), (0x30c2, 409), (0x30c3, 410),
+    (0x30c4, 411), (0x30c5, 412), (0x30c6, 413), (0x30c7, 414), (0x30c8, 415), (0x30c9, 416), (0x30ca, 417), (0x30cb, 418),
+    (0x30cc, 419), (0x30cd, 420), (0x30ce, 421), (0x30cf, 422), (0x30d0, 423), (0x30d1, 424), (0x30d2, 425), (0x30d3, 426),
+    (0x30d4, 427), (0x30d5, 428), (0x30d6, 429), (0x30d7, 430), (0x30d8, 431), (0x30d9, 432), (0x30da, 433), (0x30db, 434),
+    (0x30dc, 435), (0x30dd, 436), (0x30de, 437), (0x30df, 438), (0x30e0, 439), (0x30e1, 440), (0x30e2, 441), (0x30e3, 442),
+    (0x30e4, 443), (0x30e5, 444), (0x30e6, 445), (0x30e7, 446), (0x30e8, 447), (0x30e9, 448), (0x30ea, 449), (0x30eb, 450),
+    (0x30ec, 451), (0x30ed, 452), (0x30ee, 453), (0x30ef, 454), (0x30f0, 455), (0x30f1, 456), (0x30f2, 457), (0x30f3, 458),
+    (0x30f4, 459), (0x30f5, 460), (0x30f6, 461), (0x30fb, 5), (0x30fc, 27), (0x30fd, 18), (0x30fe, 19), (0x4e00, 1485),
+    (0x4e01, 3285), (0x4e03, 2560), (0x4e07, 4039), (0x4e08, 2795), (0x4e09, 2459), (0x4e0a, 2794), (0x4e0b, 1625), (0x4e0d, 3811),
+    (0x4e0e, 4166), (0x4e10, 4419), (0x4e11, 1518), (0x4e14, 1769), (0x4e15, 4420), (0x4e16, 2917), (0x4e17, 4639), (0x4e18, 1933),
+    (0x4e19, 3879), (0x4e1e, 2796), (0x4e21, 4259), (0x4e26, 3887), (0x4e2a, 4421), (0x4e2d, 3265), (0x4e31, 4422), (0x4e32, 2063),
+    (0x4e36, 4423), (0x4e38, 1846), (0x4e39, 3211), (0x4e3b, 2608), (0x4e3c, 4424), (0x4e3f, 4425), (0x4e42, 4426), (0x4e43, 3592),
+    (0x4e45, 1934), (0x4e4b, 3594), (0x4e4d, 3544), (0x4e4e, 2196), (0x4e4f, 3966), (0x4e55, 6808), (0x4e56, 4427), (0x4e57, 2797),
+    (0x4e58, 4428), (0x4e59, 1618), (0x4e5d, 2042), (0x4e5e, 2241), (0x4e5f, 4114), (0x4e62, 4987), (0x4e71, 4215), (0x4e73, 3570),
+    (0x4e7e, 1790), (0x4e80, 1900), (0x4e82, 4429), (0x4e85, 4430), (0x4e86, 4256), (0x4e88, 4164), (0x4e89, 3079), (0x4e8a, 4432),
+    (0x4e8b, 2529), (0x4e8c, 3560), (0x4e8e, 4435), (0x4e91, 1533), (0x4e92, 2224), (0x4e94, 2223), (0x4e95, 1479), (0x4e98, 4366),
+    (0x4e99, 4365), (0x4e9b, 2368), (0x4e9c, 1410), (0x4e9e, 4436), (0x4e9f, 4437), (0x4ea0, 4438), (0x4ea1, 3967), (0x4ea2, 4439),
+    (0x4ea4, 2243), (0x4ea5, 1480), (0x4ea6, 4029), (0x4ea8, 1971), (0x4eab, 1972), (0x4eac, 1973), (0x4ead, 3355), (0x4eae, 4257),
+    (0x4eb0, 4440), (0x4eb3, 4441), (0x4eb6, 4442), (0x4eba, 2864), (0x4ec0, 2657), (0x4ec1, 2865), (0x4ec2, 4447), (0x4ec4, 4445),
+    (0x4ec6, 4446), (0x4ec7, 1935), (0x4eca, 2352), (0x4ecb, 1677), (0x4ecd, 4444), (0x4ece, 4443), (0x4ecf, 3862), (0x4ed4, 2481),
+    (0x4ed5, 2480), (0x4ed6, 3131), (0x4ed7, 4448), (0x4ed8, 3812), (0x4ed9, 2984), (0x4edd, 23), (0x4ede, 4449), (0x4edf, 4451),
+    (0x4ee3, 3170), (0x4ee4, 4294), (0x4ee5, 1451), (0x4eed, 4450), (0x4eee, 1627), (0x4ef0, 2009), (0x4ef2, 3266), (0x4ef6, 2146),
+    (0x4ef7, 4452), (0x4efb, 3575), (0x4f01, 1860), (0x4f09, 4453), (0x4f0a, 1452), (0x4f0d, 2225), (0x4f0e, 1861), (0x4f0f, 3849),
+    (0x4f10, 3683), (0x4f11, 1936), (0x4f1a, 1678), (0x4f1c, 4488), (0x4f1d, 3416), (0x4f2f, 3647), (0x4f30, 4455), (0x4f34, 3693),
+    (0x4f36, 4295), (0x4f38, 2832), (0x4f3a, 2482), (0x4f3c, 2530), (0x4f3d, 1629), (0x4f43, 3338), (0x4f46, 3197), (0x4f47, 4459),
+    (0x4f4d, 1453), (0x4f4e, 3356), (0x4f4f, 2658), (0x4f50, 2369), (0x4f51, 4139), (0x4f53, 3147), (0x4f55, 1628), (0x4f57, 4458),
+    (0x4f59, 4165), (0x4f5a, 4454), (0x4f5b, 4456), (0x4f5c, 2427), (0x4f5d, 4457), (0x4f5e, 4891), (0x4f69, 4465), (0x4f6f, 4468),
+    (0x4f70, 4466), (0x4f73, 1631), (0x4f75, 3880), (0x4f76, 4460), (0x4f7b, 4464), (0x4f7c, 2244), (0x4f7f, 2483), (0x4f83, 1791),
+    (0x4f86, 4469), (0x4f88, 4461), (0x4f8b, 4296), (0x4f8d, 2531), (0x4f8f, 4462), (0x4f91, 4467), (0x4f96, 4470), (0x4f98, 4463),
+    (0x4f9b, 1974), (0x4f9d, 1454), (0x4fa0, 1975), (0x4fa1, 1630), (0x4fab, 4892), (0x4fad, 4036), (0x4fae, 3837), (0x4faf, 2245),
+    (0x4fb5, 2834), (0x4fb6, 4252), (0x4fbf, 3909), (0x4fc2, 2091), (0x4fc3, 3106), (0x4fc4, 1665), (0x4fca, 2682), (0x4fce, 4474),
+    (0x4fd0, 4479), (0x4fd1, 4477), (0x4fd4, 4472), (0x4fd7, 3116), (0x4fd8, 4475), (0x4fda, 4478), (0x4fdb, 4476), (0x4fdd, 3914),
+    (0x4fdf, 4473), (0x4fe1, 2833), (0x4fe3, 4030), (0x4fe4, 4480), (0x4fe5, 4481), (0x4fee, 2635), (0x4fef, 4494), (0x4ff3, 3619),
+    (0x4ff5, 3781), (0x4ff6, 4489), (0x4ff8, 3933), (0x4ffa, 1619), (0x4ffe, 4493), (0x5005, 4487), (0x5006, 4496), (0x5009, 3057),
+    (0x500b, 2197), (0x500d, 3631), (0x500f, 5960), (0x5011, 4495), (0x5012, 3444), (0x5014, 4484), (0x5016, 2247), (0x5019, 2246),
+    (0x501a, 4482), (0x501f, 2595), (0x5021, 4490), (0x5023, 3932), (0x5024, 3240), (0x5025, 4486), (0x5026, 2148), (0x5028, 4483),
+    (0x5029, 4491), (0x502a, 4485), (0x502b, 4278), (0x502c, 4492), (0x502d, 4356), (0x5036, 2043), (0x5039, 2147), (0x5043, 4497),
+    (0x5047, 4498), (0x5048, 4502), (0x5049, 1455), (0x504f, 3901), (0x5050, 4501), (0x5055, 4500), (0x5056, 4504), (0x505a, 4503),
+    (0x505c, 3357), (0x5065, 2149), (0x506c, 4505), (0x5072, 2574), (0x5074, 3107), (0x5075, 3358), (0x5076, 2059), (0x5078, 4506),
+    (0x507d, 1901), (0x5080, 4507), (0x5085, 4509), (0x508d, 3968), (0x5091, 2137), (0x5098, 2460), (0x5099, 3752), (0x509a, 4508),
+    (0x50ac, 2386), (0x50ad, 4170), (0x50b2, 4511), (0x50b3, 4514), (0x50b4, 4510), (0x50b5, 2385), (0x50b7, 2724), (0x50be, 2092),
+    (0x50c2, 4515), (0x50c5, 2020), (0x50c9, 4512), (0x50ca, 4513), (0x50cd, 3492), (0x50cf, 3099), (0x50d1, 1976), (0x50d5, 3992),
+    (0x50d6, 4516), (0x50da, 4258), (0x50de, 4517), (0x50e3, 4520), (0x50e5, 4518), (0x50e7, 3053), (0x50ed, 4519), (0x50ee, 4521),
+    (0x50f5, 4523), (0x50f9, 4522), (0x50fb, 3893), (0x5100, 1902), (0x5101, 4525), (0x5102, 4526), (0x5104, 1612), (0x5109, 4524),
+    (0x5112, 2621), (0x5114, 4529), (0x5115, 4528), (0x5116, 4527), (0x5118, 4471), (0x511a, 4530), (0x511f, 2725), (0x5121, 4531),
+    (0x512a, 4140), (0x5132, 4098), (0x5137, 4533), (0x513a, 4532), (0x513b, 4535), (0x513c, 4534), (0x513f, 4536), (0x5140, 4537),
+    (0x5141, 1493), (0x5143, 2182), (0x5144, 2094), (0x5145, 2659), (0x5146, 3286), (0x5147, 1977), (0x5148, 2985), (0x5149, 2248),
+    (0x514b, 2333), (0x514c, 4539), (0x514d, 4081), (0x514e, 3421), (0x5150, 2532), (0x5152, 4538), (0x5154, 4540), (0x515a, 3445),
+    (0x515c, 1776), (0x5162, 4541), (0x5165, 3571), (0x5168, 3027), (0x5169, 4543), (0x516a, 4544), (0x516b, 3677), (0x516c, 2249),
+    (0x516d, 4350), (0x516e, 4545), (0x5171, 1979), (0x5175, 3881), (0x5176, 3123), (0x5177, 2054), (0x5178, 3404), (0x517c, 2150),
+    (0x5180, 4546), (0x5182, 4547), (0x5185, 3543), (0x5186, 1566), (0x5189, 4550), (0x518a, 2442), (0x518c, 4549), (0x518d, 2387),
+    (0x518f, 4551), (0x5190, 6563), (0x5191, 4552), (0x5192, 3980), (0x5193, 4553), (0x5195, 4554), (0x5196, 4555), (0x5197, 2798),
+    (0x5199, 2581), (0x51a0, 1792), (0x51a2, 4558), (0x51a4, 4556), (0x51a5, 4070), (0x51a6, 4557), (0x51a8, 3817), (0x51a9, 4559),
+    (0x51aa, 4560), (0x51ab, 4561), (0x51ac, 3446), (0x51b0, 4565), (0x51b1, 4563), (0x51b2, 4564), (0x51b3, 4562), (0x51b4, 2416),
+    (0x51b5, 4566), (0x51b6, 4115), (0x51b7, 4297), (0x51bd, 4567), (0x51c4, 2921), (0x51c5, 4568), (0x51c6, 2689), (0x51c9, 4569),
+    (0x51cb, 3287), (0x51cc, 4260), (0x51cd, 3447), (0x51d6, 4642), (0x51db, 4570), (0x51dc, 7806), (0x51dd, 2010), (0x51e0, 4571),
+    (0x51e1, 4009), (0x51e6, 2703), (0x51e7, 3193), (0x51e9, 4573), (0x51ea, 3545), (0x51ed, 4574), (0x51f0, 4575), (0x51f1, 1705),
+    (0x51f5, 4576), (0x51f6, 1980), (0x51f8, 3521), (0x51f9, 1593), (0x51fa, 2679), (0x51fd, 3666), (0x51fe, 4577), (0x5200, 3448),
+    (0x5203, 2866), (0x5204, 4578), (0x5206, 3865), (0x5207, 2971), (0x5208, 1787), (0x520a, 1794), (0x520b, 4579), (0x520e, 4581),
+    (0x5211, 2093), (0x5214, 4580), (0x5217, 4312), (0x521d, 2704), (0x5224, 3694), (0x5225, 3897), (0x5227, 4582), (0x5229, 4223),
+    (0x522a, 4583), (0x522e, 4584), (0x5230, 3477), (0x5233, 4585), (0x5236, 2922), (0x5237, 2443), (0x5238, 2151), (0x5239, 4586),
+    (0x523a, 2484), (0x523b, 2334), (0x5243, 3359), (0x5244, 4588), (0x5247, 3108), (0x524a, 2428), (0x524b, 4589), (0x524c, 4590),
+    (0x524d, 3023), (0x524f, 4587), (0x5254, 4592), (0x5256, 3969), (0x525b, 2323), (0x525e, 4591), (0x5263, 2152), (0x5264, 2411),
+    (0x5265, 3648), (0x5269, 4595), (0x526a, 4593), (0x526f, 3850), (0x5270, 2799), (0x5271, 4602), (0x5272, 1759), (0x5273, 4596),
+    (0x5274, 4594), (0x5275, 3054), (0x527d, 4598), (0x527f, 4597), (0x5283, 1727), (0x5287, 2131), (0x5288, 4603), (0x5289, 4242),
+    (0x528d, 4599), (0x5291, 4604), (0x5292, 4601), (0x5294, 4600), (0x529b, 4276), (0x529f, 2250), (0x52a0, 1632), (0x52a3, 4313),
+    (0x52a9, 2716), (0x52aa, 3439), (0x52ab, 2324), (0x52ac, 4607), (0x52ad, 4608), (0x52b1, 4298), (0x52b4, 4334), (0x52b5, 4610),
+    (0x52b9, 2251), (0x52bc, 4609), (0x52be, 1706), (0x52c1, 4611), (0x52c3, 4001), (0x52c5, 3317), (0x52c7, 4141), (0x52c9, 3910),
+    (0x52cd, 4612), (0x52d2, 7478), (0x52d5, 3493), (0x52d7, 4613), (0x52d8, 1795), (0x52d9, 4060), (0x52dd, 2726), (0x52de, 4614),
+    (0x52df, 3924), (0x52e0, 4618), (0x52e2, 2923), (0x52e3, 4615), (0x52e4, 2021), (0x52e6, 4616), (0x52e7, 1796), (0x52f2, 2081),
+    (0x52f3, 4619), (0x52f5, 4620), (0x52f8, 4621), (0x52f9, 4622), (0x52fa, 2596), (0x52fe, 2252), (0x52ff, 4103), (0x5301, 4113),
+    (0x5302, 3564), (0x5305, 3934), (0x5306, 4623), (0x5308, 4624), (0x530d, 4626), (0x530f, 4628), (0x5310, 4627), (0x5315, 4629),
+    (0x5316, 1626), (0x5317, 3991), (0x5319, 2441), (0x531a, 4630), (0x531d, 3064), (0x5320, 2727), (0x5321, 1982), (0x5323, 4631),
+    (0x532a, 3724), (0x532f, 4632), (0x5331, 4633), (0x5333, 4634), (0x5338, 4635), (0x5339, 3763), (0x533a, 2045), (0x533b, 1478),
+    (0x533f, 3508), (0x5340, 4636), (0x5341, 2660), (0x5343, 2986), (0x5345, 4638), (0x5346, 4637), (0x5347, 2728), (0x5348, 2226),
+    (0x5349, 4640), (0x534a, 3695), (0x534d, 4641), (0x5351, 3725), (0x5352, 3121), (0x5353, 3179), (0x5354, 1981), (0x5357, 3555),
+    (0x5358, 3212), (0x535a, 3649), (0x535c, 3993), (0x535e, 4643), (0x5360, 2987), (0x5366, 2088), (0x5369, 4644), (0x536e, 4645),
+    (0x536f, 1515), (0x5370, 1494), (0x5371, 1862), (0x5373, 3109), (0x5374, 1928), (0x5375, 4216), (0x5377, 4648), (0x5378, 1620),
+    (0x537b, 4647), (0x537f, 1983), (0x5382, 4649), (0x5384, 4122), (0x5396, 4650), (0x5398, 4279), (0x539a, 2253), (0x539f, 2183),
+    (0x53a0, 4651), (0x53a5, 4653), (0x53a6, 4652), (0x53a8, 2882), (0x53a9, 1528), (0x53ad, 1565), (0x53ae, 4654), (0x53b0, 4655),
+    (0x53b3, 2184), (0x53b6, 4656), (0x53bb, 1957), (0x53c2, 2461), (0x53c3, 4657), (0x53c8, 4031), (0x53c9, 2370), (0x53ca, 1937),
+    (0x53cb, 4142), (0x53cc, 3055), (0x53cd, 3696), (0x53ce, 2630), (0x53d4, 2670), (0x53d6, 2609), (0x53d7, 2622), (0x53d9, 2717),
+    (0x53db, 3697), (0x53df, 4660), (0x53e1, 1538), (0x53e2, 3056), (0x53e3, 2254), (0x53e4, 2198), (0x53e5, 2044), (0x53e8, 4664),
+    (0x53e9, 3196), (0x53ea, 3195), (0x53eb, 1984), (0x53ec, 2729), (0x53ed, 4665), (0x53ee, 4663), (0x53ef, 1633), (0x53f0, 3171),
+    (0x53f1, 2561), (0x53f2, 2486), (0x53f3, 1509), (0x53f6, 1771), (0x53f7, 2325), (0x53f8, 2485), (0x53fa, 4666), (0x5401, 4667),
+    (0x5403, 1920), (0x5404, 1729), (0x5408, 2326), (0x5409, 1919), (0x540a, 3352), (0x540b, 1508), (0x540c, 3494), (0x540d, 4071),
+    (0x540e, 2256), (0x540f, 4224), (0x5410, 3422), (0x5411, 2255), (0x541b, 2082), (0x541d, 4676), (0x541f, 2040), (0x5420, 3989),
+    (0x5426, 3726), (0x5429, 4675), (0x542b, 1847), (0x542c, 4670), (0x542d, 4671), (0x542e, 4673), (0x5436, 4674), (0x5438, 1938),
+    (0x5439, 2884), (0x543b, 3866), (0x543c, 4672), (0x543d, 4668), (0x543e, 2228), (0x5440, 4669), (0x5442, 4327), (0x5446, 3935),
+    (0x5448, 3361), (0x5449, 2227), (0x544a, 2335), (0x544e, 4677), (0x5451, 3538), (0x545f, 4681), (0x5468, 2631), (0x546a, 2623),
+    (0x5470, 4684), (0x5471, 4682), (0x5473, 4044), (0x5475, 4679), (0x5476, 4688), (0x5477, 4683), (0x547b, 4686), (0x547c, 2199),
+    (0x547d, 4072), (0x5480, 4687), (0x5484, 4689), (0x5486, 4691), (0x548b, 2429), (0x548c, 4357), (0x548e, 4680), (0x548f, 4678),
+    (0x5490, 4690), (0x5492, 4685), (0x54a2, 4693), (0x54a4, 4702), (0x54a5, 4695), (0x54a8, 4699), (0x54ab, 4700), (0x54ac, 4696),
+    (0x54af, 4729), (0x54b2, 2422), (0x54b3, 1708), (0x54b8, 4694), (0x54bc, 4704), (0x54bd, 1495), (0x54be, 4703), (0x54c0, 1414),
+    (0x54c1, 3801), (0x54c2, 4701), (0x54c4, 4697), (0x54c7, 4692), (0x54c8, 4698), (0x54c9, 2389), (0x54d8, 4705), (0x54e1, 1496),
+    (0x54e2, 4714), (0x54e5, 4706), (0x54e6, 4707), (0x54e8, 2730), (0x54e9, 4020), (0x54ed, 4712), (0x54ee, 4711), (0x54f2, 3398),
+    (0x54fa, 4713), (0x54fd, 4710), (0x5504, 1523), (0x5506, 2371), (0x5507, 2835), (0x550f, 4708), (0x5510, 3449), (0x5514, 4709),
+    (0x5516, 1411), (0x552e, 4719), (0x552f, 4138), (0x5531, 2732), (0x5533, 4725), (0x5538, 4724), (0x5539, 4715), (0x553e, 3136),
+    (0x5540, 4716), (0x5544, 3180), (0x5545, 4721), (0x5546, 2731), (0x554c, 4718), (0x554f, 4109), (0x5553, 2095), (0x5556, 4722),
+    (0x5557, 4723), (0x555c, 4720), (0x555d, 4726), (0x5563, 4717), (0x557b, 4732), (0x557c, 4737), (0x557e, 4733), (0x5580, 4728),
+    (0x5583, 4738), (0x5584, 3024), (0x5587, 4740), (0x5589, 2257), (0x558a, 4730), (0x558b, 3288), (0x5598, 4734), (0x5599, 4727),
+    (0x559a, 1798), (0x559c, 1863), (0x559d, 1760), (0x559e, 4735), (0x559f, 4731), (0x55a7, 2153), (0x55a8, 4741), (0x55a9, 4739),
+    (0x55aa, 3058), (0x55ab, 1921), (0x55ac, 1985), (0x55ae, 4736), (0x55b0, 2057), (0x55b6, 1539), (0x55c4, 4745), (0x55c5, 4743),
+    (0x55c7, 4800), (0x55d4, 4748), (0x55da, 4742), (0x55dc, 4746), (0x55df, 4744), (0x55e3, 2487), (0x55e4, 4747), (0x55f7, 4750),
+    (0x55f9, 4755), (0x55fd, 4753), (0x55fe, 4752), (0x5606, 3213), (0x5609, 1634), (0x5614, 4749), (0x5616, 4751), (0x5617, 2733),
+    (0x5618, 1522), (0x561b, 4754), (0x5629, 1659), (0x562f, 4765), (0x5631, 2817), (0x5632, 4761), (0x5634, 4759), (0x5636, 4760),
+    (0x5638, 4762), (0x5642, 1532), (0x564c, 3032), (0x564e, 4756), (0x5650, 4757), (0x565b, 1781), (0x5664, 4764), (0x5668, 1864),
+    (0x566a, 4767), (0x566b, 4763), (0x566c, 4766), (0x5674, 3867), (0x5678, 3530), (0x567a, 3689), (0x5680, 4769), (0x5686, 4768),
+    (0x5687, 1728), (0x568a, 4770), (0x568f, 4773), (0x5694, 4772), (0x56a0, 4771), (0x56a2, 3596), (0x56a5, 4774), (0x56ae, 4775),
+    (0x56b4, 4777), (0x56b6, 4776), (0x56bc, 4779), (0x56c0, 4782), (0x56c1, 4780), (0x56c2, 4778), (0x56c3, 4781), (0x56c8, 4783),
+    (0x56ce, 4784), (0x56d1, 4785), (0x56d3, 4786), (0x56d7, 4787), (0x56d8, 4548), (0x56da, 2629), (0x56db, 2488), (0x56de, 1680),
+    (0x56e0, 1497), (0x56e3, 3231), (0x56ee, 4788), (0x56f0, 2353), (0x56f2, 1456), (0x56f3, 2881), (0x56f9, 4789), (0x56fa, 2200),
+    (0x56fd, 2336), (0x56ff, 4791), (0x5700, 4790), (0x5703, 3917), (0x5704, 4792), (0x5708, 4794), (0x5709, 4793), (0x570b, 4795),
+    (0x570d, 4796), (0x570f, 2154), (0x5712, 1567), (0x5713, 4797), (0x5716, 4799), (0x5718, 4798), (0x571c, 4801), (0x571f, 3441),
+    (0x5726, 4802), (0x5727, 1430), (0x5728, 2412), (0x572d, 2096), (0x5730, 3242), (0x5737, 4803), (0x5738, 4804), (0x573b, 4806),
+    (0x5740, 4807), (0x5742, 2417), (0x5747, 2022), (0x574a, 3970), (0x574e, 4805), (0x574f, 4808), (0x5750, 2382), (0x5751, 2258),
+    (0x5761, 4812), (0x5764, 2354), (0x5766, 3214), (0x5769, 4809), (0x576a, 3347), (0x577f, 4813), (0x5782, 2885), (0x5788, 4811),
+    (0x5789, 4814), (0x578b, 2098), (0x5793, 4815), (0x57a0, 4816), (0x57a2, 2259), (0x57a3, 1723), (0x57a4, 4818), (0x57aa, 4819),
+    (0x57b0, 4820), (0x57b3, 4817), (0x57c0, 4810), (0x57c3, 4821), (0x57c6, 4822), (0x57cb, 4015), (0x57ce, 2800), (0x57d2, 4824),
+    (0x57d3, 4825), (0x57d4, 4823), (0x57d6, 4827), (0x57dc, 3595), (0x57df, 1481), (0x57e0, 3813), (0x57e3, 4828), (0x57f4, 2818),
+    (0x57f7, 2562), (0x57f9, 3632), (0x57fa, 1865), (0x57fc, 2424), (0x5800, 4004), (0x5802, 3495), (0x5805, 2155), (0x5806, 3148),
+    (0x580a, 4826), (0x580b, 4829), (0x5815, 3137), (0x5819, 4830), (0x581d, 4831), (0x5821, 4833), (0x5824, 3362), (0x582a, 1799),
+    (0x582f, 7802), (0x5830, 1568), (0x5831, 3936), (0x5834, 2801), (0x5835, 3423), (0x583a, 2419), (0x583d, 4839), (0x5840, 3882),
+    (0x5841, 4290), (0x584a, 1681), (0x584b, 4835), (0x5851, 3033), (0x5852, 4838), (0x5854, 3450), (0x5857, 3424), (0x5858, 3451),
+    (0x5859, 3690), (0x585a, 3334), (0x585e, 2390), (0x5862, 4834), (0x5869, 1589), (0x586b, 3405), (0x5870, 4836), (0x5872, 4832),
+    (0x5875, 2867), (0x5879, 4840), (0x587e, 2677), (0x5883, 1986), (0x5885, 4841), (0x5893, 3925), (0x5897, 3100), (0x589c, 3327),
+    (0x589f, 4843), (0x58a8, 3994), (0x58ab, 4844), (0x58ae, 4849), (0x58b3, 3868), (0x58b8, 4848), (0x58b9, 4842), (0x58ba, 4845),
+    (0x58bb, 4847), (0x58be, 2355), (0x58c1, 3894), (0x58c5, 4850), (0x58c7, 3232), (0x58ca, 1682), (0x58cc, 2802), (0x58d1, 4852),
+    (0x58d3, 4851), (0x58d5, 2327), (0x58d7, 4853), (0x58d8, 4855), (0x58d9, 4854), (0x58dc, 4857), (0x58de, 4846), (0x58df, 4859),
+    (0x58e4, 4858), (0x58e5, 4856), (0x58eb, 2489), (0x58ec, 2868), (0x58ee, 3059), (0x58ef, 4860), (0x58f0, 2941), (0x58f1, 1486),
+    (0x58f2, 3639), (0x58f7, 3348), (0x58f9, 4862), (0x58fa, 4861), (0x58fb, 4863), (0x58fc, 4864), (0x58fd, 4865), (0x5902, 4866),
+    (0x5909, 3902), (0x590a, 4867), (0x590f, 1635), (0x5910, 4868), (0x5915, 4163), (0x5916, 1707), (0x5918, 4646), (0x5919, 2671),
+    (0x591a, 3132), (0x591b, 4869), (0x591c, 4116), (0x5922, 4061), (0x5925, 4871), (0x5927, 3172), (0x5929, 3406), (0x592a, 3133),
+    (0x592b, 3814), (0x592c, 4872), (0x592d, 4873), (0x592e, 1594), (0x5931, 2563), (0x5932, 4874), (0x5937, 1457), (0x5938, 4875),
+    (0x593e, 4876), (0x5944, 1569), (0x5947, 1866), (0x5948, 3541), (0x5949, 3937), (0x594e, 4880), (0x594f, 3060), (0x5950, 4879),
+    (0x5951, 2099), (0x5954, 4006), (0x5955, 4878), (0x5957, 3452), (0x5958, 4882), (0x595a, 4881), (0x5960, 4884), (0x5962, 4883),
+    (0x5965, 1595), (0x5967, 4885), (0x5968, 2734), (0x5969, 4887), (0x596a, 3200), (0x596c, 4886), (0x596e, 3872), (0x5973, 2718),
+    (0x5974, 3442), (0x5978, 4888), (0x597d, 2260), (0x5981, 4889), (0x5982, 3572), (0x5983, 3727), (0x5984, 4090), (0x598a, 3576),
+    (0x598d, 4898), (0x5993, 1903), (0x5996, 4172), (0x5999, 4056), (0x599b, 4993), (0x599d, 4890), (0x59a3, 4893), (0x59a5, 3138),
+    (0x59a8, 3971), (0x59ac, 3425), (0x59b2, 4894), (0x59b9, 4016), (0x59bb, 2391), (0x59be, 2735), (0x59c6, 4895), (0x59c9, 2491),
+    (0x59cb, 2490), (0x59d0, 1434), (0x59d1, 2201), (0x59d3, 2924), (0x59d4, 1458), (0x59d9, 4899), (0x59da, 4900), (0x59dc, 4897),
+    (0x59e5, 1527), (0x59e6, 1800), (0x59e8, 4896), (0x59ea, 4078), (0x59eb, 3776), (0x59f6, 1417), (0x59fb, 1498), (0x59ff, 2492),
+    (0x5a01, 1459), (0x5a03, 1412), (0x5a09, 4905), (0x5a11, 4903), (0x5a18, 4069), (0x5a1a, 4906), (0x5a1c, 4904), (0x5a1f, 4902),
+    (0x5a20, 2836), (0x5a25, 4901), (0x5a29, 3911), (0x5a2f, 2229), (0x5a35, 4910), (0x5a36, 4911), (0x5a3c, 2736), (0x5a40, 4907),
+    (0x5a41, 4335), (0x5a46, 3615), (0x5a49, 4909), (0x5a5a, 2356), (0x5a62, 4912), (0x5a66, 3815), (0x5a6a, 4913), (0x5a6c, 4908),
+    (0x5a7f, 4068), (0x5a92, 3633), (0x5a9a, 4914), (0x5a9b, 3777), (0x5abc, 4915), (0x5abd, 4919), (0x5abe, 4916), (0x5ac1, 1636),
+    (0x5ac2, 4918), (0x5ac9, 2564), (0x5acb, 4917), (0x5acc, 2156), (0x5ad0, 4931), (0x5ad6, 4924), (0x5ad7, 4921), (0x5ae1, 3263),
+    (0x5ae3, 4920), (0x5ae6, 4922), (0x5ae9, 4923), (0x5afa, 4925), (0x5afb, 4926), (0x5b09, 1867), (0x5b0b, 4928), (0x5b0c, 4927),
+    (0x5b16, 4929), (0x5b22, 2803), (0x5b2a, 4932), (0x5b2c, 3349), (0x5b30, 1540), (0x5b32, 4930), (0x5b36, 4933), (0x5b3e, 4934),
+    (0x5b40, 4937), (0x5b43, 4935), (0x5b45, 4936), (0x5b50, 2493), (0x5b51, 4938), (0x5b54, 2261), (0x5b55, 4939), (0x5b57, 2533),
+    (0x5b58, 3125), (0x5b5a, 4940), (0x5b5b, 4941), (0x5b5c, 2501), (0x5b5d, 2262), (0x5b5f, 4091), (0x5b63, 1887), (0x5b64, 2202),
+    (0x5b65, 4942), (0x5b66, 1747), (0x5b69, 4943), (0x5b6b, 3126), (0x5b70, 4944), (0x5b71, 4984), (0x5b73, 4945), (0x5b75, 4946),
+    (0x5b78, 4947), (0x5b7a, 4949), (0x5b80, 4950), (0x5b83, 4951), (0x5b85, 3181), (0x5b87, 1510), (0x5b88, 2610), (0x5b89, 1443),
+    (0x5b8b, 3062), (0x5b8c, 1801), (0x5b8d, 2558), (0x5b8f, 2263), (0x5b95, 3453), (0x5b97, 2632), (0x5b98, 1802), (0x5b99, 3267),
+    (0x5b9a, 3363), (0x5b9b, 1433), (0x5b9c, 1904), (0x5b9d, 3938), (0x5b9f, 2571), (0x5ba2, 1929), (0x5ba3, 2988), (0x5ba4, 2565),
+    (0x5ba5, 4143), (0x5ba6, 4952), (0x5bae, 1939), (0x5bb0, 2392), (0x5bb3, 1709), (0x5bb4, 1570), (0x5bb5, 2737), (0x5bb6, 1637),
+    (0x5bb8, 4953), (0x5bb9, 4173), (0x5bbf, 2672), (0x5bc2, 2605), (0x5bc3, 4954), (0x5bc4, 1868), (0x5bc5, 3527), (0x5bc6, 4050),
+    (0x5bc7, 4955), (0x5bc9, 4956), (0x5bcc, 3816), (0x5bd0, 4958), (0x5bd2, 1793), (0x5bd3, 2060), (0x5bd4, 4957), (0x5bdb, 1803),
+    (0x5bdd, 2837), (0x5bde, 4962), (0x5bdf, 2444), (0x5be1, 1638), (0x5be2, 4961), (0x5be4, 4959), (0x5be5, 4963), (0x5be6, 4960),
+    (0x5be7, 3582), (0x5be8, 5590), (0x5be9, 2838), (0x5beb, 4964), (0x5bee, 4261), (0x5bf0, 4965), (0x5bf3, 4967), (0x5bf5, 3289),
+    (0x5bf6, 4966), (0x5bf8, 2916), (0x5bfa, 2534), (0x5bfe, 3149), (0x5bff, 2624), (0x5c01, 3844), (0x5c02, 2989), (0x5c04, 2582),
+    (0x5c05, 4968), (0x5c06, 2738), (0x5c07, 4969), (0x5c08, 4970), (0x5c09, 1460), (0x5c0a, 3127), (0x5c0b, 2869), (0x5c0d, 4971),
+    (0x5c0e, 3496), (0x5c0f, 2739), (0x5c11, 2740), (0x5c13, 4972), (0x5c16, 2990), (0x5c1a, 2741), (0x5c20, 4973), (0x5c22, 4974),
+    (0x5c24, 4105), (0x5c28, 4975), (0x5c2d, 2011), (0x5c31, 2633), (0x5c38, 4976), (0x5c39, 4977), (0x5c3a, 2597), (0x5c3b, 2831),
+    (0x5c3c, 3561), (0x5c3d, 2871), (0x5c3e, 3753), (0x5c3f, 3573), (0x5c40, 2014), (0x5c41, 4978), (0x5c45, 1958), (0x5c46, 4979),
+    (0x5c48, 2067), (0x5c4a, 3524), (0x5c4b, 1613), (0x5c4d, 2494), (0x5c4e, 4980), (0x5c4f, 4983), (0x5c50, 4982), (0x5c51, 2066),
+    (0x5c53, 4981), (0x5c55, 3407), (0x5c5e, 3117), (0x5c60, 3426), (0x5c61, 2577), (0x5c64, 3063), (0x5c65, 4225), (0x5c6c, 4985),
+    (0x5c6e, 4986), (0x5c6f, 3531), (0x5c71, 2462), (0x5c76, 4988), (0x5c79, 4989), (0x5c8c, 4990), (0x5c90, 1869), (0x5c91, 4991),
+    (0x5c94, 4992), (0x5ca1, 1609), (0x5ca8, 3034), (0x5ca9, 1853), (0x5cab, 4994), (0x5cac, 4049), (0x5cb1, 3151), (0x5cb3, 1748),
+    (0x5cb6, 4996), (0x5cb7, 4998), (0x5cb8, 1848), (0x5cbb, 4995), (0x5cbc, 4997), (0x5cbe, 5000), (0x5cc5, 4999), (0x5cc7, 5001),
+    (0x5cd9, 5002), (0x5ce0, 3506), (0x5ce1, 1987), (0x5ce8, 1666), (0x5ce9, 5003), (0x5cea, 5008), (0x5ced, 5006), (0x5cef, 3940),
+    (0x5cf0, 3939), (0x5cf6, 3454), (0x5cfa, 5005), (0x5cfb, 2683), (0x5cfd, 5004), (0x5d07, 2901), (0x5d0b, 5009), (0x5d0e, 2423),
+    (0x5d11, 5015), (0x5d14, 5016), (0x5d15, 5010), (0x5d16, 1710), (0x5d17, 5011), (0x5d18, 5020), (0x5d19, 5019), (0x5d1a, 5018),
+    (0x5d1b, 5014), (0x5d1f, 5013), (0x5d22, 5017), (0x5d29, 3941), (0x5d4b, 5024), (0x5d4c, 5021), (0x5d4e, 5023), (0x5d50, 4217),
+    (0x5d52, 5022), (0x5d5c, 5012), (0x5d69, 2902), (0x5d6c, 5025), (0x5d6f, 2372), (0x5d73, 5026), (0x5d76, 5027), (0x5d82, 5030),
+    (0x5d84, 5029), (0x5d87, 5028), (0x5d8b, 3455), (0x5d8c, 5007), (0x5d90, 5036), (0x5d9d, 5032), (0x5da2, 5031), (0x5dac, 5033),
+    (0x5dae, 5034), (0x5db7, 5037), (0x5dba, 4299), (0x5dbc, 5038), (0x5dbd, 5035), (0x5dc9, 5039), (0x5dcc, 1849), (0x5dcd, 5040),
+    (0x5dd2, 5042), (0x5dd3, 5041), (0x5dd6, 5043), (0x5ddb, 5044), (0x5ddd, 2991), (0x5dde, 2634), (0x5de1, 2699), (0x5de3, 3074),
+    (0x5de5, 2264), (0x5de6, 2373), (0x5de7, 2265), (0x5de8, 1959), (0x5deb, 5045), (0x5dee, 2374), (0x5df1, 2203), (0x5df2, 5046),
+    (0x5df3, 4047), (0x5df4, 3606), (0x5df5, 5047), (0x5df7, 2266), (0x5dfb, 1797), (0x5dfd, 3202), (0x5dfe, 2023), (0x5e02, 2495),
+    (0x5e03, 3818), (0x5e06, 3698), (0x5e0b, 5048), (0x5e0c, 1870), (0x5e11, 5051), (0x5e16, 3290), (0x5e19, 5050), (0x5e1a, 5049),
+    (0x5e1b, 5052), (0x5e1d, 3364), (0x5e25, 2886), (0x5e2b, 2496), (0x5e2d, 2955), (0x5e2f, 3152), (0x5e30, 1881), (0x5e33, 3291),
+    (0x5e36, 5053), (0x5e37, 5054), (0x5e38, 2804), (0x5e3d, 3972), (0x5e40, 5057), (0x5e43, 5056), (0x5e44, 5055), (0x5e45, 3852),
+    (0x5e47, 5064), (0x5e4c, 4005), (0x5e4e, 5058), (0x5e54, 5060), (0x5e55, 4022), (0x5e57, 5059), (0x5e5f, 5061), (0x5e61, 3673),
+    (0x5e62, 5062), (0x5e63, 3883), (0x5e64, 5063), (0x5e72, 1804), (0x5e73, 3884), (0x5e74, 3586), (0x5e75, 5065), (0x5e76, 5066),
+    (0x5e78, 2267), (0x5e79, 1805), (0x5e7a, 5067), (0x5e7b, 2185), (0x5e7c, 4171), (0x5e7d, 4144), (0x5e7e, 1871), (0x5e7f, 5069),
+    (0x5e81, 3292), (0x5e83, 2268), (0x5e84, 2742), (0x5e87, 3728), (0x5e8a, 2743), (0x5e8f, 2719), (0x5e95, 3365), (0x5e96, 3942),
+    (0x5e97, 3408), (0x5e9a, 2269), (0x5e9c, 3819), (0x5ea0, 5070), (0x5ea6, 3440), (0x5ea7, 2383), (0x5eab, 2204), (0x5ead, 3366),
+    (0x5eb5, 1444), (0x5eb6, 2709), (0x5eb7, 2270), (0x5eb8, 4174), (0x5ec1, 5071), (0x5ec2, 5072), (0x5ec3, 3620), (0x5ec8, 5073),
+    (0x5ec9, 4316), (0x5eca, 4336), (0x5ecf, 5075), (0x5ed0, 5074), (0x5ed3, 1730), (0x5ed6, 5076), (0x5eda, 5079), (0x5edb, 5080),
+    (0x5edd, 5078), (0x5edf, 3791), (0x5ee0, 2744), (0x5ee1, 5082), (0x5ee2, 5081), (0x5ee3, 5077), (0x5ee8, 5083), (0x5ee9, 5084),
+    (0x5eec, 5085), (0x5ef0, 5088), (0x5ef1, 5086), (0x5ef3, 5087), (0x5ef4, 5089), (0x5ef6, 1571), (0x5ef7, 3367), (0x5ef8, 5090),
+    (0x5efa, 2157), (0x5efb, 1683), (0x5efc, 3593), (0x5efe, 5091), (0x5eff, 3568), (0x5f01, 3912), (0x5f03, 5092), (0x5f04, 4337),
+    (0x5f09, 5093), (0x5f0a, 3885), (0x5f0b, 5096), (0x5f0c, 4418), (0x5f0d, 4434), (0x5f0f, 2553), (0x5f10, 3562), (0x5f11, 5097),
+    (0x5f13, 1940), (0x5f14, 3293), (0x5f15, 1499), (0x5f16, 5098), (0x5f17, 3859), (0x5f18, 2271), (0x5f1b, 3243), (0x5f1f, 3368),
+    (0x5f25, 4120), (0x5f26, 2186), (0x5f27, 2205), (0x5f29, 5099), (0x5f2d, 5100), (0x5f2f, 5106), (0x5f31, 2606), (0x5f35, 3294),
+    (0x5f37, 1988), (0x5f38, 5101), (0x5f3c, 3770), (0x5f3e, 3233), (0x5f41, 5102), (0x5f48, 5103), (0x5f4a, 1989), (0x5f4c, 5104),
+    (0x5f4e, 5105), (0x5f51, 5107), (0x5f53, 3469), (0x5f56, 5108), (0x5f57, 5109), (0x5f59, 5110), (0x5f5c, 5095), (0x5f5d, 5094),
+    (0x5f61, 5111), (0x5f62, 2100), (0x5f66, 3766), (0x5f69, 2393), (0x5f6a, 3782), (0x5f6b, 3295), (0x5f6c, 3802), (0x5f6d, 5112),
+    (0x5f70, 2745), (0x5f71, 1541), (0x5f73, 5113), (0x5f77, 5114), (0x5f79, 4123), (0x5f7c, 3729), (0x5f7f, 5117), (0x5f80, 1596),
+    (0x5f81, 2925), (0x5f82, 5116), (0x5f83, 5115), (0x5f84, 2101), (0x5f85, 3153), (0x5f87, 5121), (0x5f88, 5119), (0x5f8a, 5118),
+    (0x5f8b, 4236), (0x5f8c, 2230), (0x5f90, 2720), (0x5f91, 5120), (0x5f92, 3427), (0x5f93, 2661), (0x5f97, 3509), (0x5f98, 5124),
+    (0x5f99, 5123), (0x5f9e, 5122), (0x5fa0, 5125), (0x5fa1, 2231), (0x5fa8, 5126), (0x5fa9, 3851), (0x5faa, 2690), (0x5fad, 5127),
+    (0x5fae, 3754), (0x5fb3, 3510), (0x5fb4, 3296), (0x5fb9, 3399), (0x5fbc, 5128), (0x5fbd, 1890), (0x5fc3, 2839), (0x5fc5, 3771),
+    (0x5fcc, 1872), (0x5fcd, 3577), (0x5fd6, 5129), (0x5fd7, 2497), (0x5fd8, 3973), (0x5fd9, 3974), (0x5fdc, 1597), (0x5fdd, 5134),
+    (0x5fe0, 3268), (0x5fe4, 5131), (0x5feb, 1684), (0x5ff0, 5182), (0x5ff1, 5133), (0x5ff5, 3587), (0x5ff8, 5132), (0x5ffb, 5130),
+    (0x5ffd, 2345), (0x5fff, 5136), (0x600e, 5142), (0x600f, 5148), (0x6010, 5140), (0x6012, 3443), (0x6015, 5145), (0x6016, 3820),
+    (0x6019, 5139), (0x601b, 5144), (0x601c, 4300), (0x601d, 2498), (0x6020, 3154), (0x6021, 5137), (0x6025, 1941), (0x6026, 5147),
+    (0x6027, 2926), (0x6028, 1572), (0x6029, 5141), (0x602a, 1685), (0x602b, 5146), (0x602f, 1990), (0x6031, 5143), (0x603a, 5149),
+    (0x6041, 5151), (0x6042, 5161), (0x6043, 5159), (0x6046, 5156), (0x604a, 5155), (0x604b, 4317), (0x604d, 5157), (0x6050, 1991),
+    (0x6052, 2272), (0x6055, 2721), (0x6059, 5164), (0x605a, 5150), (0x605f, 5154), (0x6060, 5138), (0x6062, 1687), (0x6063, 5158),
+    (0x6064, 5160), (0x6065, 3244), (0x6068, 2357), (0x6069, 1621), (0x606a, 5152), (0x606b, 5163), (0x606c, 5162), (0x606d, 1992),
+    (0x606f, 3110), (0x6070, 1761), (0x6075, 2102), (0x6077, 5153), (0x6081, 5165), (0x6083, 5168), (0x6084, 5170), (0x6089, 2566),
+    (0x608b, 5176), (0x608c, 3369), (0x608d, 5166), (0x6092, 5174), (0x6094, 1686), (0x6096, 5172), (0x6097, 5173), (0x609a, 5169),
+    (0x609b, 5171), (0x609f, 2232), (0x60a0, 4145), (0x60a3, 1806), (0x60a6, 1560), (0x60a7, 5175), (0x60a9, 3597), (0x60aa, 1422),
+    (0x60b2, 3730), (0x60b3, 5135), (0x60b4, 5181), (0x60b5, 5185), (0x60b6, 4110), (0x60b8, 5178), (0x60bc, 3456), (0x60bd, 5183),
+    (0x60c5, 2805), (0x60c6, 5184), (0x60c7, 3532), (0x60d1, 4362), (0x60d3, 5180), (0x60d8, 5186), (0x60da, 2346), (0x60dc, 2956),
+    (0x60df, 1461), (0x60e0, 5179), (0x60e1, 5177), (0x60e3, 3065), (0x60e7, 5167), (0x60e8, 2463), (0x60f0, 3139), (0x60f1, 5198),
+    (0x60f3, 3066), (0x60f4, 5193), (0x60f6, 5190), (0x60f7, 5191), (0x60f9, 2607), (0x60fa, 5194), (0x60fb, 5197), (0x6100, 5192),
+    (0x6101, 2636), (0x6103, 5195), (0x6106, 5189), (0x6108, 4133), (0x6109, 4132), (0x610d, 5199), (0x610e, 5200), (0x610f, 1462),
+    (0x6115, 5188), (0x611a, 2055), (0x611b, 1415), (0x611f, 1807), (0x6121, 5196), (0x6127, 5204), (0x6128, 5203), (0x612c, 5208),
+    (0x6134, 5209), (0x613c, 5207), (0x613d, 5210), (0x613e, 5202), (0x613f, 5206), (0x6142, 5211), (0x6144, 5212), (0x6147, 5201),
+    (0x6148, 2535), (0x614a, 5205), (0x614b, 3155), (0x614c, 2273), (0x614d, 5187), (0x614e, 2840), (0x6153, 5225), (0x6155, 3926),
+    (0x6158, 5215), (0x6159, 5216), (0x615a, 5217), (0x615d, 5224), (0x615f, 5223), (0x6162, 4040), (0x6163, 1808), (0x6165, 5221),
+    (0x6167, 2104), (0x6168, 1711), (0x616b, 5218), (0x616e, 4253), (0x616f, 5220), (0x6170, 1463), (0x6171, 5222), (0x6173, 5213),
+    (0x6174, 5219), (0x6175, 5226), (0x6176, 2103), (0x6177, 5214), (0x617e, 4196), (0x6182, 4146), (0x6187, 5229), (0x618a, 5233),
+    (0x618e, 3101), (0x6190, 4318), (0x6191, 5234), (0x6194, 5231), (0x6196, 5228), (0x6199, 5227), (0x619a, 5232), (0x61a4, 3869),
+    (0x61a7, 3497), (0x61a9, 2105), (0x61ab, 5235), (0x61ac, 5230), (0x61ae, 5236), (0x61b2, 2158), (0x61b6, 1614), (0x61ba, 5244),
+    (0x61be, 1809), (0x61c3, 5242), (0x61c6, 5243), (0x61c7, 2358), (0x61c8, 5241), (0x61c9, 5239), (0x61ca, 5238), (0x61cb, 5245),
+    (0x61cc, 5237), (0x61cd, 5247), (0x61d0, 1688), (0x61e3, 5249), (0x61e6, 5248), (0x61f2, 3297), (0x61f4, 5252), (0x61f6, 5250),
+    (0x61f7, 5240), (0x61f8, 2159), (0x61fa, 5251), (0x61fc, 5255), (0x61fd, 5254), (0x61fe, 5256), (0x61ff, 5253), (0x6200, 5257),
+    (0x6208, 5258), (0x6209, 5259), (0x620a, 3927), (0x620c, 5261), (0x620d, 5260), (0x620e, 2662), (0x6210, 2927), (0x6211, 1667),
+    (0x6212, 1689), (0x6214, 5262), (0x6216, 1440), (0x621a, 2957), (0x621b, 5263), (0x621d, 7084), (0x621e, 5264), (0x621f, 2132),
+    (0x6221, 5265), (0x6226, 2992), (0x622a, 5266), (0x622e, 5267), (0x622f, 1905), (0x6230, 5268), (0x6232, 5269), (0x6233, 5270),
+    (0x6234, 3156), (0x6238, 2206), (0x623b, 4106), (0x623f, 3975), (0x6240, 2705), (0x6241, 5271), (0x6247, 2993), (0x6248, 7266),
+    (0x6249, 3731), (0x624b, 2611), (0x624d, 2394), (0x624e, 5272), (0x6253, 3140), (0x6255, 3860), (0x6258, 3182), (0x625b, 5275),
+    (0x625e, 5273), (0x6260, 5276), (0x6263, 5274), (0x6268, 5277), (0x626e, 3870), (0x6271, 1432), (0x6276, 3821), (0x6279, 3732),
+    (0x627c, 5278), (0x627e, 5281), (0x627f, 2746), (0x6280, 1906), (0x6282, 5279), (0x6283, 5286), (0x6284, 2747), (0x6289, 5280),
+    (0x628a, 3607), (0x6291, 4197), (0x6292, 5282), (0x6293, 5283), (0x6294, 5287), (0x6295, 3457), (0x6296, 5284), (0x6297, 2274),
+    (0x6298, 2975), (0x629b, 5301), (0x629c, 3685), (0x629e, 3183), (0x62ab, 3733), (0x62ac, 5370), (0x62b1, 3943), (0x62b5, 3370),
+    (0x62b9, 4032), (0x62bb, 5290), (0x62bc, 1598), (0x62bd, 3269), (0x62c2, 5299), (0x62c5, 3215), (0x62c6, 5293), (0x62c7, 5300),
+    (0x62c8, 5295), (0x62c9, 5302), (0x62ca, 5298), (0x62cc, 5297), (0x62cd, 3650), (0x62cf, 5291), (0x62d0, 1690), (0x62d1, 5289),
+    (0x62d2, 1960), (0x62d3, 3184), (0x62d4, 5285), (0x62d7, 5288), (0x62d8, 2275), (0x62d9, 2972), (0x62db, 2748), (0x62dc, 5296),
+    (0x62dd, 3621), (0x62e0, 1961), (0x62e1, 1731), (0x62ec, 1762), (0x62ed, 2820), (0x62ee, 5304), (0x62ef, 5309), (0x62f1, 5305),
+    (0x62f3, 2160), (0x62f5, 5310), (0x62f6, 2445), (0x62f7, 2328), (0x62fe, 2637), (0x62ff, 5292), (0x6301, 2536), (0x6302, 5307),
+    (0x6307, 2499), (0x6308, 5308), (0x6309, 1445), (0x630c, 5303), (0x6311, 3298), (0x6319, 1962), (0x631f, 1993), (0x6327, 5306),
+    (0x6328, 1416), (0x632b, 2384), (0x632f, 2841), (0x633a, 3371), (0x633d, 3717), (0x633e, 5312), (0x633f, 3069), (0x6349, 3111),
+    (0x634c, 2454), (0x634d, 5313), (0x634f, 5315), (0x6350, 5311), (0x6355, 3918), (0x6357, 3318), (0x635c, 3067), (0x6367, 3944),
+    (0x6368, 2583), (0x6369, 5327), (0x636b, 5326), (0x636e, 2907), (0x6372, 2161), (0x6376, 5320), (0x6377, 2750), (0x637a, 3549),
+    (0x637b, 3588), (0x6380, 5318), (0x6383, 3068), (0x6388, 2625), (0x6389, 5323), (0x638c, 2749), (0x638e, 5317), (0x638f, 5322),
+    (0x6392, 3622), (0x6396, 5316), (0x6398, 2068), (0x639b, 1752), (0x639f, 5324), (0x63a0, 4240), (0x63a1, 2395), (0x63a2, 3216),
+    (0x63a3, 5321), (0x63a5, 2973), (0x63a7, 2276), (0x63a8, 2887), (0x63a9, 1573), (0x63aa, 3035), (0x63ab, 5319), (0x63ac, 1916),
+    (0x63b2, 2106), (0x63b4, 3336), (0x63b5, 5325), (0x63bb, 3070), (0x63be, 5328), (0x63c0, 5330), (0x63c3, 3124), (0x63c4, 5336),
+    (0x63c6, 5331), (0x63c9, 5333), (0x63cf, 3792), (0x63d0, 3372), (0x63d2, 5334), (0x63d6, 4147), (0x63da, 4175), (0x63db, 1810),
+    (0x63e1, 1423), (0x63e3, 5332), (0x63e9, 5329), (0x63ee, 1873), (0x63f4, 1574), (0x63f6, 5335), (0x63fa, 4176), (0x6406, 5339),
+    (0x640d, 3128), (0x640f, 5346), (0x6413, 5340), (0x6416, 5337), (0x6417, 5344), (0x641c, 5314), (0x6426, 5341), (0x6428, 5345),
+    (0x642c, 3699), (0x642d, 3458), (0x6434, 5338), (0x6436, 5342), (0x643a, 2107), (0x643e, 2430), (0x6442, 2974), (0x644e, 5350),
+    (0x6458, 3389), (0x6467, 5347), (0x6469, 4011), (0x646f, 5348), (0x6476, 5349), (0x6478, 4087), (0x647a, 2915), (0x6483, 2133),
+    (0x6488, 5356), (0x6492, 2464), (0x6493, 5353), (0x6495, 5352), (0x649a, 3589), (0x649e, 3498), (0x64a4, 3400), (0x64a5, 5354),
+    (0x64a9, 5355), (0x64ab, 3838), (0x64ad, 3608), (0x64ae, 2446), (0x64b0, 2994), (0x64b2, 3995), (0x64b9, 1732), (0x64bb, 5362),
+    (0x64bc, 5357), (0x64c1, 4177), (0x64c2, 5364), (0x64c5, 5360), (0x64c7, 5361), (0x64cd, 3071), (0x64d2, 5359), (0x64d4, 5294),
+    (0x64d8, 5363), (0x64da, 5358), (0x64e0, 5368), (0x64e1, 5369), (0x64e2, 3390), (0x64e3, 5371), (0x64e6, 2447), (0x64e7, 5366),
+    (0x64ec, 1907), (0x64ef, 5372), (0x64f1, 5365), (0x64f2, 5376), (0x64f4, 5375), (0x64f6, 5374), (0x64fa, 5377), (0x64fd, 5379),
+    (0x64fe, 2806), (0x6500, 5378), (0x6505, 5382), (0x6518, 5380), (0x651c, 5381), (0x651d, 5343), (0x6523, 5384), (0x6524, 5383),
+    (0x652a, 5351), (0x652b, 5385), (0x652c, 5373), (0x652f, 2500), (0x6534, 5386), (0x6535, 5387), (0x6536, 5389), (0x6537, 5388),
+    (0x6538, 5390), (0x6539, 1691), (0x653b, 2277), (0x653e, 3945), (0x653f, 2928), (0x6545, 2207), (0x6548, 5392), (0x654d, 5395),
+    (0x654f, 3809), (0x6551, 1942), (0x6555, 5394), (0x6556, 5393), (0x6557, 3623), (0x6558, 5396), (0x6559, 1994), (0x655d, 5398),
+    (0x655e, 5397), (0x6562, 1811), (0x6563, 2465), (0x6566, 3533), (0x656c, 2108), (0x6570, 2903), (0x6572, 5399), (0x6574, 2929),
+    (0x6575, 3391), (0x6577, 3822), (0x6578, 5400), (0x6582, 5401), (0x6583, 5402), (0x6587, 3877), (0x6588, 4948), (0x6589, 2951),
+    (0x658c, 3803), (0x658e, 2405), (0x6590, 3734), (0x6591, 3700), (0x6597, 3428), (0x6599, 4262), (0x659b, 5404), (0x659c, 2585),
+    (0x659f, 5405), (0x65a1, 1431), (0x65a4, 2025), (0x65a5, 2958), (0x65a7, 3823), (0x65ab, 5406), (0x65ac, 2477), (0x65ad, 3234),
+    (0x65af, 2502), (0x65b0, 2842), (0x65b7, 5407), (0x65b9, 3946), (0x65bc, 1590), (0x65bd, 2503), (0x65c1, 5410), (0x65c3, 5408),
+    (0x65c4, 5411), (0x65c5, 4254), (0x65c6, 5409), (0x65cb, 3004), (0x65cc, 5412), (0x65cf, 3119), (0x65d2, 5413), (0x65d7, 1875),
+    (0x65d9, 5415), (0x65db, 5414), (0x65e0, 5416), (0x65e1, 5417), (0x65e2, 1876), (0x65e5, 3569), (0x65e6, 3217), (0x65e7, 1955),
+    (0x65e8, 2504), (0x65e9, 3072), (0x65ec, 2691), (0x65ed, 1425), (0x65f1, 5418), (0x65fa, 1599), (0x65fb, 5422), (0x6602, 2278),
+    (0x6603, 5421), (0x6606, 2360), (0x6607, 2751), (0x660a, 5420), (0x660c, 2752), (0x660e, 4073), (0x660f, 2359), (0x6613, 1464),
+    (0x6614, 2959), (0x661c, 5427), (0x661f, 2930), (0x6620, 1542), (0x6625, 2684), (0x6627, 4017), (0x6628, 2431), (0x662d, 2753),
+    (0x662f, 2920), (0x6634, 5426), (0x6635, 5424), (0x6636, 5425), (0x663c, 3270), (0x663f, 5457), (0x6641, 5431), (0x6642, 2537),
+    (0x6643, 2279), (0x6644, 5429), (0x6649, 5430), (0x664b, 2843), (0x664f, 5428), (0x6652, 2458), (0x665d, 5433), (0x665e, 5432),
+    (0x665f, 5437), (0x6662, 5438), (0x6664, 5434), (0x6666, 1693), (0x6667, 5435), (0x6668, 5436), (0x6669, 3718), (0x666e, 3824),
+    (0x666f, 2109), (0x6670, 5439), (0x6674, 2931), (0x6676, 2754), (0x667a, 3245), (0x6681, 2012), (0x6683, 5440), (0x6684, 5444),
+    (0x6687, 1640), (0x6688, 5441), (0x6689, 5443), (0x668e, 5442), (0x6691, 2706), (0x6696, 3235), (0x6697, 1446), (0x6698, 5445),
+    (0x669d, 5446), (0x66a2, 3299), (0x66a6, 4310), (0x66ab, 2478), (0x66ae, 3928), (0x66b4, 3976), (0x66b8, 5453), (0x66b9, 5448),
+    (0x66bc, 5451), (0x66be, 5450), (0x66c1, 5447), (0x66c4, 5452), (0x66c7, 3539), (0x66c9, 5449), (0x66d6, 5454), (0x66d9, 2707),
+    (0x66da, 5455), (0x66dc, 4178), (0x66dd, 3659), (0x66e0, 5456), (0x66e6, 5458), (0x66e9, 5459), (0x66f0, 5460), (0x66f2, 2015),
+    (0x66f3, 1543), (0x66f4, 2280), (0x66f5, 5461), (0x66f7, 5462), (0x66f8, 2712), (0x66f9, 3073), (0x66fc, 4661), (0x66fd, 3037),
+    (0x66fe, 3036), (0x66ff, 3157), (0x6700, 2388), (0x6703, 4499), (0x6708, 2145), (0x6709, 4148), (0x670b, 3947), (0x670d, 3853),
+    (0x670f, 5463), (0x6714, 2432), (0x6715, 3320), (0x6716, 5464), (0x6717, 4338), (0x671b, 3977), (0x671d, 3300), (0x671e, 5465),
+    (0x671f, 1877), (0x6726, 5466), (0x6727, 5467), (0x6728, 4099), (0x672a, 4045), (0x672b, 4033), (0x672c, 4007), (0x672d, 2448),
+    (0x672e, 5469), (0x6731, 2612), (0x6734, 3996), (0x6736, 5471), (0x6737, 5474), (0x6738, 5473), (0x673a, 1874), (0x673d, 1943),
+    (0x673f, 5470), (0x6741, 5472), (0x6746, 5475), (0x6749, 2908), (0x674e, 4226), (0x674f, 1450), (0x6750, 2413), (0x6751, 3129),
+    (0x6753, 2598), (0x6756, 2808), (0x6759, 5478), (0x675c, 3429), (0x675e, 5476), (0x675f, 3112), (0x6760, 5477), (0x6761, 2807),
+    (0x6762, 4102), (0x6763, 5479), (0x6764, 5480), (0x6765, 4207), (0x676a, 5485), (0x676d, 2281), (0x676f, 3624), (0x6770, 5482),
+    (0x6771, 3459), (0x6772, 5419), (0x6773, 5423), (0x6775, 1926), (0x6777, 3610), (0x677c, 5484), (0x677e, 2755), (0x677f, 3701),
+    (0x6785, 5490), (0x6787, 3755), (0x6789, 5481), (0x678b, 5487), (0x678c, 5486), (0x6790, 2960), (0x6795, 4024), (0x6797, 4280),
+    (0x679a, 4018), (0x679c, 1641), (0x679d, 2505), (0x67a0, 4363), (0x67a1, 5489), (0x67a2, 2904), (0x67a6, 5488), (0x67a9, 5483),
+    (0x67af, 2208), (0x67b3, 5495), (0x67b4, 5493), (0x67b6, 1642), (0x67b7, 5491), (0x67b8, 5497), (0x67b9, 5503), (0x67c1, 3141),
+    (0x67c4, 3886), (0x67c6, 5505), (0x67ca, 3761), (0x67ce, 5504), (0x67cf, 3651), (0x67d0, 3978), (0x67d1, 1812), (0x67d3, 3000),
+    (0x67d4, 2663), (0x67d8, 3340), (0x67da, 4149), (0x67dd, 5500), (0x67de, 5499), (0x67e2, 5501), (0x67e4, 5498), (0x67e7, 5506),
+    (0x67e9, 5496), (0x67ec, 5494), (0x67ee, 5502), (0x67ef, 5492), (0x67f1, 3271), (0x67f3, 4129), (0x67f4, 2575), (0x67f5, 2433),
+    (0x67fb, 2375), (0x67fe, 4026), (0x67ff, 1724), (0x6802, 3335), (0x6803, 3519), (0x6804, 1544), (0x6813, 2995), (0x6816, 2933),
+    (0x6817, 2077), (0x681e, 5508), (0x6821, 2282), (0x6822, 1783), (0x6829, 5510), (0x682a, 1775), (0x682b, 5516), (0x6832, 5513),
+    (0x6834, 2996), (0x6838, 1734), (0x6839, 2361), (0x683c, 1733), (0x683d, 2396), (0x6840, 5511), (0x6841, 2136), (0x6842, 2110),
+    (0x6843, 3460), (0x6846, 5509), (0x6848, 1447), (0x684d, 5512), (0x684e, 5514), (0x6850, 2018), (0x6851, 2079), (0x6853, 1813),
+    (0x6854, 1922), (0x6859, 5517), (0x685c, 2438), (0x685d, 4028), (0x685f, 2466), (0x6863, 5518), (0x6867, 3775), (0x6874, 5530),
+    (0x6876, 1616), (0x6877, 5519), (0x687e, 5536), (0x687f, 5520), (0x6881, 4263), (0x6883, 5527), (0x6885, 3634), (0x688d, 5535),
+    (0x688f, 5522), (0x6893, 1429), (0x6894, 5524), (0x6897, 2283), (0x689b, 5526), (0x689d, 5525), (0x689f, 5521), (0x68a0, 5532),
+    (0x68a2, 2756), (0x68a6, 4870), (0x68a7, 2233), (0x68a8, 4227), (0x68ad, 5523), (0x68af, 3373), (0x68b0, 1694), (0x68b1, 2362),
+    (0x68b3, 5515), (0x68b5, 5531), (0x68b6, 1756), (0x68b9, 5529), (0x68ba, 5533), (0x68bc, 3461), (0x68c4, 1879), (0x68c6, 5563),
+    (0x68c9, 4082), (0x68ca, 5538), (0x68cb, 1878), (0x68cd, 5545), (0x68d2, 3979), (0x68d4, 5546), (0x68d5, 5548), (0x68d7, 5552),
+    (0x68d8, 5540), (0x68da, 3205), (0x68df, 3462), (0x68e0, 5556), (0x68e1, 5543), (0x68e3, 5553), (0x68e7, 5547), (0x68ee, 2844),
+    (0x68ef, 5557), (0x68f2, 2932), (0x68f9, 5555), (0x68fa, 1814), (0x6900, 4371), (0x6901, 5537), (0x6904, 5551), (0x6905, 1465),
+    (0x6908, 5539), (0x690b, 4067), (0x690c, 5544), (0x690d, 2821), (0x690e, 3328), (0x690f, 5534), (0x6912, 5550), (0x6919, 2909),
+    (0x691a, 5560), (0x691b, 1772), (0x691c, 2162), (0x6921, 5562), (0x6922, 5541), (0x6923, 5561), (0x6925, 5554), (0x6926, 5542),
+    (0x6928, 5558), (0x692a, 5559), (0x6930, 5576), (0x6934, 3523), (0x6936, 5549), (0x6939, 5572), (0x693d, 5574), (0x693f, 3345),
+    (0x694a, 4179), (0x6953, 3845), (0x6954, 5569), (0x6955, 3143), (0x6959, 5575), (0x695a, 3038), (0x695c, 5566), (0x695d, 5579),
+    (0x695e, 5578), (0x6960, 3556), (0x6961, 5577), (0x6962, 3551), (0x696a, 5581), (0x696b, 5568), (0x696d, 2013), (0x696e, 5571),
+    (0x696f, 2692), (0x6973, 3635), (0x6974, 5573), (0x6975, 2016), (0x6977, 5565), (0x6978, 5567), (0x6979, 5564), (0x697c, 4339),
+    (0x697d, 1749), (0x697e, 5570), (0x6981, 5580), (0x6982, 1712), (0x698a, 2420), (0x698e, 1564), (0x6991, 5597), (0x6994, 4340),
+    (0x6995, 5600), (0x699b, 2845), (0x699c, 5599), (0x69a0, 5598), (0x69a7, 5595), (0x69ae, 5583), (0x69b1, 5612), (0x69b2, 5582),
+    (0x69b4, 5601), (0x69bb, 5593), (0x69be, 5588), (0x69bf, 5585), (0x69c1, 5586), (0x69c3, 5594), (0x69c7, 7803), (0x69ca, 5591),
+    (0x69cb, 2284), (0x69cc, 3329), (0x69cd, 3075), (0x69ce, 5589), (0x69d0, 5584), (0x69d3, 5587), (0x69d8, 4180), (0x69d9, 4021),
+    (0x69dd, 5592), (0x69de, 5602), (0x69e7, 5610), (0x69e8, 5603), (0x69eb, 5616), (0x69ed, 5614), (0x69f2, 5609), (0x69f9, 5608),
+    (0x69fb, 3337), (0x69fd, 3076), (0x69ff, 5606), (0x6a02, 5604), (0x6a05, 5611), (0x6a0a, 5617), (0x6a0b, 3750), (0x6a0c, 5623),
+    (0x6a12, 5618), (0x6a13, 5621), (0x6a14, 5615), (0x6a17, 3279), (0x6a19, 3783), (0x6a1b, 5605), (0x6a1e, 5613), (0x6a1f, 2757),
+    (0x6a21, 4088), (0x6a22, 5633), (0x6a23, 5620), (0x6a29, 2163), (0x6a2a, 1600), (0x6a2b, 1754), (0x6a2e, 5596), (0x6a35, 2758),
+    (0x6a36, 5625), (0x6a38, 5632), (0x6a39, 2626), (0x6a3a, 1773), (0x6a3d, 3209), (0x6a44, 5622), (0x6a47, 5627), (0x6a48, 5631),
+    (0x6a4b, 1995), (0x6a58, 1923), (0x6a59, 5629), (0x6a5f, 1880), (0x6a61, 3520), (0x6a62, 5628), (0x6a66, 5630), (0x6a72, 5624),
+    (0x6a78, 5626), (0x6a7f, 1755), (0x6a80, 3236), (0x6a84, 5637), (0x6a8d, 5635), (0x6a8e, 2234), (0x6a90, 5634), (0x6a97, 5640),
+    (0x6a9c, 5507), (0x6aa0, 5636), (0x6aa2, 5638), (0x6aa3, 5639), (0x6aaa, 5651), (0x6aac, 5647), (0x6aae, 5528), (0x6ab3, 5646),
+    (0x6ab8, 5645), (0x6abb, 5642), (0x6ac1, 5619), (0x6ac2, 5644), (0x6ac3, 5643), (0x6ad1, 5649), (0x6ad3, 4329), (0x6ada, 5652),
+    (0x6adb, 2064), (0x6ade, 5648), (0x6adf, 5650), (0x6ae8, 3672), (0x6aea, 5653), (0x6afa, 5657), (0x6afb, 5654), (0x6b04, 4218),
+    (0x6b05, 5655), (0x6b0a, 5607), (0x6b12, 5658), (0x6b16, 5659), (0x6b1d, 1524), (0x6b1f, 5661), (0x6b20, 2138), (0x6b21, 2538),
+    (0x6b23, 2026), (0x6b27, 1601), (0x6b32, 4198), (0x6b37, 5663), (0x6b38, 5662), (0x6b39, 5665), (0x6b3a, 1908), (0x6b3d, 2027),
+    (0x6b3e, 1815), (0x6b43, 5668), (0x6b47, 5667), (0x6b49, 5669), (0x6b4c, 1643), (0x6b4e, 3218), (0x6b50, 5670), (0x6b53, 1816),
+    (0x6b54, 5672), (0x6b59, 5671), (0x6b5b, 5673), (0x6b5f, 5674), (0x6b61, 5675), (0x6b62, 2506), (0x6b63, 2934), (0x6b64, 2350),
+    (0x6b66, 3839), (0x6b69, 3919), (0x6b6a, 4359), (0x6b6f, 2528), (0x6b73, 2397), (0x6b74, 4311), (0x6b78, 5676), (0x6b79, 5677),
+    (0x6b7b, 2507), (0x6b7f, 5678), (0x6b80, 5679), (0x6b83, 5681), (0x6b84, 5680), (0x6b86, 4003), (0x6b89, 2693), (0x6b8a, 2613),
+    (0x6b8b, 2479), (0x6b8d, 5682), (0x6b95, 5684), (0x6b96, 2822), (0x6b98, 5683), (0x6b9e, 5685), (0x6ba4, 5686), (0x6baa, 5687),
+    (0x6bab, 5688), (0x6baf, 5689), (0x6bb1, 5691), (0x6bb2, 5690), (0x6bb3, 5692), (0x6bb4, 1602), (0x6bb5, 3237), (0x6bb7, 5693),
+    (0x6bba, 2449), (0x6bbb, 1735), (0x6bbc, 5694), (0x6bbf, 3417), (0x6bc0, 4837), (0x6bc5, 1882), (0x6bc6, 5695), (0x6bcb, 5696),
+    (0x6bcd, 3929), (0x6bce, 4019), (0x6bd2, 3516), (0x6bd3, 5697), (0x6bd4, 3735), (0x6bd8, 3756), (0x6bdb, 4092), (0x6bdf, 5698),
+    (0x6beb, 5700), (0x6bec, 5699), (0x6bef, 5702), (0x6bf3, 5701), (0x6c08, 5704), (0x6c0f, 2508), (0x6c11, 4058), (0x6c13, 5705),
+    (0x6c14, 5706), (0x6c17, 1883), (0x6c1b, 5707), (0x6c23, 5709), (0x6c24, 5708), (0x6c34, 2888), (0x6c37, 3784), (0x6c38, 1545),
+    (0x6c3e, 3702), (0x6c40, 3374), (0x6c41, 2664), (0x6c42, 1944), (0x6c4e, 3703), (0x6c50, 2551), (0x6c55, 5711), (0x6c57, 1817),
+    (0x6c5a, 1591), (0x6c5d, 3559), (0x6c5e, 5710), (0x6c5f, 2285), (0x6c60, 3246), (0x6c62, 5712), (0x6c68, 5720), (0x6c6a, 5713),
+    (0x6c70, 3134), (0x6c72, 1945), (0x6c73, 5721), (0x6c7a, 2139), (0x6c7d, 1884), (0x6c7e, 5719), (0x6c81, 5717), (0x6c82, 5714),
+    (0x6c83, 4199), (0x6c88, 3321), (0x6c8c, 3534), (0x6c8d, 5715), (0x6c90, 5723), (0x6c92, 5722), (0x6c93, 2070), (0x6c96, 1610),
+    (0x6c99, 2376), (0x6c9a, 5716), (0x6c9b, 5718), (0x6ca1, 4002), (0x6ca2, 3185), (0x6cab, 4034), (0x6cae, 5731), (0x6cb1, 5732),
+    (0x6cb3, 1644), (0x6cb8, 3861), (0x6cb9, 4134), (0x6cba, 5734), (0x6cbb, 2540), (0x6cbc, 2759), (0x6cbd, 5727), (0x6cbe, 5733),
+    (0x6cbf, 1575), (0x6cc1, 1996), (0x6cc4, 5724), (0x6cc5, 5729), (0x6cc9, 2997), (0x6cca, 3652), (0x6ccc, 3736), (0x6cd3, 5726),
+    (0x6cd5, 3948), (0x6cd7, 5728), (0x6cd9, 5737), (0x6cdb, 5735), (0x6cdd, 5730), (0x6ce1, 3949), (0x6ce2, 3611), (0x6ce3, 1946),
+    (0x6ce5, 3388), (0x6ce8, 3272), (0x6cea, 5738), (0x6cef, 5736), (0x6cf0, 3158), (0x6cf1, 5725), (0x6cf3, 1546), (0x6d0b, 4181),
+    (0x6d0c, 5749), (0x6d12, 5748), (0x6d17, 2999), (0x6d19, 5745), (0x6d1b, 4211), (0x6d1e, 3499), (0x6d1f, 5739), (0x6d25, 3326),
+    (0x6d29, 1547), (0x6d2a, 2286), (0x6d2b, 5742), (0x6d32, 2638), (0x6d33, 5747), (0x6d35, 5746), (0x6d36, 5741), (0x6d38, 5744),
+    (0x6d3b, 1763), (0x6d3d, 5743), (0x6d3e, 3612), (0x6d41, 4243), (0x6d44, 2809), (0x6d45, 2998), (0x6d59, 5755), (0x6d5a, 5753),
+    (0x6d5c, 3804), (0x6d63, 5750), (0x6d64, 5752), (0x6d66, 1529), (0x6d69, 2287), (0x6d6a, 4341), (0x6d6c, 1720), (0x6d6e, 3825),
+    (0x6d74, 4200), (0x6d77, 1695), (0x6d78, 2846), (0x6d79, 5754), (0x6d85, 5759), (0x6d88, 2760), (0x6d8c, 4151), (0x6d8e, 5756),
+    (0x6d93, 5751), (0x6d95, 5757), (0x6d99, 4291), (0x6d9b, 3466), (0x6d9c, 3511), (0x6daf, 1713), (0x6db2, 1556), (0x6db5, 5763),
+    (0x6db8, 5766), (0x6dbc, 4264), (0x6dc0, 4203), (0x6dc5, 5773), (0x6dc6, 5767), (0x6dc7, 5764), (0x6dcb, 4281), (0x6dcc, 5770),
+    (0x6dd1, 2673), (0x6dd2, 5772), (0x6dd5, 5777), (0x6dd8, 3464), (0x6dd9, 5775), (0x6dde, 5769), (0x6de1, 3219), (0x6de4, 5776),
+    (0x6de6, 5765), (0x6de8, 5771), (0x6dea, 5778), (0x6deb, 1501), (0x6dec, 5768), (0x6dee, 5779), (0x6df1, 2847), (0x6df3, 2694),
+    (0x6df5, 3858), (0x6df7, 2363), (0x6df9, 5760), (0x6dfa, 5774), (0x6dfb, 3409), (0x6e05, 2935), (0x6e07, 1764), (0x6e08, 2398),
+    (0x6e09, 2761), (0x6e0a, 5762), (0x6e0b, 2665), (0x6e13, 2111), (0x6e15, 5761), (0x6e19, 5783), (0x6e1a, 2708), (0x6e1b, 2187),
+    (0x6e1d, 5798), (0x6e1f, 5792), (0x6e20, 1963), (0x6e21, 3430), (0x6e23, 5787), (0x6e24, 5796), (0x6e25, 1424), (0x6e26, 1521),
+    (0x6e29, 1622), (0x6e2b, 5789), (0x6e2c, 3113), (0x6e2d, 5780), (0x6e2e, 5782), (0x6e2f, 2288), (0x6e38, 5799), (0x6e3a, 5794),
+    (0x6e3e, 5786), (0x6e43, 5793), (0x6e4a, 4052), (0x6e4d, 5791), (0x6e4e, 5795), (0x6e56, 2209), (0x6e58, 2762), (0x6e5b, 3220),
+    (0x6e5f, 5785), (0x6e67, 4150), (0x6e6b, 5788), (0x6e6e, 5781), (0x6e6f, 3465), (0x6e72, 5784), (0x6e76, 5790), (0x6e7e, 4372),
+    (0x6e7f, 2567), (0x6e80, 4041), (0x6e82, 5800), (0x6e8c, 3679), (0x6e8f, 5812), (0x6e90, 2188), (0x6e96, 2695), (0x6e98, 5802),
+    (0x6e9c, 4244), (0x6e9d, 2289), (0x6e9f, 5815), (0x6ea2, 1487), (0x6ea5, 5813), (0x6eaa, 5801), (0x6eaf, 5807), (0x6eb2, 5809),
+    (0x6eb6, 4182), (0x6eb7, 5804), (0x6eba, 3397), (0x6ebd, 5806), (0x6ec2, 5814), (0x6ec4, 5808), (0x6ec5, 4080), (0x6ec9, 5803),
+    (0x6ecb, 2539), (0x6ecc, 5827), (0x6ed1, 1765), (0x6ed3, 5805), (0x6ed4, 5810), (0x6ed5, 5811), (0x6edd, 3177), (0x6ede, 3159),
+    (0x6eec, 5819), (0x6eef, 5825), (0x6ef2, 5823), (0x6ef4, 3392), (0x6ef7, 5830), (0x6ef8, 5820), (0x6efe, 5821), (0x6eff, 5797),
+    (0x6f01, 1968), (0x6f02, 3785), (0x6f06, 2568), (0x6f09, 2342), (0x6f0f, 4342), (0x6f11, 5817), (0x6f13, 5829), (0x6f14, 1576),
+    (0x6f15, 3077), (0x6f20, 3660), (0x6f22, 1818), (0x6f23, 4319), (0x6f2b, 4042), (0x6f2c, 3339), (0x6f31, 5824), (0x6f32, 5826),
+    (0x6f38, 3025), (0x6f3e, 5828), (0x6f3f, 5822), (0x6f41, 5816), (0x6f45, 1820), (0x6f54, 2140), (0x6f58, 5842), (0x6f5b, 5837),
+    (0x6f5c, 3001), (0x6f5f, 1758), (0x6f64, 2696), (0x6f66, 5846), (0x6f6d, 5839), (0x6f6e, 3301), (0x6f6f, 5836), (0x6f70, 3346),
+    (0x6f74, 5871), (0x6f78, 5833), (0x6f7a, 5832), (0x6f7c, 5841), (0x6f80, 5835), (0x6f81, 5834), (0x6f82, 5840), (0x6f84, 2914),
+    (0x6f86, 5831), (0x6f8e, 5843), (0x6f91, 5844), (0x6f97, 1819), (0x6fa1, 5849), (0x6fa3, 5848), (0x6fa4, 5850), (0x6faa, 5853),
+    (0x6fb1, 3418), (0x6fb3, 5847), (0x6fb9, 5851), (0x6fc0, 2134), (0x6fc1, 3190), (0x6fc2, 5845), (0x6fc3, 3598), (0x6fc6, 5852),
+    (0x6fd4, 5857), (0x6fd5, 5855), (0x6fd8, 5858), (0x6fdb, 5861), (0x6fdf, 5854), (0x6fe0, 2329), (0x6fe1, 3579), (0x6fe4, 5758),
+    (0x6feb, 4219), (0x6fec, 5856), (0x6fee, 5860), (0x6fef, 3186), (0x6ff1, 5859), (0x6ff3, 5838), (0x6ff6, 7404), (0x6ffa, 5864),
+    (0x6ffe, 5868), (0x7001, 5866), (0x7009, 5862), (0x700b, 5863), (0x700f, 5867), (0x7011, 5865), (0x7015, 3805), (0x7018, 5873),
+    (0x701a, 5870), (0x701b, 5869), (0x701d, 5872), (0x701e, 3529), (0x701f, 5874), (0x7026, 3280), (0x7027, 3178), (0x702c, 2918),
+    (0x7030, 5875), (0x7032, 5877), (0x703e, 5876), (0x704c, 5818), (0x7051, 5878), (0x7058, 3548), (0x7063, 5879), (0x706b, 1645),
+    (0x706f, 3467), (0x7070, 1696), (0x7078, 1947), (0x707c, 2599), (0x707d, 2399), (0x7089, 4330), (0x708a, 2889), (0x708e, 1577),
+    (0x7092, 5881), (0x7099, 5880), (0x70ac, 5884), (0x70ad, 3221), (0x70ae, 5887), (0x70af, 5882), (0x70b3, 5886), (0x70b8, 5885),
+    (0x70b9, 3415), (0x70ba, 1466), (0x70c8, 4314), (0x70cb, 5889), (0x70cf, 1511), (0x70d9, 5891), (0x70dd, 5890), (0x70df, 5888),
+    (0x70f1, 5883), (0x70f9, 3950), (0x70fd, 5893), (0x7109, 5892), (0x7114, 1578), (0x7119, 5895), (0x711a, 3871), (0x711c, 5894),
+    (0x7121, 4062), (0x7126, 2764), (0x7136, 3026), (0x713c, 2763), (0x7149, 4320), (0x714c, 5901), (0x714e, 3002), (0x7155, 5897),
+    (0x7156, 5902), (0x7159, 1579), (0x7162, 5900), (0x7164, 3636), (0x7165, 5896), (0x7166, 5899), (0x7167, 2765), (0x7169, 3714),
+    (0x716c, 5903), (0x716e, 2586), (0x717d, 3003), (0x7184, 5906), (0x7188, 5898), (0x718a, 2074), (0x718f, 5904), (0x7194, 4183),
+    (0x7195, 5907), (0x7199, 7807), (0x719f, 2678), (0x71a8, 5908), (0x71ac, 5909), (0x71b1, 3585), (0x71b9, 5911), (0x71be, 5912),
+    (0x71c3, 3590), (0x71c8, 3468), (0x71c9, 5914), (0x71ce, 5916), (0x71d0, 4282), (0x71d2, 5913), (0x71d4, 5915), (0x71d5, 1580),
+    (0x71d7, 5910), (0x71df, 4758), (0x71e0, 5917), (0x71e5, 3078), (0x71e6, 2467), (0x71e7, 5919), (0x71ec, 5918), (0x71ed, 2823),
+    (0x71ee, 4662), (0x71f5, 5920), (0x71f9, 5922), (0x71fb, 5905), (0x71fc, 5921), (0x71ff, 5923), (0x7206, 3661), (0x720d, 5924),
+    (0x7210, 5925), (0x721b, 5926), (0x7228, 5927), (0x722a, 3351), (0x722c, 5929), (0x722d, 5928), (0x7230, 5930), (0x7232, 5931),
+    (0x7235, 2600), (0x7236, 3826), (0x723a, 4117), (0x723b, 5932), (0x723c, 5933), (0x723d, 3061), (0x723e, 2541), (0x723f, 5934),
+    (0x7240, 5935), (0x7246, 5936), (0x7247, 3903), (0x7248, 3704), (0x724b, 5937), (0x724c, 3626), (0x7252, 3302), (0x7258, 5938),
+    (0x7259, 1668), (0x725b, 1956), (0x725d, 4079), (0x725f, 4063), (0x7261, 1617), (0x7262, 4343), (0x7267, 3997), (0x7269, 3863),
+    (0x7272, 2936), (0x7274, 5939), (0x7279, 3512), (0x727d, 2164), (0x727e, 5940), (0x7280, 2401), (0x7281, 5942), (0x7282, 5941),
+    (0x7287, 5943), (0x7292, 5944), (0x7296, 5945), (0x72a0, 1909), (0x72a2, 5946), (0x72a7, 5947), (0x72ac, 2165), (0x72af, 3705),
+    (0x72b2, 5949), (0x72b6, 2810), (0x72b9, 5948), (0x72c2, 1997), (0x72c3, 5950), (0x72c4, 5952), (0x72c6, 5951), (0x72ce, 5953),
+    (0x72d0, 2210), (0x72d2, 5954), (0x72d7, 2046), (0x72d9, 3039), (0x72db, 2348), (0x72e0, 5956), (0x72e1, 5957), (0x72e2, 5955),
+    (0x72e9, 2614), (0x72ec, 3517), (0x72ed, 1998), (0x72f7, 5959), (0x72f8, 3207), (0x72f9, 5958), (0x72fc, 4344), (0x72fd, 3637),
+    (0x730a, 5962), (0x7316, 5964), (0x7317, 5961), (0x731b, 4093), (0x731c, 5963), (0x731d, 5965), (0x731f, 4265), (0x7325, 5969),
+    (0x7329, 5968), (0x732a, 3281), (0x732b, 3584), (0x732e, 2166), (0x732f, 5967), (0x7334, 5966), (0x7336, 4152), (0x7337, 4153),
+    (0x733e, 5970), (0x733f, 1581), (0x7344, 2341), (0x7345, 2509), (0x734e, 5971), (0x734f, 5972), (0x7357, 5974), (0x7363, 2666),
+    (0x7368, 5976), (0x736a, 5975), (0x7370, 5977), (0x7372, 1736), (0x7375, 5979), (0x7378, 5978), (0x737a, 5981), (0x737b, 5980),
+    (0x7384, 2189), (0x7387, 4237), (0x7389, 2017), (0x738b, 1603), (0x7396, 2047), (0x73a9, 1850), (0x73b2, 4301), (0x73b3, 5983),
+    (0x73bb, 5985), (0x73c0, 5986), (0x73c2, 1646), (0x73c8, 5982), (0x73ca, 2468), (0x73cd, 3322), (0x73ce, 5984), (0x73de, 5989),
+    (0x73e0, 2615), (0x73e5, 5987), (0x73ea, 2097), (0x73ed, 3706), (0x73ee, 5988), (0x73f1, 6015), (0x73f8, 5994), (0x73fe, 2190),
+    (0x7403, 1948), (0x7405, 5991), (0x7406, 4228), (0x7409, 4245), (0x7422, 3187), (0x7425, 5993), (0x7432, 5995), (0x7433, 4283),
+    (0x7434, 2028), (0x7435, 3757), (0x7436, 3613), (0x743a, 5996), (0x743f, 5998), (0x7441, 6001), (0x7455, 5997), (0x7459, 6000),
+    (0x745a, 2235), (0x745b, 1548), (0x745c, 6002), (0x745e, 2899), (0x745f, 5999), (0x7460, 4289), (0x7463, 6005), (0x7464, 7805),
+    (0x7469, 6003), (0x746a, 6006), (0x746f, 5992), (0x7470, 6004), (0x7473, 2377), (0x7476, 6007), (0x747e, 6008), (0x7483, 4229),
+    (0x748b, 6009), (0x749e, 6010), (0x74a2, 5990), (0x74a7, 6011), (0x74b0, 1821), (0x74bd, 2542), (0x74ca, 6012), (0x74cf, 6013),
+    (0x74d4, 6014), (0x74dc, 1530), (0x74e0, 6016), (0x74e2, 3786), (0x74e3, 6017), (0x74e6, 1789), (0x74e7, 6018), (0x74e9, 6019),
+    (0x74ee, 6020), (0x74f0, 6022), (0x74f1, 6023), (0x74f2, 6021), (0x74f6, 3810), (0x74f7, 6025), (0x74f8, 6024), (0x7503, 6027),
+    (0x7504, 6026), (0x7505, 6028), (0x750c, 6029), (0x750d, 6031), (0x750e, 6030), (0x7511, 2344), (0x7513, 6033), (0x7515, 6032),
+    (0x7518, 1822), (0x751a, 2870), (0x751c, 3411), (0x751e, 6034), (0x751f, 2937), (0x7523, 2469), (0x7525, 1592), (0x7526, 6035),
+    (0x7528, 4184), (0x752b, 3920), (0x752c, 6036), (0x7530, 3419), (0x7531, 4154), (0x7532, 2290), (0x7533, 2848), (0x7537, 3238),
+    (0x7538, 4625), (0x753a, 3303), (0x753b, 1669), (0x753c, 6037), (0x7544, 6038), (0x7546, 6043), (0x7549, 6041), (0x754a, 6040),
+    (0x754b, 5391), (0x754c, 1697), (0x754d, 6039), (0x754f, 1467), (0x7551, 3675), (0x7554, 3707), (0x7559, 4246), (0x755a, 6044),
+    (0x755b, 6042), (0x755c, 3255), (0x755d, 2919), (0x7560, 3676), (0x7562, 3772), (0x7564, 6046), (0x7565, 4241), (0x7566, 2112),
+    (0x7567, 6047), (0x7569, 6045), (0x756a, 3719), (0x756b, 6048), (0x756d, 6049), (0x7570, 1468), (0x7573, 2811), (0x7574, 6054),
+    (0x7576, 6051), (0x7577, 3554), (0x7578, 6050), (0x757f, 1885), (0x7582, 6057), (0x7586, 6052), (0x7587, 6053), (0x7589, 6056),
+    (0x758a, 6055), (0x758b, 3764), (0x758e, 3041), (0x758f, 3040), (0x7591, 1910), (0x7594, 6058), (0x759a, 6059), (0x759d, 6060),
+    (0x75a3, 6062), (0x75a5, 6061), (0x75ab, 1557), (0x75b1, 6070), (0x75b2, 3737), (0x75b3, 6064), (0x75b5, 6066), (0x75b8, 6068),
+    (0x75b9, 2849), (0x75bc, 6069), (0x75bd, 6067), (0x75be, 2569), (0x75c2, 6063), (0x75c3, 6065), (0x75c5, 3793), (0x75c7, 2766),
+    (0x75ca, 6072), (0x75cd, 6071), (0x75d2, 6073), (0x75d4, 2543), (0x75d5, 2364), (0x75d8, 3470), (0x75d9, 6074), (0x75db, 3332),
+    (0x75de, 6076), (0x75e2, 4230), (0x75e3, 6075), (0x75e9, 3080), (0x75f0, 6081), (0x75f2, 6083), (0x75f3, 6084), (0x75f4, 3247),
+    (0x75fa, 6082), (0x75fc, 6079), (0x75fe, 6077), (0x75ff, 6078), (0x7601, 6080), (0x7609, 6087), (0x760b, 6085), (0x760d, 6086),
+    (0x761f, 6088), (0x7620, 6090), (0x7621, 6091), (0x7622, 6092), (0x7624, 6093), (0x7627, 6089), (0x7630, 6095), (0x7634, 6094),
+    (0x763b, 6096), (0x7642, 4266), (0x7646, 6099), (0x7647, 6097), (0x7648, 6098), (0x764c, 1851), (0x7652, 4135), (0x7656, 3895),
+    (0x7658, 6101), (0x765c, 6100), (0x7661, 6102), (0x7662, 6103), (0x7667, 6107), (0x7668, 6104), (0x7669, 6105), (0x766a, 6106),
+    (0x766c, 6108), (0x7670, 6109), (0x7672, 6110), (0x7676, 6111), (0x7678, 6112), (0x767a, 3680), (0x767b, 3431), (0x767c, 6113),
+    (0x767d, 3653), (0x767e, 3779), (0x7680, 6114), (0x7683, 6115), (0x7684, 3393), (0x7686, 1698), (0x7687, 2291), (0x7688, 6116),
+    (0x768b, 6117), (0x768e, 6118), (0x7690, 2452), (0x7693, 6120), (0x7696, 6119), (0x7699, 6121), (0x769a, 6122), (0x76ae, 3738),
+    (0x76b0, 6123), (0x76b4, 6124), (0x76b7, 7780), (0x76b8, 6125), (0x76b9, 6126), (0x76ba, 6127), (0x76bf, 2457), (0x76c2, 6128),
+    (0x76c3, 3625), (0x76c6, 4010), (0x76c8, 1549), (0x76ca, 1558), (0x76cd, 6129), (0x76d2, 6131), (0x76d6, 6130), (0x76d7, 3463),
+    (0x76db, 2938), (0x76dc, 5664), (0x76de, 6132), (0x76df, 4074), (0x76e1, 6133), (0x76e3, 1823), (0x76e4, 3720), (0x76e5, 6134),
+    (0x76e7, 6135), (0x76ea, 6136), (0x76ee, 4101), (0x76f2, 4094), (0x76f4, 3319), (0x76f8, 3081), (0x76fb, 6138), (0x76fe, 2697),
+    (0x7701, 2767), (0x7704, 6141), (0x7707, 6140), (0x7708, 6139), (0x7709, 3758), (0x770b, 1824), (0x770c, 2170), (0x771b, 6147),
+    (0x771e, 6144), (0x771f, 2850), (0x7720, 4059), (0x7724, 6143), (0x7725, 6145), (0x7726, 6146), (0x7729, 6142), (0x7737, 6148),
+    (0x7738, 6149), (0x773a, 3304), (0x773c, 1852), (0x7740, 3264), (0x7747, 6150), (0x775a, 6151), (0x775b, 6154), (0x7761, 2890),
+    (0x7763, 3513), (0x7765, 6155), (0x7766, 3998), (0x7768, 6152), (0x776b, 6153), (0x7779, 6158), (0x777e, 6157), (0x777f, 6156),
+    (0x778b, 6160), (0x778e, 6159), (0x7791, 6161), (0x779e, 6163), (0x77a0, 6162), (0x77a5, 3898), (0x77ac, 2685), (0x77ad, 4267),
+    (0x77b0, 6164), (0x77b3, 3500), (0x77b6, 6165), (0x77b9, 6166), (0x77bb, 6170), (0x77bc, 6168), (0x77bd, 6169), (0x77bf, 6167),
+    (0x77c7, 6171), (0x77cd, 6172), (0x77d7, 6173), (0x77da, 6174), (0x77db, 4064), (0x77dc, 6175), (0x77e2, 4121), (0x77e3, 6176),
+    (0x77e5, 3241), (0x77e7, 3645), (0x77e9, 2048), (0x77ed, 3222), (0x77ee, 6177), (0x77ef, 1999), (0x77f3, 2961), (0x77fc, 6178),
+    (0x7802, 2378), (0x780c, 6179), (0x7812, 6180), (0x7814, 2167), (0x7815, 2402), (0x7820, 6182), (0x7825, 3437), (0x7826, 2403),
+    (0x7827, 1925), (0x7832, 3951), (0x7834, 3614), (0x783a, 3438), (0x783f, 2315), (0x7845, 6184), (0x785d, 2768), (0x786b, 4247),
+    (0x786c, 2292), (0x786f, 2168), (0x7872, 3668), (0x7874, 6186), (0x787c, 6188), (0x7881, 2236), (0x7886, 6187), (0x7887, 3375),
+    (0x788c, 6190), (0x788d, 1714), (0x788e, 6185), (0x7891, 3739), (0x7893, 1519), (0x7895, 2425), (0x7897, 4373), (0x789a, 6189),
+    (0x78a3, 6191), (0x78a7, 3896), (0x78a9, 2970), (0x78aa, 6193), (0x78af, 6194), (0x78b5, 6192), (0x78ba, 1737), (0x78bc, 6200),
+    (0x78be, 6199), (0x78c1, 2544), (0x78c5, 6201), (0x78c6, 6196), (0x78ca, 6202), (0x78cb, 6197), (0x78d0, 3721), (0x78d1, 6195),
+    (0x78d4, 6198), (0x78da, 6205), (0x78e7, 6204), (0x78e8, 4012), (0x78ec, 6203), (0x78ef, 1484), (0x78f4, 6207), (0x78fd, 6206),
+    (0x7901, 2769), (0x7907, 6208), (0x790e, 3042), (0x7911, 6210), (0x7912, 6209), (0x7919, 6211), (0x7926, 6181), (0x792a, 6183),
+    (0x792b, 6213), (0x792c, 6212), (0x793a, 2545), (0x793c, 4302), (0x793e, 2587), (0x7940, 6214), (0x7941, 2090), (0x7947, 1911),
+    (0x7948, 1886), (0x7949, 2510), (0x7950, 4155), (0x7953, 6220), (0x7955, 6219), (0x7956, 3043), (0x7957, 6216), (0x795a, 6218),
+    (0x795d, 2674), (0x795e, 2851), (0x795f, 6217), (0x7960, 6215), (0x7962, 3581), (0x7965, 2770), (0x7968, 3787), (0x796d, 2404),
+    (0x7977, 3471), (0x797a, 6221), (0x797f, 6222), (0x7980, 6244), (0x7981, 2029), (0x7984, 4352), (0x7985, 3028), (0x798a, 6223),
+    (0x798d, 1647), (0x798e, 3376), (0x798f, 3854), (0x799d, 6224), (0x79a6, 1969), (0x79a7, 6225), (0x79aa, 6227), (0x79ae, 6228),
+    (0x79b0, 3580), (0x79b3, 6229), (0x79b9, 6230), (0x79ba, 6231), (0x79bd, 2030), (0x79be, 1648), (0x79bf, 3514), (0x79c0, 2639),
+    (0x79c1, 2511), (0x79c9, 6232), (0x79cb, 2640), (0x79d1, 1639), (0x79d2, 3794), (0x79d5, 6233), (0x79d8, 3740), (0x79df, 3044),
+    (0x79e1, 6236), (0x79e3, 6237), (0x79e4, 3644), (0x79e6, 2852), (0x79e7, 6234), (0x79e9, 3260), (0x79ec, 6235), (0x79f0, 2771),
+    (0x79fb, 1469), (0x7a00, 1888), (0x7a08, 6238), (0x7a0b, 3377), (0x7a0d, 6239), (0x7a0e, 2952), (0x7a14, 4054), (0x7a17, 3762),
+    (0x7a18, 6240), (0x7a19, 6241), (0x7a1a, 3248), (0x7a1c, 4268), (0x7a1f, 6243), (0x7a20, 6242), (0x7a2e, 2616), (0x7a31, 6245),
+    (0x7a32, 1489), (0x7a37, 6248), (0x7a3b, 6246), (0x7a3c, 1649), (0x7a3d, 2113), (0x7a3e, 6247), (0x7a3f, 2293), (0x7a40, 2337),
+    (0x7a42, 3923), (0x7a43, 6249), (0x7a46, 3999), (0x7a49, 6251), (0x7a4d, 2962), (0x7a4e, 1550), (0x7a4f, 1623), (0x7a50, 1421),
+    (0x7a57, 6250), (0x7a61, 6252), (0x7a62, 6253), (0x7a63, 2812), (0x7a69, 6254), (0x7a6b, 1738), (0x7a70, 6256), (0x7a74, 2141),
+    (0x7a76, 1949), (0x7a79, 6257), (0x7a7a, 2058), (0x7a7d, 6258), (0x7a7f, 3005), (0x7a81, 3522), (0x7a83, 2977), (0x7a84, 2434),
+    (0x7a88, 6259), (0x7a92, 3261), (0x7a93, 3082), (0x7a95, 6261), (0x7a96, 6263), (0x7a97, 6260), (0x7a98, 6262), (0x7a9f, 2069),
+    (0x7aa9, 6264), (0x7aaa, 2073), (0x7aae, 1950), (0x7aaf, 4185), (0x7ab0, 6266), (0x7ab6, 6267), (0x7aba, 1517), (0x7abf, 6270),
+    (0x7ac3, 1777), (0x7ac4, 6269), (0x7ac5, 6268), (0x7ac7, 6272), (0x7ac8, 6265), (0x7aca, 6273), (0x7acb, 4238), (0x7acd, 6274),
+    (0x7acf, 6275), (0x7ad2, 4877), (0x7ad3, 6277), (0x7ad5, 6276), (0x7ad9, 6278), (0x7ada, 6279), (0x7adc, 4250), (0x7add, 6280),
+    (0x7adf, 7504), (0x7ae0, 2772), (0x7ae1, 6281), (0x7ae2, 6282), (0x7ae3, 2686), (0x7ae5, 3501), (0x7ae6, 6283), (0x7aea, 3203),
+    (0x7aed, 6284), (0x7aef, 3223), (0x7af0, 6285), (0x7af6, 1978), (0x7af8, 4542), (0x7af9, 3256), (0x7afa, 2556), (0x7aff, 1825),
+    (0x7b02, 6286), (0x7b04, 6299), (0x7b06, 6289), (0x7b08, 1951), (0x7b0a, 6288), (0x7b0b, 6301), (0x7b0f, 6287), (0x7b11, 2773),
+    (0x7b18, 6291), (0x7b19, 6292), (0x7b1b, 3394), (0x7b1e, 6293), (0x7b20, 1753), (0x7b25, 2877), (0x7b26, 3827), (0x7b28, 6295),
+    (0x7b2c, 3173), (0x7b33, 6290), (0x7b35, 6294), (0x7b36, 6296), (0x7b39, 2440), (0x7b45, 6303), (0x7b46, 3773), (0x7b48, 3671),
+    (0x7b49, 3472), (0x7b4b, 2031), (0x7b4c, 6302), (0x7b4d, 6300), (0x7b4f, 3686), (0x7b50, 6297), (0x7b51, 3257), (0x7b52, 3474),
+    (0x7b54, 3473), (0x7b56, 2435), (0x7b5d, 6321), (0x7b65, 6305), (0x7b67, 6307), (0x7b6c, 6310), (0x7b6e, 6311), (0x7b70, 6308),
+    (0x7b71, 6309), (0x7b74, 6306), (0x7b75, 6304), (0x7b7a, 6298), (0x7b86, 3900), (0x7b87, 1650), (0x7b8b, 6318), (0x7b8d, 6315),
+    (0x7b8f, 6320), (0x7b92, 6319), (0x7b94, 3654), (0x7b95, 4048), (0x7b97, 2470), (0x7b98, 6313), (0x7b99, 6322), (0x7b9a, 6317),
+    (0x7b9c, 6316), (0x7b9d, 6312), (0x7b9f, 6314), (0x7ba1, 1826), (0x7baa, 3224), (0x7bad, 3006), (0x7bb1, 3667), (0x7bb4, 6327),
+    (0x7bb8, 3669), (0x7bc0, 2978), (0x7bc1, 6324), (0x7bc4, 3712), (0x7bc6, 6328), (0x7bc7, 3904), (0x7bc9, 3254), (0x7bcb, 6323),
+    (0x7bcc, 6325), (0x7bcf, 6326), (0x7bdd, 6329), (0x7be0, 2573), (0x7be4, 3515), (0x7be5, 6334), (0x7be6, 6333), (0x7be9, 6330),
+    (0x7bed, 4345), (0x7bf3, 6339), (0x7bf6, 6343), (0x7bf7, 6340), (0x7c00, 6336), (0x7c07, 6337), (0x7c0d, 6342), (0x7c11, 6331),
+    (0x7c12, 4658), (0x7c13, 6338), (0x7c14, 6332), (0x7c17, 6341), (0x7c1f, 6347), (0x7c21, 1827), (0x7c23, 6344), (0x7c27, 6345),
+    (0x7c2a, 6346), (0x7c2b, 6349), (0x7c37, 6348), (0x7c38, 3751), (0x7c3d, 6350), (0x7c3e, 4321), (0x7c3f, 3930), (0x7c40, 6355),
+    (0x7c43, 6352), (0x7c4c, 6351), (0x7c4d, 2963), (0x7c4f, 6354), (0x7c50, 6356), (0x7c54, 6353), (0x7c56, 6360), (0x7c58, 6357),
+    (0x7c5f, 6358), (0x7c60, 6335), (0x7c64, 6359), (0x7c65, 6361), (0x7c6c, 6362), (0x7c73, 3891), (0x7c75, 6363), (0x7c7e, 4107),
+    (0x7c81, 2019), (0x7c82, 2076), (0x7c83, 6364), (0x7c89, 3873), (0x7c8b, 2891), (0x7c8d, 4057), (0x7c90, 6365), (0x7c92, 4248),
+    (0x7c95, 3655), (0x7c97, 3045), (0x7c98, 3591), (0x7c9b, 2676), (0x7c9f, 1441), (0x7ca1, 6370), (0x7ca2, 6368), (0x7ca4, 6366),
+    (0x7ca5, 1786), (0x7ca7, 2774), (0x7ca8, 6371), (0x7cab, 6369), (0x7cad, 6367), (0x7cae, 6375), (0x7cb1, 6374), (0x7cb2, 6373),
+    (0x7cb3, 6372), (0x7cb9, 6376), (0x7cbd, 6377), (0x7cbe, 2939), (0x7cc0, 6378), (0x7cc2, 6380), (0x7cc5, 6379), (0x7cca, 2211),
+    (0x7cce, 3031), (0x7cd2, 6382), (0x7cd6, 3475), (0x7cd8, 6381), (0x7cdc, 6383), (0x7cde, 3874), (0x7cdf, 3083), (0x7ce0, 2294),
+    (0x7ce2, 6384), (0x7ce7, 4269), (0x7cef, 6386), (0x7cf2, 6387), (0x7cf4, 6388), (0x7cf6, 6389), (0x7cf8, 2512), (0x7cfa, 6390),
+    (0x7cfb, 2114), (0x7cfe, 1953), (0x7d00, 1889), (0x7d02, 6392), (0x7d04, 4124), (0x7d05, 2295), (0x7d06, 6391), (0x7d0a, 6395),
+    (0x7d0b, 4111), (0x7d0d, 3599), (0x7d10, 3778), (0x7d14, 2698), (0x7d15, 6394), (0x7d17, 2588), (0x7d18, 2296), (0x7d19, 2513),
+    (0x7d1a, 1952), (0x7d1b, 3875), (0x7d1c, 6393), (0x7d20, 3046), (0x7d21, 3981), (0x7d22, 2436), (0x7d2b, 2514), (0x7d2c, 3350),
+    (0x7d2e, 6398), (0x7d2f, 4292), (0x7d30, 2406), (0x7d32, 6399), (0x7d33, 2853), (0x7d35, 6401), (0x7d39, 2775), (0x7d3a, 2365),
+    (0x7d3f, 6400), (0x7d42, 2641), (0x7d43, 2191), (0x7d44, 3047), (0x7d45, 6396), (0x7d46, 6402), (0x7d4b, 6397), (0x7d4c, 2115),
+    (0x7d4e, 6405), (0x7d4f, 6409), (0x7d50, 2142), (0x7d56, 6404), (0x7d5b, 6413), (0x7d5e, 2297), (0x7d61, 4212), (0x7d62, 1437),
+    (0x7d63, 6410), (0x7d66, 1954), (0x7d68, 6407), (0x7d6e, 6408), (0x7d71, 3476), (0x7d72, 6406), (0x7d73, 6403), (0x7d75, 1699),
+    (0x7d76, 2981), (0x7d79, 2169), (0x7d7d, 6415), (0x7d89, 6412), (0x7d8f, 6414), (0x7d93, 6411), (0x7d99, 2116), (0x7d9a, 3120),
+    (0x7d9b, 6416), (0x7d9c, 3085), (0x7d9f, 6429), (0x7da2, 6425), (0x7da3, 6419), (0x7dab, 6423), (0x7dac, 2627), (0x7dad, 1470),
+    (0x7dae, 6418), (0x7daf, 6426), (0x7db0, 6430), (0x7db1, 2298), (0x7db2, 4095), (0x7db4, 3343), (0x7db5, 6420), (0x7db8, 6428),
+    (0x7dba, 6417), (0x7dbb, 3225), (0x7dbd, 6422), (0x7dbe, 1438), (0x7dbf, 4083), (0x7dc7, 6421), (0x7dca, 2032), (0x7dcb, 3741),
+    (0x7dcf, 3084), (0x7dd1, 4277), (0x7dd2, 2710), (0x7dd5, 6469), (0x7dd8, 6431), (0x7dda, 3007), (0x7ddc, 6427), (0x7ddd, 6432),
+    (0x7dde, 6434), (0x7de0, 3378), (0x7de1, 6437), (0x7de4, 6433), (0x7de8, 3905), (0x7de9, 1828), (0x7dec, 4084), (0x7def, 1471),
+    (0x7df2, 6436), (0x7df4, 4322), (0x7dfb, 6435), (0x7e01, 1582), (0x7e04, 3553), (0x7e05, 6438), (0x7e09, 6445), (0x7e0a, 6439),
+    (0x7e0b, 6446), (0x7e12, 6442), (0x7e1b, 3662), (0x7e1e, 2579), (0x7e1f, 6444), (0x7e21, 6441), (0x7e22, 6447), (0x7e23, 6440),
+    (0x7e26, 2667), (0x7e2b, 3952), (0x7e2e, 2675), (0x7e31, 6443), (0x7e32, 6455), (0x7e35, 6451), (0x7e37, 6454), (0x7e39, 6452),
+    (0x7e3a, 6456), (0x7e3b, 6450), (0x7e3d, 6424), (0x7e3e, 2964), (0x7e41, 3708), (0x7e43, 6453), (0x7e46, 6448), (0x7e4a, 3008),
+    (0x7e4b, 2117), (0x7e4d, 2642), (0x7e54, 2824), (0x7e55, 3029), (0x7e56, 6459), (0x7e59, 6461), (0x7e5a, 6462), (0x7e5d, 6458),
+    (0x7e5e, 6460), (0x7e66, 6449), (0x7e67, 6457), (0x7e69, 6465), (0x7e6a, 6464), (0x7e6d, 4037), (0x7e70, 2078), (0x7e79, 6463),
+    (0x7e7b, 6467), (0x7e7c, 6466), (0x7e7d, 6470), (0x7e7f, 6472), (0x7e82, 2471), (0x7e83, 6468), (0x7e88, 6473), (0x7e89, 6474),
+    (0x7e8c, 6475), (0x7e8e, 6481), (0x7e8f, 3410), (0x7e90, 6477), (0x7e92, 6476), (0x7e93, 6478), (0x7e94, 6479), (0x7e96, 6480),
+    (0x7e9b, 6482), (0x7e9c, 6483), (0x7f36, 1829), (0x7f38, 6484), (0x7f3a, 6485), (0x7f45, 6486), (0x7f4c, 6487), (0x7f4d, 6488),
+    (0x7f4e, 6489), (0x7f50, 6490), (0x7f51, 6491), (0x7f54, 6493), (0x7f55, 6492), (0x7f58, 6494), (0x7f5f, 6495), (0x7f60, 6496),
+    (0x7f67, 6499), (0x7f68, 6497), (0x7f69, 6498), (0x7f6a, 2414), (0x7f6b, 2118), (0x7f6e, 3249), (0x7f70, 3684), (0x7f72, 2711),
+    (0x7f75, 3616), (0x7f77, 3742), (0x7f78, 6500), (0x7f79, 5246), (0x7f82, 6501), (0x7f83, 6503), (0x7f85, 4204), (0x7f86, 6502),
+    (0x7f87, 6505), (0x7f88, 6504), (0x7f8a, 4186), (0x7f8c, 6506), (0x7f8e, 3759), (0x7f94, 6507), (0x7f9a, 6510), (0x7f9d, 6509),
+    (0x7f9e, 6508), (0x7fa3, 6511), (0x7fa4, 2085), (0x7fa8, 3009), (0x7fa9, 1912), (0x7fae, 6515), (0x7faf, 6512), (0x7fb2, 6513),
+    (0x7fb6, 6516), (0x7fb8, 6517), (0x7fb9, 6514), (0x7fbd, 1512), (0x7fc1, 1604), (0x7fc5, 6519), (0x7fc6, 6520), (0x7fca, 6521),
+    (0x7fcc, 4201), (0x7fd2, 2643), (0x7fd4, 6523), (0x7fd5, 6522), (0x7fe0, 2892), (0x7fe1, 6524), (0x7fe6, 6525), (0x7fe9, 6526),
+    (0x7feb, 1854), (0x7ff0, 1830), (0x7ff3, 6527), (0x7ff9, 6528), (0x7ffb, 4008), (0x7ffc, 4202), (0x8000, 4187), (0x8001, 4346),
+    (0x8003, 2300), (0x8004, 6531), (0x8005, 2589), (0x8006, 6530), (0x800b, 6532), (0x800c, 2546), (0x8010, 3150), (0x8012, 6533),
+    (0x8015, 2299), (0x8017, 4096), (0x8018, 6534), (0x8019, 6535), (0x801c, 6536), (0x8021, 6537), (0x8028, 6538), (0x8033, 2547),
+    (0x8036, 4118), (0x803b, 6540), (0x803d, 3226), (0x803f, 6539), (0x8046, 6542), (0x804a, 6541), (0x8052, 6543), (0x8056, 2940),
+    (0x8058, 6544), (0x805a, 6545), (0x805e, 3878), (0x805f, 6546), (0x8061, 3086), (0x8062, 6547), (0x8068, 6548), (0x806f, 4323),
+    (0x8070, 6551), (0x8072, 6550), (0x8073, 6549), (0x8074, 3305), (0x8076, 6552), (0x8077, 2825), (0x8079, 6553), (0x807d, 6554),
+    (0x807e, 4347), (0x807f, 6555), (0x8084, 6556), (0x8085, 6558), (0x8086, 6557), (0x8087, 3670), (0x8089, 3566), (0x808b, 4353),
+    (0x808c, 3674), (0x8093, 6560), (0x8096, 2776), (0x8098, 3769), (0x809a, 6561), (0x809b, 6559), (0x809d, 1831), (0x80a1, 2213),
+    (0x80a2, 2515), (0x80a5, 3743), (0x80a9, 2171), (0x80aa, 3982), (0x80ac, 6564), (0x80ad, 6562), (0x80af, 2301), (0x80b1, 2302),
+    (0x80b2, 1482), (0x80b4, 2421), (0x80ba, 3628), (0x80c3, 1472), (0x80c4, 6569), (0x80c6, 3227), (0x80cc, 3627), (0x80ce, 3160),
+    (0x80d6, 6571), (0x80d9, 6567), (0x80da, 6570), (0x80db, 6565), (0x80dd, 6568), (0x80de, 3953), (0x80e1, 2214), (0x80e4, 1502),
+    (0x80e5, 6566), (0x80ef, 6573), (0x80f1, 6574), (0x80f4, 3502), (0x80f8, 2000), (0x80fc, 6585), (0x80fd, 3600), (0x8102, 2516),
+    (0x8105, 2001), (0x8106, 2953), (0x8107, 4361), (0x8108, 4055), (0x8109, 6572), (0x810a, 2965), (0x811a, 1930), (0x811b, 6575),
+    (0x8123, 6577), (0x8129, 6576), (0x812f, 6578), (0x8131, 3201), (0x8133, 3601), (0x8139, 3306), (0x813e, 6582), (0x8146, 6581),
+    (0x814b, 6579), (0x814e, 2872), (0x8150, 3828), (0x8151, 6584), (0x8153, 6583), (0x8154, 2303), (0x8155, 4374), (0x815f, 6600),
+    (0x8165, 6588), (0x8166, 6589), (0x816b, 2617), (0x816e, 6587), (0x8170, 2343), (0x8171, 6586), (0x8174, 6590), (0x8178, 3307),
+    (0x8179, 3855), (0x817a, 3010), (0x817f, 3161), (0x8180, 6594), (0x8182, 6595), (0x8183, 6591), (0x8188, 6592), (0x818a, 6593),
+    (0x818f, 2304), (0x8193, 6601), (0x8195, 6597), (0x819a, 3829), (0x819c, 4023), (0x819d, 3767), (0x81a0, 6596), (0x81a3, 6599),
+    (0x81a4, 6598), (0x81a8, 3983), (0x81a9, 6602), (0x81b0, 6603), (0x81b3, 3030), (0x81b5, 6604), (0x81b8, 6606), (0x81ba, 6610),
+    (0x81bd, 6607), (0x81be, 6605), (0x81bf, 3602), (0x81c0, 6608), (0x81c2, 6609), (0x81c6, 1615), (0x81c8, 6616), (0x81c9, 6611),
+    (0x81cd, 6612), (0x81d1, 6613), (0x81d3, 3102), (0x81d8, 6615), (0x81d9, 6614), (0x81da, 6617), (0x81df, 6618), (0x81e0, 6619),
+    (0x81e3, 2854), (0x81e5, 1670), (0x81e7, 6620), (0x81e8, 4284), (0x81ea, 2548), (0x81ed, 2644), (0x81f3, 2517), (0x81f4, 3250),
+    (0x81fa, 6621), (0x81fb, 6622), (0x81fc, 1520), (0x81fe, 6623), (0x8201, 6624), (0x8202, 6625), (0x8205, 6626), (0x8207, 6627),
+    (0x8208, 2002), (0x8209, 5367), (0x820a, 6628), (0x820c, 2982), (0x820d, 6629), (0x820e, 2580), (0x8210, 6630), (0x8212, 4433),
+    (0x8216, 6631), (0x8217, 3915), (0x8218, 1845), (0x821b, 3011), (0x821c, 2687), (0x821e, 3840), (0x821f, 2645), (0x8229, 6632),
+    (0x822a, 2305), (0x822b, 6633), (0x822c, 3709), (0x822e, 6647), (0x8233, 6635), (0x8235, 3142), (0x8236, 3656), (0x8237, 2192),
+    (0x8238, 6634), (0x8239, 3012), (0x8240, 6636), (0x8247, 3379), (0x8258, 6638), (0x8259, 6637), (0x825a, 6640), (0x825d, 6639),
+    (0x825f, 6641), (0x8262, 6643), (0x8264, 6642), (0x8266, 1832), (0x8268, 6644), (0x826a, 6645), (0x826b, 6646), (0x826e, 2366),
+    (0x826f, 4270), (0x8271, 6648), (0x8272, 2826), (0x8276, 1583), (0x8277, 6649), (0x8278, 6650), (0x827e, 6651), (0x828b, 1491),
+    (0x828d, 6652), (0x8292, 6653), (0x8299, 3830), (0x829d, 2576), (0x829f, 6655), (0x82a5, 1700), (0x82a6, 1427), (0x82ab, 6654),
+    (0x82ac, 6657), (0x82ad, 3617), (0x82af, 2855), (0x82b1, 1651), (0x82b3, 3954), (0x82b8, 2128), (0x82b9, 2033), (0x82bb, 6656),
+    (0x82bd, 1671), (0x82c5, 1788), (0x82d1, 1584), (0x82d2, 6661), (0x82d3, 4303), (0x82d4, 3162), (0x82d7, 3795), (0x82d9, 6673),
+    (0x82db, 1652), (0x82dc, 6671), (0x82de, 6669), (0x82df, 6660), (0x82e1, 6658), (0x82e3, 6659), (0x82e5, 2604), (0x82e6, 2049),
+    (0x82e7, 3282), (0x82eb, 3526), (0x82f1, 1552), (0x82f3, 6663), (0x82f4, 6662), (0x82f9, 6668), (0x82fa, 6664), (0x82fb, 6667),
+    (0x8302, 4089), (0x8303, 6666), (0x8304, 1653), (0x8305, 1784), (0x8306, 6670), (0x8309, 6672), (0x830e, 2119), (0x8316, 6676),
+    (0x8317, 6685), (0x8318, 6686), (0x831c, 1420), (0x8323, 6693), (0x8328, 1490), (0x832b, 6684), (0x832f, 6683), (0x8331, 6678),
+    (0x8332, 6677), (0x8334, 6675), (0x8335, 6674), (0x8336, 3262), (0x8338, 3192), (0x8339, 6680), (0x8340, 6679), (0x8345, 6682),
+    (0x8349, 3087), (0x834a, 2120), (0x834f, 1536), (0x8350, 6681), (0x8352, 2306), (0x8358, 3088), (0x8373, 6699), (0x8375, 6700),
+    (0x8377, 1654), (0x837b, 1611), (0x837c, 6697), (0x8385, 6687), (0x8387, 6695), (0x8389, 6702), (0x838a, 6696), (0x838e, 6694),
+    (0x8393, 6665), (0x8396, 6692), (0x839a, 6688), (0x839e, 1833), (0x839f, 6690), (0x83a0, 6701), (0x83a2, 6691), (0x83a8, 6703),
+    (0x83aa, 6689), (0x83ab, 3663), (0x83b1, 4208), (0x83b5, 6698), (0x83bd, 6720), (0x83c1, 6712), (0x83c5, 2910), (0x83ca, 1917),
+    (0x83cc, 2034), (0x83ce, 6707), (0x83d3, 1656), (0x83d6, 2777), (0x83d8, 6710), (0x83dc, 2407), (0x83df, 3432), (0x83e0, 6715),
+    (0x83e9, 3931), (0x83eb, 6706), (0x83ef, 1655), (0x83f0, 2215), (0x83f1, 3768), (0x83f2, 6716), (0x83f4, 6704), (0x83f7, 6713),
+    (0x83fb, 6723), (0x83fd, 6708), (0x8403, 6709), (0x8404, 3503), (0x8407, 6714), (0x840b, 6711), (0x840c, 3955), (0x840d, 6717),
+    (0x840e, 1473), (0x8413, 6705), (0x8420, 6719), (0x8422, 6718), (0x8429, 3646), (0x842a, 6725), (0x842c, 6736), (0x8431, 1785),
+    (0x8435, 6739), (0x8438, 6721), (0x843c, 6726), (0x843d, 4213), (0x8446, 6735), (0x8449, 4188), (0x844e, 4239), (0x8457, 3283),
+    (0x845b, 1766), (0x8461, 3841), (0x8462, 6741), (0x8463, 3478), (0x8466, 1426), (0x8469, 6734), (0x846b, 6730), (0x846c, 3089),
+    (0x846d, 6724), (0x846e, 6732), (0x846f, 6737), (0x8471, 3583), (0x8475, 1419), (0x8477, 6729), (0x8479, 6738), (0x847a, 3847),
+    (0x8482, 6733), (0x8484, 6728), (0x848b, 2778), (0x8490, 2646), (0x8494, 2549), (0x8499, 4097), (0x849c, 3798), (0x849f, 6744),
+    (0x84a1, 6753), (0x84ad, 6731), (0x84b2, 1778), (0x84b8, 2813), (0x84b9, 6742), (0x84bb, 6747), (0x84bc, 3090), (0x84bf, 6743),
+    (0x84c1, 6750), (0x84c4, 3258), (0x84c6, 6751), (0x84c9, 4189), (0x84ca, 6740), (0x84cb, 1715), (0x84cd, 6746), (0x84d0, 6749),
+    (0x84d1, 4053), (0x84d6, 6752), (0x84d9, 6745), (0x84da, 6748), (0x84ec, 3956), (0x84ee, 4324), (0x84f4, 6756), (0x84fc, 6763),
+    (0x84ff, 6755), (0x8500, 2572), (0x8506, 6722), (0x8511, 3899), (0x8513, 4043), (0x8514, 6762), (0x8515, 6761), (0x8517, 6757),
+    (0x8518, 6758), (0x851a, 1525), (0x851f, 6760), (0x8521, 6754), (0x8526, 3342), (0x852c, 6759), (0x852d, 1503), (0x8535, 3103),
+    (0x853d, 3888), (0x8540, 6764), (0x8541, 6768), (0x8543, 3722), (0x8548, 6767), (0x8549, 2779), (0x854a, 2578), (0x854b, 6770),
+    (0x854e, 2003), (0x8555, 6771), (0x8557, 3848), (0x8558, 6766), (0x855a, 6727), (0x8563, 6765), (0x8568, 4370), (0x8569, 3479),
+    (0x856a, 3842), (0x856d, 6778), (0x8577, 6784), (0x857e, 6785), (0x8580, 6772), (0x8584, 3657), (0x8587, 6782), (0x8588, 6774),
+    (0x858a, 6776), (0x8590, 6786), (0x8591, 6775), (0x8594, 6779), (0x8597, 1585), (0x8599, 3546), (0x859b, 6780), (0x859c, 6783),
+    (0x85a4, 6773), (0x85a6, 3013), (0x85a8, 6777), (0x85a9, 2450), (0x85aa, 2856), (0x85ab, 2083), (0x85ac, 4125), (0x85ae, 4130),
+    (0x85af, 2713), (0x85b9, 6790), (0x85ba, 6788), (0x85c1, 4369), (0x85c9, 6787), (0x85cd, 4220), (0x85cf, 6789), (0x85d0, 6791),
+    (0x85d5, 6792), (0x85dc, 6795), (0x85dd, 6793), (0x85e4, 3480), (0x85e5, 6794), (0x85e9, 3710), (0x85ea, 6781), (0x85f7, 2714),
+    (0x85f9, 6796), (0x85fa, 6801), (0x85fb, 3091), (0x85fe, 6800), (0x8602, 6769), (0x8606, 6802), (0x8607, 3048), (0x860a, 6797),
+    (0x860b, 6799), (0x8613, 6798), (0x8616, 5656), (0x8617, 5641), (0x861a, 6804), (0x8622, 6803), (0x862d, 4221), (0x862f, 6137),
+    (0x8630, 6805), (0x863f, 6806), (0x864d, 6807), (0x864e, 2216), (0x8650, 1931), (0x8654, 6809), (0x8655, 4572), (0x865a, 1964),
+    (0x865c, 4255), (0x865e, 2056), (0x865f, 6810), (0x8667, 6811), (0x866b, 3273), (0x8671, 6812), (0x8679, 3567), (0x867b, 1435),
+    (0x868a, 1664), (0x868b, 6817), (0x868c, 6818), (0x8693, 6813), (0x8695, 2472), (0x86a3, 6814), (0x86a4, 3605), (0x86a9, 6815),
+    (0x86aa, 6816), (0x86ab, 6826), (0x86af, 6820), (0x86b0, 6823), (0x86b6, 6819), (0x86c4, 6821), (0x86c6, 6822), (0x86c7, 2593),
+    (0x86c9, 6824), (0x86cb, 3228), (0x86cd, 2121), (0x86ce, 1725), (0x86d4, 6827), (0x86d9, 1722), (0x86db, 6832), (0x86de, 6828),
+    (0x86df, 6831), (0x86e4, 3691), (0x86e9, 6829), (0x86ec, 6830), (0x86ed, 3799), (0x86ee, 3723), (0x86ef, 6833), (0x86f8, 3194),
+    (0x86f9, 6843), (0x86fb, 6839), (0x86fe, 1672), (0x8700, 6837), (0x8702, 3957), (0x8703, 6838), (0x8706, 6835), (0x8708, 6836),
+    (0x8709, 6841), (0x870a, 6844), (0x870d, 6842), (0x8711, 6840), (0x8712, 6834), (0x8718, 3251), (0x871a, 6851), (0x871c, 4051),
+    (0x8725, 6849), (0x8729, 6850), (0x8734, 6845), (0x8737, 6847), (0x873b, 6848), (0x873f, 6846), (0x8749, 2983), (0x874b, 4348),
+    (0x874c, 6855), (0x874e, 6856), (0x8753, 6862), (0x8755, 2829), (0x8757, 6858), (0x8759, 6861), (0x875f, 6853), (0x8760, 6852),
+    (0x8763, 6863), (0x8766, 1657), (0x8768, 6859), (0x876a, 6864), (0x876e, 6860), (0x8774, 6857), (0x8776, 3308), (0x8778, 6854),
+    (0x877f, 3643), (0x8782, 6868), (0x878d, 4162), (0x879f, 6867), (0x87a2, 6866), (0x87ab, 6875), (0x87af, 6869), (0x87b3, 6877),
+    (0x87ba, 4205), (0x87bb, 6880), (0x87bd, 6871), (0x87c0, 6872), (0x87c4, 6876), (0x87c6, 6879), (0x87c7, 6878), (0x87cb, 6870),
+    (0x87d0, 6873), (0x87d2, 6890), (0x87e0, 6883), (0x87ef, 6881), (0x87f2, 6882), (0x87f6, 6887), (0x87f7, 6888), (0x87f9, 1701),
+    (0x87fb, 1913), (0x87fe, 6886), (0x8805, 6865), (0x880d, 6885), (0x880e, 6889), (0x880f, 6884), (0x8811, 6891), (0x8815, 6893),
+    (0x8816, 6892), (0x8821, 6895), (0x8822, 6894), (0x8823, 6825), (0x8827, 6899), (0x8831, 6896), (0x8836, 6897), (0x8839, 6898),
+    (0x883b, 6900), (0x8840, 2143), (0x8842, 6902), (0x8844, 6901), (0x8846, 2647), (0x884c, 2307), (0x884d, 5740), (0x8852, 6903),
+    (0x8853, 2680), (0x8857, 1716), (0x8859, 6904), (0x885b, 1553), (0x885d, 2780), (0x885e, 6905), (0x8861, 2308), (0x8862, 6906),
+    (0x8863, 1474), (0x8868, 3788), (0x886b, 6907), (0x8870, 2893), (0x8872, 6914), (0x8875, 6911), (0x8877, 3274), (0x887d, 6912),
+    (0x887e, 6909), (0x887f, 2035), (0x8881, 6908), (0x8882, 6915), (0x8888, 2089), (0x888b, 3163), (0x888d, 6921), (0x8892, 6917),
+    (0x8896, 3122), (0x8897, 6916), (0x8899, 6919), (0x889e, 6910), (0x88a2, 6920), (0x88a4, 6922), (0x88ab, 3744), (0x88ae, 6918),
+    (0x88b0, 6923), (0x88b1, 6925), (0x88b4, 2212), (0x88b5, 6913), (0x88b7, 1442), (0x88bf, 6924), (0x88c1, 2408), (0x88c2, 4315),
+    (0x88c3, 6926), (0x88c4, 6927), (0x88c5, 3092), (0x88cf, 4231), (0x88d4, 6928), (0x88d5, 4156), (0x88d8, 6929), (0x88d9, 6930),
+    (0x88dc, 3921), (0x88dd, 6931), (0x88df, 2381), (0x88e1, 4232), (0x88e8, 6936), (0x88f2, 6937), (0x88f3, 2781), (0x88f4, 6935),
+    (0x88f8, 4206), (0x88f9, 6932), (0x88fc, 6934), (0x88fd, 2942), (0x88fe, 2913), (0x8902, 6933), (0x8904, 6938), (0x8907, 3856),
+    (0x890a, 6940), (0x890c, 6939), (0x8910, 1767), (0x8912, 3958), (0x8913, 6941), (0x891d, 6953), (0x891e, 6943), (0x8925, 6944),
+    (0x892a, 6945), (0x892b, 6946), (0x8936, 6950), (0x8938, 6951), (0x893b, 6949), (0x8941, 6947), (0x8943, 6942), (0x8944, 6948),
+    (0x894c, 6952), (0x894d, 7448), (0x8956, 1605), (0x895e, 6955), (0x895f, 2036), (0x8960, 6954), (0x8964, 6957), (0x8966, 6956),
+    (0x896a, 6959), (0x896d, 6958), (0x896f, 6960), (0x8972, 2648), (0x8974, 6961), (0x8977, 6962), (0x897e, 6963), (0x897f, 2943),
+    (0x8981, 4190), (0x8983, 6964), (0x8986, 3857), (0x8987, 3609), (0x8988, 6965), (0x898a, 6966), (0x898b, 2172), (0x898f, 1891),
+    (0x8993, 6967), (0x8996, 2518), (0x8997, 3604), (0x8998, 6968), (0x899a, 1739), (0x89a1, 6969), (0x89a6, 6971), (0x89a7, 4222),
+    (0x89a9, 6970), (0x89aa, 2857), (0x89ac, 6972), (0x89af, 6973), (0x89b2, 6974), (0x89b3, 1834), (0x89ba, 6975), (0x89bd, 6976),
+    (0x89bf, 6977), (0x89c0, 6978), (0x89d2, 1740), (0x89da, 6979), (0x89dc, 6980), (0x89dd, 6981), (0x89e3, 1679), (0x89e6, 2827),
+    (0x89e7, 6982), (0x89f4, 6983), (0x89f8, 6984), (0x8a00, 2193), (0x8a02, 3380), (0x8a03, 6985), (0x8a08, 2122), (0x8a0a, 2873),
+    (0x8a0c, 6988), (0x8a0e, 3481), (0x8a10, 6987), (0x8a13, 2084), (0x8a16, 6986), (0x8a17, 3188), (0x8a18, 1892), (0x8a1b, 6989),
+    (0x8a1d, 6990), (0x8a1f, 2782), (0x8a23, 2144), (0x8a25, 6991), (0x8a2a, 3959), (0x8a2d, 2976), (0x8a31, 1965), (0x8a33, 4126),
+    (0x8a34, 3049), (0x8a36, 6992), (0x8a3a, 2858), (0x8a3b, 3275), (0x8a3c, 2783), (0x8a41, 6993), (0x8a46, 6996), (0x8a48, 6997),
+    (0x8a50, 2379), (0x8a51, 3135), (0x8a52, 6995), (0x8a54, 2784), (0x8a55, 3789), (0x8a5b, 6994), (0x8a5e, 2519), (0x8a60, 1554),
+    (0x8a62, 7001), (0x8a63, 2123), (0x8a66, 2521), (0x8a69, 2520), (0x8a6b, 4368), (0x8a6c, 7000), (0x8a6d, 6999), (0x8a6e, 3014),
+    (0x8a70, 1924), (0x8a71, 4358), (0x8a72, 1717), (0x8a73, 2785), (0x8a7c, 6998), (0x8a82, 7003), (0x8a84, 7004), (0x8a85, 7002),
+    (0x8a87, 2217), (0x8a89, 4167), (0x8a8c, 2522), (0x8a8d, 3578), (0x8a91, 7007), (0x8a93, 2945), (0x8a95, 3229), (0x8a98, 4157),
+    (0x8a9a, 7010), (0x8a9e, 2237), (0x8aa0, 2944), (0x8aa1, 7006), (0x8aa3, 7011), (0x8aa4, 2238), (0x8aa5, 7008), (0x8aa6, 7009),
+    (0x8aa8, 7005), (0x8aac, 2979), (0x8aad, 3518), (0x8ab0, 3210), (0x8ab2, 1658), (0x8ab9, 3745), (0x8abc, 1914), (0x8abf, 3309),
+    (0x8ac2, 7014), (0x8ac4, 7012), (0x8ac7, 3239), (0x8acb, 2946), (0x8acc, 1835), (0x8acd, 7013), (0x8acf, 2878), (0x8ad2, 4271),
+    (0x8ad6, 4355), (0x8ada, 7015), (0x8adb, 7026), (0x8adc, 3310), (0x8ade, 7025), (0x8ae0, 7022), (0x8ae1, 7030), (0x8ae2, 7023),
+    (0x8ae4, 7019), (0x8ae6, 3381), (0x8ae7, 7018), (0x8aeb, 7016), (0x8aed, 4136), (0x8aee, 2523), (0x8af1, 7020), (0x8af3, 7017),
+    (0x8af7, 7024), (0x8af8, 2715), (0x8afa, 2194), (0x8afe, 3191), (0x8b00, 3984), (0x8b01, 1561), (0x8b02, 1475), (0x8b04, 3482),
+    (0x8b07, 7028), (0x8b0c, 7027), (0x8b0e, 3547), (0x8b10, 7032), (0x8b14, 7021), (0x8b16, 7031), (0x8b17, 7033), (0x8b19, 2173),
+    (0x8b1a, 7029), (0x8b1b, 2309), (0x8b1d, 2590), (0x8b20, 7034), (0x8b21, 4191), (0x8b26, 7037), (0x8b28, 7040), (0x8b2b, 7038),
+    (0x8b2c, 3780), (0x8b33, 7035), (0x8b39, 2037), (0x8b3e, 7039), (0x8b41, 7041), (0x8b49, 7045), (0x8b4c, 7042), (0x8b4e, 7044),
+    (0x8b4f, 7043), (0x8b56, 7046), (0x8b58, 2554), (0x8b5a, 7048), (0x8b5b, 7047), (0x8b5c, 3831), (0x8b5f, 7050), (0x8b66, 2124),
+    (0x8b6b, 7049), (0x8b6c, 7051), (0x8b6f, 7052), (0x8b70, 1915), (0x8b71, 6518), (0x8b72, 2814), (0x8b74, 7053), (0x8b77, 2239),
+    (0x8b7d, 7054), (0x8b80, 7055), (0x8b83, 2473), (0x8b8a, 5403), (0x8b8c, 7056), (0x8b8e, 7057), (0x8b90, 2649), (0x8b92, 7058),
+    (0x8b93, 7059), (0x8b96, 7060), (0x8b99, 7061), (0x8b9a, 7062), (0x8c37, 3206), (0x8c3a, 7063), (0x8c3f, 7065), (0x8c41, 7064),
+    (0x8c46, 3483), (0x8c48, 7066), (0x8c4a, 3960), (0x8c4c, 7067), (0x8c4e, 7068), (0x8c50, 7069), (0x8c55, 7070), (0x8c5a, 3535),
+    (0x8c61, 2786), (0x8c62, 7071), (0x8c6a, 2330), (0x8c6b, 4431), (0x8c6c, 7072), (0x8c78, 7073), (0x8c79, 3790), (0x8c7a, 7074),
+    (0x8c7c, 7082), (0x8c82, 7075), (0x8c85, 7077), (0x8c89, 7076), (0x8c8a, 7078), (0x8c8c, 3985), (0x8c8d, 7079), (0x8c8e, 7080),
+    (0x8c94, 7081), (0x8c98, 7083), (0x8c9d, 1704), (0x8c9e, 3360), (0x8ca0, 3832), (0x8ca1, 2415), (0x8ca2, 2310), (0x8ca7, 3806),
+    (0x8ca8, 1660), (0x8ca9, 3711), (0x8caa, 7086), (0x8cab, 1836), (0x8cac, 2966), (0x8cad, 7085), (0x8cae, 7090), (0x8caf, 3284),
+    (0x8cb0, 4108), (0x8cb2, 7088), (0x8cb3, 7089), (0x8cb4, 1893), (0x8cb6, 7091), (0x8cb7, 3638), (0x8cb8, 3164), (0x8cbb, 3746),
+    (0x8cbc, 3412), (0x8cbd, 7087), (0x8cbf, 3986), (0x8cc0, 1673), (0x8cc1, 7093), (0x8cc2, 4331), (0x8cc3, 3323), (0x8cc4, 4360),
+    (0x8cc7, 2524), (0x8cc8, 7092), (0x8cca, 3118), (0x8ccd, 7109), (0x8cce, 3015), (0x8cd1, 3565), (0x8cd3, 3807), (0x8cda, 7096),
+    (0x8cdb, 2474), (0x8cdc, 2525), (0x8cde, 2787), (0x8ce0, 3640), (0x8ce2, 2174), (0x8ce3, 7095), (0x8ce4, 7094), (0x8ce6, 3833),
+    (0x8cea, 2570), (0x8ced, 3433), (0x8cfa, 7098), (0x8cfb, 7099), (0x8cfc, 2311), (0x8cfd, 7097), (0x8d04, 7100), (0x8d05, 7101),
+    (0x8d07, 7103), (0x8d08, 3104), (0x8d0a, 7102), (0x8d0b, 1855), (0x8d0d, 7105), (0x8d0f, 7104), (0x8d10, 7106), (0x8d13, 7108),
+    (0x8d14, 7110), (0x8d16, 7111), (0x8d64, 2967), (0x8d66, 2584), (0x8d67, 7112), (0x8d6b, 1741), (0x8d6d, 7113), (0x8d70, 3093),
+    (0x8d71, 7114), (0x8d73, 7115), (0x8d74, 3834), (0x8d77, 1894), (0x8d81, 7116), (0x8d85, 3311), (0x8d8a, 1562), (0x8d99, 7117),
+    (0x8da3, 2618), (0x8da8, 2905), (0x8db3, 3114), (0x8dba, 7120), (0x8dbe, 7119), (0x8dc2, 7118), (0x8dcb, 7126), (0x8dcc, 7124),
+    (0x8dcf, 7121), (0x8dd6, 7123), (0x8dda, 7122), (0x8ddb, 7125), (0x8ddd, 1966), (0x8ddf, 7129), (0x8de1, 2968), (0x8de3, 7130),
+    (0x8de8, 2218), (0x8dea, 7127), (0x8deb, 7128), (0x8def, 4332), (0x8df3, 3312), (0x8df5, 3016), (0x8dfc, 7131), (0x8dff, 7134),
+    (0x8e08, 7132), (0x8e09, 7133), (0x8e0a, 4192), (0x8e0f, 3484), (0x8e10, 7137), (0x8e1d, 7135), (0x8e1e, 7136), (0x8e1f, 7138),
+    (0x8e2a, 7152), (0x8e30, 7141), (0x8e34, 7142), (0x8e35, 7140), (0x8e42, 7139), (0x8e44, 3382), (0x8e47, 7144), (0x8e48, 7148),
+    (0x8e49, 7145), (0x8e4a, 7143), (0x8e4c, 7146), (0x8e50, 7147), (0x8e55, 7154), (0x8e59, 7149), (0x8e5f, 2969), (0x8e60, 7151),
+    (0x8e63, 7153), (0x8e64, 7150), (0x8e72, 7156), (0x8e74, 2650), (0x8e76, 7155), (0x8e7c, 7157), (0x8e81, 7158), (0x8e84, 7161),
+    (0x8e85, 7160), (0x8e87, 7159), (0x8e8a, 7163), (0x8e8b, 7162), (0x8e8d, 4127), (0x8e91, 7165), (0x8e93, 7164), (0x8e94, 7166),
+    (0x8e99, 7167), (0x8ea1, 7169), (0x8eaa, 7168), (0x8eab, 2859), (0x8eac, 7170), (0x8eaf, 2050), (0x8eb0, 7171), (0x8eb1, 7173),
+    (0x8ebe, 7174), (0x8ec5, 7175), (0x8ec6, 7172), (0x8ec8, 7176), (0x8eca, 2591), (0x8ecb, 7177), (0x8ecc, 1895), (0x8ecd, 2086),
+    (0x8ed2, 2175), (0x8edb, 7178), (0x8edf, 3557), (0x8ee2, 3413), (0x8ee3, 7179), (0x8eeb, 7182), (0x8ef8, 2557), (0x8efb, 7181),
+    (0x8efc, 7180), (0x8efd, 2125), (0x8efe, 7183), (0x8f03, 1742), (0x8f05, 7185), (0x8f09, 2409), (0x8f0a, 7184), (0x8f0c, 7193),
+    (0x8f12, 7187), (0x8f13, 7189), (0x8f14, 3922), (0x8f15, 7186), (0x8f19, 7188), (0x8f1b, 7192), (0x8f1c, 7190), (0x8f1d, 1896),
+    (0x8f1f, 7191), (0x8f26, 7194), (0x8f29, 3629), (0x8f2a, 4285), (0x8f2f, 2651), (0x8f33, 7195), (0x8f38, 4137), (0x8f39, 7197),
+    (0x8f3b, 7196), (0x8f3e, 7200), (0x8f3f, 4168), (0x8f42, 7199), (0x8f44, 1768), (0x8f45, 7198), (0x8f46, 7203), (0x8f49, 7202),
+    (0x8f4c, 7201), (0x8f4d, 3401), (0x8f4e, 7204), (0x8f57, 7205), (0x8f5c, 7206), (0x8f5f, 2331), (0x8f61, 2072), (0x8f62, 7207),
+    (0x8f63, 7208), (0x8f64, 7209), (0x8f9b, 2860), (0x8f9c, 7210), (0x8f9e, 2550), (0x8f9f, 7211), (0x8fa3, 7212), (0x8fa7, 4606),
+    (0x8fa8, 4605), (0x8fad, 7213), (0x8fae, 6471), (0x8faf, 7214), (0x8fb0, 3199), (0x8fb1, 2830), (0x8fb2, 3603), (0x8fb7, 7215),
+    (0x8fba, 3906), (0x8fbb, 3341), (0x8fbc, 2349), (0x8fbf, 3204), (0x8fc2, 1513), (0x8fc4, 4035), (0x8fc5, 2874), (0x8fce, 2129),
+    (0x8fd1, 2038), (0x8fd4, 3907), (0x8fda, 7216), (0x8fe2, 7218), (0x8fe5, 7217), (0x8fe6, 1661), (0x8fe9, 3563), (0x8fea, 7219),
+    (0x8feb, 3658), (0x8fed, 3402), (0x8fef, 7220), (0x8ff0, 2681), (0x8ff4, 7222), (0x8ff7, 4075), (0x8ff8, 7237), (0x8ff9, 7224),
+    (0x8ffa, 7225), (0x8ffd, 3330), (0x9000, 3165), (0x9001, 3094), (0x9003, 3485), (0x9005, 7223), (0x9006, 1932), (0x900b, 7232),
+    (0x900d, 7229), (0x900e, 7242), (0x900f, 3486), (0x9010, 3259), (0x9011, 7226), (0x9013, 3383), (0x9014, 3434), (0x9015, 7227),
+    (0x9016, 7231), (0x9017, 2883), (0x9019, 3642), (0x901a, 3333), (0x901d, 2947), (0x901e, 7230), (0x901f, 3115), (0x9020, 3105),
+    (0x9021, 7228), (0x9022, 1418), (0x9023, 4325), (0x9027, 7233), (0x902e, 3166), (0x9031, 2652), (0x9032, 2861), (0x9035, 7235),
+    (0x9036, 7234), (0x9038, 1488), (0x9039, 7236), (0x903c, 3774), (0x903e, 7244), (0x9041, 3536), (0x9042, 2894), (0x9045, 3252),
+    (0x9047, 2061), (0x9049, 7243), (0x904a, 4158), (0x904b, 1534), (0x904d, 3908), (0x904e, 1662), (0x904f, 7238), (0x9050, 7239),
+    (0x9051, 7240), (0x9052, 7241), (0x9053, 3504), (0x9054, 3198), (0x9055, 1476), (0x9056, 7245), (0x9058, 7246), (0x9059, 7804),
+    (0x905c, 3130), (0x905e, 7247), (0x9060, 1586), (0x9061, 3051), (0x9063, 2176), (0x9065, 4193), (0x9068, 7248), (0x9069, 3395),
+    (0x906d, 3095), (0x906e, 2592), (0x906f, 7249), (0x9072, 7252), (0x9075, 2700), (0x9076, 7250), (0x9077, 3018), (0x9078, 3017),
+    (0x907a, 1477), (0x907c, 4272), (0x907d, 7254), (0x907f, 3747), (0x9080, 7256), (0x9081, 7255), (0x9082, 7253), (0x9083, 6271),
+    (0x9084, 1837), (0x9087, 7221), (0x9089, 7258), (0x908a, 7257), (0x908f, 7259), (0x9091, 4159), (0x90a3, 3542), (0x90a6, 3961),
+    (0x90a8, 7260), (0x90aa, 2594), (0x90af, 7261), (0x90b1, 7262), (0x90b5, 7263), (0x90b8, 3384), (0x90c1, 1483), (0x90ca, 2312),
+    (0x90ce, 4349), (0x90db, 7267), (0x90e1, 2087), (0x90e2, 7264), (0x90e4, 7265), (0x90e8, 3843), (0x90ed, 1743), (0x90f5, 4160),
+    (0x90f7, 2004), (0x90fd, 3435), (0x9102, 7268), (0x9112, 7269), (0x9119, 7270), (0x912d, 3385), (0x9130, 7272), (0x9132, 7271),
+    (0x9149, 3528), (0x914a, 7273), (0x914b, 2653), (0x914c, 2601), (0x914d, 3630), (0x914e, 3276), (0x9152, 2619), (0x9154, 2895),
+    (0x9156, 7274), (0x9158, 7275), (0x9162, 2880), (0x9163, 7276), (0x9165, 7277), (0x9169, 7278), (0x916a, 4214), (0x916c, 2654),
+    (0x9172, 7280), (0x9173, 7279), (0x9175, 2313), (0x9177, 2338), (0x9178, 2475), (0x9182, 7283), (0x9187, 2701), (0x9189, 7282),
+    (0x918b, 7281), (0x918d, 3174), (0x9190, 2240), (0x9192, 2948), (0x9197, 3681), (0x919c, 2656), (0x91a2, 7284), (0x91a4, 2788),
+    (0x91aa, 7287), (0x91ab, 7285), (0x91af, 7286), (0x91b4, 7289), (0x91b5, 7288), (0x91b8, 2815), (0x91ba, 7290), (0x91c0, 7291),
+    (0x91c1, 7292), (0x91c6, 3713), (0x91c7, 2400), (0x91c8, 2602), (0x91c9, 7293), (0x91cb, 7294), (0x91cc, 4233), (0x91cd, 2668),
+    (0x91ce, 4119), (0x91cf, 4273), (0x91d0, 7295), (0x91d1, 2039), (0x91d6, 7296), (0x91d8, 3386), (0x91db, 7299), (0x91dc, 1779),
+    (0x91dd, 2862), (0x91df, 7297), (0x91e1, 7298), (0x91e3, 3353), (0x91e6, 4000), (0x91e7, 2065), (0x91f5, 7301), (0x91f6, 7302),
+    (0x91fc, 7300), (0x91ff, 7304), (0x920d, 3540), (0x920e, 1726), (0x9211, 7308), (0x9214, 7305), (0x9215, 7307), (0x921e, 7303),
+    (0x9229, 7378), (0x922c, 7306), (0x9234, 4304), (0x9237, 2219), (0x923f, 7316), (0x9244, 3403), (0x9245, 7311), (0x9248, 7314),
+    (0x9249, 7312), (0x924b, 7317), (0x9250, 7318), (0x9257, 7310), (0x925a, 7323), (0x925b, 1587), (0x925e, 7309), (0x9262, 3678),
+    (0x9264, 7313), (0x9266, 2789), (0x9271, 2314), (0x927e, 3987), (0x9280, 2041), (0x9283, 2669), (0x9285, 3505), (0x9291, 3020),
+    (0x9293, 7321), (0x9295, 7315), (0x9296, 7320), (0x9298, 4076), (0x929a, 3313), (0x929b, 7322), (0x929c, 7319), (0x92ad, 3019),
+    (0x92b7, 7326), (0x92b9, 7325), (0x92cf, 7324), (0x92d2, 3962), (0x92e4, 2722), (0x92e9, 7327), (0x92ea, 3916), (0x92ed, 1555),
+    (0x92f2, 3797), (0x92f3, 3277), (0x92f8, 1967), (0x92fa, 7329), (0x92fc, 2316), (0x9306, 2455), (0x930f, 7328), (0x9310, 2896),
+    (0x9318, 2897), (0x9319, 7332), (0x931a, 7334), (0x9320, 2816), (0x9322, 7333), (0x9323, 7335), (0x9326, 2024), (0x9328, 3796),
+    (0x932b, 2603), (0x932c, 4326), (0x932e, 7331), (0x932f, 2437), (0x9332, 4354), (0x9335, 7337), (0x933a, 7336), (0x933b, 7338),
+    (0x9344, 7330), (0x934b, 3550), (0x934d, 3436), (0x9354, 3344), (0x9356, 7343), (0x935b, 3230), (0x935c, 7339), (0x9360, 7340),
+    (0x936c, 2080), (0x936e, 7342), (0x9375, 2177), (0x937c, 7341), (0x937e, 2790), (0x938c, 1780), (0x9394, 7347), (0x9396, 2380),
+    (0x9397, 3096), (0x939a, 3331), (0x93a7, 1718), (0x93ac, 7345), (0x93ad, 7346), (0x93ae, 3324), (0x93b0, 7344), (0x93b9, 7348),
+    (0x93c3, 7354), (0x93c8, 7357), (0x93d0, 7356), (0x93d1, 3396), (0x93d6, 7349), (0x93d7, 7350), (0x93d8, 7353), (0x93dd, 7355),
+    (0x93e1, 2005), (0x93e4, 7358), (0x93e5, 7352), (0x93e8, 7351), (0x9403, 7362), (0x9407, 7363), (0x9410, 7364), (0x9413, 7361),
+    (0x9414, 7360), (0x9418, 2791), (0x9419, 3487), (0x941a, 7359), (0x9421, 7368), (0x942b, 7366), (0x9435, 7367), (0x9436, 7365),
+    (0x9438, 3189), (0x943a, 7369), (0x9441, 7370), (0x9444, 7372), (0x9451, 1838), (0x9452, 7371), (0x9453, 4131), (0x945a, 7383),
+    (0x945b, 7373), (0x945e, 7376), (0x9460, 7374), (0x9462, 7375), (0x946a, 7377), (0x9470, 7379), (0x9475, 7380), (0x9477, 7381),
+    (0x947c, 7384), (0x947d, 7382), (0x947e, 7385), (0x947f, 7387), (0x9481, 7386), (0x9577, 3314), (0x9580, 4112), (0x9582, 7388),
+    (0x9583, 3021), (0x9587, 7389), (0x9589, 3889), (0x958a, 7390), (0x958b, 1702), (0x958f, 1531), (0x9591, 1840), (0x9593, 1839),
+    (0x9594, 7391), (0x9596, 7392), (0x9598, 7393), (0x9599, 7394), (0x95a0, 7395), (0x95a2, 1841), (0x95a3, 1744), (0x95a4, 2317),
+    (0x95a5, 3687), (0x95a7, 7397), (0x95a8, 7396), (0x95ad, 7398), (0x95b2, 1563), (0x95b9, 7401), (0x95bb, 7400), (0x95bc, 7399),
+    (0x95be, 7402), (0x95c3, 7405), (0x95c7, 1448), (0x95ca, 7403), (0x95cc, 7407), (0x95cd, 7406), (0x95d4, 7409), (0x95d5, 7408),
+    (0x95d6, 7410), (0x95d8, 3491), (0x95dc, 7411), (0x95e1, 7412), (0x95e2, 7414), (0x95e5, 7413), (0x961c, 3835), (0x9621, 7415),
+    (0x9628, 7416), (0x962a, 2418), (0x962e, 7417), (0x962f, 7418), (0x9632, 3988), (0x963b, 3050), (0x963f, 1413), (0x9640, 3144),
+    (0x9642, 7419), (0x9644, 3836), (0x964b, 7422), (0x964c, 7420), (0x964d, 2318), (0x964f, 7421), (0x9650, 2195), (0x965b, 3890),
+    (0x965c, 7424), (0x965d, 7426), (0x965e, 7425), (0x965f, 7427), (0x9662, 1504), (0x9663, 2875), (0x9664, 2723), (0x9665, 1842),
+    (0x9666, 7428), (0x966a, 3641), (0x966c, 7430), (0x9670, 1505), (0x9672, 7429), (0x9673, 3325), (0x9675, 4274), (0x9676, 3488),
+    (0x9677, 7423), (0x9678, 4235), (0x967a, 2178), (0x967d, 4194), (0x9685, 2062), (0x9686, 4249), (0x9688, 2075), (0x968a, 3167),
+    (0x968b, 6580), (0x968d, 7431), (0x968e, 1703), (0x968f, 2898), (0x9694, 1745), (0x9695, 7433), (0x9697, 7434), (0x9698, 7432),
+    (0x9699, 2135), (0x969b, 2410), (0x969c, 2792), (0x96a0, 1506), (0x96a3, 4286), (0x96a7, 7436), (0x96a8, 7251), (0x96aa, 7435),
+    (0x96b0, 7439), (0x96b1, 7437), (0x96b2, 7438), (0x96b4, 7440), (0x96b6, 7441), (0x96b7, 4305), (0x96b8, 7442), (0x96b9, 7443),
+    (0x96bb, 2954), (0x96bc, 3692), (0x96c0, 2912), (0x96c1, 1856), (0x96c4, 4161), (0x96c5, 1674), (0x96c6, 2655), (0x96c7, 2220),
+    (0x96c9, 7446), (0x96cb, 7445), (0x96cc, 2526), (0x96cd, 7447), (0x96ce, 7444), (0x96d1, 2451), (0x96d5, 7451), (0x96d6, 6874),
+    (0x96d9, 4659), (0x96db, 2906), (0x96dc, 7449), (0x96e2, 4234), (0x96e3, 3558), (0x96e8, 1514), (0x96ea, 2980), (0x96eb, 2559),
+    (0x96f0, 3876), (0x96f2, 1535), (0x96f6, 4306), (0x96f7, 4210), (0x96f9, 7452), (0x96fb, 3420), (0x9700, 2628), (0x9704, 7453),
+    (0x9706, 7454), (0x9707, 2863), (0x9708, 7455), (0x970a, 4307), (0x970d, 7450), (0x970e, 7457), (0x970f, 7459), (0x9711, 7458),
+    (0x9713, 7456), (0x9716, 7460), (0x9719, 7461), (0x971c, 3097), (0x971e, 1663), (0x9724, 7462), (0x9727, 4065), (0x972a, 7463),
+    (0x9730, 7464), (0x9732, 4333), (0x9738, 5468), (0x9739, 7465), (0x973d, 7466), (0x973e, 7467), (0x9742, 7471), (0x9744, 7468),
+    (0x9746, 7469), (0x9748, 7470), (0x9749, 7472), (0x9752, 2949), (0x9756, 4128), (0x9759, 2950), (0x975c, 7473), (0x975e, 3748),
+    (0x9760, 7474), (0x9761, 7758), (0x9762, 4085), (0x9764, 7475), (0x9766, 7476), (0x9768, 7477), (0x9769, 1746), (0x976b, 7479),
+    (0x976d, 2876), (0x9771, 7480), (0x9774, 2071), (0x9779, 7481), (0x977a, 7485), (0x977c, 7483), (0x9781, 7484), (0x9784, 1774),
+    (0x9785, 7482), (0x9786, 7486), (0x978b, 7487), (0x978d, 1449), (0x978f, 7488), (0x9790, 7489), (0x9798, 2793), (0x979c, 7490),
+    (0x97a0, 1918), (0x97a3, 7493), (0x97a6, 7492), (0x97a8, 7491), (0x97ab, 7036), (0x97ad, 3913), (0x97b3, 7494), (0x97b4, 7495),
+    (0x97c3, 7496), (0x97c6, 7497), (0x97c8, 7498), (0x97cb, 7499), (0x97d3, 1843), (0x97dc, 7500), (0x97ed, 7501), (0x97ee, 3574),
+    (0x97f2, 7503), (0x97f3, 1624), (0x97f5, 7506), (0x97f6, 7505), (0x97fb, 1507), (0x97ff, 2006), (0x9801, 3892), (0x9802, 3315),
+    (0x9803, 2351), (0x9805, 2319), (0x9806, 2702), (0x9808, 2879), (0x980c, 7508), (0x980f, 7507), (0x9810, 4169), (0x9811, 1857),
+    (0x9812, 3715), (0x9813, 3537), (0x9817, 2911), (0x9818, 4275), (0x981a, 2126), (0x9821, 7511), (0x9824, 7510), (0x982c, 3990),
+    (0x982d, 3489), (0x9834, 1551), (0x9837, 7512), (0x9838, 7509), (0x983b, 3808), (0x983c, 4209), (0x983d, 7513), (0x9846, 7514),
+    (0x984b, 7516), (0x984c, 3175), (0x984d, 1750), (0x984e, 1751), (0x984f, 7515), (0x9854, 1858), (0x9855, 2179), (0x9858, 1859),
+    (0x985b, 3414), (0x985e, 4293), (0x9867, 2221), (0x986b, 7517), (0x986f, 7518), (0x9870, 7519), (0x9871, 7520), (0x9873, 7522),
+    (0x9874, 7521), (0x98a8, 3846), (0x98aa, 7523), (0x98af, 7524), (0x98b1, 7525), (0x98b6, 7526), (0x98c3, 7528), (0x98c4, 7527),
+    (0x98c6, 7529), (0x98db, 3749), (0x98dc, 6529), (0x98df, 2828), (0x98e2, 1897), (0x98e9, 7530), (0x98eb, 7531), (0x98ed, 4617),
+    (0x98ee, 5666), (0x98ef, 3716), (0x98f2, 1500), (0x98f4, 1436), (0x98fc, 2527), (0x98fd, 3963), (0x98fe, 2819), (0x9903, 7532),
+    (0x9905, 4104), (0x9909, 7533), (0x990a, 4195), (0x990c, 1537), (0x9910, 2476), (0x9912, 7534), (0x9913, 1675), (0x9914, 7535),
+    (0x9918, 7536), (0x991d, 7538), (0x991e, 7539), (0x9920, 7541), (0x9921, 7537), (0x9924, 7540), (0x9928, 1844), (0x992c, 7542),
+    (0x992e, 7543), (0x993d, 7544), (0x993e, 7545), (0x9942, 7546), (0x9945, 7548), (0x9949, 7547), (0x994b, 7550), (0x994c, 7553),
+    (0x9950, 7549), (0x9951, 7551), (0x9952, 7552), (0x9955, 7554), (0x9957, 2007), (0x9996, 2620), (0x9997, 7555), (0x9998, 7556),
+    (0x9999, 2320), (0x99a5, 7557), (0x99a8, 1721), (0x99ac, 3618), (0x99ad, 7558), (0x99ae, 7559), (0x99b3, 3253), (0x99b4, 3552),
+    (0x99bc, 7560), (0x99c1, 3664), (0x99c4, 3145), (0x99c5, 1559), (0x99c6, 2051), (0x99c8, 2052), (0x99d0, 3278), (0x99d1, 7565),
+    (0x99d2, 2053), (0x99d5, 1676), (0x99d8, 7564), (0x99db, 7562), (0x99dd, 7563), (0x99df, 7561), (0x99e2, 7575), (0x99ed, 7566),
+    (0x99ee, 7567), (0x99f1, 7568), (0x99f2, 7569), (0x99f8, 7571), (0x99fb, 7570), (0x99ff, 2688), (0x9a01, 7572), (0x9a05, 7574),
+    (0x9a0e, 1898), (0x9a0f, 7573), (0x9a12, 3098), (0x9a13, 2180), (0x9a19, 7576), (0x9a28, 3146), (0x9a2b, 7577), (0x9a30, 3490),
+    (0x9a37, 7578), (0x9a3e, 7583), (0x9a40, 7581), (0x9a42, 7580), (0x9a43, 7582), (0x9a45, 7579), (0x9a4d, 7585), (0x9a55, 7584),
+    (0x9a57, 7587), (0x9a5a, 2008), (0x9a5b, 7586), (0x9a5f, 7588), (0x9a62, 7589), (0x9a64, 7591), (0x9a65, 7590), (0x9a69, 7592),
+    (0x9a6a, 7594), (0x9a6b, 7593), (0x9aa8, 2347), (0x9aad, 7595), (0x9ab0, 7596), (0x9ab8, 1719), (0x9abc, 7597), (0x9ac0, 7598),
+    (0x9ac4, 2900), (0x9acf, 7599), (0x9ad1, 7600), (0x9ad3, 7601), (0x9ad4, 7602), (0x9ad8, 2321), (0x9ade, 7603), (0x9adf, 7604),
+    (0x9ae2, 7605), (0x9ae3, 7606), (0x9ae6, 7607), (0x9aea, 3682), (0x9aeb, 7609), (0x9aed, 3765), (0x9aee, 7610), (0x9aef, 7608),
+    (0x9af1, 7612), (0x9af4, 7611), (0x9af7, 7613), (0x9afb, 7614), (0x9b06, 7615), (0x9b18, 7616), (0x9b1a, 7617), (0x9b1f, 7618),
+    (0x9b22, 7619), (0x9b23, 7620), (0x9b25, 7621), (0x9b27, 7622), (0x9b28, 7623), (0x9b29, 7624), (0x9b2a, 7625), (0x9b2e, 7626),
+    (0x9b2f, 7627), (0x9b31, 5660), (0x9b32, 7628), (0x9b3b, 6385), (0x9b3c, 1899), (0x9b41, 1692), (0x9b42, 2367), (0x9b43, 7630),
+    (0x9b44, 7629), (0x9b45, 4046), (0x9b4d, 7632), (0x9b4e, 7633), (0x9b4f, 7631), (0x9b51, 7634), (0x9b54, 4013), (0x9b58, 7635),
+    (0x9b5a, 1970), (0x9b6f, 4328), (0x9b74, 7636), (0x9b83, 7638), (0x9b8e, 1439), (0x9b91, 7639), (0x9b92, 3864), (0x9b93, 7637),
+    (0x9b96, 7640), (0x9b97, 7641), (0x9b9f, 7642), (0x9ba0, 7643), (0x9ba8, 7644), (0x9baa, 4025), (0x9bab, 2456), (0x9bad, 2439),
+    (0x9bae, 3022), (0x9bb4, 7645), (0x9bb9, 7648), (0x9bc0, 7646), (0x9bc6, 7649), (0x9bc9, 2242), (0x9bca, 7647), (0x9bcf, 7650),
+    (0x9bd1, 7651), (0x9bd2, 7652), (0x9bd4, 7656), (0x9bd6, 2453), (0x9bdb, 3169), (0x9be1, 7657), (0x9be2, 7654), (0x9be3, 7653),
+    (0x9be4, 7655), (0x9be8, 2130), (0x9bf0, 7661), (0x9bf1, 7660), (0x9bf2, 7659), (0x9bf5, 1428), (0x9c04, 7671), (0x9c06, 7667),
+    (0x9c08, 7668), (0x9c09, 7664), (0x9c0a, 7670), (0x9c0c, 7666), (0x9c0d, 1757), (0x9c10, 4367), (0x9c12, 7669), (0x9c13, 7665),
+    (0x9c14, 7663), (0x9c15, 7662), (0x9c1b, 7673), (0x9c21, 7676), (0x9c24, 7675), (0x9c25, 7674), (0x9c2d, 3800), (0x9c2e, 7672),
+    (0x9c2f, 1492), (0x9c30, 7677), (0x9c32, 7679), (0x9c39, 1770), (0x9c3a, 7658), (0x9c3b, 1526), (0x9c3e, 7681), (0x9c46, 7680),
+    (0x9c47, 7678), (0x9c48, 3208), (0x9c52, 4027), (0x9c57, 4287), (0x9c5a, 7682), (0x9c60, 7683), (0x9c67, 7684), (0x9c76, 7685),
+    (0x9c78, 7686), (0x9ce5, 3316), (0x9ce7, 7687), (0x9ce9, 3688), (0x9ceb, 7692), (0x9cec, 7688), (0x9cf0, 7689), (0x9cf3, 3964),
+    (0x9cf4, 4077), (0x9cf6, 3525), (0x9d03, 7693), (0x9d06, 7694), (0x9d07, 3507), (0x9d08, 7691), (0x9d09, 7690), (0x9d0e, 1607),
+    (0x9d12, 7702), (0x9d15, 7701), (0x9d1b, 1588), (0x9d1f, 7699), (0x9d23, 7698), (0x9d26, 7696), (0x9d28, 1782), (0x9d2a, 7695),
+    (0x9d2b, 2555), (0x9d2c, 1606), (0x9d3b, 2322), (0x9d3e, 7705), (0x9d3f, 7704), (0x9d41, 7703), (0x9d44, 7700), (0x9d46, 7706),
+    (0x9d48, 7707), (0x9d50, 7712), (0x9d51, 7711), (0x9d59, 7713), (0x9d5c, 1516), (0x9d5d, 7708), (0x9d5e, 7709), (0x9d60, 2339),
+    (0x9d61, 4066), (0x9d64, 7710), (0x9d6c, 3965), (0x9d6f, 7718), (0x9d72, 7714), (0x9d7a, 7719), (0x9d87, 7716), (0x9d89, 7715),
+    (0x9d8f, 2127), (0x9d9a, 7720), (0x9da4, 7721), (0x9da9, 7722), (0x9dab, 7717), (0x9daf, 7697), (0x9db2, 7723), (0x9db4, 3354),
+    (0x9db8, 7727), (0x9dba, 7728), (0x9dbb, 7726), (0x9dc1, 7725), (0x9dc2, 7731), (0x9dc4, 7724), (0x9dc6, 7729), (0x9dcf, 7730),
+    (0x9dd3, 7733), (0x9dd9, 7732), (0x9de6, 7735), (0x9ded, 7736), (0x9def, 7737), (0x9df2, 4364), (0x9df8, 7734), (0x9df9, 3176),
+    (0x9dfa, 2426), (0x9dfd, 7738), (0x9e1a, 7739), (0x9e1b, 7740), (0x9e1e, 7741), (0x9e75, 7742), (0x9e78, 2181), (0x9e79, 7743),
+    (0x9e7d, 7744), (0x9e7f, 2552), (0x9e81, 7745), (0x9e88, 7746), (0x9e8b, 7747), (0x9e8c, 7748), (0x9e91, 7751), (0x9e92, 7749),
+    (0x9e93, 4351), (0x9e95, 7750), (0x9e97, 4308), (0x9e9d, 7752), (0x9e9f, 4288), (0x9ea5, 7753), (0x9ea6, 3665), (0x9ea9, 7754),
+    (0x9eaa, 7756), (0x9ead, 7757), (0x9eb8, 7755), (0x9eb9, 2332), (0x9eba, 4086), (0x9ebb, 4014), (0x9ebc, 5068), (0x9ebe, 5703),
+    (0x9ebf, 4038), (0x9ec4, 1608), (0x9ecc, 7759), (0x9ecd, 1927), (0x9ece, 7760), (0x9ecf, 7761), (0x9ed0, 7762), (0x9ed2, 2340),
+    (0x9ed4, 7763), (0x9ed8, 5973), (0x9ed9, 4100), (0x9edb, 3168), (0x9edc, 7764), (0x9edd, 7766), (0x9ede, 7765), (0x9ee0, 7767),
+    (0x9ee5, 7768), (0x9ee8, 7769), (0x9eef, 7770), (0x9ef4, 7771), (0x9ef6, 7772), (0x9ef7, 7773), (0x9ef9, 7774), (0x9efb, 7775),
+    (0x9efc, 7776), (0x9efd, 7777), (0x9f07, 7778), (0x9f08, 7779), (0x9f0e, 3387), (0x9f13, 2222), (0x9f15, 7781), (0x9f20, 3052),
+    (0x9f21, 7782), (0x9f2c, 7783), (0x9f3b, 3760), (0x9f3e, 7784), (0x9f4a, 7785), (0x9f4b, 6226), (0x9f4e, 7107), (0x9f4f, 7502),
+    (0x9f52, 7786), (0x9f54, 7787), (0x9f5f, 7789), (0x9f60, 7790), (0x9f61, 7791), (0x9f62, 4309), (0x9f63, 7788), (0x9f66, 7792),
+    (0x9f67, 7793), (0x9f6a, 7795), (0x9f6c, 7794), (0x9f72, 7797), (0x9f76, 7798), (0x9f77, 7796), (0x9f8d, 4251), (0x9f95, 7799),
+    (0x9f9c, 7800), (0x9f9d, 6255), (0x9fa0, 7801), (0xff01, 9), (0xff03, 83), (0xff04, 79), (0xff05, 82), (0xff06, 84),
+    (0xff08, 41), (0xff09, 42), (0xff0a, 85), (0xff0b, 59), (0xff0c, 3), (0xff0e, 4), (0xff0f, 30), (0xff10, 203),
+    (0xff11, 204), (0xff12, 205), (0xff13, 206), (0xff14, 207), (0xff15, 208), (0xff16, 209), (0xff17, 210), (0xff18, 211),
+    (0xff19, 212), (0xff1a, 6), (0xff1b, 7), (0xff1c, 66), (0xff1d, 64), (0xff1e, 67), (0xff1f, 8), (0xff20, 86),
+    (0xff21, 220), (0xff22, 221), (0xff23, 222), (0xff24, 223), (0xff25, 224), (0xff26, 225), (0xff27, 226), (0xff28, 227),
+    (0xff29, 228), (0xff2a, 229), (0xff2b, 230), (0xff2c, 231), (0xff2d, 232), (0xff2e, 233), (0xff2f, 234), (0xff30, 235),
+    (0xff31, 236), (0xff32, 237), (0xff33, 238), (0xff34, 239), (0xff35, 240), (0xff36, 241), (0xff37, 242), (0xff38, 243),
+    (0xff39, 244), (0xff3a, 245), (0xff3b, 45), (0xff3c, 31), (0xff3d, 46), (0xff3e, 15), (0xff3f, 17), (0xff40, 13),
+    (0xff41, 252), (0xff42, 253), (0xff43, 254), (0xff44, 255), (0xff45, 256), (0xff46, 257), (0xff47, 258), (0xff48, 259),
+    (0xff49, 260), (0xff4a, 261), (0xff4b, 262), (0xff4c, 263), (0xff4d, 264), (0xff4e, 265), (0xff4f, 266), (0xff50, 267),
+    (0xff51, 268), (0xff52, 269), (0xff53, 270), (0xff54, 271), (0xff55, 272), (0xff56, 273), (0xff57, 274), (0xff58, 275),
+    (0xff59, 276), (0xff5a, 277), (0xff5b, 47), (0xff5c, 34), (0xff5d, 48), (0xffe3, 16), (0xffe5, 78),
+];
+
+/// Returns the character at `pointer`, if any.
+pub(crate) fn decode(pointer: usize) -> Option<char> {
+    match TO_UNICODE.get(pointer) {
+        Some(&0) | None => None,
+        Some(&code_point) => char::from_u32(u32::from(code_point)),
+    }
+}
+
+/// Returns the pointer of `character`, if it is part of the character set.
+pub(crate) fn encode(character: char) -> Option<usize> {
+    let code_point = u16::try_from(u32::from(character)).ok()?;
+
+    FROM_UNICODE
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| usize::from(FROM_UNICODE[index].1))
+}
@@ -0,0 +1,1345 @@
+//! The JIS X 0212 supplementary character set, where the pointer of the character at row `r` and
+//! cell `c` is `(r - 1) * 94 + (c - 1)`.
+
+use std::char;
+use std::convert::TryFrom;
+
+/// The Unicode code point of the character at each pointer, or 0 if the pointer is unassigned.
+#[rustfmt::skip]
+static TO_UNICODE: [u16; 8836] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x02d8, 0x02c7, 0x00b8, 0x02d9,
+    0x02dd, 0x00af, 0x02db, 0x02da, 0x007e, 0x0384, 0x0385, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x00a1,
+    0x00a6, 0x00bf, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x00ba, 0x00aa, 0x00a9, 0x00ae, 0x2122, 0x00a4, 0x2116, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0386, 0x0388, 0x0389, 0x038a, 0x03aa, 0x0000, 0x038c, 0x0000, 0x038e, 0x03ab,
+    0x0000, 0x038f, 0x0000, 0x0000, 0x0000, 0x0000, 0x03ac, 0x03ad, 0x03ae, 0x03af, 0x03ca, 0x0390, 0x03cc, 0x03c2, 0x03cd, 0x03cb,
+    0x03b0, 0x03ce, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0402, 0x0403, 0x0404, 0x0405, 0x0406, 0x0407, 0x0408, 0x0409, 0x040a, 0x040b, 0x040c,
+    0x040e, 0x040f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0452, 0x0453, 0x0454, 0x0455, 0x0456, 0x0457, 0x0458, 0x0459, 0x045a, 0x045b, 0x045c,
+    0x045e, 0x045f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x00c6, 0x0110, 0x0000, 0x0126, 0x0000, 0x0132, 0x0000, 0x0141, 0x013f, 0x0000, 0x014a, 0x00d8, 0x0152, 0x0000, 0x0166, 0x00de,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x00e6, 0x0111, 0x00f0, 0x0127, 0x0131, 0x0133, 0x0138, 0x0142, 0x0140, 0x0149, 0x014b, 0x00f8, 0x0153, 0x00df, 0x0167, 0x00fe,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x00c1, 0x00c0,
+    0x00c4, 0x00c2, 0x0102, 0x01cd, 0x0100, 0x0104, 0x00c5, 0x00c3, 0x0106, 0x0108, 0x010c, 0x00c7, 0x010a, 0x010e, 0x00c9, 0x00c8,
+    0x00cb, 0x00ca, 0x011a, 0x0116, 0x0112, 0x0118, 0x0000, 0x011c, 0x011e, 0x0122, 0x0120, 0x0124, 0x00cd, 0x00cc, 0x00cf, 0x00ce,
+    0x01cf, 0x0130, 0x012a, 0x012e, 0x0128, 0x0134, 0x0136, 0x0139, 0x013d, 0x013b, 0x0143, 0x0147, 0x0145, 0x00d1, 0x00d3, 0x00d2,
+    0x00d6, 0x00d4, 0x01d1, 0x0150, 0x014c, 0x00d5, 0x0154, 0x0158, 0x0156, 0x015a, 0x015c, 0x0160, 0x015e, 0x0164, 0x0162, 0x00da,
+    0x00d9, 0x00dc, 0x00db, 0x016c, 0x01d3, 0x0170, 0x016a, 0x0172, 0x016e, 0x0168, 0x01d7, 0x01db, 0x01d9, 0x01d5, 0x0174, 0x00dd,
+    0x0178, 0x0176, 0x0179, 0x017d, 0x017b, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x00e1, 0x00e0, 0x00e4, 0x00e2,
+    0x0103, 0x01ce, 0x0101, 0x0105, 0x00e5, 0x00e3, 0x0107, 0x0109, 0x010d, 0x00e7, 0x010b, 0x010f, 0x00e9, 0x00e8, 0x00eb, 0x00ea,
+    0x011b, 0x0117, 0x0113, 0x0119, 0x01f5, 0x011d, 0x011f, 0x0000, 0x0121, 0x0125, 0x00ed, 0x00ec, 0x00ef, 0x00ee, 0x01d0, 0x0000,
+    0x012b, 0x012f, 0x0129, 0x0135, 0x0137, 0x013a, 0x013e, 0x013c, 0x0144, 0x0148, 0x0146, 0x00f1, 0x00f3, 0x00f2, 0x00f6, 0x00f4,
+    0x01d2, 0x0151, 0x014d, 0x00f5, 0x0155, 0x0159, 0x0157, 0x015b, 0x015d, 0x0161, 0x015f, 0x0165, 0x0163, 0x00fa, 0x00f9, 0x00fc,
+    0x00fb, 0x016d, 0x01d4, 0x0171, 0x016b, 0x0173, 0x016f, 0x0169, 0x01d8, 0x01dc, 0x01da, 0x01d6, 0x0175, 0x00fd, 0x00ff, 0x0177,
+    0x017a, 0x017e, 0x017c, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x4e02, 0x4e04, 0x4e05, 0x4e0c, 0x4e12, 0x4e1f, 0x4e23, 0x4e24, 0x4e28, 0x4e2b, 0x4e2e, 0x4e2f, 0x4e30, 0x4e35,
+    0x4e40, 0x4e41, 0x4e44, 0x4e47, 0x4e51, 0x4e5a, 0x4e5c, 0x4e63, 0x4e68, 0x4e69, 0x4e74, 0x4e75, 0x4e79, 0x4e7f, 0x4e8d, 0x4e96,
+    0x4e97, 0x4e9d, 0x4eaf, 0x4eb9, 0x4ec3, 0x4ed0, 0x4eda, 0x4edb, 0x4ee0, 0x4ee1, 0x4ee2, 0x4ee8, 0x4eef, 0x4ef1, 0x4ef3, 0x4ef5,
+    0x4efd, 0x4efe, 0x4eff, 0x4f00, 0x4f02, 0x4f03, 0x4f08, 0x4f0b, 0x4f0c, 0x4f12, 0x4f15, 0x4f16, 0x4f17, 0x4f19, 0x4f2e, 0x4f31,
+    0x4f60, 0x4f33, 0x4f35, 0x4f37, 0x4f39, 0x4f3b, 0x4f3e, 0x4f40, 0x4f42, 0x4f48, 0x4f49, 0x4f4b, 0x4f4c, 0x4f52, 0x4f54, 0x4f56,
+    0x4f58, 0x4f5f, 0x4f63, 0x4f6a, 0x4f6c, 0x4f6e, 0x4f71, 0x4f77, 0x4f78, 0x4f79, 0x4f7a, 0x4f7d, 0x4f7e, 0x4f81, 0x4f82, 0x4f84,
+    0x4f85, 0x4f89, 0x4f8a, 0x4f8c, 0x4f8e, 0x4f90, 0x4f92, 0x4f93, 0x4f94, 0x4f97, 0x4f99, 0x4f9a, 0x4f9e, 0x4f9f, 0x4fb2, 0x4fb7,
+    0x4fb9, 0x4fbb, 0x4fbc, 0x4fbd, 0x4fbe, 0x4fc0, 0x4fc1, 0x4fc5, 0x4fc6, 0x4fc8, 0x4fc9, 0x4fcb, 0x4fcc, 0x4fcd, 0x4fcf, 0x4fd2,
+    0x4fdc, 0x4fe0, 0x4fe2, 0x4ff0, 0x4ff2, 0x4ffc, 0x4ffd, 0x4fff, 0x5000, 0x5001, 0x5004, 0x5007, 0x500a, 0x500c, 0x500e, 0x5010,
+    0x5013, 0x5017, 0x5018, 0x501b, 0x501c, 0x501d, 0x501e, 0x5022, 0x5027, 0x502e, 0x5030, 0x5032, 0x5033, 0x5035, 0x5040, 0x5041,
+    0x5042, 0x5045, 0x5046, 0x504a, 0x504c, 0x504e, 0x5051, 0x5052, 0x5053, 0x5057, 0x5059, 0x505f, 0x5060, 0x5062, 0x5063, 0x5066,
+    0x5067, 0x506a, 0x506d, 0x5070, 0x5071, 0x503b, 0x5081, 0x5083, 0x5084, 0x5086, 0x508a, 0x508e, 0x508f, 0x5090, 0x5092, 0x5093,
+    0x5094, 0x5096, 0x509b, 0x509c, 0x509e, 0x509f, 0x50a0, 0x50a1, 0x50a2, 0x50aa, 0x50af, 0x50b0, 0x50b9, 0x50ba, 0x50bd, 0x50c0,
+    0x50c3, 0x50c4, 0x50c7, 0x50cc, 0x50ce, 0x50d0, 0x50d3, 0x50d4, 0x50d8, 0x50dc, 0x50dd, 0x50df, 0x50e2, 0x50e4, 0x50e6, 0x50e8,
+    0x50e9, 0x50ef, 0x50f1, 0x50f6, 0x50fa, 0x50fe, 0x5103, 0x5106, 0x5107, 0x5108, 0x510b, 0x510c, 0x510d, 0x510e, 0x50f2, 0x5110,
+    0x5117, 0x5119, 0x511b, 0x511c, 0x511d, 0x511e, 0x5123, 0x5127, 0x5128, 0x512c, 0x512d, 0x512f, 0x5131, 0x5133, 0x5134, 0x5135,
+    0x5138, 0x5139, 0x5142, 0x514a, 0x514f, 0x5153, 0x5155, 0x5157, 0x5158, 0x515f, 0x5164, 0x5166, 0x517e, 0x5183, 0x5184, 0x518b,
+    0x518e, 0x5198, 0x519d, 0x51a1, 0x51a3, 0x51ad, 0x51b8, 0x51ba, 0x51bc, 0x51be, 0x51bf, 0x51c2, 0x51c8, 0x51cf, 0x51d1, 0x51d2,
+    0x51d3, 0x51d5, 0x51d8, 0x51de, 0x51e2, 0x51e5, 0x51ee, 0x51f2, 0x51f3, 0x51f4, 0x51f7, 0x5201, 0x5202, 0x5205, 0x5212, 0x5213,
+    0x5215, 0x5216, 0x5218, 0x5222, 0x5228, 0x5231, 0x5232, 0x5235, 0x523c, 0x5245, 0x5249, 0x5255, 0x5257, 0x5258, 0x525a, 0x525c,
+    0x525f, 0x5260, 0x5261, 0x5266, 0x526e, 0x5277, 0x5278, 0x5279, 0x5280, 0x5282, 0x5285, 0x528a, 0x528c, 0x5293, 0x5295, 0x5296,
+    0x5297, 0x5298, 0x529a, 0x529c, 0x52a4, 0x52a5, 0x52a6, 0x52a7, 0x52af, 0x52b0, 0x52b6, 0x52b7, 0x52b8, 0x52ba, 0x52bb, 0x52bd,
+    0x52c0, 0x52c4, 0x52c6, 0x52c8, 0x52cc, 0x52cf, 0x52d1, 0x52d4, 0x52d6, 0x52db, 0x52dc, 0x52e1, 0x52e5, 0x52e8, 0x52e9, 0x52ea,
+    0x52ec, 0x52f0, 0x52f1, 0x52f4, 0x52f6, 0x52f7, 0x5300, 0x5303, 0x530a, 0x530b, 0x530c, 0x5311, 0x5313, 0x5318, 0x531b, 0x531c,
+    0x531e, 0x531f, 0x5325, 0x5327, 0x5328, 0x5329, 0x532b, 0x532c, 0x532d, 0x5330, 0x5332, 0x5335, 0x533c, 0x533d, 0x533e, 0x5342,
+    0x534c, 0x534b, 0x5359, 0x535b, 0x5361, 0x5363, 0x5365, 0x536c, 0x536d, 0x5372, 0x5379, 0x537e, 0x5383, 0x5387, 0x5388, 0x538e,
+    0x5393, 0x5394, 0x5399, 0x539d, 0x53a1, 0x53a4, 0x53aa, 0x53ab, 0x53af, 0x53b2, 0x53b4, 0x53b5, 0x53b7, 0x53b8, 0x53ba, 0x53bd,
+    0x53c0, 0x53c5, 0x53cf, 0x53d2, 0x53d3, 0x53d5, 0x53da, 0x53dd, 0x53de, 0x53e0, 0x53e6, 0x53e7, 0x53f5, 0x5402, 0x5413, 0x541a,
+    0x5421, 0x5427, 0x5428, 0x542a, 0x542f, 0x5431, 0x5434, 0x5435, 0x5443, 0x5444, 0x5447, 0x544d, 0x544f, 0x545e, 0x5462, 0x5464,
+    0x5466, 0x5467, 0x5469, 0x546b, 0x546d, 0x546e, 0x5474, 0x547f, 0x5481, 0x5483, 0x5485, 0x5488, 0x5489, 0x548d, 0x5491, 0x5495,
+    0x5496, 0x549c, 0x549f, 0x54a1, 0x54a6, 0x54a7, 0x54a9, 0x54aa, 0x54ad, 0x54ae, 0x54b1, 0x54b7, 0x54b9, 0x54ba, 0x54bb, 0x54bf,
+    0x54c6, 0x54ca, 0x54cd, 0x54ce, 0x54e0, 0x54ea, 0x54ec, 0x54ef, 0x54f6, 0x54fc, 0x54fe, 0x54ff, 0x5500, 0x5501, 0x5505, 0x5508,
+    0x5509, 0x550c, 0x550d, 0x550e, 0x5515, 0x552a, 0x552b, 0x5532, 0x5535, 0x5536, 0x553b, 0x553c, 0x553d, 0x5541, 0x5547, 0x5549,
+    0x554a, 0x554d, 0x5550, 0x5551, 0x5558, 0x555a, 0x555b, 0x555e, 0x5560, 0x5561, 0x5564, 0x5566, 0x557f, 0x5581, 0x5582, 0x5586,
+    0x5588, 0x558e, 0x558f, 0x5591, 0x5592, 0x5593, 0x5594, 0x5597, 0x55a3, 0x55a4, 0x55ad, 0x55b2, 0x55bf, 0x55c1, 0x55c3, 0x55c6,
+    0x55c9, 0x55cb, 0x55cc, 0x55ce, 0x55d1, 0x55d2, 0x55d3, 0x55d7, 0x55d8, 0x55db, 0x55de, 0x55e2, 0x55e9, 0x55f6, 0x55ff, 0x5605,
+    0x5608, 0x560a, 0x560d, 0x560e, 0x560f, 0x5610, 0x5611, 0x5612, 0x5619, 0x562c, 0x5630, 0x5633, 0x5635, 0x5637, 0x5639, 0x563b,
+    0x563c, 0x563d, 0x563f, 0x5640, 0x5641, 0x5643, 0x5644, 0x5646, 0x5649, 0x564b, 0x564d, 0x564f, 0x5654, 0x565e, 0x5660, 0x5661,
+    0x5662, 0x5663, 0x5666, 0x5669, 0x566d, 0x566f, 0x5671, 0x5672, 0x5675, 0x5684, 0x5685, 0x5688, 0x568b, 0x568c, 0x5695, 0x5699,
+    0x569a, 0x569d, 0x569e, 0x569f, 0x56a6, 0x56a7, 0x56a8, 0x56a9, 0x56ab, 0x56ac, 0x56ad, 0x56b1, 0x56b3, 0x56b7, 0x56be, 0x56c5,
+    0x56c9, 0x56ca, 0x56cb, 0x56cf, 0x56d0, 0x56cc, 0x56cd, 0x56d9, 0x56dc, 0x56dd, 0x56df, 0x56e1, 0x56e4, 0x56e5, 0x56e6, 0x56e7,
+    0x56e8, 0x56f1, 0x56eb, 0x56ed, 0x56f6, 0x56f7, 0x5701, 0x5702, 0x5707, 0x570a, 0x570c, 0x5711, 0x5715, 0x571a, 0x571b, 0x571d,
+    0x5720, 0x5722, 0x5723, 0x5724, 0x5725, 0x5729, 0x572a, 0x572c, 0x572e, 0x572f, 0x5733, 0x5734, 0x573d, 0x573e, 0x573f, 0x5745,
+    0x5746, 0x574c, 0x574d, 0x5752, 0x5762, 0x5765, 0x5767, 0x5768, 0x576b, 0x576d, 0x576e, 0x576f, 0x5770, 0x5771, 0x5773, 0x5774,
+    0x5775, 0x5777, 0x5779, 0x577a, 0x577b, 0x577c, 0x577e, 0x5781, 0x5783, 0x578c, 0x5794, 0x5797, 0x5799, 0x579a, 0x579c, 0x579d,
+    0x579e, 0x579f, 0x57a1, 0x5795, 0x57a7, 0x57a8, 0x57a9, 0x57ac, 0x57b8, 0x57bd, 0x57c7, 0x57c8, 0x57cc, 0x57cf, 0x57d5, 0x57dd,
+    0x57de, 0x57e4, 0x57e6, 0x57e7, 0x57e9, 0x57ed, 0x57f0, 0x57f5, 0x57f6, 0x57f8, 0x57fd, 0x57fe, 0x57ff, 0x5803, 0x5804, 0x5808,
+    0x5809, 0x57e1, 0x580c, 0x580d, 0x581b, 0x581e, 0x581f, 0x5820, 0x5826, 0x5827, 0x582d, 0x5832, 0x5839, 0x583f, 0x5849, 0x584c,
+    0x584d, 0x584f, 0x5850, 0x5855, 0x585f, 0x5861, 0x5864, 0x5867, 0x5868, 0x5878, 0x587c, 0x587f, 0x5880, 0x5881, 0x5887, 0x5888,
+    0x5889, 0x588a, 0x588c, 0x588d, 0x588f, 0x5890, 0x5894, 0x5896, 0x589d, 0x58a0, 0x58a1, 0x58a2, 0x58a6, 0x58a9, 0x58b1, 0x58b2,
+    0x58c4, 0x58bc, 0x58c2, 0x58c8, 0x58cd, 0x58ce, 0x58d0, 0x58d2, 0x58d4, 0x58d6, 0x58da, 0x58dd, 0x58e1, 0x58e2, 0x58e9, 0x58f3,
+    0x5905, 0x5906, 0x590b, 0x590c, 0x5912, 0x5913, 0x5914, 0x8641, 0x591d, 0x5921, 0x5923, 0x5924, 0x5928, 0x592f, 0x5930, 0x5933,
+    0x5935, 0x5936, 0x593f, 0x5943, 0x5946, 0x5952, 0x5953, 0x5959, 0x595b, 0x595d, 0x595e, 0x595f, 0x5961, 0x5963, 0x596b, 0x596d,
+    0x596f, 0x5972, 0x5975, 0x5976, 0x5979, 0x597b, 0x597c, 0x598b, 0x598c, 0x598e, 0x5992, 0x5995, 0x5997, 0x599f, 0x59a4, 0x59a7,
+    0x59ad, 0x59ae, 0x59af, 0x59b0, 0x59b3, 0x59b7, 0x59ba, 0x59bc, 0x59c1, 0x59c3, 0x59c4, 0x59c8, 0x59ca, 0x59cd, 0x59d2, 0x59dd,
+    0x59de, 0x59df, 0x59e3, 0x59e4, 0x59e7, 0x59ee, 0x59ef, 0x59f1, 0x59f2, 0x59f4, 0x59f7, 0x5a00, 0x5a04, 0x5a0c, 0x5a0d, 0x5a0e,
+    0x5a12, 0x5a13, 0x5a1e, 0x5a23, 0x5a24, 0x5a27, 0x5a28, 0x5a2a, 0x5a2d, 0x5a30, 0x5a44, 0x5a45, 0x5a47, 0x5a48, 0x5a4c, 0x5a50,
+    0x5a55, 0x5a5e, 0x5a63, 0x5a65, 0x5a67, 0x5a6d, 0x5a77, 0x5a7a, 0x5a7b, 0x5a7e, 0x5a8b, 0x5a90, 0x5a93, 0x5a96, 0x5a99, 0x5a9c,
+    0x5a9e, 0x5a9f, 0x5aa0, 0x5aa2, 0x5aa7, 0x5aac, 0x5ab1, 0x5ab2, 0x5ab3, 0x5ab5, 0x5ab8, 0x5aba, 0x5abb, 0x5abf, 0x5ac4, 0x5ac6,
+    0x5ac8, 0x5acf, 0x5ada, 0x5adc, 0x5ae0, 0x5ae5, 0x5aea, 0x5aee, 0x5af5, 0x5af6, 0x5afd, 0x5b00, 0x5b01, 0x5b08, 0x5b17, 0x5b34,
+    0x5b19, 0x5b1b, 0x5b1d, 0x5b21, 0x5b25, 0x5b2d, 0x5b38, 0x5b41, 0x5b4b, 0x5b4c, 0x5b52, 0x5b56, 0x5b5e, 0x5b68, 0x5b6e, 0x5b6f,
+    0x5b7c, 0x5b7d, 0x5b7e, 0x5b7f, 0x5b81, 0x5b84, 0x5b86, 0x5b8a, 0x5b8e, 0x5b90, 0x5b91, 0x5b93, 0x5b94, 0x5b96, 0x5ba8, 0x5ba9,
+    0x5bac, 0x5bad, 0x5baf, 0x5bb1, 0x5bb2, 0x5bb7, 0x5bba, 0x5bbc, 0x5bc0, 0x5bc1, 0x5bcd, 0x5bcf, 0x5bd6, 0x5bd7, 0x5bd8, 0x5bd9,
+    0x5bda, 0x5be0, 0x5bef, 0x5bf1, 0x5bf4, 0x5bfd, 0x5c0c, 0x5c17, 0x5c1e, 0x5c1f, 0x5c23, 0x5c26, 0x5c29, 0x5c2b, 0x5c2c, 0x5c2e,
+    0x5c30, 0x5c32, 0x5c35, 0x5c36, 0x5c59, 0x5c5a, 0x5c5c, 0x5c62, 0x5c63, 0x5c67, 0x5c68, 0x5c69, 0x5c6d, 0x5c70, 0x5c74, 0x5c75,
+    0x5c7a, 0x5c7b, 0x5c7c, 0x5c7d, 0x5c87, 0x5c88, 0x5c8a, 0x5c8f, 0x5c92, 0x5c9d, 0x5c9f, 0x5ca0, 0x5ca2, 0x5ca3, 0x5ca6, 0x5caa,
+    0x5cb2, 0x5cb4, 0x5cb5, 0x5cba, 0x5cc9, 0x5ccb, 0x5cd2, 0x5cdd, 0x5cd7, 0x5cee, 0x5cf1, 0x5cf2, 0x5cf4, 0x5d01, 0x5d06, 0x5d0d,
+    0x5d12, 0x5d2b, 0x5d23, 0x5d24, 0x5d26, 0x5d27, 0x5d31, 0x5d34, 0x5d39, 0x5d3d, 0x5d3f, 0x5d42, 0x5d43, 0x5d46, 0x5d48, 0x5d55,
+    0x5d51, 0x5d59, 0x5d4a, 0x5d5f, 0x5d60, 0x5d61, 0x5d62, 0x5d64, 0x5d6a, 0x5d6d, 0x5d70, 0x5d79, 0x5d7a, 0x5d7e, 0x5d7f, 0x5d81,
+    0x5d83, 0x5d88, 0x5d8a, 0x5d92, 0x5d93, 0x5d94, 0x5d95, 0x5d99, 0x5d9b, 0x5d9f, 0x5da0, 0x5da7, 0x5dab, 0x5db0, 0x5db4, 0x5db8,
+    0x5db9, 0x5dc3, 0x5dc7, 0x5dcb, 0x5dd0, 0x5dce, 0x5dd8, 0x5dd9, 0x5de0, 0x5de4, 0x5de9, 0x5df8, 0x5df9, 0x5e00, 0x5e07, 0x5e0d,
+    0x5e12, 0x5e14, 0x5e15, 0x5e18, 0x5e1f, 0x5e20, 0x5e2e, 0x5e28, 0x5e32, 0x5e35, 0x5e3e, 0x5e4b, 0x5e50, 0x5e49, 0x5e51, 0x5e56,
+    0x5e58, 0x5e5b, 0x5e5c, 0x5e5e, 0x5e68, 0x5e6a, 0x5e6b, 0x5e6c, 0x5e6d, 0x5e6e, 0x5e70, 0x5e80, 0x5e8b, 0x5e8e, 0x5ea2, 0x5ea4,
+    0x5ea5, 0x5ea8, 0x5eaa, 0x5eac, 0x5eb1, 0x5eb3, 0x5ebd, 0x5ebe, 0x5ebf, 0x5ec6, 0x5ecc, 0x5ecb, 0x5ece, 0x5ed1, 0x5ed2, 0x5ed4,
+    0x5ed5, 0x5edc, 0x5ede, 0x5ee5, 0x5eeb, 0x5f02, 0x5f06, 0x5f07, 0x5f08, 0x5f0e, 0x5f19, 0x5f1c, 0x5f1d, 0x5f21, 0x5f22, 0x5f23,
+    0x5f24, 0x5f28, 0x5f2b, 0x5f2c, 0x5f2e, 0x5f30, 0x5f34, 0x5f36, 0x5f3b, 0x5f3d, 0x5f3f, 0x5f40, 0x5f44, 0x5f45, 0x5f47, 0x5f4d,
+    0x5f50, 0x5f54, 0x5f58, 0x5f5b, 0x5f60, 0x5f63, 0x5f64, 0x5f67, 0x5f6f, 0x5f72, 0x5f74, 0x5f75, 0x5f78, 0x5f7a, 0x5f7d, 0x5f7e,
+    0x5f89, 0x5f8d, 0x5f8f, 0x5f96, 0x5f9c, 0x5f9d, 0x5fa2, 0x5fa7, 0x5fab, 0x5fa4, 0x5fac, 0x5faf, 0x5fb0, 0x5fb1, 0x5fb8, 0x5fc4,
+    0x5fc7, 0x5fc8, 0x5fc9, 0x5fcb, 0x5fd0, 0x5fd1, 0x5fd2, 0x5fd3, 0x5fd4, 0x5fde, 0x5fe1, 0x5fe2, 0x5fe8, 0x5fe9, 0x5fea, 0x5fec,
+    0x5fed, 0x5fee, 0x5fef, 0x5ff2, 0x5ff3, 0x5ff6, 0x5ffa, 0x5ffc, 0x6007, 0x600a, 0x600d, 0x6013, 0x6014, 0x6017, 0x6018, 0x601a,
+    0x601f, 0x6024, 0x602d, 0x6033, 0x6035, 0x6040, 0x6047, 0x6048, 0x6049, 0x604c, 0x6051, 0x6054, 0x6056, 0x6057, 0x605d, 0x6061,
+    0x6067, 0x6071, 0x607e, 0x607f, 0x6082, 0x6086, 0x6088, 0x608a, 0x608e, 0x6091, 0x6093, 0x6095, 0x6098, 0x609d, 0x609e, 0x60a2,
+    0x60a4, 0x60a5, 0x60a8, 0x60b0, 0x60b1, 0x60b7, 0x60bb, 0x60be, 0x60c2, 0x60c4, 0x60c8, 0x60c9, 0x60ca, 0x60cb, 0x60ce, 0x60cf,
+    0x60d4, 0x60d5, 0x60d9, 0x60db, 0x60dd, 0x60de, 0x60e2, 0x60e5, 0x60f2, 0x60f5, 0x60f8, 0x60fc, 0x60fd, 0x6102, 0x6107, 0x610a,
+    0x610c, 0x6110, 0x6111, 0x6112, 0x6113, 0x6114, 0x6116, 0x6117, 0x6119, 0x611c, 0x611e, 0x6122, 0x612a, 0x612b, 0x6130, 0x6131,
+    0x6135, 0x6136, 0x6137, 0x6139, 0x6141, 0x6145, 0x6146, 0x6149, 0x615e, 0x6160, 0x616c, 0x6172, 0x6178, 0x617b, 0x617c, 0x617f,
+    0x6180, 0x6181, 0x6183, 0x6184, 0x618b, 0x618d, 0x6192, 0x6193, 0x6197, 0x6198, 0x619c, 0x619d, 0x619f, 0x61a0, 0x61a5, 0x61a8,
+    0x61aa, 0x61ad, 0x61b8, 0x61b9, 0x61bc, 0x61c0, 0x61c1, 0x61c2, 0x61ce, 0x61cf, 0x61d5, 0x61dc, 0x61dd, 0x61de, 0x61df, 0x61e1,
+    0x61e2, 0x61e7, 0x61e9, 0x61e5, 0x61ec, 0x61ed, 0x61ef, 0x6201, 0x6203, 0x6204, 0x6207, 0x6213, 0x6215, 0x621c, 0x6220, 0x6222,
+    0x6223, 0x6227, 0x6229, 0x622b, 0x6239, 0x623d, 0x6242, 0x6243, 0x6244, 0x6246, 0x624c, 0x6250, 0x6251, 0x6252, 0x6254, 0x6256,
+    0x625a, 0x625c, 0x6264, 0x626d, 0x626f, 0x6273, 0x627a, 0x627d, 0x628d, 0x628e, 0x628f, 0x6290, 0x62a6, 0x62a8, 0x62b3, 0x62b6,
+    0x62b7, 0x62ba, 0x62be, 0x62bf, 0x62c4, 0x62ce, 0x62d5, 0x62d6, 0x62da, 0x62ea, 0x62f2, 0x62f4, 0x62fc, 0x62fd, 0x6303, 0x6304,
+    0x630a, 0x630b, 0x630d, 0x6310, 0x6313, 0x6316, 0x6318, 0x6329, 0x632a, 0x632d, 0x6335, 0x6336, 0x6339, 0x633c, 0x6341, 0x6342,
+    0x6343, 0x6344, 0x6346, 0x634a, 0x634b, 0x634e, 0x6352, 0x6353, 0x6354, 0x6358, 0x635b, 0x6365, 0x6366, 0x636c, 0x636d, 0x6371,
+    0x6374, 0x6375, 0x6378, 0x637c, 0x637d, 0x637f, 0x6382, 0x6384, 0x6387, 0x638a, 0x6390, 0x6394, 0x6395, 0x6399, 0x639a, 0x639e,
+    0x63a4, 0x63a6, 0x63ad, 0x63ae, 0x63af, 0x63bd, 0x63c1, 0x63c5, 0x63c8, 0x63ce, 0x63d1, 0x63d3, 0x63d4, 0x63d5, 0x63dc, 0x63e0,
+    0x63e5, 0x63ea, 0x63ec, 0x63f2, 0x63f3, 0x63f5, 0x63f8, 0x63f9, 0x6409, 0x640a, 0x6410, 0x6412, 0x6414, 0x6418, 0x641e, 0x6420,
+    0x6422, 0x6424, 0x6425, 0x6429, 0x642a, 0x642f, 0x6430, 0x6435, 0x643d, 0x643f, 0x644b, 0x644f, 0x6451, 0x6452, 0x6453, 0x6454,
+    0x645a, 0x645b, 0x645c, 0x645d, 0x645f, 0x6460, 0x6461, 0x6463, 0x646d, 0x6473, 0x6474, 0x647b, 0x647d, 0x6485, 0x6487, 0x648f,
+    0x6490, 0x6491, 0x6498, 0x6499, 0x649b, 0x649d, 0x649f, 0x64a1, 0x64a3, 0x64a6, 0x64a8, 0x64ac, 0x64b3, 0x64bd, 0x64be, 0x64bf,
+    0x64c4, 0x64c9, 0x64ca, 0x64cb, 0x64cc, 0x64ce, 0x64d0, 0x64d1, 0x64d5, 0x64d7, 0x64e4, 0x64e5, 0x64e9, 0x64ea, 0x64ed, 0x64f0,
+    0x64f5, 0x64f7, 0x64fb, 0x64ff, 0x6501, 0x6504, 0x6508, 0x6509, 0x650a, 0x650f, 0x6513, 0x6514, 0x6516, 0x6519, 0x651b, 0x651e,
+    0x651f, 0x6522, 0x6526, 0x6529, 0x652e, 0x6531, 0x653a, 0x653c, 0x653d, 0x6543, 0x6547, 0x6549, 0x6550, 0x6552, 0x6554, 0x655f,
+    0x6560, 0x6567, 0x656b, 0x657a, 0x657d, 0x6581, 0x6585, 0x658a, 0x6592, 0x6595, 0x6598, 0x659d, 0x65a0, 0x65a3, 0x65a6, 0x65ae,
+    0x65b2, 0x65b3, 0x65b4, 0x65bf, 0x65c2, 0x65c8, 0x65c9, 0x65ce, 0x65d0, 0x65d4, 0x65d6, 0x65d8, 0x65df, 0x65f0, 0x65f2, 0x65f4,
+    0x65f5, 0x65f9, 0x65fe, 0x65ff, 0x6600, 0x6604, 0x6608, 0x6609, 0x660d, 0x6611, 0x6612, 0x6615, 0x6616, 0x661d, 0x661e, 0x6621,
+    0x6622, 0x6623, 0x6624, 0x6626, 0x6629, 0x662a, 0x662b, 0x662c, 0x662e, 0x6630, 0x6631, 0x6633, 0x6639, 0x6637, 0x6640, 0x6645,
+    0x6646, 0x664a, 0x664c, 0x6651, 0x664e, 0x6657, 0x6658, 0x6659, 0x665b, 0x665c, 0x6660, 0x6661, 0x66fb, 0x666a, 0x666b, 0x666c,
+    0x667e, 0x6673, 0x6675, 0x667f, 0x6677, 0x6678, 0x6679, 0x667b, 0x6680, 0x667c, 0x668b, 0x668c, 0x668d, 0x6690, 0x6692, 0x6699,
+    0x669a, 0x669b, 0x669c, 0x669f, 0x66a0, 0x66a4, 0x66ad, 0x66b1, 0x66b2, 0x66b5, 0x66bb, 0x66bf, 0x66c0, 0x66c2, 0x66c3, 0x66c8,
+    0x66cc, 0x66ce, 0x66cf, 0x66d4, 0x66db, 0x66df, 0x66e8, 0x66eb, 0x66ec, 0x66ee, 0x66fa, 0x6705, 0x6707, 0x670e, 0x6713, 0x6719,
+    0x671c, 0x6720, 0x6722, 0x6733, 0x673e, 0x6745, 0x6747, 0x6748, 0x674c, 0x6754, 0x6755, 0x675d, 0x6766, 0x676c, 0x676e, 0x6774,
+    0x6776, 0x677b, 0x6781, 0x6784, 0x678e, 0x678f, 0x6791, 0x6793, 0x6796, 0x6798, 0x6799, 0x679b, 0x67b0, 0x67b1, 0x67b2, 0x67b5,
+    0x67bb, 0x67bc, 0x67bd, 0x67f9, 0x67c0, 0x67c2, 0x67c3, 0x67c5, 0x67c8, 0x67c9, 0x67d2, 0x67d7, 0x67d9, 0x67dc, 0x67e1, 0x67e6,
+    0x67f0, 0x67f2, 0x67f6, 0x67f7, 0x6852, 0x6814, 0x6819, 0x681d, 0x681f, 0x6828, 0x6827, 0x682c, 0x682d, 0x682f, 0x6830, 0x6831,
+    0x6833, 0x683b, 0x683f, 0x6844, 0x6845, 0x684a, 0x684c, 0x6855, 0x6857, 0x6858, 0x685b, 0x686b, 0x686e, 0x686f, 0x6870, 0x6871,
+    0x6872, 0x6875, 0x6879, 0x687a, 0x687b, 0x687c, 0x6882, 0x6884, 0x6886, 0x6888, 0x6896, 0x6898, 0x689a, 0x689c, 0x68a1, 0x68a3,
+    0x68a5, 0x68a9, 0x68aa, 0x68ae, 0x68b2, 0x68bb, 0x68c5, 0x68c8, 0x68cc, 0x68cf, 0x68d0, 0x68d1, 0x68d3, 0x68d6, 0x68d9, 0x68dc,
+    0x68dd, 0x68e5, 0x68e8, 0x68ea, 0x68eb, 0x68ec, 0x68ed, 0x68f0, 0x68f1, 0x68f5, 0x68f6, 0x68fb, 0x68fc, 0x68fd, 0x6906, 0x6909,
+    0x690a, 0x6910, 0x6911, 0x6913, 0x6916, 0x6917, 0x6931, 0x6933, 0x6935, 0x6938, 0x693b, 0x6942, 0x6945, 0x6949, 0x694e, 0x6957,
+    0x695b, 0x6963, 0x6964, 0x6965, 0x6966, 0x6968, 0x6969, 0x696c, 0x6970, 0x6971, 0x6972, 0x697a, 0x697b, 0x697f, 0x6980, 0x698d,
+    0x6992, 0x6996, 0x6998, 0x69a1, 0x69a5, 0x69a6, 0x69a8, 0x69ab, 0x69ad, 0x69af, 0x69b7, 0x69b8, 0x69ba, 0x69bc, 0x69c5, 0x69c8,
+    0x69d1, 0x69d6, 0x69d7, 0x69e2, 0x69e5, 0x69ee, 0x69ef, 0x69f1, 0x69f3, 0x69f5, 0x69fe, 0x6a00, 0x6a01, 0x6a03, 0x6a0f, 0x6a11,
+    0x6a15, 0x6a1a, 0x6a1d, 0x6a20, 0x6a24, 0x6a28, 0x6a30, 0x6a32, 0x6a34, 0x6a37, 0x6a3b, 0x6a3e, 0x6a3f, 0x6a45, 0x6a46, 0x6a49,
+    0x6a4a, 0x6a4e, 0x6a50, 0x6a51, 0x6a52, 0x6a55, 0x6a56, 0x6a5b, 0x6a64, 0x6a67, 0x6a6a, 0x6a71, 0x6a73, 0x6a7e, 0x6a81, 0x6a83,
+    0x6a86, 0x6a87, 0x6a89, 0x6a8b, 0x6a91, 0x6a9b, 0x6a9d, 0x6a9e, 0x6a9f, 0x6aa5, 0x6aab, 0x6aaf, 0x6ab0, 0x6ab1, 0x6ab4, 0x6abd,
+    0x6abe, 0x6abf, 0x6ac6, 0x6ac9, 0x6ac8, 0x6acc, 0x6ad0, 0x6ad4, 0x6ad5, 0x6ad6, 0x6adc, 0x6add, 0x6ae4, 0x6ae7, 0x6aec, 0x6af0,
+    0x6af1, 0x6af2, 0x6afc, 0x6afd, 0x6b02, 0x6b03, 0x6b06, 0x6b07, 0x6b09, 0x6b0f, 0x6b10, 0x6b11, 0x6b17, 0x6b1b, 0x6b1e, 0x6b24,
+    0x6b28, 0x6b2b, 0x6b2c, 0x6b2f, 0x6b35, 0x6b36, 0x6b3b, 0x6b3f, 0x6b46, 0x6b4a, 0x6b4d, 0x6b52, 0x6b56, 0x6b58, 0x6b5d, 0x6b60,
+    0x6b67, 0x6b6b, 0x6b6e, 0x6b70, 0x6b75, 0x6b7d, 0x6b7e, 0x6b82, 0x6b85, 0x6b97, 0x6b9b, 0x6b9f, 0x6ba0, 0x6ba2, 0x6ba3, 0x6ba8,
+    0x6ba9, 0x6bac, 0x6bad, 0x6bae, 0x6bb0, 0x6bb8, 0x6bb9, 0x6bbd, 0x6bbe, 0x6bc3, 0x6bc4, 0x6bc9, 0x6bcc, 0x6bd6, 0x6bda, 0x6be1,
+    0x6be3, 0x6be6, 0x6be7, 0x6bee, 0x6bf1, 0x6bf7, 0x6bf9, 0x6bff, 0x6c02, 0x6c04, 0x6c05, 0x6c09, 0x6c0d, 0x6c0e, 0x6c10, 0x6c12,
+    0x6c19, 0x6c1f, 0x6c26, 0x6c27, 0x6c28, 0x6c2c, 0x6c2e, 0x6c33, 0x6c35, 0x6c36, 0x6c3a, 0x6c3b, 0x6c3f, 0x6c4a, 0x6c4b, 0x6c4d,
+    0x6c4f, 0x6c52, 0x6c54, 0x6c59, 0x6c5b, 0x6c5c, 0x6c6b, 0x6c6d, 0x6c6f, 0x6c74, 0x6c76, 0x6c78, 0x6c79, 0x6c7b, 0x6c85, 0x6c86,
+    0x6c87, 0x6c89, 0x6c94, 0x6c95, 0x6c97, 0x6c98, 0x6c9c, 0x6c9f, 0x6cb0, 0x6cb2, 0x6cb4, 0x6cc2, 0x6cc6, 0x6ccd, 0x6ccf, 0x6cd0,
+    0x6cd1, 0x6cd2, 0x6cd4, 0x6cd6, 0x6cda, 0x6cdc, 0x6ce0, 0x6ce7, 0x6ce9, 0x6ceb, 0x6cec, 0x6cee, 0x6cf2, 0x6cf4, 0x6d04, 0x6d07,
+    0x6d0a, 0x6d0e, 0x6d0f, 0x6d11, 0x6d13, 0x6d1a, 0x6d26, 0x6d27, 0x6d28, 0x6c67, 0x6d2e, 0x6d2f, 0x6d31, 0x6d39, 0x6d3c, 0x6d3f,
+    0x6d57, 0x6d5e, 0x6d5f, 0x6d61, 0x6d65, 0x6d67, 0x6d6f, 0x6d70, 0x6d7c, 0x6d82, 0x6d87, 0x6d91, 0x6d92, 0x6d94, 0x6d96, 0x6d97,
+    0x6d98, 0x6daa, 0x6dac, 0x6db4, 0x6db7, 0x6db9, 0x6dbd, 0x6dbf, 0x6dc4, 0x6dc8, 0x6dca, 0x6dce, 0x6dcf, 0x6dd6, 0x6ddb, 0x6ddd,
+    0x6ddf, 0x6de0, 0x6de2, 0x6de5, 0x6de9, 0x6def, 0x6df0, 0x6df4, 0x6df6, 0x6dfc, 0x6e00, 0x6e04, 0x6e1e, 0x6e22, 0x6e27, 0x6e32,
+    0x6e36, 0x6e39, 0x6e3b, 0x6e3c, 0x6e44, 0x6e45, 0x6e48, 0x6e49, 0x6e4b, 0x6e4f, 0x6e51, 0x6e52, 0x6e53, 0x6e54, 0x6e57, 0x6e5c,
+    0x6e5d, 0x6e5e, 0x6e62, 0x6e63, 0x6e68, 0x6e73, 0x6e7b, 0x6e7d, 0x6e8d, 0x6e93, 0x6e99, 0x6ea0, 0x6ea7, 0x6ead, 0x6eae, 0x6eb1,
+    0x6eb3, 0x6ebb, 0x6ebf, 0x6ec0, 0x6ec1, 0x6ec3, 0x6ec7, 0x6ec8, 0x6eca, 0x6ecd, 0x6ece, 0x6ecf, 0x6eeb, 0x6eed, 0x6eee, 0x6ef9,
+    0x6efb, 0x6efd, 0x6f04, 0x6f08, 0x6f0a, 0x6f0c, 0x6f0d, 0x6f16, 0x6f18, 0x6f1a, 0x6f1b, 0x6f26, 0x6f29, 0x6f2a, 0x6f2f, 0x6f30,
+    0x6f33, 0x6f36, 0x6f3b, 0x6f3c, 0x6f2d, 0x6f4f, 0x6f51, 0x6f52, 0x6f53, 0x6f57, 0x6f59, 0x6f5a, 0x6f5d, 0x6f5e, 0x6f61, 0x6f62,
+    0x6f68, 0x6f6c, 0x6f7d, 0x6f7e, 0x6f83, 0x6f87, 0x6f88, 0x6f8b, 0x6f8c, 0x6f8d, 0x6f90, 0x6f92, 0x6f93, 0x6f94, 0x6f96, 0x6f9a,
+    0x6f9f, 0x6fa0, 0x6fa5, 0x6fa6, 0x6fa7, 0x6fa8, 0x6fae, 0x6faf, 0x6fb0, 0x6fb5, 0x6fb6, 0x6fbc, 0x6fc5, 0x6fc7, 0x6fc8, 0x6fca,
+    0x6fda, 0x6fde, 0x6fe8, 0x6fe9, 0x6ff0, 0x6ff5, 0x6ff9, 0x6ffc, 0x6ffd, 0x7000, 0x7005, 0x7006, 0x7007, 0x700d, 0x7017, 0x7020,
+    0x7023, 0x702f, 0x7034, 0x7037, 0x7039, 0x703c, 0x7043, 0x7044, 0x7048, 0x7049, 0x704a, 0x704b, 0x7054, 0x7055, 0x705d, 0x705e,
+    0x704e, 0x7064, 0x7065, 0x706c, 0x706e, 0x7075, 0x7076, 0x707e, 0x7081, 0x7085, 0x7086, 0x7094, 0x7095, 0x7096, 0x7097, 0x7098,
+    0x709b, 0x70a4, 0x70ab, 0x70b0, 0x70b1, 0x70b4, 0x70b7, 0x70ca, 0x70d1, 0x70d3, 0x70d4, 0x70d5, 0x70d6, 0x70d8, 0x70dc, 0x70e4,
+    0x70fa, 0x7103, 0x7104, 0x7105, 0x7106, 0x7107, 0x710b, 0x710c, 0x710f, 0x711e, 0x7120, 0x712b, 0x712d, 0x712f, 0x7130, 0x7131,
+    0x7138, 0x7141, 0x7145, 0x7146, 0x7147, 0x714a, 0x714b, 0x7150, 0x7152, 0x7157, 0x715a, 0x715c, 0x715e, 0x7160, 0x7168, 0x7179,
+    0x7180, 0x7185, 0x7187, 0x718c, 0x7192, 0x719a, 0x719b, 0x71a0, 0x71a2, 0x71af, 0x71b0, 0x71b2, 0x71b3, 0x71ba, 0x71bf, 0x71c0,
+    0x71c1, 0x71c4, 0x71cb, 0x71cc, 0x71d3, 0x71d6, 0x71d9, 0x71da, 0x71dc, 0x71f8, 0x71fe, 0x7200, 0x7207, 0x7208, 0x7209, 0x7213,
+    0x7217, 0x721a, 0x721d, 0x721f, 0x7224, 0x722b, 0x722f, 0x7234, 0x7238, 0x7239, 0x7241, 0x7242, 0x7243, 0x7245, 0x724e, 0x724f,
+    0x7250, 0x7253, 0x7255, 0x7256, 0x725a, 0x725c, 0x725e, 0x7260, 0x7263, 0x7268, 0x726b, 0x726e, 0x726f, 0x7271, 0x7277, 0x7278,
+    0x727b, 0x727c, 0x727f, 0x7284, 0x7289, 0x728d, 0x728e, 0x7293, 0x729b, 0x72a8, 0x72ad, 0x72ae, 0x72b1, 0x72b4, 0x72be, 0x72c1,
+    0x72c7, 0x72c9, 0x72cc, 0x72d5, 0x72d6, 0x72d8, 0x72df, 0x72e5, 0x72f3, 0x72f4, 0x72fa, 0x72fb, 0x72fe, 0x7302, 0x7304, 0x7305,
+    0x7307, 0x730b, 0x730d, 0x7312, 0x7313, 0x7318, 0x7319, 0x731e, 0x7322, 0x7324, 0x7327, 0x7328, 0x732c, 0x7331, 0x7332, 0x7335,
+    0x733a, 0x733b, 0x733d, 0x7343, 0x734d, 0x7350, 0x7352, 0x7356, 0x7358, 0x735d, 0x735e, 0x735f, 0x7360, 0x7366, 0x7367, 0x7369,
+    0x736b, 0x736c, 0x736e, 0x736f, 0x7371, 0x7377, 0x7379, 0x737c, 0x7380, 0x7381, 0x7383, 0x7385, 0x7386, 0x738e, 0x7390, 0x7393,
+    0x7395, 0x7397, 0x7398, 0x739c, 0x739e, 0x739f, 0x73a0, 0x73a2, 0x73a5, 0x73a6, 0x73aa, 0x73ab, 0x73ad, 0x73b5, 0x73b7, 0x73b9,
+    0x73bc, 0x73bd, 0x73bf, 0x73c5, 0x73c6, 0x73c9, 0x73cb, 0x73cc, 0x73cf, 0x73d2, 0x73d3, 0x73d6, 0x73d9, 0x73dd, 0x73e1, 0x73e3,
+    0x73e6, 0x73e7, 0x73e9, 0x73f4, 0x73f5, 0x73f7, 0x73f9, 0x73fa, 0x73fb, 0x73fd, 0x73ff, 0x7400, 0x7401, 0x7404, 0x7407, 0x740a,
+    0x7411, 0x741a, 0x741b, 0x7424, 0x7426, 0x7428, 0x7429, 0x742a, 0x742b, 0x742c, 0x742d, 0x742e, 0x742f, 0x7430, 0x7431, 0x7439,
+    0x7440, 0x7443, 0x7444, 0x7446, 0x7447, 0x744b, 0x744d, 0x7451, 0x7452, 0x7457, 0x745d, 0x7462, 0x7466, 0x7467, 0x7468, 0x746b,
+    0x746d, 0x746e, 0x7471, 0x7472, 0x7480, 0x7481, 0x7485, 0x7486, 0x7487, 0x7489, 0x748f, 0x7490, 0x7491, 0x7492, 0x7498, 0x7499,
+    0x749a, 0x749c, 0x749f, 0x74a0, 0x74a1, 0x74a3, 0x74a6, 0x74a8, 0x74a9, 0x74aa, 0x74ab, 0x74ae, 0x74af, 0x74b1, 0x74b2, 0x74b5,
+    0x74b9, 0x74bb, 0x74bf, 0x74c8, 0x74c9, 0x74cc, 0x74d0, 0x74d3, 0x74d8, 0x74da, 0x74db, 0x74de, 0x74df, 0x74e4, 0x74e8, 0x74ea,
+    0x74eb, 0x74ef, 0x74f4, 0x74fa, 0x74fb, 0x74fc, 0x74ff, 0x7506, 0x7512, 0x7516, 0x7517, 0x7520, 0x7521, 0x7524, 0x7527, 0x7529,
+    0x752a, 0x752f, 0x7536, 0x7539, 0x753d, 0x753e, 0x753f, 0x7540, 0x7543, 0x7547, 0x7548, 0x754e, 0x7550, 0x7552, 0x7557, 0x755e,
+    0x755f, 0x7561, 0x756f, 0x7571, 0x7579, 0x757a, 0x757b, 0x757c, 0x757d, 0x757e, 0x7581, 0x7585, 0x7590, 0x7592, 0x7593, 0x7595,
+    0x7599, 0x759c, 0x75a2, 0x75a4, 0x75b4, 0x75ba, 0x75bf, 0x75c0, 0x75c1, 0x75c4, 0x75c6, 0x75cc, 0x75ce, 0x75cf, 0x75d7, 0x75dc,
+    0x75df, 0x75e0, 0x75e1, 0x75e4, 0x75e7, 0x75ec, 0x75ee, 0x75ef, 0x75f1, 0x75f9, 0x7600, 0x7602, 0x7603, 0x7604, 0x7607, 0x7608,
+    0x760a, 0x760c, 0x760f, 0x7612, 0x7613, 0x7615, 0x7616, 0x7619, 0x761b, 0x761c, 0x761d, 0x761e, 0x7623, 0x7625, 0x7626, 0x7629,
+    0x762d, 0x7632, 0x7633, 0x7635, 0x7638, 0x7639, 0x763a, 0x763c, 0x764a, 0x7640, 0x7641, 0x7643, 0x7644, 0x7645, 0x7649, 0x764b,
+    0x7655, 0x7659, 0x765f, 0x7664, 0x7665, 0x766d, 0x766e, 0x766f, 0x7671, 0x7674, 0x7681, 0x7685, 0x768c, 0x768d, 0x7695, 0x769b,
+    0x769c, 0x769d, 0x769f, 0x76a0, 0x76a2, 0x76a3, 0x76a4, 0x76a5, 0x76a6, 0x76a7, 0x76a8, 0x76aa, 0x76ad, 0x76bd, 0x76c1, 0x76c5,
+    0x76c9, 0x76cb, 0x76cc, 0x76ce, 0x76d4, 0x76d9, 0x76e0, 0x76e6, 0x76e8, 0x76ec, 0x76f0, 0x76f1, 0x76f6, 0x76f9, 0x76fc, 0x7700,
+    0x7706, 0x770a, 0x770e, 0x7712, 0x7714, 0x7715, 0x7717, 0x7719, 0x771a, 0x771c, 0x7722, 0x7728, 0x772d, 0x772e, 0x772f, 0x7734,
+    0x7735, 0x7736, 0x7739, 0x773d, 0x773e, 0x7742, 0x7745, 0x7746, 0x774a, 0x774d, 0x774e, 0x774f, 0x7752, 0x7756, 0x7757, 0x775c,
+    0x775e, 0x775f, 0x7760, 0x7762, 0x7764, 0x7767, 0x776a, 0x776c, 0x7770, 0x7772, 0x7773, 0x7774, 0x777a, 0x777d, 0x7780, 0x7784,
+    0x778c, 0x778d, 0x7794, 0x7795, 0x7796, 0x779a, 0x779f, 0x77a2, 0x77a7, 0x77aa, 0x77ae, 0x77af, 0x77b1, 0x77b5, 0x77be, 0x77c3,
+    0x77c9, 0x77d1, 0x77d2, 0x77d5, 0x77d9, 0x77de, 0x77df, 0x77e0, 0x77e4, 0x77e6, 0x77ea, 0x77ec, 0x77f0, 0x77f1, 0x77f4, 0x77f8,
+    0x77fb, 0x7805, 0x7806, 0x7809, 0x780d, 0x780e, 0x7811, 0x781d, 0x7821, 0x7822, 0x7823, 0x782d, 0x782e, 0x7830, 0x7835, 0x7837,
+    0x7843, 0x7844, 0x7847, 0x7848, 0x784c, 0x784e, 0x7852, 0x785c, 0x785e, 0x7860, 0x7861, 0x7863, 0x7864, 0x7868, 0x786a, 0x786e,
+    0x787a, 0x787e, 0x788a, 0x788f, 0x7894, 0x7898, 0x78a1, 0x789d, 0x789e, 0x789f, 0x78a4, 0x78a8, 0x78ac, 0x78ad, 0x78b0, 0x78b1,
+    0x78b2, 0x78b3, 0x78bb, 0x78bd, 0x78bf, 0x78c7, 0x78c8, 0x78c9, 0x78cc, 0x78ce, 0x78d2, 0x78d3, 0x78d5, 0x78d6, 0x78e4, 0x78db,
+    0x78df, 0x78e0, 0x78e1, 0x78e6, 0x78ea, 0x78f2, 0x78f3, 0x7900, 0x78f6, 0x78f7, 0x78fa, 0x78fb, 0x78ff, 0x7906, 0x790c, 0x7910,
+    0x791a, 0x791c, 0x791e, 0x791f, 0x7920, 0x7925, 0x7927, 0x7929, 0x792d, 0x7931, 0x7934, 0x7935, 0x793b, 0x793d, 0x793f, 0x7944,
+    0x7945, 0x7946, 0x794a, 0x794b, 0x794f, 0x7951, 0x7954, 0x7958, 0x795b, 0x795c, 0x7967, 0x7969, 0x796b, 0x7972, 0x7979, 0x797b,
+    0x797c, 0x797e, 0x798b, 0x798c, 0x7991, 0x7993, 0x7994, 0x7995, 0x7996, 0x7998, 0x799b, 0x799c, 0x79a1, 0x79a8, 0x79a9, 0x79ab,
+    0x79af, 0x79b1, 0x79b4, 0x79b8, 0x79bb, 0x79c2, 0x79c4, 0x79c7, 0x79c8, 0x79ca, 0x79cf, 0x79d4, 0x79d6, 0x79da, 0x79dd, 0x79de,
+    0x79e0, 0x79e2, 0x79e5, 0x79ea, 0x79eb, 0x79ed, 0x79f1, 0x79f8, 0x79fc, 0x7a02, 0x7a03, 0x7a07, 0x7a09, 0x7a0a, 0x7a0c, 0x7a11,
+    0x7a15, 0x7a1b, 0x7a1e, 0x7a21, 0x7a27, 0x7a2b, 0x7a2d, 0x7a2f, 0x7a30, 0x7a34, 0x7a35, 0x7a38, 0x7a39, 0x7a3a, 0x7a44, 0x7a45,
+    0x7a47, 0x7a48, 0x7a4c, 0x7a55, 0x7a56, 0x7a59, 0x7a5c, 0x7a5d, 0x7a5f, 0x7a60, 0x7a65, 0x7a67, 0x7a6a, 0x7a6d, 0x7a75, 0x7a78,
+    0x7a7e, 0x7a80, 0x7a82, 0x7a85, 0x7a86, 0x7a8a, 0x7a8b, 0x7a90, 0x7a91, 0x7a94, 0x7a9e, 0x7aa0, 0x7aa3, 0x7aac, 0x7ab3, 0x7ab5,
+    0x7ab9, 0x7abb, 0x7abc, 0x7ac6, 0x7ac9, 0x7acc, 0x7ace, 0x7ad1, 0x7adb, 0x7ae8, 0x7ae9, 0x7aeb, 0x7aec, 0x7af1, 0x7af4, 0x7afb,
+    0x7afd, 0x7afe, 0x7b07, 0x7b14, 0x7b1f, 0x7b23, 0x7b27, 0x7b29, 0x7b2a, 0x7b2b, 0x7b2d, 0x7b2e, 0x7b2f, 0x7b30, 0x7b31, 0x7b34,
+    0x7b3d, 0x7b3f, 0x7b40, 0x7b41, 0x7b47, 0x7b4e, 0x7b55, 0x7b60, 0x7b64, 0x7b66, 0x7b69, 0x7b6a, 0x7b6d, 0x7b6f, 0x7b72, 0x7b73,
+    0x7b77, 0x7b84, 0x7b89, 0x7b8e, 0x7b90, 0x7b91, 0x7b96, 0x7b9b, 0x7b9e, 0x7ba0, 0x7ba5, 0x7bac, 0x7baf, 0x7bb0, 0x7bb2, 0x7bb5,
+    0x7bb6, 0x7bba, 0x7bbb, 0x7bbc, 0x7bbd, 0x7bc2, 0x7bc5, 0x7bc8, 0x7bca, 0x7bd4, 0x7bd6, 0x7bd7, 0x7bd9, 0x7bda, 0x7bdb, 0x7be8,
+    0x7bea, 0x7bf2, 0x7bf4, 0x7bf5, 0x7bf8, 0x7bf9, 0x7bfa, 0x7bfc, 0x7bfe, 0x7c01, 0x7c02, 0x7c03, 0x7c04, 0x7c06, 0x7c09, 0x7c0b,
+    0x7c0c, 0x7c0e, 0x7c0f, 0x7c19, 0x7c1b, 0x7c20, 0x7c25, 0x7c26, 0x7c28, 0x7c2c, 0x7c31, 0x7c33, 0x7c34, 0x7c36, 0x7c39, 0x7c3a,
+    0x7c46, 0x7c4a, 0x7c55, 0x7c51, 0x7c52, 0x7c53, 0x7c59, 0x7c5a, 0x7c5b, 0x7c5c, 0x7c5d, 0x7c5e, 0x7c61, 0x7c63, 0x7c67, 0x7c69,
+    0x7c6d, 0x7c6e, 0x7c70, 0x7c72, 0x7c79, 0x7c7c, 0x7c7d, 0x7c86, 0x7c87, 0x7c8f, 0x7c94, 0x7c9e, 0x7ca0, 0x7ca6, 0x7cb0, 0x7cb6,
+    0x7cb7, 0x7cba, 0x7cbb, 0x7cbc, 0x7cbf, 0x7cc4, 0x7cc7, 0x7cc8, 0x7cc9, 0x7ccd, 0x7ccf, 0x7cd3, 0x7cd4, 0x7cd5, 0x7cd7, 0x7cd9,
+    0x7cda, 0x7cdd, 0x7ce6, 0x7ce9, 0x7ceb, 0x7cf5, 0x7d03, 0x7d07, 0x7d08, 0x7d09, 0x7d0f, 0x7d11, 0x7d12, 0x7d13, 0x7d16, 0x7d1d,
+    0x7d1e, 0x7d23, 0x7d26, 0x7d2a, 0x7d2d, 0x7d31, 0x7d3c, 0x7d3d, 0x7d3e, 0x7d40, 0x7d41, 0x7d47, 0x7d48, 0x7d4d, 0x7d51, 0x7d53,
+    0x7d57, 0x7d59, 0x7d5a, 0x7d5c, 0x7d5d, 0x7d65, 0x7d67, 0x7d6a, 0x7d70, 0x7d78, 0x7d7a, 0x7d7b, 0x7d7f, 0x7d81, 0x7d82, 0x7d83,
+    0x7d85, 0x7d86, 0x7d88, 0x7d8b, 0x7d8c, 0x7d8d, 0x7d91, 0x7d96, 0x7d97, 0x7d9d, 0x7d9e, 0x7da6, 0x7da7, 0x7daa, 0x7db3, 0x7db6,
+    0x7db7, 0x7db9, 0x7dc2, 0x7dc3, 0x7dc4, 0x7dc5, 0x7dc6, 0x7dcc, 0x7dcd, 0x7dce, 0x7dd7, 0x7dd9, 0x7e00, 0x7de2, 0x7de5, 0x7de6,
+    0x7dea, 0x7deb, 0x7ded, 0x7df1, 0x7df5, 0x7df6, 0x7df9, 0x7dfa, 0x7e08, 0x7e10, 0x7e11, 0x7e15, 0x7e17, 0x7e1c, 0x7e1d, 0x7e20,
+    0x7e27, 0x7e28, 0x7e2c, 0x7e2d, 0x7e2f, 0x7e33, 0x7e36, 0x7e3f, 0x7e44, 0x7e45, 0x7e47, 0x7e4e, 0x7e50, 0x7e52, 0x7e58, 0x7e5f,
+    0x7e61, 0x7e62, 0x7e65, 0x7e6b, 0x7e6e, 0x7e6f, 0x7e73, 0x7e78, 0x7e7e, 0x7e81, 0x7e86, 0x7e87, 0x7e8a, 0x7e8d, 0x7e91, 0x7e95,
+    0x7e98, 0x7e9a, 0x7e9d, 0x7e9e, 0x7f3c, 0x7f3b, 0x7f3d, 0x7f3e, 0x7f3f, 0x7f43, 0x7f44, 0x7f47, 0x7f4f, 0x7f52, 0x7f53, 0x7f5b,
+    0x7f5c, 0x7f5d, 0x7f61, 0x7f63, 0x7f64, 0x7f65, 0x7f66, 0x7f6d, 0x7f71, 0x7f7d, 0x7f7e, 0x7f7f, 0x7f80, 0x7f8b, 0x7f8d, 0x7f8f,
+    0x7f90, 0x7f91, 0x7f96, 0x7f97, 0x7f9c, 0x7fa1, 0x7fa2, 0x7fa6, 0x7faa, 0x7fad, 0x7fb4, 0x7fbc, 0x7fbf, 0x7fc0, 0x7fc3, 0x7fc8,
+    0x7fce, 0x7fcf, 0x7fdb, 0x7fdf, 0x7fe3, 0x7fe5, 0x7fe8, 0x7fec, 0x7fee, 0x7fef, 0x7ff2, 0x7ffa, 0x7ffd, 0x7ffe, 0x7fff, 0x8007,
+    0x8008, 0x800a, 0x800d, 0x800e, 0x800f, 0x8011, 0x8013, 0x8014, 0x8016, 0x801d, 0x801e, 0x801f, 0x8020, 0x8024, 0x8026, 0x802c,
+    0x802e, 0x8030, 0x8034, 0x8035, 0x8037, 0x8039, 0x803a, 0x803c, 0x803e, 0x8040, 0x8044, 0x8060, 0x8064, 0x8066, 0x806d, 0x8071,
+    0x8075, 0x8081, 0x8088, 0x808e, 0x809c, 0x809e, 0x80a6, 0x80a7, 0x80ab, 0x80b8, 0x80b9, 0x80c8, 0x80cd, 0x80cf, 0x80d2, 0x80d4,
+    0x80d5, 0x80d7, 0x80d8, 0x80e0, 0x80ed, 0x80ee, 0x80f0, 0x80f2, 0x80f3, 0x80f6, 0x80f9, 0x80fa, 0x80fe, 0x8103, 0x810b, 0x8116,
+    0x8117, 0x8118, 0x811c, 0x811e, 0x8120, 0x8124, 0x8127, 0x812c, 0x8130, 0x8135, 0x813a, 0x813c, 0x8145, 0x8147, 0x814a, 0x814c,
+    0x8152, 0x8157, 0x8160, 0x8161, 0x8167, 0x8168, 0x8169, 0x816d, 0x816f, 0x8177, 0x8181, 0x8190, 0x8184, 0x8185, 0x8186, 0x818b,
+    0x818e, 0x8196, 0x8198, 0x819b, 0x819e, 0x81a2, 0x81ae, 0x81b2, 0x81b4, 0x81bb, 0x81cb, 0x81c3, 0x81c5, 0x81ca, 0x81ce, 0x81cf,
+    0x81d5, 0x81d7, 0x81db, 0x81dd, 0x81de, 0x81e1, 0x81e4, 0x81eb, 0x81ec, 0x81f0, 0x81f1, 0x81f2, 0x81f5, 0x81f6, 0x81f8, 0x81f9,
+    0x81fd, 0x81ff, 0x8200, 0x8203, 0x820f, 0x8213, 0x8214, 0x8219, 0x821a, 0x821d, 0x8221, 0x8222, 0x8228, 0x8232, 0x8234, 0x823a,
+    0x8243, 0x8244, 0x8245, 0x8246, 0x824b, 0x824e, 0x824f, 0x8251, 0x8256, 0x825c, 0x8260, 0x8263, 0x8267, 0x826d, 0x8274, 0x827b,
+    0x827d, 0x827f, 0x8280, 0x8281, 0x8283, 0x8284, 0x8287, 0x8289, 0x828a, 0x828e, 0x8291, 0x8294, 0x8296, 0x8298, 0x829a, 0x829b,
+    0x82a0, 0x82a1, 0x82a3, 0x82a4, 0x82a7, 0x82a8, 0x82a9, 0x82aa, 0x82ae, 0x82b0, 0x82b2, 0x82b4, 0x82b7, 0x82ba, 0x82bc, 0x82be,
+    0x82bf, 0x82c6, 0x82d0, 0x82d5, 0x82da, 0x82e0, 0x82e2, 0x82e4, 0x82e8, 0x82ea, 0x82ed, 0x82ef, 0x82f6, 0x82f7, 0x82fd, 0x82fe,
+    0x8300, 0x8301, 0x8307, 0x8308, 0x830a, 0x830b, 0x8354, 0x831b, 0x831d, 0x831e, 0x831f, 0x8321, 0x8322, 0x832c, 0x832d, 0x832e,
+    0x8330, 0x8333, 0x8337, 0x833a, 0x833c, 0x833d, 0x8342, 0x8343, 0x8344, 0x8347, 0x834d, 0x834e, 0x8351, 0x8355, 0x8356, 0x8357,
+    0x8370, 0x8378, 0x837d, 0x837f, 0x8380, 0x8382, 0x8384, 0x8386, 0x838d, 0x8392, 0x8394, 0x8395, 0x8398, 0x8399, 0x839b, 0x839c,
+    0x839d, 0x83a6, 0x83a7, 0x83a9, 0x83ac, 0x83be, 0x83bf, 0x83c0, 0x83c7, 0x83c9, 0x83cf, 0x83d0, 0x83d1, 0x83d4, 0x83dd, 0x8353,
+    0x83e8, 0x83ea, 0x83f6, 0x83f8, 0x83f9, 0x83fc, 0x8401, 0x8406, 0x840a, 0x840f, 0x8411, 0x8415, 0x8419, 0x83ad, 0x842f, 0x8439,
+    0x8445, 0x8447, 0x8448, 0x844a, 0x844d, 0x844f, 0x8451, 0x8452, 0x8456, 0x8458, 0x8459, 0x845a, 0x845c, 0x8460, 0x8464, 0x8465,
+    0x8467, 0x846a, 0x8470, 0x8473, 0x8474, 0x8476, 0x8478, 0x847c, 0x847d, 0x8481, 0x8485, 0x8492, 0x8493, 0x8495, 0x849e, 0x84a6,
+    0x84a8, 0x84a9, 0x84aa, 0x84af, 0x84b1, 0x84b4, 0x84ba, 0x84bd, 0x84be, 0x84c0, 0x84c2, 0x84c7, 0x84c8, 0x84cc, 0x84cf, 0x84d3,
+    0x84dc, 0x84e7, 0x84ea, 0x84ef, 0x84f0, 0x84f1, 0x84f2, 0x84f7, 0x8532, 0x84fa, 0x84fb, 0x84fd, 0x8502, 0x8503, 0x8507, 0x850c,
+    0x850e, 0x8510, 0x851c, 0x851e, 0x8522, 0x8523, 0x8524, 0x8525, 0x8527, 0x852a, 0x852b, 0x852f, 0x8533, 0x8534, 0x8536, 0x853f,
+    0x8546, 0x854f, 0x8550, 0x8551, 0x8552, 0x8553, 0x8556, 0x8559, 0x855c, 0x855d, 0x855e, 0x855f, 0x8560, 0x8561, 0x8562, 0x8564,
+    0x856b, 0x856f, 0x8579, 0x857a, 0x857b, 0x857d, 0x857f, 0x8581, 0x8585, 0x8586, 0x8589, 0x858b, 0x858c, 0x858f, 0x8593, 0x8598,
+    0x859d, 0x859f, 0x85a0, 0x85a2, 0x85a5, 0x85a7, 0x85b4, 0x85b6, 0x85b7, 0x85b8, 0x85bc, 0x85bd, 0x85be, 0x85bf, 0x85c2, 0x85c7,
+    0x85ca, 0x85cb, 0x85ce, 0x85ad, 0x85d8, 0x85da, 0x85df, 0x85e0, 0x85e6, 0x85e8, 0x85ed, 0x85f3, 0x85f6, 0x85fc, 0x85ff, 0x8600,
+    0x8604, 0x8605, 0x860d, 0x860e, 0x8610, 0x8611, 0x8612, 0x8618, 0x8619, 0x861b, 0x861e, 0x8621, 0x8627, 0x8629, 0x8636, 0x8638,
+    0x863a, 0x863c, 0x863d, 0x8640, 0x8642, 0x8646, 0x8652, 0x8653, 0x8656, 0x8657, 0x8658, 0x8659, 0x865d, 0x8660, 0x8661, 0x8662,
+    0x8663, 0x8664, 0x8669, 0x866c, 0x866f, 0x8675, 0x8676, 0x8677, 0x867a, 0x868d, 0x8691, 0x8696, 0x8698, 0x869a, 0x869c, 0x86a1,
+    0x86a6, 0x86a7, 0x86a8, 0x86ad, 0x86b1, 0x86b3, 0x86b4, 0x86b5, 0x86b7, 0x86b8, 0x86b9, 0x86bf, 0x86c0, 0x86c1, 0x86c3, 0x86c5,
+    0x86d1, 0x86d2, 0x86d5, 0x86d7, 0x86da, 0x86dc, 0x86e0, 0x86e3, 0x86e5, 0x86e7, 0x8688, 0x86fa, 0x86fc, 0x86fd, 0x8704, 0x8705,
+    0x8707, 0x870b, 0x870e, 0x870f, 0x8710, 0x8713, 0x8714, 0x8719, 0x871e, 0x871f, 0x8721, 0x8723, 0x8728, 0x872e, 0x872f, 0x8731,
+    0x8732, 0x8739, 0x873a, 0x873c, 0x873d, 0x873e, 0x8740, 0x8743, 0x8745, 0x874d, 0x8758, 0x875d, 0x8761, 0x8764, 0x8765, 0x876f,
+    0x8771, 0x8772, 0x877b, 0x8783, 0x8784, 0x8785, 0x8786, 0x8787, 0x8788, 0x8789, 0x878b, 0x878c, 0x8790, 0x8793, 0x8795, 0x8797,
+    0x8798, 0x8799, 0x879e, 0x87a0, 0x87a3, 0x87a7, 0x87ac, 0x87ad, 0x87ae, 0x87b1, 0x87b5, 0x87be, 0x87bf, 0x87c1, 0x87c8, 0x87c9,
+    0x87ca, 0x87ce, 0x87d5, 0x87d6, 0x87d9, 0x87da, 0x87dc, 0x87df, 0x87e2, 0x87e3, 0x87e4, 0x87ea, 0x87eb, 0x87ed, 0x87f1, 0x87f3,
+    0x87f8, 0x87fa, 0x87ff, 0x8801, 0x8803, 0x8806, 0x8809, 0x880a, 0x880b, 0x8810, 0x8819, 0x8812, 0x8813, 0x8814, 0x8818, 0x881a,
+    0x881b, 0x881c, 0x881e, 0x881f, 0x8828, 0x882d, 0x882e, 0x8830, 0x8832, 0x8835, 0x883a, 0x883c, 0x8841, 0x8843, 0x8845, 0x8848,
+    0x8849, 0x884a, 0x884b, 0x884e, 0x8851, 0x8855, 0x8856, 0x8858, 0x885a, 0x885c, 0x885f, 0x8860, 0x8864, 0x8869, 0x8871, 0x8879,
+    0x887b, 0x8880, 0x8898, 0x889a, 0x889b, 0x889c, 0x889f, 0x88a0, 0x88a8, 0x88aa, 0x88ba, 0x88bd, 0x88be, 0x88c0, 0x88ca, 0x88cb,
+    0x88cc, 0x88cd, 0x88ce, 0x88d1, 0x88d2, 0x88d3, 0x88db, 0x88de, 0x88e7, 0x88ef, 0x88f0, 0x88f1, 0x88f5, 0x88f7, 0x8901, 0x8906,
+    0x890d, 0x890e, 0x890f, 0x8915, 0x8916, 0x8918, 0x8919, 0x891a, 0x891c, 0x8920, 0x8926, 0x8927, 0x8928, 0x8930, 0x8931, 0x8932,
+    0x8935, 0x8939, 0x893a, 0x893e, 0x8940, 0x8942, 0x8945, 0x8946, 0x8949, 0x894f, 0x8952, 0x8957, 0x895a, 0x895b, 0x895c, 0x8961,
+    0x8962, 0x8963, 0x896b, 0x896e, 0x8970, 0x8973, 0x8975, 0x897a, 0x897b, 0x897c, 0x897d, 0x8989, 0x898d, 0x8990, 0x8994, 0x8995,
+    0x899b, 0x899c, 0x899f, 0x89a0, 0x89a5, 0x89b0, 0x89b4, 0x89b5, 0x89b6, 0x89b7, 0x89bc, 0x89d4, 0x89d5, 0x89d6, 0x89d7, 0x89d8,
+    0x89e5, 0x89e9, 0x89eb, 0x89ed, 0x89f1, 0x89f3, 0x89f6, 0x89f9, 0x89fd, 0x89ff, 0x8a04, 0x8a05, 0x8a07, 0x8a0f, 0x8a11, 0x8a12,
+    0x8a14, 0x8a15, 0x8a1e, 0x8a20, 0x8a22, 0x8a24, 0x8a26, 0x8a2b, 0x8a2c, 0x8a2f, 0x8a35, 0x8a37, 0x8a3d, 0x8a3e, 0x8a40, 0x8a43,
+    0x8a45, 0x8a47, 0x8a49, 0x8a4d, 0x8a4e, 0x8a53, 0x8a56, 0x8a57, 0x8a58, 0x8a5c, 0x8a5d, 0x8a61, 0x8a65, 0x8a67, 0x8a75, 0x8a76,
+    0x8a77, 0x8a79, 0x8a7a, 0x8a7b, 0x8a7e, 0x8a7f, 0x8a80, 0x8a83, 0x8a86, 0x8a8b, 0x8a8f, 0x8a90, 0x8a92, 0x8a96, 0x8a97, 0x8a99,
+    0x8a9f, 0x8aa7, 0x8aa9, 0x8aae, 0x8aaf, 0x8ab3, 0x8ab6, 0x8ab7, 0x8abb, 0x8abe, 0x8ac3, 0x8ac6, 0x8ac8, 0x8ac9, 0x8aca, 0x8ad1,
+    0x8ad3, 0x8ad4, 0x8ad5, 0x8ad7, 0x8add, 0x8adf, 0x8aec, 0x8af0, 0x8af4, 0x8af5, 0x8af6, 0x8afc, 0x8aff, 0x8b05, 0x8b06, 0x8b0b,
+    0x8b11, 0x8b1c, 0x8b1e, 0x8b1f, 0x8b0a, 0x8b2d, 0x8b30, 0x8b37, 0x8b3c, 0x8b42, 0x8b43, 0x8b44, 0x8b45, 0x8b46, 0x8b48, 0x8b52,
+    0x8b53, 0x8b54, 0x8b59, 0x8b4d, 0x8b5e, 0x8b63, 0x8b6d, 0x8b76, 0x8b78, 0x8b79, 0x8b7c, 0x8b7e, 0x8b81, 0x8b84, 0x8b85, 0x8b8b,
+    0x8b8d, 0x8b8f, 0x8b94, 0x8b95, 0x8b9c, 0x8b9e, 0x8b9f, 0x8c38, 0x8c39, 0x8c3d, 0x8c3e, 0x8c45, 0x8c47, 0x8c49, 0x8c4b, 0x8c4f,
+    0x8c51, 0x8c53, 0x8c54, 0x8c57, 0x8c58, 0x8c5b, 0x8c5d, 0x8c59, 0x8c63, 0x8c64, 0x8c66, 0x8c68, 0x8c69, 0x8c6d, 0x8c73, 0x8c75,
+    0x8c76, 0x8c7b, 0x8c7e, 0x8c86, 0x8c87, 0x8c8b, 0x8c90, 0x8c92, 0x8c93, 0x8c99, 0x8c9b, 0x8c9c, 0x8ca4, 0x8cb9, 0x8cba, 0x8cc5,
+    0x8cc6, 0x8cc9, 0x8ccb, 0x8ccf, 0x8cd6, 0x8cd5, 0x8cd9, 0x8cdd, 0x8ce1, 0x8ce8, 0x8cec, 0x8cef, 0x8cf0, 0x8cf2, 0x8cf5, 0x8cf7,
+    0x8cf8, 0x8cfe, 0x8cff, 0x8d01, 0x8d03, 0x8d09, 0x8d12, 0x8d17, 0x8d1b, 0x8d65, 0x8d69, 0x8d6c, 0x8d6e, 0x8d7f, 0x8d82, 0x8d84,
+    0x8d88, 0x8d8d, 0x8d90, 0x8d91, 0x8d95, 0x8d9e, 0x8d9f, 0x8da0, 0x8da6, 0x8dab, 0x8dac, 0x8daf, 0x8db2, 0x8db5, 0x8db7, 0x8db9,
+    0x8dbb, 0x8dc0, 0x8dc5, 0x8dc6, 0x8dc7, 0x8dc8, 0x8dca, 0x8dce, 0x8dd1, 0x8dd4, 0x8dd5, 0x8dd7, 0x8dd9, 0x8de4, 0x8de5, 0x8de7,
+    0x8dec, 0x8df0, 0x8dbc, 0x8df1, 0x8df2, 0x8df4, 0x8dfd, 0x8e01, 0x8e04, 0x8e05, 0x8e06, 0x8e0b, 0x8e11, 0x8e14, 0x8e16, 0x8e20,
+    0x8e21, 0x8e22, 0x8e23, 0x8e26, 0x8e27, 0x8e31, 0x8e33, 0x8e36, 0x8e37, 0x8e38, 0x8e39, 0x8e3d, 0x8e40, 0x8e41, 0x8e4b, 0x8e4d,
+    0x8e4e, 0x8e4f, 0x8e54, 0x8e5b, 0x8e5c, 0x8e5d, 0x8e5e, 0x8e61, 0x8e62, 0x8e69, 0x8e6c, 0x8e6d, 0x8e6f, 0x8e70, 0x8e71, 0x8e79,
+    0x8e7a, 0x8e7b, 0x8e82, 0x8e83, 0x8e89, 0x8e90, 0x8e92, 0x8e95, 0x8e9a, 0x8e9b, 0x8e9d, 0x8e9e, 0x8ea2, 0x8ea7, 0x8ea9, 0x8ead,
+    0x8eae, 0x8eb3, 0x8eb5, 0x8eba, 0x8ebb, 0x8ec0, 0x8ec1, 0x8ec3, 0x8ec4, 0x8ec7, 0x8ecf, 0x8ed1, 0x8ed4, 0x8edc, 0x8ee8, 0x8eee,
+    0x8ef0, 0x8ef1, 0x8ef7, 0x8ef9, 0x8efa, 0x8eed, 0x8f00, 0x8f02, 0x8f07, 0x8f08, 0x8f0f, 0x8f10, 0x8f16, 0x8f17, 0x8f18, 0x8f1e,
+    0x8f20, 0x8f21, 0x8f23, 0x8f25, 0x8f27, 0x8f28, 0x8f2c, 0x8f2d, 0x8f2e, 0x8f34, 0x8f35, 0x8f36, 0x8f37, 0x8f3a, 0x8f40, 0x8f41,
+    0x8f43, 0x8f47, 0x8f4f, 0x8f51, 0x8f52, 0x8f53, 0x8f54, 0x8f55, 0x8f58, 0x8f5d, 0x8f5e, 0x8f65, 0x8f9d, 0x8fa0, 0x8fa1, 0x8fa4,
+    0x8fa5, 0x8fa6, 0x8fb5, 0x8fb6, 0x8fb8, 0x8fbe, 0x8fc0, 0x8fc1, 0x8fc6, 0x8fca, 0x8fcb, 0x8fcd, 0x8fd0, 0x8fd2, 0x8fd3, 0x8fd5,
+    0x8fe0, 0x8fe3, 0x8fe4, 0x8fe8, 0x8fee, 0x8ff1, 0x8ff5, 0x8ff6, 0x8ffb, 0x8ffe, 0x9002, 0x9004, 0x9008, 0x900c, 0x9018, 0x901b,
+    0x9028, 0x9029, 0x902f, 0x902a, 0x902c, 0x902d, 0x9033, 0x9034, 0x9037, 0x903f, 0x9043, 0x9044, 0x904c, 0x905b, 0x905d, 0x9062,
+    0x9066, 0x9067, 0x906c, 0x9070, 0x9074, 0x9079, 0x9085, 0x9088, 0x908b, 0x908c, 0x908e, 0x9090, 0x9095, 0x9097, 0x9098, 0x9099,
+    0x909b, 0x90a0, 0x90a1, 0x90a2, 0x90a5, 0x90b0, 0x90b2, 0x90b3, 0x90b4, 0x90b6, 0x90bd, 0x90cc, 0x90be, 0x90c3, 0x90c4, 0x90c5,
+    0x90c7, 0x90c8, 0x90d5, 0x90d7, 0x90d8, 0x90d9, 0x90dc, 0x90dd, 0x90df, 0x90e5, 0x90d2, 0x90f6, 0x90eb, 0x90ef, 0x90f0, 0x90f4,
+    0x90fe, 0x90ff, 0x9100, 0x9104, 0x9105, 0x9106, 0x9108, 0x910d, 0x9110, 0x9114, 0x9116, 0x9117, 0x9118, 0x911a, 0x911c, 0x911e,
+    0x9120, 0x9125, 0x9122, 0x9123, 0x9127, 0x9129, 0x912e, 0x912f, 0x9131, 0x9134, 0x9136, 0x9137, 0x9139, 0x913a, 0x913c, 0x913d,
+    0x9143, 0x9147, 0x9148, 0x914f, 0x9153, 0x9157, 0x9159, 0x915a, 0x915b, 0x9161, 0x9164, 0x9167, 0x916d, 0x9174, 0x9179, 0x917a,
+    0x917b, 0x9181, 0x9183, 0x9185, 0x9186, 0x918a, 0x918e, 0x9191, 0x9193, 0x9194, 0x9195, 0x9198, 0x919e, 0x91a1, 0x91a6, 0x91a8,
+    0x91ac, 0x91ad, 0x91ae, 0x91b0, 0x91b1, 0x91b2, 0x91b3, 0x91b6, 0x91bb, 0x91bc, 0x91bd, 0x91bf, 0x91c2, 0x91c3, 0x91c5, 0x91d3,
+    0x91d4, 0x91d7, 0x91d9, 0x91da, 0x91de, 0x91e4, 0x91e5, 0x91e9, 0x91ea, 0x91ec, 0x91ed, 0x91ee, 0x91ef, 0x91f0, 0x91f1, 0x91f7,
+    0x91f9, 0x91fb, 0x91fd, 0x9200, 0x9201, 0x9204, 0x9205, 0x9206, 0x9207, 0x9209, 0x920a, 0x920c, 0x9210, 0x9212, 0x9213, 0x9216,
+    0x9218, 0x921c, 0x921d, 0x9223, 0x9224, 0x9225, 0x9226, 0x9228, 0x922e, 0x922f, 0x9230, 0x9233, 0x9235, 0x9236, 0x9238, 0x9239,
+    0x923a, 0x923c, 0x923e, 0x9240, 0x9242, 0x9243, 0x9246, 0x9247, 0x924a, 0x924d, 0x924e, 0x924f, 0x9251, 0x9258, 0x9259, 0x925c,
+    0x925d, 0x9260, 0x9261, 0x9265, 0x9267, 0x9268, 0x9269, 0x926e, 0x926f, 0x9270, 0x9275, 0x9276, 0x9277, 0x9278, 0x9279, 0x927b,
+    0x927c, 0x927d, 0x927f, 0x9288, 0x9289, 0x928a, 0x928d, 0x928e, 0x9292, 0x9297, 0x9299, 0x929f, 0x92a0, 0x92a4, 0x92a5, 0x92a7,
+    0x92a8, 0x92ab, 0x92af, 0x92b2, 0x92b6, 0x92b8, 0x92ba, 0x92bb, 0x92bc, 0x92bd, 0x92bf, 0x92c0, 0x92c1, 0x92c2, 0x92c3, 0x92c5,
+    0x92c6, 0x92c7, 0x92c8, 0x92cb, 0x92cc, 0x92cd, 0x92ce, 0x92d0, 0x92d3, 0x92d5, 0x92d7, 0x92d8, 0x92d9, 0x92dc, 0x92dd, 0x92df,
+    0x92e0, 0x92e1, 0x92e3, 0x92e5, 0x92e7, 0x92e8, 0x92ec, 0x92ee, 0x92f0, 0x92f9, 0x92fb, 0x92ff, 0x9300, 0x9302, 0x9308, 0x930d,
+    0x9311, 0x9314, 0x9315, 0x931c, 0x931d, 0x931e, 0x931f, 0x9321, 0x9324, 0x9325, 0x9327, 0x9329, 0x932a, 0x9333, 0x9334, 0x9336,
+    0x9337, 0x9347, 0x9348, 0x9349, 0x9350, 0x9351, 0x9352, 0x9355, 0x9357, 0x9358, 0x935a, 0x935e, 0x9364, 0x9365, 0x9367, 0x9369,
+    0x936a, 0x936d, 0x936f, 0x9370, 0x9371, 0x9373, 0x9374, 0x9376, 0x937a, 0x937d, 0x937f, 0x9380, 0x9381, 0x9382, 0x9388, 0x938a,
+    0x938b, 0x938d, 0x938f, 0x9392, 0x9395, 0x9398, 0x939b, 0x939e, 0x93a1, 0x93a3, 0x93a4, 0x93a6, 0x93a8, 0x93ab, 0x93b4, 0x93b5,
+    0x93b6, 0x93ba, 0x93a9, 0x93c1, 0x93c4, 0x93c5, 0x93c6, 0x93c7, 0x93c9, 0x93ca, 0x93cb, 0x93cc, 0x93cd, 0x93d3, 0x93d9, 0x93dc,
+    0x93de, 0x93df, 0x93e2, 0x93e6, 0x93e7, 0x93f9, 0x93f7, 0x93f8, 0x93fa, 0x93fb, 0x93fd, 0x9401, 0x9402, 0x9404, 0x9408, 0x9409,
+    0x940d, 0x940e, 0x940f, 0x9415, 0x9416, 0x9417, 0x941f, 0x942e, 0x942f, 0x9431, 0x9432, 0x9433, 0x9434, 0x943b, 0x943f, 0x943d,
+    0x9443, 0x9445, 0x9448, 0x944a, 0x944c, 0x9455, 0x9459, 0x945c, 0x945f, 0x9461, 0x9463, 0x9468, 0x946b, 0x946d, 0x946e, 0x946f,
+    0x9471, 0x9472, 0x9484, 0x9483, 0x9578, 0x9579, 0x957e, 0x9584, 0x9588, 0x958c, 0x958d, 0x958e, 0x959d, 0x959e, 0x959f, 0x95a1,
+    0x95a6, 0x95a9, 0x95ab, 0x95ac, 0x95b4, 0x95b6, 0x95ba, 0x95bd, 0x95bf, 0x95c6, 0x95c8, 0x95c9, 0x95cb, 0x95d0, 0x95d1, 0x95d2,
+    0x95d3, 0x95d9, 0x95da, 0x95dd, 0x95de, 0x95df, 0x95e0, 0x95e4, 0x95e6, 0x961d, 0x961e, 0x9622, 0x9624, 0x9625, 0x9626, 0x962c,
+    0x9631, 0x9633, 0x9637, 0x9638, 0x9639, 0x963a, 0x963c, 0x963d, 0x9641, 0x9652, 0x9654, 0x9656, 0x9657, 0x9658, 0x9661, 0x966e,
+    0x9674, 0x967b, 0x967c, 0x967e, 0x967f, 0x9681, 0x9682, 0x9683, 0x9684, 0x9689, 0x9691, 0x9696, 0x969a, 0x969d, 0x969f, 0x96a4,
+    0x96a5, 0x96a6, 0x96a9, 0x96ae, 0x96af, 0x96b3, 0x96ba, 0x96ca, 0x96d2, 0x5db2, 0x96d8, 0x96da, 0x96dd, 0x96de, 0x96df, 0x96e9,
+    0x96ef, 0x96f1, 0x96fa, 0x9702, 0x9703, 0x9705, 0x9709, 0x971a, 0x971b, 0x971d, 0x9721, 0x9722, 0x9723, 0x9728, 0x9731, 0x9733,
+    0x9741, 0x9743, 0x974a, 0x974e, 0x974f, 0x9755, 0x9757, 0x9758, 0x975a, 0x975b, 0x9763, 0x9767, 0x976a, 0x976e, 0x9773, 0x9776,
+    0x9777, 0x9778, 0x977b, 0x977d, 0x977f, 0x9780, 0x9789, 0x9795, 0x9796, 0x9797, 0x9799, 0x979a, 0x979e, 0x979f, 0x97a2, 0x97ac,
+    0x97ae, 0x97b1, 0x97b2, 0x97b5, 0x97b6, 0x97b8, 0x97b9, 0x97ba, 0x97bc, 0x97be, 0x97bf, 0x97c1, 0x97c4, 0x97c5, 0x97c7, 0x97c9,
+    0x97ca, 0x97cc, 0x97cd, 0x97ce, 0x97d0, 0x97d1, 0x97d4, 0x97d7, 0x97d8, 0x97d9, 0x97dd, 0x97de, 0x97e0, 0x97db, 0x97e1, 0x97e4,
+    0x97ef, 0x97f1, 0x97f4, 0x97f7, 0x97f8, 0x97fa, 0x9807, 0x980a, 0x9819, 0x980d, 0x980e, 0x9814, 0x9816, 0x981c, 0x981e, 0x9820,
+    0x9823, 0x9826, 0x982b, 0x982e, 0x982f, 0x9830, 0x9832, 0x9833, 0x9835, 0x9825, 0x983e, 0x9844, 0x9847, 0x984a, 0x9851, 0x9852,
+    0x9853, 0x9856, 0x9857, 0x9859, 0x985a, 0x9862, 0x9863, 0x9865, 0x9866, 0x986a, 0x986c, 0x98ab, 0x98ad, 0x98ae, 0x98b0, 0x98b4,
+    0x98b7, 0x98b8, 0x98ba, 0x98bb, 0x98bf, 0x98c2, 0x98c5, 0x98c8, 0x98cc, 0x98e1, 0x98e3, 0x98e5, 0x98e6, 0x98e7, 0x98ea, 0x98f3,
+    0x98f6, 0x9902, 0x9907, 0x9908, 0x9911, 0x9915, 0x9916, 0x9917, 0x991a, 0x991b, 0x991c, 0x991f, 0x9922, 0x9926, 0x9927, 0x992b,
+    0x9931, 0x9932, 0x9933, 0x9934, 0x9935, 0x9939, 0x993a, 0x993b, 0x993c, 0x9940, 0x9941, 0x9946, 0x9947, 0x9948, 0x994d, 0x994e,
+    0x9954, 0x9958, 0x9959, 0x995b, 0x995c, 0x995e, 0x995f, 0x9960, 0x999b, 0x999d, 0x999f, 0x99a6, 0x99b0, 0x99b1, 0x99b2, 0x99b5,
+    0x99b9, 0x99ba, 0x99bd, 0x99bf, 0x99c3, 0x99c9, 0x99d3, 0x99d4, 0x99d9, 0x99da, 0x99dc, 0x99de, 0x99e7, 0x99ea, 0x99eb, 0x99ec,
+    0x99f0, 0x99f4, 0x99f5, 0x99f9, 0x99fd, 0x99fe, 0x9a02, 0x9a03, 0x9a04, 0x9a0b, 0x9a0c, 0x9a10, 0x9a11, 0x9a16, 0x9a1e, 0x9a20,
+    0x9a22, 0x9a23, 0x9a24, 0x9a27, 0x9a2d, 0x9a2e, 0x9a33, 0x9a35, 0x9a36, 0x9a38, 0x9a47, 0x9a41, 0x9a44, 0x9a4a, 0x9a4b, 0x9a4c,
+    0x9a4e, 0x9a51, 0x9a54, 0x9a56, 0x9a5d, 0x9aaa, 0x9aac, 0x9aae, 0x9aaf, 0x9ab2, 0x9ab4, 0x9ab5, 0x9ab6, 0x9ab9, 0x9abb, 0x9abe,
+    0x9abf, 0x9ac1, 0x9ac3, 0x9ac6, 0x9ac8, 0x9ace, 0x9ad0, 0x9ad2, 0x9ad5, 0x9ad6, 0x9ad7, 0x9adb, 0x9adc, 0x9ae0, 0x9ae4, 0x9ae5,
+    0x9ae7, 0x9ae9, 0x9aec, 0x9af2, 0x9af3, 0x9af5, 0x9af9, 0x9afa, 0x9afd, 0x9aff, 0x9b00, 0x9b01, 0x9b02, 0x9b03, 0x9b04, 0x9b05,
+    0x9b08, 0x9b09, 0x9b0b, 0x9b0c, 0x9b0d, 0x9b0e, 0x9b10, 0x9b12, 0x9b16, 0x9b19, 0x9b1b, 0x9b1c, 0x9b20, 0x9b26, 0x9b2b, 0x9b2d,
+    0x9b33, 0x9b34, 0x9b35, 0x9b37, 0x9b39, 0x9b3a, 0x9b3d, 0x9b48, 0x9b4b, 0x9b4c, 0x9b55, 0x9b56, 0x9b57, 0x9b5b, 0x9b5e, 0x9b61,
+    0x9b63, 0x9b65, 0x9b66, 0x9b68, 0x9b6a, 0x9b6b, 0x9b6c, 0x9b6d, 0x9b6e, 0x9b73, 0x9b75, 0x9b77, 0x9b78, 0x9b79, 0x9b7f, 0x9b80,
+    0x9b84, 0x9b85, 0x9b86, 0x9b87, 0x9b89, 0x9b8a, 0x9b8b, 0x9b8d, 0x9b8f, 0x9b90, 0x9b94, 0x9b9a, 0x9b9d, 0x9b9e, 0x9ba6, 0x9ba7,
+    0x9ba9, 0x9bac, 0x9bb0, 0x9bb1, 0x9bb2, 0x9bb7, 0x9bb8, 0x9bbb, 0x9bbc, 0x9bbe, 0x9bbf, 0x9bc1, 0x9bc7, 0x9bc8, 0x9bce, 0x9bd0,
+    0x9bd7, 0x9bd8, 0x9bdd, 0x9bdf, 0x9be5, 0x9be7, 0x9bea, 0x9beb, 0x9bef, 0x9bf3, 0x9bf7, 0x9bf8, 0x9bf9, 0x9bfa, 0x9bfd, 0x9bff,
+    0x9c00, 0x9c02, 0x9c0b, 0x9c0f, 0x9c11, 0x9c16, 0x9c18, 0x9c19, 0x9c1a, 0x9c1c, 0x9c1e, 0x9c22, 0x9c23, 0x9c26, 0x9c27, 0x9c28,
+    0x9c29, 0x9c2a, 0x9c31, 0x9c35, 0x9c36, 0x9c37, 0x9c3d, 0x9c41, 0x9c43, 0x9c44, 0x9c45, 0x9c49, 0x9c4a, 0x9c4e, 0x9c4f, 0x9c50,
+    0x9c53, 0x9c54, 0x9c56, 0x9c58, 0x9c5b, 0x9c5d, 0x9c5e, 0x9c5f, 0x9c63, 0x9c69, 0x9c6a, 0x9c5c, 0x9c6b, 0x9c68, 0x9c6e, 0x9c70,
+    0x9c72, 0x9c75, 0x9c77, 0x9c7b, 0x9ce6, 0x9cf2, 0x9cf7, 0x9cf9, 0x9d0b, 0x9d02, 0x9d11, 0x9d17, 0x9d18, 0x9d1c, 0x9d1d, 0x9d1e,
+    0x9d2f, 0x9d30, 0x9d32, 0x9d33, 0x9d34, 0x9d3a, 0x9d3c, 0x9d45, 0x9d3d, 0x9d42, 0x9d43, 0x9d47, 0x9d4a, 0x9d53, 0x9d54, 0x9d5f,
+    0x9d63, 0x9d62, 0x9d65, 0x9d69, 0x9d6a, 0x9d6b, 0x9d70, 0x9d76, 0x9d77, 0x9d7b, 0x9d7c, 0x9d7e, 0x9d83, 0x9d84, 0x9d86, 0x9d8a,
+    0x9d8d, 0x9d8e, 0x9d92, 0x9d93, 0x9d95, 0x9d96, 0x9d97, 0x9d98, 0x9da1, 0x9daa, 0x9dac, 0x9dae, 0x9db1, 0x9db5, 0x9db9, 0x9dbc,
+    0x9dbf, 0x9dc3, 0x9dc7, 0x9dc9, 0x9dca, 0x9dd4, 0x9dd5, 0x9dd6, 0x9dd7, 0x9dda, 0x9dde, 0x9ddf, 0x9de0, 0x9de5, 0x9de7, 0x9de9,
+    0x9deb, 0x9dee, 0x9df0, 0x9df3, 0x9df4, 0x9dfe, 0x9e0a, 0x9e02, 0x9e07, 0x9e0e, 0x9e10, 0x9e11, 0x9e12, 0x9e15, 0x9e16, 0x9e19,
+    0x9e1c, 0x9e1d, 0x9e7a, 0x9e7b, 0x9e7c, 0x9e80, 0x9e82, 0x9e83, 0x9e84, 0x9e85, 0x9e87, 0x9e8e, 0x9e8f, 0x9e96, 0x9e98, 0x9e9b,
+    0x9e9e, 0x9ea4, 0x9ea8, 0x9eac, 0x9eae, 0x9eaf, 0x9eb0, 0x9eb3, 0x9eb4, 0x9eb5, 0x9ec6, 0x9ec8, 0x9ecb, 0x9ed5, 0x9edf, 0x9ee4,
+    0x9ee7, 0x9eec, 0x9eed, 0x9eee, 0x9ef0, 0x9ef1, 0x9ef2, 0x9ef5, 0x9ef8, 0x9eff, 0x9f02, 0x9f03, 0x9f09, 0x9f0f, 0x9f10, 0x9f11,
+    0x9f12, 0x9f14, 0x9f16, 0x9f17, 0x9f19, 0x9f1a, 0x9f1b, 0x9f1f, 0x9f22, 0x9f26, 0x9f2a, 0x9f2b, 0x9f2f, 0x9f31, 0x9f32, 0x9f34,
+    0x9f37, 0x9f39, 0x9f3a, 0x9f3c, 0x9f3d, 0x9f3f, 0x9f41, 0x9f43, 0x9f44, 0x9f45, 0x9f46, 0x9f47, 0x9f53, 0x9f55, 0x9f56, 0x9f57,
+    0x9f58, 0x9f5a, 0x9f5d, 0x9f5e, 0x9f68, 0x9f69, 0x9f6d, 0x9f6e, 0x9f6f, 0x9f70, 0x9f71, 0x9f73, 0x9f75, 0x9f7a, 0x9f7d, 0x9f8f,
+    0x9f90, 0x9f91, 0x9f92, 0x9f94, 0x9f96, 0x9f97, 0x9f9e, 0x9fa1, 0x9fa2, 0x9fa3, 0x9fa5, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000,
+];
+
+/// Pairs of a Unicode code point and the pointer of its character, sorted by code point.
+#[rustfmt::skip]
+static FROM_UNICODE: [(u16, u16); 6066] = [
+    (0x00a1, 127), (0x00a4, 173), (0x00a6, 128), (0x00a9, 170), (0x00aa, 169), (0x00ae, 171), (0x00af, 113), (0x00b8, 110),
+    (0x00ba, 168), (0x00bf, 129), (0x00c0, 847), (0x00c1, 846), (0x00c2, 849), (0x00c3, 855), (0x00c4, 848), (0x00c5, 854),
+    (0x00c6, 752), (0x00c7, 859), (0x00c8, 863), (0x00c9, 862), (0x00ca, 865), (0x00cb, 864), (0x00cc, 877), (0x00cd, 876),
+    (0x00ce, 879), (0x00cf, 878), (0x00d1, 893), (0x00d2, 895), (0x00d3, 894), (0x00d4, 897), (0x00d5, 901), (0x00d6, 896),
+    (0x00d8, 763), (0x00d9, 912), (0x00da, 911), (0x00db, 914), (0x00dc, 913), (0x00dd, 927), (0x00de, 767), (0x00df, 797),
+    (0x00e0, 941), (0x00e1, 940), (0x00e2, 943), (0x00e3, 949), (0x00e4, 942), (0x00e5, 948), (0x00e6, 784), (0x00e7, 953),
+    (0x00e8, 957), (0x00e9, 956), (0x00ea, 959), (0x00eb, 958), (0x00ec, 971), (0x00ed, 970), (0x00ee, 973), (0x00ef, 972),
+    (0x00f0, 786), (0x00f1, 987), (0x00f2, 989), (0x00f3, 988), (0x00f4, 991), (0x00f5, 995), (0x00f6, 990), (0x00f8, 795),
+    (0x00f9, 1006), (0x00fa, 1005), (0x00fb, 1008), (0x00fc, 1007), (0x00fd, 1021), (0x00fe, 799), (0x00ff, 1022), (0x0100, 852),
+    (0x0101, 946), (0x0102, 850), (0x0103, 944), (0x0104, 853), (0x0105, 947), (0x0106, 856), (0x0107, 950), (0x0108, 857),
+    (0x0109, 951), (0x010a, 860), (0x010b, 954), (0x010c, 858), (0x010d, 952), (0x010e, 861), (0x010f, 955), (0x0110, 753),
+    (0x0111, 785), (0x0112, 868), (0x0113, 962), (0x0116, 867), (0x0117, 961), (0x0118, 869), (0x0119, 963), (0x011a, 866),
+    (0x011b, 960), (0x011c, 871), (0x011d, 965), (0x011e, 872), (0x011f, 966), (0x0120, 874), (0x0121, 968), (0x0122, 873),
+    (0x0124, 875), (0x0125, 969), (0x0126, 755), (0x0127, 787), (0x0128, 884), (0x0129, 978), (0x012a, 882), (0x012b, 976),
+    (0x012e, 883), (0x012f, 977), (0x0130, 881), (0x0131, 788), (0x0132, 757), (0x0133, 789), (0x0134, 885), (0x0135, 979),
+    (0x0136, 886), (0x0137, 980), (0x0138, 790), (0x0139, 887), (0x013a, 981), (0x013b, 889), (0x013c, 983), (0x013d, 888),
+    (0x013e, 982), (0x013f, 760), (0x0140, 792), (0x0141, 759), (0x0142, 791), (0x0143, 890), (0x0144, 984), (0x0145, 892),
+    (0x0146, 986), (0x0147, 891), (0x0148, 985), (0x0149, 793), (0x014a, 762), (0x014b, 794), (0x014c, 900), (0x014d, 994),
+    (0x0150, 899), (0x0151, 993), (0x0152, 764), (0x0153, 796), (0x0154, 902), (0x0155, 996), (0x0156, 904), (0x0157, 998),
+    (0x0158, 903), (0x0159, 997), (0x015a, 905), (0x015b, 999), (0x015c, 906), (0x015d, 1000), (0x015e, 908), (0x015f, 1002),
+    (0x0160, 907), (0x0161, 1001), (0x0162, 910), (0x0163, 1004), (0x0164, 909), (0x0165, 1003), (0x0166, 766), (0x0167, 798),
+    (0x0168, 921), (0x0169, 1015), (0x016a, 918), (0x016b, 1012), (0x016c, 915), (0x016d, 1009), (0x016e, 920), (0x016f, 1014),
+    (0x0170, 917), (0x0171, 1011), (0x0172, 919), (0x0173, 1013), (0x0174, 926), (0x0175, 1020), (0x0176, 929), (0x0177, 1023),
+    (0x0178, 928), (0x0179, 930), (0x017a, 1024), (0x017b, 932), (0x017c, 1026), (0x017d, 931), (0x017e, 1025), (0x01cd, 851),
+    (0x01ce, 945), (0x01cf, 880), (0x01d0, 974), (0x01d1, 898), (0x01d2, 992), (0x01d3, 916), (0x01d4, 1010), (0x01d5, 925),
+    (0x01d6, 1019), (0x01d7, 922), (0x01d8, 1016), (0x01d9, 924), (0x01da, 1018), (0x01db, 923), (0x01dc, 1017), (0x01f5, 964),
+    (0x02c7, 109), (0x02d8, 108), (0x02d9, 111), (0x02da, 115), (0x02db, 114), (0x02dd, 112), (0x0384, 117), (0x0385, 118),
+    (0x0386, 534), (0x0388, 535), (0x0389, 536), (0x038a, 537), (0x038c, 540), (0x038e, 542), (0x038f, 545), (0x0390, 555),
+    (0x03aa, 538), (0x03ab, 543), (0x03ac, 550), (0x03ad, 551), (0x03ae, 552), (0x03af, 553), (0x03b0, 560), (0x03c2, 557),
+    (0x03ca, 554), (0x03cb, 559), (0x03cc, 556), (0x03cd, 558), (0x03ce, 561), (0x0402, 597), (0x0403, 598), (0x0404, 599),
+    (0x0405, 600), (0x0406, 601), (0x0407, 602), (0x0408, 603), (0x0409, 604), (0x040a, 605), (0x040b, 606), (0x040c, 607),
+    (0x040e, 608), (0x040f, 609), (0x0452, 645), (0x0453, 646), (0x0454, 647), (0x0455, 648), (0x0456, 649), (0x0457, 650),
+    (0x0458, 651), (0x0459, 652), (0x045a, 653), (0x045b, 654), (0x045c, 655), (0x045e, 656), (0x045f, 657), (0x2116, 174),
+    (0x2122, 172), (0x4e02, 1410), (0x4e04, 1411), (0x4e05, 1412), (0x4e0c, 1413), (0x4e12, 1414), (0x4e1f, 1415), (0x4e23, 1416),
+    (0x4e24, 1417), (0x4e28, 1418), (0x4e2b, 1419), (0x4e2e, 1420), (0x4e2f, 1421), (0x4e30, 1422), (0x4e35, 1423), (0x4e40, 1424),
+    (0x4e41, 1425), (0x4e44, 1426), (0x4e47, 1427), (0x4e51, 1428), (0x4e5a, 1429), (0x4e5c, 1430), (0x4e63, 1431), (0x4e68, 1432),
+    (0x4e69, 1433), (0x4e74, 1434), (0x4e75, 1435), (0x4e79, 1436), (0x4e7f, 1437), (0x4e8d, 1438), (0x4e96, 1439), (0x4e97, 1440),
+    (0x4e9d, 1441), (0x4eaf, 1442), (0x4eb9, 1443), (0x4ec3, 1444), (0x4ed0, 1445), (0x4eda, 1446), (0x4edb, 1447), (0x4ee0, 1448),
+    (0x4ee1, 1449), (0x4ee2, 1450), (0x4ee8, 1451), (0x4eef, 1452), (0x4ef1, 1453), (0x4ef3, 1454), (0x4ef5, 1455), (0x4efd, 1456),
+    (0x4efe, 1457), (0x4eff, 1458), (0x4f00, 1459), (0x4f02, 1460), (0x4f03, 1461), (0x4f08, 1462), (0x4f0b, 1463), (0x4f0c, 1464),
+    (0x4f12, 1465), (0x4f15, 1466), (0x4f16, 1467), (0x4f17, 1468), (0x4f19, 1469), (0x4f2e, 1470), (0x4f31, 1471), (0x4f33, 1473),
+    (0x4f35, 1474), (0x4f37, 1475), (0x4f39, 1476), (0x4f3b, 1477), (0x4f3e, 1478), (0x4f40, 1479), (0x4f42, 1480), (0x4f48, 1481),
+    (0x4f49, 1482), (0x4f4b, 1483), (0x4f4c, 1484), (0x4f52, 1485), (0x4f54, 1486), (0x4f56, 1487), (0x4f58, 1488), (0x4f5f, 1489),
+    (0x4f60, 1472), (0x4f63, 1490), (0x4f6a, 1491), (0x4f6c, 1492), (0x4f6e, 1493), (0x4f71, 1494), (0x4f77, 1495), (0x4f78, 1496),
+    (0x4f79, 1497), (0x4f7a, 1498), (0x4f7d, 1499), (0x4f7e, 1500), (0x4f81, 1501), (0x4f82, 1502), (0x4f84, 1503), (0x4f85, 1504),
+    (0x4f89, 1505), (0x4f8a, 1506), (0x4f8c, 1507), (0x4f8e, 1508), (0x4f90, 1509), (0x4f92, 1510), (0x4f93, 1511), (0x4f94, 1512),
+    (0x4f97, 1513), (0x4f99, 1514), (0x4f9a, 1515), (0x4f9e, 1516), (0x4f9f, 1517), (0x4fb2, 1518), (0x4fb7, 1519), (0x4fb9, 1520),
+    (0x4fbb, 1521), (0x4fbc, 1522), (0x4fbd, 1523), (0x4fbe, 1524), (0x4fc0, 1525), (0x4fc1, 1526), (0x4fc5, 1527), (0x4fc6, 1528),
+    (0x4fc8, 1529), (0x4fc9, 1530), (0x4fcb, 1531), (0x4fcc, 1532), (0x4fcd, 1533), (0x4fcf, 1534), (0x4fd2, 1535), (0x4fdc, 1536),
+    (0x4fe0, 1537), (0x4fe2, 1538), (0x4ff0, 1539), (0x4ff2, 1540), (0x4ffc, 1541), (0x4ffd, 1542), (0x4fff, 1543), (0x5000, 1544),
+    (0x5001, 1545), (0x5004, 1546), (0x5007, 1547), (0x500a, 1548), (0x500c, 1549), (0x500e, 1550), (0x5010, 1551), (0x5013, 1552),
+    (0x5017, 1553), (0x5018, 1554), (0x501b, 1555), (0x501c, 1556), (0x501d, 1557), (0x501e, 1558), (0x5022, 1559), (0x5027, 1560),
+    (0x502e, 1561), (0x5030, 1562), (0x5032, 1563), (0x5033, 1564), (0x5035, 1565), (0x503b, 1589), (0x5040, 1566), (0x5041, 1567),
+    (0x5042, 1568), (0x5045, 1569), (0x5046, 1570), (0x504a, 1571), (0x504c, 1572), (0x504e, 1573), (0x5051, 1574), (0x5052, 1575),
+    (0x5053, 1576), (0x5057, 1577), (0x5059, 1578), (0x505f, 1579), (0x5060, 1580), (0x5062, 1581), (0x5063, 1582), (0x5066, 1583),
+    (0x5067, 1584), (0x506a, 1585), (0x506d, 1586), (0x5070, 1587), (0x5071, 1588), (0x5081, 1590), (0x5083, 1591), (0x5084, 1592),
+    (0x5086, 1593), (0x508a, 1594), (0x508e, 1595), (0x508f, 1596), (0x5090, 1597), (0x5092, 1598), (0x5093, 1599), (0x5094, 1600),
+    (0x5096, 1601), (0x509b, 1602), (0x509c, 1603), (0x509e, 1604), (0x509f, 1605), (0x50a0, 1606), (0x50a1, 1607), (0x50a2, 1608),
+    (0x50aa, 1609), (0x50af, 1610), (0x50b0, 1611), (0x50b9, 1612), (0x50ba, 1613), (0x50bd, 1614), (0x50c0, 1615), (0x50c3, 1616),
+    (0x50c4, 1617), (0x50c7, 1618), (0x50cc, 1619), (0x50ce, 1620), (0x50d0, 1621), (0x50d3, 1622), (0x50d4, 1623), (0x50d8, 1624),
+    (0x50dc, 1625), (0x50dd, 1626), (0x50df, 1627), (0x50e2, 1628), (0x50e4, 1629), (0x50e6, 1630), (0x50e8, 1631), (0x50e9, 1632),
+    (0x50ef, 1633), (0x50f1, 1634), (0x50f2, 1646), (0x50f6, 1635), (0x50fa, 1636), (0x50fe, 1637), (0x5103, 1638), (0x5106, 1639),
+    (0x5107, 1640), (0x5108, 1641), (0x510b, 1642), (0x510c, 1643), (0x510d, 1644), (0x510e, 1645), (0x5110, 1647), (0x5117, 1648),
+    (0x5119, 1649), (0x511b, 1650), (0x511c, 1651), (0x511d, 1652), (0x511e, 1653), (0x5123, 1654), (0x5127, 1655), (0x5128, 1656),
+    (0x512c, 1657), (0x512d, 1658), (0x512f, 1659), (0x5131, 1660), (0x5133, 1661), (0x5134, 1662), (0x5135, 1663), (0x5138, 1664),
+    (0x5139, 1665), (0x5142, 1666), (0x514a, 1667), (0x514f, 1668), (0x5153, 1669), (0x5155, 1670), (0x5157, 1671), (0x5158, 1672),
+    (0x515f, 1673), (0x5164, 1674), (0x5166, 1675), (0x517e, 1676), (0x5183, 1677), (0x5184, 1678), (0x518b, 1679), (0x518e, 1680),
+    (0x5198, 1681), (0x519d, 1682), (0x51a1, 1683), (0x51a3, 1684), (0x51ad, 1685), (0x51b8, 1686), (0x51ba, 1687), (0x51bc, 1688),
+    (0x51be, 1689), (0x51bf, 1690), (0x51c2, 1691), (0x51c8, 1692), (0x51cf, 1693), (0x51d1, 1694), (0x51d2, 1695), (0x51d3, 1696),
+    (0x51d5, 1697), (0x51d8, 1698), (0x51de, 1699), (0x51e2, 1700), (0x51e5, 1701), (0x51ee, 1702), (0x51f2, 1703), (0x51f3, 1704),
+    (0x51f4, 1705), (0x51f7, 1706), (0x5201, 1707), (0x5202, 1708), (0x5205, 1709), (0x5212, 1710), (0x5213, 1711), (0x5215, 1712),
+    (0x5216, 1713), (0x5218, 1714), (0x5222, 1715), (0x5228, 1716), (0x5231, 1717), (0x5232, 1718), (0x5235, 1719), (0x523c, 1720),
+    (0x5245, 1721), (0x5249, 1722), (0x5255, 1723), (0x5257, 1724), (0x5258, 1725), (0x525a, 1726), (0x525c, 1727), (0x525f, 1728),
+    (0x5260, 1729), (0x5261, 1730), (0x5266, 1731), (0x526e, 1732), (0x5277, 1733), (0x5278, 1734), (0x5279, 1735), (0x5280, 1736),
+    (0x5282, 1737), (0x5285, 1738), (0x528a, 1739), (0x528c, 1740), (0x5293, 1741), (0x5295, 1742), (0x5296, 1743), (0x5297, 1744),
+    (0x5298, 1745), (0x529a, 1746), (0x529c, 1747), (0x52a4, 1748), (0x52a5, 1749), (0x52a6, 1750), (0x52a7, 1751), (0x52af, 1752),
+    (0x52b0, 1753), (0x52b6, 1754), (0x52b7, 1755), (0x52b8, 1756), (0x52ba, 1757), (0x52bb, 1758), (0x52bd, 1759), (0x52c0, 1760),
+    (0x52c4, 1761), (0x52c6, 1762), (0x52c8, 1763), (0x52cc, 1764), (0x52cf, 1765), (0x52d1, 1766), (0x52d4, 1767), (0x52d6, 1768),
+    (0x52db, 1769), (0x52dc, 1770), (0x52e1, 1771), (0x52e5, 1772), (0x52e8, 1773), (0x52e9, 1774), (0x52ea, 1775), (0x52ec, 1776),
+    (0x52f0, 1777), (0x52f1, 1778), (0x52f4, 1779), (0x52f6, 1780), (0x52f7, 1781), (0x5300, 1782), (0x5303, 1783), (0x530a, 1784),
+    (0x530b, 1785), (0x530c, 1786), (0x5311, 1787), (0x5313, 1788), (0x5318, 1789), (0x531b, 1790), (0x531c, 1791), (0x531e, 1792),
+    (0x531f, 1793), (0x5325, 1794), (0x5327, 1795), (0x5328, 1796), (0x5329, 1797), (0x532b, 1798), (0x532c, 1799), (0x532d, 1800),
+    (0x5330, 1801), (0x5332, 1802), (0x5335, 1803), (0x533c, 1804), (0x533d, 1805), (0x533e, 1806), (0x5342, 1807), (0x534b, 1809),
+    (0x534c, 1808), (0x5359, 1810), (0x535b, 1811), (0x5361, 1812), (0x5363, 1813), (0x5365, 1814), (0x536c, 1815), (0x536d, 1816),
+    (0x5372, 1817), (0x5379, 1818), (0x537e, 1819), (0x5383, 1820), (0x5387, 1821), (0x5388, 1822), (0x538e, 1823), (0x5393, 1824),
+    (0x5394, 1825), (0x5399, 1826), (0x539d, 1827), (0x53a1, 1828), (0x53a4, 1829), (0x53aa, 1830), (0x53ab, 1831), (0x53af, 1832),
+    (0x53b2, 1833), (0x53b4, 1834), (0x53b5, 1835), (0x53b7, 1836), (0x53b8, 1837), (0x53ba, 1838), (0x53bd, 1839), (0x53c0, 1840),
+    (0x53c5, 1841), (0x53cf, 1842), (0x53d2, 1843), (0x53d3, 1844), (0x53d5, 1845), (0x53da, 1846), (0x53dd, 1847), (0x53de, 1848),
+    (0x53e0, 1849), (0x53e6, 1850), (0x53e7, 1851), (0x53f5, 1852), (0x5402, 1853), (0x5413, 1854), (0x541a, 1855), (0x5421, 1856),
+    (0x5427, 1857), (0x5428, 1858), (0x542a, 1859), (0x542f, 1860), (0x5431, 1861), (0x5434, 1862), (0x5435, 1863), (0x5443, 1864),
+    (0x5444, 1865), (0x5447, 1866), (0x544d, 1867), (0x544f, 1868), (0x545e, 1869), (0x5462, 1870), (0x5464, 1871), (0x5466, 1872),
+    (0x5467, 1873), (0x5469, 1874), (0x546b, 1875), (0x546d, 1876), (0x546e, 1877), (0x5474, 1878), (0x547f, 1879), (0x5481, 1880),
+    (0x5483, 1881), (0x5485, 1882), (0x5488, 1883), (0x5489, 1884), (0x548d, 1885), (0x5491, 1886), (0x5495, 1887), (0x5496, 1888),
+    (0x549c, 1889), (0x549f, 1890), (0x54a1, 1891), (0x54a6, 1892), (0x54a7, 1893), (0x54a9, 1894), (0x54aa, 1895), (0x54ad, 1896),
+    (0x54ae, 1897), (0x54b1, 1898), (0x54b7, 1899), (0x54b9, 1900), (0x54ba, 1901), (0x54bb, 1902), (0x54bf, 1903), (0x54c6, 1904),
+    (0x54ca, 1905), (0x54cd, 1906), (0x54ce, 1907), (0x54e0, 1908), (0x54ea, 1909), (0x54ec, 1910), (0x54ef, 1911), (0x54f6, 1912),
+    (0x54fc, 1913), (0x54fe, 1914), (0x54ff, 1915), (0x5500, 1916), (0x5501, 1917), (0x5505, 1918), (0x5508, 1919), (0x5509, 1920),
+    (0x550c, 1921), (0x550d, 1922), (0x550e, 1923), (0x5515, 1924), (0x552a, 1925), (0x552b, 1926), (0x5532, 1927), (0x5535, 1928),
+    (0x5536, 1929), (0x553b, 1930), (0x553c, 1931), (0x553d, 1932), (0x5541, 1933), (0x5547, 1934), (0x5549, 1935), (0x554a, 1936),
+    (0x554d, 1937), (0x5550, 1938), (0x5551, 1939), (0x5558, 1940), (0x555a, 1941), (0x555b, 1942), (0x555e, 1943), (0x5560, 1944),
+    (0x5561, 1945), (0x5564, 1946), (0x5566, 1947), (0x557f, 1948), (0x5581, 1949), (0x5582, 1950), (0x5586, 1951), (0x5588, 1952),
+    (0x558e, 1953), (0x558f, 1954), (0x5591, 1955), (0x5592, 1956), (0x5593, 1957), (0x5594, 1958), (0x5597, 1959), (0x55a3, 1960),
+    (0x55a4, 1961), (0x55ad, 1962), (0x55b2, 1963), (0x55bf, 1964), (0x55c1, 1965), (0x55c3, 1966), (0x55c6, 1967), (0x55c9, 1968),
+    (0x55cb, 1969), (0x55cc, 1970), (0x55ce, 1971), (0x55d1, 1972), (0x55d2, 1973), (0x55d3, 1974), (0x55d7, 1975), (0x55d8, 1976),
+    (0x55db, 1977), (0x55de, 1978), (0x55e2, 1979), (0x55e9, 1980), (0x55f6, 1981), (0x55ff, 1982), (0x5605, 1983), (0x5608, 1984),
+    (0x560a, 1985), (0x560d, 1986), (0x560e, 1987), (0x560f, 1988), (0x5610, 1989), (0x5611, 1990), (0x5612, 1991), (0x5619, 1992),
+    (0x562c, 1993), (0x5630, 1994), (0x5633, 1995), (0x5635, 1996), (0x5637, 1997), (0x5639, 1998), (0x563b, 1999), (0x563c, 2000),
+    (0x563d, 2001), (0x563f, 2002), (0x5640, 2003), (0x5641, 2004), (0x5643, 2005), (0x5644, 2006), (0x5646, 2007), (0x5649, 2008),
+    (0x564b, 2009), (0x564d, 2010), (0x564f, 2011), (0x5654, 2012), (0x565e, 2013), (0x5660, 2014), (0x5661, 2015), (0x5662, 2016),
+    (0x5663, 2017), (0x5666, 2018), (0x5669, 2019), (0x566d, 2020), (0x566f, 2021), (0x5671, 2022), (0x5672, 2023), (0x5675, 2024),
+    (0x5684, 2025), (0x5685, 2026), (0x5688, 2027), (0x568b, 2028), (0x568c, 2029), (0x5695, 2030), (0x5699, 2031), (0x569a, 2032),
+    (0x569d, 2033), (0x569e, 2034), (0x569f, 2035), (0x56a6, 2036), (0x56a7, 2037), (0x56a8, 2038), (0x56a9, 2039), (0x56ab, 2040),
+    (0x56ac, 2041), (0x56ad, 2042), (0x56b1, 2043), (0x56b3, 2044), (0x56b7, 2045), (0x56be, 2046), (0x56c5, 2047), (0x56c9, 2048),
+    (0x56ca, 2049), (0x56cb, 2050), (0x56cc, 2053), (0x56cd, 2054), (0x56cf, 2051), (0x56d0, 2052), (0x56d9, 2055), (0x56dc, 2056),
+    (0x56dd, 2057), (0x56df, 2058), (0x56e1, 2059), (0x56e4, 2060), (0x56e5, 2061), (0x56e6, 2062), (0x56e7, 2063), (0x56e8, 2064),
+    (0x56eb, 2066), (0x56ed, 2067), (0x56f1, 2065), (0x56f6, 2068), (0x56f7, 2069), (0x5701, 2070), (0x5702, 2071), (0x5707, 2072),
+    (0x570a, 2073), (0x570c, 2074), (0x5711, 2075), (0x5715, 2076), (0x571a, 2077), (0x571b, 2078), (0x571d, 2079), (0x5720, 2080),
+    (0x5722, 2081), (0x5723, 2082), (0x5724, 2083), (0x5725, 2084), (0x5729, 2085), (0x572a, 2086), (0x572c, 2087), (0x572e, 2088),
+    (0x572f, 2089), (0x5733, 2090), (0x5734, 2091), (0x573d, 2092), (0x573e, 2093), (0x573f, 2094), (0x5745, 2095), (0x5746, 2096),
+    (0x574c, 2097), (0x574d, 2098), (0x5752, 2099), (0x5762, 2100), (0x5765, 2101), (0x5767, 2102), (0x5768, 2103), (0x576b, 2104),
+    (0x576d, 2105), (0x576e, 2106), (0x576f, 2107), (0x5770, 2108), (0x5771, 2109), (0x5773, 2110), (0x5774, 2111), (0x5775, 2112),
+    (0x5777, 2113), (0x5779, 2114), (0x577a, 2115), (0x577b, 2116), (0x577c, 2117), (0x577e, 2118), (0x5781, 2119), (0x5783, 2120),
+    (0x578c, 2121), (0x5794, 2122), (0x5795, 2131), (0x5797, 2123), (0x5799, 2124), (0x579a, 2125), (0x579c, 2126), (0x579d, 2127),
+    (0x579e, 2128), (0x579f, 2129), (0x57a1, 2130), (0x57a7, 2132), (0x57a8, 2133), (0x57a9, 2134), (0x57ac, 2135), (0x57b8, 2136),
+    (0x57bd, 2137), (0x57c7, 2138), (0x57c8, 2139), (0x57cc, 2140), (0x57cf, 2141), (0x57d5, 2142), (0x57dd, 2143), (0x57de, 2144),
+    (0x57e1, 2161), (0x57e4, 2145), (0x57e6, 2146), (0x57e7, 2147), (0x57e9, 2148), (0x57ed, 2149), (0x57f0, 2150), (0x57f5, 2151),
+    (0x57f6, 2152), (0x57f8, 2153), (0x57fd, 2154), (0x57fe, 2155), (0x57ff, 2156), (0x5803, 2157), (0x5804, 2158), (0x5808, 2159),
+    (0x5809, 2160), (0x580c, 2162), (0x580d, 2163), (0x581b, 2164), (0x581e, 2165), (0x581f, 2166), (0x5820, 2167), (0x5826, 2168),
+    (0x5827, 2169), (0x582d, 2170), (0x5832, 2171), (0x5839, 2172), (0x583f, 2173), (0x5849, 2174), (0x584c, 2175), (0x584d, 2176),
+    (0x584f, 2177), (0x5850, 2178), (0x5855, 2179), (0x585f, 2180), (0x5861, 2181), (0x5864, 2182), (0x5867, 2183), (0x5868, 2184),
+    (0x5878, 2185), (0x587c, 2186), (0x587f, 2187), (0x5880, 2188), (0x5881, 2189), (0x5887, 2190), (0x5888, 2191), (0x5889, 2192),
+    (0x588a, 2193), (0x588c, 2194), (0x588d, 2195), (0x588f, 2196), (0x5890, 2197), (0x5894, 2198), (0x5896, 2199), (0x589d, 2200),
+    (0x58a0, 2201), (0x58a1, 2202), (0x58a2, 2203), (0x58a6, 2204), (0x58a9, 2205), (0x58b1, 2206), (0x58b2, 2207), (0x58bc, 2209),
+    (0x58c2, 2210), (0x58c4, 2208), (0x58c8, 2211), (0x58cd, 2212), (0x58ce, 2213), (0x58d0, 2214), (0x58d2, 2215), (0x58d4, 2216),
+    (0x58d6, 2217), (0x58da, 2218), (0x58dd, 2219), (0x58e1, 2220), (0x58e2, 2221), (0x58e9, 2222), (0x58f3, 2223), (0x5905, 2224),
+    (0x5906, 2225), (0x590b, 2226), (0x590c, 2227), (0x5912, 2228), (0x5913, 2229), (0x5914, 2230), (0x591d, 2232), (0x5921, 2233),
+    (0x5923, 2234), (0x5924, 2235), (0x5928, 2236), (0x592f, 2237), (0x5930, 2238), (0x5933, 2239), (0x5935, 2240), (0x5936, 2241),
+    (0x593f, 2242), (0x5943, 2243), (0x5946, 2244), (0x5952, 2245), (0x5953, 2246), (0x5959, 2247), (0x595b, 2248), (0x595d, 2249),
+    (0x595e, 2250), (0x595f, 2251), (0x5961, 2252), (0x5963, 2253), (0x596b, 2254), (0x596d, 2255), (0x596f, 2256), (0x5972, 2257),
+    (0x5975, 2258), (0x5976, 2259), (0x5979, 2260), (0x597b, 2261), (0x597c, 2262), (0x598b, 2263), (0x598c, 2264), (0x598e, 2265),
+    (0x5992, 2266), (0x5995, 2267), (0x5997, 2268), (0x599f, 2269), (0x59a4, 2270), (0x59a7, 2271), (0x59ad, 2272), (0x59ae, 2273),
+    (0x59af, 2274), (0x59b0, 2275), (0x59b3, 2276), (0x59b7, 2277), (0x59ba, 2278), (0x59bc, 2279), (0x59c1, 2280), (0x59c3, 2281),
+    (0x59c4, 2282), (0x59c8, 2283), (0x59ca, 2284), (0x59cd, 2285), (0x59d2, 2286), (0x59dd, 2287), (0x59de, 2288), (0x59df, 2289),
+    (0x59e3, 2290), (0x59e4, 2291), (0x59e7, 2292), (0x59ee, 2293), (0x59ef, 2294), (0x59f1, 2295), (0x59f2, 2296), (0x59f4, 2297),
+    (0x59f7, 2298), (0x5a00, 2299), (0x5a04, 2300), (0x5a0c, 2301), (0x5a0d, 2302), (0x5a0e, 2303), (0x5a12, 2304), (0x5a13, 2305),
+    (0x5a1e, 2306), (0x5a23, 2307), (0x5a24, 2308), (0x5a27, 2309), (0x5a28, 2310), (0x5a2a, 2311), (0x5a2d, 2312), (0x5a30, 2313),
+    (0x5a44, 2314), (0x5a45, 2315), (0x5a47, 2316), (0x5a48, 2317), (0x5a4c, 2318), (0x5a50, 2319), (0x5a55, 2320), (0x5a5e, 2321),
+    (0x5a63, 2322), (0x5a65, 2323), (0x5a67, 2324), (0x5a6d, 2325), (0x5a77, 2326), (0x5a7a, 2327), (0x5a7b, 2328), (0x5a7e, 2329),
+    (0x5a8b, 2330), (0x5a90, 2331), (0x5a93, 2332), (0x5a96, 2333), (0x5a99, 2334), (0x5a9c, 2335), (0x5a9e, 2336), (0x5a9f, 2337),
+    (0x5aa0, 2338), (0x5aa2, 2339), (0x5aa7, 2340), (0x5aac, 2341), (0x5ab1, 2342), (0x5ab2, 2343), (0x5ab3, 2344), (0x5ab5, 2345),
+    (0x5ab8, 2346), (0x5aba, 2347), (0x5abb, 2348), (0x5abf, 2349), (0x5ac4, 2350), (0x5ac6, 2351), (0x5ac8, 2352), (0x5acf, 2353),
+    (0x5ada, 2354), (0x5adc, 2355), (0x5ae0, 2356), (0x5ae5, 2357), (0x5aea, 2358), (0x5aee, 2359), (0x5af5, 2360), (0x5af6, 2361),
+    (0x5afd, 2362), (0x5b00, 2363), (0x5b01, 2364), (0x5b08, 2365), (0x5b17, 2366), (0x5b19, 2368), (0x5b1b, 2369), (0x5b1d, 2370),
+    (0x5b21, 2371), (0x5b25, 2372), (0x5b2d, 2373), (0x5b34, 2367), (0x5b38, 2374), (0x5b41, 2375), (0x5b4b, 2376), (0x5b4c, 2377),
+    (0x5b52, 2378), (0x5b56, 2379), (0x5b5e, 2380), (0x5b68, 2381), (0x5b6e, 2382), (0x5b6f, 2383), (0x5b7c, 2384), (0x5b7d, 2385),
+    (0x5b7e, 2386), (0x5b7f, 2387), (0x5b81, 2388), (0x5b84, 2389), (0x5b86, 2390), (0x5b8a, 2391), (0x5b8e, 2392), (0x5b90, 2393),
+    (0x5b91, 2394), (0x5b93, 2395), (0x5b94, 2396), (0x5b96, 2397), (0x5ba8, 2398), (0x5ba9, 2399), (0x5bac, 2400), (0x5bad, 2401),
+    (0x5baf, 2402), (0x5bb1, 2403), (0x5bb2, 2404), (0x5bb7, 2405), (0x5bba, 2406), (0x5bbc, 2407), (0x5bc0, 2408), (0x5bc1, 2409),
+    (0x5bcd, 2410), (0x5bcf, 2411), (0x5bd6, 2412), (0x5bd7, 2413), (0x5bd8, 2414), (0x5bd9, 2415), (0x5bda, 2416), (0x5be0, 2417),
+    (0x5bef, 2418), (0x5bf1, 2419), (0x5bf4, 2420), (0x5bfd, 2421), (0x5c0c, 2422), (0x5c17, 2423), (0x5c1e, 2424), (0x5c1f, 2425),
+    (0x5c23, 2426), (0x5c26, 2427), (0x5c29, 2428), (0x5c2b, 2429), (0x5c2c, 2430), (0x5c2e, 2431), (0x5c30, 2432), (0x5c32, 2433),
+    (0x5c35, 2434), (0x5c36, 2435), (0x5c59, 2436), (0x5c5a, 2437), (0x5c5c, 2438), (0x5c62, 2439), (0x5c63, 2440), (0x5c67, 2441),
+    (0x5c68, 2442), (0x5c69, 2443), (0x5c6d, 2444), (0x5c70, 2445), (0x5c74, 2446), (0x5c75, 2447), (0x5c7a, 2448), (0x5c7b, 2449),
+    (0x5c7c, 2450), (0x5c7d, 2451), (0x5c87, 2452), (0x5c88, 2453), (0x5c8a, 2454), (0x5c8f, 2455), (0x5c92, 2456), (0x5c9d, 2457),
+    (0x5c9f, 2458), (0x5ca0, 2459), (0x5ca2, 2460), (0x5ca3, 2461), (0x5ca6, 2462), (0x5caa, 2463), (0x5cb2, 2464), (0x5cb4, 2465),
+    (0x5cb5, 2466), (0x5cba, 2467), (0x5cc9, 2468), (0x5ccb, 2469), (0x5cd2, 2470), (0x5cd7, 2472), (0x5cdd, 2471), (0x5cee, 2473),
+    (0x5cf1, 2474), (0x5cf2, 2475), (0x5cf4, 2476), (0x5d01, 2477), (0x5d06, 2478), (0x5d0d, 2479), (0x5d12, 2480), (0x5d23, 2482),
+    (0x5d24, 2483), (0x5d26, 2484), (0x5d27, 2485), (0x5d2b, 2481), (0x5d31, 2486), (0x5d34, 2487), (0x5d39, 2488), (0x5d3d, 2489),
+    (0x5d3f, 2490), (0x5d42, 2491), (0x5d43, 2492), (0x5d46, 2493), (0x5d48, 2494), (0x5d4a, 2498), (0x5d51, 2496), (0x5d55, 2495),
+    (0x5d59, 2497), (0x5d5f, 2499), (0x5d60, 2500), (0x5d61, 2501), (0x5d62, 2502), (0x5d64, 2503), (0x5d6a, 2504), (0x5d6d, 2505),
+    (0x5d70, 2506), (0x5d79, 2507), (0x5d7a, 2508), (0x5d7e, 2509), (0x5d7f, 2510), (0x5d81, 2511), (0x5d83, 2512), (0x5d88, 2513),
+    (0x5d8a, 2514), (0x5d92, 2515), (0x5d93, 2516), (0x5d94, 2517), (0x5d95, 2518), (0x5d99, 2519), (0x5d9b, 2520), (0x5d9f, 2521),
+    (0x5da0, 2522), (0x5da7, 2523), (0x5dab, 2524), (0x5db0, 2525), (0x5db2, 6569), (0x5db4, 2526), (0x5db8, 2527), (0x5db9, 2528),
+    (0x5dc3, 2529), (0x5dc7, 2530), (0x5dcb, 2531), (0x5dce, 2533), (0x5dd0, 2532), (0x5dd8, 2534), (0x5dd9, 2535), (0x5de0, 2536),
+    (0x5de4, 2537), (0x5de9, 2538), (0x5df8, 2539), (0x5df9, 2540), (0x5e00, 2541), (0x5e07, 2542), (0x5e0d, 2543), (0x5e12, 2544),
+    (0x5e14, 2545), (0x5e15, 2546), (0x5e18, 2547), (0x5e1f, 2548), (0x5e20, 2549), (0x5e28, 2551), (0x5e2e, 2550), (0x5e32, 2552),
+    (0x5e35, 2553), (0x5e3e, 2554), (0x5e49, 2557), (0x5e4b, 2555), (0x5e50, 2556), (0x5e51, 2558), (0x5e56, 2559), (0x5e58, 2560),
+    (0x5e5b, 2561), (0x5e5c, 2562), (0x5e5e, 2563), (0x5e68, 2564), (0x5e6a, 2565), (0x5e6b, 2566), (0x5e6c, 2567), (0x5e6d, 2568),
+    (0x5e6e, 2569), (0x5e70, 2570), (0x5e80, 2571), (0x5e8b, 2572), (0x5e8e, 2573), (0x5ea2, 2574), (0x5ea4, 2575), (0x5ea5, 2576),
+    (0x5ea8, 2577), (0x5eaa, 2578), (0x5eac, 2579), (0x5eb1, 2580), (0x5eb3, 2581), (0x5ebd, 2582), (0x5ebe, 2583), (0x5ebf, 2584),
+    (0x5ec6, 2585), (0x5ecb, 2587), (0x5ecc, 2586), (0x5ece, 2588), (0x5ed1, 2589), (0x5ed2, 2590), (0x5ed4, 2591), (0x5ed5, 2592),
+    (0x5edc, 2593), (0x5ede, 2594), (0x5ee5, 2595), (0x5eeb, 2596), (0x5f02, 2597), (0x5f06, 2598), (0x5f07, 2599), (0x5f08, 2600),
+    (0x5f0e, 2601), (0x5f19, 2602), (0x5f1c, 2603), (0x5f1d, 2604), (0x5f21, 2605), (0x5f22, 2606), (0x5f23, 2607), (0x5f24, 2608),
+    (0x5f28, 2609), (0x5f2b, 2610), (0x5f2c, 2611), (0x5f2e, 2612), (0x5f30, 2613), (0x5f34, 2614), (0x5f36, 2615), (0x5f3b, 2616),
+    (0x5f3d, 2617), (0x5f3f, 2618), (0x5f40, 2619), (0x5f44, 2620), (0x5f45, 2621), (0x5f47, 2622), (0x5f4d, 2623), (0x5f50, 2624),
+    (0x5f54, 2625), (0x5f58, 2626), (0x5f5b, 2627), (0x5f60, 2628), (0x5f63, 2629), (0x5f64, 2630), (0x5f67, 2631), (0x5f6f, 2632),
+    (0x5f72, 2633), (0x5f74, 2634), (0x5f75, 2635), (0x5f78, 2636), (0x5f7a, 2637), (0x5f7d, 2638), (0x5f7e, 2639), (0x5f89, 2640),
+    (0x5f8d, 2641), (0x5f8f, 2642), (0x5f96, 2643), (0x5f9c, 2644), (0x5f9d, 2645), (0x5fa2, 2646), (0x5fa4, 2649), (0x5fa7, 2647),
+    (0x5fab, 2648), (0x5fac, 2650), (0x5faf, 2651), (0x5fb0, 2652), (0x5fb1, 2653), (0x5fb8, 2654), (0x5fc4, 2655), (0x5fc7, 2656),
+    (0x5fc8, 2657), (0x5fc9, 2658), (0x5fcb, 2659), (0x5fd0, 2660), (0x5fd1, 2661), (0x5fd2, 2662), (0x5fd3, 2663), (0x5fd4, 2664),
+    (0x5fde, 2665), (0x5fe1, 2666), (0x5fe2, 2667), (0x5fe8, 2668), (0x5fe9, 2669), (0x5fea, 2670), (0x5fec, 2671), (0x5fed, 2672),
+    (0x5fee, 2673), (0x5fef, 2674), (0x5ff2, 2675), (0x5ff3, 2676), (0x5ff6, 2677), (0x5ffa, 2678), (0x5ffc, 2679), (0x6007, 2680),
+    (0x600a, 2681), (0x600d, 2682), (0x6013, 2683), (0x6014, 2684), (0x6017, 2685), (0x6018, 2686), (0x601a, 2687), (0x601f, 2688),
+    (0x6024, 2689), (0x602d, 2690), (0x6033, 2691), (0x6035, 2692), (0x6040, 2693), (0x6047, 2694), (0x6048, 2695), (0x6049, 2696),
+    (0x604c, 2697), (0x6051, 2698), (0x6054, 2699), (0x6056, 2700), (0x6057, 2701), (0x605d, 2702), (0x6061, 2703), (0x6067, 2704),
+    (0x6071, 2705), (0x607e, 2706), (0x607f, 2707), (0x6082, 2708), (0x6086, 2709), (0x6088, 2710), (0x608a, 2711), (0x608e, 2712),
+    (0x6091, 2713), (0x6093, 2714), (0x6095, 2715), (0x6098, 2716), (0x609d, 2717), (0x609e, 2718), (0x60a2, 2719), (0x60a4, 2720),
+    (0x60a5, 2721), (0x60a8, 2722), (0x60b0, 2723), (0x60b1, 2724), (0x60b7, 2725), (0x60bb, 2726), (0x60be, 2727), (0x60c2, 2728),
+    (0x60c4, 2729), (0x60c8, 2730), (0x60c9, 2731), (0x60ca, 2732), (0x60cb, 2733), (0x60ce, 2734), (0x60cf, 2735), (0x60d4, 2736),
+    (0x60d5, 2737), (0x60d9, 2738), (0x60db, 2739), (0x60dd, 2740), (0x60de, 2741), (0x60e2, 2742), (0x60e5, 2743), (0x60f2, 2744),
+    (0x60f5, 2745), (0x60f8, 2746), (0x60fc, 2747), (0x60fd, 2748), (0x6102, 2749), (0x6107, 2750), (0x610a, 2751), (0x610c, 2752),
+    (0x6110, 2753), (0x6111, 2754), (0x6112, 2755), (0x6113, 2756), (0x6114, 2757), (0x6116, 2758), (0x6117, 2759), (0x6119, 2760),
+    (0x611c, 2761), (0x611e, 2762), (0x6122, 2763), (0x612a, 2764), (0x612b, 2765), (0x6130, 2766), (0x6131, 2767), (0x6135, 2768),
+    (0x6136, 2769), (0x6137, 2770), (0x6139, 2771), (0x6141, 2772), (0x6145, 2773), (0x6146, 2774), (0x6149, 2775), (0x615e, 2776),
+    (0x6160, 2777), (0x616c, 2778), (0x6172, 2779), (0x6178, 2780), (0x617b, 2781), (0x617c, 2782), (0x617f, 2783), (0x6180, 2784),
+    (0x6181, 2785), (0x6183, 2786), (0x6184, 2787), (0x618b, 2788), (0x618d, 2789), (0x6192, 2790), (0x6193, 2791), (0x6197, 2792),
+    (0x6198, 2793), (0x619c, 2794), (0x619d, 2795), (0x619f, 2796), (0x61a0, 2797), (0x61a5, 2798), (0x61a8, 2799), (0x61aa, 2800),
+    (0x61ad, 2801), (0x61b8, 2802), (0x61b9, 2803), (0x61bc, 2804), (0x61c0, 2805), (0x61c1, 2806), (0x61c2, 2807), (0x61ce, 2808),
+    (0x61cf, 2809), (0x61d5, 2810), (0x61dc, 2811), (0x61dd, 2812), (0x61de, 2813), (0x61df, 2814), (0x61e1, 2815), (0x61e2, 2816),
+    (0x61e5, 2819), (0x61e7, 2817), (0x61e9, 2818), (0x61ec, 2820), (0x61ed, 2821), (0x61ef, 2822), (0x6201, 2823), (0x6203, 2824),
+    (0x6204, 2825), (0x6207, 2826), (0x6213, 2827), (0x6215, 2828), (0x621c, 2829), (0x6220, 2830), (0x6222, 2831), (0x6223, 2832),
+    (0x6227, 2833), (0x6229, 2834), (0x622b, 2835), (0x6239, 2836), (0x623d, 2837), (0x6242, 2838), (0x6243, 2839), (0x6244, 2840),
+    (0x6246, 2841), (0x624c, 2842), (0x6250, 2843), (0x6251, 2844), (0x6252, 2845), (0x6254, 2846), (0x6256, 2847), (0x625a, 2848),
+    (0x625c, 2849), (0x6264, 2850), (0x626d, 2851), (0x626f, 2852), (0x6273, 2853), (0x627a, 2854), (0x627d, 2855), (0x628d, 2856),
+    (0x628e, 2857), (0x628f, 2858), (0x6290, 2859), (0x62a6, 2860), (0x62a8, 2861), (0x62b3, 2862), (0x62b6, 2863), (0x62b7, 2864),
+    (0x62ba, 2865), (0x62be, 2866), (0x62bf, 2867), (0x62c4, 2868), (0x62ce, 2869), (0x62d5, 2870), (0x62d6, 2871), (0x62da, 2872),
+    (0x62ea, 2873), (0x62f2, 2874), (0x62f4, 2875), (0x62fc, 2876), (0x62fd, 2877), (0x6303, 2878), (0x6304, 2879), (0x630a, 2880),
+    (0x630b, 2881), (0x630d, 2882), (0x6310, 2883), (0x6313, 2884), (0x6316, 2885), (0x6318, 2886), (0x6329, 2887), (0x632a, 2888),
+    (0x632d, 2889), (0x6335, 2890), (0x6336, 2891), (0x6339, 2892), (0x633c, 2893), (0x6341, 2894), (0x6342, 2895), (0x6343, 2896),
+    (0x6344, 2897), (0x6346, 2898), (0x634a, 2899), (0x634b, 2900), (0x634e, 2901), (0x6352, 2902), (0x6353, 2903), (0x6354, 2904),
+    (0x6358, 2905), (0x635b, 2906), (0x6365, 2907), (0x6366, 2908), (0x636c, 2909), (0x636d, 2910), (0x6371, 2911), (0x6374, 2912),
+    (0x6375, 2913), (0x6378, 2914), (0x637c, 2915), (0x637d, 2916), (0x637f, 2917), (0x6382, 2918), (0x6384, 2919), (0x6387, 2920),
+    (0x638a, 2921), (0x6390, 2922), (0x6394, 2923), (0x6395, 2924), (0x6399, 2925), (0x639a, 2926), (0x639e, 2927), (0x63a4, 2928),
+    (0x63a6, 2929), (0x63ad, 2930), (0x63ae, 2931), (0x63af, 2932), (0x63bd, 2933), (0x63c1, 2934), (0x63c5, 2935), (0x63c8, 2936),
+    (0x63ce, 2937), (0x63d1, 2938), (0x63d3, 2939), (0x63d4, 2940), (0x63d5, 2941), (0x63dc, 2942), (0x63e0, 2943), (0x63e5, 2944),
+    (0x63ea, 2945), (0x63ec, 2946), (0x63f2, 2947), (0x63f3, 2948), (0x63f5, 2949), (0x63f8, 2950), (0x63f9, 2951), (0x6409, 2952),
+    (0x640a, 2953), (0x6410, 2954), (0x6412, 2955), (0x6414, 2956), (0x6418, 2957), (0x641e, 2958), (0x6420, 2959), (0x6422, 2960),
+    (0x6424, 2961), (0x6425, 2962), (0x6429, 2963), (0x642a, 2964), (0x642f, 2965), (0x6430, 2966), (0x6435, 2967), (0x643d, 2968),
+    (0x643f, 2969), (0x644b, 2970), (0x644f, 2971), (0x6451, 2972), (0x6452, 2973), (0x6453, 2974), (0x6454, 2975), (0x645a, 2976),
+    (0x645b, 2977), (0x645c, 2978), (0x645d, 2979), (0x645f, 2980), (0x6460, 2981), (0x6461, 2982), (0x6463, 2983), (0x646d, 2984),
+    (0x6473, 2985), (0x6474, 2986), (0x647b, 2987), (0x647d, 2988), (0x6485, 2989), (0x6487, 2990), (0x648f, 2991), (0x6490, 2992),
+    (0x6491, 2993), (0x6498, 2994), (0x6499, 2995), (0x649b, 2996), (0x649d, 2997), (0x649f, 2998), (0x64a1, 2999), (0x64a3, 3000),
+    (0x64a6, 3001), (0x64a8, 3002), (0x64ac, 3003), (0x64b3, 3004), (0x64bd, 3005), (0x64be, 3006), (0x64bf, 3007), (0x64c4, 3008),
+    (0x64c9, 3009), (0x64ca, 3010), (0x64cb, 3011), (0x64cc, 3012), (0x64ce, 3013), (0x64d0, 3014), (0x64d1, 3015), (0x64d5, 3016),
+    (0x64d7, 3017), (0x64e4, 3018), (0x64e5, 3019), (0x64e9, 3020), (0x64ea, 3021), (0x64ed, 3022), (0x64f0, 3023), (0x64f5, 3024),
+    (0x64f7, 3025), (0x64fb, 3026), (0x64ff, 3027), (0x6501, 3028), (0x6504, 3029), (0x6508, 3030), (0x6509, 3031), (0x650a, 3032),
+    (0x650f, 3033), (0x6513, 3034), (0x6514, 3035), (0x6516, 3036), (0x6519, 3037), (0x651b, 3038), (0x651e, 3039), (0x651f, 3040),
+    (0x6522, 3041), (0x6526, 3042), (0x6529, 3043), (0x652e, 3044), (0x6531, 3045), (0x653a, 3046), (0x653c, 3047), (0x653d, 3048),
+    (0x6543, 3049), (0x6547, 3050), (0x6549, 3051), (0x6550, 3052), (0x6552, 3053), (0x6554, 3054), (0x655f, 3055), (0x6560, 3056),
+    (0x6567, 3057), (0x656b, 3058), (0x657a, 3059), (0x657d, 3060), (0x6581, 3061), (0x6585, 3062), (0x658a, 3063), (0x6592, 3064),
+    (0x6595, 3065), (0x6598, 3066), (0x659d, 3067), (0x65a0, 3068), (0x65a3, 3069), (0x65a6, 3070), (0x65ae, 3071), (0x65b2, 3072),
+    (0x65b3, 3073), (0x65b4, 3074), (0x65bf, 3075), (0x65c2, 3076), (0x65c8, 3077), (0x65c9, 3078), (0x65ce, 3079), (0x65d0, 3080),
+    (0x65d4, 3081), (0x65d6, 3082), (0x65d8, 3083), (0x65df, 3084), (0x65f0, 3085), (0x65f2, 3086), (0x65f4, 3087), (0x65f5, 3088),
+    (0x65f9, 3089), (0x65fe, 3090), (0x65ff, 3091), (0x6600, 3092), (0x6604, 3093), (0x6608, 3094), (0x6609, 3095), (0x660d, 3096),
+    (0x6611, 3097), (0x6612, 3098), (0x6615, 3099), (0x6616, 3100), (0x661d, 3101), (0x661e, 3102), (0x6621, 3103), (0x6622, 3104),
+    (0x6623, 3105), (0x6624, 3106), (0x6626, 3107), (0x6629, 3108), (0x662a, 3109), (0x662b, 3110), (0x662c, 3111), (0x662e, 3112),
+    (0x6630, 3113), (0x6631, 3114), (0x6633, 3115), (0x6637, 3117), (0x6639, 3116), (0x6640, 3118), (0x6645, 3119), (0x6646, 3120),
+    (0x664a, 3121), (0x664c, 3122), (0x664e, 3124), (0x6651, 3123), (0x6657, 3125), (0x6658, 3126), (0x6659, 3127), (0x665b, 3128),
+    (0x665c, 3129), (0x6660, 3130), (0x6661, 3131), (0x666a, 3133), (0x666b, 3134), (0x666c, 3135), (0x6673, 3137), (0x6675, 3138),
+    (0x6677, 3140), (0x6678, 3141), (0x6679, 3142), (0x667b, 3143), (0x667c, 3145), (0x667e, 3136), (0x667f, 3139), (0x6680, 3144),
+    (0x668b, 3146), (0x668c, 3147), (0x668d, 3148), (0x6690, 3149), (0x6692, 3150), (0x6699, 3151), (0x669a, 3152), (0x669b, 3153),
+    (0x669c, 3154), (0x669f, 3155), (0x66a0, 3156), (0x66a4, 3157), (0x66ad, 3158), (0x66b1, 3159), (0x66b2, 3160), (0x66b5, 3161),
+    (0x66bb, 3162), (0x66bf, 3163), (0x66c0, 3164), (0x66c2, 3165), (0x66c3, 3166), (0x66c8, 3167), (0x66cc, 3168), (0x66ce, 3169),
+    (0x66cf, 3170), (0x66d4, 3171), (0x66db, 3172), (0x66df, 3173), (0x66e8, 3174), (0x66eb, 3175), (0x66ec, 3176), (0x66ee, 3177),
+    (0x66fa, 3178), (0x66fb, 3132), (0x6705, 3179), (0x6707, 3180), (0x670e, 3181), (0x6713, 3182), (0x6719, 3183), (0x671c, 3184),
+    (0x6720, 3185), (0x6722, 3186), (0x6733, 3187), (0x673e, 3188), (0x6745, 3189), (0x6747, 3190), (0x6748, 3191), (0x674c, 3192),
+    (0x6754, 3193), (0x6755, 3194), (0x675d, 3195), (0x6766, 3196), (0x676c, 3197), (0x676e, 3198), (0x6774, 3199), (0x6776, 3200),
+    (0x677b, 3201), (0x6781, 3202), (0x6784, 3203), (0x678e, 3204), (0x678f, 3205), (0x6791, 3206), (0x6793, 3207), (0x6796, 3208),
+    (0x6798, 3209), (0x6799, 3210), (0x679b, 3211), (0x67b0, 3212), (0x67b1, 3213), (0x67b2, 3214), (0x67b5, 3215), (0x67bb, 3216),
+    (0x67bc, 3217), (0x67bd, 3218), (0x67c0, 3220), (0x67c2, 3221), (0x67c3, 3222), (0x67c5, 3223), (0x67c8, 3224), (0x67c9, 3225),
+    (0x67d2, 3226), (0x67d7, 3227), (0x67d9, 3228), (0x67dc, 3229), (0x67e1, 3230), (0x67e6, 3231), (0x67f0, 3232), (0x67f2, 3233),
+    (0x67f6, 3234), (0x67f7, 3235), (0x67f9, 3219), (0x6814, 3237), (0x6819, 3238), (0x681d, 3239), (0x681f, 3240), (0x6827, 3242),
+    (0x6828, 3241), (0x682c, 3243), (0x682d, 3244), (0x682f, 3245), (0x6830, 3246), (0x6831, 3247), (0x6833, 3248), (0x683b, 3249),
+    (0x683f, 3250), (0x6844, 3251), (0x6845, 3252), (0x684a, 3253), (0x684c, 3254), (0x6852, 3236), (0x6855, 3255), (0x6857, 3256),
+    (0x6858, 3257), (0x685b, 3258), (0x686b, 3259), (0x686e, 3260), (0x686f, 3261), (0x6870, 3262), (0x6871, 3263), (0x6872, 3264),
+    (0x6875, 3265), (0x6879, 3266), (0x687a, 3267), (0x687b, 3268), (0x687c, 3269), (0x6882, 3270), (0x6884, 3271), (0x6886, 3272),
+    (0x6888, 3273), (0x6896, 3274), (0x6898, 3275), (0x689a, 3276), (0x689c, 3277), (0x68a1, 3278), (0x68a3, 3279), (0x68a5, 3280),
+    (0x68a9, 3281), (0x68aa, 3282), (0x68ae, 3283), (0x68b2, 3284), (0x68bb, 3285), (0x68c5, 3286), (0x68c8, 3287), (0x68cc, 3288),
+    (0x68cf, 3289), (0x68d0, 3290), (0x68d1, 3291), (0x68d3, 3292), (0x68d6, 3293), (0x68d9, 3294), (0x68dc, 3295), (0x68dd, 3296),
+    (0x68e5, 3297), (0x68e8, 3298), (0x68ea, 3299), (0x68eb, 3300), (0x68ec, 3301), (0x68ed, 3302), (0x68f0, 3303), (0x68f1, 3304),
+    (0x68f5, 3305), (0x68f6, 3306), (0x68fb, 3307), (0x68fc, 3308), (0x68fd, 3309), (0x6906, 3310), (0x6909, 3311), (0x690a, 3312),
+    (0x6910, 3313), (0x6911, 3314), (0x6913, 3315), (0x6916, 3316), (0x6917, 3317), (0x6931, 3318), (0x6933, 3319), (0x6935, 3320),
+    (0x6938, 3321), (0x693b, 3322), (0x6942, 3323), (0x6945, 3324), (0x6949, 3325), (0x694e, 3326), (0x6957, 3327), (0x695b, 3328),
+    (0x6963, 3329), (0x6964, 3330), (0x6965, 3331), (0x6966, 3332), (0x6968, 3333), (0x6969, 3334), (0x696c, 3335), (0x6970, 3336),
+    (0x6971, 3337), (0x6972, 3338), (0x697a, 3339), (0x697b, 3340), (0x697f, 3341), (0x6980, 3342), (0x698d, 3343), (0x6992, 3344),
+    (0x6996, 3345), (0x6998, 3346), (0x69a1, 3347), (0x69a5, 3348), (0x69a6, 3349), (0x69a8, 3350), (0x69ab, 3351), (0x69ad, 3352),
+    (0x69af, 3353), (0x69b7, 3354), (0x69b8, 3355), (0x69ba, 3356), (0x69bc, 3357), (0x69c5, 3358), (0x69c8, 3359), (0x69d1, 3360),
+    (0x69d6, 3361), (0x69d7, 3362), (0x69e2, 3363), (0x69e5, 3364), (0x69ee, 3365), (0x69ef, 3366), (0x69f1, 3367), (0x69f3, 3368),
+    (0x69f5, 3369), (0x69fe, 3370), (0x6a00, 3371), (0x6a01, 3372), (0x6a03, 3373), (0x6a0f, 3374), (0x6a11, 3375), (0x6a15, 3376),
+    (0x6a1a, 3377), (0x6a1d, 3378), (0x6a20, 3379), (0x6a24, 3380), (0x6a28, 3381), (0x6a30, 3382), (0x6a32, 3383), (0x6a34, 3384),
+    (0x6a37, 3385), (0x6a3b, 3386), (0x6a3e, 3387), (0x6a3f, 3388), (0x6a45, 3389), (0x6a46, 3390), (0x6a49, 3391), (0x6a4a, 3392),
+    (0x6a4e, 3393), (0x6a50, 3394), (0x6a51, 3395), (0x6a52, 3396), (0x6a55, 3397), (0x6a56, 3398), (0x6a5b, 3399), (0x6a64, 3400),
+    (0x6a67, 3401), (0x6a6a, 3402), (0x6a71, 3403), (0x6a73, 3404), (0x6a7e, 3405), (0x6a81, 3406), (0x6a83, 3407), (0x6a86, 3408),
+    (0x6a87, 3409), (0x6a89, 3410), (0x6a8b, 3411), (0x6a91, 3412), (0x6a9b, 3413), (0x6a9d, 3414), (0x6a9e, 3415), (0x6a9f, 3416),
+    (0x6aa5, 3417), (0x6aab, 3418), (0x6aaf, 3419), (0x6ab0, 3420), (0x6ab1, 3421), (0x6ab4, 3422), (0x6abd, 3423), (0x6abe, 3424),
+    (0x6abf, 3425), (0x6ac6, 3426), (0x6ac8, 3428), (0x6ac9, 3427), (0x6acc, 3429), (0x6ad0, 3430), (0x6ad4, 3431), (0x6ad5, 3432),
+    (0x6ad6, 3433), (0x6adc, 3434), (0x6add, 3435), (0x6ae4, 3436), (0x6ae7, 3437), (0x6aec, 3438), (0x6af0, 3439), (0x6af1, 3440),
+    (0x6af2, 3441), (0x6afc, 3442), (0x6afd, 3443), (0x6b02, 3444), (0x6b03, 3445), (0x6b06, 3446), (0x6b07, 3447), (0x6b09, 3448),
+    (0x6b0f, 3449), (0x6b10, 3450), (0x6b11, 3451), (0x6b17, 3452), (0x6b1b, 3453), (0x6b1e, 3454), (0x6b24, 3455), (0x6b28, 3456),
+    (0x6b2b, 3457), (0x6b2c, 3458), (0x6b2f, 3459), (0x6b35, 3460), (0x6b36, 3461), (0x6b3b, 3462), (0x6b3f, 3463), (0x6b46, 3464),
+    (0x6b4a, 3465), (0x6b4d, 3466), (0x6b52, 3467), (0x6b56, 3468), (0x6b58, 3469), (0x6b5d, 3470), (0x6b60, 3471), (0x6b67, 3472),
+    (0x6b6b, 3473), (0x6b6e, 3474), (0x6b70, 3475), (0x6b75, 3476), (0x6b7d, 3477), (0x6b7e, 3478), (0x6b82, 3479), (0x6b85, 3480),
+    (0x6b97, 3481), (0x6b9b, 3482), (0x6b9f, 3483), (0x6ba0, 3484), (0x6ba2, 3485), (0x6ba3, 3486), (0x6ba8, 3487), (0x6ba9, 3488),
+    (0x6bac, 3489), (0x6bad, 3490), (0x6bae, 3491), (0x6bb0, 3492), (0x6bb8, 3493), (0x6bb9, 3494), (0x6bbd, 3495), (0x6bbe, 3496),
+    (0x6bc3, 3497), (0x6bc4, 3498), (0x6bc9, 3499), (0x6bcc, 3500), (0x6bd6, 3501), (0x6bda, 3502), (0x6be1, 3503), (0x6be3, 3504),
+    (0x6be6, 3505), (0x6be7, 3506), (0x6bee, 3507), (0x6bf1, 3508), (0x6bf7, 3509), (0x6bf9, 3510), (0x6bff, 3511), (0x6c02, 3512),
+    (0x6c04, 3513), (0x6c05, 3514), (0x6c09, 3515), (0x6c0d, 3516), (0x6c0e, 3517), (0x6c10, 3518), (0x6c12, 3519), (0x6c19, 3520),
+    (0x6c1f, 3521), (0x6c26, 3522), (0x6c27, 3523), (0x6c28, 3524), (0x6c2c, 3525), (0x6c2e, 3526), (0x6c33, 3527), (0x6c35, 3528),
+    (0x6c36, 3529), (0x6c3a, 3530), (0x6c3b, 3531), (0x6c3f, 3532), (0x6c4a, 3533), (0x6c4b, 3534), (0x6c4d, 3535), (0x6c4f, 3536),
+    (0x6c52, 3537), (0x6c54, 3538), (0x6c59, 3539), (0x6c5b, 3540), (0x6c5c, 3541), (0x6c67, 3593), (0x6c6b, 3542), (0x6c6d, 3543),
+    (0x6c6f, 3544), (0x6c74, 3545), (0x6c76, 3546), (0x6c78, 3547), (0x6c79, 3548), (0x6c7b, 3549), (0x6c85, 3550), (0x6c86, 3551),
+    (0x6c87, 3552), (0x6c89, 3553), (0x6c94, 3554), (0x6c95, 3555), (0x6c97, 3556), (0x6c98, 3557), (0x6c9c, 3558), (0x6c9f, 3559),
+    (0x6cb0, 3560), (0x6cb2, 3561), (0x6cb4, 3562), (0x6cc2, 3563), (0x6cc6, 3564), (0x6ccd, 3565), (0x6ccf, 3566), (0x6cd0, 3567),
+    (0x6cd1, 3568), (0x6cd2, 3569), (0x6cd4, 3570), (0x6cd6, 3571), (0x6cda, 3572), (0x6cdc, 3573), (0x6ce0, 3574), (0x6ce7, 3575),
+    (0x6ce9, 3576), (0x6ceb, 3577), (0x6cec, 3578), (0x6cee, 3579), (0x6cf2, 3580), (0x6cf4, 3581), (0x6d04, 3582), (0x6d07, 3583),
+    (0x6d0a, 3584), (0x6d0e, 3585), (0x6d0f, 3586), (0x6d11, 3587), (0x6d13, 3588), (0x6d1a, 3589), (0x6d26, 3590), (0x6d27, 3591),
+    (0x6d28, 3592), (0x6d2e, 3594), (0x6d2f, 3595), (0x6d31, 3596), (0x6d39, 3597), (0x6d3c, 3598), (0x6d3f, 3599), (0x6d57, 3600),
+    (0x6d5e, 3601), (0x6d5f, 3602), (0x6d61, 3603), (0x6d65, 3604), (0x6d67, 3605), (0x6d6f, 3606), (0x6d70, 3607), (0x6d7c, 3608),
+    (0x6d82, 3609), (0x6d87, 3610), (0x6d91, 3611), (0x6d92, 3612), (0x6d94, 3613), (0x6d96, 3614), (0x6d97, 3615), (0x6d98, 3616),
+    (0x6daa, 3617), (0x6dac, 3618), (0x6db4, 3619), (0x6db7, 3620), (0x6db9, 3621), (0x6dbd, 3622), (0x6dbf, 3623), (0x6dc4, 3624),
+    (0x6dc8, 3625), (0x6dca, 3626), (0x6dce, 3627), (0x6dcf, 3628), (0x6dd6, 3629), (0x6ddb, 3630), (0x6ddd, 3631), (0x6ddf, 3632),
+    (0x6de0, 3633), (0x6de2, 3634), (0x6de5, 3635), (0x6de9, 3636), (0x6def, 3637), (0x6df0, 3638), (0x6df4, 3639), (0x6df6, 3640),
+    (0x6dfc, 3641), (0x6e00, 3642), (0x6e04, 3643), (0x6e1e, 3644), (0x6e22, 3645), (0x6e27, 3646), (0x6e32, 3647), (0x6e36, 3648),
+    (0x6e39, 3649), (0x6e3b, 3650), (0x6e3c, 3651), (0x6e44, 3652), (0x6e45, 3653), (0x6e48, 3654), (0x6e49, 3655), (0x6e4b, 3656),
+    (0x6e4f, 3657), (0x6e51, 3658), (0x6e52, 3659), (0x6e53, 3660), (0x6e54, 3661), (0x6e57, 3662), (0x6e5c, 3663), (0x6e5d, 3664),
+    (0x6e5e, 3665), (0x6e62, 3666), (0x6e63, 3667), (0x6e68, 3668), (0x6e73, 3669), (0x6e7b, 3670), (0x6e7d, 3671), (0x6e8d, 3672),
+    (0x6e93, 3673), (0x6e99, 3674), (0x6ea0, 3675), (0x6ea7, 3676), (0x6ead, 3677), (0x6eae, 3678), (0x6eb1, 3679), (0x6eb3, 3680),
+    (0x6ebb, 3681), (0x6ebf, 3682), (0x6ec0, 3683), (0x6ec1, 3684), (0x6ec3, 3685), (0x6ec7, 3686), (0x6ec8, 3687), (0x6eca, 3688),
+    (0x6ecd, 3689), (0x6ece, 3690), (0x6ecf, 3691), (0x6eeb, 3692), (0x6eed, 3693), (0x6eee, 3694), (0x6ef9, 3695), (0x6efb, 3696),
+    (0x6efd, 3697), (0x6f04, 3698), (0x6f08, 3699), (0x6f0a, 3700), (0x6f0c, 3701), (0x6f0d, 3702), (0x6f16, 3703), (0x6f18, 3704),
+    (0x6f1a, 3705), (0x6f1b, 3706), (0x6f26, 3707), (0x6f29, 3708), (0x6f2a, 3709), (0x6f2d, 3716), (0x6f2f, 3710), (0x6f30, 3711),
+    (0x6f33, 3712), (0x6f36, 3713), (0x6f3b, 3714), (0x6f3c, 3715), (0x6f4f, 3717), (0x6f51, 3718), (0x6f52, 3719), (0x6f53, 3720),
+    (0x6f57, 3721), (0x6f59, 3722), (0x6f5a, 3723), (0x6f5d, 3724), (0x6f5e, 3725), (0x6f61, 3726), (0x6f62, 3727), (0x6f68, 3728),
+    (0x6f6c, 3729), (0x6f7d, 3730), (0x6f7e, 3731), (0x6f83, 3732), (0x6f87, 3733), (0x6f88, 3734), (0x6f8b, 3735), (0x6f8c, 3736),
+    (0x6f8d, 3737), (0x6f90, 3738), (0x6f92, 3739), (0x6f93, 3740), (0x6f94, 3741), (0x6f96, 3742), (0x6f9a, 3743), (0x6f9f, 3744),
+    (0x6fa0, 3745), (0x6fa5, 3746), (0x6fa6, 3747), (0x6fa7, 3748), (0x6fa8, 3749), (0x6fae, 3750), (0x6faf, 3751), (0x6fb0, 3752),
+    (0x6fb5, 3753), (0x6fb6, 3754), (0x6fbc, 3755), (0x6fc5, 3756), (0x6fc7, 3757), (0x6fc8, 3758), (0x6fca, 3759), (0x6fda, 3760),
+    (0x6fde, 3761), (0x6fe8, 3762), (0x6fe9, 3763), (0x6ff0, 3764), (0x6ff5, 3765), (0x6ff9, 3766), (0x6ffc, 3767), (0x6ffd, 3768),
+    (0x7000, 3769), (0x7005, 3770), (0x7006, 3771), (0x7007, 3772), (0x700d, 3773), (0x7017, 3774), (0x7020, 3775), (0x7023, 3776),
+    (0x702f, 3777), (0x7034, 3778), (0x7037, 3779), (0x7039, 3780), (0x703c, 3781), (0x7043, 3782), (0x7044, 3783), (0x7048, 3784),
+    (0x7049, 3785), (0x704a, 3786), (0x704b, 3787), (0x704e, 3792), (0x7054, 3788), (0x7055, 3789), (0x705d, 3790), (0x705e, 3791),
+    (0x7064, 3793), (0x7065, 3794), (0x706c, 3795), (0x706e, 3796), (0x7075, 3797), (0x7076, 3798), (0x707e, 3799), (0x7081, 3800),
+    (0x7085, 3801), (0x7086, 3802), (0x7094, 3803), (0x7095, 3804), (0x7096, 3805), (0x7097, 3806), (0x7098, 3807), (0x709b, 3808),
+    (0x70a4, 3809), (0x70ab, 3810), (0x70b0, 3811), (0x70b1, 3812), (0x70b4, 3813), (0x70b7, 3814), (0x70ca, 3815), (0x70d1, 3816),
+    (0x70d3, 3817), (0x70d4, 3818), (0x70d5, 3819), (0x70d6, 3820), (0x70d8, 3821), (0x70dc, 3822), (0x70e4, 3823), (0x70fa, 3824),
+    (0x7103, 3825), (0x7104, 3826), (0x7105, 3827), (0x7106, 3828), (0x7107, 3829), (0x710b, 3830), (0x710c, 3831), (0x710f, 3832),
+    (0x711e, 3833), (0x7120, 3834), (0x712b, 3835), (0x712d, 3836), (0x712f, 3837), (0x7130, 3838), (0x7131, 3839), (0x7138, 3840),
+    (0x7141, 3841), (0x7145, 3842), (0x7146, 3843), (0x7147, 3844), (0x714a, 3845), (0x714b, 3846), (0x7150, 3847), (0x7152, 3848),
+    (0x7157, 3849), (0x715a, 3850), (0x715c, 3851), (0x715e, 3852), (0x7160, 3853), (0x7168, 3854), (0x7179, 3855), (0x7180, 3856),
+    (0x7185, 3857), (0x7187, 3858), (0x718c, 3859), (0x7192, 3860), (0x719a, 3861), (0x719b, 3862), (0x71a0, 3863), (0x71a2, 3864),
+    (0x71af, 3865), (0x71b0, 3866), (0x71b2, 3867), (0x71b3, 3868), (0x71ba, 3869), (0x71bf, 3870), (0x71c0, 3871), (0x71c1, 3872),
+    (0x71c4, 3873), (0x71cb, 3874), (0x71cc, 3875), (0x71d3, 3876), (0x71d6, 3877), (0x71d9, 3878), (0x71da, 3879), (0x71dc, 3880),
+    (0x71f8, 3881), (0x71fe, 3882), (0x7200, 3883), (0x7207, 3884), (0x7208, 3885), (0x7209, 3886), (0x7213, 3887), (0x7217, 3888),
+    (0x721a, 3889), (0x721d, 3890), (0x721f, 3891), (0x7224, 3892), (0x722b, 3893), (0x722f, 3894), (0x7234, 3895), (0x7238, 3896),
+    (0x7239, 3897), (0x7241, 3898), (0x7242, 3899), (0x7243, 3900), (0x7245, 3901), (0x724e, 3902), (0x724f, 3903), (0x7250, 3904),
+    (0x7253, 3905), (0x7255, 3906), (0x7256, 3907), (0x725a, 3908), (0x725c, 3909), (0x725e, 3910), (0x7260, 3911), (0x7263, 3912),
+    (0x7268, 3913), (0x726b, 3914), (0x726e, 3915), (0x726f, 3916), (0x7271, 3917), (0x7277, 3918), (0x7278, 3919), (0x727b, 3920),
+    (0x727c, 3921), (0x727f, 3922), (0x7284, 3923), (0x7289, 3924), (0x728d, 3925), (0x728e, 3926), (0x7293, 3927), (0x729b, 3928),
+    (0x72a8, 3929), (0x72ad, 3930), (0x72ae, 3931), (0x72b1, 3932), (0x72b4, 3933), (0x72be, 3934), (0x72c1, 3935), (0x72c7, 3936),
+    (0x72c9, 3937), (0x72cc, 3938), (0x72d5, 3939), (0x72d6, 3940), (0x72d8, 3941), (0x72df, 3942), (0x72e5, 3943), (0x72f3, 3944),
+    (0x72f4, 3945), (0x72fa, 3946), (0x72fb, 3947), (0x72fe, 3948), (0x7302, 3949), (0x7304, 3950), (0x7305, 3951), (0x7307, 3952),
+    (0x730b, 3953), (0x730d, 3954), (0x7312, 3955), (0x7313, 3956), (0x7318, 3957), (0x7319, 3958), (0x731e, 3959), (0x7322, 3960),
+    (0x7324, 3961), (0x7327, 3962), (0x7328, 3963), (0x732c, 3964), (0x7331, 3965), (0x7332, 3966), (0x7335, 3967), (0x733a, 3968),
+    (0x733b, 3969), (0x733d, 3970), (0x7343, 3971), (0x734d, 3972), (0x7350, 3973), (0x7352, 3974), (0x7356, 3975), (0x7358, 3976),
+    (0x735d, 3977), (0x735e, 3978), (0x735f, 3979), (0x7360, 3980), (0x7366, 3981), (0x7367, 3982), (0x7369, 3983), (0x736b, 3984),
+    (0x736c, 3985), (0x736e, 3986), (0x736f, 3987), (0x7371, 3988), (0x7377, 3989), (0x7379, 3990), (0x737c, 3991), (0x7380, 3992),
+    (0x7381, 3993), (0x7383, 3994), (0x7385, 3995), (0x7386, 3996), (0x738e, 3997), (0x7390, 3998), (0x7393, 3999), (0x7395, 4000),
+    (0x7397, 4001), (0x7398, 4002), (0x739c, 4003), (0x739e, 4004), (0x739f, 4005), (0x73a0, 4006), (0x73a2, 4007), (0x73a5, 4008),
+    (0x73a6, 4009), (0x73aa, 4010), (0x73ab, 4011), (0x73ad, 4012), (0x73b5, 4013), (0x73b7, 4014), (0x73b9, 4015), (0x73bc, 4016),
+    (0x73bd, 4017), (0x73bf, 4018), (0x73c5, 4019), (0x73c6, 4020), (0x73c9, 4021), (0x73cb, 4022), (0x73cc, 4023), (0x73cf, 4024),
+    (0x73d2, 4025), (0x73d3, 4026), (0x73d6, 4027), (0x73d9, 4028), (0x73dd, 4029), (0x73e1, 4030), (0x73e3, 4031), (0x73e6, 4032),
+    (0x73e7, 4033), (0x73e9, 4034), (0x73f4, 4035), (0x73f5, 4036), (0x73f7, 4037), (0x73f9, 4038), (0x73fa, 4039), (0x73fb, 4040),
+    (0x73fd, 4041), (0x73ff, 4042), (0x7400, 4043), (0x7401, 4044), (0x7404, 4045), (0x7407, 4046), (0x740a, 4047), (0x7411, 4048),
+    (0x741a, 4049), (0x741b, 4050), (0x7424, 4051), (0x7426, 4052), (0x7428, 4053), (0x7429, 4054), (0x742a, 4055), (0x742b, 4056),
+    (0x742c, 4057), (0x742d, 4058), (0x742e, 4059), (0x742f, 4060), (0x7430, 4061), (0x7431, 4062), (0x7439, 4063), (0x7440, 4064),
+    (0x7443, 4065), (0x7444, 4066), (0x7446, 4067), (0x7447, 4068), (0x744b, 4069), (0x744d, 4070), (0x7451, 4071), (0x7452, 4072),
+    (0x7457, 4073), (0x745d, 4074), (0x7462, 4075), (0x7466, 4076), (0x7467, 4077), (0x7468, 4078), (0x746b, 4079), (0x746d, 4080),
+    (0x746e, 4081), (0x7471, 4082), (0x7472, 4083), (0x7480, 4084), (0x7481, 4085), (0x7485, 4086), (0x7486, 4087), (0x7487, 4088),
+    (0x7489, 4089), (0x748f, 4090), (0x7490, 4091), (0x7491, 4092), (0x7492, 4093), (0x7498, 4094), (0x7499, 4095), (0x749a, 4096),
+    (0x749c, 4097), (0x749f, 4098), (0x74a0, 4099), (0x74a1, 4100), (0x74a3, 4101), (0x74a6, 4102), (0x74a8, 4103), (0x74a9, 4104),
+    (0x74aa, 4105), (0x74ab, 4106), (0x74ae, 4107), (0x74af, 4108), (0x74b1, 4109), (0x74b2, 4110), (0x74b5, 4111), (0x74b9, 4112),
+    (0x74bb, 4113), (0x74bf, 4114), (0x74c8, 4115), (0x74c9, 4116), (0x74cc, 4117), (0x74d0, 4118), (0x74d3, 4119), (0x74d8, 4120),
+    (0x74da, 4121), (0x74db, 4122), (0x74de, 4123), (0x74df, 4124), (0x74e4, 4125), (0x74e8, 4126), (0x74ea, 4127), (0x74eb, 4128),
+    (0x74ef, 4129), (0x74f4, 4130), (0x74fa, 4131), (0x74fb, 4132), (0x74fc, 4133), (0x74ff, 4134), (0x7506, 4135), (0x7512, 4136),
+    (0x7516, 4137), (0x7517, 4138), (0x7520, 4139), (0x7521, 4140), (0x7524, 4141), (0x7527, 4142), (0x7529, 4143), (0x752a, 4144),
+    (0x752f, 4145), (0x7536, 4146), (0x7539, 4147), (0x753d, 4148), (0x753e, 4149), (0x753f, 4150), (0x7540, 4151), (0x7543, 4152),
+    (0x7547, 4153), (0x7548, 4154), (0x754e, 4155), (0x7550, 4156), (0x7552, 4157), (0x7557, 4158), (0x755e, 4159), (0x755f, 4160),
+    (0x7561, 4161), (0x756f, 4162), (0x7571, 4163), (0x7579, 4164), (0x757a, 4165), (0x757b, 4166), (0x757c, 4167), (0x757d, 4168),
+    (0x757e, 4169), (0x7581, 4170), (0x7585, 4171), (0x7590, 4172), (0x7592, 4173), (0x7593, 4174), (0x7595, 4175), (0x7599, 4176),
+    (0x759c, 4177), (0x75a2, 4178), (0x75a4, 4179), (0x75b4, 4180), (0x75ba, 4181), (0x75bf, 4182), (0x75c0, 4183), (0x75c1, 4184),
+    (0x75c4, 4185), (0x75c6, 4186), (0x75cc, 4187), (0x75ce, 4188), (0x75cf, 4189), (0x75d7, 4190), (0x75dc, 4191), (0x75df, 4192),
+    (0x75e0, 4193), (0x75e1, 4194), (0x75e4, 4195), (0x75e7, 4196), (0x75ec, 4197), (0x75ee, 4198), (0x75ef, 4199), (0x75f1, 4200),
+    (0x75f9, 4201), (0x7600, 4202), (0x7602, 4203), (0x7603, 4204), (0x7604, 4205), (0x7607, 4206), (0x7608, 4207), (0x760a, 4208),
+    (0x760c, 4209), (0x760f, 4210), (0x7612, 4211), (0x7613, 4212), (0x7615, 4213), (0x7616, 4214), (0x7619, 4215), (0x761b, 4216),
+    (0x761c, 4217), (0x761d, 4218), (0x761e, 4219), (0x7623, 4220), (0x7625, 4221), (0x7626, 4222), (0x7629, 4223), (0x762d, 4224),
+    (0x7632, 4225), (0x7633, 4226), (0x7635, 4227), (0x7638, 4228), (0x7639, 4229), (0x763a, 4230), (0x763c, 4231), (0x7640, 4233),
+    (0x7641, 4234), (0x7643, 4235), (0x7644, 4236), (0x7645, 4237), (0x7649, 4238), (0x764a, 4232), (0x764b, 4239), (0x7655, 4240),
+    (0x7659, 4241), (0x765f, 4242), (0x7664, 4243), (0x7665, 4244), (0x766d, 4245), (0x766e, 4246), (0x766f, 4247), (0x7671, 4248),
+    (0x7674, 4249), (0x7681, 4250), (0x7685, 4251), (0x768c, 4252), (0x768d, 4253), (0x7695, 4254), (0x769b, 4255), (0x769c, 4256),
+    (0x769d, 4257), (0x769f, 4258), (0x76a0, 4259), (0x76a2, 4260), (0x76a3, 4261), (0x76a4, 4262), (0x76a5, 4263), (0x76a6, 4264),
+    (0x76a7, 4265), (0x76a8, 4266), (0x76aa, 4267), (0x76ad, 4268), (0x76bd, 4269), (0x76c1, 4270), (0x76c5, 4271), (0x76c9, 4272),
+    (0x76cb, 4273), (0x76cc, 4274), (0x76ce, 4275), (0x76d4, 4276), (0x76d9, 4277), (0x76e0, 4278), (0x76e6, 4279), (0x76e8, 4280),
+    (0x76ec, 4281), (0x76f0, 4282), (0x76f1, 4283), (0x76f6, 4284), (0x76f9, 4285), (0x76fc, 4286), (0x7700, 4287), (0x7706, 4288),
+    (0x770a, 4289), (0x770e, 4290), (0x7712, 4291), (0x7714, 4292), (0x7715, 4293), (0x7717, 4294), (0x7719, 4295), (0x771a, 4296),
+    (0x771c, 4297), (0x7722, 4298), (0x7728, 4299), (0x772d, 4300), (0x772e, 4301), (0x772f, 4302), (0x7734, 4303), (0x7735, 4304),
+    (0x7736, 4305), (0x7739, 4306), (0x773d, 4307), (0x773e, 4308), (0x7742, 4309), (0x7745, 4310), (0x7746, 4311), (0x774a, 4312),
+    (0x774d, 4313), (0x774e, 4314), (0x774f, 4315), (0x7752, 4316), (0x7756, 4317), (0x7757, 4318), (0x775c, 4319), (0x775e, 4320),
+    (0x775f, 4321), (0x7760, 4322), (0x7762, 4323), (0x7764, 4324), (0x7767, 4325), (0x776a, 4326), (0x776c, 4327), (0x7770, 4328),
+    (0x7772, 4329), (0x7773, 4330), (0x7774, 4331), (0x777a, 4332), (0x777d, 4333), (0x7780, 4334), (0x7784, 4335), (0x778c, 4336),
+    (0x778d, 4337), (0x7794, 4338), (0x7795, 4339), (0x7796, 4340), (0x779a, 4341), (0x779f, 4342), (0x77a2, 4343), (0x77a7, 4344),
+    (0x77aa, 4345), (0x77ae, 4346), (0x77af, 4347), (0x77b1, 4348), (0x77b5, 4349), (0x77be, 4350), (0x77c3, 4351), (0x77c9, 4352),
+    (0x77d1, 4353), (0x77d2, 4354), (0x77d5, 4355), (0x77d9, 4356), (0x77de, 4357), (0x77df, 4358), (0x77e0, 4359), (0x77e4, 4360),
+    (0x77e6, 4361), (0x77ea, 4362), (0x77ec, 4363), (0x77f0, 4364), (0x77f1, 4365), (0x77f4, 4366), (0x77f8, 4367), (0x77fb, 4368),
+    (0x7805, 4369), (0x7806, 4370), (0x7809, 4371), (0x780d, 4372), (0x780e, 4373), (0x7811, 4374), (0x781d, 4375), (0x7821, 4376),
+    (0x7822, 4377), (0x7823, 4378), (0x782d, 4379), (0x782e, 4380), (0x7830, 4381), (0x7835, 4382), (0x7837, 4383), (0x7843, 4384),
+    (0x7844, 4385), (0x7847, 4386), (0x7848, 4387), (0x784c, 4388), (0x784e, 4389), (0x7852, 4390), (0x785c, 4391), (0x785e, 4392),
+    (0x7860, 4393), (0x7861, 4394), (0x7863, 4395), (0x7864, 4396), (0x7868, 4397), (0x786a, 4398), (0x786e, 4399), (0x787a, 4400),
+    (0x787e, 4401), (0x788a, 4402), (0x788f, 4403), (0x7894, 4404), (0x7898, 4405), (0x789d, 4407), (0x789e, 4408), (0x789f, 4409),
+    (0x78a1, 4406), (0x78a4, 4410), (0x78a8, 4411), (0x78ac, 4412), (0x78ad, 4413), (0x78b0, 4414), (0x78b1, 4415), (0x78b2, 4416),
+    (0x78b3, 4417), (0x78bb, 4418), (0x78bd, 4419), (0x78bf, 4420), (0x78c7, 4421), (0x78c8, 4422), (0x78c9, 4423), (0x78cc, 4424),
+    (0x78ce, 4425), (0x78d2, 4426), (0x78d3, 4427), (0x78d5, 4428), (0x78d6, 4429), (0x78db, 4431), (0x78df, 4432), (0x78e0, 4433),
+    (0x78e1, 4434), (0x78e4, 4430), (0x78e6, 4435), (0x78ea, 4436), (0x78f2, 4437), (0x78f3, 4438), (0x78f6, 4440), (0x78f7, 4441),
+    (0x78fa, 4442), (0x78fb, 4443), (0x78ff, 4444), (0x7900, 4439), (0x7906, 4445), (0x790c, 4446), (0x7910, 4447), (0x791a, 4448),
+    (0x791c, 4449), (0x791e, 4450), (0x791f, 4451), (0x7920, 4452), (0x7925, 4453), (0x7927, 4454), (0x7929, 4455), (0x792d, 4456),
+    (0x7931, 4457), (0x7934, 4458), (0x7935, 4459), (0x793b, 4460), (0x793d, 4461), (0x793f, 4462), (0x7944, 4463), (0x7945, 4464),
+    (0x7946, 4465), (0x794a, 4466), (0x794b, 4467), (0x794f, 4468), (0x7951, 4469), (0x7954, 4470), (0x7958, 4471), (0x795b, 4472),
+    (0x795c, 4473), (0x7967, 4474), (0x7969, 4475), (0x796b, 4476), (0x7972, 4477), (0x7979, 4478), (0x797b, 4479), (0x797c, 4480),
+    (0x797e, 4481), (0x798b, 4482), (0x798c, 4483), (0x7991, 4484), (0x7993, 4485), (0x7994, 4486), (0x7995, 4487), (0x7996, 4488),
+    (0x7998, 4489), (0x799b, 4490), (0x799c, 4491), (0x79a1, 4492), (0x79a8, 4493), (0x79a9, 4494), (0x79ab, 4495), (0x79af, 4496),
+    (0x79b1, 4497), (0x79b4, 4498), (0x79b8, 4499), (0x79bb, 4500), (0x79c2, 4501), (0x79c4, 4502), (0x79c7, 4503), (0x79c8, 4504),
+    (0x79ca, 4505), (0x79cf, 4506), (0x79d4, 4507), (0x79d6, 4508), (0x79da, 4509), (0x79dd, 4510), (0x79de, 4511), (0x79e0, 4512),
+    (0x79e2, 4513), (0x79e5, 4514), (0x79ea, 4515), (0x79eb, 4516), (0x79ed, 4517), (0x79f1, 4518), (0x79f8, 4519), (0x79fc, 4520),
+    (0x7a02, 4521), (0x7a03, 4522), (0x7a07, 4523), (0x7a09, 4524), (0x7a0a, 4525), (0x7a0c, 4526), (0x7a11, 4527), (0x7a15, 4528),
+    (0x7a1b, 4529), (0x7a1e, 4530), (0x7a21, 4531), (0x7a27, 4532), (0x7a2b, 4533), (0x7a2d, 4534), (0x7a2f, 4535), (0x7a30, 4536),
+    (0x7a34, 4537), (0x7a35, 4538), (0x7a38, 4539), (0x7a39, 4540), (0x7a3a, 4541), (0x7a44, 4542), (0x7a45, 4543), (0x7a47, 4544),
+    (0x7a48, 4545), (0x7a4c, 4546), (0x7a55, 4547), (0x7a56, 4548), (0x7a59, 4549), (0x7a5c, 4550), (0x7a5d, 4551), (0x7a5f, 4552),
+    (0x7a60, 4553), (0x7a65, 4554), (0x7a67, 4555), (0x7a6a, 4556), (0x7a6d, 4557), (0x7a75, 4558), (0x7a78, 4559), (0x7a7e, 4560),
+    (0x7a80, 4561), (0x7a82, 4562), (0x7a85, 4563), (0x7a86, 4564), (0x7a8a, 4565), (0x7a8b, 4566), (0x7a90, 4567), (0x7a91, 4568),
+    (0x7a94, 4569), (0x7a9e, 4570), (0x7aa0, 4571), (0x7aa3, 4572), (0x7aac, 4573), (0x7ab3, 4574), (0x7ab5, 4575), (0x7ab9, 4576),
+    (0x7abb, 4577), (0x7abc, 4578), (0x7ac6, 4579), (0x7ac9, 4580), (0x7acc, 4581), (0x7ace, 4582), (0x7ad1, 4583), (0x7adb, 4584),
+    (0x7ae8, 4585), (0x7ae9, 4586), (0x7aeb, 4587), (0x7aec, 4588), (0x7af1, 4589), (0x7af4, 4590), (0x7afb, 4591), (0x7afd, 4592),
+    (0x7afe, 4593), (0x7b07, 4594), (0x7b14, 4595), (0x7b1f, 4596), (0x7b23, 4597), (0x7b27, 4598), (0x7b29, 4599), (0x7b2a, 4600),
+    (0x7b2b, 4601), (0x7b2d, 4602), (0x7b2e, 4603), (0x7b2f, 4604), (0x7b30, 4605), (0x7b31, 4606), (0x7b34, 4607), (0x7b3d, 4608),
+    (0x7b3f, 4609), (0x7b40, 4610), (0x7b41, 4611), (0x7b47, 4612), (0x7b4e, 4613), (0x7b55, 4614), (0x7b60, 4615), (0x7b64, 4616),
+    (0x7b66, 4617), (0x7b69, 4618), (0x7b6a, 4619), (0x7b6d, 4620), (0x7b6f, 4621), (0x7b72, 4622), (0x7b73, 4623), (0x7b77, 4624),
+    (0x7b84, 4625), (0x7b89, 4626), (0x7b8e, 4627), (0x7b90, 4628), (0x7b91, 4629), (0x7b96, 4630), (0x7b9b, 4631), (0x7b9e, 4632),
+    (0x7ba0, 4633), (0x7ba5, 4634), (0x7bac, 4635), (0x7baf, 4636), (0x7bb0, 4637), (0x7bb2, 4638), (0x7bb5, 4639), (0x7bb6, 4640),
+    (0x7bba, 4641), (0x7bbb, 4642), (0x7bbc, 4643), (0x7bbd, 4644), (0x7bc2, 4645), (0x7bc5, 4646), (0x7bc8, 4647), (0x7bca, 4648),
+    (0x7bd4, 4649), (0x7bd6, 4650), (0x7bd7, 4651), (0x7bd9, 4652), (0x7bda, 4653), (0x7bdb, 4654), (0x7be8, 4655), (0x7bea, 4656),
+    (0x7bf2, 4657), (0x7bf4, 4658), (0x7bf5, 4659), (0x7bf8, 4660), (0x7bf9, 4661), (0x7bfa, 4662), (0x7bfc, 4663), (0x7bfe, 4664),
+    (0x7c01, 4665), (0x7c02, 4666), (0x7c03, 4667), (0x7c04, 4668), (0x7c06, 4669), (0x7c09, 4670), (0x7c0b, 4671), (0x7c0c, 4672),
+    (0x7c0e, 4673), (0x7c0f, 4674), (0x7c19, 4675), (0x7c1b, 4676), (0x7c20, 4677), (0x7c25, 4678), (0x7c26, 4679), (0x7c28, 4680),
+    (0x7c2c, 4681), (0x7c31, 4682), (0x7c33, 4683), (0x7c34, 4684), (0x7c36, 4685), (0x7c39, 4686), (0x7c3a, 4687), (0x7c46, 4688),
+    (0x7c4a, 4689), (0x7c51, 4691), (0x7c52, 4692), (0x7c53, 4693), (0x7c55, 4690), (0x7c59, 4694), (0x7c5a, 4695), (0x7c5b, 4696),
+    (0x7c5c, 4697), (0x7c5d, 4698), (0x7c5e, 4699), (0x7c61, 4700), (0x7c63, 4701), (0x7c67, 4702), (0x7c69, 4703), (0x7c6d, 4704),
+    (0x7c6e, 4705), (0x7c70, 4706), (0x7c72, 4707), (0x7c79, 4708), (0x7c7c, 4709), (0x7c7d, 4710), (0x7c86, 4711), (0x7c87, 4712),
+    (0x7c8f, 4713), (0x7c94, 4714), (0x7c9e, 4715), (0x7ca0, 4716), (0x7ca6, 4717), (0x7cb0, 4718), (0x7cb6, 4719), (0x7cb7, 4720),
+    (0x7cba, 4721), (0x7cbb, 4722), (0x7cbc, 4723), (0x7cbf, 4724), (0x7cc4, 4725), (0x7cc7, 4726), (0x7cc8, 4727), (0x7cc9, 4728),
+    (0x7ccd, 4729), (0x7ccf, 4730), (0x7cd3, 4731), (0x7cd4, 4732), (0x7cd5, 4733), (0x7cd7, 4734), (0x7cd9, 4735), (0x7cda, 4736),
+    (0x7cdd, 4737), (0x7ce6, 4738), (0x7ce9, 4739), (0x7ceb, 4740), (0x7cf5, 4741), (0x7d03, 4742), (0x7d07, 4743), (0x7d08, 4744),
+    (0x7d09, 4745), (0x7d0f, 4746), (0x7d11, 4747), (0x7d12, 4748), (0x7d13, 4749), (0x7d16, 4750), (0x7d1d, 4751), (0x7d1e, 4752),
+    (0x7d23, 4753), (0x7d26, 4754), (0x7d2a, 4755), (0x7d2d, 4756), (0x7d31, 4757), (0x7d3c, 4758), (0x7d3d, 4759), (0x7d3e, 4760),
+    (0x7d40, 4761), (0x7d41, 4762), (0x7d47, 4763), (0x7d48, 4764), (0x7d4d, 4765), (0x7d51, 4766), (0x7d53, 4767), (0x7d57, 4768),
+    (0x7d59, 4769), (0x7d5a, 4770), (0x7d5c, 4771), (0x7d5d, 4772), (0x7d65, 4773), (0x7d67, 4774), (0x7d6a, 4775), (0x7d70, 4776),
+    (0x7d78, 4777), (0x7d7a, 4778), (0x7d7b, 4779), (0x7d7f, 4780), (0x7d81, 4781), (0x7d82, 4782), (0x7d83, 4783), (0x7d85, 4784),
+    (0x7d86, 4785), (0x7d88, 4786), (0x7d8b, 4787), (0x7d8c, 4788), (0x7d8d, 4789), (0x7d91, 4790), (0x7d96, 4791), (0x7d97, 4792),
+    (0x7d9d, 4793), (0x7d9e, 4794), (0x7da6, 4795), (0x7da7, 4796), (0x7daa, 4797), (0x7db3, 4798), (0x7db6, 4799), (0x7db7, 4800),
+    (0x7db9, 4801), (0x7dc2, 4802), (0x7dc3, 4803), (0x7dc4, 4804), (0x7dc5, 4805), (0x7dc6, 4806), (0x7dcc, 4807), (0x7dcd, 4808),
+    (0x7dce, 4809), (0x7dd7, 4810), (0x7dd9, 4811), (0x7de2, 4813), (0x7de5, 4814), (0x7de6, 4815), (0x7dea, 4816), (0x7deb, 4817),
+    (0x7ded, 4818), (0x7df1, 4819), (0x7df5, 4820), (0x7df6, 4821), (0x7df9, 4822), (0x7dfa, 4823), (0x7e00, 4812), (0x7e08, 4824),
+    (0x7e10, 4825), (0x7e11, 4826), (0x7e15, 4827), (0x7e17, 4828), (0x7e1c, 4829), (0x7e1d, 4830), (0x7e20, 4831), (0x7e27, 4832),
+    (0x7e28, 4833), (0x7e2c, 4834), (0x7e2d, 4835), (0x7e2f, 4836), (0x7e33, 4837), (0x7e36, 4838), (0x7e3f, 4839), (0x7e44, 4840),
+    (0x7e45, 4841), (0x7e47, 4842), (0x7e4e, 4843), (0x7e50, 4844), (0x7e52, 4845), (0x7e58, 4846), (0x7e5f, 4847), (0x7e61, 4848),
+    (0x7e62, 4849), (0x7e65, 4850), (0x7e6b, 4851), (0x7e6e, 4852), (0x7e6f, 4853), (0x7e73, 4854), (0x7e78, 4855), (0x7e7e, 4856),
+    (0x7e81, 4857), (0x7e86, 4858), (0x7e87, 4859), (0x7e8a, 4860), (0x7e8d, 4861), (0x7e91, 4862), (0x7e95, 4863), (0x7e98, 4864),
+    (0x7e9a, 4865), (0x7e9d, 4866), (0x7e9e, 4867), (0x7f3b, 4869), (0x7f3c, 4868), (0x7f3d, 4870), (0x7f3e, 4871), (0x7f3f, 4872),
+    (0x7f43, 4873), (0x7f44, 4874), (0x7f47, 4875), (0x7f4f, 4876), (0x7f52, 4877), (0x7f53, 4878), (0x7f5b, 4879), (0x7f5c, 4880),
+    (0x7f5d, 4881), (0x7f61, 4882), (0x7f63, 4883), (0x7f64, 4884), (0x7f65, 4885), (0x7f66, 4886), (0x7f6d, 4887), (0x7f71, 4888),
+    (0x7f7d, 4889), (0x7f7e, 4890), (0x7f7f, 4891), (0x7f80, 4892), (0x7f8b, 4893), (0x7f8d, 4894), (0x7f8f, 4895), (0x7f90, 4896),
+    (0x7f91, 4897), (0x7f96, 4898), (0x7f97, 4899), (0x7f9c, 4900), (0x7fa1, 4901), (0x7fa2, 4902), (0x7fa6, 4903), (0x7faa, 4904),
+    (0x7fad, 4905), (0x7fb4, 4906), (0x7fbc, 4907), (0x7fbf, 4908), (0x7fc0, 4909), (0x7fc3, 4910), (0x7fc8, 4911), (0x7fce, 4912),
+    (0x7fcf, 4913), (0x7fdb, 4914), (0x7fdf, 4915), (0x7fe3, 4916), (0x7fe5, 4917), (0x7fe8, 4918), (0x7fec, 4919), (0x7fee, 4920),
+    (0x7fef, 4921), (0x7ff2, 4922), (0x7ffa, 4923), (0x7ffd, 4924), (0x7ffe, 4925), (0x7fff, 4926), (0x8007, 4927), (0x8008, 4928),
+    (0x800a, 4929), (0x800d, 4930), (0x800e, 4931), (0x800f, 4932), (0x8011, 4933), (0x8013, 4934), (0x8014, 4935), (0x8016, 4936),
+    (0x801d, 4937), (0x801e, 4938), (0x801f, 4939), (0x8020, 4940), (0x8024, 4941), (0x8026, 4942), (0x802c, 4943), (0x802e, 4944),
+    (0x8030, 4945), (0x8034, 4946), (0x8035, 4947), (0x8037, 4948), (0x8039, 4949), (0x803a, 4950), (0x803c, 4951), (0x803e, 4952),
+    (0x8040, 4953), (0x8044, 4954), (0x8060, 4955), (0x8064, 4956), (0x8066, 4957), (0x806d, 4958), (0x8071, 4959), (0x8075, 4960),
+    (0x8081, 4961), (0x8088, 4962), (0x808e, 4963), (0x809c, 4964), (0x809e, 4965), (0x80a6, 4966), (0x80a7, 4967), (0x80ab, 4968),
+    (0x80b8, 4969), (0x80b9, 4970), (0x80c8, 4971), (0x80cd, 4972), (0x80cf, 4973), (0x80d2, 4974), (0x80d4, 4975), (0x80d5, 4976),
+    (0x80d7, 4977), (0x80d8, 4978), (0x80e0, 4979), (0x80ed, 4980), (0x80ee, 4981), (0x80f0, 4982), (0x80f2, 4983), (0x80f3, 4984),
+    (0x80f6, 4985), (0x80f9, 4986), (0x80fa, 4987), (0x80fe, 4988), (0x8103, 4989), (0x810b, 4990), (0x8116, 4991), (0x8117, 4992),
+    (0x8118, 4993), (0x811c, 4994), (0x811e, 4995), (0x8120, 4996), (0x8124, 4997), (0x8127, 4998), (0x812c, 4999), (0x8130, 5000),
+    (0x8135, 5001), (0x813a, 5002), (0x813c, 5003), (0x8145, 5004), (0x8147, 5005), (0x814a, 5006), (0x814c, 5007), (0x8152, 5008),
+    (0x8157, 5009), (0x8160, 5010), (0x8161, 5011), (0x8167, 5012), (0x8168, 5013), (0x8169, 5014), (0x816d, 5015), (0x816f, 5016),
+    (0x8177, 5017), (0x8181, 5018), (0x8184, 5020), (0x8185, 5021), (0x8186, 5022), (0x818b, 5023), (0x818e, 5024), (0x8190, 5019),
+    (0x8196, 5025), (0x8198, 5026), (0x819b, 5027), (0x819e, 5028), (0x81a2, 5029), (0x81ae, 5030), (0x81b2, 5031), (0x81b4, 5032),
+    (0x81bb, 5033), (0x81c3, 5035), (0x81c5, 5036), (0x81ca, 5037), (0x81cb, 5034), (0x81ce, 5038), (0x81cf, 5039), (0x81d5, 5040),
+    (0x81d7, 5041), (0x81db, 5042), (0x81dd, 5043), (0x81de, 5044), (0x81e1, 5045), (0x81e4, 5046), (0x81eb, 5047), (0x81ec, 5048),
+    (0x81f0, 5049), (0x81f1, 5050), (0x81f2, 5051), (0x81f5, 5052), (0x81f6, 5053), (0x81f8, 5054), (0x81f9, 5055), (0x81fd, 5056),
+    (0x81ff, 5057), (0x8200, 5058), (0x8203, 5059), (0x820f, 5060), (0x8213, 5061), (0x8214, 5062), (0x8219, 5063), (0x821a, 5064),
+    (0x821d, 5065), (0x8221, 5066), (0x8222, 5067), (0x8228, 5068), (0x8232, 5069), (0x8234, 5070), (0x823a, 5071), (0x8243, 5072),
+    (0x8244, 5073), (0x8245, 5074), (0x8246, 5075), (0x824b, 5076), (0x824e, 5077), (0x824f, 5078), (0x8251, 5079), (0x8256, 5080),
+    (0x825c, 5081), (0x8260, 5082), (0x8263, 5083), (0x8267, 5084), (0x826d, 5085), (0x8274, 5086), (0x827b, 5087), (0x827d, 5088),
+    (0x827f, 5089), (0x8280, 5090), (0x8281, 5091), (0x8283, 5092), (0x8284, 5093), (0x8287, 5094), (0x8289, 5095), (0x828a, 5096),
+    (0x828e, 5097), (0x8291, 5098), (0x8294, 5099), (0x8296, 5100), (0x8298, 5101), (0x829a, 5102), (0x829b, 5103), (0x82a0, 5104),
+    (0x82a1, 5105), (0x82a3, 5106), (0x82a4, 5107), (0x82a7, 5108), (0x82a8, 5109), (0x82a9, 5110), (0x82aa, 5111), (0x82ae, 5112),
+    (0x82b0, 5113), (0x82b2, 5114), (0x82b4, 5115), (0x82b7, 5116), (0x82ba, 5117), (0x82bc, 5118), (0x82be, 5119), (0x82bf, 5120),
+    (0x82c6, 5121), (0x82d0, 5122), (0x82d5, 5123), (0x82da, 5124), (0x82e0, 5125), (0x82e2, 5126), (0x82e4, 5127), (0x82e8, 5128),
+    (0x82ea, 5129), (0x82ed, 5130), (0x82ef, 5131), (0x82f6, 5132), (0x82f7, 5133), (0x82fd, 5134), (0x82fe, 5135), (0x8300, 5136),
+    (0x8301, 5137), (0x8307, 5138), (0x8308, 5139), (0x830a, 5140), (0x830b, 5141), (0x831b, 5143), (0x831d, 5144), (0x831e, 5145),
+    (0x831f, 5146), (0x8321, 5147), (0x8322, 5148), (0x832c, 5149), (0x832d, 5150), (0x832e, 5151), (0x8330, 5152), (0x8333, 5153),
+    (0x8337, 5154), (0x833a, 5155), (0x833c, 5156), (0x833d, 5157), (0x8342, 5158), (0x8343, 5159), (0x8344, 5160), (0x8347, 5161),
+    (0x834d, 5162), (0x834e, 5163), (0x8351, 5164), (0x8353, 5199), (0x8354, 5142), (0x8355, 5165), (0x8356, 5166), (0x8357, 5167),
+    (0x8370, 5168), (0x8378, 5169), (0x837d, 5170), (0x837f, 5171), (0x8380, 5172), (0x8382, 5173), (0x8384, 5174), (0x8386, 5175),
+    (0x838d, 5176), (0x8392, 5177), (0x8394, 5178), (0x8395, 5179), (0x8398, 5180), (0x8399, 5181), (0x839b, 5182), (0x839c, 5183),
+    (0x839d, 5184), (0x83a6, 5185), (0x83a7, 5186), (0x83a9, 5187), (0x83ac, 5188), (0x83ad, 5213), (0x83be, 5189), (0x83bf, 5190),
+    (0x83c0, 5191), (0x83c7, 5192), (0x83c9, 5193), (0x83cf, 5194), (0x83d0, 5195), (0x83d1, 5196), (0x83d4, 5197), (0x83dd, 5198),
+    (0x83e8, 5200), (0x83ea, 5201), (0x83f6, 5202), (0x83f8, 5203), (0x83f9, 5204), (0x83fc, 5205), (0x8401, 5206), (0x8406, 5207),
+    (0x840a, 5208), (0x840f, 5209), (0x8411, 5210), (0x8415, 5211), (0x8419, 5212), (0x842f, 5214), (0x8439, 5215), (0x8445, 5216),
+    (0x8447, 5217), (0x8448, 5218), (0x844a, 5219), (0x844d, 5220), (0x844f, 5221), (0x8451, 5222), (0x8452, 5223), (0x8456, 5224),
+    (0x8458, 5225), (0x8459, 5226), (0x845a, 5227), (0x845c, 5228), (0x8460, 5229), (0x8464, 5230), (0x8465, 5231), (0x8467, 5232),
+    (0x846a, 5233), (0x8470, 5234), (0x8473, 5235), (0x8474, 5236), (0x8476, 5237), (0x8478, 5238), (0x847c, 5239), (0x847d, 5240),
+    (0x8481, 5241), (0x8485, 5242), (0x8492, 5243), (0x8493, 5244), (0x8495, 5245), (0x849e, 5246), (0x84a6, 5247), (0x84a8, 5248),
+    (0x84a9, 5249), (0x84aa, 5250), (0x84af, 5251), (0x84b1, 5252), (0x84b4, 5253), (0x84ba, 5254), (0x84bd, 5255), (0x84be, 5256),
+    (0x84c0, 5257), (0x84c2, 5258), (0x84c7, 5259), (0x84c8, 5260), (0x84cc, 5261), (0x84cf, 5262), (0x84d3, 5263), (0x84dc, 5264),
+    (0x84e7, 5265), (0x84ea, 5266), (0x84ef, 5267), (0x84f0, 5268), (0x84f1, 5269), (0x84f2, 5270), (0x84f7, 5271), (0x84fa, 5273),
+    (0x84fb, 5274), (0x84fd, 5275), (0x8502, 5276), (0x8503, 5277), (0x8507, 5278), (0x850c, 5279), (0x850e, 5280), (0x8510, 5281),
+    (0x851c, 5282), (0x851e, 5283), (0x8522, 5284), (0x8523, 5285), (0x8524, 5286), (0x8525, 5287), (0x8527, 5288), (0x852a, 5289),
+    (0x852b, 5290), (0x852f, 5291), (0x8532, 5272), (0x8533, 5292), (0x8534, 5293), (0x8536, 5294), (0x853f, 5295), (0x8546, 5296),
+    (0x854f, 5297), (0x8550, 5298), (0x8551, 5299), (0x8552, 5300), (0x8553, 5301), (0x8556, 5302), (0x8559, 5303), (0x855c, 5304),
+    (0x855d, 5305), (0x855e, 5306), (0x855f, 5307), (0x8560, 5308), (0x8561, 5309), (0x8562, 5310), (0x8564, 5311), (0x856b, 5312),
+    (0x856f, 5313), (0x8579, 5314), (0x857a, 5315), (0x857b, 5316), (0x857d, 5317), (0x857f, 5318), (0x8581, 5319), (0x8585, 5320),
+    (0x8586, 5321), (0x8589, 5322), (0x858b, 5323), (0x858c, 5324), (0x858f, 5325), (0x8593, 5326), (0x8598, 5327), (0x859d, 5328),
+    (0x859f, 5329), (0x85a0, 5330), (0x85a2, 5331), (0x85a5, 5332), (0x85a7, 5333), (0x85ad, 5347), (0x85b4, 5334), (0x85b6, 5335),
+    (0x85b7, 5336), (0x85b8, 5337), (0x85bc, 5338), (0x85bd, 5339), (0x85be, 5340), (0x85bf, 5341), (0x85c2, 5342), (0x85c7, 5343),
+    (0x85ca, 5344), (0x85cb, 5345), (0x85ce, 5346), (0x85d8, 5348), (0x85da, 5349), (0x85df, 5350), (0x85e0, 5351), (0x85e6, 5352),
+    (0x85e8, 5353), (0x85ed, 5354), (0x85f3, 5355), (0x85f6, 5356), (0x85fc, 5357), (0x85ff, 5358), (0x8600, 5359), (0x8604, 5360),
+    (0x8605, 5361), (0x860d, 5362), (0x860e, 5363), (0x8610, 5364), (0x8611, 5365), (0x8612, 5366), (0x8618, 5367), (0x8619, 5368),
+    (0x861b, 5369), (0x861e, 5370), (0x8621, 5371), (0x8627, 5372), (0x8629, 5373), (0x8636, 5374), (0x8638, 5375), (0x863a, 5376),
+    (0x863c, 5377), (0x863d, 5378), (0x8640, 5379), (0x8641, 2231), (0x8642, 5380), (0x8646, 5381), (0x8652, 5382), (0x8653, 5383),
+    (0x8656, 5384), (0x8657, 5385), (0x8658, 5386), (0x8659, 5387), (0x865d, 5388), (0x8660, 5389), (0x8661, 5390), (0x8662, 5391),
+    (0x8663, 5392), (0x8664, 5393), (0x8669, 5394), (0x866c, 5395), (0x866f, 5396), (0x8675, 5397), (0x8676, 5398), (0x8677, 5399),
+    (0x867a, 5400), (0x8688, 5434), (0x868d, 5401), (0x8691, 5402), (0x8696, 5403), (0x8698, 5404), (0x869a, 5405), (0x869c, 5406),
+    (0x86a1, 5407), (0x86a6, 5408), (0x86a7, 5409), (0x86a8, 5410), (0x86ad, 5411), (0x86b1, 5412), (0x86b3, 5413), (0x86b4, 5414),
+    (0x86b5, 5415), (0x86b7, 5416), (0x86b8, 5417), (0x86b9, 5418), (0x86bf, 5419), (0x86c0, 5420), (0x86c1, 5421), (0x86c3, 5422),
+    (0x86c5, 5423), (0x86d1, 5424), (0x86d2, 5425), (0x86d5, 5426), (0x86d7, 5427), (0x86da, 5428), (0x86dc, 5429), (0x86e0, 5430),
+    (0x86e3, 5431), (0x86e5, 5432), (0x86e7, 5433), (0x86fa, 5435), (0x86fc, 5436), (0x86fd, 5437), (0x8704, 5438), (0x8705, 5439),
+    (0x8707, 5440), (0x870b, 5441), (0x870e, 5442), (0x870f, 5443), (0x8710, 5444), (0x8713, 5445), (0x8714, 5446), (0x8719, 5447),
+    (0x871e, 5448), (0x871f, 5449), (0x8721, 5450), (0x8723, 5451), (0x8728, 5452), (0x872e, 5453), (0x872f, 5454), (0x8731, 5455),
+    (0x8732, 5456), (0x8739, 5457), (0x873a, 5458), (0x873c, 5459), (0x873d, 5460), (0x873e, 5461), (0x8740, 5462), (0x8743, 5463),
+    (0x8745, 5464), (0x874d, 5465), (0x8758, 5466), (0x875d, 5467), (0x8761, 5468), (0x8764, 5469), (0x8765, 5470), (0x876f, 5471),
+    (0x8771, 5472), (0x8772, 5473), (0x877b, 5474), (0x8783, 5475), (0x8784, 5476), (0x8785, 5477), (0x8786, 5478), (0x8787, 5479),
+    (0x8788, 5480), (0x8789, 5481), (0x878b, 5482), (0x878c, 5483), (0x8790, 5484), (0x8793, 5485), (0x8795, 5486), (0x8797, 5487),
+    (0x8798, 5488), (0x8799, 5489), (0x879e, 5490), (0x87a0, 5491), (0x87a3, 5492), (0x87a7, 5493), (0x87ac, 5494), (0x87ad, 5495),
+    (0x87ae, 5496), (0x87b1, 5497), (0x87b5, 5498), (0x87be, 5499), (0x87bf, 5500), (0x87c1, 5501), (0x87c8, 5502), (0x87c9, 5503),
+    (0x87ca, 5504), (0x87ce, 5505), (0x87d5, 5506), (0x87d6, 5507), (0x87d9, 5508), (0x87da, 5509), (0x87dc, 5510), (0x87df, 5511),
+    (0x87e2, 5512), (0x87e3, 5513), (0x87e4, 5514), (0x87ea, 5515), (0x87eb, 5516), (0x87ed, 5517), (0x87f1, 5518), (0x87f3, 5519),
+    (0x87f8, 5520), (0x87fa, 5521), (0x87ff, 5522), (0x8801, 5523), (0x8803, 5524), (0x8806, 5525), (0x8809, 5526), (0x880a, 5527),
+    (0x880b, 5528), (0x8810, 5529), (0x8812, 5531), (0x8813, 5532), (0x8814, 5533), (0x8818, 5534), (0x8819, 5530), (0x881a, 5535),
+    (0x881b, 5536), (0x881c, 5537), (0x881e, 5538), (0x881f, 5539), (0x8828, 5540), (0x882d, 5541), (0x882e, 5542), (0x8830, 5543),
+    (0x8832, 5544), (0x8835, 5545), (0x883a, 5546), (0x883c, 5547), (0x8841, 5548), (0x8843, 5549), (0x8845, 5550), (0x8848, 5551),
+    (0x8849, 5552), (0x884a, 5553), (0x884b, 5554), (0x884e, 5555), (0x8851, 5556), (0x8855, 5557), (0x8856, 5558), (0x8858, 5559),
+    (0x885a, 5560), (0x885c, 5561), (0x885f, 5562), (0x8860, 5563), (0x8864, 5564), (0x8869, 5565), (0x8871, 5566), (0x8879, 5567),
+    (0x887b, 5568), (0x8880, 5569), (0x8898, 5570), (0x889a, 5571), (0x889b, 5572), (0x889c, 5573), (0x889f, 5574), (0x88a0, 5575),
+    (0x88a8, 5576), (0x88aa, 5577), (0x88ba, 5578), (0x88bd, 5579), (0x88be, 5580), (0x88c0, 5581), (0x88ca, 5582), (0x88cb, 5583),
+    (0x88cc, 5584), (0x88cd, 5585), (0x88ce, 5586), (0x88d1, 5587), (0x88d2, 5588), (0x88d3, 5589), (0x88db, 5590), (0x88de, 5591),
+    (0x88e7, 5592), (0x88ef, 5593), (0x88f0, 5594), (0x88f1, 5595), (0x88f5, 5596), (0x88f7, 5597), (0x8901, 5598), (0x8906, 5599),
+    (0x890d, 5600), (0x890e, 5601), (0x890f, 5602), (0x8915, 5603), (0x8916, 5604), (0x8918, 5605), (0x8919, 5606), (0x891a, 5607),
+    (0x891c, 5608), (0x8920, 5609), (0x8926, 5610), (0x8927, 5611), (0x8928, 5612), (0x8930, 5613), (0x8931, 5614), (0x8932, 5615),
+    (0x8935, 5616), (0x8939, 5617), (0x893a, 5618), (0x893e, 5619), (0x8940, 5620), (0x8942, 5621), (0x8945, 5622), (0x8946, 5623),
+    (0x8949, 5624), (0x894f, 5625), (0x8952, 5626), (0x8957, 5627), (0x895a, 5628), (0x895b, 5629), (0x895c, 5630), (0x8961, 5631),
+    (0x8962, 5632), (0x8963, 5633), (0x896b, 5634), (0x896e, 5635), (0x8970, 5636), (0x8973, 5637), (0x8975, 5638), (0x897a, 5639),
+    (0x897b, 5640), (0x897c, 5641), (0x897d, 5642), (0x8989, 5643), (0x898d, 5644), (0x8990, 5645), (0x8994, 5646), (0x8995, 5647),
+    (0x899b, 5648), (0x899c, 5649), (0x899f, 5650), (0x89a0, 5651), (0x89a5, 5652), (0x89b0, 5653), (0x89b4, 5654), (0x89b5, 5655),
+    (0x89b6, 5656), (0x89b7, 5657), (0x89bc, 5658), (0x89d4, 5659), (0x89d5, 5660), (0x89d6, 5661), (0x89d7, 5662), (0x89d8, 5663),
+    (0x89e5, 5664), (0x89e9, 5665), (0x89eb, 5666), (0x89ed, 5667), (0x89f1, 5668), (0x89f3, 5669), (0x89f6, 5670), (0x89f9, 5671),
+    (0x89fd, 5672), (0x89ff, 5673), (0x8a04, 5674), (0x8a05, 5675), (0x8a07, 5676), (0x8a0f, 5677), (0x8a11, 5678), (0x8a12, 5679),
+    (0x8a14, 5680), (0x8a15, 5681), (0x8a1e, 5682), (0x8a20, 5683), (0x8a22, 5684), (0x8a24, 5685), (0x8a26, 5686), (0x8a2b, 5687),
+    (0x8a2c, 5688), (0x8a2f, 5689), (0x8a35, 5690), (0x8a37, 5691), (0x8a3d, 5692), (0x8a3e, 5693), (0x8a40, 5694), (0x8a43, 5695),
+    (0x8a45, 5696), (0x8a47, 5697), (0x8a49, 5698), (0x8a4d, 5699), (0x8a4e, 5700), (0x8a53, 5701), (0x8a56, 5702), (0x8a57, 5703),
+    (0x8a58, 5704), (0x8a5c, 5705), (0x8a5d, 5706), (0x8a61, 5707), (0x8a65, 5708), (0x8a67, 5709), (0x8a75, 5710), (0x8a76, 5711),
+    (0x8a77, 5712), (0x8a79, 5713), (0x8a7a, 5714), (0x8a7b, 5715), (0x8a7e, 5716), (0x8a7f, 5717), (0x8a80, 5718), (0x8a83, 5719),
+    (0x8a86, 5720), (0x8a8b, 5721), (0x8a8f, 5722), (0x8a90, 5723), (0x8a92, 5724), (0x8a96, 5725), (0x8a97, 5726), (0x8a99, 5727),
+    (0x8a9f, 5728), (0x8aa7, 5729), (0x8aa9, 5730), (0x8aae, 5731), (0x8aaf, 5732), (0x8ab3, 5733), (0x8ab6, 5734), (0x8ab7, 5735),
+    (0x8abb, 5736), (0x8abe, 5737), (0x8ac3, 5738), (0x8ac6, 5739), (0x8ac8, 5740), (0x8ac9, 5741), (0x8aca, 5742), (0x8ad1, 5743),
+    (0x8ad3, 5744), (0x8ad4, 5745), (0x8ad5, 5746), (0x8ad7, 5747), (0x8add, 5748), (0x8adf, 5749), (0x8aec, 5750), (0x8af0, 5751),
+    (0x8af4, 5752), (0x8af5, 5753), (0x8af6, 5754), (0x8afc, 5755), (0x8aff, 5756), (0x8b05, 5757), (0x8b06, 5758), (0x8b0a, 5764),
+    (0x8b0b, 5759), (0x8b11, 5760), (0x8b1c, 5761), (0x8b1e, 5762), (0x8b1f, 5763), (0x8b2d, 5765), (0x8b30, 5766), (0x8b37, 5767),
+    (0x8b3c, 5768), (0x8b42, 5769), (0x8b43, 5770), (0x8b44, 5771), (0x8b45, 5772), (0x8b46, 5773), (0x8b48, 5774), (0x8b4d, 5779),
+    (0x8b52, 5775), (0x8b53, 5776), (0x8b54, 5777), (0x8b59, 5778), (0x8b5e, 5780), (0x8b63, 5781), (0x8b6d, 5782), (0x8b76, 5783),
+    (0x8b78, 5784), (0x8b79, 5785), (0x8b7c, 5786), (0x8b7e, 5787), (0x8b81, 5788), (0x8b84, 5789), (0x8b85, 5790), (0x8b8b, 5791),
+    (0x8b8d, 5792), (0x8b8f, 5793), (0x8b94, 5794), (0x8b95, 5795), (0x8b9c, 5796), (0x8b9e, 5797), (0x8b9f, 5798), (0x8c38, 5799),
+    (0x8c39, 5800), (0x8c3d, 5801), (0x8c3e, 5802), (0x8c45, 5803), (0x8c47, 5804), (0x8c49, 5805), (0x8c4b, 5806), (0x8c4f, 5807),
+    (0x8c51, 5808), (0x8c53, 5809), (0x8c54, 5810), (0x8c57, 5811), (0x8c58, 5812), (0x8c59, 5815), (0x8c5b, 5813), (0x8c5d, 5814),
+    (0x8c63, 5816), (0x8c64, 5817), (0x8c66, 5818), (0x8c68, 5819), (0x8c69, 5820), (0x8c6d, 5821), (0x8c73, 5822), (0x8c75, 5823),
+    (0x8c76, 5824), (0x8c7b, 5825), (0x8c7e, 5826), (0x8c86, 5827), (0x8c87, 5828), (0x8c8b, 5829), (0x8c90, 5830), (0x8c92, 5831),
+    (0x8c93, 5832), (0x8c99, 5833), (0x8c9b, 5834), (0x8c9c, 5835), (0x8ca4, 5836), (0x8cb9, 5837), (0x8cba, 5838), (0x8cc5, 5839),
+    (0x8cc6, 5840), (0x8cc9, 5841), (0x8ccb, 5842), (0x8ccf, 5843), (0x8cd5, 5845), (0x8cd6, 5844), (0x8cd9, 5846), (0x8cdd, 5847),
+    (0x8ce1, 5848), (0x8ce8, 5849), (0x8cec, 5850), (0x8cef, 5851), (0x8cf0, 5852), (0x8cf2, 5853), (0x8cf5, 5854), (0x8cf7, 5855),
+    (0x8cf8, 5856), (0x8cfe, 5857), (0x8cff, 5858), (0x8d01, 5859), (0x8d03, 5860), (0x8d09, 5861), (0x8d12, 5862), (0x8d17, 5863),
+    (0x8d1b, 5864), (0x8d65, 5865), (0x8d69, 5866), (0x8d6c, 5867), (0x8d6e, 5868), (0x8d7f, 5869), (0x8d82, 5870), (0x8d84, 5871),
+    (0x8d88, 5872), (0x8d8d, 5873), (0x8d90, 5874), (0x8d91, 5875), (0x8d95, 5876), (0x8d9e, 5877), (0x8d9f, 5878), (0x8da0, 5879),
+    (0x8da6, 5880), (0x8dab, 5881), (0x8dac, 5882), (0x8daf, 5883), (0x8db2, 5884), (0x8db5, 5885), (0x8db7, 5886), (0x8db9, 5887),
+    (0x8dbb, 5888), (0x8dbc, 5906), (0x8dc0, 5889), (0x8dc5, 5890), (0x8dc6, 5891), (0x8dc7, 5892), (0x8dc8, 5893), (0x8dca, 5894),
+    (0x8dce, 5895), (0x8dd1, 5896), (0x8dd4, 5897), (0x8dd5, 5898), (0x8dd7, 5899), (0x8dd9, 5900), (0x8de4, 5901), (0x8de5, 5902),
+    (0x8de7, 5903), (0x8dec, 5904), (0x8df0, 5905), (0x8df1, 5907), (0x8df2, 5908), (0x8df4, 5909), (0x8dfd, 5910), (0x8e01, 5911),
+    (0x8e04, 5912), (0x8e05, 5913), (0x8e06, 5914), (0x8e0b, 5915), (0x8e11, 5916), (0x8e14, 5917), (0x8e16, 5918), (0x8e20, 5919),
+    (0x8e21, 5920), (0x8e22, 5921), (0x8e23, 5922), (0x8e26, 5923), (0x8e27, 5924), (0x8e31, 5925), (0x8e33, 5926), (0x8e36, 5927),
+    (0x8e37, 5928), (0x8e38, 5929), (0x8e39, 5930), (0x8e3d, 5931), (0x8e40, 5932), (0x8e41, 5933), (0x8e4b, 5934), (0x8e4d, 5935),
+    (0x8e4e, 5936), (0x8e4f, 5937), (0x8e54, 5938), (0x8e5b, 5939), (0x8e5c, 5940), (0x8e5d, 5941), (0x8e5e, 5942), (0x8e61, 5943),
+    (0x8e62, 5944), (0x8e69, 5945), (0x8e6c, 5946), (0x8e6d, 5947), (0x8e6f, 5948), (0x8e70, 5949), (0x8e71, 5950), (0x8e79, 5951),
+    (0x8e7a, 5952), (0x8e7b, 5953), (0x8e82, 5954), (0x8e83, 5955), (0x8e89, 5956), (0x8e90, 5957), (0x8e92, 5958), (0x8e95, 5959),
+    (0x8e9a, 5960), (0x8e9b, 5961), (0x8e9d, 5962), (0x8e9e, 5963), (0x8ea2, 5964), (0x8ea7, 5965), (0x8ea9, 5966), (0x8ead, 5967),
+    (0x8eae, 5968), (0x8eb3, 5969), (0x8eb5, 5970), (0x8eba, 5971), (0x8ebb, 5972), (0x8ec0, 5973), (0x8ec1, 5974), (0x8ec3, 5975),
+    (0x8ec4, 5976), (0x8ec7, 5977), (0x8ecf, 5978), (0x8ed1, 5979), (0x8ed4, 5980), (0x8edc, 5981), (0x8ee8, 5982), (0x8eed, 5989),
+    (0x8eee, 5983), (0x8ef0, 5984), (0x8ef1, 5985), (0x8ef7, 5986), (0x8ef9, 5987), (0x8efa, 5988), (0x8f00, 5990), (0x8f02, 5991),
+    (0x8f07, 5992), (0x8f08, 5993), (0x8f0f, 5994), (0x8f10, 5995), (0x8f16, 5996), (0x8f17, 5997), (0x8f18, 5998), (0x8f1e, 5999),
+    (0x8f20, 6000), (0x8f21, 6001), (0x8f23, 6002), (0x8f25, 6003), (0x8f27, 6004), (0x8f28, 6005), (0x8f2c, 6006), (0x8f2d, 6007),
+    (0x8f2e, 6008), (0x8f34, 6009), (0x8f35, 6010), (0x8f36, 6011), (0x8f37, 6012), (0x8f3a, 6013), (0x8f40, 6014), (0x8f41, 6015),
+    (0x8f43, 6016), (0x8f47, 6017), (0x8f4f, 6018), (0x8f51, 6019), (0x8f52, 6020), (0x8f53, 6021), (0x8f54, 6022), (0x8f55, 6023),
+    (0x8f58, 6024), (0x8f5d, 6025), (0x8f5e, 6026), (0x8f65, 6027), (0x8f9d, 6028), (0x8fa0, 6029), (0x8fa1, 6030), (0x8fa4, 6031),
+    (0x8fa5, 6032), (0x8fa6, 6033), (0x8fb5, 6034), (0x8fb6, 6035), (0x8fb8, 6036), (0x8fbe, 6037), (0x8fc0, 6038), (0x8fc1, 6039),
+    (0x8fc6, 6040), (0x8fca, 6041), (0x8fcb, 6042), (0x8fcd, 6043), (0x8fd0, 6044), (0x8fd2, 6045), (0x8fd3, 6046), (0x8fd5, 6047),
+    (0x8fe0, 6048), (0x8fe3, 6049), (0x8fe4, 6050), (0x8fe8, 6051), (0x8fee, 6052), (0x8ff1, 6053), (0x8ff5, 6054), (0x8ff6, 6055),
+    (0x8ffb, 6056), (0x8ffe, 6057), (0x9002, 6058), (0x9004, 6059), (0x9008, 6060), (0x900c, 6061), (0x9018, 6062), (0x901b, 6063),
+    (0x9028, 6064), (0x9029, 6065), (0x902a, 6067), (0x902c, 6068), (0x902d, 6069), (0x902f, 6066), (0x9033, 6070), (0x9034, 6071),
+    (0x9037, 6072), (0x903f, 6073), (0x9043, 6074), (0x9044, 6075), (0x904c, 6076), (0x905b, 6077), (0x905d, 6078), (0x9062, 6079),
+    (0x9066, 6080), (0x9067, 6081), (0x906c, 6082), (0x9070, 6083), (0x9074, 6084), (0x9079, 6085), (0x9085, 6086), (0x9088, 6087),
+    (0x908b, 6088), (0x908c, 6089), (0x908e, 6090), (0x9090, 6091), (0x9095, 6092), (0x9097, 6093), (0x9098, 6094), (0x9099, 6095),
+    (0x909b, 6096), (0x90a0, 6097), (0x90a1, 6098), (0x90a2, 6099), (0x90a5, 6100), (0x90b0, 6101), (0x90b2, 6102), (0x90b3, 6103),
+    (0x90b4, 6104), (0x90b6, 6105), (0x90bd, 6106), (0x90be, 6108), (0x90c3, 6109), (0x90c4, 6110), (0x90c5, 6111), (0x90c7, 6112),
+    (0x90c8, 6113), (0x90cc, 6107), (0x90d2, 6122), (0x90d5, 6114), (0x90d7, 6115), (0x90d8, 6116), (0x90d9, 6117), (0x90dc, 6118),
+    (0x90dd, 6119), (0x90df, 6120), (0x90e5, 6121), (0x90eb, 6124), (0x90ef, 6125), (0x90f0, 6126), (0x90f4, 6127), (0x90f6, 6123),
+    (0x90fe, 6128), (0x90ff, 6129), (0x9100, 6130), (0x9104, 6131), (0x9105, 6132), (0x9106, 6133), (0x9108, 6134), (0x910d, 6135),
+    (0x9110, 6136), (0x9114, 6137), (0x9116, 6138), (0x9117, 6139), (0x9118, 6140), (0x911a, 6141), (0x911c, 6142), (0x911e, 6143),
+    (0x9120, 6144), (0x9122, 6146), (0x9123, 6147), (0x9125, 6145), (0x9127, 6148), (0x9129, 6149), (0x912e, 6150), (0x912f, 6151),
+    (0x9131, 6152), (0x9134, 6153), (0x9136, 6154), (0x9137, 6155), (0x9139, 6156), (0x913a, 6157), (0x913c, 6158), (0x913d, 6159),
+    (0x9143, 6160), (0x9147, 6161), (0x9148, 6162), (0x914f, 6163), (0x9153, 6164), (0x9157, 6165), (0x9159, 6166), (0x915a, 6167),
+    (0x915b, 6168), (0x9161, 6169), (0x9164, 6170), (0x9167, 6171), (0x916d, 6172), (0x9174, 6173), (0x9179, 6174), (0x917a, 6175),
+    (0x917b, 6176), (0x9181, 6177), (0x9183, 6178), (0x9185, 6179), (0x9186, 6180), (0x918a, 6181), (0x918e, 6182), (0x9191, 6183),
+    (0x9193, 6184), (0x9194, 6185), (0x9195, 6186), (0x9198, 6187), (0x919e, 6188), (0x91a1, 6189), (0x91a6, 6190), (0x91a8, 6191),
+    (0x91ac, 6192), (0x91ad, 6193), (0x91ae, 6194), (0x91b0, 6195), (0x91b1, 6196), (0x91b2, 6197), (0x91b3, 6198), (0x91b6, 6199),
+    (0x91bb, 6200), (0x91bc, 6201), (0x91bd, 6202), (0x91bf, 6203), (0x91c2, 6204), (0x91c3, 6205), (0x91c5, 6206), (0x91d3, 6207),
+    (0x91d4, 6208), (0x91d7, 6209), (0x91d9, 6210), (0x91da, 6211), (0x91de, 6212), (0x91e4, 6213), (0x91e5, 6214), (0x91e9, 6215),
+    (0x91ea, 6216), (0x91ec, 6217), (0x91ed, 6218), (0x91ee, 6219), (0x91ef, 6220), (0x91f0, 6221), (0x91f1, 6222), (0x91f7, 6223),
+    (0x91f9, 6224), (0x91fb, 6225), (0x91fd, 6226), (0x9200, 6227), (0x9201, 6228), (0x9204, 6229), (0x9205, 6230), (0x9206, 6231),
+    (0x9207, 6232), (0x9209, 6233), (0x920a, 6234), (0x920c, 6235), (0x9210, 6236), (0x9212, 6237), (0x9213, 6238), (0x9216, 6239),
+    (0x9218, 6240), (0x921c, 6241), (0x921d, 6242), (0x9223, 6243), (0x9224, 6244), (0x9225, 6245), (0x9226, 6246), (0x9228, 6247),
+    (0x922e, 6248), (0x922f, 6249), (0x9230, 6250), (0x9233, 6251), (0x9235, 6252), (0x9236, 6253), (0x9238, 6254), (0x9239, 6255),
+    (0x923a, 6256), (0x923c, 6257), (0x923e, 6258), (0x9240, 6259), (0x9242, 6260), (0x9243, 6261), (0x9246, 6262), (0x9247, 6263),
+    (0x924a, 6264), (0x924d, 6265), (0x924e, 6266), (0x924f, 6267), (0x9251, 6268), (0x9258, 6269), (0x9259, 6270), (0x925c, 6271),
+    (0x925d, 6272), (0x9260, 6273), (0x9261, 6274), (0x9265, 6275), (0x9267, 6276), (0x9268, 6277), (0x9269, 6278), (0x926e, 6279),
+    (0x926f, 6280), (0x9270, 6281), (0x9275, 6282), (0x9276, 6283), (0x9277, 6284), (0x9278, 6285), (0x9279, 6286), (0x927b, 6287),
+    (0x927c, 6288), (0x927d, 6289), (0x927f, 6290), (0x9288, 6291), (0x9289, 6292), (0x928a, 6293), (0x928d, 6294), (0x928e, 6295),
+    (0x9292, 6296), (0x9297, 6297), (0x9299, 6298), (0x929f, 6299), (0x92a0, 6300), (0x92a4, 6301), (0x92a5, 6302), (0x92a7, 6303),
+    (0x92a8, 6304), (0x92ab, 6305), (0x92af, 6306), (0x92b2, 6307), (0x92b6, 6308), (0x92b8, 6309), (0x92ba, 6310), (0x92bb, 6311),
+    (0x92bc, 6312), (0x92bd, 6313), (0x92bf, 6314), (0x92c0, 6315), (0x92c1, 6316), (0x92c2, 6317), (0x92c3, 6318), (0x92c5, 6319),
+    (0x92c6, 6320), (0x92c7, 6321), (0x92c8, 6322), (0x92cb, 6323), (0x92cc, 6324), (0x92cd, 6325), (0x92ce, 6326), (0x92d0, 6327),
+    (0x92d3, 6328), (0x92d5, 6329), (0x92d7, 6330), (0x92d8, 6331), (0x92d9, 6332), (0x92dc, 6333), (0x92dd, 6334), (0x92df, 6335),
+    (0x92e0, 6336), (0x92e1, 6337), (0x92e3, 6338), (0x92e5, 6339), (0x92e7, 6340), (0x92e8, 6341), (0x92ec, 6342), (0x92ee, 6343),
+    (0x92f0, 6344), (0x92f9, 6345), (0x92fb, 6346), (0x92ff, 6347), (0x9300, 6348), (0x9302, 6349), (0x9308, 6350), (0x930d, 6351),
+    (0x9311, 6352), (0x9314, 6353), (0x9315, 6354), (0x931c, 6355), (0x931d, 6356), (0x931e, 6357), (0x931f, 6358), (0x9321, 6359),
+    (0x9324, 6360), (0x9325, 6361), (0x9327, 6362), (0x9329, 6363), (0x932a, 6364), (0x9333, 6365), (0x9334, 6366), (0x9336, 6367),
+    (0x9337, 6368), (0x9347, 6369), (0x9348, 6370), (0x9349, 6371), (0x9350, 6372), (0x9351, 6373), (0x9352, 6374), (0x9355, 6375),
+    (0x9357, 6376), (0x9358, 6377), (0x935a, 6378), (0x935e, 6379), (0x9364, 6380), (0x9365, 6381), (0x9367, 6382), (0x9369, 6383),
+    (0x936a, 6384), (0x936d, 6385), (0x936f, 6386), (0x9370, 6387), (0x9371, 6388), (0x9373, 6389), (0x9374, 6390), (0x9376, 6391),
+    (0x937a, 6392), (0x937d, 6393), (0x937f, 6394), (0x9380, 6395), (0x9381, 6396), (0x9382, 6397), (0x9388, 6398), (0x938a, 6399),
+    (0x938b, 6400), (0x938d, 6401), (0x938f, 6402), (0x9392, 6403), (0x9395, 6404), (0x9398, 6405), (0x939b, 6406), (0x939e, 6407),
+    (0x93a1, 6408), (0x93a3, 6409), (0x93a4, 6410), (0x93a6, 6411), (0x93a8, 6412), (0x93a9, 6418), (0x93ab, 6413), (0x93b4, 6414),
+    (0x93b5, 6415), (0x93b6, 6416), (0x93ba, 6417), (0x93c1, 6419), (0x93c4, 6420), (0x93c5, 6421), (0x93c6, 6422), (0x93c7, 6423),
+    (0x93c9, 6424), (0x93ca, 6425), (0x93cb, 6426), (0x93cc, 6427), (0x93cd, 6428), (0x93d3, 6429), (0x93d9, 6430), (0x93dc, 6431),
+    (0x93de, 6432), (0x93df, 6433), (0x93e2, 6434), (0x93e6, 6435), (0x93e7, 6436), (0x93f7, 6438), (0x93f8, 6439), (0x93f9, 6437),
+    (0x93fa, 6440), (0x93fb, 6441), (0x93fd, 6442), (0x9401, 6443), (0x9402, 6444), (0x9404, 6445), (0x9408, 6446), (0x9409, 6447),
+    (0x940d, 6448), (0x940e, 6449), (0x940f, 6450), (0x9415, 6451), (0x9416, 6452), (0x9417, 6453), (0x941f, 6454), (0x942e, 6455),
+    (0x942f, 6456), (0x9431, 6457), (0x9432, 6458), (0x9433, 6459), (0x9434, 6460), (0x943b, 6461), (0x943d, 6463), (0x943f, 6462),
+    (0x9443, 6464), (0x9445, 6465), (0x9448, 6466), (0x944a, 6467), (0x944c, 6468), (0x9455, 6469), (0x9459, 6470), (0x945c, 6471),
+    (0x945f, 6472), (0x9461, 6473), (0x9463, 6474), (0x9468, 6475), (0x946b, 6476), (0x946d, 6477), (0x946e, 6478), (0x946f, 6479),
+    (0x9471, 6480), (0x9472, 6481), (0x9483, 6483), (0x9484, 6482), (0x9578, 6484), (0x9579, 6485), (0x957e, 6486), (0x9584, 6487),
+    (0x9588, 6488), (0x958c, 6489), (0x958d, 6490), (0x958e, 6491), (0x959d, 6492), (0x959e, 6493), (0x959f, 6494), (0x95a1, 6495),
+    (0x95a6, 6496), (0x95a9, 6497), (0x95ab, 6498), (0x95ac, 6499), (0x95b4, 6500), (0x95b6, 6501), (0x95ba, 6502), (0x95bd, 6503),
+    (0x95bf, 6504), (0x95c6, 6505), (0x95c8, 6506), (0x95c9, 6507), (0x95cb, 6508), (0x95d0, 6509), (0x95d1, 6510), (0x95d2, 6511),
+    (0x95d3, 6512), (0x95d9, 6513), (0x95da, 6514), (0x95dd, 6515), (0x95de, 6516), (0x95df, 6517), (0x95e0, 6518), (0x95e4, 6519),
+    (0x95e6, 6520), (0x961d, 6521), (0x961e, 6522), (0x9622, 6523), (0x9624, 6524), (0x9625, 6525), (0x9626, 6526), (0x962c, 6527),
+    (0x9631, 6528), (0x9633, 6529), (0x9637, 6530), (0x9638, 6531), (0x9639, 6532), (0x963a, 6533), (0x963c, 6534), (0x963d, 6535),
+    (0x9641, 6536), (0x9652, 6537), (0x9654, 6538), (0x9656, 6539), (0x9657, 6540), (0x9658, 6541), (0x9661, 6542), (0x966e, 6543),
+    (0x9674, 6544), (0x967b, 6545), (0x967c, 6546), (0x967e, 6547), (0x967f, 6548), (0x9681, 6549), (0x9682, 6550), (0x9683, 6551),
+    (0x9684, 6552), (0x9689, 6553), (0x9691, 6554), (0x9696, 6555), (0x969a, 6556), (0x969d, 6557), (0x969f, 6558), (0x96a4, 6559),
+    (0x96a5, 6560), (0x96a6, 6561), (0x96a9, 6562), (0x96ae, 6563), (0x96af, 6564), (0x96b3, 6565), (0x96ba, 6566), (0x96ca, 6567),
+    (0x96d2, 6568), (0x96d8, 6570), (0x96da, 6571), (0x96dd, 6572), (0x96de, 6573), (0x96df, 6574), (0x96e9, 6575), (0x96ef, 6576),
+    (0x96f1, 6577), (0x96fa, 6578), (0x9702, 6579), (0x9703, 6580), (0x9705, 6581), (0x9709, 6582), (0x971a, 6583), (0x971b, 6584),
+    (0x971d, 6585), (0x9721, 6586), (0x9722, 6587), (0x9723, 6588), (0x9728, 6589), (0x9731, 6590), (0x9733, 6591), (0x9741, 6592),
+    (0x9743, 6593), (0x974a, 6594), (0x974e, 6595), (0x974f, 6596), (0x9755, 6597), (0x9757, 6598), (0x9758, 6599), (0x975a, 6600),
+    (0x975b, 6601), (0x9763, 6602), (0x9767, 6603), (0x976a, 6604), (0x976e, 6605), (0x9773, 6606), (0x9776, 6607), (0x9777, 6608),
+    (0x9778, 6609), (0x977b, 6610), (0x977d, 6611), (0x977f, 6612), (0x9780, 6613), (0x9789, 6614), (0x9795, 6615), (0x9796, 6616),
+    (0x9797, 6617), (0x9799, 6618), (0x979a, 6619), (0x979e, 6620), (0x979f, 6621), (0x97a2, 6622), (0x97ac, 6623), (0x97ae, 6624),
+    (0x97b1, 6625), (0x97b2, 6626), (0x97b5, 6627), (0x97b6, 6628), (0x97b8, 6629), (0x97b9, 6630), (0x97ba, 6631), (0x97bc, 6632),
+    (0x97be, 6633), (0x97bf, 6634), (0x97c1, 6635), (0x97c4, 6636), (0x97c5, 6637), (0x97c7, 6638), (0x97c9, 6639), (0x97ca, 6640),
+    (0x97cc, 6641), (0x97cd, 6642), (0x97ce, 6643), (0x97d0, 6644), (0x97d1, 6645), (0x97d4, 6646), (0x97d7, 6647), (0x97d8, 6648),
+    (0x97d9, 6649), (0x97db, 6653), (0x97dd, 6650), (0x97de, 6651), (0x97e0, 6652), (0x97e1, 6654), (0x97e4, 6655), (0x97ef, 6656),
+    (0x97f1, 6657), (0x97f4, 6658), (0x97f7, 6659), (0x97f8, 6660), (0x97fa, 6661), (0x9807, 6662), (0x980a, 6663), (0x980d, 6665),
+    (0x980e, 6666), (0x9814, 6667), (0x9816, 6668), (0x9819, 6664), (0x981c, 6669), (0x981e, 6670), (0x9820, 6671), (0x9823, 6672),
+    (0x9825, 6681), (0x9826, 6673), (0x982b, 6674), (0x982e, 6675), (0x982f, 6676), (0x9830, 6677), (0x9832, 6678), (0x9833, 6679),
+    (0x9835, 6680), (0x983e, 6682), (0x9844, 6683), (0x9847, 6684), (0x984a, 6685), (0x9851, 6686), (0x9852, 6687), (0x9853, 6688),
+    (0x9856, 6689), (0x9857, 6690), (0x9859, 6691), (0x985a, 6692), (0x9862, 6693), (0x9863, 6694), (0x9865, 6695), (0x9866, 6696),
+    (0x986a, 6697), (0x986c, 6698), (0x98ab, 6699), (0x98ad, 6700), (0x98ae, 6701), (0x98b0, 6702), (0x98b4, 6703), (0x98b7, 6704),
+    (0x98b8, 6705), (0x98ba, 6706), (0x98bb, 6707), (0x98bf, 6708), (0x98c2, 6709), (0x98c5, 6710), (0x98c8, 6711), (0x98cc, 6712),
+    (0x98e1, 6713), (0x98e3, 6714), (0x98e5, 6715), (0x98e6, 6716), (0x98e7, 6717), (0x98ea, 6718), (0x98f3, 6719), (0x98f6, 6720),
+    (0x9902, 6721), (0x9907, 6722), (0x9908, 6723), (0x9911, 6724), (0x9915, 6725), (0x9916, 6726), (0x9917, 6727), (0x991a, 6728),
+    (0x991b, 6729), (0x991c, 6730), (0x991f, 6731), (0x9922, 6732), (0x9926, 6733), (0x9927, 6734), (0x992b, 6735), (0x9931, 6736),
+    (0x9932, 6737), (0x9933, 6738), (0x9934, 6739), (0x9935, 6740), (0x9939, 6741), (0x993a, 6742), (0x993b, 6743), (0x993c, 6744),
+    (0x9940, 6745), (0x9941, 6746), (0x9946, 6747), (0x9947, 6748), (0x9948, 6749), (0x994d, 6750), (0x994e, 6751), (0x9954, 6752),
+    (0x9958, 6753), (0x9959, 6754), (0x995b, 6755), (0x995c, 6756), (0x995e, 6757), (0x995f, 6758), (0x9960, 6759), (0x999b, 6760),
+    (0x999d, 6761), (0x999f, 6762), (0x99a6, 6763), (0x99b0, 6764), (0x99b1, 6765), (0x99b2, 6766), (0x99b5, 6767), (0x99b9, 6768),
+    (0x99ba, 6769), (0x99bd, 6770), (0x99bf, 6771), (0x99c3, 6772), (0x99c9, 6773), (0x99d3, 6774), (0x99d4, 6775), (0x99d9, 6776),
+    (0x99da, 6777), (0x99dc, 6778), (0x99de, 6779), (0x99e7, 6780), (0x99ea, 6781), (0x99eb, 6782), (0x99ec, 6783), (0x99f0, 6784),
+    (0x99f4, 6785), (0x99f5, 6786), (0x99f9, 6787), (0x99fd, 6788), (0x99fe, 6789), (0x9a02, 6790), (0x9a03, 6791), (0x9a04, 6792),
+    (0x9a0b, 6793), (0x9a0c, 6794), (0x9a10, 6795), (0x9a11, 6796), (0x9a16, 6797), (0x9a1e, 6798), (0x9a20, 6799), (0x9a22, 6800),
+    (0x9a23, 6801), (0x9a24, 6802), (0x9a27, 6803), (0x9a2d, 6804), (0x9a2e, 6805), (0x9a33, 6806), (0x9a35, 6807), (0x9a36, 6808),
+    (0x9a38, 6809), (0x9a41, 6811), (0x9a44, 6812), (0x9a47, 6810), (0x9a4a, 6813), (0x9a4b, 6814), (0x9a4c, 6815), (0x9a4e, 6816),
+    (0x9a51, 6817), (0x9a54, 6818), (0x9a56, 6819), (0x9a5d, 6820), (0x9aaa, 6821), (0x9aac, 6822), (0x9aae, 6823), (0x9aaf, 6824),
+    (0x9ab2, 6825), (0x9ab4, 6826), (0x9ab5, 6827), (0x9ab6, 6828), (0x9ab9, 6829), (0x9abb, 6830), (0x9abe, 6831), (0x9abf, 6832),
+    (0x9ac1, 6833), (0x9ac3, 6834), (0x9ac6, 6835), (0x9ac8, 6836), (0x9ace, 6837), (0x9ad0, 6838), (0x9ad2, 6839), (0x9ad5, 6840),
+    (0x9ad6, 6841), (0x9ad7, 6842), (0x9adb, 6843), (0x9adc, 6844), (0x9ae0, 6845), (0x9ae4, 6846), (0x9ae5, 6847), (0x9ae7, 6848),
+    (0x9ae9, 6849), (0x9aec, 6850), (0x9af2, 6851), (0x9af3, 6852), (0x9af5, 6853), (0x9af9, 6854), (0x9afa, 6855), (0x9afd, 6856),
+    (0x9aff, 6857), (0x9b00, 6858), (0x9b01, 6859), (0x9b02, 6860), (0x9b03, 6861), (0x9b04, 6862), (0x9b05, 6863), (0x9b08, 6864),
+    (0x9b09, 6865), (0x9b0b, 6866), (0x9b0c, 6867), (0x9b0d, 6868), (0x9b0e, 6869), (0x9b10, 6870), (0x9b12, 6871), (0x9b16, 6872),
+    (0x9b19, 6873), (0x9b1b, 6874), (0x9b1c, 6875), (0x9b20, 6876), (0x9b26, 6877), (0x9b2b, 6878), (0x9b2d, 6879), (0x9b33, 6880),
+    (0x9b34, 6881), (0x9b35, 6882), (0x9b37, 6883), (0x9b39, 6884), (0x9b3a, 6885), (0x9b3d, 6886), (0x9b48, 6887), (0x9b4b, 6888),
+    (0x9b4c, 6889), (0x9b55, 6890), (0x9b56, 6891), (0x9b57, 6892), (0x9b5b, 6893), (0x9b5e, 6894), (0x9b61, 6895), (0x9b63, 6896),
+    (0x9b65, 6897), (0x9b66, 6898), (0x9b68, 6899), (0x9b6a, 6900), (0x9b6b, 6901), (0x9b6c, 6902), (0x9b6d, 6903), (0x9b6e, 6904),
+    (0x9b73, 6905), (0x9b75, 6906), (0x9b77, 6907), (0x9b78, 6908), (0x9b79, 6909), (0x9b7f, 6910), (0x9b80, 6911), (0x9b84, 6912),
+    (0x9b85, 6913), (0x9b86, 6914), (0x9b87, 6915), (0x9b89, 6916), (0x9b8a, 6917), (0x9b8b, 6918), (0x9b8d, 6919), (0x9b8f, 6920),
+    (0x9b90, 6921), (0x9b94, 6922), (0x9b9a, 6923), (0x9b9d, 6924), (0x9b9e, 6925), (0x9ba6, 6926), (0x9ba7, 6927), (0x9ba9, 6928),
+    (0x9bac, 6929), (0x9bb0, 6930), (0x9bb1, 6931), (0x9bb2, 6932), (0x9bb7, 6933), (0x9bb8, 6934), (0x9bbb, 6935), (0x9bbc, 6936),
+    (0x9bbe, 6937), (0x9bbf, 6938), (0x9bc1, 6939), (0x9bc7, 6940), (0x9bc8, 6941), (0x9bce, 6942), (0x9bd0, 6943), (0x9bd7, 6944),
+    (0x9bd8, 6945), (0x9bdd, 6946), (0x9bdf, 6947), (0x9be5, 6948), (0x9be7, 6949), (0x9bea, 6950), (0x9beb, 6951), (0x9bef, 6952),
+    (0x9bf3, 6953), (0x9bf7, 6954), (0x9bf8, 6955), (0x9bf9, 6956), (0x9bfa, 6957), (0x9bfd, 6958), (0x9bff, 6959), (0x9c00, 6960),
+    (0x9c02, 6961), (0x9c0b, 6962), (0x9c0f, 6963), (0x9c11, 6964), (0x9c16, 6965), (0x9c18, 6966), (0x9c19, 6967), (0x9c1a, 6968),
+    (0x9c1c, 6969), (0x9c1e, 6970), (0x9c22, 6971), (0x9c23, 6972), (0x9c26, 6973), (0x9c27, 6974), (0x9c28, 6975), (0x9c29, 6976),
+    (0x9c2a, 6977), (0x9c31, 6978), (0x9c35, 6979), (0x9c36, 6980), (0x9c37, 6981), (0x9c3d, 6982), (0x9c41, 6983), (0x9c43, 6984),
+    (0x9c44, 6985), (0x9c45, 6986), (0x9c49, 6987), (0x9c4a, 6988), (0x9c4e, 6989), (0x9c4f, 6990), (0x9c50, 6991), (0x9c53, 6992),
+    (0x9c54, 6993), (0x9c56, 6994), (0x9c58, 6995), (0x9c5b, 6996), (0x9c5c, 7003), (0x9c5d, 6997), (0x9c5e, 6998), (0x9c5f, 6999),
+    (0x9c63, 7000), (0x9c68, 7005), (0x9c69, 7001), (0x9c6a, 7002), (0x9c6b, 7004), (0x9c6e, 7006), (0x9c70, 7007), (0x9c72, 7008),
+    (0x9c75, 7009), (0x9c77, 7010), (0x9c7b, 7011), (0x9ce6, 7012), (0x9cf2, 7013), (0x9cf7, 7014), (0x9cf9, 7015), (0x9d02, 7017),
+    (0x9d0b, 7016), (0x9d11, 7018), (0x9d17, 7019), (0x9d18, 7020), (0x9d1c, 7021), (0x9d1d, 7022), (0x9d1e, 7023), (0x9d2f, 7024),
+    (0x9d30, 7025), (0x9d32, 7026), (0x9d33, 7027), (0x9d34, 7028), (0x9d3a, 7029), (0x9d3c, 7030), (0x9d3d, 7032), (0x9d42, 7033),
+    (0x9d43, 7034), (0x9d45, 7031), (0x9d47, 7035), (0x9d4a, 7036), (0x9d53, 7037), (0x9d54, 7038), (0x9d5f, 7039), (0x9d62, 7041),
+    (0x9d63, 7040), (0x9d65, 7042), (0x9d69, 7043), (0x9d6a, 7044), (0x9d6b, 7045), (0x9d70, 7046), (0x9d76, 7047), (0x9d77, 7048),
+    (0x9d7b, 7049), (0x9d7c, 7050), (0x9d7e, 7051), (0x9d83, 7052), (0x9d84, 7053), (0x9d86, 7054), (0x9d8a, 7055), (0x9d8d, 7056),
+    (0x9d8e, 7057), (0x9d92, 7058), (0x9d93, 7059), (0x9d95, 7060), (0x9d96, 7061), (0x9d97, 7062), (0x9d98, 7063), (0x9da1, 7064),
+    (0x9daa, 7065), (0x9dac, 7066), (0x9dae, 7067), (0x9db1, 7068), (0x9db5, 7069), (0x9db9, 7070), (0x9dbc, 7071), (0x9dbf, 7072),
+    (0x9dc3, 7073), (0x9dc7, 7074), (0x9dc9, 7075), (0x9dca, 7076), (0x9dd4, 7077), (0x9dd5, 7078), (0x9dd6, 7079), (0x9dd7, 7080),
+    (0x9dda, 7081), (0x9dde, 7082), (0x9ddf, 7083), (0x9de0, 7084), (0x9de5, 7085), (0x9de7, 7086), (0x9de9, 7087), (0x9deb, 7088),
+    (0x9dee, 7089), (0x9df0, 7090), (0x9df3, 7091), (0x9df4, 7092), (0x9dfe, 7093), (0x9e02, 7095), (0x9e07, 7096), (0x9e0a, 7094),
+    (0x9e0e, 7097), (0x9e10, 7098), (0x9e11, 7099), (0x9e12, 7100), (0x9e15, 7101), (0x9e16, 7102), (0x9e19, 7103), (0x9e1c, 7104),
+    (0x9e1d, 7105), (0x9e7a, 7106), (0x9e7b, 7107), (0x9e7c, 7108), (0x9e80, 7109), (0x9e82, 7110), (0x9e83, 7111), (0x9e84, 7112),
+    (0x9e85, 7113), (0x9e87, 7114), (0x9e8e, 7115), (0x9e8f, 7116), (0x9e96, 7117), (0x9e98, 7118), (0x9e9b, 7119), (0x9e9e, 7120),
+    (0x9ea4, 7121), (0x9ea8, 7122), (0x9eac, 7123), (0x9eae, 7124), (0x9eaf, 7125), (0x9eb0, 7126), (0x9eb3, 7127), (0x9eb4, 7128),
+    (0x9eb5, 7129), (0x9ec6, 7130), (0x9ec8, 7131), (0x9ecb, 7132), (0x9ed5, 7133), (0x9edf, 7134), (0x9ee4, 7135), (0x9ee7, 7136),
+    (0x9eec, 7137), (0x9eed, 7138), (0x9eee, 7139), (0x9ef0, 7140), (0x9ef1, 7141), (0x9ef2, 7142), (0x9ef5, 7143), (0x9ef8, 7144),
+    (0x9eff, 7145), (0x9f02, 7146), (0x9f03, 7147), (0x9f09, 7148), (0x9f0f, 7149), (0x9f10, 7150), (0x9f11, 7151), (0x9f12, 7152),
+    (0x9f14, 7153), (0x9f16, 7154), (0x9f17, 7155), (0x9f19, 7156), (0x9f1a, 7157), (0x9f1b, 7158), (0x9f1f, 7159), (0x9f22, 7160),
+    (0x9f26, 7161), (0x9f2a, 7162), (0x9f2b, 7163), (0x9f2f, 7164), (0x9f31, 7165), (0x9f32, 7166), (0x9f34, 7167), (0x9f37, 7168),
+    (0x9f39, 7169), (0x9f3a, 7170), (0x9f3c, 7171), (0x9f3d, 7172), (0x9f3f, 7173), (0x9f41, 7174), (0x9f43, 7175), (0x9f44, 7176),
+    (0x9f45, 7177), (0x9f46, 7178), (0x9f47, 7179), (0x9f53, 7180), (0x9f55, 7181), (0x9f56, 7182), (0x9f57, 7183), (0x9f58, 7184),
+    (0x9f5a, 7185), (0x9f5d, 7186), (0x9f5e, 7187), (0x9f68, 7188), (0x9f69, 7189), (0x9f6d, 7190), (0x9f6e, 7191), (0x9f6f, 7192),
+    (0x9f70, 7193), (0x9f71, 7194), (0x9f73, 7195), (0x9f75, 7196), (0x9f7a, 7197), (0x9f7d, 7198), (0x9f8f, 7199), (0x9f90, 7200),
+    (0x9f91, 7201), (0x9f92, 7202), (0x9f94, 7203), (0x9f96, 7204), (0x9f97, 7205), (0x9f9e, 7206), (0x9fa1, 7207), (0x9fa2, 7208),
+    (0x9fa3, 7209), (0x9fa5, 7210),
+];
+
+/// Returns the character at `pointer`, if any.
+pub(crate) fn decode(pointer: usize) -> Option<char> {
+    match TO_UNICODE.get(pointer) {
+        Some(&0) | None => None,
+        Some(&code_point) => char::from_u32(u32::from(code_point)),
+    }
+}
+
+/// Returns the pointer of `character`, if it is part of the character set.
+pub(crate) fn encode(character: char) -> Option<usize> {
+    let code_point = u16::try_from(u32::from(character)).ok()?;
+
+    FROM_UNICODE
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| usize::from(FROM_UNICODE[index].1))
+}
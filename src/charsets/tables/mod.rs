@@ -0,0 +1,11 @@
+//! Mapping tables of the multi-byte charsets, indexed by pointers computed from their byte
+//! sequences.
+
+pub(crate) mod big5;
+pub(crate) mod big5_hkscs;
+pub(crate) mod gb18030;
+pub(crate) mod gb2312;
+pub(crate) mod jis0208;
+pub(crate) mod jis0212;
+pub(crate) mod uhc;
+pub(crate) mod windows_31j;
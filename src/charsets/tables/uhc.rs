@@ -0,0 +1,3662 @@
+//! The Unified Hangul Code character set, which extends KS X 1001 with the remaining precomposed
+//! Hangul syllables. The pointer of the character with lead byte `l` and trail byte `t` is
+//! `(l - 0x81) * 190 + t - 0x41`.
+
+use std::char;
+use std::convert::TryFrom;
+
+/// The Unicode code point of the character at each pointer, or 0 if the pointer is unassigned.
+#[rustfmt::skip]
+static TO_UNICODE: [u16; 23940] = [
+    0xac02, 0xac03, 0xac05, 0xac06, 0xac0b, 0xac0c, 0xac0d, 0xac0e, 0xac0f, 0xac18, 0xac1e, 0xac1f, 0xac21, 0xac22, 0xac23, 0xac25,
+    0xac26, 0xac27, 0xac28, 0xac29, 0xac2a, 0xac2b, 0xac2e, 0xac32, 0xac33, 0xac34, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xac35, 0xac36, 0xac37, 0xac3a, 0xac3b, 0xac3d, 0xac3e, 0xac3f, 0xac41, 0xac42, 0xac43, 0xac44, 0xac45, 0xac46, 0xac47, 0xac48,
+    0xac49, 0xac4a, 0xac4c, 0xac4e, 0xac4f, 0xac50, 0xac51, 0xac52, 0xac53, 0xac55, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xac56, 0xac57, 0xac59, 0xac5a, 0xac5b, 0xac5d, 0xac5e, 0xac5f, 0xac60, 0xac61, 0xac62, 0xac63, 0xac64, 0xac65, 0xac66, 0xac67,
+    0xac68, 0xac69, 0xac6a, 0xac6b, 0xac6c, 0xac6d, 0xac6e, 0xac6f, 0xac72, 0xac73, 0xac75, 0xac76, 0xac79, 0xac7b, 0xac7c, 0xac7d,
+    0xac7e, 0xac7f, 0xac82, 0xac87, 0xac88, 0xac8d, 0xac8e, 0xac8f, 0xac91, 0xac92, 0xac93, 0xac95, 0xac96, 0xac97, 0xac98, 0xac99,
+    0xac9a, 0xac9b, 0xac9e, 0xaca2, 0xaca3, 0xaca4, 0xaca5, 0xaca6, 0xaca7, 0xacab, 0xacad, 0xacae, 0xacb1, 0xacb2, 0xacb3, 0xacb4,
+    0xacb5, 0xacb6, 0xacb7, 0xacba, 0xacbe, 0xacbf, 0xacc0, 0xacc2, 0xacc3, 0xacc5, 0xacc6, 0xacc7, 0xacc9, 0xacca, 0xaccb, 0xaccd,
+    0xacce, 0xaccf, 0xacd0, 0xacd1, 0xacd2, 0xacd3, 0xacd4, 0xacd6, 0xacd8, 0xacd9, 0xacda, 0xacdb, 0xacdc, 0xacdd, 0xacde, 0xacdf,
+    0xace2, 0xace3, 0xace5, 0xace6, 0xace9, 0xaceb, 0xaced, 0xacee, 0xacf2, 0xacf4, 0xacf7, 0xacf8, 0xacf9, 0xacfa, 0xacfb, 0xacfe,
+    0xacff, 0xad01, 0xad02, 0xad03, 0xad05, 0xad07, 0xad08, 0xad09, 0xad0a, 0xad0b, 0xad0e, 0xad10, 0xad12, 0xad13, 0xad14, 0xad15,
+    0xad16, 0xad17, 0xad19, 0xad1a, 0xad1b, 0xad1d, 0xad1e, 0xad1f, 0xad21, 0xad22, 0xad23, 0xad24, 0xad25, 0xad26, 0xad27, 0xad28,
+    0xad2a, 0xad2b, 0xad2e, 0xad2f, 0xad30, 0xad31, 0xad32, 0xad33, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xad36, 0xad37,
+    0xad39, 0xad3a, 0xad3b, 0xad3d, 0xad3e, 0xad3f, 0xad40, 0xad41, 0xad42, 0xad43, 0xad46, 0xad48, 0xad4a, 0xad4b, 0xad4c, 0xad4d,
+    0xad4e, 0xad4f, 0xad51, 0xad52, 0xad53, 0xad55, 0xad56, 0xad57, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xad59, 0xad5a,
+    0xad5b, 0xad5c, 0xad5d, 0xad5e, 0xad5f, 0xad60, 0xad62, 0xad64, 0xad65, 0xad66, 0xad67, 0xad68, 0xad69, 0xad6a, 0xad6b, 0xad6e,
+    0xad6f, 0xad71, 0xad72, 0xad77, 0xad78, 0xad79, 0xad7a, 0xad7e, 0xad80, 0xad83, 0xad84, 0xad85, 0xad86, 0xad87, 0xad8a, 0xad8b,
+    0xad8d, 0xad8e, 0xad8f, 0xad91, 0xad92, 0xad93, 0xad94, 0xad95, 0xad96, 0xad97, 0xad98, 0xad99, 0xad9a, 0xad9b, 0xad9e, 0xad9f,
+    0xada0, 0xada1, 0xada2, 0xada3, 0xada5, 0xada6, 0xada7, 0xada8, 0xada9, 0xadaa, 0xadab, 0xadac, 0xadad, 0xadae, 0xadaf, 0xadb0,
+    0xadb1, 0xadb2, 0xadb3, 0xadb4, 0xadb5, 0xadb6, 0xadb8, 0xadb9, 0xadba, 0xadbb, 0xadbc, 0xadbd, 0xadbe, 0xadbf, 0xadc2, 0xadc3,
+    0xadc5, 0xadc6, 0xadc7, 0xadc9, 0xadca, 0xadcb, 0xadcc, 0xadcd, 0xadce, 0xadcf, 0xadd2, 0xadd4, 0xadd5, 0xadd6, 0xadd7, 0xadd8,
+    0xadd9, 0xadda, 0xaddb, 0xaddd, 0xadde, 0xaddf, 0xade1, 0xade2, 0xade3, 0xade5, 0xade6, 0xade7, 0xade8, 0xade9, 0xadea, 0xadeb,
+    0xadec, 0xaded, 0xadee, 0xadef, 0xadf0, 0xadf1, 0xadf2, 0xadf3, 0xadf4, 0xadf5, 0xadf6, 0xadf7, 0xadfa, 0xadfb, 0xadfd, 0xadfe,
+    0xae02, 0xae03, 0xae04, 0xae05, 0xae06, 0xae07, 0xae0a, 0xae0c, 0xae0e, 0xae0f, 0xae10, 0xae11, 0xae12, 0xae13, 0xae15, 0xae16,
+    0xae17, 0xae18, 0xae19, 0xae1a, 0xae1b, 0xae1c, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xae1d, 0xae1e, 0xae1f, 0xae20,
+    0xae21, 0xae22, 0xae23, 0xae24, 0xae25, 0xae26, 0xae27, 0xae28, 0xae29, 0xae2a, 0xae2b, 0xae2c, 0xae2d, 0xae2e, 0xae2f, 0xae32,
+    0xae33, 0xae35, 0xae36, 0xae39, 0xae3b, 0xae3c, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xae3d, 0xae3e, 0xae3f, 0xae42,
+    0xae44, 0xae47, 0xae48, 0xae49, 0xae4b, 0xae4f, 0xae51, 0xae52, 0xae53, 0xae55, 0xae57, 0xae58, 0xae59, 0xae5a, 0xae5b, 0xae5e,
+    0xae62, 0xae63, 0xae64, 0xae66, 0xae67, 0xae6a, 0xae6b, 0xae6d, 0xae6e, 0xae6f, 0xae71, 0xae72, 0xae73, 0xae74, 0xae75, 0xae76,
+    0xae77, 0xae7a, 0xae7e, 0xae7f, 0xae80, 0xae81, 0xae82, 0xae83, 0xae86, 0xae87, 0xae88, 0xae89, 0xae8a, 0xae8b, 0xae8d, 0xae8e,
+    0xae8f, 0xae90, 0xae91, 0xae92, 0xae93, 0xae94, 0xae95, 0xae96, 0xae97, 0xae98, 0xae99, 0xae9a, 0xae9b, 0xae9c, 0xae9d, 0xae9e,
+    0xae9f, 0xaea0, 0xaea1, 0xaea2, 0xaea3, 0xaea4, 0xaea5, 0xaea6, 0xaea7, 0xaea8, 0xaea9, 0xaeaa, 0xaeab, 0xaeac, 0xaead, 0xaeae,
+    0xaeaf, 0xaeb0, 0xaeb1, 0xaeb2, 0xaeb3, 0xaeb4, 0xaeb5, 0xaeb6, 0xaeb7, 0xaeb8, 0xaeb9, 0xaeba, 0xaebb, 0xaebf, 0xaec1, 0xaec2,
+    0xaec3, 0xaec5, 0xaec6, 0xaec7, 0xaec8, 0xaec9, 0xaeca, 0xaecb, 0xaece, 0xaed2, 0xaed3, 0xaed4, 0xaed5, 0xaed6, 0xaed7, 0xaeda,
+    0xaedb, 0xaedd, 0xaede, 0xaedf, 0xaee0, 0xaee1, 0xaee2, 0xaee3, 0xaee4, 0xaee5, 0xaee6, 0xaee7, 0xaee9, 0xaeea, 0xaeec, 0xaeee,
+    0xaeef, 0xaef0, 0xaef1, 0xaef2, 0xaef3, 0xaef5, 0xaef6, 0xaef7, 0xaef9, 0xaefa, 0xaefb, 0xaefd, 0xaefe, 0xaeff, 0xaf00, 0xaf01,
+    0xaf02, 0xaf03, 0xaf04, 0xaf05, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xaf06, 0xaf09, 0xaf0a, 0xaf0b, 0xaf0c, 0xaf0e,
+    0xaf0f, 0xaf11, 0xaf12, 0xaf13, 0xaf14, 0xaf15, 0xaf16, 0xaf17, 0xaf18, 0xaf19, 0xaf1a, 0xaf1b, 0xaf1c, 0xaf1d, 0xaf1e, 0xaf1f,
+    0xaf20, 0xaf21, 0xaf22, 0xaf23, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xaf24, 0xaf25, 0xaf26, 0xaf27, 0xaf28, 0xaf29,
+    0xaf2a, 0xaf2b, 0xaf2e, 0xaf2f, 0xaf31, 0xaf33, 0xaf35, 0xaf36, 0xaf37, 0xaf38, 0xaf39, 0xaf3a, 0xaf3b, 0xaf3e, 0xaf40, 0xaf44,
+    0xaf45, 0xaf46, 0xaf47, 0xaf4a, 0xaf4b, 0xaf4c, 0xaf4d, 0xaf4e, 0xaf4f, 0xaf51, 0xaf52, 0xaf53, 0xaf54, 0xaf55, 0xaf56, 0xaf57,
+    0xaf58, 0xaf59, 0xaf5a, 0xaf5b, 0xaf5e, 0xaf5f, 0xaf60, 0xaf61, 0xaf62, 0xaf63, 0xaf66, 0xaf67, 0xaf68, 0xaf69, 0xaf6a, 0xaf6b,
+    0xaf6c, 0xaf6d, 0xaf6e, 0xaf6f, 0xaf70, 0xaf71, 0xaf72, 0xaf73, 0xaf74, 0xaf75, 0xaf76, 0xaf77, 0xaf78, 0xaf7a, 0xaf7b, 0xaf7c,
+    0xaf7d, 0xaf7e, 0xaf7f, 0xaf81, 0xaf82, 0xaf83, 0xaf85, 0xaf86, 0xaf87, 0xaf89, 0xaf8a, 0xaf8b, 0xaf8c, 0xaf8d, 0xaf8e, 0xaf8f,
+    0xaf92, 0xaf93, 0xaf94, 0xaf96, 0xaf97, 0xaf98, 0xaf99, 0xaf9a, 0xaf9b, 0xaf9d, 0xaf9e, 0xaf9f, 0xafa0, 0xafa1, 0xafa2, 0xafa3,
+    0xafa4, 0xafa5, 0xafa6, 0xafa7, 0xafa8, 0xafa9, 0xafaa, 0xafab, 0xafac, 0xafad, 0xafae, 0xafaf, 0xafb0, 0xafb1, 0xafb2, 0xafb3,
+    0xafb4, 0xafb5, 0xafb6, 0xafb7, 0xafba, 0xafbb, 0xafbd, 0xafbe, 0xafbf, 0xafc1, 0xafc2, 0xafc3, 0xafc4, 0xafc5, 0xafc6, 0xafca,
+    0xafcc, 0xafcf, 0xafd0, 0xafd1, 0xafd2, 0xafd3, 0xafd5, 0xafd6, 0xafd7, 0xafd8, 0xafd9, 0xafda, 0xafdb, 0xafdd, 0xafde, 0xafdf,
+    0xafe0, 0xafe1, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xafe2, 0xafe3, 0xafe4, 0xafe5, 0xafe6, 0xafe7, 0xafea, 0xafeb,
+    0xafec, 0xafed, 0xafee, 0xafef, 0xaff2, 0xaff3, 0xaff5, 0xaff6, 0xaff7, 0xaff9, 0xaffa, 0xaffb, 0xaffc, 0xaffd, 0xaffe, 0xafff,
+    0xb002, 0xb003, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb005, 0xb006, 0xb007, 0xb008, 0xb009, 0xb00a, 0xb00b, 0xb00d,
+    0xb00e, 0xb00f, 0xb011, 0xb012, 0xb013, 0xb015, 0xb016, 0xb017, 0xb018, 0xb019, 0xb01a, 0xb01b, 0xb01e, 0xb01f, 0xb020, 0xb021,
+    0xb022, 0xb023, 0xb024, 0xb025, 0xb026, 0xb027, 0xb029, 0xb02a, 0xb02b, 0xb02c, 0xb02d, 0xb02e, 0xb02f, 0xb030, 0xb031, 0xb032,
+    0xb033, 0xb034, 0xb035, 0xb036, 0xb037, 0xb038, 0xb039, 0xb03a, 0xb03b, 0xb03c, 0xb03d, 0xb03e, 0xb03f, 0xb040, 0xb041, 0xb042,
+    0xb043, 0xb046, 0xb047, 0xb049, 0xb04b, 0xb04d, 0xb04f, 0xb050, 0xb051, 0xb052, 0xb056, 0xb058, 0xb05a, 0xb05b, 0xb05c, 0xb05e,
+    0xb05f, 0xb060, 0xb061, 0xb062, 0xb063, 0xb064, 0xb065, 0xb066, 0xb067, 0xb068, 0xb069, 0xb06a, 0xb06b, 0xb06c, 0xb06d, 0xb06e,
+    0xb06f, 0xb070, 0xb071, 0xb072, 0xb073, 0xb074, 0xb075, 0xb076, 0xb077, 0xb078, 0xb079, 0xb07a, 0xb07b, 0xb07e, 0xb07f, 0xb081,
+    0xb082, 0xb083, 0xb085, 0xb086, 0xb087, 0xb088, 0xb089, 0xb08a, 0xb08b, 0xb08e, 0xb090, 0xb092, 0xb093, 0xb094, 0xb095, 0xb096,
+    0xb097, 0xb09b, 0xb09d, 0xb09e, 0xb0a3, 0xb0a4, 0xb0a5, 0xb0a6, 0xb0a7, 0xb0aa, 0xb0b0, 0xb0b2, 0xb0b6, 0xb0b7, 0xb0b9, 0xb0ba,
+    0xb0bb, 0xb0bd, 0xb0be, 0xb0bf, 0xb0c0, 0xb0c1, 0xb0c2, 0xb0c3, 0xb0c6, 0xb0ca, 0xb0cb, 0xb0cc, 0xb0cd, 0xb0ce, 0xb0cf, 0xb0d2,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb0d3, 0xb0d5, 0xb0d6, 0xb0d7, 0xb0d9, 0xb0da, 0xb0db, 0xb0dc, 0xb0dd, 0xb0de,
+    0xb0df, 0xb0e1, 0xb0e2, 0xb0e3, 0xb0e4, 0xb0e6, 0xb0e7, 0xb0e8, 0xb0e9, 0xb0ea, 0xb0eb, 0xb0ec, 0xb0ed, 0xb0ee, 0xb0ef, 0xb0f0,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb0f1, 0xb0f2, 0xb0f3, 0xb0f4, 0xb0f5, 0xb0f6, 0xb0f7, 0xb0f8, 0xb0f9, 0xb0fa,
+    0xb0fb, 0xb0fc, 0xb0fd, 0xb0fe, 0xb0ff, 0xb100, 0xb101, 0xb102, 0xb103, 0xb104, 0xb105, 0xb106, 0xb107, 0xb10a, 0xb10d, 0xb10e,
+    0xb10f, 0xb111, 0xb114, 0xb115, 0xb116, 0xb117, 0xb11a, 0xb11e, 0xb11f, 0xb120, 0xb121, 0xb122, 0xb126, 0xb127, 0xb129, 0xb12a,
+    0xb12b, 0xb12d, 0xb12e, 0xb12f, 0xb130, 0xb131, 0xb132, 0xb133, 0xb136, 0xb13a, 0xb13b, 0xb13c, 0xb13d, 0xb13e, 0xb13f, 0xb142,
+    0xb143, 0xb145, 0xb146, 0xb147, 0xb149, 0xb14a, 0xb14b, 0xb14c, 0xb14d, 0xb14e, 0xb14f, 0xb152, 0xb153, 0xb156, 0xb157, 0xb159,
+    0xb15a, 0xb15b, 0xb15d, 0xb15e, 0xb15f, 0xb161, 0xb162, 0xb163, 0xb164, 0xb165, 0xb166, 0xb167, 0xb168, 0xb169, 0xb16a, 0xb16b,
+    0xb16c, 0xb16d, 0xb16e, 0xb16f, 0xb170, 0xb171, 0xb172, 0xb173, 0xb174, 0xb175, 0xb176, 0xb177, 0xb17a, 0xb17b, 0xb17d, 0xb17e,
+    0xb17f, 0xb181, 0xb183, 0xb184, 0xb185, 0xb186, 0xb187, 0xb18a, 0xb18c, 0xb18e, 0xb18f, 0xb190, 0xb191, 0xb195, 0xb196, 0xb197,
+    0xb199, 0xb19a, 0xb19b, 0xb19d, 0xb19e, 0xb19f, 0xb1a0, 0xb1a1, 0xb1a2, 0xb1a3, 0xb1a4, 0xb1a5, 0xb1a6, 0xb1a7, 0xb1a9, 0xb1aa,
+    0xb1ab, 0xb1ac, 0xb1ad, 0xb1ae, 0xb1af, 0xb1b0, 0xb1b1, 0xb1b2, 0xb1b3, 0xb1b4, 0xb1b5, 0xb1b6, 0xb1b7, 0xb1b8, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xb1b9, 0xb1ba, 0xb1bb, 0xb1bc, 0xb1bd, 0xb1be, 0xb1bf, 0xb1c0, 0xb1c1, 0xb1c2, 0xb1c3, 0xb1c4,
+    0xb1c5, 0xb1c6, 0xb1c7, 0xb1c8, 0xb1c9, 0xb1ca, 0xb1cb, 0xb1cd, 0xb1ce, 0xb1cf, 0xb1d1, 0xb1d2, 0xb1d3, 0xb1d5, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xb1d6, 0xb1d7, 0xb1d8, 0xb1d9, 0xb1da, 0xb1db, 0xb1de, 0xb1e0, 0xb1e1, 0xb1e2, 0xb1e3, 0xb1e4,
+    0xb1e5, 0xb1e6, 0xb1e7, 0xb1ea, 0xb1eb, 0xb1ed, 0xb1ee, 0xb1ef, 0xb1f1, 0xb1f2, 0xb1f3, 0xb1f4, 0xb1f5, 0xb1f6, 0xb1f7, 0xb1f8,
+    0xb1fa, 0xb1fc, 0xb1fe, 0xb1ff, 0xb200, 0xb201, 0xb202, 0xb203, 0xb206, 0xb207, 0xb209, 0xb20a, 0xb20d, 0xb20e, 0xb20f, 0xb210,
+    0xb211, 0xb212, 0xb213, 0xb216, 0xb218, 0xb21a, 0xb21b, 0xb21c, 0xb21d, 0xb21e, 0xb21f, 0xb221, 0xb222, 0xb223, 0xb224, 0xb225,
+    0xb226, 0xb227, 0xb228, 0xb229, 0xb22a, 0xb22b, 0xb22c, 0xb22d, 0xb22e, 0xb22f, 0xb230, 0xb231, 0xb232, 0xb233, 0xb235, 0xb236,
+    0xb237, 0xb238, 0xb239, 0xb23a, 0xb23b, 0xb23d, 0xb23e, 0xb23f, 0xb240, 0xb241, 0xb242, 0xb243, 0xb244, 0xb245, 0xb246, 0xb247,
+    0xb248, 0xb249, 0xb24a, 0xb24b, 0xb24c, 0xb24d, 0xb24e, 0xb24f, 0xb250, 0xb251, 0xb252, 0xb253, 0xb254, 0xb255, 0xb256, 0xb257,
+    0xb259, 0xb25a, 0xb25b, 0xb25d, 0xb25e, 0xb25f, 0xb261, 0xb262, 0xb263, 0xb264, 0xb265, 0xb266, 0xb267, 0xb26a, 0xb26b, 0xb26c,
+    0xb26d, 0xb26e, 0xb26f, 0xb270, 0xb271, 0xb272, 0xb273, 0xb276, 0xb277, 0xb278, 0xb279, 0xb27a, 0xb27b, 0xb27d, 0xb27e, 0xb27f,
+    0xb280, 0xb281, 0xb282, 0xb283, 0xb286, 0xb287, 0xb288, 0xb28a, 0xb28b, 0xb28c, 0xb28d, 0xb28e, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xb28f, 0xb292, 0xb293, 0xb295, 0xb296, 0xb297, 0xb29b, 0xb29c, 0xb29d, 0xb29e, 0xb29f, 0xb2a2, 0xb2a4, 0xb2a7,
+    0xb2a8, 0xb2a9, 0xb2ab, 0xb2ad, 0xb2ae, 0xb2af, 0xb2b1, 0xb2b2, 0xb2b3, 0xb2b5, 0xb2b6, 0xb2b7, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xb2b8, 0xb2b9, 0xb2ba, 0xb2bb, 0xb2bc, 0xb2bd, 0xb2be, 0xb2bf, 0xb2c0, 0xb2c1, 0xb2c2, 0xb2c3, 0xb2c4, 0xb2c5,
+    0xb2c6, 0xb2c7, 0xb2ca, 0xb2cb, 0xb2cd, 0xb2ce, 0xb2cf, 0xb2d1, 0xb2d3, 0xb2d4, 0xb2d5, 0xb2d6, 0xb2d7, 0xb2da, 0xb2dc, 0xb2de,
+    0xb2df, 0xb2e0, 0xb2e1, 0xb2e3, 0xb2e7, 0xb2e9, 0xb2ea, 0xb2f0, 0xb2f1, 0xb2f2, 0xb2f6, 0xb2fc, 0xb2fd, 0xb2fe, 0xb302, 0xb303,
+    0xb305, 0xb306, 0xb307, 0xb309, 0xb30a, 0xb30b, 0xb30c, 0xb30d, 0xb30e, 0xb30f, 0xb312, 0xb316, 0xb317, 0xb318, 0xb319, 0xb31a,
+    0xb31b, 0xb31d, 0xb31e, 0xb31f, 0xb320, 0xb321, 0xb322, 0xb323, 0xb324, 0xb325, 0xb326, 0xb327, 0xb328, 0xb329, 0xb32a, 0xb32b,
+    0xb32c, 0xb32d, 0xb32e, 0xb32f, 0xb330, 0xb331, 0xb332, 0xb333, 0xb334, 0xb335, 0xb336, 0xb337, 0xb338, 0xb339, 0xb33a, 0xb33b,
+    0xb33c, 0xb33d, 0xb33e, 0xb33f, 0xb340, 0xb341, 0xb342, 0xb343, 0xb344, 0xb345, 0xb346, 0xb347, 0xb348, 0xb349, 0xb34a, 0xb34b,
+    0xb34c, 0xb34d, 0xb34e, 0xb34f, 0xb350, 0xb351, 0xb352, 0xb353, 0xb357, 0xb359, 0xb35a, 0xb35d, 0xb360, 0xb361, 0xb362, 0xb363,
+    0xb366, 0xb368, 0xb36a, 0xb36c, 0xb36d, 0xb36f, 0xb372, 0xb373, 0xb375, 0xb376, 0xb377, 0xb379, 0xb37a, 0xb37b, 0xb37c, 0xb37d,
+    0xb37e, 0xb37f, 0xb382, 0xb386, 0xb387, 0xb388, 0xb389, 0xb38a, 0xb38b, 0xb38d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xb38e, 0xb38f, 0xb391, 0xb392, 0xb393, 0xb395, 0xb396, 0xb397, 0xb398, 0xb399, 0xb39a, 0xb39b, 0xb39c, 0xb39d, 0xb39e, 0xb39f,
+    0xb3a2, 0xb3a3, 0xb3a4, 0xb3a5, 0xb3a6, 0xb3a7, 0xb3a9, 0xb3aa, 0xb3ab, 0xb3ad, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xb3ae, 0xb3af, 0xb3b0, 0xb3b1, 0xb3b2, 0xb3b3, 0xb3b4, 0xb3b5, 0xb3b6, 0xb3b7, 0xb3b8, 0xb3b9, 0xb3ba, 0xb3bb, 0xb3bc, 0xb3bd,
+    0xb3be, 0xb3bf, 0xb3c0, 0xb3c1, 0xb3c2, 0xb3c3, 0xb3c6, 0xb3c7, 0xb3c9, 0xb3ca, 0xb3cd, 0xb3cf, 0xb3d1, 0xb3d2, 0xb3d3, 0xb3d6,
+    0xb3d8, 0xb3da, 0xb3dc, 0xb3de, 0xb3df, 0xb3e1, 0xb3e2, 0xb3e3, 0xb3e5, 0xb3e6, 0xb3e7, 0xb3e9, 0xb3ea, 0xb3eb, 0xb3ec, 0xb3ed,
+    0xb3ee, 0xb3ef, 0xb3f0, 0xb3f1, 0xb3f2, 0xb3f3, 0xb3f4, 0xb3f5, 0xb3f6, 0xb3f7, 0xb3f8, 0xb3f9, 0xb3fa, 0xb3fb, 0xb3fd, 0xb3fe,
+    0xb3ff, 0xb400, 0xb401, 0xb402, 0xb403, 0xb404, 0xb405, 0xb406, 0xb407, 0xb408, 0xb409, 0xb40a, 0xb40b, 0xb40c, 0xb40d, 0xb40e,
+    0xb40f, 0xb411, 0xb412, 0xb413, 0xb414, 0xb415, 0xb416, 0xb417, 0xb419, 0xb41a, 0xb41b, 0xb41d, 0xb41e, 0xb41f, 0xb421, 0xb422,
+    0xb423, 0xb424, 0xb425, 0xb426, 0xb427, 0xb42a, 0xb42c, 0xb42d, 0xb42e, 0xb42f, 0xb430, 0xb431, 0xb432, 0xb433, 0xb435, 0xb436,
+    0xb437, 0xb438, 0xb439, 0xb43a, 0xb43b, 0xb43c, 0xb43d, 0xb43e, 0xb43f, 0xb440, 0xb441, 0xb442, 0xb443, 0xb444, 0xb445, 0xb446,
+    0xb447, 0xb448, 0xb449, 0xb44a, 0xb44b, 0xb44c, 0xb44d, 0xb44e, 0xb44f, 0xb452, 0xb453, 0xb455, 0xb456, 0xb457, 0xb459, 0xb45a,
+    0xb45b, 0xb45c, 0xb45d, 0xb45e, 0xb45f, 0xb462, 0xb464, 0xb466, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb467, 0xb468,
+    0xb469, 0xb46a, 0xb46b, 0xb46d, 0xb46e, 0xb46f, 0xb470, 0xb471, 0xb472, 0xb473, 0xb474, 0xb475, 0xb476, 0xb477, 0xb478, 0xb479,
+    0xb47a, 0xb47b, 0xb47c, 0xb47d, 0xb47e, 0xb47f, 0xb481, 0xb482, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb483, 0xb484,
+    0xb485, 0xb486, 0xb487, 0xb489, 0xb48a, 0xb48b, 0xb48c, 0xb48d, 0xb48e, 0xb48f, 0xb490, 0xb491, 0xb492, 0xb493, 0xb494, 0xb495,
+    0xb496, 0xb497, 0xb498, 0xb499, 0xb49a, 0xb49b, 0xb49c, 0xb49e, 0xb49f, 0xb4a0, 0xb4a1, 0xb4a2, 0xb4a3, 0xb4a5, 0xb4a6, 0xb4a7,
+    0xb4a9, 0xb4aa, 0xb4ab, 0xb4ad, 0xb4ae, 0xb4af, 0xb4b0, 0xb4b1, 0xb4b2, 0xb4b3, 0xb4b4, 0xb4b6, 0xb4b8, 0xb4ba, 0xb4bb, 0xb4bc,
+    0xb4bd, 0xb4be, 0xb4bf, 0xb4c1, 0xb4c2, 0xb4c3, 0xb4c5, 0xb4c6, 0xb4c7, 0xb4c9, 0xb4ca, 0xb4cb, 0xb4cc, 0xb4cd, 0xb4ce, 0xb4cf,
+    0xb4d1, 0xb4d2, 0xb4d3, 0xb4d4, 0xb4d6, 0xb4d7, 0xb4d8, 0xb4d9, 0xb4da, 0xb4db, 0xb4de, 0xb4df, 0xb4e1, 0xb4e2, 0xb4e5, 0xb4e7,
+    0xb4e8, 0xb4e9, 0xb4ea, 0xb4eb, 0xb4ee, 0xb4f0, 0xb4f2, 0xb4f3, 0xb4f4, 0xb4f5, 0xb4f6, 0xb4f7, 0xb4f9, 0xb4fa, 0xb4fb, 0xb4fc,
+    0xb4fd, 0xb4fe, 0xb4ff, 0xb500, 0xb501, 0xb502, 0xb503, 0xb504, 0xb505, 0xb506, 0xb507, 0xb508, 0xb509, 0xb50a, 0xb50b, 0xb50c,
+    0xb50d, 0xb50e, 0xb50f, 0xb510, 0xb511, 0xb512, 0xb513, 0xb516, 0xb517, 0xb519, 0xb51a, 0xb51d, 0xb51e, 0xb51f, 0xb520, 0xb521,
+    0xb522, 0xb523, 0xb526, 0xb52b, 0xb52c, 0xb52d, 0xb52e, 0xb52f, 0xb532, 0xb533, 0xb535, 0xb536, 0xb537, 0xb539, 0xb53a, 0xb53b,
+    0xb53c, 0xb53d, 0xb53e, 0xb53f, 0xb542, 0xb546, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb547, 0xb548, 0xb549, 0xb54a,
+    0xb54e, 0xb54f, 0xb551, 0xb552, 0xb553, 0xb555, 0xb556, 0xb557, 0xb558, 0xb559, 0xb55a, 0xb55b, 0xb55e, 0xb562, 0xb563, 0xb564,
+    0xb565, 0xb566, 0xb567, 0xb568, 0xb569, 0xb56a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb56b, 0xb56c, 0xb56d, 0xb56e,
+    0xb56f, 0xb570, 0xb571, 0xb572, 0xb573, 0xb574, 0xb575, 0xb576, 0xb577, 0xb578, 0xb579, 0xb57a, 0xb57b, 0xb57c, 0xb57d, 0xb57e,
+    0xb57f, 0xb580, 0xb581, 0xb582, 0xb583, 0xb584, 0xb585, 0xb586, 0xb587, 0xb588, 0xb589, 0xb58a, 0xb58b, 0xb58c, 0xb58d, 0xb58e,
+    0xb58f, 0xb590, 0xb591, 0xb592, 0xb593, 0xb594, 0xb595, 0xb596, 0xb597, 0xb598, 0xb599, 0xb59a, 0xb59b, 0xb59c, 0xb59d, 0xb59e,
+    0xb59f, 0xb5a2, 0xb5a3, 0xb5a5, 0xb5a6, 0xb5a7, 0xb5a9, 0xb5ac, 0xb5ad, 0xb5ae, 0xb5af, 0xb5b2, 0xb5b6, 0xb5b7, 0xb5b8, 0xb5b9,
+    0xb5ba, 0xb5be, 0xb5bf, 0xb5c1, 0xb5c2, 0xb5c3, 0xb5c5, 0xb5c6, 0xb5c7, 0xb5c8, 0xb5c9, 0xb5ca, 0xb5cb, 0xb5ce, 0xb5d2, 0xb5d3,
+    0xb5d4, 0xb5d5, 0xb5d6, 0xb5d7, 0xb5d9, 0xb5da, 0xb5db, 0xb5dc, 0xb5dd, 0xb5de, 0xb5df, 0xb5e0, 0xb5e1, 0xb5e2, 0xb5e3, 0xb5e4,
+    0xb5e5, 0xb5e6, 0xb5e7, 0xb5e8, 0xb5e9, 0xb5ea, 0xb5eb, 0xb5ed, 0xb5ee, 0xb5ef, 0xb5f0, 0xb5f1, 0xb5f2, 0xb5f3, 0xb5f4, 0xb5f5,
+    0xb5f6, 0xb5f7, 0xb5f8, 0xb5f9, 0xb5fa, 0xb5fb, 0xb5fc, 0xb5fd, 0xb5fe, 0xb5ff, 0xb600, 0xb601, 0xb602, 0xb603, 0xb604, 0xb605,
+    0xb606, 0xb607, 0xb608, 0xb609, 0xb60a, 0xb60b, 0xb60c, 0xb60d, 0xb60e, 0xb60f, 0xb612, 0xb613, 0xb615, 0xb616, 0xb617, 0xb619,
+    0xb61a, 0xb61b, 0xb61c, 0xb61d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb61e, 0xb61f, 0xb620, 0xb621, 0xb622, 0xb623,
+    0xb624, 0xb626, 0xb627, 0xb628, 0xb629, 0xb62a, 0xb62b, 0xb62d, 0xb62e, 0xb62f, 0xb630, 0xb631, 0xb632, 0xb633, 0xb635, 0xb636,
+    0xb637, 0xb638, 0xb639, 0xb63a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb63b, 0xb63c, 0xb63d, 0xb63e, 0xb63f, 0xb640,
+    0xb641, 0xb642, 0xb643, 0xb644, 0xb645, 0xb646, 0xb647, 0xb649, 0xb64a, 0xb64b, 0xb64c, 0xb64d, 0xb64e, 0xb64f, 0xb650, 0xb651,
+    0xb652, 0xb653, 0xb654, 0xb655, 0xb656, 0xb657, 0xb658, 0xb659, 0xb65a, 0xb65b, 0xb65c, 0xb65d, 0xb65e, 0xb65f, 0xb660, 0xb661,
+    0xb662, 0xb663, 0xb665, 0xb666, 0xb667, 0xb669, 0xb66a, 0xb66b, 0xb66c, 0xb66d, 0xb66e, 0xb66f, 0xb670, 0xb671, 0xb672, 0xb673,
+    0xb674, 0xb675, 0xb676, 0xb677, 0xb678, 0xb679, 0xb67a, 0xb67b, 0xb67c, 0xb67d, 0xb67e, 0xb67f, 0xb680, 0xb681, 0xb682, 0xb683,
+    0xb684, 0xb685, 0xb686, 0xb687, 0xb688, 0xb689, 0xb68a, 0xb68b, 0xb68c, 0xb68d, 0xb68e, 0xb68f, 0xb690, 0xb691, 0xb692, 0xb693,
+    0xb694, 0xb695, 0xb696, 0xb697, 0xb698, 0xb699, 0xb69a, 0xb69b, 0xb69e, 0xb69f, 0xb6a1, 0xb6a2, 0xb6a3, 0xb6a5, 0xb6a6, 0xb6a7,
+    0xb6a8, 0xb6a9, 0xb6aa, 0xb6ad, 0xb6ae, 0xb6af, 0xb6b0, 0xb6b2, 0xb6b3, 0xb6b4, 0xb6b5, 0xb6b6, 0xb6b7, 0xb6b8, 0xb6b9, 0xb6ba,
+    0xb6bb, 0xb6bc, 0xb6bd, 0xb6be, 0xb6bf, 0xb6c0, 0xb6c1, 0xb6c2, 0xb6c3, 0xb6c4, 0xb6c5, 0xb6c6, 0xb6c7, 0xb6c8, 0xb6c9, 0xb6ca,
+    0xb6cb, 0xb6cc, 0xb6cd, 0xb6ce, 0xb6cf, 0xb6d0, 0xb6d1, 0xb6d2, 0xb6d3, 0xb6d5, 0xb6d6, 0xb6d7, 0xb6d8, 0xb6d9, 0xb6da, 0xb6db,
+    0xb6dc, 0xb6dd, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb6de, 0xb6df, 0xb6e0, 0xb6e1, 0xb6e2, 0xb6e3, 0xb6e4, 0xb6e5,
+    0xb6e6, 0xb6e7, 0xb6e8, 0xb6e9, 0xb6ea, 0xb6eb, 0xb6ec, 0xb6ed, 0xb6ee, 0xb6ef, 0xb6f1, 0xb6f2, 0xb6f3, 0xb6f5, 0xb6f6, 0xb6f7,
+    0xb6f9, 0xb6fa, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb6fb, 0xb6fc, 0xb6fd, 0xb6fe, 0xb6ff, 0xb702, 0xb703, 0xb704,
+    0xb706, 0xb707, 0xb708, 0xb709, 0xb70a, 0xb70b, 0xb70c, 0xb70d, 0xb70e, 0xb70f, 0xb710, 0xb711, 0xb712, 0xb713, 0xb714, 0xb715,
+    0xb716, 0xb717, 0xb718, 0xb719, 0xb71a, 0xb71b, 0xb71c, 0xb71d, 0xb71e, 0xb71f, 0xb720, 0xb721, 0xb722, 0xb723, 0xb724, 0xb725,
+    0xb726, 0xb727, 0xb72a, 0xb72b, 0xb72d, 0xb72e, 0xb731, 0xb732, 0xb733, 0xb734, 0xb735, 0xb736, 0xb737, 0xb73a, 0xb73c, 0xb73d,
+    0xb73e, 0xb73f, 0xb740, 0xb741, 0xb742, 0xb743, 0xb745, 0xb746, 0xb747, 0xb749, 0xb74a, 0xb74b, 0xb74d, 0xb74e, 0xb74f, 0xb750,
+    0xb751, 0xb752, 0xb753, 0xb756, 0xb757, 0xb758, 0xb759, 0xb75a, 0xb75b, 0xb75c, 0xb75d, 0xb75e, 0xb75f, 0xb761, 0xb762, 0xb763,
+    0xb765, 0xb766, 0xb767, 0xb769, 0xb76a, 0xb76b, 0xb76c, 0xb76d, 0xb76e, 0xb76f, 0xb772, 0xb774, 0xb776, 0xb777, 0xb778, 0xb779,
+    0xb77a, 0xb77b, 0xb77e, 0xb77f, 0xb781, 0xb782, 0xb783, 0xb785, 0xb786, 0xb787, 0xb788, 0xb789, 0xb78a, 0xb78b, 0xb78e, 0xb793,
+    0xb794, 0xb795, 0xb79a, 0xb79b, 0xb79d, 0xb79e, 0xb79f, 0xb7a1, 0xb7a2, 0xb7a3, 0xb7a4, 0xb7a5, 0xb7a6, 0xb7a7, 0xb7aa, 0xb7ae,
+    0xb7af, 0xb7b0, 0xb7b1, 0xb7b2, 0xb7b3, 0xb7b6, 0xb7b7, 0xb7b9, 0xb7ba, 0xb7bb, 0xb7bc, 0xb7bd, 0xb7be, 0xb7bf, 0xb7c0, 0xb7c1,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb7c2, 0xb7c3, 0xb7c4, 0xb7c5, 0xb7c6, 0xb7c8, 0xb7ca, 0xb7cb, 0xb7cc, 0xb7cd,
+    0xb7ce, 0xb7cf, 0xb7d0, 0xb7d1, 0xb7d2, 0xb7d3, 0xb7d4, 0xb7d5, 0xb7d6, 0xb7d7, 0xb7d8, 0xb7d9, 0xb7da, 0xb7db, 0xb7dc, 0xb7dd,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xb7de, 0xb7df, 0xb7e0, 0xb7e1, 0xb7e2, 0xb7e3, 0xb7e4, 0xb7e5, 0xb7e6, 0xb7e7,
+    0xb7e8, 0xb7e9, 0xb7ea, 0xb7eb, 0xb7ee, 0xb7ef, 0xb7f1, 0xb7f2, 0xb7f3, 0xb7f5, 0xb7f6, 0xb7f7, 0xb7f8, 0xb7f9, 0xb7fa, 0xb7fb,
+    0xb7fe, 0xb802, 0xb803, 0xb804, 0xb805, 0xb806, 0xb80a, 0xb80b, 0xb80d, 0xb80e, 0xb80f, 0xb811, 0xb812, 0xb813, 0xb814, 0xb815,
+    0xb816, 0xb817, 0xb81a, 0xb81c, 0xb81e, 0xb81f, 0xb820, 0xb821, 0xb822, 0xb823, 0xb826, 0xb827, 0xb829, 0xb82a, 0xb82b, 0xb82d,
+    0xb82e, 0xb82f, 0xb830, 0xb831, 0xb832, 0xb833, 0xb836, 0xb83a, 0xb83b, 0xb83c, 0xb83d, 0xb83e, 0xb83f, 0xb841, 0xb842, 0xb843,
+    0xb845, 0xb846, 0xb847, 0xb848, 0xb849, 0xb84a, 0xb84b, 0xb84c, 0xb84d, 0xb84e, 0xb84f, 0xb850, 0xb852, 0xb854, 0xb855, 0xb856,
+    0xb857, 0xb858, 0xb859, 0xb85a, 0xb85b, 0xb85e, 0xb85f, 0xb861, 0xb862, 0xb863, 0xb865, 0xb866, 0xb867, 0xb868, 0xb869, 0xb86a,
+    0xb86b, 0xb86e, 0xb870, 0xb872, 0xb873, 0xb874, 0xb875, 0xb876, 0xb877, 0xb879, 0xb87a, 0xb87b, 0xb87d, 0xb87e, 0xb87f, 0xb880,
+    0xb881, 0xb882, 0xb883, 0xb884, 0xb885, 0xb886, 0xb887, 0xb888, 0xb889, 0xb88a, 0xb88b, 0xb88c, 0xb88e, 0xb88f, 0xb890, 0xb891,
+    0xb892, 0xb893, 0xb894, 0xb895, 0xb896, 0xb897, 0xb898, 0xb899, 0xb89a, 0xb89b, 0xb89c, 0xb89d, 0xb89e, 0xb89f, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xb8a0, 0xb8a1, 0xb8a2, 0xb8a3, 0xb8a4, 0xb8a5, 0xb8a6, 0xb8a7, 0xb8a9, 0xb8aa, 0xb8ab, 0xb8ac,
+    0xb8ad, 0xb8ae, 0xb8af, 0xb8b1, 0xb8b2, 0xb8b3, 0xb8b5, 0xb8b6, 0xb8b7, 0xb8b9, 0xb8ba, 0xb8bb, 0xb8bc, 0xb8bd, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xb8be, 0xb8bf, 0xb8c2, 0xb8c4, 0xb8c6, 0xb8c7, 0xb8c8, 0xb8c9, 0xb8ca, 0xb8cb, 0xb8cd, 0xb8ce,
+    0xb8cf, 0xb8d1, 0xb8d2, 0xb8d3, 0xb8d5, 0xb8d6, 0xb8d7, 0xb8d8, 0xb8d9, 0xb8da, 0xb8db, 0xb8dc, 0xb8de, 0xb8e0, 0xb8e2, 0xb8e3,
+    0xb8e4, 0xb8e5, 0xb8e6, 0xb8e7, 0xb8ea, 0xb8eb, 0xb8ed, 0xb8ee, 0xb8ef, 0xb8f1, 0xb8f2, 0xb8f3, 0xb8f4, 0xb8f5, 0xb8f6, 0xb8f7,
+    0xb8fa, 0xb8fc, 0xb8fe, 0xb8ff, 0xb900, 0xb901, 0xb902, 0xb903, 0xb905, 0xb906, 0xb907, 0xb908, 0xb909, 0xb90a, 0xb90b, 0xb90c,
+    0xb90d, 0xb90e, 0xb90f, 0xb910, 0xb911, 0xb912, 0xb913, 0xb914, 0xb915, 0xb916, 0xb917, 0xb919, 0xb91a, 0xb91b, 0xb91c, 0xb91d,
+    0xb91e, 0xb91f, 0xb921, 0xb922, 0xb923, 0xb924, 0xb925, 0xb926, 0xb927, 0xb928, 0xb929, 0xb92a, 0xb92b, 0xb92c, 0xb92d, 0xb92e,
+    0xb92f, 0xb930, 0xb931, 0xb932, 0xb933, 0xb934, 0xb935, 0xb936, 0xb937, 0xb938, 0xb939, 0xb93a, 0xb93b, 0xb93e, 0xb93f, 0xb941,
+    0xb942, 0xb943, 0xb945, 0xb946, 0xb947, 0xb948, 0xb949, 0xb94a, 0xb94b, 0xb94d, 0xb94e, 0xb950, 0xb952, 0xb953, 0xb954, 0xb955,
+    0xb956, 0xb957, 0xb95a, 0xb95b, 0xb95d, 0xb95e, 0xb95f, 0xb961, 0xb962, 0xb963, 0xb964, 0xb965, 0xb966, 0xb967, 0xb96a, 0xb96c,
+    0xb96e, 0xb96f, 0xb970, 0xb971, 0xb972, 0xb973, 0xb976, 0xb977, 0xb979, 0xb97a, 0xb97b, 0xb97d, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xb97e, 0xb97f, 0xb980, 0xb981, 0xb982, 0xb983, 0xb986, 0xb988, 0xb98b, 0xb98c, 0xb98f, 0xb990, 0xb991, 0xb992,
+    0xb993, 0xb994, 0xb995, 0xb996, 0xb997, 0xb998, 0xb999, 0xb99a, 0xb99b, 0xb99c, 0xb99d, 0xb99e, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xb99f, 0xb9a0, 0xb9a1, 0xb9a2, 0xb9a3, 0xb9a4, 0xb9a5, 0xb9a6, 0xb9a7, 0xb9a8, 0xb9a9, 0xb9aa, 0xb9ab, 0xb9ae,
+    0xb9af, 0xb9b1, 0xb9b2, 0xb9b3, 0xb9b5, 0xb9b6, 0xb9b7, 0xb9b8, 0xb9b9, 0xb9ba, 0xb9bb, 0xb9be, 0xb9c0, 0xb9c2, 0xb9c3, 0xb9c4,
+    0xb9c5, 0xb9c6, 0xb9c7, 0xb9ca, 0xb9cb, 0xb9cd, 0xb9d3, 0xb9d4, 0xb9d5, 0xb9d6, 0xb9d7, 0xb9da, 0xb9dc, 0xb9df, 0xb9e0, 0xb9e2,
+    0xb9e6, 0xb9e7, 0xb9e9, 0xb9ea, 0xb9eb, 0xb9ed, 0xb9ee, 0xb9ef, 0xb9f0, 0xb9f1, 0xb9f2, 0xb9f3, 0xb9f6, 0xb9fb, 0xb9fc, 0xb9fd,
+    0xb9fe, 0xb9ff, 0xba02, 0xba03, 0xba04, 0xba05, 0xba06, 0xba07, 0xba09, 0xba0a, 0xba0b, 0xba0c, 0xba0d, 0xba0e, 0xba0f, 0xba10,
+    0xba11, 0xba12, 0xba13, 0xba14, 0xba16, 0xba17, 0xba18, 0xba19, 0xba1a, 0xba1b, 0xba1c, 0xba1d, 0xba1e, 0xba1f, 0xba20, 0xba21,
+    0xba22, 0xba23, 0xba24, 0xba25, 0xba26, 0xba27, 0xba28, 0xba29, 0xba2a, 0xba2b, 0xba2c, 0xba2d, 0xba2e, 0xba2f, 0xba30, 0xba31,
+    0xba32, 0xba33, 0xba34, 0xba35, 0xba36, 0xba37, 0xba3a, 0xba3b, 0xba3d, 0xba3e, 0xba3f, 0xba41, 0xba43, 0xba44, 0xba45, 0xba46,
+    0xba47, 0xba4a, 0xba4c, 0xba4f, 0xba50, 0xba51, 0xba52, 0xba56, 0xba57, 0xba59, 0xba5a, 0xba5b, 0xba5d, 0xba5e, 0xba5f, 0xba60,
+    0xba61, 0xba62, 0xba63, 0xba66, 0xba6a, 0xba6b, 0xba6c, 0xba6d, 0xba6e, 0xba6f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xba72, 0xba73, 0xba75, 0xba76, 0xba77, 0xba79, 0xba7a, 0xba7b, 0xba7c, 0xba7d, 0xba7e, 0xba7f, 0xba80, 0xba81, 0xba82, 0xba86,
+    0xba88, 0xba89, 0xba8a, 0xba8b, 0xba8d, 0xba8e, 0xba8f, 0xba90, 0xba91, 0xba92, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xba93, 0xba94, 0xba95, 0xba96, 0xba97, 0xba98, 0xba99, 0xba9a, 0xba9b, 0xba9c, 0xba9d, 0xba9e, 0xba9f, 0xbaa0, 0xbaa1, 0xbaa2,
+    0xbaa3, 0xbaa4, 0xbaa5, 0xbaa6, 0xbaa7, 0xbaaa, 0xbaad, 0xbaae, 0xbaaf, 0xbab1, 0xbab3, 0xbab4, 0xbab5, 0xbab6, 0xbab7, 0xbaba,
+    0xbabc, 0xbabe, 0xbabf, 0xbac0, 0xbac1, 0xbac2, 0xbac3, 0xbac5, 0xbac6, 0xbac7, 0xbac9, 0xbaca, 0xbacb, 0xbacc, 0xbacd, 0xbace,
+    0xbacf, 0xbad0, 0xbad1, 0xbad2, 0xbad3, 0xbad4, 0xbad5, 0xbad6, 0xbad7, 0xbada, 0xbadb, 0xbadc, 0xbadd, 0xbade, 0xbadf, 0xbae0,
+    0xbae1, 0xbae2, 0xbae3, 0xbae4, 0xbae5, 0xbae6, 0xbae7, 0xbae8, 0xbae9, 0xbaea, 0xbaeb, 0xbaec, 0xbaed, 0xbaee, 0xbaef, 0xbaf0,
+    0xbaf1, 0xbaf2, 0xbaf3, 0xbaf4, 0xbaf5, 0xbaf6, 0xbaf7, 0xbaf8, 0xbaf9, 0xbafa, 0xbafb, 0xbafd, 0xbafe, 0xbaff, 0xbb01, 0xbb02,
+    0xbb03, 0xbb05, 0xbb06, 0xbb07, 0xbb08, 0xbb09, 0xbb0a, 0xbb0b, 0xbb0c, 0xbb0e, 0xbb10, 0xbb12, 0xbb13, 0xbb14, 0xbb15, 0xbb16,
+    0xbb17, 0xbb19, 0xbb1a, 0xbb1b, 0xbb1d, 0xbb1e, 0xbb1f, 0xbb21, 0xbb22, 0xbb23, 0xbb24, 0xbb25, 0xbb26, 0xbb27, 0xbb28, 0xbb2a,
+    0xbb2c, 0xbb2d, 0xbb2e, 0xbb2f, 0xbb30, 0xbb31, 0xbb32, 0xbb33, 0xbb37, 0xbb39, 0xbb3a, 0xbb3f, 0xbb40, 0xbb41, 0xbb42, 0xbb43,
+    0xbb46, 0xbb48, 0xbb4a, 0xbb4b, 0xbb4c, 0xbb4e, 0xbb51, 0xbb52, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbb53, 0xbb55,
+    0xbb56, 0xbb57, 0xbb59, 0xbb5a, 0xbb5b, 0xbb5c, 0xbb5d, 0xbb5e, 0xbb5f, 0xbb60, 0xbb62, 0xbb64, 0xbb65, 0xbb66, 0xbb67, 0xbb68,
+    0xbb69, 0xbb6a, 0xbb6b, 0xbb6d, 0xbb6e, 0xbb6f, 0xbb70, 0xbb71, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbb72, 0xbb73,
+    0xbb74, 0xbb75, 0xbb76, 0xbb77, 0xbb78, 0xbb79, 0xbb7a, 0xbb7b, 0xbb7c, 0xbb7d, 0xbb7e, 0xbb7f, 0xbb80, 0xbb81, 0xbb82, 0xbb83,
+    0xbb84, 0xbb85, 0xbb86, 0xbb87, 0xbb89, 0xbb8a, 0xbb8b, 0xbb8d, 0xbb8e, 0xbb8f, 0xbb91, 0xbb92, 0xbb93, 0xbb94, 0xbb95, 0xbb96,
+    0xbb97, 0xbb98, 0xbb99, 0xbb9a, 0xbb9b, 0xbb9c, 0xbb9d, 0xbb9e, 0xbb9f, 0xbba0, 0xbba1, 0xbba2, 0xbba3, 0xbba5, 0xbba6, 0xbba7,
+    0xbba9, 0xbbaa, 0xbbab, 0xbbad, 0xbbae, 0xbbaf, 0xbbb0, 0xbbb1, 0xbbb2, 0xbbb3, 0xbbb5, 0xbbb6, 0xbbb8, 0xbbb9, 0xbbba, 0xbbbb,
+    0xbbbc, 0xbbbd, 0xbbbe, 0xbbbf, 0xbbc1, 0xbbc2, 0xbbc3, 0xbbc5, 0xbbc6, 0xbbc7, 0xbbc9, 0xbbca, 0xbbcb, 0xbbcc, 0xbbcd, 0xbbce,
+    0xbbcf, 0xbbd1, 0xbbd2, 0xbbd4, 0xbbd5, 0xbbd6, 0xbbd7, 0xbbd8, 0xbbd9, 0xbbda, 0xbbdb, 0xbbdc, 0xbbdd, 0xbbde, 0xbbdf, 0xbbe0,
+    0xbbe1, 0xbbe2, 0xbbe3, 0xbbe4, 0xbbe5, 0xbbe6, 0xbbe7, 0xbbe8, 0xbbe9, 0xbbea, 0xbbeb, 0xbbec, 0xbbed, 0xbbee, 0xbbef, 0xbbf0,
+    0xbbf1, 0xbbf2, 0xbbf3, 0xbbf4, 0xbbf5, 0xbbf6, 0xbbf7, 0xbbfa, 0xbbfb, 0xbbfd, 0xbbfe, 0xbc01, 0xbc03, 0xbc04, 0xbc05, 0xbc06,
+    0xbc07, 0xbc0a, 0xbc0e, 0xbc10, 0xbc12, 0xbc13, 0xbc19, 0xbc1a, 0xbc20, 0xbc21, 0xbc22, 0xbc23, 0xbc26, 0xbc28, 0xbc2a, 0xbc2b,
+    0xbc2c, 0xbc2e, 0xbc2f, 0xbc32, 0xbc33, 0xbc35, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbc36, 0xbc37, 0xbc39, 0xbc3a,
+    0xbc3b, 0xbc3c, 0xbc3d, 0xbc3e, 0xbc3f, 0xbc42, 0xbc46, 0xbc47, 0xbc48, 0xbc4a, 0xbc4b, 0xbc4e, 0xbc4f, 0xbc51, 0xbc52, 0xbc53,
+    0xbc54, 0xbc55, 0xbc56, 0xbc57, 0xbc58, 0xbc59, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbc5a, 0xbc5b, 0xbc5c, 0xbc5e,
+    0xbc5f, 0xbc60, 0xbc61, 0xbc62, 0xbc63, 0xbc64, 0xbc65, 0xbc66, 0xbc67, 0xbc68, 0xbc69, 0xbc6a, 0xbc6b, 0xbc6c, 0xbc6d, 0xbc6e,
+    0xbc6f, 0xbc70, 0xbc71, 0xbc72, 0xbc73, 0xbc74, 0xbc75, 0xbc76, 0xbc77, 0xbc78, 0xbc79, 0xbc7a, 0xbc7b, 0xbc7c, 0xbc7d, 0xbc7e,
+    0xbc7f, 0xbc80, 0xbc81, 0xbc82, 0xbc83, 0xbc86, 0xbc87, 0xbc89, 0xbc8a, 0xbc8d, 0xbc8f, 0xbc90, 0xbc91, 0xbc92, 0xbc93, 0xbc96,
+    0xbc98, 0xbc9b, 0xbc9c, 0xbc9d, 0xbc9e, 0xbc9f, 0xbca2, 0xbca3, 0xbca5, 0xbca6, 0xbca9, 0xbcaa, 0xbcab, 0xbcac, 0xbcad, 0xbcae,
+    0xbcaf, 0xbcb2, 0xbcb6, 0xbcb7, 0xbcb8, 0xbcb9, 0xbcba, 0xbcbb, 0xbcbe, 0xbcbf, 0xbcc1, 0xbcc2, 0xbcc3, 0xbcc5, 0xbcc6, 0xbcc7,
+    0xbcc8, 0xbcc9, 0xbcca, 0xbccb, 0xbccc, 0xbcce, 0xbcd2, 0xbcd3, 0xbcd4, 0xbcd6, 0xbcd7, 0xbcd9, 0xbcda, 0xbcdb, 0xbcdd, 0xbcde,
+    0xbcdf, 0xbce0, 0xbce1, 0xbce2, 0xbce3, 0xbce4, 0xbce5, 0xbce6, 0xbce7, 0xbce8, 0xbce9, 0xbcea, 0xbceb, 0xbcec, 0xbced, 0xbcee,
+    0xbcef, 0xbcf0, 0xbcf1, 0xbcf2, 0xbcf3, 0xbcf7, 0xbcf9, 0xbcfa, 0xbcfb, 0xbcfd, 0xbcfe, 0xbcff, 0xbd00, 0xbd01, 0xbd02, 0xbd03,
+    0xbd06, 0xbd08, 0xbd0a, 0xbd0b, 0xbd0c, 0xbd0d, 0xbd0e, 0xbd0f, 0xbd11, 0xbd12, 0xbd13, 0xbd15, 0xbd16, 0xbd17, 0xbd18, 0xbd19,
+    0xbd1a, 0xbd1b, 0xbd1c, 0xbd1d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd1e, 0xbd1f, 0xbd20, 0xbd21, 0xbd22, 0xbd23,
+    0xbd25, 0xbd26, 0xbd27, 0xbd28, 0xbd29, 0xbd2a, 0xbd2b, 0xbd2d, 0xbd2e, 0xbd2f, 0xbd30, 0xbd31, 0xbd32, 0xbd33, 0xbd34, 0xbd35,
+    0xbd36, 0xbd37, 0xbd38, 0xbd39, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbd3a, 0xbd3b, 0xbd3c, 0xbd3d, 0xbd3e, 0xbd3f,
+    0xbd41, 0xbd42, 0xbd43, 0xbd44, 0xbd45, 0xbd46, 0xbd47, 0xbd4a, 0xbd4b, 0xbd4d, 0xbd4e, 0xbd4f, 0xbd51, 0xbd52, 0xbd53, 0xbd54,
+    0xbd55, 0xbd56, 0xbd57, 0xbd5a, 0xbd5b, 0xbd5c, 0xbd5d, 0xbd5e, 0xbd5f, 0xbd60, 0xbd61, 0xbd62, 0xbd63, 0xbd65, 0xbd66, 0xbd67,
+    0xbd69, 0xbd6a, 0xbd6b, 0xbd6c, 0xbd6d, 0xbd6e, 0xbd6f, 0xbd70, 0xbd71, 0xbd72, 0xbd73, 0xbd74, 0xbd75, 0xbd76, 0xbd77, 0xbd78,
+    0xbd79, 0xbd7a, 0xbd7b, 0xbd7c, 0xbd7d, 0xbd7e, 0xbd7f, 0xbd82, 0xbd83, 0xbd85, 0xbd86, 0xbd8b, 0xbd8c, 0xbd8d, 0xbd8e, 0xbd8f,
+    0xbd92, 0xbd94, 0xbd96, 0xbd97, 0xbd98, 0xbd9b, 0xbd9d, 0xbd9e, 0xbd9f, 0xbda0, 0xbda1, 0xbda2, 0xbda3, 0xbda5, 0xbda6, 0xbda7,
+    0xbda8, 0xbda9, 0xbdaa, 0xbdab, 0xbdac, 0xbdad, 0xbdae, 0xbdaf, 0xbdb1, 0xbdb2, 0xbdb3, 0xbdb4, 0xbdb5, 0xbdb6, 0xbdb7, 0xbdb9,
+    0xbdba, 0xbdbb, 0xbdbc, 0xbdbd, 0xbdbe, 0xbdbf, 0xbdc0, 0xbdc1, 0xbdc2, 0xbdc3, 0xbdc4, 0xbdc5, 0xbdc6, 0xbdc7, 0xbdc8, 0xbdc9,
+    0xbdca, 0xbdcb, 0xbdcc, 0xbdcd, 0xbdce, 0xbdcf, 0xbdd0, 0xbdd1, 0xbdd2, 0xbdd3, 0xbdd6, 0xbdd7, 0xbdd9, 0xbdda, 0xbddb, 0xbddd,
+    0xbdde, 0xbddf, 0xbde0, 0xbde1, 0xbde2, 0xbde3, 0xbde4, 0xbde5, 0xbde6, 0xbde7, 0xbde8, 0xbdea, 0xbdeb, 0xbdec, 0xbded, 0xbdee,
+    0xbdef, 0xbdf1, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbdf2, 0xbdf3, 0xbdf5, 0xbdf6, 0xbdf7, 0xbdf9, 0xbdfa, 0xbdfb,
+    0xbdfc, 0xbdfd, 0xbdfe, 0xbdff, 0xbe01, 0xbe02, 0xbe04, 0xbe06, 0xbe07, 0xbe08, 0xbe09, 0xbe0a, 0xbe0b, 0xbe0e, 0xbe0f, 0xbe11,
+    0xbe12, 0xbe13, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbe15, 0xbe16, 0xbe17, 0xbe18, 0xbe19, 0xbe1a, 0xbe1b, 0xbe1e,
+    0xbe20, 0xbe21, 0xbe22, 0xbe23, 0xbe24, 0xbe25, 0xbe26, 0xbe27, 0xbe28, 0xbe29, 0xbe2a, 0xbe2b, 0xbe2c, 0xbe2d, 0xbe2e, 0xbe2f,
+    0xbe30, 0xbe31, 0xbe32, 0xbe33, 0xbe34, 0xbe35, 0xbe36, 0xbe37, 0xbe38, 0xbe39, 0xbe3a, 0xbe3b, 0xbe3c, 0xbe3d, 0xbe3e, 0xbe3f,
+    0xbe40, 0xbe41, 0xbe42, 0xbe43, 0xbe46, 0xbe47, 0xbe49, 0xbe4a, 0xbe4b, 0xbe4d, 0xbe4f, 0xbe50, 0xbe51, 0xbe52, 0xbe53, 0xbe56,
+    0xbe58, 0xbe5c, 0xbe5d, 0xbe5e, 0xbe5f, 0xbe62, 0xbe63, 0xbe65, 0xbe66, 0xbe67, 0xbe69, 0xbe6b, 0xbe6c, 0xbe6d, 0xbe6e, 0xbe6f,
+    0xbe72, 0xbe76, 0xbe77, 0xbe78, 0xbe79, 0xbe7a, 0xbe7e, 0xbe7f, 0xbe81, 0xbe82, 0xbe83, 0xbe85, 0xbe86, 0xbe87, 0xbe88, 0xbe89,
+    0xbe8a, 0xbe8b, 0xbe8e, 0xbe92, 0xbe93, 0xbe94, 0xbe95, 0xbe96, 0xbe97, 0xbe9a, 0xbe9b, 0xbe9c, 0xbe9d, 0xbe9e, 0xbe9f, 0xbea0,
+    0xbea1, 0xbea2, 0xbea3, 0xbea4, 0xbea5, 0xbea6, 0xbea7, 0xbea9, 0xbeaa, 0xbeab, 0xbeac, 0xbead, 0xbeae, 0xbeaf, 0xbeb0, 0xbeb1,
+    0xbeb2, 0xbeb3, 0xbeb4, 0xbeb5, 0xbeb6, 0xbeb7, 0xbeb8, 0xbeb9, 0xbeba, 0xbebb, 0xbebc, 0xbebd, 0xbebe, 0xbebf, 0xbec0, 0xbec1,
+    0xbec2, 0xbec3, 0xbec4, 0xbec5, 0xbec6, 0xbec7, 0xbec8, 0xbec9, 0xbeca, 0xbecb, 0xbecc, 0xbecd, 0xbece, 0xbecf, 0xbed2, 0xbed3,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbed5, 0xbed6, 0xbed9, 0xbeda, 0xbedb, 0xbedc, 0xbedd, 0xbede, 0xbedf, 0xbee1,
+    0xbee2, 0xbee6, 0xbee7, 0xbee8, 0xbee9, 0xbeea, 0xbeeb, 0xbeed, 0xbeee, 0xbeef, 0xbef0, 0xbef1, 0xbef2, 0xbef3, 0xbef4, 0xbef5,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xbef6, 0xbef7, 0xbef8, 0xbef9, 0xbefa, 0xbefb, 0xbefc, 0xbefd, 0xbefe, 0xbeff,
+    0xbf00, 0xbf02, 0xbf03, 0xbf04, 0xbf05, 0xbf06, 0xbf07, 0xbf0a, 0xbf0b, 0xbf0c, 0xbf0d, 0xbf0e, 0xbf0f, 0xbf10, 0xbf11, 0xbf12,
+    0xbf13, 0xbf14, 0xbf15, 0xbf16, 0xbf17, 0xbf1a, 0xbf1e, 0xbf1f, 0xbf20, 0xbf21, 0xbf22, 0xbf23, 0xbf24, 0xbf25, 0xbf26, 0xbf27,
+    0xbf28, 0xbf29, 0xbf2a, 0xbf2b, 0xbf2c, 0xbf2d, 0xbf2e, 0xbf2f, 0xbf30, 0xbf31, 0xbf32, 0xbf33, 0xbf34, 0xbf35, 0xbf36, 0xbf37,
+    0xbf38, 0xbf39, 0xbf3a, 0xbf3b, 0xbf3c, 0xbf3d, 0xbf3e, 0xbf3f, 0xbf42, 0xbf43, 0xbf45, 0xbf46, 0xbf47, 0xbf49, 0xbf4a, 0xbf4b,
+    0xbf4c, 0xbf4d, 0xbf4e, 0xbf4f, 0xbf52, 0xbf53, 0xbf54, 0xbf56, 0xbf57, 0xbf58, 0xbf59, 0xbf5a, 0xbf5b, 0xbf5c, 0xbf5d, 0xbf5e,
+    0xbf5f, 0xbf60, 0xbf61, 0xbf62, 0xbf63, 0xbf64, 0xbf65, 0xbf66, 0xbf67, 0xbf68, 0xbf69, 0xbf6a, 0xbf6b, 0xbf6c, 0xbf6d, 0xbf6e,
+    0xbf6f, 0xbf70, 0xbf71, 0xbf72, 0xbf73, 0xbf74, 0xbf75, 0xbf76, 0xbf77, 0xbf78, 0xbf79, 0xbf7a, 0xbf7b, 0xbf7c, 0xbf7d, 0xbf7e,
+    0xbf7f, 0xbf80, 0xbf81, 0xbf82, 0xbf83, 0xbf84, 0xbf85, 0xbf86, 0xbf87, 0xbf88, 0xbf89, 0xbf8a, 0xbf8b, 0xbf8c, 0xbf8d, 0xbf8e,
+    0xbf8f, 0xbf90, 0xbf91, 0xbf92, 0xbf93, 0xbf95, 0xbf96, 0xbf97, 0xbf98, 0xbf99, 0xbf9a, 0xbf9b, 0xbf9c, 0xbf9d, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xbf9e, 0xbf9f, 0xbfa0, 0xbfa1, 0xbfa2, 0xbfa3, 0xbfa4, 0xbfa5, 0xbfa6, 0xbfa7, 0xbfa8, 0xbfa9,
+    0xbfaa, 0xbfab, 0xbfac, 0xbfad, 0xbfae, 0xbfaf, 0xbfb1, 0xbfb2, 0xbfb3, 0xbfb4, 0xbfb5, 0xbfb6, 0xbfb7, 0xbfb8, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xbfb9, 0xbfba, 0xbfbb, 0xbfbc, 0xbfbd, 0xbfbe, 0xbfbf, 0xbfc0, 0xbfc1, 0xbfc2, 0xbfc3, 0xbfc4,
+    0xbfc6, 0xbfc7, 0xbfc8, 0xbfc9, 0xbfca, 0xbfcb, 0xbfce, 0xbfcf, 0xbfd1, 0xbfd2, 0xbfd3, 0xbfd5, 0xbfd6, 0xbfd7, 0xbfd8, 0xbfd9,
+    0xbfda, 0xbfdb, 0xbfdd, 0xbfde, 0xbfe0, 0xbfe2, 0xbfe3, 0xbfe4, 0xbfe5, 0xbfe6, 0xbfe7, 0xbfe8, 0xbfe9, 0xbfea, 0xbfeb, 0xbfec,
+    0xbfed, 0xbfee, 0xbfef, 0xbff0, 0xbff1, 0xbff2, 0xbff3, 0xbff4, 0xbff5, 0xbff6, 0xbff7, 0xbff8, 0xbff9, 0xbffa, 0xbffb, 0xbffc,
+    0xbffd, 0xbffe, 0xbfff, 0xc000, 0xc001, 0xc002, 0xc003, 0xc004, 0xc005, 0xc006, 0xc007, 0xc008, 0xc009, 0xc00a, 0xc00b, 0xc00c,
+    0xc00d, 0xc00e, 0xc00f, 0xc010, 0xc011, 0xc012, 0xc013, 0xc014, 0xc015, 0xc016, 0xc017, 0xc018, 0xc019, 0xc01a, 0xc01b, 0xc01c,
+    0xc01d, 0xc01e, 0xc01f, 0xc020, 0xc021, 0xc022, 0xc023, 0xc024, 0xc025, 0xc026, 0xc027, 0xc028, 0xc029, 0xc02a, 0xc02b, 0xc02c,
+    0xc02d, 0xc02e, 0xc02f, 0xc030, 0xc031, 0xc032, 0xc033, 0xc034, 0xc035, 0xc036, 0xc037, 0xc038, 0xc039, 0xc03a, 0xc03b, 0xc03d,
+    0xc03e, 0xc03f, 0xc040, 0xc041, 0xc042, 0xc043, 0xc044, 0xc045, 0xc046, 0xc047, 0xc048, 0xc049, 0xc04a, 0xc04b, 0xc04c, 0xc04d,
+    0xc04e, 0xc04f, 0xc050, 0xc052, 0xc053, 0xc054, 0xc055, 0xc056, 0xc057, 0xc059, 0xc05a, 0xc05b, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xc05d, 0xc05e, 0xc05f, 0xc061, 0xc062, 0xc063, 0xc064, 0xc065, 0xc066, 0xc067, 0xc06a, 0xc06b, 0xc06c, 0xc06d,
+    0xc06e, 0xc06f, 0xc070, 0xc071, 0xc072, 0xc073, 0xc074, 0xc075, 0xc076, 0xc077, 0xc078, 0xc079, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xc07a, 0xc07b, 0xc07c, 0xc07d, 0xc07e, 0xc07f, 0xc080, 0xc081, 0xc082, 0xc083, 0xc084, 0xc085, 0xc086, 0xc087,
+    0xc088, 0xc089, 0xc08a, 0xc08b, 0xc08c, 0xc08d, 0xc08e, 0xc08f, 0xc092, 0xc093, 0xc095, 0xc096, 0xc097, 0xc099, 0xc09a, 0xc09b,
+    0xc09c, 0xc09d, 0xc09e, 0xc09f, 0xc0a2, 0xc0a4, 0xc0a6, 0xc0a7, 0xc0a8, 0xc0a9, 0xc0aa, 0xc0ab, 0xc0ae, 0xc0b1, 0xc0b2, 0xc0b7,
+    0xc0b8, 0xc0b9, 0xc0ba, 0xc0bb, 0xc0be, 0xc0c2, 0xc0c3, 0xc0c4, 0xc0c6, 0xc0c7, 0xc0ca, 0xc0cb, 0xc0cd, 0xc0ce, 0xc0cf, 0xc0d1,
+    0xc0d2, 0xc0d3, 0xc0d4, 0xc0d5, 0xc0d6, 0xc0d7, 0xc0da, 0xc0de, 0xc0df, 0xc0e0, 0xc0e1, 0xc0e2, 0xc0e3, 0xc0e6, 0xc0e7, 0xc0e9,
+    0xc0ea, 0xc0eb, 0xc0ed, 0xc0ee, 0xc0ef, 0xc0f0, 0xc0f1, 0xc0f2, 0xc0f3, 0xc0f6, 0xc0f8, 0xc0fa, 0xc0fb, 0xc0fc, 0xc0fd, 0xc0fe,
+    0xc0ff, 0xc101, 0xc102, 0xc103, 0xc105, 0xc106, 0xc107, 0xc109, 0xc10a, 0xc10b, 0xc10c, 0xc10d, 0xc10e, 0xc10f, 0xc111, 0xc112,
+    0xc113, 0xc114, 0xc116, 0xc117, 0xc118, 0xc119, 0xc11a, 0xc11b, 0xc121, 0xc122, 0xc125, 0xc128, 0xc129, 0xc12a, 0xc12b, 0xc12e,
+    0xc132, 0xc133, 0xc134, 0xc135, 0xc137, 0xc13a, 0xc13b, 0xc13d, 0xc13e, 0xc13f, 0xc141, 0xc142, 0xc143, 0xc144, 0xc145, 0xc146,
+    0xc147, 0xc14a, 0xc14e, 0xc14f, 0xc150, 0xc151, 0xc152, 0xc153, 0xc156, 0xc157, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xc159, 0xc15a, 0xc15b, 0xc15d, 0xc15e, 0xc15f, 0xc160, 0xc161, 0xc162, 0xc163, 0xc166, 0xc16a, 0xc16b, 0xc16c, 0xc16d, 0xc16e,
+    0xc16f, 0xc171, 0xc172, 0xc173, 0xc175, 0xc176, 0xc177, 0xc179, 0xc17a, 0xc17b, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xc17c, 0xc17d, 0xc17e, 0xc17f, 0xc180, 0xc181, 0xc182, 0xc183, 0xc184, 0xc186, 0xc187, 0xc188, 0xc189, 0xc18a, 0xc18b, 0xc18f,
+    0xc191, 0xc192, 0xc193, 0xc195, 0xc197, 0xc198, 0xc199, 0xc19a, 0xc19b, 0xc19e, 0xc1a0, 0xc1a2, 0xc1a3, 0xc1a4, 0xc1a6, 0xc1a7,
+    0xc1aa, 0xc1ab, 0xc1ad, 0xc1ae, 0xc1af, 0xc1b1, 0xc1b2, 0xc1b3, 0xc1b4, 0xc1b5, 0xc1b6, 0xc1b7, 0xc1b8, 0xc1b9, 0xc1ba, 0xc1bb,
+    0xc1bc, 0xc1be, 0xc1bf, 0xc1c0, 0xc1c1, 0xc1c2, 0xc1c3, 0xc1c5, 0xc1c6, 0xc1c7, 0xc1c9, 0xc1ca, 0xc1cb, 0xc1cd, 0xc1ce, 0xc1cf,
+    0xc1d0, 0xc1d1, 0xc1d2, 0xc1d3, 0xc1d5, 0xc1d6, 0xc1d9, 0xc1da, 0xc1db, 0xc1dc, 0xc1dd, 0xc1de, 0xc1df, 0xc1e1, 0xc1e2, 0xc1e3,
+    0xc1e5, 0xc1e6, 0xc1e7, 0xc1e9, 0xc1ea, 0xc1eb, 0xc1ec, 0xc1ed, 0xc1ee, 0xc1ef, 0xc1f2, 0xc1f4, 0xc1f5, 0xc1f6, 0xc1f7, 0xc1f8,
+    0xc1f9, 0xc1fa, 0xc1fb, 0xc1fe, 0xc1ff, 0xc201, 0xc202, 0xc203, 0xc205, 0xc206, 0xc207, 0xc208, 0xc209, 0xc20a, 0xc20b, 0xc20e,
+    0xc210, 0xc212, 0xc213, 0xc214, 0xc215, 0xc216, 0xc217, 0xc21a, 0xc21b, 0xc21d, 0xc21e, 0xc221, 0xc222, 0xc223, 0xc224, 0xc225,
+    0xc226, 0xc227, 0xc22a, 0xc22c, 0xc22e, 0xc230, 0xc233, 0xc235, 0xc236, 0xc237, 0xc238, 0xc239, 0xc23a, 0xc23b, 0xc23c, 0xc23d,
+    0xc23e, 0xc23f, 0xc240, 0xc241, 0xc242, 0xc243, 0xc244, 0xc245, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc246, 0xc247,
+    0xc249, 0xc24a, 0xc24b, 0xc24c, 0xc24d, 0xc24e, 0xc24f, 0xc252, 0xc253, 0xc255, 0xc256, 0xc257, 0xc259, 0xc25a, 0xc25b, 0xc25c,
+    0xc25d, 0xc25e, 0xc25f, 0xc261, 0xc262, 0xc263, 0xc264, 0xc266, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc267, 0xc268,
+    0xc269, 0xc26a, 0xc26b, 0xc26e, 0xc26f, 0xc271, 0xc272, 0xc273, 0xc275, 0xc276, 0xc277, 0xc278, 0xc279, 0xc27a, 0xc27b, 0xc27e,
+    0xc280, 0xc282, 0xc283, 0xc284, 0xc285, 0xc286, 0xc287, 0xc28a, 0xc28b, 0xc28c, 0xc28d, 0xc28e, 0xc28f, 0xc291, 0xc292, 0xc293,
+    0xc294, 0xc295, 0xc296, 0xc297, 0xc299, 0xc29a, 0xc29c, 0xc29e, 0xc29f, 0xc2a0, 0xc2a1, 0xc2a2, 0xc2a3, 0xc2a6, 0xc2a7, 0xc2a9,
+    0xc2aa, 0xc2ab, 0xc2ae, 0xc2af, 0xc2b0, 0xc2b1, 0xc2b2, 0xc2b3, 0xc2b6, 0xc2b8, 0xc2ba, 0xc2bb, 0xc2bc, 0xc2bd, 0xc2be, 0xc2bf,
+    0xc2c0, 0xc2c1, 0xc2c2, 0xc2c3, 0xc2c4, 0xc2c5, 0xc2c6, 0xc2c7, 0xc2c8, 0xc2c9, 0xc2ca, 0xc2cb, 0xc2cc, 0xc2cd, 0xc2ce, 0xc2cf,
+    0xc2d0, 0xc2d1, 0xc2d2, 0xc2d3, 0xc2d4, 0xc2d5, 0xc2d6, 0xc2d7, 0xc2d8, 0xc2d9, 0xc2da, 0xc2db, 0xc2de, 0xc2df, 0xc2e1, 0xc2e2,
+    0xc2e5, 0xc2e6, 0xc2e7, 0xc2e8, 0xc2e9, 0xc2ea, 0xc2ee, 0xc2f0, 0xc2f2, 0xc2f3, 0xc2f4, 0xc2f5, 0xc2f7, 0xc2fa, 0xc2fd, 0xc2fe,
+    0xc2ff, 0xc301, 0xc302, 0xc303, 0xc304, 0xc305, 0xc306, 0xc307, 0xc30a, 0xc30b, 0xc30e, 0xc30f, 0xc310, 0xc311, 0xc312, 0xc316,
+    0xc317, 0xc319, 0xc31a, 0xc31b, 0xc31d, 0xc31e, 0xc31f, 0xc320, 0xc321, 0xc322, 0xc323, 0xc326, 0xc327, 0xc32a, 0xc32b, 0xc32c,
+    0xc32d, 0xc32e, 0xc32f, 0xc330, 0xc331, 0xc332, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc333, 0xc334, 0xc335, 0xc336,
+    0xc337, 0xc338, 0xc339, 0xc33a, 0xc33b, 0xc33c, 0xc33d, 0xc33e, 0xc33f, 0xc340, 0xc341, 0xc342, 0xc343, 0xc344, 0xc346, 0xc347,
+    0xc348, 0xc349, 0xc34a, 0xc34b, 0xc34c, 0xc34d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc34e, 0xc34f, 0xc350, 0xc351,
+    0xc352, 0xc353, 0xc354, 0xc355, 0xc356, 0xc357, 0xc358, 0xc359, 0xc35a, 0xc35b, 0xc35c, 0xc35d, 0xc35e, 0xc35f, 0xc360, 0xc361,
+    0xc362, 0xc363, 0xc364, 0xc365, 0xc366, 0xc367, 0xc36a, 0xc36b, 0xc36d, 0xc36e, 0xc36f, 0xc371, 0xc373, 0xc374, 0xc375, 0xc376,
+    0xc377, 0xc37a, 0xc37b, 0xc37e, 0xc37f, 0xc380, 0xc381, 0xc382, 0xc383, 0xc385, 0xc386, 0xc387, 0xc389, 0xc38a, 0xc38b, 0xc38d,
+    0xc38e, 0xc38f, 0xc390, 0xc391, 0xc392, 0xc393, 0xc394, 0xc395, 0xc396, 0xc397, 0xc398, 0xc399, 0xc39a, 0xc39b, 0xc39c, 0xc39d,
+    0xc39e, 0xc39f, 0xc3a0, 0xc3a1, 0xc3a2, 0xc3a3, 0xc3a4, 0xc3a5, 0xc3a6, 0xc3a7, 0xc3a8, 0xc3a9, 0xc3aa, 0xc3ab, 0xc3ac, 0xc3ad,
+    0xc3ae, 0xc3af, 0xc3b0, 0xc3b1, 0xc3b2, 0xc3b3, 0xc3b4, 0xc3b5, 0xc3b6, 0xc3b7, 0xc3b8, 0xc3b9, 0xc3ba, 0xc3bb, 0xc3bc, 0xc3bd,
+    0xc3be, 0xc3bf, 0xc3c1, 0xc3c2, 0xc3c3, 0xc3c4, 0xc3c5, 0xc3c6, 0xc3c7, 0xc3c8, 0xc3c9, 0xc3ca, 0xc3cb, 0xc3cc, 0xc3cd, 0xc3ce,
+    0xc3cf, 0xc3d0, 0xc3d1, 0xc3d2, 0xc3d3, 0xc3d4, 0xc3d5, 0xc3d6, 0xc3d7, 0xc3da, 0xc3db, 0xc3dd, 0xc3de, 0xc3e1, 0xc3e3, 0xc3e4,
+    0xc3e5, 0xc3e6, 0xc3e7, 0xc3ea, 0xc3eb, 0xc3ec, 0xc3ee, 0xc3ef, 0xc3f0, 0xc3f1, 0xc3f2, 0xc3f3, 0xc3f6, 0xc3f7, 0xc3f9, 0xc3fa,
+    0xc3fb, 0xc3fc, 0xc3fd, 0xc3fe, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc3ff, 0xc400, 0xc401, 0xc402, 0xc403, 0xc404,
+    0xc405, 0xc406, 0xc407, 0xc409, 0xc40a, 0xc40b, 0xc40c, 0xc40d, 0xc40e, 0xc40f, 0xc411, 0xc412, 0xc413, 0xc414, 0xc415, 0xc416,
+    0xc417, 0xc418, 0xc419, 0xc41a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc41b, 0xc41c, 0xc41d, 0xc41e, 0xc41f, 0xc420,
+    0xc421, 0xc422, 0xc423, 0xc425, 0xc426, 0xc427, 0xc428, 0xc429, 0xc42a, 0xc42b, 0xc42d, 0xc42e, 0xc42f, 0xc431, 0xc432, 0xc433,
+    0xc435, 0xc436, 0xc437, 0xc438, 0xc439, 0xc43a, 0xc43b, 0xc43e, 0xc43f, 0xc440, 0xc441, 0xc442, 0xc443, 0xc444, 0xc445, 0xc446,
+    0xc447, 0xc449, 0xc44a, 0xc44b, 0xc44c, 0xc44d, 0xc44e, 0xc44f, 0xc450, 0xc451, 0xc452, 0xc453, 0xc454, 0xc455, 0xc456, 0xc457,
+    0xc458, 0xc459, 0xc45a, 0xc45b, 0xc45c, 0xc45d, 0xc45e, 0xc45f, 0xc460, 0xc461, 0xc462, 0xc463, 0xc466, 0xc467, 0xc469, 0xc46a,
+    0xc46b, 0xc46d, 0xc46e, 0xc46f, 0xc470, 0xc471, 0xc472, 0xc473, 0xc476, 0xc477, 0xc478, 0xc47a, 0xc47b, 0xc47c, 0xc47d, 0xc47e,
+    0xc47f, 0xc481, 0xc482, 0xc483, 0xc484, 0xc485, 0xc486, 0xc487, 0xc488, 0xc489, 0xc48a, 0xc48b, 0xc48c, 0xc48d, 0xc48e, 0xc48f,
+    0xc490, 0xc491, 0xc492, 0xc493, 0xc495, 0xc496, 0xc497, 0xc498, 0xc499, 0xc49a, 0xc49b, 0xc49d, 0xc49e, 0xc49f, 0xc4a0, 0xc4a1,
+    0xc4a2, 0xc4a3, 0xc4a4, 0xc4a5, 0xc4a6, 0xc4a7, 0xc4a8, 0xc4a9, 0xc4aa, 0xc4ab, 0xc4ac, 0xc4ad, 0xc4ae, 0xc4af, 0xc4b0, 0xc4b1,
+    0xc4b2, 0xc4b3, 0xc4b4, 0xc4b5, 0xc4b6, 0xc4b7, 0xc4b9, 0xc4ba, 0xc4bb, 0xc4bd, 0xc4be, 0xc4bf, 0xc4c0, 0xc4c1, 0xc4c2, 0xc4c3,
+    0xc4c4, 0xc4c5, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc4c6, 0xc4c7, 0xc4c8, 0xc4c9, 0xc4ca, 0xc4cb, 0xc4cc, 0xc4cd,
+    0xc4ce, 0xc4cf, 0xc4d0, 0xc4d1, 0xc4d2, 0xc4d3, 0xc4d4, 0xc4d5, 0xc4d6, 0xc4d7, 0xc4d8, 0xc4d9, 0xc4da, 0xc4db, 0xc4dc, 0xc4dd,
+    0xc4de, 0xc4df, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc4e0, 0xc4e1, 0xc4e2, 0xc4e3, 0xc4e4, 0xc4e5, 0xc4e6, 0xc4e7,
+    0xc4e8, 0xc4ea, 0xc4eb, 0xc4ec, 0xc4ed, 0xc4ee, 0xc4ef, 0xc4f2, 0xc4f3, 0xc4f5, 0xc4f6, 0xc4f7, 0xc4f9, 0xc4fb, 0xc4fc, 0xc4fd,
+    0xc4fe, 0xc502, 0xc503, 0xc504, 0xc505, 0xc506, 0xc507, 0xc508, 0xc509, 0xc50a, 0xc50b, 0xc50d, 0xc50e, 0xc50f, 0xc511, 0xc512,
+    0xc513, 0xc515, 0xc516, 0xc517, 0xc518, 0xc519, 0xc51a, 0xc51b, 0xc51d, 0xc51e, 0xc51f, 0xc520, 0xc521, 0xc522, 0xc523, 0xc524,
+    0xc525, 0xc526, 0xc527, 0xc52a, 0xc52b, 0xc52d, 0xc52e, 0xc52f, 0xc531, 0xc532, 0xc533, 0xc534, 0xc535, 0xc536, 0xc537, 0xc53a,
+    0xc53c, 0xc53e, 0xc53f, 0xc540, 0xc541, 0xc542, 0xc543, 0xc546, 0xc547, 0xc54b, 0xc54f, 0xc550, 0xc551, 0xc552, 0xc556, 0xc55a,
+    0xc55b, 0xc55c, 0xc55f, 0xc562, 0xc563, 0xc565, 0xc566, 0xc567, 0xc569, 0xc56a, 0xc56b, 0xc56c, 0xc56d, 0xc56e, 0xc56f, 0xc572,
+    0xc576, 0xc577, 0xc578, 0xc579, 0xc57a, 0xc57b, 0xc57e, 0xc57f, 0xc581, 0xc582, 0xc583, 0xc585, 0xc586, 0xc588, 0xc589, 0xc58a,
+    0xc58b, 0xc58e, 0xc590, 0xc592, 0xc593, 0xc594, 0xc596, 0xc599, 0xc59a, 0xc59b, 0xc59d, 0xc59e, 0xc59f, 0xc5a1, 0xc5a2, 0xc5a3,
+    0xc5a4, 0xc5a5, 0xc5a6, 0xc5a7, 0xc5a8, 0xc5aa, 0xc5ab, 0xc5ac, 0xc5ad, 0xc5ae, 0xc5af, 0xc5b0, 0xc5b1, 0xc5b2, 0xc5b3, 0xc5b6,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc5b7, 0xc5ba, 0xc5bf, 0xc5c0, 0xc5c1, 0xc5c2, 0xc5c3, 0xc5cb, 0xc5cd, 0xc5cf,
+    0xc5d2, 0xc5d3, 0xc5d5, 0xc5d6, 0xc5d7, 0xc5d9, 0xc5da, 0xc5db, 0xc5dc, 0xc5dd, 0xc5de, 0xc5df, 0xc5e2, 0xc5e4, 0xc5e6, 0xc5e7,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc5e8, 0xc5e9, 0xc5ea, 0xc5eb, 0xc5ef, 0xc5f1, 0xc5f2, 0xc5f3, 0xc5f5, 0xc5f8,
+    0xc5f9, 0xc5fa, 0xc5fb, 0xc602, 0xc603, 0xc604, 0xc609, 0xc60a, 0xc60b, 0xc60d, 0xc60e, 0xc60f, 0xc611, 0xc612, 0xc613, 0xc614,
+    0xc615, 0xc616, 0xc617, 0xc61a, 0xc61d, 0xc61e, 0xc61f, 0xc620, 0xc621, 0xc622, 0xc623, 0xc626, 0xc627, 0xc629, 0xc62a, 0xc62b,
+    0xc62f, 0xc631, 0xc632, 0xc636, 0xc638, 0xc63a, 0xc63c, 0xc63d, 0xc63e, 0xc63f, 0xc642, 0xc643, 0xc645, 0xc646, 0xc647, 0xc649,
+    0xc64a, 0xc64b, 0xc64c, 0xc64d, 0xc64e, 0xc64f, 0xc652, 0xc656, 0xc657, 0xc658, 0xc659, 0xc65a, 0xc65b, 0xc65e, 0xc65f, 0xc661,
+    0xc662, 0xc663, 0xc664, 0xc665, 0xc666, 0xc667, 0xc668, 0xc669, 0xc66a, 0xc66b, 0xc66d, 0xc66e, 0xc670, 0xc672, 0xc673, 0xc674,
+    0xc675, 0xc676, 0xc677, 0xc67a, 0xc67b, 0xc67d, 0xc67e, 0xc67f, 0xc681, 0xc682, 0xc683, 0xc684, 0xc685, 0xc686, 0xc687, 0xc68a,
+    0xc68c, 0xc68e, 0xc68f, 0xc690, 0xc691, 0xc692, 0xc693, 0xc696, 0xc697, 0xc699, 0xc69a, 0xc69b, 0xc69d, 0xc69e, 0xc69f, 0xc6a0,
+    0xc6a1, 0xc6a2, 0xc6a3, 0xc6a6, 0xc6a8, 0xc6aa, 0xc6ab, 0xc6ac, 0xc6ad, 0xc6ae, 0xc6af, 0xc6b2, 0xc6b3, 0xc6b5, 0xc6b6, 0xc6b7,
+    0xc6bb, 0xc6bc, 0xc6bd, 0xc6be, 0xc6bf, 0xc6c2, 0xc6c4, 0xc6c6, 0xc6c7, 0xc6c8, 0xc6c9, 0xc6ca, 0xc6cb, 0xc6ce, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xc6cf, 0xc6d1, 0xc6d2, 0xc6d3, 0xc6d5, 0xc6d6, 0xc6d7, 0xc6d8, 0xc6d9, 0xc6da, 0xc6db, 0xc6de,
+    0xc6df, 0xc6e2, 0xc6e3, 0xc6e4, 0xc6e5, 0xc6e6, 0xc6e7, 0xc6ea, 0xc6eb, 0xc6ed, 0xc6ee, 0xc6ef, 0xc6f1, 0xc6f2, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xc6f3, 0xc6f4, 0xc6f5, 0xc6f6, 0xc6f7, 0xc6fa, 0xc6fb, 0xc6fc, 0xc6fe, 0xc6ff, 0xc700, 0xc701,
+    0xc702, 0xc703, 0xc706, 0xc707, 0xc709, 0xc70a, 0xc70b, 0xc70d, 0xc70e, 0xc70f, 0xc710, 0xc711, 0xc712, 0xc713, 0xc716, 0xc718,
+    0xc71a, 0xc71b, 0xc71c, 0xc71d, 0xc71e, 0xc71f, 0xc722, 0xc723, 0xc725, 0xc726, 0xc727, 0xc729, 0xc72a, 0xc72b, 0xc72c, 0xc72d,
+    0xc72e, 0xc72f, 0xc732, 0xc734, 0xc736, 0xc738, 0xc739, 0xc73a, 0xc73b, 0xc73e, 0xc73f, 0xc741, 0xc742, 0xc743, 0xc745, 0xc746,
+    0xc747, 0xc748, 0xc749, 0xc74b, 0xc74e, 0xc750, 0xc759, 0xc75a, 0xc75b, 0xc75d, 0xc75e, 0xc75f, 0xc761, 0xc762, 0xc763, 0xc764,
+    0xc765, 0xc766, 0xc767, 0xc769, 0xc76a, 0xc76c, 0xc76d, 0xc76e, 0xc76f, 0xc770, 0xc771, 0xc772, 0xc773, 0xc776, 0xc777, 0xc779,
+    0xc77a, 0xc77b, 0xc77f, 0xc780, 0xc781, 0xc782, 0xc786, 0xc78b, 0xc78c, 0xc78d, 0xc78f, 0xc792, 0xc793, 0xc795, 0xc799, 0xc79b,
+    0xc79c, 0xc79d, 0xc79e, 0xc79f, 0xc7a2, 0xc7a7, 0xc7a8, 0xc7a9, 0xc7aa, 0xc7ab, 0xc7ae, 0xc7af, 0xc7b1, 0xc7b2, 0xc7b3, 0xc7b5,
+    0xc7b6, 0xc7b7, 0xc7b8, 0xc7b9, 0xc7ba, 0xc7bb, 0xc7be, 0xc7c2, 0xc7c3, 0xc7c4, 0xc7c5, 0xc7c6, 0xc7c7, 0xc7ca, 0xc7cb, 0xc7cd,
+    0xc7cf, 0xc7d1, 0xc7d2, 0xc7d3, 0xc7d4, 0xc7d5, 0xc7d6, 0xc7d7, 0xc7d9, 0xc7da, 0xc7db, 0xc7dc, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xc7de, 0xc7df, 0xc7e0, 0xc7e1, 0xc7e2, 0xc7e3, 0xc7e5, 0xc7e6, 0xc7e7, 0xc7e9, 0xc7ea, 0xc7eb, 0xc7ed, 0xc7ee,
+    0xc7ef, 0xc7f0, 0xc7f1, 0xc7f2, 0xc7f3, 0xc7f4, 0xc7f5, 0xc7f6, 0xc7f7, 0xc7f8, 0xc7f9, 0xc7fa, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xc7fb, 0xc7fc, 0xc7fd, 0xc7fe, 0xc7ff, 0xc802, 0xc803, 0xc805, 0xc806, 0xc807, 0xc809, 0xc80b, 0xc80c, 0xc80d,
+    0xc80e, 0xc80f, 0xc812, 0xc814, 0xc817, 0xc818, 0xc819, 0xc81a, 0xc81b, 0xc81e, 0xc81f, 0xc821, 0xc822, 0xc823, 0xc825, 0xc826,
+    0xc827, 0xc828, 0xc829, 0xc82a, 0xc82b, 0xc82e, 0xc830, 0xc832, 0xc833, 0xc834, 0xc835, 0xc836, 0xc837, 0xc839, 0xc83a, 0xc83b,
+    0xc83d, 0xc83e, 0xc83f, 0xc841, 0xc842, 0xc843, 0xc844, 0xc845, 0xc846, 0xc847, 0xc84a, 0xc84b, 0xc84e, 0xc84f, 0xc850, 0xc851,
+    0xc852, 0xc853, 0xc855, 0xc856, 0xc857, 0xc858, 0xc859, 0xc85a, 0xc85b, 0xc85c, 0xc85d, 0xc85e, 0xc85f, 0xc860, 0xc861, 0xc862,
+    0xc863, 0xc864, 0xc865, 0xc866, 0xc867, 0xc868, 0xc869, 0xc86a, 0xc86b, 0xc86c, 0xc86d, 0xc86e, 0xc86f, 0xc872, 0xc873, 0xc875,
+    0xc876, 0xc877, 0xc879, 0xc87b, 0xc87c, 0xc87d, 0xc87e, 0xc87f, 0xc882, 0xc884, 0xc888, 0xc889, 0xc88a, 0xc88e, 0xc88f, 0xc890,
+    0xc891, 0xc892, 0xc893, 0xc895, 0xc896, 0xc897, 0xc898, 0xc899, 0xc89a, 0xc89b, 0xc89c, 0xc89e, 0xc8a0, 0xc8a2, 0xc8a3, 0xc8a4,
+    0xc8a5, 0xc8a6, 0xc8a7, 0xc8a9, 0xc8aa, 0xc8ab, 0xc8ac, 0xc8ad, 0xc8ae, 0xc8af, 0xc8b0, 0xc8b1, 0xc8b2, 0xc8b3, 0xc8b4, 0xc8b5,
+    0xc8b6, 0xc8b7, 0xc8b8, 0xc8b9, 0xc8ba, 0xc8bb, 0xc8be, 0xc8bf, 0xc8c0, 0xc8c1, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xc8c2, 0xc8c3, 0xc8c5, 0xc8c6, 0xc8c7, 0xc8c9, 0xc8ca, 0xc8cb, 0xc8cd, 0xc8ce, 0xc8cf, 0xc8d0, 0xc8d1, 0xc8d2, 0xc8d3, 0xc8d6,
+    0xc8d8, 0xc8da, 0xc8db, 0xc8dc, 0xc8dd, 0xc8de, 0xc8df, 0xc8e2, 0xc8e3, 0xc8e5, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xc8e6, 0xc8e7, 0xc8e8, 0xc8e9, 0xc8ea, 0xc8eb, 0xc8ec, 0xc8ed, 0xc8ee, 0xc8ef, 0xc8f0, 0xc8f1, 0xc8f2, 0xc8f3, 0xc8f4, 0xc8f6,
+    0xc8f7, 0xc8f8, 0xc8f9, 0xc8fa, 0xc8fb, 0xc8fe, 0xc8ff, 0xc901, 0xc902, 0xc903, 0xc907, 0xc908, 0xc909, 0xc90a, 0xc90b, 0xc90e,
+    0x3000, 0x3001, 0x3002, 0x00b7, 0x2025, 0x2026, 0x00a8, 0x3003, 0x00ad, 0x2015, 0x2225, 0xff3c, 0x223c, 0x2018, 0x2019, 0x201c,
+    0x201d, 0x3014, 0x3015, 0x3008, 0x3009, 0x300a, 0x300b, 0x300c, 0x300d, 0x300e, 0x300f, 0x3010, 0x3011, 0x00b1, 0x00d7, 0x00f7,
+    0x2260, 0x2264, 0x2265, 0x221e, 0x2234, 0x00b0, 0x2032, 0x2033, 0x2103, 0x212b, 0xffe0, 0xffe1, 0xffe5, 0x2642, 0x2640, 0x2220,
+    0x22a5, 0x2312, 0x2202, 0x2207, 0x2261, 0x2252, 0x00a7, 0x203b, 0x2606, 0x2605, 0x25cb, 0x25cf, 0x25ce, 0x25c7, 0x25c6, 0x25a1,
+    0x25a0, 0x25b3, 0x25b2, 0x25bd, 0x25bc, 0x2192, 0x2190, 0x2191, 0x2193, 0x2194, 0x3013, 0x226a, 0x226b, 0x221a, 0x223d, 0x221d,
+    0x2235, 0x222b, 0x222c, 0x2208, 0x220b, 0x2286, 0x2287, 0x2282, 0x2283, 0x222a, 0x2229, 0x2227, 0x2228, 0xffe2, 0xc910, 0xc912,
+    0xc913, 0xc914, 0xc915, 0xc916, 0xc917, 0xc919, 0xc91a, 0xc91b, 0xc91c, 0xc91d, 0xc91e, 0xc91f, 0xc920, 0xc921, 0xc922, 0xc923,
+    0xc924, 0xc925, 0xc926, 0xc927, 0xc928, 0xc929, 0xc92a, 0xc92b, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc92d, 0xc92e,
+    0xc92f, 0xc930, 0xc931, 0xc932, 0xc933, 0xc935, 0xc936, 0xc937, 0xc938, 0xc939, 0xc93a, 0xc93b, 0xc93c, 0xc93d, 0xc93e, 0xc93f,
+    0xc940, 0xc941, 0xc942, 0xc943, 0xc944, 0xc945, 0xc946, 0xc947, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc948, 0xc949,
+    0xc94a, 0xc94b, 0xc94c, 0xc94d, 0xc94e, 0xc94f, 0xc952, 0xc953, 0xc955, 0xc956, 0xc957, 0xc959, 0xc95a, 0xc95b, 0xc95c, 0xc95d,
+    0xc95e, 0xc95f, 0xc962, 0xc964, 0xc965, 0xc966, 0xc967, 0xc968, 0xc969, 0xc96a, 0xc96b, 0xc96d, 0xc96e, 0xc96f, 0x21d2, 0x21d4,
+    0x2200, 0x2203, 0x00b4, 0xff5e, 0x02c7, 0x02d8, 0x02dd, 0x02da, 0x02d9, 0x00b8, 0x02db, 0x00a1, 0x00bf, 0x02d0, 0x222e, 0x2211,
+    0x220f, 0x00a4, 0x2109, 0x2030, 0x25c1, 0x25c0, 0x25b7, 0x25b6, 0x2664, 0x2660, 0x2661, 0x2665, 0x2667, 0x2663, 0x2299, 0x25c8,
+    0x25a3, 0x25d0, 0x25d1, 0x2592, 0x25a4, 0x25a5, 0x25a8, 0x25a7, 0x25a6, 0x25a9, 0x2668, 0x260f, 0x260e, 0x261c, 0x261e, 0x00b6,
+    0x2020, 0x2021, 0x2195, 0x2197, 0x2199, 0x2196, 0x2198, 0x266d, 0x2669, 0x266a, 0x266c, 0x327f, 0x321c, 0x2116, 0x33c7, 0x2122,
+    0x33c2, 0x33d8, 0x2121, 0x20ac, 0x00ae, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc971, 0xc972, 0xc973, 0xc975,
+    0xc976, 0xc977, 0xc978, 0xc979, 0xc97a, 0xc97b, 0xc97d, 0xc97e, 0xc97f, 0xc980, 0xc981, 0xc982, 0xc983, 0xc984, 0xc985, 0xc986,
+    0xc987, 0xc98a, 0xc98b, 0xc98d, 0xc98e, 0xc98f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc991, 0xc992, 0xc993, 0xc994,
+    0xc995, 0xc996, 0xc997, 0xc99a, 0xc99c, 0xc99e, 0xc99f, 0xc9a0, 0xc9a1, 0xc9a2, 0xc9a3, 0xc9a4, 0xc9a5, 0xc9a6, 0xc9a7, 0xc9a8,
+    0xc9a9, 0xc9aa, 0xc9ab, 0xc9ac, 0xc9ad, 0xc9ae, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xc9af, 0xc9b0, 0xc9b1, 0xc9b2,
+    0xc9b3, 0xc9b4, 0xc9b5, 0xc9b6, 0xc9b7, 0xc9b8, 0xc9b9, 0xc9ba, 0xc9bb, 0xc9bc, 0xc9bd, 0xc9be, 0xc9bf, 0xc9c2, 0xc9c3, 0xc9c5,
+    0xc9c6, 0xc9c9, 0xc9cb, 0xc9cc, 0xc9cd, 0xc9ce, 0xc9cf, 0xc9d2, 0xc9d4, 0xc9d7, 0xc9d8, 0xc9db, 0xff01, 0xff02, 0xff03, 0xff04,
+    0xff05, 0xff06, 0xff07, 0xff08, 0xff09, 0xff0a, 0xff0b, 0xff0c, 0xff0d, 0xff0e, 0xff0f, 0xff10, 0xff11, 0xff12, 0xff13, 0xff14,
+    0xff15, 0xff16, 0xff17, 0xff18, 0xff19, 0xff1a, 0xff1b, 0xff1c, 0xff1d, 0xff1e, 0xff1f, 0xff20, 0xff21, 0xff22, 0xff23, 0xff24,
+    0xff25, 0xff26, 0xff27, 0xff28, 0xff29, 0xff2a, 0xff2b, 0xff2c, 0xff2d, 0xff2e, 0xff2f, 0xff30, 0xff31, 0xff32, 0xff33, 0xff34,
+    0xff35, 0xff36, 0xff37, 0xff38, 0xff39, 0xff3a, 0xff3b, 0xffe6, 0xff3d, 0xff3e, 0xff3f, 0xff40, 0xff41, 0xff42, 0xff43, 0xff44,
+    0xff45, 0xff46, 0xff47, 0xff48, 0xff49, 0xff4a, 0xff4b, 0xff4c, 0xff4d, 0xff4e, 0xff4f, 0xff50, 0xff51, 0xff52, 0xff53, 0xff54,
+    0xff55, 0xff56, 0xff57, 0xff58, 0xff59, 0xff5a, 0xff5b, 0xff5c, 0xff5d, 0xffe3, 0xc9de, 0xc9df, 0xc9e1, 0xc9e3, 0xc9e5, 0xc9e6,
+    0xc9e8, 0xc9e9, 0xc9ea, 0xc9eb, 0xc9ee, 0xc9f2, 0xc9f3, 0xc9f4, 0xc9f5, 0xc9f6, 0xc9f7, 0xc9fa, 0xc9fb, 0xc9fd, 0xc9fe, 0xc9ff,
+    0xca01, 0xca02, 0xca03, 0xca04, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xca05, 0xca06, 0xca07, 0xca0a, 0xca0e, 0xca0f,
+    0xca10, 0xca11, 0xca12, 0xca13, 0xca15, 0xca16, 0xca17, 0xca19, 0xca1a, 0xca1b, 0xca1c, 0xca1d, 0xca1e, 0xca1f, 0xca20, 0xca21,
+    0xca22, 0xca23, 0xca24, 0xca25, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xca26, 0xca27, 0xca28, 0xca2a, 0xca2b, 0xca2c,
+    0xca2d, 0xca2e, 0xca2f, 0xca30, 0xca31, 0xca32, 0xca33, 0xca34, 0xca35, 0xca36, 0xca37, 0xca38, 0xca39, 0xca3a, 0xca3b, 0xca3c,
+    0xca3d, 0xca3e, 0xca3f, 0xca40, 0xca41, 0xca42, 0xca43, 0xca44, 0xca45, 0xca46, 0x3131, 0x3132, 0x3133, 0x3134, 0x3135, 0x3136,
+    0x3137, 0x3138, 0x3139, 0x313a, 0x313b, 0x313c, 0x313d, 0x313e, 0x313f, 0x3140, 0x3141, 0x3142, 0x3143, 0x3144, 0x3145, 0x3146,
+    0x3147, 0x3148, 0x3149, 0x314a, 0x314b, 0x314c, 0x314d, 0x314e, 0x314f, 0x3150, 0x3151, 0x3152, 0x3153, 0x3154, 0x3155, 0x3156,
+    0x3157, 0x3158, 0x3159, 0x315a, 0x315b, 0x315c, 0x315d, 0x315e, 0x315f, 0x3160, 0x3161, 0x3162, 0x3163, 0x3164, 0x3165, 0x3166,
+    0x3167, 0x3168, 0x3169, 0x316a, 0x316b, 0x316c, 0x316d, 0x316e, 0x316f, 0x3170, 0x3171, 0x3172, 0x3173, 0x3174, 0x3175, 0x3176,
+    0x3177, 0x3178, 0x3179, 0x317a, 0x317b, 0x317c, 0x317d, 0x317e, 0x317f, 0x3180, 0x3181, 0x3182, 0x3183, 0x3184, 0x3185, 0x3186,
+    0x3187, 0x3188, 0x3189, 0x318a, 0x318b, 0x318c, 0x318d, 0x318e, 0xca47, 0xca48, 0xca49, 0xca4a, 0xca4b, 0xca4e, 0xca4f, 0xca51,
+    0xca52, 0xca53, 0xca55, 0xca56, 0xca57, 0xca58, 0xca59, 0xca5a, 0xca5b, 0xca5e, 0xca62, 0xca63, 0xca64, 0xca65, 0xca66, 0xca67,
+    0xca69, 0xca6a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xca6b, 0xca6c, 0xca6d, 0xca6e, 0xca6f, 0xca70, 0xca71, 0xca72,
+    0xca73, 0xca74, 0xca75, 0xca76, 0xca77, 0xca78, 0xca79, 0xca7a, 0xca7b, 0xca7c, 0xca7e, 0xca7f, 0xca80, 0xca81, 0xca82, 0xca83,
+    0xca85, 0xca86, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xca87, 0xca88, 0xca89, 0xca8a, 0xca8b, 0xca8c, 0xca8d, 0xca8e,
+    0xca8f, 0xca90, 0xca91, 0xca92, 0xca93, 0xca94, 0xca95, 0xca96, 0xca97, 0xca99, 0xca9a, 0xca9b, 0xca9c, 0xca9d, 0xca9e, 0xca9f,
+    0xcaa0, 0xcaa1, 0xcaa2, 0xcaa3, 0xcaa4, 0xcaa5, 0xcaa6, 0xcaa7, 0x2170, 0x2171, 0x2172, 0x2173, 0x2174, 0x2175, 0x2176, 0x2177,
+    0x2178, 0x2179, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2160, 0x2161, 0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168,
+    0x2169, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398,
+    0x0399, 0x039a, 0x039b, 0x039c, 0x039d, 0x039e, 0x039f, 0x03a0, 0x03a1, 0x03a3, 0x03a4, 0x03a5, 0x03a6, 0x03a7, 0x03a8, 0x03a9,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x03b1, 0x03b2, 0x03b3, 0x03b4, 0x03b5, 0x03b6, 0x03b7, 0x03b8,
+    0x03b9, 0x03ba, 0x03bb, 0x03bc, 0x03bd, 0x03be, 0x03bf, 0x03c0, 0x03c1, 0x03c3, 0x03c4, 0x03c5, 0x03c6, 0x03c7, 0x03c8, 0x03c9,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcaa8, 0xcaa9, 0xcaaa, 0xcaab, 0xcaac, 0xcaad, 0xcaae, 0xcaaf, 0xcab0, 0xcab1,
+    0xcab2, 0xcab3, 0xcab4, 0xcab5, 0xcab6, 0xcab7, 0xcab8, 0xcab9, 0xcaba, 0xcabb, 0xcabe, 0xcabf, 0xcac1, 0xcac2, 0xcac3, 0xcac5,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcac6, 0xcac7, 0xcac8, 0xcac9, 0xcaca, 0xcacb, 0xcace, 0xcad0, 0xcad2, 0xcad4,
+    0xcad5, 0xcad6, 0xcad7, 0xcada, 0xcadb, 0xcadc, 0xcadd, 0xcade, 0xcadf, 0xcae1, 0xcae2, 0xcae3, 0xcae4, 0xcae5, 0xcae6, 0xcae7,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcae8, 0xcae9, 0xcaea, 0xcaeb, 0xcaed, 0xcaee, 0xcaef, 0xcaf0, 0xcaf1, 0xcaf2,
+    0xcaf3, 0xcaf5, 0xcaf6, 0xcaf7, 0xcaf8, 0xcaf9, 0xcafa, 0xcafb, 0xcafc, 0xcafd, 0xcafe, 0xcaff, 0xcb00, 0xcb01, 0xcb02, 0xcb03,
+    0xcb04, 0xcb05, 0xcb06, 0xcb07, 0xcb09, 0xcb0a, 0x2500, 0x2502, 0x250c, 0x2510, 0x2518, 0x2514, 0x251c, 0x252c, 0x2524, 0x2534,
+    0x253c, 0x2501, 0x2503, 0x250f, 0x2513, 0x251b, 0x2517, 0x2523, 0x2533, 0x252b, 0x253b, 0x254b, 0x2520, 0x252f, 0x2528, 0x2537,
+    0x253f, 0x251d, 0x2530, 0x2525, 0x2538, 0x2542, 0x2512, 0x2511, 0x251a, 0x2519, 0x2516, 0x2515, 0x250e, 0x250d, 0x251e, 0x251f,
+    0x2521, 0x2522, 0x2526, 0x2527, 0x2529, 0x252a, 0x252d, 0x252e, 0x2531, 0x2532, 0x2535, 0x2536, 0x2539, 0x253a, 0x253d, 0x253e,
+    0x2540, 0x2541, 0x2543, 0x2544, 0x2545, 0x2546, 0x2547, 0x2548, 0x2549, 0x254a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xcb0b, 0xcb0c, 0xcb0d, 0xcb0e, 0xcb0f, 0xcb11, 0xcb12, 0xcb13, 0xcb15, 0xcb16, 0xcb17, 0xcb19,
+    0xcb1a, 0xcb1b, 0xcb1c, 0xcb1d, 0xcb1e, 0xcb1f, 0xcb22, 0xcb23, 0xcb24, 0xcb25, 0xcb26, 0xcb27, 0xcb28, 0xcb29, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xcb2a, 0xcb2b, 0xcb2c, 0xcb2d, 0xcb2e, 0xcb2f, 0xcb30, 0xcb31, 0xcb32, 0xcb33, 0xcb34, 0xcb35,
+    0xcb36, 0xcb37, 0xcb38, 0xcb39, 0xcb3a, 0xcb3b, 0xcb3c, 0xcb3d, 0xcb3e, 0xcb3f, 0xcb40, 0xcb42, 0xcb43, 0xcb44, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xcb45, 0xcb46, 0xcb47, 0xcb4a, 0xcb4b, 0xcb4d, 0xcb4e, 0xcb4f, 0xcb51, 0xcb52, 0xcb53, 0xcb54,
+    0xcb55, 0xcb56, 0xcb57, 0xcb5a, 0xcb5b, 0xcb5c, 0xcb5e, 0xcb5f, 0xcb60, 0xcb61, 0xcb62, 0xcb63, 0xcb65, 0xcb66, 0xcb67, 0xcb68,
+    0xcb69, 0xcb6a, 0xcb6b, 0xcb6c, 0x3395, 0x3396, 0x3397, 0x2113, 0x3398, 0x33c4, 0x33a3, 0x33a4, 0x33a5, 0x33a6, 0x3399, 0x339a,
+    0x339b, 0x339c, 0x339d, 0x339e, 0x339f, 0x33a0, 0x33a1, 0x33a2, 0x33ca, 0x338d, 0x338e, 0x338f, 0x33cf, 0x3388, 0x3389, 0x33c8,
+    0x33a7, 0x33a8, 0x33b0, 0x33b1, 0x33b2, 0x33b3, 0x33b4, 0x33b5, 0x33b6, 0x33b7, 0x33b8, 0x33b9, 0x3380, 0x3381, 0x3382, 0x3383,
+    0x3384, 0x33ba, 0x33bb, 0x33bc, 0x33bd, 0x33be, 0x33bf, 0x3390, 0x3391, 0x3392, 0x3393, 0x3394, 0x2126, 0x33c0, 0x33c1, 0x338a,
+    0x338b, 0x338c, 0x33d6, 0x33c5, 0x33ad, 0x33ae, 0x33af, 0x33db, 0x33a9, 0x33aa, 0x33ab, 0x33ac, 0x33dd, 0x33d0, 0x33d3, 0x33c3,
+    0x33c9, 0x33dc, 0x33c6, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xcb6d, 0xcb6e, 0xcb6f, 0xcb70, 0xcb71, 0xcb72, 0xcb73, 0xcb74, 0xcb75, 0xcb76, 0xcb77, 0xcb7a, 0xcb7b, 0xcb7c,
+    0xcb7d, 0xcb7e, 0xcb7f, 0xcb80, 0xcb81, 0xcb82, 0xcb83, 0xcb84, 0xcb85, 0xcb86, 0xcb87, 0xcb88, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xcb89, 0xcb8a, 0xcb8b, 0xcb8c, 0xcb8d, 0xcb8e, 0xcb8f, 0xcb90, 0xcb91, 0xcb92, 0xcb93, 0xcb94, 0xcb95, 0xcb96,
+    0xcb97, 0xcb98, 0xcb99, 0xcb9a, 0xcb9b, 0xcb9d, 0xcb9e, 0xcb9f, 0xcba0, 0xcba1, 0xcba2, 0xcba3, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xcba4, 0xcba5, 0xcba6, 0xcba7, 0xcba8, 0xcba9, 0xcbaa, 0xcbab, 0xcbac, 0xcbad, 0xcbae, 0xcbaf, 0xcbb0, 0xcbb1,
+    0xcbb2, 0xcbb3, 0xcbb4, 0xcbb5, 0xcbb6, 0xcbb7, 0xcbb9, 0xcbba, 0xcbbb, 0xcbbc, 0xcbbd, 0xcbbe, 0xcbbf, 0xcbc0, 0xcbc1, 0xcbc2,
+    0xcbc3, 0xcbc4, 0x00c6, 0x00d0, 0x00aa, 0x0126, 0x0000, 0x0132, 0x0000, 0x013f, 0x0141, 0x00d8, 0x0152, 0x00ba, 0x00de, 0x0166,
+    0x014a, 0x0000, 0x3260, 0x3261, 0x3262, 0x3263, 0x3264, 0x3265, 0x3266, 0x3267, 0x3268, 0x3269, 0x326a, 0x326b, 0x326c, 0x326d,
+    0x326e, 0x326f, 0x3270, 0x3271, 0x3272, 0x3273, 0x3274, 0x3275, 0x3276, 0x3277, 0x3278, 0x3279, 0x327a, 0x327b, 0x24d0, 0x24d1,
+    0x24d2, 0x24d3, 0x24d4, 0x24d5, 0x24d6, 0x24d7, 0x24d8, 0x24d9, 0x24da, 0x24db, 0x24dc, 0x24dd, 0x24de, 0x24df, 0x24e0, 0x24e1,
+    0x24e2, 0x24e3, 0x24e4, 0x24e5, 0x24e6, 0x24e7, 0x24e8, 0x24e9, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467,
+    0x2468, 0x2469, 0x246a, 0x246b, 0x246c, 0x246d, 0x246e, 0x00bd, 0x2153, 0x2154, 0x00bc, 0x00be, 0x215b, 0x215c, 0x215d, 0x215e,
+    0xcbc5, 0xcbc6, 0xcbc7, 0xcbc8, 0xcbc9, 0xcbca, 0xcbcb, 0xcbcc, 0xcbcd, 0xcbce, 0xcbcf, 0xcbd0, 0xcbd1, 0xcbd2, 0xcbd3, 0xcbd5,
+    0xcbd6, 0xcbd7, 0xcbd8, 0xcbd9, 0xcbda, 0xcbdb, 0xcbdc, 0xcbdd, 0xcbde, 0xcbdf, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xcbe0, 0xcbe1, 0xcbe2, 0xcbe3, 0xcbe5, 0xcbe6, 0xcbe8, 0xcbea, 0xcbeb, 0xcbec, 0xcbed, 0xcbee, 0xcbef, 0xcbf0, 0xcbf1, 0xcbf2,
+    0xcbf3, 0xcbf4, 0xcbf5, 0xcbf6, 0xcbf7, 0xcbf8, 0xcbf9, 0xcbfa, 0xcbfb, 0xcbfc, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xcbfd, 0xcbfe, 0xcbff, 0xcc00, 0xcc01, 0xcc02, 0xcc03, 0xcc04, 0xcc05, 0xcc06, 0xcc07, 0xcc08, 0xcc09, 0xcc0a, 0xcc0b, 0xcc0e,
+    0xcc0f, 0xcc11, 0xcc12, 0xcc13, 0xcc15, 0xcc16, 0xcc17, 0xcc18, 0xcc19, 0xcc1a, 0xcc1b, 0xcc1e, 0xcc1f, 0xcc20, 0xcc23, 0xcc24,
+    0x00e6, 0x0111, 0x00f0, 0x0127, 0x0131, 0x0133, 0x0138, 0x0140, 0x0142, 0x00f8, 0x0153, 0x00df, 0x00fe, 0x0167, 0x014b, 0x0149,
+    0x3200, 0x3201, 0x3202, 0x3203, 0x3204, 0x3205, 0x3206, 0x3207, 0x3208, 0x3209, 0x320a, 0x320b, 0x320c, 0x320d, 0x320e, 0x320f,
+    0x3210, 0x3211, 0x3212, 0x3213, 0x3214, 0x3215, 0x3216, 0x3217, 0x3218, 0x3219, 0x321a, 0x321b, 0x249c, 0x249d, 0x249e, 0x249f,
+    0x24a0, 0x24a1, 0x24a2, 0x24a3, 0x24a4, 0x24a5, 0x24a6, 0x24a7, 0x24a8, 0x24a9, 0x24aa, 0x24ab, 0x24ac, 0x24ad, 0x24ae, 0x24af,
+    0x24b0, 0x24b1, 0x24b2, 0x24b3, 0x24b4, 0x24b5, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479, 0x247a, 0x247b, 0x247c, 0x247d,
+    0x247e, 0x247f, 0x2480, 0x2481, 0x2482, 0x00b9, 0x00b2, 0x00b3, 0x2074, 0x207f, 0x2081, 0x2082, 0x2083, 0x2084, 0xcc25, 0xcc26,
+    0xcc2a, 0xcc2b, 0xcc2d, 0xcc2f, 0xcc31, 0xcc32, 0xcc33, 0xcc34, 0xcc35, 0xcc36, 0xcc37, 0xcc3a, 0xcc3f, 0xcc40, 0xcc41, 0xcc42,
+    0xcc43, 0xcc46, 0xcc47, 0xcc49, 0xcc4a, 0xcc4b, 0xcc4d, 0xcc4e, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcc4f, 0xcc50,
+    0xcc51, 0xcc52, 0xcc53, 0xcc56, 0xcc5a, 0xcc5b, 0xcc5c, 0xcc5d, 0xcc5e, 0xcc5f, 0xcc61, 0xcc62, 0xcc63, 0xcc65, 0xcc67, 0xcc69,
+    0xcc6a, 0xcc6b, 0xcc6c, 0xcc6d, 0xcc6e, 0xcc6f, 0xcc71, 0xcc72, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcc73, 0xcc74,
+    0xcc76, 0xcc77, 0xcc78, 0xcc79, 0xcc7a, 0xcc7b, 0xcc7c, 0xcc7d, 0xcc7e, 0xcc7f, 0xcc80, 0xcc81, 0xcc82, 0xcc83, 0xcc84, 0xcc85,
+    0xcc86, 0xcc87, 0xcc88, 0xcc89, 0xcc8a, 0xcc8b, 0xcc8c, 0xcc8d, 0xcc8e, 0xcc8f, 0xcc90, 0xcc91, 0xcc92, 0xcc93, 0x3041, 0x3042,
+    0x3043, 0x3044, 0x3045, 0x3046, 0x3047, 0x3048, 0x3049, 0x304a, 0x304b, 0x304c, 0x304d, 0x304e, 0x304f, 0x3050, 0x3051, 0x3052,
+    0x3053, 0x3054, 0x3055, 0x3056, 0x3057, 0x3058, 0x3059, 0x305a, 0x305b, 0x305c, 0x305d, 0x305e, 0x305f, 0x3060, 0x3061, 0x3062,
+    0x3063, 0x3064, 0x3065, 0x3066, 0x3067, 0x3068, 0x3069, 0x306a, 0x306b, 0x306c, 0x306d, 0x306e, 0x306f, 0x3070, 0x3071, 0x3072,
+    0x3073, 0x3074, 0x3075, 0x3076, 0x3077, 0x3078, 0x3079, 0x307a, 0x307b, 0x307c, 0x307d, 0x307e, 0x307f, 0x3080, 0x3081, 0x3082,
+    0x3083, 0x3084, 0x3085, 0x3086, 0x3087, 0x3088, 0x3089, 0x308a, 0x308b, 0x308c, 0x308d, 0x308e, 0x308f, 0x3090, 0x3091, 0x3092,
+    0x3093, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcc94, 0xcc95, 0xcc96, 0xcc97,
+    0xcc9a, 0xcc9b, 0xcc9d, 0xcc9e, 0xcc9f, 0xcca1, 0xcca2, 0xcca3, 0xcca4, 0xcca5, 0xcca6, 0xcca7, 0xccaa, 0xccae, 0xccaf, 0xccb0,
+    0xccb1, 0xccb2, 0xccb3, 0xccb6, 0xccb7, 0xccb9, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xccba, 0xccbb, 0xccbd, 0xccbe,
+    0xccbf, 0xccc0, 0xccc1, 0xccc2, 0xccc3, 0xccc6, 0xccc8, 0xccca, 0xcccb, 0xcccc, 0xcccd, 0xccce, 0xcccf, 0xccd1, 0xccd2, 0xccd3,
+    0xccd5, 0xccd6, 0xccd7, 0xccd8, 0xccd9, 0xccda, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xccdb, 0xccdc, 0xccdd, 0xccde,
+    0xccdf, 0xcce0, 0xcce1, 0xcce2, 0xcce3, 0xcce5, 0xcce6, 0xcce7, 0xcce8, 0xcce9, 0xccea, 0xcceb, 0xcced, 0xccee, 0xccef, 0xccf1,
+    0xccf2, 0xccf3, 0xccf4, 0xccf5, 0xccf6, 0xccf7, 0xccf8, 0xccf9, 0xccfa, 0xccfb, 0xccfc, 0xccfd, 0x30a1, 0x30a2, 0x30a3, 0x30a4,
+    0x30a5, 0x30a6, 0x30a7, 0x30a8, 0x30a9, 0x30aa, 0x30ab, 0x30ac, 0x30ad, 0x30ae, 0x30af, 0x30b0, 0x30b1, 0x30b2, 0x30b3, 0x30b4,
+    0x30b5, 0x30b6, 0x30b7, 0x30b8, 0x30b9, 0x30ba, 0x30bb, 0x30bc, 0x30bd, 0x30be, 0x30bf, 0x30c0, 0x30c1, 0x30c2, 0x30c3, 0x30c4,
+    0x30c5, 0x30c6, 0x30c7, 0x30c8, 0x30c9, 0x30ca, 0x30cb, 0x30cc, 0x30cd, 0x30ce, 0x30cf, 0x30d0, 0x30d1, 0x30d2, 0x30d3, 0x30d4,
+    0x30d5, 0x30d6, 0x30d7, 0x30d8, 0x30d9, 0x30da, 0x30db, 0x30dc, 0x30dd, 0x30de, 0x30df, 0x30e0, 0x30e1, 0x30e2, 0x30e3, 0x30e4,
+    0x30e5, 0x30e6, 0x30e7, 0x30e8, 0x30e9, 0x30ea, 0x30eb, 0x30ec, 0x30ed, 0x30ee, 0x30ef, 0x30f0, 0x30f1, 0x30f2, 0x30f3, 0x30f4,
+    0x30f5, 0x30f6, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xccfe, 0xccff, 0xcd00, 0xcd02, 0xcd03, 0xcd04,
+    0xcd05, 0xcd06, 0xcd07, 0xcd0a, 0xcd0b, 0xcd0d, 0xcd0e, 0xcd0f, 0xcd11, 0xcd12, 0xcd13, 0xcd14, 0xcd15, 0xcd16, 0xcd17, 0xcd1a,
+    0xcd1c, 0xcd1e, 0xcd1f, 0xcd20, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcd21, 0xcd22, 0xcd23, 0xcd25, 0xcd26, 0xcd27,
+    0xcd29, 0xcd2a, 0xcd2b, 0xcd2d, 0xcd2e, 0xcd2f, 0xcd30, 0xcd31, 0xcd32, 0xcd33, 0xcd34, 0xcd35, 0xcd36, 0xcd37, 0xcd38, 0xcd3a,
+    0xcd3b, 0xcd3c, 0xcd3d, 0xcd3e, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcd3f, 0xcd40, 0xcd41, 0xcd42, 0xcd43, 0xcd44,
+    0xcd45, 0xcd46, 0xcd47, 0xcd48, 0xcd49, 0xcd4a, 0xcd4b, 0xcd4c, 0xcd4d, 0xcd4e, 0xcd4f, 0xcd50, 0xcd51, 0xcd52, 0xcd53, 0xcd54,
+    0xcd55, 0xcd56, 0xcd57, 0xcd58, 0xcd59, 0xcd5a, 0xcd5b, 0xcd5d, 0xcd5e, 0xcd5f, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415,
+    0x0401, 0x0416, 0x0417, 0x0418, 0x0419, 0x041a, 0x041b, 0x041c, 0x041d, 0x041e, 0x041f, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424,
+    0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042a, 0x042b, 0x042c, 0x042d, 0x042e, 0x042f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435,
+    0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043a, 0x043b, 0x043c, 0x043d, 0x043e, 0x043f, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444,
+    0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044a, 0x044b, 0x044c, 0x044d, 0x044e, 0x044f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcd61, 0xcd62, 0xcd63, 0xcd65, 0xcd66, 0xcd67, 0xcd68, 0xcd69,
+    0xcd6a, 0xcd6b, 0xcd6e, 0xcd70, 0xcd72, 0xcd73, 0xcd74, 0xcd75, 0xcd76, 0xcd77, 0xcd79, 0xcd7a, 0xcd7b, 0xcd7c, 0xcd7d, 0xcd7e,
+    0xcd7f, 0xcd80, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcd81, 0xcd82, 0xcd83, 0xcd84, 0xcd85, 0xcd86, 0xcd87, 0xcd89,
+    0xcd8a, 0xcd8b, 0xcd8c, 0xcd8d, 0xcd8e, 0xcd8f, 0xcd90, 0xcd91, 0xcd92, 0xcd93, 0xcd96, 0xcd97, 0xcd99, 0xcd9a, 0xcd9b, 0xcd9d,
+    0xcd9e, 0xcd9f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcda0, 0xcda1, 0xcda2, 0xcda3, 0xcda6, 0xcda8, 0xcdaa, 0xcdab,
+    0xcdac, 0xcdad, 0xcdae, 0xcdaf, 0xcdb1, 0xcdb2, 0xcdb3, 0xcdb4, 0xcdb5, 0xcdb6, 0xcdb7, 0xcdb8, 0xcdb9, 0xcdba, 0xcdbb, 0xcdbc,
+    0xcdbd, 0xcdbe, 0xcdbf, 0xcdc0, 0xcdc1, 0xcdc2, 0xcdc3, 0xcdc5, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcdc6, 0xcdc7, 0xcdc8, 0xcdc9, 0xcdca, 0xcdcb, 0xcdcd, 0xcdce, 0xcdcf, 0xcdd1,
+    0xcdd2, 0xcdd3, 0xcdd4, 0xcdd5, 0xcdd6, 0xcdd7, 0xcdd8, 0xcdd9, 0xcdda, 0xcddb, 0xcddc, 0xcddd, 0xcdde, 0xcddf, 0xcde0, 0xcde1,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcde2, 0xcde3, 0xcde4, 0xcde5, 0xcde6, 0xcde7, 0xcde9, 0xcdea, 0xcdeb, 0xcded,
+    0xcdee, 0xcdef, 0xcdf1, 0xcdf2, 0xcdf3, 0xcdf4, 0xcdf5, 0xcdf6, 0xcdf7, 0xcdfa, 0xcdfc, 0xcdfe, 0xcdff, 0xce00, 0xce01, 0xce02,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xce03, 0xce05, 0xce06, 0xce07, 0xce09, 0xce0a, 0xce0b, 0xce0d, 0xce0e, 0xce0f,
+    0xce10, 0xce11, 0xce12, 0xce13, 0xce15, 0xce16, 0xce17, 0xce18, 0xce1a, 0xce1b, 0xce1c, 0xce1d, 0xce1e, 0xce1f, 0xce22, 0xce23,
+    0xce25, 0xce26, 0xce27, 0xce29, 0xce2a, 0xce2b, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xce2c, 0xce2d, 0xce2e, 0xce2f, 0xce32, 0xce34, 0xce36, 0xce37, 0xce38, 0xce39, 0xce3a, 0xce3b,
+    0xce3c, 0xce3d, 0xce3e, 0xce3f, 0xce40, 0xce41, 0xce42, 0xce43, 0xce44, 0xce45, 0xce46, 0xce47, 0xce48, 0xce49, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xce4a, 0xce4b, 0xce4c, 0xce4d, 0xce4e, 0xce4f, 0xce50, 0xce51, 0xce52, 0xce53, 0xce54, 0xce55,
+    0xce56, 0xce57, 0xce5a, 0xce5b, 0xce5d, 0xce5e, 0xce62, 0xce63, 0xce64, 0xce65, 0xce66, 0xce67, 0xce6a, 0xce6c, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xce6e, 0xce6f, 0xce70, 0xce71, 0xce72, 0xce73, 0xce76, 0xce77, 0xce79, 0xce7a, 0xce7b, 0xce7d,
+    0xce7e, 0xce7f, 0xce80, 0xce81, 0xce82, 0xce83, 0xce86, 0xce88, 0xce8a, 0xce8b, 0xce8c, 0xce8d, 0xce8e, 0xce8f, 0xce92, 0xce93,
+    0xce95, 0xce96, 0xce97, 0xce99, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xce9a, 0xce9b, 0xce9c, 0xce9d, 0xce9e, 0xce9f, 0xcea2, 0xcea6, 0xcea7, 0xcea8, 0xcea9, 0xceaa, 0xceab, 0xceae,
+    0xceaf, 0xceb0, 0xceb1, 0xceb2, 0xceb3, 0xceb4, 0xceb5, 0xceb6, 0xceb7, 0xceb8, 0xceb9, 0xceba, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xcebb, 0xcebc, 0xcebd, 0xcebe, 0xcebf, 0xcec0, 0xcec2, 0xcec3, 0xcec4, 0xcec5, 0xcec6, 0xcec7, 0xcec8, 0xcec9,
+    0xceca, 0xcecb, 0xcecc, 0xcecd, 0xcece, 0xcecf, 0xced0, 0xced1, 0xced2, 0xced3, 0xced4, 0xced5, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xced6, 0xced7, 0xced8, 0xced9, 0xceda, 0xcedb, 0xcedc, 0xcedd, 0xcede, 0xcedf, 0xcee0, 0xcee1, 0xcee2, 0xcee3,
+    0xcee6, 0xcee7, 0xcee9, 0xceea, 0xceed, 0xceee, 0xceef, 0xcef0, 0xcef1, 0xcef2, 0xcef3, 0xcef6, 0xcefa, 0xcefb, 0xcefc, 0xcefd,
+    0xcefe, 0xceff, 0xac00, 0xac01, 0xac04, 0xac07, 0xac08, 0xac09, 0xac0a, 0xac10, 0xac11, 0xac12, 0xac13, 0xac14, 0xac15, 0xac16,
+    0xac17, 0xac19, 0xac1a, 0xac1b, 0xac1c, 0xac1d, 0xac20, 0xac24, 0xac2c, 0xac2d, 0xac2f, 0xac30, 0xac31, 0xac38, 0xac39, 0xac3c,
+    0xac40, 0xac4b, 0xac4d, 0xac54, 0xac58, 0xac5c, 0xac70, 0xac71, 0xac74, 0xac77, 0xac78, 0xac7a, 0xac80, 0xac81, 0xac83, 0xac84,
+    0xac85, 0xac86, 0xac89, 0xac8a, 0xac8b, 0xac8c, 0xac90, 0xac94, 0xac9c, 0xac9d, 0xac9f, 0xaca0, 0xaca1, 0xaca8, 0xaca9, 0xacaa,
+    0xacac, 0xacaf, 0xacb0, 0xacb8, 0xacb9, 0xacbb, 0xacbc, 0xacbd, 0xacc1, 0xacc4, 0xacc8, 0xaccc, 0xacd5, 0xacd7, 0xace0, 0xace1,
+    0xace4, 0xace7, 0xace8, 0xacea, 0xacec, 0xacef, 0xacf0, 0xacf1, 0xacf3, 0xacf5, 0xacf6, 0xacfc, 0xacfd, 0xad00, 0xad04, 0xad06,
+    0xcf02, 0xcf03, 0xcf05, 0xcf06, 0xcf07, 0xcf09, 0xcf0a, 0xcf0b, 0xcf0c, 0xcf0d, 0xcf0e, 0xcf0f, 0xcf12, 0xcf14, 0xcf16, 0xcf17,
+    0xcf18, 0xcf19, 0xcf1a, 0xcf1b, 0xcf1d, 0xcf1e, 0xcf1f, 0xcf21, 0xcf22, 0xcf23, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xcf25, 0xcf26, 0xcf27, 0xcf28, 0xcf29, 0xcf2a, 0xcf2b, 0xcf2e, 0xcf32, 0xcf33, 0xcf34, 0xcf35, 0xcf36, 0xcf37, 0xcf39, 0xcf3a,
+    0xcf3b, 0xcf3c, 0xcf3d, 0xcf3e, 0xcf3f, 0xcf40, 0xcf41, 0xcf42, 0xcf43, 0xcf44, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xcf45, 0xcf46, 0xcf47, 0xcf48, 0xcf49, 0xcf4a, 0xcf4b, 0xcf4c, 0xcf4d, 0xcf4e, 0xcf4f, 0xcf50, 0xcf51, 0xcf52, 0xcf53, 0xcf56,
+    0xcf57, 0xcf59, 0xcf5a, 0xcf5b, 0xcf5d, 0xcf5e, 0xcf5f, 0xcf60, 0xcf61, 0xcf62, 0xcf63, 0xcf66, 0xcf68, 0xcf6a, 0xcf6b, 0xcf6c,
+    0xad0c, 0xad0d, 0xad0f, 0xad11, 0xad18, 0xad1c, 0xad20, 0xad29, 0xad2c, 0xad2d, 0xad34, 0xad35, 0xad38, 0xad3c, 0xad44, 0xad45,
+    0xad47, 0xad49, 0xad50, 0xad54, 0xad58, 0xad61, 0xad63, 0xad6c, 0xad6d, 0xad70, 0xad73, 0xad74, 0xad75, 0xad76, 0xad7b, 0xad7c,
+    0xad7d, 0xad7f, 0xad81, 0xad82, 0xad88, 0xad89, 0xad8c, 0xad90, 0xad9c, 0xad9d, 0xada4, 0xadb7, 0xadc0, 0xadc1, 0xadc4, 0xadc8,
+    0xadd0, 0xadd1, 0xadd3, 0xaddc, 0xade0, 0xade4, 0xadf8, 0xadf9, 0xadfc, 0xadff, 0xae00, 0xae01, 0xae08, 0xae09, 0xae0b, 0xae0d,
+    0xae14, 0xae30, 0xae31, 0xae34, 0xae37, 0xae38, 0xae3a, 0xae40, 0xae41, 0xae43, 0xae45, 0xae46, 0xae4a, 0xae4c, 0xae4d, 0xae4e,
+    0xae50, 0xae54, 0xae56, 0xae5c, 0xae5d, 0xae5f, 0xae60, 0xae61, 0xae65, 0xae68, 0xae69, 0xae6c, 0xae70, 0xae78, 0xcf6d, 0xcf6e,
+    0xcf6f, 0xcf72, 0xcf73, 0xcf75, 0xcf76, 0xcf77, 0xcf79, 0xcf7a, 0xcf7b, 0xcf7c, 0xcf7d, 0xcf7e, 0xcf7f, 0xcf81, 0xcf82, 0xcf83,
+    0xcf84, 0xcf86, 0xcf87, 0xcf88, 0xcf89, 0xcf8a, 0xcf8b, 0xcf8d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcf8e, 0xcf8f,
+    0xcf90, 0xcf91, 0xcf92, 0xcf93, 0xcf94, 0xcf95, 0xcf96, 0xcf97, 0xcf98, 0xcf99, 0xcf9a, 0xcf9b, 0xcf9c, 0xcf9d, 0xcf9e, 0xcf9f,
+    0xcfa0, 0xcfa2, 0xcfa3, 0xcfa4, 0xcfa5, 0xcfa6, 0xcfa7, 0xcfa9, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcfaa, 0xcfab,
+    0xcfac, 0xcfad, 0xcfae, 0xcfaf, 0xcfb1, 0xcfb2, 0xcfb3, 0xcfb4, 0xcfb5, 0xcfb6, 0xcfb7, 0xcfb8, 0xcfb9, 0xcfba, 0xcfbb, 0xcfbc,
+    0xcfbd, 0xcfbe, 0xcfbf, 0xcfc0, 0xcfc1, 0xcfc2, 0xcfc3, 0xcfc5, 0xcfc6, 0xcfc7, 0xcfc8, 0xcfc9, 0xcfca, 0xcfcb, 0xae79, 0xae7b,
+    0xae7c, 0xae7d, 0xae84, 0xae85, 0xae8c, 0xaebc, 0xaebd, 0xaebe, 0xaec0, 0xaec4, 0xaecc, 0xaecd, 0xaecf, 0xaed0, 0xaed1, 0xaed8,
+    0xaed9, 0xaedc, 0xaee8, 0xaeeb, 0xaeed, 0xaef4, 0xaef8, 0xaefc, 0xaf07, 0xaf08, 0xaf0d, 0xaf10, 0xaf2c, 0xaf2d, 0xaf30, 0xaf32,
+    0xaf34, 0xaf3c, 0xaf3d, 0xaf3f, 0xaf41, 0xaf42, 0xaf43, 0xaf48, 0xaf49, 0xaf50, 0xaf5c, 0xaf5d, 0xaf64, 0xaf65, 0xaf79, 0xaf80,
+    0xaf84, 0xaf88, 0xaf90, 0xaf91, 0xaf95, 0xaf9c, 0xafb8, 0xafb9, 0xafbc, 0xafc0, 0xafc7, 0xafc8, 0xafc9, 0xafcb, 0xafcd, 0xafce,
+    0xafd4, 0xafdc, 0xafe8, 0xafe9, 0xaff0, 0xaff1, 0xaff4, 0xaff8, 0xb000, 0xb001, 0xb004, 0xb00c, 0xb010, 0xb014, 0xb01c, 0xb01d,
+    0xb028, 0xb044, 0xb045, 0xb048, 0xb04a, 0xb04c, 0xb04e, 0xb053, 0xb054, 0xb055, 0xb057, 0xb059, 0xcfcc, 0xcfcd, 0xcfce, 0xcfcf,
+    0xcfd0, 0xcfd1, 0xcfd2, 0xcfd3, 0xcfd4, 0xcfd5, 0xcfd6, 0xcfd7, 0xcfd8, 0xcfd9, 0xcfda, 0xcfdb, 0xcfdc, 0xcfdd, 0xcfde, 0xcfdf,
+    0xcfe2, 0xcfe3, 0xcfe5, 0xcfe6, 0xcfe7, 0xcfe9, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xcfea, 0xcfeb, 0xcfec, 0xcfed,
+    0xcfee, 0xcfef, 0xcff2, 0xcff4, 0xcff6, 0xcff7, 0xcff8, 0xcff9, 0xcffa, 0xcffb, 0xcffd, 0xcffe, 0xcfff, 0xd001, 0xd002, 0xd003,
+    0xd005, 0xd006, 0xd007, 0xd008, 0xd009, 0xd00a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd00b, 0xd00c, 0xd00d, 0xd00e,
+    0xd00f, 0xd010, 0xd012, 0xd013, 0xd014, 0xd015, 0xd016, 0xd017, 0xd019, 0xd01a, 0xd01b, 0xd01c, 0xd01d, 0xd01e, 0xd01f, 0xd020,
+    0xd021, 0xd022, 0xd023, 0xd024, 0xd025, 0xd026, 0xd027, 0xd028, 0xd029, 0xd02a, 0xd02b, 0xd02c, 0xb05d, 0xb07c, 0xb07d, 0xb080,
+    0xb084, 0xb08c, 0xb08d, 0xb08f, 0xb091, 0xb098, 0xb099, 0xb09a, 0xb09c, 0xb09f, 0xb0a0, 0xb0a1, 0xb0a2, 0xb0a8, 0xb0a9, 0xb0ab,
+    0xb0ac, 0xb0ad, 0xb0ae, 0xb0af, 0xb0b1, 0xb0b3, 0xb0b4, 0xb0b5, 0xb0b8, 0xb0bc, 0xb0c4, 0xb0c5, 0xb0c7, 0xb0c8, 0xb0c9, 0xb0d0,
+    0xb0d1, 0xb0d4, 0xb0d8, 0xb0e0, 0xb0e5, 0xb108, 0xb109, 0xb10b, 0xb10c, 0xb110, 0xb112, 0xb113, 0xb118, 0xb119, 0xb11b, 0xb11c,
+    0xb11d, 0xb123, 0xb124, 0xb125, 0xb128, 0xb12c, 0xb134, 0xb135, 0xb137, 0xb138, 0xb139, 0xb140, 0xb141, 0xb144, 0xb148, 0xb150,
+    0xb151, 0xb154, 0xb155, 0xb158, 0xb15c, 0xb160, 0xb178, 0xb179, 0xb17c, 0xb180, 0xb182, 0xb188, 0xb189, 0xb18b, 0xb18d, 0xb192,
+    0xb193, 0xb194, 0xb198, 0xb19c, 0xb1a8, 0xb1cc, 0xb1d0, 0xb1d4, 0xb1dc, 0xb1dd, 0xd02e, 0xd02f, 0xd030, 0xd031, 0xd032, 0xd033,
+    0xd036, 0xd037, 0xd039, 0xd03a, 0xd03b, 0xd03d, 0xd03e, 0xd03f, 0xd040, 0xd041, 0xd042, 0xd043, 0xd046, 0xd048, 0xd04a, 0xd04b,
+    0xd04c, 0xd04d, 0xd04e, 0xd04f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd051, 0xd052, 0xd053, 0xd055, 0xd056, 0xd057,
+    0xd059, 0xd05a, 0xd05b, 0xd05c, 0xd05d, 0xd05e, 0xd05f, 0xd061, 0xd062, 0xd063, 0xd064, 0xd065, 0xd066, 0xd067, 0xd068, 0xd069,
+    0xd06a, 0xd06b, 0xd06e, 0xd06f, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd071, 0xd072, 0xd073, 0xd075, 0xd076, 0xd077,
+    0xd078, 0xd079, 0xd07a, 0xd07b, 0xd07e, 0xd07f, 0xd080, 0xd082, 0xd083, 0xd084, 0xd085, 0xd086, 0xd087, 0xd088, 0xd089, 0xd08a,
+    0xd08b, 0xd08c, 0xd08d, 0xd08e, 0xd08f, 0xd090, 0xd091, 0xd092, 0xd093, 0xd094, 0xb1df, 0xb1e8, 0xb1e9, 0xb1ec, 0xb1f0, 0xb1f9,
+    0xb1fb, 0xb1fd, 0xb204, 0xb205, 0xb208, 0xb20b, 0xb20c, 0xb214, 0xb215, 0xb217, 0xb219, 0xb220, 0xb234, 0xb23c, 0xb258, 0xb25c,
+    0xb260, 0xb268, 0xb269, 0xb274, 0xb275, 0xb27c, 0xb284, 0xb285, 0xb289, 0xb290, 0xb291, 0xb294, 0xb298, 0xb299, 0xb29a, 0xb2a0,
+    0xb2a1, 0xb2a3, 0xb2a5, 0xb2a6, 0xb2aa, 0xb2ac, 0xb2b0, 0xb2b4, 0xb2c8, 0xb2c9, 0xb2cc, 0xb2d0, 0xb2d2, 0xb2d8, 0xb2d9, 0xb2db,
+    0xb2dd, 0xb2e2, 0xb2e4, 0xb2e5, 0xb2e6, 0xb2e8, 0xb2eb, 0xb2ec, 0xb2ed, 0xb2ee, 0xb2ef, 0xb2f3, 0xb2f4, 0xb2f5, 0xb2f7, 0xb2f8,
+    0xb2f9, 0xb2fa, 0xb2fb, 0xb2ff, 0xb300, 0xb301, 0xb304, 0xb308, 0xb310, 0xb311, 0xb313, 0xb314, 0xb315, 0xb31c, 0xb354, 0xb355,
+    0xb356, 0xb358, 0xb35b, 0xb35c, 0xb35e, 0xb35f, 0xb364, 0xb365, 0xd095, 0xd096, 0xd097, 0xd098, 0xd099, 0xd09a, 0xd09b, 0xd09c,
+    0xd09d, 0xd09e, 0xd09f, 0xd0a0, 0xd0a1, 0xd0a2, 0xd0a3, 0xd0a6, 0xd0a7, 0xd0a9, 0xd0aa, 0xd0ab, 0xd0ad, 0xd0ae, 0xd0af, 0xd0b0,
+    0xd0b1, 0xd0b2, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd0b3, 0xd0b6, 0xd0b8, 0xd0ba, 0xd0bb, 0xd0bc, 0xd0bd, 0xd0be,
+    0xd0bf, 0xd0c2, 0xd0c3, 0xd0c5, 0xd0c6, 0xd0c7, 0xd0ca, 0xd0cb, 0xd0cc, 0xd0cd, 0xd0ce, 0xd0cf, 0xd0d2, 0xd0d6, 0xd0d7, 0xd0d8,
+    0xd0d9, 0xd0da, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd0db, 0xd0de, 0xd0df, 0xd0e1, 0xd0e2, 0xd0e3, 0xd0e5, 0xd0e6,
+    0xd0e7, 0xd0e8, 0xd0e9, 0xd0ea, 0xd0eb, 0xd0ee, 0xd0f2, 0xd0f3, 0xd0f4, 0xd0f5, 0xd0f6, 0xd0f7, 0xd0f9, 0xd0fa, 0xd0fb, 0xd0fc,
+    0xd0fd, 0xd0fe, 0xd0ff, 0xd100, 0xd101, 0xd102, 0xd103, 0xd104, 0xb367, 0xb369, 0xb36b, 0xb36e, 0xb370, 0xb371, 0xb374, 0xb378,
+    0xb380, 0xb381, 0xb383, 0xb384, 0xb385, 0xb38c, 0xb390, 0xb394, 0xb3a0, 0xb3a1, 0xb3a8, 0xb3ac, 0xb3c4, 0xb3c5, 0xb3c8, 0xb3cb,
+    0xb3cc, 0xb3ce, 0xb3d0, 0xb3d4, 0xb3d5, 0xb3d7, 0xb3d9, 0xb3db, 0xb3dd, 0xb3e0, 0xb3e4, 0xb3e8, 0xb3fc, 0xb410, 0xb418, 0xb41c,
+    0xb420, 0xb428, 0xb429, 0xb42b, 0xb434, 0xb450, 0xb451, 0xb454, 0xb458, 0xb460, 0xb461, 0xb463, 0xb465, 0xb46c, 0xb480, 0xb488,
+    0xb49d, 0xb4a4, 0xb4a8, 0xb4ac, 0xb4b5, 0xb4b7, 0xb4b9, 0xb4c0, 0xb4c4, 0xb4c8, 0xb4d0, 0xb4d5, 0xb4dc, 0xb4dd, 0xb4e0, 0xb4e3,
+    0xb4e4, 0xb4e6, 0xb4ec, 0xb4ed, 0xb4ef, 0xb4f1, 0xb4f8, 0xb514, 0xb515, 0xb518, 0xb51b, 0xb51c, 0xb524, 0xb525, 0xb527, 0xb528,
+    0xb529, 0xb52a, 0xb530, 0xb531, 0xb534, 0xb538, 0xd105, 0xd106, 0xd107, 0xd108, 0xd109, 0xd10a, 0xd10b, 0xd10c, 0xd10e, 0xd10f,
+    0xd110, 0xd111, 0xd112, 0xd113, 0xd114, 0xd115, 0xd116, 0xd117, 0xd118, 0xd119, 0xd11a, 0xd11b, 0xd11c, 0xd11d, 0xd11e, 0xd11f,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd120, 0xd121, 0xd122, 0xd123, 0xd124, 0xd125, 0xd126, 0xd127, 0xd128, 0xd129,
+    0xd12a, 0xd12b, 0xd12c, 0xd12d, 0xd12e, 0xd12f, 0xd132, 0xd133, 0xd135, 0xd136, 0xd137, 0xd139, 0xd13b, 0xd13c, 0xd13d, 0xd13e,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd13f, 0xd142, 0xd146, 0xd147, 0xd148, 0xd149, 0xd14a, 0xd14b, 0xd14e, 0xd14f,
+    0xd151, 0xd152, 0xd153, 0xd155, 0xd156, 0xd157, 0xd158, 0xd159, 0xd15a, 0xd15b, 0xd15e, 0xd160, 0xd162, 0xd163, 0xd164, 0xd165,
+    0xd166, 0xd167, 0xd169, 0xd16a, 0xd16b, 0xd16d, 0xb540, 0xb541, 0xb543, 0xb544, 0xb545, 0xb54b, 0xb54c, 0xb54d, 0xb550, 0xb554,
+    0xb55c, 0xb55d, 0xb55f, 0xb560, 0xb561, 0xb5a0, 0xb5a1, 0xb5a4, 0xb5a8, 0xb5aa, 0xb5ab, 0xb5b0, 0xb5b1, 0xb5b3, 0xb5b4, 0xb5b5,
+    0xb5bb, 0xb5bc, 0xb5bd, 0xb5c0, 0xb5c4, 0xb5cc, 0xb5cd, 0xb5cf, 0xb5d0, 0xb5d1, 0xb5d8, 0xb5ec, 0xb610, 0xb611, 0xb614, 0xb618,
+    0xb625, 0xb62c, 0xb634, 0xb648, 0xb664, 0xb668, 0xb69c, 0xb69d, 0xb6a0, 0xb6a4, 0xb6ab, 0xb6ac, 0xb6b1, 0xb6d4, 0xb6f0, 0xb6f4,
+    0xb6f8, 0xb700, 0xb701, 0xb705, 0xb728, 0xb729, 0xb72c, 0xb72f, 0xb730, 0xb738, 0xb739, 0xb73b, 0xb744, 0xb748, 0xb74c, 0xb754,
+    0xb755, 0xb760, 0xb764, 0xb768, 0xb770, 0xb771, 0xb773, 0xb775, 0xb77c, 0xb77d, 0xb780, 0xb784, 0xb78c, 0xb78d, 0xb78f, 0xb790,
+    0xb791, 0xb792, 0xb796, 0xb797, 0xd16e, 0xd16f, 0xd170, 0xd171, 0xd172, 0xd173, 0xd174, 0xd175, 0xd176, 0xd177, 0xd178, 0xd179,
+    0xd17a, 0xd17b, 0xd17d, 0xd17e, 0xd17f, 0xd180, 0xd181, 0xd182, 0xd183, 0xd185, 0xd186, 0xd187, 0xd189, 0xd18a, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xd18b, 0xd18c, 0xd18d, 0xd18e, 0xd18f, 0xd190, 0xd191, 0xd192, 0xd193, 0xd194, 0xd195, 0xd196,
+    0xd197, 0xd198, 0xd199, 0xd19a, 0xd19b, 0xd19c, 0xd19d, 0xd19e, 0xd19f, 0xd1a2, 0xd1a3, 0xd1a5, 0xd1a6, 0xd1a7, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xd1a9, 0xd1aa, 0xd1ab, 0xd1ac, 0xd1ad, 0xd1ae, 0xd1af, 0xd1b2, 0xd1b4, 0xd1b6, 0xd1b7, 0xd1b8,
+    0xd1b9, 0xd1bb, 0xd1bd, 0xd1be, 0xd1bf, 0xd1c1, 0xd1c2, 0xd1c3, 0xd1c4, 0xd1c5, 0xd1c6, 0xd1c7, 0xd1c8, 0xd1c9, 0xd1ca, 0xd1cb,
+    0xd1cc, 0xd1cd, 0xd1ce, 0xd1cf, 0xb798, 0xb799, 0xb79c, 0xb7a0, 0xb7a8, 0xb7a9, 0xb7ab, 0xb7ac, 0xb7ad, 0xb7b4, 0xb7b5, 0xb7b8,
+    0xb7c7, 0xb7c9, 0xb7ec, 0xb7ed, 0xb7f0, 0xb7f4, 0xb7fc, 0xb7fd, 0xb7ff, 0xb800, 0xb801, 0xb807, 0xb808, 0xb809, 0xb80c, 0xb810,
+    0xb818, 0xb819, 0xb81b, 0xb81d, 0xb824, 0xb825, 0xb828, 0xb82c, 0xb834, 0xb835, 0xb837, 0xb838, 0xb839, 0xb840, 0xb844, 0xb851,
+    0xb853, 0xb85c, 0xb85d, 0xb860, 0xb864, 0xb86c, 0xb86d, 0xb86f, 0xb871, 0xb878, 0xb87c, 0xb88d, 0xb8a8, 0xb8b0, 0xb8b4, 0xb8b8,
+    0xb8c0, 0xb8c1, 0xb8c3, 0xb8c5, 0xb8cc, 0xb8d0, 0xb8d4, 0xb8dd, 0xb8df, 0xb8e1, 0xb8e8, 0xb8e9, 0xb8ec, 0xb8f0, 0xb8f8, 0xb8f9,
+    0xb8fb, 0xb8fd, 0xb904, 0xb918, 0xb920, 0xb93c, 0xb93d, 0xb940, 0xb944, 0xb94c, 0xb94f, 0xb951, 0xb958, 0xb959, 0xb95c, 0xb960,
+    0xb968, 0xb969, 0xd1d0, 0xd1d1, 0xd1d2, 0xd1d3, 0xd1d4, 0xd1d5, 0xd1d6, 0xd1d7, 0xd1d9, 0xd1da, 0xd1db, 0xd1dc, 0xd1dd, 0xd1de,
+    0xd1df, 0xd1e0, 0xd1e1, 0xd1e2, 0xd1e3, 0xd1e4, 0xd1e5, 0xd1e6, 0xd1e7, 0xd1e8, 0xd1e9, 0xd1ea, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xd1eb, 0xd1ec, 0xd1ed, 0xd1ee, 0xd1ef, 0xd1f0, 0xd1f1, 0xd1f2, 0xd1f3, 0xd1f5, 0xd1f6, 0xd1f7, 0xd1f9, 0xd1fa,
+    0xd1fb, 0xd1fc, 0xd1fd, 0xd1fe, 0xd1ff, 0xd200, 0xd201, 0xd202, 0xd203, 0xd204, 0xd205, 0xd206, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xd208, 0xd20a, 0xd20b, 0xd20c, 0xd20d, 0xd20e, 0xd20f, 0xd211, 0xd212, 0xd213, 0xd214, 0xd215, 0xd216, 0xd217,
+    0xd218, 0xd219, 0xd21a, 0xd21b, 0xd21c, 0xd21d, 0xd21e, 0xd21f, 0xd220, 0xd221, 0xd222, 0xd223, 0xd224, 0xd225, 0xd226, 0xd227,
+    0xd228, 0xd229, 0xb96b, 0xb96d, 0xb974, 0xb975, 0xb978, 0xb97c, 0xb984, 0xb985, 0xb987, 0xb989, 0xb98a, 0xb98d, 0xb98e, 0xb9ac,
+    0xb9ad, 0xb9b0, 0xb9b4, 0xb9bc, 0xb9bd, 0xb9bf, 0xb9c1, 0xb9c8, 0xb9c9, 0xb9cc, 0xb9ce, 0xb9cf, 0xb9d0, 0xb9d1, 0xb9d2, 0xb9d8,
+    0xb9d9, 0xb9db, 0xb9dd, 0xb9de, 0xb9e1, 0xb9e3, 0xb9e4, 0xb9e5, 0xb9e8, 0xb9ec, 0xb9f4, 0xb9f5, 0xb9f7, 0xb9f8, 0xb9f9, 0xb9fa,
+    0xba00, 0xba01, 0xba08, 0xba15, 0xba38, 0xba39, 0xba3c, 0xba40, 0xba42, 0xba48, 0xba49, 0xba4b, 0xba4d, 0xba4e, 0xba53, 0xba54,
+    0xba55, 0xba58, 0xba5c, 0xba64, 0xba65, 0xba67, 0xba68, 0xba69, 0xba70, 0xba71, 0xba74, 0xba78, 0xba83, 0xba84, 0xba85, 0xba87,
+    0xba8c, 0xbaa8, 0xbaa9, 0xbaab, 0xbaac, 0xbab0, 0xbab2, 0xbab8, 0xbab9, 0xbabb, 0xbabd, 0xbac4, 0xbac8, 0xbad8, 0xbad9, 0xbafc,
+    0xd22a, 0xd22b, 0xd22e, 0xd22f, 0xd231, 0xd232, 0xd233, 0xd235, 0xd236, 0xd237, 0xd238, 0xd239, 0xd23a, 0xd23b, 0xd23e, 0xd240,
+    0xd242, 0xd243, 0xd244, 0xd245, 0xd246, 0xd247, 0xd249, 0xd24a, 0xd24b, 0xd24c, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xd24d, 0xd24e, 0xd24f, 0xd250, 0xd251, 0xd252, 0xd253, 0xd254, 0xd255, 0xd256, 0xd257, 0xd258, 0xd259, 0xd25a, 0xd25b, 0xd25d,
+    0xd25e, 0xd25f, 0xd260, 0xd261, 0xd262, 0xd263, 0xd265, 0xd266, 0xd267, 0xd268, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xd269, 0xd26a, 0xd26b, 0xd26c, 0xd26d, 0xd26e, 0xd26f, 0xd270, 0xd271, 0xd272, 0xd273, 0xd274, 0xd275, 0xd276, 0xd277, 0xd278,
+    0xd279, 0xd27a, 0xd27b, 0xd27c, 0xd27d, 0xd27e, 0xd27f, 0xd282, 0xd283, 0xd285, 0xd286, 0xd287, 0xd289, 0xd28a, 0xd28b, 0xd28c,
+    0xbb00, 0xbb04, 0xbb0d, 0xbb0f, 0xbb11, 0xbb18, 0xbb1c, 0xbb20, 0xbb29, 0xbb2b, 0xbb34, 0xbb35, 0xbb36, 0xbb38, 0xbb3b, 0xbb3c,
+    0xbb3d, 0xbb3e, 0xbb44, 0xbb45, 0xbb47, 0xbb49, 0xbb4d, 0xbb4f, 0xbb50, 0xbb54, 0xbb58, 0xbb61, 0xbb63, 0xbb6c, 0xbb88, 0xbb8c,
+    0xbb90, 0xbba4, 0xbba8, 0xbbac, 0xbbb4, 0xbbb7, 0xbbc0, 0xbbc4, 0xbbc8, 0xbbd0, 0xbbd3, 0xbbf8, 0xbbf9, 0xbbfc, 0xbbff, 0xbc00,
+    0xbc02, 0xbc08, 0xbc09, 0xbc0b, 0xbc0c, 0xbc0d, 0xbc0f, 0xbc11, 0xbc14, 0xbc15, 0xbc16, 0xbc17, 0xbc18, 0xbc1b, 0xbc1c, 0xbc1d,
+    0xbc1e, 0xbc1f, 0xbc24, 0xbc25, 0xbc27, 0xbc29, 0xbc2d, 0xbc30, 0xbc31, 0xbc34, 0xbc38, 0xbc40, 0xbc41, 0xbc43, 0xbc44, 0xbc45,
+    0xbc49, 0xbc4c, 0xbc4d, 0xbc50, 0xbc5d, 0xbc84, 0xbc85, 0xbc88, 0xbc8b, 0xbc8c, 0xbc8e, 0xbc94, 0xbc95, 0xbc97, 0xd28d, 0xd28e,
+    0xd28f, 0xd292, 0xd293, 0xd294, 0xd296, 0xd297, 0xd298, 0xd299, 0xd29a, 0xd29b, 0xd29d, 0xd29e, 0xd29f, 0xd2a1, 0xd2a2, 0xd2a3,
+    0xd2a5, 0xd2a6, 0xd2a7, 0xd2a8, 0xd2a9, 0xd2aa, 0xd2ab, 0xd2ad, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd2ae, 0xd2af,
+    0xd2b0, 0xd2b2, 0xd2b3, 0xd2b4, 0xd2b5, 0xd2b6, 0xd2b7, 0xd2ba, 0xd2bb, 0xd2bd, 0xd2be, 0xd2c1, 0xd2c3, 0xd2c4, 0xd2c5, 0xd2c6,
+    0xd2c7, 0xd2ca, 0xd2cc, 0xd2cd, 0xd2ce, 0xd2cf, 0xd2d0, 0xd2d1, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd2d2, 0xd2d3,
+    0xd2d5, 0xd2d6, 0xd2d7, 0xd2d9, 0xd2da, 0xd2db, 0xd2dd, 0xd2de, 0xd2df, 0xd2e0, 0xd2e1, 0xd2e2, 0xd2e3, 0xd2e6, 0xd2e7, 0xd2e8,
+    0xd2e9, 0xd2ea, 0xd2eb, 0xd2ec, 0xd2ed, 0xd2ee, 0xd2ef, 0xd2f2, 0xd2f3, 0xd2f5, 0xd2f6, 0xd2f7, 0xd2f9, 0xd2fa, 0xbc99, 0xbc9a,
+    0xbca0, 0xbca1, 0xbca4, 0xbca7, 0xbca8, 0xbcb0, 0xbcb1, 0xbcb3, 0xbcb4, 0xbcb5, 0xbcbc, 0xbcbd, 0xbcc0, 0xbcc4, 0xbccd, 0xbccf,
+    0xbcd0, 0xbcd1, 0xbcd5, 0xbcd8, 0xbcdc, 0xbcf4, 0xbcf5, 0xbcf6, 0xbcf8, 0xbcfc, 0xbd04, 0xbd05, 0xbd07, 0xbd09, 0xbd10, 0xbd14,
+    0xbd24, 0xbd2c, 0xbd40, 0xbd48, 0xbd49, 0xbd4c, 0xbd50, 0xbd58, 0xbd59, 0xbd64, 0xbd68, 0xbd80, 0xbd81, 0xbd84, 0xbd87, 0xbd88,
+    0xbd89, 0xbd8a, 0xbd90, 0xbd91, 0xbd93, 0xbd95, 0xbd99, 0xbd9a, 0xbd9c, 0xbda4, 0xbdb0, 0xbdb8, 0xbdd4, 0xbdd5, 0xbdd8, 0xbddc,
+    0xbde9, 0xbdf0, 0xbdf4, 0xbdf8, 0xbe00, 0xbe03, 0xbe05, 0xbe0c, 0xbe0d, 0xbe10, 0xbe14, 0xbe1c, 0xbe1d, 0xbe1f, 0xbe44, 0xbe45,
+    0xbe48, 0xbe4c, 0xbe4e, 0xbe54, 0xbe55, 0xbe57, 0xbe59, 0xbe5a, 0xbe5b, 0xbe60, 0xbe61, 0xbe64, 0xd2fb, 0xd2fc, 0xd2fd, 0xd2fe,
+    0xd2ff, 0xd302, 0xd304, 0xd306, 0xd307, 0xd308, 0xd309, 0xd30a, 0xd30b, 0xd30f, 0xd311, 0xd312, 0xd313, 0xd315, 0xd317, 0xd318,
+    0xd319, 0xd31a, 0xd31b, 0xd31e, 0xd322, 0xd323, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd324, 0xd326, 0xd327, 0xd32a,
+    0xd32b, 0xd32d, 0xd32e, 0xd32f, 0xd331, 0xd332, 0xd333, 0xd334, 0xd335, 0xd336, 0xd337, 0xd33a, 0xd33e, 0xd33f, 0xd340, 0xd341,
+    0xd342, 0xd343, 0xd346, 0xd347, 0xd348, 0xd349, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd34a, 0xd34b, 0xd34c, 0xd34d,
+    0xd34e, 0xd34f, 0xd350, 0xd351, 0xd352, 0xd353, 0xd354, 0xd355, 0xd356, 0xd357, 0xd358, 0xd359, 0xd35a, 0xd35b, 0xd35c, 0xd35d,
+    0xd35e, 0xd35f, 0xd360, 0xd361, 0xd362, 0xd363, 0xd364, 0xd365, 0xd366, 0xd367, 0xd368, 0xd369, 0xbe68, 0xbe6a, 0xbe70, 0xbe71,
+    0xbe73, 0xbe74, 0xbe75, 0xbe7b, 0xbe7c, 0xbe7d, 0xbe80, 0xbe84, 0xbe8c, 0xbe8d, 0xbe8f, 0xbe90, 0xbe91, 0xbe98, 0xbe99, 0xbea8,
+    0xbed0, 0xbed1, 0xbed4, 0xbed7, 0xbed8, 0xbee0, 0xbee3, 0xbee4, 0xbee5, 0xbeec, 0xbf01, 0xbf08, 0xbf09, 0xbf18, 0xbf19, 0xbf1b,
+    0xbf1c, 0xbf1d, 0xbf40, 0xbf41, 0xbf44, 0xbf48, 0xbf50, 0xbf51, 0xbf55, 0xbf94, 0xbfb0, 0xbfc5, 0xbfcc, 0xbfcd, 0xbfd0, 0xbfd4,
+    0xbfdc, 0xbfdf, 0xbfe1, 0xc03c, 0xc051, 0xc058, 0xc05c, 0xc060, 0xc068, 0xc069, 0xc090, 0xc091, 0xc094, 0xc098, 0xc0a0, 0xc0a1,
+    0xc0a3, 0xc0a5, 0xc0ac, 0xc0ad, 0xc0af, 0xc0b0, 0xc0b3, 0xc0b4, 0xc0b5, 0xc0b6, 0xc0bc, 0xc0bd, 0xc0bf, 0xc0c0, 0xc0c1, 0xc0c5,
+    0xc0c8, 0xc0c9, 0xc0cc, 0xc0d0, 0xc0d8, 0xc0d9, 0xc0db, 0xc0dc, 0xc0dd, 0xc0e4, 0xd36a, 0xd36b, 0xd36c, 0xd36d, 0xd36e, 0xd36f,
+    0xd370, 0xd371, 0xd372, 0xd373, 0xd374, 0xd375, 0xd376, 0xd377, 0xd378, 0xd379, 0xd37a, 0xd37b, 0xd37e, 0xd37f, 0xd381, 0xd382,
+    0xd383, 0xd385, 0xd386, 0xd387, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd388, 0xd389, 0xd38a, 0xd38b, 0xd38e, 0xd392,
+    0xd393, 0xd394, 0xd395, 0xd396, 0xd397, 0xd39a, 0xd39b, 0xd39d, 0xd39e, 0xd39f, 0xd3a1, 0xd3a2, 0xd3a3, 0xd3a4, 0xd3a5, 0xd3a6,
+    0xd3a7, 0xd3aa, 0xd3ac, 0xd3ae, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd3af, 0xd3b0, 0xd3b1, 0xd3b2, 0xd3b3, 0xd3b5,
+    0xd3b6, 0xd3b7, 0xd3b9, 0xd3ba, 0xd3bb, 0xd3bd, 0xd3be, 0xd3bf, 0xd3c0, 0xd3c1, 0xd3c2, 0xd3c3, 0xd3c6, 0xd3c7, 0xd3ca, 0xd3cb,
+    0xd3cc, 0xd3cd, 0xd3ce, 0xd3cf, 0xd3d1, 0xd3d2, 0xd3d3, 0xd3d4, 0xd3d5, 0xd3d6, 0xc0e5, 0xc0e8, 0xc0ec, 0xc0f4, 0xc0f5, 0xc0f7,
+    0xc0f9, 0xc100, 0xc104, 0xc108, 0xc110, 0xc115, 0xc11c, 0xc11d, 0xc11e, 0xc11f, 0xc120, 0xc123, 0xc124, 0xc126, 0xc127, 0xc12c,
+    0xc12d, 0xc12f, 0xc130, 0xc131, 0xc136, 0xc138, 0xc139, 0xc13c, 0xc140, 0xc148, 0xc149, 0xc14b, 0xc14c, 0xc14d, 0xc154, 0xc155,
+    0xc158, 0xc15c, 0xc164, 0xc165, 0xc167, 0xc168, 0xc169, 0xc170, 0xc174, 0xc178, 0xc185, 0xc18c, 0xc18d, 0xc18e, 0xc190, 0xc194,
+    0xc196, 0xc19c, 0xc19d, 0xc19f, 0xc1a1, 0xc1a5, 0xc1a8, 0xc1a9, 0xc1ac, 0xc1b0, 0xc1bd, 0xc1c4, 0xc1c8, 0xc1cc, 0xc1d4, 0xc1d7,
+    0xc1d8, 0xc1e0, 0xc1e4, 0xc1e8, 0xc1f0, 0xc1f1, 0xc1f3, 0xc1fc, 0xc1fd, 0xc200, 0xc204, 0xc20c, 0xc20d, 0xc20f, 0xc211, 0xc218,
+    0xc219, 0xc21c, 0xc21f, 0xc220, 0xc228, 0xc229, 0xc22b, 0xc22d, 0xd3d7, 0xd3d9, 0xd3da, 0xd3db, 0xd3dc, 0xd3dd, 0xd3de, 0xd3df,
+    0xd3e0, 0xd3e2, 0xd3e4, 0xd3e5, 0xd3e6, 0xd3e7, 0xd3e8, 0xd3e9, 0xd3ea, 0xd3eb, 0xd3ee, 0xd3ef, 0xd3f1, 0xd3f2, 0xd3f3, 0xd3f5,
+    0xd3f6, 0xd3f7, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd3f8, 0xd3f9, 0xd3fa, 0xd3fb, 0xd3fe, 0xd400, 0xd402, 0xd403,
+    0xd404, 0xd405, 0xd406, 0xd407, 0xd409, 0xd40a, 0xd40b, 0xd40c, 0xd40d, 0xd40e, 0xd40f, 0xd410, 0xd411, 0xd412, 0xd413, 0xd414,
+    0xd415, 0xd416, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd417, 0xd418, 0xd419, 0xd41a, 0xd41b, 0xd41c, 0xd41e, 0xd41f,
+    0xd420, 0xd421, 0xd422, 0xd423, 0xd424, 0xd425, 0xd426, 0xd427, 0xd428, 0xd429, 0xd42a, 0xd42b, 0xd42c, 0xd42d, 0xd42e, 0xd42f,
+    0xd430, 0xd431, 0xd432, 0xd433, 0xd434, 0xd435, 0xd436, 0xd437, 0xc22f, 0xc231, 0xc232, 0xc234, 0xc248, 0xc250, 0xc251, 0xc254,
+    0xc258, 0xc260, 0xc265, 0xc26c, 0xc26d, 0xc270, 0xc274, 0xc27c, 0xc27d, 0xc27f, 0xc281, 0xc288, 0xc289, 0xc290, 0xc298, 0xc29b,
+    0xc29d, 0xc2a4, 0xc2a5, 0xc2a8, 0xc2ac, 0xc2ad, 0xc2b4, 0xc2b5, 0xc2b7, 0xc2b9, 0xc2dc, 0xc2dd, 0xc2e0, 0xc2e3, 0xc2e4, 0xc2eb,
+    0xc2ec, 0xc2ed, 0xc2ef, 0xc2f1, 0xc2f6, 0xc2f8, 0xc2f9, 0xc2fb, 0xc2fc, 0xc300, 0xc308, 0xc309, 0xc30c, 0xc30d, 0xc313, 0xc314,
+    0xc315, 0xc318, 0xc31c, 0xc324, 0xc325, 0xc328, 0xc329, 0xc345, 0xc368, 0xc369, 0xc36c, 0xc370, 0xc372, 0xc378, 0xc379, 0xc37c,
+    0xc37d, 0xc384, 0xc388, 0xc38c, 0xc3c0, 0xc3d8, 0xc3d9, 0xc3dc, 0xc3df, 0xc3e0, 0xc3e2, 0xc3e8, 0xc3e9, 0xc3ed, 0xc3f4, 0xc3f5,
+    0xc3f8, 0xc408, 0xc410, 0xc424, 0xc42c, 0xc430, 0xd438, 0xd439, 0xd43a, 0xd43b, 0xd43c, 0xd43d, 0xd43e, 0xd43f, 0xd441, 0xd442,
+    0xd443, 0xd445, 0xd446, 0xd447, 0xd448, 0xd449, 0xd44a, 0xd44b, 0xd44c, 0xd44d, 0xd44e, 0xd44f, 0xd450, 0xd451, 0xd452, 0xd453,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd454, 0xd455, 0xd456, 0xd457, 0xd458, 0xd459, 0xd45a, 0xd45b, 0xd45d, 0xd45e,
+    0xd45f, 0xd461, 0xd462, 0xd463, 0xd465, 0xd466, 0xd467, 0xd468, 0xd469, 0xd46a, 0xd46b, 0xd46c, 0xd46e, 0xd470, 0xd471, 0xd472,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd473, 0xd474, 0xd475, 0xd476, 0xd477, 0xd47a, 0xd47b, 0xd47d, 0xd47e, 0xd481,
+    0xd483, 0xd484, 0xd485, 0xd486, 0xd487, 0xd48a, 0xd48c, 0xd48e, 0xd48f, 0xd490, 0xd491, 0xd492, 0xd493, 0xd495, 0xd496, 0xd497,
+    0xd498, 0xd499, 0xd49a, 0xd49b, 0xd49c, 0xd49d, 0xc434, 0xc43c, 0xc43d, 0xc448, 0xc464, 0xc465, 0xc468, 0xc46c, 0xc474, 0xc475,
+    0xc479, 0xc480, 0xc494, 0xc49c, 0xc4b8, 0xc4bc, 0xc4e9, 0xc4f0, 0xc4f1, 0xc4f4, 0xc4f8, 0xc4fa, 0xc4ff, 0xc500, 0xc501, 0xc50c,
+    0xc510, 0xc514, 0xc51c, 0xc528, 0xc529, 0xc52c, 0xc530, 0xc538, 0xc539, 0xc53b, 0xc53d, 0xc544, 0xc545, 0xc548, 0xc549, 0xc54a,
+    0xc54c, 0xc54d, 0xc54e, 0xc553, 0xc554, 0xc555, 0xc557, 0xc558, 0xc559, 0xc55d, 0xc55e, 0xc560, 0xc561, 0xc564, 0xc568, 0xc570,
+    0xc571, 0xc573, 0xc574, 0xc575, 0xc57c, 0xc57d, 0xc580, 0xc584, 0xc587, 0xc58c, 0xc58d, 0xc58f, 0xc591, 0xc595, 0xc597, 0xc598,
+    0xc59c, 0xc5a0, 0xc5a9, 0xc5b4, 0xc5b5, 0xc5b8, 0xc5b9, 0xc5bb, 0xc5bc, 0xc5bd, 0xc5be, 0xc5c4, 0xc5c5, 0xc5c6, 0xc5c7, 0xc5c8,
+    0xc5c9, 0xc5ca, 0xc5cc, 0xc5ce, 0xd49e, 0xd49f, 0xd4a0, 0xd4a1, 0xd4a2, 0xd4a3, 0xd4a4, 0xd4a5, 0xd4a6, 0xd4a7, 0xd4a8, 0xd4aa,
+    0xd4ab, 0xd4ac, 0xd4ad, 0xd4ae, 0xd4af, 0xd4b0, 0xd4b1, 0xd4b2, 0xd4b3, 0xd4b4, 0xd4b5, 0xd4b6, 0xd4b7, 0xd4b8, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xd4b9, 0xd4ba, 0xd4bb, 0xd4bc, 0xd4bd, 0xd4be, 0xd4bf, 0xd4c0, 0xd4c1, 0xd4c2, 0xd4c3, 0xd4c4,
+    0xd4c5, 0xd4c6, 0xd4c7, 0xd4c8, 0xd4c9, 0xd4ca, 0xd4cb, 0xd4cd, 0xd4ce, 0xd4cf, 0xd4d1, 0xd4d2, 0xd4d3, 0xd4d5, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xd4d6, 0xd4d7, 0xd4d8, 0xd4d9, 0xd4da, 0xd4db, 0xd4dd, 0xd4de, 0xd4e0, 0xd4e1, 0xd4e2, 0xd4e3,
+    0xd4e4, 0xd4e5, 0xd4e6, 0xd4e7, 0xd4e9, 0xd4ea, 0xd4eb, 0xd4ed, 0xd4ee, 0xd4ef, 0xd4f1, 0xd4f2, 0xd4f3, 0xd4f4, 0xd4f5, 0xd4f6,
+    0xd4f7, 0xd4f9, 0xd4fa, 0xd4fc, 0xc5d0, 0xc5d1, 0xc5d4, 0xc5d8, 0xc5e0, 0xc5e1, 0xc5e3, 0xc5e5, 0xc5ec, 0xc5ed, 0xc5ee, 0xc5f0,
+    0xc5f4, 0xc5f6, 0xc5f7, 0xc5fc, 0xc5fd, 0xc5fe, 0xc5ff, 0xc600, 0xc601, 0xc605, 0xc606, 0xc607, 0xc608, 0xc60c, 0xc610, 0xc618,
+    0xc619, 0xc61b, 0xc61c, 0xc624, 0xc625, 0xc628, 0xc62c, 0xc62d, 0xc62e, 0xc630, 0xc633, 0xc634, 0xc635, 0xc637, 0xc639, 0xc63b,
+    0xc640, 0xc641, 0xc644, 0xc648, 0xc650, 0xc651, 0xc653, 0xc654, 0xc655, 0xc65c, 0xc65d, 0xc660, 0xc66c, 0xc66f, 0xc671, 0xc678,
+    0xc679, 0xc67c, 0xc680, 0xc688, 0xc689, 0xc68b, 0xc68d, 0xc694, 0xc695, 0xc698, 0xc69c, 0xc6a4, 0xc6a5, 0xc6a7, 0xc6a9, 0xc6b0,
+    0xc6b1, 0xc6b4, 0xc6b8, 0xc6b9, 0xc6ba, 0xc6c0, 0xc6c1, 0xc6c3, 0xc6c5, 0xc6cc, 0xc6cd, 0xc6d0, 0xc6d4, 0xc6dc, 0xc6dd, 0xc6e0,
+    0xc6e1, 0xc6e8, 0xd4fe, 0xd4ff, 0xd500, 0xd501, 0xd502, 0xd503, 0xd505, 0xd506, 0xd507, 0xd509, 0xd50a, 0xd50b, 0xd50d, 0xd50e,
+    0xd50f, 0xd510, 0xd511, 0xd512, 0xd513, 0xd516, 0xd518, 0xd519, 0xd51a, 0xd51b, 0xd51c, 0xd51d, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xd51e, 0xd51f, 0xd520, 0xd521, 0xd522, 0xd523, 0xd524, 0xd525, 0xd526, 0xd527, 0xd528, 0xd529, 0xd52a, 0xd52b,
+    0xd52c, 0xd52d, 0xd52e, 0xd52f, 0xd530, 0xd531, 0xd532, 0xd533, 0xd534, 0xd535, 0xd536, 0xd537, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xd538, 0xd539, 0xd53a, 0xd53b, 0xd53e, 0xd53f, 0xd541, 0xd542, 0xd543, 0xd545, 0xd546, 0xd547, 0xd548, 0xd549,
+    0xd54a, 0xd54b, 0xd54e, 0xd550, 0xd552, 0xd553, 0xd554, 0xd555, 0xd556, 0xd557, 0xd55a, 0xd55b, 0xd55d, 0xd55e, 0xd55f, 0xd561,
+    0xd562, 0xd563, 0xc6e9, 0xc6ec, 0xc6f0, 0xc6f8, 0xc6f9, 0xc6fd, 0xc704, 0xc705, 0xc708, 0xc70c, 0xc714, 0xc715, 0xc717, 0xc719,
+    0xc720, 0xc721, 0xc724, 0xc728, 0xc730, 0xc731, 0xc733, 0xc735, 0xc737, 0xc73c, 0xc73d, 0xc740, 0xc744, 0xc74a, 0xc74c, 0xc74d,
+    0xc74f, 0xc751, 0xc752, 0xc753, 0xc754, 0xc755, 0xc756, 0xc757, 0xc758, 0xc75c, 0xc760, 0xc768, 0xc76b, 0xc774, 0xc775, 0xc778,
+    0xc77c, 0xc77d, 0xc77e, 0xc783, 0xc784, 0xc785, 0xc787, 0xc788, 0xc789, 0xc78a, 0xc78e, 0xc790, 0xc791, 0xc794, 0xc796, 0xc797,
+    0xc798, 0xc79a, 0xc7a0, 0xc7a1, 0xc7a3, 0xc7a4, 0xc7a5, 0xc7a6, 0xc7ac, 0xc7ad, 0xc7b0, 0xc7b4, 0xc7bc, 0xc7bd, 0xc7bf, 0xc7c0,
+    0xc7c1, 0xc7c8, 0xc7c9, 0xc7cc, 0xc7ce, 0xc7d0, 0xc7d8, 0xc7dd, 0xc7e4, 0xc7e8, 0xc7ec, 0xc800, 0xc801, 0xc804, 0xc808, 0xc80a,
+    0xd564, 0xd566, 0xd567, 0xd56a, 0xd56c, 0xd56e, 0xd56f, 0xd570, 0xd571, 0xd572, 0xd573, 0xd576, 0xd577, 0xd579, 0xd57a, 0xd57b,
+    0xd57d, 0xd57e, 0xd57f, 0xd580, 0xd581, 0xd582, 0xd583, 0xd586, 0xd58a, 0xd58b, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xd58c, 0xd58d, 0xd58e, 0xd58f, 0xd591, 0xd592, 0xd593, 0xd594, 0xd595, 0xd596, 0xd597, 0xd598, 0xd599, 0xd59a, 0xd59b, 0xd59c,
+    0xd59d, 0xd59e, 0xd59f, 0xd5a0, 0xd5a1, 0xd5a2, 0xd5a3, 0xd5a4, 0xd5a6, 0xd5a7, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0xd5a8, 0xd5a9, 0xd5aa, 0xd5ab, 0xd5ac, 0xd5ad, 0xd5ae, 0xd5af, 0xd5b0, 0xd5b1, 0xd5b2, 0xd5b3, 0xd5b4, 0xd5b5, 0xd5b6, 0xd5b7,
+    0xd5b8, 0xd5b9, 0xd5ba, 0xd5bb, 0xd5bc, 0xd5bd, 0xd5be, 0xd5bf, 0xd5c0, 0xd5c1, 0xd5c2, 0xd5c3, 0xd5c4, 0xd5c5, 0xd5c6, 0xd5c7,
+    0xc810, 0xc811, 0xc813, 0xc815, 0xc816, 0xc81c, 0xc81d, 0xc820, 0xc824, 0xc82c, 0xc82d, 0xc82f, 0xc831, 0xc838, 0xc83c, 0xc840,
+    0xc848, 0xc849, 0xc84c, 0xc84d, 0xc854, 0xc870, 0xc871, 0xc874, 0xc878, 0xc87a, 0xc880, 0xc881, 0xc883, 0xc885, 0xc886, 0xc887,
+    0xc88b, 0xc88c, 0xc88d, 0xc894, 0xc89d, 0xc89f, 0xc8a1, 0xc8a8, 0xc8bc, 0xc8bd, 0xc8c4, 0xc8c8, 0xc8cc, 0xc8d4, 0xc8d5, 0xc8d7,
+    0xc8d9, 0xc8e0, 0xc8e1, 0xc8e4, 0xc8f5, 0xc8fc, 0xc8fd, 0xc900, 0xc904, 0xc905, 0xc906, 0xc90c, 0xc90d, 0xc90f, 0xc911, 0xc918,
+    0xc92c, 0xc934, 0xc950, 0xc951, 0xc954, 0xc958, 0xc960, 0xc961, 0xc963, 0xc96c, 0xc970, 0xc974, 0xc97c, 0xc988, 0xc989, 0xc98c,
+    0xc990, 0xc998, 0xc999, 0xc99b, 0xc99d, 0xc9c0, 0xc9c1, 0xc9c4, 0xc9c7, 0xc9c8, 0xc9ca, 0xc9d0, 0xc9d1, 0xc9d3, 0xd5ca, 0xd5cb,
+    0xd5cd, 0xd5ce, 0xd5cf, 0xd5d1, 0xd5d3, 0xd5d4, 0xd5d5, 0xd5d6, 0xd5d7, 0xd5da, 0xd5dc, 0xd5de, 0xd5df, 0xd5e0, 0xd5e1, 0xd5e2,
+    0xd5e3, 0xd5e6, 0xd5e7, 0xd5e9, 0xd5ea, 0xd5eb, 0xd5ed, 0xd5ee, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd5ef, 0xd5f0,
+    0xd5f1, 0xd5f2, 0xd5f3, 0xd5f6, 0xd5f8, 0xd5fa, 0xd5fb, 0xd5fc, 0xd5fd, 0xd5fe, 0xd5ff, 0xd602, 0xd603, 0xd605, 0xd606, 0xd607,
+    0xd609, 0xd60a, 0xd60b, 0xd60c, 0xd60d, 0xd60e, 0xd60f, 0xd612, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd616, 0xd617,
+    0xd618, 0xd619, 0xd61a, 0xd61b, 0xd61d, 0xd61e, 0xd61f, 0xd621, 0xd622, 0xd623, 0xd625, 0xd626, 0xd627, 0xd628, 0xd629, 0xd62a,
+    0xd62b, 0xd62c, 0xd62e, 0xd62f, 0xd630, 0xd631, 0xd632, 0xd633, 0xd634, 0xd635, 0xd636, 0xd637, 0xd63a, 0xd63b, 0xc9d5, 0xc9d6,
+    0xc9d9, 0xc9da, 0xc9dc, 0xc9dd, 0xc9e0, 0xc9e2, 0xc9e4, 0xc9e7, 0xc9ec, 0xc9ed, 0xc9ef, 0xc9f0, 0xc9f1, 0xc9f8, 0xc9f9, 0xc9fc,
+    0xca00, 0xca08, 0xca09, 0xca0b, 0xca0c, 0xca0d, 0xca14, 0xca18, 0xca29, 0xca4c, 0xca4d, 0xca50, 0xca54, 0xca5c, 0xca5d, 0xca5f,
+    0xca60, 0xca61, 0xca68, 0xca7d, 0xca84, 0xca98, 0xcabc, 0xcabd, 0xcac0, 0xcac4, 0xcacc, 0xcacd, 0xcacf, 0xcad1, 0xcad3, 0xcad8,
+    0xcad9, 0xcae0, 0xcaec, 0xcaf4, 0xcb08, 0xcb10, 0xcb14, 0xcb18, 0xcb20, 0xcb21, 0xcb41, 0xcb48, 0xcb49, 0xcb4c, 0xcb50, 0xcb58,
+    0xcb59, 0xcb5d, 0xcb64, 0xcb78, 0xcb79, 0xcb9c, 0xcbb8, 0xcbd4, 0xcbe4, 0xcbe7, 0xcbe9, 0xcc0c, 0xcc0d, 0xcc10, 0xcc14, 0xcc1c,
+    0xcc1d, 0xcc21, 0xcc22, 0xcc27, 0xcc28, 0xcc29, 0xcc2c, 0xcc2e, 0xcc30, 0xcc38, 0xcc39, 0xcc3b, 0xd63d, 0xd63e, 0xd63f, 0xd641,
+    0xd642, 0xd643, 0xd644, 0xd646, 0xd647, 0xd64a, 0xd64c, 0xd64e, 0xd64f, 0xd650, 0xd652, 0xd653, 0xd656, 0xd657, 0xd659, 0xd65a,
+    0xd65b, 0xd65d, 0xd65e, 0xd65f, 0xd660, 0xd661, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd662, 0xd663, 0xd664, 0xd665,
+    0xd666, 0xd668, 0xd66a, 0xd66b, 0xd66c, 0xd66d, 0xd66e, 0xd66f, 0xd672, 0xd673, 0xd675, 0xd676, 0xd677, 0xd678, 0xd679, 0xd67a,
+    0xd67b, 0xd67c, 0xd67d, 0xd67e, 0xd67f, 0xd680, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd681, 0xd682, 0xd684, 0xd686,
+    0xd687, 0xd688, 0xd689, 0xd68a, 0xd68b, 0xd68e, 0xd68f, 0xd691, 0xd692, 0xd693, 0xd695, 0xd696, 0xd697, 0xd698, 0xd699, 0xd69a,
+    0xd69b, 0xd69c, 0xd69e, 0xd6a0, 0xd6a2, 0xd6a3, 0xd6a4, 0xd6a5, 0xd6a6, 0xd6a7, 0xd6a9, 0xd6aa, 0xcc3c, 0xcc3d, 0xcc3e, 0xcc44,
+    0xcc45, 0xcc48, 0xcc4c, 0xcc54, 0xcc55, 0xcc57, 0xcc58, 0xcc59, 0xcc60, 0xcc64, 0xcc66, 0xcc68, 0xcc70, 0xcc75, 0xcc98, 0xcc99,
+    0xcc9c, 0xcca0, 0xcca8, 0xcca9, 0xccab, 0xccac, 0xccad, 0xccb4, 0xccb5, 0xccb8, 0xccbc, 0xccc4, 0xccc5, 0xccc7, 0xccc9, 0xccd0,
+    0xccd4, 0xcce4, 0xccec, 0xccf0, 0xcd01, 0xcd08, 0xcd09, 0xcd0c, 0xcd10, 0xcd18, 0xcd19, 0xcd1b, 0xcd1d, 0xcd24, 0xcd28, 0xcd2c,
+    0xcd39, 0xcd5c, 0xcd60, 0xcd64, 0xcd6c, 0xcd6d, 0xcd6f, 0xcd71, 0xcd78, 0xcd88, 0xcd94, 0xcd95, 0xcd98, 0xcd9c, 0xcda4, 0xcda5,
+    0xcda7, 0xcda9, 0xcdb0, 0xcdc4, 0xcdcc, 0xcdd0, 0xcde8, 0xcdec, 0xcdf0, 0xcdf8, 0xcdf9, 0xcdfb, 0xcdfd, 0xce04, 0xce08, 0xce0c,
+    0xce14, 0xce19, 0xce20, 0xce21, 0xce24, 0xce28, 0xce30, 0xce31, 0xce33, 0xce35, 0xd6ab, 0xd6ad, 0xd6ae, 0xd6af, 0xd6b1, 0xd6b2,
+    0xd6b3, 0xd6b4, 0xd6b5, 0xd6b6, 0xd6b7, 0xd6b8, 0xd6ba, 0xd6bc, 0xd6bd, 0xd6be, 0xd6bf, 0xd6c0, 0xd6c1, 0xd6c2, 0xd6c3, 0xd6c6,
+    0xd6c7, 0xd6c9, 0xd6ca, 0xd6cb, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd6cd, 0xd6ce, 0xd6cf, 0xd6d0, 0xd6d2, 0xd6d3,
+    0xd6d5, 0xd6d6, 0xd6d8, 0xd6da, 0xd6db, 0xd6dc, 0xd6dd, 0xd6de, 0xd6df, 0xd6e1, 0xd6e2, 0xd6e3, 0xd6e5, 0xd6e6, 0xd6e7, 0xd6e9,
+    0xd6ea, 0xd6eb, 0xd6ec, 0xd6ed, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd6ee, 0xd6ef, 0xd6f1, 0xd6f2, 0xd6f3, 0xd6f4,
+    0xd6f6, 0xd6f7, 0xd6f8, 0xd6f9, 0xd6fa, 0xd6fb, 0xd6fe, 0xd6ff, 0xd701, 0xd702, 0xd703, 0xd705, 0xd706, 0xd707, 0xd708, 0xd709,
+    0xd70a, 0xd70b, 0xd70c, 0xd70d, 0xd70e, 0xd70f, 0xd710, 0xd712, 0xd713, 0xd714, 0xce58, 0xce59, 0xce5c, 0xce5f, 0xce60, 0xce61,
+    0xce68, 0xce69, 0xce6b, 0xce6d, 0xce74, 0xce75, 0xce78, 0xce7c, 0xce84, 0xce85, 0xce87, 0xce89, 0xce90, 0xce91, 0xce94, 0xce98,
+    0xcea0, 0xcea1, 0xcea3, 0xcea4, 0xcea5, 0xceac, 0xcead, 0xcec1, 0xcee4, 0xcee5, 0xcee8, 0xceeb, 0xceec, 0xcef4, 0xcef5, 0xcef7,
+    0xcef8, 0xcef9, 0xcf00, 0xcf01, 0xcf04, 0xcf08, 0xcf10, 0xcf11, 0xcf13, 0xcf15, 0xcf1c, 0xcf20, 0xcf24, 0xcf2c, 0xcf2d, 0xcf2f,
+    0xcf30, 0xcf31, 0xcf38, 0xcf54, 0xcf55, 0xcf58, 0xcf5c, 0xcf64, 0xcf65, 0xcf67, 0xcf69, 0xcf70, 0xcf71, 0xcf74, 0xcf78, 0xcf80,
+    0xcf85, 0xcf8c, 0xcfa1, 0xcfa8, 0xcfb0, 0xcfc4, 0xcfe0, 0xcfe1, 0xcfe4, 0xcfe8, 0xcff0, 0xcff1, 0xcff3, 0xcff5, 0xcffc, 0xd000,
+    0xd004, 0xd011, 0xd018, 0xd02d, 0xd034, 0xd035, 0xd038, 0xd03c, 0xd715, 0xd716, 0xd717, 0xd71a, 0xd71b, 0xd71d, 0xd71e, 0xd71f,
+    0xd721, 0xd722, 0xd723, 0xd724, 0xd725, 0xd726, 0xd727, 0xd72a, 0xd72c, 0xd72e, 0xd72f, 0xd730, 0xd731, 0xd732, 0xd733, 0xd736,
+    0xd737, 0xd739, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd73a, 0xd73b, 0xd73d, 0xd73e, 0xd73f, 0xd740, 0xd741, 0xd742,
+    0xd743, 0xd745, 0xd746, 0xd748, 0xd74a, 0xd74b, 0xd74c, 0xd74d, 0xd74e, 0xd74f, 0xd752, 0xd753, 0xd755, 0xd75a, 0xd75b, 0xd75c,
+    0xd75d, 0xd75e, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd75f, 0xd762, 0xd764, 0xd766, 0xd767, 0xd768, 0xd76a, 0xd76b,
+    0xd76d, 0xd76e, 0xd76f, 0xd771, 0xd772, 0xd773, 0xd775, 0xd776, 0xd777, 0xd778, 0xd779, 0xd77a, 0xd77b, 0xd77e, 0xd77f, 0xd780,
+    0xd782, 0xd783, 0xd784, 0xd785, 0xd786, 0xd787, 0xd78a, 0xd78b, 0xd044, 0xd045, 0xd047, 0xd049, 0xd050, 0xd054, 0xd058, 0xd060,
+    0xd06c, 0xd06d, 0xd070, 0xd074, 0xd07c, 0xd07d, 0xd081, 0xd0a4, 0xd0a5, 0xd0a8, 0xd0ac, 0xd0b4, 0xd0b5, 0xd0b7, 0xd0b9, 0xd0c0,
+    0xd0c1, 0xd0c4, 0xd0c8, 0xd0c9, 0xd0d0, 0xd0d1, 0xd0d3, 0xd0d4, 0xd0d5, 0xd0dc, 0xd0dd, 0xd0e0, 0xd0e4, 0xd0ec, 0xd0ed, 0xd0ef,
+    0xd0f0, 0xd0f1, 0xd0f8, 0xd10d, 0xd130, 0xd131, 0xd134, 0xd138, 0xd13a, 0xd140, 0xd141, 0xd143, 0xd144, 0xd145, 0xd14c, 0xd14d,
+    0xd150, 0xd154, 0xd15c, 0xd15d, 0xd15f, 0xd161, 0xd168, 0xd16c, 0xd17c, 0xd184, 0xd188, 0xd1a0, 0xd1a1, 0xd1a4, 0xd1a8, 0xd1b0,
+    0xd1b1, 0xd1b3, 0xd1b5, 0xd1ba, 0xd1bc, 0xd1c0, 0xd1d8, 0xd1f4, 0xd1f8, 0xd207, 0xd209, 0xd210, 0xd22c, 0xd22d, 0xd230, 0xd234,
+    0xd23c, 0xd23d, 0xd23f, 0xd241, 0xd248, 0xd25c, 0xd78d, 0xd78e, 0xd78f, 0xd791, 0xd792, 0xd793, 0xd794, 0xd795, 0xd796, 0xd797,
+    0xd79a, 0xd79c, 0xd79e, 0xd79f, 0xd7a0, 0xd7a1, 0xd7a2, 0xd7a3, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xd264, 0xd280, 0xd281, 0xd284, 0xd288, 0xd290, 0xd291, 0xd295, 0xd29c, 0xd2a0,
+    0xd2a4, 0xd2ac, 0xd2b1, 0xd2b8, 0xd2b9, 0xd2bc, 0xd2bf, 0xd2c0, 0xd2c2, 0xd2c8, 0xd2c9, 0xd2cb, 0xd2d4, 0xd2d8, 0xd2dc, 0xd2e4,
+    0xd2e5, 0xd2f0, 0xd2f1, 0xd2f4, 0xd2f8, 0xd300, 0xd301, 0xd303, 0xd305, 0xd30c, 0xd30d, 0xd30e, 0xd310, 0xd314, 0xd316, 0xd31c,
+    0xd31d, 0xd31f, 0xd320, 0xd321, 0xd325, 0xd328, 0xd329, 0xd32c, 0xd330, 0xd338, 0xd339, 0xd33b, 0xd33c, 0xd33d, 0xd344, 0xd345,
+    0xd37c, 0xd37d, 0xd380, 0xd384, 0xd38c, 0xd38d, 0xd38f, 0xd390, 0xd391, 0xd398, 0xd399, 0xd39c, 0xd3a0, 0xd3a8, 0xd3a9, 0xd3ab,
+    0xd3ad, 0xd3b4, 0xd3b8, 0xd3bc, 0xd3c4, 0xd3c5, 0xd3c8, 0xd3c9, 0xd3d0, 0xd3d8, 0xd3e1, 0xd3e3, 0xd3ec, 0xd3ed, 0xd3f0, 0xd3f4,
+    0xd3fc, 0xd3fd, 0xd3ff, 0xd401, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xd408, 0xd41d, 0xd440, 0xd444, 0xd45c, 0xd460, 0xd464, 0xd46d, 0xd46f, 0xd478, 0xd479, 0xd47c,
+    0xd47f, 0xd480, 0xd482, 0xd488, 0xd489, 0xd48b, 0xd48d, 0xd494, 0xd4a9, 0xd4cc, 0xd4d0, 0xd4d4, 0xd4dc, 0xd4df, 0xd4e8, 0xd4ec,
+    0xd4f0, 0xd4f8, 0xd4fb, 0xd4fd, 0xd504, 0xd508, 0xd50c, 0xd514, 0xd515, 0xd517, 0xd53c, 0xd53d, 0xd540, 0xd544, 0xd54c, 0xd54d,
+    0xd54f, 0xd551, 0xd558, 0xd559, 0xd55c, 0xd560, 0xd565, 0xd568, 0xd569, 0xd56b, 0xd56d, 0xd574, 0xd575, 0xd578, 0xd57c, 0xd584,
+    0xd585, 0xd587, 0xd588, 0xd589, 0xd590, 0xd5a5, 0xd5c8, 0xd5c9, 0xd5cc, 0xd5d0, 0xd5d2, 0xd5d8, 0xd5d9, 0xd5db, 0xd5dd, 0xd5e4,
+    0xd5e5, 0xd5e8, 0xd5ec, 0xd5f4, 0xd5f5, 0xd5f7, 0xd5f9, 0xd600, 0xd601, 0xd604, 0xd608, 0xd610, 0xd611, 0xd613, 0xd614, 0xd615,
+    0xd61c, 0xd620, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xd624, 0xd62d, 0xd638, 0xd639, 0xd63c, 0xd640, 0xd645, 0xd648, 0xd649, 0xd64b, 0xd64d, 0xd651, 0xd654, 0xd655,
+    0xd658, 0xd65c, 0xd667, 0xd669, 0xd670, 0xd671, 0xd674, 0xd683, 0xd685, 0xd68c, 0xd68d, 0xd690, 0xd694, 0xd69d, 0xd69f, 0xd6a1,
+    0xd6a8, 0xd6ac, 0xd6b0, 0xd6b9, 0xd6bb, 0xd6c4, 0xd6c5, 0xd6c8, 0xd6cc, 0xd6d1, 0xd6d4, 0xd6d7, 0xd6d9, 0xd6e0, 0xd6e4, 0xd6e8,
+    0xd6f0, 0xd6f5, 0xd6fc, 0xd6fd, 0xd700, 0xd704, 0xd711, 0xd718, 0xd719, 0xd71c, 0xd720, 0xd728, 0xd729, 0xd72b, 0xd72d, 0xd734,
+    0xd735, 0xd738, 0xd73c, 0xd744, 0xd747, 0xd749, 0xd750, 0xd751, 0xd754, 0xd756, 0xd757, 0xd758, 0xd759, 0xd760, 0xd761, 0xd763,
+    0xd765, 0xd769, 0xd76c, 0xd770, 0xd774, 0xd77c, 0xd77d, 0xd781, 0xd788, 0xd789, 0xd78c, 0xd790, 0xd798, 0xd799, 0xd79b, 0xd79d,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4f3d, 0x4f73,
+    0x5047, 0x50f9, 0x52a0, 0x53ef, 0x5475, 0x54e5, 0x5609, 0x5ac1, 0x5bb6, 0x6687, 0x67b6, 0x67b7, 0x67ef, 0x6b4c, 0x73c2, 0x75c2,
+    0x7a3c, 0x82db, 0x8304, 0x8857, 0x8888, 0x8a36, 0x8cc8, 0x8dcf, 0x8efb, 0x8fe6, 0x99d5, 0x523b, 0x5374, 0x5404, 0x606a, 0x6164,
+    0x6bbc, 0x73cf, 0x811a, 0x89ba, 0x89d2, 0x95a3, 0x4f83, 0x520a, 0x58be, 0x5978, 0x59e6, 0x5e72, 0x5e79, 0x61c7, 0x63c0, 0x6746,
+    0x67ec, 0x687f, 0x6f97, 0x764e, 0x770b, 0x78f5, 0x7a08, 0x7aff, 0x7c21, 0x809d, 0x826e, 0x8271, 0x8aeb, 0x9593, 0x4e6b, 0x559d,
+    0x66f7, 0x6e34, 0x78a3, 0x7aed, 0x845b, 0x8910, 0x874e, 0x97a8, 0x52d8, 0x574e, 0x582a, 0x5d4c, 0x611f, 0x61be, 0x6221, 0x6562,
+    0x67d1, 0x6a44, 0x6e1b, 0x7518, 0x75b3, 0x76e3, 0x77b0, 0x7d3a, 0x90af, 0x9451, 0x9452, 0x9f95, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5323, 0x5cac, 0x7532, 0x80db,
+    0x9240, 0x9598, 0x525b, 0x5808, 0x59dc, 0x5ca1, 0x5d17, 0x5eb7, 0x5f3a, 0x5f4a, 0x6177, 0x6c5f, 0x757a, 0x7586, 0x7ce0, 0x7d73,
+    0x7db1, 0x7f8c, 0x8154, 0x8221, 0x8591, 0x8941, 0x8b1b, 0x92fc, 0x964d, 0x9c47, 0x4ecb, 0x4ef7, 0x500b, 0x51f1, 0x584f, 0x6137,
+    0x613e, 0x6168, 0x6539, 0x69ea, 0x6f11, 0x75a5, 0x7686, 0x76d6, 0x7b87, 0x82a5, 0x84cb, 0xf900, 0x93a7, 0x958b, 0x5580, 0x5ba2,
+    0x5751, 0xf901, 0x7cb3, 0x7fb9, 0x91b5, 0x5028, 0x53bb, 0x5c45, 0x5de8, 0x62d2, 0x636e, 0x64da, 0x64e7, 0x6e20, 0x70ac, 0x795b,
+    0x8ddd, 0x8e1e, 0xf902, 0x907d, 0x9245, 0x92f8, 0x4e7e, 0x4ef6, 0x5065, 0x5dfe, 0x5efa, 0x6106, 0x6957, 0x8171, 0x8654, 0x8e47,
+    0x9375, 0x9a2b, 0x4e5e, 0x5091, 0x6770, 0x6840, 0x5109, 0x528d, 0x5292, 0x6aa2, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x77bc, 0x9210, 0x9ed4, 0x52ab, 0x602f, 0x8ff2,
+    0x5048, 0x61a9, 0x63ed, 0x64ca, 0x683c, 0x6a84, 0x6fc0, 0x8188, 0x89a1, 0x9694, 0x5805, 0x727d, 0x72ac, 0x7504, 0x7d79, 0x7e6d,
+    0x80a9, 0x898b, 0x8b74, 0x9063, 0x9d51, 0x6289, 0x6c7a, 0x6f54, 0x7d50, 0x7f3a, 0x8a23, 0x517c, 0x614a, 0x7b9d, 0x8b19, 0x9257,
+    0x938c, 0x4eac, 0x4fd3, 0x501e, 0x50be, 0x5106, 0x52c1, 0x52cd, 0x537f, 0x5770, 0x5883, 0x5e9a, 0x5f91, 0x6176, 0x61ac, 0x64ce,
+    0x656c, 0x666f, 0x66bb, 0x66f4, 0x6897, 0x6d87, 0x7085, 0x70f1, 0x749f, 0x74a5, 0x74ca, 0x75d9, 0x786c, 0x78ec, 0x7adf, 0x7af6,
+    0x7d45, 0x7d93, 0x8015, 0x803f, 0x811b, 0x8396, 0x8b66, 0x8f15, 0x9015, 0x93e1, 0x9803, 0x9838, 0x9a5a, 0x9be8, 0x4fc2, 0x5553,
+    0x583a, 0x5951, 0x5b63, 0x5c46, 0x60b8, 0x6212, 0x6842, 0x68b0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x68e8, 0x6eaa, 0x754c, 0x7678, 0x78ce, 0x7a3d, 0x7cfb, 0x7e6b,
+    0x7e7c, 0x8a08, 0x8aa1, 0x8c3f, 0x968e, 0x9dc4, 0x53e4, 0x53e9, 0x544a, 0x5471, 0x56fa, 0x59d1, 0x5b64, 0x5c3b, 0x5eab, 0x62f7,
+    0x6537, 0x6545, 0x6572, 0x66a0, 0x67af, 0x69c1, 0x6cbd, 0x75fc, 0x7690, 0x777e, 0x7a3f, 0x7f94, 0x8003, 0x80a1, 0x818f, 0x82e6,
+    0x82fd, 0x83f0, 0x85c1, 0x8831, 0x88b4, 0x8aa5, 0xf903, 0x8f9c, 0x932e, 0x96c7, 0x9867, 0x9ad8, 0x9f13, 0x54ed, 0x659b, 0x66f2,
+    0x688f, 0x7a40, 0x8c37, 0x9d60, 0x56f0, 0x5764, 0x5d11, 0x6606, 0x68b1, 0x68cd, 0x6efe, 0x7428, 0x889e, 0x9be4, 0x6c68, 0xf904,
+    0x9aa8, 0x4f9b, 0x516c, 0x5171, 0x529f, 0x5b54, 0x5de5, 0x6050, 0x606d, 0x62f1, 0x63a7, 0x653b, 0x73d9, 0x7a7a, 0x86a3, 0x8ca2,
+    0x978f, 0x4e32, 0x5be1, 0x6208, 0x679c, 0x74dc, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x79d1, 0x83d3, 0x8a87, 0x8ab2, 0x8de8, 0x904e, 0x934b, 0x9846, 0x5ed3, 0x69e8,
+    0x85ff, 0x90ed, 0xf905, 0x51a0, 0x5b98, 0x5bec, 0x6163, 0x68fa, 0x6b3e, 0x704c, 0x742f, 0x74d8, 0x7ba1, 0x7f50, 0x83c5, 0x89c0,
+    0x8cab, 0x95dc, 0x9928, 0x522e, 0x605d, 0x62ec, 0x9002, 0x4f8a, 0x5149, 0x5321, 0x58d9, 0x5ee3, 0x66e0, 0x6d38, 0x709a, 0x72c2,
+    0x73d6, 0x7b50, 0x80f1, 0x945b, 0x5366, 0x639b, 0x7f6b, 0x4e56, 0x5080, 0x584a, 0x58de, 0x602a, 0x6127, 0x62d0, 0x69d0, 0x9b41,
+    0x5b8f, 0x7d18, 0x80b1, 0x8f5f, 0x4ea4, 0x50d1, 0x54ac, 0x55ac, 0x5b0c, 0x5da0, 0x5de7, 0x652a, 0x654e, 0x6821, 0x6a4b, 0x72e1,
+    0x768e, 0x77ef, 0x7d5e, 0x7ff9, 0x81a0, 0x854e, 0x86df, 0x8f03, 0x8f4e, 0x90ca, 0x9903, 0x9a55, 0x9bab, 0x4e18, 0x4e45, 0x4e5d,
+    0x4ec7, 0x4ff1, 0x5177, 0x52fe, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x5340, 0x53e3, 0x53e5, 0x548e, 0x5614, 0x5775, 0x57a2, 0x5bc7, 0x5d87, 0x5ed0, 0x61fc, 0x62d8,
+    0x6551, 0x67b8, 0x67e9, 0x69cb, 0x6b50, 0x6bc6, 0x6bec, 0x6c42, 0x6e9d, 0x7078, 0x72d7, 0x7396, 0x7403, 0x77bf, 0x77e9, 0x7a76,
+    0x7d7f, 0x8009, 0x81fc, 0x8205, 0x820a, 0x82df, 0x8862, 0x8b33, 0x8cfc, 0x8ec0, 0x9011, 0x90b1, 0x9264, 0x92b6, 0x99d2, 0x9a45,
+    0x9ce9, 0x9dd7, 0x9f9c, 0x570b, 0x5c40, 0x83ca, 0x97a0, 0x97ab, 0x9eb4, 0x541b, 0x7a98, 0x7fa4, 0x88d9, 0x8ecd, 0x90e1, 0x5800,
+    0x5c48, 0x6398, 0x7a9f, 0x5bae, 0x5f13, 0x7a79, 0x7aae, 0x828e, 0x8eac, 0x5026, 0x5238, 0x52f8, 0x5377, 0x5708, 0x62f3, 0x6372,
+    0x6b0a, 0x6dc3, 0x7737, 0x53a5, 0x7357, 0x8568, 0x8e76, 0x95d5, 0x673a, 0x6ac3, 0x6f70, 0x8a6d, 0x8ecc, 0x994b, 0xf906, 0x6677,
+    0x6b78, 0x8cb4, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x9b3c, 0xf907, 0x53eb, 0x572d, 0x594e, 0x63c6, 0x69fb, 0x73ea, 0x7845, 0x7aba, 0x7ac5, 0x7cfe, 0x8475, 0x898f,
+    0x8d73, 0x9035, 0x95a8, 0x52fb, 0x5747, 0x7547, 0x7b60, 0x83cc, 0x921e, 0xf908, 0x6a58, 0x514b, 0x524b, 0x5287, 0x621f, 0x68d8,
+    0x6975, 0x9699, 0x50c5, 0x52a4, 0x52e4, 0x61c3, 0x65a4, 0x6839, 0x69ff, 0x747e, 0x7b4b, 0x82b9, 0x83eb, 0x89b2, 0x8b39, 0x8fd1,
+    0x9949, 0xf909, 0x4eca, 0x5997, 0x64d2, 0x6611, 0x6a8e, 0x7434, 0x7981, 0x79bd, 0x82a9, 0x887e, 0x887f, 0x895f, 0xf90a, 0x9326,
+    0x4f0b, 0x53ca, 0x6025, 0x6271, 0x6c72, 0x7d1a, 0x7d66, 0x4e98, 0x5162, 0x77dc, 0x80af, 0x4f01, 0x4f0e, 0x5176, 0x5180, 0x55dc,
+    0x5668, 0x573b, 0x57fa, 0x57fc, 0x5914, 0x5947, 0x5993, 0x5bc4, 0x5c90, 0x5d0e, 0x5df1, 0x5e7e, 0x5fcc, 0x6280, 0x65d7, 0x65e3,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x671e, 0x671f, 0x675e, 0x68cb, 0x68c4, 0x6a5f, 0x6b3a, 0x6c23, 0x6c7d, 0x6c82, 0x6dc7, 0x7398, 0x7426, 0x742a, 0x7482, 0x74a3,
+    0x7578, 0x757f, 0x7881, 0x78ef, 0x7941, 0x7947, 0x7948, 0x797a, 0x7b95, 0x7d00, 0x7dba, 0x7f88, 0x8006, 0x802d, 0x808c, 0x8a18,
+    0x8b4f, 0x8c48, 0x8d77, 0x9321, 0x9324, 0x98e2, 0x9951, 0x9a0e, 0x9a0f, 0x9a65, 0x9e92, 0x7dca, 0x4f76, 0x5409, 0x62ee, 0x6854,
+    0x91d1, 0x55ab, 0x513a, 0xf90b, 0xf90c, 0x5a1c, 0x61e6, 0xf90d, 0x62cf, 0x62ff, 0xf90e, 0xf90f, 0xf910, 0xf911, 0xf912, 0xf913,
+    0x90a3, 0xf914, 0xf915, 0xf916, 0xf917, 0xf918, 0x8afe, 0xf919, 0xf91a, 0xf91b, 0xf91c, 0x6696, 0xf91d, 0x7156, 0xf91e, 0xf91f,
+    0x96e3, 0xf920, 0x634f, 0x637a, 0x5357, 0xf921, 0x678f, 0x6960, 0x6e73, 0xf922, 0x7537, 0xf923, 0xf924, 0xf925, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7d0d, 0xf926,
+    0xf927, 0x8872, 0x56ca, 0x5a18, 0xf928, 0xf929, 0xf92a, 0xf92b, 0xf92c, 0x4e43, 0xf92d, 0x5167, 0x5948, 0x67f0, 0x8010, 0xf92e,
+    0x5973, 0x5e74, 0x649a, 0x79ca, 0x5ff5, 0x606c, 0x62c8, 0x637b, 0x5be7, 0x5bd7, 0x52aa, 0xf92f, 0x5974, 0x5f29, 0x6012, 0xf930,
+    0xf931, 0xf932, 0x7459, 0xf933, 0xf934, 0xf935, 0xf936, 0xf937, 0xf938, 0x99d1, 0xf939, 0xf93a, 0xf93b, 0xf93c, 0xf93d, 0xf93e,
+    0xf93f, 0xf940, 0xf941, 0xf942, 0xf943, 0x6fc3, 0xf944, 0xf945, 0x81bf, 0x8fb2, 0x60f1, 0xf946, 0xf947, 0x8166, 0xf948, 0xf949,
+    0x5c3f, 0xf94a, 0xf94b, 0xf94c, 0xf94d, 0xf94e, 0xf94f, 0xf950, 0xf951, 0x5ae9, 0x8a25, 0x677b, 0x7d10, 0xf952, 0xf953, 0xf954,
+    0xf955, 0xf956, 0xf957, 0x80fd, 0xf958, 0xf959, 0x5c3c, 0x6ce5, 0x533f, 0x6eba, 0x591a, 0x8336, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4e39, 0x4eb6, 0x4f46, 0x55ae,
+    0x5718, 0x58c7, 0x5f56, 0x65b7, 0x65e6, 0x6a80, 0x6bb5, 0x6e4d, 0x77ed, 0x7aef, 0x7c1e, 0x7dde, 0x86cb, 0x8892, 0x9132, 0x935b,
+    0x64bb, 0x6fbe, 0x737a, 0x75b8, 0x9054, 0x5556, 0x574d, 0x61ba, 0x64d4, 0x66c7, 0x6de1, 0x6e5b, 0x6f6d, 0x6fb9, 0x75f0, 0x8043,
+    0x81bd, 0x8541, 0x8983, 0x8ac7, 0x8b5a, 0x931f, 0x6c93, 0x7553, 0x7b54, 0x8e0f, 0x905d, 0x5510, 0x5802, 0x5858, 0x5e62, 0x6207,
+    0x649e, 0x68e0, 0x7576, 0x7cd6, 0x87b3, 0x9ee8, 0x4ee3, 0x5788, 0x576e, 0x5927, 0x5c0d, 0x5cb1, 0x5e36, 0x5f85, 0x6234, 0x64e1,
+    0x73b3, 0x81fa, 0x888b, 0x8cb8, 0x968a, 0x9edb, 0x5b85, 0x5fb7, 0x60b3, 0x5012, 0x5200, 0x5230, 0x5716, 0x5835, 0x5857, 0x5c0e,
+    0x5c60, 0x5cf6, 0x5d8b, 0x5ea6, 0x5f92, 0x60bc, 0x6311, 0x6389, 0x6417, 0x6843, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x68f9, 0x6ac2, 0x6dd8, 0x6e21, 0x6ed4, 0x6fe4,
+    0x71fe, 0x76dc, 0x7779, 0x79b1, 0x7a3b, 0x8404, 0x89a9, 0x8ced, 0x8df3, 0x8e48, 0x9003, 0x9014, 0x9053, 0x90fd, 0x934d, 0x9676,
+    0x97dc, 0x6bd2, 0x7006, 0x7258, 0x72a2, 0x7368, 0x7763, 0x79bf, 0x7be4, 0x7e9b, 0x8b80, 0x58a9, 0x60c7, 0x6566, 0x65fd, 0x66be,
+    0x6c8c, 0x711e, 0x71c9, 0x8c5a, 0x9813, 0x4e6d, 0x7a81, 0x4edd, 0x51ac, 0x51cd, 0x52d5, 0x540c, 0x61a7, 0x6771, 0x6850, 0x68df,
+    0x6d1e, 0x6f7c, 0x75bc, 0x77b3, 0x7ae5, 0x80f4, 0x8463, 0x9285, 0x515c, 0x6597, 0x675c, 0x6793, 0x75d8, 0x7ac7, 0x8373, 0xf95a,
+    0x8c46, 0x9017, 0x982d, 0x5c6f, 0x81c0, 0x829a, 0x9041, 0x906f, 0x920d, 0x5f97, 0x5d9d, 0x6a59, 0x71c8, 0x767b, 0x7b49, 0x85e4,
+    0x8b04, 0x9127, 0x9a30, 0x5587, 0x61f6, 0xf95b, 0x7669, 0x7f85, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x863f, 0x87ba, 0x88f8, 0x908f, 0xf95c, 0x6d1b, 0x70d9, 0x73de,
+    0x7d61, 0x843d, 0xf95d, 0x916a, 0x99f1, 0xf95e, 0x4e82, 0x5375, 0x6b04, 0x6b12, 0x703e, 0x721b, 0x862d, 0x9e1e, 0x524c, 0x8fa3,
+    0x5d50, 0x64e5, 0x652c, 0x6b16, 0x6feb, 0x7c43, 0x7e9c, 0x85cd, 0x8964, 0x89bd, 0x62c9, 0x81d8, 0x881f, 0x5eca, 0x6717, 0x6d6a,
+    0x72fc, 0x7405, 0x746f, 0x8782, 0x90de, 0x4f86, 0x5d0d, 0x5fa0, 0x840a, 0x51b7, 0x63a0, 0x7565, 0x4eae, 0x5006, 0x5169, 0x51c9,
+    0x6881, 0x6a11, 0x7cae, 0x7cb1, 0x7ce7, 0x826f, 0x8ad2, 0x8f1b, 0x91cf, 0x4fb6, 0x5137, 0x52f5, 0x5442, 0x5eec, 0x616e, 0x623e,
+    0x65c5, 0x6ada, 0x6ffe, 0x792a, 0x85dc, 0x8823, 0x95ad, 0x9a62, 0x9a6a, 0x9e97, 0x9ece, 0x529b, 0x66c6, 0x6b77, 0x701d, 0x792b,
+    0x8f62, 0x9742, 0x6190, 0x6200, 0x6523, 0x6f23, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7149, 0x7489, 0x7df4, 0x806f, 0x84ee, 0x8f26, 0x9023, 0x934a, 0x51bd, 0x5217,
+    0x52a3, 0x6d0c, 0x70c8, 0x88c2, 0x5ec9, 0x6582, 0x6bae, 0x6fc2, 0x7c3e, 0x7375, 0x4ee4, 0x4f36, 0x56f9, 0xf95f, 0x5cba, 0x5dba,
+    0x601c, 0x73b2, 0x7b2d, 0x7f9a, 0x7fce, 0x8046, 0x901e, 0x9234, 0x96f6, 0x9748, 0x9818, 0x9f61, 0x4f8b, 0x6fa7, 0x79ae, 0x91b4,
+    0x96b7, 0x52de, 0xf960, 0x6488, 0x64c4, 0x6ad3, 0x6f5e, 0x7018, 0x7210, 0x76e7, 0x8001, 0x8606, 0x865c, 0x8def, 0x8f05, 0x9732,
+    0x9b6f, 0x9dfa, 0x9e75, 0x788c, 0x797f, 0x7da0, 0x83c9, 0x9304, 0x9e7f, 0x9e93, 0x8ad6, 0x58df, 0x5f04, 0x6727, 0x7027, 0x74cf,
+    0x7c60, 0x807e, 0x5121, 0x7028, 0x7262, 0x78ca, 0x8cc2, 0x8cda, 0x8cf4, 0x96f7, 0x4e86, 0x50da, 0x5bee, 0x5ed6, 0x6599, 0x71ce,
+    0x7642, 0x77ad, 0x804a, 0x84fc, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x907c, 0x9b27, 0x9f8d, 0x58d8, 0x5a41, 0x5c62, 0x6a13, 0x6dda, 0x6f0f, 0x763b, 0x7d2f, 0x7e37,
+    0x851e, 0x8938, 0x93e4, 0x964b, 0x5289, 0x65d2, 0x67f3, 0x69b4, 0x6d41, 0x6e9c, 0x700f, 0x7409, 0x7460, 0x7559, 0x7624, 0x786b,
+    0x8b2c, 0x985e, 0x516d, 0x622e, 0x9678, 0x4f96, 0x502b, 0x5d19, 0x6dea, 0x7db8, 0x8f2a, 0x5f8b, 0x6144, 0x6817, 0xf961, 0x9686,
+    0x52d2, 0x808b, 0x51dc, 0x51cc, 0x695e, 0x7a1c, 0x7dbe, 0x83f1, 0x9675, 0x4fda, 0x5229, 0x5398, 0x540f, 0x550e, 0x5c65, 0x60a7,
+    0x674e, 0x68a8, 0x6d6c, 0x7281, 0x72f8, 0x7406, 0x7483, 0xf962, 0x75e2, 0x7c6c, 0x7f79, 0x7fb8, 0x8389, 0x88cf, 0x88e1, 0x91cc,
+    0x91d0, 0x96e2, 0x9bc9, 0x541d, 0x6f7e, 0x71d0, 0x7498, 0x85fa, 0x8eaa, 0x96a3, 0x9c57, 0x9e9f, 0x6797, 0x6dcb, 0x7433, 0x81e8,
+    0x9716, 0x782c, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x7acb, 0x7b20, 0x7c92, 0x6469, 0x746a, 0x75f2, 0x78bc, 0x78e8, 0x99ac, 0x9b54, 0x9ebb, 0x5bde, 0x5e55, 0x6f20,
+    0x819c, 0x83ab, 0x9088, 0x4e07, 0x534d, 0x5a29, 0x5dd2, 0x5f4e, 0x6162, 0x633d, 0x6669, 0x66fc, 0x6eff, 0x6f2b, 0x7063, 0x779e,
+    0x842c, 0x8513, 0x883b, 0x8f13, 0x9945, 0x9c3b, 0x551c, 0x62b9, 0x672b, 0x6cab, 0x8309, 0x896a, 0x977a, 0x4ea1, 0x5984, 0x5fd8,
+    0x5fd9, 0x671b, 0x7db2, 0x7f54, 0x8292, 0x832b, 0x83bd, 0x8f1e, 0x9099, 0x57cb, 0x59b9, 0x5a92, 0x5bd0, 0x6627, 0x679a, 0x6885,
+    0x6bcf, 0x7164, 0x7f75, 0x8cb7, 0x8ce3, 0x9081, 0x9b45, 0x8108, 0x8c8a, 0x964c, 0x9a40, 0x9ea5, 0x5b5f, 0x6c13, 0x731b, 0x76f2,
+    0x76df, 0x840c, 0x51aa, 0x8993, 0x514d, 0x5195, 0x52c9, 0x68c9, 0x6c94, 0x7704, 0x7720, 0x7dbf, 0x7dec, 0x9762, 0x9eb5, 0x6ec5,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x8511, 0x51a5, 0x540d, 0x547d, 0x660e, 0x669d, 0x6927, 0x6e9f, 0x76bf, 0x7791, 0x8317, 0x84c2, 0x879f, 0x9169, 0x9298, 0x9cf4,
+    0x8882, 0x4fae, 0x5192, 0x52df, 0x59c6, 0x5e3d, 0x6155, 0x6478, 0x6479, 0x66ae, 0x67d0, 0x6a21, 0x6bcd, 0x6bdb, 0x725f, 0x7261,
+    0x7441, 0x7738, 0x77db, 0x8017, 0x82bc, 0x8305, 0x8b00, 0x8b28, 0x8c8c, 0x6728, 0x6c90, 0x7267, 0x76ee, 0x7766, 0x7a46, 0x9da9,
+    0x6b7f, 0x6c92, 0x5922, 0x6726, 0x8499, 0x536f, 0x5893, 0x5999, 0x5edf, 0x63cf, 0x6634, 0x6773, 0x6e3a, 0x732b, 0x7ad7, 0x82d7,
+    0x9328, 0x52d9, 0x5deb, 0x61ae, 0x61cb, 0x620a, 0x62c7, 0x64ab, 0x65e0, 0x6959, 0x6b66, 0x6bcb, 0x7121, 0x73f7, 0x755d, 0x7e46,
+    0x821e, 0x8302, 0x856a, 0x8aa3, 0x8cbf, 0x9727, 0x9d61, 0x58a8, 0x9ed8, 0x5011, 0x520e, 0x543b, 0x554f, 0x6587, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6c76, 0x7d0a,
+    0x7d0b, 0x805e, 0x868a, 0x9580, 0x96ef, 0x52ff, 0x6c95, 0x7269, 0x5473, 0x5a9a, 0x5c3e, 0x5d4b, 0x5f4c, 0x5fae, 0x672a, 0x68b6,
+    0x6963, 0x6e3c, 0x6e44, 0x7709, 0x7c73, 0x7f8e, 0x8587, 0x8b0e, 0x8ff7, 0x9761, 0x9ef4, 0x5cb7, 0x60b6, 0x610d, 0x61ab, 0x654f,
+    0x65fb, 0x65fc, 0x6c11, 0x6cef, 0x739f, 0x73c9, 0x7de1, 0x9594, 0x5bc6, 0x871c, 0x8b10, 0x525d, 0x535a, 0x62cd, 0x640f, 0x64b2,
+    0x6734, 0x6a38, 0x6cca, 0x73c0, 0x749e, 0x7b94, 0x7c95, 0x7e1b, 0x818a, 0x8236, 0x8584, 0x8feb, 0x96f9, 0x99c1, 0x4f34, 0x534a,
+    0x53cd, 0x53db, 0x62cc, 0x642c, 0x6500, 0x6591, 0x69c3, 0x6cee, 0x6f58, 0x73ed, 0x7554, 0x7622, 0x76e4, 0x76fc, 0x78d0, 0x78fb,
+    0x792c, 0x7d46, 0x822c, 0x87e0, 0x8fd4, 0x9812, 0x98ef, 0x52c3, 0x62d4, 0x64a5, 0x6e24, 0x6f51, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x767c, 0x8dcb, 0x91b1, 0x9262,
+    0x9aee, 0x9b43, 0x5023, 0x508d, 0x574a, 0x59a8, 0x5c28, 0x5e47, 0x5f77, 0x623f, 0x653e, 0x65b9, 0x65c1, 0x6609, 0x678b, 0x699c,
+    0x6ec2, 0x78c5, 0x7d21, 0x80aa, 0x8180, 0x822b, 0x82b3, 0x84a1, 0x868c, 0x8a2a, 0x8b17, 0x90a6, 0x9632, 0x9f90, 0x500d, 0x4ff3,
+    0xf963, 0x57f9, 0x5f98, 0x62dc, 0x6392, 0x676f, 0x6e43, 0x7119, 0x76c3, 0x80cc, 0x80da, 0x88f4, 0x88f5, 0x8919, 0x8ce0, 0x8f29,
+    0x914d, 0x966a, 0x4f2f, 0x4f70, 0x5e1b, 0x67cf, 0x6822, 0x767d, 0x767e, 0x9b44, 0x5e61, 0x6a0a, 0x7169, 0x71d4, 0x756a, 0xf964,
+    0x7e41, 0x8543, 0x85e9, 0x98dc, 0x4f10, 0x7b4f, 0x7f70, 0x95a5, 0x51e1, 0x5e06, 0x68b5, 0x6c3e, 0x6c4e, 0x6cdb, 0x72af, 0x7bc4,
+    0x8303, 0x6cd5, 0x743a, 0x50fb, 0x5288, 0x58c1, 0x64d8, 0x6a97, 0x74a7, 0x7656, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x78a7, 0x8617, 0x95e2, 0x9739, 0xf965, 0x535e,
+    0x5f01, 0x8b8a, 0x8fa8, 0x8faf, 0x908a, 0x5225, 0x77a5, 0x9c49, 0x9f08, 0x4e19, 0x5002, 0x5175, 0x5c5b, 0x5e77, 0x661e, 0x663a,
+    0x67c4, 0x68c5, 0x70b3, 0x7501, 0x75c5, 0x79c9, 0x7add, 0x8f27, 0x9920, 0x9a08, 0x4fdd, 0x5821, 0x5831, 0x5bf6, 0x666e, 0x6b65,
+    0x6d11, 0x6e7a, 0x6f7d, 0x73e4, 0x752b, 0x83e9, 0x88dc, 0x8913, 0x8b5c, 0x8f14, 0x4f0f, 0x50d5, 0x5310, 0x535c, 0x5b93, 0x5fa9,
+    0x670d, 0x798f, 0x8179, 0x832f, 0x8514, 0x8907, 0x8986, 0x8f39, 0x8f3b, 0x99a5, 0x9c12, 0x672c, 0x4e76, 0x4ff8, 0x5949, 0x5c01,
+    0x5cef, 0x5cf0, 0x6367, 0x68d2, 0x70fd, 0x71a2, 0x742b, 0x7e2b, 0x84ec, 0x8702, 0x9022, 0x92d2, 0x9cf3, 0x4e0d, 0x4ed8, 0x4fef,
+    0x5085, 0x5256, 0x526f, 0x5426, 0x5490, 0x57e0, 0x592b, 0x5a66, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5b5a, 0x5b75, 0x5bcc, 0x5e9c, 0xf966, 0x6276, 0x6577, 0x65a7,
+    0x6d6e, 0x6ea5, 0x7236, 0x7b26, 0x7c3f, 0x7f36, 0x8150, 0x8151, 0x819a, 0x8240, 0x8299, 0x83a9, 0x8a03, 0x8ca0, 0x8ce6, 0x8cfb,
+    0x8d74, 0x8dba, 0x90e8, 0x91dc, 0x961c, 0x9644, 0x99d9, 0x9ce7, 0x5317, 0x5206, 0x5429, 0x5674, 0x58b3, 0x5954, 0x596e, 0x5fff,
+    0x61a4, 0x626e, 0x6610, 0x6c7e, 0x711a, 0x76c6, 0x7c89, 0x7cde, 0x7d1b, 0x82ac, 0x8cc1, 0x96f0, 0xf967, 0x4f5b, 0x5f17, 0x5f7f,
+    0x62c2, 0x5d29, 0x670b, 0x68da, 0x787c, 0x7e43, 0x9d6c, 0x4e15, 0x5099, 0x5315, 0x532a, 0x5351, 0x5983, 0x5a62, 0x5e87, 0x60b2,
+    0x618a, 0x6249, 0x6279, 0x6590, 0x6787, 0x69a7, 0x6bd4, 0x6bd6, 0x6bd7, 0x6bd8, 0x6cb8, 0xf968, 0x7435, 0x75fa, 0x7812, 0x7891,
+    0x79d5, 0x79d8, 0x7c83, 0x7dcb, 0x7fe1, 0x80a5, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x813e, 0x81c2, 0x83f2, 0x871a, 0x88e8, 0x8ab9, 0x8b6c, 0x8cbb, 0x9119, 0x975e,
+    0x98db, 0x9f3b, 0x56ac, 0x5b2a, 0x5f6c, 0x658c, 0x6ab3, 0x6baf, 0x6d5c, 0x6ff1, 0x7015, 0x725d, 0x73ad, 0x8ca7, 0x8cd3, 0x983b,
+    0x6191, 0x6c37, 0x8058, 0x9a01, 0x4e4d, 0x4e8b, 0x4e9b, 0x4ed5, 0x4f3a, 0x4f3c, 0x4f7f, 0x4fdf, 0x50ff, 0x53f2, 0x53f8, 0x5506,
+    0x55e3, 0x56db, 0x58eb, 0x5962, 0x5a11, 0x5beb, 0x5bfa, 0x5c04, 0x5df3, 0x5e2b, 0x5f99, 0x601d, 0x6368, 0x659c, 0x65af, 0x67f6,
+    0x67fb, 0x68ad, 0x6b7b, 0x6c99, 0x6cd7, 0x6e23, 0x7009, 0x7345, 0x7802, 0x793e, 0x7940, 0x7960, 0x79c1, 0x7be9, 0x7d17, 0x7d72,
+    0x8086, 0x820d, 0x838e, 0x84d1, 0x86c7, 0x88df, 0x8a50, 0x8a5e, 0x8b1d, 0x8cdc, 0x8d66, 0x8fad, 0x90aa, 0x98fc, 0x99df, 0x9e9d,
+    0x524a, 0xf969, 0x6714, 0xf96a, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x5098, 0x522a, 0x5c71, 0x6563, 0x6c55, 0x73ca, 0x7523, 0x759d, 0x7b97, 0x849c, 0x9178, 0x9730,
+    0x4e77, 0x6492, 0x6bba, 0x715e, 0x85a9, 0x4e09, 0xf96b, 0x6749, 0x68ee, 0x6e17, 0x829f, 0x8518, 0x886b, 0x63f7, 0x6f81, 0x9212,
+    0x98af, 0x4e0a, 0x50b7, 0x50cf, 0x511f, 0x5546, 0x55aa, 0x5617, 0x5b40, 0x5c19, 0x5ce0, 0x5e38, 0x5e8a, 0x5ea0, 0x5ec2, 0x60f3,
+    0x6851, 0x6a61, 0x6e58, 0x723d, 0x7240, 0x72c0, 0x76f8, 0x7965, 0x7bb1, 0x7fd4, 0x88f3, 0x89f4, 0x8a73, 0x8c61, 0x8cde, 0x971c,
+    0x585e, 0x74bd, 0x8cfd, 0x55c7, 0xf96c, 0x7a61, 0x7d22, 0x8272, 0x7272, 0x751f, 0x7525, 0xf96d, 0x7b19, 0x5885, 0x58fb, 0x5dbc,
+    0x5e8f, 0x5eb6, 0x5f90, 0x6055, 0x6292, 0x637f, 0x654d, 0x6691, 0x66d9, 0x66f8, 0x6816, 0x68f2, 0x7280, 0x745e, 0x7b6e, 0x7d6e,
+    0x7dd6, 0x7f72, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x80e5, 0x8212, 0x85af, 0x897f, 0x8a93, 0x901d, 0x92e4, 0x9ecd, 0x9f20, 0x5915, 0x596d, 0x5e2d, 0x60dc, 0x6614,
+    0x6673, 0x6790, 0x6c50, 0x6dc5, 0x6f5f, 0x77f3, 0x78a9, 0x84c6, 0x91cb, 0x932b, 0x4ed9, 0x50ca, 0x5148, 0x5584, 0x5b0b, 0x5ba3,
+    0x6247, 0x657e, 0x65cb, 0x6e32, 0x717d, 0x7401, 0x7444, 0x7487, 0x74bf, 0x766c, 0x79aa, 0x7dda, 0x7e55, 0x7fa8, 0x817a, 0x81b3,
+    0x8239, 0x861a, 0x87ec, 0x8a75, 0x8de3, 0x9078, 0x9291, 0x9425, 0x994d, 0x9bae, 0x5368, 0x5c51, 0x6954, 0x6cc4, 0x6d29, 0x6e2b,
+    0x820c, 0x859b, 0x893b, 0x8a2d, 0x8aaa, 0x96ea, 0x9f67, 0x5261, 0x66b9, 0x6bb2, 0x7e96, 0x87fe, 0x8d0d, 0x9583, 0x965d, 0x651d,
+    0x6d89, 0x71ee, 0xf96e, 0x57ce, 0x59d3, 0x5bac, 0x6027, 0x60fa, 0x6210, 0x661f, 0x665f, 0x7329, 0x73f9, 0x76db, 0x7701, 0x7b6c,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x8056, 0x8072, 0x8165, 0x8aa0, 0x9192, 0x4e16, 0x52e2, 0x6b72, 0x6d17, 0x7a05, 0x7b39, 0x7d30, 0xf96f, 0x8cb0, 0x53ec, 0x562f,
+    0x5851, 0x5bb5, 0x5c0f, 0x5c11, 0x5de2, 0x6240, 0x6383, 0x6414, 0x662d, 0x68b3, 0x6cbc, 0x6d88, 0x6eaf, 0x701f, 0x70a4, 0x71d2,
+    0x7526, 0x758f, 0x758e, 0x7619, 0x7b11, 0x7be0, 0x7c2b, 0x7d20, 0x7d39, 0x852c, 0x856d, 0x8607, 0x8a34, 0x900d, 0x9061, 0x90b5,
+    0x92b7, 0x97f6, 0x9a37, 0x4fd7, 0x5c6c, 0x675f, 0x6d91, 0x7c9f, 0x7e8c, 0x8b16, 0x8d16, 0x901f, 0x5b6b, 0x5dfd, 0x640d, 0x84c0,
+    0x905c, 0x98e1, 0x7387, 0x5b8b, 0x609a, 0x677e, 0x6dde, 0x8a1f, 0x8aa6, 0x9001, 0x980c, 0x5237, 0xf970, 0x7051, 0x788e, 0x9396,
+    0x8870, 0x91d7, 0x4fee, 0x53d7, 0x55fd, 0x56da, 0x5782, 0x58fd, 0x5ac2, 0x5b88, 0x5cab, 0x5cc0, 0x5e25, 0x6101, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x620d, 0x624b,
+    0x6388, 0x641c, 0x6536, 0x6578, 0x6a39, 0x6b8a, 0x6c34, 0x6d19, 0x6f31, 0x71e7, 0x72e9, 0x7378, 0x7407, 0x74b2, 0x7626, 0x7761,
+    0x79c0, 0x7a57, 0x7aea, 0x7cb9, 0x7d8f, 0x7dac, 0x7e61, 0x7f9e, 0x8129, 0x8331, 0x8490, 0x84da, 0x85ea, 0x8896, 0x8ab0, 0x8b90,
+    0x8f38, 0x9042, 0x9083, 0x916c, 0x9296, 0x92b9, 0x968b, 0x96a7, 0x96a8, 0x96d6, 0x9700, 0x9808, 0x9996, 0x9ad3, 0x9b1a, 0x53d4,
+    0x587e, 0x5919, 0x5b70, 0x5bbf, 0x6dd1, 0x6f5a, 0x719f, 0x7421, 0x74b9, 0x8085, 0x83fd, 0x5de1, 0x5f87, 0x5faa, 0x6042, 0x65ec,
+    0x6812, 0x696f, 0x6a53, 0x6b89, 0x6d35, 0x6df3, 0x73e3, 0x76fe, 0x77ac, 0x7b4d, 0x7d14, 0x8123, 0x821c, 0x8340, 0x84f4, 0x8563,
+    0x8a62, 0x8ac4, 0x9187, 0x931e, 0x9806, 0x99b4, 0x620c, 0x8853, 0x8ff0, 0x9265, 0x5d07, 0x5d27, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5d69, 0x745f, 0x819d, 0x8768,
+    0x6fd5, 0x62fe, 0x7fd2, 0x8936, 0x8972, 0x4e1e, 0x4e58, 0x50e7, 0x52dd, 0x5347, 0x627f, 0x6607, 0x7e69, 0x8805, 0x965e, 0x4f8d,
+    0x5319, 0x5636, 0x59cb, 0x5aa4, 0x5c38, 0x5c4e, 0x5c4d, 0x5e02, 0x5f11, 0x6043, 0x65bd, 0x662f, 0x6642, 0x67be, 0x67f4, 0x731c,
+    0x77e2, 0x793a, 0x7fc5, 0x8494, 0x84cd, 0x8996, 0x8a66, 0x8a69, 0x8ae1, 0x8c55, 0x8c7a, 0x57f4, 0x5bd4, 0x5f0f, 0x606f, 0x62ed,
+    0x690d, 0x6b96, 0x6e5c, 0x7184, 0x7bd2, 0x8755, 0x8b58, 0x8efe, 0x98df, 0x98fe, 0x4f38, 0x4f81, 0x4fe1, 0x547b, 0x5a20, 0x5bb8,
+    0x613c, 0x65b0, 0x6668, 0x71fc, 0x7533, 0x795e, 0x7d33, 0x814e, 0x81e3, 0x8398, 0x85aa, 0x85ce, 0x8703, 0x8a0a, 0x8eab, 0x8f9b,
+    0xf971, 0x8fc5, 0x5931, 0x5ba4, 0x5be6, 0x6089, 0x5be9, 0x5c0b, 0x5fc3, 0x6c81, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf972, 0x6df1, 0x700b, 0x751a, 0x82af, 0x8af6,
+    0x4ec0, 0x5341, 0xf973, 0x96d9, 0x6c0f, 0x4e9e, 0x4fc4, 0x5152, 0x555e, 0x5a25, 0x5ce8, 0x6211, 0x7259, 0x82bd, 0x83aa, 0x86fe,
+    0x8859, 0x8a1d, 0x963f, 0x96c5, 0x9913, 0x9d09, 0x9d5d, 0x580a, 0x5cb3, 0x5dbd, 0x5e44, 0x60e1, 0x6115, 0x63e1, 0x6a02, 0x6e25,
+    0x9102, 0x9354, 0x984e, 0x9c10, 0x9f77, 0x5b89, 0x5cb8, 0x6309, 0x664f, 0x6848, 0x773c, 0x96c1, 0x978d, 0x9854, 0x9b9f, 0x65a1,
+    0x8b01, 0x8ecb, 0x95bc, 0x5535, 0x5ca9, 0x5dd6, 0x5eb5, 0x6697, 0x764c, 0x83f4, 0x95c7, 0x58d3, 0x62bc, 0x72ce, 0x9d28, 0x4ef0,
+    0x592e, 0x600f, 0x663b, 0x6b83, 0x79e7, 0x9d26, 0x5393, 0x54c0, 0x57c3, 0x5d16, 0x611b, 0x66d6, 0x6daf, 0x788d, 0x827e, 0x9698,
+    0x9744, 0x5384, 0x627c, 0x6396, 0x6db2, 0x7e0a, 0x814b, 0x984d, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6afb, 0x7f4c, 0x9daf, 0x9e1a, 0x4e5f, 0x503b, 0x51b6, 0x591c,
+    0x60f9, 0x63f6, 0x6930, 0x723a, 0x8036, 0xf974, 0x91ce, 0x5f31, 0xf975, 0xf976, 0x7d04, 0x82e5, 0x846f, 0x84bb, 0x85e5, 0x8e8d,
+    0xf977, 0x4f6f, 0xf978, 0xf979, 0x58e4, 0x5b43, 0x6059, 0x63da, 0x6518, 0x656d, 0x6698, 0xf97a, 0x694a, 0x6a23, 0x6d0b, 0x7001,
+    0x716c, 0x75d2, 0x760d, 0x79b3, 0x7a70, 0xf97b, 0x7f8a, 0xf97c, 0x8944, 0xf97d, 0x8b93, 0x91c0, 0x967d, 0xf97e, 0x990a, 0x5704,
+    0x5fa1, 0x65bc, 0x6f01, 0x7600, 0x79a6, 0x8a9e, 0x99ad, 0x9b5a, 0x9f6c, 0x5104, 0x61b6, 0x6291, 0x6a8d, 0x81c6, 0x5043, 0x5830,
+    0x5f66, 0x7109, 0x8a00, 0x8afa, 0x5b7c, 0x8616, 0x4ffa, 0x513c, 0x56b4, 0x5944, 0x63a9, 0x6df9, 0x5daa, 0x696d, 0x5186, 0x4e88,
+    0x4f59, 0xf97f, 0xf980, 0xf981, 0x5982, 0xf982, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf983, 0x6b5f, 0x6c5d, 0xf984, 0x74b5, 0x7916, 0xf985, 0x8207, 0x8245, 0x8339,
+    0x8f3f, 0x8f5d, 0xf986, 0x9918, 0xf987, 0xf988, 0xf989, 0x4ea6, 0xf98a, 0x57df, 0x5f79, 0x6613, 0xf98b, 0xf98c, 0x75ab, 0x7e79,
+    0x8b6f, 0xf98d, 0x9006, 0x9a5b, 0x56a5, 0x5827, 0x59f8, 0x5a1f, 0x5bb4, 0xf98e, 0x5ef6, 0xf98f, 0xf990, 0x6350, 0x633b, 0xf991,
+    0x693d, 0x6c87, 0x6cbf, 0x6d8e, 0x6d93, 0x6df5, 0x6f14, 0xf992, 0x70df, 0x7136, 0x7159, 0xf993, 0x71c3, 0x71d5, 0xf994, 0x784f,
+    0x786f, 0xf995, 0x7b75, 0x7de3, 0xf996, 0x7e2f, 0xf997, 0x884d, 0x8edf, 0xf998, 0xf999, 0xf99a, 0x925b, 0xf99b, 0x9cf6, 0xf99c,
+    0xf99d, 0xf99e, 0x6085, 0x6d85, 0xf99f, 0x71b1, 0xf9a0, 0xf9a1, 0x95b1, 0x53ad, 0xf9a2, 0xf9a3, 0xf9a4, 0x67d3, 0xf9a5, 0x708e,
+    0x7130, 0x7430, 0x8276, 0x82d2, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xf9a6, 0x95bb, 0x9ae5, 0x9e7d, 0x66c4, 0xf9a7, 0x71c1, 0x8449, 0xf9a8, 0xf9a9, 0x584b, 0xf9aa,
+    0xf9ab, 0x5db8, 0x5f71, 0xf9ac, 0x6620, 0x668e, 0x6979, 0x69ae, 0x6c38, 0x6cf3, 0x6e36, 0x6f41, 0x6fda, 0x701b, 0x702f, 0x7150,
+    0x71df, 0x7370, 0xf9ad, 0x745b, 0xf9ae, 0x74d4, 0x76c8, 0x7a4e, 0x7e93, 0xf9af, 0xf9b0, 0x82f1, 0x8a60, 0x8fce, 0xf9b1, 0x9348,
+    0xf9b2, 0x9719, 0xf9b3, 0xf9b4, 0x4e42, 0x502a, 0xf9b5, 0x5208, 0x53e1, 0x66f3, 0x6c6d, 0x6fca, 0x730a, 0x777f, 0x7a62, 0x82ae,
+    0x85dd, 0x8602, 0xf9b6, 0x88d4, 0x8a63, 0x8b7d, 0x8c6b, 0xf9b7, 0x92b3, 0xf9b8, 0x9713, 0x9810, 0x4e94, 0x4f0d, 0x4fc9, 0x50b2,
+    0x5348, 0x543e, 0x5433, 0x55da, 0x5862, 0x58ba, 0x5967, 0x5a1b, 0x5be4, 0x609f, 0xf9b9, 0x61ca, 0x6556, 0x65ff, 0x6664, 0x68a7,
+    0x6c5a, 0x6fb3, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x70cf, 0x71ac, 0x7352, 0x7b7d, 0x8708, 0x8aa4, 0x9c32, 0x9f07, 0x5c4b, 0x6c83, 0x7344, 0x7389, 0x923a, 0x6eab,
+    0x7465, 0x761f, 0x7a69, 0x7e15, 0x860a, 0x5140, 0x58c5, 0x64c1, 0x74ee, 0x7515, 0x7670, 0x7fc1, 0x9095, 0x96cd, 0x9954, 0x6e26,
+    0x74e6, 0x7aa9, 0x7aaa, 0x81e5, 0x86d9, 0x8778, 0x8a1b, 0x5a49, 0x5b8c, 0x5b9b, 0x68a1, 0x6900, 0x6d63, 0x73a9, 0x7413, 0x742c,
+    0x7897, 0x7de9, 0x7feb, 0x8118, 0x8155, 0x839e, 0x8c4c, 0x962e, 0x9811, 0x66f0, 0x5f80, 0x65fa, 0x6789, 0x6c6a, 0x738b, 0x502d,
+    0x5a03, 0x6b6a, 0x77ee, 0x5916, 0x5d6c, 0x5dcd, 0x7325, 0x754f, 0xf9ba, 0xf9bb, 0x50e5, 0x51f9, 0x582f, 0x592d, 0x5996, 0x59da,
+    0x5be5, 0xf9bc, 0xf9bd, 0x5da2, 0x62d7, 0x6416, 0x6493, 0x64fe, 0xf9be, 0x66dc, 0xf9bf, 0x6a48, 0xf9c0, 0x71ff, 0x7464, 0xf9c1,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x7a88, 0x7aaf, 0x7e47, 0x7e5e, 0x8000, 0x8170, 0xf9c2, 0x87ef, 0x8981, 0x8b20, 0x9059, 0xf9c3, 0x9080, 0x9952, 0x617e, 0x6b32,
+    0x6d74, 0x7e1f, 0x8925, 0x8fb1, 0x4fd1, 0x50ad, 0x5197, 0x52c7, 0x57c7, 0x5889, 0x5bb9, 0x5eb8, 0x6142, 0x6995, 0x6d8c, 0x6e67,
+    0x6eb6, 0x7194, 0x7462, 0x7528, 0x752c, 0x8073, 0x8338, 0x84c9, 0x8e0a, 0x9394, 0x93de, 0xf9c4, 0x4e8e, 0x4f51, 0x5076, 0x512a,
+    0x53c8, 0x53cb, 0x53f3, 0x5b87, 0x5bd3, 0x5c24, 0x611a, 0x6182, 0x65f4, 0x725b, 0x7397, 0x7440, 0x76c2, 0x7950, 0x7991, 0x79b9,
+    0x7d06, 0x7fbd, 0x828b, 0x85d5, 0x865e, 0x8fc2, 0x9047, 0x90f5, 0x91ea, 0x9685, 0x96e8, 0x96e9, 0x52d6, 0x5f67, 0x65ed, 0x6631,
+    0x682f, 0x715c, 0x7a36, 0x90c1, 0x980a, 0x4e91, 0xf9c5, 0x6a52, 0x6b9e, 0x6f90, 0x7189, 0x8018, 0x82b8, 0x8553, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x904b, 0x9695,
+    0x96f2, 0x97fb, 0x851a, 0x9b31, 0x4e90, 0x718a, 0x96c4, 0x5143, 0x539f, 0x54e1, 0x5713, 0x5712, 0x57a3, 0x5a9b, 0x5ac4, 0x5bc3,
+    0x6028, 0x613f, 0x63f4, 0x6c85, 0x6d39, 0x6e72, 0x6e90, 0x7230, 0x733f, 0x7457, 0x82d1, 0x8881, 0x8f45, 0x9060, 0xf9c6, 0x9662,
+    0x9858, 0x9d1b, 0x6708, 0x8d8a, 0x925e, 0x4f4d, 0x5049, 0x50de, 0x5371, 0x570d, 0x59d4, 0x5a01, 0x5c09, 0x6170, 0x6690, 0x6e2d,
+    0x7232, 0x744b, 0x7def, 0x80c3, 0x840e, 0x8466, 0x853f, 0x875f, 0x885b, 0x8918, 0x8b02, 0x9055, 0x97cb, 0x9b4f, 0x4e73, 0x4f91,
+    0x5112, 0x516a, 0xf9c7, 0x552f, 0x55a9, 0x5b7a, 0x5ba5, 0x5e7c, 0x5e7d, 0x5ebe, 0x60a0, 0x60df, 0x6108, 0x6109, 0x63c4, 0x6538,
+    0x6709, 0xf9c8, 0x67d4, 0x67da, 0xf9c9, 0x6961, 0x6962, 0x6cb9, 0x6d27, 0xf9ca, 0x6e38, 0xf9cb, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6fe1, 0x7336, 0x7337, 0xf9cc,
+    0x745c, 0x7531, 0xf9cd, 0x7652, 0xf9ce, 0xf9cf, 0x7dad, 0x81fe, 0x8438, 0x88d5, 0x8a98, 0x8adb, 0x8aed, 0x8e30, 0x8e42, 0x904a,
+    0x903e, 0x907a, 0x9149, 0x91c9, 0x936e, 0xf9d0, 0xf9d1, 0x5809, 0xf9d2, 0x6bd3, 0x8089, 0x80b2, 0xf9d3, 0xf9d4, 0x5141, 0x596b,
+    0x5c39, 0xf9d5, 0xf9d6, 0x6f64, 0x73a7, 0x80e4, 0x8d07, 0xf9d7, 0x9217, 0x958f, 0xf9d8, 0xf9d9, 0xf9da, 0xf9db, 0x807f, 0x620e,
+    0x701c, 0x7d68, 0x878d, 0xf9dc, 0x57a0, 0x6069, 0x6147, 0x6bb7, 0x8abe, 0x9280, 0x96b1, 0x4e59, 0x541f, 0x6deb, 0x852d, 0x9670,
+    0x97f3, 0x98ee, 0x63d6, 0x6ce3, 0x9091, 0x51dd, 0x61c9, 0x81ba, 0x9df9, 0x4f9d, 0x501a, 0x5100, 0x5b9c, 0x610f, 0x61ff, 0x64ec,
+    0x6905, 0x6bc5, 0x7591, 0x77e3, 0x7fa9, 0x8264, 0x858f, 0x87fb, 0x8863, 0x8abc, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8b70, 0x91ab, 0x4e8c, 0x4ee5, 0x4f0a, 0xf9dd,
+    0xf9de, 0x5937, 0x59e8, 0xf9df, 0x5df2, 0x5f1b, 0x5f5b, 0x6021, 0xf9e0, 0xf9e1, 0xf9e2, 0xf9e3, 0x723e, 0x73e5, 0xf9e4, 0x7570,
+    0x75cd, 0xf9e5, 0x79fb, 0xf9e6, 0x800c, 0x8033, 0x8084, 0x82e1, 0x8351, 0xf9e7, 0xf9e8, 0x8cbd, 0x8cb3, 0x9087, 0xf9e9, 0xf9ea,
+    0x98f4, 0x990c, 0xf9eb, 0xf9ec, 0x7037, 0x76ca, 0x7fca, 0x7fcc, 0x7ffc, 0x8b1a, 0x4eba, 0x4ec1, 0x5203, 0x5370, 0xf9ed, 0x54bd,
+    0x56e0, 0x59fb, 0x5bc5, 0x5f15, 0x5fcd, 0x6e6e, 0xf9ee, 0xf9ef, 0x7d6a, 0x8335, 0xf9f0, 0x8693, 0x8a8d, 0xf9f1, 0x976d, 0x9777,
+    0xf9f2, 0xf9f3, 0x4e00, 0x4f5a, 0x4f7e, 0x58f9, 0x65e5, 0x6ea2, 0x9038, 0x93b0, 0x99b9, 0x4efb, 0x58ec, 0x598a, 0x59d9, 0x6041,
+    0xf9f4, 0xf9f5, 0x7a14, 0xf9f6, 0x834f, 0x8cc3, 0x5165, 0x5344, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xf9f7, 0xf9f8, 0xf9f9, 0x4ecd, 0x5269, 0x5b55, 0x82bf, 0x4ed4,
+    0x523a, 0x54a8, 0x59c9, 0x59ff, 0x5b50, 0x5b57, 0x5b5c, 0x6063, 0x6148, 0x6ecb, 0x7099, 0x716e, 0x7386, 0x74f7, 0x75b5, 0x78c1,
+    0x7d2b, 0x8005, 0x81ea, 0x8328, 0x8517, 0x85c9, 0x8aee, 0x8cc7, 0x96cc, 0x4f5c, 0x52fa, 0x56bc, 0x65ab, 0x6628, 0x707c, 0x70b8,
+    0x7235, 0x7dbd, 0x828d, 0x914c, 0x96c0, 0x9d72, 0x5b71, 0x68e7, 0x6b98, 0x6f7a, 0x76de, 0x5c91, 0x66ab, 0x6f5b, 0x7bb4, 0x7c2a,
+    0x8836, 0x96dc, 0x4e08, 0x4ed7, 0x5320, 0x5834, 0x58bb, 0x58ef, 0x596c, 0x5c07, 0x5e33, 0x5e84, 0x5f35, 0x638c, 0x66b2, 0x6756,
+    0x6a1f, 0x6aa3, 0x6b0c, 0x6f3f, 0x7246, 0xf9fa, 0x7350, 0x748b, 0x7ae0, 0x7ca7, 0x8178, 0x81df, 0x81e7, 0x838a, 0x846c, 0x8523,
+    0x8594, 0x85cf, 0x88dd, 0x8d13, 0x91ac, 0x9577, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x969c, 0x518d, 0x54c9, 0x5728, 0x5bb0, 0x624d, 0x6750, 0x683d, 0x6893, 0x6e3d,
+    0x6ed3, 0x707d, 0x7e21, 0x88c1, 0x8ca1, 0x8f09, 0x9f4b, 0x9f4e, 0x722d, 0x7b8f, 0x8acd, 0x931a, 0x4f47, 0x4f4e, 0x5132, 0x5480,
+    0x59d0, 0x5e95, 0x62b5, 0x6775, 0x696e, 0x6a17, 0x6cae, 0x6e1a, 0x72d9, 0x732a, 0x75bd, 0x7bb8, 0x7d35, 0x82e7, 0x83f9, 0x8457,
+    0x85f7, 0x8a5b, 0x8caf, 0x8e87, 0x9019, 0x90b8, 0x96ce, 0x9f5f, 0x52e3, 0x540a, 0x5ae1, 0x5bc2, 0x6458, 0x6575, 0x6ef4, 0x72c4,
+    0xf9fb, 0x7684, 0x7a4d, 0x7b1b, 0x7c4d, 0x7e3e, 0x7fdf, 0x837b, 0x8b2b, 0x8cca, 0x8d64, 0x8de1, 0x8e5f, 0x8fea, 0x8ff9, 0x9069,
+    0x93d1, 0x4f43, 0x4f7a, 0x50b3, 0x5168, 0x5178, 0x524d, 0x526a, 0x5861, 0x587c, 0x5960, 0x5c08, 0x5c55, 0x5edb, 0x609b, 0x6230,
+    0x6813, 0x6bbf, 0x6c08, 0x6fb1, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x714e, 0x7420, 0x7530, 0x7538, 0x7551, 0x7672, 0x7b4c, 0x7b8b, 0x7bad, 0x7bc6, 0x7e8f, 0x8a6e,
+    0x8f3e, 0x8f49, 0x923f, 0x9293, 0x9322, 0x942b, 0x96fb, 0x985a, 0x986b, 0x991e, 0x5207, 0x622a, 0x6298, 0x6d59, 0x7664, 0x7aca,
+    0x7bc0, 0x7d76, 0x5360, 0x5cbe, 0x5e97, 0x6f38, 0x70b9, 0x7c98, 0x9711, 0x9b8e, 0x9ede, 0x63a5, 0x647a, 0x8776, 0x4e01, 0x4e95,
+    0x4ead, 0x505c, 0x5075, 0x5448, 0x59c3, 0x5b9a, 0x5e40, 0x5ead, 0x5ef7, 0x5f81, 0x60c5, 0x633a, 0x653f, 0x6574, 0x65cc, 0x6676,
+    0x6678, 0x67fe, 0x6968, 0x6a89, 0x6b63, 0x6c40, 0x6dc0, 0x6de8, 0x6e1f, 0x6e5e, 0x701e, 0x70a1, 0x738e, 0x73fd, 0x753a, 0x775b,
+    0x7887, 0x798e, 0x7a0b, 0x7a7d, 0x7cbe, 0x7d8e, 0x8247, 0x8a02, 0x8aea, 0x8c9e, 0x912d, 0x914a, 0x91d8, 0x9266, 0x92cc, 0x9320,
+    0x9706, 0x9756, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x975c, 0x9802, 0x9f0e, 0x5236, 0x5291, 0x557c, 0x5824, 0x5e1d, 0x5f1f, 0x608c, 0x63d0, 0x68af, 0x6fdf, 0x796d,
+    0x7b2c, 0x81cd, 0x85ba, 0x88fd, 0x8af8, 0x8e44, 0x918d, 0x9664, 0x969b, 0x973d, 0x984c, 0x9f4a, 0x4fce, 0x5146, 0x51cb, 0x52a9,
+    0x5632, 0x5f14, 0x5f6b, 0x63aa, 0x64cd, 0x65e9, 0x6641, 0x66fa, 0x66f9, 0x671d, 0x689d, 0x68d7, 0x69fd, 0x6f15, 0x6f6e, 0x7167,
+    0x71e5, 0x722a, 0x74aa, 0x773a, 0x7956, 0x795a, 0x79df, 0x7a20, 0x7a95, 0x7c97, 0x7cdf, 0x7d44, 0x7e70, 0x8087, 0x85fb, 0x86a4,
+    0x8a54, 0x8abf, 0x8d99, 0x8e81, 0x9020, 0x906d, 0x91e3, 0x963b, 0x96d5, 0x9ce5, 0x65cf, 0x7c07, 0x8db3, 0x93c3, 0x5b58, 0x5c0a,
+    0x5352, 0x62d9, 0x731d, 0x5027, 0x5b97, 0x5f9e, 0x60b0, 0x616b, 0x68d5, 0x6dd9, 0x742e, 0x7a2e, 0x7d42, 0x7d9c, 0x7e31, 0x816b,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x8e2a, 0x8e35, 0x937e, 0x9418, 0x4f50, 0x5750, 0x5de6, 0x5ea7, 0x632b, 0x7f6a, 0x4e3b, 0x4f4f, 0x4f8f, 0x505a, 0x59dd, 0x80c4,
+    0x546a, 0x5468, 0x55fe, 0x594f, 0x5b99, 0x5dde, 0x5eda, 0x665d, 0x6731, 0x67f1, 0x682a, 0x6ce8, 0x6d32, 0x6e4a, 0x6f8d, 0x70b7,
+    0x73e0, 0x7587, 0x7c4c, 0x7d02, 0x7d2c, 0x7da2, 0x821f, 0x86db, 0x8a3b, 0x8a85, 0x8d70, 0x8e8a, 0x8f33, 0x9031, 0x914e, 0x9152,
+    0x9444, 0x99d0, 0x7af9, 0x7ca5, 0x4fca, 0x5101, 0x51c6, 0x57c8, 0x5bef, 0x5cfb, 0x6659, 0x6a3d, 0x6d5a, 0x6e96, 0x6fec, 0x710c,
+    0x756f, 0x7ae3, 0x8822, 0x9021, 0x9075, 0x96cb, 0x99ff, 0x8301, 0x4e2d, 0x4ef2, 0x8846, 0x91cd, 0x537d, 0x6adb, 0x696b, 0x6c41,
+    0x847a, 0x589e, 0x618e, 0x66fe, 0x62ef, 0x70dd, 0x7511, 0x75c7, 0x7e52, 0x84b8, 0x8b49, 0x8d08, 0x4e4b, 0x53ea, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x54ab, 0x5730,
+    0x5740, 0x5fd7, 0x6301, 0x6307, 0x646f, 0x652f, 0x65e8, 0x667a, 0x679d, 0x67b3, 0x6b62, 0x6c60, 0x6c9a, 0x6f2c, 0x77e5, 0x7825,
+    0x7949, 0x7957, 0x7d19, 0x80a2, 0x8102, 0x81f3, 0x829d, 0x82b7, 0x8718, 0x8a8c, 0xf9fc, 0x8d04, 0x8dbe, 0x9072, 0x76f4, 0x7a19,
+    0x7a37, 0x7e54, 0x8077, 0x5507, 0x55d4, 0x5875, 0x632f, 0x6422, 0x6649, 0x664b, 0x686d, 0x699b, 0x6b84, 0x6d25, 0x6eb1, 0x73cd,
+    0x7468, 0x74a1, 0x755b, 0x75b9, 0x76e1, 0x771e, 0x778b, 0x79e6, 0x7e09, 0x7e1d, 0x81fb, 0x852f, 0x8897, 0x8a3a, 0x8cd1, 0x8eeb,
+    0x8fb0, 0x9032, 0x93ad, 0x9663, 0x9673, 0x9707, 0x4f84, 0x53f1, 0x59ea, 0x5ac9, 0x5e19, 0x684e, 0x74c6, 0x75be, 0x79e9, 0x7a92,
+    0x81a3, 0x86ed, 0x8cea, 0x8dcc, 0x8fed, 0x659f, 0x6715, 0xf9fd, 0x57f7, 0x6f57, 0x7ddd, 0x8f2f, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x93f6, 0x96c6, 0x5fb5, 0x61f2,
+    0x6f84, 0x4e14, 0x4f98, 0x501f, 0x53c9, 0x55df, 0x5d6f, 0x5dee, 0x6b21, 0x6b64, 0x78cb, 0x7b9a, 0xf9fe, 0x8e49, 0x8eca, 0x906e,
+    0x6349, 0x643e, 0x7740, 0x7a84, 0x932f, 0x947f, 0x9f6a, 0x64b0, 0x6faf, 0x71e6, 0x74a8, 0x74da, 0x7ac4, 0x7c12, 0x7e82, 0x7cb2,
+    0x7e98, 0x8b9a, 0x8d0a, 0x947d, 0x9910, 0x994c, 0x5239, 0x5bdf, 0x64e6, 0x672d, 0x7d2e, 0x50ed, 0x53c3, 0x5879, 0x6158, 0x6159,
+    0x61fa, 0x65ac, 0x7ad9, 0x8b92, 0x8b96, 0x5009, 0x5021, 0x5275, 0x5531, 0x5a3c, 0x5ee0, 0x5f70, 0x6134, 0x655e, 0x660c, 0x6636,
+    0x66a2, 0x69cd, 0x6ec4, 0x6f32, 0x7316, 0x7621, 0x7a93, 0x8139, 0x8259, 0x83d6, 0x84bc, 0x50b5, 0x57f0, 0x5bc0, 0x5be8, 0x5f69,
+    0x63a1, 0x7826, 0x7db5, 0x83dc, 0x8521, 0x91c7, 0x91f5, 0x518a, 0x67f5, 0x7b56, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8cac, 0x51c4, 0x59bb, 0x60bd, 0x8655, 0x501c,
+    0xf9ff, 0x5254, 0x5c3a, 0x617d, 0x621a, 0x62d3, 0x64f2, 0x65a5, 0x6ecc, 0x7620, 0x810a, 0x8e60, 0x965f, 0x96bb, 0x4edf, 0x5343,
+    0x5598, 0x5929, 0x5ddd, 0x64c5, 0x6cc9, 0x6dfa, 0x7394, 0x7a7f, 0x821b, 0x85a6, 0x8ce4, 0x8e10, 0x9077, 0x91e7, 0x95e1, 0x9621,
+    0x97c6, 0x51f8, 0x54f2, 0x5586, 0x5fb9, 0x64a4, 0x6f88, 0x7db4, 0x8f1f, 0x8f4d, 0x9435, 0x50c9, 0x5c16, 0x6cbe, 0x6dfb, 0x751b,
+    0x77bb, 0x7c3d, 0x7c64, 0x8a79, 0x8ac2, 0x581e, 0x59be, 0x5e16, 0x6377, 0x7252, 0x758a, 0x776b, 0x8adc, 0x8cbc, 0x8f12, 0x5ef3,
+    0x6674, 0x6df8, 0x807d, 0x83c1, 0x8acb, 0x9751, 0x9bd6, 0xfa00, 0x5243, 0x66ff, 0x6d95, 0x6eef, 0x7de0, 0x8ae6, 0x902e, 0x905e,
+    0x9ad4, 0x521d, 0x527f, 0x54e8, 0x6194, 0x6284, 0x62db, 0x68a2, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6912, 0x695a, 0x6a35, 0x7092, 0x7126, 0x785d, 0x7901, 0x790e,
+    0x79d2, 0x7a0d, 0x8096, 0x8278, 0x82d5, 0x8349, 0x8549, 0x8c82, 0x8d85, 0x9162, 0x918b, 0x91ae, 0x4fc3, 0x56d1, 0x71ed, 0x77d7,
+    0x8700, 0x89f8, 0x5bf8, 0x5fd6, 0x6751, 0x90a8, 0x53e2, 0x585a, 0x5bf5, 0x60a4, 0x6181, 0x6460, 0x7e3d, 0x8070, 0x8525, 0x9283,
+    0x64ae, 0x50ac, 0x5d14, 0x6700, 0x589c, 0x62bd, 0x63a8, 0x690e, 0x6978, 0x6a1e, 0x6e6b, 0x76ba, 0x79cb, 0x82bb, 0x8429, 0x8acf,
+    0x8da8, 0x8ffd, 0x9112, 0x914b, 0x919c, 0x9310, 0x9318, 0x939a, 0x96db, 0x9a36, 0x9c0d, 0x4e11, 0x755c, 0x795d, 0x7afa, 0x7b51,
+    0x7bc9, 0x7e2e, 0x84c4, 0x8e59, 0x8e74, 0x8ef8, 0x9010, 0x6625, 0x693f, 0x7443, 0x51fa, 0x672e, 0x9edc, 0x5145, 0x5fe0, 0x6c96,
+    0x87f2, 0x885d, 0x8877, 0x60b4, 0x81b5, 0x8403, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8d05, 0x53d6, 0x5439, 0x5634, 0x5a36, 0x5c31, 0x708a, 0x7fe0, 0x805a, 0x8106,
+    0x81ed, 0x8da3, 0x9189, 0x9a5f, 0x9df2, 0x5074, 0x4ec4, 0x53a0, 0x60fb, 0x6e2c, 0x5c64, 0x4f88, 0x5024, 0x55e4, 0x5cd9, 0x5e5f,
+    0x6065, 0x6894, 0x6cbb, 0x6dc4, 0x71be, 0x75d4, 0x75f4, 0x7661, 0x7a1a, 0x7a49, 0x7dc7, 0x7dfb, 0x7f6e, 0x81f4, 0x86a9, 0x8f1c,
+    0x96c9, 0x99b3, 0x9f52, 0x5247, 0x52c5, 0x98ed, 0x89aa, 0x4e03, 0x67d2, 0x6f06, 0x4fb5, 0x5be2, 0x6795, 0x6c88, 0x6d78, 0x741b,
+    0x7827, 0x91dd, 0x937c, 0x87c4, 0x79e4, 0x7a31, 0x5feb, 0x4ed6, 0x54a4, 0x553e, 0x58ae, 0x59a5, 0x60f0, 0x6253, 0x62d6, 0x6736,
+    0x6955, 0x8235, 0x9640, 0x99b1, 0x99dd, 0x502c, 0x5353, 0x5544, 0x577c, 0xfa01, 0x6258, 0xfa02, 0x64e2, 0x666b, 0x67dd, 0x6fc1,
+    0x6fef, 0x7422, 0x7438, 0x8a17, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x9438, 0x5451, 0x5606, 0x5766, 0x5f48, 0x619a, 0x6b4e, 0x7058, 0x70ad, 0x7dbb, 0x8a95, 0x596a,
+    0x812b, 0x63a2, 0x7708, 0x803d, 0x8caa, 0x5854, 0x642d, 0x69bb, 0x5b95, 0x5e11, 0x6e6f, 0xfa03, 0x8569, 0x514c, 0x53f0, 0x592a,
+    0x6020, 0x614b, 0x6b86, 0x6c70, 0x6cf0, 0x7b1e, 0x80ce, 0x82d4, 0x8dc6, 0x90b0, 0x98b1, 0xfa04, 0x64c7, 0x6fa4, 0x6491, 0x6504,
+    0x514e, 0x5410, 0x571f, 0x8a0e, 0x615f, 0x6876, 0xfa05, 0x75db, 0x7b52, 0x7d71, 0x901a, 0x5806, 0x69cc, 0x817f, 0x892a, 0x9000,
+    0x9839, 0x5078, 0x5957, 0x59ac, 0x6295, 0x900f, 0x9b2a, 0x615d, 0x7279, 0x95d6, 0x5761, 0x5a46, 0x5df4, 0x628a, 0x64ad, 0x64fa,
+    0x6777, 0x6ce2, 0x6d3e, 0x722c, 0x7436, 0x7834, 0x7f77, 0x82ad, 0x8ddb, 0x9817, 0x5224, 0x5742, 0x677f, 0x7248, 0x74e3, 0x8ca9,
+    0x8fa6, 0x9211, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x962a, 0x516b, 0x53ed, 0x634c, 0x4f69, 0x5504, 0x6096, 0x6557, 0x6c9b, 0x6d7f, 0x724c, 0x72fd, 0x7a17, 0x8987,
+    0x8c9d, 0x5f6d, 0x6f8e, 0x70f9, 0x81a8, 0x610e, 0x4fbf, 0x504f, 0x6241, 0x7247, 0x7bc7, 0x7de8, 0x7fe9, 0x904d, 0x97ad, 0x9a19,
+    0x8cb6, 0x576a, 0x5e73, 0x67b0, 0x840d, 0x8a55, 0x5420, 0x5b16, 0x5e63, 0x5ee2, 0x5f0a, 0x6583, 0x80ba, 0x853d, 0x9589, 0x965b,
+    0x4f48, 0x5305, 0x530d, 0x530f, 0x5486, 0x54fa, 0x5703, 0x5e03, 0x6016, 0x629b, 0x62b1, 0x6355, 0xfa06, 0x6ce1, 0x6d66, 0x75b1,
+    0x7832, 0x80de, 0x812f, 0x82de, 0x8461, 0x84b2, 0x888d, 0x8912, 0x900b, 0x92ea, 0x98fd, 0x9b91, 0x5e45, 0x66b4, 0x66dd, 0x7011,
+    0x7206, 0xfa07, 0x4ff5, 0x527d, 0x5f6a, 0x6153, 0x6753, 0x6a19, 0x6f02, 0x74e2, 0x7968, 0x8868, 0x8c79, 0x98c7, 0x98c4, 0x9a43,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x54c1, 0x7a1f, 0x6953, 0x8af7, 0x8c4a, 0x98a8, 0x99ae, 0x5f7c, 0x62ab, 0x75b2, 0x76ae, 0x88ab, 0x907f, 0x9642, 0x5339, 0x5f3c,
+    0x5fc5, 0x6ccc, 0x73cc, 0x7562, 0x758b, 0x7b46, 0x82fe, 0x999d, 0x4e4f, 0x903c, 0x4e0b, 0x4f55, 0x53a6, 0x590f, 0x5ec8, 0x6630,
+    0x6cb3, 0x7455, 0x8377, 0x8766, 0x8cc0, 0x9050, 0x971e, 0x9c15, 0x58d1, 0x5b78, 0x8650, 0x8b14, 0x9db4, 0x5bd2, 0x6068, 0x608d,
+    0x65f1, 0x6c57, 0x6f22, 0x6fa3, 0x701a, 0x7f55, 0x7ff0, 0x9591, 0x9592, 0x9650, 0x97d3, 0x5272, 0x8f44, 0x51fd, 0x542b, 0x54b8,
+    0x5563, 0x558a, 0x6abb, 0x6db5, 0x7dd8, 0x8266, 0x929c, 0x9677, 0x9e79, 0x5408, 0x54c8, 0x76d2, 0x86e4, 0x95a4, 0x95d4, 0x965c,
+    0x4ea2, 0x4f09, 0x59ee, 0x5ae6, 0x5df7, 0x6052, 0x6297, 0x676d, 0x6841, 0x6c86, 0x6e2f, 0x7f38, 0x809b, 0x822a, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xfa08, 0xfa09,
+    0x9805, 0x4ea5, 0x5055, 0x54b3, 0x5793, 0x595a, 0x5b69, 0x5bb3, 0x61c8, 0x6977, 0x6d77, 0x7023, 0x87f9, 0x89e3, 0x8a72, 0x8ae7,
+    0x9082, 0x99ed, 0x9ab8, 0x52be, 0x6838, 0x5016, 0x5e78, 0x674f, 0x8347, 0x884c, 0x4eab, 0x5411, 0x56ae, 0x73e6, 0x9115, 0x97ff,
+    0x9909, 0x9957, 0x9999, 0x5653, 0x589f, 0x865b, 0x8a31, 0x61b2, 0x6af6, 0x737b, 0x8ed2, 0x6b47, 0x96aa, 0x9a57, 0x5955, 0x7200,
+    0x8d6b, 0x9769, 0x4fd4, 0x5cf4, 0x5f26, 0x61f8, 0x665b, 0x6ceb, 0x70ab, 0x7384, 0x73b9, 0x73fe, 0x7729, 0x774d, 0x7d43, 0x7d62,
+    0x7e23, 0x8237, 0x8852, 0xfa0a, 0x8ce2, 0x9249, 0x986f, 0x5b51, 0x7a74, 0x8840, 0x9801, 0x5acc, 0x4fe0, 0x5354, 0x593e, 0x5cfd,
+    0x633e, 0x6d79, 0x72f9, 0x8105, 0x8107, 0x83a2, 0x92cf, 0x9830, 0x4ea8, 0x5144, 0x5211, 0x578b, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x5f62, 0x6cc2, 0x6ece, 0x7005,
+    0x7050, 0x70af, 0x7192, 0x73e9, 0x7469, 0x834a, 0x87a2, 0x8861, 0x9008, 0x90a2, 0x93a3, 0x99a8, 0x516e, 0x5f57, 0x60e0, 0x6167,
+    0x66b3, 0x8559, 0x8e4a, 0x91af, 0x978b, 0x4e4e, 0x4e92, 0x547c, 0x58d5, 0x58fa, 0x597d, 0x5cb5, 0x5f27, 0x6236, 0x6248, 0x660a,
+    0x6667, 0x6beb, 0x6d69, 0x6dcf, 0x6e56, 0x6ef8, 0x6f94, 0x6fe0, 0x6fe9, 0x705d, 0x72d0, 0x7425, 0x745a, 0x74e0, 0x7693, 0x795c,
+    0x7cca, 0x7e1e, 0x80e1, 0x82a6, 0x846b, 0x84bf, 0x864e, 0x865f, 0x8774, 0x8b77, 0x8c6a, 0x93ac, 0x9800, 0x9865, 0x60d1, 0x6216,
+    0x9177, 0x5a5a, 0x660f, 0x6df7, 0x6e3e, 0x743f, 0x9b42, 0x5ffd, 0x60da, 0x7b0f, 0x54c4, 0x5f18, 0x6c5e, 0x6cd3, 0x6d2a, 0x70d8,
+    0x7d05, 0x8679, 0x8a0c, 0x9d3b, 0x5316, 0x548c, 0x5b05, 0x6a3a, 0x706b, 0x7575, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x798d, 0x79be, 0x82b1, 0x83ef, 0x8a71, 0x8b41,
+    0x8ca8, 0x9774, 0xfa0b, 0x64f4, 0x652b, 0x78ba, 0x78bb, 0x7a6b, 0x4e38, 0x559a, 0x5950, 0x5ba6, 0x5e7b, 0x60a3, 0x63db, 0x6b61,
+    0x6665, 0x6853, 0x6e19, 0x7165, 0x74b0, 0x7d08, 0x9084, 0x9a69, 0x9c25, 0x6d3b, 0x6ed1, 0x733e, 0x8c41, 0x95ca, 0x51f0, 0x5e4c,
+    0x5fa8, 0x604d, 0x60f6, 0x6130, 0x614c, 0x6643, 0x6644, 0x69a5, 0x6cc1, 0x6e5f, 0x6ec9, 0x6f62, 0x714c, 0x749c, 0x7687, 0x7bc1,
+    0x7c27, 0x8352, 0x8757, 0x9051, 0x968d, 0x9ec3, 0x532f, 0x56de, 0x5efb, 0x5f8a, 0x6062, 0x6094, 0x61f7, 0x6666, 0x6703, 0x6a9c,
+    0x6dee, 0x6fae, 0x7070, 0x736a, 0x7e6a, 0x81be, 0x8334, 0x86d4, 0x8aa8, 0x8cc4, 0x5283, 0x7372, 0x5b96, 0x6a6b, 0x9404, 0x54ee,
+    0x5686, 0x5b5d, 0x6548, 0x6585, 0x66c9, 0x689f, 0x6d8d, 0x6dc6, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x723b, 0x80b4, 0x9175, 0x9a4d, 0x4faf, 0x5019, 0x539a, 0x540e,
+    0x543c, 0x5589, 0x55c5, 0x5e3f, 0x5f8c, 0x673d, 0x7166, 0x73dd, 0x9005, 0x52db, 0x52f3, 0x5864, 0x58ce, 0x7104, 0x718f, 0x71fb,
+    0x85b0, 0x8a13, 0x6688, 0x85a8, 0x55a7, 0x6684, 0x714a, 0x8431, 0x5349, 0x5599, 0x6bc1, 0x5f59, 0x5fbd, 0x63ee, 0x6689, 0x7147,
+    0x8af1, 0x8f1d, 0x9ebe, 0x4f11, 0x643a, 0x70cb, 0x7566, 0x8667, 0x6064, 0x8b4e, 0x9df8, 0x5147, 0x51f6, 0x5308, 0x6d36, 0x80f8,
+    0x9ed1, 0x6615, 0x6b23, 0x7098, 0x75d5, 0x5403, 0x5c79, 0x7d07, 0x8a16, 0x6b20, 0x6b3d, 0x6b46, 0x5438, 0x6070, 0x6d3d, 0x7fd5,
+    0x8208, 0x50d6, 0x51de, 0x559c, 0x566b, 0x56cd, 0x59ec, 0x5b09, 0x5e0c, 0x6199, 0x6198, 0x6231, 0x665e, 0x66e6, 0x7199, 0x71b9,
+    0x71ba, 0x72a7, 0x79a7, 0x7a00, 0x7fb2, 0x8a70, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000,
+];
+
+/// Pairs of a Unicode code point and the pointer of its character, sorted by code point.
+#[rustfmt::skip]
+static FROM_UNICODE: [(u16, u16); 17048] = [
+    (0x00a1, 6379), (0x00a4, 6385), (0x00a7, 6230), (0x00a8, 6182), (0x00aa, 7508), (0x00ad, 6184), (0x00ae, 6436), (0x00b0, 6213),
+    (0x00b1, 6205), (0x00b2, 7782), (0x00b3, 7783), (0x00b4, 6370), (0x00b6, 6415), (0x00b7, 6179), (0x00b8, 6377), (0x00b9, 7781),
+    (0x00ba, 7517), (0x00bc, 7594), (0x00bd, 7591), (0x00be, 7595), (0x00bf, 6380), (0x00c6, 7506), (0x00d0, 7507), (0x00d7, 6206),
+    (0x00d8, 7515), (0x00de, 7518), (0x00df, 7707), (0x00e6, 7696), (0x00f0, 7698), (0x00f7, 6207), (0x00f8, 7705), (0x00fe, 7708),
+    (0x0111, 7697), (0x0126, 7509), (0x0127, 7699), (0x0131, 7700), (0x0132, 7511), (0x0133, 7701), (0x0138, 7702), (0x013f, 7513),
+    (0x0140, 7703), (0x0141, 7514), (0x0142, 7704), (0x0149, 7711), (0x014a, 7520), (0x014b, 7710), (0x0152, 7516), (0x0153, 7706),
+    (0x0166, 7519), (0x0167, 7709), (0x02c7, 6372), (0x02d0, 6381), (0x02d8, 6373), (0x02d9, 6376), (0x02da, 6375), (0x02db, 6378),
+    (0x02dd, 6374), (0x0391, 6968), (0x0392, 6969), (0x0393, 6970), (0x0394, 6971), (0x0395, 6972), (0x0396, 6973), (0x0397, 6974),
+    (0x0398, 6975), (0x0399, 6976), (0x039a, 6977), (0x039b, 6978), (0x039c, 6979), (0x039d, 6980), (0x039e, 6981), (0x039f, 6982),
+    (0x03a0, 6983), (0x03a1, 6984), (0x03a3, 6985), (0x03a4, 6986), (0x03a5, 6987), (0x03a6, 6988), (0x03a7, 6989), (0x03a8, 6990),
+    (0x03a9, 6991), (0x03b1, 7000), (0x03b2, 7001), (0x03b3, 7002), (0x03b4, 7003), (0x03b5, 7004), (0x03b6, 7005), (0x03b7, 7006),
+    (0x03b8, 7007), (0x03b9, 7008), (0x03ba, 7009), (0x03bb, 7010), (0x03bc, 7011), (0x03bd, 7012), (0x03be, 7013), (0x03bf, 7014),
+    (0x03c0, 7015), (0x03c1, 7016), (0x03c3, 7017), (0x03c4, 7018), (0x03c5, 7019), (0x03c6, 7020), (0x03c7, 7021), (0x03c8, 7022),
+    (0x03c9, 7023), (0x0401, 8272), (0x0410, 8266), (0x0411, 8267), (0x0412, 8268), (0x0413, 8269), (0x0414, 8270), (0x0415, 8271),
+    (0x0416, 8273), (0x0417, 8274), (0x0418, 8275), (0x0419, 8276), (0x041a, 8277), (0x041b, 8278), (0x041c, 8279), (0x041d, 8280),
+    (0x041e, 8281), (0x041f, 8282), (0x0420, 8283), (0x0421, 8284), (0x0422, 8285), (0x0423, 8286), (0x0424, 8287), (0x0425, 8288),
+    (0x0426, 8289), (0x0427, 8290), (0x0428, 8291), (0x0429, 8292), (0x042a, 8293), (0x042b, 8294), (0x042c, 8295), (0x042d, 8296),
+    (0x042e, 8297), (0x042f, 8298), (0x0430, 8314), (0x0431, 8315), (0x0432, 8316), (0x0433, 8317), (0x0434, 8318), (0x0435, 8319),
+    (0x0436, 8321), (0x0437, 8322), (0x0438, 8323), (0x0439, 8324), (0x043a, 8325), (0x043b, 8326), (0x043c, 8327), (0x043d, 8328),
+    (0x043e, 8329), (0x043f, 8330), (0x0440, 8331), (0x0441, 8332), (0x0442, 8333), (0x0443, 8334), (0x0444, 8335), (0x0445, 8336),
+    (0x0446, 8337), (0x0447, 8338), (0x0448, 8339), (0x0449, 8340), (0x044a, 8341), (0x044b, 8342), (0x044c, 8343), (0x044d, 8344),
+    (0x044e, 8345), (0x044f, 8346), (0x0451, 8320), (0x2015, 6185), (0x2018, 6189), (0x2019, 6190), (0x201c, 6191), (0x201d, 6192),
+    (0x2020, 6416), (0x2021, 6417), (0x2025, 6180), (0x2026, 6181), (0x2030, 6387), (0x2032, 6214), (0x2033, 6215), (0x203b, 6231),
+    (0x2074, 7784), (0x207f, 7785), (0x2081, 7786), (0x2082, 7787), (0x2083, 7788), (0x2084, 7789), (0x20ac, 6435), (0x2103, 6216),
+    (0x2109, 6386), (0x2113, 7319), (0x2116, 6429), (0x2121, 6434), (0x2122, 6431), (0x2126, 7372), (0x212b, 6217), (0x2153, 7592),
+    (0x2154, 7593), (0x215b, 7596), (0x215c, 7597), (0x215d, 7598), (0x215e, 7599), (0x2160, 6951), (0x2161, 6952), (0x2162, 6953),
+    (0x2163, 6954), (0x2164, 6955), (0x2165, 6956), (0x2166, 6957), (0x2167, 6958), (0x2168, 6959), (0x2169, 6960), (0x2170, 6936),
+    (0x2171, 6937), (0x2172, 6938), (0x2173, 6939), (0x2174, 6940), (0x2175, 6941), (0x2176, 6942), (0x2177, 6943), (0x2178, 6944),
+    (0x2179, 6945), (0x2190, 6246), (0x2191, 6247), (0x2192, 6245), (0x2193, 6248), (0x2194, 6249), (0x2195, 6418), (0x2196, 6421),
+    (0x2197, 6419), (0x2198, 6422), (0x2199, 6420), (0x21d2, 6366), (0x21d4, 6367), (0x2200, 6368), (0x2202, 6226), (0x2203, 6369),
+    (0x2207, 6227), (0x2208, 6259), (0x220b, 6260), (0x220f, 6384), (0x2211, 6383), (0x221a, 6253), (0x221d, 6255), (0x221e, 6211),
+    (0x2220, 6223), (0x2225, 6186), (0x2227, 6267), (0x2228, 6268), (0x2229, 6266), (0x222a, 6265), (0x222b, 6257), (0x222c, 6258),
+    (0x222e, 6382), (0x2234, 6212), (0x2235, 6256), (0x223c, 6188), (0x223d, 6254), (0x2252, 6229), (0x2260, 6208), (0x2261, 6228),
+    (0x2264, 6209), (0x2265, 6210), (0x226a, 6251), (0x226b, 6252), (0x2282, 6263), (0x2283, 6264), (0x2286, 6261), (0x2287, 6262),
+    (0x2299, 6398), (0x22a5, 6224), (0x2312, 6225), (0x2460, 7576), (0x2461, 7577), (0x2462, 7578), (0x2463, 7579), (0x2464, 7580),
+    (0x2465, 7581), (0x2466, 7582), (0x2467, 7583), (0x2468, 7584), (0x2469, 7585), (0x246a, 7586), (0x246b, 7587), (0x246c, 7588),
+    (0x246d, 7589), (0x246e, 7590), (0x2474, 7766), (0x2475, 7767), (0x2476, 7768), (0x2477, 7769), (0x2478, 7770), (0x2479, 7771),
+    (0x247a, 7772), (0x247b, 7773), (0x247c, 7774), (0x247d, 7775), (0x247e, 7776), (0x247f, 7777), (0x2480, 7778), (0x2481, 7779),
+    (0x2482, 7780), (0x249c, 7740), (0x249d, 7741), (0x249e, 7742), (0x249f, 7743), (0x24a0, 7744), (0x24a1, 7745), (0x24a2, 7746),
+    (0x24a3, 7747), (0x24a4, 7748), (0x24a5, 7749), (0x24a6, 7750), (0x24a7, 7751), (0x24a8, 7752), (0x24a9, 7753), (0x24aa, 7754),
+    (0x24ab, 7755), (0x24ac, 7756), (0x24ad, 7757), (0x24ae, 7758), (0x24af, 7759), (0x24b0, 7760), (0x24b1, 7761), (0x24b2, 7762),
+    (0x24b3, 7763), (0x24b4, 7764), (0x24b5, 7765), (0x24d0, 7550), (0x24d1, 7551), (0x24d2, 7552), (0x24d3, 7553), (0x24d4, 7554),
+    (0x24d5, 7555), (0x24d6, 7556), (0x24d7, 7557), (0x24d8, 7558), (0x24d9, 7559), (0x24da, 7560), (0x24db, 7561), (0x24dc, 7562),
+    (0x24dd, 7563), (0x24de, 7564), (0x24df, 7565), (0x24e0, 7566), (0x24e1, 7567), (0x24e2, 7568), (0x24e3, 7569), (0x24e4, 7570),
+    (0x24e5, 7571), (0x24e6, 7572), (0x24e7, 7573), (0x24e8, 7574), (0x24e9, 7575), (0x2500, 7126), (0x2501, 7137), (0x2502, 7127),
+    (0x2503, 7138), (0x250c, 7128), (0x250d, 7165), (0x250e, 7164), (0x250f, 7139), (0x2510, 7129), (0x2511, 7159), (0x2512, 7158),
+    (0x2513, 7140), (0x2514, 7131), (0x2515, 7163), (0x2516, 7162), (0x2517, 7142), (0x2518, 7130), (0x2519, 7161), (0x251a, 7160),
+    (0x251b, 7141), (0x251c, 7132), (0x251d, 7153), (0x251e, 7166), (0x251f, 7167), (0x2520, 7148), (0x2521, 7168), (0x2522, 7169),
+    (0x2523, 7143), (0x2524, 7134), (0x2525, 7155), (0x2526, 7170), (0x2527, 7171), (0x2528, 7150), (0x2529, 7172), (0x252a, 7173),
+    (0x252b, 7145), (0x252c, 7133), (0x252d, 7174), (0x252e, 7175), (0x252f, 7149), (0x2530, 7154), (0x2531, 7176), (0x2532, 7177),
+    (0x2533, 7144), (0x2534, 7135), (0x2535, 7178), (0x2536, 7179), (0x2537, 7151), (0x2538, 7156), (0x2539, 7180), (0x253a, 7181),
+    (0x253b, 7146), (0x253c, 7136), (0x253d, 7182), (0x253e, 7183), (0x253f, 7152), (0x2540, 7184), (0x2541, 7185), (0x2542, 7157),
+    (0x2543, 7186), (0x2544, 7187), (0x2545, 7188), (0x2546, 7189), (0x2547, 7190), (0x2548, 7191), (0x2549, 7192), (0x254a, 7193),
+    (0x254b, 7147), (0x2592, 6403), (0x25a0, 6240), (0x25a1, 6239), (0x25a3, 6400), (0x25a4, 6404), (0x25a5, 6405), (0x25a6, 6408),
+    (0x25a7, 6407), (0x25a8, 6406), (0x25a9, 6409), (0x25b2, 6242), (0x25b3, 6241), (0x25b6, 6391), (0x25b7, 6390), (0x25bc, 6244),
+    (0x25bd, 6243), (0x25c0, 6389), (0x25c1, 6388), (0x25c6, 6238), (0x25c7, 6237), (0x25c8, 6399), (0x25cb, 6234), (0x25ce, 6236),
+    (0x25cf, 6235), (0x25d0, 6401), (0x25d1, 6402), (0x2605, 6233), (0x2606, 6232), (0x260e, 6412), (0x260f, 6411), (0x261c, 6413),
+    (0x261e, 6414), (0x2640, 6222), (0x2642, 6221), (0x2660, 6393), (0x2661, 6394), (0x2663, 6397), (0x2664, 6392), (0x2665, 6395),
+    (0x2667, 6396), (0x2668, 6410), (0x2669, 6424), (0x266a, 6425), (0x266c, 6426), (0x266d, 6423), (0x3000, 6176), (0x3001, 6177),
+    (0x3002, 6178), (0x3003, 6183), (0x3008, 6195), (0x3009, 6196), (0x300a, 6197), (0x300b, 6198), (0x300c, 6199), (0x300d, 6200),
+    (0x300e, 6201), (0x300f, 6202), (0x3010, 6203), (0x3011, 6204), (0x3013, 6250), (0x3014, 6193), (0x3015, 6194), (0x3041, 7886),
+    (0x3042, 7887), (0x3043, 7888), (0x3044, 7889), (0x3045, 7890), (0x3046, 7891), (0x3047, 7892), (0x3048, 7893), (0x3049, 7894),
+    (0x304a, 7895), (0x304b, 7896), (0x304c, 7897), (0x304d, 7898), (0x304e, 7899), (0x304f, 7900), (0x3050, 7901), (0x3051, 7902),
+    (0x3052, 7903), (0x3053, 7904), (0x3054, 7905), (0x3055, 7906), (0x3056, 7907), (0x3057, 7908), (0x3058, 7909), (0x3059, 7910),
+    (0x305a, 7911), (0x305b, 7912), (0x305c, 7913), (0x305d, 7914), (0x305e, 7915), (0x305f, 7916), (0x3060, 7917), (0x3061, 7918),
+    (0x3062, 7919), (0x3063, 7920), (0x3064, 7921), (0x3065, 7922), (0x3066, 7923), (0x3067, 7924), (0x3068, 7925), (0x3069, 7926),
+    (0x306a, 7927), (0x306b, 7928), (0x306c, 7929), (0x306d, 7930), (0x306e, 7931), (0x306f, 7932), (0x3070, 7933), (0x3071, 7934),
+    (0x3072, 7935), (0x3073, 7936), (0x3074, 7937), (0x3075, 7938), (0x3076, 7939), (0x3077, 7940), (0x3078, 7941), (0x3079, 7942),
+    (0x307a, 7943), (0x307b, 7944), (0x307c, 7945), (0x307d, 7946), (0x307e, 7947), (0x307f, 7948), (0x3080, 7949), (0x3081, 7950),
+    (0x3082, 7951), (0x3083, 7952), (0x3084, 7953), (0x3085, 7954), (0x3086, 7955), (0x3087, 7956), (0x3088, 7957), (0x3089, 7958),
+    (0x308a, 7959), (0x308b, 7960), (0x308c, 7961), (0x308d, 7962), (0x308e, 7963), (0x308f, 7964), (0x3090, 7965), (0x3091, 7966),
+    (0x3092, 7967), (0x3093, 7968), (0x30a1, 8076), (0x30a2, 8077), (0x30a3, 8078), (0x30a4, 8079), (0x30a5, 8080), (0x30a6, 8081),
+    (0x30a7, 8082), (0x30a8, 8083), (0x30a9, 8084), (0x30aa, 8085), (0x30ab, 8086), (0x30ac, 8087), (0x30ad, 8088), (0x30ae, 8089),
+    (0x30af, 8090), (0x30b0, 8091), (0x30b1, 8092), (0x30b2, 8093), (0x30b3, 8094), (0x30b4, 8095), (0x30b5, 8096), (0x30b6, 8097),
+    (0x30b7, 8098), (0x30b8, 8099), (0x30b9, 8100), (0x30ba, 8101), (0x30bb, 8102), (0x30bc, 8103), (0x30bd, 8104), (0x30be, 8105),
+    (0x30bf, 8106), (0x30c0, 8107), (0x30c1, 8108), (0x30c2, 8109), (0x30c3, 8110), (0x30c4, 8111), (0x30c5, 8112), (0x30c6, 8113),
+    (0x30c7, 8114), (0x30c8, 8115), (0x30c9, 8116), (0x30ca, 8117), (0x30cb, 8118), (0x30cc, 8119), (0x30cd, 8120), (0x30ce, 8121),
+    (0x30cf, 8122), (0x30d0, 8123), (0x30d1, 8124), (0x30d2, 8125), (0x30d3, 8126), (0x30d4, 8127), (0x30d5, 8128), (0x30d6, 8129),
+    (0x30d7, 8130), (0x30d8, 8131), (0x30d9, 8132), (0x30da, 8133), (0x30db, 8134), (0x30dc, 8135), (0x30dd, 8136), (0x30de, 8137),
+    (0x30df, 8138), (0x30e0, 8139), (0x30e1, 8140), (0x30e2, 8141), (0x30e3, 8142), (0x30e4, 8143), (0x30e5, 8144), (0x30e6, 8145),
+    (0x30e7, 8146), (0x30e8, 8147), (0x30e9, 8148), (0x30ea, 8149), (0x30eb, 8150), (0x30ec, 8151), (0x30ed, 8152), (0x30ee, 8153),
+    (0x30ef, 8154), (0x30f0, 8155), (0x30f1, 8156), (0x30f2, 8157), (0x30f3, 8158), (0x30f4, 8159), (0x30f5, 8160), (0x30f6, 8161),
+    (0x3131, 6746), (0x3132, 6747), (0x3133, 6748), (0x3134, 6749), (0x3135, 6750), (0x3136, 6751), (0x3137, 6752), (0x3138, 6753),
+    (0x3139, 6754), (0x313a, 6755), (0x313b, 6756), (0x313c, 6757), (0x313d, 6758), (0x313e, 6759), (0x313f, 6760), (0x3140, 6761),
+    (0x3141, 6762), (0x3142, 6763), (0x3143, 6764), (0x3144, 6765), (0x3145, 6766), (0x3146, 6767), (0x3147, 6768), (0x3148, 6769),
+    (0x3149, 6770), (0x314a, 6771), (0x314b, 6772), (0x314c, 6773), (0x314d, 6774), (0x314e, 6775), (0x314f, 6776), (0x3150, 6777),
+    (0x3151, 6778), (0x3152, 6779), (0x3153, 6780), (0x3154, 6781), (0x3155, 6782), (0x3156, 6783), (0x3157, 6784), (0x3158, 6785),
+    (0x3159, 6786), (0x315a, 6787), (0x315b, 6788), (0x315c, 6789), (0x315d, 6790), (0x315e, 6791), (0x315f, 6792), (0x3160, 6793),
+    (0x3161, 6794), (0x3162, 6795), (0x3163, 6796), (0x3164, 6797), (0x3165, 6798), (0x3166, 6799), (0x3167, 6800), (0x3168, 6801),
+    (0x3169, 6802), (0x316a, 6803), (0x316b, 6804), (0x316c, 6805), (0x316d, 6806), (0x316e, 6807), (0x316f, 6808), (0x3170, 6809),
+    (0x3171, 6810), (0x3172, 6811), (0x3173, 6812), (0x3174, 6813), (0x3175, 6814), (0x3176, 6815), (0x3177, 6816), (0x3178, 6817),
+    (0x3179, 6818), (0x317a, 6819), (0x317b, 6820), (0x317c, 6821), (0x317d, 6822), (0x317e, 6823), (0x317f, 6824), (0x3180, 6825),
+    (0x3181, 6826), (0x3182, 6827), (0x3183, 6828), (0x3184, 6829), (0x3185, 6830), (0x3186, 6831), (0x3187, 6832), (0x3188, 6833),
+    (0x3189, 6834), (0x318a, 6835), (0x318b, 6836), (0x318c, 6837), (0x318d, 6838), (0x318e, 6839), (0x3200, 7712), (0x3201, 7713),
+    (0x3202, 7714), (0x3203, 7715), (0x3204, 7716), (0x3205, 7717), (0x3206, 7718), (0x3207, 7719), (0x3208, 7720), (0x3209, 7721),
+    (0x320a, 7722), (0x320b, 7723), (0x320c, 7724), (0x320d, 7725), (0x320e, 7726), (0x320f, 7727), (0x3210, 7728), (0x3211, 7729),
+    (0x3212, 7730), (0x3213, 7731), (0x3214, 7732), (0x3215, 7733), (0x3216, 7734), (0x3217, 7735), (0x3218, 7736), (0x3219, 7737),
+    (0x321a, 7738), (0x321b, 7739), (0x321c, 6428), (0x3260, 7522), (0x3261, 7523), (0x3262, 7524), (0x3263, 7525), (0x3264, 7526),
+    (0x3265, 7527), (0x3266, 7528), (0x3267, 7529), (0x3268, 7530), (0x3269, 7531), (0x326a, 7532), (0x326b, 7533), (0x326c, 7534),
+    (0x326d, 7535), (0x326e, 7536), (0x326f, 7537), (0x3270, 7538), (0x3271, 7539), (0x3272, 7540), (0x3273, 7541), (0x3274, 7542),
+    (0x3275, 7543), (0x3276, 7544), (0x3277, 7545), (0x3278, 7546), (0x3279, 7547), (0x327a, 7548), (0x327b, 7549), (0x327f, 6427),
+    (0x3380, 7356), (0x3381, 7357), (0x3382, 7358), (0x3383, 7359), (0x3384, 7360), (0x3388, 7341), (0x3389, 7342), (0x338a, 7375),
+    (0x338b, 7376), (0x338c, 7377), (0x338d, 7337), (0x338e, 7338), (0x338f, 7339), (0x3390, 7367), (0x3391, 7368), (0x3392, 7369),
+    (0x3393, 7370), (0x3394, 7371), (0x3395, 7316), (0x3396, 7317), (0x3397, 7318), (0x3398, 7320), (0x3399, 7326), (0x339a, 7327),
+    (0x339b, 7328), (0x339c, 7329), (0x339d, 7330), (0x339e, 7331), (0x339f, 7332), (0x33a0, 7333), (0x33a1, 7334), (0x33a2, 7335),
+    (0x33a3, 7322), (0x33a4, 7323), (0x33a5, 7324), (0x33a6, 7325), (0x33a7, 7344), (0x33a8, 7345), (0x33a9, 7384), (0x33aa, 7385),
+    (0x33ab, 7386), (0x33ac, 7387), (0x33ad, 7380), (0x33ae, 7381), (0x33af, 7382), (0x33b0, 7346), (0x33b1, 7347), (0x33b2, 7348),
+    (0x33b3, 7349), (0x33b4, 7350), (0x33b5, 7351), (0x33b6, 7352), (0x33b7, 7353), (0x33b8, 7354), (0x33b9, 7355), (0x33ba, 7361),
+    (0x33bb, 7362), (0x33bc, 7363), (0x33bd, 7364), (0x33be, 7365), (0x33bf, 7366), (0x33c0, 7373), (0x33c1, 7374), (0x33c2, 6432),
+    (0x33c3, 7391), (0x33c4, 7321), (0x33c5, 7379), (0x33c6, 7394), (0x33c7, 6430), (0x33c8, 7343), (0x33c9, 7392), (0x33ca, 7336),
+    (0x33cf, 7340), (0x33d0, 7389), (0x33d3, 7390), (0x33d6, 7378), (0x33d8, 6433), (0x33db, 7383), (0x33dc, 7393), (0x33dd, 7388),
+    (0x4e00, 20498), (0x4e01, 21038), (0x4e03, 22375), (0x4e07, 16643), (0x4e08, 20674), (0x4e09, 17973), (0x4e0a, 17985), (0x4e0b, 22922),
+    (0x4e0d, 17469), (0x4e11, 22203), (0x4e14, 21761), (0x4e15, 17639), (0x4e16, 18341), (0x4e18, 14813), (0x4e19, 17401), (0x4e1e, 18725),
+    (0x4e2d, 21448), (0x4e32, 14625), (0x4e38, 23480), (0x4e39, 15676), (0x4e3b, 21386), (0x4e42, 19524), (0x4e43, 15497), (0x4e45, 14814),
+    (0x4e4b, 21468), (0x4e4d, 17796), (0x4e4e, 23301), (0x4e4f, 22920), (0x4e56, 14775), (0x4e58, 18726), (0x4e59, 20299), (0x4e5d, 14815),
+    (0x4e5e, 14242), (0x4e5f, 19100), (0x4e6b, 14030), (0x4e6d, 15909), (0x4e73, 20110), (0x4e76, 17452), (0x4e77, 17968), (0x4e7e, 14230),
+    (0x4e82, 16070), (0x4e86, 16330), (0x4e88, 19183), (0x4e8b, 17797), (0x4e8c, 20428), (0x4e8e, 19900), (0x4e90, 20052), (0x4e91, 19941),
+    (0x4e92, 23302), (0x4e94, 19548), (0x4e95, 21039), (0x4e98, 15175), (0x4e9b, 17798), (0x4e9e, 18917), (0x4ea1, 16669), (0x4ea2, 22976),
+    (0x4ea4, 14788), (0x4ea5, 23089), (0x4ea6, 19303), (0x4ea8, 23176), (0x4eab, 23114), (0x4eac, 14385), (0x4ead, 21040), (0x4eae, 16108),
+    (0x4eb6, 15677), (0x4eba, 20474), (0x4ec0, 18912), (0x4ec1, 20475), (0x4ec4, 22342), (0x4ec7, 14816), (0x4eca, 15154), (0x4ecb, 14186),
+    (0x4ecd, 20619), (0x4ed4, 20623), (0x4ed5, 17799), (0x4ed6, 22391), (0x4ed7, 20675), (0x4ed8, 17470), (0x4ed9, 18170), (0x4edd, 15911),
+    (0x4edf, 21966), (0x4ee3, 15734), (0x4ee4, 16266), (0x4ee5, 20429), (0x4ef0, 18975), (0x4ef2, 21449), (0x4ef6, 14231), (0x4ef7, 14187),
+    (0x4efb, 20507), (0x4f01, 15179), (0x4f09, 22977), (0x4f0a, 20430), (0x4f0b, 15168), (0x4f0d, 19549), (0x4f0e, 15180), (0x4f0f, 17434),
+    (0x4f10, 17268), (0x4f11, 23699), (0x4f2f, 17250), (0x4f34, 17070), (0x4f36, 16267), (0x4f38, 18778), (0x4f3a, 17800), (0x4f3c, 17801),
+    (0x4f3d, 13966), (0x4f43, 20881), (0x4f46, 15678), (0x4f47, 20828), (0x4f48, 22752), (0x4f4d, 20085), (0x4f4e, 20829), (0x4f4f, 21387),
+    (0x4f50, 21380), (0x4f51, 19901), (0x4f55, 22923), (0x4f59, 19184), (0x4f5a, 20499), (0x4f5b, 17629), (0x4f5c, 20649), (0x4f69, 22710),
+    (0x4f6f, 19121), (0x4f70, 17251), (0x4f73, 13967), (0x4f76, 15340), (0x4f7a, 20882), (0x4f7e, 20500), (0x4f7f, 17802), (0x4f81, 18779),
+    (0x4f83, 14006), (0x4f84, 21638), (0x4f86, 16101), (0x4f88, 22347), (0x4f8a, 14759), (0x4f8b, 16284), (0x4f8d, 18735), (0x4f8f, 21388),
+    (0x4f91, 20111), (0x4f96, 16469), (0x4f98, 21762), (0x4f9b, 14609), (0x4f9d, 20313), (0x4fae, 16833), (0x4faf, 23660), (0x4fb5, 22378),
+    (0x4fb6, 16121), (0x4fbf, 22726), (0x4fc2, 14430), (0x4fc3, 22156), (0x4fc4, 18918), (0x4fc9, 19550), (0x4fca, 21428), (0x4fce, 21212),
+    (0x4fd1, 19876), (0x4fd3, 14386), (0x4fd4, 23138), (0x4fd7, 18387), (0x4fda, 16489), (0x4fdd, 17418), (0x4fdf, 17803), (0x4fe0, 23164),
+    (0x4fe1, 18780), (0x4fee, 18418), (0x4fef, 17471), (0x4ff1, 14817), (0x4ff3, 17231), (0x4ff5, 22786), (0x4ff8, 17453), (0x4ffa, 19174),
+    (0x5002, 17402), (0x5006, 16109), (0x5009, 21813), (0x500b, 14188), (0x500d, 17230), (0x5011, 16905), (0x5012, 15753), (0x5016, 23109),
+    (0x5019, 23661), (0x501a, 20314), (0x501c, 21951), (0x501e, 14387), (0x501f, 21763), (0x5021, 21814), (0x5023, 17202), (0x5024, 22348),
+    (0x5026, 14985), (0x5027, 21267), (0x5028, 14213), (0x502a, 19525), (0x502b, 16470), (0x502c, 22405), (0x502d, 19727), (0x503b, 19101),
+    (0x5043, 19166), (0x5047, 13968), (0x5048, 14352), (0x5049, 20086), (0x504f, 22727), (0x5055, 23090), (0x505a, 21389), (0x505c, 21041),
+    (0x5065, 14232), (0x5074, 22341), (0x5075, 21042), (0x5076, 19902), (0x5078, 22577), (0x5080, 14776), (0x5085, 17472), (0x508d, 17203),
+    (0x5091, 14243), (0x5098, 17956), (0x5099, 17640), (0x50ac, 22177), (0x50ad, 19877), (0x50b2, 19551), (0x50b3, 20883), (0x50b5, 21835),
+    (0x50b7, 17986), (0x50be, 14388), (0x50c5, 15138), (0x50c9, 21995), (0x50ca, 18171), (0x50cf, 17987), (0x50d1, 14789), (0x50d5, 17435),
+    (0x50d6, 23729), (0x50da, 16331), (0x50de, 20087), (0x50e5, 19738), (0x50e7, 18727), (0x50ed, 21803), (0x50f9, 13969), (0x50fb, 17283),
+    (0x50ff, 17804), (0x5100, 20315), (0x5101, 21429), (0x5104, 19161), (0x5106, 14389), (0x5109, 14246), (0x5112, 20112), (0x511f, 17988),
+    (0x5121, 16322), (0x512a, 19903), (0x5132, 20830), (0x5137, 16122), (0x513a, 15346), (0x513c, 19175), (0x5140, 19685), (0x5141, 20270),
+    (0x5143, 20055), (0x5144, 23177), (0x5145, 22221), (0x5146, 21213), (0x5147, 23707), (0x5148, 18172), (0x5149, 14760), (0x514b, 15131),
+    (0x514c, 22541), (0x514d, 16708), (0x514e, 22560), (0x5152, 18919), (0x515c, 15928), (0x5162, 15176), (0x5165, 20518), (0x5167, 15499),
+    (0x5168, 20884), (0x5169, 16110), (0x516a, 20113), (0x516b, 22707), (0x516c, 14610), (0x516d, 16466), (0x516e, 23292), (0x5171, 14611),
+    (0x5175, 17403), (0x5176, 15181), (0x5177, 14818), (0x5178, 20885), (0x517c, 14379), (0x5180, 15182), (0x5186, 19182), (0x518a, 21847),
+    (0x518d, 20807), (0x5192, 16834), (0x5195, 16709), (0x5197, 19878), (0x51a0, 14739), (0x51a5, 16817), (0x51aa, 16706), (0x51ac, 15912),
+    (0x51b6, 19102), (0x51b7, 16105), (0x51bd, 16254), (0x51c4, 21947), (0x51c6, 21430), (0x51c9, 16111), (0x51cb, 21214), (0x51cc, 16483),
+    (0x51cd, 15913), (0x51dc, 16482), (0x51dd, 20309), (0x51de, 23730), (0x51e1, 17272), (0x51f0, 23502), (0x51f1, 14189), (0x51f6, 23708),
+    (0x51f8, 21985), (0x51f9, 19739), (0x51fa, 22218), (0x51fd, 22957), (0x5200, 15754), (0x5203, 20476), (0x5206, 17609), (0x5207, 21018),
+    (0x5208, 19527), (0x520a, 14007), (0x520e, 16906), (0x5211, 23178), (0x5217, 16255), (0x521d, 22033), (0x5224, 22602), (0x5225, 17397),
+    (0x5229, 16490), (0x522a, 17957), (0x522e, 14755), (0x5230, 15755), (0x5236, 21189), (0x5237, 18411), (0x5238, 14986), (0x5239, 21798),
+    (0x523a, 20624), (0x523b, 13995), (0x5243, 22024), (0x5247, 22371), (0x524a, 17856), (0x524b, 15132), (0x524c, 16078), (0x524d, 20886),
+    (0x5254, 21953), (0x5256, 17473), (0x525b, 14162), (0x525d, 17051), (0x5261, 18215), (0x5269, 20620), (0x526a, 20887), (0x526f, 17474),
+    (0x5272, 22955), (0x5275, 21815), (0x527d, 22787), (0x527f, 22034), (0x5283, 23546), (0x5287, 15133), (0x5288, 17284), (0x5289, 16452),
+    (0x528d, 14247), (0x5291, 21190), (0x5292, 14248), (0x529b, 16139), (0x529f, 14612), (0x52a0, 13970), (0x52a3, 16256), (0x52a4, 15139),
+    (0x52a9, 21215), (0x52aa, 15514), (0x52ab, 14349), (0x52be, 23107), (0x52c1, 14390), (0x52c3, 17095), (0x52c5, 22372), (0x52c7, 19879),
+    (0x52c9, 16710), (0x52cd, 14391), (0x52d2, 16480), (0x52d5, 15914), (0x52d6, 19932), (0x52d8, 14040), (0x52d9, 16881), (0x52db, 23673),
+    (0x52dd, 18728), (0x52de, 16289), (0x52df, 16835), (0x52e2, 18342), (0x52e3, 20856), (0x52e4, 15140), (0x52f3, 23674), (0x52f5, 16123),
+    (0x52f8, 14987), (0x52fa, 20650), (0x52fb, 15123), (0x52fe, 14819), (0x52ff, 17013), (0x5305, 22753), (0x5308, 23709), (0x530d, 22754),
+    (0x530f, 22755), (0x5310, 17436), (0x5315, 17641), (0x5316, 23364), (0x5317, 17608), (0x5319, 18736), (0x5320, 20676), (0x5321, 14761),
+    (0x5323, 14156), (0x532a, 17642), (0x532f, 23526), (0x5339, 22910), (0x533f, 15576), (0x5340, 14916), (0x5341, 18913), (0x5343, 21967),
+    (0x5344, 20519), (0x5347, 18729), (0x5348, 19552), (0x5349, 23688), (0x534a, 17071), (0x534d, 16644), (0x5351, 17643), (0x5352, 21264),
+    (0x5353, 22406), (0x5354, 23165), (0x5357, 15380), (0x535a, 17052), (0x535c, 17437), (0x535e, 17391), (0x5360, 21026), (0x5366, 14772),
+    (0x5368, 18202), (0x536f, 16869), (0x5370, 20477), (0x5371, 20088), (0x5374, 13996), (0x5375, 16071), (0x5377, 14988), (0x537d, 21452),
+    (0x537f, 14392), (0x5384, 18993), (0x5393, 18982), (0x5398, 16491), (0x539a, 23662), (0x539f, 20056), (0x53a0, 22343), (0x53a5, 14995),
+    (0x53a6, 22924), (0x53ad, 19369), (0x53bb, 14214), (0x53c3, 21804), (0x53c8, 19904), (0x53c9, 21764), (0x53ca, 15169), (0x53cb, 19905),
+    (0x53cd, 17072), (0x53d4, 18575), (0x53d6, 22327), (0x53d7, 18419), (0x53db, 17073), (0x53e1, 19528), (0x53e2, 22166), (0x53e3, 14917),
+    (0x53e4, 14550), (0x53e5, 14918), (0x53e9, 14551), (0x53ea, 21469), (0x53eb, 15108), (0x53ec, 18350), (0x53ed, 22708), (0x53ef, 13971),
+    (0x53f0, 22542), (0x53f1, 21639), (0x53f2, 17805), (0x53f3, 19906), (0x53f8, 17806), (0x5403, 23717), (0x5404, 13997), (0x5408, 22969),
+    (0x5409, 15341), (0x540a, 20857), (0x540c, 15915), (0x540d, 16818), (0x540e, 23663), (0x540f, 16492), (0x5410, 22561), (0x5411, 23115),
+    (0x541b, 14969), (0x541d, 16515), (0x541f, 20300), (0x5420, 22742), (0x5426, 17475), (0x5429, 17610), (0x542b, 22958), (0x5433, 19554),
+    (0x5438, 23724), (0x5439, 22328), (0x543b, 16907), (0x543c, 23664), (0x543e, 19553), (0x5442, 16124), (0x5448, 21043), (0x544a, 14552),
+    (0x5451, 22517), (0x5468, 21393), (0x546a, 21392), (0x5471, 14553), (0x5473, 17016), (0x5475, 13972), (0x547b, 18781), (0x547c, 23303),
+    (0x547d, 16819), (0x5480, 20831), (0x5486, 22756), (0x548c, 23365), (0x548e, 14919), (0x5490, 17476), (0x54a4, 22392), (0x54a8, 20625),
+    (0x54ab, 21566), (0x54ac, 14790), (0x54b3, 23091), (0x54b8, 22959), (0x54bd, 20479), (0x54c0, 18983), (0x54c1, 22896), (0x54c4, 23354),
+    (0x54c8, 22970), (0x54c9, 20808), (0x54e1, 20057), (0x54e5, 13973), (0x54e8, 22035), (0x54ed, 14589), (0x54ee, 23551), (0x54f2, 21986),
+    (0x54fa, 22757), (0x5504, 22711), (0x5506, 17807), (0x5507, 21603), (0x550e, 16493), (0x5510, 15723), (0x551c, 16662), (0x552f, 20115),
+    (0x5531, 21816), (0x5535, 18963), (0x553e, 22393), (0x5544, 22407), (0x5546, 17989), (0x554f, 16908), (0x5553, 14431), (0x5556, 15701),
+    (0x555e, 18920), (0x5563, 22960), (0x557c, 21191), (0x5580, 14206), (0x5584, 18173), (0x5586, 21987), (0x5587, 15955), (0x5589, 23665),
+    (0x558a, 22961), (0x5598, 21968), (0x5599, 23689), (0x559a, 23481), (0x559c, 23731), (0x559d, 14031), (0x55a7, 23684), (0x55a9, 20116),
+    (0x55aa, 17990), (0x55ab, 15345), (0x55ac, 14791), (0x55ae, 15679), (0x55c5, 23666), (0x55c7, 18019), (0x55d4, 21604), (0x55da, 19555),
+    (0x55dc, 15183), (0x55df, 21765), (0x55e3, 17808), (0x55e4, 22349), (0x55fd, 18420), (0x55fe, 21394), (0x5606, 22518), (0x5609, 13974),
+    (0x5614, 14920), (0x5617, 17991), (0x562f, 18351), (0x5632, 21216), (0x5634, 22329), (0x5636, 18737), (0x5653, 23123), (0x5668, 15184),
+    (0x566b, 23732), (0x5674, 17611), (0x5686, 23552), (0x56a5, 19316), (0x56ac, 17778), (0x56ae, 23116), (0x56b4, 19176), (0x56bc, 20651),
+    (0x56ca, 15490), (0x56cd, 23733), (0x56d1, 22157), (0x56da, 18421), (0x56db, 17809), (0x56de, 23527), (0x56e0, 20480), (0x56f0, 14596),
+    (0x56f9, 16268), (0x56fa, 14554), (0x5703, 22758), (0x5704, 19151), (0x5708, 14989), (0x570b, 14963), (0x570d, 20089), (0x5712, 20059),
+    (0x5713, 20058), (0x5716, 15756), (0x5718, 15680), (0x571f, 22562), (0x5728, 20809), (0x572d, 15109), (0x5730, 21567), (0x573b, 15185),
+    (0x5740, 21568), (0x5742, 22603), (0x5747, 15124), (0x574a, 17204), (0x574d, 15702), (0x574e, 14041), (0x5750, 21381), (0x5751, 14208),
+    (0x5761, 22586), (0x5764, 14597), (0x5766, 22519), (0x576a, 22737), (0x576e, 15736), (0x5770, 14393), (0x5775, 14921), (0x577c, 22408),
+    (0x5782, 18422), (0x5788, 15735), (0x578b, 23179), (0x5793, 23092), (0x57a0, 20292), (0x57a2, 14922), (0x57a3, 20060), (0x57c3, 18984),
+    (0x57c7, 19880), (0x57c8, 21431), (0x57cb, 16681), (0x57ce, 18227), (0x57df, 19305), (0x57e0, 17477), (0x57f0, 21836), (0x57f4, 18763),
+    (0x57f7, 21656), (0x57f9, 17233), (0x57fa, 15186), (0x57fc, 15187), (0x5800, 14975), (0x5802, 15724), (0x5805, 14362), (0x5806, 22571),
+    (0x5808, 14163), (0x5809, 20263), (0x580a, 18935), (0x581e, 22005), (0x5821, 17419), (0x5824, 21192), (0x5827, 19317), (0x582a, 14042),
+    (0x582f, 19740), (0x5830, 19167), (0x5831, 17420), (0x5834, 20677), (0x5835, 15757), (0x583a, 14432), (0x584a, 14777), (0x584b, 19486),
+    (0x584f, 14190), (0x5851, 18352), (0x5854, 22533), (0x5857, 15758), (0x5858, 15725), (0x585a, 22167), (0x585e, 18016), (0x5861, 20888),
+    (0x5862, 19556), (0x5864, 23675), (0x5875, 21605), (0x5879, 21805), (0x587c, 20889), (0x587e, 18576), (0x5883, 14394), (0x5885, 18029),
+    (0x5889, 19881), (0x5893, 16870), (0x589c, 22180), (0x589e, 21457), (0x589f, 23124), (0x58a8, 16903), (0x58a9, 15899), (0x58ae, 22394),
+    (0x58b3, 17612), (0x58ba, 19557), (0x58bb, 20678), (0x58be, 14008), (0x58c1, 17285), (0x58c5, 19686), (0x58c7, 15681), (0x58ce, 23676),
+    (0x58d1, 22936), (0x58d3, 18971), (0x58d5, 23304), (0x58d8, 16439), (0x58d9, 14762), (0x58de, 14778), (0x58df, 16315), (0x58e4, 19124),
+    (0x58eb, 17810), (0x58ec, 20508), (0x58ef, 20679), (0x58f9, 20501), (0x58fa, 23305), (0x58fb, 18030), (0x58fd, 18423), (0x590f, 22925),
+    (0x5914, 15188), (0x5915, 18155), (0x5916, 19731), (0x5919, 18577), (0x591a, 15578), (0x591c, 19103), (0x5922, 16866), (0x5927, 15737),
+    (0x5929, 21969), (0x592a, 22543), (0x592b, 17478), (0x592d, 19741), (0x592e, 18976), (0x5931, 18802), (0x5937, 20433), (0x593e, 23166),
+    (0x5944, 19177), (0x5947, 15189), (0x5948, 15500), (0x5949, 17454), (0x594e, 15110), (0x594f, 21395), (0x5950, 23482), (0x5951, 14433),
+    (0x5954, 17613), (0x5955, 23134), (0x5957, 22578), (0x595a, 23093), (0x5960, 20890), (0x5962, 17811), (0x5967, 19558), (0x596a, 22527),
+    (0x596b, 20271), (0x596c, 20680), (0x596d, 18156), (0x596e, 17614), (0x5973, 15504), (0x5974, 15516), (0x5978, 14009), (0x597d, 23306),
+    (0x5982, 19188), (0x5983, 17644), (0x5984, 16670), (0x598a, 20509), (0x5993, 15190), (0x5996, 19742), (0x5997, 15155), (0x5999, 16871),
+    (0x59a5, 22395), (0x59a8, 17205), (0x59ac, 22579), (0x59b9, 16682), (0x59bb, 21948), (0x59be, 22006), (0x59c3, 21044), (0x59c6, 16836),
+    (0x59c9, 20626), (0x59cb, 18738), (0x59d0, 20832), (0x59d1, 14555), (0x59d3, 18228), (0x59d4, 20090), (0x59d9, 20510), (0x59da, 19743),
+    (0x59dc, 14164), (0x59dd, 21390), (0x59e6, 14010), (0x59e8, 20434), (0x59ea, 21640), (0x59ec, 23734), (0x59ee, 22978), (0x59f8, 19318),
+    (0x59fb, 20481), (0x59ff, 20627), (0x5a01, 20091), (0x5a03, 19728), (0x5a11, 17812), (0x5a18, 15491), (0x5a1b, 19559), (0x5a1c, 15349),
+    (0x5a1f, 19319), (0x5a20, 18782), (0x5a25, 18921), (0x5a29, 16645), (0x5a36, 22330), (0x5a3c, 21817), (0x5a41, 16440), (0x5a46, 22587),
+    (0x5a49, 19703), (0x5a5a, 23345), (0x5a62, 17645), (0x5a66, 17479), (0x5a92, 16683), (0x5a9a, 17017), (0x5a9b, 20061), (0x5aa4, 18739),
+    (0x5ac1, 13975), (0x5ac2, 18424), (0x5ac4, 20062), (0x5ac9, 21641), (0x5acc, 23163), (0x5ae1, 20858), (0x5ae6, 22979), (0x5ae9, 15561),
+    (0x5b05, 23366), (0x5b09, 23735), (0x5b0b, 18174), (0x5b0c, 14792), (0x5b16, 22743), (0x5b2a, 17779), (0x5b40, 17992), (0x5b43, 19125),
+    (0x5b50, 20628), (0x5b51, 23159), (0x5b54, 14613), (0x5b55, 20621), (0x5b57, 20629), (0x5b58, 21262), (0x5b5a, 17576), (0x5b5c, 20630),
+    (0x5b5d, 23553), (0x5b5f, 16700), (0x5b63, 14434), (0x5b64, 14556), (0x5b69, 23094), (0x5b6b, 18396), (0x5b70, 18578), (0x5b71, 20662),
+    (0x5b75, 17577), (0x5b78, 22937), (0x5b7a, 20117), (0x5b7c, 19172), (0x5b85, 15750), (0x5b87, 19907), (0x5b88, 18425), (0x5b89, 18949),
+    (0x5b8b, 18403), (0x5b8c, 19704), (0x5b8f, 14784), (0x5b93, 17438), (0x5b95, 22536), (0x5b96, 23548), (0x5b97, 21268), (0x5b98, 14740),
+    (0x5b99, 21396), (0x5b9a, 21045), (0x5b9b, 19705), (0x5b9c, 20316), (0x5ba2, 14207), (0x5ba3, 18175), (0x5ba4, 18803), (0x5ba5, 20118),
+    (0x5ba6, 23483), (0x5bac, 18229), (0x5bae, 14979), (0x5bb0, 20810), (0x5bb3, 23095), (0x5bb4, 19320), (0x5bb5, 18353), (0x5bb6, 13976),
+    (0x5bb8, 18783), (0x5bb9, 19882), (0x5bbf, 18579), (0x5bc0, 21837), (0x5bc2, 20859), (0x5bc3, 20063), (0x5bc4, 15191), (0x5bc5, 20482),
+    (0x5bc6, 17048), (0x5bc7, 14923), (0x5bcc, 17578), (0x5bd0, 16684), (0x5bd2, 22941), (0x5bd3, 19908), (0x5bd4, 18764), (0x5bd7, 15513),
+    (0x5bde, 16637), (0x5bdf, 21799), (0x5be1, 14626), (0x5be2, 22379), (0x5be4, 19560), (0x5be5, 19744), (0x5be6, 18804), (0x5be7, 15512),
+    (0x5be8, 21838), (0x5be9, 18806), (0x5beb, 17813), (0x5bec, 14741), (0x5bee, 16332), (0x5bef, 21432), (0x5bf5, 22168), (0x5bf6, 17421),
+    (0x5bf8, 22162), (0x5bfa, 17814), (0x5c01, 17455), (0x5c04, 17815), (0x5c07, 20681), (0x5c08, 20891), (0x5c09, 20092), (0x5c0a, 21263),
+    (0x5c0b, 18807), (0x5c0d, 15738), (0x5c0e, 15759), (0x5c0f, 18354), (0x5c11, 18355), (0x5c16, 21996), (0x5c19, 17993), (0x5c24, 19909),
+    (0x5c28, 17206), (0x5c31, 22331), (0x5c38, 18740), (0x5c39, 20272), (0x5c3a, 21954), (0x5c3b, 14557), (0x5c3c, 15574), (0x5c3e, 17018),
+    (0x5c3f, 15552), (0x5c40, 14964), (0x5c45, 14215), (0x5c46, 14435), (0x5c48, 14976), (0x5c4b, 19674), (0x5c4d, 18742), (0x5c4e, 18741),
+    (0x5c51, 18203), (0x5c55, 20892), (0x5c5b, 17404), (0x5c60, 15760), (0x5c62, 16441), (0x5c64, 22346), (0x5c65, 16494), (0x5c6c, 18388),
+    (0x5c6f, 15939), (0x5c71, 17958), (0x5c79, 23718), (0x5c90, 15192), (0x5c91, 20667), (0x5ca1, 14165), (0x5ca9, 18964), (0x5cab, 18426),
+    (0x5cac, 14157), (0x5cb1, 15739), (0x5cb3, 18936), (0x5cb5, 23307), (0x5cb7, 17035), (0x5cb8, 18950), (0x5cba, 16270), (0x5cbe, 21027),
+    (0x5cc0, 18427), (0x5cd9, 22350), (0x5ce0, 17994), (0x5ce8, 18922), (0x5cef, 17456), (0x5cf0, 17457), (0x5cf4, 23139), (0x5cf6, 15761),
+    (0x5cfb, 21433), (0x5cfd, 23167), (0x5d07, 18618), (0x5d0d, 16102), (0x5d0e, 15193), (0x5d11, 14598), (0x5d14, 22178), (0x5d16, 18985),
+    (0x5d17, 14166), (0x5d19, 16471), (0x5d27, 18619), (0x5d29, 17633), (0x5d4b, 17019), (0x5d4c, 14043), (0x5d50, 16080), (0x5d69, 18716),
+    (0x5d6c, 19732), (0x5d6f, 21766), (0x5d87, 14924), (0x5d8b, 15762), (0x5d9d, 15946), (0x5da0, 14793), (0x5da2, 19747), (0x5daa, 19180),
+    (0x5db8, 19489), (0x5dba, 16271), (0x5dbc, 18031), (0x5dbd, 18937), (0x5dcd, 19733), (0x5dd2, 16646), (0x5dd6, 18965), (0x5ddd, 21970),
+    (0x5dde, 21397), (0x5de1, 18587), (0x5de2, 18356), (0x5de5, 14614), (0x5de6, 21382), (0x5de7, 14794), (0x5de8, 14216), (0x5deb, 16882),
+    (0x5dee, 21767), (0x5df1, 15194), (0x5df2, 20436), (0x5df3, 17816), (0x5df4, 22588), (0x5df7, 22980), (0x5dfd, 18397), (0x5dfe, 14233),
+    (0x5e02, 18743), (0x5e03, 22759), (0x5e06, 17273), (0x5e0c, 23736), (0x5e11, 22537), (0x5e16, 22007), (0x5e19, 21642), (0x5e1b, 17252),
+    (0x5e1d, 21193), (0x5e25, 18428), (0x5e2b, 17817), (0x5e2d, 18157), (0x5e33, 20682), (0x5e36, 15740), (0x5e38, 17995), (0x5e3d, 16837),
+    (0x5e3f, 23667), (0x5e40, 21046), (0x5e44, 18938), (0x5e45, 22780), (0x5e47, 17207), (0x5e4c, 23503), (0x5e55, 16638), (0x5e5f, 22351),
+    (0x5e61, 17258), (0x5e62, 15726), (0x5e63, 22744), (0x5e72, 14011), (0x5e73, 22738), (0x5e74, 15505), (0x5e77, 17405), (0x5e78, 23110),
+    (0x5e79, 14012), (0x5e7b, 23484), (0x5e7c, 20119), (0x5e7d, 20120), (0x5e7e, 15195), (0x5e84, 20683), (0x5e87, 17646), (0x5e8a, 17996),
+    (0x5e8f, 18032), (0x5e95, 20833), (0x5e97, 21028), (0x5e9a, 14395), (0x5e9c, 17579), (0x5ea0, 17997), (0x5ea6, 15763), (0x5ea7, 21383),
+    (0x5eab, 14558), (0x5ead, 21047), (0x5eb5, 18966), (0x5eb6, 18033), (0x5eb7, 14167), (0x5eb8, 19883), (0x5ebe, 20121), (0x5ec2, 17998),
+    (0x5ec8, 22926), (0x5ec9, 16260), (0x5eca, 16093), (0x5ed0, 14925), (0x5ed3, 14734), (0x5ed6, 16333), (0x5eda, 21398), (0x5edb, 20893),
+    (0x5edf, 16872), (0x5ee0, 21818), (0x5ee2, 22745), (0x5ee3, 14763), (0x5eec, 16125), (0x5ef3, 22015), (0x5ef6, 19322), (0x5ef7, 21048),
+    (0x5efa, 14234), (0x5efb, 23528), (0x5f01, 17392), (0x5f04, 16316), (0x5f0a, 22746), (0x5f0f, 18765), (0x5f11, 18744), (0x5f13, 14980),
+    (0x5f14, 21217), (0x5f15, 20483), (0x5f17, 17630), (0x5f18, 23355), (0x5f1b, 20437), (0x5f1f, 21194), (0x5f26, 23140), (0x5f27, 23308),
+    (0x5f29, 15517), (0x5f31, 19111), (0x5f35, 20684), (0x5f3a, 14168), (0x5f3c, 22911), (0x5f48, 22520), (0x5f4a, 14169), (0x5f4c, 17020),
+    (0x5f4e, 16647), (0x5f56, 15682), (0x5f57, 23293), (0x5f59, 23691), (0x5f5b, 20438), (0x5f62, 23276), (0x5f66, 19168), (0x5f67, 19933),
+    (0x5f69, 21839), (0x5f6a, 22788), (0x5f6b, 21218), (0x5f6c, 17780), (0x5f6d, 22721), (0x5f70, 21819), (0x5f71, 19490), (0x5f77, 17208),
+    (0x5f79, 19306), (0x5f7c, 22903), (0x5f7f, 17631), (0x5f80, 19722), (0x5f81, 21049), (0x5f85, 15741), (0x5f87, 18588), (0x5f8a, 23529),
+    (0x5f8b, 16475), (0x5f8c, 23668), (0x5f90, 18034), (0x5f91, 14396), (0x5f92, 15764), (0x5f97, 15945), (0x5f98, 17234), (0x5f99, 17818),
+    (0x5f9e, 21269), (0x5fa0, 16103), (0x5fa1, 19152), (0x5fa8, 23504), (0x5fa9, 17439), (0x5faa, 18589), (0x5fae, 17021), (0x5fb5, 21758),
+    (0x5fb7, 15751), (0x5fb9, 21988), (0x5fbd, 23692), (0x5fc3, 18808), (0x5fc5, 22912), (0x5fcc, 15196), (0x5fcd, 20484), (0x5fd6, 22163),
+    (0x5fd7, 21569), (0x5fd8, 16671), (0x5fd9, 16672), (0x5fe0, 22222), (0x5feb, 22390), (0x5ff5, 15508), (0x5ffd, 23351), (0x5fff, 17615),
+    (0x600f, 18977), (0x6012, 15518), (0x6016, 22760), (0x601c, 16272), (0x601d, 17819), (0x6020, 22544), (0x6021, 20439), (0x6025, 15170),
+    (0x6027, 18230), (0x6028, 20064), (0x602a, 14779), (0x602f, 14350), (0x6041, 20511), (0x6042, 18590), (0x6043, 18745), (0x604d, 23505),
+    (0x6050, 14615), (0x6052, 22981), (0x6055, 18035), (0x6059, 19126), (0x605d, 14756), (0x6062, 23530), (0x6063, 20631), (0x6064, 23704),
+    (0x6065, 22352), (0x6068, 22942), (0x6069, 20293), (0x606a, 13998), (0x606c, 15509), (0x606d, 14616), (0x606f, 18766), (0x6070, 23725),
+    (0x6085, 19362), (0x6089, 18805), (0x608c, 21195), (0x608d, 22943), (0x6094, 23531), (0x6096, 22712), (0x609a, 18404), (0x609b, 20894),
+    (0x609f, 19561), (0x60a0, 20122), (0x60a3, 23485), (0x60a4, 22169), (0x60a7, 16495), (0x60b0, 21270), (0x60b2, 17647), (0x60b3, 15752),
+    (0x60b4, 22227), (0x60b6, 17036), (0x60b8, 14436), (0x60bc, 15765), (0x60bd, 21949), (0x60c5, 21050), (0x60c7, 15900), (0x60d1, 23342),
+    (0x60da, 23352), (0x60dc, 18158), (0x60df, 20123), (0x60e0, 23294), (0x60e1, 18939), (0x60f0, 22396), (0x60f1, 15546), (0x60f3, 17999),
+    (0x60f6, 23506), (0x60f9, 19104), (0x60fa, 18231), (0x60fb, 22344), (0x6101, 18429), (0x6106, 14235), (0x6108, 20124), (0x6109, 20125),
+    (0x610d, 17037), (0x610e, 22725), (0x610f, 20317), (0x6115, 18940), (0x611a, 19910), (0x611b, 18986), (0x611f, 14044), (0x6127, 14780),
+    (0x6130, 23507), (0x6134, 21820), (0x6137, 14191), (0x613c, 18784), (0x613e, 14192), (0x613f, 20065), (0x6142, 19884), (0x6144, 16476),
+    (0x6147, 20294), (0x6148, 20632), (0x614a, 14380), (0x614b, 22545), (0x614c, 23508), (0x6153, 22789), (0x6155, 16838), (0x6158, 21806),
+    (0x6159, 21807), (0x615d, 22583), (0x615f, 22564), (0x6162, 16648), (0x6163, 14742), (0x6164, 13999), (0x6167, 23295), (0x6168, 14193),
+    (0x616b, 21271), (0x616e, 16126), (0x6170, 20093), (0x6176, 14397), (0x6177, 14170), (0x617d, 21955), (0x617e, 19870), (0x6181, 22170),
+    (0x6182, 19911), (0x618a, 17648), (0x618e, 21458), (0x6190, 16146), (0x6191, 17792), (0x6194, 22036), (0x6198, 23738), (0x6199, 23737),
+    (0x619a, 22521), (0x61a4, 17616), (0x61a7, 15916), (0x61a9, 14353), (0x61ab, 17038), (0x61ac, 14398), (0x61ae, 16883), (0x61b2, 23127),
+    (0x61b6, 19162), (0x61ba, 15703), (0x61be, 14045), (0x61c3, 15141), (0x61c7, 14013), (0x61c8, 23096), (0x61c9, 20310), (0x61ca, 19563),
+    (0x61cb, 16884), (0x61e6, 15350), (0x61f2, 21759), (0x61f6, 15956), (0x61f7, 23532), (0x61f8, 23141), (0x61fa, 21808), (0x61fc, 14926),
+    (0x61ff, 20318), (0x6200, 16147), (0x6207, 15727), (0x6208, 14627), (0x620a, 16885), (0x620c, 18614), (0x620d, 18526), (0x620e, 20287),
+    (0x6210, 18232), (0x6211, 18923), (0x6212, 14437), (0x6216, 23343), (0x621a, 21956), (0x621f, 15134), (0x6221, 14046), (0x622a, 21019),
+    (0x622e, 16467), (0x6230, 20895), (0x6231, 23739), (0x6234, 15742), (0x6236, 23309), (0x623e, 16127), (0x623f, 17209), (0x6240, 18357),
+    (0x6241, 22728), (0x6247, 18176), (0x6248, 23310), (0x6249, 17649), (0x624b, 18527), (0x624d, 20811), (0x6253, 22397), (0x6258, 22410),
+    (0x626e, 17617), (0x6271, 15171), (0x6276, 17581), (0x6279, 17650), (0x627c, 18994), (0x627f, 18730), (0x6280, 15197), (0x6284, 22037),
+    (0x6289, 14373), (0x628a, 22589), (0x6291, 19163), (0x6292, 18036), (0x6295, 22580), (0x6297, 22982), (0x6298, 21020), (0x629b, 22761),
+    (0x62ab, 22904), (0x62b1, 22762), (0x62b5, 20834), (0x62b9, 16663), (0x62bc, 18972), (0x62bd, 22181), (0x62c2, 17632), (0x62c7, 16886),
+    (0x62c8, 15510), (0x62c9, 16090), (0x62cc, 17074), (0x62cd, 17053), (0x62cf, 15352), (0x62d0, 14781), (0x62d2, 14217), (0x62d3, 21957),
+    (0x62d4, 17096), (0x62d6, 22398), (0x62d7, 19748), (0x62d8, 14927), (0x62d9, 21265), (0x62db, 22038), (0x62dc, 17235), (0x62ec, 14757),
+    (0x62ed, 18767), (0x62ee, 15342), (0x62ef, 21460), (0x62f1, 14617), (0x62f3, 14990), (0x62f7, 14559), (0x62fe, 18721), (0x62ff, 15353),
+    (0x6301, 21570), (0x6307, 21571), (0x6309, 18951), (0x6311, 15766), (0x632b, 21384), (0x632f, 21606), (0x633a, 21051), (0x633b, 19326),
+    (0x633d, 16649), (0x633e, 23168), (0x6349, 21776), (0x634c, 22709), (0x634f, 15378), (0x6350, 19325), (0x6355, 22763), (0x6367, 17458),
+    (0x6368, 17820), (0x636e, 14218), (0x6372, 14991), (0x6377, 22008), (0x637a, 15379), (0x637b, 15511), (0x637f, 18037), (0x6383, 18358),
+    (0x6388, 18528), (0x6389, 15767), (0x638c, 20685), (0x6392, 17236), (0x6396, 18995), (0x6398, 14977), (0x639b, 14773), (0x63a0, 16106),
+    (0x63a1, 21840), (0x63a2, 22529), (0x63a5, 21035), (0x63a7, 14618), (0x63a8, 22182), (0x63a9, 19178), (0x63aa, 21219), (0x63c0, 14014),
+    (0x63c4, 20126), (0x63c6, 15111), (0x63cf, 16873), (0x63d0, 21196), (0x63d6, 20306), (0x63da, 19127), (0x63db, 23486), (0x63e1, 18941),
+    (0x63ed, 14354), (0x63ee, 23693), (0x63f4, 20066), (0x63f6, 19105), (0x63f7, 17981), (0x640d, 18398), (0x640f, 17054), (0x6414, 18359),
+    (0x6416, 19749), (0x6417, 15768), (0x641c, 18529), (0x6422, 21607), (0x642c, 17075), (0x642d, 22534), (0x643a, 23700), (0x643e, 21777),
+    (0x6458, 20860), (0x6460, 22171), (0x6469, 16629), (0x646f, 21572), (0x6478, 16839), (0x6479, 16840), (0x647a, 21036), (0x6488, 16291),
+    (0x6491, 22558), (0x6492, 17969), (0x6493, 19750), (0x649a, 15506), (0x649e, 15728), (0x64a4, 21989), (0x64a5, 17097), (0x64ab, 16887),
+    (0x64ad, 22590), (0x64ae, 22176), (0x64b0, 21783), (0x64b2, 17055), (0x64bb, 15696), (0x64c1, 19687), (0x64c4, 16292), (0x64c5, 21971),
+    (0x64c7, 22556), (0x64ca, 14355), (0x64cd, 21220), (0x64ce, 14399), (0x64d2, 15156), (0x64d4, 15704), (0x64d8, 17286), (0x64da, 14219),
+    (0x64e1, 15743), (0x64e2, 22412), (0x64e5, 16081), (0x64e6, 21800), (0x64e7, 14220), (0x64ec, 20319), (0x64f2, 21958), (0x64f4, 23475),
+    (0x64fa, 22591), (0x64fe, 19751), (0x6500, 17076), (0x6504, 22559), (0x6518, 19128), (0x651d, 18223), (0x6523, 16148), (0x652a, 14795),
+    (0x652b, 23476), (0x652c, 16082), (0x652f, 21573), (0x6536, 18530), (0x6537, 14560), (0x6538, 20127), (0x6539, 14194), (0x653b, 14619),
+    (0x653e, 17210), (0x653f, 21052), (0x6545, 14561), (0x6548, 23554), (0x654d, 18038), (0x654e, 14796), (0x654f, 17039), (0x6551, 14928),
+    (0x6556, 19564), (0x6557, 22713), (0x655e, 21821), (0x6562, 14047), (0x6563, 17959), (0x6566, 15901), (0x656c, 14400), (0x656d, 19129),
+    (0x6572, 14562), (0x6574, 21053), (0x6575, 20861), (0x6577, 17582), (0x6578, 18531), (0x657e, 18177), (0x6582, 16261), (0x6583, 22747),
+    (0x6585, 23555), (0x6587, 16909), (0x658c, 17781), (0x6590, 17651), (0x6591, 17077), (0x6597, 15929), (0x6599, 16334), (0x659b, 14590),
+    (0x659c, 17821), (0x659f, 21653), (0x65a1, 18959), (0x65a4, 15142), (0x65a5, 21959), (0x65a7, 17583), (0x65ab, 20652), (0x65ac, 21809),
+    (0x65af, 17822), (0x65b0, 18785), (0x65b7, 15683), (0x65b9, 17211), (0x65bc, 19153), (0x65bd, 18746), (0x65c1, 17212), (0x65c5, 16128),
+    (0x65cb, 18178), (0x65cc, 21054), (0x65cf, 21258), (0x65d2, 16453), (0x65d7, 15198), (0x65e0, 16888), (0x65e3, 15199), (0x65e5, 20502),
+    (0x65e6, 15684), (0x65e8, 21574), (0x65e9, 21221), (0x65ec, 18591), (0x65ed, 19934), (0x65f1, 22944), (0x65f4, 19912), (0x65fa, 19723),
+    (0x65fb, 17040), (0x65fc, 17041), (0x65fd, 15902), (0x65ff, 19565), (0x6606, 14599), (0x6607, 18731), (0x6609, 17213), (0x660a, 23311),
+    (0x660c, 21822), (0x660e, 16820), (0x660f, 23346), (0x6610, 17618), (0x6611, 15157), (0x6613, 19307), (0x6614, 18159), (0x6615, 23713),
+    (0x661e, 17406), (0x661f, 18233), (0x6620, 19492), (0x6625, 22215), (0x6627, 16685), (0x6628, 20653), (0x662d, 18360), (0x662f, 18747),
+    (0x6630, 22927), (0x6631, 19935), (0x6634, 16874), (0x6636, 21823), (0x663a, 17407), (0x663b, 18978), (0x6641, 21222), (0x6642, 18748),
+    (0x6643, 23509), (0x6644, 23510), (0x6649, 21608), (0x664b, 21609), (0x664f, 18952), (0x6659, 21434), (0x665b, 23142), (0x665d, 21399),
+    (0x665e, 23740), (0x665f, 18234), (0x6664, 19566), (0x6665, 23488), (0x6666, 23533), (0x6667, 23312), (0x6668, 18786), (0x6669, 16650),
+    (0x666b, 22413), (0x666e, 17422), (0x666f, 14401), (0x6673, 18160), (0x6674, 22016), (0x6676, 21055), (0x6677, 15007), (0x6678, 21056),
+    (0x667a, 21575), (0x6684, 23685), (0x6687, 13977), (0x6688, 23682), (0x6689, 23694), (0x668e, 19493), (0x6690, 20094), (0x6691, 18039),
+    (0x6696, 15371), (0x6697, 18967), (0x6698, 19130), (0x669d, 16821), (0x66a0, 14563), (0x66a2, 21824), (0x66ab, 20668), (0x66ae, 16841),
+    (0x66b2, 20686), (0x66b3, 23296), (0x66b4, 22781), (0x66b9, 18216), (0x66bb, 14402), (0x66be, 15903), (0x66c4, 19480), (0x66c6, 16140),
+    (0x66c7, 15705), (0x66c9, 23556), (0x66d6, 18987), (0x66d9, 18040), (0x66dc, 19753), (0x66dd, 22782), (0x66e0, 14764), (0x66e6, 23741),
+    (0x66f0, 19721), (0x66f2, 14591), (0x66f3, 19529), (0x66f4, 14403), (0x66f7, 14032), (0x66f8, 18041), (0x66f9, 21224), (0x66fa, 21223),
+    (0x66fc, 16651), (0x66fe, 21459), (0x66ff, 22025), (0x6700, 22179), (0x6703, 23534), (0x6708, 20082), (0x6709, 20128), (0x670b, 17634),
+    (0x670d, 17440), (0x6714, 17858), (0x6715, 21654), (0x6717, 16094), (0x671b, 16673), (0x671d, 21225), (0x671e, 15296), (0x671f, 15297),
+    (0x6726, 16867), (0x6727, 16317), (0x6728, 16857), (0x672a, 17022), (0x672b, 16664), (0x672c, 17451), (0x672d, 21801), (0x672e, 22219),
+    (0x6731, 21400), (0x6734, 17056), (0x6736, 22399), (0x673a, 15000), (0x673d, 23669), (0x6746, 14015), (0x6749, 17975), (0x674e, 16496),
+    (0x674f, 23111), (0x6750, 20812), (0x6751, 22164), (0x6753, 22790), (0x6756, 20687), (0x675c, 15930), (0x675e, 15298), (0x675f, 18389),
+    (0x676d, 22983), (0x676f, 17237), (0x6770, 14244), (0x6771, 15917), (0x6773, 16875), (0x6775, 20835), (0x6777, 22592), (0x677b, 15563),
+    (0x677e, 18405), (0x677f, 22604), (0x6787, 17652), (0x6789, 19724), (0x678b, 17214), (0x678f, 15382), (0x6790, 18161), (0x6793, 15931),
+    (0x6795, 22380), (0x6797, 16524), (0x679a, 16686), (0x679c, 14628), (0x679d, 21576), (0x67af, 14564), (0x67b0, 22739), (0x67b3, 21577),
+    (0x67b6, 13978), (0x67b7, 13979), (0x67b8, 14929), (0x67be, 18749), (0x67c4, 17408), (0x67cf, 17253), (0x67d0, 16842), (0x67d1, 14048),
+    (0x67d2, 22376), (0x67d3, 19373), (0x67d4, 20130), (0x67da, 20131), (0x67dd, 22414), (0x67e9, 14930), (0x67ec, 14016), (0x67ef, 13980),
+    (0x67f0, 15501), (0x67f1, 21401), (0x67f3, 16454), (0x67f4, 18750), (0x67f5, 21848), (0x67f6, 17823), (0x67fb, 17824), (0x67fe, 21057),
+    (0x6812, 18592), (0x6813, 20896), (0x6816, 18042), (0x6817, 16477), (0x6821, 14797), (0x6822, 17254), (0x682a, 21402), (0x682f, 19936),
+    (0x6838, 23108), (0x6839, 15143), (0x683c, 14356), (0x683d, 20813), (0x6840, 14245), (0x6841, 22984), (0x6842, 14438), (0x6843, 15769),
+    (0x6848, 18953), (0x684e, 21643), (0x6850, 15918), (0x6851, 18000), (0x6853, 23489), (0x6854, 15343), (0x686d, 21610), (0x6876, 22565),
+    (0x687f, 14017), (0x6881, 16112), (0x6885, 16687), (0x688f, 14592), (0x6893, 20814), (0x6894, 22353), (0x6897, 14404), (0x689d, 21226),
+    (0x689f, 23557), (0x68a1, 19706), (0x68a2, 22039), (0x68a7, 19567), (0x68a8, 16497), (0x68ad, 17825), (0x68af, 21197), (0x68b0, 14439),
+    (0x68b1, 14600), (0x68b3, 18361), (0x68b5, 17274), (0x68b6, 17023), (0x68c4, 15300), (0x68c5, 17409), (0x68c9, 16711), (0x68cb, 15299),
+    (0x68cd, 14601), (0x68d2, 17459), (0x68d5, 21272), (0x68d7, 21227), (0x68d8, 15135), (0x68da, 17635), (0x68df, 15919), (0x68e0, 15729),
+    (0x68e7, 20663), (0x68e8, 14536), (0x68ee, 17976), (0x68f2, 18043), (0x68f9, 15866), (0x68fa, 14743), (0x6900, 19707), (0x6905, 20320),
+    (0x690d, 18768), (0x690e, 22183), (0x6912, 22136), (0x6927, 16822), (0x6930, 19106), (0x693d, 19328), (0x693f, 22216), (0x694a, 19132),
+    (0x6953, 22898), (0x6954, 18204), (0x6955, 22400), (0x6957, 14236), (0x6959, 16889), (0x695a, 22137), (0x695e, 16484), (0x6960, 15383),
+    (0x6961, 20133), (0x6962, 20134), (0x6963, 17024), (0x6968, 21058), (0x696b, 21454), (0x696d, 19181), (0x696e, 20836), (0x696f, 18593),
+    (0x6975, 15136), (0x6977, 23097), (0x6978, 22184), (0x6979, 19494), (0x6995, 19885), (0x699b, 21611), (0x699c, 17215), (0x69a5, 23511),
+    (0x69a7, 17653), (0x69ae, 19495), (0x69b4, 16455), (0x69bb, 22535), (0x69c1, 14565), (0x69c3, 17078), (0x69cb, 14931), (0x69cc, 22572),
+    (0x69cd, 21825), (0x69d0, 14782), (0x69e8, 14735), (0x69ea, 14195), (0x69fb, 15112), (0x69fd, 21228), (0x69ff, 15144), (0x6a02, 18942),
+    (0x6a0a, 17259), (0x6a11, 16113), (0x6a13, 16442), (0x6a17, 20837), (0x6a19, 22791), (0x6a1e, 22185), (0x6a1f, 20688), (0x6a21, 16843),
+    (0x6a23, 19133), (0x6a35, 22138), (0x6a38, 17057), (0x6a39, 18532), (0x6a3a, 23367), (0x6a3d, 21435), (0x6a44, 14049), (0x6a48, 19755),
+    (0x6a4b, 14798), (0x6a52, 19943), (0x6a53, 18594), (0x6a58, 15130), (0x6a59, 15947), (0x6a5f, 15301), (0x6a61, 18001), (0x6a6b, 23549),
+    (0x6a80, 15685), (0x6a84, 14357), (0x6a89, 21059), (0x6a8d, 19164), (0x6a8e, 15158), (0x6a97, 17287), (0x6a9c, 23535), (0x6aa2, 14249),
+    (0x6aa3, 20689), (0x6ab3, 17782), (0x6abb, 22962), (0x6ac2, 15867), (0x6ac3, 15001), (0x6ad3, 16293), (0x6ada, 16129), (0x6adb, 21453),
+    (0x6af6, 23128), (0x6afb, 19096), (0x6b04, 16072), (0x6b0a, 14992), (0x6b0c, 20690), (0x6b12, 16073), (0x6b16, 16083), (0x6b20, 23721),
+    (0x6b21, 21768), (0x6b23, 23714), (0x6b32, 19871), (0x6b3a, 15302), (0x6b3d, 23722), (0x6b3e, 14744), (0x6b46, 23723), (0x6b47, 23131),
+    (0x6b4c, 13981), (0x6b4e, 22522), (0x6b50, 14932), (0x6b5f, 19287), (0x6b61, 23487), (0x6b62, 21578), (0x6b63, 21060), (0x6b64, 21769),
+    (0x6b65, 17423), (0x6b66, 16890), (0x6b6a, 19729), (0x6b72, 18343), (0x6b77, 16141), (0x6b78, 15008), (0x6b7b, 17826), (0x6b7f, 16864),
+    (0x6b83, 18979), (0x6b84, 21612), (0x6b86, 22546), (0x6b89, 18595), (0x6b8a, 18533), (0x6b96, 18769), (0x6b98, 20664), (0x6b9e, 19944),
+    (0x6bae, 16262), (0x6baf, 17783), (0x6bb2, 18217), (0x6bb5, 15686), (0x6bb7, 20295), (0x6bba, 17970), (0x6bbc, 14000), (0x6bbf, 20897),
+    (0x6bc1, 23690), (0x6bc5, 20321), (0x6bc6, 14933), (0x6bcb, 16891), (0x6bcd, 16844), (0x6bcf, 16688), (0x6bd2, 15889), (0x6bd3, 20265),
+    (0x6bd4, 17654), (0x6bd6, 17655), (0x6bd7, 17656), (0x6bd8, 17657), (0x6bdb, 16845), (0x6beb, 23313), (0x6bec, 14934), (0x6c08, 20898),
+    (0x6c0f, 18916), (0x6c11, 17042), (0x6c13, 16701), (0x6c23, 15303), (0x6c34, 18534), (0x6c37, 17793), (0x6c38, 19496), (0x6c3e, 17275),
+    (0x6c40, 21061), (0x6c41, 21455), (0x6c42, 14935), (0x6c4e, 17276), (0x6c50, 18162), (0x6c55, 17960), (0x6c57, 22945), (0x6c5a, 19568),
+    (0x6c5d, 19288), (0x6c5e, 23356), (0x6c5f, 14171), (0x6c60, 21579), (0x6c68, 14606), (0x6c6a, 19725), (0x6c6d, 19530), (0x6c70, 22547),
+    (0x6c72, 15172), (0x6c76, 17006), (0x6c7a, 14374), (0x6c7d, 15304), (0x6c7e, 17619), (0x6c81, 18809), (0x6c82, 15305), (0x6c83, 19675),
+    (0x6c85, 20067), (0x6c86, 22985), (0x6c87, 19329), (0x6c88, 22381), (0x6c8c, 15904), (0x6c90, 16858), (0x6c92, 16865), (0x6c93, 15718),
+    (0x6c94, 16712), (0x6c95, 17014), (0x6c96, 22223), (0x6c99, 17827), (0x6c9a, 21580), (0x6c9b, 22714), (0x6cab, 16665), (0x6cae, 20838),
+    (0x6cb3, 22928), (0x6cb8, 17658), (0x6cb9, 20135), (0x6cbb, 22354), (0x6cbc, 18362), (0x6cbd, 14566), (0x6cbe, 21997), (0x6cbf, 19330),
+    (0x6cc1, 23512), (0x6cc2, 23277), (0x6cc4, 18205), (0x6cc9, 21972), (0x6cca, 17058), (0x6ccc, 22913), (0x6cd3, 23357), (0x6cd5, 17281),
+    (0x6cd7, 17828), (0x6cdb, 17277), (0x6ce1, 22765), (0x6ce2, 22593), (0x6ce3, 20307), (0x6ce5, 15575), (0x6ce8, 21403), (0x6ceb, 23143),
+    (0x6cee, 17079), (0x6cef, 17043), (0x6cf0, 22548), (0x6cf3, 19497), (0x6d0b, 19134), (0x6d0c, 16257), (0x6d11, 17424), (0x6d17, 18344),
+    (0x6d19, 18535), (0x6d1b, 16061), (0x6d1e, 15920), (0x6d25, 21613), (0x6d27, 20136), (0x6d29, 18206), (0x6d2a, 23358), (0x6d32, 21404),
+    (0x6d35, 18596), (0x6d36, 23710), (0x6d38, 14765), (0x6d39, 20068), (0x6d3b, 23497), (0x6d3d, 23726), (0x6d3e, 22594), (0x6d41, 16456),
+    (0x6d59, 21021), (0x6d5a, 21436), (0x6d5c, 17784), (0x6d63, 19708), (0x6d66, 22766), (0x6d69, 23314), (0x6d6a, 16095), (0x6d6c, 16498),
+    (0x6d6e, 17584), (0x6d74, 19872), (0x6d77, 23098), (0x6d78, 22382), (0x6d79, 23169), (0x6d7f, 22715), (0x6d85, 19363), (0x6d87, 14405),
+    (0x6d88, 18363), (0x6d89, 18224), (0x6d8c, 19886), (0x6d8d, 23558), (0x6d8e, 19331), (0x6d91, 18390), (0x6d93, 19332), (0x6d95, 22026),
+    (0x6daf, 18988), (0x6db2, 18996), (0x6db5, 22963), (0x6dc0, 21062), (0x6dc3, 14993), (0x6dc4, 22355), (0x6dc5, 18163), (0x6dc6, 23559),
+    (0x6dc7, 15306), (0x6dcb, 16525), (0x6dcf, 23315), (0x6dd1, 18580), (0x6dd8, 15868), (0x6dd9, 21273), (0x6dda, 16443), (0x6dde, 18406),
+    (0x6de1, 15706), (0x6de8, 21063), (0x6dea, 16472), (0x6deb, 20301), (0x6dee, 23536), (0x6df1, 18907), (0x6df3, 18597), (0x6df5, 19333),
+    (0x6df7, 23347), (0x6df8, 22017), (0x6df9, 19179), (0x6dfa, 21973), (0x6dfb, 21998), (0x6e17, 17977), (0x6e19, 23490), (0x6e1a, 20839),
+    (0x6e1b, 14050), (0x6e1f, 21064), (0x6e20, 14221), (0x6e21, 15869), (0x6e23, 17829), (0x6e24, 17098), (0x6e25, 18943), (0x6e26, 19695),
+    (0x6e2b, 18207), (0x6e2c, 22345), (0x6e2d, 20095), (0x6e2f, 22986), (0x6e32, 18179), (0x6e34, 14033), (0x6e36, 19498), (0x6e38, 20138),
+    (0x6e3a, 16876), (0x6e3c, 17025), (0x6e3d, 20815), (0x6e3e, 23348), (0x6e43, 17238), (0x6e44, 17026), (0x6e4a, 21405), (0x6e4d, 15687),
+    (0x6e56, 23316), (0x6e58, 18002), (0x6e5b, 15707), (0x6e5c, 18770), (0x6e5e, 21065), (0x6e5f, 23513), (0x6e67, 19887), (0x6e6b, 22186),
+    (0x6e6e, 20485), (0x6e6f, 22538), (0x6e72, 20069), (0x6e73, 15384), (0x6e7a, 17425), (0x6e90, 20070), (0x6e96, 21437), (0x6e9c, 16457),
+    (0x6e9d, 14936), (0x6e9f, 16823), (0x6ea2, 20503), (0x6ea5, 17585), (0x6eaa, 14537), (0x6eab, 19679), (0x6eaf, 18364), (0x6eb1, 21614),
+    (0x6eb6, 19888), (0x6eba, 15577), (0x6ec2, 17216), (0x6ec4, 21826), (0x6ec5, 16719), (0x6ec9, 23514), (0x6ecb, 20633), (0x6ecc, 21960),
+    (0x6ece, 23278), (0x6ed1, 23498), (0x6ed3, 20816), (0x6ed4, 15870), (0x6eef, 22027), (0x6ef4, 20862), (0x6ef8, 23317), (0x6efe, 14602),
+    (0x6eff, 16652), (0x6f01, 19154), (0x6f02, 22792), (0x6f06, 22377), (0x6f0f, 16444), (0x6f11, 14196), (0x6f14, 19334), (0x6f15, 21229),
+    (0x6f20, 16639), (0x6f22, 22946), (0x6f23, 16149), (0x6f2b, 16653), (0x6f2c, 21581), (0x6f31, 18536), (0x6f32, 21827), (0x6f38, 21029),
+    (0x6f3f, 20691), (0x6f41, 19499), (0x6f51, 17099), (0x6f54, 14375), (0x6f57, 21657), (0x6f58, 17080), (0x6f5a, 18581), (0x6f5b, 20669),
+    (0x6f5e, 16294), (0x6f5f, 18164), (0x6f62, 23515), (0x6f64, 20275), (0x6f6d, 15708), (0x6f6e, 21230), (0x6f70, 15002), (0x6f7a, 20665),
+    (0x6f7c, 15921), (0x6f7d, 17426), (0x6f7e, 16516), (0x6f81, 17982), (0x6f84, 21760), (0x6f88, 21990), (0x6f8d, 21406), (0x6f8e, 22722),
+    (0x6f90, 19945), (0x6f94, 23318), (0x6f97, 14018), (0x6fa3, 22947), (0x6fa4, 22557), (0x6fa7, 16285), (0x6fae, 23537), (0x6faf, 21784),
+    (0x6fb1, 20899), (0x6fb3, 19569), (0x6fb9, 15709), (0x6fbe, 15697), (0x6fc0, 14358), (0x6fc1, 22415), (0x6fc2, 16263), (0x6fc3, 15541),
+    (0x6fca, 19531), (0x6fd5, 18720), (0x6fda, 19500), (0x6fdf, 21198), (0x6fe0, 23319), (0x6fe1, 20236), (0x6fe4, 15871), (0x6fe9, 23320),
+    (0x6feb, 16084), (0x6fec, 21438), (0x6fef, 22416), (0x6ff1, 17785), (0x6ffe, 16130), (0x7001, 19135), (0x7005, 23279), (0x7006, 15890),
+    (0x7009, 17830), (0x700b, 18908), (0x700f, 16458), (0x7011, 22783), (0x7015, 17786), (0x7018, 16295), (0x701a, 22948), (0x701b, 19501),
+    (0x701c, 20288), (0x701d, 16142), (0x701e, 21066), (0x701f, 18365), (0x7023, 23099), (0x7027, 16318), (0x7028, 16323), (0x702f, 19502),
+    (0x7037, 20468), (0x703e, 16074), (0x704c, 14745), (0x7050, 23280), (0x7051, 18413), (0x7058, 22523), (0x705d, 23321), (0x7063, 16654),
+    (0x706b, 23368), (0x7070, 23538), (0x7078, 14937), (0x707c, 20654), (0x707d, 20817), (0x7085, 14406), (0x708a, 22332), (0x708e, 19375),
+    (0x7092, 22139), (0x7098, 23715), (0x7099, 20634), (0x709a, 14766), (0x70a1, 21067), (0x70a4, 18366), (0x70ab, 23144), (0x70ac, 14222),
+    (0x70ad, 22524), (0x70af, 23281), (0x70b3, 17410), (0x70b7, 21407), (0x70b8, 20655), (0x70b9, 21030), (0x70c8, 16258), (0x70cb, 23701),
+    (0x70cf, 19666), (0x70d8, 23359), (0x70d9, 16062), (0x70dd, 21461), (0x70df, 19336), (0x70f1, 14407), (0x70f9, 22723), (0x70fd, 17460),
+    (0x7104, 23677), (0x7109, 19169), (0x710c, 21439), (0x7119, 17239), (0x711a, 17620), (0x711e, 15905), (0x7121, 16892), (0x7126, 22140),
+    (0x7130, 19376), (0x7136, 19337), (0x7147, 23695), (0x7149, 16246), (0x714a, 23686), (0x714c, 23516), (0x714e, 20996), (0x7150, 19503),
+    (0x7156, 15373), (0x7159, 19338), (0x715c, 19937), (0x715e, 17971), (0x7164, 16689), (0x7165, 23491), (0x7166, 23670), (0x7167, 21231),
+    (0x7169, 17260), (0x716c, 19136), (0x716e, 20635), (0x717d, 18180), (0x7184, 18771), (0x7189, 19946), (0x718a, 20053), (0x718f, 23678),
+    (0x7192, 23282), (0x7194, 19889), (0x7199, 23742), (0x719f, 18582), (0x71a2, 17461), (0x71ac, 19667), (0x71b1, 19365), (0x71b9, 23743),
+    (0x71ba, 23744), (0x71be, 22356), (0x71c1, 19482), (0x71c3, 19340), (0x71c8, 15948), (0x71c9, 15906), (0x71ce, 16335), (0x71d0, 16517),
+    (0x71d2, 18367), (0x71d4, 17261), (0x71d5, 19341), (0x71df, 19504), (0x71e5, 21232), (0x71e6, 21785), (0x71e7, 18537), (0x71ed, 22158),
+    (0x71ee, 18225), (0x71fb, 23679), (0x71fc, 18787), (0x71fe, 15872), (0x71ff, 19757), (0x7200, 23135), (0x7206, 22784), (0x7210, 16296),
+    (0x721b, 16075), (0x722a, 21233), (0x722c, 22595), (0x722d, 20824), (0x7230, 20071), (0x7232, 20096), (0x7235, 20656), (0x7236, 17586),
+    (0x723a, 19107), (0x723b, 23656), (0x723d, 18003), (0x723e, 20444), (0x7240, 18004), (0x7246, 20692), (0x7247, 22729), (0x7248, 22605),
+    (0x724c, 22716), (0x7252, 22009), (0x7258, 15891), (0x7259, 18924), (0x725b, 19913), (0x725d, 17787), (0x725f, 16846), (0x7261, 16847),
+    (0x7262, 16324), (0x7267, 16859), (0x7269, 17015), (0x7272, 18024), (0x7279, 22584), (0x727d, 14363), (0x7280, 18044), (0x7281, 16499),
+    (0x72a2, 15892), (0x72a7, 23745), (0x72ac, 14364), (0x72af, 17278), (0x72c0, 18005), (0x72c2, 14767), (0x72c4, 20863), (0x72ce, 18973),
+    (0x72d0, 23322), (0x72d7, 14938), (0x72d9, 20840), (0x72e1, 14799), (0x72e9, 18538), (0x72f8, 16500), (0x72f9, 23170), (0x72fc, 16096),
+    (0x72fd, 22717), (0x730a, 19532), (0x7316, 21828), (0x731b, 16702), (0x731c, 18751), (0x731d, 21266), (0x7325, 19734), (0x7329, 18235),
+    (0x732a, 20841), (0x732b, 16877), (0x7336, 20237), (0x7337, 20238), (0x733e, 23499), (0x733f, 20072), (0x7344, 19676), (0x7345, 17831),
+    (0x7350, 20694), (0x7352, 19668), (0x7357, 14996), (0x7368, 15893), (0x736a, 23539), (0x7370, 19505), (0x7372, 23547), (0x7375, 16265),
+    (0x7378, 18539), (0x737a, 15698), (0x737b, 23129), (0x7384, 23145), (0x7386, 20636), (0x7387, 18402), (0x7389, 19677), (0x738b, 19726),
+    (0x738e, 21068), (0x7394, 21974), (0x7396, 14939), (0x7397, 19914), (0x7398, 15307), (0x739f, 17044), (0x73a7, 20276), (0x73a9, 19709),
+    (0x73ad, 17788), (0x73b2, 16273), (0x73b3, 15744), (0x73b9, 23146), (0x73c0, 17059), (0x73c2, 13982), (0x73c9, 17045), (0x73ca, 17961),
+    (0x73cc, 22914), (0x73cd, 21615), (0x73cf, 14001), (0x73d6, 14768), (0x73d9, 14620), (0x73dd, 23671), (0x73de, 16063), (0x73e0, 21408),
+    (0x73e3, 18598), (0x73e4, 17427), (0x73e5, 20445), (0x73e6, 23117), (0x73e9, 23283), (0x73ea, 15113), (0x73ed, 17081), (0x73f7, 16893),
+    (0x73f9, 18236), (0x73fd, 21069), (0x73fe, 23147), (0x7401, 18181), (0x7403, 14940), (0x7405, 16097), (0x7406, 16501), (0x7407, 18540),
+    (0x7409, 16459), (0x7413, 19710), (0x741b, 22383), (0x7420, 20997), (0x7421, 18583), (0x7422, 22417), (0x7425, 23323), (0x7426, 15308),
+    (0x7428, 14603), (0x742a, 15309), (0x742b, 17462), (0x742c, 19711), (0x742e, 21274), (0x742f, 14746), (0x7430, 19377), (0x7433, 16526),
+    (0x7434, 15159), (0x7435, 17660), (0x7436, 22596), (0x7438, 22418), (0x743a, 17282), (0x743f, 23349), (0x7440, 19915), (0x7441, 16848),
+    (0x7443, 22217), (0x7444, 18182), (0x744b, 20097), (0x7455, 22929), (0x7457, 20073), (0x7459, 15522), (0x745a, 23324), (0x745b, 19507),
+    (0x745c, 20240), (0x745e, 18045), (0x745f, 18717), (0x7460, 16460), (0x7462, 19890), (0x7464, 19758), (0x7465, 19680), (0x7468, 21616),
+    (0x7469, 23284), (0x746a, 16630), (0x746f, 16098), (0x747e, 15145), (0x7482, 15310), (0x7483, 16502), (0x7487, 18183), (0x7489, 16247),
+    (0x748b, 20695), (0x7498, 16518), (0x749c, 23517), (0x749e, 17060), (0x749f, 14408), (0x74a1, 21617), (0x74a3, 15311), (0x74a5, 14409),
+    (0x74a7, 17288), (0x74a8, 21786), (0x74aa, 21234), (0x74b0, 23492), (0x74b2, 18541), (0x74b5, 19290), (0x74b9, 18584), (0x74bd, 18017),
+    (0x74bf, 18184), (0x74c6, 21644), (0x74ca, 14410), (0x74cf, 16319), (0x74d4, 19509), (0x74d8, 14747), (0x74da, 21787), (0x74dc, 14629),
+    (0x74e0, 23325), (0x74e2, 22793), (0x74e3, 22606), (0x74e6, 19696), (0x74ee, 19688), (0x74f7, 20637), (0x7501, 17411), (0x7504, 14365),
+    (0x7511, 21462), (0x7515, 19689), (0x7518, 14051), (0x751a, 18909), (0x751b, 21999), (0x751f, 18025), (0x7523, 17962), (0x7525, 18026),
+    (0x7526, 18368), (0x7528, 19891), (0x752b, 17428), (0x752c, 19892), (0x7530, 20998), (0x7531, 20241), (0x7532, 14158), (0x7533, 18788),
+    (0x7537, 15386), (0x7538, 20999), (0x753a, 21070), (0x7547, 15125), (0x754c, 14538), (0x754f, 19735), (0x7551, 21000), (0x7553, 15719),
+    (0x7554, 17082), (0x7559, 16461), (0x755b, 21618), (0x755c, 22204), (0x755d, 16894), (0x7562, 22915), (0x7565, 16107), (0x7566, 23702),
+    (0x756a, 17262), (0x756f, 21440), (0x7570, 20447), (0x7575, 23369), (0x7576, 15730), (0x7578, 15312), (0x757a, 14172), (0x757f, 15313),
+    (0x7586, 14173), (0x7587, 21409), (0x758a, 22010), (0x758b, 22916), (0x758e, 18370), (0x758f, 18369), (0x7591, 20322), (0x759d, 17963),
+    (0x75a5, 14197), (0x75ab, 19310), (0x75b1, 22767), (0x75b2, 22905), (0x75b3, 14052), (0x75b5, 20638), (0x75b8, 15699), (0x75b9, 21619),
+    (0x75bc, 15922), (0x75bd, 20842), (0x75be, 21645), (0x75c2, 13983), (0x75c5, 17412), (0x75c7, 21463), (0x75cd, 20448), (0x75d2, 19137),
+    (0x75d4, 22357), (0x75d5, 23716), (0x75d8, 15932), (0x75d9, 14411), (0x75db, 22567), (0x75e2, 16504), (0x75f0, 15710), (0x75f2, 16631),
+    (0x75f4, 22358), (0x75fa, 17661), (0x75fc, 14567), (0x7600, 19155), (0x760d, 19138), (0x7619, 18371), (0x761f, 19681), (0x7620, 21961),
+    (0x7621, 21829), (0x7622, 17083), (0x7624, 16462), (0x7626, 18542), (0x763b, 16445), (0x7642, 16336), (0x764c, 18968), (0x764e, 14019),
+    (0x7652, 20243), (0x7656, 17289), (0x7661, 22359), (0x7664, 21022), (0x7669, 15958), (0x766c, 18185), (0x7670, 19690), (0x7672, 21001),
+    (0x7678, 14539), (0x767b, 15949), (0x767c, 17196), (0x767d, 17255), (0x767e, 17256), (0x7684, 20865), (0x7686, 14198), (0x7687, 23518),
+    (0x768e, 14800), (0x7690, 14568), (0x7693, 23326), (0x76ae, 22906), (0x76ba, 22187), (0x76bf, 16824), (0x76c2, 19916), (0x76c3, 17240),
+    (0x76c6, 17621), (0x76c8, 19510), (0x76ca, 20469), (0x76d2, 22971), (0x76d6, 14199), (0x76db, 18237), (0x76dc, 15873), (0x76de, 20666),
+    (0x76df, 16704), (0x76e1, 21620), (0x76e3, 14053), (0x76e4, 17084), (0x76e7, 16297), (0x76ee, 16860), (0x76f2, 16703), (0x76f4, 21598),
+    (0x76f8, 18006), (0x76fc, 17085), (0x76fe, 18599), (0x7701, 18238), (0x7704, 16713), (0x7708, 22530), (0x7709, 17027), (0x770b, 14020),
+    (0x771e, 21621), (0x7720, 16714), (0x7729, 23148), (0x7737, 14994), (0x7738, 16849), (0x773a, 21235), (0x773c, 18954), (0x7740, 21778),
+    (0x774d, 23149), (0x775b, 21071), (0x7761, 18543), (0x7763, 15894), (0x7766, 16861), (0x776b, 22011), (0x7779, 15874), (0x777e, 14569),
+    (0x777f, 19533), (0x778b, 21622), (0x7791, 16825), (0x779e, 16655), (0x77a5, 17398), (0x77ac, 18600), (0x77ad, 16337), (0x77b0, 14054),
+    (0x77b3, 15923), (0x77bb, 22000), (0x77bc, 14346), (0x77bf, 14941), (0x77d7, 22159), (0x77db, 16850), (0x77dc, 15177), (0x77e2, 18752),
+    (0x77e3, 20323), (0x77e5, 21582), (0x77e9, 14942), (0x77ed, 15688), (0x77ee, 19730), (0x77ef, 14801), (0x77f3, 18165), (0x7802, 17832),
+    (0x7812, 17662), (0x7825, 21583), (0x7826, 21841), (0x7827, 22384), (0x782c, 16529), (0x7832, 22768), (0x7834, 22597), (0x7845, 15114),
+    (0x784f, 19343), (0x785d, 22141), (0x786b, 16463), (0x786c, 14412), (0x786f, 19344), (0x787c, 17636), (0x7881, 15314), (0x7887, 21072),
+    (0x788c, 16307), (0x788d, 18989), (0x788e, 18414), (0x7891, 17663), (0x7897, 19712), (0x78a3, 14034), (0x78a7, 17386), (0x78a9, 18166),
+    (0x78ba, 23477), (0x78bb, 23478), (0x78bc, 16632), (0x78c1, 20639), (0x78c5, 17217), (0x78ca, 16325), (0x78cb, 21770), (0x78ce, 14540),
+    (0x78d0, 17086), (0x78e8, 16633), (0x78ec, 14413), (0x78ef, 15315), (0x78f5, 14021), (0x78fb, 17087), (0x7901, 22142), (0x790e, 22143),
+    (0x7916, 19291), (0x792a, 16131), (0x792b, 16143), (0x792c, 17088), (0x793a, 18753), (0x793e, 17833), (0x7940, 17834), (0x7941, 15316),
+    (0x7947, 15317), (0x7948, 15318), (0x7949, 21584), (0x7950, 19917), (0x7956, 21236), (0x7957, 21585), (0x795a, 21237), (0x795b, 14223),
+    (0x795c, 23327), (0x795d, 22205), (0x795e, 18789), (0x7960, 17835), (0x7965, 18007), (0x7968, 22794), (0x796d, 21199), (0x797a, 15319),
+    (0x797f, 16308), (0x7981, 15160), (0x798d, 23466), (0x798e, 21073), (0x798f, 17441), (0x7991, 19918), (0x79a6, 19156), (0x79a7, 23746),
+    (0x79aa, 18186), (0x79ae, 16286), (0x79b1, 15875), (0x79b3, 19139), (0x79b9, 19919), (0x79bd, 15161), (0x79be, 23467), (0x79bf, 15895),
+    (0x79c0, 18544), (0x79c1, 17836), (0x79c9, 17413), (0x79ca, 15507), (0x79cb, 22188), (0x79d1, 14726), (0x79d2, 22144), (0x79d5, 17664),
+    (0x79d8, 17665), (0x79df, 21238), (0x79e4, 22388), (0x79e6, 21623), (0x79e7, 18980), (0x79e9, 21646), (0x79fb, 20450), (0x7a00, 23747),
+    (0x7a05, 18345), (0x7a08, 14022), (0x7a0b, 21074), (0x7a0d, 22145), (0x7a14, 20514), (0x7a17, 22718), (0x7a19, 21599), (0x7a1a, 22360),
+    (0x7a1c, 16485), (0x7a1f, 22897), (0x7a20, 21239), (0x7a2e, 21275), (0x7a31, 22389), (0x7a36, 19938), (0x7a37, 21600), (0x7a3b, 15876),
+    (0x7a3c, 13984), (0x7a3d, 14541), (0x7a3f, 14570), (0x7a40, 14593), (0x7a46, 16862), (0x7a49, 22361), (0x7a4d, 20866), (0x7a4e, 19511),
+    (0x7a57, 18545), (0x7a61, 18021), (0x7a62, 19534), (0x7a69, 19682), (0x7a6b, 23479), (0x7a70, 19140), (0x7a74, 23160), (0x7a76, 14943),
+    (0x7a79, 14981), (0x7a7a, 14621), (0x7a7d, 21075), (0x7a7f, 21975), (0x7a81, 15910), (0x7a84, 21779), (0x7a88, 19856), (0x7a92, 21647),
+    (0x7a93, 21830), (0x7a95, 21240), (0x7a98, 14970), (0x7a9f, 14978), (0x7aa9, 19697), (0x7aaa, 19698), (0x7aae, 14982), (0x7aaf, 19857),
+    (0x7aba, 15115), (0x7ac4, 21788), (0x7ac5, 15116), (0x7ac7, 15933), (0x7aca, 21023), (0x7acb, 16626), (0x7ad7, 16878), (0x7ad9, 21810),
+    (0x7add, 17414), (0x7adf, 14414), (0x7ae0, 20696), (0x7ae3, 21441), (0x7ae5, 15924), (0x7aea, 18546), (0x7aed, 14035), (0x7aef, 15689),
+    (0x7af6, 14415), (0x7af9, 21426), (0x7afa, 22206), (0x7aff, 14023), (0x7b0f, 23353), (0x7b11, 18372), (0x7b19, 18028), (0x7b1b, 20867),
+    (0x7b1e, 22549), (0x7b20, 16627), (0x7b26, 17587), (0x7b2c, 21200), (0x7b2d, 16274), (0x7b39, 18346), (0x7b46, 22917), (0x7b49, 15950),
+    (0x7b4b, 15146), (0x7b4c, 21002), (0x7b4d, 18601), (0x7b4f, 17269), (0x7b50, 14769), (0x7b51, 22207), (0x7b52, 22568), (0x7b54, 15720),
+    (0x7b56, 21849), (0x7b60, 15126), (0x7b6c, 18239), (0x7b6e, 18046), (0x7b75, 19346), (0x7b7d, 19669), (0x7b87, 14200), (0x7b8b, 21003),
+    (0x7b8f, 20825), (0x7b94, 17061), (0x7b95, 15320), (0x7b97, 17964), (0x7b9a, 21771), (0x7b9d, 14381), (0x7ba1, 14748), (0x7bad, 21004),
+    (0x7bb1, 18008), (0x7bb4, 20670), (0x7bb8, 20843), (0x7bc0, 21024), (0x7bc1, 23519), (0x7bc4, 17279), (0x7bc6, 21005), (0x7bc7, 22730),
+    (0x7bc9, 22208), (0x7bd2, 18772), (0x7be0, 18373), (0x7be4, 15896), (0x7be9, 17837), (0x7c07, 21259), (0x7c12, 21789), (0x7c1e, 15690),
+    (0x7c21, 14024), (0x7c27, 23520), (0x7c2a, 20671), (0x7c2b, 18374), (0x7c3d, 22001), (0x7c3e, 16264), (0x7c3f, 17588), (0x7c43, 16085),
+    (0x7c4c, 21410), (0x7c4d, 20868), (0x7c60, 16320), (0x7c64, 22002), (0x7c6c, 16505), (0x7c73, 17028), (0x7c83, 17666), (0x7c89, 17622),
+    (0x7c92, 16628), (0x7c95, 17062), (0x7c97, 21241), (0x7c98, 21031), (0x7c9f, 18391), (0x7ca5, 21427), (0x7ca7, 20697), (0x7cae, 16114),
+    (0x7cb1, 16115), (0x7cb2, 21791), (0x7cb3, 14210), (0x7cb9, 18547), (0x7cbe, 21076), (0x7cca, 23328), (0x7cd6, 15731), (0x7cde, 17623),
+    (0x7cdf, 21242), (0x7ce0, 14174), (0x7ce7, 16116), (0x7cfb, 14542), (0x7cfe, 15117), (0x7d00, 15321), (0x7d02, 21411), (0x7d04, 19114),
+    (0x7d05, 23360), (0x7d06, 19920), (0x7d07, 23719), (0x7d08, 23493), (0x7d0a, 17007), (0x7d0b, 17008), (0x7d0d, 15486), (0x7d10, 15564),
+    (0x7d14, 18602), (0x7d17, 17838), (0x7d18, 14785), (0x7d19, 21586), (0x7d1a, 15173), (0x7d1b, 17624), (0x7d20, 18375), (0x7d21, 17218),
+    (0x7d22, 18022), (0x7d2b, 20640), (0x7d2c, 21412), (0x7d2e, 21802), (0x7d2f, 16446), (0x7d30, 18347), (0x7d33, 18790), (0x7d35, 20844),
+    (0x7d39, 18376), (0x7d3a, 14055), (0x7d42, 21276), (0x7d43, 23150), (0x7d44, 21243), (0x7d45, 14416), (0x7d46, 17089), (0x7d50, 14376),
+    (0x7d5e, 14802), (0x7d61, 16064), (0x7d62, 23151), (0x7d66, 15174), (0x7d68, 20289), (0x7d6a, 20488), (0x7d6e, 18047), (0x7d71, 22569),
+    (0x7d72, 17839), (0x7d73, 14175), (0x7d76, 21025), (0x7d79, 14366), (0x7d7f, 14944), (0x7d8e, 21077), (0x7d8f, 18548), (0x7d93, 14417),
+    (0x7d9c, 21277), (0x7da0, 16309), (0x7da2, 21413), (0x7dac, 18549), (0x7dad, 20246), (0x7db1, 14176), (0x7db2, 16674), (0x7db4, 21991),
+    (0x7db5, 21842), (0x7db8, 16473), (0x7dba, 15322), (0x7dbb, 22525), (0x7dbd, 20657), (0x7dbe, 16486), (0x7dbf, 16715), (0x7dc7, 22362),
+    (0x7dca, 15339), (0x7dcb, 17667), (0x7dd6, 18048), (0x7dd8, 22964), (0x7dda, 18187), (0x7ddd, 21658), (0x7dde, 15691), (0x7de0, 22028),
+    (0x7de1, 17046), (0x7de3, 19347), (0x7de8, 22731), (0x7de9, 19713), (0x7dec, 16716), (0x7def, 20098), (0x7df4, 16248), (0x7dfb, 22363),
+    (0x7e09, 21624), (0x7e0a, 18997), (0x7e15, 19683), (0x7e1b, 17063), (0x7e1d, 21625), (0x7e1e, 23329), (0x7e1f, 19873), (0x7e21, 20818),
+    (0x7e23, 23152), (0x7e2b, 17463), (0x7e2e, 22209), (0x7e2f, 19349), (0x7e31, 21278), (0x7e37, 16447), (0x7e3d, 22172), (0x7e3e, 20869),
+    (0x7e41, 17264), (0x7e43, 17637), (0x7e46, 16895), (0x7e47, 19858), (0x7e52, 21464), (0x7e54, 21601), (0x7e55, 18188), (0x7e5e, 19859),
+    (0x7e61, 18550), (0x7e69, 18732), (0x7e6a, 23540), (0x7e6b, 14543), (0x7e6d, 14367), (0x7e70, 21244), (0x7e79, 19311), (0x7e7c, 14544),
+    (0x7e82, 21790), (0x7e8c, 18392), (0x7e8f, 21006), (0x7e93, 19512), (0x7e96, 18218), (0x7e98, 21792), (0x7e9b, 15897), (0x7e9c, 16086),
+    (0x7f36, 17589), (0x7f38, 22987), (0x7f3a, 14377), (0x7f4c, 19097), (0x7f50, 14749), (0x7f54, 16675), (0x7f55, 22949), (0x7f6a, 21385),
+    (0x7f6b, 14774), (0x7f6e, 22364), (0x7f70, 17270), (0x7f72, 18049), (0x7f75, 16690), (0x7f77, 22598), (0x7f79, 16506), (0x7f85, 15959),
+    (0x7f88, 15323), (0x7f8a, 19142), (0x7f8c, 14177), (0x7f8e, 17029), (0x7f94, 14571), (0x7f9a, 16275), (0x7f9e, 18551), (0x7fa4, 14971),
+    (0x7fa8, 18189), (0x7fa9, 20324), (0x7fb2, 23748), (0x7fb8, 16507), (0x7fb9, 14211), (0x7fbd, 19921), (0x7fc1, 19691), (0x7fc5, 18754),
+    (0x7fca, 20470), (0x7fcc, 20471), (0x7fce, 16276), (0x7fd2, 18722), (0x7fd4, 18009), (0x7fd5, 23727), (0x7fdf, 20870), (0x7fe0, 22333),
+    (0x7fe1, 17668), (0x7fe9, 22732), (0x7feb, 19714), (0x7ff0, 22950), (0x7ff9, 14803), (0x7ffc, 20472), (0x8000, 19860), (0x8001, 16298),
+    (0x8003, 14572), (0x8005, 20641), (0x8006, 15324), (0x8009, 14945), (0x800c, 20452), (0x8010, 15502), (0x8015, 14418), (0x8017, 16851),
+    (0x8018, 19947), (0x802d, 15325), (0x8033, 20453), (0x8036, 19108), (0x803d, 22531), (0x803f, 14419), (0x8043, 15711), (0x8046, 16277),
+    (0x804a, 16338), (0x8056, 18336), (0x8058, 17794), (0x805a, 22334), (0x805e, 17009), (0x806f, 16249), (0x8070, 22173), (0x8072, 18337),
+    (0x8073, 19893), (0x8077, 21602), (0x807d, 22018), (0x807e, 16321), (0x807f, 20286), (0x8084, 20454), (0x8085, 18585), (0x8086, 17840),
+    (0x8087, 21245), (0x8089, 20266), (0x808b, 16481), (0x808c, 15326), (0x8096, 22146), (0x809b, 22988), (0x809d, 14025), (0x80a1, 14573),
+    (0x80a2, 21587), (0x80a5, 17669), (0x80a9, 14368), (0x80aa, 17219), (0x80af, 15178), (0x80b1, 14786), (0x80b2, 20267), (0x80b4, 23657),
+    (0x80ba, 22748), (0x80c3, 20099), (0x80c4, 21391), (0x80cc, 17241), (0x80ce, 22550), (0x80da, 17242), (0x80db, 14159), (0x80de, 22769),
+    (0x80e1, 23330), (0x80e4, 20277), (0x80e5, 18146), (0x80f1, 14770), (0x80f4, 15925), (0x80f8, 23711), (0x80fd, 15571), (0x8102, 21588),
+    (0x8105, 23171), (0x8106, 22335), (0x8107, 23172), (0x8108, 16695), (0x810a, 21962), (0x8118, 19715), (0x811a, 14002), (0x811b, 14420),
+    (0x8123, 18603), (0x8129, 18552), (0x812b, 22528), (0x812f, 22770), (0x8139, 21831), (0x813e, 17766), (0x814b, 18998), (0x814e, 18791),
+    (0x8150, 17590), (0x8151, 17591), (0x8154, 14178), (0x8155, 19716), (0x8165, 18338), (0x8166, 15549), (0x816b, 21279), (0x8170, 19861),
+    (0x8171, 14237), (0x8178, 20698), (0x8179, 17442), (0x817a, 18190), (0x817f, 22573), (0x8180, 17220), (0x8188, 14359), (0x818a, 17064),
+    (0x818f, 14574), (0x819a, 17592), (0x819c, 16640), (0x819d, 18718), (0x81a0, 14804), (0x81a3, 21648), (0x81a8, 22724), (0x81b3, 18191),
+    (0x81b5, 22228), (0x81ba, 20311), (0x81bd, 15712), (0x81be, 23541), (0x81bf, 15544), (0x81c0, 15940), (0x81c2, 17767), (0x81c6, 19165),
+    (0x81cd, 21201), (0x81d8, 16091), (0x81df, 20699), (0x81e3, 18792), (0x81e5, 19699), (0x81e7, 20700), (0x81e8, 16527), (0x81ea, 20642),
+    (0x81ed, 22336), (0x81f3, 21589), (0x81f4, 22365), (0x81fa, 15745), (0x81fb, 21626), (0x81fc, 14946), (0x81fe, 20247), (0x8205, 14947),
+    (0x8207, 19293), (0x8208, 23728), (0x820a, 14948), (0x820c, 18208), (0x820d, 17841), (0x8212, 18147), (0x821b, 21976), (0x821c, 18604),
+    (0x821e, 16896), (0x821f, 21414), (0x8221, 14179), (0x822a, 22989), (0x822b, 17221), (0x822c, 17090), (0x8235, 22401), (0x8236, 17065),
+    (0x8237, 23153), (0x8239, 18192), (0x8240, 17593), (0x8245, 19294), (0x8247, 21078), (0x8259, 21832), (0x8264, 20325), (0x8266, 22965),
+    (0x826e, 14026), (0x826f, 16117), (0x8271, 14027), (0x8272, 18023), (0x8276, 19378), (0x8278, 22147), (0x827e, 18990), (0x828b, 19922),
+    (0x828d, 20658), (0x828e, 14983), (0x8292, 16676), (0x8299, 17594), (0x829a, 15941), (0x829d, 21590), (0x829f, 17978), (0x82a5, 14201),
+    (0x82a6, 23331), (0x82a9, 15162), (0x82ac, 17625), (0x82ad, 22599), (0x82ae, 19535), (0x82af, 18910), (0x82b1, 23468), (0x82b3, 17222),
+    (0x82b7, 21591), (0x82b8, 19948), (0x82b9, 15147), (0x82bb, 22189), (0x82bc, 16852), (0x82bd, 18925), (0x82bf, 20622), (0x82d1, 20074),
+    (0x82d2, 19379), (0x82d4, 22551), (0x82d5, 22148), (0x82d7, 16879), (0x82db, 13985), (0x82de, 22771), (0x82df, 14949), (0x82e1, 20455),
+    (0x82e5, 19115), (0x82e6, 14575), (0x82e7, 20845), (0x82f1, 19515), (0x82fd, 14576), (0x82fe, 22918), (0x8301, 21447), (0x8302, 16897),
+    (0x8303, 17280), (0x8304, 13986), (0x8305, 16853), (0x8309, 16666), (0x8317, 16826), (0x8328, 20643), (0x832b, 16677), (0x832f, 17443),
+    (0x8331, 18553), (0x8334, 23542), (0x8335, 20489), (0x8336, 15579), (0x8338, 19894), (0x8339, 19295), (0x8340, 18605), (0x8347, 23112),
+    (0x8349, 22149), (0x834a, 23285), (0x834f, 20516), (0x8351, 20456), (0x8352, 23521), (0x8373, 15934), (0x8377, 22930), (0x837b, 20871),
+    (0x8389, 16508), (0x838a, 20701), (0x838e, 17842), (0x8396, 14421), (0x8398, 18793), (0x839e, 19717), (0x83a2, 23173), (0x83a9, 17595),
+    (0x83aa, 18926), (0x83ab, 16641), (0x83bd, 16678), (0x83c1, 22019), (0x83c5, 14750), (0x83c9, 16310), (0x83ca, 14965), (0x83cc, 15127),
+    (0x83d3, 14727), (0x83d6, 21833), (0x83dc, 21843), (0x83e9, 17429), (0x83eb, 15148), (0x83ef, 23469), (0x83f0, 14577), (0x83f1, 16487),
+    (0x83f2, 17768), (0x83f4, 18969), (0x83f9, 20846), (0x83fd, 18586), (0x8403, 22229), (0x8404, 15877), (0x840a, 16104), (0x840c, 16705),
+    (0x840d, 22740), (0x840e, 20100), (0x8429, 22190), (0x842c, 16656), (0x8431, 23687), (0x8438, 20248), (0x843d, 16065), (0x8449, 19483),
+    (0x8457, 20847), (0x845b, 14036), (0x8461, 22772), (0x8463, 15926), (0x8466, 20101), (0x846b, 23332), (0x846c, 20702), (0x846f, 19116),
+    (0x8475, 15118), (0x847a, 21456), (0x8490, 18554), (0x8494, 18755), (0x8499, 16868), (0x849c, 17965), (0x84a1, 17223), (0x84b2, 22773),
+    (0x84b8, 21465), (0x84bb, 19117), (0x84bc, 21834), (0x84bf, 23333), (0x84c0, 18399), (0x84c2, 16827), (0x84c4, 22210), (0x84c6, 18167),
+    (0x84c9, 19895), (0x84cb, 14202), (0x84cd, 18756), (0x84d1, 17843), (0x84da, 18555), (0x84ec, 17464), (0x84ee, 16250), (0x84f4, 18606),
+    (0x84fc, 16339), (0x8511, 16816), (0x8513, 16657), (0x8514, 17444), (0x8517, 20644), (0x8518, 17979), (0x851a, 20050), (0x851e, 16448),
+    (0x8521, 21844), (0x8523, 20703), (0x8525, 22174), (0x852c, 18377), (0x852d, 20302), (0x852f, 21627), (0x853d, 22749), (0x853f, 20102),
+    (0x8541, 15713), (0x8543, 17265), (0x8549, 22150), (0x854e, 14805), (0x8553, 19949), (0x8559, 23297), (0x8563, 18607), (0x8568, 14997),
+    (0x8569, 22540), (0x856a, 16898), (0x856d, 18378), (0x8584, 17066), (0x8587, 17030), (0x858f, 20326), (0x8591, 14180), (0x8594, 20704),
+    (0x859b, 18209), (0x85a6, 21977), (0x85a8, 23683), (0x85a9, 17972), (0x85aa, 18794), (0x85af, 18148), (0x85b0, 23680), (0x85ba, 21202),
+    (0x85c1, 14578), (0x85c9, 20645), (0x85cd, 16087), (0x85ce, 18795), (0x85cf, 20705), (0x85d5, 19923), (0x85dc, 16132), (0x85dd, 19536),
+    (0x85e4, 15951), (0x85e5, 19118), (0x85e9, 17266), (0x85ea, 18556), (0x85f7, 20848), (0x85fa, 16519), (0x85fb, 21246), (0x85ff, 14736),
+    (0x8602, 19537), (0x8606, 16299), (0x8607, 18379), (0x860a, 19684), (0x8616, 19173), (0x8617, 17387), (0x861a, 18193), (0x862d, 16076),
+    (0x863f, 16056), (0x864e, 23334), (0x8650, 22938), (0x8654, 14238), (0x8655, 21950), (0x865b, 23125), (0x865c, 16300), (0x865e, 19924),
+    (0x865f, 23335), (0x8667, 23703), (0x8679, 23361), (0x868a, 17010), (0x868c, 17224), (0x8693, 20491), (0x86a3, 14622), (0x86a4, 21247),
+    (0x86a9, 22366), (0x86c7, 17844), (0x86cb, 15692), (0x86d4, 23543), (0x86d9, 19700), (0x86db, 21415), (0x86df, 14806), (0x86e4, 22972),
+    (0x86ed, 21649), (0x86fe, 18927), (0x8700, 22160), (0x8702, 17465), (0x8703, 18796), (0x8708, 19670), (0x8718, 21592), (0x871a, 17769),
+    (0x871c, 17049), (0x874e, 14038), (0x8755, 18773), (0x8757, 23522), (0x875f, 20103), (0x8766, 22931), (0x8768, 18719), (0x8774, 23336),
+    (0x8776, 21037), (0x8778, 19701), (0x8782, 16099), (0x878d, 20290), (0x879f, 16828), (0x87a2, 23286), (0x87b3, 15732), (0x87ba, 16057),
+    (0x87c4, 22387), (0x87e0, 17091), (0x87ec, 18194), (0x87ef, 19863), (0x87f2, 22224), (0x87f9, 23100), (0x87fb, 20327), (0x87fe, 18219),
+    (0x8805, 18733), (0x881f, 16092), (0x8822, 21442), (0x8823, 16133), (0x8831, 14579), (0x8836, 20672), (0x883b, 16658), (0x8840, 23161),
+    (0x8846, 21450), (0x884c, 23113), (0x884d, 19351), (0x8852, 23154), (0x8853, 18615), (0x8857, 13987), (0x8859, 18928), (0x885b, 20104),
+    (0x885d, 22225), (0x8861, 23287), (0x8862, 14950), (0x8863, 20328), (0x8868, 22795), (0x886b, 17980), (0x8870, 18416), (0x8872, 15489),
+    (0x8877, 22226), (0x887e, 15163), (0x887f, 15164), (0x8881, 20075), (0x8882, 16832), (0x8888, 13988), (0x888b, 15746), (0x888d, 22774),
+    (0x8892, 15693), (0x8896, 18557), (0x8897, 21628), (0x889e, 14604), (0x88ab, 22907), (0x88b4, 14580), (0x88c1, 20819), (0x88c2, 16259),
+    (0x88cf, 16509), (0x88d4, 19539), (0x88d5, 20249), (0x88d9, 14972), (0x88dc, 17430), (0x88dd, 20706), (0x88df, 17845), (0x88e1, 16510),
+    (0x88e8, 17770), (0x88f3, 18010), (0x88f4, 17243), (0x88f5, 17244), (0x88f8, 16058), (0x88fd, 21203), (0x8907, 17445), (0x8910, 14037),
+    (0x8912, 22775), (0x8913, 17431), (0x8918, 20105), (0x8919, 17245), (0x8925, 19874), (0x892a, 22574), (0x8936, 18723), (0x8938, 16449),
+    (0x893b, 18210), (0x8941, 14181), (0x8944, 19144), (0x895f, 15165), (0x8964, 16088), (0x896a, 16667), (0x8972, 18724), (0x897f, 18149),
+    (0x8981, 19864), (0x8983, 15714), (0x8986, 17446), (0x8987, 22719), (0x898b, 14369), (0x898f, 15119), (0x8993, 16707), (0x8996, 18757),
+    (0x89a1, 14360), (0x89a9, 15878), (0x89aa, 22374), (0x89b2, 15149), (0x89ba, 14003), (0x89bd, 16089), (0x89c0, 14751), (0x89d2, 14004),
+    (0x89e3, 23101), (0x89f4, 18011), (0x89f8, 22161), (0x8a00, 19170), (0x8a02, 21079), (0x8a03, 17596), (0x8a08, 14545), (0x8a0a, 18797),
+    (0x8a0c, 23362), (0x8a0e, 22563), (0x8a13, 23681), (0x8a16, 23720), (0x8a17, 22419), (0x8a18, 15327), (0x8a1b, 19702), (0x8a1d, 18929),
+    (0x8a1f, 18407), (0x8a23, 14378), (0x8a25, 15562), (0x8a2a, 17225), (0x8a2d, 18211), (0x8a31, 23126), (0x8a34, 18380), (0x8a36, 13989),
+    (0x8a3a, 21629), (0x8a3b, 21416), (0x8a50, 17846), (0x8a54, 21248), (0x8a55, 22741), (0x8a5b, 20849), (0x8a5e, 17847), (0x8a60, 19516),
+    (0x8a62, 18608), (0x8a63, 19540), (0x8a66, 18758), (0x8a69, 18759), (0x8a6d, 15003), (0x8a6e, 21007), (0x8a70, 23749), (0x8a71, 23470),
+    (0x8a72, 23102), (0x8a73, 18012), (0x8a75, 18195), (0x8a79, 22003), (0x8a85, 21417), (0x8a87, 14728), (0x8a8c, 21593), (0x8a8d, 20492),
+    (0x8a93, 18150), (0x8a95, 22526), (0x8a98, 20250), (0x8a9e, 19157), (0x8aa0, 18339), (0x8aa1, 14546), (0x8aa3, 16899), (0x8aa4, 19671),
+    (0x8aa5, 14581), (0x8aa6, 18408), (0x8aa8, 23544), (0x8aaa, 18212), (0x8ab0, 18558), (0x8ab2, 14729), (0x8ab9, 17771), (0x8abc, 20329),
+    (0x8abe, 20296), (0x8abf, 21249), (0x8ac2, 22004), (0x8ac4, 18609), (0x8ac7, 15715), (0x8acb, 22020), (0x8acd, 20826), (0x8acf, 22191),
+    (0x8ad2, 16118), (0x8ad6, 16314), (0x8adb, 20251), (0x8adc, 22012), (0x8ae1, 18760), (0x8ae6, 22029), (0x8ae7, 23103), (0x8aea, 21080),
+    (0x8aeb, 14028), (0x8aed, 20252), (0x8aee, 20646), (0x8af1, 23696), (0x8af6, 18911), (0x8af7, 22899), (0x8af8, 21204), (0x8afa, 19171),
+    (0x8afe, 15366), (0x8b00, 16854), (0x8b01, 18960), (0x8b02, 20106), (0x8b04, 15952), (0x8b0e, 17031), (0x8b10, 17050), (0x8b14, 22939),
+    (0x8b16, 18393), (0x8b17, 17226), (0x8b19, 14382), (0x8b1a, 20473), (0x8b1b, 14182), (0x8b1d, 17848), (0x8b20, 19865), (0x8b28, 16855),
+    (0x8b2b, 20872), (0x8b2c, 16464), (0x8b33, 14951), (0x8b39, 15150), (0x8b41, 23471), (0x8b49, 21466), (0x8b4e, 23705), (0x8b4f, 15328),
+    (0x8b58, 18774), (0x8b5a, 15716), (0x8b5c, 17432), (0x8b66, 14422), (0x8b6c, 17772), (0x8b6f, 19312), (0x8b70, 20426), (0x8b74, 14370),
+    (0x8b77, 23337), (0x8b7d, 19541), (0x8b80, 15898), (0x8b8a, 17393), (0x8b90, 18559), (0x8b92, 21811), (0x8b93, 19146), (0x8b96, 21812),
+    (0x8b9a, 21793), (0x8c37, 14594), (0x8c3f, 14547), (0x8c41, 23500), (0x8c46, 15936), (0x8c48, 15329), (0x8c4a, 22900), (0x8c4c, 19718),
+    (0x8c55, 18761), (0x8c5a, 15907), (0x8c61, 18013), (0x8c6a, 23338), (0x8c6b, 19542), (0x8c79, 22796), (0x8c7a, 18762), (0x8c82, 22151),
+    (0x8c8a, 16696), (0x8c8c, 16856), (0x8c9d, 22720), (0x8c9e, 21081), (0x8ca0, 17597), (0x8ca1, 20820), (0x8ca2, 14623), (0x8ca7, 17789),
+    (0x8ca8, 23472), (0x8ca9, 22607), (0x8caa, 22532), (0x8cab, 14752), (0x8cac, 21946), (0x8caf, 20850), (0x8cb0, 18349), (0x8cb3, 20460),
+    (0x8cb4, 15009), (0x8cb6, 22736), (0x8cb7, 16691), (0x8cb8, 15747), (0x8cbb, 17773), (0x8cbc, 22013), (0x8cbd, 20459), (0x8cbf, 16900),
+    (0x8cc0, 22932), (0x8cc1, 17626), (0x8cc2, 16326), (0x8cc3, 20517), (0x8cc4, 23545), (0x8cc7, 20647), (0x8cc8, 13990), (0x8cca, 20873),
+    (0x8cd1, 21630), (0x8cd3, 17790), (0x8cda, 16327), (0x8cdc, 17849), (0x8cde, 18014), (0x8ce0, 17246), (0x8ce2, 23156), (0x8ce3, 16692),
+    (0x8ce4, 21978), (0x8ce6, 17598), (0x8cea, 21650), (0x8ced, 15879), (0x8cf4, 16328), (0x8cfb, 17599), (0x8cfc, 14952), (0x8cfd, 18018),
+    (0x8d04, 21595), (0x8d05, 22326), (0x8d07, 20278), (0x8d08, 21467), (0x8d0a, 21794), (0x8d0d, 18220), (0x8d13, 20707), (0x8d16, 18394),
+    (0x8d64, 20874), (0x8d66, 17850), (0x8d6b, 23136), (0x8d70, 21418), (0x8d73, 15120), (0x8d74, 17600), (0x8d77, 15330), (0x8d85, 22152),
+    (0x8d8a, 20083), (0x8d99, 21250), (0x8da3, 22337), (0x8da8, 22192), (0x8db3, 21260), (0x8dba, 17601), (0x8dbe, 21596), (0x8dc6, 22552),
+    (0x8dcb, 17197), (0x8dcc, 21651), (0x8dcf, 13991), (0x8ddb, 22600), (0x8ddd, 14224), (0x8de1, 20875), (0x8de3, 18196), (0x8de8, 14730),
+    (0x8def, 16301), (0x8df3, 15880), (0x8e0a, 19896), (0x8e0f, 15721), (0x8e10, 21979), (0x8e1e, 14225), (0x8e2a, 21376), (0x8e30, 20253),
+    (0x8e35, 21377), (0x8e42, 20254), (0x8e44, 21205), (0x8e47, 14239), (0x8e48, 15881), (0x8e49, 21773), (0x8e4a, 23298), (0x8e59, 22211),
+    (0x8e5f, 20876), (0x8e60, 21963), (0x8e74, 22212), (0x8e76, 14998), (0x8e81, 21251), (0x8e87, 20851), (0x8e8a, 21419), (0x8e8d, 19119),
+    (0x8eaa, 16520), (0x8eab, 18798), (0x8eac, 14984), (0x8ec0, 14953), (0x8eca, 21774), (0x8ecb, 18961), (0x8ecc, 15004), (0x8ecd, 14973),
+    (0x8ed2, 23130), (0x8edf, 19352), (0x8eeb, 21631), (0x8ef8, 22213), (0x8efb, 13992), (0x8efe, 18775), (0x8f03, 14807), (0x8f05, 16302),
+    (0x8f09, 20821), (0x8f12, 22014), (0x8f13, 16659), (0x8f14, 17433), (0x8f15, 14423), (0x8f1b, 16119), (0x8f1c, 22367), (0x8f1d, 23697),
+    (0x8f1e, 16679), (0x8f1f, 21992), (0x8f26, 16251), (0x8f27, 17415), (0x8f29, 17247), (0x8f2a, 16474), (0x8f2f, 21659), (0x8f33, 21420),
+    (0x8f38, 18560), (0x8f39, 17447), (0x8f3b, 17448), (0x8f3e, 21008), (0x8f3f, 19296), (0x8f44, 22956), (0x8f45, 20076), (0x8f49, 21009),
+    (0x8f4d, 21993), (0x8f4e, 14808), (0x8f5d, 19297), (0x8f5f, 14787), (0x8f62, 16144), (0x8f9b, 18799), (0x8f9c, 14583), (0x8fa3, 16079),
+    (0x8fa6, 22608), (0x8fa8, 17394), (0x8fad, 17851), (0x8faf, 17395), (0x8fb0, 21632), (0x8fb1, 19875), (0x8fb2, 15545), (0x8fc2, 19925),
+    (0x8fc5, 18801), (0x8fce, 19517), (0x8fd1, 15151), (0x8fd4, 17092), (0x8fe6, 13993), (0x8fea, 20877), (0x8feb, 17067), (0x8fed, 21652),
+    (0x8ff0, 18616), (0x8ff2, 14351), (0x8ff7, 17032), (0x8ff9, 20878), (0x8ffd, 22193), (0x9000, 22575), (0x9001, 18409), (0x9002, 14758),
+    (0x9003, 15882), (0x9005, 23672), (0x9006, 19314), (0x9008, 23288), (0x900b, 22776), (0x900d, 18381), (0x900f, 22581), (0x9010, 22214),
+    (0x9011, 14954), (0x9014, 15883), (0x9015, 14424), (0x9017, 15937), (0x9019, 20852), (0x901a, 22570), (0x901d, 18151), (0x901e, 16278),
+    (0x901f, 18395), (0x9020, 21252), (0x9021, 21443), (0x9022, 17466), (0x9023, 16252), (0x902e, 22030), (0x9031, 21421), (0x9032, 21633),
+    (0x9035, 15121), (0x9038, 20504), (0x903c, 22921), (0x903e, 20256), (0x9041, 15942), (0x9042, 18561), (0x9047, 19926), (0x904a, 20255),
+    (0x904b, 20046), (0x904d, 22733), (0x904e, 14731), (0x9050, 22933), (0x9051, 23523), (0x9053, 15884), (0x9054, 15700), (0x9055, 20107),
+    (0x9059, 19866), (0x905c, 18400), (0x905d, 15722), (0x905e, 22031), (0x9060, 20077), (0x9061, 18382), (0x9063, 14371), (0x9069, 20879),
+    (0x906d, 21253), (0x906e, 21775), (0x906f, 15943), (0x9072, 21597), (0x9075, 21444), (0x9077, 21980), (0x9078, 18197), (0x907a, 20257),
+    (0x907c, 16436), (0x907d, 14227), (0x907f, 22908), (0x9080, 19868), (0x9081, 16693), (0x9082, 23104), (0x9083, 18562), (0x9084, 23494),
+    (0x9087, 20461), (0x9088, 16642), (0x908a, 17396), (0x908f, 16059), (0x9091, 20308), (0x9095, 19692), (0x9099, 16680), (0x90a2, 23289),
+    (0x90a3, 15360), (0x90a6, 17227), (0x90a8, 22165), (0x90aa, 17852), (0x90af, 14056), (0x90b0, 22553), (0x90b1, 14955), (0x90b5, 18383),
+    (0x90b8, 20853), (0x90c1, 19939), (0x90ca, 14809), (0x90de, 16100), (0x90e1, 14974), (0x90e8, 17602), (0x90ed, 14737), (0x90f5, 19927),
+    (0x90fd, 15885), (0x9102, 18944), (0x9112, 22194), (0x9115, 23118), (0x9119, 17774), (0x9127, 15953), (0x912d, 21082), (0x9132, 15694),
+    (0x9149, 20258), (0x914a, 21083), (0x914b, 22195), (0x914c, 20659), (0x914d, 17248), (0x914e, 21422), (0x9152, 21423), (0x9162, 22153),
+    (0x9169, 16829), (0x916a, 16067), (0x916c, 18563), (0x9175, 23658), (0x9177, 23344), (0x9178, 17966), (0x9187, 18610), (0x9189, 22338),
+    (0x918b, 22154), (0x918d, 21206), (0x9192, 18340), (0x919c, 22196), (0x91ab, 20427), (0x91ac, 20708), (0x91ae, 22155), (0x91af, 23299),
+    (0x91b1, 17198), (0x91b4, 16287), (0x91b5, 14212), (0x91c0, 19147), (0x91c7, 21845), (0x91c9, 20259), (0x91cb, 18168), (0x91cc, 16511),
+    (0x91cd, 21451), (0x91ce, 19110), (0x91cf, 16120), (0x91d0, 16512), (0x91d1, 15344), (0x91d7, 18417), (0x91d8, 21084), (0x91dc, 17603),
+    (0x91dd, 22385), (0x91e3, 21254), (0x91e7, 21981), (0x91ea, 19928), (0x91f5, 21846), (0x920d, 15944), (0x9210, 14347), (0x9211, 22609),
+    (0x9212, 17983), (0x9217, 20280), (0x921e, 15128), (0x9234, 16279), (0x923a, 19678), (0x923f, 21010), (0x9240, 14160), (0x9245, 14228),
+    (0x9249, 23157), (0x9257, 14383), (0x925b, 19356), (0x925e, 20084), (0x9262, 17199), (0x9264, 14956), (0x9265, 18617), (0x9266, 21085),
+    (0x9280, 20297), (0x9283, 22175), (0x9285, 15927), (0x9291, 18198), (0x9293, 21011), (0x9296, 18564), (0x9298, 16830), (0x929c, 22966),
+    (0x92b3, 19544), (0x92b6, 14957), (0x92b7, 18384), (0x92b9, 18565), (0x92cc, 21086), (0x92cf, 23174), (0x92d2, 17467), (0x92e4, 18152),
+    (0x92ea, 22777), (0x92f8, 14229), (0x92fc, 14183), (0x9304, 16311), (0x9310, 22197), (0x9318, 22198), (0x931a, 20827), (0x931e, 18611),
+    (0x931f, 15717), (0x9320, 21087), (0x9321, 15331), (0x9322, 21012), (0x9324, 15332), (0x9326, 15167), (0x9328, 16880), (0x932b, 18169),
+    (0x932e, 14584), (0x932f, 21780), (0x9348, 19519), (0x934a, 16253), (0x934b, 14732), (0x934d, 15886), (0x9354, 18945), (0x935b, 15695),
+    (0x936e, 20260), (0x9375, 14240), (0x937c, 22386), (0x937e, 21378), (0x938c, 14384), (0x9394, 19897), (0x9396, 18415), (0x939a, 22199),
+    (0x93a3, 23290), (0x93a7, 14204), (0x93ac, 23339), (0x93ad, 21634), (0x93b0, 20505), (0x93c3, 21261), (0x93d1, 20880), (0x93de, 19898),
+    (0x93e1, 14425), (0x93e4, 16450), (0x93f6, 21756), (0x9404, 23550), (0x9418, 21379), (0x9425, 18199), (0x942b, 21013), (0x9435, 21994),
+    (0x9438, 22516), (0x9444, 21424), (0x9451, 14057), (0x9452, 14058), (0x945b, 14771), (0x947d, 21795), (0x947f, 21781), (0x9577, 20709),
+    (0x9580, 17011), (0x9583, 18221), (0x9589, 22750), (0x958b, 14205), (0x958f, 20281), (0x9591, 22951), (0x9592, 22952), (0x9593, 14029),
+    (0x9594, 17047), (0x9598, 14161), (0x95a3, 14005), (0x95a4, 22973), (0x95a5, 17271), (0x95a8, 15122), (0x95ad, 16134), (0x95b1, 19368),
+    (0x95bb, 19477), (0x95bc, 18962), (0x95c7, 18970), (0x95ca, 23501), (0x95d4, 22974), (0x95d5, 14999), (0x95d6, 22585), (0x95dc, 14753),
+    (0x95e1, 21982), (0x95e2, 17388), (0x961c, 17604), (0x9621, 21983), (0x962a, 22706), (0x962e, 19719), (0x9632, 17228), (0x963b, 21255),
+    (0x963f, 18930), (0x9640, 22402), (0x9642, 22909), (0x9644, 17605), (0x964b, 16451), (0x964c, 16697), (0x964d, 14184), (0x9650, 22953),
+    (0x965b, 22751), (0x965c, 22975), (0x965d, 18222), (0x965e, 18734), (0x965f, 21964), (0x9662, 20079), (0x9663, 21635), (0x9664, 21207),
+    (0x966a, 17249), (0x9670, 20303), (0x9673, 21636), (0x9675, 16488), (0x9676, 15887), (0x9677, 22967), (0x9678, 16468), (0x967d, 19148),
+    (0x9685, 19929), (0x9686, 16479), (0x968a, 15748), (0x968b, 18566), (0x968d, 23524), (0x968e, 14548), (0x9694, 14361), (0x9695, 20047),
+    (0x9698, 18991), (0x9699, 15137), (0x969b, 21208), (0x969c, 20806), (0x96a3, 16521), (0x96a7, 18567), (0x96a8, 18568), (0x96aa, 23132),
+    (0x96b1, 20298), (0x96b7, 16288), (0x96bb, 21965), (0x96c0, 20660), (0x96c1, 18955), (0x96c4, 20054), (0x96c5, 18931), (0x96c6, 21757),
+    (0x96c7, 14585), (0x96c9, 22368), (0x96cb, 21445), (0x96cc, 20648), (0x96cd, 19693), (0x96ce, 20854), (0x96d5, 21256), (0x96d6, 18569),
+    (0x96d9, 18915), (0x96db, 22200), (0x96dc, 20673), (0x96e2, 16513), (0x96e3, 15376), (0x96e8, 19930), (0x96e9, 19931), (0x96ea, 18213),
+    (0x96ef, 17012), (0x96f0, 17627), (0x96f2, 20048), (0x96f6, 16280), (0x96f7, 16329), (0x96f9, 17068), (0x96fb, 21014), (0x9700, 18570),
+    (0x9706, 21088), (0x9707, 21637), (0x9711, 21032), (0x9713, 19546), (0x9716, 16528), (0x9719, 19521), (0x971c, 18015), (0x971e, 22934),
+    (0x9727, 16901), (0x9730, 17967), (0x9732, 16303), (0x9739, 17389), (0x973d, 21209), (0x9742, 16145), (0x9744, 18992), (0x9748, 16281),
+    (0x9751, 22021), (0x9756, 21089), (0x975c, 21186), (0x975e, 17775), (0x9761, 17033), (0x9762, 16717), (0x9769, 23137), (0x976d, 20494),
+    (0x9774, 23473), (0x9777, 20495), (0x977a, 16668), (0x978b, 23300), (0x978d, 18956), (0x978f, 14624), (0x97a0, 14966), (0x97a8, 14039),
+    (0x97ab, 14967), (0x97ad, 22734), (0x97c6, 21984), (0x97cb, 20108), (0x97d3, 22954), (0x97dc, 15888), (0x97f3, 20304), (0x97f6, 18385),
+    (0x97fb, 20049), (0x97ff, 23119), (0x9800, 23340), (0x9801, 23162), (0x9802, 21187), (0x9803, 14426), (0x9805, 23088), (0x9806, 18612),
+    (0x9808, 18571), (0x980a, 19940), (0x980c, 18410), (0x9810, 19547), (0x9811, 19720), (0x9812, 17093), (0x9813, 15908), (0x9817, 22601),
+    (0x9818, 16282), (0x982d, 15938), (0x9830, 23175), (0x9838, 14427), (0x9839, 22576), (0x983b, 17791), (0x9846, 14733), (0x984c, 21210),
+    (0x984d, 18999), (0x984e, 18946), (0x9854, 18957), (0x9858, 20080), (0x985a, 21015), (0x985e, 16465), (0x9865, 23341), (0x9867, 14586),
+    (0x986b, 21016), (0x986f, 23158), (0x98a8, 22901), (0x98af, 17984), (0x98b1, 22554), (0x98c4, 22798), (0x98c7, 22797), (0x98db, 17776),
+    (0x98dc, 17267), (0x98df, 18776), (0x98e1, 18401), (0x98e2, 15333), (0x98ed, 22373), (0x98ee, 20305), (0x98ef, 17094), (0x98f4, 20464),
+    (0x98fc, 17853), (0x98fd, 22778), (0x98fe, 18777), (0x9903, 14810), (0x9909, 23120), (0x990a, 19150), (0x990c, 20465), (0x9910, 21796),
+    (0x9913, 18932), (0x9918, 19299), (0x991e, 21017), (0x9920, 17416), (0x9928, 14754), (0x9945, 16660), (0x9949, 15152), (0x994b, 15005),
+    (0x994c, 21797), (0x994d, 18200), (0x9951, 15334), (0x9952, 19869), (0x9954, 19694), (0x9957, 23121), (0x9996, 18572), (0x9999, 23122),
+    (0x999d, 22919), (0x99a5, 17449), (0x99a8, 23291), (0x99ac, 16634), (0x99ad, 19158), (0x99ae, 22902), (0x99b1, 22403), (0x99b3, 22369),
+    (0x99b4, 18613), (0x99b9, 20506), (0x99c1, 17069), (0x99d0, 21425), (0x99d1, 15529), (0x99d2, 14958), (0x99d5, 13994), (0x99d9, 17606),
+    (0x99dd, 22404), (0x99df, 17854), (0x99ed, 23105), (0x99f1, 16068), (0x99ff, 21446), (0x9a01, 17795), (0x9a08, 17417), (0x9a0e, 15335),
+    (0x9a0f, 15336), (0x9a19, 22735), (0x9a2b, 14241), (0x9a30, 15954), (0x9a36, 22201), (0x9a37, 18386), (0x9a40, 16698), (0x9a43, 22799),
+    (0x9a45, 14959), (0x9a4d, 23659), (0x9a55, 14811), (0x9a57, 23133), (0x9a5a, 14428), (0x9a5b, 19315), (0x9a5f, 22339), (0x9a62, 16135),
+    (0x9a65, 15337), (0x9a69, 23495), (0x9a6a, 16136), (0x9aa8, 14608), (0x9ab8, 23106), (0x9ad3, 18573), (0x9ad4, 22032), (0x9ad8, 14587),
+    (0x9ae5, 19478), (0x9aee, 17200), (0x9b1a, 18574), (0x9b27, 16437), (0x9b2a, 22582), (0x9b31, 20051), (0x9b3c, 15106), (0x9b41, 14783),
+    (0x9b42, 23350), (0x9b43, 17201), (0x9b44, 17257), (0x9b45, 16694), (0x9b4f, 20109), (0x9b54, 16635), (0x9b5a, 19159), (0x9b6f, 16304),
+    (0x9b8e, 21033), (0x9b91, 22779), (0x9b9f, 18958), (0x9bab, 14812), (0x9bae, 18201), (0x9bc9, 16514), (0x9bd6, 22022), (0x9be4, 14605),
+    (0x9be8, 14429), (0x9c0d, 22202), (0x9c10, 18947), (0x9c12, 17450), (0x9c15, 22935), (0x9c25, 23496), (0x9c32, 19672), (0x9c3b, 16661),
+    (0x9c47, 14185), (0x9c49, 17399), (0x9c57, 16522), (0x9ce5, 21257), (0x9ce7, 17607), (0x9ce9, 14960), (0x9cf3, 17468), (0x9cf4, 16831),
+    (0x9cf6, 19358), (0x9d09, 18933), (0x9d1b, 20081), (0x9d26, 18981), (0x9d28, 18974), (0x9d3b, 23363), (0x9d51, 14372), (0x9d5d, 18934),
+    (0x9d60, 14595), (0x9d61, 16902), (0x9d6c, 17638), (0x9d72, 20661), (0x9da9, 16863), (0x9daf, 19098), (0x9db4, 22940), (0x9dc4, 14549),
+    (0x9dd7, 14961), (0x9df2, 22340), (0x9df8, 23706), (0x9df9, 20312), (0x9dfa, 16305), (0x9e1a, 19099), (0x9e1e, 16077), (0x9e75, 16306),
+    (0x9e79, 22968), (0x9e7d, 19479), (0x9e7f, 16312), (0x9e92, 15338), (0x9e93, 16313), (0x9e97, 16137), (0x9e9d, 17855), (0x9e9f, 16523),
+    (0x9ea5, 16699), (0x9eb4, 14968), (0x9eb5, 16718), (0x9ebb, 16636), (0x9ebe, 23698), (0x9ec3, 23525), (0x9ecd, 18153), (0x9ece, 16138),
+    (0x9ed1, 23712), (0x9ed4, 14348), (0x9ed8, 16904), (0x9edb, 15749), (0x9edc, 22220), (0x9ede, 21034), (0x9ee8, 15733), (0x9ef4, 17034),
+    (0x9f07, 19673), (0x9f08, 17400), (0x9f0e, 21188), (0x9f13, 14588), (0x9f20, 18154), (0x9f3b, 17777), (0x9f4a, 21211), (0x9f4b, 20822),
+    (0x9f4e, 20823), (0x9f52, 22370), (0x9f5f, 20855), (0x9f61, 16283), (0x9f67, 18214), (0x9f6a, 21782), (0x9f6c, 19160), (0x9f77, 18948),
+    (0x9f8d, 16438), (0x9f90, 17229), (0x9f95, 14059), (0x9f9c, 14962), (0xac00, 9026), (0xac01, 9027), (0xac02, 0), (0xac03, 1),
+    (0xac04, 9028), (0xac05, 2), (0xac06, 3), (0xac07, 9029), (0xac08, 9030), (0xac09, 9031), (0xac0a, 9032), (0xac0b, 4),
+    (0xac0c, 5), (0xac0d, 6), (0xac0e, 7), (0xac0f, 8), (0xac10, 9033), (0xac11, 9034), (0xac12, 9035), (0xac13, 9036),
+    (0xac14, 9037), (0xac15, 9038), (0xac16, 9039), (0xac17, 9040), (0xac18, 9), (0xac19, 9041), (0xac1a, 9042), (0xac1b, 9043),
+    (0xac1c, 9044), (0xac1d, 9045), (0xac1e, 10), (0xac1f, 11), (0xac20, 9046), (0xac21, 12), (0xac22, 13), (0xac23, 14),
+    (0xac24, 9047), (0xac25, 15), (0xac26, 16), (0xac27, 17), (0xac28, 18), (0xac29, 19), (0xac2a, 20), (0xac2b, 21),
+    (0xac2c, 9048), (0xac2d, 9049), (0xac2e, 22), (0xac2f, 9050), (0xac30, 9051), (0xac31, 9052), (0xac32, 23), (0xac33, 24),
+    (0xac34, 25), (0xac35, 32), (0xac36, 33), (0xac37, 34), (0xac38, 9053), (0xac39, 9054), (0xac3a, 35), (0xac3b, 36),
+    (0xac3c, 9055), (0xac3d, 37), (0xac3e, 38), (0xac3f, 39), (0xac40, 9056), (0xac41, 40), (0xac42, 41), (0xac43, 42),
+    (0xac44, 43), (0xac45, 44), (0xac46, 45), (0xac47, 46), (0xac48, 47), (0xac49, 48), (0xac4a, 49), (0xac4b, 9057),
+    (0xac4c, 50), (0xac4d, 9058), (0xac4e, 51), (0xac4f, 52), (0xac50, 53), (0xac51, 54), (0xac52, 55), (0xac53, 56),
+    (0xac54, 9059), (0xac55, 57), (0xac56, 64), (0xac57, 65), (0xac58, 9060), (0xac59, 66), (0xac5a, 67), (0xac5b, 68),
+    (0xac5c, 9061), (0xac5d, 69), (0xac5e, 70), (0xac5f, 71), (0xac60, 72), (0xac61, 73), (0xac62, 74), (0xac63, 75),
+    (0xac64, 76), (0xac65, 77), (0xac66, 78), (0xac67, 79), (0xac68, 80), (0xac69, 81), (0xac6a, 82), (0xac6b, 83),
+    (0xac6c, 84), (0xac6d, 85), (0xac6e, 86), (0xac6f, 87), (0xac70, 9062), (0xac71, 9063), (0xac72, 88), (0xac73, 89),
+    (0xac74, 9064), (0xac75, 90), (0xac76, 91), (0xac77, 9065), (0xac78, 9066), (0xac79, 92), (0xac7a, 9067), (0xac7b, 93),
+    (0xac7c, 94), (0xac7d, 95), (0xac7e, 96), (0xac7f, 97), (0xac80, 9068), (0xac81, 9069), (0xac82, 98), (0xac83, 9070),
+    (0xac84, 9071), (0xac85, 9072), (0xac86, 9073), (0xac87, 99), (0xac88, 100), (0xac89, 9074), (0xac8a, 9075), (0xac8b, 9076),
+    (0xac8c, 9077), (0xac8d, 101), (0xac8e, 102), (0xac8f, 103), (0xac90, 9078), (0xac91, 104), (0xac92, 105), (0xac93, 106),
+    (0xac94, 9079), (0xac95, 107), (0xac96, 108), (0xac97, 109), (0xac98, 110), (0xac99, 111), (0xac9a, 112), (0xac9b, 113),
+    (0xac9c, 9080), (0xac9d, 9081), (0xac9e, 114), (0xac9f, 9082), (0xaca0, 9083), (0xaca1, 9084), (0xaca2, 115), (0xaca3, 116),
+    (0xaca4, 117), (0xaca5, 118), (0xaca6, 119), (0xaca7, 120), (0xaca8, 9085), (0xaca9, 9086), (0xacaa, 9087), (0xacab, 121),
+    (0xacac, 9088), (0xacad, 122), (0xacae, 123), (0xacaf, 9089), (0xacb0, 9090), (0xacb1, 124), (0xacb2, 125), (0xacb3, 126),
+    (0xacb4, 127), (0xacb5, 128), (0xacb6, 129), (0xacb7, 130), (0xacb8, 9091), (0xacb9, 9092), (0xacba, 131), (0xacbb, 9093),
+    (0xacbc, 9094), (0xacbd, 9095), (0xacbe, 132), (0xacbf, 133), (0xacc0, 134), (0xacc1, 9096), (0xacc2, 135), (0xacc3, 136),
+    (0xacc4, 9097), (0xacc5, 137), (0xacc6, 138), (0xacc7, 139), (0xacc8, 9098), (0xacc9, 140), (0xacca, 141), (0xaccb, 142),
+    (0xaccc, 9099), (0xaccd, 143), (0xacce, 144), (0xaccf, 145), (0xacd0, 146), (0xacd1, 147), (0xacd2, 148), (0xacd3, 149),
+    (0xacd4, 150), (0xacd5, 9100), (0xacd6, 151), (0xacd7, 9101), (0xacd8, 152), (0xacd9, 153), (0xacda, 154), (0xacdb, 155),
+    (0xacdc, 156), (0xacdd, 157), (0xacde, 158), (0xacdf, 159), (0xace0, 9102), (0xace1, 9103), (0xace2, 160), (0xace3, 161),
+    (0xace4, 9104), (0xace5, 162), (0xace6, 163), (0xace7, 9105), (0xace8, 9106), (0xace9, 164), (0xacea, 9107), (0xaceb, 165),
+    (0xacec, 9108), (0xaced, 166), (0xacee, 167), (0xacef, 9109), (0xacf0, 9110), (0xacf1, 9111), (0xacf2, 168), (0xacf3, 9112),
+    (0xacf4, 169), (0xacf5, 9113), (0xacf6, 9114), (0xacf7, 170), (0xacf8, 171), (0xacf9, 172), (0xacfa, 173), (0xacfb, 174),
+    (0xacfc, 9115), (0xacfd, 9116), (0xacfe, 175), (0xacff, 176), (0xad00, 9117), (0xad01, 177), (0xad02, 178), (0xad03, 179),
+    (0xad04, 9118), (0xad05, 180), (0xad06, 9119), (0xad07, 181), (0xad08, 182), (0xad09, 183), (0xad0a, 184), (0xad0b, 185),
+    (0xad0c, 9216), (0xad0d, 9217), (0xad0e, 186), (0xad0f, 9218), (0xad10, 187), (0xad11, 9219), (0xad12, 188), (0xad13, 189),
+    (0xad14, 190), (0xad15, 191), (0xad16, 192), (0xad17, 193), (0xad18, 9220), (0xad19, 194), (0xad1a, 195), (0xad1b, 196),
+    (0xad1c, 9221), (0xad1d, 197), (0xad1e, 198), (0xad1f, 199), (0xad20, 9222), (0xad21, 200), (0xad22, 201), (0xad23, 202),
+    (0xad24, 203), (0xad25, 204), (0xad26, 205), (0xad27, 206), (0xad28, 207), (0xad29, 9223), (0xad2a, 208), (0xad2b, 209),
+    (0xad2c, 9224), (0xad2d, 9225), (0xad2e, 210), (0xad2f, 211), (0xad30, 212), (0xad31, 213), (0xad32, 214), (0xad33, 215),
+    (0xad34, 9226), (0xad35, 9227), (0xad36, 222), (0xad37, 223), (0xad38, 9228), (0xad39, 224), (0xad3a, 225), (0xad3b, 226),
+    (0xad3c, 9229), (0xad3d, 227), (0xad3e, 228), (0xad3f, 229), (0xad40, 230), (0xad41, 231), (0xad42, 232), (0xad43, 233),
+    (0xad44, 9230), (0xad45, 9231), (0xad46, 234), (0xad47, 9232), (0xad48, 235), (0xad49, 9233), (0xad4a, 236), (0xad4b, 237),
+    (0xad4c, 238), (0xad4d, 239), (0xad4e, 240), (0xad4f, 241), (0xad50, 9234), (0xad51, 242), (0xad52, 243), (0xad53, 244),
+    (0xad54, 9235), (0xad55, 245), (0xad56, 246), (0xad57, 247), (0xad58, 9236), (0xad59, 254), (0xad5a, 255), (0xad5b, 256),
+    (0xad5c, 257), (0xad5d, 258), (0xad5e, 259), (0xad5f, 260), (0xad60, 261), (0xad61, 9237), (0xad62, 262), (0xad63, 9238),
+    (0xad64, 263), (0xad65, 264), (0xad66, 265), (0xad67, 266), (0xad68, 267), (0xad69, 268), (0xad6a, 269), (0xad6b, 270),
+    (0xad6c, 9239), (0xad6d, 9240), (0xad6e, 271), (0xad6f, 272), (0xad70, 9241), (0xad71, 273), (0xad72, 274), (0xad73, 9242),
+    (0xad74, 9243), (0xad75, 9244), (0xad76, 9245), (0xad77, 275), (0xad78, 276), (0xad79, 277), (0xad7a, 278), (0xad7b, 9246),
+    (0xad7c, 9247), (0xad7d, 9248), (0xad7e, 279), (0xad7f, 9249), (0xad80, 280), (0xad81, 9250), (0xad82, 9251), (0xad83, 281),
+    (0xad84, 282), (0xad85, 283), (0xad86, 284), (0xad87, 285), (0xad88, 9252), (0xad89, 9253), (0xad8a, 286), (0xad8b, 287),
+    (0xad8c, 9254), (0xad8d, 288), (0xad8e, 289), (0xad8f, 290), (0xad90, 9255), (0xad91, 291), (0xad92, 292), (0xad93, 293),
+    (0xad94, 294), (0xad95, 295), (0xad96, 296), (0xad97, 297), (0xad98, 298), (0xad99, 299), (0xad9a, 300), (0xad9b, 301),
+    (0xad9c, 9256), (0xad9d, 9257), (0xad9e, 302), (0xad9f, 303), (0xada0, 304), (0xada1, 305), (0xada2, 306), (0xada3, 307),
+    (0xada4, 9258), (0xada5, 308), (0xada6, 309), (0xada7, 310), (0xada8, 311), (0xada9, 312), (0xadaa, 313), (0xadab, 314),
+    (0xadac, 315), (0xadad, 316), (0xadae, 317), (0xadaf, 318), (0xadb0, 319), (0xadb1, 320), (0xadb2, 321), (0xadb3, 322),
+    (0xadb4, 323), (0xadb5, 324), (0xadb6, 325), (0xadb7, 9259), (0xadb8, 326), (0xadb9, 327), (0xadba, 328), (0xadbb, 329),
+    (0xadbc, 330), (0xadbd, 331), (0xadbe, 332), (0xadbf, 333), (0xadc0, 9260), (0xadc1, 9261), (0xadc2, 334), (0xadc3, 335),
+    (0xadc4, 9262), (0xadc5, 336), (0xadc6, 337), (0xadc7, 338), (0xadc8, 9263), (0xadc9, 339), (0xadca, 340), (0xadcb, 341),
+    (0xadcc, 342), (0xadcd, 343), (0xadce, 344), (0xadcf, 345), (0xadd0, 9264), (0xadd1, 9265), (0xadd2, 346), (0xadd3, 9266),
+    (0xadd4, 347), (0xadd5, 348), (0xadd6, 349), (0xadd7, 350), (0xadd8, 351), (0xadd9, 352), (0xadda, 353), (0xaddb, 354),
+    (0xaddc, 9267), (0xaddd, 355), (0xadde, 356), (0xaddf, 357), (0xade0, 9268), (0xade1, 358), (0xade2, 359), (0xade3, 360),
+    (0xade4, 9269), (0xade5, 361), (0xade6, 362), (0xade7, 363), (0xade8, 364), (0xade9, 365), (0xadea, 366), (0xadeb, 367),
+    (0xadec, 368), (0xaded, 369), (0xadee, 370), (0xadef, 371), (0xadf0, 372), (0xadf1, 373), (0xadf2, 374), (0xadf3, 375),
+    (0xadf4, 376), (0xadf5, 377), (0xadf6, 378), (0xadf7, 379), (0xadf8, 9270), (0xadf9, 9271), (0xadfa, 380), (0xadfb, 381),
+    (0xadfc, 9272), (0xadfd, 382), (0xadfe, 383), (0xadff, 9273), (0xae00, 9274), (0xae01, 9275), (0xae02, 384), (0xae03, 385),
+    (0xae04, 386), (0xae05, 387), (0xae06, 388), (0xae07, 389), (0xae08, 9276), (0xae09, 9277), (0xae0a, 390), (0xae0b, 9278),
+    (0xae0c, 391), (0xae0d, 9279), (0xae0e, 392), (0xae0f, 393), (0xae10, 394), (0xae11, 395), (0xae12, 396), (0xae13, 397),
+    (0xae14, 9280), (0xae15, 398), (0xae16, 399), (0xae17, 400), (0xae18, 401), (0xae19, 402), (0xae1a, 403), (0xae1b, 404),
+    (0xae1c, 405), (0xae1d, 412), (0xae1e, 413), (0xae1f, 414), (0xae20, 415), (0xae21, 416), (0xae22, 417), (0xae23, 418),
+    (0xae24, 419), (0xae25, 420), (0xae26, 421), (0xae27, 422), (0xae28, 423), (0xae29, 424), (0xae2a, 425), (0xae2b, 426),
+    (0xae2c, 427), (0xae2d, 428), (0xae2e, 429), (0xae2f, 430), (0xae30, 9281), (0xae31, 9282), (0xae32, 431), (0xae33, 432),
+    (0xae34, 9283), (0xae35, 433), (0xae36, 434), (0xae37, 9284), (0xae38, 9285), (0xae39, 435), (0xae3a, 9286), (0xae3b, 436),
+    (0xae3c, 437), (0xae3d, 444), (0xae3e, 445), (0xae3f, 446), (0xae40, 9287), (0xae41, 9288), (0xae42, 447), (0xae43, 9289),
+    (0xae44, 448), (0xae45, 9290), (0xae46, 9291), (0xae47, 449), (0xae48, 450), (0xae49, 451), (0xae4a, 9292), (0xae4b, 452),
+    (0xae4c, 9293), (0xae4d, 9294), (0xae4e, 9295), (0xae4f, 453), (0xae50, 9296), (0xae51, 454), (0xae52, 455), (0xae53, 456),
+    (0xae54, 9297), (0xae55, 457), (0xae56, 9298), (0xae57, 458), (0xae58, 459), (0xae59, 460), (0xae5a, 461), (0xae5b, 462),
+    (0xae5c, 9299), (0xae5d, 9300), (0xae5e, 463), (0xae5f, 9301), (0xae60, 9302), (0xae61, 9303), (0xae62, 464), (0xae63, 465),
+    (0xae64, 466), (0xae65, 9304), (0xae66, 467), (0xae67, 468), (0xae68, 9305), (0xae69, 9306), (0xae6a, 469), (0xae6b, 470),
+    (0xae6c, 9307), (0xae6d, 471), (0xae6e, 472), (0xae6f, 473), (0xae70, 9308), (0xae71, 474), (0xae72, 475), (0xae73, 476),
+    (0xae74, 477), (0xae75, 478), (0xae76, 479), (0xae77, 480), (0xae78, 9309), (0xae79, 9406), (0xae7a, 481), (0xae7b, 9407),
+    (0xae7c, 9408), (0xae7d, 9409), (0xae7e, 482), (0xae7f, 483), (0xae80, 484), (0xae81, 485), (0xae82, 486), (0xae83, 487),
+    (0xae84, 9410), (0xae85, 9411), (0xae86, 488), (0xae87, 489), (0xae88, 490), (0xae89, 491), (0xae8a, 492), (0xae8b, 493),
+    (0xae8c, 9412), (0xae8d, 494), (0xae8e, 495), (0xae8f, 496), (0xae90, 497), (0xae91, 498), (0xae92, 499), (0xae93, 500),
+    (0xae94, 501), (0xae95, 502), (0xae96, 503), (0xae97, 504), (0xae98, 505), (0xae99, 506), (0xae9a, 507), (0xae9b, 508),
+    (0xae9c, 509), (0xae9d, 510), (0xae9e, 511), (0xae9f, 512), (0xaea0, 513), (0xaea1, 514), (0xaea2, 515), (0xaea3, 516),
+    (0xaea4, 517), (0xaea5, 518), (0xaea6, 519), (0xaea7, 520), (0xaea8, 521), (0xaea9, 522), (0xaeaa, 523), (0xaeab, 524),
+    (0xaeac, 525), (0xaead, 526), (0xaeae, 527), (0xaeaf, 528), (0xaeb0, 529), (0xaeb1, 530), (0xaeb2, 531), (0xaeb3, 532),
+    (0xaeb4, 533), (0xaeb5, 534), (0xaeb6, 535), (0xaeb7, 536), (0xaeb8, 537), (0xaeb9, 538), (0xaeba, 539), (0xaebb, 540),
+    (0xaebc, 9413), (0xaebd, 9414), (0xaebe, 9415), (0xaebf, 541), (0xaec0, 9416), (0xaec1, 542), (0xaec2, 543), (0xaec3, 544),
+    (0xaec4, 9417), (0xaec5, 545), (0xaec6, 546), (0xaec7, 547), (0xaec8, 548), (0xaec9, 549), (0xaeca, 550), (0xaecb, 551),
+    (0xaecc, 9418), (0xaecd, 9419), (0xaece, 552), (0xaecf, 9420), (0xaed0, 9421), (0xaed1, 9422), (0xaed2, 553), (0xaed3, 554),
+    (0xaed4, 555), (0xaed5, 556), (0xaed6, 557), (0xaed7, 558), (0xaed8, 9423), (0xaed9, 9424), (0xaeda, 559), (0xaedb, 560),
+    (0xaedc, 9425), (0xaedd, 561), (0xaede, 562), (0xaedf, 563), (0xaee0, 564), (0xaee1, 565), (0xaee2, 566), (0xaee3, 567),
+    (0xaee4, 568), (0xaee5, 569), (0xaee6, 570), (0xaee7, 571), (0xaee8, 9426), (0xaee9, 572), (0xaeea, 573), (0xaeeb, 9427),
+    (0xaeec, 574), (0xaeed, 9428), (0xaeee, 575), (0xaeef, 576), (0xaef0, 577), (0xaef1, 578), (0xaef2, 579), (0xaef3, 580),
+    (0xaef4, 9429), (0xaef5, 581), (0xaef6, 582), (0xaef7, 583), (0xaef8, 9430), (0xaef9, 584), (0xaefa, 585), (0xaefb, 586),
+    (0xaefc, 9431), (0xaefd, 587), (0xaefe, 588), (0xaeff, 589), (0xaf00, 590), (0xaf01, 591), (0xaf02, 592), (0xaf03, 593),
+    (0xaf04, 594), (0xaf05, 595), (0xaf06, 602), (0xaf07, 9432), (0xaf08, 9433), (0xaf09, 603), (0xaf0a, 604), (0xaf0b, 605),
+    (0xaf0c, 606), (0xaf0d, 9434), (0xaf0e, 607), (0xaf0f, 608), (0xaf10, 9435), (0xaf11, 609), (0xaf12, 610), (0xaf13, 611),
+    (0xaf14, 612), (0xaf15, 613), (0xaf16, 614), (0xaf17, 615), (0xaf18, 616), (0xaf19, 617), (0xaf1a, 618), (0xaf1b, 619),
+    (0xaf1c, 620), (0xaf1d, 621), (0xaf1e, 622), (0xaf1f, 623), (0xaf20, 624), (0xaf21, 625), (0xaf22, 626), (0xaf23, 627),
+    (0xaf24, 634), (0xaf25, 635), (0xaf26, 636), (0xaf27, 637), (0xaf28, 638), (0xaf29, 639), (0xaf2a, 640), (0xaf2b, 641),
+    (0xaf2c, 9436), (0xaf2d, 9437), (0xaf2e, 642), (0xaf2f, 643), (0xaf30, 9438), (0xaf31, 644), (0xaf32, 9439), (0xaf33, 645),
+    (0xaf34, 9440), (0xaf35, 646), (0xaf36, 647), (0xaf37, 648), (0xaf38, 649), (0xaf39, 650), (0xaf3a, 651), (0xaf3b, 652),
+    (0xaf3c, 9441), (0xaf3d, 9442), (0xaf3e, 653), (0xaf3f, 9443), (0xaf40, 654), (0xaf41, 9444), (0xaf42, 9445), (0xaf43, 9446),
+    (0xaf44, 655), (0xaf45, 656), (0xaf46, 657), (0xaf47, 658), (0xaf48, 9447), (0xaf49, 9448), (0xaf4a, 659), (0xaf4b, 660),
+    (0xaf4c, 661), (0xaf4d, 662), (0xaf4e, 663), (0xaf4f, 664), (0xaf50, 9449), (0xaf51, 665), (0xaf52, 666), (0xaf53, 667),
+    (0xaf54, 668), (0xaf55, 669), (0xaf56, 670), (0xaf57, 671), (0xaf58, 672), (0xaf59, 673), (0xaf5a, 674), (0xaf5b, 675),
+    (0xaf5c, 9450), (0xaf5d, 9451), (0xaf5e, 676), (0xaf5f, 677), (0xaf60, 678), (0xaf61, 679), (0xaf62, 680), (0xaf63, 681),
+    (0xaf64, 9452), (0xaf65, 9453), (0xaf66, 682), (0xaf67, 683), (0xaf68, 684), (0xaf69, 685), (0xaf6a, 686), (0xaf6b, 687),
+    (0xaf6c, 688), (0xaf6d, 689), (0xaf6e, 690), (0xaf6f, 691), (0xaf70, 692), (0xaf71, 693), (0xaf72, 694), (0xaf73, 695),
+    (0xaf74, 696), (0xaf75, 697), (0xaf76, 698), (0xaf77, 699), (0xaf78, 700), (0xaf79, 9454), (0xaf7a, 701), (0xaf7b, 702),
+    (0xaf7c, 703), (0xaf7d, 704), (0xaf7e, 705), (0xaf7f, 706), (0xaf80, 9455), (0xaf81, 707), (0xaf82, 708), (0xaf83, 709),
+    (0xaf84, 9456), (0xaf85, 710), (0xaf86, 711), (0xaf87, 712), (0xaf88, 9457), (0xaf89, 713), (0xaf8a, 714), (0xaf8b, 715),
+    (0xaf8c, 716), (0xaf8d, 717), (0xaf8e, 718), (0xaf8f, 719), (0xaf90, 9458), (0xaf91, 9459), (0xaf92, 720), (0xaf93, 721),
+    (0xaf94, 722), (0xaf95, 9460), (0xaf96, 723), (0xaf97, 724), (0xaf98, 725), (0xaf99, 726), (0xaf9a, 727), (0xaf9b, 728),
+    (0xaf9c, 9461), (0xaf9d, 729), (0xaf9e, 730), (0xaf9f, 731), (0xafa0, 732), (0xafa1, 733), (0xafa2, 734), (0xafa3, 735),
+    (0xafa4, 736), (0xafa5, 737), (0xafa6, 738), (0xafa7, 739), (0xafa8, 740), (0xafa9, 741), (0xafaa, 742), (0xafab, 743),
+    (0xafac, 744), (0xafad, 745), (0xafae, 746), (0xafaf, 747), (0xafb0, 748), (0xafb1, 749), (0xafb2, 750), (0xafb3, 751),
+    (0xafb4, 752), (0xafb5, 753), (0xafb6, 754), (0xafb7, 755), (0xafb8, 9462), (0xafb9, 9463), (0xafba, 756), (0xafbb, 757),
+    (0xafbc, 9464), (0xafbd, 758), (0xafbe, 759), (0xafbf, 760), (0xafc0, 9465), (0xafc1, 761), (0xafc2, 762), (0xafc3, 763),
+    (0xafc4, 764), (0xafc5, 765), (0xafc6, 766), (0xafc7, 9466), (0xafc8, 9467), (0xafc9, 9468), (0xafca, 767), (0xafcb, 9469),
+    (0xafcc, 768), (0xafcd, 9470), (0xafce, 9471), (0xafcf, 769), (0xafd0, 770), (0xafd1, 771), (0xafd2, 772), (0xafd3, 773),
+    (0xafd4, 9472), (0xafd5, 774), (0xafd6, 775), (0xafd7, 776), (0xafd8, 777), (0xafd9, 778), (0xafda, 779), (0xafdb, 780),
+    (0xafdc, 9473), (0xafdd, 781), (0xafde, 782), (0xafdf, 783), (0xafe0, 784), (0xafe1, 785), (0xafe2, 792), (0xafe3, 793),
+    (0xafe4, 794), (0xafe5, 795), (0xafe6, 796), (0xafe7, 797), (0xafe8, 9474), (0xafe9, 9475), (0xafea, 798), (0xafeb, 799),
+    (0xafec, 800), (0xafed, 801), (0xafee, 802), (0xafef, 803), (0xaff0, 9476), (0xaff1, 9477), (0xaff2, 804), (0xaff3, 805),
+    (0xaff4, 9478), (0xaff5, 806), (0xaff6, 807), (0xaff7, 808), (0xaff8, 9479), (0xaff9, 809), (0xaffa, 810), (0xaffb, 811),
+    (0xaffc, 812), (0xaffd, 813), (0xaffe, 814), (0xafff, 815), (0xb000, 9480), (0xb001, 9481), (0xb002, 816), (0xb003, 817),
+    (0xb004, 9482), (0xb005, 824), (0xb006, 825), (0xb007, 826), (0xb008, 827), (0xb009, 828), (0xb00a, 829), (0xb00b, 830),
+    (0xb00c, 9483), (0xb00d, 831), (0xb00e, 832), (0xb00f, 833), (0xb010, 9484), (0xb011, 834), (0xb012, 835), (0xb013, 836),
+    (0xb014, 9485), (0xb015, 837), (0xb016, 838), (0xb017, 839), (0xb018, 840), (0xb019, 841), (0xb01a, 842), (0xb01b, 843),
+    (0xb01c, 9486), (0xb01d, 9487), (0xb01e, 844), (0xb01f, 845), (0xb020, 846), (0xb021, 847), (0xb022, 848), (0xb023, 849),
+    (0xb024, 850), (0xb025, 851), (0xb026, 852), (0xb027, 853), (0xb028, 9488), (0xb029, 854), (0xb02a, 855), (0xb02b, 856),
+    (0xb02c, 857), (0xb02d, 858), (0xb02e, 859), (0xb02f, 860), (0xb030, 861), (0xb031, 862), (0xb032, 863), (0xb033, 864),
+    (0xb034, 865), (0xb035, 866), (0xb036, 867), (0xb037, 868), (0xb038, 869), (0xb039, 870), (0xb03a, 871), (0xb03b, 872),
+    (0xb03c, 873), (0xb03d, 874), (0xb03e, 875), (0xb03f, 876), (0xb040, 877), (0xb041, 878), (0xb042, 879), (0xb043, 880),
+    (0xb044, 9489), (0xb045, 9490), (0xb046, 881), (0xb047, 882), (0xb048, 9491), (0xb049, 883), (0xb04a, 9492), (0xb04b, 884),
+    (0xb04c, 9493), (0xb04d, 885), (0xb04e, 9494), (0xb04f, 886), (0xb050, 887), (0xb051, 888), (0xb052, 889), (0xb053, 9495),
+    (0xb054, 9496), (0xb055, 9497), (0xb056, 890), (0xb057, 9498), (0xb058, 891), (0xb059, 9499), (0xb05a, 892), (0xb05b, 893),
+    (0xb05c, 894), (0xb05d, 9596), (0xb05e, 895), (0xb05f, 896), (0xb060, 897), (0xb061, 898), (0xb062, 899), (0xb063, 900),
+    (0xb064, 901), (0xb065, 902), (0xb066, 903), (0xb067, 904), (0xb068, 905), (0xb069, 906), (0xb06a, 907), (0xb06b, 908),
+    (0xb06c, 909), (0xb06d, 910), (0xb06e, 911), (0xb06f, 912), (0xb070, 913), (0xb071, 914), (0xb072, 915), (0xb073, 916),
+    (0xb074, 917), (0xb075, 918), (0xb076, 919), (0xb077, 920), (0xb078, 921), (0xb079, 922), (0xb07a, 923), (0xb07b, 924),
+    (0xb07c, 9597), (0xb07d, 9598), (0xb07e, 925), (0xb07f, 926), (0xb080, 9599), (0xb081, 927), (0xb082, 928), (0xb083, 929),
+    (0xb084, 9600), (0xb085, 930), (0xb086, 931), (0xb087, 932), (0xb088, 933), (0xb089, 934), (0xb08a, 935), (0xb08b, 936),
+    (0xb08c, 9601), (0xb08d, 9602), (0xb08e, 937), (0xb08f, 9603), (0xb090, 938), (0xb091, 9604), (0xb092, 939), (0xb093, 940),
+    (0xb094, 941), (0xb095, 942), (0xb096, 943), (0xb097, 944), (0xb098, 9605), (0xb099, 9606), (0xb09a, 9607), (0xb09b, 945),
+    (0xb09c, 9608), (0xb09d, 946), (0xb09e, 947), (0xb09f, 9609), (0xb0a0, 9610), (0xb0a1, 9611), (0xb0a2, 9612), (0xb0a3, 948),
+    (0xb0a4, 949), (0xb0a5, 950), (0xb0a6, 951), (0xb0a7, 952), (0xb0a8, 9613), (0xb0a9, 9614), (0xb0aa, 953), (0xb0ab, 9615),
+    (0xb0ac, 9616), (0xb0ad, 9617), (0xb0ae, 9618), (0xb0af, 9619), (0xb0b0, 954), (0xb0b1, 9620), (0xb0b2, 955), (0xb0b3, 9621),
+    (0xb0b4, 9622), (0xb0b5, 9623), (0xb0b6, 956), (0xb0b7, 957), (0xb0b8, 9624), (0xb0b9, 958), (0xb0ba, 959), (0xb0bb, 960),
+    (0xb0bc, 9625), (0xb0bd, 961), (0xb0be, 962), (0xb0bf, 963), (0xb0c0, 964), (0xb0c1, 965), (0xb0c2, 966), (0xb0c3, 967),
+    (0xb0c4, 9626), (0xb0c5, 9627), (0xb0c6, 968), (0xb0c7, 9628), (0xb0c8, 9629), (0xb0c9, 9630), (0xb0ca, 969), (0xb0cb, 970),
+    (0xb0cc, 971), (0xb0cd, 972), (0xb0ce, 973), (0xb0cf, 974), (0xb0d0, 9631), (0xb0d1, 9632), (0xb0d2, 975), (0xb0d3, 982),
+    (0xb0d4, 9633), (0xb0d5, 983), (0xb0d6, 984), (0xb0d7, 985), (0xb0d8, 9634), (0xb0d9, 986), (0xb0da, 987), (0xb0db, 988),
+    (0xb0dc, 989), (0xb0dd, 990), (0xb0de, 991), (0xb0df, 992), (0xb0e0, 9635), (0xb0e1, 993), (0xb0e2, 994), (0xb0e3, 995),
+    (0xb0e4, 996), (0xb0e5, 9636), (0xb0e6, 997), (0xb0e7, 998), (0xb0e8, 999), (0xb0e9, 1000), (0xb0ea, 1001), (0xb0eb, 1002),
+    (0xb0ec, 1003), (0xb0ed, 1004), (0xb0ee, 1005), (0xb0ef, 1006), (0xb0f0, 1007), (0xb0f1, 1014), (0xb0f2, 1015), (0xb0f3, 1016),
+    (0xb0f4, 1017), (0xb0f5, 1018), (0xb0f6, 1019), (0xb0f7, 1020), (0xb0f8, 1021), (0xb0f9, 1022), (0xb0fa, 1023), (0xb0fb, 1024),
+    (0xb0fc, 1025), (0xb0fd, 1026), (0xb0fe, 1027), (0xb0ff, 1028), (0xb100, 1029), (0xb101, 1030), (0xb102, 1031), (0xb103, 1032),
+    (0xb104, 1033), (0xb105, 1034), (0xb106, 1035), (0xb107, 1036), (0xb108, 9637), (0xb109, 9638), (0xb10a, 1037), (0xb10b, 9639),
+    (0xb10c, 9640), (0xb10d, 1038), (0xb10e, 1039), (0xb10f, 1040), (0xb110, 9641), (0xb111, 1041), (0xb112, 9642), (0xb113, 9643),
+    (0xb114, 1042), (0xb115, 1043), (0xb116, 1044), (0xb117, 1045), (0xb118, 9644), (0xb119, 9645), (0xb11a, 1046), (0xb11b, 9646),
+    (0xb11c, 9647), (0xb11d, 9648), (0xb11e, 1047), (0xb11f, 1048), (0xb120, 1049), (0xb121, 1050), (0xb122, 1051), (0xb123, 9649),
+    (0xb124, 9650), (0xb125, 9651), (0xb126, 1052), (0xb127, 1053), (0xb128, 9652), (0xb129, 1054), (0xb12a, 1055), (0xb12b, 1056),
+    (0xb12c, 9653), (0xb12d, 1057), (0xb12e, 1058), (0xb12f, 1059), (0xb130, 1060), (0xb131, 1061), (0xb132, 1062), (0xb133, 1063),
+    (0xb134, 9654), (0xb135, 9655), (0xb136, 1064), (0xb137, 9656), (0xb138, 9657), (0xb139, 9658), (0xb13a, 1065), (0xb13b, 1066),
+    (0xb13c, 1067), (0xb13d, 1068), (0xb13e, 1069), (0xb13f, 1070), (0xb140, 9659), (0xb141, 9660), (0xb142, 1071), (0xb143, 1072),
+    (0xb144, 9661), (0xb145, 1073), (0xb146, 1074), (0xb147, 1075), (0xb148, 9662), (0xb149, 1076), (0xb14a, 1077), (0xb14b, 1078),
+    (0xb14c, 1079), (0xb14d, 1080), (0xb14e, 1081), (0xb14f, 1082), (0xb150, 9663), (0xb151, 9664), (0xb152, 1083), (0xb153, 1084),
+    (0xb154, 9665), (0xb155, 9666), (0xb156, 1085), (0xb157, 1086), (0xb158, 9667), (0xb159, 1087), (0xb15a, 1088), (0xb15b, 1089),
+    (0xb15c, 9668), (0xb15d, 1090), (0xb15e, 1091), (0xb15f, 1092), (0xb160, 9669), (0xb161, 1093), (0xb162, 1094), (0xb163, 1095),
+    (0xb164, 1096), (0xb165, 1097), (0xb166, 1098), (0xb167, 1099), (0xb168, 1100), (0xb169, 1101), (0xb16a, 1102), (0xb16b, 1103),
+    (0xb16c, 1104), (0xb16d, 1105), (0xb16e, 1106), (0xb16f, 1107), (0xb170, 1108), (0xb171, 1109), (0xb172, 1110), (0xb173, 1111),
+    (0xb174, 1112), (0xb175, 1113), (0xb176, 1114), (0xb177, 1115), (0xb178, 9670), (0xb179, 9671), (0xb17a, 1116), (0xb17b, 1117),
+    (0xb17c, 9672), (0xb17d, 1118), (0xb17e, 1119), (0xb17f, 1120), (0xb180, 9673), (0xb181, 1121), (0xb182, 9674), (0xb183, 1122),
+    (0xb184, 1123), (0xb185, 1124), (0xb186, 1125), (0xb187, 1126), (0xb188, 9675), (0xb189, 9676), (0xb18a, 1127), (0xb18b, 9677),
+    (0xb18c, 1128), (0xb18d, 9678), (0xb18e, 1129), (0xb18f, 1130), (0xb190, 1131), (0xb191, 1132), (0xb192, 9679), (0xb193, 9680),
+    (0xb194, 9681), (0xb195, 1133), (0xb196, 1134), (0xb197, 1135), (0xb198, 9682), (0xb199, 1136), (0xb19a, 1137), (0xb19b, 1138),
+    (0xb19c, 9683), (0xb19d, 1139), (0xb19e, 1140), (0xb19f, 1141), (0xb1a0, 1142), (0xb1a1, 1143), (0xb1a2, 1144), (0xb1a3, 1145),
+    (0xb1a4, 1146), (0xb1a5, 1147), (0xb1a6, 1148), (0xb1a7, 1149), (0xb1a8, 9684), (0xb1a9, 1150), (0xb1aa, 1151), (0xb1ab, 1152),
+    (0xb1ac, 1153), (0xb1ad, 1154), (0xb1ae, 1155), (0xb1af, 1156), (0xb1b0, 1157), (0xb1b1, 1158), (0xb1b2, 1159), (0xb1b3, 1160),
+    (0xb1b4, 1161), (0xb1b5, 1162), (0xb1b6, 1163), (0xb1b7, 1164), (0xb1b8, 1165), (0xb1b9, 1172), (0xb1ba, 1173), (0xb1bb, 1174),
+    (0xb1bc, 1175), (0xb1bd, 1176), (0xb1be, 1177), (0xb1bf, 1178), (0xb1c0, 1179), (0xb1c1, 1180), (0xb1c2, 1181), (0xb1c3, 1182),
+    (0xb1c4, 1183), (0xb1c5, 1184), (0xb1c6, 1185), (0xb1c7, 1186), (0xb1c8, 1187), (0xb1c9, 1188), (0xb1ca, 1189), (0xb1cb, 1190),
+    (0xb1cc, 9685), (0xb1cd, 1191), (0xb1ce, 1192), (0xb1cf, 1193), (0xb1d0, 9686), (0xb1d1, 1194), (0xb1d2, 1195), (0xb1d3, 1196),
+    (0xb1d4, 9687), (0xb1d5, 1197), (0xb1d6, 1204), (0xb1d7, 1205), (0xb1d8, 1206), (0xb1d9, 1207), (0xb1da, 1208), (0xb1db, 1209),
+    (0xb1dc, 9688), (0xb1dd, 9689), (0xb1de, 1210), (0xb1df, 9786), (0xb1e0, 1211), (0xb1e1, 1212), (0xb1e2, 1213), (0xb1e3, 1214),
+    (0xb1e4, 1215), (0xb1e5, 1216), (0xb1e6, 1217), (0xb1e7, 1218), (0xb1e8, 9787), (0xb1e9, 9788), (0xb1ea, 1219), (0xb1eb, 1220),
+    (0xb1ec, 9789), (0xb1ed, 1221), (0xb1ee, 1222), (0xb1ef, 1223), (0xb1f0, 9790), (0xb1f1, 1224), (0xb1f2, 1225), (0xb1f3, 1226),
+    (0xb1f4, 1227), (0xb1f5, 1228), (0xb1f6, 1229), (0xb1f7, 1230), (0xb1f8, 1231), (0xb1f9, 9791), (0xb1fa, 1232), (0xb1fb, 9792),
+    (0xb1fc, 1233), (0xb1fd, 9793), (0xb1fe, 1234), (0xb1ff, 1235), (0xb200, 1236), (0xb201, 1237), (0xb202, 1238), (0xb203, 1239),
+    (0xb204, 9794), (0xb205, 9795), (0xb206, 1240), (0xb207, 1241), (0xb208, 9796), (0xb209, 1242), (0xb20a, 1243), (0xb20b, 9797),
+    (0xb20c, 9798), (0xb20d, 1244), (0xb20e, 1245), (0xb20f, 1246), (0xb210, 1247), (0xb211, 1248), (0xb212, 1249), (0xb213, 1250),
+    (0xb214, 9799), (0xb215, 9800), (0xb216, 1251), (0xb217, 9801), (0xb218, 1252), (0xb219, 9802), (0xb21a, 1253), (0xb21b, 1254),
+    (0xb21c, 1255), (0xb21d, 1256), (0xb21e, 1257), (0xb21f, 1258), (0xb220, 9803), (0xb221, 1259), (0xb222, 1260), (0xb223, 1261),
+    (0xb224, 1262), (0xb225, 1263), (0xb226, 1264), (0xb227, 1265), (0xb228, 1266), (0xb229, 1267), (0xb22a, 1268), (0xb22b, 1269),
+    (0xb22c, 1270), (0xb22d, 1271), (0xb22e, 1272), (0xb22f, 1273), (0xb230, 1274), (0xb231, 1275), (0xb232, 1276), (0xb233, 1277),
+    (0xb234, 9804), (0xb235, 1278), (0xb236, 1279), (0xb237, 1280), (0xb238, 1281), (0xb239, 1282), (0xb23a, 1283), (0xb23b, 1284),
+    (0xb23c, 9805), (0xb23d, 1285), (0xb23e, 1286), (0xb23f, 1287), (0xb240, 1288), (0xb241, 1289), (0xb242, 1290), (0xb243, 1291),
+    (0xb244, 1292), (0xb245, 1293), (0xb246, 1294), (0xb247, 1295), (0xb248, 1296), (0xb249, 1297), (0xb24a, 1298), (0xb24b, 1299),
+    (0xb24c, 1300), (0xb24d, 1301), (0xb24e, 1302), (0xb24f, 1303), (0xb250, 1304), (0xb251, 1305), (0xb252, 1306), (0xb253, 1307),
+    (0xb254, 1308), (0xb255, 1309), (0xb256, 1310), (0xb257, 1311), (0xb258, 9806), (0xb259, 1312), (0xb25a, 1313), (0xb25b, 1314),
+    (0xb25c, 9807), (0xb25d, 1315), (0xb25e, 1316), (0xb25f, 1317), (0xb260, 9808), (0xb261, 1318), (0xb262, 1319), (0xb263, 1320),
+    (0xb264, 1321), (0xb265, 1322), (0xb266, 1323), (0xb267, 1324), (0xb268, 9809), (0xb269, 9810), (0xb26a, 1325), (0xb26b, 1326),
+    (0xb26c, 1327), (0xb26d, 1328), (0xb26e, 1329), (0xb26f, 1330), (0xb270, 1331), (0xb271, 1332), (0xb272, 1333), (0xb273, 1334),
+    (0xb274, 9811), (0xb275, 9812), (0xb276, 1335), (0xb277, 1336), (0xb278, 1337), (0xb279, 1338), (0xb27a, 1339), (0xb27b, 1340),
+    (0xb27c, 9813), (0xb27d, 1341), (0xb27e, 1342), (0xb27f, 1343), (0xb280, 1344), (0xb281, 1345), (0xb282, 1346), (0xb283, 1347),
+    (0xb284, 9814), (0xb285, 9815), (0xb286, 1348), (0xb287, 1349), (0xb288, 1350), (0xb289, 9816), (0xb28a, 1351), (0xb28b, 1352),
+    (0xb28c, 1353), (0xb28d, 1354), (0xb28e, 1355), (0xb28f, 1362), (0xb290, 9817), (0xb291, 9818), (0xb292, 1363), (0xb293, 1364),
+    (0xb294, 9819), (0xb295, 1365), (0xb296, 1366), (0xb297, 1367), (0xb298, 9820), (0xb299, 9821), (0xb29a, 9822), (0xb29b, 1368),
+    (0xb29c, 1369), (0xb29d, 1370), (0xb29e, 1371), (0xb29f, 1372), (0xb2a0, 9823), (0xb2a1, 9824), (0xb2a2, 1373), (0xb2a3, 9825),
+    (0xb2a4, 1374), (0xb2a5, 9826), (0xb2a6, 9827), (0xb2a7, 1375), (0xb2a8, 1376), (0xb2a9, 1377), (0xb2aa, 9828), (0xb2ab, 1378),
+    (0xb2ac, 9829), (0xb2ad, 1379), (0xb2ae, 1380), (0xb2af, 1381), (0xb2b0, 9830), (0xb2b1, 1382), (0xb2b2, 1383), (0xb2b3, 1384),
+    (0xb2b4, 9831), (0xb2b5, 1385), (0xb2b6, 1386), (0xb2b7, 1387), (0xb2b8, 1394), (0xb2b9, 1395), (0xb2ba, 1396), (0xb2bb, 1397),
+    (0xb2bc, 1398), (0xb2bd, 1399), (0xb2be, 1400), (0xb2bf, 1401), (0xb2c0, 1402), (0xb2c1, 1403), (0xb2c2, 1404), (0xb2c3, 1405),
+    (0xb2c4, 1406), (0xb2c5, 1407), (0xb2c6, 1408), (0xb2c7, 1409), (0xb2c8, 9832), (0xb2c9, 9833), (0xb2ca, 1410), (0xb2cb, 1411),
+    (0xb2cc, 9834), (0xb2cd, 1412), (0xb2ce, 1413), (0xb2cf, 1414), (0xb2d0, 9835), (0xb2d1, 1415), (0xb2d2, 9836), (0xb2d3, 1416),
+    (0xb2d4, 1417), (0xb2d5, 1418), (0xb2d6, 1419), (0xb2d7, 1420), (0xb2d8, 9837), (0xb2d9, 9838), (0xb2da, 1421), (0xb2db, 9839),
+    (0xb2dc, 1422), (0xb2dd, 9840), (0xb2de, 1423), (0xb2df, 1424), (0xb2e0, 1425), (0xb2e1, 1426), (0xb2e2, 9841), (0xb2e3, 1427),
+    (0xb2e4, 9842), (0xb2e5, 9843), (0xb2e6, 9844), (0xb2e7, 1428), (0xb2e8, 9845), (0xb2e9, 1429), (0xb2ea, 1430), (0xb2eb, 9846),
+    (0xb2ec, 9847), (0xb2ed, 9848), (0xb2ee, 9849), (0xb2ef, 9850), (0xb2f0, 1431), (0xb2f1, 1432), (0xb2f2, 1433), (0xb2f3, 9851),
+    (0xb2f4, 9852), (0xb2f5, 9853), (0xb2f6, 1434), (0xb2f7, 9854), (0xb2f8, 9855), (0xb2f9, 9856), (0xb2fa, 9857), (0xb2fb, 9858),
+    (0xb2fc, 1435), (0xb2fd, 1436), (0xb2fe, 1437), (0xb2ff, 9859), (0xb300, 9860), (0xb301, 9861), (0xb302, 1438), (0xb303, 1439),
+    (0xb304, 9862), (0xb305, 1440), (0xb306, 1441), (0xb307, 1442), (0xb308, 9863), (0xb309, 1443), (0xb30a, 1444), (0xb30b, 1445),
+    (0xb30c, 1446), (0xb30d, 1447), (0xb30e, 1448), (0xb30f, 1449), (0xb310, 9864), (0xb311, 9865), (0xb312, 1450), (0xb313, 9866),
+    (0xb314, 9867), (0xb315, 9868), (0xb316, 1451), (0xb317, 1452), (0xb318, 1453), (0xb319, 1454), (0xb31a, 1455), (0xb31b, 1456),
+    (0xb31c, 9869), (0xb31d, 1457), (0xb31e, 1458), (0xb31f, 1459), (0xb320, 1460), (0xb321, 1461), (0xb322, 1462), (0xb323, 1463),
+    (0xb324, 1464), (0xb325, 1465), (0xb326, 1466), (0xb327, 1467), (0xb328, 1468), (0xb329, 1469), (0xb32a, 1470), (0xb32b, 1471),
+    (0xb32c, 1472), (0xb32d, 1473), (0xb32e, 1474), (0xb32f, 1475), (0xb330, 1476), (0xb331, 1477), (0xb332, 1478), (0xb333, 1479),
+    (0xb334, 1480), (0xb335, 1481), (0xb336, 1482), (0xb337, 1483), (0xb338, 1484), (0xb339, 1485), (0xb33a, 1486), (0xb33b, 1487),
+    (0xb33c, 1488), (0xb33d, 1489), (0xb33e, 1490), (0xb33f, 1491), (0xb340, 1492), (0xb341, 1493), (0xb342, 1494), (0xb343, 1495),
+    (0xb344, 1496), (0xb345, 1497), (0xb346, 1498), (0xb347, 1499), (0xb348, 1500), (0xb349, 1501), (0xb34a, 1502), (0xb34b, 1503),
+    (0xb34c, 1504), (0xb34d, 1505), (0xb34e, 1506), (0xb34f, 1507), (0xb350, 1508), (0xb351, 1509), (0xb352, 1510), (0xb353, 1511),
+    (0xb354, 9870), (0xb355, 9871), (0xb356, 9872), (0xb357, 1512), (0xb358, 9873), (0xb359, 1513), (0xb35a, 1514), (0xb35b, 9874),
+    (0xb35c, 9875), (0xb35d, 1515), (0xb35e, 9876), (0xb35f, 9877), (0xb360, 1516), (0xb361, 1517), (0xb362, 1518), (0xb363, 1519),
+    (0xb364, 9878), (0xb365, 9879), (0xb366, 1520), (0xb367, 9976), (0xb368, 1521), (0xb369, 9977), (0xb36a, 1522), (0xb36b, 9978),
+    (0xb36c, 1523), (0xb36d, 1524), (0xb36e, 9979), (0xb36f, 1525), (0xb370, 9980), (0xb371, 9981), (0xb372, 1526), (0xb373, 1527),
+    (0xb374, 9982), (0xb375, 1528), (0xb376, 1529), (0xb377, 1530), (0xb378, 9983), (0xb379, 1531), (0xb37a, 1532), (0xb37b, 1533),
+    (0xb37c, 1534), (0xb37d, 1535), (0xb37e, 1536), (0xb37f, 1537), (0xb380, 9984), (0xb381, 9985), (0xb382, 1538), (0xb383, 9986),
+    (0xb384, 9987), (0xb385, 9988), (0xb386, 1539), (0xb387, 1540), (0xb388, 1541), (0xb389, 1542), (0xb38a, 1543), (0xb38b, 1544),
+    (0xb38c, 9989), (0xb38d, 1545), (0xb38e, 1552), (0xb38f, 1553), (0xb390, 9990), (0xb391, 1554), (0xb392, 1555), (0xb393, 1556),
+    (0xb394, 9991), (0xb395, 1557), (0xb396, 1558), (0xb397, 1559), (0xb398, 1560), (0xb399, 1561), (0xb39a, 1562), (0xb39b, 1563),
+    (0xb39c, 1564), (0xb39d, 1565), (0xb39e, 1566), (0xb39f, 1567), (0xb3a0, 9992), (0xb3a1, 9993), (0xb3a2, 1568), (0xb3a3, 1569),
+    (0xb3a4, 1570), (0xb3a5, 1571), (0xb3a6, 1572), (0xb3a7, 1573), (0xb3a8, 9994), (0xb3a9, 1574), (0xb3aa, 1575), (0xb3ab, 1576),
+    (0xb3ac, 9995), (0xb3ad, 1577), (0xb3ae, 1584), (0xb3af, 1585), (0xb3b0, 1586), (0xb3b1, 1587), (0xb3b2, 1588), (0xb3b3, 1589),
+    (0xb3b4, 1590), (0xb3b5, 1591), (0xb3b6, 1592), (0xb3b7, 1593), (0xb3b8, 1594), (0xb3b9, 1595), (0xb3ba, 1596), (0xb3bb, 1597),
+    (0xb3bc, 1598), (0xb3bd, 1599), (0xb3be, 1600), (0xb3bf, 1601), (0xb3c0, 1602), (0xb3c1, 1603), (0xb3c2, 1604), (0xb3c3, 1605),
+    (0xb3c4, 9996), (0xb3c5, 9997), (0xb3c6, 1606), (0xb3c7, 1607), (0xb3c8, 9998), (0xb3c9, 1608), (0xb3ca, 1609), (0xb3cb, 9999),
+    (0xb3cc, 10000), (0xb3cd, 1610), (0xb3ce, 10001), (0xb3cf, 1611), (0xb3d0, 10002), (0xb3d1, 1612), (0xb3d2, 1613), (0xb3d3, 1614),
+    (0xb3d4, 10003), (0xb3d5, 10004), (0xb3d6, 1615), (0xb3d7, 10005), (0xb3d8, 1616), (0xb3d9, 10006), (0xb3da, 1617), (0xb3db, 10007),
+    (0xb3dc, 1618), (0xb3dd, 10008), (0xb3de, 1619), (0xb3df, 1620), (0xb3e0, 10009), (0xb3e1, 1621), (0xb3e2, 1622), (0xb3e3, 1623),
+    (0xb3e4, 10010), (0xb3e5, 1624), (0xb3e6, 1625), (0xb3e7, 1626), (0xb3e8, 10011), (0xb3e9, 1627), (0xb3ea, 1628), (0xb3eb, 1629),
+    (0xb3ec, 1630), (0xb3ed, 1631), (0xb3ee, 1632), (0xb3ef, 1633), (0xb3f0, 1634), (0xb3f1, 1635), (0xb3f2, 1636), (0xb3f3, 1637),
+    (0xb3f4, 1638), (0xb3f5, 1639), (0xb3f6, 1640), (0xb3f7, 1641), (0xb3f8, 1642), (0xb3f9, 1643), (0xb3fa, 1644), (0xb3fb, 1645),
+    (0xb3fc, 10012), (0xb3fd, 1646), (0xb3fe, 1647), (0xb3ff, 1648), (0xb400, 1649), (0xb401, 1650), (0xb402, 1651), (0xb403, 1652),
+    (0xb404, 1653), (0xb405, 1654), (0xb406, 1655), (0xb407, 1656), (0xb408, 1657), (0xb409, 1658), (0xb40a, 1659), (0xb40b, 1660),
+    (0xb40c, 1661), (0xb40d, 1662), (0xb40e, 1663), (0xb40f, 1664), (0xb410, 10013), (0xb411, 1665), (0xb412, 1666), (0xb413, 1667),
+    (0xb414, 1668), (0xb415, 1669), (0xb416, 1670), (0xb417, 1671), (0xb418, 10014), (0xb419, 1672), (0xb41a, 1673), (0xb41b, 1674),
+    (0xb41c, 10015), (0xb41d, 1675), (0xb41e, 1676), (0xb41f, 1677), (0xb420, 10016), (0xb421, 1678), (0xb422, 1679), (0xb423, 1680),
+    (0xb424, 1681), (0xb425, 1682), (0xb426, 1683), (0xb427, 1684), (0xb428, 10017), (0xb429, 10018), (0xb42a, 1685), (0xb42b, 10019),
+    (0xb42c, 1686), (0xb42d, 1687), (0xb42e, 1688), (0xb42f, 1689), (0xb430, 1690), (0xb431, 1691), (0xb432, 1692), (0xb433, 1693),
+    (0xb434, 10020), (0xb435, 1694), (0xb436, 1695), (0xb437, 1696), (0xb438, 1697), (0xb439, 1698), (0xb43a, 1699), (0xb43b, 1700),
+    (0xb43c, 1701), (0xb43d, 1702), (0xb43e, 1703), (0xb43f, 1704), (0xb440, 1705), (0xb441, 1706), (0xb442, 1707), (0xb443, 1708),
+    (0xb444, 1709), (0xb445, 1710), (0xb446, 1711), (0xb447, 1712), (0xb448, 1713), (0xb449, 1714), (0xb44a, 1715), (0xb44b, 1716),
+    (0xb44c, 1717), (0xb44d, 1718), (0xb44e, 1719), (0xb44f, 1720), (0xb450, 10021), (0xb451, 10022), (0xb452, 1721), (0xb453, 1722),
+    (0xb454, 10023), (0xb455, 1723), (0xb456, 1724), (0xb457, 1725), (0xb458, 10024), (0xb459, 1726), (0xb45a, 1727), (0xb45b, 1728),
+    (0xb45c, 1729), (0xb45d, 1730), (0xb45e, 1731), (0xb45f, 1732), (0xb460, 10025), (0xb461, 10026), (0xb462, 1733), (0xb463, 10027),
+    (0xb464, 1734), (0xb465, 10028), (0xb466, 1735), (0xb467, 1742), (0xb468, 1743), (0xb469, 1744), (0xb46a, 1745), (0xb46b, 1746),
+    (0xb46c, 10029), (0xb46d, 1747), (0xb46e, 1748), (0xb46f, 1749), (0xb470, 1750), (0xb471, 1751), (0xb472, 1752), (0xb473, 1753),
+    (0xb474, 1754), (0xb475, 1755), (0xb476, 1756), (0xb477, 1757), (0xb478, 1758), (0xb479, 1759), (0xb47a, 1760), (0xb47b, 1761),
+    (0xb47c, 1762), (0xb47d, 1763), (0xb47e, 1764), (0xb47f, 1765), (0xb480, 10030), (0xb481, 1766), (0xb482, 1767), (0xb483, 1774),
+    (0xb484, 1775), (0xb485, 1776), (0xb486, 1777), (0xb487, 1778), (0xb488, 10031), (0xb489, 1779), (0xb48a, 1780), (0xb48b, 1781),
+    (0xb48c, 1782), (0xb48d, 1783), (0xb48e, 1784), (0xb48f, 1785), (0xb490, 1786), (0xb491, 1787), (0xb492, 1788), (0xb493, 1789),
+    (0xb494, 1790), (0xb495, 1791), (0xb496, 1792), (0xb497, 1793), (0xb498, 1794), (0xb499, 1795), (0xb49a, 1796), (0xb49b, 1797),
+    (0xb49c, 1798), (0xb49d, 10032), (0xb49e, 1799), (0xb49f, 1800), (0xb4a0, 1801), (0xb4a1, 1802), (0xb4a2, 1803), (0xb4a3, 1804),
+    (0xb4a4, 10033), (0xb4a5, 1805), (0xb4a6, 1806), (0xb4a7, 1807), (0xb4a8, 10034), (0xb4a9, 1808), (0xb4aa, 1809), (0xb4ab, 1810),
+    (0xb4ac, 10035), (0xb4ad, 1811), (0xb4ae, 1812), (0xb4af, 1813), (0xb4b0, 1814), (0xb4b1, 1815), (0xb4b2, 1816), (0xb4b3, 1817),
+    (0xb4b4, 1818), (0xb4b5, 10036), (0xb4b6, 1819), (0xb4b7, 10037), (0xb4b8, 1820), (0xb4b9, 10038), (0xb4ba, 1821), (0xb4bb, 1822),
+    (0xb4bc, 1823), (0xb4bd, 1824), (0xb4be, 1825), (0xb4bf, 1826), (0xb4c0, 10039), (0xb4c1, 1827), (0xb4c2, 1828), (0xb4c3, 1829),
+    (0xb4c4, 10040), (0xb4c5, 1830), (0xb4c6, 1831), (0xb4c7, 1832), (0xb4c8, 10041), (0xb4c9, 1833), (0xb4ca, 1834), (0xb4cb, 1835),
+    (0xb4cc, 1836), (0xb4cd, 1837), (0xb4ce, 1838), (0xb4cf, 1839), (0xb4d0, 10042), (0xb4d1, 1840), (0xb4d2, 1841), (0xb4d3, 1842),
+    (0xb4d4, 1843), (0xb4d5, 10043), (0xb4d6, 1844), (0xb4d7, 1845), (0xb4d8, 1846), (0xb4d9, 1847), (0xb4da, 1848), (0xb4db, 1849),
+    (0xb4dc, 10044), (0xb4dd, 10045), (0xb4de, 1850), (0xb4df, 1851), (0xb4e0, 10046), (0xb4e1, 1852), (0xb4e2, 1853), (0xb4e3, 10047),
+    (0xb4e4, 10048), (0xb4e5, 1854), (0xb4e6, 10049), (0xb4e7, 1855), (0xb4e8, 1856), (0xb4e9, 1857), (0xb4ea, 1858), (0xb4eb, 1859),
+    (0xb4ec, 10050), (0xb4ed, 10051), (0xb4ee, 1860), (0xb4ef, 10052), (0xb4f0, 1861), (0xb4f1, 10053), (0xb4f2, 1862), (0xb4f3, 1863),
+    (0xb4f4, 1864), (0xb4f5, 1865), (0xb4f6, 1866), (0xb4f7, 1867), (0xb4f8, 10054), (0xb4f9, 1868), (0xb4fa, 1869), (0xb4fb, 1870),
+    (0xb4fc, 1871), (0xb4fd, 1872), (0xb4fe, 1873), (0xb4ff, 1874), (0xb500, 1875), (0xb501, 1876), (0xb502, 1877), (0xb503, 1878),
+    (0xb504, 1879), (0xb505, 1880), (0xb506, 1881), (0xb507, 1882), (0xb508, 1883), (0xb509, 1884), (0xb50a, 1885), (0xb50b, 1886),
+    (0xb50c, 1887), (0xb50d, 1888), (0xb50e, 1889), (0xb50f, 1890), (0xb510, 1891), (0xb511, 1892), (0xb512, 1893), (0xb513, 1894),
+    (0xb514, 10055), (0xb515, 10056), (0xb516, 1895), (0xb517, 1896), (0xb518, 10057), (0xb519, 1897), (0xb51a, 1898), (0xb51b, 10058),
+    (0xb51c, 10059), (0xb51d, 1899), (0xb51e, 1900), (0xb51f, 1901), (0xb520, 1902), (0xb521, 1903), (0xb522, 1904), (0xb523, 1905),
+    (0xb524, 10060), (0xb525, 10061), (0xb526, 1906), (0xb527, 10062), (0xb528, 10063), (0xb529, 10064), (0xb52a, 10065), (0xb52b, 1907),
+    (0xb52c, 1908), (0xb52d, 1909), (0xb52e, 1910), (0xb52f, 1911), (0xb530, 10066), (0xb531, 10067), (0xb532, 1912), (0xb533, 1913),
+    (0xb534, 10068), (0xb535, 1914), (0xb536, 1915), (0xb537, 1916), (0xb538, 10069), (0xb539, 1917), (0xb53a, 1918), (0xb53b, 1919),
+    (0xb53c, 1920), (0xb53d, 1921), (0xb53e, 1922), (0xb53f, 1923), (0xb540, 10166), (0xb541, 10167), (0xb542, 1924), (0xb543, 10168),
+    (0xb544, 10169), (0xb545, 10170), (0xb546, 1925), (0xb547, 1932), (0xb548, 1933), (0xb549, 1934), (0xb54a, 1935), (0xb54b, 10171),
+    (0xb54c, 10172), (0xb54d, 10173), (0xb54e, 1936), (0xb54f, 1937), (0xb550, 10174), (0xb551, 1938), (0xb552, 1939), (0xb553, 1940),
+    (0xb554, 10175), (0xb555, 1941), (0xb556, 1942), (0xb557, 1943), (0xb558, 1944), (0xb559, 1945), (0xb55a, 1946), (0xb55b, 1947),
+    (0xb55c, 10176), (0xb55d, 10177), (0xb55e, 1948), (0xb55f, 10178), (0xb560, 10179), (0xb561, 10180), (0xb562, 1949), (0xb563, 1950),
+    (0xb564, 1951), (0xb565, 1952), (0xb566, 1953), (0xb567, 1954), (0xb568, 1955), (0xb569, 1956), (0xb56a, 1957), (0xb56b, 1964),
+    (0xb56c, 1965), (0xb56d, 1966), (0xb56e, 1967), (0xb56f, 1968), (0xb570, 1969), (0xb571, 1970), (0xb572, 1971), (0xb573, 1972),
+    (0xb574, 1973), (0xb575, 1974), (0xb576, 1975), (0xb577, 1976), (0xb578, 1977), (0xb579, 1978), (0xb57a, 1979), (0xb57b, 1980),
+    (0xb57c, 1981), (0xb57d, 1982), (0xb57e, 1983), (0xb57f, 1984), (0xb580, 1985), (0xb581, 1986), (0xb582, 1987), (0xb583, 1988),
+    (0xb584, 1989), (0xb585, 1990), (0xb586, 1991), (0xb587, 1992), (0xb588, 1993), (0xb589, 1994), (0xb58a, 1995), (0xb58b, 1996),
+    (0xb58c, 1997), (0xb58d, 1998), (0xb58e, 1999), (0xb58f, 2000), (0xb590, 2001), (0xb591, 2002), (0xb592, 2003), (0xb593, 2004),
+    (0xb594, 2005), (0xb595, 2006), (0xb596, 2007), (0xb597, 2008), (0xb598, 2009), (0xb599, 2010), (0xb59a, 2011), (0xb59b, 2012),
+    (0xb59c, 2013), (0xb59d, 2014), (0xb59e, 2015), (0xb59f, 2016), (0xb5a0, 10181), (0xb5a1, 10182), (0xb5a2, 2017), (0xb5a3, 2018),
+    (0xb5a4, 10183), (0xb5a5, 2019), (0xb5a6, 2020), (0xb5a7, 2021), (0xb5a8, 10184), (0xb5a9, 2022), (0xb5aa, 10185), (0xb5ab, 10186),
+    (0xb5ac, 2023), (0xb5ad, 2024), (0xb5ae, 2025), (0xb5af, 2026), (0xb5b0, 10187), (0xb5b1, 10188), (0xb5b2, 2027), (0xb5b3, 10189),
+    (0xb5b4, 10190), (0xb5b5, 10191), (0xb5b6, 2028), (0xb5b7, 2029), (0xb5b8, 2030), (0xb5b9, 2031), (0xb5ba, 2032), (0xb5bb, 10192),
+    (0xb5bc, 10193), (0xb5bd, 10194), (0xb5be, 2033), (0xb5bf, 2034), (0xb5c0, 10195), (0xb5c1, 2035), (0xb5c2, 2036), (0xb5c3, 2037),
+    (0xb5c4, 10196), (0xb5c5, 2038), (0xb5c6, 2039), (0xb5c7, 2040), (0xb5c8, 2041), (0xb5c9, 2042), (0xb5ca, 2043), (0xb5cb, 2044),
+    (0xb5cc, 10197), (0xb5cd, 10198), (0xb5ce, 2045), (0xb5cf, 10199), (0xb5d0, 10200), (0xb5d1, 10201), (0xb5d2, 2046), (0xb5d3, 2047),
+    (0xb5d4, 2048), (0xb5d5, 2049), (0xb5d6, 2050), (0xb5d7, 2051), (0xb5d8, 10202), (0xb5d9, 2052), (0xb5da, 2053), (0xb5db, 2054),
+    (0xb5dc, 2055), (0xb5dd, 2056), (0xb5de, 2057), (0xb5df, 2058), (0xb5e0, 2059), (0xb5e1, 2060), (0xb5e2, 2061), (0xb5e3, 2062),
+    (0xb5e4, 2063), (0xb5e5, 2064), (0xb5e6, 2065), (0xb5e7, 2066), (0xb5e8, 2067), (0xb5e9, 2068), (0xb5ea, 2069), (0xb5eb, 2070),
+    (0xb5ec, 10203), (0xb5ed, 2071), (0xb5ee, 2072), (0xb5ef, 2073), (0xb5f0, 2074), (0xb5f1, 2075), (0xb5f2, 2076), (0xb5f3, 2077),
+    (0xb5f4, 2078), (0xb5f5, 2079), (0xb5f6, 2080), (0xb5f7, 2081), (0xb5f8, 2082), (0xb5f9, 2083), (0xb5fa, 2084), (0xb5fb, 2085),
+    (0xb5fc, 2086), (0xb5fd, 2087), (0xb5fe, 2088), (0xb5ff, 2089), (0xb600, 2090), (0xb601, 2091), (0xb602, 2092), (0xb603, 2093),
+    (0xb604, 2094), (0xb605, 2095), (0xb606, 2096), (0xb607, 2097), (0xb608, 2098), (0xb609, 2099), (0xb60a, 2100), (0xb60b, 2101),
+    (0xb60c, 2102), (0xb60d, 2103), (0xb60e, 2104), (0xb60f, 2105), (0xb610, 10204), (0xb611, 10205), (0xb612, 2106), (0xb613, 2107),
+    (0xb614, 10206), (0xb615, 2108), (0xb616, 2109), (0xb617, 2110), (0xb618, 10207), (0xb619, 2111), (0xb61a, 2112), (0xb61b, 2113),
+    (0xb61c, 2114), (0xb61d, 2115), (0xb61e, 2122), (0xb61f, 2123), (0xb620, 2124), (0xb621, 2125), (0xb622, 2126), (0xb623, 2127),
+    (0xb624, 2128), (0xb625, 10208), (0xb626, 2129), (0xb627, 2130), (0xb628, 2131), (0xb629, 2132), (0xb62a, 2133), (0xb62b, 2134),
+    (0xb62c, 10209), (0xb62d, 2135), (0xb62e, 2136), (0xb62f, 2137), (0xb630, 2138), (0xb631, 2139), (0xb632, 2140), (0xb633, 2141),
+    (0xb634, 10210), (0xb635, 2142), (0xb636, 2143), (0xb637, 2144), (0xb638, 2145), (0xb639, 2146), (0xb63a, 2147), (0xb63b, 2154),
+    (0xb63c, 2155), (0xb63d, 2156), (0xb63e, 2157), (0xb63f, 2158), (0xb640, 2159), (0xb641, 2160), (0xb642, 2161), (0xb643, 2162),
+    (0xb644, 2163), (0xb645, 2164), (0xb646, 2165), (0xb647, 2166), (0xb648, 10211), (0xb649, 2167), (0xb64a, 2168), (0xb64b, 2169),
+    (0xb64c, 2170), (0xb64d, 2171), (0xb64e, 2172), (0xb64f, 2173), (0xb650, 2174), (0xb651, 2175), (0xb652, 2176), (0xb653, 2177),
+    (0xb654, 2178), (0xb655, 2179), (0xb656, 2180), (0xb657, 2181), (0xb658, 2182), (0xb659, 2183), (0xb65a, 2184), (0xb65b, 2185),
+    (0xb65c, 2186), (0xb65d, 2187), (0xb65e, 2188), (0xb65f, 2189), (0xb660, 2190), (0xb661, 2191), (0xb662, 2192), (0xb663, 2193),
+    (0xb664, 10212), (0xb665, 2194), (0xb666, 2195), (0xb667, 2196), (0xb668, 10213), (0xb669, 2197), (0xb66a, 2198), (0xb66b, 2199),
+    (0xb66c, 2200), (0xb66d, 2201), (0xb66e, 2202), (0xb66f, 2203), (0xb670, 2204), (0xb671, 2205), (0xb672, 2206), (0xb673, 2207),
+    (0xb674, 2208), (0xb675, 2209), (0xb676, 2210), (0xb677, 2211), (0xb678, 2212), (0xb679, 2213), (0xb67a, 2214), (0xb67b, 2215),
+    (0xb67c, 2216), (0xb67d, 2217), (0xb67e, 2218), (0xb67f, 2219), (0xb680, 2220), (0xb681, 2221), (0xb682, 2222), (0xb683, 2223),
+    (0xb684, 2224), (0xb685, 2225), (0xb686, 2226), (0xb687, 2227), (0xb688, 2228), (0xb689, 2229), (0xb68a, 2230), (0xb68b, 2231),
+    (0xb68c, 2232), (0xb68d, 2233), (0xb68e, 2234), (0xb68f, 2235), (0xb690, 2236), (0xb691, 2237), (0xb692, 2238), (0xb693, 2239),
+    (0xb694, 2240), (0xb695, 2241), (0xb696, 2242), (0xb697, 2243), (0xb698, 2244), (0xb699, 2245), (0xb69a, 2246), (0xb69b, 2247),
+    (0xb69c, 10214), (0xb69d, 10215), (0xb69e, 2248), (0xb69f, 2249), (0xb6a0, 10216), (0xb6a1, 2250), (0xb6a2, 2251), (0xb6a3, 2252),
+    (0xb6a4, 10217), (0xb6a5, 2253), (0xb6a6, 2254), (0xb6a7, 2255), (0xb6a8, 2256), (0xb6a9, 2257), (0xb6aa, 2258), (0xb6ab, 10218),
+    (0xb6ac, 10219), (0xb6ad, 2259), (0xb6ae, 2260), (0xb6af, 2261), (0xb6b0, 2262), (0xb6b1, 10220), (0xb6b2, 2263), (0xb6b3, 2264),
+    (0xb6b4, 2265), (0xb6b5, 2266), (0xb6b6, 2267), (0xb6b7, 2268), (0xb6b8, 2269), (0xb6b9, 2270), (0xb6ba, 2271), (0xb6bb, 2272),
+    (0xb6bc, 2273), (0xb6bd, 2274), (0xb6be, 2275), (0xb6bf, 2276), (0xb6c0, 2277), (0xb6c1, 2278), (0xb6c2, 2279), (0xb6c3, 2280),
+    (0xb6c4, 2281), (0xb6c5, 2282), (0xb6c6, 2283), (0xb6c7, 2284), (0xb6c8, 2285), (0xb6c9, 2286), (0xb6ca, 2287), (0xb6cb, 2288),
+    (0xb6cc, 2289), (0xb6cd, 2290), (0xb6ce, 2291), (0xb6cf, 2292), (0xb6d0, 2293), (0xb6d1, 2294), (0xb6d2, 2295), (0xb6d3, 2296),
+    (0xb6d4, 10221), (0xb6d5, 2297), (0xb6d6, 2298), (0xb6d7, 2299), (0xb6d8, 2300), (0xb6d9, 2301), (0xb6da, 2302), (0xb6db, 2303),
+    (0xb6dc, 2304), (0xb6dd, 2305), (0xb6de, 2312), (0xb6df, 2313), (0xb6e0, 2314), (0xb6e1, 2315), (0xb6e2, 2316), (0xb6e3, 2317),
+    (0xb6e4, 2318), (0xb6e5, 2319), (0xb6e6, 2320), (0xb6e7, 2321), (0xb6e8, 2322), (0xb6e9, 2323), (0xb6ea, 2324), (0xb6eb, 2325),
+    (0xb6ec, 2326), (0xb6ed, 2327), (0xb6ee, 2328), (0xb6ef, 2329), (0xb6f0, 10222), (0xb6f1, 2330), (0xb6f2, 2331), (0xb6f3, 2332),
+    (0xb6f4, 10223), (0xb6f5, 2333), (0xb6f6, 2334), (0xb6f7, 2335), (0xb6f8, 10224), (0xb6f9, 2336), (0xb6fa, 2337), (0xb6fb, 2344),
+    (0xb6fc, 2345), (0xb6fd, 2346), (0xb6fe, 2347), (0xb6ff, 2348), (0xb700, 10225), (0xb701, 10226), (0xb702, 2349), (0xb703, 2350),
+    (0xb704, 2351), (0xb705, 10227), (0xb706, 2352), (0xb707, 2353), (0xb708, 2354), (0xb709, 2355), (0xb70a, 2356), (0xb70b, 2357),
+    (0xb70c, 2358), (0xb70d, 2359), (0xb70e, 2360), (0xb70f, 2361), (0xb710, 2362), (0xb711, 2363), (0xb712, 2364), (0xb713, 2365),
+    (0xb714, 2366), (0xb715, 2367), (0xb716, 2368), (0xb717, 2369), (0xb718, 2370), (0xb719, 2371), (0xb71a, 2372), (0xb71b, 2373),
+    (0xb71c, 2374), (0xb71d, 2375), (0xb71e, 2376), (0xb71f, 2377), (0xb720, 2378), (0xb721, 2379), (0xb722, 2380), (0xb723, 2381),
+    (0xb724, 2382), (0xb725, 2383), (0xb726, 2384), (0xb727, 2385), (0xb728, 10228), (0xb729, 10229), (0xb72a, 2386), (0xb72b, 2387),
+    (0xb72c, 10230), (0xb72d, 2388), (0xb72e, 2389), (0xb72f, 10231), (0xb730, 10232), (0xb731, 2390), (0xb732, 2391), (0xb733, 2392),
+    (0xb734, 2393), (0xb735, 2394), (0xb736, 2395), (0xb737, 2396), (0xb738, 10233), (0xb739, 10234), (0xb73a, 2397), (0xb73b, 10235),
+    (0xb73c, 2398), (0xb73d, 2399), (0xb73e, 2400), (0xb73f, 2401), (0xb740, 2402), (0xb741, 2403), (0xb742, 2404), (0xb743, 2405),
+    (0xb744, 10236), (0xb745, 2406), (0xb746, 2407), (0xb747, 2408), (0xb748, 10237), (0xb749, 2409), (0xb74a, 2410), (0xb74b, 2411),
+    (0xb74c, 10238), (0xb74d, 2412), (0xb74e, 2413), (0xb74f, 2414), (0xb750, 2415), (0xb751, 2416), (0xb752, 2417), (0xb753, 2418),
+    (0xb754, 10239), (0xb755, 10240), (0xb756, 2419), (0xb757, 2420), (0xb758, 2421), (0xb759, 2422), (0xb75a, 2423), (0xb75b, 2424),
+    (0xb75c, 2425), (0xb75d, 2426), (0xb75e, 2427), (0xb75f, 2428), (0xb760, 10241), (0xb761, 2429), (0xb762, 2430), (0xb763, 2431),
+    (0xb764, 10242), (0xb765, 2432), (0xb766, 2433), (0xb767, 2434), (0xb768, 10243), (0xb769, 2435), (0xb76a, 2436), (0xb76b, 2437),
+    (0xb76c, 2438), (0xb76d, 2439), (0xb76e, 2440), (0xb76f, 2441), (0xb770, 10244), (0xb771, 10245), (0xb772, 2442), (0xb773, 10246),
+    (0xb774, 2443), (0xb775, 10247), (0xb776, 2444), (0xb777, 2445), (0xb778, 2446), (0xb779, 2447), (0xb77a, 2448), (0xb77b, 2449),
+    (0xb77c, 10248), (0xb77d, 10249), (0xb77e, 2450), (0xb77f, 2451), (0xb780, 10250), (0xb781, 2452), (0xb782, 2453), (0xb783, 2454),
+    (0xb784, 10251), (0xb785, 2455), (0xb786, 2456), (0xb787, 2457), (0xb788, 2458), (0xb789, 2459), (0xb78a, 2460), (0xb78b, 2461),
+    (0xb78c, 10252), (0xb78d, 10253), (0xb78e, 2462), (0xb78f, 10254), (0xb790, 10255), (0xb791, 10256), (0xb792, 10257), (0xb793, 2463),
+    (0xb794, 2464), (0xb795, 2465), (0xb796, 10258), (0xb797, 10259), (0xb798, 10356), (0xb799, 10357), (0xb79a, 2466), (0xb79b, 2467),
+    (0xb79c, 10358), (0xb79d, 2468), (0xb79e, 2469), (0xb79f, 2470), (0xb7a0, 10359), (0xb7a1, 2471), (0xb7a2, 2472), (0xb7a3, 2473),
+    (0xb7a4, 2474), (0xb7a5, 2475), (0xb7a6, 2476), (0xb7a7, 2477), (0xb7a8, 10360), (0xb7a9, 10361), (0xb7aa, 2478), (0xb7ab, 10362),
+    (0xb7ac, 10363), (0xb7ad, 10364), (0xb7ae, 2479), (0xb7af, 2480), (0xb7b0, 2481), (0xb7b1, 2482), (0xb7b2, 2483), (0xb7b3, 2484),
+    (0xb7b4, 10365), (0xb7b5, 10366), (0xb7b6, 2485), (0xb7b7, 2486), (0xb7b8, 10367), (0xb7b9, 2487), (0xb7ba, 2488), (0xb7bb, 2489),
+    (0xb7bc, 2490), (0xb7bd, 2491), (0xb7be, 2492), (0xb7bf, 2493), (0xb7c0, 2494), (0xb7c1, 2495), (0xb7c2, 2502), (0xb7c3, 2503),
+    (0xb7c4, 2504), (0xb7c5, 2505), (0xb7c6, 2506), (0xb7c7, 10368), (0xb7c8, 2507), (0xb7c9, 10369), (0xb7ca, 2508), (0xb7cb, 2509),
+    (0xb7cc, 2510), (0xb7cd, 2511), (0xb7ce, 2512), (0xb7cf, 2513), (0xb7d0, 2514), (0xb7d1, 2515), (0xb7d2, 2516), (0xb7d3, 2517),
+    (0xb7d4, 2518), (0xb7d5, 2519), (0xb7d6, 2520), (0xb7d7, 2521), (0xb7d8, 2522), (0xb7d9, 2523), (0xb7da, 2524), (0xb7db, 2525),
+    (0xb7dc, 2526), (0xb7dd, 2527), (0xb7de, 2534), (0xb7df, 2535), (0xb7e0, 2536), (0xb7e1, 2537), (0xb7e2, 2538), (0xb7e3, 2539),
+    (0xb7e4, 2540), (0xb7e5, 2541), (0xb7e6, 2542), (0xb7e7, 2543), (0xb7e8, 2544), (0xb7e9, 2545), (0xb7ea, 2546), (0xb7eb, 2547),
+    (0xb7ec, 10370), (0xb7ed, 10371), (0xb7ee, 2548), (0xb7ef, 2549), (0xb7f0, 10372), (0xb7f1, 2550), (0xb7f2, 2551), (0xb7f3, 2552),
+    (0xb7f4, 10373), (0xb7f5, 2553), (0xb7f6, 2554), (0xb7f7, 2555), (0xb7f8, 2556), (0xb7f9, 2557), (0xb7fa, 2558), (0xb7fb, 2559),
+    (0xb7fc, 10374), (0xb7fd, 10375), (0xb7fe, 2560), (0xb7ff, 10376), (0xb800, 10377), (0xb801, 10378), (0xb802, 2561), (0xb803, 2562),
+    (0xb804, 2563), (0xb805, 2564), (0xb806, 2565), (0xb807, 10379), (0xb808, 10380), (0xb809, 10381), (0xb80a, 2566), (0xb80b, 2567),
+    (0xb80c, 10382), (0xb80d, 2568), (0xb80e, 2569), (0xb80f, 2570), (0xb810, 10383), (0xb811, 2571), (0xb812, 2572), (0xb813, 2573),
+    (0xb814, 2574), (0xb815, 2575), (0xb816, 2576), (0xb817, 2577), (0xb818, 10384), (0xb819, 10385), (0xb81a, 2578), (0xb81b, 10386),
+    (0xb81c, 2579), (0xb81d, 10387), (0xb81e, 2580), (0xb81f, 2581), (0xb820, 2582), (0xb821, 2583), (0xb822, 2584), (0xb823, 2585),
+    (0xb824, 10388), (0xb825, 10389), (0xb826, 2586), (0xb827, 2587), (0xb828, 10390), (0xb829, 2588), (0xb82a, 2589), (0xb82b, 2590),
+    (0xb82c, 10391), (0xb82d, 2591), (0xb82e, 2592), (0xb82f, 2593), (0xb830, 2594), (0xb831, 2595), (0xb832, 2596), (0xb833, 2597),
+    (0xb834, 10392), (0xb835, 10393), (0xb836, 2598), (0xb837, 10394), (0xb838, 10395), (0xb839, 10396), (0xb83a, 2599), (0xb83b, 2600),
+    (0xb83c, 2601), (0xb83d, 2602), (0xb83e, 2603), (0xb83f, 2604), (0xb840, 10397), (0xb841, 2605), (0xb842, 2606), (0xb843, 2607),
+    (0xb844, 10398), (0xb845, 2608), (0xb846, 2609), (0xb847, 2610), (0xb848, 2611), (0xb849, 2612), (0xb84a, 2613), (0xb84b, 2614),
+    (0xb84c, 2615), (0xb84d, 2616), (0xb84e, 2617), (0xb84f, 2618), (0xb850, 2619), (0xb851, 10399), (0xb852, 2620), (0xb853, 10400),
+    (0xb854, 2621), (0xb855, 2622), (0xb856, 2623), (0xb857, 2624), (0xb858, 2625), (0xb859, 2626), (0xb85a, 2627), (0xb85b, 2628),
+    (0xb85c, 10401), (0xb85d, 10402), (0xb85e, 2629), (0xb85f, 2630), (0xb860, 10403), (0xb861, 2631), (0xb862, 2632), (0xb863, 2633),
+    (0xb864, 10404), (0xb865, 2634), (0xb866, 2635), (0xb867, 2636), (0xb868, 2637), (0xb869, 2638), (0xb86a, 2639), (0xb86b, 2640),
+    (0xb86c, 10405), (0xb86d, 10406), (0xb86e, 2641), (0xb86f, 10407), (0xb870, 2642), (0xb871, 10408), (0xb872, 2643), (0xb873, 2644),
+    (0xb874, 2645), (0xb875, 2646), (0xb876, 2647), (0xb877, 2648), (0xb878, 10409), (0xb879, 2649), (0xb87a, 2650), (0xb87b, 2651),
+    (0xb87c, 10410), (0xb87d, 2652), (0xb87e, 2653), (0xb87f, 2654), (0xb880, 2655), (0xb881, 2656), (0xb882, 2657), (0xb883, 2658),
+    (0xb884, 2659), (0xb885, 2660), (0xb886, 2661), (0xb887, 2662), (0xb888, 2663), (0xb889, 2664), (0xb88a, 2665), (0xb88b, 2666),
+    (0xb88c, 2667), (0xb88d, 10411), (0xb88e, 2668), (0xb88f, 2669), (0xb890, 2670), (0xb891, 2671), (0xb892, 2672), (0xb893, 2673),
+    (0xb894, 2674), (0xb895, 2675), (0xb896, 2676), (0xb897, 2677), (0xb898, 2678), (0xb899, 2679), (0xb89a, 2680), (0xb89b, 2681),
+    (0xb89c, 2682), (0xb89d, 2683), (0xb89e, 2684), (0xb89f, 2685), (0xb8a0, 2692), (0xb8a1, 2693), (0xb8a2, 2694), (0xb8a3, 2695),
+    (0xb8a4, 2696), (0xb8a5, 2697), (0xb8a6, 2698), (0xb8a7, 2699), (0xb8a8, 10412), (0xb8a9, 2700), (0xb8aa, 2701), (0xb8ab, 2702),
+    (0xb8ac, 2703), (0xb8ad, 2704), (0xb8ae, 2705), (0xb8af, 2706), (0xb8b0, 10413), (0xb8b1, 2707), (0xb8b2, 2708), (0xb8b3, 2709),
+    (0xb8b4, 10414), (0xb8b5, 2710), (0xb8b6, 2711), (0xb8b7, 2712), (0xb8b8, 10415), (0xb8b9, 2713), (0xb8ba, 2714), (0xb8bb, 2715),
+    (0xb8bc, 2716), (0xb8bd, 2717), (0xb8be, 2724), (0xb8bf, 2725), (0xb8c0, 10416), (0xb8c1, 10417), (0xb8c2, 2726), (0xb8c3, 10418),
+    (0xb8c4, 2727), (0xb8c5, 10419), (0xb8c6, 2728), (0xb8c7, 2729), (0xb8c8, 2730), (0xb8c9, 2731), (0xb8ca, 2732), (0xb8cb, 2733),
+    (0xb8cc, 10420), (0xb8cd, 2734), (0xb8ce, 2735), (0xb8cf, 2736), (0xb8d0, 10421), (0xb8d1, 2737), (0xb8d2, 2738), (0xb8d3, 2739),
+    (0xb8d4, 10422), (0xb8d5, 2740), (0xb8d6, 2741), (0xb8d7, 2742), (0xb8d8, 2743), (0xb8d9, 2744), (0xb8da, 2745), (0xb8db, 2746),
+    (0xb8dc, 2747), (0xb8dd, 10423), (0xb8de, 2748), (0xb8df, 10424), (0xb8e0, 2749), (0xb8e1, 10425), (0xb8e2, 2750), (0xb8e3, 2751),
+    (0xb8e4, 2752), (0xb8e5, 2753), (0xb8e6, 2754), (0xb8e7, 2755), (0xb8e8, 10426), (0xb8e9, 10427), (0xb8ea, 2756), (0xb8eb, 2757),
+    (0xb8ec, 10428), (0xb8ed, 2758), (0xb8ee, 2759), (0xb8ef, 2760), (0xb8f0, 10429), (0xb8f1, 2761), (0xb8f2, 2762), (0xb8f3, 2763),
+    (0xb8f4, 2764), (0xb8f5, 2765), (0xb8f6, 2766), (0xb8f7, 2767), (0xb8f8, 10430), (0xb8f9, 10431), (0xb8fa, 2768), (0xb8fb, 10432),
+    (0xb8fc, 2769), (0xb8fd, 10433), (0xb8fe, 2770), (0xb8ff, 2771), (0xb900, 2772), (0xb901, 2773), (0xb902, 2774), (0xb903, 2775),
+    (0xb904, 10434), (0xb905, 2776), (0xb906, 2777), (0xb907, 2778), (0xb908, 2779), (0xb909, 2780), (0xb90a, 2781), (0xb90b, 2782),
+    (0xb90c, 2783), (0xb90d, 2784), (0xb90e, 2785), (0xb90f, 2786), (0xb910, 2787), (0xb911, 2788), (0xb912, 2789), (0xb913, 2790),
+    (0xb914, 2791), (0xb915, 2792), (0xb916, 2793), (0xb917, 2794), (0xb918, 10435), (0xb919, 2795), (0xb91a, 2796), (0xb91b, 2797),
+    (0xb91c, 2798), (0xb91d, 2799), (0xb91e, 2800), (0xb91f, 2801), (0xb920, 10436), (0xb921, 2802), (0xb922, 2803), (0xb923, 2804),
+    (0xb924, 2805), (0xb925, 2806), (0xb926, 2807), (0xb927, 2808), (0xb928, 2809), (0xb929, 2810), (0xb92a, 2811), (0xb92b, 2812),
+    (0xb92c, 2813), (0xb92d, 2814), (0xb92e, 2815), (0xb92f, 2816), (0xb930, 2817), (0xb931, 2818), (0xb932, 2819), (0xb933, 2820),
+    (0xb934, 2821), (0xb935, 2822), (0xb936, 2823), (0xb937, 2824), (0xb938, 2825), (0xb939, 2826), (0xb93a, 2827), (0xb93b, 2828),
+    (0xb93c, 10437), (0xb93d, 10438), (0xb93e, 2829), (0xb93f, 2830), (0xb940, 10439), (0xb941, 2831), (0xb942, 2832), (0xb943, 2833),
+    (0xb944, 10440), (0xb945, 2834), (0xb946, 2835), (0xb947, 2836), (0xb948, 2837), (0xb949, 2838), (0xb94a, 2839), (0xb94b, 2840),
+    (0xb94c, 10441), (0xb94d, 2841), (0xb94e, 2842), (0xb94f, 10442), (0xb950, 2843), (0xb951, 10443), (0xb952, 2844), (0xb953, 2845),
+    (0xb954, 2846), (0xb955, 2847), (0xb956, 2848), (0xb957, 2849), (0xb958, 10444), (0xb959, 10445), (0xb95a, 2850), (0xb95b, 2851),
+    (0xb95c, 10446), (0xb95d, 2852), (0xb95e, 2853), (0xb95f, 2854), (0xb960, 10447), (0xb961, 2855), (0xb962, 2856), (0xb963, 2857),
+    (0xb964, 2858), (0xb965, 2859), (0xb966, 2860), (0xb967, 2861), (0xb968, 10448), (0xb969, 10449), (0xb96a, 2862), (0xb96b, 10546),
+    (0xb96c, 2863), (0xb96d, 10547), (0xb96e, 2864), (0xb96f, 2865), (0xb970, 2866), (0xb971, 2867), (0xb972, 2868), (0xb973, 2869),
+    (0xb974, 10548), (0xb975, 10549), (0xb976, 2870), (0xb977, 2871), (0xb978, 10550), (0xb979, 2872), (0xb97a, 2873), (0xb97b, 2874),
+    (0xb97c, 10551), (0xb97d, 2875), (0xb97e, 2882), (0xb97f, 2883), (0xb980, 2884), (0xb981, 2885), (0xb982, 2886), (0xb983, 2887),
+    (0xb984, 10552), (0xb985, 10553), (0xb986, 2888), (0xb987, 10554), (0xb988, 2889), (0xb989, 10555), (0xb98a, 10556), (0xb98b, 2890),
+    (0xb98c, 2891), (0xb98d, 10557), (0xb98e, 10558), (0xb98f, 2892), (0xb990, 2893), (0xb991, 2894), (0xb992, 2895), (0xb993, 2896),
+    (0xb994, 2897), (0xb995, 2898), (0xb996, 2899), (0xb997, 2900), (0xb998, 2901), (0xb999, 2902), (0xb99a, 2903), (0xb99b, 2904),
+    (0xb99c, 2905), (0xb99d, 2906), (0xb99e, 2907), (0xb99f, 2914), (0xb9a0, 2915), (0xb9a1, 2916), (0xb9a2, 2917), (0xb9a3, 2918),
+    (0xb9a4, 2919), (0xb9a5, 2920), (0xb9a6, 2921), (0xb9a7, 2922), (0xb9a8, 2923), (0xb9a9, 2924), (0xb9aa, 2925), (0xb9ab, 2926),
+    (0xb9ac, 10559), (0xb9ad, 10560), (0xb9ae, 2927), (0xb9af, 2928), (0xb9b0, 10561), (0xb9b1, 2929), (0xb9b2, 2930), (0xb9b3, 2931),
+    (0xb9b4, 10562), (0xb9b5, 2932), (0xb9b6, 2933), (0xb9b7, 2934), (0xb9b8, 2935), (0xb9b9, 2936), (0xb9ba, 2937), (0xb9bb, 2938),
+    (0xb9bc, 10563), (0xb9bd, 10564), (0xb9be, 2939), (0xb9bf, 10565), (0xb9c0, 2940), (0xb9c1, 10566), (0xb9c2, 2941), (0xb9c3, 2942),
+    (0xb9c4, 2943), (0xb9c5, 2944), (0xb9c6, 2945), (0xb9c7, 2946), (0xb9c8, 10567), (0xb9c9, 10568), (0xb9ca, 2947), (0xb9cb, 2948),
+    (0xb9cc, 10569), (0xb9cd, 2949), (0xb9ce, 10570), (0xb9cf, 10571), (0xb9d0, 10572), (0xb9d1, 10573), (0xb9d2, 10574), (0xb9d3, 2950),
+    (0xb9d4, 2951), (0xb9d5, 2952), (0xb9d6, 2953), (0xb9d7, 2954), (0xb9d8, 10575), (0xb9d9, 10576), (0xb9da, 2955), (0xb9db, 10577),
+    (0xb9dc, 2956), (0xb9dd, 10578), (0xb9de, 10579), (0xb9df, 2957), (0xb9e0, 2958), (0xb9e1, 10580), (0xb9e2, 2959), (0xb9e3, 10581),
+    (0xb9e4, 10582), (0xb9e5, 10583), (0xb9e6, 2960), (0xb9e7, 2961), (0xb9e8, 10584), (0xb9e9, 2962), (0xb9ea, 2963), (0xb9eb, 2964),
+    (0xb9ec, 10585), (0xb9ed, 2965), (0xb9ee, 2966), (0xb9ef, 2967), (0xb9f0, 2968), (0xb9f1, 2969), (0xb9f2, 2970), (0xb9f3, 2971),
+    (0xb9f4, 10586), (0xb9f5, 10587), (0xb9f6, 2972), (0xb9f7, 10588), (0xb9f8, 10589), (0xb9f9, 10590), (0xb9fa, 10591), (0xb9fb, 2973),
+    (0xb9fc, 2974), (0xb9fd, 2975), (0xb9fe, 2976), (0xb9ff, 2977), (0xba00, 10592), (0xba01, 10593), (0xba02, 2978), (0xba03, 2979),
+    (0xba04, 2980), (0xba05, 2981), (0xba06, 2982), (0xba07, 2983), (0xba08, 10594), (0xba09, 2984), (0xba0a, 2985), (0xba0b, 2986),
+    (0xba0c, 2987), (0xba0d, 2988), (0xba0e, 2989), (0xba0f, 2990), (0xba10, 2991), (0xba11, 2992), (0xba12, 2993), (0xba13, 2994),
+    (0xba14, 2995), (0xba15, 10595), (0xba16, 2996), (0xba17, 2997), (0xba18, 2998), (0xba19, 2999), (0xba1a, 3000), (0xba1b, 3001),
+    (0xba1c, 3002), (0xba1d, 3003), (0xba1e, 3004), (0xba1f, 3005), (0xba20, 3006), (0xba21, 3007), (0xba22, 3008), (0xba23, 3009),
+    (0xba24, 3010), (0xba25, 3011), (0xba26, 3012), (0xba27, 3013), (0xba28, 3014), (0xba29, 3015), (0xba2a, 3016), (0xba2b, 3017),
+    (0xba2c, 3018), (0xba2d, 3019), (0xba2e, 3020), (0xba2f, 3021), (0xba30, 3022), (0xba31, 3023), (0xba32, 3024), (0xba33, 3025),
+    (0xba34, 3026), (0xba35, 3027), (0xba36, 3028), (0xba37, 3029), (0xba38, 10596), (0xba39, 10597), (0xba3a, 3030), (0xba3b, 3031),
+    (0xba3c, 10598), (0xba3d, 3032), (0xba3e, 3033), (0xba3f, 3034), (0xba40, 10599), (0xba41, 3035), (0xba42, 10600), (0xba43, 3036),
+    (0xba44, 3037), (0xba45, 3038), (0xba46, 3039), (0xba47, 3040), (0xba48, 10601), (0xba49, 10602), (0xba4a, 3041), (0xba4b, 10603),
+    (0xba4c, 3042), (0xba4d, 10604), (0xba4e, 10605), (0xba4f, 3043), (0xba50, 3044), (0xba51, 3045), (0xba52, 3046), (0xba53, 10606),
+    (0xba54, 10607), (0xba55, 10608), (0xba56, 3047), (0xba57, 3048), (0xba58, 10609), (0xba59, 3049), (0xba5a, 3050), (0xba5b, 3051),
+    (0xba5c, 10610), (0xba5d, 3052), (0xba5e, 3053), (0xba5f, 3054), (0xba60, 3055), (0xba61, 3056), (0xba62, 3057), (0xba63, 3058),
+    (0xba64, 10611), (0xba65, 10612), (0xba66, 3059), (0xba67, 10613), (0xba68, 10614), (0xba69, 10615), (0xba6a, 3060), (0xba6b, 3061),
+    (0xba6c, 3062), (0xba6d, 3063), (0xba6e, 3064), (0xba6f, 3065), (0xba70, 10616), (0xba71, 10617), (0xba72, 3072), (0xba73, 3073),
+    (0xba74, 10618), (0xba75, 3074), (0xba76, 3075), (0xba77, 3076), (0xba78, 10619), (0xba79, 3077), (0xba7a, 3078), (0xba7b, 3079),
+    (0xba7c, 3080), (0xba7d, 3081), (0xba7e, 3082), (0xba7f, 3083), (0xba80, 3084), (0xba81, 3085), (0xba82, 3086), (0xba83, 10620),
+    (0xba84, 10621), (0xba85, 10622), (0xba86, 3087), (0xba87, 10623), (0xba88, 3088), (0xba89, 3089), (0xba8a, 3090), (0xba8b, 3091),
+    (0xba8c, 10624), (0xba8d, 3092), (0xba8e, 3093), (0xba8f, 3094), (0xba90, 3095), (0xba91, 3096), (0xba92, 3097), (0xba93, 3104),
+    (0xba94, 3105), (0xba95, 3106), (0xba96, 3107), (0xba97, 3108), (0xba98, 3109), (0xba99, 3110), (0xba9a, 3111), (0xba9b, 3112),
+    (0xba9c, 3113), (0xba9d, 3114), (0xba9e, 3115), (0xba9f, 3116), (0xbaa0, 3117), (0xbaa1, 3118), (0xbaa2, 3119), (0xbaa3, 3120),
+    (0xbaa4, 3121), (0xbaa5, 3122), (0xbaa6, 3123), (0xbaa7, 3124), (0xbaa8, 10625), (0xbaa9, 10626), (0xbaaa, 3125), (0xbaab, 10627),
+    (0xbaac, 10628), (0xbaad, 3126), (0xbaae, 3127), (0xbaaf, 3128), (0xbab0, 10629), (0xbab1, 3129), (0xbab2, 10630), (0xbab3, 3130),
+    (0xbab4, 3131), (0xbab5, 3132), (0xbab6, 3133), (0xbab7, 3134), (0xbab8, 10631), (0xbab9, 10632), (0xbaba, 3135), (0xbabb, 10633),
+    (0xbabc, 3136), (0xbabd, 10634), (0xbabe, 3137), (0xbabf, 3138), (0xbac0, 3139), (0xbac1, 3140), (0xbac2, 3141), (0xbac3, 3142),
+    (0xbac4, 10635), (0xbac5, 3143), (0xbac6, 3144), (0xbac7, 3145), (0xbac8, 10636), (0xbac9, 3146), (0xbaca, 3147), (0xbacb, 3148),
+    (0xbacc, 3149), (0xbacd, 3150), (0xbace, 3151), (0xbacf, 3152), (0xbad0, 3153), (0xbad1, 3154), (0xbad2, 3155), (0xbad3, 3156),
+    (0xbad4, 3157), (0xbad5, 3158), (0xbad6, 3159), (0xbad7, 3160), (0xbad8, 10637), (0xbad9, 10638), (0xbada, 3161), (0xbadb, 3162),
+    (0xbadc, 3163), (0xbadd, 3164), (0xbade, 3165), (0xbadf, 3166), (0xbae0, 3167), (0xbae1, 3168), (0xbae2, 3169), (0xbae3, 3170),
+    (0xbae4, 3171), (0xbae5, 3172), (0xbae6, 3173), (0xbae7, 3174), (0xbae8, 3175), (0xbae9, 3176), (0xbaea, 3177), (0xbaeb, 3178),
+    (0xbaec, 3179), (0xbaed, 3180), (0xbaee, 3181), (0xbaef, 3182), (0xbaf0, 3183), (0xbaf1, 3184), (0xbaf2, 3185), (0xbaf3, 3186),
+    (0xbaf4, 3187), (0xbaf5, 3188), (0xbaf6, 3189), (0xbaf7, 3190), (0xbaf8, 3191), (0xbaf9, 3192), (0xbafa, 3193), (0xbafb, 3194),
+    (0xbafc, 10639), (0xbafd, 3195), (0xbafe, 3196), (0xbaff, 3197), (0xbb00, 10736), (0xbb01, 3198), (0xbb02, 3199), (0xbb03, 3200),
+    (0xbb04, 10737), (0xbb05, 3201), (0xbb06, 3202), (0xbb07, 3203), (0xbb08, 3204), (0xbb09, 3205), (0xbb0a, 3206), (0xbb0b, 3207),
+    (0xbb0c, 3208), (0xbb0d, 10738), (0xbb0e, 3209), (0xbb0f, 10739), (0xbb10, 3210), (0xbb11, 10740), (0xbb12, 3211), (0xbb13, 3212),
+    (0xbb14, 3213), (0xbb15, 3214), (0xbb16, 3215), (0xbb17, 3216), (0xbb18, 10741), (0xbb19, 3217), (0xbb1a, 3218), (0xbb1b, 3219),
+    (0xbb1c, 10742), (0xbb1d, 3220), (0xbb1e, 3221), (0xbb1f, 3222), (0xbb20, 10743), (0xbb21, 3223), (0xbb22, 3224), (0xbb23, 3225),
+    (0xbb24, 3226), (0xbb25, 3227), (0xbb26, 3228), (0xbb27, 3229), (0xbb28, 3230), (0xbb29, 10744), (0xbb2a, 3231), (0xbb2b, 10745),
+    (0xbb2c, 3232), (0xbb2d, 3233), (0xbb2e, 3234), (0xbb2f, 3235), (0xbb30, 3236), (0xbb31, 3237), (0xbb32, 3238), (0xbb33, 3239),
+    (0xbb34, 10746), (0xbb35, 10747), (0xbb36, 10748), (0xbb37, 3240), (0xbb38, 10749), (0xbb39, 3241), (0xbb3a, 3242), (0xbb3b, 10750),
+    (0xbb3c, 10751), (0xbb3d, 10752), (0xbb3e, 10753), (0xbb3f, 3243), (0xbb40, 3244), (0xbb41, 3245), (0xbb42, 3246), (0xbb43, 3247),
+    (0xbb44, 10754), (0xbb45, 10755), (0xbb46, 3248), (0xbb47, 10756), (0xbb48, 3249), (0xbb49, 10757), (0xbb4a, 3250), (0xbb4b, 3251),
+    (0xbb4c, 3252), (0xbb4d, 10758), (0xbb4e, 3253), (0xbb4f, 10759), (0xbb50, 10760), (0xbb51, 3254), (0xbb52, 3255), (0xbb53, 3262),
+    (0xbb54, 10761), (0xbb55, 3263), (0xbb56, 3264), (0xbb57, 3265), (0xbb58, 10762), (0xbb59, 3266), (0xbb5a, 3267), (0xbb5b, 3268),
+    (0xbb5c, 3269), (0xbb5d, 3270), (0xbb5e, 3271), (0xbb5f, 3272), (0xbb60, 3273), (0xbb61, 10763), (0xbb62, 3274), (0xbb63, 10764),
+    (0xbb64, 3275), (0xbb65, 3276), (0xbb66, 3277), (0xbb67, 3278), (0xbb68, 3279), (0xbb69, 3280), (0xbb6a, 3281), (0xbb6b, 3282),
+    (0xbb6c, 10765), (0xbb6d, 3283), (0xbb6e, 3284), (0xbb6f, 3285), (0xbb70, 3286), (0xbb71, 3287), (0xbb72, 3294), (0xbb73, 3295),
+    (0xbb74, 3296), (0xbb75, 3297), (0xbb76, 3298), (0xbb77, 3299), (0xbb78, 3300), (0xbb79, 3301), (0xbb7a, 3302), (0xbb7b, 3303),
+    (0xbb7c, 3304), (0xbb7d, 3305), (0xbb7e, 3306), (0xbb7f, 3307), (0xbb80, 3308), (0xbb81, 3309), (0xbb82, 3310), (0xbb83, 3311),
+    (0xbb84, 3312), (0xbb85, 3313), (0xbb86, 3314), (0xbb87, 3315), (0xbb88, 10766), (0xbb89, 3316), (0xbb8a, 3317), (0xbb8b, 3318),
+    (0xbb8c, 10767), (0xbb8d, 3319), (0xbb8e, 3320), (0xbb8f, 3321), (0xbb90, 10768), (0xbb91, 3322), (0xbb92, 3323), (0xbb93, 3324),
+    (0xbb94, 3325), (0xbb95, 3326), (0xbb96, 3327), (0xbb97, 3328), (0xbb98, 3329), (0xbb99, 3330), (0xbb9a, 3331), (0xbb9b, 3332),
+    (0xbb9c, 3333), (0xbb9d, 3334), (0xbb9e, 3335), (0xbb9f, 3336), (0xbba0, 3337), (0xbba1, 3338), (0xbba2, 3339), (0xbba3, 3340),
+    (0xbba4, 10769), (0xbba5, 3341), (0xbba6, 3342), (0xbba7, 3343), (0xbba8, 10770), (0xbba9, 3344), (0xbbaa, 3345), (0xbbab, 3346),
+    (0xbbac, 10771), (0xbbad, 3347), (0xbbae, 3348), (0xbbaf, 3349), (0xbbb0, 3350), (0xbbb1, 3351), (0xbbb2, 3352), (0xbbb3, 3353),
+    (0xbbb4, 10772), (0xbbb5, 3354), (0xbbb6, 3355), (0xbbb7, 10773), (0xbbb8, 3356), (0xbbb9, 3357), (0xbbba, 3358), (0xbbbb, 3359),
+    (0xbbbc, 3360), (0xbbbd, 3361), (0xbbbe, 3362), (0xbbbf, 3363), (0xbbc0, 10774), (0xbbc1, 3364), (0xbbc2, 3365), (0xbbc3, 3366),
+    (0xbbc4, 10775), (0xbbc5, 3367), (0xbbc6, 3368), (0xbbc7, 3369), (0xbbc8, 10776), (0xbbc9, 3370), (0xbbca, 3371), (0xbbcb, 3372),
+    (0xbbcc, 3373), (0xbbcd, 3374), (0xbbce, 3375), (0xbbcf, 3376), (0xbbd0, 10777), (0xbbd1, 3377), (0xbbd2, 3378), (0xbbd3, 10778),
+    (0xbbd4, 3379), (0xbbd5, 3380), (0xbbd6, 3381), (0xbbd7, 3382), (0xbbd8, 3383), (0xbbd9, 3384), (0xbbda, 3385), (0xbbdb, 3386),
+    (0xbbdc, 3387), (0xbbdd, 3388), (0xbbde, 3389), (0xbbdf, 3390), (0xbbe0, 3391), (0xbbe1, 3392), (0xbbe2, 3393), (0xbbe3, 3394),
+    (0xbbe4, 3395), (0xbbe5, 3396), (0xbbe6, 3397), (0xbbe7, 3398), (0xbbe8, 3399), (0xbbe9, 3400), (0xbbea, 3401), (0xbbeb, 3402),
+    (0xbbec, 3403), (0xbbed, 3404), (0xbbee, 3405), (0xbbef, 3406), (0xbbf0, 3407), (0xbbf1, 3408), (0xbbf2, 3409), (0xbbf3, 3410),
+    (0xbbf4, 3411), (0xbbf5, 3412), (0xbbf6, 3413), (0xbbf7, 3414), (0xbbf8, 10779), (0xbbf9, 10780), (0xbbfa, 3415), (0xbbfb, 3416),
+    (0xbbfc, 10781), (0xbbfd, 3417), (0xbbfe, 3418), (0xbbff, 10782), (0xbc00, 10783), (0xbc01, 3419), (0xbc02, 10784), (0xbc03, 3420),
+    (0xbc04, 3421), (0xbc05, 3422), (0xbc06, 3423), (0xbc07, 3424), (0xbc08, 10785), (0xbc09, 10786), (0xbc0a, 3425), (0xbc0b, 10787),
+    (0xbc0c, 10788), (0xbc0d, 10789), (0xbc0e, 3426), (0xbc0f, 10790), (0xbc10, 3427), (0xbc11, 10791), (0xbc12, 3428), (0xbc13, 3429),
+    (0xbc14, 10792), (0xbc15, 10793), (0xbc16, 10794), (0xbc17, 10795), (0xbc18, 10796), (0xbc19, 3430), (0xbc1a, 3431), (0xbc1b, 10797),
+    (0xbc1c, 10798), (0xbc1d, 10799), (0xbc1e, 10800), (0xbc1f, 10801), (0xbc20, 3432), (0xbc21, 3433), (0xbc22, 3434), (0xbc23, 3435),
+    (0xbc24, 10802), (0xbc25, 10803), (0xbc26, 3436), (0xbc27, 10804), (0xbc28, 3437), (0xbc29, 10805), (0xbc2a, 3438), (0xbc2b, 3439),
+    (0xbc2c, 3440), (0xbc2d, 10806), (0xbc2e, 3441), (0xbc2f, 3442), (0xbc30, 10807), (0xbc31, 10808), (0xbc32, 3443), (0xbc33, 3444),
+    (0xbc34, 10809), (0xbc35, 3445), (0xbc36, 3452), (0xbc37, 3453), (0xbc38, 10810), (0xbc39, 3454), (0xbc3a, 3455), (0xbc3b, 3456),
+    (0xbc3c, 3457), (0xbc3d, 3458), (0xbc3e, 3459), (0xbc3f, 3460), (0xbc40, 10811), (0xbc41, 10812), (0xbc42, 3461), (0xbc43, 10813),
+    (0xbc44, 10814), (0xbc45, 10815), (0xbc46, 3462), (0xbc47, 3463), (0xbc48, 3464), (0xbc49, 10816), (0xbc4a, 3465), (0xbc4b, 3466),
+    (0xbc4c, 10817), (0xbc4d, 10818), (0xbc4e, 3467), (0xbc4f, 3468), (0xbc50, 10819), (0xbc51, 3469), (0xbc52, 3470), (0xbc53, 3471),
+    (0xbc54, 3472), (0xbc55, 3473), (0xbc56, 3474), (0xbc57, 3475), (0xbc58, 3476), (0xbc59, 3477), (0xbc5a, 3484), (0xbc5b, 3485),
+    (0xbc5c, 3486), (0xbc5d, 10820), (0xbc5e, 3487), (0xbc5f, 3488), (0xbc60, 3489), (0xbc61, 3490), (0xbc62, 3491), (0xbc63, 3492),
+    (0xbc64, 3493), (0xbc65, 3494), (0xbc66, 3495), (0xbc67, 3496), (0xbc68, 3497), (0xbc69, 3498), (0xbc6a, 3499), (0xbc6b, 3500),
+    (0xbc6c, 3501), (0xbc6d, 3502), (0xbc6e, 3503), (0xbc6f, 3504), (0xbc70, 3505), (0xbc71, 3506), (0xbc72, 3507), (0xbc73, 3508),
+    (0xbc74, 3509), (0xbc75, 3510), (0xbc76, 3511), (0xbc77, 3512), (0xbc78, 3513), (0xbc79, 3514), (0xbc7a, 3515), (0xbc7b, 3516),
+    (0xbc7c, 3517), (0xbc7d, 3518), (0xbc7e, 3519), (0xbc7f, 3520), (0xbc80, 3521), (0xbc81, 3522), (0xbc82, 3523), (0xbc83, 3524),
+    (0xbc84, 10821), (0xbc85, 10822), (0xbc86, 3525), (0xbc87, 3526), (0xbc88, 10823), (0xbc89, 3527), (0xbc8a, 3528), (0xbc8b, 10824),
+    (0xbc8c, 10825), (0xbc8d, 3529), (0xbc8e, 10826), (0xbc8f, 3530), (0xbc90, 3531), (0xbc91, 3532), (0xbc92, 3533), (0xbc93, 3534),
+    (0xbc94, 10827), (0xbc95, 10828), (0xbc96, 3535), (0xbc97, 10829), (0xbc98, 3536), (0xbc99, 10926), (0xbc9a, 10927), (0xbc9b, 3537),
+    (0xbc9c, 3538), (0xbc9d, 3539), (0xbc9e, 3540), (0xbc9f, 3541), (0xbca0, 10928), (0xbca1, 10929), (0xbca2, 3542), (0xbca3, 3543),
+    (0xbca4, 10930), (0xbca5, 3544), (0xbca6, 3545), (0xbca7, 10931), (0xbca8, 10932), (0xbca9, 3546), (0xbcaa, 3547), (0xbcab, 3548),
+    (0xbcac, 3549), (0xbcad, 3550), (0xbcae, 3551), (0xbcaf, 3552), (0xbcb0, 10933), (0xbcb1, 10934), (0xbcb2, 3553), (0xbcb3, 10935),
+    (0xbcb4, 10936), (0xbcb5, 10937), (0xbcb6, 3554), (0xbcb7, 3555), (0xbcb8, 3556), (0xbcb9, 3557), (0xbcba, 3558), (0xbcbb, 3559),
+    (0xbcbc, 10938), (0xbcbd, 10939), (0xbcbe, 3560), (0xbcbf, 3561), (0xbcc0, 10940), (0xbcc1, 3562), (0xbcc2, 3563), (0xbcc3, 3564),
+    (0xbcc4, 10941), (0xbcc5, 3565), (0xbcc6, 3566), (0xbcc7, 3567), (0xbcc8, 3568), (0xbcc9, 3569), (0xbcca, 3570), (0xbccb, 3571),
+    (0xbccc, 3572), (0xbccd, 10942), (0xbcce, 3573), (0xbccf, 10943), (0xbcd0, 10944), (0xbcd1, 10945), (0xbcd2, 3574), (0xbcd3, 3575),
+    (0xbcd4, 3576), (0xbcd5, 10946), (0xbcd6, 3577), (0xbcd7, 3578), (0xbcd8, 10947), (0xbcd9, 3579), (0xbcda, 3580), (0xbcdb, 3581),
+    (0xbcdc, 10948), (0xbcdd, 3582), (0xbcde, 3583), (0xbcdf, 3584), (0xbce0, 3585), (0xbce1, 3586), (0xbce2, 3587), (0xbce3, 3588),
+    (0xbce4, 3589), (0xbce5, 3590), (0xbce6, 3591), (0xbce7, 3592), (0xbce8, 3593), (0xbce9, 3594), (0xbcea, 3595), (0xbceb, 3596),
+    (0xbcec, 3597), (0xbced, 3598), (0xbcee, 3599), (0xbcef, 3600), (0xbcf0, 3601), (0xbcf1, 3602), (0xbcf2, 3603), (0xbcf3, 3604),
+    (0xbcf4, 10949), (0xbcf5, 10950), (0xbcf6, 10951), (0xbcf7, 3605), (0xbcf8, 10952), (0xbcf9, 3606), (0xbcfa, 3607), (0xbcfb, 3608),
+    (0xbcfc, 10953), (0xbcfd, 3609), (0xbcfe, 3610), (0xbcff, 3611), (0xbd00, 3612), (0xbd01, 3613), (0xbd02, 3614), (0xbd03, 3615),
+    (0xbd04, 10954), (0xbd05, 10955), (0xbd06, 3616), (0xbd07, 10956), (0xbd08, 3617), (0xbd09, 10957), (0xbd0a, 3618), (0xbd0b, 3619),
+    (0xbd0c, 3620), (0xbd0d, 3621), (0xbd0e, 3622), (0xbd0f, 3623), (0xbd10, 10958), (0xbd11, 3624), (0xbd12, 3625), (0xbd13, 3626),
+    (0xbd14, 10959), (0xbd15, 3627), (0xbd16, 3628), (0xbd17, 3629), (0xbd18, 3630), (0xbd19, 3631), (0xbd1a, 3632), (0xbd1b, 3633),
+    (0xbd1c, 3634), (0xbd1d, 3635), (0xbd1e, 3642), (0xbd1f, 3643), (0xbd20, 3644), (0xbd21, 3645), (0xbd22, 3646), (0xbd23, 3647),
+    (0xbd24, 10960), (0xbd25, 3648), (0xbd26, 3649), (0xbd27, 3650), (0xbd28, 3651), (0xbd29, 3652), (0xbd2a, 3653), (0xbd2b, 3654),
+    (0xbd2c, 10961), (0xbd2d, 3655), (0xbd2e, 3656), (0xbd2f, 3657), (0xbd30, 3658), (0xbd31, 3659), (0xbd32, 3660), (0xbd33, 3661),
+    (0xbd34, 3662), (0xbd35, 3663), (0xbd36, 3664), (0xbd37, 3665), (0xbd38, 3666), (0xbd39, 3667), (0xbd3a, 3674), (0xbd3b, 3675),
+    (0xbd3c, 3676), (0xbd3d, 3677), (0xbd3e, 3678), (0xbd3f, 3679), (0xbd40, 10962), (0xbd41, 3680), (0xbd42, 3681), (0xbd43, 3682),
+    (0xbd44, 3683), (0xbd45, 3684), (0xbd46, 3685), (0xbd47, 3686), (0xbd48, 10963), (0xbd49, 10964), (0xbd4a, 3687), (0xbd4b, 3688),
+    (0xbd4c, 10965), (0xbd4d, 3689), (0xbd4e, 3690), (0xbd4f, 3691), (0xbd50, 10966), (0xbd51, 3692), (0xbd52, 3693), (0xbd53, 3694),
+    (0xbd54, 3695), (0xbd55, 3696), (0xbd56, 3697), (0xbd57, 3698), (0xbd58, 10967), (0xbd59, 10968), (0xbd5a, 3699), (0xbd5b, 3700),
+    (0xbd5c, 3701), (0xbd5d, 3702), (0xbd5e, 3703), (0xbd5f, 3704), (0xbd60, 3705), (0xbd61, 3706), (0xbd62, 3707), (0xbd63, 3708),
+    (0xbd64, 10969), (0xbd65, 3709), (0xbd66, 3710), (0xbd67, 3711), (0xbd68, 10970), (0xbd69, 3712), (0xbd6a, 3713), (0xbd6b, 3714),
+    (0xbd6c, 3715), (0xbd6d, 3716), (0xbd6e, 3717), (0xbd6f, 3718), (0xbd70, 3719), (0xbd71, 3720), (0xbd72, 3721), (0xbd73, 3722),
+    (0xbd74, 3723), (0xbd75, 3724), (0xbd76, 3725), (0xbd77, 3726), (0xbd78, 3727), (0xbd79, 3728), (0xbd7a, 3729), (0xbd7b, 3730),
+    (0xbd7c, 3731), (0xbd7d, 3732), (0xbd7e, 3733), (0xbd7f, 3734), (0xbd80, 10971), (0xbd81, 10972), (0xbd82, 3735), (0xbd83, 3736),
+    (0xbd84, 10973), (0xbd85, 3737), (0xbd86, 3738), (0xbd87, 10974), (0xbd88, 10975), (0xbd89, 10976), (0xbd8a, 10977), (0xbd8b, 3739),
+    (0xbd8c, 3740), (0xbd8d, 3741), (0xbd8e, 3742), (0xbd8f, 3743), (0xbd90, 10978), (0xbd91, 10979), (0xbd92, 3744), (0xbd93, 10980),
+    (0xbd94, 3745), (0xbd95, 10981), (0xbd96, 3746), (0xbd97, 3747), (0xbd98, 3748), (0xbd99, 10982), (0xbd9a, 10983), (0xbd9b, 3749),
+    (0xbd9c, 10984), (0xbd9d, 3750), (0xbd9e, 3751), (0xbd9f, 3752), (0xbda0, 3753), (0xbda1, 3754), (0xbda2, 3755), (0xbda3, 3756),
+    (0xbda4, 10985), (0xbda5, 3757), (0xbda6, 3758), (0xbda7, 3759), (0xbda8, 3760), (0xbda9, 3761), (0xbdaa, 3762), (0xbdab, 3763),
+    (0xbdac, 3764), (0xbdad, 3765), (0xbdae, 3766), (0xbdaf, 3767), (0xbdb0, 10986), (0xbdb1, 3768), (0xbdb2, 3769), (0xbdb3, 3770),
+    (0xbdb4, 3771), (0xbdb5, 3772), (0xbdb6, 3773), (0xbdb7, 3774), (0xbdb8, 10987), (0xbdb9, 3775), (0xbdba, 3776), (0xbdbb, 3777),
+    (0xbdbc, 3778), (0xbdbd, 3779), (0xbdbe, 3780), (0xbdbf, 3781), (0xbdc0, 3782), (0xbdc1, 3783), (0xbdc2, 3784), (0xbdc3, 3785),
+    (0xbdc4, 3786), (0xbdc5, 3787), (0xbdc6, 3788), (0xbdc7, 3789), (0xbdc8, 3790), (0xbdc9, 3791), (0xbdca, 3792), (0xbdcb, 3793),
+    (0xbdcc, 3794), (0xbdcd, 3795), (0xbdce, 3796), (0xbdcf, 3797), (0xbdd0, 3798), (0xbdd1, 3799), (0xbdd2, 3800), (0xbdd3, 3801),
+    (0xbdd4, 10988), (0xbdd5, 10989), (0xbdd6, 3802), (0xbdd7, 3803), (0xbdd8, 10990), (0xbdd9, 3804), (0xbdda, 3805), (0xbddb, 3806),
+    (0xbddc, 10991), (0xbddd, 3807), (0xbdde, 3808), (0xbddf, 3809), (0xbde0, 3810), (0xbde1, 3811), (0xbde2, 3812), (0xbde3, 3813),
+    (0xbde4, 3814), (0xbde5, 3815), (0xbde6, 3816), (0xbde7, 3817), (0xbde8, 3818), (0xbde9, 10992), (0xbdea, 3819), (0xbdeb, 3820),
+    (0xbdec, 3821), (0xbded, 3822), (0xbdee, 3823), (0xbdef, 3824), (0xbdf0, 10993), (0xbdf1, 3825), (0xbdf2, 3832), (0xbdf3, 3833),
+    (0xbdf4, 10994), (0xbdf5, 3834), (0xbdf6, 3835), (0xbdf7, 3836), (0xbdf8, 10995), (0xbdf9, 3837), (0xbdfa, 3838), (0xbdfb, 3839),
+    (0xbdfc, 3840), (0xbdfd, 3841), (0xbdfe, 3842), (0xbdff, 3843), (0xbe00, 10996), (0xbe01, 3844), (0xbe02, 3845), (0xbe03, 10997),
+    (0xbe04, 3846), (0xbe05, 10998), (0xbe06, 3847), (0xbe07, 3848), (0xbe08, 3849), (0xbe09, 3850), (0xbe0a, 3851), (0xbe0b, 3852),
+    (0xbe0c, 10999), (0xbe0d, 11000), (0xbe0e, 3853), (0xbe0f, 3854), (0xbe10, 11001), (0xbe11, 3855), (0xbe12, 3856), (0xbe13, 3857),
+    (0xbe14, 11002), (0xbe15, 3864), (0xbe16, 3865), (0xbe17, 3866), (0xbe18, 3867), (0xbe19, 3868), (0xbe1a, 3869), (0xbe1b, 3870),
+    (0xbe1c, 11003), (0xbe1d, 11004), (0xbe1e, 3871), (0xbe1f, 11005), (0xbe20, 3872), (0xbe21, 3873), (0xbe22, 3874), (0xbe23, 3875),
+    (0xbe24, 3876), (0xbe25, 3877), (0xbe26, 3878), (0xbe27, 3879), (0xbe28, 3880), (0xbe29, 3881), (0xbe2a, 3882), (0xbe2b, 3883),
+    (0xbe2c, 3884), (0xbe2d, 3885), (0xbe2e, 3886), (0xbe2f, 3887), (0xbe30, 3888), (0xbe31, 3889), (0xbe32, 3890), (0xbe33, 3891),
+    (0xbe34, 3892), (0xbe35, 3893), (0xbe36, 3894), (0xbe37, 3895), (0xbe38, 3896), (0xbe39, 3897), (0xbe3a, 3898), (0xbe3b, 3899),
+    (0xbe3c, 3900), (0xbe3d, 3901), (0xbe3e, 3902), (0xbe3f, 3903), (0xbe40, 3904), (0xbe41, 3905), (0xbe42, 3906), (0xbe43, 3907),
+    (0xbe44, 11006), (0xbe45, 11007), (0xbe46, 3908), (0xbe47, 3909), (0xbe48, 11008), (0xbe49, 3910), (0xbe4a, 3911), (0xbe4b, 3912),
+    (0xbe4c, 11009), (0xbe4d, 3913), (0xbe4e, 11010), (0xbe4f, 3914), (0xbe50, 3915), (0xbe51, 3916), (0xbe52, 3917), (0xbe53, 3918),
+    (0xbe54, 11011), (0xbe55, 11012), (0xbe56, 3919), (0xbe57, 11013), (0xbe58, 3920), (0xbe59, 11014), (0xbe5a, 11015), (0xbe5b, 11016),
+    (0xbe5c, 3921), (0xbe5d, 3922), (0xbe5e, 3923), (0xbe5f, 3924), (0xbe60, 11017), (0xbe61, 11018), (0xbe62, 3925), (0xbe63, 3926),
+    (0xbe64, 11019), (0xbe65, 3927), (0xbe66, 3928), (0xbe67, 3929), (0xbe68, 11116), (0xbe69, 3930), (0xbe6a, 11117), (0xbe6b, 3931),
+    (0xbe6c, 3932), (0xbe6d, 3933), (0xbe6e, 3934), (0xbe6f, 3935), (0xbe70, 11118), (0xbe71, 11119), (0xbe72, 3936), (0xbe73, 11120),
+    (0xbe74, 11121), (0xbe75, 11122), (0xbe76, 3937), (0xbe77, 3938), (0xbe78, 3939), (0xbe79, 3940), (0xbe7a, 3941), (0xbe7b, 11123),
+    (0xbe7c, 11124), (0xbe7d, 11125), (0xbe7e, 3942), (0xbe7f, 3943), (0xbe80, 11126), (0xbe81, 3944), (0xbe82, 3945), (0xbe83, 3946),
+    (0xbe84, 11127), (0xbe85, 3947), (0xbe86, 3948), (0xbe87, 3949), (0xbe88, 3950), (0xbe89, 3951), (0xbe8a, 3952), (0xbe8b, 3953),
+    (0xbe8c, 11128), (0xbe8d, 11129), (0xbe8e, 3954), (0xbe8f, 11130), (0xbe90, 11131), (0xbe91, 11132), (0xbe92, 3955), (0xbe93, 3956),
+    (0xbe94, 3957), (0xbe95, 3958), (0xbe96, 3959), (0xbe97, 3960), (0xbe98, 11133), (0xbe99, 11134), (0xbe9a, 3961), (0xbe9b, 3962),
+    (0xbe9c, 3963), (0xbe9d, 3964), (0xbe9e, 3965), (0xbe9f, 3966), (0xbea0, 3967), (0xbea1, 3968), (0xbea2, 3969), (0xbea3, 3970),
+    (0xbea4, 3971), (0xbea5, 3972), (0xbea6, 3973), (0xbea7, 3974), (0xbea8, 11135), (0xbea9, 3975), (0xbeaa, 3976), (0xbeab, 3977),
+    (0xbeac, 3978), (0xbead, 3979), (0xbeae, 3980), (0xbeaf, 3981), (0xbeb0, 3982), (0xbeb1, 3983), (0xbeb2, 3984), (0xbeb3, 3985),
+    (0xbeb4, 3986), (0xbeb5, 3987), (0xbeb6, 3988), (0xbeb7, 3989), (0xbeb8, 3990), (0xbeb9, 3991), (0xbeba, 3992), (0xbebb, 3993),
+    (0xbebc, 3994), (0xbebd, 3995), (0xbebe, 3996), (0xbebf, 3997), (0xbec0, 3998), (0xbec1, 3999), (0xbec2, 4000), (0xbec3, 4001),
+    (0xbec4, 4002), (0xbec5, 4003), (0xbec6, 4004), (0xbec7, 4005), (0xbec8, 4006), (0xbec9, 4007), (0xbeca, 4008), (0xbecb, 4009),
+    (0xbecc, 4010), (0xbecd, 4011), (0xbece, 4012), (0xbecf, 4013), (0xbed0, 11136), (0xbed1, 11137), (0xbed2, 4014), (0xbed3, 4015),
+    (0xbed4, 11138), (0xbed5, 4022), (0xbed6, 4023), (0xbed7, 11139), (0xbed8, 11140), (0xbed9, 4024), (0xbeda, 4025), (0xbedb, 4026),
+    (0xbedc, 4027), (0xbedd, 4028), (0xbede, 4029), (0xbedf, 4030), (0xbee0, 11141), (0xbee1, 4031), (0xbee2, 4032), (0xbee3, 11142),
+    (0xbee4, 11143), (0xbee5, 11144), (0xbee6, 4033), (0xbee7, 4034), (0xbee8, 4035), (0xbee9, 4036), (0xbeea, 4037), (0xbeeb, 4038),
+    (0xbeec, 11145), (0xbeed, 4039), (0xbeee, 4040), (0xbeef, 4041), (0xbef0, 4042), (0xbef1, 4043), (0xbef2, 4044), (0xbef3, 4045),
+    (0xbef4, 4046), (0xbef5, 4047), (0xbef6, 4054), (0xbef7, 4055), (0xbef8, 4056), (0xbef9, 4057), (0xbefa, 4058), (0xbefb, 4059),
+    (0xbefc, 4060), (0xbefd, 4061), (0xbefe, 4062), (0xbeff, 4063), (0xbf00, 4064), (0xbf01, 11146), (0xbf02, 4065), (0xbf03, 4066),
+    (0xbf04, 4067), (0xbf05, 4068), (0xbf06, 4069), (0xbf07, 4070), (0xbf08, 11147), (0xbf09, 11148), (0xbf0a, 4071), (0xbf0b, 4072),
+    (0xbf0c, 4073), (0xbf0d, 4074), (0xbf0e, 4075), (0xbf0f, 4076), (0xbf10, 4077), (0xbf11, 4078), (0xbf12, 4079), (0xbf13, 4080),
+    (0xbf14, 4081), (0xbf15, 4082), (0xbf16, 4083), (0xbf17, 4084), (0xbf18, 11149), (0xbf19, 11150), (0xbf1a, 4085), (0xbf1b, 11151),
+    (0xbf1c, 11152), (0xbf1d, 11153), (0xbf1e, 4086), (0xbf1f, 4087), (0xbf20, 4088), (0xbf21, 4089), (0xbf22, 4090), (0xbf23, 4091),
+    (0xbf24, 4092), (0xbf25, 4093), (0xbf26, 4094), (0xbf27, 4095), (0xbf28, 4096), (0xbf29, 4097), (0xbf2a, 4098), (0xbf2b, 4099),
+    (0xbf2c, 4100), (0xbf2d, 4101), (0xbf2e, 4102), (0xbf2f, 4103), (0xbf30, 4104), (0xbf31, 4105), (0xbf32, 4106), (0xbf33, 4107),
+    (0xbf34, 4108), (0xbf35, 4109), (0xbf36, 4110), (0xbf37, 4111), (0xbf38, 4112), (0xbf39, 4113), (0xbf3a, 4114), (0xbf3b, 4115),
+    (0xbf3c, 4116), (0xbf3d, 4117), (0xbf3e, 4118), (0xbf3f, 4119), (0xbf40, 11154), (0xbf41, 11155), (0xbf42, 4120), (0xbf43, 4121),
+    (0xbf44, 11156), (0xbf45, 4122), (0xbf46, 4123), (0xbf47, 4124), (0xbf48, 11157), (0xbf49, 4125), (0xbf4a, 4126), (0xbf4b, 4127),
+    (0xbf4c, 4128), (0xbf4d, 4129), (0xbf4e, 4130), (0xbf4f, 4131), (0xbf50, 11158), (0xbf51, 11159), (0xbf52, 4132), (0xbf53, 4133),
+    (0xbf54, 4134), (0xbf55, 11160), (0xbf56, 4135), (0xbf57, 4136), (0xbf58, 4137), (0xbf59, 4138), (0xbf5a, 4139), (0xbf5b, 4140),
+    (0xbf5c, 4141), (0xbf5d, 4142), (0xbf5e, 4143), (0xbf5f, 4144), (0xbf60, 4145), (0xbf61, 4146), (0xbf62, 4147), (0xbf63, 4148),
+    (0xbf64, 4149), (0xbf65, 4150), (0xbf66, 4151), (0xbf67, 4152), (0xbf68, 4153), (0xbf69, 4154), (0xbf6a, 4155), (0xbf6b, 4156),
+    (0xbf6c, 4157), (0xbf6d, 4158), (0xbf6e, 4159), (0xbf6f, 4160), (0xbf70, 4161), (0xbf71, 4162), (0xbf72, 4163), (0xbf73, 4164),
+    (0xbf74, 4165), (0xbf75, 4166), (0xbf76, 4167), (0xbf77, 4168), (0xbf78, 4169), (0xbf79, 4170), (0xbf7a, 4171), (0xbf7b, 4172),
+    (0xbf7c, 4173), (0xbf7d, 4174), (0xbf7e, 4175), (0xbf7f, 4176), (0xbf80, 4177), (0xbf81, 4178), (0xbf82, 4179), (0xbf83, 4180),
+    (0xbf84, 4181), (0xbf85, 4182), (0xbf86, 4183), (0xbf87, 4184), (0xbf88, 4185), (0xbf89, 4186), (0xbf8a, 4187), (0xbf8b, 4188),
+    (0xbf8c, 4189), (0xbf8d, 4190), (0xbf8e, 4191), (0xbf8f, 4192), (0xbf90, 4193), (0xbf91, 4194), (0xbf92, 4195), (0xbf93, 4196),
+    (0xbf94, 11161), (0xbf95, 4197), (0xbf96, 4198), (0xbf97, 4199), (0xbf98, 4200), (0xbf99, 4201), (0xbf9a, 4202), (0xbf9b, 4203),
+    (0xbf9c, 4204), (0xbf9d, 4205), (0xbf9e, 4212), (0xbf9f, 4213), (0xbfa0, 4214), (0xbfa1, 4215), (0xbfa2, 4216), (0xbfa3, 4217),
+    (0xbfa4, 4218), (0xbfa5, 4219), (0xbfa6, 4220), (0xbfa7, 4221), (0xbfa8, 4222), (0xbfa9, 4223), (0xbfaa, 4224), (0xbfab, 4225),
+    (0xbfac, 4226), (0xbfad, 4227), (0xbfae, 4228), (0xbfaf, 4229), (0xbfb0, 11162), (0xbfb1, 4230), (0xbfb2, 4231), (0xbfb3, 4232),
+    (0xbfb4, 4233), (0xbfb5, 4234), (0xbfb6, 4235), (0xbfb7, 4236), (0xbfb8, 4237), (0xbfb9, 4244), (0xbfba, 4245), (0xbfbb, 4246),
+    (0xbfbc, 4247), (0xbfbd, 4248), (0xbfbe, 4249), (0xbfbf, 4250), (0xbfc0, 4251), (0xbfc1, 4252), (0xbfc2, 4253), (0xbfc3, 4254),
+    (0xbfc4, 4255), (0xbfc5, 11163), (0xbfc6, 4256), (0xbfc7, 4257), (0xbfc8, 4258), (0xbfc9, 4259), (0xbfca, 4260), (0xbfcb, 4261),
+    (0xbfcc, 11164), (0xbfcd, 11165), (0xbfce, 4262), (0xbfcf, 4263), (0xbfd0, 11166), (0xbfd1, 4264), (0xbfd2, 4265), (0xbfd3, 4266),
+    (0xbfd4, 11167), (0xbfd5, 4267), (0xbfd6, 4268), (0xbfd7, 4269), (0xbfd8, 4270), (0xbfd9, 4271), (0xbfda, 4272), (0xbfdb, 4273),
+    (0xbfdc, 11168), (0xbfdd, 4274), (0xbfde, 4275), (0xbfdf, 11169), (0xbfe0, 4276), (0xbfe1, 11170), (0xbfe2, 4277), (0xbfe3, 4278),
+    (0xbfe4, 4279), (0xbfe5, 4280), (0xbfe6, 4281), (0xbfe7, 4282), (0xbfe8, 4283), (0xbfe9, 4284), (0xbfea, 4285), (0xbfeb, 4286),
+    (0xbfec, 4287), (0xbfed, 4288), (0xbfee, 4289), (0xbfef, 4290), (0xbff0, 4291), (0xbff1, 4292), (0xbff2, 4293), (0xbff3, 4294),
+    (0xbff4, 4295), (0xbff5, 4296), (0xbff6, 4297), (0xbff7, 4298), (0xbff8, 4299), (0xbff9, 4300), (0xbffa, 4301), (0xbffb, 4302),
+    (0xbffc, 4303), (0xbffd, 4304), (0xbffe, 4305), (0xbfff, 4306), (0xc000, 4307), (0xc001, 4308), (0xc002, 4309), (0xc003, 4310),
+    (0xc004, 4311), (0xc005, 4312), (0xc006, 4313), (0xc007, 4314), (0xc008, 4315), (0xc009, 4316), (0xc00a, 4317), (0xc00b, 4318),
+    (0xc00c, 4319), (0xc00d, 4320), (0xc00e, 4321), (0xc00f, 4322), (0xc010, 4323), (0xc011, 4324), (0xc012, 4325), (0xc013, 4326),
+    (0xc014, 4327), (0xc015, 4328), (0xc016, 4329), (0xc017, 4330), (0xc018, 4331), (0xc019, 4332), (0xc01a, 4333), (0xc01b, 4334),
+    (0xc01c, 4335), (0xc01d, 4336), (0xc01e, 4337), (0xc01f, 4338), (0xc020, 4339), (0xc021, 4340), (0xc022, 4341), (0xc023, 4342),
+    (0xc024, 4343), (0xc025, 4344), (0xc026, 4345), (0xc027, 4346), (0xc028, 4347), (0xc029, 4348), (0xc02a, 4349), (0xc02b, 4350),
+    (0xc02c, 4351), (0xc02d, 4352), (0xc02e, 4353), (0xc02f, 4354), (0xc030, 4355), (0xc031, 4356), (0xc032, 4357), (0xc033, 4358),
+    (0xc034, 4359), (0xc035, 4360), (0xc036, 4361), (0xc037, 4362), (0xc038, 4363), (0xc039, 4364), (0xc03a, 4365), (0xc03b, 4366),
+    (0xc03c, 11171), (0xc03d, 4367), (0xc03e, 4368), (0xc03f, 4369), (0xc040, 4370), (0xc041, 4371), (0xc042, 4372), (0xc043, 4373),
+    (0xc044, 4374), (0xc045, 4375), (0xc046, 4376), (0xc047, 4377), (0xc048, 4378), (0xc049, 4379), (0xc04a, 4380), (0xc04b, 4381),
+    (0xc04c, 4382), (0xc04d, 4383), (0xc04e, 4384), (0xc04f, 4385), (0xc050, 4386), (0xc051, 11172), (0xc052, 4387), (0xc053, 4388),
+    (0xc054, 4389), (0xc055, 4390), (0xc056, 4391), (0xc057, 4392), (0xc058, 11173), (0xc059, 4393), (0xc05a, 4394), (0xc05b, 4395),
+    (0xc05c, 11174), (0xc05d, 4402), (0xc05e, 4403), (0xc05f, 4404), (0xc060, 11175), (0xc061, 4405), (0xc062, 4406), (0xc063, 4407),
+    (0xc064, 4408), (0xc065, 4409), (0xc066, 4410), (0xc067, 4411), (0xc068, 11176), (0xc069, 11177), (0xc06a, 4412), (0xc06b, 4413),
+    (0xc06c, 4414), (0xc06d, 4415), (0xc06e, 4416), (0xc06f, 4417), (0xc070, 4418), (0xc071, 4419), (0xc072, 4420), (0xc073, 4421),
+    (0xc074, 4422), (0xc075, 4423), (0xc076, 4424), (0xc077, 4425), (0xc078, 4426), (0xc079, 4427), (0xc07a, 4434), (0xc07b, 4435),
+    (0xc07c, 4436), (0xc07d, 4437), (0xc07e, 4438), (0xc07f, 4439), (0xc080, 4440), (0xc081, 4441), (0xc082, 4442), (0xc083, 4443),
+    (0xc084, 4444), (0xc085, 4445), (0xc086, 4446), (0xc087, 4447), (0xc088, 4448), (0xc089, 4449), (0xc08a, 4450), (0xc08b, 4451),
+    (0xc08c, 4452), (0xc08d, 4453), (0xc08e, 4454), (0xc08f, 4455), (0xc090, 11178), (0xc091, 11179), (0xc092, 4456), (0xc093, 4457),
+    (0xc094, 11180), (0xc095, 4458), (0xc096, 4459), (0xc097, 4460), (0xc098, 11181), (0xc099, 4461), (0xc09a, 4462), (0xc09b, 4463),
+    (0xc09c, 4464), (0xc09d, 4465), (0xc09e, 4466), (0xc09f, 4467), (0xc0a0, 11182), (0xc0a1, 11183), (0xc0a2, 4468), (0xc0a3, 11184),
+    (0xc0a4, 4469), (0xc0a5, 11185), (0xc0a6, 4470), (0xc0a7, 4471), (0xc0a8, 4472), (0xc0a9, 4473), (0xc0aa, 4474), (0xc0ab, 4475),
+    (0xc0ac, 11186), (0xc0ad, 11187), (0xc0ae, 4476), (0xc0af, 11188), (0xc0b0, 11189), (0xc0b1, 4477), (0xc0b2, 4478), (0xc0b3, 11190),
+    (0xc0b4, 11191), (0xc0b5, 11192), (0xc0b6, 11193), (0xc0b7, 4479), (0xc0b8, 4480), (0xc0b9, 4481), (0xc0ba, 4482), (0xc0bb, 4483),
+    (0xc0bc, 11194), (0xc0bd, 11195), (0xc0be, 4484), (0xc0bf, 11196), (0xc0c0, 11197), (0xc0c1, 11198), (0xc0c2, 4485), (0xc0c3, 4486),
+    (0xc0c4, 4487), (0xc0c5, 11199), (0xc0c6, 4488), (0xc0c7, 4489), (0xc0c8, 11200), (0xc0c9, 11201), (0xc0ca, 4490), (0xc0cb, 4491),
+    (0xc0cc, 11202), (0xc0cd, 4492), (0xc0ce, 4493), (0xc0cf, 4494), (0xc0d0, 11203), (0xc0d1, 4495), (0xc0d2, 4496), (0xc0d3, 4497),
+    (0xc0d4, 4498), (0xc0d5, 4499), (0xc0d6, 4500), (0xc0d7, 4501), (0xc0d8, 11204), (0xc0d9, 11205), (0xc0da, 4502), (0xc0db, 11206),
+    (0xc0dc, 11207), (0xc0dd, 11208), (0xc0de, 4503), (0xc0df, 4504), (0xc0e0, 4505), (0xc0e1, 4506), (0xc0e2, 4507), (0xc0e3, 4508),
+    (0xc0e4, 11209), (0xc0e5, 11306), (0xc0e6, 4509), (0xc0e7, 4510), (0xc0e8, 11307), (0xc0e9, 4511), (0xc0ea, 4512), (0xc0eb, 4513),
+    (0xc0ec, 11308), (0xc0ed, 4514), (0xc0ee, 4515), (0xc0ef, 4516), (0xc0f0, 4517), (0xc0f1, 4518), (0xc0f2, 4519), (0xc0f3, 4520),
+    (0xc0f4, 11309), (0xc0f5, 11310), (0xc0f6, 4521), (0xc0f7, 11311), (0xc0f8, 4522), (0xc0f9, 11312), (0xc0fa, 4523), (0xc0fb, 4524),
+    (0xc0fc, 4525), (0xc0fd, 4526), (0xc0fe, 4527), (0xc0ff, 4528), (0xc100, 11313), (0xc101, 4529), (0xc102, 4530), (0xc103, 4531),
+    (0xc104, 11314), (0xc105, 4532), (0xc106, 4533), (0xc107, 4534), (0xc108, 11315), (0xc109, 4535), (0xc10a, 4536), (0xc10b, 4537),
+    (0xc10c, 4538), (0xc10d, 4539), (0xc10e, 4540), (0xc10f, 4541), (0xc110, 11316), (0xc111, 4542), (0xc112, 4543), (0xc113, 4544),
+    (0xc114, 4545), (0xc115, 11317), (0xc116, 4546), (0xc117, 4547), (0xc118, 4548), (0xc119, 4549), (0xc11a, 4550), (0xc11b, 4551),
+    (0xc11c, 11318), (0xc11d, 11319), (0xc11e, 11320), (0xc11f, 11321), (0xc120, 11322), (0xc121, 4552), (0xc122, 4553), (0xc123, 11323),
+    (0xc124, 11324), (0xc125, 4554), (0xc126, 11325), (0xc127, 11326), (0xc128, 4555), (0xc129, 4556), (0xc12a, 4557), (0xc12b, 4558),
+    (0xc12c, 11327), (0xc12d, 11328), (0xc12e, 4559), (0xc12f, 11329), (0xc130, 11330), (0xc131, 11331), (0xc132, 4560), (0xc133, 4561),
+    (0xc134, 4562), (0xc135, 4563), (0xc136, 11332), (0xc137, 4564), (0xc138, 11333), (0xc139, 11334), (0xc13a, 4565), (0xc13b, 4566),
+    (0xc13c, 11335), (0xc13d, 4567), (0xc13e, 4568), (0xc13f, 4569), (0xc140, 11336), (0xc141, 4570), (0xc142, 4571), (0xc143, 4572),
+    (0xc144, 4573), (0xc145, 4574), (0xc146, 4575), (0xc147, 4576), (0xc148, 11337), (0xc149, 11338), (0xc14a, 4577), (0xc14b, 11339),
+    (0xc14c, 11340), (0xc14d, 11341), (0xc14e, 4578), (0xc14f, 4579), (0xc150, 4580), (0xc151, 4581), (0xc152, 4582), (0xc153, 4583),
+    (0xc154, 11342), (0xc155, 11343), (0xc156, 4584), (0xc157, 4585), (0xc158, 11344), (0xc159, 4592), (0xc15a, 4593), (0xc15b, 4594),
+    (0xc15c, 11345), (0xc15d, 4595), (0xc15e, 4596), (0xc15f, 4597), (0xc160, 4598), (0xc161, 4599), (0xc162, 4600), (0xc163, 4601),
+    (0xc164, 11346), (0xc165, 11347), (0xc166, 4602), (0xc167, 11348), (0xc168, 11349), (0xc169, 11350), (0xc16a, 4603), (0xc16b, 4604),
+    (0xc16c, 4605), (0xc16d, 4606), (0xc16e, 4607), (0xc16f, 4608), (0xc170, 11351), (0xc171, 4609), (0xc172, 4610), (0xc173, 4611),
+    (0xc174, 11352), (0xc175, 4612), (0xc176, 4613), (0xc177, 4614), (0xc178, 11353), (0xc179, 4615), (0xc17a, 4616), (0xc17b, 4617),
+    (0xc17c, 4624), (0xc17d, 4625), (0xc17e, 4626), (0xc17f, 4627), (0xc180, 4628), (0xc181, 4629), (0xc182, 4630), (0xc183, 4631),
+    (0xc184, 4632), (0xc185, 11354), (0xc186, 4633), (0xc187, 4634), (0xc188, 4635), (0xc189, 4636), (0xc18a, 4637), (0xc18b, 4638),
+    (0xc18c, 11355), (0xc18d, 11356), (0xc18e, 11357), (0xc18f, 4639), (0xc190, 11358), (0xc191, 4640), (0xc192, 4641), (0xc193, 4642),
+    (0xc194, 11359), (0xc195, 4643), (0xc196, 11360), (0xc197, 4644), (0xc198, 4645), (0xc199, 4646), (0xc19a, 4647), (0xc19b, 4648),
+    (0xc19c, 11361), (0xc19d, 11362), (0xc19e, 4649), (0xc19f, 11363), (0xc1a0, 4650), (0xc1a1, 11364), (0xc1a2, 4651), (0xc1a3, 4652),
+    (0xc1a4, 4653), (0xc1a5, 11365), (0xc1a6, 4654), (0xc1a7, 4655), (0xc1a8, 11366), (0xc1a9, 11367), (0xc1aa, 4656), (0xc1ab, 4657),
+    (0xc1ac, 11368), (0xc1ad, 4658), (0xc1ae, 4659), (0xc1af, 4660), (0xc1b0, 11369), (0xc1b1, 4661), (0xc1b2, 4662), (0xc1b3, 4663),
+    (0xc1b4, 4664), (0xc1b5, 4665), (0xc1b6, 4666), (0xc1b7, 4667), (0xc1b8, 4668), (0xc1b9, 4669), (0xc1ba, 4670), (0xc1bb, 4671),
+    (0xc1bc, 4672), (0xc1bd, 11370), (0xc1be, 4673), (0xc1bf, 4674), (0xc1c0, 4675), (0xc1c1, 4676), (0xc1c2, 4677), (0xc1c3, 4678),
+    (0xc1c4, 11371), (0xc1c5, 4679), (0xc1c6, 4680), (0xc1c7, 4681), (0xc1c8, 11372), (0xc1c9, 4682), (0xc1ca, 4683), (0xc1cb, 4684),
+    (0xc1cc, 11373), (0xc1cd, 4685), (0xc1ce, 4686), (0xc1cf, 4687), (0xc1d0, 4688), (0xc1d1, 4689), (0xc1d2, 4690), (0xc1d3, 4691),
+    (0xc1d4, 11374), (0xc1d5, 4692), (0xc1d6, 4693), (0xc1d7, 11375), (0xc1d8, 11376), (0xc1d9, 4694), (0xc1da, 4695), (0xc1db, 4696),
+    (0xc1dc, 4697), (0xc1dd, 4698), (0xc1de, 4699), (0xc1df, 4700), (0xc1e0, 11377), (0xc1e1, 4701), (0xc1e2, 4702), (0xc1e3, 4703),
+    (0xc1e4, 11378), (0xc1e5, 4704), (0xc1e6, 4705), (0xc1e7, 4706), (0xc1e8, 11379), (0xc1e9, 4707), (0xc1ea, 4708), (0xc1eb, 4709),
+    (0xc1ec, 4710), (0xc1ed, 4711), (0xc1ee, 4712), (0xc1ef, 4713), (0xc1f0, 11380), (0xc1f1, 11381), (0xc1f2, 4714), (0xc1f3, 11382),
+    (0xc1f4, 4715), (0xc1f5, 4716), (0xc1f6, 4717), (0xc1f7, 4718), (0xc1f8, 4719), (0xc1f9, 4720), (0xc1fa, 4721), (0xc1fb, 4722),
+    (0xc1fc, 11383), (0xc1fd, 11384), (0xc1fe, 4723), (0xc1ff, 4724), (0xc200, 11385), (0xc201, 4725), (0xc202, 4726), (0xc203, 4727),
+    (0xc204, 11386), (0xc205, 4728), (0xc206, 4729), (0xc207, 4730), (0xc208, 4731), (0xc209, 4732), (0xc20a, 4733), (0xc20b, 4734),
+    (0xc20c, 11387), (0xc20d, 11388), (0xc20e, 4735), (0xc20f, 11389), (0xc210, 4736), (0xc211, 11390), (0xc212, 4737), (0xc213, 4738),
+    (0xc214, 4739), (0xc215, 4740), (0xc216, 4741), (0xc217, 4742), (0xc218, 11391), (0xc219, 11392), (0xc21a, 4743), (0xc21b, 4744),
+    (0xc21c, 11393), (0xc21d, 4745), (0xc21e, 4746), (0xc21f, 11394), (0xc220, 11395), (0xc221, 4747), (0xc222, 4748), (0xc223, 4749),
+    (0xc224, 4750), (0xc225, 4751), (0xc226, 4752), (0xc227, 4753), (0xc228, 11396), (0xc229, 11397), (0xc22a, 4754), (0xc22b, 11398),
+    (0xc22c, 4755), (0xc22d, 11399), (0xc22e, 4756), (0xc22f, 11496), (0xc230, 4757), (0xc231, 11497), (0xc232, 11498), (0xc233, 4758),
+    (0xc234, 11499), (0xc235, 4759), (0xc236, 4760), (0xc237, 4761), (0xc238, 4762), (0xc239, 4763), (0xc23a, 4764), (0xc23b, 4765),
+    (0xc23c, 4766), (0xc23d, 4767), (0xc23e, 4768), (0xc23f, 4769), (0xc240, 4770), (0xc241, 4771), (0xc242, 4772), (0xc243, 4773),
+    (0xc244, 4774), (0xc245, 4775), (0xc246, 4782), (0xc247, 4783), (0xc248, 11500), (0xc249, 4784), (0xc24a, 4785), (0xc24b, 4786),
+    (0xc24c, 4787), (0xc24d, 4788), (0xc24e, 4789), (0xc24f, 4790), (0xc250, 11501), (0xc251, 11502), (0xc252, 4791), (0xc253, 4792),
+    (0xc254, 11503), (0xc255, 4793), (0xc256, 4794), (0xc257, 4795), (0xc258, 11504), (0xc259, 4796), (0xc25a, 4797), (0xc25b, 4798),
+    (0xc25c, 4799), (0xc25d, 4800), (0xc25e, 4801), (0xc25f, 4802), (0xc260, 11505), (0xc261, 4803), (0xc262, 4804), (0xc263, 4805),
+    (0xc264, 4806), (0xc265, 11506), (0xc266, 4807), (0xc267, 4814), (0xc268, 4815), (0xc269, 4816), (0xc26a, 4817), (0xc26b, 4818),
+    (0xc26c, 11507), (0xc26d, 11508), (0xc26e, 4819), (0xc26f, 4820), (0xc270, 11509), (0xc271, 4821), (0xc272, 4822), (0xc273, 4823),
+    (0xc274, 11510), (0xc275, 4824), (0xc276, 4825), (0xc277, 4826), (0xc278, 4827), (0xc279, 4828), (0xc27a, 4829), (0xc27b, 4830),
+    (0xc27c, 11511), (0xc27d, 11512), (0xc27e, 4831), (0xc27f, 11513), (0xc280, 4832), (0xc281, 11514), (0xc282, 4833), (0xc283, 4834),
+    (0xc284, 4835), (0xc285, 4836), (0xc286, 4837), (0xc287, 4838), (0xc288, 11515), (0xc289, 11516), (0xc28a, 4839), (0xc28b, 4840),
+    (0xc28c, 4841), (0xc28d, 4842), (0xc28e, 4843), (0xc28f, 4844), (0xc290, 11517), (0xc291, 4845), (0xc292, 4846), (0xc293, 4847),
+    (0xc294, 4848), (0xc295, 4849), (0xc296, 4850), (0xc297, 4851), (0xc298, 11518), (0xc299, 4852), (0xc29a, 4853), (0xc29b, 11519),
+    (0xc29c, 4854), (0xc29d, 11520), (0xc29e, 4855), (0xc29f, 4856), (0xc2a0, 4857), (0xc2a1, 4858), (0xc2a2, 4859), (0xc2a3, 4860),
+    (0xc2a4, 11521), (0xc2a5, 11522), (0xc2a6, 4861), (0xc2a7, 4862), (0xc2a8, 11523), (0xc2a9, 4863), (0xc2aa, 4864), (0xc2ab, 4865),
+    (0xc2ac, 11524), (0xc2ad, 11525), (0xc2ae, 4866), (0xc2af, 4867), (0xc2b0, 4868), (0xc2b1, 4869), (0xc2b2, 4870), (0xc2b3, 4871),
+    (0xc2b4, 11526), (0xc2b5, 11527), (0xc2b6, 4872), (0xc2b7, 11528), (0xc2b8, 4873), (0xc2b9, 11529), (0xc2ba, 4874), (0xc2bb, 4875),
+    (0xc2bc, 4876), (0xc2bd, 4877), (0xc2be, 4878), (0xc2bf, 4879), (0xc2c0, 4880), (0xc2c1, 4881), (0xc2c2, 4882), (0xc2c3, 4883),
+    (0xc2c4, 4884), (0xc2c5, 4885), (0xc2c6, 4886), (0xc2c7, 4887), (0xc2c8, 4888), (0xc2c9, 4889), (0xc2ca, 4890), (0xc2cb, 4891),
+    (0xc2cc, 4892), (0xc2cd, 4893), (0xc2ce, 4894), (0xc2cf, 4895), (0xc2d0, 4896), (0xc2d1, 4897), (0xc2d2, 4898), (0xc2d3, 4899),
+    (0xc2d4, 4900), (0xc2d5, 4901), (0xc2d6, 4902), (0xc2d7, 4903), (0xc2d8, 4904), (0xc2d9, 4905), (0xc2da, 4906), (0xc2db, 4907),
+    (0xc2dc, 11530), (0xc2dd, 11531), (0xc2de, 4908), (0xc2df, 4909), (0xc2e0, 11532), (0xc2e1, 4910), (0xc2e2, 4911), (0xc2e3, 11533),
+    (0xc2e4, 11534), (0xc2e5, 4912), (0xc2e6, 4913), (0xc2e7, 4914), (0xc2e8, 4915), (0xc2e9, 4916), (0xc2ea, 4917), (0xc2eb, 11535),
+    (0xc2ec, 11536), (0xc2ed, 11537), (0xc2ee, 4918), (0xc2ef, 11538), (0xc2f0, 4919), (0xc2f1, 11539), (0xc2f2, 4920), (0xc2f3, 4921),
+    (0xc2f4, 4922), (0xc2f5, 4923), (0xc2f6, 11540), (0xc2f7, 4924), (0xc2f8, 11541), (0xc2f9, 11542), (0xc2fa, 4925), (0xc2fb, 11543),
+    (0xc2fc, 11544), (0xc2fd, 4926), (0xc2fe, 4927), (0xc2ff, 4928), (0xc300, 11545), (0xc301, 4929), (0xc302, 4930), (0xc303, 4931),
+    (0xc304, 4932), (0xc305, 4933), (0xc306, 4934), (0xc307, 4935), (0xc308, 11546), (0xc309, 11547), (0xc30a, 4936), (0xc30b, 4937),
+    (0xc30c, 11548), (0xc30d, 11549), (0xc30e, 4938), (0xc30f, 4939), (0xc310, 4940), (0xc311, 4941), (0xc312, 4942), (0xc313, 11550),
+    (0xc314, 11551), (0xc315, 11552), (0xc316, 4943), (0xc317, 4944), (0xc318, 11553), (0xc319, 4945), (0xc31a, 4946), (0xc31b, 4947),
+    (0xc31c, 11554), (0xc31d, 4948), (0xc31e, 4949), (0xc31f, 4950), (0xc320, 4951), (0xc321, 4952), (0xc322, 4953), (0xc323, 4954),
+    (0xc324, 11555), (0xc325, 11556), (0xc326, 4955), (0xc327, 4956), (0xc328, 11557), (0xc329, 11558), (0xc32a, 4957), (0xc32b, 4958),
+    (0xc32c, 4959), (0xc32d, 4960), (0xc32e, 4961), (0xc32f, 4962), (0xc330, 4963), (0xc331, 4964), (0xc332, 4965), (0xc333, 4972),
+    (0xc334, 4973), (0xc335, 4974), (0xc336, 4975), (0xc337, 4976), (0xc338, 4977), (0xc339, 4978), (0xc33a, 4979), (0xc33b, 4980),
+    (0xc33c, 4981), (0xc33d, 4982), (0xc33e, 4983), (0xc33f, 4984), (0xc340, 4985), (0xc341, 4986), (0xc342, 4987), (0xc343, 4988),
+    (0xc344, 4989), (0xc345, 11559), (0xc346, 4990), (0xc347, 4991), (0xc348, 4992), (0xc349, 4993), (0xc34a, 4994), (0xc34b, 4995),
+    (0xc34c, 4996), (0xc34d, 4997), (0xc34e, 5004), (0xc34f, 5005), (0xc350, 5006), (0xc351, 5007), (0xc352, 5008), (0xc353, 5009),
+    (0xc354, 5010), (0xc355, 5011), (0xc356, 5012), (0xc357, 5013), (0xc358, 5014), (0xc359, 5015), (0xc35a, 5016), (0xc35b, 5017),
+    (0xc35c, 5018), (0xc35d, 5019), (0xc35e, 5020), (0xc35f, 5021), (0xc360, 5022), (0xc361, 5023), (0xc362, 5024), (0xc363, 5025),
+    (0xc364, 5026), (0xc365, 5027), (0xc366, 5028), (0xc367, 5029), (0xc368, 11560), (0xc369, 11561), (0xc36a, 5030), (0xc36b, 5031),
+    (0xc36c, 11562), (0xc36d, 5032), (0xc36e, 5033), (0xc36f, 5034), (0xc370, 11563), (0xc371, 5035), (0xc372, 11564), (0xc373, 5036),
+    (0xc374, 5037), (0xc375, 5038), (0xc376, 5039), (0xc377, 5040), (0xc378, 11565), (0xc379, 11566), (0xc37a, 5041), (0xc37b, 5042),
+    (0xc37c, 11567), (0xc37d, 11568), (0xc37e, 5043), (0xc37f, 5044), (0xc380, 5045), (0xc381, 5046), (0xc382, 5047), (0xc383, 5048),
+    (0xc384, 11569), (0xc385, 5049), (0xc386, 5050), (0xc387, 5051), (0xc388, 11570), (0xc389, 5052), (0xc38a, 5053), (0xc38b, 5054),
+    (0xc38c, 11571), (0xc38d, 5055), (0xc38e, 5056), (0xc38f, 5057), (0xc390, 5058), (0xc391, 5059), (0xc392, 5060), (0xc393, 5061),
+    (0xc394, 5062), (0xc395, 5063), (0xc396, 5064), (0xc397, 5065), (0xc398, 5066), (0xc399, 5067), (0xc39a, 5068), (0xc39b, 5069),
+    (0xc39c, 5070), (0xc39d, 5071), (0xc39e, 5072), (0xc39f, 5073), (0xc3a0, 5074), (0xc3a1, 5075), (0xc3a2, 5076), (0xc3a3, 5077),
+    (0xc3a4, 5078), (0xc3a5, 5079), (0xc3a6, 5080), (0xc3a7, 5081), (0xc3a8, 5082), (0xc3a9, 5083), (0xc3aa, 5084), (0xc3ab, 5085),
+    (0xc3ac, 5086), (0xc3ad, 5087), (0xc3ae, 5088), (0xc3af, 5089), (0xc3b0, 5090), (0xc3b1, 5091), (0xc3b2, 5092), (0xc3b3, 5093),
+    (0xc3b4, 5094), (0xc3b5, 5095), (0xc3b6, 5096), (0xc3b7, 5097), (0xc3b8, 5098), (0xc3b9, 5099), (0xc3ba, 5100), (0xc3bb, 5101),
+    (0xc3bc, 5102), (0xc3bd, 5103), (0xc3be, 5104), (0xc3bf, 5105), (0xc3c0, 11572), (0xc3c1, 5106), (0xc3c2, 5107), (0xc3c3, 5108),
+    (0xc3c4, 5109), (0xc3c5, 5110), (0xc3c6, 5111), (0xc3c7, 5112), (0xc3c8, 5113), (0xc3c9, 5114), (0xc3ca, 5115), (0xc3cb, 5116),
+    (0xc3cc, 5117), (0xc3cd, 5118), (0xc3ce, 5119), (0xc3cf, 5120), (0xc3d0, 5121), (0xc3d1, 5122), (0xc3d2, 5123), (0xc3d3, 5124),
+    (0xc3d4, 5125), (0xc3d5, 5126), (0xc3d6, 5127), (0xc3d7, 5128), (0xc3d8, 11573), (0xc3d9, 11574), (0xc3da, 5129), (0xc3db, 5130),
+    (0xc3dc, 11575), (0xc3dd, 5131), (0xc3de, 5132), (0xc3df, 11576), (0xc3e0, 11577), (0xc3e1, 5133), (0xc3e2, 11578), (0xc3e3, 5134),
+    (0xc3e4, 5135), (0xc3e5, 5136), (0xc3e6, 5137), (0xc3e7, 5138), (0xc3e8, 11579), (0xc3e9, 11580), (0xc3ea, 5139), (0xc3eb, 5140),
+    (0xc3ec, 5141), (0xc3ed, 11581), (0xc3ee, 5142), (0xc3ef, 5143), (0xc3f0, 5144), (0xc3f1, 5145), (0xc3f2, 5146), (0xc3f3, 5147),
+    (0xc3f4, 11582), (0xc3f5, 11583), (0xc3f6, 5148), (0xc3f7, 5149), (0xc3f8, 11584), (0xc3f9, 5150), (0xc3fa, 5151), (0xc3fb, 5152),
+    (0xc3fc, 5153), (0xc3fd, 5154), (0xc3fe, 5155), (0xc3ff, 5162), (0xc400, 5163), (0xc401, 5164), (0xc402, 5165), (0xc403, 5166),
+    (0xc404, 5167), (0xc405, 5168), (0xc406, 5169), (0xc407, 5170), (0xc408, 11585), (0xc409, 5171), (0xc40a, 5172), (0xc40b, 5173),
+    (0xc40c, 5174), (0xc40d, 5175), (0xc40e, 5176), (0xc40f, 5177), (0xc410, 11586), (0xc411, 5178), (0xc412, 5179), (0xc413, 5180),
+    (0xc414, 5181), (0xc415, 5182), (0xc416, 5183), (0xc417, 5184), (0xc418, 5185), (0xc419, 5186), (0xc41a, 5187), (0xc41b, 5194),
+    (0xc41c, 5195), (0xc41d, 5196), (0xc41e, 5197), (0xc41f, 5198), (0xc420, 5199), (0xc421, 5200), (0xc422, 5201), (0xc423, 5202),
+    (0xc424, 11587), (0xc425, 5203), (0xc426, 5204), (0xc427, 5205), (0xc428, 5206), (0xc429, 5207), (0xc42a, 5208), (0xc42b, 5209),
+    (0xc42c, 11588), (0xc42d, 5210), (0xc42e, 5211), (0xc42f, 5212), (0xc430, 11589), (0xc431, 5213), (0xc432, 5214), (0xc433, 5215),
+    (0xc434, 11686), (0xc435, 5216), (0xc436, 5217), (0xc437, 5218), (0xc438, 5219), (0xc439, 5220), (0xc43a, 5221), (0xc43b, 5222),
+    (0xc43c, 11687), (0xc43d, 11688), (0xc43e, 5223), (0xc43f, 5224), (0xc440, 5225), (0xc441, 5226), (0xc442, 5227), (0xc443, 5228),
+    (0xc444, 5229), (0xc445, 5230), (0xc446, 5231), (0xc447, 5232), (0xc448, 11689), (0xc449, 5233), (0xc44a, 5234), (0xc44b, 5235),
+    (0xc44c, 5236), (0xc44d, 5237), (0xc44e, 5238), (0xc44f, 5239), (0xc450, 5240), (0xc451, 5241), (0xc452, 5242), (0xc453, 5243),
+    (0xc454, 5244), (0xc455, 5245), (0xc456, 5246), (0xc457, 5247), (0xc458, 5248), (0xc459, 5249), (0xc45a, 5250), (0xc45b, 5251),
+    (0xc45c, 5252), (0xc45d, 5253), (0xc45e, 5254), (0xc45f, 5255), (0xc460, 5256), (0xc461, 5257), (0xc462, 5258), (0xc463, 5259),
+    (0xc464, 11690), (0xc465, 11691), (0xc466, 5260), (0xc467, 5261), (0xc468, 11692), (0xc469, 5262), (0xc46a, 5263), (0xc46b, 5264),
+    (0xc46c, 11693), (0xc46d, 5265), (0xc46e, 5266), (0xc46f, 5267), (0xc470, 5268), (0xc471, 5269), (0xc472, 5270), (0xc473, 5271),
+    (0xc474, 11694), (0xc475, 11695), (0xc476, 5272), (0xc477, 5273), (0xc478, 5274), (0xc479, 11696), (0xc47a, 5275), (0xc47b, 5276),
+    (0xc47c, 5277), (0xc47d, 5278), (0xc47e, 5279), (0xc47f, 5280), (0xc480, 11697), (0xc481, 5281), (0xc482, 5282), (0xc483, 5283),
+    (0xc484, 5284), (0xc485, 5285), (0xc486, 5286), (0xc487, 5287), (0xc488, 5288), (0xc489, 5289), (0xc48a, 5290), (0xc48b, 5291),
+    (0xc48c, 5292), (0xc48d, 5293), (0xc48e, 5294), (0xc48f, 5295), (0xc490, 5296), (0xc491, 5297), (0xc492, 5298), (0xc493, 5299),
+    (0xc494, 11698), (0xc495, 5300), (0xc496, 5301), (0xc497, 5302), (0xc498, 5303), (0xc499, 5304), (0xc49a, 5305), (0xc49b, 5306),
+    (0xc49c, 11699), (0xc49d, 5307), (0xc49e, 5308), (0xc49f, 5309), (0xc4a0, 5310), (0xc4a1, 5311), (0xc4a2, 5312), (0xc4a3, 5313),
+    (0xc4a4, 5314), (0xc4a5, 5315), (0xc4a6, 5316), (0xc4a7, 5317), (0xc4a8, 5318), (0xc4a9, 5319), (0xc4aa, 5320), (0xc4ab, 5321),
+    (0xc4ac, 5322), (0xc4ad, 5323), (0xc4ae, 5324), (0xc4af, 5325), (0xc4b0, 5326), (0xc4b1, 5327), (0xc4b2, 5328), (0xc4b3, 5329),
+    (0xc4b4, 5330), (0xc4b5, 5331), (0xc4b6, 5332), (0xc4b7, 5333), (0xc4b8, 11700), (0xc4b9, 5334), (0xc4ba, 5335), (0xc4bb, 5336),
+    (0xc4bc, 11701), (0xc4bd, 5337), (0xc4be, 5338), (0xc4bf, 5339), (0xc4c0, 5340), (0xc4c1, 5341), (0xc4c2, 5342), (0xc4c3, 5343),
+    (0xc4c4, 5344), (0xc4c5, 5345), (0xc4c6, 5352), (0xc4c7, 5353), (0xc4c8, 5354), (0xc4c9, 5355), (0xc4ca, 5356), (0xc4cb, 5357),
+    (0xc4cc, 5358), (0xc4cd, 5359), (0xc4ce, 5360), (0xc4cf, 5361), (0xc4d0, 5362), (0xc4d1, 5363), (0xc4d2, 5364), (0xc4d3, 5365),
+    (0xc4d4, 5366), (0xc4d5, 5367), (0xc4d6, 5368), (0xc4d7, 5369), (0xc4d8, 5370), (0xc4d9, 5371), (0xc4da, 5372), (0xc4db, 5373),
+    (0xc4dc, 5374), (0xc4dd, 5375), (0xc4de, 5376), (0xc4df, 5377), (0xc4e0, 5384), (0xc4e1, 5385), (0xc4e2, 5386), (0xc4e3, 5387),
+    (0xc4e4, 5388), (0xc4e5, 5389), (0xc4e6, 5390), (0xc4e7, 5391), (0xc4e8, 5392), (0xc4e9, 11702), (0xc4ea, 5393), (0xc4eb, 5394),
+    (0xc4ec, 5395), (0xc4ed, 5396), (0xc4ee, 5397), (0xc4ef, 5398), (0xc4f0, 11703), (0xc4f1, 11704), (0xc4f2, 5399), (0xc4f3, 5400),
+    (0xc4f4, 11705), (0xc4f5, 5401), (0xc4f6, 5402), (0xc4f7, 5403), (0xc4f8, 11706), (0xc4f9, 5404), (0xc4fa, 11707), (0xc4fb, 5405),
+    (0xc4fc, 5406), (0xc4fd, 5407), (0xc4fe, 5408), (0xc4ff, 11708), (0xc500, 11709), (0xc501, 11710), (0xc502, 5409), (0xc503, 5410),
+    (0xc504, 5411), (0xc505, 5412), (0xc506, 5413), (0xc507, 5414), (0xc508, 5415), (0xc509, 5416), (0xc50a, 5417), (0xc50b, 5418),
+    (0xc50c, 11711), (0xc50d, 5419), (0xc50e, 5420), (0xc50f, 5421), (0xc510, 11712), (0xc511, 5422), (0xc512, 5423), (0xc513, 5424),
+    (0xc514, 11713), (0xc515, 5425), (0xc516, 5426), (0xc517, 5427), (0xc518, 5428), (0xc519, 5429), (0xc51a, 5430), (0xc51b, 5431),
+    (0xc51c, 11714), (0xc51d, 5432), (0xc51e, 5433), (0xc51f, 5434), (0xc520, 5435), (0xc521, 5436), (0xc522, 5437), (0xc523, 5438),
+    (0xc524, 5439), (0xc525, 5440), (0xc526, 5441), (0xc527, 5442), (0xc528, 11715), (0xc529, 11716), (0xc52a, 5443), (0xc52b, 5444),
+    (0xc52c, 11717), (0xc52d, 5445), (0xc52e, 5446), (0xc52f, 5447), (0xc530, 11718), (0xc531, 5448), (0xc532, 5449), (0xc533, 5450),
+    (0xc534, 5451), (0xc535, 5452), (0xc536, 5453), (0xc537, 5454), (0xc538, 11719), (0xc539, 11720), (0xc53a, 5455), (0xc53b, 11721),
+    (0xc53c, 5456), (0xc53d, 11722), (0xc53e, 5457), (0xc53f, 5458), (0xc540, 5459), (0xc541, 5460), (0xc542, 5461), (0xc543, 5462),
+    (0xc544, 11723), (0xc545, 11724), (0xc546, 5463), (0xc547, 5464), (0xc548, 11725), (0xc549, 11726), (0xc54a, 11727), (0xc54b, 5465),
+    (0xc54c, 11728), (0xc54d, 11729), (0xc54e, 11730), (0xc54f, 5466), (0xc550, 5467), (0xc551, 5468), (0xc552, 5469), (0xc553, 11731),
+    (0xc554, 11732), (0xc555, 11733), (0xc556, 5470), (0xc557, 11734), (0xc558, 11735), (0xc559, 11736), (0xc55a, 5471), (0xc55b, 5472),
+    (0xc55c, 5473), (0xc55d, 11737), (0xc55e, 11738), (0xc55f, 5474), (0xc560, 11739), (0xc561, 11740), (0xc562, 5475), (0xc563, 5476),
+    (0xc564, 11741), (0xc565, 5477), (0xc566, 5478), (0xc567, 5479), (0xc568, 11742), (0xc569, 5480), (0xc56a, 5481), (0xc56b, 5482),
+    (0xc56c, 5483), (0xc56d, 5484), (0xc56e, 5485), (0xc56f, 5486), (0xc570, 11743), (0xc571, 11744), (0xc572, 5487), (0xc573, 11745),
+    (0xc574, 11746), (0xc575, 11747), (0xc576, 5488), (0xc577, 5489), (0xc578, 5490), (0xc579, 5491), (0xc57a, 5492), (0xc57b, 5493),
+    (0xc57c, 11748), (0xc57d, 11749), (0xc57e, 5494), (0xc57f, 5495), (0xc580, 11750), (0xc581, 5496), (0xc582, 5497), (0xc583, 5498),
+    (0xc584, 11751), (0xc585, 5499), (0xc586, 5500), (0xc587, 11752), (0xc588, 5501), (0xc589, 5502), (0xc58a, 5503), (0xc58b, 5504),
+    (0xc58c, 11753), (0xc58d, 11754), (0xc58e, 5505), (0xc58f, 11755), (0xc590, 5506), (0xc591, 11756), (0xc592, 5507), (0xc593, 5508),
+    (0xc594, 5509), (0xc595, 11757), (0xc596, 5510), (0xc597, 11758), (0xc598, 11759), (0xc599, 5511), (0xc59a, 5512), (0xc59b, 5513),
+    (0xc59c, 11760), (0xc59d, 5514), (0xc59e, 5515), (0xc59f, 5516), (0xc5a0, 11761), (0xc5a1, 5517), (0xc5a2, 5518), (0xc5a3, 5519),
+    (0xc5a4, 5520), (0xc5a5, 5521), (0xc5a6, 5522), (0xc5a7, 5523), (0xc5a8, 5524), (0xc5a9, 11762), (0xc5aa, 5525), (0xc5ab, 5526),
+    (0xc5ac, 5527), (0xc5ad, 5528), (0xc5ae, 5529), (0xc5af, 5530), (0xc5b0, 5531), (0xc5b1, 5532), (0xc5b2, 5533), (0xc5b3, 5534),
+    (0xc5b4, 11763), (0xc5b5, 11764), (0xc5b6, 5535), (0xc5b7, 5542), (0xc5b8, 11765), (0xc5b9, 11766), (0xc5ba, 5543), (0xc5bb, 11767),
+    (0xc5bc, 11768), (0xc5bd, 11769), (0xc5be, 11770), (0xc5bf, 5544), (0xc5c0, 5545), (0xc5c1, 5546), (0xc5c2, 5547), (0xc5c3, 5548),
+    (0xc5c4, 11771), (0xc5c5, 11772), (0xc5c6, 11773), (0xc5c7, 11774), (0xc5c8, 11775), (0xc5c9, 11776), (0xc5ca, 11777), (0xc5cb, 5549),
+    (0xc5cc, 11778), (0xc5cd, 5550), (0xc5ce, 11779), (0xc5cf, 5551), (0xc5d0, 11876), (0xc5d1, 11877), (0xc5d2, 5552), (0xc5d3, 5553),
+    (0xc5d4, 11878), (0xc5d5, 5554), (0xc5d6, 5555), (0xc5d7, 5556), (0xc5d8, 11879), (0xc5d9, 5557), (0xc5da, 5558), (0xc5db, 5559),
+    (0xc5dc, 5560), (0xc5dd, 5561), (0xc5de, 5562), (0xc5df, 5563), (0xc5e0, 11880), (0xc5e1, 11881), (0xc5e2, 5564), (0xc5e3, 11882),
+    (0xc5e4, 5565), (0xc5e5, 11883), (0xc5e6, 5566), (0xc5e7, 5567), (0xc5e8, 5574), (0xc5e9, 5575), (0xc5ea, 5576), (0xc5eb, 5577),
+    (0xc5ec, 11884), (0xc5ed, 11885), (0xc5ee, 11886), (0xc5ef, 5578), (0xc5f0, 11887), (0xc5f1, 5579), (0xc5f2, 5580), (0xc5f3, 5581),
+    (0xc5f4, 11888), (0xc5f5, 5582), (0xc5f6, 11889), (0xc5f7, 11890), (0xc5f8, 5583), (0xc5f9, 5584), (0xc5fa, 5585), (0xc5fb, 5586),
+    (0xc5fc, 11891), (0xc5fd, 11892), (0xc5fe, 11893), (0xc5ff, 11894), (0xc600, 11895), (0xc601, 11896), (0xc602, 5587), (0xc603, 5588),
+    (0xc604, 5589), (0xc605, 11897), (0xc606, 11898), (0xc607, 11899), (0xc608, 11900), (0xc609, 5590), (0xc60a, 5591), (0xc60b, 5592),
+    (0xc60c, 11901), (0xc60d, 5593), (0xc60e, 5594), (0xc60f, 5595), (0xc610, 11902), (0xc611, 5596), (0xc612, 5597), (0xc613, 5598),
+    (0xc614, 5599), (0xc615, 5600), (0xc616, 5601), (0xc617, 5602), (0xc618, 11903), (0xc619, 11904), (0xc61a, 5603), (0xc61b, 11905),
+    (0xc61c, 11906), (0xc61d, 5604), (0xc61e, 5605), (0xc61f, 5606), (0xc620, 5607), (0xc621, 5608), (0xc622, 5609), (0xc623, 5610),
+    (0xc624, 11907), (0xc625, 11908), (0xc626, 5611), (0xc627, 5612), (0xc628, 11909), (0xc629, 5613), (0xc62a, 5614), (0xc62b, 5615),
+    (0xc62c, 11910), (0xc62d, 11911), (0xc62e, 11912), (0xc62f, 5616), (0xc630, 11913), (0xc631, 5617), (0xc632, 5618), (0xc633, 11914),
+    (0xc634, 11915), (0xc635, 11916), (0xc636, 5619), (0xc637, 11917), (0xc638, 5620), (0xc639, 11918), (0xc63a, 5621), (0xc63b, 11919),
+    (0xc63c, 5622), (0xc63d, 5623), (0xc63e, 5624), (0xc63f, 5625), (0xc640, 11920), (0xc641, 11921), (0xc642, 5626), (0xc643, 5627),
+    (0xc644, 11922), (0xc645, 5628), (0xc646, 5629), (0xc647, 5630), (0xc648, 11923), (0xc649, 5631), (0xc64a, 5632), (0xc64b, 5633),
+    (0xc64c, 5634), (0xc64d, 5635), (0xc64e, 5636), (0xc64f, 5637), (0xc650, 11924), (0xc651, 11925), (0xc652, 5638), (0xc653, 11926),
+    (0xc654, 11927), (0xc655, 11928), (0xc656, 5639), (0xc657, 5640), (0xc658, 5641), (0xc659, 5642), (0xc65a, 5643), (0xc65b, 5644),
+    (0xc65c, 11929), (0xc65d, 11930), (0xc65e, 5645), (0xc65f, 5646), (0xc660, 11931), (0xc661, 5647), (0xc662, 5648), (0xc663, 5649),
+    (0xc664, 5650), (0xc665, 5651), (0xc666, 5652), (0xc667, 5653), (0xc668, 5654), (0xc669, 5655), (0xc66a, 5656), (0xc66b, 5657),
+    (0xc66c, 11932), (0xc66d, 5658), (0xc66e, 5659), (0xc66f, 11933), (0xc670, 5660), (0xc671, 11934), (0xc672, 5661), (0xc673, 5662),
+    (0xc674, 5663), (0xc675, 5664), (0xc676, 5665), (0xc677, 5666), (0xc678, 11935), (0xc679, 11936), (0xc67a, 5667), (0xc67b, 5668),
+    (0xc67c, 11937), (0xc67d, 5669), (0xc67e, 5670), (0xc67f, 5671), (0xc680, 11938), (0xc681, 5672), (0xc682, 5673), (0xc683, 5674),
+    (0xc684, 5675), (0xc685, 5676), (0xc686, 5677), (0xc687, 5678), (0xc688, 11939), (0xc689, 11940), (0xc68a, 5679), (0xc68b, 11941),
+    (0xc68c, 5680), (0xc68d, 11942), (0xc68e, 5681), (0xc68f, 5682), (0xc690, 5683), (0xc691, 5684), (0xc692, 5685), (0xc693, 5686),
+    (0xc694, 11943), (0xc695, 11944), (0xc696, 5687), (0xc697, 5688), (0xc698, 11945), (0xc699, 5689), (0xc69a, 5690), (0xc69b, 5691),
+    (0xc69c, 11946), (0xc69d, 5692), (0xc69e, 5693), (0xc69f, 5694), (0xc6a0, 5695), (0xc6a1, 5696), (0xc6a2, 5697), (0xc6a3, 5698),
+    (0xc6a4, 11947), (0xc6a5, 11948), (0xc6a6, 5699), (0xc6a7, 11949), (0xc6a8, 5700), (0xc6a9, 11950), (0xc6aa, 5701), (0xc6ab, 5702),
+    (0xc6ac, 5703), (0xc6ad, 5704), (0xc6ae, 5705), (0xc6af, 5706), (0xc6b0, 11951), (0xc6b1, 11952), (0xc6b2, 5707), (0xc6b3, 5708),
+    (0xc6b4, 11953), (0xc6b5, 5709), (0xc6b6, 5710), (0xc6b7, 5711), (0xc6b8, 11954), (0xc6b9, 11955), (0xc6ba, 11956), (0xc6bb, 5712),
+    (0xc6bc, 5713), (0xc6bd, 5714), (0xc6be, 5715), (0xc6bf, 5716), (0xc6c0, 11957), (0xc6c1, 11958), (0xc6c2, 5717), (0xc6c3, 11959),
+    (0xc6c4, 5718), (0xc6c5, 11960), (0xc6c6, 5719), (0xc6c7, 5720), (0xc6c8, 5721), (0xc6c9, 5722), (0xc6ca, 5723), (0xc6cb, 5724),
+    (0xc6cc, 11961), (0xc6cd, 11962), (0xc6ce, 5725), (0xc6cf, 5732), (0xc6d0, 11963), (0xc6d1, 5733), (0xc6d2, 5734), (0xc6d3, 5735),
+    (0xc6d4, 11964), (0xc6d5, 5736), (0xc6d6, 5737), (0xc6d7, 5738), (0xc6d8, 5739), (0xc6d9, 5740), (0xc6da, 5741), (0xc6db, 5742),
+    (0xc6dc, 11965), (0xc6dd, 11966), (0xc6de, 5743), (0xc6df, 5744), (0xc6e0, 11967), (0xc6e1, 11968), (0xc6e2, 5745), (0xc6e3, 5746),
+    (0xc6e4, 5747), (0xc6e5, 5748), (0xc6e6, 5749), (0xc6e7, 5750), (0xc6e8, 11969), (0xc6e9, 12066), (0xc6ea, 5751), (0xc6eb, 5752),
+    (0xc6ec, 12067), (0xc6ed, 5753), (0xc6ee, 5754), (0xc6ef, 5755), (0xc6f0, 12068), (0xc6f1, 5756), (0xc6f2, 5757), (0xc6f3, 5764),
+    (0xc6f4, 5765), (0xc6f5, 5766), (0xc6f6, 5767), (0xc6f7, 5768), (0xc6f8, 12069), (0xc6f9, 12070), (0xc6fa, 5769), (0xc6fb, 5770),
+    (0xc6fc, 5771), (0xc6fd, 12071), (0xc6fe, 5772), (0xc6ff, 5773), (0xc700, 5774), (0xc701, 5775), (0xc702, 5776), (0xc703, 5777),
+    (0xc704, 12072), (0xc705, 12073), (0xc706, 5778), (0xc707, 5779), (0xc708, 12074), (0xc709, 5780), (0xc70a, 5781), (0xc70b, 5782),
+    (0xc70c, 12075), (0xc70d, 5783), (0xc70e, 5784), (0xc70f, 5785), (0xc710, 5786), (0xc711, 5787), (0xc712, 5788), (0xc713, 5789),
+    (0xc714, 12076), (0xc715, 12077), (0xc716, 5790), (0xc717, 12078), (0xc718, 5791), (0xc719, 12079), (0xc71a, 5792), (0xc71b, 5793),
+    (0xc71c, 5794), (0xc71d, 5795), (0xc71e, 5796), (0xc71f, 5797), (0xc720, 12080), (0xc721, 12081), (0xc722, 5798), (0xc723, 5799),
+    (0xc724, 12082), (0xc725, 5800), (0xc726, 5801), (0xc727, 5802), (0xc728, 12083), (0xc729, 5803), (0xc72a, 5804), (0xc72b, 5805),
+    (0xc72c, 5806), (0xc72d, 5807), (0xc72e, 5808), (0xc72f, 5809), (0xc730, 12084), (0xc731, 12085), (0xc732, 5810), (0xc733, 12086),
+    (0xc734, 5811), (0xc735, 12087), (0xc736, 5812), (0xc737, 12088), (0xc738, 5813), (0xc739, 5814), (0xc73a, 5815), (0xc73b, 5816),
+    (0xc73c, 12089), (0xc73d, 12090), (0xc73e, 5817), (0xc73f, 5818), (0xc740, 12091), (0xc741, 5819), (0xc742, 5820), (0xc743, 5821),
+    (0xc744, 12092), (0xc745, 5822), (0xc746, 5823), (0xc747, 5824), (0xc748, 5825), (0xc749, 5826), (0xc74a, 12093), (0xc74b, 5827),
+    (0xc74c, 12094), (0xc74d, 12095), (0xc74e, 5828), (0xc74f, 12096), (0xc750, 5829), (0xc751, 12097), (0xc752, 12098), (0xc753, 12099),
+    (0xc754, 12100), (0xc755, 12101), (0xc756, 12102), (0xc757, 12103), (0xc758, 12104), (0xc759, 5830), (0xc75a, 5831), (0xc75b, 5832),
+    (0xc75c, 12105), (0xc75d, 5833), (0xc75e, 5834), (0xc75f, 5835), (0xc760, 12106), (0xc761, 5836), (0xc762, 5837), (0xc763, 5838),
+    (0xc764, 5839), (0xc765, 5840), (0xc766, 5841), (0xc767, 5842), (0xc768, 12107), (0xc769, 5843), (0xc76a, 5844), (0xc76b, 12108),
+    (0xc76c, 5845), (0xc76d, 5846), (0xc76e, 5847), (0xc76f, 5848), (0xc770, 5849), (0xc771, 5850), (0xc772, 5851), (0xc773, 5852),
+    (0xc774, 12109), (0xc775, 12110), (0xc776, 5853), (0xc777, 5854), (0xc778, 12111), (0xc779, 5855), (0xc77a, 5856), (0xc77b, 5857),
+    (0xc77c, 12112), (0xc77d, 12113), (0xc77e, 12114), (0xc77f, 5858), (0xc780, 5859), (0xc781, 5860), (0xc782, 5861), (0xc783, 12115),
+    (0xc784, 12116), (0xc785, 12117), (0xc786, 5862), (0xc787, 12118), (0xc788, 12119), (0xc789, 12120), (0xc78a, 12121), (0xc78b, 5863),
+    (0xc78c, 5864), (0xc78d, 5865), (0xc78e, 12122), (0xc78f, 5866), (0xc790, 12123), (0xc791, 12124), (0xc792, 5867), (0xc793, 5868),
+    (0xc794, 12125), (0xc795, 5869), (0xc796, 12126), (0xc797, 12127), (0xc798, 12128), (0xc799, 5870), (0xc79a, 12129), (0xc79b, 5871),
+    (0xc79c, 5872), (0xc79d, 5873), (0xc79e, 5874), (0xc79f, 5875), (0xc7a0, 12130), (0xc7a1, 12131), (0xc7a2, 5876), (0xc7a3, 12132),
+    (0xc7a4, 12133), (0xc7a5, 12134), (0xc7a6, 12135), (0xc7a7, 5877), (0xc7a8, 5878), (0xc7a9, 5879), (0xc7aa, 5880), (0xc7ab, 5881),
+    (0xc7ac, 12136), (0xc7ad, 12137), (0xc7ae, 5882), (0xc7af, 5883), (0xc7b0, 12138), (0xc7b1, 5884), (0xc7b2, 5885), (0xc7b3, 5886),
+    (0xc7b4, 12139), (0xc7b5, 5887), (0xc7b6, 5888), (0xc7b7, 5889), (0xc7b8, 5890), (0xc7b9, 5891), (0xc7ba, 5892), (0xc7bb, 5893),
+    (0xc7bc, 12140), (0xc7bd, 12141), (0xc7be, 5894), (0xc7bf, 12142), (0xc7c0, 12143), (0xc7c1, 12144), (0xc7c2, 5895), (0xc7c3, 5896),
+    (0xc7c4, 5897), (0xc7c5, 5898), (0xc7c6, 5899), (0xc7c7, 5900), (0xc7c8, 12145), (0xc7c9, 12146), (0xc7ca, 5901), (0xc7cb, 5902),
+    (0xc7cc, 12147), (0xc7cd, 5903), (0xc7ce, 12148), (0xc7cf, 5904), (0xc7d0, 12149), (0xc7d1, 5905), (0xc7d2, 5906), (0xc7d3, 5907),
+    (0xc7d4, 5908), (0xc7d5, 5909), (0xc7d6, 5910), (0xc7d7, 5911), (0xc7d8, 12150), (0xc7d9, 5912), (0xc7da, 5913), (0xc7db, 5914),
+    (0xc7dc, 5915), (0xc7dd, 12151), (0xc7de, 5922), (0xc7df, 5923), (0xc7e0, 5924), (0xc7e1, 5925), (0xc7e2, 5926), (0xc7e3, 5927),
+    (0xc7e4, 12152), (0xc7e5, 5928), (0xc7e6, 5929), (0xc7e7, 5930), (0xc7e8, 12153), (0xc7e9, 5931), (0xc7ea, 5932), (0xc7eb, 5933),
+    (0xc7ec, 12154), (0xc7ed, 5934), (0xc7ee, 5935), (0xc7ef, 5936), (0xc7f0, 5937), (0xc7f1, 5938), (0xc7f2, 5939), (0xc7f3, 5940),
+    (0xc7f4, 5941), (0xc7f5, 5942), (0xc7f6, 5943), (0xc7f7, 5944), (0xc7f8, 5945), (0xc7f9, 5946), (0xc7fa, 5947), (0xc7fb, 5954),
+    (0xc7fc, 5955), (0xc7fd, 5956), (0xc7fe, 5957), (0xc7ff, 5958), (0xc800, 12155), (0xc801, 12156), (0xc802, 5959), (0xc803, 5960),
+    (0xc804, 12157), (0xc805, 5961), (0xc806, 5962), (0xc807, 5963), (0xc808, 12158), (0xc809, 5964), (0xc80a, 12159), (0xc80b, 5965),
+    (0xc80c, 5966), (0xc80d, 5967), (0xc80e, 5968), (0xc80f, 5969), (0xc810, 12256), (0xc811, 12257), (0xc812, 5970), (0xc813, 12258),
+    (0xc814, 5971), (0xc815, 12259), (0xc816, 12260), (0xc817, 5972), (0xc818, 5973), (0xc819, 5974), (0xc81a, 5975), (0xc81b, 5976),
+    (0xc81c, 12261), (0xc81d, 12262), (0xc81e, 5977), (0xc81f, 5978), (0xc820, 12263), (0xc821, 5979), (0xc822, 5980), (0xc823, 5981),
+    (0xc824, 12264), (0xc825, 5982), (0xc826, 5983), (0xc827, 5984), (0xc828, 5985), (0xc829, 5986), (0xc82a, 5987), (0xc82b, 5988),
+    (0xc82c, 12265), (0xc82d, 12266), (0xc82e, 5989), (0xc82f, 12267), (0xc830, 5990), (0xc831, 12268), (0xc832, 5991), (0xc833, 5992),
+    (0xc834, 5993), (0xc835, 5994), (0xc836, 5995), (0xc837, 5996), (0xc838, 12269), (0xc839, 5997), (0xc83a, 5998), (0xc83b, 5999),
+    (0xc83c, 12270), (0xc83d, 6000), (0xc83e, 6001), (0xc83f, 6002), (0xc840, 12271), (0xc841, 6003), (0xc842, 6004), (0xc843, 6005),
+    (0xc844, 6006), (0xc845, 6007), (0xc846, 6008), (0xc847, 6009), (0xc848, 12272), (0xc849, 12273), (0xc84a, 6010), (0xc84b, 6011),
+    (0xc84c, 12274), (0xc84d, 12275), (0xc84e, 6012), (0xc84f, 6013), (0xc850, 6014), (0xc851, 6015), (0xc852, 6016), (0xc853, 6017),
+    (0xc854, 12276), (0xc855, 6018), (0xc856, 6019), (0xc857, 6020), (0xc858, 6021), (0xc859, 6022), (0xc85a, 6023), (0xc85b, 6024),
+    (0xc85c, 6025), (0xc85d, 6026), (0xc85e, 6027), (0xc85f, 6028), (0xc860, 6029), (0xc861, 6030), (0xc862, 6031), (0xc863, 6032),
+    (0xc864, 6033), (0xc865, 6034), (0xc866, 6035), (0xc867, 6036), (0xc868, 6037), (0xc869, 6038), (0xc86a, 6039), (0xc86b, 6040),
+    (0xc86c, 6041), (0xc86d, 6042), (0xc86e, 6043), (0xc86f, 6044), (0xc870, 12277), (0xc871, 12278), (0xc872, 6045), (0xc873, 6046),
+    (0xc874, 12279), (0xc875, 6047), (0xc876, 6048), (0xc877, 6049), (0xc878, 12280), (0xc879, 6050), (0xc87a, 12281), (0xc87b, 6051),
+    (0xc87c, 6052), (0xc87d, 6053), (0xc87e, 6054), (0xc87f, 6055), (0xc880, 12282), (0xc881, 12283), (0xc882, 6056), (0xc883, 12284),
+    (0xc884, 6057), (0xc885, 12285), (0xc886, 12286), (0xc887, 12287), (0xc888, 6058), (0xc889, 6059), (0xc88a, 6060), (0xc88b, 12288),
+    (0xc88c, 12289), (0xc88d, 12290), (0xc88e, 6061), (0xc88f, 6062), (0xc890, 6063), (0xc891, 6064), (0xc892, 6065), (0xc893, 6066),
+    (0xc894, 12291), (0xc895, 6067), (0xc896, 6068), (0xc897, 6069), (0xc898, 6070), (0xc899, 6071), (0xc89a, 6072), (0xc89b, 6073),
+    (0xc89c, 6074), (0xc89d, 12292), (0xc89e, 6075), (0xc89f, 12293), (0xc8a0, 6076), (0xc8a1, 12294), (0xc8a2, 6077), (0xc8a3, 6078),
+    (0xc8a4, 6079), (0xc8a5, 6080), (0xc8a6, 6081), (0xc8a7, 6082), (0xc8a8, 12295), (0xc8a9, 6083), (0xc8aa, 6084), (0xc8ab, 6085),
+    (0xc8ac, 6086), (0xc8ad, 6087), (0xc8ae, 6088), (0xc8af, 6089), (0xc8b0, 6090), (0xc8b1, 6091), (0xc8b2, 6092), (0xc8b3, 6093),
+    (0xc8b4, 6094), (0xc8b5, 6095), (0xc8b6, 6096), (0xc8b7, 6097), (0xc8b8, 6098), (0xc8b9, 6099), (0xc8ba, 6100), (0xc8bb, 6101),
+    (0xc8bc, 12296), (0xc8bd, 12297), (0xc8be, 6102), (0xc8bf, 6103), (0xc8c0, 6104), (0xc8c1, 6105), (0xc8c2, 6112), (0xc8c3, 6113),
+    (0xc8c4, 12298), (0xc8c5, 6114), (0xc8c6, 6115), (0xc8c7, 6116), (0xc8c8, 12299), (0xc8c9, 6117), (0xc8ca, 6118), (0xc8cb, 6119),
+    (0xc8cc, 12300), (0xc8cd, 6120), (0xc8ce, 6121), (0xc8cf, 6122), (0xc8d0, 6123), (0xc8d1, 6124), (0xc8d2, 6125), (0xc8d3, 6126),
+    (0xc8d4, 12301), (0xc8d5, 12302), (0xc8d6, 6127), (0xc8d7, 12303), (0xc8d8, 6128), (0xc8d9, 12304), (0xc8da, 6129), (0xc8db, 6130),
+    (0xc8dc, 6131), (0xc8dd, 6132), (0xc8de, 6133), (0xc8df, 6134), (0xc8e0, 12305), (0xc8e1, 12306), (0xc8e2, 6135), (0xc8e3, 6136),
+    (0xc8e4, 12307), (0xc8e5, 6137), (0xc8e6, 6144), (0xc8e7, 6145), (0xc8e8, 6146), (0xc8e9, 6147), (0xc8ea, 6148), (0xc8eb, 6149),
+    (0xc8ec, 6150), (0xc8ed, 6151), (0xc8ee, 6152), (0xc8ef, 6153), (0xc8f0, 6154), (0xc8f1, 6155), (0xc8f2, 6156), (0xc8f3, 6157),
+    (0xc8f4, 6158), (0xc8f5, 12308), (0xc8f6, 6159), (0xc8f7, 6160), (0xc8f8, 6161), (0xc8f9, 6162), (0xc8fa, 6163), (0xc8fb, 6164),
+    (0xc8fc, 12309), (0xc8fd, 12310), (0xc8fe, 6165), (0xc8ff, 6166), (0xc900, 12311), (0xc901, 6167), (0xc902, 6168), (0xc903, 6169),
+    (0xc904, 12312), (0xc905, 12313), (0xc906, 12314), (0xc907, 6170), (0xc908, 6171), (0xc909, 6172), (0xc90a, 6173), (0xc90b, 6174),
+    (0xc90c, 12315), (0xc90d, 12316), (0xc90e, 6175), (0xc90f, 12317), (0xc910, 6270), (0xc911, 12318), (0xc912, 6271), (0xc913, 6272),
+    (0xc914, 6273), (0xc915, 6274), (0xc916, 6275), (0xc917, 6276), (0xc918, 12319), (0xc919, 6277), (0xc91a, 6278), (0xc91b, 6279),
+    (0xc91c, 6280), (0xc91d, 6281), (0xc91e, 6282), (0xc91f, 6283), (0xc920, 6284), (0xc921, 6285), (0xc922, 6286), (0xc923, 6287),
+    (0xc924, 6288), (0xc925, 6289), (0xc926, 6290), (0xc927, 6291), (0xc928, 6292), (0xc929, 6293), (0xc92a, 6294), (0xc92b, 6295),
+    (0xc92c, 12320), (0xc92d, 6302), (0xc92e, 6303), (0xc92f, 6304), (0xc930, 6305), (0xc931, 6306), (0xc932, 6307), (0xc933, 6308),
+    (0xc934, 12321), (0xc935, 6309), (0xc936, 6310), (0xc937, 6311), (0xc938, 6312), (0xc939, 6313), (0xc93a, 6314), (0xc93b, 6315),
+    (0xc93c, 6316), (0xc93d, 6317), (0xc93e, 6318), (0xc93f, 6319), (0xc940, 6320), (0xc941, 6321), (0xc942, 6322), (0xc943, 6323),
+    (0xc944, 6324), (0xc945, 6325), (0xc946, 6326), (0xc947, 6327), (0xc948, 6334), (0xc949, 6335), (0xc94a, 6336), (0xc94b, 6337),
+    (0xc94c, 6338), (0xc94d, 6339), (0xc94e, 6340), (0xc94f, 6341), (0xc950, 12322), (0xc951, 12323), (0xc952, 6342), (0xc953, 6343),
+    (0xc954, 12324), (0xc955, 6344), (0xc956, 6345), (0xc957, 6346), (0xc958, 12325), (0xc959, 6347), (0xc95a, 6348), (0xc95b, 6349),
+    (0xc95c, 6350), (0xc95d, 6351), (0xc95e, 6352), (0xc95f, 6353), (0xc960, 12326), (0xc961, 12327), (0xc962, 6354), (0xc963, 12328),
+    (0xc964, 6355), (0xc965, 6356), (0xc966, 6357), (0xc967, 6358), (0xc968, 6359), (0xc969, 6360), (0xc96a, 6361), (0xc96b, 6362),
+    (0xc96c, 12329), (0xc96d, 6363), (0xc96e, 6364), (0xc96f, 6365), (0xc970, 12330), (0xc971, 6460), (0xc972, 6461), (0xc973, 6462),
+    (0xc974, 12331), (0xc975, 6463), (0xc976, 6464), (0xc977, 6465), (0xc978, 6466), (0xc979, 6467), (0xc97a, 6468), (0xc97b, 6469),
+    (0xc97c, 12332), (0xc97d, 6470), (0xc97e, 6471), (0xc97f, 6472), (0xc980, 6473), (0xc981, 6474), (0xc982, 6475), (0xc983, 6476),
+    (0xc984, 6477), (0xc985, 6478), (0xc986, 6479), (0xc987, 6480), (0xc988, 12333), (0xc989, 12334), (0xc98a, 6481), (0xc98b, 6482),
+    (0xc98c, 12335), (0xc98d, 6483), (0xc98e, 6484), (0xc98f, 6485), (0xc990, 12336), (0xc991, 6492), (0xc992, 6493), (0xc993, 6494),
+    (0xc994, 6495), (0xc995, 6496), (0xc996, 6497), (0xc997, 6498), (0xc998, 12337), (0xc999, 12338), (0xc99a, 6499), (0xc99b, 12339),
+    (0xc99c, 6500), (0xc99d, 12340), (0xc99e, 6501), (0xc99f, 6502), (0xc9a0, 6503), (0xc9a1, 6504), (0xc9a2, 6505), (0xc9a3, 6506),
+    (0xc9a4, 6507), (0xc9a5, 6508), (0xc9a6, 6509), (0xc9a7, 6510), (0xc9a8, 6511), (0xc9a9, 6512), (0xc9aa, 6513), (0xc9ab, 6514),
+    (0xc9ac, 6515), (0xc9ad, 6516), (0xc9ae, 6517), (0xc9af, 6524), (0xc9b0, 6525), (0xc9b1, 6526), (0xc9b2, 6527), (0xc9b3, 6528),
+    (0xc9b4, 6529), (0xc9b5, 6530), (0xc9b6, 6531), (0xc9b7, 6532), (0xc9b8, 6533), (0xc9b9, 6534), (0xc9ba, 6535), (0xc9bb, 6536),
+    (0xc9bc, 6537), (0xc9bd, 6538), (0xc9be, 6539), (0xc9bf, 6540), (0xc9c0, 12341), (0xc9c1, 12342), (0xc9c2, 6541), (0xc9c3, 6542),
+    (0xc9c4, 12343), (0xc9c5, 6543), (0xc9c6, 6544), (0xc9c7, 12344), (0xc9c8, 12345), (0xc9c9, 6545), (0xc9ca, 12346), (0xc9cb, 6546),
+    (0xc9cc, 6547), (0xc9cd, 6548), (0xc9ce, 6549), (0xc9cf, 6550), (0xc9d0, 12347), (0xc9d1, 12348), (0xc9d2, 6551), (0xc9d3, 12349),
+    (0xc9d4, 6552), (0xc9d5, 12446), (0xc9d6, 12447), (0xc9d7, 6553), (0xc9d8, 6554), (0xc9d9, 12448), (0xc9da, 12449), (0xc9db, 6555),
+    (0xc9dc, 12450), (0xc9dd, 12451), (0xc9de, 6650), (0xc9df, 6651), (0xc9e0, 12452), (0xc9e1, 6652), (0xc9e2, 12453), (0xc9e3, 6653),
+    (0xc9e4, 12454), (0xc9e5, 6654), (0xc9e6, 6655), (0xc9e7, 12455), (0xc9e8, 6656), (0xc9e9, 6657), (0xc9ea, 6658), (0xc9eb, 6659),
+    (0xc9ec, 12456), (0xc9ed, 12457), (0xc9ee, 6660), (0xc9ef, 12458), (0xc9f0, 12459), (0xc9f1, 12460), (0xc9f2, 6661), (0xc9f3, 6662),
+    (0xc9f4, 6663), (0xc9f5, 6664), (0xc9f6, 6665), (0xc9f7, 6666), (0xc9f8, 12461), (0xc9f9, 12462), (0xc9fa, 6667), (0xc9fb, 6668),
+    (0xc9fc, 12463), (0xc9fd, 6669), (0xc9fe, 6670), (0xc9ff, 6671), (0xca00, 12464), (0xca01, 6672), (0xca02, 6673), (0xca03, 6674),
+    (0xca04, 6675), (0xca05, 6682), (0xca06, 6683), (0xca07, 6684), (0xca08, 12465), (0xca09, 12466), (0xca0a, 6685), (0xca0b, 12467),
+    (0xca0c, 12468), (0xca0d, 12469), (0xca0e, 6686), (0xca0f, 6687), (0xca10, 6688), (0xca11, 6689), (0xca12, 6690), (0xca13, 6691),
+    (0xca14, 12470), (0xca15, 6692), (0xca16, 6693), (0xca17, 6694), (0xca18, 12471), (0xca19, 6695), (0xca1a, 6696), (0xca1b, 6697),
+    (0xca1c, 6698), (0xca1d, 6699), (0xca1e, 6700), (0xca1f, 6701), (0xca20, 6702), (0xca21, 6703), (0xca22, 6704), (0xca23, 6705),
+    (0xca24, 6706), (0xca25, 6707), (0xca26, 6714), (0xca27, 6715), (0xca28, 6716), (0xca29, 12472), (0xca2a, 6717), (0xca2b, 6718),
+    (0xca2c, 6719), (0xca2d, 6720), (0xca2e, 6721), (0xca2f, 6722), (0xca30, 6723), (0xca31, 6724), (0xca32, 6725), (0xca33, 6726),
+    (0xca34, 6727), (0xca35, 6728), (0xca36, 6729), (0xca37, 6730), (0xca38, 6731), (0xca39, 6732), (0xca3a, 6733), (0xca3b, 6734),
+    (0xca3c, 6735), (0xca3d, 6736), (0xca3e, 6737), (0xca3f, 6738), (0xca40, 6739), (0xca41, 6740), (0xca42, 6741), (0xca43, 6742),
+    (0xca44, 6743), (0xca45, 6744), (0xca46, 6745), (0xca47, 6840), (0xca48, 6841), (0xca49, 6842), (0xca4a, 6843), (0xca4b, 6844),
+    (0xca4c, 12473), (0xca4d, 12474), (0xca4e, 6845), (0xca4f, 6846), (0xca50, 12475), (0xca51, 6847), (0xca52, 6848), (0xca53, 6849),
+    (0xca54, 12476), (0xca55, 6850), (0xca56, 6851), (0xca57, 6852), (0xca58, 6853), (0xca59, 6854), (0xca5a, 6855), (0xca5b, 6856),
+    (0xca5c, 12477), (0xca5d, 12478), (0xca5e, 6857), (0xca5f, 12479), (0xca60, 12480), (0xca61, 12481), (0xca62, 6858), (0xca63, 6859),
+    (0xca64, 6860), (0xca65, 6861), (0xca66, 6862), (0xca67, 6863), (0xca68, 12482), (0xca69, 6864), (0xca6a, 6865), (0xca6b, 6872),
+    (0xca6c, 6873), (0xca6d, 6874), (0xca6e, 6875), (0xca6f, 6876), (0xca70, 6877), (0xca71, 6878), (0xca72, 6879), (0xca73, 6880),
+    (0xca74, 6881), (0xca75, 6882), (0xca76, 6883), (0xca77, 6884), (0xca78, 6885), (0xca79, 6886), (0xca7a, 6887), (0xca7b, 6888),
+    (0xca7c, 6889), (0xca7d, 12483), (0xca7e, 6890), (0xca7f, 6891), (0xca80, 6892), (0xca81, 6893), (0xca82, 6894), (0xca83, 6895),
+    (0xca84, 12484), (0xca85, 6896), (0xca86, 6897), (0xca87, 6904), (0xca88, 6905), (0xca89, 6906), (0xca8a, 6907), (0xca8b, 6908),
+    (0xca8c, 6909), (0xca8d, 6910), (0xca8e, 6911), (0xca8f, 6912), (0xca90, 6913), (0xca91, 6914), (0xca92, 6915), (0xca93, 6916),
+    (0xca94, 6917), (0xca95, 6918), (0xca96, 6919), (0xca97, 6920), (0xca98, 12485), (0xca99, 6921), (0xca9a, 6922), (0xca9b, 6923),
+    (0xca9c, 6924), (0xca9d, 6925), (0xca9e, 6926), (0xca9f, 6927), (0xcaa0, 6928), (0xcaa1, 6929), (0xcaa2, 6930), (0xcaa3, 6931),
+    (0xcaa4, 6932), (0xcaa5, 6933), (0xcaa6, 6934), (0xcaa7, 6935), (0xcaa8, 7030), (0xcaa9, 7031), (0xcaaa, 7032), (0xcaab, 7033),
+    (0xcaac, 7034), (0xcaad, 7035), (0xcaae, 7036), (0xcaaf, 7037), (0xcab0, 7038), (0xcab1, 7039), (0xcab2, 7040), (0xcab3, 7041),
+    (0xcab4, 7042), (0xcab5, 7043), (0xcab6, 7044), (0xcab7, 7045), (0xcab8, 7046), (0xcab9, 7047), (0xcaba, 7048), (0xcabb, 7049),
+    (0xcabc, 12486), (0xcabd, 12487), (0xcabe, 7050), (0xcabf, 7051), (0xcac0, 12488), (0xcac1, 7052), (0xcac2, 7053), (0xcac3, 7054),
+    (0xcac4, 12489), (0xcac5, 7055), (0xcac6, 7062), (0xcac7, 7063), (0xcac8, 7064), (0xcac9, 7065), (0xcaca, 7066), (0xcacb, 7067),
+    (0xcacc, 12490), (0xcacd, 12491), (0xcace, 7068), (0xcacf, 12492), (0xcad0, 7069), (0xcad1, 12493), (0xcad2, 7070), (0xcad3, 12494),
+    (0xcad4, 7071), (0xcad5, 7072), (0xcad6, 7073), (0xcad7, 7074), (0xcad8, 12495), (0xcad9, 12496), (0xcada, 7075), (0xcadb, 7076),
+    (0xcadc, 7077), (0xcadd, 7078), (0xcade, 7079), (0xcadf, 7080), (0xcae0, 12497), (0xcae1, 7081), (0xcae2, 7082), (0xcae3, 7083),
+    (0xcae4, 7084), (0xcae5, 7085), (0xcae6, 7086), (0xcae7, 7087), (0xcae8, 7094), (0xcae9, 7095), (0xcaea, 7096), (0xcaeb, 7097),
+    (0xcaec, 12498), (0xcaed, 7098), (0xcaee, 7099), (0xcaef, 7100), (0xcaf0, 7101), (0xcaf1, 7102), (0xcaf2, 7103), (0xcaf3, 7104),
+    (0xcaf4, 12499), (0xcaf5, 7105), (0xcaf6, 7106), (0xcaf7, 7107), (0xcaf8, 7108), (0xcaf9, 7109), (0xcafa, 7110), (0xcafb, 7111),
+    (0xcafc, 7112), (0xcafd, 7113), (0xcafe, 7114), (0xcaff, 7115), (0xcb00, 7116), (0xcb01, 7117), (0xcb02, 7118), (0xcb03, 7119),
+    (0xcb04, 7120), (0xcb05, 7121), (0xcb06, 7122), (0xcb07, 7123), (0xcb08, 12500), (0xcb09, 7124), (0xcb0a, 7125), (0xcb0b, 7220),
+    (0xcb0c, 7221), (0xcb0d, 7222), (0xcb0e, 7223), (0xcb0f, 7224), (0xcb10, 12501), (0xcb11, 7225), (0xcb12, 7226), (0xcb13, 7227),
+    (0xcb14, 12502), (0xcb15, 7228), (0xcb16, 7229), (0xcb17, 7230), (0xcb18, 12503), (0xcb19, 7231), (0xcb1a, 7232), (0xcb1b, 7233),
+    (0xcb1c, 7234), (0xcb1d, 7235), (0xcb1e, 7236), (0xcb1f, 7237), (0xcb20, 12504), (0xcb21, 12505), (0xcb22, 7238), (0xcb23, 7239),
+    (0xcb24, 7240), (0xcb25, 7241), (0xcb26, 7242), (0xcb27, 7243), (0xcb28, 7244), (0xcb29, 7245), (0xcb2a, 7252), (0xcb2b, 7253),
+    (0xcb2c, 7254), (0xcb2d, 7255), (0xcb2e, 7256), (0xcb2f, 7257), (0xcb30, 7258), (0xcb31, 7259), (0xcb32, 7260), (0xcb33, 7261),
+    (0xcb34, 7262), (0xcb35, 7263), (0xcb36, 7264), (0xcb37, 7265), (0xcb38, 7266), (0xcb39, 7267), (0xcb3a, 7268), (0xcb3b, 7269),
+    (0xcb3c, 7270), (0xcb3d, 7271), (0xcb3e, 7272), (0xcb3f, 7273), (0xcb40, 7274), (0xcb41, 12506), (0xcb42, 7275), (0xcb43, 7276),
+    (0xcb44, 7277), (0xcb45, 7284), (0xcb46, 7285), (0xcb47, 7286), (0xcb48, 12507), (0xcb49, 12508), (0xcb4a, 7287), (0xcb4b, 7288),
+    (0xcb4c, 12509), (0xcb4d, 7289), (0xcb4e, 7290), (0xcb4f, 7291), (0xcb50, 12510), (0xcb51, 7292), (0xcb52, 7293), (0xcb53, 7294),
+    (0xcb54, 7295), (0xcb55, 7296), (0xcb56, 7297), (0xcb57, 7298), (0xcb58, 12511), (0xcb59, 12512), (0xcb5a, 7299), (0xcb5b, 7300),
+    (0xcb5c, 7301), (0xcb5d, 12513), (0xcb5e, 7302), (0xcb5f, 7303), (0xcb60, 7304), (0xcb61, 7305), (0xcb62, 7306), (0xcb63, 7307),
+    (0xcb64, 12514), (0xcb65, 7308), (0xcb66, 7309), (0xcb67, 7310), (0xcb68, 7311), (0xcb69, 7312), (0xcb6a, 7313), (0xcb6b, 7314),
+    (0xcb6c, 7315), (0xcb6d, 7410), (0xcb6e, 7411), (0xcb6f, 7412), (0xcb70, 7413), (0xcb71, 7414), (0xcb72, 7415), (0xcb73, 7416),
+    (0xcb74, 7417), (0xcb75, 7418), (0xcb76, 7419), (0xcb77, 7420), (0xcb78, 12515), (0xcb79, 12516), (0xcb7a, 7421), (0xcb7b, 7422),
+    (0xcb7c, 7423), (0xcb7d, 7424), (0xcb7e, 7425), (0xcb7f, 7426), (0xcb80, 7427), (0xcb81, 7428), (0xcb82, 7429), (0xcb83, 7430),
+    (0xcb84, 7431), (0xcb85, 7432), (0xcb86, 7433), (0xcb87, 7434), (0xcb88, 7435), (0xcb89, 7442), (0xcb8a, 7443), (0xcb8b, 7444),
+    (0xcb8c, 7445), (0xcb8d, 7446), (0xcb8e, 7447), (0xcb8f, 7448), (0xcb90, 7449), (0xcb91, 7450), (0xcb92, 7451), (0xcb93, 7452),
+    (0xcb94, 7453), (0xcb95, 7454), (0xcb96, 7455), (0xcb97, 7456), (0xcb98, 7457), (0xcb99, 7458), (0xcb9a, 7459), (0xcb9b, 7460),
+    (0xcb9c, 12517), (0xcb9d, 7461), (0xcb9e, 7462), (0xcb9f, 7463), (0xcba0, 7464), (0xcba1, 7465), (0xcba2, 7466), (0xcba3, 7467),
+    (0xcba4, 7474), (0xcba5, 7475), (0xcba6, 7476), (0xcba7, 7477), (0xcba8, 7478), (0xcba9, 7479), (0xcbaa, 7480), (0xcbab, 7481),
+    (0xcbac, 7482), (0xcbad, 7483), (0xcbae, 7484), (0xcbaf, 7485), (0xcbb0, 7486), (0xcbb1, 7487), (0xcbb2, 7488), (0xcbb3, 7489),
+    (0xcbb4, 7490), (0xcbb5, 7491), (0xcbb6, 7492), (0xcbb7, 7493), (0xcbb8, 12518), (0xcbb9, 7494), (0xcbba, 7495), (0xcbbb, 7496),
+    (0xcbbc, 7497), (0xcbbd, 7498), (0xcbbe, 7499), (0xcbbf, 7500), (0xcbc0, 7501), (0xcbc1, 7502), (0xcbc2, 7503), (0xcbc3, 7504),
+    (0xcbc4, 7505), (0xcbc5, 7600), (0xcbc6, 7601), (0xcbc7, 7602), (0xcbc8, 7603), (0xcbc9, 7604), (0xcbca, 7605), (0xcbcb, 7606),
+    (0xcbcc, 7607), (0xcbcd, 7608), (0xcbce, 7609), (0xcbcf, 7610), (0xcbd0, 7611), (0xcbd1, 7612), (0xcbd2, 7613), (0xcbd3, 7614),
+    (0xcbd4, 12519), (0xcbd5, 7615), (0xcbd6, 7616), (0xcbd7, 7617), (0xcbd8, 7618), (0xcbd9, 7619), (0xcbda, 7620), (0xcbdb, 7621),
+    (0xcbdc, 7622), (0xcbdd, 7623), (0xcbde, 7624), (0xcbdf, 7625), (0xcbe0, 7632), (0xcbe1, 7633), (0xcbe2, 7634), (0xcbe3, 7635),
+    (0xcbe4, 12520), (0xcbe5, 7636), (0xcbe6, 7637), (0xcbe7, 12521), (0xcbe8, 7638), (0xcbe9, 12522), (0xcbea, 7639), (0xcbeb, 7640),
+    (0xcbec, 7641), (0xcbed, 7642), (0xcbee, 7643), (0xcbef, 7644), (0xcbf0, 7645), (0xcbf1, 7646), (0xcbf2, 7647), (0xcbf3, 7648),
+    (0xcbf4, 7649), (0xcbf5, 7650), (0xcbf6, 7651), (0xcbf7, 7652), (0xcbf8, 7653), (0xcbf9, 7654), (0xcbfa, 7655), (0xcbfb, 7656),
+    (0xcbfc, 7657), (0xcbfd, 7664), (0xcbfe, 7665), (0xcbff, 7666), (0xcc00, 7667), (0xcc01, 7668), (0xcc02, 7669), (0xcc03, 7670),
+    (0xcc04, 7671), (0xcc05, 7672), (0xcc06, 7673), (0xcc07, 7674), (0xcc08, 7675), (0xcc09, 7676), (0xcc0a, 7677), (0xcc0b, 7678),
+    (0xcc0c, 12523), (0xcc0d, 12524), (0xcc0e, 7679), (0xcc0f, 7680), (0xcc10, 12525), (0xcc11, 7681), (0xcc12, 7682), (0xcc13, 7683),
+    (0xcc14, 12526), (0xcc15, 7684), (0xcc16, 7685), (0xcc17, 7686), (0xcc18, 7687), (0xcc19, 7688), (0xcc1a, 7689), (0xcc1b, 7690),
+    (0xcc1c, 12527), (0xcc1d, 12528), (0xcc1e, 7691), (0xcc1f, 7692), (0xcc20, 7693), (0xcc21, 12529), (0xcc22, 12530), (0xcc23, 7694),
+    (0xcc24, 7695), (0xcc25, 7790), (0xcc26, 7791), (0xcc27, 12531), (0xcc28, 12532), (0xcc29, 12533), (0xcc2a, 7792), (0xcc2b, 7793),
+    (0xcc2c, 12534), (0xcc2d, 7794), (0xcc2e, 12535), (0xcc2f, 7795), (0xcc30, 12536), (0xcc31, 7796), (0xcc32, 7797), (0xcc33, 7798),
+    (0xcc34, 7799), (0xcc35, 7800), (0xcc36, 7801), (0xcc37, 7802), (0xcc38, 12537), (0xcc39, 12538), (0xcc3a, 7803), (0xcc3b, 12539),
+    (0xcc3c, 12636), (0xcc3d, 12637), (0xcc3e, 12638), (0xcc3f, 7804), (0xcc40, 7805), (0xcc41, 7806), (0xcc42, 7807), (0xcc43, 7808),
+    (0xcc44, 12639), (0xcc45, 12640), (0xcc46, 7809), (0xcc47, 7810), (0xcc48, 12641), (0xcc49, 7811), (0xcc4a, 7812), (0xcc4b, 7813),
+    (0xcc4c, 12642), (0xcc4d, 7814), (0xcc4e, 7815), (0xcc4f, 7822), (0xcc50, 7823), (0xcc51, 7824), (0xcc52, 7825), (0xcc53, 7826),
+    (0xcc54, 12643), (0xcc55, 12644), (0xcc56, 7827), (0xcc57, 12645), (0xcc58, 12646), (0xcc59, 12647), (0xcc5a, 7828), (0xcc5b, 7829),
+    (0xcc5c, 7830), (0xcc5d, 7831), (0xcc5e, 7832), (0xcc5f, 7833), (0xcc60, 12648), (0xcc61, 7834), (0xcc62, 7835), (0xcc63, 7836),
+    (0xcc64, 12649), (0xcc65, 7837), (0xcc66, 12650), (0xcc67, 7838), (0xcc68, 12651), (0xcc69, 7839), (0xcc6a, 7840), (0xcc6b, 7841),
+    (0xcc6c, 7842), (0xcc6d, 7843), (0xcc6e, 7844), (0xcc6f, 7845), (0xcc70, 12652), (0xcc71, 7846), (0xcc72, 7847), (0xcc73, 7854),
+    (0xcc74, 7855), (0xcc75, 12653), (0xcc76, 7856), (0xcc77, 7857), (0xcc78, 7858), (0xcc79, 7859), (0xcc7a, 7860), (0xcc7b, 7861),
+    (0xcc7c, 7862), (0xcc7d, 7863), (0xcc7e, 7864), (0xcc7f, 7865), (0xcc80, 7866), (0xcc81, 7867), (0xcc82, 7868), (0xcc83, 7869),
+    (0xcc84, 7870), (0xcc85, 7871), (0xcc86, 7872), (0xcc87, 7873), (0xcc88, 7874), (0xcc89, 7875), (0xcc8a, 7876), (0xcc8b, 7877),
+    (0xcc8c, 7878), (0xcc8d, 7879), (0xcc8e, 7880), (0xcc8f, 7881), (0xcc90, 7882), (0xcc91, 7883), (0xcc92, 7884), (0xcc93, 7885),
+    (0xcc94, 7980), (0xcc95, 7981), (0xcc96, 7982), (0xcc97, 7983), (0xcc98, 12654), (0xcc99, 12655), (0xcc9a, 7984), (0xcc9b, 7985),
+    (0xcc9c, 12656), (0xcc9d, 7986), (0xcc9e, 7987), (0xcc9f, 7988), (0xcca0, 12657), (0xcca1, 7989), (0xcca2, 7990), (0xcca3, 7991),
+    (0xcca4, 7992), (0xcca5, 7993), (0xcca6, 7994), (0xcca7, 7995), (0xcca8, 12658), (0xcca9, 12659), (0xccaa, 7996), (0xccab, 12660),
+    (0xccac, 12661), (0xccad, 12662), (0xccae, 7997), (0xccaf, 7998), (0xccb0, 7999), (0xccb1, 8000), (0xccb2, 8001), (0xccb3, 8002),
+    (0xccb4, 12663), (0xccb5, 12664), (0xccb6, 8003), (0xccb7, 8004), (0xccb8, 12665), (0xccb9, 8005), (0xccba, 8012), (0xccbb, 8013),
+    (0xccbc, 12666), (0xccbd, 8014), (0xccbe, 8015), (0xccbf, 8016), (0xccc0, 8017), (0xccc1, 8018), (0xccc2, 8019), (0xccc3, 8020),
+    (0xccc4, 12667), (0xccc5, 12668), (0xccc6, 8021), (0xccc7, 12669), (0xccc8, 8022), (0xccc9, 12670), (0xccca, 8023), (0xcccb, 8024),
+    (0xcccc, 8025), (0xcccd, 8026), (0xccce, 8027), (0xcccf, 8028), (0xccd0, 12671), (0xccd1, 8029), (0xccd2, 8030), (0xccd3, 8031),
+    (0xccd4, 12672), (0xccd5, 8032), (0xccd6, 8033), (0xccd7, 8034), (0xccd8, 8035), (0xccd9, 8036), (0xccda, 8037), (0xccdb, 8044),
+    (0xccdc, 8045), (0xccdd, 8046), (0xccde, 8047), (0xccdf, 8048), (0xcce0, 8049), (0xcce1, 8050), (0xcce2, 8051), (0xcce3, 8052),
+    (0xcce4, 12673), (0xcce5, 8053), (0xcce6, 8054), (0xcce7, 8055), (0xcce8, 8056), (0xcce9, 8057), (0xccea, 8058), (0xcceb, 8059),
+    (0xccec, 12674), (0xcced, 8060), (0xccee, 8061), (0xccef, 8062), (0xccf0, 12675), (0xccf1, 8063), (0xccf2, 8064), (0xccf3, 8065),
+    (0xccf4, 8066), (0xccf5, 8067), (0xccf6, 8068), (0xccf7, 8069), (0xccf8, 8070), (0xccf9, 8071), (0xccfa, 8072), (0xccfb, 8073),
+    (0xccfc, 8074), (0xccfd, 8075), (0xccfe, 8170), (0xccff, 8171), (0xcd00, 8172), (0xcd01, 12676), (0xcd02, 8173), (0xcd03, 8174),
+    (0xcd04, 8175), (0xcd05, 8176), (0xcd06, 8177), (0xcd07, 8178), (0xcd08, 12677), (0xcd09, 12678), (0xcd0a, 8179), (0xcd0b, 8180),
+    (0xcd0c, 12679), (0xcd0d, 8181), (0xcd0e, 8182), (0xcd0f, 8183), (0xcd10, 12680), (0xcd11, 8184), (0xcd12, 8185), (0xcd13, 8186),
+    (0xcd14, 8187), (0xcd15, 8188), (0xcd16, 8189), (0xcd17, 8190), (0xcd18, 12681), (0xcd19, 12682), (0xcd1a, 8191), (0xcd1b, 12683),
+    (0xcd1c, 8192), (0xcd1d, 12684), (0xcd1e, 8193), (0xcd1f, 8194), (0xcd20, 8195), (0xcd21, 8202), (0xcd22, 8203), (0xcd23, 8204),
+    (0xcd24, 12685), (0xcd25, 8205), (0xcd26, 8206), (0xcd27, 8207), (0xcd28, 12686), (0xcd29, 8208), (0xcd2a, 8209), (0xcd2b, 8210),
+    (0xcd2c, 12687), (0xcd2d, 8211), (0xcd2e, 8212), (0xcd2f, 8213), (0xcd30, 8214), (0xcd31, 8215), (0xcd32, 8216), (0xcd33, 8217),
+    (0xcd34, 8218), (0xcd35, 8219), (0xcd36, 8220), (0xcd37, 8221), (0xcd38, 8222), (0xcd39, 12688), (0xcd3a, 8223), (0xcd3b, 8224),
+    (0xcd3c, 8225), (0xcd3d, 8226), (0xcd3e, 8227), (0xcd3f, 8234), (0xcd40, 8235), (0xcd41, 8236), (0xcd42, 8237), (0xcd43, 8238),
+    (0xcd44, 8239), (0xcd45, 8240), (0xcd46, 8241), (0xcd47, 8242), (0xcd48, 8243), (0xcd49, 8244), (0xcd4a, 8245), (0xcd4b, 8246),
+    (0xcd4c, 8247), (0xcd4d, 8248), (0xcd4e, 8249), (0xcd4f, 8250), (0xcd50, 8251), (0xcd51, 8252), (0xcd52, 8253), (0xcd53, 8254),
+    (0xcd54, 8255), (0xcd55, 8256), (0xcd56, 8257), (0xcd57, 8258), (0xcd58, 8259), (0xcd59, 8260), (0xcd5a, 8261), (0xcd5b, 8262),
+    (0xcd5c, 12689), (0xcd5d, 8263), (0xcd5e, 8264), (0xcd5f, 8265), (0xcd60, 12690), (0xcd61, 8360), (0xcd62, 8361), (0xcd63, 8362),
+    (0xcd64, 12691), (0xcd65, 8363), (0xcd66, 8364), (0xcd67, 8365), (0xcd68, 8366), (0xcd69, 8367), (0xcd6a, 8368), (0xcd6b, 8369),
+    (0xcd6c, 12692), (0xcd6d, 12693), (0xcd6e, 8370), (0xcd6f, 12694), (0xcd70, 8371), (0xcd71, 12695), (0xcd72, 8372), (0xcd73, 8373),
+    (0xcd74, 8374), (0xcd75, 8375), (0xcd76, 8376), (0xcd77, 8377), (0xcd78, 12696), (0xcd79, 8378), (0xcd7a, 8379), (0xcd7b, 8380),
+    (0xcd7c, 8381), (0xcd7d, 8382), (0xcd7e, 8383), (0xcd7f, 8384), (0xcd80, 8385), (0xcd81, 8392), (0xcd82, 8393), (0xcd83, 8394),
+    (0xcd84, 8395), (0xcd85, 8396), (0xcd86, 8397), (0xcd87, 8398), (0xcd88, 12697), (0xcd89, 8399), (0xcd8a, 8400), (0xcd8b, 8401),
+    (0xcd8c, 8402), (0xcd8d, 8403), (0xcd8e, 8404), (0xcd8f, 8405), (0xcd90, 8406), (0xcd91, 8407), (0xcd92, 8408), (0xcd93, 8409),
+    (0xcd94, 12698), (0xcd95, 12699), (0xcd96, 8410), (0xcd97, 8411), (0xcd98, 12700), (0xcd99, 8412), (0xcd9a, 8413), (0xcd9b, 8414),
+    (0xcd9c, 12701), (0xcd9d, 8415), (0xcd9e, 8416), (0xcd9f, 8417), (0xcda0, 8424), (0xcda1, 8425), (0xcda2, 8426), (0xcda3, 8427),
+    (0xcda4, 12702), (0xcda5, 12703), (0xcda6, 8428), (0xcda7, 12704), (0xcda8, 8429), (0xcda9, 12705), (0xcdaa, 8430), (0xcdab, 8431),
+    (0xcdac, 8432), (0xcdad, 8433), (0xcdae, 8434), (0xcdaf, 8435), (0xcdb0, 12706), (0xcdb1, 8436), (0xcdb2, 8437), (0xcdb3, 8438),
+    (0xcdb4, 8439), (0xcdb5, 8440), (0xcdb6, 8441), (0xcdb7, 8442), (0xcdb8, 8443), (0xcdb9, 8444), (0xcdba, 8445), (0xcdbb, 8446),
+    (0xcdbc, 8447), (0xcdbd, 8448), (0xcdbe, 8449), (0xcdbf, 8450), (0xcdc0, 8451), (0xcdc1, 8452), (0xcdc2, 8453), (0xcdc3, 8454),
+    (0xcdc4, 12707), (0xcdc5, 8455), (0xcdc6, 8550), (0xcdc7, 8551), (0xcdc8, 8552), (0xcdc9, 8553), (0xcdca, 8554), (0xcdcb, 8555),
+    (0xcdcc, 12708), (0xcdcd, 8556), (0xcdce, 8557), (0xcdcf, 8558), (0xcdd0, 12709), (0xcdd1, 8559), (0xcdd2, 8560), (0xcdd3, 8561),
+    (0xcdd4, 8562), (0xcdd5, 8563), (0xcdd6, 8564), (0xcdd7, 8565), (0xcdd8, 8566), (0xcdd9, 8567), (0xcdda, 8568), (0xcddb, 8569),
+    (0xcddc, 8570), (0xcddd, 8571), (0xcdde, 8572), (0xcddf, 8573), (0xcde0, 8574), (0xcde1, 8575), (0xcde2, 8582), (0xcde3, 8583),
+    (0xcde4, 8584), (0xcde5, 8585), (0xcde6, 8586), (0xcde7, 8587), (0xcde8, 12710), (0xcde9, 8588), (0xcdea, 8589), (0xcdeb, 8590),
+    (0xcdec, 12711), (0xcded, 8591), (0xcdee, 8592), (0xcdef, 8593), (0xcdf0, 12712), (0xcdf1, 8594), (0xcdf2, 8595), (0xcdf3, 8596),
+    (0xcdf4, 8597), (0xcdf5, 8598), (0xcdf6, 8599), (0xcdf7, 8600), (0xcdf8, 12713), (0xcdf9, 12714), (0xcdfa, 8601), (0xcdfb, 12715),
+    (0xcdfc, 8602), (0xcdfd, 12716), (0xcdfe, 8603), (0xcdff, 8604), (0xce00, 8605), (0xce01, 8606), (0xce02, 8607), (0xce03, 8614),
+    (0xce04, 12717), (0xce05, 8615), (0xce06, 8616), (0xce07, 8617), (0xce08, 12718), (0xce09, 8618), (0xce0a, 8619), (0xce0b, 8620),
+    (0xce0c, 12719), (0xce0d, 8621), (0xce0e, 8622), (0xce0f, 8623), (0xce10, 8624), (0xce11, 8625), (0xce12, 8626), (0xce13, 8627),
+    (0xce14, 12720), (0xce15, 8628), (0xce16, 8629), (0xce17, 8630), (0xce18, 8631), (0xce19, 12721), (0xce1a, 8632), (0xce1b, 8633),
+    (0xce1c, 8634), (0xce1d, 8635), (0xce1e, 8636), (0xce1f, 8637), (0xce20, 12722), (0xce21, 12723), (0xce22, 8638), (0xce23, 8639),
+    (0xce24, 12724), (0xce25, 8640), (0xce26, 8641), (0xce27, 8642), (0xce28, 12725), (0xce29, 8643), (0xce2a, 8644), (0xce2b, 8645),
+    (0xce2c, 8740), (0xce2d, 8741), (0xce2e, 8742), (0xce2f, 8743), (0xce30, 12726), (0xce31, 12727), (0xce32, 8744), (0xce33, 12728),
+    (0xce34, 8745), (0xce35, 12729), (0xce36, 8746), (0xce37, 8747), (0xce38, 8748), (0xce39, 8749), (0xce3a, 8750), (0xce3b, 8751),
+    (0xce3c, 8752), (0xce3d, 8753), (0xce3e, 8754), (0xce3f, 8755), (0xce40, 8756), (0xce41, 8757), (0xce42, 8758), (0xce43, 8759),
+    (0xce44, 8760), (0xce45, 8761), (0xce46, 8762), (0xce47, 8763), (0xce48, 8764), (0xce49, 8765), (0xce4a, 8772), (0xce4b, 8773),
+    (0xce4c, 8774), (0xce4d, 8775), (0xce4e, 8776), (0xce4f, 8777), (0xce50, 8778), (0xce51, 8779), (0xce52, 8780), (0xce53, 8781),
+    (0xce54, 8782), (0xce55, 8783), (0xce56, 8784), (0xce57, 8785), (0xce58, 12826), (0xce59, 12827), (0xce5a, 8786), (0xce5b, 8787),
+    (0xce5c, 12828), (0xce5d, 8788), (0xce5e, 8789), (0xce5f, 12829), (0xce60, 12830), (0xce61, 12831), (0xce62, 8790), (0xce63, 8791),
+    (0xce64, 8792), (0xce65, 8793), (0xce66, 8794), (0xce67, 8795), (0xce68, 12832), (0xce69, 12833), (0xce6a, 8796), (0xce6b, 12834),
+    (0xce6c, 8797), (0xce6d, 12835), (0xce6e, 8804), (0xce6f, 8805), (0xce70, 8806), (0xce71, 8807), (0xce72, 8808), (0xce73, 8809),
+    (0xce74, 12836), (0xce75, 12837), (0xce76, 8810), (0xce77, 8811), (0xce78, 12838), (0xce79, 8812), (0xce7a, 8813), (0xce7b, 8814),
+    (0xce7c, 12839), (0xce7d, 8815), (0xce7e, 8816), (0xce7f, 8817), (0xce80, 8818), (0xce81, 8819), (0xce82, 8820), (0xce83, 8821),
+    (0xce84, 12840), (0xce85, 12841), (0xce86, 8822), (0xce87, 12842), (0xce88, 8823), (0xce89, 12843), (0xce8a, 8824), (0xce8b, 8825),
+    (0xce8c, 8826), (0xce8d, 8827), (0xce8e, 8828), (0xce8f, 8829), (0xce90, 12844), (0xce91, 12845), (0xce92, 8830), (0xce93, 8831),
+    (0xce94, 12846), (0xce95, 8832), (0xce96, 8833), (0xce97, 8834), (0xce98, 12847), (0xce99, 8835), (0xce9a, 8930), (0xce9b, 8931),
+    (0xce9c, 8932), (0xce9d, 8933), (0xce9e, 8934), (0xce9f, 8935), (0xcea0, 12848), (0xcea1, 12849), (0xcea2, 8936), (0xcea3, 12850),
+    (0xcea4, 12851), (0xcea5, 12852), (0xcea6, 8937), (0xcea7, 8938), (0xcea8, 8939), (0xcea9, 8940), (0xceaa, 8941), (0xceab, 8942),
+    (0xceac, 12853), (0xcead, 12854), (0xceae, 8943), (0xceaf, 8944), (0xceb0, 8945), (0xceb1, 8946), (0xceb2, 8947), (0xceb3, 8948),
+    (0xceb4, 8949), (0xceb5, 8950), (0xceb6, 8951), (0xceb7, 8952), (0xceb8, 8953), (0xceb9, 8954), (0xceba, 8955), (0xcebb, 8962),
+    (0xcebc, 8963), (0xcebd, 8964), (0xcebe, 8965), (0xcebf, 8966), (0xcec0, 8967), (0xcec1, 12855), (0xcec2, 8968), (0xcec3, 8969),
+    (0xcec4, 8970), (0xcec5, 8971), (0xcec6, 8972), (0xcec7, 8973), (0xcec8, 8974), (0xcec9, 8975), (0xceca, 8976), (0xcecb, 8977),
+    (0xcecc, 8978), (0xcecd, 8979), (0xcece, 8980), (0xcecf, 8981), (0xced0, 8982), (0xced1, 8983), (0xced2, 8984), (0xced3, 8985),
+    (0xced4, 8986), (0xced5, 8987), (0xced6, 8994), (0xced7, 8995), (0xced8, 8996), (0xced9, 8997), (0xceda, 8998), (0xcedb, 8999),
+    (0xcedc, 9000), (0xcedd, 9001), (0xcede, 9002), (0xcedf, 9003), (0xcee0, 9004), (0xcee1, 9005), (0xcee2, 9006), (0xcee3, 9007),
+    (0xcee4, 12856), (0xcee5, 12857), (0xcee6, 9008), (0xcee7, 9009), (0xcee8, 12858), (0xcee9, 9010), (0xceea, 9011), (0xceeb, 12859),
+    (0xceec, 12860), (0xceed, 9012), (0xceee, 9013), (0xceef, 9014), (0xcef0, 9015), (0xcef1, 9016), (0xcef2, 9017), (0xcef3, 9018),
+    (0xcef4, 12861), (0xcef5, 12862), (0xcef6, 9019), (0xcef7, 12863), (0xcef8, 12864), (0xcef9, 12865), (0xcefa, 9020), (0xcefb, 9021),
+    (0xcefc, 9022), (0xcefd, 9023), (0xcefe, 9024), (0xceff, 9025), (0xcf00, 12866), (0xcf01, 12867), (0xcf02, 9120), (0xcf03, 9121),
+    (0xcf04, 12868), (0xcf05, 9122), (0xcf06, 9123), (0xcf07, 9124), (0xcf08, 12869), (0xcf09, 9125), (0xcf0a, 9126), (0xcf0b, 9127),
+    (0xcf0c, 9128), (0xcf0d, 9129), (0xcf0e, 9130), (0xcf0f, 9131), (0xcf10, 12870), (0xcf11, 12871), (0xcf12, 9132), (0xcf13, 12872),
+    (0xcf14, 9133), (0xcf15, 12873), (0xcf16, 9134), (0xcf17, 9135), (0xcf18, 9136), (0xcf19, 9137), (0xcf1a, 9138), (0xcf1b, 9139),
+    (0xcf1c, 12874), (0xcf1d, 9140), (0xcf1e, 9141), (0xcf1f, 9142), (0xcf20, 12875), (0xcf21, 9143), (0xcf22, 9144), (0xcf23, 9145),
+    (0xcf24, 12876), (0xcf25, 9152), (0xcf26, 9153), (0xcf27, 9154), (0xcf28, 9155), (0xcf29, 9156), (0xcf2a, 9157), (0xcf2b, 9158),
+    (0xcf2c, 12877), (0xcf2d, 12878), (0xcf2e, 9159), (0xcf2f, 12879), (0xcf30, 12880), (0xcf31, 12881), (0xcf32, 9160), (0xcf33, 9161),
+    (0xcf34, 9162), (0xcf35, 9163), (0xcf36, 9164), (0xcf37, 9165), (0xcf38, 12882), (0xcf39, 9166), (0xcf3a, 9167), (0xcf3b, 9168),
+    (0xcf3c, 9169), (0xcf3d, 9170), (0xcf3e, 9171), (0xcf3f, 9172), (0xcf40, 9173), (0xcf41, 9174), (0xcf42, 9175), (0xcf43, 9176),
+    (0xcf44, 9177), (0xcf45, 9184), (0xcf46, 9185), (0xcf47, 9186), (0xcf48, 9187), (0xcf49, 9188), (0xcf4a, 9189), (0xcf4b, 9190),
+    (0xcf4c, 9191), (0xcf4d, 9192), (0xcf4e, 9193), (0xcf4f, 9194), (0xcf50, 9195), (0xcf51, 9196), (0xcf52, 9197), (0xcf53, 9198),
+    (0xcf54, 12883), (0xcf55, 12884), (0xcf56, 9199), (0xcf57, 9200), (0xcf58, 12885), (0xcf59, 9201), (0xcf5a, 9202), (0xcf5b, 9203),
+    (0xcf5c, 12886), (0xcf5d, 9204), (0xcf5e, 9205), (0xcf5f, 9206), (0xcf60, 9207), (0xcf61, 9208), (0xcf62, 9209), (0xcf63, 9210),
+    (0xcf64, 12887), (0xcf65, 12888), (0xcf66, 9211), (0xcf67, 12889), (0xcf68, 9212), (0xcf69, 12890), (0xcf6a, 9213), (0xcf6b, 9214),
+    (0xcf6c, 9215), (0xcf6d, 9310), (0xcf6e, 9311), (0xcf6f, 9312), (0xcf70, 12891), (0xcf71, 12892), (0xcf72, 9313), (0xcf73, 9314),
+    (0xcf74, 12893), (0xcf75, 9315), (0xcf76, 9316), (0xcf77, 9317), (0xcf78, 12894), (0xcf79, 9318), (0xcf7a, 9319), (0xcf7b, 9320),
+    (0xcf7c, 9321), (0xcf7d, 9322), (0xcf7e, 9323), (0xcf7f, 9324), (0xcf80, 12895), (0xcf81, 9325), (0xcf82, 9326), (0xcf83, 9327),
+    (0xcf84, 9328), (0xcf85, 12896), (0xcf86, 9329), (0xcf87, 9330), (0xcf88, 9331), (0xcf89, 9332), (0xcf8a, 9333), (0xcf8b, 9334),
+    (0xcf8c, 12897), (0xcf8d, 9335), (0xcf8e, 9342), (0xcf8f, 9343), (0xcf90, 9344), (0xcf91, 9345), (0xcf92, 9346), (0xcf93, 9347),
+    (0xcf94, 9348), (0xcf95, 9349), (0xcf96, 9350), (0xcf97, 9351), (0xcf98, 9352), (0xcf99, 9353), (0xcf9a, 9354), (0xcf9b, 9355),
+    (0xcf9c, 9356), (0xcf9d, 9357), (0xcf9e, 9358), (0xcf9f, 9359), (0xcfa0, 9360), (0xcfa1, 12898), (0xcfa2, 9361), (0xcfa3, 9362),
+    (0xcfa4, 9363), (0xcfa5, 9364), (0xcfa6, 9365), (0xcfa7, 9366), (0xcfa8, 12899), (0xcfa9, 9367), (0xcfaa, 9374), (0xcfab, 9375),
+    (0xcfac, 9376), (0xcfad, 9377), (0xcfae, 9378), (0xcfaf, 9379), (0xcfb0, 12900), (0xcfb1, 9380), (0xcfb2, 9381), (0xcfb3, 9382),
+    (0xcfb4, 9383), (0xcfb5, 9384), (0xcfb6, 9385), (0xcfb7, 9386), (0xcfb8, 9387), (0xcfb9, 9388), (0xcfba, 9389), (0xcfbb, 9390),
+    (0xcfbc, 9391), (0xcfbd, 9392), (0xcfbe, 9393), (0xcfbf, 9394), (0xcfc0, 9395), (0xcfc1, 9396), (0xcfc2, 9397), (0xcfc3, 9398),
+    (0xcfc4, 12901), (0xcfc5, 9399), (0xcfc6, 9400), (0xcfc7, 9401), (0xcfc8, 9402), (0xcfc9, 9403), (0xcfca, 9404), (0xcfcb, 9405),
+    (0xcfcc, 9500), (0xcfcd, 9501), (0xcfce, 9502), (0xcfcf, 9503), (0xcfd0, 9504), (0xcfd1, 9505), (0xcfd2, 9506), (0xcfd3, 9507),
+    (0xcfd4, 9508), (0xcfd5, 9509), (0xcfd6, 9510), (0xcfd7, 9511), (0xcfd8, 9512), (0xcfd9, 9513), (0xcfda, 9514), (0xcfdb, 9515),
+    (0xcfdc, 9516), (0xcfdd, 9517), (0xcfde, 9518), (0xcfdf, 9519), (0xcfe0, 12902), (0xcfe1, 12903), (0xcfe2, 9520), (0xcfe3, 9521),
+    (0xcfe4, 12904), (0xcfe5, 9522), (0xcfe6, 9523), (0xcfe7, 9524), (0xcfe8, 12905), (0xcfe9, 9525), (0xcfea, 9532), (0xcfeb, 9533),
+    (0xcfec, 9534), (0xcfed, 9535), (0xcfee, 9536), (0xcfef, 9537), (0xcff0, 12906), (0xcff1, 12907), (0xcff2, 9538), (0xcff3, 12908),
+    (0xcff4, 9539), (0xcff5, 12909), (0xcff6, 9540), (0xcff7, 9541), (0xcff8, 9542), (0xcff9, 9543), (0xcffa, 9544), (0xcffb, 9545),
+    (0xcffc, 12910), (0xcffd, 9546), (0xcffe, 9547), (0xcfff, 9548), (0xd000, 12911), (0xd001, 9549), (0xd002, 9550), (0xd003, 9551),
+    (0xd004, 12912), (0xd005, 9552), (0xd006, 9553), (0xd007, 9554), (0xd008, 9555), (0xd009, 9556), (0xd00a, 9557), (0xd00b, 9564),
+    (0xd00c, 9565), (0xd00d, 9566), (0xd00e, 9567), (0xd00f, 9568), (0xd010, 9569), (0xd011, 12913), (0xd012, 9570), (0xd013, 9571),
+    (0xd014, 9572), (0xd015, 9573), (0xd016, 9574), (0xd017, 9575), (0xd018, 12914), (0xd019, 9576), (0xd01a, 9577), (0xd01b, 9578),
+    (0xd01c, 9579), (0xd01d, 9580), (0xd01e, 9581), (0xd01f, 9582), (0xd020, 9583), (0xd021, 9584), (0xd022, 9585), (0xd023, 9586),
+    (0xd024, 9587), (0xd025, 9588), (0xd026, 9589), (0xd027, 9590), (0xd028, 9591), (0xd029, 9592), (0xd02a, 9593), (0xd02b, 9594),
+    (0xd02c, 9595), (0xd02d, 12915), (0xd02e, 9690), (0xd02f, 9691), (0xd030, 9692), (0xd031, 9693), (0xd032, 9694), (0xd033, 9695),
+    (0xd034, 12916), (0xd035, 12917), (0xd036, 9696), (0xd037, 9697), (0xd038, 12918), (0xd039, 9698), (0xd03a, 9699), (0xd03b, 9700),
+    (0xd03c, 12919), (0xd03d, 9701), (0xd03e, 9702), (0xd03f, 9703), (0xd040, 9704), (0xd041, 9705), (0xd042, 9706), (0xd043, 9707),
+    (0xd044, 13016), (0xd045, 13017), (0xd046, 9708), (0xd047, 13018), (0xd048, 9709), (0xd049, 13019), (0xd04a, 9710), (0xd04b, 9711),
+    (0xd04c, 9712), (0xd04d, 9713), (0xd04e, 9714), (0xd04f, 9715), (0xd050, 13020), (0xd051, 9722), (0xd052, 9723), (0xd053, 9724),
+    (0xd054, 13021), (0xd055, 9725), (0xd056, 9726), (0xd057, 9727), (0xd058, 13022), (0xd059, 9728), (0xd05a, 9729), (0xd05b, 9730),
+    (0xd05c, 9731), (0xd05d, 9732), (0xd05e, 9733), (0xd05f, 9734), (0xd060, 13023), (0xd061, 9735), (0xd062, 9736), (0xd063, 9737),
+    (0xd064, 9738), (0xd065, 9739), (0xd066, 9740), (0xd067, 9741), (0xd068, 9742), (0xd069, 9743), (0xd06a, 9744), (0xd06b, 9745),
+    (0xd06c, 13024), (0xd06d, 13025), (0xd06e, 9746), (0xd06f, 9747), (0xd070, 13026), (0xd071, 9754), (0xd072, 9755), (0xd073, 9756),
+    (0xd074, 13027), (0xd075, 9757), (0xd076, 9758), (0xd077, 9759), (0xd078, 9760), (0xd079, 9761), (0xd07a, 9762), (0xd07b, 9763),
+    (0xd07c, 13028), (0xd07d, 13029), (0xd07e, 9764), (0xd07f, 9765), (0xd080, 9766), (0xd081, 13030), (0xd082, 9767), (0xd083, 9768),
+    (0xd084, 9769), (0xd085, 9770), (0xd086, 9771), (0xd087, 9772), (0xd088, 9773), (0xd089, 9774), (0xd08a, 9775), (0xd08b, 9776),
+    (0xd08c, 9777), (0xd08d, 9778), (0xd08e, 9779), (0xd08f, 9780), (0xd090, 9781), (0xd091, 9782), (0xd092, 9783), (0xd093, 9784),
+    (0xd094, 9785), (0xd095, 9880), (0xd096, 9881), (0xd097, 9882), (0xd098, 9883), (0xd099, 9884), (0xd09a, 9885), (0xd09b, 9886),
+    (0xd09c, 9887), (0xd09d, 9888), (0xd09e, 9889), (0xd09f, 9890), (0xd0a0, 9891), (0xd0a1, 9892), (0xd0a2, 9893), (0xd0a3, 9894),
+    (0xd0a4, 13031), (0xd0a5, 13032), (0xd0a6, 9895), (0xd0a7, 9896), (0xd0a8, 13033), (0xd0a9, 9897), (0xd0aa, 9898), (0xd0ab, 9899),
+    (0xd0ac, 13034), (0xd0ad, 9900), (0xd0ae, 9901), (0xd0af, 9902), (0xd0b0, 9903), (0xd0b1, 9904), (0xd0b2, 9905), (0xd0b3, 9912),
+    (0xd0b4, 13035), (0xd0b5, 13036), (0xd0b6, 9913), (0xd0b7, 13037), (0xd0b8, 9914), (0xd0b9, 13038), (0xd0ba, 9915), (0xd0bb, 9916),
+    (0xd0bc, 9917), (0xd0bd, 9918), (0xd0be, 9919), (0xd0bf, 9920), (0xd0c0, 13039), (0xd0c1, 13040), (0xd0c2, 9921), (0xd0c3, 9922),
+    (0xd0c4, 13041), (0xd0c5, 9923), (0xd0c6, 9924), (0xd0c7, 9925), (0xd0c8, 13042), (0xd0c9, 13043), (0xd0ca, 9926), (0xd0cb, 9927),
+    (0xd0cc, 9928), (0xd0cd, 9929), (0xd0ce, 9930), (0xd0cf, 9931), (0xd0d0, 13044), (0xd0d1, 13045), (0xd0d2, 9932), (0xd0d3, 13046),
+    (0xd0d4, 13047), (0xd0d5, 13048), (0xd0d6, 9933), (0xd0d7, 9934), (0xd0d8, 9935), (0xd0d9, 9936), (0xd0da, 9937), (0xd0db, 9944),
+    (0xd0dc, 13049), (0xd0dd, 13050), (0xd0de, 9945), (0xd0df, 9946), (0xd0e0, 13051), (0xd0e1, 9947), (0xd0e2, 9948), (0xd0e3, 9949),
+    (0xd0e4, 13052), (0xd0e5, 9950), (0xd0e6, 9951), (0xd0e7, 9952), (0xd0e8, 9953), (0xd0e9, 9954), (0xd0ea, 9955), (0xd0eb, 9956),
+    (0xd0ec, 13053), (0xd0ed, 13054), (0xd0ee, 9957), (0xd0ef, 13055), (0xd0f0, 13056), (0xd0f1, 13057), (0xd0f2, 9958), (0xd0f3, 9959),
+    (0xd0f4, 9960), (0xd0f5, 9961), (0xd0f6, 9962), (0xd0f7, 9963), (0xd0f8, 13058), (0xd0f9, 9964), (0xd0fa, 9965), (0xd0fb, 9966),
+    (0xd0fc, 9967), (0xd0fd, 9968), (0xd0fe, 9969), (0xd0ff, 9970), (0xd100, 9971), (0xd101, 9972), (0xd102, 9973), (0xd103, 9974),
+    (0xd104, 9975), (0xd105, 10070), (0xd106, 10071), (0xd107, 10072), (0xd108, 10073), (0xd109, 10074), (0xd10a, 10075), (0xd10b, 10076),
+    (0xd10c, 10077), (0xd10d, 13059), (0xd10e, 10078), (0xd10f, 10079), (0xd110, 10080), (0xd111, 10081), (0xd112, 10082), (0xd113, 10083),
+    (0xd114, 10084), (0xd115, 10085), (0xd116, 10086), (0xd117, 10087), (0xd118, 10088), (0xd119, 10089), (0xd11a, 10090), (0xd11b, 10091),
+    (0xd11c, 10092), (0xd11d, 10093), (0xd11e, 10094), (0xd11f, 10095), (0xd120, 10102), (0xd121, 10103), (0xd122, 10104), (0xd123, 10105),
+    (0xd124, 10106), (0xd125, 10107), (0xd126, 10108), (0xd127, 10109), (0xd128, 10110), (0xd129, 10111), (0xd12a, 10112), (0xd12b, 10113),
+    (0xd12c, 10114), (0xd12d, 10115), (0xd12e, 10116), (0xd12f, 10117), (0xd130, 13060), (0xd131, 13061), (0xd132, 10118), (0xd133, 10119),
+    (0xd134, 13062), (0xd135, 10120), (0xd136, 10121), (0xd137, 10122), (0xd138, 13063), (0xd139, 10123), (0xd13a, 13064), (0xd13b, 10124),
+    (0xd13c, 10125), (0xd13d, 10126), (0xd13e, 10127), (0xd13f, 10134), (0xd140, 13065), (0xd141, 13066), (0xd142, 10135), (0xd143, 13067),
+    (0xd144, 13068), (0xd145, 13069), (0xd146, 10136), (0xd147, 10137), (0xd148, 10138), (0xd149, 10139), (0xd14a, 10140), (0xd14b, 10141),
+    (0xd14c, 13070), (0xd14d, 13071), (0xd14e, 10142), (0xd14f, 10143), (0xd150, 13072), (0xd151, 10144), (0xd152, 10145), (0xd153, 10146),
+    (0xd154, 13073), (0xd155, 10147), (0xd156, 10148), (0xd157, 10149), (0xd158, 10150), (0xd159, 10151), (0xd15a, 10152), (0xd15b, 10153),
+    (0xd15c, 13074), (0xd15d, 13075), (0xd15e, 10154), (0xd15f, 13076), (0xd160, 10155), (0xd161, 13077), (0xd162, 10156), (0xd163, 10157),
+    (0xd164, 10158), (0xd165, 10159), (0xd166, 10160), (0xd167, 10161), (0xd168, 13078), (0xd169, 10162), (0xd16a, 10163), (0xd16b, 10164),
+    (0xd16c, 13079), (0xd16d, 10165), (0xd16e, 10260), (0xd16f, 10261), (0xd170, 10262), (0xd171, 10263), (0xd172, 10264), (0xd173, 10265),
+    (0xd174, 10266), (0xd175, 10267), (0xd176, 10268), (0xd177, 10269), (0xd178, 10270), (0xd179, 10271), (0xd17a, 10272), (0xd17b, 10273),
+    (0xd17c, 13080), (0xd17d, 10274), (0xd17e, 10275), (0xd17f, 10276), (0xd180, 10277), (0xd181, 10278), (0xd182, 10279), (0xd183, 10280),
+    (0xd184, 13081), (0xd185, 10281), (0xd186, 10282), (0xd187, 10283), (0xd188, 13082), (0xd189, 10284), (0xd18a, 10285), (0xd18b, 10292),
+    (0xd18c, 10293), (0xd18d, 10294), (0xd18e, 10295), (0xd18f, 10296), (0xd190, 10297), (0xd191, 10298), (0xd192, 10299), (0xd193, 10300),
+    (0xd194, 10301), (0xd195, 10302), (0xd196, 10303), (0xd197, 10304), (0xd198, 10305), (0xd199, 10306), (0xd19a, 10307), (0xd19b, 10308),
+    (0xd19c, 10309), (0xd19d, 10310), (0xd19e, 10311), (0xd19f, 10312), (0xd1a0, 13083), (0xd1a1, 13084), (0xd1a2, 10313), (0xd1a3, 10314),
+    (0xd1a4, 13085), (0xd1a5, 10315), (0xd1a6, 10316), (0xd1a7, 10317), (0xd1a8, 13086), (0xd1a9, 10324), (0xd1aa, 10325), (0xd1ab, 10326),
+    (0xd1ac, 10327), (0xd1ad, 10328), (0xd1ae, 10329), (0xd1af, 10330), (0xd1b0, 13087), (0xd1b1, 13088), (0xd1b2, 10331), (0xd1b3, 13089),
+    (0xd1b4, 10332), (0xd1b5, 13090), (0xd1b6, 10333), (0xd1b7, 10334), (0xd1b8, 10335), (0xd1b9, 10336), (0xd1ba, 13091), (0xd1bb, 10337),
+    (0xd1bc, 13092), (0xd1bd, 10338), (0xd1be, 10339), (0xd1bf, 10340), (0xd1c0, 13093), (0xd1c1, 10341), (0xd1c2, 10342), (0xd1c3, 10343),
+    (0xd1c4, 10344), (0xd1c5, 10345), (0xd1c6, 10346), (0xd1c7, 10347), (0xd1c8, 10348), (0xd1c9, 10349), (0xd1ca, 10350), (0xd1cb, 10351),
+    (0xd1cc, 10352), (0xd1cd, 10353), (0xd1ce, 10354), (0xd1cf, 10355), (0xd1d0, 10450), (0xd1d1, 10451), (0xd1d2, 10452), (0xd1d3, 10453),
+    (0xd1d4, 10454), (0xd1d5, 10455), (0xd1d6, 10456), (0xd1d7, 10457), (0xd1d8, 13094), (0xd1d9, 10458), (0xd1da, 10459), (0xd1db, 10460),
+    (0xd1dc, 10461), (0xd1dd, 10462), (0xd1de, 10463), (0xd1df, 10464), (0xd1e0, 10465), (0xd1e1, 10466), (0xd1e2, 10467), (0xd1e3, 10468),
+    (0xd1e4, 10469), (0xd1e5, 10470), (0xd1e6, 10471), (0xd1e7, 10472), (0xd1e8, 10473), (0xd1e9, 10474), (0xd1ea, 10475), (0xd1eb, 10482),
+    (0xd1ec, 10483), (0xd1ed, 10484), (0xd1ee, 10485), (0xd1ef, 10486), (0xd1f0, 10487), (0xd1f1, 10488), (0xd1f2, 10489), (0xd1f3, 10490),
+    (0xd1f4, 13095), (0xd1f5, 10491), (0xd1f6, 10492), (0xd1f7, 10493), (0xd1f8, 13096), (0xd1f9, 10494), (0xd1fa, 10495), (0xd1fb, 10496),
+    (0xd1fc, 10497), (0xd1fd, 10498), (0xd1fe, 10499), (0xd1ff, 10500), (0xd200, 10501), (0xd201, 10502), (0xd202, 10503), (0xd203, 10504),
+    (0xd204, 10505), (0xd205, 10506), (0xd206, 10507), (0xd207, 13097), (0xd208, 10514), (0xd209, 13098), (0xd20a, 10515), (0xd20b, 10516),
+    (0xd20c, 10517), (0xd20d, 10518), (0xd20e, 10519), (0xd20f, 10520), (0xd210, 13099), (0xd211, 10521), (0xd212, 10522), (0xd213, 10523),
+    (0xd214, 10524), (0xd215, 10525), (0xd216, 10526), (0xd217, 10527), (0xd218, 10528), (0xd219, 10529), (0xd21a, 10530), (0xd21b, 10531),
+    (0xd21c, 10532), (0xd21d, 10533), (0xd21e, 10534), (0xd21f, 10535), (0xd220, 10536), (0xd221, 10537), (0xd222, 10538), (0xd223, 10539),
+    (0xd224, 10540), (0xd225, 10541), (0xd226, 10542), (0xd227, 10543), (0xd228, 10544), (0xd229, 10545), (0xd22a, 10640), (0xd22b, 10641),
+    (0xd22c, 13100), (0xd22d, 13101), (0xd22e, 10642), (0xd22f, 10643), (0xd230, 13102), (0xd231, 10644), (0xd232, 10645), (0xd233, 10646),
+    (0xd234, 13103), (0xd235, 10647), (0xd236, 10648), (0xd237, 10649), (0xd238, 10650), (0xd239, 10651), (0xd23a, 10652), (0xd23b, 10653),
+    (0xd23c, 13104), (0xd23d, 13105), (0xd23e, 10654), (0xd23f, 13106), (0xd240, 10655), (0xd241, 13107), (0xd242, 10656), (0xd243, 10657),
+    (0xd244, 10658), (0xd245, 10659), (0xd246, 10660), (0xd247, 10661), (0xd248, 13108), (0xd249, 10662), (0xd24a, 10663), (0xd24b, 10664),
+    (0xd24c, 10665), (0xd24d, 10672), (0xd24e, 10673), (0xd24f, 10674), (0xd250, 10675), (0xd251, 10676), (0xd252, 10677), (0xd253, 10678),
+    (0xd254, 10679), (0xd255, 10680), (0xd256, 10681), (0xd257, 10682), (0xd258, 10683), (0xd259, 10684), (0xd25a, 10685), (0xd25b, 10686),
+    (0xd25c, 13109), (0xd25d, 10687), (0xd25e, 10688), (0xd25f, 10689), (0xd260, 10690), (0xd261, 10691), (0xd262, 10692), (0xd263, 10693),
+    (0xd264, 13206), (0xd265, 10694), (0xd266, 10695), (0xd267, 10696), (0xd268, 10697), (0xd269, 10704), (0xd26a, 10705), (0xd26b, 10706),
+    (0xd26c, 10707), (0xd26d, 10708), (0xd26e, 10709), (0xd26f, 10710), (0xd270, 10711), (0xd271, 10712), (0xd272, 10713), (0xd273, 10714),
+    (0xd274, 10715), (0xd275, 10716), (0xd276, 10717), (0xd277, 10718), (0xd278, 10719), (0xd279, 10720), (0xd27a, 10721), (0xd27b, 10722),
+    (0xd27c, 10723), (0xd27d, 10724), (0xd27e, 10725), (0xd27f, 10726), (0xd280, 13207), (0xd281, 13208), (0xd282, 10727), (0xd283, 10728),
+    (0xd284, 13209), (0xd285, 10729), (0xd286, 10730), (0xd287, 10731), (0xd288, 13210), (0xd289, 10732), (0xd28a, 10733), (0xd28b, 10734),
+    (0xd28c, 10735), (0xd28d, 10830), (0xd28e, 10831), (0xd28f, 10832), (0xd290, 13211), (0xd291, 13212), (0xd292, 10833), (0xd293, 10834),
+    (0xd294, 10835), (0xd295, 13213), (0xd296, 10836), (0xd297, 10837), (0xd298, 10838), (0xd299, 10839), (0xd29a, 10840), (0xd29b, 10841),
+    (0xd29c, 13214), (0xd29d, 10842), (0xd29e, 10843), (0xd29f, 10844), (0xd2a0, 13215), (0xd2a1, 10845), (0xd2a2, 10846), (0xd2a3, 10847),
+    (0xd2a4, 13216), (0xd2a5, 10848), (0xd2a6, 10849), (0xd2a7, 10850), (0xd2a8, 10851), (0xd2a9, 10852), (0xd2aa, 10853), (0xd2ab, 10854),
+    (0xd2ac, 13217), (0xd2ad, 10855), (0xd2ae, 10862), (0xd2af, 10863), (0xd2b0, 10864), (0xd2b1, 13218), (0xd2b2, 10865), (0xd2b3, 10866),
+    (0xd2b4, 10867), (0xd2b5, 10868), (0xd2b6, 10869), (0xd2b7, 10870), (0xd2b8, 13219), (0xd2b9, 13220), (0xd2ba, 10871), (0xd2bb, 10872),
+    (0xd2bc, 13221), (0xd2bd, 10873), (0xd2be, 10874), (0xd2bf, 13222), (0xd2c0, 13223), (0xd2c1, 10875), (0xd2c2, 13224), (0xd2c3, 10876),
+    (0xd2c4, 10877), (0xd2c5, 10878), (0xd2c6, 10879), (0xd2c7, 10880), (0xd2c8, 13225), (0xd2c9, 13226), (0xd2ca, 10881), (0xd2cb, 13227),
+    (0xd2cc, 10882), (0xd2cd, 10883), (0xd2ce, 10884), (0xd2cf, 10885), (0xd2d0, 10886), (0xd2d1, 10887), (0xd2d2, 10894), (0xd2d3, 10895),
+    (0xd2d4, 13228), (0xd2d5, 10896), (0xd2d6, 10897), (0xd2d7, 10898), (0xd2d8, 13229), (0xd2d9, 10899), (0xd2da, 10900), (0xd2db, 10901),
+    (0xd2dc, 13230), (0xd2dd, 10902), (0xd2de, 10903), (0xd2df, 10904), (0xd2e0, 10905), (0xd2e1, 10906), (0xd2e2, 10907), (0xd2e3, 10908),
+    (0xd2e4, 13231), (0xd2e5, 13232), (0xd2e6, 10909), (0xd2e7, 10910), (0xd2e8, 10911), (0xd2e9, 10912), (0xd2ea, 10913), (0xd2eb, 10914),
+    (0xd2ec, 10915), (0xd2ed, 10916), (0xd2ee, 10917), (0xd2ef, 10918), (0xd2f0, 13233), (0xd2f1, 13234), (0xd2f2, 10919), (0xd2f3, 10920),
+    (0xd2f4, 13235), (0xd2f5, 10921), (0xd2f6, 10922), (0xd2f7, 10923), (0xd2f8, 13236), (0xd2f9, 10924), (0xd2fa, 10925), (0xd2fb, 11020),
+    (0xd2fc, 11021), (0xd2fd, 11022), (0xd2fe, 11023), (0xd2ff, 11024), (0xd300, 13237), (0xd301, 13238), (0xd302, 11025), (0xd303, 13239),
+    (0xd304, 11026), (0xd305, 13240), (0xd306, 11027), (0xd307, 11028), (0xd308, 11029), (0xd309, 11030), (0xd30a, 11031), (0xd30b, 11032),
+    (0xd30c, 13241), (0xd30d, 13242), (0xd30e, 13243), (0xd30f, 11033), (0xd310, 13244), (0xd311, 11034), (0xd312, 11035), (0xd313, 11036),
+    (0xd314, 13245), (0xd315, 11037), (0xd316, 13246), (0xd317, 11038), (0xd318, 11039), (0xd319, 11040), (0xd31a, 11041), (0xd31b, 11042),
+    (0xd31c, 13247), (0xd31d, 13248), (0xd31e, 11043), (0xd31f, 13249), (0xd320, 13250), (0xd321, 13251), (0xd322, 11044), (0xd323, 11045),
+    (0xd324, 11052), (0xd325, 13252), (0xd326, 11053), (0xd327, 11054), (0xd328, 13253), (0xd329, 13254), (0xd32a, 11055), (0xd32b, 11056),
+    (0xd32c, 13255), (0xd32d, 11057), (0xd32e, 11058), (0xd32f, 11059), (0xd330, 13256), (0xd331, 11060), (0xd332, 11061), (0xd333, 11062),
+    (0xd334, 11063), (0xd335, 11064), (0xd336, 11065), (0xd337, 11066), (0xd338, 13257), (0xd339, 13258), (0xd33a, 11067), (0xd33b, 13259),
+    (0xd33c, 13260), (0xd33d, 13261), (0xd33e, 11068), (0xd33f, 11069), (0xd340, 11070), (0xd341, 11071), (0xd342, 11072), (0xd343, 11073),
+    (0xd344, 13262), (0xd345, 13263), (0xd346, 11074), (0xd347, 11075), (0xd348, 11076), (0xd349, 11077), (0xd34a, 11084), (0xd34b, 11085),
+    (0xd34c, 11086), (0xd34d, 11087), (0xd34e, 11088), (0xd34f, 11089), (0xd350, 11090), (0xd351, 11091), (0xd352, 11092), (0xd353, 11093),
+    (0xd354, 11094), (0xd355, 11095), (0xd356, 11096), (0xd357, 11097), (0xd358, 11098), (0xd359, 11099), (0xd35a, 11100), (0xd35b, 11101),
+    (0xd35c, 11102), (0xd35d, 11103), (0xd35e, 11104), (0xd35f, 11105), (0xd360, 11106), (0xd361, 11107), (0xd362, 11108), (0xd363, 11109),
+    (0xd364, 11110), (0xd365, 11111), (0xd366, 11112), (0xd367, 11113), (0xd368, 11114), (0xd369, 11115), (0xd36a, 11210), (0xd36b, 11211),
+    (0xd36c, 11212), (0xd36d, 11213), (0xd36e, 11214), (0xd36f, 11215), (0xd370, 11216), (0xd371, 11217), (0xd372, 11218), (0xd373, 11219),
+    (0xd374, 11220), (0xd375, 11221), (0xd376, 11222), (0xd377, 11223), (0xd378, 11224), (0xd379, 11225), (0xd37a, 11226), (0xd37b, 11227),
+    (0xd37c, 13264), (0xd37d, 13265), (0xd37e, 11228), (0xd37f, 11229), (0xd380, 13266), (0xd381, 11230), (0xd382, 11231), (0xd383, 11232),
+    (0xd384, 13267), (0xd385, 11233), (0xd386, 11234), (0xd387, 11235), (0xd388, 11242), (0xd389, 11243), (0xd38a, 11244), (0xd38b, 11245),
+    (0xd38c, 13268), (0xd38d, 13269), (0xd38e, 11246), (0xd38f, 13270), (0xd390, 13271), (0xd391, 13272), (0xd392, 11247), (0xd393, 11248),
+    (0xd394, 11249), (0xd395, 11250), (0xd396, 11251), (0xd397, 11252), (0xd398, 13273), (0xd399, 13274), (0xd39a, 11253), (0xd39b, 11254),
+    (0xd39c, 13275), (0xd39d, 11255), (0xd39e, 11256), (0xd39f, 11257), (0xd3a0, 13276), (0xd3a1, 11258), (0xd3a2, 11259), (0xd3a3, 11260),
+    (0xd3a4, 11261), (0xd3a5, 11262), (0xd3a6, 11263), (0xd3a7, 11264), (0xd3a8, 13277), (0xd3a9, 13278), (0xd3aa, 11265), (0xd3ab, 13279),
+    (0xd3ac, 11266), (0xd3ad, 13280), (0xd3ae, 11267), (0xd3af, 11274), (0xd3b0, 11275), (0xd3b1, 11276), (0xd3b2, 11277), (0xd3b3, 11278),
+    (0xd3b4, 13281), (0xd3b5, 11279), (0xd3b6, 11280), (0xd3b7, 11281), (0xd3b8, 13282), (0xd3b9, 11282), (0xd3ba, 11283), (0xd3bb, 11284),
+    (0xd3bc, 13283), (0xd3bd, 11285), (0xd3be, 11286), (0xd3bf, 11287), (0xd3c0, 11288), (0xd3c1, 11289), (0xd3c2, 11290), (0xd3c3, 11291),
+    (0xd3c4, 13284), (0xd3c5, 13285), (0xd3c6, 11292), (0xd3c7, 11293), (0xd3c8, 13286), (0xd3c9, 13287), (0xd3ca, 11294), (0xd3cb, 11295),
+    (0xd3cc, 11296), (0xd3cd, 11297), (0xd3ce, 11298), (0xd3cf, 11299), (0xd3d0, 13288), (0xd3d1, 11300), (0xd3d2, 11301), (0xd3d3, 11302),
+    (0xd3d4, 11303), (0xd3d5, 11304), (0xd3d6, 11305), (0xd3d7, 11400), (0xd3d8, 13289), (0xd3d9, 11401), (0xd3da, 11402), (0xd3db, 11403),
+    (0xd3dc, 11404), (0xd3dd, 11405), (0xd3de, 11406), (0xd3df, 11407), (0xd3e0, 11408), (0xd3e1, 13290), (0xd3e2, 11409), (0xd3e3, 13291),
+    (0xd3e4, 11410), (0xd3e5, 11411), (0xd3e6, 11412), (0xd3e7, 11413), (0xd3e8, 11414), (0xd3e9, 11415), (0xd3ea, 11416), (0xd3eb, 11417),
+    (0xd3ec, 13292), (0xd3ed, 13293), (0xd3ee, 11418), (0xd3ef, 11419), (0xd3f0, 13294), (0xd3f1, 11420), (0xd3f2, 11421), (0xd3f3, 11422),
+    (0xd3f4, 13295), (0xd3f5, 11423), (0xd3f6, 11424), (0xd3f7, 11425), (0xd3f8, 11432), (0xd3f9, 11433), (0xd3fa, 11434), (0xd3fb, 11435),
+    (0xd3fc, 13296), (0xd3fd, 13297), (0xd3fe, 11436), (0xd3ff, 13298), (0xd400, 11437), (0xd401, 13299), (0xd402, 11438), (0xd403, 11439),
+    (0xd404, 11440), (0xd405, 11441), (0xd406, 11442), (0xd407, 11443), (0xd408, 13396), (0xd409, 11444), (0xd40a, 11445), (0xd40b, 11446),
+    (0xd40c, 11447), (0xd40d, 11448), (0xd40e, 11449), (0xd40f, 11450), (0xd410, 11451), (0xd411, 11452), (0xd412, 11453), (0xd413, 11454),
+    (0xd414, 11455), (0xd415, 11456), (0xd416, 11457), (0xd417, 11464), (0xd418, 11465), (0xd419, 11466), (0xd41a, 11467), (0xd41b, 11468),
+    (0xd41c, 11469), (0xd41d, 13397), (0xd41e, 11470), (0xd41f, 11471), (0xd420, 11472), (0xd421, 11473), (0xd422, 11474), (0xd423, 11475),
+    (0xd424, 11476), (0xd425, 11477), (0xd426, 11478), (0xd427, 11479), (0xd428, 11480), (0xd429, 11481), (0xd42a, 11482), (0xd42b, 11483),
+    (0xd42c, 11484), (0xd42d, 11485), (0xd42e, 11486), (0xd42f, 11487), (0xd430, 11488), (0xd431, 11489), (0xd432, 11490), (0xd433, 11491),
+    (0xd434, 11492), (0xd435, 11493), (0xd436, 11494), (0xd437, 11495), (0xd438, 11590), (0xd439, 11591), (0xd43a, 11592), (0xd43b, 11593),
+    (0xd43c, 11594), (0xd43d, 11595), (0xd43e, 11596), (0xd43f, 11597), (0xd440, 13398), (0xd441, 11598), (0xd442, 11599), (0xd443, 11600),
+    (0xd444, 13399), (0xd445, 11601), (0xd446, 11602), (0xd447, 11603), (0xd448, 11604), (0xd449, 11605), (0xd44a, 11606), (0xd44b, 11607),
+    (0xd44c, 11608), (0xd44d, 11609), (0xd44e, 11610), (0xd44f, 11611), (0xd450, 11612), (0xd451, 11613), (0xd452, 11614), (0xd453, 11615),
+    (0xd454, 11622), (0xd455, 11623), (0xd456, 11624), (0xd457, 11625), (0xd458, 11626), (0xd459, 11627), (0xd45a, 11628), (0xd45b, 11629),
+    (0xd45c, 13400), (0xd45d, 11630), (0xd45e, 11631), (0xd45f, 11632), (0xd460, 13401), (0xd461, 11633), (0xd462, 11634), (0xd463, 11635),
+    (0xd464, 13402), (0xd465, 11636), (0xd466, 11637), (0xd467, 11638), (0xd468, 11639), (0xd469, 11640), (0xd46a, 11641), (0xd46b, 11642),
+    (0xd46c, 11643), (0xd46d, 13403), (0xd46e, 11644), (0xd46f, 13404), (0xd470, 11645), (0xd471, 11646), (0xd472, 11647), (0xd473, 11654),
+    (0xd474, 11655), (0xd475, 11656), (0xd476, 11657), (0xd477, 11658), (0xd478, 13405), (0xd479, 13406), (0xd47a, 11659), (0xd47b, 11660),
+    (0xd47c, 13407), (0xd47d, 11661), (0xd47e, 11662), (0xd47f, 13408), (0xd480, 13409), (0xd481, 11663), (0xd482, 13410), (0xd483, 11664),
+    (0xd484, 11665), (0xd485, 11666), (0xd486, 11667), (0xd487, 11668), (0xd488, 13411), (0xd489, 13412), (0xd48a, 11669), (0xd48b, 13413),
+    (0xd48c, 11670), (0xd48d, 13414), (0xd48e, 11671), (0xd48f, 11672), (0xd490, 11673), (0xd491, 11674), (0xd492, 11675), (0xd493, 11676),
+    (0xd494, 13415), (0xd495, 11677), (0xd496, 11678), (0xd497, 11679), (0xd498, 11680), (0xd499, 11681), (0xd49a, 11682), (0xd49b, 11683),
+    (0xd49c, 11684), (0xd49d, 11685), (0xd49e, 11780), (0xd49f, 11781), (0xd4a0, 11782), (0xd4a1, 11783), (0xd4a2, 11784), (0xd4a3, 11785),
+    (0xd4a4, 11786), (0xd4a5, 11787), (0xd4a6, 11788), (0xd4a7, 11789), (0xd4a8, 11790), (0xd4a9, 13416), (0xd4aa, 11791), (0xd4ab, 11792),
+    (0xd4ac, 11793), (0xd4ad, 11794), (0xd4ae, 11795), (0xd4af, 11796), (0xd4b0, 11797), (0xd4b1, 11798), (0xd4b2, 11799), (0xd4b3, 11800),
+    (0xd4b4, 11801), (0xd4b5, 11802), (0xd4b6, 11803), (0xd4b7, 11804), (0xd4b8, 11805), (0xd4b9, 11812), (0xd4ba, 11813), (0xd4bb, 11814),
+    (0xd4bc, 11815), (0xd4bd, 11816), (0xd4be, 11817), (0xd4bf, 11818), (0xd4c0, 11819), (0xd4c1, 11820), (0xd4c2, 11821), (0xd4c3, 11822),
+    (0xd4c4, 11823), (0xd4c5, 11824), (0xd4c6, 11825), (0xd4c7, 11826), (0xd4c8, 11827), (0xd4c9, 11828), (0xd4ca, 11829), (0xd4cb, 11830),
+    (0xd4cc, 13417), (0xd4cd, 11831), (0xd4ce, 11832), (0xd4cf, 11833), (0xd4d0, 13418), (0xd4d1, 11834), (0xd4d2, 11835), (0xd4d3, 11836),
+    (0xd4d4, 13419), (0xd4d5, 11837), (0xd4d6, 11844), (0xd4d7, 11845), (0xd4d8, 11846), (0xd4d9, 11847), (0xd4da, 11848), (0xd4db, 11849),
+    (0xd4dc, 13420), (0xd4dd, 11850), (0xd4de, 11851), (0xd4df, 13421), (0xd4e0, 11852), (0xd4e1, 11853), (0xd4e2, 11854), (0xd4e3, 11855),
+    (0xd4e4, 11856), (0xd4e5, 11857), (0xd4e6, 11858), (0xd4e7, 11859), (0xd4e8, 13422), (0xd4e9, 11860), (0xd4ea, 11861), (0xd4eb, 11862),
+    (0xd4ec, 13423), (0xd4ed, 11863), (0xd4ee, 11864), (0xd4ef, 11865), (0xd4f0, 13424), (0xd4f1, 11866), (0xd4f2, 11867), (0xd4f3, 11868),
+    (0xd4f4, 11869), (0xd4f5, 11870), (0xd4f6, 11871), (0xd4f7, 11872), (0xd4f8, 13425), (0xd4f9, 11873), (0xd4fa, 11874), (0xd4fb, 13426),
+    (0xd4fc, 11875), (0xd4fd, 13427), (0xd4fe, 11970), (0xd4ff, 11971), (0xd500, 11972), (0xd501, 11973), (0xd502, 11974), (0xd503, 11975),
+    (0xd504, 13428), (0xd505, 11976), (0xd506, 11977), (0xd507, 11978), (0xd508, 13429), (0xd509, 11979), (0xd50a, 11980), (0xd50b, 11981),
+    (0xd50c, 13430), (0xd50d, 11982), (0xd50e, 11983), (0xd50f, 11984), (0xd510, 11985), (0xd511, 11986), (0xd512, 11987), (0xd513, 11988),
+    (0xd514, 13431), (0xd515, 13432), (0xd516, 11989), (0xd517, 13433), (0xd518, 11990), (0xd519, 11991), (0xd51a, 11992), (0xd51b, 11993),
+    (0xd51c, 11994), (0xd51d, 11995), (0xd51e, 12002), (0xd51f, 12003), (0xd520, 12004), (0xd521, 12005), (0xd522, 12006), (0xd523, 12007),
+    (0xd524, 12008), (0xd525, 12009), (0xd526, 12010), (0xd527, 12011), (0xd528, 12012), (0xd529, 12013), (0xd52a, 12014), (0xd52b, 12015),
+    (0xd52c, 12016), (0xd52d, 12017), (0xd52e, 12018), (0xd52f, 12019), (0xd530, 12020), (0xd531, 12021), (0xd532, 12022), (0xd533, 12023),
+    (0xd534, 12024), (0xd535, 12025), (0xd536, 12026), (0xd537, 12027), (0xd538, 12034), (0xd539, 12035), (0xd53a, 12036), (0xd53b, 12037),
+    (0xd53c, 13434), (0xd53d, 13435), (0xd53e, 12038), (0xd53f, 12039), (0xd540, 13436), (0xd541, 12040), (0xd542, 12041), (0xd543, 12042),
+    (0xd544, 13437), (0xd545, 12043), (0xd546, 12044), (0xd547, 12045), (0xd548, 12046), (0xd549, 12047), (0xd54a, 12048), (0xd54b, 12049),
+    (0xd54c, 13438), (0xd54d, 13439), (0xd54e, 12050), (0xd54f, 13440), (0xd550, 12051), (0xd551, 13441), (0xd552, 12052), (0xd553, 12053),
+    (0xd554, 12054), (0xd555, 12055), (0xd556, 12056), (0xd557, 12057), (0xd558, 13442), (0xd559, 13443), (0xd55a, 12058), (0xd55b, 12059),
+    (0xd55c, 13444), (0xd55d, 12060), (0xd55e, 12061), (0xd55f, 12062), (0xd560, 13445), (0xd561, 12063), (0xd562, 12064), (0xd563, 12065),
+    (0xd564, 12160), (0xd565, 13446), (0xd566, 12161), (0xd567, 12162), (0xd568, 13447), (0xd569, 13448), (0xd56a, 12163), (0xd56b, 13449),
+    (0xd56c, 12164), (0xd56d, 13450), (0xd56e, 12165), (0xd56f, 12166), (0xd570, 12167), (0xd571, 12168), (0xd572, 12169), (0xd573, 12170),
+    (0xd574, 13451), (0xd575, 13452), (0xd576, 12171), (0xd577, 12172), (0xd578, 13453), (0xd579, 12173), (0xd57a, 12174), (0xd57b, 12175),
+    (0xd57c, 13454), (0xd57d, 12176), (0xd57e, 12177), (0xd57f, 12178), (0xd580, 12179), (0xd581, 12180), (0xd582, 12181), (0xd583, 12182),
+    (0xd584, 13455), (0xd585, 13456), (0xd586, 12183), (0xd587, 13457), (0xd588, 13458), (0xd589, 13459), (0xd58a, 12184), (0xd58b, 12185),
+    (0xd58c, 12192), (0xd58d, 12193), (0xd58e, 12194), (0xd58f, 12195), (0xd590, 13460), (0xd591, 12196), (0xd592, 12197), (0xd593, 12198),
+    (0xd594, 12199), (0xd595, 12200), (0xd596, 12201), (0xd597, 12202), (0xd598, 12203), (0xd599, 12204), (0xd59a, 12205), (0xd59b, 12206),
+    (0xd59c, 12207), (0xd59d, 12208), (0xd59e, 12209), (0xd59f, 12210), (0xd5a0, 12211), (0xd5a1, 12212), (0xd5a2, 12213), (0xd5a3, 12214),
+    (0xd5a4, 12215), (0xd5a5, 13461), (0xd5a6, 12216), (0xd5a7, 12217), (0xd5a8, 12224), (0xd5a9, 12225), (0xd5aa, 12226), (0xd5ab, 12227),
+    (0xd5ac, 12228), (0xd5ad, 12229), (0xd5ae, 12230), (0xd5af, 12231), (0xd5b0, 12232), (0xd5b1, 12233), (0xd5b2, 12234), (0xd5b3, 12235),
+    (0xd5b4, 12236), (0xd5b5, 12237), (0xd5b6, 12238), (0xd5b7, 12239), (0xd5b8, 12240), (0xd5b9, 12241), (0xd5ba, 12242), (0xd5bb, 12243),
+    (0xd5bc, 12244), (0xd5bd, 12245), (0xd5be, 12246), (0xd5bf, 12247), (0xd5c0, 12248), (0xd5c1, 12249), (0xd5c2, 12250), (0xd5c3, 12251),
+    (0xd5c4, 12252), (0xd5c5, 12253), (0xd5c6, 12254), (0xd5c7, 12255), (0xd5c8, 13462), (0xd5c9, 13463), (0xd5ca, 12350), (0xd5cb, 12351),
+    (0xd5cc, 13464), (0xd5cd, 12352), (0xd5ce, 12353), (0xd5cf, 12354), (0xd5d0, 13465), (0xd5d1, 12355), (0xd5d2, 13466), (0xd5d3, 12356),
+    (0xd5d4, 12357), (0xd5d5, 12358), (0xd5d6, 12359), (0xd5d7, 12360), (0xd5d8, 13467), (0xd5d9, 13468), (0xd5da, 12361), (0xd5db, 13469),
+    (0xd5dc, 12362), (0xd5dd, 13470), (0xd5de, 12363), (0xd5df, 12364), (0xd5e0, 12365), (0xd5e1, 12366), (0xd5e2, 12367), (0xd5e3, 12368),
+    (0xd5e4, 13471), (0xd5e5, 13472), (0xd5e6, 12369), (0xd5e7, 12370), (0xd5e8, 13473), (0xd5e9, 12371), (0xd5ea, 12372), (0xd5eb, 12373),
+    (0xd5ec, 13474), (0xd5ed, 12374), (0xd5ee, 12375), (0xd5ef, 12382), (0xd5f0, 12383), (0xd5f1, 12384), (0xd5f2, 12385), (0xd5f3, 12386),
+    (0xd5f4, 13475), (0xd5f5, 13476), (0xd5f6, 12387), (0xd5f7, 13477), (0xd5f8, 12388), (0xd5f9, 13478), (0xd5fa, 12389), (0xd5fb, 12390),
+    (0xd5fc, 12391), (0xd5fd, 12392), (0xd5fe, 12393), (0xd5ff, 12394), (0xd600, 13479), (0xd601, 13480), (0xd602, 12395), (0xd603, 12396),
+    (0xd604, 13481), (0xd605, 12397), (0xd606, 12398), (0xd607, 12399), (0xd608, 13482), (0xd609, 12400), (0xd60a, 12401), (0xd60b, 12402),
+    (0xd60c, 12403), (0xd60d, 12404), (0xd60e, 12405), (0xd60f, 12406), (0xd610, 13483), (0xd611, 13484), (0xd612, 12407), (0xd613, 13485),
+    (0xd614, 13486), (0xd615, 13487), (0xd616, 12414), (0xd617, 12415), (0xd618, 12416), (0xd619, 12417), (0xd61a, 12418), (0xd61b, 12419),
+    (0xd61c, 13488), (0xd61d, 12420), (0xd61e, 12421), (0xd61f, 12422), (0xd620, 13489), (0xd621, 12423), (0xd622, 12424), (0xd623, 12425),
+    (0xd624, 13586), (0xd625, 12426), (0xd626, 12427), (0xd627, 12428), (0xd628, 12429), (0xd629, 12430), (0xd62a, 12431), (0xd62b, 12432),
+    (0xd62c, 12433), (0xd62d, 13587), (0xd62e, 12434), (0xd62f, 12435), (0xd630, 12436), (0xd631, 12437), (0xd632, 12438), (0xd633, 12439),
+    (0xd634, 12440), (0xd635, 12441), (0xd636, 12442), (0xd637, 12443), (0xd638, 13588), (0xd639, 13589), (0xd63a, 12444), (0xd63b, 12445),
+    (0xd63c, 13590), (0xd63d, 12540), (0xd63e, 12541), (0xd63f, 12542), (0xd640, 13591), (0xd641, 12543), (0xd642, 12544), (0xd643, 12545),
+    (0xd644, 12546), (0xd645, 13592), (0xd646, 12547), (0xd647, 12548), (0xd648, 13593), (0xd649, 13594), (0xd64a, 12549), (0xd64b, 13595),
+    (0xd64c, 12550), (0xd64d, 13596), (0xd64e, 12551), (0xd64f, 12552), (0xd650, 12553), (0xd651, 13597), (0xd652, 12554), (0xd653, 12555),
+    (0xd654, 13598), (0xd655, 13599), (0xd656, 12556), (0xd657, 12557), (0xd658, 13600), (0xd659, 12558), (0xd65a, 12559), (0xd65b, 12560),
+    (0xd65c, 13601), (0xd65d, 12561), (0xd65e, 12562), (0xd65f, 12563), (0xd660, 12564), (0xd661, 12565), (0xd662, 12572), (0xd663, 12573),
+    (0xd664, 12574), (0xd665, 12575), (0xd666, 12576), (0xd667, 13602), (0xd668, 12577), (0xd669, 13603), (0xd66a, 12578), (0xd66b, 12579),
+    (0xd66c, 12580), (0xd66d, 12581), (0xd66e, 12582), (0xd66f, 12583), (0xd670, 13604), (0xd671, 13605), (0xd672, 12584), (0xd673, 12585),
+    (0xd674, 13606), (0xd675, 12586), (0xd676, 12587), (0xd677, 12588), (0xd678, 12589), (0xd679, 12590), (0xd67a, 12591), (0xd67b, 12592),
+    (0xd67c, 12593), (0xd67d, 12594), (0xd67e, 12595), (0xd67f, 12596), (0xd680, 12597), (0xd681, 12604), (0xd682, 12605), (0xd683, 13607),
+    (0xd684, 12606), (0xd685, 13608), (0xd686, 12607), (0xd687, 12608), (0xd688, 12609), (0xd689, 12610), (0xd68a, 12611), (0xd68b, 12612),
+    (0xd68c, 13609), (0xd68d, 13610), (0xd68e, 12613), (0xd68f, 12614), (0xd690, 13611), (0xd691, 12615), (0xd692, 12616), (0xd693, 12617),
+    (0xd694, 13612), (0xd695, 12618), (0xd696, 12619), (0xd697, 12620), (0xd698, 12621), (0xd699, 12622), (0xd69a, 12623), (0xd69b, 12624),
+    (0xd69c, 12625), (0xd69d, 13613), (0xd69e, 12626), (0xd69f, 13614), (0xd6a0, 12627), (0xd6a1, 13615), (0xd6a2, 12628), (0xd6a3, 12629),
+    (0xd6a4, 12630), (0xd6a5, 12631), (0xd6a6, 12632), (0xd6a7, 12633), (0xd6a8, 13616), (0xd6a9, 12634), (0xd6aa, 12635), (0xd6ab, 12730),
+    (0xd6ac, 13617), (0xd6ad, 12731), (0xd6ae, 12732), (0xd6af, 12733), (0xd6b0, 13618), (0xd6b1, 12734), (0xd6b2, 12735), (0xd6b3, 12736),
+    (0xd6b4, 12737), (0xd6b5, 12738), (0xd6b6, 12739), (0xd6b7, 12740), (0xd6b8, 12741), (0xd6b9, 13619), (0xd6ba, 12742), (0xd6bb, 13620),
+    (0xd6bc, 12743), (0xd6bd, 12744), (0xd6be, 12745), (0xd6bf, 12746), (0xd6c0, 12747), (0xd6c1, 12748), (0xd6c2, 12749), (0xd6c3, 12750),
+    (0xd6c4, 13621), (0xd6c5, 13622), (0xd6c6, 12751), (0xd6c7, 12752), (0xd6c8, 13623), (0xd6c9, 12753), (0xd6ca, 12754), (0xd6cb, 12755),
+    (0xd6cc, 13624), (0xd6cd, 12762), (0xd6ce, 12763), (0xd6cf, 12764), (0xd6d0, 12765), (0xd6d1, 13625), (0xd6d2, 12766), (0xd6d3, 12767),
+    (0xd6d4, 13626), (0xd6d5, 12768), (0xd6d6, 12769), (0xd6d7, 13627), (0xd6d8, 12770), (0xd6d9, 13628), (0xd6da, 12771), (0xd6db, 12772),
+    (0xd6dc, 12773), (0xd6dd, 12774), (0xd6de, 12775), (0xd6df, 12776), (0xd6e0, 13629), (0xd6e1, 12777), (0xd6e2, 12778), (0xd6e3, 12779),
+    (0xd6e4, 13630), (0xd6e5, 12780), (0xd6e6, 12781), (0xd6e7, 12782), (0xd6e8, 13631), (0xd6e9, 12783), (0xd6ea, 12784), (0xd6eb, 12785),
+    (0xd6ec, 12786), (0xd6ed, 12787), (0xd6ee, 12794), (0xd6ef, 12795), (0xd6f0, 13632), (0xd6f1, 12796), (0xd6f2, 12797), (0xd6f3, 12798),
+    (0xd6f4, 12799), (0xd6f5, 13633), (0xd6f6, 12800), (0xd6f7, 12801), (0xd6f8, 12802), (0xd6f9, 12803), (0xd6fa, 12804), (0xd6fb, 12805),
+    (0xd6fc, 13634), (0xd6fd, 13635), (0xd6fe, 12806), (0xd6ff, 12807), (0xd700, 13636), (0xd701, 12808), (0xd702, 12809), (0xd703, 12810),
+    (0xd704, 13637), (0xd705, 12811), (0xd706, 12812), (0xd707, 12813), (0xd708, 12814), (0xd709, 12815), (0xd70a, 12816), (0xd70b, 12817),
+    (0xd70c, 12818), (0xd70d, 12819), (0xd70e, 12820), (0xd70f, 12821), (0xd710, 12822), (0xd711, 13638), (0xd712, 12823), (0xd713, 12824),
+    (0xd714, 12825), (0xd715, 12920), (0xd716, 12921), (0xd717, 12922), (0xd718, 13639), (0xd719, 13640), (0xd71a, 12923), (0xd71b, 12924),
+    (0xd71c, 13641), (0xd71d, 12925), (0xd71e, 12926), (0xd71f, 12927), (0xd720, 13642), (0xd721, 12928), (0xd722, 12929), (0xd723, 12930),
+    (0xd724, 12931), (0xd725, 12932), (0xd726, 12933), (0xd727, 12934), (0xd728, 13643), (0xd729, 13644), (0xd72a, 12935), (0xd72b, 13645),
+    (0xd72c, 12936), (0xd72d, 13646), (0xd72e, 12937), (0xd72f, 12938), (0xd730, 12939), (0xd731, 12940), (0xd732, 12941), (0xd733, 12942),
+    (0xd734, 13647), (0xd735, 13648), (0xd736, 12943), (0xd737, 12944), (0xd738, 13649), (0xd739, 12945), (0xd73a, 12952), (0xd73b, 12953),
+    (0xd73c, 13650), (0xd73d, 12954), (0xd73e, 12955), (0xd73f, 12956), (0xd740, 12957), (0xd741, 12958), (0xd742, 12959), (0xd743, 12960),
+    (0xd744, 13651), (0xd745, 12961), (0xd746, 12962), (0xd747, 13652), (0xd748, 12963), (0xd749, 13653), (0xd74a, 12964), (0xd74b, 12965),
+    (0xd74c, 12966), (0xd74d, 12967), (0xd74e, 12968), (0xd74f, 12969), (0xd750, 13654), (0xd751, 13655), (0xd752, 12970), (0xd753, 12971),
+    (0xd754, 13656), (0xd755, 12972), (0xd756, 13657), (0xd757, 13658), (0xd758, 13659), (0xd759, 13660), (0xd75a, 12973), (0xd75b, 12974),
+    (0xd75c, 12975), (0xd75d, 12976), (0xd75e, 12977), (0xd75f, 12984), (0xd760, 13661), (0xd761, 13662), (0xd762, 12985), (0xd763, 13663),
+    (0xd764, 12986), (0xd765, 13664), (0xd766, 12987), (0xd767, 12988), (0xd768, 12989), (0xd769, 13665), (0xd76a, 12990), (0xd76b, 12991),
+    (0xd76c, 13666), (0xd76d, 12992), (0xd76e, 12993), (0xd76f, 12994), (0xd770, 13667), (0xd771, 12995), (0xd772, 12996), (0xd773, 12997),
+    (0xd774, 13668), (0xd775, 12998), (0xd776, 12999), (0xd777, 13000), (0xd778, 13001), (0xd779, 13002), (0xd77a, 13003), (0xd77b, 13004),
+    (0xd77c, 13669), (0xd77d, 13670), (0xd77e, 13005), (0xd77f, 13006), (0xd780, 13007), (0xd781, 13671), (0xd782, 13008), (0xd783, 13009),
+    (0xd784, 13010), (0xd785, 13011), (0xd786, 13012), (0xd787, 13013), (0xd788, 13672), (0xd789, 13673), (0xd78a, 13014), (0xd78b, 13015),
+    (0xd78c, 13674), (0xd78d, 13110), (0xd78e, 13111), (0xd78f, 13112), (0xd790, 13675), (0xd791, 13113), (0xd792, 13114), (0xd793, 13115),
+    (0xd794, 13116), (0xd795, 13117), (0xd796, 13118), (0xd797, 13119), (0xd798, 13676), (0xd799, 13677), (0xd79a, 13120), (0xd79b, 13678),
+    (0xd79c, 13121), (0xd79d, 13679), (0xd79e, 13122), (0xd79f, 13123), (0xd7a0, 13124), (0xd7a1, 13125), (0xd7a2, 13126), (0xd7a3, 13127),
+    (0xf900, 14203), (0xf901, 14209), (0xf902, 14226), (0xf903, 14582), (0xf904, 14607), (0xf905, 14738), (0xf906, 15006), (0xf907, 15107),
+    (0xf908, 15129), (0xf909, 15153), (0xf90a, 15166), (0xf90b, 15347), (0xf90c, 15348), (0xf90d, 15351), (0xf90e, 15354), (0xf90f, 15355),
+    (0xf910, 15356), (0xf911, 15357), (0xf912, 15358), (0xf913, 15359), (0xf914, 15361), (0xf915, 15362), (0xf916, 15363), (0xf917, 15364),
+    (0xf918, 15365), (0xf919, 15367), (0xf91a, 15368), (0xf91b, 15369), (0xf91c, 15370), (0xf91d, 15372), (0xf91e, 15374), (0xf91f, 15375),
+    (0xf920, 15377), (0xf921, 15381), (0xf922, 15385), (0xf923, 15387), (0xf924, 15388), (0xf925, 15389), (0xf926, 15487), (0xf927, 15488),
+    (0xf928, 15492), (0xf929, 15493), (0xf92a, 15494), (0xf92b, 15495), (0xf92c, 15496), (0xf92d, 15498), (0xf92e, 15503), (0xf92f, 15515),
+    (0xf930, 15519), (0xf931, 15520), (0xf932, 15521), (0xf933, 15523), (0xf934, 15524), (0xf935, 15525), (0xf936, 15526), (0xf937, 15527),
+    (0xf938, 15528), (0xf939, 15530), (0xf93a, 15531), (0xf93b, 15532), (0xf93c, 15533), (0xf93d, 15534), (0xf93e, 15535), (0xf93f, 15536),
+    (0xf940, 15537), (0xf941, 15538), (0xf942, 15539), (0xf943, 15540), (0xf944, 15542), (0xf945, 15543), (0xf946, 15547), (0xf947, 15548),
+    (0xf948, 15550), (0xf949, 15551), (0xf94a, 15553), (0xf94b, 15554), (0xf94c, 15555), (0xf94d, 15556), (0xf94e, 15557), (0xf94f, 15558),
+    (0xf950, 15559), (0xf951, 15560), (0xf952, 15565), (0xf953, 15566), (0xf954, 15567), (0xf955, 15568), (0xf956, 15569), (0xf957, 15570),
+    (0xf958, 15572), (0xf959, 15573), (0xf95a, 15935), (0xf95b, 15957), (0xf95c, 16060), (0xf95d, 16066), (0xf95e, 16069), (0xf95f, 16269),
+    (0xf960, 16290), (0xf961, 16478), (0xf962, 16503), (0xf963, 17232), (0xf964, 17263), (0xf965, 17390), (0xf966, 17580), (0xf967, 17628),
+    (0xf968, 17659), (0xf969, 17857), (0xf96a, 17859), (0xf96b, 17974), (0xf96c, 18020), (0xf96d, 18027), (0xf96e, 18226), (0xf96f, 18348),
+    (0xf970, 18412), (0xf971, 18800), (0xf972, 18906), (0xf973, 18914), (0xf974, 19109), (0xf975, 19112), (0xf976, 19113), (0xf977, 19120),
+    (0xf978, 19122), (0xf979, 19123), (0xf97a, 19131), (0xf97b, 19141), (0xf97c, 19143), (0xf97d, 19145), (0xf97e, 19149), (0xf97f, 19185),
+    (0xf980, 19186), (0xf981, 19187), (0xf982, 19189), (0xf983, 19286), (0xf984, 19289), (0xf985, 19292), (0xf986, 19298), (0xf987, 19300),
+    (0xf988, 19301), (0xf989, 19302), (0xf98a, 19304), (0xf98b, 19308), (0xf98c, 19309), (0xf98d, 19313), (0xf98e, 19321), (0xf98f, 19323),
+    (0xf990, 19324), (0xf991, 19327), (0xf992, 19335), (0xf993, 19339), (0xf994, 19342), (0xf995, 19345), (0xf996, 19348), (0xf997, 19350),
+    (0xf998, 19353), (0xf999, 19354), (0xf99a, 19355), (0xf99b, 19357), (0xf99c, 19359), (0xf99d, 19360), (0xf99e, 19361), (0xf99f, 19364),
+    (0xf9a0, 19366), (0xf9a1, 19367), (0xf9a2, 19370), (0xf9a3, 19371), (0xf9a4, 19372), (0xf9a5, 19374), (0xf9a6, 19476), (0xf9a7, 19481),
+    (0xf9a8, 19484), (0xf9a9, 19485), (0xf9aa, 19487), (0xf9ab, 19488), (0xf9ac, 19491), (0xf9ad, 19506), (0xf9ae, 19508), (0xf9af, 19513),
+    (0xf9b0, 19514), (0xf9b1, 19518), (0xf9b2, 19520), (0xf9b3, 19522), (0xf9b4, 19523), (0xf9b5, 19526), (0xf9b6, 19538), (0xf9b7, 19543),
+    (0xf9b8, 19545), (0xf9b9, 19562), (0xf9ba, 19736), (0xf9bb, 19737), (0xf9bc, 19745), (0xf9bd, 19746), (0xf9be, 19752), (0xf9bf, 19754),
+    (0xf9c0, 19756), (0xf9c1, 19759), (0xf9c2, 19862), (0xf9c3, 19867), (0xf9c4, 19899), (0xf9c5, 19942), (0xf9c6, 20078), (0xf9c7, 20114),
+    (0xf9c8, 20129), (0xf9c9, 20132), (0xf9ca, 20137), (0xf9cb, 20139), (0xf9cc, 20239), (0xf9cd, 20242), (0xf9ce, 20244), (0xf9cf, 20245),
+    (0xf9d0, 20261), (0xf9d1, 20262), (0xf9d2, 20264), (0xf9d3, 20268), (0xf9d4, 20269), (0xf9d5, 20273), (0xf9d6, 20274), (0xf9d7, 20279),
+    (0xf9d8, 20282), (0xf9d9, 20283), (0xf9da, 20284), (0xf9db, 20285), (0xf9dc, 20291), (0xf9dd, 20431), (0xf9de, 20432), (0xf9df, 20435),
+    (0xf9e0, 20440), (0xf9e1, 20441), (0xf9e2, 20442), (0xf9e3, 20443), (0xf9e4, 20446), (0xf9e5, 20449), (0xf9e6, 20451), (0xf9e7, 20457),
+    (0xf9e8, 20458), (0xf9e9, 20462), (0xf9ea, 20463), (0xf9eb, 20466), (0xf9ec, 20467), (0xf9ed, 20478), (0xf9ee, 20486), (0xf9ef, 20487),
+    (0xf9f0, 20490), (0xf9f1, 20493), (0xf9f2, 20496), (0xf9f3, 20497), (0xf9f4, 20512), (0xf9f5, 20513), (0xf9f6, 20515), (0xf9f7, 20616),
+    (0xf9f8, 20617), (0xf9f9, 20618), (0xf9fa, 20693), (0xf9fb, 20864), (0xf9fc, 21594), (0xf9fd, 21655), (0xf9fe, 21772), (0xf9ff, 21952),
+    (0xfa00, 22023), (0xfa01, 22409), (0xfa02, 22411), (0xfa03, 22539), (0xfa04, 22555), (0xfa05, 22566), (0xfa06, 22764), (0xfa07, 22785),
+    (0xfa08, 23086), (0xfa09, 23087), (0xfa0a, 23155), (0xfa0b, 23474), (0xff01, 6556), (0xff02, 6557), (0xff03, 6558), (0xff04, 6559),
+    (0xff05, 6560), (0xff06, 6561), (0xff07, 6562), (0xff08, 6563), (0xff09, 6564), (0xff0a, 6565), (0xff0b, 6566), (0xff0c, 6567),
+    (0xff0d, 6568), (0xff0e, 6569), (0xff0f, 6570), (0xff10, 6571), (0xff11, 6572), (0xff12, 6573), (0xff13, 6574), (0xff14, 6575),
+    (0xff15, 6576), (0xff16, 6577), (0xff17, 6578), (0xff18, 6579), (0xff19, 6580), (0xff1a, 6581), (0xff1b, 6582), (0xff1c, 6583),
+    (0xff1d, 6584), (0xff1e, 6585), (0xff1f, 6586), (0xff20, 6587), (0xff21, 6588), (0xff22, 6589), (0xff23, 6590), (0xff24, 6591),
+    (0xff25, 6592), (0xff26, 6593), (0xff27, 6594), (0xff28, 6595), (0xff29, 6596), (0xff2a, 6597), (0xff2b, 6598), (0xff2c, 6599),
+    (0xff2d, 6600), (0xff2e, 6601), (0xff2f, 6602), (0xff30, 6603), (0xff31, 6604), (0xff32, 6605), (0xff33, 6606), (0xff34, 6607),
+    (0xff35, 6608), (0xff36, 6609), (0xff37, 6610), (0xff38, 6611), (0xff39, 6612), (0xff3a, 6613), (0xff3b, 6614), (0xff3c, 6187),
+    (0xff3d, 6616), (0xff3e, 6617), (0xff3f, 6618), (0xff40, 6619), (0xff41, 6620), (0xff42, 6621), (0xff43, 6622), (0xff44, 6623),
+    (0xff45, 6624), (0xff46, 6625), (0xff47, 6626), (0xff48, 6627), (0xff49, 6628), (0xff4a, 6629), (0xff4b, 6630), (0xff4c, 6631),
+    (0xff4d, 6632), (0xff4e, 6633), (0xff4f, 6634), (0xff50, 6635), (0xff51, 6636), (0xff52, 6637), (0xff53, 6638), (0xff54, 6639),
+    (0xff55, 6640), (0xff56, 6641), (0xff57, 6642), (0xff58, 6643), (0xff59, 6644), (0xff5a, 6645), (0xff5b, 6646), (0xff5c, 6647),
+    (0xff5d, 6648), (0xff5e, 6371), (0xffe0, 6218), (0xffe1, 6219), (0xffe2, 6269), (0xffe3, 6649), (0xffe5, 6220), (0xffe6, 6615),
+];
+
+/// Returns the character at `pointer`, if any.
+pub(crate) fn decode(pointer: usize) -> Option<char> {
+    match TO_UNICODE.get(pointer) {
+        Some(&0) | None => None,
+        Some(&code_point) => char::from_u32(u32::from(code_point)),
+    }
+}
+
+/// Returns the pointer of `character`, if it is part of the character set.
+pub(crate) fn encode(character: char) -> Option<usize> {
+    let code_point = u16::try_from(u32::from(character)).ok()?;
+
+    FROM_UNICODE
+        .binary_search_by_key(&code_point, |&(code_point, _)| code_point)
+        .ok()
+        .map(|index| usize::from(FROM_UNICODE[index].1))
+}
@@ -0,0 +1,793 @@
+//! The implementation shared by the UTF-16 and UTF-32 charsets, which differ only in the length of
+//! their code units and in how the byte order is chosen.
+
+use crate::charsets::sequence::Sequence;
+
+/// Defines the charset of the enclosing module, along with its string, character, error, decoder,
+/// and encoder types.
+///
+/// `next` decodes the code units at the start of some bytes in a given byte order, and `write`
+/// encodes a character in a given byte order. The charset either always uses `byte_order`, or uses
+/// the byte order detected by `detect_byte_order` from a leading byte order mark, defaulting to
+/// big-endian. The latter kind also writes a byte order mark before any text it encodes.
+macro_rules! unicode_charset {
+    (
+        primary_name: $primary_name:literal,
+        mib_enum: $mib_enum:literal,
+        code_unit_length: $code_unit_length:literal,
+        next: $next:path,
+        write: $write:path,
+        byte_order: $byte_order:ident,
+        aliases: [
+            $(($variant:ident, $value:expr);)+
+        ],
+    ) => {
+        unicode_charset!(@common $primary_name, $mib_enum);
+
+        const BYTE_ORDER: ByteOrder = ByteOrder::$byte_order;
+
+        impl StrTrait for Str {
+            type Character = Character;
+            type DecodeError = DecodeError;
+            type State = ();
+            type String = String;
+
+            fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
+                sequence::validate(value, next).map_err(DecodeError)?;
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            unsafe fn decode_unchecked(value: &[u8]) -> &Self {
+                &*(value as *const [u8] as *const Str)
+            }
+
+            fn next_character(
+                &self,
+                index: usize,
+                _state: &mut Self::State,
+            ) -> Option<(Self::Character, usize)> {
+                match next(&self.0[index..])? {
+                    Sequence::Character(character, length) => Some((Character(character), length)),
+                    _ => unreachable!(concat!("string contains invalid ", $primary_name)),
+                }
+            }
+
+            fn to_unicode(&self) -> Cow<'_, str> {
+                Cow::Owned(self.chars().map(Character::to_char).collect())
+            }
+        }
+
+        impl StringTrait for String {
+            type DecodeError = DecodeError;
+            type EncodeError = EncodeError;
+            type Str = Str;
+
+            fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
+                if let Err(error) = sequence::validate(&value, next) {
+                    return Err((value, DecodeError(error)));
+                }
+
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+                let (text, replacements) = sequence::decode_lossy(value, next, replacement);
+                (Cow::Owned(text), replacements)
+            }
+
+            unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
+                String(value)
+            }
+
+            fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+                let mut bytes = Vec::with_capacity(value.len() * $code_unit_length);
+                let mut buffer = [0; 4];
+
+                for character in value.chars() {
+                    let length = $write(character, BYTE_ORDER, &mut buffer).unwrap();
+                    bytes.extend_from_slice(&buffer[..length]);
+                }
+
+                Ok(String(bytes))
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Decoder(SequenceDecoder);
+
+        impl DecoderTrait for Decoder {
+            type DecodeError = DecodeError;
+
+            fn decode_to_bytes(
+                &mut self,
+                input: &[u8],
+                output: &mut Vec<u8>,
+            ) -> Result<(), Self::DecodeError> {
+                self.0
+                    .decode(input, next, |bytes, _| output.extend_from_slice(bytes))
+                    .map_err(DecodeError)
+            }
+
+            fn decode_to_unicode(
+                &mut self,
+                input: &[u8],
+                output: &mut StdString,
+            ) -> Result<(), Self::DecodeError> {
+                self.0
+                    .decode(input, next, |_, characters| output.extend(characters))
+                    .map_err(DecodeError)
+            }
+
+            fn finish(self) -> Result<(), Self::DecodeError> {
+                self.0.finish().map_err(DecodeError)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Encoder;
+
+        impl EncoderTrait for Encoder {
+            type EncodeError = EncodeError;
+
+            fn encode(
+                &mut self,
+                input: &str,
+                output: &mut [u8],
+            ) -> Result<(usize, usize), Self::EncodeError> {
+                let mut read = 0;
+                let mut written = 0;
+
+                for character in input.chars() {
+                    match $write(character, BYTE_ORDER, &mut output[written..]) {
+                        Some(length) => {
+                            read += character.len_utf8();
+                            written += length;
+                        }
+                        None => break,
+                    }
+                }
+
+                Ok((read, written))
+            }
+
+            fn finish(&mut self, _output: &mut [u8]) -> Option<usize> {
+                Some(0)
+            }
+        }
+
+        fn next(value: &[u8]) -> Option<Sequence> {
+            $next(value, BYTE_ORDER)
+        }
+
+        aliases! {
+            Alias,
+
+            $(($variant, $value);)+
+        }
+    };
+
+    (
+        primary_name: $primary_name:literal,
+        mib_enum: $mib_enum:literal,
+        code_unit_length: $code_unit_length:literal,
+        next: $next:path,
+        write: $write:path,
+        detect_byte_order: $detect_byte_order:path,
+        aliases: [
+            $(($variant:ident, $value:expr);)+
+        ],
+    ) => {
+        unicode_charset!(@common $primary_name, $mib_enum);
+
+        const BOM: char = '\u{feff}';
+
+        /// The byte order used when there is no byte order mark, and when encoding.
+        const BYTE_ORDER: ByteOrder = ByteOrder::BigEndian;
+
+        impl StrTrait for Str {
+            type Character = Character;
+            type DecodeError = DecodeError;
+            type State = ();
+            type String = String;
+
+            fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
+                validate(value)?;
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            unsafe fn decode_unchecked(value: &[u8]) -> &Self {
+                &*(value as *const [u8] as *const Str)
+            }
+
+            /// A leading byte order mark is not part of the text, so it is skipped along with the
+            /// first character.
+            fn next_character(
+                &self,
+                index: usize,
+                _state: &mut Self::State,
+            ) -> Option<(Self::Character, usize)> {
+                let (byte_order, bom_length) = $detect_byte_order(&self.0);
+                let skipped = if index == 0 { bom_length } else { 0 };
+
+                match $next(&self.0[index + skipped..], byte_order)? {
+                    Sequence::Character(character, length) => {
+                        Some((Character(character), skipped + length))
+                    }
+                    _ => unreachable!(concat!("string contains invalid ", $primary_name)),
+                }
+            }
+
+            fn to_unicode(&self) -> Cow<'_, str> {
+                Cow::Owned(self.chars().map(Character::to_char).collect())
+            }
+        }
+
+        impl StringTrait for String {
+            type DecodeError = DecodeError;
+            type EncodeError = EncodeError;
+            type Str = Str;
+
+            fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
+                if let Err(error) = validate(&value) {
+                    return Err((value, error));
+                }
+
+                Ok(unsafe { Self::decode_unchecked(value) })
+            }
+
+            fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+                let (byte_order, bom_length) = $detect_byte_order(value);
+                let next = |value: &[u8]| $next(value, byte_order);
+                let (text, replacements) =
+                    sequence::decode_lossy(&value[bom_length..], next, replacement);
+                (Cow::Owned(text), replacements)
+            }
+
+            unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
+                String(value)
+            }
+
+            /// Encodes the text as big-endian, preceded by a byte order mark unless the text is
+            /// empty.
+            fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+                let mut bytes =
+                    Vec::with_capacity(value.len() * $code_unit_length + $code_unit_length);
+                let mut buffer = [0; 4];
+
+                for character in value.chars() {
+                    if bytes.is_empty() {
+                        let length = $write(BOM, BYTE_ORDER, &mut buffer).unwrap();
+                        bytes.extend_from_slice(&buffer[..length]);
+                    }
+
+                    let length = $write(character, BYTE_ORDER, &mut buffer).unwrap();
+                    bytes.extend_from_slice(&buffer[..length]);
+                }
+
+                Ok(String(bytes))
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Decoder {
+            byte_order: Option<ByteOrder>,
+            decoder: SequenceDecoder,
+            pending: [u8; $code_unit_length],
+            pending_length: usize,
+        }
+
+        impl Decoder {
+            /// Decodes the next chunk of input, passing the bytes of every complete character along
+            /// with the character itself to `emit`. A byte order mark is passed without a
+            /// character.
+            fn decode<F>(&mut self, mut input: &[u8], mut emit: F) -> Result<(), DecodeError>
+            where
+                F: FnMut(&[u8], &[char]),
+            {
+                let byte_order = match self.byte_order {
+                    Some(byte_order) => byte_order,
+                    None => {
+                        // The byte order can only be detected once the first code unit is known.
+                        let pending_length = self.pending_length;
+                        let length = (self.pending.len() - pending_length).min(input.len());
+                        self.pending[pending_length..pending_length + length]
+                            .copy_from_slice(&input[..length]);
+                        self.pending_length += length;
+                        input = &input[length..];
+
+                        if self.pending_length < self.pending.len() {
+                            return Ok(());
+                        }
+
+                        let (byte_order, bom_length) = $detect_byte_order(&self.pending);
+                        self.byte_order = Some(byte_order);
+
+                        if bom_length > 0 {
+                            emit(&self.pending, &[]);
+                            self.decoder.skip(bom_length);
+                        } else {
+                            let next = |value: &[u8]| $next(value, byte_order);
+                            self.decoder
+                                .decode(&self.pending, next, &mut emit)
+                                .map_err(DecodeError)?;
+                        }
+
+                        byte_order
+                    }
+                };
+
+                let next = |value: &[u8]| $next(value, byte_order);
+                self.decoder.decode(input, next, emit).map_err(DecodeError)
+            }
+        }
+
+        impl DecoderTrait for Decoder {
+            type DecodeError = DecodeError;
+
+            /// The byte order mark, if any, is passed through so that `output` keeps the byte
+            /// order of the input.
+            fn decode_to_bytes(
+                &mut self,
+                input: &[u8],
+                output: &mut Vec<u8>,
+            ) -> Result<(), Self::DecodeError> {
+                self.decode(input, |bytes, _| output.extend_from_slice(bytes))
+            }
+
+            fn decode_to_unicode(
+                &mut self,
+                input: &[u8],
+                output: &mut StdString,
+            ) -> Result<(), Self::DecodeError> {
+                self.decode(input, |_, characters| output.extend(characters))
+            }
+
+            fn finish(mut self) -> Result<(), Self::DecodeError> {
+                if self.byte_order.is_none() {
+                    // Too little input was given to detect the byte order, so it cannot contain a
+                    // complete character either.
+                    let pending = &self.pending[..self.pending_length];
+                    let next = |value: &[u8]| $next(value, BYTE_ORDER);
+                    self.decoder
+                        .decode(pending, next, |_, _| {})
+                        .map_err(DecodeError)?;
+                }
+
+                self.decoder.finish().map_err(DecodeError)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+        pub struct Encoder {
+            started: bool,
+        }
+
+        impl EncoderTrait for Encoder {
+            type EncodeError = EncodeError;
+
+            /// Encodes the text as big-endian, preceded by a byte order mark unless the text is
+            /// empty.
+            fn encode(
+                &mut self,
+                input: &str,
+                output: &mut [u8],
+            ) -> Result<(usize, usize), Self::EncodeError> {
+                let mut read = 0;
+                let mut written = 0;
+
+                if !self.started && !input.is_empty() {
+                    match $write(BOM, BYTE_ORDER, output) {
+                        Some(length) => written += length,
+                        None => return Ok((0, 0)),
+                    }
+
+                    self.started = true;
+                }
+
+                for character in input.chars() {
+                    match $write(character, BYTE_ORDER, &mut output[written..]) {
+                        Some(length) => {
+                            read += character.len_utf8();
+                            written += length;
+                        }
+                        None => break,
+                    }
+                }
+
+                Ok((read, written))
+            }
+
+            fn finish(&mut self, _output: &mut [u8]) -> Option<usize> {
+                Some(0)
+            }
+        }
+
+        fn validate(value: &[u8]) -> Result<(), DecodeError> {
+            let (byte_order, bom_length) = $detect_byte_order(value);
+            let next = |value: &[u8]| $next(value, byte_order);
+
+            sequence::validate(&value[bom_length..], next)
+                .map_err(|error| DecodeError(error.offset(bom_length)))
+        }
+
+        aliases! {
+            Alias,
+
+            $(($variant, $value);)+
+        }
+    };
+
+    (@common $primary_name:literal, $mib_enum:literal) => {
+        use std::borrow::{Borrow, Cow, ToOwned};
+        use std::convert::TryFrom;
+        use std::error::Error;
+        use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
+        use std::ops::Deref;
+        use std::string::String as StdString;
+
+        use crate::charset::private::Sealed;
+        use crate::charset::{
+            Character as CharacterTrait, Charset as CharsetTrait,
+            DecodeError as DecodeErrorTrait, Decoder as DecoderTrait,
+            EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
+            Str as StrTrait, String as StringTrait,
+        };
+        use crate::charsets::sequence::{self, Sequence, SequenceDecoder, SequenceError};
+        use crate::charsets::unicode::{self, ByteOrder};
+
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct Charset;
+
+        impl CharsetTrait for Charset {
+            type Alias = Alias;
+            type Character = Character;
+            type DecodeError = DecodeError;
+            type Decoder = Decoder;
+            type EncodeError = EncodeError;
+            type Encoder = Encoder;
+            type Str = Str;
+            type String = String;
+
+            const IS_ASCII_COMPATIBLE: bool = false;
+            const MIB_ENUM: u16 = $mib_enum;
+            const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> = None;
+            const PRIMARY_NAME: &'static UsAsciiStr =
+                unsafe { UsAsciiStr::from_bytes_unchecked($primary_name.as_bytes()) };
+        }
+
+        impl Sealed for Charset {}
+
+        #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Character(char);
+
+        impl CharacterTrait for Character {
+            /// Every code unit is at least 2 bytes long, so this always returns [`None`].
+            fn from_byte(_value: u8) -> Option<Self> {
+                None
+            }
+
+            fn to_byte(self) -> Option<u8> {
+                None
+            }
+
+            fn to_char(self) -> char {
+                self.0
+            }
+        }
+
+        impl Debug for Character {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_char(self.0)
+            }
+        }
+
+        impl Display for Character {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                formatter.write_char(self.0)
+            }
+        }
+
+        impl TryFrom<char> for Character {
+            type Error = EncodeError;
+
+            fn try_from(value: char) -> Result<Self, Self::Error> {
+                Ok(Character(value))
+            }
+        }
+
+        #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct Str([u8]);
+
+        impl AsRef<[u8]> for Str {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl Debug for Str {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                for character in self.chars() {
+                    Debug::fmt(&character, formatter)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl Display for Str {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(self, formatter)
+            }
+        }
+
+        impl Sealed for Str {}
+
+        impl ToOwned for Str {
+            type Owned = String;
+
+            fn to_owned(&self) -> Self::Owned {
+                String(self.0.to_owned())
+            }
+        }
+
+        #[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct String(Vec<u8>);
+
+        impl AsRef<[u8]> for String {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl AsRef<Str> for String {
+            fn as_ref(&self) -> &Str {
+                &self
+            }
+        }
+
+        impl Borrow<Str> for String {
+            fn borrow(&self) -> &Str {
+                &self
+            }
+        }
+
+        impl Debug for String {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(&**self, formatter)
+            }
+        }
+
+        impl Deref for String {
+            type Target = <Self as StringTrait>::Str;
+
+            fn deref(&self) -> &Self::Target {
+                unsafe { Str::decode_unchecked(&*self.0) }
+            }
+        }
+
+        impl Display for String {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                Debug::fmt(self, formatter)
+            }
+        }
+
+        impl<'str> From<&'str Str> for String {
+            fn from(value: &'str Str) -> Self {
+                value.to_owned()
+            }
+        }
+
+        impl From<String> for Vec<u8> {
+            fn from(value: String) -> Self {
+                value.0
+            }
+        }
+
+        impl Sealed for String {}
+
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct DecodeError(SequenceError);
+
+        impl DecodeErrorTrait for DecodeError {
+            fn error_len(&self) -> Option<usize> {
+                self.0.error_len()
+            }
+
+            fn invalid_bytes(&self) -> &[u8] {
+                self.0.invalid_bytes()
+            }
+
+            fn valid_up_to(&self) -> usize {
+                self.0.valid_up_to()
+            }
+        }
+
+        impl Display for DecodeError {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    concat!("{} ", $primary_name, " sequence {:02x?} at index {}"),
+                    self.0.kind(),
+                    self.invalid_bytes(),
+                    self.valid_up_to()
+                )
+            }
+        }
+
+        impl Error for DecodeError {}
+
+        /// Every Unicode scalar value can be encoded, so this error is never actually returned.
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct EncodeError {
+            character: char,
+            valid_up_to: usize,
+        }
+
+        impl EncodeErrorTrait for EncodeError {
+            fn character(&self) -> char {
+                self.character
+            }
+
+            fn valid_up_to(&self) -> usize {
+                self.valid_up_to
+            }
+        }
+
+        impl Display for EncodeError {
+            fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    concat!("character {:?} at index {} cannot be encoded as ", $primary_name),
+                    self.character, self.valid_up_to
+                )
+            }
+        }
+
+        impl Error for EncodeError {}
+    };
+}
+
+/// The order in which the bytes of a code unit are serialized.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum ByteOrder {
+    BigEndian,
+    LittleEndian,
+}
+
+impl ByteOrder {
+    /// Detects the byte order of UTF-16 from a leading byte order mark as described by RFC 2781,
+    /// defaulting to big-endian if there is none.
+    ///
+    /// Returns the byte order along with the length of the byte order mark.
+    pub(crate) fn detect_utf16(value: &[u8]) -> (Self, usize) {
+        match value {
+            [0xfe, 0xff, ..] => (ByteOrder::BigEndian, 2),
+            [0xff, 0xfe, ..] => (ByteOrder::LittleEndian, 2),
+            _ => (ByteOrder::BigEndian, 0),
+        }
+    }
+
+    /// Detects the byte order of UTF-32 from a leading byte order mark, defaulting to big-endian if
+    /// there is none.
+    ///
+    /// Returns the byte order along with the length of the byte order mark.
+    pub(crate) fn detect_utf32(value: &[u8]) -> (Self, usize) {
+        match value {
+            [0x00, 0x00, 0xfe, 0xff, ..] => (ByteOrder::BigEndian, 4),
+            [0xff, 0xfe, 0x00, 0x00, ..] => (ByteOrder::LittleEndian, 4),
+            _ => (ByteOrder::BigEndian, 0),
+        }
+    }
+
+    fn read_u16(self, value: [u8; 2]) -> u16 {
+        match self {
+            ByteOrder::BigEndian => u16::from_be_bytes(value),
+            ByteOrder::LittleEndian => u16::from_le_bytes(value),
+        }
+    }
+
+    fn read_u32(self, value: [u8; 4]) -> u32 {
+        match self {
+            ByteOrder::BigEndian => u32::from_be_bytes(value),
+            ByteOrder::LittleEndian => u32::from_le_bytes(value),
+        }
+    }
+
+    fn write_u16(self, value: u16) -> [u8; 2] {
+        match self {
+            ByteOrder::BigEndian => value.to_be_bytes(),
+            ByteOrder::LittleEndian => value.to_le_bytes(),
+        }
+    }
+
+    fn write_u32(self, value: u32) -> [u8; 4] {
+        match self {
+            ByteOrder::BigEndian => value.to_be_bytes(),
+            ByteOrder::LittleEndian => value.to_le_bytes(),
+        }
+    }
+}
+
+/// Decodes the UTF-16 sequence at the start of `value`, or returns [`None`] if it is empty.
+pub(crate) fn next_utf16(value: &[u8], byte_order: ByteOrder) -> Option<Sequence> {
+    let unit = match value {
+        [] => return None,
+        [_] => return Some(Sequence::Incomplete),
+        [first, second, ..] => byte_order.read_u16([*first, *second]),
+    };
+
+    let sequence = match unit {
+        0xd800..=0xdbff => match value {
+            [_, _, first, second, ..] => match byte_order.read_u16([*first, *second]) {
+                low @ 0xdc00..=0xdfff => {
+                    let scalar =
+                        0x10000 + ((u32::from(unit) - 0xd800) << 10) + (u32::from(low) - 0xdc00);
+
+                    // Unsafe justification: a surrogate pair always combines into a scalar value
+                    // between U+10000 and U+10FFFF.
+                    Sequence::Character(unsafe { std::char::from_u32_unchecked(scalar) }, 4)
+                }
+                _ => Sequence::Invalid(2),
+            },
+            _ => Sequence::Incomplete,
+        },
+        0xdc00..=0xdfff => Sequence::Invalid(2),
+        // Unsafe justification: every code unit outside of the surrogate range is a scalar value.
+        _ => Sequence::Character(unsafe { std::char::from_u32_unchecked(u32::from(unit)) }, 2),
+    };
+
+    Some(sequence)
+}
+
+/// Decodes the UTF-32 sequence at the start of `value`, or returns [`None`] if it is empty.
+pub(crate) fn next_utf32(value: &[u8], byte_order: ByteOrder) -> Option<Sequence> {
+    let sequence = match value {
+        [] => return None,
+        [first, second, third, fourth, ..] => {
+            match std::char::from_u32(byte_order.read_u32([*first, *second, *third, *fourth])) {
+                Some(character) => Sequence::Character(character, 4),
+                None => Sequence::Invalid(4),
+            }
+        }
+        _ => Sequence::Incomplete,
+    };
+
+    Some(sequence)
+}
+
+/// Encodes `character` as UTF-16 into `output`, returning the number of bytes written, or [`None`]
+/// if `output` is too small.
+pub(crate) fn write_utf16(
+    character: char,
+    byte_order: ByteOrder,
+    output: &mut [u8],
+) -> Option<usize> {
+    let mut units = [0; 2];
+    let units = character.encode_utf16(&mut units);
+    let length = units.len() * 2;
+
+    if output.len() < length {
+        return None;
+    }
+
+    for (index, &unit) in units.iter().enumerate() {
+        output[index * 2..index * 2 + 2].copy_from_slice(&byte_order.write_u16(unit));
+    }
+
+    Some(length)
+}
+
+/// Encodes `character` as UTF-32 into `output`, returning the number of bytes written, or [`None`]
+/// if `output` is too small.
+pub(crate) fn write_utf32(
+    character: char,
+    byte_order: ByteOrder,
+    output: &mut [u8],
+) -> Option<usize> {
+    if output.len() < 4 {
+        return None;
+    }
+
+    output[..4].copy_from_slice(&byte_order.write_u32(character as u32));
+    Some(4)
+}
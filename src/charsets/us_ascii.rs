@@ -1,12 +1,16 @@
-use std::borrow::{Borrow, ToOwned};
+use std::borrow::{Borrow, Cow, ToOwned};
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
 use std::ops::Deref;
+use std::slice;
 use std::str;
+use std::string::String as StdString;
 
 use crate::charset::private::Sealed;
 use crate::charset::{
     Character as CharacterTrait, Charset as CharsetTrait, DecodeError as DecodeErrorTrait,
+    Decoder as DecoderTrait, EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
     Str as StrTrait, String as StringTrait,
 };
 
@@ -17,9 +21,13 @@ impl CharsetTrait for Charset {
     type Alias = Alias;
     type Character = Character;
     type DecodeError = DecodeError;
+    type Decoder = Decoder;
+    type EncodeError = EncodeError;
+    type Encoder = Encoder;
     type Str = Str;
     type String = String;
 
+    const IS_ASCII_COMPATIBLE: bool = true;
     const MIB_ENUM: u16 = 3;
     const PREFERRED_MIME_NAME: Option<&'static Str> =
         Some(unsafe { Str::from_bytes_unchecked(b"US-ASCII") });
@@ -31,7 +39,23 @@ impl Sealed for Charset {}
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Character(u8);
 
-impl CharacterTrait for Character {}
+impl CharacterTrait for Character {
+    fn from_byte(value: u8) -> Option<Self> {
+        if value.is_ascii() {
+            Some(Character(value))
+        } else {
+            None
+        }
+    }
+
+    fn to_byte(self) -> Option<u8> {
+        Some(self.0)
+    }
+
+    fn to_char(self) -> char {
+        self.0 as char
+    }
+}
 
 impl Debug for Character {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
@@ -45,6 +69,17 @@ impl Display for Character {
     }
 }
 
+impl TryFrom<char> for Character {
+    type Error = EncodeError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        encode_character(value).map(Character).ok_or(EncodeError {
+            character: value,
+            valid_up_to: 0,
+        })
+    }
+}
+
 #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Str([u8]);
 
@@ -79,7 +114,9 @@ impl Display for Str {
 impl Sealed for Str {}
 
 impl StrTrait for Str {
+    type Character = Character;
     type DecodeError = DecodeError;
+    type State = ();
     type String = String;
 
     fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
@@ -90,6 +127,18 @@ impl StrTrait for Str {
     unsafe fn decode_unchecked(value: &[u8]) -> &Self {
         Self::from_bytes_unchecked(value)
     }
+
+    fn next_character(
+        &self,
+        index: usize,
+        _state: &mut Self::State,
+    ) -> Option<(Self::Character, usize)> {
+        self.0.get(index).map(|&byte| (Character(byte), 1))
+    }
+
+    fn to_unicode(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
 }
 
 impl ToOwned for Str {
@@ -157,6 +206,7 @@ impl Sealed for String {}
 
 impl StringTrait for String {
     type DecodeError = DecodeError;
+    type EncodeError = EncodeError;
     type Str = Str;
 
     fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
@@ -167,34 +217,234 @@ impl StringTrait for String {
         Ok(unsafe { Self::decode_unchecked(value) })
     }
 
+    fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, str>, usize) {
+        if validate(value).is_ok() {
+            return (unsafe { Str::decode_unchecked(value) }.to_unicode(), 0);
+        }
+
+        let mut replacements = 0;
+        let text = value
+            .iter()
+            .map(|&byte| {
+                if byte.is_ascii() {
+                    byte as char
+                } else {
+                    replacements += 1;
+                    replacement.character()
+                }
+            })
+            .collect();
+
+        (Cow::Owned(text), replacements)
+    }
+
     unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
         String(value)
     }
+
+    fn encode(value: &str) -> Result<Self, Self::EncodeError> {
+        let mut bytes = Vec::with_capacity(value.len());
+
+        for (index, character) in value.char_indices() {
+            match encode_character(character) {
+                Some(byte) => bytes.push(byte),
+                None => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: index,
+                    })
+                }
+            }
+        }
+
+        Ok(String(bytes))
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-pub struct DecodeError;
+pub struct DecodeError {
+    byte: u8,
+    valid_up_to: usize,
+}
 
-impl DecodeErrorTrait for DecodeError {}
+impl DecodeErrorTrait for DecodeError {
+    fn error_len(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn invalid_bytes(&self) -> &[u8] {
+        slice::from_ref(&self.byte)
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
 
 impl Display for DecodeError {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("invalid US-ASCII")
+        write!(
+            formatter,
+            "invalid US-ASCII byte {:#04x} at index {}",
+            self.byte, self.valid_up_to
+        )
     }
 }
 
 impl Error for DecodeError {}
 
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EncodeError {
+    character: char,
+    valid_up_to: usize,
+}
+
+impl EncodeErrorTrait for EncodeError {
+    fn character(&self) -> char {
+        self.character
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "character {:?} at index {} cannot be encoded as US-ASCII",
+            self.character, self.valid_up_to
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Decoder {
+    position: usize,
+}
+
+impl DecoderTrait for Decoder {
+    type DecodeError = DecodeError;
+
+    fn decode_to_bytes(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Self::DecodeError> {
+        match validate(input) {
+            Ok(()) => {
+                output.extend_from_slice(input);
+                self.position += input.len();
+                Ok(())
+            }
+            Err(error) => {
+                output.extend_from_slice(&input[..error.valid_up_to]);
+                self.position += error.valid_up_to;
+                Err(DecodeError {
+                    valid_up_to: self.position,
+                    ..error
+                })
+            }
+        }
+    }
+
+    fn decode_to_unicode(
+        &mut self,
+        input: &[u8],
+        output: &mut StdString,
+    ) -> Result<(), Self::DecodeError> {
+        for &byte in input {
+            match Character::from_byte(byte) {
+                Some(character) => output.push(character.to_char()),
+                None => {
+                    return Err(DecodeError {
+                        byte,
+                        valid_up_to: self.position,
+                    })
+                }
+            }
+
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), Self::DecodeError> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Encoder {
+    position: usize,
+}
+
+impl EncoderTrait for Encoder {
+    type EncodeError = EncodeError;
+
+    fn encode(
+        &mut self,
+        input: &str,
+        output: &mut [u8],
+    ) -> Result<(usize, usize), Self::EncodeError> {
+        let mut read = 0;
+        let mut written = 0;
+
+        for character in input.chars() {
+            if written == output.len() {
+                break;
+            }
+
+            match encode_character(character) {
+                Some(byte) => {
+                    output[written] = byte;
+                    read += character.len_utf8();
+                    written += 1;
+                }
+                None if read == 0 => {
+                    return Err(EncodeError {
+                        character,
+                        valid_up_to: self.position,
+                    })
+                }
+                None => break,
+            }
+        }
+
+        self.position += read;
+        Ok((read, written))
+    }
+
+    fn finish(&mut self, _output: &mut [u8]) -> Option<usize> {
+        Some(0)
+    }
+}
+
 fn validate(value: &[u8]) -> Result<(), DecodeError> {
-    for byte in value {
+    for (index, &byte) in value.iter().enumerate() {
         if !byte.is_ascii() {
-            return Err(DecodeError);
+            return Err(DecodeError {
+                byte,
+                valid_up_to: index,
+            });
         }
     }
 
     Ok(())
 }
 
+fn encode_character(character: char) -> Option<u8> {
+    if character.is_ascii() {
+        Some(character as u8)
+    } else {
+        None
+    }
+}
+
 aliases! {
     Alias,
 
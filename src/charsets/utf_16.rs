@@ -0,0 +1,88 @@
+unicode_charset! {
+    primary_name: "UTF-16",
+    mib_enum: 1015,
+    code_unit_length: 2,
+    next: unicode::next_utf16,
+    write: unicode::write_utf16,
+    detect_byte_order: ByteOrder::detect_utf16,
+    aliases: [
+        (CsUtf16, b"csUTF16");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_chunks(chunks: &[&[u8]]) -> Result<StdString, DecodeError> {
+        let mut decoder = Decoder::default();
+        let mut output = StdString::new();
+
+        for chunk in chunks {
+            decoder.decode_to_unicode(chunk, &mut output)?;
+        }
+
+        decoder.finish()?;
+        Ok(output)
+    }
+
+    #[test]
+    fn rejects_lone_surrogates() {
+        let error = Str::decode(b"\x00a\xd8\x3d\x00b").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\xd8\x3d");
+        assert_eq!(error.error_len(), Some(2));
+
+        let error = Str::decode(b"\x00a\xde\x00").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.error_len(), Some(2));
+
+        let error = Str::decode(b"\x00a\xd8\x3d").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.error_len(), None);
+    }
+
+    #[test]
+    fn rejects_odd_trailing_byte() {
+        let error = Str::decode(b"\x00a\x00").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\x00");
+        assert_eq!(error.error_len(), None);
+
+        let error = decode_chunks(&[b"\x00a", b"\x00"]).unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.error_len(), None);
+    }
+
+    #[test]
+    fn detects_byte_order_mark_split_across_chunks() {
+        assert_eq!(decode_chunks(&[b"\xff", b"\xfea\x00"]).unwrap(), "a");
+        assert_eq!(decode_chunks(&[b"\xfe", b"\xff\x00a"]).unwrap(), "a");
+        assert_eq!(
+            decode_chunks(&[b"\xff", b"\xfe", b"=\xd8", b"\x00\xde"]).unwrap(),
+            "\u{1f600}"
+        );
+    }
+
+    #[test]
+    fn defaults_to_big_endian_without_byte_order_mark() {
+        assert_eq!(
+            Str::decode(b"\x00a\x30\x42").unwrap().to_unicode(),
+            "a\u{3042}"
+        );
+        assert_eq!(
+            decode_chunks(&[b"\x00", b"a\x30", b"\x42"]).unwrap(),
+            "a\u{3042}"
+        );
+    }
+
+    #[test]
+    fn char_indices_include_byte_order_mark_in_first_character() {
+        let string = Str::decode(b"\xff\xfea\x00\x3d\xd8\x00\xdeb\x00").unwrap();
+        let characters: Vec<_> = string
+            .char_indices()
+            .map(|(index, character)| (index, character.to_char()))
+            .collect();
+        assert_eq!(characters, [(0, 'a'), (4, '\u{1f600}'), (8, 'b')]);
+    }
+}
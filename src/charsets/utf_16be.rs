@@ -0,0 +1,11 @@
+unicode_charset! {
+    primary_name: "UTF-16BE",
+    mib_enum: 1013,
+    code_unit_length: 2,
+    next: unicode::next_utf16,
+    write: unicode::write_utf16,
+    byte_order: BigEndian,
+    aliases: [
+        (CsUtf16Be, b"csUTF16BE");
+    ],
+}
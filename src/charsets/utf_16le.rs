@@ -0,0 +1,11 @@
+unicode_charset! {
+    primary_name: "UTF-16LE",
+    mib_enum: 1014,
+    code_unit_length: 2,
+    next: unicode::next_utf16,
+    write: unicode::write_utf16,
+    byte_order: LittleEndian,
+    aliases: [
+        (CsUtf16Le, b"csUTF16LE");
+    ],
+}
@@ -0,0 +1,81 @@
+unicode_charset! {
+    primary_name: "UTF-32",
+    mib_enum: 1017,
+    code_unit_length: 4,
+    next: unicode::next_utf32,
+    write: unicode::write_utf32,
+    detect_byte_order: ByteOrder::detect_utf32,
+    aliases: [
+        (CsUtf32, b"csUTF32");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_chunks(chunks: &[&[u8]]) -> Result<StdString, DecodeError> {
+        let mut decoder = Decoder::default();
+        let mut output = StdString::new();
+
+        for chunk in chunks {
+            decoder.decode_to_unicode(chunk, &mut output)?;
+        }
+
+        decoder.finish()?;
+        Ok(output)
+    }
+
+    #[test]
+    fn rejects_surrogates_and_values_beyond_unicode() {
+        let error = Str::decode(b"\x00\x00\x00a\x00\x00\xd8\x3d").unwrap_err();
+        assert_eq!(error.valid_up_to(), 4);
+        assert_eq!(error.invalid_bytes(), b"\x00\x00\xd8\x3d");
+        assert_eq!(error.error_len(), Some(4));
+
+        let error = Str::decode(b"\x00\x11\x00\x00").unwrap_err();
+        assert_eq!(error.valid_up_to(), 0);
+        assert_eq!(error.error_len(), Some(4));
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let error = Str::decode(b"\x00\x00\x00a\x00\x00").unwrap_err();
+        assert_eq!(error.valid_up_to(), 4);
+        assert_eq!(error.invalid_bytes(), b"\x00\x00");
+        assert_eq!(error.error_len(), None);
+
+        let error = decode_chunks(&[b"\x00\x00\x00a\x00", b"\x00\x00"]).unwrap_err();
+        assert_eq!(error.valid_up_to(), 4);
+        assert_eq!(error.error_len(), None);
+    }
+
+    #[test]
+    fn detects_byte_order_mark_split_across_chunks() {
+        assert_eq!(
+            decode_chunks(&[b"\xff\xfe", b"\x00\x00a\x00\x00\x00"]).unwrap(),
+            "a"
+        );
+        assert_eq!(
+            decode_chunks(&[b"\x00", b"\x00\xfe", b"\xff\x00\x00\x00a"]).unwrap(),
+            "a"
+        );
+    }
+
+    #[test]
+    fn defaults_to_big_endian_without_byte_order_mark() {
+        let string = Str::decode(b"\x00\x00\x00a\x00\x01\xf6\x00").unwrap();
+        assert_eq!(string.to_unicode(), "a\u{1f600}");
+        assert_eq!(decode_chunks(&[b"\x00\x00", b"\x00a"]).unwrap(), "a");
+    }
+
+    #[test]
+    fn char_indices_include_byte_order_mark_in_first_character() {
+        let string = Str::decode(b"\xff\xfe\x00\x00a\x00\x00\x00\x00\xf6\x01\x00").unwrap();
+        let characters: Vec<_> = string
+            .char_indices()
+            .map(|(index, character)| (index, character.to_char()))
+            .collect();
+        assert_eq!(characters, [(0, 'a'), (8, '\u{1f600}')]);
+    }
+}
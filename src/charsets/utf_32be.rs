@@ -0,0 +1,11 @@
+unicode_charset! {
+    primary_name: "UTF-32BE",
+    mib_enum: 1018,
+    code_unit_length: 4,
+    next: unicode::next_utf32,
+    write: unicode::write_utf32,
+    byte_order: BigEndian,
+    aliases: [
+        (CsUtf32Be, b"csUTF32BE");
+    ],
+}
@@ -0,0 +1,11 @@
+unicode_charset! {
+    primary_name: "UTF-32LE",
+    mib_enum: 1019,
+    code_unit_length: 4,
+    next: unicode::next_utf32,
+    write: unicode::write_utf32,
+    byte_order: LittleEndian,
+    aliases: [
+        (CsUtf32Le, b"csUTF32LE");
+    ],
+}
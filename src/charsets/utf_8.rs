@@ -1,13 +1,15 @@
-use std::borrow::{Borrow, ToOwned};
+use std::borrow::{Borrow, Cow, ToOwned};
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult, Write};
 use std::ops::Deref;
-use std::str;
+use std::str::{self, Utf8Error};
 use std::string::String as StdString;
 
 use crate::charset::private::Sealed;
 use crate::charset::{
     Character as CharacterTrait, Charset as CharsetTrait, DecodeError as DecodeErrorTrait,
+    Decoder as DecoderTrait, EncodeError as EncodeErrorTrait, Encoder as EncoderTrait, Replacement,
     Str as StrTrait, String as StringTrait,
 };
 
@@ -20,13 +22,16 @@ impl CharsetTrait for Charset {
     type Alias = Alias;
     type Character = Character;
     type DecodeError = DecodeError;
+    type Decoder = Decoder;
+    type EncodeError = EncodeError;
+    type Encoder = Encoder;
     type Str = Str;
     type String = String;
 
+    const IS_ASCII_COMPATIBLE: bool = true;
     const MIB_ENUM: u16 = 106;
     const PREFERRED_MIME_NAME: Option<&'static UsAsciiStr> = None;
-    const PRIMARY_NAME: &'static UsAsciiStr =
-        unsafe { UsAsciiStr::from_bytes_unchecked(b"US-ASCII") };
+    const PRIMARY_NAME: &'static UsAsciiStr = unsafe { UsAsciiStr::from_bytes_unchecked(b"UTF-8") };
 }
 
 impl Sealed for Charset {}
@@ -34,7 +39,27 @@ impl Sealed for Charset {}
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Character(char);
 
-impl CharacterTrait for Character {}
+impl CharacterTrait for Character {
+    fn from_byte(value: u8) -> Option<Self> {
+        if value.is_ascii() {
+            Some(Character(value as char))
+        } else {
+            None
+        }
+    }
+
+    fn to_byte(self) -> Option<u8> {
+        if self.0.is_ascii() {
+            Some(self.0 as u8)
+        } else {
+            None
+        }
+    }
+
+    fn to_char(self) -> char {
+        self.0
+    }
+}
 
 impl Debug for Character {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
@@ -48,6 +73,14 @@ impl Display for Character {
     }
 }
 
+impl TryFrom<char> for Character {
+    type Error = EncodeError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(Character(value))
+    }
+}
+
 #[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Str(StdStr);
 
@@ -66,17 +99,34 @@ impl Display for Str {
 impl Sealed for Str {}
 
 impl StrTrait for Str {
+    type Character = Character;
     type DecodeError = DecodeError;
+    type State = ();
     type String = String;
 
     fn decode(value: &[u8]) -> Result<&Self, Self::DecodeError> {
-        let value = str::from_utf8(value).map_err(|_| DecodeError)?;
+        let value = str::from_utf8(value).map_err(|error| DecodeError::new(value, error))?;
         Ok(unsafe { &*(value as *const StdStr as *const Str) })
     }
 
     unsafe fn decode_unchecked(value: &[u8]) -> &Self {
         &*(value as *const [u8] as *const Str)
     }
+
+    fn next_character(
+        &self,
+        index: usize,
+        _state: &mut Self::State,
+    ) -> Option<(Self::Character, usize)> {
+        self.0[index..]
+            .chars()
+            .next()
+            .map(|character| (Character(character), character.len_utf8()))
+    }
+
+    fn to_unicode(&self) -> Cow<'_, StdStr> {
+        Cow::Borrowed(&self.0)
+    }
 }
 
 impl ToOwned for Str {
@@ -138,33 +188,285 @@ impl Sealed for String {}
 
 impl StringTrait for String {
     type DecodeError = DecodeError;
+    type EncodeError = EncodeError;
     type Str = Str;
 
     fn decode(value: Vec<u8>) -> Result<Self, (Vec<u8>, Self::DecodeError)> {
-        let value =
-            StdString::from_utf8(value).map_err(|error| (error.into_bytes(), DecodeError))?;
+        let value = StdString::from_utf8(value).map_err(|error| {
+            let decode_error = DecodeError::new(error.as_bytes(), error.utf8_error());
+            (error.into_bytes(), decode_error)
+        })?;
 
         Ok(String(value))
     }
 
+    fn decode_lossy(value: &[u8], replacement: Replacement) -> (Cow<'_, StdStr>, usize) {
+        let mut remaining = match str::from_utf8(value) {
+            Ok(value) => return (Cow::Borrowed(value), 0),
+            Err(_) => value,
+        };
+        let mut text = StdString::with_capacity(value.len());
+        let mut replacements = 0;
+
+        loop {
+            match str::from_utf8(remaining) {
+                Ok(valid) => {
+                    text.push_str(valid);
+                    break;
+                }
+                Err(error) => {
+                    let (valid, invalid) = remaining.split_at(error.valid_up_to());
+                    text.push_str(unsafe { str::from_utf8_unchecked(valid) });
+                    text.push(replacement.character());
+                    replacements += 1;
+
+                    match error.error_len() {
+                        Some(length) => remaining = &invalid[length..],
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        (Cow::Owned(text), replacements)
+    }
+
     unsafe fn decode_unchecked(value: Vec<u8>) -> Self {
         String(StdString::from_utf8_unchecked(value))
     }
+
+    fn encode(value: &StdStr) -> Result<Self, Self::EncodeError> {
+        Ok(String(value.to_owned()))
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
-pub struct DecodeError;
+pub struct DecodeError {
+    error_len: Option<u8>,
+    invalid_bytes: [u8; 3],
+    invalid_length: u8,
+    valid_up_to: usize,
+}
 
-impl DecodeErrorTrait for DecodeError {}
+impl DecodeError {
+    fn new(value: &[u8], error: Utf8Error) -> Self {
+        let valid_up_to = error.valid_up_to();
+        let invalid = &value[valid_up_to..];
+        let invalid_length = error.error_len().unwrap_or(invalid.len());
+        let mut invalid_bytes = [0; 3];
+        invalid_bytes[..invalid_length].copy_from_slice(&invalid[..invalid_length]);
+
+        DecodeError {
+            error_len: error.error_len().map(|length| length as u8),
+            invalid_bytes,
+            invalid_length: invalid_length as u8,
+            valid_up_to,
+        }
+    }
+
+    fn offset(self, offset: usize) -> Self {
+        DecodeError {
+            valid_up_to: self.valid_up_to + offset,
+            ..self
+        }
+    }
+}
+
+impl DecodeErrorTrait for DecodeError {
+    fn error_len(&self) -> Option<usize> {
+        self.error_len.map(usize::from)
+    }
+
+    fn invalid_bytes(&self) -> &[u8] {
+        &self.invalid_bytes[..self.invalid_length as usize]
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
 
 impl Display for DecodeError {
     fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
-        formatter.write_str("invalid UTF-8")
+        let kind = if self.error_len.is_some() {
+            "invalid"
+        } else {
+            "incomplete"
+        };
+
+        write!(
+            formatter,
+            "{} UTF-8 sequence {:02x?} at index {}",
+            kind,
+            self.invalid_bytes(),
+            self.valid_up_to
+        )
     }
 }
 
 impl Error for DecodeError {}
 
+/// UTF-8 can represent every Unicode scalar value, so this error is never actually returned.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EncodeError {
+    character: char,
+    valid_up_to: usize,
+}
+
+impl EncodeErrorTrait for EncodeError {
+    fn character(&self) -> char {
+        self.character
+    }
+
+    fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, formatter: &mut Formatter) -> FmtResult {
+        write!(
+            formatter,
+            "character {:?} at index {} cannot be encoded as UTF-8",
+            self.character, self.valid_up_to
+        )
+    }
+}
+
+impl Error for EncodeError {}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Decoder {
+    partial: [u8; 4],
+    partial_length: usize,
+    position: usize,
+}
+
+impl Decoder {
+    fn decode<F>(&mut self, mut input: &[u8], mut emit: F) -> Result<(), DecodeError>
+    where
+        F: FnMut(&StdStr),
+    {
+        if self.partial_length > 0 {
+            // Complete the pending sequence using the start of the input. Since at most 4 bytes
+            // are ever validated here, this is cheap even if the sequence is still incomplete.
+            let partial_length = self.partial_length;
+            let length = (self.partial.len() - partial_length).min(input.len());
+            self.partial[partial_length..partial_length + length].copy_from_slice(&input[..length]);
+            let pending = &self.partial[..partial_length + length];
+
+            let valid_up_to = match str::from_utf8(pending) {
+                Ok(value) => {
+                    emit(value);
+                    pending.len()
+                }
+                Err(error) if error.valid_up_to() > 0 => {
+                    let valid_up_to = error.valid_up_to();
+                    emit(unsafe { str::from_utf8_unchecked(&pending[..valid_up_to]) });
+                    valid_up_to
+                }
+                Err(error) if error.error_len().is_some() => {
+                    return Err(DecodeError::new(pending, error).offset(self.position));
+                }
+                Err(_) => {
+                    self.partial_length += length;
+                    return Ok(());
+                }
+            };
+
+            input = &input[valid_up_to - partial_length..];
+            self.partial_length = 0;
+            self.position += valid_up_to;
+        }
+
+        match str::from_utf8(input) {
+            Ok(value) => {
+                emit(value);
+                self.position += input.len();
+                Ok(())
+            }
+            Err(error) => {
+                let valid_up_to = error.valid_up_to();
+                emit(unsafe { str::from_utf8_unchecked(&input[..valid_up_to]) });
+
+                if error.error_len().is_some() {
+                    let error = DecodeError::new(input, error).offset(self.position);
+                    self.position += valid_up_to;
+                    return Err(error);
+                }
+
+                let remaining = &input[valid_up_to..];
+                self.partial[..remaining.len()].copy_from_slice(remaining);
+                self.partial_length = remaining.len();
+                self.position += valid_up_to;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl DecoderTrait for Decoder {
+    type DecodeError = DecodeError;
+
+    fn decode_to_bytes(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Self::DecodeError> {
+        self.decode(input, |value| output.extend_from_slice(value.as_bytes()))
+    }
+
+    fn decode_to_unicode(
+        &mut self,
+        input: &[u8],
+        output: &mut StdString,
+    ) -> Result<(), Self::DecodeError> {
+        self.decode(input, |value| output.push_str(value))
+    }
+
+    fn finish(self) -> Result<(), Self::DecodeError> {
+        if self.partial_length == 0 {
+            return Ok(());
+        }
+
+        let mut invalid_bytes = [0; 3];
+        invalid_bytes[..self.partial_length].copy_from_slice(&self.partial[..self.partial_length]);
+
+        Err(DecodeError {
+            error_len: None,
+            invalid_bytes,
+            invalid_length: self.partial_length as u8,
+            valid_up_to: self.position,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Encoder;
+
+impl EncoderTrait for Encoder {
+    type EncodeError = EncodeError;
+
+    fn encode(
+        &mut self,
+        input: &StdStr,
+        output: &mut [u8],
+    ) -> Result<(usize, usize), Self::EncodeError> {
+        let mut length = input.len().min(output.len());
+
+        while !input.is_char_boundary(length) {
+            length -= 1;
+        }
+
+        output[..length].copy_from_slice(&input.as_bytes()[..length]);
+        Ok((length, length))
+    }
+
+    fn finish(&mut self, _output: &mut [u8]) -> Option<usize> {
+        Some(0)
+    }
+}
+
 aliases! {
     Alias,
 
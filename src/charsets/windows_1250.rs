@@ -0,0 +1,57 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{20ac}', '\0',       '\u{201a}', '\0',       '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}', '\0',       '\u{2030}', '\u{0160}', '\u{2039}', '\u{015a}', '\u{0164}', '\u{017d}', '\u{0179}',
+    '\0',       '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}', '\0',       '\u{2122}', '\u{0161}', '\u{203a}', '\u{015b}', '\u{0165}', '\u{017e}', '\u{017a}',
+    '\u{00a0}', '\u{02c7}', '\u{02d8}', '\u{0141}', '\u{00a4}', '\u{0104}', '\u{00a6}', '\u{00a7}', '\u{00a8}', '\u{00a9}', '\u{015e}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{017b}',
+    '\u{00b0}', '\u{00b1}', '\u{02db}', '\u{0142}', '\u{00b4}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{00b8}', '\u{0105}', '\u{015f}', '\u{00bb}', '\u{013d}', '\u{02dd}', '\u{013e}', '\u{017c}',
+    '\u{0154}', '\u{00c1}', '\u{00c2}', '\u{0102}', '\u{00c4}', '\u{0139}', '\u{0106}', '\u{00c7}', '\u{010c}', '\u{00c9}', '\u{0118}', '\u{00cb}', '\u{011a}', '\u{00cd}', '\u{00ce}', '\u{010e}',
+    '\u{0110}', '\u{0143}', '\u{0147}', '\u{00d3}', '\u{00d4}', '\u{0150}', '\u{00d6}', '\u{00d7}', '\u{0158}', '\u{016e}', '\u{00da}', '\u{0170}', '\u{00dc}', '\u{00dd}', '\u{0162}', '\u{00df}',
+    '\u{0155}', '\u{00e1}', '\u{00e2}', '\u{0103}', '\u{00e4}', '\u{013a}', '\u{0107}', '\u{00e7}', '\u{010d}', '\u{00e9}', '\u{0119}', '\u{00eb}', '\u{011b}', '\u{00ed}', '\u{00ee}', '\u{010f}',
+    '\u{0111}', '\u{0144}', '\u{0148}', '\u{00f3}', '\u{00f4}', '\u{0151}', '\u{00f6}', '\u{00f7}', '\u{0159}', '\u{016f}', '\u{00fa}', '\u{0171}', '\u{00fc}', '\u{00fd}', '\u{0163}', '\u{02d9}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 123] = [
+    ('\u{00a0}', 0xa0), ('\u{00a4}', 0xa4), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a8}', 0xa8), ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab), ('\u{00ac}', 0xac),
+    ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1), ('\u{00b4}', 0xb4), ('\u{00b5}', 0xb5), ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7),
+    ('\u{00b8}', 0xb8), ('\u{00bb}', 0xbb), ('\u{00c1}', 0xc1), ('\u{00c2}', 0xc2), ('\u{00c4}', 0xc4), ('\u{00c7}', 0xc7), ('\u{00c9}', 0xc9), ('\u{00cb}', 0xcb),
+    ('\u{00cd}', 0xcd), ('\u{00ce}', 0xce), ('\u{00d3}', 0xd3), ('\u{00d4}', 0xd4), ('\u{00d6}', 0xd6), ('\u{00d7}', 0xd7), ('\u{00da}', 0xda), ('\u{00dc}', 0xdc),
+    ('\u{00dd}', 0xdd), ('\u{00df}', 0xdf), ('\u{00e1}', 0xe1), ('\u{00e2}', 0xe2), ('\u{00e4}', 0xe4), ('\u{00e7}', 0xe7), ('\u{00e9}', 0xe9), ('\u{00eb}', 0xeb),
+    ('\u{00ed}', 0xed), ('\u{00ee}', 0xee), ('\u{00f3}', 0xf3), ('\u{00f4}', 0xf4), ('\u{00f6}', 0xf6), ('\u{00f7}', 0xf7), ('\u{00fa}', 0xfa), ('\u{00fc}', 0xfc),
+    ('\u{00fd}', 0xfd), ('\u{0102}', 0xc3), ('\u{0103}', 0xe3), ('\u{0104}', 0xa5), ('\u{0105}', 0xb9), ('\u{0106}', 0xc6), ('\u{0107}', 0xe6), ('\u{010c}', 0xc8),
+    ('\u{010d}', 0xe8), ('\u{010e}', 0xcf), ('\u{010f}', 0xef), ('\u{0110}', 0xd0), ('\u{0111}', 0xf0), ('\u{0118}', 0xca), ('\u{0119}', 0xea), ('\u{011a}', 0xcc),
+    ('\u{011b}', 0xec), ('\u{0139}', 0xc5), ('\u{013a}', 0xe5), ('\u{013d}', 0xbc), ('\u{013e}', 0xbe), ('\u{0141}', 0xa3), ('\u{0142}', 0xb3), ('\u{0143}', 0xd1),
+    ('\u{0144}', 0xf1), ('\u{0147}', 0xd2), ('\u{0148}', 0xf2), ('\u{0150}', 0xd5), ('\u{0151}', 0xf5), ('\u{0154}', 0xc0), ('\u{0155}', 0xe0), ('\u{0158}', 0xd8),
+    ('\u{0159}', 0xf8), ('\u{015a}', 0x8c), ('\u{015b}', 0x9c), ('\u{015e}', 0xaa), ('\u{015f}', 0xba), ('\u{0160}', 0x8a), ('\u{0161}', 0x9a), ('\u{0162}', 0xde),
+    ('\u{0163}', 0xfe), ('\u{0164}', 0x8d), ('\u{0165}', 0x9d), ('\u{016e}', 0xd9), ('\u{016f}', 0xf9), ('\u{0170}', 0xdb), ('\u{0171}', 0xfb), ('\u{0179}', 0x8f),
+    ('\u{017a}', 0x9f), ('\u{017b}', 0xaf), ('\u{017c}', 0xbf), ('\u{017d}', 0x8e), ('\u{017e}', 0x9e), ('\u{02c7}', 0xa1), ('\u{02d8}', 0xa2), ('\u{02d9}', 0xff),
+    ('\u{02db}', 0xb2), ('\u{02dd}', 0xbd), ('\u{2013}', 0x96), ('\u{2014}', 0x97), ('\u{2018}', 0x91), ('\u{2019}', 0x92), ('\u{201a}', 0x82), ('\u{201c}', 0x93),
+    ('\u{201d}', 0x94), ('\u{201e}', 0x84), ('\u{2020}', 0x86), ('\u{2021}', 0x87), ('\u{2022}', 0x95), ('\u{2026}', 0x85), ('\u{2030}', 0x89), ('\u{2039}', 0x8b),
+    ('\u{203a}', 0x9b), ('\u{20ac}', 0x80), ('\u{2122}', 0x99),
+];
+
+single_byte_charset! {
+    primary_name: "windows-1250",
+    mib_enum: 2250,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsWindows1250, b"cswindows1250");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0x83), None);
+        let error = Str::decode(b"ab\x83").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\x83");
+
+        let string = Str::decode(b"\x8a").unwrap();
+        assert_eq!(string.to_unicode(), "\u{160}");
+    }
+}
@@ -0,0 +1,57 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{0402}', '\u{0403}', '\u{201a}', '\u{0453}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}', '\u{20ac}', '\u{2030}', '\u{0409}', '\u{2039}', '\u{040a}', '\u{040c}', '\u{040b}', '\u{040f}',
+    '\u{0452}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}', '\0',       '\u{2122}', '\u{0459}', '\u{203a}', '\u{045a}', '\u{045c}', '\u{045b}', '\u{045f}',
+    '\u{00a0}', '\u{040e}', '\u{045e}', '\u{0408}', '\u{00a4}', '\u{0490}', '\u{00a6}', '\u{00a7}', '\u{0401}', '\u{00a9}', '\u{0404}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{0407}',
+    '\u{00b0}', '\u{00b1}', '\u{0406}', '\u{0456}', '\u{0491}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{0451}', '\u{2116}', '\u{0454}', '\u{00bb}', '\u{0458}', '\u{0405}', '\u{0455}', '\u{0457}',
+    '\u{0410}', '\u{0411}', '\u{0412}', '\u{0413}', '\u{0414}', '\u{0415}', '\u{0416}', '\u{0417}', '\u{0418}', '\u{0419}', '\u{041a}', '\u{041b}', '\u{041c}', '\u{041d}', '\u{041e}', '\u{041f}',
+    '\u{0420}', '\u{0421}', '\u{0422}', '\u{0423}', '\u{0424}', '\u{0425}', '\u{0426}', '\u{0427}', '\u{0428}', '\u{0429}', '\u{042a}', '\u{042b}', '\u{042c}', '\u{042d}', '\u{042e}', '\u{042f}',
+    '\u{0430}', '\u{0431}', '\u{0432}', '\u{0433}', '\u{0434}', '\u{0435}', '\u{0436}', '\u{0437}', '\u{0438}', '\u{0439}', '\u{043a}', '\u{043b}', '\u{043c}', '\u{043d}', '\u{043e}', '\u{043f}',
+    '\u{0440}', '\u{0441}', '\u{0442}', '\u{0443}', '\u{0444}', '\u{0445}', '\u{0446}', '\u{0447}', '\u{0448}', '\u{0449}', '\u{044a}', '\u{044b}', '\u{044c}', '\u{044d}', '\u{044e}', '\u{044f}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 127] = [
+    ('\u{00a0}', 0xa0), ('\u{00a4}', 0xa4), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab), ('\u{00ac}', 0xac), ('\u{00ad}', 0xad),
+    ('\u{00ae}', 0xae), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1), ('\u{00b5}', 0xb5), ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7), ('\u{00bb}', 0xbb), ('\u{0401}', 0xa8),
+    ('\u{0402}', 0x80), ('\u{0403}', 0x81), ('\u{0404}', 0xaa), ('\u{0405}', 0xbd), ('\u{0406}', 0xb2), ('\u{0407}', 0xaf), ('\u{0408}', 0xa3), ('\u{0409}', 0x8a),
+    ('\u{040a}', 0x8c), ('\u{040b}', 0x8e), ('\u{040c}', 0x8d), ('\u{040e}', 0xa1), ('\u{040f}', 0x8f), ('\u{0410}', 0xc0), ('\u{0411}', 0xc1), ('\u{0412}', 0xc2),
+    ('\u{0413}', 0xc3), ('\u{0414}', 0xc4), ('\u{0415}', 0xc5), ('\u{0416}', 0xc6), ('\u{0417}', 0xc7), ('\u{0418}', 0xc8), ('\u{0419}', 0xc9), ('\u{041a}', 0xca),
+    ('\u{041b}', 0xcb), ('\u{041c}', 0xcc), ('\u{041d}', 0xcd), ('\u{041e}', 0xce), ('\u{041f}', 0xcf), ('\u{0420}', 0xd0), ('\u{0421}', 0xd1), ('\u{0422}', 0xd2),
+    ('\u{0423}', 0xd3), ('\u{0424}', 0xd4), ('\u{0425}', 0xd5), ('\u{0426}', 0xd6), ('\u{0427}', 0xd7), ('\u{0428}', 0xd8), ('\u{0429}', 0xd9), ('\u{042a}', 0xda),
+    ('\u{042b}', 0xdb), ('\u{042c}', 0xdc), ('\u{042d}', 0xdd), ('\u{042e}', 0xde), ('\u{042f}', 0xdf), ('\u{0430}', 0xe0), ('\u{0431}', 0xe1), ('\u{0432}', 0xe2),
+    ('\u{0433}', 0xe3), ('\u{0434}', 0xe4), ('\u{0435}', 0xe5), ('\u{0436}', 0xe6), ('\u{0437}', 0xe7), ('\u{0438}', 0xe8), ('\u{0439}', 0xe9), ('\u{043a}', 0xea),
+    ('\u{043b}', 0xeb), ('\u{043c}', 0xec), ('\u{043d}', 0xed), ('\u{043e}', 0xee), ('\u{043f}', 0xef), ('\u{0440}', 0xf0), ('\u{0441}', 0xf1), ('\u{0442}', 0xf2),
+    ('\u{0443}', 0xf3), ('\u{0444}', 0xf4), ('\u{0445}', 0xf5), ('\u{0446}', 0xf6), ('\u{0447}', 0xf7), ('\u{0448}', 0xf8), ('\u{0449}', 0xf9), ('\u{044a}', 0xfa),
+    ('\u{044b}', 0xfb), ('\u{044c}', 0xfc), ('\u{044d}', 0xfd), ('\u{044e}', 0xfe), ('\u{044f}', 0xff), ('\u{0451}', 0xb8), ('\u{0452}', 0x90), ('\u{0453}', 0x83),
+    ('\u{0454}', 0xba), ('\u{0455}', 0xbe), ('\u{0456}', 0xb3), ('\u{0457}', 0xbf), ('\u{0458}', 0xbc), ('\u{0459}', 0x9a), ('\u{045a}', 0x9c), ('\u{045b}', 0x9e),
+    ('\u{045c}', 0x9d), ('\u{045e}', 0xa2), ('\u{045f}', 0x9f), ('\u{0490}', 0xa5), ('\u{0491}', 0xb4), ('\u{2013}', 0x96), ('\u{2014}', 0x97), ('\u{2018}', 0x91),
+    ('\u{2019}', 0x92), ('\u{201a}', 0x82), ('\u{201c}', 0x93), ('\u{201d}', 0x94), ('\u{201e}', 0x84), ('\u{2020}', 0x86), ('\u{2021}', 0x87), ('\u{2022}', 0x95),
+    ('\u{2026}', 0x85), ('\u{2030}', 0x89), ('\u{2039}', 0x8b), ('\u{203a}', 0x9b), ('\u{20ac}', 0x88), ('\u{2116}', 0xb9), ('\u{2122}', 0x99),
+];
+
+single_byte_charset! {
+    primary_name: "windows-1251",
+    mib_enum: 2251,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsWindows1251, b"cswindows1251");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0x98), None);
+        let error = Str::decode(b"ab\x98").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\x98");
+
+        let string = Str::decode(b"\x88").unwrap();
+        assert_eq!(string.to_unicode(), "\u{20ac}");
+    }
+}
@@ -0,0 +1,65 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{20ac}', '\0',       '\u{201a}', '\u{0192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}', '\u{02c6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\0',       '\u{017d}', '\0',
+    '\0',       '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}', '\u{02dc}', '\u{2122}', '\u{0161}', '\u{203a}', '\u{0153}', '\0',       '\u{017e}', '\u{0178}',
+    '\u{00a0}', '\u{00a1}', '\u{00a2}', '\u{00a3}', '\u{00a4}', '\u{00a5}', '\u{00a6}', '\u{00a7}', '\u{00a8}', '\u{00a9}', '\u{00aa}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{00af}',
+    '\u{00b0}', '\u{00b1}', '\u{00b2}', '\u{00b3}', '\u{00b4}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{00b8}', '\u{00b9}', '\u{00ba}', '\u{00bb}', '\u{00bc}', '\u{00bd}', '\u{00be}', '\u{00bf}',
+    '\u{00c0}', '\u{00c1}', '\u{00c2}', '\u{00c3}', '\u{00c4}', '\u{00c5}', '\u{00c6}', '\u{00c7}', '\u{00c8}', '\u{00c9}', '\u{00ca}', '\u{00cb}', '\u{00cc}', '\u{00cd}', '\u{00ce}', '\u{00cf}',
+    '\u{00d0}', '\u{00d1}', '\u{00d2}', '\u{00d3}', '\u{00d4}', '\u{00d5}', '\u{00d6}', '\u{00d7}', '\u{00d8}', '\u{00d9}', '\u{00da}', '\u{00db}', '\u{00dc}', '\u{00dd}', '\u{00de}', '\u{00df}',
+    '\u{00e0}', '\u{00e1}', '\u{00e2}', '\u{00e3}', '\u{00e4}', '\u{00e5}', '\u{00e6}', '\u{00e7}', '\u{00e8}', '\u{00e9}', '\u{00ea}', '\u{00eb}', '\u{00ec}', '\u{00ed}', '\u{00ee}', '\u{00ef}',
+    '\u{00f0}', '\u{00f1}', '\u{00f2}', '\u{00f3}', '\u{00f4}', '\u{00f5}', '\u{00f6}', '\u{00f7}', '\u{00f8}', '\u{00f9}', '\u{00fa}', '\u{00fb}', '\u{00fc}', '\u{00fd}', '\u{00fe}', '\u{00ff}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 123] = [
+    ('\u{00a0}', 0xa0), ('\u{00a1}', 0xa1), ('\u{00a2}', 0xa2), ('\u{00a3}', 0xa3), ('\u{00a4}', 0xa4), ('\u{00a5}', 0xa5), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7),
+    ('\u{00a8}', 0xa8), ('\u{00a9}', 0xa9), ('\u{00aa}', 0xaa), ('\u{00ab}', 0xab), ('\u{00ac}', 0xac), ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00af}', 0xaf),
+    ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1), ('\u{00b2}', 0xb2), ('\u{00b3}', 0xb3), ('\u{00b4}', 0xb4), ('\u{00b5}', 0xb5), ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7),
+    ('\u{00b8}', 0xb8), ('\u{00b9}', 0xb9), ('\u{00ba}', 0xba), ('\u{00bb}', 0xbb), ('\u{00bc}', 0xbc), ('\u{00bd}', 0xbd), ('\u{00be}', 0xbe), ('\u{00bf}', 0xbf),
+    ('\u{00c0}', 0xc0), ('\u{00c1}', 0xc1), ('\u{00c2}', 0xc2), ('\u{00c3}', 0xc3), ('\u{00c4}', 0xc4), ('\u{00c5}', 0xc5), ('\u{00c6}', 0xc6), ('\u{00c7}', 0xc7),
+    ('\u{00c8}', 0xc8), ('\u{00c9}', 0xc9), ('\u{00ca}', 0xca), ('\u{00cb}', 0xcb), ('\u{00cc}', 0xcc), ('\u{00cd}', 0xcd), ('\u{00ce}', 0xce), ('\u{00cf}', 0xcf),
+    ('\u{00d0}', 0xd0), ('\u{00d1}', 0xd1), ('\u{00d2}', 0xd2), ('\u{00d3}', 0xd3), ('\u{00d4}', 0xd4), ('\u{00d5}', 0xd5), ('\u{00d6}', 0xd6), ('\u{00d7}', 0xd7),
+    ('\u{00d8}', 0xd8), ('\u{00d9}', 0xd9), ('\u{00da}', 0xda), ('\u{00db}', 0xdb), ('\u{00dc}', 0xdc), ('\u{00dd}', 0xdd), ('\u{00de}', 0xde), ('\u{00df}', 0xdf),
+    ('\u{00e0}', 0xe0), ('\u{00e1}', 0xe1), ('\u{00e2}', 0xe2), ('\u{00e3}', 0xe3), ('\u{00e4}', 0xe4), ('\u{00e5}', 0xe5), ('\u{00e6}', 0xe6), ('\u{00e7}', 0xe7),
+    ('\u{00e8}', 0xe8), ('\u{00e9}', 0xe9), ('\u{00ea}', 0xea), ('\u{00eb}', 0xeb), ('\u{00ec}', 0xec), ('\u{00ed}', 0xed), ('\u{00ee}', 0xee), ('\u{00ef}', 0xef),
+    ('\u{00f0}', 0xf0), ('\u{00f1}', 0xf1), ('\u{00f2}', 0xf2), ('\u{00f3}', 0xf3), ('\u{00f4}', 0xf4), ('\u{00f5}', 0xf5), ('\u{00f6}', 0xf6), ('\u{00f7}', 0xf7),
+    ('\u{00f8}', 0xf8), ('\u{00f9}', 0xf9), ('\u{00fa}', 0xfa), ('\u{00fb}', 0xfb), ('\u{00fc}', 0xfc), ('\u{00fd}', 0xfd), ('\u{00fe}', 0xfe), ('\u{00ff}', 0xff),
+    ('\u{0152}', 0x8c), ('\u{0153}', 0x9c), ('\u{0160}', 0x8a), ('\u{0161}', 0x9a), ('\u{0178}', 0x9f), ('\u{017d}', 0x8e), ('\u{017e}', 0x9e), ('\u{0192}', 0x83),
+    ('\u{02c6}', 0x88), ('\u{02dc}', 0x98), ('\u{2013}', 0x96), ('\u{2014}', 0x97), ('\u{2018}', 0x91), ('\u{2019}', 0x92), ('\u{201a}', 0x82), ('\u{201c}', 0x93),
+    ('\u{201d}', 0x94), ('\u{201e}', 0x84), ('\u{2020}', 0x86), ('\u{2021}', 0x87), ('\u{2022}', 0x95), ('\u{2026}', 0x85), ('\u{2030}', 0x89), ('\u{2039}', 0x8b),
+    ('\u{203a}', 0x9b), ('\u{20ac}', 0x80), ('\u{2122}', 0x99),
+];
+
+single_byte_charset! {
+    primary_name: "windows-1252",
+    mib_enum: 2252,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsWindows1252, b"cswindows1252");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0x81), None);
+        let error = Str::decode(b"ab\x81").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\x81");
+
+        let (text, replacements) =
+            String::decode_lossy(b"\x80\x81", Replacement::ReplacementCharacter);
+        assert_eq!(text, "\u{20ac}\u{fffd}");
+        assert_eq!(replacements, 1);
+    }
+
+    #[test]
+    fn encodes_upper_half() {
+        let string = String::encode("\u{20ac}\u{e9}\u{178}").unwrap();
+        assert_eq!(Vec::from(string), b"\x80\xe9\x9f");
+    }
+}
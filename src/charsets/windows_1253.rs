@@ -0,0 +1,55 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{20ac}', '\0',       '\u{201a}', '\u{0192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}', '\0',       '\u{2030}', '\0',       '\u{2039}', '\0',       '\0',       '\0',       '\0',
+    '\0',       '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}', '\0',       '\u{2122}', '\0',       '\u{203a}', '\0',       '\0',       '\0',       '\0',
+    '\u{00a0}', '\u{0385}', '\u{0386}', '\u{00a3}', '\u{00a4}', '\u{00a5}', '\u{00a6}', '\u{00a7}', '\u{00a8}', '\u{00a9}', '\0',       '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{2015}',
+    '\u{00b0}', '\u{00b1}', '\u{00b2}', '\u{00b3}', '\u{0384}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{0388}', '\u{0389}', '\u{038a}', '\u{00bb}', '\u{038c}', '\u{00bd}', '\u{038e}', '\u{038f}',
+    '\u{0390}', '\u{0391}', '\u{0392}', '\u{0393}', '\u{0394}', '\u{0395}', '\u{0396}', '\u{0397}', '\u{0398}', '\u{0399}', '\u{039a}', '\u{039b}', '\u{039c}', '\u{039d}', '\u{039e}', '\u{039f}',
+    '\u{03a0}', '\u{03a1}', '\0',       '\u{03a3}', '\u{03a4}', '\u{03a5}', '\u{03a6}', '\u{03a7}', '\u{03a8}', '\u{03a9}', '\u{03aa}', '\u{03ab}', '\u{03ac}', '\u{03ad}', '\u{03ae}', '\u{03af}',
+    '\u{03b0}', '\u{03b1}', '\u{03b2}', '\u{03b3}', '\u{03b4}', '\u{03b5}', '\u{03b6}', '\u{03b7}', '\u{03b8}', '\u{03b9}', '\u{03ba}', '\u{03bb}', '\u{03bc}', '\u{03bd}', '\u{03be}', '\u{03bf}',
+    '\u{03c0}', '\u{03c1}', '\u{03c2}', '\u{03c3}', '\u{03c4}', '\u{03c5}', '\u{03c6}', '\u{03c7}', '\u{03c8}', '\u{03c9}', '\u{03ca}', '\u{03cb}', '\u{03cc}', '\u{03cd}', '\u{03ce}', '\0',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 111] = [
+    ('\u{00a0}', 0xa0), ('\u{00a3}', 0xa3), ('\u{00a4}', 0xa4), ('\u{00a5}', 0xa5), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a8}', 0xa8), ('\u{00a9}', 0xa9),
+    ('\u{00ab}', 0xab), ('\u{00ac}', 0xac), ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1), ('\u{00b2}', 0xb2), ('\u{00b3}', 0xb3),
+    ('\u{00b5}', 0xb5), ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7), ('\u{00bb}', 0xbb), ('\u{00bd}', 0xbd), ('\u{0192}', 0x83), ('\u{0384}', 0xb4), ('\u{0385}', 0xa1),
+    ('\u{0386}', 0xa2), ('\u{0388}', 0xb8), ('\u{0389}', 0xb9), ('\u{038a}', 0xba), ('\u{038c}', 0xbc), ('\u{038e}', 0xbe), ('\u{038f}', 0xbf), ('\u{0390}', 0xc0),
+    ('\u{0391}', 0xc1), ('\u{0392}', 0xc2), ('\u{0393}', 0xc3), ('\u{0394}', 0xc4), ('\u{0395}', 0xc5), ('\u{0396}', 0xc6), ('\u{0397}', 0xc7), ('\u{0398}', 0xc8),
+    ('\u{0399}', 0xc9), ('\u{039a}', 0xca), ('\u{039b}', 0xcb), ('\u{039c}', 0xcc), ('\u{039d}', 0xcd), ('\u{039e}', 0xce), ('\u{039f}', 0xcf), ('\u{03a0}', 0xd0),
+    ('\u{03a1}', 0xd1), ('\u{03a3}', 0xd3), ('\u{03a4}', 0xd4), ('\u{03a5}', 0xd5), ('\u{03a6}', 0xd6), ('\u{03a7}', 0xd7), ('\u{03a8}', 0xd8), ('\u{03a9}', 0xd9),
+    ('\u{03aa}', 0xda), ('\u{03ab}', 0xdb), ('\u{03ac}', 0xdc), ('\u{03ad}', 0xdd), ('\u{03ae}', 0xde), ('\u{03af}', 0xdf), ('\u{03b0}', 0xe0), ('\u{03b1}', 0xe1),
+    ('\u{03b2}', 0xe2), ('\u{03b3}', 0xe3), ('\u{03b4}', 0xe4), ('\u{03b5}', 0xe5), ('\u{03b6}', 0xe6), ('\u{03b7}', 0xe7), ('\u{03b8}', 0xe8), ('\u{03b9}', 0xe9),
+    ('\u{03ba}', 0xea), ('\u{03bb}', 0xeb), ('\u{03bc}', 0xec), ('\u{03bd}', 0xed), ('\u{03be}', 0xee), ('\u{03bf}', 0xef), ('\u{03c0}', 0xf0), ('\u{03c1}', 0xf1),
+    ('\u{03c2}', 0xf2), ('\u{03c3}', 0xf3), ('\u{03c4}', 0xf4), ('\u{03c5}', 0xf5), ('\u{03c6}', 0xf6), ('\u{03c7}', 0xf7), ('\u{03c8}', 0xf8), ('\u{03c9}', 0xf9),
+    ('\u{03ca}', 0xfa), ('\u{03cb}', 0xfb), ('\u{03cc}', 0xfc), ('\u{03cd}', 0xfd), ('\u{03ce}', 0xfe), ('\u{2013}', 0x96), ('\u{2014}', 0x97), ('\u{2015}', 0xaf),
+    ('\u{2018}', 0x91), ('\u{2019}', 0x92), ('\u{201a}', 0x82), ('\u{201c}', 0x93), ('\u{201d}', 0x94), ('\u{201e}', 0x84), ('\u{2020}', 0x86), ('\u{2021}', 0x87),
+    ('\u{2022}', 0x95), ('\u{2026}', 0x85), ('\u{2030}', 0x89), ('\u{2039}', 0x8b), ('\u{203a}', 0x9b), ('\u{20ac}', 0x80), ('\u{2122}', 0x99),
+];
+
+single_byte_charset! {
+    primary_name: "windows-1253",
+    mib_enum: 2253,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsWindows1253, b"cswindows1253");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0xaa), None);
+        let error = Str::decode(b"ab\xaa").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\xaa");
+
+        let string = Str::decode(b"\xa2").unwrap();
+        assert_eq!(string.to_unicode(), "\u{386}");
+    }
+}
@@ -0,0 +1,55 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{20ac}', '\0',       '\u{201a}', '\u{0192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}', '\u{02c6}', '\u{2030}', '\0',       '\u{2039}', '\0',       '\0',       '\0',       '\0',
+    '\0',       '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}', '\u{02dc}', '\u{2122}', '\0',       '\u{203a}', '\0',       '\0',       '\0',       '\0',
+    '\u{00a0}', '\u{00a1}', '\u{00a2}', '\u{00a3}', '\u{20aa}', '\u{00a5}', '\u{00a6}', '\u{00a7}', '\u{00a8}', '\u{00a9}', '\u{00d7}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{00af}',
+    '\u{00b0}', '\u{00b1}', '\u{00b2}', '\u{00b3}', '\u{00b4}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{00b8}', '\u{00b9}', '\u{00f7}', '\u{00bb}', '\u{00bc}', '\u{00bd}', '\u{00be}', '\u{00bf}',
+    '\u{05b0}', '\u{05b1}', '\u{05b2}', '\u{05b3}', '\u{05b4}', '\u{05b5}', '\u{05b6}', '\u{05b7}', '\u{05b8}', '\u{05b9}', '\0',       '\u{05bb}', '\u{05bc}', '\u{05bd}', '\u{05be}', '\u{05bf}',
+    '\u{05c0}', '\u{05c1}', '\u{05c2}', '\u{05c3}', '\u{05f0}', '\u{05f1}', '\u{05f2}', '\u{05f3}', '\u{05f4}', '\0',       '\0',       '\0',       '\0',       '\0',       '\0',       '\0',
+    '\u{05d0}', '\u{05d1}', '\u{05d2}', '\u{05d3}', '\u{05d4}', '\u{05d5}', '\u{05d6}', '\u{05d7}', '\u{05d8}', '\u{05d9}', '\u{05da}', '\u{05db}', '\u{05dc}', '\u{05dd}', '\u{05de}', '\u{05df}',
+    '\u{05e0}', '\u{05e1}', '\u{05e2}', '\u{05e3}', '\u{05e4}', '\u{05e5}', '\u{05e6}', '\u{05e7}', '\u{05e8}', '\u{05e9}', '\u{05ea}', '\0',       '\0',       '\u{200e}', '\u{200f}', '\0',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 105] = [
+    ('\u{00a0}', 0xa0), ('\u{00a1}', 0xa1), ('\u{00a2}', 0xa2), ('\u{00a3}', 0xa3), ('\u{00a5}', 0xa5), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a8}', 0xa8),
+    ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab), ('\u{00ac}', 0xac), ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00af}', 0xaf), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1),
+    ('\u{00b2}', 0xb2), ('\u{00b3}', 0xb3), ('\u{00b4}', 0xb4), ('\u{00b5}', 0xb5), ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7), ('\u{00b8}', 0xb8), ('\u{00b9}', 0xb9),
+    ('\u{00bb}', 0xbb), ('\u{00bc}', 0xbc), ('\u{00bd}', 0xbd), ('\u{00be}', 0xbe), ('\u{00bf}', 0xbf), ('\u{00d7}', 0xaa), ('\u{00f7}', 0xba), ('\u{0192}', 0x83),
+    ('\u{02c6}', 0x88), ('\u{02dc}', 0x98), ('\u{05b0}', 0xc0), ('\u{05b1}', 0xc1), ('\u{05b2}', 0xc2), ('\u{05b3}', 0xc3), ('\u{05b4}', 0xc4), ('\u{05b5}', 0xc5),
+    ('\u{05b6}', 0xc6), ('\u{05b7}', 0xc7), ('\u{05b8}', 0xc8), ('\u{05b9}', 0xc9), ('\u{05bb}', 0xcb), ('\u{05bc}', 0xcc), ('\u{05bd}', 0xcd), ('\u{05be}', 0xce),
+    ('\u{05bf}', 0xcf), ('\u{05c0}', 0xd0), ('\u{05c1}', 0xd1), ('\u{05c2}', 0xd2), ('\u{05c3}', 0xd3), ('\u{05d0}', 0xe0), ('\u{05d1}', 0xe1), ('\u{05d2}', 0xe2),
+    ('\u{05d3}', 0xe3), ('\u{05d4}', 0xe4), ('\u{05d5}', 0xe5), ('\u{05d6}', 0xe6), ('\u{05d7}', 0xe7), ('\u{05d8}', 0xe8), ('\u{05d9}', 0xe9), ('\u{05da}', 0xea),
+    ('\u{05db}', 0xeb), ('\u{05dc}', 0xec), ('\u{05dd}', 0xed), ('\u{05de}', 0xee), ('\u{05df}', 0xef), ('\u{05e0}', 0xf0), ('\u{05e1}', 0xf1), ('\u{05e2}', 0xf2),
+    ('\u{05e3}', 0xf3), ('\u{05e4}', 0xf4), ('\u{05e5}', 0xf5), ('\u{05e6}', 0xf6), ('\u{05e7}', 0xf7), ('\u{05e8}', 0xf8), ('\u{05e9}', 0xf9), ('\u{05ea}', 0xfa),
+    ('\u{05f0}', 0xd4), ('\u{05f1}', 0xd5), ('\u{05f2}', 0xd6), ('\u{05f3}', 0xd7), ('\u{05f4}', 0xd8), ('\u{200e}', 0xfd), ('\u{200f}', 0xfe), ('\u{2013}', 0x96),
+    ('\u{2014}', 0x97), ('\u{2018}', 0x91), ('\u{2019}', 0x92), ('\u{201a}', 0x82), ('\u{201c}', 0x93), ('\u{201d}', 0x94), ('\u{201e}', 0x84), ('\u{2020}', 0x86),
+    ('\u{2021}', 0x87), ('\u{2022}', 0x95), ('\u{2026}', 0x85), ('\u{2030}', 0x89), ('\u{2039}', 0x8b), ('\u{203a}', 0x9b), ('\u{20aa}', 0xa4), ('\u{20ac}', 0x80),
+    ('\u{2122}', 0x99),
+];
+
+single_byte_charset! {
+    primary_name: "windows-1255",
+    mib_enum: 2255,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsWindows1255, b"cswindows1255");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unassigned_bytes() {
+        assert_eq!(Character::from_byte(0xd9), None);
+        let error = Str::decode(b"ab\xd9").unwrap_err();
+        assert_eq!(error.valid_up_to(), 2);
+        assert_eq!(error.invalid_bytes(), b"\xd9");
+
+        let string = Str::decode(b"\xe0").unwrap();
+        assert_eq!(string.to_unicode(), "\u{5d0}");
+    }
+}
@@ -0,0 +1,56 @@
+#[rustfmt::skip]
+const UPPER_HALF_TO_UNICODE_MAP: [char; 128] = [
+    '\u{20ac}', '\u{067e}', '\u{201a}', '\u{0192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}', '\u{02c6}', '\u{2030}', '\u{0679}', '\u{2039}', '\u{0152}', '\u{0686}', '\u{0698}', '\u{0688}',
+    '\u{06af}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}', '\u{06a9}', '\u{2122}', '\u{0691}', '\u{203a}', '\u{0153}', '\u{200c}', '\u{200d}', '\u{06ba}',
+    '\u{00a0}', '\u{060c}', '\u{00a2}', '\u{00a3}', '\u{00a4}', '\u{00a5}', '\u{00a6}', '\u{00a7}', '\u{00a8}', '\u{00a9}', '\u{06be}', '\u{00ab}', '\u{00ac}', '\u{00ad}', '\u{00ae}', '\u{00af}',
+    '\u{00b0}', '\u{00b1}', '\u{00b2}', '\u{00b3}', '\u{00b4}', '\u{00b5}', '\u{00b6}', '\u{00b7}', '\u{00b8}', '\u{00b9}', '\u{061b}', '\u{00bb}', '\u{00bc}', '\u{00bd}', '\u{00be}', '\u{061f}',
+    '\u{06c1}', '\u{0621}', '\u{0622}', '\u{0623}', '\u{0624}', '\u{0625}', '\u{0626}', '\u{0627}', '\u{0628}', '\u{0629}', '\u{062a}', '\u{062b}', '\u{062c}', '\u{062d}', '\u{062e}', '\u{062f}',
+    '\u{0630}', '\u{0631}', '\u{0632}', '\u{0633}', '\u{0634}', '\u{0635}', '\u{0636}', '\u{00d7}', '\u{0637}', '\u{0638}', '\u{0639}', '\u{063a}', '\u{0640}', '\u{0641}', '\u{0642}', '\u{0643}',
+    '\u{00e0}', '\u{0644}', '\u{00e2}', '\u{0645}', '\u{0646}', '\u{0647}', '\u{0648}', '\u{00e7}', '\u{00e8}', '\u{00e9}', '\u{00ea}', '\u{00eb}', '\u{0649}', '\u{064a}', '\u{00ee}', '\u{00ef}',
+    '\u{064b}', '\u{064c}', '\u{064d}', '\u{064e}', '\u{00f4}', '\u{064f}', '\u{0650}', '\u{00f7}', '\u{0651}', '\u{00f9}', '\u{0652}', '\u{00fb}', '\u{00fc}', '\u{200e}', '\u{200f}', '\u{06d2}',
+];
+
+#[rustfmt::skip]
+const FROM_UNICODE_MAP: [(char, u8); 128] = [
+    ('\u{00a0}', 0xa0), ('\u{00a2}', 0xa2), ('\u{00a3}', 0xa3), ('\u{00a4}', 0xa4), ('\u{00a5}', 0xa5), ('\u{00a6}', 0xa6), ('\u{00a7}', 0xa7), ('\u{00a8}', 0xa8),
+    ('\u{00a9}', 0xa9), ('\u{00ab}', 0xab), ('\u{00ac}', 0xac), ('\u{00ad}', 0xad), ('\u{00ae}', 0xae), ('\u{00af}', 0xaf), ('\u{00b0}', 0xb0), ('\u{00b1}', 0xb1),
+    ('\u{00b2}', 0xb2), ('\u{00b3}', 0xb3), ('\u{00b4}', 0xb4), ('\u{00b5}', 0xb5), ('\u{00b6}', 0xb6), ('\u{00b7}', 0xb7), ('\u{00b8}', 0xb8), ('\u{00b9}', 0xb9),
+    ('\u{00bb}', 0xbb), ('\u{00bc}', 0xbc), ('\u{00bd}', 0xbd), ('\u{00be}', 0xbe), ('\u{00d7}', 0xd7), ('\u{00e0}', 0xe0), ('\u{00e2}', 0xe2), ('\u{00e7}', 0xe7),
+    ('\u{00e8}', 0xe8), ('\u{00e9}', 0xe9), ('\u{00ea}', 0xea), ('\u{00eb}', 0xeb), ('\u{00ee}', 0xee), ('\u{00ef}', 0xef), ('\u{00f4}', 0xf4), ('\u{00f7}', 0xf7),
+    ('\u{00f9}', 0xf9), ('\u{00fb}', 0xfb), ('\u{00fc}', 0xfc), ('\u{0152}', 0x8c), ('\u{0153}', 0x9c), ('\u{0192}', 0x83), ('\u{02c6}', 0x88), ('\u{060c}', 0xa1),
+    ('\u{061b}', 0xba), ('\u{061f}', 0xbf), ('\u{0621}', 0xc1), ('\u{0622}', 0xc2), ('\u{0623}', 0xc3), ('\u{0624}', 0xc4), ('\u{0625}', 0xc5), ('\u{0626}', 0xc6),
+    ('\u{0627}', 0xc7), ('\u{0628}', 0xc8), ('\u{0629}', 0xc9), ('\u{062a}', 0xca), ('\u{062b}', 0xcb), ('\u{062c}', 0xcc), ('\u{062d}', 0xcd), ('\u{062e}', 0xce),
+    ('\u{062f}', 0xcf), ('\u{0630}', 0xd0), ('\u{0631}', 0xd1), ('\u{0632}', 0xd2), ('\u{0633}', 0xd3), ('\u{0634}', 0xd4), ('\u{0635}', 0xd5), ('\u{0636}', 0xd6),
+    ('\u{0637}', 0xd8), ('\u{0638}', 0xd9), ('\u{0639}', 0xda), ('\u{063a}', 0xdb), ('\u{0640}', 0xdc), ('\u{0641}', 0xdd), ('\u{0642}', 0xde), ('\u{0643}', 0xdf),
+    ('\u{0644}', 0xe1), ('\u{0645}', 0xe3), ('\u{0646}', 0xe4), ('\u{0647}', 0xe5), ('\u{0648}', 0xe6), ('\u{0649}', 0xec), ('\u{064a}', 0xed), ('\u{064b}', 0xf0),
+    ('\u{064c}', 0xf1), ('\u{064d}', 0xf2), ('\u{064e}', 0xf3), ('\u{064f}', 0xf5), ('\u{0650}', 0xf6), ('\u{0651}', 0xf8), ('\u{0652}', 0xfa), ('\u{0679}', 0x8a),
+    ('\u{067e}', 0x81), ('\u{0686}', 0x8d), ('\u{0688}', 0x8f), ('\u{0691}', 0x9a), ('\u{0698}', 0x8e), ('\u{06a9}', 0x98), ('\u{06af}', 0x90), ('\u{06ba}', 0x9f),
+    ('\u{06be}', 0xaa), ('\u{06c1}', 0xc0), ('\u{06d2}', 0xff), ('\u{200c}', 0x9d), ('\u{200d}', 0x9e), ('\u{200e}', 0xfd), ('\u{200f}', 0xfe), ('\u{2013}', 0x96),
+    ('\u{2014}', 0x97), ('\u{2018}', 0x91), ('\u{2019}', 0x92), ('\u{201a}', 0x82), ('\u{201c}', 0x93), ('\u{201d}', 0x94), ('\u{201e}', 0x84), ('\u{2020}', 0x86),
+    ('\u{2021}', 0x87), ('\u{2022}', 0x95), ('\u{2026}', 0x85), ('\u{2030}', 0x89), ('\u{2039}', 0x8b), ('\u{203a}', 0x9b), ('\u{20ac}', 0x80), ('\u{2122}', 0x99),
+];
+
+single_byte_charset! {
+    primary_name: "windows-1256",
+    mib_enum: 2256,
+    upper_half: UPPER_HALF_TO_UNICODE_MAP,
+    from_unicode: FROM_UNICODE_MAP,
+    aliases: [
+        (CsWindows1256, b"cswindows1256");
+    ],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assigns_every_byte() {
+        for byte in 0..=0xff {
+            assert!(Character::from_byte(byte).is_some(), "{:02x}", byte);
+        }
+
+        let string = Str::decode(b"\x81\x90\xff").unwrap();
+        assert_eq!(string.to_unicode(), "\u{67e}\u{6af}\u{6d2}");
+    }
+}
@@ -0,0 +1,258 @@
+use std::io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write};
+use std::mem;
+
+use crate::charset::Decoder as DecoderTrait;
+use crate::charsets::{Charset, DecodeError, Decoder, Encoder, Utf8Decoder};
+
+const BUFFER_SIZE: usize = 8 * 1024;
+
+/// A reader that decodes the bytes of the wrapped reader from a charset, producing UTF-8.
+///
+/// Invalid input is reported as an [`ErrorKind::InvalidData`] error once everything before it
+/// has been read.
+#[derive(Debug)]
+pub struct DecodingReader<R> {
+    decoder: Option<Decoder>,
+    error: Option<DecodeError>,
+    input: Vec<u8>,
+    output: String,
+    output_position: usize,
+    reader: R,
+}
+
+impl<R: Read> DecodingReader<R> {
+    pub fn new(reader: R, charset: Charset) -> Self {
+        DecodingReader {
+            decoder: Some(charset.decoder()),
+            error: None,
+            input: vec![0; BUFFER_SIZE],
+            output: String::new(),
+            output_position: 0,
+            reader,
+        }
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for DecodingReader<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> IoResult<usize> {
+        while self.output_position == self.output.len() {
+            if let Some(error) = self.error.take() {
+                return Err(IoError::new(ErrorKind::InvalidData, error));
+            }
+
+            let decoder = match self.decoder.as_mut() {
+                Some(decoder) => decoder,
+                None => return Ok(0),
+            };
+
+            self.output.clear();
+            self.output_position = 0;
+            let length = self.reader.read(&mut self.input)?;
+
+            let result = if length == 0 {
+                let decoder = *decoder;
+                self.decoder = None;
+                decoder.finish()
+            } else {
+                decoder.decode_to_unicode(&self.input[..length], &mut self.output)
+            };
+
+            if let Err(error) = result {
+                self.decoder = None;
+                self.error = Some(error);
+            }
+        }
+
+        let output = &self.output.as_bytes()[self.output_position..];
+        let length = output.len().min(buffer.len());
+        buffer[..length].copy_from_slice(&output[..length]);
+        self.output_position += length;
+        Ok(length)
+    }
+}
+
+/// A writer that encodes the UTF-8 written to it into a charset before passing it on to the
+/// wrapped writer.
+///
+/// Input that is not UTF-8 or that cannot be encoded into the charset is reported as an
+/// [`ErrorKind::InvalidData`] error. [`EncodingWriter::finish`] should be called once everything
+/// has been written, as dropping the writer ends the output but ignores any errors in doing so.
+#[derive(Debug)]
+pub struct EncodingWriter<W: Write> {
+    encoder: Encoder,
+    output: Vec<u8>,
+    text: String,
+    utf8_decoder: Utf8Decoder,
+
+    /// The wrapped writer, which is only taken out by [`EncodingWriter::finish`] and
+    /// [`EncodingWriter::into_inner`].
+    writer: Option<W>,
+}
+
+impl<W: Write> EncodingWriter<W> {
+    pub fn new(writer: W, charset: Charset) -> Self {
+        EncodingWriter {
+            encoder: charset.encoder(),
+            output: Vec::new(),
+            text: String::new(),
+            utf8_decoder: Utf8Decoder::default(),
+            writer: Some(writer),
+        }
+    }
+
+    /// Ends the output, failing if it ended in the middle of a UTF-8 sequence, and returns the
+    /// wrapped writer after flushing it.
+    pub fn finish(mut self) -> IoResult<W> {
+        let result = self.finish_output();
+        let writer = self.writer.take().unwrap();
+        result.map(|()| writer)
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        self.writer.as_mut().unwrap()
+    }
+
+    pub fn get_ref(&self) -> &W {
+        self.writer.as_ref().unwrap()
+    }
+
+    /// Returns the wrapped writer without ending the output.
+    pub fn into_inner(mut self) -> W {
+        self.writer.take().unwrap()
+    }
+
+    fn finish_output(&mut self) -> IoResult<()> {
+        mem::take(&mut self.utf8_decoder)
+            .finish()
+            .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+        // The output buffer is far larger than anything an encoder needs to finish.
+        self.output.resize(BUFFER_SIZE, 0);
+        let length = self.encoder.finish(&mut self.output).unwrap();
+        let writer = self.writer.as_mut().unwrap();
+        writer.write_all(&self.output[..length])?;
+        writer.flush()
+    }
+}
+
+impl<W: Write> Drop for EncodingWriter<W> {
+    fn drop(&mut self) {
+        if self.writer.is_some() {
+            // Errors cannot be reported from here, so the output is only ended on a best-effort
+            // basis, like `BufWriter` does.
+            let _ = self.finish_output();
+        }
+    }
+}
+
+impl<W: Write> Write for EncodingWriter<W> {
+    /// The whole buffer is encoded before anything is passed on to the wrapped writer, so that
+    /// nothing is written if any of it cannot be encoded.
+    fn write(&mut self, buffer: &[u8]) -> IoResult<usize> {
+        let mut encoder = self.encoder;
+        let mut utf8_decoder = self.utf8_decoder;
+        self.text.clear();
+        utf8_decoder
+            .decode_to_unicode(buffer, &mut self.text)
+            .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+
+        let mut text = self.text.as_str();
+        self.output.clear();
+
+        while !text.is_empty() {
+            let length = self.output.len();
+            self.output.resize(length + BUFFER_SIZE, 0);
+            let (read, written) = encoder
+                .encode(text, &mut self.output[length..])
+                .map_err(|error| IoError::new(ErrorKind::InvalidData, error))?;
+            self.output.truncate(length + written);
+            text = &text[read..];
+        }
+
+        self.writer.as_mut().unwrap().write_all(&self.output)?;
+        self.encoder = encoder;
+        self.utf8_decoder = utf8_decoder;
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.get_mut().flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decoding_reader_decodes_whole_input() {
+        let input: &[u8] = b"caf\xe9";
+        let mut reader = DecodingReader::new(input, Charset::Iso8859_1_1987);
+        let mut output = String::new();
+        reader.read_to_string(&mut output).unwrap();
+        assert_eq!(output, "caf\u{e9}");
+    }
+
+    #[test]
+    fn decoding_reader_reports_invalid_input_after_valid_prefix() {
+        let input: &[u8] = b"ab\x80";
+        let mut reader = DecodingReader::new(input, Charset::UsAscii);
+        let mut buffer = [0; 16];
+        assert_eq!(reader.read(&mut buffer).unwrap(), 2);
+        let error = reader.read(&mut buffer).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn encoding_writer_writes_nothing_on_error() {
+        let mut writer = EncodingWriter::new(Vec::new(), Charset::UsAscii);
+        let error = writer.write("abc\u{e9}".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert!(writer.get_ref().is_empty());
+
+        writer.write_all(b"abc").unwrap();
+        assert_eq!(writer.finish().unwrap(), b"abc");
+    }
+
+    #[test]
+    fn encoding_writer_carries_state_across_writes() {
+        let mut writer = EncodingWriter::new(Vec::new(), Charset::Iso2022Jp);
+        let text = "a\u{65e5}\u{672c}b".as_bytes();
+
+        for byte in text {
+            writer.write_all(std::slice::from_ref(byte)).unwrap();
+        }
+
+        assert_eq!(writer.finish().unwrap(), b"a\x1b$BF|K\\\x1b(Bb");
+    }
+
+    #[test]
+    fn encoding_writer_ends_output_when_dropped() {
+        let mut output = Vec::new();
+
+        {
+            let mut writer = EncodingWriter::new(&mut output, Charset::Iso2022Jp);
+            writer.write_all("a\u{65e5}".as_bytes()).unwrap();
+        }
+
+        assert_eq!(output, b"a\x1b$BF|\x1b(B");
+    }
+
+    #[test]
+    fn encoding_writer_into_inner_does_not_end_output() {
+        let mut writer = EncodingWriter::new(Vec::new(), Charset::Iso2022Jp);
+        writer.write_all("a\u{65e5}".as_bytes()).unwrap();
+        assert_eq!(writer.into_inner(), b"a\x1b$BF|");
+    }
+}
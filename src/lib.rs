@@ -3,4 +3,7 @@
 
 #[macro_use]
 pub mod charset;
+#[cfg(feature = "async")]
+pub mod async_io;
 pub mod charsets;
+pub mod io;